@@ -18,13 +18,10 @@ fn containers_doc_exit_codes_match_cli_contract() {
     let expected_rows = [
         "| 0 | Success |",
         "| 1 | General error |",
-        "| 2 | Authentication failure |",
-        "| 3 | Connection error |",
+        "| 2 | Usage error |",
+        "| 3 | Authentication failure |",
         "| 4 | Resource not found |",
-        "| 5 | Validation error |",
-        "| 6 | Permission denied |",
-        "| 7 | Rate limited |",
-        "| 8 | Service unavailable |",
+        "| 5 | Timeout |",
         "| 130 | Interrupted (Ctrl+C) |",
     ];
 
@@ -37,7 +34,15 @@ fn containers_doc_exit_codes_match_cli_contract() {
     }
 
     // Guard against old/incorrect mapping that previously drifted.
-    let stale_rows = ["| 2 | Invalid arguments |", "| 5 | Timeout |"];
+    let stale_rows = [
+        "| 2 | Invalid arguments |",
+        "| 2 | Authentication failure |",
+        "| 3 | Connection error |",
+        "| 5 | Validation error |",
+        "| 6 | Permission denied |",
+        "| 7 | Rate limited |",
+        "| 8 | Service unavailable |",
+    ];
 
     for stale in stale_rows {
         assert!(
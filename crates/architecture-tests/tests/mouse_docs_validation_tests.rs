@@ -5,8 +5,9 @@
 //!
 //! What This Tests:
 //! - Mouse Support section exists in docs/user-guide.md
-//! - Documentation mentions correct features (selection, click-twice inspect, scrolling, quit)
-//! - Documentation correctly states navigation is keyboard-only
+//! - Documentation mentions correct features (tabs, selection, click-twice inspect,
+//!   scrolling, confirmations, quit)
+//! - Documentation describes tab bar clicks alongside the Tab/Shift+Tab keys
 //! - Documentation uses "click twice" not "double-click" (implementation has no timing)
 //!
 //! What This Does NOT Do:
@@ -16,9 +17,16 @@
 use std::fs;
 use std::path::PathBuf;
 
-const EXPECTED_MOUSE_FEATURES: &[&str] = &["Selection", "Inspect", "Scrolling", "Quit"];
+const EXPECTED_MOUSE_FEATURES: &[&str] = &[
+    "Tabs",
+    "Selection",
+    "Inspect",
+    "Scrolling",
+    "Confirmations",
+    "Quit",
+];
 
-const KEYBOARD_ONLY_PHRASES: &[&str] = &["keyboard-only", "Tab"];
+const SCREEN_NAVIGATION_PHRASES: &[&str] = &["tab bar", "Shift+Tab"];
 
 fn find_workspace_root() -> PathBuf {
     let current_dir = std::env::current_dir().expect("Failed to get current directory");
@@ -88,19 +96,19 @@ fn mouse_docs_lists_expected_features() {
 }
 
 #[test]
-fn mouse_docs_states_keyboard_only_navigation() {
+fn mouse_docs_describes_screen_navigation() {
     let section = get_mouse_docs_section();
 
-    let has_keyboard_note = KEYBOARD_ONLY_PHRASES
+    let has_navigation_note = SCREEN_NAVIGATION_PHRASES
         .iter()
         .all(|phrase| section.to_lowercase().contains(&phrase.to_lowercase()));
 
     assert!(
-        has_keyboard_note,
-        "Mouse Support section should state that screen navigation is keyboard-only\n\
+        has_navigation_note,
+        "Mouse Support section should describe tab bar clicks and the Tab/Shift+Tab keys\n\
          Expected phrases: {:?}\n\
          Section content:\n{}",
-        KEYBOARD_ONLY_PHRASES, section
+        SCREEN_NAVIGATION_PHRASES, section
     );
 }
 
@@ -122,10 +122,51 @@ pub struct Cli {
     #[arg(short, long, global = true, default_value = "table")]
     pub output: String,
 
+    /// Print only the value at a JSON path, e.g. `.splunkd_health.health` or `/items/0/name`
+    ///
+    /// Implies `--output json`. Strings print without quotes; a missing or null value
+    /// exits with code 4.
+    #[arg(long, global = true, value_name = "PATH", value_parser = crate::formatters::parse_value_path)]
+    pub get: Option<crate::formatters::ValuePath>,
+
+    /// Keep only these fields (comma-separated) in each result, e.g. `name,owner`
+    ///
+    /// Nested fields use `--get` paths (`content.disabled`). Applies to every output format.
+    #[arg(
+        long = "fields",
+        id = "select_fields",
+        global = true,
+        value_delimiter = ',',
+        value_name = "FIELDS"
+    )]
+    pub fields: Option<Vec<String>>,
+
+    /// Filter results with a JSONPath expression, e.g. `$[?@.totalEventCount > 0]`
+    ///
+    /// Runs before `--fields`; the matched values are rendered in the `--output` format.
+    #[arg(
+        long = "query",
+        id = "output_query",
+        global = true,
+        value_name = "JSONPATH",
+        value_parser = crate::formatters::parse_output_query
+    )]
+    pub query: Option<crate::formatters::OutputQuery>,
+
     /// Output file path (saves results to file instead of stdout)
     #[arg(long, global = true, value_name = "FILE")]
     pub output_file: Option<PathBuf>,
 
+    /// How errors are printed on stderr: `text`, or `json` for one structured object
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        env = "SPLUNK_ERROR_FORMAT",
+        default_value_t = crate::error::ErrorFormat::Text
+    )]
+    pub error_format: crate::error::ErrorFormat,
+
     /// Path to a custom configuration file (overrides default location).
     ///
     /// Can also be set via SPLUNK_CONFIG_PATH environment variable.
@@ -169,6 +210,52 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub no_cache: bool,
 
+    /// Read only from the offline snapshot cache; never contact the server.
+    ///
+    /// Supports `indexes list`, `jobs`, and `health`, answered with the last
+    /// responses splunk-tui saved for the selected profile.
+    #[arg(long, global = true)]
+    pub offline: bool,
+
+    /// Record sanitized HTTP request/response traces to a JSON file.
+    ///
+    /// Captures URLs, status codes, latency, sizes, and bodies for every request
+    /// with credentials redacted, so the file can be attached to bug reports.
+    #[arg(long, global = true, value_name = "FILE")]
+    pub trace_http: Option<PathBuf>,
+
+    /// App context for knowledge-object requests.
+    ///
+    /// Saved searches, macros, lookups, dashboards, event types and configs are
+    /// read and written under `/servicesNS/{owner}/{app}`, and listings only show
+    /// objects defined in this app.
+    #[arg(
+        long,
+        global = true,
+        env = "SPLUNK_APP",
+        value_name = "APP",
+        hide_env_values = true
+    )]
+    pub app: Option<String>,
+
+    /// Owner (user context) for knowledge-object requests.
+    ///
+    /// Combine with --app to reach a user's private objects.
+    #[arg(
+        long,
+        global = true,
+        env = "SPLUNK_OWNER",
+        value_name = "USER",
+        hide_env_values = true
+    )]
+    pub owner: Option<String>,
+
+    /// Only list knowledge objects shared at this level.
+    ///
+    /// Also the sharing level given to lookups uploaded without --sharing.
+    #[arg(long, global = true, value_parser = ["user", "app", "global"])]
+    pub sharing: Option<String>,
+
     /// Disable the circuit breaker for API calls.
     ///
     /// When enabled (default), the client will fail fast when an endpoint
@@ -217,6 +304,20 @@ pub struct Cli {
     )]
     pub circuit_half_open_requests: Option<u32>,
 
+    /// Limit outgoing API requests as RATE or RATE:BURST (e.g., "10" or "10:20").
+    ///
+    /// RATE is requests per second; BURST is how many requests may be sent
+    /// back-to-back (defaults to RATE). Applies to every endpoint, including
+    /// retries, and to each profile in multi-profile commands.
+    #[arg(
+        long,
+        global = true,
+        env = "SPLUNK_RATE_LIMIT",
+        value_name = "RATE[:BURST]",
+        hide_env_values = true
+    )]
+    pub rate_limit: Option<splunk_config::RateLimitConfig>,
+
     /// Enable Prometheus metrics endpoint and bind address (e.g., "localhost:9090")
     ///
     /// When enabled, exposes /metrics endpoint for Prometheus scraping.
@@ -263,6 +364,9 @@ pub struct Cli {
     pub command: Commands,
 }
 
+// Parsed once per invocation, so variant sizes don't matter and boxing would only
+// complicate matching
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand)]
 pub enum Commands {
     /// Manage configuration profiles
@@ -288,12 +392,12 @@ pub enum Commands {
 
         /// Earliest time for the search (e.g., '-24h', '2024-01-01T00:00:00')
         /// (Deprecated: use 'search execute --earliest')
-        #[arg(short, long, allow_hyphen_values = true, hide = true)]
+        #[arg(short, long, allow_hyphen_values = true, hide = true, value_parser = commands::search::parse_time_modifier)]
         earliest: Option<String>,
 
         /// Latest time for the search (e.g., 'now', '2024-01-02T00:00:00')
         /// (Deprecated: use 'search execute --latest')
-        #[arg(short, long, allow_hyphen_values = true, hide = true)]
+        #[arg(short, long, allow_hyphen_values = true, hide = true, value_parser = commands::search::parse_time_modifier)]
         latest: Option<String>,
 
         /// Maximum number of results to return
@@ -310,6 +414,26 @@ pub enum Commands {
         /// (Deprecated: use 'search execute --realtime-window')
         #[arg(long, requires = "realtime", hide = true)]
         realtime_window: Option<u64>,
+
+        /// Finalize the job once N results (or events) are available
+        /// (Deprecated: use 'search execute --finalize-after')
+        #[arg(long, value_name = "N", value_parser = commands::search::parse_finalize_after, hide = true)]
+        finalize_after: Option<usize>,
+
+        #[command(flatten)]
+        diff: commands::search_diff::SearchDiffArgs,
+
+        #[command(flatten)]
+        targets: commands::multi_profile::ProfileTargets,
+
+        #[command(flatten)]
+        merge: commands::search::SearchMergeArgs,
+    },
+
+    /// Save search templates with placeholders and run them with parameter values
+    Templates {
+        #[command(subcommand)]
+        command: commands::templates::TemplatesCommand,
     },
 
     /// List and manage indexes
@@ -333,8 +457,11 @@ pub enum Commands {
         offset: usize,
     },
 
-    /// List distributed search peers
+    /// List and manage distributed search peers
     SearchPeers {
+        #[command(subcommand)]
+        command: Option<commands::search_peers::SearchPeersCommand>,
+
         /// Show detailed information about each search peer
         #[arg(short, long)]
         detailed: bool,
@@ -379,6 +506,10 @@ pub enum Commands {
         #[arg(long, value_name = "SID", group = "action")]
         inspect: Option<String>,
 
+        /// Show command timings, dispatch phases, and per-peer counters (for --inspect)
+        #[arg(long, requires = "inspect")]
+        performance: bool,
+
         /// Cancel a specific job by SID
         #[arg(long, value_name = "SID", group = "action")]
         cancel: Option<String>,
@@ -402,10 +533,39 @@ pub enum Commands {
         /// Maximum number of jobs to list
         #[arg(short, long, default_value_t = DEFAULT_LIST_PAGE_SIZE, visible_alias = "job-count")]
         count: usize,
+
+        /// Only list jobs owned by this user (filtered server-side)
+        #[arg(long, value_name = "USER")]
+        owner: Option<String>,
+
+        /// Only list jobs dispatched from this app (filtered server-side)
+        #[arg(long)]
+        app: Option<String>,
+
+        /// Only list jobs in this state: running, done, failed (filtered server-side)
+        #[arg(long, value_name = "STATUS")]
+        status: Option<splunk_client::JobStatusFilter>,
+
+        #[command(flatten)]
+        targets: commands::multi_profile::ProfileTargets,
     },
 
     /// Perform a comprehensive system health check
-    Health,
+    Health {
+        #[command(subcommand)]
+        command: Option<commands::health::HealthCommand>,
+
+        /// Re-run the full health check at this interval (e.g., 60s, 5m) until interrupted
+        #[arg(long, value_name = "INTERVAL", value_parser = commands::generate::parse_duration)]
+        watch: Option<std::time::Duration>,
+
+        /// POST a JSON payload (Slack-compatible) to this URL when the health status changes
+        #[arg(long, value_name = "URL")]
+        notify_webhook: Option<String>,
+
+        #[command(flatten)]
+        targets: commands::multi_profile::ProfileTargets,
+    },
 
     /// Run comprehensive diagnostics and validate configuration
     Doctor {
@@ -416,6 +576,49 @@ pub enum Commands {
         /// Include recent splunk-tui logs in the bundle
         #[arg(long, requires = "bundle")]
         include_logs: bool,
+
+        /// POST a JSON payload (Slack-compatible) to this URL when the overall result changes
+        #[arg(long, value_name = "URL")]
+        notify_webhook: Option<String>,
+    },
+
+    /// Run health, license, and cluster checks on an interval and report level changes
+    #[command(after_help = "Examples:
+  splunk-cli monitor --interval 60 --checks health,license,cluster --state-file state.json
+  RUST_LOG=off splunk-cli monitor -o ndjson >> transitions.ndjson
+  splunk-cli monitor --notify-webhook https://hooks.slack.com/services/T000/B000/XXXX
+  splunk-cli monitor --once --state-file state.json
+")]
+    Monitor {
+        /// Time between evaluations (e.g., 60, 30s, 5m)
+        #[arg(long, value_name = "INTERVAL", default_value = "60", value_parser = commands::generate::parse_duration)]
+        interval: std::time::Duration,
+
+        /// Comma-separated checks to run: health, license, cluster
+        #[arg(long, value_delimiter = ',', default_value = "health,license")]
+        checks: Vec<splunk_client::workflows::monitor::MonitorCheck>,
+
+        /// Keep check levels in this file so a restart only reports real changes
+        #[arg(long, value_name = "PATH")]
+        state_file: Option<PathBuf>,
+
+        /// POST each level change (Slack-compatible JSON) to this URL
+        #[arg(long, value_name = "URL")]
+        notify_webhook: Option<String>,
+
+        /// License usage (percent of quota) at which the license check warns
+        /// [default: the profile's health_thresholds, else 70]
+        #[arg(long, value_name = "PCT")]
+        license_warning: Option<f64>,
+
+        /// License usage (percent of quota) at which the license check is critical
+        /// [default: the profile's health_thresholds, else 90]
+        #[arg(long, value_name = "PCT")]
+        license_critical: Option<f64>,
+
+        /// Evaluate once and exit instead of running until interrupted
+        #[arg(long)]
+        once: bool,
     },
 
     /// Show KVStore status and manage collections
@@ -437,7 +640,7 @@ pub enum Commands {
         count: usize,
 
         /// Earliest time for logs (e.g., '-24h', '2024-01-01T00:00:00')
-        #[arg(short, long, default_value = DEFAULT_INTERNAL_LOGS_EARLIEST_TIME, allow_hyphen_values = true)]
+        #[arg(short, long, default_value = DEFAULT_INTERNAL_LOGS_EARLIEST_TIME, allow_hyphen_values = true, value_parser = commands::search::parse_time_modifier)]
         earliest: String,
 
         /// Follow logs in real-time
@@ -457,6 +660,13 @@ pub enum Commands {
         command: commands::roles::RolesCommand,
     },
 
+    /// Show the authenticated user's roles and effective capabilities
+    #[command(after_help = "Examples:
+  splunk-cli whoami
+  splunk-cli whoami -o json
+")]
+    Whoami,
+
     /// List and manage installed Splunk apps
     Apps {
         #[command(subcommand)]
@@ -469,14 +679,8 @@ pub enum Commands {
         #[arg(short, long, value_delimiter = ',')]
         resources: Option<Vec<String>>,
 
-        /// Comma-separated list of profile names to query (e.g., 'dev,prod')
-        /// If not specified, uses the default profile or SPLUNK_PROFILE env var
-        #[arg(long, value_delimiter = ',')]
-        profiles: Option<Vec<String>>,
-
-        /// Query all configured profiles
-        #[arg(long, conflicts_with = "profiles")]
-        all_profiles: bool,
+        #[command(flatten)]
+        targets: commands::multi_profile::ProfileTargets,
     },
 
     /// List and manage saved searches
@@ -491,12 +695,62 @@ pub enum Commands {
         command: commands::macros::MacrosCommand,
     },
 
+    /// Manage Splunk Cloud indexes and HEC tokens through the Admin Config Service
+    #[command(after_help = "Examples:
+  splunk-cli acs indexes list
+  splunk-cli acs indexes create web --searchable-days 90
+  splunk-cli acs hec-tokens list -o json
+
+The stack comes from acs_stack in the profile, SPLUNK_ACS_STACK, or a
+*.splunkcloud.com base URL. ACS requires API token authentication.
+")]
+    Acs {
+        #[command(subcommand)]
+        command: commands::acs::AcsCommand,
+    },
+
+    /// List and dismiss server messages (bulletin board)
+    Messages {
+        #[command(subcommand)]
+        command: commands::messages::MessagesCommand,
+    },
+
     /// List and manage data inputs (TCP, UDP, Monitor, Script)
     Inputs {
         #[command(subcommand)]
         command: commands::inputs::InputsCommand,
     },
 
+    /// Show indexing latency and thruput per sourcetype, index, or host
+    Ingestion {
+        #[command(subcommand)]
+        command: commands::ingestion::IngestionCommand,
+    },
+
+    /// List sourcetypes with event counts, first/last seen, and indexes
+    #[command(after_help = "Examples:
+  splunk-cli sourcetypes
+  splunk-cli sourcetypes --index main
+  splunk-cli sourcetypes -o csv > sourcetypes.csv
+")]
+    Sourcetypes {
+        /// Only list sourcetypes with events in this index
+        #[arg(short, long)]
+        index: Option<String>,
+    },
+
+    /// Test regex field extractions against sample events
+    Extract {
+        #[command(subcommand)]
+        command: commands::extract::ExtractCommand,
+    },
+
+    /// List hosts and sources with last event times, and find silent ones
+    Metadata {
+        #[command(subcommand)]
+        command: commands::metadata::MetadataCommand,
+    },
+
     /// View and manage configuration files (props.conf, transforms.conf, etc.)
     Configs {
         #[command(subcommand)]
@@ -556,12 +810,95 @@ pub enum Commands {
         offset: usize,
     },
 
-    /// Send events to Splunk via HTTP Event Collector (HEC)
+    /// Send events to Splunk via HTTP Event Collector (HEC) and manage HEC tokens
     Hec {
         #[command(subcommand)]
         command: commands::hec::HecCommand,
     },
 
+    /// Generate synthetic events and ingest them via HEC (lab/demo data)
+    Generate {
+        /// HEC URL (e.g., https://localhost:8088).
+        #[arg(
+            long,
+            env = "SPLUNK_HEC_URL",
+            hide_env_values = true,
+            required_unless_present = "dry_run"
+        )]
+        hec_url: Option<String>,
+
+        /// HEC token for authentication.
+        #[arg(
+            long,
+            env = "SPLUNK_HEC_TOKEN",
+            hide_env_values = true,
+            required_unless_present = "dry_run"
+        )]
+        hec_token: Option<String>,
+
+        /// Destination index (defaults to the HEC token's index)
+        #[arg(long)]
+        index: Option<String>,
+
+        /// Sourcetype (defaults per template: access_combined, _json, syslog)
+        #[arg(long)]
+        sourcetype: Option<String>,
+
+        /// Source field for generated events
+        #[arg(long, default_value = commands::generate::DEFAULT_SOURCE)]
+        source: String,
+
+        /// Host field (defaults to a rotating set of synthetic hosts)
+        #[arg(long)]
+        host: Option<String>,
+
+        /// Target ingestion rate (e.g., 50/s, 300/m, 3600/h)
+        #[arg(long, default_value = "10/s", value_parser = commands::generate::parse_rate)]
+        rate: f64,
+
+        /// How long to generate events for (e.g., 30s, 10m, 1h)
+        #[arg(long, default_value = "1m", value_parser = commands::generate::parse_duration)]
+        duration: std::time::Duration,
+
+        /// Event template to synthesize
+        #[arg(long, value_enum, default_value = "json")]
+        template: commands::generate::EventTemplate,
+
+        /// Seed for reproducible event bodies
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Print the generated HEC events as JSON instead of sending them
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Restart splunkd and wait for it to come back
+    Server {
+        #[command(subcommand)]
+        command: commands::server::ServerCommand,
+    },
+
+    /// Send a raw GET or POST request to any REST endpoint
+    #[command(after_help = "Examples:
+  splunk-cli rest GET /services/server/info
+  splunk-cli rest GET '/services/data/indexes?count=5' -o json
+  splunk-cli rest GET '/services/server/info?output_mode=xml'
+  splunk-cli rest POST /services/saved/searches -d name=errors -d 'search=index=main error'
+")]
+    Rest {
+        /// HTTP method (GET or POST)
+        #[arg(value_parser = clap::builder::ValueParser::new(|s: &str| s.parse::<splunk_client::RestMethod>()))]
+        method: splunk_client::RestMethod,
+
+        /// Endpoint path, optionally with a query string (e.g. /services/server/info)
+        path: String,
+
+        /// Form field to send with POST (repeatable)
+        #[arg(short = 'd', long = "data", value_name = "KEY=VALUE", value_parser = commands::rest::parse_form_field)]
+        data: Vec<(String, String)>,
+    },
+
     /// Show search head cluster status and manage SHC configuration
     Shc {
         #[command(subcommand)]
@@ -580,6 +917,28 @@ pub enum Commands {
         count: usize,
     },
 
+    /// Launch the interactive TUI with this invocation's connection settings
+    ///
+    /// The resolved profile, credentials, and connection flags are handed to
+    /// `splunk-tui` so it connects exactly as this command would.
+    Tui {
+        /// Directory for TUI log files
+        #[arg(long)]
+        log_dir: Option<PathBuf>,
+
+        /// Disable mouse support in the TUI
+        #[arg(long)]
+        no_mouse: bool,
+
+        /// Skip the first-run tutorial
+        #[arg(long)]
+        skip_tutorial: bool,
+
+        /// Start with fresh TUI state, ignoring any persisted state
+        #[arg(long)]
+        fresh: bool,
+    },
+
     /// Generate shell completion scripts
     Completions {
         /// Shell to generate completions for
@@ -610,6 +969,64 @@ pub enum Commands {
     /// Generate manpage
     Man,
 
+    /// Apply a declarative spec of indexes, roles, users, macros, and saved searches
+    #[command(after_help = "Examples:
+  splunk-cli apply -f resources.yaml
+  splunk-cli apply -f resources.yaml --auto-approve
+  splunk-cli apply -f resources.yaml -o json
+
+Without --auto-approve the plan is only printed. Only fields set in the spec
+are managed, and resources are deleted only when marked `state: absent`.
+")]
+    Apply {
+        /// Resource spec file (YAML)
+        #[arg(short, long, value_name = "FILE")]
+        file: PathBuf,
+
+        /// Apply the plan without further confirmation
+        #[arg(long)]
+        auto_approve: bool,
+    },
+
+    /// Export indexes, roles, users, macros, and saved searches as a spec for `apply`
+    #[command(after_help = "Examples:
+  splunk-cli export --types indexes,roles,macros --out spec.yaml
+  splunk-cli export --types saved-searches > searches.yaml
+  splunk-cli --profile prod apply -f spec.yaml
+
+Passwords are never exported; add `password` or `password_env` to users that
+must be created elsewhere.
+")]
+    Export {
+        /// Resource types to export (comma-separated): indexes, roles, users, macros, saved-searches [default: all]
+        #[arg(short, long, value_delimiter = ',')]
+        types: Vec<splunk_client::workflows::declarative::ResourceKind>,
+
+        /// Write the spec to this file instead of stdout
+        #[arg(long, value_name = "FILE")]
+        out: Option<PathBuf>,
+
+        /// Also export internal indexes (names starting with `_`)
+        #[arg(long)]
+        include_internal: bool,
+    },
+
+    /// Start an interactive shell that reuses one session across commands
+    #[command(after_help = "Examples:
+  splunk-cli repl
+  splunk-cli --profile prod repl
+
+Inside the REPL:
+  splunk> \\set output json
+  splunk> indexes list
+  splunk> jobs --inspect <TAB>
+  splunk> exit
+
+Connection options (profile, URL, credentials) are read once at startup.
+History is kept in the splunk-tui config directory as `repl_history`.
+")]
+    Repl,
+
     /// Manage multi-step configuration transactions
     Transaction {
         #[command(subcommand)]
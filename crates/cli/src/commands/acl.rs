@@ -0,0 +1,199 @@
+//! Knowledge object permission (ACL) subcommands.
+//!
+//! Responsibilities:
+//! - Define the `acl show`/`acl set` subcommands shared by saved searches, macros,
+//!   lookups and dashboards.
+//! - Format an object's ACL in every output format.
+//!
+//! Does NOT handle:
+//! - Merging partial updates with the current ACL (handled by client crate).
+//! - Locating private objects (use the global `--app`/`--owner` namespace flags).
+//!
+//! Invariants:
+//! - `acl set` requires at least one change and prints the ACL as written.
+
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use splunk_client::models::{AclUpdateParams, KnowledgeObjectKind, ObjectAcl};
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+#[derive(Subcommand)]
+pub enum AclCommand {
+    /// Show the owner, sharing level and read/write roles
+    Show {
+        /// Object name
+        #[arg(value_name = "NAME")]
+        name: String,
+    },
+    /// Change the owner, sharing level or read/write roles
+    #[command(after_help = "Examples:
+  splunk-cli saved-searches acl set \"Errors\" --sharing app --read user,power
+  splunk-cli macros acl set my_macro --sharing global --read '*' --write admin
+  splunk-cli --app search --owner jane dashboards acl set ops --new-owner svc_ops
+
+Unset fields keep their current value. Read/write roles require app or global sharing.
+")]
+    Set {
+        /// Object name
+        #[arg(value_name = "NAME")]
+        name: String,
+        /// New sharing level
+        #[arg(long, value_parser = ["user", "app", "global"])]
+        sharing: Option<String>,
+        /// New owner
+        #[arg(long, value_name = "USER")]
+        new_owner: Option<String>,
+        /// Roles allowed to read (comma-separated, `*` for everyone)
+        #[arg(long, value_name = "ROLES", value_delimiter = ',')]
+        read: Option<Vec<String>>,
+        /// Roles allowed to write (comma-separated, `*` for everyone)
+        #[arg(long, value_name = "ROLES", value_delimiter = ',')]
+        write: Option<Vec<String>>,
+    },
+}
+
+pub async fn run(
+    config: splunk_config::Config,
+    kind: KnowledgeObjectKind,
+    command: AclCommand,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let acl = match command {
+        AclCommand::Show { name } => {
+            info!("Getting ACL of {} '{}'", kind, name);
+            cancellable!(client.get_object_acl(kind, &name), cancel)?
+        }
+        AclCommand::Set {
+            name,
+            sharing,
+            new_owner,
+            read,
+            write,
+        } => {
+            info!("Setting ACL of {} '{}'", kind, name);
+            let params = AclUpdateParams {
+                sharing,
+                owner: new_owner,
+                read,
+                write,
+            };
+            if params.is_empty() {
+                anyhow::bail!(
+                    "At least one change must be provided (--sharing, --new-owner, --read, or --write)"
+                );
+            }
+            let acl = cancellable!(client.set_object_acl(kind, &name, &params), cancel)
+                .with_context(|| format!("Failed to update permissions of {} '{}'", kind, name))?;
+            eprintln!("Permissions of {} '{}' updated", kind, acl.name);
+            acl
+        }
+    };
+
+    let output = format_acl(&acl, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+    Ok(())
+}
+
+/// Format an object's ACL based on the selected format.
+pub fn format_acl(acl: &ObjectAcl, format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(acl)?),
+        OutputFormat::Ndjson => Ok(format!("{}\n", serde_json::to_string(acl)?)),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(acl)?),
+        OutputFormat::Table => Ok(format_table(acl)),
+        OutputFormat::Csv => Ok(format_csv(acl)),
+        OutputFormat::Xml => Ok(format_xml(acl)),
+        OutputFormat::Markdown => Ok(format_markdown(acl)),
+    }
+}
+
+fn roles_label(roles: &[String]) -> String {
+    if roles.is_empty() {
+        "-".to_string()
+    } else {
+        roles.join(", ")
+    }
+}
+
+fn format_table(acl: &ObjectAcl) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("Name:             {}\n", acl.name));
+    out.push_str(&format!("Type:             {}\n", acl.kind));
+    out.push_str(&format!("App:              {}\n", acl.app));
+    out.push_str(&format!("Owner:            {}\n", acl.owner));
+    out.push_str(&format!("Sharing:          {}\n", acl.sharing));
+    out.push_str(&format!("Read:             {}\n", roles_label(&acl.read)));
+    out.push_str(&format!("Write:            {}\n", roles_label(&acl.write)));
+    out.push_str(&format!(
+        "Can change perms: {}\n",
+        if acl.can_change_perms { "Yes" } else { "No" }
+    ));
+    out
+}
+
+fn format_csv(acl: &ObjectAcl) -> String {
+    format!(
+        "name,type,app,owner,sharing,read,write,can_change_perms\n{},{},{},{},{},{},{},{}\n",
+        escape_csv(&acl.name),
+        escape_csv(acl.kind.label()),
+        escape_csv(&acl.app),
+        escape_csv(&acl.owner),
+        escape_csv(&acl.sharing),
+        escape_csv(&acl.read.join(";")),
+        escape_csv(&acl.write.join(";")),
+        acl.can_change_perms
+    )
+}
+
+fn format_xml(acl: &ObjectAcl) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<acl>\n");
+    xml.push_str(&format!("  <name>{}</name>\n", escape_xml(&acl.name)));
+    xml.push_str(&format!(
+        "  <type>{}</type>\n",
+        escape_xml(acl.kind.label())
+    ));
+    xml.push_str(&format!("  <app>{}</app>\n", escape_xml(&acl.app)));
+    xml.push_str(&format!("  <owner>{}</owner>\n", escape_xml(&acl.owner)));
+    xml.push_str(&format!(
+        "  <sharing>{}</sharing>\n",
+        escape_xml(&acl.sharing)
+    ));
+    for (tag, roles) in [("read", &acl.read), ("write", &acl.write)] {
+        xml.push_str(&format!("  <{}>\n", tag));
+        for role in roles {
+            xml.push_str(&format!("    <role>{}</role>\n", escape_xml(role)));
+        }
+        xml.push_str(&format!("  </{}>\n", tag));
+    }
+    xml.push_str(&format!(
+        "  <can_change_perms>{}</can_change_perms>\n",
+        acl.can_change_perms
+    ));
+    xml.push_str("</acl>");
+    xml
+}
+
+fn format_markdown(acl: &ObjectAcl) -> String {
+    let mut md = format!("# Permissions: {}\n\n", acl.name);
+    md.push_str("| Field | Value |\n");
+    md.push_str("|-------|-------|\n");
+    md.push_str(&format!("| Type | {} |\n", acl.kind));
+    md.push_str(&format!("| App | {} |\n", acl.app));
+    md.push_str(&format!("| Owner | {} |\n", acl.owner));
+    md.push_str(&format!("| Sharing | {} |\n", acl.sharing));
+    md.push_str(&format!("| Read | {} |\n", roles_label(&acl.read)));
+    md.push_str(&format!("| Write | {} |\n", roles_label(&acl.write)));
+    md.push_str(&format!(
+        "| Can change perms | {} |\n",
+        acl.can_change_perms
+    ));
+    md
+}
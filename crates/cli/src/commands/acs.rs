@@ -0,0 +1,489 @@
+//! Admin Config Service (ACS) command implementation for Splunk Cloud.
+//!
+//! Responsibilities:
+//! - List, create, and delete indexes on a Splunk Cloud stack through ACS
+//! - List, create, and delete HEC tokens on a Splunk Cloud stack through ACS
+//! - Format ACS indexes and HEC tokens in every output format
+//!
+//! Does NOT handle:
+//! - Direct ACS API calls (handled by client crate)
+//! - Resolving the stack or ACS URL (profile `acs_stack`, `SPLUNK_ACS_STACK`,
+//!   `SPLUNK_ACS_URL`, or the `*.splunkcloud.com` base URL host)
+//!
+//! Invariants:
+//! - Delete operations require confirmation unless --force is used
+//! - Token values are shown only in JSON, YAML, and NDJSON output and on creation
+
+use anyhow::Result;
+use clap::Subcommand;
+use splunk_client::models::{AcsHecToken, AcsHecTokenCreateParams, AcsIndex, AcsIndexCreateParams};
+use splunk_config::constants::DEFAULT_LIST_PAGE_SIZE;
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+#[derive(Subcommand)]
+pub enum AcsCommand {
+    /// Manage indexes on the Splunk Cloud stack
+    #[command(subcommand)]
+    Indexes(AcsIndexesCommand),
+    /// Manage HEC tokens on the Splunk Cloud stack
+    #[command(subcommand)]
+    HecTokens(AcsHecTokensCommand),
+}
+
+#[derive(Subcommand)]
+pub enum AcsIndexesCommand {
+    /// List indexes
+    List {
+        /// Maximum number of indexes to return
+        #[arg(short, long, default_value_t = DEFAULT_LIST_PAGE_SIZE)]
+        count: usize,
+        /// Offset into the index list (zero-based)
+        #[arg(long)]
+        offset: Option<usize>,
+    },
+    /// Create an index (provisioned asynchronously by ACS)
+    #[command(after_help = "Examples:
+  splunk-cli acs indexes create web --searchable-days 90
+  splunk-cli acs indexes create web_metrics --datatype metric --max-data-size-mb 512000
+")]
+    Create {
+        /// Index name (required)
+        name: String,
+        /// Index data type
+        #[arg(long, value_parser = ["event", "metric"])]
+        datatype: Option<String>,
+        /// Days data stays searchable
+        #[arg(long)]
+        searchable_days: Option<u64>,
+        /// Maximum index size in MB (0 means unlimited)
+        #[arg(long)]
+        max_data_size_mb: Option<u64>,
+    },
+    /// Delete an index
+    Delete {
+        /// Index name (required)
+        name: String,
+        /// Skip confirmation prompt
+        #[arg(short, long)]
+        force: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum AcsHecTokensCommand {
+    /// List HEC tokens
+    List,
+    /// Create a HEC token
+    #[command(after_help = "Examples:
+  splunk-cli acs hec-tokens create web-hec --default-index web --allowed-index web --allowed-index main
+")]
+    Create {
+        /// Token name (required)
+        name: String,
+        /// Index events go to when the sender does not name one
+        #[arg(long)]
+        default_index: Option<String>,
+        /// Index the token may write to (repeatable)
+        #[arg(long = "allowed-index", value_name = "INDEX")]
+        allowed_indexes: Vec<String>,
+        /// Default sourcetype for events
+        #[arg(long)]
+        source_type: Option<String>,
+        /// Require indexer acknowledgement
+        #[arg(long)]
+        use_ack: bool,
+        /// Create the token disabled
+        #[arg(long)]
+        disabled: bool,
+    },
+    /// Delete a HEC token
+    Delete {
+        /// Token name (required)
+        name: String,
+        /// Skip confirmation prompt
+        #[arg(short, long)]
+        force: bool,
+    },
+}
+
+pub async fn run(
+    config: splunk_config::Config,
+    command: AcsCommand,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    match command {
+        AcsCommand::Indexes(AcsIndexesCommand::List { count, offset }) => {
+            info!("Listing ACS indexes");
+            let indexes = cancellable!(client.acs_list_indexes(Some(count), offset), cancel)?;
+            output_result(
+                &format_acs_indexes(&indexes, format)?,
+                format,
+                output_file.as_ref(),
+            )?;
+        }
+        AcsCommand::Indexes(AcsIndexesCommand::Create {
+            name,
+            datatype,
+            searchable_days,
+            max_data_size_mb,
+        }) => {
+            info!("Creating ACS index: {}", name);
+            let params = AcsIndexCreateParams {
+                name,
+                datatype,
+                searchable_days,
+                max_data_size_mb,
+            };
+            cancellable_with!(client.acs_create_index(&params), cancel, |index| {
+                println!(
+                    "Index '{}' creation accepted; ACS provisions it asynchronously.",
+                    index.name
+                );
+                Ok(())
+            })?;
+        }
+        AcsCommand::Indexes(AcsIndexesCommand::Delete { name, force }) => {
+            if !force && !crate::interactive::confirm_delete(&name, "index")? {
+                return Ok(());
+            }
+            info!("Deleting ACS index: {}", name);
+            cancellable_with!(client.acs_delete_index(&name), cancel, |_res| {
+                println!("Index '{}' deletion accepted.", name);
+                Ok(())
+            })?;
+        }
+        AcsCommand::HecTokens(AcsHecTokensCommand::List) => {
+            info!("Listing ACS HEC tokens");
+            let tokens = cancellable!(client.acs_list_hec_tokens(), cancel)?;
+            output_result(
+                &format_acs_hec_tokens(&tokens, format)?,
+                format,
+                output_file.as_ref(),
+            )?;
+        }
+        AcsCommand::HecTokens(AcsHecTokensCommand::Create {
+            name,
+            default_index,
+            allowed_indexes,
+            source_type,
+            use_ack,
+            disabled,
+        }) => {
+            info!("Creating ACS HEC token: {}", name);
+            let params = AcsHecTokenCreateParams {
+                name,
+                default_index,
+                allowed_indexes,
+                source_type,
+                disabled,
+                use_ack,
+            };
+            cancellable_with!(client.acs_create_hec_token(&params), cancel, |token| {
+                println!("HEC token '{}' created.", token.name);
+                if let Some(value) = &token.token {
+                    println!("Token: {}", value);
+                }
+                Ok(())
+            })?;
+        }
+        AcsCommand::HecTokens(AcsHecTokensCommand::Delete { name, force }) => {
+            if !force && !crate::interactive::confirm_delete(&name, "HEC token")? {
+                return Ok(());
+            }
+            info!("Deleting ACS HEC token: {}", name);
+            cancellable_with!(client.acs_delete_hec_token(&name), cancel, |_res| {
+                println!("HEC token '{}' deletion accepted.", name);
+                Ok(())
+            })?;
+        }
+    }
+    Ok(())
+}
+
+fn opt_num(value: Option<u64>) -> String {
+    value.map_or_else(|| "-".to_string(), |v| v.to_string())
+}
+
+/// Format ACS indexes based on the selected format.
+pub fn format_acs_indexes(indexes: &[AcsIndex], format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(indexes)?),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(indexes)?),
+        OutputFormat::Ndjson => {
+            let mut ndjson = String::new();
+            for index in indexes {
+                ndjson.push_str(&serde_json::to_string(index)?);
+                ndjson.push('\n');
+            }
+            Ok(ndjson)
+        }
+        OutputFormat::Table => {
+            if indexes.is_empty() {
+                return Ok("No indexes.".to_string());
+            }
+            let mut out = format!(
+                "{:<32} {:<8} {:>10} {:>12} {:>14} {:>10}\n",
+                "NAME", "TYPE", "SEARCHABLE", "MAX SIZE MB", "EVENTS", "RAW MB"
+            );
+            out.push_str(&format!(
+                "{:<32} {:<8} {:>10} {:>12} {:>14} {:>10}\n",
+                "====", "====", "==========", "===========", "======", "======"
+            ));
+            for index in indexes {
+                out.push_str(&format!(
+                    "{:<32} {:<8} {:>10} {:>12} {:>14} {:>10}\n",
+                    index.name,
+                    index.datatype.as_deref().unwrap_or("-"),
+                    index
+                        .searchable_days
+                        .map_or_else(|| "-".to_string(), |d| format!("{}d", d)),
+                    opt_num(index.max_data_size_mb),
+                    opt_num(index.total_event_count),
+                    opt_num(index.total_raw_size_mb)
+                ));
+            }
+            Ok(out)
+        }
+        OutputFormat::Csv => {
+            let mut csv = String::from(
+                "name,datatype,searchable_days,max_data_size_mb,total_event_count,total_raw_size_mb,archival_retention_days\n",
+            );
+            for index in indexes {
+                let num = |v: Option<u64>| v.map(|v| v.to_string()).unwrap_or_default();
+                csv.push_str(&format!(
+                    "{},{},{},{},{},{},{}\n",
+                    escape_csv(&index.name),
+                    escape_csv(index.datatype.as_deref().unwrap_or("")),
+                    num(index.searchable_days),
+                    num(index.max_data_size_mb),
+                    num(index.total_event_count),
+                    num(index.total_raw_size_mb),
+                    num(index.splunk_archival_retention_days)
+                ));
+            }
+            Ok(csv)
+        }
+        OutputFormat::Xml => {
+            let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<indexes>\n");
+            for index in indexes {
+                xml.push_str(&format!("  <index name=\"{}\">\n", escape_xml(&index.name)));
+                if let Some(ref datatype) = index.datatype {
+                    xml.push_str(&format!(
+                        "    <datatype>{}</datatype>\n",
+                        escape_xml(datatype)
+                    ));
+                }
+                for (tag, value) in [
+                    ("searchable_days", index.searchable_days),
+                    ("max_data_size_mb", index.max_data_size_mb),
+                    ("total_event_count", index.total_event_count),
+                    ("total_raw_size_mb", index.total_raw_size_mb),
+                    (
+                        "archival_retention_days",
+                        index.splunk_archival_retention_days,
+                    ),
+                ] {
+                    if let Some(value) = value {
+                        xml.push_str(&format!("    <{tag}>{value}</{tag}>\n"));
+                    }
+                }
+                xml.push_str("  </index>\n");
+            }
+            xml.push_str("</indexes>");
+            Ok(xml)
+        }
+        OutputFormat::Markdown => {
+            let mut md = String::from("# Splunk Cloud Indexes\n\n");
+            if indexes.is_empty() {
+                md.push_str("_No indexes._\n");
+                return Ok(md);
+            }
+            md.push_str("| Name | Type | Searchable Days | Max Size MB | Events | Raw MB |\n");
+            md.push_str("|------|------|-----------------|-------------|--------|--------|\n");
+            for index in indexes {
+                md.push_str(&format!(
+                    "| {} | {} | {} | {} | {} | {} |\n",
+                    index.name,
+                    index.datatype.as_deref().unwrap_or("-"),
+                    opt_num(index.searchable_days),
+                    opt_num(index.max_data_size_mb),
+                    opt_num(index.total_event_count),
+                    opt_num(index.total_raw_size_mb)
+                ));
+            }
+            Ok(md)
+        }
+    }
+}
+
+/// Format ACS HEC tokens based on the selected format.
+pub fn format_acs_hec_tokens(tokens: &[AcsHecToken], format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(tokens)?),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(tokens)?),
+        OutputFormat::Ndjson => {
+            let mut ndjson = String::new();
+            for token in tokens {
+                ndjson.push_str(&serde_json::to_string(token)?);
+                ndjson.push('\n');
+            }
+            Ok(ndjson)
+        }
+        OutputFormat::Table => {
+            if tokens.is_empty() {
+                return Ok("No HEC tokens.".to_string());
+            }
+            let mut out = format!(
+                "{:<28} {:<8} {:<16} {:<24} {}\n",
+                "NAME", "STATUS", "DEFAULT INDEX", "SOURCETYPE", "ALLOWED INDEXES"
+            );
+            out.push_str(&format!(
+                "{:<28} {:<8} {:<16} {:<24} {}\n",
+                "====", "======", "=============", "==========", "==============="
+            ));
+            for token in tokens {
+                out.push_str(&format!(
+                    "{:<28} {:<8} {:<16} {:<24} {}\n",
+                    token.name,
+                    if token.disabled {
+                        "disabled"
+                    } else {
+                        "enabled"
+                    },
+                    token.default_index.as_deref().unwrap_or("-"),
+                    token.source_type.as_deref().unwrap_or("-"),
+                    token.allowed_indexes.join(",")
+                ));
+            }
+            Ok(out)
+        }
+        OutputFormat::Csv => {
+            let mut csv =
+                String::from("name,disabled,default_index,source_type,allowed_indexes,use_ack\n");
+            for token in tokens {
+                csv.push_str(&format!(
+                    "{},{},{},{},{},{}\n",
+                    escape_csv(&token.name),
+                    token.disabled,
+                    escape_csv(token.default_index.as_deref().unwrap_or("")),
+                    escape_csv(token.source_type.as_deref().unwrap_or("")),
+                    escape_csv(&token.allowed_indexes.join(";")),
+                    token.use_ack
+                ));
+            }
+            Ok(csv)
+        }
+        OutputFormat::Xml => {
+            let mut xml =
+                String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<hec_tokens>\n");
+            for token in tokens {
+                xml.push_str(&format!(
+                    "  <hec_token name=\"{}\" disabled=\"{}\" use_ack=\"{}\">\n",
+                    escape_xml(&token.name),
+                    token.disabled,
+                    token.use_ack
+                ));
+                if let Some(ref index) = token.default_index {
+                    xml.push_str(&format!(
+                        "    <default_index>{}</default_index>\n",
+                        escape_xml(index)
+                    ));
+                }
+                if let Some(ref source_type) = token.source_type {
+                    xml.push_str(&format!(
+                        "    <source_type>{}</source_type>\n",
+                        escape_xml(source_type)
+                    ));
+                }
+                for index in &token.allowed_indexes {
+                    xml.push_str(&format!(
+                        "    <allowed_index>{}</allowed_index>\n",
+                        escape_xml(index)
+                    ));
+                }
+                xml.push_str("  </hec_token>\n");
+            }
+            xml.push_str("</hec_tokens>");
+            Ok(xml)
+        }
+        OutputFormat::Markdown => {
+            let mut md = String::from("# Splunk Cloud HEC Tokens\n\n");
+            if tokens.is_empty() {
+                md.push_str("_No HEC tokens._\n");
+                return Ok(md);
+            }
+            md.push_str("| Name | Status | Default Index | Sourcetype | Allowed Indexes |\n");
+            md.push_str("|------|--------|---------------|------------|-----------------|\n");
+            for token in tokens {
+                md.push_str(&format!(
+                    "| {} | {} | {} | {} | {} |\n",
+                    token.name,
+                    if token.disabled {
+                        "disabled"
+                    } else {
+                        "enabled"
+                    },
+                    token.default_index.as_deref().unwrap_or("-"),
+                    token.source_type.as_deref().unwrap_or("-"),
+                    token.allowed_indexes.join(", ")
+                ));
+            }
+            Ok(md)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens() -> Vec<AcsHecToken> {
+        vec![AcsHecToken {
+            name: "web-hec".to_string(),
+            token: Some("secret-value".to_string()),
+            default_index: Some("web".to_string()),
+            allowed_indexes: vec!["web".to_string(), "main".to_string()],
+            source_type: None,
+            disabled: false,
+            use_ack: true,
+        }]
+    }
+
+    #[test]
+    fn test_hec_token_table_and_csv_hide_token_value() {
+        let table = format_acs_hec_tokens(&tokens(), OutputFormat::Table).unwrap();
+        assert!(table.contains("web-hec"));
+        assert!(table.contains("web,main"));
+        assert!(!table.contains("secret-value"));
+
+        let csv = format_acs_hec_tokens(&tokens(), OutputFormat::Csv).unwrap();
+        assert_eq!(csv.lines().nth(1), Some("web-hec,false,web,,web;main,true"));
+    }
+
+    #[test]
+    fn test_index_table_handles_empty_and_missing_values() {
+        assert_eq!(
+            format_acs_indexes(&[], OutputFormat::Table).unwrap(),
+            "No indexes."
+        );
+        let index = AcsIndex {
+            name: "web".to_string(),
+            datatype: None,
+            searchable_days: Some(90),
+            max_data_size_mb: None,
+            total_event_count: None,
+            total_raw_size_mb: None,
+            splunk_archival_retention_days: None,
+        };
+        let table = format_acs_indexes(&[index], OutputFormat::Table).unwrap();
+        assert!(table.contains("90d"));
+    }
+}
@@ -3,6 +3,8 @@
 //! Responsibilities:
 //! - List fired alerts with optional count limiting
 //! - Show detailed information about specific fired alerts
+//! - List alert actions and the actions a saved search triggers (`alerts actions`)
+//! - Test-fire a saved search's alert actions (`alerts test-action`)
 //! - Format output via shared formatters
 //!
 //! Does NOT handle:
@@ -22,6 +24,9 @@ use splunk_config::constants::*;
 
 use crate::formatters::{OutputFormat, get_formatter, output_result};
 
+mod actions;
+mod test_action;
+
 #[derive(Subcommand)]
 pub enum AlertsCommand {
     /// List fired alerts (triggered alert instances)
@@ -36,6 +41,39 @@ pub enum AlertsCommand {
         #[arg(value_name = "NAME")]
         name: String,
     },
+    /// List alert actions, or the actions a saved search triggers
+    #[command(after_help = "Examples:
+  splunk-cli alerts actions
+  splunk-cli alerts actions \"Errors Last Hour\"
+  splunk-cli alerts actions \"Errors Last Hour\" -o json
+")]
+    Actions {
+        /// Show the actions this saved search triggers and their parameters
+        #[arg(value_name = "SAVED_SEARCH")]
+        saved_search: Option<String>,
+        /// Maximum number of alert actions to list
+        #[arg(short, long, default_value_t = DEFAULT_LIST_PAGE_SIZE)]
+        count: usize,
+    },
+    /// Test-fire a saved search's alert actions with a synthetic result
+    #[command(after_help = "Examples:
+  splunk-cli alerts test-action \"Errors Last Hour\"
+  splunk-cli alerts test-action \"Errors Last Hour\" --action webhook
+  splunk-cli alerts test-action \"Errors Last Hour\" --dry-run
+
+Each action receives one result tagged splunk_cli_test=1; email subjects are prefixed [TEST].
+")]
+    TestAction {
+        /// Saved search whose alert actions are fired
+        #[arg(value_name = "SAVED_SEARCH")]
+        saved_search: String,
+        /// Only fire this action (repeatable); defaults to every action the search triggers
+        #[arg(long = "action", value_name = "NAME")]
+        actions: Vec<String>,
+        /// Print the test-fire searches without dispatching them
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
 
 pub async fn run(
@@ -69,6 +107,41 @@ pub async fn run(
             )
             .await
         }
+        AlertsCommand::Actions {
+            saved_search,
+            count,
+        } => {
+            actions::run_actions(
+                config,
+                saved_search.as_deref(),
+                count,
+                output_format,
+                output_file.clone(),
+                cancel,
+                no_cache,
+            )
+            .await
+        }
+        AlertsCommand::TestAction {
+            saved_search,
+            actions,
+            dry_run,
+        } => {
+            let request = splunk_client::workflows::alert_action_test::AlertActionTestRequest {
+                saved_search,
+                actions,
+                dry_run,
+            };
+            test_action::run_test_action(
+                config,
+                request,
+                output_format,
+                output_file.clone(),
+                cancel,
+                no_cache,
+            )
+            .await
+        }
     }
 }
 
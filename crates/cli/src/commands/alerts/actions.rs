@@ -0,0 +1,304 @@
+//! Alert action inspection for the alerts command.
+//!
+//! Responsibilities:
+//! - List the alert actions available on the server.
+//! - Show which actions a saved search triggers and their configured parameters.
+//! - Format both views in every output format.
+//!
+//! Does NOT handle:
+//! - Test-firing actions (see `test_action`).
+//! - Parsing `action.<name>.*` settings (lives in `splunk-client::models::alerts`).
+//!
+//! Invariants:
+//! - Row-oriented formats (CSV, NDJSON) emit one row per action, or per action
+//!   parameter for a saved search.
+
+use anyhow::Result;
+use splunk_client::{AlertAction, SavedSearchAlertActions};
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+pub(super) async fn run_actions(
+    config: splunk_config::Config,
+    saved_search: Option<&str>,
+    count: usize,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let output = match saved_search {
+        Some(name) => {
+            info!("Getting alert actions triggered by saved search: {}", name);
+            let actions = cancellable!(client.get_saved_search_alert_actions(name), cancel)?;
+            format_saved_search_actions(&actions, format)?
+        }
+        None => {
+            info!("Listing alert actions (count: {})", count);
+            let actions = cancellable!(client.list_alert_actions(Some(count), None), cancel)?;
+            format_alert_actions(&actions, format)?
+        }
+    };
+    output_result(&output, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+/// Format the server's alert actions based on the selected format.
+pub fn format_alert_actions(actions: &[AlertAction], format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(actions)?),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(actions)?),
+        OutputFormat::Ndjson => {
+            let mut ndjson = String::new();
+            for action in actions {
+                ndjson.push_str(&serde_json::to_string(action)?);
+                ndjson.push('\n');
+            }
+            Ok(ndjson)
+        }
+        OutputFormat::Table => {
+            if actions.is_empty() {
+                return Ok("No alert actions found.".to_string());
+            }
+            let mut out = format!(
+                "{:<24} {:<28} {:<7} {:<9} {}\n",
+                "NAME", "LABEL", "CUSTOM", "DISABLED", "DESCRIPTION"
+            );
+            out.push_str(&format!(
+                "{:<24} {:<28} {:<7} {:<9} {}\n",
+                "====", "=====", "======", "========", "==========="
+            ));
+            for action in actions {
+                out.push_str(&format!(
+                    "{:<24} {:<28} {:<7} {:<9} {}\n",
+                    action.name,
+                    action.label.as_deref().unwrap_or("-"),
+                    yes_no(action.is_custom),
+                    yes_no(action.disabled),
+                    action.description.as_deref().unwrap_or("")
+                ));
+            }
+            Ok(out)
+        }
+        OutputFormat::Csv => {
+            let mut csv =
+                String::from("name,label,is_custom,disabled,payload_format,description\n");
+            for action in actions {
+                csv.push_str(&format!(
+                    "{},{},{},{},{},{}\n",
+                    escape_csv(&action.name),
+                    escape_csv(action.label.as_deref().unwrap_or("")),
+                    action.is_custom,
+                    action.disabled,
+                    escape_csv(action.payload_format.as_deref().unwrap_or("")),
+                    escape_csv(action.description.as_deref().unwrap_or(""))
+                ));
+            }
+            Ok(csv)
+        }
+        OutputFormat::Xml => {
+            let mut xml =
+                String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<alert_actions>\n");
+            for action in actions {
+                xml.push_str(&format!(
+                    "  <alert_action is_custom=\"{}\" disabled=\"{}\">\n",
+                    action.is_custom, action.disabled
+                ));
+                xml.push_str(&format!("    <name>{}</name>\n", escape_xml(&action.name)));
+                if let Some(ref label) = action.label {
+                    xml.push_str(&format!("    <label>{}</label>\n", escape_xml(label)));
+                }
+                if let Some(ref description) = action.description {
+                    xml.push_str(&format!(
+                        "    <description>{}</description>\n",
+                        escape_xml(description)
+                    ));
+                }
+                xml.push_str("  </alert_action>\n");
+            }
+            xml.push_str("</alert_actions>");
+            Ok(xml)
+        }
+        OutputFormat::Markdown => {
+            let mut md = String::from("# Alert Actions\n\n");
+            if actions.is_empty() {
+                md.push_str("_No alert actions found._\n");
+                return Ok(md);
+            }
+            md.push_str("| Name | Label | Custom | Disabled | Description |\n");
+            md.push_str("|------|-------|--------|----------|-------------|\n");
+            for action in actions {
+                md.push_str(&format!(
+                    "| {} | {} | {} | {} | {} |\n",
+                    action.name,
+                    action.label.as_deref().unwrap_or(""),
+                    yes_no(action.is_custom),
+                    yes_no(action.disabled),
+                    action.description.as_deref().unwrap_or("")
+                ));
+            }
+            Ok(md)
+        }
+    }
+}
+
+/// Format the actions a saved search triggers based on the selected format.
+pub fn format_saved_search_actions(
+    actions: &SavedSearchAlertActions,
+    format: OutputFormat,
+) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(actions)?),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(actions)?),
+        OutputFormat::Ndjson => {
+            let mut ndjson = String::new();
+            for action in &actions.actions {
+                ndjson.push_str(&serde_json::to_string(action)?);
+                ndjson.push('\n');
+            }
+            Ok(ndjson)
+        }
+        OutputFormat::Table => {
+            let mut out = format!(
+                "Alert actions for saved search: {}\n\n",
+                actions.saved_search
+            );
+            if actions.actions.is_empty() {
+                out.push_str("No alert actions configured.\n");
+                return Ok(out);
+            }
+            for action in &actions.actions {
+                out.push_str(&format!("{}\n", action.name));
+                if action.params.is_empty() {
+                    out.push_str("  (no parameters)\n");
+                }
+                for (key, value) in &action.params {
+                    out.push_str(&format!("  {:<28} {}\n", key, value));
+                }
+            }
+            Ok(out)
+        }
+        OutputFormat::Csv => {
+            let mut csv = String::from("saved_search,action,param,value\n");
+            for action in &actions.actions {
+                if action.params.is_empty() {
+                    csv.push_str(&format!(
+                        "{},{},,\n",
+                        escape_csv(&actions.saved_search),
+                        escape_csv(&action.name)
+                    ));
+                }
+                for (key, value) in &action.params {
+                    csv.push_str(&format!(
+                        "{},{},{},{}\n",
+                        escape_csv(&actions.saved_search),
+                        escape_csv(&action.name),
+                        escape_csv(key),
+                        escape_csv(value)
+                    ));
+                }
+            }
+            Ok(csv)
+        }
+        OutputFormat::Xml => {
+            let mut xml = format!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<saved_search_alert_actions saved_search=\"{}\">\n",
+                escape_xml(&actions.saved_search)
+            );
+            for action in &actions.actions {
+                xml.push_str(&format!(
+                    "  <action name=\"{}\">\n",
+                    escape_xml(&action.name)
+                ));
+                for (key, value) in &action.params {
+                    xml.push_str(&format!(
+                        "    <param name=\"{}\">{}</param>\n",
+                        escape_xml(key),
+                        escape_xml(value)
+                    ));
+                }
+                xml.push_str("  </action>\n");
+            }
+            xml.push_str("</saved_search_alert_actions>");
+            Ok(xml)
+        }
+        OutputFormat::Markdown => {
+            let mut md = format!("# Alert Actions: {}\n\n", actions.saved_search);
+            if actions.actions.is_empty() {
+                md.push_str("_No alert actions configured._\n");
+                return Ok(md);
+            }
+            for action in &actions.actions {
+                md.push_str(&format!("## {}\n\n", action.name));
+                if action.params.is_empty() {
+                    md.push_str("_No parameters._\n\n");
+                    continue;
+                }
+                md.push_str("| Parameter | Value |\n");
+                md.push_str("|-----------|-------|\n");
+                for (key, value) in &action.params {
+                    md.push_str(&format!("| {} | {} |\n", key, value));
+                }
+                md.push('\n');
+            }
+            Ok(md)
+        }
+    }
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value { "Yes" } else { "No" }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use splunk_client::models::TriggeredAlertAction;
+
+    fn saved_search_actions() -> SavedSearchAlertActions {
+        SavedSearchAlertActions {
+            saved_search: "Errors".to_string(),
+            actions: vec![
+                TriggeredAlertAction {
+                    name: "email".to_string(),
+                    params: [("to".to_string(), "oncall@example.com".to_string())]
+                        .into_iter()
+                        .collect(),
+                },
+                TriggeredAlertAction {
+                    name: "webhook".to_string(),
+                    params: Default::default(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_saved_search_actions_table_lists_params() {
+        let output =
+            format_saved_search_actions(&saved_search_actions(), OutputFormat::Table).unwrap();
+        assert!(output.contains("email\n  to"));
+        assert!(output.contains("oncall@example.com"));
+        assert!(output.contains("webhook\n  (no parameters)"));
+    }
+
+    #[test]
+    fn test_saved_search_actions_csv_has_row_per_param() {
+        let output =
+            format_saved_search_actions(&saved_search_actions(), OutputFormat::Csv).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "saved_search,action,param,value",
+                "Errors,email,to,oncall@example.com",
+                "Errors,webhook,,"
+            ]
+        );
+    }
+}
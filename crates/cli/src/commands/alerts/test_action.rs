@@ -0,0 +1,227 @@
+//! Alert action test-fire for the alerts command.
+//!
+//! Responsibilities:
+//! - Run the shared test-fire workflow for a saved search's alert actions.
+//! - Format the per-action outcomes in every output format.
+//!
+//! Does NOT handle:
+//! - Building or dispatching test-fire searches (lives in
+//!   `splunk-client::workflows::alert_action_test`).
+//!
+//! Invariants:
+//! - `--dry-run` prints the test-fire searches without dispatching them.
+//! - The command fails when any action fails to fire, after printing the report.
+
+use anyhow::Result;
+use splunk_client::workflows::alert_action_test::{
+    AlertActionTestReport, AlertActionTestRequest, test_alert_actions,
+};
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+pub(super) async fn run_test_action(
+    config: splunk_config::Config,
+    request: AlertActionTestRequest,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!(
+        "Test-firing alert actions for saved search '{}' (dry_run: {})",
+        request.saved_search, request.dry_run
+    );
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let report = cancellable!(test_alert_actions(&client, &request, Some(cancel)), cancel)?;
+
+    let output = format_test_action_report(&report, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    let failed = report.failed_count();
+    if failed > 0 {
+        anyhow::bail!(
+            "{} of {} alert action(s) failed to fire; check the job messages above",
+            failed,
+            report.items.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// Format a test-fire report based on the selected format.
+pub fn format_test_action_report(
+    report: &AlertActionTestReport,
+    format: OutputFormat,
+) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(report)?),
+        OutputFormat::Table => Ok(format_table(report)),
+        OutputFormat::Csv => Ok(format_csv(report)),
+        OutputFormat::Xml => Ok(format_xml(report)),
+        OutputFormat::Ndjson => format_ndjson(report),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(report)?),
+        OutputFormat::Markdown => Ok(format_markdown(report)),
+    }
+}
+
+fn mode_label(report: &AlertActionTestReport) -> &'static str {
+    if report.dry_run { "dry run" } else { "fired" }
+}
+
+fn format_table(report: &AlertActionTestReport) -> String {
+    let mut out = format!(
+        "Test alert actions: {} ({})\n\n",
+        report.saved_search,
+        mode_label(report)
+    );
+
+    out.push_str(&format!("{:<20} {:<12} {}\n", "ACTION", "STATUS", "SID"));
+    out.push_str(&format!("{:<20} {:<12} {}\n", "======", "======", "==="));
+    for item in &report.items {
+        out.push_str(&format!(
+            "{:<20} {:<12} {}\n",
+            item.action,
+            item.status.slug(),
+            item.sid.as_deref().unwrap_or("-")
+        ));
+        for message in &item.messages {
+            out.push_str(&format!("  {}\n", message));
+        }
+        if report.dry_run
+            && let Some(ref search) = item.search
+        {
+            out.push_str(&format!("  {}\n", search));
+        }
+    }
+
+    out
+}
+
+fn format_csv(report: &AlertActionTestReport) -> String {
+    let mut csv = String::from("saved_search,action,status,sid,search,messages\n");
+    for item in &report.items {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            escape_csv(&report.saved_search),
+            escape_csv(&item.action),
+            item.status.slug(),
+            escape_csv(item.sid.as_deref().unwrap_or("")),
+            escape_csv(item.search.as_deref().unwrap_or("")),
+            escape_csv(&item.messages.join("; "))
+        ));
+    }
+    csv
+}
+
+fn format_xml(report: &AlertActionTestReport) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<alert_action_test>\n");
+    xml.push_str(&format!(
+        "  <saved_search>{}</saved_search>\n",
+        escape_xml(&report.saved_search)
+    ));
+    xml.push_str(&format!("  <dry_run>{}</dry_run>\n", report.dry_run));
+    xml.push_str("  <items>\n");
+    for item in &report.items {
+        xml.push_str("    <item>\n");
+        xml.push_str(&format!(
+            "      <action>{}</action>\n",
+            escape_xml(&item.action)
+        ));
+        xml.push_str(&format!("      <status>{}</status>\n", item.status.slug()));
+        if let Some(ref sid) = item.sid {
+            xml.push_str(&format!("      <sid>{}</sid>\n", escape_xml(sid)));
+        }
+        if let Some(ref search) = item.search {
+            xml.push_str(&format!("      <search>{}</search>\n", escape_xml(search)));
+        }
+        for message in &item.messages {
+            xml.push_str(&format!(
+                "      <message>{}</message>\n",
+                escape_xml(message)
+            ));
+        }
+        xml.push_str("    </item>\n");
+    }
+    xml.push_str("  </items>\n");
+    xml.push_str("</alert_action_test>");
+    xml
+}
+
+fn format_ndjson(report: &AlertActionTestReport) -> Result<String> {
+    let mut ndjson = String::new();
+    for item in &report.items {
+        ndjson.push_str(&serde_json::to_string(item)?);
+        ndjson.push('\n');
+    }
+    Ok(ndjson)
+}
+
+fn format_markdown(report: &AlertActionTestReport) -> String {
+    let mut md = String::from("# Alert Action Test\n\n");
+    md.push_str(&format!("- **Saved search**: {}\n", report.saved_search));
+    md.push_str(&format!("- **Mode**: {}\n\n", mode_label(report)));
+
+    md.push_str("| Action | Status | SID | Messages |\n");
+    md.push_str("|--------|--------|-----|----------|\n");
+    for item in &report.items {
+        md.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            item.action,
+            item.status.slug(),
+            item.sid.as_deref().unwrap_or("-"),
+            item.messages.join("<br>")
+        ));
+    }
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use splunk_client::workflows::alert_action_test::{AlertActionTestItem, AlertActionTestStatus};
+
+    fn report() -> AlertActionTestReport {
+        AlertActionTestReport {
+            saved_search: "Errors".to_string(),
+            dry_run: false,
+            items: vec![
+                AlertActionTestItem {
+                    action: "webhook".to_string(),
+                    status: AlertActionTestStatus::Failed,
+                    search: Some("| makeresults | sendalert webhook".to_string()),
+                    sid: Some("1700000000.1".to_string()),
+                    messages: vec!["ERROR: Alert script returned error code 2.".to_string()],
+                },
+                AlertActionTestItem {
+                    action: "script".to_string(),
+                    status: AlertActionTestStatus::Unsupported,
+                    search: None,
+                    sid: None,
+                    messages: vec!["legacy scripted alerts cannot be invoked".to_string()],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_table_shows_status_and_messages() {
+        let output = format_test_action_report(&report(), OutputFormat::Table).unwrap();
+        assert!(output.contains("webhook              failed       1700000000.1"));
+        assert!(output.contains("  ERROR: Alert script returned error code 2."));
+        assert!(output.contains("script               unsupported  -"));
+        assert!(!output.contains("| makeresults"));
+    }
+
+    #[test]
+    fn test_csv_has_one_row_per_action() {
+        let output = format_test_action_report(&report(), OutputFormat::Csv).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[2].starts_with("Errors,script,unsupported,,,"));
+    }
+}
@@ -0,0 +1,333 @@
+//! Declarative apply command (`splunk-cli apply -f resources.yaml`).
+//!
+//! Responsibilities:
+//! - Load a resource spec, plan it against the live server, and print the plan.
+//! - With `--auto-approve`, commit the plan as one transaction.
+//! - Format plans and apply results in every output format.
+//!
+//! Does NOT handle:
+//! - Spec parsing or diffing (lives in `splunk-client::workflows::declarative`).
+//! - Executing or rolling back operations (lives in `splunk-client::transaction`).
+//!
+//! Invariants:
+//! - Nothing is changed on the server without `--auto-approve`.
+//! - Applied plans are archived in the transaction log as committed or failed.
+//! - Passwords never appear in plan output.
+//! - Cancellation is honored while planning, not mid-commit.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use splunk_client::workflows::declarative::{
+    ApplyPlan, PlanAction, PlannedChange, ResourceSpec, plan_apply,
+};
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+/// A plan and whether it was applied.
+#[derive(Debug, Serialize)]
+pub struct ApplyReport {
+    pub spec: String,
+    pub applied: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_id: Option<String>,
+    pub create: usize,
+    pub update: usize,
+    pub delete: usize,
+    pub changes: Vec<PlannedChange>,
+}
+
+impl ApplyReport {
+    fn new(spec: &Path, plan: ApplyPlan) -> Self {
+        Self {
+            spec: spec.display().to_string(),
+            applied: false,
+            transaction_id: None,
+            create: plan.count(PlanAction::Create),
+            update: plan.count(PlanAction::Update),
+            delete: plan.count(PlanAction::Delete),
+            changes: plan.changes,
+        }
+    }
+}
+
+/// Load a resource spec file.
+pub fn load_spec(path: &Path) -> Result<ResourceSpec> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read spec {}", path.display()))?;
+    ResourceSpec::from_yaml(&text).with_context(|| format!("Invalid spec {}", path.display()))
+}
+
+pub async fn run(
+    config: splunk_config::Config,
+    file: PathBuf,
+    auto_approve: bool,
+    output_format: &str,
+    output_file: Option<PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Planning resource spec {}", file.display());
+
+    let format = OutputFormat::from_str(output_format)?;
+    let spec = load_spec(&file)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let plan = cancellable!(plan_apply(&client, &spec, Some(cancel)), cancel)?;
+    let transaction = plan.to_transaction();
+    let mut report = ApplyReport::new(&file, plan);
+
+    if auto_approve && !report.changes.is_empty() {
+        let manager = crate::commands::get_transaction_manager()?;
+        manager.validate(&client, &transaction).await?;
+        info!(
+            "Applying {} changes as transaction {}",
+            report.changes.len(),
+            transaction.id
+        );
+        // Not cancellable: interrupting a commit would skip its rollback.
+        if let Err(e) = manager.commit(&client, &transaction).await {
+            manager.archive(&transaction, "failed").await?;
+            anyhow::bail!("Apply failed and was rolled back: {}", e);
+        }
+        manager.archive(&transaction, "committed").await?;
+        report.applied = true;
+        report.transaction_id = Some(transaction.id);
+    }
+
+    let output = format_apply_report(&report, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+/// Format an apply report based on the selected format.
+pub fn format_apply_report(report: &ApplyReport, format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(report)?),
+        OutputFormat::Table => Ok(format_table(report)),
+        OutputFormat::Csv => Ok(format_csv(report)),
+        OutputFormat::Xml => Ok(format_xml(report)),
+        OutputFormat::Ndjson => format_ndjson(report),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(report)?),
+        OutputFormat::Markdown => Ok(format_markdown(report)),
+    }
+}
+
+fn action_name(action: PlanAction) -> &'static str {
+    match action {
+        PlanAction::Create => "create",
+        PlanAction::Update => "update",
+        PlanAction::Delete => "delete",
+    }
+}
+
+fn totals_line(report: &ApplyReport) -> String {
+    format!(
+        "{} to create, {} to update, {} to delete",
+        report.create, report.update, report.delete
+    )
+}
+
+/// `current -> desired` for updates, `= desired` for creates.
+fn describe_field(current: Option<&str>, desired: Option<&str>) -> String {
+    match current {
+        Some(current) => format!("{} -> {}", current, desired.unwrap_or("")),
+        None => format!("= {}", desired.unwrap_or("")),
+    }
+}
+
+fn format_table(report: &ApplyReport) -> String {
+    if report.changes.is_empty() {
+        return format!("No changes. The server matches {}.\n", report.spec);
+    }
+
+    let mut out = format!("Plan: {}.\n\n", totals_line(report));
+    for change in &report.changes {
+        out.push_str(&format!(
+            "  {} {} {}\n",
+            change.action.marker(),
+            change.kind,
+            change.name
+        ));
+        for field in &change.fields {
+            out.push_str(&format!(
+                "      {} {}\n",
+                field.field,
+                describe_field(field.current.as_deref(), field.desired.as_deref())
+            ));
+        }
+    }
+
+    match &report.transaction_id {
+        Some(id) => out.push_str(&format!(
+            "\nApplied {} changes (transaction {}).\n",
+            report.changes.len(),
+            id
+        )),
+        None => out.push_str("\nRun with --auto-approve to apply these changes.\n"),
+    }
+    out
+}
+
+fn format_csv(report: &ApplyReport) -> String {
+    let mut csv = String::from("action,kind,name,field,current,desired\n");
+    for change in &report.changes {
+        let prefix = format!(
+            "{},{},{}",
+            action_name(change.action),
+            escape_csv(&change.kind.to_string()),
+            escape_csv(&change.name)
+        );
+        if change.fields.is_empty() {
+            csv.push_str(&format!("{},,,\n", prefix));
+        }
+        for field in &change.fields {
+            csv.push_str(&format!(
+                "{},{},{},{}\n",
+                prefix,
+                escape_csv(&field.field),
+                escape_csv(field.current.as_deref().unwrap_or("")),
+                escape_csv(field.desired.as_deref().unwrap_or(""))
+            ));
+        }
+    }
+    csv
+}
+
+fn format_xml(report: &ApplyReport) -> String {
+    let mut xml = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<apply spec=\"{}\" applied=\"{}\"",
+        escape_xml(&report.spec),
+        report.applied
+    );
+    if let Some(id) = &report.transaction_id {
+        xml.push_str(&format!(" transaction_id=\"{}\"", escape_xml(id)));
+    }
+    xml.push_str(">\n");
+    for change in &report.changes {
+        xml.push_str(&format!(
+            "  <change action=\"{}\" kind=\"{}\" name=\"{}\">\n",
+            action_name(change.action),
+            escape_xml(&change.kind.to_string()),
+            escape_xml(&change.name)
+        ));
+        for field in &change.fields {
+            xml.push_str(&format!(
+                "    <field name=\"{}\">\n",
+                escape_xml(&field.field)
+            ));
+            if let Some(current) = &field.current {
+                xml.push_str(&format!(
+                    "      <current>{}</current>\n",
+                    escape_xml(current)
+                ));
+            }
+            if let Some(desired) = &field.desired {
+                xml.push_str(&format!(
+                    "      <desired>{}</desired>\n",
+                    escape_xml(desired)
+                ));
+            }
+            xml.push_str("    </field>\n");
+        }
+        xml.push_str("  </change>\n");
+    }
+    xml.push_str("</apply>");
+    xml
+}
+
+fn format_ndjson(report: &ApplyReport) -> Result<String> {
+    let mut ndjson = String::new();
+    for change in &report.changes {
+        ndjson.push_str(&serde_json::to_string(change)?);
+        ndjson.push('\n');
+    }
+    Ok(ndjson)
+}
+
+fn format_markdown(report: &ApplyReport) -> String {
+    let mut md = format!("# Apply Plan: `{}`\n\n", report.spec);
+    if report.changes.is_empty() {
+        md.push_str("_No changes._\n");
+        return md;
+    }
+    md.push_str(&format!("{}.\n\n", totals_line(report)));
+    md.push_str("| Action | Kind | Name | Field | Current | Desired |\n");
+    md.push_str("|--------|------|------|-------|---------|---------|\n");
+    for change in &report.changes {
+        let fields: Vec<(&str, &str, &str)> = if change.fields.is_empty() {
+            vec![("", "", "")]
+        } else {
+            change
+                .fields
+                .iter()
+                .map(|f| {
+                    (
+                        f.field.as_str(),
+                        f.current.as_deref().unwrap_or(""),
+                        f.desired.as_deref().unwrap_or(""),
+                    )
+                })
+                .collect()
+        };
+        for (field, current, desired) in fields {
+            md.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} |\n",
+                action_name(change.action),
+                change.kind,
+                change.name,
+                field,
+                current.replace('|', "\\|"),
+                desired.replace('|', "\\|")
+            ));
+        }
+    }
+    if let Some(id) = &report.transaction_id {
+        md.push_str(&format!("\nApplied as transaction `{}`.\n", id));
+    }
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use splunk_client::workflows::declarative::{LiveState, build_plan};
+
+    fn report() -> ApplyReport {
+        let spec = ResourceSpec::from_yaml(
+            "indexes:\n  - name: web\n    max_data_size_mb: 1000\nroles:\n  - name: old\n    state: absent\n",
+        )
+        .unwrap();
+        let live = LiveState {
+            roles: vec![serde_json::from_value(serde_json::json!({"name": "old"})).unwrap()],
+            ..LiveState::default()
+        };
+        ApplyReport::new(
+            Path::new("resources.yaml"),
+            build_plan(&spec, &live).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_table_shows_plan_and_approval_hint() {
+        let output = format_apply_report(&report(), OutputFormat::Table).unwrap();
+        assert!(output.starts_with("Plan: 1 to create, 0 to update, 1 to delete.\n"));
+        assert!(output.contains("  + index web\n      max_data_size_mb = 1000\n"));
+        assert!(output.contains("  - role old\n"));
+        assert!(output.ends_with("Run with --auto-approve to apply these changes.\n"));
+    }
+
+    #[test]
+    fn test_csv_has_one_row_per_field() {
+        let output = format_apply_report(&report(), OutputFormat::Csv).unwrap();
+        assert_eq!(
+            output,
+            "action,kind,name,field,current,desired\n\
+             create,index,web,max_data_size_mb,,1000\n\
+             delete,role,old,,,\n"
+        );
+    }
+}
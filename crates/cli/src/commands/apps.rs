@@ -3,8 +3,9 @@
 //! Responsibilities:
 //! - List installed apps with optional count limiting
 //! - Show detailed information about specific apps
+//! - List the knowledge objects an app ships (saved searches, dashboards, lookups, macros, event types)
 //! - Enable/disable apps by name
-//! - Install apps from .spl package files
+//! - Install apps from .spl package files or Splunkbase (optionally version-pinned)
 //! - Remove (uninstall) apps with confirmation
 //! - Format output via shared formatters
 //!
@@ -24,8 +25,14 @@ use tracing::info;
 
 use splunk_config::constants::*;
 
+use splunk_client::workflows::splunkbase::{
+    AppPackageSource, SplunkbaseConfig, SplunkbaseCredentials, install_app_package,
+};
+
 use crate::formatters::{OutputFormat, get_formatter, output_result};
 
+mod contents;
+
 #[derive(Subcommand)]
 pub enum AppsCommand {
     /// List installed apps
@@ -33,6 +40,9 @@ pub enum AppsCommand {
         /// Maximum number of apps to list
         #[arg(short, long, default_value_t = DEFAULT_LIST_PAGE_SIZE)]
         count: usize,
+        /// Fetch every page instead of stopping at --count
+        #[arg(long, conflicts_with = "count")]
+        all: bool,
     },
     /// Show detailed information about an app
     Info {
@@ -40,6 +50,12 @@ pub enum AppsCommand {
         #[arg(value_name = "APP_NAME")]
         app_name: String,
     },
+    /// List the saved searches, dashboards, lookups, macros and event types an app ships
+    Contents {
+        /// App name (e.g., 'search', 'Splunk_TA_nix')
+        #[arg(value_name = "APP_NAME")]
+        app_name: String,
+    },
     /// Enable an app by name
     Enable {
         /// App name to enable
@@ -52,11 +68,56 @@ pub enum AppsCommand {
         #[arg(value_name = "APP_NAME")]
         app_name: String,
     },
-    /// Install an app from a .spl file
+    /// Install an app from a .spl file or from Splunkbase
+    #[command(after_help = "Examples:
+  splunk-cli apps install ./my_app.spl
+  splunk-cli apps install --splunkbase 2890
+  splunk-cli apps install --splunkbase 2890 --version 8.2.0
+
+Splunkbase downloads need SPLUNKBASE_TOKEN or SPLUNKBASE_USERNAME/SPLUNKBASE_PASSWORD.
+")]
     Install {
         /// Path to the .spl package file
-        #[arg(value_name = "FILE_PATH")]
-        file_path: std::path::PathBuf,
+        #[arg(
+            value_name = "FILE_PATH",
+            required_unless_present = "splunkbase",
+            conflicts_with = "splunkbase"
+        )]
+        file_path: Option<std::path::PathBuf>,
+
+        /// Download the app with this Splunkbase ID instead of reading a file
+        #[arg(long, value_name = "APP_ID")]
+        splunkbase: Option<u64>,
+
+        /// Splunkbase release to install (defaults to the latest)
+        #[arg(
+            long = "version",
+            value_name = "VERSION",
+            requires = "splunkbase",
+            conflicts_with = "file_path"
+        )]
+        release: Option<String>,
+
+        /// Splunkbase session token
+        #[arg(long, env = "SPLUNKBASE_TOKEN", hide_env_values = true)]
+        splunkbase_token: Option<String>,
+
+        /// splunk.com username for Splunkbase login
+        #[arg(long, env = "SPLUNKBASE_USERNAME", hide_env_values = true)]
+        splunkbase_username: Option<String>,
+
+        /// splunk.com password for Splunkbase login
+        #[arg(long, env = "SPLUNKBASE_PASSWORD", hide_env_values = true)]
+        splunkbase_password: Option<String>,
+
+        /// Splunkbase base URL
+        #[arg(
+            long,
+            env = "SPLUNKBASE_URL",
+            hide = true,
+            default_value = splunk_client::workflows::splunkbase::DEFAULT_SPLUNKBASE_URL
+        )]
+        splunkbase_url: String,
     },
     /// Remove (uninstall) an app by name
     Remove {
@@ -79,10 +140,11 @@ pub async fn run(
     no_cache: bool,
 ) -> Result<()> {
     match command {
-        AppsCommand::List { count } => {
+        AppsCommand::List { count, all } => {
             run_list(
                 config,
                 count,
+                all,
                 output_format,
                 output_file.clone(),
                 cancel,
@@ -101,12 +163,52 @@ pub async fn run(
             )
             .await
         }
+        AppsCommand::Contents { app_name } => {
+            contents::run_contents(
+                config,
+                &app_name,
+                output_format,
+                output_file.clone(),
+                cancel,
+                no_cache,
+            )
+            .await
+        }
         AppsCommand::Enable { app_name } => run_enable(config, &app_name, cancel, no_cache).await,
         AppsCommand::Disable { app_name } => run_disable(config, &app_name, cancel, no_cache).await,
-        AppsCommand::Install { file_path } => {
+        AppsCommand::Install {
+            file_path,
+            splunkbase,
+            release,
+            splunkbase_token,
+            splunkbase_username,
+            splunkbase_password,
+            splunkbase_url,
+        } => {
+            let source = match (file_path, splunkbase) {
+                (Some(path), _) => AppPackageSource::File(path),
+                (None, Some(app_id)) => AppPackageSource::Splunkbase {
+                    app_id,
+                    version: release,
+                },
+                (None, None) => unreachable!("clap requires FILE_PATH or --splunkbase"),
+            };
+            let credentials = match (splunkbase_token, splunkbase_username, splunkbase_password) {
+                (Some(token), _, _) => Some(SplunkbaseCredentials::Token(token.into())),
+                (None, Some(username), Some(password)) => Some(SplunkbaseCredentials::Login {
+                    username,
+                    password: password.into(),
+                }),
+                _ => None,
+            };
+            let splunkbase = SplunkbaseConfig {
+                base_url: splunkbase_url,
+                credentials,
+            };
             run_install(
                 config,
-                &file_path,
+                &source,
+                &splunkbase,
                 output_format,
                 output_file.clone(),
                 cancel,
@@ -123,6 +225,7 @@ pub async fn run(
 async fn run_list(
     config: splunk_config::Config,
     count: usize,
+    all: bool,
     output_format: &str,
     output_file: Option<std::path::PathBuf>,
     cancel: &crate::cancellation::CancellationToken,
@@ -132,7 +235,16 @@ async fn run_list(
 
     let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
 
-    let apps = cancellable!(client.list_apps(Some(count), None), cancel)?;
+    let apps = if all {
+        cancellable!(
+            crate::commands::fetch_all_pages(
+                |count, offset| client.list_apps(Some(count), Some(offset))
+            ),
+            cancel
+        )?
+    } else {
+        cancellable!(client.list_apps(Some(count), None), cancel)?
+    };
 
     let format = OutputFormat::from_str(output_format)?;
     let formatter = get_formatter(format);
@@ -203,26 +315,29 @@ async fn run_disable(
 
 async fn run_install(
     config: splunk_config::Config,
-    file_path: &std::path::Path,
+    source: &AppPackageSource,
+    splunkbase: &SplunkbaseConfig,
     output_format: &str,
     output_file: Option<std::path::PathBuf>,
     cancel: &crate::cancellation::CancellationToken,
     no_cache: bool,
 ) -> Result<()> {
     // Validate file exists before attempting upload
-    if !file_path.exists() {
+    if let AppPackageSource::File(file_path) = source
+        && !file_path.exists()
+    {
         return Err(anyhow::anyhow!(
             "App package file not found: {}",
             file_path.display()
         ));
     }
 
-    info!("Installing app from: {}", file_path.display());
+    info!("Installing app from: {}", source);
 
     let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
 
-    let app = cancellable!(client.install_app(file_path), cancel)
-        .with_context(|| format!("Failed to install app from '{}'", file_path.display()))?;
+    let app = cancellable!(install_app_package(&client, source, splunkbase), cancel)
+        .with_context(|| format!("Failed to install app from {}", source))?;
 
     let format = OutputFormat::from_str(output_format)?;
     let formatter = get_formatter(format);
@@ -0,0 +1,222 @@
+//! Per-app object inventory for the apps command.
+//!
+//! Responsibilities:
+//! - Run the shared app contents workflow.
+//! - Format the inventory, grouped by object kind, in every output format.
+//!
+//! Does NOT handle:
+//! - Namespace queries (lives in `splunk-client::workflows::app_contents`).
+//!
+//! Invariants:
+//! - Row-oriented formats (CSV, NDJSON) emit one row per object.
+
+use anyhow::{Context, Result};
+use splunk_client::workflows::app_contents::{AppContents, AppObjectKind, fetch_app_contents};
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+/// Longest object detail shown in the table view.
+const MAX_DETAIL_CHARS: usize = 80;
+
+pub(super) async fn run_contents(
+    config: splunk_config::Config,
+    app_name: &str,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Listing objects shipped by app: {}", app_name);
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let contents = cancellable!(fetch_app_contents(&client, app_name), cancel)
+        .with_context(|| format!("Failed to list contents of app '{}'", app_name))?;
+
+    let output = format_app_contents(&contents, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+/// Format an app inventory based on the selected format.
+pub fn format_app_contents(contents: &AppContents, format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(contents)?),
+        OutputFormat::Table => Ok(format_table(contents)),
+        OutputFormat::Csv => Ok(format_csv(contents)),
+        OutputFormat::Xml => Ok(format_xml(contents)),
+        OutputFormat::Ndjson => format_ndjson(contents),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(contents)?),
+        OutputFormat::Markdown => Ok(format_markdown(contents)),
+    }
+}
+
+fn truncate(detail: &str) -> String {
+    let detail = detail.trim().replace('\n', " ");
+    if detail.chars().count() <= MAX_DETAIL_CHARS {
+        return detail;
+    }
+    let truncated: String = detail.chars().take(MAX_DETAIL_CHARS - 3).collect();
+    format!("{}...", truncated)
+}
+
+fn format_table(contents: &AppContents) -> String {
+    let total = contents.objects.len();
+    let mut out = format!(
+        "Contents of app '{}' ({} {})\n",
+        contents.app,
+        total,
+        if total == 1 { "object" } else { "objects" }
+    );
+    for kind in AppObjectKind::ALL {
+        out.push_str(&format!("\n{} ({}):\n", kind.title(), contents.count(kind)));
+        let objects: Vec<_> = contents.of_kind(kind).collect();
+        if objects.is_empty() {
+            out.push_str("  (none)\n");
+            continue;
+        }
+        let width = objects.iter().map(|o| o.name.len()).max().unwrap_or(0);
+        for object in objects {
+            let flag = if object.disabled { " [disabled]" } else { "" };
+            out.push_str(&format!(
+                "  {:<width$}  {}{}\n",
+                object.name,
+                truncate(&object.detail),
+                flag,
+                width = width
+            ));
+        }
+    }
+    out
+}
+
+fn format_csv(contents: &AppContents) -> String {
+    let mut csv = String::from("app,kind,name,detail,disabled\n");
+    for object in &contents.objects {
+        csv.push_str(&format!(
+            "{},{},{},{},{}\n",
+            escape_csv(&contents.app),
+            object.kind,
+            escape_csv(&object.name),
+            escape_csv(&object.detail),
+            object.disabled
+        ));
+    }
+    csv
+}
+
+fn format_xml(contents: &AppContents) -> String {
+    let mut xml = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<app_contents app=\"{}\">\n",
+        escape_xml(&contents.app)
+    );
+    for object in &contents.objects {
+        xml.push_str(&format!(
+            "  <object kind=\"{}\" disabled=\"{}\">\n",
+            object.kind, object.disabled
+        ));
+        xml.push_str(&format!("    <name>{}</name>\n", escape_xml(&object.name)));
+        xml.push_str(&format!(
+            "    <detail>{}</detail>\n",
+            escape_xml(&object.detail)
+        ));
+        xml.push_str("  </object>\n");
+    }
+    xml.push_str("</app_contents>");
+    xml
+}
+
+fn format_ndjson(contents: &AppContents) -> Result<String> {
+    let mut ndjson = String::new();
+    for object in &contents.objects {
+        ndjson.push_str(&serde_json::to_string(object)?);
+        ndjson.push('\n');
+    }
+    Ok(ndjson)
+}
+
+fn format_markdown(contents: &AppContents) -> String {
+    let mut md = format!("# Contents of app `{}`\n", contents.app);
+    for kind in AppObjectKind::ALL {
+        md.push_str(&format!(
+            "\n## {} ({})\n\n",
+            kind.title(),
+            contents.count(kind)
+        ));
+        let mut objects = contents.of_kind(kind).peekable();
+        if objects.peek().is_none() {
+            md.push_str("_None._\n");
+            continue;
+        }
+        md.push_str("| Name | Detail | Disabled |\n|------|--------|----------|\n");
+        for object in objects {
+            md.push_str(&format!(
+                "| {} | {} | {} |\n",
+                object.name.replace('|', "\\|"),
+                truncate(&object.detail).replace('|', "\\|"),
+                object.disabled
+            ));
+        }
+    }
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use splunk_client::workflows::app_contents::AppObject;
+
+    fn contents() -> AppContents {
+        AppContents {
+            app: "my_app".to_string(),
+            objects: vec![
+                AppObject {
+                    kind: AppObjectKind::SavedSearch,
+                    name: "Errors by host".to_string(),
+                    detail: "index=main error | stats count by host".to_string(),
+                    disabled: true,
+                },
+                AppObject {
+                    kind: AppObjectKind::Macro,
+                    name: "my_index".to_string(),
+                    detail: "index=my_app".to_string(),
+                    disabled: false,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_table_groups_objects_by_kind() {
+        let output = format_app_contents(&contents(), OutputFormat::Table).unwrap();
+
+        assert!(output.contains("Contents of app 'my_app' (2 objects)"));
+        assert!(output.contains("Saved Searches (1):"));
+        assert!(
+            output.contains("Errors by host  index=main error | stats count by host [disabled]")
+        );
+        assert!(output.contains("Dashboards (0):\n  (none)"));
+        assert!(output.contains("Macros (1):"));
+    }
+
+    #[test]
+    fn test_csv_has_one_row_per_object() {
+        let output = format_app_contents(&contents(), OutputFormat::Csv).unwrap();
+
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], "app,kind,name,detail,disabled");
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[2], "my_app,macro,my_index,index=my_app,false");
+    }
+
+    #[test]
+    fn test_truncate_long_details() {
+        let long = "x".repeat(200);
+        let truncated = truncate(&long);
+        assert_eq!(truncated.chars().count(), MAX_DETAIL_CHARS);
+        assert!(truncated.ends_with("..."));
+    }
+}
@@ -4,6 +4,8 @@
 //! - List audit events with optional filtering and count limiting
 //! - Show detailed information about audit events
 //! - Support pagination via offset parameter
+//! - Export audit events to a hash-chained archive and verify it (see `archive`)
+//! - Summarize one user's activity per day (see `user_activity`)
 //! - Format output via shared formatters
 //!
 //! Does NOT handle:
@@ -15,8 +17,19 @@
 //! - Count and offset parameters are validated for safe pagination
 //! - Time-based filtering uses Splunk's standard time format
 
+mod archive;
+mod user_activity;
+
+pub use archive::run_verify_archive;
+
+use std::path::PathBuf;
+
 use anyhow::Result;
 use clap::Subcommand;
+use splunk_client::workflows::user_activity::{
+    DEFAULT_MAX_EVENTS as DEFAULT_ACTIVITY_EVENTS, DEFAULT_SINCE as DEFAULT_ACTIVITY_SINCE,
+    UserActivityRequest,
+};
 use splunk_config::constants::*;
 use tracing::info;
 
@@ -36,10 +49,10 @@ pub enum AuditCommand {
         #[arg(long, default_value = "0")]
         offset: usize,
         /// Earliest time for events (e.g., "-24h", "2024-01-01T00:00:00")
-        #[arg(short, long, default_value = "-24h")]
+        #[arg(short, long, default_value = "-24h", value_parser = crate::commands::search::parse_time_modifier)]
         earliest: String,
         /// Latest time for events (e.g., "now", "2024-01-02T00:00:00")
-        #[arg(short, long, default_value = "now")]
+        #[arg(short, long, default_value = "now", value_parser = crate::commands::search::parse_time_modifier)]
         latest: String,
         /// Filter by user
         #[arg(long)]
@@ -54,13 +67,58 @@ pub enum AuditCommand {
         #[arg(short, long, default_value = "20")]
         count: usize,
     },
+    /// Append audit events to a tamper-evident, hash-chained NDJSON archive
+    ///
+    /// Each run writes a new read-only segment (audit-NNNNNN.ndjson) plus a manifest
+    /// to DIR, continuing the hash chain from the previous segment. Use
+    /// non-overlapping --earliest/--latest windows to avoid archiving events twice.
+    Archive {
+        /// Archive directory (created if missing)
+        #[arg(long, value_name = "DIR")]
+        dir: PathBuf,
+        /// Earliest time for events (e.g., "-24h", "2024-01-01T00:00:00")
+        #[arg(short, long, default_value = "-24h", value_parser = crate::commands::search::parse_time_modifier)]
+        earliest: String,
+        /// Latest time for events (e.g., "now", "2024-01-02T00:00:00")
+        #[arg(short, long, default_value = "now", value_parser = crate::commands::search::parse_time_modifier)]
+        latest: String,
+        /// Filter by user
+        #[arg(long)]
+        user: Option<String>,
+        /// Filter by action
+        #[arg(long)]
+        action: Option<String>,
+        /// Maximum number of events to archive
+        #[arg(short, long, default_value = "10000")]
+        count: usize,
+    },
+    /// Summarize a user's logins, searches, object changes, and failed logins per day
+    UserActivity {
+        /// Username to report on
+        username: String,
+        /// How far back to look (e.g. 7d, 24h, -1w@w)
+        #[arg(long, value_name = "AGE", default_value = DEFAULT_ACTIVITY_SINCE)]
+        since: String,
+        /// Maximum number of audit events to read
+        #[arg(long, default_value_t = DEFAULT_ACTIVITY_EVENTS)]
+        max_events: usize,
+    },
+    /// Verify the hash chain and manifests of an audit archive
+    ///
+    /// Exits non-zero when any record, segment, or manifest was modified,
+    /// removed, or reordered.
+    VerifyArchive {
+        /// Archive directory
+        #[arg(value_name = "DIR")]
+        dir: PathBuf,
+    },
 }
 
 pub async fn run(
     config: splunk_config::Config,
     command: AuditCommand,
     output_format: &str,
-    output_file: Option<std::path::PathBuf>,
+    output_file: Option<PathBuf>,
     cancel: &crate::cancellation::CancellationToken,
     no_cache: bool,
 ) -> Result<()> {
@@ -93,6 +151,55 @@ pub async fn run(
         AuditCommand::Recent { count } => {
             run_recent(config, count, output_format, output_file, cancel, no_cache).await
         }
+        AuditCommand::Archive {
+            dir,
+            earliest,
+            latest,
+            user,
+            action,
+            count,
+        } => {
+            let query = archive::ArchiveQuery {
+                earliest,
+                latest,
+                user,
+                action,
+                count,
+            };
+            archive::run_archive(
+                config,
+                dir,
+                query,
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
+        AuditCommand::UserActivity {
+            username,
+            since,
+            max_events,
+        } => {
+            let request = UserActivityRequest {
+                user: username,
+                since,
+                max_events,
+            };
+            user_activity::run_user_activity(
+                config,
+                request,
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
+        AuditCommand::VerifyArchive { dir } => {
+            archive::run_verify_archive(dir, output_format, output_file)
+        }
     }
 }
 
@@ -107,7 +214,7 @@ async fn run_list(
     user: Option<String>,
     action: Option<String>,
     output_format: &str,
-    output_file: Option<std::path::PathBuf>,
+    output_file: Option<PathBuf>,
     cancel: &crate::cancellation::CancellationToken,
     no_cache: bool,
 ) -> Result<()> {
@@ -154,7 +261,7 @@ async fn run_recent(
     config: splunk_config::Config,
     count: usize,
     output_format: &str,
-    output_file: Option<std::path::PathBuf>,
+    output_file: Option<PathBuf>,
     cancel: &crate::cancellation::CancellationToken,
     no_cache: bool,
 ) -> Result<()> {
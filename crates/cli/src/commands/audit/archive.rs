@@ -0,0 +1,721 @@
+//! Tamper-evident audit archive export and verification.
+//!
+//! Responsibilities:
+//! - Append audit events to a directory as hash-chained NDJSON segments with manifests.
+//! - Verify the chain integrity of every segment in an archive directory.
+//! - Format export and verification summaries in every output format.
+//!
+//! Does NOT handle:
+//! - Fetching audit events (uses `SplunkClient::list_audit_events`).
+//! - Retention or WORM enforcement on the storage itself (segments are only marked read-only).
+//!
+//! Invariants:
+//! - Each export run writes a new segment; existing segments and manifests are never rewritten.
+//! - Every record carries the previous record's hash; the chain continues across segments
+//!   and starts from `GENESIS_HASH`.
+//! - A record's `hash` is the SHA-256 of its exact line bytes with the trailing `hash` member removed.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use splunk_client::models::{AuditEvent, ListAuditEventsParams};
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+/// Manifest `format` marker.
+const ARCHIVE_FORMAT: &str = "splunk-audit-archive";
+/// Manifest schema version.
+const ARCHIVE_VERSION: u32 = 1;
+/// `prev_hash` of the first record in an archive.
+const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+/// Length of the `,"hash":"<64 hex>"}` suffix that closes every record line.
+const HASH_SUFFIX_LEN: usize = r#","hash":""}"#.len() + 64;
+
+/// Search filters recorded in a segment manifest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(super) struct ArchiveQuery {
+    pub earliest: String,
+    pub latest: String,
+    pub user: Option<String>,
+    pub action: Option<String>,
+    pub count: usize,
+}
+
+/// Manifest written next to each NDJSON segment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SegmentManifest {
+    format: String,
+    version: u32,
+    segment: String,
+    created_at: String,
+    query: ArchiveQuery,
+    record_count: u64,
+    first_seq: u64,
+    last_seq: u64,
+    prev_hash: String,
+    last_hash: String,
+    segment_sha256: String,
+}
+
+/// Result of an `audit archive` run.
+#[derive(Debug, Serialize)]
+struct ArchiveExport {
+    dir: String,
+    segment: Option<String>,
+    manifest: Option<String>,
+    records: u64,
+    first_seq: Option<u64>,
+    last_seq: Option<u64>,
+    last_hash: String,
+}
+
+/// Result of an `audit verify-archive` run.
+#[derive(Debug, Serialize)]
+struct ArchiveVerification {
+    dir: String,
+    valid: bool,
+    segments: usize,
+    records: u64,
+    last_hash: String,
+    errors: Vec<String>,
+}
+
+pub(super) async fn run_archive(
+    config: splunk_config::Config,
+    dir: PathBuf,
+    query: ArchiveQuery,
+    output_format: &str,
+    output_file: Option<PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!(
+        "Archiving audit events to {} (earliest: {}, latest: {})",
+        dir.display(),
+        query.earliest,
+        query.latest
+    );
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let params = ListAuditEventsParams {
+        earliest: Some(query.earliest.clone()),
+        latest: Some(query.latest.clone()),
+        count: Some(query.count),
+        offset: None,
+        user: query.user.clone(),
+        action: query.action.clone(),
+    };
+    let mut events = cancellable!(client.list_audit_events(&params), cancel)?;
+    // The search returns newest first; archives are appended in chronological order.
+    events.reverse();
+
+    let export = append_segment(&dir, &events, query)?;
+    output_result(
+        &format_export(&export, format)?,
+        format,
+        output_file.as_ref(),
+    )?;
+
+    Ok(())
+}
+
+/// Verify the archive in `dir` and report the result; fails when the chain is broken.
+///
+/// Runs without a Splunk connection.
+pub fn run_verify_archive(
+    dir: PathBuf,
+    output_format: &str,
+    output_file: Option<PathBuf>,
+) -> Result<()> {
+    info!("Verifying audit archive {}", dir.display());
+
+    let format = OutputFormat::from_str(output_format)?;
+    let verification = verify_archive(&dir)?;
+    output_result(
+        &format_verification(&verification, format)?,
+        format,
+        output_file.as_ref(),
+    )?;
+
+    if !verification.valid {
+        anyhow::bail!("Audit archive verification failed");
+    }
+    Ok(())
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+fn segment_name(number: u32) -> String {
+    format!("audit-{:06}.ndjson", number)
+}
+
+fn manifest_name(number: u32) -> String {
+    format!("audit-{:06}.manifest.json", number)
+}
+
+/// Segment numbers present in `dir` (from segment or manifest files), ascending.
+fn segment_numbers(dir: &Path) -> Result<Vec<u32>> {
+    let mut numbers = Vec::new();
+    for entry in fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir.display()))? {
+        let name = entry?.file_name();
+        let Some(rest) = name.to_str().and_then(|n| n.strip_prefix("audit-")) else {
+            continue;
+        };
+        let digits = rest
+            .strip_suffix(".ndjson")
+            .or_else(|| rest.strip_suffix(".manifest.json"));
+        if let Some(number) = digits.and_then(|d| d.parse::<u32>().ok()) {
+            numbers.push(number);
+        }
+    }
+    numbers.sort_unstable();
+    numbers.dedup();
+    Ok(numbers)
+}
+
+fn read_manifest(dir: &Path, number: u32) -> Result<SegmentManifest> {
+    let path = dir.join(manifest_name(number));
+    let content =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_str(&content).with_context(|| format!("Invalid manifest {}", path.display()))
+}
+
+/// Encode one record line and return it with its hash.
+fn encode_record(seq: u64, prev_hash: &str, event: &AuditEvent) -> Result<(String, String)> {
+    let body = serde_json::to_string(&serde_json::json!({
+        "seq": seq,
+        "prev_hash": prev_hash,
+        "event": event,
+    }))?;
+    let hash = sha256_hex(body.as_bytes());
+    let line = format!(
+        "{},\"hash\":\"{}\"}}",
+        body.strip_suffix('}').unwrap_or(&body),
+        hash
+    );
+    Ok((line, hash))
+}
+
+/// Write `content` to a new file (never overwriting) and mark it read-only.
+fn write_sealed(path: &Path, content: &[u8]) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .with_context(|| format!("Failed to create {}", path.display()))?;
+    file.write_all(content)?;
+    file.sync_all()?;
+
+    let mut permissions = file.metadata()?.permissions();
+    permissions.set_readonly(true);
+    fs::set_permissions(path, permissions)?;
+    Ok(())
+}
+
+/// Append `events` to the archive in `dir` as a new segment, continuing the hash chain.
+fn append_segment(dir: &Path, events: &[AuditEvent], query: ArchiveQuery) -> Result<ArchiveExport> {
+    fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+
+    let last = segment_numbers(dir)?.last().copied();
+    let (number, first_seq, prev_hash) = match last {
+        Some(number) => {
+            let manifest = read_manifest(dir, number)?;
+            (number + 1, manifest.last_seq + 1, manifest.last_hash)
+        }
+        None => (1, 1, GENESIS_HASH.to_string()),
+    };
+
+    let mut export = ArchiveExport {
+        dir: dir.display().to_string(),
+        segment: None,
+        manifest: None,
+        records: 0,
+        first_seq: None,
+        last_seq: None,
+        last_hash: prev_hash.clone(),
+    };
+    if events.is_empty() {
+        return Ok(export);
+    }
+
+    let mut content = String::new();
+    let mut hash = prev_hash.clone();
+    for (seq, event) in (first_seq..).zip(events) {
+        let (line, line_hash) = encode_record(seq, &hash, event)?;
+        content.push_str(&line);
+        content.push('\n');
+        hash = line_hash;
+    }
+
+    let last_seq = first_seq + events.len() as u64 - 1;
+    let manifest = SegmentManifest {
+        format: ARCHIVE_FORMAT.to_string(),
+        version: ARCHIVE_VERSION,
+        segment: segment_name(number),
+        created_at: chrono::Utc::now().to_rfc3339(),
+        query,
+        record_count: events.len() as u64,
+        first_seq,
+        last_seq,
+        prev_hash,
+        last_hash: hash.clone(),
+        segment_sha256: sha256_hex(content.as_bytes()),
+    };
+
+    write_sealed(&dir.join(segment_name(number)), content.as_bytes())?;
+    write_sealed(
+        &dir.join(manifest_name(number)),
+        serde_json::to_string_pretty(&manifest)?.as_bytes(),
+    )?;
+
+    export.segment = Some(segment_name(number));
+    export.manifest = Some(manifest_name(number));
+    export.records = manifest.record_count;
+    export.first_seq = Some(first_seq);
+    export.last_seq = Some(last_seq);
+    export.last_hash = hash;
+    Ok(export)
+}
+
+/// Check one record line against the expected sequence number and previous hash.
+///
+/// Returns the line's hash when it is intact.
+fn verify_line(line: &str, expected_seq: u64, prev_hash: &str) -> Result<String, String> {
+    if line.len() <= HASH_SUFFIX_LEN {
+        return Err("truncated record".to_string());
+    }
+    let split = line.len() - HASH_SUFFIX_LEN;
+    // A tampered line can end in multibyte text where the hash suffix should be.
+    if !line.is_char_boundary(split) {
+        return Err("malformed hash suffix".to_string());
+    }
+    let (head, suffix) = line.split_at(split);
+    let claimed = suffix
+        .strip_prefix(r#","hash":""#)
+        .and_then(|rest| rest.strip_suffix(r#""}"#))
+        .ok_or("missing trailing hash")?;
+
+    let hash = sha256_hex(format!("{}}}", head).as_bytes());
+    if hash != claimed {
+        return Err("hash mismatch (record was modified)".to_string());
+    }
+
+    let record: serde_json::Value =
+        serde_json::from_str(line).map_err(|e| format!("invalid JSON: {}", e))?;
+    let seq = record.get("seq").and_then(serde_json::Value::as_u64);
+    if seq != Some(expected_seq) {
+        return Err(format!(
+            "expected seq {}, found {}",
+            expected_seq,
+            seq.map_or_else(|| "none".to_string(), |s| s.to_string())
+        ));
+    }
+    if record.get("prev_hash").and_then(serde_json::Value::as_str) != Some(prev_hash) {
+        return Err("prev_hash does not match the previous record".to_string());
+    }
+    Ok(hash)
+}
+
+/// Verify one segment, appending problems to `errors`.
+///
+/// Returns the number of intact records and advances `seq`/`hash` to the segment's end.
+fn verify_segment(
+    dir: &Path,
+    number: u32,
+    seq: &mut u64,
+    hash: &mut String,
+    errors: &mut Vec<String>,
+) -> u64 {
+    let segment = segment_name(number);
+    let manifest = match read_manifest(dir, number) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            errors.push(format!("{}: {:#}", manifest_name(number), e));
+            return 0;
+        }
+    };
+    if manifest.format != ARCHIVE_FORMAT || manifest.version != ARCHIVE_VERSION {
+        errors.push(format!(
+            "{}: unsupported format {} v{}",
+            manifest_name(number),
+            manifest.format,
+            manifest.version
+        ));
+        return 0;
+    }
+    if manifest.segment != segment {
+        errors.push(format!(
+            "{}: names segment {}",
+            manifest_name(number),
+            manifest.segment
+        ));
+    }
+
+    let content = match fs::read(dir.join(&segment)) {
+        Ok(content) => content,
+        Err(e) => {
+            errors.push(format!("{}: {}", segment, e));
+            return 0;
+        }
+    };
+    if sha256_hex(&content) != manifest.segment_sha256 {
+        errors.push(format!("{}: file hash does not match manifest", segment));
+    }
+    if manifest.prev_hash != *hash || manifest.first_seq != *seq {
+        errors.push(format!(
+            "{}: does not continue the previous segment (segment missing or reordered)",
+            segment
+        ));
+    }
+
+    let text = String::from_utf8_lossy(&content);
+    let mut records = 0;
+    for (index, line) in text.lines().enumerate() {
+        match verify_line(line, *seq, hash) {
+            Ok(line_hash) => {
+                *hash = line_hash;
+                *seq += 1;
+                records += 1;
+            }
+            Err(e) => {
+                errors.push(format!("{} line {}: {}", segment, index + 1, e));
+                break;
+            }
+        }
+    }
+
+    if records != manifest.record_count || *hash != manifest.last_hash {
+        errors.push(format!(
+            "{}: manifest lists {} records ending in {}, found {} intact",
+            segment, manifest.record_count, manifest.last_hash, records
+        ));
+    }
+    // Resume from the manifest so one broken segment does not cascade into the next.
+    *seq = manifest.last_seq + 1;
+    *hash = manifest.last_hash;
+    records
+}
+
+/// Verify chain integrity across every segment in `dir`.
+fn verify_archive(dir: &Path) -> Result<ArchiveVerification> {
+    let numbers = segment_numbers(dir)?;
+
+    let mut errors = Vec::new();
+    let mut seq = 1;
+    let mut hash = GENESIS_HASH.to_string();
+    let mut records = 0;
+    let mut expected = 1;
+    for &number in &numbers {
+        for missing in expected..number {
+            errors.push(format!("{}: missing", segment_name(missing)));
+        }
+        expected = number + 1;
+        records += verify_segment(dir, number, &mut seq, &mut hash, &mut errors);
+    }
+    if numbers.is_empty() {
+        errors.push("no audit archive segments found".to_string());
+    }
+
+    Ok(ArchiveVerification {
+        dir: dir.display().to_string(),
+        valid: errors.is_empty(),
+        segments: numbers.len(),
+        records,
+        last_hash: hash,
+        errors,
+    })
+}
+
+fn export_rows(export: &ArchiveExport) -> Vec<(&'static str, String)> {
+    let seq = |value: Option<u64>| value.map(|s| s.to_string()).unwrap_or_default();
+    vec![
+        ("Directory", export.dir.clone()),
+        (
+            "Segment",
+            export
+                .segment
+                .clone()
+                .unwrap_or_else(|| "(none, no new events)".to_string()),
+        ),
+        ("Manifest", export.manifest.clone().unwrap_or_default()),
+        ("Records", export.records.to_string()),
+        ("First seq", seq(export.first_seq)),
+        ("Last seq", seq(export.last_seq)),
+        ("Last hash", export.last_hash.clone()),
+    ]
+}
+
+fn verification_rows(verification: &ArchiveVerification) -> Vec<(&'static str, String)> {
+    vec![
+        ("Directory", verification.dir.clone()),
+        (
+            "Status",
+            if verification.valid {
+                "valid"
+            } else {
+                "INVALID"
+            }
+            .to_string(),
+        ),
+        ("Segments", verification.segments.to_string()),
+        ("Records", verification.records.to_string()),
+        ("Last hash", verification.last_hash.clone()),
+    ]
+}
+
+fn format_export(export: &ArchiveExport, format: OutputFormat) -> Result<String> {
+    format_summary(
+        "Audit Archive Export",
+        "audit_archive",
+        &export_rows(export),
+        None,
+        export,
+        format,
+    )
+}
+
+fn format_verification(verification: &ArchiveVerification, format: OutputFormat) -> Result<String> {
+    format_summary(
+        "Audit Archive Verification",
+        "audit_archive_verification",
+        &verification_rows(verification),
+        Some(&verification.errors),
+        verification,
+        format,
+    )
+}
+
+/// Render a key/value summary (plus an error list, when the summary has one) in `format`.
+fn format_summary<T: Serialize>(
+    title: &str,
+    root: &str,
+    rows: &[(&'static str, String)],
+    errors: Option<&[String]>,
+    value: &T,
+    format: OutputFormat,
+) -> Result<String> {
+    let key = |label: &str| label.to_lowercase().replace(' ', "_");
+    let errors = errors.unwrap_or_default();
+    let mut out = String::new();
+    match format {
+        OutputFormat::Json => return Ok(serde_json::to_string_pretty(value)?),
+        OutputFormat::Ndjson => return Ok(format!("{}\n", serde_json::to_string(value)?)),
+        OutputFormat::Yaml => return Ok(serde_yaml::to_string(value)?),
+        OutputFormat::Table => {
+            out.push_str(&format!("{}\n{}\n\n", title, "=".repeat(title.len())));
+            for (label, value) in rows {
+                out.push_str(&format!("{:<11} {}\n", format!("{}:", label), value));
+            }
+            if !errors.is_empty() {
+                out.push_str("\nErrors:\n");
+                for error in errors {
+                    out.push_str(&format!("  {}\n", error));
+                }
+            }
+        }
+        OutputFormat::Csv => {
+            let mut header: Vec<String> = rows.iter().map(|(label, _)| key(label)).collect();
+            let mut values: Vec<String> = rows.iter().map(|(_, value)| escape_csv(value)).collect();
+            if !errors.is_empty() {
+                header.push("errors".to_string());
+                values.push(escape_csv(&errors.join("; ")));
+            }
+            out.push_str(&format!("{}\n{}\n", header.join(","), values.join(",")));
+        }
+        OutputFormat::Xml => {
+            out.push_str(&format!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<{}>\n",
+                root
+            ));
+            for (label, value) in rows {
+                let tag = key(label);
+                out.push_str(&format!("  <{}>{}</{}>\n", tag, escape_xml(value), tag));
+            }
+            for error in errors {
+                out.push_str(&format!("  <error>{}</error>\n", escape_xml(error)));
+            }
+            out.push_str(&format!("</{}>\n", root));
+        }
+        OutputFormat::Markdown => {
+            out.push_str(&format!("## {}\n\n| Field | Value |\n|---|---|\n", title));
+            for (label, value) in rows {
+                out.push_str(&format!("| {} | {} |\n", label, value));
+            }
+            if !errors.is_empty() {
+                out.push_str("\n### Errors\n\n");
+                for error in errors {
+                    out.push_str(&format!("- {}\n", error));
+                }
+            }
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(user: &str, action: &str) -> AuditEvent {
+        serde_json::from_value(serde_json::json!({
+            "_time": "2026-02-22T15:00:00.000+00:00",
+            "user": user,
+            "action": action,
+            "result": "success",
+            "_raw": format!("{} {}", user, action),
+        }))
+        .unwrap()
+    }
+
+    fn query() -> ArchiveQuery {
+        ArchiveQuery {
+            earliest: "-24h".to_string(),
+            latest: "now".to_string(),
+            user: None,
+            action: None,
+            count: 100,
+        }
+    }
+
+    fn make_writable(path: &Path) {
+        let mut permissions = fs::metadata(path).unwrap().permissions();
+        #[allow(clippy::permissions_set_readonly_false)]
+        permissions.set_readonly(false);
+        fs::set_permissions(path, permissions).unwrap();
+    }
+
+    #[test]
+    fn test_archive_chains_records_across_segments() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let first = append_segment(
+            dir.path(),
+            &[event("admin", "login"), event("bob", "search")],
+            query(),
+        )
+        .unwrap();
+        let second = append_segment(dir.path(), &[event("carol", "logout")], query()).unwrap();
+
+        assert_eq!(first.segment.as_deref(), Some("audit-000001.ndjson"));
+        assert_eq!(second.segment.as_deref(), Some("audit-000002.ndjson"));
+        assert_eq!(second.first_seq, Some(3));
+
+        let content = fs::read_to_string(dir.path().join("audit-000002.ndjson")).unwrap();
+        let record: serde_json::Value = serde_json::from_str(content.trim()).unwrap();
+        assert_eq!(record["prev_hash"], first.last_hash);
+        assert_eq!(record["hash"], second.last_hash);
+
+        let verification = verify_archive(dir.path()).unwrap();
+        assert!(verification.valid, "{:?}", verification.errors);
+        assert_eq!(verification.segments, 2);
+        assert_eq!(verification.records, 3);
+        assert_eq!(verification.last_hash, second.last_hash);
+    }
+
+    #[test]
+    fn test_archive_without_events_writes_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let export = append_segment(dir.path(), &[], query()).unwrap();
+
+        assert!(export.segment.is_none());
+        assert_eq!(export.last_hash, GENESIS_HASH);
+        assert!(segment_numbers(dir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_archive_segments_are_never_overwritten() {
+        let dir = tempfile::tempdir().unwrap();
+        append_segment(dir.path(), &[event("admin", "login")], query()).unwrap();
+
+        assert!(write_sealed(&dir.path().join("audit-000001.ndjson"), b"{}").is_err());
+    }
+
+    #[test]
+    fn test_verify_detects_modified_record() {
+        let dir = tempfile::tempdir().unwrap();
+        append_segment(
+            dir.path(),
+            &[event("admin", "login"), event("bob", "search")],
+            query(),
+        )
+        .unwrap();
+
+        let path = dir.path().join("audit-000001.ndjson");
+        make_writable(&path);
+        let tampered = fs::read_to_string(&path).unwrap().replace("bob", "eve");
+        fs::write(&path, tampered).unwrap();
+
+        let verification = verify_archive(dir.path()).unwrap();
+        assert!(!verification.valid);
+        assert!(
+            verification
+                .errors
+                .iter()
+                .any(|e| e == "audit-000001.ndjson line 2: hash mismatch (record was modified)")
+        );
+    }
+
+    #[test]
+    fn test_verify_detects_removed_segment() {
+        let dir = tempfile::tempdir().unwrap();
+        for user in ["admin", "bob", "carol"] {
+            append_segment(dir.path(), &[event(user, "login")], query()).unwrap();
+        }
+        for name in ["audit-000002.ndjson", "audit-000002.manifest.json"] {
+            fs::remove_file(dir.path().join(name)).unwrap();
+        }
+
+        let verification = verify_archive(dir.path()).unwrap();
+        assert!(!verification.valid);
+        assert!(
+            verification
+                .errors
+                .iter()
+                .any(|e| e.starts_with("audit-000003.ndjson: does not continue"))
+        );
+    }
+
+    #[test]
+    fn test_verify_line_rejects_broken_links() {
+        let (line, hash) = encode_record(1, GENESIS_HASH, &event("admin", "login")).unwrap();
+        assert_eq!(verify_line(&line, 1, GENESIS_HASH), Ok(hash.clone()));
+
+        assert_eq!(
+            verify_line(&line, 2, GENESIS_HASH),
+            Err("expected seq 2, found 1".to_string())
+        );
+        assert_eq!(
+            verify_line(&line, 1, &hash),
+            Err("prev_hash does not match the previous record".to_string())
+        );
+        assert_eq!(
+            verify_line("{}", 1, GENESIS_HASH),
+            Err("truncated record".to_string())
+        );
+    }
+
+    #[test]
+    fn test_verify_line_rejects_multibyte_suffix() {
+        let (line, _) = encode_record(1, GENESIS_HASH, &event("admin", "login")).unwrap();
+        let tampered = format!(
+            "{}{}",
+            &line[..line.len() - HASH_SUFFIX_LEN],
+            "é".repeat(40)
+        );
+
+        assert_eq!(
+            verify_line(&tampered, 1, GENESIS_HASH),
+            Err("malformed hash suffix".to_string())
+        );
+    }
+}
@@ -0,0 +1,80 @@
+//! Per-user activity report for the audit command.
+//!
+//! Responsibilities:
+//! - Run the shared user activity report for one user and window.
+//! - Print one row per day with login, search, modification, and failed-auth counts.
+//!
+//! Does NOT handle:
+//! - Fetching or classifying audit events (lives in `splunk-client::workflows::user_activity`).
+//!
+//! Invariants:
+//! - An invalid `--since` is a usage error reported before contacting Splunk.
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use splunk_client::workflows::user_activity::{UserActivityRequest, user_activity};
+use tracing::info;
+
+use crate::formatters::{OutputFormat, output_result, render_rows};
+
+/// Report columns, in display order.
+const COLUMNS: [&str; 6] = [
+    "day",
+    "logins",
+    "searches",
+    "objects_modified",
+    "failed_auth",
+    "other",
+];
+
+pub(super) async fn run_user_activity(
+    config: splunk_config::Config,
+    request: UserActivityRequest,
+    output_format: &str,
+    output_file: Option<PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    let earliest = request
+        .earliest()
+        .map_err(|e| crate::error::UsageError(format!("{e:#}")))?;
+    info!(
+        "Summarizing audit activity for {} since {}",
+        request.user, earliest
+    );
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let report = cancellable!(user_activity(&client, &request, Some(cancel)), cancel)?;
+
+    let rows = report
+        .days
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<serde_json::Result<Vec<_>>>()?;
+    let columns: Vec<String> = COLUMNS.iter().map(|c| c.to_string()).collect();
+    let output = render_rows(rows, &columns, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    let totals = report.totals();
+    eprintln!(
+        "{} since {}: {} login(s), {} search(es), {} object modification(s), {} failed auth attempt(s) over {} day(s)",
+        report.user,
+        report.earliest,
+        totals.logins,
+        totals.searches,
+        totals.objects_modified,
+        totals.failed_auth,
+        report.days.len()
+    );
+    if report.truncated {
+        eprintln!(
+            "Stopped at {} audit events; narrow --since or raise --max-events for older activity",
+            request.max_events
+        );
+    }
+
+    Ok(())
+}
@@ -5,6 +5,8 @@
 //! - Manage cluster maintenance mode
 //! - Rebalance cluster primaries
 //! - Decommission and remove cluster peers
+//! - Inspect bucket fix-ups and remove excess bucket copies (see `buckets`)
+//! - Report per-index replication/search factor compliance (see `buckets`)
 //!
 //! Does NOT handle:
 //! - Low-level cluster API calls (handled by client crate)
@@ -13,6 +15,9 @@
 //! Invariants:
 //! - Cluster operations require appropriate administrative privileges
 //! - Peer decommissioning is irreversible and requires confirmation
+//! - Excess bucket removal requires confirmation unless forced
+
+mod buckets;
 
 use std::path::PathBuf;
 
@@ -20,6 +25,7 @@ use anyhow::Result;
 use clap::Subcommand;
 use tracing::{info, warn};
 
+use splunk_client::ClusterFixupLevel;
 use splunk_config::constants::*;
 
 use crate::cancellation::Cancelled;
@@ -65,6 +71,50 @@ pub enum ClusterCommand {
     /// Manage cluster peers
     #[command(subcommand)]
     PeersManage(PeersCommand),
+
+    /// Show pending bucket fix-up tasks on the cluster manager
+    Fixups {
+        /// Only show tasks at this level (streaming, data_safety, generation,
+        /// replication_factor, search_factor, checksum_sync)
+        #[arg(short, long)]
+        level: Option<ClusterFixupLevel>,
+        /// Only show tasks for this index
+        #[arg(short, long)]
+        index: Option<String>,
+    },
+
+    /// Show excess bucket copies per index
+    ExcessBuckets {
+        /// Only show this index
+        #[arg(short, long)]
+        index: Option<String>,
+    },
+
+    /// Show actual replicated/searchable copies per index against the configured RF/SF
+    IndexCompliance {
+        /// Only show this index
+        #[arg(short, long)]
+        index: Option<String>,
+        /// Only show indexes currently below the replication or search factor
+        #[arg(long)]
+        non_compliant: bool,
+    },
+
+    /// Remove excess bucket copies from selected indexes
+    RemoveExcessBuckets {
+        /// Index to prune (can be repeated)
+        #[arg(short, long = "index", value_name = "INDEX", conflicts_with = "all")]
+        indexes: Vec<String>,
+        /// Remove excess copies from every index that has them
+        #[arg(long)]
+        all: bool,
+        /// Show which indexes would be pruned without removing anything
+        #[arg(long)]
+        dry_run: bool,
+        /// Skip confirmation prompt
+        #[arg(short, long)]
+        force: bool,
+    },
 }
 
 /// Maintenance mode subcommands.
@@ -162,12 +212,62 @@ pub async fn run(
             )
             .await
         }
+        ClusterCommand::Fixups { level, index } => {
+            buckets::run_fixups(
+                config,
+                level,
+                index,
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
+        ClusterCommand::ExcessBuckets { index } => {
+            buckets::run_excess_buckets(config, index, output_format, output_file, cancel, no_cache)
+                .await
+        }
+        ClusterCommand::IndexCompliance {
+            index,
+            non_compliant,
+        } => {
+            buckets::run_index_compliance(
+                config,
+                index,
+                non_compliant,
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
+        ClusterCommand::RemoveExcessBuckets {
+            indexes,
+            all,
+            dry_run,
+            force,
+        } => {
+            buckets::run_remove_excess_buckets(
+                config,
+                indexes,
+                all,
+                dry_run,
+                force,
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
     }
 }
 
 /// Fetch a page of cluster peers with pagination.
 async fn fetch_cluster_peers_page(
-    client: &mut splunk_client::SplunkClient,
+    client: &splunk_client::SplunkClient,
     offset: usize,
     count: usize,
     cancel: &crate::cancellation::CancellationToken,
@@ -245,7 +345,7 @@ async fn run_show(
         anyhow::bail!("The count value must be greater than 0");
     }
 
-    let mut client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
 
     let cluster_info = tokio::select! {
         res = client.get_cluster_info() => res,
@@ -256,7 +356,7 @@ async fn run_show(
         Ok(cluster_info) => {
             // Fetch peers if detailed (fetch ALL once, then paginate locally)
             let (peers_output, peers_pagination) = if detailed {
-                fetch_cluster_peers_page(&mut client, offset, count, cancel).await?
+                fetch_cluster_peers_page(&client, offset, count, cancel).await?
             } else {
                 (None, None)
             };
@@ -0,0 +1,244 @@
+//! Bucket fix-up and excess copy subcommands for the cluster command.
+//!
+//! Responsibilities:
+//! - List pending bucket fix-up tasks, optionally filtered by level and index.
+//! - List excess bucket copies per clustered index.
+//! - Report actual copy counts per clustered index against the replication/search factors.
+//! - Remove excess bucket copies for selected indexes (or all of them).
+//!
+//! Does NOT handle:
+//! - Low-level cluster manager API calls (handled by client crate).
+//!
+//! Invariants:
+//! - `remove-excess-buckets` only targets indexes that currently report excess copies.
+//! - Removal requires confirmation unless `--force` is given; `--dry-run` never issues writes.
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use splunk_client::{ClusterFixupLevel, ClusterIndexExcess};
+use tracing::info;
+
+use crate::cancellation::Cancelled;
+use crate::formatters::{ClusterManagementOutput, OutputFormat, get_formatter, output_result};
+
+pub(super) async fn run_fixups(
+    config: splunk_config::Config,
+    level: Option<ClusterFixupLevel>,
+    index: Option<String>,
+    output_format: &str,
+    output_file: Option<PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!(
+        "Fetching cluster fix-up tasks (level: {:?}, index: {:?})",
+        level, index
+    );
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let levels = match level {
+        Some(level) => vec![level],
+        None => ClusterFixupLevel::ALL.to_vec(),
+    };
+
+    let mut fixups = Vec::new();
+    for level in levels {
+        fixups.extend(cancellable!(client.get_cluster_fixups(level), cancel)?);
+    }
+
+    if let Some(ref index) = index {
+        fixups.retain(|task| &task.index == index);
+    }
+
+    let formatter = get_formatter(format);
+    let output = formatter.format_cluster_fixups(&fixups)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+pub(super) async fn run_excess_buckets(
+    config: splunk_config::Config,
+    index: Option<String>,
+    output_format: &str,
+    output_file: Option<PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Fetching excess bucket copies (index: {:?})", index);
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let indexes = cancellable!(client.get_cluster_excess_buckets(index.as_deref()), cancel)?;
+
+    let formatter = get_formatter(format);
+    let output = formatter.format_cluster_excess_buckets(&indexes)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+pub(super) async fn run_index_compliance(
+    config: splunk_config::Config,
+    index: Option<String>,
+    non_compliant: bool,
+    output_format: &str,
+    output_file: Option<PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!(
+        "Fetching index replication compliance (index: {:?}, non_compliant: {})",
+        index, non_compliant
+    );
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let mut indexes = cancellable!(client.get_cluster_index_compliance(), cancel)?;
+    if let Some(ref index) = index {
+        indexes.retain(|compliance| &compliance.index == index);
+    }
+    if non_compliant {
+        indexes.retain(|compliance| !compliance.compliant);
+    }
+
+    let formatter = get_formatter(format);
+    let output = formatter.format_cluster_index_compliance(&indexes)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn run_remove_excess_buckets(
+    config: splunk_config::Config,
+    indexes: Vec<String>,
+    all: bool,
+    dry_run: bool,
+    force: bool,
+    output_format: &str,
+    output_file: Option<PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    if indexes.is_empty() && !all {
+        anyhow::bail!("Specify at least one --index or use --all");
+    }
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let available = cancellable!(client.get_cluster_excess_buckets(None), cancel)?;
+    let selected = select_indexes(&available, &indexes, all)?;
+
+    if selected.is_empty() {
+        println!("No excess bucket copies to remove.");
+        return Ok(());
+    }
+
+    let target = selected
+        .iter()
+        .map(|excess| excess.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let total: usize = selected.iter().map(|e| e.total_excess_bucket_copies).sum();
+
+    if dry_run {
+        eprintln!(
+            "Dry run: would remove {} excess bucket copies from {} index(es)",
+            total,
+            selected.len()
+        );
+        let formatter = get_formatter(format);
+        let output = formatter.format_cluster_excess_buckets(&selected)?;
+        output_result(&output, format, output_file.as_ref())?;
+        return Ok(());
+    }
+
+    if !force && !crate::interactive::confirm_delete(&target, "excess bucket copies in index(es)")?
+    {
+        return Ok(());
+    }
+
+    info!("Removing excess bucket copies from: {}", target);
+
+    let mut messages = Vec::new();
+    let mut errors = Vec::new();
+    if all {
+        let result = cancellable!(client.remove_excess_buckets(None), cancel)?;
+        messages.extend(result.message);
+    } else {
+        for excess in &selected {
+            let result = tokio::select! {
+                res = client.remove_excess_buckets(Some(&excess.name)) => res,
+                _ = cancel.cancelled() => return Err(Cancelled.into()),
+            };
+            match result {
+                Ok(result) => messages.push(
+                    result
+                        .message
+                        .unwrap_or_else(|| format!("Excess buckets removed for {}", excess.name)),
+                ),
+                Err(e) => errors.push(format!("{}: {}", excess.name, e)),
+            }
+        }
+    }
+
+    let output = ClusterManagementOutput {
+        operation: "remove excess buckets".to_string(),
+        target,
+        success: errors.is_empty(),
+        message: messages
+            .into_iter()
+            .chain(errors.iter().cloned())
+            .collect::<Vec<_>>()
+            .join("; "),
+    };
+
+    super::handle_management_output(output, output_format, output_file).await?;
+
+    if !errors.is_empty() {
+        anyhow::bail!(
+            "Failed to remove excess buckets for {} of {} index(es)",
+            errors.len(),
+            selected.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// Pick the indexes to prune, keeping only those that currently have excess copies.
+fn select_indexes(
+    available: &[ClusterIndexExcess],
+    requested: &[String],
+    all: bool,
+) -> Result<Vec<ClusterIndexExcess>> {
+    if all {
+        return Ok(available
+            .iter()
+            .filter(|excess| excess.has_excess())
+            .cloned()
+            .collect());
+    }
+
+    let mut selected = Vec::new();
+    for name in requested {
+        let excess = available
+            .iter()
+            .find(|excess| &excess.name == name)
+            .ok_or_else(|| anyhow::anyhow!("Index '{}' is not a clustered index", name))?;
+        if excess.has_excess() {
+            selected.push(excess.clone());
+        } else {
+            info!("Index '{}' has no excess bucket copies, skipping", name);
+        }
+    }
+
+    Ok(selected)
+}
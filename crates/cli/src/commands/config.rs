@@ -4,6 +4,8 @@
 //! - Provide CLI commands for listing, showing, setting, and deleting profiles.
 //! - Facilitate manual configuration of Splunk connection details.
 //! - Handle secure credential storage via keyring integration.
+//! - Sync and show the shared team config bundle (see `team`).
+//! - Share search templates with parameter schemas as files (see `templates`).
 //!
 //! Does NOT handle:
 //! - Automated configuration loading for other commands (see `splunk_config`).
@@ -22,6 +24,11 @@ use splunk_config::persistence::ConfigManager;
 use splunk_config::types::{ProfileConfig, SecureValue};
 use std::path::PathBuf;
 
+mod team;
+mod templates;
+
+pub(crate) use templates::{parse_params, render_named_template};
+
 #[derive(Subcommand)]
 pub enum ConfigCommand {
     /// List all configured profiles
@@ -138,16 +145,108 @@ pub enum ConfigCommand {
         #[arg(long)]
         env_var: Option<String>,
     },
+
+    /// Fetch the shared team config bundle and cache it beneath your personal config
+    #[command(after_help = "\
+The team bundle is a read-only JSON file with optional `name`, `description`,
+`theme`, `keybind_overrides`, `search_templates`, and secret-free `profiles`.
+Personal settings and profiles always take precedence over the bundle.
+
+Examples:
+  splunk-cli config sync --source /mnt/shared/splunk-tui/team.json
+  splunk-cli config sync --source https://config.example.com/splunk-tui/team.json
+  SPLUNK_TEAM_CONFIG=https://config.example.com/team.json splunk-cli config sync
+  splunk-cli config sync   # refresh from the last synced source
+")]
+    Sync {
+        /// Path or URL of the team bundle (defaults to SPLUNK_TEAM_CONFIG, then the last source)
+        #[arg(long, value_name = "PATH|URL")]
+        source: Option<String>,
+    },
+
+    /// Show the cached team config bundle
+    Team,
+
+    /// List, export, import, and render shareable search templates
+    Templates {
+        #[command(subcommand)]
+        command: TemplatesCommand,
+    },
 }
 
-pub fn run(
-    command: ConfigCommand,
-    output_format: &str,
-    output_file: Option<PathBuf>,
+#[derive(Subcommand)]
+pub enum TemplatesCommand {
+    /// List personal and team search templates with their parameters
+    List,
+
+    /// Export search templates to a shareable JSON file
+    #[command(after_help = "\
+Exports personal and team templates (or only those named with --name) in the
+versioned template file format, including each template's parameter schema:
+
+  {
+    \"version\": 1,
+    \"templates\": [{
+      \"name\": \"Failed logins\",
+      \"query\": \"index=$index$ action=failure earliest=$since$ | stats count by user\",
+      \"parameters\": [
+        { \"name\": \"index\", \"type\": \"string\", \"default\": \"security\" },
+        { \"name\": \"since\", \"type\": \"time\", \"default\": \"-24h\", \"description\": \"Earliest time\" }
+      ]
+    }]
+  }
+
+Parameter types are string, integer, number, boolean, and time. Use $$ for a literal $.
+
+Examples:
+  splunk-cli config templates export investigations.json
+  splunk-cli config templates export failed-logins.json --name \"Failed logins\"
+")]
+    Export {
+        /// Destination file
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+
+        /// Export only the named template (repeatable)
+        #[arg(long = "name", value_name = "NAME")]
+        names: Vec<String>,
+    },
+
+    /// Import templates from a file into the personal library
+    Import {
+        /// Template file to import (validated before anything is saved)
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+
+        /// Overwrite personal templates that have the same name
+        #[arg(long)]
+        replace: bool,
+    },
+
+    /// Print a template's SPL with parameters filled in
+    #[command(after_help = "\
+Examples:
+  splunk-cli config templates render \"Failed logins\" --param index=wineventlog --param since=-7d
+  splunk-cli search execute \"$(splunk-cli config templates render \"Failed logins\")\"
+")]
+    Render {
+        /// Template name
+        #[arg(value_name = "NAME")]
+        name: String,
+
+        /// Parameter value as NAME=VALUE (repeatable); omitted parameters use their defaults
+        #[arg(long = "param", value_name = "NAME=VALUE")]
+        params: Vec<String>,
+    },
+}
+
+/// Open the config file selected by `--config-path` (or the env/default path),
+/// unlocking it with `--config-password`, `--config-key-var`, or the keyring.
+pub(crate) fn open_config_manager(
     config_path: Option<PathBuf>,
     config_password: Option<String>,
     config_key_var: Option<String>,
-) -> Result<()> {
+) -> Result<ConfigManager> {
     // Filter out blank/whitespace-only config paths to allow fallback to env/default
     let config_path = config_path.filter(|p| !p.to_string_lossy().trim().is_empty());
 
@@ -159,11 +258,22 @@ pub fn run(
         MasterKeySource::Keyring
     };
 
-    let mut manager = if let Some(path) = config_path {
-        ConfigManager::new_with_path_and_source(path, source)?
+    if let Some(path) = config_path {
+        ConfigManager::new_with_path_and_source(path, source)
     } else {
-        ConfigManager::new_with_source(source)?
-    };
+        ConfigManager::new_with_source(source)
+    }
+}
+
+pub async fn run(
+    command: ConfigCommand,
+    output_format: &str,
+    output_file: Option<PathBuf>,
+    config_path: Option<PathBuf>,
+    config_password: Option<String>,
+    config_key_var: Option<String>,
+) -> Result<()> {
+    let mut manager = open_config_manager(config_path, config_password, config_key_var)?;
 
     match command {
         ConfigCommand::List => {
@@ -241,6 +351,24 @@ pub fn run(
             manager.rotate_key(source)?;
             println!("Encryption key rotated successfully.");
         }
+        ConfigCommand::Sync { source } => {
+            team::run_sync(&mut manager, source).await?;
+        }
+        ConfigCommand::Team => {
+            team::run_show(&manager, output_format)?;
+        }
+        ConfigCommand::Templates { command } => match command {
+            TemplatesCommand::List => templates::run_list(&manager, output_format)?,
+            TemplatesCommand::Export { file, names } => {
+                templates::run_export(&manager, &file, &names)?
+            }
+            TemplatesCommand::Import { file, replace } => {
+                templates::run_import(&mut manager, &file, replace)?
+            }
+            TemplatesCommand::Render { name, params } => {
+                templates::run_render(&manager, &name, &params)?
+            }
+        },
     }
 
     Ok(())
@@ -256,7 +384,7 @@ fn run_list(
     let format = OutputFormat::from_str(output_format)?;
     let formatter = get_formatter(format);
 
-    let output = formatter.format_profiles(profiles)?;
+    let output = formatter.format_profiles(&profiles)?;
 
     if let Some(ref path) = output_file {
         write_to_file(&output, path)
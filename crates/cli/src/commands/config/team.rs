@@ -0,0 +1,162 @@
+//! Team config bundle commands.
+//!
+//! Responsibilities:
+//! - Fetch a team bundle from a shared path or URL and cache it (`config sync`).
+//! - Show the currently cached team bundle (`config team`).
+//!
+//! Does NOT handle:
+//! - Validating bundle contents or layering them beneath personal settings
+//!   (see `splunk_config::persistence::team`).
+//!
+//! Invariants:
+//! - The source is resolved from `--source`, then `SPLUNK_TEAM_CONFIG`, then the last synced source.
+//! - An invalid bundle never replaces the cached copy.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use splunk_config::persistence::ConfigManager;
+use splunk_config::{CachedTeamBundle, TeamBundle, env_var_or_none};
+
+use crate::formatters::OutputFormat;
+
+/// Environment variable naming the default team bundle source.
+const TEAM_CONFIG_ENV: &str = "SPLUNK_TEAM_CONFIG";
+
+/// Timeout for fetching a bundle over HTTP(S).
+const FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub(super) async fn run_sync(manager: &mut ConfigManager, source: Option<String>) -> Result<()> {
+    let source = source
+        .filter(|s| !s.trim().is_empty())
+        .or_else(|| env_var_or_none(TEAM_CONFIG_ENV))
+        .or_else(|| manager.team_bundle().map(|team| team.source.clone()))
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "No team bundle source configured. Pass --source <PATH|URL> or set {}.",
+                TEAM_CONFIG_ENV
+            )
+        })?;
+
+    let content = fetch_bundle(&source).await?;
+    let bundle = TeamBundle::parse(&content)
+        .with_context(|| format!("Team bundle from {} is invalid", source))?;
+
+    let synced_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let summary = summarize(&bundle);
+    let name = bundle.name.clone();
+
+    manager.install_team_bundle(CachedTeamBundle {
+        source: source.clone(),
+        synced_at,
+        bundle,
+    })?;
+
+    match name {
+        Some(name) => println!("Synced team bundle '{}' from {}", name, source),
+        None => println!("Synced team bundle from {}", source),
+    }
+    println!("{}", summary);
+    println!("Cached at {}", manager.team_bundle_path().display());
+    Ok(())
+}
+
+pub(super) fn run_show(manager: &ConfigManager, output_format: &str) -> Result<()> {
+    let format = OutputFormat::from_str(output_format)?;
+    let Some(team) = manager.team_bundle() else {
+        println!("No team bundle synced. Run 'splunk-cli config sync --source <PATH|URL>'.");
+        return Ok(());
+    };
+
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(team)?),
+        OutputFormat::Yaml => print!("{}", serde_yaml::to_string(team)?),
+        _ => print!("{}", format_team_bundle(team)),
+    }
+    Ok(())
+}
+
+/// Read the bundle from an `http(s)://` URL, a `file://` URL, or a local path.
+async fn fetch_bundle(source: &str) -> Result<String> {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        let client = reqwest::Client::builder()
+            .timeout(FETCH_TIMEOUT)
+            .build()
+            .context("Failed to build HTTP client")?;
+        let response = client
+            .get(source)
+            .send()
+            .await
+            .with_context(|| format!("Failed to fetch team bundle from {}", source))?
+            .error_for_status()
+            .with_context(|| format!("Failed to fetch team bundle from {}", source))?;
+        return response
+            .text()
+            .await
+            .with_context(|| format!("Failed to read team bundle from {}", source));
+    }
+
+    let path = source.strip_prefix("file://").unwrap_or(source);
+    tokio::fs::read_to_string(path)
+        .await
+        .with_context(|| format!("Failed to read team bundle from {}", path))
+}
+
+fn summarize(bundle: &TeamBundle) -> String {
+    let mut parts = vec![
+        format!("{} profile(s)", bundle.profiles.len()),
+        format!("{} search template(s)", bundle.search_templates.len()),
+        format!(
+            "{} keybinding override(s)",
+            bundle.keybind_overrides.overrides.len()
+        ),
+    ];
+    if let Some(theme) = bundle.theme {
+        parts.push(format!("theme {}", theme));
+    }
+    parts.join(", ")
+}
+
+fn format_team_bundle(team: &CachedTeamBundle) -> String {
+    let bundle = &team.bundle;
+    let mut out = format!(
+        "Team bundle: {}\n",
+        bundle.name.as_deref().unwrap_or("(unnamed)")
+    );
+    if let Some(description) = &bundle.description {
+        out.push_str(&format!("Description: {}\n", description));
+    }
+    out.push_str(&format!("Source: {}\n", team.source));
+    let synced = chrono::DateTime::from_timestamp(team.synced_at as i64, 0)
+        .map(|t| t.to_rfc3339())
+        .unwrap_or_else(|| team.synced_at.to_string());
+    out.push_str(&format!("Synced: {}\n", synced));
+    out.push_str(&format!("Contents: {}\n", summarize(bundle)));
+
+    if !bundle.profiles.is_empty() {
+        out.push_str("\nProfiles:\n");
+        for (name, profile) in &bundle.profiles {
+            out.push_str(&format!(
+                "  {:<20} {}\n",
+                name,
+                profile.base_url.as_deref().unwrap_or("-")
+            ));
+        }
+    }
+    if !bundle.search_templates.is_empty() {
+        out.push_str("\nSearch templates:\n");
+        for template in &bundle.search_templates {
+            out.push_str(&format!("  {:<20} {}\n", template.name, template.query));
+        }
+    }
+    if !bundle.keybind_overrides.is_empty() {
+        out.push_str("\nKeybindings:\n");
+        for (action, key) in &bundle.keybind_overrides.overrides {
+            out.push_str(&format!("  {:<20} {}\n", action.to_string(), key));
+        }
+    }
+    out
+}
@@ -0,0 +1,196 @@
+//! Search template library commands.
+//!
+//! Responsibilities:
+//! - List personal and team search templates with their parameter schemas (`config templates list`).
+//! - Export templates to a shareable file and import them into the personal library.
+//! - Render a template into SPL from `--param NAME=VALUE` values (`config templates render`).
+//!
+//! Does NOT handle:
+//! - Template validation or storage (see `splunk_config::persistence::templates`).
+//! - Saving or running a template (see `splunk-cli templates save|run`).
+//!
+//! Invariants:
+//! - An invalid template file is rejected as a whole; nothing is imported from it.
+//! - Personal templates shadow team templates with the same name.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use splunk_config::persistence::ConfigManager;
+use splunk_config::{SearchTemplate, SearchTemplateFile};
+
+use crate::formatters::OutputFormat;
+
+/// Where a listed template comes from.
+#[derive(Serialize)]
+struct ListedTemplate<'a> {
+    source: &'static str,
+    #[serde(flatten)]
+    template: &'a SearchTemplate,
+}
+
+pub(super) fn run_list(manager: &ConfigManager, output_format: &str) -> Result<()> {
+    let format = OutputFormat::from_str(output_format)?;
+    let templates = manager.search_templates();
+    let listed: Vec<ListedTemplate> = templates
+        .iter()
+        .map(|template| ListedTemplate {
+            source: template_source(manager, &template.name),
+            template,
+        })
+        .collect();
+
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&listed)?),
+        OutputFormat::Yaml => print!("{}", serde_yaml::to_string(&listed)?),
+        _ if listed.is_empty() => println!(
+            "No search templates. Import a file with 'splunk-cli config templates import <FILE>' or run 'splunk-cli config sync'."
+        ),
+        _ => print!("{}", format_templates(&listed)),
+    }
+    Ok(())
+}
+
+pub(super) fn run_export(manager: &ConfigManager, file: &Path, names: &[String]) -> Result<()> {
+    let mut templates = manager.search_templates();
+    if !names.is_empty() {
+        if let Some(missing) = names
+            .iter()
+            .find(|name| !templates.iter().any(|t| &t.name == *name))
+        {
+            anyhow::bail!("Search template '{}' not found", missing);
+        }
+        templates.retain(|template| names.contains(&template.name));
+    }
+    if templates.is_empty() {
+        anyhow::bail!("No search templates to export");
+    }
+
+    let count = templates.len();
+    let content = serde_json::to_string_pretty(&SearchTemplateFile::new(templates))?;
+    std::fs::write(file, content + "\n")
+        .with_context(|| format!("Failed to write {}", file.display()))?;
+    println!(
+        "Exported {} search template(s) to {}",
+        count,
+        file.display()
+    );
+    Ok(())
+}
+
+pub(super) fn run_import(manager: &mut ConfigManager, file: &Path, replace: bool) -> Result<()> {
+    let content = std::fs::read_to_string(file)
+        .with_context(|| format!("Failed to read {}", file.display()))?;
+    let template_file = SearchTemplateFile::parse(&content)
+        .with_context(|| format!("Search template file {} is invalid", file.display()))?;
+
+    let summary = manager.import_search_templates(template_file.templates, replace)?;
+    println!(
+        "Imported {} new and {} replaced search template(s) into {}",
+        summary.added.len(),
+        summary.replaced.len(),
+        manager.search_templates_path().display()
+    );
+    if !summary.skipped.is_empty() {
+        println!(
+            "Skipped {} existing template(s) (use --replace to overwrite): {}",
+            summary.skipped.len(),
+            summary.skipped.join(", ")
+        );
+    }
+    Ok(())
+}
+
+pub(super) fn run_render(manager: &ConfigManager, name: &str, params: &[String]) -> Result<()> {
+    println!("{}", render_named_template(manager, name, params)?);
+    Ok(())
+}
+
+/// Render the personal or team template `name` from `NAME=VALUE` parameters.
+pub(crate) fn render_named_template(
+    manager: &ConfigManager,
+    name: &str,
+    params: &[String],
+) -> Result<String> {
+    let template = manager
+        .search_templates()
+        .into_iter()
+        .find(|template| template.name == name)
+        .ok_or_else(|| anyhow::anyhow!("Search template '{}' not found", name))?;
+
+    let values = parse_params(params)?;
+    template
+        .render(&values)
+        .with_context(|| format!("Failed to render search template '{}'", name))
+}
+
+/// Parse `NAME=VALUE` pairs; later values for the same name win.
+pub(crate) fn parse_params(params: &[String]) -> Result<BTreeMap<String, String>> {
+    params
+        .iter()
+        .map(|param| {
+            param
+                .split_once('=')
+                .map(|(name, value)| (name.trim().to_string(), value.to_string()))
+                .ok_or_else(|| anyhow::anyhow!("Invalid --param '{}': expected NAME=VALUE", param))
+        })
+        .collect()
+}
+
+fn template_source(manager: &ConfigManager, name: &str) -> &'static str {
+    if manager
+        .personal_search_templates()
+        .iter()
+        .any(|template| template.name == name)
+    {
+        "personal"
+    } else {
+        "team"
+    }
+}
+
+fn format_templates(listed: &[ListedTemplate]) -> String {
+    let mut out = String::new();
+    for (i, entry) in listed.iter().enumerate() {
+        let template = entry.template;
+        if i > 0 {
+            out.push('\n');
+        }
+        out.push_str(&format!("{} ({})\n", template.name, entry.source));
+        if let Some(description) = &template.description {
+            out.push_str(&format!("  {}\n", description));
+        }
+        out.push_str(&format!("  Query: {}\n", template.query));
+        for parameter in &template.parameters {
+            let default = parameter
+                .default
+                .as_deref()
+                .map_or_else(|| "required".to_string(), |d| format!("default {}", d));
+            out.push_str(&format!(
+                "  ${}$ ({}, {})",
+                parameter.name, parameter.kind, default
+            ));
+            if let Some(description) = &parameter.description {
+                out.push_str(&format!(" - {}", description));
+            }
+            out.push('\n');
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_params() {
+        let values = parse_params(&["index=main".to_string(), "filter=a=b".to_string()]).unwrap();
+        assert_eq!(values["index"], "main");
+        assert_eq!(values["filter"], "a=b");
+
+        assert!(parse_params(&["index".to_string()]).is_err());
+    }
+}
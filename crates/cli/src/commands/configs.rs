@@ -4,6 +4,9 @@
 //! - List available configuration files (e.g., props, transforms, indexes)
 //! - List configuration stanzas for a specific config file
 //! - View detailed configuration for specific stanzas
+//! - Resolve effective configuration with per-key provenance (btool-style)
+//! - Audit knowledge bundle replication for large lookups (distsearch.conf)
+//! - Back up every stanza to `.conf` files and diff against a previous backup
 //! - Support pagination for large config file listings
 //! - Format output via shared formatters
 //!
@@ -25,6 +28,10 @@ use crate::formatters::{
 };
 use splunk_config::constants::*;
 
+mod bundle_audit;
+mod dump;
+mod effective;
+
 /// Configs subcommands.
 #[derive(Subcommand)]
 pub enum ConfigsCommand {
@@ -51,6 +58,54 @@ pub enum ConfigsCommand {
         /// Stanza name to view
         stanza_name: String,
     },
+
+    /// Show effective settings and the context each came from (like `btool --debug`)
+    Effective {
+        /// Configuration file name (e.g., "props", "transforms")
+        config_file: String,
+
+        /// Only resolve this stanza (all stanzas if omitted)
+        stanza_name: Option<String>,
+
+        /// Resolve in this app's context; its settings outrank other apps
+        #[arg(long)]
+        app: Option<String>,
+
+        /// Include this user's private settings
+        #[arg(long)]
+        user: Option<String>,
+    },
+
+    /// Audit knowledge bundle replication for large lookups (distsearch.conf allow/deny lists)
+    #[command(after_help = "Examples:
+  splunk-cli configs bundle-audit
+  splunk-cli configs bundle-audit --min-size-mb 50 -o json
+")]
+    BundleAudit {
+        /// Report lookups at least this large, in MB
+        #[arg(long, default_value_t = splunk_client::workflows::bundle_audit::DEFAULT_MIN_LOOKUP_SIZE_MB)]
+        min_size_mb: u64,
+    },
+
+    /// Back up every stanza to .conf files per app, optionally diffing against a previous backup
+    #[command(after_help = "Examples:
+  splunk-cli configs dump --out ./confbackup
+  splunk-cli configs dump --out ./confbackup-new --diff ./confbackup
+  splunk-cli configs dump --out ./props-only --config-file props --config-file transforms
+")]
+    Dump {
+        /// Directory to write the backup to (must be missing or empty)
+        #[arg(long)]
+        out: std::path::PathBuf,
+
+        /// Previous backup directory to report changes against
+        #[arg(long, value_name = "PREVIOUS")]
+        diff: Option<std::path::PathBuf>,
+
+        /// Only back up this config file (repeatable; all config files if omitted)
+        #[arg(long = "config-file", value_name = "CONFIG_FILE")]
+        config_files: Vec<String>,
+    },
 }
 
 /// Run the configs command.
@@ -109,6 +164,60 @@ pub async fn run(
             )
             .await
         }
+        ConfigsCommand::Effective {
+            config_file,
+            stanza_name,
+            app,
+            user,
+        } => {
+            let request = splunk_client::workflows::effective_config::EffectiveConfigRequest {
+                config_file,
+                stanza: stanza_name,
+                app,
+                user,
+            };
+            effective::run_effective(
+                config,
+                request,
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
+        ConfigsCommand::BundleAudit { min_size_mb } => {
+            let request = splunk_client::workflows::bundle_audit::BundleAuditRequest {
+                min_size_bytes: min_size_mb.saturating_mul(1024 * 1024),
+            };
+            bundle_audit::run_bundle_audit(
+                config,
+                request,
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
+        ConfigsCommand::Dump {
+            out,
+            diff,
+            config_files,
+        } => {
+            let request = splunk_client::workflows::config_dump::ConfigDumpRequest { config_files };
+            dump::run_dump(
+                config,
+                request,
+                out,
+                diff,
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
     }
 }
 
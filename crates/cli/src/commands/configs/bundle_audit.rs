@@ -0,0 +1,331 @@
+//! Knowledge bundle replication audit for the configs command.
+//!
+//! Responsibilities:
+//! - Run the shared bundle audit workflow.
+//! - Format the replication rules and large lookups in every output format.
+//!
+//! Does NOT handle:
+//! - Rule matching or size estimates (lives in `splunk-client::workflows::bundle_audit`).
+//!
+//! Invariants:
+//! - Row-oriented formats (CSV, NDJSON) emit one row per audited lookup.
+
+use anyhow::Result;
+use splunk_client::format_bytes;
+use splunk_client::workflows::bundle_audit::{
+    BundleAuditReport, BundleAuditRequest, audit_bundle_replication,
+};
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+pub(super) async fn run_bundle_audit(
+    config: splunk_config::Config,
+    request: BundleAuditRequest,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!(
+        "Auditing knowledge bundle replication (min lookup size: {} bytes)",
+        request.min_size_bytes
+    );
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let report = cancellable!(audit_bundle_replication(&client, &request), cancel)?;
+
+    let output = format_bundle_audit(&report, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+/// Format a bundle audit report based on the selected format.
+pub fn format_bundle_audit(report: &BundleAuditReport, format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(report)?),
+        OutputFormat::Table => Ok(format_table(report)),
+        OutputFormat::Csv => Ok(format_csv(report)),
+        OutputFormat::Xml => Ok(format_xml(report)),
+        OutputFormat::Ndjson => format_ndjson(report),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(report)?),
+        OutputFormat::Markdown => Ok(format_markdown(report)),
+    }
+}
+
+fn size(bytes: u64) -> String {
+    format_bytes(usize::try_from(bytes).unwrap_or(usize::MAX))
+}
+
+fn mb_setting(value: Option<u64>) -> String {
+    value
+        .map(|mb| format!("{} MB", mb))
+        .unwrap_or_else(|| "(default)".to_string())
+}
+
+fn format_table(report: &BundleAuditReport) -> String {
+    let mut out = String::from("Knowledge Bundle Replication Audit\n");
+    out.push_str(&format!(
+        "maxBundleSize: {}  excludeReplicatedLookupSize: {}\n",
+        mb_setting(report.max_bundle_size_mb),
+        mb_setting(report.exclude_replicated_lookup_size_mb)
+    ));
+    out.push_str(&format!(
+        "Lookups: {} total, {} replicated\n",
+        report.total_lookups,
+        size(report.replicated_lookup_bytes)
+    ));
+    if report.exceeds_max_bundle_size() {
+        out.push_str(
+            "WARNING: replicated lookups alone exceed maxBundleSize; bundle pushes will fail\n",
+        );
+    }
+
+    out.push_str("\nDeny list:\n");
+    if report.deny_rules.is_empty() {
+        out.push_str("  (none)\n");
+    }
+    for rule in &report.deny_rules {
+        out.push_str(&format!(
+            "  [{}] {} = {}\n",
+            rule.stanza, rule.name, rule.pattern
+        ));
+    }
+
+    out.push_str(&format!("\nLookups >= {}:\n", size(report.min_size_bytes)));
+    if report.lookups.is_empty() {
+        out.push_str("  None found.\n");
+        return out;
+    }
+    for lookup in &report.lookups {
+        let flag = if lookup.is_flagged(report.min_size_bytes) {
+            "!"
+        } else {
+            " "
+        };
+        out.push_str(&format!(
+            "{} {:>10}  {:<13}  {}",
+            flag,
+            size(lookup.size_bytes),
+            lookup.status,
+            lookup.path
+        ));
+        if let Some(rule) = &lookup.rule {
+            out.push_str(&format!("  ({})", rule));
+        }
+        out.push('\n');
+    }
+
+    let suggestions: Vec<&str> = report
+        .flagged()
+        .filter_map(|lookup| lookup.suggested_denylist_entry.as_deref())
+        .collect();
+    if !suggestions.is_empty() {
+        out.push_str("\nSuggested distsearch.conf entries for lookups search peers don't need:\n");
+        out.push_str("[replicationDenylist]\n");
+        for suggestion in suggestions {
+            out.push_str(suggestion);
+            out.push('\n');
+        }
+    }
+
+    out
+}
+
+fn format_csv(report: &BundleAuditReport) -> String {
+    let mut csv = String::from(
+        "path,app,owner,sharing,size_bytes,status,rule,flagged,suggested_denylist_entry\n",
+    );
+    for lookup in &report.lookups {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{}\n",
+            escape_csv(&lookup.path),
+            escape_csv(&lookup.app),
+            escape_csv(&lookup.owner),
+            escape_csv(&lookup.sharing),
+            lookup.size_bytes,
+            lookup.status,
+            escape_csv(lookup.rule.as_deref().unwrap_or("")),
+            lookup.is_flagged(report.min_size_bytes),
+            escape_csv(lookup.suggested_denylist_entry.as_deref().unwrap_or(""))
+        ));
+    }
+    csv
+}
+
+fn format_xml(report: &BundleAuditReport) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<bundle_audit>\n");
+    xml.push_str(&format!(
+        "  <min_size_bytes>{}</min_size_bytes>\n",
+        report.min_size_bytes
+    ));
+    if let Some(max) = report.max_bundle_size_mb {
+        xml.push_str(&format!(
+            "  <max_bundle_size_mb>{}</max_bundle_size_mb>\n",
+            max
+        ));
+    }
+    if let Some(limit) = report.exclude_replicated_lookup_size_mb {
+        xml.push_str(&format!(
+            "  <exclude_replicated_lookup_size_mb>{}</exclude_replicated_lookup_size_mb>\n",
+            limit
+        ));
+    }
+    xml.push_str(&format!(
+        "  <total_lookups>{}</total_lookups>\n",
+        report.total_lookups
+    ));
+    xml.push_str(&format!(
+        "  <replicated_lookup_bytes>{}</replicated_lookup_bytes>\n",
+        report.replicated_lookup_bytes
+    ));
+    xml.push_str("  <rules>\n");
+    for (kind, rules) in [("allow", &report.allow_rules), ("deny", &report.deny_rules)] {
+        for rule in rules {
+            xml.push_str(&format!(
+                "    <rule kind=\"{}\" stanza=\"{}\" name=\"{}\">{}</rule>\n",
+                kind,
+                escape_xml(&rule.stanza),
+                escape_xml(&rule.name),
+                escape_xml(&rule.pattern)
+            ));
+        }
+    }
+    xml.push_str("  </rules>\n");
+    xml.push_str("  <lookups>\n");
+    for lookup in &report.lookups {
+        xml.push_str(&format!(
+            "    <lookup status=\"{}\" flagged=\"{}\">\n",
+            lookup.status,
+            lookup.is_flagged(report.min_size_bytes)
+        ));
+        xml.push_str(&format!(
+            "      <path>{}</path>\n",
+            escape_xml(&lookup.path)
+        ));
+        xml.push_str(&format!("      <app>{}</app>\n", escape_xml(&lookup.app)));
+        xml.push_str(&format!(
+            "      <owner>{}</owner>\n",
+            escape_xml(&lookup.owner)
+        ));
+        xml.push_str(&format!(
+            "      <size_bytes>{}</size_bytes>\n",
+            lookup.size_bytes
+        ));
+        if let Some(rule) = &lookup.rule {
+            xml.push_str(&format!("      <rule>{}</rule>\n", escape_xml(rule)));
+        }
+        if let Some(suggestion) = &lookup.suggested_denylist_entry {
+            xml.push_str(&format!(
+                "      <suggested_denylist_entry>{}</suggested_denylist_entry>\n",
+                escape_xml(suggestion)
+            ));
+        }
+        xml.push_str("    </lookup>\n");
+    }
+    xml.push_str("  </lookups>\n");
+    xml.push_str("</bundle_audit>");
+    xml
+}
+
+fn format_ndjson(report: &BundleAuditReport) -> Result<String> {
+    let mut ndjson = String::new();
+    for lookup in &report.lookups {
+        ndjson.push_str(&serde_json::to_string(lookup)?);
+        ndjson.push('\n');
+    }
+    Ok(ndjson)
+}
+
+fn format_markdown(report: &BundleAuditReport) -> String {
+    let mut md = String::from("# Knowledge Bundle Replication Audit\n\n");
+    md.push_str(&format!(
+        "- **maxBundleSize**: {}\n",
+        mb_setting(report.max_bundle_size_mb)
+    ));
+    md.push_str(&format!(
+        "- **excludeReplicatedLookupSize**: {}\n",
+        mb_setting(report.exclude_replicated_lookup_size_mb)
+    ));
+    md.push_str(&format!(
+        "- **Replicated lookups**: {} across {} lookups\n\n",
+        size(report.replicated_lookup_bytes),
+        report.total_lookups
+    ));
+
+    if report.lookups.is_empty() {
+        md.push_str("_No lookups at or above the size threshold._\n");
+        return md;
+    }
+    md.push_str("| Path | Size | Status | Rule | Suggested deny entry |\n");
+    md.push_str("|------|------|--------|------|----------------------|\n");
+    for lookup in &report.lookups {
+        md.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            lookup.path,
+            size(lookup.size_bytes),
+            lookup.status,
+            lookup.rule.as_deref().unwrap_or(""),
+            lookup
+                .suggested_denylist_entry
+                .as_deref()
+                .map(|s| format!("`{}`", s))
+                .unwrap_or_default()
+        ));
+    }
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use splunk_client::workflows::bundle_audit::{BundleLookup, ReplicationStatus};
+
+    fn report() -> BundleAuditReport {
+        BundleAuditReport {
+            min_size_bytes: 10 * 1024 * 1024,
+            max_bundle_size_mb: Some(2048),
+            exclude_replicated_lookup_size_mb: None,
+            allow_rules: vec![],
+            deny_rules: vec![],
+            total_lookups: 4,
+            replicated_lookup_bytes: 64 * 1024 * 1024,
+            lookups: vec![BundleLookup {
+                name: "threat_intel.csv".to_string(),
+                app: "ES".to_string(),
+                owner: "nobody".to_string(),
+                sharing: "global".to_string(),
+                path: "apps/ES/lookups/threat_intel.csv".to_string(),
+                size_bytes: 60 * 1024 * 1024,
+                status: ReplicationStatus::Replicated,
+                rule: None,
+                suggested_denylist_entry: Some(
+                    r"ES_threat_intel_csv = apps/ES/lookups/threat_intel\.csv".to_string(),
+                ),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_table_flags_lookup_and_prints_denylist_stanza() {
+        let output = format_bundle_audit(&report(), OutputFormat::Table).unwrap();
+        assert!(output.contains("!    60.0 MB  replicated     apps/ES/lookups/threat_intel.csv"));
+        assert!(output.contains(
+            "[replicationDenylist]\nES_threat_intel_csv = apps/ES/lookups/threat_intel\\.csv"
+        ));
+        assert!(!output.contains("WARNING"));
+    }
+
+    #[test]
+    fn test_csv_has_one_row_per_lookup() {
+        let output = format_bundle_audit(&report(), OutputFormat::Csv).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].starts_with(
+            "apps/ES/lookups/threat_intel.csv,ES,nobody,global,62914560,replicated,,true,"
+        ));
+    }
+}
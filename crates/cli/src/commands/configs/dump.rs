@@ -0,0 +1,316 @@
+//! Config backup (`configs dump`) for the configs command.
+//!
+//! Responsibilities:
+//! - Run the shared config dump workflow and write the `.conf` files.
+//! - Optionally diff the new dump against a previous one.
+//! - Format the dump summary and changes in every output format.
+//!
+//! Does NOT handle:
+//! - Fetching, file layout, or diffing (lives in `splunk-client::workflows::config_dump`).
+//!
+//! Invariants:
+//! - The new dump is written before it is diffed, so the diff reflects what is on disk.
+//! - Row-oriented formats (CSV, NDJSON) emit one row per change with `--diff`,
+//!   otherwise a single summary row.
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use serde::Serialize;
+use splunk_client::workflows::config_dump::{
+    ConfigChange, ConfigChangeKind, ConfigDump, ConfigDumpRequest, ConfigDumpSummary,
+    collect_config_dump, diff_config_dumps,
+};
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+/// Summary of a dump and, with `--diff`, its changes since a previous dump.
+#[derive(Debug, Serialize)]
+pub struct ConfigDumpReport {
+    #[serde(flatten)]
+    pub summary: ConfigDumpSummary,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<Vec<ConfigChange>>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn run_dump(
+    config: splunk_config::Config,
+    request: ConfigDumpRequest,
+    out: PathBuf,
+    previous: Option<PathBuf>,
+    output_format: &str,
+    output_file: Option<PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Dumping config stanzas to {}", out.display());
+
+    let format = OutputFormat::from_str(output_format)?;
+    // Read the previous dump first so a bad path fails before anything is fetched.
+    let previous_dump = previous.as_deref().map(ConfigDump::read).transpose()?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let dump = cancellable!(collect_config_dump(&client, &request, Some(cancel)), cancel)?;
+    let summary = dump.write(&out)?;
+
+    let changes = match previous_dump {
+        Some(previous_dump) => Some(diff_config_dumps(&previous_dump, &ConfigDump::read(&out)?)),
+        None => None,
+    };
+    let report = ConfigDumpReport {
+        summary,
+        previous: previous.map(|path| path.display().to_string()),
+        changes,
+    };
+
+    let output = format_config_dump(&report, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+/// Format a config dump report based on the selected format.
+pub fn format_config_dump(report: &ConfigDumpReport, format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(report)?),
+        OutputFormat::Table => Ok(format_table(report)),
+        OutputFormat::Csv => Ok(format_csv(report)),
+        OutputFormat::Xml => Ok(format_xml(report)),
+        OutputFormat::Ndjson => format_ndjson(report),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(report)?),
+        OutputFormat::Markdown => Ok(format_markdown(report)),
+    }
+}
+
+fn summary_line(summary: &ConfigDumpSummary) -> String {
+    format!(
+        "Dumped {} stanzas ({} settings) in {} files to {}",
+        summary.stanzas, summary.settings, summary.files, summary.out_dir
+    )
+}
+
+/// One-line description of a change, e.g. `[syslog] TZ: UTC -> EST`.
+fn describe(change: &ConfigChange) -> String {
+    let mut line = format!("[{}]", change.stanza);
+    if let Some(key) = &change.key {
+        line.push(' ');
+        line.push_str(key);
+        match (&change.previous, &change.current) {
+            (Some(previous), Some(current)) => {
+                line.push_str(&format!(": {} -> {}", previous, current))
+            }
+            (Some(value), None) | (None, Some(value)) => line.push_str(&format!(" = {}", value)),
+            (None, None) => {}
+        }
+    }
+    line
+}
+
+fn format_table(report: &ConfigDumpReport) -> String {
+    let mut out = summary_line(&report.summary);
+    out.push('\n');
+
+    let (Some(previous), Some(changes)) = (&report.previous, &report.changes) else {
+        return out;
+    };
+    out.push_str(&format!(
+        "\nChanges since {}: {}\n",
+        previous,
+        changes.len()
+    ));
+    if changes.is_empty() {
+        out.push_str("  No changes.\n");
+    }
+    let mut current_file = None;
+    for change in changes {
+        if current_file != Some(&change.file) {
+            out.push_str(&format!("{}\n", change.file));
+            current_file = Some(&change.file);
+        }
+        out.push_str(&format!(
+            "  {} {}\n",
+            change.change.marker(),
+            describe(change)
+        ));
+    }
+    out
+}
+
+fn format_csv(report: &ConfigDumpReport) -> String {
+    let Some(changes) = &report.changes else {
+        let summary = &report.summary;
+        return format!(
+            "out_dir,files,stanzas,settings\n{},{},{},{}\n",
+            escape_csv(&summary.out_dir),
+            summary.files,
+            summary.stanzas,
+            summary.settings
+        );
+    };
+
+    let mut csv = String::from("file,stanza,key,change,previous,current\n");
+    for change in changes {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            escape_csv(&change.file),
+            escape_csv(&change.stanza),
+            escape_csv(change.key.as_deref().unwrap_or("")),
+            change_name(change),
+            escape_csv(change.previous.as_deref().unwrap_or("")),
+            escape_csv(change.current.as_deref().unwrap_or(""))
+        ));
+    }
+    csv
+}
+
+fn change_name(change: &ConfigChange) -> &'static str {
+    match change.change {
+        ConfigChangeKind::Added => "added",
+        ConfigChangeKind::Removed => "removed",
+        ConfigChangeKind::Changed => "changed",
+    }
+}
+
+fn format_xml(report: &ConfigDumpReport) -> String {
+    let summary = &report.summary;
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<config_dump>\n");
+    xml.push_str(&format!(
+        "  <out_dir>{}</out_dir>\n",
+        escape_xml(&summary.out_dir)
+    ));
+    xml.push_str(&format!("  <files>{}</files>\n", summary.files));
+    xml.push_str(&format!("  <stanzas>{}</stanzas>\n", summary.stanzas));
+    xml.push_str(&format!("  <settings>{}</settings>\n", summary.settings));
+    if let (Some(previous), Some(changes)) = (&report.previous, &report.changes) {
+        xml.push_str(&format!(
+            "  <changes previous=\"{}\">\n",
+            escape_xml(previous)
+        ));
+        for change in changes {
+            xml.push_str(&format!(
+                "    <change kind=\"{}\" file=\"{}\" stanza=\"{}\"",
+                change_name(change),
+                escape_xml(&change.file),
+                escape_xml(&change.stanza)
+            ));
+            if let Some(key) = &change.key {
+                xml.push_str(&format!(" key=\"{}\"", escape_xml(key)));
+            }
+            xml.push_str(">\n");
+            if let Some(previous) = &change.previous {
+                xml.push_str(&format!(
+                    "      <previous>{}</previous>\n",
+                    escape_xml(previous)
+                ));
+            }
+            if let Some(current) = &change.current {
+                xml.push_str(&format!(
+                    "      <current>{}</current>\n",
+                    escape_xml(current)
+                ));
+            }
+            xml.push_str("    </change>\n");
+        }
+        xml.push_str("  </changes>\n");
+    }
+    xml.push_str("</config_dump>");
+    xml
+}
+
+fn format_ndjson(report: &ConfigDumpReport) -> Result<String> {
+    let Some(changes) = &report.changes else {
+        return Ok(format!("{}\n", serde_json::to_string(&report.summary)?));
+    };
+    let mut ndjson = String::new();
+    for change in changes {
+        ndjson.push_str(&serde_json::to_string(change)?);
+        ndjson.push('\n');
+    }
+    Ok(ndjson)
+}
+
+fn format_markdown(report: &ConfigDumpReport) -> String {
+    let mut md = String::from("# Config Dump\n\n");
+    md.push_str(&format!("{}\n", summary_line(&report.summary)));
+
+    let (Some(previous), Some(changes)) = (&report.previous, &report.changes) else {
+        return md;
+    };
+    md.push_str(&format!("\n## Changes since `{}`\n\n", previous));
+    if changes.is_empty() {
+        md.push_str("_No changes._\n");
+        return md;
+    }
+    md.push_str("| Change | File | Stanza | Key | Previous | Current |\n");
+    md.push_str("|--------|------|--------|-----|----------|---------|\n");
+    for change in changes {
+        md.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} |\n",
+            change_name(change),
+            change.file,
+            change.stanza,
+            change.key.as_deref().unwrap_or(""),
+            change.previous.as_deref().unwrap_or("").replace('|', "\\|"),
+            change.current.as_deref().unwrap_or("").replace('|', "\\|")
+        ));
+    }
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(changes: Option<Vec<ConfigChange>>) -> ConfigDumpReport {
+        ConfigDumpReport {
+            summary: ConfigDumpSummary {
+                out_dir: "./confbackup".to_string(),
+                files: 2,
+                stanzas: 3,
+                settings: 7,
+            },
+            previous: changes.as_ref().map(|_| "./previous".to_string()),
+            changes,
+        }
+    }
+
+    fn changed() -> ConfigChange {
+        ConfigChange {
+            file: "apps/search/props.conf".to_string(),
+            stanza: "syslog".to_string(),
+            key: Some("TZ".to_string()),
+            change: ConfigChangeKind::Changed,
+            previous: Some("UTC".to_string()),
+            current: Some("EST".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_table_lists_changes_grouped_by_file() {
+        let output =
+            format_config_dump(&report(Some(vec![changed()])), OutputFormat::Table).unwrap();
+        assert!(output.starts_with("Dumped 3 stanzas (7 settings) in 2 files to ./confbackup\n"));
+        assert!(output.contains("Changes since ./previous: 1\napps/search/props.conf\n"));
+        assert!(output.contains("  ~ [syslog] TZ: UTC -> EST\n"));
+    }
+
+    #[test]
+    fn test_csv_rows_are_changes_only_with_diff() {
+        let summary = format_config_dump(&report(None), OutputFormat::Csv).unwrap();
+        assert_eq!(
+            summary,
+            "out_dir,files,stanzas,settings\n./confbackup,2,3,7\n"
+        );
+
+        let changes =
+            format_config_dump(&report(Some(vec![changed()])), OutputFormat::Csv).unwrap();
+        assert_eq!(
+            changes.lines().nth(1),
+            Some("apps/search/props.conf,syslog,TZ,changed,UTC,EST")
+        );
+    }
+}
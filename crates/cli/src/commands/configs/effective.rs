@@ -0,0 +1,251 @@
+//! Effective configuration resolution for the configs command.
+//!
+//! Responsibilities:
+//! - Run the shared effective-config workflow for a conf file or stanza.
+//! - Format the merged settings and their provenance in every output format.
+//!
+//! Does NOT handle:
+//! - Layer fetching or merge precedence (lives in `splunk-client::workflows::effective_config`).
+//!
+//! Invariants:
+//! - The table output mirrors `btool --debug`: each line starts with the layer
+//!   that supplied the value, and shadowed values are listed beneath it.
+
+use anyhow::Result;
+use splunk_client::workflows::effective_config::{
+    EffectiveConfigReport, EffectiveConfigRequest, resolve_effective_config,
+};
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+pub(super) async fn run_effective(
+    config: splunk_config::Config,
+    request: EffectiveConfigRequest,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!(
+        "Resolving effective config for '{}' (stanza: {:?}, app: {:?}, user: {:?})",
+        request.config_file, request.stanza, request.app, request.user
+    );
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let report = cancellable!(resolve_effective_config(&client, &request), cancel)?;
+
+    let output = format_effective_report(&report, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+/// Format an effective-config report based on the selected format.
+pub fn format_effective_report(
+    report: &EffectiveConfigReport,
+    format: OutputFormat,
+) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(report)?),
+        OutputFormat::Table => Ok(format_table(report)),
+        OutputFormat::Csv => Ok(format_csv(report)),
+        OutputFormat::Xml => Ok(format_xml(report)),
+        OutputFormat::Ndjson => format_ndjson(report),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(report)?),
+        OutputFormat::Markdown => Ok(format_markdown(report)),
+    }
+}
+
+fn context_label(report: &EffectiveConfigReport) -> String {
+    format!(
+        "app: {}, user: {}",
+        report.app.as_deref().unwrap_or("(global)"),
+        report.user.as_deref().unwrap_or("(none)")
+    )
+}
+
+fn format_table(report: &EffectiveConfigReport) -> String {
+    let mut out = format!(
+        "Effective {}.conf ({})\n",
+        report.config_file,
+        context_label(report)
+    );
+
+    if report.settings.is_empty() {
+        out.push_str("\nNo settings found.\n");
+        return out;
+    }
+
+    let width = report
+        .settings
+        .iter()
+        .flat_map(|s| std::iter::once(&s.source).chain(s.overridden.iter().map(|o| &o.source)))
+        .map(String::len)
+        .max()
+        .unwrap_or(0);
+
+    let mut current_stanza: Option<&str> = None;
+    for setting in &report.settings {
+        if current_stanza != Some(setting.stanza.as_str()) {
+            out.push_str(&format!("\n[{}]\n", setting.stanza));
+            current_stanza = Some(&setting.stanza);
+        }
+        out.push_str(&format!(
+            "{:<width$}  {} = {}\n",
+            setting.source, setting.key, setting.value
+        ));
+        for overridden in &setting.overridden {
+            out.push_str(&format!(
+                "{:<width$}    (overrides {} = {})\n",
+                overridden.source, setting.key, overridden.value
+            ));
+        }
+    }
+
+    out
+}
+
+fn format_csv(report: &EffectiveConfigReport) -> String {
+    let mut csv = String::from("stanza,key,value,scope,source,overridden_sources\n");
+
+    for setting in &report.settings {
+        let overridden = setting
+            .overridden
+            .iter()
+            .map(|o| o.source.as_str())
+            .collect::<Vec<_>>()
+            .join(";");
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            escape_csv(&setting.stanza),
+            escape_csv(&setting.key),
+            escape_csv(&setting.value),
+            setting.scope,
+            escape_csv(&setting.source),
+            escape_csv(&overridden)
+        ));
+    }
+
+    csv
+}
+
+fn format_xml(report: &EffectiveConfigReport) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<effective_config>\n");
+    xml.push_str(&format!(
+        "  <config_file>{}</config_file>\n",
+        escape_xml(&report.config_file)
+    ));
+    if let Some(ref app) = report.app {
+        xml.push_str(&format!("  <app>{}</app>\n", escape_xml(app)));
+    }
+    if let Some(ref user) = report.user {
+        xml.push_str(&format!("  <user>{}</user>\n", escape_xml(user)));
+    }
+    xml.push_str("  <settings>\n");
+    for setting in &report.settings {
+        xml.push_str("    <setting>\n");
+        xml.push_str(&format!(
+            "      <stanza>{}</stanza>\n",
+            escape_xml(&setting.stanza)
+        ));
+        xml.push_str(&format!("      <key>{}</key>\n", escape_xml(&setting.key)));
+        xml.push_str(&format!(
+            "      <value>{}</value>\n",
+            escape_xml(&setting.value)
+        ));
+        xml.push_str(&format!("      <scope>{}</scope>\n", setting.scope));
+        xml.push_str(&format!(
+            "      <source>{}</source>\n",
+            escape_xml(&setting.source)
+        ));
+        for overridden in &setting.overridden {
+            xml.push_str(&format!(
+                "      <overridden source=\"{}\">{}</overridden>\n",
+                escape_xml(&overridden.source),
+                escape_xml(&overridden.value)
+            ));
+        }
+        xml.push_str("    </setting>\n");
+    }
+    xml.push_str("  </settings>\n");
+    xml.push_str("</effective_config>");
+    xml
+}
+
+fn format_ndjson(report: &EffectiveConfigReport) -> Result<String> {
+    let mut ndjson = String::new();
+    for setting in &report.settings {
+        ndjson.push_str(&serde_json::to_string(setting)?);
+        ndjson.push('\n');
+    }
+    Ok(ndjson)
+}
+
+fn format_markdown(report: &EffectiveConfigReport) -> String {
+    let mut md = format!("# Effective {}.conf\n\n", report.config_file);
+    md.push_str(&format!("- **Context**: {}\n\n", context_label(report)));
+
+    if report.settings.is_empty() {
+        md.push_str("_No settings found._\n");
+        return md;
+    }
+    md.push_str("| Stanza | Key | Value | Source | Overrides |\n");
+    md.push_str("|--------|-----|-------|--------|-----------|\n");
+    for setting in &report.settings {
+        let overridden = setting
+            .overridden
+            .iter()
+            .map(|o| o.source.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        md.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            setting.stanza, setting.key, setting.value, setting.source, overridden
+        ));
+    }
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use splunk_client::models::ConfigScope;
+    use splunk_client::workflows::effective_config::{EffectiveSetting, OverriddenValue};
+
+    fn report() -> EffectiveConfigReport {
+        EffectiveConfigReport {
+            config_file: "props".to_string(),
+            app: Some("search".to_string()),
+            user: None,
+            settings: vec![EffectiveSetting {
+                stanza: "syslog".to_string(),
+                key: "TZ".to_string(),
+                value: "UTC".to_string(),
+                scope: ConfigScope::App,
+                source: "app:search".to_string(),
+                overridden: vec![OverriddenValue {
+                    source: "system".to_string(),
+                    value: "GMT".to_string(),
+                }],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_table_lists_source_and_overridden_values() {
+        let output = format_effective_report(&report(), OutputFormat::Table).unwrap();
+        assert!(output.contains("Effective props.conf (app: search, user: (none))"));
+        assert!(output.contains("[syslog]"));
+        assert!(output.contains("app:search  TZ = UTC"));
+        assert!(output.contains("system        (overrides TZ = GMT)"));
+    }
+
+    #[test]
+    fn test_csv_includes_overridden_sources() {
+        let output = format_effective_report(&report(), OutputFormat::Csv).unwrap();
+        assert!(output.contains("syslog,TZ,UTC,app,app:search,system"));
+    }
+}
@@ -4,6 +4,8 @@
 //! - List dashboards with optional count limiting and pagination
 //! - Show detailed information about specific dashboards
 //! - Support detailed view with descriptions
+//! - Fetch a dashboard's source (Simple XML or Dashboard Studio JSON) to stdout or a file
+//! - View and change dashboard permissions (see `acl`)
 //! - Format output via shared formatters
 //!
 //! Does NOT handle:
@@ -14,9 +16,11 @@
 //! Invariants:
 //! - Count and offset parameters are validated for safe pagination
 //! - Dashboard names are passed through without modification
+//! - `get` emits the dashboard source itself; the global output format does not apply
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Subcommand;
+use splunk_client::models::KnowledgeObjectKind;
 use tracing::info;
 
 use crate::formatters::{OutputFormat, Pagination, TableFormatter, get_formatter, output_result};
@@ -41,6 +45,34 @@ pub enum DashboardsCommand {
         /// Dashboard name (required)
         name: String,
     },
+    /// Print or save a dashboard's source definition
+    #[command(after_help = "Examples:
+  splunk-cli dashboards get ops_overview
+  splunk-cli dashboards get ops_overview --out dashboard.xml
+  splunk-cli dashboards get studio_ops --definition --out studio_ops.json
+  splunk-cli dashboards get ops_overview --raw
+
+Simple XML is re-indented and Dashboard Studio JSON definitions are pretty-printed
+unless --raw is given.
+")]
+    Get {
+        /// Dashboard name
+        name: String,
+        /// Write the source to this file instead of stdout
+        #[arg(long, value_name = "FILE")]
+        out: Option<std::path::PathBuf>,
+        /// Emit the source exactly as stored on the server
+        #[arg(long, conflicts_with = "definition")]
+        raw: bool,
+        /// Emit only the Dashboard Studio JSON definition
+        #[arg(long)]
+        definition: bool,
+    },
+    /// View or change permissions (owner, sharing, read/write roles)
+    Acl {
+        #[command(subcommand)]
+        command: crate::commands::acl::AclCommand,
+    },
 }
 
 pub async fn run(
@@ -72,6 +104,24 @@ pub async fn run(
         DashboardsCommand::View { name } => {
             run_view(config, &name, output_format, output_file, cancel, no_cache).await
         }
+        DashboardsCommand::Get {
+            name,
+            out,
+            raw,
+            definition,
+        } => run_get(config, &name, out, raw, definition, cancel, no_cache).await,
+        DashboardsCommand::Acl { command } => {
+            crate::commands::acl::run(
+                config,
+                KnowledgeObjectKind::Dashboard,
+                command,
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
     }
 }
 
@@ -92,7 +142,10 @@ async fn run_list(
 
     let offset_param = if offset == 0 { None } else { Some(offset) };
 
-    let dashboards = cancellable!(client.list_dashboards(Some(count), offset_param), cancel)?;
+    let dashboards = cancellable!(
+        client.list_dashboards(Some(count), offset_param, None),
+        cancel
+    )?;
 
     let format = OutputFormat::from_str(output_format)?;
 
@@ -135,3 +188,78 @@ async fn run_view(
     output_result(&output, format, output_file.as_ref())?;
     Ok(())
 }
+
+async fn run_get(
+    config: splunk_config::Config,
+    name: &str,
+    out: Option<std::path::PathBuf>,
+    raw: bool,
+    definition: bool,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Getting dashboard source: {}", name);
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let dashboard = cancellable!(client.get_dashboard(name), cancel)?;
+    let source = dashboard_source(&dashboard, raw, definition)?;
+
+    match out {
+        Some(path) => {
+            tokio::fs::write(&path, &source)
+                .await
+                .with_context(|| format!("Failed to write output to {}", path.display()))?;
+            let format = dashboard
+                .source_format()
+                .map(|f| f.label())
+                .unwrap_or("unknown format");
+            println!(
+                "Dashboard '{}' ({}) saved to {}",
+                dashboard.name,
+                format,
+                path.display()
+            );
+        }
+        None => print!("{}", ensure_trailing_newline(source)),
+    }
+
+    Ok(())
+}
+
+/// Select the dashboard source to emit for `dashboards get`.
+fn dashboard_source(
+    dashboard: &splunk_client::models::Dashboard,
+    raw: bool,
+    definition: bool,
+) -> Result<String> {
+    if dashboard.xml_data.is_none() {
+        anyhow::bail!(
+            "Dashboard '{}' has no source definition (eai:data was not returned)",
+            dashboard.name
+        );
+    }
+
+    if definition {
+        return dashboard.studio_definition().with_context(|| {
+            format!(
+                "Dashboard '{}' is not a Dashboard Studio dashboard; omit --definition to get its Simple XML",
+                dashboard.name
+            )
+        });
+    }
+
+    let source = if raw {
+        dashboard.xml_data.clone()
+    } else {
+        dashboard.pretty_source()
+    };
+    Ok(source.unwrap_or_default())
+}
+
+fn ensure_trailing_newline(mut text: String) -> String {
+    if !text.ends_with('\n') {
+        text.push('\n');
+    }
+    text
+}
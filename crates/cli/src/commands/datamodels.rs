@@ -4,6 +4,7 @@
 //! - List data models with optional count limiting and pagination
 //! - Show detailed information about specific data models
 //! - Support detailed view with descriptions
+//! - Build and run `| tstats` queries against a data model dataset
 //! - Format output via shared formatters
 //!
 //! Does NOT handle:
@@ -14,9 +15,12 @@
 //! Invariants:
 //! - Count and offset parameters are validated for safe pagination
 //! - Data model names are passed through without modification
+//! - tstats fields are validated against the data model's JSON definition before running
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Subcommand;
+use splunk_client::models::{DataModel, DataModelObject, TstatsAggregate, TstatsQuery};
+use splunk_config::SearchDefaultConfig;
 use tracing::info;
 
 use crate::formatters::{OutputFormat, Pagination, TableFormatter, get_formatter, output_result};
@@ -41,12 +45,72 @@ pub enum DatamodelsCommand {
         /// Data model name (required)
         name: String,
     },
+    /// Build and run a `| tstats` query against a data model
+    ///
+    /// Field names may be given bare (`src`) and are qualified with the
+    /// dataset (`All_Traffic.src`). Use --list-fields to see what is available.
+    Tstats {
+        /// Data model name (e.g., Network_Traffic)
+        name: String,
+        /// Dataset to query, by name or lineage (defaults to the first root dataset)
+        #[arg(long, value_name = "DATASET")]
+        object: Option<String>,
+        /// Aggregate as FUNC or FUNC:FIELD (e.g., count, dc:src, sum:bytes); repeatable
+        #[arg(long = "stat", value_name = "FUNC[:FIELD]", value_parser = TstatsAggregate::parse)]
+        stats: Vec<TstatsAggregate>,
+        /// Field to split results by; repeatable
+        #[arg(long = "by", value_name = "FIELD")]
+        split_by: Vec<String>,
+        /// Bucket results by _time with this span (e.g., 1h)
+        #[arg(long)]
+        span: Option<String>,
+        /// Extra where clause, passed through as written (e.g., 'All_Traffic.action=blocked')
+        #[arg(long = "where", value_name = "CONDITION")]
+        filter: Option<String>,
+        /// Only use accelerated summaries (summariesonly=true)
+        #[arg(long)]
+        summaries_only: bool,
+        /// Earliest time for the search (e.g., '-24h')
+        #[arg(short, long, allow_hyphen_values = true, value_parser = crate::commands::search::parse_time_modifier)]
+        earliest: Option<String>,
+        /// Latest time for the search (e.g., 'now')
+        #[arg(short, long, allow_hyphen_values = true, value_parser = crate::commands::search::parse_time_modifier)]
+        latest: Option<String>,
+        /// Maximum number of results to return
+        #[arg(short, long)]
+        count: Option<usize>,
+        /// Print the generated SPL without running it
+        #[arg(long)]
+        dry_run: bool,
+        /// List the data model's datasets and fields instead of running a query
+        #[arg(long)]
+        list_fields: bool,
+    },
 }
 
+/// Options for `datamodels tstats`.
+pub struct TstatsOptions {
+    pub name: String,
+    pub object: Option<String>,
+    pub stats: Vec<TstatsAggregate>,
+    pub split_by: Vec<String>,
+    pub span: Option<String>,
+    pub filter: Option<String>,
+    pub summaries_only: bool,
+    pub earliest: Option<String>,
+    pub latest: Option<String>,
+    pub count: Option<usize>,
+    pub dry_run: bool,
+    pub list_fields: bool,
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
     config: splunk_config::Config,
     command: DatamodelsCommand,
+    search_defaults: &SearchDefaultConfig,
     output_format: &str,
+    quiet: bool,
     output_file: Option<std::path::PathBuf>,
     cancel: &crate::cancellation::CancellationToken,
     no_cache: bool,
@@ -72,6 +136,46 @@ pub async fn run(
         DatamodelsCommand::View { name } => {
             run_view(config, &name, output_format, output_file, cancel, no_cache).await
         }
+        DatamodelsCommand::Tstats {
+            name,
+            object,
+            stats,
+            split_by,
+            span,
+            filter,
+            summaries_only,
+            earliest,
+            latest,
+            count,
+            dry_run,
+            list_fields,
+        } => {
+            let options = TstatsOptions {
+                name,
+                object,
+                stats,
+                split_by,
+                span,
+                filter,
+                summaries_only,
+                earliest,
+                latest,
+                count,
+                dry_run,
+                list_fields,
+            };
+            run_tstats(
+                config,
+                options,
+                search_defaults,
+                output_format,
+                quiet,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
     }
 }
 
@@ -135,3 +239,132 @@ async fn run_view(
     output_result(&output, format, output_file.as_ref())?;
     Ok(())
 }
+
+/// Find the dataset named by `object` (name or lineage), or the first dataset.
+fn select_object(
+    datamodel: &DataModel,
+    objects: Vec<DataModelObject>,
+    object: Option<&str>,
+) -> Result<DataModelObject> {
+    let Some(object) = object else {
+        return objects
+            .into_iter()
+            .next()
+            .with_context(|| format!("Data model '{}' defines no datasets", datamodel.name));
+    };
+
+    let available: Vec<String> = objects.iter().map(|o| o.lineage.clone()).collect();
+    objects
+        .into_iter()
+        .find(|o| o.lineage == object || o.name == object)
+        .with_context(|| {
+            format!(
+                "Data model '{}' has no dataset '{}' (available: {})",
+                datamodel.name,
+                object,
+                available.join(", ")
+            )
+        })
+}
+
+/// Render datasets and their fields for `--list-fields`.
+fn format_objects(objects: &[DataModelObject], format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(objects)?),
+        OutputFormat::Ndjson => objects
+            .iter()
+            .map(|object| Ok(format!("{}\n", serde_json::to_string(object)?)))
+            .collect(),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(objects)?),
+        _ => {
+            let mut output = String::new();
+            for object in objects {
+                output.push_str(&format!("{}\n", object.lineage));
+                for field in &object.fields {
+                    output.push_str(&format!("  {}\n", field));
+                }
+            }
+            Ok(output)
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn run_tstats(
+    config: splunk_config::Config,
+    options: TstatsOptions,
+    search_defaults: &SearchDefaultConfig,
+    output_format: &str,
+    quiet: bool,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Building tstats query for data model: {}", options.name);
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+    let datamodel = cancellable!(client.get_datamodel(&options.name), cancel)?;
+    let objects = datamodel.objects().with_context(|| {
+        format!(
+            "Data model '{}' has no readable JSON definition",
+            datamodel.name
+        )
+    })?;
+
+    let format = OutputFormat::from_str(output_format)?;
+    if options.list_fields {
+        let output = format_objects(&objects, format)?;
+        output_result(&output, format, output_file.as_ref())?;
+        return Ok(());
+    }
+
+    let object = select_object(&datamodel, objects, options.object.as_deref())?;
+    let mut query = TstatsQuery {
+        datamodel: datamodel.name.clone(),
+        object: None,
+        aggregates: options.stats,
+        split_by: options.split_by,
+        span: options.span,
+        filter: options.filter,
+        summaries_only: options.summaries_only,
+    };
+    query.resolve(&object).map_err(anyhow::Error::msg)?;
+    let spl = query.to_spl();
+
+    if options.dry_run {
+        println!("{}", spl);
+        return Ok(());
+    }
+
+    if !datamodel.is_accelerated && !quiet {
+        eprintln!(
+            "Warning: data model '{}' is not accelerated; tstats will search raw events{}",
+            datamodel.name,
+            if query.summaries_only {
+                " and summariesonly=true will return no results"
+            } else {
+                ""
+            }
+        );
+    }
+
+    crate::commands::search::run(
+        config,
+        spl,
+        true,
+        options.earliest.as_deref(),
+        options.latest.as_deref(),
+        options.count,
+        search_defaults,
+        output_format,
+        quiet,
+        output_file,
+        cancel,
+        false,
+        None,
+        None,
+        &Default::default(),
+        no_cache,
+    )
+    .await
+}
@@ -7,6 +7,7 @@
 //! - Run the shared doctor workflow.
 //! - Format and emit doctor output.
 //! - Generate redacted support bundles from the shared report.
+//! - Report changes of the overall result to a webhook (`--notify-webhook`).
 //!
 //! Scope:
 //! - CLI orchestration only; diagnostics models and probe logic live in `splunk-client`.
@@ -22,6 +23,7 @@ use std::io::Write;
 use std::path::PathBuf;
 use tracing::info;
 
+use crate::commands::webhook::{self, StatusEvent};
 use crate::formatters::{OutputFormat, get_formatter, output_result};
 
 pub type CheckStatus = splunk_client::workflows::diagnostics::CheckStatus;
@@ -30,10 +32,12 @@ pub type ConfigSummary = splunk_client::workflows::diagnostics::ConfigSummary;
 pub type DiagnosticReport = splunk_client::workflows::diagnostics::DiagnosticReport;
 
 /// Run the doctor diagnostic command.
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
     config: splunk_config::Config,
     bundle_path: Option<PathBuf>,
     include_logs: bool,
+    notify_webhook: Option<&str>,
     output_format: &str,
     output_file: Option<PathBuf>,
     cancel: &crate::cancellation::CancellationToken,
@@ -70,6 +74,10 @@ pub async fn run(
     let output = formatter.format_health_check_report(&report)?;
     output_result(&output, format, output_file.as_ref())?;
 
+    if let Some(url) = notify_webhook {
+        webhook::notify_on_change(url, &doctor_event(&report)).await;
+    }
+
     if report
         .checks
         .iter()
@@ -81,6 +89,33 @@ pub async fn run(
     Ok(())
 }
 
+/// Webhook status for a doctor run: `fail` if any check failed, else `warning` if
+/// any warned, else `pass`, with one detail line per failing or warning check.
+fn doctor_event(report: &DiagnosticReport) -> StatusEvent {
+    let has = |status: CheckStatus| report.checks.iter().any(|check| check.status == status);
+    let status = if has(CheckStatus::Fail) {
+        CheckStatus::Fail
+    } else if has(CheckStatus::Warning) {
+        CheckStatus::Warning
+    } else {
+        CheckStatus::Pass
+    };
+
+    StatusEvent {
+        source: "doctor",
+        check: None,
+        server: report.config_summary.base_url.clone(),
+        status: status.slug().to_string(),
+        healthy: status == CheckStatus::Pass,
+        details: report
+            .checks
+            .iter()
+            .filter(|check| matches!(check.status, CheckStatus::Fail | CheckStatus::Warning))
+            .map(|check| format!("{}: {}", check.name, check.message))
+            .collect(),
+    }
+}
+
 fn write_zip_entry<W: std::io::Write + std::io::Seek>(
     zip: &mut zip::ZipWriter<W>,
     entry_name: &str,
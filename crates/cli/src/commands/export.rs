@@ -0,0 +1,105 @@
+//! Declarative export command (`splunk-cli export --types indexes,roles --out spec.yaml`).
+//!
+//! Responsibilities:
+//! - Export live server state as a resource spec that `splunk-cli apply` accepts.
+//! - Write the spec to a file, or print it.
+//!
+//! Does NOT handle:
+//! - Converting live resources into spec entries (lives in
+//!   `splunk-client::workflows::declarative`).
+//!
+//! Invariants:
+//! - The spec is always YAML, regardless of `--output`.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use splunk_client::workflows::declarative::{
+    ExportOptions, ResourceKind, ResourceSpec, export_spec,
+};
+use tracing::info;
+
+pub async fn run(
+    config: splunk_config::Config,
+    types: Vec<ResourceKind>,
+    out: Option<PathBuf>,
+    include_internal: bool,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    let options = ExportOptions {
+        kinds: if types.is_empty() {
+            ResourceKind::ALL.into_iter().collect()
+        } else {
+            types.into_iter().collect()
+        },
+        include_internal,
+    };
+    info!("Exporting resource spec");
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+    let spec = cancellable!(export_spec(&client, &options, Some(cancel)), cancel)?;
+    let yaml = serde_yaml::to_string(&spec)?;
+
+    match out {
+        Some(path) => {
+            tokio::fs::write(&path, &yaml)
+                .await
+                .with_context(|| format!("Failed to write spec to {}", path.display()))?;
+            println!(
+                "Exported {} to {}",
+                export_summary(&spec, &options),
+                path.display()
+            );
+        }
+        None => print!("{}", yaml),
+    }
+
+    Ok(())
+}
+
+/// Count per exported section, e.g. `3 indexes, 2 roles`.
+fn export_summary(spec: &ResourceSpec, options: &ExportOptions) -> String {
+    options
+        .kinds
+        .iter()
+        .map(|kind| {
+            let count = match kind {
+                ResourceKind::Index => spec.indexes.len(),
+                ResourceKind::Role => spec.roles.len(),
+                ResourceKind::User => spec.users.len(),
+                ResourceKind::Macro => spec.macros.len(),
+                ResourceKind::SavedSearch => spec.saved_searches.len(),
+            };
+            format!("{} {}", count, kind.section().replace('_', " "))
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_summary_lists_requested_sections_in_order() {
+        let spec = ResourceSpec::from_yaml(
+            "indexes:\n  - name: web\nmacros:\n  - name: m\n    definition: x\n",
+        )
+        .unwrap();
+        let options = ExportOptions {
+            kinds: [
+                ResourceKind::Macro,
+                ResourceKind::Index,
+                ResourceKind::SavedSearch,
+            ]
+            .into_iter()
+            .collect(),
+            include_internal: false,
+        };
+        assert_eq!(
+            export_summary(&spec, &options),
+            "1 indexes, 1 macros, 0 saved searches"
+        );
+    }
+}
@@ -0,0 +1,298 @@
+//! Field extraction tester command implementation.
+//!
+//! Responsibilities:
+//! - Read sample events (one per line) and `--regex` values or props.conf
+//!   `EXTRACT-` lines
+//! - Run the extractions against the samples on the server and show the
+//!   extracted fields next to each event
+//! - Format the preview in every output format
+//!
+//! Does NOT handle:
+//! - Building or running the test search (handled by
+//!   `splunk_client::workflows::field_extraction`)
+//! - Saving extractions to props.conf (see `configs`)
+//!
+//! Invariants:
+//! - Events are listed in sample order; unmatched events are kept and marked
+//! - Every named group is a column, even when no event matched it
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use splunk_client::workflows::field_extraction::{
+    Extraction, FieldExtractionPreview, parse_props_extractions, sample_events,
+    test_field_extraction,
+};
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+/// Widest raw event shown in table output before truncation.
+const RAW_COLUMN_WIDTH: usize = 60;
+
+#[derive(Subcommand)]
+pub enum ExtractCommand {
+    /// Test regex extractions against sample events
+    #[command(after_help = "Examples:
+  splunk-cli extract test --sample app.log --regex 'user=(?<user>\\w+) status=(?<status>\\d+)'
+  splunk-cli extract test --sample app.log --props props.conf -o json
+
+Each non-blank line of the sample file is one event. Regexes must use named
+groups, e.g. (?<field>...). With --props, every EXTRACT-<class> = <regex> [in <field>]
+line in the file is tested. Samples run through `| makeresults | rex` on the server,
+so nothing is indexed.
+")]
+    Test {
+        /// File of sample events, one per line (`-` for stdin)
+        #[arg(long, value_name = "FILE")]
+        sample: PathBuf,
+
+        /// Extraction regex with named groups (repeatable)
+        #[arg(long, value_name = "REGEX", required_unless_present = "props")]
+        regex: Vec<String>,
+
+        /// props.conf file whose EXTRACT- lines should be tested
+        #[arg(long, value_name = "FILE")]
+        props: Option<PathBuf>,
+    },
+}
+
+pub async fn run(
+    config: splunk_config::Config,
+    command: ExtractCommand,
+    output_format: &str,
+    output_file: Option<PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    match command {
+        ExtractCommand::Test {
+            sample,
+            regex,
+            props,
+        } => {
+            let samples = sample_events(&read_input(&sample)?);
+            let mut extractions: Vec<Extraction> =
+                regex.into_iter().map(Extraction::regex).collect();
+            if let Some(props) = props {
+                let text = read_input(&props)?;
+                let from_props = parse_props_extractions(&text);
+                if from_props.is_empty() {
+                    anyhow::bail!("No EXTRACT- settings found in {}", props.display());
+                }
+                extractions.extend(from_props);
+            }
+
+            info!(
+                "Testing {} extraction(s) against {} sample event(s)",
+                extractions.len(),
+                samples.len()
+            );
+
+            let format = OutputFormat::from_str(output_format)?;
+            let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+            let preview = cancellable!(
+                test_field_extraction(&client, &samples, &extractions),
+                cancel
+            )?;
+
+            let output = format_extraction_preview(&preview, format)?;
+            output_result(&output, format, output_file.as_ref())?;
+            Ok(())
+        }
+    }
+}
+
+fn read_input(path: &PathBuf) -> Result<String> {
+    if path.as_os_str() == "-" {
+        return std::io::read_to_string(std::io::stdin()).context("Failed to read stdin");
+    }
+    std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+fn truncate(value: &str, width: usize) -> String {
+    if value.chars().count() <= width {
+        value.to_string()
+    } else {
+        let kept: String = value.chars().take(width.saturating_sub(3)).collect();
+        format!("{kept}...")
+    }
+}
+
+/// Format an extraction preview based on the selected format.
+pub fn format_extraction_preview(
+    preview: &FieldExtractionPreview,
+    format: OutputFormat,
+) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(preview)?),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(preview)?),
+        OutputFormat::Ndjson => {
+            let mut ndjson = String::new();
+            for event in &preview.events {
+                ndjson.push_str(&serde_json::to_string(event)?);
+                ndjson.push('\n');
+            }
+            Ok(ndjson)
+        }
+        OutputFormat::Table => {
+            // Size each field column to its widest value so events line up side by side.
+            let widths: Vec<usize> = preview
+                .fields
+                .iter()
+                .map(|field| {
+                    preview
+                        .events
+                        .iter()
+                        .filter_map(|e| e.values.get(field))
+                        .map(|v| v.chars().count())
+                        .chain([field.len(), 1])
+                        .max()
+                        .unwrap_or(1)
+                })
+                .collect();
+
+            let mut out = format!("{:<5} {:<5}", "LINE", "MATCH");
+            for (field, width) in preview.fields.iter().zip(&widths) {
+                out.push_str(&format!(" {:<width$}", field.to_uppercase()));
+            }
+            out.push_str(" RAW\n");
+            for (i, event) in preview.events.iter().enumerate() {
+                out.push_str(&format!(
+                    "{:<5} {:<5}",
+                    i + 1,
+                    if event.matched() { "yes" } else { "no" }
+                ));
+                for (field, width) in preview.fields.iter().zip(&widths) {
+                    let value = event.values.get(field).map_or("-", String::as_str);
+                    out.push_str(&format!(" {:<width$}", value));
+                }
+                out.push_str(&format!(" {}\n", truncate(&event.raw, RAW_COLUMN_WIDTH)));
+            }
+            out.push_str(&format!(
+                "\n{} of {} event(s) matched.\n",
+                preview.matched_count(),
+                preview.events.len()
+            ));
+            Ok(out)
+        }
+        OutputFormat::Csv => {
+            let mut csv = String::from("line");
+            for field in &preview.fields {
+                csv.push(',');
+                csv.push_str(&escape_csv(field));
+            }
+            csv.push_str(",raw\n");
+            for (i, event) in preview.events.iter().enumerate() {
+                csv.push_str(&(i + 1).to_string());
+                for field in &preview.fields {
+                    csv.push(',');
+                    csv.push_str(&escape_csv(
+                        event.values.get(field).map_or("", String::as_str),
+                    ));
+                }
+                csv.push(',');
+                csv.push_str(&escape_csv(&event.raw));
+                csv.push('\n');
+            }
+            Ok(csv)
+        }
+        OutputFormat::Xml => {
+            let mut xml = format!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<field_extraction matched=\"{}\" total=\"{}\">\n",
+                preview.matched_count(),
+                preview.events.len()
+            );
+            xml.push_str(&format!(
+                "  <search>{}</search>\n",
+                escape_xml(&preview.search)
+            ));
+            for (i, event) in preview.events.iter().enumerate() {
+                xml.push_str(&format!("  <event line=\"{}\">\n", i + 1));
+                xml.push_str(&format!("    <raw>{}</raw>\n", escape_xml(&event.raw)));
+                for (field, value) in &event.values {
+                    xml.push_str(&format!(
+                        "    <field name=\"{}\">{}</field>\n",
+                        escape_xml(field),
+                        escape_xml(value)
+                    ));
+                }
+                xml.push_str("  </event>\n");
+            }
+            xml.push_str("</field_extraction>");
+            Ok(xml)
+        }
+        OutputFormat::Markdown => {
+            let mut md = format!(
+                "# Field Extraction Test\n\n{} of {} event(s) matched.\n\n",
+                preview.matched_count(),
+                preview.events.len()
+            );
+            md.push_str("| Line |");
+            for field in &preview.fields {
+                md.push_str(&format!(" {} |", field));
+            }
+            md.push_str(" Raw |\n|------|");
+            for _ in &preview.fields {
+                md.push_str("------|");
+            }
+            md.push_str("-----|\n");
+            for (i, event) in preview.events.iter().enumerate() {
+                md.push_str(&format!("| {} |", i + 1));
+                for field in &preview.fields {
+                    let value = event.values.get(field).map_or("-", String::as_str);
+                    md.push_str(&format!(" {} |", value.replace('|', "\\|")));
+                }
+                md.push_str(&format!(" `{}` |\n", event.raw.replace('`', "'")));
+            }
+            Ok(md)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use splunk_client::workflows::field_extraction::ExtractedEvent;
+
+    fn preview() -> FieldExtractionPreview {
+        FieldExtractionPreview {
+            fields: vec!["user".to_string(), "status".to_string()],
+            events: vec![
+                ExtractedEvent {
+                    raw: "user=alice status=200".to_string(),
+                    values: [
+                        ("user".to_string(), "alice".to_string()),
+                        ("status".to_string(), "200".to_string()),
+                    ]
+                    .into(),
+                },
+                ExtractedEvent {
+                    raw: "garbage".to_string(),
+                    values: Default::default(),
+                },
+            ],
+            search: "| makeresults".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_format_table_lines_up_fields_and_counts_matches() {
+        let table = format_extraction_preview(&preview(), OutputFormat::Table).unwrap();
+        assert!(table.starts_with("LINE  MATCH USER  STATUS RAW\n"));
+        assert!(table.contains("1     yes   alice 200    user=alice status=200\n"));
+        assert!(table.contains("2     no    -     -      garbage\n"));
+        assert!(table.contains("1 of 2 event(s) matched."));
+    }
+
+    #[test]
+    fn test_format_csv_leaves_unmatched_fields_empty() {
+        let csv = format_extraction_preview(&preview(), OutputFormat::Csv).unwrap();
+        assert_eq!(
+            csv,
+            "line,user,status,raw\n1,alice,200,user=alice status=200\n2,,,garbage\n"
+        );
+    }
+}
@@ -0,0 +1,653 @@
+//! Synthetic test data generator for lab and demo environments.
+//!
+//! Responsibilities:
+//! - Synthesize realistic events from built-in templates (apache, json, syslog).
+//! - Pace ingestion to a target rate for a fixed duration and send events via HEC.
+//! - Report progress on STDERR and print an ingestion summary in every output format.
+//!
+//! Does NOT handle:
+//! - HEC transport details (see `splunk-client` HEC endpoints).
+//! - Creating indexes or HEC tokens (the destination must already exist).
+//!
+//! Environment Variables:
+//! - `SPLUNK_HEC_URL`: HEC endpoint URL (e.g., `https://localhost:8088`)
+//! - `SPLUNK_HEC_TOKEN`: HEC authentication token
+//!
+//! Invariants:
+//! - `--dry-run` never contacts HEC and ignores pacing.
+//! - The same `--seed` always produces the same event bodies (timestamps aside).
+//! - The command fails on the first batch HEC rejects; events already sent stay indexed.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::{RngExt, SeedableRng};
+use serde::Serialize;
+use splunk_client::HecEvent;
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+/// Maximum number of events sent in a single HEC request.
+const MAX_BATCH_EVENTS: u64 = 500;
+
+/// How often the pacing loop wakes up to send due events.
+const SEND_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Default `source` field for generated events.
+pub const DEFAULT_SOURCE: &str = "splunk-cli:generate";
+
+/// Built-in event templates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum EventTemplate {
+    /// Apache combined access log lines
+    Apache,
+    /// Structured JSON application logs
+    Json,
+    /// BSD-style syslog lines
+    Syslog,
+}
+
+impl EventTemplate {
+    /// Sourcetype used when `--sourcetype` is not given.
+    pub fn default_sourcetype(self) -> &'static str {
+        match self {
+            Self::Apache => "access_combined",
+            Self::Json => "_json",
+            Self::Syslog => "syslog",
+        }
+    }
+
+    fn slug(self) -> &'static str {
+        match self {
+            Self::Apache => "apache",
+            Self::Json => "json",
+            Self::Syslog => "syslog",
+        }
+    }
+}
+
+/// Options for a generate run, resolved from CLI arguments.
+#[derive(Debug, Clone)]
+pub struct GenerateOptions {
+    pub hec_url: Option<String>,
+    pub hec_token: Option<String>,
+    pub index: Option<String>,
+    pub sourcetype: Option<String>,
+    pub source: String,
+    pub host: Option<String>,
+    /// Target events per second.
+    pub rate: f64,
+    pub duration: Duration,
+    pub template: EventTemplate,
+    pub seed: Option<u64>,
+    pub dry_run: bool,
+}
+
+impl GenerateOptions {
+    fn sourcetype(&self) -> &str {
+        self.sourcetype
+            .as_deref()
+            .unwrap_or_else(|| self.template.default_sourcetype())
+    }
+
+    /// Total number of events the run will produce.
+    pub fn total_events(&self) -> u64 {
+        (self.rate * self.duration.as_secs_f64()).ceil().max(1.0) as u64
+    }
+}
+
+/// Summary of a completed generate run.
+#[derive(Debug, Clone, Serialize)]
+pub struct GenerateSummary {
+    pub template: String,
+    pub index: Option<String>,
+    pub sourcetype: String,
+    pub requested_events: u64,
+    pub events_sent: u64,
+    pub batches: u64,
+    pub elapsed_secs: f64,
+    pub events_per_sec: f64,
+}
+
+/// Parse a rate such as `50/s`, `300/m`, `3600/h`, or a bare `50` (per second).
+pub fn parse_rate(value: &str) -> std::result::Result<f64, String> {
+    let value = value.trim();
+    let (count, per_secs) = match value.split_once('/') {
+        Some((count, unit)) => {
+            let per_secs = match unit.trim() {
+                "s" | "sec" => 1.0,
+                "m" | "min" => 60.0,
+                "h" | "hr" => 3600.0,
+                other => return Err(format!("unknown rate unit '{}' (use s, m, or h)", other)),
+            };
+            (count.trim(), per_secs)
+        }
+        None => (value, 1.0),
+    };
+
+    let count: f64 = count
+        .parse()
+        .map_err(|_| format!("invalid rate '{}' (expected e.g. 50/s)", value))?;
+    if !count.is_finite() || count <= 0.0 {
+        return Err(format!("rate must be positive, got '{}'", value));
+    }
+    Ok(count / per_secs)
+}
+
+/// Parse a duration such as `30s`, `10m`, `1h`, or a bare number of seconds.
+pub fn parse_duration(value: &str) -> std::result::Result<Duration, String> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+
+    let amount: u64 = amount
+        .parse()
+        .map_err(|_| format!("invalid duration '{}' (expected e.g. 10m)", value))?;
+    let secs = match unit {
+        "" | "s" => amount,
+        "m" => amount.saturating_mul(60),
+        "h" => amount.saturating_mul(3600),
+        other => {
+            return Err(format!(
+                "unknown duration unit '{}' (use s, m, or h)",
+                other
+            ));
+        }
+    };
+    if secs == 0 {
+        return Err("duration must be greater than zero".to_string());
+    }
+    Ok(Duration::from_secs(secs))
+}
+
+/// Number of events that should have been sent after `elapsed` at `rate`.
+fn due_events(rate: f64, elapsed: Duration, total: u64) -> u64 {
+    ((rate * elapsed.as_secs_f64()).floor() as u64).min(total)
+}
+
+const HOSTS: &[&str] = &["web-01", "web-02", "web-03", "app-01", "app-02"];
+const USERS: &[&str] = &["-", "alice", "bob", "carol", "dave", "svc-batch"];
+const METHODS: &[(&str, u32)] = &[("GET", 70), ("POST", 20), ("PUT", 6), ("DELETE", 4)];
+const PATHS: &[&str] = &[
+    "/",
+    "/login",
+    "/cart",
+    "/checkout",
+    "/api/v1/products",
+    "/api/v1/orders",
+    "/static/app.js",
+    "/static/style.css",
+];
+const STATUSES: &[(u16, u32)] = &[
+    (200, 78),
+    (201, 4),
+    (304, 6),
+    (401, 3),
+    (404, 5),
+    (500, 3),
+    (503, 1),
+];
+const REFERERS: &[&str] = &[
+    "-",
+    "https://www.example.com/",
+    "https://search.example.org/",
+];
+const USER_AGENTS: &[&str] = &[
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/124.0 Safari/537.36",
+    "Mozilla/5.0 (Macintosh; Intel Mac OS X 14_4) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.4 Safari/605.1.15",
+    "curl/8.5.0",
+    "python-requests/2.31.0",
+];
+const SERVICES: &[&str] = &["checkout", "inventory", "payments", "auth", "search"];
+const LEVELS: &[(&str, u32)] = &[("INFO", 80), ("WARN", 12), ("ERROR", 6), ("DEBUG", 2)];
+const SYSLOG_PROGRAMS: &[(&str, &[&str])] = &[
+    (
+        "sshd",
+        &[
+            "Accepted publickey for deploy from 10.0.4.17 port 52144 ssh2",
+            "Failed password for invalid user admin from 203.0.113.9 port 40112 ssh2",
+            "Connection closed by 198.51.100.23 port 51422 [preauth]",
+        ],
+    ),
+    (
+        "CRON",
+        &[
+            "(root) CMD (/usr/local/bin/backup.sh)",
+            "(www-data) CMD (php /var/www/cron.php)",
+        ],
+    ),
+    (
+        "kernel",
+        &[
+            "eth0: link up, 1000Mbps, full-duplex",
+            "Out of memory: Killed process 4242 (java)",
+        ],
+    ),
+    (
+        "systemd",
+        &[
+            "Started Daily apt download activities.",
+            "Stopping User Manager for UID 1000...",
+        ],
+    ),
+];
+
+/// Deterministic (when seeded) source of synthetic events.
+pub struct EventGenerator {
+    template: EventTemplate,
+    rng: StdRng,
+}
+
+impl EventGenerator {
+    /// Create a generator; a `seed` makes event bodies reproducible.
+    pub fn new(template: EventTemplate, seed: Option<u64>) -> Self {
+        let rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => rand::make_rng(),
+        };
+        Self { template, rng }
+    }
+
+    /// Produce the next event body and the host it claims to come from.
+    pub fn next_event(&mut self, now: DateTime<Utc>) -> (serde_json::Value, &'static str) {
+        let host = *HOSTS.choose(&mut self.rng).expect("HOSTS is non-empty");
+        let body = match self.template {
+            EventTemplate::Apache => serde_json::Value::String(self.apache_line(now)),
+            EventTemplate::Json => self.json_event(now, host),
+            EventTemplate::Syslog => serde_json::Value::String(self.syslog_line(now, host)),
+        };
+        (body, host)
+    }
+
+    fn weighted<T: Copy>(&mut self, choices: &[(T, u32)]) -> T {
+        let total: u32 = choices.iter().map(|(_, weight)| weight).sum();
+        let mut roll = self.rng.random_range(0..total);
+        for (choice, weight) in choices {
+            if roll < *weight {
+                return *choice;
+            }
+            roll -= weight;
+        }
+        choices[choices.len() - 1].0
+    }
+
+    fn pick<'a>(&mut self, choices: &'a [&'a str]) -> &'a str {
+        choices
+            .choose(&mut self.rng)
+            .expect("choices are non-empty")
+    }
+
+    fn apache_line(&mut self, now: DateTime<Utc>) -> String {
+        let ip = format!(
+            "{}.{}.{}.{}",
+            self.rng.random_range(10..=223u8),
+            self.rng.random_range(0..=255u8),
+            self.rng.random_range(0..=255u8),
+            self.rng.random_range(1..=254u8)
+        );
+        let user = self.pick(USERS);
+        let method = self.weighted(METHODS);
+        let path = self.pick(PATHS);
+        let status = self.weighted(STATUSES);
+        let bytes = if status == 304 {
+            0
+        } else {
+            self.rng.random_range(200..=48_000u32)
+        };
+        let referer = self.pick(REFERERS);
+        let agent = self.pick(USER_AGENTS);
+        format!(
+            "{} - {} [{}] \"{} {} HTTP/1.1\" {} {} \"{}\" \"{}\"",
+            ip,
+            user,
+            now.format("%d/%b/%Y:%H:%M:%S %z"),
+            method,
+            path,
+            status,
+            bytes,
+            referer,
+            agent
+        )
+    }
+
+    fn json_event(&mut self, now: DateTime<Utc>, host: &str) -> serde_json::Value {
+        let level = self.weighted(LEVELS);
+        let service = self.pick(SERVICES);
+        let status = self.weighted(STATUSES);
+        let user = match self.pick(USERS) {
+            "-" => "anonymous",
+            user => user,
+        };
+        let message = match level {
+            "ERROR" => format!("{} request failed with upstream error", service),
+            "WARN" => format!("{} request exceeded latency budget", service),
+            _ => format!("{} request completed", service),
+        };
+        serde_json::json!({
+            "timestamp": now.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+            "level": level,
+            "service": service,
+            "host": host,
+            "user": user,
+            "method": self.weighted(METHODS),
+            "path": self.pick(PATHS),
+            "status": status,
+            "latency_ms": self.rng.random_range(2..=2_500u32),
+            "request_id": format!("{:016x}", self.rng.random::<u64>()),
+            "message": message,
+        })
+    }
+
+    fn syslog_line(&mut self, now: DateTime<Utc>, host: &str) -> String {
+        let (program, messages) = *SYSLOG_PROGRAMS
+            .choose(&mut self.rng)
+            .expect("SYSLOG_PROGRAMS is non-empty");
+        let message = self.pick(messages);
+        let pid = self.rng.random_range(300..=65_000u32);
+        format!(
+            "{} {} {}[{}]: {}",
+            now.format("%b %e %H:%M:%S"),
+            host,
+            program,
+            pid,
+            message
+        )
+    }
+}
+
+fn build_event(
+    generator: &mut EventGenerator,
+    options: &GenerateOptions,
+    now: DateTime<Utc>,
+) -> HecEvent {
+    let (body, host) = generator.next_event(now);
+    let mut event = HecEvent::new(body);
+    event.index = options.index.clone();
+    event.source = Some(options.source.clone());
+    event.sourcetype = Some(options.sourcetype().to_string());
+    event.host = Some(options.host.clone().unwrap_or_else(|| host.to_string()));
+    event.time = Some(now.timestamp_millis() as f64 / 1000.0);
+    event
+}
+
+/// Run the generate command.
+pub async fn run(
+    options: GenerateOptions,
+    quiet: bool,
+    output_format: &str,
+    output_file: Option<PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+) -> Result<()> {
+    let format = OutputFormat::from_str(output_format)?;
+    let total = options.total_events();
+    let mut generator = EventGenerator::new(options.template, options.seed);
+
+    if options.dry_run {
+        info!("Generating {} events (dry run)", total);
+        let events: Vec<HecEvent> = (0..total)
+            .map(|_| build_event(&mut generator, &options, Utc::now()))
+            .collect();
+        let output = serde_json::to_string_pretty(&events)?;
+        output_result(&output, OutputFormat::Json, output_file.as_ref())?;
+        return Ok(());
+    }
+
+    let (Some(hec_url), Some(hec_token)) = (options.hec_url.clone(), options.hec_token.clone())
+    else {
+        anyhow::bail!("Failed to generate events: --hec-url and --hec-token are required");
+    };
+
+    info!(
+        "Generating {} {} events at {:.2}/s to HEC at {}",
+        total,
+        options.template.slug(),
+        options.rate,
+        hec_url
+    );
+
+    // Create a minimal client (HEC doesn't use standard auth)
+    let client = super::with_http_trace(splunk_client::SplunkClient::builder())
+        .base_url(hec_url.clone())
+        .auth_strategy(splunk_client::AuthStrategy::ApiToken {
+            token: secrecy::SecretString::new(hec_token.clone().into()),
+        })
+        .build()
+        .context("Failed to create HEC client")?;
+
+    let progress = crate::progress::CountProgress::new(!quiet, "Generating events", total);
+    let started = Instant::now();
+    let mut ticker = tokio::time::interval(SEND_INTERVAL);
+    let mut sent = 0u64;
+    let mut batches = 0u64;
+
+    while sent < total {
+        tokio::select! {
+            _ = ticker.tick() => {}
+            _ = cancel.cancelled() => return Err(crate::cancellation::Cancelled.into()),
+        }
+
+        let due = due_events(options.rate, started.elapsed(), total);
+        while sent < due {
+            let size = (due - sent).min(MAX_BATCH_EVENTS);
+            let batch: Vec<HecEvent> = (0..size)
+                .map(|_| build_event(&mut generator, &options, Utc::now()))
+                .collect();
+
+            let response = cancellable!(
+                client.hec_send_batch(&hec_url, &hec_token, &batch, false),
+                cancel
+            )?;
+            if !response.is_success() {
+                anyhow::bail!(
+                    "Failed to send generated events: HEC returned code {} ({}) after {} events",
+                    response.code,
+                    response.text,
+                    sent
+                );
+            }
+
+            sent += size;
+            batches += 1;
+            progress.inc(size);
+        }
+    }
+    progress.finish();
+
+    let elapsed = started.elapsed().as_secs_f64();
+    let summary = GenerateSummary {
+        template: options.template.slug().to_string(),
+        index: options.index.clone(),
+        sourcetype: options.sourcetype().to_string(),
+        requested_events: total,
+        events_sent: sent,
+        batches,
+        elapsed_secs: (elapsed * 100.0).round() / 100.0,
+        events_per_sec: if elapsed > 0.0 {
+            ((sent as f64 / elapsed) * 100.0).round() / 100.0
+        } else {
+            0.0
+        },
+    };
+
+    let output = format_generate_summary(&summary, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+/// Format a generate summary based on the selected format.
+pub fn format_generate_summary(summary: &GenerateSummary, format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(summary)?),
+        OutputFormat::Ndjson => Ok(format!("{}\n", serde_json::to_string(summary)?)),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(summary)?),
+        OutputFormat::Table => Ok(format_table(summary)),
+        OutputFormat::Csv => Ok(format_csv(summary)),
+        OutputFormat::Xml => Ok(format_xml(summary)),
+        OutputFormat::Markdown => Ok(format_markdown(summary)),
+    }
+}
+
+fn summary_rows(summary: &GenerateSummary) -> Vec<(&'static str, String)> {
+    vec![
+        ("Template", summary.template.clone()),
+        (
+            "Index",
+            summary
+                .index
+                .clone()
+                .unwrap_or_else(|| "(token default)".to_string()),
+        ),
+        ("Sourcetype", summary.sourcetype.clone()),
+        ("Requested", summary.requested_events.to_string()),
+        ("Sent", summary.events_sent.to_string()),
+        ("Batches", summary.batches.to_string()),
+        ("Elapsed (s)", format!("{:.2}", summary.elapsed_secs)),
+        ("Events/s", format!("{:.2}", summary.events_per_sec)),
+    ]
+}
+
+fn format_table(summary: &GenerateSummary) -> String {
+    let mut out = String::new();
+    out.push_str("Generated Events\n");
+    out.push_str("================\n\n");
+    for (label, value) in summary_rows(summary) {
+        out.push_str(&format!("{:<12} {}\n", format!("{}:", label), value));
+    }
+    out
+}
+
+fn format_csv(summary: &GenerateSummary) -> String {
+    let mut out = String::from(
+        "template,index,sourcetype,requested_events,events_sent,batches,elapsed_secs,events_per_sec\n",
+    );
+    out.push_str(&format!(
+        "{},{},{},{},{},{},{:.2},{:.2}\n",
+        escape_csv(&summary.template),
+        escape_csv(summary.index.as_deref().unwrap_or("")),
+        escape_csv(&summary.sourcetype),
+        summary.requested_events,
+        summary.events_sent,
+        summary.batches,
+        summary.elapsed_secs,
+        summary.events_per_sec
+    ));
+    out
+}
+
+fn format_xml(summary: &GenerateSummary) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<generate>\n");
+    out.push_str(&format!(
+        "  <template>{}</template>\n",
+        escape_xml(&summary.template)
+    ));
+    if let Some(ref index) = summary.index {
+        out.push_str(&format!("  <index>{}</index>\n", escape_xml(index)));
+    }
+    out.push_str(&format!(
+        "  <sourcetype>{}</sourcetype>\n",
+        escape_xml(&summary.sourcetype)
+    ));
+    out.push_str(&format!(
+        "  <requestedEvents>{}</requestedEvents>\n",
+        summary.requested_events
+    ));
+    out.push_str(&format!(
+        "  <eventsSent>{}</eventsSent>\n",
+        summary.events_sent
+    ));
+    out.push_str(&format!("  <batches>{}</batches>\n", summary.batches));
+    out.push_str(&format!(
+        "  <elapsedSecs>{:.2}</elapsedSecs>\n",
+        summary.elapsed_secs
+    ));
+    out.push_str(&format!(
+        "  <eventsPerSec>{:.2}</eventsPerSec>\n",
+        summary.events_per_sec
+    ));
+    out.push_str("</generate>\n");
+    out
+}
+
+fn format_markdown(summary: &GenerateSummary) -> String {
+    let mut out = String::from("## Generated Events\n\n| Field | Value |\n|-------|-------|\n");
+    for (label, value) in summary_rows(summary) {
+        out.push_str(&format!("| {} | {} |\n", label, value));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixed_now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-03-05T14:07:09Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_parse_rate_units() {
+        assert_eq!(parse_rate("50/s").unwrap(), 50.0);
+        assert_eq!(parse_rate("120/m").unwrap(), 2.0);
+        assert_eq!(parse_rate("1800/h").unwrap(), 0.5);
+        assert_eq!(parse_rate("7").unwrap(), 7.0);
+        assert!(parse_rate("0/s").is_err());
+        assert!(parse_rate("10/d").is_err());
+        assert!(parse_rate("fast").is_err());
+    }
+
+    #[test]
+    fn test_parse_duration_units() {
+        assert_eq!(parse_duration("10m").unwrap(), Duration::from_secs(600));
+        assert_eq!(parse_duration("45s").unwrap(), Duration::from_secs(45));
+        assert_eq!(parse_duration("2h").unwrap(), Duration::from_secs(7200));
+        assert_eq!(parse_duration("30").unwrap(), Duration::from_secs(30));
+        assert!(parse_duration("0s").is_err());
+        assert!(parse_duration("5d").is_err());
+        assert!(parse_duration("m").is_err());
+    }
+
+    #[test]
+    fn test_due_events_is_capped_at_total() {
+        assert_eq!(due_events(50.0, Duration::from_millis(0), 100), 0);
+        assert_eq!(due_events(50.0, Duration::from_millis(500), 100), 25);
+        assert_eq!(due_events(50.0, Duration::from_secs(10), 100), 100);
+    }
+
+    #[test]
+    fn test_seeded_generator_is_reproducible() {
+        let mut a = EventGenerator::new(EventTemplate::Json, Some(42));
+        let mut b = EventGenerator::new(EventTemplate::Json, Some(42));
+        for _ in 0..5 {
+            assert_eq!(a.next_event(fixed_now()), b.next_event(fixed_now()));
+        }
+    }
+
+    #[test]
+    fn test_apache_template_shape() {
+        let mut generator = EventGenerator::new(EventTemplate::Apache, Some(7));
+        let (body, _) = generator.next_event(fixed_now());
+        let line = body.as_str().unwrap();
+        assert!(line.contains("[05/Mar/2024:14:07:09 +0000]"));
+        assert!(line.contains(" HTTP/1.1\" "));
+    }
+
+    #[test]
+    fn test_syslog_template_uses_host() {
+        let mut generator = EventGenerator::new(EventTemplate::Syslog, Some(7));
+        let (body, host) = generator.next_event(fixed_now());
+        let line = body.as_str().unwrap();
+        assert!(line.starts_with("Mar  5 14:07:09 "));
+        assert!(line.contains(host));
+    }
+}
@@ -3,6 +3,15 @@
 //! Responsibilities:
 //! - Perform comprehensive health checks against Splunk instance
 //! - Aggregate health status from multiple endpoints
+//! - Report skipped and failed scheduled searches (`health scheduler`)
+//! - Report per-host CPU/memory/I/O wait and top processes (`health resources`)
+//! - Report ingestion queue fill and blocking per pipeline (`health queues`)
+//! - Flag source IPs with many failed logins (`health security`)
+//! - Report disk usage per partition against the disk thresholds (`health disk`)
+//! - Report running and queued searches against concurrency limits and user quotas (`health search-load`)
+//! - Summarize health across several profiles (`health --profiles a,b`)
+//! - Re-run the full check on an interval (`health --watch 60s`)
+//! - Report status changes of the full check to a webhook (`--notify-webhook`)
 //! - Format output via shared formatters
 //!
 //! Does NOT handle:
@@ -14,13 +23,178 @@
 //! - Partial failures are logged but do not fail the overall command
 //! - Health check results are aggregated from multiple endpoints
 
+use std::time::Duration;
+
 use anyhow::Result;
+use clap::Subcommand;
+use tokio::time::sleep;
 use tracing::{info, warn};
 
+use crate::cancellation::{Cancelled, is_cancelled_error};
+use crate::commands::multi_profile::{self, ProfileSource, ProfileTargets};
+use crate::commands::webhook::{self, StatusEvent};
+use crate::error::UsageError;
 use crate::formatters::{OutputFormat, get_formatter, output_result};
+use splunk_client::models::{DEFAULT_FAILED_LOGIN_THRESHOLD, HealthStatus};
+use splunk_client::{AggregatedHealth, SplunkClient};
+
+/// Focused health subcommands.
+#[derive(Debug, Subcommand)]
+pub enum HealthCommand {
+    /// Summarize skipped and failed scheduled searches from the scheduler log
+    #[command(after_help = "Examples:
+  splunk-cli health scheduler
+  splunk-cli health scheduler --since 7d
+  splunk-cli health scheduler --since -1d@d -o csv
+")]
+    Scheduler {
+        /// Look-back window: a duration like 24h or 7d, or a Splunk relative time like -1d@d
+        #[arg(long, default_value = "24h", allow_hyphen_values = true)]
+        since: String,
+    },
+
+    /// Show splunkd CPU, memory, and I/O wait per host and the busiest processes
+    #[command(after_help = "Examples:
+  splunk-cli health resources
+  splunk-cli health resources --host idx01
+  splunk-cli health resources -o json
+")]
+    Resources {
+        /// Only report this host (default: every host reporting introspection data)
+        #[arg(long)]
+        host: Option<String>,
+    },
+
+    /// Show ingestion queue fill ratios and blocked counts per pipeline from metrics.log
+    #[command(after_help = "Examples:
+  splunk-cli health queues
+  splunk-cli health queues --since 1h
+  splunk-cli health queues -o csv
+")]
+    Queues {
+        /// Look-back window: a duration like 15m or 1h, or a Splunk relative time like -1h@h
+        #[arg(long, default_value = "15m", allow_hyphen_values = true)]
+        since: String,
+    },
+
+    /// Count failed logins per source IP from the audit index and flag likely brute force
+    #[command(after_help = "Examples:
+  splunk-cli health security
+  splunk-cli health security --threshold 20 --since 24h
+  splunk-cli health security -o ndjson | my-alert-forwarder
+")]
+    Security {
+        /// Look-back window: a duration like 1h or 24h, or a Splunk relative time like -1d@d
+        #[arg(long, default_value = "1h", allow_hyphen_values = true)]
+        since: String,
+        /// Flag source IPs with more failed logins than this in the window
+        #[arg(long, default_value_t = DEFAULT_FAILED_LOGIN_THRESHOLD)]
+        threshold: u64,
+    },
 
+    /// Show disk usage on each partition splunkd writes to, flagged against the disk thresholds
+    #[command(after_help = "Examples:
+  splunk-cli health disk
+  splunk-cli health disk --profile indexer01
+  splunk-cli health disk -o json
+")]
+    Disk,
+
+    /// Show running and queued searches against the concurrency limits and per-user quotas
+    #[command(after_help = "Examples:
+  splunk-cli health search-load
+  splunk-cli health search-load --profile shc-member01
+  splunk-cli health search-load -o csv
+")]
+    SearchLoad,
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
     config: splunk_config::Config,
+    command: Option<HealthCommand>,
+    watch: Option<Duration>,
+    notify_webhook: Option<&str>,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    if command.is_some() && (watch.is_some() || notify_webhook.is_some()) {
+        return Err(UsageError(
+            "--watch and --notify-webhook apply to the full health check, not health subcommands"
+                .to_string(),
+        )
+        .into());
+    }
+
+    match command {
+        None => match watch {
+            Some(interval) => {
+                run_watch(
+                    config,
+                    interval,
+                    notify_webhook,
+                    output_format,
+                    output_file,
+                    cancel,
+                    no_cache,
+                )
+                .await
+            }
+            None => {
+                run_aggregate(
+                    config,
+                    notify_webhook,
+                    output_format,
+                    output_file,
+                    cancel,
+                    no_cache,
+                )
+                .await
+            }
+        },
+        Some(HealthCommand::Scheduler { since }) => {
+            run_scheduler(config, &since, output_format, output_file, cancel, no_cache).await
+        }
+        Some(HealthCommand::Resources { host }) => {
+            run_resources(
+                config,
+                host.as_deref(),
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
+        Some(HealthCommand::Queues { since }) => {
+            run_queues(config, &since, output_format, output_file, cancel, no_cache).await
+        }
+        Some(HealthCommand::Security { since, threshold }) => {
+            run_security(
+                config,
+                &since,
+                threshold,
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
+        Some(HealthCommand::Disk) => {
+            run_disk(config, output_format, output_file, cancel, no_cache).await
+        }
+        Some(HealthCommand::SearchLoad) => {
+            run_search_load(config, output_format, output_file, cancel, no_cache).await
+        }
+    }
+}
+
+async fn run_aggregate(
+    config: splunk_config::Config,
+    notify_webhook: Option<&str>,
     output_format: &str,
     output_file: Option<std::path::PathBuf>,
     cancel: &crate::cancellation::CancellationToken,
@@ -32,13 +206,96 @@ pub async fn run(
 
     info!("Connecting to {}", client.base_url());
 
+    check_and_report(
+        &client,
+        notify_webhook,
+        output_format,
+        output_file.as_ref(),
+        cancel,
+    )
+    .await
+}
+
+/// Run the full health check every `interval` until cancelled.
+///
+/// A failed check is reported on stderr and retried at the next interval.
+async fn run_watch(
+    config: splunk_config::Config,
+    interval: Duration,
+    notify_webhook: Option<&str>,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    // Watch mode is incompatible with file output
+    if output_file.is_some() {
+        anyhow::bail!(
+            "Failed to use output file in watch mode: watch mode does not support file output"
+        );
+    }
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+    info!(
+        "Watching health of {} every {}s...",
+        client.base_url(),
+        interval.as_secs()
+    );
+
+    loop {
+        eprintln!(
+            "Health check at {}",
+            chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
+        );
+        if let Err(e) = check_and_report(&client, notify_webhook, output_format, None, cancel).await
+        {
+            if is_cancelled_error(&e) {
+                return Err(e);
+            }
+            eprintln!("Health check failed: {:#}", e);
+        }
+
+        tokio::select! {
+            _ = sleep(interval) => {}
+            _ = cancel.cancelled() => return Err(Cancelled.into()),
+        }
+    }
+}
+
+/// Run the full health check once, print it, and report status changes to the webhook.
+async fn check_and_report(
+    client: &SplunkClient,
+    notify_webhook: Option<&str>,
+    output_format: &str,
+    output_file: Option<&std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+) -> Result<()> {
     // Use shared health check aggregation from client crate
-    let health_result = cancellable!(client.check_health_aggregate(), cancel)?;
+    let health_result = match cancellable!(client.check_health_aggregate(), cancel) {
+        Ok(health_result) => health_result,
+        Err(e) => {
+            if let Some(url) = notify_webhook.filter(|_| !is_cancelled_error(&e)) {
+                let event = StatusEvent {
+                    source: "health",
+                    check: None,
+                    server: client.base_url().to_string(),
+                    status: "unreachable".to_string(),
+                    healthy: false,
+                    details: vec![format!("{:#}", e)],
+                };
+                webhook::notify_on_change(url, &event).await;
+            }
+            return Err(e);
+        }
+    };
 
     // Log partial errors as warnings (individual health checks that failed)
     for (endpoint, err) in &health_result.partial_errors {
         warn!("Failed to fetch {}: {}", endpoint, err);
     }
+    for warning in &health_result.warnings {
+        warn!("{}", warning);
+    }
 
     // Parse output format
     let format = OutputFormat::from_str(output_format)?;
@@ -46,6 +303,238 @@ pub async fn run(
 
     // Format and print results
     let output = formatter.format_health(&health_result.output)?;
+    output_result(&output, format, output_file)?;
+
+    if let Some(url) = notify_webhook {
+        webhook::notify_on_change(url, &health_event(client.base_url(), &health_result)).await;
+    }
+
+    Ok(())
+}
+
+/// Webhook status for a full health check: splunkd's overall health, with one
+/// detail line per feature that is not green.
+fn health_event(server: &str, health_result: &AggregatedHealth) -> StatusEvent {
+    let (status, details) = match &health_result.output.splunkd_health {
+        Some(health) => {
+            let mut features: Vec<_> = health
+                .features
+                .iter()
+                .filter(|(_, feature)| feature.health != HealthStatus::Green)
+                .collect();
+            features.sort_by(|a, b| a.0.cmp(b.0));
+            let details = features
+                .into_iter()
+                .map(|(name, feature)| match feature.reasons.first() {
+                    Some(reason) => format!("{}: {} ({})", name, feature.health, reason),
+                    None => format!("{}: {}", name, feature.health),
+                })
+                .collect();
+            (health.health, details)
+        }
+        None => (
+            HealthStatus::Unknown,
+            health_result
+                .partial_errors
+                .iter()
+                .map(|(endpoint, err)| format!("{}: {}", endpoint, err))
+                .collect(),
+        ),
+    };
+
+    StatusEvent {
+        source: "health",
+        check: None,
+        server: server.to_string(),
+        status: status.to_string(),
+        healthy: status == HealthStatus::Green,
+        details,
+    }
+}
+
+/// Summarize health on every target profile, one row per profile.
+pub async fn run_profiles(
+    source: &ProfileSource,
+    targets: &ProfileTargets,
+    output_format: &str,
+    output_file: Option<&std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+) -> Result<()> {
+    info!("Performing health check across profiles...");
+
+    let results = multi_profile::fan_out(source, targets, cancel, |client| async move {
+        let health_result = client.check_health_aggregate().await?;
+        for (endpoint, err) in &health_result.partial_errors {
+            warn!(
+                "Failed to fetch {} from {}: {}",
+                endpoint,
+                client.base_url(),
+                err
+            );
+        }
+        for warning in &health_result.warnings {
+            warn!("{}: {}", client.base_url(), warning);
+        }
+        Ok(vec![serde_json::to_value(&health_result.output)?])
+    })
+    .await?;
+
+    results.print(
+        &[
+            "server_info.serverName",
+            "server_info.version",
+            "splunkd_health.health",
+            "kvstore_status.currentMember.status",
+        ],
+        output_format,
+        output_file,
+    )
+}
+
+async fn run_scheduler(
+    config: splunk_config::Config,
+    since: &str,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Checking scheduled search health since {}", since);
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let scheduler_health = cancellable!(client.check_scheduler_health(since), cancel)?;
+
+    let format = OutputFormat::from_str(output_format)?;
+    let formatter = get_formatter(format);
+    let output = formatter.format_scheduler_health(&scheduler_health)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+async fn run_resources(
+    config: splunk_config::Config,
+    host: Option<&str>,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!(
+        "Checking resource usage for {}",
+        host.unwrap_or("all hosts")
+    );
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let usage = cancellable!(client.check_resource_usage(host), cancel)?;
+
+    let format = OutputFormat::from_str(output_format)?;
+    let formatter = get_formatter(format);
+    let output = formatter.format_resource_usage(&usage)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+async fn run_disk(
+    config: splunk_config::Config,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Checking disk usage per partition");
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let disk_usage = cancellable!(client.check_disk_usage(), cancel)?;
+    for warning in disk_usage.warnings() {
+        warn!("{}", warning);
+    }
+
+    let format = OutputFormat::from_str(output_format)?;
+    let formatter = get_formatter(format);
+    let output = formatter.format_disk_usage(&disk_usage)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+async fn run_search_load(
+    config: splunk_config::Config,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Checking search concurrency and per-user quotas");
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let load = cancellable!(client.check_search_load(), cancel)?;
+    for warning in load.warnings() {
+        warn!("{}", warning);
+    }
+
+    let format = OutputFormat::from_str(output_format)?;
+    let formatter = get_formatter(format);
+    let output = formatter.format_search_load(&load)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+async fn run_queues(
+    config: splunk_config::Config,
+    since: &str,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Checking ingestion queue health since {}", since);
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let queue_health = cancellable!(client.check_queue_health(since), cancel)?;
+    for warning in queue_health.warnings() {
+        warn!("{}", warning);
+    }
+
+    let format = OutputFormat::from_str(output_format)?;
+    let formatter = get_formatter(format);
+    let output = formatter.format_queue_health(&queue_health)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+async fn run_security(
+    config: splunk_config::Config,
+    since: &str,
+    threshold: u64,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!(
+        "Checking failed logins since {} (threshold {})",
+        since, threshold
+    );
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let security_health = cancellable!(client.check_security_health(since, threshold), cancel)?;
+    for warning in security_health.warnings() {
+        warn!("{}", warning);
+    }
+
+    let format = OutputFormat::from_str(output_format)?;
+    let formatter = get_formatter(format);
+    let output = formatter.format_security_health(&security_health)?;
     output_result(&output, format, output_file.as_ref())?;
 
     Ok(())
@@ -9,9 +9,11 @@
 //! - Check HEC health status
 //! - Query acknowledgment status for guaranteed delivery
 //! - Format output via shared formatters
+//! - Route token management to the `tokens` submodule, which uses the
+//!   standard REST API and connection settings
 //!
 //! Does NOT handle:
-//! - HEC token management or configuration storage
+//! - HEC configuration storage
 //! - Direct HTTP implementation (handled by client crate)
 //! - Output formatting details (see formatters module)
 //!
@@ -31,6 +33,8 @@ use tracing::info;
 
 use crate::formatters::{OutputFormat, get_formatter, output_result};
 
+pub mod tokens;
+
 /// HEC subcommands.
 #[derive(Subcommand)]
 pub enum HecCommand {
@@ -111,6 +115,12 @@ pub enum HecCommand {
         #[arg(long, value_delimiter = ',')]
         ack_ids: Vec<u64>,
     },
+
+    /// Manage HEC tokens (list, create, rotate, enable, disable).
+    Tokens {
+        #[command(subcommand)]
+        command: tokens::HecTokensCommand,
+    },
 }
 
 /// Run the HEC command.
@@ -181,6 +191,10 @@ pub async fn run(
             )
             .await
         }
+        // Token management needs connection settings; dispatch routes it to `tokens::run`.
+        HecCommand::Tokens { .. } => {
+            anyhow::bail!("HEC token management requires Splunk connection settings")
+        }
     }
 }
 
@@ -229,7 +243,7 @@ async fn run_send(
     }
 
     // Create a minimal client (HEC doesn't use standard auth)
-    let client = splunk_client::SplunkClient::builder()
+    let client = super::with_http_trace(splunk_client::SplunkClient::builder())
         .base_url(hec_url.clone())
         .auth_strategy(splunk_client::AuthStrategy::ApiToken {
             token: secrecy::SecretString::new(hec_token.clone().into()),
@@ -280,7 +294,7 @@ async fn run_send_batch(
     }
 
     // Create a minimal client (HEC doesn't use standard auth)
-    let client = splunk_client::SplunkClient::builder()
+    let client = super::with_http_trace(splunk_client::SplunkClient::builder())
         .base_url(hec_url.clone())
         .auth_strategy(splunk_client::AuthStrategy::ApiToken {
             token: secrecy::SecretString::new(hec_token.clone().into()),
@@ -317,7 +331,7 @@ async fn run_health(
     info!("Checking HEC health at {}", hec_url);
 
     // Create a minimal client (HEC doesn't use standard auth)
-    let client = splunk_client::SplunkClient::builder()
+    let client = super::with_http_trace(splunk_client::SplunkClient::builder())
         .base_url(hec_url.clone())
         .auth_strategy(splunk_client::AuthStrategy::ApiToken {
             token: secrecy::SecretString::new(hec_token.clone().into()),
@@ -359,7 +373,7 @@ async fn run_check_ack(
     }
 
     // Create a minimal client (HEC doesn't use standard auth)
-    let client = splunk_client::SplunkClient::builder()
+    let client = super::with_http_trace(splunk_client::SplunkClient::builder())
         .base_url(hec_url.clone())
         .auth_strategy(splunk_client::AuthStrategy::ApiToken {
             token: secrecy::SecretString::new(hec_token.clone().into()),
@@ -0,0 +1,361 @@
+//! HEC token management (`splunk-cli hec tokens`).
+//!
+//! Responsibilities:
+//! - List, create, rotate, enable, and disable HEC tokens via the
+//!   standard Splunk REST API (`data/inputs/http`)
+//! - Format token listings in every output format
+//!
+//! Does NOT handle:
+//! - Sending events with a token (see the parent `hec` module)
+//! - Direct REST API calls (handled by client crate)
+//!
+//! Invariants:
+//! - `list` masks token values unless `--show-token` is given
+//! - `create` and `rotate` always print the new value; it is the only way to learn it
+
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use secrecy::ExposeSecret;
+use serde::Serialize;
+use splunk_client::{HecToken, HecTokenCreateParams};
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+/// Placeholder shown instead of a token value.
+const MASKED_TOKEN: &str = "********";
+
+/// HEC token subcommands.
+#[derive(Subcommand)]
+pub enum HecTokensCommand {
+    /// List HEC tokens with their indexes, sourcetype, and status
+    #[command(after_help = "Examples:
+  splunk-cli hec tokens list
+  splunk-cli hec tokens list --show-token -o json
+")]
+    List {
+        /// Show token values instead of masking them
+        #[arg(long)]
+        show_token: bool,
+    },
+
+    /// Create a HEC token and print its generated value
+    #[command(after_help = "Examples:
+  splunk-cli hec tokens create web_events --index web
+  splunk-cli hec tokens create app --index app --indexes app,app_audit --sourcetype app:json --use-ack
+")]
+    Create {
+        /// Token name
+        name: String,
+
+        /// Default index for events that do not name one
+        #[arg(long)]
+        index: Option<String>,
+
+        /// Indexes the token may write to (comma-separated; default: any index)
+        #[arg(long, value_delimiter = ',')]
+        indexes: Vec<String>,
+
+        /// Default sourcetype for events that do not set one
+        #[arg(long)]
+        sourcetype: Option<String>,
+
+        /// Token description
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Require indexer acknowledgement
+        #[arg(long)]
+        use_ack: bool,
+    },
+
+    /// Replace a token's value with a newly generated one
+    #[command(
+        long_about = "Replace a token's value with a newly generated one.\n\nSplunk cannot regenerate a value in place, so the token is deleted and recreated in the same app with the same settings. Senders using the old value are rejected immediately. If the new token cannot be created, the old one is restored with its old value."
+    )]
+    Rotate {
+        /// Token name (with or without the `http://` prefix)
+        name: String,
+    },
+
+    /// Enable a HEC token
+    Enable {
+        /// Token name (with or without the `http://` prefix)
+        name: String,
+    },
+
+    /// Disable a HEC token; events sent with it are rejected
+    Disable {
+        /// Token name (with or without the `http://` prefix)
+        name: String,
+    },
+}
+
+/// A token as printed, with its value masked or revealed.
+#[derive(Serialize)]
+struct TokenRow<'a> {
+    name: &'a str,
+    token: String,
+    index: Option<&'a str>,
+    indexes: &'a [String],
+    sourcetype: Option<&'a str>,
+    description: Option<&'a str>,
+    disabled: bool,
+    use_ack: bool,
+}
+
+impl<'a> TokenRow<'a> {
+    fn new(token: &'a HecToken, show_token: bool) -> Self {
+        let value = match &token.token {
+            Some(value) if show_token => value.expose_secret().to_string(),
+            Some(_) => MASKED_TOKEN.to_string(),
+            None => String::new(),
+        };
+        Self {
+            name: token.input_name(),
+            token: value,
+            index: token.index.as_deref(),
+            indexes: &token.indexes,
+            sourcetype: token.sourcetype.as_deref(),
+            description: token.description.as_deref(),
+            disabled: token.disabled,
+            use_ack: token.use_ack,
+        }
+    }
+}
+
+/// Run a HEC token subcommand.
+pub async fn run(
+    config: splunk_config::Config,
+    command: HecTokensCommand,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let (tokens, show_token) = match command {
+        HecTokensCommand::List { show_token } => {
+            info!("Listing HEC tokens");
+            (cancellable!(client.list_hec_tokens(), cancel)?, show_token)
+        }
+        HecTokensCommand::Create {
+            name,
+            index,
+            indexes,
+            sourcetype,
+            description,
+            use_ack,
+        } => {
+            info!("Creating HEC token: {}", name);
+            let params = HecTokenCreateParams {
+                name,
+                index,
+                indexes,
+                sourcetype,
+                description,
+                use_ack,
+                ..Default::default()
+            };
+            let token = cancellable!(client.create_hec_token(&params), cancel)
+                .with_context(|| format!("Failed to create HEC token '{}'", params.name))?;
+            (vec![token], true)
+        }
+        HecTokensCommand::Rotate { name } => {
+            info!("Rotating HEC token: {}", name);
+            let token = cancellable!(client.rotate_hec_token(&name), cancel)
+                .with_context(|| format!("Failed to rotate HEC token '{}'", name))?;
+            (vec![token], true)
+        }
+        HecTokensCommand::Enable { name } => {
+            cancellable!(client.enable_hec_token(&name), cancel)
+                .with_context(|| format!("Failed to enable HEC token '{}'", name))?;
+            println!("HEC token '{}' enabled successfully.", name);
+            return Ok(());
+        }
+        HecTokensCommand::Disable { name } => {
+            cancellable!(client.disable_hec_token(&name), cancel)
+                .with_context(|| format!("Failed to disable HEC token '{}'", name))?;
+            println!("HEC token '{}' disabled successfully.", name);
+            return Ok(());
+        }
+    };
+
+    let output = format_tokens(&tokens, show_token, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+    Ok(())
+}
+
+/// Format HEC tokens based on the selected format.
+fn format_tokens(tokens: &[HecToken], show_token: bool, format: OutputFormat) -> Result<String> {
+    let rows: Vec<TokenRow> = tokens
+        .iter()
+        .map(|token| TokenRow::new(token, show_token))
+        .collect();
+
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(&rows)?),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(&rows)?),
+        OutputFormat::Ndjson => {
+            let mut ndjson = String::new();
+            for row in &rows {
+                ndjson.push_str(&serde_json::to_string(row)?);
+                ndjson.push('\n');
+            }
+            Ok(ndjson)
+        }
+        OutputFormat::Table => {
+            if rows.is_empty() {
+                return Ok("No HEC tokens found.".to_string());
+            }
+            let mut out = format!(
+                "{:<24} {:<36} {:<16} {:<20} {:<20} {:<4} {}\n",
+                "NAME", "TOKEN", "INDEX", "INDEXES", "SOURCETYPE", "ACK", "STATUS"
+            );
+            out.push_str(&format!(
+                "{:<24} {:<36} {:<16} {:<20} {:<20} {:<4} {}\n",
+                "====", "=====", "=====", "=======", "==========", "===", "======"
+            ));
+            for row in &rows {
+                out.push_str(&format!(
+                    "{:<24} {:<36} {:<16} {:<20} {:<20} {:<4} {}\n",
+                    row.name,
+                    row.token,
+                    row.index.unwrap_or("-"),
+                    indexes_label(row.indexes),
+                    row.sourcetype.unwrap_or("-"),
+                    if row.use_ack { "yes" } else { "no" },
+                    status_label(row.disabled)
+                ));
+            }
+            Ok(out)
+        }
+        OutputFormat::Csv => {
+            let mut csv =
+                String::from("name,token,index,indexes,sourcetype,description,disabled,use_ack\n");
+            for row in &rows {
+                csv.push_str(&format!(
+                    "{},{},{},{},{},{},{},{}\n",
+                    escape_csv(row.name),
+                    escape_csv(&row.token),
+                    escape_csv(row.index.unwrap_or_default()),
+                    escape_csv(&row.indexes.join(";")),
+                    escape_csv(row.sourcetype.unwrap_or_default()),
+                    escape_csv(row.description.unwrap_or_default()),
+                    row.disabled,
+                    row.use_ack
+                ));
+            }
+            Ok(csv)
+        }
+        OutputFormat::Xml => {
+            let mut xml =
+                String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<hec_tokens>\n");
+            for row in &rows {
+                xml.push_str(&format!(
+                    "  <hec_token name=\"{}\">\n",
+                    escape_xml(row.name)
+                ));
+                xml.push_str(&format!("    <token>{}</token>\n", escape_xml(&row.token)));
+                for (tag, value) in [
+                    ("index", row.index),
+                    ("sourcetype", row.sourcetype),
+                    ("description", row.description),
+                ] {
+                    if let Some(value) = value {
+                        xml.push_str(&format!("    <{tag}>{}</{tag}>\n", escape_xml(value)));
+                    }
+                }
+                xml.push_str("    <indexes>\n");
+                for index in row.indexes {
+                    xml.push_str(&format!("      <index>{}</index>\n", escape_xml(index)));
+                }
+                xml.push_str("    </indexes>\n");
+                xml.push_str(&format!("    <disabled>{}</disabled>\n", row.disabled));
+                xml.push_str(&format!("    <use_ack>{}</use_ack>\n", row.use_ack));
+                xml.push_str("  </hec_token>\n");
+            }
+            xml.push_str("</hec_tokens>");
+            Ok(xml)
+        }
+        OutputFormat::Markdown => {
+            let mut md = String::from("# HEC Tokens\n\n");
+            if rows.is_empty() {
+                md.push_str("_No HEC tokens found._\n");
+                return Ok(md);
+            }
+            md.push_str("| Name | Token | Index | Indexes | Sourcetype | Ack | Status |\n");
+            md.push_str("|------|-------|-------|---------|------------|-----|--------|\n");
+            for row in &rows {
+                md.push_str(&format!(
+                    "| {} | {} | {} | {} | {} | {} | {} |\n",
+                    row.name,
+                    row.token,
+                    row.index.unwrap_or("-"),
+                    indexes_label(row.indexes),
+                    row.sourcetype.unwrap_or("-"),
+                    if row.use_ack { "yes" } else { "no" },
+                    status_label(row.disabled)
+                ));
+            }
+            Ok(md)
+        }
+    }
+}
+
+fn indexes_label(indexes: &[String]) -> String {
+    if indexes.is_empty() {
+        "(any)".to_string()
+    } else {
+        indexes.join(",")
+    }
+}
+
+fn status_label(disabled: bool) -> &'static str {
+    if disabled { "Disabled" } else { "Enabled" }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use secrecy::SecretString;
+
+    fn token() -> HecToken {
+        HecToken {
+            name: "http://web_events".to_string(),
+            token: Some(SecretString::from("6b1c8f2e-0a4d-4c59")),
+            index: Some("web".to_string()),
+            indexes: vec!["web".to_string(), "web_audit".to_string()],
+            sourcetype: Some("web:json".to_string()),
+            source: None,
+            host: None,
+            outputgroup: None,
+            description: None,
+            app: None,
+            disabled: false,
+            use_ack: true,
+        }
+    }
+
+    #[test]
+    fn test_format_tokens_masks_value_unless_shown() {
+        let masked = format_tokens(&[token()], false, OutputFormat::Csv).unwrap();
+        assert!(masked.contains("web_events,********,web,web;web_audit,web:json,,false,true"));
+        assert!(!masked.contains("6b1c8f2e"));
+
+        let shown = format_tokens(&[token()], true, OutputFormat::Json).unwrap();
+        assert!(shown.contains(r#""token": "6b1c8f2e-0a4d-4c59""#));
+    }
+
+    #[test]
+    fn test_format_tokens_table_labels_any_index() {
+        let mut open = token();
+        open.indexes.clear();
+        open.disabled = true;
+        let table = format_tokens(&[open], false, OutputFormat::Table).unwrap();
+        assert!(table.contains("(any)"));
+        assert!(table.contains("Disabled"));
+    }
+}
@@ -5,11 +5,14 @@
 //! - Create new indexes with configurable parameters
 //! - Modify existing index properties
 //! - Delete indexes with confirmation
+//! - Roll hot buckets and control data rebalance before maintenance
+//! - Sample the newest raw events of an index (see `sample`)
 //! - Show detailed index information when requested
+//! - List indexes across several profiles (`indexes list --profiles a,b`)
 //! - Format output via shared formatters
 //!
 //! Does NOT handle:
-//! - Index data ingestion or searching
+//! - Index data ingestion or free-form searching
 //! - Direct REST API calls (handled by client crate)
 //! - Output formatting details (see formatters module)
 //!
@@ -23,9 +26,16 @@ use anyhow::Result;
 use clap::Subcommand;
 use tracing::info;
 
-use crate::formatters::{OutputFormat, Pagination, TableFormatter, get_formatter, output_result};
+use crate::commands::multi_profile::{self, ProfileSource, ProfileTargets};
+use crate::formatters::{
+    ClusterManagementOutput, OutputFormat, Pagination, TableFormatter, get_formatter, output_result,
+};
+use splunk_client::DataRebalanceAction;
+use splunk_client::workflows::event_sample::DEFAULT_SAMPLE_COUNT;
 use splunk_config::constants::*;
 
+mod sample;
+
 #[derive(Subcommand)]
 pub enum IndexesCommand {
     /// List all indexes (default)
@@ -39,6 +49,11 @@ pub enum IndexesCommand {
         /// Offset into the index list (zero-based)
         #[arg(long, default_value = "0")]
         offset: usize,
+        /// Fetch every page instead of a single page of --count entries
+        #[arg(long, conflicts_with_all = ["count", "offset"])]
+        all: bool,
+        #[command(flatten)]
+        targets: ProfileTargets,
     },
     /// Create a new index
     Create {
@@ -106,6 +121,44 @@ pub enum IndexesCommand {
         #[arg(short, long)]
         force: bool,
     },
+    /// Roll an index's hot buckets to warm (e.g., before a maintenance window)
+    Roll {
+        /// Index name (required)
+        name: String,
+    },
+    /// Show the newest raw events of an index
+    #[command(after_help = "Examples:
+  splunk-cli indexes sample main
+  splunk-cli indexes sample web --sourcetype access_combined --count 10
+  splunk-cli indexes sample web -o json
+")]
+    Sample {
+        /// Index name (required)
+        name: String,
+        /// Number of events to show (at most 1000)
+        #[arg(short, long, default_value_t = DEFAULT_SAMPLE_COUNT)]
+        count: usize,
+        /// Only show events of this sourcetype
+        #[arg(long)]
+        sourcetype: Option<String>,
+    },
+    /// Start, stop, or check a data rebalance across cluster peers
+    #[command(after_help = "Examples:
+  splunk-cli indexes rebalance start --index main
+  splunk-cli indexes rebalance status
+  splunk-cli indexes rebalance stop
+
+Runs on the cluster manager. Data rebalance moves bucket copies so every peer holds
+an even share; `cluster rebalance` only reassigns primaries.
+")]
+    Rebalance {
+        /// Rebalance action
+        #[arg(value_parser = ["start", "stop", "status"])]
+        action: String,
+        /// Only rebalance this index (default: all indexes)
+        #[arg(long, value_name = "NAME")]
+        index: Option<String>,
+    },
 }
 
 pub async fn run(
@@ -121,12 +174,15 @@ pub async fn run(
             detailed,
             count,
             offset,
+            all,
+            ..
         } => {
             run_list(
                 config,
                 detailed,
                 count,
                 offset,
+                all,
                 output_format,
                 output_file,
                 cancel,
@@ -191,6 +247,36 @@ pub async fn run(
         IndexesCommand::Delete { name, force } => {
             run_delete(config, &name, force, cancel, no_cache).await
         }
+        IndexesCommand::Roll { name } => run_roll(config, &name, cancel, no_cache).await,
+        IndexesCommand::Sample {
+            name,
+            count,
+            sourcetype,
+        } => {
+            sample::run_sample(
+                config,
+                &name,
+                count,
+                sourcetype.as_deref(),
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
+        IndexesCommand::Rebalance { action, index } => {
+            run_rebalance(
+                config,
+                &action,
+                index,
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
     }
 }
 
@@ -200,6 +286,7 @@ async fn run_list(
     detailed: bool,
     count: usize,
     offset: usize,
+    all: bool,
     output_format: &str,
     output_file: Option<std::path::PathBuf>,
     cancel: &crate::cancellation::CancellationToken,
@@ -212,13 +299,23 @@ async fn run_list(
     // Avoid sending offset=0 unless user explicitly paginates; both are functionally OK.
     let offset_param = if offset == 0 { None } else { Some(offset) };
 
-    let indexes = cancellable!(client.list_indexes(Some(count), offset_param), cancel)?;
+    let indexes = if all {
+        cancellable!(
+            crate::commands::fetch_all_pages(
+                |count, offset| client.list_indexes(Some(count), Some(offset))
+            ),
+            cancel
+        )?
+    } else {
+        cancellable!(client.list_indexes(Some(count), offset_param), cancel)?
+    };
 
     // Parse output format
     let format = OutputFormat::from_str(output_format)?;
 
     // Table output gets pagination footer; machine-readable formats must not.
-    if format == OutputFormat::Table {
+    // A complete listing has no further pages to hint at.
+    if format == OutputFormat::Table && !all {
         let formatter = TableFormatter;
         let pagination = Pagination {
             offset,
@@ -236,6 +333,54 @@ async fn run_list(
     Ok(())
 }
 
+/// List indexes on every target profile; `detailed` keeps every column in tables.
+#[allow(clippy::too_many_arguments)]
+pub async fn run_list_profiles(
+    source: &ProfileSource,
+    targets: &ProfileTargets,
+    detailed: bool,
+    count: usize,
+    offset: usize,
+    all: bool,
+    output_format: &str,
+    output_file: Option<&std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+) -> Result<()> {
+    info!(
+        "Listing indexes across profiles (count: {}, offset: {})",
+        count, offset
+    );
+
+    let offset_param = if offset == 0 { None } else { Some(offset) };
+    let results = multi_profile::fan_out(source, targets, cancel, |client| async move {
+        let indexes = if all {
+            crate::commands::fetch_all_pages(|count, offset| {
+                client.list_indexes(Some(count), Some(offset))
+            })
+            .await?
+        } else {
+            client.list_indexes(Some(count), offset_param).await?
+        };
+        Ok(indexes
+            .iter()
+            .map(serde_json::to_value)
+            .collect::<serde_json::Result<Vec<_>>>()?)
+    })
+    .await?;
+
+    let columns: &[&str] = if detailed {
+        &[]
+    } else {
+        &[
+            "name",
+            "currentDBSizeMB",
+            "totalEventCount",
+            "maxTotalDataSizeMB",
+        ]
+    };
+    results.print(columns, output_format, output_file)
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn run_create(
     config: splunk_config::Config,
@@ -329,3 +474,57 @@ async fn run_delete(
         Ok(())
     })
 }
+
+async fn run_roll(
+    config: splunk_config::Config,
+    name: &str,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Rolling hot buckets of index: {}", name);
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    cancellable_with!(client.roll_hot_buckets(name), cancel, |_res| {
+        println!("Hot buckets of index '{}' rolled to warm.", name);
+        Ok(())
+    })
+}
+
+async fn run_rebalance(
+    config: splunk_config::Config,
+    action: &str,
+    index: Option<String>,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    let action = match action {
+        "start" => DataRebalanceAction::Start,
+        "stop" => DataRebalanceAction::Stop,
+        _ => DataRebalanceAction::Status,
+    };
+    let target = index.as_deref().unwrap_or("all indexes");
+    info!("Data rebalance {} for {}", action, target);
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let result = cancellable!(
+        client.control_data_rebalance(action, index.as_deref()),
+        cancel
+    )?;
+
+    let output = ClusterManagementOutput {
+        operation: format!("data rebalance {}", action),
+        target: target.to_string(),
+        success: result.success,
+        message: result
+            .message
+            .unwrap_or_else(|| format!("Data rebalance {} completed", action)),
+    };
+    let format = OutputFormat::from_str(output_format)?;
+    let formatted = get_formatter(format).format_cluster_management(&output)?;
+    output_result(&formatted, format, output_file.as_ref())?;
+    Ok(())
+}
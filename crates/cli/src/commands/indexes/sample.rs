@@ -0,0 +1,178 @@
+//! `indexes sample` implementation.
+//!
+//! Responsibilities:
+//! - Fetch the newest raw events of an index, optionally one sourcetype
+//! - Print them as a raw-event listing (table) or in machine-readable formats
+//!
+//! Does NOT handle:
+//! - Building or running the sampling search (handled by
+//!   `splunk_client::workflows::event_sample`)
+
+use anyhow::Result;
+use splunk_client::workflows::event_sample::{SampleEvent, sample_index_events};
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+/// Fetch and print sample events of an index.
+#[allow(clippy::too_many_arguments)]
+pub async fn run_sample(
+    config: splunk_config::Config,
+    name: &str,
+    count: usize,
+    sourcetype: Option<&str>,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!(
+        "Sampling {} events of index {} (sourcetype: {:?})",
+        count, name, sourcetype
+    );
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let events = cancellable!(
+        sample_index_events(&client, name, sourcetype, count),
+        cancel
+    )?;
+
+    let output = format_sample_events(&events, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+    Ok(())
+}
+
+/// Header line shown above each raw event in table and markdown output.
+fn event_header(event: &SampleEvent) -> String {
+    let mut header = event.time.clone().unwrap_or_else(|| "-".to_string());
+    for (label, value) in [
+        ("host", &event.host),
+        ("source", &event.source),
+        ("sourcetype", &event.sourcetype),
+    ] {
+        if let Some(value) = value {
+            header.push_str(&format!("  {}={}", label, value));
+        }
+    }
+    header
+}
+
+/// Format sample events based on the selected format.
+fn format_sample_events(events: &[SampleEvent], format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(events)?),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(events)?),
+        OutputFormat::Ndjson => {
+            let mut ndjson = String::new();
+            for event in events {
+                ndjson.push_str(&serde_json::to_string(event)?);
+                ndjson.push('\n');
+            }
+            Ok(ndjson)
+        }
+        OutputFormat::Table => {
+            if events.is_empty() {
+                return Ok("No events found.".to_string());
+            }
+            let mut out = String::new();
+            for event in events {
+                out.push_str(&event_header(event));
+                out.push('\n');
+                out.push_str(&event.raw);
+                out.push_str("\n\n");
+            }
+            out.push_str(&format!("{} event(s).\n", events.len()));
+            Ok(out)
+        }
+        OutputFormat::Csv => {
+            let mut csv = String::from("_time,index,host,source,sourcetype,_raw\n");
+            for event in events {
+                let fields = [
+                    &event.time,
+                    &event.index,
+                    &event.host,
+                    &event.source,
+                    &event.sourcetype,
+                ];
+                for value in fields {
+                    csv.push_str(&escape_csv(value.as_deref().unwrap_or_default()));
+                    csv.push(',');
+                }
+                csv.push_str(&escape_csv(&event.raw));
+                csv.push('\n');
+            }
+            Ok(csv)
+        }
+        OutputFormat::Xml => {
+            let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<events>\n");
+            for event in events {
+                xml.push_str("  <event>\n");
+                for (tag, value) in [
+                    ("time", &event.time),
+                    ("index", &event.index),
+                    ("host", &event.host),
+                    ("source", &event.source),
+                    ("sourcetype", &event.sourcetype),
+                ] {
+                    if let Some(value) = value {
+                        xml.push_str(&format!("    <{tag}>{}</{tag}>\n", escape_xml(value)));
+                    }
+                }
+                xml.push_str(&format!("    <raw>{}</raw>\n", escape_xml(&event.raw)));
+                xml.push_str("  </event>\n");
+            }
+            xml.push_str("</events>");
+            Ok(xml)
+        }
+        OutputFormat::Markdown => {
+            let mut md = String::from("# Sample Events\n\n");
+            if events.is_empty() {
+                md.push_str("_No events found._\n");
+                return Ok(md);
+            }
+            for event in events {
+                md.push_str(&format!(
+                    "**{}**\n\n```\n{}\n```\n\n",
+                    event_header(event),
+                    event.raw
+                ));
+            }
+            Ok(md)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event() -> SampleEvent {
+        SampleEvent {
+            time: Some("2024-01-15T10:30:00.000+00:00".to_string()),
+            index: Some("web".to_string()),
+            host: Some("web01".to_string()),
+            source: None,
+            sourcetype: Some("access_combined".to_string()),
+            raw: "10.0.0.1 - - \"GET / HTTP/1.1\" 200".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_format_table_shows_header_then_raw_event() {
+        let table = format_sample_events(&[event()], OutputFormat::Table).unwrap();
+        assert_eq!(
+            table,
+            "2024-01-15T10:30:00.000+00:00  host=web01  sourcetype=access_combined\n10.0.0.1 - - \"GET / HTTP/1.1\" 200\n\n1 event(s).\n"
+        );
+    }
+
+    #[test]
+    fn test_format_csv_escapes_raw_event() {
+        let csv = format_sample_events(&[event()], OutputFormat::Csv).unwrap();
+        assert!(csv.ends_with(
+            "2024-01-15T10:30:00.000+00:00,web,web01,,access_combined,\"10.0.0.1 - - \"\"GET / HTTP/1.1\"\" 200\"\n"
+        ));
+    }
+}
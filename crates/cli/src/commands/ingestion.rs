@@ -0,0 +1,290 @@
+//! Ingestion statistics command implementation.
+//!
+//! Responsibilities:
+//! - Report indexing latency (index time minus event time) and thruput per
+//!   sourcetype, index, or host over a look-back window
+//! - Format the statistics in every output format
+//!
+//! Does NOT handle:
+//! - Direct REST API calls or the underlying searches (handled by client crate)
+//! - Per-input throughput (see `inputs list --throughput`)
+//!
+//! Invariants:
+//! - Groups are listed highest thruput first
+//! - Latency is reported in seconds; groups with no searchable events show `-`
+
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Subcommand;
+use splunk_client::{IngestionGroupBy, IngestionStats};
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+#[derive(Subcommand)]
+pub enum IngestionCommand {
+    /// Show indexing latency and thruput over a recent window
+    #[command(after_help = "Examples:
+  splunk-cli ingestion stats
+  splunk-cli ingestion stats --by sourcetype --since 1h
+  splunk-cli ingestion stats --by index --since 15m -o json
+")]
+    Stats {
+        /// Group by this field (sourcetype, index, host)
+        #[arg(long, value_name = "FIELD", default_value = "sourcetype")]
+        by: IngestionGroupBy,
+        /// Look-back window (e.g. 30m, 1h, 24h)
+        #[arg(
+            long,
+            value_name = "DURATION",
+            default_value = "1h",
+            value_parser = crate::commands::generate::parse_duration
+        )]
+        since: Duration,
+    },
+}
+
+pub async fn run(
+    config: splunk_config::Config,
+    command: IngestionCommand,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    match command {
+        IngestionCommand::Stats { by, since } => {
+            run_stats(
+                config,
+                by,
+                since,
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
+    }
+}
+
+async fn run_stats(
+    config: splunk_config::Config,
+    by: IngestionGroupBy,
+    since: Duration,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!(
+        "Getting ingestion stats by {} over {}s",
+        by,
+        since.as_secs()
+    );
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let stats = cancellable!(client.get_ingestion_stats(by, since.as_secs()), cancel)?;
+
+    let output = format_ingestion_stats(&stats, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+    Ok(())
+}
+
+/// Format ingestion statistics based on the selected format.
+pub fn format_ingestion_stats(stats: &IngestionStats, format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(stats)?),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(stats)?),
+        OutputFormat::Ndjson => {
+            let mut ndjson = String::new();
+            for stat in &stats.stats {
+                ndjson.push_str(&serde_json::to_string(stat)?);
+                ndjson.push('\n');
+            }
+            Ok(ndjson)
+        }
+        OutputFormat::Table => {
+            if stats.stats.is_empty() {
+                return Ok(format!("No ingestion in the last {}s.", stats.window_secs));
+            }
+            let header = stats.group_by.as_str().to_uppercase();
+            let mut out = format!(
+                "{:<32} {:>12} {:>12} {:>10} {:>10} {:>12} {:>12}\n",
+                header, "EVENTS", "KB", "KB/S", "EPS", "AVG LATENCY", "MAX LATENCY"
+            );
+            out.push_str(&format!(
+                "{:<32} {:>12} {:>12} {:>10} {:>10} {:>12} {:>12}\n",
+                "=".repeat(header.len()),
+                "======",
+                "==",
+                "====",
+                "===",
+                "===========",
+                "==========="
+            ));
+            for stat in &stats.stats {
+                out.push_str(&format!(
+                    "{:<32} {:>12} {:>12.1} {:>10.2} {:>10.2} {:>12} {:>12}\n",
+                    stat.name,
+                    stat.events,
+                    stat.kb,
+                    stat.kb_per_sec,
+                    stat.events_per_sec,
+                    format_latency(stat.avg_latency_secs),
+                    format_latency(stat.max_latency_secs)
+                ));
+            }
+            Ok(out)
+        }
+        OutputFormat::Csv => {
+            let mut csv = format!(
+                "{},events,kb,kb_per_sec,events_per_sec,avg_latency_secs,max_latency_secs\n",
+                stats.group_by
+            );
+            for stat in &stats.stats {
+                csv.push_str(&format!(
+                    "{},{},{},{},{},{},{}\n",
+                    escape_csv(&stat.name),
+                    stat.events,
+                    stat.kb,
+                    stat.kb_per_sec,
+                    stat.events_per_sec,
+                    stat.avg_latency_secs
+                        .map(|v| v.to_string())
+                        .unwrap_or_default(),
+                    stat.max_latency_secs
+                        .map(|v| v.to_string())
+                        .unwrap_or_default()
+                ));
+            }
+            Ok(csv)
+        }
+        OutputFormat::Xml => {
+            let mut xml = format!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<ingestion group_by=\"{}\" window_secs=\"{}\">\n",
+                stats.group_by, stats.window_secs
+            );
+            for stat in &stats.stats {
+                xml.push_str(&format!("  <group name=\"{}\">\n", escape_xml(&stat.name)));
+                xml.push_str(&format!("    <events>{}</events>\n", stat.events));
+                xml.push_str(&format!("    <kb>{}</kb>\n", stat.kb));
+                xml.push_str(&format!(
+                    "    <kb_per_sec>{}</kb_per_sec>\n",
+                    stat.kb_per_sec
+                ));
+                xml.push_str(&format!(
+                    "    <events_per_sec>{}</events_per_sec>\n",
+                    stat.events_per_sec
+                ));
+                if let Some(avg) = stat.avg_latency_secs {
+                    xml.push_str(&format!(
+                        "    <avg_latency_secs>{}</avg_latency_secs>\n",
+                        avg
+                    ));
+                }
+                if let Some(max) = stat.max_latency_secs {
+                    xml.push_str(&format!(
+                        "    <max_latency_secs>{}</max_latency_secs>\n",
+                        max
+                    ));
+                }
+                xml.push_str("  </group>\n");
+            }
+            xml.push_str("</ingestion>");
+            Ok(xml)
+        }
+        OutputFormat::Markdown => {
+            let mut md = format!(
+                "# Ingestion by {} (last {}s)\n\n",
+                stats.group_by, stats.window_secs
+            );
+            if stats.stats.is_empty() {
+                md.push_str("_No ingestion in the window._\n");
+                return Ok(md);
+            }
+            md.push_str(&format!(
+                "| {} | Events | KB | KB/s | EPS | Avg Latency | Max Latency |\n",
+                stats.group_by
+            ));
+            md.push_str("|---|--------|----|------|-----|-------------|-------------|\n");
+            for stat in &stats.stats {
+                md.push_str(&format!(
+                    "| {} | {} | {:.1} | {:.2} | {:.2} | {} | {} |\n",
+                    stat.name,
+                    stat.events,
+                    stat.kb,
+                    stat.kb_per_sec,
+                    stat.events_per_sec,
+                    format_latency(stat.avg_latency_secs),
+                    format_latency(stat.max_latency_secs)
+                ));
+            }
+            Ok(md)
+        }
+    }
+}
+
+fn format_latency(secs: Option<f64>) -> String {
+    secs.map(|s| format!("{:.1}s", s))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use splunk_client::IngestionStat;
+
+    fn stats() -> IngestionStats {
+        IngestionStats {
+            group_by: IngestionGroupBy::Sourcetype,
+            window_secs: 3600,
+            stats: vec![
+                IngestionStat {
+                    name: "access_combined".to_string(),
+                    events: 7200,
+                    kb: 3600.0,
+                    kb_per_sec: 1.0,
+                    events_per_sec: 2.0,
+                    avg_latency_secs: Some(2.5),
+                    max_latency_secs: Some(31.0),
+                },
+                IngestionStat {
+                    name: "splunkd".to_string(),
+                    events: 360,
+                    kb: 36.0,
+                    kb_per_sec: 0.01,
+                    events_per_sec: 0.1,
+                    avg_latency_secs: None,
+                    max_latency_secs: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_ingestion_table_shows_latency_and_rates() {
+        let output = format_ingestion_stats(&stats(), OutputFormat::Table).unwrap();
+        assert!(output.starts_with("SOURCETYPE"));
+        assert!(output.contains("access_combined"));
+        assert!(output.contains("2.5s"));
+        assert!(output.contains("31.0s"));
+    }
+
+    #[test]
+    fn test_ingestion_csv_leaves_missing_latency_empty() {
+        let output = format_ingestion_stats(&stats(), OutputFormat::Csv).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "sourcetype,events,kb,kb_per_sec,events_per_sec,avg_latency_secs,max_latency_secs",
+                "access_combined,7200,3600,1,2,2.5,31",
+                "splunkd,360,36,0.01,0.1,,"
+            ]
+        );
+    }
+}
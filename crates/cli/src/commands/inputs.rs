@@ -4,10 +4,12 @@
 //! - List data inputs with optional type filtering (tcp/raw, tcp/cooked, udp, monitor, script)
 //! - Support pagination via offset parameter
 //! - Show detailed input information when requested
+//! - Send test lines to a network input and verify they arrive (`test-send`)
+//! - Create monitor/TCP/UDP inputs and delete inputs (`add`, `delete`)
 //! - Format output via shared formatters
 //!
 //! Does NOT handle:
-//! - Input configuration or creation
+//! - Editing the settings of existing inputs
 //! - Direct REST API calls (handled by client crate)
 //! - Output formatting details (see formatters module)
 //!
@@ -22,6 +24,12 @@ use tracing::info;
 use crate::formatters::{OutputFormat, Pagination, TableFormatter, get_formatter, output_result};
 use splunk_config::constants::*;
 
+mod add;
+mod test_send;
+
+pub use add::AddInputCommand;
+pub use test_send::Transport;
+
 /// Inputs subcommands.
 #[derive(Subcommand)]
 pub enum InputsCommand {
@@ -43,11 +51,75 @@ pub enum InputsCommand {
         #[arg(long, default_value = "0")]
         offset: usize,
     },
+
+    /// Create a monitor, TCP, or UDP input
+    Add {
+        #[command(subcommand)]
+        input: AddInputCommand,
+    },
+
+    /// Delete an input
+    #[command(
+        after_help = "Examples:\n  splunk-cli inputs delete monitor /var/log/app\n  splunk-cli inputs delete tcp/raw 1514 --force\n"
+    )]
+    Delete {
+        /// Input type (tcp/raw, tcp/cooked, udp, monitor, script)
+        input_type: String,
+
+        /// Input name: the monitored path or the port
+        name: String,
+
+        /// Skip confirmation prompt
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Send test lines to a raw TCP/UDP input and verify they become searchable
+    #[command(
+        after_help = "Examples:\n  splunk-cli inputs test-send --type tcp --port 1514 --host splunk01 --count 10\n  splunk-cli inputs test-send --type tcp --port 6514 --tls --index network\n  splunk-cli inputs test-send --type udp --port 514 --no-verify\n"
+    )]
+    TestSend {
+        /// Transport of the input under test
+        #[arg(long = "type", value_enum, default_value_t = Transport::Tcp)]
+        transport: Transport,
+
+        /// Port the input listens on
+        #[arg(long)]
+        port: u16,
+
+        /// Host to send to (defaults to the host of the Splunk base URL)
+        #[arg(long)]
+        host: Option<String>,
+
+        /// Number of test lines to send
+        #[arg(long, default_value_t = 10)]
+        count: u64,
+
+        /// Wrap the TCP connection in TLS
+        #[arg(long)]
+        tls: bool,
+
+        /// Skip TLS certificate verification
+        #[arg(long, requires = "tls")]
+        insecure: bool,
+
+        /// Index the input writes to (verification searches all indexes by default)
+        #[arg(long)]
+        index: Option<String>,
+
+        /// Seconds to wait for the test lines to become searchable
+        #[arg(long, default_value_t = 60)]
+        verify_timeout: u64,
+
+        /// Only send the lines; skip the verification search
+        #[arg(long)]
+        no_verify: bool,
+    },
 }
 
 /// Run the inputs command.
 ///
-/// Lists data inputs from the Splunk server.
+/// Lists, creates, deletes, or test-sends to data inputs on the Splunk server.
 ///
 /// # Arguments
 ///
@@ -88,6 +160,43 @@ pub async fn run(
             )
             .await
         }
+        InputsCommand::Add { input } => add::run_add(config, input, cancel, no_cache).await,
+        InputsCommand::Delete {
+            input_type,
+            name,
+            force,
+        } => add::run_delete(config, &input_type, &name, force, cancel, no_cache).await,
+        InputsCommand::TestSend {
+            transport,
+            port,
+            host,
+            count,
+            tls,
+            insecure,
+            index,
+            verify_timeout,
+            no_verify,
+        } => {
+            test_send::run_test_send(
+                config,
+                test_send::TestSendOptions {
+                    transport,
+                    host,
+                    port,
+                    count,
+                    tls,
+                    insecure,
+                    index,
+                    verify_timeout: std::time::Duration::from_secs(verify_timeout),
+                    no_verify,
+                },
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
     }
 }
 
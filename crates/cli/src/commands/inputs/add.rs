@@ -0,0 +1,212 @@
+//! `inputs add` and `inputs delete` implementation.
+//!
+//! Responsibilities:
+//! - Map the `add monitor|tcp|udp` subcommands onto typed [`CreateInputParams`]
+//! - Create the input and report its name, type, index, and sourcetype
+//! - Delete an input after confirmation
+//!
+//! Does NOT handle:
+//! - Scripted or cooked TCP inputs
+//! - Direct REST API calls (handled by client crate)
+
+use anyhow::{Context, Result};
+use clap::{Args, Subcommand};
+use splunk_client::{CreateInputParams, NewInput};
+use tracing::info;
+
+/// Settings shared by every input type.
+#[derive(Args)]
+pub struct CommonInputArgs {
+    /// Destination index (Splunk defaults to main)
+    #[arg(long)]
+    pub index: Option<String>,
+
+    /// Sourcetype to assign to the input's events
+    #[arg(long)]
+    pub sourcetype: Option<String>,
+
+    /// Host field value for the input's events
+    #[arg(long)]
+    pub host: Option<String>,
+}
+
+/// Input types that can be created.
+#[derive(Subcommand)]
+pub enum AddInputCommand {
+    /// Monitor a file or directory
+    #[command(after_help = "Examples:
+  splunk-cli inputs add monitor /var/log/app --index app --sourcetype app:log
+  splunk-cli inputs add monitor /var/log/nginx --whitelist '\\.log$' --no-recursive
+")]
+    Monitor {
+        /// File or directory to monitor
+        path: String,
+
+        /// Only monitor files whose path matches this regex
+        #[arg(long)]
+        whitelist: Option<String>,
+
+        /// Skip files whose path matches this regex
+        #[arg(long)]
+        blacklist: Option<String>,
+
+        /// Do not descend into subdirectories
+        #[arg(long)]
+        no_recursive: bool,
+
+        #[command(flatten)]
+        common: CommonInputArgs,
+    },
+
+    /// Listen for raw data on a TCP port
+    #[command(after_help = "Examples:
+  splunk-cli inputs add tcp 1514 --index network --sourcetype syslog
+")]
+    Tcp {
+        /// Port to listen on
+        port: u16,
+
+        #[command(flatten)]
+        common: CommonInputArgs,
+    },
+
+    /// Listen for data on a UDP port
+    #[command(after_help = "Examples:
+  splunk-cli inputs add udp 514 --index network --sourcetype syslog
+")]
+    Udp {
+        /// Port to listen on
+        port: u16,
+
+        #[command(flatten)]
+        common: CommonInputArgs,
+    },
+}
+
+impl From<AddInputCommand> for CreateInputParams {
+    fn from(command: AddInputCommand) -> Self {
+        let (input, common) = match command {
+            AddInputCommand::Monitor {
+                path,
+                whitelist,
+                blacklist,
+                no_recursive,
+                common,
+            } => (
+                NewInput::Monitor {
+                    path,
+                    whitelist,
+                    blacklist,
+                    // Only send `recursive` when overriding Splunk's default.
+                    recursive: no_recursive.then_some(false),
+                },
+                common,
+            ),
+            AddInputCommand::Tcp { port, common } => (NewInput::Tcp { port }, common),
+            AddInputCommand::Udp { port, common } => (NewInput::Udp { port }, common),
+        };
+        CreateInputParams {
+            input,
+            index: common.index,
+            sourcetype: common.sourcetype,
+            host: common.host,
+        }
+    }
+}
+
+/// Create an input from an `inputs add` subcommand.
+pub async fn run_add(
+    config: splunk_config::Config,
+    command: AddInputCommand,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    let params = CreateInputParams::from(command);
+    info!("Creating {} input: {}", params.input_type(), params.name());
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let input = cancellable!(client.create_input(&params), cancel).with_context(|| {
+        format!(
+            "Failed to create {} input '{}'",
+            params.input_type(),
+            params.name()
+        )
+    })?;
+
+    println!(
+        "Input '{}' ({}) created successfully (index: {}, sourcetype: {}).",
+        input.name,
+        input.input_type,
+        params.index.as_deref().unwrap_or("default"),
+        input.sourcetype.as_deref().unwrap_or("automatic")
+    );
+    Ok(())
+}
+
+/// Delete an input after confirmation.
+pub async fn run_delete(
+    config: splunk_config::Config,
+    input_type: &str,
+    name: &str,
+    force: bool,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    if !force && !crate::interactive::confirm_delete(name, &format!("{} input", input_type))? {
+        return Ok(());
+    }
+
+    info!("Deleting {} input: {}", input_type, name);
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    cancellable_with!(client.delete_input(input_type, name), cancel, |_res| {
+        println!("Input '{}' ({}) deleted successfully.", name, input_type);
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_monitor_command_only_sends_recursive_when_disabled() {
+        let common = || CommonInputArgs {
+            index: Some("app".to_string()),
+            sourcetype: Some("app:log".to_string()),
+            host: None,
+        };
+        let params = CreateInputParams::from(AddInputCommand::Monitor {
+            path: "/var/log/app".to_string(),
+            whitelist: None,
+            blacklist: None,
+            no_recursive: false,
+            common: common(),
+        });
+        assert!(matches!(
+            params.input,
+            NewInput::Monitor {
+                recursive: None,
+                ..
+            }
+        ));
+        assert_eq!(params.index.as_deref(), Some("app"));
+
+        let params = CreateInputParams::from(AddInputCommand::Monitor {
+            path: "/var/log/app".to_string(),
+            whitelist: None,
+            blacklist: None,
+            no_recursive: true,
+            common: common(),
+        });
+        assert!(matches!(
+            params.input,
+            NewInput::Monitor {
+                recursive: Some(false),
+                ..
+            }
+        ));
+    }
+}
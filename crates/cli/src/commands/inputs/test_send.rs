@@ -0,0 +1,584 @@
+//! End-to-end test sender for network (TCP/UDP) data inputs.
+//!
+//! Responsibilities:
+//! - Send uniquely tagged test lines to a raw TCP or UDP input, optionally over TLS.
+//! - Poll a follow-up search until the lines become searchable or a timeout elapses.
+//! - Report what was sent and what arrived in every output format.
+//!
+//! Does NOT handle:
+//! - Splunk-to-Splunk (cooked) receivers such as `splunktcp`; lines sent there are dropped.
+//! - Creating or reconfiguring the input under test.
+//!
+//! Invariants:
+//! - Every run uses a fresh marker, so the verification search only counts this run's lines.
+//! - Each line is newline-terminated; each UDP line is sent as its own datagram.
+//! - The command fails after printing the report when fewer lines arrived than were sent.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result, bail};
+use chrono::Utc;
+use clap::ValueEnum;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{ClientConfig, DigitallySignedStruct, SignatureScheme};
+use rustls_platform_verifier::BuilderVerifierExt;
+use serde::Serialize;
+use splunk_client::SearchRequest;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio::net::{TcpStream, UdpSocket};
+use tokio_rustls::TlsConnector;
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+/// Time allowed to open the TCP connection (and complete the TLS handshake).
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Delay between verification searches.
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Network input transport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Transport {
+    /// Raw TCP input (`[tcp://PORT]`)
+    Tcp,
+    /// UDP input (`[udp://PORT]`)
+    Udp,
+}
+
+impl std::fmt::Display for Transport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tcp => write!(f, "tcp"),
+            Self::Udp => write!(f, "udp"),
+        }
+    }
+}
+
+/// Options for a test-send run.
+#[derive(Debug, Clone)]
+pub struct TestSendOptions {
+    pub transport: Transport,
+    pub host: Option<String>,
+    pub port: u16,
+    pub count: u64,
+    pub tls: bool,
+    pub insecure: bool,
+    pub index: Option<String>,
+    pub verify_timeout: Duration,
+    pub no_verify: bool,
+}
+
+/// Outcome of the follow-up verification search.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Verification {
+    pub search: String,
+    pub found: u64,
+    pub elapsed_secs: u64,
+    pub timed_out: bool,
+}
+
+/// Summary of a test-send run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TestSendReport {
+    pub transport: Transport,
+    pub host: String,
+    pub port: u16,
+    pub tls: bool,
+    pub marker: String,
+    pub sent: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification: Option<Verification>,
+}
+
+impl TestSendReport {
+    /// Whether every sent line was found (always true when verification was skipped).
+    pub fn passed(&self) -> bool {
+        self.verification
+            .as_ref()
+            .is_none_or(|verification| verification.found >= self.sent)
+    }
+}
+
+pub(super) async fn run_test_send(
+    config: splunk_config::Config,
+    options: TestSendOptions,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    if options.tls && options.transport == Transport::Udp {
+        bail!("--tls is only supported with --type tcp");
+    }
+    if options.count == 0 {
+        bail!("--count must be at least 1");
+    }
+
+    let format = OutputFormat::from_str(output_format)?;
+    let host = match options.host.clone() {
+        Some(host) => host,
+        None => default_host(&config.connection.base_url)?,
+    };
+    let marker = new_marker();
+    let lines = build_lines(&marker, options.count);
+
+    info!(
+        "Sending {} test line(s) to {}://{}:{} (tls: {}, marker: {})",
+        options.count, options.transport, host, options.port, options.tls, marker
+    );
+    match options.transport {
+        Transport::Tcp => cancellable!(
+            send_tcp(&host, options.port, options.tls, options.insecure, &lines),
+            cancel
+        )?,
+        Transport::Udp => cancellable!(send_udp(&host, options.port, &lines), cancel)?,
+    }
+
+    let verification = if options.no_verify {
+        None
+    } else {
+        let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+        let search = verification_search(&marker, options.index.as_deref());
+        Some(
+            verify_arrival(
+                &client,
+                search,
+                options.count,
+                options.verify_timeout,
+                cancel,
+            )
+            .await?,
+        )
+    };
+
+    let report = TestSendReport {
+        transport: options.transport,
+        host,
+        port: options.port,
+        tls: options.tls,
+        marker,
+        sent: options.count,
+        verification,
+    };
+
+    let output = format_test_send_report(&report, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    if !report.passed() {
+        let found = report.verification.as_ref().map_or(0, |v| v.found);
+        bail!(
+            "Only {} of {} test line(s) became searchable; check firewall rules, the input's port and index, and its sourcetype/line breaking",
+            found,
+            report.sent
+        );
+    }
+
+    Ok(())
+}
+
+/// Host of the configured Splunk management URL.
+fn default_host(base_url: &str) -> Result<String> {
+    let url = url::Url::parse(base_url)
+        .with_context(|| format!("Invalid base URL '{}'; pass --host", base_url))?;
+    url.host_str()
+        .map(str::to_string)
+        .context("Base URL has no host; pass --host")
+}
+
+fn new_marker() -> String {
+    format!("splunkcli_testsend_{:016x}", rand::random::<u64>())
+}
+
+fn build_lines(marker: &str, count: u64) -> Vec<String> {
+    let now = Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ");
+    (1..=count)
+        .map(|seq| {
+            format!(
+                "{} splunk-cli inputs test-send marker={} seq={}/{}\n",
+                now, marker, seq, count
+            )
+        })
+        .collect()
+}
+
+fn verification_search(marker: &str, index: Option<&str>) -> String {
+    format!(
+        "search index={} \"{}\" | stats count",
+        index.unwrap_or("*"),
+        marker
+    )
+}
+
+async fn send_tcp(
+    host: &str,
+    port: u16,
+    tls: bool,
+    insecure: bool,
+    lines: &[String],
+) -> Result<()> {
+    let stream = tokio::time::timeout(CONNECT_TIMEOUT, TcpStream::connect((host, port)))
+        .await
+        .with_context(|| format!("Timed out connecting to {}:{}", host, port))?
+        .with_context(|| format!("Failed to connect to {}:{}", host, port))?;
+
+    if tls {
+        let connector = TlsConnector::from(Arc::new(tls_config(insecure)?));
+        let server_name = ServerName::try_from(host.to_string())
+            .with_context(|| format!("Invalid TLS server name '{}'", host))?;
+        let stream = tokio::time::timeout(CONNECT_TIMEOUT, connector.connect(server_name, stream))
+            .await
+            .with_context(|| format!("Timed out during TLS handshake with {}:{}", host, port))?
+            .with_context(|| format!("TLS handshake with {}:{} failed", host, port))?;
+        write_lines(stream, lines).await
+    } else {
+        write_lines(stream, lines).await
+    }
+}
+
+async fn write_lines(mut stream: impl AsyncWrite + Unpin, lines: &[String]) -> Result<()> {
+    for line in lines {
+        stream
+            .write_all(line.as_bytes())
+            .await
+            .context("Failed to write test line")?;
+    }
+    stream.flush().await.context("Failed to flush test lines")?;
+    stream
+        .shutdown()
+        .await
+        .context("Failed to close connection")?;
+    Ok(())
+}
+
+async fn send_udp(host: &str, port: u16, lines: &[String]) -> Result<()> {
+    let target = tokio::net::lookup_host((host, port))
+        .await
+        .with_context(|| format!("Failed to resolve {}", host))?
+        .next()
+        .with_context(|| format!("No addresses found for {}", host))?;
+    let bind_addr = if target.is_ipv4() {
+        "0.0.0.0:0"
+    } else {
+        "[::]:0"
+    };
+    let socket = UdpSocket::bind(bind_addr)
+        .await
+        .context("Failed to open UDP socket")?;
+    for line in lines {
+        socket
+            .send_to(line.as_bytes(), target)
+            .await
+            .with_context(|| format!("Failed to send UDP datagram to {}", target))?;
+    }
+    Ok(())
+}
+
+fn tls_config(insecure: bool) -> Result<ClientConfig> {
+    let builder = ClientConfig::builder();
+    let config = if insecure {
+        let provider = builder.crypto_provider().clone();
+        builder
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(NoCertificateVerification(provider)))
+            .with_no_client_auth()
+    } else {
+        builder
+            .with_platform_verifier()
+            .context("Failed to load platform certificate verifier")?
+            .with_no_client_auth()
+    };
+    Ok(config)
+}
+
+/// Accepts any server certificate (`--insecure`), still checking handshake signatures.
+#[derive(Debug)]
+struct NoCertificateVerification(Arc<rustls::crypto::CryptoProvider>);
+
+impl ServerCertVerifier for NoCertificateVerification {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+/// Poll the verification search until all lines are found or the timeout elapses.
+async fn verify_arrival(
+    client: &splunk_client::SplunkClient,
+    search: String,
+    expected: u64,
+    timeout: Duration,
+    cancel: &crate::cancellation::CancellationToken,
+) -> Result<Verification> {
+    let started = Instant::now();
+    loop {
+        let request = SearchRequest::new(&search, true).time_bounds("-24h", "+24h");
+        let results = cancellable!(client.search(request), cancel)?;
+        let found = count_from_results(&results);
+        let elapsed = started.elapsed();
+
+        if found >= expected || elapsed + POLL_INTERVAL > timeout {
+            return Ok(Verification {
+                search,
+                found,
+                elapsed_secs: elapsed.as_secs(),
+                timed_out: found < expected,
+            });
+        }
+
+        info!(
+            "Found {}/{} test line(s) after {}s; retrying",
+            found,
+            expected,
+            elapsed.as_secs()
+        );
+        cancellable!(
+            async {
+                tokio::time::sleep(POLL_INTERVAL).await;
+                anyhow::Ok(())
+            },
+            cancel
+        )?;
+    }
+}
+
+/// Read the `count` field of a `stats count` result.
+fn count_from_results(results: &[serde_json::Value]) -> u64 {
+    results
+        .first()
+        .and_then(|row| row.get("count"))
+        .and_then(|count| match count {
+            serde_json::Value::String(s) => s.parse().ok(),
+            other => other.as_u64(),
+        })
+        .unwrap_or(0)
+}
+
+/// Format a test-send report based on the selected format.
+pub fn format_test_send_report(report: &TestSendReport, format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(report)?),
+        OutputFormat::Table => Ok(format_table(report)),
+        OutputFormat::Csv => Ok(format_csv(report)),
+        OutputFormat::Xml => Ok(format_xml(report)),
+        OutputFormat::Ndjson => Ok(format!("{}\n", serde_json::to_string(report)?)),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(report)?),
+        OutputFormat::Markdown => Ok(format_markdown(report)),
+    }
+}
+
+fn target(report: &TestSendReport) -> String {
+    format!(
+        "{}{}://{}:{}",
+        report.transport,
+        if report.tls { "+tls" } else { "" },
+        report.host,
+        report.port
+    )
+}
+
+fn status(report: &TestSendReport) -> &'static str {
+    match &report.verification {
+        None => "SENT (not verified)",
+        Some(_) if report.passed() => "PASS",
+        Some(_) => "FAIL",
+    }
+}
+
+fn format_table(report: &TestSendReport) -> String {
+    let mut out = String::from("Input Test Send\n\n");
+    out.push_str(&format!("Target:   {}\n", target(report)));
+    out.push_str(&format!("Marker:   {}\n", report.marker));
+    out.push_str(&format!("Sent:     {}\n", report.sent));
+    if let Some(verification) = &report.verification {
+        out.push_str(&format!(
+            "Found:    {} (after {}s{})\n",
+            verification.found,
+            verification.elapsed_secs,
+            if verification.timed_out {
+                ", timed out"
+            } else {
+                ""
+            }
+        ));
+        out.push_str(&format!("Search:   {}\n", verification.search));
+    }
+    out.push_str(&format!("Result:   {}\n", status(report)));
+    out
+}
+
+fn format_csv(report: &TestSendReport) -> String {
+    let mut csv = String::from("transport,host,port,tls,marker,sent,found,elapsed_secs,status\n");
+    let (found, elapsed) = report
+        .verification
+        .as_ref()
+        .map(|v| (v.found.to_string(), v.elapsed_secs.to_string()))
+        .unwrap_or_default();
+    csv.push_str(&format!(
+        "{},{},{},{},{},{},{},{},{}\n",
+        report.transport,
+        escape_csv(&report.host),
+        report.port,
+        report.tls,
+        escape_csv(&report.marker),
+        report.sent,
+        found,
+        elapsed,
+        escape_csv(status(report))
+    ));
+    csv
+}
+
+fn format_xml(report: &TestSendReport) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<input_test_send>\n");
+    xml.push_str(&format!("  <transport>{}</transport>\n", report.transport));
+    xml.push_str(&format!("  <host>{}</host>\n", escape_xml(&report.host)));
+    xml.push_str(&format!("  <port>{}</port>\n", report.port));
+    xml.push_str(&format!("  <tls>{}</tls>\n", report.tls));
+    xml.push_str(&format!(
+        "  <marker>{}</marker>\n",
+        escape_xml(&report.marker)
+    ));
+    xml.push_str(&format!("  <sent>{}</sent>\n", report.sent));
+    if let Some(verification) = &report.verification {
+        xml.push_str("  <verification>\n");
+        xml.push_str(&format!(
+            "    <search>{}</search>\n",
+            escape_xml(&verification.search)
+        ));
+        xml.push_str(&format!("    <found>{}</found>\n", verification.found));
+        xml.push_str(&format!(
+            "    <elapsed_secs>{}</elapsed_secs>\n",
+            verification.elapsed_secs
+        ));
+        xml.push_str(&format!(
+            "    <timed_out>{}</timed_out>\n",
+            verification.timed_out
+        ));
+        xml.push_str("  </verification>\n");
+    }
+    xml.push_str(&format!(
+        "  <status>{}</status>\n",
+        escape_xml(status(report))
+    ));
+    xml.push_str("</input_test_send>");
+    xml
+}
+
+fn format_markdown(report: &TestSendReport) -> String {
+    let mut md = String::from("# Input Test Send\n\n");
+    md.push_str(&format!("- **Target**: {}\n", target(report)));
+    md.push_str(&format!("- **Marker**: `{}`\n", report.marker));
+    md.push_str(&format!("- **Sent**: {}\n", report.sent));
+    if let Some(verification) = &report.verification {
+        md.push_str(&format!(
+            "- **Found**: {} (after {}s)\n",
+            verification.found, verification.elapsed_secs
+        ));
+        md.push_str(&format!("- **Search**: `{}`\n", verification.search));
+    }
+    md.push_str(&format!("- **Result**: {}\n", status(report)));
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn report(found: Option<u64>) -> TestSendReport {
+        TestSendReport {
+            transport: Transport::Tcp,
+            host: "splunk01".to_string(),
+            port: 1514,
+            tls: true,
+            marker: "splunkcli_testsend_abc".to_string(),
+            sent: 10,
+            verification: found.map(|found| Verification {
+                search: verification_search("splunkcli_testsend_abc", None),
+                found,
+                elapsed_secs: 12,
+                timed_out: found < 10,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_lines_carry_marker_and_sequence() {
+        let lines = build_lines("m1", 3);
+        assert_eq!(lines.len(), 3);
+        assert!(lines[2].ends_with("marker=m1 seq=3/3\n"));
+    }
+
+    #[test]
+    fn test_count_parses_string_and_numeric_values() {
+        assert_eq!(count_from_results(&[json!({"count": "7"})]), 7);
+        assert_eq!(count_from_results(&[json!({"count": 4})]), 4);
+        assert_eq!(count_from_results(&[]), 0);
+    }
+
+    #[test]
+    fn test_passed_requires_every_line() {
+        assert!(report(None).passed());
+        assert!(report(Some(10)).passed());
+        assert!(!report(Some(9)).passed());
+    }
+
+    #[test]
+    fn test_table_shows_target_and_result() {
+        let output = format_test_send_report(&report(Some(9)), OutputFormat::Table).unwrap();
+        assert!(output.contains("Target:   tcp+tls://splunk01:1514"));
+        assert!(output.contains("Found:    9 (after 12s, timed out)"));
+        assert!(output.contains("Result:   FAIL"));
+    }
+
+    #[test]
+    fn test_default_host_uses_base_url_host() {
+        assert_eq!(
+            default_host("https://splunk01.example.com:8089").unwrap(),
+            "splunk01.example.com"
+        );
+        assert!(default_host("not a url").is_err());
+    }
+}
@@ -1,10 +1,13 @@
 //! Search jobs command implementation.
 //!
 //! Responsibilities:
-//! - List search jobs with optional count limiting
-//! - Inspect detailed information about specific jobs
+//! - List search jobs with optional count limiting and server-side owner/app/status filters
+//! - Inspect detailed information about specific jobs, optionally with a performance breakdown
 //! - Cancel running jobs by SID (single or batch)
 //! - Delete completed jobs by SID (single or batch)
+//! - Bulk-extend artifact TTLs of jobs that are about to expire
+//! - Prune jobs older than a cutoff in rate-limited batches
+//! - List jobs across several profiles (`jobs --profiles a,b`)
 //! - Format output via shared formatters
 //!
 //! Does NOT handle:
@@ -16,16 +19,24 @@
 //! - Job SIDs are validated as non-empty strings
 //! - Cancel/delete operations are idempotent (safe to retry)
 //! - Only the job owner or admin can cancel/delete jobs
-//! - Batch operations process jobs sequentially to avoid API throttling
+//! - Batch operations process jobs sequentially to avoid API throttling; pruning deletes
+//!   concurrently within fixed-size batches and pauses between them
+//! - TTL extension never shortens a job's remaining lifetime
 
 use anyhow::{Context, Result};
 use clap::Subcommand;
 use std::io::Write;
 use std::path::PathBuf;
+use std::time::Duration;
 use tracing::info;
 
+use splunk_client::workflows::job_prune::{self, JobPrunePolicy};
+use splunk_client::{JobFilter, JobStatusFilter, SearchJobStatus};
+
 use crate::formatters::{OutputFormat, get_formatter, output_result};
 
+mod performance;
+
 /// Jobs subcommands for batch operations.
 #[derive(Subcommand)]
 pub enum JobsCommand {
@@ -56,6 +67,60 @@ pub enum JobsCommand {
         #[arg(long)]
         force: bool,
     },
+
+    /// Extend the artifact TTL of jobs that are about to expire
+    ExtendTtl {
+        /// Only extend jobs owned by this user ("me" for the authenticated user)
+        #[arg(long, value_name = "USER")]
+        owner: Option<String>,
+
+        /// Only extend jobs with less than this lifetime remaining (defaults to --to)
+        #[arg(long, value_name = "DURATION", value_parser = crate::commands::generate::parse_duration)]
+        min_remaining: Option<Duration>,
+
+        /// New artifact lifetime, measured from now (e.g. 24h)
+        #[arg(long, value_name = "DURATION", default_value = "24h", value_parser = crate::commands::generate::parse_duration)]
+        to: Duration,
+
+        /// List the jobs that would be extended without changing them
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Skip confirmation prompt
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Delete jobs older than a cutoff, in rate-limited batches
+    Prune {
+        /// Only prune jobs created before this long ago (e.g. 2d, 12h, -1w@w)
+        #[arg(long, value_name = "AGE")]
+        older_than: String,
+
+        /// Only prune jobs owned by this user ("me" for the authenticated user)
+        #[arg(long, value_name = "USER")]
+        owner: Option<String>,
+
+        /// Only prune jobs in this state: running, done, failed
+        #[arg(long, value_name = "STATUS")]
+        status: Option<JobStatusFilter>,
+
+        /// Number of jobs deleted concurrently per batch
+        #[arg(long, value_name = "N", default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..=100))]
+        batch_size: u64,
+
+        /// Pause between batches (e.g. 1s, 30s)
+        #[arg(long, value_name = "DURATION", default_value = "1s", value_parser = crate::commands::generate::parse_duration)]
+        pause: Duration,
+
+        /// List the jobs that would be deleted without deleting them
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Skip confirmation prompt
+        #[arg(long)]
+        force: bool,
+    },
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -63,12 +128,14 @@ pub async fn run(
     config: splunk_config::Config,
     mut list: bool,
     inspect: Option<String>,
+    performance: bool,
     cancel: Option<String>,
     delete: Option<String>,
     results: Option<String>,
     result_count: Option<usize>,
     result_offset: usize,
     count: usize,
+    filter: splunk_client::JobFilter,
     output_format: &str,
     quiet: bool,
     output_file: Option<std::path::PathBuf>,
@@ -82,21 +149,50 @@ pub async fn run(
     if let Some(cmd) = command {
         match cmd {
             JobsCommand::Cancel { sids, file, force } => {
-                return run_cancel_batch(
-                    std::sync::Arc::new(client),
-                    sids,
-                    file,
+                return run_cancel_batch(client, sids, file, force, quiet, cancel_token).await;
+            }
+            JobsCommand::Delete { sids, file, force } => {
+                return run_delete_batch(client, sids, file, force, quiet, cancel_token).await;
+            }
+            JobsCommand::ExtendTtl {
+                owner,
+                min_remaining,
+                to,
+                dry_run,
+                force,
+            } => {
+                return run_extend_ttl(
+                    &client,
+                    owner,
+                    min_remaining,
+                    to,
+                    dry_run,
                     force,
                     quiet,
                     cancel_token,
                 )
                 .await;
             }
-            JobsCommand::Delete { sids, file, force } => {
-                return run_delete_batch(
-                    std::sync::Arc::new(client),
-                    sids,
-                    file,
+            JobsCommand::Prune {
+                older_than,
+                owner,
+                status,
+                batch_size,
+                pause,
+                dry_run,
+                force,
+            } => {
+                let policy = JobPrunePolicy {
+                    older_than,
+                    owner,
+                    status,
+                };
+                return run_prune(
+                    &client,
+                    &policy,
+                    batch_size as usize,
+                    pause,
+                    dry_run,
                     force,
                     quiet,
                     cancel_token,
@@ -140,10 +236,13 @@ pub async fn run(
 
         // Parse output format
         let format = OutputFormat::from_str(output_format)?;
-        let formatter = get_formatter(format);
 
-        // Format and print job details
-        let output = formatter.format_job_details(&job)?;
+        let output = if performance {
+            let report = performance::JobPerformanceReport::from_status(&job);
+            performance::format_job_performance(&report, format)?
+        } else {
+            get_formatter(format).format_job_details(&job)?
+        };
         output_result(&output, format, output_file.as_ref())?;
         return Ok(());
     }
@@ -169,8 +268,15 @@ pub async fn run(
     }
 
     if list {
-        info!("Listing search jobs");
-        let jobs = cancellable!(client.list_jobs(Some(count), None), cancel_token)?;
+        if filter.is_empty() {
+            info!("Listing search jobs");
+        } else {
+            info!("Listing search jobs matching {}", filter);
+        }
+        let jobs = cancellable!(
+            client.list_jobs_filtered(Some(count), None, &filter),
+            cancel_token
+        )?;
 
         // Parse output format
         let format = OutputFormat::from_str(output_format)?;
@@ -184,6 +290,46 @@ pub async fn run(
     Ok(())
 }
 
+/// List jobs on every target profile, one row per job.
+pub async fn run_list_profiles(
+    source: &crate::commands::multi_profile::ProfileSource,
+    targets: &crate::commands::multi_profile::ProfileTargets,
+    count: usize,
+    filter: &JobFilter,
+    output_format: &str,
+    output_file: Option<&PathBuf>,
+    cancel_token: &crate::cancellation::CancellationToken,
+) -> Result<()> {
+    info!("Listing search jobs across profiles");
+
+    let results = crate::commands::multi_profile::fan_out(
+        source,
+        targets,
+        cancel_token,
+        |client| async move {
+            let jobs = client.list_jobs_filtered(Some(count), None, filter).await?;
+            Ok(jobs
+                .iter()
+                .map(serde_json::to_value)
+                .collect::<serde_json::Result<Vec<_>>>()?)
+        },
+    )
+    .await?;
+
+    results.print(
+        &[
+            "sid",
+            "label",
+            "isDone",
+            "doneProgress",
+            "runDuration",
+            "resultCount",
+        ],
+        output_format,
+        output_file,
+    )
+}
+
 /// Run batch cancel operation.
 async fn run_cancel_batch(
     client: std::sync::Arc<splunk_client::SplunkClient>,
@@ -351,3 +497,243 @@ async fn run_delete_batch(
 
     Ok(())
 }
+
+/// Run bulk TTL extension.
+#[allow(clippy::too_many_arguments)]
+async fn run_extend_ttl(
+    client: &splunk_client::SplunkClient,
+    owner: Option<String>,
+    min_remaining: Option<Duration>,
+    to: Duration,
+    dry_run: bool,
+    force: bool,
+    quiet: bool,
+    cancel_token: &crate::cancellation::CancellationToken,
+) -> Result<()> {
+    let owner = match owner {
+        Some(owner) if owner.eq_ignore_ascii_case("me") => Some(
+            cancellable!(client.get_current_username(), cancel_token)
+                .context("Failed to resolve the current user")?,
+        ),
+        other => other,
+    };
+    let filter = JobFilter {
+        owner,
+        ..JobFilter::default()
+    };
+
+    info!("Listing jobs for TTL extension");
+    let jobs = cancellable!(
+        client.list_jobs_filtered(Some(0), None, &filter),
+        cancel_token
+    )?;
+    let expiring = jobs_needing_ttl_extension(&jobs, min_remaining.unwrap_or(to), to);
+
+    if expiring.is_empty() {
+        println!("No jobs need a TTL extension.");
+        return Ok(());
+    }
+
+    if dry_run {
+        for job in &expiring {
+            println!("{} ({}s remaining)", job.sid, job.ttl.unwrap_or_default());
+        }
+        println!(
+            "Would extend {} job(s) to {}s",
+            expiring.len(),
+            to.as_secs()
+        );
+        return Ok(());
+    }
+
+    // Confirmation prompt (unless --force)
+    if !force && expiring.len() > 1 {
+        print!(
+            "Are you sure you want to extend the TTL of {} jobs? [y/N] ",
+            expiring.len()
+        );
+        std::io::stdout().flush()?;
+        let mut input = String::new();
+        std::io::stdin().read_line(&mut input)?;
+        if !input.trim().eq_ignore_ascii_case("y") {
+            println!("Extend operation aborted.");
+            return Ok(());
+        }
+    }
+
+    // Process jobs sequentially (following TUI pattern)
+    let spinner =
+        crate::progress::Spinner::new(!quiet, format!("Extending TTL of {} jobs", expiring.len()));
+    let mut success_count = 0;
+    let mut errors = Vec::new();
+
+    for job in &expiring {
+        if cancel_token.is_cancelled() {
+            spinner.finish();
+            anyhow::bail!("Operation cancelled by user");
+        }
+
+        match client.set_job_ttl(&job.sid, to.as_secs()).await {
+            Ok(_) => success_count += 1,
+            Err(e) => errors.push(format!("{}: {}", job.sid, e)),
+        }
+    }
+
+    spinner.finish();
+
+    if success_count > 0 {
+        println!(
+            "Extended TTL of {} job(s) to {}s",
+            success_count,
+            to.as_secs()
+        );
+    }
+
+    if !errors.is_empty() {
+        eprintln!("\nErrors:");
+        for err in &errors {
+            eprintln!("  {}", err);
+        }
+        anyhow::bail!("Failed to extend TTL of {} job(s)", errors.len());
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn run_prune(
+    client: &splunk_client::SplunkClient,
+    policy: &JobPrunePolicy,
+    batch_size: usize,
+    pause: Duration,
+    dry_run: bool,
+    force: bool,
+    quiet: bool,
+    cancel_token: &crate::cancellation::CancellationToken,
+) -> Result<()> {
+    policy
+        .validate()
+        .map_err(|e| crate::error::UsageError(format!("{e:#}")))?;
+
+    info!("Listing jobs older than {}", policy.older_than);
+    let jobs = cancellable!(
+        job_prune::find_prunable_jobs(client, policy, Some(cancel_token)),
+        cancel_token
+    )?;
+
+    if jobs.is_empty() {
+        println!("No jobs older than {} to prune.", policy.older_than);
+        return Ok(());
+    }
+
+    if dry_run {
+        for job in &jobs {
+            println!(
+                "{}\t{}\t{}\t{}",
+                job.sid,
+                job.owner,
+                job.published.as_deref().unwrap_or_default(),
+                job.dispatch_state.as_deref().unwrap_or_default()
+            );
+        }
+        println!("Would delete {} job(s)", jobs.len());
+        return Ok(());
+    }
+
+    // Confirmation prompt (unless --force)
+    if !force {
+        print!(
+            "Are you sure you want to delete {} job(s) older than {}? [y/N] ",
+            jobs.len(),
+            policy.older_than
+        );
+        std::io::stdout().flush()?;
+        let mut input = String::new();
+        std::io::stdin().read_line(&mut input)?;
+        if !input.trim().eq_ignore_ascii_case("y") {
+            println!("Prune operation aborted.");
+            return Ok(());
+        }
+    }
+
+    let sids: Vec<String> = jobs.into_iter().map(|job| job.sid).collect();
+    let progress = crate::progress::CountProgress::new(!quiet, "Deleting jobs", sids.len() as u64);
+    let mut reported = 0;
+    let report = job_prune::delete_in_batches(
+        client,
+        &sids,
+        batch_size,
+        pause,
+        Some(cancel_token),
+        |processed| {
+            progress.inc((processed - reported) as u64);
+            reported = processed;
+        },
+    )
+    .await;
+    progress.finish();
+    let report = report?;
+
+    if !report.deleted.is_empty() {
+        println!("Deleted {} job(s)", report.deleted.len());
+    }
+
+    if !report.failed.is_empty() {
+        eprintln!("\nErrors:");
+        for (sid, err) in &report.failed {
+            eprintln!("  {}: {}", sid, err);
+        }
+        anyhow::bail!("Failed to delete {} job(s)", report.failed.len());
+    }
+
+    Ok(())
+}
+
+/// Jobs with less than `min_remaining` TTL left that `to` would lengthen.
+fn jobs_needing_ttl_extension(
+    jobs: &[SearchJobStatus],
+    min_remaining: Duration,
+    to: Duration,
+) -> Vec<&SearchJobStatus> {
+    let threshold = min_remaining.min(to).as_secs() as usize;
+    jobs.iter()
+        .filter(|job| job.ttl_expires_within(threshold))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(sid: &str, ttl: Option<usize>) -> SearchJobStatus {
+        serde_json::from_value(serde_json::json!({ "sid": sid, "ttl": ttl })).unwrap()
+    }
+
+    #[test]
+    fn test_jobs_needing_ttl_extension() {
+        let jobs = vec![
+            job("soon", Some(300)),
+            job("later", Some(7200)),
+            job("unknown", None),
+        ];
+
+        let selected = jobs_needing_ttl_extension(
+            &jobs,
+            Duration::from_secs(600),
+            Duration::from_secs(86_400),
+        );
+        let sids: Vec<_> = selected.iter().map(|j| j.sid.as_str()).collect();
+        assert_eq!(sids, vec!["soon"]);
+    }
+
+    #[test]
+    fn test_jobs_needing_ttl_extension_never_shortens() {
+        let jobs = vec![job("a", Some(300)), job("b", Some(5400))];
+
+        // A threshold above the target only selects jobs the target lengthens.
+        let selected =
+            jobs_needing_ttl_extension(&jobs, Duration::from_secs(7200), Duration::from_secs(3600));
+        let sids: Vec<_> = selected.iter().map(|j| j.sid.as_str()).collect();
+        assert_eq!(sids, vec!["a"]);
+    }
+}
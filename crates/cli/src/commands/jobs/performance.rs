@@ -0,0 +1,254 @@
+//! Job performance breakdown for `jobs --inspect SID --performance`.
+//!
+//! Responsibilities:
+//! - Split a job's `performance` map into command timings, dispatch phases,
+//!   and per-peer remote streaming counters.
+//! - Format the breakdown in every output format.
+//!
+//! Does NOT handle:
+//! - Fetching the job status (handled by `jobs::run`).
+//! - Parsing the `performance` map (lives in `splunk_client::models::jobs`).
+//!
+//! Invariants:
+//! - Each section is sorted slowest first.
+//! - Row-oriented formats (CSV, NDJSON) emit one row per component, tagged with its section.
+
+use anyhow::Result;
+use serde::Serialize;
+use splunk_client::{PerformanceEntry, SearchJobStatus};
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml};
+
+/// Performance breakdown of a single search job.
+#[derive(Debug, Serialize)]
+pub(super) struct JobPerformanceReport {
+    pub sid: String,
+    pub run_duration: f64,
+    pub commands: Vec<PerformanceEntry>,
+    pub dispatch: Vec<PerformanceEntry>,
+    pub peers: Vec<PerformanceEntry>,
+}
+
+impl JobPerformanceReport {
+    pub(super) fn from_status(job: &SearchJobStatus) -> Self {
+        let performance = job.performance.clone().unwrap_or_default();
+        Self {
+            sid: job.sid.clone(),
+            run_duration: job.run_duration,
+            commands: performance.commands(),
+            dispatch: performance.dispatch_phases(),
+            peers: performance.peers(),
+        }
+    }
+
+    fn sections(&self) -> [(&'static str, &[PerformanceEntry]); 3] {
+        [
+            ("command", &self.commands),
+            ("dispatch", &self.dispatch),
+            ("peer", &self.peers),
+        ]
+    }
+
+    fn is_empty(&self) -> bool {
+        self.sections()
+            .iter()
+            .all(|(_, entries)| entries.is_empty())
+    }
+}
+
+/// Row shape for NDJSON output.
+#[derive(Serialize)]
+struct SectionRow<'a> {
+    section: &'a str,
+    #[serde(flatten)]
+    entry: &'a PerformanceEntry,
+}
+
+/// Format a job performance report based on the selected format.
+pub(super) fn format_job_performance(
+    report: &JobPerformanceReport,
+    format: OutputFormat,
+) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(report)?),
+        OutputFormat::Table => Ok(format_table(report)),
+        OutputFormat::Csv => Ok(format_csv(report)),
+        OutputFormat::Xml => Ok(format_xml(report)),
+        OutputFormat::Ndjson => format_ndjson(report),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(report)?),
+        OutputFormat::Markdown => Ok(format_markdown(report)),
+    }
+}
+
+fn format_table(report: &JobPerformanceReport) -> String {
+    let mut out = format!(
+        "Job {} performance (run duration {:.2}s)\n",
+        report.sid, report.run_duration
+    );
+    if report.is_empty() {
+        out.push_str("No performance data reported for this job.\n");
+        return out;
+    }
+    for (title, entries) in [
+        ("Commands", &report.commands),
+        ("Dispatch phases", &report.dispatch),
+        ("Remote peers", &report.peers),
+    ] {
+        if entries.is_empty() {
+            continue;
+        }
+        out.push_str(&format!(
+            "\n{}:\n  {:<40} {:>10} {:>11} {:>12} {:>12}\n",
+            title, "Name", "Duration", "Invocations", "Input", "Output"
+        ));
+        for entry in entries {
+            out.push_str(&format!(
+                "  {:<40} {:>9.3}s {:>11} {:>12} {:>12}\n",
+                entry.name,
+                entry.counters.duration_secs,
+                entry.counters.invocations,
+                entry.counters.input_count,
+                entry.counters.output_count
+            ));
+        }
+    }
+    out
+}
+
+fn format_csv(report: &JobPerformanceReport) -> String {
+    let mut csv = String::from("section,name,duration_secs,invocations,input_count,output_count\n");
+    for (section, entries) in report.sections() {
+        for entry in entries {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{}\n",
+                section,
+                escape_csv(&entry.name),
+                entry.counters.duration_secs,
+                entry.counters.invocations,
+                entry.counters.input_count,
+                entry.counters.output_count
+            ));
+        }
+    }
+    csv
+}
+
+fn format_xml(report: &JobPerformanceReport) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<job_performance sid=\"{}\" run_duration=\"{}\">\n",
+        escape_xml(&report.sid),
+        report.run_duration
+    ));
+    for (section, entries) in report.sections() {
+        for entry in entries {
+            xml.push_str(&format!(
+                "  <component section=\"{}\" name=\"{}\" duration_secs=\"{}\" invocations=\"{}\" input_count=\"{}\" output_count=\"{}\"/>\n",
+                section,
+                escape_xml(&entry.name),
+                entry.counters.duration_secs,
+                entry.counters.invocations,
+                entry.counters.input_count,
+                entry.counters.output_count
+            ));
+        }
+    }
+    xml.push_str("</job_performance>");
+    xml
+}
+
+fn format_ndjson(report: &JobPerformanceReport) -> Result<String> {
+    let mut ndjson = String::new();
+    for (section, entries) in report.sections() {
+        for entry in entries {
+            ndjson.push_str(&serde_json::to_string(&SectionRow { section, entry })?);
+            ndjson.push('\n');
+        }
+    }
+    Ok(ndjson)
+}
+
+fn format_markdown(report: &JobPerformanceReport) -> String {
+    let mut md = format!(
+        "# Job {} performance\n\n- **Run duration**: {:.2}s\n",
+        report.sid, report.run_duration
+    );
+    if report.is_empty() {
+        md.push_str("\n_No performance data reported for this job._\n");
+        return md;
+    }
+    for (title, entries) in [
+        ("Commands", &report.commands),
+        ("Dispatch phases", &report.dispatch),
+        ("Remote peers", &report.peers),
+    ] {
+        if entries.is_empty() {
+            continue;
+        }
+        md.push_str(&format!("\n## {}\n\n", title));
+        md.push_str("| Name | Duration (s) | Invocations | Input | Output |\n");
+        md.push_str("|------|--------------|-------------|-------|--------|\n");
+        for entry in entries {
+            md.push_str(&format!(
+                "| {} | {:.3} | {} | {} | {} |\n",
+                entry.name,
+                entry.counters.duration_secs,
+                entry.counters.invocations,
+                entry.counters.input_count,
+                entry.counters.output_count
+            ));
+        }
+    }
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> JobPerformanceReport {
+        let job: SearchJobStatus = serde_json::from_value(serde_json::json!({
+            "sid": "1700000000.42",
+            "runDuration": 2.5,
+            "performance": {
+                "command.search": {"duration_secs": 0.8, "invocations": 6, "input_count": 0, "output_count": 1200},
+                "command.stats": {"duration_secs": 0.2, "invocations": 2, "input_count": 1200, "output_count": 4},
+                "dispatch.fetch": {"duration_secs": 1.9, "invocations": 7},
+                "dispatch.stream.remote.idx1": {"duration_secs": 1.1, "invocations": 4, "input_count": 800, "output_count": 2048}
+            }
+        }))
+        .unwrap();
+        JobPerformanceReport::from_status(&job)
+    }
+
+    #[test]
+    fn test_table_groups_sections() {
+        let output = format_job_performance(&report(), OutputFormat::Table).unwrap();
+        assert!(output.starts_with("Job 1700000000.42 performance (run duration 2.50s)"));
+        let commands = output.find("Commands:").unwrap();
+        let peers = output.find("Remote peers:").unwrap();
+        assert!(commands < peers);
+        assert!(output.contains("  idx1 "));
+        assert!(output.find("command.search").unwrap() < output.find("command.stats").unwrap());
+    }
+
+    #[test]
+    fn test_csv_has_one_row_per_component() {
+        let output = format_job_performance(&report(), OutputFormat::Csv).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[4], "peer,idx1,1.1,4,800,2048");
+    }
+
+    #[test]
+    fn test_table_without_performance_data() {
+        let job: SearchJobStatus =
+            serde_json::from_value(serde_json::json!({ "sid": "empty" })).unwrap();
+        let output = format_job_performance(
+            &JobPerformanceReport::from_status(&job),
+            OutputFormat::Table,
+        )
+        .unwrap();
+        assert!(output.contains("No performance data reported for this job."));
+    }
+}
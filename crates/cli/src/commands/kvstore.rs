@@ -56,7 +56,7 @@ pub enum KvstoreCommand {
         #[arg(long, default_value = "nobody")]
         owner: String,
         /// Field schema as JSON string
-        #[arg(long)]
+        #[arg(long = "schema")]
         fields: Option<String>,
         /// Accelerated fields as JSON string
         #[arg(long)]
@@ -87,7 +87,7 @@ pub enum KvstoreCommand {
         #[arg(long, default_value = "nobody")]
         owner: String,
         /// MongoDB-style query (JSON)
-        #[arg(short, long)]
+        #[arg(short = 'q', long = "filter")]
         query: Option<String>,
         /// Maximum number of records
         #[arg(short, long, default_value_t = DEFAULT_MAX_RESULTS)]
@@ -2,6 +2,7 @@
 //!
 //! Responsibilities:
 //! - Fetch license usage, pools, stacks, and installed licenses from Splunk.
+//! - Show licenser messages and slave warning counts with the violation countdown.
 //! - Install license files.
 //! - Manage license pools (create, modify, delete).
 //! - Activate/deactivate licenses.
@@ -21,7 +22,7 @@ use tracing::info;
 
 use crate::formatters::{
     LicenseActivationOutput, LicenseInfoOutput, LicenseInstallOutput, LicensePoolOperationOutput,
-    OutputFormat, get_formatter, output_result, write_to_file,
+    LicenseSlaveOutput, OutputFormat, get_formatter, output_result, write_to_file,
 };
 
 #[derive(Subcommand)]
@@ -33,6 +34,12 @@ pub enum LicenseCommand {
     /// List installed licenses
     List,
 
+    /// Show licenser warning and violation messages
+    Messages,
+
+    /// Show license slaves with warning counts and over-quota status
+    Slaves,
+
     /// Install a license file
     Install {
         /// Path to the .sla license file
@@ -120,6 +127,12 @@ pub async fn run(
         LicenseCommand::List => {
             run_list(config, output_format, output_file.clone(), cancel, no_cache).await
         }
+        LicenseCommand::Messages => {
+            run_messages(config, output_format, output_file.clone(), cancel, no_cache).await
+        }
+        LicenseCommand::Slaves => {
+            run_slaves(config, output_format, output_file.clone(), cancel, no_cache).await
+        }
         LicenseCommand::Install { file_path } => {
             run_install(
                 config,
@@ -203,6 +216,60 @@ async fn run_list(
     Ok(())
 }
 
+async fn run_messages(
+    config: splunk_config::Config,
+    output_format: &str,
+    output_file: Option<PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Listing licenser messages...");
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let messages = cancellable!(client.list_license_messages(), cancel)?;
+
+    let format = OutputFormat::from_str(output_format)?;
+    let formatter = get_formatter(format);
+    let formatted = formatter.format_license_messages(&messages)?;
+
+    output_result(&formatted, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+async fn run_slaves(
+    config: splunk_config::Config,
+    output_format: &str,
+    output_file: Option<PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Listing license slaves...");
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let slaves = cancellable!(client.list_license_slaves(), cancel)?;
+    let messages = cancellable!(client.list_license_messages(), cancel)?;
+
+    let output: Vec<LicenseSlaveOutput> = slaves
+        .into_iter()
+        .map(|slave| LicenseSlaveOutput {
+            warnings_until_violation: slave.warnings_until_violation(),
+            over_quota: slave.is_over_quota(&messages),
+            slave,
+        })
+        .collect();
+
+    let format = OutputFormat::from_str(output_format)?;
+    let formatter = get_formatter(format);
+    let formatted = formatter.format_license_slaves(&output)?;
+
+    output_result(&formatted, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
 async fn run_install(
     config: splunk_config::Config,
     file_path: &std::path::Path,
@@ -95,13 +95,11 @@ pub async fn run_single_profile(
 /// profiles. No single Config is needed since each profile's config is loaded
 /// from the ConfigManager.
 ///
-/// Either `profile_names` or `all_profiles` must be specified to determine
-/// which profiles to query.
+/// `targets` (`--profiles` or `--all-profiles`) determines which profiles to query.
 pub async fn run_multi_profile(
     config_manager: splunk_config::ConfigManager,
     resources_filter: Option<Vec<String>>,
-    profile_names: Option<Vec<String>>,
-    all_profiles: bool,
+    targets: &crate::commands::multi_profile::ProfileTargets,
     output_format: &str,
     output_file: Option<std::path::PathBuf>,
     cancel: &CancellationToken,
@@ -114,46 +112,7 @@ pub async fn run_multi_profile(
             resources_filter,
         )?;
 
-    // Determine which profiles to query
-    let target_profiles: Vec<String> = if all_profiles {
-        // Query all profiles from config file
-        config_manager.list_profiles().keys().cloned().collect()
-    } else {
-        // Query specified profiles - trim and dedupe (preserve case, preserve order)
-        profile_names
-            .map(|profiles| {
-                let mut seen = std::collections::HashSet::new();
-                profiles
-                    .into_iter()
-                    .map(|p| p.trim().to_string())
-                    .filter(|p| !p.is_empty())
-                    .filter(|p| seen.insert(p.clone()))
-                    .collect()
-            })
-            .unwrap_or_default()
-    };
-
-    if target_profiles.is_empty() {
-        anyhow::bail!(
-            "Failed to list profiles: No profiles configured. Use 'splunk-cli config set <profile>' to add one."
-        );
-    }
-
-    // Validate that specified profiles exist
-    let available_profiles = config_manager.list_profiles();
-    for profile_name in &target_profiles {
-        if !available_profiles.contains_key(profile_name) {
-            anyhow::bail!(
-                "Profile '{}' not found. Available profiles: {}",
-                profile_name,
-                available_profiles
-                    .keys()
-                    .cloned()
-                    .collect::<Vec<_>>()
-                    .join(", ")
-            );
-        }
-    }
+    let target_profiles = targets.resolve(&config_manager)?;
 
     // Fetch resources from all target profiles
     let profiles_map = config_manager.list_profiles().clone();
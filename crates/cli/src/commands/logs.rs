@@ -18,8 +18,7 @@
 //! - Time filtering uses Splunk's standard time format
 
 use anyhow::Result;
-use splunk_client::models::LogEntry;
-use splunk_client::models::logs::sort_logs_newest_first;
+use splunk_client::models::{LogCursor, LogEntry, take_new_logs};
 use splunk_config::constants::DEFAULT_LOGS_TAIL_POLL_INTERVAL_SECS;
 use tokio::time::{Duration, sleep};
 use tracing::info;
@@ -27,58 +26,6 @@ use tracing::info;
 use crate::cancellation::Cancelled;
 use crate::formatters::{Formatter, OutputFormat, get_formatter, output_result};
 
-/// Cursor for tracking log position during tailing.
-#[derive(Debug, Clone)]
-struct LogCursor {
-    time: String,
-    index_time: String,
-    serial: Option<usize>,
-    content_hash: Option<usize>, // For entries without serial
-}
-
-impl LogCursor {
-    /// Returns true if * log entry is NEWER than this cursor.
-    fn is_after(&self, entry: &LogEntry) -> bool {
-        // Compare by timestamp first
-        if entry.time != self.time {
-            return entry.time > self.time;
-        }
-        // Same timestamp: compare by index_time
-        if entry.index_time != self.index_time {
-            return entry.index_time > self.index_time;
-        }
-        // Same index_time: compare by serial or content hash
-        match (self.serial, entry.serial) {
-            (Some(s), Some(e)) => e > s,
-            (None, Some(_)) => {
-                // Cursor has no serial but entry does - entry is newer
-                true
-            }
-            (Some(_), None) => {
-                // Cursor has serial but entry doesn't - need content comparison
-                // Compare by content hash to detect if same entry
-                let entry_hash = entry.content_hash();
-                self.content_hash != Some(entry_hash)
-            }
-            (None, None) => {
-                // Neither has serial - compare by content hash
-                let entry_hash = entry.content_hash();
-                self.content_hash != Some(entry_hash)
-            }
-        }
-    }
-
-    /// Create a new cursor from a log entry.
-    fn from_entry(entry: &LogEntry) -> Self {
-        LogCursor {
-            time: entry.time.clone(),
-            index_time: entry.index_time.clone(),
-            serial: entry.serial,
-            content_hash: entry.serial.is_none().then(|| entry.content_hash()),
-        }
-    }
-}
-
 #[allow(clippy::too_many_arguments)]
 pub async fn run(
     config: splunk_config::Config,
@@ -97,15 +44,15 @@ pub async fn run(
         );
     }
 
-    let mut client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
     let format = OutputFormat::from_str(output_format)?;
     let formatter = get_formatter(format);
 
     if tail {
-        run_tail_mode(&mut client, count, &earliest, formatter.as_ref(), cancel).await
+        run_tail_mode(&client, count, &earliest, formatter.as_ref(), cancel).await
     } else {
         run_normal_mode(
-            &mut client,
+            &client,
             count,
             &earliest,
             formatter.as_ref(),
@@ -119,7 +66,7 @@ pub async fn run(
 
 /// Run in continuous tail mode, polling for new logs.
 async fn run_tail_mode(
-    client: &mut splunk_client::SplunkClient,
+    client: &splunk_client::SplunkClient,
     count: usize,
     earliest: &str,
     formatter: &dyn Formatter,
@@ -150,7 +97,7 @@ async fn run_tail_mode(
 
 /// Fetch a batch of logs from the Splunk server.
 async fn fetch_logs_batch(
-    client: &mut splunk_client::SplunkClient,
+    client: &splunk_client::SplunkClient,
     count: usize,
     earliest: &str,
     cursor: &Option<LogCursor>,
@@ -175,28 +122,12 @@ async fn process_log_batch(
         return Ok(());
     }
 
-    // Filter out logs we've already seen
-    let mut new_logs: Vec<_> = match cursor {
-        Some(c) => logs.iter().filter(|l| c.is_after(l)).cloned().collect(),
-        None => logs.to_vec(),
-    };
-
+    // Filter out logs we've already seen and advance the cursor to the newest
+    let new_logs = take_new_logs(cursor, logs);
     if new_logs.is_empty() {
         return Ok(());
     }
 
-    // Ensure deterministic ordering before cursor update (defensive)
-    // API returns sorted results, but client-side filtering could
-    // theoretically disrupt ordering. Explicit sort adds resilience
-    // against future changes to filtering or data flow.
-    sort_logs_newest_first(&mut new_logs);
-
-    // Update cursor to the NEWEST new log (first in sorted descending list)
-    // This prevents re-querying same-timestamp events on next poll
-    if let Some(newest_new) = new_logs.first() {
-        *cursor = Some(LogCursor::from_entry(newest_new));
-    }
-
     // Print new logs using streaming formatter (sorted newest-first, which is correct for tailing)
     let output = formatter.format_logs_streaming(&new_logs, *is_first)?;
     if !output.is_empty() {
@@ -209,7 +140,7 @@ async fn process_log_batch(
 
 /// Run in normal mode, fetching logs once and outputting.
 async fn run_normal_mode(
-    client: &mut splunk_client::SplunkClient,
+    client: &splunk_client::SplunkClient,
     count: usize,
     earliest: &str,
     formatter: &dyn Formatter,
@@ -228,284 +159,3 @@ async fn run_normal_mode(
 
     Ok(())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use splunk_client::models::LogLevel;
-
-    fn make_log_entry(time: &str, index_time: &str, serial: Option<usize>) -> LogEntry {
-        LogEntry {
-            time: time.to_string(),
-            index_time: index_time.to_string(),
-            serial,
-            level: LogLevel::Info,
-            component: "test".to_string(),
-            message: "test message".to_string(),
-        }
-    }
-
-    #[test]
-    fn test_cursor_is_after_same_timestamp_different_serial() {
-        let cursor = LogCursor {
-            time: "2025-01-24T12:00:00.000Z".to_string(),
-            index_time: "2025-01-24T12:00:01.000Z".to_string(),
-            serial: Some(100),
-            content_hash: None,
-        };
-
-        let entry1 = make_log_entry(
-            "2025-01-24T12:00:00.000Z",
-            "2025-01-24T12:00:01.001Z",
-            Some(101),
-        );
-        let entry2 = make_log_entry(
-            "2025-01-24T12:00:00.000Z",
-            "2025-01-24T12:00:01.000Z",
-            Some(99),
-        );
-
-        assert!(cursor.is_after(&entry1)); // Newer by index_time
-        assert!(!cursor.is_after(&entry2)); // Older by serial
-    }
-
-    #[test]
-    fn test_cursor_is_after_different_timestamp() {
-        let cursor = LogCursor {
-            time: "2025-01-24T12:00:00.000Z".to_string(),
-            index_time: "2025-01-24T12:00:01.000Z".to_string(),
-            serial: Some(100),
-            content_hash: None,
-        };
-
-        let newer_entry = make_log_entry(
-            "2025-01-24T12:00:01.000Z",
-            "2025-01-24T12:00:02.000Z",
-            Some(50),
-        );
-
-        assert!(cursor.is_after(&newer_entry));
-    }
-
-    #[test]
-    fn test_cursor_with_missing_serial() {
-        let cursor = LogCursor {
-            time: "2025-01-24T12:00:00.000Z".to_string(),
-            index_time: "2025-01-24T12:00:01.000Z".to_string(),
-            serial: Some(100),
-            content_hash: None,
-        };
-
-        let entry_no_serial =
-            make_log_entry("2025-01-24T12:00:00.000Z", "2025-01-24T12:00:01.000Z", None);
-
-        // Cursor has serial but entry doesn't - use content hash comparison
-        // Since content differs, entry should be considered new
-        assert!(cursor.is_after(&entry_no_serial));
-    }
-
-    #[test]
-    fn test_cursor_from_entry() {
-        let entry = make_log_entry(
-            "2025-01-24T12:00:00.000Z",
-            "2025-01-24T12:00:01.000Z",
-            Some(42),
-        );
-
-        let cursor = LogCursor::from_entry(&entry);
-        assert_eq!(cursor.time, "2025-01-24T12:00:00.000Z");
-        assert_eq!(cursor.index_time, "2025-01-24T12:00:01.000Z");
-        assert_eq!(cursor.serial, Some(42));
-    }
-
-    #[test]
-    fn test_cursor_is_after_by_index_time_only() {
-        let cursor = LogCursor {
-            time: "2025-01-24T12:00:00.000Z".to_string(),
-            index_time: "2025-01-24T12:00:01.000Z".to_string(),
-            serial: Some(100),
-            content_hash: None,
-        };
-
-        // Same time, same serial, different index_time (edge case)
-        let entry_same_time_serial = make_log_entry(
-            "2025-01-24T12:00:00.000Z",
-            "2025-01-24T12:00:02.000Z",
-            Some(100),
-        );
-
-        assert!(cursor.is_after(&entry_same_time_serial));
-    }
-
-    #[test]
-    fn test_cursor_with_empty_index_time() {
-        // Cursor has valid index_time, entry has empty (missing) index_time
-        let cursor = LogCursor {
-            time: "2025-01-24T12:00:00.000Z".to_string(),
-            index_time: "2025-01-24T12:00:01.000Z".to_string(),
-            serial: Some(100),
-            content_hash: None,
-        };
-
-        let entry_empty_idx = make_log_entry("2025-01-24T12:00:00.000Z", "", None);
-
-        // Empty string is "less than" any valid timestamp, so entry is considered older
-        assert!(!cursor.is_after(&entry_empty_idx));
-
-        // Reverse: cursor has empty index_time, entry has valid
-        let cursor_empty = LogCursor {
-            time: "2025-01-24T12:00:00.000Z".to_string(),
-            index_time: "".to_string(),
-            serial: None,
-            content_hash: None,
-        };
-
-        let entry_valid_idx = make_log_entry(
-            "2025-01-24T12:00:00.000Z",
-            "2025-01-24T12:00:01.000Z",
-            Some(50),
-        );
-
-        // Valid index_time is "greater than" empty string, so entry is considered newer
-        assert!(cursor_empty.is_after(&entry_valid_idx));
-    }
-
-    #[test]
-    fn test_cursor_update_uses_newest_entry() {
-        // This test verifies fix for duplicate bug:
-        // When we have multiple new logs, cursor should update to the NEWEST one (first in list),
-        // not the oldest. This prevents re-querying same-timestamp events.
-
-        let cursor = LogCursor {
-            time: "2025-01-24T12:00:00.000Z".to_string(),
-            index_time: "2025-01-24T12:00:01.000Z".to_string(),
-            serial: Some(10),
-            content_hash: None,
-        };
-
-        // Simulate new logs returned from query (sorted descending by time, index_time, serial)
-        let new_logs = [
-            make_log_entry(
-                "2025-01-24T12:00:01.000Z",
-                "2025-01-24T12:00:02.000Z",
-                Some(30),
-            ),
-            make_log_entry(
-                "2025-01-24T12:00:01.000Z",
-                "2025-01-24T12:00:02.000Z",
-                Some(20),
-            ),
-            make_log_entry(
-                "2025-01-24T12:00:01.000Z",
-                "2025-01-24T12:00:02.000Z",
-                Some(15),
-            ),
-        ];
-
-        // All should be "after" current cursor
-        assert!(cursor.is_after(&new_logs[0]));
-        assert!(cursor.is_after(&new_logs[1]));
-        assert!(cursor.is_after(&new_logs[2]));
-
-        // Cursor should update to the FIRST (newest) entry
-        let new_cursor = LogCursor::from_entry(&new_logs[0]);
-        assert_eq!(new_cursor.serial, Some(30));
-
-        // On next query, none of these entries should be "after" new cursor
-        assert!(!new_cursor.is_after(&new_logs[0]));
-        assert!(!new_cursor.is_after(&new_logs[1]));
-        assert!(!new_cursor.is_after(&new_logs[2]));
-    }
-
-    #[test]
-    fn test_cursor_both_missing_serial_same_content() {
-        // Both cursor and entry lack serial, same content - should NOT be after
-        let entry = make_log_entry("2025-01-24T12:00:00.000Z", "2025-01-24T12:00:01.000Z", None);
-        let cursor = LogCursor::from_entry(&entry);
-
-        // Same entry - should NOT be after
-        assert!(!cursor.is_after(&entry));
-    }
-
-    #[test]
-    fn test_cursor_both_missing_serial_different_content() {
-        // Both cursor and entry lack serial, different content - should be after
-        let cursor_entry = LogEntry {
-            time: "2025-01-24T12:00:00.000Z".to_string(),
-            index_time: "2025-01-24T12:00:01.000Z".to_string(),
-            serial: None,
-            level: LogLevel::Info,
-            component: "test".to_string(),
-            message: "first message".to_string(),
-        };
-        let cursor = LogCursor::from_entry(&cursor_entry);
-
-        let new_entry = LogEntry {
-            time: "2025-01-24T12:00:00.000Z".to_string(),
-            index_time: "2025-01-24T12:00:01.000Z".to_string(),
-            serial: None,
-            level: LogLevel::Info,
-            component: "test".to_string(),
-            message: "different message".to_string(),
-        };
-
-        // Different content - should be after
-        assert!(cursor.is_after(&new_entry));
-    }
-
-    #[test]
-    fn test_cursor_missing_serial_entry_has_serial() {
-        // Cursor lacks serial but entry has it - entry is newer
-        let cursor = LogCursor {
-            time: "2025-01-24T12:00:00.000Z".to_string(),
-            index_time: "2025-01-24T12:00:01.000Z".to_string(),
-            serial: None,
-            content_hash: None,
-        };
-
-        let entry_with_serial = make_log_entry(
-            "2025-01-24T12:00:00.000Z",
-            "2025-01-24T12:00:01.000Z",
-            Some(100),
-        );
-
-        // Entry has serial but cursor doesn't - entry is newer
-        assert!(cursor.is_after(&entry_with_serial));
-    }
-
-    #[test]
-    fn test_cursor_from_entry_captures_content_hash_when_no_serial() {
-        let entry = LogEntry {
-            time: "2025-01-24T12:00:00.000Z".to_string(),
-            index_time: "2025-01-24T12:00:01.000Z".to_string(),
-            serial: None,
-            level: LogLevel::Info,
-            component: "test".to_string(),
-            message: "test message".to_string(),
-        };
-
-        let cursor = LogCursor::from_entry(&entry);
-
-        assert_eq!(cursor.time, "2025-01-24T12:00:00.000Z");
-        assert_eq!(cursor.index_time, "2025-01-24T12:00:01.000Z");
-        assert_eq!(cursor.serial, None);
-        assert!(cursor.content_hash.is_some());
-        assert_eq!(cursor.content_hash, Some(entry.content_hash()));
-    }
-
-    #[test]
-    fn test_cursor_from_entry_no_content_hash_when_has_serial() {
-        let entry = make_log_entry(
-            "2025-01-24T12:00:00.000Z",
-            "2025-01-24T12:00:01.000Z",
-            Some(42),
-        );
-
-        let cursor = LogCursor::from_entry(&entry);
-
-        assert_eq!(cursor.time, "2025-01-24T12:00:00.000Z");
-        assert_eq!(cursor.index_time, "2025-01-24T12:00:01.000Z");
-        assert_eq!(cursor.serial, Some(42));
-        assert!(cursor.content_hash.is_none());
-    }
-}
@@ -5,10 +5,11 @@
 //! - Download lookup table files as CSV
 //! - Upload or replace lookup table files
 //! - Delete lookup tables with confirmation
+//! - Print and edit lookup contents (see `contents`)
+//! - View and change lookup permissions (see `acl`)
 //! - Format output via shared formatters
 //!
 //! Does NOT handle:
-//! - Lookup table content editing
 //! - Direct REST API calls (handled by client crate)
 //! - Output formatting details (see formatters module)
 //!
@@ -16,8 +17,11 @@
 //! - Count and offset parameters are validated for safe pagination
 //! - Delete operations require confirmation unless --force is used
 
+mod contents;
+
 use anyhow::{Context, Result};
 use clap::Subcommand;
+use splunk_client::models::KnowledgeObjectKind;
 use tracing::info;
 
 use crate::formatters::{OutputFormat, get_formatter, output_result};
@@ -65,6 +69,32 @@ pub enum LookupsCommand {
         #[arg(long)]
         sharing: Option<String>,
     },
+    /// Print a lookup table's CSV contents
+    Cat {
+        /// The lookup name to print
+        name: String,
+        /// App namespace (defaults to "search")
+        #[arg(long)]
+        app: Option<String>,
+        /// Owner namespace (defaults to "-" for all users)
+        #[arg(long)]
+        owner: Option<String>,
+    },
+    /// Edit a lookup table in $VISUAL/$EDITOR and upload the result
+    ///
+    /// The lookup is downloaded to a temporary CSV file and opened in the editor.
+    /// When the editor exits successfully and the file changed, the edited CSV is
+    /// validated and uploaded in place of the original.
+    Edit {
+        /// The lookup name to edit
+        name: String,
+        /// App namespace (defaults to "search")
+        #[arg(long)]
+        app: Option<String>,
+        /// Owner namespace (defaults to "-" for all users)
+        #[arg(long)]
+        owner: Option<String>,
+    },
     /// Delete a lookup table file
     Delete {
         /// The lookup name to delete
@@ -79,6 +109,11 @@ pub enum LookupsCommand {
         #[arg(short, long)]
         force: bool,
     },
+    /// View or change permissions (owner, sharing, read/write roles)
+    Acl {
+        #[command(subcommand)]
+        command: crate::commands::acl::AclCommand,
+    },
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -136,12 +171,39 @@ pub async fn run(
             )
             .await
         }
+        LookupsCommand::Cat { name, app, owner } => {
+            contents::run_cat(
+                config,
+                &name,
+                app,
+                owner,
+                output_file,
+                cancel_token,
+                no_cache,
+            )
+            .await
+        }
+        LookupsCommand::Edit { name, app, owner } => {
+            contents::run_edit(config, &name, app, owner, cancel_token, no_cache).await
+        }
         LookupsCommand::Delete {
             name,
             app,
             owner,
             force,
         } => run_delete(config, &name, app, owner, force, cancel_token, no_cache).await,
+        LookupsCommand::Acl { command } => {
+            crate::commands::acl::run(
+                config,
+                KnowledgeObjectKind::Lookup,
+                command,
+                output_format,
+                output_file,
+                cancel_token,
+                no_cache,
+            )
+            .await
+        }
     }
 }
 
@@ -158,7 +220,7 @@ async fn run_list(
 
     info!("Listing lookup tables");
     let lookups = cancellable!(
-        client.list_lookup_tables(Some(count), Some(offset)),
+        client.list_lookup_tables(Some(count), Some(offset), None),
         cancel_token
     )?;
 
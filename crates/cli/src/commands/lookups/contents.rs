@@ -0,0 +1,130 @@
+//! Lookup contents commands (`lookups cat` and `lookups edit`).
+//!
+//! Responsibilities:
+//! - Print a lookup file's CSV contents.
+//! - Round-trip a lookup through `$VISUAL`/`$EDITOR` and upload the edited CSV.
+//!
+//! Does NOT handle:
+//! - Lookup file transfer (uses `SplunkClient::download_lookup_table` and
+//!   `SplunkClient::save_lookup_contents`).
+//!
+//! Invariants:
+//! - Unchanged or invalid edits are never uploaded; invalid edits are kept on disk.
+
+use anyhow::{Context, Result};
+use splunk_client::models::LookupContent;
+use tracing::info;
+
+use crate::formatters::{OutputFormat, output_result};
+
+/// Editor used when neither `VISUAL` nor `EDITOR` is set.
+const DEFAULT_EDITOR: &str = "vi";
+
+pub(super) async fn run_cat(
+    config: splunk_config::Config,
+    name: &str,
+    app: Option<String>,
+    owner: Option<String>,
+    output_file: Option<std::path::PathBuf>,
+    cancel_token: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    info!("Reading lookup table: {}", name);
+    let content = cancellable!(
+        client.download_lookup_table(name, app.as_deref(), owner.as_deref()),
+        cancel_token
+    )?;
+
+    output_result(&content, OutputFormat::Csv, output_file.as_ref())
+}
+
+pub(super) async fn run_edit(
+    config: splunk_config::Config,
+    name: &str,
+    app: Option<String>,
+    owner: Option<String>,
+    cancel_token: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    info!("Editing lookup table: {}", name);
+    let original = cancellable!(
+        client.download_lookup_table(name, app.as_deref(), owner.as_deref()),
+        cancel_token
+    )?;
+
+    let prefix = format!("{}-", name.trim_end_matches(".csv"));
+    let file = tempfile::Builder::new()
+        .prefix(&prefix)
+        .suffix(".csv")
+        .tempfile()
+        .context("Failed to create a temporary file for editing")?;
+    std::fs::write(file.path(), &original)
+        .with_context(|| format!("Failed to write {}", file.path().display()))?;
+
+    launch_editor(file.path()).await?;
+
+    let edited = std::fs::read_to_string(file.path())
+        .with_context(|| format!("Failed to read {}", file.path().display()))?;
+    if edited == original {
+        println!("No changes; lookup '{}' was not uploaded.", name);
+        return Ok(());
+    }
+
+    let content = match LookupContent::from_csv(&edited) {
+        Ok(content) => content,
+        Err(e) => {
+            let (_, path) = file.keep().context("Failed to keep the edited file")?;
+            anyhow::bail!(
+                "Edited lookup is not valid CSV ({}); nothing was uploaded. Your edits were kept at {}",
+                e,
+                path.display()
+            );
+        }
+    };
+
+    let lookup = cancellable!(
+        client.save_lookup_contents(name, app.as_deref(), owner.as_deref(), &content),
+        cancel_token
+    )?;
+    println!(
+        "Lookup '{}' updated ({} rows).",
+        lookup.name,
+        content.rows.len()
+    );
+
+    Ok(())
+}
+
+/// The user's editor command, split into program and arguments (e.g. `code --wait`).
+fn editor_command() -> Vec<String> {
+    let editor = ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_EDITOR.to_string());
+    editor.split_whitespace().map(str::to_string).collect()
+}
+
+async fn launch_editor(path: &std::path::Path) -> Result<()> {
+    let command = editor_command();
+    let (program, args) = command.split_first().context("Editor command is empty")?;
+
+    let status = tokio::process::Command::new(program)
+        .args(args)
+        .arg(path)
+        .status()
+        .await
+        .with_context(|| format!("Failed to launch editor '{}'", program))?;
+    if !status.success() {
+        anyhow::bail!(
+            "Editor '{}' exited with {}; nothing was uploaded",
+            program,
+            status
+        );
+    }
+    Ok(())
+}
@@ -6,10 +6,13 @@
 //! - Create new macros with definitions, arguments, and metadata
 //! - Update existing macro properties
 //! - Delete macros with confirmation
+//! - Preview the SPL a query expands to after macro substitution
+//! - View and change macro permissions (see `acl`)
 //! - Format output via shared formatters
 //!
 //! Does NOT handle:
-//! - Macro expansion or execution (done by Splunk at search time)
+//! - Macro expansion logic (lives in `splunk-client::workflows::macro_expansion`)
+//! - Macro execution (done by Splunk at search time)
 //! - Direct REST API calls (handled by client crate)
 //! - Output formatting details (see formatters module)
 //!
@@ -21,10 +24,12 @@
 
 use anyhow::Result;
 use clap::Subcommand;
+use splunk_client::models::KnowledgeObjectKind;
+use splunk_client::workflows::macro_expansion::{MacroExpansion, preview_macro_expansion};
 use splunk_client::{MacroCreateParams, MacroUpdateParams};
 use tracing::info;
 
-use crate::formatters::{OutputFormat, get_formatter, output_result};
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, get_formatter, output_result};
 use splunk_config::constants::*;
 
 #[derive(Subcommand)]
@@ -110,6 +115,17 @@ pub enum MacrosCommand {
         #[arg(short, long)]
         force: bool,
     },
+    /// Preview a query with all backtick macros expanded (nested macros included)
+    Expand {
+        /// SPL query containing macro invocations, e.g. '`my_macro(500)` | stats count'
+        #[arg(value_name = "QUERY")]
+        query: String,
+    },
+    /// View or change permissions (owner, sharing, read/write roles)
+    Acl {
+        #[command(subcommand)]
+        command: crate::commands::acl::AclCommand,
+    },
 }
 
 pub async fn run(
@@ -200,6 +216,29 @@ pub async fn run(
         MacrosCommand::Delete { name, force } => {
             run_delete(config, &name, force, cancel, no_cache).await
         }
+        MacrosCommand::Expand { query } => {
+            run_expand(
+                config,
+                &query,
+                output_format,
+                output_file.clone(),
+                cancel,
+                no_cache,
+            )
+            .await
+        }
+        MacrosCommand::Acl { command } => {
+            crate::commands::acl::run(
+                config,
+                KnowledgeObjectKind::Macro,
+                command,
+                output_format,
+                output_file.clone(),
+                cancel,
+                no_cache,
+            )
+            .await
+        }
     }
 }
 
@@ -333,7 +372,7 @@ async fn run_list(
 
     let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
 
-    let macros = cancellable!(client.list_macros(), cancel)?;
+    let macros = cancellable!(client.list_macros(None), cancel)?;
 
     let format = OutputFormat::from_str(output_format)?;
     let formatter = get_formatter(format);
@@ -387,3 +426,143 @@ async fn run_delete(
 
     Ok(())
 }
+
+async fn run_expand(
+    config: splunk_config::Config,
+    query: &str,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Expanding macros in query: {}", query);
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let expansion = cancellable!(preview_macro_expansion(&client, query), cancel)?;
+
+    let output = format_macro_expansion(&expansion, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+/// Format a macro expansion preview based on the selected format.
+pub fn format_macro_expansion(expansion: &MacroExpansion, format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(expansion)?),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(expansion)?),
+        OutputFormat::Ndjson => Ok(format!("{}\n", serde_json::to_string(expansion)?)),
+        OutputFormat::Table => Ok(format_expansion_text(expansion)),
+        OutputFormat::Csv => Ok(format_expansion_csv(expansion)),
+        OutputFormat::Xml => Ok(format_expansion_xml(expansion)),
+        OutputFormat::Markdown => Ok(format_expansion_markdown(expansion)),
+    }
+}
+
+fn eval_marker(iseval: bool) -> &'static str {
+    if iseval { " (eval, not evaluated)" } else { "" }
+}
+
+fn format_expansion_text(expansion: &MacroExpansion) -> String {
+    let mut out = format!("{}\n", expansion.expanded);
+    if !expansion.has_macros() {
+        out.push_str("\nNo macros found in query.\n");
+        return out;
+    }
+    if !expansion.macros.is_empty() {
+        out.push_str("\nExpanded macros:\n");
+        for m in &expansion.macros {
+            out.push_str(&format!(
+                "{}`{}`{} = {}\n",
+                "  ".repeat(m.depth),
+                m.name,
+                eval_marker(m.iseval),
+                m.definition
+            ));
+        }
+    }
+    if !expansion.unknown.is_empty() {
+        out.push_str("\nUnknown or disabled macros (left unexpanded):\n");
+        for name in &expansion.unknown {
+            out.push_str(&format!("  `{}`\n", name));
+        }
+    }
+    out
+}
+
+fn format_expansion_csv(expansion: &MacroExpansion) -> String {
+    let mut csv = String::from("name,depth,iseval,status,definition\n");
+    for m in &expansion.macros {
+        csv.push_str(&format!(
+            "{},{},{},expanded,{}\n",
+            escape_csv(&m.name),
+            m.depth,
+            m.iseval,
+            escape_csv(&m.definition)
+        ));
+    }
+    for name in &expansion.unknown {
+        csv.push_str(&format!("{},,,unknown,\n", escape_csv(name)));
+    }
+    csv
+}
+
+fn format_expansion_xml(expansion: &MacroExpansion) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<macro_expansion>\n");
+    xml.push_str(&format!(
+        "  <original>{}</original>\n",
+        escape_xml(&expansion.original)
+    ));
+    xml.push_str(&format!(
+        "  <expanded>{}</expanded>\n",
+        escape_xml(&expansion.expanded)
+    ));
+    xml.push_str("  <macros>\n");
+    for m in &expansion.macros {
+        xml.push_str(&format!(
+            "    <macro name=\"{}\" depth=\"{}\" iseval=\"{}\">{}</macro>\n",
+            escape_xml(&m.name),
+            m.depth,
+            m.iseval,
+            escape_xml(&m.definition)
+        ));
+    }
+    xml.push_str("  </macros>\n");
+    xml.push_str("  <unknown>\n");
+    for name in &expansion.unknown {
+        xml.push_str(&format!("    <macro name=\"{}\"/>\n", escape_xml(name)));
+    }
+    xml.push_str("  </unknown>\n");
+    xml.push_str("</macro_expansion>");
+    xml
+}
+
+fn format_expansion_markdown(expansion: &MacroExpansion) -> String {
+    let mut md = String::from("# Macro Expansion\n\n");
+    md.push_str(&format!("```spl\n{}\n```\n", expansion.expanded));
+    if !expansion.macros.is_empty() {
+        md.push_str("\n| Macro | Depth | Definition |\n|-------|-------|------------|\n");
+        for m in &expansion.macros {
+            md.push_str(&format!(
+                "| `{}`{} | {} | `{}` |\n",
+                m.name,
+                eval_marker(m.iseval),
+                m.depth,
+                m.definition.replace('|', "\\|")
+            ));
+        }
+    }
+    if !expansion.unknown.is_empty() {
+        md.push_str("\n**Unknown or disabled macros:** ");
+        let names: Vec<String> = expansion
+            .unknown
+            .iter()
+            .map(|name| format!("`{}`", name))
+            .collect();
+        md.push_str(&names.join(", "));
+        md.push('\n');
+    }
+    md
+}
@@ -0,0 +1,244 @@
+//! Server messages (bulletin board) command implementation.
+//!
+//! Responsibilities:
+//! - List the messages Splunk posts to its bulletin board (restart required,
+//!   license warnings, disk space alerts, ...)
+//! - Dismiss a message by name
+//! - Format the message list in every output format
+//!
+//! Does NOT handle:
+//! - Direct REST API calls (handled by client crate)
+//! - Read/unread tracking (the TUI header tracks what it has shown)
+//!
+//! Invariants:
+//! - Messages are listed most severe first, newest first within a severity
+//! - Message names are passed through without modification
+
+use anyhow::Result;
+use clap::Subcommand;
+use splunk_client::ServerMessage;
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+#[derive(Subcommand)]
+pub enum MessagesCommand {
+    /// List messages on the server's bulletin board
+    List,
+    /// Dismiss (delete) a bulletin board message
+    #[command(after_help = "Examples:
+  splunk-cli messages list
+  splunk-cli messages dismiss restart_required
+")]
+    Dismiss {
+        /// Name of the message to dismiss (the NAME column of `messages list`)
+        #[arg(value_name = "NAME")]
+        name: String,
+    },
+}
+
+pub async fn run(
+    config: splunk_config::Config,
+    command: MessagesCommand,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    match command {
+        MessagesCommand::List => {
+            run_list(config, output_format, output_file, cancel, no_cache).await
+        }
+        MessagesCommand::Dismiss { name } => run_dismiss(config, &name, cancel, no_cache).await,
+    }
+}
+
+async fn run_list(
+    config: splunk_config::Config,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Listing server messages");
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let messages = cancellable!(client.list_server_messages(), cancel)?;
+
+    let output = format_messages(&messages, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+    Ok(())
+}
+
+async fn run_dismiss(
+    config: splunk_config::Config,
+    name: &str,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Dismissing server message: {}", name);
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    cancellable_with!(client.delete_server_message(name), cancel, |_res| {
+        eprintln!("Message '{}' dismissed", name);
+        Ok(())
+    })?;
+
+    Ok(())
+}
+
+/// Format bulletin board messages based on the selected format.
+pub fn format_messages(messages: &[ServerMessage], format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(messages)?),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(messages)?),
+        OutputFormat::Ndjson => {
+            let mut ndjson = String::new();
+            for message in messages {
+                ndjson.push_str(&serde_json::to_string(message)?);
+                ndjson.push('\n');
+            }
+            Ok(ndjson)
+        }
+        OutputFormat::Table => {
+            if messages.is_empty() {
+                return Ok("No server messages.".to_string());
+            }
+            let mut out = format!(
+                "{:<8} {:<28} {:<25} {}\n",
+                "SEVERITY", "NAME", "CREATED", "MESSAGE"
+            );
+            out.push_str(&format!(
+                "{:<8} {:<28} {:<25} {}\n",
+                "========", "====", "=======", "======="
+            ));
+            for message in messages {
+                out.push_str(&format!(
+                    "{:<8} {:<28} {:<25} {}\n",
+                    message.severity,
+                    message.name,
+                    format_created(message.time_created),
+                    message.message
+                ));
+            }
+            Ok(out)
+        }
+        OutputFormat::Csv => {
+            let mut csv = String::from("name,severity,time_created,server,message,help\n");
+            for message in messages {
+                csv.push_str(&format!(
+                    "{},{},{},{},{},{}\n",
+                    escape_csv(&message.name),
+                    message.severity,
+                    message
+                        .time_created
+                        .map(|t| t.to_string())
+                        .unwrap_or_default(),
+                    escape_csv(message.server.as_deref().unwrap_or("")),
+                    escape_csv(&message.message),
+                    escape_csv(message.help.as_deref().unwrap_or(""))
+                ));
+            }
+            Ok(csv)
+        }
+        OutputFormat::Xml => {
+            let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<messages>\n");
+            for message in messages {
+                xml.push_str(&format!(
+                    "  <message name=\"{}\" severity=\"{}\">\n",
+                    escape_xml(&message.name),
+                    message.severity
+                ));
+                xml.push_str(&format!(
+                    "    <text>{}</text>\n",
+                    escape_xml(&message.message)
+                ));
+                if let Some(time_created) = message.time_created {
+                    xml.push_str(&format!(
+                        "    <time_created>{}</time_created>\n",
+                        time_created
+                    ));
+                }
+                if let Some(ref server) = message.server {
+                    xml.push_str(&format!("    <server>{}</server>\n", escape_xml(server)));
+                }
+                if let Some(ref help) = message.help {
+                    xml.push_str(&format!("    <help>{}</help>\n", escape_xml(help)));
+                }
+                xml.push_str("  </message>\n");
+            }
+            xml.push_str("</messages>");
+            Ok(xml)
+        }
+        OutputFormat::Markdown => {
+            let mut md = String::from("# Server Messages\n\n");
+            if messages.is_empty() {
+                md.push_str("_No server messages._\n");
+                return Ok(md);
+            }
+            md.push_str("| Severity | Name | Created | Message |\n");
+            md.push_str("|----------|------|---------|---------|\n");
+            for message in messages {
+                md.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    message.severity,
+                    message.name,
+                    format_created(message.time_created),
+                    message.message
+                ));
+            }
+            Ok(md)
+        }
+    }
+}
+
+fn format_created(time_created: Option<u64>) -> String {
+    time_created
+        .and_then(|t| chrono::DateTime::from_timestamp(t as i64, 0))
+        .map(|t| t.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use splunk_client::ServerMessageSeverity;
+
+    fn messages() -> Vec<ServerMessage> {
+        vec![ServerMessage {
+            name: "disk_space".to_string(),
+            message: "Disk space low, \"var\" at 95%".to_string(),
+            severity: ServerMessageSeverity::Error,
+            server: Some("idx1".to_string()),
+            help: None,
+            time_created: Some(1_700_000_000),
+        }]
+    }
+
+    #[test]
+    fn test_messages_table_shows_severity_and_time() {
+        let output = format_messages(&messages(), OutputFormat::Table).unwrap();
+        assert!(output.contains("error    disk_space"));
+        assert!(output.contains("2023-11-14 22:13:20 UTC"));
+        assert_eq!(
+            format_messages(&[], OutputFormat::Table).unwrap(),
+            "No server messages."
+        );
+    }
+
+    #[test]
+    fn test_messages_csv_escapes_message() {
+        let output = format_messages(&messages(), OutputFormat::Csv).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "name,severity,time_created,server,message,help",
+                "disk_space,error,1700000000,idx1,\"Disk space low, \"\"var\"\" at 95%\","
+            ]
+        );
+    }
+}
@@ -0,0 +1,326 @@
+//! Index metadata command implementation.
+//!
+//! Responsibilities:
+//! - List sourcetypes with event counts, first/last seen times, and the
+//!   indexes they appear in (`splunk-cli sourcetypes`)
+//! - List hosts and sources the same way, optionally only those with no
+//!   events within a staleness window (`splunk-cli metadata`)
+//! - Format metadata listings in every output format
+//!
+//! Does NOT handle:
+//! - Direct REST API calls or the underlying searches (handled by client crate)
+//!
+//! Invariants:
+//! - Entries are listed by name
+//! - Entries with no known last event time count as stale
+//! - Times are shown in UTC in table output and as epoch seconds in CSV
+
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Subcommand;
+use splunk_client::{MetadataEntry, MetadataType};
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+#[derive(Subcommand)]
+pub enum MetadataCommand {
+    /// List hosts with event counts, first/last seen, and indexes
+    #[command(after_help = "Examples:
+  splunk-cli metadata hosts
+  splunk-cli metadata hosts --index main --stale 24h
+  splunk-cli metadata hosts --stale 4h -o json
+")]
+    Hosts {
+        /// Only list hosts with events in this index
+        #[arg(short, long)]
+        index: Option<String>,
+        /// Only list hosts with no events within this window (e.g. 30m, 4h, 24h)
+        #[arg(long, value_name = "DURATION", value_parser = crate::commands::generate::parse_duration)]
+        stale: Option<Duration>,
+    },
+    /// List sources with event counts, first/last seen, and indexes
+    #[command(after_help = "Examples:
+  splunk-cli metadata sources
+  splunk-cli metadata sources --index main --stale 24h
+")]
+    Sources {
+        /// Only list sources with events in this index
+        #[arg(short, long)]
+        index: Option<String>,
+        /// Only list sources with no events within this window (e.g. 30m, 4h, 24h)
+        #[arg(long, value_name = "DURATION", value_parser = crate::commands::generate::parse_duration)]
+        stale: Option<Duration>,
+    },
+}
+
+pub async fn run(
+    config: splunk_config::Config,
+    command: MetadataCommand,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    let (metadata_type, index, stale) = match command {
+        MetadataCommand::Hosts { index, stale } => (MetadataType::Hosts, index, stale),
+        MetadataCommand::Sources { index, stale } => (MetadataType::Sources, index, stale),
+    };
+
+    info!(
+        "Listing {} (index: {:?}, stale: {:?})",
+        metadata_type, index, stale
+    );
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let mut entries = cancellable!(client.get_metadata(metadata_type, index.as_deref()), cancel)?;
+
+    if let Some(stale) = stale {
+        let now = chrono::Utc::now().timestamp().max(0) as u64;
+        entries = filter_stale(entries, now, stale);
+    }
+
+    let output = format_metadata(&entries, metadata_type, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+    Ok(())
+}
+
+/// Keep only entries with no events within `stale` before `now`.
+fn filter_stale(entries: Vec<MetadataEntry>, now: u64, stale: Duration) -> Vec<MetadataEntry> {
+    entries
+        .into_iter()
+        .filter(|entry| entry.is_stale(now, stale.as_secs()))
+        .collect()
+}
+
+pub async fn run_sourcetypes(
+    config: splunk_config::Config,
+    index: Option<String>,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Listing sourcetypes (index: {:?})", index);
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let entries = cancellable!(
+        client.get_metadata(MetadataType::Sourcetypes, index.as_deref()),
+        cancel
+    )?;
+
+    let output = format_metadata(&entries, MetadataType::Sourcetypes, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+    Ok(())
+}
+
+/// Format a host, source, or sourcetype listing based on the selected format.
+pub fn format_metadata(
+    entries: &[MetadataEntry],
+    metadata_type: MetadataType,
+    format: OutputFormat,
+) -> Result<String> {
+    let field = metadata_type.field();
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(entries)?),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(entries)?),
+        OutputFormat::Ndjson => {
+            let mut ndjson = String::new();
+            for entry in entries {
+                ndjson.push_str(&serde_json::to_string(entry)?);
+                ndjson.push('\n');
+            }
+            Ok(ndjson)
+        }
+        OutputFormat::Table => {
+            if entries.is_empty() {
+                return Ok(format!("No {} found.", metadata_type));
+            }
+            let header = field.to_uppercase();
+            let mut out = format!(
+                "{:<32} {:>12} {:<23} {:<23} {}\n",
+                header, "EVENTS", "FIRST SEEN", "LAST SEEN", "INDEXES"
+            );
+            out.push_str(&format!(
+                "{:<32} {:>12} {:<23} {:<23} {}\n",
+                "=".repeat(header.len()),
+                "======",
+                "==========",
+                "=========",
+                "======="
+            ));
+            for entry in entries {
+                out.push_str(&format!(
+                    "{:<32} {:>12} {:<23} {:<23} {}\n",
+                    entry.name,
+                    entry.total_count,
+                    format_time(entry.first_time),
+                    format_time(entry.last_time),
+                    entry.indexes.join(", ")
+                ));
+            }
+            Ok(out)
+        }
+        OutputFormat::Csv => {
+            let mut csv = format!(
+                "{},total_count,first_time,last_time,recent_time,indexes\n",
+                field
+            );
+            for entry in entries {
+                csv.push_str(&format!(
+                    "{},{},{},{},{},{}\n",
+                    escape_csv(&entry.name),
+                    entry.total_count,
+                    format_epoch(entry.first_time),
+                    format_epoch(entry.last_time),
+                    format_epoch(entry.recent_time),
+                    escape_csv(&entry.indexes.join(";"))
+                ));
+            }
+            Ok(csv)
+        }
+        OutputFormat::Xml => {
+            let mut xml = format!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<{}>\n",
+                metadata_type
+            );
+            for entry in entries {
+                xml.push_str(&format!(
+                    "  <{} name=\"{}\">\n",
+                    field,
+                    escape_xml(&entry.name)
+                ));
+                xml.push_str(&format!(
+                    "    <total_count>{}</total_count>\n",
+                    entry.total_count
+                ));
+                for (tag, time) in [
+                    ("first_time", entry.first_time),
+                    ("last_time", entry.last_time),
+                    ("recent_time", entry.recent_time),
+                ] {
+                    if let Some(time) = time {
+                        xml.push_str(&format!("    <{tag}>{time}</{tag}>\n"));
+                    }
+                }
+                xml.push_str("    <indexes>\n");
+                for index in &entry.indexes {
+                    xml.push_str(&format!("      <index>{}</index>\n", escape_xml(index)));
+                }
+                xml.push_str("    </indexes>\n");
+                xml.push_str(&format!("  </{}>\n", field));
+            }
+            xml.push_str(&format!("</{}>", metadata_type));
+            Ok(xml)
+        }
+        OutputFormat::Markdown => {
+            let mut md = format!("# {}\n\n", capitalize(metadata_type.as_str()));
+            if entries.is_empty() {
+                md.push_str(&format!("_No {} found._\n", metadata_type));
+                return Ok(md);
+            }
+            md.push_str(&format!(
+                "| {} | Events | First Seen | Last Seen | Indexes |\n",
+                capitalize(field)
+            ));
+            md.push_str("|---|--------|------------|-----------|---------|\n");
+            for entry in entries {
+                md.push_str(&format!(
+                    "| {} | {} | {} | {} | {} |\n",
+                    entry.name,
+                    entry.total_count,
+                    format_time(entry.first_time),
+                    format_time(entry.last_time),
+                    entry.indexes.join(", ")
+                ));
+            }
+            Ok(md)
+        }
+    }
+}
+
+fn format_time(epoch: Option<u64>) -> String {
+    epoch
+        .and_then(|t| chrono::DateTime::from_timestamp(t as i64, 0))
+        .map(|t| t.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn format_epoch(epoch: Option<u64>) -> String {
+    epoch.map(|t| t.to_string()).unwrap_or_default()
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries() -> Vec<MetadataEntry> {
+        vec![
+            MetadataEntry {
+                name: "access_combined".to_string(),
+                total_count: 1_250_000,
+                first_time: Some(1_690_000_000),
+                last_time: Some(1_700_086_400),
+                recent_time: Some(1_700_086_405),
+                indexes: vec!["main".to_string(), "web".to_string()],
+            },
+            MetadataEntry {
+                name: "legacy_csv".to_string(),
+                total_count: 312,
+                first_time: None,
+                last_time: None,
+                recent_time: None,
+                indexes: Vec::new(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_metadata_table_shows_times_and_indexes() {
+        let output =
+            format_metadata(&entries(), MetadataType::Sourcetypes, OutputFormat::Table).unwrap();
+        assert!(output.starts_with("SOURCETYPE"));
+        assert!(output.contains("2023-11-15 22:13:20 UTC"));
+        assert!(output.contains("main, web"));
+    }
+
+    #[test]
+    fn test_metadata_csv_uses_epoch_seconds() {
+        let output =
+            format_metadata(&entries(), MetadataType::Sourcetypes, OutputFormat::Csv).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "sourcetype,total_count,first_time,last_time,recent_time,indexes",
+                "access_combined,1250000,1690000000,1700086400,1700086405,main;web",
+                "legacy_csv,312,,,,"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_stale_keeps_silent_entries() {
+        let now = 1_700_086_400 + 25 * 3600;
+        let stale = filter_stale(entries(), now, Duration::from_secs(24 * 3600));
+        let names: Vec<&str> = stale.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["access_combined", "legacy_csv"]);
+
+        let stale = filter_stale(entries(), now, Duration::from_secs(48 * 3600));
+        let names: Vec<&str> = stale.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["legacy_csv"]);
+    }
+}
@@ -15,7 +15,10 @@
 //! - All async commands respect cancellation tokens
 //! - All output goes through shared formatters for consistency
 
+pub mod acl;
+pub mod acs;
 pub mod alerts;
+pub mod apply;
 pub mod apps;
 pub mod audit;
 pub mod cluster;
@@ -25,10 +28,14 @@ pub mod configs;
 pub mod dashboards;
 pub mod datamodels;
 pub mod doctor;
+pub mod export;
+pub mod extract;
 pub mod forwarders;
+pub mod generate;
 pub mod health;
 pub mod hec;
 pub mod indexes;
+pub mod ingestion;
 pub mod inputs;
 pub mod jobs;
 pub mod kvstore;
@@ -38,17 +45,65 @@ pub mod logs;
 pub mod lookups;
 pub mod macros;
 pub mod manpage;
+pub mod messages;
+pub mod metadata;
+pub mod monitor;
+pub mod multi_profile;
+pub mod offline;
+pub mod repl;
+pub mod rest;
 pub mod roles;
 pub mod saved_searches;
 pub mod search;
+pub mod search_diff;
 pub mod search_peers;
+pub mod server;
 pub mod shc;
+pub mod templates;
 pub mod transaction;
+pub mod tui;
 pub mod users;
+pub mod webhook;
+pub mod whoami;
 pub mod workload;
 
+use std::sync::{Arc, OnceLock};
+
 use anyhow::{Context, Result};
-use splunk_client::SplunkClient;
+use splunk_client::http_trace::HttpTraceRecorder;
+use splunk_client::{SplunkClient, SplunkClientBuilder};
+
+/// Process-wide HTTP trace recorder installed by `--trace-http`.
+static HTTP_TRACE_RECORDER: OnceLock<HttpTraceRecorder> = OnceLock::new();
+
+/// Process-wide client installed by `splunk-cli repl`.
+static SHARED_CLIENT: OnceLock<Arc<SplunkClient>> = OnceLock::new();
+
+/// Install a client that every later [`build_client_from_config`] call returns.
+///
+/// The REPL installs one client up front so its session, connection pool, and
+/// response cache outlive individual commands.
+pub fn install_shared_client(client: Arc<SplunkClient>) {
+    let _ = SHARED_CLIENT.set(client);
+}
+
+/// Install the process-wide HTTP trace recorder.
+///
+/// Every client built afterwards shares this recorder, so a single capture
+/// covers all requests made by the command.
+pub fn install_http_trace_recorder() -> HttpTraceRecorder {
+    HTTP_TRACE_RECORDER
+        .get_or_init(HttpTraceRecorder::new)
+        .clone()
+}
+
+/// Attach the process-wide HTTP trace recorder to a client builder, if installed.
+pub fn with_http_trace(builder: SplunkClientBuilder) -> SplunkClientBuilder {
+    match HTTP_TRACE_RECORDER.get() {
+        Some(recorder) => builder.trace_recorder(recorder.clone()),
+        None => builder,
+    }
+}
 
 /// Build a SplunkClient from configuration.
 ///
@@ -61,21 +116,47 @@ use splunk_client::SplunkClient;
 /// * `no_cache` - Optional flag to disable client-side response caching
 ///
 /// # Returns
-/// A configured SplunkClient ready for API calls
+/// A configured SplunkClient ready for API calls; the shared client when one is installed
 ///
 /// # Errors
 /// Returns an error if the client builder fails (e.g., invalid base_url)
 pub fn build_client_from_config(
     config: &splunk_config::Config,
     no_cache: Option<bool>,
-) -> Result<SplunkClient> {
-    let mut builder = SplunkClient::builder().from_config(config);
+) -> Result<Arc<SplunkClient>> {
+    if let Some(client) = SHARED_CLIENT.get() {
+        return Ok(client.clone());
+    }
+
+    let mut builder = with_http_trace(SplunkClient::builder().from_config(config));
 
     if no_cache == Some(true) {
         builder = builder.no_cache();
     }
 
-    builder.build().map_err(|e| e.into())
+    Ok(Arc::new(builder.build()?))
+}
+
+/// Fetch every page of a `count`/`offset` list endpoint for `--all` flags.
+///
+/// Prints a warning to stderr when the safety cap truncates the listing.
+pub async fn fetch_all_pages<T, F, Fut>(fetch: F) -> Result<Vec<T>>
+where
+    F: FnMut(usize, usize) -> Fut,
+    Fut: std::future::Future<Output = splunk_client::Result<Vec<T>>>,
+{
+    use futures::TryStreamExt;
+    use splunk_client::pagination::{PaginationOptions, hit_safety_cap, paginate_all};
+
+    let options = PaginationOptions::default();
+    let items: Vec<T> = paginate_all(options, fetch).try_collect().await?;
+    if hit_safety_cap(items.len(), &options) {
+        eprintln!(
+            "Warning: stopped after {} entries (safety cap); results may be incomplete",
+            options.max_items
+        );
+    }
+    Ok(items)
 }
 
 /// Get the transaction manager for the current environment.
@@ -0,0 +1,218 @@
+//! Monitoring daemon (`splunk-cli monitor`).
+//!
+//! Responsibilities:
+//! - Run the selected checks every `--interval` until interrupted, or once with `--once`
+//! - Print each level change as a text line, or one JSON object per line with
+//!   `-o json`/`-o ndjson`
+//! - Post level changes to `--notify-webhook`
+//! - Load and save check levels in `--state-file` so a restart only reports real changes
+//!
+//! Does NOT handle:
+//! - Evaluating checks against thresholds (see `splunk_client::workflows::monitor`)
+//! - Webhook payloads (see the webhook module)
+//!
+//! Invariants:
+//! - stdout carries only transitions; an evaluation without changes prints nothing
+//! - The state file is rewritten atomically after every evaluation that changed a level
+//! - An unreadable state file fails at startup instead of being silently reset
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use futures::future::join_all;
+use splunk_client::SplunkClient;
+use splunk_client::workflows::monitor::{
+    CheckOutcome, MonitorCheck, MonitorState, Transition, run_check,
+};
+use splunk_config::HealthThresholds;
+use tokio::time::sleep;
+use tracing::info;
+
+use crate::cancellation::Cancelled;
+use crate::commands::webhook::{self, StatusEvent};
+use crate::error::UsageError;
+use crate::formatters::{OutputFormat, write_to_file};
+
+/// What `monitor` runs and where it reports.
+#[derive(Debug, Clone)]
+pub struct MonitorOptions {
+    pub interval: Duration,
+    pub checks: Vec<MonitorCheck>,
+    pub state_file: Option<PathBuf>,
+    pub notify_webhook: Option<String>,
+    /// Overrides the profile's license warning threshold.
+    pub license_warning: Option<f64>,
+    /// Overrides the profile's license critical threshold.
+    pub license_critical: Option<f64>,
+    pub once: bool,
+}
+
+pub async fn run(
+    config: splunk_config::Config,
+    options: MonitorOptions,
+    output_format: &str,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    let json = match OutputFormat::from_str(output_format)? {
+        OutputFormat::Json | OutputFormat::Ndjson => true,
+        OutputFormat::Table => false,
+        _ => {
+            return Err(UsageError(format!(
+                "monitor supports table, json, and ndjson output, not '{}'",
+                output_format
+            ))
+            .into());
+        }
+    };
+    let mut thresholds = config
+        .connection
+        .health_thresholds
+        .clone()
+        .unwrap_or_default();
+    if let Some(pct) = options.license_warning {
+        thresholds.license_warning_pct = pct;
+    }
+    if let Some(pct) = options.license_critical {
+        thresholds.license_critical_pct = pct;
+    }
+    thresholds.validate().map_err(UsageError)?;
+    let mut checks = options.checks.clone();
+    checks.sort();
+    checks.dedup();
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+    let server = client.base_url().to_string();
+    let mut state = match &options.state_file {
+        Some(path) => load_state(path)?,
+        None => MonitorState::default(),
+    };
+
+    info!(
+        "Monitoring {} every {}s: {}",
+        server,
+        options.interval.as_secs(),
+        checks
+            .iter()
+            .map(|check| check.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    loop {
+        let outcomes = tokio::select! {
+            outcomes = evaluate(&client, &checks, &thresholds) => outcomes,
+            _ = cancel.cancelled() => return Err(Cancelled.into()),
+        };
+        let at = chrono::Utc::now().to_rfc3339();
+        let transitions = state.apply(&server, &outcomes, &at);
+
+        if let Some(path) = options
+            .state_file
+            .as_ref()
+            .filter(|_| !transitions.is_empty())
+        {
+            save_state(path, &state)?;
+        }
+        for transition in &transitions {
+            println!("{}", format_transition(transition, json)?);
+            if let Some(url) = &options.notify_webhook {
+                notify(url, &server, transition).await;
+            }
+        }
+
+        if options.once {
+            return Ok(());
+        }
+        tokio::select! {
+            _ = sleep(options.interval) => {}
+            _ = cancel.cancelled() => return Err(Cancelled.into()),
+        }
+    }
+}
+
+/// Run every check concurrently, in `checks` order.
+async fn evaluate(
+    client: &SplunkClient,
+    checks: &[MonitorCheck],
+    thresholds: &HealthThresholds,
+) -> Vec<CheckOutcome> {
+    join_all(
+        checks
+            .iter()
+            .map(|check| run_check(client, *check, thresholds)),
+    )
+    .await
+}
+
+fn format_transition(transition: &Transition, json: bool) -> Result<String> {
+    if json {
+        return Ok(serde_json::to_string(transition)?);
+    }
+    let from = transition.from.map(|level| level.as_str()).unwrap_or("new");
+    Ok(format!(
+        "{} {} {} -> {}: {}",
+        transition.at, transition.check, from, transition.to, transition.message
+    ))
+}
+
+/// Post a transition, except a check first seen as ok.
+async fn notify(url: &str, server: &str, transition: &Transition) {
+    if transition.from.is_none() && transition.to.is_ok() {
+        return;
+    }
+    let event = StatusEvent {
+        source: "monitor",
+        check: Some(transition.check.as_str()),
+        server: server.to_string(),
+        status: transition.to.to_string(),
+        healthy: transition.to.is_ok(),
+        details: vec![transition.message.clone()],
+    };
+    let previous = transition.from.map(|level| level.as_str());
+    if let Err(e) = webhook::send_event(url, &event, previous).await {
+        eprintln!("Warning: Failed to send webhook notification: {:#}", e);
+    }
+}
+
+/// Load saved levels; a missing file starts empty.
+fn load_state(path: &Path) -> Result<MonitorState> {
+    if !path.exists() {
+        return Ok(MonitorState::default());
+    }
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read state file {}", path.display()))?;
+    serde_json::from_str(&contents)
+        .with_context(|| format!("Failed to parse state file {}", path.display()))
+}
+
+fn save_state(path: &Path, state: &MonitorState) -> Result<()> {
+    write_to_file(&serde_json::to_string_pretty(state)?, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use splunk_client::workflows::monitor::CheckLevel;
+
+    #[test]
+    fn test_format_transition_text_and_json() {
+        let transition = Transition {
+            check: MonitorCheck::License,
+            from: None,
+            to: CheckLevel::Warning,
+            message: "enterprise at 75.0% of daily quota".to_string(),
+            at: "2026-10-19T08:00:00+00:00".to_string(),
+        };
+        assert_eq!(
+            format_transition(&transition, false).unwrap(),
+            "2026-10-19T08:00:00+00:00 license new -> warning: enterprise at 75.0% of daily quota"
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&format_transition(&transition, true).unwrap()).unwrap();
+        assert_eq!(json["check"], "license");
+        assert_eq!(json["from"], serde_json::Value::Null);
+        assert_eq!(json["to"], "warning");
+    }
+}
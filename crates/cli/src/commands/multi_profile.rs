@@ -0,0 +1,592 @@
+//! Run a command against several profiles at once (`--profiles`, `--all-profiles`).
+//!
+//! Responsibilities:
+//! - Define the `--profiles a,b` / `--all-profiles` flags shared by `health`,
+//!   `indexes list`, `jobs`, `search execute`, and `list-all`.
+//! - Resolve and validate target profile names against the config file.
+//! - Run one fetch per profile concurrently, at most `MAX_CONCURRENT_PROFILES` at a time.
+//! - Tag every result row with its profile and render the rows in the output format.
+//! - Report the profiles that failed together, after the other profiles' rows are printed.
+//!
+//! Does NOT handle:
+//! - What each command fetches (command modules pass a fetch closure).
+//! - `list-all` resource summaries (see `list_all`, which only shares profile resolution).
+//!
+//! Invariants:
+//! - Connection settings come from each profile; connection flags such as `--base-url`
+//!   and `SPLUNK_*` connection environment variables are not applied.
+//! - Rows keep the order of the profiles, whatever order the profiles finish in.
+//! - One failing profile never hides the rows of the others.
+
+use std::fmt;
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::Result;
+use futures::stream::{self, StreamExt};
+use serde_json::{Value, json};
+use splunk_client::SplunkClient;
+use splunk_client::workflows::result_merge::merge_results;
+use splunk_config::{ConfigLoader, ConfigManager};
+use tracing::info;
+
+use crate::args::{Cli, Commands};
+use crate::cancellation::CancellationToken;
+use crate::error::{ExitCode, ExitCodeExt, UsageError};
+use crate::formatters::{OutputFormat, installed_output_filter, output_result, render_rows};
+
+/// Profiles queried at the same time.
+const MAX_CONCURRENT_PROFILES: usize = 4;
+
+/// Column added to every row, holding the profile it came from.
+pub const PROFILE_COLUMN: &str = "profile";
+
+/// `--profiles` / `--all-profiles` flags.
+#[derive(Debug, Clone, Default, clap::Args)]
+pub struct ProfileTargets {
+    /// Comma-separated list of profile names to query concurrently (e.g., 'dev,prod')
+    ///
+    /// If not specified, uses the default profile or SPLUNK_PROFILE env var.
+    #[arg(long, value_delimiter = ',', value_name = "PROFILES")]
+    pub profiles: Option<Vec<String>>,
+
+    /// Query all configured profiles
+    #[arg(long, conflicts_with = "profiles")]
+    pub all_profiles: bool,
+}
+
+impl ProfileTargets {
+    /// True when the command should fan out instead of using the active profile.
+    pub fn is_set(&self) -> bool {
+        self.all_profiles || self.profiles.is_some()
+    }
+
+    /// Target profile names: every configured profile (sorted) for `--all-profiles`,
+    /// else the listed names trimmed and deduplicated in the order given.
+    pub fn resolve(&self, manager: &ConfigManager) -> Result<Vec<String>> {
+        let available = manager.list_profiles();
+        let names: Vec<String> = if self.all_profiles {
+            available.keys().cloned().collect()
+        } else {
+            let mut seen = std::collections::HashSet::new();
+            self.profiles
+                .iter()
+                .flatten()
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty())
+                .filter(|name| seen.insert(name.clone()))
+                .collect()
+        };
+
+        if names.is_empty() {
+            return Err(UsageError(
+                "Failed to list profiles: No profiles configured. Use 'splunk-cli config set <profile>' to add one."
+                    .to_string(),
+            )
+            .into());
+        }
+        if let Some(missing) = names.iter().find(|name| !available.contains_key(*name)) {
+            return Err(UsageError(format!(
+                "Profile '{}' not found. Available profiles: {}",
+                missing,
+                available.keys().cloned().collect::<Vec<_>>().join(", ")
+            ))
+            .into());
+        }
+        Ok(names)
+    }
+}
+
+/// The `--profiles` / `--all-profiles` flags of `command`, when they were given.
+pub fn requested_targets(command: &Commands) -> Option<&ProfileTargets> {
+    use crate::commands::indexes::IndexesCommand;
+    use crate::commands::search::SearchCommand;
+
+    let targets = match command {
+        Commands::Health { targets, .. }
+        | Commands::Jobs { targets, .. }
+        | Commands::ListAll { targets, .. }
+        | Commands::Indexes {
+            command: IndexesCommand::List { targets, .. },
+        }
+        | Commands::Search {
+            command: Some(SearchCommand::Execute { targets, .. }),
+            ..
+        } if targets.is_set() => targets,
+        // Legacy `search <QUERY> --profiles ...`, or flags given before the subcommand
+        Commands::Search { targets, .. } => targets,
+        _ => return None,
+    };
+    targets.is_set().then_some(targets)
+}
+
+/// Run `command` on the profiles its `--profiles` / `--all-profiles` flags name.
+///
+/// Covers `health`, `indexes list`, `jobs`, and `search execute`; `list-all` has its own
+/// multi-profile summary.
+pub async fn run(
+    source: &ProfileSource,
+    command: Commands,
+    output_format: &str,
+    output_file: Option<&PathBuf>,
+    cancel: &CancellationToken,
+) -> Result<()> {
+    use crate::commands::indexes::IndexesCommand;
+    use crate::commands::search::SearchCommand;
+    use crate::commands::{health, indexes, jobs, search};
+
+    let unsupported = |what: &str| -> Result<()> {
+        Err(UsageError(format!(
+            "--profiles and --all-profiles are not supported with {}",
+            what
+        ))
+        .into())
+    };
+
+    match command {
+        Commands::Health {
+            command: Some(_), ..
+        } => unsupported("health subcommands"),
+        Commands::Health { watch: Some(_), .. }
+        | Commands::Health {
+            notify_webhook: Some(_),
+            ..
+        } => unsupported("--watch or --notify-webhook"),
+        Commands::Health { targets, .. } => {
+            health::run_profiles(source, &targets, output_format, output_file, cancel).await
+        }
+        Commands::Indexes {
+            command:
+                IndexesCommand::List {
+                    detailed,
+                    count,
+                    offset,
+                    all,
+                    targets,
+                },
+        } => {
+            indexes::run_list_profiles(
+                source,
+                &targets,
+                detailed,
+                count,
+                offset,
+                all,
+                output_format,
+                output_file,
+                cancel,
+            )
+            .await
+        }
+        Commands::Jobs {
+            command,
+            inspect,
+            cancel: cancel_sid,
+            delete,
+            results,
+            ..
+        } if command.is_some()
+            || inspect.is_some()
+            || cancel_sid.is_some()
+            || delete.is_some()
+            || results.is_some() =>
+        {
+            unsupported("job actions; they only apply to listing jobs")
+        }
+        Commands::Jobs {
+            count,
+            owner,
+            app,
+            status,
+            targets,
+            ..
+        } => {
+            let filter = splunk_client::JobFilter { owner, app, status };
+            jobs::run_list_profiles(
+                source,
+                &targets,
+                count,
+                &filter,
+                output_format,
+                output_file,
+                cancel,
+            )
+            .await
+        }
+        Commands::Search {
+            command,
+            query,
+            earliest,
+            latest,
+            count,
+            realtime,
+            finalize_after,
+            diff,
+            targets,
+            merge,
+            ..
+        } => {
+            // A legacy positional query runs like `search execute`
+            let command = command.or_else(|| {
+                query.map(|query| SearchCommand::Execute {
+                    query,
+                    wait: true,
+                    earliest,
+                    latest,
+                    count,
+                    realtime,
+                    realtime_window: None,
+                    finalize_after,
+                    diff,
+                    targets: targets.clone(),
+                    merge: merge.clone(),
+                })
+            });
+            let Some(SearchCommand::Execute {
+                query,
+                earliest,
+                latest,
+                count,
+                realtime,
+                finalize_after,
+                diff,
+                targets: execute_targets,
+                merge: execute_merge,
+                ..
+            }) = command
+            else {
+                return unsupported("search subcommands other than execute");
+            };
+            if realtime {
+                return unsupported("--realtime");
+            }
+            if diff.diff_earliest.is_some() {
+                return unsupported("--diff-earliest");
+            }
+            // Flags given before `execute` apply when the subcommand has none of its own
+            let (targets, merge) = if execute_targets.is_set() {
+                (execute_targets, execute_merge)
+            } else {
+                (targets, merge)
+            };
+            search::run_profiles(
+                source,
+                &targets,
+                &merge,
+                query,
+                earliest.as_deref(),
+                latest.as_deref(),
+                count,
+                finalize_after,
+                output_format,
+                output_file,
+                cancel,
+            )
+            .await
+        }
+        _ => anyhow::bail!("Internal error: command does not run across profiles"),
+    }
+}
+
+/// Where profiles are loaded from: the global config file options.
+#[derive(Debug, Clone, Default)]
+pub struct ProfileSource {
+    config_path: Option<PathBuf>,
+    config_password: Option<String>,
+    config_key_var: Option<String>,
+    no_cache: bool,
+}
+
+impl ProfileSource {
+    pub fn from_cli(cli: &Cli) -> Self {
+        Self {
+            // Blank paths fall back to the default location, as they do for `--profile`
+            config_path: cli
+                .config_path
+                .clone()
+                .filter(|path| !path.to_string_lossy().trim().is_empty()),
+            config_password: cli.config_password.clone(),
+            config_key_var: cli.config_key_var.clone(),
+            no_cache: cli.no_cache,
+        }
+    }
+
+    /// Open the config file the profiles are listed in.
+    pub fn manager(&self) -> Result<ConfigManager> {
+        super::config::open_config_manager(
+            self.config_path.clone(),
+            self.config_password.clone(),
+            self.config_key_var.clone(),
+        )
+    }
+
+    /// Search defaults for fanned-out searches: environment overrides or the built-in values.
+    pub fn search_defaults(&self) -> Result<splunk_config::SearchDefaultConfig> {
+        Ok(ConfigLoader::new().from_env()?.build_search_defaults(None))
+    }
+
+    /// Build a client for `profile` from its settings alone.
+    ///
+    /// Never returns the REPL's shared client, which belongs to another profile.
+    fn client(&self, profile: &str) -> Result<Arc<SplunkClient>> {
+        let mut loader = ConfigLoader::new().with_profile_name(profile.to_string());
+        if let Some(path) = &self.config_path {
+            loader = loader.with_config_path(path.clone());
+        }
+        if let Some(password) = &self.config_password {
+            loader = loader.with_config_password(password.clone());
+        }
+        if let Some(var_name) = &self.config_key_var {
+            loader = loader.with_config_key_var(var_name.clone());
+        }
+        let config = loader.from_profile()?.build()?;
+
+        let mut builder = super::with_http_trace(SplunkClient::builder().from_config(&config));
+        if self.no_cache {
+            builder = builder.no_cache();
+        }
+        Ok(Arc::new(builder.build()?))
+    }
+}
+
+/// Rows from every profile that answered, and the errors of those that did not.
+pub struct ProfileRows {
+    rows: Vec<Value>,
+    failures: ProfileFailures,
+}
+
+impl ProfileRows {
+    /// Merge rows returned by several profiles (see `workflows::result_merge`).
+    pub fn merge(mut self, sum_by: &[String], sum_fields: &[String]) -> Self {
+        self.rows = merge_results(&self.rows, PROFILE_COLUMN, sum_by, sum_fields);
+        self
+    }
+
+    /// Print the rows, then fail with the profiles that failed, if any.
+    ///
+    /// Table, CSV, and Markdown output show the profile column followed by
+    /// `columns` (every field when empty); other formats keep every field.
+    pub fn print(
+        self,
+        columns: &[&str],
+        output_format: &str,
+        output_file: Option<&PathBuf>,
+    ) -> Result<()> {
+        let format = OutputFormat::from_str(output_format)?;
+
+        if !self.rows.is_empty() || self.failures.failures.is_empty() {
+            let tabular = matches!(
+                format,
+                OutputFormat::Table | OutputFormat::Csv | OutputFormat::Markdown
+            );
+            // An installed `--fields`/`--query` filter picks the columns itself
+            let fields = if tabular && installed_output_filter().is_none() {
+                table_columns(&self.rows, columns)
+            } else {
+                Vec::new()
+            };
+            let output = render_rows(self.rows, &fields, format)?;
+            output_result(&output, format, output_file)?;
+        }
+
+        if self.failures.failures.is_empty() {
+            Ok(())
+        } else {
+            Err(self.failures.into())
+        }
+    }
+}
+
+/// The profile column, then `columns` or every other field in first-seen order.
+fn table_columns(rows: &[Value], columns: &[&str]) -> Vec<String> {
+    let mut fields = vec![PROFILE_COLUMN.to_string()];
+    if !columns.is_empty() {
+        fields.extend(columns.iter().map(|column| column.to_string()));
+        return fields;
+    }
+    for row in rows {
+        if let Value::Object(map) = row {
+            for key in map.keys() {
+                if !fields.contains(key) {
+                    fields.push(key.clone());
+                }
+            }
+        }
+    }
+    fields
+}
+
+/// Add the profile column to a row; non-object rows are wrapped as `value`.
+fn tag_row(row: Value, profile: &str) -> Value {
+    match row {
+        Value::Object(mut map) => {
+            map.insert(
+                PROFILE_COLUMN.to_string(),
+                Value::String(profile.to_string()),
+            );
+            Value::Object(map)
+        }
+        other => json!({ PROFILE_COLUMN: profile, "value": other }),
+    }
+}
+
+/// Run `fetch` once per target profile, at most `MAX_CONCURRENT_PROFILES` at a time.
+///
+/// Each row `fetch` returns is tagged with the profile name. A profile whose client
+/// cannot be built or whose fetch fails is recorded in the returned failures.
+pub async fn fan_out<F, Fut>(
+    source: &ProfileSource,
+    targets: &ProfileTargets,
+    cancel: &CancellationToken,
+    fetch: F,
+) -> Result<ProfileRows>
+where
+    F: Fn(Arc<SplunkClient>) -> Fut,
+    Fut: Future<Output = Result<Vec<Value>>>,
+{
+    let names = targets.resolve(&source.manager()?)?;
+    let profile_count = names.len();
+    info!(
+        "Running against {} profile(s): {}",
+        profile_count,
+        names.join(", ")
+    );
+
+    let fetch = &fetch;
+    let mut outcomes = cancellable!(
+        async {
+            Ok::<_, anyhow::Error>(
+                stream::iter(
+                    names
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, name)| async move {
+                            let outcome = match source.client(&name) {
+                                Ok(client) => fetch(client).await,
+                                Err(e) => Err(e),
+                            };
+                            (index, name, outcome)
+                        }),
+                )
+                .buffer_unordered(MAX_CONCURRENT_PROFILES)
+                .collect::<Vec<_>>()
+                .await,
+            )
+        },
+        cancel
+    )?;
+    outcomes.sort_by_key(|(index, _, _)| *index);
+
+    let mut rows = Vec::new();
+    let mut failures = Vec::new();
+    for (_, name, outcome) in outcomes {
+        match outcome {
+            Ok(profile_rows) => {
+                rows.extend(profile_rows.into_iter().map(|row| tag_row(row, &name)))
+            }
+            Err(e) => failures.push((name, e)),
+        }
+    }
+
+    Ok(ProfileRows {
+        rows,
+        failures: ProfileFailures {
+            profile_count,
+            failures,
+        },
+    })
+}
+
+/// The profiles that failed during a fan-out, reported as one error.
+#[derive(Debug)]
+pub struct ProfileFailures {
+    profile_count: usize,
+    failures: Vec<(String, anyhow::Error)>,
+}
+
+impl ProfileFailures {
+    /// The exit code every failure shares, or the generic one when they differ.
+    pub fn exit_code(&self) -> ExitCode {
+        let mut codes = self.failures.iter().map(|(_, e)| e.exit_code());
+        match codes.next() {
+            Some(first) if codes.all(|code| code == first) => first,
+            _ => ExitCode::GeneralError,
+        }
+    }
+}
+
+impl fmt::Display for ProfileFailures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} profiles failed:",
+            self.failures.len(),
+            self.profile_count
+        )?;
+        for (name, error) in &self.failures {
+            write!(f, "\n  {}: {:#}", name, error)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ProfileFailures {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use splunk_client::ClientError;
+
+    #[test]
+    fn test_tag_row_adds_profile_column() {
+        let row = tag_row(json!({ "name": "main" }), "prod");
+        assert_eq!(row, json!({ "name": "main", "profile": "prod" }));
+
+        let row = tag_row(json!(42), "dev");
+        assert_eq!(row, json!({ "profile": "dev", "value": 42 }));
+    }
+
+    #[test]
+    fn test_table_columns_put_profile_first() {
+        let rows = vec![
+            json!({ "profile": "dev", "sid": "1", "isDone": true }),
+            json!({ "profile": "prod", "sid": "2", "label": "x" }),
+        ];
+        assert_eq!(
+            table_columns(&rows, &["sid", "isDone"]),
+            vec!["profile", "sid", "isDone"]
+        );
+        assert_eq!(
+            table_columns(&rows, &[]),
+            vec!["profile", "isDone", "sid", "label"]
+        );
+    }
+
+    #[test]
+    fn test_failures_share_exit_code_only_when_all_agree() {
+        let auth = || anyhow::Error::new(ClientError::AuthFailed("bad token".to_string()));
+        let failures = ProfileFailures {
+            profile_count: 3,
+            failures: vec![("dev".to_string(), auth()), ("prod".to_string(), auth())],
+        };
+        assert_eq!(failures.exit_code(), ExitCode::AuthenticationFailed);
+        assert!(
+            failures
+                .to_string()
+                .starts_with("2 of 3 profiles failed:\n  dev: ")
+        );
+
+        let failures = ProfileFailures {
+            profile_count: 2,
+            failures: vec![
+                ("dev".to_string(), auth()),
+                (
+                    "prod".to_string(),
+                    anyhow::Error::new(ClientError::NotFound("index".to_string())),
+                ),
+            ],
+        };
+        assert_eq!(failures.exit_code(), ExitCode::GeneralError);
+    }
+}
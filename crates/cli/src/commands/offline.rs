@@ -0,0 +1,119 @@
+//! Offline mode (`--offline`) implementation.
+//!
+//! Responsibilities:
+//! - Answer `indexes list`, `jobs` (list), and `health` from the snapshot cache the TUI saves
+//! - Report when the shown data was saved
+//!
+//! Does NOT handle:
+//! - Saving snapshots (the TUI saves its last successful indexes, jobs, and health responses)
+//! - Any network access; no client is built in offline mode
+//!
+//! Invariants:
+//! - Snapshots are read for the selected profile (`--profile`/`SPLUNK_PROFILE`), or the
+//!   default snapshot when no profile is selected
+//! - Commands without a snapshot fail instead of falling back to the server
+
+use anyhow::{Context, Result, bail};
+use serde::de::DeserializeOwned;
+use splunk_client::SearchJobStatus;
+use splunk_client::models::{HealthCheckOutput, Index};
+use splunk_config::{DataSnapshot, SnapshotKind};
+
+use crate::args::{Cli, Commands};
+use crate::commands::indexes::IndexesCommand;
+use crate::formatters::{OutputFormat, get_formatter, output_result};
+
+/// Run the selected command against the snapshot cache.
+pub fn run(cli: &Cli) -> Result<()> {
+    let kind = snapshot_kind(&cli.command)?;
+    let manager = crate::commands::config::open_config_manager(
+        cli.config_path.clone(),
+        cli.config_password.clone(),
+        cli.config_key_var.clone(),
+    )?;
+    let profile = cli.profile.as_deref();
+    let Some(snapshot) = manager.load_snapshot(profile, kind) else {
+        bail!(
+            "No offline snapshot of {} for profile '{}'. Open the {} screen in splunk-tui while connected to save one.",
+            kind,
+            profile.unwrap_or(splunk_config::DEFAULT_SNAPSHOT_PROFILE),
+            kind
+        );
+    };
+    eprintln!("OFFLINE — data from {}", format_saved_at(snapshot.saved_at));
+
+    let format = OutputFormat::from_str(&cli.output)?;
+    let formatter = get_formatter(format);
+    let output = match &cli.command {
+        Commands::Indexes {
+            command:
+                IndexesCommand::List {
+                    detailed,
+                    count,
+                    offset,
+                    all,
+                    ..
+                },
+        } => {
+            let indexes: Vec<Index> = decode(snapshot, kind)?;
+            let indexes: Vec<Index> = if *all {
+                indexes
+            } else {
+                indexes.into_iter().skip(*offset).take(*count).collect()
+            };
+            formatter.format_indexes(&indexes, *detailed)?
+        }
+        Commands::Jobs { count, .. } => {
+            let mut jobs: Vec<SearchJobStatus> = decode(snapshot, kind)?;
+            jobs.truncate(*count);
+            formatter.format_jobs(&jobs)?
+        }
+        _ => formatter.format_health(&decode::<HealthCheckOutput>(snapshot, kind)?)?,
+    };
+    output_result(&output, format, cli.output_file.as_ref())?;
+    Ok(())
+}
+
+/// Snapshot that answers `command`, or an error if it cannot run offline.
+fn snapshot_kind(command: &Commands) -> Result<SnapshotKind> {
+    match command {
+        Commands::Indexes {
+            command: IndexesCommand::List { .. },
+        } => Ok(SnapshotKind::Indexes),
+        Commands::Jobs {
+            command: None,
+            inspect: None,
+            cancel: None,
+            delete: None,
+            results: None,
+            owner: None,
+            app: None,
+            status: None,
+            ..
+        } => Ok(SnapshotKind::Jobs),
+        Commands::Health {
+            command: None,
+            watch: None,
+            notify_webhook: None,
+            ..
+        } => Ok(SnapshotKind::Health),
+        _ => {
+            bail!("--offline only supports `indexes list`, `jobs` (unfiltered list), and `health`")
+        }
+    }
+}
+
+fn decode<T: DeserializeOwned>(snapshot: DataSnapshot, kind: SnapshotKind) -> Result<T> {
+    serde_json::from_value(snapshot.data)
+        .with_context(|| format!("Offline snapshot of {} is unreadable", kind))
+}
+
+fn format_saved_at(saved_at: u64) -> String {
+    chrono::DateTime::from_timestamp(saved_at as i64, 0)
+        .map(|time| {
+            time.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M:%S %Z")
+                .to_string()
+        })
+        .unwrap_or_else(|| "an unknown time".to_string())
+}
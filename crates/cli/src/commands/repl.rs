@@ -0,0 +1,430 @@
+//! Interactive REPL (`splunk-cli repl`).
+//!
+//! Responsibilities:
+//! - Read commands line by line and run them through the normal dispatcher.
+//! - Keep one authenticated client for the whole session.
+//! - Persist command history and offer tab completion.
+//! - Apply session settings (`\set output json`) to every command.
+//!
+//! Does NOT handle:
+//! - Completion candidates (see `complete` submodule).
+//! - Command execution (see `dispatch`).
+//!
+//! Invariants:
+//! - Connection options are fixed when the REPL starts; per-line connection
+//!   flags are ignored with a warning.
+//! - A failing or cancelled command never ends the session.
+//! - Ctrl+C cancels the running command; at the prompt it clears the line.
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Context, Result};
+use clap::parser::ValueSource;
+use clap::{CommandFactory, FromArgMatches};
+use rustyline::error::ReadlineError;
+use rustyline::history::DefaultHistory;
+use rustyline::{Config as EditorConfig, Editor};
+use splunk_config::SearchDefaultConfig;
+use tracing::warn;
+
+use crate::args::{Cli, Commands};
+use crate::cancellation::CancellationToken;
+use crate::config_context::ConfigCommandContext;
+use crate::formatters::OutputFormat;
+
+mod complete;
+
+use complete::{ReplHelper, ResourceNames, SharedNames, spawn_refresh};
+
+const PROMPT: &str = "splunk> ";
+
+/// Global flags that only take effect when the REPL starts.
+const CONNECTION_FLAGS: &[&str] = &[
+    "base_url",
+    "username",
+    "password",
+    "api_token",
+    "timeout",
+    "max_retries",
+    "skip_verify",
+    "profile",
+    "config_path",
+    "no_cache",
+    "app",
+    "owner",
+    "sharing",
+];
+
+const HELP: &str = "\
+Type any splunk-cli command without the `splunk-cli` prefix, e.g. `indexes list`.
+Use `<command> --help` for command details and Tab to complete.
+
+Meta commands:
+  \\set output <FORMAT>     Default output format (table, json, csv, xml, ndjson, yaml, markdown)
+  \\set output-file <FILE>  Write results to FILE instead of stdout
+  \\set quiet on|off        Suppress progress indicators
+  \\unset <SETTING>         Restore a setting to its default
+  \\show                    Show current settings
+  \\refresh                 Refresh completion names from the server
+  \\help                    Show this help
+  \\quit                    Leave the REPL (also `exit`, `quit`, Ctrl+D)
+";
+
+/// Defaults applied to every command unless the line overrides them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+    pub output: String,
+    pub output_file: Option<PathBuf>,
+    pub quiet: bool,
+}
+
+impl Settings {
+    fn describe(&self) -> String {
+        format!(
+            "output = {}\noutput-file = {}\nquiet = {}\n",
+            self.output,
+            self.output_file
+                .as_ref()
+                .map(|path| path.display().to_string())
+                .unwrap_or_else(|| "(stdout)".to_string()),
+            if self.quiet { "on" } else { "off" }
+        )
+    }
+}
+
+/// A REPL line that is handled by the REPL itself rather than dispatched.
+#[derive(Debug, PartialEq, Eq)]
+pub enum MetaCommand {
+    Set(String, String),
+    Unset(String),
+    Show,
+    Refresh,
+    Help,
+    Quit,
+}
+
+/// Parse a meta command, or `None` when the line is a regular command.
+pub fn parse_meta(line: &str) -> Option<Result<MetaCommand>> {
+    let mut words = line.split_whitespace();
+    let head = words.next()?;
+    let rest: Vec<&str> = words.collect();
+
+    let meta = match head {
+        "exit" | "quit" | "\\q" | "\\quit" if rest.is_empty() => MetaCommand::Quit,
+        "help" if rest.is_empty() => MetaCommand::Help,
+        "\\help" | "\\h" | "\\?" => MetaCommand::Help,
+        "\\show" => MetaCommand::Show,
+        "\\refresh" => MetaCommand::Refresh,
+        "\\set" => match rest.as_slice() {
+            [key, value] => MetaCommand::Set(key.to_string(), value.to_string()),
+            _ => return Some(Err(anyhow::anyhow!("Usage: \\set <SETTING> <VALUE>"))),
+        },
+        "\\unset" => match rest.as_slice() {
+            [key] => MetaCommand::Unset(key.to_string()),
+            _ => return Some(Err(anyhow::anyhow!("Usage: \\unset <SETTING>"))),
+        },
+        other if other.starts_with('\\') => {
+            return Some(Err(anyhow::anyhow!(
+                "Unknown meta command: {}. Type \\help for a list.",
+                other
+            )));
+        }
+        _ => return None,
+    };
+    Some(Ok(meta))
+}
+
+/// Apply `\set KEY VALUE` to the session settings.
+pub fn apply_set(settings: &mut Settings, key: &str, value: &str) -> Result<()> {
+    match key {
+        "output" | "o" => {
+            OutputFormat::from_str(value)?;
+            settings.output = value.to_lowercase();
+        }
+        "output-file" => settings.output_file = Some(PathBuf::from(value)),
+        "quiet" => {
+            settings.quiet = match value {
+                "on" | "true" | "1" => true,
+                "off" | "false" | "0" => false,
+                _ => anyhow::bail!("Invalid value for quiet: {}. Use on or off.", value),
+            }
+        }
+        _ => anyhow::bail!(
+            "Unknown setting: {}. Settings: output, output-file, quiet",
+            key
+        ),
+    }
+    Ok(())
+}
+
+/// Parse a command line into `Cli`, filling in session settings the line does not set.
+///
+/// Returns the parsed command and the connection flags the line tried to change.
+pub fn parse_line(
+    tokens: &[String],
+    settings: &Settings,
+) -> std::result::Result<(Cli, Vec<&'static str>), clap::Error> {
+    let argv = std::iter::once("splunk-cli".to_string()).chain(tokens.iter().cloned());
+    let matches = Cli::command().try_get_matches_from(argv)?;
+    let mut cli = Cli::from_arg_matches(&matches)?;
+
+    let from_line = |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
+    if !from_line("output") {
+        cli.output = settings.output.clone();
+    }
+    if cli.output_file.is_none() {
+        cli.output_file = settings.output_file.clone();
+    }
+    cli.quiet |= settings.quiet;
+
+    let ignored = CONNECTION_FLAGS
+        .iter()
+        .copied()
+        .filter(|id| from_line(id))
+        .collect();
+    Ok((cli, ignored))
+}
+
+/// Run the REPL until the user quits.
+pub async fn run(
+    config: splunk_config::Config,
+    search_defaults: SearchDefaultConfig,
+    output: &str,
+    output_file: Option<PathBuf>,
+    quiet: bool,
+    no_cache: bool,
+) -> Result<()> {
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+    crate::commands::install_shared_client(client.clone());
+
+    // Log in once before anything runs concurrently, so the completion refresh
+    // and the first command share one session instead of each logging in
+    if !client.is_api_token_auth()
+        && let Err(e) = client.login().await
+    {
+        warn!("Login failed; commands will retry: {}", e);
+    }
+
+    let names: SharedNames = Arc::new(Mutex::new(ResourceNames::default()));
+    spawn_refresh(client.clone(), names.clone());
+
+    let mut editor: Editor<ReplHelper, DefaultHistory> =
+        Editor::with_config(EditorConfig::builder().auto_add_history(true).build())
+            .context("Failed to initialize line editor")?;
+    editor.set_helper(Some(ReplHelper::new(names.clone())));
+    let history = history_path();
+    if let Some(path) = &history {
+        // A missing history file is expected on first use
+        let _ = editor.load_history(path);
+    }
+
+    // Ctrl+C cancels whichever command is running; the prompt handles its own
+    let current: Arc<Mutex<Option<CancellationToken>>> = Arc::new(Mutex::new(None));
+    let signal_current = current.clone();
+    tokio::spawn(async move {
+        while tokio::signal::ctrl_c().await.is_ok() {
+            if let Some(token) = signal_current
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .as_ref()
+            {
+                token.cancel();
+            }
+        }
+    });
+
+    let mut settings = Settings {
+        output: output.to_string(),
+        output_file,
+        quiet,
+    };
+    eprintln!(
+        "Connected to {}. Type \\help for help.",
+        config.connection.base_url
+    );
+
+    loop {
+        let line = match tokio::task::block_in_place(|| editor.readline(PROMPT)) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(e) => return Err(e).context("Failed to read input"),
+        };
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        match parse_meta(line) {
+            Some(Ok(MetaCommand::Quit)) => break,
+            Some(Ok(meta)) => {
+                if let Err(e) = run_meta(meta, &mut settings, &client, &names) {
+                    eprintln!("{:#}", e);
+                }
+                continue;
+            }
+            Some(Err(e)) => {
+                eprintln!("{:#}", e);
+                continue;
+            }
+            None => {}
+        }
+
+        let Some(tokens) = shlex::split(line) else {
+            eprintln!("Unbalanced quotes in: {}", line);
+            continue;
+        };
+        let mut cli = match parse_line(&tokens, &settings) {
+            Ok((cli, ignored)) => {
+                for flag in ignored {
+                    warn!(
+                        "--{} only takes effect when the REPL starts; ignoring it",
+                        flag.replace('_', "-")
+                    );
+                }
+                cli
+            }
+            Err(e) => {
+                let _ = e.print();
+                continue;
+            }
+        };
+        if matches!(cli.command, Commands::Repl | Commands::Tui { .. }) {
+            eprintln!("That command cannot be run inside the REPL.");
+            continue;
+        }
+        if cli.get.is_some() {
+            eprintln!("--get is not supported in the REPL; use `\\set output json` instead.");
+            continue;
+        }
+        let (fields, query) = (cli.fields.take(), cli.query.take());
+        if let Err(e) = crate::formatters::configure_output_filter(&mut cli.output, fields, query) {
+            eprintln!("{:#}", e);
+            continue;
+        }
+
+        let error_format = cli.error_format;
+        let token = CancellationToken::new();
+        *current.lock().unwrap_or_else(|e| e.into_inner()) = Some(token.clone());
+        let context =
+            ConfigCommandContext::Real(Box::new(config.clone()), search_defaults.clone(), no_cache);
+        let result = Box::pin(crate::dispatch::run_command(cli, context, &token)).await;
+        *current.lock().unwrap_or_else(|e| e.into_inner()) = None;
+
+        if let Err(e) = result {
+            crate::error::report_error(&e, error_format);
+        }
+
+        if names.lock().unwrap_or_else(|e| e.into_inner()).is_stale() {
+            spawn_refresh(client.clone(), names.clone());
+        }
+    }
+
+    if let Some(path) = &history
+        && let Err(e) = save_history(&mut editor, path)
+    {
+        warn!("Failed to save REPL history: {:#}", e);
+    }
+    Ok(())
+}
+
+fn run_meta(
+    meta: MetaCommand,
+    settings: &mut Settings,
+    client: &Arc<splunk_client::SplunkClient>,
+    names: &SharedNames,
+) -> Result<()> {
+    match meta {
+        MetaCommand::Set(key, value) => apply_set(settings, &key, &value)?,
+        MetaCommand::Unset(key) => match key.as_str() {
+            "output" | "o" => settings.output = "table".to_string(),
+            "output-file" => settings.output_file = None,
+            "quiet" => settings.quiet = false,
+            _ => anyhow::bail!("Unknown setting: {}", key),
+        },
+        MetaCommand::Show => print!("{}", settings.describe()),
+        MetaCommand::Refresh => spawn_refresh(client.clone(), names.clone()),
+        MetaCommand::Help => print!("{}", HELP),
+        MetaCommand::Quit => {}
+    }
+    Ok(())
+}
+
+/// `<config dir>/splunk-tui/repl_history`, when a home directory is known.
+fn history_path() -> Option<PathBuf> {
+    directories::ProjectDirs::from("", "", "splunk-tui")
+        .map(|dirs| dirs.config_dir().join("repl_history"))
+}
+
+fn save_history(editor: &mut Editor<ReplHelper, DefaultHistory>, path: &PathBuf) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    editor
+        .save_history(path)
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> Settings {
+        Settings {
+            output: "table".to_string(),
+            output_file: None,
+            quiet: false,
+        }
+    }
+
+    fn tokens(line: &str) -> Vec<String> {
+        shlex::split(line).unwrap()
+    }
+
+    #[test]
+    fn test_parse_meta_commands() {
+        assert_eq!(
+            parse_meta("\\set output json").unwrap().unwrap(),
+            MetaCommand::Set("output".into(), "json".into())
+        );
+        assert_eq!(parse_meta("exit").unwrap().unwrap(), MetaCommand::Quit);
+        assert!(parse_meta("\\set output").unwrap().is_err());
+        assert!(parse_meta("\\frobnicate").unwrap().is_err());
+        assert!(parse_meta("indexes list").is_none());
+        // `help` with arguments is clap's help subcommand
+        assert!(parse_meta("help indexes").is_none());
+    }
+
+    #[test]
+    fn test_apply_set_validates_values() {
+        let mut settings = settings();
+        apply_set(&mut settings, "output", "JSON").unwrap();
+        assert_eq!(settings.output, "json");
+        assert!(apply_set(&mut settings, "output", "html").is_err());
+        assert!(apply_set(&mut settings, "color", "on").is_err());
+    }
+
+    #[test]
+    fn test_parse_line_applies_session_defaults_unless_overridden() {
+        let mut session = settings();
+        apply_set(&mut session, "output", "json").unwrap();
+
+        let (cli, _) = parse_line(&tokens("indexes list"), &session).unwrap();
+        assert_eq!(cli.output, "json");
+
+        let (cli, _) = parse_line(&tokens("indexes list -o csv"), &session).unwrap();
+        assert_eq!(cli.output, "csv");
+    }
+
+    #[test]
+    fn test_parse_line_reports_ignored_connection_flags() {
+        let (_, ignored) = parse_line(
+            &tokens("--profile prod indexes list --skip-verify"),
+            &settings(),
+        )
+        .unwrap();
+        assert_eq!(ignored, vec!["skip_verify", "profile"]);
+    }
+}
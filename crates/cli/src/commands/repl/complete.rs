@@ -0,0 +1,284 @@
+//! Tab completion for the REPL.
+//!
+//! Responsibilities:
+//! - Complete subcommands and long flags by walking the clap command tree.
+//! - Complete index, saved search, job, and app names from a snapshot of the server.
+//! - Refresh that snapshot in the background through the shared client.
+//!
+//! Does NOT handle:
+//! - Line editing or history (rustyline).
+//!
+//! Invariants:
+//! - Completion never blocks on the network; it only reads the last snapshot.
+//! - A failed refresh keeps the previous snapshot.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use clap::{Command, CommandFactory};
+use rustyline::Context;
+use rustyline::completion::{Completer, Pair};
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use splunk_client::SplunkClient;
+use tracing::debug;
+
+use crate::args::Cli;
+
+/// How long a snapshot of resource names is used before it is refreshed.
+const SNAPSHOT_TTL: Duration = Duration::from_secs(60);
+
+/// Meta commands offered when a line starts with a backslash.
+const META_COMMANDS: &[&str] = &[
+    "\\set",
+    "\\unset",
+    "\\show",
+    "\\refresh",
+    "\\help",
+    "\\quit",
+];
+
+/// Resource names offered as argument completions.
+#[derive(Debug, Default, Clone)]
+pub struct ResourceNames {
+    pub indexes: Vec<String>,
+    pub saved_searches: Vec<String>,
+    pub jobs: Vec<String>,
+    pub apps: Vec<String>,
+    fetched_at: Option<Instant>,
+}
+
+impl ResourceNames {
+    /// True when the snapshot was never fetched or is older than the TTL.
+    pub fn is_stale(&self) -> bool {
+        self.fetched_at
+            .is_none_or(|fetched_at| fetched_at.elapsed() > SNAPSHOT_TTL)
+    }
+}
+
+/// Shared, refreshable snapshot of resource names.
+pub type SharedNames = Arc<Mutex<ResourceNames>>;
+
+/// Fetch resource names in the background and store them in `names`.
+///
+/// Each list is fetched independently so one forbidden endpoint does not
+/// blank out the others.
+pub fn spawn_refresh(client: Arc<SplunkClient>, names: SharedNames) {
+    tokio::spawn(async move {
+        let (indexes, saved_searches, jobs, apps) = tokio::join!(
+            client.list_indexes(Some(100), None),
+            client.list_saved_searches(Some(100), None, None),
+            client.list_jobs(Some(50), None),
+            client.list_apps(Some(100), None),
+        );
+
+        let mut names = names.lock().unwrap_or_else(|e| e.into_inner());
+        match indexes {
+            Ok(list) => names.indexes = list.into_iter().map(|i| i.name).collect(),
+            Err(e) => debug!("Failed to refresh index completions: {}", e),
+        }
+        match saved_searches {
+            Ok(list) => names.saved_searches = list.into_iter().map(|s| s.name).collect(),
+            Err(e) => debug!("Failed to refresh saved search completions: {}", e),
+        }
+        match jobs {
+            Ok(list) => names.jobs = list.into_iter().map(|j| j.sid).collect(),
+            Err(e) => debug!("Failed to refresh job completions: {}", e),
+        }
+        match apps {
+            Ok(list) => names.apps = list.into_iter().map(|a| a.name).collect(),
+            Err(e) => debug!("Failed to refresh app completions: {}", e),
+        }
+        names.fetched_at = Some(Instant::now());
+    });
+}
+
+/// rustyline helper that completes REPL lines.
+pub struct ReplHelper {
+    command: Command,
+    names: SharedNames,
+}
+
+impl ReplHelper {
+    pub fn new(names: SharedNames) -> Self {
+        let mut command = Cli::command();
+        // Propagates global flags such as `--output` to every subcommand
+        command.build();
+        Self { command, names }
+    }
+}
+
+impl Completer for ReplHelper {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let head = &line[..pos];
+        let start = head.rfind(char::is_whitespace).map(|i| i + 1).unwrap_or(0);
+        let before: Vec<String> = shlex::split(&head[..start])
+            .unwrap_or_else(|| head[..start].split_whitespace().map(String::from).collect());
+
+        let names = self.names.lock().unwrap_or_else(|e| e.into_inner());
+        let candidates = candidates(&self.command, &names, &before, &head[start..]);
+        Ok((
+            start,
+            candidates
+                .into_iter()
+                .map(|candidate| Pair {
+                    display: candidate.clone(),
+                    replacement: candidate,
+                })
+                .collect(),
+        ))
+    }
+}
+
+impl Hinter for ReplHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ReplHelper {}
+
+impl Validator for ReplHelper {}
+
+impl rustyline::Helper for ReplHelper {}
+
+/// Completion candidates for `prefix`, given the words before it.
+pub fn candidates(
+    root: &Command,
+    names: &ResourceNames,
+    before: &[String],
+    prefix: &str,
+) -> Vec<String> {
+    if before.is_empty() && prefix.starts_with('\\') {
+        return filter(META_COMMANDS.iter().map(|m| m.to_string()), prefix);
+    }
+
+    // Descend through the subcommands named so far
+    let mut command = root;
+    let mut path: Vec<&str> = Vec::new();
+    for word in before {
+        if word.starts_with('-') {
+            continue;
+        }
+        if let Some(sub) = command.find_subcommand(word) {
+            command = sub;
+            path.push(sub.get_name());
+        }
+    }
+
+    if prefix.starts_with('-') {
+        let flags = command
+            .get_arguments()
+            .filter(|arg| !arg.is_hide_set())
+            .filter_map(|arg| arg.get_long())
+            .map(|long| format!("--{}", long));
+        return filter(flags, prefix);
+    }
+
+    match before.last().map(String::as_str) {
+        Some("--index" | "-i") => return filter(names.indexes.iter().cloned(), prefix),
+        Some("--inspect" | "--cancel" | "--delete" | "--results") => {
+            return filter(names.jobs.iter().cloned(), prefix);
+        }
+        Some("--app") => return filter(names.apps.iter().cloned(), prefix),
+        _ => {}
+    }
+
+    if command.has_subcommands() {
+        let subcommands = command
+            .get_subcommands()
+            .filter(|sub| !sub.is_hide_set())
+            .map(|sub| sub.get_name().to_string());
+        return filter(subcommands, prefix);
+    }
+
+    let resources = match path.first() {
+        Some(&"indexes") => &names.indexes,
+        Some(&"saved-searches") => &names.saved_searches,
+        Some(&"jobs") => &names.jobs,
+        Some(&"apps") => &names.apps,
+        _ => return Vec::new(),
+    };
+    filter(resources.iter().cloned(), prefix)
+}
+
+fn filter(values: impl Iterator<Item = String>, prefix: &str) -> Vec<String> {
+    let mut matches: Vec<String> = values.filter(|v| v.starts_with(prefix)).collect();
+    matches.sort();
+    matches.dedup();
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root() -> Command {
+        let mut command = Cli::command();
+        command.build();
+        command
+    }
+
+    fn names() -> ResourceNames {
+        ResourceNames {
+            indexes: vec!["main".into(), "web".into(), "wineventlog".into()],
+            jobs: vec!["1700000000.1".into()],
+            ..ResourceNames::default()
+        }
+    }
+
+    fn words(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_completes_top_level_and_nested_subcommands() {
+        let top = candidates(&root(), &names(), &[], "ind");
+        assert_eq!(top, vec!["indexes"]);
+
+        let nested = candidates(&root(), &names(), &words("indexes"), "");
+        assert!(nested.contains(&"list".to_string()));
+        assert!(!nested.contains(&"indexes".to_string()));
+    }
+
+    #[test]
+    fn test_completes_global_and_command_flags() {
+        let flags = candidates(&root(), &names(), &words("jobs list"), "--o");
+        assert!(flags.contains(&"--output".to_string()));
+        assert!(flags.contains(&"--output-file".to_string()));
+    }
+
+    #[test]
+    fn test_completes_resource_names_by_context() {
+        assert_eq!(
+            candidates(&root(), &names(), &words("indexes delete"), "w"),
+            vec!["web", "wineventlog"]
+        );
+        assert_eq!(
+            candidates(&root(), &names(), &words("search --index"), "m"),
+            vec!["main"]
+        );
+        assert_eq!(
+            candidates(&root(), &names(), &words("jobs cancel"), ""),
+            vec!["1700000000.1"]
+        );
+        assert_eq!(
+            candidates(&root(), &names(), &words("jobs --inspect"), ""),
+            vec!["1700000000.1"]
+        );
+    }
+
+    #[test]
+    fn test_completes_meta_commands() {
+        assert_eq!(
+            candidates(&root(), &names(), &[], "\\s"),
+            vec!["\\set", "\\show"]
+        );
+    }
+}
@@ -0,0 +1,102 @@
+//! Raw REST command implementation.
+//!
+//! Responsibilities:
+//! - Send an authenticated GET or POST to any REST path (`splunk-cli rest`)
+//! - Print the response pretty-printed, or re-encoded for JSON/NDJSON/YAML
+//!
+//! Does NOT handle:
+//! - Direct HTTP calls, auth, or retries (handled by client crate)
+//! - Interpreting the response structure
+//!
+//! Invariants:
+//! - Form data (`--data`) is only accepted with POST
+
+use anyhow::{Result, bail};
+use splunk_client::RestMethod;
+use splunk_client::endpoints::split_path_query;
+use splunk_client::raw_format::pretty_response;
+use tracing::info;
+
+use crate::formatters::{OutputFormat, output_result};
+
+/// Parse a `KEY=VALUE` form field for `--data`.
+pub fn parse_form_field(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+        _ => Err(format!("expected KEY=VALUE, got '{}'", s)),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn run(
+    config: splunk_config::Config,
+    method: RestMethod,
+    path: String,
+    data: Vec<(String, String)>,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    if method == RestMethod::Get && !data.is_empty() {
+        bail!("--data is only sent with POST requests");
+    }
+
+    info!("Sending raw {} request to {}", method, path);
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let (path, query) = split_path_query(&path);
+    let body = cancellable!(client.raw_request(method, &path, &query, &data), cancel)?;
+
+    let output = format_response(&body, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+    Ok(())
+}
+
+/// Format a raw response body based on the selected format.
+///
+/// JSON, NDJSON, and YAML re-encode the body; every other format prints it
+/// pretty-printed as returned (JSON or XML).
+pub fn format_response(body: &serde_json::Value, format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(body)?),
+        OutputFormat::Ndjson => Ok(format!("{}\n", serde_json::to_string(body)?)),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(body)?),
+        OutputFormat::Table | OutputFormat::Csv | OutputFormat::Xml | OutputFormat::Markdown => {
+            let (_, pretty) = pretty_response(body);
+            Ok(pretty)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_form_field() {
+        assert_eq!(
+            parse_form_field("search=index=main | head 5"),
+            Ok(("search".to_string(), "index=main | head 5".to_string()))
+        );
+        assert_eq!(
+            parse_form_field("disabled="),
+            Ok(("disabled".to_string(), String::new()))
+        );
+        assert!(parse_form_field("name").is_err());
+        assert!(parse_form_field("=value").is_err());
+    }
+
+    #[test]
+    fn test_format_response_pretty_prints_xml_body() {
+        let body = serde_json::Value::String("<feed><entry>x</entry></feed>".to_string());
+        let output = format_response(&body, OutputFormat::Table).unwrap();
+        assert!(output.contains("<feed>\n"), "{}", output);
+
+        let body = serde_json::json!({"entry": []});
+        let output = format_response(&body, OutputFormat::Ndjson).unwrap();
+        assert_eq!(output, "{\"entry\":[]}\n");
+    }
+}
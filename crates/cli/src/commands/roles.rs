@@ -3,6 +3,8 @@
 //! Responsibilities:
 //! - List roles with optional count limiting
 //! - List available capabilities
+//! - Show per-role search quotas against current usage by the role's users
+//! - Show the roles × capabilities matrix, including inherited capabilities
 //! - Create new roles with assigned capabilities and settings
 //! - Modify existing role properties
 //! - Delete roles with confirmation
@@ -23,7 +25,7 @@ use anyhow::Result;
 use clap::Subcommand;
 use tracing::info;
 
-use crate::formatters::{OutputFormat, get_formatter, output_result};
+use crate::formatters::{OutputFormat, get_formatter, output_result, render_rows};
 use splunk_config::constants::*;
 
 #[derive(Debug, Subcommand)]
@@ -33,9 +35,30 @@ pub enum RolesCommand {
         /// Maximum number of roles to list
         #[arg(short, long, default_value_t = DEFAULT_LIST_PAGE_SIZE)]
         count: usize,
+        /// Fetch every page instead of stopping at --count
+        #[arg(long, conflicts_with = "count")]
+        all: bool,
     },
     /// List all available capabilities
     Capabilities,
+    /// Show per-role search quotas next to current usage by each role's users
+    Usage {
+        /// Only show this role
+        #[arg(short, long)]
+        role: Option<String>,
+        /// Only show roles with at least one user at or over a per-user quota
+        #[arg(long)]
+        at_limit: bool,
+    },
+    /// Show which roles hold which capabilities, directly or through imported roles
+    Matrix {
+        /// Only show these roles (comma-separated)
+        #[arg(short, long, value_delimiter = ',')]
+        role: Vec<String>,
+        /// Only show capabilities containing this text
+        #[arg(short, long)]
+        capability: Option<String>,
+    },
     /// Create a new role
     Create {
         /// Role name (required)
@@ -95,12 +118,45 @@ pub async fn run(
     no_cache: bool,
 ) -> Result<()> {
     match command {
-        RolesCommand::List { count } => {
-            run_list(config, count, output_format, output_file, cancel, no_cache).await
+        RolesCommand::List { count, all } => {
+            run_list(
+                config,
+                count,
+                all,
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
         }
         RolesCommand::Capabilities => {
             run_capabilities(config, output_format, output_file, cancel, no_cache).await
         }
+        RolesCommand::Usage { role, at_limit } => {
+            run_usage(
+                config,
+                role,
+                at_limit,
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
+        RolesCommand::Matrix { role, capability } => {
+            run_matrix(
+                config,
+                role,
+                capability,
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
         RolesCommand::Create {
             name,
             capabilities,
@@ -152,6 +208,7 @@ pub async fn run(
 async fn run_list(
     config: splunk_config::Config,
     count: usize,
+    all: bool,
     output_format: &str,
     output_file: Option<std::path::PathBuf>,
     cancel: &crate::cancellation::CancellationToken,
@@ -161,7 +218,16 @@ async fn run_list(
 
     let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
 
-    let roles = cancellable!(client.list_roles(Some(count), None), cancel)?;
+    let roles = if all {
+        cancellable!(
+            crate::commands::fetch_all_pages(
+                |count, offset| client.list_roles(Some(count), Some(offset))
+            ),
+            cancel
+        )?
+    } else {
+        cancellable!(client.list_roles(Some(count), None), cancel)?
+    };
 
     let format = OutputFormat::from_str(output_format)?;
     let formatter = get_formatter(format);
@@ -194,6 +260,77 @@ async fn run_capabilities(
     Ok(())
 }
 
+async fn run_usage(
+    config: splunk_config::Config,
+    role: Option<String>,
+    at_limit: bool,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!(
+        "Fetching role quota usage (role: {:?}, at_limit: {})",
+        role, at_limit
+    );
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let mut usage = cancellable!(client.role_usage(), cancel)?;
+    if let Some(ref role) = role {
+        usage.retain(|u| &u.role == role);
+    }
+    if at_limit {
+        usage.retain(|u| u.users_at_limit > 0);
+    }
+
+    let formatter = get_formatter(format);
+    let output = formatter.format_role_usage(&usage)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+async fn run_matrix(
+    config: splunk_config::Config,
+    roles: Vec<String>,
+    capability: Option<String>,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!(
+        "Building role capability matrix (roles: {:?}, capability: {:?})",
+        roles, capability
+    );
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let mut matrix = cancellable!(client.role_capability_matrix(), cancel)?;
+    if !roles.is_empty() {
+        if let Some(unknown) = roles.iter().find(|r| !matrix.roles.contains(r)) {
+            return Err(splunk_client::ClientError::NotFound(format!("role '{}'", unknown)).into());
+        }
+        matrix.retain_roles(&roles);
+    }
+    if let Some(ref pattern) = capability {
+        let pattern = pattern.to_lowercase();
+        matrix.retain_capabilities(|cap| cap.to_lowercase().contains(&pattern));
+    }
+
+    // One row per capability, one column per role
+    let columns: Vec<String> = std::iter::once("capability".to_string())
+        .chain(matrix.roles.iter().cloned())
+        .collect();
+    let output = render_rows(matrix.to_rows(), &columns, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn run_create(
     config: splunk_config::Config,
@@ -4,11 +4,15 @@
 //! - List saved searches with optional count limiting
 //! - Show detailed information about specific saved searches
 //! - Execute saved searches with optional time bounds
-//! - Edit saved search properties (search query, description, disabled status)
+//! - Edit saved search properties (search query, description, disabled status,
+//!   scheduled dispatch window)
+//! - Bulk-reassign saved search ownership (see `reassign`)
+//! - Bulk enable/disable/reassign selected saved searches (see `bulk`)
+//! - View and change saved search permissions (see `acl`)
 //! - Format output via shared formatters
 //!
 //! Does NOT handle:
-//! - Saved search scheduling or alerting configuration
+//! - Saved search cron schedules or alerting configuration
 //! - Direct REST API calls (handled by client crate)
 //! - Output formatting details (see formatters module)
 //!
@@ -21,11 +25,15 @@
 use anyhow::{Context, Result};
 use clap::Subcommand;
 use splunk_client::SearchRequest;
+use splunk_client::models::KnowledgeObjectKind;
 use splunk_config::constants::*;
 use tracing::info;
 
 use crate::formatters::{OutputFormat, get_formatter, output_result};
 
+mod bulk;
+mod reassign;
+
 #[derive(Subcommand)]
 pub enum SavedSearchesCommand {
     /// List saved searches
@@ -49,10 +57,10 @@ pub enum SavedSearchesCommand {
         #[arg(long)]
         wait: bool,
         /// Earliest time for the search (e.g., '-24h', '2024-01-01T00:00:00')
-        #[arg(short, long, allow_hyphen_values = true)]
+        #[arg(short, long, allow_hyphen_values = true, value_parser = crate::commands::search::parse_time_modifier)]
         earliest: Option<String>,
         /// Latest time for the search (e.g., 'now', '2024-01-02T00:00:00')
-        #[arg(short, long, allow_hyphen_values = true)]
+        #[arg(short, long, allow_hyphen_values = true, value_parser = crate::commands::search::parse_time_modifier)]
         latest: Option<String>,
         /// Maximum number of results to return
         #[arg(short, long, default_value_t = DEFAULT_MAX_RESULTS)]
@@ -72,6 +80,12 @@ pub enum SavedSearchesCommand {
         /// Enable/disable the saved search
         #[arg(long)]
         disabled: Option<bool>,
+        /// Earliest time of the scheduled dispatch window (e.g., '-1h@h')
+        #[arg(long, value_name = "TIME", allow_hyphen_values = true, value_parser = crate::commands::search::parse_time_modifier)]
+        dispatch_earliest: Option<String>,
+        /// Latest time of the scheduled dispatch window (e.g., '@h')
+        #[arg(long, value_name = "TIME", allow_hyphen_values = true, value_parser = crate::commands::search::parse_time_modifier)]
+        dispatch_latest: Option<String>,
     },
     /// Create a new saved search
     Create {
@@ -109,6 +123,61 @@ pub enum SavedSearchesCommand {
         #[arg(value_name = "NAME")]
         name: String,
     },
+    /// Reassign ownership of saved searches from one user to another
+    Reassign {
+        /// Current owner of the saved searches (e.g., a departed user)
+        #[arg(long, value_name = "USER")]
+        from_owner: String,
+        /// New owner of the saved searches (e.g., a service account)
+        #[arg(long, value_name = "USER")]
+        to_owner: String,
+        /// Only reassign saved searches in this app
+        #[arg(long)]
+        app: Option<String>,
+        /// Also change sharing (user, app, global); keeps current sharing if omitted
+        #[arg(long, value_parser = ["user", "app", "global"])]
+        sharing: Option<String>,
+        /// Show what would change without modifying anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Enable, disable, or reassign many saved searches at once
+    #[command(group(
+        clap::ArgGroup::new("bulk_action")
+            .required(true)
+            .args(["enable", "disable", "reassign_to"])
+    ))]
+    Bulk {
+        /// Only these saved searches (all searches matching --owner/--app when omitted)
+        #[arg(value_name = "NAME")]
+        names: Vec<String>,
+        /// Only saved searches owned by this user
+        #[arg(long, value_name = "USER")]
+        owner: Option<String>,
+        /// Only saved searches in this app
+        #[arg(long)]
+        app: Option<String>,
+        /// Enable the selected saved searches
+        #[arg(long)]
+        enable: bool,
+        /// Disable the selected saved searches
+        #[arg(long)]
+        disable: bool,
+        /// Reassign the selected saved searches to this user (as `saved-searches reassign`)
+        #[arg(long, value_name = "USER")]
+        reassign_to: Option<String>,
+        /// Also change sharing when reassigning (user, app, global)
+        #[arg(long, requires = "reassign_to", value_parser = ["user", "app", "global"])]
+        sharing: Option<String>,
+        /// Show what would change without modifying anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// View or change permissions (owner, sharing, read/write roles)
+    Acl {
+        #[command(subcommand)]
+        command: crate::commands::acl::AclCommand,
+    },
 }
 
 pub async fn run(
@@ -168,17 +237,17 @@ pub async fn run(
             search,
             description,
             disabled,
+            dispatch_earliest,
+            dispatch_latest,
         } => {
-            run_edit(
-                config,
-                &name,
-                search.as_deref(),
-                description.as_deref(),
+            let params = splunk_client::models::SavedSearchUpdateParams {
+                search,
+                description,
                 disabled,
-                cancel,
-                no_cache,
-            )
-            .await
+                dispatch_earliest_time: dispatch_earliest,
+                dispatch_latest_time: dispatch_latest,
+            };
+            run_edit(config, &name, params, cancel, no_cache).await
         }
         SavedSearchesCommand::Create {
             name,
@@ -206,6 +275,100 @@ pub async fn run(
         SavedSearchesCommand::Disable { name } => {
             run_enable_disable(config, &name, true, cancel, no_cache).await
         }
+        SavedSearchesCommand::Reassign {
+            from_owner,
+            to_owner,
+            app,
+            sharing,
+            dry_run,
+        } => {
+            let request =
+                splunk_client::workflows::saved_search_ownership::ReassignSavedSearchesRequest {
+                    from_owner: Some(from_owner),
+                    to_owner,
+                    app,
+                    names: Vec::new(),
+                    sharing,
+                    dry_run,
+                };
+            reassign::run_reassign(
+                config,
+                request,
+                output_format,
+                output_file.clone(),
+                cancel,
+                no_cache,
+            )
+            .await
+        }
+        SavedSearchesCommand::Bulk {
+            names,
+            owner,
+            app,
+            enable,
+            disable: _,
+            reassign_to,
+            sharing,
+            dry_run,
+        } => {
+            use splunk_client::workflows::saved_search_bulk::{
+                SavedSearchBulkAction, SavedSearchBulkRequest,
+            };
+            if let Some(to_owner) = reassign_to {
+                // Same workflow and report as `saved-searches reassign`.
+                let request =
+                    splunk_client::workflows::saved_search_ownership::ReassignSavedSearchesRequest {
+                        from_owner: owner,
+                        to_owner,
+                        app,
+                        names,
+                        sharing,
+                        dry_run,
+                    };
+                return reassign::run_reassign(
+                    config,
+                    request,
+                    output_format,
+                    output_file.clone(),
+                    cancel,
+                    no_cache,
+                )
+                .await;
+            }
+            let action = if enable {
+                SavedSearchBulkAction::Enable
+            } else {
+                SavedSearchBulkAction::Disable
+            };
+            let request = SavedSearchBulkRequest {
+                owner,
+                app,
+                names,
+                action,
+                dry_run,
+            };
+            bulk::run_bulk(
+                config,
+                request,
+                output_format,
+                output_file.clone(),
+                cancel,
+                no_cache,
+            )
+            .await
+        }
+        SavedSearchesCommand::Acl { command } => {
+            crate::commands::acl::run(
+                config,
+                KnowledgeObjectKind::SavedSearch,
+                command,
+                output_format,
+                output_file.clone(),
+                cancel,
+                no_cache,
+            )
+            .await
+        }
     }
 }
 
@@ -221,7 +384,7 @@ async fn run_list(
 
     let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
 
-    let searches = cancellable!(client.list_saved_searches(Some(count), None), cancel)?;
+    let searches = cancellable!(client.list_saved_searches(Some(count), None, None), cancel)?;
 
     let format = OutputFormat::from_str(output_format)?;
     let formatter = get_formatter(format);
@@ -289,29 +452,26 @@ async fn run_run(
 async fn run_edit(
     config: splunk_config::Config,
     name: &str,
-    search: Option<&str>,
-    description: Option<&str>,
-    disabled: Option<bool>,
+    params: splunk_client::models::SavedSearchUpdateParams,
     cancel: &crate::cancellation::CancellationToken,
     no_cache: bool,
 ) -> Result<()> {
     info!("Editing saved search: {}", name);
 
     // Validate at least one field is provided
-    if search.is_none() && description.is_none() && disabled.is_none() {
+    if params.search.is_none()
+        && params.description.is_none()
+        && params.disabled.is_none()
+        && params.dispatch_earliest_time.is_none()
+        && params.dispatch_latest_time.is_none()
+    {
         return Err(anyhow::anyhow!(
-            "At least one field must be provided to update (--search, --description, or --disabled)"
+            "At least one field must be provided to update (--search, --description, --disabled, --dispatch-earliest, or --dispatch-latest)"
         ));
     }
 
     let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
 
-    let params = splunk_client::models::SavedSearchUpdateParams {
-        search: search.map(|s| s.to_string()),
-        description: description.map(|s| s.to_string()),
-        disabled,
-    };
-
     cancellable_with!(client.update_saved_search(name, params), cancel, |_res| {
         eprintln!("Saved search '{}' updated successfully", name);
         Ok(())
@@ -0,0 +1,92 @@
+//! Bulk enable/disable for the saved-searches command.
+//!
+//! Responsibilities:
+//! - Run the shared bulk workflow over saved searches selected by owner, app, or name.
+//! - Print the per-search outcome in every output format.
+//!
+//! Does NOT handle:
+//! - Selection or state updates (lives in `splunk-client::workflows::saved_search_bulk`).
+//! - `--reassign-to`, which runs the `reassign` command.
+//!
+//! Invariants:
+//! - `--dry-run` never issues write requests.
+//! - The command fails when any selected saved search failed, after printing the report.
+
+use anyhow::Result;
+use splunk_client::workflows::saved_search_bulk::{
+    BulkItemStatus, SavedSearchBulkRequest, bulk_update_saved_searches,
+};
+use tracing::info;
+
+use crate::formatters::{OutputFormat, output_result, render_rows};
+
+/// Report columns, in display order.
+const COLUMNS: [&str; 6] = ["name", "app", "owner", "disabled", "status", "error"];
+
+pub(super) async fn run_bulk(
+    config: splunk_config::Config,
+    request: SavedSearchBulkRequest,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!(
+        "Bulk {} of saved searches (owner: {:?}, app: {:?}, names: {}, dry_run: {})",
+        request.action.slug(),
+        request.owner,
+        request.app,
+        request.names.len(),
+        request.dry_run
+    );
+
+    request
+        .validate()
+        .map_err(|e| crate::error::UsageError(format!("{e:#}")))?;
+    let format = OutputFormat::from_str(output_format)?;
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let report = cancellable!(
+        bulk_update_saved_searches(&client, &request, Some(cancel)),
+        cancel
+    )?;
+
+    let rows = report
+        .items
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<serde_json::Result<Vec<_>>>()?;
+    let columns: Vec<String> = COLUMNS.iter().map(|c| c.to_string()).collect();
+    let output = render_rows(rows, &columns, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    let changed = if report.dry_run {
+        report.count(BulkItemStatus::Planned)
+    } else {
+        report.count(BulkItemStatus::Applied)
+    };
+    eprintln!(
+        "{} {} saved search(es) ({} unchanged, {} failed)",
+        if report.dry_run {
+            format!("Would {}", report.action)
+        } else {
+            format!("Applied {} to", report.action)
+        },
+        changed,
+        report.count(BulkItemStatus::Unchanged),
+        report.failed_count()
+    );
+
+    let failed = report.failed_count();
+    if failed > 0 {
+        anyhow::bail!(
+            "Failed to {} {} of {} saved search(es)",
+            report.action,
+            failed,
+            report.items.len()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,297 @@
+//! Saved search ownership reassignment for the saved-searches command.
+//!
+//! Responsibilities:
+//! - Run the shared reassignment workflow for offboarding-style owner changes.
+//! - Format the reassignment report (including schedule impact) in every output format.
+//!
+//! Does NOT handle:
+//! - Discovery or ACL updates (lives in `splunk-client::workflows::saved_search_ownership`).
+//!
+//! Invariants:
+//! - `--dry-run` never issues write requests.
+//! - The command fails when any individual reassignment fails, after printing the report.
+
+use anyhow::Result;
+use splunk_client::workflows::saved_search_ownership::{
+    ReassignSavedSearchesRequest, SavedSearchReassignReport, reassign_saved_searches,
+};
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+pub(super) async fn run_reassign(
+    config: splunk_config::Config,
+    request: ReassignSavedSearchesRequest,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!(
+        "Reassigning saved searches from {:?} to '{}' (names: {}, dry_run: {})",
+        request.from_owner,
+        request.to_owner,
+        request.names.len(),
+        request.dry_run
+    );
+
+    request
+        .validate()
+        .map_err(|e| crate::error::UsageError(format!("{e:#}")))?;
+    let format = OutputFormat::from_str(output_format)?;
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let report = cancellable!(
+        reassign_saved_searches(&client, &request, Some(cancel)),
+        cancel
+    )?;
+
+    let output = format_reassign_report(&report, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    let failed = report.failed_count();
+    if failed > 0 {
+        anyhow::bail!(
+            "Failed to reassign {} of {} saved search(es)",
+            failed,
+            report.items.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// Format a reassignment report based on the selected format.
+pub fn format_reassign_report(
+    report: &SavedSearchReassignReport,
+    format: OutputFormat,
+) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(report)?),
+        OutputFormat::Table => Ok(format_table(report)),
+        OutputFormat::Csv => Ok(format_csv(report)),
+        OutputFormat::Xml => Ok(format_xml(report)),
+        OutputFormat::Ndjson => format_ndjson(report),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(report)?),
+        OutputFormat::Markdown => Ok(format_markdown(report)),
+    }
+}
+
+fn mode_label(report: &SavedSearchReassignReport) -> &'static str {
+    if report.dry_run { "dry run" } else { "applied" }
+}
+
+/// Current owner the searches were selected by, or `any owner` for a by-name selection.
+fn from_owner_label(report: &SavedSearchReassignReport) -> &str {
+    report.from_owner.as_deref().unwrap_or("any owner")
+}
+
+fn format_table(report: &SavedSearchReassignReport) -> String {
+    let impact = &report.schedule_impact;
+    let mut out = String::new();
+
+    out.push_str(&format!(
+        "Reassign saved searches: {} -> {} ({})\n",
+        from_owner_label(report),
+        report.to_owner,
+        mode_label(report)
+    ));
+    if let Some(ref app) = report.app {
+        out.push_str(&format!("App: {}\n", app));
+    }
+    out.push('\n');
+
+    if report.items.is_empty() {
+        out.push_str(&format!(
+            "No saved searches owned by '{}' found.\n",
+            from_owner_label(report)
+        ));
+        return out;
+    }
+
+    out.push_str(&format!(
+        "{:<35} {:<15} {:<8} {:<10} {:<18} {:<11} {}\n",
+        "NAME", "APP", "SHARING", "SCHEDULED", "CRON", "STATUS", "ERROR"
+    ));
+    out.push_str(&format!(
+        "{:<35} {:<15} {:<8} {:<10} {:<18} {:<11} {}\n",
+        "====", "===", "=======", "=========", "====", "======", "====="
+    ));
+    for item in &report.items {
+        let scheduled = match (item.is_scheduled, item.disabled) {
+            (true, false) => "Yes",
+            (true, true) => "Disabled",
+            (false, _) => "No",
+        };
+        out.push_str(&format!(
+            "{:<35} {:<15} {:<8} {:<10} {:<18} {:<11} {}\n",
+            item.name,
+            item.app,
+            item.sharing,
+            scheduled,
+            item.cron_schedule.as_deref().unwrap_or("-"),
+            item.status.slug(),
+            item.error.as_deref().unwrap_or("")
+        ));
+    }
+
+    out.push_str("\nSchedule impact:\n");
+    out.push_str(&format!("  Saved searches:       {}\n", impact.total));
+    out.push_str(&format!("  Scheduled:            {}\n", impact.scheduled));
+    out.push_str(&format!(
+        "  Active scheduled:     {} (will run under '{}' quotas)\n",
+        impact.active_scheduled, report.to_owner
+    ));
+    out.push_str(&format!(
+        "  Disabled scheduled:   {}\n",
+        impact.disabled_scheduled
+    ));
+
+    out
+}
+
+fn format_csv(report: &SavedSearchReassignReport) -> String {
+    let mut csv = String::from(
+        "name,app,previous_sharing,sharing,is_scheduled,cron_schedule,disabled,status,error\n",
+    );
+
+    for item in &report.items {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{}\n",
+            escape_csv(&item.name),
+            escape_csv(&item.app),
+            escape_csv(&item.previous_sharing),
+            escape_csv(&item.sharing),
+            item.is_scheduled,
+            escape_csv(item.cron_schedule.as_deref().unwrap_or("")),
+            item.disabled,
+            item.status.slug(),
+            escape_csv(item.error.as_deref().unwrap_or(""))
+        ));
+    }
+
+    csv
+}
+
+fn format_xml(report: &SavedSearchReassignReport) -> String {
+    let impact = &report.schedule_impact;
+    let mut xml =
+        String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<saved_search_reassign>\n");
+    if let Some(ref from_owner) = report.from_owner {
+        xml.push_str(&format!(
+            "  <from_owner>{}</from_owner>\n",
+            escape_xml(from_owner)
+        ));
+    }
+    xml.push_str(&format!(
+        "  <to_owner>{}</to_owner>\n",
+        escape_xml(&report.to_owner)
+    ));
+    if let Some(ref app) = report.app {
+        xml.push_str(&format!("  <app>{}</app>\n", escape_xml(app)));
+    }
+    xml.push_str(&format!("  <dry_run>{}</dry_run>\n", report.dry_run));
+    xml.push_str("  <schedule_impact>\n");
+    xml.push_str(&format!("    <total>{}</total>\n", impact.total));
+    xml.push_str(&format!(
+        "    <scheduled>{}</scheduled>\n",
+        impact.scheduled
+    ));
+    xml.push_str(&format!(
+        "    <active_scheduled>{}</active_scheduled>\n",
+        impact.active_scheduled
+    ));
+    xml.push_str(&format!(
+        "    <disabled_scheduled>{}</disabled_scheduled>\n",
+        impact.disabled_scheduled
+    ));
+    xml.push_str("  </schedule_impact>\n");
+    xml.push_str("  <items>\n");
+    for item in &report.items {
+        xml.push_str("    <item>\n");
+        xml.push_str(&format!("      <name>{}</name>\n", escape_xml(&item.name)));
+        xml.push_str(&format!("      <app>{}</app>\n", escape_xml(&item.app)));
+        xml.push_str(&format!(
+            "      <previous_sharing>{}</previous_sharing>\n",
+            escape_xml(&item.previous_sharing)
+        ));
+        xml.push_str(&format!(
+            "      <sharing>{}</sharing>\n",
+            escape_xml(&item.sharing)
+        ));
+        xml.push_str(&format!(
+            "      <is_scheduled>{}</is_scheduled>\n",
+            item.is_scheduled
+        ));
+        if let Some(ref cron) = item.cron_schedule {
+            xml.push_str(&format!(
+                "      <cron_schedule>{}</cron_schedule>\n",
+                escape_xml(cron)
+            ));
+        }
+        xml.push_str(&format!("      <disabled>{}</disabled>\n", item.disabled));
+        xml.push_str(&format!("      <status>{}</status>\n", item.status.slug()));
+        if let Some(ref error) = item.error {
+            xml.push_str(&format!("      <error>{}</error>\n", escape_xml(error)));
+        }
+        xml.push_str("    </item>\n");
+    }
+    xml.push_str("  </items>\n");
+    xml.push_str("</saved_search_reassign>");
+    xml
+}
+
+fn format_ndjson(report: &SavedSearchReassignReport) -> Result<String> {
+    let mut ndjson = String::new();
+    for item in &report.items {
+        ndjson.push_str(&serde_json::to_string(item)?);
+        ndjson.push('\n');
+    }
+    Ok(ndjson)
+}
+
+fn format_markdown(report: &SavedSearchReassignReport) -> String {
+    let impact = &report.schedule_impact;
+    let mut md = String::from("# Saved Search Reassignment\n\n");
+    md.push_str(&format!("- **From owner**: {}\n", from_owner_label(report)));
+    md.push_str(&format!("- **To owner**: {}\n", report.to_owner));
+    if let Some(ref app) = report.app {
+        md.push_str(&format!("- **App**: {}\n", app));
+    }
+    md.push_str(&format!("- **Mode**: {}\n\n", mode_label(report)));
+
+    md.push_str("## Schedule Impact\n\n");
+    md.push_str("| Metric | Count |\n");
+    md.push_str("|--------|-------|\n");
+    md.push_str(&format!("| Saved searches | {} |\n", impact.total));
+    md.push_str(&format!("| Scheduled | {} |\n", impact.scheduled));
+    md.push_str(&format!(
+        "| Active scheduled | {} |\n",
+        impact.active_scheduled
+    ));
+    md.push_str(&format!(
+        "| Disabled scheduled | {} |\n\n",
+        impact.disabled_scheduled
+    ));
+
+    md.push_str("## Saved Searches\n\n");
+    if report.items.is_empty() {
+        md.push_str("_No saved searches found._\n");
+        return md;
+    }
+    md.push_str("| Name | App | Sharing | Scheduled | Cron | Status |\n");
+    md.push_str("|------|-----|---------|-----------|------|--------|\n");
+    for item in &report.items {
+        md.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} |\n",
+            item.name,
+            item.app,
+            item.sharing,
+            item.is_scheduled,
+            item.cron_schedule.as_deref().unwrap_or("-"),
+            item.status.slug()
+        ));
+    }
+    md
+}
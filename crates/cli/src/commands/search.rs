@@ -4,9 +4,13 @@
 //! - Execute SPL queries with configurable time bounds
 //! - Support blocking (wait) and non-blocking execution modes
 //! - Handle real-time search with optional window
+//! - Auto-finalize running jobs once a result threshold is reached
+//! - Compare two time ranges (`--diff-earliest`) and print the row-level diff
 //! - Apply search defaults from configuration when CLI flags not provided
 //! - Format output via shared formatters
 //! - Validate SPL syntax without executing searches
+//! - Run one search on several profiles (`search execute --profiles a,b`), optionally
+//!   merging identical rows or summing counts by key across profiles (`--merge`)
 //!
 //! Does NOT handle:
 //! - Saved search management (see saved_searches module)
@@ -18,17 +22,22 @@
 //! - Time bounds default to configuration values or -24h/now
 //! - Max results default to configuration or 100
 //! - Progress callbacks are only used in non-quiet mode
+//! - `--finalize-after` implies `--wait`, since the threshold is checked while polling
 
 use anyhow::Result;
-use clap::Subcommand;
-use splunk_client::{SearchMode, SearchRequest, normalize_search_query};
+use clap::{Args, Subcommand};
+use splunk_client::workflows::result_diff::diff_results;
+use splunk_client::{SearchMode, SearchRequest, SplunkClient, normalize_search_query};
 use splunk_config::SearchDefaultConfig;
 use std::path::PathBuf;
 use tracing::info;
 
+use crate::commands::multi_profile::{self, ProfileSource, ProfileTargets};
+use crate::commands::search_diff::{SearchDiffArgs, format_result_diff};
 use crate::formatters::{OutputFormat, get_formatter, output_result};
 
 /// Search subcommands.
+#[allow(clippy::large_enum_variant)] // parsed once per invocation, see `Commands`
 #[derive(Subcommand)]
 pub enum SearchCommand {
     /// Execute a search query (default)
@@ -41,11 +50,11 @@ pub enum SearchCommand {
         wait: bool,
 
         /// Earliest time for the search (e.g., '-24h', '2024-01-01T00:00:00')
-        #[arg(short, long, allow_hyphen_values = true)]
+        #[arg(short, long, allow_hyphen_values = true, value_parser = parse_time_modifier)]
         earliest: Option<String>,
 
         /// Latest time for the search (e.g., 'now', '2024-01-02T00:00:00')
-        #[arg(short, long, allow_hyphen_values = true)]
+        #[arg(short, long, allow_hyphen_values = true, value_parser = parse_time_modifier)]
         latest: Option<String>,
 
         /// Maximum number of results to return
@@ -59,6 +68,21 @@ pub enum SearchCommand {
         /// Real-time window in seconds (e.g., 60 for a 60-second window)
         #[arg(long, requires = "realtime")]
         realtime_window: Option<u64>,
+
+        /// Finalize the job once N results (or events) are available and return them
+        ///
+        /// Useful for exploratory queries where partial results are enough. Implies --wait.
+        #[arg(long, value_name = "N", value_parser = parse_finalize_after)]
+        finalize_after: Option<usize>,
+
+        #[command(flatten)]
+        diff: SearchDiffArgs,
+
+        #[command(flatten)]
+        targets: ProfileTargets,
+
+        #[command(flatten)]
+        merge: SearchMergeArgs,
     },
 
     /// Validate SPL syntax without executing the search
@@ -82,6 +106,44 @@ pub enum SearchCommand {
     },
 }
 
+/// Flags that combine the results of a multi-profile search into one result set.
+#[derive(Args, Debug, Clone, Default)]
+pub struct SearchMergeArgs {
+    /// Merge results from all profiles, collapsing rows returned by several profiles
+    ///
+    /// The profile column of a merged row lists every profile that returned it.
+    #[arg(long, requires = "ProfileTargets")]
+    pub merge: bool,
+
+    /// Sum --sum-field across profiles for rows with the same value(s) of these fields
+    #[arg(long, value_name = "FIELD", value_delimiter = ',', requires = "merge")]
+    pub sum_by: Vec<String>,
+
+    /// Field(s) to total with --sum-by
+    #[arg(
+        long,
+        value_name = "FIELD",
+        value_delimiter = ',',
+        default_value = "count",
+        requires = "sum_by"
+    )]
+    pub sum_field: Vec<String>,
+}
+
+/// Parse the `--finalize-after` threshold, rejecting zero.
+pub fn parse_finalize_after(value: &str) -> std::result::Result<usize, String> {
+    match value.trim().parse::<usize>() {
+        Ok(0) => Err("threshold must be at least 1".to_string()),
+        Ok(n) => Ok(n),
+        Err(_) => Err(format!("invalid result count '{}'", value)),
+    }
+}
+
+/// Validate an `--earliest`/`--latest` time modifier such as `-24h@h`, keeping it as typed.
+pub fn parse_time_modifier(value: &str) -> std::result::Result<String, String> {
+    splunk_client::relative_time::validate_time_modifier(value).map_err(|e| e.to_string())
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn run(
     config: splunk_config::Config,
@@ -97,9 +159,12 @@ pub async fn run(
     cancel: &crate::cancellation::CancellationToken,
     realtime: bool,
     realtime_window: Option<u64>,
+    finalize_after: Option<usize>,
+    diff: &SearchDiffArgs,
     no_cache: bool,
 ) -> Result<()> {
     let query = normalize_search_query(&query);
+    let wait = wait || finalize_after.is_some();
 
     info!("Executing search: {}", query);
 
@@ -110,26 +175,132 @@ pub async fn run(
 
     // Determine search mode based on realtime flag
     let search_mode = if realtime {
-        Some(SearchMode::Realtime)
+        SearchMode::Realtime
     } else {
-        Some(SearchMode::Normal)
+        SearchMode::Normal
     };
 
     let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
 
     info!("Connecting to {}", client.base_url());
 
-    // Build the search request with common parameters
-    let mut request = SearchRequest::new(&query, wait)
-        .time_bounds(earliest, latest)
-        .max_results(max_results)
-        .search_mode(search_mode.unwrap_or(SearchMode::Normal));
-    if let Some(window) = realtime_window {
-        request = request.realtime_window(window);
+    // Build a search request with common parameters for the given time bounds
+    let build_request = |earliest, latest| {
+        let mut request = SearchRequest::new(&query, wait)
+            .time_bounds(earliest, latest)
+            .max_results(max_results)
+            .search_mode(search_mode);
+        if let Some(window) = realtime_window {
+            request = request.realtime_window(window);
+        }
+        if let Some(threshold) = finalize_after {
+            request = request.finalize_after(threshold);
+        }
+        request
+    };
+
+    // In compare mode the baseline range runs first; it defaults to ending where this run starts
+    let baseline = match &diff.diff_earliest {
+        Some(diff_earliest) => {
+            let diff_latest = diff.diff_latest.as_deref().unwrap_or(earliest);
+            info!(
+                "Running baseline search: {} to {}",
+                diff_earliest, diff_latest
+            );
+            let request = build_request(diff_earliest, diff_latest);
+            let label = "Waiting for baseline search";
+            Some(execute(&client, request, wait, quiet, label, finalize_after, cancel).await?)
+        }
+        None => None,
+    };
+
+    let request = build_request(earliest, latest);
+    let label = "Waiting for search";
+    let results = execute(&client, request, wait, quiet, label, finalize_after, cancel).await?;
+
+    // Parse output format
+    let format = OutputFormat::from_str(output_format)?;
+
+    // Format and print results, or their diff against the baseline
+    let output = match baseline {
+        Some(baseline) => {
+            let diff = diff_results(&baseline, &results, &diff.diff_key);
+            format_result_diff(&diff, format)?
+        }
+        None => get_formatter(format).format_search_results(&results)?,
+    };
+    output_result(&output, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+/// Run one search job and return its results, reporting progress and early finalization.
+/// Run `query` on every target profile and print the combined results.
+///
+/// Searches always wait for completion and show no progress bars, since several run at once.
+#[allow(clippy::too_many_arguments)]
+pub async fn run_profiles(
+    source: &ProfileSource,
+    targets: &ProfileTargets,
+    merge: &SearchMergeArgs,
+    query: String,
+    earliest: Option<&str>,
+    latest: Option<&str>,
+    max_results: Option<usize>,
+    finalize_after: Option<usize>,
+    output_format: &str,
+    output_file: Option<&PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+) -> Result<()> {
+    let query = normalize_search_query(&query);
+    info!("Executing search across profiles: {}", query);
+
+    let search_defaults = source.search_defaults()?;
+    let earliest = earliest.unwrap_or(&search_defaults.earliest_time);
+    let latest = latest.unwrap_or(&search_defaults.latest_time);
+    let max_results = max_results.unwrap_or(search_defaults.max_results);
+
+    let query = query.as_str();
+    let results = multi_profile::fan_out(source, targets, cancel, |client| async move {
+        let mut request = SearchRequest::new(query, true)
+            .time_bounds(earliest, latest)
+            .max_results(max_results);
+        if let Some(threshold) = finalize_after {
+            request = request.finalize_after(threshold);
+        }
+        execute(&client, request, true, true, "", finalize_after, cancel).await
+    })
+    .await?;
+
+    if !merge.merge {
+        return results.print(&[], output_format, output_file);
     }
+    let columns: Vec<&str> = if merge.sum_by.is_empty() {
+        Vec::new()
+    } else {
+        merge
+            .sum_by
+            .iter()
+            .chain(&merge.sum_field)
+            .map(String::as_str)
+            .collect()
+    };
+    results
+        .merge(&merge.sum_by, &merge.sum_field)
+        .print(&columns, output_format, output_file)
+}
 
-    let (results, _sid, _total) = if wait {
-        let progress = crate::progress::SearchProgress::new(!quiet, "Waiting for search");
+async fn execute(
+    client: &SplunkClient,
+    request: SearchRequest<'_>,
+    wait: bool,
+    quiet: bool,
+    label: &str,
+    finalize_after: Option<usize>,
+    cancel: &crate::cancellation::CancellationToken,
+) -> Result<Vec<serde_json::Value>> {
+    let (results, sid, _total) = if wait {
+        let progress = crate::progress::SearchProgress::new(!quiet, label);
 
         let mut on_progress = |done_progress: f64| {
             progress.set_fraction(done_progress);
@@ -147,15 +318,17 @@ pub async fn run(
         cancellable!(client.search_with_progress(request, None), cancel)?
     };
 
-    // Parse output format
-    let format = OutputFormat::from_str(output_format)?;
-    let formatter = get_formatter(format);
-
-    // Format and print results
-    let output = formatter.format_search_results(&results)?;
-    output_result(&output, format, output_file.as_ref())?;
+    if finalize_after.is_some() && !quiet {
+        let status = client.get_job_status(&sid).await?;
+        if status.is_finalized {
+            eprintln!(
+                "Search finalized early after {} result(s) ({} event(s)); results are partial (sid: {})",
+                status.result_count, status.event_count, sid
+            );
+        }
+    }
 
-    Ok(())
+    Ok(results)
 }
 
 /// Validate SPL syntax without executing the search.
@@ -231,6 +404,30 @@ pub async fn run_validate(
 mod tests {
     use splunk_client::normalize_search_query;
 
+    #[test]
+    fn parse_finalize_after_rejects_zero() {
+        assert_eq!(super::parse_finalize_after("250"), Ok(250));
+        assert!(super::parse_finalize_after("0").is_err());
+        assert!(super::parse_finalize_after("many").is_err());
+    }
+
+    #[test]
+    fn parse_time_modifier_keeps_valid_values() {
+        assert_eq!(
+            super::parse_time_modifier("-24h@h"),
+            Ok("-24h@h".to_string())
+        );
+        assert_eq!(
+            super::parse_time_modifier("2024-01-01T00:00:00"),
+            Ok("2024-01-01T00:00:00".to_string())
+        );
+        assert!(
+            super::parse_time_modifier("-24x")
+                .unwrap_err()
+                .contains("unknown time unit 'x'")
+        );
+    }
+
     #[test]
     fn normalize_prefixes_bare_index_query() {
         assert_eq!(
@@ -0,0 +1,314 @@
+//! Search compare mode (`search execute --diff-earliest`).
+//!
+//! Responsibilities:
+//! - Define the compare flags for `search execute`
+//! - Format the row-level diff of the baseline and current runs in every output format
+//!
+//! Does NOT handle:
+//! - Running the searches (see the search module)
+//! - Pairing and classifying rows (see `splunk_client::workflows::result_diff`)
+//!
+//! Invariants:
+//! - Only added, removed, and changed rows are emitted; unchanged rows are only counted
+//! - The baseline run defaults to ending where the current run starts
+
+use anyhow::Result;
+use clap::Args;
+use serde::Serialize;
+use serde_json::Value;
+use splunk_client::workflows::result_diff::{DiffSummary, ResultDiff, RowDiff};
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml};
+
+/// Flags that turn `search execute` into a compare of two time ranges.
+#[derive(Args, Debug, Clone, Default)]
+pub struct SearchDiffArgs {
+    /// Also run the query from this earliest time and print the row-level diff against it
+    ///
+    /// The baseline run ends at --diff-latest (default: the current run's earliest time).
+    #[arg(
+        long,
+        value_name = "TIME",
+        allow_hyphen_values = true,
+        value_parser = crate::commands::search::parse_time_modifier,
+        conflicts_with = "realtime"
+    )]
+    pub diff_earliest: Option<String>,
+
+    /// Latest time for the baseline run (default: the current run's earliest time)
+    #[arg(
+        long,
+        value_name = "TIME",
+        allow_hyphen_values = true,
+        value_parser = crate::commands::search::parse_time_modifier,
+        requires = "diff_earliest"
+    )]
+    pub diff_latest: Option<String>,
+
+    /// Field(s) that identify a row when comparing (comma-separated or repeated)
+    ///
+    /// By default rows are keyed by the non-numeric fields present in every row.
+    #[arg(
+        long,
+        value_name = "FIELD",
+        value_delimiter = ',',
+        requires = "diff_earliest"
+    )]
+    pub diff_key: Vec<String>,
+}
+
+/// Structured diff output: summary plus the rows that changed.
+#[derive(Serialize)]
+struct DiffOutput<'a> {
+    key_fields: &'a [String],
+    summary: DiffSummary,
+    changes: Vec<&'a RowDiff>,
+}
+
+/// Format a result diff based on the selected format.
+pub fn format_result_diff(diff: &ResultDiff, format: OutputFormat) -> Result<String> {
+    let output = DiffOutput {
+        key_fields: &diff.key_fields,
+        summary: diff.summary,
+        changes: diff.changes().collect(),
+    };
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(&output)?),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(&output)?),
+        OutputFormat::Ndjson => {
+            let mut lines = String::new();
+            for change in &output.changes {
+                lines.push_str(&serde_json::to_string(change)?);
+                lines.push('\n');
+            }
+            Ok(lines)
+        }
+        OutputFormat::Table => Ok(format_diff_text(diff)),
+        OutputFormat::Csv => Ok(format_diff_csv(diff)),
+        OutputFormat::Xml => Ok(format_diff_xml(diff)),
+        OutputFormat::Markdown => Ok(format_diff_markdown(diff)),
+    }
+}
+
+fn summary_line(diff: &ResultDiff) -> String {
+    let keyed = if diff.key_fields.is_empty() {
+        "whole rows".to_string()
+    } else {
+        diff.key_fields.join(", ")
+    };
+    let s = diff.summary;
+    format!(
+        "Compared by {}: {} added, {} removed, {} changed, {} unchanged",
+        keyed, s.added, s.removed, s.changed, s.unchanged
+    )
+}
+
+/// `host=web-01` style label for a row's key fields (the whole row without key fields).
+fn key_label(diff: &ResultDiff, row: &Value) -> String {
+    if diff.key_fields.is_empty() {
+        return compact(row);
+    }
+    diff.key_fields
+        .iter()
+        .map(|field| format!("{}={}", field, display_value(row.get(field))))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn display_value(value: Option<&Value>) -> String {
+    match value {
+        Some(Value::String(text)) => text.clone(),
+        Some(Value::Null) | None => "(missing)".to_string(),
+        Some(other) => other.to_string(),
+    }
+}
+
+fn compact(value: &Value) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+/// Field changes of a changed row as `field: before -> after`.
+fn field_changes<'a>(
+    before: &'a Value,
+    after: &'a Value,
+    fields: &'a [String],
+) -> impl Iterator<Item = String> + 'a {
+    fields.iter().map(move |field| {
+        format!(
+            "{}: {} -> {}",
+            field,
+            display_value(before.get(field)),
+            display_value(after.get(field))
+        )
+    })
+}
+
+fn format_diff_text(diff: &ResultDiff) -> String {
+    let mut out = format!("{}\n", summary_line(diff));
+    for change in diff.changes() {
+        match change {
+            RowDiff::Changed {
+                before,
+                after,
+                fields,
+            } => {
+                out.push_str(&format!("~ {}\n", key_label(diff, after)));
+                for line in field_changes(before, after, fields) {
+                    out.push_str(&format!("    {}\n", line));
+                }
+            }
+            other => out.push_str(&format!("{} {}\n", other.marker(), compact(other.row()))),
+        }
+    }
+    out
+}
+
+fn format_diff_csv(diff: &ResultDiff) -> String {
+    let mut csv = String::from("change,key,fields,before,after\n");
+    for change in diff.changes() {
+        let (name, before, after, fields) = match change {
+            RowDiff::Added { row } => ("added", None, Some(row), None),
+            RowDiff::Removed { row } => ("removed", Some(row), None, None),
+            RowDiff::Changed {
+                before,
+                after,
+                fields,
+            } => ("changed", Some(before), Some(after), Some(fields.join(" "))),
+            RowDiff::Unchanged { .. } => continue,
+        };
+        csv.push_str(&format!(
+            "{},{},{},{},{}\n",
+            name,
+            escape_csv(&key_label(diff, change.row())),
+            escape_csv(&fields.unwrap_or_default()),
+            escape_csv(&before.map(compact).unwrap_or_default()),
+            escape_csv(&after.map(compact).unwrap_or_default())
+        ));
+    }
+    csv
+}
+
+fn format_diff_xml(diff: &ResultDiff) -> String {
+    let s = diff.summary;
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<result_diff>\n");
+    xml.push_str(&format!(
+        "  <summary added=\"{}\" removed=\"{}\" changed=\"{}\" unchanged=\"{}\"/>\n",
+        s.added, s.removed, s.changed, s.unchanged
+    ));
+    xml.push_str(&format!(
+        "  <key_fields>{}</key_fields>\n",
+        escape_xml(&diff.key_fields.join(","))
+    ));
+    xml.push_str("  <changes>\n");
+    for change in diff.changes() {
+        match change {
+            RowDiff::Changed {
+                before,
+                after,
+                fields,
+            } => {
+                xml.push_str(&format!(
+                    "    <row change=\"changed\" fields=\"{}\">\n      <before>{}</before>\n      <after>{}</after>\n    </row>\n",
+                    escape_xml(&fields.join(",")),
+                    escape_xml(&compact(before)),
+                    escape_xml(&compact(after))
+                ));
+            }
+            other => {
+                let name = if other.marker() == '+' {
+                    "added"
+                } else {
+                    "removed"
+                };
+                xml.push_str(&format!(
+                    "    <row change=\"{}\">{}</row>\n",
+                    name,
+                    escape_xml(&compact(other.row()))
+                ));
+            }
+        }
+    }
+    xml.push_str("  </changes>\n");
+    xml.push_str("</result_diff>");
+    xml
+}
+
+fn format_diff_markdown(diff: &ResultDiff) -> String {
+    let mut md = format!("# Search Diff\n\n{}\n", summary_line(diff));
+    if !diff.has_changes() {
+        return md;
+    }
+    md.push_str("\n| Change | Row | Details |\n|--------|-----|---------|\n");
+    for change in diff.changes() {
+        let details = match change {
+            RowDiff::Changed {
+                before,
+                after,
+                fields,
+            } => field_changes(before, after, fields)
+                .collect::<Vec<_>>()
+                .join("; "),
+            _ => String::new(),
+        };
+        md.push_str(&format!(
+            "| {} | `{}` | {} |\n",
+            change.marker(),
+            key_label(diff, change.row()).replace('|', "\\|"),
+            details.replace('|', "\\|")
+        ));
+    }
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use splunk_client::workflows::result_diff::diff_results;
+
+    fn sample() -> ResultDiff {
+        diff_results(
+            &[
+                json!({"host": "a", "count": "1"}),
+                json!({"host": "b", "count": "2"}),
+            ],
+            &[
+                json!({"host": "a", "count": "3"}),
+                json!({"host": "c", "count": "1"}),
+            ],
+            &[],
+        )
+    }
+
+    #[test]
+    fn test_text_diff_marks_rows() {
+        let text = format_diff_text(&sample());
+        assert!(text.starts_with("Compared by host: 1 added, 1 removed, 1 changed, 0 unchanged"));
+        assert!(text.contains("~ host=a\n    count: 1 -> 3\n"));
+        let added = text.lines().find(|line| line.starts_with("+ ")).unwrap();
+        assert!(added.contains("\"host\":\"c\""));
+        let removed = text.lines().find(|line| line.starts_with("- ")).unwrap();
+        assert!(removed.contains("\"host\":\"b\""));
+    }
+
+    #[test]
+    fn test_json_diff_lists_changes_only() {
+        let diff = diff_results(
+            &[json!({"host": "a"}), json!({"host": "b"})],
+            &[json!({"host": "a"})],
+            &[],
+        );
+        let value: Value =
+            serde_json::from_str(&format_result_diff(&diff, OutputFormat::Json).unwrap()).unwrap();
+        assert_eq!(value["summary"]["unchanged"], 1);
+        assert_eq!(value["changes"][0]["change"], "removed");
+        assert_eq!(value["changes"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_csv_diff_has_one_line_per_change() {
+        let csv = format_diff_csv(&sample());
+        assert_eq!(csv.lines().count(), 4);
+        assert!(csv.contains("changed,host=a,count,"));
+    }
+}
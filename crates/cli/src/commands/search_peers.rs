@@ -4,22 +4,179 @@
 //! - List distributed search peers with optional count limiting
 //! - Support pagination via offset parameter
 //! - Show detailed peer information when requested
+//! - Add, remove, quarantine, and unquarantine search peers
 //! - Format output via shared formatters
 //!
 //! Does NOT handle:
-//! - Search peer configuration or management
 //! - Direct REST API calls (handled by client crate)
 //! - Output formatting details (see formatters module)
 //!
 //! Invariants:
 //! - Count and offset parameters are validated for safe pagination
 //! - Server-side total may not be available for all peer listings
+//! - Remove operations require confirmation unless --force is used
+//! - Remote passwords are handled securely via SecretString
 
 use anyhow::Result;
+use clap::Subcommand;
+use secrecy::SecretString;
+use splunk_client::models::AddSearchPeerParams;
 use tracing::info;
 
 use crate::formatters::{OutputFormat, Pagination, TableFormatter, get_formatter, output_result};
 
+#[derive(Debug, Subcommand)]
+pub enum SearchPeersCommand {
+    /// Add a distributed search peer
+    Add {
+        /// Peer management URI (e.g. https://idx1.example.com:8089)
+        uri: String,
+        /// Admin username on the peer, used once to exchange trust keys
+        #[arg(long)]
+        remote_username: String,
+        /// Password for the remote user (will prompt if not provided)
+        #[arg(long)]
+        remote_password: Option<String>,
+    },
+    /// Remove a distributed search peer
+    Remove {
+        /// Peer URI or host:port name
+        uri: String,
+        /// Skip confirmation prompt
+        #[arg(short, long)]
+        force: bool,
+    },
+    /// Quarantine a search peer, excluding it from new searches
+    Quarantine {
+        /// Peer URI or host:port name
+        uri: String,
+    },
+    /// Return a quarantined search peer to service
+    Unquarantine {
+        /// Peer URI or host:port name
+        uri: String,
+    },
+}
+
+/// Run a search-peers management subcommand.
+pub async fn run_command(
+    config: splunk_config::Config,
+    command: SearchPeersCommand,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    match command {
+        SearchPeersCommand::Add {
+            uri,
+            remote_username,
+            remote_password,
+        } => {
+            run_add(
+                config,
+                uri,
+                remote_username,
+                remote_password,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
+        SearchPeersCommand::Remove { uri, force } => {
+            run_remove(config, &uri, force, cancel, no_cache).await
+        }
+        SearchPeersCommand::Quarantine { uri } => {
+            run_quarantine(config, &uri, true, cancel, no_cache).await
+        }
+        SearchPeersCommand::Unquarantine { uri } => {
+            run_quarantine(config, &uri, false, cancel, no_cache).await
+        }
+    }
+}
+
+async fn run_add(
+    config: splunk_config::Config,
+    uri: String,
+    remote_username: String,
+    remote_password: Option<String>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Adding search peer: {}", uri);
+
+    // Prompt for password if not provided via CLI
+    let remote_password = match remote_password {
+        Some(p) => SecretString::new(p.into()),
+        None => {
+            print!("Enter password for '{}' on {}: ", remote_username, uri);
+            use std::io::Write;
+            std::io::stdout().flush()?;
+            let mut input = String::new();
+            std::io::stdin().read_line(&mut input)?;
+            SecretString::new(input.trim().to_string().into())
+        }
+    };
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let params = AddSearchPeerParams {
+        uri,
+        remote_username,
+        remote_password,
+    };
+
+    cancellable_with!(client.add_search_peer(&params), cancel, |_res| {
+        println!("Search peer '{}' added successfully.", params.uri);
+        Ok(())
+    })
+}
+
+async fn run_remove(
+    config: splunk_config::Config,
+    uri: &str,
+    force: bool,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    if !force && !crate::interactive::confirm_delete(uri, "search peer")? {
+        return Ok(());
+    }
+
+    info!("Removing search peer: {}", uri);
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    cancellable_with!(client.remove_search_peer(uri), cancel, |_res| {
+        println!("Search peer '{}' removed successfully.", uri);
+        Ok(())
+    })
+}
+
+async fn run_quarantine(
+    config: splunk_config::Config,
+    uri: &str,
+    quarantine: bool,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    let verb = if quarantine {
+        "quarantined"
+    } else {
+        "unquarantined"
+    };
+    info!("Setting search peer quarantine ({}): {}", quarantine, uri);
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    cancellable_with!(
+        client.set_search_peer_quarantine(uri, quarantine),
+        cancel,
+        |_res| {
+            println!("Search peer '{}' {}.", uri, verb);
+            Ok(())
+        }
+    )
+}
+
 /// Run the search-peers command.
 ///
 /// Lists distributed search peers from the Splunk search head.
@@ -0,0 +1,108 @@
+//! Server control command implementation.
+//!
+//! Responsibilities:
+//! - Restart splunkd, optionally waiting until it answers again
+//! - Report whether configuration changes are waiting on a restart
+//!
+//! Does NOT handle:
+//! - Direct REST API calls (handled by client crate)
+//! - Search head cluster rolling restarts (see `shc rolling-restart`)
+//!
+//! Invariants:
+//! - A restart is only sent after confirmation unless `--force` is given
+//! - `--wait` treats the server as back once its startup time changes
+
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Subcommand;
+use tracing::info;
+
+/// How often `--wait` polls server info while splunkd restarts.
+const RESTART_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Subcommand)]
+pub enum ServerCommand {
+    /// Restart splunkd
+    #[command(after_help = "Examples:
+  splunk-cli server restart
+  splunk-cli server restart --force --wait
+  splunk-cli server restart --wait --wait-timeout 15m
+")]
+    Restart {
+        /// Wait until splunkd is back before exiting
+        #[arg(long)]
+        wait: bool,
+
+        /// How long to wait for splunkd to come back (with --wait)
+        #[arg(
+            long,
+            value_name = "DURATION",
+            default_value = "10m",
+            value_parser = crate::commands::generate::parse_duration
+        )]
+        wait_timeout: Duration,
+
+        /// Skip confirmation prompt
+        #[arg(short, long)]
+        force: bool,
+    },
+}
+
+pub async fn run(
+    config: splunk_config::Config,
+    command: ServerCommand,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    match command {
+        ServerCommand::Restart {
+            wait,
+            wait_timeout,
+            force,
+        } => run_restart(config, wait, wait_timeout, force, cancel, no_cache).await,
+    }
+}
+
+async fn run_restart(
+    config: splunk_config::Config,
+    wait: bool,
+    timeout: Duration,
+    force: bool,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let before = cancellable!(client.get_server_info(), cancel)?;
+    if before.restart_required {
+        eprintln!(
+            "Server '{}' reports a restart is required.",
+            before.server_name
+        );
+    }
+
+    if !force && !crate::interactive::confirm_restart(&before.server_name)? {
+        return Ok(());
+    }
+
+    info!("Restarting splunkd on {}", before.server_name);
+    cancellable!(client.restart_server(), cancel)?;
+    eprintln!("Restart requested for '{}'", before.server_name);
+
+    if !wait {
+        return Ok(());
+    }
+
+    eprintln!("Waiting for splunkd to come back...");
+    let after = cancellable!(
+        client.wait_for_restart(before.startup_time, timeout, RESTART_POLL_INTERVAL),
+        cancel
+    )?;
+    eprintln!(
+        "Server '{}' is back (version {})",
+        after.server_name, after.version
+    );
+
+    Ok(())
+}
@@ -158,7 +158,7 @@ pub async fn run(
 
 /// Fetch a page of SHC members with pagination.
 async fn fetch_shc_members_page(
-    client: &mut splunk_client::SplunkClient,
+    client: &splunk_client::SplunkClient,
     offset: usize,
     count: usize,
     cancel: &crate::cancellation::CancellationToken,
@@ -237,7 +237,7 @@ async fn run_show(
         anyhow::bail!("The count value must be greater than 0");
     }
 
-    let mut client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
 
     let shc_status = tokio::select! {
         res = client.get_shc_status() => res,
@@ -248,7 +248,7 @@ async fn run_show(
         Ok(shc_status) => {
             // Fetch members if detailed (fetch ALL once, then paginate locally)
             let (members_output, members_pagination) = if detailed {
-                fetch_shc_members_page(&mut client, offset, count, cancel).await?
+                fetch_shc_members_page(&client, offset, count, cancel).await?
             } else {
                 (None, None)
             };
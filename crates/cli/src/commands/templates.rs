@@ -0,0 +1,162 @@
+//! Search template save and run commands.
+//!
+//! Responsibilities:
+//! - Save an SPL query with `{{name}}` (or `$name$`) placeholders as a personal template.
+//! - Render a template from `--param NAME=VALUE` values and execute it as a search.
+//!
+//! Does NOT handle:
+//! - Listing, exporting, or importing templates (see `splunk-cli config templates`).
+//! - Search execution and output formatting (see the `search` module).
+//!
+//! Invariants:
+//! - Every placeholder of a saved template becomes a required string parameter unless
+//!   given a `--default`; richer schemas are written as template files and imported.
+//! - An existing personal template is only overwritten with `--replace`.
+
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use splunk_config::SearchTemplate;
+use splunk_config::persistence::ConfigManager;
+
+/// Search template subcommands.
+#[derive(Subcommand)]
+pub enum TemplatesCommand {
+    /// Save an SPL query with placeholders as a personal search template
+    #[command(after_help = "\
+Placeholders are written {{name}} (or $name$) and become required parameters;
+--default makes one optional. List saved templates with 'splunk-cli config templates list'.
+
+Examples:
+  splunk-cli templates save \"User activity\" --spl 'index={{index}} user={{user}} | stats count by action'
+  splunk-cli templates save \"User activity\" --spl 'index={{index}} user={{user}}' --default index=auth --replace
+")]
+    Save {
+        /// Template name
+        #[arg(value_name = "NAME")]
+        name: String,
+
+        /// SPL query with `{{name}}` placeholders
+        #[arg(long = "spl", allow_hyphen_values = true)]
+        query: String,
+
+        /// What the template is for
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Default value for a placeholder as NAME=VALUE (repeatable)
+        #[arg(long = "default", value_name = "NAME=VALUE")]
+        defaults: Vec<String>,
+
+        /// Overwrite a personal template with the same name
+        #[arg(long)]
+        replace: bool,
+    },
+
+    /// Fill in a template's parameters and execute the search
+    #[command(after_help = "\
+Examples:
+  splunk-cli templates run \"User activity\" --param user=alice
+  splunk-cli templates run \"Failed logins\" --param threshold=10 --earliest -7d --wait
+")]
+    Run {
+        /// Template name
+        #[arg(value_name = "NAME")]
+        name: String,
+
+        /// Parameter value as NAME=VALUE (repeatable); omitted parameters use their defaults
+        #[arg(long = "param", value_name = "NAME=VALUE")]
+        params: Vec<String>,
+
+        /// Wait for the search to complete before returning results
+        #[arg(long)]
+        wait: bool,
+
+        /// Earliest time for the search (e.g., '-24h', '2024-01-01T00:00:00')
+        #[arg(short, long, allow_hyphen_values = true, value_parser = crate::commands::search::parse_time_modifier)]
+        earliest: Option<String>,
+
+        /// Latest time for the search (e.g., 'now', '2024-01-02T00:00:00')
+        #[arg(short, long, allow_hyphen_values = true, value_parser = crate::commands::search::parse_time_modifier)]
+        latest: Option<String>,
+
+        /// Maximum number of results to return
+        #[arg(short, long)]
+        count: Option<usize>,
+    },
+}
+
+/// Save `query` as a personal template, declaring its placeholders as parameters.
+pub fn run_save(
+    manager: &mut ConfigManager,
+    name: &str,
+    query: &str,
+    description: Option<String>,
+    defaults: &[String],
+    replace: bool,
+) -> Result<()> {
+    let template = build_template(name, query, description, defaults)?;
+    let parameters = template.parameters.len();
+
+    let summary = manager.import_search_templates(vec![template], replace)?;
+    if !summary.skipped.is_empty() {
+        anyhow::bail!(
+            "Search template '{}' already exists (use --replace to overwrite)",
+            name
+        );
+    }
+    println!(
+        "Saved search template '{}' with {} parameter(s) to {}",
+        name,
+        parameters,
+        manager.search_templates_path().display()
+    );
+    Ok(())
+}
+
+/// Build a template from a query, applying `NAME=VALUE` defaults to its placeholders.
+fn build_template(
+    name: &str,
+    query: &str,
+    description: Option<String>,
+    defaults: &[String],
+) -> Result<SearchTemplate> {
+    let mut template = SearchTemplate::from_query(name, query)
+        .with_context(|| format!("Invalid search template '{}'", name))?;
+    template.description = description.filter(|d| !d.trim().is_empty());
+
+    for (parameter, value) in super::config::parse_params(defaults)? {
+        let declared = template
+            .parameters
+            .iter_mut()
+            .find(|p| p.name == parameter)
+            .ok_or_else(|| anyhow::anyhow!("Query has no placeholder '{}'", parameter))?;
+        declared.default = Some(value);
+    }
+    template
+        .validate()
+        .with_context(|| format!("Invalid search template '{}'", name))?;
+    Ok(template)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_template_applies_defaults() {
+        let template = build_template(
+            "User activity",
+            "index={{index}} user={{user}}",
+            Some("Per-user actions".to_string()),
+            &["index=auth".to_string()],
+        )
+        .unwrap();
+        assert_eq!(template.parameters[0].default.as_deref(), Some("auth"));
+        assert_eq!(template.parameters[1].default, None);
+        assert_eq!(template.description.as_deref(), Some("Per-user actions"));
+
+        let err =
+            build_template("x", "index={{index}}", None, &["host=web".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("no placeholder 'host'"));
+    }
+}
@@ -1,12 +1,12 @@
 //! Purpose: Provide CLI entrypoints for transaction lifecycle commands.
-//! Responsibilities: Execute begin/status/commit/rollback/archive flows via the shared transaction manager.
+//! Responsibilities: Execute begin/status/commit/rollback/archive flows via the shared transaction manager, and stage mutating commands run with `--transaction`.
 //! Non-scope: Does not implement transaction persistence/rollback internals (handled in client crate).
 //! Invariants/Assumptions: At most one pending transaction file exists per profile scope.
 
-use crate::args::TransactionCommand;
-use crate::commands::get_transaction_manager;
+use crate::args::{Commands, TransactionCommand};
+use crate::commands::{self, get_transaction_manager};
 use anyhow::{Context, Result};
-use splunk_client::transaction::Transaction;
+use splunk_client::transaction::{Transaction, TransactionOperation};
 
 pub async fn run(
     config: splunk_config::Config,
@@ -106,3 +106,265 @@ pub async fn run(
 
     Ok(())
 }
+
+/// Stage a mutating command in the pending transaction instead of running it.
+pub async fn stage(command: Commands) -> Result<()> {
+    let manager = get_transaction_manager()?;
+    let mut transaction = manager
+        .load_pending()
+        .await?
+        .unwrap_or_else(Transaction::new);
+
+    let op = match command {
+        Commands::Indexes { command } => match command {
+            commands::indexes::IndexesCommand::Create {
+                name,
+                max_data_size_mb,
+                max_hot_buckets,
+                max_warm_db_count,
+                frozen_time_period_secs,
+                home_path,
+                cold_db_path,
+                thawed_path,
+                cold_to_frozen_dir,
+            } => TransactionOperation::CreateIndex(splunk_client::CreateIndexParams {
+                name,
+                max_data_size_mb,
+                max_hot_buckets,
+                max_warm_db_count,
+                frozen_time_period_in_secs: frozen_time_period_secs,
+                home_path,
+                cold_db_path,
+                thawed_path,
+                cold_to_frozen_dir,
+            }),
+            commands::indexes::IndexesCommand::Modify {
+                name,
+                max_data_size_mb,
+                max_hot_buckets,
+                max_warm_db_count,
+                frozen_time_period_secs,
+                home_path,
+                cold_db_path,
+                thawed_path,
+                cold_to_frozen_dir,
+            } => TransactionOperation::ModifyIndex(
+                name,
+                splunk_client::ModifyIndexParams {
+                    max_data_size_mb,
+                    max_hot_buckets,
+                    max_warm_db_count,
+                    frozen_time_period_in_secs: frozen_time_period_secs,
+                    home_path,
+                    cold_db_path,
+                    thawed_path,
+                    cold_to_frozen_dir,
+                },
+            ),
+            commands::indexes::IndexesCommand::Delete { name, .. } => {
+                TransactionOperation::DeleteIndex(name)
+            }
+            _ => anyhow::bail!("Operation not supported in transactions"),
+        },
+        Commands::Users { command } => match command {
+            commands::users::UsersCommand::Create {
+                name,
+                password,
+                roles,
+                realname,
+                email,
+                default_app,
+            } => {
+                let password = match password {
+                    Some(p) => secrecy::SecretString::from(p),
+                    None => {
+                        print!("Enter password for user '{}': ", name);
+                        use std::io::Write;
+                        std::io::stdout().flush()?;
+                        let mut input = String::new();
+                        std::io::stdin().read_line(&mut input)?;
+                        secrecy::SecretString::from(input.trim().to_string())
+                    }
+                };
+                TransactionOperation::CreateUser(splunk_client::CreateUserParams {
+                    name,
+                    password,
+                    roles,
+                    realname,
+                    email,
+                    default_app,
+                })
+            }
+            commands::users::UsersCommand::Modify {
+                name,
+                password,
+                roles,
+                realname,
+                email,
+                default_app,
+            } => TransactionOperation::ModifyUser(
+                name,
+                splunk_client::ModifyUserParams {
+                    password: password.map(secrecy::SecretString::from),
+                    roles,
+                    realname,
+                    email,
+                    default_app,
+                },
+            ),
+            commands::users::UsersCommand::Delete { name, .. } => {
+                TransactionOperation::DeleteUser(name)
+            }
+            _ => anyhow::bail!("Operation not supported in transactions"),
+        },
+        Commands::Roles { command } => match command {
+            commands::roles::RolesCommand::Create {
+                name,
+                capabilities,
+                search_indexes,
+                search_filter,
+                imported_roles,
+                default_app,
+            } => TransactionOperation::CreateRole(splunk_client::CreateRoleParams {
+                name,
+                capabilities,
+                search_indexes,
+                search_filter,
+                imported_roles,
+                default_app,
+            }),
+            commands::roles::RolesCommand::Update {
+                name,
+                capabilities,
+                search_indexes,
+                search_filter,
+                imported_roles,
+                default_app,
+            } => TransactionOperation::ModifyRole(
+                name,
+                splunk_client::ModifyRoleParams {
+                    capabilities,
+                    search_indexes,
+                    search_filter,
+                    imported_roles,
+                    default_app,
+                },
+            ),
+            commands::roles::RolesCommand::Delete { name, .. } => {
+                TransactionOperation::DeleteRole(name)
+            }
+            _ => anyhow::bail!("Operation not supported in transactions"),
+        },
+        Commands::Macros { command } => match command {
+            commands::macros::MacrosCommand::Create {
+                name,
+                definition,
+                args,
+                description,
+                disabled,
+                iseval,
+                validation,
+                errormsg,
+            } => TransactionOperation::CreateMacro(splunk_client::MacroCreateParams {
+                name,
+                definition,
+                args,
+                description,
+                disabled,
+                iseval,
+                validation,
+                errormsg,
+            }),
+            commands::macros::MacrosCommand::Update {
+                name,
+                definition,
+                args,
+                description,
+                disable,
+                enable,
+                iseval,
+                no_iseval,
+                validation,
+                errormsg,
+            } => {
+                let disabled = if disable {
+                    Some(true)
+                } else if enable {
+                    Some(false)
+                } else {
+                    None
+                };
+                let iseval_flag = if iseval {
+                    Some(true)
+                } else if no_iseval {
+                    Some(false)
+                } else {
+                    None
+                };
+                TransactionOperation::UpdateMacro(
+                    name,
+                    splunk_client::MacroUpdateParams {
+                        definition,
+                        args,
+                        description,
+                        disabled,
+                        iseval: iseval_flag,
+                        validation,
+                        errormsg,
+                    },
+                )
+            }
+            commands::macros::MacrosCommand::Delete { name, .. } => {
+                TransactionOperation::DeleteMacro(name)
+            }
+            _ => anyhow::bail!("Operation not supported in transactions"),
+        },
+        Commands::SavedSearches { command } => match command {
+            commands::saved_searches::SavedSearchesCommand::Create {
+                name,
+                search,
+                description,
+                disabled,
+            } => TransactionOperation::CreateSavedSearch(
+                splunk_client::models::SavedSearchCreateParams {
+                    name,
+                    search,
+                    description,
+                    disabled,
+                },
+            ),
+            commands::saved_searches::SavedSearchesCommand::Edit {
+                name,
+                search,
+                description,
+                disabled,
+                dispatch_earliest,
+                dispatch_latest,
+            } => TransactionOperation::UpdateSavedSearch(
+                name,
+                splunk_client::models::SavedSearchUpdateParams {
+                    search,
+                    description,
+                    disabled,
+                    dispatch_earliest_time: dispatch_earliest,
+                    dispatch_latest_time: dispatch_latest,
+                },
+            ),
+            commands::saved_searches::SavedSearchesCommand::Delete { name, .. } => {
+                TransactionOperation::DeleteSavedSearch(name)
+            }
+            _ => anyhow::bail!("Operation not supported in transactions"),
+        },
+        _ => anyhow::bail!("Operation not supported in transactions"),
+    };
+
+    transaction.add_operation(op);
+    manager.save_pending(&transaction).await?;
+
+    println!(
+        "Staged operation in transaction {}. Total staged: {}",
+        transaction.id,
+        transaction.operations.len()
+    );
+    Ok(())
+}
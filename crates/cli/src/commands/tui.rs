@@ -0,0 +1,192 @@
+//! TUI launcher command implementation.
+//!
+//! Responsibilities:
+//! - Locate the `splunk-tui` binary (`SPLUNK_TUI_BIN`, next to `splunk-cli`, or on `PATH`)
+//! - Hand the CLI's resolved connection settings to the TUI via `SPLUNK_*` env vars
+//! - Forward TUI-only flags and propagate the TUI's exit status
+//!
+//! Does NOT handle:
+//! - Configuration resolution (done in `main()`; this command receives the result)
+//! - Rendering or running the TUI itself (separate binary)
+//!
+//! Invariants:
+//! - Secrets (passwords, tokens) are passed through the environment, never argv
+//! - Resolved values are set as env vars, which the TUI ranks above profile values
+//! - `SPLUNK_METRICS_BIND` is not inherited; the CLI process already holds that address
+
+use anyhow::{Context, Result};
+use secrecy::ExposeSecret;
+use splunk_config::{AuthStrategy, Config};
+use std::path::PathBuf;
+use tracing::info;
+
+/// Environment variable overriding the TUI binary to launch.
+pub const TUI_BIN_ENV: &str = "SPLUNK_TUI_BIN";
+
+/// Options forwarded to the TUI binary.
+#[derive(Debug, Default)]
+pub struct TuiOptions {
+    /// Profile name given on the CLI command line.
+    pub profile: Option<String>,
+    /// Config file path given on the CLI command line.
+    pub config_path: Option<PathBuf>,
+    /// Config encryption password given on the CLI command line.
+    pub config_password: Option<String>,
+    /// Encryption key variable name given on the CLI command line.
+    pub config_key_var: Option<String>,
+    /// OTLP endpoint for trace export.
+    pub otlp_endpoint: Option<String>,
+    /// Service name for OpenTelemetry traces.
+    pub otel_service_name: Option<String>,
+    /// TUI log directory (the TUI default applies when unset).
+    pub log_dir: Option<PathBuf>,
+    /// Disable mouse support.
+    pub no_mouse: bool,
+    /// Skip the first-run tutorial.
+    pub skip_tutorial: bool,
+    /// Ignore persisted TUI state.
+    pub fresh: bool,
+}
+
+/// Launch the TUI with the resolved configuration and wait for it to exit.
+pub async fn run(config: Config, options: TuiOptions) -> Result<()> {
+    let program = tui_binary();
+    info!("Launching {}", program.display());
+
+    let mut command = tokio::process::Command::new(&program);
+    command
+        .args(tui_args(&options))
+        .envs(config_env(&config))
+        .env_remove("SPLUNK_METRICS_BIND");
+    if let Some(password) = &options.config_password {
+        command.env("SPLUNK_CONFIG_PASSWORD", password);
+    }
+    if let Some(endpoint) = &options.otlp_endpoint {
+        command.env("SPLUNK_OTLP_ENDPOINT", endpoint);
+    }
+    if let Some(service_name) = &options.otel_service_name {
+        command.env("SPLUNK_OTEL_SERVICE_NAME", service_name);
+    }
+    // Only one auth method may reach the TUI, matching what the CLI resolved.
+    match config.auth.strategy {
+        AuthStrategy::ApiToken { .. } => command
+            .env_remove("SPLUNK_USERNAME")
+            .env_remove("SPLUNK_PASSWORD"),
+        AuthStrategy::SessionToken { .. } => command.env_remove("SPLUNK_API_TOKEN"),
+    };
+
+    let status = match command.status().await {
+        Ok(status) => status,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => anyhow::bail!(
+            "TUI binary '{}' not found; install splunk-tui alongside splunk-cli or set {}",
+            program.display(),
+            TUI_BIN_ENV
+        ),
+        Err(e) => {
+            return Err(e).with_context(|| format!("Failed to launch '{}'", program.display()));
+        }
+    };
+    if !status.success() {
+        anyhow::bail!("splunk-tui exited with {}", status);
+    }
+    Ok(())
+}
+
+/// The TUI binary: `SPLUNK_TUI_BIN`, else a sibling of this executable, else `PATH` lookup.
+fn tui_binary() -> PathBuf {
+    if let Some(path) = std::env::var_os(TUI_BIN_ENV).filter(|path| !path.is_empty()) {
+        return PathBuf::from(path);
+    }
+    let name = format!("splunk-tui{}", std::env::consts::EXE_SUFFIX);
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(|dir| dir.join(&name)))
+        .filter(|sibling| sibling.is_file())
+        .unwrap_or_else(|| PathBuf::from(name))
+}
+
+/// Command-line arguments for the TUI (non-secret values only).
+fn tui_args(options: &TuiOptions) -> Vec<String> {
+    let mut args = Vec::new();
+    if let Some(profile) = &options.profile {
+        args.extend(["--profile".to_string(), profile.clone()]);
+    }
+    if let Some(path) = &options.config_path {
+        args.extend(["--config-path".to_string(), path.display().to_string()]);
+    }
+    if let Some(var_name) = &options.config_key_var {
+        args.extend(["--config-key-var".to_string(), var_name.clone()]);
+    }
+    if let Some(dir) = &options.log_dir {
+        args.extend(["--log-dir".to_string(), dir.display().to_string()]);
+    }
+    for (enabled, flag) in [
+        (options.no_mouse, "--no-mouse"),
+        (options.skip_tutorial, "--skip-tutorial"),
+        (options.fresh, "--fresh"),
+    ] {
+        if enabled {
+            args.push(flag.to_string());
+        }
+    }
+    args
+}
+
+/// The resolved configuration as the `SPLUNK_*` variables the TUI's loader reads.
+fn config_env(config: &Config) -> Vec<(&'static str, String)> {
+    let connection = &config.connection;
+    let mut env = vec![
+        ("SPLUNK_BASE_URL", connection.base_url.clone()),
+        ("SPLUNK_SKIP_VERIFY", connection.skip_verify.to_string()),
+        ("SPLUNK_TIMEOUT", connection.timeout.as_secs().to_string()),
+        ("SPLUNK_MAX_RETRIES", connection.max_retries.to_string()),
+        (
+            "SPLUNK_SESSION_EXPIRY_BUFFER",
+            connection.session_expiry_buffer_seconds.to_string(),
+        ),
+        (
+            "SPLUNK_SESSION_TTL",
+            connection.session_ttl_seconds.to_string(),
+        ),
+        (
+            "SPLUNK_HEALTH_CHECK_INTERVAL",
+            connection.health_check_interval_seconds.to_string(),
+        ),
+        (
+            "SPLUNK_CIRCUIT_BREAKER_ENABLED",
+            connection.circuit_breaker_enabled.to_string(),
+        ),
+        (
+            "SPLUNK_CIRCUIT_FAILURE_THRESHOLD",
+            connection.circuit_failure_threshold.to_string(),
+        ),
+        (
+            "SPLUNK_CIRCUIT_FAILURE_WINDOW",
+            connection.circuit_failure_window_seconds.to_string(),
+        ),
+        (
+            "SPLUNK_CIRCUIT_RESET_TIMEOUT",
+            connection.circuit_reset_timeout_seconds.to_string(),
+        ),
+        (
+            "SPLUNK_CIRCUIT_HALF_OPEN_REQUESTS",
+            connection.circuit_half_open_requests.to_string(),
+        ),
+    ];
+    if let Some(rate_limit) = &connection.rate_limit {
+        env.push((
+            "SPLUNK_RATE_LIMIT",
+            format!("{}:{}", rate_limit.requests_per_second, rate_limit.burst),
+        ));
+    }
+    match &config.auth.strategy {
+        AuthStrategy::ApiToken { token } => {
+            env.push(("SPLUNK_API_TOKEN", token.expose_secret().to_string()));
+        }
+        AuthStrategy::SessionToken { username, password } => {
+            env.push(("SPLUNK_USERNAME", username.clone()));
+            env.push(("SPLUNK_PASSWORD", password.expose_secret().to_string()));
+        }
+    }
+    env
+}
@@ -4,6 +4,9 @@
 //! - List users with optional count limiting
 //! - Create new users with roles and authentication
 //! - Delete users with confirmation
+//! - Unlock locked-out users
+//! - Show the password/lockout policy and locked-out users (`users info`)
+//! - Find knowledge objects owned by users that no longer exist (`users orphans`)
 //! - Format output via shared formatters
 //!
 //! Does NOT handle:
@@ -20,11 +23,15 @@
 use anyhow::Result;
 use clap::Subcommand;
 use secrecy::SecretString;
+use serde::Serialize;
+use splunk_client::models::PasswordPolicy;
 use tracing::info;
 
-use crate::formatters::{OutputFormat, get_formatter, output_result};
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, get_formatter, output_result};
 use splunk_config::constants::*;
 
+mod orphans;
+
 #[derive(Debug, Subcommand)]
 pub enum UsersCommand {
     /// List all users (default)
@@ -32,6 +39,9 @@ pub enum UsersCommand {
         /// Maximum number of users to list
         #[arg(short, long, default_value_t = DEFAULT_LIST_PAGE_SIZE)]
         count: usize,
+        /// Fetch every page instead of stopping at --count
+        #[arg(long, conflicts_with = "count")]
+        all: bool,
     },
     /// Create a new user
     Create {
@@ -81,6 +91,25 @@ pub enum UsersCommand {
         #[arg(short, long)]
         force: bool,
     },
+    /// Unlock a user locked out after too many failed logins
+    Unlock {
+        /// Username (required)
+        name: String,
+    },
+    /// Show the password/lockout policy and currently locked-out users
+    Info,
+    /// Find saved searches, dashboards, macros, and lookups owned by users that no longer exist
+    Orphans {
+        /// Object kinds to check (comma-separated; all kinds when omitted)
+        #[arg(long, value_enum, value_delimiter = ',')]
+        kind: Vec<orphans::OrphanKind>,
+        /// Only check objects in this app
+        #[arg(long)]
+        app: Option<String>,
+        /// Suggest this user as the new owner of every orphan
+        #[arg(long, value_name = "USER")]
+        suggest_owner: Option<String>,
+    },
 }
 
 pub async fn run(
@@ -92,8 +121,17 @@ pub async fn run(
     no_cache: bool,
 ) -> Result<()> {
     match command {
-        UsersCommand::List { count } => {
-            run_list(config, count, output_format, output_file, cancel, no_cache).await
+        UsersCommand::List { count, all } => {
+            run_list(
+                config,
+                count,
+                all,
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
         }
         UsersCommand::Create {
             name,
@@ -140,12 +178,35 @@ pub async fn run(
         UsersCommand::Delete { name, force } => {
             run_delete(config, &name, force, cancel, no_cache).await
         }
+        UsersCommand::Unlock { name } => run_unlock(config, &name, cancel, no_cache).await,
+        UsersCommand::Info => run_info(config, output_format, output_file, cancel, no_cache).await,
+        UsersCommand::Orphans {
+            kind,
+            app,
+            suggest_owner,
+        } => {
+            let request = splunk_client::workflows::orphaned_objects::OrphanScanRequest {
+                kinds: kind.into_iter().map(Into::into).collect(),
+                app,
+                fallback_owner: suggest_owner,
+            };
+            orphans::run_orphans(
+                config,
+                request,
+                output_format,
+                output_file,
+                cancel,
+                no_cache,
+            )
+            .await
+        }
     }
 }
 
 async fn run_list(
     config: splunk_config::Config,
     count: usize,
+    all: bool,
     output_format: &str,
     output_file: Option<std::path::PathBuf>,
     cancel: &crate::cancellation::CancellationToken,
@@ -155,7 +216,16 @@ async fn run_list(
 
     let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
 
-    let users = cancellable!(client.list_users(Some(count), None), cancel)?;
+    let users = if all {
+        cancellable!(
+            crate::commands::fetch_all_pages(
+                |count, offset| client.list_users(Some(count), Some(offset))
+            ),
+            cancel
+        )?
+    } else {
+        cancellable!(client.list_users(Some(count), None), cancel)?
+    };
 
     let format = OutputFormat::from_str(output_format)?;
     let formatter = get_formatter(format);
@@ -260,3 +330,159 @@ async fn run_delete(
         Ok(())
     })
 }
+
+async fn run_unlock(
+    config: splunk_config::Config,
+    name: &str,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Unlocking user: {}", name);
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    cancellable_with!(client.unlock_user(name), cancel, |user| {
+        println!("User '{}' unlocked.", user.name);
+        Ok(())
+    })
+}
+
+/// Password policy plus the users it currently locks out.
+#[derive(Debug, Serialize)]
+struct UsersInfoOutput {
+    password_policy: PasswordPolicy,
+    locked_out_users: Vec<String>,
+}
+
+async fn run_info(
+    config: splunk_config::Config,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Getting password policy and lockout status");
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let password_policy = cancellable!(client.get_password_policy(), cancel)?;
+    let users = cancellable!(
+        crate::commands::fetch_all_pages(
+            |count, offset| client.list_users(Some(count), Some(offset))
+        ),
+        cancel
+    )?;
+    let output = UsersInfoOutput {
+        password_policy,
+        locked_out_users: users
+            .into_iter()
+            .filter(|user| user.locked_out)
+            .map(|user| user.name)
+            .collect(),
+    };
+
+    let format = OutputFormat::from_str(output_format)?;
+    let formatted = format_users_info(&output, format)?;
+    output_result(&formatted, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+fn format_users_info(info: &UsersInfoOutput, format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(info)?),
+        OutputFormat::Ndjson => Ok(format!("{}\n", serde_json::to_string(info)?)),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(info)?),
+        OutputFormat::Table => Ok(format_info_rows(info, |label, _, value| {
+            format!("{:<26}{}\n", format!("{}:", label), value)
+        })),
+        OutputFormat::Csv => Ok(format!(
+            "setting,value\n{}",
+            format_info_rows(info, |_, key, value| {
+                format!("{},{}\n", key, escape_csv(&value))
+            })
+        )),
+        OutputFormat::Xml => Ok(format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<usersInfo>\n{}</usersInfo>",
+            format_info_rows(info, |_, key, value| {
+                format!("  <{key}>{}</{key}>\n", escape_xml(&value))
+            })
+        )),
+        OutputFormat::Markdown => Ok(format!(
+            "# Password Policy\n\n| Setting | Value |\n|---------|-------|\n{}",
+            format_info_rows(info, |label, _, value| format!(
+                "| {} | {} |\n",
+                label, value
+            ))
+        )),
+    }
+}
+
+/// Render every policy setting (label, machine key, value) through `row`.
+fn format_info_rows(info: &UsersInfoOutput, row: impl Fn(&str, &str, String) -> String) -> String {
+    let policy = &info.password_policy;
+    let count = |value: Option<usize>| value.map_or_else(|| "-".to_string(), |v| v.to_string());
+    let yes_no = |value: bool| if value { "Yes" } else { "No" }.to_string();
+    let rows = [
+        ("Minimum length", "min_length", count(policy.min_length)),
+        (
+            "Minimum uppercase",
+            "min_uppercase",
+            count(policy.min_uppercase),
+        ),
+        (
+            "Minimum lowercase",
+            "min_lowercase",
+            count(policy.min_lowercase),
+        ),
+        ("Minimum digits", "min_digits", count(policy.min_digits)),
+        ("Minimum special", "min_special", count(policy.min_special)),
+        (
+            "Password expires (days)",
+            "expire_password_days",
+            count(policy.expire_password_days),
+        ),
+        (
+            "Password history",
+            "enable_password_history",
+            yes_no(policy.enable_password_history),
+        ),
+        (
+            "History count",
+            "password_history_count",
+            count(policy.password_history_count),
+        ),
+        (
+            "Lock out users",
+            "lockout_users",
+            yes_no(policy.lockout_users),
+        ),
+        (
+            "Lockout attempts",
+            "lockout_attempts",
+            count(policy.lockout_attempts),
+        ),
+        (
+            "Attempt window (min)",
+            "lockout_threshold_mins",
+            count(policy.lockout_threshold_mins),
+        ),
+        (
+            "Lockout duration (min)",
+            "lockout_mins",
+            count(policy.lockout_mins),
+        ),
+        (
+            "Locked-out users",
+            "locked_out_users",
+            if info.locked_out_users.is_empty() {
+                "none".to_string()
+            } else {
+                info.locked_out_users.join(", ")
+            },
+        ),
+    ];
+    rows.into_iter()
+        .map(|(label, key, value)| row(label, key, value))
+        .collect()
+}
@@ -0,0 +1,92 @@
+//! Orphaned knowledge object report for the users command.
+//!
+//! Responsibilities:
+//! - Run the shared orphan scan over the requested object kinds and app.
+//! - Print each orphan with a suggested new owner in every output format.
+//! - Point at the commands that perform the reassignment.
+//!
+//! Does NOT handle:
+//! - Listing or correlation (lives in `splunk-client::workflows::orphaned_objects`).
+//! - Reassigning objects (see `saved-searches bulk` and the `acl set` subcommands).
+//!
+//! Invariants:
+//! - The scan is read-only; finding orphans is not an error.
+
+use anyhow::Result;
+use clap::ValueEnum;
+use splunk_client::models::KnowledgeObjectKind;
+use splunk_client::workflows::orphaned_objects::{OrphanScanRequest, find_orphaned_objects};
+use tracing::info;
+
+use crate::formatters::{OutputFormat, output_result, render_rows};
+
+/// Report columns, in display order.
+const COLUMNS: [&str; 6] = ["kind", "name", "app", "owner", "sharing", "suggested_owner"];
+
+/// Knowledge object kinds that can be scanned for orphans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OrphanKind {
+    SavedSearch,
+    Dashboard,
+    Macro,
+    Lookup,
+}
+
+impl From<OrphanKind> for KnowledgeObjectKind {
+    fn from(kind: OrphanKind) -> Self {
+        match kind {
+            OrphanKind::SavedSearch => Self::SavedSearch,
+            OrphanKind::Dashboard => Self::Dashboard,
+            OrphanKind::Macro => Self::Macro,
+            OrphanKind::Lookup => Self::Lookup,
+        }
+    }
+}
+
+pub(super) async fn run_orphans(
+    config: splunk_config::Config,
+    request: OrphanScanRequest,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!(
+        "Scanning for orphaned knowledge objects (kinds: {:?}, app: {:?})",
+        request.kinds, request.app
+    );
+
+    let format = OutputFormat::from_str(output_format)?;
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+
+    let report = cancellable!(
+        find_orphaned_objects(&client, &request, Some(cancel)),
+        cancel
+    )?;
+
+    let rows = report
+        .orphans
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<serde_json::Result<Vec<_>>>()?;
+    let columns: Vec<String> = COLUMNS.iter().map(|c| c.to_string()).collect();
+    let output = render_rows(rows, &columns, format)?;
+    output_result(&output, format, output_file.as_ref())?;
+
+    let missing = report.missing_owners();
+    eprintln!(
+        "Found {} orphaned object(s) owned by {} missing user(s) (checked {} objects against {} users)",
+        report.orphans.len(),
+        missing.len(),
+        report.scanned,
+        report.users
+    );
+    if !missing.is_empty() {
+        eprintln!(
+            "Reassign saved searches with `splunk-cli saved-searches bulk --owner <USER> --reassign-to <NEW_OWNER>`, \
+             other objects with `splunk-cli <dashboards|macros|lookups> acl set <NAME> --new-owner <NEW_OWNER>`"
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,278 @@
+//! Webhook notifications (`--notify-webhook`) for health, `health --watch`, doctor, and monitor.
+//!
+//! Responsibilities:
+//! - POST a JSON payload to a webhook when a check's status changes; the payload
+//!   carries a `text` field so Slack incoming webhooks accept it as-is
+//! - Remember the last status per webhook, command, and server in
+//!   `webhook_state.json` so repeated runs (cron, `health --watch`) only post on changes
+//!
+//! Does NOT handle:
+//! - Running the checks or deciding their status (handled by the health, doctor,
+//!   and monitor commands)
+//! - Deduplicating `monitor` transitions (the monitor keeps its own `--state-file`)
+//!
+//! Invariants:
+//! - With no remembered status, only an unhealthy status is posted; after that, a
+//!   post is sent whenever the status differs from the remembered one
+//! - Webhook URLs are never written to disk; state is keyed by a SHA-256 of the URL
+//! - A failed POST is reported on stderr without failing the command, and the
+//!   status is not remembered, so the next run retries it
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Timeout for one webhook POST.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The status of one check run, as reported to the webhook.
+#[derive(Debug, Clone)]
+pub struct StatusEvent {
+    /// Command that ran the check (`health`, `doctor`, or `monitor`).
+    pub source: &'static str,
+    /// Check within the command, for `monitor` (e.g. `license`).
+    pub check: Option<&'static str>,
+    /// Splunk server the check ran against.
+    pub server: String,
+    /// Status word, e.g. `green`, `red`, `fail`, or `unreachable`.
+    pub status: String,
+    pub healthy: bool,
+    /// One line per degraded feature or failing check.
+    pub details: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct WebhookPayload<'a> {
+    text: String,
+    source: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    check: Option<&'a str>,
+    server: &'a str,
+    status: &'a str,
+    previous_status: Option<&'a str>,
+    healthy: bool,
+    details: &'a [String],
+    checked_at: String,
+}
+
+/// Last status per `source|server|url hash`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct WebhookState {
+    #[serde(default)]
+    last_status: BTreeMap<String, String>,
+}
+
+/// `<config dir>/splunk-tui/webhook_state.json`, when a home directory is known.
+fn state_path() -> Option<PathBuf> {
+    directories::ProjectDirs::from("", "", "splunk-tui")
+        .map(|dirs| dirs.config_dir().join("webhook_state.json"))
+}
+
+/// Post `event` to `url` if its status changed since the last post.
+///
+/// Failures are printed as warnings; notification never fails the command.
+pub async fn notify_on_change(url: &str, event: &StatusEvent) {
+    if let Err(e) = notify_with_state(url, event, state_path().as_deref()).await {
+        eprintln!("Warning: Failed to send webhook notification: {:#}", e);
+    }
+}
+
+/// Returns whether a POST was sent.
+async fn notify_with_state(
+    url: &str,
+    event: &StatusEvent,
+    state_path: Option<&Path>,
+) -> Result<bool> {
+    let mut state = state_path.map(load_state).unwrap_or_default();
+    let key = state_key(url, event);
+    let previous = state.last_status.get(&key).cloned();
+    if previous.as_deref() == Some(event.status.as_str()) {
+        return Ok(false);
+    }
+
+    let send = previous.is_some() || !event.healthy;
+    if send {
+        send_event(url, event, previous.as_deref()).await?;
+    }
+
+    state.last_status.insert(key, event.status.clone());
+    if let Some(path) = state_path {
+        save_state(path, &state)?;
+    }
+    Ok(send)
+}
+
+fn state_key(url: &str, event: &StatusEvent) -> String {
+    let url_hash = hex::encode(Sha256::digest(url.as_bytes()));
+    format!("{}|{}|{}", event.source, event.server, &url_hash[..16])
+}
+
+/// POST `event`, which changed from `previous`, without consulting the remembered state.
+pub async fn send_event(url: &str, event: &StatusEvent, previous: Option<&str>) -> Result<()> {
+    post(url, &payload(event, previous)).await
+}
+
+fn payload<'a>(event: &'a StatusEvent, previous: Option<&'a str>) -> WebhookPayload<'a> {
+    let source = match event.check {
+        Some(check) => format!("{} {}", event.source, check),
+        None => event.source.to_string(),
+    };
+    let mut text = match previous {
+        Some(previous) => format!(
+            "splunk-cli {}: {} changed from {} to {}",
+            source, event.server, previous, event.status
+        ),
+        None => format!(
+            "splunk-cli {}: {} is {}",
+            source, event.server, event.status
+        ),
+    };
+    for detail in &event.details {
+        text.push_str("\n- ");
+        text.push_str(detail);
+    }
+
+    WebhookPayload {
+        text,
+        source: event.source,
+        check: event.check,
+        server: &event.server,
+        status: &event.status,
+        previous_status: previous,
+        healthy: event.healthy,
+        details: &event.details,
+        checked_at: chrono::Utc::now().to_rfc3339(),
+    }
+}
+
+async fn post(url: &str, payload: &WebhookPayload<'_>) -> Result<()> {
+    let client = reqwest::Client::builder()
+        .timeout(WEBHOOK_TIMEOUT)
+        .build()
+        .context("Failed to build HTTP client")?;
+    client
+        .post(url)
+        .json(payload)
+        .send()
+        .await
+        .context("Failed to reach webhook")?
+        .error_for_status()
+        .context("Webhook rejected the notification")?;
+    Ok(())
+}
+
+/// Load the remembered statuses; a missing or unreadable file starts empty.
+fn load_state(path: &Path) -> WebhookState {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_state(path: &Path, state: &WebhookState) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let contents = serde_json::to_string_pretty(state)?;
+    std::fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn event(status: &str, healthy: bool) -> StatusEvent {
+        StatusEvent {
+            source: "health",
+            check: None,
+            server: "https://splunk.example.com:8089".to_string(),
+            status: status.to_string(),
+            healthy,
+            details: vec!["kvstore: red".to_string()],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_posts_only_status_changes() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/hook"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        let url = format!("{}/hook", server.uri());
+        let dir = tempfile::tempdir().unwrap();
+        let state = dir.path().join("webhook_state.json");
+        let state = Some(state.as_path());
+
+        // Healthy first run: remembered, not posted.
+        assert!(
+            !notify_with_state(&url, &event("green", true), state)
+                .await
+                .unwrap()
+        );
+        assert!(
+            notify_with_state(&url, &event("red", false), state)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !notify_with_state(&url, &event("red", false), state)
+                .await
+                .unwrap()
+        );
+        assert!(
+            notify_with_state(&url, &event("green", true), state)
+                .await
+                .unwrap()
+        );
+
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 2);
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["status"], "red");
+        assert_eq!(body["previous_status"], "green");
+        assert_eq!(
+            body["text"],
+            "splunk-cli health: https://splunk.example.com:8089 changed from green to red\n- kvstore: red"
+        );
+
+        let saved = std::fs::read_to_string(dir.path().join("webhook_state.json")).unwrap();
+        assert!(!saved.contains(&server.uri()));
+    }
+
+    #[tokio::test]
+    async fn test_failed_post_is_retried_next_run() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir().unwrap();
+        let state = dir.path().join("webhook_state.json");
+        let state = Some(state.as_path());
+
+        assert!(
+            notify_with_state(&server.uri(), &event("red", false), state)
+                .await
+                .is_err()
+        );
+        assert!(
+            notify_with_state(&server.uri(), &event("red", false), state)
+                .await
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! Whoami command implementation.
+//!
+//! Responsibilities:
+//! - Show the authenticated user, their roles and effective capabilities
+//! - Format the current context in every output format
+//!
+//! Does NOT handle:
+//! - Resolving which capabilities a role grants directly (see `roles`)
+//! - Direct REST API calls (handled by client crate)
+//!
+//! Invariants:
+//! - Capabilities are reported as returned by Splunk (already inherited through roles)
+
+use anyhow::Result;
+use splunk_client::models::CurrentContext;
+use tracing::info;
+
+use crate::formatters::{OutputFormat, escape_csv, escape_xml, output_result};
+
+pub async fn run(
+    config: splunk_config::Config,
+    output_format: &str,
+    output_file: Option<std::path::PathBuf>,
+    cancel: &crate::cancellation::CancellationToken,
+    no_cache: bool,
+) -> Result<()> {
+    info!("Getting current user context");
+
+    let client = crate::commands::build_client_from_config(&config, Some(no_cache))?;
+    let context = cancellable!(client.current_context(), cancel)?;
+
+    let format = OutputFormat::from_str(output_format)?;
+    let formatted = format_context(&context, format)?;
+    output_result(&formatted, format, output_file.as_ref())?;
+
+    Ok(())
+}
+
+/// Format the current user context in the requested output format.
+pub fn format_context(context: &CurrentContext, format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(context)?),
+        OutputFormat::Ndjson => Ok(format!("{}\n", serde_json::to_string(context)?)),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(context)?),
+        OutputFormat::Table => Ok(format_table(context)),
+        OutputFormat::Csv => Ok(format_csv(context)),
+        OutputFormat::Xml => Ok(format_xml(context)),
+        OutputFormat::Markdown => Ok(format_markdown(context)),
+    }
+}
+
+fn or_dash(value: &Option<String>) -> &str {
+    value.as_deref().unwrap_or("-")
+}
+
+fn format_table(context: &CurrentContext) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("Username:     {}\n", context.username));
+    out.push_str(&format!("Real name:    {}\n", or_dash(&context.realname)));
+    out.push_str(&format!("Email:        {}\n", or_dash(&context.email)));
+    out.push_str(&format!(
+        "Default app:  {}\n",
+        or_dash(&context.default_app)
+    ));
+    out.push_str(&format!("Roles:        {}\n", context.roles.join(", ")));
+    out.push_str(&format!("Capabilities ({}):\n", context.capabilities.len()));
+    for capability in &context.capabilities {
+        out.push_str(&format!("  {}\n", capability));
+    }
+    out
+}
+
+fn format_csv(context: &CurrentContext) -> String {
+    format!(
+        "username,realname,email,default_app,roles,capabilities\n{},{},{},{},{},{}\n",
+        escape_csv(&context.username),
+        escape_csv(context.realname.as_deref().unwrap_or("")),
+        escape_csv(context.email.as_deref().unwrap_or("")),
+        escape_csv(context.default_app.as_deref().unwrap_or("")),
+        escape_csv(&context.roles.join(";")),
+        escape_csv(&context.capabilities.join(";"))
+    )
+}
+
+fn format_xml(context: &CurrentContext) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<currentContext>\n");
+    xml.push_str(&format!(
+        "  <username>{}</username>\n",
+        escape_xml(&context.username)
+    ));
+    for (tag, value) in [
+        ("realname", &context.realname),
+        ("email", &context.email),
+        ("defaultApp", &context.default_app),
+    ] {
+        if let Some(value) = value {
+            xml.push_str(&format!("  <{tag}>{}</{tag}>\n", escape_xml(value)));
+        }
+    }
+    xml.push_str("  <roles>\n");
+    for role in &context.roles {
+        xml.push_str(&format!("    <role>{}</role>\n", escape_xml(role)));
+    }
+    xml.push_str("  </roles>\n  <capabilities>\n");
+    for capability in &context.capabilities {
+        xml.push_str(&format!(
+            "    <capability>{}</capability>\n",
+            escape_xml(capability)
+        ));
+    }
+    xml.push_str("  </capabilities>\n</currentContext>");
+    xml
+}
+
+fn format_markdown(context: &CurrentContext) -> String {
+    let mut md = format!("# {}\n\n", context.username);
+    md.push_str("| Field | Value |\n|-------|-------|\n");
+    md.push_str(&format!("| Real name | {} |\n", or_dash(&context.realname)));
+    md.push_str(&format!("| Email | {} |\n", or_dash(&context.email)));
+    md.push_str(&format!(
+        "| Default app | {} |\n",
+        or_dash(&context.default_app)
+    ));
+    md.push_str(&format!("| Roles | {} |\n", context.roles.join(", ")));
+    md.push_str("\n## Capabilities\n\n");
+    for capability in &context.capabilities {
+        md.push_str(&format!("- {}\n", capability));
+    }
+    md
+}
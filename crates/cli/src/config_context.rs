@@ -26,7 +26,7 @@ pub(crate) enum ConfigCommandContext {
     /// Includes no_cache flag for disabling client-side response caching.
     Real(Box<splunk_config::Config>, SearchDefaultConfig, bool),
     /// A placeholder config for commands that don't need real connection details.
-    /// Only valid for Config commands and multi-profile ListAll operations.
+    /// Only valid for Config commands and multi-profile operations.
     Placeholder,
 }
 
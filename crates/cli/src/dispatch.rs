@@ -23,7 +23,6 @@ use crate::args::{Cli, Commands};
 use crate::cancellation::CancellationToken;
 use crate::commands;
 use crate::config_context::ConfigCommandContext;
-use splunk_client::transaction::{Transaction, TransactionOperation};
 
 /// Dispatch CLI commands to their respective handlers.
 ///
@@ -46,7 +45,22 @@ pub(crate) async fn run_command(
     trace!("Dispatching command");
 
     if cli.transaction && !matches!(cli.command, Commands::Transaction { .. }) {
-        return stage_operation(cli.command).await;
+        return commands::transaction::stage(cli.command).await;
+    }
+
+    let profile_source = commands::multi_profile::ProfileSource::from_cli(&cli);
+    if commands::multi_profile::requested_targets(&cli.command).is_some()
+        && !matches!(cli.command, Commands::ListAll { .. })
+    {
+        trace!("Routing to multi-profile command");
+        return commands::multi_profile::run(
+            &profile_source,
+            cli.command,
+            &cli.output,
+            cli.output_file.as_ref(),
+            cancel_token,
+        )
+        .await;
     }
 
     match cli.command {
@@ -61,7 +75,8 @@ pub(crate) async fn run_command(
                 cli.config_path.clone(),
                 cli.config_password.clone(),
                 cli.config_key_var.clone(),
-            )?;
+            )
+            .await?;
         }
         Commands::Search {
             command,
@@ -72,11 +87,30 @@ pub(crate) async fn run_command(
             count,
             realtime,
             realtime_window,
+            finalize_after,
+            diff,
+            ..
         } => {
             trace!("Routing to search command");
             let (config, search_defaults, no_cache) = config.into_real_config_with_cache()?;
 
-            // Handle subcommand or backward-compatible direct query
+            // Backward compatibility: a legacy positional query runs like `search execute`
+            let command = command.or_else(|| {
+                query.map(|query| commands::search::SearchCommand::Execute {
+                    query,
+                    wait,
+                    earliest,
+                    latest,
+                    count,
+                    realtime,
+                    realtime_window,
+                    finalize_after,
+                    diff,
+                    targets: Default::default(),
+                    merge: Default::default(),
+                })
+            });
+
             match command {
                 Some(commands::search::SearchCommand::Execute {
                     query,
@@ -86,6 +120,9 @@ pub(crate) async fn run_command(
                     count,
                     realtime,
                     realtime_window,
+                    finalize_after,
+                    diff,
+                    ..
                 }) => {
                     commands::search::run(
                         config,
@@ -101,6 +138,8 @@ pub(crate) async fn run_command(
                         cancel_token,
                         realtime,
                         realtime_window,
+                        finalize_after,
+                        &diff,
                         no_cache,
                     )
                     .await?;
@@ -121,30 +160,66 @@ pub(crate) async fn run_command(
                     .await?;
                 }
                 None => {
-                    // Backward compatibility: if no subcommand, check for legacy positional query
-                    if let Some(query) = query {
-                        commands::search::run(
-                            config,
-                            query,
-                            wait,
-                            earliest.as_deref(),
-                            latest.as_deref(),
-                            count,
-                            &search_defaults,
-                            &cli.output,
-                            cli.quiet,
-                            cli.output_file.clone(),
-                            cancel_token,
-                            realtime,
-                            realtime_window,
-                            no_cache,
-                        )
-                        .await?;
-                    } else {
-                        anyhow::bail!(
-                            "Failed to execute search: either provide a query or use a subcommand (execute, validate). See 'splunk-cli search --help' for more information."
-                        );
-                    }
+                    anyhow::bail!(
+                        "Failed to execute search: either provide a query or use a subcommand (execute, validate). See 'splunk-cli search --help' for more information."
+                    );
+                }
+            }
+        }
+        Commands::Templates { command } => {
+            trace!("Routing to templates command");
+            let mut manager = commands::config::open_config_manager(
+                cli.config_path.clone(),
+                cli.config_password.clone(),
+                cli.config_key_var.clone(),
+            )?;
+            match command {
+                commands::templates::TemplatesCommand::Save {
+                    name,
+                    query,
+                    description,
+                    defaults,
+                    replace,
+                } => {
+                    commands::templates::run_save(
+                        &mut manager,
+                        &name,
+                        &query,
+                        description,
+                        &defaults,
+                        replace,
+                    )?;
+                }
+                commands::templates::TemplatesCommand::Run {
+                    name,
+                    params,
+                    wait,
+                    earliest,
+                    latest,
+                    count,
+                } => {
+                    let query = commands::config::render_named_template(&manager, &name, &params)?;
+                    let (config, search_defaults, no_cache) =
+                        config.into_real_config_with_cache()?;
+                    commands::search::run(
+                        config,
+                        query,
+                        wait,
+                        earliest.as_deref(),
+                        latest.as_deref(),
+                        count,
+                        &search_defaults,
+                        &cli.output,
+                        cli.quiet,
+                        cli.output_file.clone(),
+                        cancel_token,
+                        false,
+                        None,
+                        None,
+                        &Default::default(),
+                        no_cache,
+                    )
+                    .await?;
                 }
             }
         }
@@ -181,6 +256,15 @@ pub(crate) async fn run_command(
             .await?;
         }
         Commands::SearchPeers {
+            command: Some(command),
+            ..
+        } => {
+            trace!("Routing to search-peers management command");
+            let (config, _, no_cache) = config.into_real_config_with_cache()?;
+            commands::search_peers::run_command(config, command, cancel_token, no_cache).await?;
+        }
+        Commands::SearchPeers {
+            command: None,
             detailed,
             count,
             offset,
@@ -230,12 +314,17 @@ pub(crate) async fn run_command(
             command,
             list,
             inspect,
+            performance,
             cancel,
             delete,
             results,
             result_count,
             result_offset,
             count,
+            owner,
+            app,
+            status,
+            ..
         } => {
             trace!("Routing to jobs command");
             let (config, _, no_cache) = config.into_real_config_with_cache()?;
@@ -243,12 +332,14 @@ pub(crate) async fn run_command(
                 config,
                 list,
                 inspect,
+                performance,
                 cancel,
                 delete,
                 results,
                 result_count,
                 result_offset,
                 count,
+                splunk_client::JobFilter { owner, app, status },
                 &cli.output,
                 cli.quiet,
                 cli.output_file.clone(),
@@ -258,21 +349,33 @@ pub(crate) async fn run_command(
             )
             .await?;
         }
-        Commands::Health => {
+        Commands::Health {
+            command,
+            watch,
+            notify_webhook,
+            ..
+        } => {
             trace!("Routing to health command");
             let (config, _, no_cache) = config.into_real_config_with_cache()?;
-            commands::health::run(
+            // Boxed: the aggregate check joins several search futures, which
+            // would otherwise inflate every `run_command` future (and the REPL's
+            // stack) to its size.
+            Box::pin(commands::health::run(
                 config,
+                command,
+                watch,
+                notify_webhook.as_deref(),
                 &cli.output,
                 cli.output_file.clone(),
                 cancel_token,
                 no_cache,
-            )
+            ))
             .await?;
         }
         Commands::Doctor {
             bundle,
             include_logs,
+            notify_webhook,
         } => {
             trace!("Routing to doctor command");
             let (config, _, no_cache) = config.into_real_config_with_cache()?;
@@ -280,6 +383,7 @@ pub(crate) async fn run_command(
                 config,
                 bundle,
                 include_logs,
+                notify_webhook.as_deref(),
                 &cli.output,
                 cli.output_file.clone(),
                 cancel_token,
@@ -287,6 +391,28 @@ pub(crate) async fn run_command(
             )
             .await?;
         }
+        Commands::Monitor {
+            interval,
+            checks,
+            state_file,
+            notify_webhook,
+            license_warning,
+            license_critical,
+            once,
+        } => {
+            trace!("Routing to monitor command");
+            let (config, _, no_cache) = config.into_real_config_with_cache()?;
+            let options = commands::monitor::MonitorOptions {
+                interval,
+                checks,
+                state_file,
+                notify_webhook,
+                license_warning,
+                license_critical,
+                once,
+            };
+            commands::monitor::run(config, options, &cli.output, cancel_token, no_cache).await?;
+        }
         Commands::Kvstore { command } => {
             trace!("Routing to kvstore command");
             let (config, _, no_cache) = config.into_real_config_with_cache()?;
@@ -360,6 +486,12 @@ pub(crate) async fn run_command(
             )
             .await?;
         }
+        Commands::Whoami => {
+            trace!("Routing to whoami command");
+            let (config, _, no_cache) = config.into_real_config_with_cache()?;
+            let output_file = cli.output_file.clone();
+            commands::whoami::run(config, &cli.output, output_file, cancel_token, no_cache).await?;
+        }
         Commands::Apps { apps_command } => {
             trace!("Routing to apps command");
             let (config, _, no_cache) = config.into_real_config_with_cache()?;
@@ -373,29 +505,16 @@ pub(crate) async fn run_command(
             )
             .await?;
         }
-        Commands::ListAll {
-            resources,
-            profiles,
-            all_profiles,
-        } => {
+        Commands::ListAll { resources, targets } => {
             trace!("Routing to list-all command");
             // Determine mode: multi-profile uses ConfigManager, single-profile uses Config
-            let is_multi_profile = all_profiles || profiles.is_some();
-
-            if is_multi_profile {
+            if targets.is_set() {
                 // Multi-profile mode: build ConfigManager and route to run_multi_profile
                 // No Config is needed since each profile loads its own config
-                let config_manager = if let Some(config_path) = &cli.config_path {
-                    splunk_config::ConfigManager::new_with_path(config_path.clone())?
-                } else {
-                    splunk_config::ConfigManager::new()?
-                };
-
                 commands::list_all::run_multi_profile(
-                    config_manager,
+                    profile_source.manager()?,
                     resources,
-                    profiles,
-                    all_profiles,
+                    &targets,
                     &cli.output,
                     cli.output_file.clone(),
                     cancel_token,
@@ -442,6 +561,32 @@ pub(crate) async fn run_command(
             )
             .await?;
         }
+        Commands::Acs { command } => {
+            trace!("Routing to acs command");
+            let (config, _, no_cache) = config.into_real_config_with_cache()?;
+            commands::acs::run(
+                config,
+                command,
+                &cli.output,
+                cli.output_file.clone(),
+                cancel_token,
+                no_cache,
+            )
+            .await?;
+        }
+        Commands::Messages { command } => {
+            trace!("Routing to messages command");
+            let (config, _, no_cache) = config.into_real_config_with_cache()?;
+            commands::messages::run(
+                config,
+                command,
+                &cli.output,
+                cli.output_file.clone(),
+                cancel_token,
+                no_cache,
+            )
+            .await?;
+        }
         Commands::Inputs { command } => {
             trace!("Routing to inputs command");
             let (config, _, no_cache) = config.into_real_config_with_cache()?;
@@ -455,6 +600,58 @@ pub(crate) async fn run_command(
             )
             .await?;
         }
+        Commands::Ingestion { command } => {
+            trace!("Routing to ingestion command");
+            let (config, _, no_cache) = config.into_real_config_with_cache()?;
+            commands::ingestion::run(
+                config,
+                command,
+                &cli.output,
+                cli.output_file.clone(),
+                cancel_token,
+                no_cache,
+            )
+            .await?;
+        }
+        Commands::Sourcetypes { index } => {
+            trace!("Routing to sourcetypes command");
+            let (config, _, no_cache) = config.into_real_config_with_cache()?;
+            commands::metadata::run_sourcetypes(
+                config,
+                index,
+                &cli.output,
+                cli.output_file.clone(),
+                cancel_token,
+                no_cache,
+            )
+            .await?;
+        }
+        Commands::Extract { command } => {
+            trace!("Routing to extract command");
+            let (config, _, no_cache) = config.into_real_config_with_cache()?;
+            commands::extract::run(
+                config,
+                command,
+                &cli.output,
+                cli.output_file.clone(),
+                cancel_token,
+                no_cache,
+            )
+            .await?;
+        }
+        Commands::Metadata { command } => {
+            trace!("Routing to metadata command");
+            let (config, _, no_cache) = config.into_real_config_with_cache()?;
+            commands::metadata::run(
+                config,
+                command,
+                &cli.output,
+                cli.output_file.clone(),
+                cancel_token,
+                no_cache,
+            )
+            .await?;
+        }
         Commands::Configs { command } => {
             trace!("Routing to configs command");
             let (config, _, no_cache) = config.into_real_config_with_cache()?;
@@ -481,6 +678,12 @@ pub(crate) async fn run_command(
             )
             .await?;
         }
+        Commands::Audit {
+            command: commands::audit::AuditCommand::VerifyArchive { dir },
+        } => {
+            trace!("Routing to audit verify-archive (no config required)");
+            commands::audit::run_verify_archive(dir, &cli.output, cli.output_file.clone())?;
+        }
         Commands::Audit { command } => {
             trace!("Routing to audit command");
             let (config, _, no_cache) = config.into_real_config_with_cache()?;
@@ -509,11 +712,13 @@ pub(crate) async fn run_command(
         }
         Commands::Datamodels { command } => {
             trace!("Routing to datamodels command");
-            let (config, _, no_cache) = config.into_real_config_with_cache()?;
+            let (config, search_defaults, no_cache) = config.into_real_config_with_cache()?;
             commands::datamodels::run(
                 config,
                 command,
+                &search_defaults,
                 &cli.output,
+                cli.quiet,
                 cli.output_file.clone(),
                 cancel_token,
                 no_cache,
@@ -558,11 +763,82 @@ pub(crate) async fn run_command(
             )
             .await?;
         }
+        Commands::Hec {
+            command: commands::hec::HecCommand::Tokens { command },
+        } => {
+            trace!("Routing to HEC tokens command");
+            let (config, _, no_cache) = config.into_real_config_with_cache()?;
+            commands::hec::tokens::run(
+                config,
+                command,
+                &cli.output,
+                cli.output_file.clone(),
+                cancel_token,
+                no_cache,
+            )
+            .await?;
+        }
         Commands::Hec { command } => {
             trace!("Routing to HEC command (no config required)");
             // HEC commands don't use the standard config - they use HEC-specific URL/token
             commands::hec::run(command, &cli.output, cli.output_file.clone(), cancel_token).await?;
         }
+        Commands::Generate {
+            hec_url,
+            hec_token,
+            index,
+            sourcetype,
+            source,
+            host,
+            rate,
+            duration,
+            template,
+            seed,
+            dry_run,
+        } => {
+            trace!("Routing to generate command (no config required)");
+            let options = commands::generate::GenerateOptions {
+                hec_url,
+                hec_token,
+                index,
+                sourcetype,
+                source,
+                host,
+                rate,
+                duration,
+                template,
+                seed,
+                dry_run,
+            };
+            commands::generate::run(
+                options,
+                cli.quiet,
+                &cli.output,
+                cli.output_file.clone(),
+                cancel_token,
+            )
+            .await?;
+        }
+        Commands::Server { command } => {
+            trace!("Routing to server command");
+            let (config, _, no_cache) = config.into_real_config_with_cache()?;
+            commands::server::run(config, command, cancel_token, no_cache).await?;
+        }
+        Commands::Rest { method, path, data } => {
+            trace!("Routing to rest command");
+            let (config, _, no_cache) = config.into_real_config_with_cache()?;
+            commands::rest::run(
+                config,
+                method,
+                path,
+                data,
+                &cli.output,
+                cli.output_file.clone(),
+                cancel_token,
+                no_cache,
+            )
+            .await?;
+        }
         Commands::Shc {
             command,
             detailed,
@@ -590,6 +866,28 @@ pub(crate) async fn run_command(
             )
             .await?;
         }
+        Commands::Tui {
+            log_dir,
+            no_mouse,
+            skip_tutorial,
+            fresh,
+        } => {
+            trace!("Routing to tui launcher");
+            let config = config.into_real_config()?;
+            let options = commands::tui::TuiOptions {
+                profile: cli.profile.clone(),
+                config_path: cli.config_path.clone(),
+                config_password: cli.config_password.clone(),
+                config_key_var: cli.config_key_var.clone(),
+                otlp_endpoint: cli.otlp_endpoint.clone(),
+                otel_service_name: cli.otel_service_name.clone(),
+                log_dir,
+                no_mouse,
+                skip_tutorial,
+                fresh,
+            };
+            commands::tui::run(config, options).await?;
+        }
         Commands::Completions {
             shell,
             dynamic,
@@ -623,6 +921,43 @@ pub(crate) async fn run_command(
             // Manpage command doesn't need config - works offline
             commands::manpage::run()?;
         }
+        Commands::Apply { file, auto_approve } => {
+            trace!("Routing to apply command");
+            let (config, _, no_cache) = config.into_real_config_with_cache()?;
+            commands::apply::run(
+                config,
+                file,
+                auto_approve,
+                &cli.output,
+                cli.output_file.clone(),
+                cancel_token,
+                no_cache,
+            )
+            .await?;
+        }
+        Commands::Export {
+            types,
+            out,
+            include_internal,
+        } => {
+            trace!("Routing to export command");
+            let (config, _, no_cache) = config.into_real_config_with_cache()?;
+            commands::export::run(config, types, out, include_internal, cancel_token, no_cache)
+                .await?;
+        }
+        Commands::Repl => {
+            trace!("Routing to repl");
+            let (config, search_defaults, no_cache) = config.into_real_config_with_cache()?;
+            commands::repl::run(
+                config,
+                search_defaults,
+                &cli.output,
+                cli.output_file.clone(),
+                cli.quiet,
+                no_cache,
+            )
+            .await?;
+        }
         Commands::Transaction { command } => {
             trace!("Routing to transaction command");
             let (config, _, no_cache) = config.into_real_config_with_cache()?;
@@ -633,260 +968,3 @@ pub(crate) async fn run_command(
     trace!("Command execution completed successfully");
     Ok(())
 }
-
-async fn stage_operation(command: Commands) -> Result<()> {
-    let manager = crate::commands::get_transaction_manager()?;
-    let mut transaction = manager
-        .load_pending()
-        .await?
-        .unwrap_or_else(Transaction::new);
-
-    let op = match command {
-        Commands::Indexes { command } => match command {
-            commands::indexes::IndexesCommand::Create {
-                name,
-                max_data_size_mb,
-                max_hot_buckets,
-                max_warm_db_count,
-                frozen_time_period_secs,
-                home_path,
-                cold_db_path,
-                thawed_path,
-                cold_to_frozen_dir,
-            } => TransactionOperation::CreateIndex(splunk_client::CreateIndexParams {
-                name,
-                max_data_size_mb,
-                max_hot_buckets,
-                max_warm_db_count,
-                frozen_time_period_in_secs: frozen_time_period_secs,
-                home_path,
-                cold_db_path,
-                thawed_path,
-                cold_to_frozen_dir,
-            }),
-            commands::indexes::IndexesCommand::Modify {
-                name,
-                max_data_size_mb,
-                max_hot_buckets,
-                max_warm_db_count,
-                frozen_time_period_secs,
-                home_path,
-                cold_db_path,
-                thawed_path,
-                cold_to_frozen_dir,
-            } => TransactionOperation::ModifyIndex(
-                name,
-                splunk_client::ModifyIndexParams {
-                    max_data_size_mb,
-                    max_hot_buckets,
-                    max_warm_db_count,
-                    frozen_time_period_in_secs: frozen_time_period_secs,
-                    home_path,
-                    cold_db_path,
-                    thawed_path,
-                    cold_to_frozen_dir,
-                },
-            ),
-            commands::indexes::IndexesCommand::Delete { name, .. } => {
-                TransactionOperation::DeleteIndex(name)
-            }
-            _ => anyhow::bail!("Operation not supported in transactions"),
-        },
-        Commands::Users { command } => match command {
-            commands::users::UsersCommand::Create {
-                name,
-                password,
-                roles,
-                realname,
-                email,
-                default_app,
-            } => {
-                let password = match password {
-                    Some(p) => secrecy::SecretString::from(p),
-                    None => {
-                        print!("Enter password for user '{}': ", name);
-                        use std::io::Write;
-                        std::io::stdout().flush()?;
-                        let mut input = String::new();
-                        std::io::stdin().read_line(&mut input)?;
-                        secrecy::SecretString::from(input.trim().to_string())
-                    }
-                };
-                TransactionOperation::CreateUser(splunk_client::CreateUserParams {
-                    name,
-                    password,
-                    roles,
-                    realname,
-                    email,
-                    default_app,
-                })
-            }
-            commands::users::UsersCommand::Modify {
-                name,
-                password,
-                roles,
-                realname,
-                email,
-                default_app,
-            } => TransactionOperation::ModifyUser(
-                name,
-                splunk_client::ModifyUserParams {
-                    password: password.map(secrecy::SecretString::from),
-                    roles,
-                    realname,
-                    email,
-                    default_app,
-                },
-            ),
-            commands::users::UsersCommand::Delete { name, .. } => {
-                TransactionOperation::DeleteUser(name)
-            }
-            _ => anyhow::bail!("Operation not supported in transactions"),
-        },
-        Commands::Roles { command } => match command {
-            commands::roles::RolesCommand::Create {
-                name,
-                capabilities,
-                search_indexes,
-                search_filter,
-                imported_roles,
-                default_app,
-            } => TransactionOperation::CreateRole(splunk_client::CreateRoleParams {
-                name,
-                capabilities,
-                search_indexes,
-                search_filter,
-                imported_roles,
-                default_app,
-            }),
-            commands::roles::RolesCommand::Update {
-                name,
-                capabilities,
-                search_indexes,
-                search_filter,
-                imported_roles,
-                default_app,
-            } => TransactionOperation::ModifyRole(
-                name,
-                splunk_client::ModifyRoleParams {
-                    capabilities,
-                    search_indexes,
-                    search_filter,
-                    imported_roles,
-                    default_app,
-                },
-            ),
-            commands::roles::RolesCommand::Delete { name, .. } => {
-                TransactionOperation::DeleteRole(name)
-            }
-            _ => anyhow::bail!("Operation not supported in transactions"),
-        },
-        Commands::Macros { command } => match command {
-            commands::macros::MacrosCommand::Create {
-                name,
-                definition,
-                args,
-                description,
-                disabled,
-                iseval,
-                validation,
-                errormsg,
-            } => TransactionOperation::CreateMacro(splunk_client::MacroCreateParams {
-                name,
-                definition,
-                args,
-                description,
-                disabled,
-                iseval,
-                validation,
-                errormsg,
-            }),
-            commands::macros::MacrosCommand::Update {
-                name,
-                definition,
-                args,
-                description,
-                disable,
-                enable,
-                iseval,
-                no_iseval,
-                validation,
-                errormsg,
-            } => {
-                let disabled = if disable {
-                    Some(true)
-                } else if enable {
-                    Some(false)
-                } else {
-                    None
-                };
-                let iseval_flag = if iseval {
-                    Some(true)
-                } else if no_iseval {
-                    Some(false)
-                } else {
-                    None
-                };
-                TransactionOperation::UpdateMacro(
-                    name,
-                    splunk_client::MacroUpdateParams {
-                        definition,
-                        args,
-                        description,
-                        disabled,
-                        iseval: iseval_flag,
-                        validation,
-                        errormsg,
-                    },
-                )
-            }
-            commands::macros::MacrosCommand::Delete { name, .. } => {
-                TransactionOperation::DeleteMacro(name)
-            }
-            _ => anyhow::bail!("Operation not supported in transactions"),
-        },
-        Commands::SavedSearches { command } => match command {
-            commands::saved_searches::SavedSearchesCommand::Create {
-                name,
-                search,
-                description,
-                disabled,
-            } => TransactionOperation::CreateSavedSearch(
-                splunk_client::models::SavedSearchCreateParams {
-                    name,
-                    search,
-                    description,
-                    disabled,
-                },
-            ),
-            commands::saved_searches::SavedSearchesCommand::Edit {
-                name,
-                search,
-                description,
-                disabled,
-            } => TransactionOperation::UpdateSavedSearch(
-                name,
-                splunk_client::models::SavedSearchUpdateParams {
-                    search,
-                    description,
-                    disabled,
-                },
-            ),
-            commands::saved_searches::SavedSearchesCommand::Delete { name, .. } => {
-                TransactionOperation::DeleteSavedSearch(name)
-            }
-            _ => anyhow::bail!("Operation not supported in transactions"),
-        },
-        _ => anyhow::bail!("Operation not supported in transactions"),
-    };
-
-    transaction.add_operation(op);
-    manager.save_pending(&transaction).await?;
-
-    println!(
-        "Staged operation in transaction {}. Total staged: {}",
-        transaction.id,
-        transaction.operations.len()
-    );
-    Ok(())
-}
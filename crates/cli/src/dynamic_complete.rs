@@ -158,7 +158,7 @@ impl SavedSearchCompleter {
         cache
             .get_or_fetch("saved_searches", || async {
                 let client = splunk_client::SplunkClient::from_config(&config).await?;
-                let searches = client.list_saved_searches(Some(100), None).await?;
+                let searches = client.list_saved_searches(Some(100), None, None).await?;
                 Ok(searches.into_iter().map(|s| s.name).collect())
             })
             .await
@@ -1,19 +1,28 @@
-//! CLI exit codes for scripting and automation.
+//! CLI exit codes and error reporting for scripting and automation.
 //!
 //! Responsibilities:
 //! - Define structured exit codes that scripts can use to distinguish error types.
 //! - Map ClientError variants to appropriate exit codes.
+//! - Map `--get` extraction errors (missing value, bad path) to exit codes.
+//! - Find remediation hints for Splunk-explained failures (quota, capability, ...).
+//! - Print errors on stderr as text or as a JSON envelope (`--error-format json`).
 //!
 //! Does NOT handle:
-//! - Error message formatting (handled by anyhow Display).
+//! - Error message wording (handled by anyhow Display).
 //! - Signal handling (see cancellation.rs for SIGINT handling).
 //!
 //! Invariants:
-//! - Exit codes 1-9 are reserved for specific error categories.
+//! - Exit codes are 0 ok, 1 generic, 2 usage, 3 auth, 4 not found, 5 timeout.
 //! - Exit code 130 is reserved for SIGINT (Unix standard: 128 + SIGINT).
+//! - The JSON envelope is a single line on stderr; stdout is left untouched.
 
+use serde_json::json;
 use splunk_client::ClientError;
 
+use crate::cancellation::{is_cancelled_error, print_cancelled_message};
+use crate::commands::multi_profile::ProfileFailures;
+use crate::formatters::ExtractError;
+
 /// Structured exit codes for splunk-cli.
 ///
 /// These codes enable scripts to distinguish between different failure modes
@@ -24,43 +33,31 @@ pub enum ExitCode {
     /// Success - command completed successfully.
     Success = 0,
 
-    /// General error - unhandled or generic failure.
+    /// General error - unhandled or generic failure (including connection,
+    /// rate limiting, and server errors).
     GeneralError = 1,
 
-    /// Authentication failure - invalid credentials or expired session.
+    /// Usage error - bad arguments, invalid configuration, or a request Splunk
+    /// rejected as invalid.
     ///
-    /// Scripts should refresh credentials or prompt for re-authentication.
-    AuthenticationFailed = 2,
+    /// Scripts should fix the input and not retry the same request.
+    UsageError = 2,
 
-    /// Connection error - network, timeout, or DNS failure.
+    /// Authentication failure - invalid credentials, expired session, or
+    /// missing permissions.
     ///
-    /// Scripts may retry with exponential backoff.
-    ConnectionError = 3,
+    /// Scripts should refresh credentials or use an account with more privileges.
+    AuthenticationFailed = 3,
 
     /// Resource not found - job, index, saved search, etc.
     ///
     /// Scripts should verify resource identifiers or create missing resources.
     NotFound = 4,
 
-    /// Validation error - invalid SPL, bad parameters.
-    ///
-    /// Scripts should fix the input and not retry the same request.
-    ValidationError = 5,
-
-    /// Permission denied - insufficient privileges.
-    ///
-    /// Scripts should escalate permissions or use different credentials.
-    PermissionDenied = 6,
-
-    /// Rate limited - HTTP 429 Too Many Requests.
-    ///
-    /// Scripts should back off and retry later.
-    RateLimited = 7,
-
-    /// Service unavailable - HTTP 503, maintenance mode.
+    /// Timeout - the operation or the server took too long.
     ///
-    /// Scripts should back off and retry later.
-    ServiceUnavailable = 8,
+    /// Scripts may retry, possibly with a longer `--timeout`.
+    Timeout = 5,
 
     /// Interrupted - SIGINT/Ctrl+C (Unix standard: 128 + 2).
     ///
@@ -74,6 +71,19 @@ impl ExitCode {
     pub const fn as_i32(self) -> i32 {
         self as u8 as i32
     }
+
+    /// Stable name of the error category, used as `kind` in JSON errors.
+    pub const fn kind(self) -> &'static str {
+        match self {
+            ExitCode::Success => "success",
+            ExitCode::GeneralError => "error",
+            ExitCode::UsageError => "usage",
+            ExitCode::AuthenticationFailed => "auth",
+            ExitCode::NotFound => "not_found",
+            ExitCode::Timeout => "timeout",
+            ExitCode::Interrupted => "cancelled",
+        }
+    }
 }
 
 impl From<&ClientError> for ExitCode {
@@ -83,63 +93,88 @@ impl From<&ClientError> for ExitCode {
     /// Each ClientError variant is categorized based on how scripts should respond.
     fn from(err: &ClientError) -> Self {
         match err {
-            // Authentication errors (exit code 2)
+            // Authentication and permission errors (exit code 3)
             ClientError::AuthFailed(_) => ExitCode::AuthenticationFailed,
             ClientError::SessionExpired { .. } => ExitCode::AuthenticationFailed,
             ClientError::TokenRefreshFailed { .. } => ExitCode::AuthenticationFailed,
+            ClientError::Unauthorized(_) => ExitCode::AuthenticationFailed,
+            ClientError::RoleLacksCapability { .. } => ExitCode::AuthenticationFailed,
+            ClientError::ApiError { status: 401, .. } => ExitCode::AuthenticationFailed,
+            ClientError::ApiError { status: 403, .. } => ExitCode::AuthenticationFailed,
 
-            // Connection errors (exit code 3)
-            ClientError::ConnectionRefused(_) => ExitCode::ConnectionError,
-            ClientError::OperationTimeout { .. } => ExitCode::ConnectionError,
-            ClientError::InvalidUrl(_) => ExitCode::ConnectionError,
-            ClientError::TlsError(_) => ExitCode::ConnectionError,
+            // Usage errors (exit code 2)
+            ClientError::InvalidRequest(_) => ExitCode::UsageError,
+            ClientError::ValidationError(_) => ExitCode::UsageError,
+            ClientError::UnsupportedOnCloud { .. } => ExitCode::UsageError,
+            ClientError::InvalidUrl(_) => ExitCode::UsageError,
+            ClientError::ApiError { status: 400, .. } => ExitCode::UsageError,
 
             // Not found (exit code 4)
             ClientError::NotFound(_) => ExitCode::NotFound,
+            ClientError::IndexNotFound(_) => ExitCode::NotFound,
             ClientError::ApiError { status: 404, .. } => ExitCode::NotFound,
 
-            // Validation errors (exit code 5)
-            ClientError::InvalidRequest(_) => ExitCode::ValidationError,
-            ClientError::ValidationError(_) => ExitCode::ValidationError,
-            ClientError::InvalidResponse(_) => ExitCode::ValidationError,
-            ClientError::ApiError { status: 400, .. } => ExitCode::ValidationError,
-
-            // Authentication errors (exit code 2)
-            ClientError::ApiError { status: 401, .. } => ExitCode::AuthenticationFailed,
-
-            // Permission denied (exit code 6)
-            ClientError::Unauthorized(_) => ExitCode::PermissionDenied,
-            ClientError::ApiError { status: 403, .. } => ExitCode::PermissionDenied,
-
-            // Rate limited (exit code 7)
-            ClientError::RateLimited(_) => ExitCode::RateLimited,
-            // Also handle HTTP 429 from ApiError (when retries are exhausted)
-            ClientError::ApiError { status: 429, .. } => ExitCode::RateLimited,
-
-            // Service unavailable (exit code 8)
-            ClientError::ApiError { status: 503, .. } => ExitCode::ServiceUnavailable,
-            ClientError::ApiError { status: 502, .. } => ExitCode::ServiceUnavailable,
-            ClientError::ApiError { status: 504, .. } => ExitCode::ServiceUnavailable,
-            ClientError::CircuitBreakerOpen(_) => ExitCode::ServiceUnavailable,
+            // Timeouts (exit code 5)
+            ClientError::OperationTimeout { .. } => ExitCode::Timeout,
+            ClientError::ApiError { status: 504, .. } => ExitCode::Timeout,
+            ClientError::HttpError(e) if e.is_timeout() => ExitCode::Timeout,
 
             // Max retries exceeded - check the underlying error recursively
             ClientError::MaxRetriesExceeded(_, inner) => Self::from(inner.as_ref()),
 
-            // HttpError - check if it's a connection/timeout error
-            ClientError::HttpError(e) => {
-                if e.is_connect() || e.is_timeout() {
-                    ExitCode::ConnectionError
-                } else {
-                    ExitCode::GeneralError
-                }
-            }
+            // Connection, TLS, rate limiting, quota, license, and server errors (exit code 1)
+            ClientError::ConnectionRefused(_)
+            | ClientError::TlsError(_)
+            | ClientError::HttpError(_)
+            | ClientError::InvalidResponse(_)
+            | ClientError::RateLimited(_)
+            | ClientError::QuotaExceeded(_)
+            | ClientError::ConcurrentSearchLimit(_)
+            | ClientError::LicenseViolation(_)
+            | ClientError::CircuitBreakerOpen(_)
+            | ClientError::ApiError { .. }
+            | ClientError::TransactionRollbackError { .. } => ExitCode::GeneralError,
+        }
+    }
+}
 
-            // Default: general error
-            ClientError::ApiError { .. } => ExitCode::GeneralError,
+/// An invalid argument, option combination, or configuration.
+///
+/// Exits with [`ExitCode::UsageError`] wherever it appears in the error chain.
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub struct UsageError(pub String);
+
+/// How errors are printed on stderr (`--error-format`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ErrorFormat {
+    /// The error message followed by `hint:` lines.
+    #[default]
+    Text,
+    /// One JSON object per error: `{"error": {"kind": ..., "exit_code": ..., ...}}`.
+    Json,
+}
 
-            // Transaction rollback error - data integrity issue (exit code 1)
-            ClientError::TransactionRollbackError { .. } => ExitCode::GeneralError,
+impl ErrorFormat {
+    /// The `--error-format` requested on the command line or in
+    /// `SPLUNK_ERROR_FORMAT`, for errors reported before clap has parsed the arguments.
+    pub fn from_raw_args() -> Self {
+        let mut args = std::env::args().skip(1);
+        let mut requested = None;
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                break;
+            }
+            if arg == "--error-format" {
+                requested = args.next();
+            } else if let Some(value) = arg.strip_prefix("--error-format=") {
+                requested = Some(value.to_string());
+            }
         }
+        requested
+            .or_else(|| std::env::var("SPLUNK_ERROR_FORMAT").ok())
+            .and_then(|value| <Self as clap::ValueEnum>::from_str(&value, true).ok())
+            .unwrap_or_default()
     }
 }
 
@@ -156,16 +191,28 @@ pub trait ExitCodeExt {
 
 impl ExitCodeExt for anyhow::Error {
     fn exit_code(&self) -> ExitCode {
-        // Try to downcast to ClientError
-        if let Some(client_err) = self.downcast_ref::<ClientError>() {
-            return ExitCode::from(client_err);
+        if is_cancelled_error(self) {
+            return ExitCode::Interrupted;
         }
 
-        // Try to find ClientError in the chain
         for cause in self.chain() {
             if let Some(client_err) = cause.downcast_ref::<ClientError>() {
                 return ExitCode::from(client_err);
             }
+            if cause.is::<UsageError>() {
+                return ExitCode::UsageError;
+            }
+            if let Some(failures) = cause.downcast_ref::<ProfileFailures>() {
+                return failures.exit_code();
+            }
+            // `--get` reports a missing value as not found and a bad path as a usage error
+            if let Some(extract_err) = cause.downcast_ref::<ExtractError>() {
+                return match extract_err {
+                    ExtractError::NotFound(_) => ExitCode::NotFound,
+                    ExtractError::InvalidPath { .. } => ExitCode::UsageError,
+                    ExtractError::NotJson => ExitCode::GeneralError,
+                };
+            }
         }
 
         // Default to general error
@@ -173,6 +220,55 @@ impl ExitCodeExt for anyhow::Error {
     }
 }
 
+/// Print `err` on stderr in `format` and return the exit code for it.
+pub fn report_error(err: &anyhow::Error, format: ErrorFormat) -> ExitCode {
+    let code = err.exit_code();
+    match format {
+        ErrorFormat::Text if code == ExitCode::Interrupted => print_cancelled_message(),
+        ErrorFormat::Text => {
+            // Print the error message, then how to fix it when Splunk said why it failed
+            eprintln!("{:#}", err);
+            for hint in remediation_hints(err) {
+                eprintln!("hint: {}", hint);
+            }
+        }
+        ErrorFormat::Json => eprintln!("{}", error_envelope(err, code)),
+    }
+    code
+}
+
+/// The JSON object printed for `err` with `--error-format json`.
+///
+/// `message` is the outermost error and `causes` the errors beneath it;
+/// `http_status` and `request_id` are null unless Splunk answered the request.
+pub fn error_envelope(err: &anyhow::Error, code: ExitCode) -> serde_json::Value {
+    let failure = err
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<ClientError>())
+        .map(ClientError::to_user_facing_failure);
+    let causes: Vec<String> = err.chain().skip(1).map(|cause| cause.to_string()).collect();
+
+    json!({
+        "error": {
+            "kind": code.kind(),
+            "exit_code": code.as_i32(),
+            "message": err.to_string(),
+            "causes": causes,
+            "hints": remediation_hints(err),
+            "http_status": failure.as_ref().and_then(|f| f.status_code),
+            "request_id": failure.and_then(|f| f.request_id),
+        }
+    })
+}
+
+/// Remediation hints for the first client error in the chain, if Splunk explained it.
+pub fn remediation_hints(err: &anyhow::Error) -> Vec<String> {
+    err.chain()
+        .find_map(|cause| cause.downcast_ref::<ClientError>())
+        .map(ClientError::remediation_hints)
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -182,7 +278,10 @@ mod tests {
     fn test_exit_code_as_i32() {
         assert_eq!(ExitCode::Success.as_i32(), 0);
         assert_eq!(ExitCode::GeneralError.as_i32(), 1);
-        assert_eq!(ExitCode::AuthenticationFailed.as_i32(), 2);
+        assert_eq!(ExitCode::UsageError.as_i32(), 2);
+        assert_eq!(ExitCode::AuthenticationFailed.as_i32(), 3);
+        assert_eq!(ExitCode::NotFound.as_i32(), 4);
+        assert_eq!(ExitCode::Timeout.as_i32(), 5);
         assert_eq!(ExitCode::Interrupted.as_i32(), 130);
     }
 
@@ -203,13 +302,13 @@ mod tests {
     #[test]
     fn test_from_client_error_unauthorized() {
         let err = ClientError::Unauthorized("access denied".to_string());
-        assert_eq!(ExitCode::from(&err), ExitCode::PermissionDenied);
+        assert_eq!(ExitCode::from(&err), ExitCode::AuthenticationFailed);
     }
 
     #[test]
     fn test_from_client_error_connection_refused() {
         let err = ClientError::ConnectionRefused("localhost:8089".to_string());
-        assert_eq!(ExitCode::from(&err), ExitCode::ConnectionError);
+        assert_eq!(ExitCode::from(&err), ExitCode::GeneralError);
     }
 
     #[test]
@@ -218,7 +317,7 @@ mod tests {
             operation: "fetch_cluster",
             timeout: Duration::from_secs(30),
         };
-        assert_eq!(ExitCode::from(&err), ExitCode::ConnectionError);
+        assert_eq!(ExitCode::from(&err), ExitCode::Timeout);
     }
 
     #[test]
@@ -230,7 +329,31 @@ mod tests {
     #[test]
     fn test_from_client_error_invalid_request() {
         let err = ClientError::InvalidRequest("bad parameter".to_string());
-        assert_eq!(ExitCode::from(&err), ExitCode::ValidationError);
+        assert_eq!(ExitCode::from(&err), ExitCode::UsageError);
+    }
+
+    #[test]
+    fn test_from_client_error_splunk_explained_failures() {
+        let err = ClientError::RoleLacksCapability {
+            capability: "edit_indexes".to_string(),
+            message: "requires capability: edit_indexes".to_string(),
+        };
+        assert_eq!(ExitCode::from(&err), ExitCode::AuthenticationFailed);
+        let err = ClientError::IndexNotFound("web".to_string());
+        assert_eq!(ExitCode::from(&err), ExitCode::NotFound);
+        let err = ClientError::ConcurrentSearchLimit("limit reached".to_string());
+        assert_eq!(ExitCode::from(&err), ExitCode::GeneralError);
+    }
+
+    #[test]
+    fn test_remediation_hints_from_context_chain() {
+        let err = anyhow::Error::new(ClientError::IndexNotFound("web".to_string()))
+            .context("Failed to send events");
+        let hints = remediation_hints(&err);
+        assert!(hints.iter().any(|hint| hint.contains("indexes create web")));
+
+        let err = anyhow::anyhow!("plain failure");
+        assert!(remediation_hints(&err).is_empty());
     }
 
     #[test]
@@ -241,7 +364,7 @@ mod tests {
             message: "Forbidden".to_string(),
             request_id: None,
         };
-        assert_eq!(ExitCode::from(&err), ExitCode::PermissionDenied);
+        assert_eq!(ExitCode::from(&err), ExitCode::AuthenticationFailed);
     }
 
     #[test]
@@ -252,31 +375,38 @@ mod tests {
             message: "Too Many Requests".to_string(),
             request_id: None,
         };
-        assert_eq!(ExitCode::from(&err), ExitCode::RateLimited);
+        assert_eq!(ExitCode::from(&err), ExitCode::GeneralError);
     }
 
     #[test]
-    fn test_from_client_error_api_error_503() {
+    fn test_from_client_error_api_error_503_and_504() {
         let err = ClientError::ApiError {
             status: 503,
             url: "https://localhost:8089".to_string(),
             message: "Service Unavailable".to_string(),
             request_id: None,
         };
-        assert_eq!(ExitCode::from(&err), ExitCode::ServiceUnavailable);
+        assert_eq!(ExitCode::from(&err), ExitCode::GeneralError);
+        let err = ClientError::ApiError {
+            status: 504,
+            url: "https://localhost:8089".to_string(),
+            message: "Gateway Timeout".to_string(),
+            request_id: None,
+        };
+        assert_eq!(ExitCode::from(&err), ExitCode::Timeout);
     }
 
     #[test]
     fn test_from_client_error_rate_limited() {
         let err = ClientError::RateLimited(Some(Duration::from_secs(60)));
-        assert_eq!(ExitCode::from(&err), ExitCode::RateLimited);
+        assert_eq!(ExitCode::from(&err), ExitCode::GeneralError);
     }
 
     #[test]
     fn test_from_client_error_max_retries_exceeded() {
-        let inner = ClientError::ConnectionRefused("localhost:8089".to_string());
+        let inner = ClientError::AuthFailed("bad credentials".to_string());
         let err = ClientError::MaxRetriesExceeded(3, Box::new(inner));
-        assert_eq!(ExitCode::from(&err), ExitCode::ConnectionError);
+        assert_eq!(ExitCode::from(&err), ExitCode::AuthenticationFailed);
     }
 
     #[test]
@@ -286,4 +416,47 @@ mod tests {
         let outer = ClientError::MaxRetriesExceeded(3, Box::new(middle));
         assert_eq!(ExitCode::from(&outer), ExitCode::NotFound);
     }
+
+    #[test]
+    fn test_extract_error_exit_codes() {
+        let missing = anyhow::Error::new(ExtractError::NotFound(".health".to_string()));
+        assert_eq!(missing.exit_code(), ExitCode::NotFound);
+
+        let not_json = anyhow::Error::new(ExtractError::NotJson);
+        assert_eq!(not_json.exit_code(), ExitCode::GeneralError);
+    }
+
+    #[test]
+    fn test_usage_and_cancelled_errors_anywhere_in_chain() {
+        let usage = anyhow::Error::new(UsageError("bad flag".to_string())).context("Failed");
+        assert_eq!(usage.exit_code(), ExitCode::UsageError);
+
+        let cancelled = anyhow::Error::new(crate::cancellation::Cancelled);
+        assert_eq!(cancelled.exit_code(), ExitCode::Interrupted);
+    }
+
+    #[test]
+    fn test_error_envelope_includes_status_causes_and_hints() {
+        let err = anyhow::Error::new(ClientError::ApiError {
+            status: 404,
+            url: "https://localhost:8089/services/saved/searches/nope".to_string(),
+            message: "Not Found".to_string(),
+            request_id: Some("req-1".to_string()),
+        })
+        .context("Failed to get saved search");
+        let envelope = error_envelope(&err, err.exit_code());
+        let error = &envelope["error"];
+        assert_eq!(error["kind"], "not_found");
+        assert_eq!(error["exit_code"], 4);
+        assert_eq!(error["message"], "Failed to get saved search");
+        assert_eq!(error["causes"].as_array().unwrap().len(), 1);
+        assert_eq!(error["http_status"], 404);
+        assert_eq!(error["request_id"], "req-1");
+        assert!(error["hints"].as_array().unwrap().is_empty());
+
+        let err = anyhow::anyhow!("plain failure");
+        let envelope = error_envelope(&err, err.exit_code());
+        assert_eq!(envelope["error"]["kind"], "error");
+        assert!(envelope["error"]["http_status"].is_null());
+    }
 }
@@ -442,6 +442,25 @@ pub fn output_result(
     format: crate::formatters::OutputFormat,
     output_file: Option<&std::path::PathBuf>,
 ) -> Result<()> {
+    // `--fields` / `--query` reshape the JSON and render the format the user asked for
+    let filtered;
+    let (output, format) = match crate::formatters::installed_output_filter() {
+        Some(filter) if format == crate::formatters::OutputFormat::Json => {
+            filtered = filter.apply(output)?;
+            (filtered.as_str(), filter.format())
+        }
+        _ => (output, format),
+    };
+
+    let extracted;
+    let output = match crate::formatters::installed_value_path() {
+        Some(value_path) if format == crate::formatters::OutputFormat::Json => {
+            extracted = value_path.extract(output)?;
+            extracted.as_str()
+        }
+        _ => output,
+    };
+
     if let Some(path) = output_file {
         write_to_file(output, path)
             .with_context(|| format!("Failed to write output to {}", path.display()))?;
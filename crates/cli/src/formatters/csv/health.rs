@@ -1,7 +1,7 @@
 //! Health CSV formatter.
 //!
 //! Responsibilities:
-//! - Format health check and KV store status as CSV.
+//! - Format health check, scheduler health, queue health, security health, disk usage, search load, resource usage, and KV store status as CSV.
 //!
 //! Does NOT handle:
 //! - Other resource types.
@@ -9,7 +9,10 @@
 use crate::formatters::DiagnosticReport;
 use crate::formatters::common::{build_csv_header, build_csv_row, escape_csv};
 use anyhow::Result;
-use splunk_client::{HealthCheckOutput, KvStoreStatus};
+use splunk_client::{
+    DiskUsage, HealthCheckOutput, KvStoreStatus, QueueHealth, ResourceUsage, SchedulerHealth,
+    SearchLoad, SecurityHealth,
+};
 
 /// Format health check as CSV.
 pub fn format_health(health: &HealthCheckOutput) -> Result<String> {
@@ -25,6 +28,16 @@ pub fn format_health(health: &HealthCheckOutput) -> Result<String> {
         "kvstore_status",
         "log_parsing_healthy",
         "log_parsing_errors",
+        "scheduler_skipped",
+        "scheduler_failed",
+        "queues_healthy",
+        "queue_blocked_samples",
+        "security_healthy",
+        "failed_logins",
+        "fullest_partition",
+        "fullest_partition_used_pct",
+        "searches_running",
+        "searches_queued",
     ]));
 
     // Data row
@@ -71,6 +84,51 @@ pub fn format_health(health: &HealthCheckOutput) -> Result<String> {
         .map(|lp| lp.total_errors.to_string())
         .unwrap_or_else(|| "N/A".to_string());
 
+    let (scheduler_skipped, scheduler_failed) = health
+        .scheduler_health
+        .as_ref()
+        .map(|s| (s.skipped.to_string(), s.failed.to_string()))
+        .unwrap_or_else(|| ("N/A".to_string(), "N/A".to_string()));
+
+    let queues_healthy = health
+        .queue_health
+        .as_ref()
+        .map(|q| if q.is_healthy { "Yes" } else { "No" })
+        .unwrap_or("N/A");
+    let queue_blocked = health
+        .queue_health
+        .as_ref()
+        .map(|q| q.blocked_count.to_string())
+        .unwrap_or_else(|| "N/A".to_string());
+
+    let security_healthy = health
+        .security_health
+        .as_ref()
+        .map(|s| if s.is_healthy { "Yes" } else { "No" })
+        .unwrap_or("N/A");
+    let failed_logins = health
+        .security_health
+        .as_ref()
+        .map(|s| s.total_failures.to_string())
+        .unwrap_or_else(|| "N/A".to_string());
+
+    let (fullest_partition, fullest_used_pct) = health
+        .disk_partition
+        .as_ref()
+        .map(|p| {
+            (
+                p.mount_point.as_str(),
+                format!("{:.1}", p.used_percent().unwrap_or_default()),
+            )
+        })
+        .unwrap_or(("N/A", "N/A".to_string()));
+
+    let (searches_running, searches_queued) = health
+        .search_load
+        .as_ref()
+        .map(|l| (l.running.to_string(), l.queued.to_string()))
+        .unwrap_or_else(|| ("N/A".to_string(), "N/A".to_string()));
+
     let row = vec![
         escape_csv(server_name),
         escape_csv(version),
@@ -80,12 +138,242 @@ pub fn format_health(health: &HealthCheckOutput) -> Result<String> {
         escape_csv(&kv_status),
         escape_csv(parsing_healthy),
         escape_csv(&parsing_errors),
+        escape_csv(&scheduler_skipped),
+        escape_csv(&scheduler_failed),
+        escape_csv(queues_healthy),
+        escape_csv(&queue_blocked),
+        escape_csv(security_healthy),
+        escape_csv(&failed_logins),
+        escape_csv(fullest_partition),
+        escape_csv(&fullest_used_pct),
+        escape_csv(&searches_running),
+        escape_csv(&searches_queued),
     ];
     output.push_str(&build_csv_row(&row));
 
     Ok(output)
 }
 
+/// Format skipped/failed scheduled searches as CSV, one row per search.
+pub fn format_scheduler_health(health: &SchedulerHealth) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str(&build_csv_header(&[
+        "app",
+        "name",
+        "owner",
+        "runs",
+        "succeeded",
+        "skipped",
+        "failed",
+        "last_reason",
+        "last_run",
+    ]));
+
+    for search in &health.searches {
+        let row = vec![
+            escape_csv(&search.app),
+            escape_csv(&search.name),
+            escape_csv(&search.owner),
+            escape_csv(&search.runs.to_string()),
+            escape_csv(&search.succeeded.to_string()),
+            escape_csv(&search.skipped.to_string()),
+            escape_csv(&search.failed.to_string()),
+            escape_csv(search.last_reason.as_deref().unwrap_or("N/A")),
+            escape_csv(search.last_run.as_deref().unwrap_or("N/A")),
+        ];
+        output.push_str(&build_csv_row(&row));
+    }
+
+    Ok(output)
+}
+
+/// Format ingestion queue health as CSV, one row per queue per pipeline.
+pub fn format_queue_health(health: &QueueHealth) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str(&build_csv_header(&[
+        "host",
+        "pipeline",
+        "queue",
+        "avg_fill_pct",
+        "max_fill_pct",
+        "blocked_count",
+        "samples",
+        "sustained_full",
+    ]));
+
+    for queue in &health.queues {
+        let row = vec![
+            escape_csv(&queue.host),
+            escape_csv(&queue.pipeline),
+            escape_csv(&queue.queue),
+            escape_csv(&queue.avg_fill_pct.to_string()),
+            escape_csv(&queue.max_fill_pct.to_string()),
+            escape_csv(&queue.blocked_count.to_string()),
+            escape_csv(&queue.samples.to_string()),
+            escape_csv(&queue.is_sustained_full().to_string()),
+        ];
+        output.push_str(&build_csv_row(&row));
+    }
+
+    Ok(output)
+}
+
+/// Format failed logins as CSV, one row per source IP.
+pub fn format_security_health(health: &SecurityHealth) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str(&build_csv_header(&[
+        "src_ip",
+        "failures",
+        "distinct_users",
+        "first_seen",
+        "last_seen",
+        "flagged",
+    ]));
+
+    for source in &health.sources {
+        let row = vec![
+            escape_csv(&source.src_ip),
+            escape_csv(&source.failures.to_string()),
+            escape_csv(&source.distinct_users.to_string()),
+            escape_csv(&source.first_seen),
+            escape_csv(&source.last_seen),
+            escape_csv(&health.is_flagged(source).to_string()),
+        ];
+        output.push_str(&build_csv_row(&row));
+    }
+
+    Ok(output)
+}
+
+/// Format disk usage as CSV, one row per partition, fullest first.
+pub fn format_disk_usage(usage: &DiskUsage) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str(&build_csv_header(&[
+        "server_name",
+        "server_roles",
+        "mount_point",
+        "fs_type",
+        "capacity_mb",
+        "free_mb",
+        "used_pct",
+        "level",
+    ]));
+
+    let roles = usage.server_roles.join(";");
+    for partition in &usage.partitions {
+        let row = vec![
+            escape_csv(&usage.server_name),
+            escape_csv(&roles),
+            escape_csv(&partition.mount_point),
+            escape_csv(partition.fs_type.as_deref().unwrap_or("")),
+            escape_csv(&format!("{:.0}", partition.capacity_mb)),
+            escape_csv(&format!("{:.0}", partition.free_mb)),
+            escape_csv(
+                &partition
+                    .used_percent()
+                    .map(|pct| format!("{:.1}", pct))
+                    .unwrap_or_default(),
+            ),
+            escape_csv(usage.level(partition).as_str()),
+        ];
+        output.push_str(&build_csv_row(&row));
+    }
+
+    Ok(output)
+}
+
+/// Format search load as CSV, one row per user with searches in flight,
+/// busiest first; the instance limits repeat on every row.
+pub fn format_search_load(load: &SearchLoad) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str(&build_csv_header(&[
+        "user",
+        "running",
+        "queued",
+        "realtime",
+        "quota",
+        "at_quota",
+        "max_hist_searches",
+        "max_rt_searches",
+    ]));
+
+    let opt = |value: Option<u64>| value.map(|v| v.to_string()).unwrap_or_default();
+    for user in &load.users {
+        let row = vec![
+            escape_csv(&user.user),
+            escape_csv(&user.running.to_string()),
+            escape_csv(&user.queued.to_string()),
+            escape_csv(&user.realtime.to_string()),
+            escape_csv(&opt(user.quota)),
+            escape_csv(&user.is_at_quota().to_string()),
+            escape_csv(&opt(load.limits.max_hist_searches)),
+            escape_csv(&opt(load.limits.max_rt_searches)),
+        ];
+        output.push_str(&build_csv_row(&row));
+    }
+
+    Ok(output)
+}
+
+/// Format resource usage as CSV: one `host` row per host, then one `process`
+/// row per top process.
+pub fn format_resource_usage(usage: &ResourceUsage) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str(&build_csv_header(&[
+        "kind",
+        "host",
+        "process",
+        "pid",
+        "process_type",
+        "cpu_pct",
+        "mem_used_mb",
+        "mem_total_mb",
+        "iowait_pct",
+    ]));
+
+    for host in &usage.hosts {
+        let row = vec![
+            escape_csv("host"),
+            escape_csv(&host.host),
+            String::new(),
+            String::new(),
+            String::new(),
+            escape_csv(&host.cpu_pct.to_string()),
+            escape_csv(&host.mem_used_mb.to_string()),
+            escape_csv(&host.mem_total_mb.to_string()),
+            escape_csv(
+                &host
+                    .iowait_pct
+                    .map(|pct| pct.to_string())
+                    .unwrap_or_else(|| "N/A".to_string()),
+            ),
+        ];
+        output.push_str(&build_csv_row(&row));
+    }
+    for process in &usage.processes {
+        let row = vec![
+            escape_csv("process"),
+            escape_csv(&process.host),
+            escape_csv(&process.process),
+            escape_csv(&process.pid),
+            escape_csv(&process.process_type),
+            escape_csv(&process.cpu_pct.to_string()),
+            escape_csv(&process.mem_used_mb.to_string()),
+            String::new(),
+            String::new(),
+        ];
+        output.push_str(&build_csv_row(&row));
+    }
+
+    Ok(output)
+}
+
 /// Format KV store status as CSV.
 pub fn format_kvstore_status(status: &KvStoreStatus) -> Result<String> {
     let mut output = String::new();
@@ -0,0 +1,246 @@
+//! Single-value extraction from JSON output (`--get`).
+//!
+//! Responsibilities:
+//! - Parse `--get` paths: dotted paths (`.splunkd_health.health`, `[0].name`,
+//!   `.["key.with.dots"]`) and JSON pointers (`/splunkd_health/health`).
+//! - Evaluate a path against a command's JSON output and print the bare value.
+//!
+//! Does NOT handle:
+//! - Filters, wildcards, or expressions beyond a single path (pipe to `jq` for those).
+//! - Choosing the output format (`--get` forces `--output json` in `main()`).
+//!
+//! Invariants:
+//! - Strings print without quotes; numbers and booleans print as JSON scalars;
+//!   objects and arrays print as pretty JSON.
+//! - A missing or null value is an `ExtractError::NotFound` (exit code 4), so scripts
+//!   can distinguish "no value" from an empty string.
+
+use std::sync::OnceLock;
+
+use serde_json::Value;
+
+/// Path installed by `--get`, applied to every JSON output of the command.
+static VALUE_PATH: OnceLock<ValuePath> = OnceLock::new();
+
+/// One step of a value path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PathSegment {
+    /// Object key (also an array index when it parses as one, for JSON pointers)
+    Key(String),
+    /// Array index
+    Index(usize),
+}
+
+/// A parsed `--get` path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValuePath {
+    expr: String,
+    segments: Vec<PathSegment>,
+}
+
+/// Errors from parsing or applying a `--get` path.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ExtractError {
+    #[error("Invalid path '{path}': {reason}")]
+    InvalidPath { path: String, reason: String },
+
+    #[error("No value at '{0}'")]
+    NotFound(String),
+
+    #[error("--get needs JSON output, but the command output is not JSON")]
+    NotJson,
+}
+
+impl ValuePath {
+    /// Parse a dotted path or a JSON pointer (leading `/`); `.` selects the whole document.
+    pub fn parse(expr: &str) -> Result<Self, ExtractError> {
+        let expr = expr.trim();
+        let invalid = |reason: &str| ExtractError::InvalidPath {
+            path: expr.to_string(),
+            reason: reason.to_string(),
+        };
+        if expr.is_empty() {
+            return Err(invalid("path is empty"));
+        }
+
+        let segments = if let Some(pointer) = expr.strip_prefix('/') {
+            pointer
+                .split('/')
+                .map(|token| PathSegment::Key(token.replace("~1", "/").replace("~0", "~")))
+                .collect()
+        } else {
+            parse_dotted(expr.strip_prefix('.').unwrap_or(expr)).map_err(|r| invalid(&r))?
+        };
+
+        Ok(Self {
+            expr: expr.to_string(),
+            segments,
+        })
+    }
+
+    /// The value at this path, if present.
+    pub fn select<'a>(&self, value: &'a Value) -> Option<&'a Value> {
+        self.segments
+            .iter()
+            .try_fold(value, |current, segment| match (segment, current) {
+                (PathSegment::Key(key), Value::Object(map)) => map.get(key),
+                (PathSegment::Key(key), Value::Array(items)) => {
+                    key.parse::<usize>().ok().and_then(|i| items.get(i))
+                }
+                (PathSegment::Index(i), Value::Array(items)) => items.get(*i),
+                _ => None,
+            })
+    }
+
+    /// Extract the value at this path from JSON command output, formatted for printing.
+    pub fn extract(&self, output: &str) -> Result<String, ExtractError> {
+        let document: Value = serde_json::from_str(output).map_err(|_| ExtractError::NotJson)?;
+        match self.select(&document) {
+            None | Some(Value::Null) => Err(ExtractError::NotFound(self.expr.clone())),
+            Some(value) => Ok(format!("{}\n", bare_value(value))),
+        }
+    }
+}
+
+/// Clap value parser for `--get`.
+pub fn parse_value_path(expr: &str) -> Result<ValuePath, String> {
+    ValuePath::parse(expr).map_err(|e| e.to_string())
+}
+
+/// Apply `path` to every JSON output of this process.
+pub fn install_value_path(path: ValuePath) {
+    let _ = VALUE_PATH.set(path);
+}
+
+/// The path installed by `--get`, if any.
+pub fn installed_value_path() -> Option<&'static ValuePath> {
+    VALUE_PATH.get()
+}
+
+/// Strings without quotes, scalars as JSON, containers as pretty JSON.
+fn bare_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Array(_) | Value::Object(_) => {
+            serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string())
+        }
+        _ => value.to_string(),
+    }
+}
+
+/// Parse `a.b[0].["c.d"]` (without the optional leading `.`).
+fn parse_dotted(mut rest: &str) -> Result<Vec<PathSegment>, String> {
+    let mut segments = Vec::new();
+    while !rest.is_empty() {
+        if let Some(bracket) = rest.strip_prefix('[') {
+            let (segment, after) = parse_bracket(bracket)?;
+            segments.push(segment);
+            rest = after;
+        } else {
+            let end = rest.find(['.', '[']).unwrap_or(rest.len());
+            if end == 0 {
+                return Err("empty key".to_string());
+            }
+            segments.push(PathSegment::Key(rest[..end].to_string()));
+            rest = &rest[end..];
+        }
+
+        if let Some(after) = rest.strip_prefix('.') {
+            if after.is_empty() {
+                return Err("path ends with '.'".to_string());
+            }
+            rest = after;
+        } else if !rest.is_empty() && !rest.starts_with('[') {
+            return Err(format!("unexpected '{}'", rest));
+        }
+    }
+    Ok(segments)
+}
+
+/// Parse the inside of `[0]` or `["key"]`, returning the segment and the text after `]`.
+fn parse_bracket(text: &str) -> Result<(PathSegment, &str), String> {
+    if text.starts_with('"') {
+        let end = text
+            .find("\"]")
+            .filter(|&end| end > 0)
+            .ok_or_else(|| "unterminated [\"key\"]".to_string())?;
+        let key: String = serde_json::from_str(&text[..=end])
+            .map_err(|_| format!("invalid quoted key {}", &text[..=end]))?;
+        return Ok((PathSegment::Key(key), &text[end + 2..]));
+    }
+
+    let end = text
+        .find(']')
+        .ok_or_else(|| "unterminated [index]".to_string())?;
+    let index = text[..end]
+        .trim()
+        .parse::<usize>()
+        .map_err(|_| format!("invalid array index '{}'", &text[..end]))?;
+    Ok((PathSegment::Index(index), &text[end + 1..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn get(expr: &str, document: &Value) -> Result<String, ExtractError> {
+        ValuePath::parse(expr)?.extract(&document.to_string())
+    }
+
+    #[test]
+    fn test_dotted_paths_and_pointers() {
+        let document = json!({
+            "splunkd_health": { "health": "green", "features": { "a.b": { "healthy": true } } },
+            "license_usage": [{ "quota": 1024 }, { "quota": 2048 }]
+        });
+
+        assert_eq!(get(".splunkd_health.health", &document).unwrap(), "green\n");
+        assert_eq!(get("splunkd_health.health", &document).unwrap(), "green\n");
+        assert_eq!(get("/splunkd_health/health", &document).unwrap(), "green\n");
+        assert_eq!(get(".license_usage[1].quota", &document).unwrap(), "2048\n");
+        assert_eq!(get("/license_usage/0/quota", &document).unwrap(), "1024\n");
+        assert_eq!(
+            get(r#".splunkd_health.features["a.b"].healthy"#, &document).unwrap(),
+            "true\n"
+        );
+        assert_eq!(
+            get(".license_usage[0]", &document).unwrap(),
+            "{\n  \"quota\": 1024\n}\n"
+        );
+        assert!(get(".", &document).unwrap().starts_with('{'));
+    }
+
+    #[test]
+    fn test_missing_null_and_non_json() {
+        let document = json!({ "health": null, "items": [] });
+        assert_eq!(
+            get(".health", &document),
+            Err(ExtractError::NotFound(".health".to_string()))
+        );
+        assert!(matches!(
+            get(".items[0]", &document),
+            Err(ExtractError::NotFound(_))
+        ));
+        assert!(matches!(
+            get(".health.status", &document),
+            Err(ExtractError::NotFound(_))
+        ));
+
+        let path = ValuePath::parse(".health").unwrap();
+        assert_eq!(path.extract("Health: OK\n"), Err(ExtractError::NotJson));
+    }
+
+    #[test]
+    fn test_invalid_paths() {
+        for expr in ["", ".a..b", ".a.", "[x]", "[0", r#"["a"#, "a[0]x"] {
+            assert!(
+                matches!(
+                    ValuePath::parse(expr),
+                    Err(ExtractError::InvalidPath { .. })
+                ),
+                "{expr}"
+            );
+        }
+    }
+}
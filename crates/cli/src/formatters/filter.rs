@@ -0,0 +1,360 @@
+//! Field selection and JSONPath filtering of command output (`--fields`, `--query`).
+//!
+//! Responsibilities:
+//! - Parse `--query` JSONPath expressions (RFC 9535) and `--fields` lists.
+//! - Filter and project a command's JSON output, then render it in the format the
+//!   user asked for.
+//!
+//! Does NOT handle:
+//! - Making commands produce JSON (`main()` forces `--output json` while a filter
+//!   is installed, as it does for `--get`).
+//! - Command-specific table layouts: filtered output is rendered generically,
+//!   one row per element and one column per field.
+//!
+//! Invariants:
+//! - `--query` runs before `--fields`, and its result is always a list.
+//! - `--fields` keeps the listed order; a field missing from a row is null
+//!   (empty in tabular formats).
+//! - Fields accept the same paths as `--get`, so nested values can be selected
+//!   (`--fields name,content.disabled`).
+
+use std::sync::RwLock;
+
+use anyhow::Result;
+use serde_json::{Map, Value};
+use serde_json_path::JsonPath;
+
+use super::common::{escape_csv, format_json_value};
+use super::{Formatter, OutputFormat, ValuePath, XmlFormatter};
+
+/// Filter installed for the current command, if any.
+///
+/// A lock rather than a `OnceLock` so the REPL can change it between commands.
+static OUTPUT_FILTER: RwLock<Option<OutputFilter>> = RwLock::new(None);
+
+/// A parsed `--query` expression.
+#[derive(Debug, Clone)]
+pub struct OutputQuery {
+    path: JsonPath,
+}
+
+impl OutputQuery {
+    /// Parse a JSONPath expression such as `$[?@.totalEventCount > 0]`.
+    pub fn parse(expr: &str) -> Result<Self> {
+        let expr = expr.trim();
+        let path = JsonPath::parse(expr)
+            .map_err(|e| anyhow::anyhow!("Invalid JSONPath '{}': {}", expr, e))?;
+        Ok(Self { path })
+    }
+}
+
+/// Clap value parser for `--query`.
+pub fn parse_output_query(expr: &str) -> Result<OutputQuery, String> {
+    OutputQuery::parse(expr).map_err(|e| e.to_string())
+}
+
+/// `--fields` and `--query`, plus the format to render the result in.
+#[derive(Debug, Clone)]
+pub struct OutputFilter {
+    fields: Vec<(String, ValuePath)>,
+    query: Option<OutputQuery>,
+    format: OutputFormat,
+}
+
+impl OutputFilter {
+    /// Build a filter; `format` is the output format the user asked for.
+    pub fn new(
+        fields: &[String],
+        query: Option<OutputQuery>,
+        format: OutputFormat,
+    ) -> Result<Self> {
+        let fields = fields
+            .iter()
+            .map(|field| field.trim())
+            .filter(|field| !field.is_empty())
+            .map(|field| Ok((field.to_string(), ValuePath::parse(field)?)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            fields,
+            query,
+            format,
+        })
+    }
+
+    /// The format filtered output is rendered in.
+    pub fn format(&self) -> OutputFormat {
+        self.format
+    }
+
+    /// Filter JSON command output and render it in the requested format.
+    pub fn apply(&self, json_output: &str) -> Result<String> {
+        let document: Value = serde_json::from_str(json_output).map_err(|_| {
+            anyhow::anyhow!(
+                "--fields and --query need JSON output, but the command output is not JSON"
+            )
+        })?;
+        self.apply_value(document)
+    }
+
+    /// Filter an already-parsed JSON document and render it.
+    fn apply_value(&self, document: Value) -> Result<String> {
+        let document = match &self.query {
+            Some(query) => Value::Array(
+                query
+                    .path
+                    .query(&document)
+                    .all()
+                    .into_iter()
+                    .cloned()
+                    .collect(),
+            ),
+            None => document,
+        };
+        let document = match document {
+            Value::Array(items) => {
+                Value::Array(items.iter().map(|item| self.project(item)).collect())
+            }
+            other => self.project(&other),
+        };
+
+        self.render(document)
+    }
+
+    /// Keep only the selected fields of an object, in `--fields` order.
+    fn project(&self, value: &Value) -> Value {
+        if self.fields.is_empty() || !value.is_object() {
+            return value.clone();
+        }
+        let mut projected = Map::new();
+        for (name, path) in &self.fields {
+            let selected = path.select(value).cloned().unwrap_or(Value::Null);
+            projected.insert(name.clone(), selected);
+        }
+        Value::Object(projected)
+    }
+
+    fn render(&self, document: Value) -> Result<String> {
+        let rows = match &document {
+            Value::Array(items) => items.clone(),
+            other => vec![other.clone()],
+        };
+
+        match self.format {
+            OutputFormat::Json => Ok(format!("{}\n", serde_json::to_string_pretty(&document)?)),
+            OutputFormat::Ndjson => {
+                let mut ndjson = String::new();
+                for row in &rows {
+                    ndjson.push_str(&serde_json::to_string(row)?);
+                    ndjson.push('\n');
+                }
+                Ok(ndjson)
+            }
+            OutputFormat::Yaml => Ok(serde_yaml::to_string(&document)?),
+            OutputFormat::Xml => XmlFormatter.format_search_results(&rows),
+            OutputFormat::Table => Ok(self.render_table(&rows)),
+            OutputFormat::Csv => Ok(self.render_csv(&rows)),
+            OutputFormat::Markdown => Ok(self.render_markdown(&rows)),
+        }
+    }
+
+    /// Column names: the `--fields` list, else every key in first-seen order.
+    fn columns(&self, rows: &[Value]) -> Vec<String> {
+        if !self.fields.is_empty() {
+            return self.fields.iter().map(|(name, _)| name.clone()).collect();
+        }
+        let mut columns: Vec<String> = Vec::new();
+        for row in rows {
+            match row {
+                Value::Object(map) => {
+                    for key in map.keys() {
+                        if !columns.contains(key) {
+                            columns.push(key.clone());
+                        }
+                    }
+                }
+                _ if !columns.iter().any(|c| c == "value") => columns.push("value".to_string()),
+                _ => {}
+            }
+        }
+        columns
+    }
+
+    fn cells(row: &Value, columns: &[String]) -> Vec<String> {
+        columns
+            .iter()
+            .map(|column| match row {
+                Value::Object(map) => map.get(column).map(format_json_value).unwrap_or_default(),
+                other if column == "value" => format_json_value(other),
+                _ => String::new(),
+            })
+            .collect()
+    }
+
+    fn render_table(&self, rows: &[Value]) -> String {
+        if rows.is_empty() {
+            return "No results found.\n".to_string();
+        }
+        let columns = self.columns(rows);
+        let mut table = format!("{}\n", columns.join("\t"));
+        for row in rows {
+            table.push_str(&Self::cells(row, &columns).join("\t"));
+            table.push('\n');
+        }
+        table
+    }
+
+    fn render_csv(&self, rows: &[Value]) -> String {
+        let columns = self.columns(rows);
+        if columns.is_empty() {
+            return String::new();
+        }
+        let header: Vec<String> = columns.iter().map(|c| escape_csv(c)).collect();
+        let mut csv = format!("{}\n", header.join(","));
+        for row in rows {
+            let cells: Vec<String> = Self::cells(row, &columns)
+                .iter()
+                .map(|cell| escape_csv(cell))
+                .collect();
+            csv.push_str(&cells.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    fn render_markdown(&self, rows: &[Value]) -> String {
+        if rows.is_empty() {
+            return "_No results found._\n".to_string();
+        }
+        let columns = self.columns(rows);
+        let escape = |cell: &str| cell.replace('|', "\\|");
+        let mut md = format!(
+            "| {} |\n|{}|\n",
+            columns
+                .iter()
+                .map(|c| escape(c))
+                .collect::<Vec<_>>()
+                .join(" | "),
+            vec!["---"; columns.len()].join("|")
+        );
+        for row in rows {
+            let cells: Vec<String> = Self::cells(row, &columns)
+                .iter()
+                .map(|cell| escape(cell))
+                .collect();
+            md.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+        md
+    }
+}
+
+/// Render `rows` generically in `format`: one row per element, one column per field.
+///
+/// `fields` selects and orders the columns as `--fields` does; empty keeps every field.
+pub fn render_rows(rows: Vec<Value>, fields: &[String], format: OutputFormat) -> Result<String> {
+    OutputFilter::new(fields, None, format)?.apply_value(Value::Array(rows))
+}
+
+/// Install (or clear) the filter applied by `output_result`.
+///
+/// While a filter is installed `output` is switched to `json`, so commands hand
+/// `output_result` JSON to filter; the filter renders the originally requested format.
+pub fn configure_output_filter(
+    output: &mut String,
+    fields: Option<Vec<String>>,
+    query: Option<OutputQuery>,
+) -> Result<()> {
+    let filter = if fields.is_some() || query.is_some() {
+        let format = OutputFormat::from_str(output)?;
+        *output = "json".to_string();
+        Some(OutputFilter::new(
+            &fields.unwrap_or_default(),
+            query,
+            format,
+        )?)
+    } else {
+        None
+    };
+    *OUTPUT_FILTER.write().unwrap_or_else(|e| e.into_inner()) = filter;
+    Ok(())
+}
+
+/// The filter installed for the current command, if any.
+pub fn installed_output_filter() -> Option<OutputFilter> {
+    OUTPUT_FILTER
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn indexes() -> String {
+        json!([
+            { "name": "main", "current_db_size_mb": 120, "owner": "nobody", "content": { "disabled": false } },
+            { "name": "web", "current_db_size_mb": 4, "owner": "admin", "content": { "disabled": true } }
+        ])
+        .to_string()
+    }
+
+    fn filter(fields: &[&str], query: Option<&str>, format: OutputFormat) -> OutputFilter {
+        let fields: Vec<String> = fields.iter().map(|f| f.to_string()).collect();
+        OutputFilter::new(
+            &fields,
+            query.map(|q| OutputQuery::parse(q).unwrap()),
+            format,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_fields_keep_order_and_select_nested_values() {
+        let output = filter(
+            &["owner", "name", "content.disabled"],
+            None,
+            OutputFormat::Table,
+        )
+        .apply(&indexes())
+        .unwrap();
+        assert_eq!(
+            output,
+            "owner\tname\tcontent.disabled\nnobody\tmain\tfalse\nadmin\tweb\ttrue\n"
+        );
+    }
+
+    #[test]
+    fn test_query_filters_before_fields() {
+        let output = filter(
+            &["name"],
+            Some("$[?@.current_db_size_mb > 100]"),
+            OutputFormat::Csv,
+        )
+        .apply(&indexes())
+        .unwrap();
+        assert_eq!(output, "name\nmain\n");
+
+        let output = filter(&[], Some("$[*].name"), OutputFormat::Json)
+            .apply(&indexes())
+            .unwrap();
+        assert_eq!(output, "[\n  \"main\",\n  \"web\"\n]\n");
+    }
+
+    #[test]
+    fn test_missing_fields_and_single_objects() {
+        let output = filter(&["name", "nope"], None, OutputFormat::Ndjson)
+            .apply(r#"{"name": "main", "size": 1}"#)
+            .unwrap();
+        assert_eq!(output, "{\"name\":\"main\",\"nope\":null}\n");
+    }
+
+    #[test]
+    fn test_rejects_bad_queries_and_non_json_output() {
+        assert!(OutputQuery::parse("$[?@.size >").is_err());
+        let err = filter(&["name"], None, OutputFormat::Table)
+            .apply("Name: main\n")
+            .unwrap_err();
+        assert!(err.to_string().contains("need JSON output"));
+    }
+}
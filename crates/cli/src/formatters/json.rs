@@ -9,8 +9,8 @@
 
 use crate::formatters::{
     ClusterInfoOutput, ClusterManagementOutput, ClusterPeerOutput, Formatter, LicenseInfoOutput,
-    LicenseInstallOutput, LicensePoolOperationOutput, Pagination, ShcCaptainOutput,
-    ShcConfigOutput, ShcManagementOutput, ShcMemberOutput, ShcStatusOutput,
+    LicenseInstallOutput, LicensePoolOperationOutput, LicenseSlaveOutput, Pagination,
+    ShcCaptainOutput, ShcConfigOutput, ShcManagementOutput, ShcMemberOutput, ShcStatusOutput,
     common::{build_named_profile_display, build_profile_display_map},
 };
 use anyhow::Result;
@@ -21,6 +21,7 @@ use splunk_client::models::{
 use splunk_client::{
     App, Forwarder, HealthCheckOutput, Index, KvStoreStatus, SavedSearch, SearchJobStatus, User,
 };
+use splunk_client::{ClusterFixupTask, ClusterIndexCompliance, ClusterIndexExcess, LicenseMessage};
 use splunk_config::types::ProfileConfig;
 use std::collections::BTreeMap;
 
@@ -61,6 +62,29 @@ impl Formatter for JsonFormatter {
         Ok(serde_json::to_string_pretty(output)?)
     }
 
+    fn format_license_messages(&self, messages: &[LicenseMessage]) -> Result<String> {
+        Ok(serde_json::to_string_pretty(messages)?)
+    }
+
+    fn format_license_slaves(&self, slaves: &[LicenseSlaveOutput]) -> Result<String> {
+        Ok(serde_json::to_string_pretty(slaves)?)
+    }
+
+    fn format_cluster_fixups(&self, fixups: &[ClusterFixupTask]) -> Result<String> {
+        Ok(serde_json::to_string_pretty(fixups)?)
+    }
+
+    fn format_cluster_excess_buckets(&self, indexes: &[ClusterIndexExcess]) -> Result<String> {
+        Ok(serde_json::to_string_pretty(indexes)?)
+    }
+
+    fn format_cluster_index_compliance(
+        &self,
+        indexes: &[ClusterIndexCompliance],
+    ) -> Result<String> {
+        Ok(serde_json::to_string_pretty(indexes)?)
+    }
+
     fn format_health(&self, health: &HealthCheckOutput) -> Result<String> {
         Ok(serde_json::to_string_pretty(health)?)
     }
@@ -72,6 +96,30 @@ impl Formatter for JsonFormatter {
         Ok(serde_json::to_string_pretty(report)?)
     }
 
+    fn format_scheduler_health(&self, health: &splunk_client::SchedulerHealth) -> Result<String> {
+        Ok(serde_json::to_string_pretty(health)?)
+    }
+
+    fn format_queue_health(&self, health: &splunk_client::QueueHealth) -> Result<String> {
+        Ok(serde_json::to_string_pretty(health)?)
+    }
+
+    fn format_security_health(&self, health: &splunk_client::SecurityHealth) -> Result<String> {
+        Ok(serde_json::to_string_pretty(health)?)
+    }
+
+    fn format_disk_usage(&self, usage: &splunk_client::DiskUsage) -> Result<String> {
+        Ok(serde_json::to_string_pretty(usage)?)
+    }
+
+    fn format_search_load(&self, load: &splunk_client::SearchLoad) -> Result<String> {
+        Ok(serde_json::to_string_pretty(load)?)
+    }
+
+    fn format_resource_usage(&self, usage: &splunk_client::ResourceUsage) -> Result<String> {
+        Ok(serde_json::to_string_pretty(usage)?)
+    }
+
     fn format_kvstore_status(&self, status: &KvStoreStatus) -> Result<String> {
         Ok(serde_json::to_string_pretty(status)?)
     }
@@ -184,6 +232,10 @@ impl Formatter for JsonFormatter {
         Ok(serde_json::to_string_pretty(roles)?)
     }
 
+    fn format_role_usage(&self, usage: &[splunk_client::RoleUsage]) -> Result<String> {
+        Ok(serde_json::to_string_pretty(usage)?)
+    }
+
     fn format_capabilities(&self, capabilities: &[splunk_client::Capability]) -> Result<String> {
         Ok(serde_json::to_string_pretty(capabilities)?)
     }
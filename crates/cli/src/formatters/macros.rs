@@ -109,6 +109,12 @@ macro_rules! impl_table_formatter {
                         "User" => "users",
                         "Index" => "indexes",
                         "SavedSearch" => "saved searches",
+                        "ClusterFixupTask" => "pending fix-ups",
+                        "ClusterIndexExcess" => "clustered indexes",
+                        "ClusterIndexCompliance" => "clustered indexes",
+                        "RoleUsage" => "roles",
+                        "LicenseMessage" => "license messages",
+                        "LicenseSlaveOutput" => "license slaves",
                         _ => resource_name.to_lowercase().leak(),
                     };
                     return Ok(format!("No {} found.", display_name));
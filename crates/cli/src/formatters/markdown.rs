@@ -10,8 +10,8 @@
 
 use crate::formatters::{
     ClusterInfoOutput, ClusterManagementOutput, ClusterPeerOutput, Formatter, LicenseInfoOutput,
-    LicenseInstallOutput, LicensePoolOperationOutput, Pagination, ShcCaptainOutput,
-    ShcConfigOutput, ShcManagementOutput, ShcMemberOutput, ShcStatusOutput,
+    LicenseInstallOutput, LicensePoolOperationOutput, LicenseSlaveOutput, Pagination,
+    ShcCaptainOutput, ShcConfigOutput, ShcManagementOutput, ShcMemberOutput, ShcStatusOutput,
     common::{build_named_profile_display, build_profile_summary_row},
 };
 use anyhow::Result;
@@ -23,6 +23,7 @@ use splunk_client::models::{
 use splunk_client::{
     App, Forwarder, HealthCheckOutput, Index, KvStoreStatus, SavedSearch, SearchJobStatus, User,
 };
+use splunk_client::{ClusterFixupTask, ClusterIndexCompliance, ClusterIndexExcess, LicenseMessage};
 use splunk_config::types::ProfileConfig;
 use std::collections::BTreeMap;
 
@@ -244,6 +245,29 @@ impl Formatter for MarkdownFormatter {
         to_markdown_section(output, "Cluster Management Operation")
     }
 
+    fn format_license_messages(&self, messages: &[LicenseMessage]) -> Result<String> {
+        to_markdown_table(messages, "License Messages")
+    }
+
+    fn format_license_slaves(&self, slaves: &[LicenseSlaveOutput]) -> Result<String> {
+        to_markdown_table(slaves, "License Slaves")
+    }
+
+    fn format_cluster_fixups(&self, fixups: &[ClusterFixupTask]) -> Result<String> {
+        to_markdown_table(fixups, "Cluster Fix-ups")
+    }
+
+    fn format_cluster_excess_buckets(&self, indexes: &[ClusterIndexExcess]) -> Result<String> {
+        to_markdown_table(indexes, "Excess Bucket Copies")
+    }
+
+    fn format_cluster_index_compliance(
+        &self,
+        indexes: &[ClusterIndexCompliance],
+    ) -> Result<String> {
+        to_markdown_table(indexes, "Index Replication Compliance")
+    }
+
     fn format_health(&self, health: &HealthCheckOutput) -> Result<String> {
         to_markdown_section(health, "Health Check Results")
     }
@@ -280,6 +304,141 @@ impl Formatter for MarkdownFormatter {
         Ok(output)
     }
 
+    fn format_scheduler_health(&self, health: &splunk_client::SchedulerHealth) -> Result<String> {
+        let mut output = "# Scheduler Health\n\n".to_string();
+        output.push_str(&format!(
+            "- **Status**: {}\n",
+            if health.is_healthy {
+                "Healthy"
+            } else {
+                "Unhealthy"
+            }
+        ));
+        output.push_str(&format!("- **Time Window**: {}\n", health.time_window));
+        output.push_str(&format!("- **Total Runs**: {}\n", health.total_runs));
+        output.push_str(&format!("- **Succeeded**: {}\n", health.succeeded));
+        output.push_str(&format!(
+            "- **Skipped**: {} ({:.1}%)\n",
+            health.skipped,
+            health.skip_ratio()
+        ));
+        output.push_str(&format!("- **Failed**: {}\n\n", health.failed));
+        output.push_str(&to_markdown_table(
+            &health.searches,
+            "Skipped/Failed Searches",
+        )?);
+        Ok(output)
+    }
+
+    fn format_queue_health(&self, health: &splunk_client::QueueHealth) -> Result<String> {
+        let mut output = "# Queue Health\n\n".to_string();
+        output.push_str(&format!(
+            "- **Status**: {}\n",
+            if health.is_healthy {
+                "Healthy"
+            } else {
+                "Unhealthy"
+            }
+        ));
+        output.push_str(&format!("- **Time Window**: {}\n", health.time_window));
+        output.push_str(&format!(
+            "- **Blocked Samples**: {}\n",
+            health.blocked_count
+        ));
+        for warning in health.warnings() {
+            output.push_str(&format!("- **Warning**: {}\n", warning));
+        }
+        output.push('\n');
+        output.push_str(&to_markdown_table(&health.queues, "Queues")?);
+        Ok(output)
+    }
+
+    fn format_security_health(&self, health: &splunk_client::SecurityHealth) -> Result<String> {
+        let mut output = "# Security Health\n\n".to_string();
+        output.push_str(&format!(
+            "- **Status**: {}\n",
+            if health.is_healthy {
+                "Healthy"
+            } else {
+                "Unhealthy"
+            }
+        ));
+        output.push_str(&format!("- **Time Window**: {}\n", health.time_window));
+        output.push_str(&format!("- **Threshold**: {}\n", health.threshold));
+        output.push_str(&format!("- **Failed Logins**: {}\n", health.total_failures));
+        for warning in health.warnings() {
+            output.push_str(&format!("- **Warning**: {}\n", warning));
+        }
+        output.push('\n');
+        output.push_str(&to_markdown_table(
+            &health.sources,
+            "Failed Logins by Source IP",
+        )?);
+        Ok(output)
+    }
+
+    fn format_disk_usage(&self, usage: &splunk_client::DiskUsage) -> Result<String> {
+        let mut output = "# Disk Usage\n\n".to_string();
+        output.push_str(&format!(
+            "- **Status**: {}\n",
+            if usage.is_healthy {
+                "Healthy"
+            } else {
+                "Unhealthy"
+            }
+        ));
+        output.push_str(&format!("- **Server**: {}\n", usage.server_name));
+        output.push_str(&format!("- **Roles**: {}\n", usage.server_roles.join(", ")));
+        output.push_str(&format!(
+            "- **Thresholds**: warning {}%, critical {}%\n",
+            usage.warning_pct, usage.critical_pct
+        ));
+        for warning in usage.warnings() {
+            output.push_str(&format!("- **Warning**: {}\n", warning));
+        }
+        output.push('\n');
+        output.push_str(&to_markdown_table(&usage.partitions, "Partitions")?);
+        Ok(output)
+    }
+
+    fn format_search_load(&self, load: &splunk_client::SearchLoad) -> Result<String> {
+        let mut output = "# Search Load\n\n".to_string();
+        output.push_str(&format!(
+            "- **Status**: {}\n",
+            if load.is_healthy {
+                "Healthy"
+            } else {
+                "Saturated"
+            }
+        ));
+        output.push_str(&format!(
+            "- **Running**: {} ({} real-time)\n",
+            load.running, load.realtime
+        ));
+        output.push_str(&format!("- **Queued**: {}\n", load.queued));
+        for warning in load.warnings() {
+            output.push_str(&format!("- **Warning**: {}\n", warning));
+        }
+        output.push('\n');
+        output.push_str(&to_markdown_section(&load.limits, "Concurrency Limits")?);
+        output.push('\n');
+        output.push_str(&to_markdown_table(&load.users, "Users")?);
+        Ok(output)
+    }
+
+    fn format_resource_usage(&self, usage: &splunk_client::ResourceUsage) -> Result<String> {
+        let mut output = "# Resource Usage\n\n".to_string();
+        output.push_str(&format!("- **Time Window**: {}\n", usage.time_window));
+        if let Some(host) = &usage.host {
+            output.push_str(&format!("- **Host**: {}\n", host));
+        }
+        output.push('\n');
+        output.push_str(&to_markdown_table(&usage.hosts, "Hosts")?);
+        output.push('\n');
+        output.push_str(&to_markdown_table(&usage.processes, "Top Processes")?);
+        Ok(output)
+    }
+
     fn format_kvstore_status(&self, status: &KvStoreStatus) -> Result<String> {
         to_markdown_section(status, "KVStore Status")
     }
@@ -432,6 +591,10 @@ impl Formatter for MarkdownFormatter {
         to_markdown_table(roles, "Roles")
     }
 
+    fn format_role_usage(&self, usage: &[splunk_client::RoleUsage]) -> Result<String> {
+        to_markdown_table(usage, "Role Search Quota Usage")
+    }
+
     fn format_capabilities(&self, capabilities: &[splunk_client::Capability]) -> Result<String> {
         let mut output = "# Capabilities\n\n".to_string();
 
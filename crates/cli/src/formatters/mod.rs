@@ -4,6 +4,8 @@
 //! - Provide multiple output formats: JSON, Table, CSV, and XML.
 //! - Implement the `Formatter` trait for various Splunk resource types.
 //! - Handle nested JSON flattening for CSV and hierarchical mapping for XML.
+//! - Extract a single value from JSON output for `--get` (see `extract`).
+//! - Select fields and filter rows with `--fields` / `--query` (see `filter`).
 //!
 //! Does NOT handle:
 //! - Direct printing to stdout (returns formatted strings).
@@ -54,7 +56,8 @@ use splunk_client::models::{
     WorkloadRule,
 };
 use splunk_client::{
-    App, ClusterPeer, Forwarder, Index, KvStoreStatus, LicensePool, LicenseStack, LicenseUsage,
+    App, ClusterFixupTask, ClusterIndexCompliance, ClusterIndexExcess, ClusterPeer, Forwarder,
+    Index, KvStoreStatus, LicenseMessage, LicensePool, LicenseSlave, LicenseStack, LicenseUsage,
     SavedSearch, SearchJobStatus, User,
 };
 use splunk_config::types::ProfileConfig;
@@ -66,6 +69,8 @@ pub use crate::commands::doctor::DiagnosticReport;
 
 mod common;
 mod csv;
+mod extract;
+mod filter;
 mod json;
 mod macros;
 mod markdown;
@@ -77,8 +82,14 @@ mod yaml;
 
 // Macros are exported at crate root via #[macro_export]
 
-pub use common::{escape_xml, output_result, write_to_file};
+pub use common::{escape_csv, escape_xml, output_result, write_to_file};
 pub use csv::CsvFormatter;
+pub use extract::{
+    ExtractError, ValuePath, install_value_path, installed_value_path, parse_value_path,
+};
+pub use filter::{
+    OutputQuery, configure_output_filter, installed_output_filter, parse_output_query, render_rows,
+};
 pub use json::JsonFormatter;
 pub use markdown::MarkdownFormatter;
 pub use ndjson::NdjsonFormatter;
@@ -109,10 +120,11 @@ impl OutputFormat {
             "ndjson" | "jsonl" => Ok(OutputFormat::Ndjson),
             "yaml" | "yml" => Ok(OutputFormat::Yaml),
             "markdown" | "md" => Ok(OutputFormat::Markdown),
-            _ => anyhow::bail!(
+            _ => Err(crate::error::UsageError(format!(
                 "Invalid output format: {}. Valid options: json, table, csv, xml, ndjson, yaml, markdown",
                 s
-            ),
+            ))
+            .into()),
         }
     }
 }
@@ -142,6 +154,15 @@ pub struct LicensePoolOperationOutput {
     pub message: String,
 }
 
+/// License slave with its violation countdown and over-quota state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LicenseSlaveOutput {
+    #[serde(flatten)]
+    pub slave: LicenseSlave,
+    pub warnings_until_violation: usize,
+    pub over_quota: bool,
+}
+
 /// License activation/deactivation output.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LicenseActivationOutput {
@@ -179,12 +200,40 @@ pub trait Formatter {
     /// Format cluster management operation result.
     fn format_cluster_management(&self, output: &ClusterManagementOutput) -> Result<String>;
 
+    /// Format pending cluster bucket fix-up tasks.
+    fn format_cluster_fixups(&self, fixups: &[ClusterFixupTask]) -> Result<String>;
+
+    /// Format per-index excess bucket copy counts.
+    fn format_cluster_excess_buckets(&self, indexes: &[ClusterIndexExcess]) -> Result<String>;
+
+    /// Format per-index replication/search factor compliance.
+    fn format_cluster_index_compliance(&self, indexes: &[ClusterIndexCompliance])
+    -> Result<String>;
+
     /// Format health check results.
     fn format_health(&self, health: &HealthCheckOutput) -> Result<String>;
 
     /// Format diagnostic report from doctor command.
     fn format_health_check_report(&self, report: &DiagnosticReport) -> Result<String>;
 
+    /// Format skipped/failed scheduled search summary.
+    fn format_scheduler_health(&self, health: &splunk_client::SchedulerHealth) -> Result<String>;
+
+    /// Format ingestion queue fill and blocking per pipeline.
+    fn format_queue_health(&self, health: &splunk_client::QueueHealth) -> Result<String>;
+
+    /// Format failed logins per source IP and brute-force flags.
+    fn format_security_health(&self, health: &splunk_client::SecurityHealth) -> Result<String>;
+
+    /// Format disk usage per partition against the disk thresholds.
+    fn format_disk_usage(&self, usage: &splunk_client::DiskUsage) -> Result<String>;
+
+    /// Format running and queued searches against the concurrency limits and user quotas.
+    fn format_search_load(&self, load: &splunk_client::SearchLoad) -> Result<String>;
+
+    /// Format per-host resource usage and top processes.
+    fn format_resource_usage(&self, usage: &splunk_client::ResourceUsage) -> Result<String>;
+
     /// Format KVStore status.
     fn format_kvstore_status(&self, status: &KvStoreStatus) -> Result<String>;
 
@@ -209,6 +258,12 @@ pub trait Formatter {
     /// Format license pools list.
     fn format_license_pools(&self, pools: &[LicensePool]) -> Result<String>;
 
+    /// Format licenser messages.
+    fn format_license_messages(&self, messages: &[LicenseMessage]) -> Result<String>;
+
+    /// Format license slaves with their violation countdown.
+    fn format_license_slaves(&self, slaves: &[LicenseSlaveOutput]) -> Result<String>;
+
     /// Format license pool operation result.
     fn format_license_pool_operation(&self, result: &LicensePoolOperationOutput) -> Result<String>;
 
@@ -286,6 +341,9 @@ pub trait Formatter {
     /// Format roles list.
     fn format_roles(&self, roles: &[splunk_client::Role]) -> Result<String>;
 
+    /// Format per-role search quotas against current usage.
+    fn format_role_usage(&self, usage: &[splunk_client::RoleUsage]) -> Result<String>;
+
     /// Format capabilities list.
     fn format_capabilities(&self, capabilities: &[splunk_client::Capability]) -> Result<String>;
 
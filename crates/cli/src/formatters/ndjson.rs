@@ -15,8 +15,8 @@
 
 use crate::formatters::{
     ClusterInfoOutput, ClusterManagementOutput, ClusterPeerOutput, Formatter, LicenseInfoOutput,
-    LicenseInstallOutput, LicensePoolOperationOutput, Pagination, ShcCaptainOutput,
-    ShcConfigOutput, ShcManagementOutput, ShcMemberOutput, ShcStatusOutput,
+    LicenseInstallOutput, LicensePoolOperationOutput, LicenseSlaveOutput, Pagination,
+    ShcCaptainOutput, ShcConfigOutput, ShcManagementOutput, ShcMemberOutput, ShcStatusOutput,
     common::{build_named_profile_display, build_profile_display_map},
 };
 use anyhow::Result;
@@ -29,6 +29,7 @@ use splunk_client::{
     App, Forwarder, HealthCheckOutput, Index, KvStoreStatus, LicensePool, SavedSearch,
     SearchJobStatus, User,
 };
+use splunk_client::{ClusterFixupTask, ClusterIndexCompliance, ClusterIndexExcess, LicenseMessage};
 use splunk_config::types::ProfileConfig;
 use std::collections::BTreeMap;
 
@@ -85,6 +86,29 @@ impl Formatter for NdjsonFormatter {
         to_ndjson_single(output)
     }
 
+    fn format_license_messages(&self, messages: &[LicenseMessage]) -> Result<String> {
+        to_ndjson(messages)
+    }
+
+    fn format_license_slaves(&self, slaves: &[LicenseSlaveOutput]) -> Result<String> {
+        to_ndjson(slaves)
+    }
+
+    fn format_cluster_fixups(&self, fixups: &[ClusterFixupTask]) -> Result<String> {
+        to_ndjson(fixups)
+    }
+
+    fn format_cluster_excess_buckets(&self, indexes: &[ClusterIndexExcess]) -> Result<String> {
+        to_ndjson(indexes)
+    }
+
+    fn format_cluster_index_compliance(
+        &self,
+        indexes: &[ClusterIndexCompliance],
+    ) -> Result<String> {
+        to_ndjson(indexes)
+    }
+
     fn format_health(&self, health: &HealthCheckOutput) -> Result<String> {
         to_ndjson_single(health)
     }
@@ -96,6 +120,31 @@ impl Formatter for NdjsonFormatter {
         to_ndjson_single(report)
     }
 
+    fn format_scheduler_health(&self, health: &splunk_client::SchedulerHealth) -> Result<String> {
+        to_ndjson_single(health)
+    }
+
+    fn format_queue_health(&self, health: &splunk_client::QueueHealth) -> Result<String> {
+        to_ndjson_single(health)
+    }
+
+    /// One alert per flagged source IP, so each line can be forwarded on its own.
+    fn format_security_health(&self, health: &splunk_client::SecurityHealth) -> Result<String> {
+        to_ndjson(&health.alerts())
+    }
+
+    fn format_disk_usage(&self, usage: &splunk_client::DiskUsage) -> Result<String> {
+        to_ndjson_single(usage)
+    }
+
+    fn format_search_load(&self, load: &splunk_client::SearchLoad) -> Result<String> {
+        to_ndjson_single(load)
+    }
+
+    fn format_resource_usage(&self, usage: &splunk_client::ResourceUsage) -> Result<String> {
+        to_ndjson_single(usage)
+    }
+
     fn format_kvstore_status(&self, status: &KvStoreStatus) -> Result<String> {
         to_ndjson_single(status)
     }
@@ -195,6 +244,10 @@ impl Formatter for NdjsonFormatter {
         to_ndjson(roles)
     }
 
+    fn format_role_usage(&self, usage: &[splunk_client::RoleUsage]) -> Result<String> {
+        to_ndjson(usage)
+    }
+
     fn format_capabilities(&self, capabilities: &[splunk_client::Capability]) -> Result<String> {
         to_ndjson(capabilities)
     }
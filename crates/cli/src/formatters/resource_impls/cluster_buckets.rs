@@ -0,0 +1,268 @@
+//! ResourceDisplay implementations for cluster bucket health resources.
+//!
+//! Covers pending fix-up tasks, per-index excess bucket copy counts, and
+//! per-index replication/search factor compliance reported by the cluster
+//! manager.
+
+use crate::formatters::ResourceDisplay;
+use splunk_client::{ClusterFixupTask, ClusterIndexCompliance, ClusterIndexExcess};
+
+impl ResourceDisplay for ClusterFixupTask {
+    fn headers(_detailed: bool) -> Vec<&'static str> {
+        vec!["Bucket ID", "Index", "Level", "Reason", "Timestamp"]
+    }
+
+    fn headers_csv(_detailed: bool) -> Vec<&'static str> {
+        vec!["bucket_id", "index", "level", "reason", "timestamp"]
+    }
+
+    fn headers_table(_detailed: bool) -> Vec<&'static str> {
+        vec!["BUCKET ID", "INDEX", "LEVEL", "REASON", "TIMESTAMP"]
+    }
+
+    fn row_data(&self, _detailed: bool) -> Vec<Vec<String>> {
+        vec![vec![
+            self.bucket_id.clone(),
+            self.index.clone(),
+            self.level.to_string(),
+            self.reason.clone().unwrap_or_else(|| "-".to_string()),
+            self.timestamp
+                .map(|t| t.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+        ]]
+    }
+
+    fn xml_element_name() -> &'static str {
+        "fixup"
+    }
+
+    fn xml_fields(&self) -> Vec<(&'static str, Option<String>)> {
+        vec![
+            ("bucketId", Some(self.bucket_id.clone())),
+            ("index", Some(self.index.clone())),
+            ("level", Some(self.level.to_string())),
+            ("reason", self.reason.clone()),
+            ("timestamp", self.timestamp.map(|t| t.to_string())),
+        ]
+    }
+}
+
+impl ResourceDisplay for ClusterIndexExcess {
+    fn headers(_detailed: bool) -> Vec<&'static str> {
+        vec![
+            "Index",
+            "Buckets With Excess",
+            "Excess Copies",
+            "Buckets With Excess Searchable",
+            "Excess Searchable Copies",
+        ]
+    }
+
+    fn headers_csv(_detailed: bool) -> Vec<&'static str> {
+        vec![
+            "index",
+            "buckets_with_excess_copies",
+            "total_excess_bucket_copies",
+            "buckets_with_excess_searchable_copies",
+            "total_excess_searchable_copies",
+        ]
+    }
+
+    fn headers_table(_detailed: bool) -> Vec<&'static str> {
+        vec![
+            "INDEX",
+            "BUCKETS W/ EXCESS",
+            "EXCESS COPIES",
+            "BUCKETS W/ EXCESS SEARCHABLE",
+            "EXCESS SEARCHABLE",
+        ]
+    }
+
+    fn row_data(&self, _detailed: bool) -> Vec<Vec<String>> {
+        vec![vec![
+            self.name.clone(),
+            self.buckets_with_excess_copies.to_string(),
+            self.total_excess_bucket_copies.to_string(),
+            self.buckets_with_excess_searchable_copies.to_string(),
+            self.total_excess_searchable_copies.to_string(),
+        ]]
+    }
+
+    fn xml_element_name() -> &'static str {
+        "index"
+    }
+
+    fn xml_fields(&self) -> Vec<(&'static str, Option<String>)> {
+        vec![
+            ("name", Some(self.name.clone())),
+            (
+                "bucketsWithExcessCopies",
+                Some(self.buckets_with_excess_copies.to_string()),
+            ),
+            (
+                "totalExcessBucketCopies",
+                Some(self.total_excess_bucket_copies.to_string()),
+            ),
+            (
+                "bucketsWithExcessSearchableCopies",
+                Some(self.buckets_with_excess_searchable_copies.to_string()),
+            ),
+            (
+                "totalExcessSearchableCopies",
+                Some(self.total_excess_searchable_copies.to_string()),
+            ),
+        ]
+    }
+}
+
+impl ResourceDisplay for ClusterIndexCompliance {
+    fn headers(_detailed: bool) -> Vec<&'static str> {
+        vec![
+            "Index",
+            "Buckets",
+            "Replicated Copies",
+            "Buckets Below RF",
+            "Searchable Copies",
+            "Buckets Below SF",
+            "Compliant",
+        ]
+    }
+
+    fn headers_csv(_detailed: bool) -> Vec<&'static str> {
+        vec![
+            "index",
+            "buckets",
+            "replicated_copies",
+            "replication_factor",
+            "buckets_below_rf",
+            "searchable_copies",
+            "search_factor",
+            "buckets_below_sf",
+            "compliant",
+        ]
+    }
+
+    fn headers_table(_detailed: bool) -> Vec<&'static str> {
+        vec![
+            "INDEX",
+            "BUCKETS",
+            "COPIES/RF",
+            "BELOW RF",
+            "SEARCHABLE/SF",
+            "BELOW SF",
+            "COMPLIANT",
+        ]
+    }
+
+    fn row_data(&self, _detailed: bool) -> Vec<Vec<String>> {
+        vec![vec![
+            self.index.clone(),
+            self.buckets.to_string(),
+            format!("{}/{}", self.replicated_copies, self.replication_factor),
+            self.buckets_below_rf.to_string(),
+            format!("{}/{}", self.searchable_copies, self.search_factor),
+            self.buckets_below_sf.to_string(),
+            if self.compliant { "yes" } else { "NO" }.to_string(),
+        ]]
+    }
+
+    fn row_data_csv(&self, _detailed: bool) -> Vec<Vec<String>> {
+        vec![vec![
+            self.index.clone(),
+            self.buckets.to_string(),
+            self.replicated_copies.to_string(),
+            self.replication_factor.to_string(),
+            self.buckets_below_rf.to_string(),
+            self.searchable_copies.to_string(),
+            self.search_factor.to_string(),
+            self.buckets_below_sf.to_string(),
+            self.compliant.to_string(),
+        ]]
+    }
+
+    fn xml_element_name() -> &'static str {
+        "index"
+    }
+
+    fn xml_fields(&self) -> Vec<(&'static str, Option<String>)> {
+        vec![
+            ("name", Some(self.index.clone())),
+            ("buckets", Some(self.buckets.to_string())),
+            ("replicatedCopies", Some(self.replicated_copies.to_string())),
+            (
+                "replicationFactor",
+                Some(self.replication_factor.to_string()),
+            ),
+            ("bucketsBelowRf", Some(self.buckets_below_rf.to_string())),
+            ("searchableCopies", Some(self.searchable_copies.to_string())),
+            ("searchFactor", Some(self.search_factor.to_string())),
+            ("bucketsBelowSf", Some(self.buckets_below_sf.to_string())),
+            ("compliant", Some(self.compliant.to_string())),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use splunk_client::ClusterFixupLevel;
+
+    #[test]
+    fn test_fixup_row_data_defaults() {
+        let task = ClusterFixupTask {
+            bucket_id: "main~12~ABC".to_string(),
+            index: "main".to_string(),
+            level: ClusterFixupLevel::ReplicationFactor,
+            reason: None,
+            timestamp: None,
+        };
+
+        let rows = task.row_data(false);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0][2], "replication_factor");
+        assert_eq!(rows[0][3], "-");
+        assert_eq!(rows[0][4], "-");
+    }
+
+    #[test]
+    fn test_index_excess_xml_fields() {
+        let excess = ClusterIndexExcess {
+            name: "main".to_string(),
+            total_excess_bucket_copies: 7,
+            ..Default::default()
+        };
+
+        let fields = excess.xml_fields();
+        assert!(
+            fields
+                .iter()
+                .any(|(k, v)| *k == "totalExcessBucketCopies" && v.as_deref() == Some("7"))
+        );
+        assert_eq!(ClusterIndexExcess::xml_element_name(), "index");
+    }
+
+    #[test]
+    fn test_index_compliance_rows() {
+        let compliance = ClusterIndexCompliance {
+            index: "main".to_string(),
+            buckets: 10,
+            replication_factor: 3,
+            replicated_copies: 2,
+            buckets_below_rf: 2,
+            search_factor: 2,
+            searchable_copies: 2,
+            ..Default::default()
+        };
+
+        let rows = compliance.row_data(false);
+        assert_eq!(rows[0][2], "2/3");
+        assert_eq!(rows[0][6], "NO");
+
+        let csv = compliance.row_data_csv(false);
+        assert_eq!(
+            csv[0].len(),
+            ClusterIndexCompliance::headers_csv(false).len()
+        );
+        assert_eq!(csv[0][8], "false");
+    }
+}
@@ -0,0 +1,174 @@
+//! ResourceDisplay implementations for licenser messages and slaves.
+//!
+//! Slaves are rendered with their violation countdown and whether a
+//! licenser message reports them over quota.
+
+use crate::formatters::{LicenseSlaveOutput, ResourceDisplay};
+use splunk_client::LicenseMessage;
+
+impl ResourceDisplay for LicenseMessage {
+    fn headers(_detailed: bool) -> Vec<&'static str> {
+        vec![
+            "Severity",
+            "Category",
+            "Pool",
+            "Slave",
+            "Created",
+            "Description",
+        ]
+    }
+
+    fn headers_csv(_detailed: bool) -> Vec<&'static str> {
+        vec![
+            "severity",
+            "category",
+            "pool_id",
+            "slave_id",
+            "create_time",
+            "description",
+        ]
+    }
+
+    fn headers_table(_detailed: bool) -> Vec<&'static str> {
+        vec![
+            "SEVERITY",
+            "CATEGORY",
+            "POOL",
+            "SLAVE",
+            "CREATED",
+            "DESCRIPTION",
+        ]
+    }
+
+    fn row_data(&self, _detailed: bool) -> Vec<Vec<String>> {
+        vec![vec![
+            self.severity.to_string(),
+            self.category.clone(),
+            self.pool_id.clone().unwrap_or_else(|| "-".to_string()),
+            self.slave_id.clone().unwrap_or_else(|| "-".to_string()),
+            self.create_time
+                .map(|t| t.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            self.description.clone(),
+        ]]
+    }
+
+    fn xml_element_name() -> &'static str {
+        "message"
+    }
+
+    fn xml_fields(&self) -> Vec<(&'static str, Option<String>)> {
+        vec![
+            ("severity", Some(self.severity.to_string())),
+            ("category", Some(self.category.clone())),
+            ("poolId", self.pool_id.clone()),
+            ("slaveId", self.slave_id.clone()),
+            ("createTime", self.create_time.map(|t| t.to_string())),
+            ("description", Some(self.description.clone())),
+        ]
+    }
+}
+
+impl ResourceDisplay for LicenseSlaveOutput {
+    fn headers(_detailed: bool) -> Vec<&'static str> {
+        vec![
+            "Label",
+            "GUID",
+            "Pools",
+            "Warnings",
+            "Until Violation",
+            "Over Quota",
+        ]
+    }
+
+    fn headers_csv(_detailed: bool) -> Vec<&'static str> {
+        vec![
+            "label",
+            "guid",
+            "pool_ids",
+            "warning_count",
+            "warnings_until_violation",
+            "over_quota",
+        ]
+    }
+
+    fn headers_table(_detailed: bool) -> Vec<&'static str> {
+        vec![
+            "LABEL",
+            "GUID",
+            "POOLS",
+            "WARNINGS",
+            "UNTIL VIOLATION",
+            "OVER QUOTA",
+        ]
+    }
+
+    fn row_data(&self, _detailed: bool) -> Vec<Vec<String>> {
+        vec![vec![
+            self.slave.display_name().to_string(),
+            self.slave.name.clone(),
+            self.slave.pool_ids.join(";"),
+            self.slave.warning_count.to_string(),
+            self.warnings_until_violation.to_string(),
+            if self.over_quota { "yes" } else { "no" }.to_string(),
+        ]]
+    }
+
+    fn xml_element_name() -> &'static str {
+        "slave"
+    }
+
+    fn xml_fields(&self) -> Vec<(&'static str, Option<String>)> {
+        vec![
+            ("label", Some(self.slave.display_name().to_string())),
+            ("guid", Some(self.slave.name.clone())),
+            ("poolIds", Some(self.slave.pool_ids.join(";"))),
+            ("warningCount", Some(self.slave.warning_count.to_string())),
+            (
+                "warningsUntilViolation",
+                Some(self.warnings_until_violation.to_string()),
+            ),
+            ("overQuota", Some(self.over_quota.to_string())),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use splunk_client::LicenseSlave;
+
+    #[test]
+    fn test_license_message_row_data_defaults() {
+        let message = LicenseMessage {
+            category: "license_window".to_string(),
+            description: "4 warnings".to_string(),
+            ..Default::default()
+        };
+
+        let rows = message.row_data(false);
+        assert_eq!(rows[0][0], "UNKNOWN");
+        assert_eq!(rows[0][2], "-");
+        assert_eq!(rows[0][4], "-");
+    }
+
+    #[test]
+    fn test_license_slave_row_data() {
+        let output = LicenseSlaveOutput {
+            slave: LicenseSlave {
+                name: "GUID-1".to_string(),
+                pool_ids: vec!["a".to_string(), "b".to_string()],
+                warning_count: 4,
+                ..Default::default()
+            },
+            warnings_until_violation: 1,
+            over_quota: true,
+        };
+
+        let rows = output.row_data(false);
+        assert_eq!(rows[0][0], "GUID-1");
+        assert_eq!(rows[0][2], "a;b");
+        assert_eq!(rows[0][4], "1");
+        assert_eq!(rows[0][5], "yes");
+    }
+}
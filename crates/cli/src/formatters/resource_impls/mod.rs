@@ -25,3 +25,6 @@
 //! ```
 
 pub mod apps;
+pub mod cluster_buckets;
+pub mod license_messages;
+pub mod role_usage;
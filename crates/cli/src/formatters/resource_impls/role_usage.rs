@@ -0,0 +1,158 @@
+//! ResourceDisplay implementation for per-role search quota usage.
+//!
+//! Per-user quotas are shown as `peak/quota` (busiest single user against
+//! the role's per-user limit); cumulative quotas as `total/quota`. Unset or
+//! `0` quotas are unlimited in Splunk and render as `-`.
+
+use crate::formatters::ResourceDisplay;
+use splunk_client::RoleUsage;
+
+fn quota(value: Option<i32>) -> String {
+    match value {
+        Some(q) if q > 0 => q.to_string(),
+        _ => "-".to_string(),
+    }
+}
+
+fn used_of(used: impl ToString, limit: Option<i32>) -> String {
+    format!("{}/{}", used.to_string(), quota(limit))
+}
+
+impl ResourceDisplay for RoleUsage {
+    fn headers(_detailed: bool) -> Vec<&'static str> {
+        vec![
+            "Role",
+            "Users",
+            "Peak User Searches",
+            "Peak User RT Searches",
+            "Peak User Disk MB",
+            "Running Searches",
+            "Running RT Searches",
+            "Users At Limit",
+        ]
+    }
+
+    fn headers_csv(_detailed: bool) -> Vec<&'static str> {
+        vec![
+            "role",
+            "users",
+            "srch_jobs_quota",
+            "peak_user_jobs",
+            "rt_srch_jobs_quota",
+            "peak_user_rt_jobs",
+            "srch_disk_quota_mb",
+            "peak_user_disk_mb",
+            "cumulative_srch_jobs_quota",
+            "running_jobs",
+            "cumulative_rt_srch_jobs_quota",
+            "running_rt_jobs",
+            "users_at_limit",
+        ]
+    }
+
+    fn headers_table(_detailed: bool) -> Vec<&'static str> {
+        vec![
+            "ROLE",
+            "USERS",
+            "SEARCHES/USER",
+            "RT/USER",
+            "DISK MB/USER",
+            "SEARCHES",
+            "RT SEARCHES",
+            "AT LIMIT",
+        ]
+    }
+
+    fn row_data(&self, _detailed: bool) -> Vec<Vec<String>> {
+        vec![vec![
+            self.role.clone(),
+            self.users.to_string(),
+            used_of(self.peak_user_jobs, self.srch_jobs_quota),
+            used_of(self.peak_user_rt_jobs, self.rt_srch_jobs_quota),
+            used_of(self.peak_user_disk_mb, self.srch_disk_quota_mb),
+            used_of(self.running_jobs, self.cumulative_srch_jobs_quota),
+            used_of(self.running_rt_jobs, self.cumulative_rt_srch_jobs_quota),
+            self.users_at_limit.to_string(),
+        ]]
+    }
+
+    fn row_data_csv(&self, _detailed: bool) -> Vec<Vec<String>> {
+        let opt = |v: Option<i32>| v.map(|q| q.to_string()).unwrap_or_default();
+        vec![vec![
+            self.role.clone(),
+            self.users.to_string(),
+            opt(self.srch_jobs_quota),
+            self.peak_user_jobs.to_string(),
+            opt(self.rt_srch_jobs_quota),
+            self.peak_user_rt_jobs.to_string(),
+            opt(self.srch_disk_quota_mb),
+            self.peak_user_disk_mb.to_string(),
+            opt(self.cumulative_srch_jobs_quota),
+            self.running_jobs.to_string(),
+            opt(self.cumulative_rt_srch_jobs_quota),
+            self.running_rt_jobs.to_string(),
+            self.users_at_limit.to_string(),
+        ]]
+    }
+
+    fn xml_element_name() -> &'static str {
+        "role"
+    }
+
+    fn xml_fields(&self) -> Vec<(&'static str, Option<String>)> {
+        let opt = |v: Option<i32>| v.map(|q| q.to_string());
+        vec![
+            ("name", Some(self.role.clone())),
+            ("users", Some(self.users.to_string())),
+            ("srchJobsQuota", opt(self.srch_jobs_quota)),
+            ("peakUserJobs", Some(self.peak_user_jobs.to_string())),
+            ("rtSrchJobsQuota", opt(self.rt_srch_jobs_quota)),
+            ("peakUserRtJobs", Some(self.peak_user_rt_jobs.to_string())),
+            ("srchDiskQuota", opt(self.srch_disk_quota_mb)),
+            ("peakUserDiskMb", Some(self.peak_user_disk_mb.to_string())),
+            (
+                "cumulativeSrchJobsQuota",
+                opt(self.cumulative_srch_jobs_quota),
+            ),
+            ("runningJobs", Some(self.running_jobs.to_string())),
+            (
+                "cumulativeRtSrchJobsQuota",
+                opt(self.cumulative_rt_srch_jobs_quota),
+            ),
+            ("runningRtJobs", Some(self.running_rt_jobs.to_string())),
+            ("usersAtLimit", Some(self.users_at_limit.to_string())),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_usage_rows() {
+        let usage = RoleUsage {
+            role: "user".to_string(),
+            users: 2,
+            srch_jobs_quota: Some(3),
+            rt_srch_jobs_quota: Some(0),
+            srch_disk_quota_mb: Some(100),
+            peak_user_jobs: 3,
+            peak_user_disk_mb: 120,
+            running_jobs: 4,
+            users_at_limit: 2,
+            ..Default::default()
+        };
+
+        let rows = usage.row_data(false);
+        assert_eq!(rows[0][2], "3/3");
+        assert_eq!(rows[0][3], "0/-");
+        assert_eq!(rows[0][4], "120/100");
+        assert_eq!(rows[0][5], "4/-");
+
+        let csv = usage.row_data_csv(false);
+        assert_eq!(csv[0].len(), RoleUsage::headers_csv(false).len());
+        assert_eq!(csv[0][4], "0");
+        assert_eq!(csv[0][8], "");
+    }
+}
@@ -1,14 +1,17 @@
 //! Health check table formatter.
 //!
 //! Responsibilities:
-//! - Format health check results and KVStore status as formatted text.
+//! - Format health check results, scheduler health, queue health, security health, disk usage, search load, resource usage, and KVStore status as formatted text.
 //!
 //! Does NOT handle:
 //! - Other resource types.
 
 use crate::formatters::DiagnosticReport;
 use anyhow::Result;
-use splunk_client::{HealthCheckOutput, KvStoreStatus};
+use splunk_client::{
+    DiskUsage, HealthCheckOutput, KvStoreStatus, PartitionSpace, QueueHealth, ResourceUsage,
+    SchedulerHealth, SearchLoad, SecurityHealth,
+};
 
 /// Format health check results as formatted text.
 pub fn format_health(health: &HealthCheckOutput) -> Result<String> {
@@ -93,6 +96,21 @@ pub fn format_health(health: &HealthCheckOutput) -> Result<String> {
         output.push('\n');
     }
 
+    if let Some(partition) = &health.disk_partition {
+        output.push_str("--- Disk ---\n");
+        output.push_str(&format!(
+            "Fullest Partition: {}\n",
+            format_partition(partition)
+        ));
+        output.push('\n');
+    }
+
+    if let Some(load) = &health.search_load {
+        output.push_str("--- Search Load ---\n");
+        push_search_load_summary(&mut output, load);
+        output.push('\n');
+    }
+
     if let Some(lp) = &health.log_parsing_health {
         output.push_str("--- Log Parsing Health ---\n");
         output.push_str(&format!(
@@ -116,6 +134,319 @@ pub fn format_health(health: &HealthCheckOutput) -> Result<String> {
         }
     }
 
+    if let Some(scheduler) = &health.scheduler_health {
+        if health.log_parsing_health.is_some() {
+            output.push('\n');
+        }
+        output.push_str("--- Scheduler Health ---\n");
+        output.push_str(&format_scheduler_summary(scheduler));
+    }
+
+    if let Some(queues) = &health.queue_health {
+        if health.log_parsing_health.is_some() || health.scheduler_health.is_some() {
+            output.push('\n');
+        }
+        output.push_str("--- Queue Health ---\n");
+        output.push_str(&format_queue_summary(queues));
+    }
+
+    if let Some(security) = &health.security_health {
+        if health.log_parsing_health.is_some()
+            || health.scheduler_health.is_some()
+            || health.queue_health.is_some()
+        {
+            output.push('\n');
+        }
+        output.push_str("--- Security ---\n");
+        output.push_str(&format_security_summary(security));
+    }
+
+    Ok(output)
+}
+
+/// Format skipped/failed scheduled search summary as formatted text.
+pub fn format_scheduler_health(health: &SchedulerHealth) -> Result<String> {
+    let mut output = String::from("Scheduler Health:\n");
+    output.push_str(&format_scheduler_summary(health));
+    Ok(output)
+}
+
+fn format_scheduler_summary(health: &SchedulerHealth) -> String {
+    let mut output = String::new();
+    output.push_str(&format!(
+        "Status: {}\n",
+        if health.is_healthy {
+            "Healthy"
+        } else {
+            "Unhealthy"
+        }
+    ));
+    output.push_str(&format!("Time Window: {}\n", health.time_window));
+    output.push_str(&format!(
+        "Runs: {} (Succeeded: {}, Skipped: {} [{:.1}%], Failed: {})\n",
+        health.total_runs,
+        health.succeeded,
+        health.skipped,
+        health.skip_ratio(),
+        health.failed
+    ));
+    if !health.searches.is_empty() {
+        output.push_str("Skipped/Failed Searches:\n");
+        for search in &health.searches {
+            output.push_str(&format!(
+                "  {}/{} (owner {}): {} skipped, {} failed of {} runs\n",
+                search.app, search.name, search.owner, search.skipped, search.failed, search.runs
+            ));
+            if let Some(reason) = &search.last_reason {
+                output.push_str(&format!("    Last Reason: {}\n", reason));
+            }
+        }
+    }
+    output
+}
+
+/// Format ingestion queue fill and blocking per pipeline as formatted text.
+pub fn format_queue_health(health: &QueueHealth) -> Result<String> {
+    let mut output = String::from("Queue Health:\n");
+    output.push_str(&format_queue_summary(health));
+    Ok(output)
+}
+
+fn format_queue_summary(health: &QueueHealth) -> String {
+    let mut output = String::new();
+    output.push_str(&format!(
+        "Status: {}\n",
+        if health.is_healthy {
+            "Healthy"
+        } else {
+            "Unhealthy"
+        }
+    ));
+    output.push_str(&format!("Time Window: {}\n", health.time_window));
+    output.push_str(&format!("Blocked Samples: {}\n", health.blocked_count));
+    if !health.queues.is_empty() {
+        output.push_str("Queues:\n");
+        for queue in &health.queues {
+            output.push_str(&format!(
+                "  {} pipeline {} {}: avg {:.1}%, max {:.1}%, blocked {} of {} samples{}\n",
+                queue.host,
+                queue.pipeline,
+                queue.queue,
+                queue.avg_fill_pct,
+                queue.max_fill_pct,
+                queue.blocked_count,
+                queue.samples,
+                if queue.is_sustained_full() {
+                    " [WARNING: sustained fill]"
+                } else {
+                    ""
+                }
+            ));
+        }
+    }
+    output
+}
+
+/// Format failed logins per source IP as formatted text.
+pub fn format_security_health(health: &SecurityHealth) -> Result<String> {
+    let mut output = String::from("Security Health:\n");
+    output.push_str(&format_security_summary(health));
+    Ok(output)
+}
+
+fn format_security_summary(health: &SecurityHealth) -> String {
+    let mut output = String::new();
+    output.push_str(&format!(
+        "Status: {}\n",
+        if health.is_healthy {
+            "Healthy"
+        } else {
+            "Unhealthy"
+        }
+    ));
+    output.push_str(&format!("Time Window: {}\n", health.time_window));
+    output.push_str(&format!(
+        "Failed Logins: {} (threshold {} per source IP)\n",
+        health.total_failures, health.threshold
+    ));
+    if !health.sources.is_empty() {
+        output.push_str("Source IPs:\n");
+        for source in &health.sources {
+            output.push_str(&format!(
+                "  {}: {} failed login(s) for {} user(s), {} to {}{}\n",
+                source.src_ip,
+                source.failures,
+                source.distinct_users,
+                source.first_seen,
+                source.last_seen,
+                if health.is_flagged(source) {
+                    " [WARNING: possible brute force]"
+                } else {
+                    ""
+                }
+            ));
+        }
+    }
+    output
+}
+
+/// Format disk usage per partition as formatted text.
+pub fn format_disk_usage(usage: &DiskUsage) -> Result<String> {
+    let mut output = String::from("Disk Usage:\n");
+    output.push_str(&format!(
+        "Status: {}\n",
+        if usage.is_healthy {
+            "Healthy"
+        } else {
+            "Unhealthy"
+        }
+    ));
+    output.push_str(&format!("Server: {}\n", usage.server_name));
+    output.push_str(&format!("Roles: {}\n", usage.server_roles.join(", ")));
+    output.push_str(&format!(
+        "Thresholds: warning {}%, critical {}%\n",
+        usage.warning_pct, usage.critical_pct
+    ));
+    if usage.partitions.is_empty() {
+        output.push_str("No partitions reported.\n");
+        return Ok(output);
+    }
+    output.push_str("Partitions:\n");
+    for partition in &usage.partitions {
+        let level = usage.level(partition);
+        output.push_str(&format!(
+            "  {}{}\n",
+            format_partition(partition),
+            if level == splunk_config::ThresholdLevel::Ok {
+                String::new()
+            } else {
+                format!(" [{}]", level.as_str().to_uppercase())
+            }
+        ));
+    }
+    Ok(output)
+}
+
+/// Format running and queued searches per user as formatted text.
+pub fn format_search_load(load: &SearchLoad) -> Result<String> {
+    let mut output = String::from("Search Load:\n");
+    push_search_load_summary(&mut output, load);
+    if load.users.is_empty() {
+        output.push_str("No searches running or queued.\n");
+        return Ok(output);
+    }
+    output.push_str(&format!(
+        "{:<24} {:>7} {:>6} {:>9} {:>5}\n",
+        "User", "Running", "Queued", "Real-time", "Quota"
+    ));
+    for user in &load.users {
+        output.push_str(&format!(
+            "{:<24} {:>7} {:>6} {:>9} {:>5}{}\n",
+            user.user,
+            user.running,
+            user.queued,
+            user.realtime,
+            limit_text(user.quota),
+            if user.is_at_quota() {
+                " [AT QUOTA]"
+            } else {
+                ""
+            }
+        ));
+    }
+    Ok(output)
+}
+
+/// Status, running/queued counts against the limits, and one line per warning.
+fn push_search_load_summary(output: &mut String, load: &SearchLoad) {
+    output.push_str(&format!(
+        "Status: {}\n",
+        if load.is_healthy {
+            "Healthy"
+        } else {
+            "Saturated"
+        }
+    ));
+    output.push_str(&format!(
+        "Historical: {} / {} running\n",
+        load.running.saturating_sub(load.realtime),
+        limit_text(load.limits.max_hist_searches)
+    ));
+    output.push_str(&format!(
+        "Real-time: {} / {} running\n",
+        load.realtime,
+        limit_text(load.limits.max_rt_searches)
+    ));
+    output.push_str(&format!("Queued: {}\n", load.queued));
+    for warning in load.warnings() {
+        output.push_str(&format!("Warning: {}\n", warning));
+    }
+}
+
+/// A concurrency limit or quota, or `-` when splunkd did not report one.
+fn limit_text(limit: Option<u64>) -> String {
+    limit.map_or_else(|| "-".to_string(), |limit| limit.to_string())
+}
+
+/// `mount (fs): 85.0% used, 150 of 1000 MB free`.
+fn format_partition(partition: &PartitionSpace) -> String {
+    let fs_type = partition
+        .fs_type
+        .as_deref()
+        .map(|fs| format!(" ({})", fs))
+        .unwrap_or_default();
+    let used = partition
+        .used_percent()
+        .map(|pct| format!("{:.1}% used", pct))
+        .unwrap_or_else(|| "N/A".to_string());
+    format!(
+        "{}{}: {}, {:.0} of {:.0} MB free",
+        partition.mount_point, fs_type, used, partition.free_mb, partition.capacity_mb
+    )
+}
+
+/// Format per-host resource usage and top processes as formatted text.
+pub fn format_resource_usage(usage: &ResourceUsage) -> Result<String> {
+    let mut output = format!("Resource Usage (since {}):\n", usage.time_window);
+    if usage.hosts.is_empty() {
+        output.push_str("No introspection data found.\n");
+        return Ok(output);
+    }
+    output.push_str("Hosts:\n");
+    for host in &usage.hosts {
+        let cores = host
+            .cpu_count
+            .map(|count| format!(" ({} cores)", count))
+            .unwrap_or_default();
+        let iowait = host
+            .iowait_pct
+            .map(|pct| format!("{:.1}%", pct))
+            .unwrap_or_else(|| "N/A".to_string());
+        output.push_str(&format!(
+            "  {}: CPU {:.1}%{}, Memory {:.1}% ({:.0} of {:.0} MB), I/O Wait {}\n",
+            host.host,
+            host.cpu_pct,
+            cores,
+            host.mem_used_pct(),
+            host.mem_used_mb,
+            host.mem_total_mb,
+            iowait
+        ));
+    }
+    if !usage.processes.is_empty() {
+        output.push_str("Top Processes:\n");
+        for process in &usage.processes {
+            output.push_str(&format!(
+                "  {} {} [{}] pid {}: CPU {:.1}%, Memory {:.1} MB\n",
+                process.host,
+                process.process,
+                process.process_type,
+                process.pid,
+                process.cpu_pct,
+                process.mem_used_mb
+            ));
+        }
+    }
     Ok(output)
 }
 
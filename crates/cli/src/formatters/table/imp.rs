@@ -15,8 +15,8 @@
 use crate::formatters::table::workload;
 use crate::formatters::{
     ClusterInfoOutput, ClusterManagementOutput, ClusterPeerOutput, Formatter, LicenseInfoOutput,
-    LicenseInstallOutput, LicensePoolOperationOutput, ShcCaptainOutput, ShcConfigOutput,
-    ShcManagementOutput, ShcMemberOutput, ShcStatusOutput,
+    LicenseInstallOutput, LicensePoolOperationOutput, LicenseSlaveOutput, ShcCaptainOutput,
+    ShcConfigOutput, ShcManagementOutput, ShcMemberOutput, ShcStatusOutput,
 };
 use anyhow::Result;
 use splunk_client::models::AuditEvent;
@@ -27,6 +27,9 @@ use splunk_client::{
     App, Dashboard, Forwarder, HealthCheckOutput, Index, KvStoreStatus, SavedSearch,
     SearchJobStatus, User,
 };
+use splunk_client::{
+    ClusterFixupTask, ClusterIndexCompliance, ClusterIndexExcess, LicenseMessage, RoleUsage,
+};
 use splunk_config::types::ProfileConfig;
 use std::collections::BTreeMap;
 
@@ -103,6 +106,12 @@ impl Formatter for TableFormatter {
         format_health: &HealthCheckOutput => health::format_health,
         format_health_check_report: &crate::formatters::DiagnosticReport => health::format_health_check_report,
         format_kvstore_status: &KvStoreStatus => health::format_kvstore_status,
+        format_scheduler_health: &splunk_client::SchedulerHealth => health::format_scheduler_health,
+        format_queue_health: &splunk_client::QueueHealth => health::format_queue_health,
+        format_security_health: &splunk_client::SecurityHealth => health::format_security_health,
+        format_disk_usage: &splunk_client::DiskUsage => health::format_disk_usage,
+        format_search_load: &splunk_client::SearchLoad => health::format_search_load,
+        format_resource_usage: &splunk_client::ResourceUsage => health::format_resource_usage,
         format_license: &LicenseInfoOutput => license::format_license,
         format_license_install: &LicenseInstallOutput => license::format_license_install,
         format_license_pool_operation: &LicensePoolOperationOutput => license::format_license_pool_operation,
@@ -125,6 +134,12 @@ impl Formatter for TableFormatter {
 
     crate::impl_table_formatter! {
         format_apps: &[App] => apps,
+        format_cluster_fixups: &[ClusterFixupTask] => fixups,
+        format_cluster_excess_buckets: &[ClusterIndexExcess] => indexes,
+        format_cluster_index_compliance: &[ClusterIndexCompliance] => indexes,
+        format_role_usage: &[RoleUsage] => usage,
+        format_license_messages: &[LicenseMessage] => messages,
+        format_license_slaves: &[LicenseSlaveOutput] => slaves,
     }
 
     fn format_search_results(&self, results: &[serde_json::Value]) -> Result<String> {
@@ -62,6 +62,8 @@ fn test_csv_formatter_indexes_basic() {
         thawed_path: Some("/opt/splunk/var/lib/splunk/main/thaweddb".to_string()),
         cold_to_frozen_dir: None,
         primary_index: Some(true),
+        min_time: None,
+        max_time: None,
     }];
     let output = formatter.format_indexes(&indexes, false).unwrap();
     assert!(output.contains("Name,SizeMB,Events,MaxSizeMB"));
@@ -85,6 +87,8 @@ fn test_csv_formatter_indexes_detailed() {
         thawed_path: Some("/opt/splunk/var/lib/splunk/main/thaweddb".to_string()),
         cold_to_frozen_dir: None,
         primary_index: Some(true),
+        min_time: None,
+        max_time: None,
     }];
     let output = formatter.format_indexes(&indexes, true).unwrap();
     assert!(
@@ -141,6 +145,7 @@ fn test_kvstore_peers_csv_formatting() {
         replication_status: KvStoreReplicationStatus {
             oplog_size: 100,
             oplog_used: 1.5,
+            replication_lag_secs: None,
         },
     };
     let output = CsvFormatter.format_kvstore_status(&status).unwrap();
@@ -179,6 +184,7 @@ fn test_users_csv_formatting() {
         default_app: Some("launcher".to_string()),
         roles: vec!["admin".to_string(), "power".to_string()],
         last_successful_login: Some(1704067200),
+        locked_out: false,
     }];
     let output = formatter.format_users(&users).unwrap();
     assert!(output.contains("name,realname,user_type,default_app,roles,last_successful_login"));
@@ -196,6 +202,7 @@ fn test_users_csv_special_characters() {
         default_app: None,
         roles: vec![],
         last_successful_login: None,
+        locked_out: false,
     }];
     let output = formatter.format_users(&users).unwrap();
     assert!(output.contains("\"user,name\""));
@@ -350,6 +357,7 @@ fn test_csv_null_fields_in_users() {
         default_app: None,
         roles: vec![],
         last_successful_login: None,
+        locked_out: false,
     }];
     let output = formatter.format_users(&users).unwrap();
     // Null fields should be empty in CSV (consecutive commas)
@@ -369,6 +377,7 @@ fn test_csv_unicode_in_users() {
         default_app: None,
         roles: vec![],
         last_successful_login: None,
+        locked_out: false,
     }];
     let output = formatter.format_users(&users).unwrap();
     // Unicode should be preserved in CSV output
@@ -30,6 +30,8 @@ fn test_json_formatter_indexes_always_detailed() {
         thawed_path: Some("/opt/splunk/var/lib/splunk/main/thaweddb".to_string()),
         cold_to_frozen_dir: None,
         primary_index: Some(true),
+        min_time: None,
+        max_time: None,
     }];
     // JSON always outputs all fields regardless of detailed flag
     let output_basic = formatter.format_indexes(&indexes, false).unwrap();
@@ -101,6 +103,7 @@ fn test_kvstore_peers_json_formatting() {
         replication_status: KvStoreReplicationStatus {
             oplog_size: 100,
             oplog_used: 1.5,
+            replication_lag_secs: None,
         },
     };
     let output = JsonFormatter.format_kvstore_status(&status).unwrap();
@@ -121,6 +124,7 @@ fn test_users_json_formatting() {
         default_app: Some("launcher".to_string()),
         roles: vec!["admin".to_string(), "power".to_string()],
         last_successful_login: Some(1704067200),
+        locked_out: false,
     }];
     let output = formatter.format_users(&users).unwrap();
     assert!(output.contains("\"name\""));
@@ -72,6 +72,8 @@ fn test_ndjson_formatter_indexes() {
         thawed_path: Some("/opt/splunk/thawed".to_string()),
         cold_to_frozen_dir: None,
         primary_index: Some(true),
+        min_time: None,
+        max_time: None,
     }];
     let output = formatter.format_indexes(&indexes, false).unwrap();
 
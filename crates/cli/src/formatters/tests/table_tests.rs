@@ -40,6 +40,8 @@ fn test_table_formatter_indexes_basic() {
         thawed_path: Some("/opt/splunk/var/lib/splunk/main/thaweddb".to_string()),
         cold_to_frozen_dir: None,
         primary_index: Some(true),
+        min_time: None,
+        max_time: None,
     }];
     let output = formatter.format_indexes(&indexes, false).unwrap();
     assert!(output.contains("Name"));
@@ -66,6 +68,8 @@ fn test_table_formatter_indexes_detailed() {
         thawed_path: Some("/opt/splunk/var/lib/splunk/main/thaweddb".to_string()),
         cold_to_frozen_dir: None,
         primary_index: Some(true),
+        min_time: None,
+        max_time: None,
     }];
     let output = formatter.format_indexes(&indexes, true).unwrap();
     assert!(output.contains("Name"));
@@ -124,6 +128,7 @@ fn test_kvstore_peers_table_formatting() {
         replication_status: KvStoreReplicationStatus {
             oplog_size: 100,
             oplog_used: 1.5,
+            replication_lag_secs: None,
         },
     };
     let output = TableFormatter.format_kvstore_status(&status).unwrap();
@@ -167,10 +172,17 @@ fn test_doctor_report_kvstore_section_uses_separate_lines() {
                 replication_status: KvStoreReplicationStatus {
                     oplog_size: 100,
                     oplog_used: 1.5,
+                    replication_lag_secs: None,
                 },
             }),
             log_parsing_health: None,
+            scheduler_health: None,
+            queue_health: None,
+            security_health: None,
             circuit_breaker_states: None,
+            cluster_peers: None,
+            disk_partition: None,
+            search_load: None,
         }),
         partial_errors: vec![],
     };
@@ -179,6 +191,163 @@ fn test_doctor_report_kvstore_section_uses_separate_lines() {
     assert!(output.contains("Member: localhost:8089 (ready)\nReplica Set: rs0"));
 }
 
+#[test]
+fn test_format_scheduler_health_table() {
+    let health = splunk_client::SchedulerHealth::from_searches(
+        "-24h".to_string(),
+        vec![splunk_client::ScheduledSearchHealth {
+            app: "search".to_string(),
+            name: "Errors Last Hour".to_string(),
+            owner: "admin".to_string(),
+            runs: 24,
+            succeeded: 20,
+            skipped: 4,
+            failed: 0,
+            last_reason: Some("concurrency limit reached".to_string()),
+            last_run: None,
+        }],
+    );
+
+    let output = TableFormatter.format_scheduler_health(&health).unwrap();
+    assert!(output.contains("Status: Unhealthy"));
+    assert!(output.contains("Runs: 24 (Succeeded: 20, Skipped: 4 [16.7%], Failed: 0)"));
+    assert!(output.contains(
+        "  search/Errors Last Hour (owner admin): 4 skipped, 0 failed of 24 runs\n    Last Reason: concurrency limit reached"
+    ));
+}
+
+#[test]
+fn test_format_queue_health_table() {
+    let queue =
+        |name: &str, avg_fill_pct: f64, blocked_count: u64| splunk_client::PipelineQueueHealth {
+            host: "idx01".to_string(),
+            pipeline: "0".to_string(),
+            queue: name.to_string(),
+            avg_fill_pct,
+            max_fill_pct: 100.0,
+            blocked_count,
+            samples: 30,
+        };
+    let health = splunk_client::QueueHealth::from_queues(
+        "-15m".to_string(),
+        vec![queue("parsingqueue", 5.0, 0), queue("indexqueue", 88.0, 3)],
+    );
+
+    let output = TableFormatter.format_queue_health(&health).unwrap();
+    assert!(output.contains("Status: Unhealthy"));
+    assert!(output.contains("Blocked Samples: 3"));
+    assert!(output.contains(
+        "Queues:\n  idx01 pipeline 0 indexqueue: avg 88.0%, max 100.0%, blocked 3 of 30 samples [WARNING: sustained fill]\n  idx01 pipeline 0 parsingqueue: avg 5.0%, max 100.0%, blocked 0 of 30 samples\n"
+    ));
+}
+
+#[test]
+fn test_format_security_health_table() {
+    let source = |src_ip: &str, failures: u64| splunk_client::models::FailedLoginSource {
+        src_ip: src_ip.to_string(),
+        failures,
+        distinct_users: 3,
+        first_seen: "09:00".to_string(),
+        last_seen: "09:59".to_string(),
+    };
+    let health = splunk_client::SecurityHealth::from_sources(
+        "-1h".to_string(),
+        20,
+        vec![source("10.0.0.1", 4), source("203.0.113.7", 52)],
+    );
+
+    let output = TableFormatter.format_security_health(&health).unwrap();
+    assert!(output.contains("Status: Unhealthy"));
+    assert!(output.contains("Failed Logins: 56 (threshold 20 per source IP)"));
+    assert!(output.contains(
+        "Source IPs:\n  203.0.113.7: 52 failed login(s) for 3 user(s), 09:00 to 09:59 [WARNING: possible brute force]\n  10.0.0.1: 4 failed login(s) for 3 user(s), 09:00 to 09:59\n"
+    ));
+}
+
+#[test]
+fn test_format_disk_usage_table() {
+    let partition = |mount_point: &str, free_mb: f64| splunk_client::PartitionSpace {
+        mount_point: mount_point.to_string(),
+        fs_type: Some("ext4".to_string()),
+        capacity_mb: 1000.0,
+        free_mb,
+    };
+    let usage = splunk_client::DiskUsage::new(
+        "idx01".to_string(),
+        vec!["indexer".to_string(), "license_master".to_string()],
+        &splunk_config::HealthThresholds::default(),
+        vec![partition("/", 600.0), partition("/opt/splunk", 150.0)],
+    );
+
+    let output = TableFormatter.format_disk_usage(&usage).unwrap();
+    assert!(output.contains("Status: Unhealthy"));
+    assert!(output.contains("Roles: indexer, license_master"));
+    assert!(output.contains(
+        "Partitions:\n  /opt/splunk (ext4): 85.0% used, 150 of 1000 MB free [WARNING]\n  / (ext4): 40.0% used, 600 of 1000 MB free\n"
+    ));
+}
+
+#[test]
+fn test_format_search_load_table() {
+    let user = |name: &str, running: u64, queued: u64, quota: Option<u64>| {
+        splunk_client::UserSearchUsage {
+            user: name.to_string(),
+            running,
+            queued,
+            realtime: 0,
+            quota,
+        }
+    };
+    let load = splunk_client::SearchLoad::new(
+        splunk_client::SearchConcurrencyLimits {
+            max_hist_searches: Some(26),
+            max_rt_searches: Some(26),
+            ..Default::default()
+        },
+        vec![user("admin", 1, 0, None), user("analyst", 3, 2, Some(3))],
+    );
+
+    let output = TableFormatter.format_search_load(&load).unwrap();
+    assert!(output.starts_with("Search Load:\nStatus: Saturated\nHistorical: 4 / 26 running\n"));
+    assert!(output.contains("Queued: 2\n"));
+    assert!(
+        output.contains(
+            "Warning: User analyst is at their search quota (3 of 3 running, 2 queued)\n"
+        )
+    );
+    let analyst = output.lines().find(|l| l.starts_with("analyst")).unwrap();
+    assert!(analyst.ends_with("3 [AT QUOTA]"), "{analyst}");
+    let admin = output.lines().find(|l| l.starts_with("admin")).unwrap();
+    assert!(admin.ends_with("-"), "{admin}");
+}
+
+#[test]
+fn test_format_resource_usage_table() {
+    let usage = splunk_client::ResourceUsage::new(
+        "-15m".to_string(),
+        None,
+        vec![splunk_client::HostResourceUsage {
+            host: "sh01".to_string(),
+            cpu_pct: 71.9,
+            mem_used_mb: 12288.0,
+            mem_total_mb: 16384.0,
+            iowait_pct: None,
+            cpu_count: Some(8),
+            last_seen: None,
+        }],
+        Vec::new(),
+    );
+
+    let output = TableFormatter.format_resource_usage(&usage).unwrap();
+    assert!(output.starts_with("Resource Usage (since -15m):\nHosts:\n"));
+    assert!(
+        output.contains(
+            "  sh01: CPU 71.9% (8 cores), Memory 75.0% (12288 of 16384 MB), I/O Wait N/A"
+        )
+    );
+    assert!(!output.contains("Top Processes"));
+}
+
 #[test]
 fn test_format_license_table() {
     let formatter = TableFormatter;
@@ -225,6 +394,7 @@ fn test_users_table_formatting() {
             default_app: Some("launcher".to_string()),
             roles: vec!["admin".to_string(), "power".to_string()],
             last_successful_login: Some(1704067200),
+            locked_out: false,
         },
         User {
             name: "user1".to_string(),
@@ -234,6 +404,7 @@ fn test_users_table_formatting() {
             default_app: None,
             roles: vec![],
             last_successful_login: None,
+            locked_out: false,
         },
     ];
     let output = formatter.format_users(&users).unwrap();
@@ -269,6 +440,7 @@ fn test_table_null_fields_in_users() {
         default_app: None,
         roles: vec![],
         last_successful_login: None,
+        locked_out: false,
     }];
     let output = formatter.format_users(&users).unwrap();
     // Null fields should show as "-"
@@ -291,6 +463,7 @@ fn test_table_unicode_in_users() {
             default_app: Some("launcher".to_string()),
             roles: vec!["admin".to_string()],
             last_successful_login: Some(1704067200),
+            locked_out: false,
         },
         User {
             name: "user_emoji".to_string(),
@@ -300,6 +473,7 @@ fn test_table_unicode_in_users() {
             default_app: None,
             roles: vec![],
             last_successful_login: None,
+            locked_out: false,
         },
     ];
     let output = formatter.format_users(&users).unwrap();
@@ -320,6 +494,7 @@ fn test_table_wide_characters() {
         default_app: None,
         roles: vec![],
         last_successful_login: None,
+        locked_out: false,
     }];
     let output = formatter.format_users(&users).unwrap();
     // CJK characters should be preserved
@@ -52,6 +52,8 @@ fn test_xml_formatter_indexes_basic() {
         thawed_path: Some("/opt/splunk/var/lib/splunk/main/thaweddb".to_string()),
         cold_to_frozen_dir: None,
         primary_index: Some(true),
+        min_time: None,
+        max_time: None,
     }];
     let output = formatter.format_indexes(&indexes, false).unwrap();
     assert!(output.contains("<?xml"));
@@ -81,6 +83,8 @@ fn test_xml_formatter_indexes_detailed() {
         thawed_path: Some("/opt/splunk/var/lib/splunk/main/thaweddb".to_string()),
         cold_to_frozen_dir: None,
         primary_index: Some(true),
+        min_time: None,
+        max_time: None,
     }];
     let output = formatter.format_indexes(&indexes, true).unwrap();
     assert!(output.contains("<?xml"));
@@ -143,6 +147,7 @@ fn test_kvstore_peers_xml_formatting() {
         replication_status: KvStoreReplicationStatus {
             oplog_size: 100,
             oplog_used: 1.5,
+            replication_lag_secs: None,
         },
     };
     let output = XmlFormatter.format_kvstore_status(&status).unwrap();
@@ -188,6 +193,7 @@ fn test_users_xml_formatting() {
             default_app: Some("launcher".to_string()),
             roles: vec!["admin".to_string(), "power".to_string()],
             last_successful_login: Some(1704067200),
+            locked_out: false,
         },
         User {
             name: "user1".to_string(),
@@ -197,6 +203,7 @@ fn test_users_xml_formatting() {
             default_app: None,
             roles: vec![],
             last_successful_login: None,
+            locked_out: false,
         },
     ];
     let output = formatter.format_users(&users).unwrap();
@@ -1,7 +1,7 @@
 //! Health check XML formatter.
 //!
 //! Responsibilities:
-//! - Format health check results and KVStore status as XML.
+//! - Format health check results, scheduler health, queue health, security health, disk usage, search load, resource usage, and KVStore status as XML.
 //!
 //! Does NOT handle:
 //! - Other resource types.
@@ -9,7 +9,10 @@
 use crate::formatters::DiagnosticReport;
 use crate::formatters::common::escape_xml;
 use anyhow::Result;
-use splunk_client::{HealthCheckOutput, KvStoreStatus};
+use splunk_client::{
+    DiskUsage, HealthCheckOutput, KvStoreStatus, PartitionSpace, QueueHealth, ResourceUsage,
+    SchedulerHealth, SearchLoad, SecurityHealth,
+};
 
 /// Format health check results as XML.
 pub fn format_health(health: &HealthCheckOutput) -> Result<String> {
@@ -162,10 +165,427 @@ pub fn format_health(health: &HealthCheckOutput) -> Result<String> {
         xml.push_str("  </logParsingHealth>\n");
     }
 
+    if let Some(scheduler) = &health.scheduler_health {
+        push_scheduler_health(&mut xml, scheduler, "  ");
+    }
+
+    if let Some(queues) = &health.queue_health {
+        push_queue_health(&mut xml, queues, "  ");
+    }
+
+    if let Some(security) = &health.security_health {
+        push_security_health(&mut xml, security, "  ");
+    }
+
+    if let Some(partition) = &health.disk_partition {
+        xml.push_str("  <diskPartition>\n");
+        push_partition_fields(&mut xml, partition, "    ");
+        xml.push_str("  </diskPartition>\n");
+    }
+
+    if let Some(load) = &health.search_load {
+        push_search_load(&mut xml, load, "  ");
+    }
+
     xml.push_str("</health>");
     Ok(xml)
 }
 
+/// Format skipped/failed scheduled search summary as XML.
+pub fn format_scheduler_health(health: &SchedulerHealth) -> Result<String> {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    push_scheduler_health(&mut xml, health, "");
+    // Drop the trailing newline to match the other top-level documents.
+    xml.pop();
+    Ok(xml)
+}
+
+fn push_scheduler_health(xml: &mut String, health: &SchedulerHealth, indent: &str) {
+    xml.push_str(&format!("{indent}<schedulerHealth>\n"));
+    xml.push_str(&format!(
+        "{indent}  <isHealthy>{}</isHealthy>\n",
+        health.is_healthy
+    ));
+    xml.push_str(&format!(
+        "{indent}  <timeWindow>{}</timeWindow>\n",
+        escape_xml(&health.time_window)
+    ));
+    xml.push_str(&format!(
+        "{indent}  <totalRuns>{}</totalRuns>\n",
+        health.total_runs
+    ));
+    xml.push_str(&format!(
+        "{indent}  <succeeded>{}</succeeded>\n",
+        health.succeeded
+    ));
+    xml.push_str(&format!(
+        "{indent}  <skipped>{}</skipped>\n",
+        health.skipped
+    ));
+    xml.push_str(&format!("{indent}  <failed>{}</failed>\n", health.failed));
+    xml.push_str(&format!("{indent}  <searches>\n"));
+    for search in &health.searches {
+        xml.push_str(&format!("{indent}    <search>\n"));
+        xml.push_str(&format!(
+            "{indent}      <app>{}</app>\n",
+            escape_xml(&search.app)
+        ));
+        xml.push_str(&format!(
+            "{indent}      <name>{}</name>\n",
+            escape_xml(&search.name)
+        ));
+        xml.push_str(&format!(
+            "{indent}      <owner>{}</owner>\n",
+            escape_xml(&search.owner)
+        ));
+        xml.push_str(&format!("{indent}      <runs>{}</runs>\n", search.runs));
+        xml.push_str(&format!(
+            "{indent}      <skipped>{}</skipped>\n",
+            search.skipped
+        ));
+        xml.push_str(&format!(
+            "{indent}      <failed>{}</failed>\n",
+            search.failed
+        ));
+        if let Some(reason) = &search.last_reason {
+            xml.push_str(&format!(
+                "{indent}      <lastReason>{}</lastReason>\n",
+                escape_xml(reason)
+            ));
+        }
+        if let Some(last_run) = &search.last_run {
+            xml.push_str(&format!(
+                "{indent}      <lastRun>{}</lastRun>\n",
+                escape_xml(last_run)
+            ));
+        }
+        xml.push_str(&format!("{indent}    </search>\n"));
+    }
+    xml.push_str(&format!("{indent}  </searches>\n"));
+    xml.push_str(&format!("{indent}</schedulerHealth>\n"));
+}
+
+/// Format ingestion queue fill and blocking per pipeline as XML.
+pub fn format_queue_health(health: &QueueHealth) -> Result<String> {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    push_queue_health(&mut xml, health, "");
+    // Drop the trailing newline to match the other top-level documents.
+    xml.pop();
+    Ok(xml)
+}
+
+fn push_queue_health(xml: &mut String, health: &QueueHealth, indent: &str) {
+    xml.push_str(&format!("{indent}<queueHealth>\n"));
+    xml.push_str(&format!(
+        "{indent}  <isHealthy>{}</isHealthy>\n",
+        health.is_healthy
+    ));
+    xml.push_str(&format!(
+        "{indent}  <timeWindow>{}</timeWindow>\n",
+        escape_xml(&health.time_window)
+    ));
+    xml.push_str(&format!(
+        "{indent}  <blockedCount>{}</blockedCount>\n",
+        health.blocked_count
+    ));
+    xml.push_str(&format!("{indent}  <queues>\n"));
+    for queue in &health.queues {
+        xml.push_str(&format!("{indent}    <queue>\n"));
+        xml.push_str(&format!(
+            "{indent}      <host>{}</host>\n",
+            escape_xml(&queue.host)
+        ));
+        xml.push_str(&format!(
+            "{indent}      <pipeline>{}</pipeline>\n",
+            escape_xml(&queue.pipeline)
+        ));
+        xml.push_str(&format!(
+            "{indent}      <name>{}</name>\n",
+            escape_xml(&queue.queue)
+        ));
+        xml.push_str(&format!(
+            "{indent}      <avgFillPct>{}</avgFillPct>\n",
+            queue.avg_fill_pct
+        ));
+        xml.push_str(&format!(
+            "{indent}      <maxFillPct>{}</maxFillPct>\n",
+            queue.max_fill_pct
+        ));
+        xml.push_str(&format!(
+            "{indent}      <blockedCount>{}</blockedCount>\n",
+            queue.blocked_count
+        ));
+        xml.push_str(&format!(
+            "{indent}      <samples>{}</samples>\n",
+            queue.samples
+        ));
+        xml.push_str(&format!(
+            "{indent}      <sustainedFull>{}</sustainedFull>\n",
+            queue.is_sustained_full()
+        ));
+        xml.push_str(&format!("{indent}    </queue>\n"));
+    }
+    xml.push_str(&format!("{indent}  </queues>\n"));
+    xml.push_str(&format!("{indent}</queueHealth>\n"));
+}
+
+/// Format failed logins per source IP as XML.
+pub fn format_security_health(health: &SecurityHealth) -> Result<String> {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    push_security_health(&mut xml, health, "");
+    // Drop the trailing newline to match the other top-level documents.
+    xml.pop();
+    Ok(xml)
+}
+
+fn push_security_health(xml: &mut String, health: &SecurityHealth, indent: &str) {
+    xml.push_str(&format!("{indent}<securityHealth>\n"));
+    xml.push_str(&format!(
+        "{indent}  <isHealthy>{}</isHealthy>\n",
+        health.is_healthy
+    ));
+    xml.push_str(&format!(
+        "{indent}  <timeWindow>{}</timeWindow>\n",
+        escape_xml(&health.time_window)
+    ));
+    xml.push_str(&format!(
+        "{indent}  <threshold>{}</threshold>\n",
+        health.threshold
+    ));
+    xml.push_str(&format!(
+        "{indent}  <totalFailures>{}</totalFailures>\n",
+        health.total_failures
+    ));
+    xml.push_str(&format!("{indent}  <sources>\n"));
+    for source in &health.sources {
+        xml.push_str(&format!("{indent}    <source>\n"));
+        xml.push_str(&format!(
+            "{indent}      <srcIp>{}</srcIp>\n",
+            escape_xml(&source.src_ip)
+        ));
+        xml.push_str(&format!(
+            "{indent}      <failures>{}</failures>\n",
+            source.failures
+        ));
+        xml.push_str(&format!(
+            "{indent}      <distinctUsers>{}</distinctUsers>\n",
+            source.distinct_users
+        ));
+        xml.push_str(&format!(
+            "{indent}      <firstSeen>{}</firstSeen>\n",
+            escape_xml(&source.first_seen)
+        ));
+        xml.push_str(&format!(
+            "{indent}      <lastSeen>{}</lastSeen>\n",
+            escape_xml(&source.last_seen)
+        ));
+        xml.push_str(&format!(
+            "{indent}      <flagged>{}</flagged>\n",
+            health.is_flagged(source)
+        ));
+        xml.push_str(&format!("{indent}    </source>\n"));
+    }
+    xml.push_str(&format!("{indent}  </sources>\n"));
+    xml.push_str(&format!("{indent}</securityHealth>\n"));
+}
+
+/// Format disk usage per partition as XML.
+pub fn format_disk_usage(usage: &DiskUsage) -> Result<String> {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<diskUsage>\n");
+    xml.push_str(&format!("  <isHealthy>{}</isHealthy>\n", usage.is_healthy));
+    xml.push_str(&format!(
+        "  <serverName>{}</serverName>\n",
+        escape_xml(&usage.server_name)
+    ));
+    xml.push_str("  <serverRoles>\n");
+    for role in &usage.server_roles {
+        xml.push_str(&format!("    <role>{}</role>\n", escape_xml(role)));
+    }
+    xml.push_str("  </serverRoles>\n");
+    xml.push_str(&format!(
+        "  <warningPct>{}</warningPct>\n",
+        usage.warning_pct
+    ));
+    xml.push_str(&format!(
+        "  <criticalPct>{}</criticalPct>\n",
+        usage.critical_pct
+    ));
+    xml.push_str("  <partitions>\n");
+    for partition in &usage.partitions {
+        xml.push_str("    <partition>\n");
+        push_partition_fields(&mut xml, partition, "      ");
+        xml.push_str(&format!(
+            "      <level>{}</level>\n",
+            usage.level(partition)
+        ));
+        xml.push_str("    </partition>\n");
+    }
+    xml.push_str("  </partitions>\n</diskUsage>");
+    Ok(xml)
+}
+
+fn push_partition_fields(xml: &mut String, partition: &PartitionSpace, indent: &str) {
+    xml.push_str(&format!(
+        "{indent}<mountPoint>{}</mountPoint>\n",
+        escape_xml(&partition.mount_point)
+    ));
+    if let Some(fs_type) = &partition.fs_type {
+        xml.push_str(&format!(
+            "{indent}<fsType>{}</fsType>\n",
+            escape_xml(fs_type)
+        ));
+    }
+    xml.push_str(&format!(
+        "{indent}<capacityMb>{:.0}</capacityMb>\n",
+        partition.capacity_mb
+    ));
+    xml.push_str(&format!(
+        "{indent}<freeMb>{:.0}</freeMb>\n",
+        partition.free_mb
+    ));
+    if let Some(pct) = partition.used_percent() {
+        xml.push_str(&format!("{indent}<usedPct>{:.1}</usedPct>\n", pct));
+    }
+}
+
+/// Format running and queued searches per user as XML.
+pub fn format_search_load(load: &SearchLoad) -> Result<String> {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    push_search_load(&mut xml, load, "");
+    // Drop the trailing newline to match the other top-level documents.
+    xml.pop();
+    Ok(xml)
+}
+
+fn push_search_load(xml: &mut String, load: &SearchLoad, indent: &str) {
+    xml.push_str(&format!("{indent}<searchLoad>\n"));
+    xml.push_str(&format!(
+        "{indent}  <isHealthy>{}</isHealthy>\n",
+        load.is_healthy
+    ));
+    xml.push_str(&format!("{indent}  <running>{}</running>\n", load.running));
+    xml.push_str(&format!("{indent}  <queued>{}</queued>\n", load.queued));
+    xml.push_str(&format!(
+        "{indent}  <realtime>{}</realtime>\n",
+        load.realtime
+    ));
+    xml.push_str(&format!("{indent}  <limits>\n"));
+    for (tag, value) in [
+        ("maxHistSearches", load.limits.max_hist_searches),
+        (
+            "maxHistScheduledSearches",
+            load.limits.max_hist_scheduled_searches,
+        ),
+        ("maxRtSearches", load.limits.max_rt_searches),
+        (
+            "maxRtScheduledSearches",
+            load.limits.max_rt_scheduled_searches,
+        ),
+        (
+            "maxAutoSummarySearches",
+            load.limits.max_auto_summary_searches,
+        ),
+    ] {
+        if let Some(value) = value {
+            xml.push_str(&format!("{indent}    <{tag}>{value}</{tag}>\n"));
+        }
+    }
+    xml.push_str(&format!("{indent}  </limits>\n"));
+    xml.push_str(&format!("{indent}  <users>\n"));
+    for user in &load.users {
+        xml.push_str(&format!("{indent}    <user>\n"));
+        xml.push_str(&format!(
+            "{indent}      <name>{}</name>\n",
+            escape_xml(&user.user)
+        ));
+        xml.push_str(&format!(
+            "{indent}      <running>{}</running>\n",
+            user.running
+        ));
+        xml.push_str(&format!("{indent}      <queued>{}</queued>\n", user.queued));
+        xml.push_str(&format!(
+            "{indent}      <realtime>{}</realtime>\n",
+            user.realtime
+        ));
+        if let Some(quota) = user.quota {
+            xml.push_str(&format!("{indent}      <quota>{}</quota>\n", quota));
+        }
+        xml.push_str(&format!(
+            "{indent}      <atQuota>{}</atQuota>\n",
+            user.is_at_quota()
+        ));
+        xml.push_str(&format!("{indent}    </user>\n"));
+    }
+    xml.push_str(&format!("{indent}  </users>\n"));
+    xml.push_str(&format!("{indent}</searchLoad>\n"));
+}
+
+/// Format per-host resource usage and top processes as XML.
+pub fn format_resource_usage(usage: &ResourceUsage) -> Result<String> {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<resourceUsage>\n");
+    xml.push_str(&format!(
+        "  <timeWindow>{}</timeWindow>\n",
+        escape_xml(&usage.time_window)
+    ));
+    if let Some(host) = &usage.host {
+        xml.push_str(&format!("  <host>{}</host>\n", escape_xml(host)));
+    }
+    xml.push_str("  <hosts>\n");
+    for host in &usage.hosts {
+        xml.push_str("    <host>\n");
+        xml.push_str(&format!("      <name>{}</name>\n", escape_xml(&host.host)));
+        xml.push_str(&format!("      <cpuPct>{}</cpuPct>\n", host.cpu_pct));
+        xml.push_str(&format!(
+            "      <memUsedMb>{}</memUsedMb>\n",
+            host.mem_used_mb
+        ));
+        xml.push_str(&format!(
+            "      <memTotalMb>{}</memTotalMb>\n",
+            host.mem_total_mb
+        ));
+        if let Some(iowait) = host.iowait_pct {
+            xml.push_str(&format!("      <iowaitPct>{}</iowaitPct>\n", iowait));
+        }
+        if let Some(count) = host.cpu_count {
+            xml.push_str(&format!("      <cpuCount>{}</cpuCount>\n", count));
+        }
+        if let Some(last_seen) = &host.last_seen {
+            xml.push_str(&format!(
+                "      <lastSeen>{}</lastSeen>\n",
+                escape_xml(last_seen)
+            ));
+        }
+        xml.push_str("    </host>\n");
+    }
+    xml.push_str("  </hosts>\n");
+    xml.push_str("  <processes>\n");
+    for process in &usage.processes {
+        xml.push_str("    <process>\n");
+        xml.push_str(&format!(
+            "      <host>{}</host>\n",
+            escape_xml(&process.host)
+        ));
+        xml.push_str(&format!(
+            "      <name>{}</name>\n",
+            escape_xml(&process.process)
+        ));
+        xml.push_str(&format!("      <pid>{}</pid>\n", escape_xml(&process.pid)));
+        xml.push_str(&format!(
+            "      <processType>{}</processType>\n",
+            escape_xml(&process.process_type)
+        ));
+        xml.push_str(&format!("      <cpuPct>{}</cpuPct>\n", process.cpu_pct));
+        xml.push_str(&format!(
+            "      <memUsedMb>{}</memUsedMb>\n",
+            process.mem_used_mb
+        ));
+        xml.push_str("    </process>\n");
+    }
+    xml.push_str("  </processes>\n");
+    xml.push_str("</resourceUsage>");
+    Ok(xml)
+}
+
 /// Format KVStore status as XML.
 pub fn format_kvstore_status(status: &KvStoreStatus) -> Result<String> {
     let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<kvstoreStatus>\n");
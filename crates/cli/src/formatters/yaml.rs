@@ -10,8 +10,8 @@
 
 use crate::formatters::{
     ClusterInfoOutput, ClusterManagementOutput, ClusterPeerOutput, Formatter, LicenseInfoOutput,
-    LicenseInstallOutput, LicensePoolOperationOutput, Pagination, ShcCaptainOutput,
-    ShcConfigOutput, ShcManagementOutput, ShcMemberOutput, ShcStatusOutput,
+    LicenseInstallOutput, LicensePoolOperationOutput, LicenseSlaveOutput, Pagination,
+    ShcCaptainOutput, ShcConfigOutput, ShcManagementOutput, ShcMemberOutput, ShcStatusOutput,
     common::{build_named_profile_display, build_profile_display_map},
 };
 use anyhow::Result;
@@ -23,6 +23,7 @@ use splunk_client::{
     App, Forwarder, HealthCheckOutput, Index, KvStoreStatus, LicensePool, SavedSearch,
     SearchJobStatus, User,
 };
+use splunk_client::{ClusterFixupTask, ClusterIndexCompliance, ClusterIndexExcess, LicenseMessage};
 use splunk_config::types::ProfileConfig;
 use std::collections::BTreeMap;
 
@@ -62,6 +63,29 @@ impl Formatter for YamlFormatter {
         Ok(serde_yaml::to_string(output)?)
     }
 
+    fn format_license_messages(&self, messages: &[LicenseMessage]) -> Result<String> {
+        Ok(serde_yaml::to_string(messages)?)
+    }
+
+    fn format_license_slaves(&self, slaves: &[LicenseSlaveOutput]) -> Result<String> {
+        Ok(serde_yaml::to_string(slaves)?)
+    }
+
+    fn format_cluster_fixups(&self, fixups: &[ClusterFixupTask]) -> Result<String> {
+        Ok(serde_yaml::to_string(fixups)?)
+    }
+
+    fn format_cluster_excess_buckets(&self, indexes: &[ClusterIndexExcess]) -> Result<String> {
+        Ok(serde_yaml::to_string(indexes)?)
+    }
+
+    fn format_cluster_index_compliance(
+        &self,
+        indexes: &[ClusterIndexCompliance],
+    ) -> Result<String> {
+        Ok(serde_yaml::to_string(indexes)?)
+    }
+
     fn format_health(&self, health: &HealthCheckOutput) -> Result<String> {
         Ok(serde_yaml::to_string(health)?)
     }
@@ -73,6 +97,30 @@ impl Formatter for YamlFormatter {
         Ok(serde_yaml::to_string(report)?)
     }
 
+    fn format_scheduler_health(&self, health: &splunk_client::SchedulerHealth) -> Result<String> {
+        Ok(serde_yaml::to_string(health)?)
+    }
+
+    fn format_queue_health(&self, health: &splunk_client::QueueHealth) -> Result<String> {
+        Ok(serde_yaml::to_string(health)?)
+    }
+
+    fn format_security_health(&self, health: &splunk_client::SecurityHealth) -> Result<String> {
+        Ok(serde_yaml::to_string(health)?)
+    }
+
+    fn format_disk_usage(&self, usage: &splunk_client::DiskUsage) -> Result<String> {
+        Ok(serde_yaml::to_string(usage)?)
+    }
+
+    fn format_search_load(&self, load: &splunk_client::SearchLoad) -> Result<String> {
+        Ok(serde_yaml::to_string(load)?)
+    }
+
+    fn format_resource_usage(&self, usage: &splunk_client::ResourceUsage) -> Result<String> {
+        Ok(serde_yaml::to_string(usage)?)
+    }
+
     fn format_kvstore_status(&self, status: &KvStoreStatus) -> Result<String> {
         Ok(serde_yaml::to_string(status)?)
     }
@@ -179,6 +227,10 @@ impl Formatter for YamlFormatter {
         Ok(serde_yaml::to_string(roles)?)
     }
 
+    fn format_role_usage(&self, usage: &[splunk_client::RoleUsage]) -> Result<String> {
+        Ok(serde_yaml::to_string(usage)?)
+    }
+
     fn format_capabilities(&self, capabilities: &[splunk_client::Capability]) -> Result<String> {
         Ok(serde_yaml::to_string(capabilities)?)
     }
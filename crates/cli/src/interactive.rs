@@ -48,6 +48,27 @@ pub fn confirm_delete(item_name: &str, item_type: &str) -> Result<bool> {
     Ok(true)
 }
 
+/// Prompt the user to confirm a splunkd restart.
+///
+/// Returns `true` if the user confirms (enters 'y' or 'Y'), `false` otherwise.
+pub fn confirm_restart(server_name: &str) -> Result<bool> {
+    print!(
+        "Are you sure you want to restart Splunk on '{}'? [y/N] ",
+        server_name
+    );
+    std::io::stdout().flush()?;
+
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input)?;
+
+    if !input.trim().eq_ignore_ascii_case("y") {
+        println!("Restart cancelled.");
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
 #[cfg(test)]
 mod tests {
     // Note: confirm_delete cannot be easily unit tested due to stdin interaction.
@@ -15,6 +15,8 @@
 //! Invariants:
 //! - `load_dotenv()` is called BEFORE CLI parsing to allow `.env` to provide clap defaults.
 //! - Global options (like `--base-url`) are applied consistently across all subcommands.
+//! - Every failure exits through `error::report_error`, so the exit code and
+//!   `--error-format` apply to argument, configuration, and command errors alike.
 
 mod args;
 #[macro_use]
@@ -30,54 +32,106 @@ mod progress;
 mod telemetry;
 
 use args::Cli;
-use cancellation::{CancellationToken, is_cancelled_error, print_cancelled_message};
+use cancellation::CancellationToken;
 use clap::Parser;
 use config_context::ConfigCommandContext;
 use dispatch::run_command;
-use error::{ExitCode, ExitCodeExt};
+use error::{ErrorFormat, ExitCode, UsageError};
 use splunk_config::ConfigLoader;
+
+/// Report `err` in `format` and exit with its exit code.
+fn exit_with_error(err: anyhow::Error, format: ErrorFormat) -> ! {
+    std::process::exit(error::report_error(&err, format).as_i32());
+}
+
+/// Report a configuration problem as a usage error and exit.
+fn exit_with_usage_error(context: &str, err: impl std::fmt::Display, format: ErrorFormat) -> ! {
+    exit_with_error(UsageError(format!("{}: {:#}", context, err)).into(), format);
+}
+
 #[tokio::main]
 async fn main() {
     // Load .env file BEFORE CLI parsing so clap env defaults can read .env values
     if let Err(e) = ConfigLoader::new().load_dotenv() {
-        eprintln!("Failed to load environment: {}", e);
-        std::process::exit(ExitCode::GeneralError.as_i32());
+        exit_with_usage_error(
+            "Failed to load environment",
+            e,
+            ErrorFormat::from_raw_args(),
+        );
+    }
+
+    let mut cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        // Help and version go to stdout and exit 0
+        Err(e) if !e.use_stderr() => e.exit(),
+        Err(e) => match ErrorFormat::from_raw_args() {
+            // clap exits with the usage error code (2) itself
+            ErrorFormat::Text => e.exit(),
+            ErrorFormat::Json => {
+                let rendered = e.render().to_string();
+                let message = rendered
+                    .lines()
+                    .next()
+                    .unwrap_or_default()
+                    .trim_start_matches("error: ");
+                exit_with_error(UsageError(message.to_string()).into(), ErrorFormat::Json)
+            }
+        },
+    };
+    let error_format = cli.error_format;
+
+    // `--get` extracts from JSON output, whatever `--output` says
+    if let Some(value_path) = cli.get.take() {
+        cli.output = "json".to_string();
+        formatters::install_value_path(value_path);
     }
 
-    let cli = Cli::parse();
+    // `--fields` / `--query` filter JSON output, then render the requested format
+    if let Err(e) =
+        formatters::configure_output_filter(&mut cli.output, cli.fields.take(), cli.query.take())
+    {
+        exit_with_usage_error("Invalid output filter", e, error_format);
+    }
 
     let _telemetry = match telemetry::init(
         cli.otlp_endpoint.as_deref(),
         cli.otel_service_name.as_deref(),
         cli.metrics_bind.as_deref(),
+        formatters::installed_value_path().is_some(),
     ) {
         Ok(state) => state,
-        Err(e) => {
-            eprintln!("Failed to initialize CLI telemetry: {}", e);
-            std::process::exit(ExitCode::GeneralError.as_i32());
-        }
+        Err(e) => exit_with_error(
+            anyhow::anyhow!("Failed to initialize CLI telemetry: {}", e),
+            error_format,
+        ),
     };
 
     // Determine if we need a real config or can use a placeholder
-    // Config commands, multi-profile list-all, and HEC commands don't need standard connection details
-    let is_multi_profile_list_all = matches!(
-        cli.command,
-        args::Commands::ListAll {
-            all_profiles: true,
-            ..
-        } | args::Commands::ListAll {
-            profiles: Some(_),
-            ..
-        }
-    );
-    let needs_real_config = !matches!(
-        cli.command,
-        args::Commands::Config { .. }
-            | args::Commands::Hec { .. }
-            | args::Commands::Completions { .. }
-            | args::Commands::Complete { .. }
-            | args::Commands::Man
-    ) && !is_multi_profile_list_all;
+    // Config commands, multi-profile commands, HEC event/generate commands, saving search
+    // templates, audit archive verification, and offline mode don't need standard connection details
+    let is_multi_profile = commands::multi_profile::requested_targets(&cli.command).is_some();
+    let needs_real_config = !cli.offline
+        && !matches!(
+            cli.command,
+            args::Commands::Config { .. }
+                | args::Commands::Hec {
+                    command: commands::hec::HecCommand::Send { .. }
+                        | commands::hec::HecCommand::SendBatch { .. }
+                        | commands::hec::HecCommand::Health { .. }
+                        | commands::hec::HecCommand::CheckAck { .. }
+                }
+                | args::Commands::Generate { .. }
+                | args::Commands::Completions { .. }
+                | args::Commands::Complete { .. }
+                | args::Commands::Man
+                | args::Commands::Templates {
+                    command: commands::templates::TemplatesCommand::Save { .. }
+                }
+                | args::Commands::Audit {
+                    command: commands::audit::AuditCommand::VerifyArchive { .. }
+                }
+        )
+        && !is_multi_profile;
 
     // Build configuration only if needed
     let config = if needs_real_config {
@@ -109,20 +163,22 @@ async fn main() {
         // if not already set via CLI args). Env vars override profile values.
         loader = match loader.from_env() {
             Ok(l) => l,
-            Err(e) => {
-                eprintln!("Failed to load configuration from environment: {:#}", e);
-                std::process::exit(ExitCode::GeneralError.as_i32());
-            }
+            Err(e) => exit_with_usage_error(
+                "Failed to load configuration from environment",
+                e,
+                error_format,
+            ),
         };
 
         // Load from profile if profile_name is now set (from CLI or env var)
         if loader.profile_name().is_some() {
             loader = match loader.from_profile() {
                 Ok(l) => l,
-                Err(e) => {
-                    eprintln!("Failed to load configuration from profile: {:#}", e);
-                    std::process::exit(ExitCode::GeneralError.as_i32());
-                }
+                Err(e) => exit_with_usage_error(
+                    "Failed to load configuration from profile",
+                    e,
+                    error_format,
+                ),
             };
         }
 
@@ -165,6 +221,16 @@ async fn main() {
         if let Some(requests) = cli.circuit_half_open_requests {
             loader = loader.with_circuit_half_open_requests(requests);
         }
+        if let Some(rate_limit) = cli.rate_limit {
+            loader = loader.with_rate_limit(rate_limit);
+        }
+        if cli.app.is_some() || cli.owner.is_some() || cli.sharing.is_some() {
+            loader = loader.with_namespace(splunk_config::NamespaceConfig {
+                owner: cli.owner.clone(),
+                app: cli.app.clone(),
+                sharing: cli.sharing.clone(),
+            });
+        }
 
         // Build search defaults with env var overrides (matching TUI behavior)
         // Must be done before loader.build() since build() consumes the loader
@@ -172,10 +238,7 @@ async fn main() {
 
         let config = match loader.build() {
             Ok(c) => c,
-            Err(e) => {
-                eprintln!("Failed to build configuration: {:#}", e);
-                std::process::exit(ExitCode::GeneralError.as_i32());
-            }
+            Err(e) => exit_with_usage_error("Failed to build configuration", e, error_format),
         };
 
         // Warn if using default credentials (security check)
@@ -210,21 +273,34 @@ async fn main() {
         ConfigCommandContext::Placeholder
     };
 
+    // Install the HTTP trace recorder before any client is built
+    let http_trace = cli
+        .trace_http
+        .clone()
+        .map(|path| (path, commands::install_http_trace_recorder()));
+
     // Execute command
-    let exit_code = match run_command(cli, config_context, &cancel).await {
+    let result = if cli.offline {
+        commands::offline::run(&cli)
+    } else {
+        run_command(cli, config_context, &cancel).await
+    };
+    let exit_code = match result {
         Ok(()) => ExitCode::Success,
-        Err(e) if is_cancelled_error(&e) => {
-            print_cancelled_message();
-            ExitCode::Interrupted
-        }
-        Err(e) => {
-            // Print the error message
-            eprintln!("{:#}", e);
+        Err(e) => error::report_error(&e, error_format),
+    };
 
-            // Return structured exit code
-            e.exit_code()
+    // Write the trace even when the command failed; that is when it is most useful
+    if let Some((path, recorder)) = http_trace {
+        match recorder.write_to_file(&path) {
+            Ok(()) => eprintln!(
+                "Wrote {} HTTP trace entries to {}",
+                recorder.len(),
+                path.display()
+            ),
+            Err(e) => eprintln!("Failed to write HTTP trace to {}: {}", path.display(), e),
         }
-    };
+    }
 
     std::process::exit(exit_code.as_i32());
 }
@@ -140,3 +140,67 @@ impl Drop for Spinner {
         }
     }
 }
+
+/// A count-based progress bar for operations with a known number of items.
+///
+/// Shows `done/total` and throughput, always drawing to STDERR; no-op when disabled.
+pub(crate) struct CountProgress {
+    label: String,
+    pb: Option<ProgressBar>,
+}
+
+impl CountProgress {
+    /// Create a new count progress indicator.
+    ///
+    /// `enabled` should be `!quiet`.
+    pub(crate) fn new(enabled: bool, label: impl Into<String>, total: u64) -> Self {
+        let label = label.into();
+
+        if !enabled {
+            return Self { label, pb: None };
+        }
+
+        let pb = ProgressBar::new(total);
+        pb.set_draw_target(ProgressDrawTarget::stderr());
+        pb.set_style(
+            ProgressStyle::with_template(
+                "{spinner} {msg} [{bar:40.cyan/blue}] {pos}/{len} ({per_sec}, {elapsed})",
+            )
+            .expect("template is a compile-time constant with valid syntax")
+            .progress_chars("=>-"),
+        );
+        pb.set_message(label.clone());
+        pb.enable_steady_tick(Duration::from_millis(100));
+
+        Self {
+            label,
+            pb: Some(pb),
+        }
+    }
+
+    /// Advance the progress by `delta` items.
+    pub(crate) fn inc(&self, delta: u64) {
+        if let Some(pb) = &self.pb {
+            pb.inc(delta);
+        }
+    }
+
+    /// Finish the progress indicator with a stable message (on STDERR).
+    pub(crate) fn finish(&self) {
+        let Some(pb) = &self.pb else {
+            return;
+        };
+
+        pb.finish_with_message(format!("{} done", self.label));
+    }
+}
+
+impl Drop for CountProgress {
+    fn drop(&mut self) {
+        if let Some(pb) = &self.pb
+            && !pb.is_finished()
+        {
+            pb.finish_and_clear();
+        }
+    }
+}
@@ -10,7 +10,8 @@
 //! - Request-level trace propagation (handled by `splunk-client`).
 //!
 //! Invariants:
-//! - The CLI owns stdout logging configuration.
+//! - The CLI owns stdout logging configuration; logs move to stderr when stdout must
+//!   carry only command output (`--get`).
 //! - OTLP and metrics bootstrapping are binary concerns, not client-library concerns.
 
 use metrics_exporter_prometheus::PrometheusBuilder;
@@ -18,6 +19,7 @@ use opentelemetry::trace::TracerProvider;
 use opentelemetry_sdk::trace::SdkTracerProvider;
 use std::net::SocketAddr;
 use std::time::Duration;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
 use tracing_subscriber::{EnvFilter, fmt, layer::SubscriberExt, util::SubscriberInitExt};
 
 /// Holds telemetry resources for the CLI process lifetime.
@@ -35,12 +37,15 @@ impl TelemetryState {
 }
 
 /// Initialize CLI-local tracing and optional metrics exporting.
+///
+/// Logs are written to stdout unless `log_to_stderr` is set.
 pub fn init(
     otlp_endpoint: Option<&str>,
     service_name: Option<&str>,
     metrics_bind: Option<&str>,
+    log_to_stderr: bool,
 ) -> Result<TelemetryState, String> {
-    let tracing_guard = init_tracing(otlp_endpoint, service_name)?;
+    let tracing_guard = init_tracing(otlp_endpoint, service_name, log_to_stderr)?;
     let metrics_exporter = if let Some(bind_addr) = metrics_bind {
         Some(MetricsExporter::install(bind_addr).map_err(|e| e.to_string())?)
     } else {
@@ -56,8 +61,16 @@ pub fn init(
 fn init_tracing(
     otlp_endpoint: Option<&str>,
     service_name: Option<&str>,
+    log_to_stderr: bool,
 ) -> Result<Option<TracingGuard>, String> {
     let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let writer = || {
+        if log_to_stderr {
+            BoxMakeWriter::new(std::io::stderr)
+        } else {
+            BoxMakeWriter::new(std::io::stdout)
+        }
+    };
 
     if let Some(endpoint) = otlp_endpoint {
         let provider = create_tracer_provider(
@@ -70,7 +83,7 @@ fn init_tracing(
         tracing_subscriber::registry()
             .with(env_filter)
             .with(otel_layer)
-            .with(fmt::layer())
+            .with(fmt::layer().with_writer(writer()))
             .init();
         Ok(Some(TracingGuard {
             provider: Some(provider),
@@ -78,7 +91,7 @@ fn init_tracing(
     } else {
         tracing_subscriber::registry()
             .with(env_filter)
-            .with(fmt::layer())
+            .with(fmt::layer().with_writer(writer()))
             .init();
         Ok(None)
     }
@@ -0,0 +1,126 @@
+//! Integration tests for `splunk-cli acs` and Splunk Cloud mode.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn test_acs_help() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["acs", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("indexes").and(predicate::str::contains("hec-tokens")));
+}
+
+#[tokio::test]
+async fn test_acs_indexes_list_csv() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = include_str!("../../client/fixtures/acs/list_indexes.json");
+
+    Mock::given(method("GET"))
+        .and(path("/acme/adminconfig/v2/indexes"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(fixture))
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri())
+        .env("SPLUNK_ACS_URL", mock_server.uri())
+        .env("SPLUNK_ACS_STACK", "acme");
+
+    cmd.args(["acs", "indexes", "list", "-o", "csv"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "name,datatype,searchable_days,max_data_size_mb",
+        ))
+        .stdout(predicate::str::contains("main,event,90,0,1523400,812,"))
+        .stdout(predicate::str::contains("web_metrics,metric,30"));
+}
+
+#[tokio::test]
+async fn test_acs_hec_tokens_create() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/acme/adminconfig/v2/inputs/http-event-collectors"))
+        .and(body_json(serde_json::json!({
+            "name": "web-hec",
+            "defaultIndex": "web",
+            "allowedIndexes": ["web", "main"],
+            "disabled": false,
+            "useAck": false
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(serde_json::json!({
+            "http-event-collector": {
+                "spec": { "name": "web-hec", "defaultIndex": "web" },
+                "token": "new-token-value"
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri())
+        .env("SPLUNK_ACS_URL", mock_server.uri())
+        .env("SPLUNK_ACS_STACK", "acme");
+
+    cmd.args([
+        "acs",
+        "hec-tokens",
+        "create",
+        "web-hec",
+        "--default-index",
+        "web",
+        "--allowed-index",
+        "web",
+        "--allowed-index",
+        "main",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("HEC token 'web-hec' created."))
+    .stdout(predicate::str::contains("Token: new-token-value"));
+}
+
+#[tokio::test]
+async fn test_acs_without_stack_fails_with_hint() {
+    let mock_server = MockServer::start().await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["acs", "hec-tokens", "list"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("SPLUNK_ACS_STACK"));
+}
+
+#[tokio::test]
+async fn test_cloud_mode_rejects_on_prem_index_create() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/data/indexes"))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri())
+        .env("SPLUNK_CLOUD", "true");
+
+    cmd.args(["indexes", "create", "web"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("not available on Splunk Cloud"))
+        .stderr(predicate::str::contains("splunk-cli acs indexes create"));
+}
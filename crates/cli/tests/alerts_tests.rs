@@ -0,0 +1,155 @@
+//! Integration tests for `splunk-cli alerts` command.
+//!
+//! Tests cover:
+//! - Help text verification for the alert action subcommands (actions, test-action)
+//! - Showing the actions a saved search triggers against a mock server
+//! - Test-firing alert actions (dry run and dispatched) against a mock server
+//!
+//! Does NOT:
+//! - Test live Splunk server interactions (see `test-live` in Makefile).
+//!
+//! Invariants:
+//! - All tests use hermetic CLI commands via `splunk_cmd()` to prevent env leakage.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_saved_search(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/services/saved/searches/Errors"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "Errors",
+                "content": {
+                    "search": "index=main log_level=ERROR",
+                    "actions": "webhook,script",
+                    "action.webhook.param.url": "https://hooks.example.com/splunk",
+                    "action.script.filename": "page_oncall.sh"
+                }
+            }]
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[test]
+fn test_alerts_help_lists_action_subcommands() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["alerts", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("actions"))
+        .stdout(predicate::str::contains("test-action"));
+}
+
+#[test]
+fn test_alerts_test_action_help() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["alerts", "test-action", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--action"))
+        .stdout(predicate::str::contains("--dry-run"));
+}
+
+#[tokio::test]
+async fn test_alerts_actions_for_saved_search() {
+    let mock_server = MockServer::start().await;
+    mount_saved_search(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["alerts", "actions", "Errors", "--output", "csv"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Errors,webhook,param.url,https://hooks.example.com/splunk",
+        ))
+        .stdout(predicate::str::contains(
+            "Errors,script,filename,page_oncall.sh",
+        ));
+}
+
+#[tokio::test]
+async fn test_alerts_test_action_dry_run_does_not_dispatch() {
+    let mock_server = MockServer::start().await;
+    mount_saved_search(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["alerts", "test-action", "Errors", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("webhook              planned"))
+        .stdout(predicate::str::contains(
+            "| sendalert webhook param.url=\"https://hooks.example.com/splunk\"",
+        ))
+        .stdout(predicate::str::contains("script               unsupported"));
+}
+
+#[tokio::test]
+async fn test_alerts_test_action_fails_when_delivery_errors() {
+    let mock_server = MockServer::start().await;
+    mount_saved_search(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains("sendalert+webhook"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": "alert-test-sid" } }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/alert-test-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": {
+                "sid": "alert-test-sid",
+                "isDone": true,
+                "isFailed": false,
+                "doneProgress": 1.0,
+                "messages": [{
+                    "type": "ERROR",
+                    "text": "Error in 'sendalert' command: Alert script returned error code 2."
+                }]
+            } }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args([
+        "alerts",
+        "test-action",
+        "Errors",
+        "--action",
+        "webhook",
+        "--output",
+        "json",
+    ])
+    .assert()
+    .failure()
+    .stdout(predicate::str::contains("\"status\": \"failed\""))
+    .stdout(predicate::str::contains(
+        "Alert script returned error code 2.",
+    ))
+    .stderr(predicate::str::contains(
+        "1 of 1 alert action(s) failed to fire",
+    ));
+}
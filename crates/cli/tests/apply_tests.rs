@@ -0,0 +1,138 @@
+//! Integration tests for `splunk-cli apply`.
+//!
+//! Responsibilities:
+//! - Verify plans are printed without changing the server by default.
+//! - Verify `--auto-approve` runs the planned create/delete operations.
+//! - Verify invalid specs fail before any request is made.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const SPEC: &str = "\
+indexes:
+  - name: web
+    max_data_size_mb: 1000
+roles:
+  - name: old_role
+    state: absent
+";
+
+async fn mock_live_state(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/services/data/indexes"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "main",
+                "content": { "currentDBSizeMB": 1, "totalEventCount": 10 }
+            }]
+        })))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/authorization/roles"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "name": "old_role", "content": {} }]
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+fn write_spec(dir: &TempDir, contents: &str) -> std::path::PathBuf {
+    let spec = dir.path().join("resources.yaml");
+    std::fs::write(&spec, contents).unwrap();
+    spec
+}
+
+#[test]
+fn test_apply_help() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["apply", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--auto-approve").and(predicate::str::contains("--file")));
+}
+
+#[tokio::test]
+async fn test_apply_prints_plan_without_changes() {
+    let mock_server = MockServer::start().await;
+    mock_live_state(&mock_server).await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let dir = TempDir::new().unwrap();
+    let spec = write_spec(&dir, SPEC);
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["apply", "-f", spec.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Plan: 1 to create, 0 to update, 1 to delete.")
+                .and(predicate::str::contains("+ index web"))
+                .and(predicate::str::contains("- role old_role"))
+                .and(predicate::str::contains("Run with --auto-approve")),
+        );
+}
+
+#[tokio::test]
+async fn test_apply_auto_approve_runs_plan() {
+    let mock_server = MockServer::start().await;
+    mock_live_state(&mock_server).await;
+    Mock::given(method("POST"))
+        .and(path("/services/data/indexes"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "web",
+                "content": { "currentDBSizeMB": 0, "totalEventCount": 0 }
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/services/authorization/roles/old_role"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let dir = TempDir::new().unwrap();
+    let spec = write_spec(&dir, SPEC);
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri())
+        .env("HOME", dir.path())
+        .env("XDG_CONFIG_HOME", dir.path().join("config"));
+    cmd.args(["apply", "-f", spec.to_str().unwrap(), "--auto-approve"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Applied 2 changes"));
+}
+
+#[test]
+fn test_apply_rejects_invalid_spec() {
+    let dir = TempDir::new().unwrap();
+    let spec = write_spec(&dir, "indexes:\n  - name: web\n    retention: forever\n");
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", "https://localhost:8089");
+    cmd.args(["apply", "-f", spec.to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid spec"));
+}
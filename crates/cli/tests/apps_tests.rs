@@ -1,7 +1,7 @@
 //! Integration tests for `splunk-cli apps` command.
 //!
 //! Responsibilities:
-//! - Validate `apps list`, `info`, `enable`, `disable`, `install`, and `remove` subcommands.
+//! - Validate `apps list`, `info`, `contents`, `enable`, `disable`, `install`, and `remove` subcommands.
 //! - Ensure help text and argument validation work correctly.
 //! - Verify that commands attempt network connection with correct parameters.
 //!
@@ -25,6 +25,7 @@ fn test_apps_help() {
         predicate::str::contains("List and manage installed Splunk apps")
             .and(predicate::str::contains("list"))
             .and(predicate::str::contains("info"))
+            .and(predicate::str::contains("contents"))
             .and(predicate::str::contains("enable"))
             .and(predicate::str::contains("disable"))
             .and(predicate::str::contains("install"))
@@ -97,6 +98,63 @@ fn test_apps_info_valid_name() {
     result.failure().stderr(connection_error_predicate());
 }
 
+/// Test that missing app name for contents shows error
+#[test]
+fn test_apps_contents_missing_name() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["apps", "contents"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ));
+}
+
+/// Test that `splunk-cli apps contents <name>` lists objects from the app namespace
+#[tokio::test]
+async fn test_apps_contents_lists_app_objects() {
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let mock_server = MockServer::start().await;
+
+    let empty = serde_json::json!({ "entry": [] });
+    for endpoint in [
+        "data/ui/views",
+        "data/lookup-table-files",
+        "admin/macros",
+        "saved/eventtypes",
+    ] {
+        Mock::given(method("GET"))
+            .and(path(format!("/servicesNS/-/my_app/{endpoint}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&empty))
+            .mount(&mock_server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path("/servicesNS/-/my_app/saved/searches"))
+        .and(query_param("search", "eai:acl.app=\"my_app\""))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{"name": "Errors by host", "content": {"search": "index=main error"}}]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["apps", "contents", "my_app"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Contents of app 'my_app' (1 object)")
+                .and(predicate::str::contains("Errors by host"))
+                .and(predicate::str::contains("Event Types (0):")),
+        );
+}
+
 /// Test that `splunk-cli apps enable --help` shows enable usage
 #[test]
 fn test_apps_enable_help() {
@@ -187,7 +245,9 @@ fn test_apps_install_help() {
         .success()
         .stdout(
             predicate::str::contains("Install an app from a .spl file")
-                .and(predicate::str::contains("<FILE_PATH>")),
+                .and(predicate::str::contains("[FILE_PATH]"))
+                .and(predicate::str::contains("--splunkbase <APP_ID>"))
+                .and(predicate::str::contains("--version <VERSION>")),
         );
 }
 
@@ -220,6 +280,125 @@ fn test_apps_install_nonexistent_file() {
         .stderr(predicate::str::contains("App package file not found"));
 }
 
+/// Test that `--version` is only accepted together with `--splunkbase`
+#[test]
+fn test_apps_install_version_requires_splunkbase() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["apps", "install", "./app.spl", "--version", "1.0.0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["apps", "install", "--version", "1.0.0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--splunkbase <APP_ID>"));
+}
+
+/// Test that a file path and `--splunkbase` cannot be combined
+#[test]
+fn test_apps_install_file_conflicts_with_splunkbase() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["apps", "install", "./app.spl", "--splunkbase", "2890"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+/// Test that a pinned Splunkbase release is downloaded, verified, and uploaded
+#[tokio::test]
+async fn test_apps_install_from_splunkbase() {
+    use sha2::{Digest, Sha256};
+    use wiremock::matchers::{body_string_contains, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let package = b"splunkbase package";
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/app/2890/release/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"name": "8.3.0", "filename": "windows_830.tgz", "sha256": "0000"},
+            {"name": "8.2.0", "filename": "windows_820.tgz", "sha256": hex::encode(Sha256::digest(package))}
+        ])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/app/2890/release/8.2.0/download/"))
+        .and(header("X-Auth-Token", "sb-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(package.as_slice()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/services/apps/appinstall"))
+        .and(body_string_contains("windows_820.tgz"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "Splunk_TA_windows",
+                "content": {"label": "Splunk Add-on for Microsoft Windows", "version": "8.2.0", "disabled": false}
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri())
+        .env("SPLUNKBASE_URL", mock_server.uri())
+        .env("SPLUNKBASE_TOKEN", "sb-token");
+
+    cmd.args([
+        "apps",
+        "install",
+        "--splunkbase",
+        "2890",
+        "--version",
+        "8.2.0",
+        "-o",
+        "json",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("Splunk_TA_windows"));
+}
+
+/// Test that a Splunkbase install without credentials fails before uploading
+#[tokio::test]
+async fn test_apps_install_from_splunkbase_requires_credentials() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/app/2890/release/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"name": "8.2.0", "sha256": "0000"}
+        ])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/services/apps/appinstall"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri())
+        .env("SPLUNKBASE_URL", mock_server.uri());
+
+    cmd.args(["apps", "install", "--splunkbase", "2890"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Splunkbase credentials required"));
+}
+
 /// Test that `splunk-cli apps remove --help` shows remove usage
 #[test]
 fn test_apps_remove_help() {
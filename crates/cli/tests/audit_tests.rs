@@ -0,0 +1,196 @@
+//! Integration tests for `splunk-cli audit archive`, `audit verify-archive`, and
+//! `audit user-activity`.
+//!
+//! Tests cover:
+//! - Help text verification for the `archive` subcommand
+//! - Archiving mocked audit events into a hash-chained segment and verifying it
+//! - Failing verification after an archived record is modified
+//! - Per-day user activity counts and `--since` validation
+//!
+//! Does NOT:
+//! - Test live Splunk server interactions (see `test-live` in Makefile).
+//! - Test chain edge cases (covered by unit tests in `commands/audit/archive.rs`).
+//!
+//! Invariants:
+//! - All tests use hermetic CLI commands via `splunk_cmd()` to prevent env leakage.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_audit_events(mock_server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "sid": "audit-sid-1"
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/audit-sid-1/results"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                {
+                    "_time": "2026-02-22T15:05:00.000+00:00",
+                    "user": "bob",
+                    "action": "search",
+                    "result": "success",
+                    "_raw": "bob search"
+                },
+                {
+                    "_time": "2026-02-22T15:00:00.000+00:00",
+                    "user": "admin",
+                    "action": "login",
+                    "result": "success",
+                    "_raw": "admin login"
+                }
+            ],
+            "preview": false,
+            "offset": 0,
+            "total": 2
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+async fn archive_into(dir: &std::path::Path) {
+    let mock_server = MockServer::start().await;
+    mount_audit_events(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["audit", "archive", "--dir"])
+        .arg(dir)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("audit-000001.ndjson"))
+        .stdout(predicate::str::contains("Records:    2"));
+}
+
+#[test]
+fn test_audit_archive_help() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["audit", "archive", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("hash-chained"))
+        .stdout(predicate::str::contains("--dir"));
+}
+
+#[tokio::test]
+async fn test_audit_archive_then_verify() {
+    let dir = tempfile::tempdir().unwrap();
+    archive_into(dir.path()).await;
+
+    let segment = std::fs::read_to_string(dir.path().join("audit-000001.ndjson")).unwrap();
+    let records: Vec<serde_json::Value> = segment
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(records[0]["event"]["user"], "admin");
+    assert_eq!(records[1]["prev_hash"], records[0]["hash"]);
+    assert!(dir.path().join("audit-000001.manifest.json").exists());
+
+    let mut cmd = splunk_cmd();
+    cmd.args(["audit", "verify-archive", "-o", "json"])
+        .arg(dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"valid\": true"))
+        .stdout(predicate::str::contains("\"records\": 2"));
+}
+
+#[tokio::test]
+async fn test_audit_verify_archive_fails_on_tampering() {
+    let dir = tempfile::tempdir().unwrap();
+    archive_into(dir.path()).await;
+
+    let path = dir.path().join("audit-000001.ndjson");
+    let mut permissions = std::fs::metadata(&path).unwrap().permissions();
+    #[allow(clippy::permissions_set_readonly_false)]
+    permissions.set_readonly(false);
+    std::fs::set_permissions(&path, permissions).unwrap();
+    let tampered = std::fs::read_to_string(&path)
+        .unwrap()
+        .replace("\"bob\"", "\"eve\"");
+    std::fs::write(&path, tampered).unwrap();
+
+    let mut cmd = splunk_cmd();
+    cmd.args(["audit", "verify-archive"])
+        .arg(dir.path())
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("INVALID"))
+        .stdout(predicate::str::contains("line 2: hash mismatch"))
+        .stderr(predicate::str::contains(
+            "Audit archive verification failed",
+        ));
+}
+
+#[tokio::test]
+async fn test_audit_user_activity_groups_per_day() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains("earliest_time=-7d"))
+        .and(body_string_contains("alice"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "sid": "activity-sid"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/activity-sid/results"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                { "_time": "2026-02-23T09:00:00.000+00:00", "user": "alice", "action": "search", "result": "success" },
+                { "_time": "2026-02-22T15:02:00.000+00:00", "user": "alice", "action": "edit_role", "result": "success" },
+                { "_time": "2026-02-22T15:00:00.000+00:00", "user": "alice", "action": "login", "result": "success" },
+                { "_time": "2026-02-22T14:59:00.000+00:00", "user": "alice", "action": "login", "result": "failure" }
+            ],
+            "preview": false,
+            "offset": 0,
+            "total": 4
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args([
+        "audit",
+        "user-activity",
+        "alice",
+        "--since",
+        "7d",
+        "-o",
+        "csv",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "day,logins,searches,objects_modified,failed_auth,other",
+    ))
+    .stdout(predicate::str::contains("2026-02-22,1,0,1,1,0"))
+    .stdout(predicate::str::contains("2026-02-23,0,1,0,0,0"))
+    .stderr(predicate::str::contains(
+        "1 failed auth attempt(s) over 2 day(s)",
+    ));
+}
+
+#[test]
+fn test_audit_user_activity_rejects_invalid_since() {
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", "http://127.0.0.1:1");
+    cmd.args(["audit", "user-activity", "alice", "--since", "a week"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("Invalid --since"));
+}
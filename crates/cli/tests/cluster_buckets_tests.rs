@@ -0,0 +1,152 @@
+//! Integration tests for `splunk-cli cluster` bucket fix-up, excess copy, and
+//! index replication compliance commands.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn test_cluster_remove_excess_buckets_help() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["cluster", "remove-excess-buckets", "--help"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("--index")
+                .and(predicate::str::contains("--all"))
+                .and(predicate::str::contains("--dry-run"))
+                .and(predicate::str::contains("--force")),
+        );
+}
+
+#[test]
+fn test_cluster_remove_excess_buckets_requires_selection() {
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", "https://localhost:9999");
+
+    cmd.args(["cluster", "remove-excess-buckets", "--force"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--index").and(predicate::str::contains("--all")));
+}
+
+#[test]
+fn test_cluster_fixups_rejects_invalid_level() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["cluster", "fixups", "--level", "bogus"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid fix-up level"));
+}
+
+#[tokio::test]
+async fn test_cluster_remove_excess_buckets_dry_run_skips_clean_indexes() {
+    let mock_server = MockServer::start().await;
+
+    let fixture_data = include_str!("../../client/fixtures/cluster/get_cluster_indexes.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/cluster/master/indexes"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(fixture_data))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["cluster", "remove-excess-buckets", "--all", "--dry-run"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "would remove 3 excess bucket copies",
+        ))
+        .stdout(predicate::str::contains("main"))
+        .stdout(predicate::str::contains("web").not());
+}
+
+#[tokio::test]
+async fn test_cluster_remove_excess_buckets_forced_for_index() {
+    let mock_server = MockServer::start().await;
+
+    let fixture_data = include_str!("../../client/fixtures/cluster/get_cluster_indexes.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/cluster/master/indexes"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(fixture_data))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/cluster/master/control/control/prune_index"))
+        .and(body_string_contains("index=main"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args([
+        "cluster",
+        "remove-excess-buckets",
+        "--index",
+        "main",
+        "--index",
+        "web",
+        "--force",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Excess bucket removal initiated for main",
+    ));
+}
+
+#[tokio::test]
+async fn test_cluster_index_compliance_non_compliant_json() {
+    let mock_server = MockServer::start().await;
+
+    let config = include_str!("../../client/fixtures/cluster/get_cluster_info.json");
+    let indexes = include_str!("../../client/fixtures/cluster/get_cluster_indexes.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/cluster/master/config"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(config))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/cluster/master/indexes"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(indexes))
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args([
+        "cluster",
+        "index-compliance",
+        "--non-compliant",
+        "-o",
+        "json",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("\"index\": \"main\""))
+    .stdout(predicate::str::contains("\"buckets_below_rf\": 2"))
+    .stdout(predicate::str::contains("\"compliant\": false"))
+    .stdout(predicate::str::contains("\"index\": \"web\"").not());
+}
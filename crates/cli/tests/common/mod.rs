@@ -56,7 +56,17 @@ pub fn splunk_cmd() -> Command {
         .env_remove("SPLUNK_USERNAME")
         .env_remove("SPLUNK_PASSWORD")
         .env_remove("SPLUNK_PROFILE")
-        .env_remove("SPLUNK_CONFIG_PATH");
+        .env_remove("SPLUNK_CONFIG_PATH")
+        .env_remove("SPLUNK_TEAM_CONFIG")
+        .env_remove("SPLUNK_APP")
+        .env_remove("SPLUNK_OWNER")
+        .env_remove("SPLUNK_CLOUD")
+        .env_remove("SPLUNK_ACS_STACK")
+        .env_remove("SPLUNK_ACS_URL")
+        .env_remove("SPLUNKBASE_URL")
+        .env_remove("SPLUNKBASE_TOKEN")
+        .env_remove("SPLUNKBASE_USERNAME")
+        .env_remove("SPLUNKBASE_PASSWORD");
 
     cmd
 }
@@ -3,7 +3,7 @@
 //! This module contains tests organized by subcommand and functionality.
 //!
 //! Responsibilities:
-//! - Test all config subcommands: list, set, delete, show, edit
+//! - Test all config subcommands: list, set, delete, show, edit, sync, team, templates
 //! - Test output formats and validation
 //! - Test keyring integration and config path handling
 //!
@@ -22,6 +22,8 @@ mod list_tests;
 mod secure_input_tests;
 mod set_tests;
 mod show_tests;
+mod sync_tests;
+mod templates_tests;
 
 use tempfile::TempDir;
 
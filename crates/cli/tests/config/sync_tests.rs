@@ -0,0 +1,117 @@
+//! Team bundle tests for `splunk-cli config sync` and `config team`.
+//!
+//! Tests syncing a shared bundle from a file or URL, layering its profiles
+//! beneath personal ones, and rejecting bundles that carry secrets.
+
+use crate::common::splunk_cmd;
+use crate::config::setup_temp_config;
+use predicates::prelude::*;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const BUNDLE: &str = r#"{
+    "name": "platform-standard",
+    "theme": "dark",
+    "search_templates": [
+        { "name": "Errors", "query": "index=main log_level=ERROR" }
+    ],
+    "profiles": {
+        "prod": { "base_url": "https://splunk.example.com:8089" }
+    }
+}"#;
+
+fn write_bundle(dir: &std::path::Path, content: &str) -> String {
+    let bundle_path = dir.join("shared-team.json");
+    std::fs::write(&bundle_path, content).unwrap();
+    bundle_path.to_string_lossy().to_string()
+}
+
+#[test]
+fn test_config_sync_from_file_layers_team_profiles() {
+    let (temp_dir, config_path) = setup_temp_config();
+    let source = write_bundle(temp_dir.path(), BUNDLE);
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args(["config", "sync", "--source", &source])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Synced team bundle 'platform-standard' from",
+        ))
+        .stdout(predicate::str::contains(
+            "1 profile(s), 1 search template(s), 0 keybinding override(s), theme Dark",
+        ));
+
+    assert!(temp_dir.path().join("team-bundle.json").exists());
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args(["-o", "json", "config", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"prod\""))
+        .stdout(predicate::str::contains("https://splunk.example.com:8089"));
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args(["config", "team"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Team bundle: platform-standard"))
+        .stdout(predicate::str::contains(
+            "Errors               index=main log_level=ERROR",
+        ));
+}
+
+#[test]
+fn test_config_sync_rejects_bundle_with_secrets() {
+    let (temp_dir, config_path) = setup_temp_config();
+    let source = write_bundle(
+        temp_dir.path(),
+        r#"{ "profiles": { "prod": { "username": "svc", "api_token": "abc123" } } }"#,
+    );
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args(["config", "sync", "--source", &source])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("must not carry secrets"));
+
+    assert!(!temp_dir.path().join("team-bundle.json").exists());
+}
+
+#[test]
+fn test_config_sync_requires_source() {
+    let (_temp_dir, config_path) = setup_temp_config();
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args(["config", "sync"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("SPLUNK_TEAM_CONFIG"));
+}
+
+#[tokio::test]
+async fn test_config_sync_from_url_env() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/splunk-tui/team.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(BUNDLE))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let (_temp_dir, config_path) = setup_temp_config();
+    let url = format!("{}/splunk-tui/team.json", mock_server.uri());
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .env("SPLUNK_TEAM_CONFIG", &url)
+        .args(["config", "sync"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("from {}", url)));
+}
@@ -0,0 +1,116 @@
+//! Search template sharing tests for `splunk-cli config templates`.
+//!
+//! Tests importing a template file into the personal library, exporting it
+//! again, rendering parameters, and rejecting files with schema errors.
+
+use crate::common::splunk_cmd;
+use crate::config::setup_temp_config;
+use predicates::prelude::*;
+
+const TEMPLATES: &str = r#"{
+    "version": 1,
+    "templates": [
+        {
+            "name": "Failed logins",
+            "query": "index=$index$ action=failure earliest=$since$ | where count >= $threshold$",
+            "parameters": [
+                { "name": "index", "default": "security" },
+                { "name": "since", "type": "time", "default": "-24h" },
+                { "name": "threshold", "type": "integer", "description": "Minimum failures" }
+            ]
+        }
+    ]
+}"#;
+
+#[test]
+fn test_config_templates_import_export_and_render() {
+    let (temp_dir, config_path) = setup_temp_config();
+    let source = temp_dir.path().join("shared.json");
+    std::fs::write(&source, TEMPLATES).unwrap();
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args(["config", "templates", "import"])
+        .arg(&source)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Imported 1 new and 0 replaced search template(s)",
+        ));
+    assert!(temp_dir.path().join("search-templates.json").exists());
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args(["config", "templates", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Failed logins (personal)"))
+        .stdout(predicate::str::contains(
+            "$threshold$ (integer, required) - Minimum failures",
+        ));
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args([
+            "config",
+            "templates",
+            "render",
+            "Failed logins",
+            "--param",
+            "threshold=10",
+        ])
+        .assert()
+        .success()
+        .stdout("index=security action=failure earliest=-24h | where count >= 10\n");
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args(["config", "templates", "render", "Failed logins"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Parameter 'threshold' is required",
+        ));
+
+    let exported = temp_dir.path().join("exported.json");
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args(["config", "templates", "export"])
+        .arg(&exported)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Exported 1 search template(s)"));
+    let content = std::fs::read_to_string(&exported).unwrap();
+    assert!(content.contains("\"type\": \"time\""));
+
+    // Re-importing without --replace leaves the existing template alone
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args(["config", "templates", "import"])
+        .arg(&exported)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Skipped 1 existing template(s)"));
+}
+
+#[test]
+fn test_config_templates_import_rejects_undeclared_placeholder() {
+    let (temp_dir, config_path) = setup_temp_config();
+    let source = temp_dir.path().join("bad.json");
+    std::fs::write(
+        &source,
+        r#"{ "version": 1, "templates": [{ "name": "Bad", "query": "index=$index$" }] }"#,
+    )
+    .unwrap();
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args(["config", "templates", "import"])
+        .arg(&source)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Query uses placeholder 'index' but no parameter 'index' is declared",
+        ));
+    assert!(!temp_dir.path().join("search-templates.json").exists());
+}
@@ -3,6 +3,9 @@
 //! Tests cover:
 //! - List subcommand with `--config-file`, `--count`, `--offset` flags
 //! - View subcommand for specific stanzas
+//! - Effective subcommand merging per-context layers
+//! - Bundle-audit subcommand flagging large replicated lookups
+//! - Dump subcommand writing .conf backups and diffing against a previous one
 //! - Output format variations (json, csv, xml)
 //! - `--output-file` flag
 //! - Error handling
@@ -315,6 +318,206 @@ async fn test_configs_view_mock_server() {
         .stdout(predicate::str::contains("access_combined"));
 }
 
+/// Test that `configs effective` reports the winning layer for each key.
+#[tokio::test]
+async fn test_configs_effective_mock_server() {
+    let mock_server = MockServer::start().await;
+
+    let fixture_data = include_str!("../../client/fixtures/configs/get_config_stanza_layers.json");
+
+    Mock::given(method("GET"))
+        .and(path("/servicesNS/-/-/configs/conf-props/syslog"))
+        .and(query_param("output_mode", "json"))
+        .and(query_param("count", "0"))
+        .and(header("Authorization", "Bearer test-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(fixture_data))
+        .mount(&mock_server)
+        .await;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let output_file = temp_dir.path().join("effective.json");
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args([
+        "configs",
+        "effective",
+        "props",
+        "syslog",
+        "--output",
+        "json",
+        "--output-file",
+        output_file.to_str().unwrap(),
+    ])
+    .assert()
+    .success();
+
+    let content = std::fs::read_to_string(&output_file).unwrap();
+    let report: serde_json::Value = serde_json::from_str(&content).unwrap();
+    let settings = report["settings"].as_array().unwrap();
+    assert_eq!(settings.len(), 2, "eai:* metadata must be dropped");
+
+    let tz = settings.iter().find(|s| s["key"] == "TZ").unwrap();
+    assert_eq!(tz["value"], "UTC");
+    assert_eq!(tz["source"], "app:search");
+    assert_eq!(tz["overridden"][0]["source"], "system");
+    assert_eq!(tz["overridden"][0]["value"], "GMT");
+
+    let linemerge = settings
+        .iter()
+        .find(|s| s["key"] == "SHOULD_LINEMERGE")
+        .unwrap();
+    assert_eq!(linemerge["scope"], "system");
+}
+
+/// Test that `configs dump` writes one .conf file per context and diffs against a previous dump.
+#[tokio::test]
+async fn test_configs_dump_and_diff_mock_server() {
+    let mock_server = MockServer::start().await;
+
+    let fixture_data = include_str!("../../client/fixtures/configs/get_config_stanza_layers.json");
+
+    Mock::given(method("GET"))
+        .and(path("/servicesNS/-/-/configs/conf-props"))
+        .and(query_param("count", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(fixture_data))
+        .mount(&mock_server)
+        .await;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let previous = temp_dir.path().join("previous");
+    std::fs::create_dir_all(previous.join("apps/search")).unwrap();
+    std::fs::write(
+        previous.join("apps/search/props.conf"),
+        "[syslog]\nTZ = EST\n\n[old]\nA = 1\n",
+    )
+    .unwrap();
+    let out = temp_dir.path().join("confbackup");
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args([
+        "configs",
+        "dump",
+        "--config-file",
+        "props",
+        "--out",
+        out.to_str().unwrap(),
+        "--diff",
+        previous.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Dumped 2 stanzas (3 settings) in 2 files",
+    ))
+    .stdout(predicate::str::contains("~ [syslog] TZ: EST -> UTC"))
+    .stdout(predicate::str::contains("- [old]"))
+    .stdout(predicate::str::contains("+ [syslog]"));
+
+    assert_eq!(
+        std::fs::read_to_string(out.join("system/props.conf")).unwrap(),
+        "[syslog]\nSHOULD_LINEMERGE = false\nTZ = GMT\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(out.join("apps/search/props.conf")).unwrap(),
+        "[syslog]\nTZ = UTC\n"
+    );
+
+    // A dump never overwrites an existing backup.
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args([
+        "configs",
+        "dump",
+        "--config-file",
+        "props",
+        "--out",
+        out.to_str().unwrap(),
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("is not empty"));
+}
+
+/// Test that `configs bundle-audit` flags large replicated lookups and honors the deny list.
+#[tokio::test]
+async fn test_configs_bundle_audit_mock_server() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/configs/conf-distsearch"))
+        .and(header("Authorization", "Bearer test-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [
+                {
+                    "name": "replicationWhitelist",
+                    "content": { "allLookups": "apps/*/lookups/*", "eai:appName": "system" }
+                },
+                {
+                    "name": "replicationDenylist",
+                    "content": { "geo": "apps/search/lookups/geo_*.csv" }
+                },
+                {
+                    "name": "replicationSettings",
+                    "content": { "maxBundleSize": "2048" }
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/data/lookup-table-files"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [
+                {
+                    "name": "threat_intel.csv",
+                    "content": {
+                        "name": "threat_intel.csv", "filename": "threat_intel.csv",
+                        "owner": "nobody", "app": "ES", "sharing": "global",
+                        "size": "62914560"
+                    }
+                },
+                {
+                    "name": "geo_cities.csv",
+                    "content": {
+                        "name": "geo_cities.csv", "filename": "geo_cities.csv",
+                        "owner": "nobody", "app": "search", "sharing": "app",
+                        "size": "104857600"
+                    }
+                },
+                {
+                    "name": "small.csv",
+                    "content": {
+                        "name": "small.csv", "filename": "small.csv",
+                        "owner": "admin", "app": "search", "sharing": "app",
+                        "size": "1024"
+                    }
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["configs", "bundle-audit", "--min-size-mb", "10"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("denied         apps/search/lookups/geo_cities.csv")
+                .and(predicate::str::contains(
+                    "!    60.0 MB  replicated     apps/ES/lookups/threat_intel.csv",
+                ))
+                .and(predicate::str::contains(
+                    "ES_threat_intel_csv = apps/ES/lookups/threat_intel\\.csv",
+                ))
+                .and(predicate::str::contains("small.csv").not()),
+        );
+}
+
 /// Test that pagination parameters are passed correctly for config stanzas.
 #[tokio::test]
 async fn test_configs_list_pagination_params() {
@@ -0,0 +1,120 @@
+//! Integration tests for `splunk-cli dashboards` command.
+//!
+//! Tests cover:
+//! - Help text verification for the `get` subcommand
+//! - Printing a re-indented Simple XML source against a mock server
+//! - Saving a Dashboard Studio JSON definition with `--definition --out`
+//! - Rejecting `--definition` for Simple XML dashboards
+//!
+//! Does NOT:
+//! - Test live Splunk server interactions (see `test-live` in Makefile).
+//!
+//! Invariants:
+//! - All tests use hermetic CLI commands via `splunk_cmd()` to prevent env leakage.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_dashboard(mock_server: &MockServer, name: &str, source: &str) {
+    Mock::given(method("GET"))
+        .and(path(format!("/services/data/ui/views/{}", name)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": name,
+                "content": {
+                    "label": "Ops",
+                    "isDashboard": true,
+                    "isVisible": true,
+                    "eai:data": source
+                }
+            }]
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[test]
+fn test_dashboards_get_help() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["dashboards", "get", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--out"))
+        .stdout(predicate::str::contains("--definition"))
+        .stdout(predicate::str::contains("--raw"));
+}
+
+#[tokio::test]
+async fn test_dashboards_get_prints_indented_simple_xml() {
+    let mock_server = MockServer::start().await;
+    mount_dashboard(
+        &mock_server,
+        "ops",
+        "<dashboard><label>Ops</label><row><panel><title>Errors</title></panel></row></dashboard>",
+    )
+    .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["dashboards", "get", "ops"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "<dashboard>\n  <label>Ops</label>\n  <row>\n    <panel>\n      <title>Errors</title>",
+        ));
+}
+
+#[tokio::test]
+async fn test_dashboards_get_saves_studio_definition() {
+    let mock_server = MockServer::start().await;
+    mount_dashboard(
+        &mock_server,
+        "studio_ops",
+        r#"<dashboard version="2"><label>Ops</label><definition><![CDATA[{"title":"Ops","dataSources":{}}]]></definition></dashboard>"#,
+    )
+    .await;
+
+    let dir = TempDir::new().unwrap();
+    let out = dir.path().join("studio_ops.json");
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["dashboards", "get", "studio_ops", "--definition", "--out"])
+        .arg(&out)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Dashboard 'studio_ops' (Dashboard Studio) saved to",
+        ));
+
+    let written = std::fs::read_to_string(&out).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&written).unwrap();
+    assert_eq!(value["title"], "Ops");
+    assert!(written.contains("\n  \"title\": \"Ops\""));
+}
+
+#[tokio::test]
+async fn test_dashboards_get_definition_rejects_simple_xml() {
+    let mock_server = MockServer::start().await;
+    mount_dashboard(
+        &mock_server,
+        "ops",
+        "<dashboard><label>Ops</label></dashboard>",
+    )
+    .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["dashboards", "get", "ops", "--definition"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "is not a Dashboard Studio dashboard",
+        ));
+}
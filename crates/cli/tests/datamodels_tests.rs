@@ -0,0 +1,135 @@
+//! Integration tests for `splunk-cli datamodels tstats` command.
+//!
+//! Tests cover:
+//! - Help text verification for the `tstats` subcommand
+//! - Generating SPL for a child dataset with `--dry-run`
+//! - Listing datasets and fields with `--list-fields`
+//! - Rejecting fields the dataset does not define
+//!
+//! Does NOT:
+//! - Test live Splunk server interactions (see `test-live` in Makefile).
+//!
+//! Invariants:
+//! - All tests use hermetic CLI commands via `splunk_cmd()` to prevent env leakage.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_network_traffic(mock_server: &MockServer) {
+    let definition = serde_json::json!({
+        "modelName": "Network_Traffic",
+        "objects": [
+            {
+                "objectName": "All_Traffic",
+                "parentName": "BaseEvent",
+                "fields": [
+                    { "fieldName": "_time", "owner": "BaseEvent" },
+                    { "fieldName": "action", "owner": "All_Traffic" },
+                    { "fieldName": "src", "owner": "All_Traffic" }
+                ]
+            },
+            {
+                "objectName": "Blocked_Traffic",
+                "parentName": "All_Traffic",
+                "fields": []
+            }
+        ]
+    });
+
+    Mock::given(method("GET"))
+        .and(path("/services/datamodel/Network_Traffic"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "Network_Traffic",
+                "content": {
+                    "displayName": "Network Traffic",
+                    "accelerated": true,
+                    "eai:data": definition.to_string()
+                }
+            }]
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[test]
+fn test_datamodels_tstats_help() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["datamodels", "tstats", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--stat"))
+        .stdout(predicate::str::contains("--by"))
+        .stdout(predicate::str::contains("--dry-run"))
+        .stdout(predicate::str::contains("--list-fields"));
+}
+
+#[tokio::test]
+async fn test_datamodels_tstats_dry_run_prints_spl() {
+    let mock_server = MockServer::start().await;
+    mount_network_traffic(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args([
+        "datamodels",
+        "tstats",
+        "Network_Traffic",
+        "--object",
+        "Blocked_Traffic",
+        "--stat",
+        "count",
+        "--stat",
+        "dc:src",
+        "--by",
+        "action",
+        "--span",
+        "1h",
+        "--summaries-only",
+        "--dry-run",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "| tstats summariesonly=true count dc(All_Traffic.src) from datamodel=Network_Traffic.All_Traffic where nodename=All_Traffic.Blocked_Traffic by All_Traffic.action _time span=1h",
+    ));
+}
+
+#[tokio::test]
+async fn test_datamodels_tstats_list_fields() {
+    let mock_server = MockServer::start().await;
+    mount_network_traffic(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["datamodels", "tstats", "Network_Traffic", "--list-fields"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("All_Traffic.Blocked_Traffic"))
+        .stdout(predicate::str::contains("  All_Traffic.src"));
+}
+
+#[tokio::test]
+async fn test_datamodels_tstats_rejects_unknown_field() {
+    let mock_server = MockServer::start().await;
+    mount_network_traffic(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args([
+        "datamodels",
+        "tstats",
+        "Network_Traffic",
+        "--by",
+        "dest_port",
+        "--dry-run",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("unknown field 'dest_port'"));
+}
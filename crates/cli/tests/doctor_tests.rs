@@ -6,6 +6,8 @@ use common::splunk_cmd;
 use predicates::prelude::*;
 use std::io::Read;
 use tempfile::TempDir;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 /// Test that `splunk-cli doctor --help` shows the command and examples.
 #[test]
@@ -321,3 +323,39 @@ fn test_doctor_bundle_logs_dir_error_has_context() {
             "Failed to read TUI log directory at",
         ));
 }
+
+/// Test that `--notify-webhook` posts a failing result once and dedups the repeat run.
+#[tokio::test]
+async fn test_doctor_notify_webhook_posts_status_changes_only() {
+    let webhook = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&webhook)
+        .await;
+    let home = TempDir::new().unwrap();
+    let url = format!("{}/hook", webhook.uri());
+
+    for _ in 0..2 {
+        let mut cmd = splunk_cmd();
+        cmd.env("SPLUNK_BASE_URL", "https://localhost:8089")
+            .env("HOME", home.path())
+            .env("XDG_CONFIG_HOME", home.path().join("config"))
+            .args(["doctor", "--notify-webhook", &url])
+            .assert()
+            .failure();
+    }
+
+    let requests = webhook.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(body["source"], "doctor");
+    assert_eq!(body["status"], "fail");
+    assert_eq!(body["healthy"], false);
+    assert!(
+        body["text"]
+            .as_str()
+            .unwrap()
+            .starts_with("splunk-cli doctor: https://localhost:8089 is fail")
+    );
+}
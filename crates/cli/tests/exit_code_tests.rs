@@ -1,11 +1,13 @@
 //! Integration tests for structured exit codes.
 //!
 //! These tests verify that splunk-cli returns the correct exit codes
-//! for different error scenarios, enabling reliable shell scripting.
+//! for different error scenarios, enabling reliable shell scripting, and that
+//! `--error-format json` reports errors as a JSON object on stderr.
 
 mod common;
 
 use common::splunk_cmd;
+use predicates::prelude::*;
 use wiremock::matchers::{header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -34,9 +36,9 @@ async fn test_success_returns_exit_code_0() {
     cmd.arg("health").assert().code(0);
 }
 
-/// Test that semantic permission failures return exit code 6.
+/// Test that authentication failures (401) return exit code 3.
 #[tokio::test]
-async fn test_unauthorized_returns_exit_code_6() {
+async fn test_unauthorized_returns_exit_code_3() {
     let server = MockServer::start().await;
 
     Mock::given(method("GET"))
@@ -50,16 +52,16 @@ async fn test_unauthorized_returns_exit_code_6() {
     let mut cmd = splunk_cmd();
     cmd.env("SPLUNK_BASE_URL", server.uri());
     cmd.env("SPLUNK_API_TOKEN", "invalid-token");
-    cmd.arg("health").assert().code(6);
+    cmd.arg("health").assert().code(3);
 }
 
-/// Test that connection refused returns exit code 3.
+/// Test that connection refused returns the general exit code 1.
 #[test]
-fn test_connection_refused_returns_exit_code_3() {
+fn test_connection_refused_returns_exit_code_1() {
     let mut cmd = splunk_cmd();
     // Use a port that's unlikely to be open
     cmd.env("SPLUNK_BASE_URL", "https://localhost:1");
-    cmd.arg("health").assert().code(3);
+    cmd.arg("health").assert().code(1);
 }
 
 /// Test that resource not found returns exit code 4.
@@ -84,9 +86,9 @@ async fn test_not_found_returns_exit_code_4() {
         .code(4);
 }
 
-/// Test that permission denied (403) returns exit code 6.
+/// Test that permission denied (403) returns the auth exit code 3.
 #[tokio::test]
-async fn test_permission_denied_returns_exit_code_6() {
+async fn test_permission_denied_returns_exit_code_3() {
     let server = MockServer::start().await;
 
     Mock::given(method("GET"))
@@ -101,12 +103,12 @@ async fn test_permission_denied_returns_exit_code_6() {
     let mut cmd = splunk_cmd();
     cmd.env("SPLUNK_BASE_URL", server.uri());
     cmd.env("SPLUNK_API_TOKEN", "test-token");
-    cmd.args(["users", "list"]).assert().code(6);
+    cmd.args(["users", "list"]).assert().code(3);
 }
 
-/// Test that rate limiting (429) returns exit code 7.
+/// Test that rate limiting (429) returns the general exit code 1.
 #[tokio::test]
-async fn test_rate_limited_returns_exit_code_7() {
+async fn test_rate_limited_returns_exit_code_1() {
     let server = MockServer::start().await;
 
     // Use a very short retry-after to avoid test timeout
@@ -120,12 +122,12 @@ async fn test_rate_limited_returns_exit_code_7() {
     let mut cmd = splunk_cmd();
     cmd.env("SPLUNK_BASE_URL", server.uri());
     cmd.env("SPLUNK_API_TOKEN", "test-token");
-    cmd.arg("health").assert().code(7);
+    cmd.arg("health").assert().code(1);
 }
 
-/// Test that service unavailable (503) returns exit code 8.
+/// Test that service unavailable (503) returns the general exit code 1.
 #[tokio::test]
-async fn test_service_unavailable_returns_exit_code_8() {
+async fn test_service_unavailable_returns_exit_code_1() {
     let server = MockServer::start().await;
 
     Mock::given(method("GET"))
@@ -137,12 +139,12 @@ async fn test_service_unavailable_returns_exit_code_8() {
     let mut cmd = splunk_cmd();
     cmd.env("SPLUNK_BASE_URL", server.uri());
     cmd.env("SPLUNK_API_TOKEN", "test-token");
-    cmd.arg("health").assert().code(8);
+    cmd.arg("health").assert().code(1);
 }
 
-/// Test that bad gateway (502) returns exit code 8 (service unavailable category).
+/// Test that bad gateway (502) returns the general exit code 1.
 #[tokio::test]
-async fn test_bad_gateway_returns_exit_code_8() {
+async fn test_bad_gateway_returns_exit_code_1() {
     let server = MockServer::start().await;
 
     Mock::given(method("GET"))
@@ -154,12 +156,12 @@ async fn test_bad_gateway_returns_exit_code_8() {
     let mut cmd = splunk_cmd();
     cmd.env("SPLUNK_BASE_URL", server.uri());
     cmd.env("SPLUNK_API_TOKEN", "test-token");
-    cmd.arg("health").assert().code(8);
+    cmd.arg("health").assert().code(1);
 }
 
-/// Test that gateway timeout (504) returns exit code 8 (service unavailable category).
+/// Test that gateway timeout (504) returns the timeout exit code 5.
 #[tokio::test]
-async fn test_gateway_timeout_returns_exit_code_8() {
+async fn test_gateway_timeout_returns_exit_code_5() {
     let server = MockServer::start().await;
 
     Mock::given(method("GET"))
@@ -171,7 +173,7 @@ async fn test_gateway_timeout_returns_exit_code_8() {
     let mut cmd = splunk_cmd();
     cmd.env("SPLUNK_BASE_URL", server.uri());
     cmd.env("SPLUNK_API_TOKEN", "test-token");
-    cmd.arg("health").assert().code(8);
+    cmd.arg("health").assert().code(5);
 }
 
 /// Test that general errors return exit code 1.
@@ -192,3 +194,101 @@ async fn test_general_error_returns_exit_code_1() {
     cmd.env("SPLUNK_API_TOKEN", "test-token");
     cmd.arg("health").assert().code(1);
 }
+
+/// Test that a missing capability returns the auth exit code 3 with a remediation hint.
+#[tokio::test]
+async fn test_missing_capability_returns_exit_code_3_with_hint() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/authentication/users"))
+        .respond_with(ResponseTemplate::new(403).set_body_string(
+            r#"<response><messages><msg type="ERROR">You (user=bob) do not have permission to perform this operation (requires capability: edit_user).</msg></messages></response>"#,
+        ))
+        .mount(&server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", server.uri());
+    cmd.env("SPLUNK_API_TOKEN", "test-token");
+    cmd.args(["users", "list"])
+        .assert()
+        .code(3)
+        .stderr(predicates::str::contains(
+            "User lacks 'edit_user' capability",
+        ))
+        .stderr(predicates::str::contains(
+            "hint: Ask a Splunk admin to grant 'edit_user' to one of your roles",
+        ));
+}
+
+/// Test that argument and configuration errors return the usage exit code 2.
+#[test]
+fn test_usage_errors_return_exit_code_2() {
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", "https://localhost:8089");
+    cmd.args(["health", "--no-such-flag"]).assert().code(2);
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_CONFIG_PATH", temp_dir.path().join("config.json"));
+    cmd.args(["--profile", "missing", "indexes", "list"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("Profile 'missing' not found"));
+}
+
+/// Test that `--error-format json` prints one JSON error object on stderr.
+#[tokio::test]
+async fn test_error_format_json_prints_envelope() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/saved/searches/missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "messages": [{ "type": "ERROR", "text": "Could not find object id=missing" }]
+        })))
+        .mount(&server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", server.uri());
+    cmd.env("SPLUNK_API_TOKEN", "test-token");
+    let output = cmd
+        .args([
+            "saved-searches",
+            "info",
+            "missing",
+            "--error-format",
+            "json",
+        ])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(4));
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let envelope: serde_json::Value = serde_json::from_str(stderr.trim()).unwrap();
+    assert_eq!(envelope["error"]["kind"], "not_found");
+    assert_eq!(envelope["error"]["exit_code"], 4);
+    assert_eq!(envelope["error"]["http_status"], 404);
+    assert!(envelope["error"]["message"].is_string());
+}
+
+/// Test that argument errors use the JSON envelope when it is requested.
+#[test]
+fn test_error_format_json_applies_to_argument_errors() {
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_ERROR_FORMAT", "json");
+    let output = cmd.args(["health", "--no-such-flag"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let envelope: serde_json::Value = serde_json::from_str(stderr.trim()).unwrap();
+    assert_eq!(envelope["error"]["kind"], "usage");
+    assert!(
+        envelope["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("--no-such-flag")
+    );
+}
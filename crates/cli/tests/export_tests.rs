@@ -0,0 +1,105 @@
+//! Integration tests for `splunk-cli export`.
+//!
+//! Responsibilities:
+//! - Verify live state is written as a spec that round-trips through `apply`.
+//! - Verify `--types` validation and stdout output.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mock_live_state(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/services/data/indexes"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [
+                {
+                    "name": "web",
+                    "content": {
+                        "maxTotalDataSizeMB": "1000",
+                        "currentDBSizeMB": 1,
+                        "totalEventCount": 10
+                    }
+                },
+                {
+                    "name": "_internal",
+                    "content": { "currentDBSizeMB": 1, "totalEventCount": 10 }
+                }
+            ]
+        })))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/authorization/roles"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "web_reader",
+                "content": { "searchIndexes": ["web"], "importedRoles": ["user"] }
+            }]
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[test]
+fn test_export_rejects_unknown_type() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["export", "--types", "indexes,dashboards"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Invalid resource type: dashboards",
+        ));
+}
+
+#[tokio::test]
+async fn test_export_writes_spec_that_applies_cleanly() {
+    let mock_server = MockServer::start().await;
+    mock_live_state(&mock_server).await;
+
+    let dir = TempDir::new().unwrap();
+    let spec = dir.path().join("spec.yaml");
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args([
+        "export",
+        "--types",
+        "indexes,roles",
+        "--out",
+        spec.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("Exported 1 indexes, 1 roles to"));
+
+    let yaml = std::fs::read_to_string(&spec).unwrap();
+    assert!(yaml.contains("name: web\n"));
+    assert!(yaml.contains("max_data_size_mb: 1000"));
+    assert!(!yaml.contains("_internal"));
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["apply", "-f", spec.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No changes."));
+}
+
+#[tokio::test]
+async fn test_export_prints_spec_without_out() {
+    let mock_server = MockServer::start().await;
+    mock_live_state(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["export", "--types", "roles"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("roles:\n- name: web_reader\n"));
+}
@@ -0,0 +1,133 @@
+//! Integration tests for `splunk-cli extract`.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_extraction_search(mock_server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains("rex+field%3D_raw"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": "extract-sid" } }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/extract-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": "extract-sid", "isDone": true, "doneProgress": 1.0 } }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/extract-sid/results"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                { "sample_line": "1", "user": "alice", "status": "200" },
+                { "sample_line": "2" }
+            ]
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+fn write_samples() -> tempfile::NamedTempFile {
+    let file = tempfile::NamedTempFile::new().unwrap();
+    std::fs::write(file.path(), "user=alice status=200\n\nnot a match\n").unwrap();
+    file
+}
+
+#[test]
+fn test_extract_test_help() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["extract", "test", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--sample"))
+        .stdout(predicate::str::contains("--props"));
+}
+
+#[test]
+fn test_extract_test_requires_regex_or_props() {
+    let samples = write_samples();
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["extract", "test", "--sample"])
+        .arg(samples.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--regex"));
+}
+
+#[tokio::test]
+async fn test_extract_test_csv_lists_fields_side_by_side() {
+    let mock_server = MockServer::start().await;
+    mount_extraction_search(&mock_server).await;
+    let samples = write_samples();
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["extract", "test", "-o", "csv", "--sample"])
+        .arg(samples.path())
+        .args(["--regex", r"user=(?<user>\w+) status=(?<status>\d+)"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("line,user,status,raw"))
+        .stdout(predicate::str::contains(
+            "1,alice,200,user=alice status=200",
+        ))
+        .stdout(predicate::str::contains("2,,,not a match"));
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let body = String::from_utf8_lossy(&requests[0].body).to_string();
+    assert!(body.contains("makeresults"));
+    assert!(body.contains("mvexpand"));
+}
+
+#[tokio::test]
+async fn test_extract_test_reads_props_extractions() {
+    let mock_server = MockServer::start().await;
+    mount_extraction_search(&mock_server).await;
+    let samples = write_samples();
+    let props = tempfile::NamedTempFile::new().unwrap();
+    std::fs::write(
+        props.path(),
+        "[app:log]\nEXTRACT-user = user=(?<user>\\w+)\nEXTRACT-status = status=(?<status>\\d+)\n",
+    )
+    .unwrap();
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["extract", "test", "--sample"])
+        .arg(samples.path())
+        .arg("--props")
+        .arg(props.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("USER"))
+        .stdout(predicate::str::contains("STATUS"))
+        .stdout(predicate::str::contains("1 of 2 event(s) matched."));
+}
+
+#[test]
+fn test_extract_test_rejects_regex_without_named_groups() {
+    let samples = write_samples();
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", "http://127.0.0.1:1");
+
+    cmd.args(["extract", "test", "--sample"])
+        .arg(samples.path())
+        .args(["--regex", r"user=\w+"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("named"));
+}
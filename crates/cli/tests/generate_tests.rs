@@ -0,0 +1,144 @@
+//! Integration tests for `splunk-cli generate` command.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Test that `splunk-cli generate --help` shows the command and templates.
+#[test]
+fn test_generate_help() {
+    let mut cmd = splunk_cmd();
+    cmd.args(["generate", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Generate synthetic events"))
+        .stdout(predicate::str::contains("apache"))
+        .stdout(predicate::str::contains("syslog"));
+}
+
+/// Test that `generate` requires HEC connection details unless `--dry-run` is used.
+#[test]
+fn test_generate_requires_url_and_token() {
+    let mut cmd = splunk_cmd();
+    cmd.env_remove("SPLUNK_HEC_URL")
+        .env_remove("SPLUNK_HEC_TOKEN")
+        .args(["generate", "--duration", "1s"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--hec-url"));
+}
+
+/// Test that an invalid rate is rejected at parse time.
+#[test]
+fn test_generate_rejects_invalid_rate() {
+    let mut cmd = splunk_cmd();
+    cmd.args(["generate", "--dry-run", "--rate", "10/d"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown rate unit"));
+}
+
+/// Test that `--dry-run` writes HEC events with metadata and never sends them.
+#[test]
+fn test_generate_dry_run_prints_events() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("events.json");
+
+    let mut cmd = splunk_cmd();
+    cmd.env_remove("SPLUNK_HEC_URL")
+        .env_remove("SPLUNK_HEC_TOKEN")
+        .args([
+            "generate",
+            "--dry-run",
+            "--template",
+            "syslog",
+            "--index",
+            "lab",
+            "--rate",
+            "5/s",
+            "--duration",
+            "2s",
+            "--seed",
+            "1",
+            "--output-file",
+        ])
+        .arg(&output_path)
+        .assert()
+        .success();
+
+    let content = std::fs::read_to_string(&output_path).unwrap();
+    let events: Vec<serde_json::Value> = serde_json::from_str(&content).unwrap();
+    assert_eq!(events.len(), 10);
+    for event in &events {
+        assert_eq!(event["index"], "lab");
+        assert_eq!(event["sourcetype"], "syslog");
+        assert_eq!(event["source"], "splunk-cli:generate");
+        assert!(event["event"].is_string());
+        assert!(event["time"].is_number());
+    }
+}
+
+/// Test that events are paced and sent to HEC, with a summary on stdout.
+#[tokio::test]
+async fn test_generate_sends_events_to_hec() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("summary.json");
+
+    Mock::given(method("POST"))
+        .and(path("/services/collector/event"))
+        .and(header("Authorization", "Splunk hec-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "text": "Success",
+            "code": 0
+        })))
+        .expect(1..)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.args([
+        "generate",
+        "--hec-url",
+        &mock_server.uri(),
+        "--hec-token",
+        "hec-token",
+        "--index",
+        "lab",
+        "--sourcetype",
+        "demo:json",
+        "--rate",
+        "20/s",
+        "--duration",
+        "1s",
+        "--quiet",
+        "--output",
+        "json",
+        "--output-file",
+    ])
+    .arg(&output_path)
+    .assert()
+    .success();
+
+    let content = std::fs::read_to_string(&output_path).unwrap();
+    let summary: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert_eq!(summary["template"], "json");
+    assert_eq!(summary["sourcetype"], "demo:json");
+    assert_eq!(summary["requested_events"], 20);
+    assert_eq!(summary["events_sent"], 20);
+
+    let received = mock_server.received_requests().await.unwrap();
+    let sent: usize = received
+        .iter()
+        .map(|request| {
+            let body: Vec<serde_json::Value> = serde_json::from_slice(&request.body).unwrap();
+            assert!(body.iter().all(|event| event["index"] == "lab"));
+            body.len()
+        })
+        .sum();
+    assert_eq!(sent, 20);
+}
@@ -4,6 +4,8 @@ mod common;
 
 use common::{connection_error_predicate, splunk_cmd};
 use predicates::prelude::*;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 /// Test that `splunk-cli health --help` shows the command.
 #[test]
@@ -17,6 +19,58 @@ fn test_health_help() {
         ));
 }
 
+/// Test that `splunk-cli health --help` documents watch mode and webhook notifications.
+#[test]
+fn test_health_help_documents_watch_and_webhook() {
+    let mut cmd = splunk_cmd();
+    cmd.args(["health", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--watch <INTERVAL>"))
+        .stdout(predicate::str::contains("--notify-webhook <URL>"));
+}
+
+/// Test that `--notify-webhook` is rejected for health subcommands.
+#[test]
+fn test_health_notify_webhook_rejects_subcommands() {
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", "https://localhost:8089");
+    cmd.args([
+        "health",
+        "--notify-webhook",
+        "https://hooks.example.com/x",
+        "queues",
+    ])
+    .assert()
+    .code(2)
+    .stderr(predicate::str::contains("apply to the full health check"));
+}
+
+/// Test that an unreachable server is reported to the webhook as `unreachable`.
+#[tokio::test]
+async fn test_health_notify_webhook_reports_unreachable_server() {
+    let webhook = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&webhook)
+        .await;
+    let home = tempfile::TempDir::new().unwrap();
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", "https://localhost:8089")
+        .env("HOME", home.path())
+        .env("XDG_CONFIG_HOME", home.path().join("config"))
+        .args(["health", "--notify-webhook", &webhook.uri()])
+        .assert()
+        .failure();
+
+    let requests = webhook.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(body["source"], "health");
+    assert_eq!(body["status"], "unreachable");
+}
+
 /// Test that `splunk-cli health` executes and tries to connect.
 #[test]
 fn test_health_execution() {
@@ -27,3 +81,539 @@ fn test_health_execution() {
         .failure()
         .stderr(connection_error_predicate());
 }
+
+/// Test that `splunk-cli health scheduler --help` documents the window flag.
+#[test]
+fn test_health_scheduler_help() {
+    let mut cmd = splunk_cmd();
+    cmd.args(["health", "scheduler", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--since"));
+}
+
+/// Mount a scheduler health search over the last 7 days returning two saved searches.
+async fn mount_scheduler_mocks(mock_server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains("sourcetype%3Dscheduler"))
+        .and(body_string_contains("earliest_time=-7d"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": "scheduler-sid" } }]
+        })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/scheduler-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": {
+                "sid": "scheduler-sid",
+                "isDone": true,
+                "isFinalized": true,
+                "doneProgress": 1.0,
+                "runDuration": 0.0,
+                "scanCount": 0,
+                "eventCount": 0,
+                "resultCount": 2,
+                "diskUsage": 0
+            } }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/scheduler-sid/results"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                {
+                    "app": "search",
+                    "savedsearch_name": "Errors Last Hour",
+                    "user": "admin",
+                    "runs": "168",
+                    "succeeded": "160",
+                    "skipped": "8",
+                    "failed": "0",
+                    "last_reason": "The maximum number of concurrent historical scheduled searches on this instance has been reached"
+                },
+                {
+                    "app": "search",
+                    "savedsearch_name": "Nightly Rollup",
+                    "user": "admin",
+                    "runs": "7",
+                    "succeeded": "7",
+                    "skipped": "0",
+                    "failed": "0"
+                }
+            ],
+            "preview": false,
+            "total": 2
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+/// Test that `health scheduler` searches the requested window and reports problem searches.
+#[tokio::test]
+async fn test_health_scheduler_reports_skipped_searches() {
+    let mock_server = MockServer::start().await;
+    mount_scheduler_mocks(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["health", "scheduler", "--since", "7d", "--output", "json"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("\"total_runs\": 175")
+                .and(predicate::str::contains("\"skipped\": 8"))
+                .and(predicate::str::contains("Errors Last Hour"))
+                .and(predicate::str::contains("Nightly Rollup").not()),
+        );
+}
+
+/// Test that `--get` prints the bare value at a path and exits 4 when it is missing.
+#[tokio::test]
+async fn test_health_scheduler_get_extracts_single_value() {
+    let mock_server = MockServer::start().await;
+    mount_scheduler_mocks(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args([
+        "health",
+        "scheduler",
+        "--since",
+        "7d",
+        "--get",
+        ".total_runs",
+    ])
+    .assert()
+    .success()
+    .stdout("175\n");
+
+    let mock_server = MockServer::start().await;
+    mount_scheduler_mocks(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args([
+        "health",
+        "scheduler",
+        "--since",
+        "7d",
+        "--get",
+        ".no_such_field",
+    ])
+    .assert()
+    .code(4)
+    .stdout("")
+    .stderr(predicate::str::contains("No value at '.no_such_field'"));
+}
+
+/// Test that an invalid `--get` path is rejected before connecting.
+#[test]
+fn test_get_rejects_invalid_path() {
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", "https://localhost:8089");
+    cmd.args(["health", "--get", ".a..b"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid path '.a..b': empty key"));
+}
+
+/// Test that `splunk-cli health queues --help` documents the window flag.
+#[test]
+fn test_health_queues_help() {
+    let mut cmd = splunk_cmd();
+    cmd.args(["health", "queues", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--since"));
+}
+
+/// Test that `health queues` flags a queue sustained above 80% full.
+#[tokio::test]
+async fn test_health_queues_flags_sustained_fill() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains("group%3Dqueue"))
+        .and(body_string_contains("earliest_time=-15m"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": "queues-sid" } }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/queues-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": {
+                "sid": "queues-sid",
+                "isDone": true,
+                "isFinalized": true,
+                "doneProgress": 1.0,
+                "runDuration": 0.0,
+                "scanCount": 0,
+                "eventCount": 0,
+                "resultCount": 2,
+                "diskUsage": 0
+            } }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/queues-sid/results"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                {
+                    "host": "idx01",
+                    "pipeline": "0",
+                    "queue": "typingqueue",
+                    "avg_fill_pct": "97.5",
+                    "max_fill_pct": "100",
+                    "blocked_count": "6",
+                    "samples": "30"
+                },
+                {
+                    "host": "idx01",
+                    "pipeline": "0",
+                    "queue": "parsingqueue",
+                    "avg_fill_pct": "20",
+                    "max_fill_pct": "35",
+                    "blocked_count": "0",
+                    "samples": "30"
+                }
+            ],
+            "preview": false,
+            "total": 2
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["health", "queues"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Status: Unhealthy")
+                .and(predicate::str::contains(
+                    "idx01 pipeline 0 typingqueue: avg 97.5%, max 100.0%, blocked 6 of 30 samples [WARNING: sustained fill]",
+                ))
+                .and(predicate::str::contains(
+                    "idx01 pipeline 0 parsingqueue: avg 20.0%, max 35.0%, blocked 0 of 30 samples\n",
+                )),
+        );
+}
+
+/// Test that `splunk-cli health security --help` documents the threshold flag.
+#[test]
+fn test_health_security_help() {
+    let mut cmd = splunk_cmd();
+    cmd.args(["health", "security", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--threshold").and(predicate::str::contains("--since")));
+}
+
+/// Test that `health security -o ndjson` emits one alert line per flagged source IP.
+#[tokio::test]
+async fn test_health_security_ndjson_alerts() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains("index%3D_audit"))
+        .and(body_string_contains("earliest_time=-1h"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": "security-sid" } }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/security-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": {
+                "sid": "security-sid",
+                "isDone": true,
+                "isFinalized": true,
+                "doneProgress": 1.0,
+                "runDuration": 0.0,
+                "scanCount": 0,
+                "eventCount": 0,
+                "resultCount": 2,
+                "diskUsage": 0
+            } }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/security-sid/results"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                {
+                    "src_ip": "203.0.113.7",
+                    "failures": "31",
+                    "distinct_users": "4",
+                    "first_seen": "2025-01-20T09:00:01+0000",
+                    "last_seen": "2025-01-20T09:58:40+0000"
+                },
+                {
+                    "src_ip": "10.1.4.22",
+                    "failures": "2",
+                    "distinct_users": "1",
+                    "first_seen": "2025-01-20T09:12:44+0000",
+                    "last_seen": "2025-01-20T09:13:02+0000"
+                }
+            ],
+            "preview": false,
+            "total": 2
+        })))
+        .mount(&mock_server)
+        .await;
+
+    // Logs go to stdout by default; keep it to the alert lines
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri())
+        .env("RUST_LOG", "off");
+    let output = cmd
+        .args(["health", "security", "--threshold", "20", "-o", "ndjson"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0]["alert"], "brute_force_login");
+    assert_eq!(lines[0]["src_ip"], "203.0.113.7");
+    assert_eq!(lines[0]["failures"], 31);
+    assert_eq!(lines[0]["threshold"], 20);
+    assert_eq!(lines[0]["time_window"], "-1h");
+}
+
+/// Test that `health disk -o json` reports every partition, fullest first, with the server roles.
+#[tokio::test]
+async fn test_health_disk_reports_partitions() {
+    let mock_server = MockServer::start().await;
+
+    let server_info: serde_json::Value = serde_json::from_str(include_str!(
+        "../../client/fixtures/server/get_server_info.json"
+    ))
+    .unwrap();
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(server_info))
+        .mount(&mock_server)
+        .await;
+    let partitions: serde_json::Value = serde_json::from_str(include_str!(
+        "../../client/fixtures/server/get_partitions_space.json"
+    ))
+    .unwrap();
+    Mock::given(method("GET"))
+        .and(path("/services/server/status/partitions-space"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(partitions))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri())
+        .env("RUST_LOG", "off");
+    let output = cmd.args(["health", "disk", "-o", "json"]).output().unwrap();
+    assert!(output.status.success());
+
+    let usage: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(usage["is_healthy"], false);
+    assert_eq!(usage["server_name"], "splunk-local");
+    assert!(
+        usage["server_roles"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!("indexer"))
+    );
+    assert_eq!(usage["critical_pct"], 90.0);
+    assert_eq!(usage["partitions"][0]["mount_point"], "/data/splunk");
+    assert_eq!(usage["partitions"][1]["mount_point"], "/opt/splunk");
+    assert_eq!(usage["partitions"][1]["capacity_mb"], 102400.0);
+}
+
+/// Test that `health search-load -o json` reports totals against the limits and flags users at quota.
+#[tokio::test]
+async fn test_health_search_load_reports_users_at_quota() {
+    let mock_server = MockServer::start().await;
+
+    let limits: serde_json::Value = serde_json::from_str(include_str!(
+        "../../client/fixtures/server/get_search_concurrency.json"
+    ))
+    .unwrap();
+    Mock::given(method("GET"))
+        .and(path("/services/server/status/limits/search-concurrency"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(limits))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains("srchJobsQuota"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": "search-load-sid" } }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/search-load-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": {
+                "sid": "search-load-sid",
+                "isDone": true,
+                "isFinalized": true,
+                "doneProgress": 1.0,
+                "runDuration": 0.0,
+                "scanCount": 0,
+                "eventCount": 0,
+                "resultCount": 3,
+                "diskUsage": 0
+            } }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let users: serde_json::Value = serde_json::from_str(include_str!(
+        "../../client/fixtures/search_load/user_search_usage.json"
+    ))
+    .unwrap();
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/search-load-sid/results"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(users))
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri())
+        .env("RUST_LOG", "off");
+    let output = cmd
+        .args(["health", "search-load", "-o", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let load: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(load["is_healthy"], false);
+    assert_eq!(load["running"], 10);
+    assert_eq!(load["queued"], 2);
+    assert_eq!(load["limits"]["max_hist_searches"], 26);
+    assert_eq!(load["users"][0]["user"], "svc_reporting");
+    assert_eq!(load["users"][1]["user"], "analyst");
+    assert_eq!(load["users"][1]["quota"], 3);
+}
+
+/// Test that `splunk-cli health resources --help` documents the host flag.
+#[test]
+fn test_health_resources_help() {
+    let mut cmd = splunk_cmd();
+    cmd.args(["health", "resources", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--host"));
+}
+
+/// Test that `health resources --host` limits both introspection searches to the host.
+#[tokio::test]
+async fn test_health_resources_reports_host_usage() {
+    let mock_server = MockServer::start().await;
+
+    for (sid, component, results) in [
+        (
+            "hosts-sid",
+            "component%3DHostwide",
+            serde_json::json!([{
+                "host": "idx01",
+                "cpu_pct": "42.5",
+                "mem_used_mb": "8192",
+                "mem_total_mb": "16384",
+                "iowait_pct": "3.5"
+            }]),
+        ),
+        (
+            "processes-sid",
+            "component%3DPerProcess",
+            serde_json::json!([{
+                "host": "idx01",
+                "process": "splunkd",
+                "pid": "4182",
+                "process_type": "search",
+                "cpu_pct": "98.2",
+                "mem_used_mb": "1024"
+            }]),
+        ),
+    ] {
+        Mock::given(method("POST"))
+            .and(path("/services/search/jobs"))
+            .and(body_string_contains(component))
+            .and(body_string_contains("host%3D%22idx01%22"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "entry": [{ "content": { "sid": sid } }]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path(format!("/services/search/jobs/{}", sid)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "entry": [{ "content": {
+                    "sid": sid,
+                    "isDone": true,
+                    "isFinalized": true,
+                    "doneProgress": 1.0,
+                    "runDuration": 0.0,
+                    "scanCount": 0,
+                    "eventCount": 0,
+                    "resultCount": 1,
+                    "diskUsage": 0
+                } }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path(format!("/services/search/jobs/{}/results", sid)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "results": results,
+                "preview": false,
+                "total": 1
+            })))
+            .mount(&mock_server)
+            .await;
+    }
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["health", "resources", "--host", "idx01"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains(
+                "idx01: CPU 42.5%, Memory 50.0% (8192 of 16384 MB), I/O Wait 3.5%",
+            )
+            .and(predicate::str::contains(
+                "idx01 splunkd [search] pid 4182: CPU 98.2%, Memory 1024.0 MB",
+            )),
+        );
+}
@@ -137,3 +137,62 @@ fn test_hec_check_ack_requires_ids() {
         predicate::str::contains("ack-ids").or(predicate::str::contains("No acknowledgment IDs")),
     );
 }
+
+/// Test that `splunk-cli hec tokens list` masks token values by default.
+#[tokio::test]
+async fn test_hec_tokens_list_masks_values() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/services/data/inputs/http"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(include_str!(
+            "../../client/fixtures/hec_tokens/list_hec_tokens.json"
+        )))
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["hec", "tokens", "list", "-o", "csv"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "name,token,index,indexes,sourcetype,description,disabled,use_ack",
+        ))
+        .stdout(predicate::str::contains("web_events,********,web"));
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["hec", "tokens", "list", "--show-token", "-o", "csv"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "web_events,6b1c8f2e-0a4d-4c59-9a4e-2f1d7c3b8e90,web",
+        ));
+}
+
+/// Test that `splunk-cli hec tokens disable` posts to the token's disable path.
+#[tokio::test]
+async fn test_hec_tokens_disable() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/services/data/inputs/http/web_events/disable"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "entry": [] })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["hec", "tokens", "disable", "web_events"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "HEC token 'web_events' disabled successfully.",
+        ));
+}
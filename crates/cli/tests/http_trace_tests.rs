@@ -0,0 +1,55 @@
+//! Integration tests for the global `--trace-http` option.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn test_trace_http_in_help() {
+    let mut cmd = splunk_cmd();
+
+    cmd.arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--trace-http"));
+}
+
+#[tokio::test]
+async fn test_trace_http_writes_redacted_capture() {
+    let mock_server = MockServer::start().await;
+
+    let fixture_data = include_str!("../../client/fixtures/indexes/list_indexes.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/data/indexes"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(fixture_data))
+        .mount(&mock_server)
+        .await;
+
+    let temp_dir = TempDir::new().unwrap();
+    let trace_path = temp_dir.path().join("trace.json");
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["indexes", "list", "--trace-http"])
+        .arg(&trace_path)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Wrote 1 HTTP trace entries"));
+
+    let contents = std::fs::read_to_string(&trace_path).unwrap();
+    let trace: serde_json::Value = serde_json::from_str(&contents).unwrap();
+    let entries = trace["entries"].as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["status"], 200);
+    assert_eq!(
+        entries[0]["request"]["headers"]["authorization"],
+        "<redacted>"
+    );
+    assert!(!contents.contains("test-token"));
+}
@@ -1,9 +1,11 @@
-//! Integration tests for `splunk-cli indexes` create, modify, and delete commands.
+//! Integration tests for `splunk-cli indexes` create, modify, delete, roll, rebalance, and sample commands.
 
 mod common;
 
 use common::{connection_error_predicate, splunk_cmd};
 use predicates::prelude::*;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[test]
 fn test_indexes_create_help() {
@@ -108,3 +110,149 @@ fn test_indexes_delete_attempts_connection() {
         .failure()
         .stderr(connection_error_predicate());
 }
+
+#[tokio::test]
+async fn test_indexes_roll_posts_roll_hot_buckets() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/services/data/indexes/main/roll-hot-buckets"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["indexes", "roll", "main"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Hot buckets of index 'main' rolled to warm.",
+        ));
+}
+
+#[tokio::test]
+async fn test_indexes_rebalance_start_for_index() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(
+            "/services/cluster/master/control/control/rebalance_buckets",
+        ))
+        .and(body_string_contains("action=start"))
+        .and(body_string_contains("index=main"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args([
+        "indexes",
+        "rebalance",
+        "start",
+        "--index",
+        "main",
+        "-o",
+        "json",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("Data rebalance started for main"));
+}
+
+#[test]
+fn test_indexes_rebalance_rejects_unknown_action() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["indexes", "rebalance", "pause"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'pause'"));
+}
+
+async fn mount_sample_search(mock_server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains("head+2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": "sample-sid" } }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/sample-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": "sample-sid", "isDone": true, "doneProgress": 1.0 } }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/sample-sid/results"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                {
+                    "_time": "2024-01-15T10:30:00.000+00:00",
+                    "index": "web",
+                    "host": "web01",
+                    "sourcetype": "access_combined",
+                    "_raw": "10.0.0.1 - - \"GET / HTTP/1.1\" 200"
+                },
+                {
+                    "_time": "2024-01-15T10:29:58.000+00:00",
+                    "index": "web",
+                    "host": "web02",
+                    "sourcetype": "access_combined",
+                    "_raw": "10.0.0.2 - - \"GET /login HTTP/1.1\" 302"
+                }
+            ]
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_indexes_sample_prints_raw_events() {
+    let mock_server = MockServer::start().await;
+    mount_sample_search(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args([
+        "indexes",
+        "sample",
+        "web",
+        "--count",
+        "2",
+        "--sourcetype",
+        "access_combined",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "2024-01-15T10:30:00.000+00:00  host=web01  sourcetype=access_combined\n10.0.0.1 - - \"GET / HTTP/1.1\" 200",
+    ))
+    .stdout(predicate::str::contains("2 event(s)."));
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let body = String::from_utf8_lossy(&requests[0].body).to_string();
+    assert!(body.contains("index%3D%22web%22+sourcetype%3D%22access_combined%22"));
+}
+
+#[tokio::test]
+async fn test_indexes_sample_json() {
+    let mock_server = MockServer::start().await;
+    mount_sample_search(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["indexes", "sample", "web", "--count", "2", "-o", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(r#""host": "web02""#))
+        .stdout(predicate::str::contains(r#""_raw": "10.0.0.2"#));
+}
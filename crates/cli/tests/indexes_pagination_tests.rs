@@ -4,6 +4,21 @@ mod common;
 
 use common::{connection_error_predicate, splunk_cmd};
 use predicates::prelude::*;
+use tempfile::TempDir;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn index_page(range: std::ops::Range<usize>) -> serde_json::Value {
+    let entries: Vec<_> = range
+        .map(|i| {
+            serde_json::json!({
+                "name": format!("idx_{i:03}"),
+                "content": { "currentDBSizeMB": 1, "totalEventCount": i }
+            })
+        })
+        .collect();
+    serde_json::json!({ "entry": entries })
+}
 
 #[test]
 fn test_indexes_help_includes_offset() {
@@ -37,3 +52,55 @@ fn test_indexes_offset_negative_rejected_by_clap() {
         .failure()
         .stderr(predicate::str::contains("unexpected argument"));
 }
+
+#[test]
+fn test_indexes_all_conflicts_with_offset() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["indexes", "list", "--all", "--offset", "10"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+/// Test that `--all` walks every page until a short page is returned.
+#[tokio::test]
+async fn test_indexes_all_fetches_every_page() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("indexes.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/data/indexes"))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(index_page(0..100)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/data/indexes"))
+        .and(query_param("offset", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(index_page(100..130)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri())
+        .args([
+            "indexes",
+            "list",
+            "--all",
+            "--output",
+            "json",
+            "--output-file",
+        ])
+        .arg(&output_path)
+        .assert()
+        .success();
+
+    let content = std::fs::read_to_string(&output_path).unwrap();
+    let indexes: Vec<serde_json::Value> = serde_json::from_str(&content).unwrap();
+    assert_eq!(indexes.len(), 130);
+    assert_eq!(indexes[129]["name"], "idx_129");
+}
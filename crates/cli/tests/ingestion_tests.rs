@@ -0,0 +1,99 @@
+//! Integration tests for `splunk-cli ingestion`.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_search(mock_server: &MockServer, sid: &str, query_marker: &str, fixture: &str) {
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains(query_marker))
+        .and(body_string_contains("earliest_time=-1800s"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": sid } }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}", sid)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": sid, "isDone": true, "doneProgress": 1.0 } }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}/results", sid)))
+        .respond_with(ResponseTemplate::new(200).set_body_string(fixture))
+        .mount(mock_server)
+        .await;
+}
+
+#[test]
+fn test_ingestion_stats_help() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["ingestion", "stats", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--by").and(predicate::str::contains("--since")));
+}
+
+#[test]
+fn test_ingestion_stats_rejects_unknown_grouping() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["ingestion", "stats", "--by", "source"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Valid values: sourcetype, index, host",
+        ));
+}
+
+#[tokio::test]
+async fn test_ingestion_stats_by_sourcetype_csv() {
+    let mock_server = MockServer::start().await;
+
+    mount_search(
+        &mock_server,
+        "latency-sid",
+        "_indextime-_time",
+        include_str!("../../client/fixtures/ingestion/latency.json"),
+    )
+    .await;
+    mount_search(
+        &mock_server,
+        "thruput-sid",
+        "group%3Dper_sourcetype_thruput",
+        include_str!("../../client/fixtures/ingestion/thruput.json"),
+    )
+    .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args([
+        "ingestion",
+        "stats",
+        "--by",
+        "sourcetype",
+        "--since",
+        "30m",
+        "-o",
+        "csv",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "sourcetype,events,kb,kb_per_sec,events_per_sec,avg_latency_secs,max_latency_secs",
+    ))
+    .stdout(predicate::str::contains(
+        "access_combined,7200,3600,2,4,2.5,31",
+    ))
+    .stdout(predicate::str::contains("splunkd,360,7200,4,0.2,,"));
+}
@@ -4,13 +4,15 @@
 //! - List subcommand with `--detailed`, `--input-type`, `--count`, `--offset` flags
 //! - Output format variations (json, csv, xml)
 //! - `--output-file` flag
+//! - `test-send` over TCP and UDP with search-based arrival verification
+//! - `add monitor|tcp|udp` and `delete`
 //! - Error handling
 
 mod common;
 
 use common::{connection_error_predicate, splunk_cmd};
 use predicates::prelude::*;
-use wiremock::matchers::{header, method, path, query_param};
+use wiremock::matchers::{body_string_contains, header, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 /// Test that `splunk-cli inputs --help` shows correct subcommands.
@@ -408,3 +410,249 @@ async fn test_inputs_server_error() {
         .assert()
         .failure();
 }
+
+/// Mount search job mocks whose `stats count` result reports `count` events.
+async fn mount_count_search(mock_server: &MockServer, count: u64) {
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": "test-send-sid" } }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/test-send-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": {
+                "sid": "test-send-sid",
+                "isDone": true,
+                "isFinalized": true,
+                "doneProgress": 1.0,
+                "runDuration": 0.0,
+                "scanCount": 0,
+                "eventCount": 0,
+                "resultCount": 1,
+                "diskUsage": 0
+            } }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/test-send-sid/results"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{ "count": count.to_string() }],
+            "preview": false,
+            "total": 1
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+/// Test that `inputs test-send` delivers tagged lines over TCP and verifies them by search.
+#[tokio::test]
+async fn test_inputs_test_send_tcp_verified() {
+    use std::io::Read;
+
+    let mock_server = MockServer::start().await;
+    mount_count_search(&mock_server, 3).await;
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let receiver = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut received = String::new();
+        stream.read_to_string(&mut received).unwrap();
+        received
+    });
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let output_path = temp_dir.path().join("test-send.json");
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args([
+        "inputs",
+        "test-send",
+        "--type",
+        "tcp",
+        "--host",
+        "127.0.0.1",
+        "--port",
+        &port.to_string(),
+        "--count",
+        "3",
+        "--output",
+        "json",
+        "--output-file",
+        output_path.to_str().unwrap(),
+    ])
+    .assert()
+    .success();
+
+    let report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&output_path).unwrap()).unwrap();
+    assert_eq!(report["sent"], 3);
+    assert_eq!(report["verification"]["found"], 3);
+
+    let marker = report["marker"].as_str().unwrap();
+    let received = receiver.join().unwrap();
+    assert_eq!(received.lines().count(), 3);
+    assert!(received.lines().all(|line| line.contains(marker)));
+    assert!(received.contains("seq=3/3"));
+}
+
+/// Test that `inputs test-send --no-verify` sends one UDP datagram per line without searching.
+#[tokio::test]
+async fn test_inputs_test_send_udp_no_verify() {
+    let socket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+    socket
+        .set_read_timeout(Some(std::time::Duration::from_secs(5)))
+        .unwrap();
+    let port = socket.local_addr().unwrap().port();
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", "http://127.0.0.1:1");
+    cmd.args([
+        "inputs",
+        "test-send",
+        "--type",
+        "udp",
+        "--host",
+        "127.0.0.1",
+        "--port",
+        &port.to_string(),
+        "--count",
+        "2",
+        "--no-verify",
+    ])
+    .assert()
+    .success();
+
+    let mut buf = [0u8; 512];
+    for _ in 0..2 {
+        let len = socket.recv(&mut buf).unwrap();
+        let datagram = std::str::from_utf8(&buf[..len]).unwrap();
+        assert!(datagram.contains("splunk-cli inputs test-send marker="));
+        assert!(datagram.ends_with('\n'));
+    }
+}
+
+/// Test that `inputs test-send` fails when fewer lines arrive than were sent.
+#[tokio::test]
+async fn test_inputs_test_send_reports_missing_lines() {
+    let mock_server = MockServer::start().await;
+    mount_count_search(&mock_server, 1).await;
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let receiver = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        std::io::copy(&mut stream, &mut std::io::sink()).unwrap();
+    });
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args([
+        "inputs",
+        "test-send",
+        "--host",
+        "127.0.0.1",
+        "--port",
+        &port.to_string(),
+        "--count",
+        "2",
+        "--verify-timeout",
+        "0",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "Only 1 of 2 test line(s) became searchable",
+    ));
+
+    receiver.join().unwrap();
+}
+
+/// Test that `--tls` is rejected for UDP.
+#[test]
+fn test_inputs_test_send_rejects_tls_over_udp() {
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", "http://127.0.0.1:1");
+    cmd.args([
+        "inputs",
+        "test-send",
+        "--type",
+        "udp",
+        "--port",
+        "514",
+        "--tls",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "--tls is only supported with --type tcp",
+    ));
+}
+
+/// Test that `inputs add monitor` posts the path, index, and sourcetype.
+#[tokio::test]
+async fn test_inputs_add_monitor() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/data/inputs/monitor"))
+        .and(body_string_contains("name=%2Fvar%2Flog%2Fapp"))
+        .and(body_string_contains("index=app"))
+        .and(body_string_contains("sourcetype=app%3Alog"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "/var/log/app",
+                "content": {"index": "app", "sourcetype": "app:log", "disabled": false}
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args([
+        "inputs",
+        "add",
+        "monitor",
+        "/var/log/app",
+        "--index",
+        "app",
+        "--sourcetype",
+        "app:log",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Input '/var/log/app' (monitor) created successfully (index: app, sourcetype: app:log).",
+    ));
+}
+
+/// Test that `inputs delete --force` deletes without prompting.
+#[tokio::test]
+async fn test_inputs_delete_force() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/services/data/inputs/udp/514"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(""))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["inputs", "delete", "udp", "514", "--force"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Input '514' (udp) deleted successfully.",
+        ));
+}
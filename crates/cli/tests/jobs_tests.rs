@@ -5,7 +5,8 @@
 //! - `--cancel` and `--delete` flags (verify list is NOT called)
 //! - `--list` flag explicit usage
 //! - `--results` flag for retrieving job results
-//! - `--inspect` flag for job inspection
+//! - `--inspect` flag for job inspection, with `--performance` breakdown
+//! - `--owner`, `--app`, `--status` server-side list filters
 //! - Batch cancel and delete subcommands
 //! - File-based batch operations
 
@@ -13,7 +14,7 @@ mod common;
 
 use common::{connection_error_predicate, splunk_cmd};
 use predicates::prelude::*;
-use wiremock::matchers::{header, method, path};
+use wiremock::matchers::{body_string_contains, header, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 /// Test that `splunk-cli jobs` with no arguments defaults to listing jobs.
@@ -181,6 +182,69 @@ fn test_jobs_inspect_and_delete_mutually_exclusive() {
         .stderr(predicate::str::contains("cannot be used with"));
 }
 
+/// Test that --performance requires --inspect (clap enforcement).
+#[test]
+fn test_jobs_performance_requires_inspect() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["jobs", "--performance"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--inspect"));
+}
+
+/// Test that `--inspect SID --performance` prints the timing breakdown.
+#[tokio::test]
+async fn test_jobs_inspect_performance() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/perf-sid"))
+        .and(header("Authorization", "Bearer test-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "perf-sid",
+                "content": {
+                    "sid": "perf-sid",
+                    "isDone": true,
+                    "runDuration": 3.5,
+                    "performance": {
+                        "command.search": {"duration_secs": 1.2, "invocations": 4, "input_count": 0, "output_count": 500},
+                        "dispatch.fetch": {"duration_secs": 2.1, "invocations": 5},
+                        "dispatch.stream.remote.idx1": {"duration_secs": 0.9, "invocations": 3, "input_count": 400, "output_count": 4096}
+                    }
+                }
+            }]
+        })))
+        .mount(&server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", server.uri());
+    cmd.args(["jobs", "--inspect", "perf-sid", "--performance"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Job perf-sid performance (run duration 3.50s)",
+        ))
+        .stdout(predicate::str::contains("Remote peers:"))
+        .stdout(predicate::str::contains("idx1"));
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", server.uri());
+    cmd.args([
+        "jobs",
+        "--inspect",
+        "perf-sid",
+        "--performance",
+        "-o",
+        "csv",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("peer,idx1,0.9,3,400,4096"));
+}
+
 #[tokio::test]
 async fn test_jobs_cancel_delete_show_progress_on_stderr_unless_quiet() {
     let server = MockServer::start().await;
@@ -351,6 +415,221 @@ async fn test_jobs_results_json_output() {
         .stdout(predicate::str::contains("server2"));
 }
 
+/// Test that list filters are sent to Splunk as a `search` query parameter.
+#[tokio::test]
+async fn test_jobs_list_server_side_filters() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs"))
+        .and(query_param(
+            "search",
+            "eai:acl.owner=admin eai:acl.app=search dispatchState=FAILED",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "failed-sid",
+                "content": {"sid": "failed-sid", "isDone": true, "doneProgress": 1.0}
+            }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let output_file = temp_dir.path().join("jobs.json");
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", server.uri());
+    cmd.args([
+        "--output",
+        "json",
+        "--output-file",
+        output_file.to_str().unwrap(),
+        "jobs",
+        "--owner",
+        "admin",
+        "--app",
+        "search",
+        "--status",
+        "failed",
+    ])
+    .assert()
+    .success();
+
+    let content = std::fs::read_to_string(&output_file).unwrap();
+    assert!(content.contains("failed-sid"));
+}
+
+/// Test that `jobs extend-ttl` resolves `--owner me` and only extends expiring jobs.
+#[tokio::test]
+async fn test_jobs_extend_ttl_owner_me() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/authentication/current-context"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{"name": "context", "content": {"username": "analyst"}}]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs"))
+        .and(query_param("search", "eai:acl.owner=analyst"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [
+                {"name": "soon-sid", "content": {"sid": "soon-sid", "isDone": true, "ttl": 300}},
+                {"name": "later-sid", "content": {"sid": "later-sid", "isDone": true, "ttl": 90000}}
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs/soon-sid/control"))
+        .and(body_string_contains("action=setttl"))
+        .and(body_string_contains("ttl=86400"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs/later-sid/control"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", server.uri());
+    cmd.args([
+        "jobs",
+        "extend-ttl",
+        "--owner",
+        "me",
+        "--min-remaining",
+        "10m",
+        "--to",
+        "24h",
+        "--force",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("Extended TTL of 1 job(s)"));
+}
+
+/// Serve a done-job listing for `jobs prune`: one job from 2020 and one from the far future.
+async fn mount_prunable_jobs(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs"))
+        .and(query_param("count", "0"))
+        .and(query_param("search", "dispatchState=DONE"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [
+                {
+                    "author": "admin",
+                    "published": "2020-01-01T00:00:00.000+00:00",
+                    "content": {"sid": "old-sid", "isDone": true, "dispatchState": "DONE"}
+                },
+                {
+                    "author": "admin",
+                    "published": "2999-01-01T00:00:00.000+00:00",
+                    "content": {"sid": "new-sid", "isDone": true, "dispatchState": "DONE"}
+                }
+            ]
+        })))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+/// Test that `jobs prune` deletes only jobs older than the cutoff.
+#[tokio::test]
+async fn test_jobs_prune_deletes_old_jobs() {
+    let server = MockServer::start().await;
+    mount_prunable_jobs(&server).await;
+    Mock::given(method("DELETE"))
+        .and(path("/services/search/jobs/old-sid"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/services/search/jobs/new-sid"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", server.uri());
+    cmd.args([
+        "jobs",
+        "prune",
+        "--older-than",
+        "2d",
+        "--status",
+        "done",
+        "--force",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("Deleted 1 job(s)"));
+}
+
+/// Test that `jobs prune --dry-run` lists old jobs without deleting them.
+#[tokio::test]
+async fn test_jobs_prune_dry_run() {
+    let server = MockServer::start().await;
+    mount_prunable_jobs(&server).await;
+    Mock::given(method("DELETE"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", server.uri());
+    cmd.args([
+        "jobs",
+        "prune",
+        "--older-than",
+        "2d",
+        "--status",
+        "done",
+        "--dry-run",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("old-sid"))
+    .stdout(predicate::str::contains("new-sid").not())
+    .stdout(predicate::str::contains("Would delete 1 job(s)"));
+}
+
+/// Test that an invalid `--older-than` is a usage error.
+#[test]
+fn test_jobs_prune_invalid_age() {
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", "https://localhost:8089");
+    cmd.args(["jobs", "prune", "--older-than", "2fortnights"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "Invalid --older-than '2fortnights'",
+        ));
+}
+
+/// Test that an unknown `--status` value is rejected before any request.
+#[test]
+fn test_jobs_invalid_status_rejected() {
+    let mut cmd = splunk_cmd();
+    cmd.args(["jobs", "--status", "paused"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid job status 'paused'"));
+}
+
 /// Test results with output file.
 #[tokio::test]
 async fn test_jobs_results_output_file() {
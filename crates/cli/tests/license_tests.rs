@@ -4,6 +4,8 @@ mod common;
 
 use common::{connection_error_predicate, splunk_cmd};
 use predicates::prelude::*;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 /// Test that `splunk-cli license --help` shows the command.
 #[test]
@@ -38,3 +40,71 @@ fn test_license_formats() {
             .stderr(connection_error_predicate());
     }
 }
+
+/// Test that `splunk-cli license messages` lists licenser messages.
+#[tokio::test]
+async fn test_license_messages() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/licenser/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "msg1",
+                "content": {
+                    "category": "pool_over_quota",
+                    "severity": "WARN",
+                    "description": "pool exceeded quota",
+                    "slave_id": "GUID-1"
+                }
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["-o", "csv", "license", "messages"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "WARN,pool_over_quota,-,GUID-1,-,pool exceeded quota",
+        ));
+}
+
+/// Test that `splunk-cli license slaves` flags slaves named in over-quota messages.
+#[tokio::test]
+async fn test_license_slaves_over_quota() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/licenser/slaves"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [
+                {"name": "GUID-1", "content": {"label": "idx1", "warning_count": "4"}},
+                {"name": "GUID-2", "content": {"label": "idx2", "warning_count": "0"}}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/licenser/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "msg1",
+                "content": {"category": "pool_over_quota", "severity": "WARN", "slave_id": "GUID-1"}
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["-o", "csv", "license", "slaves"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("idx1,GUID-1,,4,1,yes")
+                .and(predicate::str::contains("idx2,GUID-2,,0,5,no")),
+        );
+}
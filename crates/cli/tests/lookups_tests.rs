@@ -7,6 +7,7 @@
 //! - `--output-file` flag
 //! - Empty results handling
 //! - Error handling
+//! - `cat` and `edit` round-tripping lookup contents (editor stubbed with `sed`)
 
 mod common;
 
@@ -24,6 +25,8 @@ fn test_lookups_help() {
         predicate::str::contains("list")
             .and(predicate::str::contains("download"))
             .and(predicate::str::contains("upload"))
+            .and(predicate::str::contains("cat"))
+            .and(predicate::str::contains("edit"))
             .and(predicate::str::contains("delete")),
     );
 }
@@ -358,3 +361,83 @@ fn test_lookups_no_detailed_flag() {
         .success()
         .stdout(predicate::str::contains("--detailed").not());
 }
+
+async fn mount_lookup_contents(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path(
+            "/servicesNS/-/search/data/lookup-table-files/hosts.csv",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_string("host,owner\nweb-01,ops\n"))
+        .mount(mock_server)
+        .await;
+}
+
+/// Test that `splunk-cli lookups cat` prints the raw CSV.
+#[tokio::test]
+async fn test_lookups_cat_prints_csv() {
+    let mock_server = MockServer::start().await;
+    mount_lookup_contents(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["lookups", "cat", "hosts.csv"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("host,owner\nweb-01,ops\n"));
+}
+
+/// Test that `splunk-cli lookups edit` uploads the file changed by the editor.
+#[tokio::test]
+async fn test_lookups_edit_uploads_changes() {
+    let mock_server = MockServer::start().await;
+    mount_lookup_contents(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/servicesNS/-/search/data/lookup-table-files"))
+        .and(wiremock::matchers::body_string_contains(
+            "host,owner\nweb-01,sre\n",
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "hosts.csv",
+                "content": {
+                    "name": "hosts.csv",
+                    "filename": "hosts.csv",
+                    "owner": "nobody",
+                    "app": "search",
+                    "sharing": "app",
+                    "size": 23
+                }
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri())
+        .env_remove("VISUAL")
+        .env("EDITOR", "sed -i s/ops/sre/");
+    cmd.args(["lookups", "edit", "hosts.csv"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Lookup 'hosts.csv' updated (1 rows).",
+        ));
+}
+
+/// Test that `splunk-cli lookups edit` skips the upload when nothing changed.
+#[tokio::test]
+async fn test_lookups_edit_without_changes_skips_upload() {
+    let mock_server = MockServer::start().await;
+    mount_lookup_contents(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri())
+        .env_remove("VISUAL")
+        .env("EDITOR", "true");
+    cmd.args(["lookups", "edit", "hosts.csv"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No changes"));
+}
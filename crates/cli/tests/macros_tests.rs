@@ -0,0 +1,69 @@
+//! Integration tests for `splunk-cli macros expand`.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn macro_entry(name: &str, definition: &str) -> serde_json::Value {
+    serde_json::json!({
+        "entry": [{
+            "name": name,
+            "content": { "definition": definition, "disabled": false, "iseval": false }
+        }]
+    })
+}
+
+#[test]
+fn test_macros_expand_help() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["macros", "expand", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("QUERY"));
+}
+
+#[tokio::test]
+async fn test_macros_expand_nested_and_unknown() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/admin/macros/web"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(macro_entry("web", "index=web `noise`")),
+        )
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/admin/macros/noise"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(macro_entry("noise", "NOT bot=true")),
+        )
+        .mount(&mock_server)
+        .await;
+
+    // `missing` has no mock and resolves to a 404, which is reported rather than failing.
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["macros", "expand", "`web` `missing` | head 5"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "index=web NOT bot=true `missing` | head 5",
+        ))
+        .stdout(predicate::str::contains("Unknown or disabled macros"));
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["macros", "expand", "`web`", "-o", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"name\": \"noise\""))
+        .stdout(predicate::str::contains("\"depth\": 2"));
+}
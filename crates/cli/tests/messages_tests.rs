@@ -0,0 +1,67 @@
+//! Integration tests for `splunk-cli messages`.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn test_messages_help() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["messages", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("list").and(predicate::str::contains("dismiss")));
+}
+
+#[tokio::test]
+async fn test_messages_list_csv() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = include_str!("../../client/fixtures/messages/list_messages.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(fixture))
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["messages", "list", "-o", "csv"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "name,severity,time_created,server,message,help",
+        ))
+        .stdout(predicate::str::contains("disk_space,error"))
+        .stdout(predicate::str::contains(
+            "Splunk must be restarted for changes to take effect.",
+        ));
+}
+
+#[tokio::test]
+async fn test_messages_dismiss() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/services/messages/restart_required"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["messages", "dismiss", "restart_required"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Message 'restart_required' dismissed",
+        ));
+}
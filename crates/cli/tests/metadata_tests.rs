@@ -0,0 +1,117 @@
+//! Integration tests for `splunk-cli metadata`.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_search(mock_server: &MockServer, sid: &str, query_marker: &str, fixture: &str) {
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains(query_marker))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": sid } }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}", sid)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": sid, "isDone": true, "doneProgress": 1.0 } }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}/results", sid)))
+        .respond_with(ResponseTemplate::new(200).set_body_string(fixture))
+        .mount(mock_server)
+        .await;
+}
+
+async fn mount_host_searches(mock_server: &MockServer) {
+    mount_search(
+        mock_server,
+        "metadata-sid",
+        "type%3Dhosts",
+        include_str!("../../client/fixtures/metadata/hosts.json"),
+    )
+    .await;
+    mount_search(
+        mock_server,
+        "indexes-sid",
+        "tstats+values%28index%29",
+        include_str!("../../client/fixtures/metadata/host_indexes.json"),
+    )
+    .await;
+}
+
+#[test]
+fn test_metadata_hosts_help() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["metadata", "hosts", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--index"))
+        .stdout(predicate::str::contains("--stale"));
+}
+
+#[test]
+fn test_metadata_hosts_rejects_invalid_stale() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["metadata", "hosts", "--stale", "1d"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown duration unit"));
+}
+
+#[tokio::test]
+async fn test_metadata_hosts_csv() {
+    let mock_server = MockServer::start().await;
+    mount_host_searches(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["metadata", "hosts", "-o", "csv"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "host,total_count,first_time,last_time,recent_time,indexes",
+        ))
+        .stdout(predicate::str::contains(
+            "web01,982113,1690000000,1700086400,1700086405,main;web",
+        ))
+        .stdout(predicate::str::contains("old-fwd,5120"));
+}
+
+#[tokio::test]
+async fn test_metadata_hosts_stale_lists_only_silent_hosts() {
+    let mock_server = MockServer::start().await;
+    mount_host_searches(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    // old-fwd last sent in 2014; the others in late 2023.
+    cmd.args([
+        "metadata", "hosts", "--index", "main", "--stale", "100000h", "-o", "csv",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("old-fwd,5120"))
+    .stdout(predicate::str::contains("web01").not())
+    .stdout(predicate::str::contains("db01").not());
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let filtered = requests
+        .iter()
+        .filter(|r| String::from_utf8_lossy(&r.body).contains("index%3D%22main%22"))
+        .count();
+    assert_eq!(filtered, 2);
+}
@@ -0,0 +1,110 @@
+//! Integration tests for `splunk-cli monitor`.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_check_mocks(mock_server: &MockServer) {
+    let health: serde_json::Value =
+        serde_json::from_str(include_str!("../../client/fixtures/server/get_health.json")).unwrap();
+    Mock::given(method("GET"))
+        .and(path("/services/server/health/splunkd"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(health))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/licenser/usage"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "daily_usage",
+                "content": {"quota": 1000, "used_bytes": 950, "stack_id": "enterprise"}
+            }]
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+/// Test that `splunk-cli monitor --help` documents the daemon flags.
+#[test]
+fn test_monitor_help() {
+    let mut cmd = splunk_cmd();
+    cmd.args(["monitor", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--interval <INTERVAL>"))
+        .stdout(predicate::str::contains("--checks <CHECKS>"))
+        .stdout(predicate::str::contains("--state-file <PATH>"))
+        .stdout(predicate::str::contains("--notify-webhook <URL>"));
+}
+
+/// Test that an unknown check name is a usage error.
+#[test]
+fn test_monitor_rejects_unknown_check() {
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", "https://localhost:8089")
+        .args(["monitor", "--checks", "health,disk", "--once"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("unknown check 'disk'"));
+}
+
+/// Test that transitions are printed once and remembered across restarts.
+#[tokio::test]
+async fn test_monitor_once_reports_transitions_and_keeps_state() {
+    let mock_server = MockServer::start().await;
+    mount_check_mocks(&mock_server).await;
+    let webhook = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&webhook)
+        .await;
+    let dir = TempDir::new().unwrap();
+    let state_file = dir.path().join("state.json");
+
+    let run = || {
+        let mut cmd = splunk_cmd();
+        cmd.env("SPLUNK_BASE_URL", mock_server.uri())
+            .env("RUST_LOG", "off")
+            .args([
+                "monitor",
+                "--once",
+                "--checks",
+                "license,health",
+                "--state-file",
+                state_file.to_str().unwrap(),
+                "--notify-webhook",
+                &webhook.uri(),
+                "-o",
+                "ndjson",
+            ]);
+        cmd.assert().success().get_output().stdout.clone()
+    };
+
+    let stdout = String::from_utf8(run()).unwrap();
+    let lines: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2, "stdout: {stdout}");
+    assert_eq!(lines[0]["check"], "health");
+    assert_eq!(lines[0]["to"], "ok");
+    assert_eq!(lines[1]["check"], "license");
+    assert_eq!(lines[1]["from"], serde_json::Value::Null);
+    assert_eq!(lines[1]["to"], "critical");
+    assert_eq!(lines[1]["message"], "enterprise at 95.0% of daily quota");
+
+    // A restart with the same state reports nothing new.
+    assert!(run().is_empty());
+
+    // Only the critical license check was posted; a check first seen as ok is not.
+    let requests = webhook.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(body["source"], "monitor");
+    assert_eq!(body["check"], "license");
+    assert_eq!(body["status"], "critical");
+}
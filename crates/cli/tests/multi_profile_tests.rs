@@ -0,0 +1,253 @@
+//! Integration tests for running commands across profiles (`--profiles`, `--all-profiles`).
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Write a config file whose profiles point at the given base URLs.
+fn write_config(temp_dir: &TempDir, profiles: &[(&str, &str)]) -> std::path::PathBuf {
+    let profiles: serde_json::Map<String, serde_json::Value> = profiles
+        .iter()
+        .map(|(name, base_url)| {
+            (
+                name.to_string(),
+                serde_json::json!({ "base_url": base_url, "api_token": format!("{name}-token") }),
+            )
+        })
+        .collect();
+    let config_path = temp_dir.path().join("config.json");
+    std::fs::write(
+        &config_path,
+        serde_json::json!({ "profiles": profiles }).to_string(),
+    )
+    .unwrap();
+    config_path
+}
+
+async fn mount_indexes(server: &MockServer, names: &[&str]) {
+    let entries: Vec<_> = names
+        .iter()
+        .map(|name| {
+            serde_json::json!({
+                "name": name,
+                "content": { "currentDBSizeMB": 10, "totalEventCount": 42 }
+            })
+        })
+        .collect();
+    Mock::given(method("GET"))
+        .and(path("/services/data/indexes"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "entry": entries })),
+        )
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_indexes_list_across_profiles_adds_profile_column() {
+    let dev = MockServer::start().await;
+    let prod = MockServer::start().await;
+    mount_indexes(&dev, &["dev_main"]).await;
+    mount_indexes(&prod, &["prod_main", "prod_web"]).await;
+
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = write_config(&temp_dir, &[("dev", &dev.uri()), ("prod", &prod.uri())]);
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args(["-o", "csv", "indexes", "list", "--profiles", "prod,dev"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "profile,name,currentDBSizeMB,totalEventCount,maxTotalDataSizeMB",
+        ))
+        .stdout(predicate::str::contains(
+            "prod,prod_main,10,42,\nprod,prod_web,10,42,\ndev,dev_main,10,42,",
+        ));
+}
+
+#[tokio::test]
+async fn test_failing_profile_does_not_hide_other_rows() {
+    let dev = MockServer::start().await;
+    let prod = MockServer::start().await;
+    mount_indexes(&dev, &["dev_main"]).await;
+    Mock::given(method("GET"))
+        .and(path("/services/data/indexes"))
+        .respond_with(ResponseTemplate::new(401).set_body_string("Unauthorized"))
+        .mount(&prod)
+        .await;
+
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = write_config(&temp_dir, &[("dev", &dev.uri()), ("prod", &prod.uri())]);
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args(["-o", "json", "indexes", "list", "--all-profiles"])
+        .assert()
+        .code(3)
+        .stdout(predicate::str::contains("\"profile\": \"dev\""))
+        .stdout(predicate::str::contains("dev_main"))
+        .stderr(predicate::str::contains("1 of 2 profiles failed"))
+        .stderr(predicate::str::contains("prod: "));
+}
+
+#[tokio::test]
+async fn test_jobs_across_all_profiles() {
+    let dev = MockServer::start().await;
+    let prod = MockServer::start().await;
+    for (server, sid) in [(&dev, "dev-sid"), (&prod, "prod-sid")] {
+        Mock::given(method("GET"))
+            .and(path("/services/search/jobs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "entry": [{ "name": sid, "content": { "sid": sid, "isDone": true } }]
+            })))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = write_config(&temp_dir, &[("dev", &dev.uri()), ("prod", &prod.uri())]);
+    let output_file = temp_dir.path().join("jobs.json");
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args(["-o", "json", "--output-file"])
+        .arg(&output_file)
+        .args(["jobs", "--all-profiles"])
+        .assert()
+        .success();
+
+    let jobs: Vec<serde_json::Value> =
+        serde_json::from_str(&std::fs::read_to_string(&output_file).unwrap()).unwrap();
+    let tagged: Vec<_> = jobs
+        .iter()
+        .map(|job| (job["profile"].as_str(), job["sid"].as_str()))
+        .collect();
+    assert_eq!(
+        tagged,
+        vec![
+            (Some("dev"), Some("dev-sid")),
+            (Some("prod"), Some("prod-sid"))
+        ]
+    );
+}
+
+/// Serve a finished search job that returns `results`.
+async fn mount_search(server: &MockServer, results: serde_json::Value) {
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": "merge-sid" } }]
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/merge-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": {
+                "sid": "merge-sid",
+                "isDone": true,
+                "isFinalized": false,
+                "doneProgress": 1.0,
+                "runDuration": 0.0,
+                "scanCount": 0,
+                "eventCount": 0,
+                "resultCount": 2,
+                "diskUsage": 0
+            } }]
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/merge-sid/results"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": results,
+            "preview": false
+        })))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_search_merge_sums_counts_by_key() {
+    let us = MockServer::start().await;
+    let eu = MockServer::start().await;
+    mount_search(
+        &us,
+        serde_json::json!([
+            { "sourcetype": "access", "count": "10" },
+            { "sourcetype": "syslog", "count": "4" }
+        ]),
+    )
+    .await;
+    mount_search(
+        &eu,
+        serde_json::json!([{ "sourcetype": "access", "count": "5" }]),
+    )
+    .await;
+
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = write_config(&temp_dir, &[("prod-us", &us.uri()), ("prod-eu", &eu.uri())]);
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args([
+            "-o",
+            "csv",
+            "search",
+            "--profiles",
+            "prod-us,prod-eu",
+            "index=web | stats count by sourcetype",
+            "--merge",
+            "--sum-by",
+            "sourcetype",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "profile,sourcetype,count\n\"prod-us,prod-eu\",access,15\nprod-us,syslog,4",
+        ));
+}
+
+#[test]
+fn test_merge_requires_profiles() {
+    splunk_cmd()
+        .args(["search", "execute", "index=main", "--merge"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("--profiles"));
+}
+
+#[test]
+fn test_unknown_profile_is_a_usage_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = write_config(&temp_dir, &[("dev", "https://localhost:8089")]);
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args(["health", "--profiles", "dev,staging"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "Profile 'staging' not found. Available profiles: dev",
+        ));
+}
+
+#[test]
+fn test_job_actions_reject_profiles() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = write_config(&temp_dir, &[("dev", "https://localhost:8089")]);
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args(["jobs", "--profiles", "dev", "--cancel", "1234"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("not supported with job actions"));
+}
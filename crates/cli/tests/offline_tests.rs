@@ -0,0 +1,87 @@
+//! Integration tests for `splunk-cli --offline`.
+//!
+//! Responsibilities:
+//! - Verify offline mode answers supported commands from the snapshot cache.
+//! - Verify unsupported commands and missing snapshots fail without contacting a server.
+//!
+//! Does NOT:
+//! - Test how snapshots are saved (see `splunk-config` snapshot tests and the TUI runtime).
+//!
+//! Invariants:
+//! - No server is configured; any network access would fail the test.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+fn write_snapshots(dir: &TempDir) -> std::path::PathBuf {
+    let config_path = dir.path().join("config.json");
+    let snapshots = serde_json::json!({
+        "version": 1,
+        "profiles": {
+            "prod": {
+                "indexes": {
+                    "saved_at": 1_700_000_000,
+                    "data": [
+                        {"name": "main", "totalEventCount": 1200, "currentDBSizeMB": 5},
+                        {"name": "security", "totalEventCount": 80, "currentDBSizeMB": 1}
+                    ]
+                }
+            }
+        }
+    });
+    std::fs::write(dir.path().join("snapshots.json"), snapshots.to_string()).unwrap();
+    config_path
+}
+
+#[test]
+fn test_offline_indexes_list_reads_snapshot() {
+    let dir = TempDir::new().unwrap();
+    let config_path = write_snapshots(&dir);
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args([
+            "--offline",
+            "--profile",
+            "prod",
+            "indexes",
+            "list",
+            "-o",
+            "json",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"security\""))
+        .stderr(predicate::str::contains("OFFLINE — data from "));
+}
+
+#[test]
+fn test_offline_without_snapshot_fails() {
+    let dir = TempDir::new().unwrap();
+    let config_path = write_snapshots(&dir);
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args(["--offline", "--profile", "prod", "health"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "No offline snapshot of health for profile 'prod'",
+        ));
+}
+
+#[test]
+fn test_offline_rejects_unsupported_commands() {
+    let dir = TempDir::new().unwrap();
+    let config_path = write_snapshots(&dir);
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args(["--offline", "users", "list"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--offline only supports"));
+}
@@ -0,0 +1,79 @@
+//! Integration tests for the global `--fields` and `--query` output filters.
+//!
+//! Responsibilities:
+//! - Verify fields are selected in order and rendered in the requested format.
+//! - Verify JSONPath queries filter rows before field selection.
+//! - Verify invalid queries are rejected before connecting.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mock_indexes(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/services/data/indexes"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [
+                {
+                    "name": "main",
+                    "content": { "currentDBSizeMB": 120, "totalEventCount": 5000 }
+                },
+                {
+                    "name": "web",
+                    "content": { "currentDBSizeMB": 4, "totalEventCount": 0 }
+                }
+            ]
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_fields_select_columns_in_table_output() {
+    let mock_server = MockServer::start().await;
+    mock_indexes(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["indexes", "list", "--fields", "name,currentDBSizeMB"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "name\tcurrentDBSizeMB\nmain\t120\nweb\t4\n",
+        ));
+}
+
+#[tokio::test]
+async fn test_query_filters_rows_before_fields() {
+    let mock_server = MockServer::start().await;
+    mock_indexes(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args([
+        "indexes",
+        "list",
+        "--query",
+        "$[?@.totalEventCount > 0]",
+        "--fields",
+        "name",
+        "-o",
+        "csv",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("name\nmain\n").and(predicate::str::contains("web").not()));
+}
+
+#[test]
+fn test_query_rejects_invalid_jsonpath() {
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", "https://localhost:8089");
+    cmd.args(["indexes", "list", "--query", "$[?@.size >"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid JSONPath"));
+}
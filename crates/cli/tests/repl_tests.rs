@@ -0,0 +1,91 @@
+//! Integration tests for `splunk-cli repl`.
+//!
+//! Responsibilities:
+//! - Verify commands piped on stdin run against one session.
+//! - Verify `\set` settings apply to later commands.
+//! - Verify bad lines are reported without ending the session.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mock_indexes(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/services/data/indexes"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "web",
+                "content": { "currentDBSizeMB": 1, "totalEventCount": 10 }
+            }]
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+fn repl_cmd(mock_server: &MockServer, home: &TempDir) -> assert_cmd::Command {
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri())
+        .env("HOME", home.path())
+        .env("XDG_CONFIG_HOME", home.path().join("config"));
+    cmd.arg("repl");
+    cmd
+}
+
+#[tokio::test]
+async fn test_repl_applies_session_output_format() {
+    let mock_server = MockServer::start().await;
+    mock_indexes(&mock_server).await;
+    let home = TempDir::new().unwrap();
+
+    repl_cmd(&mock_server, &home)
+        .write_stdin("\\set output json\nindexes list\nexit\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"name\": \"web\""));
+}
+
+#[tokio::test]
+async fn test_repl_logs_in_once_for_many_commands() {
+    let mock_server = MockServer::start().await;
+    mock_indexes(&mock_server).await;
+    Mock::given(method("POST"))
+        .and(path("/services/auth/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "sessionKey": "repl-session"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let home = TempDir::new().unwrap();
+
+    let mut cmd = repl_cmd(&mock_server, &home);
+    cmd.env_remove("SPLUNK_API_TOKEN")
+        .env("SPLUNK_USERNAME", "admin")
+        .env("SPLUNK_PASSWORD", "changeme");
+    cmd.write_stdin("indexes list\nindexes list --no-cache\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("web"));
+}
+
+#[tokio::test]
+async fn test_repl_reports_bad_lines_and_keeps_going() {
+    let mock_server = MockServer::start().await;
+    mock_indexes(&mock_server).await;
+    let home = TempDir::new().unwrap();
+
+    repl_cmd(&mock_server, &home)
+        .write_stdin("frobnicate\n\\set output html\nrepl\nindexes list -o csv\n")
+        .assert()
+        .success()
+        .stderr(
+            predicate::str::contains("unrecognized subcommand 'frobnicate'")
+                .and(predicate::str::contains("Invalid output format"))
+                .and(predicate::str::contains("cannot be run inside the REPL")),
+        )
+        .stdout(predicate::str::contains("web"));
+}
@@ -0,0 +1,127 @@
+//! Integration tests for `splunk-cli rest`.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use wiremock::matchers::{body_string_contains, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn test_rest_help() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["rest", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--data"))
+        .stdout(predicate::str::contains("KEY=VALUE"));
+}
+
+#[test]
+fn test_rest_rejects_unsupported_method() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["rest", "DELETE", "/services/server/info"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unsupported method 'DELETE'"));
+}
+
+#[tokio::test]
+async fn test_rest_get_prints_pretty_json() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/data/indexes"))
+        .and(query_param("count", "1"))
+        .and(query_param("output_mode", "json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "name": "main" }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["rest", "get", "/services/data/indexes?count=1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"name\": \"main\""));
+}
+
+#[tokio::test]
+async fn test_rest_get_pretty_prints_xml() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .and(query_param("output_mode", "xml"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string("<feed><title>server-info</title></feed>"),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["rest", "GET", "/services/server/info?output_mode=xml"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "<feed>\n  <title>server-info</title>\n</feed>",
+        ));
+}
+
+#[tokio::test]
+async fn test_rest_post_sends_form_data() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/saved/searches"))
+        .and(body_string_contains("name=errors"))
+        .and(body_string_contains("search=index%3Dmain+error"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "entry": [{ "name": "errors" }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args([
+        "rest",
+        "POST",
+        "/services/saved/searches",
+        "-d",
+        "name=errors",
+        "-d",
+        "search=index=main error",
+        "-o",
+        "ndjson",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "{\"entry\":[{\"name\":\"errors\"}]}",
+    ));
+}
+
+#[test]
+fn test_rest_get_rejects_form_data() {
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", "https://localhost:8089");
+
+    cmd.args(["rest", "GET", "/services/server/info", "-d", "a=b"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--data is only sent with POST requests",
+        ));
+}
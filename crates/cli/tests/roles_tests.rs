@@ -0,0 +1,136 @@
+//! Integration tests for `splunk-cli roles usage` and `roles matrix`.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn test_roles_usage_help() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["roles", "usage", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--role").and(predicate::str::contains("--at-limit")));
+}
+
+#[tokio::test]
+async fn test_roles_usage_at_limit_json() {
+    let mock_server = MockServer::start().await;
+
+    let roles = serde_json::json!({
+        "entry": [
+            {
+                "name": "admin",
+                "content": { "srchJobsQuota": "1", "rtSrchJobsQuota": "0", "srchDiskQuota": "100" }
+            },
+            {
+                "name": "user",
+                "content": { "srchJobsQuota": "3", "rtSrchJobsQuota": "6", "srchDiskQuota": "100" }
+            }
+        ]
+    });
+    let users = include_str!("../../client/fixtures/users/list_users.json");
+    let jobs = include_str!("../../client/fixtures/jobs/list_jobs.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/authorization/roles"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(roles))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/authentication/users"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(users))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(jobs))
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    // admin's running real-time job does not hit the unlimited (0) RT quota,
+    // and no user is near the historical or disk quotas.
+    cmd.args(["roles", "usage", "-o", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"role\": \"admin\""))
+        .stdout(predicate::str::contains("\"running_rt_jobs\": 1"))
+        .stdout(predicate::str::contains("\"users_at_limit\": 0"));
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["roles", "usage", "--at-limit", "-o", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"role\"").not());
+}
+
+async fn mount_inheriting_roles(mock_server: &MockServer) {
+    let roles = serde_json::json!({
+        "entry": [
+            {
+                "name": "user",
+                "content": { "capabilities": ["search", "rest_properties_get"] }
+            },
+            {
+                "name": "power",
+                "content": { "capabilities": ["schedule_search"], "importedRoles": ["user"] }
+            },
+            {
+                "name": "sec_reviewer",
+                "content": { "capabilities": ["edit_search_server"], "importedRoles": ["power"] }
+            }
+        ]
+    });
+
+    Mock::given(method("GET"))
+        .and(path("/services/authorization/roles"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(roles))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_roles_matrix_csv_includes_inherited_capabilities() {
+    let mock_server = MockServer::start().await;
+    mount_inheriting_roles(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["roles", "matrix", "--capability", "search", "-o", "csv"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "capability,power,sec_reviewer,user",
+        ))
+        .stdout(predicate::str::contains("search,via user,via user,direct"))
+        .stdout(predicate::str::contains(
+            "schedule_search,direct,via power,",
+        ))
+        .stdout(predicate::str::contains("rest_properties_get").not());
+}
+
+#[tokio::test]
+async fn test_roles_matrix_unknown_role_is_not_found() {
+    let mock_server = MockServer::start().await;
+    mount_inheriting_roles(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["roles", "matrix", "--role", "power,ghost"])
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains("role 'ghost'"));
+}
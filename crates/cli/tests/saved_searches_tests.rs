@@ -1,8 +1,11 @@
 //! Integration tests for `splunk-cli saved-searches` command.
 //!
 //! Tests cover:
-//! - Help text verification for all subcommands (list, run, info, edit, create, delete, enable, disable)
+//! - Help text verification for all subcommands (list, run, info, edit, create, delete, enable, disable, reassign)
 //! - Output format parsing validation (json, table, csv, xml, ndjson, yaml, markdown)
+//! - Ownership reassignment dry runs against a mock server
+//! - Bulk enable/disable, and bulk reassign through the reassign workflow, against a mock server
+//! - Permission (ACL) show/set against a mock server
 //!
 //! Does NOT:
 //! - Test live Splunk server interactions (see `test-live` in Makefile).
@@ -14,6 +17,8 @@ mod common;
 
 use common::splunk_cmd;
 use predicates::prelude::*;
+use wiremock::matchers::{body_string_contains, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[test]
 fn test_saved_searches_help() {
@@ -29,7 +34,10 @@ fn test_saved_searches_help() {
         .stdout(predicate::str::contains("Create a new saved search"))
         .stdout(predicate::str::contains("Delete a saved search"))
         .stdout(predicate::str::contains("Enable a saved search"))
-        .stdout(predicate::str::contains("Disable a saved search"));
+        .stdout(predicate::str::contains("Disable a saved search"))
+        .stdout(predicate::str::contains(
+            "Reassign ownership of saved searches",
+        ));
 }
 
 #[test]
@@ -75,7 +83,26 @@ fn test_saved_searches_edit_help() {
         .stdout(predicate::str::contains("<NAME>"))
         .stdout(predicate::str::contains("--search"))
         .stdout(predicate::str::contains("--description"))
-        .stdout(predicate::str::contains("--disabled"));
+        .stdout(predicate::str::contains("--disabled"))
+        .stdout(predicate::str::contains("--dispatch-earliest"))
+        .stdout(predicate::str::contains("--dispatch-latest"));
+}
+
+#[test]
+fn test_saved_searches_edit_rejects_invalid_dispatch_time() {
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", "https://localhost:9999");
+
+    cmd.args([
+        "saved-searches",
+        "edit",
+        "nightly",
+        "--dispatch-earliest",
+        "-1d@dd",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("unknown time unit 'dd'"));
 }
 
 #[test]
@@ -133,3 +160,274 @@ fn test_saved_searches_disable_help() {
         .success()
         .stdout(predicate::str::contains("<NAME>"));
 }
+
+#[test]
+fn test_saved_searches_reassign_help() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["saved-searches", "reassign", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--from-owner"))
+        .stdout(predicate::str::contains("--to-owner"))
+        .stdout(predicate::str::contains("--app"))
+        .stdout(predicate::str::contains("--sharing"))
+        .stdout(predicate::str::contains("--dry-run"));
+}
+
+#[test]
+fn test_saved_searches_reassign_rejects_same_owner() {
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", "https://localhost:9999");
+
+    cmd.args([
+        "saved-searches",
+        "reassign",
+        "--from-owner",
+        "alice",
+        "--to-owner",
+        "alice",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("must differ"));
+}
+
+#[tokio::test]
+async fn test_saved_searches_reassign_dry_run_reports_schedule_impact() {
+    let mock_server = MockServer::start().await;
+
+    let fixture_data =
+        include_str!("../../client/fixtures/search/list_saved_search_ownership.json");
+
+    Mock::given(method("GET"))
+        .and(path("/servicesNS/-/-/saved/searches"))
+        .and(query_param("search", "eai:acl.owner=departed_user"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(fixture_data))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args([
+        "saved-searches",
+        "reassign",
+        "--from-owner",
+        "departed_user",
+        "--to-owner",
+        "svc_account",
+        "--dry-run",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("Nightly License Report"))
+    .stdout(predicate::str::contains("planned"))
+    .stdout(predicate::str::contains("Active scheduled:     1"))
+    .stdout(predicate::str::contains("Someone Elses Search").not());
+}
+
+/// Serve the saved searches owned by `departed_user` (plus one owned by someone else).
+async fn mount_departed_user_searches(mock_server: &MockServer) {
+    let fixture_data =
+        include_str!("../../client/fixtures/search/list_saved_search_ownership.json");
+
+    Mock::given(method("GET"))
+        .and(path("/servicesNS/-/-/saved/searches"))
+        .and(query_param("search", "eai:acl.owner=departed_user"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(fixture_data))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_saved_searches_bulk_disable_skips_already_disabled() {
+    let mock_server = MockServer::start().await;
+    mount_departed_user_searches(&mock_server).await;
+
+    for name in ["Nightly%20License%20Report", "Ad-hoc%20Errors"] {
+        Mock::given(method("POST"))
+            .and(path(format!(
+                "/servicesNS/departed_user/search/saved/searches/{name}/disable"
+            )))
+            .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+    Mock::given(method("POST"))
+        .and(path(
+            "/servicesNS/departed_user/security/saved/searches/Old%20Firewall%20Alert/disable",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args([
+        "-o",
+        "csv",
+        "saved-searches",
+        "bulk",
+        "--owner",
+        "departed_user",
+        "--disable",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "name,app,owner,disabled,status,error",
+    ))
+    .stdout(predicate::str::contains(
+        "Old Firewall Alert,security,departed_user,true,unchanged,",
+    ))
+    .stdout(predicate::str::contains(
+        "Nightly License Report,search,departed_user,false,applied,",
+    ))
+    .stdout(predicate::str::contains("Someone Elses Search").not())
+    .stderr(predicate::str::contains(
+        "Applied disable to 2 saved search(es) (1 unchanged, 0 failed)",
+    ));
+}
+
+#[tokio::test]
+async fn test_saved_searches_bulk_reassign_by_name_reports_missing() {
+    let mock_server = MockServer::start().await;
+    mount_departed_user_searches(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/servicesNS/departed_user/search/saved/searches/Ad-hoc%20Errors/acl",
+        ))
+        .and(body_string_contains("owner=svc_account"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args([
+        "-o",
+        "csv",
+        "saved-searches",
+        "bulk",
+        "Ad-hoc Errors",
+        "Ghost Search",
+        "--owner",
+        "departed_user",
+        "--reassign-to",
+        "svc_account",
+    ])
+    .assert()
+    .failure()
+    .stdout(predicate::str::contains(
+        "name,app,previous_sharing,sharing,is_scheduled,cron_schedule,disabled,status,error",
+    ))
+    .stdout(predicate::str::contains(
+        "Ad-hoc Errors,search,user,user,false,,false,reassigned,",
+    ))
+    .stdout(predicate::str::contains(
+        "Ghost Search,,,,false,,false,failed,Saved search not found",
+    ))
+    .stderr(predicate::str::contains(
+        "Failed to reassign 1 of 2 saved search(es)",
+    ));
+}
+
+#[test]
+fn test_saved_searches_bulk_requires_an_action() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["saved-searches", "bulk", "--owner", "departed_user"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("--enable"));
+}
+
+#[tokio::test]
+async fn test_global_app_flag_scopes_saved_searches_list() {
+    let mock_server = MockServer::start().await;
+
+    let fixture_data = include_str!("../../client/fixtures/search/list_saved_searches.json");
+
+    Mock::given(method("GET"))
+        .and(path("/servicesNS/-/my_app/saved/searches"))
+        .and(query_param("search", "eai:acl.app=\"my_app\""))
+        .respond_with(ResponseTemplate::new(200).set_body_string(fixture_data))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["--app", "my_app", "saved-searches", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Errors in the last 24 hours"));
+}
+
+#[tokio::test]
+async fn test_saved_searches_acl_set_updates_sharing_and_read_roles() {
+    let mock_server = MockServer::start().await;
+
+    let fixture_data = include_str!("../../client/fixtures/acl/get_saved_search_acl.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/saved/searches/Errors"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(fixture_data))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(
+            "/servicesNS/admin/search/saved/searches/Errors%20in%20the%20last%2024%20hours/acl",
+        ))
+        .and(body_string_contains("sharing=app"))
+        .and(body_string_contains("perms.read=role1%2Crole2"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(fixture_data))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args([
+        "saved-searches",
+        "acl",
+        "set",
+        "Errors",
+        "--sharing",
+        "app",
+        "--read",
+        "role1,role2",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("Read:             role1, role2"))
+    .stdout(predicate::str::contains("Write:            admin, power"));
+}
+
+#[test]
+fn test_saved_searches_acl_set_requires_a_change() {
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", "https://localhost:8089");
+
+    cmd.args(["saved-searches", "acl", "set", "Errors"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "At least one change must be provided",
+        ));
+}
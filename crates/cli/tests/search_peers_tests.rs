@@ -6,13 +6,14 @@
 //! - `--count` and `--offset` pagination flags
 //! - Output format variations (json, csv, xml)
 //! - `--output-file` flag
+//! - `add`, `remove`, `quarantine`, and `unquarantine` subcommands
 //! - Error handling
 
 mod common;
 
 use common::{connection_error_predicate, splunk_cmd};
 use predicates::prelude::*;
-use wiremock::matchers::{header, method, path, query_param};
+use wiremock::matchers::{body_string_contains, header, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 /// Test that `splunk-cli search-peers --help` shows correct flags.
@@ -28,7 +29,10 @@ fn test_search_peers_help() {
                 .and(predicate::str::contains("--count"))
                 .and(predicate::str::contains("--offset"))
                 .and(predicate::str::contains("--output"))
-                .and(predicate::str::contains("--output-file")),
+                .and(predicate::str::contains("--output-file"))
+                .and(predicate::str::contains("add"))
+                .and(predicate::str::contains("remove"))
+                .and(predicate::str::contains("quarantine")),
         );
 }
 
@@ -320,3 +324,86 @@ async fn test_search_peers_server_error() {
 
     cmd.arg("search-peers").assert().failure();
 }
+
+/// Test that `splunk-cli search-peers add` posts the peer and remote credentials.
+#[tokio::test]
+async fn test_search_peers_add() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/search/distributed/peers"))
+        .and(body_string_contains("remoteUsername=admin"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({"entry": []})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args([
+        "search-peers",
+        "add",
+        "https://idx3:8089",
+        "--remote-username",
+        "admin",
+        "--remote-password",
+        "changeme",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Search peer 'https://idx3:8089' added successfully.",
+    ));
+}
+
+/// Test that `splunk-cli search-peers remove --force` deletes the peer by host:port.
+#[tokio::test]
+async fn test_search_peers_remove_force() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/services/search/distributed/peers/idx3:8089"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"entry": []})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["search-peers", "remove", "https://idx3:8089", "--force"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("removed successfully"));
+}
+
+/// Test that `splunk-cli search-peers quarantine` and `unquarantine` hit the peer actions.
+#[tokio::test]
+async fn test_search_peers_quarantine_and_unquarantine() {
+    let mock_server = MockServer::start().await;
+
+    for action in ["quarantine", "unquarantine"] {
+        Mock::given(method("POST"))
+            .and(path(format!(
+                "/services/search/distributed/peers/idx3:8089/{}",
+                action
+            )))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"entry": []})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    for (action, message) in [
+        ("quarantine", "Search peer 'idx3:8089' quarantined."),
+        ("unquarantine", "Search peer 'idx3:8089' unquarantined."),
+    ] {
+        let mut cmd = splunk_cmd();
+        cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+        cmd.args(["search-peers", action, "idx3:8089"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(message));
+    }
+}
@@ -4,6 +4,8 @@
 //! - Validate search query execution and argument handling.
 //! - Ensure progress reporting and `--quiet` mode work correctly.
 //! - Verify connection attempt behavior for various output formats.
+//! - Verify `--finalize-after` finalizes the job and reports partial results.
+//! - Verify `--diff-earliest` compares two time ranges and emits a structured diff.
 //!
 //! Does NOT:
 //! - Perform live searches against a real Splunk server (see `test-live`).
@@ -15,7 +17,7 @@ mod common;
 
 use common::{connection_error_predicate, splunk_cmd};
 use predicates::prelude::*;
-use wiremock::matchers::{header, method, path, query_param};
+use wiremock::matchers::{body_string_contains, header, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 const TEST_BASE_URL: &str = "https://localhost:8089";
@@ -85,6 +87,17 @@ fn test_search_with_earliest_and_latest_flags_attempts_connection() {
     .stderr(connection_error_predicate());
 }
 
+#[test]
+fn test_search_rejects_invalid_earliest_before_connecting() {
+    let mut cmd = splunk_cli_cmd_with_base_url();
+    cmd.args(["search", TEST_QUERY, "--earliest", "yesterday"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid time modifier 'yesterday'",
+        ));
+}
+
 #[test]
 fn test_search_with_count_flag_attempts_connection() {
     let mut cmd = splunk_cli_cmd_with_base_url();
@@ -539,3 +552,199 @@ async fn test_search_count_cli_overrides_env() {
         .assert()
         .success();
 }
+
+fn job_status_body(result_count: usize, is_done: bool) -> serde_json::Value {
+    serde_json::json!({
+        "entry": [
+            { "content": {
+                "sid": "test-sid",
+                "isDone": is_done,
+                "isFinalized": is_done,
+                "doneProgress": if is_done { 1.0 } else { 0.3 },
+                "eventCount": result_count,
+                "resultCount": result_count
+            } }
+        ]
+    })
+}
+
+/// Test that `--finalize-after` finalizes the job at the threshold and reports partial results.
+#[tokio::test]
+async fn test_search_finalize_after_finalizes_job() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [
+                { "content": { "sid": "test-sid" } }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    // Running with enough results to cross the threshold, then done once finalized
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/test-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(job_status_body(120, false)))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/test-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(job_status_body(125, true)))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs/test-sid/control"))
+        .and(body_string_contains("action=finalize"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/test-sid/results"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"foo": "bar"}],
+            "preview": false,
+            "total": 125
+        })))
+        .mount(&server)
+        .await;
+
+    let mut cmd = splunk_cli_cmd();
+    cmd.env("SPLUNK_BASE_URL", server.uri())
+        .args([
+            "--output",
+            "json",
+            "search",
+            "execute",
+            TEST_QUERY,
+            "--finalize-after",
+            "100",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"foo\": \"bar\""))
+        .stderr(predicate::str::contains(
+            "Search finalized early after 125 result(s)",
+        ));
+}
+
+#[test]
+fn test_search_finalize_after_rejects_zero() {
+    let mut cmd = splunk_cli_cmd_with_base_url();
+    cmd.args(["search", "execute", TEST_QUERY, "--finalize-after", "0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("threshold must be at least 1"));
+}
+
+/// Mount a finished job whose creation request contains `time_param` and that returns `results`.
+async fn mount_finished_job(
+    server: &MockServer,
+    sid: &str,
+    time_param: &str,
+    results: serde_json::Value,
+) {
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains(time_param))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": sid } }]
+        })))
+        .mount(server)
+        .await;
+    let mut status = job_status_body(2, true);
+    status["entry"][0]["content"]["sid"] = serde_json::json!(sid);
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}", sid)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(status))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}/results", sid)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": results,
+            "preview": false,
+            "total": 2
+        })))
+        .mount(server)
+        .await;
+}
+
+/// Test that `--diff-earliest` runs a baseline search and prints the row-level diff.
+#[tokio::test]
+async fn test_search_diff_earliest_emits_structured_diff() {
+    let server = MockServer::start().await;
+    mount_finished_job(
+        &server,
+        "baseline-sid",
+        "earliest_time=-2d",
+        serde_json::json!([
+            {"host": "web-01", "count": "10"},
+            {"host": "web-02", "count": "4"}
+        ]),
+    )
+    .await;
+    mount_finished_job(
+        &server,
+        "current-sid",
+        "earliest_time=-1d",
+        serde_json::json!([
+            {"host": "web-01", "count": "12"},
+            {"host": "web-03", "count": "1"}
+        ]),
+    )
+    .await;
+
+    // Logs go to stdout by default; keep it to the JSON document
+    let output = splunk_cli_cmd()
+        .env("SPLUNK_BASE_URL", server.uri())
+        .env("RUST_LOG", "off")
+        .args([
+            "--output",
+            "json",
+            "search",
+            "index=main | stats count by host",
+            "--wait",
+            "--diff-earliest",
+            "-2d",
+            "--earliest",
+            "-1d",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let diff: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(diff["key_fields"], serde_json::json!(["host"]));
+    assert_eq!(
+        diff["summary"],
+        serde_json::json!({"added": 1, "removed": 1, "changed": 1, "unchanged": 0})
+    );
+    assert_eq!(diff["changes"][0]["change"], "changed");
+    assert_eq!(diff["changes"][0]["fields"], serde_json::json!(["count"]));
+    assert_eq!(diff["changes"][1]["row"]["host"], "web-03");
+    assert_eq!(diff["changes"][2]["change"], "removed");
+}
+
+#[test]
+fn test_search_diff_rejects_realtime() {
+    let mut cmd = splunk_cli_cmd_with_base_url();
+    cmd.args([
+        "search",
+        "execute",
+        TEST_QUERY,
+        "--realtime",
+        "--diff-earliest",
+        "-2d",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("cannot be used with"));
+}
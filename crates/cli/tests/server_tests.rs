@@ -0,0 +1,108 @@
+//! Integration tests for `splunk-cli server`.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn server_info(startup_time: &str, restart_required: bool) -> serde_json::Value {
+    serde_json::json!({
+        "entry": [{
+            "name": "server-info",
+            "content": {
+                "serverName": "splunk-local",
+                "version": "9.1.2",
+                "build": "123456",
+                "restart_required": restart_required,
+                "startup_time": startup_time
+            }
+        }]
+    })
+}
+
+#[test]
+fn test_server_restart_help() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["server", "restart", "--help"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("--wait")
+                .and(predicate::str::contains("--wait-timeout"))
+                .and(predicate::str::contains("--force")),
+        );
+}
+
+#[tokio::test]
+async fn test_server_restart_force_wait() {
+    let mock_server = MockServer::start().await;
+
+    // The pre-restart lookup sees the old process; polls after the restart see a new one.
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(server_info("1705773600", true)))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(server_info("1705777200", false)))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/services/server/control/restart"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args([
+        "server",
+        "restart",
+        "--force",
+        "--wait",
+        "--wait-timeout",
+        "30s",
+    ])
+    .assert()
+    .success()
+    .stderr(predicate::str::contains("reports a restart is required"))
+    .stderr(predicate::str::contains(
+        "Restart requested for 'splunk-local'",
+    ))
+    .stderr(predicate::str::contains(
+        "Server 'splunk-local' is back (version 9.1.2)",
+    ));
+}
+
+#[tokio::test]
+async fn test_server_restart_declined() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(server_info("1705773600", false)))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/services/server/control/restart"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["server", "restart"])
+        .write_stdin("n\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Restart cancelled."));
+}
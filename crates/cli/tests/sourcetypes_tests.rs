@@ -0,0 +1,103 @@
+//! Integration tests for `splunk-cli sourcetypes`.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_search(mock_server: &MockServer, sid: &str, query_marker: &str, fixture: &str) {
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains(query_marker))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": sid } }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}", sid)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": sid, "isDone": true, "doneProgress": 1.0 } }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}/results", sid)))
+        .respond_with(ResponseTemplate::new(200).set_body_string(fixture))
+        .mount(mock_server)
+        .await;
+}
+
+async fn mount_sourcetype_searches(mock_server: &MockServer) {
+    mount_search(
+        mock_server,
+        "metadata-sid",
+        "type%3Dsourcetypes",
+        include_str!("../../client/fixtures/metadata/sourcetypes.json"),
+    )
+    .await;
+    mount_search(
+        mock_server,
+        "indexes-sid",
+        "tstats+values%28index%29",
+        include_str!("../../client/fixtures/metadata/sourcetype_indexes.json"),
+    )
+    .await;
+}
+
+#[test]
+fn test_sourcetypes_help() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["sourcetypes", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--index"));
+}
+
+#[tokio::test]
+async fn test_sourcetypes_csv() {
+    let mock_server = MockServer::start().await;
+    mount_sourcetype_searches(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["sourcetypes", "-o", "csv"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "sourcetype,total_count,first_time,last_time,recent_time,indexes",
+        ))
+        .stdout(predicate::str::contains(
+            "access_combined,1250000,1690000000,1700086400,1700086405,main;web",
+        ))
+        .stdout(predicate::str::contains(
+            "syslog,48210,1699990000,1700086300,1700086302,network",
+        ));
+}
+
+#[tokio::test]
+async fn test_sourcetypes_filters_by_index() {
+    let mock_server = MockServer::start().await;
+    mount_sourcetype_searches(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+
+    cmd.args(["sourcetypes", "--index", "web"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("SOURCETYPE"));
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let filtered = requests
+        .iter()
+        .filter(|r| String::from_utf8_lossy(&r.body).contains("index%3D%22web%22"))
+        .count();
+    assert_eq!(filtered, 2);
+}
@@ -0,0 +1,147 @@
+//! Integration tests for `splunk-cli templates`.
+//!
+//! Responsibilities:
+//! - Verify `templates save` stores a query with `{{name}}` placeholders in the personal library.
+//! - Verify `templates run` renders the template and dispatches the rendered search.
+//!
+//! Does NOT:
+//! - Test template file import/export (see `config/templates_tests.rs`).
+//!
+//! Invariants:
+//! - All tests use the hermetic `splunk_cmd()` helper and a temporary config file.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn save_user_activity(config_path: &std::path::Path) {
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", config_path)
+        .args([
+            "templates",
+            "save",
+            "User activity",
+            "--spl",
+            "index={{index}} user={{user}} | stats count by action",
+            "--default",
+            "index=auth",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Saved search template 'User activity' with 2 parameter(s)",
+        ));
+}
+
+#[test]
+fn test_templates_save_and_render() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("config.json");
+    save_user_activity(&config_path);
+
+    // Saving again without --replace keeps the existing template
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args(["templates", "save", "User activity", "--spl", "index=main"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Search template 'User activity' already exists (use --replace to overwrite)",
+        ));
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .args([
+            "config",
+            "templates",
+            "render",
+            "User activity",
+            "--param",
+            "user=alice",
+        ])
+        .assert()
+        .success()
+        .stdout("index=auth user=alice | stats count by action\n");
+}
+
+#[test]
+fn test_templates_run_requires_parameters() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("config.json");
+    save_user_activity(&config_path);
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .env("SPLUNK_BASE_URL", "https://localhost:8089")
+        .args(["templates", "run", "User activity"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Parameter 'user' is required"));
+}
+
+#[tokio::test]
+async fn test_templates_run_dispatches_rendered_search() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("config.json");
+    save_user_activity(&config_path);
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains(
+            "search=search+index%3Dauth+user%3Dalice+%7C+stats+count+by+action",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [
+                { "content": { "sid": "template-sid" } }
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/template-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [
+                { "content": {
+                    "sid": "template-sid",
+                    "isDone": true,
+                    "doneProgress": 1.0,
+                    "eventCount": 1,
+                    "resultCount": 1
+                } }
+            ]
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/template-sid/results"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"action": "login", "count": "3"}],
+            "preview": false,
+            "total": 1
+        })))
+        .mount(&server)
+        .await;
+
+    splunk_cmd()
+        .env("SPLUNK_CONFIG_PATH", &config_path)
+        .env("SPLUNK_BASE_URL", server.uri())
+        .args([
+            "--output",
+            "json",
+            "templates",
+            "run",
+            "User activity",
+            "--param",
+            "user=alice",
+            "--wait",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"action\": \"login\""));
+}
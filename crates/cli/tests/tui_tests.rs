@@ -0,0 +1,117 @@
+//! Integration tests for `splunk-cli tui` launcher command.
+//!
+//! Tests cover:
+//! - Help output lists the forwarded TUI flags
+//! - Resolved connection settings reach the TUI via environment variables
+//! - Profile and TUI flags are forwarded as arguments
+//! - The TUI's failure exit status is propagated
+//! - A missing TUI binary produces an actionable error
+//!
+//! The TUI binary is replaced by a shell script via `SPLUNK_TUI_BIN`.
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+
+/// Write an executable script standing in for `splunk-tui`.
+#[cfg(unix)]
+fn fake_tui(dir: &tempfile::TempDir, body: &str) -> std::path::PathBuf {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = dir.path().join("fake-splunk-tui");
+    std::fs::write(&path, format!("#!/bin/sh\n{}\n", body)).unwrap();
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    path
+}
+
+/// Test that `splunk-cli tui --help` shows the forwarded flags.
+#[test]
+fn test_tui_help() {
+    let mut cmd = splunk_cmd();
+
+    cmd.args(["tui", "--help"]).assert().success().stdout(
+        predicate::str::contains("--no-mouse")
+            .and(predicate::str::contains("--skip-tutorial"))
+            .and(predicate::str::contains("--fresh"))
+            .and(predicate::str::contains("--log-dir")),
+    );
+}
+
+/// Test that the resolved CLI configuration is handed to the TUI.
+#[cfg(unix)]
+#[test]
+fn test_tui_inherits_resolved_configuration() {
+    let dir = tempfile::tempdir().unwrap();
+    let script = fake_tui(
+        &dir,
+        r#"echo "args=$*"
+echo "url=$SPLUNK_BASE_URL"
+echo "token=$SPLUNK_API_TOKEN"
+echo "user=${SPLUNK_USERNAME:-unset}"
+echo "timeout=$SPLUNK_TIMEOUT"
+echo "skip_verify=$SPLUNK_SKIP_VERIFY"
+echo "rate=$SPLUNK_RATE_LIMIT""#,
+    );
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_TUI_BIN", &script)
+        .env("SPLUNK_USERNAME", "ignored-user")
+        .args([
+            "--base-url",
+            "https://splunk.example.com:8089",
+            "--timeout",
+            "45",
+            "--skip-verify",
+            "--rate-limit",
+            "5:10",
+            "tui",
+            "--no-mouse",
+            "--fresh",
+        ])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("args=--no-mouse --fresh")
+                .and(predicate::str::contains(
+                    "url=https://splunk.example.com:8089",
+                ))
+                .and(predicate::str::contains("token=test-token"))
+                .and(predicate::str::contains("user=unset"))
+                .and(predicate::str::contains("timeout=45"))
+                .and(predicate::str::contains("skip_verify=true"))
+                .and(predicate::str::contains("rate=5:10")),
+        );
+}
+
+/// Test that a non-zero TUI exit status fails the CLI command.
+#[cfg(unix)]
+#[test]
+fn test_tui_failure_exit_status_is_reported() {
+    let dir = tempfile::tempdir().unwrap();
+    let script = fake_tui(&dir, "exit 3");
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_TUI_BIN", &script)
+        .env("SPLUNK_BASE_URL", "https://localhost:8089")
+        .arg("tui")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("splunk-tui exited with"));
+}
+
+/// Test that a missing TUI binary explains how to point at one.
+#[test]
+fn test_tui_missing_binary() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_TUI_BIN", dir.path().join("does-not-exist"))
+        .env("SPLUNK_BASE_URL", "https://localhost:8089")
+        .arg("tui")
+        .assert()
+        .failure()
+        .stderr(
+            predicate::str::contains("not found").and(predicate::str::contains("SPLUNK_TUI_BIN")),
+        );
+}
@@ -5,11 +5,15 @@
 //! - `--count` flag
 //! - Output format variations (json, table, csv, xml, ndjson, yaml, markdown)
 //! - Create, modify, delete subcommands
+//! - Unlock and info (password policy, locked-out users)
+//! - Orphaned knowledge objects (owners missing from the user list)
 
 mod common;
 
 use common::{connection_error_predicate, splunk_cmd};
 use predicates::prelude::*;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 /// Test that `splunk-cli users list` lists users.
 #[test]
@@ -100,3 +104,105 @@ fn test_users_delete_help() {
         .success()
         .stdout(predicate::str::contains("USERNAME").and(predicate::str::contains("--force")));
 }
+
+/// Test that `splunk-cli users unlock <name>` clears the lockout flag.
+#[tokio::test]
+async fn test_users_unlock_posts_locked_out() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/services/authentication/users/user1"))
+        .and(body_string_contains("locked-out=0"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("../../client/fixtures/users/unlock_user.json")),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["users", "unlock", "user1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("User 'user1' unlocked."));
+}
+
+/// Test that `splunk-cli users info` shows the policy and locked-out users.
+#[tokio::test]
+async fn test_users_info_shows_policy_and_locked_out_users() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/services/admin/Splunkd-auth/splunk_auth"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(include_str!(
+            "../../client/fixtures/users/password_policy.json"
+        )))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/authentication/users"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("../../client/fixtures/users/list_users.json")),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["users", "info"]).assert().success().stdout(
+        predicate::str::contains("Minimum length:")
+            .and(predicate::str::contains("Lockout attempts:         5"))
+            .and(predicate::str::contains("Locked-out users:         user1")),
+    );
+}
+
+/// Test that `splunk-cli users orphans` flags objects owned by missing users.
+#[tokio::test]
+async fn test_users_orphans_flags_missing_owners() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/services/authentication/users"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("../../client/fixtures/users/list_users.json")),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/servicesNS/-/-/admin/macros"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(include_str!(
+            "../../client/fixtures/acl/list_macro_acls.json"
+        )))
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["-o", "csv", "users", "orphans", "--kind", "macro"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("kind,name,app,owner,sharing,suggested_owner")
+                .and(predicate::str::contains(
+                    "macro,web_errors,search,departed_user,user,user1",
+                ))
+                .and(predicate::str::contains("slow_searches").not())
+                .and(predicate::str::contains("comment(1)").not()),
+        )
+        .stderr(predicate::str::contains(
+            "Found 1 orphaned object(s) owned by 1 missing user(s) (checked 3 objects against 2 users)",
+        ));
+}
+
+/// Test that `splunk-cli users orphans` rejects unknown object kinds.
+#[test]
+fn test_users_orphans_rejects_unknown_kind() {
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", "https://localhost:8089");
+
+    cmd.args(["users", "orphans", "--kind", "eventtype"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("saved-search"));
+}
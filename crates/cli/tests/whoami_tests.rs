@@ -0,0 +1,55 @@
+//! Integration tests for `splunk-cli whoami` command.
+//!
+//! Tests cover:
+//! - Table output with roles and capabilities
+//! - JSON output
+
+mod common;
+
+use common::splunk_cmd;
+use predicates::prelude::*;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_current_context(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/services/authentication/current-context"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(include_str!(
+            "../../client/fixtures/users/current_context.json"
+        )))
+        .mount(mock_server)
+        .await;
+}
+
+/// Test that `splunk-cli whoami` shows the user, roles and capabilities.
+#[tokio::test]
+async fn test_whoami_table() {
+    let mock_server = MockServer::start().await;
+    mount_current_context(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.arg("whoami").assert().success().stdout(
+        predicate::str::contains("Username:     analyst")
+            .and(predicate::str::contains("Roles:        power, user"))
+            .and(predicate::str::contains("Capabilities (2):"))
+            .and(predicate::str::contains("  list_inputs")),
+    );
+}
+
+/// Test that `splunk-cli whoami -o json` emits the capability list.
+#[tokio::test]
+async fn test_whoami_json() {
+    let mock_server = MockServer::start().await;
+    mount_current_context(&mock_server).await;
+
+    let mut cmd = splunk_cmd();
+    cmd.env("SPLUNK_BASE_URL", mock_server.uri());
+    cmd.args(["whoami", "-o", "json"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("\"username\": \"analyst\"")
+                .and(predicate::str::contains("\"capabilities\": [")),
+        );
+}
@@ -2,7 +2,7 @@
 
 use secrecy::{ExposeSecret, SecretString};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tokio::sync::{Mutex, RwLock};
 
 /// Username placeholder for API token authentication when displaying error messages.
@@ -74,6 +74,12 @@ impl SessionToken {
             .unwrap_or(false)
     }
 
+    /// Time left before the token expires (None if it never expires).
+    fn remaining(&self) -> Option<Duration> {
+        self.expires_at
+            .map(|exp| exp.saturating_duration_since(Instant::now()))
+    }
+
     /// Check if the token will expire soon (within the buffer window).
     ///
     /// This is used to proactively refresh tokens before they expire,
@@ -176,6 +182,36 @@ impl SessionManager {
             .unwrap_or(true)
     }
 
+    /// Time left before the current session token expires.
+    ///
+    /// Returns None for API token auth, when no session token is set, and for
+    /// tokens without an expiry.
+    pub async fn session_remaining(&self) -> Option<Duration> {
+        if self.is_api_token() {
+            return None;
+        }
+        let token_guard = self.session_token.read().await;
+        token_guard.as_ref().and_then(SessionToken::remaining)
+    }
+
+    /// Time until the session should be renewed (remaining lifetime minus the
+    /// expiry buffer).
+    ///
+    /// Returns zero when no session token is set yet, and None for API token
+    /// auth and tokens without an expiry (nothing to renew).
+    pub async fn time_until_renewal(&self) -> Option<Duration> {
+        if self.is_api_token() {
+            return None;
+        }
+        let token_guard = self.session_token.read().await;
+        match token_guard.as_ref() {
+            None => Some(Duration::ZERO),
+            Some(token) => token.remaining().map(|remaining| {
+                remaining.saturating_sub(Duration::from_secs(self.expiry_buffer_seconds))
+            }),
+        }
+    }
+
     /// Clear the current session token (force re-authentication).
     pub async fn clear_session(&self) {
         let mut token_guard = self.session_token.write().await;
@@ -393,6 +429,48 @@ mod tests {
         // Note: Can't easily test actual expiry in unit test without time manipulation
     }
 
+    #[tokio::test]
+    async fn test_session_renewal_timing() {
+        let strategy = AuthStrategy::SessionToken {
+            username: "admin".to_string(),
+            password: SecretString::new("pass".to_string().into()),
+        };
+        let manager = SessionManager::new(strategy, 3600, 60);
+
+        // No session yet: renew immediately, nothing to count down
+        assert_eq!(manager.time_until_renewal().await, Some(Duration::ZERO));
+        assert_eq!(manager.session_remaining().await, None);
+
+        manager
+            .set_session_token("session-key".to_string(), Some(3600))
+            .await;
+        let remaining = manager.session_remaining().await.unwrap();
+        let until_renewal = manager.time_until_renewal().await.unwrap();
+        assert!(remaining > Duration::from_secs(3590));
+        assert!(until_renewal <= Duration::from_secs(3540));
+        assert!(until_renewal > Duration::from_secs(3530));
+
+        // Sessions without expiry never need renewal
+        manager
+            .set_session_token("session-key".to_string(), None)
+            .await;
+        assert_eq!(manager.time_until_renewal().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_api_token_has_no_renewal() {
+        let strategy = AuthStrategy::ApiToken {
+            token: SecretString::new("test-token".to_string().into()),
+        };
+        let manager = SessionManager::new(
+            strategy,
+            DEFAULT_SESSION_TTL_SECS,
+            DEFAULT_EXPIRY_BUFFER_SECS,
+        );
+        assert_eq!(manager.time_until_renewal().await, None);
+        assert_eq!(manager.session_remaining().await, None);
+    }
+
     // ============================================================================
     // Security-focused tests for secret handling
     // ============================================================================
@@ -0,0 +1,98 @@
+//! Validate response fixtures against the current model definitions.
+//!
+//! Responsibilities:
+//! - Report fixtures whose entries no longer parse into their models (exit 1)
+//! - Report fields the models do not capture, failing with `--deny-drift`
+//! - Validate a directory of captured live responses laid out like the fixtures
+//!
+//! Does NOT handle:
+//! - Fetching live responses (capture them with `splunk-cli rest -o json`)
+//! - Deciding which model a fixture maps to (see `testing::fixture_validation`)
+//!
+//! Invariants:
+//! - Unchecked fixtures are listed but never fail the run.
+
+use std::path::PathBuf;
+
+use splunk_client::testing::fixture_validation::{
+    EntryIssues, FixtureOutcome, fixtures_dir, validate_fixtures,
+};
+
+const USAGE: &str = "Usage: validate-fixtures [DIR] [--deny-drift]
+
+Checks entry-shaped JSON fixtures against the current client models.
+
+Arguments:
+  [DIR]          Directory to validate (default: the client crate's fixtures)
+
+Options:
+  --deny-drift   Fail when entries have fields the models do not capture
+  -h, --help     Print help
+";
+
+fn print_entries(label: &str, path: &str, model: Option<&str>, entries: &[EntryIssues]) {
+    println!("{} {} ({})", label, path, model.unwrap_or("?"));
+    for entry in entries {
+        println!("  {}: {}", entry.entry, entry.issues);
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let mut dir = None;
+    let mut deny_drift = false;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--deny-drift" => deny_drift = true,
+            "-h" | "--help" => {
+                print!("{}", USAGE);
+                return Ok(());
+            }
+            other if other.starts_with('-') => {
+                anyhow::bail!("unknown option '{}'\n\n{}", other, USAGE);
+            }
+            other if dir.is_none() => dir = Some(PathBuf::from(other)),
+            other => anyhow::bail!("unexpected argument '{}'\n\n{}", other, USAGE),
+        }
+    }
+    let dir = dir.unwrap_or_else(fixtures_dir);
+
+    let reports = validate_fixtures(&dir)?;
+    let (mut clean, mut drift, mut failed, mut unchecked) = (0, 0, 0, Vec::new());
+    for report in &reports {
+        match &report.outcome {
+            FixtureOutcome::Clean => clean += 1,
+            FixtureOutcome::Drift(entries) => {
+                drift += 1;
+                print_entries("DRIFT", &report.path, report.model, entries);
+            }
+            FixtureOutcome::Failed(entries) => {
+                failed += 1;
+                print_entries("FAIL", &report.path, report.model, entries);
+            }
+            FixtureOutcome::Unreadable(reason) => {
+                failed += 1;
+                println!("FAIL {}: {}", report.path, reason);
+            }
+            FixtureOutcome::Unchecked => unchecked.push(report.path.as_str()),
+        }
+    }
+
+    if !unchecked.is_empty() {
+        println!("Unchecked (no model registered):");
+        for path in &unchecked {
+            println!("  {}", path);
+        }
+    }
+    println!(
+        "{} clean, {} drift, {} failed, {} unchecked",
+        clean,
+        drift,
+        failed,
+        unchecked.len()
+    );
+
+    if failed > 0 || (deny_drift && drift > 0) {
+        std::process::exit(1);
+    }
+    Ok(())
+}
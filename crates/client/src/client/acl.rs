@@ -0,0 +1,233 @@
+//! Knowledge object permission (ACL) API methods for [`SplunkClient`].
+//!
+//! # What this module handles:
+//! - Reading the ACL of saved searches, macros, lookups and dashboards
+//! - Partial ACL updates merged onto the object's current ACL
+//! - Listing the ACLs of every object of a kind across owners
+//!
+//! # What this module does NOT handle:
+//! - Low-level ACL endpoint HTTP calls (in [`crate::endpoints`])
+//! - Bulk ownership changes (see `workflows::saved_search_ownership`)
+
+use crate::client::SplunkClient;
+use crate::endpoints;
+use crate::error::{ClientError, Result};
+use crate::models::{AclUpdateParams, KnowledgeObjectKind, Namespace, ObjectAcl};
+
+/// Sharing levels accepted by the ACL endpoint.
+const SHARING_LEVELS: [&str; 3] = ["user", "app", "global"];
+
+impl SplunkClient {
+    /// Get the permissions of a knowledge object.
+    ///
+    /// The object is looked up in the client's default owner/app namespace, so
+    /// private objects need the owner's namespace. The namespace's sharing level
+    /// only filters listings and is ignored here.
+    pub async fn get_object_acl(&self, kind: KnowledgeObjectKind, name: &str) -> Result<ObjectAcl> {
+        let namespace = self
+            .namespace()
+            .map(|namespace| Namespace {
+                sharing: None,
+                ..namespace
+            })
+            .filter(|namespace| !namespace.is_empty());
+        let namespace = namespace.as_ref();
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("get_object_acl"),
+            |__token| async move {
+                endpoints::get_object_acl(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    kind,
+                    name,
+                    namespace,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// List the permissions of every object of `kind` across owners.
+    ///
+    /// Ignores the client's default namespace so objects of every owner are
+    /// listed; `app` restricts the listing to one app.
+    pub async fn list_object_acls(
+        &self,
+        kind: KnowledgeObjectKind,
+        app: Option<&str>,
+    ) -> Result<Vec<ObjectAcl>> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("list_object_acls"),
+            |__token| async move {
+                endpoints::list_object_acls(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    kind,
+                    app,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Change the permissions of a knowledge object.
+    ///
+    /// Reads the current ACL first and keeps every field `params` leaves unset.
+    /// Role lists cannot be set on privately shared (`user`) objects.
+    ///
+    /// # Returns
+    /// The ACL as written.
+    pub async fn set_object_acl(
+        &self,
+        kind: KnowledgeObjectKind,
+        name: &str,
+        params: &AclUpdateParams,
+    ) -> Result<ObjectAcl> {
+        if params.is_empty() {
+            return Err(ClientError::InvalidRequest(
+                "No permission changes requested".to_string(),
+            ));
+        }
+
+        let current = self.get_object_acl(kind, name).await?;
+        let updated = merge_acl_update(&current, params)?;
+
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("set_object_acl"),
+            |__token| {
+                let current = &current;
+                let updated = &updated;
+                async move {
+                    endpoints::set_object_acl(
+                        &self.http,
+                        &self.base_url,
+                        &__token,
+                        current,
+                        updated,
+                        self.max_retries,
+                        self.metrics.as_ref(),
+                        self.circuit_breaker.as_deref(),
+                    )
+                    .await
+                }
+            },
+        )
+        .await?;
+
+        Ok(updated)
+    }
+}
+
+/// Apply `params` on top of `current`, validating the result.
+fn merge_acl_update(current: &ObjectAcl, params: &AclUpdateParams) -> Result<ObjectAcl> {
+    let sharing = params.sharing.as_deref().unwrap_or(&current.sharing);
+    if !SHARING_LEVELS.contains(&sharing) {
+        return Err(ClientError::ValidationError(format!(
+            "Invalid sharing level '{}' (expected user, app or global)",
+            sharing
+        )));
+    }
+    if sharing == "user" && (params.read.is_some() || params.write.is_some()) {
+        return Err(ClientError::ValidationError(
+            "Read/write roles require app or global sharing".to_string(),
+        ));
+    }
+
+    let clean_roles = |roles: &Vec<String>| -> Vec<String> {
+        roles
+            .iter()
+            .map(|role| role.trim().to_string())
+            .filter(|role| !role.is_empty())
+            .collect()
+    };
+
+    Ok(ObjectAcl {
+        sharing: sharing.to_string(),
+        owner: params
+            .owner
+            .clone()
+            .unwrap_or_else(|| current.owner.clone()),
+        read: params
+            .read
+            .as_ref()
+            .map(clean_roles)
+            .unwrap_or_else(|| current.read.clone()),
+        write: params
+            .write
+            .as_ref()
+            .map(clean_roles)
+            .unwrap_or_else(|| current.write.clone()),
+        ..current.clone()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn current() -> ObjectAcl {
+        ObjectAcl {
+            name: "Errors".to_string(),
+            kind: KnowledgeObjectKind::SavedSearch,
+            app: "search".to_string(),
+            owner: "admin".to_string(),
+            sharing: "app".to_string(),
+            read: vec!["*".to_string()],
+            write: vec!["admin".to_string()],
+            can_change_perms: true,
+        }
+    }
+
+    #[test]
+    fn test_merge_keeps_unset_fields() {
+        let params = AclUpdateParams {
+            read: Some(vec![
+                " user ".to_string(),
+                "power".to_string(),
+                "".to_string(),
+            ]),
+            ..AclUpdateParams::default()
+        };
+
+        let merged = merge_acl_update(&current(), &params).unwrap();
+
+        assert_eq!(merged.read, vec!["user", "power"]);
+        assert_eq!(merged.write, vec!["admin"]);
+        assert_eq!(merged.owner, "admin");
+        assert_eq!(merged.sharing, "app");
+    }
+
+    #[test]
+    fn test_merge_rejects_roles_for_private_sharing() {
+        let params = AclUpdateParams {
+            sharing: Some("user".to_string()),
+            write: Some(vec!["admin".to_string()]),
+            ..AclUpdateParams::default()
+        };
+
+        assert!(matches!(
+            merge_acl_update(&current(), &params),
+            Err(ClientError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_merge_rejects_unknown_sharing() {
+        let params = AclUpdateParams {
+            sharing: Some("system".to_string()),
+            ..AclUpdateParams::default()
+        };
+
+        assert!(merge_acl_update(&current(), &params).is_err());
+    }
+}
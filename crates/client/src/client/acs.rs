@@ -0,0 +1,181 @@
+//! Admin Config Service (ACS) methods for [`SplunkClient`].
+//!
+//! # What this module handles:
+//! - Index and HEC token management on Splunk Cloud stacks through ACS
+//! - Resolving the ACS stack and checking the client can authenticate to ACS
+//!
+//! # What this module does NOT handle:
+//! - Low-level ACS HTTP calls (in [`crate::endpoints::acs`])
+//! - Cloud detection (see `client::cloud`)
+//!
+//! # Invariants
+//! - ACS only accepts authentication tokens, so session (username/password)
+//!   clients are rejected before any request is sent
+
+use crate::client::SplunkClient;
+use crate::endpoints;
+use crate::error::{ClientError, Result};
+use crate::models::{AcsHecToken, AcsHecTokenCreateParams, AcsIndex, AcsIndexCreateParams};
+
+impl SplunkClient {
+    /// Stack name for ACS requests, after checking the auth strategy.
+    fn acs_target(&self) -> Result<String> {
+        if !self.is_api_token_auth() {
+            return Err(ClientError::ValidationError(
+                "ACS requires API token authentication (set SPLUNK_API_TOKEN or api_token in the profile)"
+                    .to_string(),
+            ));
+        }
+        self.acs_stack().ok_or_else(|| {
+            ClientError::ValidationError(
+                "ACS stack is unknown; set SPLUNK_ACS_STACK or acs_stack in the profile"
+                    .to_string(),
+            )
+        })
+    }
+
+    /// List indexes on the Splunk Cloud stack.
+    pub async fn acs_list_indexes(
+        &self,
+        count: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<AcsIndex>> {
+        let stack = self.acs_target()?;
+        let stack = stack.as_str();
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("acs_list_indexes"),
+            |__token| async move {
+                endpoints::list_acs_indexes(
+                    &self.http,
+                    &self.acs_url,
+                    stack,
+                    &__token,
+                    count,
+                    offset,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Create an index on the Splunk Cloud stack.
+    pub async fn acs_create_index(&self, params: &AcsIndexCreateParams) -> Result<AcsIndex> {
+        let stack = self.acs_target()?;
+        let stack = stack.as_str();
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("acs_create_index"),
+            |__token| async move {
+                endpoints::create_acs_index(
+                    &self.http,
+                    &self.acs_url,
+                    stack,
+                    &__token,
+                    params,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Delete an index from the Splunk Cloud stack.
+    pub async fn acs_delete_index(&self, name: &str) -> Result<()> {
+        let stack = self.acs_target()?;
+        let stack = stack.as_str();
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("acs_delete_index"),
+            |__token| async move {
+                endpoints::delete_acs_index(
+                    &self.http,
+                    &self.acs_url,
+                    stack,
+                    &__token,
+                    name,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// List HEC tokens on the Splunk Cloud stack.
+    pub async fn acs_list_hec_tokens(&self) -> Result<Vec<AcsHecToken>> {
+        let stack = self.acs_target()?;
+        let stack = stack.as_str();
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("acs_list_hec_tokens"),
+            |__token| async move {
+                endpoints::list_acs_hec_tokens(
+                    &self.http,
+                    &self.acs_url,
+                    stack,
+                    &__token,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Create a HEC token on the Splunk Cloud stack.
+    pub async fn acs_create_hec_token(
+        &self,
+        params: &AcsHecTokenCreateParams,
+    ) -> Result<AcsHecToken> {
+        let stack = self.acs_target()?;
+        let stack = stack.as_str();
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("acs_create_hec_token"),
+            |__token| async move {
+                endpoints::create_acs_hec_token(
+                    &self.http,
+                    &self.acs_url,
+                    stack,
+                    &__token,
+                    params,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Delete a HEC token from the Splunk Cloud stack.
+    pub async fn acs_delete_hec_token(&self, name: &str) -> Result<()> {
+        let stack = self.acs_target()?;
+        let stack = stack.as_str();
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("acs_delete_hec_token"),
+            |__token| async move {
+                endpoints::delete_acs_hec_token(
+                    &self.http,
+                    &self.acs_url,
+                    stack,
+                    &__token,
+                    name,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+}
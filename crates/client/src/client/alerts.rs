@@ -3,6 +3,8 @@
 //! # What this module handles:
 //! - Listing fired alerts
 //! - Getting fired alert details
+//! - Listing alert actions and the actions a saved search triggers
+//! - Dispatching alert action test-fire searches
 //!
 //! # What this module does NOT handle:
 //! - Low-level alert endpoint HTTP calls (in [`crate::endpoints::alerts`])
@@ -10,7 +12,7 @@
 use crate::client::SplunkClient;
 use crate::endpoints;
 use crate::error::Result;
-use crate::models::FiredAlert;
+use crate::models::{AlertAction, AlertActionDispatch, FiredAlert, SavedSearchAlertActions};
 
 impl SplunkClient {
     /// List all fired alerts.
@@ -70,4 +72,86 @@ impl SplunkClient {
         )
         .await
     }
+
+    /// List alert actions available on the server.
+    ///
+    /// # Arguments
+    /// * `count` - Maximum number of alert actions to return
+    /// * `offset` - Offset for pagination
+    pub async fn list_alert_actions(
+        &self,
+        count: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<AlertAction>> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("list_alert_actions"),
+            |__token| async move {
+                endpoints::list_alert_actions(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    count,
+                    offset,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Get the alert actions a saved search triggers, with their parameters.
+    ///
+    /// # Returns
+    /// The configured actions, or `ClientError::NotFound` if the saved search doesn't exist.
+    pub async fn get_saved_search_alert_actions(
+        &self,
+        name: &str,
+    ) -> Result<SavedSearchAlertActions> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation(
+                "get_saved_search_alert_actions",
+            ),
+            |__token| async move {
+                endpoints::get_saved_search_alert_actions(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    name,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Run a test-fire search (e.g. `| makeresults | sendalert ...`) and report
+    /// whether the alert action succeeded.
+    pub async fn dispatch_alert_action_test(&self, search: &str) -> Result<AlertActionDispatch> {
+        self.execute_request(
+            // Retrying after an auth error could deliver the test notification twice.
+            crate::client::request_executor::RequestPolicy::for_operation(
+                "dispatch_alert_action_test",
+            )
+            .without_auth_retry(),
+            |__token| async move {
+                endpoints::dispatch_alert_action_test(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    search,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
 }
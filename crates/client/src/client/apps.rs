@@ -125,6 +125,36 @@ impl SplunkClient {
         .await
     }
 
+    /// Install an app from an in-memory .spl package.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_name` - File name reported for the uploaded package
+    /// * `file_content` - Raw package bytes
+    ///
+    /// # Returns
+    ///
+    /// The installed `App` on success.
+    pub async fn install_app_package(&self, file_name: &str, file_content: &[u8]) -> Result<App> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("install_app"),
+            |__token| async move {
+                endpoints::install_app_package(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    file_name,
+                    file_content.to_vec(),
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
     /// Remove (uninstall) an app by name.
     ///
     /// # Arguments
@@ -5,6 +5,8 @@
 //! - Validating required configuration (base_url, auth_strategy)
 //! - Normalizing the base URL (removing trailing slashes)
 //! - Configuring the underlying HTTP client (timeouts, TLS verification)
+//! - Attaching the optional client-side rate limiter
+//! - Carrying Splunk Cloud and ACS settings
 //!
 //! # What this module does NOT handle:
 //! - Actual API calls (handled by [`SplunkClient`] methods in `mod.rs`)
@@ -22,10 +24,14 @@ use crate::auth::{AuthStrategy, SessionManager};
 use crate::client::SplunkClient;
 use crate::client::cache;
 use crate::client::circuit_breaker::CircuitBreaker;
+use crate::client::rate_limiter::RateLimiter;
 use crate::error::{ClientError, Result};
+use crate::http_trace::HttpTraceRecorder;
 use crate::metrics::MetricsCollector;
+use crate::middleware::{RequestHooks, RequestInterceptor};
+use crate::models::Namespace;
 use splunk_config::{
-    AuthStrategy as ConfigAuthStrategy, Config,
+    AuthStrategy as ConfigAuthStrategy, Config, HealthThresholds,
     constants::{
         DEFAULT_EXPIRY_BUFFER_SECS, DEFAULT_MAX_REDIRECTS, DEFAULT_MAX_RETRIES,
         DEFAULT_SESSION_TTL_SECS, DEFAULT_TIMEOUT_SECS,
@@ -78,6 +84,22 @@ pub struct SplunkClientBuilder {
     circuit_reset_timeout: Duration,
     /// Circuit breaker half-open requests.
     circuit_half_open_requests: u32,
+    /// HTTP trace recorder for debugging captures.
+    trace_recorder: Option<HttpTraceRecorder>,
+    /// Request interceptors, in registration order.
+    interceptors: Vec<Arc<dyn RequestInterceptor>>,
+    /// Client-side request rate limiter.
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// Default namespace for knowledge-object requests.
+    namespace: Option<Namespace>,
+    /// Explicit Splunk Cloud setting; `None` means detect.
+    cloud: Option<bool>,
+    /// ACS stack name override.
+    acs_stack: Option<String>,
+    /// Admin Config Service base URL override.
+    acs_url: Option<String>,
+    /// Thresholds the aggregated health check flags conditions against.
+    health_thresholds: HealthThresholds,
 }
 
 impl Default for SplunkClientBuilder {
@@ -103,6 +125,14 @@ impl Default for SplunkClientBuilder {
                 splunk_config::default_circuit_reset_timeout(),
             ),
             circuit_half_open_requests: splunk_config::default_circuit_half_open_requests(),
+            trace_recorder: None,
+            interceptors: Vec::new(),
+            rate_limiter: None,
+            namespace: None,
+            cloud: None,
+            acs_stack: None,
+            acs_url: None,
+            health_thresholds: HealthThresholds::default(),
         }
     }
 }
@@ -264,6 +294,122 @@ impl SplunkClientBuilder {
         self
     }
 
+    /// Set the HTTP trace recorder.
+    ///
+    /// When set, every request attempt made by the client is captured with
+    /// credentials redacted. Responses are buffered in memory while tracing,
+    /// so this is intended for debugging rather than routine use.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use splunk_client::{SplunkClient, http_trace::HttpTraceRecorder};
+    ///
+    /// let recorder = HttpTraceRecorder::new();
+    /// let client = SplunkClient::builder()
+    ///     .base_url("https://localhost:8089".to_string())
+    ///     .auth_strategy(auth_strategy)
+    ///     .trace_recorder(recorder.clone())
+    ///     .build()?;
+    /// // ... make requests ...
+    /// recorder.write_to_file(Path::new("trace.json"))?;
+    /// ```
+    pub fn trace_recorder(mut self, recorder: HttpTraceRecorder) -> Self {
+        self.trace_recorder = Some(recorder);
+        self
+    }
+
+    /// Add a request interceptor.
+    ///
+    /// Interceptors run for every HTTP attempt (including retries and logins)
+    /// in the order they were added. They can mutate outgoing headers, observe
+    /// responses, and short-circuit requests with a synthetic response. See
+    /// [`RequestInterceptor`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use std::sync::Arc;
+    /// use splunk_client::SplunkClient;
+    ///
+    /// let client = SplunkClient::builder()
+    ///     .base_url("https://localhost:8089".to_string())
+    ///     .auth_strategy(auth_strategy)
+    ///     .with_middleware(Arc::new(MyAuditInterceptor::default()))
+    ///     .build()?;
+    /// ```
+    pub fn with_middleware(mut self, interceptor: Arc<dyn RequestInterceptor>) -> Self {
+        self.interceptors.push(interceptor);
+        self
+    }
+
+    /// Limit outgoing requests to `requests_per_second`, allowing bursts of up
+    /// to `burst` back-to-back requests.
+    ///
+    /// The limit applies to every HTTP attempt (including retries and logins)
+    /// across all endpoints, so batch operations stay under Splunk's concurrent
+    /// request limits.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let client = SplunkClient::builder()
+    ///     .base_url("https://localhost:8089".to_string())
+    ///     .auth_strategy(auth_strategy)
+    ///     .rate_limit(10.0, 20)
+    ///     .build()?;
+    /// ```
+    pub fn rate_limit(mut self, requests_per_second: f64, burst: u32) -> Self {
+        self.rate_limiter = Some(Arc::new(RateLimiter::new(requests_per_second, burst)));
+        self
+    }
+
+    /// Use an existing rate limiter, sharing its budget with other clients.
+    pub fn rate_limiter(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Set the default namespace for knowledge-object requests.
+    ///
+    /// Saved search, macro, lookup, dashboard, event type and config requests
+    /// that are not given a namespace explicitly go to
+    /// `/servicesNS/{owner}/{app}` using this namespace.
+    pub fn namespace(mut self, namespace: Namespace) -> Self {
+        self.namespace = (!namespace.is_empty()).then_some(namespace);
+        self
+    }
+
+    /// Mark the target as Splunk Cloud (or on-prem), skipping detection.
+    ///
+    /// In cloud mode, operations Splunk Cloud does not allow on the management
+    /// port (index and HEC token management, restarts) fail before any request
+    /// is sent; use the ACS methods instead.
+    pub fn cloud(mut self, cloud: bool) -> Self {
+        self.cloud = Some(cloud);
+        self
+    }
+
+    /// Set the ACS stack name (defaults to the first label of a
+    /// `*.splunkcloud.com` base URL host).
+    pub fn acs_stack(mut self, stack: String) -> Self {
+        self.acs_stack = Some(stack);
+        self
+    }
+
+    /// Set the Admin Config Service base URL (defaults to `https://admin.splunk.com`).
+    pub fn acs_url(mut self, url: String) -> Self {
+        self.acs_url = Some(url);
+        self
+    }
+
+    /// Set the thresholds [`SplunkClient::check_health_aggregate`] flags
+    /// license usage, KVStore replication lag, and searchable peers against.
+    pub fn health_thresholds(mut self, thresholds: HealthThresholds) -> Self {
+        self.health_thresholds = thresholds;
+        self
+    }
+
     /// Create a client builder from configuration.
     ///
     /// This method centralizes the conversion from config crate types to client crate types,
@@ -312,6 +458,24 @@ impl SplunkClientBuilder {
         self.circuit_reset_timeout =
             Duration::from_secs(config.connection.circuit_reset_timeout_seconds);
         self.circuit_half_open_requests = config.connection.circuit_half_open_requests;
+        if let Some(limit) = config.connection.rate_limit {
+            self = self.rate_limit(limit.requests_per_second, limit.burst);
+        }
+        if let Some(namespace) = &config.connection.namespace {
+            self = self.namespace(Namespace {
+                owner: namespace.owner.clone(),
+                app: namespace.app.clone(),
+                sharing: namespace.sharing.clone(),
+            });
+        }
+        if let Some(cloud) = &config.connection.cloud {
+            self.cloud = cloud.enabled;
+            self.acs_stack = cloud.acs_stack.clone();
+            self.acs_url = cloud.acs_url.clone();
+        }
+        if let Some(thresholds) = &config.connection.health_thresholds {
+            self.health_thresholds = thresholds.clone();
+        }
         self
     }
 
@@ -410,6 +574,20 @@ impl SplunkClientBuilder {
             metrics: self.metrics,
             cache,
             circuit_breaker,
+            hooks: RequestHooks {
+                interceptors: self.interceptors,
+                trace_recorder: self.trace_recorder,
+                rate_limiter: self.rate_limiter,
+            },
+            namespace: std::sync::RwLock::new(self.namespace),
+            cloud: self.cloud,
+            detected_cloud: std::sync::RwLock::new(None),
+            acs_stack: self.acs_stack,
+            acs_url: Self::normalize_base_url(
+                self.acs_url
+                    .unwrap_or_else(|| crate::client::cloud::DEFAULT_ACS_URL.to_string()),
+            ),
+            health_thresholds: self.health_thresholds,
         })
     }
 }
@@ -473,6 +651,75 @@ mod tests {
         assert_eq!(builder.session_expiry_buffer_seconds, 120);
     }
 
+    #[test]
+    fn test_from_config_applies_rate_limit() {
+        let mut config = Config::with_api_token(
+            "https://splunk.example.com:8089".to_string(),
+            SecretString::new("test-token".to_string().into()),
+        );
+        config.connection.rate_limit = Some(splunk_config::RateLimitConfig::new(5.0, 8).unwrap());
+
+        let client = SplunkClient::builder()
+            .from_config(&config)
+            .build()
+            .unwrap();
+        let limiter = client.rate_limiter().expect("rate limiter configured");
+        assert_eq!(limiter.requests_per_second(), 5.0);
+        assert_eq!(limiter.burst(), 8);
+
+        config.connection.rate_limit = None;
+        let client = SplunkClient::builder()
+            .from_config(&config)
+            .build()
+            .unwrap();
+        assert!(client.rate_limiter().is_none());
+    }
+
+    #[test]
+    fn test_from_config_applies_namespace() {
+        let mut config = Config::with_api_token(
+            "https://splunk.example.com:8089".to_string(),
+            SecretString::new("test-token".to_string().into()),
+        );
+        config.connection.namespace = Some(splunk_config::NamespaceConfig {
+            owner: Some("jane".to_string()),
+            app: Some("search".to_string()),
+            sharing: None,
+        });
+
+        let client = SplunkClient::builder()
+            .from_config(&config)
+            .build()
+            .unwrap();
+        let namespace = client.namespace().expect("namespace configured");
+        assert_eq!(namespace.owner.as_deref(), Some("jane"));
+        assert_eq!(namespace.app.as_deref(), Some("search"));
+
+        client.set_namespace(Some(Namespace::default()));
+        assert!(client.namespace().is_none());
+    }
+
+    #[test]
+    fn test_from_config_applies_cloud_settings() {
+        let mut config = Config::with_api_token(
+            "https://splunk.example.com:8089".to_string(),
+            SecretString::new("test-token".to_string().into()),
+        );
+        config.connection.cloud = Some(splunk_config::CloudConfig {
+            enabled: Some(true),
+            acs_stack: Some("acme".to_string()),
+            acs_url: Some("https://acs.example.com/".to_string()),
+        });
+
+        let client = SplunkClient::builder()
+            .from_config(&config)
+            .build()
+            .unwrap();
+        assert!(client.is_cloud());
+        assert_eq!(client.acs_stack().as_deref(), Some("acme"));
+        assert_eq!(client.acs_url(), "https://acs.example.com");
+    }
+
     #[test]
     fn test_normalize_base_url_trailing_slash() {
         let input = "https://localhost:8089/".to_string();
@@ -0,0 +1,157 @@
+//! Splunk Cloud detection for [`SplunkClient`].
+//!
+//! # What this module handles:
+//! - Deciding whether the client talks to a Splunk Cloud Platform stack
+//! - Rejecting on-prem-only operations before they reach the server
+//! - Resolving the Admin Config Service (ACS) stack name
+//!
+//! # What this module does NOT handle:
+//! - ACS requests themselves (see `client::acs`)
+//!
+//! # Invariants
+//! - Detection never sends a request: an explicit setting wins, then the
+//!   `instance_type` seen in the last server info response, then the base URL
+//!   host (`*.splunkcloud.com`)
+
+use reqwest::Url;
+
+use crate::client::SplunkClient;
+use crate::error::{ClientError, Result};
+use crate::models::ServerInfo;
+
+/// Host suffix of Splunk Cloud Platform stacks.
+const SPLUNK_CLOUD_HOST_SUFFIX: &str = ".splunkcloud.com";
+
+/// Default Admin Config Service endpoint.
+pub const DEFAULT_ACS_URL: &str = "https://admin.splunk.com";
+
+impl SplunkClient {
+    /// True when the client targets a Splunk Cloud Platform stack.
+    pub fn is_cloud(&self) -> bool {
+        self.cloud
+            .or_else(|| {
+                *self
+                    .detected_cloud
+                    .read()
+                    .unwrap_or_else(|e| e.into_inner())
+            })
+            .unwrap_or_else(|| cloud_host(&self.base_url).is_some())
+    }
+
+    /// ACS stack name: the configured one, or the first label of a
+    /// `*.splunkcloud.com` base URL host.
+    pub fn acs_stack(&self) -> Option<String> {
+        self.acs_stack.clone().or_else(|| {
+            cloud_host(&self.base_url)
+                .and_then(|host| host.split('.').next().map(str::to_string))
+                .filter(|stack| !stack.is_empty())
+        })
+    }
+
+    /// Base URL of the Admin Config Service.
+    pub fn acs_url(&self) -> &str {
+        &self.acs_url
+    }
+
+    /// Remember the deployment type reported by splunkd.
+    pub(crate) fn record_server_info(&self, info: &ServerInfo) {
+        if info.instance_type.is_some() {
+            *self
+                .detected_cloud
+                .write()
+                .unwrap_or_else(|e| e.into_inner()) = Some(info.is_cloud());
+        }
+    }
+
+    /// Fail with [`ClientError::UnsupportedOnCloud`] when in cloud mode.
+    pub(crate) fn ensure_on_prem(
+        &self,
+        operation: &'static str,
+        alternative: &'static str,
+    ) -> Result<()> {
+        if self.is_cloud() {
+            return Err(ClientError::UnsupportedOnCloud {
+                operation,
+                alternative,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Lowercased host of `base_url` when it is a Splunk Cloud stack.
+fn cloud_host(base_url: &str) -> Option<String> {
+    let host = Url::parse(base_url).ok()?.host_str()?.to_ascii_lowercase();
+    host.ends_with(SPLUNK_CLOUD_HOST_SUFFIX).then_some(host)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::auth::AuthStrategy;
+    use crate::client::SplunkClient;
+    use crate::error::ClientError;
+    use crate::models::ServerInfo;
+    use secrecy::SecretString;
+
+    fn builder_for(base_url: &str) -> crate::client::builder::SplunkClientBuilder {
+        SplunkClient::builder()
+            .base_url(base_url.to_string())
+            .auth_strategy(AuthStrategy::ApiToken {
+                token: SecretString::new("test-token".to_string().into()),
+            })
+    }
+
+    fn server_info(instance_type: Option<&str>) -> ServerInfo {
+        serde_json::from_value(serde_json::json!({
+            "serverName": "sh1",
+            "version": "9.3.2408",
+            "build": "abc",
+            "instance_type": instance_type,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_cloud_detected_from_host_with_stack() {
+        let client = builder_for("https://Acme.splunkcloud.com:8089")
+            .build()
+            .unwrap();
+        assert!(client.is_cloud());
+        assert_eq!(client.acs_stack().as_deref(), Some("acme"));
+        assert_eq!(client.acs_url(), super::DEFAULT_ACS_URL);
+
+        let client = builder_for("https://localhost:8089").build().unwrap();
+        assert!(!client.is_cloud());
+        assert_eq!(client.acs_stack(), None);
+    }
+
+    #[test]
+    fn test_explicit_setting_overrides_detection() {
+        let client = builder_for("https://acme.splunkcloud.com:8089")
+            .cloud(false)
+            .build()
+            .unwrap();
+        client.record_server_info(&server_info(Some("cloud")));
+        assert!(!client.is_cloud());
+    }
+
+    #[test]
+    fn test_cloud_detected_from_server_info() {
+        let client = builder_for("https://10.0.0.5:8089").build().unwrap();
+        client.record_server_info(&server_info(None));
+        assert!(!client.is_cloud());
+
+        client.record_server_info(&server_info(Some("cloud")));
+        assert!(client.is_cloud());
+        let err = client
+            .ensure_on_prem("create_index", "splunk-cli acs indexes create")
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ClientError::UnsupportedOnCloud {
+                operation: "create_index",
+                ..
+            }
+        ));
+    }
+}
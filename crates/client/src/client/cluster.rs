@@ -3,6 +3,9 @@
 //! # What this module handles:
 //! - Getting cluster information
 //! - Listing cluster peers
+//! - Bucket fix-up and excess copy monitoring
+//! - Per-index replication/search factor compliance
+//! - Splunk version skew across the manager, peers, and search heads
 //! - Cluster configuration and management operations
 //!
 //! # What this module does NOT handle:
@@ -10,10 +13,12 @@
 
 use crate::client::SplunkClient;
 use crate::endpoints;
-use crate::error::Result;
+use crate::error::{ClientError, Result};
 use crate::models::{
-    ClusterInfo, ClusterManagementResponse, ClusterPeer, DecommissionPeerParams,
-    MaintenanceModeParams, RemovePeersParams,
+    ClusterBucketHealth, ClusterFixupLevel, ClusterFixupTask, ClusterIndexCompliance,
+    ClusterIndexCopies, ClusterIndexExcess, ClusterInfo, ClusterManagementResponse,
+    ClusterNodeRole, ClusterNodeVersion, ClusterPeer, ClusterSearchHead, ClusterVersionReport,
+    DataRebalanceAction, DecommissionPeerParams, MaintenanceModeParams, RemovePeersParams,
 };
 
 impl SplunkClient {
@@ -55,6 +60,74 @@ impl SplunkClient {
         .await
     }
 
+    /// List the search heads registered with the cluster manager.
+    pub async fn get_cluster_search_heads(&self) -> Result<Vec<ClusterSearchHead>> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation(
+                "get_cluster_search_heads",
+            ),
+            |__token| async move {
+                endpoints::get_cluster_search_heads(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Collect Splunk versions across the manager, peers, and search heads and flag unsupported skew.
+    ///
+    /// Must be called against the cluster manager. Peer and search head versions are the ones
+    /// each node last reported to the manager, so unreachable nodes keep their last known
+    /// version. Search heads are omitted if the manager cannot list them.
+    pub async fn get_cluster_versions(&self) -> Result<ClusterVersionReport> {
+        let server = self.get_server_info().await?;
+        let manager_name = server.server_name.clone();
+        let mut nodes = vec![ClusterNodeVersion {
+            role: ClusterNodeRole::Manager,
+            name: server.server_name,
+            version: Some(server.version).filter(|v| !v.is_empty()),
+        }];
+
+        nodes.extend(
+            self.get_cluster_peers()
+                .await?
+                .into_iter()
+                .map(|peer| ClusterNodeVersion {
+                    role: ClusterNodeRole::Peer,
+                    name: peer.label.unwrap_or(peer.host),
+                    version: peer.splunk_version,
+                }),
+        );
+
+        match self.get_cluster_search_heads().await {
+            Ok(search_heads) => {
+                // The manager lists itself as a search head; it is already included above.
+                nodes.extend(
+                    search_heads
+                        .into_iter()
+                        .map(|sh| ClusterNodeVersion {
+                            role: ClusterNodeRole::SearchHead,
+                            name: sh.label.or(sh.host_port_pair).unwrap_or_default(),
+                            version: sh.splunk_version,
+                        })
+                        .filter(|node| node.name != manager_name),
+                );
+            }
+            Err(e) => {
+                tracing::warn!("Failed to list cluster search heads: {}", e);
+            }
+        }
+
+        Ok(ClusterVersionReport::new(nodes))
+    }
+
     /// Set maintenance mode on the cluster manager.
     ///
     /// # Arguments
@@ -170,4 +243,166 @@ impl SplunkClient {
         )
         .await
     }
+
+    /// List pending bucket fix-up tasks at the given level.
+    pub async fn get_cluster_fixups(
+        &self,
+        level: ClusterFixupLevel,
+    ) -> Result<Vec<ClusterFixupTask>> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("get_cluster_fixups"),
+            |__token| async move {
+                endpoints::get_cluster_fixups(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    level,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// List excess bucket copy counts per index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Optional index name to limit the result to
+    pub async fn get_cluster_excess_buckets(
+        &self,
+        index: Option<&str>,
+    ) -> Result<Vec<ClusterIndexExcess>> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation(
+                "get_cluster_excess_buckets",
+            ),
+            |__token| async move {
+                endpoints::get_cluster_excess_buckets(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    index,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Collect pending fix-ups at every level plus excess copies for all indexes.
+    pub async fn get_cluster_bucket_health(&self) -> Result<ClusterBucketHealth> {
+        let mut fixups = Vec::new();
+        for level in ClusterFixupLevel::ALL {
+            fixups.extend(self.get_cluster_fixups(level).await?);
+        }
+        let excess = self.get_cluster_excess_buckets(None).await?;
+        Ok(ClusterBucketHealth { fixups, excess })
+    }
+
+    /// List replicated and searchable copy trackers per clustered index.
+    pub async fn get_cluster_index_copies(&self) -> Result<Vec<ClusterIndexCopies>> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation(
+                "get_cluster_index_copies",
+            ),
+            |__token| async move {
+                endpoints::get_cluster_index_copies(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Compare each clustered index's actual copy counts against the configured
+    /// replication and search factors.
+    ///
+    /// Must be called against the cluster manager.
+    pub async fn get_cluster_index_compliance(&self) -> Result<Vec<ClusterIndexCompliance>> {
+        let info = self.get_cluster_info().await?;
+        let (Some(replication_factor), Some(search_factor)) =
+            (info.replication_factor, info.search_factor)
+        else {
+            return Err(ClientError::InvalidResponse(
+                "Cluster config does not report replication and search factors".to_string(),
+            ));
+        };
+
+        Ok(self
+            .get_cluster_index_copies()
+            .await?
+            .iter()
+            .map(|copies| ClusterIndexCompliance::new(copies, replication_factor, search_factor))
+            .collect())
+    }
+
+    /// Remove excess bucket copies.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Optional index name; `None` prunes excess copies for all indexes
+    pub async fn remove_excess_buckets(
+        &self,
+        index: Option<&str>,
+    ) -> Result<ClusterManagementResponse> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("remove_excess_buckets"),
+            |__token| async move {
+                endpoints::remove_excess_buckets(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    index,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Start, stop, or query a data rebalance across cluster peers.
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - Whether to start, stop, or report on the rebalance
+    /// * `index` - Optional index name; `None` rebalances all indexes
+    pub async fn control_data_rebalance(
+        &self,
+        action: DataRebalanceAction,
+        index: Option<&str>,
+    ) -> Result<ClusterManagementResponse> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("control_data_rebalance"),
+            |__token| async move {
+                endpoints::control_data_rebalance(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    action,
+                    index,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
 }
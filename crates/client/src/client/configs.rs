@@ -4,15 +4,18 @@
 //! - Listing configuration files
 //! - Listing configuration stanzas for a specific config file
 //! - Retrieving specific configuration stanzas
+//! - Listing a stanza's per-context layers for effective-config resolution
+//! - Creating configuration stanzas
+//! - Scoping stanza listing, lookup, and creation to the client's default namespace
 //!
 //! # What this module does NOT handle:
-//! - Creating or modifying configuration stanzas (not yet implemented)
+//! - Modifying or deleting existing configuration stanzas (not yet implemented)
 //! - Low-level config endpoint HTTP calls (in [`crate::endpoints::configs`])
 
 use crate::client::SplunkClient;
 use crate::endpoints;
 use crate::error::Result;
-use crate::models::{ConfigFile, ConfigStanza};
+use crate::models::{ConfigFile, ConfigLayer, ConfigStanza};
 use std::collections::HashMap;
 
 impl SplunkClient {
@@ -67,6 +70,8 @@ impl SplunkClient {
         count: Option<usize>,
         offset: Option<usize>,
     ) -> Result<Vec<ConfigStanza>> {
+        let namespace = self.namespace();
+        let namespace = namespace.as_ref();
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("list_config_stanzas"),
             |__token| async move {
@@ -77,6 +82,7 @@ impl SplunkClient {
                     config_file,
                     count,
                     offset,
+                    namespace,
                     self.max_retries,
                     self.metrics.as_ref(),
                     self.circuit_breaker.as_deref(),
@@ -106,10 +112,97 @@ impl SplunkClient {
         config_file: &str,
         stanza_name: &str,
     ) -> Result<ConfigStanza> {
+        let namespace = self.namespace();
+        let namespace = namespace.as_ref();
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("get_config_stanza"),
             |__token| async move {
                 endpoints::get_config_stanza(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    config_file,
+                    stanza_name,
+                    namespace,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Create a configuration stanza.
+    ///
+    /// # Arguments
+    ///
+    /// * `config_file` - The config file name (e.g., "props", "transforms")
+    /// * `stanza_name` - The name of the stanza to create
+    /// * `settings` - Key/value settings written into the new stanza
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ClientError` if the request fails or the stanza already exists.
+    pub async fn create_config_stanza(
+        &self,
+        config_file: &str,
+        stanza_name: &str,
+        settings: &[(String, String)],
+    ) -> Result<()> {
+        let namespace = self.namespace();
+        let namespace = namespace.as_ref();
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("create_config_stanza"),
+            |__token| async move {
+                endpoints::create_config_stanza(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    config_file,
+                    stanza_name,
+                    settings,
+                    namespace,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// List every context's copy of a config file's stanzas.
+    ///
+    /// Unlike [`get_config_stanza`](Self::get_config_stanza), which returns the
+    /// server's merged view, this returns one layer per system, app, or user
+    /// context so callers can see where each setting comes from.
+    ///
+    /// # Arguments
+    ///
+    /// * `config_file` - The config file name (e.g., "props", "transforms")
+    /// * `stanza_name` - Optional stanza to restrict the listing to
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the stanza layers on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ClientError` if the request fails or the stanza is not found.
+    pub async fn list_config_stanza_layers(
+        &self,
+        config_file: &str,
+        stanza_name: Option<&str>,
+    ) -> Result<Vec<ConfigLayer>> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation(
+                "list_config_stanza_layers",
+            ),
+            |__token| async move {
+                endpoints::list_config_stanza_layers(
                     &self.http,
                     &self.base_url,
                     &__token,
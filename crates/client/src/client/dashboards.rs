@@ -10,15 +10,18 @@
 use crate::client::SplunkClient;
 use crate::endpoints;
 use crate::error::Result;
-use crate::models::Dashboard;
+use crate::models::{Dashboard, Namespace};
 
 impl SplunkClient {
-    /// List all dashboards.
+    /// List all dashboards, optionally only those defined in a namespace's app.
     pub async fn list_dashboards(
         &self,
         count: Option<usize>,
         offset: Option<usize>,
+        namespace: Option<&Namespace>,
     ) -> Result<Vec<Dashboard>> {
+        let namespace = self.resolve_namespace(namespace);
+        let namespace = namespace.as_ref();
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("list_dashboards"),
             |__token| async move {
@@ -28,6 +31,7 @@ impl SplunkClient {
                     &__token,
                     count,
                     offset,
+                    namespace,
                     self.max_retries,
                     self.metrics.as_ref(),
                     self.circuit_breaker.as_deref(),
@@ -40,6 +44,8 @@ impl SplunkClient {
 
     /// Get a dashboard by name, including its XML definition.
     pub async fn get_dashboard(&self, name: &str) -> Result<Dashboard> {
+        let namespace = self.namespace();
+        let namespace = namespace.as_ref();
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("get_dashboard"),
             |__token| async move {
@@ -48,6 +54,7 @@ impl SplunkClient {
                     &self.base_url,
                     &__token,
                     name,
+                    namespace,
                     self.max_retries,
                     self.metrics.as_ref(),
                     self.circuit_breaker.as_deref(),
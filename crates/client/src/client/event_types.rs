@@ -0,0 +1,43 @@
+//! Event type API methods for [`SplunkClient`].
+//!
+//! # What this module handles:
+//! - Listing event types
+//!
+//! # What this module does NOT handle:
+//! - Low-level event type endpoint HTTP calls (in [`crate::endpoints`])
+
+use crate::client::SplunkClient;
+use crate::endpoints;
+use crate::error::Result;
+use crate::models::{EventType, Namespace};
+
+impl SplunkClient {
+    /// List event types, optionally only those defined in a namespace's app.
+    pub async fn list_event_types(
+        &self,
+        count: Option<usize>,
+        offset: Option<usize>,
+        namespace: Option<&Namespace>,
+    ) -> Result<Vec<EventType>> {
+        let namespace = self.resolve_namespace(namespace);
+        let namespace = namespace.as_ref();
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("list_event_types"),
+            |__token| async move {
+                endpoints::list_event_types(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    count,
+                    offset,
+                    namespace,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+}
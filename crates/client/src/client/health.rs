@@ -4,8 +4,13 @@
 //! eliminating duplication of the health check aggregation pattern.
 
 use crate::client::SplunkClient;
+use crate::endpoints;
 use crate::error::Result;
-use crate::models::HealthCheckOutput;
+use crate::models::{
+    ClusterPeerHealth, DEFAULT_FAILED_LOGIN_THRESHOLD, DiskUsage, HealthCheckOutput,
+    PartitionSpace, QueueHealth, ResourceUsage, SchedulerHealth, SearchLoad, SecurityHealth,
+    UserSearchUsage,
+};
 
 /// Result of a health check aggregation.
 ///
@@ -18,6 +23,10 @@ pub struct AggregatedHealth {
     /// Errors from individual health checks that failed but didn't abort the aggregation.
     /// Each tuple contains (endpoint_name, error).
     pub partial_errors: Vec<(String, crate::error::ClientError)>,
+    /// Conditions worth flagging that did not fail any check, such as
+    /// ingestion queues sustained above 80% full, source IPs with many
+    /// failed logins, queued searches, or values past the client's health thresholds.
+    pub warnings: Vec<String>,
 }
 
 impl SplunkClient {
@@ -29,6 +38,12 @@ impl SplunkClient {
     /// - License usage
     /// - KVStore status
     /// - Log parsing health
+    /// - Scheduled search health (last 24 hours)
+    /// - Ingestion queue health (last 15 minutes)
+    /// - Failed logins per source IP (last hour)
+    /// - Disk space per partition (the fullest one is kept)
+    /// - Running and queued searches against the concurrency limits and user quotas
+    /// - Cluster peer counts, on a cluster manager when `min_searchable_peers` is set
     ///
     /// The optional health checks are performed concurrently for improved performance.
     ///
@@ -37,6 +52,9 @@ impl SplunkClient {
     /// Returns `Ok(AggregatedHealth)` if server_info can be fetched.
     /// Returns `Err` only if server_info fails (indicating the server is unreachable).
     /// Other endpoints may fail and will be recorded in `partial_errors`.
+    /// Sustained-full queues, flagged source IPs, queued searches, users at
+    /// their search quota, and values past
+    /// [`SplunkClient::health_thresholds`] are reported in `warnings`.
     ///
     /// # Example
     ///
@@ -50,15 +68,38 @@ impl SplunkClient {
     pub async fn check_health_aggregate(&self) -> Result<AggregatedHealth> {
         // Server info is required - if this fails, the whole check fails
         let server_info = self.get_server_info().await?;
+        let is_cluster_manager = server_info
+            .server_roles
+            .iter()
+            .any(|role| role == "cluster_master" || role == "cluster_manager");
 
         // Fetch optional health data concurrently for improved performance
-        // Using tokio::join! to run all checks in parallel
-        let (splunkd_health, license_usage, kvstore_status, log_parsing_health) = tokio::join!(
-            self.get_health(),
-            self.get_license_usage(),
-            self.get_kvstore_status(),
-            self.check_log_parsing_health(),
-        );
+        // Using tokio::join! to run all checks in parallel; boxed so the joined
+        // futures live on the heap instead of inflating every caller's future.
+        let (
+            splunkd_health,
+            license_usage,
+            kvstore_status,
+            log_parsing_health,
+            scheduler_health,
+            queue_health,
+            security_health,
+            partitions_space,
+            search_load,
+        ) = Box::pin(async {
+            tokio::join!(
+                self.get_health(),
+                self.get_license_usage(),
+                self.get_kvstore_status(),
+                self.check_log_parsing_health(),
+                self.check_scheduler_health("24h"),
+                self.check_queue_health("15m"),
+                self.check_security_health("1h", DEFAULT_FAILED_LOGIN_THRESHOLD),
+                self.get_partitions_space(),
+                self.check_search_load(),
+            )
+        })
+        .await;
 
         let mut output = HealthCheckOutput {
             server_info: Some(server_info),
@@ -66,14 +107,21 @@ impl SplunkClient {
             license_usage: None,
             kvstore_status: None,
             log_parsing_health: None,
+            scheduler_health: None,
+            queue_health: None,
+            security_health: None,
             circuit_breaker_states: self.circuit_breaker.as_ref().map(|cb| {
                 cb.all_states()
                     .into_iter()
                     .map(|(k, v)| (k, v.as_str().to_string()))
                     .collect()
             }),
+            cluster_peers: None,
+            disk_partition: None,
+            search_load: None,
         };
         let mut partial_errors = Vec::new();
+        let mut warnings = Vec::new();
 
         // Collect optional health data - failures don't abort the aggregation
         match splunkd_health {
@@ -96,11 +144,198 @@ impl SplunkClient {
             Err(e) => partial_errors.push(("log_parsing_health".to_string(), e)),
         }
 
+        match scheduler_health {
+            Ok(scheduler) => output.scheduler_health = Some(scheduler),
+            Err(e) => partial_errors.push(("scheduler_health".to_string(), e)),
+        }
+
+        match queue_health {
+            Ok(queues) => {
+                warnings.extend(queues.warnings());
+                output.queue_health = Some(queues);
+            }
+            Err(e) => partial_errors.push(("queue_health".to_string(), e)),
+        }
+
+        match security_health {
+            Ok(security) => {
+                warnings.extend(security.warnings());
+                output.security_health = Some(security);
+            }
+            Err(e) => partial_errors.push(("security_health".to_string(), e)),
+        }
+
+        match partitions_space {
+            Ok(partitions) => output.disk_partition = PartitionSpace::fullest(partitions),
+            Err(e) => partial_errors.push(("disk_partition".to_string(), e)),
+        }
+
+        match search_load {
+            Ok(load) => {
+                warnings.extend(load.warnings());
+                output.search_load = Some(load);
+            }
+            Err(e) => partial_errors.push(("search_load".to_string(), e)),
+        }
+
+        if is_cluster_manager && self.health_thresholds.min_searchable_peers > 0 {
+            match self.get_cluster_peers().await {
+                Ok(peers) => output.cluster_peers = Some(ClusterPeerHealth::from_peers(&peers)),
+                Err(e) => partial_errors.push(("cluster_peers".to_string(), e)),
+            }
+        }
+
+        warnings.extend(output.threshold_warnings(&self.health_thresholds));
+
         Ok(AggregatedHealth {
             output,
             partial_errors,
+            warnings,
         })
     }
+
+    /// Summarize skipped and failed scheduled searches over a look-back window.
+    ///
+    /// `since` accepts a bare duration such as `24h` or a Splunk relative time
+    /// such as `-7d@d`; see [`endpoints::scheduler_time_window`].
+    pub async fn check_scheduler_health(&self, since: &str) -> Result<SchedulerHealth> {
+        let earliest_time = endpoints::scheduler_time_window(since);
+        let earliest_time = earliest_time.as_str();
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("check_scheduler_health"),
+            |__token| async move {
+                endpoints::check_scheduler_health(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    earliest_time,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Summarize ingestion queue fill ratios and blocked counts per pipeline.
+    ///
+    /// `since` accepts the same forms as [`Self::check_scheduler_health`].
+    pub async fn check_queue_health(&self, since: &str) -> Result<QueueHealth> {
+        let earliest_time = endpoints::scheduler_time_window(since);
+        let earliest_time = earliest_time.as_str();
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("check_queue_health"),
+            |__token| async move {
+                endpoints::check_queue_health(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    earliest_time,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Summarize failed logins per source IP from `_audit`, flagging addresses
+    /// with more than `threshold` failures as likely brute-force attempts.
+    ///
+    /// `since` accepts the same forms as [`Self::check_scheduler_health`].
+    pub async fn check_security_health(
+        &self,
+        since: &str,
+        threshold: u64,
+    ) -> Result<SecurityHealth> {
+        let earliest_time = endpoints::scheduler_time_window(since);
+        let earliest_time = earliest_time.as_str();
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("check_security_health"),
+            |__token| async move {
+                endpoints::check_security_health(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    earliest_time,
+                    threshold,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Report disk space on each partition of the target instance, flagged
+    /// against the disk thresholds in [`SplunkClient::health_thresholds`].
+    pub async fn check_disk_usage(&self) -> Result<DiskUsage> {
+        let (server_info, partitions) =
+            tokio::try_join!(self.get_server_info(), self.get_partitions_space())?;
+        Ok(DiskUsage::new(
+            server_info.server_name,
+            server_info.server_roles,
+            &self.health_thresholds,
+            partitions,
+        ))
+    }
+
+    /// Count running and queued searches per user, with the highest job
+    /// quota (`srchJobsQuota`) of each user's roles.
+    pub async fn get_user_search_usage(&self) -> Result<Vec<UserSearchUsage>> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("get_user_search_usage"),
+            |__token| async move {
+                endpoints::get_user_search_usage(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Report running and queued searches against the instance's concurrency
+    /// limits, flagging queued searches and users at their quota.
+    pub async fn check_search_load(&self) -> Result<SearchLoad> {
+        let (limits, users) = tokio::try_join!(
+            self.get_search_concurrency_limits(),
+            self.get_user_search_usage()
+        )?;
+        Ok(SearchLoad::new(limits, users))
+    }
+
+    /// Report the latest CPU, memory, and I/O wait per host and the busiest
+    /// Splunk processes from `_introspection`, optionally for one `host`.
+    pub async fn check_resource_usage(&self, host: Option<&str>) -> Result<ResourceUsage> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("check_resource_usage"),
+            |__token| async move {
+                endpoints::check_resource_usage(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    host,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
 }
 
 #[cfg(test)]
@@ -118,17 +353,27 @@ mod tests {
                 mode: Some(ServerMode::Standalone),
                 server_roles: vec!["search_head".to_string()],
                 os_name: Some("Linux".to_string()),
+                restart_required: false,
+                startup_time: None,
+                instance_type: None,
             }),
             splunkd_health: None,
             license_usage: None,
             kvstore_status: None,
             log_parsing_health: None,
+            scheduler_health: None,
+            queue_health: None,
+            security_health: None,
             circuit_breaker_states: None,
+            cluster_peers: None,
+            disk_partition: None,
+            search_load: None,
         };
 
         let aggregated = AggregatedHealth {
             output,
             partial_errors: vec![],
+            warnings: vec![],
         };
 
         assert!(aggregated.output.server_info.is_some());
@@ -146,6 +391,9 @@ mod tests {
                 mode: Some(ServerMode::Standalone),
                 server_roles: vec!["search_head".to_string()],
                 os_name: Some("Linux".to_string()),
+                restart_required: false,
+                startup_time: None,
+                instance_type: None,
             }),
             splunkd_health: Some(SplunkHealth {
                 health: HealthStatus::Green,
@@ -154,7 +402,13 @@ mod tests {
             license_usage: None,
             kvstore_status: None,
             log_parsing_health: None,
+            scheduler_health: None,
+            queue_health: None,
+            security_health: None,
             circuit_breaker_states: None,
+            cluster_peers: None,
+            disk_partition: None,
+            search_load: None,
         };
 
         let partial_errors = vec![
@@ -181,6 +435,7 @@ mod tests {
         let aggregated = AggregatedHealth {
             output,
             partial_errors,
+            warnings: vec![],
         };
 
         assert_eq!(aggregated.partial_errors.len(), 2);
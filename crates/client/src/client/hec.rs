@@ -8,7 +8,7 @@
 //!
 //! # What this module does NOT handle:
 //! - Low-level HEC endpoint HTTP calls (see [`crate::endpoints::hec`])
-//! - HEC token management (handled by CLI/config)
+//! - HEC token management (see [`crate::client::hec_tokens`])
 //!
 //! # Important Notes
 //! - HEC uses a separate URL (typically port 8088) from the REST API (port 8089)
@@ -48,7 +48,7 @@ impl SplunkClient {
         hec_token: &str,
         event: &HecEvent,
     ) -> Result<HecResponse> {
-        endpoints::hec::send_event(
+        self.with_hooks(endpoints::hec::send_event(
             &self.http,
             hec_url,
             hec_token,
@@ -56,7 +56,7 @@ impl SplunkClient {
             self.max_retries,
             self.metrics.as_ref(),
             self.circuit_breaker.as_deref(),
-        )
+        ))
         .await
     }
 
@@ -91,7 +91,7 @@ impl SplunkClient {
         events: &[HecEvent],
         use_ndjson: bool,
     ) -> Result<HecBatchResponse> {
-        endpoints::hec::send_batch(
+        self.with_hooks(endpoints::hec::send_batch(
             &self.http,
             hec_url,
             hec_token,
@@ -100,7 +100,7 @@ impl SplunkClient {
             self.max_retries,
             self.metrics.as_ref(),
             self.circuit_breaker.as_deref(),
-        )
+        ))
         .await
     }
 
@@ -122,14 +122,14 @@ impl SplunkClient {
     /// println!("HEC is healthy: {}", health.is_healthy());
     /// ```
     pub async fn hec_health_check(&self, hec_url: &str, hec_token: &str) -> Result<HecHealth> {
-        endpoints::hec::health_check(
+        self.with_hooks(endpoints::hec::health_check(
             &self.http,
             hec_url,
             hec_token,
             self.max_retries,
             self.metrics.as_ref(),
             self.circuit_breaker.as_deref(),
-        )
+        ))
         .await
     }
 
@@ -161,7 +161,7 @@ impl SplunkClient {
         hec_token: &str,
         ack_ids: &[u64],
     ) -> Result<HecAckStatus> {
-        endpoints::hec::check_ack_status(
+        self.with_hooks(endpoints::hec::check_ack_status(
             &self.http,
             hec_url,
             hec_token,
@@ -169,7 +169,7 @@ impl SplunkClient {
             self.max_retries,
             self.metrics.as_ref(),
             self.circuit_breaker.as_deref(),
-        )
+        ))
         .await
     }
 }
@@ -0,0 +1,351 @@
+//! HEC token client methods.
+//!
+//! Responsibilities:
+//! - High-level API for HTTP Event Collector token management with auth retry.
+//! - Route endpoint calls through the shared request executor.
+//! - Scope requests to the client's default namespace.
+//! - Rotate a token by recreating it with the same settings.
+//!
+//! Does NOT handle:
+//! - Sending events to HEC (see [`crate::client::hec`]).
+//! - Token management on Splunk Cloud (see `client::acs`; creating and
+//!   deleting here is rejected in cloud mode).
+//!
+//! Invariants:
+//! - Splunk has no endpoint to regenerate a token value, so rotation deletes
+//!   the input and recreates it; the old value stops working immediately.
+//! - A rotation whose recreate fails puts the old token back with its old
+//!   value before returning the error.
+
+use tracing::warn;
+
+use crate::client::SplunkClient;
+use crate::endpoints;
+use crate::error::{ClientError, Result, RollbackFailure};
+use crate::models::{HecToken, HecTokenCreateParams, HecTokenUpdateParams, Namespace};
+
+impl SplunkClient {
+    /// List all HEC tokens.
+    pub async fn list_hec_tokens(&self) -> Result<Vec<HecToken>> {
+        let namespace = self.namespace();
+        let namespace = namespace.as_ref();
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("list_hec_tokens"),
+            |__token| async move {
+                endpoints::list_hec_tokens(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    namespace,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Get a single HEC token by name.
+    ///
+    /// # Arguments
+    /// * `name` - The token input name, with or without the `http://` prefix
+    ///
+    /// # Returns
+    /// The `HecToken` if found, or `ClientError::NotFound` if it doesn't exist.
+    pub async fn get_hec_token(&self, name: &str) -> Result<HecToken> {
+        self.get_hec_token_in(name, self.namespace().as_ref()).await
+    }
+
+    async fn get_hec_token_in(
+        &self,
+        name: &str,
+        namespace: Option<&Namespace>,
+    ) -> Result<HecToken> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("get_hec_token"),
+            |__token| async move {
+                endpoints::get_hec_token(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    name,
+                    namespace,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Create a new HEC token.
+    ///
+    /// The input is defined in `params.app` when set, otherwise in the
+    /// client's namespace.
+    ///
+    /// # Arguments
+    /// * `params` - Parameters for creating the token
+    ///
+    /// # Returns
+    /// The created token, including the generated token value.
+    pub async fn create_hec_token(&self, params: &HecTokenCreateParams) -> Result<HecToken> {
+        self.ensure_on_prem("create_hec_token", "use splunk-cli acs hec-tokens create")?;
+        let namespace = self.token_namespace(params.app.as_deref());
+        let namespace = namespace.as_ref();
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("create_hec_token"),
+            |__token| async move {
+                endpoints::create_hec_token(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    params,
+                    namespace,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Update an existing HEC token.
+    ///
+    /// # Arguments
+    /// * `name` - The token input name, with or without the `http://` prefix
+    /// * `params` - Fields to update; omitted fields are left unchanged
+    pub async fn update_hec_token(&self, name: &str, params: &HecTokenUpdateParams) -> Result<()> {
+        let namespace = self.namespace();
+        let namespace = namespace.as_ref();
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("update_hec_token"),
+            |__token| async move {
+                endpoints::update_hec_token(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    name,
+                    params,
+                    namespace,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Enable a HEC token.
+    ///
+    /// # Arguments
+    /// * `name` - The token input name, with or without the `http://` prefix
+    pub async fn enable_hec_token(&self, name: &str) -> Result<()> {
+        let namespace = self.namespace();
+        let namespace = namespace.as_ref();
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("enable_hec_token"),
+            |__token| async move {
+                endpoints::enable_hec_token(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    name,
+                    namespace,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Disable a HEC token.
+    ///
+    /// # Arguments
+    /// * `name` - The token input name, with or without the `http://` prefix
+    pub async fn disable_hec_token(&self, name: &str) -> Result<()> {
+        self.disable_hec_token_in(name, self.namespace().as_ref())
+            .await
+    }
+
+    async fn disable_hec_token_in(&self, name: &str, namespace: Option<&Namespace>) -> Result<()> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("disable_hec_token"),
+            |__token| async move {
+                endpoints::disable_hec_token(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    name,
+                    namespace,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Replace a HEC token's value with a newly generated one.
+    ///
+    /// The token is deleted and recreated in the same app with the same
+    /// settings; a disabled token stays disabled. If the new token cannot be
+    /// created, the old one is restored with its previous value.
+    ///
+    /// # Arguments
+    /// * `name` - The token input name, with or without the `http://` prefix
+    ///
+    /// # Returns
+    /// The recreated token, including its new value. When the recreate fails,
+    /// its error once the old token is restored, or
+    /// `ClientError::TransactionRollbackError` listing the token's settings
+    /// when the restore fails too.
+    pub async fn rotate_hec_token(&self, name: &str) -> Result<HecToken> {
+        let current = self.get_hec_token(name).await?;
+        let params = HecTokenCreateParams::from(&current);
+        let namespace = self.token_namespace(params.app.as_deref());
+        let namespace = namespace.as_ref();
+
+        self.delete_hec_token_in(name, namespace).await?;
+        let mut rotated = match self.create_hec_token(&params).await {
+            Ok(rotated) => rotated,
+            Err(e) => return Err(self.restore_hec_token(&current, e).await),
+        };
+        if current.disabled {
+            self.disable_hec_token_in(&params.name, namespace).await?;
+            rotated.disabled = true;
+        }
+        // Some servers omit the value from the create response; read it back.
+        if rotated.token.is_none() {
+            rotated = self.get_hec_token_in(&params.name, namespace).await?;
+        }
+        Ok(rotated)
+    }
+
+    /// Recreate a deleted token with its old value after a failed rotation.
+    ///
+    /// Returns the error to report: the rotation's own error when the old
+    /// token is back, or a rollback error carrying the token's settings when
+    /// it is not, so it can be recreated by hand.
+    async fn restore_hec_token(&self, current: &HecToken, error: ClientError) -> ClientError {
+        let params = HecTokenCreateParams {
+            token: current.token.clone(),
+            ..HecTokenCreateParams::from(current)
+        };
+        match self.create_hec_token(&params).await {
+            Ok(_) => {
+                warn!(
+                    "Recreating HEC token {} failed; restored its previous value",
+                    params.name
+                );
+                if current.disabled {
+                    let namespace = self.token_namespace(params.app.as_deref());
+                    if let Err(e) = self
+                        .disable_hec_token_in(&params.name, namespace.as_ref())
+                        .await
+                    {
+                        warn!(
+                            "Failed to disable restored HEC token {}: {}",
+                            params.name, e
+                        );
+                    }
+                }
+                error
+            }
+            Err(restore_error) => {
+                warn!("Recreating HEC token {} failed: {}", params.name, error);
+                ClientError::TransactionRollbackError {
+                    count: 1,
+                    failures: vec![RollbackFailure {
+                        resource_name: format!(
+                            "{} ({})",
+                            params.name,
+                            describe_settings(&params, current.disabled)
+                        ),
+                        operation: "restore HEC token".to_string(),
+                        error: restore_error,
+                    }],
+                }
+            }
+        }
+    }
+
+    /// The client's namespace, moved to `app` when given.
+    ///
+    /// The owner defaults to `nobody`, since inputs cannot be written through
+    /// the wildcard owner.
+    fn token_namespace(&self, app: Option<&str>) -> Option<Namespace> {
+        let namespace = self.namespace();
+        let Some(app) = app else {
+            return namespace;
+        };
+        let mut namespace = namespace.unwrap_or_default();
+        namespace.owner.get_or_insert_with(|| "nobody".to_string());
+        namespace.app = Some(app.to_string());
+        Some(namespace)
+    }
+
+    /// Delete a HEC token.
+    ///
+    /// # Arguments
+    /// * `name` - The token input name, with or without the `http://` prefix
+    pub async fn delete_hec_token(&self, name: &str) -> Result<()> {
+        self.delete_hec_token_in(name, self.namespace().as_ref())
+            .await
+    }
+
+    async fn delete_hec_token_in(&self, name: &str, namespace: Option<&Namespace>) -> Result<()> {
+        self.ensure_on_prem("delete_hec_token", "use splunk-cli acs hec-tokens delete")?;
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("delete_hec_token"),
+            |__token| async move {
+                endpoints::delete_hec_token(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    name,
+                    namespace,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+}
+
+/// Token settings as `key=value` pairs, without the token value.
+fn describe_settings(params: &HecTokenCreateParams, disabled: bool) -> String {
+    let mut settings = Vec::new();
+    let mut push = |key: &str, value: Option<&str>| {
+        if let Some(value) = value {
+            settings.push(format!("{}={}", key, value));
+        }
+    };
+    let indexes = (!params.indexes.is_empty()).then(|| params.indexes.join(","));
+    push("app", params.app.as_deref());
+    push("index", params.index.as_deref());
+    push("indexes", indexes.as_deref());
+    push("sourcetype", params.sourcetype.as_deref());
+    push("source", params.source.as_deref());
+    push("host", params.host.as_deref());
+    push("outputgroup", params.outputgroup.as_deref());
+    push("description", params.description.as_deref());
+    settings.push(format!("useACK={}", params.use_ack));
+    settings.push(format!("disabled={}", disabled));
+    settings.join(", ")
+}
@@ -5,9 +5,12 @@
 //! - Creating new indexes
 //! - Modifying existing indexes
 //! - Deleting indexes
+//! - Rolling hot buckets to warm
 //!
 //! # What this module does NOT handle:
 //! - Low-level index endpoint HTTP calls (in [`crate::endpoints::indexes`])
+//! - Index management on Splunk Cloud (see `client::acs`; writes here are
+//!   rejected in cloud mode)
 
 use crate::client::SplunkClient;
 use crate::endpoints;
@@ -61,7 +64,10 @@ impl SplunkClient {
     }
 
     /// Create a new index with the specified parameters.
+    ///
+    /// Fails with `UnsupportedOnCloud` in cloud mode; see [`Self::acs_create_index`].
     pub async fn create_index(&self, params: &CreateIndexParams) -> Result<Index> {
+        self.ensure_on_prem("create_index", "use splunk-cli acs indexes create")?;
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("create_index"),
             |__token| async move {
@@ -82,6 +88,10 @@ impl SplunkClient {
 
     /// Modify an existing index.
     pub async fn modify_index(&self, name: &str, params: &ModifyIndexParams) -> Result<Index> {
+        self.ensure_on_prem(
+            "modify_index",
+            "change index settings through the Admin Config Service",
+        )?;
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("modify_index"),
             |__token| async move {
@@ -103,6 +113,7 @@ impl SplunkClient {
 
     /// Delete an index by name.
     pub async fn delete_index(&self, name: &str) -> Result<()> {
+        self.ensure_on_prem("delete_index", "use splunk-cli acs indexes delete")?;
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("delete_index"),
             |__token| async move {
@@ -120,4 +131,24 @@ impl SplunkClient {
         )
         .await
     }
+
+    /// Roll an index's hot buckets to warm (e.g., before a maintenance window).
+    pub async fn roll_hot_buckets(&self, name: &str) -> Result<()> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("roll_hot_buckets"),
+            |__token| async move {
+                endpoints::roll_hot_buckets(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    name,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
 }
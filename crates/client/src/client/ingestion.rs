@@ -0,0 +1,41 @@
+//! Indexing latency and thruput API methods for [`SplunkClient`].
+//!
+//! # What this module handles:
+//! - Indexing latency and thruput per sourcetype, index, or host
+//!
+//! # What this module does NOT handle:
+//! - Authentication and session management (in [`crate::client::session`])
+//! - Low-level search job HTTP calls (in [`crate::endpoints::ingestion`])
+
+use crate::client::SplunkClient;
+use crate::endpoints;
+use crate::error::Result;
+use crate::models::{IngestionGroupBy, IngestionStats};
+
+impl SplunkClient {
+    /// Report indexing latency (index time minus event time) and thruput per
+    /// `group_by` over the last `window_secs` seconds, highest thruput first.
+    pub async fn get_ingestion_stats(
+        &self,
+        group_by: IngestionGroupBy,
+        window_secs: u64,
+    ) -> Result<IngestionStats> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("get_ingestion_stats"),
+            |__token| async move {
+                endpoints::get_ingestion_stats(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    group_by,
+                    window_secs,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+}
@@ -3,16 +3,18 @@
 //! # What this module handles:
 //! - Listing data inputs (TCP, UDP, Monitor, Script)
 //! - Enabling/disabling inputs
+//! - Creating monitor, TCP, and UDP inputs and deleting inputs
+//! - Summarizing per-source ingestion throughput
 //!
 //! # What this module does NOT handle:
-//! - Creating or removing inputs (not yet implemented)
+//! - Creating scripted or cooked TCP inputs
 //! - Low-level input endpoint HTTP calls (in [`crate::endpoints::inputs`])
 
 use crate::client::SplunkClient;
 use crate::endpoints;
 use crate::error::ClientError;
 use crate::error::Result;
-use crate::models::Input;
+use crate::models::{CreateInputParams, Input, ThroughputReport};
 
 impl SplunkClient {
     /// List all data inputs across all types.
@@ -156,4 +158,82 @@ impl SplunkClient {
         )
         .await
     }
+
+    /// Create a monitor, TCP, or UDP input.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ClientError` if the request fails, including when an input
+    /// with the same path or port already exists.
+    pub async fn create_input(&self, params: &CreateInputParams) -> Result<Input> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("create_input"),
+            |__token| async move {
+                endpoints::create_input(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    params,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Delete an input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_type` - The type of input (tcp/raw, tcp/cooked, udp, monitor, script)
+    /// * `name` - The name of the input to delete
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ClientError` if the request fails.
+    pub async fn delete_input(&self, input_type: &str, name: &str) -> Result<()> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("delete_input"),
+            |__token| async move {
+                endpoints::delete_input(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    input_type,
+                    name,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Summarize per-source ingestion throughput over the last `window_secs` seconds.
+    ///
+    /// Reads the `per_source_thruput` group of `metrics.log`; join the result
+    /// against inputs with [`ThroughputReport::for_input`].
+    pub async fn get_input_throughput(&self, window_secs: u64) -> Result<ThroughputReport> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("get_input_throughput"),
+            |__token| async move {
+                endpoints::get_input_throughput(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    window_secs,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
 }
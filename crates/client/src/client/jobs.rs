@@ -1,8 +1,10 @@
 //! Job management API methods for [`SplunkClient`].
 //!
 //! # What this module handles:
-//! - Listing search jobs
-//! - Cancelling search jobs
+//! - Listing search jobs (optionally filtered server-side)
+//! - Listing per-job owner and disk usage for quota reporting
+//! - Listing per-job creation time for pruning old jobs
+//! - Cancelling and finalizing search jobs
 //! - Deleting search jobs
 //!
 //! # What this module does NOT handle:
@@ -13,7 +15,7 @@
 use crate::client::SplunkClient;
 use crate::endpoints;
 use crate::error::Result;
-use crate::models::SearchJobStatus;
+use crate::models::{JobAge, JobFilter, JobUsage, SearchJobStatus};
 
 impl SplunkClient {
     /// List all search jobs.
@@ -21,6 +23,59 @@ impl SplunkClient {
         &self,
         count: Option<usize>,
         offset: Option<usize>,
+    ) -> Result<Vec<SearchJobStatus>> {
+        self.list_jobs_filtered(count, offset, &JobFilter::default())
+            .await
+    }
+
+    /// List every search job with its owner, state, and artifact disk usage.
+    pub async fn list_job_usage(&self) -> Result<Vec<JobUsage>> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("list_job_usage"),
+            |__token| async move {
+                endpoints::list_job_usage(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// List every search job matching `filter` with its owner, state, and creation time.
+    pub async fn list_job_ages(&self, filter: &JobFilter) -> Result<Vec<JobAge>> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("list_job_ages"),
+            |__token| async move {
+                endpoints::list_job_ages(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    filter,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// List search jobs matching a server-side owner/app/status filter.
+    ///
+    /// The filter is applied by Splunk before pagination, so `count` and
+    /// `offset` page through matching jobs only.
+    pub async fn list_jobs_filtered(
+        &self,
+        count: Option<usize>,
+        offset: Option<usize>,
+        filter: &JobFilter,
     ) -> Result<Vec<SearchJobStatus>> {
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("list_jobs"),
@@ -31,6 +86,7 @@ impl SplunkClient {
                     &__token,
                     count,
                     offset,
+                    filter,
                     self.max_retries,
                     self.metrics.as_ref(),
                     self.circuit_breaker.as_deref(),
@@ -61,6 +117,47 @@ impl SplunkClient {
         .await
     }
 
+    /// Finalize a running search job, keeping the results gathered so far.
+    pub async fn finalize_job(&self, sid: &str) -> Result<()> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("finalize_job"),
+            |__token| async move {
+                endpoints::finalize_job(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    sid,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Set a search job's artifact TTL to `ttl_secs` seconds from now.
+    pub async fn set_job_ttl(&self, sid: &str, ttl_secs: u64) -> Result<()> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("set_job_ttl"),
+            |__token| async move {
+                endpoints::set_job_ttl(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    sid,
+                    ttl_secs,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
     /// Delete a search job.
     pub async fn delete_job(&self, sid: &str) -> Result<()> {
         self.execute_request(
@@ -3,6 +3,8 @@
 //! # What this module handles:
 //! - Getting license usage information
 //! - Listing license pools, stacks, and installed licenses
+//! - Listing licenser messages and slaves
+//! - Summarizing daily license usage history
 //! - Installing license files
 //! - Managing license pools (create, modify, delete)
 //! - Activating/deactivating licenses
@@ -17,8 +19,9 @@ use crate::client::SplunkClient;
 use crate::endpoints;
 use crate::error::{ClientError, Result};
 use crate::models::{
-    CreatePoolParams, InstalledLicense, LicenseActivationResult, LicenseInstallResult, LicensePool,
-    LicenseStack, LicenseUsage, ModifyPoolParams,
+    CreatePoolParams, InstalledLicense, LicenseActivationResult, LicenseInstallResult,
+    LicenseMessage, LicensePool, LicenseSlave, LicenseStack, LicenseUsage, LicenseUsageDay,
+    ModifyPoolParams,
 };
 
 impl SplunkClient {
@@ -79,6 +82,68 @@ impl SplunkClient {
         .await
     }
 
+    /// List licenser messages (warnings, violations, and other license notices).
+    pub async fn list_license_messages(&self) -> Result<Vec<LicenseMessage>> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("list_license_messages"),
+            |__token| async move {
+                endpoints::list_license_messages(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// List license slaves reporting to this license manager.
+    pub async fn list_license_slaves(&self) -> Result<Vec<LicenseSlave>> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("list_license_slaves"),
+            |__token| async move {
+                endpoints::list_license_slaves(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Summarize daily license usage over the last `days` days, oldest first.
+    ///
+    /// Runs a search over `license_usage.log`, so it needs access to `_internal`.
+    pub async fn get_license_usage_history(&self, days: u32) -> Result<Vec<LicenseUsageDay>> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation(
+                "get_license_usage_history",
+            ),
+            |__token| async move {
+                endpoints::get_license_usage_history(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    days,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
     /// List all installed licenses.
     pub async fn list_installed_licenses(&self) -> Result<Vec<InstalledLicense>> {
         self.execute_request(
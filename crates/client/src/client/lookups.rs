@@ -4,7 +4,9 @@
 //! - Listing lookup table files
 //! - Downloading lookup table files (CSV content)
 //! - Uploading/replacing lookup table files
+//! - Reading and saving parsed lookup contents (download/upload round trip)
 //! - Deleting lookup table files
+//! - Falling back to the client's default namespace for unset app/owner
 //!
 //! # What this module does NOT handle:
 //! - KV store lookups (different endpoint)
@@ -12,19 +14,23 @@
 
 use crate::client::SplunkClient;
 use crate::endpoints;
-use crate::error::Result;
-use crate::models::{LookupTable, UploadLookupParams};
+use crate::error::{ClientError, Result};
+use crate::models::{LookupContent, LookupTable, Namespace, UploadLookupParams};
 
 impl SplunkClient {
     /// List all lookup table files.
     ///
     /// Returns CSV-based lookup files stored in Splunk.
-    /// KV store lookups are managed via a different endpoint.
+    /// KV store lookups are managed via a different endpoint. With a namespace,
+    /// only lookup files defined in that app are listed.
     pub async fn list_lookup_tables(
         &self,
         count: Option<usize>,
         offset: Option<usize>,
+        namespace: Option<&Namespace>,
     ) -> Result<Vec<LookupTable>> {
+        let namespace = self.resolve_namespace(namespace);
+        let namespace = namespace.as_ref();
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("list_lookup_tables"),
             |__token| async move {
@@ -34,6 +40,7 @@ impl SplunkClient {
                     &__token,
                     count,
                     offset,
+                    namespace,
                     self.max_retries,
                     self.metrics.as_ref(),
                     self.circuit_breaker.as_deref(),
@@ -48,8 +55,8 @@ impl SplunkClient {
     ///
     /// # Arguments
     /// * `name` - The lookup name
-    /// * `app` - Optional app namespace (defaults to "search")
-    /// * `owner` - Optional owner namespace (defaults to "-" for all users)
+    /// * `app` - Optional app namespace (defaults to the client's namespace, then "search")
+    /// * `owner` - Optional owner namespace (defaults to the client's namespace, then "-")
     ///
     /// # Returns
     /// The raw CSV content as a string
@@ -59,6 +66,9 @@ impl SplunkClient {
         app: Option<&str>,
         owner: Option<&str>,
     ) -> Result<String> {
+        let defaults = self.namespace().unwrap_or_default();
+        let app = app.or(defaults.app.as_deref());
+        let owner = owner.or(defaults.owner.as_deref());
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("download_lookup_table"),
             |__token| async move {
@@ -85,37 +95,92 @@ impl SplunkClient {
     /// being consumed on the first attempt.
     ///
     /// # Arguments
-    /// * `params` - Upload parameters including name, filename, and content
+    /// * `params` - Upload parameters including name, filename, and content; unset
+    ///   app, owner and sharing fall back to the client's namespace
     ///
     /// # Returns
     /// The created/updated lookup table metadata
     pub async fn upload_lookup_table(&self, params: &UploadLookupParams) -> Result<LookupTable> {
+        let defaults = self.namespace().unwrap_or_default();
+        let params = UploadLookupParams {
+            app: params.app.clone().or(defaults.app),
+            owner: params.owner.clone().or(defaults.owner),
+            sharing: params.sharing.clone().or(defaults.sharing),
+            ..params.clone()
+        };
+
         // Get auth token (no retry for upload due to body consumption)
         let token = self.get_auth_token().await?;
-        endpoints::upload_lookup_table(
+        self.with_hooks(endpoints::upload_lookup_table(
             &self.http,
             &self.base_url,
             &token,
-            params,
+            &params,
             self.max_retries,
             self.metrics.as_ref(),
             self.circuit_breaker.as_deref(),
-        )
+        ))
         .await
     }
 
+    /// Download a lookup table file and parse it into header and rows.
+    ///
+    /// # Arguments
+    /// * `name` - The lookup name
+    /// * `app` - Optional app namespace (defaults to the client's namespace, then "search")
+    /// * `owner` - Optional owner namespace (defaults to the client's namespace, then "-")
+    pub async fn get_lookup_contents(
+        &self,
+        name: &str,
+        app: Option<&str>,
+        owner: Option<&str>,
+    ) -> Result<LookupContent> {
+        let csv = self.download_lookup_table(name, app, owner).await?;
+        LookupContent::from_csv(&csv).map_err(|e| {
+            ClientError::InvalidResponse(format!("Lookup '{}' is not valid CSV: {}", name, e))
+        })
+    }
+
+    /// Replace a lookup table file with `content` by uploading it as CSV.
+    ///
+    /// # Arguments
+    /// * `name` - The lookup name (also used as the uploaded filename)
+    /// * `app` - Optional app namespace (defaults to the client's namespace, then "search")
+    /// * `owner` - Optional owner namespace (defaults to the client's namespace, then "-")
+    /// * `content` - The full lookup contents to save
+    pub async fn save_lookup_contents(
+        &self,
+        name: &str,
+        app: Option<&str>,
+        owner: Option<&str>,
+        content: &LookupContent,
+    ) -> Result<LookupTable> {
+        let params = UploadLookupParams {
+            name: name.to_string(),
+            filename: name.to_string(),
+            content: content.to_csv().into_bytes(),
+            app: app.map(str::to_string),
+            owner: owner.map(str::to_string),
+            sharing: None,
+        };
+        self.upload_lookup_table(&params).await
+    }
+
     /// Delete a lookup table file.
     ///
     /// # Arguments
     /// * `name` - The lookup name to delete
-    /// * `app` - Optional app namespace (defaults to "search")
-    /// * `owner` - Optional owner namespace (defaults to "-" for all users)
+    /// * `app` - Optional app namespace (defaults to the client's namespace, then "search")
+    /// * `owner` - Optional owner namespace (defaults to the client's namespace, then "-")
     pub async fn delete_lookup_table(
         &self,
         name: &str,
         app: Option<&str>,
         owner: Option<&str>,
     ) -> Result<()> {
+        let defaults = self.namespace().unwrap_or_default();
+        let app = app.or(defaults.app.as_deref());
+        let owner = owner.or(defaults.owner.as_deref());
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("delete_lookup_table"),
             |__token| async move {
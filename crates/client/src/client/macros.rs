@@ -3,16 +3,21 @@
 //! Responsibilities:
 //! - High-level API for macro operations with auth retry.
 //! - Route endpoint calls through the shared request executor.
+//! - Scope requests to the client's default namespace.
 
 use crate::client::SplunkClient;
 use crate::endpoints;
 use crate::endpoints::{CreateMacroRequest, UpdateMacroRequest};
 use crate::error::Result;
-use crate::models::{Macro, MacroCreateParams, MacroUpdateParams};
+use crate::models::{Macro, MacroCreateParams, MacroUpdateParams, Namespace};
 
 impl SplunkClient {
-    /// List all search macros.
-    pub async fn list_macros(&self) -> Result<Vec<Macro>> {
+    /// List all search macros, optionally only those defined in a namespace's app.
+    ///
+    /// Falls back to the client's default namespace when `namespace` is `None`.
+    pub async fn list_macros(&self, namespace: Option<&Namespace>) -> Result<Vec<Macro>> {
+        let namespace = self.resolve_namespace(namespace);
+        let namespace = namespace.as_ref();
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("list_macros"),
             |__token| async move {
@@ -20,6 +25,7 @@ impl SplunkClient {
                     &self.http,
                     &self.base_url,
                     &__token,
+                    namespace,
                     self.max_retries,
                     self.metrics.as_ref(),
                     self.circuit_breaker.as_deref(),
@@ -38,6 +44,8 @@ impl SplunkClient {
     /// # Returns
     /// The `Macro` if found, or `ClientError::NotFound` if it doesn't exist.
     pub async fn get_macro(&self, name: &str) -> Result<Macro> {
+        let namespace = self.namespace();
+        let namespace = namespace.as_ref();
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("get_macro"),
             |__token| async move {
@@ -46,6 +54,7 @@ impl SplunkClient {
                     &self.base_url,
                     &__token,
                     name,
+                    namespace,
                     self.max_retries,
                     self.metrics.as_ref(),
                     self.circuit_breaker.as_deref(),
@@ -72,6 +81,8 @@ impl SplunkClient {
             errormsg: params.errormsg.as_deref(),
         };
 
+        let namespace = self.namespace();
+        let namespace = namespace.as_ref();
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("create_macro"),
             |__token| {
@@ -82,6 +93,7 @@ impl SplunkClient {
                         &self.base_url,
                         &__token,
                         &request,
+                        namespace,
                         self.max_retries,
                         self.metrics.as_ref(),
                         self.circuit_breaker.as_deref(),
@@ -115,6 +127,8 @@ impl SplunkClient {
             errormsg: params.errormsg.as_deref(),
         };
 
+        let namespace = self.namespace();
+        let namespace = namespace.as_ref();
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("update_macro"),
             |__token| {
@@ -125,6 +139,7 @@ impl SplunkClient {
                         &self.base_url,
                         &__token,
                         &request,
+                        namespace,
                         self.max_retries,
                         self.metrics.as_ref(),
                         self.circuit_breaker.as_deref(),
@@ -144,6 +159,8 @@ impl SplunkClient {
     /// # Returns
     /// Ok(()) on success, or `ClientError::NotFound` if the macro doesn't exist.
     pub async fn delete_macro(&self, name: &str) -> Result<()> {
+        let namespace = self.namespace();
+        let namespace = namespace.as_ref();
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("delete_macro"),
             |__token| async move {
@@ -152,6 +169,7 @@ impl SplunkClient {
                     &self.base_url,
                     &__token,
                     name,
+                    namespace,
                     self.max_retries,
                     self.metrics.as_ref(),
                     self.circuit_breaker.as_deref(),
@@ -0,0 +1,58 @@
+//! Server message (bulletin board) API methods for [`SplunkClient`].
+//!
+//! # What this module handles:
+//! - Listing bulletin board messages
+//! - Dismissing a message
+//!
+//! # What this module does NOT handle:
+//! - Authentication and session management (in [`crate::client::session`])
+//! - Low-level message endpoint HTTP calls (in [`crate::endpoints::messages`])
+
+use crate::client::SplunkClient;
+use crate::endpoints;
+use crate::error::Result;
+use crate::models::ServerMessage;
+
+impl SplunkClient {
+    /// List all messages on the server's bulletin board, most severe first.
+    pub async fn list_server_messages(&self) -> Result<Vec<ServerMessage>> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("list_server_messages"),
+            |__token| async move {
+                endpoints::list_server_messages(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Dismiss (delete) a bulletin board message by name.
+    ///
+    /// # Returns
+    /// Ok(()) on success, or `ClientError::NotFound` if the message doesn't exist.
+    pub async fn delete_server_message(&self, name: &str) -> Result<()> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("delete_server_message"),
+            |__token| async move {
+                endpoints::delete_server_message(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    name,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+}
@@ -0,0 +1,43 @@
+//! Index metadata API methods for [`SplunkClient`].
+//!
+//! # What this module handles:
+//! - Host, source, and sourcetype inventories with counts and first/last seen times
+//!
+//! # What this module does NOT handle:
+//! - Authentication and session management (in [`crate::client::session`])
+//! - Low-level search job HTTP calls (in [`crate::endpoints::metadata`])
+
+use crate::client::SplunkClient;
+use crate::endpoints;
+use crate::error::Result;
+use crate::models::{MetadataEntry, MetadataType};
+
+impl SplunkClient {
+    /// List every host, source, or sourcetype with its event count,
+    /// first/last seen times, and indexes, ordered by name.
+    ///
+    /// Pass `index` to restrict the listing to one index.
+    pub async fn get_metadata(
+        &self,
+        metadata_type: MetadataType,
+        index: Option<&str>,
+    ) -> Result<Vec<MetadataEntry>> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("get_metadata"),
+            |__token| async move {
+                endpoints::get_metadata(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    metadata_type,
+                    index,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+}
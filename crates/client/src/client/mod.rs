@@ -19,6 +19,14 @@
 //! - `logs`: Log parsing and internal logs methods
 //! - `dashboards`: Dashboard management methods
 //! - `datamodels`: Data model management methods
+//! - `event_types`: Event type methods
+//! - `acl`: Knowledge object permission methods
+//! - `cloud`: Splunk Cloud detection and on-prem-only guards
+//! - `acs`: Admin Config Service (Splunk Cloud) methods
+//!
+//! Knowledge-object methods (saved searches, macros, lookups, dashboards,
+//! event types, configs) use the client's default [`Namespace`] unless the
+//! caller passes one explicitly.
 //!
 //! # What this module does NOT handle:
 //! - Direct HTTP request implementation (delegated to [`crate::endpoints`])
@@ -33,22 +41,29 @@
 pub mod builder;
 pub mod cache;
 pub mod circuit_breaker;
+pub mod rate_limiter;
 pub mod request_executor;
 mod session;
 
 // API method submodules
+mod acl;
+mod acs;
 mod alerts;
 mod apps;
 mod audit;
 mod capabilities;
+pub mod cloud;
 mod cluster;
 mod configs;
 mod dashboards;
 mod datamodels;
+mod event_types;
 mod forwarders;
 pub mod health;
 mod hec;
+mod hec_tokens;
 mod indexes;
+mod ingestion;
 mod inputs;
 mod jobs;
 mod kvstore;
@@ -56,6 +71,9 @@ mod license;
 mod logs;
 mod lookups;
 pub mod macros;
+mod messages;
+mod metadata;
+mod rest;
 mod roles;
 pub mod search;
 mod search_peers;
@@ -66,8 +84,13 @@ mod workload;
 
 use crate::auth::SessionManager;
 use crate::client::circuit_breaker::CircuitBreaker;
+use crate::client::rate_limiter::RateLimiter;
+use crate::http_trace::HttpTraceRecorder;
 use crate::metrics::MetricsCollector;
-use std::sync::Arc;
+use crate::middleware::RequestHooks;
+use crate::models::Namespace;
+use splunk_config::HealthThresholds;
+use std::sync::{Arc, RwLock};
 
 /// Splunk REST API client.
 ///
@@ -108,6 +131,20 @@ pub struct SplunkClient {
     pub(crate) cache: cache::ResponseCache,
     /// Circuit breaker for resilient API calls.
     pub(crate) circuit_breaker: Option<Arc<CircuitBreaker>>,
+    /// Request middleware, rate limiter, and HTTP trace recorder applied to every attempt.
+    pub(crate) hooks: RequestHooks,
+    /// Default namespace for knowledge-object requests; switchable at runtime.
+    pub(crate) namespace: RwLock<Option<Namespace>>,
+    /// Explicit Splunk Cloud setting; `None` means detect.
+    pub(crate) cloud: Option<bool>,
+    /// Cloud mode reported by the last server info response.
+    pub(crate) detected_cloud: RwLock<Option<bool>>,
+    /// Configured ACS stack name.
+    pub(crate) acs_stack: Option<String>,
+    /// Admin Config Service base URL (no trailing slash).
+    pub(crate) acs_url: String,
+    /// Thresholds the aggregated health check flags conditions against.
+    pub(crate) health_thresholds: HealthThresholds,
 }
 
 impl SplunkClient {
@@ -123,6 +160,11 @@ impl SplunkClient {
         &self.base_url
     }
 
+    /// Get the thresholds the aggregated health check is evaluated against.
+    pub fn health_thresholds(&self) -> &HealthThresholds {
+        &self.health_thresholds
+    }
+
     /// Create a client from configuration with optional auto-login.
     ///
     /// This is a convenience method that builds a client from the provided configuration
@@ -162,6 +204,43 @@ impl SplunkClient {
         Ok(client)
     }
 
+    /// Get the HTTP trace recorder attached to this client, if any.
+    pub fn trace_recorder(&self) -> Option<&HttpTraceRecorder> {
+        self.hooks.trace_recorder.as_ref()
+    }
+
+    /// Get the request rate limiter attached to this client, if any.
+    pub fn rate_limiter(&self) -> Option<&Arc<RateLimiter>> {
+        self.hooks.rate_limiter.as_ref()
+    }
+
+    /// Run `fut` with this client's request middleware and trace recorder active.
+    pub(crate) async fn with_hooks<F: std::future::Future>(&self, fut: F) -> F::Output {
+        self.hooks.scope(fut).await
+    }
+
+    /// Get the default namespace for knowledge-object requests, if any.
+    pub fn namespace(&self) -> Option<Namespace> {
+        self.namespace
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Switch the default namespace for knowledge-object requests.
+    ///
+    /// Takes `&self` so a client shared behind an `Arc` can be switched in place;
+    /// requests already in flight keep the namespace they started with.
+    pub fn set_namespace(&self, namespace: Option<Namespace>) {
+        *self.namespace.write().unwrap_or_else(|e| e.into_inner()) =
+            namespace.filter(|namespace| !namespace.is_empty());
+    }
+
+    /// Use `explicit` when given, falling back to the default namespace.
+    pub(crate) fn resolve_namespace(&self, explicit: Option<&Namespace>) -> Option<Namespace> {
+        explicit.cloned().or_else(|| self.namespace())
+    }
+
     /// Get a reference to the response cache.
     pub fn cache(&self) -> &cache::ResponseCache {
         &self.cache
@@ -0,0 +1,140 @@
+//! Token-bucket rate limiter for outgoing Splunk REST requests.
+//!
+//! Purpose: Keep batch operations (bulk deletes, multi-profile fan-out) below
+//! Splunk's concurrent request limits on the management port.
+//! Responsibilities: Hand out send permits at a sustained rate with a bounded burst.
+//! Non-scope: Per-endpoint budgets, server-side 429 handling (retried by the
+//! request layer), and limits shared across processes.
+//! Invariants/Assumptions: Permits are granted in request order; a waiting
+//! caller reserves its slot up front, so later callers queue behind it.
+//!
+//! The limiter applies to every HTTP attempt the client makes, including
+//! retries and session logins, because each attempt costs the server a request.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use tracing::debug;
+
+/// Token-bucket limiter shared by all requests made through a client.
+///
+/// Clone the `Arc` and pass it to several builders to share one budget across
+/// clients that talk to the same server.
+#[derive(Debug)]
+pub struct RateLimiter {
+    requests_per_second: f64,
+    burst: u32,
+    state: Mutex<BucketState>,
+}
+
+#[derive(Debug)]
+struct BucketState {
+    /// Available tokens; negative when callers have reserved future tokens.
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing `requests_per_second` sustained, with bursts of
+    /// up to `burst` requests. Both values are clamped to sensible minimums.
+    pub fn new(requests_per_second: f64, burst: u32) -> Self {
+        let requests_per_second = if requests_per_second.is_finite() && requests_per_second > 0.0 {
+            requests_per_second
+        } else {
+            f64::MIN_POSITIVE
+        };
+        let burst = burst.max(1);
+        Self {
+            requests_per_second,
+            burst,
+            state: Mutex::new(BucketState {
+                tokens: f64::from(burst),
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Sustained requests per second.
+    pub fn requests_per_second(&self) -> f64 {
+        self.requests_per_second
+    }
+
+    /// Maximum number of back-to-back requests.
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+
+    /// Wait until a request may be sent.
+    pub async fn acquire(&self) {
+        let wait = self.reserve(Instant::now());
+        if !wait.is_zero() {
+            debug!(
+                wait_ms = wait.as_millis() as u64,
+                "Rate limit reached; delaying request"
+            );
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Take a token, returning how long the caller must wait before using it.
+    fn reserve(&self, now: Instant) -> Duration {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let elapsed = now
+            .saturating_duration_since(state.last_refill)
+            .as_secs_f64();
+        state.tokens =
+            (state.tokens + elapsed * self.requests_per_second).min(f64::from(self.burst));
+        state.last_refill = now;
+        state.tokens -= 1.0;
+
+        if state.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-state.tokens / self.requests_per_second)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_burst_is_granted_immediately() {
+        let limiter = RateLimiter::new(2.0, 3);
+        let now = Instant::now();
+        for _ in 0..3 {
+            assert_eq!(limiter.reserve(now), Duration::ZERO);
+        }
+        assert_eq!(limiter.reserve(now), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_waiters_queue_behind_each_other() {
+        let limiter = RateLimiter::new(4.0, 1);
+        let now = Instant::now();
+        assert_eq!(limiter.reserve(now), Duration::ZERO);
+        assert_eq!(limiter.reserve(now), Duration::from_millis(250));
+        assert_eq!(limiter.reserve(now), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_tokens_refill_up_to_burst() {
+        let limiter = RateLimiter::new(10.0, 2);
+        let start = Instant::now();
+        limiter.reserve(start);
+        limiter.reserve(start);
+
+        // Far more than enough time to refill; capped at the burst size.
+        let later = start + Duration::from_secs(60);
+        assert_eq!(limiter.reserve(later), Duration::ZERO);
+        assert_eq!(limiter.reserve(later), Duration::ZERO);
+        assert_eq!(limiter.reserve(later), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_invalid_settings_are_clamped() {
+        let limiter = RateLimiter::new(5.0, 0);
+        assert_eq!(limiter.burst(), 1);
+    }
+}
@@ -4,6 +4,7 @@
 //! - Acquire auth tokens for requests.
 //! - Retry once on refreshable auth failures.
 //! - Centralize request-execution policy metadata, tracing, and auth-retry instrumentation.
+//! - Activate the client's request middleware and trace recorder for each request.
 //!
 //! Does NOT handle:
 //! - Endpoint-specific HTTP construction.
@@ -43,11 +44,23 @@ impl RequestPolicy {
 
 impl SplunkClient {
     /// Execute an authenticated request with the shared request pipeline.
+    ///
+    /// Requests (including any login they trigger) run through the client's
+    /// request middleware and HTTP trace recorder.
     pub(crate) async fn execute_request<T, F, Fut>(
         &self,
         policy: RequestPolicy,
         request: F,
     ) -> Result<T>
+    where
+        F: Fn(String) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        self.with_hooks(self.run_request_pipeline(policy, request))
+            .await
+    }
+
+    async fn run_request_pipeline<T, F, Fut>(&self, policy: RequestPolicy, request: F) -> Result<T>
     where
         F: Fn(String) -> Fut,
         Fut: Future<Output = Result<T>>,
@@ -0,0 +1,58 @@
+//! Raw REST API methods for [`SplunkClient`].
+//!
+//! # What this module handles:
+//! - Sending authenticated requests to endpoints without a typed wrapper
+//!
+//! # What this module does NOT handle:
+//! - Authentication and session management (in [`crate::client::session`])
+//! - Low-level raw request HTTP calls (in [`crate::endpoints::rest`])
+
+use crate::client::SplunkClient;
+use crate::endpoints::{self, RestMethod};
+use crate::error::Result;
+
+impl SplunkClient {
+    /// Send an authenticated GET or POST to any REST path (e.g. `/services/server/info`).
+    ///
+    /// This is the escape hatch for endpoints without a typed method: the request
+    /// goes through the same session renewal, retries, circuit breaker, metrics,
+    /// and trace redaction as every other call. `query` is appended to the URL,
+    /// `form_body` is sent form-encoded with POST.
+    ///
+    /// Returns the JSON body, or the raw body text as a JSON string when the
+    /// endpoint answers with something else (such as Atom XML).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClientError::InvalidRequest`](crate::ClientError::InvalidRequest)
+    /// before sending anything when `path` is outside `/services` and `/servicesNS`.
+    pub async fn raw_request(
+        &self,
+        method: RestMethod,
+        path: &str,
+        query: &[(String, String)],
+        form_body: &[(String, String)],
+    ) -> Result<serde_json::Value> {
+        let path = endpoints::normalize_rest_path(path)?;
+        let path = path.as_str();
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("raw_request"),
+            |__token| async move {
+                endpoints::raw_request(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    method,
+                    path,
+                    query,
+                    form_body,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+}
@@ -5,6 +5,8 @@
 //! - Creating new roles
 //! - Modifying existing roles
 //! - Deleting roles
+//! - Correlating role search quotas with current usage by the role's users
+//! - Resolving every role's effective capabilities into a role-capability matrix
 //!
 //! # What this module does NOT handle:
 //! - Authentication and session management (in [`crate::client::session`])
@@ -13,7 +15,7 @@
 use crate::client::SplunkClient;
 use crate::endpoints;
 use crate::error::Result;
-use crate::models::{CreateRoleParams, ModifyRoleParams, Role};
+use crate::models::{CreateRoleParams, ModifyRoleParams, Role, RoleCapabilityMatrix, RoleUsage};
 
 impl SplunkClient {
     /// List all roles.
@@ -41,6 +43,28 @@ impl SplunkClient {
         .await
     }
 
+    /// Per-role search quotas alongside the current usage of each role's users.
+    ///
+    /// Fetches all roles, users, and search jobs concurrently and attributes
+    /// each job to the roles its owner holds (see [`RoleUsage::correlate`]).
+    pub async fn role_usage(&self) -> Result<Vec<RoleUsage>> {
+        // count=0 asks Splunk for every entry rather than one page
+        let (roles, users, jobs) = tokio::try_join!(
+            self.list_roles(Some(0), None),
+            self.list_users(Some(0), None),
+            self.list_job_usage(),
+        )?;
+        Ok(RoleUsage::correlate(&roles, &users, &jobs))
+    }
+
+    /// Every role's effective capabilities, including those inherited through
+    /// imported roles (see [`RoleCapabilityMatrix::from_roles`]).
+    pub async fn role_capability_matrix(&self) -> Result<RoleCapabilityMatrix> {
+        // count=0 asks Splunk for every role so imports resolve across pages
+        let roles = self.list_roles(Some(0), None).await?;
+        Ok(RoleCapabilityMatrix::from_roles(&roles))
+    }
+
     /// Create a new role with the specified parameters.
     pub async fn create_role(&self, params: &CreateRoleParams) -> Result<Role> {
         self.execute_request(
@@ -14,7 +14,10 @@ use crate::client::SplunkClient;
 use crate::endpoints;
 use crate::endpoints::search::SearchMode;
 use crate::error::Result;
-use crate::models::{SavedSearch, SearchJobResults, SearchJobStatus, ValidateSplResponse};
+use crate::models::{
+    Namespace, SavedSearch, SavedSearchOwnership, SearchJobResults, SearchJobStatus,
+    ValidateSplResponse,
+};
 use splunk_config::constants::{
     DEFAULT_MAX_RESULTS, DEFAULT_MAX_WAIT_SECS, DEFAULT_POLL_INTERVAL_MS,
 };
@@ -29,6 +32,7 @@ use splunk_config::constants::{
 /// - Time bounds (earliest/latest)
 /// - Result limits and search mode
 /// - Real-time window configuration
+/// - Auto-finalize result threshold
 ///
 /// # What this struct does NOT handle:
 /// - Authentication or session management
@@ -38,6 +42,7 @@ use splunk_config::constants::{
 /// # Invariants
 /// - `realtime_window` is only meaningful when `search_mode` is `Some(SearchMode::Realtime)`
 /// - `wait` controls client-side polling; the server may still process asynchronously
+/// - `finalize_after` only takes effect when `wait` is true, since it relies on polling
 #[derive(Debug, Clone, Copy)]
 pub struct SearchRequest<'a> {
     /// The SPL query to execute.
@@ -54,6 +59,8 @@ pub struct SearchRequest<'a> {
     pub search_mode: Option<SearchMode>,
     /// Optional real-time window in seconds (only for Realtime mode).
     pub realtime_window: Option<u64>,
+    /// Finalize the job once this many results (or events) are available.
+    pub finalize_after: Option<usize>,
 }
 
 impl<'a> SearchRequest<'a> {
@@ -69,6 +76,7 @@ impl<'a> SearchRequest<'a> {
             max_results: None,
             search_mode: None,
             realtime_window: None,
+            finalize_after: None,
         }
     }
 
@@ -97,6 +105,12 @@ impl<'a> SearchRequest<'a> {
         self
     }
 
+    /// Finalize the job early once `threshold` results (or events) are available.
+    pub fn finalize_after(mut self, threshold: usize) -> Self {
+        self.finalize_after = Some(threshold);
+        self
+    }
+
     /// Get the effective max results count, using the default if not set.
     fn effective_max_results(&self) -> usize {
         self.max_results.unwrap_or(DEFAULT_MAX_RESULTS)
@@ -138,7 +152,7 @@ impl SplunkClient {
 
         if request.wait {
             let auth_token = self.get_auth_token().await?;
-            endpoints::wait_for_job(
+            self.with_hooks(endpoints::wait_for_job_with_finalize(
                 &self.http,
                 &self.base_url,
                 &auth_token,
@@ -146,9 +160,11 @@ impl SplunkClient {
                 DEFAULT_POLL_INTERVAL_MS,
                 DEFAULT_MAX_WAIT_SECS,
                 self.max_retries,
+                request.finalize_after,
+                None,
                 self.metrics.as_ref(),
                 self.circuit_breaker.as_deref(),
-            )
+            ))
             .await?;
         }
 
@@ -180,6 +196,8 @@ impl SplunkClient {
     /// Job creation is always non-blocking (`CreateJobOptions.wait = Some(false)`),
     /// regardless of `request.wait`, so that polling/progress remains a client concern.
     /// `request.wait` only controls whether the client polls before fetching results.
+    /// When `request.finalize_after` is set, polling finalizes the job once the
+    /// threshold is reached, so the returned results may be partial.
     pub async fn search_with_progress(
         &self,
         request: SearchRequest<'_>,
@@ -190,7 +208,7 @@ impl SplunkClient {
 
         if request.wait {
             let auth_token = self.get_auth_token().await?;
-            endpoints::search::wait_for_job_with_progress(
+            self.with_hooks(endpoints::search::wait_for_job_with_finalize(
                 &self.http,
                 &self.base_url,
                 &auth_token,
@@ -198,10 +216,11 @@ impl SplunkClient {
                 DEFAULT_POLL_INTERVAL_MS,
                 DEFAULT_MAX_WAIT_SECS,
                 self.max_retries,
+                request.finalize_after,
                 progress_cb,
                 self.metrics.as_ref(),
                 self.circuit_breaker.as_deref(),
-            )
+            ))
             .await?;
         }
 
@@ -285,12 +304,17 @@ impl SplunkClient {
         .await
     }
 
-    /// List all saved searches.
+    /// List all saved searches, optionally only those defined in a namespace's app.
+    ///
+    /// Falls back to the client's default namespace when `namespace` is `None`.
     pub async fn list_saved_searches(
         &self,
         count: Option<usize>,
         offset: Option<usize>,
+        namespace: Option<&Namespace>,
     ) -> Result<Vec<SavedSearch>> {
+        let namespace = self.resolve_namespace(namespace);
+        let namespace = namespace.as_ref();
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("list_saved_searches"),
             |__token| async move {
@@ -300,6 +324,7 @@ impl SplunkClient {
                     &__token,
                     count,
                     offset,
+                    namespace,
                     self.max_retries,
                     self.metrics.as_ref(),
                     self.circuit_breaker.as_deref(),
@@ -315,6 +340,8 @@ impl SplunkClient {
         &self,
         params: crate::models::SavedSearchCreateParams,
     ) -> Result<()> {
+        let namespace = self.namespace();
+        let namespace = namespace.as_ref();
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("create_saved_search"),
             |__token| {
@@ -326,6 +353,7 @@ impl SplunkClient {
                         &__token,
                         &params.name,
                         &params.search,
+                        namespace,
                         self.max_retries,
                         self.metrics.as_ref(),
                         self.circuit_breaker.as_deref(),
@@ -339,6 +367,8 @@ impl SplunkClient {
 
     /// Delete a saved search by name.
     pub async fn delete_saved_search(&self, name: &str) -> Result<()> {
+        let namespace = self.namespace();
+        let namespace = namespace.as_ref();
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("delete_saved_search"),
             |__token| async move {
@@ -347,6 +377,7 @@ impl SplunkClient {
                     &self.base_url,
                     &__token,
                     name,
+                    namespace,
                     self.max_retries,
                     self.metrics.as_ref(),
                     self.circuit_breaker.as_deref(),
@@ -365,6 +396,8 @@ impl SplunkClient {
     /// # Returns
     /// The `SavedSearch` if found, or `ClientError::NotFound` if it doesn't exist.
     pub async fn get_saved_search(&self, name: &str) -> Result<SavedSearch> {
+        let namespace = self.namespace();
+        let namespace = namespace.as_ref();
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("get_saved_search"),
             |__token| async move {
@@ -373,6 +406,7 @@ impl SplunkClient {
                     &self.base_url,
                     &__token,
                     name,
+                    namespace,
                     self.max_retries,
                     self.metrics.as_ref(),
                     self.circuit_breaker.as_deref(),
@@ -402,7 +436,11 @@ impl SplunkClient {
             search: params.search.as_deref(),
             description: params.description.as_deref(),
             disabled: params.disabled,
+            dispatch_earliest_time: params.dispatch_earliest_time.as_deref(),
+            dispatch_latest_time: params.dispatch_latest_time.as_deref(),
         };
+        let namespace = self.namespace();
+        let namespace = namespace.as_ref();
         self.execute_request(
             crate::client::request_executor::RequestPolicy::for_operation("update_saved_search"),
             |__token| {
@@ -414,6 +452,7 @@ impl SplunkClient {
                         &__token,
                         name,
                         &endpoint_params,
+                        namespace,
                         self.max_retries,
                         self.metrics.as_ref(),
                         self.circuit_breaker.as_deref(),
@@ -425,6 +464,110 @@ impl SplunkClient {
         .await
     }
 
+    /// List ownership and schedule details of saved searches across apps.
+    ///
+    /// # Arguments
+    /// * `app` - Restrict to a single app (all apps when `None`)
+    /// * `owner` - Restrict to saved searches owned by this user
+    pub async fn list_saved_search_ownership(
+        &self,
+        app: Option<&str>,
+        owner: Option<&str>,
+    ) -> Result<Vec<SavedSearchOwnership>> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation(
+                "list_saved_search_ownership",
+            ),
+            |__token| async move {
+                endpoints::list_saved_search_ownership(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    app,
+                    owner,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Change the owner and sharing level of a saved search.
+    ///
+    /// # Arguments
+    /// * `search` - The saved search to update (identifies name, app, and current owner)
+    /// * `new_owner` - The user that should own the saved search
+    /// * `sharing` - The sharing level to apply (`user`, `app`, or `global`)
+    ///
+    /// # Returns
+    /// Ok(()) on success, or `ClientError::NotFound` if the saved search doesn't exist.
+    pub async fn set_saved_search_owner(
+        &self,
+        search: &SavedSearchOwnership,
+        new_owner: &str,
+        sharing: &str,
+    ) -> Result<()> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("set_saved_search_acl"),
+            |__token| async move {
+                endpoints::set_saved_search_acl(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    &search.name,
+                    &search.app,
+                    &search.owner,
+                    new_owner,
+                    sharing,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Enable or disable a saved search in its own owner/app namespace.
+    ///
+    /// # Arguments
+    /// * `search` - The saved search to update (identifies name, app, and owner)
+    /// * `disabled` - Whether the saved search should be disabled
+    ///
+    /// # Returns
+    /// Ok(()) on success, or `ClientError::NotFound` if the saved search doesn't exist.
+    pub async fn set_saved_search_disabled(
+        &self,
+        search: &SavedSearchOwnership,
+        disabled: bool,
+    ) -> Result<()> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation(
+                "set_saved_search_disabled",
+            ),
+            |__token| async move {
+                endpoints::set_saved_search_disabled(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    &search.name,
+                    &search.app,
+                    &search.owner,
+                    disabled,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
     /// Validate SPL syntax without executing the search.
     ///
     /// Sends the query to Splunk's search parser endpoint to check for
@@ -486,6 +629,7 @@ mod tests {
         assert_eq!(req.max_results, None);
         assert_eq!(req.search_mode, None);
         assert_eq!(req.realtime_window, None);
+        assert_eq!(req.finalize_after, None);
     }
 
     #[test]
@@ -494,13 +638,15 @@ mod tests {
             .time_bounds("-24h", "now")
             .max_results(100)
             .search_mode(SearchMode::Realtime)
-            .realtime_window(60);
+            .realtime_window(60)
+            .finalize_after(50);
 
         assert_eq!(req.earliest_time, Some("-24h"));
         assert_eq!(req.latest_time, Some("now"));
         assert_eq!(req.max_results, Some(100));
         assert_eq!(req.search_mode, Some(SearchMode::Realtime));
         assert_eq!(req.realtime_window, Some(60));
+        assert_eq!(req.finalize_after, Some(50));
     }
 
     #[test]
@@ -2,15 +2,15 @@
 //!
 //! # What this module handles:
 //! - Listing distributed search peers
+//! - Adding, removing, and quarantining search peers
 //!
 //! # What this module does NOT handle:
-//! - Creating or modifying search peers (not supported by this API)
 //! - Low-level search peer endpoint HTTP calls (in [`crate::endpoints::search_peers`])
 
 use crate::client::SplunkClient;
 use crate::endpoints;
 use crate::error::Result;
-use crate::models::SearchPeer;
+use crate::models::{AddSearchPeerParams, SearchPeer};
 
 impl SplunkClient {
     /// List all distributed search peers.
@@ -53,4 +53,86 @@ impl SplunkClient {
         )
         .await
     }
+
+    /// Add a distributed search peer.
+    pub async fn add_search_peer(&self, params: &AddSearchPeerParams) -> Result<()> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("add_search_peer"),
+            |__token| async move {
+                endpoints::add_search_peer(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    params,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Remove a distributed search peer.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Peer URI (`https://host:port`) or entry name (`host:port`)
+    pub async fn remove_search_peer(&self, name: &str) -> Result<()> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("remove_search_peer"),
+            |__token| async move {
+                endpoints::remove_search_peer(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    name,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Quarantine or unquarantine a distributed search peer.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Peer URI (`https://host:port`) or entry name (`host:port`)
+    /// * `quarantine` - true to quarantine, false to return the peer to service
+    pub async fn set_search_peer_quarantine(&self, name: &str, quarantine: bool) -> Result<()> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation(
+                "set_search_peer_quarantine",
+            ),
+            |__token| async move {
+                endpoints::set_search_peer_quarantine(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    name,
+                    quarantine,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Quarantine a distributed search peer, excluding it from new searches.
+    pub async fn quarantine_search_peer(&self, name: &str) -> Result<()> {
+        self.set_search_peer_quarantine(name, true).await
+    }
+
+    /// Return a quarantined search peer to service.
+    pub async fn unquarantine_search_peer(&self, name: &str) -> Result<()> {
+        self.set_search_peer_quarantine(name, false).await
+    }
 }
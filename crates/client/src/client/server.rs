@@ -3,23 +3,55 @@
 //! # What this module handles:
 //! - Getting server information
 //! - Getting system health status
+//! - Getting disk space per partition
+//! - Getting the concurrent search limits
+//! - Restarting splunkd and waiting for it to come back
+//! - Detecting whether a restart is required
 //!
 //! # What this module does NOT handle:
 //! - Server configuration management (not yet implemented)
 //! - Low-level server endpoint HTTP calls (in [`crate::endpoints::server`])
 
+use std::time::{Duration, Instant};
+
+use tracing::debug;
+
 use crate::client::SplunkClient;
 use crate::endpoints;
-use crate::error::Result;
-use crate::models::{ServerInfo, SplunkHealth};
+use crate::error::{ClientError, Result};
+use crate::models::{PartitionSpace, SearchConcurrencyLimits, ServerInfo, SplunkHealth};
 
 impl SplunkClient {
     /// Get server information.
+    ///
+    /// Also records whether the server reports itself as Splunk Cloud.
     pub async fn get_server_info(&self) -> Result<ServerInfo> {
+        let info = self
+            .execute_request(
+                crate::client::request_executor::RequestPolicy::for_operation("get_server_info"),
+                |__token| async move {
+                    endpoints::get_server_info(
+                        &self.http,
+                        &self.base_url,
+                        &__token,
+                        self.max_retries,
+                        self.metrics.as_ref(),
+                        self.circuit_breaker.as_deref(),
+                    )
+                    .await
+                },
+            )
+            .await?;
+        self.record_server_info(&info);
+        Ok(info)
+    }
+
+    /// Get system-wide health information.
+    pub async fn get_health(&self) -> Result<SplunkHealth> {
         self.execute_request(
-            crate::client::request_executor::RequestPolicy::for_operation("get_server_info"),
+            crate::client::request_executor::RequestPolicy::for_operation("get_health"),
             |__token| async move {
-                endpoints::get_server_info(
+                endpoints::get_health(
                     &self.http,
                     &self.base_url,
                     &__token,
@@ -33,12 +65,33 @@ impl SplunkClient {
         .await
     }
 
-    /// Get system-wide health information.
-    pub async fn get_health(&self) -> Result<SplunkHealth> {
+    /// Get disk space on each partition splunkd writes to.
+    pub async fn get_partitions_space(&self) -> Result<Vec<PartitionSpace>> {
         self.execute_request(
-            crate::client::request_executor::RequestPolicy::for_operation("get_health"),
+            crate::client::request_executor::RequestPolicy::for_operation("get_partitions_space"),
             |__token| async move {
-                endpoints::get_health(
+                endpoints::get_partitions_space(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Get the concurrent search limits splunkd derives from `limits.conf`.
+    pub async fn get_search_concurrency_limits(&self) -> Result<SearchConcurrencyLimits> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation(
+                "get_search_concurrency_limits",
+            ),
+            |__token| async move {
+                endpoints::get_search_concurrency_limits(
                     &self.http,
                     &self.base_url,
                     &__token,
@@ -51,4 +104,107 @@ impl SplunkClient {
         )
         .await
     }
+
+    /// Restart splunkd.
+    ///
+    /// The request is sent once without transport retries: a retried POST could
+    /// restart the server twice.
+    pub async fn restart_server(&self) -> Result<()> {
+        self.ensure_on_prem(
+            "restart_server",
+            "request a restart with the Admin Config Service (acs restart current-stack)",
+        )?;
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("restart_server"),
+            |__token| async move {
+                endpoints::restart_server(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    0,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Check whether splunkd has pending changes that need a restart.
+    ///
+    /// True when server info reports `restart_required` or a restart-required
+    /// message is on the bulletin board.
+    pub async fn is_restart_required(&self) -> Result<bool> {
+        if self.get_server_info().await?.restart_required {
+            return Ok(true);
+        }
+        let messages = self.list_server_messages().await?;
+        Ok(messages.iter().any(|message| message.is_restart_required()))
+    }
+
+    /// Wait for splunkd to come back after [`restart_server`](Self::restart_server).
+    ///
+    /// Polls server info every `poll_interval` until the server answers with a
+    /// `startup_time` different from `previous_startup_time`, or (when the
+    /// startup time is unknown) answers again after having been unreachable.
+    /// Polls bypass the circuit breaker so the outage does not trip it.
+    ///
+    /// # Errors
+    /// Returns `ClientError::OperationTimeout` if the server is not back within `timeout`.
+    pub async fn wait_for_restart(
+        &self,
+        previous_startup_time: Option<u64>,
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> Result<ServerInfo> {
+        let start = Instant::now();
+        let mut seen_down = false;
+
+        loop {
+            tokio::time::sleep(poll_interval).await;
+
+            let result = self
+                .execute_request(
+                    crate::client::request_executor::RequestPolicy::for_operation(
+                        "wait_for_restart",
+                    ),
+                    |__token| async move {
+                        endpoints::get_server_info(
+                            &self.http,
+                            &self.base_url,
+                            &__token,
+                            0,
+                            self.metrics.as_ref(),
+                            None,
+                        )
+                        .await
+                    },
+                )
+                .await;
+
+            match result {
+                Ok(info) => {
+                    let restarted = match (previous_startup_time, info.startup_time) {
+                        (Some(before), Some(now)) => now != before,
+                        _ => seen_down,
+                    };
+                    if restarted {
+                        return Ok(info);
+                    }
+                }
+                Err(error) => {
+                    debug!("Server not reachable yet: {}", error);
+                    seen_down = true;
+                }
+            }
+
+            if start.elapsed() >= timeout {
+                return Err(ClientError::OperationTimeout {
+                    operation: "wait_for_restart",
+                    timeout,
+                });
+            }
+        }
+    }
 }
@@ -12,12 +12,14 @@
 //! - [`get_auth_token()`] requires `&mut self` because it may trigger a login call
 //! - API token authentication never triggers login; the token is returned directly
 //! - Session authentication proactively refreshes tokens before they expire
+//! - Keep-alive callers renew via [`renew_session()`] when [`time_until_session_renewal()`] elapses
 
 use crate::auth::AuthStrategy;
 use crate::client::SplunkClient;
 use crate::endpoints;
 use crate::error::{ClientError, Result};
 use secrecy::ExposeSecret;
+use std::time::Duration;
 
 impl SplunkClient {
     /// Get the current authentication token, logging in if necessary.
@@ -69,6 +71,31 @@ impl SplunkClient {
         self.session_manager.is_api_token()
     }
 
+    /// Time left before the current session token expires.
+    ///
+    /// Returns None for API token auth or before the first login.
+    pub async fn session_remaining(&self) -> Option<Duration> {
+        self.session_manager.session_remaining().await
+    }
+
+    /// Time until the session should be proactively renewed.
+    ///
+    /// Returns zero before the first login and None for API token auth, which
+    /// has no session to keep alive.
+    pub async fn time_until_session_renewal(&self) -> Option<Duration> {
+        self.session_manager.time_until_renewal().await
+    }
+
+    /// Renew the session by logging in again, returning the new session lifetime.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClientError::AuthFailed`] for API token auth or if login fails.
+    pub async fn renew_session(&self) -> Result<Duration> {
+        self.login().await?;
+        Ok(Duration::from_secs(self.session_ttl_seconds))
+    }
+
     /// Login with username/password to get a session token.
     ///
     /// This method is only valid for [`AuthStrategy::SessionToken`] authentication.
@@ -81,16 +108,17 @@ impl SplunkClient {
     /// Returns [`ClientError::ApiError`] if the login request fails.
     pub async fn login(&self) -> Result<String> {
         if let AuthStrategy::SessionToken { username, password } = self.session_manager.strategy() {
-            let token = endpoints::login(
-                &self.http,
-                &self.base_url,
-                username,
-                password.expose_secret(),
-                self.max_retries,
-                self.metrics.as_ref(),
-                self.circuit_breaker.as_deref(),
-            )
-            .await?;
+            let token = self
+                .with_hooks(endpoints::login(
+                    &self.http,
+                    &self.base_url,
+                    username,
+                    password.expose_secret(),
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                ))
+                .await?;
 
             // Use configured session TTL for proactive refresh
             let token_clone = token.clone();
@@ -2,9 +2,11 @@
 //!
 //! # What this module handles:
 //! - Listing users
+//! - Resolving the currently authenticated user and their capabilities
 //! - Creating new users
 //! - Modifying existing users
 //! - Deleting users
+//! - Unlocking locked-out users and reading the password/lockout policy
 //!
 //! # What this module does NOT handle:
 //! - Authentication and session management (in [`crate::client::session`])
@@ -13,7 +15,7 @@
 use crate::client::SplunkClient;
 use crate::endpoints;
 use crate::error::Result;
-use crate::models::{CreateUserParams, ModifyUserParams, User};
+use crate::models::{CreateUserParams, CurrentContext, ModifyUserParams, PasswordPolicy, User};
 
 impl SplunkClient {
     /// List all users.
@@ -41,6 +43,44 @@ impl SplunkClient {
         .await
     }
 
+    /// Get the name of the user the client is authenticated as.
+    pub async fn get_current_username(&self) -> Result<String> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("get_current_username"),
+            |__token| async move {
+                endpoints::get_current_username(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Get the authenticated user's roles and effective capabilities.
+    pub async fn current_context(&self) -> Result<CurrentContext> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("current_context"),
+            |__token| async move {
+                endpoints::get_current_context(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
     /// Create a new user with the specified parameters.
     pub async fn create_user(&self, params: &CreateUserParams) -> Result<User> {
         self.execute_request(
@@ -101,4 +141,43 @@ impl SplunkClient {
         )
         .await
     }
+
+    /// Unlock a user that was locked out after too many failed logins.
+    pub async fn unlock_user(&self, name: &str) -> Result<User> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("unlock_user"),
+            |__token| async move {
+                endpoints::unlock_user(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    name,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    /// Get the password and lockout policy for native Splunk users.
+    pub async fn get_password_policy(&self) -> Result<PasswordPolicy> {
+        self.execute_request(
+            crate::client::request_executor::RequestPolicy::for_operation("get_password_policy"),
+            |__token| async move {
+                endpoints::get_password_policy(
+                    &self.http,
+                    &self.base_url,
+                    &__token,
+                    self.max_retries,
+                    self.metrics.as_ref(),
+                    self.circuit_breaker.as_deref(),
+                )
+                .await
+            },
+        )
+        .await
+    }
 }
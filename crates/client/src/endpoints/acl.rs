@@ -0,0 +1,233 @@
+//! Knowledge object permission (ACL) REST API endpoints.
+//!
+//! Responsibilities:
+//! - Read an object's ACL from its entry under `/servicesNS/{owner}/{app}`.
+//! - Post ACL changes to `/servicesNS/{owner}/{app}/{collection}/{name}/acl`.
+//! - List the ACLs of every object of a kind across owners via `/servicesNS/-/{app}`.
+//!
+//! Does NOT handle:
+//! - Does not handle auth retry (see client module).
+//! - Does not merge partial updates with the current ACL (see client module).
+
+use reqwest::Client;
+use tracing::debug;
+
+use crate::client::circuit_breaker::CircuitBreaker;
+use crate::endpoints::{encode_path_segment, namespaced_path, send_request_with_retry};
+use crate::error::{ClientError, Result};
+use crate::metrics::MetricsCollector;
+use crate::models::{Entry, KnowledgeObjectKind, Namespace, ObjectAcl, SplunkResponse};
+
+/// Get the permissions of a knowledge object.
+///
+/// # Arguments
+/// * `kind` - The object kind (selects the REST collection)
+/// * `name` - The object name
+/// * `namespace` - Optional owner/app context used to find the object
+///
+/// # Returns
+/// The object's `ObjectAcl`, or `ClientError::NotFound` if it doesn't exist.
+#[allow(clippy::too_many_arguments)]
+pub async fn get_object_acl(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    kind: KnowledgeObjectKind,
+    name: &str,
+    namespace: Option<&Namespace>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<ObjectAcl> {
+    debug!("Getting ACL of {} '{}'", kind, name);
+
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(
+            namespace,
+            &format!("{}/{}", kind.rest_path(), encode_path_segment(name))
+        )
+    );
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json")]);
+
+    let endpoint = format!("/services/{}/{{name}}", kind.rest_path());
+    let response = match send_request_with_retry(
+        builder,
+        max_retries,
+        &endpoint,
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await
+    {
+        Ok(resp) => resp,
+        Err(ClientError::ApiError { status: 404, .. }) => {
+            return Err(not_found(kind, name));
+        }
+        Err(e) => return Err(e),
+    };
+
+    let body: SplunkResponse<serde_json::Value> = response.json().await.map_err(|e| {
+        ClientError::InvalidResponse(format!("Failed to parse {} ACL response: {}", kind, e))
+    })?;
+
+    let entry = body
+        .entry
+        .into_iter()
+        .next()
+        .ok_or_else(|| not_found(kind, name))?;
+    entry_acl(kind, entry)
+        .ok_or_else(|| ClientError::InvalidResponse(format!("Missing ACL for {} '{}'", kind, name)))
+}
+
+/// List the permissions of every object of `kind`, across all owners.
+///
+/// Queries `/servicesNS/-/{app}/{collection}` with `count=0`, so private
+/// (user-shared) objects of every owner are included. Entries without an ACL
+/// are skipped.
+///
+/// # Arguments
+/// * `kind` - The object kind (selects the REST collection)
+/// * `app` - Restrict to a single app (all apps when `None`)
+#[allow(clippy::too_many_arguments)]
+pub async fn list_object_acls(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    kind: KnowledgeObjectKind,
+    app: Option<&str>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<ObjectAcl>> {
+    debug!("Listing {} ACLs (app={:?})", kind, app);
+
+    let app_segment = app
+        .map(encode_path_segment)
+        .unwrap_or_else(|| "-".to_string());
+    let url = format!(
+        "{}/servicesNS/-/{}/{}",
+        base_url,
+        app_segment,
+        kind.rest_path()
+    );
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json"), ("count", "0")]);
+
+    let endpoint = format!("/servicesNS/-/{{app}}/{}", kind.rest_path());
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        &endpoint,
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let body: SplunkResponse<serde_json::Value> = response.json().await.map_err(|e| {
+        ClientError::InvalidResponse(format!("Failed to parse {} ACL listing: {}", kind, e))
+    })?;
+
+    Ok(body
+        .entry
+        .into_iter()
+        .filter_map(|entry| entry_acl(kind, entry))
+        .collect())
+}
+
+fn entry_acl(kind: KnowledgeObjectKind, entry: Entry<serde_json::Value>) -> Option<ObjectAcl> {
+    let acl = entry.acl?;
+    let (read, write) = acl.perms.map(|p| (p.read, p.write)).unwrap_or_default();
+
+    Some(ObjectAcl {
+        name: entry.name,
+        kind,
+        app: acl.app,
+        owner: acl.owner,
+        sharing: acl.sharing.unwrap_or_else(|| "user".to_string()),
+        read,
+        write,
+        can_change_perms: acl.can_change_perms,
+    })
+}
+
+/// Replace the permissions of a knowledge object.
+///
+/// Posts to `/servicesNS/{current.owner}/{current.app}/{collection}/{name}/acl`.
+/// Splunk requires `sharing` and `owner` on every ACL update, so `acl` must be
+/// the complete desired ACL. Role lists are only sent for app and global sharing.
+#[allow(clippy::too_many_arguments)]
+pub async fn set_object_acl(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    current: &ObjectAcl,
+    acl: &ObjectAcl,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    debug!(
+        "Setting ACL of {} '{}' in app '{}' (sharing={}, owner={})",
+        current.kind, current.name, current.app, acl.sharing, acl.owner
+    );
+
+    let url = format!(
+        "{}/servicesNS/{}/{}/{}/{}/acl",
+        base_url,
+        encode_path_segment(&current.owner),
+        encode_path_segment(&current.app),
+        current.kind.rest_path(),
+        encode_path_segment(&current.name)
+    );
+
+    let mut form: Vec<(&str, String)> = vec![
+        ("sharing", acl.sharing.clone()),
+        ("owner", acl.owner.clone()),
+    ];
+    if acl.sharing != "user" {
+        form.push(("perms.read", acl.read.join(",")));
+        form.push(("perms.write", acl.write.join(",")));
+    }
+
+    let builder = client
+        .post(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json")])
+        .form(&form);
+
+    let endpoint = format!(
+        "/servicesNS/{{owner}}/{{app}}/{}/{{name}}/acl",
+        current.kind.rest_path()
+    );
+    match send_request_with_retry(
+        builder,
+        max_retries,
+        &endpoint,
+        "POST",
+        metrics,
+        circuit_breaker,
+    )
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(ClientError::ApiError { status: 404, .. }) => {
+            Err(not_found(current.kind, &current.name))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+fn not_found(kind: KnowledgeObjectKind, name: &str) -> ClientError {
+    ClientError::NotFound(format!("No {} named '{}' found", kind, name))
+}
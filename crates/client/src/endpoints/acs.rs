@@ -0,0 +1,230 @@
+//! Admin Config Service (ACS) endpoints for Splunk Cloud Platform.
+//!
+//! This module provides low-level HTTP endpoint functions for the ACS
+//! `adminconfig/v2` API, which manages Splunk Cloud stacks from outside the
+//! management port.
+//!
+//! # What this module handles:
+//! - Listing, creating, and deleting indexes
+//! - Listing, creating, and deleting HEC tokens
+//!
+//! # What this module does NOT handle:
+//! - Cloud detection and stack resolution (see [`crate::client::cloud`])
+//! - Authentication retry logic (handled by [`crate::client`])
+//!
+//! # Invariants
+//! - Every URL has the form `{acs_url}/{stack}/adminconfig/v2/...`
+//! - ACS bodies are plain JSON, not Splunk's `entry` envelope
+
+use reqwest::Client;
+
+use crate::client::circuit_breaker::CircuitBreaker;
+use crate::endpoints::{encode_path_segment, send_request_with_retry};
+use crate::error::{ClientError, Result};
+use crate::metrics::MetricsCollector;
+use crate::models::{
+    AcsHecToken, AcsHecTokenCreateParams, AcsHecTokenListResponse, AcsHecTokenResponse, AcsIndex,
+    AcsIndexCreateParams,
+};
+
+const INDEXES_PATH: &str = "/adminconfig/v2/indexes";
+const HEC_TOKENS_PATH: &str = "/adminconfig/v2/inputs/http-event-collectors";
+
+fn acs_endpoint(acs_url: &str, stack: &str, path: &str) -> String {
+    format!("{}/{}{}", acs_url, encode_path_segment(stack), path)
+}
+
+/// List the indexes of a Splunk Cloud stack.
+#[allow(clippy::too_many_arguments)]
+pub async fn list_acs_indexes(
+    client: &Client,
+    acs_url: &str,
+    stack: &str,
+    auth_token: &str,
+    count: Option<usize>,
+    offset: Option<usize>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<AcsIndex>> {
+    let mut query_params = Vec::new();
+    if let Some(count) = count {
+        query_params.push(("count", count.to_string()));
+    }
+    if let Some(offset) = offset {
+        query_params.push(("offset", offset.to_string()));
+    }
+
+    let builder = client
+        .get(acs_endpoint(acs_url, stack, INDEXES_PATH))
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&query_params);
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        INDEXES_PATH,
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    response.json().await.map_err(|e| {
+        ClientError::InvalidResponse(format!("Failed to parse ACS indexes response: {}", e))
+    })
+}
+
+/// Create an index on a Splunk Cloud stack.
+///
+/// ACS accepts the request and provisions the index asynchronously.
+#[allow(clippy::too_many_arguments)]
+pub async fn create_acs_index(
+    client: &Client,
+    acs_url: &str,
+    stack: &str,
+    auth_token: &str,
+    params: &AcsIndexCreateParams,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<AcsIndex> {
+    let builder = client
+        .post(acs_endpoint(acs_url, stack, INDEXES_PATH))
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .json(params);
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        INDEXES_PATH,
+        "POST",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    response.json().await.map_err(|e| {
+        ClientError::InvalidResponse(format!("Failed to parse ACS index response: {}", e))
+    })
+}
+
+/// Delete an index from a Splunk Cloud stack.
+#[allow(clippy::too_many_arguments)]
+pub async fn delete_acs_index(
+    client: &Client,
+    acs_url: &str,
+    stack: &str,
+    auth_token: &str,
+    name: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    let path = format!("{}/{}", INDEXES_PATH, encode_path_segment(name));
+    let builder = client
+        .delete(acs_endpoint(acs_url, stack, &path))
+        .header("Authorization", format!("Bearer {}", auth_token));
+    send_request_with_retry(
+        builder,
+        max_retries,
+        INDEXES_PATH,
+        "DELETE",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+    Ok(())
+}
+
+/// List the HEC tokens of a Splunk Cloud stack.
+#[allow(clippy::too_many_arguments)]
+pub async fn list_acs_hec_tokens(
+    client: &Client,
+    acs_url: &str,
+    stack: &str,
+    auth_token: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<AcsHecToken>> {
+    let builder = client
+        .get(acs_endpoint(acs_url, stack, HEC_TOKENS_PATH))
+        .header("Authorization", format!("Bearer {}", auth_token));
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        HEC_TOKENS_PATH,
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: AcsHecTokenListResponse = response.json().await.map_err(|e| {
+        ClientError::InvalidResponse(format!("Failed to parse ACS HEC tokens response: {}", e))
+    })?;
+    Ok(resp
+        .http_event_collectors
+        .into_iter()
+        .map(AcsHecToken::from)
+        .collect())
+}
+
+/// Create a HEC token on a Splunk Cloud stack.
+#[allow(clippy::too_many_arguments)]
+pub async fn create_acs_hec_token(
+    client: &Client,
+    acs_url: &str,
+    stack: &str,
+    auth_token: &str,
+    params: &AcsHecTokenCreateParams,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<AcsHecToken> {
+    let builder = client
+        .post(acs_endpoint(acs_url, stack, HEC_TOKENS_PATH))
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .json(params);
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        HEC_TOKENS_PATH,
+        "POST",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: AcsHecTokenResponse = response.json().await.map_err(|e| {
+        ClientError::InvalidResponse(format!("Failed to parse ACS HEC token response: {}", e))
+    })?;
+    Ok(resp.http_event_collector.into())
+}
+
+/// Delete a HEC token from a Splunk Cloud stack.
+#[allow(clippy::too_many_arguments)]
+pub async fn delete_acs_hec_token(
+    client: &Client,
+    acs_url: &str,
+    stack: &str,
+    auth_token: &str,
+    name: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    let path = format!("{}/{}", HEC_TOKENS_PATH, encode_path_segment(name));
+    let builder = client
+        .delete(acs_endpoint(acs_url, stack, &path))
+        .header("Authorization", format!("Bearer {}", auth_token));
+    send_request_with_retry(
+        builder,
+        max_retries,
+        HEC_TOKENS_PATH,
+        "DELETE",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+    Ok(())
+}
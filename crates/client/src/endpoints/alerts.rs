@@ -5,6 +5,8 @@
 //! # What this module handles:
 //! - Fired alerts listing and retrieval
 //! - Alert configuration access
+//! - Alert action listing and the actions a saved search triggers
+//! - Dispatching test-fire searches and collecting their job messages
 //!
 //! # What this module does NOT handle:
 //! - High-level alert operations (see [`crate::client::alerts`])
@@ -14,11 +16,15 @@ use reqwest::Client;
 use tracing::debug;
 
 use crate::client::circuit_breaker::CircuitBreaker;
-use crate::endpoints::encode_path_segment;
+use crate::endpoints::search::{CreateJobOptions, OutputMode, create_job, wait_for_job};
 use crate::endpoints::send_request_with_retry;
+use crate::endpoints::{encode_path_segment, extract_entry_content};
 use crate::error::{ClientError, Result};
 use crate::metrics::MetricsCollector;
-use crate::models::{FiredAlert, FiredAlertListResponse};
+use crate::models::{
+    AlertAction, AlertActionDispatch, AlertActionListResponse, FiredAlert, FiredAlertListResponse,
+    SavedSearchAlertActions,
+};
 use crate::name_merge::attach_entry_name;
 
 /// List fired alerts.
@@ -120,3 +126,255 @@ pub async fn get_fired_alert(
 
     Ok(attach_entry_name(entry.name, entry.content))
 }
+
+/// List alert actions available on the server.
+///
+/// Returns the entries of `/services/alerts/alert_actions`, including custom
+/// (modular) actions installed by apps.
+#[allow(clippy::too_many_arguments)]
+pub async fn list_alert_actions(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    count: Option<usize>,
+    offset: Option<usize>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<AlertAction>> {
+    debug!("Listing alert actions");
+
+    let url = format!("{}/services/alerts/alert_actions", base_url);
+
+    let mut query_params: Vec<(String, String)> =
+        vec![("output_mode".to_string(), "json".to_string())];
+
+    if let Some(c) = count {
+        query_params.push(("count".to_string(), c.to_string()));
+    }
+    if let Some(o) = offset {
+        query_params.push(("offset".to_string(), o.to_string()));
+    }
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&query_params);
+
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/alerts/alert_actions",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: AlertActionListResponse = response.json().await.map_err(|e| {
+        ClientError::InvalidResponse(format!("Failed to parse alert actions response: {}", e))
+    })?;
+
+    Ok(resp
+        .entry
+        .into_iter()
+        .map(|e| attach_entry_name(e.name, e.content))
+        .collect())
+}
+
+/// Get the alert actions a saved search triggers.
+///
+/// Reads the saved search's raw settings so every `action.<name>.*` parameter
+/// is available, including those of custom actions the typed model omits.
+#[allow(clippy::too_many_arguments)]
+pub async fn get_saved_search_alert_actions(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    name: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<SavedSearchAlertActions> {
+    debug!("Getting alert actions for saved search: {}", name);
+
+    let encoded_name = encode_path_segment(name);
+    let url = format!("{}/services/saved/searches/{}", base_url, encoded_name);
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json")]);
+
+    let response = match send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/saved/searches/{name}",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await
+    {
+        Ok(resp) => resp,
+        Err(ClientError::ApiError { status: 404, .. }) => {
+            return Err(ClientError::NotFound(format!(
+                "Saved search '{}' not found",
+                name
+            )));
+        }
+        Err(e) => return Err(e),
+    };
+
+    let resp: serde_json::Value = response.json().await.map_err(|e| {
+        ClientError::InvalidResponse(format!("Failed to parse saved search response: {}", e))
+    })?;
+
+    let content = extract_entry_content(&resp)?.as_object().ok_or_else(|| {
+        ClientError::InvalidResponse("Saved search content is not an object".to_string())
+    })?;
+
+    Ok(SavedSearchAlertActions::from_content(name, content))
+}
+
+/// Dispatch a search that test-fires an alert action and collect its outcome.
+///
+/// Alert action failures (a rejected webhook, an unreachable mail server) do
+/// not fail the HTTP request; they surface as job messages, so the finished
+/// job is read back and its `isFailed` flag and messages are returned.
+#[allow(clippy::too_many_arguments)]
+pub async fn dispatch_alert_action_test(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    search: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<AlertActionDispatch> {
+    debug!("Dispatching alert action test search: {}", search);
+
+    let options = CreateJobOptions {
+        output_mode: Some(OutputMode::Json),
+        ..Default::default()
+    };
+
+    let sid = create_job(
+        client,
+        base_url,
+        auth_token,
+        search,
+        &options,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    // Delivery happens inline in the search; allow for slow SMTP relays and webhooks.
+    wait_for_job(
+        client,
+        base_url,
+        auth_token,
+        &sid,
+        500,
+        120,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let encoded_sid = encode_path_segment(&sid);
+    let url = format!("{}/services/search/jobs/{}", base_url, encoded_sid);
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json")]);
+
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/search/jobs/{sid}",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: serde_json::Value = response.json().await?;
+    let content = extract_entry_content(&resp)?;
+
+    let messages = job_messages(content.get("messages"));
+    let failed = content
+        .get("isFailed")
+        .is_some_and(|v| v.as_bool() == Some(true) || v.as_str() == Some("1"))
+        || messages
+            .iter()
+            .any(|m| m.starts_with("ERROR:") || m.starts_with("FATAL:"));
+
+    Ok(AlertActionDispatch {
+        sid,
+        failed,
+        messages,
+    })
+}
+
+/// Flatten job messages into `TYPE: text` lines.
+///
+/// Splunk reports messages either as a list of `{type, text}` objects or as an
+/// object keyed by lowercase type; both shapes are accepted.
+fn job_messages(value: Option<&serde_json::Value>) -> Vec<String> {
+    match value {
+        Some(serde_json::Value::Array(items)) => items
+            .iter()
+            .filter_map(|item| {
+                let text = item.get("text")?.as_str()?;
+                let kind = item.get("type").and_then(|t| t.as_str()).unwrap_or("INFO");
+                Some(format!("{}: {}", kind.to_ascii_uppercase(), text))
+            })
+            .collect(),
+        Some(serde_json::Value::Object(by_type)) => by_type
+            .iter()
+            .flat_map(|(kind, texts)| {
+                let texts = match texts {
+                    serde_json::Value::Array(items) => items.clone(),
+                    other => vec![other.clone()],
+                };
+                texts.into_iter().filter_map(move |text| {
+                    text.as_str()
+                        .map(|text| format!("{}: {}", kind.to_ascii_uppercase(), text))
+                })
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_job_messages_accepts_list_and_map_shapes() {
+        let list = serde_json::json!([
+            { "type": "ERROR", "text": "Error in 'sendalert' command: Alert script returned error code 3." },
+            { "type": "info", "text": "done" }
+        ]);
+        assert_eq!(
+            job_messages(Some(&list)),
+            [
+                "ERROR: Error in 'sendalert' command: Alert script returned error code 3.",
+                "INFO: done"
+            ]
+        );
+
+        let map = serde_json::json!({ "fatal": ["boom"], "warn": "slow relay" });
+        assert_eq!(
+            job_messages(Some(&map)),
+            ["FATAL: boom", "WARN: slow relay"]
+        );
+        assert!(job_messages(None).is_empty());
+    }
+}
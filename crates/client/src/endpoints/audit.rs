@@ -144,7 +144,7 @@ pub async fn get_recent_audit_events(
     .await
 }
 
-fn escape_spl_string(value: &str) -> String {
+pub(crate) fn escape_spl_string(value: &str) -> String {
     value.replace('\\', "\\\\").replace('"', "\\\"")
 }
 
@@ -9,9 +9,11 @@ use crate::endpoints::{extract_entry_content, extract_entry_message};
 use crate::error::{ClientError, Result};
 use crate::metrics::MetricsCollector;
 use crate::models::{
-    ClusterInfo, ClusterManagementResponse, ClusterPeer, DecommissionPeerParams,
-    MaintenanceModeParams, RemovePeersParams,
+    ClusterFixupContent, ClusterFixupLevel, ClusterFixupTask, ClusterIndexCopies,
+    ClusterIndexExcess, ClusterInfo, ClusterManagementResponse, ClusterPeer, ClusterSearchHead,
+    DataRebalanceAction, DecommissionPeerParams, MaintenanceModeParams, RemovePeersParams,
 };
+use crate::schema_check::parse_entry_content;
 
 /// Get cluster configuration/status.
 #[allow(clippy::too_many_arguments)]
@@ -43,9 +45,7 @@ pub async fn get_cluster_info(
 
     let content = extract_entry_content(&resp)?;
 
-    let info: ClusterInfo = serde_json::from_value(content.clone()).map_err(|e| {
-        ClientError::InvalidResponse(format!("Failed to parse cluster info: {}", e))
-    })?;
+    let info: ClusterInfo = parse_entry_content(content, "cluster info")?;
     Ok(info)
 }
 
@@ -86,14 +86,61 @@ pub async fn get_cluster_peers(
             let content = e.get("content").ok_or_else(|| {
                 ClientError::InvalidResponse("Missing content in cluster peer entry".to_string())
             })?;
-            let peer: ClusterPeer = serde_json::from_value(content.clone()).map_err(|e| {
-                ClientError::InvalidResponse(format!("Failed to parse cluster peer: {}", e))
-            })?;
+            let peer: ClusterPeer = parse_entry_content(content, "cluster peer")?;
             Ok(peer)
         })
         .collect()
 }
 
+/// List search heads registered with the cluster manager.
+#[allow(clippy::too_many_arguments)]
+pub async fn get_cluster_search_heads(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<ClusterSearchHead>> {
+    let url = format!("{}/services/cluster/master/searchheads", base_url);
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json"), ("count", "0")]);
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/cluster/master/searchheads",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: serde_json::Value = response.json().await?;
+
+    let empty = vec![];
+    let entries = resp["entry"].as_array().unwrap_or(&empty);
+
+    entries
+        .iter()
+        .map(|e| {
+            let content = e.get("content").ok_or_else(|| {
+                ClientError::InvalidResponse(
+                    "Missing content in cluster search head entry".to_string(),
+                )
+            })?;
+            let mut search_head: ClusterSearchHead =
+                parse_entry_content(content, "cluster search head")?;
+            if search_head.label.is_none() {
+                search_head.label = e["name"].as_str().map(str::to_string);
+            }
+            Ok(search_head)
+        })
+        .collect()
+}
+
 /// Set maintenance mode on the cluster manager.
 #[allow(clippy::too_many_arguments)]
 pub async fn set_maintenance_mode(
@@ -309,8 +356,283 @@ pub async fn decommission_peer(
         )
     })?;
 
-    let peer: ClusterPeer = serde_json::from_value(content.clone()).map_err(|e| {
-        ClientError::InvalidResponse(format!("Failed to parse cluster peer: {}", e))
-    })?;
+    let peer: ClusterPeer = parse_entry_content(content, "cluster peer")?;
     Ok(peer)
 }
+
+/// List pending bucket fix-up tasks at a given fix-up level.
+#[allow(clippy::too_many_arguments)]
+pub async fn get_cluster_fixups(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    level: ClusterFixupLevel,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<ClusterFixupTask>> {
+    let url = format!("{}/services/cluster/master/fixup", base_url);
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[
+            ("level", level.as_str()),
+            ("output_mode", "json"),
+            ("count", "0"),
+        ]);
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/cluster/master/fixup",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: serde_json::Value = response.json().await?;
+
+    let empty = vec![];
+    let entries = resp["entry"].as_array().unwrap_or(&empty);
+
+    entries
+        .iter()
+        .map(|e| {
+            let bucket_id = e["name"].as_str().unwrap_or_default().to_string();
+            let content = e.get("content").ok_or_else(|| {
+                ClientError::InvalidResponse("Missing content in cluster fixup entry".to_string())
+            })?;
+            let fixup: ClusterFixupContent = parse_entry_content(content, "cluster fixup")?;
+            let latest = fixup.latest.or(fixup.initial).unwrap_or_default();
+            Ok(ClusterFixupTask {
+                bucket_id,
+                index: fixup.index,
+                level,
+                reason: latest.reason,
+                timestamp: latest.timestamp,
+            })
+        })
+        .collect()
+}
+
+/// List excess bucket copy counts per clustered index.
+///
+/// When `index` is provided only that index is returned.
+#[allow(clippy::too_many_arguments)]
+pub async fn get_cluster_excess_buckets(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    index: Option<&str>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<ClusterIndexExcess>> {
+    let url = format!("{}/services/cluster/master/indexes", base_url);
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json"), ("count", "0")]);
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/cluster/master/indexes",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: serde_json::Value = response.json().await?;
+
+    let empty = vec![];
+    let entries = resp["entry"].as_array().unwrap_or(&empty);
+
+    entries
+        .iter()
+        .filter(|e| index.is_none_or(|name| e["name"].as_str() == Some(name)))
+        .map(|e| {
+            let content = e.get("content").ok_or_else(|| {
+                ClientError::InvalidResponse("Missing content in cluster index entry".to_string())
+            })?;
+            let mut excess: ClusterIndexExcess = parse_entry_content(content, "cluster index")?;
+            excess.name = e["name"].as_str().unwrap_or_default().to_string();
+            Ok(excess)
+        })
+        .collect()
+}
+
+/// List replicated and searchable copy trackers per clustered index.
+#[allow(clippy::too_many_arguments)]
+pub async fn get_cluster_index_copies(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<ClusterIndexCopies>> {
+    let url = format!("{}/services/cluster/master/indexes", base_url);
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json"), ("count", "0")]);
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/cluster/master/indexes",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: serde_json::Value = response.json().await?;
+
+    let empty = vec![];
+    let entries = resp["entry"].as_array().unwrap_or(&empty);
+
+    entries
+        .iter()
+        .map(|e| {
+            let content = e.get("content").ok_or_else(|| {
+                ClientError::InvalidResponse("Missing content in cluster index entry".to_string())
+            })?;
+            let mut copies: ClusterIndexCopies =
+                parse_entry_content(content, "cluster index copies")?;
+            copies.name = e["name"].as_str().unwrap_or_default().to_string();
+            Ok(copies)
+        })
+        .collect()
+}
+
+/// Remove excess bucket copies, optionally limited to a single index.
+#[allow(clippy::too_many_arguments)]
+pub async fn remove_excess_buckets(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    index: Option<&str>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<ClusterManagementResponse> {
+    let url = format!(
+        "{}/services/cluster/master/control/control/prune_index",
+        base_url
+    );
+
+    let mut form_params: Vec<(String, String)> =
+        vec![("output_mode".to_string(), "json".to_string())];
+    if let Some(index) = index {
+        form_params.push(("index".to_string(), index.to_string()));
+    }
+
+    let builder = client
+        .post(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .form(&form_params);
+
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/cluster/master/control/control/prune_index",
+        "POST",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    // Parse response - may be empty on success
+    let text = response.text().await?;
+    if text.trim().is_empty() {
+        let target = index.unwrap_or("all indexes");
+        return Ok(ClusterManagementResponse {
+            success: true,
+            message: Some(format!("Excess bucket removal initiated for {}", target)),
+        });
+    }
+
+    let resp: serde_json::Value = serde_json::from_str(&text).map_err(|e| {
+        ClientError::InvalidResponse(format!(
+            "Failed to parse remove excess buckets response: {}",
+            e
+        ))
+    })?;
+
+    Ok(ClusterManagementResponse {
+        success: true,
+        message: extract_entry_message(&resp),
+    })
+}
+
+/// Start, stop, or query a data rebalance, optionally limited to a single index.
+///
+/// Data rebalance moves bucket copies between peers so each holds an even share;
+/// unlike [`rebalance_cluster`] it changes where data lives, not just which copy is primary.
+#[allow(clippy::too_many_arguments)]
+pub async fn control_data_rebalance(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    action: DataRebalanceAction,
+    index: Option<&str>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<ClusterManagementResponse> {
+    let url = format!(
+        "{}/services/cluster/master/control/control/rebalance_buckets",
+        base_url
+    );
+
+    let mut form_params: Vec<(String, String)> = vec![
+        ("output_mode".to_string(), "json".to_string()),
+        ("action".to_string(), action.as_str().to_string()),
+    ];
+    if let Some(index) = index {
+        form_params.push(("index".to_string(), index.to_string()));
+    }
+
+    let builder = client
+        .post(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .form(&form_params);
+
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/cluster/master/control/control/rebalance_buckets",
+        "POST",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    // Parse response - may be empty on success
+    let text = response.text().await?;
+    if text.trim().is_empty() {
+        let target = index.unwrap_or("all indexes");
+        let message = match action {
+            DataRebalanceAction::Start => format!("Data rebalance started for {}", target),
+            DataRebalanceAction::Stop => format!("Data rebalance stopped for {}", target),
+            DataRebalanceAction::Status => format!("No data rebalance status for {}", target),
+        };
+        return Ok(ClusterManagementResponse {
+            success: true,
+            message: Some(message),
+        });
+    }
+
+    let resp: serde_json::Value = serde_json::from_str(&text).map_err(|e| {
+        ClientError::InvalidResponse(format!("Failed to parse data rebalance response: {}", e))
+    })?;
+
+    Ok(ClusterManagementResponse {
+        success: true,
+        message: extract_entry_message(&resp),
+    })
+}
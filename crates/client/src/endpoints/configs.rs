@@ -6,6 +6,9 @@
 //! # What this module handles:
 //! - HTTP GET requests to list configuration stanzas
 //! - HTTP GET requests to retrieve specific configuration stanzas
+//! - HTTP GET requests listing a stanza's per-context layers (all apps and users)
+//! - HTTP POST requests to create configuration stanzas
+//! - Optional owner/app namespace context for stanza listing and lookup
 //! - Query parameter construction for pagination
 //!
 //! # What this module does NOT handle:
@@ -13,14 +16,19 @@
 //! - High-level client operations (see [`crate::client::configs`])
 //! - Response deserialization (delegated to models)
 
+use std::collections::{BTreeMap, HashMap};
+
 use reqwest::Client;
 
 use crate::client::circuit_breaker::CircuitBreaker;
-use crate::endpoints::encode_path_segment;
 use crate::endpoints::send_request_with_retry;
+use crate::endpoints::{encode_path_segment, namespaced_path};
 use crate::error::Result;
 use crate::metrics::MetricsCollector;
-use crate::models::{ConfigFile, ConfigListResponse, ConfigStanza};
+use crate::models::{
+    ConfigFile, ConfigLayer, ConfigListResponse, ConfigScope, ConfigStanza, Namespace,
+    SplunkResponse,
+};
 use crate::name_merge::attach_entry_name;
 
 /// List configuration stanzas for a specific config file.
@@ -36,6 +44,7 @@ use crate::name_merge::attach_entry_name;
 /// * `config_file` - The config file name (e.g., "props", "transforms")
 /// * `count` - Maximum number of results to return (default: 30)
 /// * `offset` - Offset for pagination
+/// * `namespace` - Optional owner/app context; stanzas are merged as seen from it
 /// * `max_retries` - Maximum number of retry attempts for failed requests
 /// * `metrics` - Optional metrics collector for request tracking
 ///
@@ -54,12 +63,17 @@ pub async fn list_config_stanzas(
     config_file: &str,
     count: Option<usize>,
     offset: Option<usize>,
+    namespace: Option<&Namespace>,
     max_retries: usize,
     metrics: Option<&MetricsCollector>,
     circuit_breaker: Option<&CircuitBreaker>,
 ) -> Result<Vec<ConfigStanza>> {
     let encoded_config_file = encode_path_segment(config_file);
-    let url = format!("{}/services/configs/conf-{}", base_url, encoded_config_file);
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(namespace, &format!("configs/conf-{}", encoded_config_file))
+    );
 
     let mut query_params: Vec<(String, String)> = vec![
         ("output_mode".to_string(), "json".to_string()),
@@ -108,6 +122,7 @@ pub async fn list_config_stanzas(
 /// * `auth_token` - The authentication token for the request
 /// * `config_file` - The config file name (e.g., "props", "transforms")
 /// * `stanza_name` - The name of the stanza to retrieve
+/// * `namespace` - Optional owner/app context; the stanza is merged as seen from it
 /// * `max_retries` - Maximum number of retry attempts for failed requests
 /// * `metrics` - Optional metrics collector for request tracking
 ///
@@ -125,6 +140,7 @@ pub async fn get_config_stanza(
     auth_token: &str,
     config_file: &str,
     stanza_name: &str,
+    namespace: Option<&Namespace>,
     max_retries: usize,
     metrics: Option<&MetricsCollector>,
     circuit_breaker: Option<&CircuitBreaker>,
@@ -133,8 +149,12 @@ pub async fn get_config_stanza(
     let encoded_config_file = encode_path_segment(config_file);
     let encoded_stanza = encode_path_segment(stanza_name);
     let url = format!(
-        "{}/services/configs/conf-{}/{}",
-        base_url, encoded_config_file, encoded_stanza
+        "{}{}",
+        base_url,
+        namespaced_path(
+            namespace,
+            &format!("configs/conf-{}/{}", encoded_config_file, encoded_stanza)
+        )
     );
 
     let query_params: Vec<(String, String)> = vec![("output_mode".to_string(), "json".to_string())];
@@ -175,6 +195,172 @@ pub async fn get_config_stanza(
     Ok(stanza)
 }
 
+/// Create a configuration stanza with the given settings.
+///
+/// # Arguments
+///
+/// * `client` - The HTTP client to use for the request
+/// * `base_url` - The base URL of the Splunk server
+/// * `auth_token` - The authentication token for the request
+/// * `config_file` - The config file name (e.g., "props", "transforms")
+/// * `stanza_name` - The name of the stanza to create
+/// * `settings` - Key/value settings written into the new stanza
+/// * `namespace` - Optional owner/app context the stanza is created in
+/// * `max_retries` - Maximum number of retry attempts for failed requests
+/// * `metrics` - Optional metrics collector for request tracking
+///
+/// # Errors
+///
+/// Returns a `ClientError` if the request fails, including when the stanza
+/// already exists (Splunk answers 409).
+#[allow(clippy::too_many_arguments)]
+pub async fn create_config_stanza(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    config_file: &str,
+    stanza_name: &str,
+    settings: &[(String, String)],
+    namespace: Option<&Namespace>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    let encoded_config_file = encode_path_segment(config_file);
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(namespace, &format!("configs/conf-{}", encoded_config_file))
+    );
+
+    let mut form_params: Vec<(&str, &str)> = vec![("name", stanza_name)];
+    form_params.extend(settings.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+
+    let builder = client
+        .post(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json")])
+        .form(&form_params);
+    send_request_with_retry(
+        builder,
+        max_retries,
+        &format!("/services/configs/conf-{}", encoded_config_file),
+        "POST",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// List every context's copy of a config file's stanzas.
+///
+/// Queries the namespace-wildcard endpoint (`/servicesNS/-/-/configs/...`),
+/// which returns one entry per system, app, or user context defining a stanza,
+/// along with its ACL. When `stanza_name` is `None`, all stanzas are listed.
+///
+/// # Arguments
+///
+/// * `client` - The HTTP client to use for the request
+/// * `base_url` - The base URL of the Splunk server
+/// * `auth_token` - The authentication token for the request
+/// * `config_file` - The config file name (e.g., "props", "transforms")
+/// * `stanza_name` - Optional stanza to restrict the listing to
+/// * `max_retries` - Maximum number of retry attempts for failed requests
+/// * `metrics` - Optional metrics collector for request tracking
+///
+/// # Returns
+///
+/// A `Result` containing one `ConfigLayer` per context, in response order.
+///
+/// # Errors
+///
+/// Returns a `ClientError` if the request fails or the response cannot be parsed.
+#[allow(clippy::too_many_arguments)]
+pub async fn list_config_stanza_layers(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    config_file: &str,
+    stanza_name: Option<&str>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<ConfigLayer>> {
+    let encoded_config_file = encode_path_segment(config_file);
+    let (url, endpoint) = match stanza_name {
+        Some(stanza) => (
+            format!(
+                "{}/servicesNS/-/-/configs/conf-{}/{}",
+                base_url,
+                encoded_config_file,
+                encode_path_segment(stanza)
+            ),
+            format!(
+                "/servicesNS/-/-/configs/conf-{}/{{stanza}}",
+                encoded_config_file
+            ),
+        ),
+        None => (
+            format!(
+                "{}/servicesNS/-/-/configs/conf-{}",
+                base_url, encoded_config_file
+            ),
+            format!("/servicesNS/-/-/configs/conf-{}", encoded_config_file),
+        ),
+    };
+
+    let query_params: Vec<(String, String)> = vec![
+        ("output_mode".to_string(), "json".to_string()),
+        ("count".to_string(), "0".to_string()),
+    ];
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&query_params);
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        &endpoint,
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: SplunkResponse<HashMap<String, serde_json::Value>> = response.json().await?;
+
+    Ok(resp
+        .entry
+        .into_iter()
+        .map(|entry| {
+            let (app, owner, sharing) = match entry.acl {
+                Some(acl) => (acl.app, acl.owner, acl.sharing),
+                None => ("system".to_string(), "nobody".to_string(), None),
+            };
+            let scope = match sharing.as_deref() {
+                Some("user") => ConfigScope::User,
+                _ if app == "system" || sharing.as_deref() == Some("system") => ConfigScope::System,
+                _ => ConfigScope::App,
+            };
+            let settings: BTreeMap<String, serde_json::Value> = entry
+                .content
+                .into_iter()
+                .filter(|(key, _)| !key.starts_with("eai:"))
+                .collect();
+            ConfigLayer {
+                stanza: entry.name,
+                scope,
+                app,
+                owner,
+                settings,
+            }
+        })
+        .collect())
+}
+
 /// List available configuration files.
 ///
 /// Retrieves a list of available configuration files from the Splunk server.
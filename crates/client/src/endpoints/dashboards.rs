@@ -3,14 +3,18 @@
 use reqwest::Client;
 
 use crate::client::circuit_breaker::CircuitBreaker;
-use crate::endpoints::encode_path_segment;
 use crate::endpoints::send_request_with_retry;
+use crate::endpoints::{acl_filter_param, encode_path_segment, namespaced_path};
 use crate::error::{ClientError, Result};
 use crate::metrics::MetricsCollector;
-use crate::models::{Dashboard, DashboardListResponse};
+use crate::models::{Dashboard, DashboardListResponse, Namespace};
 use crate::name_merge::attach_entry_name;
+use crate::schema_check::parse_entry_content;
 
 /// List all dashboards.
+///
+/// With a namespace, lists from `/servicesNS/{owner}/{app}`, limited to dashboards
+/// defined in that app.
 #[allow(clippy::too_many_arguments)]
 pub async fn list_dashboards(
     client: &Client,
@@ -18,11 +22,16 @@ pub async fn list_dashboards(
     auth_token: &str,
     count: Option<usize>,
     offset: Option<usize>,
+    namespace: Option<&Namespace>,
     max_retries: usize,
     metrics: Option<&MetricsCollector>,
     circuit_breaker: Option<&CircuitBreaker>,
 ) -> Result<Vec<Dashboard>> {
-    let url = format!("{}/services/data/ui/views", base_url);
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(namespace, "data/ui/views")
+    );
 
     let mut query_params: Vec<(String, String)> = vec![
         ("output_mode".to_string(), "json".to_string()),
@@ -32,6 +41,7 @@ pub async fn list_dashboards(
     if let Some(o) = offset {
         query_params.push(("offset".to_string(), o.to_string()));
     }
+    query_params.extend(acl_filter_param(namespace));
 
     let builder = client
         .get(&url)
@@ -64,14 +74,19 @@ pub async fn get_dashboard(
     base_url: &str,
     auth_token: &str,
     dashboard_name: &str,
+    namespace: Option<&Namespace>,
     max_retries: usize,
     metrics: Option<&MetricsCollector>,
     circuit_breaker: Option<&CircuitBreaker>,
 ) -> Result<Dashboard> {
     let encoded_dashboard_name = encode_path_segment(dashboard_name);
     let url = format!(
-        "{}/services/data/ui/views/{}",
-        base_url, encoded_dashboard_name
+        "{}{}",
+        base_url,
+        namespaced_path(
+            namespace,
+            &format!("data/ui/views/{}", encoded_dashboard_name)
+        )
     );
 
     let query_params: Vec<(String, String)> = vec![("output_mode".to_string(), "json".to_string())];
@@ -108,8 +123,7 @@ pub async fn get_dashboard(
         ClientError::InvalidResponse("Missing entry content in dashboard response".to_string())
     })?;
 
-    let dashboard: Dashboard = serde_json::from_value(content.clone())
-        .map_err(|e| ClientError::InvalidResponse(format!("Failed to parse dashboard: {}", e)))?;
+    let dashboard: Dashboard = parse_entry_content(content, "dashboard")?;
 
     Ok(attach_entry_name(entry_name, dashboard))
 }
@@ -9,6 +9,7 @@ use crate::error::{ClientError, Result};
 use crate::metrics::MetricsCollector;
 use crate::models::{DataModel, DataModelListResponse};
 use crate::name_merge::attach_entry_name;
+use crate::schema_check::parse_entry_content;
 
 /// List all data models.
 #[allow(clippy::too_many_arguments)]
@@ -105,8 +106,7 @@ pub async fn get_datamodel(
         ClientError::InvalidResponse("Missing entry content in data model response".to_string())
     })?;
 
-    let datamodel: DataModel = serde_json::from_value(content.clone())
-        .map_err(|e| ClientError::InvalidResponse(format!("Failed to parse data model: {}", e)))?;
+    let datamodel: DataModel = parse_entry_content(content, "data model")?;
 
     Ok(attach_entry_name(entry_name, datamodel))
 }
@@ -0,0 +1,67 @@
+//! Event type endpoints.
+
+use reqwest::Client;
+
+use crate::client::circuit_breaker::CircuitBreaker;
+use crate::endpoints::send_request_with_retry;
+use crate::endpoints::{acl_filter_param, namespaced_path};
+use crate::error::Result;
+use crate::metrics::MetricsCollector;
+use crate::models::{EventType, EventTypeListResponse, Namespace};
+use crate::name_merge::attach_entry_name;
+
+/// List all event types.
+///
+/// With a namespace, lists from `/servicesNS/{owner}/{app}`, limited to event
+/// types defined in that app.
+#[allow(clippy::too_many_arguments)]
+pub async fn list_event_types(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    count: Option<usize>,
+    offset: Option<usize>,
+    namespace: Option<&Namespace>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<EventType>> {
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(namespace, "saved/eventtypes")
+    );
+
+    let mut query_params: Vec<(String, String)> = vec![
+        ("output_mode".to_string(), "json".to_string()),
+        ("count".to_string(), count.unwrap_or(30).to_string()),
+    ];
+
+    if let Some(o) = offset {
+        query_params.push(("offset".to_string(), o.to_string()));
+    }
+    query_params.extend(acl_filter_param(namespace));
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&query_params);
+
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/saved/eventtypes",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: EventTypeListResponse = response.json().await?;
+
+    Ok(resp
+        .entry
+        .into_iter()
+        .map(|e| attach_entry_name(e.name, e.content))
+        .collect())
+}
@@ -13,7 +13,7 @@
 //!
 //! # What this module does NOT handle:
 //! - High-level client methods (see [`crate::client::hec`])
-//! - Token management or configuration
+//! - Token management or configuration (see [`crate::endpoints::hec_tokens`])
 //!
 //! # Authentication
 //! HEC uses a different authorization header format than the REST API:
@@ -0,0 +1,443 @@
+//! HEC token REST API endpoints.
+//!
+//! Responsibilities:
+//! - Low-level HTTP calls to /services/data/inputs/http endpoints.
+//! - Enable and disable tokens via their action sub-paths.
+//! - Handle request serialization and response parsing.
+//!
+//! Does NOT handle:
+//! - Does not handle auth retry (see client module).
+//! - Does not send events to HEC (see [`crate::endpoints::hec`]).
+//!
+//! Invariants:
+//! - Token names may be passed with or without the `http://` prefix Splunk
+//!   lists them under; the prefix is stripped from request paths.
+
+use reqwest::Client;
+use secrecy::ExposeSecret;
+use tracing::debug;
+
+use crate::client::circuit_breaker::CircuitBreaker;
+use crate::endpoints::{encode_path_segment, namespaced_path};
+use crate::endpoints::{form_params_str, send_request_with_retry};
+use crate::error::{ClientError, Result};
+use crate::metrics::MetricsCollector;
+use crate::models::{
+    HecToken, HecTokenCreateParams, HecTokenEntry, HecTokenListResponse, HecTokenUpdateParams,
+    Namespace,
+};
+use crate::name_merge::attach_entry_name;
+
+/// Prefix Splunk puts in front of HTTP input names in listings.
+const HEC_INPUT_PREFIX: &str = "http://";
+
+/// Path of one token, without the listing prefix.
+fn token_path(name: &str) -> String {
+    let name = name.strip_prefix(HEC_INPUT_PREFIX).unwrap_or(name);
+    format!("data/inputs/http/{}", encode_path_segment(name))
+}
+
+/// Parse the single entry returned by a token GET or create.
+fn parse_single_token(body: HecTokenListResponse, name: &str) -> Result<HecToken> {
+    let entry = body
+        .entry
+        .into_iter()
+        .next()
+        .ok_or_else(|| ClientError::NotFound(format!("HEC token '{}' not found", name)))?;
+    Ok(token_from_entry(entry))
+}
+
+/// Token content with the entry name and the app from the entry's ACL.
+fn token_from_entry(entry: HecTokenEntry) -> HecToken {
+    let mut token = attach_entry_name(entry.name, entry.content);
+    if let Some(acl) = entry.acl {
+        token.app = Some(acl.app);
+    }
+    token
+}
+
+/// List all HEC tokens.
+pub async fn list_hec_tokens(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    namespace: Option<&Namespace>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<HecToken>> {
+    debug!("Listing HEC tokens");
+
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(namespace, "data/inputs/http")
+    );
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json"), ("count", "0")]);
+
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/data/inputs/http",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: HecTokenListResponse = response.json().await.map_err(|e| {
+        ClientError::InvalidResponse(format!("Failed to parse HEC tokens response: {}", e))
+    })?;
+
+    Ok(resp.entry.into_iter().map(token_from_entry).collect())
+}
+
+/// Get a single HEC token by name.
+///
+/// # Returns
+/// The `HecToken` if found, or `ClientError::NotFound` if it doesn't exist.
+#[allow(clippy::too_many_arguments)]
+pub async fn get_hec_token(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    name: &str,
+    namespace: Option<&Namespace>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<HecToken> {
+    debug!("Getting HEC token: {}", name);
+
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(namespace, &token_path(name))
+    );
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json")]);
+
+    let response = match send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/data/inputs/http/{name}",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await
+    {
+        Ok(resp) => resp,
+        Err(ClientError::ApiError { status: 404, .. }) => {
+            return Err(ClientError::NotFound(format!(
+                "HEC token '{}' not found",
+                name
+            )));
+        }
+        Err(e) => return Err(e),
+    };
+
+    let body: HecTokenListResponse = response.json().await.map_err(|e| {
+        ClientError::InvalidResponse(format!("Failed to parse HEC token response: {}", e))
+    })?;
+
+    parse_single_token(body, name)
+}
+
+/// Create a new HEC token.
+///
+/// # Returns
+/// The created token, including the generated token value.
+#[allow(clippy::too_many_arguments)]
+pub async fn create_hec_token(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    params: &HecTokenCreateParams,
+    namespace: Option<&Namespace>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<HecToken> {
+    debug!("Creating HEC token: {}", params.name);
+
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(namespace, "data/inputs/http")
+    );
+
+    let indexes = (!params.indexes.is_empty()).then(|| params.indexes.join(","));
+    let mut form_params: Vec<(&str, String)> = vec![];
+
+    form_params_str! { form_params =>
+        "name" => str Some(params.name.as_str()),
+        "index" => str params.index.as_deref(),
+        "indexes" => str indexes.as_deref(),
+        "sourcetype" => str params.sourcetype.as_deref(),
+        "source" => str params.source.as_deref(),
+        "host" => str params.host.as_deref(),
+        "outputgroup" => str params.outputgroup.as_deref(),
+        "description" => str params.description.as_deref(),
+        "useACK" => required_bool params.use_ack,
+        "token" => str params.token.as_ref().map(|token| token.expose_secret()),
+    }
+
+    let builder = client
+        .post(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json")])
+        .form(&form_params);
+
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/data/inputs/http",
+        "POST",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let body: HecTokenListResponse = response.json().await.map_err(|e| {
+        ClientError::InvalidResponse(format!("Failed to parse create HEC token response: {}", e))
+    })?;
+
+    parse_single_token(body, &params.name)
+}
+
+/// Update an existing HEC token.
+///
+/// Only provided fields are updated; omitted fields retain their current values.
+///
+/// # Returns
+/// Ok(()) on success, or `ClientError::NotFound` if the token doesn't exist.
+#[allow(clippy::too_many_arguments)]
+pub async fn update_hec_token(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    name: &str,
+    params: &HecTokenUpdateParams,
+    namespace: Option<&Namespace>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    debug!("Updating HEC token: {}", name);
+
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(namespace, &token_path(name))
+    );
+
+    let indexes = params.indexes.as_ref().map(|indexes| indexes.join(","));
+    let mut form_params: Vec<(&str, String)> = Vec::new();
+
+    form_params_str! { form_params =>
+        "index" => str params.index.as_deref(),
+        "indexes" => str indexes.as_deref(),
+        "sourcetype" => str params.sourcetype.as_deref(),
+        "description" => str params.description.as_deref(),
+        "disabled" => bool params.disabled,
+        "useACK" => bool params.use_ack,
+    }
+
+    let builder = client
+        .post(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json")])
+        .form(&form_params);
+
+    match send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/data/inputs/http/{name}",
+        "POST",
+        metrics,
+        circuit_breaker,
+    )
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(ClientError::ApiError { status: 404, .. }) => Err(ClientError::NotFound(format!(
+            "HEC token '{}' not found",
+            name
+        ))),
+        Err(e) => Err(e),
+    }
+}
+
+/// Delete a HEC token.
+///
+/// # Returns
+/// Ok(()) on success, or `ClientError::NotFound` if the token doesn't exist.
+#[allow(clippy::too_many_arguments)]
+pub async fn delete_hec_token(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    name: &str,
+    namespace: Option<&Namespace>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    debug!("Deleting HEC token: {}", name);
+
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(namespace, &token_path(name))
+    );
+
+    let builder = client
+        .delete(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json")]);
+
+    match send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/data/inputs/http/{name}",
+        "DELETE",
+        metrics,
+        circuit_breaker,
+    )
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(ClientError::ApiError { status: 404, .. }) => Err(ClientError::NotFound(format!(
+            "HEC token '{}' not found",
+            name
+        ))),
+        Err(e) => Err(e),
+    }
+}
+
+/// Enable a HEC token.
+///
+/// # Returns
+/// Ok(()) on success, or `ClientError::NotFound` if the token doesn't exist.
+#[allow(clippy::too_many_arguments)]
+pub async fn enable_hec_token(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    name: &str,
+    namespace: Option<&Namespace>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    debug!("Enabling HEC token: {}", name);
+    post_token_action(
+        client,
+        base_url,
+        auth_token,
+        name,
+        "enable",
+        namespace,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await
+}
+
+/// Disable a HEC token.
+///
+/// A disabled token is kept, but Splunk rejects events sent with it.
+///
+/// # Returns
+/// Ok(()) on success, or `ClientError::NotFound` if the token doesn't exist.
+#[allow(clippy::too_many_arguments)]
+pub async fn disable_hec_token(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    name: &str,
+    namespace: Option<&Namespace>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    debug!("Disabling HEC token: {}", name);
+    post_token_action(
+        client,
+        base_url,
+        auth_token,
+        name,
+        "disable",
+        namespace,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await
+}
+
+/// POST to an action sub-path of a token (e.g., `enable`, `disable`).
+#[allow(clippy::too_many_arguments)]
+async fn post_token_action(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    name: &str,
+    action: &str,
+    namespace: Option<&Namespace>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(namespace, &format!("{}/{}", token_path(name), action))
+    );
+
+    let builder = client
+        .post(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json")]);
+
+    match send_request_with_retry(
+        builder,
+        max_retries,
+        &format!("/services/data/inputs/http/{{name}}/{}", action),
+        "POST",
+        metrics,
+        circuit_breaker,
+    )
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(ClientError::ApiError { status: 404, .. }) => Err(ClientError::NotFound(format!(
+            "HEC token '{}' not found",
+            name
+        ))),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_path_strips_listing_prefix() {
+        assert_eq!(
+            token_path("http://web events"),
+            "data/inputs/http/web%20events"
+        );
+        assert_eq!(token_path("web"), "data/inputs/http/web");
+    }
+}
@@ -10,6 +10,7 @@ use crate::error::{ClientError, Result};
 use crate::metrics::MetricsCollector;
 use crate::models::{CreateIndexParams, Index, IndexListResponse, ModifyIndexParams};
 use crate::name_merge::attach_entry_name;
+use crate::schema_check::parse_entry_content;
 
 /// List all indexes.
 #[allow(clippy::too_many_arguments)]
@@ -159,8 +160,7 @@ pub async fn create_index(
         ClientError::InvalidResponse("Missing entry content in create index response".to_string())
     })?;
 
-    let index: Index = serde_json::from_value(content.clone())
-        .map_err(|e| ClientError::InvalidResponse(format!("Failed to parse index: {}", e)))?;
+    let index: Index = parse_entry_content(content, "index")?;
 
     Ok(attach_entry_name(entry_name, index))
 }
@@ -235,8 +235,7 @@ pub async fn modify_index(
         ))
     })?;
 
-    let index: Index = serde_json::from_value(content.clone())
-        .map_err(|e| ClientError::InvalidResponse(format!("Failed to parse index: {}", e)))?;
+    let index: Index = parse_entry_content(content, "index")?;
 
     Ok(attach_entry_name(entry_name, index))
 }
@@ -274,3 +273,44 @@ pub async fn delete_index(
 
     Ok(())
 }
+
+/// Roll an index's hot buckets to warm.
+///
+/// Closes the buckets currently being written so they can be backed up or
+/// replicated before maintenance; new hot buckets open on the next write.
+#[allow(clippy::too_many_arguments)]
+pub async fn roll_hot_buckets(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    index_name: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    let encoded_index_name = encode_path_segment(index_name);
+    let url = Url::parse(base_url)
+        .map_err(|e| ClientError::InvalidUrl(format!("Invalid base URL: {}", e)))?
+        .join(&format!(
+            "/services/data/indexes/{}/roll-hot-buckets",
+            encoded_index_name
+        ))
+        .map_err(|e| ClientError::InvalidUrl(format!("Invalid index name: {}", e)))?;
+
+    let builder = client
+        .post(url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .form(&[("output_mode", "json")]);
+
+    let _response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/data/indexes/{name}/roll-hot-buckets",
+        "POST",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    Ok(())
+}
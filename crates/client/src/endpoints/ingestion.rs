@@ -0,0 +1,185 @@
+//! Indexing latency and thruput endpoints.
+
+use reqwest::Client;
+use tracing::{debug, warn};
+
+use crate::client::circuit_breaker::CircuitBreaker;
+use crate::endpoints::search::{
+    CreateJobOptions, OutputMode, create_job, get_results, wait_for_job,
+};
+use crate::error::Result;
+use crate::metrics::MetricsCollector;
+use crate::models::{IngestionGroupBy, IngestionStat, IngestionStats};
+
+/// Search query producing event counts and indexing latency per group.
+///
+/// `{field}` is replaced by the grouping field. Only non-internal indexes
+/// match `index=*`.
+const LATENCY_SEARCH_QUERY: &str = r#"search index=* {field}=* | eval latency=_indextime-_time | stats count as events avg(latency) as avg_latency_secs max(latency) as max_latency_secs by {field} | rename {field} as name"#;
+
+/// Search query producing thruput per group from `metrics.log`.
+///
+/// splunkd only logs the busiest series of each `per_<field>_thruput` group
+/// per interval, so quiet groups may be missing or undercounted.
+const THRUPUT_SEARCH_QUERY: &str = r#"search index=_internal source=*metrics.log* group=per_{field}_thruput | stats sum(kb) as kb sum(ev) as events by series | rename series as name"#;
+
+/// Report indexing latency and thruput per `group_by` over the last `window_secs` seconds.
+///
+/// Runs the latency and thruput searches concurrently and merges their rows
+/// into an [`IngestionStats`].
+///
+/// # Arguments
+///
+/// * `client` - HTTP client for making requests
+/// * `base_url` - Base URL of the Splunk server
+/// * `auth_token` - Authentication token
+/// * `group_by` - Field to group the statistics by
+/// * `window_secs` - Length of the look-back window in seconds
+/// * `max_retries` - Maximum number of retry attempts for failed requests
+#[allow(clippy::too_many_arguments)]
+pub async fn get_ingestion_stats(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    group_by: IngestionGroupBy,
+    window_secs: u64,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<IngestionStats> {
+    debug!(
+        "Getting ingestion stats by {} over {}s",
+        group_by, window_secs
+    );
+
+    let field = group_by.as_str();
+    let latency_query = LATENCY_SEARCH_QUERY.replace("{field}", field);
+    let thruput_query = THRUPUT_SEARCH_QUERY.replace("{field}", field);
+    let earliest_time = format!("-{}s", window_secs);
+
+    let (latency, thruput) = tokio::try_join!(
+        run_ingestion_search(
+            client,
+            base_url,
+            auth_token,
+            &latency_query,
+            &earliest_time,
+            max_retries,
+            metrics,
+            circuit_breaker,
+        ),
+        run_ingestion_search(
+            client,
+            base_url,
+            auth_token,
+            &thruput_query,
+            &earliest_time,
+            max_retries,
+            metrics,
+            circuit_breaker,
+        ),
+    )?;
+
+    let stats = IngestionStats::from_rows(group_by, window_secs, latency, thruput);
+
+    debug!("Ingestion stats complete: {} groups", stats.stats.len());
+
+    Ok(stats)
+}
+
+/// Run one ingestion search to completion and deserialize every result row.
+#[allow(clippy::too_many_arguments)]
+async fn run_ingestion_search(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    query: &str,
+    earliest_time: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<IngestionStat>> {
+    let options = CreateJobOptions {
+        earliest_time: Some(earliest_time.to_string()),
+        output_mode: Some(OutputMode::Json),
+        ..Default::default()
+    };
+
+    let sid = create_job(
+        client,
+        base_url,
+        auth_token,
+        query,
+        &options,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    debug!("Created search job {} for ingestion stats", sid);
+
+    let _status = wait_for_job(
+        client,
+        base_url,
+        auth_token,
+        &sid,
+        500,
+        60,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let results = get_results(
+        client,
+        base_url,
+        auth_token,
+        &sid,
+        Some(0),
+        None,
+        OutputMode::Json,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let endpoint = "/services/search/jobs/results";
+    Ok(results
+        .results
+        .into_iter()
+        .filter_map(
+            |v| match serde_json::from_value::<IngestionStat>(v.clone()) {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    warn!(
+                        "Failed to deserialize IngestionStat from {}: error={}, value_preview={}",
+                        endpoint,
+                        e,
+                        serde_json::to_string(&v).unwrap_or_else(|_| format!("{:?}", v))
+                    );
+                    if let Some(m) = metrics {
+                        m.record_deserialization_failure(endpoint, "IngestionStat");
+                    }
+                    None
+                }
+            },
+        )
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queries_group_by_field() {
+        let latency = LATENCY_SEARCH_QUERY.replace("{field}", IngestionGroupBy::Index.as_str());
+        assert!(latency.contains("by index | rename index as name"));
+
+        let thruput = THRUPUT_SEARCH_QUERY.replace("{field}", IngestionGroupBy::Host.as_str());
+        assert!(thruput.contains("group=per_host_thruput"));
+    }
+}
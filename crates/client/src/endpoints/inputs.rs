@@ -6,7 +6,9 @@
 //! # What this module handles:
 //! - HTTP GET requests to list data inputs
 //! - HTTP POST requests to enable/disable inputs
+//! - Creating monitor, TCP, and UDP inputs and deleting inputs
 //! - Query parameter construction for pagination
+//! - Per-source ingestion throughput from `metrics.log`
 //!
 //! # What this module does NOT handle:
 //! - Authentication retry logic (handled by [`crate::client`])
@@ -14,12 +16,26 @@
 //! - Response deserialization (delegated to models)
 
 use reqwest::Client;
+use tracing::{debug, warn};
 
 use crate::client::circuit_breaker::CircuitBreaker;
-use crate::endpoints::send_request_with_retry;
-use crate::error::Result;
+use crate::endpoints::search::{
+    CreateJobOptions, OutputMode, create_job, get_results, wait_for_job,
+};
+use crate::endpoints::{form_params_str, send_request_with_retry};
+use crate::error::{ClientError, Result};
 use crate::metrics::MetricsCollector;
-use crate::models::{Input, InputListResponse, InputType};
+use crate::models::{
+    CreateInputParams, Input, InputListResponse, InputType, NewInput, SourceThroughput,
+    ThroughputReport,
+};
+
+/// Search query totalling ingestion per source from the `per_source_thruput`
+/// metrics group.
+///
+/// Produces one row per series with summed KB and events and the time of the
+/// latest sample.
+const INPUT_THROUGHPUT_SEARCH_QUERY: &str = r#"search index=_internal source=*metrics.log group=per_source_thruput | stats sum(kb) as kb sum(ev) as events max(_time) as last_seen by series | eval last_seen=strftime(last_seen, "%Y-%m-%dT%H:%M:%S%z")"#;
 use crate::name_merge::attach_entry_name;
 
 /// List inputs of a specific type.
@@ -208,3 +224,230 @@ pub async fn disable_input(
 
     Ok(())
 }
+
+/// Create a monitor, TCP, or UDP input.
+///
+/// POSTs to the `data/inputs/{type}` collection selected by the params; the
+/// entry name is the monitored path or the port.
+///
+/// # Errors
+///
+/// Returns a `ClientError` if the request fails (for example, when an input
+/// with the same name already exists) or the response cannot be parsed.
+pub async fn create_input(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    params: &CreateInputParams,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Input> {
+    let input_type = params.input_type();
+    let name = params.name();
+    debug!("Creating {} input: {}", input_type, name);
+
+    let url = format!("{}/services/data/inputs/{}", base_url, input_type);
+
+    let mut form_params: Vec<(&str, String)> = vec![("name", name.clone())];
+    form_params_str! { form_params =>
+        "index" => str params.index.as_deref(),
+        "sourcetype" => str params.sourcetype.as_deref(),
+        "host" => str params.host.as_deref(),
+    }
+    if let NewInput::Monitor {
+        whitelist,
+        blacklist,
+        recursive,
+        ..
+    } = &params.input
+    {
+        form_params_str! { form_params =>
+            "whitelist" => str whitelist.as_deref(),
+            "blacklist" => str blacklist.as_deref(),
+            "recursive" => *recursive,
+        }
+    }
+
+    let builder = client
+        .post(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json")])
+        .form(&form_params);
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        &format!("/services/data/inputs/{}", input_type),
+        "POST",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: InputListResponse = response.json().await.map_err(|e| {
+        ClientError::InvalidResponse(format!("Failed to parse create input response: {}", e))
+    })?;
+    let entry = resp.entry.into_iter().next().ok_or_else(|| {
+        ClientError::InvalidResponse(format!("No entry returned for created input '{}'", name))
+    })?;
+
+    let mut input = attach_entry_name(entry.name, entry.content);
+    if matches!(input.input_type, InputType::Unknown) {
+        input.input_type = input_type;
+    }
+    Ok(input)
+}
+
+/// Delete an input.
+///
+/// # Arguments
+///
+/// * `input_type` - The type of input (tcp/raw, tcp/cooked, udp, monitor, script)
+/// * `name` - The name of the input to delete
+///
+/// # Errors
+///
+/// Returns a `ClientError` if the request fails.
+#[allow(clippy::too_many_arguments)]
+pub async fn delete_input(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    input_type: &str,
+    name: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    debug!("Deleting {} input: {}", input_type, name);
+
+    let encoded_name = crate::endpoints::encode_path_segment(name);
+    let url = format!(
+        "{}/services/data/inputs/{}/{}",
+        base_url, input_type, encoded_name
+    );
+
+    let builder = client
+        .delete(&url)
+        .header("Authorization", format!("Bearer {}", auth_token));
+    let _response = send_request_with_retry(
+        builder,
+        max_retries,
+        &format!("/services/data/inputs/{}/{{name}}", input_type),
+        "DELETE",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Summarize per-source ingestion throughput over the last `window_secs` seconds.
+///
+/// This function creates a search job over `metrics.log`, waits for it to
+/// complete, and collects the per-series rows into a [`ThroughputReport`].
+/// Use [`ThroughputReport::for_input`] to attribute the series to inputs.
+///
+/// # Arguments
+///
+/// * `client` - HTTP client for making requests
+/// * `base_url` - Base URL of the Splunk server
+/// * `auth_token` - Authentication token
+/// * `window_secs` - Length of the look-back window in seconds
+/// * `max_retries` - Maximum number of retry attempts for failed requests
+pub async fn get_input_throughput(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    window_secs: u64,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<ThroughputReport> {
+    debug!(
+        "Summarizing input throughput over the last {}s",
+        window_secs
+    );
+
+    let options = CreateJobOptions {
+        earliest_time: Some(format!("-{}s", window_secs)),
+        output_mode: Some(OutputMode::Json),
+        ..Default::default()
+    };
+
+    let sid = create_job(
+        client,
+        base_url,
+        auth_token,
+        INPUT_THROUGHPUT_SEARCH_QUERY,
+        &options,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    debug!("Created search job {} for input throughput", sid);
+
+    let _status = wait_for_job(
+        client,
+        base_url,
+        auth_token,
+        &sid,
+        500,
+        60,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    // count=0 returns every series; silent inputs can only be judged against all of them.
+    let results = get_results(
+        client,
+        base_url,
+        auth_token,
+        &sid,
+        Some(0),
+        None,
+        OutputMode::Json,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let endpoint = "/services/search/jobs/results";
+    let sources: Vec<SourceThroughput> = results
+        .results
+        .into_iter()
+        .filter_map(
+            |v| match serde_json::from_value::<SourceThroughput>(v.clone()) {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    warn!(
+                        "Failed to deserialize SourceThroughput from {}: error={}, value_preview={}",
+                        endpoint,
+                        e,
+                        serde_json::to_string(&v).unwrap_or_else(|_| format!("{:?}", v))
+                    );
+                    if let Some(m) = metrics {
+                        m.record_deserialization_failure(endpoint, "SourceThroughput");
+                    }
+                    None
+                }
+            },
+        )
+        .collect();
+
+    debug!(
+        "Input throughput summary complete: {} series",
+        sources.len()
+    );
+
+    Ok(ThroughputReport {
+        window_secs,
+        sources,
+    })
+}
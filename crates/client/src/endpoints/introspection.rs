@@ -0,0 +1,208 @@
+//! Introspection resource usage endpoints.
+
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+use tracing::{debug, warn};
+
+use crate::client::circuit_breaker::CircuitBreaker;
+use crate::endpoints::audit::escape_spl_string;
+use crate::endpoints::search::{
+    CreateJobOptions, OutputMode, create_job, get_results, wait_for_job,
+};
+use crate::error::Result;
+use crate::metrics::MetricsCollector;
+use crate::models::{HostResourceUsage, ProcessResourceUsage, ResourceUsage};
+
+/// Look-back window for resource usage; splunkd samples every few seconds,
+/// so the latest sample per host is always well inside it.
+pub const RESOURCE_USAGE_WINDOW: &str = "-15m";
+
+/// Number of processes kept in the top-processes list.
+const TOP_PROCESS_LIMIT: usize = 20;
+
+/// Search query producing the latest CPU, memory, and I/O wait per host.
+///
+/// Hostwide events carry CPU and memory; IOStats events carry I/O wait as
+/// `data.cpu_pct`. `{host}` is replaced by an optional host filter.
+const HOST_USAGE_SEARCH_QUERY: &str = r#"search index=_introspection sourcetype=splunk_resource_usage (component=Hostwide OR component=IOStats){host} | eval cpu_pct=if(component=="Hostwide", 'data.cpu_system_pct' + 'data.cpu_user_pct', null()), mem_used_mb=if(component=="Hostwide", 'data.mem_used', null()), mem_total_mb=if(component=="Hostwide", 'data.mem', null()), cpu_count=if(component=="Hostwide", 'data.cpu_count', null()), iowait_pct=if(component=="IOStats", 'data.cpu_pct', null()) | stats latest(cpu_pct) as cpu_pct latest(mem_used_mb) as mem_used_mb latest(mem_total_mb) as mem_total_mb latest(cpu_count) as cpu_count latest(iowait_pct) as iowait_pct max(_time) as last_seen by host | eval last_seen=strftime(last_seen, "%Y-%m-%dT%H:%M:%S%z")"#;
+
+/// Search query producing the latest CPU and memory of each Splunk process.
+const PROCESS_USAGE_SEARCH_QUERY: &str = r#"search index=_introspection sourcetype=splunk_resource_usage component=PerProcess{host} | stats latest(data.pct_cpu) as cpu_pct latest(data.mem_used) as mem_used_mb latest(data.process_type) as process_type by host data.process data.pid | rename data.process as process data.pid as pid | sort 0 - cpu_pct | head {limit}"#;
+
+/// Build the ` host="..."` filter for the usage searches, or nothing for all hosts.
+fn host_filter(host: Option<&str>) -> String {
+    match host.map(str::trim).filter(|host| !host.is_empty()) {
+        Some(host) => format!(" host=\"{}\"", escape_spl_string(host)),
+        None => String::new(),
+    }
+}
+
+/// Report the latest per-host resource usage and the busiest Splunk processes.
+///
+/// Runs the host and process searches over [`RESOURCE_USAGE_WINDOW`]
+/// concurrently, optionally limited to one `host`.
+///
+/// # Arguments
+///
+/// * `client` - HTTP client for making requests
+/// * `base_url` - Base URL of the Splunk server
+/// * `auth_token` - Authentication token
+/// * `host` - Only report this host (`None` for every host)
+/// * `max_retries` - Maximum number of retry attempts for failed requests
+pub async fn check_resource_usage(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    host: Option<&str>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<ResourceUsage> {
+    debug!("Checking resource usage for host {:?}", host);
+
+    let filter = host_filter(host);
+    let host_query = HOST_USAGE_SEARCH_QUERY.replace("{host}", &filter);
+    let process_query = PROCESS_USAGE_SEARCH_QUERY
+        .replace("{host}", &filter)
+        .replace("{limit}", &TOP_PROCESS_LIMIT.to_string());
+
+    let (hosts, processes) = tokio::try_join!(
+        run_usage_search::<HostResourceUsage>(
+            client,
+            base_url,
+            auth_token,
+            &host_query,
+            "HostResourceUsage",
+            max_retries,
+            metrics,
+            circuit_breaker,
+        ),
+        run_usage_search::<ProcessResourceUsage>(
+            client,
+            base_url,
+            auth_token,
+            &process_query,
+            "ProcessResourceUsage",
+            max_retries,
+            metrics,
+            circuit_breaker,
+        ),
+    )?;
+
+    let usage = ResourceUsage::new(
+        RESOURCE_USAGE_WINDOW.to_string(),
+        host.map(str::to_string),
+        hosts,
+        processes,
+    );
+
+    debug!(
+        "Resource usage check complete: {} hosts, {} processes",
+        usage.hosts.len(),
+        usage.processes.len()
+    );
+
+    Ok(usage)
+}
+
+/// Run one usage search to completion and deserialize every result row.
+#[allow(clippy::too_many_arguments)]
+async fn run_usage_search<T: DeserializeOwned>(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    query: &str,
+    type_name: &'static str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<T>> {
+    let options = CreateJobOptions {
+        earliest_time: Some(RESOURCE_USAGE_WINDOW.to_string()),
+        output_mode: Some(OutputMode::Json),
+        ..Default::default()
+    };
+
+    let sid = create_job(
+        client,
+        base_url,
+        auth_token,
+        query,
+        &options,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    debug!("Created search job {} for {} check", sid, type_name);
+
+    let _status = wait_for_job(
+        client,
+        base_url,
+        auth_token,
+        &sid,
+        500,
+        60,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let results = get_results(
+        client,
+        base_url,
+        auth_token,
+        &sid,
+        Some(0),
+        None,
+        OutputMode::Json,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let endpoint = "/services/search/jobs/results";
+    Ok(results
+        .results
+        .into_iter()
+        .filter_map(|v| match serde_json::from_value::<T>(v.clone()) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                warn!(
+                    "Failed to deserialize {} from {}: error={}, value_preview={}",
+                    type_name,
+                    endpoint,
+                    e,
+                    serde_json::to_string(&v).unwrap_or_else(|_| format!("{:?}", v))
+                );
+                if let Some(m) = metrics {
+                    m.record_deserialization_failure(endpoint, type_name);
+                }
+                None
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_filter_quotes_and_escapes() {
+        assert_eq!(host_filter(None), "");
+        assert_eq!(host_filter(Some("  ")), "");
+        assert_eq!(host_filter(Some("idx01")), " host=\"idx01\"");
+        assert_eq!(host_filter(Some("a\"b")), " host=\"a\\\"b\"");
+    }
+
+    #[test]
+    fn test_queries_have_placeholders() {
+        assert!(HOST_USAGE_SEARCH_QUERY.contains("{host}"));
+        assert!(PROCESS_USAGE_SEARCH_QUERY.contains("{host}"));
+        assert!(PROCESS_USAGE_SEARCH_QUERY.contains("{limit}"));
+    }
+}
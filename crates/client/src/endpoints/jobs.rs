@@ -6,9 +6,13 @@ use crate::client::circuit_breaker::CircuitBreaker;
 use crate::endpoints::encode_path_segment;
 use crate::endpoints::extract_entry_content;
 use crate::endpoints::send_request_with_retry;
-use crate::error::{ClientError, Result};
+use crate::error::Result;
 use crate::metrics::MetricsCollector;
-use crate::models::{SearchJobListResponse, SearchJobStatus};
+use crate::models::{
+    JobAge, JobAgeListResponse, JobFilter, JobUsage, JobUsageListResponse, SearchJobListResponse,
+    SearchJobStatus,
+};
+use crate::schema_check::parse_entry_content;
 
 /// Get a specific search job.
 #[allow(clippy::too_many_arguments)]
@@ -41,11 +45,101 @@ pub async fn get_job(
     let resp: serde_json::Value = response.json().await?;
 
     let content = extract_entry_content(&resp)?;
-    serde_json::from_value(content.clone())
-        .map_err(|e| ClientError::InvalidResponse(format!("Failed to parse job: {}", e)))
+    parse_entry_content(content, "job")
 }
 
-/// List all search jobs.
+/// List every search job with its owner, state, and artifact disk usage.
+pub async fn list_job_usage(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<JobUsage>> {
+    let url = format!("{}/services/search/jobs", base_url);
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json"), ("count", "0")]);
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/search/jobs",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: JobUsageListResponse = response.json().await?;
+
+    Ok(resp
+        .entry
+        .into_iter()
+        .map(|e| JobUsage {
+            sid: e.content.sid,
+            owner: e.author,
+            is_done: e.content.is_done,
+            is_realtime: e.content.is_realtime,
+            disk_usage: e.content.disk_usage,
+        })
+        .collect())
+}
+
+/// List every search job matching `filter` with its owner, state, and creation time.
+#[allow(clippy::too_many_arguments)]
+pub async fn list_job_ages(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    filter: &JobFilter,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<JobAge>> {
+    let url = format!("{}/services/search/jobs", base_url);
+
+    let mut query_params: Vec<(String, String)> = vec![
+        ("output_mode".to_string(), "json".to_string()),
+        ("count".to_string(), "0".to_string()),
+    ];
+    if let Some(search) = filter.to_search_query() {
+        query_params.push(("search".to_string(), search));
+    }
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&query_params);
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/search/jobs",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: JobAgeListResponse = response.json().await?;
+
+    Ok(resp
+        .entry
+        .into_iter()
+        .map(|e| JobAge {
+            sid: e.content.sid,
+            owner: e.author,
+            label: e.content.label,
+            is_done: e.content.is_done,
+            dispatch_state: e.content.dispatch_state,
+            published: e.published,
+        })
+        .collect())
+}
+
+/// List search jobs, optionally filtered server-side by owner, app, or dispatch state.
 #[allow(clippy::too_many_arguments)]
 pub async fn list_jobs(
     client: &Client,
@@ -53,6 +147,7 @@ pub async fn list_jobs(
     auth_token: &str,
     count: Option<usize>,
     offset: Option<usize>,
+    filter: &JobFilter,
     max_retries: usize,
     metrics: Option<&MetricsCollector>,
     circuit_breaker: Option<&CircuitBreaker>,
@@ -68,6 +163,9 @@ pub async fn list_jobs(
     if let Some(o) = offset {
         query_params.push(("offset".to_string(), o.to_string()));
     }
+    if let Some(search) = filter.to_search_query() {
+        query_params.push(("search".to_string(), search));
+    }
 
     let builder = client
         .get(&url)
@@ -101,6 +199,8 @@ pub async fn list_jobs(
             disk_usage: e.content.diskUsage,
             priority: e.content.priority,
             label: e.content.label.clone(),
+            ttl: e.content.ttl,
+            performance: None,
         })
         .collect())
 }
@@ -136,6 +236,74 @@ pub async fn cancel_job(
     Ok(())
 }
 
+/// Finalize a running search job.
+///
+/// Finalizing stops the search early while keeping the results gathered so far.
+#[allow(clippy::too_many_arguments)]
+pub async fn finalize_job(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    sid: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    let encoded_sid = encode_path_segment(sid);
+    let url = format!("{}/services/search/jobs/{}/control", base_url, encoded_sid);
+
+    let builder = client
+        .post(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .form(&[("action", "finalize")]);
+    let _response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/search/jobs/{sid}/control",
+        "POST",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Set the time-to-live of a search job's artifacts.
+///
+/// `ttl_secs` is the new lifetime measured from now, not an increment.
+#[allow(clippy::too_many_arguments)]
+pub async fn set_job_ttl(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    sid: &str,
+    ttl_secs: u64,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    let encoded_sid = encode_path_segment(sid);
+    let url = format!("{}/services/search/jobs/{}/control", base_url, encoded_sid);
+    let ttl = ttl_secs.to_string();
+
+    let builder = client
+        .post(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .form(&[("action", "setttl"), ("ttl", ttl.as_str())]);
+    let _response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/search/jobs/{sid}/control",
+        "POST",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    Ok(())
+}
+
 /// Delete a search job.
 #[allow(clippy::too_many_arguments)]
 pub async fn delete_job(
@@ -12,6 +12,7 @@ use crate::models::{
     KvStoreRecord, KvStoreReplicationStatus, KvStoreStatus, ModifyCollectionParams,
 };
 use crate::name_merge::attach_entry_name;
+use crate::schema_check::parse_entry_content;
 
 /// Get KVStore status.
 #[allow(clippy::too_many_arguments)]
@@ -56,9 +57,7 @@ pub async fn get_kvstore_status(
     // - Clustered: { currentMember: {...}, replicationStatus: {...} }
     // - Standalone: { current: {...}, members: {...} }
     if content.get("currentMember").is_some() {
-        return serde_json::from_value(content.clone()).map_err(|e| {
-            ClientError::InvalidResponse(format!("Failed to parse KVStore status: {}", e))
-        });
+        return parse_entry_content(content, "KVStore status");
     }
 
     let current = content.get("current").ok_or_else(|| {
@@ -114,10 +113,32 @@ pub async fn get_kvstore_status(
         replication_status: KvStoreReplicationStatus {
             oplog_size: 0,
             oplog_used: 0.0,
+            replication_lag_secs: members_replication_lag(content.get("members")),
         },
     })
 }
 
+/// Spread between the newest and oldest `optimeDate` (epoch seconds) across
+/// members; `None` unless at least two members report one.
+fn members_replication_lag(members: Option<&serde_json::Value>) -> Option<f64> {
+    let optimes: Vec<f64> = members?
+        .as_object()?
+        .values()
+        .filter_map(|member| member.get("optimeDate"))
+        .filter_map(|optime| {
+            optime
+                .as_f64()
+                .or_else(|| optime.as_str().and_then(|s| s.parse().ok()))
+        })
+        .collect();
+    if optimes.len() < 2 {
+        return None;
+    }
+    let newest = optimes.iter().copied().fold(f64::MIN, f64::max);
+    let oldest = optimes.iter().copied().fold(f64::MAX, f64::min);
+    Some(newest - oldest)
+}
+
 /// List all KVStore collections.
 #[allow(clippy::too_many_arguments)]
 pub async fn list_collections(
@@ -243,8 +264,7 @@ pub async fn create_collection(
         )
     })?;
 
-    let collection: KvStoreCollection = serde_json::from_value(content.clone())
-        .map_err(|e| ClientError::InvalidResponse(format!("Failed to parse collection: {}", e)))?;
+    let collection: KvStoreCollection = parse_entry_content(content, "collection")?;
 
     Ok(attach_entry_name(entry_name, collection))
 }
@@ -324,8 +344,7 @@ pub async fn modify_collection(
         ))
     })?;
 
-    let collection: KvStoreCollection = serde_json::from_value(content.clone())
-        .map_err(|e| ClientError::InvalidResponse(format!("Failed to parse collection: {}", e)))?;
+    let collection: KvStoreCollection = parse_entry_content(content, "collection")?;
 
     Ok(attach_entry_name(entry_name, collection))
 }
@@ -2,6 +2,8 @@
 //!
 //! This module provides HTTP endpoint functions for Splunk license management:
 //! - Reading license usage, pools, and stacks
+//! - Reading licenser messages and slave warning counts
+//! - Summarizing daily license usage from `license_usage.log`
 //! - Installing license files
 //! - Managing license pools (create, modify, delete)
 //! - Activating/deactivating licenses
@@ -16,17 +18,29 @@
 //! - License file validation (handled by Splunk server)
 
 use reqwest::Client;
+use tracing::{debug, warn};
 
 use crate::client::circuit_breaker::CircuitBreaker;
 use crate::endpoints::encode_path_segment;
+use crate::endpoints::search::{
+    CreateJobOptions, OutputMode, create_job, get_results, wait_for_job,
+};
 use crate::endpoints::{form_params_str, send_request_with_retry};
 use crate::error::{ClientError, Result};
 use crate::metrics::MetricsCollector;
 use crate::models::{
-    CreatePoolParams, LicenseActivationResult, LicenseInstallResult, LicensePool, LicenseStack,
-    LicenseUsage, ModifyPoolParams, SplunkResponse,
+    CreatePoolParams, LicenseActivationResult, LicenseInstallResult, LicenseMessage, LicensePool,
+    LicenseSlave, LicenseStack, LicenseUsage, LicenseUsageDay, ModifyPoolParams, SplunkResponse,
 };
 
+/// Search query summarizing daily indexed volume against the stack quota.
+///
+/// This is the rollover summary search behind Splunk's license usage report:
+/// each `RolloverSummary` event covers the previous day, so `_time` is shifted
+/// back half a day before binning. One row per day with `day`, `used_bytes`,
+/// and `quota_bytes`.
+const LICENSE_USAGE_HISTORY_SEARCH_QUERY: &str = r#"search index=_internal source=*license_usage.log* type=RolloverSummary | eval _time=_time-43200 | bin _time span=1d | stats latest(b) as b latest(stacksz) as stacksz by _time slave pool | stats sum(b) as used_bytes max(stacksz) as quota_bytes by _time | fillnull value=0 used_bytes quota_bytes | eval day=strftime(_time, "%Y-%m-%d") | sort 0 _time | table day used_bytes quota_bytes"#;
+
 /// Get license usage information.
 #[allow(clippy::too_many_arguments)]
 pub async fn get_license_usage(
@@ -144,6 +158,174 @@ pub async fn list_license_stacks(
         .collect())
 }
 
+/// List licenser messages (warnings, violations, and other license notices).
+///
+/// GET /services/licenser/messages
+#[allow(clippy::too_many_arguments)]
+pub async fn list_license_messages(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<LicenseMessage>> {
+    let url = format!("{}/services/licenser/messages", base_url);
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json"), ("count", "0")]);
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/licenser/messages",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: SplunkResponse<LicenseMessage> = response.json().await?;
+
+    Ok(resp
+        .entry
+        .into_iter()
+        .map(|e| {
+            let mut content = e.content;
+            content.name = e.name;
+            content
+        })
+        .collect())
+}
+
+/// List license slaves reporting to this license manager.
+///
+/// GET /services/licenser/slaves
+#[allow(clippy::too_many_arguments)]
+pub async fn list_license_slaves(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<LicenseSlave>> {
+    let url = format!("{}/services/licenser/slaves", base_url);
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json"), ("count", "0")]);
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/licenser/slaves",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: SplunkResponse<LicenseSlave> = response.json().await?;
+
+    Ok(resp
+        .entry
+        .into_iter()
+        .map(|e| {
+            let mut content = e.content;
+            content.name = e.name;
+            content
+        })
+        .collect())
+}
+
+/// Summarize daily license usage over the last `days` days.
+///
+/// This function creates a search job over `license_usage.log`, waits for it
+/// to complete, and returns one [`LicenseUsageDay`] per day, oldest first.
+#[allow(clippy::too_many_arguments)]
+pub async fn get_license_usage_history(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    days: u32,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<LicenseUsageDay>> {
+    debug!("Summarizing license usage over the last {} days", days);
+
+    let options = CreateJobOptions {
+        earliest_time: Some(format!("-{}d@d", days)),
+        output_mode: Some(OutputMode::Json),
+        ..Default::default()
+    };
+
+    let sid = create_job(
+        client,
+        base_url,
+        auth_token,
+        LICENSE_USAGE_HISTORY_SEARCH_QUERY,
+        &options,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    // One rollover event per slave and pool per day keeps this search small.
+    let _status = wait_for_job(
+        client,
+        base_url,
+        auth_token,
+        &sid,
+        500,
+        60,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let results = get_results(
+        client,
+        base_url,
+        auth_token,
+        &sid,
+        Some(0),
+        None,
+        OutputMode::Json,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let endpoint = "/services/search/jobs/results";
+    Ok(results
+        .results
+        .into_iter()
+        .filter_map(
+            |v| match serde_json::from_value::<LicenseUsageDay>(v.clone()) {
+                Ok(day) => Some(day),
+                Err(e) => {
+                    warn!(
+                        "Failed to deserialize LicenseUsageDay from {}: error={}, value_preview={}",
+                        endpoint,
+                        e,
+                        serde_json::to_string(&v).unwrap_or_else(|_| format!("{:?}", v))
+                    );
+                    if let Some(m) = metrics {
+                        m.record_deserialization_failure(endpoint, "LicenseUsageDay");
+                    }
+                    None
+                }
+            },
+        )
+        .collect())
+}
+
 /// List all installed licenses.
 ///
 /// GET /services/licenser/licenses
@@ -7,16 +7,18 @@ use reqwest::Client;
 use reqwest::Url;
 
 use crate::client::circuit_breaker::CircuitBreaker;
-use crate::endpoints::encode_path_segment;
 use crate::endpoints::send_request_with_retry;
+use crate::endpoints::{acl_filter_param, encode_path_segment, namespaced_path};
 use crate::error::{ClientError, Result};
 use crate::metrics::MetricsCollector;
-use crate::models::{LookupTable, UploadLookupParams};
+use crate::models::{LookupTable, Namespace, UploadLookupParams};
+use crate::schema_check::parse_entry_content;
 
 /// List all lookup table files.
 ///
 /// This endpoint returns CSV-based lookup files stored in Splunk.
-/// KV store lookups are managed via a different endpoint.
+/// KV store lookups are managed via a different endpoint. With a namespace,
+/// only lookup files defined in that app are listed.
 #[allow(clippy::too_many_arguments)]
 pub async fn list_lookup_tables(
     client: &Client,
@@ -24,11 +26,16 @@ pub async fn list_lookup_tables(
     auth_token: &str,
     count: Option<usize>,
     offset: Option<usize>,
+    namespace: Option<&Namespace>,
     max_retries: usize,
     metrics: Option<&MetricsCollector>,
     circuit_breaker: Option<&CircuitBreaker>,
 ) -> Result<Vec<LookupTable>> {
-    let url = format!("{}/services/data/lookup-table-files", base_url);
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(namespace, "data/lookup-table-files")
+    );
 
     let mut query_params: Vec<(String, String)> =
         vec![("output_mode".to_string(), "json".to_string())];
@@ -39,6 +46,7 @@ pub async fn list_lookup_tables(
     if let Some(o) = offset {
         query_params.push(("offset".to_string(), o.to_string()));
     }
+    query_params.extend(acl_filter_param(namespace));
 
     let builder = client
         .get(&url)
@@ -281,8 +289,7 @@ pub async fn upload_lookup_table(
         ClientError::InvalidResponse("Missing entry content in upload response".to_string())
     })?;
 
-    let lookup: LookupTable = serde_json::from_value(content.clone())
-        .map_err(|e| ClientError::InvalidResponse(format!("Failed to parse lookup: {}", e)))?;
+    let lookup: LookupTable = parse_entry_content(content, "lookup")?;
 
     Ok(lookup)
 }
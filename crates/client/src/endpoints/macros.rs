@@ -12,11 +12,11 @@ use reqwest::Client;
 use tracing::debug;
 
 use crate::client::circuit_breaker::CircuitBreaker;
-use crate::endpoints::encode_path_segment;
+use crate::endpoints::{acl_filter_param, encode_path_segment, namespaced_path};
 use crate::endpoints::{form_params_str, send_request_with_retry};
 use crate::error::{ClientError, Result};
 use crate::metrics::MetricsCollector;
-use crate::models::MacroListResponse;
+use crate::models::{MacroListResponse, Namespace};
 use crate::name_merge::attach_entry_name;
 
 /// Request parameters for creating a new macro.
@@ -95,20 +95,28 @@ impl<'a> UpdateMacroRequest<'a> {
 }
 
 /// List all search macros.
+///
+/// With an app namespace, lists every macro defined in that app (no paging).
 #[allow(clippy::too_many_arguments)]
 pub async fn list_macros(
     client: &Client,
     base_url: &str,
     auth_token: &str,
+    namespace: Option<&Namespace>,
     max_retries: usize,
     metrics: Option<&MetricsCollector>,
     circuit_breaker: Option<&CircuitBreaker>,
 ) -> Result<Vec<crate::models::Macro>> {
     debug!("Listing search macros");
 
-    let url = format!("{}/services/admin/macros", base_url);
+    let url = format!("{}{}", base_url, namespaced_path(namespace, "admin/macros"));
 
-    let query_params: Vec<(String, String)> = vec![("output_mode".to_string(), "json".to_string())];
+    let mut query_params: Vec<(String, String)> =
+        vec![("output_mode".to_string(), "json".to_string())];
+    if let Some(filter) = acl_filter_param(namespace) {
+        query_params.push(filter);
+        query_params.push(("count".to_string(), "0".to_string()));
+    }
 
     let builder = client
         .get(&url)
@@ -143,6 +151,7 @@ pub async fn list_macros(
 /// * `base_url` - The Splunk base URL
 /// * `auth_token` - Authentication token
 /// * `name` - The name of the macro
+/// * `namespace` - Optional owner/app context for the request
 /// * `max_retries` - Maximum number of retries for transient failures
 /// * `metrics` - Optional metrics collector
 ///
@@ -154,14 +163,21 @@ pub async fn get_macro(
     base_url: &str,
     auth_token: &str,
     name: &str,
+    namespace: Option<&Namespace>,
     max_retries: usize,
     metrics: Option<&MetricsCollector>,
     circuit_breaker: Option<&CircuitBreaker>,
 ) -> Result<crate::models::Macro> {
     debug!("Getting macro: {}", name);
 
-    let encoded_name = encode_path_segment(name);
-    let url = format!("{}/services/admin/macros/{}", base_url, encoded_name);
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(
+            namespace,
+            &format!("admin/macros/{}", encode_path_segment(name))
+        )
+    );
 
     let builder = client
         .get(&url)
@@ -206,6 +222,7 @@ pub async fn get_macro(
 /// * `base_url` - The Splunk base URL
 /// * `auth_token` - Authentication token
 /// * `request` - Request parameters for creating the macro
+/// * `namespace` - Optional owner/app context for the request
 /// * `max_retries` - Maximum number of retries
 /// * `metrics` - Optional metrics collector
 #[allow(clippy::too_many_arguments)]
@@ -214,13 +231,14 @@ pub async fn create_macro(
     base_url: &str,
     auth_token: &str,
     request: &CreateMacroRequest<'_>,
+    namespace: Option<&Namespace>,
     max_retries: usize,
     metrics: Option<&MetricsCollector>,
     circuit_breaker: Option<&CircuitBreaker>,
 ) -> Result<()> {
     debug!("Creating macro: {}", request.name);
 
-    let url = format!("{}/services/admin/macros", base_url);
+    let url = format!("{}{}", base_url, namespaced_path(namespace, "admin/macros"));
 
     let mut form_params: Vec<(&str, String)> = vec![];
 
@@ -263,6 +281,7 @@ pub async fn create_macro(
 /// * `base_url` - The Splunk base URL
 /// * `auth_token` - Authentication token
 /// * `request` - Request parameters for updating the macro
+/// * `namespace` - Optional owner/app context for the request
 /// * `max_retries` - Maximum number of retries
 /// * `metrics` - Optional metrics collector
 ///
@@ -274,14 +293,21 @@ pub async fn update_macro(
     base_url: &str,
     auth_token: &str,
     request: &UpdateMacroRequest<'_>,
+    namespace: Option<&Namespace>,
     max_retries: usize,
     metrics: Option<&MetricsCollector>,
     circuit_breaker: Option<&CircuitBreaker>,
 ) -> Result<()> {
     debug!("Updating macro: {}", request.name);
 
-    let encoded_name = encode_path_segment(request.name);
-    let url = format!("{}/services/admin/macros/{}", base_url, encoded_name);
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(
+            namespace,
+            &format!("admin/macros/{}", encode_path_segment(request.name))
+        )
+    );
 
     let mut form_params: Vec<(&str, String)> = Vec::new();
 
@@ -327,6 +353,7 @@ pub async fn update_macro(
 /// * `base_url` - The Splunk base URL
 /// * `auth_token` - Authentication token
 /// * `name` - The name of the macro to delete
+/// * `namespace` - Optional owner/app context for the request
 /// * `max_retries` - Maximum number of retries
 /// * `metrics` - Optional metrics collector
 ///
@@ -338,14 +365,21 @@ pub async fn delete_macro(
     base_url: &str,
     auth_token: &str,
     name: &str,
+    namespace: Option<&Namespace>,
     max_retries: usize,
     metrics: Option<&MetricsCollector>,
     circuit_breaker: Option<&CircuitBreaker>,
 ) -> Result<()> {
     debug!("Deleting macro: {}", name);
 
-    let encoded_name = encode_path_segment(name);
-    let url = format!("{}/services/admin/macros/{}", base_url, encoded_name);
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(
+            namespace,
+            &format!("admin/macros/{}", encode_path_segment(name))
+        )
+    );
 
     let builder = client
         .delete(&url)
@@ -0,0 +1,107 @@
+//! Server message (bulletin board) REST API endpoints.
+//!
+//! Responsibilities:
+//! - Low-level HTTP calls to /services/messages endpoints.
+//! - Normalize message entries into [`ServerMessage`] values.
+//!
+//! Does NOT handle:
+//! - Does not handle auth retry (see client module).
+//! - Does not track which messages were already seen.
+
+use reqwest::Client;
+use tracing::debug;
+
+use crate::client::circuit_breaker::CircuitBreaker;
+use crate::endpoints::encode_path_segment;
+use crate::endpoints::send_request_with_retry;
+use crate::error::{ClientError, Result};
+use crate::metrics::MetricsCollector;
+use crate::models::{ServerMessage, ServerMessageListResponse};
+
+/// List all messages on the server's bulletin board.
+///
+/// Messages are returned most severe first, newest first within a severity.
+pub async fn list_server_messages(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<ServerMessage>> {
+    debug!("Listing server messages");
+
+    let url = format!("{}/services/messages", base_url);
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json"), ("count", "0")]);
+
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/messages",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: ServerMessageListResponse = response.json().await.map_err(|e| {
+        ClientError::InvalidResponse(format!("Failed to parse server messages response: {}", e))
+    })?;
+
+    let mut messages: Vec<ServerMessage> = resp.entry.into_iter().map(Into::into).collect();
+    messages.sort_by(|a, b| {
+        b.severity
+            .cmp(&a.severity)
+            .then_with(|| b.time_created.cmp(&a.time_created))
+    });
+    Ok(messages)
+}
+
+/// Dismiss (delete) a message from the server's bulletin board.
+///
+/// # Returns
+/// Ok(()) on success, or `ClientError::NotFound` if the message doesn't exist.
+pub async fn delete_server_message(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    name: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    debug!("Dismissing server message: {}", name);
+
+    let url = format!(
+        "{}/services/messages/{}",
+        base_url,
+        encode_path_segment(name)
+    );
+
+    let builder = client
+        .delete(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json")]);
+
+    match send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/messages/{name}",
+        "DELETE",
+        metrics,
+        circuit_breaker,
+    )
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(ClientError::ApiError { status: 404, .. }) => Err(ClientError::NotFound(format!(
+            "Message '{}' not found",
+            name
+        ))),
+        Err(e) => Err(e),
+    }
+}
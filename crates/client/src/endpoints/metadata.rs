@@ -0,0 +1,197 @@
+//! Index metadata endpoints.
+
+use reqwest::Client;
+use tracing::{debug, warn};
+
+use crate::client::circuit_breaker::CircuitBreaker;
+use crate::endpoints::audit::escape_spl_string;
+use crate::endpoints::search::{
+    CreateJobOptions, OutputMode, create_job, get_results, wait_for_job,
+};
+use crate::error::Result;
+use crate::metrics::MetricsCollector;
+use crate::models::{MetadataEntry, MetadataType};
+
+/// Search query listing hosts, sources, or sourcetypes with counts and times.
+///
+/// `{type}`, `{field}`, and `{index}` are replaced by the metadata type, its
+/// event field, and the index filter.
+const METADATA_SEARCH_QUERY: &str = r#"| metadata type={type} {index} | rename {field} as name | fields name totalCount firstTime lastTime recentTime"#;
+
+/// Search query listing the indexes each host, source, or sourcetype appears in.
+///
+/// `| metadata` aggregates across indexes, so the index list comes from the
+/// index-time fields `tstats` reads instead.
+const METADATA_INDEXES_QUERY: &str =
+    r#"| tstats values(index) as indexes where {index} by {field} | rename {field} as name"#;
+
+/// List every host, source, or sourcetype with its event count, first/last
+/// seen times, and the indexes it appears in, ordered by name.
+///
+/// Without `index`, all non-internal indexes are covered.
+///
+/// # Arguments
+///
+/// * `client` - HTTP client for making requests
+/// * `base_url` - Base URL of the Splunk server
+/// * `auth_token` - Authentication token
+/// * `metadata_type` - Which kind of value to list
+/// * `index` - Restrict the listing to one index
+/// * `max_retries` - Maximum number of retry attempts for failed requests
+#[allow(clippy::too_many_arguments)]
+pub async fn get_metadata(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    metadata_type: MetadataType,
+    index: Option<&str>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<MetadataEntry>> {
+    debug!("Getting {} metadata (index: {:?})", metadata_type, index);
+
+    let (metadata_query, indexes_query) = metadata_queries(metadata_type, index);
+
+    let (entries, index_rows) = tokio::try_join!(
+        run_metadata_search(
+            client,
+            base_url,
+            auth_token,
+            &metadata_query,
+            max_retries,
+            metrics,
+            circuit_breaker,
+        ),
+        run_metadata_search(
+            client,
+            base_url,
+            auth_token,
+            &indexes_query,
+            max_retries,
+            metrics,
+            circuit_breaker,
+        ),
+    )?;
+
+    let entries = MetadataEntry::attach_indexes(entries, index_rows);
+
+    debug!(
+        "Metadata search complete: {} {}",
+        entries.len(),
+        metadata_type
+    );
+
+    Ok(entries)
+}
+
+/// Build the metadata and index lookup queries for `metadata_type`.
+fn metadata_queries(metadata_type: MetadataType, index: Option<&str>) -> (String, String) {
+    let index_filter = match index {
+        Some(index) => format!("index=\"{}\"", escape_spl_string(index)),
+        None => "index=*".to_string(),
+    };
+    let fill = |query: &str| {
+        query
+            .replace("{type}", metadata_type.as_str())
+            .replace("{field}", metadata_type.field())
+            .replace("{index}", &index_filter)
+    };
+    (fill(METADATA_SEARCH_QUERY), fill(METADATA_INDEXES_QUERY))
+}
+
+/// Run one metadata search over all time and deserialize every result row.
+async fn run_metadata_search(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    query: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<MetadataEntry>> {
+    let options = CreateJobOptions {
+        earliest_time: Some("0".to_string()),
+        output_mode: Some(OutputMode::Json),
+        ..Default::default()
+    };
+
+    let sid = create_job(
+        client,
+        base_url,
+        auth_token,
+        query,
+        &options,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    debug!("Created search job {} for metadata", sid);
+
+    let _status = wait_for_job(
+        client,
+        base_url,
+        auth_token,
+        &sid,
+        500,
+        60,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let results = get_results(
+        client,
+        base_url,
+        auth_token,
+        &sid,
+        Some(0),
+        None,
+        OutputMode::Json,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let endpoint = "/services/search/jobs/results";
+    Ok(results
+        .results
+        .into_iter()
+        .filter_map(
+            |v| match serde_json::from_value::<MetadataEntry>(v.clone()) {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    warn!(
+                        "Failed to deserialize MetadataEntry from {}: error={}, value_preview={}",
+                        endpoint,
+                        e,
+                        serde_json::to_string(&v).unwrap_or_else(|_| format!("{:?}", v))
+                    );
+                    if let Some(m) = metrics {
+                        m.record_deserialization_failure(endpoint, "MetadataEntry");
+                    }
+                    None
+                }
+            },
+        )
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_queries_fill_type_field_and_index() {
+        let (metadata, indexes) = metadata_queries(MetadataType::Sourcetypes, None);
+        assert!(metadata.starts_with("| metadata type=sourcetypes index=* | rename sourcetype"));
+        assert!(indexes.contains("where index=* by sourcetype"));
+
+        let (metadata, _) = metadata_queries(MetadataType::Hosts, Some("we\"b"));
+        assert!(metadata.contains(r#"type=hosts index="we\"b" | rename host as name"#));
+    }
+}
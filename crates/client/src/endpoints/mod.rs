@@ -1,5 +1,7 @@
 //! REST API endpoint implementations.
 
+mod acl;
+mod acs;
 mod alerts;
 mod audit;
 mod auth;
@@ -8,22 +10,34 @@ mod cluster;
 mod configs;
 mod dashboards;
 mod datamodels;
+mod event_types;
 mod form_params;
 mod forwarders;
 pub mod hec;
+mod hec_tokens;
 mod indexes;
+mod ingestion;
 mod inputs;
+mod introspection;
 mod jobs;
 mod kvstore;
 mod license;
 mod logs;
 mod lookups;
 mod macros;
+mod messages;
+mod metadata;
+mod namespace;
 mod parsing;
+mod queues;
 mod request;
+mod rest;
 mod roles;
+mod scheduler;
 pub mod search;
+mod search_load;
 mod search_peers;
+mod security;
 mod server;
 mod shc;
 mod url_encoding;
@@ -33,32 +47,63 @@ mod workload;
 // Internal helper for percent-encoding URL path segments
 pub(crate) use url_encoding::encode_path_segment;
 
+// Internal helpers for `/servicesNS/{owner}/{app}` paths
+pub(crate) use namespace::{acl_filter_param, namespaced_path};
+
 // Re-export form parameter macros for use by endpoint modules
 pub use crate::{form_params, form_params_str};
 
-pub use alerts::{get_fired_alert, list_fired_alerts};
+pub use acl::{get_object_acl, list_object_acls, set_object_acl};
+pub use acs::{
+    create_acs_hec_token, create_acs_index, delete_acs_hec_token, delete_acs_index,
+    list_acs_hec_tokens, list_acs_indexes,
+};
+pub use alerts::{
+    dispatch_alert_action_test, get_fired_alert, get_saved_search_alert_actions,
+    list_alert_actions, list_fired_alerts,
+};
 pub use audit::{get_recent_audit_events, list_audit_events};
 pub use auth::login;
 pub use capabilities::list_capabilities;
 pub use cluster::{
-    decommission_peer, get_cluster_info, get_cluster_peers, rebalance_cluster, remove_peers,
-    set_maintenance_mode,
+    control_data_rebalance, decommission_peer, get_cluster_excess_buckets, get_cluster_fixups,
+    get_cluster_index_copies, get_cluster_info, get_cluster_peers, get_cluster_search_heads,
+    rebalance_cluster, remove_excess_buckets, remove_peers, set_maintenance_mode,
+};
+pub use configs::{
+    create_config_stanza, get_config_stanza, list_config_files, list_config_stanza_layers,
+    list_config_stanzas,
 };
-pub use configs::{get_config_stanza, list_config_files, list_config_stanzas};
 pub use dashboards::{get_dashboard, list_dashboards};
 pub use datamodels::{get_datamodel, list_datamodels};
+pub use event_types::list_event_types;
 pub use forwarders::list_forwarders;
-pub use indexes::{create_index, delete_index, get_index, list_indexes, modify_index};
-pub use inputs::{disable_input, enable_input, list_inputs_by_type};
-pub use jobs::{cancel_job, delete_job, get_job, list_jobs};
+pub use hec_tokens::{
+    create_hec_token, delete_hec_token, disable_hec_token, enable_hec_token, get_hec_token,
+    list_hec_tokens, update_hec_token,
+};
+pub use indexes::{
+    create_index, delete_index, get_index, list_indexes, modify_index, roll_hot_buckets,
+};
+pub use ingestion::get_ingestion_stats;
+pub use inputs::{
+    create_input, delete_input, disable_input, enable_input, get_input_throughput,
+    list_inputs_by_type,
+};
+pub use introspection::{RESOURCE_USAGE_WINDOW, check_resource_usage};
+pub use jobs::{
+    cancel_job, delete_job, finalize_job, get_job, list_job_ages, list_job_usage, list_jobs,
+    set_job_ttl,
+};
 pub use kvstore::{
     create_collection, delete_collection, delete_collection_record, get_kvstore_status,
     insert_collection_record, list_collection_records, list_collections, modify_collection,
 };
 pub use license::{
     activate_license, create_license_pool, deactivate_license, delete_license_pool,
-    get_license_usage, install_license, list_installed_licenses, list_license_pools,
-    list_license_stacks, modify_license_pool,
+    get_license_usage, get_license_usage_history, install_license, list_installed_licenses,
+    list_license_messages, list_license_pools, list_license_slaves, list_license_stacks,
+    modify_license_pool,
 };
 pub use logs::get_internal_logs;
 pub use lookups::{
@@ -68,21 +113,35 @@ pub use macros::{
     CreateMacroRequest, UpdateMacroRequest, create_macro, delete_macro, get_macro, list_macros,
     update_macro,
 };
+pub use messages::{delete_server_message, list_server_messages};
+pub use metadata::get_metadata;
 pub use parsing::check_log_parsing_health;
+pub use queues::check_queue_health;
 pub use request::send_request_with_retry;
+pub use rest::{RestMethod, normalize_rest_path, parse_params, raw_request, split_path_query};
 pub use roles::{create_role, delete_role, list_roles, modify_role};
+pub use scheduler::{check_scheduler_health, scheduler_time_window};
 pub use search::{
     CreateJobOptions, OutputMode, SavedSearchUpdateParams, create_job, create_saved_search,
-    delete_saved_search, get_job_status, get_results, get_saved_search, list_saved_searches,
-    update_saved_search, wait_for_job, wait_for_job_with_progress,
+    delete_saved_search, get_job_status, get_results, get_saved_search,
+    list_saved_search_ownership, list_saved_searches, set_saved_search_acl,
+    set_saved_search_disabled, update_saved_search, wait_for_job, wait_for_job_with_finalize,
+    wait_for_job_with_progress,
 };
-pub use search_peers::list_search_peers;
+pub use search_load::get_user_search_usage;
+pub use search_peers::{
+    add_search_peer, list_search_peers, remove_search_peer, set_search_peer_quarantine,
+};
+pub use security::check_security_health;
 pub use server::*;
 pub use shc::{
     add_shc_member, get_shc_captain, get_shc_config, get_shc_members, get_shc_status,
     remove_shc_member, rolling_restart_shc, set_shc_captain,
 };
-pub use users::{create_user, delete_user, list_users, modify_user};
+pub use users::{
+    create_user, delete_user, get_current_context, get_current_username, get_password_policy,
+    list_users, modify_user, unlock_user,
+};
 pub use workload::{list_workload_pools, list_workload_rules};
 
 use crate::error::ClientError;
@@ -0,0 +1,106 @@
+//! Namespace-aware REST path construction.
+//!
+//! Purpose: Route knowledge-object requests to `/servicesNS/{owner}/{app}` when a
+//! namespace is given, and to `/services` otherwise.
+//! Responsibilities: Build namespaced paths and the matching ACL filter for listings.
+//! Non-scope: Choosing a namespace (callers decide) and ACL changes.
+//! Invariants/Assumptions: Owner and app are encoded as single path segments; unset parts
+//! become the `-` wildcard.
+
+use crate::endpoints::encode_path_segment;
+use crate::models::Namespace;
+
+/// Wildcard segment for an unset owner or app.
+const WILDCARD: &str = "-";
+
+/// Build a REST path (starting with `/`) for `path` under the given namespace.
+///
+/// `path` is relative to the services root, e.g. `saved/searches`.
+pub fn namespaced_path(namespace: Option<&Namespace>, path: &str) -> String {
+    match namespace {
+        None => format!("/services/{}", path),
+        Some(ns) => format!(
+            "/servicesNS/{}/{}/{}",
+            segment(ns.owner.as_deref()),
+            segment(ns.app.as_deref()),
+            path
+        ),
+    }
+}
+
+/// Query parameter restricting a listing to objects defined in the namespace's app
+/// and, when set, shared at the namespace's sharing level.
+///
+/// Without it, an app context also returns objects shared globally from other apps.
+pub fn acl_filter_param(namespace: Option<&Namespace>) -> Option<(String, String)> {
+    let namespace = namespace?;
+    let terms: Vec<String> = [
+        ("app", namespace.app.as_deref()),
+        ("sharing", namespace.sharing.as_deref()),
+    ]
+    .into_iter()
+    .filter_map(|(field, value)| {
+        let value = value.filter(|value| !value.is_empty() && *value != WILDCARD)?;
+        Some(format!("eai:acl.{}=\"{}\"", field, value))
+    })
+    .collect();
+
+    (!terms.is_empty()).then(|| ("search".to_string(), terms.join(" ")))
+}
+
+fn segment(value: Option<&str>) -> String {
+    match value {
+        Some(value) if !value.is_empty() => encode_path_segment(value),
+        _ => WILDCARD.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_namespaced_path() {
+        assert_eq!(
+            namespaced_path(None, "saved/searches"),
+            "/services/saved/searches"
+        );
+        assert_eq!(
+            namespaced_path(Some(&Namespace::app("search")), "saved/searches"),
+            "/servicesNS/-/search/saved/searches"
+        );
+        let ns = Namespace {
+            owner: Some("jane doe".to_string()),
+            ..Namespace::default()
+        };
+        assert_eq!(
+            namespaced_path(Some(&ns), "admin/macros"),
+            "/servicesNS/jane%20doe/-/admin/macros"
+        );
+    }
+
+    #[test]
+    fn test_acl_filter_param() {
+        assert_eq!(acl_filter_param(None), None);
+        assert_eq!(acl_filter_param(Some(&Namespace::default())), None);
+        assert_eq!(
+            acl_filter_param(Some(&Namespace::app("Splunk_TA_nix"))),
+            Some((
+                "search".to_string(),
+                "eai:acl.app=\"Splunk_TA_nix\"".to_string()
+            ))
+        );
+        let private = Namespace {
+            owner: Some("jane".to_string()),
+            app: Some("search".to_string()),
+            sharing: Some("user".to_string()),
+        };
+        assert_eq!(
+            acl_filter_param(Some(&private)),
+            Some((
+                "search".to_string(),
+                "eai:acl.app=\"search\" eai:acl.sharing=\"user\"".to_string()
+            ))
+        );
+    }
+}
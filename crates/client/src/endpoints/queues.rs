@@ -0,0 +1,125 @@
+//! Ingestion queue health endpoints.
+
+use reqwest::Client;
+use tracing::{debug, warn};
+
+use crate::client::circuit_breaker::CircuitBreaker;
+use crate::endpoints::search::{
+    CreateJobOptions, OutputMode, create_job, get_results, wait_for_job,
+};
+use crate::error::Result;
+use crate::metrics::MetricsCollector;
+use crate::models::{PipelineQueueHealth, QueueHealth};
+
+/// Search query summarizing queue fill and blocking per pipeline from `metrics.log`.
+///
+/// Produces one row per host, ingestion pipeline, and queue. Older releases
+/// log `max_size`/`current_size` instead of the `_kb` fields, and omit
+/// `ingest_pipe` when only one pipeline is configured.
+const QUEUE_HEALTH_SEARCH_QUERY: &str = r#"search index=_internal source=*metrics.log* group=queue | eval max_kb=coalesce(max_size_kb, max_size), current_kb=coalesce(current_size_kb, current_size), fill_pct=if(max_kb > 0, current_kb * 100 / max_kb, 0), ingest_pipe=coalesce(ingest_pipe, "0") | stats avg(fill_pct) as avg_fill_pct max(fill_pct) as max_fill_pct count(eval(blocked=="true")) as blocked_count count as samples by host ingest_pipe name | rename ingest_pipe as pipeline name as queue"#;
+
+/// Summarize ingestion queue fill ratios and blocked counts since `earliest_time`.
+///
+/// This function creates a search job over `metrics.log`, waits for it to
+/// complete, and folds the per-queue rows into a [`QueueHealth`].
+///
+/// # Arguments
+///
+/// * `client` - HTTP client for making requests
+/// * `base_url` - Base URL of the Splunk server
+/// * `auth_token` - Authentication token
+/// * `earliest_time` - Start of the window as a Splunk relative time (e.g. `-15m`)
+/// * `max_retries` - Maximum number of retry attempts for failed requests
+#[allow(clippy::too_many_arguments)]
+pub async fn check_queue_health(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    earliest_time: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<QueueHealth> {
+    debug!("Checking queue health since {}", earliest_time);
+
+    let options = CreateJobOptions {
+        earliest_time: Some(earliest_time.to_string()),
+        output_mode: Some(OutputMode::Json),
+        ..Default::default()
+    };
+
+    let sid = create_job(
+        client,
+        base_url,
+        auth_token,
+        QUEUE_HEALTH_SEARCH_QUERY,
+        &options,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    debug!("Created search job {} for queue health check", sid);
+
+    let _status = wait_for_job(
+        client,
+        base_url,
+        auth_token,
+        &sid,
+        500,
+        60,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    // count=0 returns every row; large deployments have many queues per pipeline.
+    let results = get_results(
+        client,
+        base_url,
+        auth_token,
+        &sid,
+        Some(0),
+        None,
+        OutputMode::Json,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let endpoint = "/services/search/jobs/results";
+    let queues: Vec<PipelineQueueHealth> = results
+        .results
+        .into_iter()
+        .filter_map(
+            |v| match serde_json::from_value::<PipelineQueueHealth>(v.clone()) {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    warn!(
+                        "Failed to deserialize PipelineQueueHealth from {}: error={}, value_preview={}",
+                        endpoint,
+                        e,
+                        serde_json::to_string(&v).unwrap_or_else(|_| format!("{:?}", v))
+                    );
+                    if let Some(m) = metrics {
+                        m.record_deserialization_failure(endpoint, "PipelineQueueHealth");
+                    }
+                    None
+                }
+            },
+        )
+        .collect();
+
+    let health = QueueHealth::from_queues(earliest_time.to_string(), queues);
+
+    debug!(
+        "Queue health check complete: {} queues, {} blocked samples",
+        health.queues.len(),
+        health.blocked_count
+    );
+
+    Ok(health)
+}
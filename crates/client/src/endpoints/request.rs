@@ -109,8 +109,10 @@ fn is_retryable_transport_error(error: &reqwest::Error) -> bool {
 /// Parses a Splunk error response body into a displayable message string.
 ///
 /// Attempts to parse the body as JSON containing Splunk's standard error message
-/// format (`SplunkMessages`). If successful, formats each message as "{type}: {text}"
-/// and joins them with "; ". If parsing fails, returns the raw body as a fallback.
+/// format (`SplunkMessages`), or as the XML equivalent
+/// (`<response><messages><msg type="ERROR">...</msg></messages></response>`).
+/// If successful, formats each message as "{type}: {text}" and joins them with "; ".
+/// If parsing fails, returns the raw body as a fallback.
 ///
 /// # Arguments
 ///
@@ -127,11 +129,40 @@ fn parse_splunk_error_response(body: &str) -> String {
             .map(|msg| format!("{}: {}", msg.message_type, msg.text))
             .collect::<Vec<_>>()
             .join("; ")
+    } else if let Some(messages) = parse_xml_messages(body) {
+        messages
     } else {
         body.to_string()
     }
 }
 
+/// Formats the `<msg type="...">` elements of an XML error body, if it has any.
+fn parse_xml_messages(body: &str) -> Option<String> {
+    let mut messages = Vec::new();
+    let mut rest = body;
+    while let Some(start) = rest.find("<msg") {
+        let tag_end = start + rest[start..].find('>')?;
+        let tag = &rest[start..tag_end];
+        let close = tag_end + rest[tag_end..].find("</msg>")?;
+        let message_type = tag
+            .split_once("type=\"")
+            .and_then(|(_, value)| value.split_once('"'))
+            .map_or("ERROR", |(value, _)| value);
+        let text = unescape_xml(rest[tag_end + 1..close].trim());
+        messages.push(format!("{}: {}", message_type, text));
+        rest = &rest[close + "</msg>".len()..];
+    }
+    (!messages.is_empty()).then(|| messages.join("; "))
+}
+
+fn unescape_xml(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
 /// Returns `true` when a SHC endpoint reports expected standalone/unclustered unavailability.
 ///
 /// Standalone Splunk instances can return HTTP 503 for `/services/shcluster/*` requests even
@@ -169,6 +200,7 @@ fn is_expected_shc_unavailable(endpoint: &str, status: u16, message: &str) -> bo
 /// - Logs retry attempts with `tracing::debug`
 /// - Returns `MaxRetriesExceeded` error when retries are exhausted
 /// - Records metrics for request duration, retries, and errors (if metrics collector provided)
+/// - Runs each attempt through the client's request middleware (see [`crate::middleware`])
 ///
 /// # Arguments
 ///
@@ -263,7 +295,9 @@ pub async fn send_request_with_retry(
                 // on the first attempt.
                 if attempt == 0 {
                     warn!("Request builder cannot be cloned, single attempt only");
-                    let result = builder.send().await.map_err(ClientError::from);
+                    let result = crate::middleware::send_attempt(builder, endpoint, attempt)
+                        .await
+                        .map_err(ClientError::from);
                     // Record metrics for the result
                     if let Some(m) = metrics {
                         let duration = start_time.elapsed();
@@ -292,7 +326,7 @@ pub async fn send_request_with_retry(
             }
         };
 
-        match attempt_builder.send().await {
+        match crate::middleware::send_attempt(attempt_builder, endpoint, attempt).await {
             Ok(response) => {
                 let status = response.status();
                 let status_u16 = status.as_u16();
@@ -534,6 +568,22 @@ mod tests {
         assert_eq!(result, "");
     }
 
+    #[test]
+    fn test_parse_splunk_error_response_with_xml_messages() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+  <messages>
+    <msg type="ERROR">You (user=bob) do not have permission to perform this operation (requires capability: edit_indexes).</msg>
+    <msg type="WARN">Index &apos;web&apos; &amp; more</msg>
+  </messages>
+</response>"#;
+        let result = parse_splunk_error_response(xml);
+        assert_eq!(
+            result,
+            "ERROR: You (user=bob) do not have permission to perform this operation (requires capability: edit_indexes).; WARN: Index 'web' & more"
+        );
+    }
+
     #[test]
     fn test_parse_splunk_error_response_with_invalid_json() {
         let body = "Raw error message without JSON structure";
@@ -0,0 +1,215 @@
+//! Raw REST API requests for endpoints without a typed wrapper.
+//!
+//! Responsibilities:
+//! - Send GET or POST requests to an arbitrary `/services` or `/servicesNS` path.
+//! - Reject paths outside those roots before sending the auth token anywhere.
+//! - Ask for JSON output unless the caller chose an `output_mode`.
+//! - Decode the body as JSON, falling back to the raw text (e.g. Atom XML).
+//!
+//! Does NOT handle:
+//! - Does not handle auth retry (see client module).
+//! - Does not interpret the response structure.
+
+use std::fmt;
+use std::str::FromStr;
+
+use reqwest::Client;
+use tracing::debug;
+
+use crate::client::circuit_breaker::CircuitBreaker;
+use crate::endpoints::send_request_with_retry;
+use crate::error::{ClientError, Result};
+use crate::metrics::MetricsCollector;
+
+/// HTTP methods supported by raw REST requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestMethod {
+    Get,
+    Post,
+}
+
+impl RestMethod {
+    /// The method name as sent on the wire.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Post => "POST",
+        }
+    }
+}
+
+impl fmt::Display for RestMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RestMethod {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "GET" => Ok(Self::Get),
+            "POST" => Ok(Self::Post),
+            _ => Err(format!("unsupported method '{}' (use GET or POST)", s)),
+        }
+    }
+}
+
+/// Split `key=value` pairs joined by `&` (as in a query string or `curl -d`).
+///
+/// A pair without `=` becomes a key with an empty value; empty pairs are skipped.
+pub fn parse_params(params: &str) -> Vec<(String, String)> {
+    params
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) => (key.to_string(), value.to_string()),
+            None => (pair.to_string(), String::new()),
+        })
+        .collect()
+}
+
+/// Split an endpoint path like `/services/data/indexes?count=5` into the path
+/// and its query parameters.
+pub fn split_path_query(path: &str) -> (String, Vec<(String, String)>) {
+    match path.split_once('?') {
+        Some((path, query)) => (path.to_string(), parse_params(query)),
+        None => (path.to_string(), Vec::new()),
+    }
+}
+
+/// Check that `path` names a REST endpoint under `/services` or `/servicesNS`.
+///
+/// Paths without a leading `/` are accepted and normalized; `..` segments are
+/// rejected so a path cannot climb out of the REST API roots.
+pub fn normalize_rest_path(path: &str) -> Result<String> {
+    let path = if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("/{}", path)
+    };
+
+    let root = path[1..].split('/').next().unwrap_or_default();
+    if root != "services" && root != "servicesNS" {
+        return Err(ClientError::InvalidRequest(format!(
+            "REST path must start with /services or /servicesNS: {}",
+            path
+        )));
+    }
+    if path.split('/').any(|segment| segment == "..") {
+        return Err(ClientError::InvalidRequest(format!(
+            "REST path must not contain '..': {}",
+            path
+        )));
+    }
+    Ok(path)
+}
+
+/// Send an authenticated request to `path` and return the decoded body.
+///
+/// JSON bodies are returned as-is; anything else (Atom XML, plain text) is
+/// returned as a JSON string. `output_mode=json` is added unless `query`
+/// already sets an `output_mode`. Paths are checked by [`normalize_rest_path`].
+#[allow(clippy::too_many_arguments)]
+pub async fn raw_request(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    method: RestMethod,
+    path: &str,
+    query: &[(String, String)],
+    form_body: &[(String, String)],
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<serde_json::Value> {
+    let path = normalize_rest_path(path)?;
+    debug!("Sending raw {} request to {}", method, path);
+
+    let url = format!("{}{}", base_url, path);
+
+    let mut builder = match method {
+        RestMethod::Get => client.get(&url),
+        RestMethod::Post => client.post(&url).form(form_body),
+    }
+    .header("Authorization", format!("Bearer {}", auth_token))
+    .query(query);
+    if !query.iter().any(|(key, _)| key == "output_mode") {
+        builder = builder.query(&[("output_mode", "json")]);
+    }
+
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "{raw}",
+        method.as_str(),
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let body = response.text().await.map_err(|e| {
+        ClientError::InvalidResponse(format!("Failed to read response body: {}", e))
+    })?;
+
+    Ok(serde_json::from_str(&body).unwrap_or(serde_json::Value::String(body)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rest_method_from_str() {
+        assert_eq!("get".parse::<RestMethod>(), Ok(RestMethod::Get));
+        assert_eq!("POST".parse::<RestMethod>(), Ok(RestMethod::Post));
+        assert!("DELETE".parse::<RestMethod>().is_err());
+    }
+
+    #[test]
+    fn test_normalize_rest_path() {
+        assert_eq!(
+            normalize_rest_path("services/server/info").unwrap(),
+            "/services/server/info"
+        );
+        assert_eq!(
+            normalize_rest_path("/servicesNS/nobody/search/saved/searches").unwrap(),
+            "/servicesNS/nobody/search/saved/searches"
+        );
+        assert!(normalize_rest_path("/services").is_ok());
+
+        for path in [
+            "/",
+            "/en-US/app/search",
+            "/servicesfoo",
+            "/services/../en-US",
+        ] {
+            assert!(
+                matches!(
+                    normalize_rest_path(path),
+                    Err(ClientError::InvalidRequest(_))
+                ),
+                "{}",
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_path_query() {
+        let (path, query) = split_path_query("/services/data/indexes?count=5&search=name=main");
+        assert_eq!(path, "/services/data/indexes");
+        assert_eq!(
+            query,
+            vec![
+                ("count".to_string(), "5".to_string()),
+                ("search".to_string(), "name=main".to_string()),
+            ]
+        );
+
+        let (path, query) = split_path_query("/services/server/info");
+        assert_eq!(path, "/services/server/info");
+        assert!(query.is_empty());
+    }
+}
@@ -10,6 +10,7 @@ use crate::error::{ClientError, Result};
 use crate::metrics::MetricsCollector;
 use crate::models::{CreateRoleParams, ModifyRoleParams, Role, RoleListResponse};
 use crate::name_merge::attach_entry_name;
+use crate::schema_check::parse_entry_content;
 
 /// List all roles.
 #[allow(clippy::too_many_arguments)]
@@ -114,8 +115,7 @@ pub async fn create_role(
         ClientError::InvalidResponse("Missing entry content in create role response".to_string())
     })?;
 
-    let role: Role = serde_json::from_value(content.clone())
-        .map_err(|e| ClientError::InvalidResponse(format!("Failed to parse role: {}", e)))?;
+    let role: Role = parse_entry_content(content, "role")?;
 
     Ok(attach_entry_name(entry_name, role))
 }
@@ -190,8 +190,7 @@ pub async fn modify_role(
         ))
     })?;
 
-    let role: Role = serde_json::from_value(content.clone())
-        .map_err(|e| ClientError::InvalidResponse(format!("Failed to parse role: {}", e)))?;
+    let role: Role = parse_entry_content(content, "role")?;
 
     Ok(attach_entry_name(entry_name, role))
 }
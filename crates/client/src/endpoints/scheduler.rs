@@ -0,0 +1,156 @@
+//! Scheduled search health endpoints.
+
+use reqwest::Client;
+use tracing::{debug, warn};
+
+use crate::client::circuit_breaker::CircuitBreaker;
+use crate::endpoints::search::{
+    CreateJobOptions, OutputMode, create_job, get_results, wait_for_job,
+};
+use crate::error::Result;
+use crate::metrics::MetricsCollector;
+use crate::models::{ScheduledSearchHealth, SchedulerHealth};
+
+/// Search query summarizing scheduled search outcomes from the scheduler log.
+///
+/// Produces one row per scheduled search (app, name, owner) with run counts by
+/// outcome. `delegated_remote_error` covers runs a search head cluster member
+/// failed to dispatch on behalf of the captain.
+const SCHEDULER_HEALTH_SEARCH_QUERY: &str = r#"search index=_internal sourcetype=scheduler status=* savedsearch_name=* | eval outcome=case(status=="skipped", "skipped", status=="failed" OR status=="delegated_remote_error", "failed", status=="success" OR status=="delegated_remote_completion", "succeeded", true(), "other") | stats count as runs count(eval(outcome=="succeeded")) as succeeded count(eval(outcome=="skipped")) as skipped count(eval(outcome=="failed")) as failed latest(reason) as last_reason max(_time) as last_run by app savedsearch_name user | eval last_run=strftime(last_run, "%Y-%m-%dT%H:%M:%S%z")"#;
+
+/// Convert a look-back window such as `24h` or `-7d@d` into the relative
+/// earliest time used by the scheduler health search.
+///
+/// Bare durations are made relative to now by prefixing `-`; anything else
+/// (already signed, snapped, or empty) is passed through, with empty meaning
+/// the default 24 hours.
+pub fn scheduler_time_window(since: &str) -> String {
+    let since = since.trim();
+    if since.is_empty() {
+        "-24h".to_string()
+    } else if since.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("-{since}")
+    } else {
+        since.to_string()
+    }
+}
+
+/// Summarize skipped and failed scheduled searches since `earliest_time`.
+///
+/// This function creates a search job over the scheduler log, waits for it to
+/// complete, and folds the per-search rows into a [`SchedulerHealth`].
+///
+/// # Arguments
+///
+/// * `client` - HTTP client for making requests
+/// * `base_url` - Base URL of the Splunk server
+/// * `auth_token` - Authentication token
+/// * `earliest_time` - Start of the window as a Splunk relative time (e.g. `-24h`)
+/// * `max_retries` - Maximum number of retry attempts for failed requests
+#[allow(clippy::too_many_arguments)]
+pub async fn check_scheduler_health(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    earliest_time: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<SchedulerHealth> {
+    debug!("Checking scheduler health since {}", earliest_time);
+
+    let options = CreateJobOptions {
+        earliest_time: Some(earliest_time.to_string()),
+        output_mode: Some(OutputMode::Json),
+        ..Default::default()
+    };
+
+    let sid = create_job(
+        client,
+        base_url,
+        auth_token,
+        SCHEDULER_HEALTH_SEARCH_QUERY,
+        &options,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    debug!("Created search job {} for scheduler health check", sid);
+
+    // Scheduler logs are small; the same 60 second budget as parsing health applies.
+    let _status = wait_for_job(
+        client,
+        base_url,
+        auth_token,
+        &sid,
+        500,
+        60,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    // count=0 returns every row; the totals need all searches, not just the first page.
+    let results = get_results(
+        client,
+        base_url,
+        auth_token,
+        &sid,
+        Some(0),
+        None,
+        OutputMode::Json,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let endpoint = "/services/search/jobs/results";
+    let searches: Vec<ScheduledSearchHealth> = results
+        .results
+        .into_iter()
+        .filter_map(
+            |v| match serde_json::from_value::<ScheduledSearchHealth>(v.clone()) {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    warn!(
+                        "Failed to deserialize ScheduledSearchHealth from {}: error={}, value_preview={}",
+                        endpoint,
+                        e,
+                        serde_json::to_string(&v).unwrap_or_else(|_| format!("{:?}", v))
+                    );
+                    if let Some(m) = metrics {
+                        m.record_deserialization_failure(endpoint, "ScheduledSearchHealth");
+                    }
+                    None
+                }
+            },
+        )
+        .collect();
+
+    let health = SchedulerHealth::from_searches(earliest_time.to_string(), searches);
+
+    debug!(
+        "Scheduler health check complete: {} runs, {} skipped, {} failed",
+        health.total_runs, health.skipped, health.failed
+    );
+
+    Ok(health)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scheduler_time_window() {
+        assert_eq!(scheduler_time_window("24h"), "-24h");
+        assert_eq!(scheduler_time_window(" 7d@d "), "-7d@d");
+        assert_eq!(scheduler_time_window("-15m"), "-15m");
+        assert_eq!(scheduler_time_window("@d"), "@d");
+        assert_eq!(scheduler_time_window(""), "-24h");
+    }
+}
@@ -5,7 +5,7 @@
 //! # What this module handles:
 //! - Creating search jobs
 //! - Getting job status
-//! - Waiting for job completion
+//! - Waiting for job completion (optionally finalizing at a result threshold)
 //! - Retrieving search results
 //!
 //! # What this module does NOT handle:
@@ -19,10 +19,11 @@ use crate::redact_query;
 
 use crate::client::circuit_breaker::CircuitBreaker;
 use crate::endpoints::encode_path_segment;
-use crate::endpoints::{extract_entry_content, send_request_with_retry};
+use crate::endpoints::{extract_entry_content, finalize_job, send_request_with_retry};
 use crate::error::{ClientError, Result};
 use crate::metrics::MetricsCollector;
 use crate::models::{SearchJobResults, SearchJobStatus};
+use crate::schema_check::parse_entry_content;
 
 use super::types::{CreateJobOptions, OutputMode};
 
@@ -165,8 +166,7 @@ pub async fn get_job_status(
     let resp: serde_json::Value = response.json().await?;
 
     let content = extract_entry_content(&resp)?;
-    serde_json::from_value(content.clone())
-        .map_err(|e| ClientError::InvalidResponse(format!("Failed to parse job status: {}", e)))
+    parse_entry_content(content, "job status")
 }
 
 /// Wait for a search job to complete.
@@ -210,12 +210,49 @@ pub async fn wait_for_job_with_progress(
     poll_interval_ms: u64,
     max_wait_secs: u64,
     max_retries: usize,
+    progress_cb: Option<&mut (dyn FnMut(f64) + Send)>,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<SearchJobStatus> {
+    wait_for_job_with_finalize(
+        client,
+        base_url,
+        auth_token,
+        sid,
+        poll_interval_ms,
+        max_wait_secs,
+        max_retries,
+        None,
+        progress_cb,
+        metrics,
+        circuit_breaker,
+    )
+    .await
+}
+
+/// Wait for a search job to complete, finalizing it early at a result threshold.
+///
+/// When `finalize_after` is set, the job is finalized once its result or event
+/// count reaches the threshold; polling then continues until Splunk marks the
+/// finalized job done, so the returned status reflects the partial results.
+/// The callback receives `done_progress` as a fraction (0.0–1.0).
+#[allow(clippy::too_many_arguments)]
+pub async fn wait_for_job_with_finalize(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    sid: &str,
+    poll_interval_ms: u64,
+    max_wait_secs: u64,
+    max_retries: usize,
+    finalize_after: Option<usize>,
     mut progress_cb: Option<&mut (dyn FnMut(f64) + Send)>,
     metrics: Option<&MetricsCollector>,
     circuit_breaker: Option<&CircuitBreaker>,
 ) -> Result<SearchJobStatus> {
     let start = std::time::Instant::now();
     let max_wait = std::time::Duration::from_secs(max_wait_secs);
+    let mut finalize_requested = false;
 
     loop {
         let status = get_job_status(
@@ -238,6 +275,30 @@ pub async fn wait_for_job_with_progress(
             return Ok(status);
         }
 
+        if let Some(threshold) = finalize_after
+            && !finalize_requested
+            && !status.is_finalized
+            && status.result_count.max(status.event_count) >= threshold
+        {
+            debug!(
+                "Finalizing job {} after {} results ({} events)",
+                sid, status.result_count, status.event_count
+            );
+            finalize_job(
+                client,
+                base_url,
+                auth_token,
+                sid,
+                max_retries,
+                metrics,
+                circuit_breaker,
+            )
+            .await?;
+            finalize_requested = true;
+            // Re-check immediately; finalization usually completes within one poll.
+            continue;
+        }
+
         if start.elapsed() > max_wait {
             return Err(ClientError::OperationTimeout {
                 operation: "wait_for_job",
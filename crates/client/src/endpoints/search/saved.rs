@@ -8,6 +8,9 @@
 //! - Updating saved searches
 //! - Deleting saved searches
 //! - Getting a single saved search by name
+//! - Listing saved search ownership (ACL + schedule) across apps
+//! - Reassigning saved search ownership via the ACL endpoint
+//! - Enabling/disabling a saved search in its own owner/app namespace
 //!
 //! # What this module does NOT handle:
 //! - Search job execution (see [`super::jobs`])
@@ -17,15 +20,20 @@ use reqwest::Client;
 use tracing::debug;
 
 use crate::client::circuit_breaker::CircuitBreaker;
-use crate::endpoints::encode_path_segment;
 use crate::endpoints::form_params_str;
 use crate::endpoints::send_request_with_retry;
+use crate::endpoints::{acl_filter_param, encode_path_segment, namespaced_path};
 use crate::error::{ClientError, Result};
 use crate::metrics::MetricsCollector;
-use crate::models::SavedSearchListResponse;
+use crate::models::{
+    Namespace, SavedSearchListResponse, SavedSearchOwnership, SavedSearchSchedule, SplunkResponse,
+};
 use crate::name_merge::attach_entry_name;
 
 /// List saved searches.
+///
+/// With a namespace, lists from `/servicesNS/{owner}/{app}`, limited to searches
+/// defined in that app.
 #[allow(clippy::too_many_arguments)]
 pub async fn list_saved_searches(
     client: &Client,
@@ -33,13 +41,18 @@ pub async fn list_saved_searches(
     auth_token: &str,
     count: Option<usize>,
     offset: Option<usize>,
+    namespace: Option<&Namespace>,
     max_retries: usize,
     metrics: Option<&MetricsCollector>,
     circuit_breaker: Option<&CircuitBreaker>,
 ) -> Result<Vec<crate::models::SavedSearch>> {
     debug!("Listing saved searches");
 
-    let url = format!("{}/services/saved/searches", base_url);
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(namespace, "saved/searches")
+    );
 
     let mut query_params: Vec<(String, String)> =
         vec![("output_mode".to_string(), "json".to_string())];
@@ -50,6 +63,7 @@ pub async fn list_saved_searches(
     if let Some(o) = offset {
         query_params.push(("offset".to_string(), o.to_string()));
     }
+    query_params.extend(acl_filter_param(namespace));
 
     let builder = client
         .get(&url)
@@ -86,13 +100,18 @@ pub async fn create_saved_search(
     auth_token: &str,
     name: &str,
     search: &str,
+    namespace: Option<&Namespace>,
     max_retries: usize,
     metrics: Option<&MetricsCollector>,
     circuit_breaker: Option<&CircuitBreaker>,
 ) -> Result<()> {
     debug!("Creating saved search: {}", name);
 
-    let url = format!("{}/services/saved/searches", base_url);
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(namespace, "saved/searches")
+    );
 
     let builder = client
         .post(&url)
@@ -121,14 +140,21 @@ pub async fn delete_saved_search(
     base_url: &str,
     auth_token: &str,
     name: &str,
+    namespace: Option<&Namespace>,
     max_retries: usize,
     metrics: Option<&MetricsCollector>,
     circuit_breaker: Option<&CircuitBreaker>,
 ) -> Result<()> {
     debug!("Deleting saved search: {}", name);
 
-    let encoded_name = encode_path_segment(name);
-    let url = format!("{}/services/saved/searches/{}", base_url, encoded_name);
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(
+            namespace,
+            &format!("saved/searches/{}", encode_path_segment(name))
+        )
+    );
 
     let builder = client
         .delete(&url)
@@ -157,6 +183,7 @@ pub async fn delete_saved_search(
 /// * `base_url` - The Splunk base URL
 /// * `auth_token` - Authentication token
 /// * `name` - The name of the saved search
+/// * `namespace` - Optional owner/app context for the request
 /// * `max_retries` - Maximum number of retries for transient failures
 /// * `metrics` - Optional metrics collector
 ///
@@ -168,14 +195,21 @@ pub async fn get_saved_search(
     base_url: &str,
     auth_token: &str,
     name: &str,
+    namespace: Option<&Namespace>,
     max_retries: usize,
     metrics: Option<&MetricsCollector>,
     circuit_breaker: Option<&CircuitBreaker>,
 ) -> Result<crate::models::SavedSearch> {
     debug!("Getting saved search: {}", name);
 
-    let encoded_name = encode_path_segment(name);
-    let url = format!("{}/services/saved/searches/{}", base_url, encoded_name);
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(
+            namespace,
+            &format!("saved/searches/{}", encode_path_segment(name))
+        )
+    );
 
     let builder = client
         .get(&url)
@@ -227,12 +261,16 @@ pub struct SavedSearchUpdateParams<'a> {
     pub description: Option<&'a str>,
     /// Enable/disable flag
     pub disabled: Option<bool>,
+    /// Earliest time of the scheduled dispatch window
+    pub dispatch_earliest_time: Option<&'a str>,
+    /// Latest time of the scheduled dispatch window
+    pub dispatch_latest_time: Option<&'a str>,
 }
 
 /// Update an existing saved search.
 ///
-/// This endpoint uses POST to `/services/saved/searches/{name}` to update
-/// an existing saved search. Only provided fields are updated; omitted
+/// This endpoint uses POST to `/services/saved/searches/{name}` (or the
+/// `/servicesNS/{owner}/{app}` equivalent) to update an existing saved search. Only provided fields are updated; omitted
 /// fields retain their current values.
 ///
 /// # Arguments
@@ -240,7 +278,8 @@ pub struct SavedSearchUpdateParams<'a> {
 /// * `base_url` - The Splunk base URL
 /// * `auth_token` - Authentication token
 /// * `name` - The name of the saved search to update
-/// * `params` - Update parameters (search, description, disabled)
+/// * `params` - Update parameters (search, description, disabled, dispatch window)
+/// * `namespace` - Optional owner/app context for the request
 /// * `max_retries` - Maximum number of retries for transient failures
 /// * `metrics` - Optional metrics collector
 ///
@@ -253,14 +292,21 @@ pub async fn update_saved_search(
     auth_token: &str,
     name: &str,
     params: &SavedSearchUpdateParams<'_>,
+    namespace: Option<&Namespace>,
     max_retries: usize,
     metrics: Option<&MetricsCollector>,
     circuit_breaker: Option<&CircuitBreaker>,
 ) -> Result<()> {
     debug!("Updating saved search: {}", name);
 
-    let encoded_name = encode_path_segment(name);
-    let url = format!("{}/services/saved/searches/{}", base_url, encoded_name);
+    let url = format!(
+        "{}{}",
+        base_url,
+        namespaced_path(
+            namespace,
+            &format!("saved/searches/{}", encode_path_segment(name))
+        )
+    );
 
     let mut form_params: Vec<(&str, String)> = Vec::new();
 
@@ -268,6 +314,8 @@ pub async fn update_saved_search(
         "search" => str params.search,
         "description" => str params.description,
         "disabled" => bool params.disabled,
+        "dispatch.earliest_time" => str params.dispatch_earliest_time,
+        "dispatch.latest_time" => str params.dispatch_latest_time,
     }
 
     let builder = client
@@ -294,3 +342,201 @@ pub async fn update_saved_search(
         Err(e) => Err(e),
     }
 }
+
+/// List ownership and schedule details of saved searches across apps.
+///
+/// Queries the namespaced `/servicesNS/-/{app}/saved/searches` endpoint so that
+/// private (user-shared) searches of every owner are visible. When `owner` is
+/// provided, results are filtered server-side via `eai:acl.owner` and then
+/// re-checked client-side for an exact match.
+#[allow(clippy::too_many_arguments)]
+pub async fn list_saved_search_ownership(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    app: Option<&str>,
+    owner: Option<&str>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<SavedSearchOwnership>> {
+    debug!(
+        "Listing saved search ownership (app={:?}, owner={:?})",
+        app, owner
+    );
+
+    let app_segment = app
+        .map(encode_path_segment)
+        .unwrap_or_else(|| "-".to_string());
+    let url = format!("{}/servicesNS/-/{}/saved/searches", base_url, app_segment);
+
+    let mut query_params: Vec<(String, String)> = vec![
+        ("output_mode".to_string(), "json".to_string()),
+        ("count".to_string(), "0".to_string()),
+    ];
+    if let Some(o) = owner {
+        query_params.push(("search".to_string(), format!("eai:acl.owner={}", o)));
+    }
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&query_params);
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/servicesNS/-/{app}/saved/searches",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: SplunkResponse<SavedSearchSchedule> = response.json().await.map_err(|e| {
+        ClientError::InvalidResponse(format!(
+            "Failed to parse saved search ownership response: {}",
+            e
+        ))
+    })?;
+
+    Ok(resp
+        .entry
+        .into_iter()
+        .filter_map(|e| {
+            let acl = e.acl?;
+            if owner.is_some_and(|o| o != acl.owner) {
+                return None;
+            }
+            Some(SavedSearchOwnership {
+                name: e.name,
+                app: acl.app,
+                owner: acl.owner,
+                sharing: acl.sharing.unwrap_or_else(|| "user".to_string()),
+                is_scheduled: e.content.is_scheduled,
+                cron_schedule: e.content.cron_schedule,
+                disabled: e.content.disabled,
+            })
+        })
+        .collect())
+}
+
+/// Change the owner (and sharing level) of a saved search.
+///
+/// Posts to `/servicesNS/{owner}/{app}/saved/searches/{name}/acl`. Splunk
+/// requires `sharing` on every ACL update, so the caller must pass the
+/// current sharing level when only the owner should change.
+#[allow(clippy::too_many_arguments)]
+pub async fn set_saved_search_acl(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    name: &str,
+    app: &str,
+    current_owner: &str,
+    new_owner: &str,
+    sharing: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    debug!(
+        "Reassigning saved search '{}' in app '{}' from '{}' to '{}'",
+        name, app, current_owner, new_owner
+    );
+
+    let url = format!(
+        "{}/servicesNS/{}/{}/saved/searches/{}/acl",
+        base_url,
+        encode_path_segment(current_owner),
+        encode_path_segment(app),
+        encode_path_segment(name)
+    );
+
+    let builder = client
+        .post(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json")])
+        .form(&[("owner", new_owner), ("sharing", sharing)]);
+
+    match send_request_with_retry(
+        builder,
+        max_retries,
+        "/servicesNS/{owner}/{app}/saved/searches/{name}/acl",
+        "POST",
+        metrics,
+        circuit_breaker,
+    )
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(ClientError::ApiError { status: 404, .. }) => Err(ClientError::NotFound(format!(
+            "Saved search '{}' not found in app '{}'",
+            name, app
+        ))),
+        Err(e) => Err(e),
+    }
+}
+
+/// Enable or disable a saved search in its own owner/app namespace.
+///
+/// Posts to `/servicesNS/{owner}/{app}/saved/searches/{name}/enable` (or
+/// `/disable`), so searches owned by other users can be toggled without
+/// switching the client namespace.
+#[allow(clippy::too_many_arguments)]
+pub async fn set_saved_search_disabled(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    name: &str,
+    app: &str,
+    owner: &str,
+    disabled: bool,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    let (operation, endpoint) = if disabled {
+        (
+            "disable",
+            "/servicesNS/{owner}/{app}/saved/searches/{name}/disable",
+        )
+    } else {
+        (
+            "enable",
+            "/servicesNS/{owner}/{app}/saved/searches/{name}/enable",
+        )
+    };
+    debug!("Saved search '{}' in app '{}': {}", name, app, operation);
+
+    let url = format!(
+        "{}/servicesNS/{}/{}/saved/searches/{}/{}",
+        base_url,
+        encode_path_segment(owner),
+        encode_path_segment(app),
+        encode_path_segment(name),
+        operation
+    );
+
+    let builder = client
+        .post(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json")]);
+
+    match send_request_with_retry(
+        builder,
+        max_retries,
+        endpoint,
+        "POST",
+        metrics,
+        circuit_breaker,
+    )
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(ClientError::ApiError { status: 404, .. }) => Err(ClientError::NotFound(format!(
+            "Saved search '{}' not found in app '{}'",
+            name, app
+        ))),
+        Err(e) => Err(e),
+    }
+}
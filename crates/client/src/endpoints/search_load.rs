@@ -0,0 +1,119 @@
+//! Search load endpoints.
+
+use reqwest::Client;
+use tracing::{debug, warn};
+
+use crate::client::circuit_breaker::CircuitBreaker;
+use crate::endpoints::search::{
+    CreateJobOptions, OutputMode, create_job, get_results, wait_for_job,
+};
+use crate::error::Result;
+use crate::metrics::MetricsCollector;
+use crate::models::UserSearchUsage;
+
+/// Search query counting running and queued search jobs per owner on the
+/// local instance, joined with the highest `srchJobsQuota` of each owner's roles.
+///
+/// Produces one row per user with searches in flight. The query's own job
+/// is excluded so the check does not count itself.
+const USER_SEARCH_USAGE_QUERY: &str = r#"| rest /services/search/jobs splunk_server=local count=0 | search dispatchState="RUNNING" OR dispatchState="QUEUED" OR dispatchState="PARSING" OR dispatchState="FINALIZING" | where NOT like(title, "| rest /services/search/jobs splunk_server=local%") | eval user='eai:acl.owner', queued=if(dispatchState="QUEUED", 1, 0), running=1-queued, realtime=if(queued=0 AND (isRealTimeSearch="1" OR isRealTimeSearch="true"), 1, 0) | stats sum(running) as running sum(queued) as queued sum(realtime) as realtime by user | join type=left user [| rest /services/authentication/users splunk_server=local count=0 | fields title roles | rename title as user | mvexpand roles | join type=left roles [| rest /services/authorization/roles splunk_server=local count=0 | fields title srchJobsQuota | rename title as roles] | stats max(srchJobsQuota) as quota by user]"#;
+
+/// Count running and queued searches per user, with each user's job quota.
+///
+/// This function creates a search job over the local job list, waits for it
+/// to complete, and returns one [`UserSearchUsage`] per user with searches
+/// in flight.
+///
+/// # Arguments
+///
+/// * `client` - HTTP client for making requests
+/// * `base_url` - Base URL of the Splunk server
+/// * `auth_token` - Authentication token
+/// * `max_retries` - Maximum number of retry attempts for failed requests
+pub async fn get_user_search_usage(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<UserSearchUsage>> {
+    debug!("Counting running and queued searches per user");
+
+    let options = CreateJobOptions {
+        output_mode: Some(OutputMode::Json),
+        ..Default::default()
+    };
+
+    let sid = create_job(
+        client,
+        base_url,
+        auth_token,
+        USER_SEARCH_USAGE_QUERY,
+        &options,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    debug!("Created search job {} for search load check", sid);
+
+    let _status = wait_for_job(
+        client,
+        base_url,
+        auth_token,
+        &sid,
+        500,
+        60,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    // count=0 returns every row; each user with searches in flight is one.
+    let results = get_results(
+        client,
+        base_url,
+        auth_token,
+        &sid,
+        Some(0),
+        None,
+        OutputMode::Json,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let endpoint = "/services/search/jobs/results";
+    let users: Vec<UserSearchUsage> = results
+        .results
+        .into_iter()
+        .filter_map(
+            |v| match serde_json::from_value::<UserSearchUsage>(v.clone()) {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    warn!(
+                        "Failed to deserialize UserSearchUsage from {}: error={}, value_preview={}",
+                        endpoint,
+                        e,
+                        serde_json::to_string(&v).unwrap_or_else(|_| format!("{:?}", v))
+                    );
+                    if let Some(m) = metrics {
+                        m.record_deserialization_failure(endpoint, "UserSearchUsage");
+                    }
+                    None
+                }
+            },
+        )
+        .collect();
+
+    debug!(
+        "Search load check complete: {} users with searches",
+        users.len()
+    );
+
+    Ok(users)
+}
@@ -6,6 +6,7 @@
 //! # What this module handles:
 //! - HTTP GET requests to list distributed search peers
 //! - Query parameter construction for pagination
+//! - Adding, removing, and quarantining search peers
 //!
 //! # What this module does NOT handle:
 //! - Authentication retry logic (handled by [`crate::client`])
@@ -13,12 +14,17 @@
 //! - Response deserialization (delegated to models)
 
 use reqwest::Client;
+use secrecy::ExposeSecret;
 
 use crate::client::circuit_breaker::CircuitBreaker;
+use crate::endpoints::encode_path_segment;
 use crate::endpoints::send_request_with_retry;
 use crate::error::Result;
+use crate::form_params;
 use crate::metrics::MetricsCollector;
-use crate::models::{SearchPeer, SearchPeerListResponse};
+use crate::models::{
+    AddSearchPeerParams, SearchPeer, SearchPeerListResponse, search_peer_entry_name,
+};
 use crate::name_merge::attach_entry_name;
 
 /// List all distributed search peers.
@@ -87,3 +93,120 @@ pub async fn list_search_peers(
         .map(|e| attach_entry_name(e.name, e.content))
         .collect())
 }
+
+/// Add a distributed search peer.
+///
+/// Splunk authenticates to the peer with the remote credentials once to
+/// exchange trust keys; the credentials are not stored.
+#[allow(clippy::too_many_arguments)]
+pub async fn add_search_peer(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    params: &AddSearchPeerParams,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    let url = format!("{}/services/search/distributed/peers", base_url);
+
+    let mut form_params: Vec<(String, String)> =
+        vec![("output_mode".to_string(), "json".to_string())];
+
+    form_params! { form_params =>
+        "name" => required_clone params.uri,
+        "remoteUsername" => required_clone params.remote_username,
+        "remotePassword" => secret &params.remote_password,
+    }
+
+    let builder = client
+        .post(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .form(&form_params);
+    send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/search/distributed/peers",
+        "POST",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Remove a distributed search peer by URI or `host:port` name.
+#[allow(clippy::too_many_arguments)]
+pub async fn remove_search_peer(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    name: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    let endpoint = format!(
+        "/services/search/distributed/peers/{}",
+        encode_path_segment(search_peer_entry_name(name))
+    );
+    let url = format!("{}{}", base_url, endpoint);
+
+    let builder = client
+        .delete(&url)
+        .header("Authorization", format!("Bearer {}", auth_token));
+    send_request_with_retry(
+        builder,
+        max_retries,
+        &endpoint,
+        "DELETE",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Quarantine or unquarantine a distributed search peer.
+///
+/// A quarantined peer is excluded from new searches but stays configured.
+#[allow(clippy::too_many_arguments)]
+pub async fn set_search_peer_quarantine(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    name: &str,
+    quarantine: bool,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    let endpoint = format!(
+        "/services/search/distributed/peers/{}/{}",
+        encode_path_segment(search_peer_entry_name(name)),
+        if quarantine {
+            "quarantine"
+        } else {
+            "unquarantine"
+        }
+    );
+    let url = format!("{}{}", base_url, endpoint);
+
+    let builder = client
+        .post(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json")]);
+    send_request_with_retry(
+        builder,
+        max_retries,
+        &endpoint,
+        "POST",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+//! Security health endpoints.
+
+use reqwest::Client;
+use tracing::{debug, warn};
+
+use crate::client::circuit_breaker::CircuitBreaker;
+use crate::endpoints::search::{
+    CreateJobOptions, OutputMode, create_job, get_results, wait_for_job,
+};
+use crate::error::Result;
+use crate::metrics::MetricsCollector;
+use crate::models::{FailedLoginSource, SecurityHealth};
+
+/// Search query counting failed logins per source IP from the `_audit` index.
+///
+/// Produces one row per source IP. Web logins record the address as `src`;
+/// older releases and REST logins use `clientip`.
+const FAILED_LOGIN_SEARCH_QUERY: &str = r#"search index=_audit action="login attempt" info=failed | eval src_ip=coalesce(src, clientip, "unknown") | stats count as failures dc(user) as distinct_users min(_time) as first_seen max(_time) as last_seen by src_ip | eval first_seen=strftime(first_seen, "%Y-%m-%dT%H:%M:%S%z"), last_seen=strftime(last_seen, "%Y-%m-%dT%H:%M:%S%z")"#;
+
+/// Summarize failed logins per source IP since `earliest_time`.
+///
+/// This function creates a search job over `_audit`, waits for it to
+/// complete, and folds the per-IP rows into a [`SecurityHealth`] that flags
+/// addresses with more than `threshold` failures.
+///
+/// # Arguments
+///
+/// * `client` - HTTP client for making requests
+/// * `base_url` - Base URL of the Splunk server
+/// * `auth_token` - Authentication token
+/// * `earliest_time` - Start of the window as a Splunk relative time (e.g. `-1h`)
+/// * `threshold` - Failed logins a source IP must exceed to be flagged
+/// * `max_retries` - Maximum number of retry attempts for failed requests
+#[allow(clippy::too_many_arguments)]
+pub async fn check_security_health(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    earliest_time: &str,
+    threshold: u64,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<SecurityHealth> {
+    debug!("Checking failed logins since {}", earliest_time);
+
+    let options = CreateJobOptions {
+        earliest_time: Some(earliest_time.to_string()),
+        output_mode: Some(OutputMode::Json),
+        ..Default::default()
+    };
+
+    let sid = create_job(
+        client,
+        base_url,
+        auth_token,
+        FAILED_LOGIN_SEARCH_QUERY,
+        &options,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    debug!("Created search job {} for security health check", sid);
+
+    let _status = wait_for_job(
+        client,
+        base_url,
+        auth_token,
+        &sid,
+        500,
+        60,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    // count=0 returns every row; an attack may come from many addresses.
+    let results = get_results(
+        client,
+        base_url,
+        auth_token,
+        &sid,
+        Some(0),
+        None,
+        OutputMode::Json,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let endpoint = "/services/search/jobs/results";
+    let sources: Vec<FailedLoginSource> = results
+        .results
+        .into_iter()
+        .filter_map(
+            |v| match serde_json::from_value::<FailedLoginSource>(v.clone()) {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    warn!(
+                        "Failed to deserialize FailedLoginSource from {}: error={}, value_preview={}",
+                        endpoint,
+                        e,
+                        serde_json::to_string(&v).unwrap_or_else(|_| format!("{:?}", v))
+                    );
+                    if let Some(m) = metrics {
+                        m.record_deserialization_failure(endpoint, "FailedLoginSource");
+                    }
+                    None
+                }
+            },
+        )
+        .collect();
+
+    let health = SecurityHealth::from_sources(earliest_time.to_string(), threshold, sources);
+
+    debug!(
+        "Security health check complete: {} source IPs, {} failed logins",
+        health.sources.len(),
+        health.total_failures
+    );
+
+    Ok(health)
+}
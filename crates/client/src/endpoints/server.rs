@@ -7,8 +7,12 @@ use crate::endpoints::encode_path_segment;
 use crate::endpoints::send_request_with_retry;
 use crate::error::{ClientError, Result};
 use crate::metrics::MetricsCollector;
-use crate::models::{App, AppListResponse, ServerInfo, SplunkHealth};
+use crate::models::{
+    App, AppListResponse, PartitionSpace, SearchConcurrencyLimits, ServerInfo, SplunkHealth,
+    SplunkResponse,
+};
 use crate::name_merge::attach_entry_name;
+use crate::schema_check::parse_entry_content;
 
 /// Get server information.
 #[allow(clippy::too_many_arguments)]
@@ -50,8 +54,7 @@ pub async fn get_server_info(
         })?;
 
     // Deserialize content into ServerInfo struct
-    serde_json::from_value(content.clone())
-        .map_err(|e| ClientError::InvalidResponse(format!("Failed to parse server info: {}", e)))
+    parse_entry_content(content, "server info")
 }
 
 /// Get system-wide health information.
@@ -92,8 +95,113 @@ pub async fn get_health(
         })?;
 
     // Deserialize content into SplunkHealth struct
-    serde_json::from_value(content.clone())
-        .map_err(|e| ClientError::InvalidResponse(format!("Failed to parse health info: {}", e)))
+    parse_entry_content(content, "health info")
+}
+
+/// Get disk space on each partition splunkd writes to.
+#[allow(clippy::too_many_arguments)]
+pub async fn get_partitions_space(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<Vec<PartitionSpace>> {
+    let url = format!("{}/services/server/status/partitions-space", base_url);
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json"), ("count", "0")]);
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/server/status/partitions-space",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: SplunkResponse<PartitionSpace> = response.json().await?;
+
+    Ok(resp.entry.into_iter().map(|e| e.content).collect())
+}
+
+/// Get the concurrent search limits of the instance.
+#[allow(clippy::too_many_arguments)]
+pub async fn get_search_concurrency_limits(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<SearchConcurrencyLimits> {
+    let url = format!(
+        "{}/services/server/status/limits/search-concurrency",
+        base_url
+    );
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json")]);
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/server/status/limits/search-concurrency",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: serde_json::Value = response.json().await?;
+
+    let content = resp
+        .get("entry")
+        .and_then(|e| e.get(0))
+        .and_then(|e| e.get("content"))
+        .ok_or_else(|| {
+            ClientError::InvalidResponse(
+                "Missing entry content in search concurrency response".to_string(),
+            )
+        })?;
+
+    parse_entry_content(content, "search concurrency limits")
+}
+
+/// Restart splunkd.
+///
+/// Splunk acknowledges the request and then restarts in the background, so the
+/// server stays unreachable for a while after this returns.
+pub async fn restart_server(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<()> {
+    let url = format!("{}/services/server/control/restart", base_url);
+
+    let builder = client
+        .post(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .form(&[("output_mode", "json")]);
+    send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/server/control/restart",
+        "POST",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    Ok(())
 }
 
 /// List all installed apps.
@@ -194,8 +302,7 @@ pub async fn get_app(
     })?;
 
     // Deserialize content into App struct and attach the entry name
-    let app: App = serde_json::from_value(content.clone())
-        .map_err(|e| ClientError::InvalidResponse(format!("Failed to parse app info: {}", e)))?;
+    let app: App = parse_entry_content(content, "app info")?;
 
     Ok(crate::name_merge::attach_entry_name(entry_name, app))
 }
@@ -290,8 +397,6 @@ pub async fn install_app(
     metrics: Option<&MetricsCollector>,
     circuit_breaker: Option<&CircuitBreaker>,
 ) -> Result<App> {
-    let url = format!("{}/services/apps/appinstall", base_url);
-
     // Read the file content
     let file_content = tokio::fs::read(file_path).await.map_err(|e| {
         ClientError::InvalidRequest(format!(
@@ -306,6 +411,46 @@ pub async fn install_app(
         .and_then(|n| n.to_str())
         .unwrap_or("app.spl");
 
+    install_app_package(
+        client,
+        base_url,
+        auth_token,
+        file_name,
+        file_content,
+        max_retries,
+        metrics,
+        circuit_breaker,
+    )
+    .await
+}
+
+/// Install a Splunk app from an in-memory .spl package.
+///
+/// Same upload as [`install_app`], for packages that did not come from a local
+/// file (e.g. downloaded from Splunkbase).
+///
+/// # Arguments
+///
+/// * `client` - The HTTP client
+/// * `base_url` - The Splunk base URL
+/// * `auth_token` - The authentication token
+/// * `file_name` - File name reported for the uploaded package
+/// * `file_content` - Raw package bytes
+/// * `max_retries` - Maximum number of retries for authentication failures
+/// * `metrics` - Optional metrics collector
+#[allow(clippy::too_many_arguments)]
+pub async fn install_app_package(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    file_name: &str,
+    file_content: Vec<u8>,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<App> {
+    let url = format!("{}/services/apps/appinstall", base_url);
+
     // Build multipart form
     let form = reqwest::multipart::Form::new().part(
         "splunk_file",
@@ -349,8 +494,7 @@ pub async fn install_app(
     })?;
 
     // Deserialize content into App struct and attach the entry name
-    let app: App = serde_json::from_value(content.clone())
-        .map_err(|e| ClientError::InvalidResponse(format!("Failed to parse app info: {}", e)))?;
+    let app: App = parse_entry_content(content, "app info")?;
 
     Ok(crate::name_merge::attach_entry_name(entry_name, app))
 }
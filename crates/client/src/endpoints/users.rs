@@ -5,12 +5,16 @@ use secrecy::ExposeSecret;
 
 use crate::client::circuit_breaker::CircuitBreaker;
 use crate::endpoints::encode_path_segment;
+use crate::endpoints::extract_entry_content;
 use crate::endpoints::send_request_with_retry;
 use crate::error::{ClientError, Result};
 use crate::form_params;
 use crate::metrics::MetricsCollector;
-use crate::models::{CreateUserParams, ModifyUserParams, User, UserListResponse};
+use crate::models::{
+    CreateUserParams, CurrentContext, ModifyUserParams, PasswordPolicy, User, UserListResponse,
+};
 use crate::name_merge::attach_entry_name;
+use crate::schema_check::parse_entry_content;
 
 /// List all users.
 #[allow(clippy::too_many_arguments)]
@@ -58,6 +62,73 @@ pub async fn list_users(
         .collect())
 }
 
+/// Get the name of the user the current credentials authenticate as.
+pub async fn get_current_username(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<String> {
+    let url = format!("{}/services/authentication/current-context", base_url);
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json")]);
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/authentication/current-context",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: serde_json::Value = response.json().await?;
+
+    extract_entry_content(&resp)?
+        .get("username")
+        .and_then(|u| u.as_str())
+        .map(str::to_string)
+        .ok_or_else(|| {
+            ClientError::InvalidResponse("Missing username in current-context response".to_string())
+        })
+}
+
+/// Get the roles and effective capabilities of the authenticated user.
+pub async fn get_current_context(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<CurrentContext> {
+    let url = format!("{}/services/authentication/current-context", base_url);
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json")]);
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/authentication/current-context",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: serde_json::Value = response.json().await?;
+    let content = extract_entry_content(&resp)?;
+
+    parse_entry_content(content, "current-context response")
+}
+
 /// Create a new user.
 #[allow(clippy::too_many_arguments)]
 pub async fn create_user(
@@ -115,8 +186,7 @@ pub async fn create_user(
         ClientError::InvalidResponse("Missing entry content in create user response".to_string())
     })?;
 
-    let user: User = serde_json::from_value(content.clone())
-        .map_err(|e| ClientError::InvalidResponse(format!("Failed to parse user: {}", e)))?;
+    let user: User = parse_entry_content(content, "user")?;
 
     Ok(attach_entry_name(entry_name, user))
 }
@@ -170,31 +240,7 @@ pub async fn modify_user(
 
     let resp: serde_json::Value = response.json().await?;
 
-    // Extract entry from response
-    let entry = resp.get("entry").and_then(|e| e.get(0)).ok_or_else(|| {
-        ClientError::InvalidResponse(format!(
-            "Missing entry in modify user response for '{}'",
-            user_name
-        ))
-    })?;
-
-    let entry_name = entry
-        .get("name")
-        .and_then(|n| n.as_str())
-        .unwrap_or(user_name)
-        .to_string();
-
-    let content = entry.get("content").ok_or_else(|| {
-        ClientError::InvalidResponse(format!(
-            "Missing entry content in modify user response for '{}'",
-            user_name
-        ))
-    })?;
-
-    let user: User = serde_json::from_value(content.clone())
-        .map_err(|e| ClientError::InvalidResponse(format!("Failed to parse user: {}", e)))?;
-
-    Ok(attach_entry_name(entry_name, user))
+    parse_user_entry(&resp, user_name, "modify user")
 }
 
 /// Delete a user by name.
@@ -233,3 +279,103 @@ pub async fn delete_user(
 
     Ok(())
 }
+
+/// Unlock a user that was locked out after too many failed logins.
+#[allow(clippy::too_many_arguments)]
+pub async fn unlock_user(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    user_name: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<User> {
+    let encoded_user_name = encode_path_segment(user_name);
+    let url = Url::parse(base_url)
+        .map_err(|e| ClientError::InvalidUrl(format!("Invalid base URL: {}", e)))?
+        .join(&format!(
+            "/services/authentication/users/{}",
+            encoded_user_name
+        ))
+        .map_err(|e| ClientError::InvalidUrl(format!("Invalid user name: {}", e)))?;
+
+    let builder = client
+        .post(url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .form(&[("output_mode", "json"), ("locked-out", "0")]);
+
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        &format!("/services/authentication/users/{}", encoded_user_name),
+        "POST",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: serde_json::Value = response.json().await?;
+
+    parse_user_entry(&resp, user_name, "unlock user")
+}
+
+/// Get the password and lockout policy for native Splunk users.
+pub async fn get_password_policy(
+    client: &Client,
+    base_url: &str,
+    auth_token: &str,
+    max_retries: usize,
+    metrics: Option<&MetricsCollector>,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<PasswordPolicy> {
+    let url = format!("{}/services/admin/Splunkd-auth/splunk_auth", base_url);
+
+    let builder = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .query(&[("output_mode", "json")]);
+    let response = send_request_with_retry(
+        builder,
+        max_retries,
+        "/services/admin/Splunkd-auth/splunk_auth",
+        "GET",
+        metrics,
+        circuit_breaker,
+    )
+    .await?;
+
+    let resp: serde_json::Value = response.json().await?;
+
+    serde_json::from_value(extract_entry_content(&resp)?.clone()).map_err(|e| {
+        ClientError::InvalidResponse(format!("Failed to parse password policy: {}", e))
+    })
+}
+
+/// Parse the single user entry returned by a user update.
+fn parse_user_entry(resp: &serde_json::Value, user_name: &str, operation: &str) -> Result<User> {
+    // Extract entry from response
+    let entry = resp.get("entry").and_then(|e| e.get(0)).ok_or_else(|| {
+        ClientError::InvalidResponse(format!(
+            "Missing entry in {} response for '{}'",
+            operation, user_name
+        ))
+    })?;
+
+    let entry_name = entry
+        .get("name")
+        .and_then(|n| n.as_str())
+        .unwrap_or(user_name)
+        .to_string();
+
+    let content = entry.get("content").ok_or_else(|| {
+        ClientError::InvalidResponse(format!(
+            "Missing entry content in {} response for '{}'",
+            operation, user_name
+        ))
+    })?;
+
+    let user: User = parse_entry_content(content, "user")?;
+
+    Ok(attach_entry_name(entry_name, user))
+}
@@ -3,11 +3,12 @@
 //! Scope: Internal client error classification only; user-facing rendering lives in `user_facing.rs`.
 //! Usage: Called by request execution, endpoint parsing, and tests.
 //! Invariants/Assumptions: 403 responses classify as semantic permission failures in the client layer and transport connection failures remain retryable.
+//! Splunk messages naming a quota, missing capability, missing index, or license violation classify as those structured variants regardless of status (except 401).
 
 use std::error::Error as StdError;
 use std::time::Duration;
 
-use super::kinds::ClientError;
+use super::kinds::{ClientError, FailureCategory};
 
 impl ClientError {
     /// Check if this error is retryable.
@@ -26,6 +27,21 @@ impl ClientError {
         matches!(self, Self::CircuitBreakerOpen(_))
     }
 
+    /// Check if this error means the server could not be reached at all.
+    ///
+    /// Covers refused connections, timeouts, and open circuit breakers (including
+    /// when they are the final cause after retries), but not errors the server returned.
+    pub fn is_unreachable(&self) -> bool {
+        if let Self::MaxRetriesExceeded(_, source) = self {
+            return source.is_unreachable();
+        }
+        self.is_circuit_breaker_error()
+            || matches!(
+                self.to_user_facing_failure().category,
+                FailureCategory::Connection | FailureCategory::Timeout
+            )
+    }
+
     /// Check if an HTTP status code is retryable.
     pub fn is_retryable_status(status: u16) -> bool {
         matches!(status, 429 | 502 | 503 | 504)
@@ -84,6 +100,10 @@ impl ClientError {
             return Self::Unauthorized(message);
         }
 
+        if let Some(error) = Self::from_splunk_message(&url, &message) {
+            return error;
+        }
+
         if status == 403 {
             return Self::Unauthorized(message);
         }
@@ -104,6 +124,32 @@ impl ClientError {
         }
     }
 
+    /// Recognize Splunk messages that explain why a request failed and how to fix it.
+    fn from_splunk_message(url: &str, message: &str) -> Option<Self> {
+        let lower = message.to_lowercase();
+
+        if lower.contains("disk usage quota") || lower.contains("disk quota") {
+            return Some(Self::QuotaExceeded(message.to_string()));
+        }
+        if lower.contains("maximum number of concurrent") {
+            return Some(Self::ConcurrentSearchLimit(message.to_string()));
+        }
+        if let Some(capability) = capability_name(message) {
+            return Some(Self::RoleLacksCapability {
+                capability,
+                message: message.to_string(),
+            });
+        }
+        if lower.contains("license")
+            && (lower.contains("violation")
+                || lower.contains("expired")
+                || lower.contains("exceeded your license"))
+        {
+            return Some(Self::LicenseViolation(message.to_string()));
+        }
+        missing_index_name(url, message).map(Self::IndexNotFound)
+    }
+
     /// Create a ClientError from a reqwest error with transport-level classification.
     pub(crate) fn from_reqwest_error_classified(error: reqwest::Error) -> Self {
         if error.is_timeout() {
@@ -137,3 +183,56 @@ impl ClientError {
         Self::HttpError(snapshot)
     }
 }
+
+/// Characters Splunk allows in capability and index names.
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-')
+}
+
+/// The leading name in `text`, skipping an opening quote or `=`.
+fn leading_name(text: &str) -> Option<String> {
+    let text = text.trim_start_matches([' ', '\'', '"', '=']);
+    let name: String = text.chars().take_while(|&c| is_name_char(c)).collect();
+    (!name.is_empty()).then_some(name)
+}
+
+/// The capability named in a permission message, such as
+/// `... (requires capability: edit_indexes)` or `lacks capability 'edit_user'`.
+fn capability_name(message: &str) -> Option<String> {
+    let lower = message.to_lowercase();
+    let start = lower
+        .find("requires capability:")
+        .map(|i| i + "requires capability:".len())
+        .or_else(|| {
+            lower
+                .find("capability '")
+                .or_else(|| lower.find("capability \""))
+                .map(|i| i + "capability ".len())
+        })?;
+    leading_name(&message[start..])
+}
+
+/// The index named in a missing-index message, such as `Index 'web' does not exist`
+/// or a 404 for `/services/data/indexes/web` (`Could not find object id=web`).
+fn missing_index_name(url: &str, message: &str) -> Option<String> {
+    let lower = message.to_lowercase();
+    if !(lower.contains("does not exist")
+        || lower.contains("not found")
+        || lower.contains("could not find")
+        || lower.contains("unknown index"))
+    {
+        return None;
+    }
+
+    if url.contains("/data/indexes/")
+        && let Some(i) = lower.find("id=")
+    {
+        return leading_name(&message[i + "id=".len()..]);
+    }
+    // Skip longer words such as "indexes" or "indexer".
+    lower
+        .match_indices("index")
+        .map(|(i, _)| &message[i + "index".len()..])
+        .find(|rest| !rest.starts_with(is_name_char))
+        .and_then(leading_name)
+}
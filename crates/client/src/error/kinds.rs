@@ -42,6 +42,10 @@ pub enum FailureCategory {
     Connection,
     Timeout,
     RateLimited,
+    /// Search disk or concurrency quota reached.
+    Quota,
+    /// License expired or in violation.
+    License,
     NotFound,
     InvalidRequest,
     Validation,
@@ -192,9 +196,30 @@ pub enum ClientError {
     #[error("Validation error: {0}")]
     ValidationError(String),
 
+    #[error("Search disk quota exceeded: {0}")]
+    QuotaExceeded(String),
+
+    #[error("Concurrent search limit reached: {0}")]
+    ConcurrentSearchLimit(String),
+
+    #[error("User lacks '{capability}' capability: {message}")]
+    RoleLacksCapability { capability: String, message: String },
+
+    #[error("Index not found: {0}")]
+    IndexNotFound(String),
+
+    #[error("License violation: {0}")]
+    LicenseViolation(String),
+
     #[error("Circuit breaker open: {0}")]
     CircuitBreakerOpen(String),
 
+    #[error("'{operation}' is not available on Splunk Cloud; {alternative}")]
+    UnsupportedOnCloud {
+        operation: &'static str,
+        alternative: &'static str,
+    },
+
     #[error("Transaction rollback failed with {count} error(s): {}", failures.iter().map(|f| f.to_string()).collect::<Vec<_>>().join("; "))]
     TransactionRollbackError {
         count: usize,
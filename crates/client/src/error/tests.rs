@@ -191,3 +191,134 @@ fn http_error_variant_clone_preserves_variant() {
 
     assert!(matches!(error.clone(), ClientError::HttpError(_)));
 }
+
+fn classify(status: u16, url: &str, message: &str) -> ClientError {
+    ClientError::from_status_response(status, url.to_string(), message.to_string(), None)
+}
+
+#[test]
+fn status_response_classification_recognizes_splunk_explanations() {
+    let url = "https://localhost:8089/services/search/jobs";
+
+    assert!(matches!(
+        classify(
+            503,
+            url,
+            "ERROR: This search could not be dispatched because the role-based disk usage quota of search artifacts for user \"bob\" has been reached (usage=105MB, quota=100MB)."
+        ),
+        ClientError::QuotaExceeded(_)
+    ));
+    assert!(matches!(
+        classify(
+            503,
+            url,
+            "ERROR: The maximum number of concurrent historical searches for this user based on their role quota has been reached. concurrency_limit=3"
+        ),
+        ClientError::ConcurrentSearchLimit(_)
+    ));
+    assert!(matches!(
+        classify(
+            400,
+            url,
+            "ERROR: Your Splunk license expired or you have exceeded your license limit too many times."
+        ),
+        ClientError::LicenseViolation(_)
+    ));
+
+    let error = classify(
+        403,
+        "https://localhost:8089/services/data/indexes",
+        "ERROR: You (user=bob) do not have permission to perform this operation (requires capability: edit_indexes).",
+    );
+    assert!(matches!(
+        &error,
+        ClientError::RoleLacksCapability { capability, .. } if capability == "edit_indexes"
+    ));
+    assert!(matches!(
+        classify(403, url, "ERROR: Action forbidden: user lacks capability 'edit_user'"),
+        ClientError::RoleLacksCapability { capability, .. } if capability == "edit_user"
+    ));
+}
+
+#[test]
+fn status_response_classification_extracts_missing_index_name() {
+    assert!(matches!(
+        classify(
+            404,
+            "https://localhost:8089/services/data/indexes/web",
+            "ERROR: In handler 'indexes': Could not find object id=web"
+        ),
+        ClientError::IndexNotFound(index) if index == "web"
+    ));
+    assert!(matches!(
+        classify(
+            400,
+            "https://localhost:8089/services/receivers/simple",
+            "ERROR: Index 'web_proxy' does not exist"
+        ),
+        ClientError::IndexNotFound(index) if index == "web_proxy"
+    ));
+    // Other missing objects stay plain not-found errors.
+    assert!(matches!(
+        classify(
+            404,
+            "https://localhost:8089/services/saved/searches/missing",
+            "ERROR: Could not find object id=missing"
+        ),
+        ClientError::NotFound(_)
+    ));
+    assert!(matches!(
+        classify(
+            404,
+            "https://localhost:8089/services/cluster/manager/indexes",
+            "ERROR: In handler 'indexes': not found"
+        ),
+        ClientError::NotFound(_)
+    ));
+}
+
+#[test]
+fn session_expiry_wins_over_message_classification() {
+    assert!(matches!(
+        classify(
+            401,
+            "https://localhost:8089",
+            "ERROR: Session expired (requires capability: search)"
+        ),
+        ClientError::SessionExpired { .. }
+    ));
+}
+
+#[test]
+fn remediation_hints_only_for_explained_failures() {
+    let error = ClientError::RoleLacksCapability {
+        capability: "edit_indexes".to_string(),
+        message: "requires capability: edit_indexes".to_string(),
+    };
+    let failure = error.to_user_facing_failure();
+    assert_eq!(
+        failure.category,
+        FailureCategory::AuthInsufficientPermissions
+    );
+    assert!(
+        failure
+            .diagnosis
+            .contains("User lacks 'edit_indexes' capability")
+    );
+
+    let hints = ClientError::MaxRetriesExceeded(3, Box::new(error)).remediation_hints();
+    assert_eq!(hints.len(), 2);
+    assert!(hints[0].contains("grant 'edit_indexes'"));
+
+    assert_eq!(
+        ClientError::ConcurrentSearchLimit("limit".to_string())
+            .to_user_facing_failure()
+            .category,
+        FailureCategory::Quota
+    );
+    assert!(
+        ClientError::NotFound("job".to_string())
+            .remediation_hints()
+            .is_empty()
+    );
+}
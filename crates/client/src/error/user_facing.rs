@@ -7,6 +7,26 @@
 use super::kinds::{ClientError, FailureCategory, UserFacingFailure};
 
 impl ClientError {
+    /// Remediation steps for failures Splunk explained: quotas, missing
+    /// capabilities, missing indexes, license violations, and operations
+    /// Splunk Cloud does not allow.
+    ///
+    /// Empty for other errors, whose generic hints are not worth printing unasked.
+    pub fn remediation_hints(&self) -> Vec<String> {
+        match self {
+            Self::MaxRetriesExceeded(_, source) | Self::TokenRefreshFailed { source, .. } => {
+                source.remediation_hints()
+            }
+            Self::QuotaExceeded(_)
+            | Self::ConcurrentSearchLimit(_)
+            | Self::RoleLacksCapability { .. }
+            | Self::IndexNotFound(_)
+            | Self::LicenseViolation(_)
+            | Self::UnsupportedOnCloud { .. } => self.to_user_facing_failure().action_hints,
+            _ => Vec::new(),
+        }
+    }
+
     /// Convert this error to a user-facing failure with consistent messaging.
     pub fn to_user_facing_failure(&self) -> UserFacingFailure {
         match self {
@@ -333,6 +353,89 @@ impl ClientError {
                     request_id: source_failure.request_id,
                 }
             }
+            Self::QuotaExceeded(msg) => UserFacingFailure {
+                category: FailureCategory::Quota,
+                title: "Search disk quota exceeded",
+                diagnosis: format!("Your role's search disk usage quota is full: {}", msg),
+                action_hints: vec![
+                    "Delete finished search jobs you no longer need (splunk-cli jobs --list, then splunk-cli jobs --delete <SID>)".to_string(),
+                    "Ask a Splunk admin to raise srchDiskQuota for your role".to_string(),
+                ],
+                status_code: None,
+                request_id: None,
+            },
+            Self::ConcurrentSearchLimit(msg) => UserFacingFailure {
+                category: FailureCategory::Quota,
+                title: "Concurrent search limit reached",
+                diagnosis: format!("Too many searches are already running: {}", msg),
+                action_hints: vec![
+                    "Wait for running searches to finish, or cancel ones you no longer need (splunk-cli jobs --cancel <SID>)".to_string(),
+                    "Ask a Splunk admin to raise srchJobsQuota for your role".to_string(),
+                ],
+                status_code: None,
+                request_id: None,
+            },
+            Self::RoleLacksCapability {
+                capability,
+                message,
+            } => UserFacingFailure {
+                category: FailureCategory::AuthInsufficientPermissions,
+                title: "Missing capability",
+                diagnosis: format!("User lacks '{}' capability: {}", capability, message),
+                action_hints: vec![
+                    format!(
+                        "Ask a Splunk admin to grant '{}' to one of your roles",
+                        capability
+                    ),
+                    "List your roles and capabilities with: splunk-cli whoami".to_string(),
+                ],
+                status_code: Some(403),
+                request_id: None,
+            },
+            Self::IndexNotFound(index) => UserFacingFailure {
+                category: FailureCategory::NotFound,
+                title: "Index not found",
+                diagnosis: format!("Index '{}' does not exist", index),
+                action_hints: vec![
+                    "Check the index name with: splunk-cli indexes list".to_string(),
+                    format!(
+                        "Create it with: splunk-cli indexes create {} (if it should exist)",
+                        index
+                    ),
+                ],
+                status_code: Some(404),
+                request_id: None,
+            },
+            Self::LicenseViolation(msg) => UserFacingFailure {
+                category: FailureCategory::License,
+                title: "License violation",
+                diagnosis: format!("Splunk blocked the request because of its license: {}", msg),
+                action_hints: vec![
+                    "Check license warnings and violations with: splunk-cli license messages"
+                        .to_string(),
+                    "Ask a Splunk admin to install a valid license or reduce indexing volume"
+                        .to_string(),
+                ],
+                status_code: None,
+                request_id: None,
+            },
+            Self::UnsupportedOnCloud {
+                operation,
+                alternative,
+            } => UserFacingFailure {
+                category: FailureCategory::InvalidRequest,
+                title: "Not available on Splunk Cloud",
+                diagnosis: format!(
+                    "'{}' uses a management endpoint that Splunk Cloud does not allow",
+                    operation
+                ),
+                action_hints: vec![
+                    format!("Use the Admin Config Service instead: {}", alternative),
+                    "If this is not a Splunk Cloud deployment, set cloud = false in the profile (or SPLUNK_CLOUD=false)".to_string(),
+                ],
+                status_code: None,
+                request_id: None,
+            },
             Self::CircuitBreakerOpen(endpoint) => UserFacingFailure {
                 category: FailureCategory::Server,
                 title: "Service temporarily unavailable",
@@ -11,9 +11,15 @@ mod auth;
 pub mod client;
 pub mod error;
 pub mod format;
+pub mod http_trace;
 pub mod metrics;
+pub mod middleware;
 pub mod models;
 mod name_merge;
+pub mod pagination;
+pub mod raw_format;
+pub mod relative_time;
+pub mod schema_check;
 pub(crate) mod tracing;
 pub mod transaction;
 pub mod workflows;
@@ -35,29 +41,43 @@ pub use client::SplunkClient;
 pub use client::builder::SplunkClientBuilder;
 pub use client::cache::{CacheConfig, CachePolicy, CacheStats, ResponseCache};
 pub use client::health::AggregatedHealth;
+pub use client::rate_limiter::RateLimiter;
 pub use error::{ClientError, FailureCategory, Result, RollbackFailure, UserFacingFailure};
 pub use format::{format_bytes, format_bytes_with_precision};
 pub use metrics::{ErrorCategory, MetricsCollector};
 pub use models::{
-    AddShcMemberParams, App, AppListResponse, Capability, CapabilityListResponse, ClusterInfo,
-    ClusterManagementResponse, ClusterPeer, CreateIndexParams, CreatePoolParams, CreateRoleParams,
-    CreateUserParams, Dashboard, DashboardEntry, DashboardListResponse, DecommissionPeerParams,
-    Forwarder, ForwarderListResponse, HealthCheckOutput, HecAckRequest, HecAckStatus,
-    HecBatchResponse, HecError, HecEvent, HecHealth, HecResponse, Index, IndexListResponse,
-    InstalledLicense, KvStoreMember, KvStoreReplicationStatus, KvStoreStatus,
-    LicenseActivationResult, LicenseInstallResult, LicensePool, LicenseStack, LicenseUsage,
-    LogEntry, LogParsingHealth, LookupTable, LookupTableEntry, LookupTableListResponse, Macro,
-    MacroCreateParams, MacroEntry, MacroListResponse, MacroUpdateParams, MaintenanceModeParams,
-    ModifyIndexParams, ModifyPoolParams, ModifyRoleParams, ModifyUserParams, RemovePeersParams,
-    RemoveShcMemberParams, Role, RoleListResponse, RollingRestartParams, SavedSearch, SearchJob,
-    SearchJobListResponse, SearchJobResults, SearchJobStatus, SendBatchParams, ServerInfo,
+    AddShcMemberParams, AlertAction, App, AppListResponse, Capability, CapabilityListResponse,
+    ClusterBucketHealth, ClusterFixupLevel, ClusterFixupTask, ClusterIndexBucketSummary,
+    ClusterIndexCompliance, ClusterIndexCopies, ClusterIndexExcess, ClusterInfo,
+    ClusterManagementResponse, ClusterNodeRole, ClusterNodeVersion, ClusterPeer, ClusterPeerHealth,
+    ClusterSearchHead, ClusterVersionReport, ClusterVersionSkew, CreateIndexParams,
+    CreateInputParams, CreatePoolParams, CreateRoleParams, CreateUserParams, CurrentContext,
+    Dashboard, DashboardEntry, DashboardFormat, DashboardListResponse, DataRebalanceAction,
+    DecommissionPeerParams, DiskUsage, Forwarder, ForwarderListResponse, FreezeProjection,
+    HealthCheckOutput, HecAckRequest, HecAckStatus, HecBatchResponse, HecError, HecEvent,
+    HecHealth, HecResponse, HecToken, HecTokenCreateParams, HecTokenUpdateParams,
+    HostResourceUsage, Index, IndexListResponse, IngestionGroupBy, IngestionStat, IngestionStats,
+    InstalledLicense, JobAge, JobFilter, JobPerformance, JobStatusFilter, JobUsage, KvStoreMember,
+    KvStoreReplicationStatus, KvStoreStatus, LicenseActivationResult, LicenseInstallResult,
+    LicenseMessage, LicenseMessageSeverity, LicensePool, LicenseSlave, LicenseStack, LicenseUsage,
+    LicenseUsageDay, LicenseWarningSummary, LogEntry, LogParsingHealth, LookupContent, LookupTable,
+    LookupTableEntry, LookupTableListResponse, Macro, MacroCreateParams, MacroEntry,
+    MacroListResponse, MacroUpdateParams, MaintenanceModeParams, MetadataEntry, MetadataType,
+    ModifyIndexParams, ModifyPoolParams, ModifyRoleParams, ModifyUserParams, NewInput,
+    PartitionSpace, PasswordPolicy, PerformanceEntry, PipelineQueueHealth, ProcessResourceUsage,
+    QueueHealth, RemovePeersParams, RemoveShcMemberParams, ResourceUsage, Role,
+    RoleCapabilityMatrix, RoleListResponse, RoleUsage, RollingRestartParams, SavedSearch,
+    SavedSearchAlertActions, ScheduledSearchHealth, SchedulerHealth, SearchConcurrencyLimits,
+    SearchJob, SearchJobListResponse, SearchJobResults, SearchJobStatus, SearchLoad,
+    SecurityHealth, SendBatchParams, ServerInfo, ServerMessage, ServerMessageSeverity,
     SetCaptainParams, ShcCaptain, ShcConfig, ShcManagementResponse, ShcMember, ShcStatus,
-    SplunkHealth, SplunkResponse, UploadLookupParams, User, UserListResponse, WorkloadPool,
-    WorkloadRule,
+    SplunkHealth, SplunkResponse, UploadLookupParams, User, UserListResponse, UserSearchUsage,
+    WorkloadPool, WorkloadRule,
 };
 
 // Re-export search types for CLI/TUI use
 pub use client::search::SearchRequest;
+pub use endpoints::RestMethod;
 pub use endpoints::search::{CreateJobOptions, OutputMode, SearchMode};
 
 /// Normalize a user-provided SPL query for Splunk compatibility.
@@ -0,0 +1,256 @@
+//! Pluggable request middleware for [`crate::SplunkClient`].
+//!
+//! Responsibilities:
+//! - Define the [`RequestInterceptor`] trait that downstream users implement to
+//!   mutate outgoing headers, observe responses, or short-circuit requests.
+//! - Carry the client's per-request hooks (interceptors, the rate limiter, and
+//!   the HTTP trace recorder) to the endpoint layer without threading them
+//!   through every endpoint function.
+//! - Run each HTTP attempt through the registered hooks.
+//!
+//! Does NOT handle:
+//! - Retry, backoff, or circuit-breaker decisions (see `endpoints::request`).
+//! - Trace capture and redaction (see [`crate::http_trace`]).
+//! - Token-bucket accounting (see [`crate::client::rate_limiter`]).
+//!
+//! Invariants:
+//! - Interceptors run in registration order for every attempt, including retries
+//!   and session logins.
+//! - The first interceptor that short-circuits wins; later interceptors'
+//!   `on_request` hooks are skipped, but every interceptor still sees `on_response`.
+//! - Short-circuited responses flow through the normal status handling, so a
+//!   synthetic 429 or 503 is retried like a real one.
+//! - The rate limiter is consulted only for attempts that reach the network;
+//!   short-circuited attempts are not throttled.
+//! - Hooks are task-scoped; work spawned onto other tasks does not inherit them.
+
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use reqwest::header::HeaderMap;
+use reqwest::{Method, RequestBuilder, Response, ResponseBuilderExt, StatusCode, Url};
+
+use crate::client::rate_limiter::RateLimiter;
+use crate::http_trace::{self, HttpTraceRecorder};
+
+tokio::task_local! {
+    static ACTIVE_HOOKS: RequestHooks;
+}
+
+/// Metadata describing the HTTP attempt an interceptor is called for.
+#[derive(Debug, Clone, Copy)]
+pub struct RequestContext<'a> {
+    /// Endpoint label used for metrics (e.g., `/services/search/jobs`).
+    pub endpoint: &'a str,
+    pub method: &'a Method,
+    pub url: &'a Url,
+    /// 1-based attempt number within the retry loop.
+    pub attempt: usize,
+}
+
+/// Outcome of an attempt, passed to [`RequestInterceptor::on_response`].
+#[derive(Debug, Clone, Copy)]
+pub struct ResponseInfo<'a> {
+    /// HTTP status, or `None` when the request failed at the transport level.
+    pub status: Option<StatusCode>,
+    pub headers: Option<&'a HeaderMap>,
+    /// Transport error message when no response was received.
+    pub error: Option<&'a str>,
+    pub elapsed: Duration,
+    /// True when an interceptor supplied the response instead of the server.
+    pub short_circuited: bool,
+}
+
+/// Decision returned by [`RequestInterceptor::on_request`].
+#[derive(Debug, Clone)]
+pub enum InterceptorAction {
+    /// Send the request (with any header changes applied).
+    Continue,
+    /// Skip the network call and use this response instead.
+    ShortCircuit(InterceptedResponse),
+}
+
+/// Synthetic response returned by a short-circuiting interceptor.
+#[derive(Debug, Clone)]
+pub struct InterceptedResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl InterceptedResponse {
+    /// Create an empty response with the given status.
+    pub fn new(status: StatusCode) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: Vec::new(),
+        }
+    }
+
+    /// Create a JSON response with the given status and body.
+    pub fn json(status: StatusCode, body: &serde_json::Value) -> Self {
+        let mut response = Self::new(status);
+        response.headers.insert(
+            reqwest::header::CONTENT_TYPE,
+            reqwest::header::HeaderValue::from_static("application/json"),
+        );
+        response.body = body.to_string().into_bytes();
+        response
+    }
+
+    fn into_response(self, url: Url) -> Response {
+        let mut builder = http::Response::builder().status(self.status).url(url);
+        if let Some(headers) = builder.headers_mut() {
+            *headers = self.headers;
+        }
+        let response = builder
+            .body(self.body)
+            .expect("status and headers come from typed values");
+        Response::from(response)
+    }
+}
+
+/// Hook into every HTTP attempt made by a [`crate::SplunkClient`].
+///
+/// Both methods have no-op defaults, so implementors only override what they
+/// need. Hooks are synchronous; expensive work should be handed off rather than
+/// done inline.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use std::sync::Arc;
+/// use splunk_client::middleware::{InterceptorAction, RequestContext, RequestInterceptor};
+///
+/// struct TenantHeader;
+///
+/// impl RequestInterceptor for TenantHeader {
+///     fn on_request(&self, _ctx: &RequestContext<'_>, headers: &mut HeaderMap) -> InterceptorAction {
+///         headers.insert("x-tenant", HeaderValue::from_static("blue"));
+///         InterceptorAction::Continue
+///     }
+/// }
+///
+/// let client = SplunkClient::builder()
+///     .base_url("https://localhost:8089".to_string())
+///     .auth_strategy(auth_strategy)
+///     .with_middleware(Arc::new(TenantHeader))
+///     .build()?;
+/// ```
+pub trait RequestInterceptor: Send + Sync {
+    /// Called before each attempt. Headers may be modified in place.
+    fn on_request(&self, _ctx: &RequestContext<'_>, _headers: &mut HeaderMap) -> InterceptorAction {
+        InterceptorAction::Continue
+    }
+
+    /// Called after each attempt completes, successfully or not.
+    fn on_response(&self, _ctx: &RequestContext<'_>, _response: &ResponseInfo<'_>) {}
+}
+
+/// Per-client hooks made available to the endpoint layer while a request runs.
+#[derive(Clone, Default)]
+pub(crate) struct RequestHooks {
+    pub(crate) interceptors: Vec<Arc<dyn RequestInterceptor>>,
+    pub(crate) trace_recorder: Option<HttpTraceRecorder>,
+    pub(crate) rate_limiter: Option<Arc<RateLimiter>>,
+}
+
+impl RequestHooks {
+    fn is_empty(&self) -> bool {
+        self.interceptors.is_empty() && self.trace_recorder.is_none() && self.rate_limiter.is_none()
+    }
+
+    /// Run `fut` with these hooks active for the current task.
+    pub(crate) async fn scope<F: Future>(&self, fut: F) -> F::Output {
+        if self.is_empty() {
+            fut.await
+        } else {
+            ACTIVE_HOOKS.scope(self.clone(), fut).await
+        }
+    }
+}
+
+impl fmt::Debug for RequestHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestHooks")
+            .field("interceptors", &self.interceptors.len())
+            .field("trace_recorder", &self.trace_recorder.is_some())
+            .field("rate_limiter", &self.rate_limiter)
+            .finish()
+    }
+}
+
+/// Send a single attempt through the hooks active for the current task.
+pub(crate) async fn send_attempt(
+    builder: RequestBuilder,
+    endpoint: &str,
+    attempt: usize,
+) -> reqwest::Result<Response> {
+    match ACTIVE_HOOKS.try_with(|hooks| hooks.clone()).ok() {
+        // Boxed so the hook machinery does not inflate every endpoint future.
+        Some(hooks) => Box::pin(send_with_hooks(builder, &hooks, endpoint, attempt)).await,
+        None => builder.send().await,
+    }
+}
+
+async fn send_with_hooks(
+    builder: RequestBuilder,
+    hooks: &RequestHooks,
+    endpoint: &str,
+    attempt: usize,
+) -> reqwest::Result<Response> {
+    let (client, request) = builder.build_split();
+    let mut request = request?;
+    let method = request.method().clone();
+    let url = request.url().clone();
+    let ctx = RequestContext {
+        endpoint,
+        method: &method,
+        url: &url,
+        attempt: attempt + 1,
+    };
+    let started = Instant::now();
+
+    let mut short_circuit = None;
+    for interceptor in &hooks.interceptors {
+        if let InterceptorAction::ShortCircuit(response) =
+            interceptor.on_request(&ctx, request.headers_mut())
+        {
+            short_circuit = Some(response);
+            break;
+        }
+    }
+
+    let short_circuited = short_circuit.is_some();
+    if !short_circuited && let Some(limiter) = &hooks.rate_limiter {
+        limiter.acquire().await;
+    }
+    let result = match short_circuit {
+        Some(response) => Ok(response.into_response(url.clone())),
+        None => match &hooks.trace_recorder {
+            Some(recorder) => {
+                http_trace::send_recorded(&client, request, recorder, endpoint, attempt + 1).await
+            }
+            None => client.execute(request).await,
+        },
+    };
+
+    if !hooks.interceptors.is_empty() {
+        let error = result.as_ref().err().map(|e| e.to_string());
+        let info = ResponseInfo {
+            status: result.as_ref().ok().map(|r| r.status()),
+            headers: result.as_ref().ok().map(|r| r.headers()),
+            error: error.as_deref(),
+            elapsed: started.elapsed(),
+            short_circuited,
+        };
+        for interceptor in &hooks.interceptors {
+            interceptor.on_response(&ctx, &info);
+        }
+    }
+
+    result
+}
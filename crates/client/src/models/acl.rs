@@ -0,0 +1,130 @@
+//! Knowledge object permission (ACL) models.
+//!
+//! Responsibilities:
+//! - Identify the knowledge object kinds whose ACL can be read and changed.
+//! - Define the flattened ACL view and the update parameters.
+//!
+//! Does NOT handle:
+//! - Does not handle HTTP requests (see endpoints module).
+//! - Does not merge updates with the current ACL (see client module).
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Knowledge object types with an `.../{name}/acl` endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KnowledgeObjectKind {
+    SavedSearch,
+    Macro,
+    Lookup,
+    Dashboard,
+}
+
+impl KnowledgeObjectKind {
+    /// REST collection path relative to the services root.
+    pub fn rest_path(self) -> &'static str {
+        match self {
+            Self::SavedSearch => "saved/searches",
+            Self::Macro => "admin/macros",
+            Self::Lookup => "data/lookup-table-files",
+            Self::Dashboard => "data/ui/views",
+        }
+    }
+
+    /// Human-readable singular label (e.g., "saved search").
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::SavedSearch => "saved search",
+            Self::Macro => "macro",
+            Self::Lookup => "lookup",
+            Self::Dashboard => "dashboard",
+        }
+    }
+}
+
+impl fmt::Display for KnowledgeObjectKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Permissions of a single knowledge object.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObjectAcl {
+    /// Object name
+    pub name: String,
+    /// Object kind
+    pub kind: KnowledgeObjectKind,
+    /// App the object is defined in
+    pub app: String,
+    /// Object owner
+    pub owner: String,
+    /// Sharing level (`user`, `app` or `global`)
+    pub sharing: String,
+    /// Roles allowed to read the object (`*` = everyone)
+    pub read: Vec<String>,
+    /// Roles allowed to write the object (`*` = everyone)
+    pub write: Vec<String>,
+    /// Whether the current user may change the object's permissions
+    pub can_change_perms: bool,
+}
+
+/// Parameters for changing a knowledge object's permissions.
+///
+/// Unset fields keep their current value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AclUpdateParams {
+    /// New sharing level (`user`, `app` or `global`)
+    pub sharing: Option<String>,
+    /// New owner
+    pub owner: Option<String>,
+    /// New read role list
+    pub read: Option<Vec<String>>,
+    /// New write role list
+    pub write: Option<Vec<String>>,
+}
+
+impl AclUpdateParams {
+    /// Whether no change is requested.
+    pub fn is_empty(&self) -> bool {
+        self.sharing.is_none()
+            && self.owner.is_none()
+            && self.read.is_none()
+            && self.write.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind_paths_and_labels() {
+        assert_eq!(
+            KnowledgeObjectKind::SavedSearch.rest_path(),
+            "saved/searches"
+        );
+        assert_eq!(
+            KnowledgeObjectKind::Lookup.rest_path(),
+            "data/lookup-table-files"
+        );
+        assert_eq!(KnowledgeObjectKind::Dashboard.to_string(), "dashboard");
+        assert_eq!(
+            serde_json::to_string(&KnowledgeObjectKind::SavedSearch).unwrap(),
+            "\"saved_search\""
+        );
+    }
+
+    #[test]
+    fn test_update_params_is_empty() {
+        assert!(AclUpdateParams::default().is_empty());
+        assert!(
+            !AclUpdateParams {
+                read: Some(vec![]),
+                ..AclUpdateParams::default()
+            }
+            .is_empty()
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! Admin Config Service (ACS) models for Splunk Cloud Platform.
+//!
+//! # What this module handles:
+//! - Indexes and HEC tokens as returned by the ACS `adminconfig/v2` API
+//! - Request bodies for creating indexes and HEC tokens through ACS
+//!
+//! # What this module does NOT handle:
+//! - Direct HTTP API calls (see [`crate::endpoints::acs`])
+//! - Management-port (`/services`) index and HEC token models (see
+//!   [`crate::models::indexes`] and [`crate::models::hec_tokens`])
+
+use serde::{Deserialize, Serialize};
+
+/// An index on a Splunk Cloud stack, as reported by ACS.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AcsIndex {
+    pub name: String,
+    /// `event` or `metric`.
+    #[serde(default)]
+    pub datatype: Option<String>,
+    /// Days data stays searchable before it is archived or deleted.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::opt_u64_from_string_or_number"
+    )]
+    pub searchable_days: Option<u64>,
+    /// Maximum index size in MB (0 means unlimited).
+    #[serde(
+        rename = "maxDataSizeMB",
+        default,
+        deserialize_with = "crate::serde_helpers::opt_u64_from_string_or_number"
+    )]
+    pub max_data_size_mb: Option<u64>,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::opt_u64_from_string_or_number"
+    )]
+    pub total_event_count: Option<u64>,
+    #[serde(
+        rename = "totalRawSizeMB",
+        default,
+        deserialize_with = "crate::serde_helpers::opt_u64_from_string_or_number"
+    )]
+    pub total_raw_size_mb: Option<u64>,
+    /// Days archived data is kept (Dynamic Data Active Archive).
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::opt_u64_from_string_or_number"
+    )]
+    pub splunk_archival_retention_days: Option<u64>,
+}
+
+/// Body of an ACS index creation request.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AcsIndexCreateParams {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datatype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub searchable_days: Option<u64>,
+    #[serde(rename = "maxDataSizeMB", skip_serializing_if = "Option::is_none")]
+    pub max_data_size_mb: Option<u64>,
+}
+
+/// A HEC token on a Splunk Cloud stack, as reported by ACS.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AcsHecToken {
+    pub name: String,
+    /// Token value; ACS only returns it to users allowed to see it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(default)]
+    pub default_index: Option<String>,
+    #[serde(default)]
+    pub allowed_indexes: Vec<String>,
+    #[serde(default)]
+    pub source_type: Option<String>,
+    #[serde(default)]
+    pub disabled: bool,
+    #[serde(default, alias = "useACK")]
+    pub use_ack: bool,
+}
+
+/// Token settings as nested under `spec` in ACS responses.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AcsHecTokenSpec {
+    pub name: String,
+    #[serde(default)]
+    pub default_index: Option<String>,
+    #[serde(default)]
+    pub allowed_indexes: Vec<String>,
+    #[serde(default)]
+    pub source_type: Option<String>,
+    #[serde(default)]
+    pub disabled: bool,
+    #[serde(default, alias = "useACK")]
+    pub use_ack: bool,
+}
+
+/// One HEC token in an ACS response: its settings plus the token value.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AcsHecTokenEntry {
+    pub spec: AcsHecTokenSpec,
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+impl From<AcsHecTokenEntry> for AcsHecToken {
+    fn from(entry: AcsHecTokenEntry) -> Self {
+        let AcsHecTokenEntry { spec, token } = entry;
+        Self {
+            name: spec.name,
+            token,
+            default_index: spec.default_index,
+            allowed_indexes: spec.allowed_indexes,
+            source_type: spec.source_type,
+            disabled: spec.disabled,
+            use_ack: spec.use_ack,
+        }
+    }
+}
+
+/// ACS HEC token list response.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AcsHecTokenListResponse {
+    #[serde(rename = "http-event-collectors", default)]
+    pub http_event_collectors: Vec<AcsHecTokenEntry>,
+}
+
+/// ACS HEC token creation response.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AcsHecTokenResponse {
+    #[serde(rename = "http-event-collector")]
+    pub http_event_collector: AcsHecTokenEntry,
+}
+
+/// Body of an ACS HEC token creation request.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AcsHecTokenCreateParams {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_index: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub allowed_indexes: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<String>,
+    pub disabled: bool,
+    pub use_ack: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_acs_index_accepts_string_counts() {
+        let index: AcsIndex = serde_json::from_value(json!({
+            "name": "web",
+            "datatype": "event",
+            "searchableDays": 90,
+            "maxDataSizeMB": 0,
+            "totalEventCount": "1200",
+            "totalRawSizeMB": "3"
+        }))
+        .unwrap();
+        assert_eq!(index.searchable_days, Some(90));
+        assert_eq!(index.total_event_count, Some(1200));
+        assert_eq!(index.splunk_archival_retention_days, None);
+    }
+
+    #[test]
+    fn test_acs_hec_token_flattens_spec() {
+        let response: AcsHecTokenListResponse = serde_json::from_value(json!({
+            "http-event-collectors": [{
+                "spec": {
+                    "name": "web-hec",
+                    "defaultIndex": "web",
+                    "allowedIndexes": ["web", "main"],
+                    "useACK": true
+                },
+                "token": "abc-123"
+            }]
+        }))
+        .unwrap();
+        let token = AcsHecToken::from(response.http_event_collectors[0].clone());
+        assert_eq!(token.name, "web-hec");
+        assert_eq!(token.token.as_deref(), Some("abc-123"));
+        assert_eq!(token.allowed_indexes, vec!["web", "main"]);
+        assert!(token.use_ack);
+        assert!(!token.disabled);
+    }
+
+    #[test]
+    fn test_create_params_skip_unset_fields() {
+        let body = serde_json::to_value(AcsIndexCreateParams {
+            name: "web".to_string(),
+            searchable_days: Some(30),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(body, json!({ "name": "web", "searchableDays": 30 }));
+    }
+}
@@ -1,10 +1,12 @@
 //! Alert models for Splunk alerts API.
 //!
-//! This module contains types for listing and managing fired alerts.
+//! This module contains types for listing and managing fired alerts and the
+//! alert actions they trigger.
 //!
 //! # What this module handles:
 //! - Deserialization of fired alert data from Splunk REST API
 //! - Type-safe representation of alert metadata and trigger information
+//! - Alert action definitions and the actions configured on a saved search
 //!
 //! # What this module does NOT handle:
 //! - Direct HTTP API calls (see [`crate::endpoints::alerts`])
@@ -13,8 +15,10 @@
 //! Splunk alerts API endpoints:
 //! - /services/alerts/fired_alerts
 //! - /services/alerts/fired_alerts/{name}
+//! - /services/alerts/alert_actions
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 
 /// Alert severity levels.
@@ -140,6 +144,159 @@ pub struct AlertConfig {
     pub action_webhook_url: Option<String>,
 }
 
+/// Alert action available on the server (email, webhook, custom modular actions).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct AlertAction {
+    /// The action name (entry name from API), e.g. `email` or `webhook`.
+    #[serde(default)]
+    pub name: String,
+    /// Display label shown in Splunk Web.
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Short description of what the action does.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Whether the action is disabled server-wide.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::bool_from_string_or_bool"
+    )]
+    pub disabled: bool,
+    /// Whether this is a custom (modular) alert action invoked via `sendalert`.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::bool_from_string_or_bool"
+    )]
+    pub is_custom: bool,
+    /// Payload format passed to custom action scripts (`json` or `xml`).
+    #[serde(default)]
+    pub payload_format: Option<String>,
+}
+
+/// Alert action entry wrapper.
+#[derive(Debug, Deserialize, Clone)]
+pub struct AlertActionEntry {
+    pub name: String,
+    pub content: AlertAction,
+}
+
+/// Alert action list response.
+#[derive(Debug, Deserialize, Clone)]
+pub struct AlertActionListResponse {
+    pub entry: Vec<AlertActionEntry>,
+}
+
+/// An alert action a saved search triggers, with its configured parameters.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct TriggeredAlertAction {
+    /// The action name, e.g. `email`.
+    pub name: String,
+    /// Non-empty `action.<name>.*` settings, keyed without the prefix
+    /// (e.g. `to`, `param.url`).
+    pub params: BTreeMap<String, String>,
+}
+
+/// Alert actions configured on a single saved search.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct SavedSearchAlertActions {
+    /// The saved search name.
+    pub saved_search: String,
+    /// Actions the saved search triggers, in `actions` order.
+    pub actions: Vec<TriggeredAlertAction>,
+}
+
+impl SavedSearchAlertActions {
+    /// Collect triggered actions from a saved search's raw entry content.
+    ///
+    /// An action counts as triggered when it is listed in `actions` or its
+    /// `action.<name>` flag is set; actions only enabled by flag are appended
+    /// after the listed ones, sorted by name.
+    pub fn from_content(
+        saved_search: impl Into<String>,
+        content: &serde_json::Map<String, serde_json::Value>,
+    ) -> Self {
+        let mut names: Vec<String> = content
+            .get("actions")
+            .and_then(|v| v.as_str())
+            .map(|list| {
+                list.split(',')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut flagged: Vec<&str> = content
+            .iter()
+            .filter_map(|(key, value)| {
+                let name = key.strip_prefix("action.")?;
+                (!name.contains('.') && conf_flag(value)).then_some(name)
+            })
+            .filter(|name| !names.iter().any(|n| n == name))
+            .collect();
+        flagged.sort_unstable();
+        names.extend(flagged.into_iter().map(str::to_string));
+
+        let actions = names
+            .into_iter()
+            .map(|name| {
+                let prefix = format!("action.{}.", name);
+                let params = content
+                    .iter()
+                    .filter_map(|(key, value)| {
+                        let param = key.strip_prefix(&prefix)?;
+                        let value = conf_value(value)?;
+                        Some((param.to_string(), value))
+                    })
+                    .collect();
+                TriggeredAlertAction { name, params }
+            })
+            .collect();
+
+        Self {
+            saved_search: saved_search.into(),
+            actions,
+        }
+    }
+}
+
+/// Outcome of a search dispatched to test-fire an alert action.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct AlertActionDispatch {
+    /// Search ID of the test-fire job.
+    pub sid: String,
+    /// Whether the job failed or reported an error or fatal message.
+    pub failed: bool,
+    /// Job messages as `TYPE: text`, in the order Splunk reported them.
+    pub messages: Vec<String>,
+}
+
+/// Render a scalar conf value as a string, skipping empty and null values.
+fn conf_value(value: &serde_json::Value) -> Option<String> {
+    let rendered = match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Bool(b) => if *b { "1" } else { "0" }.to_string(),
+        serde_json::Value::Number(n) => n.to_string(),
+        _ => return None,
+    };
+    (!rendered.is_empty()).then_some(rendered)
+}
+
+fn conf_flag(value: &serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Bool(b) => *b,
+        serde_json::Value::Number(n) => n.as_u64().is_some_and(|n| n != 0),
+        serde_json::Value::String(s) => {
+            matches!(
+                s.trim().to_ascii_lowercase().as_str(),
+                "1" | "true" | "t" | "yes"
+            )
+        }
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,4 +373,47 @@ mod tests {
         assert_eq!(config.is_scheduled, Some(true));
         assert_eq!(config.alert_severity, Some(AlertSeverity::Critical));
     }
+
+    #[test]
+    fn test_alert_action_accepts_string_flags() {
+        let json = r#"{
+            "label": "Webhook",
+            "disabled": "0",
+            "is_custom": "1",
+            "payload_format": "json"
+        }"#;
+        let action: AlertAction = serde_json::from_str(json).unwrap();
+        assert!(!action.disabled);
+        assert!(action.is_custom);
+        assert_eq!(action.payload_format.as_deref(), Some("json"));
+    }
+
+    #[test]
+    fn test_saved_search_alert_actions_from_content() {
+        let content = serde_json::json!({
+            "actions": "email, webhook",
+            "action.email": "1",
+            "action.email.to": "oncall@example.com",
+            "action.email.subject": "",
+            "action.email.maxresults": 100,
+            "action.webhook": true,
+            "action.webhook.param.url": "https://hooks.example.com/alert",
+            "action.script": false,
+            "action.script.filename": "page.sh",
+            "action.logevent": "1",
+            "action.logevent.param.index": "alerts"
+        });
+        let actions = SavedSearchAlertActions::from_content("Errors", content.as_object().unwrap());
+
+        let names: Vec<&str> = actions.actions.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, ["email", "webhook", "logevent"]);
+        let email = &actions.actions[0];
+        assert_eq!(email.params.get("to").unwrap(), "oncall@example.com");
+        assert_eq!(email.params.get("maxresults").unwrap(), "100");
+        assert!(!email.params.contains_key("subject"));
+        assert_eq!(
+            actions.actions[1].params.get("param.url").unwrap(),
+            "https://hooks.example.com/alert"
+        );
+    }
 }
@@ -148,6 +148,32 @@ pub struct ClusterPeer {
     pub bundle_replication_count: Option<u32>,
     #[serde(rename = "isCaptain")]
     pub is_captain: Option<bool>,
+    /// Splunk version the peer reported when it registered with the manager.
+    pub splunk_version: Option<String>,
+}
+
+/// Peer counts on a cluster manager, as reported by the aggregated health check.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClusterPeerHealth {
+    pub total: usize,
+    pub searchable: usize,
+    pub down: usize,
+}
+
+impl ClusterPeerHealth {
+    pub fn from_peers(peers: &[ClusterPeer]) -> Self {
+        Self {
+            total: peers.len(),
+            searchable: peers
+                .iter()
+                .filter(|peer| peer.peer_state == PeerState::Searchable)
+                .count(),
+            down: peers
+                .iter()
+                .filter(|peer| peer.status == PeerStatus::Down)
+                .count(),
+        }
+    }
 }
 
 /// Parameters for setting maintenance mode.
@@ -171,6 +197,35 @@ pub struct DecommissionPeerParams {
     pub decommission: bool,
 }
 
+/// Data rebalance control action (`cluster/master/control/control/rebalance_buckets`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataRebalanceAction {
+    /// Start moving bucket copies so peers hold an even share of data
+    Start,
+    /// Stop a running data rebalance
+    Stop,
+    /// Report the progress of a data rebalance
+    Status,
+}
+
+impl DataRebalanceAction {
+    /// Value of the endpoint's `action` parameter.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Start => "start",
+            Self::Stop => "stop",
+            Self::Status => "status",
+        }
+    }
+}
+
+impl fmt::Display for DataRebalanceAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Response from a cluster management operation.
 #[derive(Debug, Deserialize)]
 pub struct ClusterManagementResponse {
@@ -180,329 +235,483 @@ pub struct ClusterManagementResponse {
     pub message: Option<String>,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Fix-up level reported by the cluster manager (`cluster/master/fixup?level=`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClusterFixupLevel {
+    Streaming,
+    DataSafety,
+    Generation,
+    ReplicationFactor,
+    SearchFactor,
+    ChecksumSync,
+}
 
-    // ClusterMode tests
-    #[test]
-    fn cluster_mode_default_is_unknown() {
-        assert_eq!(ClusterMode::default(), ClusterMode::Unknown);
+impl ClusterFixupLevel {
+    /// All fix-up levels in the order the cluster manager prioritizes them.
+    pub const ALL: [ClusterFixupLevel; 6] = [
+        Self::Streaming,
+        Self::DataSafety,
+        Self::Generation,
+        Self::ReplicationFactor,
+        Self::SearchFactor,
+        Self::ChecksumSync,
+    ];
+
+    /// The value expected by the `level` query parameter.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Streaming => "streaming",
+            Self::DataSafety => "data_safety",
+            Self::Generation => "generation",
+            Self::ReplicationFactor => "replication_factor",
+            Self::SearchFactor => "search_factor",
+            Self::ChecksumSync => "checksum_sync",
+        }
     }
+}
 
-    #[test]
-    fn cluster_mode_display_formats_correctly() {
-        assert_eq!(ClusterMode::Manager.to_string(), "Manager");
-        assert_eq!(ClusterMode::Peer.to_string(), "Peer");
-        assert_eq!(ClusterMode::Unknown.to_string(), "Unknown");
+impl fmt::Display for ClusterFixupLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
     }
+}
 
-    #[test]
-    fn cluster_mode_deserializes_from_snake_case() {
-        let manager: ClusterMode = serde_json::from_str("\"manager\"").unwrap();
-        assert_eq!(manager, ClusterMode::Manager);
-
-        let peer: ClusterMode = serde_json::from_str("\"peer\"").unwrap();
-        assert_eq!(peer, ClusterMode::Peer);
+impl std::str::FromStr for ClusterFixupLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|level| level.as_str() == s)
+            .ok_or_else(|| {
+                format!(
+                    "Invalid fix-up level '{}'. Valid values: {}",
+                    s,
+                    Self::ALL.map(Self::as_str).join(", ")
+                )
+            })
     }
+}
 
-    #[test]
-    fn cluster_mode_deserializes_unknown_variant() {
-        let unknown: ClusterMode = serde_json::from_str("\"invalid_mode\"").unwrap();
-        assert_eq!(unknown, ClusterMode::Unknown);
-    }
+/// Reason recorded for a pending fix-up task.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClusterFixupReason {
+    #[serde(default)]
+    pub reason: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::opt_u64_from_string_or_number"
+    )]
+    pub timestamp: Option<u64>,
+}
 
-    #[test]
-    fn cluster_mode_serializes_to_snake_case() {
-        assert_eq!(
-            serde_json::to_string(&ClusterMode::Manager).unwrap(),
-            "\"manager\""
-        );
-        assert_eq!(
-            serde_json::to_string(&ClusterMode::Peer).unwrap(),
-            "\"peer\""
-        );
-    }
+/// Raw content of a fix-up entry as returned by the cluster manager.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClusterFixupContent {
+    #[serde(default)]
+    pub index: String,
+    #[serde(default)]
+    pub initial: Option<ClusterFixupReason>,
+    #[serde(default)]
+    pub latest: Option<ClusterFixupReason>,
+}
 
-    // ClusterStatus tests
-    #[test]
-    fn cluster_status_default_is_unknown() {
-        assert_eq!(ClusterStatus::default(), ClusterStatus::Unknown);
-    }
+/// A pending bucket fix-up task on the cluster manager.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClusterFixupTask {
+    /// Bucket ID (e.g. `main~42~GUID`).
+    pub bucket_id: String,
+    /// Index the bucket belongs to.
+    pub index: String,
+    /// Fix-up level the task is queued at.
+    pub level: ClusterFixupLevel,
+    /// Most recent reason for the fix-up.
+    pub reason: Option<String>,
+    /// Epoch seconds of the most recent reason.
+    pub timestamp: Option<u64>,
+}
+
+/// Excess bucket copy counts for a single clustered index.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClusterIndexExcess {
+    #[serde(default)]
+    pub name: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::usize_from_string_or_number"
+    )]
+    pub buckets_with_excess_copies: usize,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::usize_from_string_or_number"
+    )]
+    pub buckets_with_excess_searchable_copies: usize,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::usize_from_string_or_number"
+    )]
+    pub total_excess_bucket_copies: usize,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::usize_from_string_or_number"
+    )]
+    pub total_excess_searchable_copies: usize,
+}
 
-    #[test]
-    fn cluster_status_display_formats_correctly() {
-        assert_eq!(ClusterStatus::Enabled.to_string(), "Enabled");
-        assert_eq!(ClusterStatus::Disabled.to_string(), "Disabled");
-        assert_eq!(ClusterStatus::Unknown.to_string(), "Unknown");
+impl ClusterIndexExcess {
+    /// Whether this index has any excess bucket copies to remove.
+    pub fn has_excess(&self) -> bool {
+        self.total_excess_bucket_copies > 0 || self.total_excess_searchable_copies > 0
     }
+}
 
-    #[test]
-    fn cluster_status_deserializes_from_snake_case() {
-        let enabled: ClusterStatus = serde_json::from_str("\"enabled\"").unwrap();
-        assert_eq!(enabled, ClusterStatus::Enabled);
+/// One copy slot of an index's replicated or searchable copy tracker.
+///
+/// Slot `n` counts the buckets that have at least `n + 1` copies against the
+/// number of buckets expected to have them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClusterCopySlot {
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::usize_from_string_or_number"
+    )]
+    pub actual_copies_per_slot: usize,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::usize_from_string_or_number"
+    )]
+    pub expected_total_per_slot: usize,
+}
 
-        let disabled: ClusterStatus = serde_json::from_str("\"disabled\"").unwrap();
-        assert_eq!(disabled, ClusterStatus::Disabled);
-    }
+/// Bucket copy trackers for a single clustered index (`cluster/master/indexes`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClusterIndexCopies {
+    #[serde(default)]
+    pub name: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::usize_from_string_or_number"
+    )]
+    pub num_buckets: usize,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::bool_from_string_or_bool"
+    )]
+    pub is_searchable: bool,
+    #[serde(default)]
+    pub replicated_copies_tracker: Vec<ClusterCopySlot>,
+    #[serde(default)]
+    pub searchable_copies_tracker: Vec<ClusterCopySlot>,
+}
 
-    #[test]
-    fn cluster_status_deserializes_unknown_variant() {
-        let unknown: ClusterStatus = serde_json::from_str("\"invalid_status\"").unwrap();
-        assert_eq!(unknown, ClusterStatus::Unknown);
-    }
+/// Actual copy counts of a clustered index against the configured replication
+/// and search factors.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClusterIndexCompliance {
+    pub index: String,
+    pub buckets: usize,
+    pub searchable: bool,
+    pub replication_factor: u32,
+    /// Copies every bucket currently has, capped at the replication factor.
+    pub replicated_copies: u32,
+    pub buckets_below_rf: usize,
+    pub search_factor: u32,
+    /// Searchable copies every bucket currently has, capped at the search factor.
+    pub searchable_copies: u32,
+    pub buckets_below_sf: usize,
+    pub compliant: bool,
+}
 
-    #[test]
-    fn cluster_status_serializes_to_snake_case() {
-        assert_eq!(
-            serde_json::to_string(&ClusterStatus::Enabled).unwrap(),
-            "\"enabled\""
+impl ClusterIndexCompliance {
+    /// Compare an index's copy trackers against the cluster's replication and search factors.
+    pub fn new(copies: &ClusterIndexCopies, replication_factor: u32, search_factor: u32) -> Self {
+        let (replicated_copies, buckets_below_rf) = met_copies(
+            &copies.replicated_copies_tracker,
+            replication_factor,
+            copies.num_buckets,
         );
-        assert_eq!(
-            serde_json::to_string(&ClusterStatus::Disabled).unwrap(),
-            "\"disabled\""
+        let (searchable_copies, buckets_below_sf) = met_copies(
+            &copies.searchable_copies_tracker,
+            search_factor,
+            copies.num_buckets,
         );
+        Self {
+            index: copies.name.clone(),
+            buckets: copies.num_buckets,
+            searchable: copies.is_searchable,
+            replication_factor,
+            replicated_copies,
+            buckets_below_rf,
+            search_factor,
+            searchable_copies,
+            buckets_below_sf,
+            compliant: buckets_below_rf == 0 && buckets_below_sf == 0,
+        }
     }
 
-    // PeerStatus tests
-    #[test]
-    fn peer_status_default_is_unknown() {
-        assert_eq!(PeerStatus::default(), PeerStatus::Unknown);
-    }
-
-    #[test]
-    fn peer_status_display_formats_correctly() {
-        assert_eq!(PeerStatus::Up.to_string(), "Up");
-        assert_eq!(PeerStatus::Down.to_string(), "Down");
-        assert_eq!(PeerStatus::Unknown.to_string(), "Unknown");
-    }
-
-    #[test]
-    fn peer_status_deserializes_from_explicit_rename() {
-        let up: PeerStatus = serde_json::from_str("\"Up\"").unwrap();
-        assert_eq!(up, PeerStatus::Up);
-
-        let down: PeerStatus = serde_json::from_str("\"Down\"").unwrap();
-        assert_eq!(down, PeerStatus::Down);
-    }
-
-    #[test]
-    fn peer_status_deserializes_unknown_variant() {
-        let unknown: PeerStatus = serde_json::from_str("\"invalid_status\"").unwrap();
-        assert_eq!(unknown, PeerStatus::Unknown);
-
-        let unknown_lowercase: PeerStatus = serde_json::from_str("\"up\"").unwrap();
-        assert_eq!(unknown_lowercase, PeerStatus::Unknown);
+    /// Whether every bucket has the full replication factor.
+    pub fn meets_replication_factor(&self) -> bool {
+        self.buckets_below_rf == 0
     }
 
-    #[test]
-    fn peer_status_serializes_with_explicit_rename() {
-        assert_eq!(serde_json::to_string(&PeerStatus::Up).unwrap(), "\"Up\"");
-        assert_eq!(
-            serde_json::to_string(&PeerStatus::Down).unwrap(),
-            "\"Down\""
-        );
-    }
-
-    // PeerState tests
-    #[test]
-    fn peer_state_default_is_unknown() {
-        assert_eq!(PeerState::default(), PeerState::Unknown);
+    /// Whether every bucket has the full search factor.
+    pub fn meets_search_factor(&self) -> bool {
+        self.buckets_below_sf == 0
     }
+}
 
-    #[test]
-    fn peer_state_display_formats_correctly() {
-        assert_eq!(PeerState::Searchable.to_string(), "Searchable");
-        assert_eq!(PeerState::Unsearchable.to_string(), "Unsearchable");
-        assert_eq!(PeerState::Streaming.to_string(), "Streaming");
-        assert_eq!(PeerState::Unknown.to_string(), "Unknown");
+/// Count the leading fully met copy slots up to `factor` and the largest bucket shortfall.
+///
+/// Slots missing from the tracker are treated as having no copies.
+fn met_copies(slots: &[ClusterCopySlot], factor: u32, buckets: usize) -> (u32, usize) {
+    let mut copies = 0;
+    let mut below = 0;
+    for slot in 0..factor as usize {
+        let (actual, expected) = slots
+            .get(slot)
+            .map(|s| (s.actual_copies_per_slot, s.expected_total_per_slot))
+            .unwrap_or((0, buckets));
+        if actual >= expected && copies as usize == slot {
+            copies += 1;
+        }
+        below = below.max(expected.saturating_sub(actual));
     }
+    (copies, below)
+}
 
-    #[test]
-    fn peer_state_deserializes_from_snake_case() {
-        let searchable: PeerState = serde_json::from_str("\"searchable\"").unwrap();
-        assert_eq!(searchable, PeerState::Searchable);
-
-        let unsearchable: PeerState = serde_json::from_str("\"unsearchable\"").unwrap();
-        assert_eq!(unsearchable, PeerState::Unsearchable);
-
-        let streaming: PeerState = serde_json::from_str("\"streaming\"").unwrap();
-        assert_eq!(streaming, PeerState::Streaming);
-    }
+/// Per-index rollup of pending fix-ups and excess copies.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClusterIndexBucketSummary {
+    pub index: String,
+    pub pending_fixups: usize,
+    pub generation_fixups: usize,
+    pub replication_factor_fixups: usize,
+    pub search_factor_fixups: usize,
+    pub excess_bucket_copies: usize,
+    pub excess_searchable_copies: usize,
+}
 
-    #[test]
-    fn peer_state_deserializes_unknown_variant() {
-        let unknown: PeerState = serde_json::from_str("\"invalid_state\"").unwrap();
-        assert_eq!(unknown, PeerState::Unknown);
-    }
+/// Bucket fix-up and excess-copy state of an indexer cluster.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClusterBucketHealth {
+    pub fixups: Vec<ClusterFixupTask>,
+    pub excess: Vec<ClusterIndexExcess>,
+}
 
-    #[test]
-    fn peer_state_serializes_to_snake_case() {
-        assert_eq!(
-            serde_json::to_string(&PeerState::Searchable).unwrap(),
-            "\"searchable\""
-        );
-        assert_eq!(
-            serde_json::to_string(&PeerState::Unsearchable).unwrap(),
-            "\"unsearchable\""
-        );
-        assert_eq!(
-            serde_json::to_string(&PeerState::Streaming).unwrap(),
-            "\"streaming\""
-        );
-    }
+impl ClusterBucketHealth {
+    /// Total number of pending fix-up tasks across all levels.
+    pub fn total_fixups(&self) -> usize {
+        self.fixups.len()
+    }
+
+    /// Number of pending fix-up tasks at a specific level.
+    pub fn fixup_count(&self, level: ClusterFixupLevel) -> usize {
+        self.fixups.iter().filter(|t| t.level == level).count()
+    }
+
+    /// Total excess bucket copies across all indexes.
+    pub fn total_excess_copies(&self) -> usize {
+        self.excess
+            .iter()
+            .map(|e| e.total_excess_bucket_copies)
+            .sum()
+    }
+
+    /// Per-index rollup, limited to indexes with pending fix-ups or excess copies.
+    pub fn index_summaries(&self) -> Vec<ClusterIndexBucketSummary> {
+        let mut by_index: std::collections::BTreeMap<&str, ClusterIndexBucketSummary> =
+            std::collections::BTreeMap::new();
+
+        for task in &self.fixups {
+            let summary =
+                by_index
+                    .entry(task.index.as_str())
+                    .or_insert_with(|| ClusterIndexBucketSummary {
+                        index: task.index.clone(),
+                        ..Default::default()
+                    });
+            summary.pending_fixups += 1;
+            match task.level {
+                ClusterFixupLevel::Generation => summary.generation_fixups += 1,
+                ClusterFixupLevel::ReplicationFactor => summary.replication_factor_fixups += 1,
+                ClusterFixupLevel::SearchFactor => summary.search_factor_fixups += 1,
+                _ => {}
+            }
+        }
 
-    // ReplicationStatus tests
-    #[test]
-    fn replication_status_default_is_unknown() {
-        assert_eq!(ReplicationStatus::default(), ReplicationStatus::Unknown);
-    }
+        for excess in self.excess.iter().filter(|e| e.has_excess()) {
+            let summary =
+                by_index
+                    .entry(excess.name.as_str())
+                    .or_insert_with(|| ClusterIndexBucketSummary {
+                        index: excess.name.clone(),
+                        ..Default::default()
+                    });
+            summary.excess_bucket_copies = excess.total_excess_bucket_copies;
+            summary.excess_searchable_copies = excess.total_excess_searchable_copies;
+        }
 
-    #[test]
-    fn replication_status_display_formats_correctly() {
-        assert_eq!(ReplicationStatus::Complete.to_string(), "Complete");
-        assert_eq!(ReplicationStatus::Pending.to_string(), "Pending");
-        assert_eq!(ReplicationStatus::Failed.to_string(), "Failed");
-        assert_eq!(ReplicationStatus::Unknown.to_string(), "Unknown");
+        by_index.into_values().collect()
     }
+}
 
-    #[test]
-    fn replication_status_deserializes_from_snake_case() {
-        let complete: ReplicationStatus = serde_json::from_str("\"complete\"").unwrap();
-        assert_eq!(complete, ReplicationStatus::Complete);
-
-        let pending: ReplicationStatus = serde_json::from_str("\"pending\"").unwrap();
-        assert_eq!(pending, ReplicationStatus::Pending);
-
-        let failed: ReplicationStatus = serde_json::from_str("\"failed\"").unwrap();
-        assert_eq!(failed, ReplicationStatus::Failed);
-    }
+/// Search head registered with the cluster manager (`cluster/master/searchheads`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClusterSearchHead {
+    #[serde(default)]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub host_port_pair: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub splunk_version: Option<String>,
+}
 
-    #[test]
-    fn replication_status_deserializes_unknown_variant() {
-        let unknown: ReplicationStatus = serde_json::from_str("\"invalid_status\"").unwrap();
-        assert_eq!(unknown, ReplicationStatus::Unknown);
-    }
+/// Role a node plays in an indexer cluster.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClusterNodeRole {
+    Manager,
+    Peer,
+    SearchHead,
+}
 
-    #[test]
-    fn replication_status_serializes_to_snake_case() {
-        assert_eq!(
-            serde_json::to_string(&ReplicationStatus::Complete).unwrap(),
-            "\"complete\""
-        );
-        assert_eq!(
-            serde_json::to_string(&ReplicationStatus::Pending).unwrap(),
-            "\"pending\""
-        );
-        assert_eq!(
-            serde_json::to_string(&ReplicationStatus::Failed).unwrap(),
-            "\"failed\""
-        );
+impl fmt::Display for ClusterNodeRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClusterNodeRole::Manager => write!(f, "Manager"),
+            ClusterNodeRole::Peer => write!(f, "Peer"),
+            ClusterNodeRole::SearchHead => write!(f, "Search Head"),
+        }
     }
+}
 
-    // Struct tests with new enum fields
-    #[test]
-    fn cluster_info_deserializes_with_enum_fields() {
-        let json = r#"{
-            "id": "cluster-1",
-            "label": "Test Cluster",
-            "mode": "manager",
-            "manager_uri": "https://manager:8089",
-            "replication_factor": 3,
-            "search_factor": 2,
-            "status": "enabled",
-            "maintenance_mode": false
-        }"#;
-
-        let info: ClusterInfo = serde_json::from_str(json).unwrap();
-        assert_eq!(info.id, "cluster-1");
-        assert_eq!(info.mode, ClusterMode::Manager);
-        assert_eq!(info.status, Some(ClusterStatus::Enabled));
-    }
+/// Splunk version of a single cluster node.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClusterNodeVersion {
+    pub role: ClusterNodeRole,
+    pub name: String,
+    /// `None` when the node did not report a version.
+    pub version: Option<String>,
+}
 
-    #[test]
-    fn cluster_info_handles_unknown_mode() {
-        let json = r#"{
-            "id": "cluster-1",
-            "mode": "unknown_mode"
-        }"#;
+/// A node whose version forms an unsupported combination with the rest of the cluster.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClusterVersionSkew {
+    pub role: ClusterNodeRole,
+    pub name: String,
+    pub version: String,
+    pub reason: String,
+}
 
-        let info: ClusterInfo = serde_json::from_str(json).unwrap();
-        assert_eq!(info.mode, ClusterMode::Unknown);
-        assert!(info.status.is_none());
-    }
+/// Splunk versions across the cluster manager, peers, and search heads.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClusterVersionReport {
+    pub nodes: Vec<ClusterNodeVersion>,
+    pub skew: Vec<ClusterVersionSkew>,
+}
 
-    #[test]
-    fn cluster_peer_deserializes_with_enum_fields() {
-        let json = r#"{
-            "id": "peer-1",
-            "label": "Peer One",
-            "status": "Up",
-            "peer_state": "searchable",
-            "site": "site1",
-            "guid": "abc123",
-            "host": "peer1.example.com",
-            "port": 8089,
-            "replication_count": 5,
-            "replication_status": "complete"
-        }"#;
-
-        let peer: ClusterPeer = serde_json::from_str(json).unwrap();
-        assert_eq!(peer.id, "peer-1");
-        assert_eq!(peer.status, PeerStatus::Up);
-        assert_eq!(peer.peer_state, PeerState::Searchable);
-        assert_eq!(peer.replication_status, Some(ReplicationStatus::Complete));
+impl ClusterVersionReport {
+    /// Build a report from node versions, flagging unsupported skew.
+    ///
+    /// Supported combinations follow Splunk's indexer cluster upgrade rules:
+    /// - the manager runs the same or a later version than every peer and search head;
+    /// - all peers run the same version, down to the maintenance release;
+    /// - search heads run the same or a later version than the peers.
+    ///
+    /// Nodes without a parseable version are listed but never flagged.
+    pub fn new(nodes: Vec<ClusterNodeVersion>) -> Self {
+        let skew = find_version_skew(&nodes);
+        Self { nodes, skew }
     }
 
-    #[test]
-    fn cluster_peer_handles_unknown_status() {
-        let json = r#"{
-            "id": "peer-1",
-            "status": "Unknown",
-            "peer_state": "unknown_state",
-            "guid": "abc123",
-            "host": "peer1.example.com",
-            "port": 8089
-        }"#;
-
-        let peer: ClusterPeer = serde_json::from_str(json).unwrap();
-        assert_eq!(peer.status, PeerStatus::Unknown);
-        assert_eq!(peer.peer_state, PeerState::Unknown);
+    /// Version reported by the cluster manager.
+    pub fn manager_version(&self) -> Option<&str> {
+        self.nodes
+            .iter()
+            .find(|n| n.role == ClusterNodeRole::Manager)
+            .and_then(|n| n.version.as_deref())
     }
 
-    #[test]
-    fn cluster_peer_handles_missing_replication_status() {
-        let json = r#"{
-            "id": "peer-1",
-            "status": "Up",
-            "peer_state": "streaming",
-            "guid": "abc123",
-            "host": "peer1.example.com",
-            "port": 8089
-        }"#;
-
-        let peer: ClusterPeer = serde_json::from_str(json).unwrap();
-        assert_eq!(peer.peer_state, PeerState::Streaming);
-        assert!(peer.replication_status.is_none());
+    /// Nodes that did not report a version.
+    pub fn unknown_versions(&self) -> impl Iterator<Item = &ClusterNodeVersion> {
+        self.nodes.iter().filter(|n| n.version.is_none())
     }
 
-    #[test]
-    fn enums_are_cloneable() {
-        let mode = ClusterMode::Manager;
-        let cloned = mode.clone();
-        assert_eq!(mode, cloned);
-
-        let status = PeerStatus::Up;
-        let cloned = status.clone();
-        assert_eq!(status, cloned);
+    /// Whether the named node has unsupported version skew.
+    pub fn is_skewed(&self, role: ClusterNodeRole, name: &str) -> bool {
+        self.skew.iter().any(|s| s.role == role && s.name == name)
     }
+}
 
-    #[test]
-    fn enums_implement_partial_eq() {
-        assert_eq!(ClusterMode::Manager, ClusterMode::Manager);
-        assert_ne!(ClusterMode::Manager, ClusterMode::Peer);
+/// Parse `major.minor.maintenance` from a Splunk version string such as `9.1.2` or `9.2.0.1`.
+fn parse_splunk_version(version: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = version.trim().split('.').map(|part| {
+        let digits: String = part.chars().take_while(char::is_ascii_digit).collect();
+        digits.parse::<u32>().ok()
+    });
+    let major = parts.next()??;
+    let minor = parts.next().flatten().unwrap_or(0);
+    let maintenance = parts.next().flatten().unwrap_or(0);
+    Some((major, minor, maintenance))
+}
 
-        assert_eq!(PeerStatus::Up, PeerStatus::Up);
-        assert_ne!(PeerStatus::Up, PeerStatus::Down);
+fn find_version_skew(nodes: &[ClusterNodeVersion]) -> Vec<ClusterVersionSkew> {
+    let parsed: Vec<(&ClusterNodeVersion, &str, (u32, u32, u32))> = nodes
+        .iter()
+        .filter_map(|node| {
+            let version = node.version.as_deref()?;
+            Some((node, version, parse_splunk_version(version)?))
+        })
+        .collect();
+    let of_role = |role: ClusterNodeRole| parsed.iter().filter(move |(n, _, _)| n.role == role);
+
+    let manager = of_role(ClusterNodeRole::Manager).next();
+
+    // The version most peers run is the baseline the others should match.
+    let mut peer_counts: std::collections::BTreeMap<(u32, u32, u32), (usize, &str)> =
+        std::collections::BTreeMap::new();
+    for (_, version, parsed) in of_role(ClusterNodeRole::Peer) {
+        peer_counts.entry(*parsed).or_insert((0, version)).0 += 1;
+    }
+    let peer_baseline = peer_counts
+        .iter()
+        .max_by_key(|(parsed, (count, _))| (*count, *parsed))
+        .map(|(parsed, (_, version))| (*parsed, *version));
+    let newest_peer = peer_counts
+        .iter()
+        .next_back()
+        .map(|(parsed, (_, version))| (*parsed, *version));
+
+    let mut skew = Vec::new();
+    for (node, version, parsed) in &parsed {
+        let reason = match node.role {
+            ClusterNodeRole::Manager => None,
+            _ if manager.is_some_and(|(_, _, m)| parsed > m) => Some(format!(
+                "runs a newer version than the manager ({})",
+                manager.map(|(_, v, _)| *v).unwrap_or_default()
+            )),
+            ClusterNodeRole::Peer => peer_baseline
+                .filter(|(baseline, _)| parsed != baseline)
+                .map(|(_, v)| format!("peers must run the same version; most peers run {}", v)),
+            ClusterNodeRole::SearchHead => newest_peer
+                .filter(|(peer, _)| parsed < peer)
+                .map(|(_, v)| format!("runs an older version than peers ({})", v)),
+        };
+        if let Some(reason) = reason {
+            skew.push(ClusterVersionSkew {
+                role: node.role,
+                name: node.name.clone(),
+                version: version.to_string(),
+                reason,
+            });
+        }
     }
+    skew
 }
+
+#[cfg(test)]
+#[path = "cluster_tests.rs"]
+mod tests;
@@ -0,0 +1,502 @@
+//! Unit tests for cluster model enums, serialization, and derived summaries.
+
+use super::*;
+
+// ClusterMode tests
+#[test]
+fn cluster_mode_default_is_unknown() {
+    assert_eq!(ClusterMode::default(), ClusterMode::Unknown);
+}
+
+#[test]
+fn cluster_mode_display_formats_correctly() {
+    assert_eq!(ClusterMode::Manager.to_string(), "Manager");
+    assert_eq!(ClusterMode::Peer.to_string(), "Peer");
+    assert_eq!(ClusterMode::Unknown.to_string(), "Unknown");
+}
+
+#[test]
+fn cluster_mode_deserializes_from_snake_case() {
+    let manager: ClusterMode = serde_json::from_str("\"manager\"").unwrap();
+    assert_eq!(manager, ClusterMode::Manager);
+
+    let peer: ClusterMode = serde_json::from_str("\"peer\"").unwrap();
+    assert_eq!(peer, ClusterMode::Peer);
+}
+
+#[test]
+fn cluster_mode_deserializes_unknown_variant() {
+    let unknown: ClusterMode = serde_json::from_str("\"invalid_mode\"").unwrap();
+    assert_eq!(unknown, ClusterMode::Unknown);
+}
+
+#[test]
+fn cluster_mode_serializes_to_snake_case() {
+    assert_eq!(
+        serde_json::to_string(&ClusterMode::Manager).unwrap(),
+        "\"manager\""
+    );
+    assert_eq!(
+        serde_json::to_string(&ClusterMode::Peer).unwrap(),
+        "\"peer\""
+    );
+}
+
+// ClusterStatus tests
+#[test]
+fn cluster_status_default_is_unknown() {
+    assert_eq!(ClusterStatus::default(), ClusterStatus::Unknown);
+}
+
+#[test]
+fn cluster_status_display_formats_correctly() {
+    assert_eq!(ClusterStatus::Enabled.to_string(), "Enabled");
+    assert_eq!(ClusterStatus::Disabled.to_string(), "Disabled");
+    assert_eq!(ClusterStatus::Unknown.to_string(), "Unknown");
+}
+
+#[test]
+fn cluster_status_deserializes_from_snake_case() {
+    let enabled: ClusterStatus = serde_json::from_str("\"enabled\"").unwrap();
+    assert_eq!(enabled, ClusterStatus::Enabled);
+
+    let disabled: ClusterStatus = serde_json::from_str("\"disabled\"").unwrap();
+    assert_eq!(disabled, ClusterStatus::Disabled);
+}
+
+#[test]
+fn cluster_status_deserializes_unknown_variant() {
+    let unknown: ClusterStatus = serde_json::from_str("\"invalid_status\"").unwrap();
+    assert_eq!(unknown, ClusterStatus::Unknown);
+}
+
+#[test]
+fn cluster_status_serializes_to_snake_case() {
+    assert_eq!(
+        serde_json::to_string(&ClusterStatus::Enabled).unwrap(),
+        "\"enabled\""
+    );
+    assert_eq!(
+        serde_json::to_string(&ClusterStatus::Disabled).unwrap(),
+        "\"disabled\""
+    );
+}
+
+// PeerStatus tests
+#[test]
+fn peer_status_default_is_unknown() {
+    assert_eq!(PeerStatus::default(), PeerStatus::Unknown);
+}
+
+#[test]
+fn peer_status_display_formats_correctly() {
+    assert_eq!(PeerStatus::Up.to_string(), "Up");
+    assert_eq!(PeerStatus::Down.to_string(), "Down");
+    assert_eq!(PeerStatus::Unknown.to_string(), "Unknown");
+}
+
+#[test]
+fn peer_status_deserializes_from_explicit_rename() {
+    let up: PeerStatus = serde_json::from_str("\"Up\"").unwrap();
+    assert_eq!(up, PeerStatus::Up);
+
+    let down: PeerStatus = serde_json::from_str("\"Down\"").unwrap();
+    assert_eq!(down, PeerStatus::Down);
+}
+
+#[test]
+fn peer_status_deserializes_unknown_variant() {
+    let unknown: PeerStatus = serde_json::from_str("\"invalid_status\"").unwrap();
+    assert_eq!(unknown, PeerStatus::Unknown);
+
+    let unknown_lowercase: PeerStatus = serde_json::from_str("\"up\"").unwrap();
+    assert_eq!(unknown_lowercase, PeerStatus::Unknown);
+}
+
+#[test]
+fn peer_status_serializes_with_explicit_rename() {
+    assert_eq!(serde_json::to_string(&PeerStatus::Up).unwrap(), "\"Up\"");
+    assert_eq!(
+        serde_json::to_string(&PeerStatus::Down).unwrap(),
+        "\"Down\""
+    );
+}
+
+// PeerState tests
+#[test]
+fn peer_state_default_is_unknown() {
+    assert_eq!(PeerState::default(), PeerState::Unknown);
+}
+
+#[test]
+fn peer_state_display_formats_correctly() {
+    assert_eq!(PeerState::Searchable.to_string(), "Searchable");
+    assert_eq!(PeerState::Unsearchable.to_string(), "Unsearchable");
+    assert_eq!(PeerState::Streaming.to_string(), "Streaming");
+    assert_eq!(PeerState::Unknown.to_string(), "Unknown");
+}
+
+#[test]
+fn peer_state_deserializes_from_snake_case() {
+    let searchable: PeerState = serde_json::from_str("\"searchable\"").unwrap();
+    assert_eq!(searchable, PeerState::Searchable);
+
+    let unsearchable: PeerState = serde_json::from_str("\"unsearchable\"").unwrap();
+    assert_eq!(unsearchable, PeerState::Unsearchable);
+
+    let streaming: PeerState = serde_json::from_str("\"streaming\"").unwrap();
+    assert_eq!(streaming, PeerState::Streaming);
+}
+
+#[test]
+fn peer_state_deserializes_unknown_variant() {
+    let unknown: PeerState = serde_json::from_str("\"invalid_state\"").unwrap();
+    assert_eq!(unknown, PeerState::Unknown);
+}
+
+#[test]
+fn peer_state_serializes_to_snake_case() {
+    assert_eq!(
+        serde_json::to_string(&PeerState::Searchable).unwrap(),
+        "\"searchable\""
+    );
+    assert_eq!(
+        serde_json::to_string(&PeerState::Unsearchable).unwrap(),
+        "\"unsearchable\""
+    );
+    assert_eq!(
+        serde_json::to_string(&PeerState::Streaming).unwrap(),
+        "\"streaming\""
+    );
+}
+
+// ReplicationStatus tests
+#[test]
+fn replication_status_default_is_unknown() {
+    assert_eq!(ReplicationStatus::default(), ReplicationStatus::Unknown);
+}
+
+#[test]
+fn replication_status_display_formats_correctly() {
+    assert_eq!(ReplicationStatus::Complete.to_string(), "Complete");
+    assert_eq!(ReplicationStatus::Pending.to_string(), "Pending");
+    assert_eq!(ReplicationStatus::Failed.to_string(), "Failed");
+    assert_eq!(ReplicationStatus::Unknown.to_string(), "Unknown");
+}
+
+#[test]
+fn replication_status_deserializes_from_snake_case() {
+    let complete: ReplicationStatus = serde_json::from_str("\"complete\"").unwrap();
+    assert_eq!(complete, ReplicationStatus::Complete);
+
+    let pending: ReplicationStatus = serde_json::from_str("\"pending\"").unwrap();
+    assert_eq!(pending, ReplicationStatus::Pending);
+
+    let failed: ReplicationStatus = serde_json::from_str("\"failed\"").unwrap();
+    assert_eq!(failed, ReplicationStatus::Failed);
+}
+
+#[test]
+fn replication_status_deserializes_unknown_variant() {
+    let unknown: ReplicationStatus = serde_json::from_str("\"invalid_status\"").unwrap();
+    assert_eq!(unknown, ReplicationStatus::Unknown);
+}
+
+#[test]
+fn replication_status_serializes_to_snake_case() {
+    assert_eq!(
+        serde_json::to_string(&ReplicationStatus::Complete).unwrap(),
+        "\"complete\""
+    );
+    assert_eq!(
+        serde_json::to_string(&ReplicationStatus::Pending).unwrap(),
+        "\"pending\""
+    );
+    assert_eq!(
+        serde_json::to_string(&ReplicationStatus::Failed).unwrap(),
+        "\"failed\""
+    );
+}
+
+// Struct tests with new enum fields
+#[test]
+fn cluster_info_deserializes_with_enum_fields() {
+    let json = r#"{
+        "id": "cluster-1",
+        "label": "Test Cluster",
+        "mode": "manager",
+        "manager_uri": "https://manager:8089",
+        "replication_factor": 3,
+        "search_factor": 2,
+        "status": "enabled",
+        "maintenance_mode": false
+    }"#;
+
+    let info: ClusterInfo = serde_json::from_str(json).unwrap();
+    assert_eq!(info.id, "cluster-1");
+    assert_eq!(info.mode, ClusterMode::Manager);
+    assert_eq!(info.status, Some(ClusterStatus::Enabled));
+}
+
+#[test]
+fn cluster_info_handles_unknown_mode() {
+    let json = r#"{
+        "id": "cluster-1",
+        "mode": "unknown_mode"
+    }"#;
+
+    let info: ClusterInfo = serde_json::from_str(json).unwrap();
+    assert_eq!(info.mode, ClusterMode::Unknown);
+    assert!(info.status.is_none());
+}
+
+#[test]
+fn cluster_peer_deserializes_with_enum_fields() {
+    let json = r#"{
+        "id": "peer-1",
+        "label": "Peer One",
+        "status": "Up",
+        "peer_state": "searchable",
+        "site": "site1",
+        "guid": "abc123",
+        "host": "peer1.example.com",
+        "port": 8089,
+        "replication_count": 5,
+        "replication_status": "complete"
+    }"#;
+
+    let peer: ClusterPeer = serde_json::from_str(json).unwrap();
+    assert_eq!(peer.id, "peer-1");
+    assert_eq!(peer.status, PeerStatus::Up);
+    assert_eq!(peer.peer_state, PeerState::Searchable);
+    assert_eq!(peer.replication_status, Some(ReplicationStatus::Complete));
+}
+
+#[test]
+fn cluster_peer_handles_unknown_status() {
+    let json = r#"{
+        "id": "peer-1",
+        "status": "Unknown",
+        "peer_state": "unknown_state",
+        "guid": "abc123",
+        "host": "peer1.example.com",
+        "port": 8089
+    }"#;
+
+    let peer: ClusterPeer = serde_json::from_str(json).unwrap();
+    assert_eq!(peer.status, PeerStatus::Unknown);
+    assert_eq!(peer.peer_state, PeerState::Unknown);
+}
+
+#[test]
+fn cluster_peer_handles_missing_replication_status() {
+    let json = r#"{
+        "id": "peer-1",
+        "status": "Up",
+        "peer_state": "streaming",
+        "guid": "abc123",
+        "host": "peer1.example.com",
+        "port": 8089
+    }"#;
+
+    let peer: ClusterPeer = serde_json::from_str(json).unwrap();
+    assert_eq!(peer.peer_state, PeerState::Streaming);
+    assert!(peer.replication_status.is_none());
+}
+
+#[test]
+fn enums_are_cloneable() {
+    let mode = ClusterMode::Manager;
+    let cloned = mode.clone();
+    assert_eq!(mode, cloned);
+
+    let status = PeerStatus::Up;
+    let cloned = status.clone();
+    assert_eq!(status, cloned);
+}
+
+#[test]
+fn enums_implement_partial_eq() {
+    assert_eq!(ClusterMode::Manager, ClusterMode::Manager);
+    assert_ne!(ClusterMode::Manager, ClusterMode::Peer);
+
+    assert_eq!(PeerStatus::Up, PeerStatus::Up);
+    assert_ne!(PeerStatus::Up, PeerStatus::Down);
+}
+
+// Bucket health tests
+fn fixup(index: &str, level: ClusterFixupLevel) -> ClusterFixupTask {
+    ClusterFixupTask {
+        bucket_id: format!("{}~1~GUID", index),
+        index: index.to_string(),
+        level,
+        reason: None,
+        timestamp: None,
+    }
+}
+
+#[test]
+fn cluster_fixup_level_round_trips_through_str() {
+    for level in ClusterFixupLevel::ALL {
+        assert_eq!(level.as_str().parse::<ClusterFixupLevel>(), Ok(level));
+    }
+    assert!("bogus".parse::<ClusterFixupLevel>().is_err());
+}
+
+#[test]
+fn cluster_index_excess_deserializes_string_counts() {
+    let excess: ClusterIndexExcess = serde_json::from_str(
+        r#"{"total_excess_bucket_copies": "3", "total_excess_searchable_copies": 1}"#,
+    )
+    .unwrap();
+    assert_eq!(excess.total_excess_bucket_copies, 3);
+    assert_eq!(excess.total_excess_searchable_copies, 1);
+    assert!(excess.has_excess());
+}
+
+#[test]
+fn cluster_bucket_health_rolls_up_per_index() {
+    let health = ClusterBucketHealth {
+        fixups: vec![
+            fixup("main", ClusterFixupLevel::ReplicationFactor),
+            fixup("main", ClusterFixupLevel::SearchFactor),
+            fixup("web", ClusterFixupLevel::Generation),
+        ],
+        excess: vec![
+            ClusterIndexExcess {
+                name: "web".to_string(),
+                total_excess_bucket_copies: 4,
+                ..Default::default()
+            },
+            ClusterIndexExcess {
+                name: "idle".to_string(),
+                ..Default::default()
+            },
+        ],
+    };
+
+    assert_eq!(health.total_fixups(), 3);
+    assert_eq!(health.fixup_count(ClusterFixupLevel::SearchFactor), 1);
+    assert_eq!(health.total_excess_copies(), 4);
+
+    let summaries = health.index_summaries();
+    assert_eq!(summaries.len(), 2);
+    assert_eq!(summaries[0].index, "main");
+    assert_eq!(summaries[0].pending_fixups, 2);
+    assert_eq!(summaries[0].replication_factor_fixups, 1);
+    assert_eq!(summaries[1].index, "web");
+    assert_eq!(summaries[1].generation_fixups, 1);
+    assert_eq!(summaries[1].excess_bucket_copies, 4);
+}
+
+fn node(role: ClusterNodeRole, name: &str, version: Option<&str>) -> ClusterNodeVersion {
+    ClusterNodeVersion {
+        role,
+        name: name.to_string(),
+        version: version.map(str::to_string),
+    }
+}
+
+#[test]
+fn parse_splunk_version_handles_short_and_suffixed_versions() {
+    assert_eq!(parse_splunk_version("9.1.2"), Some((9, 1, 2)));
+    assert_eq!(parse_splunk_version("9.2"), Some((9, 2, 0)));
+    assert_eq!(parse_splunk_version("9.0.4.1"), Some((9, 0, 4)));
+    assert_eq!(parse_splunk_version("10.0.0-beta"), Some((10, 0, 0)));
+    assert_eq!(parse_splunk_version("unknown"), None);
+}
+
+#[test]
+fn cluster_version_report_accepts_supported_upgrade_order() {
+    let report = ClusterVersionReport::new(vec![
+        node(ClusterNodeRole::Manager, "cm", Some("9.2.1")),
+        node(ClusterNodeRole::SearchHead, "sh1", Some("9.2.1")),
+        node(ClusterNodeRole::Peer, "idx1", Some("9.1.3")),
+        node(ClusterNodeRole::Peer, "idx2", Some("9.1.3")),
+        node(ClusterNodeRole::Peer, "idx3", None),
+    ]);
+
+    assert!(report.skew.is_empty());
+    assert_eq!(report.manager_version(), Some("9.2.1"));
+    assert_eq!(report.unknown_versions().count(), 1);
+}
+
+#[test]
+fn cluster_version_report_flags_unsupported_skew() {
+    let report = ClusterVersionReport::new(vec![
+        node(ClusterNodeRole::Manager, "cm", Some("9.1.3")),
+        node(ClusterNodeRole::Peer, "idx1", Some("9.1.3")),
+        node(ClusterNodeRole::Peer, "idx2", Some("9.1.3")),
+        node(ClusterNodeRole::Peer, "idx3", Some("9.1.2")),
+        node(ClusterNodeRole::Peer, "idx4", Some("9.2.0")),
+        node(ClusterNodeRole::SearchHead, "sh1", Some("9.0.8")),
+    ]);
+
+    let flagged: Vec<&str> = report.skew.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(flagged, vec!["idx3", "idx4", "sh1"]);
+    assert!(report.skew[0].reason.contains("most peers run 9.1.3"));
+    assert!(
+        report.skew[1]
+            .reason
+            .contains("newer version than the manager")
+    );
+    assert!(
+        report.skew[2]
+            .reason
+            .contains("older version than peers (9.2.0)")
+    );
+    assert!(report.is_skewed(ClusterNodeRole::Peer, "idx4"));
+    assert!(!report.is_skewed(ClusterNodeRole::Peer, "idx1"));
+}
+
+fn slots(pairs: &[(usize, usize)]) -> Vec<ClusterCopySlot> {
+    pairs
+        .iter()
+        .map(|&(actual, expected)| ClusterCopySlot {
+            actual_copies_per_slot: actual,
+            expected_total_per_slot: expected,
+        })
+        .collect()
+}
+
+#[test]
+fn cluster_index_compliance_counts_met_copies() {
+    let copies = ClusterIndexCopies {
+        name: "main".to_string(),
+        num_buckets: 10,
+        is_searchable: true,
+        replicated_copies_tracker: slots(&[(10, 10), (10, 10), (7, 10)]),
+        searchable_copies_tracker: slots(&[(10, 10), (10, 10)]),
+    };
+
+    let compliance = ClusterIndexCompliance::new(&copies, 3, 2);
+    assert_eq!(compliance.replicated_copies, 2);
+    assert_eq!(compliance.buckets_below_rf, 3);
+    assert_eq!(compliance.searchable_copies, 2);
+    assert_eq!(compliance.buckets_below_sf, 0);
+    assert!(!compliance.meets_replication_factor());
+    assert!(compliance.meets_search_factor());
+    assert!(!compliance.compliant);
+}
+
+#[test]
+fn cluster_index_compliance_treats_missing_slots_as_unmet() {
+    let copies = ClusterIndexCopies {
+        name: "web".to_string(),
+        num_buckets: 4,
+        replicated_copies_tracker: slots(&[(4, 4)]),
+        ..Default::default()
+    };
+
+    let compliance = ClusterIndexCompliance::new(&copies, 2, 1);
+    assert_eq!(compliance.replicated_copies, 1);
+    assert_eq!(compliance.buckets_below_rf, 4);
+    assert_eq!(compliance.searchable_copies, 0);
+    assert_eq!(compliance.buckets_below_sf, 4);
+
+    let empty = ClusterIndexCopies {
+        name: "empty".to_string(),
+        ..Default::default()
+    };
+    assert!(ClusterIndexCompliance::new(&empty, 3, 2).compliant);
+}
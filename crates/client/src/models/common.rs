@@ -50,6 +50,10 @@ pub struct Acl {
     pub app: String,
     pub owner: String,
     pub perms: Option<Perms>,
+    #[serde(default)]
+    pub sharing: Option<String>,
+    #[serde(default)]
+    pub can_change_perms: bool,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -58,6 +62,55 @@ pub struct Perms {
     pub write: Vec<String>,
 }
 
+/// Owner/app/sharing context for knowledge-object endpoints.
+///
+/// Requests made with a namespace go to `/servicesNS/{owner}/{app}/...`; an
+/// unset owner or app becomes the `-` wildcard. The owner also decides whose
+/// private objects are visible.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Namespace {
+    /// Object owner, or any owner when `None`.
+    #[serde(default)]
+    pub owner: Option<String>,
+    /// App context, or any app when `None`.
+    #[serde(default)]
+    pub app: Option<String>,
+    /// Sharing level (`user`, `app` or `global`) that listings are limited to, or any when `None`.
+    #[serde(default)]
+    pub sharing: Option<String>,
+}
+
+impl Namespace {
+    /// Namespace for every owner's objects in `app`.
+    pub fn app(app: impl Into<String>) -> Self {
+        Self {
+            app: Some(app.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Whether no part of the namespace is set.
+    pub fn is_empty(&self) -> bool {
+        self.owner.is_none() && self.app.is_none() && self.sharing.is_none()
+    }
+}
+
+impl std::fmt::Display for Namespace {
+    /// Formats as `owner/app`, with `-` for unset parts, plus ` (sharing)` when set.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}/{}",
+            self.owner.as_deref().unwrap_or("-"),
+            self.app.as_deref().unwrap_or("-")
+        )?;
+        if let Some(sharing) = &self.sharing {
+            write!(f, " ({})", sharing)?;
+        }
+        Ok(())
+    }
+}
+
 /// A single message from Splunk (usually in error responses).
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SplunkMessage {
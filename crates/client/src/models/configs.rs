@@ -13,9 +13,11 @@
 //! Splunk configs API endpoints:
 //! - /services/configs/conf-{config_file} - List stanzas for a config file
 //! - /services/configs/conf-{config_file}/{stanza_name} - Get a specific stanza
+//! - /servicesNS/-/-/configs/conf-{config_file}[/{stanza_name}] - Per-context stanza layers
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
 
 /// Represents a configuration stanza (a section within a .conf file).
 ///
@@ -66,6 +68,60 @@ pub struct ConfigFile {
     pub description: Option<String>,
 }
 
+/// Configuration context a stanza layer was defined in.
+///
+/// Variants are ordered from lowest to highest precedence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigScope {
+    /// `$SPLUNK_HOME/etc/system`.
+    System,
+    /// An app's `default` and `local` directories.
+    App,
+    /// A user's private copy under `etc/users`.
+    User,
+}
+
+impl fmt::Display for ConfigScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::System => write!(f, "system"),
+            Self::App => write!(f, "app"),
+            Self::User => write!(f, "user"),
+        }
+    }
+}
+
+/// One context's copy of a configuration stanza.
+///
+/// Returned by the namespace-wildcard configs endpoint, which lists a stanza
+/// once per app or user context that defines it. Splunk already merges an
+/// app's `default` and `local` directories into a single layer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigLayer {
+    /// The stanza name.
+    pub stanza: String,
+    /// The context this layer was read from.
+    pub scope: ConfigScope,
+    /// The app owning the layer (`system` for system-level settings).
+    pub app: String,
+    /// The owning user (`nobody` for app and system layers).
+    pub owner: String,
+    /// Settings defined in this layer, without `eai:*` metadata.
+    pub settings: BTreeMap<String, serde_json::Value>,
+}
+
+impl ConfigLayer {
+    /// Human-readable origin of the layer, e.g. `app:search` or `user:admin@search`.
+    pub fn source(&self) -> String {
+        match self.scope {
+            ConfigScope::System => "system".to_string(),
+            ConfigScope::App => format!("app:{}", self.app),
+            ConfigScope::User => format!("user:{}@{}", self.owner, self.app),
+        }
+    }
+}
+
 /// Supported configuration file types.
 ///
 /// This is a curated list of commonly accessed Splunk configuration files.
@@ -154,6 +210,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_config_layer_source() {
+        let mut layer = ConfigLayer {
+            stanza: "syslog".to_string(),
+            scope: ConfigScope::System,
+            app: "system".to_string(),
+            owner: "nobody".to_string(),
+            settings: BTreeMap::new(),
+        };
+        assert_eq!(layer.source(), "system");
+
+        layer.scope = ConfigScope::App;
+        layer.app = "search".to_string();
+        assert_eq!(layer.source(), "app:search");
+
+        layer.scope = ConfigScope::User;
+        layer.owner = "admin".to_string();
+        assert_eq!(layer.source(), "user:admin@search");
+    }
+
     #[test]
     fn test_supported_config_files_list() {
         assert!(SUPPORTED_CONFIG_FILES.contains(&"props"));
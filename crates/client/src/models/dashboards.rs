@@ -1,6 +1,8 @@
 //! Dashboard models for Splunk dashboard API.
 //!
-//! This module contains types for listing and viewing Splunk dashboards.
+//! This module contains types for listing and viewing Splunk dashboards,
+//! plus helpers to detect and pretty-print a dashboard's source definition
+//! (Simple XML, or Dashboard Studio JSON embedded in `<definition>`).
 
 use serde::{Deserialize, Serialize};
 
@@ -40,3 +42,298 @@ pub struct DashboardEntry {
     pub name: String,
     pub content: Dashboard,
 }
+
+/// Source format of a dashboard definition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DashboardFormat {
+    /// Classic Simple XML (`<dashboard>` or `<form>`).
+    SimpleXml,
+    /// Dashboard Studio (`version="2"` with a JSON `<definition>`).
+    Studio,
+}
+
+impl DashboardFormat {
+    /// Human-readable name.
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::SimpleXml => "Simple XML",
+            Self::Studio => "Dashboard Studio",
+        }
+    }
+}
+
+impl Dashboard {
+    /// Detect the source format, or `None` when the definition was not fetched.
+    pub fn source_format(&self) -> Option<DashboardFormat> {
+        let source = self.xml_data.as_deref()?.trim_start();
+        if source.starts_with('{') {
+            return Some(DashboardFormat::Studio);
+        }
+        let root = root_start_tag(source).unwrap_or_default();
+        let is_v2 = ["version=\"2\"", "version='2'"]
+            .iter()
+            .any(|attr| root.contains(attr));
+        Some(if is_v2 {
+            DashboardFormat::Studio
+        } else {
+            DashboardFormat::SimpleXml
+        })
+    }
+
+    /// Indented source definition, with an embedded Studio JSON definition
+    /// pretty-printed in place. Falls back to the original text when the XML
+    /// cannot be tokenized.
+    pub fn pretty_source(&self) -> Option<String> {
+        let source = self.xml_data.as_deref()?;
+        if source.trim_start().starts_with('{') {
+            return Some(pretty_json_or_original(source));
+        }
+        Some(pretty_xml(source).unwrap_or_else(|| source.to_string()))
+    }
+
+    /// Dashboard Studio JSON definition, pretty-printed.
+    ///
+    /// Returns `None` for Simple XML dashboards or when no JSON definition is present.
+    pub fn studio_definition(&self) -> Option<String> {
+        let source = self.xml_data.as_deref()?;
+        let trimmed = source.trim_start();
+        let json = if trimmed.starts_with('{') {
+            trimmed
+        } else {
+            let start = source.find("<definition")?;
+            let body = &source[start..];
+            let open = body.find("<![CDATA[")? + "<![CDATA[".len();
+            let close = body[open..].find("]]>")? + open;
+            &body[open..close]
+        };
+        let value: serde_json::Value = serde_json::from_str(json.trim()).ok()?;
+        serde_json::to_string_pretty(&value).ok()
+    }
+}
+
+/// First element start tag (skipping the prolog, comments, and doctype).
+fn root_start_tag(source: &str) -> Option<&str> {
+    tokenize_xml(source)?
+        .into_iter()
+        .find_map(|token| match token {
+            XmlToken::Open(tag) | XmlToken::SelfClosing(tag) => Some(tag),
+            _ => None,
+        })
+}
+
+fn pretty_json_or_original(source: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(source)
+        .ok()
+        .and_then(|value| serde_json::to_string_pretty(&value).ok())
+        .unwrap_or_else(|| source.to_string())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum XmlToken<'a> {
+    Open(&'a str),
+    Close(&'a str),
+    SelfClosing(&'a str),
+    CData(&'a str),
+    /// Comments, processing instructions, and doctype declarations.
+    Other(&'a str),
+    Text(&'a str),
+}
+
+/// Split XML into tags, CDATA sections, and text. `None` on an unterminated construct.
+fn tokenize_xml(source: &str) -> Option<Vec<XmlToken<'_>>> {
+    let mut tokens = Vec::new();
+    let mut rest = source;
+    while !rest.is_empty() {
+        let Some(lt) = rest.find('<') else {
+            tokens.push(XmlToken::Text(rest));
+            break;
+        };
+        if lt > 0 {
+            tokens.push(XmlToken::Text(&rest[..lt]));
+            rest = &rest[lt..];
+        }
+
+        let (token, len) = if rest.starts_with("<![CDATA[") {
+            let end = rest.find("]]>")? + 3;
+            (XmlToken::CData(&rest[..end]), end)
+        } else if rest.starts_with("<!--") {
+            let end = rest.find("-->")? + 3;
+            (XmlToken::Other(&rest[..end]), end)
+        } else {
+            let end = tag_end(rest)? + 1;
+            let tag = &rest[..end];
+            let token = if tag.starts_with("</") {
+                XmlToken::Close(tag)
+            } else if tag.starts_with("<?") || tag.starts_with("<!") {
+                XmlToken::Other(tag)
+            } else if tag.ends_with("/>") {
+                XmlToken::SelfClosing(tag)
+            } else {
+                XmlToken::Open(tag)
+            };
+            (token, end)
+        };
+        tokens.push(token);
+        rest = &rest[len..];
+    }
+    Some(tokens)
+}
+
+/// Index of the `>` closing the tag at the start of `tag`, ignoring quoted attribute values.
+fn tag_end(tag: &str) -> Option<usize> {
+    let mut quote: Option<char> = None;
+    for (idx, ch) in tag.char_indices() {
+        match (quote, ch) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, '>') => return Some(idx),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Re-indent XML two spaces per level. Elements holding only a single line of
+/// text stay on one line; CDATA holding JSON is pretty-printed.
+fn pretty_xml(source: &str) -> Option<String> {
+    let tokens: Vec<XmlToken<'_>> = tokenize_xml(source)?
+        .into_iter()
+        .filter(|token| !matches!(token, XmlToken::Text(text) if text.trim().is_empty()))
+        .collect();
+
+    let mut out = String::with_capacity(source.len());
+    let mut depth = 0usize;
+    let mut i = 0;
+    let push_line = |out: &mut String, depth: usize, line: &str| {
+        out.push_str(&"  ".repeat(depth));
+        out.push_str(line);
+        out.push('\n');
+    };
+
+    while i < tokens.len() {
+        match tokens[i] {
+            XmlToken::Open(open) => match (tokens.get(i + 1), tokens.get(i + 2)) {
+                (Some(XmlToken::Close(close)), _) => {
+                    push_line(&mut out, depth, &format!("{open}{close}"));
+                    i += 2;
+                    continue;
+                }
+                (Some(XmlToken::Text(text)), Some(XmlToken::Close(close)))
+                    if !text.trim().contains('\n') =>
+                {
+                    push_line(&mut out, depth, &format!("{open}{}{close}", text.trim()));
+                    i += 3;
+                    continue;
+                }
+                _ => {
+                    push_line(&mut out, depth, open);
+                    depth += 1;
+                }
+            },
+            XmlToken::Close(close) => {
+                depth = depth.saturating_sub(1);
+                push_line(&mut out, depth, close);
+            }
+            XmlToken::SelfClosing(tag) | XmlToken::Other(tag) => push_line(&mut out, depth, tag),
+            XmlToken::Text(text) => {
+                for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+                    push_line(&mut out, depth, line);
+                }
+            }
+            XmlToken::CData(cdata) => {
+                let inner = &cdata["<![CDATA[".len()..cdata.len() - 3];
+                match serde_json::from_str::<serde_json::Value>(inner.trim()) {
+                    Ok(value @ (serde_json::Value::Object(_) | serde_json::Value::Array(_))) => {
+                        push_line(&mut out, depth, "<![CDATA[");
+                        let pretty = serde_json::to_string_pretty(&value).ok()?;
+                        for line in pretty.lines() {
+                            push_line(&mut out, depth + 1, line);
+                        }
+                        push_line(&mut out, depth, "]]>");
+                    }
+                    _ => push_line(&mut out, depth, cdata),
+                }
+            }
+        }
+        i += 1;
+    }
+
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dashboard(source: &str) -> Dashboard {
+        Dashboard {
+            name: "ops".to_string(),
+            label: "Ops".to_string(),
+            description: None,
+            author: "admin".to_string(),
+            is_dashboard: true,
+            is_visible: true,
+            version: None,
+            xml_data: Some(source.to_string()),
+            updated: None,
+        }
+    }
+
+    #[test]
+    fn test_pretty_source_indents_simple_xml() {
+        let d = dashboard(
+            r#"<?xml version="1.0"?><dashboard><label>Ops</label><row><panel><title a=">">Errors</title><single/></panel></row></dashboard>"#,
+        );
+
+        assert_eq!(d.source_format(), Some(DashboardFormat::SimpleXml));
+        assert_eq!(
+            d.pretty_source().unwrap(),
+            concat!(
+                "<?xml version=\"1.0\"?>\n",
+                "<dashboard>\n",
+                "  <label>Ops</label>\n",
+                "  <row>\n",
+                "    <panel>\n",
+                "      <title a=\">\">Errors</title>\n",
+                "      <single/>\n",
+                "    </panel>\n",
+                "  </row>\n",
+                "</dashboard>\n",
+            )
+        );
+        assert!(d.studio_definition().is_none());
+    }
+
+    #[test]
+    fn test_studio_definition_is_extracted_and_pretty_printed() {
+        let d = dashboard(
+            r#"<dashboard version="2" theme="dark"><label>Ops</label><definition><![CDATA[{"title":"Ops","visualizations":{}}]]></definition></dashboard>"#,
+        );
+
+        assert_eq!(d.source_format(), Some(DashboardFormat::Studio));
+        let definition = d.studio_definition().unwrap();
+        assert!(definition.starts_with("{\n  \"title\": \"Ops\""));
+
+        let pretty = d.pretty_source().unwrap();
+        assert!(
+            pretty.contains("  <definition>\n    <![CDATA[\n      {\n        \"title\": \"Ops\"")
+        );
+    }
+
+    #[test]
+    fn test_pretty_source_falls_back_on_malformed_xml() {
+        let d = dashboard(r#"<dashboard><label a="x>Broken"#);
+        assert_eq!(
+            d.pretty_source().unwrap(),
+            r#"<dashboard><label a="x>Broken"#
+        );
+
+        let missing = Dashboard {
+            xml_data: None,
+            ..dashboard("")
+        };
+        assert_eq!(missing.source_format(), None);
+    }
+}
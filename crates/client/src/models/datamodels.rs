@@ -39,3 +39,454 @@ pub struct DataModelEntry {
     pub name: String,
     pub content: DataModel,
 }
+
+/// Fields inherited from the base event, which `tstats` references unqualified.
+const BASE_FIELDS: &[&str] = &["_time", "host", "source", "sourcetype"];
+
+/// Aggregate functions offered by the tstats builder.
+pub const TSTATS_FUNCTIONS: &[&str] = &[
+    "count", "dc", "sum", "avg", "min", "max", "values", "earliest", "latest", "median", "stdev",
+    "range",
+];
+
+/// A dataset (object) of a data model, as `tstats` addresses it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DataModelObject {
+    /// Object name, e.g. `Blocked_Traffic`.
+    pub name: String,
+    /// Dotted path from the root object, e.g. `All_Traffic.Blocked_Traffic`.
+    pub lineage: String,
+    /// Field names as `tstats` references them, e.g. `All_Traffic.src` or `host`.
+    pub fields: Vec<String>,
+}
+
+impl DataModelObject {
+    /// Name of the root object this dataset belongs to.
+    pub fn root(&self) -> &str {
+        self.lineage.split('.').next().unwrap_or(&self.lineage)
+    }
+
+    /// Resolve a field given as a bare name (`src`) or as its qualified name
+    /// (`All_Traffic.src`).
+    pub fn resolve_field(&self, field: &str) -> Result<String, String> {
+        self.fields
+            .iter()
+            .find(|known| *known == field)
+            .or_else(|| {
+                self.fields
+                    .iter()
+                    .find(|known| known.rsplit('.').next() == Some(field))
+            })
+            .cloned()
+            .ok_or_else(|| {
+                format!(
+                    "unknown field '{}' for dataset '{}' (available: {})",
+                    field,
+                    self.lineage,
+                    self.fields.join(", ")
+                )
+            })
+    }
+}
+
+#[derive(Deserialize)]
+struct DefinitionJson {
+    #[serde(default)]
+    objects: Vec<ObjectJson>,
+}
+
+#[derive(Deserialize)]
+struct ObjectJson {
+    objectName: String,
+    #[serde(default)]
+    parentName: Option<String>,
+    #[serde(default)]
+    lineage: Option<String>,
+    #[serde(default)]
+    fields: Vec<FieldJson>,
+    #[serde(default)]
+    calculations: Vec<CalculationJson>,
+}
+
+#[derive(Deserialize)]
+struct FieldJson {
+    fieldName: String,
+    #[serde(default)]
+    owner: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CalculationJson {
+    #[serde(default)]
+    outputFields: Vec<FieldJson>,
+}
+
+impl DataModel {
+    /// Datasets defined by the data model's JSON definition, in definition order.
+    ///
+    /// Child datasets include the fields inherited from their parents. Returns
+    /// `None` when the definition is missing or is not valid JSON.
+    pub fn objects(&self) -> Option<Vec<DataModelObject>> {
+        let definition: DefinitionJson = serde_json::from_str(self.json_data.as_deref()?).ok()?;
+        let by_name: std::collections::HashMap<&str, &ObjectJson> = definition
+            .objects
+            .iter()
+            .map(|object| (object.objectName.as_str(), object))
+            .collect();
+
+        let lineage_of = |object: &ObjectJson| -> String {
+            if let Some(lineage) = object.lineage.as_deref().filter(|l| !l.is_empty()) {
+                return lineage.to_string();
+            }
+            let mut path = vec![object.objectName.as_str()];
+            let mut parent = object.parentName.as_deref();
+            // Parents outside the model (BaseEvent, BaseSearch, ...) end the lineage
+            while let Some(next) = parent.and_then(|name| by_name.get(name)) {
+                if path.contains(&next.objectName.as_str()) {
+                    break;
+                }
+                path.push(&next.objectName);
+                parent = next.parentName.as_deref();
+            }
+            path.reverse();
+            path.join(".")
+        };
+        let lineages: std::collections::HashMap<&str, String> = definition
+            .objects
+            .iter()
+            .map(|object| (object.objectName.as_str(), lineage_of(object)))
+            .collect();
+
+        let objects = definition
+            .objects
+            .iter()
+            .map(|object| {
+                let lineage = lineages[object.objectName.as_str()].clone();
+                let mut fields: Vec<String> = Vec::new();
+                let mut add = |name: String| {
+                    if !fields.contains(&name) {
+                        fields.push(name);
+                    }
+                };
+
+                // Walk from the root down so inherited fields come first
+                let segments: Vec<&str> = lineage.split('.').collect();
+                for depth in 1..=segments.len() {
+                    let Some(owner) = by_name.get(segments[depth - 1]) else {
+                        continue;
+                    };
+                    let owner_lineage = segments[..depth].join(".");
+                    let declared = owner.fields.iter().chain(
+                        owner
+                            .calculations
+                            .iter()
+                            .flat_map(|calculation| calculation.outputFields.iter()),
+                    );
+                    for field in declared {
+                        if BASE_FIELDS.contains(&field.fieldName.as_str()) {
+                            add(field.fieldName.clone());
+                            continue;
+                        }
+                        // Inherited copies of a field are qualified by their owner
+                        let qualifier = field
+                            .owner
+                            .as_deref()
+                            .and_then(|name| lineages.get(name))
+                            .unwrap_or(&owner_lineage);
+                        add(format!("{}.{}", qualifier, field.fieldName));
+                    }
+                }
+
+                DataModelObject {
+                    name: object.objectName.clone(),
+                    lineage,
+                    fields,
+                }
+            })
+            .collect();
+        Some(objects)
+    }
+}
+
+/// One aggregation of a tstats query, such as `count` or `dc(All_Traffic.src)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TstatsAggregate {
+    pub function: String,
+    pub field: Option<String>,
+}
+
+impl TstatsAggregate {
+    /// Parse `count`, `func:field`, or `func(field)`.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let spec = spec.trim();
+        let (function, field) = if let Some((function, rest)) = spec.split_once('(') {
+            let field = rest
+                .strip_suffix(')')
+                .ok_or_else(|| format!("missing ')' in aggregate '{}'", spec))?;
+            (function, Some(field))
+        } else if let Some((function, field)) = spec.split_once(':') {
+            (function, Some(field))
+        } else {
+            (spec, None)
+        };
+
+        let function = function.trim().to_ascii_lowercase();
+        if !TSTATS_FUNCTIONS.contains(&function.as_str()) {
+            return Err(format!(
+                "unsupported aggregate function '{}' (supported: {})",
+                function,
+                TSTATS_FUNCTIONS.join(", ")
+            ));
+        }
+        let field = field.map(str::trim).filter(|field| !field.is_empty());
+        if field.is_none() && function != "count" {
+            return Err(format!("aggregate '{}' requires a field", function));
+        }
+
+        Ok(Self {
+            function,
+            field: field.map(str::to_string),
+        })
+    }
+}
+
+impl std::fmt::Display for TstatsAggregate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.field {
+            Some(field) => write!(f, "{}({})", self.function, field),
+            None => write!(f, "{}", self.function),
+        }
+    }
+}
+
+/// A `| tstats` query against an accelerated data model.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TstatsQuery {
+    pub datamodel: String,
+    /// Dataset lineage (e.g. `All_Traffic.Blocked_Traffic`); `None` queries the whole model.
+    pub object: Option<String>,
+    /// Aggregations; an empty list means `count`.
+    pub aggregates: Vec<TstatsAggregate>,
+    pub split_by: Vec<String>,
+    /// Time bucket for `_time` (e.g. `1h`); adds `_time` to the split-by fields.
+    pub span: Option<String>,
+    /// Extra `where` clause, passed through as written.
+    pub filter: Option<String>,
+    /// Only use accelerated summaries (`summariesonly=true`).
+    pub summaries_only: bool,
+}
+
+impl TstatsQuery {
+    /// Qualify aggregate and split-by fields against `object`, which also
+    /// becomes the queried dataset.
+    pub fn resolve(&mut self, object: &DataModelObject) -> Result<(), String> {
+        for aggregate in &mut self.aggregates {
+            if let Some(field) = &aggregate.field {
+                aggregate.field = Some(object.resolve_field(field)?);
+            }
+        }
+        for field in &mut self.split_by {
+            *field = object.resolve_field(field)?;
+        }
+        self.object = Some(object.lineage.clone());
+        Ok(())
+    }
+
+    /// Render the query as SPL.
+    pub fn to_spl(&self) -> String {
+        let mut spl = String::from("| tstats");
+        if self.summaries_only {
+            spl.push_str(" summariesonly=true");
+        }
+
+        if self.aggregates.is_empty() {
+            spl.push_str(" count");
+        }
+        for aggregate in &self.aggregates {
+            spl.push(' ');
+            spl.push_str(&aggregate.to_string());
+        }
+
+        spl.push_str(" from datamodel=");
+        spl.push_str(&self.datamodel);
+        let mut conditions = Vec::new();
+        if let Some(object) = &self.object {
+            let root = object.split('.').next().unwrap_or(object);
+            spl.push('.');
+            spl.push_str(root);
+            if object.contains('.') {
+                conditions.push(format!("nodename={}", object));
+            }
+        }
+        if let Some(filter) = self.filter.as_deref().map(str::trim)
+            && !filter.is_empty()
+        {
+            conditions.push(filter.to_string());
+        }
+        if !conditions.is_empty() {
+            spl.push_str(" where ");
+            spl.push_str(&conditions.join(" AND "));
+        }
+
+        let mut split_by: Vec<String> = self.split_by.clone();
+        if let Some(span) = &self.span {
+            match split_by.iter().position(|field| field == "_time") {
+                Some(idx) => split_by[idx] = format!("_time span={}", span),
+                None => split_by.push(format!("_time span={}", span)),
+            }
+        }
+        if !split_by.is_empty() {
+            spl.push_str(" by ");
+            spl.push_str(&split_by.join(" "));
+        }
+        spl
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn network_traffic() -> DataModel {
+        DataModel {
+            name: "Network_Traffic".to_string(),
+            displayName: "Network Traffic".to_string(),
+            description: None,
+            owner: "nobody".to_string(),
+            app: "Splunk_SA_CIM".to_string(),
+            is_accelerated: true,
+            json_data: Some(
+                serde_json::json!({
+                    "modelName": "Network_Traffic",
+                    "objects": [
+                        {
+                            "objectName": "All_Traffic",
+                            "parentName": "BaseEvent",
+                            "fields": [
+                                { "fieldName": "_time", "owner": "BaseEvent" },
+                                { "fieldName": "host", "owner": "BaseEvent" },
+                                { "fieldName": "action", "owner": "All_Traffic" },
+                                { "fieldName": "src", "owner": "All_Traffic" }
+                            ],
+                            "calculations": [
+                                { "outputFields": [{ "fieldName": "bytes", "owner": "All_Traffic" }] }
+                            ]
+                        },
+                        {
+                            "objectName": "Blocked_Traffic",
+                            "parentName": "All_Traffic",
+                            "fields": [{ "fieldName": "rule", "owner": "Blocked_Traffic" }]
+                        }
+                    ]
+                })
+                .to_string(),
+            ),
+            updated: None,
+        }
+    }
+
+    #[test]
+    fn test_objects_compute_lineage_and_inherited_fields() {
+        let objects = network_traffic().objects().unwrap();
+
+        assert_eq!(objects.len(), 2);
+        assert_eq!(objects[0].lineage, "All_Traffic");
+        assert_eq!(
+            objects[0].fields,
+            vec![
+                "_time",
+                "host",
+                "All_Traffic.action",
+                "All_Traffic.src",
+                "All_Traffic.bytes"
+            ]
+        );
+        assert_eq!(objects[1].lineage, "All_Traffic.Blocked_Traffic");
+        assert_eq!(objects[1].root(), "All_Traffic");
+        assert!(objects[1].fields.contains(&"All_Traffic.src".to_string()));
+        assert!(
+            objects[1]
+                .fields
+                .contains(&"All_Traffic.Blocked_Traffic.rule".to_string())
+        );
+    }
+
+    #[test]
+    fn test_objects_requires_json_definition() {
+        let mut model = network_traffic();
+        model.json_data = None;
+        assert!(model.objects().is_none());
+
+        model.json_data = Some("not json".to_string());
+        assert!(model.objects().is_none());
+    }
+
+    #[test]
+    fn test_aggregate_parse_forms() {
+        assert_eq!(
+            TstatsAggregate::parse("count").unwrap().to_string(),
+            "count"
+        );
+        assert_eq!(
+            TstatsAggregate::parse("dc:src").unwrap().to_string(),
+            "dc(src)"
+        );
+        assert_eq!(
+            TstatsAggregate::parse("SUM(bytes)").unwrap().to_string(),
+            "sum(bytes)"
+        );
+        assert!(TstatsAggregate::parse("eval:x").is_err());
+        assert!(TstatsAggregate::parse("dc").is_err());
+        assert!(TstatsAggregate::parse("dc(src").is_err());
+    }
+
+    #[test]
+    fn test_query_resolves_fields_and_renders_child_dataset() {
+        let objects = network_traffic().objects().unwrap();
+        let mut query = TstatsQuery {
+            datamodel: "Network_Traffic".to_string(),
+            aggregates: vec![
+                TstatsAggregate::parse("count").unwrap(),
+                TstatsAggregate::parse("sum:bytes").unwrap(),
+            ],
+            split_by: vec!["src".to_string(), "rule".to_string()],
+            span: Some("1h".to_string()),
+            filter: Some("All_Traffic.action=blocked".to_string()),
+            summaries_only: true,
+            ..Default::default()
+        };
+
+        query.resolve(&objects[1]).unwrap();
+
+        assert_eq!(
+            query.to_spl(),
+            "| tstats summariesonly=true count sum(All_Traffic.bytes) from datamodel=Network_Traffic.All_Traffic where nodename=All_Traffic.Blocked_Traffic AND All_Traffic.action=blocked by All_Traffic.src All_Traffic.Blocked_Traffic.rule _time span=1h"
+        );
+    }
+
+    #[test]
+    fn test_query_rejects_unknown_fields() {
+        let objects = network_traffic().objects().unwrap();
+        let mut query = TstatsQuery {
+            datamodel: "Network_Traffic".to_string(),
+            split_by: vec!["dest_port".to_string()],
+            ..Default::default()
+        };
+
+        let error = query.resolve(&objects[0]).unwrap_err();
+        assert!(error.contains("unknown field 'dest_port'"));
+        assert!(error.contains("All_Traffic.src"));
+    }
+
+    #[test]
+    fn test_query_defaults_to_count_over_whole_model() {
+        let query = TstatsQuery {
+            datamodel: "Authentication".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.to_spl(),
+            "| tstats count from datamodel=Authentication"
+        );
+    }
+}
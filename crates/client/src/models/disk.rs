@@ -0,0 +1,226 @@
+//! Disk usage models.
+//!
+//! These models describe the partitions splunkd writes to (its home, index,
+//! and dispatch volumes), as reported by `server/status/partitions-space`,
+//! and classify each one against the disk health thresholds.
+
+use serde::{Deserialize, Serialize};
+use splunk_config::{HealthThresholds, ThresholdLevel};
+
+/// Space on one partition of the target instance.
+///
+/// splunkd reports sizes in megabytes, as strings on some versions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartitionSpace {
+    pub mount_point: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fs_type: Option<String>,
+    #[serde(
+        alias = "capacity",
+        default,
+        deserialize_with = "crate::serde_helpers::f64_from_string_or_number"
+    )]
+    pub capacity_mb: f64,
+    #[serde(
+        alias = "free",
+        default,
+        deserialize_with = "crate::serde_helpers::f64_from_string_or_number"
+    )]
+    pub free_mb: f64,
+}
+
+impl PartitionSpace {
+    pub fn used_mb(&self) -> f64 {
+        (self.capacity_mb - self.free_mb).max(0.0)
+    }
+
+    /// Percentage of capacity in use, or `None` for a partition reporting no capacity.
+    pub fn used_percent(&self) -> Option<f64> {
+        (self.capacity_mb > 0.0).then(|| self.used_mb() / self.capacity_mb * 100.0)
+    }
+
+    /// The fullest of `partitions`, ignoring any that report no capacity.
+    pub fn fullest(partitions: Vec<Self>) -> Option<Self> {
+        partitions
+            .into_iter()
+            .filter_map(|p| p.used_percent().map(|pct| (pct, p)))
+            .max_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, p)| p)
+    }
+}
+
+/// Disk usage of every partition on one instance.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiskUsage {
+    /// No partition is at or above the warning threshold.
+    pub is_healthy: bool,
+    pub server_name: String,
+    /// Roles of the instance (e.g. `indexer`, `search_head`), so a full
+    /// partition can be weighed by what the server does.
+    #[serde(default)]
+    pub server_roles: Vec<String>,
+    /// Usage percentage at which a partition is a warning.
+    pub warning_pct: f64,
+    /// Usage percentage at which a partition is critical.
+    pub critical_pct: f64,
+    /// Every partition, fullest first.
+    pub partitions: Vec<PartitionSpace>,
+}
+
+impl DiskUsage {
+    /// Build the report, ordering the fullest partition first.
+    pub fn new(
+        server_name: String,
+        server_roles: Vec<String>,
+        thresholds: &HealthThresholds,
+        mut partitions: Vec<PartitionSpace>,
+    ) -> Self {
+        sort_fullest_first(&mut partitions);
+        let mut usage = Self {
+            is_healthy: true,
+            server_name,
+            server_roles,
+            warning_pct: thresholds.disk_warning_pct,
+            critical_pct: thresholds.disk_critical_pct,
+            partitions,
+        };
+        usage.is_healthy = usage
+            .partitions
+            .iter()
+            .all(|p| usage.level(p) == ThresholdLevel::Ok);
+        usage
+    }
+
+    /// The fullest partition, if any reported a capacity.
+    pub fn worst(&self) -> Option<&PartitionSpace> {
+        self.partitions
+            .first()
+            .filter(|p| p.used_percent().is_some())
+    }
+
+    /// Where `partition` falls against this report's thresholds.
+    pub fn level(&self, partition: &PartitionSpace) -> ThresholdLevel {
+        let thresholds = HealthThresholds {
+            disk_warning_pct: self.warning_pct,
+            disk_critical_pct: self.critical_pct,
+            ..Default::default()
+        };
+        partition
+            .used_percent()
+            .map_or(ThresholdLevel::Ok, |pct| thresholds.disk_level(pct))
+    }
+
+    /// One warning per partition at or above the warning threshold, fullest first.
+    pub fn warnings(&self) -> Vec<String> {
+        self.partitions
+            .iter()
+            .filter_map(|p| {
+                let level = self.level(p);
+                (level != ThresholdLevel::Ok).then(|| partition_warning(p, level))
+            })
+            .collect()
+    }
+}
+
+/// Order partitions by usage, fullest first; partitions without a capacity go last.
+fn sort_fullest_first(partitions: &mut [PartitionSpace]) {
+    partitions.sort_by(|a, b| {
+        b.used_percent()
+            .unwrap_or(-1.0)
+            .total_cmp(&a.used_percent().unwrap_or(-1.0))
+            .then_with(|| a.mount_point.cmp(&b.mount_point))
+    });
+}
+
+/// Warning text for a partition past its threshold.
+pub(crate) fn partition_warning(partition: &PartitionSpace, level: ThresholdLevel) -> String {
+    format!(
+        "Partition {} is {:.1}% full ({:.0} MB free, {})",
+        partition.mount_point,
+        partition.used_percent().unwrap_or_default(),
+        partition.free_mb,
+        level
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn partition(mount_point: &str, capacity_mb: f64, free_mb: f64) -> PartitionSpace {
+        PartitionSpace {
+            mount_point: mount_point.to_string(),
+            fs_type: Some("ext4".to_string()),
+            capacity_mb,
+            free_mb,
+        }
+    }
+
+    #[test]
+    fn test_partition_deserializes_splunk_fields() {
+        let json = r#"{
+            "mount_point": "/opt/splunk",
+            "fs_type": "xfs",
+            "capacity": "102400",
+            "free": 25600,
+            "available": "20480"
+        }"#;
+        let p: PartitionSpace = serde_json::from_str(json).unwrap();
+        assert_eq!(p.capacity_mb, 102400.0);
+        assert_eq!(p.free_mb, 25600.0);
+        assert_eq!(p.used_percent(), Some(75.0));
+
+        // Serialized output round-trips through the renamed fields.
+        let value = serde_json::to_value(&p).unwrap();
+        assert_eq!(value["capacity_mb"], 102400.0);
+        let back: PartitionSpace = serde_json::from_value(value).unwrap();
+        assert_eq!(back, p);
+    }
+
+    #[test]
+    fn test_disk_usage_orders_and_flags_partitions() {
+        let usage = DiskUsage::new(
+            "idx01".to_string(),
+            vec!["indexer".to_string()],
+            &HealthThresholds::default(),
+            vec![
+                partition("/", 1000.0, 700.0),
+                partition("/empty", 0.0, 0.0),
+                partition("/opt/splunk/var", 1000.0, 50.0),
+                partition("/opt/splunk", 1000.0, 150.0),
+            ],
+        );
+
+        assert!(!usage.is_healthy);
+        let order: Vec<&str> = usage
+            .partitions
+            .iter()
+            .map(|p| p.mount_point.as_str())
+            .collect();
+        assert_eq!(order, vec!["/opt/splunk/var", "/opt/splunk", "/", "/empty"]);
+        assert_eq!(usage.worst().unwrap().mount_point, "/opt/splunk/var");
+        assert_eq!(
+            PartitionSpace::fullest(usage.partitions.clone()).as_ref(),
+            usage.worst()
+        );
+        assert_eq!(
+            usage.warnings(),
+            vec![
+                "Partition /opt/splunk/var is 95.0% full (50 MB free, critical)".to_string(),
+                "Partition /opt/splunk is 85.0% full (150 MB free, warning)".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_partitions_is_healthy() {
+        let usage = DiskUsage::new(
+            "sh01".to_string(),
+            vec![],
+            &HealthThresholds::default(),
+            vec![],
+        );
+        assert!(usage.is_healthy);
+        assert!(usage.worst().is_none());
+    }
+}
@@ -0,0 +1,51 @@
+//! Event type models for Splunk REST API.
+//!
+//! Responsibilities:
+//! - Define structs for event type data from /services/saved/eventtypes endpoint.
+//! - Provide serialization/deserialization via serde.
+//!
+//! Does NOT handle:
+//! - Does not handle HTTP requests (see endpoints module).
+//! - Does not contain business logic (see client module).
+
+use serde::{Deserialize, Serialize};
+
+/// An event type definition.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EventType {
+    /// Event type name
+    #[serde(default)]
+    pub name: String,
+    /// The search that defines the event type
+    #[serde(default)]
+    pub search: String,
+    /// Human-readable description
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Whether the event type is disabled
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::bool_from_string_or_bool"
+    )]
+    pub disabled: bool,
+    /// Priority used to order event types on matching events (1 is highest)
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::opt_i32_from_string_or_number"
+    )]
+    pub priority: Option<i32>,
+}
+
+/// Wrapper for a single event type entry in list responses.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EventTypeEntry {
+    pub name: String,
+    pub content: EventType,
+}
+
+/// Response from listing event types.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EventTypeListResponse {
+    #[serde(default)]
+    pub entry: Vec<EventTypeEntry>,
+}
@@ -12,7 +12,7 @@
 //!
 //! # What this module does NOT handle:
 //! - Direct HTTP request implementation (see [`crate::endpoints::hec`])
-//! - HEC token management (handled by CLI/config)
+//! - HEC token management (see [`crate::models::hec_tokens`])
 //!
 //! # Invariants
 //! - The `event` field is required and can be any JSON-serializable value
@@ -0,0 +1,212 @@
+//! HEC token models for the Splunk REST API.
+//!
+//! Responsibilities:
+//! - Define structs for HTTP Event Collector tokens from the
+//!   /services/data/inputs/http endpoint.
+//! - Define create and update parameters for token management.
+//!
+//! Does NOT handle:
+//! - Does not handle HTTP requests (see endpoints module).
+//! - Does not send events with a token (see [`crate::models::hec`]).
+//!
+//! Invariants:
+//! - Token values are wrapped in [`SecretString`] and serialize as `***`.
+
+use secrecy::SecretString;
+use serde::{Deserialize, Serialize};
+
+use crate::models::Acl;
+
+/// An HTTP Event Collector token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HecToken {
+    /// Input name as listed by Splunk (e.g., "http://web_events").
+    #[serde(default)]
+    pub name: String,
+    /// Token value sent in the `Authorization: Splunk <token>` header.
+    #[serde(
+        default,
+        serialize_with = "crate::serde_helpers::serialize_opt_secret_redacted",
+        deserialize_with = "crate::serde_helpers::deserialize_opt_secret_from_string"
+    )]
+    pub token: Option<SecretString>,
+    /// Index events go to when the sender does not name one.
+    #[serde(default)]
+    pub index: Option<String>,
+    /// Indexes the token may write to; empty means any index.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::vec_string_from_string_or_seq"
+    )]
+    pub indexes: Vec<String>,
+    /// Sourcetype assigned to events that do not set one.
+    #[serde(default)]
+    pub sourcetype: Option<String>,
+    /// Source assigned to events that do not set one.
+    #[serde(default)]
+    pub source: Option<String>,
+    /// Host assigned to events that do not set one.
+    #[serde(default)]
+    pub host: Option<String>,
+    /// Output group events are forwarded to.
+    #[serde(default)]
+    pub outputgroup: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// App the input is defined in, from the entry's ACL.
+    #[serde(default)]
+    pub app: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::bool_from_string_or_bool"
+    )]
+    pub disabled: bool,
+    /// Whether indexer acknowledgement is required.
+    #[serde(
+        default,
+        rename = "useACK",
+        deserialize_with = "crate::serde_helpers::bool_from_string_or_bool"
+    )]
+    pub use_ack: bool,
+}
+
+impl HecToken {
+    /// Token name without the `http://` prefix Splunk lists it under.
+    pub fn input_name(&self) -> &str {
+        self.name.strip_prefix("http://").unwrap_or(&self.name)
+    }
+}
+
+/// Wrapper for a single HEC token entry in list responses.
+#[derive(Debug, Clone, Deserialize)]
+pub struct HecTokenEntry {
+    pub name: String,
+    pub content: HecToken,
+    #[serde(default)]
+    pub acl: Option<Acl>,
+}
+
+/// Response from listing HEC tokens.
+#[derive(Debug, Clone, Deserialize)]
+pub struct HecTokenListResponse {
+    #[serde(default)]
+    pub entry: Vec<HecTokenEntry>,
+}
+
+/// Parameters for creating a new HEC token.
+#[derive(Debug, Clone, Default)]
+pub struct HecTokenCreateParams {
+    /// Token input name (required), without the `http://` prefix.
+    pub name: String,
+    /// Default index for events.
+    pub index: Option<String>,
+    /// Indexes the token may write to; empty allows any index.
+    pub indexes: Vec<String>,
+    /// Default sourcetype for events.
+    pub sourcetype: Option<String>,
+    /// Default source for events.
+    pub source: Option<String>,
+    /// Default host for events.
+    pub host: Option<String>,
+    /// Output group to forward events to.
+    pub outputgroup: Option<String>,
+    pub description: Option<String>,
+    /// Require indexer acknowledgement.
+    pub use_ack: bool,
+    /// App to define the input in; the client's namespace when `None`.
+    pub app: Option<String>,
+    /// Token value to use instead of a generated one.
+    pub token: Option<SecretString>,
+}
+
+impl From<&HecToken> for HecTokenCreateParams {
+    /// Settings of an existing token, for recreating it under the same name
+    /// and app. The token value is left out so Splunk generates a new one.
+    fn from(token: &HecToken) -> Self {
+        Self {
+            name: token.input_name().to_string(),
+            index: token.index.clone(),
+            indexes: token.indexes.clone(),
+            sourcetype: token.sourcetype.clone(),
+            source: token.source.clone(),
+            host: token.host.clone(),
+            outputgroup: token.outputgroup.clone(),
+            description: token.description.clone(),
+            use_ack: token.use_ack,
+            app: token.app.clone(),
+            token: None,
+        }
+    }
+}
+
+/// Parameters for updating an existing HEC token.
+///
+/// Only provided fields are updated; omitted fields (None) retain their current values.
+#[derive(Debug, Clone, Default)]
+pub struct HecTokenUpdateParams {
+    pub index: Option<String>,
+    pub indexes: Option<Vec<String>>,
+    pub sourcetype: Option<String>,
+    pub description: Option<String>,
+    pub disabled: Option<bool>,
+    pub use_ack: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use secrecy::ExposeSecret;
+
+    #[test]
+    fn test_deserialize_hec_token_and_redact_on_serialize() {
+        let json = r#"{
+            "token": "6b1c8f2e-0a4d-4c59-9a4e-2f1d7c3b8e90",
+            "index": "web",
+            "indexes": ["web", "main"],
+            "sourcetype": "access_combined",
+            "disabled": "0",
+            "useACK": "1"
+        }"#;
+        let token: HecToken = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            token.token.as_ref().unwrap().expose_secret(),
+            "6b1c8f2e-0a4d-4c59-9a4e-2f1d7c3b8e90"
+        );
+        assert_eq!(token.indexes, vec!["web", "main"]);
+        assert!(!token.disabled);
+        assert!(token.use_ack);
+
+        let serialized = serde_json::to_string(&token).unwrap();
+        assert!(serialized.contains(r#""token":"***""#));
+        assert!(!serialized.contains("6b1c8f2e"));
+    }
+
+    #[test]
+    fn test_create_params_from_token_keep_settings() {
+        let token = HecToken {
+            name: "http://web_events".to_string(),
+            token: Some(SecretString::from("6b1c8f2e")),
+            index: Some("web".to_string()),
+            indexes: vec!["web".to_string()],
+            sourcetype: Some("web:json".to_string()),
+            source: Some("web-tier".to_string()),
+            host: None,
+            outputgroup: Some("primary".to_string()),
+            description: None,
+            app: Some("web_app".to_string()),
+            disabled: true,
+            use_ack: true,
+        };
+        assert_eq!(token.input_name(), "web_events");
+
+        let params = HecTokenCreateParams::from(&token);
+        assert_eq!(params.name, "web_events");
+        assert_eq!(params.index.as_deref(), Some("web"));
+        assert_eq!(params.indexes, vec!["web"]);
+        assert_eq!(params.source.as_deref(), Some("web-tier"));
+        assert_eq!(params.outputgroup.as_deref(), Some("primary"));
+        assert_eq!(params.app.as_deref(), Some("web_app"));
+        assert!(params.token.is_none());
+        assert!(params.use_ack);
+    }
+}
@@ -97,6 +97,88 @@ pub struct Index {
     pub cold_to_frozen_dir: Option<String>,
     #[serde(rename = "primaryIndex")]
     pub primary_index: Option<bool>,
+    /// Timestamp of the earliest event in the index.
+    #[serde(rename = "minTime", default)]
+    pub min_time: Option<String>,
+    /// Timestamp of the latest event in the index.
+    #[serde(rename = "maxTime", default)]
+    pub max_time: Option<String>,
+}
+
+/// Estimated effect of new retention settings on an index's current data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct FreezeProjection {
+    /// Current size of the index in MB.
+    pub current_size_mb: usize,
+    /// Data older than the new frozen time period, in MB.
+    pub frozen_by_age_mb: usize,
+    /// Data still over the new size limit once aged-out data froze, in MB.
+    pub frozen_by_size_mb: usize,
+}
+
+impl FreezeProjection {
+    /// Total data that would freeze immediately, in MB.
+    pub fn frozen_mb(&self) -> usize {
+        self.frozen_by_age_mb + self.frozen_by_size_mb
+    }
+
+    /// Share of the current data that would freeze immediately (0-100).
+    pub fn frozen_percent(&self) -> f64 {
+        if self.current_size_mb == 0 {
+            return 0.0;
+        }
+        self.frozen_mb() as f64 * 100.0 / self.current_size_mb as f64
+    }
+}
+
+impl Index {
+    /// Estimate how much current data would freeze under new retention settings.
+    ///
+    /// Age-based freezing assumes events are spread evenly between `minTime` and
+    /// `maxTime`; Splunk freezes whole buckets, so the real amount can differ.
+    /// A `None` setting is treated as unchanged from the index's current value.
+    pub fn project_freeze(
+        &self,
+        max_total_data_size_mb: Option<usize>,
+        frozen_time_period_in_secs: Option<usize>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> FreezeProjection {
+        let current = self.current_db_size_mb;
+        let frozen_by_age_mb = frozen_time_period_in_secs
+            .or(self.frozen_time_period_in_secs)
+            .and_then(|period| {
+                let min = parse_index_time(self.min_time.as_deref()?)?;
+                let max = parse_index_time(self.max_time.as_deref()?)?;
+                let cutoff = now.timestamp() - i64::try_from(period).ok()?;
+                Some(if cutoff <= min {
+                    0
+                } else if cutoff >= max {
+                    current
+                } else {
+                    let fraction = (cutoff - min) as f64 / (max - min) as f64;
+                    (current as f64 * fraction).round() as usize
+                })
+            })
+            .unwrap_or(0);
+        let frozen_by_size_mb = max_total_data_size_mb
+            .or(self.max_total_data_size_mb)
+            .map(|limit| (current - frozen_by_age_mb).saturating_sub(limit))
+            .unwrap_or(0);
+
+        FreezeProjection {
+            current_size_mb: current,
+            frozen_by_age_mb,
+            frozen_by_size_mb,
+        }
+    }
+}
+
+/// Parse an index `minTime`/`maxTime` value into epoch seconds.
+fn parse_index_time(value: &str) -> Option<i64> {
+    chrono::DateTime::parse_from_rfc3339(value)
+        .or_else(|_| chrono::DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%z"))
+        .ok()
+        .map(|time| time.timestamp())
 }
 
 /// Index list response.
@@ -110,3 +192,77 @@ pub struct IndexEntry {
     pub name: String,
     pub content: Index,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn index(size_mb: usize, min_time: &str, max_time: &str) -> Index {
+        Index {
+            name: "main".to_string(),
+            max_total_data_size_mb: Some(500000),
+            current_db_size_mb: size_mb,
+            total_event_count: 0,
+            max_warm_db_count: None,
+            max_hot_buckets: None,
+            frozen_time_period_in_secs: Some(15552000),
+            cold_db_path: None,
+            home_path: None,
+            thawed_path: None,
+            cold_to_frozen_dir: None,
+            primary_index: None,
+            min_time: Some(min_time.to_string()),
+            max_time: Some(max_time.to_string()),
+        }
+    }
+
+    fn now() -> chrono::DateTime<chrono::Utc> {
+        chrono::Utc.with_ymd_and_hms(2024, 1, 11, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_project_freeze_by_age_is_proportional() {
+        let index = index(
+            1000,
+            "2024-01-01T00:00:00+00:00",
+            "2024-01-11T00:00:00+0000",
+        );
+
+        // Keep 7 of the 10 days of data.
+        let projection = index.project_freeze(None, Some(7 * 86400), now());
+
+        assert_eq!(projection.frozen_by_age_mb, 300);
+        assert_eq!(projection.frozen_by_size_mb, 0);
+        assert!((projection.frozen_percent() - 30.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_project_freeze_by_size_counts_remaining_data() {
+        let index = index(
+            1000,
+            "2024-01-01T00:00:00+00:00",
+            "2024-01-11T00:00:00+00:00",
+        );
+
+        let projection = index.project_freeze(Some(600), Some(5 * 86400), now());
+
+        assert_eq!(projection.frozen_by_age_mb, 500);
+        assert_eq!(projection.frozen_by_size_mb, 0);
+
+        let projection = index.project_freeze(Some(600), None, now());
+        assert_eq!(projection.frozen_by_age_mb, 0);
+        assert_eq!(projection.frozen_mb(), 400);
+    }
+
+    #[test]
+    fn test_project_freeze_without_time_range_ignores_age() {
+        let mut index = index(0, "", "");
+        index.min_time = None;
+
+        let projection = index.project_freeze(Some(1), Some(1), now());
+
+        assert_eq!(projection.frozen_mb(), 0);
+        assert_eq!(projection.frozen_percent(), 0.0);
+    }
+}
@@ -0,0 +1,250 @@
+//! Indexing latency and thruput models.
+//!
+//! These models combine two views of recent ingestion: the delay between an
+//! event's timestamp and the moment it was indexed (`_indextime - _time`),
+//! and the volume splunkd records in the `per_<field>_thruput` groups of
+//! `metrics.log`.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Field ingestion statistics are grouped by.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IngestionGroupBy {
+    #[default]
+    Sourcetype,
+    Index,
+    Host,
+}
+
+impl IngestionGroupBy {
+    /// All supported groupings.
+    pub const ALL: [IngestionGroupBy; 3] = [Self::Sourcetype, Self::Index, Self::Host];
+
+    /// The event field (and `metrics.log` thruput group suffix) for this grouping.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Sourcetype => "sourcetype",
+            Self::Index => "index",
+            Self::Host => "host",
+        }
+    }
+}
+
+impl fmt::Display for IngestionGroupBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for IngestionGroupBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|group_by| group_by.as_str() == s)
+            .ok_or_else(|| {
+                format!(
+                    "Invalid grouping '{}'. Valid values: {}",
+                    s,
+                    Self::ALL.map(Self::as_str).join(", ")
+                )
+            })
+    }
+}
+
+/// Indexing latency and thruput for one sourcetype, index, or host.
+///
+/// Deserialized from the rows of both the latency and the thruput search,
+/// where Splunk returns numbers as strings; each search fills in its own
+/// columns and [`IngestionStats::from_rows`] merges them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IngestionStat {
+    /// Sourcetype, index, or host name, depending on the grouping.
+    pub name: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::u64_from_string_or_number"
+    )]
+    pub events: u64,
+    /// Kilobytes processed by the indexing pipeline in the window.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::f64_from_string_or_number"
+    )]
+    pub kb: f64,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::f64_from_string_or_number"
+    )]
+    pub kb_per_sec: f64,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::f64_from_string_or_number"
+    )]
+    pub events_per_sec: f64,
+    /// Average seconds between event time and index time; absent when no
+    /// events were searchable in the window.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::serde_helpers::opt_f64_from_string_or_number"
+    )]
+    pub avg_latency_secs: Option<f64>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::serde_helpers::opt_f64_from_string_or_number"
+    )]
+    pub max_latency_secs: Option<f64>,
+}
+
+impl IngestionStat {
+    fn empty(name: String) -> Self {
+        Self {
+            name,
+            events: 0,
+            kb: 0.0,
+            kb_per_sec: 0.0,
+            events_per_sec: 0.0,
+            avg_latency_secs: None,
+            max_latency_secs: None,
+        }
+    }
+}
+
+/// Indexing latency and thruput per group over a time window.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IngestionStats {
+    pub group_by: IngestionGroupBy,
+    /// Length of the window in seconds, used to turn totals into rates.
+    pub window_secs: u64,
+    /// One entry per group, highest thruput first.
+    pub stats: Vec<IngestionStat>,
+}
+
+impl IngestionStats {
+    /// Merge the latency rows and thruput rows by name.
+    ///
+    /// Event counts come from the latency search, which counts every event;
+    /// the `metrics.log` count is only used for groups with no searchable
+    /// events. Rates are computed over `window_secs`.
+    pub fn from_rows(
+        group_by: IngestionGroupBy,
+        window_secs: u64,
+        latency: Vec<IngestionStat>,
+        thruput: Vec<IngestionStat>,
+    ) -> Self {
+        let mut merged: BTreeMap<String, IngestionStat> = BTreeMap::new();
+
+        for row in thruput {
+            let entry = merged
+                .entry(row.name.clone())
+                .or_insert_with(|| IngestionStat::empty(row.name.clone()));
+            entry.kb += row.kb;
+            entry.events += row.events;
+        }
+        for row in latency {
+            let entry = merged
+                .entry(row.name.clone())
+                .or_insert_with(|| IngestionStat::empty(row.name.clone()));
+            entry.events = row.events;
+            entry.avg_latency_secs = row.avg_latency_secs;
+            entry.max_latency_secs = row.max_latency_secs;
+        }
+
+        let secs = window_secs.max(1) as f64;
+        let mut stats: Vec<IngestionStat> = merged
+            .into_values()
+            .map(|mut stat| {
+                stat.kb_per_sec = stat.kb / secs;
+                stat.events_per_sec = stat.events as f64 / secs;
+                stat
+            })
+            .collect();
+        stats.sort_by(|a, b| {
+            b.kb.total_cmp(&a.kb)
+                .then_with(|| b.events.cmp(&a.events))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+
+        Self {
+            group_by,
+            window_secs,
+            stats,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(name: &str, events: u64, kb: f64, latency: Option<f64>) -> IngestionStat {
+        IngestionStat {
+            avg_latency_secs: latency,
+            max_latency_secs: latency,
+            events,
+            kb,
+            ..IngestionStat::empty(name.to_string())
+        }
+    }
+
+    #[test]
+    fn test_group_by_round_trips_through_str() {
+        for group_by in IngestionGroupBy::ALL {
+            assert_eq!(group_by.as_str().parse::<IngestionGroupBy>(), Ok(group_by));
+        }
+        let err = "source".parse::<IngestionGroupBy>().unwrap_err();
+        assert!(err.contains("sourcetype, index, host"));
+    }
+
+    #[test]
+    fn test_deserialize_latency_row_with_string_numbers() {
+        let json = r#"{
+            "name": "access_combined",
+            "events": "7200",
+            "avg_latency_secs": "2.5",
+            "max_latency_secs": "31"
+        }"#;
+        let stat: IngestionStat = serde_json::from_str(json).unwrap();
+        assert_eq!(stat.events, 7200);
+        assert_eq!(stat.kb, 0.0);
+        assert_eq!(stat.avg_latency_secs, Some(2.5));
+        assert_eq!(stat.max_latency_secs, Some(31.0));
+    }
+
+    #[test]
+    fn test_from_rows_merges_and_orders_by_thruput() {
+        let stats = IngestionStats::from_rows(
+            IngestionGroupBy::Sourcetype,
+            3600,
+            vec![
+                row("syslog", 720, 0.0, Some(1.0)),
+                row("access_combined", 7200, 0.0, Some(2.5)),
+            ],
+            vec![
+                row("access_combined", 7000, 3600.0, None),
+                row("syslog", 700, 36.0, None),
+                row("splunkd", 360, 7200.0, None),
+            ],
+        );
+
+        let names: Vec<&str> = stats.stats.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["splunkd", "access_combined", "syslog"]);
+
+        let access = &stats.stats[1];
+        assert_eq!(access.events, 7200);
+        assert_eq!(access.avg_latency_secs, Some(2.5));
+        assert!((access.kb_per_sec - 1.0).abs() < 1e-9);
+        assert!((access.events_per_sec - 2.0).abs() < 1e-9);
+
+        let splunkd = &stats.stats[0];
+        assert_eq!(splunkd.events, 360);
+        assert_eq!(splunkd.avg_latency_secs, None);
+    }
+}
@@ -5,6 +5,8 @@
 //! # What this module handles:
 //! - Deserialization of data input data from Splunk REST API
 //! - Type-safe representation of input metadata (TCP, UDP, Monitor, Script)
+//! - Joining inputs with `metrics.log` per-source throughput by source or path
+//! - Typed parameters for creating monitor, TCP, and UDP inputs
 //!
 //! # What this module does NOT handle:
 //! - Direct HTTP API calls (see [`crate::endpoints::inputs`])
@@ -130,6 +132,217 @@ pub struct InputListResponse {
     pub entry: Vec<InputEntry>,
 }
 
+/// Settings specific to the kind of input being created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NewInput {
+    /// Monitor a file or directory.
+    Monitor {
+        /// File or directory to monitor.
+        path: String,
+        /// Regex of file paths to include.
+        whitelist: Option<String>,
+        /// Regex of file paths to exclude.
+        blacklist: Option<String>,
+        /// Descend into subdirectories (Splunk defaults to true).
+        recursive: Option<bool>,
+    },
+    /// Listen for raw data on a TCP port.
+    Tcp { port: u16 },
+    /// Listen for data on a UDP port.
+    Udp { port: u16 },
+}
+
+/// Parameters for creating a monitor, TCP, or UDP input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateInputParams {
+    pub input: NewInput,
+    /// Destination index (Splunk defaults to `main`).
+    pub index: Option<String>,
+    pub sourcetype: Option<String>,
+    /// Host field value for events from this input.
+    pub host: Option<String>,
+}
+
+impl CreateInputParams {
+    /// The input type, which selects the `data/inputs/{type}` collection.
+    pub fn input_type(&self) -> InputType {
+        match self.input {
+            NewInput::Monitor { .. } => InputType::Monitor,
+            NewInput::Tcp { .. } => InputType::TcpRaw,
+            NewInput::Udp { .. } => InputType::Udp,
+        }
+    }
+
+    /// The input's entry name: the monitored path or the port.
+    pub fn name(&self) -> String {
+        match &self.input {
+            NewInput::Monitor { path, .. } => path.clone(),
+            NewInput::Tcp { port } | NewInput::Udp { port } => port.to_string(),
+        }
+    }
+}
+
+/// Ingestion volume for one `per_source_thruput` series over the throughput window.
+///
+/// Deserialized from the rows of the throughput summary search. Splunk only
+/// records the busiest series in each sampling interval (`maxseries` in
+/// limits.conf, 10 by default), so low-volume sources can be missing here even
+/// while they ingest data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceThroughput {
+    /// Source of the series: a file path, `tcp:PORT`, `udp:PORT`, or a script path.
+    pub series: String,
+    /// Kilobytes ingested over the window.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::f64_from_string_or_number"
+    )]
+    pub kb: f64,
+    /// Events ingested over the window.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::u64_from_string_or_number"
+    )]
+    pub events: u64,
+    /// Time of the most recent sample in the window.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_seen: Option<String>,
+}
+
+/// Throughput attributed to a single configured input.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct InputThroughput {
+    pub kb_per_sec: f64,
+    pub events_per_sec: f64,
+    pub kb: f64,
+    pub events: u64,
+}
+
+/// Per-source ingestion throughput over a time window.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThroughputReport {
+    /// Length of the window in seconds, used to turn totals into rates.
+    pub window_secs: u64,
+    pub sources: Vec<SourceThroughput>,
+}
+
+impl ThroughputReport {
+    /// Sum the throughput of every series that belongs to `input`.
+    ///
+    /// Returns `None` when the input's data cannot be attributed to a series:
+    /// cooked TCP inputs receive events under the forwarders' own sources.
+    pub fn for_input(&self, input: &Input) -> Option<InputThroughput> {
+        let matchers = source_matchers(input);
+        if matchers.is_empty() {
+            return None;
+        }
+
+        let (kb, events) = self
+            .sources
+            .iter()
+            .filter(|source| {
+                let series = normalize_source(&source.series);
+                matchers.iter().any(|m| m.matches(&series))
+            })
+            .fold((0.0, 0), |(kb, events), source| {
+                (kb + source.kb, events + source.events)
+            });
+
+        let secs = self.window_secs.max(1) as f64;
+        Some(InputThroughput {
+            kb_per_sec: kb / secs,
+            events_per_sec: events as f64 / secs,
+            kb,
+            events,
+        })
+    }
+
+    /// Whether `input` is enabled but logged no events in the window.
+    pub fn is_silent(&self, input: &Input) -> bool {
+        !input.disabled && self.for_input(input).is_some_and(|t| t.events == 0)
+    }
+}
+
+/// How a `per_source_thruput` series is matched to an input.
+enum SourceMatcher {
+    /// The series is exactly this source.
+    Exact(String),
+    /// The series is a file beneath this directory (with trailing `/`).
+    Under(String),
+    /// The series is a path ending in this file name.
+    FileName(String),
+}
+
+impl SourceMatcher {
+    fn matches(&self, series: &str) -> bool {
+        match self {
+            SourceMatcher::Exact(source) => series == source,
+            SourceMatcher::Under(dir) => series.starts_with(dir.as_str()),
+            SourceMatcher::FileName(name) => series.rsplit('/').next() == Some(name.as_str()),
+        }
+    }
+}
+
+/// Lowercase and use forward slashes so paths compare like `metrics.log` reports them.
+fn normalize_source(source: &str) -> String {
+    source.trim().replace('\\', "/").to_ascii_lowercase()
+}
+
+/// Derive the series matchers for an input from its source override or type.
+fn source_matchers(input: &Input) -> Vec<SourceMatcher> {
+    // An explicit `source =` setting replaces the default source entirely.
+    if let Some(source) = input.source.as_deref().filter(|s| !s.trim().is_empty()) {
+        return vec![SourceMatcher::Exact(normalize_source(source))];
+    }
+
+    match input.input_type {
+        InputType::Monitor => {
+            let path = normalize_source(input.path.as_deref().unwrap_or(&input.name));
+            let wildcard = [path.find('*'), path.find("...")]
+                .into_iter()
+                .flatten()
+                .min();
+            match wildcard {
+                // Only the static directory prefix before a wildcard can be matched.
+                Some(pos) => {
+                    let dir_end = path[..pos].rfind('/').map_or(0, |i| i + 1);
+                    vec![SourceMatcher::Under(path[..dir_end].to_string())]
+                }
+                None => {
+                    let path = path.trim_end_matches('/').to_string();
+                    vec![
+                        SourceMatcher::Under(format!("{path}/")),
+                        SourceMatcher::Exact(path),
+                    ]
+                }
+            }
+        }
+        InputType::TcpRaw | InputType::Udp => {
+            let port = input.port.clone().unwrap_or_else(|| {
+                input
+                    .name
+                    .rsplit(':')
+                    .next()
+                    .unwrap_or_default()
+                    .to_string()
+            });
+            let scheme = if input.input_type == InputType::Udp {
+                "udp"
+            } else {
+                "tcp"
+            };
+            vec![SourceMatcher::Exact(format!("{scheme}:{}", port.trim()))]
+        }
+        InputType::Script => normalize_source(&input.name)
+            .rsplit('/')
+            .next()
+            .filter(|name| !name.is_empty())
+            .map(|name| vec![SourceMatcher::FileName(name.to_string())])
+            .unwrap_or_default(),
+        InputType::TcpCooked | InputType::Unknown => Vec::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -367,4 +580,132 @@ mod tests {
         assert_ne!(InputType::TcpRaw, InputType::TcpCooked);
         assert_ne!(InputType::Udp, InputType::Monitor);
     }
+
+    fn input(name: &str, input_type: InputType) -> Input {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "input_type": input_type,
+        }))
+        .unwrap()
+    }
+
+    fn series(series: &str, kb: f64, events: u64) -> SourceThroughput {
+        SourceThroughput {
+            series: series.to_string(),
+            kb,
+            events,
+            last_seen: None,
+        }
+    }
+
+    #[test]
+    fn test_deserialize_source_throughput_with_string_totals() {
+        let json = r#"{
+            "series": "/var/log/messages",
+            "kb": "1843.2",
+            "events": "7200",
+            "last_seen": "2025-01-20T10:00:00+0000"
+        }"#;
+        let source: SourceThroughput = serde_json::from_str(json).unwrap();
+        assert_eq!(source.series, "/var/log/messages");
+        assert_eq!(source.kb, 1843.2);
+        assert_eq!(source.events, 7200);
+    }
+
+    #[test]
+    fn test_throughput_joins_inputs_by_path_port_and_script() {
+        let report = ThroughputReport {
+            window_secs: 3600,
+            sources: vec![
+                series("/var/log/nginx/access.log", 3600.0, 7200),
+                series("/var/log/nginx/error.log", 360.0, 360),
+                series("/var/log/nginx-old/access.log", 100.0, 100),
+                series("udp:514", 36.0, 720),
+                series("/opt/splunk/etc/apps/ops/bin/poll.sh", 3.6, 36),
+            ],
+        };
+
+        let nginx = report
+            .for_input(&input("/var/log/nginx/", InputType::Monitor))
+            .unwrap();
+        assert_eq!(nginx.events, 7560);
+        assert!((nginx.kb_per_sec - 1.1).abs() < 1e-9);
+        assert!((nginx.events_per_sec - 2.1).abs() < 1e-9);
+
+        let wildcard = report
+            .for_input(&input("/var/log/*/error.log", InputType::Monitor))
+            .unwrap();
+        assert_eq!(wildcard.events, 7660);
+
+        let syslog = report.for_input(&input("514", InputType::Udp)).unwrap();
+        assert_eq!(syslog.events, 720);
+
+        let script = report
+            .for_input(&input(
+                "$SPLUNK_HOME/etc/apps/ops/bin/poll.sh",
+                InputType::Script,
+            ))
+            .unwrap();
+        assert_eq!(script.events, 36);
+    }
+
+    #[test]
+    fn test_throughput_prefers_explicit_source() {
+        let report = ThroughputReport {
+            window_secs: 3600,
+            sources: vec![
+                series("tcp:9997", 10.0, 10),
+                series("syslog-edge", 20.0, 20),
+            ],
+        };
+        let mut tcp = input("9997", InputType::TcpRaw);
+        assert_eq!(report.for_input(&tcp).unwrap().events, 10);
+
+        tcp.source = Some("Syslog-Edge".to_string());
+        assert_eq!(report.for_input(&tcp).unwrap().events, 20);
+    }
+
+    #[test]
+    fn test_throughput_flags_silent_enabled_inputs() {
+        let report = ThroughputReport {
+            window_secs: 3600,
+            sources: vec![series("/var/log/messages", 10.0, 10)],
+        };
+
+        assert!(!report.is_silent(&input("/var/log/messages", InputType::Monitor)));
+        assert!(report.is_silent(&input("/var/log/secure", InputType::Monitor)));
+
+        let mut disabled = input("/var/log/secure", InputType::Monitor);
+        disabled.disabled = true;
+        assert!(!report.is_silent(&disabled));
+
+        // Cooked TCP data arrives under the forwarders' sources, so it is never flagged.
+        let cooked = input("9997", InputType::TcpCooked);
+        assert_eq!(report.for_input(&cooked), None);
+        assert!(!report.is_silent(&cooked));
+    }
+
+    #[test]
+    fn test_create_input_params_name_and_type() {
+        let monitor = CreateInputParams {
+            input: NewInput::Monitor {
+                path: "/var/log/app".to_string(),
+                whitelist: None,
+                blacklist: None,
+                recursive: None,
+            },
+            index: Some("app".to_string()),
+            sourcetype: None,
+            host: None,
+        };
+        assert_eq!(monitor.input_type(), InputType::Monitor);
+        assert_eq!(monitor.name(), "/var/log/app");
+
+        let udp = CreateInputParams {
+            input: NewInput::Udp { port: 514 },
+            ..monitor
+        };
+        assert_eq!(udp.input_type(), InputType::Udp);
+        assert_eq!(udp.name(), "514");
+    }
 }
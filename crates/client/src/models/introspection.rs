@@ -0,0 +1,197 @@
+//! Resource usage models from splunkd introspection.
+//!
+//! These models summarize `index=_introspection sourcetype=splunk_resource_usage`,
+//! which splunkd writes every few seconds: host-wide CPU and memory
+//! (`component=Hostwide`), I/O wait (`component=IOStats`), and per-process
+//! usage (`component=PerProcess`).
+
+use serde::{Deserialize, Serialize};
+
+/// Latest CPU, memory, and I/O wait sample for one host.
+///
+/// Deserialized from the rows of the host usage search, where Splunk returns
+/// numbers as strings and omits fields with no sample in the window.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HostResourceUsage {
+    pub host: String,
+    /// System plus user CPU, as a percentage of all cores.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::f64_from_string_or_number"
+    )]
+    pub cpu_pct: f64,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::f64_from_string_or_number"
+    )]
+    pub mem_used_mb: f64,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::f64_from_string_or_number"
+    )]
+    pub mem_total_mb: f64,
+    /// CPU time spent waiting on I/O; absent where splunkd does not collect IOStats.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::serde_helpers::opt_f64_from_string_or_number"
+    )]
+    pub iowait_pct: Option<f64>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::serde_helpers::opt_u64_from_string_or_number"
+    )]
+    pub cpu_count: Option<u64>,
+    /// Time of the most recent sample in the window.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_seen: Option<String>,
+}
+
+impl HostResourceUsage {
+    /// Memory in use as a percentage of physical memory.
+    pub fn mem_used_pct(&self) -> f64 {
+        if self.mem_total_mb <= 0.0 {
+            0.0
+        } else {
+            self.mem_used_mb * 100.0 / self.mem_total_mb
+        }
+    }
+}
+
+/// Latest CPU and memory sample for one Splunk process.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProcessResourceUsage {
+    pub host: String,
+    /// Executable name, e.g. `splunkd` or `python3.9`.
+    pub process: String,
+    /// Role splunkd assigns the process, e.g. `search` or `splunkd server`.
+    #[serde(default)]
+    pub process_type: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::string_from_number_or_string"
+    )]
+    pub pid: String,
+    /// CPU as a percentage of one core; may exceed 100 for multi-threaded processes.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::f64_from_string_or_number"
+    )]
+    pub cpu_pct: f64,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::f64_from_string_or_number"
+    )]
+    pub mem_used_mb: f64,
+}
+
+/// Per-host resource usage and the busiest processes for a time window.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceUsage {
+    /// Earliest time of the window, as a Splunk relative time (e.g. `-15m`).
+    pub time_window: String,
+    /// Host the report was limited to, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// Hosts, busiest CPU first.
+    pub hosts: Vec<HostResourceUsage>,
+    /// Processes, busiest CPU first.
+    pub processes: Vec<ProcessResourceUsage>,
+}
+
+impl ResourceUsage {
+    /// Build the report, ordering hosts and processes by CPU (then name) so the
+    /// busiest come first.
+    pub fn new(
+        time_window: String,
+        host: Option<String>,
+        mut hosts: Vec<HostResourceUsage>,
+        mut processes: Vec<ProcessResourceUsage>,
+    ) -> Self {
+        hosts.sort_by(|a, b| {
+            b.cpu_pct
+                .total_cmp(&a.cpu_pct)
+                .then_with(|| a.host.cmp(&b.host))
+        });
+        processes.sort_by(|a, b| {
+            b.cpu_pct
+                .total_cmp(&a.cpu_pct)
+                .then_with(|| a.host.cmp(&b.host))
+                .then_with(|| a.pid.cmp(&b.pid))
+        });
+        Self {
+            time_window,
+            host,
+            hosts,
+            processes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn host(name: &str, cpu_pct: f64) -> HostResourceUsage {
+        HostResourceUsage {
+            host: name.to_string(),
+            cpu_pct,
+            mem_used_mb: 0.0,
+            mem_total_mb: 0.0,
+            iowait_pct: None,
+            cpu_count: None,
+            last_seen: None,
+        }
+    }
+
+    #[test]
+    fn test_deserialize_host_row_with_string_numbers() {
+        let json = r#"{
+            "host": "sh01",
+            "cpu_pct": "37.5",
+            "mem_used_mb": "12288",
+            "mem_total_mb": "16384",
+            "iowait_pct": "2.25",
+            "cpu_count": "8",
+            "last_seen": "2025-01-20T10:00:00+0000"
+        }"#;
+        let usage: HostResourceUsage = serde_json::from_str(json).unwrap();
+        assert_eq!(usage.host, "sh01");
+        assert_eq!(usage.cpu_pct, 37.5);
+        assert_eq!(usage.iowait_pct, Some(2.25));
+        assert_eq!(usage.cpu_count, Some(8));
+        assert!((usage.mem_used_pct() - 75.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_deserialize_host_row_without_iostats() {
+        let json =
+            r#"{"host": "win01", "cpu_pct": "5", "mem_used_mb": "100", "mem_total_mb": "0"}"#;
+        let usage: HostResourceUsage = serde_json::from_str(json).unwrap();
+        assert_eq!(usage.iowait_pct, None);
+        assert_eq!(usage.mem_used_pct(), 0.0);
+    }
+
+    #[test]
+    fn test_new_orders_busiest_first() {
+        let process = |pid: &str, cpu_pct: f64| ProcessResourceUsage {
+            host: "sh01".to_string(),
+            process: "splunkd".to_string(),
+            process_type: "search".to_string(),
+            pid: pid.to_string(),
+            cpu_pct,
+            mem_used_mb: 0.0,
+        };
+        let usage = ResourceUsage::new(
+            "-15m".to_string(),
+            None,
+            vec![host("idx01", 10.0), host("sh01", 80.0), host("idx02", 10.0)],
+            vec![process("1", 5.0), process("2", 150.0)],
+        );
+
+        let hosts: Vec<&str> = usage.hosts.iter().map(|h| h.host.as_str()).collect();
+        assert_eq!(hosts, vec!["sh01", "idx01", "idx02"]);
+        assert_eq!(usage.processes[0].pid, "2");
+    }
+}
@@ -5,6 +5,8 @@
 //!
 //! # What this module handles:
 //! - Search job status and results
+//! - Per-component performance counters (`performance` map of a job)
+//! - Server-side job list filters (owner, app, dispatch state)
 //! - SPL validation request/response types
 //!
 //! # What this module does NOT handle:
@@ -12,6 +14,8 @@
 //! - HTTP transport (see [`crate::endpoints::search`])
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
 
 /// Search job status information.
 #[derive(Debug, Deserialize, Clone)]
@@ -53,6 +57,87 @@ pub struct SearchJob {
     pub diskUsage: usize,
 }
 
+/// One search job's quota-relevant resource usage, attributed to its owner.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JobUsage {
+    pub sid: String,
+    /// User who dispatched the job
+    pub owner: String,
+    pub is_done: bool,
+    pub is_realtime: bool,
+    /// Disk space used by the job's artifacts, in bytes
+    pub disk_usage: usize,
+}
+
+/// Search job list response, reduced to the fields needed for [`JobUsage`].
+#[derive(Debug, Deserialize, Clone)]
+pub struct JobUsageListResponse {
+    #[serde(default)]
+    pub entry: Vec<JobUsageEntry>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct JobUsageEntry {
+    #[serde(default)]
+    pub author: String,
+    pub content: JobUsageContent,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct JobUsageContent {
+    pub sid: String,
+    #[serde(rename = "isDone", default)]
+    pub is_done: bool,
+    #[serde(rename = "isRealTimeSearch", default)]
+    pub is_realtime: bool,
+    #[serde(
+        rename = "diskUsage",
+        default,
+        deserialize_with = "crate::serde_helpers::usize_from_string_or_number"
+    )]
+    pub disk_usage: usize,
+}
+
+/// One search job's age and state, used to select old jobs for pruning.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JobAge {
+    pub sid: String,
+    /// User who dispatched the job
+    pub owner: String,
+    pub label: Option<String>,
+    pub is_done: bool,
+    pub dispatch_state: Option<String>,
+    /// When the job was created (RFC 3339), if Splunk reported it
+    pub published: Option<String>,
+}
+
+/// Search job list response, reduced to the fields needed for [`JobAge`].
+#[derive(Debug, Deserialize, Clone)]
+pub struct JobAgeListResponse {
+    #[serde(default)]
+    pub entry: Vec<JobAgeEntry>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct JobAgeEntry {
+    #[serde(default)]
+    pub author: String,
+    #[serde(default)]
+    pub published: Option<String>,
+    pub content: JobAgeContent,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct JobAgeContent {
+    pub sid: String,
+    #[serde(default)]
+    pub label: Option<String>,
+    #[serde(rename = "isDone", default)]
+    pub is_done: bool,
+    #[serde(rename = "dispatchState", default)]
+    pub dispatch_state: Option<String>,
+}
+
 /// Search job list response.
 #[derive(Debug, Deserialize, Clone)]
 pub struct SearchJobListResponse {
@@ -101,6 +186,11 @@ pub struct JobContent {
     pub priority: Option<i32>,
     #[serde(default)]
     pub label: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::opt_usize_from_string_or_number"
+    )]
+    pub ttl: Option<usize>,
 }
 
 /// Search job status (detailed).
@@ -144,6 +234,243 @@ pub struct SearchJobStatus {
     #[serde(rename = "priority")]
     pub priority: Option<i32>,
     pub label: Option<String>,
+    /// Seconds remaining before the job artifacts expire.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::opt_usize_from_string_or_number"
+    )]
+    pub ttl: Option<usize>,
+    /// Per-component execution counters, when the endpoint reports them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performance: Option<JobPerformance>,
+}
+
+impl SearchJobStatus {
+    /// Whether the job's artifacts expire in less than `secs` seconds.
+    ///
+    /// Jobs whose TTL is unknown are never considered expiring.
+    pub fn ttl_expires_within(&self, secs: usize) -> bool {
+        self.ttl.is_some_and(|ttl| ttl < secs)
+    }
+}
+
+/// Execution counters for one component of a job's `performance` map.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PerformanceCounters {
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::f64_from_string_or_number"
+    )]
+    pub duration_secs: f64,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::usize_from_string_or_number"
+    )]
+    pub invocations: usize,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::usize_from_string_or_number"
+    )]
+    pub input_count: usize,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::usize_from_string_or_number"
+    )]
+    pub output_count: usize,
+}
+
+/// A named performance component (a command, dispatch phase, or remote peer).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PerformanceEntry {
+    pub name: String,
+    #[serde(flatten)]
+    pub counters: PerformanceCounters,
+}
+
+/// Parsed `performance` map of a search job, keyed by component name.
+///
+/// Splunk reports `command.*` entries for each SPL command, `dispatch.*`
+/// entries for dispatch phases, and `dispatch.stream.remote.<peer>` entries
+/// for each search peer that streamed results back. Entries that are not
+/// counter objects are ignored rather than failing the whole job status.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(from = "BTreeMap<String, serde_json::Value>")]
+pub struct JobPerformance {
+    pub components: BTreeMap<String, PerformanceCounters>,
+}
+
+impl From<BTreeMap<String, serde_json::Value>> for JobPerformance {
+    fn from(raw: BTreeMap<String, serde_json::Value>) -> Self {
+        let components = raw
+            .into_iter()
+            .filter_map(|(name, value)| Some((name, serde_json::from_value(value).ok()?)))
+            .collect();
+        Self { components }
+    }
+}
+
+impl JobPerformance {
+    /// Prefix of the per-peer remote streaming entries.
+    const REMOTE_PEER_PREFIX: &'static str = "dispatch.stream.remote.";
+
+    /// Whether no components were reported.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// `command.*` timings, slowest first.
+    pub fn commands(&self) -> Vec<PerformanceEntry> {
+        self.entries(|name| name.starts_with("command.").then_some(name))
+    }
+
+    /// `dispatch.*` phase timings (excluding per-peer entries), slowest first.
+    pub fn dispatch_phases(&self) -> Vec<PerformanceEntry> {
+        self.entries(|name| {
+            (name.starts_with("dispatch.") && !name.starts_with(Self::REMOTE_PEER_PREFIX))
+                .then_some(name)
+        })
+    }
+
+    /// Per-peer remote streaming counters, named by peer, slowest first.
+    pub fn peers(&self) -> Vec<PerformanceEntry> {
+        self.entries(|name| name.strip_prefix(Self::REMOTE_PEER_PREFIX))
+    }
+
+    fn entries<'a>(&'a self, select: impl Fn(&'a str) -> Option<&'a str>) -> Vec<PerformanceEntry> {
+        let mut entries: Vec<_> = self
+            .components
+            .iter()
+            .filter_map(|(name, counters)| {
+                Some(PerformanceEntry {
+                    name: select(name)?.to_string(),
+                    counters: counters.clone(),
+                })
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            b.counters
+                .duration_secs
+                .total_cmp(&a.counters.duration_secs)
+        });
+        entries
+    }
+}
+
+/// Dispatch-state filter for job listings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatusFilter {
+    Running,
+    Done,
+    Failed,
+}
+
+impl JobStatusFilter {
+    /// All status filters, in display order.
+    pub const ALL: [JobStatusFilter; 3] = [Self::Running, Self::Done, Self::Failed];
+
+    /// The CLI/TUI spelling of the filter.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Running => "running",
+            Self::Done => "done",
+            Self::Failed => "failed",
+        }
+    }
+
+    /// The matching `dispatchState` value reported by Splunk.
+    pub const fn dispatch_state(self) -> &'static str {
+        match self {
+            Self::Running => "RUNNING",
+            Self::Done => "DONE",
+            Self::Failed => "FAILED",
+        }
+    }
+}
+
+impl fmt::Display for JobStatusFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for JobStatusFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.trim().to_ascii_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|status| status.as_str() == lower)
+            .ok_or_else(|| {
+                format!(
+                    "Invalid job status '{}'. Valid values: {}",
+                    s,
+                    Self::ALL.map(Self::as_str).join(", ")
+                )
+            })
+    }
+}
+
+/// Server-side filter for `/services/search/jobs`.
+///
+/// Translated into the endpoint's `search` parameter, so filtering happens
+/// before pagination rather than on the page already fetched.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct JobFilter {
+    /// Only jobs owned by this user.
+    pub owner: Option<String>,
+    /// Only jobs dispatched from this app.
+    pub app: Option<String>,
+    /// Only jobs in this dispatch state.
+    pub status: Option<JobStatusFilter>,
+}
+
+impl JobFilter {
+    /// Whether the filter matches every job.
+    pub fn is_empty(&self) -> bool {
+        self.owner.is_none() && self.app.is_none() && self.status.is_none()
+    }
+
+    /// The `search` query parameter value, or `None` when the filter is empty.
+    pub fn to_search_query(&self) -> Option<String> {
+        let mut terms = Vec::new();
+        if let Some(owner) = &self.owner {
+            terms.push(format!("eai:acl.owner={}", quote_search_value(owner)));
+        }
+        if let Some(app) = &self.app {
+            terms.push(format!("eai:acl.app={}", quote_search_value(app)));
+        }
+        if let Some(status) = self.status {
+            terms.push(format!("dispatchState={}", status.dispatch_state()));
+        }
+        (!terms.is_empty()).then(|| terms.join(" "))
+    }
+}
+
+impl fmt::Display for JobFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(owner) = &self.owner {
+            parts.push(format!("owner={}", owner));
+        }
+        if let Some(app) = &self.app {
+            parts.push(format!("app={}", app));
+        }
+        if let Some(status) = self.status {
+            parts.push(format!("status={}", status));
+        }
+        f.write_str(&parts.join(" "))
+    }
+}
+
+/// Quote a filter value when it contains characters the search parser splits on.
+fn quote_search_value(value: &str) -> String {
+    if value.is_empty() || value.contains(|c: char| c.is_whitespace() || c == '"' || c == '=') {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        value.to_string()
+    }
 }
 
 /// Search job results.
@@ -232,6 +559,57 @@ mod tests {
         assert_eq!(status.sid, "test-sid");
         assert!(status.is_done);
         assert_eq!(status.result_count, 100);
+        assert_eq!(status.ttl, None);
+    }
+
+    #[test]
+    fn test_ttl_expires_within() {
+        let json = r#"{"sid": "test-sid", "ttl": "300"}"#;
+        let status: SearchJobStatus = serde_json::from_str(json).unwrap();
+        assert_eq!(status.ttl, Some(300));
+        assert!(status.ttl_expires_within(600));
+        assert!(!status.ttl_expires_within(300));
+
+        let unknown: SearchJobStatus = serde_json::from_str(r#"{"sid": "x"}"#).unwrap();
+        assert!(!unknown.ttl_expires_within(600));
+    }
+
+    #[test]
+    fn test_deserialize_job_performance() {
+        let json = r#"{
+            "sid": "perf-sid",
+            "performance": {
+                "command.search": {"duration_secs": 0.4, "invocations": 12, "input_count": 0, "output_count": 900},
+                "command.stats": {"duration_secs": "1.25", "invocations": "3", "input_count": "900", "output_count": "5"},
+                "dispatch.fetch": {"duration_secs": 1.6, "invocations": 4},
+                "dispatch.stream.remote": {"duration_secs": 1.1, "invocations": 8, "output_count": 2048},
+                "dispatch.stream.remote.idx1": {"duration_secs": 0.7, "invocations": 5, "input_count": 600, "output_count": 1536},
+                "dispatch.stream.remote.idx2": {"duration_secs": 0.4, "invocations": 3, "input_count": 300, "output_count": 512},
+                "startup.handoff": "not a counter object"
+            }
+        }"#;
+        let status: SearchJobStatus = serde_json::from_str(json).unwrap();
+        let performance = status.performance.unwrap();
+
+        let commands = performance.commands();
+        assert_eq!(commands[0].name, "command.stats");
+        assert_eq!(commands[0].counters.duration_secs, 1.25);
+        assert_eq!(commands[0].counters.input_count, 900);
+        assert_eq!(commands[1].name, "command.search");
+
+        let phases: Vec<_> = performance
+            .dispatch_phases()
+            .into_iter()
+            .map(|e| e.name)
+            .collect();
+        assert_eq!(phases, vec!["dispatch.fetch", "dispatch.stream.remote"]);
+
+        let peers = performance.peers();
+        assert_eq!(peers.len(), 2);
+        assert_eq!(peers[0].name, "idx1");
+        assert_eq!(peers[0].counters.input_count, 600);
+
+        assert!(!performance.components.contains_key("startup.handoff"));
     }
 
     #[test]
@@ -313,4 +691,41 @@ mod tests {
         assert_eq!(error.line, None);
         assert_eq!(error.column, None);
     }
+
+    #[test]
+    fn test_job_filter_search_query() {
+        assert_eq!(JobFilter::default().to_search_query(), None);
+
+        let filter = JobFilter {
+            owner: Some("admin".to_string()),
+            app: Some("search".to_string()),
+            status: Some(JobStatusFilter::Failed),
+        };
+        assert_eq!(
+            filter.to_search_query().as_deref(),
+            Some("eai:acl.owner=admin eai:acl.app=search dispatchState=FAILED")
+        );
+        assert_eq!(filter.to_string(), "owner=admin app=search status=failed");
+    }
+
+    #[test]
+    fn test_job_filter_quotes_values_with_spaces() {
+        let filter = JobFilter {
+            owner: Some("jane doe".to_string()),
+            ..JobFilter::default()
+        };
+        assert_eq!(
+            filter.to_search_query().as_deref(),
+            Some("eai:acl.owner=\"jane doe\"")
+        );
+    }
+
+    #[test]
+    fn test_job_status_filter_from_str() {
+        assert_eq!(
+            "RUNNING".parse::<JobStatusFilter>().unwrap(),
+            JobStatusFilter::Running
+        );
+        assert!("paused".parse::<JobStatusFilter>().is_err());
+    }
 }
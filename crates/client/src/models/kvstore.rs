@@ -54,6 +54,14 @@ pub struct KvStoreReplicationStatus {
     pub oplog_size: usize,
     #[serde(rename = "oplogUsed")]
     pub oplog_used: f64,
+    /// Seconds the furthest-behind member trails the newest oplog entry, when
+    /// the members report their optimes.
+    #[serde(
+        rename = "replicationLagSecs",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub replication_lag_secs: Option<f64>,
 }
 
 /// KVStore status information.
@@ -9,10 +9,11 @@
 //! - License stacks (license grouping)
 //! - Installed licenses (license file management)
 //! - License installation and configuration parameters
+//! - Licenser messages, slave warning counts, and the violation countdown
 //!
 //! # What this module does NOT handle:
 //! - HTTP API calls (see `crate::endpoints::license`)
-//! - License violation handling or alerting
+//! - Alert delivery for license violations
 //! - License file parsing (.sla format internals)
 
 use serde::{Deserialize, Serialize};
@@ -125,6 +126,11 @@ impl LicenseUsage {
         }
     }
 
+    /// Share of the daily quota used, in percent; `None` when the stack has no quota.
+    pub fn usage_percent(&self) -> Option<f64> {
+        (self.quota > 0).then(|| self.effective_used_bytes() as f64 / self.quota as f64 * 100.0)
+    }
+
     /// Returns per-slave usage when Splunk provides a breakdown.
     pub fn slaves_breakdown(&self) -> Option<&HashMap<String, usize>> {
         match &self.slaves_usage_bytes {
@@ -230,6 +236,252 @@ pub struct LicenseActivationResult {
     pub message: String,
 }
 
+/// Number of license warnings in the rolling window that put a slave in violation.
+///
+/// Splunk Enterprise blocks search on a slave once it collects this many
+/// warnings within [`LICENSE_WARNING_WINDOW_DAYS`].
+pub const LICENSE_VIOLATION_WARNING_LIMIT: usize = 5;
+
+/// Length of the rolling window, in days, over which license warnings count.
+pub const LICENSE_WARNING_WINDOW_DAYS: usize = 30;
+
+/// Remaining warnings at or below which a violation is considered near.
+pub const LICENSE_VIOLATION_NEAR_THRESHOLD: usize = 2;
+
+/// Severity of a licenser message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+#[derive(Default)]
+pub enum LicenseMessageSeverity {
+    /// Informational message.
+    Info,
+    /// Warning (for example, a pool exceeded its quota today).
+    Warn,
+    /// Error (for example, a slave is in violation).
+    Error,
+    /// Unknown severity (fallback for unrecognized values).
+    #[serde(other)]
+    #[default]
+    Unknown,
+}
+
+impl fmt::Display for LicenseMessageSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+            Self::Unknown => "UNKNOWN",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// A message reported by the license manager (`/services/licenser/messages`).
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct LicenseMessage {
+    /// Message identifier
+    #[serde(default)]
+    pub name: String,
+    /// Message category (e.g., "pool_over_quota", "license_window")
+    #[serde(default)]
+    pub category: String,
+    /// Message severity
+    #[serde(default)]
+    pub severity: LicenseMessageSeverity,
+    /// Human-readable description
+    #[serde(default)]
+    pub description: String,
+    /// Creation time as Unix epoch seconds
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::opt_usize_from_string_or_number"
+    )]
+    pub create_time: Option<usize>,
+    /// Pool the message applies to
+    #[serde(default)]
+    pub pool_id: Option<String>,
+    /// Stack the message applies to
+    #[serde(default)]
+    pub stack_id: Option<String>,
+    /// Slave GUID the message applies to
+    #[serde(default)]
+    pub slave_id: Option<String>,
+}
+
+impl LicenseMessage {
+    /// Whether this message reports a pool or stack exceeding its quota.
+    pub fn is_over_quota(&self) -> bool {
+        self.category.contains("over_quota")
+    }
+}
+
+/// A license slave known to the license manager (`/services/licenser/slaves`).
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct LicenseSlave {
+    /// Slave GUID
+    #[serde(default)]
+    pub name: String,
+    /// Slave label (usually the server name)
+    #[serde(default)]
+    pub label: String,
+    /// Pools the slave is assigned to
+    #[serde(default)]
+    pub pool_ids: Vec<String>,
+    /// Pools currently drawing this slave's usage
+    #[serde(default)]
+    pub active_pool_ids: Vec<String>,
+    /// Stacks the slave is assigned to
+    #[serde(default)]
+    pub stack_ids: Vec<String>,
+    /// Warnings in the current rolling window
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::usize_from_string_or_number"
+    )]
+    pub warning_count: usize,
+}
+
+impl LicenseSlave {
+    /// Display name: the label when set, otherwise the GUID.
+    pub fn display_name(&self) -> &str {
+        if self.label.is_empty() {
+            &self.name
+        } else {
+            &self.label
+        }
+    }
+
+    /// Warnings left before the slave enters violation (zero once in violation).
+    pub fn warnings_until_violation(&self) -> usize {
+        LICENSE_VIOLATION_WARNING_LIMIT.saturating_sub(self.warning_count)
+    }
+
+    /// Whether the slave has reached the violation warning limit.
+    pub fn is_in_violation(&self) -> bool {
+        self.warnings_until_violation() == 0
+    }
+
+    /// Whether any over-quota message names this slave.
+    pub fn is_over_quota(&self, messages: &[LicenseMessage]) -> bool {
+        messages
+            .iter()
+            .any(|m| m.is_over_quota() && m.slave_id.as_deref() == Some(self.name.as_str()))
+    }
+}
+
+/// Violation countdown derived from licenser messages and slave warning counts.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct LicenseWarningSummary {
+    /// Highest warning count across slaves in the rolling window
+    pub warning_count: usize,
+    /// Warnings left before the worst slave enters violation
+    pub warnings_until_violation: usize,
+    /// Display names of slaves named by over-quota messages
+    pub over_quota_slaves: Vec<String>,
+    /// Display names of slaves at the violation limit
+    pub violating_slaves: Vec<String>,
+    /// Number of WARN messages
+    pub warning_messages: usize,
+    /// Number of ERROR messages
+    pub error_messages: usize,
+}
+
+impl LicenseWarningSummary {
+    /// Summarize messages and slaves into a violation countdown.
+    ///
+    /// Over-quota messages naming a slave GUID that is not in `slaves` are
+    /// reported by GUID.
+    pub fn from_parts(messages: &[LicenseMessage], slaves: &[LicenseSlave]) -> Self {
+        let warning_count = slaves.iter().map(|s| s.warning_count).max().unwrap_or(0);
+
+        let mut over_quota_slaves: Vec<String> = Vec::new();
+        for slave_id in messages
+            .iter()
+            .filter(|m| m.is_over_quota())
+            .filter_map(|m| m.slave_id.as_deref())
+        {
+            let name = slaves
+                .iter()
+                .find(|s| s.name == slave_id)
+                .map(|s| s.display_name())
+                .unwrap_or(slave_id)
+                .to_string();
+            if !over_quota_slaves.contains(&name) {
+                over_quota_slaves.push(name);
+            }
+        }
+
+        Self {
+            warning_count,
+            warnings_until_violation: LICENSE_VIOLATION_WARNING_LIMIT.saturating_sub(warning_count),
+            over_quota_slaves,
+            violating_slaves: slaves
+                .iter()
+                .filter(|s| s.is_in_violation())
+                .map(|s| s.display_name().to_string())
+                .collect(),
+            warning_messages: messages
+                .iter()
+                .filter(|m| m.severity == LicenseMessageSeverity::Warn)
+                .count(),
+            error_messages: messages
+                .iter()
+                .filter(|m| m.severity == LicenseMessageSeverity::Error)
+                .count(),
+        }
+    }
+
+    /// Whether any slave is in violation.
+    pub fn is_in_violation(&self) -> bool {
+        !self.violating_slaves.is_empty()
+    }
+
+    /// Whether a violation is near: few warnings left or a slave is over quota.
+    pub fn is_near_violation(&self) -> bool {
+        (self.warning_count > 0
+            && self.warnings_until_violation <= LICENSE_VIOLATION_NEAR_THRESHOLD)
+            || !self.over_quota_slaves.is_empty()
+    }
+}
+
+/// Number of days covered by the license usage trend.
+pub const LICENSE_USAGE_HISTORY_DAYS: u32 = 30;
+
+/// One day of indexed volume from the `license_usage.log` rollover summary.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct LicenseUsageDay {
+    /// Day the volume was indexed, as `YYYY-MM-DD`
+    pub day: String,
+    /// Bytes indexed that day across all pools and slaves
+    #[serde(deserialize_with = "crate::serde_helpers::usize_from_string_or_number")]
+    pub used_bytes: usize,
+    /// Stack quota in effect that day
+    #[serde(deserialize_with = "crate::serde_helpers::usize_from_string_or_number")]
+    pub quota_bytes: usize,
+}
+
+impl LicenseUsageDay {
+    /// Indexed volume in GB (1024^3 bytes), as license reports show it.
+    pub fn used_gb(&self) -> f64 {
+        bytes_to_gb(self.used_bytes)
+    }
+
+    /// Quota in GB (1024^3 bytes).
+    pub fn quota_gb(&self) -> f64 {
+        bytes_to_gb(self.quota_bytes)
+    }
+
+    /// Whether the day's volume exceeded a known quota.
+    pub fn is_over_quota(&self) -> bool {
+        self.quota_bytes > 0 && self.used_bytes > self.quota_bytes
+    }
+}
+
+fn bytes_to_gb(bytes: usize) -> f64 {
+    bytes as f64 / (1024.0 * 1024.0 * 1024.0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -487,4 +739,116 @@ mod tests {
         assert_eq!(license.license_type, LicenseType::Enterprise);
         assert_eq!(license.status, LicenseStatus::Unknown);
     }
+    // Licenser messages and slaves tests
+
+    fn slave(name: &str, label: &str, warning_count: usize) -> LicenseSlave {
+        LicenseSlave {
+            name: name.to_string(),
+            label: label.to_string(),
+            warning_count,
+            ..Default::default()
+        }
+    }
+
+    fn over_quota_message(slave_id: &str) -> LicenseMessage {
+        LicenseMessage {
+            category: "pool_over_quota".to_string(),
+            severity: LicenseMessageSeverity::Warn,
+            slave_id: Some(slave_id.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_license_message_deserialize() {
+        let json = r#"{
+            "category": "pool_over_quota",
+            "create_time": "1700000000",
+            "description": "pool auto_generated_pool_enterprise exceeded quota",
+            "pool_id": "auto_generated_pool_enterprise",
+            "severity": "WARN",
+            "slave_id": "ABCD",
+            "stack_id": "enterprise"
+        }"#;
+
+        let message: LicenseMessage = serde_json::from_str(json).unwrap();
+        assert_eq!(message.severity, LicenseMessageSeverity::Warn);
+        assert_eq!(message.create_time, Some(1700000000));
+        assert!(message.is_over_quota());
+
+        let unknown: LicenseMessage = serde_json::from_str(r#"{"severity": "DEBUG"}"#).unwrap();
+        assert_eq!(unknown.severity, LicenseMessageSeverity::Unknown);
+        assert!(!unknown.is_over_quota());
+    }
+
+    #[test]
+    fn test_license_slave_countdown() {
+        let healthy = slave("A", "idx1", 0);
+        assert_eq!(healthy.warnings_until_violation(), 5);
+        assert!(!healthy.is_in_violation());
+
+        let violating = slave("B", "", 7);
+        assert_eq!(violating.warnings_until_violation(), 0);
+        assert!(violating.is_in_violation());
+        assert_eq!(violating.display_name(), "B");
+    }
+
+    #[test]
+    fn test_license_slave_is_over_quota() {
+        let messages = vec![over_quota_message("A")];
+        assert!(slave("A", "idx1", 1).is_over_quota(&messages));
+        assert!(!slave("B", "idx2", 1).is_over_quota(&messages));
+    }
+
+    #[test]
+    fn test_warning_summary_from_parts() {
+        let slaves = vec![slave("A", "idx1", 3), slave("B", "idx2", 5)];
+        let messages = vec![
+            over_quota_message("A"),
+            over_quota_message("A"),
+            over_quota_message("ZZZ"),
+            LicenseMessage {
+                severity: LicenseMessageSeverity::Error,
+                ..Default::default()
+            },
+        ];
+
+        let summary = LicenseWarningSummary::from_parts(&messages, &slaves);
+        assert_eq!(summary.warning_count, 5);
+        assert_eq!(summary.warnings_until_violation, 0);
+        assert_eq!(summary.over_quota_slaves, vec!["idx1", "ZZZ"]);
+        assert_eq!(summary.violating_slaves, vec!["idx2"]);
+        assert_eq!(summary.warning_messages, 3);
+        assert_eq!(summary.error_messages, 1);
+        assert!(summary.is_in_violation());
+        assert!(summary.is_near_violation());
+    }
+
+    #[test]
+    fn test_warning_summary_near_violation_threshold() {
+        let quiet = LicenseWarningSummary::from_parts(&[], &[slave("A", "idx1", 0)]);
+        assert!(!quiet.is_near_violation());
+
+        let early = LicenseWarningSummary::from_parts(&[], &[slave("A", "idx1", 2)]);
+        assert!(!early.is_near_violation());
+
+        let near = LicenseWarningSummary::from_parts(&[], &[slave("A", "idx1", 3)]);
+        assert_eq!(near.warnings_until_violation, 2);
+        assert!(near.is_near_violation());
+        assert!(!near.is_in_violation());
+    }
+
+    #[test]
+    fn test_license_usage_day_from_search_row() {
+        let day: LicenseUsageDay = serde_json::from_value(serde_json::json!({
+            "day": "2024-01-15",
+            "used_bytes": "5368709120",
+            "quota_bytes": "4294967296"
+        }))
+        .unwrap();
+
+        assert_eq!(day.used_gb(), 5.0);
+        assert_eq!(day.quota_gb(), 4.0);
+        assert!(day.is_over_quota());
+    }
 }
@@ -0,0 +1,398 @@
+//! Cursor for incremental ("tail") reads of internal logs.
+//!
+//! Responsibilities:
+//! - Track the newest log entry seen (`LogCursor`)
+//! - Pick the entries of a freshly fetched batch that are newer than the cursor
+//!   and advance it (`take_new_logs`)
+//!
+//! Does NOT handle:
+//! - Polling or fetching (see `splunk-cli logs --tail` and the TUI internal logs follow mode)
+//!
+//! Invariants:
+//! - Entries are ordered by time, index time, then serial; entries without a
+//!   serial are told apart by a content hash
+//! - The cursor always points at the newest entry returned so far, so re-fetching
+//!   from `cursor.time` never yields duplicates
+
+use crate::models::logs::{LogEntry, sort_logs_newest_first};
+
+/// Cursor for tracking log position during tailing.
+#[derive(Debug, Clone)]
+pub struct LogCursor {
+    pub time: String,
+    pub index_time: String,
+    pub serial: Option<usize>,
+    /// Content hash, for entries without a serial
+    pub content_hash: Option<usize>,
+}
+
+impl LogCursor {
+    /// Returns true if the log entry is NEWER than this cursor.
+    pub fn is_after(&self, entry: &LogEntry) -> bool {
+        // Compare by timestamp first
+        if entry.time != self.time {
+            return entry.time > self.time;
+        }
+        // Same timestamp: compare by index_time
+        if entry.index_time != self.index_time {
+            return entry.index_time > self.index_time;
+        }
+        // Same index_time: compare by serial or content hash
+        match (self.serial, entry.serial) {
+            (Some(s), Some(e)) => e > s,
+            (None, Some(_)) => {
+                // Cursor has no serial but entry does - entry is newer
+                true
+            }
+            (Some(_), None) => {
+                // Cursor has serial but entry doesn't - need content comparison
+                // Compare by content hash to detect if same entry
+                let entry_hash = entry.content_hash();
+                self.content_hash != Some(entry_hash)
+            }
+            (None, None) => {
+                // Neither has serial - compare by content hash
+                let entry_hash = entry.content_hash();
+                self.content_hash != Some(entry_hash)
+            }
+        }
+    }
+
+    /// Create a new cursor from a log entry.
+    pub fn from_entry(entry: &LogEntry) -> Self {
+        LogCursor {
+            time: entry.time.clone(),
+            index_time: entry.index_time.clone(),
+            serial: entry.serial,
+            content_hash: entry.serial.is_none().then(|| entry.content_hash()),
+        }
+    }
+}
+
+/// Returns the entries of `logs` newer than `cursor`, newest first, and moves
+/// the cursor to the newest of them.
+///
+/// With no cursor every entry is new.
+pub fn take_new_logs(cursor: &mut Option<LogCursor>, logs: &[LogEntry]) -> Vec<LogEntry> {
+    let mut new_logs: Vec<LogEntry> = match cursor {
+        Some(c) => logs.iter().filter(|l| c.is_after(l)).cloned().collect(),
+        None => logs.to_vec(),
+    };
+
+    // Sort before updating the cursor: the API returns sorted results, but the
+    // cursor must land on the newest entry even if that ever changes.
+    sort_logs_newest_first(&mut new_logs);
+
+    // Update cursor to the NEWEST new log (first in sorted descending list)
+    // This prevents re-querying same-timestamp events on next poll
+    if let Some(newest_new) = new_logs.first() {
+        *cursor = Some(LogCursor::from_entry(newest_new));
+    }
+    new_logs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::LogLevel;
+
+    fn make_log_entry(time: &str, index_time: &str, serial: Option<usize>) -> LogEntry {
+        LogEntry {
+            time: time.to_string(),
+            index_time: index_time.to_string(),
+            serial,
+            level: LogLevel::Info,
+            component: "test".to_string(),
+            message: "test message".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_cursor_is_after_same_timestamp_different_serial() {
+        let cursor = LogCursor {
+            time: "2025-01-24T12:00:00.000Z".to_string(),
+            index_time: "2025-01-24T12:00:01.000Z".to_string(),
+            serial: Some(100),
+            content_hash: None,
+        };
+
+        let entry1 = make_log_entry(
+            "2025-01-24T12:00:00.000Z",
+            "2025-01-24T12:00:01.001Z",
+            Some(101),
+        );
+        let entry2 = make_log_entry(
+            "2025-01-24T12:00:00.000Z",
+            "2025-01-24T12:00:01.000Z",
+            Some(99),
+        );
+
+        assert!(cursor.is_after(&entry1)); // Newer by index_time
+        assert!(!cursor.is_after(&entry2)); // Older by serial
+    }
+
+    #[test]
+    fn test_cursor_is_after_different_timestamp() {
+        let cursor = LogCursor {
+            time: "2025-01-24T12:00:00.000Z".to_string(),
+            index_time: "2025-01-24T12:00:01.000Z".to_string(),
+            serial: Some(100),
+            content_hash: None,
+        };
+
+        let newer_entry = make_log_entry(
+            "2025-01-24T12:00:01.000Z",
+            "2025-01-24T12:00:02.000Z",
+            Some(50),
+        );
+
+        assert!(cursor.is_after(&newer_entry));
+    }
+
+    #[test]
+    fn test_cursor_with_missing_serial() {
+        let cursor = LogCursor {
+            time: "2025-01-24T12:00:00.000Z".to_string(),
+            index_time: "2025-01-24T12:00:01.000Z".to_string(),
+            serial: Some(100),
+            content_hash: None,
+        };
+
+        let entry_no_serial =
+            make_log_entry("2025-01-24T12:00:00.000Z", "2025-01-24T12:00:01.000Z", None);
+
+        // Cursor has serial but entry doesn't - use content hash comparison
+        // Since content differs, entry should be considered new
+        assert!(cursor.is_after(&entry_no_serial));
+    }
+
+    #[test]
+    fn test_cursor_from_entry() {
+        let entry = make_log_entry(
+            "2025-01-24T12:00:00.000Z",
+            "2025-01-24T12:00:01.000Z",
+            Some(42),
+        );
+
+        let cursor = LogCursor::from_entry(&entry);
+        assert_eq!(cursor.time, "2025-01-24T12:00:00.000Z");
+        assert_eq!(cursor.index_time, "2025-01-24T12:00:01.000Z");
+        assert_eq!(cursor.serial, Some(42));
+    }
+
+    #[test]
+    fn test_cursor_is_after_by_index_time_only() {
+        let cursor = LogCursor {
+            time: "2025-01-24T12:00:00.000Z".to_string(),
+            index_time: "2025-01-24T12:00:01.000Z".to_string(),
+            serial: Some(100),
+            content_hash: None,
+        };
+
+        // Same time, same serial, different index_time (edge case)
+        let entry_same_time_serial = make_log_entry(
+            "2025-01-24T12:00:00.000Z",
+            "2025-01-24T12:00:02.000Z",
+            Some(100),
+        );
+
+        assert!(cursor.is_after(&entry_same_time_serial));
+    }
+
+    #[test]
+    fn test_cursor_with_empty_index_time() {
+        // Cursor has valid index_time, entry has empty (missing) index_time
+        let cursor = LogCursor {
+            time: "2025-01-24T12:00:00.000Z".to_string(),
+            index_time: "2025-01-24T12:00:01.000Z".to_string(),
+            serial: Some(100),
+            content_hash: None,
+        };
+
+        let entry_empty_idx = make_log_entry("2025-01-24T12:00:00.000Z", "", None);
+
+        // Empty string is "less than" any valid timestamp, so entry is considered older
+        assert!(!cursor.is_after(&entry_empty_idx));
+
+        // Reverse: cursor has empty index_time, entry has valid
+        let cursor_empty = LogCursor {
+            time: "2025-01-24T12:00:00.000Z".to_string(),
+            index_time: "".to_string(),
+            serial: None,
+            content_hash: None,
+        };
+
+        let entry_valid_idx = make_log_entry(
+            "2025-01-24T12:00:00.000Z",
+            "2025-01-24T12:00:01.000Z",
+            Some(50),
+        );
+
+        // Valid index_time is "greater than" empty string, so entry is considered newer
+        assert!(cursor_empty.is_after(&entry_valid_idx));
+    }
+
+    #[test]
+    fn test_cursor_update_uses_newest_entry() {
+        // This test verifies fix for duplicate bug:
+        // When we have multiple new logs, cursor should update to the NEWEST one (first in list),
+        // not the oldest. This prevents re-querying same-timestamp events.
+
+        let cursor = LogCursor {
+            time: "2025-01-24T12:00:00.000Z".to_string(),
+            index_time: "2025-01-24T12:00:01.000Z".to_string(),
+            serial: Some(10),
+            content_hash: None,
+        };
+
+        // Simulate new logs returned from query (sorted descending by time, index_time, serial)
+        let new_logs = [
+            make_log_entry(
+                "2025-01-24T12:00:01.000Z",
+                "2025-01-24T12:00:02.000Z",
+                Some(30),
+            ),
+            make_log_entry(
+                "2025-01-24T12:00:01.000Z",
+                "2025-01-24T12:00:02.000Z",
+                Some(20),
+            ),
+            make_log_entry(
+                "2025-01-24T12:00:01.000Z",
+                "2025-01-24T12:00:02.000Z",
+                Some(15),
+            ),
+        ];
+
+        // All should be "after" current cursor
+        assert!(cursor.is_after(&new_logs[0]));
+        assert!(cursor.is_after(&new_logs[1]));
+        assert!(cursor.is_after(&new_logs[2]));
+
+        // Cursor should update to the FIRST (newest) entry
+        let new_cursor = LogCursor::from_entry(&new_logs[0]);
+        assert_eq!(new_cursor.serial, Some(30));
+
+        // On next query, none of these entries should be "after" new cursor
+        assert!(!new_cursor.is_after(&new_logs[0]));
+        assert!(!new_cursor.is_after(&new_logs[1]));
+        assert!(!new_cursor.is_after(&new_logs[2]));
+    }
+
+    #[test]
+    fn test_cursor_both_missing_serial_same_content() {
+        // Both cursor and entry lack serial, same content - should NOT be after
+        let entry = make_log_entry("2025-01-24T12:00:00.000Z", "2025-01-24T12:00:01.000Z", None);
+        let cursor = LogCursor::from_entry(&entry);
+
+        // Same entry - should NOT be after
+        assert!(!cursor.is_after(&entry));
+    }
+
+    #[test]
+    fn test_cursor_both_missing_serial_different_content() {
+        // Both cursor and entry lack serial, different content - should be after
+        let cursor_entry = LogEntry {
+            time: "2025-01-24T12:00:00.000Z".to_string(),
+            index_time: "2025-01-24T12:00:01.000Z".to_string(),
+            serial: None,
+            level: LogLevel::Info,
+            component: "test".to_string(),
+            message: "first message".to_string(),
+        };
+        let cursor = LogCursor::from_entry(&cursor_entry);
+
+        let new_entry = LogEntry {
+            time: "2025-01-24T12:00:00.000Z".to_string(),
+            index_time: "2025-01-24T12:00:01.000Z".to_string(),
+            serial: None,
+            level: LogLevel::Info,
+            component: "test".to_string(),
+            message: "different message".to_string(),
+        };
+
+        // Different content - should be after
+        assert!(cursor.is_after(&new_entry));
+    }
+
+    #[test]
+    fn test_cursor_missing_serial_entry_has_serial() {
+        // Cursor lacks serial but entry has it - entry is newer
+        let cursor = LogCursor {
+            time: "2025-01-24T12:00:00.000Z".to_string(),
+            index_time: "2025-01-24T12:00:01.000Z".to_string(),
+            serial: None,
+            content_hash: None,
+        };
+
+        let entry_with_serial = make_log_entry(
+            "2025-01-24T12:00:00.000Z",
+            "2025-01-24T12:00:01.000Z",
+            Some(100),
+        );
+
+        // Entry has serial but cursor doesn't - entry is newer
+        assert!(cursor.is_after(&entry_with_serial));
+    }
+
+    #[test]
+    fn test_cursor_from_entry_captures_content_hash_when_no_serial() {
+        let entry = LogEntry {
+            time: "2025-01-24T12:00:00.000Z".to_string(),
+            index_time: "2025-01-24T12:00:01.000Z".to_string(),
+            serial: None,
+            level: LogLevel::Info,
+            component: "test".to_string(),
+            message: "test message".to_string(),
+        };
+
+        let cursor = LogCursor::from_entry(&entry);
+
+        assert_eq!(cursor.time, "2025-01-24T12:00:00.000Z");
+        assert_eq!(cursor.index_time, "2025-01-24T12:00:01.000Z");
+        assert_eq!(cursor.serial, None);
+        assert!(cursor.content_hash.is_some());
+        assert_eq!(cursor.content_hash, Some(entry.content_hash()));
+    }
+
+    #[test]
+    fn test_cursor_from_entry_no_content_hash_when_has_serial() {
+        let entry = make_log_entry(
+            "2025-01-24T12:00:00.000Z",
+            "2025-01-24T12:00:01.000Z",
+            Some(42),
+        );
+
+        let cursor = LogCursor::from_entry(&entry);
+
+        assert_eq!(cursor.time, "2025-01-24T12:00:00.000Z");
+        assert_eq!(cursor.index_time, "2025-01-24T12:00:01.000Z");
+        assert_eq!(cursor.serial, Some(42));
+        assert!(cursor.content_hash.is_none());
+    }
+
+    #[test]
+    fn test_take_new_logs_skips_seen_entries_and_advances_cursor() {
+        let older = make_log_entry(
+            "2025-01-24T12:00:00.000Z",
+            "2025-01-24T12:00:01.000Z",
+            Some(1),
+        );
+        let newer = make_log_entry(
+            "2025-01-24T12:00:05.000Z",
+            "2025-01-24T12:00:06.000Z",
+            Some(2),
+        );
+        let mut cursor = None;
+
+        let first = take_new_logs(&mut cursor, std::slice::from_ref(&older));
+        assert_eq!(first.len(), 1);
+
+        let second = take_new_logs(&mut cursor, &[older.clone(), newer.clone()]);
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].serial, Some(2));
+        assert_eq!(cursor.as_ref().map(|c| c.serial), Some(Some(2)));
+
+        assert!(take_new_logs(&mut cursor, &[older, newer]).is_empty());
+    }
+}
@@ -4,9 +4,11 @@
 //! and health check aggregation.
 
 use serde::{Deserialize, Serialize};
+use splunk_config::{HealthThresholds, ThresholdLevel};
 use std::fmt;
 
 use crate::models::{
+    cluster::ClusterPeerHealth,
     kvstore::KvStoreStatus,
     license::LicenseUsage,
     server::{ServerInfo, SplunkHealth},
@@ -184,10 +186,80 @@ pub struct HealthCheckOutput {
     pub kvstore_status: Option<KvStoreStatus>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub log_parsing_health: Option<LogParsingHealth>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scheduler_health: Option<crate::models::SchedulerHealth>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub queue_health: Option<crate::models::QueueHealth>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_health: Option<crate::models::SecurityHealth>,
+    /// Peer counts, collected on a cluster manager.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cluster_peers: Option<ClusterPeerHealth>,
+    /// Fullest partition on the instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disk_partition: Option<crate::models::PartitionSpace>,
+    /// Running and queued searches against the concurrency limits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search_load: Option<crate::models::SearchLoad>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub circuit_breaker_states: Option<std::collections::HashMap<String, String>>,
 }
 
+impl HealthCheckOutput {
+    /// Describe each collected value that is past `thresholds`.
+    pub fn threshold_warnings(&self, thresholds: &HealthThresholds) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        for stack in self.license_usage.iter().flatten() {
+            let Some(pct) = stack.usage_percent() else {
+                continue;
+            };
+            let level = thresholds.license_level(pct);
+            if level != ThresholdLevel::Ok {
+                warnings.push(format!(
+                    "License stack {} is at {:.1}% of its daily quota ({})",
+                    stack.stack_id.as_deref().unwrap_or(&stack.name),
+                    pct,
+                    level
+                ));
+            }
+        }
+
+        if let Some(lag) = self
+            .kvstore_status
+            .as_ref()
+            .and_then(|status| status.replication_status.replication_lag_secs)
+            .filter(|lag| *lag > thresholds.kvstore_max_replication_lag_secs as f64)
+        {
+            warnings.push(format!(
+                "KVStore replication lag is {:.0}s (threshold {}s)",
+                lag, thresholds.kvstore_max_replication_lag_secs
+            ));
+        }
+
+        if let Some(peers) = self
+            .cluster_peers
+            .filter(|peers| peers.searchable < thresholds.min_searchable_peers as usize)
+        {
+            warnings.push(format!(
+                "Only {} of {} cluster peers are searchable (minimum {})",
+                peers.searchable, peers.total, thresholds.min_searchable_peers
+            ));
+        }
+
+        if let Some(partition) = &self.disk_partition {
+            let level = partition
+                .used_percent()
+                .map_or(ThresholdLevel::Ok, |pct| thresholds.disk_level(pct));
+            if level != ThresholdLevel::Ok {
+                warnings.push(crate::models::disk::partition_warning(partition, level));
+            }
+        }
+
+        warnings
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -680,4 +752,44 @@ mod tests {
         let error: LogParsingError = serde_json::from_str(json).unwrap();
         assert_eq!(error.log_level, LogLevel::Unknown);
     }
+
+    #[test]
+    fn test_threshold_warnings() {
+        let output: HealthCheckOutput = serde_json::from_value(serde_json::json!({
+            "license_usage": [
+                {"name": "a", "stack_id": "enterprise", "quota": 1000, "used_bytes": 750},
+                {"name": "b", "stack_id": "forwarder", "quota": 1000, "used_bytes": 100}
+            ],
+            "kvstore_status": {
+                "currentMember": {
+                    "guid": "g", "host": "sh1", "port": 8191,
+                    "replicaSet": "rs0", "status": "ready"
+                },
+                "replicationStatus": {"oplogSize": 1000, "oplogUsed": 1.0, "replicationLagSecs": 90.0}
+            },
+            "cluster_peers": {"total": 3, "searchable": 1, "down": 2}
+        }))
+        .unwrap();
+
+        let thresholds = HealthThresholds {
+            min_searchable_peers: 2,
+            ..Default::default()
+        };
+        assert_eq!(
+            output.threshold_warnings(&thresholds),
+            vec![
+                "License stack enterprise is at 75.0% of its daily quota (warning)",
+                "KVStore replication lag is 90s (threshold 60s)",
+                "Only 1 of 3 cluster peers are searchable (minimum 2)",
+            ]
+        );
+
+        let relaxed = HealthThresholds {
+            license_warning_pct: 80.0,
+            kvstore_max_replication_lag_secs: 120,
+            min_searchable_peers: 1,
+            ..Default::default()
+        };
+        assert!(output.threshold_warnings(&relaxed).is_empty());
+    }
 }
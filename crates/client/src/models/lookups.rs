@@ -8,6 +8,7 @@
 //! - ACL information for lookup tables
 //! - API response structures for lookup table listings
 //! - Lookup file upload parameters
+//! - Parsed lookup file contents (header and rows) for viewing and editing
 //!
 //! # What this module does NOT handle:
 //! - KV store lookups (different endpoint)
@@ -66,10 +67,79 @@ pub struct UploadLookupParams {
     pub sharing: Option<String>,
 }
 
+/// Parsed contents of a CSV lookup file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LookupContent {
+    /// Column names from the first CSV record
+    pub header: Vec<String>,
+    /// Data rows, each with one cell per header column
+    pub rows: Vec<Vec<String>>,
+}
+
+impl LookupContent {
+    /// Parse CSV lookup content, rejecting rows whose width differs from the header.
+    pub fn from_csv(content: &str) -> Result<Self, String> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader(content.as_bytes());
+        let mut records = reader.records();
+
+        let header = match records.next() {
+            Some(record) => record
+                .map_err(|e| e.to_string())?
+                .iter()
+                .map(str::to_string)
+                .collect(),
+            None => return Ok(Self::default()),
+        };
+        let rows = records
+            .map(|record| {
+                record
+                    .map(|r| r.iter().map(str::to_string).collect())
+                    .map_err(|e| e.to_string())
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { header, rows })
+    }
+
+    /// Serialize the header and rows back to CSV, quoting cells as needed.
+    pub fn to_csv(&self) -> String {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        for record in std::iter::once(&self.header).chain(&self.rows) {
+            // Writing to a Vec only fails on inconsistent record widths, which
+            // `from_csv` and the editors prevent.
+            let _ = writer.write_record(record);
+        }
+        let bytes = writer.into_inner().unwrap_or_default();
+        String::from_utf8_lossy(&bytes).into_owned()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_lookup_content_round_trips_quoted_cells() {
+        let csv = "host,owner\nweb-01,\"Ops, EU\"\ndb-01,\"say \"\"hi\"\"\"\n";
+        let content = LookupContent::from_csv(csv).unwrap();
+
+        assert_eq!(content.header, vec!["host", "owner"]);
+        assert_eq!(content.rows[0], vec!["web-01", "Ops, EU"]);
+        assert_eq!(content.rows[1], vec!["db-01", "say \"hi\""]);
+        assert_eq!(content.to_csv(), csv);
+    }
+
+    #[test]
+    fn test_lookup_content_rejects_ragged_rows() {
+        assert!(LookupContent::from_csv("a,b\n1,2,3\n").is_err());
+        assert_eq!(
+            LookupContent::from_csv("").unwrap(),
+            LookupContent::default()
+        );
+    }
+
     #[test]
     fn test_deserialize_lookup_table() {
         let json = r#"{
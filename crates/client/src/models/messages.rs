@@ -0,0 +1,197 @@
+//! Server message (bulletin board) models for Splunk messages API.
+//!
+//! This module contains types for the messages Splunk posts to its bulletin
+//! board: restart required, license warnings, disk space alerts and similar
+//! notices that Splunk Web shows under "Messages".
+//!
+//! # What this module handles:
+//! - Deserialization of `/services/messages` entries
+//! - Normalizing the message text, which older Splunk versions store under the
+//!   entry name instead of a `message` field
+//!
+//! # What this module does NOT handle:
+//! - Direct HTTP API calls (see [`crate::endpoints::messages`])
+//! - Tracking which messages a user has already seen (client-side concern)
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Severity of a server message, ordered from least to most severe.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase", from = "String")]
+pub enum ServerMessageSeverity {
+    /// Informational notice (also used for unrecognized severities).
+    #[default]
+    Info,
+    /// Warning that may need attention.
+    Warn,
+    /// Error that usually needs immediate attention.
+    Error,
+}
+
+impl From<String> for ServerMessageSeverity {
+    fn from(value: String) -> Self {
+        match value.to_ascii_lowercase().as_str() {
+            "error" | "critical" => Self::Error,
+            "warn" | "warning" => Self::Warn,
+            _ => Self::Info,
+        }
+    }
+}
+
+impl fmt::Display for ServerMessageSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+        };
+        f.pad(label)
+    }
+}
+
+/// A message posted to the Splunk bulletin board.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ServerMessage {
+    /// Message identifier (entry name from API), used to dismiss the message.
+    pub name: String,
+    /// Human-readable message text.
+    pub message: String,
+    /// Message severity.
+    pub severity: ServerMessageSeverity,
+    /// Server that posted the message.
+    pub server: Option<String>,
+    /// Help link or hint attached to the message.
+    pub help: Option<String>,
+    /// When the message was created (epoch seconds).
+    pub time_created: Option<u64>,
+}
+
+/// Raw content of a `/services/messages` entry.
+#[derive(Debug, Deserialize, Clone)]
+pub struct ServerMessageContent {
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub severity: ServerMessageSeverity,
+    #[serde(default)]
+    pub server: Option<String>,
+    #[serde(default)]
+    pub help: Option<String>,
+    #[serde(
+        default,
+        rename = "timeCreated_epochSecs",
+        deserialize_with = "crate::serde_helpers::opt_u64_from_string_or_number"
+    )]
+    pub time_created: Option<u64>,
+    /// Remaining fields; older Splunk versions put the text under the entry name.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+/// Server message entry wrapper.
+#[derive(Debug, Deserialize, Clone)]
+pub struct ServerMessageEntry {
+    pub name: String,
+    pub content: ServerMessageContent,
+}
+
+/// Server message list response.
+#[derive(Debug, Deserialize, Clone)]
+pub struct ServerMessageListResponse {
+    #[serde(default)]
+    pub entry: Vec<ServerMessageEntry>,
+}
+
+impl ServerMessage {
+    /// Whether this message tells the user splunkd must be restarted.
+    pub fn is_restart_required(&self) -> bool {
+        self.name.starts_with("restart_required")
+            || self
+                .message
+                .to_ascii_lowercase()
+                .contains("must be restarted")
+    }
+}
+
+impl From<ServerMessageEntry> for ServerMessage {
+    fn from(entry: ServerMessageEntry) -> Self {
+        let ServerMessageEntry { name, content } = entry;
+        let message = content
+            .message
+            .filter(|message| !message.is_empty())
+            .or_else(|| {
+                content
+                    .extra
+                    .get(&name)
+                    .and_then(|value| value.as_str())
+                    .map(str::to_string)
+            })
+            .unwrap_or_default();
+
+        Self {
+            name,
+            message,
+            severity: content.severity,
+            server: content.server,
+            help: content.help.filter(|help| !help.is_empty()),
+            time_created: content.time_created,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_server_message_entries() {
+        let json = r#"{
+            "entry": [
+                {
+                    "name": "restart_required",
+                    "content": {
+                        "restart_required": "Splunk must be restarted for changes to take effect.",
+                        "severity": "warn",
+                        "server": "sh1",
+                        "help": "",
+                        "timeCreated_epochSecs": "1700000000"
+                    }
+                },
+                {
+                    "name": "disk_space",
+                    "content": {
+                        "message": "Disk space below 5GB on /opt/splunk/var.",
+                        "severity": "critical",
+                        "timeCreated_epochSecs": 1700000100
+                    }
+                },
+                {
+                    "name": "notice",
+                    "content": {"message": "Welcome", "severity": "notice"}
+                }
+            ]
+        }"#;
+        let resp: ServerMessageListResponse = serde_json::from_str(json).unwrap();
+        let messages: Vec<ServerMessage> = resp.entry.into_iter().map(Into::into).collect();
+
+        assert_eq!(messages[0].name, "restart_required");
+        assert_eq!(
+            messages[0].message,
+            "Splunk must be restarted for changes to take effect."
+        );
+        assert_eq!(messages[0].severity, ServerMessageSeverity::Warn);
+        assert_eq!(messages[0].server.as_deref(), Some("sh1"));
+        assert_eq!(messages[0].help, None);
+        assert_eq!(messages[0].time_created, Some(1_700_000_000));
+        assert!(messages[0].is_restart_required());
+        assert!(!messages[1].is_restart_required());
+
+        assert_eq!(messages[1].severity, ServerMessageSeverity::Error);
+        assert_eq!(messages[1].time_created, Some(1_700_000_100));
+        assert_eq!(messages[2].severity, ServerMessageSeverity::Info);
+    }
+}
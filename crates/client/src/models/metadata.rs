@@ -0,0 +1,200 @@
+//! Index metadata models.
+//!
+//! These models describe the hosts, sources, and sourcetypes Splunk has
+//! indexed, as reported by the `| metadata` search command, together with
+//! the indexes each value appears in.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Kind of value reported by `| metadata type=<kind>`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetadataType {
+    Hosts,
+    Sources,
+    #[default]
+    Sourcetypes,
+}
+
+impl MetadataType {
+    /// All supported metadata types.
+    pub const ALL: [MetadataType; 3] = [Self::Hosts, Self::Sources, Self::Sourcetypes];
+
+    /// The `type=` argument of the `| metadata` command.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Hosts => "hosts",
+            Self::Sources => "sources",
+            Self::Sourcetypes => "sourcetypes",
+        }
+    }
+
+    /// The event field holding the value, which `| metadata` also uses as
+    /// its column name.
+    pub const fn field(self) -> &'static str {
+        match self {
+            Self::Hosts => "host",
+            Self::Sources => "source",
+            Self::Sourcetypes => "sourcetype",
+        }
+    }
+}
+
+impl fmt::Display for MetadataType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// One host, source, or sourcetype with its event count and time range.
+///
+/// Deserialized from the rows of both the `| metadata` search and the
+/// per-value index lookup; [`MetadataEntry::attach_indexes`] merges them.
+/// Times are epoch seconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetadataEntry {
+    /// Host, source, or sourcetype name, depending on the metadata type.
+    pub name: String,
+    #[serde(
+        default,
+        alias = "totalCount",
+        deserialize_with = "crate::serde_helpers::u64_from_string_or_number"
+    )]
+    pub total_count: u64,
+    /// Timestamp of the oldest event.
+    #[serde(
+        default,
+        alias = "firstTime",
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::serde_helpers::opt_u64_from_string_or_number"
+    )]
+    pub first_time: Option<u64>,
+    /// Timestamp of the newest event.
+    #[serde(
+        default,
+        alias = "lastTime",
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::serde_helpers::opt_u64_from_string_or_number"
+    )]
+    pub last_time: Option<u64>,
+    /// When the newest event was indexed.
+    #[serde(
+        default,
+        alias = "recentTime",
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::serde_helpers::opt_u64_from_string_or_number"
+    )]
+    pub recent_time: Option<u64>,
+    /// Indexes holding events with this value, sorted by name.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::vec_string_from_string_or_seq"
+    )]
+    pub indexes: Vec<String>,
+}
+
+impl MetadataEntry {
+    /// Fill in `indexes` from the index lookup rows, matched by name, and
+    /// order the entries by name.
+    pub fn attach_indexes(
+        mut entries: Vec<MetadataEntry>,
+        index_rows: Vec<MetadataEntry>,
+    ) -> Vec<MetadataEntry> {
+        let mut indexes: HashMap<String, Vec<String>> = index_rows
+            .into_iter()
+            .map(|row| (row.name, row.indexes))
+            .collect();
+
+        for entry in &mut entries {
+            if let Some(mut found) = indexes.remove(&entry.name) {
+                found.sort();
+                found.dedup();
+                entry.indexes = found;
+            }
+        }
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        entries
+    }
+
+    /// Whether the newest event is older than `threshold_secs` before `now`.
+    ///
+    /// Entries with no known last event time count as stale.
+    pub fn is_stale(&self, now: u64, threshold_secs: u64) -> bool {
+        match self.last_time {
+            Some(last) => now.saturating_sub(last) > threshold_secs,
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_metadata_row_with_string_numbers() {
+        let json = r#"{
+            "name": "access_combined",
+            "type": "sourcetypes",
+            "totalCount": "125000",
+            "firstTime": "1700000000",
+            "lastTime": "1700086400",
+            "recentTime": "1700086405"
+        }"#;
+        let entry: MetadataEntry = serde_json::from_str(json).unwrap();
+        assert_eq!(entry.total_count, 125_000);
+        assert_eq!(entry.first_time, Some(1_700_000_000));
+        assert_eq!(entry.last_time, Some(1_700_086_400));
+        assert_eq!(entry.recent_time, Some(1_700_086_405));
+        assert!(entry.indexes.is_empty());
+    }
+
+    #[test]
+    fn test_attach_indexes_matches_by_name_and_sorts() {
+        let entry = |name: &str| MetadataEntry {
+            name: name.to_string(),
+            total_count: 1,
+            first_time: None,
+            last_time: None,
+            recent_time: None,
+            indexes: Vec::new(),
+        };
+        let index_row = |name: &str, indexes: &[&str]| MetadataEntry {
+            indexes: indexes.iter().map(|i| i.to_string()).collect(),
+            ..entry(name)
+        };
+
+        let entries = MetadataEntry::attach_indexes(
+            vec![entry("syslog"), entry("access_combined")],
+            vec![
+                index_row("access_combined", &["web", "main", "web"]),
+                index_row("orphan", &["main"]),
+            ],
+        );
+
+        let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["access_combined", "syslog"]);
+        assert_eq!(entries[0].indexes, vec!["main", "web"]);
+        assert!(entries[1].indexes.is_empty());
+    }
+
+    #[test]
+    fn test_is_stale_compares_last_event_time_to_threshold() {
+        let mut entry = MetadataEntry {
+            name: "web01".to_string(),
+            total_count: 10,
+            first_time: None,
+            last_time: Some(1_000),
+            recent_time: None,
+            indexes: Vec::new(),
+        };
+        assert!(!entry.is_stale(1_000 + 3_600, 3_600));
+        assert!(entry.is_stale(1_000 + 3_601, 3_600));
+
+        entry.last_time = None;
+        assert!(entry.is_stale(1_000, 3_600));
+    }
+}
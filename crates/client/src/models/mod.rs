@@ -4,6 +4,8 @@
 //! Types are organized by resource in submodules and re-exported here
 //! for convenient access.
 
+pub mod acl;
+pub mod acs;
 pub mod alerts;
 pub mod apps;
 pub mod audit;
@@ -14,26 +16,47 @@ pub mod common;
 pub mod configs;
 pub mod dashboards;
 pub mod datamodels;
+pub mod disk;
+pub mod event_types;
 pub mod forwarders;
 pub mod hec;
+pub mod hec_tokens;
 pub mod indexes;
+pub mod ingestion;
 pub mod inputs;
+pub mod introspection;
 pub mod jobs;
 pub mod kvstore;
 pub mod license;
+pub mod log_cursor;
 pub mod logs;
 pub mod lookups;
 pub mod macros;
+pub mod messages;
+pub mod metadata;
+pub mod queues;
 pub mod roles;
 pub mod saved_searches;
+pub mod scheduler;
+pub mod search_load;
 pub mod search_peers;
+pub mod security;
 pub mod server;
 pub mod shc;
 pub mod users;
 pub mod workload;
 
 // Re-exports for backward compatibility
-pub use alerts::{AlertConfig, AlertSeverity, FiredAlert, FiredAlertEntry, FiredAlertListResponse};
+pub use acl::{AclUpdateParams, KnowledgeObjectKind, ObjectAcl};
+pub use acs::{
+    AcsHecToken, AcsHecTokenCreateParams, AcsHecTokenEntry, AcsHecTokenListResponse,
+    AcsHecTokenResponse, AcsHecTokenSpec, AcsIndex, AcsIndexCreateParams,
+};
+pub use alerts::{
+    AlertAction, AlertActionDispatch, AlertActionEntry, AlertActionListResponse, AlertConfig,
+    AlertSeverity, FiredAlert, FiredAlertEntry, FiredAlertListResponse, SavedSearchAlertActions,
+    TriggeredAlertAction,
+};
 pub use apps::{App, AppEntry, AppListResponse};
 pub use audit::{
     AuditAction, AuditEvent, AuditEventEntry, AuditEventListResponse, AuditResult,
@@ -43,26 +66,51 @@ pub use audit::{
 pub use auth::AuthResponse;
 pub use capabilities::{Capability, CapabilityEntry, CapabilityListResponse};
 pub use cluster::{
-    ClusterInfo, ClusterManagementResponse, ClusterMode, ClusterPeer, ClusterStatus,
+    ClusterBucketHealth, ClusterCopySlot, ClusterFixupContent, ClusterFixupLevel,
+    ClusterFixupReason, ClusterFixupTask, ClusterIndexBucketSummary, ClusterIndexCompliance,
+    ClusterIndexCopies, ClusterIndexExcess, ClusterInfo, ClusterManagementResponse, ClusterMode,
+    ClusterNodeRole, ClusterNodeVersion, ClusterPeer, ClusterPeerHealth, ClusterSearchHead,
+    ClusterStatus, ClusterVersionReport, ClusterVersionSkew, DataRebalanceAction,
     DecommissionPeerParams, MaintenanceModeParams, PeerState, PeerStatus, RemovePeersParams,
     ReplicationStatus,
 };
-pub use common::{Acl, Entry, MessageType, Perms, SplunkMessage, SplunkMessages, SplunkResponse};
+pub use common::{
+    Acl, Entry, MessageType, Namespace, Perms, SplunkMessage, SplunkMessages, SplunkResponse,
+};
 pub use configs::{
-    ConfigFile, ConfigListResponse, ConfigStanza, ConfigStanzaEntry, SUPPORTED_CONFIG_FILES,
+    ConfigFile, ConfigLayer, ConfigListResponse, ConfigScope, ConfigStanza, ConfigStanzaEntry,
+    SUPPORTED_CONFIG_FILES,
+};
+pub use dashboards::{Dashboard, DashboardEntry, DashboardFormat, DashboardListResponse};
+pub use datamodels::{
+    DataModel, DataModelEntry, DataModelListResponse, DataModelObject, TSTATS_FUNCTIONS,
+    TstatsAggregate, TstatsQuery,
 };
-pub use dashboards::{Dashboard, DashboardEntry, DashboardListResponse};
-pub use datamodels::{DataModel, DataModelEntry, DataModelListResponse};
+pub use disk::{DiskUsage, PartitionSpace};
+pub use event_types::{EventType, EventTypeEntry, EventTypeListResponse};
 pub use forwarders::{Forwarder, ForwarderEntry, ForwarderListResponse};
 pub use hec::{
     HecAckRequest, HecAckStatus, HecBatchResponse, HecError, HecEvent, HecHealth, HecResponse,
     SendBatchParams,
 };
-pub use indexes::{CreateIndexParams, Index, IndexEntry, IndexListResponse, ModifyIndexParams};
-pub use inputs::{Input, InputEntry, InputListResponse, InputType};
+pub use hec_tokens::{
+    HecToken, HecTokenCreateParams, HecTokenEntry, HecTokenListResponse, HecTokenUpdateParams,
+};
+pub use indexes::{
+    CreateIndexParams, FreezeProjection, Index, IndexEntry, IndexListResponse, ModifyIndexParams,
+};
+pub use ingestion::{IngestionGroupBy, IngestionStat, IngestionStats};
+pub use inputs::{
+    CreateInputParams, Input, InputEntry, InputListResponse, InputThroughput, InputType, NewInput,
+    SourceThroughput, ThroughputReport,
+};
+pub use introspection::{HostResourceUsage, ProcessResourceUsage, ResourceUsage};
 pub use jobs::{
-    JobContent, JobEntry, SearchJob, SearchJobListResponse, SearchJobResults, SearchJobStatus,
-    SplError, SplWarning, ValidateSplRequest, ValidateSplResponse,
+    JobAge, JobAgeContent, JobAgeEntry, JobAgeListResponse, JobContent, JobEntry, JobFilter,
+    JobPerformance, JobStatusFilter, JobUsage, JobUsageContent, JobUsageEntry,
+    JobUsageListResponse, PerformanceCounters, PerformanceEntry, SearchJob, SearchJobListResponse,
+    SearchJobResults, SearchJobStatus, SplError, SplWarning, ValidateSplRequest,
+    ValidateSplResponse,
 };
 pub use kvstore::{
     CollectionEntry, CollectionListResponse, CreateCollectionParams, KvStoreCollection,
@@ -70,18 +118,41 @@ pub use kvstore::{
     ModifyCollectionParams,
 };
 pub use license::{
-    CreatePoolParams, InstalledLicense, LicenseActivationResult, LicenseInstallResult, LicensePool,
-    LicenseStack, LicenseStatus, LicenseType, LicenseUsage, ModifyPoolParams, SlavesUsageBytes,
+    CreatePoolParams, InstalledLicense, LICENSE_USAGE_HISTORY_DAYS,
+    LICENSE_VIOLATION_NEAR_THRESHOLD, LICENSE_VIOLATION_WARNING_LIMIT, LICENSE_WARNING_WINDOW_DAYS,
+    LicenseActivationResult, LicenseInstallResult, LicenseMessage, LicenseMessageSeverity,
+    LicensePool, LicenseSlave, LicenseStack, LicenseStatus, LicenseType, LicenseUsage,
+    LicenseUsageDay, LicenseWarningSummary, ModifyPoolParams, SlavesUsageBytes,
 };
+pub use log_cursor::{LogCursor, take_new_logs};
 pub use logs::{HealthCheckOutput, LogEntry, LogLevel, LogParsingError, LogParsingHealth};
-pub use lookups::{LookupTable, LookupTableEntry, LookupTableListResponse, UploadLookupParams};
+pub use lookups::{
+    LookupContent, LookupTable, LookupTableEntry, LookupTableListResponse, UploadLookupParams,
+};
 pub use macros::{Macro, MacroCreateParams, MacroEntry, MacroListResponse, MacroUpdateParams};
-pub use roles::{CreateRoleParams, ModifyRoleParams, Role, RoleEntry, RoleListResponse};
+pub use messages::{
+    ServerMessage, ServerMessageContent, ServerMessageEntry, ServerMessageListResponse,
+    ServerMessageSeverity,
+};
+pub use metadata::{MetadataEntry, MetadataType};
+pub use queues::{PipelineQueueHealth, QUEUE_FILL_WARNING_PCT, QueueHealth};
+pub use roles::{
+    CapabilityGrant, CreateRoleParams, ModifyRoleParams, Role, RoleCapabilityMatrix, RoleEntry,
+    RoleListResponse, RoleUsage,
+};
 pub use saved_searches::{
     SavedSearch, SavedSearchCreateParams, SavedSearchEntry, SavedSearchListResponse,
-    SavedSearchUpdateParams,
+    SavedSearchOwnership, SavedSearchSchedule, SavedSearchUpdateParams,
+};
+pub use scheduler::{ScheduledSearchHealth, SchedulerHealth};
+pub use search_load::{SearchConcurrencyLimits, SearchLoad, UserSearchUsage};
+pub use search_peers::{
+    AddSearchPeerParams, SearchPeer, SearchPeerEntry, SearchPeerListResponse, SearchPeerStatus,
+    search_peer_entry_name,
+};
+pub use security::{
+    DEFAULT_FAILED_LOGIN_THRESHOLD, FailedLoginAlert, FailedLoginSource, SecurityHealth,
 };
-pub use search_peers::{SearchPeer, SearchPeerEntry, SearchPeerListResponse, SearchPeerStatus};
 pub use server::{
     FeatureStatus, HealthFeature, HealthStatus, ServerInfo, ServerMode, SplunkHealth,
 };
@@ -89,7 +160,10 @@ pub use shc::{
     AddShcMemberParams, RemoveShcMemberParams, RollingRestartParams, SetCaptainParams, ShcCaptain,
     ShcConfig, ShcManagementResponse, ShcMember, ShcMemberStatus, ShcStatus,
 };
-pub use users::{CreateUserParams, ModifyUserParams, User, UserEntry, UserListResponse, UserType};
+pub use users::{
+    CreateUserParams, CurrentContext, ModifyUserParams, PasswordPolicy, User, UserEntry,
+    UserListResponse, UserType,
+};
 pub use workload::{
     SearchType, WorkloadPool, WorkloadPoolEntry, WorkloadPoolListResponse, WorkloadRule,
     WorkloadRuleEntry, WorkloadRuleListResponse,
@@ -0,0 +1,180 @@
+//! Ingestion queue health models.
+//!
+//! These models summarize the queue samples splunkd writes to `metrics.log`
+//! (`index=_internal source=*metrics.log* group=queue`) about every 30
+//! seconds: the fill level of each queue (parsing, aggregation, typing,
+//! indexing, ...) in each ingestion pipeline, and whether it was blocked.
+
+use serde::{Deserialize, Serialize};
+
+/// Average fill percentage above which a queue counts as sustained-full.
+///
+/// Brief spikes are normal; a queue that stays above this level across the
+/// whole window is backing up the pipeline in front of it.
+pub const QUEUE_FILL_WARNING_PCT: f64 = 80.0;
+
+/// Fill and blocking statistics for one queue in one ingestion pipeline.
+///
+/// Deserialized from the rows of the queue health search, where Splunk
+/// returns numbers as strings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PipelineQueueHealth {
+    pub host: String,
+    /// Ingestion pipeline set (`ingest_pipe`); `0` on single-pipeline instances.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::string_from_number_or_string"
+    )]
+    pub pipeline: String,
+    /// Queue name, e.g. `parsingqueue` or `indexqueue`.
+    pub queue: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::f64_from_string_or_number"
+    )]
+    pub avg_fill_pct: f64,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::f64_from_string_or_number"
+    )]
+    pub max_fill_pct: f64,
+    /// Samples in which the queue reported `blocked=true`.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::u64_from_string_or_number"
+    )]
+    pub blocked_count: u64,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::u64_from_string_or_number"
+    )]
+    pub samples: u64,
+}
+
+impl PipelineQueueHealth {
+    /// Whether the queue stayed above [`QUEUE_FILL_WARNING_PCT`] on average.
+    pub fn is_sustained_full(&self) -> bool {
+        self.avg_fill_pct > QUEUE_FILL_WARNING_PCT
+    }
+
+    /// Warning describing a sustained-full queue, or `None` if it is not.
+    pub fn warning(&self) -> Option<String> {
+        self.is_sustained_full().then(|| {
+            format!(
+                "{} pipeline {} {} averaged {:.1}% full (above {:.0}%)",
+                self.host, self.pipeline, self.queue, self.avg_fill_pct, QUEUE_FILL_WARNING_PCT
+            )
+        })
+    }
+}
+
+/// Queue fill and blocking summary for a time window.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueueHealth {
+    /// No queue was sustained-full or blocked in the window.
+    pub is_healthy: bool,
+    /// Earliest time of the window, as a Splunk relative time (e.g. `-15m`).
+    pub time_window: String,
+    pub blocked_count: u64,
+    /// Every queue seen in the window, fullest first.
+    pub queues: Vec<PipelineQueueHealth>,
+}
+
+impl QueueHealth {
+    /// Build the summary from per-queue rows, ordering the fullest first.
+    pub fn from_queues(time_window: String, mut queues: Vec<PipelineQueueHealth>) -> Self {
+        queues.sort_by(|a, b| {
+            b.avg_fill_pct
+                .total_cmp(&a.avg_fill_pct)
+                .then_with(|| a.host.cmp(&b.host))
+                .then_with(|| a.pipeline.cmp(&b.pipeline))
+                .then_with(|| a.queue.cmp(&b.queue))
+        });
+        let blocked_count = queues.iter().map(|q| q.blocked_count).sum();
+        let is_healthy = blocked_count == 0 && !queues.iter().any(|q| q.is_sustained_full());
+
+        Self {
+            is_healthy,
+            time_window,
+            blocked_count,
+            queues,
+        }
+    }
+
+    /// One warning per sustained-full queue, fullest first.
+    pub fn warnings(&self) -> Vec<String> {
+        self.queues.iter().filter_map(|q| q.warning()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queue(name: &str, avg_fill_pct: f64, blocked_count: u64) -> PipelineQueueHealth {
+        PipelineQueueHealth {
+            host: "idx01".to_string(),
+            pipeline: "0".to_string(),
+            queue: name.to_string(),
+            avg_fill_pct,
+            max_fill_pct: avg_fill_pct,
+            blocked_count,
+            samples: 30,
+        }
+    }
+
+    #[test]
+    fn test_deserialize_queue_row_with_string_numbers() {
+        let json = r#"{
+            "host": "idx01",
+            "pipeline": 1,
+            "queue": "typingqueue",
+            "avg_fill_pct": "85.25",
+            "max_fill_pct": "100",
+            "blocked_count": "3",
+            "samples": "30"
+        }"#;
+        let row: PipelineQueueHealth = serde_json::from_str(json).unwrap();
+        assert_eq!(row.pipeline, "1");
+        assert_eq!(row.avg_fill_pct, 85.25);
+        assert_eq!(row.blocked_count, 3);
+        assert!(row.is_sustained_full());
+    }
+
+    #[test]
+    fn test_from_queues_orders_fullest_first_and_totals_blocked() {
+        let health = QueueHealth::from_queues(
+            "-15m".to_string(),
+            vec![
+                queue("parsingqueue", 10.0, 0),
+                queue("indexqueue", 95.0, 2),
+                queue("typingqueue", 50.0, 1),
+            ],
+        );
+
+        let names: Vec<&str> = health.queues.iter().map(|q| q.queue.as_str()).collect();
+        assert_eq!(names, vec!["indexqueue", "typingqueue", "parsingqueue"]);
+        assert_eq!(health.blocked_count, 3);
+        assert!(!health.is_healthy);
+    }
+
+    #[test]
+    fn test_warnings_only_for_sustained_full_queues() {
+        let health = QueueHealth::from_queues(
+            "-15m".to_string(),
+            vec![queue("parsingqueue", 80.0, 0), queue("indexqueue", 80.5, 0)],
+        );
+
+        assert_eq!(
+            health.warnings(),
+            vec!["idx01 pipeline 0 indexqueue averaged 80.5% full (above 80%)".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_healthy_when_not_full_or_blocked() {
+        let health = QueueHealth::from_queues("-15m".to_string(), vec![queue("aggqueue", 12.0, 0)]);
+        assert!(health.is_healthy);
+        assert!(health.warnings().is_empty());
+    }
+}
@@ -1,9 +1,15 @@
 //! Role models for Splunk role management API.
 //!
-//! This module contains types for listing and managing Splunk roles.
+//! This module contains types for listing and managing Splunk roles, for
+//! correlating per-role search quotas with the current usage of each role's users,
+//! and for resolving each role's effective capabilities through imported roles.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 
 use serde::{Deserialize, Serialize};
 
+use super::{JobUsage, User};
+
 /// Splunk role information.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Role {
@@ -38,6 +44,27 @@ pub struct Role {
         deserialize_with = "crate::serde_helpers::opt_i32_from_string_or_number"
     )]
     pub cumulative_rt_srch_jobs_quota: Option<i32>,
+    /// Maximum concurrent historical searches per user
+    #[serde(
+        default,
+        rename = "srchJobsQuota",
+        deserialize_with = "crate::serde_helpers::opt_i32_from_string_or_number"
+    )]
+    pub srch_jobs_quota: Option<i32>,
+    /// Maximum concurrent real-time searches per user
+    #[serde(
+        default,
+        rename = "rtSrchJobsQuota",
+        deserialize_with = "crate::serde_helpers::opt_i32_from_string_or_number"
+    )]
+    pub rt_srch_jobs_quota: Option<i32>,
+    /// Maximum disk space (MB) a user's search artifacts may use
+    #[serde(
+        default,
+        rename = "srchDiskQuota",
+        deserialize_with = "crate::serde_helpers::opt_i32_from_string_or_number"
+    )]
+    pub srch_disk_quota: Option<i32>,
 }
 
 /// Role entry wrapper.
@@ -85,6 +112,237 @@ pub struct ModifyRoleParams {
     pub default_app: Option<String>,
 }
 
+/// A role's search quotas next to the current usage of the users holding it.
+///
+/// Per-user quotas (`srch_jobs_quota`, `rt_srch_jobs_quota`, `srch_disk_quota_mb`)
+/// are compared against the busiest user; cumulative quotas against the role-wide
+/// totals. A user's jobs count toward every role the user holds directly.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RoleUsage {
+    /// Role name
+    pub role: String,
+    /// Number of users holding the role
+    pub users: usize,
+    /// Per-user concurrent historical search quota
+    pub srch_jobs_quota: Option<i32>,
+    /// Per-user concurrent real-time search quota
+    pub rt_srch_jobs_quota: Option<i32>,
+    /// Per-user search artifact disk quota in MB
+    pub srch_disk_quota_mb: Option<i32>,
+    /// Role-wide concurrent historical search quota
+    pub cumulative_srch_jobs_quota: Option<i32>,
+    /// Role-wide concurrent real-time search quota
+    pub cumulative_rt_srch_jobs_quota: Option<i32>,
+    /// Running historical searches across all users holding the role
+    pub running_jobs: usize,
+    /// Running real-time searches across all users holding the role
+    pub running_rt_jobs: usize,
+    /// Most running historical searches owned by a single user
+    pub peak_user_jobs: usize,
+    /// Most running real-time searches owned by a single user
+    pub peak_user_rt_jobs: usize,
+    /// Largest search artifact disk usage of a single user, in MB
+    pub peak_user_disk_mb: u64,
+    /// Users currently at or over one of the per-user quotas
+    pub users_at_limit: usize,
+}
+
+/// Current search usage of one user.
+#[derive(Debug, Default, Clone, Copy)]
+struct UserSearchUsage {
+    running_jobs: usize,
+    running_rt_jobs: usize,
+    disk_bytes: u64,
+}
+
+impl RoleUsage {
+    /// Correlate roles, their users, and the jobs list into per-role usage.
+    ///
+    /// Jobs owned by accounts that are not in `users` (e.g. `nobody`) are ignored.
+    /// Quotas that are unset or `0` never count a user as at the limit.
+    pub fn correlate(roles: &[Role], users: &[User], jobs: &[JobUsage]) -> Vec<RoleUsage> {
+        let mut per_user: HashMap<&str, UserSearchUsage> = HashMap::new();
+        for job in jobs {
+            let usage = per_user.entry(job.owner.as_str()).or_default();
+            usage.disk_bytes += job.disk_usage as u64;
+            if !job.is_done {
+                if job.is_realtime {
+                    usage.running_rt_jobs += 1;
+                } else {
+                    usage.running_jobs += 1;
+                }
+            }
+        }
+
+        roles
+            .iter()
+            .map(|role| {
+                let mut usage = RoleUsage {
+                    role: role.name.clone(),
+                    srch_jobs_quota: role.srch_jobs_quota,
+                    rt_srch_jobs_quota: role.rt_srch_jobs_quota,
+                    srch_disk_quota_mb: role.srch_disk_quota,
+                    cumulative_srch_jobs_quota: role.cumulative_srch_jobs_quota,
+                    cumulative_rt_srch_jobs_quota: role.cumulative_rt_srch_jobs_quota,
+                    ..Default::default()
+                };
+                let at_limit = |used: u64, quota: Option<i32>| {
+                    quota.is_some_and(|q| q > 0 && used >= q as u64)
+                };
+
+                for user in users.iter().filter(|u| u.roles.contains(&role.name)) {
+                    usage.users += 1;
+                    let stats = per_user
+                        .get(user.name.as_str())
+                        .copied()
+                        .unwrap_or_default();
+                    let disk_mb = stats.disk_bytes.div_ceil(1024 * 1024);
+
+                    usage.running_jobs += stats.running_jobs;
+                    usage.running_rt_jobs += stats.running_rt_jobs;
+                    usage.peak_user_jobs = usage.peak_user_jobs.max(stats.running_jobs);
+                    usage.peak_user_rt_jobs = usage.peak_user_rt_jobs.max(stats.running_rt_jobs);
+                    usage.peak_user_disk_mb = usage.peak_user_disk_mb.max(disk_mb);
+
+                    if at_limit(stats.running_jobs as u64, role.srch_jobs_quota)
+                        || at_limit(stats.running_rt_jobs as u64, role.rt_srch_jobs_quota)
+                        || at_limit(disk_mb, role.srch_disk_quota)
+                    {
+                        usage.users_at_limit += 1;
+                    }
+                }
+                usage
+            })
+            .collect()
+    }
+}
+
+/// How a role holds a capability in a [`RoleCapabilityMatrix`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "grant", rename_all = "snake_case")]
+pub enum CapabilityGrant {
+    /// Assigned to the role itself.
+    Direct,
+    /// Inherited through imported roles; `via` is the nearest imported role assigning it.
+    Inherited { via: String },
+}
+
+impl CapabilityGrant {
+    /// Short cell label: `direct` or `via <role>`.
+    pub fn label(&self) -> String {
+        match self {
+            Self::Direct => "direct".to_string(),
+            Self::Inherited { via } => format!("via {via}"),
+        }
+    }
+}
+
+/// Effective capabilities of every role, including those inherited through
+/// imported roles (resolved recursively).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoleCapabilityMatrix {
+    /// Role names, sorted.
+    pub roles: Vec<String>,
+    /// Every capability held by at least one role, sorted.
+    pub capabilities: Vec<String>,
+    /// Per role, each effective capability and how it is granted.
+    pub grants: BTreeMap<String, BTreeMap<String, CapabilityGrant>>,
+}
+
+impl RoleCapabilityMatrix {
+    /// Resolve the effective capabilities of `roles`.
+    ///
+    /// Imported roles are walked breadth-first, so an inherited capability is
+    /// attributed to the nearest imported role that assigns it. Import cycles
+    /// are visited once and imported roles missing from `roles` are skipped.
+    pub fn from_roles(roles: &[Role]) -> Self {
+        let by_name: HashMap<&str, &Role> = roles.iter().map(|r| (r.name.as_str(), r)).collect();
+
+        let mut grants = BTreeMap::new();
+        for role in roles {
+            let mut effective: BTreeMap<String, CapabilityGrant> = role
+                .capabilities
+                .iter()
+                .map(|cap| (cap.clone(), CapabilityGrant::Direct))
+                .collect();
+
+            let mut visited: HashSet<&str> = HashSet::from([role.name.as_str()]);
+            let mut queue: VecDeque<&str> =
+                role.imported_roles.iter().map(String::as_str).collect();
+            while let Some(name) = queue.pop_front() {
+                if !visited.insert(name) {
+                    continue;
+                }
+                let Some(imported) = by_name.get(name) else {
+                    continue;
+                };
+                for cap in &imported.capabilities {
+                    effective
+                        .entry(cap.clone())
+                        .or_insert_with(|| CapabilityGrant::Inherited {
+                            via: imported.name.clone(),
+                        });
+                }
+                queue.extend(imported.imported_roles.iter().map(String::as_str));
+            }
+            grants.insert(role.name.clone(), effective);
+        }
+
+        let mut matrix = Self {
+            roles: grants.keys().cloned().collect(),
+            capabilities: Vec::new(),
+            grants,
+        };
+        matrix.collect_capabilities();
+        matrix
+    }
+
+    /// How `role` holds `capability`, if it does.
+    pub fn grant(&self, role: &str, capability: &str) -> Option<&CapabilityGrant> {
+        self.grants.get(role)?.get(capability)
+    }
+
+    /// Keep only the named roles; capabilities none of them hold are dropped.
+    pub fn retain_roles(&mut self, roles: &[String]) {
+        self.grants.retain(|name, _| roles.contains(name));
+        self.roles.retain(|name| roles.contains(name));
+        self.collect_capabilities();
+    }
+
+    /// Keep only capabilities matching `keep`.
+    pub fn retain_capabilities(&mut self, keep: impl Fn(&str) -> bool) {
+        for effective in self.grants.values_mut() {
+            effective.retain(|cap, _| keep(cap));
+        }
+        self.collect_capabilities();
+    }
+
+    /// One row per capability with a column per role holding the grant label
+    /// (empty when the role lacks the capability), for tabular output.
+    pub fn to_rows(&self) -> Vec<serde_json::Value> {
+        self.capabilities
+            .iter()
+            .map(|cap| {
+                let mut row = serde_json::Map::new();
+                row.insert("capability".to_string(), cap.clone().into());
+                for role in &self.roles {
+                    let label = self
+                        .grant(role, cap)
+                        .map(CapabilityGrant::label)
+                        .unwrap_or_default();
+                    row.insert(role.clone(), label.into());
+                }
+                serde_json::Value::Object(row)
+            })
+            .collect()
+    }
+
+    fn collect_capabilities(&mut self) {
+        let all: BTreeSet<&String> = self.grants.values().flat_map(|g| g.keys()).collect();
+        self.capabilities = all.into_iter().cloned().collect();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,6 +373,95 @@ mod tests {
         assert_eq!(role.cumulative_rt_srch_jobs_quota, Some(50));
     }
 
+    #[test]
+    fn test_deserialize_role_per_user_quotas() {
+        let json = r#"{
+            "name": "power",
+            "srchJobsQuota": "10",
+            "rtSrchJobsQuota": 20,
+            "srchDiskQuota": "500"
+        }"#;
+        let role: Role = serde_json::from_str(json).unwrap();
+        assert_eq!(role.srch_jobs_quota, Some(10));
+        assert_eq!(role.rt_srch_jobs_quota, Some(20));
+        assert_eq!(role.srch_disk_quota, Some(500));
+    }
+
+    fn role(name: &str, jobs: i32, rt_jobs: i32, disk_mb: i32) -> Role {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "srchJobsQuota": jobs,
+            "rtSrchJobsQuota": rt_jobs,
+            "srchDiskQuota": disk_mb,
+            "cumulativeSrchJobsQuota": 50
+        }))
+        .unwrap()
+    }
+
+    fn user(name: &str, roles: &[&str]) -> User {
+        serde_json::from_value(serde_json::json!({ "name": name, "roles": roles })).unwrap()
+    }
+
+    fn job(owner: &str, is_done: bool, is_realtime: bool, disk_mb: usize) -> JobUsage {
+        JobUsage {
+            sid: format!("{owner}-{disk_mb}"),
+            owner: owner.to_string(),
+            is_done,
+            is_realtime,
+            disk_usage: disk_mb * 1024 * 1024,
+        }
+    }
+
+    #[test]
+    fn test_role_usage_correlates_jobs_by_owner_roles() {
+        let roles = vec![role("user", 3, 6, 100), role("power", 10, 20, 500)];
+        let users = vec![
+            user("alice", &["user"]),
+            user("bob", &["user", "power"]),
+            user("carol", &["power"]),
+        ];
+        let jobs = vec![
+            job("alice", false, false, 10),
+            job("alice", false, false, 10),
+            job("alice", false, false, 10),
+            job("bob", false, false, 40),
+            job("bob", false, true, 0),
+            job("bob", true, false, 80),
+            job("nobody", false, false, 999),
+        ];
+
+        let usage = RoleUsage::correlate(&roles, &users, &jobs);
+        assert_eq!(usage.len(), 2);
+
+        let user_role = &usage[0];
+        assert_eq!(user_role.role, "user");
+        assert_eq!(user_role.users, 2);
+        assert_eq!(user_role.running_jobs, 4);
+        assert_eq!(user_role.running_rt_jobs, 1);
+        assert_eq!(user_role.peak_user_jobs, 3);
+        assert_eq!(user_role.peak_user_disk_mb, 120);
+        // alice hits the 3-job quota, bob exceeds the 100 MB disk quota
+        assert_eq!(user_role.users_at_limit, 2);
+        assert_eq!(user_role.cumulative_srch_jobs_quota, Some(50));
+
+        let power = &usage[1];
+        assert_eq!(power.users, 2);
+        assert_eq!(power.running_jobs, 1);
+        assert_eq!(power.peak_user_rt_jobs, 1);
+        assert_eq!(power.users_at_limit, 0);
+    }
+
+    #[test]
+    fn test_role_usage_zero_quota_is_not_a_limit() {
+        let roles = vec![role("unlimited", 0, 0, 0)];
+        let users = vec![user("alice", &["unlimited"])];
+        let jobs = vec![job("alice", false, false, 10)];
+
+        let usage = RoleUsage::correlate(&roles, &users, &jobs);
+        assert_eq!(usage[0].running_jobs, 1);
+        assert_eq!(usage[0].users_at_limit, 0);
+    }
+
     #[test]
     fn test_deserialize_role_with_optional_fields_missing() {
         let json = r#"{
@@ -143,4 +490,82 @@ mod tests {
         assert_eq!(role.name, "custom_power");
         assert_eq!(role.imported_roles, vec!["power", "user"]);
     }
+
+    fn role_with(name: &str, capabilities: &[&str], imported: &[&str]) -> Role {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "capabilities": capabilities,
+            "importedRoles": imported,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_capability_matrix_resolves_imports_recursively() {
+        let roles = vec![
+            role_with("user", &["search"], &[]),
+            role_with("power", &["schedule_search"], &["user"]),
+            role_with("admin", &["edit_users", "search"], &["power"]),
+        ];
+        let matrix = RoleCapabilityMatrix::from_roles(&roles);
+
+        assert_eq!(matrix.roles, vec!["admin", "power", "user"]);
+        assert_eq!(
+            matrix.capabilities,
+            vec!["edit_users", "schedule_search", "search"]
+        );
+        // Direct assignment wins over inheritance
+        assert_eq!(
+            matrix.grant("admin", "search"),
+            Some(&CapabilityGrant::Direct)
+        );
+        assert_eq!(
+            matrix.grant("admin", "schedule_search"),
+            Some(&CapabilityGrant::Inherited {
+                via: "power".to_string()
+            })
+        );
+        assert_eq!(
+            matrix.grant("power", "search").map(CapabilityGrant::label),
+            Some("via user".to_string())
+        );
+        assert_eq!(matrix.grant("user", "edit_users"), None);
+    }
+
+    #[test]
+    fn test_capability_matrix_tolerates_cycles_and_missing_imports() {
+        let roles = vec![
+            role_with("a", &["cap_a"], &["b", "ghost"]),
+            role_with("b", &["cap_b"], &["a"]),
+        ];
+        let matrix = RoleCapabilityMatrix::from_roles(&roles);
+
+        assert_eq!(matrix.grants["a"].len(), 2);
+        assert_eq!(
+            matrix.grant("b", "cap_a"),
+            Some(&CapabilityGrant::Inherited {
+                via: "a".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_capability_matrix_filters_and_rows() {
+        let roles = vec![
+            role_with("user", &["search", "rest_properties_get"], &[]),
+            role_with("auditor", &["edit_search_head_clustering"], &[]),
+            role_with("power", &["schedule_search"], &["user"]),
+        ];
+        let mut matrix = RoleCapabilityMatrix::from_roles(&roles);
+        matrix.retain_roles(&["power".to_string(), "user".to_string()]);
+        matrix.retain_capabilities(|cap| cap.contains("search"));
+
+        assert_eq!(matrix.capabilities, vec!["schedule_search", "search"]);
+        let rows = matrix.to_rows();
+        assert_eq!(
+            rows[1],
+            serde_json::json!({"capability": "search", "power": "via user", "user": "direct"})
+        );
+        assert_eq!(rows[0]["user"], "");
+    }
 }
@@ -50,4 +50,38 @@ pub struct SavedSearchUpdateParams {
     pub description: Option<String>,
     /// Enable/disable flag.
     pub disabled: Option<bool>,
+    /// New earliest time of the scheduled dispatch window (`dispatch.earliest_time`).
+    pub dispatch_earliest_time: Option<String>,
+    /// New latest time of the scheduled dispatch window (`dispatch.latest_time`).
+    pub dispatch_latest_time: Option<String>,
+}
+
+/// Scheduling fields of a saved search, used for ownership audits.
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct SavedSearchSchedule {
+    #[serde(default)]
+    pub is_scheduled: bool,
+    #[serde(default)]
+    pub cron_schedule: Option<String>,
+    #[serde(default)]
+    pub disabled: bool,
+}
+
+/// Ownership and schedule details of a saved search.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct SavedSearchOwnership {
+    /// The saved search name.
+    pub name: String,
+    /// The app the saved search belongs to.
+    pub app: String,
+    /// The current owner.
+    pub owner: String,
+    /// Sharing level (`user`, `app`, or `global`).
+    pub sharing: String,
+    /// Whether the saved search runs on a schedule.
+    pub is_scheduled: bool,
+    /// Cron schedule, if scheduled.
+    pub cron_schedule: Option<String>,
+    /// Whether the saved search is disabled.
+    pub disabled: bool,
 }
@@ -0,0 +1,177 @@
+//! Scheduled search health models.
+//!
+//! These models summarize the scheduler's own log (`index=_internal
+//! sourcetype=scheduler`), which records one event per scheduled run
+//! including runs the scheduler skipped.
+
+use serde::{Deserialize, Serialize};
+
+/// Run outcome counts for a single scheduled search over the report window.
+///
+/// Deserialized from the rows of the scheduler summary search, where Splunk
+/// returns the `stats` counts as strings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduledSearchHealth {
+    #[serde(default)]
+    pub app: String,
+    #[serde(alias = "savedsearch_name")]
+    pub name: String,
+    #[serde(default, alias = "user")]
+    pub owner: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::u64_from_string_or_number"
+    )]
+    pub runs: u64,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::u64_from_string_or_number"
+    )]
+    pub succeeded: u64,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::u64_from_string_or_number"
+    )]
+    pub skipped: u64,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::u64_from_string_or_number"
+    )]
+    pub failed: u64,
+    /// Most recent skip or failure reason logged by the scheduler.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_reason: Option<String>,
+    /// Time of the most recent run in the window.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_run: Option<String>,
+}
+
+impl ScheduledSearchHealth {
+    /// Whether any run in the window was skipped or failed.
+    pub fn has_problems(&self) -> bool {
+        self.skipped > 0 || self.failed > 0
+    }
+}
+
+/// Skipped and failed scheduled search summary for a time window.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchedulerHealth {
+    pub is_healthy: bool,
+    /// Earliest time of the window, as a Splunk relative time (e.g. `-24h`).
+    pub time_window: String,
+    pub total_runs: u64,
+    pub succeeded: u64,
+    pub skipped: u64,
+    pub failed: u64,
+    /// Searches with at least one skipped or failed run, worst first.
+    pub searches: Vec<ScheduledSearchHealth>,
+}
+
+impl SchedulerHealth {
+    /// Build the summary from per-search rows, totalling every row but keeping
+    /// only the searches that skipped or failed.
+    pub fn from_searches(time_window: String, searches: Vec<ScheduledSearchHealth>) -> Self {
+        let total_runs = searches.iter().map(|s| s.runs).sum();
+        let succeeded = searches.iter().map(|s| s.succeeded).sum();
+        let skipped = searches.iter().map(|s| s.skipped).sum();
+        let failed = searches.iter().map(|s| s.failed).sum();
+
+        let mut searches: Vec<ScheduledSearchHealth> =
+            searches.into_iter().filter(|s| s.has_problems()).collect();
+        searches.sort_by(|a, b| {
+            (b.skipped + b.failed)
+                .cmp(&(a.skipped + a.failed))
+                .then_with(|| a.app.cmp(&b.app))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+
+        Self {
+            is_healthy: skipped == 0 && failed == 0,
+            time_window,
+            total_runs,
+            succeeded,
+            skipped,
+            failed,
+            searches,
+        }
+    }
+
+    /// Percentage of runs in the window that were skipped.
+    pub fn skip_ratio(&self) -> f64 {
+        if self.total_runs == 0 {
+            0.0
+        } else {
+            self.skipped as f64 * 100.0 / self.total_runs as f64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(name: &str, runs: u64, skipped: u64, failed: u64) -> ScheduledSearchHealth {
+        ScheduledSearchHealth {
+            app: "search".to_string(),
+            name: name.to_string(),
+            owner: "admin".to_string(),
+            runs,
+            succeeded: runs - skipped - failed,
+            skipped,
+            failed,
+            last_reason: None,
+            last_run: None,
+        }
+    }
+
+    #[test]
+    fn test_deserialize_scheduler_row_with_string_counts() {
+        let json = r#"{
+            "app": "search",
+            "savedsearch_name": "Errors Last Hour",
+            "user": "admin",
+            "runs": "24",
+            "succeeded": "20",
+            "skipped": "3",
+            "failed": "1",
+            "last_reason": "The maximum number of concurrent running jobs for this historical scheduled search on this cluster has been reached",
+            "last_run": "2025-01-20T10:00:00+0000"
+        }"#;
+        let search: ScheduledSearchHealth = serde_json::from_str(json).unwrap();
+        assert_eq!(search.name, "Errors Last Hour");
+        assert_eq!(search.owner, "admin");
+        assert_eq!(search.runs, 24);
+        assert_eq!(search.skipped, 3);
+        assert_eq!(search.failed, 1);
+        assert!(search.has_problems());
+    }
+
+    #[test]
+    fn test_from_searches_totals_all_and_keeps_problems_worst_first() {
+        let health = SchedulerHealth::from_searches(
+            "-24h".to_string(),
+            vec![
+                row("clean", 10, 0, 0),
+                row("flaky", 10, 1, 0),
+                row("broken", 10, 2, 3),
+            ],
+        );
+
+        assert!(!health.is_healthy);
+        assert_eq!(health.total_runs, 30);
+        assert_eq!(health.succeeded, 24);
+        assert_eq!(health.skipped, 3);
+        assert_eq!(health.failed, 3);
+        let names: Vec<&str> = health.searches.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["broken", "flaky"]);
+        assert!((health.skip_ratio() - 10.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_from_searches_empty_window_is_healthy() {
+        let health = SchedulerHealth::from_searches("-1h".to_string(), Vec::new());
+        assert!(health.is_healthy);
+        assert_eq!(health.skip_ratio(), 0.0);
+        assert!(health.searches.is_empty());
+    }
+}
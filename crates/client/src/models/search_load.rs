@@ -0,0 +1,255 @@
+//! Search load models.
+//!
+//! These models combine the instance's search concurrency limits
+//! (`server/status/limits/search-concurrency`) with the searches currently
+//! running or queued per user, and the per-user job quota from their roles
+//! (`srchJobsQuota`), so a saturated search tier or a user stuck at quota
+//! stands out.
+
+use serde::{Deserialize, Serialize};
+
+/// Concurrent search limits splunkd derives from `limits.conf` and the CPU count.
+///
+/// splunkd returns the numbers as strings on some versions; a limit missing
+/// from the response is `None`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchConcurrencyLimits {
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::opt_u64_from_string_or_number"
+    )]
+    pub max_hist_searches: Option<u64>,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::opt_u64_from_string_or_number"
+    )]
+    pub max_hist_scheduled_searches: Option<u64>,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::opt_u64_from_string_or_number"
+    )]
+    pub max_rt_searches: Option<u64>,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::opt_u64_from_string_or_number"
+    )]
+    pub max_rt_scheduled_searches: Option<u64>,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::opt_u64_from_string_or_number"
+    )]
+    pub max_auto_summary_searches: Option<u64>,
+}
+
+/// Searches one user has running or queued.
+///
+/// Deserialized from the rows of the search load search, where Splunk
+/// returns numbers as strings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserSearchUsage {
+    pub user: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::u64_from_string_or_number"
+    )]
+    pub running: u64,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::u64_from_string_or_number"
+    )]
+    pub queued: u64,
+    /// Running searches that are real-time.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::u64_from_string_or_number"
+    )]
+    pub realtime: u64,
+    /// Highest `srchJobsQuota` of the user's roles; `None` when unknown or
+    /// unlimited (0).
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::opt_u64_from_string_or_number"
+    )]
+    pub quota: Option<u64>,
+}
+
+impl UserSearchUsage {
+    /// Whether the user has as many running searches as their quota allows.
+    pub fn is_at_quota(&self) -> bool {
+        self.quota.is_some_and(|quota| self.running >= quota)
+    }
+}
+
+/// Running and queued searches against the concurrency limits.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchLoad {
+    /// Nothing is queued, no concurrency limit is reached, and no user is at quota.
+    pub is_healthy: bool,
+    pub running: u64,
+    pub queued: u64,
+    /// Running searches that are real-time.
+    pub realtime: u64,
+    pub limits: SearchConcurrencyLimits,
+    /// Users with running or queued searches, most running first.
+    pub users: Vec<UserSearchUsage>,
+}
+
+impl SearchLoad {
+    /// Build the summary from the limits and per-user rows, ordering the busiest user first.
+    pub fn new(limits: SearchConcurrencyLimits, mut users: Vec<UserSearchUsage>) -> Self {
+        for user in &mut users {
+            // A zero quota means the role does not limit the user.
+            if user.quota == Some(0) {
+                user.quota = None;
+            }
+        }
+        users.sort_by(|a, b| {
+            b.running
+                .cmp(&a.running)
+                .then_with(|| b.queued.cmp(&a.queued))
+                .then_with(|| a.user.cmp(&b.user))
+        });
+        let running = users.iter().map(|u| u.running).sum();
+        let queued = users.iter().map(|u| u.queued).sum();
+        let realtime = users.iter().map(|u| u.realtime).sum();
+
+        let mut load = Self {
+            is_healthy: true,
+            running,
+            queued,
+            realtime,
+            limits,
+            users,
+        };
+        load.is_healthy = load.warnings().is_empty();
+        load
+    }
+
+    /// Running historical searches as a percentage of `max_hist_searches`.
+    pub fn historical_usage_percent(&self) -> Option<f64> {
+        self.limits
+            .max_hist_searches
+            .filter(|max| *max > 0)
+            .map(|max| self.running.saturating_sub(self.realtime) as f64 / max as f64 * 100.0)
+    }
+
+    /// Users whose running searches reached their quota.
+    pub fn users_at_quota(&self) -> impl Iterator<Item = &UserSearchUsage> {
+        self.users.iter().filter(|u| u.is_at_quota())
+    }
+
+    /// One warning for queued searches, one per reached concurrency limit,
+    /// and one per user at quota.
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if self.queued > 0 {
+            warnings.push(format!("{} search(es) queued", self.queued));
+        }
+        let historical = self.running.saturating_sub(self.realtime);
+        if let Some(max) = self
+            .limits
+            .max_hist_searches
+            .filter(|max| *max > 0 && historical >= *max)
+        {
+            warnings.push(format!(
+                "{} historical searches running, at the concurrency limit of {}",
+                historical, max
+            ));
+        }
+        if let Some(max) = self
+            .limits
+            .max_rt_searches
+            .filter(|max| *max > 0 && self.realtime >= *max)
+        {
+            warnings.push(format!(
+                "{} real-time searches running, at the concurrency limit of {}",
+                self.realtime, max
+            ));
+        }
+        for user in self.users_at_quota() {
+            warnings.push(format!(
+                "User {} is at their search quota ({} of {} running, {} queued)",
+                user.user,
+                user.running,
+                user.quota.unwrap_or_default(),
+                user.queued
+            ));
+        }
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(user: &str, running: u64, queued: u64, quota: Option<u64>) -> UserSearchUsage {
+        UserSearchUsage {
+            user: user.to_string(),
+            running,
+            queued,
+            realtime: 0,
+            quota,
+        }
+    }
+
+    #[test]
+    fn test_limits_deserialize_string_numbers() {
+        let json = r#"{
+            "max_hist_searches": "38",
+            "max_hist_scheduled_searches": 19,
+            "max_rt_searches": "38"
+        }"#;
+        let limits: SearchConcurrencyLimits = serde_json::from_str(json).unwrap();
+        assert_eq!(limits.max_hist_searches, Some(38));
+        assert_eq!(limits.max_hist_scheduled_searches, Some(19));
+        assert_eq!(limits.max_auto_summary_searches, None);
+    }
+
+    #[test]
+    fn test_search_load_flags_queue_and_quota() {
+        let load = SearchLoad::new(
+            SearchConcurrencyLimits {
+                max_hist_searches: Some(10),
+                ..Default::default()
+            },
+            vec![
+                usage("admin", 1, 0, Some(0)),
+                usage("analyst", 3, 2, Some(3)),
+                usage("svc_report", 4, 0, Some(10)),
+            ],
+        );
+
+        assert!(!load.is_healthy);
+        assert_eq!((load.running, load.queued), (8, 2));
+        assert_eq!(load.users[0].user, "svc_report");
+        assert_eq!(load.users[2].quota, None, "a zero quota is unlimited");
+        assert_eq!(load.historical_usage_percent(), Some(80.0));
+        assert_eq!(
+            load.warnings(),
+            vec![
+                "2 search(es) queued".to_string(),
+                "User analyst is at their search quota (3 of 3 running, 2 queued)".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_historical_limit_reached() {
+        let load = SearchLoad::new(
+            SearchConcurrencyLimits {
+                max_hist_searches: Some(2),
+                ..Default::default()
+            },
+            vec![usage("admin", 2, 0, None)],
+        );
+        assert_eq!(
+            load.warnings(),
+            vec!["2 historical searches running, at the concurrency limit of 2".to_string()]
+        );
+
+        let idle = SearchLoad::new(SearchConcurrencyLimits::default(), vec![]);
+        assert!(idle.is_healthy);
+        assert_eq!(idle.historical_usage_percent(), None);
+    }
+}
@@ -7,12 +7,13 @@
 //! # What this module handles:
 //! - Deserialization of search peer data from Splunk REST API
 //! - Type-safe representation of search peer metadata
+//! - Parameters for adding a search peer
 //!
 //! # What this module does NOT handle:
 //! - Direct HTTP API calls (see [`crate::endpoints::search_peers`])
 //! - Client-side filtering or searching of peers
-//! - Modifying search peer configuration
 
+use secrecy::SecretString;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -28,6 +29,8 @@ pub enum SearchPeerStatus {
     Up,
     /// The peer is unreachable or not responding.
     Down,
+    /// The peer is quarantined and excluded from new searches.
+    Quarantined,
     /// The peer status is unknown or unrecognized.
     #[serde(other)]
     #[default]
@@ -39,6 +42,7 @@ impl fmt::Display for SearchPeerStatus {
         match self {
             SearchPeerStatus::Up => write!(f, "Up"),
             SearchPeerStatus::Down => write!(f, "Down"),
+            SearchPeerStatus::Quarantined => write!(f, "Quarantined"),
             SearchPeerStatus::Unknown => write!(f, "Unknown"),
         }
     }
@@ -77,6 +81,31 @@ pub struct SearchPeer {
     pub disabled: Option<bool>,
 }
 
+/// Parameters for adding a distributed search peer.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AddSearchPeerParams {
+    /// Management URI of the peer (e.g. `https://idx1.example.com:8089`).
+    pub uri: String,
+    /// Username of an admin account on the peer, used to exchange trust keys.
+    pub remote_username: String,
+    /// Password of the remote account.
+    #[serde(serialize_with = "crate::serde_helpers::serialize_secret_redacted")]
+    #[serde(deserialize_with = "crate::serde_helpers::deserialize_secret_from_string")]
+    pub remote_password: SecretString,
+}
+
+/// Search peer entry name (`host:port`) for a peer URI or name.
+///
+/// Accepts `https://host:port`, `host:port`, or an existing entry name.
+pub fn search_peer_entry_name(uri: &str) -> &str {
+    let uri = uri.trim();
+    let uri = uri
+        .strip_prefix("https://")
+        .or_else(|| uri.strip_prefix("http://"))
+        .unwrap_or(uri);
+    uri.trim_end_matches('/')
+}
+
 /// Search peer list response.
 ///
 /// Wrapper struct for deserializing the Splunk API response when listing search peers.
@@ -102,6 +131,16 @@ pub struct SearchPeerEntry {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_search_peer_entry_name_strips_scheme_and_slash() {
+        assert_eq!(
+            search_peer_entry_name("https://idx1.example.com:8089/"),
+            "idx1.example.com:8089"
+        );
+        assert_eq!(search_peer_entry_name("http://idx1:8089"), "idx1:8089");
+        assert_eq!(search_peer_entry_name(" idx1:8089 "), "idx1:8089");
+    }
+
     mod search_peer_status_tests {
         use super::*;
 
@@ -119,6 +158,13 @@ mod tests {
             assert_eq!(status, SearchPeerStatus::Down);
         }
 
+        #[test]
+        fn test_deserialize_quarantined() {
+            let json = r#""Quarantined""#;
+            let status: SearchPeerStatus = serde_json::from_str(json).unwrap();
+            assert_eq!(status, SearchPeerStatus::Quarantined);
+        }
+
         #[test]
         fn test_deserialize_unknown_variants() {
             let unknown_values = vec![
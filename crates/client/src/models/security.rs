@@ -0,0 +1,193 @@
+//! Security health models.
+//!
+//! These models summarize failed logins recorded in the `_audit` index
+//! (`action="login attempt" info=failed`) per source IP, so that an address
+//! guessing passwords stands out from the occasional mistyped login.
+
+use serde::{Deserialize, Serialize};
+
+/// Failed logins from one source IP above which it is flagged as a likely
+/// brute-force attempt.
+pub const DEFAULT_FAILED_LOGIN_THRESHOLD: u64 = 20;
+
+/// Failed logins from one source IP in the window.
+///
+/// Deserialized from the rows of the failed login search, where Splunk
+/// returns numbers as strings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FailedLoginSource {
+    /// Client address of the attempts; `unknown` when the event has none.
+    pub src_ip: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::u64_from_string_or_number"
+    )]
+    pub failures: u64,
+    /// Distinct user names tried from this address.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::u64_from_string_or_number"
+    )]
+    pub distinct_users: u64,
+    #[serde(default)]
+    pub first_seen: String,
+    #[serde(default)]
+    pub last_seen: String,
+}
+
+/// Failed login summary for a time window.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecurityHealth {
+    /// No source IP exceeded the threshold in the window.
+    pub is_healthy: bool,
+    /// Earliest time of the window, as a Splunk relative time (e.g. `-1h`).
+    pub time_window: String,
+    /// Failed logins a source IP must exceed to be flagged.
+    pub threshold: u64,
+    pub total_failures: u64,
+    /// Every source IP with failed logins in the window, most failures first.
+    pub sources: Vec<FailedLoginSource>,
+}
+
+impl SecurityHealth {
+    /// Build the summary from per-IP rows, ordering the most failures first.
+    pub fn from_sources(
+        time_window: String,
+        threshold: u64,
+        mut sources: Vec<FailedLoginSource>,
+    ) -> Self {
+        sources.sort_by(|a, b| {
+            b.failures
+                .cmp(&a.failures)
+                .then_with(|| a.src_ip.cmp(&b.src_ip))
+        });
+        let total_failures = sources.iter().map(|s| s.failures).sum();
+        let is_healthy = !sources.iter().any(|s| s.failures > threshold);
+
+        Self {
+            is_healthy,
+            time_window,
+            threshold,
+            total_failures,
+            sources,
+        }
+    }
+
+    /// Whether `source` exceeded the threshold.
+    pub fn is_flagged(&self, source: &FailedLoginSource) -> bool {
+        source.failures > self.threshold
+    }
+
+    /// Source IPs that exceeded the threshold, most failures first.
+    pub fn flagged(&self) -> impl Iterator<Item = &FailedLoginSource> {
+        self.sources.iter().filter(|s| self.is_flagged(s))
+    }
+
+    /// One warning per flagged source IP, most failures first.
+    pub fn warnings(&self) -> Vec<String> {
+        self.flagged()
+            .map(|s| {
+                format!(
+                    "{} had {} failed logins for {} user(s) since {} (threshold {})",
+                    s.src_ip, s.failures, s.distinct_users, self.time_window, self.threshold
+                )
+            })
+            .collect()
+    }
+
+    /// One self-contained alert record per flagged source IP, for line-oriented
+    /// output that is piped into alerting.
+    pub fn alerts(&self) -> Vec<FailedLoginAlert<'_>> {
+        self.flagged()
+            .map(|source| FailedLoginAlert {
+                alert: "brute_force_login",
+                time_window: &self.time_window,
+                threshold: self.threshold,
+                source,
+            })
+            .collect()
+    }
+}
+
+/// A flagged source IP together with the window and threshold that flagged it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FailedLoginAlert<'a> {
+    pub alert: &'static str,
+    pub time_window: &'a str,
+    pub threshold: u64,
+    #[serde(flatten)]
+    pub source: &'a FailedLoginSource,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(src_ip: &str, failures: u64) -> FailedLoginSource {
+        FailedLoginSource {
+            src_ip: src_ip.to_string(),
+            failures,
+            distinct_users: 1,
+            first_seen: "2025-01-20T10:00:00+0000".to_string(),
+            last_seen: "2025-01-20T10:30:00+0000".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_failed_login_source_deserializes_string_numbers() {
+        let json = r#"{
+            "src_ip": "10.0.0.5",
+            "failures": "42",
+            "distinct_users": "3",
+            "first_seen": "2025-01-20T10:00:00+0000",
+            "last_seen": "2025-01-20T10:30:00+0000"
+        }"#;
+        let row: FailedLoginSource = serde_json::from_str(json).unwrap();
+        assert_eq!(row.failures, 42);
+        assert_eq!(row.distinct_users, 3);
+    }
+
+    #[test]
+    fn test_from_sources_orders_and_flags_above_threshold() {
+        let health = SecurityHealth::from_sources(
+            "-1h".to_string(),
+            20,
+            vec![
+                source("10.0.0.1", 3),
+                source("10.0.0.9", 20),
+                source("10.0.0.5", 57),
+            ],
+        );
+
+        assert!(!health.is_healthy);
+        assert_eq!(health.total_failures, 80);
+        let order: Vec<&str> = health.sources.iter().map(|s| s.src_ip.as_str()).collect();
+        assert_eq!(order, vec!["10.0.0.5", "10.0.0.9", "10.0.0.1"]);
+        assert_eq!(
+            health.warnings(),
+            vec![
+                "10.0.0.5 had 57 failed logins for 1 user(s) since -1h (threshold 20)".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_alerts_flatten_source_with_context() {
+        let health =
+            SecurityHealth::from_sources("-1h".to_string(), 5, vec![source("10.0.0.5", 6)]);
+
+        let alerts = serde_json::to_value(health.alerts()).unwrap();
+        assert_eq!(alerts[0]["alert"], "brute_force_login");
+        assert_eq!(alerts[0]["time_window"], "-1h");
+        assert_eq!(alerts[0]["threshold"], 5);
+        assert_eq!(alerts[0]["src_ip"], "10.0.0.5");
+        assert_eq!(alerts[0]["failures"], 6);
+    }
+
+    #[test]
+    fn test_no_failures_is_healthy() {
+        let health = SecurityHealth::from_sources("-1h".to_string(), 20, vec![]);
+        assert!(health.is_healthy);
+        assert!(health.alerts().is_empty());
+    }
+}
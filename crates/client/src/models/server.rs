@@ -86,6 +86,30 @@ pub struct ServerInfo {
     pub server_roles: Vec<String>,
     #[serde(rename = "osName")]
     pub os_name: Option<String>,
+    /// Whether configuration changes are waiting on a splunkd restart.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::bool_from_string_or_bool"
+    )]
+    pub restart_required: bool,
+    /// When splunkd last started (epoch seconds); changes after a restart.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::opt_u64_from_string_or_number"
+    )]
+    pub startup_time: Option<u64>,
+    /// Deployment type reported by splunkd (`cloud` on Splunk Cloud Platform).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance_type: Option<String>,
+}
+
+impl ServerInfo {
+    /// True when the server reports itself as a Splunk Cloud Platform instance.
+    pub fn is_cloud(&self) -> bool {
+        self.instance_type
+            .as_deref()
+            .is_some_and(|t| t.eq_ignore_ascii_case("cloud"))
+    }
 }
 
 /// Health feature information.
@@ -303,6 +327,21 @@ mod tests {
         assert_eq!(info.mode, None);
     }
 
+    #[test]
+    fn server_info_deserializes_restart_required() {
+        let json = r#"{
+            "serverName": "test-server",
+            "version": "9.0.0",
+            "build": "abcdef",
+            "restart_required": "1",
+            "startup_time": "1705773600"
+        }"#;
+
+        let info: ServerInfo = serde_json::from_str(json).unwrap();
+        assert!(info.restart_required);
+        assert_eq!(info.startup_time, Some(1_705_773_600));
+    }
+
     #[test]
     fn health_feature_deserializes() {
         let json = r#"{
@@ -58,6 +58,13 @@ pub struct User {
         deserialize_with = "crate::serde_helpers::opt_usize_from_string_or_number"
     )]
     pub last_successful_login: Option<usize>, // Unix timestamp
+    /// Whether the account is locked out after too many failed logins.
+    #[serde(
+        rename = "locked-out",
+        default,
+        deserialize_with = "crate::serde_helpers::bool_from_string_or_bool"
+    )]
+    pub locked_out: bool,
 }
 
 /// User entry wrapper.
@@ -73,6 +80,119 @@ pub struct UserListResponse {
     pub entry: Vec<UserEntry>,
 }
 
+/// Password and lockout policy for native Splunk users (`admin/Splunkd-auth`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PasswordPolicy {
+    /// Minimum password length.
+    #[serde(
+        rename = "minPasswordLength",
+        default,
+        deserialize_with = "crate::serde_helpers::opt_usize_from_string_or_number"
+    )]
+    pub min_length: Option<usize>,
+    /// Minimum number of uppercase characters.
+    #[serde(
+        rename = "minPasswordUppercase",
+        default,
+        deserialize_with = "crate::serde_helpers::opt_usize_from_string_or_number"
+    )]
+    pub min_uppercase: Option<usize>,
+    /// Minimum number of lowercase characters.
+    #[serde(
+        rename = "minPasswordLowercase",
+        default,
+        deserialize_with = "crate::serde_helpers::opt_usize_from_string_or_number"
+    )]
+    pub min_lowercase: Option<usize>,
+    /// Minimum number of digits.
+    #[serde(
+        rename = "minPasswordDigit",
+        default,
+        deserialize_with = "crate::serde_helpers::opt_usize_from_string_or_number"
+    )]
+    pub min_digits: Option<usize>,
+    /// Minimum number of special characters.
+    #[serde(
+        rename = "minPasswordSpecial",
+        default,
+        deserialize_with = "crate::serde_helpers::opt_usize_from_string_or_number"
+    )]
+    pub min_special: Option<usize>,
+    /// Days before a password expires (0 = never).
+    #[serde(
+        rename = "expirePasswordDays",
+        default,
+        deserialize_with = "crate::serde_helpers::opt_usize_from_string_or_number"
+    )]
+    pub expire_password_days: Option<usize>,
+    /// Whether password history is enforced.
+    #[serde(
+        rename = "enablePasswordHistory",
+        default,
+        deserialize_with = "crate::serde_helpers::bool_from_string_or_bool"
+    )]
+    pub enable_password_history: bool,
+    /// Number of previous passwords that cannot be reused.
+    #[serde(
+        rename = "passwordHistoryCount",
+        default,
+        deserialize_with = "crate::serde_helpers::opt_usize_from_string_or_number"
+    )]
+    pub password_history_count: Option<usize>,
+    /// Whether accounts lock out after repeated failed logins.
+    #[serde(
+        rename = "lockoutUsers",
+        default,
+        deserialize_with = "crate::serde_helpers::bool_from_string_or_bool"
+    )]
+    pub lockout_users: bool,
+    /// Failed login attempts that trigger a lockout.
+    #[serde(
+        rename = "lockoutAttempts",
+        default,
+        deserialize_with = "crate::serde_helpers::opt_usize_from_string_or_number"
+    )]
+    pub lockout_attempts: Option<usize>,
+    /// Window in minutes in which failed attempts are counted.
+    #[serde(
+        rename = "lockoutThresholdMins",
+        default,
+        deserialize_with = "crate::serde_helpers::opt_usize_from_string_or_number"
+    )]
+    pub lockout_threshold_mins: Option<usize>,
+    /// Minutes an account stays locked out.
+    #[serde(
+        rename = "lockoutMins",
+        default,
+        deserialize_with = "crate::serde_helpers::opt_usize_from_string_or_number"
+    )]
+    pub lockout_mins: Option<usize>,
+}
+
+/// Identity and effective permissions of the authenticated user
+/// (`authentication/current-context`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CurrentContext {
+    #[serde(default)]
+    pub username: String,
+    pub realname: Option<String>,
+    pub email: Option<String>,
+    #[serde(rename = "defaultApp")]
+    pub default_app: Option<String>,
+    #[serde(default)]
+    pub roles: Vec<String>,
+    /// Effective capabilities, including those inherited through roles.
+    #[serde(default)]
+    pub capabilities: Vec<String>,
+}
+
+impl CurrentContext {
+    /// Returns true if the user holds `capability`.
+    pub fn has_capability(&self, capability: &str) -> bool {
+        self.capabilities.iter().any(|c| c == capability)
+    }
+}
+
 /// Parameters for creating a new user.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CreateUserParams {
@@ -132,6 +252,51 @@ mod tests {
         assert_eq!(user.default_app, Some("search".to_string()));
         assert_eq!(user.roles, vec!["admin", "power"]);
         assert_eq!(user.last_successful_login, Some(1737712345));
+        assert!(!user.locked_out);
+    }
+
+    #[test]
+    fn test_deserialize_locked_out_user() {
+        let json = r#"{"name": "jane", "roles": ["user"], "locked-out": "1"}"#;
+        let user: User = serde_json::from_str(json).unwrap();
+        assert!(user.locked_out);
+    }
+
+    #[test]
+    fn test_deserialize_password_policy() {
+        let json = r#"{
+            "minPasswordLength": "8",
+            "minPasswordDigit": "1",
+            "expirePasswordDays": "90",
+            "enablePasswordHistory": "0",
+            "lockoutUsers": "1",
+            "lockoutAttempts": "5",
+            "lockoutThresholdMins": "5",
+            "lockoutMins": "30"
+        }"#;
+        let policy: PasswordPolicy = serde_json::from_str(json).unwrap();
+        assert_eq!(policy.min_length, Some(8));
+        assert_eq!(policy.min_digits, Some(1));
+        assert_eq!(policy.min_special, None);
+        assert!(!policy.enable_password_history);
+        assert!(policy.lockout_users);
+        assert_eq!(policy.lockout_attempts, Some(5));
+        assert_eq!(policy.lockout_mins, Some(30));
+    }
+
+    #[test]
+    fn test_current_context_has_capability() {
+        let json = r#"{
+            "username": "analyst",
+            "defaultApp": "search",
+            "roles": ["power"],
+            "capabilities": ["search", "list_inputs"]
+        }"#;
+        let ctx: CurrentContext = serde_json::from_str(json).unwrap();
+        assert_eq!(ctx.username, "analyst");
+        assert_eq!(ctx.default_app.as_deref(), Some("search"));
+        assert!(ctx.has_capability("search"));
+        assert!(!ctx.has_capability("indexes_edit"));
     }
 
     #[test]
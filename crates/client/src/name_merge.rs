@@ -13,8 +13,9 @@
 //! - This is crate-internal glue; it is not part of the public API contract.
 
 use crate::models::{
-    App, Capability, ConfigStanza, Dashboard, DataModel, FiredAlert, Forwarder, Index, Input,
-    KvStoreCollection, Macro, Role, SavedSearch, SearchPeer, User, WorkloadPool, WorkloadRule,
+    AlertAction, App, Capability, ConfigStanza, Dashboard, DataModel, EventType, FiredAlert,
+    Forwarder, HecToken, Index, Input, KvStoreCollection, Macro, Role, SavedSearch, SearchPeer,
+    User, WorkloadPool, WorkloadRule,
 };
 
 pub(crate) trait HasName {
@@ -74,6 +75,12 @@ impl HasName for ConfigStanza {
     }
 }
 
+impl HasName for AlertAction {
+    fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+}
+
 impl HasName for FiredAlert {
     fn set_name(&mut self, name: String) {
         self.name = name;
@@ -104,6 +111,12 @@ impl HasName for Macro {
     }
 }
 
+impl HasName for HecToken {
+    fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+}
+
 impl HasName for Dashboard {
     fn set_name(&mut self, name: String) {
         self.name = name;
@@ -116,6 +129,12 @@ impl HasName for DataModel {
     }
 }
 
+impl HasName for EventType {
+    fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+}
+
 impl HasName for WorkloadPool {
     fn set_name(&mut self, name: String) {
         self.name = name;
@@ -147,6 +166,8 @@ mod tests {
             thawed_path: None,
             cold_to_frozen_dir: None,
             primary_index: None,
+            min_time: None,
+            max_time: None,
         };
 
         let index = attach_entry_name("main".to_string(), index);
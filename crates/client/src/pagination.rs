@@ -0,0 +1,219 @@
+//! Automatic pagination over `count`/`offset` list endpoints.
+//!
+//! Responsibilities:
+//! - Walk every page of a list endpoint and yield its entries as an async [`Stream`].
+//! - Stop at the first short page, an empty page, or a safety cap on total entries.
+//!
+//! Does NOT handle:
+//! - Issuing requests (callers pass a page-fetch closure, usually a `SplunkClient` list method).
+//! - Cursor- or search-job-based paging (search results use their own offset API).
+//!
+//! Invariants:
+//! - Pages are fetched lazily and sequentially; the next page is requested only
+//!   after the previous page's entries have been consumed.
+//! - The first error is yielded and ends the stream.
+//! - At most `max_items` entries are yielded; reaching the cap logs a warning.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use futures::TryStreamExt;
+//! use splunk_client::pagination::{PaginationOptions, paginate_all};
+//!
+//! let indexes: Vec<_> = paginate_all(PaginationOptions::default(), |count, offset| {
+//!     client.list_indexes(Some(count), Some(offset))
+//! })
+//! .try_collect()
+//! .await?;
+//! ```
+
+use std::collections::VecDeque;
+use std::future::Future;
+
+use futures::Stream;
+use futures::stream;
+
+use crate::error::Result;
+
+/// Default number of entries requested per page.
+pub const DEFAULT_PAGE_SIZE: usize = 100;
+
+/// Default safety cap on the total number of entries fetched.
+pub const DEFAULT_MAX_ITEMS: usize = 100_000;
+
+/// Page size and safety cap for [`paginate_all`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaginationOptions {
+    /// Entries requested per page (minimum 1).
+    pub page_size: usize,
+    /// Maximum total entries to yield before stopping.
+    pub max_items: usize,
+}
+
+impl Default for PaginationOptions {
+    fn default() -> Self {
+        Self {
+            page_size: DEFAULT_PAGE_SIZE,
+            max_items: DEFAULT_MAX_ITEMS,
+        }
+    }
+}
+
+impl PaginationOptions {
+    /// Set the page size.
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Set the safety cap on total entries.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = max_items;
+        self
+    }
+}
+
+struct PageState<T, F> {
+    fetch: F,
+    buffer: VecDeque<T>,
+    offset: usize,
+    yielded: usize,
+    exhausted: bool,
+}
+
+/// Stream every entry of a paginated list endpoint.
+///
+/// `fetch(count, offset)` is called for each page, starting at offset 0, until
+/// it returns fewer than `count` entries or `options.max_items` entries have
+/// been yielded.
+pub fn paginate_all<T, F, Fut>(
+    options: PaginationOptions,
+    fetch: F,
+) -> impl Stream<Item = Result<T>>
+where
+    F: FnMut(usize, usize) -> Fut,
+    Fut: Future<Output = Result<Vec<T>>>,
+{
+    let page_size = options.page_size.max(1);
+    let max_items = options.max_items;
+    let state = PageState {
+        fetch,
+        buffer: VecDeque::new(),
+        offset: 0,
+        yielded: 0,
+        exhausted: false,
+    };
+
+    stream::unfold(state, move |mut state| async move {
+        if state.yielded >= max_items {
+            // A short final page means the listing ended on its own.
+            if !state.exhausted {
+                tracing::warn!(
+                    max_items,
+                    "Pagination stopped at the safety cap; results may be incomplete"
+                );
+            }
+            return None;
+        }
+
+        if state.buffer.is_empty() {
+            if state.exhausted {
+                return None;
+            }
+            let count = page_size.min(max_items - state.yielded);
+            match (state.fetch)(count, state.offset).await {
+                Ok(page) => {
+                    state.exhausted = page.len() < count;
+                    state.offset += page.len();
+                    state.buffer.extend(page);
+                }
+                Err(error) => {
+                    state.exhausted = true;
+                    return Some((Err(error), state));
+                }
+            }
+        }
+
+        let item = state.buffer.pop_front()?;
+        state.yielded += 1;
+        Some((Ok(item), state))
+    })
+}
+
+/// Whether a fully collected listing stopped at the safety cap.
+pub fn hit_safety_cap(collected: usize, options: &PaginationOptions) -> bool {
+    collected >= options.max_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ClientError;
+    use futures::{StreamExt, TryStreamExt};
+    use std::sync::Mutex;
+
+    fn numbers(total: usize) -> impl Fn(usize, usize) -> std::future::Ready<Result<Vec<usize>>> {
+        move |count, offset| std::future::ready(Ok((offset..total.min(offset + count)).collect()))
+    }
+
+    #[tokio::test]
+    async fn test_walks_all_pages() {
+        let items: Vec<usize> =
+            paginate_all(PaginationOptions::default().page_size(3), numbers(10))
+                .try_collect()
+                .await
+                .unwrap();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn test_exact_page_multiple_requests_one_empty_page() {
+        let calls = Mutex::new(Vec::new());
+        let items: Vec<usize> = paginate_all(PaginationOptions::default().page_size(5), |c, o| {
+            calls.lock().unwrap().push(o);
+            numbers(10)(c, o)
+        })
+        .try_collect()
+        .await
+        .unwrap();
+
+        assert_eq!(items.len(), 10);
+        assert_eq!(*calls.lock().unwrap(), vec![0, 5, 10]);
+    }
+
+    #[tokio::test]
+    async fn test_stops_at_safety_cap() {
+        let options = PaginationOptions::default().page_size(4).max_items(6);
+        let calls = Mutex::new(Vec::new());
+        let items: Vec<usize> = paginate_all(options, |c, o| {
+            calls.lock().unwrap().push((c, o));
+            numbers(usize::MAX)(c, o)
+        })
+        .try_collect()
+        .await
+        .unwrap();
+
+        assert_eq!(items, (0..6).collect::<Vec<_>>());
+        // The last page only asks for what the cap still allows.
+        assert_eq!(*calls.lock().unwrap(), vec![(4, 0), (2, 4)]);
+        assert!(hit_safety_cap(items.len(), &options));
+    }
+
+    #[tokio::test]
+    async fn test_error_ends_stream() {
+        let results: Vec<Result<usize>> =
+            paginate_all(PaginationOptions::default().page_size(2), |c, o| {
+                std::future::ready(if o == 0 {
+                    Ok((0..c).collect())
+                } else {
+                    Err(ClientError::InvalidResponse("boom".to_string()))
+                })
+            })
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok() && results[1].is_ok());
+        assert!(results[2].is_err());
+    }
+}
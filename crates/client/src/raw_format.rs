@@ -0,0 +1,175 @@
+//! Pretty-printing of structured text (JSON and XML).
+//!
+//! Shared by the TUI result detail popup (event `_raw`) and the CLI/TUI REST
+//! explorer (response bodies).
+
+/// Structured format detected in an event's `_raw`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RawFormat {
+    Json,
+    Xml,
+}
+
+impl RawFormat {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Json => "JSON",
+            Self::Xml => "XML",
+        }
+    }
+}
+
+/// Pretty-print `raw` when it is a JSON object/array or well-formed XML.
+pub fn pretty_raw(raw: &str) -> Option<(RawFormat, String)> {
+    let trimmed = raw.trim();
+    if trimmed.starts_with('{') || trimmed.starts_with('[') {
+        let value: serde_json::Value = serde_json::from_str(trimmed).ok()?;
+        return serde_json::to_string_pretty(&value)
+            .ok()
+            .map(|pretty| (RawFormat::Json, pretty));
+    }
+    if trimmed.starts_with('<') && trimmed.ends_with('>') {
+        return pretty_xml(trimmed).map(|pretty| (RawFormat::Xml, pretty));
+    }
+    None
+}
+
+/// Pretty-print a [`SplunkClient::raw_request`](crate::SplunkClient::raw_request) body.
+///
+/// JSON values are indented; text bodies (such as Atom XML) are indented when
+/// well-formed and otherwise returned unchanged.
+pub fn pretty_response(body: &serde_json::Value) -> (Option<RawFormat>, String) {
+    match body {
+        serde_json::Value::String(text) => match pretty_raw(text) {
+            Some((format, pretty)) => (Some(format), pretty),
+            None => (None, text.clone()),
+        },
+        value => (
+            Some(RawFormat::Json),
+            serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string()),
+        ),
+    }
+}
+
+enum XmlToken<'a> {
+    Open(&'a str),
+    Close(&'a str),
+    /// Self-closing elements, declarations, and comments.
+    Standalone(&'a str),
+    Text(&'a str),
+}
+
+fn xml_tokens(xml: &str) -> Option<Vec<XmlToken<'_>>> {
+    let mut tokens = Vec::new();
+    let mut rest = xml;
+    while !rest.is_empty() {
+        if rest.starts_with('<') {
+            let end = rest.find('>')? + 1;
+            let tag = &rest[..end];
+            tokens.push(if tag.starts_with("</") {
+                XmlToken::Close(tag)
+            } else if tag.ends_with("/>") || tag.starts_with("<?") || tag.starts_with("<!") {
+                XmlToken::Standalone(tag)
+            } else {
+                XmlToken::Open(tag)
+            });
+            rest = &rest[end..];
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let text = rest[..end].trim();
+            if !text.is_empty() {
+                tokens.push(XmlToken::Text(text));
+            }
+            rest = &rest[end..];
+        }
+    }
+    Some(tokens)
+}
+
+/// Indent XML two spaces per level; `<a>text</a>` stays on one line.
+///
+/// Returns `None` for unbalanced markup so it is shown unchanged.
+fn pretty_xml(xml: &str) -> Option<String> {
+    let tokens = xml_tokens(xml)?;
+    let mut lines = Vec::new();
+    let mut depth = 0usize;
+    let mut i = 0;
+    while i < tokens.len() {
+        let indent = "  ".repeat(depth);
+        match (&tokens[i], tokens.get(i + 1), tokens.get(i + 2)) {
+            (XmlToken::Open(open), Some(XmlToken::Text(text)), Some(XmlToken::Close(close))) => {
+                lines.push(format!("{}{}{}{}", indent, open, text, close));
+                i += 3;
+                continue;
+            }
+            (XmlToken::Open(open), Some(XmlToken::Close(close)), _) => {
+                lines.push(format!("{}{}{}", indent, open, close));
+                i += 2;
+                continue;
+            }
+            (XmlToken::Open(open), _, _) => {
+                lines.push(format!("{}{}", indent, open));
+                depth += 1;
+            }
+            (XmlToken::Close(close), _, _) => {
+                depth = depth.checked_sub(1)?;
+                lines.push(format!("{}{}", "  ".repeat(depth), close));
+            }
+            (XmlToken::Standalone(text) | XmlToken::Text(text), _, _) => {
+                lines.push(format!("{}{}", indent, text));
+            }
+        }
+        i += 1;
+    }
+    (depth == 0).then(|| lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pretty_raw_json() {
+        let (format, pretty) = pretty_raw(r#" {"tags":["a"],"user":"alice"} "#).unwrap();
+        assert_eq!(format, RawFormat::Json);
+        assert_eq!(
+            pretty,
+            "{\n  \"tags\": [\n    \"a\"\n  ],\n  \"user\": \"alice\"\n}"
+        );
+    }
+
+    #[test]
+    fn test_pretty_raw_xml() {
+        let (format, pretty) = pretty_raw(
+            r#"<?xml version="1.0"?><Event><System><EventID>4625</EventID><Computer/></System><Data>x</Data></Event>"#,
+        )
+        .unwrap();
+        assert_eq!(format, RawFormat::Xml);
+        assert_eq!(
+            pretty,
+            "<?xml version=\"1.0\"?>\n<Event>\n  <System>\n    <EventID>4625</EventID>\n    <Computer/>\n  </System>\n  <Data>x</Data>\n</Event>"
+        );
+    }
+
+    #[test]
+    fn test_pretty_response() {
+        let (format, pretty) = pretty_response(&serde_json::json!({"entry": []}));
+        assert_eq!(format, Some(RawFormat::Json));
+        assert_eq!(pretty, "{\n  \"entry\": []\n}");
+
+        let atom = serde_json::Value::String("<feed><title>info</title></feed>".to_string());
+        let (format, pretty) = pretty_response(&atom);
+        assert_eq!(format, Some(RawFormat::Xml));
+        assert_eq!(pretty, "<feed>\n  <title>info</title>\n</feed>");
+
+        let text = serde_json::Value::String("OK".to_string());
+        assert_eq!(pretty_response(&text), (None, "OK".to_string()));
+    }
+
+    #[test]
+    fn test_pretty_raw_leaves_plain_and_malformed_text() {
+        assert!(pretty_raw("GET /index.html 200").is_none());
+        assert!(pretty_raw("{not json}").is_none());
+        assert!(pretty_raw("<a><b></a>").is_none());
+    }
+}
@@ -0,0 +1,434 @@
+//! Purpose: Shared parser for Splunk time modifiers (`earliest`/`latest` values).
+//! Responsibilities: Validate strings such as `-24h@h`, `@w1+8h`, `now`, epoch
+//! seconds, and absolute times, and resolve them to an absolute time given "now".
+//! Scope: Used by the CLI (`--earliest`/`--latest` validation) and the TUI (search
+//! submit validation, saved search dispatch window previews).
+//! Usage: `parse_time_modifier("-7d@d")?.resolve(&Local::now())`.
+//! Invariants/Assumptions: Day, week, month, quarter, and year arithmetic and all
+//! snapping happen in the time zone of "now", as Splunk does for the user's zone.
+//! `@w` and `@w0` snap to Sunday; `@w1`..`@w6` to Monday..Saturday; `@w7` to Sunday.
+
+use std::str::FromStr;
+
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, Months, NaiveDate, NaiveDateTime, NaiveTime,
+    TimeZone, Timelike,
+};
+
+/// Splunk's default absolute time format, e.g. `01/15/2024:08:00:00`.
+const SPLUNK_TIME_FORMAT: &str = "%m/%d/%Y:%H:%M:%S";
+
+/// ISO 8601 formats accepted without a UTC offset.
+const ISO_FORMATS: [&str; 2] = ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%dT%H:%M"];
+
+/// Why a time modifier was rejected.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TimeModifierError {
+    /// An empty (or whitespace-only) value.
+    #[error("time modifier is empty")]
+    Empty,
+    /// A relative offset or snap with an unrecognised unit.
+    #[error("unknown time unit '{unit}' in '{value}' (use s, m, h, d, w, mon, q, or y)")]
+    UnknownUnit { value: String, unit: String },
+    /// Anything else that is not a time modifier.
+    #[error(
+        "invalid time modifier '{0}' (expected now, epoch seconds, an ISO 8601 time, or a relative time like -24h@h)"
+    )]
+    Invalid(String),
+}
+
+/// Unit of a relative offset or snap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl TimeUnit {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "s" | "sec" | "secs" | "second" | "seconds" => Self::Second,
+            "m" | "min" | "mins" | "minute" | "minutes" => Self::Minute,
+            "h" | "hr" | "hrs" | "hour" | "hours" => Self::Hour,
+            "d" | "day" | "days" => Self::Day,
+            "w" | "week" | "weeks" => Self::Week,
+            "mon" | "month" | "months" => Self::Month,
+            "q" | "qtr" | "qtrs" | "quarter" | "quarters" => Self::Quarter,
+            "y" | "yr" | "yrs" | "year" | "years" => Self::Year,
+            _ => return None,
+        })
+    }
+}
+
+/// One step of a relative time modifier, applied left to right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeStep {
+    /// `+N<unit>` or `-N<unit>`.
+    Offset { amount: i64, unit: TimeUnit },
+    /// `@<unit>`: round down to the start of the unit.
+    Snap(TimeUnit),
+    /// `@w0`..`@w7`: round down to the most recent weekday (0 and 7 are Sunday).
+    SnapWeekday(u32),
+}
+
+/// A parsed Splunk time modifier.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimeModifier {
+    /// `now`.
+    Now,
+    /// Seconds since the Unix epoch; `0` means all time.
+    Epoch(f64),
+    /// Absolute time with an explicit UTC offset.
+    Absolute(DateTime<FixedOffset>),
+    /// Absolute time read in the time zone of "now".
+    LocalAbsolute(NaiveDateTime),
+    /// Relative steps from now, e.g. `-1d@d+8h`; an `rt` prefix marks a real-time
+    /// window (`rt` alone is now).
+    Relative {
+        realtime: bool,
+        steps: Vec<TimeStep>,
+    },
+}
+
+impl TimeModifier {
+    /// Resolve to an absolute time, relative to `now` and in its time zone.
+    ///
+    /// Returns `None` when the result is out of range or falls in a
+    /// daylight-saving gap of the zone.
+    pub fn resolve<Tz: TimeZone>(&self, now: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let tz = now.timezone();
+        match self {
+            Self::Now => Some(now.clone()),
+            Self::Epoch(seconds) => {
+                let whole = seconds.floor();
+                let nanos = ((seconds - whole) * 1e9) as u32;
+                tz.timestamp_opt(whole as i64, nanos).single()
+            }
+            Self::Absolute(time) => Some(time.with_timezone(&tz)),
+            Self::LocalAbsolute(naive) => tz.from_local_datetime(naive).earliest(),
+            Self::Relative { steps, .. } => {
+                let naive = steps
+                    .iter()
+                    .try_fold(now.naive_local(), |time, step| apply_step(time, *step))?;
+                tz.from_local_datetime(&naive).earliest()
+            }
+        }
+    }
+
+    /// Human-readable resolved time for previews, e.g. `2024-01-15 08:00:00 -05:00`.
+    pub fn preview<Tz: TimeZone>(&self, now: &DateTime<Tz>) -> String
+    where
+        Tz::Offset: std::fmt::Display,
+    {
+        match self {
+            Self::Epoch(seconds) if *seconds == 0.0 => "all time".to_string(),
+            Self::Now => "now".to_string(),
+            _ => match self.resolve(now) {
+                Some(time) => time.format("%Y-%m-%d %H:%M:%S %:z").to_string(),
+                None => "out of range".to_string(),
+            },
+        }
+    }
+}
+
+impl FromStr for TimeModifier {
+    type Err = TimeModifierError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        parse_time_modifier(value)
+    }
+}
+
+/// Parse a Splunk time modifier.
+///
+/// Accepts `now`, epoch seconds (`0` is all time), ISO 8601 times (with or
+/// without an offset), Splunk's `%m/%d/%Y:%H:%M:%S` format, and relative
+/// modifiers built from `+N<unit>`, `-N<unit>`, and `@<unit>` steps such as
+/// `-24h`, `-7d@d`, `@w1+8h`, or `rt-5m`.
+pub fn parse_time_modifier(value: &str) -> Result<TimeModifier, TimeModifierError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(TimeModifierError::Empty);
+    }
+    if value.eq_ignore_ascii_case("now") {
+        return Ok(TimeModifier::Now);
+    }
+    if let Ok(seconds) = value.parse::<f64>()
+        && seconds.is_finite()
+        && !value.starts_with(['+', '-'])
+    {
+        return Ok(TimeModifier::Epoch(seconds));
+    }
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Ok(TimeModifier::Absolute(time));
+    }
+    if let Some(naive) = parse_local_absolute(value) {
+        return Ok(TimeModifier::LocalAbsolute(naive));
+    }
+
+    let (realtime, relative) = match value.strip_prefix("rt") {
+        Some(rest) => (true, rest),
+        None => (false, value),
+    };
+    if realtime && relative.is_empty() {
+        return Ok(TimeModifier::Relative {
+            realtime,
+            steps: Vec::new(),
+        });
+    }
+    let steps = parse_steps(value, relative)?;
+    Ok(TimeModifier::Relative { realtime, steps })
+}
+
+/// Check a time modifier, returning it unchanged; suits clap `value_parser`s.
+pub fn validate_time_modifier(value: &str) -> Result<String, TimeModifierError> {
+    parse_time_modifier(value).map(|_| value.to_string())
+}
+
+/// `earliest=`/`latest=` terms written in an SPL query, as `(name, value)` pairs.
+///
+/// Only whole terms are matched (`myearliest=` is not), and quotes around the
+/// value are removed.
+pub fn query_time_terms(query: &str) -> Vec<(&str, &str)> {
+    query
+        .split_whitespace()
+        .filter_map(|term| {
+            let (name, value) = term.split_once('=')?;
+            let name = name.trim_start_matches(['(', '|']);
+            ["earliest", "latest"]
+                .into_iter()
+                .find(|bound| name.eq_ignore_ascii_case(bound))?;
+            Some((name, value.trim_end_matches(')').trim_matches('"')))
+        })
+        .collect()
+}
+
+fn parse_local_absolute(value: &str) -> Option<NaiveDateTime> {
+    ISO_FORMATS
+        .iter()
+        .chain([SPLUNK_TIME_FORMAT].iter())
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .map(|date| date.and_time(NaiveTime::MIN))
+        })
+}
+
+fn parse_steps(value: &str, mut rest: &str) -> Result<Vec<TimeStep>, TimeModifierError> {
+    let invalid = || TimeModifierError::Invalid(value.to_string());
+    let mut steps = Vec::new();
+    while let Some(marker) = rest.chars().next() {
+        rest = &rest[marker.len_utf8()..];
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        let (amount, after_amount) = rest.split_at(digits);
+        let letters = after_amount.len()
+            - after_amount
+                .trim_start_matches(|c: char| c.is_ascii_alphabetic())
+                .len();
+        let (unit, after_unit) = after_amount.split_at(letters);
+        if unit.is_empty() {
+            return Err(invalid());
+        }
+        let unknown = || TimeModifierError::UnknownUnit {
+            value: value.to_string(),
+            unit: unit.to_string(),
+        };
+
+        match marker {
+            '+' | '-' => {
+                let amount = if amount.is_empty() {
+                    1
+                } else {
+                    amount.parse::<i64>().map_err(|_| invalid())?
+                };
+                let unit = TimeUnit::parse(unit).ok_or_else(unknown)?;
+                let amount = if marker == '-' { -amount } else { amount };
+                steps.push(TimeStep::Offset { amount, unit });
+                rest = after_unit;
+            }
+            '@' if amount.is_empty() => {
+                // `@w0`..`@w7` carry a weekday digit after the unit
+                let weekday_digits = after_unit.len().saturating_sub(
+                    after_unit
+                        .trim_start_matches(|c: char| c.is_ascii_digit())
+                        .len(),
+                );
+                let (weekday, after_snap) = after_unit.split_at(weekday_digits);
+                let step = match (TimeUnit::parse(unit).ok_or_else(unknown)?, weekday) {
+                    (unit, "") => TimeStep::Snap(unit),
+                    (TimeUnit::Week, day) => match day.parse::<u32>() {
+                        Ok(day @ 0..=7) => TimeStep::SnapWeekday(day % 7),
+                        _ => return Err(invalid()),
+                    },
+                    _ => return Err(invalid()),
+                };
+                steps.push(step);
+                rest = after_snap;
+            }
+            _ => return Err(invalid()),
+        }
+    }
+    if steps.is_empty() {
+        return Err(invalid());
+    }
+    Ok(steps)
+}
+
+fn apply_step(time: NaiveDateTime, step: TimeStep) -> Option<NaiveDateTime> {
+    match step {
+        TimeStep::Offset { amount, unit } => {
+            let months = |count: i64| -> Option<NaiveDateTime> {
+                let months = Months::new(u32::try_from(count.checked_abs()?).ok()?);
+                if count < 0 {
+                    time.checked_sub_months(months)
+                } else {
+                    time.checked_add_months(months)
+                }
+            };
+            match unit {
+                TimeUnit::Second => time.checked_add_signed(Duration::try_seconds(amount)?),
+                TimeUnit::Minute => time.checked_add_signed(Duration::try_minutes(amount)?),
+                TimeUnit::Hour => time.checked_add_signed(Duration::try_hours(amount)?),
+                TimeUnit::Day => time.checked_add_signed(Duration::try_days(amount)?),
+                TimeUnit::Week => time.checked_add_signed(Duration::try_weeks(amount)?),
+                TimeUnit::Month => months(amount),
+                TimeUnit::Quarter => months(amount.checked_mul(3)?),
+                TimeUnit::Year => months(amount.checked_mul(12)?),
+            }
+        }
+        TimeStep::Snap(unit) => {
+            let date = time.date();
+            Some(match unit {
+                TimeUnit::Second => time.with_nanosecond(0)?,
+                TimeUnit::Minute => time.with_nanosecond(0)?.with_second(0)?,
+                TimeUnit::Hour => date.and_hms_opt(time.hour(), 0, 0)?,
+                TimeUnit::Day => date.and_time(NaiveTime::MIN),
+                TimeUnit::Week => return apply_step(time, TimeStep::SnapWeekday(0)),
+                TimeUnit::Month => date.with_day(1)?.and_time(NaiveTime::MIN),
+                TimeUnit::Quarter => {
+                    let month = (date.month0() / 3) * 3 + 1;
+                    NaiveDate::from_ymd_opt(date.year(), month, 1)?.and_time(NaiveTime::MIN)
+                }
+                TimeUnit::Year => {
+                    NaiveDate::from_ymd_opt(date.year(), 1, 1)?.and_time(NaiveTime::MIN)
+                }
+            })
+        }
+        TimeStep::SnapWeekday(day) => {
+            let date = time.date();
+            let back = (date.weekday().num_days_from_sunday() + 7 - day) % 7;
+            Some((date - Duration::days(i64::from(back))).and_time(NaiveTime::MIN))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Monday 2024-01-15 10:37:42 at UTC-5.
+    fn now() -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339("2024-01-15T10:37:42-05:00").unwrap()
+    }
+
+    fn resolve(value: &str) -> String {
+        parse_time_modifier(value)
+            .unwrap()
+            .resolve(&now())
+            .unwrap()
+            .to_rfc3339()
+    }
+
+    #[test]
+    fn test_relative_offsets_and_snaps() {
+        assert_eq!(resolve("-24h"), "2024-01-14T10:37:42-05:00");
+        assert_eq!(resolve("-24h@h"), "2024-01-14T10:00:00-05:00");
+        assert_eq!(resolve("-7d@d"), "2024-01-08T00:00:00-05:00");
+        assert_eq!(resolve("@d+8h"), "2024-01-15T08:00:00-05:00");
+        assert_eq!(resolve("-1mon@mon"), "2023-12-01T00:00:00-05:00");
+        assert_eq!(resolve("@q"), "2024-01-01T00:00:00-05:00");
+        assert_eq!(resolve("-2qtr@y"), "2023-01-01T00:00:00-05:00");
+        assert_eq!(resolve("+90seconds"), "2024-01-15T10:39:12-05:00");
+        assert_eq!(resolve("-h"), "2024-01-15T09:37:42-05:00");
+        assert_eq!(resolve("rt-5m"), "2024-01-15T10:32:42-05:00");
+    }
+
+    #[test]
+    fn test_week_snaps() {
+        // Sunday before the Monday "now"
+        assert_eq!(resolve("@w"), "2024-01-14T00:00:00-05:00");
+        assert_eq!(resolve("@w0"), "2024-01-14T00:00:00-05:00");
+        assert_eq!(resolve("@w7"), "2024-01-14T00:00:00-05:00");
+        assert_eq!(resolve("@w1"), "2024-01-15T00:00:00-05:00");
+        assert_eq!(resolve("-1w@w5"), "2024-01-05T00:00:00-05:00");
+    }
+
+    #[test]
+    fn test_absolute_and_epoch() {
+        assert_eq!(resolve("now"), "2024-01-15T10:37:42-05:00");
+        assert_eq!(resolve("2024-01-10T08:00:00Z"), "2024-01-10T03:00:00-05:00");
+        assert_eq!(resolve("2024-01-10T08:00:00"), "2024-01-10T08:00:00-05:00");
+        assert_eq!(resolve("01/10/2024:08:00:00"), "2024-01-10T08:00:00-05:00");
+        assert_eq!(resolve("2024-01-10"), "2024-01-10T00:00:00-05:00");
+        assert_eq!(resolve("1705000000"), "2024-01-11T14:06:40-05:00");
+        assert_eq!(
+            parse_time_modifier("0").unwrap().preview(&now()),
+            "all time"
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_modifiers() {
+        assert_eq!(parse_time_modifier("  "), Err(TimeModifierError::Empty));
+        assert_eq!(
+            parse_time_modifier("-24x"),
+            Err(TimeModifierError::UnknownUnit {
+                value: "-24x".to_string(),
+                unit: "x".to_string()
+            })
+        );
+        for value in [
+            "yesterday",
+            "-24",
+            "24h",
+            "@",
+            "@w8",
+            "-1d@",
+            "@3d",
+            "-1d 2h",
+        ] {
+            assert!(
+                matches!(
+                    parse_time_modifier(value),
+                    Err(TimeModifierError::Invalid(_))
+                ),
+                "{} should be invalid",
+                value
+            );
+        }
+        assert_eq!(validate_time_modifier("-7d@d").as_deref(), Ok("-7d@d"));
+    }
+
+    #[test]
+    fn test_preview() {
+        let modifier = parse_time_modifier("-1d@d").unwrap();
+        assert_eq!(modifier.preview(&now()), "2024-01-14 00:00:00 -05:00");
+        assert_eq!(TimeModifier::Now.preview(&now()), "now");
+    }
+
+    #[test]
+    fn test_query_time_terms() {
+        assert_eq!(
+            query_time_terms(r#"index=main earliest=-24h@h latest="now" myearliest=1 | head 5"#),
+            vec![("earliest", "-24h@h"), ("latest", "now")]
+        );
+        assert!(query_time_terms("index=main | stats count").is_empty());
+    }
+}
@@ -0,0 +1,274 @@
+//! Response schema checks for model drift detection.
+//!
+//! Responsibilities:
+//! - Compare a raw JSON object against a model and report unexpected and missing fields
+//! - Parse entry content into models, with detailed reports under the `strict-models` feature
+//!
+//! Does NOT handle:
+//! - Locating fixtures or entries in responses (see `testing::fixture_validation`)
+//! - Type coercion beyond what the model's own deserializer does
+//!
+//! Invariants:
+//! - Unexpected fields are top-level keys the model drops on a deserialize/serialize round trip
+//! - Keys whose value is `null` are never reported as unexpected
+//! - Missing fields are collected one at a time from serde's `missing field` errors
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::error::{ClientError, Result};
+
+/// Upper bound on missing-field probes for a single object.
+const MAX_MISSING_PROBES: usize = 64;
+
+/// Placeholder values tried for a missing field, in order.
+fn placeholders() -> [Value; 6] {
+    [
+        Value::String(String::new()),
+        Value::from(0),
+        Value::Bool(false),
+        Value::Array(Vec::new()),
+        Value::Object(serde_json::Map::new()),
+        Value::Null,
+    ]
+}
+
+/// Differences between a JSON object and the model it was parsed into.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModelIssues {
+    /// Fields present in the JSON that the model does not capture.
+    pub unexpected_fields: Vec<String>,
+    /// Fields the model requires that the JSON does not contain.
+    pub missing_fields: Vec<String>,
+    /// Deserialization error that remains after accounting for missing fields.
+    pub error: Option<String>,
+}
+
+impl ModelIssues {
+    /// True when the model parsed and captured every field.
+    pub fn is_clean(&self) -> bool {
+        self.unexpected_fields.is_empty() && !self.is_failure()
+    }
+
+    /// True when the JSON cannot be parsed into the model.
+    pub fn is_failure(&self) -> bool {
+        !self.missing_fields.is_empty() || self.error.is_some()
+    }
+}
+
+impl fmt::Display for ModelIssues {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if !self.missing_fields.is_empty() {
+            parts.push(format!(
+                "missing fields: {}",
+                self.missing_fields.join(", ")
+            ));
+        }
+        if !self.unexpected_fields.is_empty() {
+            parts.push(format!(
+                "unexpected fields: {}",
+                self.unexpected_fields.join(", ")
+            ));
+        }
+        if let Some(error) = &self.error {
+            parts.push(error.clone());
+        }
+        if parts.is_empty() {
+            write!(f, "no issues")
+        } else {
+            write!(f, "{}", parts.join("; "))
+        }
+    }
+}
+
+/// Check a JSON object against model `T`.
+///
+/// Required fields are reported by name, even when several are absent. A
+/// non-object value is reported as an error.
+pub fn check_model<T: DeserializeOwned + Serialize>(value: &Value) -> ModelIssues {
+    let mut issues = ModelIssues::default();
+    let Some(object) = value.as_object() else {
+        issues.error = Some(format!("expected a JSON object, got {}", value_kind(value)));
+        return issues;
+    };
+
+    let mut probe = object.clone();
+    let parsed = loop {
+        let error = match serde_json::from_value::<T>(Value::Object(probe.clone())) {
+            Ok(model) => break Some(model),
+            Err(e) => e,
+        };
+        let Some(field) = missing_field_name(&error.to_string()) else {
+            issues.error = Some(error.to_string());
+            break None;
+        };
+        if issues.missing_fields.len() >= MAX_MISSING_PROBES
+            || issues.missing_fields.contains(&field)
+        {
+            break None;
+        }
+        issues.missing_fields.push(field.clone());
+        if !fill_placeholder::<T>(&mut probe, &field) {
+            break None;
+        }
+    };
+
+    if let Some(model) = parsed {
+        issues.unexpected_fields = unexpected_fields(object, &model);
+    }
+    issues
+}
+
+/// Parse entry content into model `T`, describing the model as `what` on error.
+///
+/// With the `strict-models` feature, a failure lists every missing field and
+/// a successful parse logs fields the model drops as drift.
+pub(crate) fn parse_entry_content<T: DeserializeOwned + Serialize>(
+    content: &Value,
+    what: &str,
+) -> Result<T> {
+    #[cfg(feature = "strict-models")]
+    {
+        let issues = check_model::<T>(content);
+        if issues.is_failure() {
+            return Err(ClientError::InvalidResponse(format!(
+                "Failed to parse {}: {}",
+                what, issues
+            )));
+        }
+        if !issues.unexpected_fields.is_empty() {
+            tracing::warn!(
+                model = what,
+                fields = %issues.unexpected_fields.join(", "),
+                "Response has fields the model does not capture"
+            );
+        }
+    }
+
+    serde_json::from_value(content.clone())
+        .map_err(|e| ClientError::InvalidResponse(format!("Failed to parse {}: {}", what, e)))
+}
+
+/// Extract the field name from a serde `missing field` error message.
+fn missing_field_name(message: &str) -> Option<String> {
+    let rest = message.strip_prefix("missing field `")?;
+    rest.split('`').next().map(str::to_string)
+}
+
+/// Insert the first placeholder that gets past the missing-field error for `field`.
+fn fill_placeholder<T: DeserializeOwned>(
+    probe: &mut serde_json::Map<String, Value>,
+    field: &str,
+) -> bool {
+    for placeholder in placeholders() {
+        probe.insert(field.to_string(), placeholder);
+        match serde_json::from_value::<T>(Value::Object(probe.clone())) {
+            Ok(_) => return true,
+            Err(e) if missing_field_name(&e.to_string()).is_some_and(|f| f != field) => {
+                return true;
+            }
+            Err(_) => {}
+        }
+    }
+    false
+}
+
+/// Top-level keys in `object` that do not survive a round trip through `model`.
+fn unexpected_fields<T: Serialize>(
+    object: &serde_json::Map<String, Value>,
+    model: &T,
+) -> Vec<String> {
+    let captured: BTreeSet<String> = match serde_json::to_value(model) {
+        Ok(Value::Object(map)) => map.into_iter().map(|(key, _)| key).collect(),
+        _ => return Vec::new(),
+    };
+    object
+        .iter()
+        .filter(|(key, value)| !value.is_null() && !captured.contains(*key))
+        .map(|(key, _)| key.clone())
+        .collect()
+}
+
+fn value_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Sample {
+        name: String,
+        count: u64,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        owner: Option<String>,
+    }
+
+    #[test]
+    fn test_check_model_reports_unexpected_fields() {
+        let issues = check_model::<Sample>(&json!({
+            "name": "main",
+            "count": 3,
+            "owner": null,
+            "frozenTimePeriodInSecs": 100
+        }));
+        assert_eq!(issues.unexpected_fields, vec!["frozenTimePeriodInSecs"]);
+        assert!(!issues.is_failure());
+        assert!(!issues.is_clean());
+    }
+
+    #[test]
+    fn test_check_model_reports_every_missing_field() {
+        let issues = check_model::<Sample>(&json!({ "owner": "admin" }));
+        assert_eq!(issues.missing_fields, vec!["name", "count"]);
+        assert!(issues.error.is_none());
+        assert_eq!(issues.to_string(), "missing fields: name, count");
+    }
+
+    #[test]
+    fn test_check_model_reports_type_errors_and_non_objects() {
+        let issues = check_model::<Sample>(&json!({ "name": "main", "count": "many" }));
+        assert!(issues.error.as_deref().unwrap().contains("invalid type"));
+
+        let issues = check_model::<Sample>(&json!(["main"]));
+        assert_eq!(
+            issues.error.as_deref(),
+            Some("expected a JSON object, got an array")
+        );
+    }
+
+    #[cfg(feature = "strict-models")]
+    #[test]
+    fn test_parse_entry_content_lists_every_missing_field_in_strict_mode() {
+        let err = parse_entry_content::<Sample>(&json!({}), "sample").unwrap_err();
+        assert!(
+            matches!(&err, ClientError::InvalidResponse(msg) if msg == "Failed to parse sample: missing fields: name, count"),
+            "{err}"
+        );
+    }
+
+    #[cfg(not(feature = "strict-models"))]
+    #[test]
+    fn test_parse_entry_content_names_model_on_error() {
+        let err = parse_entry_content::<Sample>(&json!({ "count": 1 }), "sample").unwrap_err();
+        assert!(
+            matches!(&err, ClientError::InvalidResponse(msg) if msg.starts_with("Failed to parse sample: missing field `name`")),
+            "{err}"
+        );
+    }
+}
@@ -143,6 +143,86 @@ where
     }
 }
 
+/// Deserialize a float that Splunk may return as `1.5` or `"1.5"` (e.g. `stats sum()` output).
+pub fn f64_from_string_or_number<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = StringOrNumber::deserialize(deserializer)?;
+    match value {
+        StringOrNumber::String(s) => s.trim().parse::<f64>().map_err(D::Error::custom),
+        StringOrNumber::U64(v) => Ok(v as f64),
+        StringOrNumber::I64(v) => Ok(v as f64),
+        StringOrNumber::F64(v) => Ok(v),
+    }
+}
+
+/// Deserialize an optional float that Splunk may return as `1.5` or `"1.5"`.
+pub fn opt_f64_from_string_or_number<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = Option::<StringOrNumber>::deserialize(deserializer)?;
+    match value {
+        None => Ok(None),
+        Some(StringOrNumber::String(s)) => {
+            Ok(Some(s.trim().parse::<f64>().map_err(D::Error::custom)?))
+        }
+        Some(StringOrNumber::U64(v)) => Ok(Some(v as f64)),
+        Some(StringOrNumber::I64(v)) => Ok(Some(v as f64)),
+        Some(StringOrNumber::F64(v)) => Ok(Some(v)),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum StringOrSeq {
+    String(String),
+    Seq(Vec<String>),
+}
+
+/// Deserialize a multivalue search field, which Splunk returns as a plain
+/// string when it holds one value and as an array when it holds several.
+pub fn vec_string_from_string_or_seq<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = Option::<StringOrSeq>::deserialize(deserializer)?;
+    match value {
+        None => Ok(Vec::new()),
+        Some(StringOrSeq::String(s)) => Ok(vec![s]),
+        Some(StringOrSeq::Seq(values)) => Ok(values),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum BoolOrString {
+    Bool(bool),
+    U64(u64),
+    String(String),
+}
+
+/// Deserialize a conf-file boolean that Splunk may return as `true`, `1`, or `"1"`.
+///
+/// Accepts the spellings Splunk itself accepts (`1/0`, `true/false`, `t/f`,
+/// `yes/no`, case-insensitive); anything else is a parse error.
+pub fn bool_from_string_or_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = BoolOrString::deserialize(deserializer)?;
+    match value {
+        BoolOrString::Bool(b) => Ok(b),
+        BoolOrString::U64(v) => Ok(v != 0),
+        BoolOrString::String(s) => match s.trim().to_ascii_lowercase().as_str() {
+            "1" | "true" | "t" | "yes" | "y" => Ok(true),
+            "0" | "false" | "f" | "no" | "n" | "" => Ok(false),
+            other => Err(D::Error::custom(format!("invalid boolean value: {other}"))),
+        },
+    }
+}
+
 #[allow(dead_code)]
 pub fn map_string_to_u64_from_string_or_number<'de, D>(
     deserializer: D,
@@ -232,6 +312,24 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_vec_string_from_string_or_seq_accepts_single_and_multi_values() {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            #[serde(default, deserialize_with = "vec_string_from_string_or_seq")]
+            value: Vec<String>,
+        }
+
+        let parsed: Wrapper = serde_json::from_str(r#"{ "value": "main" }"#).unwrap();
+        assert_eq!(parsed.value, vec!["main"]);
+
+        let parsed: Wrapper = serde_json::from_str(r#"{ "value": ["main", "web"] }"#).unwrap();
+        assert_eq!(parsed.value, vec!["main", "web"]);
+
+        let parsed: Wrapper = serde_json::from_str(r#"{}"#).unwrap();
+        assert!(parsed.value.is_empty());
+    }
+
     #[test]
     fn test_u64_from_string_or_number_accepts_number() {
         #[derive(Deserialize)]
@@ -298,6 +396,46 @@ mod tests {
         assert_eq!(parsed.value, "3");
     }
 
+    #[test]
+    fn test_f64_from_string_or_number_accepts_number_and_string() {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            #[serde(deserialize_with = "f64_from_string_or_number")]
+            value: f64,
+        }
+
+        let parsed: Wrapper = serde_json::from_str(r#"{ "value": 2 }"#).unwrap();
+        assert_eq!(parsed.value, 2.0);
+
+        let parsed: Wrapper = serde_json::from_str(r#"{ "value": "12.5" }"#).unwrap();
+        assert_eq!(parsed.value, 12.5);
+
+        assert!(serde_json::from_str::<Wrapper>(r#"{ "value": "n/a" }"#).is_err());
+    }
+
+    #[test]
+    fn test_bool_from_string_or_bool_accepts_conf_spellings() {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            #[serde(deserialize_with = "bool_from_string_or_bool")]
+            value: bool,
+        }
+
+        for (raw, expected) in [
+            ("true", true),
+            ("1", true),
+            (r#""1""#, true),
+            (r#""True""#, true),
+            (r#""0""#, false),
+            (r#""no""#, false),
+        ] {
+            let parsed: Wrapper =
+                serde_json::from_str(&format!(r#"{{ "value": {raw} }}"#)).unwrap();
+            assert_eq!(parsed.value, expected, "raw={raw}");
+        }
+        assert!(serde_json::from_str::<Wrapper>(r#"{ "value": "maybe" }"#).is_err());
+    }
+
     #[test]
     fn test_map_string_to_u64_from_string_or_number_accepts_strings() {
         #[derive(Deserialize)]
@@ -0,0 +1,247 @@
+//! Fixture validation against the current model definitions.
+//!
+//! Responsibilities:
+//! - Map entry-shaped fixtures to the model their `entry[].content` parses into
+//! - Check every entry with `schema_check::check_model` and collect the issues
+//!
+//! Does NOT handle:
+//! - Search-results fixtures (`results` rows are parsed by endpoint-specific code)
+//! - Printing reports (see the `validate-fixtures` binary)
+//!
+//! Invariants:
+//! - Registry paths are relative to the fixtures directory and use `/` separators
+//! - JSON fixtures missing from the registry are reported as unchecked, never as passing
+
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use crate::models::{
+    AlertAction, App, ClusterFixupContent, ClusterInfo, ClusterSearchHead, ConfigStanza, EventType,
+    FiredAlert, Forwarder, HecToken, Index, Input, KvStoreStatus, LicenseMessage, LicensePool,
+    LicenseSlave, LicenseStack, LicenseUsage, LookupTable, Macro, SavedSearch, SearchJobStatus,
+    SearchPeer, ServerInfo, SplunkHealth, User,
+};
+use crate::schema_check::{ModelIssues, check_model};
+
+type Checker = fn(&Value) -> ModelIssues;
+
+/// Build registry rows of (fixture path, model name, checker).
+macro_rules! fixture_models {
+    ($($path:literal => $model:ty),* $(,)?) => {
+        &[$(($path, stringify!($model), check_model::<$model>)),*]
+    };
+}
+
+/// Fixtures whose `entry[].content` objects parse into a known model.
+const FIXTURE_MODELS: &[(&str, &str, Checker)] = fixture_models! {
+    "alerts/get_fired_alert.json" => FiredAlert,
+    "alerts/list_alert_actions.json" => AlertAction,
+    "alerts/list_fired_alerts.json" => FiredAlert,
+    "apps/get_app.json" => App,
+    "apps/install_app.json" => App,
+    "apps/list_apps.json" => App,
+    "apps/list_apps_empty.json" => App,
+    "apps/update_app.json" => App,
+    "cluster/get_cluster_fixups.json" => ClusterFixupContent,
+    "cluster/get_cluster_info.json" => ClusterInfo,
+    "cluster/get_cluster_searchheads.json" => ClusterSearchHead,
+    "configs/get_config_stanza.json" => ConfigStanza,
+    "configs/list_config_stanzas.json" => ConfigStanza,
+    "event_types/list_event_types.json" => EventType,
+    "forwarders/list_forwarders.json" => Forwarder,
+    "hec_tokens/create_hec_token.json" => HecToken,
+    "hec_tokens/list_hec_tokens.json" => HecToken,
+    "indexes/create_index.json" => Index,
+    "indexes/list_indexes.json" => Index,
+    "indexes/modify_index.json" => Index,
+    "inputs/enable_input.json" => Input,
+    "inputs/list_inputs_monitor.json" => Input,
+    "inputs/list_inputs_tcp.json" => Input,
+    "jobs/cancel_job_success.json" => SearchJobStatus,
+    "jobs/list_jobs.json" => SearchJobStatus,
+    "kvstore/status.json" => KvStoreStatus,
+    "license/get_usage.json" => LicenseUsage,
+    "license/list_messages.json" => LicenseMessage,
+    "license/list_pools.json" => LicensePool,
+    "license/list_slaves.json" => LicenseSlave,
+    "license/list_stacks.json" => LicenseStack,
+    "lookups/list_lookup_tables.json" => LookupTable,
+    "lookups/list_lookup_tables_empty.json" => LookupTable,
+    "macros/get_macro.json" => Macro,
+    "macros/list_macros.json" => Macro,
+    "search/create_job_success.json" => SearchJobStatus,
+    "search/get_saved_search.json" => SavedSearch,
+    "search/list_saved_searches.json" => SavedSearch,
+    "search_peers/list_search_peers.json" => SearchPeer,
+    "server/get_health.json" => SplunkHealth,
+    "server/get_server_info.json" => ServerInfo,
+    "users/create_user.json" => User,
+    "users/list_users.json" => User,
+    "users/modify_user.json" => User,
+    "users/unlock_user.json" => User,
+};
+
+/// Issues found in one entry of a fixture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryIssues {
+    /// The entry's `name`, or its position when unnamed.
+    pub entry: String,
+    /// Differences between the entry content and the model.
+    pub issues: ModelIssues,
+}
+
+/// Outcome of validating a single fixture file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FixtureOutcome {
+    /// Every entry parsed and captured every field.
+    Clean,
+    /// At least one entry has unexpected fields, but all entries parse.
+    Drift(Vec<EntryIssues>),
+    /// At least one entry cannot be parsed into the model.
+    Failed(Vec<EntryIssues>),
+    /// The file could not be read or is not an entry-shaped response.
+    Unreadable(String),
+    /// No model is registered for this fixture.
+    Unchecked,
+}
+
+/// Validation result for a single fixture file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixtureReport {
+    /// Path relative to the fixtures directory.
+    pub path: String,
+    /// Registered model name, if any.
+    pub model: Option<&'static str>,
+    /// What validation found.
+    pub outcome: FixtureOutcome,
+}
+
+/// Default fixtures directory of the client crate.
+pub fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures")
+}
+
+/// Check the `entry[].content` objects of a response against model `check`.
+pub fn check_entries(response: &Value, check: Checker) -> Result<Vec<EntryIssues>, String> {
+    let entries = response
+        .get("entry")
+        .and_then(Value::as_array)
+        .ok_or_else(|| "missing 'entry' array".to_string())?;
+
+    Ok(entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let name = entry
+                .get("name")
+                .and_then(Value::as_str)
+                .map_or_else(|| format!("#{}", i), str::to_string);
+            let issues = match entry.get("content") {
+                Some(content) => check(content),
+                None => ModelIssues {
+                    error: Some("missing 'content' field".to_string()),
+                    ..ModelIssues::default()
+                },
+            };
+            EntryIssues {
+                entry: name,
+                issues,
+            }
+        })
+        .filter(|entry| !entry.issues.is_clean())
+        .collect())
+}
+
+/// Validate every JSON file under `dir` against the registered models.
+///
+/// Reports are sorted by path. Files in `dir` without a registry entry are
+/// reported as [`FixtureOutcome::Unchecked`]; registered files absent from
+/// `dir` are skipped, so a directory of captured live responses can be
+/// validated the same way as the bundled fixtures.
+pub fn validate_fixtures(dir: &Path) -> std::io::Result<Vec<FixtureReport>> {
+    let mut paths = Vec::new();
+    collect_json_files(dir, dir, &mut paths)?;
+    paths.sort();
+
+    Ok(paths
+        .into_iter()
+        .map(|path| {
+            let registered = FIXTURE_MODELS.iter().find(|(p, _, _)| *p == path);
+            let outcome = match registered {
+                Some((_, _, check)) => validate_file(&dir.join(&path), *check),
+                None => FixtureOutcome::Unchecked,
+            };
+            FixtureReport {
+                model: registered.map(|(_, model, _)| *model),
+                path,
+                outcome,
+            }
+        })
+        .collect())
+}
+
+fn validate_file(path: &Path, check: Checker) -> FixtureOutcome {
+    let response = match std::fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|s| serde_json::from_str::<Value>(&s).map_err(|e| e.to_string()))
+    {
+        Ok(response) => response,
+        Err(e) => return FixtureOutcome::Unreadable(e),
+    };
+
+    match check_entries(&response, check) {
+        Err(e) => FixtureOutcome::Unreadable(e),
+        Ok(entries) if entries.is_empty() => FixtureOutcome::Clean,
+        Ok(entries) if entries.iter().any(|e| e.issues.is_failure()) => {
+            FixtureOutcome::Failed(entries)
+        }
+        Ok(entries) => FixtureOutcome::Drift(entries),
+    }
+}
+
+fn collect_json_files(root: &Path, dir: &Path, out: &mut Vec<String>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_json_files(root, &path, out)?;
+        } else if path.extension().is_some_and(|ext| ext == "json")
+            && let Ok(relative) = path.strip_prefix(root)
+        {
+            let parts: Vec<_> = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                .collect();
+            out.push(parts.join("/"));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_check_entries_names_entries_with_issues() {
+        let response = json!({
+            "entry": [
+                { "name": "fixup-1", "content": { "index": "main" } },
+                { "content": { "index": "web", "surprise": 1 } }
+            ]
+        });
+        let entries = check_entries(&response, check_model::<ClusterFixupContent>).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].entry, "#1");
+        assert_eq!(entries[0].issues.unexpected_fields, vec!["surprise"]);
+    }
+
+    #[test]
+    fn test_registry_paths_exist() {
+        let dir = fixtures_dir();
+        for (path, _, _) in FIXTURE_MODELS {
+            assert!(dir.join(path).is_file(), "missing fixture {}", path);
+        }
+    }
+}
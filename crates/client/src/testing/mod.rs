@@ -18,6 +18,7 @@
 //! let data = generator.generate();
 //! ```
 
+pub mod fixture_validation;
 #[cfg(any(feature = "test-utils", test))]
 pub mod generators;
 
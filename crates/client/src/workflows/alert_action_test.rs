@@ -0,0 +1,347 @@
+//! Shared alert action test-fire workflow.
+//!
+//! Purpose:
+//! - Let on-call teams verify that a saved search's alert actions actually deliver,
+//!   without waiting for (or forcing) the real trigger condition.
+//!
+//! Responsibilities:
+//! - Resolve the actions a saved search triggers, optionally narrowed to named actions.
+//! - Build one test-fire search per action that delivers a single synthetic result
+//!   using the saved search's own action parameters.
+//! - Dispatch the searches (unless dry run) and report per-action outcomes.
+//!
+//! Does NOT handle:
+//! - Output formatting (frontend concern).
+//! - Running the saved search itself or evaluating its trigger condition.
+//! - Legacy scripted alerts and data-writing actions (summary index, lookups),
+//!   which cannot be invoked from a search and are reported as unsupported.
+//!
+//! Invariants:
+//! - The synthetic result is tagged `splunk_cli_test=1` and its subject marked `[TEST]`.
+//! - A failure on one action is recorded and does not stop the remaining actions.
+//! - Dry runs never dispatch searches.
+
+use anyhow::{Result, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::SplunkClient;
+use crate::models::TriggeredAlertAction;
+use crate::workflows::{CancellationProbe, ensure_not_cancelled};
+
+/// Actions that cannot be test-fired from a search, with the reason shown to users.
+const UNSUPPORTED_ACTIONS: &[(&str, &str)] = &[
+    (
+        "script",
+        "legacy scripted alerts cannot be invoked from a search; run the script directly",
+    ),
+    (
+        "summary_index",
+        "summary indexing writes data instead of delivering a notification",
+    ),
+    (
+        "populate_lookup",
+        "lookup population writes data instead of delivering a notification",
+    ),
+    (
+        "lookup",
+        "lookup output writes data instead of delivering a notification",
+    ),
+    ("rss", "RSS feeds are only updated by the scheduler"),
+];
+
+/// Email settings passed through to `sendemail` (as `action.email.<key>`).
+const EMAIL_PASSTHROUGH: &[&str] = &["cc", "bcc", "from", "format", "priority"];
+
+/// Input for an alert action test-fire run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlertActionTestRequest {
+    /// Saved search whose alert actions are tested.
+    pub saved_search: String,
+    /// Only test these actions; every triggered action when empty.
+    pub actions: Vec<String>,
+    /// Only build the test searches without dispatching them.
+    pub dry_run: bool,
+}
+
+/// Outcome of test-firing a single alert action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertActionTestStatus {
+    Planned,
+    Fired,
+    Failed,
+    Unsupported,
+}
+
+impl AlertActionTestStatus {
+    pub const fn slug(self) -> &'static str {
+        match self {
+            Self::Planned => "planned",
+            Self::Fired => "fired",
+            Self::Failed => "failed",
+            Self::Unsupported => "unsupported",
+        }
+    }
+}
+
+/// Per-action entry in a test-fire report.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlertActionTestItem {
+    pub action: String,
+    pub status: AlertActionTestStatus,
+    /// The test-fire search, when the action is supported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search: Option<String>,
+    /// Search ID of the dispatched test-fire job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>,
+    /// Job messages, the dispatch error, or the reason the action is unsupported.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub messages: Vec<String>,
+}
+
+/// Full report of an alert action test-fire run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlertActionTestReport {
+    pub saved_search: String,
+    pub dry_run: bool,
+    pub items: Vec<AlertActionTestItem>,
+}
+
+impl AlertActionTestReport {
+    /// Number of actions whose test-fire failed.
+    pub fn failed_count(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|item| item.status == AlertActionTestStatus::Failed)
+            .count()
+    }
+}
+
+/// Run the shared alert action test-fire workflow.
+pub async fn test_alert_actions(
+    client: &SplunkClient,
+    request: &AlertActionTestRequest,
+    cancel: Option<&dyn CancellationProbe>,
+) -> Result<AlertActionTestReport> {
+    ensure_not_cancelled(cancel)?;
+
+    let configured = client
+        .get_saved_search_alert_actions(&request.saved_search)
+        .await?;
+    let actions = select_actions(&request.saved_search, configured.actions, &request.actions)?;
+
+    let mut items = Vec::with_capacity(actions.len());
+    for action in &actions {
+        ensure_not_cancelled(cancel)?;
+
+        let search = match build_test_search(&request.saved_search, action) {
+            Ok(search) => search,
+            Err(reason) => {
+                items.push(AlertActionTestItem {
+                    action: action.name.clone(),
+                    status: AlertActionTestStatus::Unsupported,
+                    search: None,
+                    sid: None,
+                    messages: vec![reason],
+                });
+                continue;
+            }
+        };
+
+        let item = if request.dry_run {
+            AlertActionTestItem {
+                action: action.name.clone(),
+                status: AlertActionTestStatus::Planned,
+                search: Some(search),
+                sid: None,
+                messages: Vec::new(),
+            }
+        } else {
+            match client.dispatch_alert_action_test(&search).await {
+                Ok(dispatch) => AlertActionTestItem {
+                    action: action.name.clone(),
+                    status: if dispatch.failed {
+                        AlertActionTestStatus::Failed
+                    } else {
+                        AlertActionTestStatus::Fired
+                    },
+                    search: Some(search),
+                    sid: Some(dispatch.sid),
+                    messages: dispatch.messages,
+                },
+                Err(error) => AlertActionTestItem {
+                    action: action.name.clone(),
+                    status: AlertActionTestStatus::Failed,
+                    search: Some(search),
+                    sid: None,
+                    messages: vec![error.to_string()],
+                },
+            }
+        };
+        items.push(item);
+    }
+
+    Ok(AlertActionTestReport {
+        saved_search: request.saved_search.clone(),
+        dry_run: request.dry_run,
+        items,
+    })
+}
+
+/// Narrow the triggered actions to the requested names, preserving saved-search order.
+fn select_actions(
+    saved_search: &str,
+    configured: Vec<TriggeredAlertAction>,
+    requested: &[String],
+) -> Result<Vec<TriggeredAlertAction>> {
+    if configured.is_empty() {
+        bail!(
+            "Saved search '{}' does not trigger any alert actions",
+            saved_search
+        );
+    }
+    if let Some(missing) = requested
+        .iter()
+        .find(|name| !configured.iter().any(|action| &action.name == *name))
+    {
+        let available: Vec<&str> = configured.iter().map(|a| a.name.as_str()).collect();
+        bail!(
+            "Saved search '{}' does not trigger alert action '{}' (configured: {})",
+            saved_search,
+            missing,
+            available.join(", ")
+        );
+    }
+
+    Ok(configured
+        .into_iter()
+        .filter(|action| requested.is_empty() || requested.contains(&action.name))
+        .collect())
+}
+
+/// Build the search that delivers one synthetic result through `action`.
+///
+/// Email goes through `sendemail` with the saved search's recipients; every
+/// other action is treated as a custom (modular) action and invoked with
+/// `sendalert`, passing its `param.*` settings. Returns the reason as the
+/// error when the action cannot be test-fired.
+pub fn build_test_search(
+    saved_search: &str,
+    action: &TriggeredAlertAction,
+) -> std::result::Result<String, String> {
+    if let Some((_, reason)) = UNSUPPORTED_ACTIONS
+        .iter()
+        .find(|(name, _)| *name == action.name)
+    {
+        return Err((*reason).to_string());
+    }
+
+    let mut search = format!(
+        "| makeresults | eval splunk_cli_test=1, savedsearch_name=\"{}\", message=\"Test of alert action '{}' sent by splunk-cli alerts test-action\"",
+        escape_spl_string(saved_search),
+        escape_spl_string(&action.name)
+    );
+
+    if action.name == "email" {
+        let to = action
+            .params
+            .get("to")
+            .filter(|to| !to.trim().is_empty())
+            .ok_or_else(|| "no recipients configured (action.email.to)".to_string())?;
+        let subject = format!("[TEST] Splunk Alert: {}", saved_search);
+        search.push_str(&format!(
+            " | sendemail to=\"{}\" subject=\"{}\"",
+            escape_spl_string(to),
+            escape_spl_string(&subject)
+        ));
+        for key in EMAIL_PASSTHROUGH {
+            if let Some(value) = action.params.get(*key) {
+                search.push_str(&format!(" {}=\"{}\"", key, escape_spl_string(value)));
+            }
+        }
+        search.push_str(" sendresults=true inline=true");
+    } else {
+        search.push_str(&format!(" | sendalert {}", action.name));
+        for (key, value) in &action.params {
+            if key.starts_with("param.") {
+                search.push_str(&format!(" {}=\"{}\"", key, escape_spl_string(value)));
+            }
+        }
+    }
+
+    Ok(search)
+}
+
+fn escape_spl_string(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn action(name: &str, params: &[(&str, &str)]) -> TriggeredAlertAction {
+        TriggeredAlertAction {
+            name: name.to_string(),
+            params: params
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<BTreeMap<_, _>>(),
+        }
+    }
+
+    #[test]
+    fn test_build_test_search_for_webhook_passes_params() {
+        let search = build_test_search(
+            "Errors \"prod\"",
+            &action(
+                "webhook",
+                &[
+                    ("param.url", "https://hooks.example.com/a"),
+                    ("command", "x"),
+                ],
+            ),
+        )
+        .unwrap();
+
+        assert!(search.starts_with("| makeresults | eval splunk_cli_test=1"));
+        assert!(search.contains(r#"savedsearch_name="Errors \"prod\"""#));
+        assert!(search.ends_with(r#"| sendalert webhook param.url="https://hooks.example.com/a""#));
+    }
+
+    #[test]
+    fn test_build_test_search_for_email_requires_recipients() {
+        let search = build_test_search(
+            "Errors",
+            &action(
+                "email",
+                &[("to", "oncall@example.com"), ("cc", "lead@example.com")],
+            ),
+        )
+        .unwrap();
+        assert!(search.contains(
+            r#"| sendemail to="oncall@example.com" subject="[TEST] Splunk Alert: Errors" cc="lead@example.com" sendresults=true"#
+        ));
+
+        assert!(build_test_search("Errors", &action("email", &[])).is_err());
+        assert!(build_test_search("Errors", &action("script", &[])).is_err());
+    }
+
+    #[test]
+    fn test_select_actions_rejects_unknown_names() {
+        let configured = vec![action("email", &[]), action("webhook", &[])];
+
+        let all = select_actions("Errors", configured.clone(), &[]).unwrap();
+        assert_eq!(all.len(), 2);
+
+        let only = select_actions("Errors", configured.clone(), &["webhook".to_string()]).unwrap();
+        assert_eq!(only, vec![action("webhook", &[])]);
+
+        let err = select_actions("Errors", configured, &["slack".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("configured: email, webhook"));
+
+        assert!(select_actions("Errors", Vec::new(), &[]).is_err());
+    }
+}
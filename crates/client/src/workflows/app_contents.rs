@@ -0,0 +1,309 @@
+//! Shared per-app object inventory workflow.
+//!
+//! Purpose:
+//! - Answer "what does this app ship?" by listing the knowledge objects defined in one app.
+//!
+//! Responsibilities:
+//! - Query saved searches, dashboards, lookup files, macros and event types in the
+//!   app's namespace, filtered to objects whose ACL app is that app.
+//! - Flatten the results into one summary row per object.
+//!
+//! Does NOT handle:
+//! - Output formatting (frontend concern).
+//! - Objects owned by other apps but shared globally into this one.
+//!
+//! Invariants:
+//! - Objects are grouped by kind in [`AppObjectKind::ALL`] order, then sorted by name.
+
+use std::fmt;
+
+use anyhow::Result;
+use futures::TryStreamExt;
+use serde::{Deserialize, Serialize};
+
+use crate::SplunkClient;
+use crate::format_bytes;
+use crate::models::Namespace;
+use crate::pagination::{PaginationOptions, paginate_all};
+
+/// Kind of knowledge object an app can ship.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AppObjectKind {
+    SavedSearch,
+    Dashboard,
+    Lookup,
+    Macro,
+    EventType,
+}
+
+impl AppObjectKind {
+    /// Every kind, in inventory order.
+    pub const ALL: [AppObjectKind; 5] = [
+        AppObjectKind::SavedSearch,
+        AppObjectKind::Dashboard,
+        AppObjectKind::Lookup,
+        AppObjectKind::Macro,
+        AppObjectKind::EventType,
+    ];
+
+    /// Plural heading for a group of objects of this kind.
+    pub const fn title(self) -> &'static str {
+        match self {
+            AppObjectKind::SavedSearch => "Saved Searches",
+            AppObjectKind::Dashboard => "Dashboards",
+            AppObjectKind::Lookup => "Lookups",
+            AppObjectKind::Macro => "Macros",
+            AppObjectKind::EventType => "Event Types",
+        }
+    }
+}
+
+impl fmt::Display for AppObjectKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            AppObjectKind::SavedSearch => "saved_search",
+            AppObjectKind::Dashboard => "dashboard",
+            AppObjectKind::Lookup => "lookup",
+            AppObjectKind::Macro => "macro",
+            AppObjectKind::EventType => "event_type",
+        };
+        f.write_str(label)
+    }
+}
+
+/// One knowledge object defined in an app.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppObject {
+    pub kind: AppObjectKind,
+    pub name: String,
+    /// Short kind-specific summary: the search or definition, dashboard label, or file size.
+    pub detail: String,
+    pub disabled: bool,
+}
+
+/// Knowledge objects shipped by an app.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppContents {
+    pub app: String,
+    pub objects: Vec<AppObject>,
+}
+
+impl AppContents {
+    /// Objects of one kind, sorted by name.
+    pub fn of_kind(&self, kind: AppObjectKind) -> impl Iterator<Item = &AppObject> {
+        self.objects
+            .iter()
+            .filter(move |object| object.kind == kind)
+    }
+
+    /// Number of objects of one kind.
+    pub fn count(&self, kind: AppObjectKind) -> usize {
+        self.of_kind(kind).count()
+    }
+}
+
+/// List the saved searches, dashboards, lookups, macros and event types defined in `app`.
+pub async fn fetch_app_contents(client: &SplunkClient, app: &str) -> Result<AppContents> {
+    let namespace = Namespace::app(app);
+    let ns = Some(&namespace);
+
+    let (saved_searches, dashboards, lookups, macros, event_types) = futures::try_join!(
+        paginate_all(PaginationOptions::default(), |count, offset| {
+            client.list_saved_searches(Some(count), Some(offset), ns)
+        })
+        .try_collect::<Vec<_>>(),
+        paginate_all(PaginationOptions::default(), |count, offset| {
+            client.list_dashboards(Some(count), Some(offset), ns)
+        })
+        .try_collect::<Vec<_>>(),
+        paginate_all(PaginationOptions::default(), |count, offset| {
+            client.list_lookup_tables(Some(count), Some(offset), ns)
+        })
+        .try_collect::<Vec<_>>(),
+        client.list_macros(ns),
+        paginate_all(PaginationOptions::default(), |count, offset| {
+            client.list_event_types(Some(count), Some(offset), ns)
+        })
+        .try_collect::<Vec<_>>(),
+    )?;
+
+    let mut groups: [Vec<AppObject>; 5] = [
+        saved_searches
+            .into_iter()
+            .map(|search| AppObject {
+                kind: AppObjectKind::SavedSearch,
+                name: search.name,
+                detail: search.search,
+                disabled: search.disabled,
+            })
+            .collect(),
+        dashboards
+            .into_iter()
+            .map(|dashboard| AppObject {
+                kind: AppObjectKind::Dashboard,
+                name: dashboard.name,
+                detail: dashboard.label,
+                disabled: false,
+            })
+            .collect(),
+        lookups
+            .into_iter()
+            .map(|lookup| AppObject {
+                kind: AppObjectKind::Lookup,
+                name: lookup.name,
+                detail: format_bytes(lookup.size),
+                disabled: false,
+            })
+            .collect(),
+        macros
+            .into_iter()
+            .map(|m| AppObject {
+                kind: AppObjectKind::Macro,
+                name: m.name,
+                detail: m.definition,
+                disabled: m.disabled,
+            })
+            .collect(),
+        event_types
+            .into_iter()
+            .map(|event_type| AppObject {
+                kind: AppObjectKind::EventType,
+                name: event_type.name,
+                detail: event_type.search,
+                disabled: event_type.disabled,
+            })
+            .collect(),
+    ];
+
+    let mut objects = Vec::new();
+    for group in &mut groups {
+        group.sort_by(|a, b| a.name.cmp(&b.name));
+        objects.append(group);
+    }
+
+    Ok(AppContents {
+        app: app.to_string(),
+        objects,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AuthStrategy;
+    use secrecy::SecretString;
+    use serde_json::{Value, json};
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn list(entries: Value) -> Value {
+        json!({ "entry": entries })
+    }
+
+    async fn mount(server: &MockServer, endpoint: &str, body: Value) {
+        Mock::given(method("GET"))
+            .and(path(format!("/servicesNS/-/my_app/{endpoint}")))
+            .and(query_param("search", "eai:acl.app=\"my_app\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(server)
+            .await;
+    }
+
+    fn client(server: &MockServer) -> SplunkClient {
+        SplunkClient::builder()
+            .base_url(server.uri())
+            .auth_strategy(AuthStrategy::ApiToken {
+                token: SecretString::new("test-token".to_string().into()),
+            })
+            .max_retries(0)
+            .build()
+            .expect("client should build")
+    }
+
+    #[tokio::test]
+    async fn fetch_app_contents_lists_every_kind_in_the_app_namespace() {
+        let server = MockServer::start().await;
+        mount(
+            &server,
+            "saved/searches",
+            list(json!([
+                {"name": "Zeta alert", "content": {"search": "index=main error", "disabled": true}},
+                {"name": "Alpha report", "content": {"search": "index=main | stats count"}}
+            ])),
+        )
+        .await;
+        mount(
+            &server,
+            "data/ui/views",
+            list(json!([{"name": "overview", "content": {"label": "App Overview"}}])),
+        )
+        .await;
+        mount(
+            &server,
+            "data/lookup-table-files",
+            list(json!([{
+                "name": "hosts.csv",
+                "content": {"eai:data": "/opt/splunk/etc/apps/my_app/lookups/hosts.csv", "size": 2048},
+                "acl": {"app": "my_app", "owner": "nobody", "sharing": "app"}
+            }])),
+        )
+        .await;
+        mount(
+            &server,
+            "admin/macros",
+            list(json!([{"name": "my_index", "content": {"definition": "index=my_app"}}])),
+        )
+        .await;
+        mount(
+            &server,
+            "saved/eventtypes",
+            list(json!([{"name": "my_errors", "content": {"search": "`my_index` error"}}])),
+        )
+        .await;
+
+        let contents = fetch_app_contents(&client(&server), "my_app")
+            .await
+            .unwrap();
+
+        assert_eq!(contents.app, "my_app");
+        let names: Vec<&str> = contents.objects.iter().map(|o| o.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "Alpha report",
+                "Zeta alert",
+                "overview",
+                "hosts.csv",
+                "my_index",
+                "my_errors"
+            ]
+        );
+        assert_eq!(contents.count(AppObjectKind::SavedSearch), 2);
+        assert!(contents.objects[1].disabled);
+        let dashboard = contents.of_kind(AppObjectKind::Dashboard).next().unwrap();
+        assert_eq!(dashboard.detail, "App Overview");
+        let lookup = contents.of_kind(AppObjectKind::Lookup).next().unwrap();
+        assert_eq!(lookup.detail, format_bytes(2048));
+    }
+
+    #[tokio::test]
+    async fn fetch_app_contents_fails_when_any_listing_fails() {
+        let server = MockServer::start().await;
+        for endpoint in ["saved/searches", "data/ui/views", "data/lookup-table-files"] {
+            mount(&server, endpoint, list(json!([]))).await;
+        }
+        mount(&server, "saved/eventtypes", list(json!([]))).await;
+        Mock::given(method("GET"))
+            .and(path("/servicesNS/-/my_app/admin/macros"))
+            .respond_with(ResponseTemplate::new(403))
+            .mount(&server)
+            .await;
+
+        assert!(
+            fetch_app_contents(&client(&server), "my_app")
+                .await
+                .is_err()
+        );
+    }
+}
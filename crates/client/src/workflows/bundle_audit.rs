@@ -0,0 +1,507 @@
+//! Shared knowledge bundle replication audit workflow.
+//!
+//! Purpose:
+//! - Find large lookup files that search heads replicate to every search peer,
+//!   the most common cause of oversized knowledge bundles and failed bundle pushes.
+//!
+//! Responsibilities:
+//! - Read the `distsearch.conf` replication allow/deny lists and bundle size
+//!   settings via the configs endpoints.
+//! - Estimate each lookup's bundle path and size from the lookup-table-files listing.
+//! - Decide whether each lookup is replicated and which rule decided it.
+//!
+//! Does NOT handle:
+//! - Output formatting (frontend concern).
+//! - Non-lookup bundle content (conf files, scripts, binaries): only lookups
+//!   carry a size in the REST listings.
+//! - Editing `distsearch.conf`; the report suggests deny-list entries instead.
+//!
+//! Invariants:
+//! - Rule patterns are Splunk wildcard regexes matched against the path relative
+//!   to `$SPLUNK_HOME/etc`: `...` spans directories and `*` stays within one.
+//! - Deny-list matches win over allow-list matches, as in Splunk.
+//! - Audited lookups are sorted largest first.
+
+use anyhow::Result;
+use futures::TryStreamExt;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::SplunkClient;
+use crate::models::{ConfigStanza, LookupTable};
+use crate::pagination::{PaginationOptions, paginate_all};
+
+/// Stanzas listing files to replicate (`Whitelist` is the pre-9.0 name).
+const ALLOW_STANZAS: &[&str] = &["replicationAllowlist", "replicationWhitelist"];
+
+/// Stanzas listing files to keep out of the bundle (`Blacklist` is the pre-9.0 name).
+const DENY_STANZAS: &[&str] = &["replicationDenylist", "replicationBlacklist"];
+
+/// Default lookup size worth flagging.
+pub const DEFAULT_MIN_LOOKUP_SIZE_MB: u64 = 10;
+
+const BYTES_PER_MB: u64 = 1024 * 1024;
+
+/// Input for a bundle replication audit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BundleAuditRequest {
+    /// Lookups at least this large are reported.
+    pub min_size_bytes: u64,
+}
+
+impl Default for BundleAuditRequest {
+    fn default() -> Self {
+        Self {
+            min_size_bytes: DEFAULT_MIN_LOOKUP_SIZE_MB * BYTES_PER_MB,
+        }
+    }
+}
+
+/// One allow- or deny-list entry from `distsearch.conf`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplicationRule {
+    pub stanza: String,
+    pub name: String,
+    pub pattern: String,
+}
+
+impl ReplicationRule {
+    fn matches(&self, path: &str) -> bool {
+        wildcard_regex(&self.pattern).is_some_and(|re| re.is_match(path))
+    }
+}
+
+/// Whether a lookup ends up in the knowledge bundle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReplicationStatus {
+    /// Shipped to every search peer.
+    Replicated,
+    /// Kept out by a deny-list entry.
+    Denied,
+    /// Not matched by any allow-list entry.
+    NotAllowed,
+    /// Larger than `excludeReplicatedLookupSize`, so Splunk drops it automatically.
+    SizeExcluded,
+}
+
+impl std::fmt::Display for ReplicationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            Self::Replicated => "replicated",
+            Self::Denied => "denied",
+            Self::NotAllowed => "not_allowed",
+            Self::SizeExcluded => "size_excluded",
+        };
+        f.pad(label)
+    }
+}
+
+/// A lookup file and its fate in the knowledge bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleLookup {
+    pub name: String,
+    pub app: String,
+    pub owner: String,
+    pub sharing: String,
+    /// Bundle path relative to `$SPLUNK_HOME/etc`.
+    pub path: String,
+    pub size_bytes: u64,
+    pub status: ReplicationStatus,
+    /// `stanza/name` of the rule that decided the status, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule: Option<String>,
+    /// `replicationDenylist` entry that would drop a flagged lookup from the bundle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_denylist_entry: Option<String>,
+}
+
+impl BundleLookup {
+    /// Replicated and at least `min_size_bytes`: a candidate for the deny list.
+    pub fn is_flagged(&self, min_size_bytes: u64) -> bool {
+        self.status == ReplicationStatus::Replicated && self.size_bytes >= min_size_bytes
+    }
+}
+
+/// Result of a bundle replication audit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleAuditReport {
+    pub min_size_bytes: u64,
+    /// `[replicationSettings] maxBundleSize`, in MB.
+    pub max_bundle_size_mb: Option<u64>,
+    /// `[replicationSettings] excludeReplicatedLookupSize`, in MB (0 disables).
+    pub exclude_replicated_lookup_size_mb: Option<u64>,
+    pub allow_rules: Vec<ReplicationRule>,
+    pub deny_rules: Vec<ReplicationRule>,
+    pub total_lookups: usize,
+    /// Combined size of every replicated lookup, flagged or not.
+    pub replicated_lookup_bytes: u64,
+    /// Lookups at or above the size threshold, largest first.
+    pub lookups: Vec<BundleLookup>,
+}
+
+impl BundleAuditReport {
+    /// Large lookups that are replicated and could be denied.
+    pub fn flagged(&self) -> impl Iterator<Item = &BundleLookup> {
+        self.lookups
+            .iter()
+            .filter(|lookup| lookup.is_flagged(self.min_size_bytes))
+    }
+
+    /// Whether replicated lookups alone exceed `maxBundleSize`.
+    pub fn exceeds_max_bundle_size(&self) -> bool {
+        self.max_bundle_size_mb
+            .is_some_and(|max| self.replicated_lookup_bytes > max * BYTES_PER_MB)
+    }
+}
+
+/// Fetch replication settings and lookups, then audit the bundle contents.
+pub async fn audit_bundle_replication(
+    client: &SplunkClient,
+    request: &BundleAuditRequest,
+) -> Result<BundleAuditReport> {
+    let stanzas = client
+        .list_config_stanzas("distsearch", Some(100), None)
+        .await?;
+    let lookups: Vec<LookupTable> = paginate_all(PaginationOptions::default(), |count, offset| {
+        client.list_lookup_tables(Some(count), Some(offset), None)
+    })
+    .try_collect()
+    .await?;
+
+    Ok(build_report(&stanzas, lookups, request))
+}
+
+/// Audit lookups against the replication rules found in `distsearch` stanzas.
+pub fn build_report(
+    stanzas: &[ConfigStanza],
+    lookups: Vec<LookupTable>,
+    request: &BundleAuditRequest,
+) -> BundleAuditReport {
+    let allow_rules = collect_rules(stanzas, ALLOW_STANZAS);
+    let deny_rules = collect_rules(stanzas, DENY_STANZAS);
+    let max_bundle_size_mb = replication_setting(stanzas, "maxBundleSize");
+    let exclude_replicated_lookup_size_mb =
+        replication_setting(stanzas, "excludeReplicatedLookupSize");
+
+    let total_lookups = lookups.len();
+    let mut replicated_lookup_bytes = 0;
+    let mut audited: Vec<BundleLookup> = Vec::new();
+
+    for lookup in lookups {
+        let path = bundle_path(&lookup);
+        let size_bytes = lookup.size as u64;
+        let (status, rule) = classify(
+            &path,
+            size_bytes,
+            &allow_rules,
+            &deny_rules,
+            exclude_replicated_lookup_size_mb,
+        );
+        if status == ReplicationStatus::Replicated {
+            replicated_lookup_bytes += size_bytes;
+        }
+        if size_bytes < request.min_size_bytes {
+            continue;
+        }
+
+        let mut entry = BundleLookup {
+            name: lookup.name,
+            app: lookup.app,
+            owner: lookup.owner,
+            sharing: lookup.sharing,
+            path,
+            size_bytes,
+            status,
+            rule,
+            suggested_denylist_entry: None,
+        };
+        if entry.is_flagged(request.min_size_bytes) {
+            entry.suggested_denylist_entry = Some(suggest_denylist_entry(&entry));
+        }
+        audited.push(entry);
+    }
+
+    audited.sort_by(|a, b| {
+        b.size_bytes
+            .cmp(&a.size_bytes)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+
+    BundleAuditReport {
+        min_size_bytes: request.min_size_bytes,
+        max_bundle_size_mb,
+        exclude_replicated_lookup_size_mb,
+        allow_rules,
+        deny_rules,
+        total_lookups,
+        replicated_lookup_bytes,
+        lookups: audited,
+    }
+}
+
+fn collect_rules(stanzas: &[ConfigStanza], names: &[&str]) -> Vec<ReplicationRule> {
+    let mut rules: Vec<ReplicationRule> = stanzas
+        .iter()
+        .filter(|stanza| names.contains(&stanza.name.as_str()))
+        .flat_map(|stanza| {
+            stanza.settings.iter().filter_map(|(key, value)| {
+                if key == "disabled" || key.starts_with("eai:") {
+                    return None;
+                }
+                let pattern = value.as_str()?.trim();
+                (!pattern.is_empty()).then(|| ReplicationRule {
+                    stanza: stanza.name.clone(),
+                    name: key.clone(),
+                    pattern: pattern.to_string(),
+                })
+            })
+        })
+        .collect();
+    rules.sort_by(|a, b| (&a.stanza, &a.name).cmp(&(&b.stanza, &b.name)));
+    rules.dedup();
+    rules
+}
+
+fn replication_setting(stanzas: &[ConfigStanza], key: &str) -> Option<u64> {
+    let value = stanzas
+        .iter()
+        .find(|stanza| stanza.name == "replicationSettings")?
+        .settings
+        .get(key)?;
+    match value {
+        serde_json::Value::Number(n) => n.as_u64(),
+        serde_json::Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Path of a lookup inside the bundle, relative to `$SPLUNK_HOME/etc`.
+fn bundle_path(lookup: &LookupTable) -> String {
+    if lookup.sharing == "user" {
+        format!(
+            "users/{}/{}/lookups/{}",
+            lookup.owner, lookup.app, lookup.filename
+        )
+    } else {
+        format!("apps/{}/lookups/{}", lookup.app, lookup.filename)
+    }
+}
+
+fn classify(
+    path: &str,
+    size_bytes: u64,
+    allow_rules: &[ReplicationRule],
+    deny_rules: &[ReplicationRule],
+    exclude_size_mb: Option<u64>,
+) -> (ReplicationStatus, Option<String>) {
+    let label = |rule: &ReplicationRule| Some(format!("{}/{}", rule.stanza, rule.name));
+
+    if let Some(rule) = deny_rules.iter().find(|rule| rule.matches(path)) {
+        return (ReplicationStatus::Denied, label(rule));
+    }
+    if let Some(limit) = exclude_size_mb.filter(|limit| *limit > 0)
+        && size_bytes > limit * BYTES_PER_MB
+    {
+        return (ReplicationStatus::SizeExcluded, None);
+    }
+    if allow_rules.is_empty() {
+        return (ReplicationStatus::Replicated, None);
+    }
+    match allow_rules.iter().find(|rule| rule.matches(path)) {
+        Some(rule) => (ReplicationStatus::Replicated, label(rule)),
+        None => (ReplicationStatus::NotAllowed, None),
+    }
+}
+
+fn suggest_denylist_entry(lookup: &BundleLookup) -> String {
+    let name: String = format!("{}_{}", lookup.app, lookup.name)
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("{} = {}", name, lookup.path.replace('.', "\\."))
+}
+
+/// Compile a Splunk wildcard regex: `...` matches across directories and `*`
+/// within one; everything else keeps its regex meaning. Invalid regexes fall
+/// back to matching the remaining characters literally.
+fn wildcard_regex(pattern: &str) -> Option<Regex> {
+    let translate = |escape_rest: bool| {
+        let mut out = String::from("^");
+        let mut rest = pattern;
+        while !rest.is_empty() {
+            if let Some(tail) = rest.strip_prefix("...") {
+                out.push_str(".*");
+                rest = tail;
+            } else if let Some(tail) = rest.strip_prefix('*') {
+                out.push_str(r"[^/\\]*");
+                rest = tail;
+            } else {
+                let c = rest.chars().next().unwrap_or_default();
+                if escape_rest {
+                    out.push_str(&regex::escape(c.encode_utf8(&mut [0; 4])));
+                } else {
+                    out.push(c);
+                }
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        out.push('$');
+        out
+    };
+
+    Regex::new(&translate(false))
+        .or_else(|_| Regex::new(&translate(true)))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn stanza(name: &str, settings: &[(&str, serde_json::Value)]) -> ConfigStanza {
+        ConfigStanza {
+            name: name.to_string(),
+            config_file: "distsearch".to_string(),
+            settings: settings
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.clone()))
+                .collect::<HashMap<_, _>>(),
+        }
+    }
+
+    fn lookup(name: &str, app: &str, sharing: &str, size_mb: usize) -> LookupTable {
+        LookupTable {
+            name: name.to_string(),
+            filename: name.to_string(),
+            owner: "admin".to_string(),
+            app: app.to_string(),
+            sharing: sharing.to_string(),
+            size: size_mb * 1024 * 1024,
+        }
+    }
+
+    fn stanzas() -> Vec<ConfigStanza> {
+        vec![
+            stanza(
+                "replicationWhitelist",
+                &[
+                    ("allConf", json!("*.conf")),
+                    ("allLookups", json!(r"apps[/\\]*[/\\]lookups[/\\]*")),
+                    ("userLookups", json!("users/.../lookups/*")),
+                    ("eai:acl", json!({"app": "system"})),
+                ],
+            ),
+            stanza(
+                "replicationDenylist",
+                &[("geo", json!("apps/search/lookups/geo_*.csv"))],
+            ),
+            stanza(
+                "replicationSettings",
+                &[
+                    ("maxBundleSize", json!("100")),
+                    ("excludeReplicatedLookupSize", json!("500")),
+                    ("disabled", json!(false)),
+                ],
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_wildcards_follow_splunk_semantics() {
+        let rule = |pattern: &str| ReplicationRule {
+            stanza: "replicationAllowlist".to_string(),
+            name: "test".to_string(),
+            pattern: pattern.to_string(),
+        };
+        assert!(rule("apps/*/lookups/*.csv").matches("apps/search/lookups/big.csv"));
+        assert!(!rule("apps/*/lookups/*.csv").matches("apps/search/nested/lookups/big.csv"));
+        assert!(rule("apps/.../big.csv").matches("apps/search/nested/lookups/big.csv"));
+        assert!(rule(r"apps[/\\]*[/\\]lookups[/\\]*").matches("apps/search/lookups/x.csv"));
+        // Unbalanced brackets are matched literally instead of being dropped.
+        assert!(rule("apps/[broken/*").matches("apps/[broken/x"));
+    }
+
+    #[test]
+    fn test_large_replicated_lookup_is_flagged_with_suggestion() {
+        let report = build_report(
+            &stanzas(),
+            vec![
+                lookup("threat_intel.csv", "ES", "global", 60),
+                lookup("small.csv", "search", "app", 1),
+            ],
+            &BundleAuditRequest::default(),
+        );
+
+        assert_eq!(report.total_lookups, 2);
+        assert_eq!(report.lookups.len(), 1);
+        let flagged: Vec<_> = report.flagged().collect();
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].path, "apps/ES/lookups/threat_intel.csv");
+        assert_eq!(
+            flagged[0].rule.as_deref(),
+            Some("replicationWhitelist/allLookups")
+        );
+        assert_eq!(
+            flagged[0].suggested_denylist_entry.as_deref(),
+            Some(r"ES_threat_intel_csv = apps/ES/lookups/threat_intel\.csv")
+        );
+        assert_eq!(report.replicated_lookup_bytes, 61 * BYTES_PER_MB);
+        assert!(!report.exceeds_max_bundle_size());
+    }
+
+    #[test]
+    fn test_denied_size_excluded_and_user_lookups() {
+        let report = build_report(
+            &stanzas(),
+            vec![
+                lookup("geo_cities.csv", "search", "app", 200),
+                lookup("huge.csv", "search", "app", 600),
+                lookup("mine.csv", "search", "user", 150),
+            ],
+            &BundleAuditRequest::default(),
+        );
+
+        let status = |name: &str| {
+            report
+                .lookups
+                .iter()
+                .find(|l| l.name == name)
+                .map(|l| (l.status, l.rule.clone()))
+                .unwrap()
+        };
+        assert_eq!(
+            status("geo_cities.csv"),
+            (
+                ReplicationStatus::Denied,
+                Some("replicationDenylist/geo".to_string())
+            )
+        );
+        assert_eq!(status("huge.csv").0, ReplicationStatus::SizeExcluded);
+        assert_eq!(status("mine.csv").0, ReplicationStatus::Replicated);
+        assert_eq!(
+            report.lookups[0].name, "huge.csv",
+            "lookups are sorted largest first"
+        );
+        assert!(report.exceeds_max_bundle_size());
+        assert_eq!(report.allow_rules.len(), 3);
+        assert_eq!(report.max_bundle_size_mb, Some(100));
+    }
+
+    #[test]
+    fn test_unmatched_lookup_is_not_allowed_when_allowlist_exists() {
+        let mut stanzas = stanzas();
+        stanzas[0]
+            .settings
+            .retain(|key, _| key != "allLookups" && key != "userLookups");
+        let report = build_report(
+            &stanzas,
+            vec![lookup("big.csv", "search", "app", 20)],
+            &BundleAuditRequest::default(),
+        );
+        assert_eq!(report.lookups[0].status, ReplicationStatus::NotAllowed);
+        assert_eq!(report.flagged().count(), 0);
+    }
+}
@@ -0,0 +1,478 @@
+//! Shared backup of configuration stanzas to `.conf` files, and diffs between backups.
+//!
+//! Purpose:
+//! - Snapshot a server's configuration over REST, where shell access to
+//!   `$SPLUNK_HOME/etc` isn't available, and report what changed between snapshots.
+//!
+//! Responsibilities:
+//! - Fetch every context's copy of every stanza in the requested config files.
+//! - Write each context's stanzas as a `.conf` file laid out like `$SPLUNK_HOME/etc`:
+//!   `system/<file>.conf`, `apps/<app>/<file>.conf`, `users/<user>/<app>/<file>.conf`.
+//! - Read a dump directory back and diff two dumps stanza by stanza.
+//!
+//! Does NOT handle:
+//! - Output formatting of summaries and diffs (frontend concern).
+//! - Telling an app's `default` and `local` directories apart: the REST API
+//!   returns them already merged into one layer per app.
+//!
+//! Invariants:
+//! - Dumps are only written to missing or empty directories, so a dump never
+//!   mixes files from two snapshots.
+//! - Multi-line values are written with trailing-backslash continuations and
+//!   read back unchanged.
+//! - Path segments taken from app and user names never escape the dump directory.
+//! - Changes are sorted by file, stanza, then key.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::SplunkClient;
+use crate::models::{ConfigLayer, ConfigScope};
+use crate::workflows::effective_config::layer_settings;
+use crate::workflows::{CancellationProbe, ensure_not_cancelled};
+
+/// Settings of every stanza in one `.conf` file, keyed by stanza then setting.
+pub type ConfStanzas = BTreeMap<String, BTreeMap<String, String>>;
+
+/// Input for a config dump.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigDumpRequest {
+    /// Config files to dump, without the `.conf` suffix; all files the server
+    /// lists when empty.
+    pub config_files: Vec<String>,
+}
+
+/// Stanzas fetched from the server, keyed by path relative to the dump directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigDump {
+    pub files: BTreeMap<String, ConfStanzas>,
+}
+
+/// Totals for a written dump.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigDumpSummary {
+    pub out_dir: String,
+    pub files: usize,
+    pub stanzas: usize,
+    pub settings: usize,
+}
+
+/// Kind of difference between two dumps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+impl ConfigChangeKind {
+    /// Diff marker: `+` added, `-` removed, `~` changed.
+    pub fn marker(self) -> char {
+        match self {
+            Self::Added => '+',
+            Self::Removed => '-',
+            Self::Changed => '~',
+        }
+    }
+}
+
+/// One difference between two dumps.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigChange {
+    /// Path of the `.conf` file relative to the dump directory.
+    pub file: String,
+    pub stanza: String,
+    /// Setting name; `None` when the whole stanza was added or removed.
+    pub key: Option<String>,
+    pub change: ConfigChangeKind,
+    pub previous: Option<String>,
+    pub current: Option<String>,
+}
+
+/// Fetch the stanzas of every requested config file.
+pub async fn collect_config_dump(
+    client: &SplunkClient,
+    request: &ConfigDumpRequest,
+    cancel: Option<&dyn CancellationProbe>,
+) -> Result<ConfigDump> {
+    let config_files = if request.config_files.is_empty() {
+        client
+            .list_config_files()
+            .await?
+            .into_iter()
+            .map(|file| file.name)
+            .collect()
+    } else {
+        request.config_files.clone()
+    };
+
+    let mut dump = ConfigDump::default();
+    for config_file in &config_files {
+        ensure_not_cancelled(cancel)?;
+        let layers = client
+            .list_config_stanza_layers(config_file, None)
+            .await
+            .with_context(|| format!("Failed to dump {}.conf", config_file))?;
+        dump.add_layers(config_file, layers);
+    }
+    Ok(dump)
+}
+
+impl ConfigDump {
+    /// Add every layer of `config_file` under its context's path.
+    pub fn add_layers(&mut self, config_file: &str, layers: Vec<ConfigLayer>) {
+        for layer in layers {
+            let settings = layer_settings(&layer);
+            self.files
+                .entry(layer_path(&layer, config_file))
+                .or_default()
+                .insert(layer.stanza, settings);
+        }
+    }
+
+    /// Write every file under `out_dir`, which must be missing or empty.
+    pub fn write(&self, out_dir: &Path) -> Result<ConfigDumpSummary> {
+        if out_dir.exists()
+            && std::fs::read_dir(out_dir)
+                .with_context(|| format!("Failed to read {}", out_dir.display()))?
+                .next()
+                .is_some()
+        {
+            bail!(
+                "Output directory '{}' is not empty; dump into a new directory",
+                out_dir.display()
+            );
+        }
+
+        for (path, stanzas) in &self.files {
+            let file_path = out_dir.join(path);
+            if let Some(parent) = file_path.parent() {
+                std::fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create {}", parent.display()))?;
+            }
+            std::fs::write(&file_path, render_conf(stanzas))
+                .with_context(|| format!("Failed to write {}", file_path.display()))?;
+        }
+
+        Ok(ConfigDumpSummary {
+            out_dir: out_dir.display().to_string(),
+            files: self.files.len(),
+            stanzas: self.files.values().map(BTreeMap::len).sum(),
+            settings: self
+                .files
+                .values()
+                .flat_map(BTreeMap::values)
+                .map(BTreeMap::len)
+                .sum(),
+        })
+    }
+
+    /// Read a dump directory written by [`ConfigDump::write`].
+    pub fn read(dir: &Path) -> Result<Self> {
+        if !dir.is_dir() {
+            bail!("Dump directory '{}' does not exist", dir.display());
+        }
+        let mut dump = Self::default();
+        read_dir_into(dir, dir, &mut dump.files)?;
+        Ok(dump)
+    }
+}
+
+fn read_dir_into(root: &Path, dir: &Path, files: &mut BTreeMap<String, ConfStanzas>) -> Result<()> {
+    let entries =
+        std::fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir.display()))?;
+    for entry in entries {
+        let path = entry?.path();
+        if path.is_dir() {
+            read_dir_into(root, &path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "conf") {
+            let text = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let relative = path
+                .strip_prefix(root)
+                .unwrap_or(&path)
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            files.insert(relative, parse_conf(&text));
+        }
+    }
+    Ok(())
+}
+
+/// Path of a layer's `.conf` file relative to the dump directory.
+pub fn layer_path(layer: &ConfigLayer, config_file: &str) -> String {
+    let file = format!("{}.conf", path_segment(config_file));
+    match layer.scope {
+        ConfigScope::System => format!("system/{}", file),
+        ConfigScope::App => format!("apps/{}/{}", path_segment(&layer.app), file),
+        ConfigScope::User => format!(
+            "users/{}/{}/{}",
+            path_segment(&layer.owner),
+            path_segment(&layer.app),
+            file
+        ),
+    }
+}
+
+/// A name usable as a single path segment.
+fn path_segment(name: &str) -> String {
+    let segment: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if segment.is_empty() || segment.chars().all(|c| c == '.') {
+        segment.replace('.', "_") + "_"
+    } else {
+        segment
+    }
+}
+
+/// Render stanzas in `.conf` syntax.
+pub fn render_conf(stanzas: &ConfStanzas) -> String {
+    let mut out = String::new();
+    for (stanza, settings) in stanzas {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        out.push_str(&format!("[{}]\n", stanza));
+        for (key, value) in settings {
+            out.push_str(&format!("{} = {}\n", key, value.replace('\n', "\\\n")));
+        }
+    }
+    out
+}
+
+/// Parse `.conf` text; settings before the first stanza header belong to `default`.
+pub fn parse_conf(text: &str) -> ConfStanzas {
+    let mut stanzas = ConfStanzas::new();
+    let mut current = "default".to_string();
+    let mut lines = text.lines();
+    while let Some(line) = lines.next() {
+        let mut line = line.to_string();
+        while line.ends_with('\\') {
+            line.pop();
+            line.push('\n');
+            match lines.next() {
+                Some(next) => line.push_str(next),
+                None => break,
+            }
+        }
+
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if trimmed.starts_with('[') && trimmed.trim_end().ends_with(']') {
+            let header = trimmed.trim_end();
+            current = header[1..header.len() - 1].to_string();
+            stanzas.entry(current.clone()).or_default();
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            stanzas
+                .entry(current.clone())
+                .or_default()
+                .insert(key.trim().to_string(), value.trim().to_string());
+        }
+    }
+    stanzas
+}
+
+/// Differences from `previous` to `current`.
+pub fn diff_config_dumps(previous: &ConfigDump, current: &ConfigDump) -> Vec<ConfigChange> {
+    let empty = ConfStanzas::new();
+    let files: BTreeSet<&String> = previous.files.keys().chain(current.files.keys()).collect();
+
+    let mut changes = Vec::new();
+    for file in files {
+        let before = previous.files.get(file).unwrap_or(&empty);
+        let after = current.files.get(file).unwrap_or(&empty);
+        let stanzas: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+        for stanza in stanzas {
+            let change = |key: Option<&String>, change, previous, current| ConfigChange {
+                file: file.clone(),
+                stanza: stanza.clone(),
+                key: key.cloned(),
+                change,
+                previous,
+                current,
+            };
+            match (before.get(stanza), after.get(stanza)) {
+                (None, Some(_)) => changes.push(change(None, ConfigChangeKind::Added, None, None)),
+                (Some(_), None) => {
+                    changes.push(change(None, ConfigChangeKind::Removed, None, None))
+                }
+                (Some(old), Some(new)) => {
+                    let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+                    for key in keys {
+                        let (old_value, new_value) = (old.get(key), new.get(key));
+                        let kind = match (old_value, new_value) {
+                            (None, Some(_)) => ConfigChangeKind::Added,
+                            (Some(_), None) => ConfigChangeKind::Removed,
+                            (Some(a), Some(b)) if a != b => ConfigChangeKind::Changed,
+                            _ => continue,
+                        };
+                        changes.push(change(
+                            Some(key),
+                            kind,
+                            old_value.cloned(),
+                            new_value.cloned(),
+                        ));
+                    }
+                }
+                (None, None) => {}
+            }
+        }
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn layer(scope: ConfigScope, app: &str, owner: &str, stanza: &str) -> ConfigLayer {
+        ConfigLayer {
+            stanza: stanza.to_string(),
+            scope,
+            app: app.to_string(),
+            owner: owner.to_string(),
+            settings: [
+                ("TZ".to_string(), json!("UTC")),
+                ("TRUNCATE".to_string(), json!(10000)),
+            ]
+            .into_iter()
+            .collect(),
+        }
+    }
+
+    #[test]
+    fn test_layers_are_laid_out_like_splunk_home_etc() {
+        let mut dump = ConfigDump::default();
+        dump.add_layers(
+            "props",
+            vec![
+                layer(ConfigScope::System, "system", "nobody", "default"),
+                layer(ConfigScope::App, "search", "nobody", "syslog"),
+                layer(ConfigScope::App, "search", "nobody", "access_combined"),
+                layer(ConfigScope::User, "../search", "admin", "syslog"),
+            ],
+        );
+
+        let paths: Vec<&str> = dump.files.keys().map(String::as_str).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "apps/search/props.conf",
+                "system/props.conf",
+                "users/admin/.._search/props.conf",
+            ]
+        );
+        assert_eq!(dump.files["apps/search/props.conf"].len(), 2);
+        assert_eq!(
+            dump.files["system/props.conf"]["default"]["TRUNCATE"],
+            "10000"
+        );
+    }
+
+    #[test]
+    fn test_path_segment_never_escapes() {
+        assert_eq!(path_segment(".."), "___");
+        assert_eq!(path_segment(""), "_");
+        assert_eq!(path_segment("a/b"), "a_b");
+    }
+
+    #[test]
+    fn test_conf_round_trip_with_multiline_values() {
+        let mut stanzas = ConfStanzas::new();
+        stanzas.entry("syslog".to_string()).or_default().insert(
+            "search".to_string(),
+            "index=main\n| stats count".to_string(),
+        );
+        stanzas.entry("empty".to_string()).or_default();
+
+        let text = render_conf(&stanzas);
+        assert_eq!(
+            text,
+            "[empty]\n\n[syslog]\nsearch = index=main\\\n| stats count\n"
+        );
+        assert_eq!(parse_conf(&text), stanzas);
+    }
+
+    #[test]
+    fn test_parse_conf_skips_comments_and_defaults_leading_settings() {
+        let stanzas = parse_conf("# comment\nTZ = UTC\n[syslog]\n  # indented\nA=b = c\n");
+        assert_eq!(stanzas["default"]["TZ"], "UTC");
+        assert_eq!(stanzas["syslog"]["A"], "b = c");
+    }
+
+    #[test]
+    fn test_diff_config_dumps() {
+        let dump = |text: &str| ConfigDump {
+            files: [("apps/search/props.conf".to_string(), parse_conf(text))]
+                .into_iter()
+                .collect(),
+        };
+        let previous = dump("[a]\nX = 1\nY = 2\n[gone]\nZ = 1\n");
+        let current = dump("[a]\nX = 1\nY = 3\nW = 4\n[new]\n");
+
+        let changes = diff_config_dumps(&previous, &current);
+        let summary: Vec<(String, Option<String>, ConfigChangeKind)> = changes
+            .iter()
+            .map(|c| (c.stanza.clone(), c.key.clone(), c.change))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "a".to_string(),
+                    Some("W".to_string()),
+                    ConfigChangeKind::Added
+                ),
+                (
+                    "a".to_string(),
+                    Some("Y".to_string()),
+                    ConfigChangeKind::Changed
+                ),
+                ("gone".to_string(), None, ConfigChangeKind::Removed),
+                ("new".to_string(), None, ConfigChangeKind::Added),
+            ]
+        );
+        assert_eq!(changes[1].previous.as_deref(), Some("2"));
+        assert_eq!(changes[1].current.as_deref(), Some("3"));
+    }
+
+    #[test]
+    fn test_write_refuses_non_empty_directory_and_reads_back() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut dump = ConfigDump::default();
+        dump.add_layers(
+            "props",
+            vec![layer(ConfigScope::App, "search", "nobody", "syslog")],
+        );
+
+        let out = dir.path().join("backup");
+        let summary = dump.write(&out).unwrap();
+        assert_eq!(
+            (summary.files, summary.stanzas, summary.settings),
+            (1, 1, 2)
+        );
+        assert_eq!(ConfigDump::read(&out).unwrap(), dump);
+
+        let err = dump.write(&out).unwrap_err();
+        assert!(err.to_string().contains("not empty"));
+    }
+}
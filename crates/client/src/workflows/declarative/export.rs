@@ -0,0 +1,212 @@
+//! Exporting live server state as a resource spec.
+//!
+//! Responsibilities:
+//! - Convert live indexes, roles, users, macros, and saved searches into spec entries.
+//!
+//! Does NOT handle:
+//! - Writing the spec anywhere (frontend concern).
+//!
+//! Invariants:
+//! - Exported specs round-trip: planning an export against the server it came
+//!   from yields no changes.
+//! - Passwords are never exported, so exported users can only be applied where
+//!   they already exist unless a password is added to the spec.
+//! - Internal indexes (names starting with `_`) are skipped unless requested.
+
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+
+use super::{
+    IndexSpec, LiveState, MacroSpec, ResourceKind, ResourceSpec, ResourceState, RoleSpec,
+    SavedSearchSpec, UserSpec, fetch_live_state,
+};
+use crate::SplunkClient;
+use crate::workflows::CancellationProbe;
+
+/// Which live resources an export includes.
+#[derive(Debug, Clone, Default)]
+pub struct ExportOptions {
+    /// Resource kinds to export.
+    pub kinds: BTreeSet<ResourceKind>,
+    /// Also export internal indexes such as `_internal` and `_audit`.
+    pub include_internal: bool,
+}
+
+/// Fetch the requested resource kinds and export them as a spec.
+pub async fn export_spec(
+    client: &SplunkClient,
+    options: &ExportOptions,
+    cancel: Option<&dyn CancellationProbe>,
+) -> Result<ResourceSpec> {
+    let live = fetch_live_state(client, &options.kinds, cancel).await?;
+    Ok(spec_from_live(&live, options))
+}
+
+/// Export live state as a spec, sorted by name within each section.
+pub fn spec_from_live(live: &LiveState, options: &ExportOptions) -> ResourceSpec {
+    let mut spec = ResourceSpec {
+        indexes: live
+            .indexes
+            .iter()
+            .filter(|index| options.include_internal || !index.name.starts_with('_'))
+            .map(|index| IndexSpec {
+                name: index.name.clone(),
+                state: ResourceState::Present,
+                max_data_size_mb: index.max_total_data_size_mb,
+                // `auto` and other non-numeric values cannot be set through the spec.
+                max_hot_buckets: index
+                    .max_hot_buckets
+                    .as_deref()
+                    .and_then(|value| value.parse().ok()),
+                max_warm_db_count: index.max_warm_db_count,
+                frozen_time_period_in_secs: index.frozen_time_period_in_secs,
+                home_path: index.home_path.clone(),
+                cold_db_path: index.cold_db_path.clone(),
+                thawed_path: index.thawed_path.clone(),
+                cold_to_frozen_dir: index.cold_to_frozen_dir.clone(),
+            })
+            .collect(),
+        roles: live
+            .roles
+            .iter()
+            .map(|role| RoleSpec {
+                name: role.name.clone(),
+                state: ResourceState::Present,
+                capabilities: Some(role.capabilities.clone()),
+                search_indexes: Some(role.search_indexes.clone()),
+                search_filter: role.search_filter.clone(),
+                imported_roles: Some(role.imported_roles.clone()),
+                default_app: role.default_app.clone(),
+            })
+            .collect(),
+        users: live
+            .users
+            .iter()
+            .map(|user| UserSpec {
+                name: user.name.clone(),
+                state: ResourceState::Present,
+                roles: Some(user.roles.clone()),
+                realname: user.realname.clone(),
+                email: user.email.clone(),
+                default_app: user.default_app.clone(),
+                password: None,
+                password_env: None,
+            })
+            .collect(),
+        macros: live
+            .macros
+            .iter()
+            .map(|current| MacroSpec {
+                name: current.name.clone(),
+                state: ResourceState::Present,
+                definition: Some(current.definition.clone()),
+                args: current.args.clone(),
+                description: current.description.clone(),
+                disabled: Some(current.disabled),
+                iseval: Some(current.iseval),
+                validation: current.validation.clone(),
+                errormsg: current.errormsg.clone(),
+            })
+            .collect(),
+        saved_searches: live
+            .saved_searches
+            .iter()
+            .map(|search| SavedSearchSpec {
+                name: search.name.clone(),
+                state: ResourceState::Present,
+                search: Some(search.search.clone()),
+                description: search.description.clone(),
+                disabled: Some(search.disabled),
+            })
+            .collect(),
+    };
+
+    spec.indexes.sort_by(|a, b| a.name.cmp(&b.name));
+    spec.roles.sort_by(|a, b| a.name.cmp(&b.name));
+    spec.users.sort_by(|a, b| a.name.cmp(&b.name));
+    spec.macros.sort_by(|a, b| a.name.cmp(&b.name));
+    spec.saved_searches.sort_by(|a, b| a.name.cmp(&b.name));
+    spec
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workflows::declarative::build_plan;
+    use serde_json::json;
+
+    fn live() -> LiveState {
+        LiveState {
+            indexes: vec![
+                serde_json::from_value(json!({
+                    "name": "web",
+                    "maxTotalDataSizeMB": "1000",
+                    "maxHotBuckets": "auto",
+                    "frozenTimePeriodInSecs": 86400,
+                    "homePath": "$SPLUNK_DB/web/db",
+                    "currentDBSizeMB": 1,
+                    "totalEventCount": 1
+                }))
+                .unwrap(),
+                serde_json::from_value(json!({
+                    "name": "_internal",
+                    "currentDBSizeMB": 1,
+                    "totalEventCount": 1
+                }))
+                .unwrap(),
+            ],
+            roles: vec![
+                serde_json::from_value(json!({
+                    "name": "web_reader",
+                    "searchIndexes": ["web"],
+                    "importedRoles": ["user"]
+                }))
+                .unwrap(),
+            ],
+            macros: vec![
+                serde_json::from_value(json!({
+                    "name": "web_errors",
+                    "definition": "index=web status>=500",
+                    "disabled": true
+                }))
+                .unwrap(),
+            ],
+            ..LiveState::default()
+        }
+    }
+
+    fn options() -> ExportOptions {
+        ExportOptions {
+            kinds: ResourceKind::ALL.into_iter().collect(),
+            include_internal: false,
+        }
+    }
+
+    #[test]
+    fn test_export_round_trips_to_an_empty_plan() {
+        let spec = spec_from_live(&live(), &options());
+        let yaml = serde_yaml::to_string(&spec).unwrap();
+        let parsed = ResourceSpec::from_yaml(&yaml).unwrap();
+
+        assert_eq!(parsed, spec);
+        assert!(build_plan(&parsed, &live()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_export_skips_internal_indexes_and_unsettable_values() {
+        let spec = spec_from_live(&live(), &options());
+        let names: Vec<_> = spec.indexes.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, vec!["web"]);
+        assert_eq!(spec.indexes[0].max_hot_buckets, None);
+
+        let all = spec_from_live(
+            &live(),
+            &ExportOptions {
+                include_internal: true,
+                ..options()
+            },
+        );
+        assert_eq!(all.indexes.len(), 2);
+    }
+}
@@ -0,0 +1,389 @@
+//! Shared declarative resource specs ("GitOps for Splunk").
+//!
+//! Purpose:
+//! - Describe indexes, users, roles, macros, and saved searches as desired state in
+//!   YAML, and compute the changes that bring a live server in line with it.
+//!
+//! Responsibilities:
+//! - Parse and validate resource specs.
+//! - Fetch the live state of the resource kinds a spec mentions.
+//! - Re-export planning (`plan`) and export of live state (`export`).
+//!
+//! Does NOT handle:
+//! - Executing plans (frontends commit [`ApplyPlan::to_transaction`] through
+//!   [`crate::transaction::TransactionManager`], which rolls back on failure).
+//! - Plan presentation (frontend concern).
+//!
+//! Invariants:
+//! - Only fields set in the spec are managed; omitted fields are left as they are.
+//! - Resources are only deleted when the spec marks them `state: absent`.
+
+mod export;
+mod plan;
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use anyhow::{Result, bail};
+use futures::TryStreamExt;
+use serde::{Deserialize, Serialize};
+
+use crate::SplunkClient;
+use crate::models::{Index, Macro, Role, SavedSearch, User};
+use crate::pagination::{PaginationOptions, paginate_all};
+use crate::workflows::{CancellationProbe, ensure_not_cancelled};
+
+pub use export::{ExportOptions, export_spec, spec_from_live};
+pub use plan::{ApplyPlan, FieldChange, PlanAction, PlannedChange, build_plan, plan_apply};
+
+/// Whether a resource should exist.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResourceState {
+    #[default]
+    Present,
+    Absent,
+}
+
+impl ResourceState {
+    fn is_present(&self) -> bool {
+        *self == Self::Present
+    }
+}
+
+/// Desired state of an index.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IndexSpec {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "ResourceState::is_present")]
+    pub state: ResourceState,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_data_size_mb: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_hot_buckets: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_warm_db_count: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frozen_time_period_in_secs: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub home_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cold_db_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thawed_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cold_to_frozen_dir: Option<String>,
+}
+
+/// Desired state of a user.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UserSpec {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "ResourceState::is_present")]
+    pub state: ResourceState,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub realname: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_app: Option<String>,
+    /// Initial password; only used when the user is created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    /// Environment variable holding the initial password.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password_env: Option<String>,
+}
+
+/// Desired state of a role.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoleSpec {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "ResourceState::is_present")]
+    pub state: ResourceState,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search_indexes: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search_filter: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub imported_roles: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_app: Option<String>,
+}
+
+/// Desired state of a search macro.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MacroSpec {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "ResourceState::is_present")]
+    pub state: ResourceState,
+    /// Required unless the macro is absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub definition: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub args: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iseval: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub errormsg: Option<String>,
+}
+
+/// Desired state of a saved search.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SavedSearchSpec {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "ResourceState::is_present")]
+    pub state: ResourceState,
+    /// Required unless the saved search is absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+}
+
+/// A declarative resource spec.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ResourceSpec {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub indexes: Vec<IndexSpec>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<RoleSpec>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub users: Vec<UserSpec>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub macros: Vec<MacroSpec>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub saved_searches: Vec<SavedSearchSpec>,
+}
+
+impl ResourceSpec {
+    /// Parse and validate a YAML spec.
+    pub fn from_yaml(text: &str) -> Result<Self> {
+        let spec: Self = serde_yaml::from_str(text)?;
+        spec.validate()?;
+        Ok(spec)
+    }
+
+    /// Resource kinds with at least one entry.
+    pub fn kinds(&self) -> BTreeSet<ResourceKind> {
+        let listed = [
+            (ResourceKind::Index, self.indexes.is_empty()),
+            (ResourceKind::Role, self.roles.is_empty()),
+            (ResourceKind::User, self.users.is_empty()),
+            (ResourceKind::Macro, self.macros.is_empty()),
+            (ResourceKind::SavedSearch, self.saved_searches.is_empty()),
+        ];
+        listed
+            .into_iter()
+            .filter(|(_, empty)| !empty)
+            .map(|(kind, _)| kind)
+            .collect()
+    }
+
+    /// Reject empty or duplicate names and present macros/saved searches without a body.
+    pub fn validate(&self) -> Result<()> {
+        fn check<'a>(kind: ResourceKind, names: impl Iterator<Item = &'a str>) -> Result<()> {
+            let mut seen = BTreeSet::new();
+            for name in names {
+                if name.trim().is_empty() {
+                    bail!("{} name cannot be empty", kind);
+                }
+                if !seen.insert(name) {
+                    bail!("{} '{}' is listed more than once", kind, name);
+                }
+            }
+            Ok(())
+        }
+
+        check(
+            ResourceKind::Index,
+            self.indexes.iter().map(|s| s.name.as_str()),
+        )?;
+        check(
+            ResourceKind::Role,
+            self.roles.iter().map(|s| s.name.as_str()),
+        )?;
+        check(
+            ResourceKind::User,
+            self.users.iter().map(|s| s.name.as_str()),
+        )?;
+        check(
+            ResourceKind::Macro,
+            self.macros.iter().map(|s| s.name.as_str()),
+        )?;
+        check(
+            ResourceKind::SavedSearch,
+            self.saved_searches.iter().map(|s| s.name.as_str()),
+        )?;
+
+        for spec in &self.macros {
+            if spec.state.is_present() && spec.definition.is_none() {
+                bail!("macro '{}' needs a definition", spec.name);
+            }
+        }
+        for spec in &self.saved_searches {
+            if spec.state.is_present() && spec.search.is_none() {
+                bail!("saved search '{}' needs a search", spec.name);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Kind of managed resource, in dependency order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceKind {
+    Index,
+    Role,
+    User,
+    Macro,
+    SavedSearch,
+}
+
+impl ResourceKind {
+    /// Every kind, in dependency order.
+    pub const ALL: [Self; 5] = [
+        Self::Index,
+        Self::Role,
+        Self::User,
+        Self::Macro,
+        Self::SavedSearch,
+    ];
+
+    /// The spec section listing this kind, e.g. `saved_searches`.
+    pub fn section(self) -> &'static str {
+        match self {
+            Self::Index => "indexes",
+            Self::Role => "roles",
+            Self::User => "users",
+            Self::Macro => "macros",
+            Self::SavedSearch => "saved_searches",
+        }
+    }
+}
+
+impl std::str::FromStr for ResourceKind {
+    type Err = anyhow::Error;
+
+    /// Parse a section name; `saved-searches` is accepted for `saved_searches`.
+    fn from_str(value: &str) -> Result<Self> {
+        let value = value.trim().to_lowercase().replace('-', "_");
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.section() == value)
+            .ok_or_else(|| {
+                let valid: Vec<_> = Self::ALL.iter().map(|kind| kind.section()).collect();
+                anyhow::anyhow!(
+                    "Invalid resource type: {}. Valid types: {}",
+                    value,
+                    valid.join(", ")
+                )
+            })
+    }
+}
+
+impl fmt::Display for ResourceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Index => write!(f, "index"),
+            Self::Role => write!(f, "role"),
+            Self::User => write!(f, "user"),
+            Self::Macro => write!(f, "macro"),
+            Self::SavedSearch => write!(f, "saved search"),
+        }
+    }
+}
+
+/// Live state of the requested resource kinds.
+#[derive(Debug, Clone, Default)]
+pub struct LiveState {
+    pub indexes: Vec<Index>,
+    pub roles: Vec<Role>,
+    pub users: Vec<User>,
+    pub macros: Vec<Macro>,
+    pub saved_searches: Vec<SavedSearch>,
+}
+
+/// Fetch the live state of the given resource kinds.
+pub async fn fetch_live_state(
+    client: &SplunkClient,
+    kinds: &BTreeSet<ResourceKind>,
+    cancel: Option<&dyn CancellationProbe>,
+) -> Result<LiveState> {
+    let mut live = LiveState::default();
+    if kinds.contains(&ResourceKind::Index) {
+        ensure_not_cancelled(cancel)?;
+        live.indexes = paginate_all(PaginationOptions::default(), |count, offset| {
+            client.list_indexes(Some(count), Some(offset))
+        })
+        .try_collect()
+        .await?;
+    }
+    if kinds.contains(&ResourceKind::Role) {
+        ensure_not_cancelled(cancel)?;
+        live.roles = paginate_all(PaginationOptions::default(), |count, offset| {
+            client.list_roles(Some(count), Some(offset))
+        })
+        .try_collect()
+        .await?;
+    }
+    if kinds.contains(&ResourceKind::User) {
+        ensure_not_cancelled(cancel)?;
+        live.users = paginate_all(PaginationOptions::default(), |count, offset| {
+            client.list_users(Some(count), Some(offset))
+        })
+        .try_collect()
+        .await?;
+    }
+    if kinds.contains(&ResourceKind::Macro) {
+        ensure_not_cancelled(cancel)?;
+        live.macros = client.list_macros(None).await?;
+    }
+    if kinds.contains(&ResourceKind::SavedSearch) {
+        ensure_not_cancelled(cancel)?;
+        live.saved_searches = paginate_all(PaginationOptions::default(), |count, offset| {
+            client.list_saved_searches(Some(count), Some(offset), None)
+        })
+        .try_collect()
+        .await?;
+    }
+    Ok(live)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spec_validation() {
+        assert!(ResourceSpec::from_yaml("indexes:\n  - name: a\n    bogus: 1\n").is_err());
+        let duplicate = ResourceSpec::from_yaml("roles:\n  - name: a\n  - name: a\n");
+        assert!(
+            duplicate
+                .unwrap_err()
+                .to_string()
+                .contains("more than once")
+        );
+        let no_body = ResourceSpec::from_yaml("macros:\n  - name: m\n");
+        assert!(no_body.unwrap_err().to_string().contains("definition"));
+    }
+}
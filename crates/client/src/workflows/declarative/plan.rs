@@ -0,0 +1,856 @@
+//! Diffing resource specs against live state into ordered plans.
+//!
+//! Responsibilities:
+//! - Compare each spec entry with its live resource, field by managed field.
+//! - Order changes and attach the transaction operation that performs each one.
+//!
+//! Does NOT handle:
+//! - Fetching live state or parsing specs (see the parent module).
+//!
+//! Invariants:
+//! - List fields (roles, capabilities, indexes) compare as sets.
+//! - User passwords are only used when creating a user, so plans stay idempotent.
+//! - Creates and updates run in dependency order (indexes, roles, users, macros,
+//!   saved searches); deletes run afterwards in reverse order.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+use anyhow::{Result, bail};
+use secrecy::SecretString;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    IndexSpec, LiveState, MacroSpec, ResourceKind, ResourceSpec, ResourceState, RoleSpec,
+    SavedSearchSpec, UserSpec, fetch_live_state,
+};
+use crate::SplunkClient;
+use crate::models::{
+    CreateIndexParams, CreateRoleParams, CreateUserParams, Index, Macro, MacroCreateParams,
+    MacroUpdateParams, ModifyIndexParams, ModifyRoleParams, ModifyUserParams, Role, SavedSearch,
+    SavedSearchCreateParams, SavedSearchUpdateParams, User,
+};
+use crate::transaction::{Transaction, TransactionOperation};
+use crate::workflows::CancellationProbe;
+
+/// What a planned change does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlanAction {
+    Create,
+    Update,
+    Delete,
+}
+
+impl PlanAction {
+    /// Plan marker: `+` create, `~` update, `-` delete.
+    pub fn marker(self) -> char {
+        match self {
+            Self::Create => '+',
+            Self::Update => '~',
+            Self::Delete => '-',
+        }
+    }
+}
+
+/// One managed field that differs from the live value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub current: Option<String>,
+    pub desired: Option<String>,
+}
+
+/// One change in a plan.
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannedChange {
+    pub kind: ResourceKind,
+    pub name: String,
+    pub action: PlanAction,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<FieldChange>,
+    #[serde(skip)]
+    pub operation: TransactionOperation,
+}
+
+/// Ordered changes that bring a server in line with a spec.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ApplyPlan {
+    pub changes: Vec<PlannedChange>,
+}
+
+impl ApplyPlan {
+    /// True when the server already matches the spec.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Number of changes with the given action.
+    pub fn count(&self, action: PlanAction) -> usize {
+        self.changes.iter().filter(|c| c.action == action).count()
+    }
+
+    /// A transaction running the plan's operations in order.
+    pub fn to_transaction(&self) -> Transaction {
+        let mut transaction = Transaction::new();
+        for change in &self.changes {
+            transaction.add_operation(change.operation.clone());
+        }
+        transaction
+    }
+}
+
+/// Fetch the live state and plan the changes for a spec.
+pub async fn plan_apply(
+    client: &SplunkClient,
+    spec: &ResourceSpec,
+    cancel: Option<&dyn CancellationProbe>,
+) -> Result<ApplyPlan> {
+    let live = fetch_live_state(client, &spec.kinds(), cancel).await?;
+    build_plan(spec, &live)
+}
+
+/// Diff a spec against live state.
+pub fn build_plan(spec: &ResourceSpec, live: &LiveState) -> Result<ApplyPlan> {
+    let mut upserts = Vec::new();
+    let mut deletes = Vec::new();
+
+    let indexes: HashMap<&str, &Index> =
+        live.indexes.iter().map(|i| (i.name.as_str(), i)).collect();
+    for spec in &spec.indexes {
+        plan_index(
+            spec,
+            indexes.get(spec.name.as_str()).copied(),
+            &mut upserts,
+            &mut deletes,
+        );
+    }
+    let roles: HashMap<&str, &Role> = live.roles.iter().map(|r| (r.name.as_str(), r)).collect();
+    for spec in &spec.roles {
+        plan_role(
+            spec,
+            roles.get(spec.name.as_str()).copied(),
+            &mut upserts,
+            &mut deletes,
+        );
+    }
+    let users: HashMap<&str, &User> = live.users.iter().map(|u| (u.name.as_str(), u)).collect();
+    for spec in &spec.users {
+        plan_user(
+            spec,
+            users.get(spec.name.as_str()).copied(),
+            &mut upserts,
+            &mut deletes,
+        )?;
+    }
+    let macros: HashMap<&str, &Macro> = live.macros.iter().map(|m| (m.name.as_str(), m)).collect();
+    for spec in &spec.macros {
+        plan_macro(
+            spec,
+            macros.get(spec.name.as_str()).copied(),
+            &mut upserts,
+            &mut deletes,
+        );
+    }
+    let searches: HashMap<&str, &SavedSearch> = live
+        .saved_searches
+        .iter()
+        .map(|s| (s.name.as_str(), s))
+        .collect();
+    for spec in &spec.saved_searches {
+        plan_saved_search(
+            spec,
+            searches.get(spec.name.as_str()).copied(),
+            &mut upserts,
+            &mut deletes,
+        );
+    }
+
+    // Dependents go first when deleting (users before the roles they hold).
+    deletes.reverse();
+    upserts.extend(deletes);
+    Ok(ApplyPlan { changes: upserts })
+}
+
+/// Record `desired` as a change when it differs from `current`.
+fn diff_field<T: PartialEq + fmt::Display>(
+    fields: &mut Vec<FieldChange>,
+    field: &str,
+    current: Option<T>,
+    desired: Option<T>,
+) -> bool {
+    if desired.is_none() || current == desired {
+        return false;
+    }
+    fields.push(FieldChange {
+        field: field.to_string(),
+        current: current.map(|v| v.to_string()),
+        desired: desired.map(|v| v.to_string()),
+    });
+    true
+}
+
+/// Like [`diff_field`], comparing lists as sets.
+fn diff_set(
+    fields: &mut Vec<FieldChange>,
+    field: &str,
+    current: &[String],
+    desired: Option<&Vec<String>>,
+) -> bool {
+    let Some(desired) = desired else {
+        return false;
+    };
+    let current_set: BTreeSet<&String> = current.iter().collect();
+    let desired_set: BTreeSet<&String> = desired.iter().collect();
+    if current_set == desired_set {
+        return false;
+    }
+    let join = |set: BTreeSet<&String>| {
+        set.into_iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    fields.push(FieldChange {
+        field: field.to_string(),
+        current: Some(join(current_set)),
+        desired: Some(join(desired_set)),
+    });
+    true
+}
+
+/// Fields a create sets, for display.
+fn created_fields(pairs: &[(&str, Option<String>)]) -> Vec<FieldChange> {
+    pairs
+        .iter()
+        .filter_map(|(field, value)| {
+            value.as_ref().map(|value| FieldChange {
+                field: field.to_string(),
+                current: None,
+                desired: Some(value.clone()),
+            })
+        })
+        .collect()
+}
+
+fn change(
+    kind: ResourceKind,
+    name: &str,
+    action: PlanAction,
+    fields: Vec<FieldChange>,
+    operation: TransactionOperation,
+) -> PlannedChange {
+    PlannedChange {
+        kind,
+        name: name.to_string(),
+        action,
+        fields,
+        operation,
+    }
+}
+
+fn opt_string<T: ToString>(value: &Option<T>) -> Option<String> {
+    value.as_ref().map(ToString::to_string)
+}
+
+fn opt_list(value: &Option<Vec<String>>) -> Option<String> {
+    value.as_ref().map(|list| list.join(","))
+}
+
+fn plan_index(
+    spec: &IndexSpec,
+    live: Option<&Index>,
+    upserts: &mut Vec<PlannedChange>,
+    deletes: &mut Vec<PlannedChange>,
+) {
+    let kind = ResourceKind::Index;
+    match (spec.state, live) {
+        (ResourceState::Absent, Some(_)) => deletes.push(change(
+            kind,
+            &spec.name,
+            PlanAction::Delete,
+            Vec::new(),
+            TransactionOperation::DeleteIndex(spec.name.clone()),
+        )),
+        (ResourceState::Absent, None) => {}
+        (ResourceState::Present, None) => upserts.push(change(
+            kind,
+            &spec.name,
+            PlanAction::Create,
+            created_fields(&[
+                ("max_data_size_mb", opt_string(&spec.max_data_size_mb)),
+                ("max_hot_buckets", opt_string(&spec.max_hot_buckets)),
+                ("max_warm_db_count", opt_string(&spec.max_warm_db_count)),
+                (
+                    "frozen_time_period_in_secs",
+                    opt_string(&spec.frozen_time_period_in_secs),
+                ),
+                ("home_path", spec.home_path.clone()),
+                ("cold_db_path", spec.cold_db_path.clone()),
+                ("thawed_path", spec.thawed_path.clone()),
+                ("cold_to_frozen_dir", spec.cold_to_frozen_dir.clone()),
+            ]),
+            TransactionOperation::CreateIndex(CreateIndexParams {
+                name: spec.name.clone(),
+                max_data_size_mb: spec.max_data_size_mb,
+                max_hot_buckets: spec.max_hot_buckets,
+                max_warm_db_count: spec.max_warm_db_count,
+                frozen_time_period_in_secs: spec.frozen_time_period_in_secs,
+                home_path: spec.home_path.clone(),
+                cold_db_path: spec.cold_db_path.clone(),
+                thawed_path: spec.thawed_path.clone(),
+                cold_to_frozen_dir: spec.cold_to_frozen_dir.clone(),
+            }),
+        )),
+        (ResourceState::Present, Some(index)) => {
+            let mut fields = Vec::new();
+            let mut params = ModifyIndexParams::default();
+            if diff_field(
+                &mut fields,
+                "max_data_size_mb",
+                index.max_total_data_size_mb,
+                spec.max_data_size_mb,
+            ) {
+                params.max_data_size_mb = spec.max_data_size_mb;
+            }
+            let live_hot_buckets = index
+                .max_hot_buckets
+                .as_deref()
+                .and_then(|value| value.parse::<usize>().ok());
+            if diff_field(
+                &mut fields,
+                "max_hot_buckets",
+                live_hot_buckets,
+                spec.max_hot_buckets,
+            ) {
+                params.max_hot_buckets = spec.max_hot_buckets;
+            }
+            if diff_field(
+                &mut fields,
+                "max_warm_db_count",
+                index.max_warm_db_count,
+                spec.max_warm_db_count,
+            ) {
+                params.max_warm_db_count = spec.max_warm_db_count;
+            }
+            if diff_field(
+                &mut fields,
+                "frozen_time_period_in_secs",
+                index.frozen_time_period_in_secs,
+                spec.frozen_time_period_in_secs,
+            ) {
+                params.frozen_time_period_in_secs = spec.frozen_time_period_in_secs;
+            }
+            if diff_field(
+                &mut fields,
+                "home_path",
+                index.home_path.as_deref(),
+                spec.home_path.as_deref(),
+            ) {
+                params.home_path = spec.home_path.clone();
+            }
+            if diff_field(
+                &mut fields,
+                "cold_db_path",
+                index.cold_db_path.as_deref(),
+                spec.cold_db_path.as_deref(),
+            ) {
+                params.cold_db_path = spec.cold_db_path.clone();
+            }
+            if diff_field(
+                &mut fields,
+                "thawed_path",
+                index.thawed_path.as_deref(),
+                spec.thawed_path.as_deref(),
+            ) {
+                params.thawed_path = spec.thawed_path.clone();
+            }
+            if diff_field(
+                &mut fields,
+                "cold_to_frozen_dir",
+                index.cold_to_frozen_dir.as_deref(),
+                spec.cold_to_frozen_dir.as_deref(),
+            ) {
+                params.cold_to_frozen_dir = spec.cold_to_frozen_dir.clone();
+            }
+            if !fields.is_empty() {
+                upserts.push(change(
+                    kind,
+                    &spec.name,
+                    PlanAction::Update,
+                    fields,
+                    TransactionOperation::ModifyIndex(spec.name.clone(), params),
+                ));
+            }
+        }
+    }
+}
+
+fn plan_role(
+    spec: &RoleSpec,
+    live: Option<&Role>,
+    upserts: &mut Vec<PlannedChange>,
+    deletes: &mut Vec<PlannedChange>,
+) {
+    let kind = ResourceKind::Role;
+    match (spec.state, live) {
+        (ResourceState::Absent, Some(_)) => deletes.push(change(
+            kind,
+            &spec.name,
+            PlanAction::Delete,
+            Vec::new(),
+            TransactionOperation::DeleteRole(spec.name.clone()),
+        )),
+        (ResourceState::Absent, None) => {}
+        (ResourceState::Present, None) => upserts.push(change(
+            kind,
+            &spec.name,
+            PlanAction::Create,
+            created_fields(&[
+                ("capabilities", opt_list(&spec.capabilities)),
+                ("search_indexes", opt_list(&spec.search_indexes)),
+                ("search_filter", spec.search_filter.clone()),
+                ("imported_roles", opt_list(&spec.imported_roles)),
+                ("default_app", spec.default_app.clone()),
+            ]),
+            TransactionOperation::CreateRole(CreateRoleParams {
+                name: spec.name.clone(),
+                capabilities: spec.capabilities.clone().unwrap_or_default(),
+                search_indexes: spec.search_indexes.clone().unwrap_or_default(),
+                search_filter: spec.search_filter.clone(),
+                imported_roles: spec.imported_roles.clone().unwrap_or_default(),
+                default_app: spec.default_app.clone(),
+            }),
+        )),
+        (ResourceState::Present, Some(role)) => {
+            let mut fields = Vec::new();
+            let mut params = ModifyRoleParams::default();
+            if diff_set(
+                &mut fields,
+                "capabilities",
+                &role.capabilities,
+                spec.capabilities.as_ref(),
+            ) {
+                params.capabilities = spec.capabilities.clone();
+            }
+            if diff_set(
+                &mut fields,
+                "search_indexes",
+                &role.search_indexes,
+                spec.search_indexes.as_ref(),
+            ) {
+                params.search_indexes = spec.search_indexes.clone();
+            }
+            if diff_field(
+                &mut fields,
+                "search_filter",
+                role.search_filter.as_deref(),
+                spec.search_filter.as_deref(),
+            ) {
+                params.search_filter = spec.search_filter.clone();
+            }
+            if diff_set(
+                &mut fields,
+                "imported_roles",
+                &role.imported_roles,
+                spec.imported_roles.as_ref(),
+            ) {
+                params.imported_roles = spec.imported_roles.clone();
+            }
+            if diff_field(
+                &mut fields,
+                "default_app",
+                role.default_app.as_deref(),
+                spec.default_app.as_deref(),
+            ) {
+                params.default_app = spec.default_app.clone();
+            }
+            if !fields.is_empty() {
+                upserts.push(change(
+                    kind,
+                    &spec.name,
+                    PlanAction::Update,
+                    fields,
+                    TransactionOperation::ModifyRole(spec.name.clone(), params),
+                ));
+            }
+        }
+    }
+}
+
+/// Initial password of a user spec, from `password` or `password_env`.
+fn initial_password(spec: &UserSpec) -> Result<SecretString> {
+    if let Some(password) = &spec.password {
+        return Ok(SecretString::new(password.clone().into()));
+    }
+    if let Some(var) = &spec.password_env {
+        return match std::env::var(var) {
+            Ok(password) if !password.is_empty() => Ok(SecretString::new(password.into())),
+            _ => bail!(
+                "user '{}' needs a password: environment variable {} is not set",
+                spec.name,
+                var
+            ),
+        };
+    }
+    bail!(
+        "user '{}' does not exist and needs `password` or `password_env` to be created",
+        spec.name
+    )
+}
+
+fn plan_user(
+    spec: &UserSpec,
+    live: Option<&User>,
+    upserts: &mut Vec<PlannedChange>,
+    deletes: &mut Vec<PlannedChange>,
+) -> Result<()> {
+    let kind = ResourceKind::User;
+    match (spec.state, live) {
+        (ResourceState::Absent, Some(_)) => deletes.push(change(
+            kind,
+            &spec.name,
+            PlanAction::Delete,
+            Vec::new(),
+            TransactionOperation::DeleteUser(spec.name.clone()),
+        )),
+        (ResourceState::Absent, None) => {}
+        (ResourceState::Present, None) => upserts.push(change(
+            kind,
+            &spec.name,
+            PlanAction::Create,
+            created_fields(&[
+                ("roles", opt_list(&spec.roles)),
+                ("realname", spec.realname.clone()),
+                ("email", spec.email.clone()),
+                ("default_app", spec.default_app.clone()),
+            ]),
+            TransactionOperation::CreateUser(CreateUserParams {
+                name: spec.name.clone(),
+                password: initial_password(spec)?,
+                roles: spec.roles.clone().unwrap_or_default(),
+                realname: spec.realname.clone(),
+                email: spec.email.clone(),
+                default_app: spec.default_app.clone(),
+            }),
+        )),
+        (ResourceState::Present, Some(user)) => {
+            let mut fields = Vec::new();
+            let mut params = ModifyUserParams::default();
+            if diff_set(&mut fields, "roles", &user.roles, spec.roles.as_ref()) {
+                params.roles = spec.roles.clone();
+            }
+            if diff_field(
+                &mut fields,
+                "realname",
+                user.realname.as_deref(),
+                spec.realname.as_deref(),
+            ) {
+                params.realname = spec.realname.clone();
+            }
+            if diff_field(
+                &mut fields,
+                "email",
+                user.email.as_deref(),
+                spec.email.as_deref(),
+            ) {
+                params.email = spec.email.clone();
+            }
+            if diff_field(
+                &mut fields,
+                "default_app",
+                user.default_app.as_deref(),
+                spec.default_app.as_deref(),
+            ) {
+                params.default_app = spec.default_app.clone();
+            }
+            if !fields.is_empty() {
+                upserts.push(change(
+                    kind,
+                    &spec.name,
+                    PlanAction::Update,
+                    fields,
+                    TransactionOperation::ModifyUser(spec.name.clone(), params),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn plan_macro(
+    spec: &MacroSpec,
+    live: Option<&Macro>,
+    upserts: &mut Vec<PlannedChange>,
+    deletes: &mut Vec<PlannedChange>,
+) {
+    let kind = ResourceKind::Macro;
+    match (spec.state, live) {
+        (ResourceState::Absent, Some(_)) => deletes.push(change(
+            kind,
+            &spec.name,
+            PlanAction::Delete,
+            Vec::new(),
+            TransactionOperation::DeleteMacro(spec.name.clone()),
+        )),
+        (ResourceState::Absent, None) => {}
+        (ResourceState::Present, None) => upserts.push(change(
+            kind,
+            &spec.name,
+            PlanAction::Create,
+            created_fields(&[
+                ("definition", spec.definition.clone()),
+                ("args", spec.args.clone()),
+                ("description", spec.description.clone()),
+                ("disabled", opt_string(&spec.disabled)),
+                ("iseval", opt_string(&spec.iseval)),
+                ("validation", spec.validation.clone()),
+                ("errormsg", spec.errormsg.clone()),
+            ]),
+            TransactionOperation::CreateMacro(MacroCreateParams {
+                name: spec.name.clone(),
+                definition: spec.definition.clone().unwrap_or_default(),
+                args: spec.args.clone(),
+                description: spec.description.clone(),
+                disabled: spec.disabled.unwrap_or(false),
+                iseval: spec.iseval.unwrap_or(false),
+                validation: spec.validation.clone(),
+                errormsg: spec.errormsg.clone(),
+            }),
+        )),
+        (ResourceState::Present, Some(current)) => {
+            let mut fields = Vec::new();
+            let mut params = MacroUpdateParams::default();
+            if diff_field(
+                &mut fields,
+                "definition",
+                Some(current.definition.as_str()),
+                spec.definition.as_deref(),
+            ) {
+                params.definition = spec.definition.clone();
+            }
+            if diff_field(
+                &mut fields,
+                "args",
+                current.args.as_deref(),
+                spec.args.as_deref(),
+            ) {
+                params.args = spec.args.clone();
+            }
+            if diff_field(
+                &mut fields,
+                "description",
+                current.description.as_deref(),
+                spec.description.as_deref(),
+            ) {
+                params.description = spec.description.clone();
+            }
+            if diff_field(
+                &mut fields,
+                "disabled",
+                Some(current.disabled),
+                spec.disabled,
+            ) {
+                params.disabled = spec.disabled;
+            }
+            if diff_field(&mut fields, "iseval", Some(current.iseval), spec.iseval) {
+                params.iseval = spec.iseval;
+            }
+            if diff_field(
+                &mut fields,
+                "validation",
+                current.validation.as_deref(),
+                spec.validation.as_deref(),
+            ) {
+                params.validation = spec.validation.clone();
+            }
+            if diff_field(
+                &mut fields,
+                "errormsg",
+                current.errormsg.as_deref(),
+                spec.errormsg.as_deref(),
+            ) {
+                params.errormsg = spec.errormsg.clone();
+            }
+            if !fields.is_empty() {
+                upserts.push(change(
+                    kind,
+                    &spec.name,
+                    PlanAction::Update,
+                    fields,
+                    TransactionOperation::UpdateMacro(spec.name.clone(), params),
+                ));
+            }
+        }
+    }
+}
+
+fn plan_saved_search(
+    spec: &SavedSearchSpec,
+    live: Option<&SavedSearch>,
+    upserts: &mut Vec<PlannedChange>,
+    deletes: &mut Vec<PlannedChange>,
+) {
+    let kind = ResourceKind::SavedSearch;
+    match (spec.state, live) {
+        (ResourceState::Absent, Some(_)) => deletes.push(change(
+            kind,
+            &spec.name,
+            PlanAction::Delete,
+            Vec::new(),
+            TransactionOperation::DeleteSavedSearch(spec.name.clone()),
+        )),
+        (ResourceState::Absent, None) => {}
+        (ResourceState::Present, None) => upserts.push(change(
+            kind,
+            &spec.name,
+            PlanAction::Create,
+            created_fields(&[
+                ("search", spec.search.clone()),
+                ("description", spec.description.clone()),
+                ("disabled", opt_string(&spec.disabled)),
+            ]),
+            TransactionOperation::CreateSavedSearch(SavedSearchCreateParams {
+                name: spec.name.clone(),
+                search: spec.search.clone().unwrap_or_default(),
+                description: spec.description.clone(),
+                disabled: spec.disabled.unwrap_or(false),
+            }),
+        )),
+        (ResourceState::Present, Some(current)) => {
+            let mut fields = Vec::new();
+            let mut params = SavedSearchUpdateParams::default();
+            if diff_field(
+                &mut fields,
+                "search",
+                Some(current.search.as_str()),
+                spec.search.as_deref(),
+            ) {
+                params.search = spec.search.clone();
+            }
+            if diff_field(
+                &mut fields,
+                "description",
+                current.description.as_deref(),
+                spec.description.as_deref(),
+            ) {
+                params.description = spec.description.clone();
+            }
+            if diff_field(
+                &mut fields,
+                "disabled",
+                Some(current.disabled),
+                spec.disabled,
+            ) {
+                params.disabled = spec.disabled;
+            }
+            if !fields.is_empty() {
+                upserts.push(change(
+                    kind,
+                    &spec.name,
+                    PlanAction::Update,
+                    fields,
+                    TransactionOperation::UpdateSavedSearch(spec.name.clone(), params),
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const SPEC: &str = r#"
+indexes:
+  - name: web
+    max_data_size_mb: 1000
+  - name: old_logs
+    state: absent
+roles:
+  - name: web_reader
+    search_indexes: [web, main]
+users:
+  - name: alice
+    roles: [web_reader]
+    password: changeme
+macros:
+  - name: web_errors
+    definition: index=web status>=500
+"#;
+
+    fn live() -> LiveState {
+        LiveState {
+            indexes: vec![
+                serde_json::from_value(json!({
+                    "name": "web",
+                    "maxTotalDataSizeMB": "500",
+                    "currentDBSizeMB": 0,
+                    "totalEventCount": 0
+                }))
+                .unwrap(),
+                serde_json::from_value(json!({
+                    "name": "old_logs",
+                    "currentDBSizeMB": 0,
+                    "totalEventCount": 0
+                }))
+                .unwrap(),
+            ],
+            roles: vec![
+                serde_json::from_value(json!({
+                    "name": "web_reader",
+                    "searchIndexes": ["main", "web"]
+                }))
+                .unwrap(),
+            ],
+            ..LiveState::default()
+        }
+    }
+
+    #[test]
+    fn test_plan_orders_upserts_by_dependency_and_deletes_last() {
+        let spec = ResourceSpec::from_yaml(SPEC).unwrap();
+        let plan = build_plan(&spec, &live()).unwrap();
+
+        let summary: Vec<_> = plan
+            .changes
+            .iter()
+            .map(|c| (c.kind, c.name.as_str(), c.action))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (ResourceKind::Index, "web", PlanAction::Update),
+                (ResourceKind::User, "alice", PlanAction::Create),
+                (ResourceKind::Macro, "web_errors", PlanAction::Create),
+                (ResourceKind::Index, "old_logs", PlanAction::Delete),
+            ]
+        );
+        assert_eq!(
+            plan.changes[0].fields,
+            vec![FieldChange {
+                field: "max_data_size_mb".to_string(),
+                current: Some("500".to_string()),
+                desired: Some("1000".to_string()),
+            }]
+        );
+        assert_eq!(plan.to_transaction().operations.len(), 4);
+    }
+
+    #[test]
+    fn test_plan_is_empty_when_server_matches() {
+        let spec = ResourceSpec::from_yaml(
+            "roles:\n  - name: web_reader\n    search_indexes: [web, main]\n",
+        )
+        .unwrap();
+        assert!(build_plan(&spec, &live()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_new_user_without_password_is_rejected() {
+        let spec = ResourceSpec::from_yaml("users:\n  - name: bob\n").unwrap();
+        let error = build_plan(&spec, &LiveState::default()).unwrap_err();
+        assert!(error.to_string().contains("password"));
+    }
+}
@@ -6,6 +6,7 @@
 //! Responsibilities:
 //! - Build frontend-neutral diagnostic reports from configuration and client health probes.
 //! - Classify connection diagnostics into reachability/auth/TLS results.
+//! - Flag unsupported Splunk version skew when the target is a cluster manager.
 //! - Produce redacted support-bundle report views.
 //!
 //! Scope:
@@ -25,6 +26,7 @@ use splunk_config::Config;
 use std::path::PathBuf;
 use std::time::Instant;
 
+use crate::models::ClusterVersionReport;
 use crate::models::kvstore::KvStoreMemberStatus;
 use crate::workflows::{CancellationProbe, ensure_not_cancelled};
 use crate::{ClientError, HealthCheckOutput, SplunkClient};
@@ -253,6 +255,25 @@ pub async fn run_doctor_report(
                     });
                 }
 
+                let is_cluster_manager = health.output.server_info.as_ref().is_some_and(|server| {
+                    server
+                        .server_roles
+                        .iter()
+                        .any(|role| role == "cluster_master" || role == "cluster_manager")
+                });
+                if is_cluster_manager {
+                    ensure_not_cancelled(cancel)?;
+                    checks.push(match client.get_cluster_versions().await {
+                        Ok(report) => cluster_version_check(&report),
+                        Err(error) => DiagnosticCheck {
+                            name: "cluster_version_skew".to_string(),
+                            status: CheckStatus::Warning,
+                            message: format!("Could not collect cluster versions: {error}"),
+                            details: None,
+                        },
+                    });
+                }
+
                 let partial_errors = health
                     .partial_errors
                     .into_iter()
@@ -417,6 +438,54 @@ fn cancelled_diagnostics_result(start: Instant) -> ConnectionDiagnosticsResult {
     }
 }
 
+fn cluster_version_check(report: &ClusterVersionReport) -> DiagnosticCheck {
+    let unknown: Vec<&str> = report
+        .unknown_versions()
+        .map(|node| node.name.as_str())
+        .collect();
+
+    let (status, message) = if !report.skew.is_empty() {
+        let nodes = report
+            .skew
+            .iter()
+            .map(|s| format!("{} {} {}: {}", s.role, s.name, s.version, s.reason))
+            .collect::<Vec<_>>()
+            .join("; ");
+        (
+            CheckStatus::Fail,
+            format!(
+                "Unsupported version skew on {} node(s): {nodes}",
+                report.skew.len()
+            ),
+        )
+    } else if !unknown.is_empty() {
+        (
+            CheckStatus::Warning,
+            format!(
+                "Version unknown for {} node(s): {}",
+                unknown.len(),
+                unknown.join(", ")
+            ),
+        )
+    } else {
+        (
+            CheckStatus::Pass,
+            format!(
+                "{} cluster nodes on supported versions (manager {})",
+                report.nodes.len(),
+                report.manager_version().unwrap_or("unknown")
+            ),
+        )
+    };
+
+    DiagnosticCheck {
+        name: "cluster_version_skew".to_string(),
+        status,
+        message,
+        details: serde_json::to_value(report).ok(),
+    }
+}
+
 fn summarize_config(config: &Config) -> ConfigSummary {
     ConfigSummary {
         config_source: "resolved".to_string(),
@@ -560,6 +629,10 @@ mod tests {
                 circuit_failure_window_seconds: 60,
                 circuit_reset_timeout_seconds: 30,
                 circuit_half_open_requests: 1,
+                rate_limit: None,
+                namespace: None,
+                cloud: None,
+                health_thresholds: None,
             },
             auth: AuthConfig {
                 strategy: splunk_config::AuthStrategy::ApiToken {
@@ -683,7 +756,13 @@ mod tests {
                 license_usage: None,
                 kvstore_status: None,
                 log_parsing_health: None,
+                scheduler_health: None,
+                queue_health: None,
+                security_health: None,
                 circuit_breaker_states: None,
+                cluster_peers: None,
+                disk_partition: None,
+                search_load: None,
             }),
             partial_errors: vec![("kvstore_status".to_string(), "boom".to_string())],
         };
@@ -696,6 +775,41 @@ mod tests {
         assert!(serialized.get("partial_errors").is_none());
     }
 
+    #[test]
+    fn cluster_version_check_fails_on_skew_and_warns_on_unknown_versions() {
+        use crate::models::{ClusterNodeRole, ClusterNodeVersion};
+
+        let node = |role, name: &str, version: Option<&str>| ClusterNodeVersion {
+            role,
+            name: name.to_string(),
+            version: version.map(str::to_string),
+        };
+
+        let skewed = cluster_version_check(&ClusterVersionReport::new(vec![
+            node(ClusterNodeRole::Manager, "cm", Some("9.1.3")),
+            node(ClusterNodeRole::Peer, "idx1", Some("9.2.0")),
+        ]));
+        assert_eq!(skewed.status, CheckStatus::Fail);
+        assert!(skewed.message.contains("Peer idx1 9.2.0"));
+
+        let unknown = cluster_version_check(&ClusterVersionReport::new(vec![
+            node(ClusterNodeRole::Manager, "cm", Some("9.1.3")),
+            node(ClusterNodeRole::Peer, "idx1", None),
+        ]));
+        assert_eq!(unknown.status, CheckStatus::Warning);
+        assert!(unknown.message.contains("idx1"));
+
+        let supported = cluster_version_check(&ClusterVersionReport::new(vec![
+            node(ClusterNodeRole::Manager, "cm", Some("9.1.3")),
+            node(ClusterNodeRole::Peer, "idx1", Some("9.1.3")),
+        ]));
+        assert_eq!(supported.status, CheckStatus::Pass);
+        assert_eq!(
+            supported.message,
+            "2 cluster nodes on supported versions (manager 9.1.3)"
+        );
+    }
+
     #[test]
     fn categorize_connection_errors_by_domain() {
         assert_eq!(
@@ -0,0 +1,359 @@
+//! Shared effective-configuration resolution workflow (a REST take on `btool --debug`).
+//!
+//! Purpose:
+//! - Show the effective value of every setting in a conf file or stanza, and which
+//!   context supplied it, on hosts where shell access for `splunk btool` isn't available.
+//!
+//! Responsibilities:
+//! - Fetch every context's copy of the stanza(s) from the configs endpoints.
+//! - Merge the layers in precedence order (system < apps < user), recording the
+//!   winning source of each key and the values it overrode.
+//! - Diff one app's layer of a stanza against the stanza's effective settings.
+//!
+//! Does NOT handle:
+//! - Output formatting (frontend concern).
+//! - Telling an app's `default` and `local` directories apart: the REST API
+//!   returns them already merged into one layer per app.
+//!
+//! Invariants:
+//! - Among app layers, the app named in the request wins; the rest follow
+//!   Splunk's global-context ordering, where apps earlier in ASCII order win.
+//! - User layers are only included when a user is requested, and then only that
+//!   user's layers (restricted to the requested app, if any).
+//! - Settings are returned sorted by stanza, then key.
+//! - Diffs compare whole settings, so a changed value shows as a removed line
+//!   followed by an added line.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::SplunkClient;
+use crate::models::{ConfigLayer, ConfigScope};
+
+/// Input for an effective-configuration lookup.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EffectiveConfigRequest {
+    /// Config file name without the `.conf` suffix (e.g. "props").
+    pub config_file: String,
+    /// Restrict resolution to a single stanza.
+    pub stanza: Option<String>,
+    /// Resolve in this app's context (its layer outranks other apps).
+    pub app: Option<String>,
+    /// Include this user's private layers.
+    pub user: Option<String>,
+}
+
+/// A value shadowed by a higher-precedence layer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OverriddenValue {
+    pub source: String,
+    pub value: String,
+}
+
+/// The effective value of one setting and where it came from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EffectiveSetting {
+    pub stanza: String,
+    pub key: String,
+    pub value: String,
+    pub scope: ConfigScope,
+    /// Winning layer, e.g. `system`, `app:search`, or `user:admin@search`.
+    pub source: String,
+    /// Lower-precedence values, closest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub overridden: Vec<OverriddenValue>,
+}
+
+/// Result of an effective-configuration lookup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EffectiveConfigReport {
+    pub config_file: String,
+    pub app: Option<String>,
+    pub user: Option<String>,
+    pub settings: Vec<EffectiveSetting>,
+}
+
+/// Fetch and merge the layers of a config file (or one of its stanzas).
+pub async fn resolve_effective_config(
+    client: &SplunkClient,
+    request: &EffectiveConfigRequest,
+) -> Result<EffectiveConfigReport> {
+    let layers = client
+        .list_config_stanza_layers(&request.config_file, request.stanza.as_deref())
+        .await?;
+
+    Ok(EffectiveConfigReport {
+        config_file: request.config_file.clone(),
+        app: request.app.clone(),
+        user: request.user.clone(),
+        settings: merge_layers(layers, request.app.as_deref(), request.user.as_deref()),
+    })
+}
+
+/// Merge stanza layers into effective settings with per-key provenance.
+pub fn merge_layers(
+    layers: Vec<ConfigLayer>,
+    app: Option<&str>,
+    user: Option<&str>,
+) -> Vec<EffectiveSetting> {
+    let mut layers: Vec<ConfigLayer> = layers
+        .into_iter()
+        .filter(|layer| match layer.scope {
+            ConfigScope::User => {
+                user == Some(layer.owner.as_str()) && app.is_none_or(|app| app == layer.app)
+            }
+            ConfigScope::System | ConfigScope::App => true,
+        })
+        .collect();
+
+    // Lowest precedence first, so later layers override earlier ones.
+    layers.sort_by(|left, right| {
+        let rank = |layer: &ConfigLayer| {
+            (
+                layer.scope,
+                app == Some(layer.app.as_str()),
+                Reverse(layer.app.clone()),
+            )
+        };
+        rank(left).cmp(&rank(right))
+    });
+
+    let mut merged: BTreeMap<(String, String), EffectiveSetting> = BTreeMap::new();
+    for layer in layers {
+        let source = layer.source();
+        for (key, value) in layer.settings {
+            let value = display_value(&value);
+            match merged.get_mut(&(layer.stanza.clone(), key.clone())) {
+                Some(setting) => {
+                    let previous = OverriddenValue {
+                        source: std::mem::replace(&mut setting.source, source.clone()),
+                        value: std::mem::replace(&mut setting.value, value),
+                    };
+                    setting.overridden.insert(0, previous);
+                    setting.scope = layer.scope;
+                }
+                None => {
+                    merged.insert(
+                        (layer.stanza.clone(), key.clone()),
+                        EffectiveSetting {
+                            stanza: layer.stanza.clone(),
+                            key,
+                            value,
+                            scope: layer.scope,
+                            source: source.clone(),
+                            overridden: Vec::new(),
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    merged.into_values().collect()
+}
+
+/// Settings of a single layer as display strings.
+pub fn layer_settings(layer: &ConfigLayer) -> BTreeMap<String, String> {
+    layer
+        .settings
+        .iter()
+        .map(|(key, value)| (key.clone(), display_value(value)))
+        .collect()
+}
+
+/// Unified diff of `app`'s layer of `stanza` (`---`) against the stanza's
+/// effective settings (`+++`).
+///
+/// An app without a layer for the stanza diffs as empty, so every effective
+/// setting shows as added.
+pub fn app_layer_diff(layers: &[ConfigLayer], stanza: &str, app: &str) -> Vec<String> {
+    let app_settings = layers
+        .iter()
+        .find(|layer| {
+            layer.stanza == stanza && layer.scope != ConfigScope::User && layer.app == app
+        })
+        .map(layer_settings)
+        .unwrap_or_default();
+    let effective: BTreeMap<String, String> = merge_layers(layers.to_vec(), None, None)
+        .into_iter()
+        .filter(|setting| setting.stanza == stanza)
+        .map(|setting| (setting.key, setting.value))
+        .collect();
+
+    settings_diff(
+        stanza,
+        &format!("app:{}", app),
+        &app_settings,
+        "effective",
+        &effective,
+    )
+}
+
+/// Key-level unified diff between two copies of a stanza's settings.
+///
+/// Lines are `key = value` prefixed with ` `, `-`, or `+`, under `---`/`+++`
+/// headers and an `@@ [stanza] @@` hunk header.
+pub fn settings_diff(
+    stanza: &str,
+    from_label: &str,
+    from: &BTreeMap<String, String>,
+    to_label: &str,
+    to: &BTreeMap<String, String>,
+) -> Vec<String> {
+    let mut lines = vec![
+        format!("--- {}", from_label),
+        format!("+++ {}", to_label),
+        format!("@@ [{}] @@", stanza),
+    ];
+    let keys: std::collections::BTreeSet<&String> = from.keys().chain(to.keys()).collect();
+    for key in keys {
+        match (from.get(key), to.get(key)) {
+            (Some(old), Some(new)) if old == new => lines.push(format!(" {} = {}", key, old)),
+            (old, new) => {
+                if let Some(old) = old {
+                    lines.push(format!("-{} = {}", key, old));
+                }
+                if let Some(new) = new {
+                    lines.push(format!("+{} = {}", key, new));
+                }
+            }
+        }
+    }
+    lines
+}
+
+fn display_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn layer(scope: ConfigScope, app: &str, owner: &str, settings: &[(&str, &str)]) -> ConfigLayer {
+        ConfigLayer {
+            stanza: "syslog".to_string(),
+            scope,
+            app: app.to_string(),
+            owner: owner.to_string(),
+            settings: settings
+                .iter()
+                .map(|(k, v)| (k.to_string(), json!(v)))
+                .collect(),
+        }
+    }
+
+    fn layers() -> Vec<ConfigLayer> {
+        vec![
+            layer(ConfigScope::User, "search", "admin", &[("TZ", "UTC")]),
+            layer(
+                ConfigScope::App,
+                "zeta",
+                "nobody",
+                &[("TZ", "EST"), ("A", "z")],
+            ),
+            layer(ConfigScope::App, "alpha", "nobody", &[("A", "a")]),
+            layer(
+                ConfigScope::System,
+                "system",
+                "nobody",
+                &[("TZ", "GMT"), ("SHOULD_LINEMERGE", "false")],
+            ),
+        ]
+    }
+
+    fn find<'a>(settings: &'a [EffectiveSetting], key: &str) -> &'a EffectiveSetting {
+        settings.iter().find(|s| s.key == key).unwrap()
+    }
+
+    #[test]
+    fn test_apps_override_system_and_ascii_order_wins_among_apps() {
+        let settings = merge_layers(layers(), None, None);
+
+        let tz = find(&settings, "TZ");
+        assert_eq!(tz.value, "EST");
+        assert_eq!(tz.source, "app:zeta");
+        assert_eq!(tz.overridden.len(), 1);
+        assert_eq!(tz.overridden[0].source, "system");
+
+        let a = find(&settings, "A");
+        assert_eq!(a.value, "a");
+        assert_eq!(a.source, "app:alpha");
+        assert_eq!(a.overridden[0].value, "z");
+
+        assert_eq!(
+            find(&settings, "SHOULD_LINEMERGE").scope,
+            ConfigScope::System
+        );
+    }
+
+    #[test]
+    fn test_requested_app_outranks_other_apps() {
+        let settings = merge_layers(layers(), Some("zeta"), None);
+        assert_eq!(find(&settings, "A").source, "app:zeta");
+    }
+
+    #[test]
+    fn test_user_layer_only_applies_when_requested() {
+        let settings = merge_layers(layers(), None, Some("admin"));
+        let tz = find(&settings, "TZ");
+        assert_eq!(tz.value, "UTC");
+        assert_eq!(tz.source, "user:admin@search");
+        assert_eq!(
+            tz.overridden
+                .iter()
+                .map(|o| o.source.as_str())
+                .collect::<Vec<_>>(),
+            vec!["app:zeta", "system"]
+        );
+
+        let other_app = merge_layers(layers(), Some("launcher"), Some("admin"));
+        assert_eq!(find(&other_app, "TZ").source, "app:zeta");
+    }
+
+    #[test]
+    fn test_settings_sorted_by_stanza_then_key() {
+        let mut extra = layer(ConfigScope::App, "search", "nobody", &[("B", "1")]);
+        extra.stanza = "access_combined".to_string();
+        let mut input = layers();
+        input.push(extra);
+
+        let keys: Vec<(String, String)> = merge_layers(input, None, None)
+            .into_iter()
+            .map(|s| (s.stanza, s.key))
+            .collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+        assert_eq!(keys[0].0, "access_combined");
+    }
+
+    #[test]
+    fn test_app_layer_diff_against_effective_settings() {
+        let diff = app_layer_diff(&layers(), "syslog", "zeta");
+        assert_eq!(
+            diff,
+            vec![
+                "--- app:zeta",
+                "+++ effective",
+                "@@ [syslog] @@",
+                "-A = z",
+                "+A = a",
+                "+SHOULD_LINEMERGE = false",
+                " TZ = EST",
+            ]
+        );
+
+        let missing = app_layer_diff(&layers(), "syslog", "launcher");
+        assert_eq!(missing[0], "--- app:launcher");
+        assert!(missing[3..].iter().all(|line| line.starts_with('+')));
+    }
+}
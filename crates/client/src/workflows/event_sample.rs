@@ -0,0 +1,128 @@
+//! Shared raw event sampling workflow.
+//!
+//! Purpose:
+//! - Show what an index (optionally one sourcetype in it) actually contains,
+//!   for onboarding checks, without writing a search by hand.
+//!
+//! Responsibilities:
+//! - Build the `search index=<name> [sourcetype=<st>] | head <n>` query.
+//! - Run it and shape each result into a [`SampleEvent`].
+//!
+//! Does NOT handle:
+//! - Time range selection (all time; `head` stops at the newest events).
+//! - Output formatting (frontend concern).
+//!
+//! Invariants:
+//! - Events are returned newest first, as the search returns them.
+//! - `count` is clamped to [`MAX_SAMPLE_COUNT`].
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::SplunkClient;
+use crate::client::search::SearchRequest;
+
+/// Default number of events sampled.
+pub const DEFAULT_SAMPLE_COUNT: usize = 50;
+
+/// Most events a single sample may return.
+pub const MAX_SAMPLE_COUNT: usize = 1000;
+
+/// A raw event with the metadata fields shown next to it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SampleEvent {
+    #[serde(rename = "_time", skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sourcetype: Option<String>,
+    #[serde(rename = "_raw")]
+    pub raw: String,
+}
+
+impl SampleEvent {
+    /// Shape a search result row; missing fields stay `None`.
+    pub fn from_result(result: &serde_json::Value) -> Self {
+        let field = |name: &str| {
+            result
+                .get(name)
+                .and_then(serde_json::Value::as_str)
+                .map(str::to_string)
+        };
+        Self {
+            time: field("_time"),
+            index: field("index"),
+            host: field("host"),
+            source: field("source"),
+            sourcetype: field("sourcetype"),
+            raw: field("_raw").unwrap_or_default(),
+        }
+    }
+}
+
+/// Quote a value as an SPL string literal.
+fn spl_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Build the sampling search for `index`, optionally limited to `sourcetype`.
+pub fn sample_query(index: &str, sourcetype: Option<&str>, count: usize) -> String {
+    let mut query = format!("search index={}", spl_string(index));
+    if let Some(sourcetype) = sourcetype {
+        query.push_str(&format!(" sourcetype={}", spl_string(sourcetype)));
+    }
+    query.push_str(&format!(
+        " | head {} | table _time index host source sourcetype _raw",
+        count.clamp(1, MAX_SAMPLE_COUNT)
+    ));
+    query
+}
+
+/// Fetch the newest `count` raw events of `index` (and `sourcetype`, if given).
+pub async fn sample_index_events(
+    client: &SplunkClient,
+    index: &str,
+    sourcetype: Option<&str>,
+    count: usize,
+) -> Result<Vec<SampleEvent>> {
+    let query = sample_query(index, sourcetype, count);
+    let request = SearchRequest::new(&query, true).max_results(count.clamp(1, MAX_SAMPLE_COUNT));
+    let results = client.search(request).await?;
+    Ok(results.iter().map(SampleEvent::from_result).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_query_quotes_names_and_clamps_count() {
+        assert_eq!(
+            sample_query("main", None, 50),
+            r#"search index="main" | head 50 | table _time index host source sourcetype _raw"#
+        );
+        assert_eq!(
+            sample_query("web", Some(r#"odd"type"#), 0),
+            r#"search index="web" sourcetype="odd\"type" | head 1 | table _time index host source sourcetype _raw"#
+        );
+        assert!(sample_query("main", None, 5000).contains("| head 1000 |"));
+    }
+
+    #[test]
+    fn test_sample_event_from_result() {
+        let event = SampleEvent::from_result(&serde_json::json!({
+            "_time": "2024-01-15T10:30:00.000+00:00",
+            "host": "web01",
+            "_raw": "GET /index.html 200"
+        }));
+        assert_eq!(event.time.as_deref(), Some("2024-01-15T10:30:00.000+00:00"));
+        assert_eq!(event.host.as_deref(), Some("web01"));
+        assert_eq!(event.sourcetype, None);
+        assert_eq!(event.raw, "GET /index.html 200");
+    }
+}
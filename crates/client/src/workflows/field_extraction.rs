@@ -0,0 +1,374 @@
+//! Shared field extraction tester workflow.
+//!
+//! Purpose:
+//! - Try search-time field extractions (`rex` regexes or props.conf `EXTRACT-`
+//!   lines) against pasted sample events before deploying them.
+//!
+//! Responsibilities:
+//! - Parse `EXTRACT-<class> = <regex> [in <field>]` lines from props-style text.
+//! - Find the named capture groups each regex extracts.
+//! - Run the samples through `| makeresults ... | rex` on the server, so Splunk's
+//!   own PCRE engine does the matching, and collect the values per event.
+//!
+//! Does NOT handle:
+//! - `REPORT-` transforms or `FIELDALIAS-`/`EVAL-` settings.
+//! - Output formatting (frontend concern).
+//!
+//! Invariants:
+//! - Samples are sent inline in the search string; nothing is indexed.
+//! - Events keep their sample order, and every named group appears as a field
+//!   even when no event matched it.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::SplunkClient;
+use crate::client::search::SearchRequest;
+
+/// Maximum number of sample events sent in one test search.
+pub const MAX_SAMPLE_EVENTS: usize = 100;
+
+/// Why an extraction test could not be built.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FieldExtractionError {
+    /// No non-blank sample events were given.
+    #[error("no sample events provided")]
+    NoSamples,
+    /// More samples than [`MAX_SAMPLE_EVENTS`].
+    #[error("too many sample events ({0}); the limit is {MAX_SAMPLE_EVENTS}")]
+    TooManySamples(usize),
+    /// No regex was given, directly or through `EXTRACT-` lines.
+    #[error("no extraction regex provided")]
+    NoRegex,
+    /// A regex without any `(?<name>...)` group, which would extract nothing.
+    #[error("regex has no named capture groups: {0}")]
+    NoNamedGroups(String),
+}
+
+/// One search-time extraction: a regex applied to a source field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Extraction {
+    /// Extraction class from `EXTRACT-<class>`, if it came from props.conf.
+    pub class: Option<String>,
+    pub regex: String,
+    /// Field the regex runs against (`_raw` unless `in <field>` was given).
+    pub source_field: String,
+}
+
+impl Extraction {
+    /// An extraction of a bare regex against `_raw`.
+    pub fn regex(regex: impl Into<String>) -> Self {
+        Self {
+            class: None,
+            regex: regex.into(),
+            source_field: "_raw".to_string(),
+        }
+    }
+}
+
+/// Values extracted from one sample event.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtractedEvent {
+    /// The sample event as given.
+    pub raw: String,
+    /// Extracted values by field name; multi-value fields are joined with `, `.
+    pub values: BTreeMap<String, String>,
+}
+
+impl ExtractedEvent {
+    /// Whether any extraction matched this event.
+    pub fn matched(&self) -> bool {
+        !self.values.is_empty()
+    }
+}
+
+/// Result of testing extractions against sample events.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldExtractionPreview {
+    /// Named groups across all extractions, in the order they first appear.
+    pub fields: Vec<String>,
+    pub events: Vec<ExtractedEvent>,
+    /// The search that ran, for reuse as an ad-hoc `rex` query.
+    pub search: String,
+}
+
+impl FieldExtractionPreview {
+    /// Number of events at least one extraction matched.
+    pub fn matched_count(&self) -> usize {
+        self.events.iter().filter(|e| e.matched()).count()
+    }
+}
+
+/// Parse `EXTRACT-<class> = <regex> [in <field>]` lines from props.conf text.
+///
+/// Stanza headers, comments, and other settings are ignored.
+pub fn parse_props_extractions(props: &str) -> Vec<Extraction> {
+    props
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.trim().split_once('=')?;
+            let class = key.trim().strip_prefix("EXTRACT-")?.trim();
+            let value = value.trim();
+            // `in <field>` is only a source field when it follows the regex.
+            let (regex, source_field) = match value.rsplit_once(" in ") {
+                Some((regex, field))
+                    if !field.trim().is_empty()
+                        && field
+                            .trim()
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '_') =>
+                {
+                    (regex.trim_end(), field.trim())
+                }
+                _ => (value, "_raw"),
+            };
+            (!regex.is_empty()).then(|| Extraction {
+                class: Some(class.to_string()),
+                regex: regex.to_string(),
+                source_field: source_field.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Names of the `(?<name>...)`, `(?P<name>...)`, and `(?'name'...)` groups in a regex.
+pub fn named_groups(regex: &str) -> Vec<String> {
+    let bytes = regex.as_bytes();
+    let mut names = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => {
+                i += 2;
+                continue;
+            }
+            b'(' if regex[i..].starts_with("(?") => {
+                let rest = &regex[i + 2..];
+                let (open, close) = if rest.starts_with("P<") {
+                    (2, '>')
+                } else if rest.starts_with('<')
+                    && !rest.starts_with("<=")
+                    && !rest.starts_with("<!")
+                {
+                    (1, '>')
+                } else if rest.starts_with('\'') {
+                    (1, '\'')
+                } else {
+                    i += 1;
+                    continue;
+                };
+                if let Some(end) = rest[open..].find(close) {
+                    let name = &rest[open..open + end];
+                    if !name.is_empty() && !names.iter().any(|n| n == name) {
+                        names.push(name.to_string());
+                    }
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    names
+}
+
+/// Quote a value as an SPL string literal.
+fn spl_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Split pasted text into sample events, one per non-blank line.
+pub fn sample_events(text: &str) -> Vec<String> {
+    text.lines()
+        .map(str::trim_end)
+        .filter(|line| !line.trim().is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Build the search that runs `extractions` over `samples`, returning it with the
+/// extracted field names.
+pub fn build_extraction_search(
+    samples: &[String],
+    extractions: &[Extraction],
+) -> std::result::Result<(String, Vec<String>), FieldExtractionError> {
+    if samples.is_empty() {
+        return Err(FieldExtractionError::NoSamples);
+    }
+    if samples.len() > MAX_SAMPLE_EVENTS {
+        return Err(FieldExtractionError::TooManySamples(samples.len()));
+    }
+    if extractions.is_empty() {
+        return Err(FieldExtractionError::NoRegex);
+    }
+
+    let mut fields: Vec<String> = Vec::new();
+    for extraction in extractions {
+        let groups = named_groups(&extraction.regex);
+        if groups.is_empty() {
+            return Err(FieldExtractionError::NoNamedGroups(
+                extraction.regex.clone(),
+            ));
+        }
+        for group in groups {
+            if !fields.contains(&group) {
+                fields.push(group);
+            }
+        }
+    }
+
+    let raws = samples
+        .iter()
+        .map(|s| spl_string(s))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut search = format!(
+        "| makeresults | eval _raw=mvappend({raws}) | mvexpand _raw | streamstats count as sample_line"
+    );
+    for extraction in extractions {
+        search.push_str(&format!(
+            " | rex field={} {}",
+            extraction.source_field,
+            spl_string(&extraction.regex)
+        ));
+    }
+    search.push_str(" | table sample_line");
+    for field in &fields {
+        search.push(' ');
+        search.push_str(&spl_string(field));
+    }
+
+    Ok((search, fields))
+}
+
+/// Render a result value, joining multi-value fields.
+fn value_text(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::Null => None,
+        serde_json::Value::String(s) if s.is_empty() => None,
+        serde_json::Value::String(s) => Some(s.clone()),
+        serde_json::Value::Array(values) => {
+            let parts: Vec<String> = values.iter().filter_map(value_text).collect();
+            (!parts.is_empty()).then(|| parts.join(", "))
+        }
+        other => Some(other.to_string()),
+    }
+}
+
+/// Pair search results with their samples by `sample_line`.
+pub fn collect_extracted_events(
+    samples: &[String],
+    fields: &[String],
+    results: &[serde_json::Value],
+) -> Vec<ExtractedEvent> {
+    let mut events: Vec<ExtractedEvent> = samples
+        .iter()
+        .map(|raw| ExtractedEvent {
+            raw: raw.clone(),
+            values: BTreeMap::new(),
+        })
+        .collect();
+
+    for row in results {
+        let Some(line) = row
+            .get("sample_line")
+            .and_then(value_text)
+            .and_then(|line| line.parse::<usize>().ok())
+        else {
+            continue;
+        };
+        let Some(event) = line.checked_sub(1).and_then(|i| events.get_mut(i)) else {
+            continue;
+        };
+        for field in fields {
+            if let Some(value) = row.get(field).and_then(value_text) {
+                event.values.insert(field.clone(), value);
+            }
+        }
+    }
+
+    events
+}
+
+/// Run `extractions` over `samples` on the server and collect the extracted values.
+pub async fn test_field_extraction(
+    client: &SplunkClient,
+    samples: &[String],
+    extractions: &[Extraction],
+) -> Result<FieldExtractionPreview> {
+    let (search, fields) = build_extraction_search(samples, extractions)?;
+
+    let request = SearchRequest::new(&search, true).max_results(samples.len());
+    let results = client.search(request).await?;
+
+    Ok(FieldExtractionPreview {
+        events: collect_extracted_events(samples, &fields, &results),
+        fields,
+        search,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named_groups_skips_lookbehind_and_escaped_parens() {
+        let regex =
+            r"\(?<notgroup>x\) (?<user>\w+) (?P<ip>[\d.]+) (?<=a)(?<!b)(?'port'\d+) (?<user>x)";
+        assert_eq!(named_groups(regex), vec!["user", "ip", "port"]);
+    }
+
+    #[test]
+    fn test_parse_props_extractions_reads_class_and_source_field() {
+        let props = "[app:log]\n# comment\nEXTRACT-user = user=(?<user>\\w+)\nEXTRACT-status = (?<status>\\d{3}) in message\nTRANSFORMS-x = y\n";
+        let extractions = parse_props_extractions(props);
+        assert_eq!(extractions.len(), 2);
+        assert_eq!(extractions[0].class.as_deref(), Some("user"));
+        assert_eq!(extractions[0].regex, r"user=(?<user>\w+)");
+        assert_eq!(extractions[0].source_field, "_raw");
+        assert_eq!(extractions[1].regex, r"(?<status>\d{3})");
+        assert_eq!(extractions[1].source_field, "message");
+    }
+
+    #[test]
+    fn test_build_extraction_search_quotes_samples_and_regex() {
+        let samples = vec![r#"user="bob" id=1"#.to_string(), r"path=C:\tmp".to_string()];
+        let (search, fields) =
+            build_extraction_search(&samples, &[Extraction::regex(r#"user="(?<user>\w+)""#)])
+                .unwrap();
+        assert_eq!(fields, vec!["user"]);
+        assert_eq!(
+            search,
+            r#"| makeresults | eval _raw=mvappend("user=\"bob\" id=1", "path=C:\\tmp") | mvexpand _raw | streamstats count as sample_line | rex field=_raw "user=\"(?<user>\\w+)\"" | table sample_line "user""#
+        );
+    }
+
+    #[test]
+    fn test_build_extraction_search_rejects_regex_without_groups() {
+        let err =
+            build_extraction_search(&["a".to_string()], &[Extraction::regex(r"\d+")]).unwrap_err();
+        assert_eq!(err, FieldExtractionError::NoNamedGroups(r"\d+".to_string()));
+        assert_eq!(
+            build_extraction_search(&[], &[Extraction::regex("(?<a>.)")]).unwrap_err(),
+            FieldExtractionError::NoSamples
+        );
+    }
+
+    #[test]
+    fn test_collect_extracted_events_pairs_rows_by_line() {
+        let samples = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let fields = vec!["user".to_string(), "tag".to_string()];
+        let results = vec![
+            serde_json::json!({"sample_line": "1", "user": "alice", "tag": ["x", "y"]}),
+            serde_json::json!({"sample_line": "3", "user": ""}),
+            serde_json::json!({"sample_line": "2"}),
+        ];
+        let events = collect_extracted_events(&samples, &fields, &results);
+        assert_eq!(events[0].values["user"], "alice");
+        assert_eq!(events[0].values["tag"], "x, y");
+        assert!(!events[1].matched());
+        assert!(!events[2].matched());
+    }
+}
@@ -0,0 +1,207 @@
+//! Shared workflow for pruning old search jobs.
+//!
+//! Purpose:
+//! - Find and delete search jobs older than a cutoff, e.g. to free dispatch directory
+//!   space or stay under a role's disk quota.
+//!
+//! Responsibilities:
+//! - Resolve the age (`2d`, `-12h@h`, ...), owner (`me`), and status policy into a
+//!   server-side job filter plus a creation-time cutoff.
+//! - Select jobs created before the cutoff, oldest first.
+//! - Delete selected jobs in fixed-size batches with a pause between batches, so a large
+//!   prune does not flood the search head.
+//!
+//! Does NOT handle:
+//! - Confirmation prompts, progress display, or output formatting (frontend concerns).
+//!
+//! Invariants:
+//! - Jobs without a parseable creation time are never selected.
+//! - A failed delete is recorded and does not abort the remaining jobs.
+//! - Listing never issues write requests; only `delete_in_batches` deletes.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use serde::{Deserialize, Serialize};
+
+use crate::SplunkClient;
+use crate::models::{JobAge, JobFilter, JobStatusFilter};
+use crate::relative_time::parse_time_modifier;
+use crate::workflows::{CancellationProbe, ensure_not_cancelled};
+
+/// Owner alias for the authenticated user.
+pub const CURRENT_USER_ALIAS: &str = "me";
+
+/// Which jobs to prune.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobPrunePolicy {
+    /// Minimum job age: a bare duration such as `2d` or a relative time such as `-12h@h`.
+    pub older_than: String,
+    /// Only jobs owned by this user (`me` for the authenticated user).
+    pub owner: Option<String>,
+    /// Only jobs in this dispatch state.
+    pub status: Option<JobStatusFilter>,
+}
+
+impl JobPrunePolicy {
+    /// Creation-time cutoff relative to `now`; jobs created before it are prunable.
+    pub fn cutoff(&self, now: &DateTime<Utc>) -> Result<DateTime<Utc>> {
+        let older_than = self.older_than.trim();
+        if older_than.is_empty() {
+            bail!("--older-than must not be empty");
+        }
+        let relative = if older_than.starts_with(|c: char| c.is_ascii_digit()) {
+            format!("-{older_than}")
+        } else {
+            older_than.to_string()
+        };
+        let modifier = parse_time_modifier(&relative)
+            .with_context(|| format!("Invalid --older-than '{older_than}'"))?;
+        match modifier.resolve(now) {
+            Some(cutoff) if cutoff <= *now => Ok(cutoff),
+            Some(_) => bail!("--older-than '{older_than}' resolves to a time in the future"),
+            None => bail!("--older-than '{older_than}' does not resolve to a point in time"),
+        }
+    }
+
+    /// Validate the policy without contacting Splunk.
+    pub fn validate(&self) -> Result<()> {
+        self.cutoff(&Utc::now()).map(|_| ())
+    }
+}
+
+/// Jobs from `jobs` created before `cutoff`, oldest first.
+pub fn select_prunable(jobs: Vec<JobAge>, cutoff: &DateTime<Utc>) -> Vec<JobAge> {
+    let mut prunable: Vec<(DateTime<Utc>, JobAge)> = jobs
+        .into_iter()
+        .filter_map(|job| {
+            let published = DateTime::parse_from_rfc3339(job.published.as_deref()?).ok()?;
+            let published = published.with_timezone(&Utc);
+            (published < *cutoff).then_some((published, job))
+        })
+        .collect();
+    prunable.sort_by(|(left, _), (right, _)| left.cmp(right));
+    prunable.into_iter().map(|(_, job)| job).collect()
+}
+
+/// List the jobs matching `policy` that are old enough to prune, oldest first.
+pub async fn find_prunable_jobs(
+    client: &SplunkClient,
+    policy: &JobPrunePolicy,
+    cancel: Option<&dyn CancellationProbe>,
+) -> Result<Vec<JobAge>> {
+    let cutoff = policy.cutoff(&Utc::now())?;
+    ensure_not_cancelled(cancel)?;
+
+    let owner = match policy.owner.as_deref() {
+        Some(CURRENT_USER_ALIAS) => Some(client.get_current_username().await?),
+        other => other.map(str::to_string),
+    };
+    let filter = JobFilter {
+        owner,
+        app: None,
+        status: policy.status,
+    };
+    let jobs = client.list_job_ages(&filter).await?;
+    Ok(select_prunable(jobs, &cutoff))
+}
+
+/// Outcome of a batched delete.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JobPruneReport {
+    pub deleted: Vec<String>,
+    /// `(sid, error)` for every job that could not be deleted.
+    pub failed: Vec<(String, String)>,
+}
+
+/// Delete `sids` in batches of `batch_size`, pausing `pause` between batches.
+///
+/// Deletes within a batch run concurrently. `on_batch` is called after each batch with
+/// the number of jobs processed so far.
+pub async fn delete_in_batches(
+    client: &SplunkClient,
+    sids: &[String],
+    batch_size: usize,
+    pause: Duration,
+    cancel: Option<&dyn CancellationProbe>,
+    mut on_batch: impl FnMut(usize),
+) -> Result<JobPruneReport> {
+    let mut report = JobPruneReport::default();
+
+    for (index, batch) in sids.chunks(batch_size.max(1)).enumerate() {
+        ensure_not_cancelled(cancel)?;
+        if index > 0 && !pause.is_zero() {
+            tokio::time::sleep(pause).await;
+        }
+
+        let results = join_all(batch.iter().map(|sid| client.delete_job(sid))).await;
+        for (sid, result) in batch.iter().zip(results) {
+            match result {
+                Ok(()) => report.deleted.push(sid.clone()),
+                Err(error) => report.failed.push((sid.clone(), error.to_string())),
+            }
+        }
+        on_batch(report.deleted.len() + report.failed.len());
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(older_than: &str) -> JobPrunePolicy {
+        JobPrunePolicy {
+            older_than: older_than.to_string(),
+            owner: None,
+            status: None,
+        }
+    }
+
+    fn job(sid: &str, published: Option<&str>) -> JobAge {
+        JobAge {
+            sid: sid.to_string(),
+            published: published.map(str::to_string),
+            ..JobAge::default()
+        }
+    }
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-01-15T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn cutoff_accepts_bare_and_relative_durations() {
+        let expected = DateTime::parse_from_rfc3339("2024-01-13T12:00:00Z").unwrap();
+        assert_eq!(policy("2d").cutoff(&now()).unwrap(), expected);
+        assert_eq!(policy("-2d").cutoff(&now()).unwrap(), expected);
+    }
+
+    #[test]
+    fn cutoff_rejects_invalid_and_future_times() {
+        assert!(policy("").cutoff(&now()).is_err());
+        assert!(policy("2fortnights").cutoff(&now()).is_err());
+        assert!(policy("+1h").cutoff(&now()).is_err());
+    }
+
+    #[test]
+    fn select_prunable_keeps_old_jobs_oldest_first() {
+        let jobs = vec![
+            job("recent", Some("2024-01-15T11:00:00Z")),
+            job("old", Some("2024-01-12T08:00:00.000-05:00")),
+            job("unknown", None),
+            job("older", Some("2024-01-01T00:00:00Z")),
+        ];
+        let cutoff = policy("2d").cutoff(&now()).unwrap();
+        let sids: Vec<_> = select_prunable(jobs, &cutoff)
+            .into_iter()
+            .map(|job| job.sid)
+            .collect();
+        assert_eq!(sids, vec!["older", "old"]);
+    }
+}
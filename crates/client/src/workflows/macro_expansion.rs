@@ -0,0 +1,406 @@
+//! Shared SPL macro expansion preview workflow.
+//!
+//! Purpose:
+//! - Show the SPL a query actually runs after Splunk expands its backtick macros,
+//!   to debug macro-heavy searches before dispatching them.
+//!
+//! Responsibilities:
+//! - Find `` `name` `` and `` `name(arg, ...)` `` invocations in a query.
+//! - Fetch each referenced macro (and the macros those reference) via the macros endpoints.
+//! - Substitute `$arg$` placeholders and expand nested macros recursively.
+//!
+//! Does NOT handle:
+//! - Evaluating `iseval` macros: their eval expression is inserted as-is and flagged.
+//! - Output formatting (frontend concern).
+//!
+//! Invariants:
+//! - A macro invoked with N arguments resolves to the stanza `name(N)`; without
+//!   arguments (or with `()`) to `name`, as in Splunk.
+//! - Unknown and disabled macros are left unexpanded and reported, not treated as errors.
+//! - A macro that (directly or indirectly) invokes itself is an error, reported with
+//!   the invocation chain.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::SplunkClient;
+use crate::error::ClientError;
+use crate::models::Macro;
+
+/// Maximum nesting depth, guarding against runaway expansion even without a cycle.
+pub const MAX_EXPANSION_DEPTH: usize = 32;
+
+/// Why a query could not be expanded.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MacroExpansionError {
+    /// A backtick with no closing backtick.
+    #[error("unterminated macro invocation starting at character {0}")]
+    Unterminated(usize),
+    /// An invocation with unbalanced parentheses or quotes in its arguments.
+    #[error("malformed macro invocation `{0}`")]
+    Malformed(String),
+    /// A macro that expands to itself, with the chain of stanzas that led back to it.
+    #[error("macro cycle detected: {}", .0.join(" -> "))]
+    Cycle(Vec<String>),
+    /// Nesting deeper than [`MAX_EXPANSION_DEPTH`].
+    #[error("macro nesting exceeds {MAX_EXPANSION_DEPTH} levels: {}", .0.join(" -> "))]
+    TooDeep(Vec<String>),
+}
+
+/// One macro expanded while producing the preview.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExpandedMacro {
+    /// Stanza name, e.g. `my_macro(2)`.
+    pub name: String,
+    /// Definition as stored in Splunk, before argument substitution.
+    pub definition: String,
+    /// Nesting level (1 for macros invoked directly by the query).
+    pub depth: usize,
+    /// Whether the definition is an eval expression Splunk evaluates at search time.
+    pub iseval: bool,
+}
+
+/// Result of expanding every macro in a query.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MacroExpansion {
+    /// The query as entered.
+    pub original: String,
+    /// The query with every known macro expanded.
+    pub expanded: String,
+    /// Macros expanded, in the order they were encountered.
+    pub macros: Vec<ExpandedMacro>,
+    /// Stanzas that were invoked but do not exist or are disabled (left unexpanded).
+    pub unknown: Vec<String>,
+}
+
+impl MacroExpansion {
+    /// Whether the query invoked any macro at all.
+    pub fn has_macros(&self) -> bool {
+        !self.macros.is_empty() || !self.unknown.is_empty()
+    }
+}
+
+/// A parsed backtick invocation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Invocation {
+    /// Byte range of the invocation, including both backticks.
+    start: usize,
+    end: usize,
+    /// Stanza name the invocation resolves to.
+    stanza: String,
+    args: Vec<String>,
+}
+
+/// Fetch the macros a query references (recursively) and expand them.
+pub async fn preview_macro_expansion(client: &SplunkClient, query: &str) -> Result<MacroExpansion> {
+    let mut macros: BTreeMap<String, Macro> = BTreeMap::new();
+    let mut missing: BTreeSet<String> = BTreeSet::new();
+    let mut pending: BTreeSet<String> = referenced_stanzas(query)?;
+
+    while !pending.is_empty() {
+        let fetched = futures::future::join_all(
+            pending
+                .iter()
+                .map(|stanza| async move { (stanza.clone(), client.get_macro(stanza).await) }),
+        )
+        .await;
+
+        let mut next = BTreeSet::new();
+        for (stanza, result) in fetched {
+            match result {
+                Ok(definition) => {
+                    // Malformed nested invocations surface when the definition is expanded
+                    next.extend(referenced_stanzas(&definition.definition).unwrap_or_default());
+                    macros.insert(stanza, definition);
+                }
+                Err(ClientError::NotFound(_)) => {
+                    missing.insert(stanza);
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+        next.retain(|stanza| !macros.contains_key(stanza) && !missing.contains(stanza));
+        pending = next;
+    }
+
+    Ok(expand_macros(query, &macros)?)
+}
+
+/// Expand every macro in `query` using already-fetched definitions keyed by stanza name.
+pub fn expand_macros(
+    query: &str,
+    macros: &BTreeMap<String, Macro>,
+) -> std::result::Result<MacroExpansion, MacroExpansionError> {
+    let mut expansion = MacroExpansion {
+        original: query.to_string(),
+        ..Default::default()
+    };
+    let mut stack = Vec::new();
+    expansion.expanded = expand_text(query, macros, &mut stack, &mut expansion)?;
+    Ok(expansion)
+}
+
+fn expand_text(
+    text: &str,
+    macros: &BTreeMap<String, Macro>,
+    stack: &mut Vec<String>,
+    expansion: &mut MacroExpansion,
+) -> std::result::Result<String, MacroExpansionError> {
+    let invocations = parse_invocations(text)?;
+    let mut out = String::with_capacity(text.len());
+    let mut cursor = 0;
+
+    for invocation in invocations {
+        out.push_str(&text[cursor..invocation.start]);
+        cursor = invocation.end;
+
+        let Some(definition) = macros.get(&invocation.stanza).filter(|m| !m.disabled) else {
+            if !expansion.unknown.contains(&invocation.stanza) {
+                expansion.unknown.push(invocation.stanza.clone());
+            }
+            out.push_str(&text[invocation.start..invocation.end]);
+            continue;
+        };
+
+        if stack.contains(&invocation.stanza) {
+            let mut chain = stack.clone();
+            chain.push(invocation.stanza);
+            return Err(MacroExpansionError::Cycle(chain));
+        }
+        if stack.len() >= MAX_EXPANSION_DEPTH {
+            let mut chain = stack.clone();
+            chain.push(invocation.stanza);
+            return Err(MacroExpansionError::TooDeep(chain));
+        }
+
+        expansion.macros.push(ExpandedMacro {
+            name: invocation.stanza.clone(),
+            definition: definition.definition.clone(),
+            depth: stack.len() + 1,
+            iseval: definition.iseval,
+        });
+
+        let body = substitute_args(definition, &invocation.args);
+        stack.push(invocation.stanza);
+        out.push_str(&expand_text(&body, macros, stack, expansion)?);
+        stack.pop();
+    }
+
+    out.push_str(&text[cursor..]);
+    Ok(out)
+}
+
+/// Replace `$name$` placeholders with the invocation's arguments, by position.
+fn substitute_args(definition: &Macro, args: &[String]) -> String {
+    let names = definition
+        .args
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty());
+
+    names
+        .zip(args)
+        .fold(definition.definition.clone(), |body, (name, value)| {
+            body.replace(&format!("${}$", name), value)
+        })
+}
+
+/// Distinct stanza names invoked directly by `text`.
+fn referenced_stanzas(text: &str) -> std::result::Result<BTreeSet<String>, MacroExpansionError> {
+    Ok(parse_invocations(text)?
+        .into_iter()
+        .map(|invocation| invocation.stanza)
+        .collect())
+}
+
+fn parse_invocations(text: &str) -> std::result::Result<Vec<Invocation>, MacroExpansionError> {
+    let mut invocations = Vec::new();
+    let mut search_from = 0;
+
+    while let Some(offset) = text[search_from..].find('`') {
+        let start = search_from + offset;
+        let close = text[start + 1..]
+            .find('`')
+            .ok_or_else(|| MacroExpansionError::Unterminated(text[..start].chars().count()))?;
+        let end = start + 1 + close + 1;
+        let (stanza, args) = parse_call(&text[start + 1..end - 1])?;
+        invocations.push(Invocation {
+            start,
+            end,
+            stanza,
+            args,
+        });
+        search_from = end;
+    }
+
+    Ok(invocations)
+}
+
+/// Split `name(arg, ...)` into its stanza name and arguments.
+fn parse_call(inner: &str) -> std::result::Result<(String, Vec<String>), MacroExpansionError> {
+    let inner = inner.trim();
+    let malformed = || MacroExpansionError::Malformed(inner.to_string());
+
+    let Some(open) = inner.find('(') else {
+        if inner.is_empty() || inner.contains(')') {
+            return Err(malformed());
+        }
+        return Ok((inner.to_string(), Vec::new()));
+    };
+    let name = inner[..open].trim();
+    let arg_text = inner[open + 1..].strip_suffix(')').ok_or_else(malformed)?;
+    if name.is_empty() {
+        return Err(malformed());
+    }
+
+    let args = split_args(arg_text).ok_or_else(malformed)?;
+    if args.is_empty() {
+        return Ok((name.to_string(), args));
+    }
+    Ok((format!("{}({})", name, args.len()), args))
+}
+
+/// Split arguments on top-level commas, respecting double quotes and nested parentheses.
+fn split_args(text: &str) -> Option<Vec<String>> {
+    if text.trim().is_empty() {
+        return Some(Vec::new());
+    }
+
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+    let mut in_quotes = false;
+    let mut escaped = false;
+
+    for c in text.chars() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' && in_quotes {
+            escaped = true;
+        } else if c == '"' {
+            in_quotes = !in_quotes;
+        } else if !in_quotes && c == '(' {
+            depth += 1;
+        } else if !in_quotes && c == ')' {
+            depth = depth.checked_sub(1)?;
+        } else if !in_quotes && depth == 0 && c == ',' {
+            args.push(current.trim().to_string());
+            current.clear();
+            continue;
+        }
+        current.push(c);
+    }
+
+    if in_quotes || depth != 0 {
+        return None;
+    }
+    args.push(current.trim().to_string());
+    Some(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn def(name: &str, definition: &str, args: Option<&str>) -> (String, Macro) {
+        (
+            name.to_string(),
+            Macro {
+                name: name.to_string(),
+                definition: definition.to_string(),
+                args: args.map(str::to_string),
+                description: None,
+                disabled: false,
+                iseval: false,
+                validation: None,
+                errormsg: None,
+            },
+        )
+    }
+
+    #[test]
+    fn test_expands_nested_macros_with_arguments() {
+        let macros = BTreeMap::from([
+            def("web", "index=web `errors(500)`", None),
+            def("errors(1)", "status>=$code$ `noise`", Some("code")),
+            def("noise", "NOT bot=true", None),
+        ]);
+
+        let expansion = expand_macros("`web` | stats count by host", &macros).unwrap();
+
+        assert_eq!(
+            expansion.expanded,
+            "index=web status>=500 NOT bot=true | stats count by host"
+        );
+        let names: Vec<_> = expansion
+            .macros
+            .iter()
+            .map(|m| (m.name.as_str(), m.depth))
+            .collect();
+        assert_eq!(names, vec![("web", 1), ("errors(1)", 2), ("noise", 3)]);
+        assert!(expansion.unknown.is_empty());
+    }
+
+    #[test]
+    fn test_argument_splitting_respects_quotes_and_parens() {
+        let macros = BTreeMap::from([def("pair(2)", "a=$a$ b=$b$", Some("a, b"))]);
+
+        let expansion = expand_macros(r#"`pair("x,y", max(1,2))`"#, &macros).unwrap();
+
+        assert_eq!(expansion.expanded, r#"a="x,y" b=max(1,2)"#);
+    }
+
+    #[test]
+    fn test_unknown_and_disabled_macros_are_left_in_place() {
+        let (name, mut disabled) = def("off", "index=off", None);
+        disabled.disabled = true;
+        let macros = BTreeMap::from([(name, disabled)]);
+
+        let expansion = expand_macros("`missing(1)` `off` `off`", &macros).unwrap();
+
+        assert_eq!(expansion.expanded, "`missing(1)` `off` `off`");
+        assert_eq!(expansion.unknown, vec!["missing(1)", "off"]);
+        assert!(expansion.has_macros());
+    }
+
+    #[test]
+    fn test_cycle_is_reported_with_chain() {
+        let macros = BTreeMap::from([def("a", "`b`", None), def("b", "x `a`", None)]);
+
+        let err = expand_macros("search `a`", &macros).unwrap_err();
+
+        assert_eq!(
+            err,
+            MacroExpansionError::Cycle(vec!["a".into(), "b".into(), "a".into()])
+        );
+        assert_eq!(err.to_string(), "macro cycle detected: a -> b -> a");
+    }
+
+    #[test]
+    fn test_malformed_invocations_are_errors() {
+        let macros = BTreeMap::new();
+        assert_eq!(
+            expand_macros("index=main `oops", &macros).unwrap_err(),
+            MacroExpansionError::Unterminated(11)
+        );
+        assert!(matches!(
+            expand_macros("`f(\"a)`", &macros).unwrap_err(),
+            MacroExpansionError::Malformed(_)
+        ));
+    }
+
+    #[test]
+    fn test_query_without_macros_is_unchanged() {
+        let expansion = expand_macros("index=main | head 5", &BTreeMap::new()).unwrap();
+        assert_eq!(expansion.expanded, "index=main | head 5");
+        assert!(!expansion.has_macros());
+        assert_eq!(
+            parse_call("f()").unwrap(),
+            ("f".to_string(), Vec::<String>::new())
+        );
+    }
+}
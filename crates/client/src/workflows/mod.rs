@@ -11,9 +11,27 @@
 //! Invariants:
 //! - Workflow modules are frontend-neutral.
 
+pub mod alert_action_test;
+pub mod app_contents;
+pub mod bundle_audit;
+pub mod config_dump;
+pub mod declarative;
 pub mod diagnostics;
+pub mod effective_config;
+pub mod event_sample;
 pub mod export;
+pub mod field_extraction;
+pub mod job_prune;
+pub mod macro_expansion;
+pub mod monitor;
 pub mod multi_profile;
+pub mod orphaned_objects;
+pub mod result_diff;
+pub mod result_merge;
+pub mod saved_search_bulk;
+pub mod saved_search_ownership;
+pub mod splunkbase;
+pub mod user_activity;
 
 /// Cancellation probe used by shared workflows without depending on frontend crates.
 pub trait CancellationProbe: Send + Sync {
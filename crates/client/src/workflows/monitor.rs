@@ -0,0 +1,379 @@
+//! Shared workflow for watchdog-style monitoring checks.
+//!
+//! Purpose:
+//! - Reduce health, license, and cluster state to one level per check (`ok`,
+//!   `warning`, `critical`, or `unknown`) so a long-running monitor can report
+//!   only transitions.
+//!
+//! Responsibilities:
+//! - Fetch and evaluate each check against its thresholds.
+//! - Track the last level of every check and produce transitions when it changes.
+//!
+//! Does NOT handle:
+//! - Scheduling, persisting state to disk, output, or notifications (frontend concerns).
+//!
+//! Invariants:
+//! - A check that cannot be fetched is `unknown`, never an error; one failing
+//!   endpoint does not stop the other checks.
+//! - Only a level change is a transition; a changed message at the same level is not.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use splunk_config::{HealthThresholds, ThresholdLevel};
+
+use crate::SplunkClient;
+use crate::models::{ClusterPeer, HealthStatus, LicenseUsage, PeerState, PeerStatus, SplunkHealth};
+
+/// A check the monitor can run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MonitorCheck {
+    /// splunkd's overall health (`server/health/splunkd`).
+    Health,
+    /// License usage against quota, per stack.
+    License,
+    /// Indexer cluster peers (on a cluster manager).
+    Cluster,
+}
+
+impl MonitorCheck {
+    pub const ALL: [MonitorCheck; 3] = [Self::Health, Self::License, Self::Cluster];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Health => "health",
+            Self::License => "license",
+            Self::Cluster => "cluster",
+        }
+    }
+}
+
+impl fmt::Display for MonitorCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for MonitorCheck {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|check| check.as_str().eq_ignore_ascii_case(value.trim()))
+            .ok_or_else(|| {
+                format!(
+                    "unknown check '{}' (expected health, license, or cluster)",
+                    value.trim()
+                )
+            })
+    }
+}
+
+/// How a check evaluated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckLevel {
+    Ok,
+    Warning,
+    Critical,
+    /// The check could not be fetched or evaluated.
+    Unknown,
+}
+
+impl CheckLevel {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Warning => "warning",
+            Self::Critical => "critical",
+            Self::Unknown => "unknown",
+        }
+    }
+
+    pub fn is_ok(self) -> bool {
+        self == Self::Ok
+    }
+}
+
+impl fmt::Display for CheckLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<ThresholdLevel> for CheckLevel {
+    fn from(level: ThresholdLevel) -> Self {
+        match level {
+            ThresholdLevel::Ok => Self::Ok,
+            ThresholdLevel::Warning => Self::Warning,
+            ThresholdLevel::Critical => Self::Critical,
+        }
+    }
+}
+
+/// The result of one check.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckOutcome {
+    pub check: MonitorCheck,
+    pub level: CheckLevel,
+    pub message: String,
+}
+
+/// A change in a check's level.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Transition {
+    pub check: MonitorCheck,
+    /// Level before the change; `None` the first time a check is seen.
+    pub from: Option<CheckLevel>,
+    pub to: CheckLevel,
+    pub message: String,
+    /// RFC 3339 time of the evaluation that saw the change.
+    pub at: String,
+}
+
+/// Last known level of a check.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckRecord {
+    pub level: CheckLevel,
+    pub message: String,
+    /// RFC 3339 time the check entered this level.
+    pub since: String,
+}
+
+/// Last known level of every check, for one server.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MonitorState {
+    /// Server the levels were recorded for.
+    #[serde(default)]
+    pub server: Option<String>,
+    #[serde(default)]
+    pub checks: BTreeMap<MonitorCheck, CheckRecord>,
+}
+
+impl MonitorState {
+    /// Record `outcomes` seen at `at` and return the checks whose level changed.
+    ///
+    /// State recorded for a different server is discarded first.
+    pub fn apply(&mut self, server: &str, outcomes: &[CheckOutcome], at: &str) -> Vec<Transition> {
+        if self.server.as_deref() != Some(server) {
+            self.server = Some(server.to_string());
+            self.checks.clear();
+        }
+
+        let mut transitions = Vec::new();
+        for outcome in outcomes {
+            let previous = self.checks.get(&outcome.check);
+            if previous.is_some_and(|record| record.level == outcome.level) {
+                continue;
+            }
+            transitions.push(Transition {
+                check: outcome.check,
+                from: previous.map(|record| record.level),
+                to: outcome.level,
+                message: outcome.message.clone(),
+                at: at.to_string(),
+            });
+            self.checks.insert(
+                outcome.check,
+                CheckRecord {
+                    level: outcome.level,
+                    message: outcome.message.clone(),
+                    since: at.to_string(),
+                },
+            );
+        }
+        transitions
+    }
+}
+
+/// Fetch and evaluate `check`. Fetch errors yield an `unknown` outcome.
+pub async fn run_check(
+    client: &SplunkClient,
+    check: MonitorCheck,
+    thresholds: &HealthThresholds,
+) -> CheckOutcome {
+    let result = match check {
+        MonitorCheck::Health => client.get_health().await.map(|h| evaluate_health(&h)),
+        MonitorCheck::License => client
+            .get_license_usage()
+            .await
+            .map(|usage| evaluate_license(&usage, thresholds)),
+        MonitorCheck::Cluster => client
+            .get_cluster_peers()
+            .await
+            .map(|peers| evaluate_cluster(&peers)),
+    };
+    let (level, message) = result.unwrap_or_else(|e| {
+        (
+            CheckLevel::Unknown,
+            format!("Failed to check {}: {}", check, e),
+        )
+    });
+    CheckOutcome {
+        check,
+        level,
+        message,
+    }
+}
+
+/// Green is ok, yellow a warning, red critical; features that are not green are named.
+pub fn evaluate_health(health: &SplunkHealth) -> (CheckLevel, String) {
+    let level = match health.health {
+        HealthStatus::Green => CheckLevel::Ok,
+        HealthStatus::Yellow => CheckLevel::Warning,
+        HealthStatus::Red => CheckLevel::Critical,
+        HealthStatus::Unknown => CheckLevel::Unknown,
+    };
+    let mut degraded: Vec<&str> = health
+        .features
+        .iter()
+        .filter(|(_, feature)| feature.health != HealthStatus::Green)
+        .map(|(name, _)| name.as_str())
+        .collect();
+    degraded.sort_unstable();
+
+    let mut message = format!("splunkd health is {}", health.health);
+    if !degraded.is_empty() {
+        message.push_str(&format!(" ({})", degraded.join(", ")));
+    }
+    (level, message)
+}
+
+/// Level of the stack with the highest share of its quota used.
+pub fn evaluate_license(
+    usage: &[LicenseUsage],
+    thresholds: &HealthThresholds,
+) -> (CheckLevel, String) {
+    let worst = usage
+        .iter()
+        .filter_map(|stack| {
+            let pct = stack.usage_percent()?;
+            let name = stack.stack_id.as_deref().unwrap_or(&stack.name);
+            Some((name, pct))
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1));
+
+    let Some((name, pct)) = worst else {
+        return (
+            CheckLevel::Unknown,
+            "No license stack reports a quota".to_string(),
+        );
+    };
+    let level = thresholds.license_level(pct).into();
+    (level, format!("{} at {:.1}% of daily quota", name, pct))
+}
+
+/// Critical when a peer is down, a warning when an up peer is not searchable.
+pub fn evaluate_cluster(peers: &[ClusterPeer]) -> (CheckLevel, String) {
+    let label = |peer: &ClusterPeer| peer.label.clone().unwrap_or_else(|| peer.host.clone());
+    let down: Vec<String> = peers
+        .iter()
+        .filter(|peer| peer.status == PeerStatus::Down)
+        .map(label)
+        .collect();
+    let unsearchable: Vec<String> = peers
+        .iter()
+        .filter(|peer| peer.status != PeerStatus::Down && peer.peer_state != PeerState::Searchable)
+        .map(label)
+        .collect();
+
+    let mut message = format!("{} peer(s)", peers.len());
+    if !down.is_empty() {
+        message.push_str(&format!(", down: {}", down.join(", ")));
+    }
+    if !unsearchable.is_empty() {
+        message.push_str(&format!(", not searchable: {}", unsearchable.join(", ")));
+    }
+    let level = if peers.is_empty() {
+        CheckLevel::Unknown
+    } else if !down.is_empty() {
+        CheckLevel::Critical
+    } else if !unsearchable.is_empty() {
+        CheckLevel::Warning
+    } else {
+        CheckLevel::Ok
+    };
+    (level, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(check: MonitorCheck, level: CheckLevel) -> CheckOutcome {
+        CheckOutcome {
+            check,
+            level,
+            message: format!("{} is {}", check, level),
+        }
+    }
+
+    #[test]
+    fn test_apply_reports_level_changes_only() {
+        let mut state = MonitorState::default();
+        let server = "https://splunk:8089";
+
+        let first = state.apply(
+            server,
+            &[
+                outcome(MonitorCheck::Health, CheckLevel::Ok),
+                outcome(MonitorCheck::License, CheckLevel::Warning),
+            ],
+            "t1",
+        );
+        assert_eq!(first.len(), 2);
+        assert_eq!(first[0].from, None);
+
+        let second = state.apply(
+            server,
+            &[
+                outcome(MonitorCheck::Health, CheckLevel::Critical),
+                outcome(MonitorCheck::License, CheckLevel::Warning),
+            ],
+            "t2",
+        );
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].check, MonitorCheck::Health);
+        assert_eq!(second[0].from, Some(CheckLevel::Ok));
+        assert_eq!(second[0].to, CheckLevel::Critical);
+        assert_eq!(state.checks[&MonitorCheck::License].since, "t1");
+
+        // Another server starts from scratch.
+        let other = state.apply(
+            "https://other:8089",
+            &[outcome(MonitorCheck::Health, CheckLevel::Critical)],
+            "t3",
+        );
+        assert_eq!(other[0].from, None);
+        assert_eq!(state.checks.len(), 1);
+    }
+
+    #[test]
+    fn test_evaluate_license_uses_worst_stack() {
+        let usage: Vec<LicenseUsage> = serde_json::from_value(serde_json::json!([
+            {"name": "a", "stack_id": "enterprise", "quota": 1000, "used_bytes": 500},
+            {"name": "b", "stack_id": "forwarder", "quota": 1000, "used_bytes": 950},
+            {"name": "c", "quota": 0, "used_bytes": 10}
+        ]))
+        .unwrap();
+        let thresholds = HealthThresholds::default();
+
+        let (level, message) = evaluate_license(&usage, &thresholds);
+        assert_eq!(level, CheckLevel::Critical);
+        assert_eq!(message, "forwarder at 95.0% of daily quota");
+
+        let (level, _) = evaluate_license(&usage[..1], &thresholds);
+        assert_eq!(level, CheckLevel::Ok);
+        assert_eq!(evaluate_license(&[], &thresholds).0, CheckLevel::Unknown);
+    }
+
+    #[test]
+    fn test_parse_check_names() {
+        assert_eq!("License".parse::<MonitorCheck>(), Ok(MonitorCheck::License));
+        assert!("disk".parse::<MonitorCheck>().is_err());
+    }
+}
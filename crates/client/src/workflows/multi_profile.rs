@@ -247,20 +247,33 @@ pub async fn fetch_multi_profile_overview(
 pub async fn fetch_multi_instance_overview(
     profiles: Vec<(String, ProfileConfig)>,
     cancel: Option<&dyn CancellationProbe>,
+) -> Result<MultiInstanceOverviewData> {
+    fetch_multi_instance_overview_with_progress(profiles, cancel, &mut |_| {}).await
+}
+
+/// Fetch shared dashboard overview for every configured profile, invoking
+/// `on_instance` as each profile completes (in completion order).
+pub async fn fetch_multi_instance_overview_with_progress(
+    profiles: Vec<(String, ProfileConfig)>,
+    cancel: Option<&dyn CancellationProbe>,
+    on_instance: &mut (dyn FnMut(&InstanceOverview) + Send),
 ) -> Result<MultiInstanceOverviewData> {
     ensure_not_cancelled(cancel)?;
 
-    let results = stream::iter(profiles.into_iter().map(
+    let mut pending = stream::iter(profiles.into_iter().map(
         |(profile_name, profile_config)| async move {
             ensure_not_cancelled(cancel)?;
             fetch_instance_overview(profile_name, profile_config, cancel).await
         },
     ))
-    .buffer_unordered(MAX_CONCURRENT_PROFILE_FETCHES)
-    .collect::<Vec<_>>()
-    .await;
+    .buffer_unordered(MAX_CONCURRENT_PROFILE_FETCHES);
 
-    let mut instances: Vec<InstanceOverview> = results.into_iter().collect::<Result<Vec<_>>>()?;
+    let mut instances = Vec::new();
+    while let Some(result) = pending.next().await {
+        let instance = result?;
+        on_instance(&instance);
+        instances.push(instance);
+    }
     instances.sort_by(|left, right| left.profile_name.cmp(&right.profile_name));
 
     Ok(MultiInstanceOverviewData {
@@ -459,6 +472,10 @@ fn config_from_profile(profile_config: &ProfileConfig) -> std::result::Result<Co
             circuit_failure_window_seconds: default_circuit_failure_window(),
             circuit_reset_timeout_seconds: default_circuit_reset_timeout(),
             circuit_half_open_requests: default_circuit_half_open_requests(),
+            rate_limit: splunk_config::rate_limit_from_env().map_err(|e| e.to_string())?,
+            namespace: None,
+            cloud: None,
+            health_thresholds: profile_config.health_thresholds.clone(),
         },
         auth: ConfigAuthConfig {
             strategy: auth_strategy,
@@ -644,7 +661,7 @@ async fn fetch_saved_searches(client: &SplunkClient) -> ResourceSummary {
     fetch_with_timeout(
         "saved-searches",
         client_timeout(client),
-        || client.list_saved_searches(Some(LIST_LIMIT_1000), None),
+        || client.list_saved_searches(Some(LIST_LIMIT_1000), None, None),
         |searches| searches.len(),
         |_| "ok".to_string(),
     )
@@ -733,6 +750,9 @@ mod tests {
             session_expiry_buffer_seconds: Some(90),
             session_ttl_seconds: Some(7200),
             health_check_interval_seconds: Some(30),
+            cloud: None,
+            acs_stack: None,
+            health_thresholds: None,
         }
     }
 
@@ -832,6 +852,25 @@ mod tests {
         assert!(error.to_string().contains("workflow cancelled"));
     }
 
+    #[tokio::test]
+    async fn multi_instance_overview_reports_each_profile() {
+        let mut seen = Vec::new();
+        let data = fetch_multi_instance_overview_with_progress(
+            vec![
+                ("b".to_string(), ProfileConfig::default()),
+                ("a".to_string(), ProfileConfig::default()),
+            ],
+            None,
+            &mut |instance| seen.push(instance.profile_name.clone()),
+        )
+        .await
+        .unwrap();
+
+        seen.sort();
+        assert_eq!(seen, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(data.instances[0].profile_name, "a");
+    }
+
     #[tokio::test]
     async fn fetch_instance_overview_stops_when_cancelled() {
         let cancel = TestCancel(true);
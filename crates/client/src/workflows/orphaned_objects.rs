@@ -0,0 +1,273 @@
+//! Shared orphaned knowledge object detection workflow.
+//!
+//! Purpose:
+//! - Find saved searches, dashboards, macros, and lookups whose owner no longer
+//!   exists, typically left behind when an employee's account is removed.
+//!
+//! Responsibilities:
+//! - List every object of the requested kinds across owners (namespace-wide).
+//! - Cross-reference object owners with the current user list.
+//! - Suggest a new owner for each orphan: an explicit fallback owner, otherwise
+//!   the existing user who owns the most objects in the same app.
+//!
+//! Does NOT handle:
+//! - Reassigning objects (see `saved_search_bulk` and `SplunkClient::set_object_acl`).
+//! - Output formatting (frontend concern).
+//!
+//! Invariants:
+//! - Built-in owners (`nobody`, `splunk-system-user`) are never orphaned.
+//! - Owner names are compared case-insensitively, as Splunk does for usernames.
+//! - Orphans are sorted by owner, kind, app, and name.
+
+use std::collections::{BTreeMap, HashSet};
+
+use anyhow::{Result, bail};
+use futures::future::try_join_all;
+use serde::{Deserialize, Serialize};
+
+use crate::SplunkClient;
+use crate::models::{KnowledgeObjectKind, ObjectAcl};
+use crate::workflows::{CancellationProbe, ensure_not_cancelled};
+
+/// Object kinds scanned when none are requested.
+pub const DEFAULT_KINDS: [KnowledgeObjectKind; 4] = [
+    KnowledgeObjectKind::SavedSearch,
+    KnowledgeObjectKind::Dashboard,
+    KnowledgeObjectKind::Macro,
+    KnowledgeObjectKind::Lookup,
+];
+
+/// Owners that exist without a user account (app-level and system objects).
+pub const BUILTIN_OWNERS: [&str; 2] = ["nobody", "splunk-system-user"];
+
+/// Input for an orphaned object scan.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OrphanScanRequest {
+    /// Object kinds to scan (`DEFAULT_KINDS` when empty).
+    pub kinds: Vec<KnowledgeObjectKind>,
+    /// Only objects in this app.
+    pub app: Option<String>,
+    /// Suggest this user for every orphan instead of deriving a suggestion.
+    pub fallback_owner: Option<String>,
+}
+
+/// A knowledge object owned by a user that no longer exists.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrphanedObject {
+    pub kind: KnowledgeObjectKind,
+    pub name: String,
+    pub app: String,
+    /// The missing owner.
+    pub owner: String,
+    pub sharing: String,
+    /// Existing user suggested as the new owner, if any.
+    pub suggested_owner: Option<String>,
+}
+
+/// Result of an orphaned object scan.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrphanReport {
+    /// Number of objects checked.
+    pub scanned: usize,
+    /// Number of existing users the owners were checked against.
+    pub users: usize,
+    pub orphans: Vec<OrphanedObject>,
+}
+
+impl OrphanReport {
+    /// Distinct missing owners, sorted.
+    pub fn missing_owners(&self) -> Vec<&str> {
+        let mut owners: Vec<&str> = self.orphans.iter().map(|o| o.owner.as_str()).collect();
+        owners.sort_unstable();
+        owners.dedup();
+        owners
+    }
+}
+
+/// Run the shared orphaned object scan.
+pub async fn find_orphaned_objects(
+    client: &SplunkClient,
+    request: &OrphanScanRequest,
+    cancel: Option<&dyn CancellationProbe>,
+) -> Result<OrphanReport> {
+    ensure_not_cancelled(cancel)?;
+
+    let kinds: &[KnowledgeObjectKind] = if request.kinds.is_empty() {
+        &DEFAULT_KINDS
+    } else {
+        &request.kinds
+    };
+    let app = request.app.as_deref();
+
+    // count=0 asks Splunk for every user rather than one page
+    let (users, listings) = tokio::try_join!(
+        client.list_users(Some(0), None),
+        try_join_all(kinds.iter().map(|kind| client.list_object_acls(*kind, app))),
+    )?;
+    ensure_not_cancelled(cancel)?;
+
+    let usernames: Vec<String> = users.into_iter().map(|user| user.name).collect();
+    if let Some(fallback) = request.fallback_owner.as_deref()
+        && !usernames
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(fallback))
+    {
+        bail!("Suggested owner '{}' is not an existing user", fallback);
+    }
+
+    let objects: Vec<ObjectAcl> = listings.into_iter().flatten().collect();
+    Ok(OrphanReport {
+        scanned: objects.len(),
+        users: usernames.len(),
+        orphans: correlate(&objects, &usernames, request.fallback_owner.as_deref()),
+    })
+}
+
+/// Objects in `objects` whose owner is neither a built-in owner nor in `usernames`.
+pub fn correlate(
+    objects: &[ObjectAcl],
+    usernames: &[String],
+    fallback_owner: Option<&str>,
+) -> Vec<OrphanedObject> {
+    let existing: HashSet<String> = usernames.iter().map(|name| name.to_lowercase()).collect();
+    let is_live = |owner: &str| existing.contains(&owner.to_lowercase());
+    let is_builtin = |owner: &str| {
+        BUILTIN_OWNERS
+            .iter()
+            .any(|builtin| owner.eq_ignore_ascii_case(builtin))
+    };
+
+    // Per app, how many objects each existing (non-built-in) user owns.
+    let mut app_owners: BTreeMap<&str, BTreeMap<&str, usize>> = BTreeMap::new();
+    for object in objects {
+        if is_live(&object.owner) && !is_builtin(&object.owner) {
+            *app_owners
+                .entry(object.app.as_str())
+                .or_default()
+                .entry(object.owner.as_str())
+                .or_default() += 1;
+        }
+    }
+    let suggest = |app: &str| -> Option<String> {
+        if let Some(fallback) = fallback_owner {
+            return Some(fallback.to_string());
+        }
+        // Highest count wins; ties go to the alphabetically first owner.
+        app_owners
+            .get(app)?
+            .iter()
+            .max_by(|(left, left_count), (right, right_count)| {
+                left_count.cmp(right_count).then_with(|| right.cmp(left))
+            })
+            .map(|(owner, _)| owner.to_string())
+    };
+
+    let mut orphans: Vec<OrphanedObject> = objects
+        .iter()
+        .filter(|object| !is_builtin(&object.owner) && !is_live(&object.owner))
+        .map(|object| OrphanedObject {
+            kind: object.kind,
+            name: object.name.clone(),
+            app: object.app.clone(),
+            owner: object.owner.clone(),
+            sharing: object.sharing.clone(),
+            suggested_owner: suggest(&object.app),
+        })
+        .collect();
+    orphans.sort_by(|left, right| {
+        (&left.owner, left.kind.label(), &left.app, &left.name).cmp(&(
+            &right.owner,
+            right.kind.label(),
+            &right.app,
+            &right.name,
+        ))
+    });
+    orphans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(kind: KnowledgeObjectKind, name: &str, app: &str, owner: &str) -> ObjectAcl {
+        ObjectAcl {
+            name: name.to_string(),
+            kind,
+            app: app.to_string(),
+            owner: owner.to_string(),
+            sharing: "user".to_string(),
+            read: Vec::new(),
+            write: Vec::new(),
+            can_change_perms: true,
+        }
+    }
+
+    fn users(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn correlate_flags_missing_owners_only() {
+        let objects = vec![
+            object(KnowledgeObjectKind::Macro, "m", "search", "gone"),
+            object(KnowledgeObjectKind::SavedSearch, "s", "search", "Admin"),
+            object(KnowledgeObjectKind::Lookup, "l", "search", "nobody"),
+            object(
+                KnowledgeObjectKind::Dashboard,
+                "d",
+                "search",
+                "splunk-system-user",
+            ),
+        ];
+        let orphans = correlate(&objects, &users(&["admin"]), None);
+        assert_eq!(orphans.len(), 1);
+        assert_eq!(orphans[0].name, "m");
+        assert_eq!(orphans[0].owner, "gone");
+    }
+
+    #[test]
+    fn correlate_suggests_most_common_owner_in_app() {
+        let objects = vec![
+            object(KnowledgeObjectKind::SavedSearch, "a", "itsi", "bob"),
+            object(KnowledgeObjectKind::SavedSearch, "b", "itsi", "carol"),
+            object(KnowledgeObjectKind::Macro, "c", "itsi", "carol"),
+            object(KnowledgeObjectKind::Macro, "d", "itsi", "nobody"),
+            object(KnowledgeObjectKind::SavedSearch, "e", "search", "bob"),
+            object(KnowledgeObjectKind::SavedSearch, "x", "itsi", "gone"),
+            object(KnowledgeObjectKind::SavedSearch, "y", "empty_app", "gone"),
+        ];
+        let users = users(&["bob", "carol"]);
+
+        let orphans = correlate(&objects, &users, None);
+        let suggestions: Vec<_> = orphans
+            .iter()
+            .map(|o| (o.app.as_str(), o.suggested_owner.as_deref()))
+            .collect();
+        assert_eq!(
+            suggestions,
+            vec![("empty_app", None), ("itsi", Some("carol"))]
+        );
+
+        let orphans = correlate(&objects, &users, Some("svc_account"));
+        assert!(
+            orphans
+                .iter()
+                .all(|o| o.suggested_owner.as_deref() == Some("svc_account"))
+        );
+    }
+
+    #[test]
+    fn missing_owners_are_distinct_and_sorted() {
+        let objects = vec![
+            object(KnowledgeObjectKind::Macro, "m", "search", "zed"),
+            object(KnowledgeObjectKind::Macro, "n", "search", "amy"),
+            object(KnowledgeObjectKind::Lookup, "l", "search", "zed"),
+        ];
+        let report = OrphanReport {
+            scanned: 3,
+            users: 0,
+            orphans: correlate(&objects, &[], None),
+        };
+        assert_eq!(report.missing_owners(), vec!["amy", "zed"]);
+    }
+}
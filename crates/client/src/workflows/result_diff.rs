@@ -0,0 +1,341 @@
+//! Shared row-level diff of two search result sets ("compare" mode).
+//!
+//! Purpose:
+//! - Show what changed between two runs of a query, either over two time ranges or
+//!   before and after a change, as added, removed, and changed rows.
+//!
+//! Responsibilities:
+//! - Pair baseline and current rows by key fields (given, or inferred from the results).
+//! - Classify every row as added, removed, changed, or unchanged, listing changed fields.
+//!
+//! Does NOT handle:
+//! - Running the searches (frontends run the query twice or keep the previous run).
+//! - Output formatting or coloring (frontend concern).
+//!
+//! Invariants:
+//! - Inferred key fields are the non-internal fields (no leading `_`) present in every
+//!   row of both sets whose values are never numeric; with no key fields, the whole row
+//!   is the key, so a differing row shows up as removed plus added.
+//! - Rows sharing a key are paired in result order; extras are added or removed.
+//! - Splunk bookkeeping fields that vary between runs (`_cd`, `_serial`, ...) are ignored.
+//! - Rows are reported in current-result order, followed by removed rows in baseline order.
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Per-run Splunk fields that never indicate a change in the data itself.
+const VOLATILE_FIELDS: &[&str] = &["_bkt", "_cd", "_indextime", "_kv", "_serial", "_si"];
+
+/// How one row differs between the baseline and current results.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "change", rename_all = "lowercase")]
+pub enum RowDiff {
+    /// Row only in the current results.
+    Added { row: Value },
+    /// Row only in the baseline results.
+    Removed { row: Value },
+    /// Row in both with different values; `fields` lists the fields that differ.
+    Changed {
+        before: Value,
+        after: Value,
+        fields: Vec<String>,
+    },
+    /// Row in both with identical values.
+    Unchanged { row: Value },
+}
+
+impl RowDiff {
+    /// Diff marker: `+` added, `-` removed, `~` changed, space unchanged.
+    pub fn marker(&self) -> char {
+        match self {
+            Self::Added { .. } => '+',
+            Self::Removed { .. } => '-',
+            Self::Changed { .. } => '~',
+            Self::Unchanged { .. } => ' ',
+        }
+    }
+
+    /// The row as it is now (the baseline row for removed rows).
+    pub fn row(&self) -> &Value {
+        match self {
+            Self::Added { row } | Self::Removed { row } | Self::Unchanged { row } => row,
+            Self::Changed { after, .. } => after,
+        }
+    }
+
+    /// Whether the row differs between the two result sets.
+    pub fn is_change(&self) -> bool {
+        !matches!(self, Self::Unchanged { .. })
+    }
+}
+
+/// Row counts per kind of change.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffSummary {
+    pub added: usize,
+    pub removed: usize,
+    pub changed: usize,
+    pub unchanged: usize,
+}
+
+/// Row-level diff of a baseline and a current result set.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResultDiff {
+    /// Fields rows were paired by; empty when whole rows were compared.
+    pub key_fields: Vec<String>,
+    pub summary: DiffSummary,
+    /// Every row, in current-result order followed by removed rows.
+    pub rows: Vec<RowDiff>,
+}
+
+impl ResultDiff {
+    /// Rows that differ (everything but unchanged rows).
+    pub fn changes(&self) -> impl Iterator<Item = &RowDiff> {
+        self.rows.iter().filter(|row| row.is_change())
+    }
+
+    /// Whether any row was added, removed, or changed.
+    pub fn has_changes(&self) -> bool {
+        self.summary.added + self.summary.removed + self.summary.changed > 0
+    }
+}
+
+/// Diff `current` against `baseline`, pairing rows by `key_fields`.
+///
+/// An empty `key_fields` infers the key with [`infer_key_fields`].
+pub fn diff_results(baseline: &[Value], current: &[Value], key_fields: &[String]) -> ResultDiff {
+    let key_fields = if key_fields.is_empty() {
+        infer_key_fields(baseline, current)
+    } else {
+        key_fields.to_vec()
+    };
+
+    let mut unmatched: HashMap<String, VecDeque<usize>> = HashMap::new();
+    for (index, row) in baseline.iter().enumerate() {
+        unmatched
+            .entry(row_key(row, &key_fields))
+            .or_default()
+            .push_back(index);
+    }
+
+    let mut paired = vec![false; baseline.len()];
+    let mut summary = DiffSummary::default();
+    let mut rows = Vec::with_capacity(current.len());
+    for row in current {
+        let partner = unmatched
+            .get_mut(&row_key(row, &key_fields))
+            .and_then(VecDeque::pop_front);
+        let Some(index) = partner else {
+            summary.added += 1;
+            rows.push(RowDiff::Added { row: row.clone() });
+            continue;
+        };
+        paired[index] = true;
+
+        let fields = changed_fields(&baseline[index], row);
+        if fields.is_empty() {
+            summary.unchanged += 1;
+            rows.push(RowDiff::Unchanged { row: row.clone() });
+        } else {
+            summary.changed += 1;
+            rows.push(RowDiff::Changed {
+                before: baseline[index].clone(),
+                after: row.clone(),
+                fields,
+            });
+        }
+    }
+
+    for (row, _) in baseline.iter().zip(&paired).filter(|(_, paired)| !**paired) {
+        summary.removed += 1;
+        rows.push(RowDiff::Removed { row: row.clone() });
+    }
+
+    ResultDiff {
+        key_fields,
+        summary,
+        rows,
+    }
+}
+
+/// Infer key fields: non-internal fields present in every row whose values are never numeric.
+///
+/// Typical `stats ... by host` output is keyed by `host`; results without such fields
+/// (raw events, `timechart`) get no key fields.
+pub fn infer_key_fields(baseline: &[Value], current: &[Value]) -> Vec<String> {
+    let mut rows = baseline.iter().chain(current).map(Value::as_object);
+    let Some(Some(first)) = rows.next() else {
+        return Vec::new();
+    };
+
+    let mut candidates: BTreeSet<&str> = first
+        .iter()
+        .filter(|(name, value)| !name.starts_with('_') && is_key_value(value))
+        .map(|(name, _)| name.as_str())
+        .collect();
+    for row in rows {
+        let Some(row) = row else {
+            return Vec::new();
+        };
+        candidates.retain(|name| row.get(*name).is_some_and(is_key_value));
+    }
+    candidates.into_iter().map(str::to_string).collect()
+}
+
+/// Key fields identify a row, so they must be non-numeric scalars.
+fn is_key_value(value: &Value) -> bool {
+    match value {
+        Value::String(text) => text.trim().parse::<f64>().is_err(),
+        Value::Bool(_) => true,
+        _ => false,
+    }
+}
+
+/// Map key for a row: its key field values, or the whole comparable row without key fields.
+fn row_key(row: &Value, key_fields: &[String]) -> String {
+    if key_fields.is_empty() {
+        return comparable(row).to_string();
+    }
+    let key: Vec<&Value> = key_fields
+        .iter()
+        .map(|field| row.get(field).unwrap_or(&Value::Null))
+        .collect();
+    serde_json::to_string(&key).unwrap_or_default()
+}
+
+/// The row without volatile fields (non-object rows are compared as-is).
+pub(crate) fn comparable(row: &Value) -> Value {
+    match row {
+        Value::Object(object) => Value::Object(
+            object
+                .iter()
+                .filter(|(name, _)| !VOLATILE_FIELDS.contains(&name.as_str()))
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect::<Map<_, _>>(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Sorted names of the fields whose values differ (missing counts as different).
+fn changed_fields(before: &Value, after: &Value) -> Vec<String> {
+    let (Value::Object(before), Value::Object(after)) = (comparable(before), comparable(after))
+    else {
+        return if comparable(before) == comparable(after) {
+            Vec::new()
+        } else {
+            vec![String::new()]
+        };
+    };
+
+    let names: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+    names
+        .into_iter()
+        .filter(|name| before.get(*name) != after.get(*name))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn counts(host: &str, count: &str) -> Value {
+        json!({"host": host, "count": count})
+    }
+
+    #[test]
+    fn test_stats_rows_are_paired_by_inferred_key() {
+        let baseline = vec![
+            counts("web-01", "10"),
+            counts("web-02", "5"),
+            counts("db-01", "3"),
+        ];
+        let current = vec![
+            counts("web-01", "12"),
+            counts("db-01", "3"),
+            counts("app-01", "1"),
+        ];
+
+        let diff = diff_results(&baseline, &current, &[]);
+
+        assert_eq!(diff.key_fields, vec!["host".to_string()]);
+        assert_eq!(
+            diff.summary,
+            DiffSummary {
+                added: 1,
+                removed: 1,
+                changed: 1,
+                unchanged: 1,
+            }
+        );
+        let markers: String = diff.rows.iter().map(RowDiff::marker).collect();
+        assert_eq!(markers, "~ +-");
+        match &diff.rows[0] {
+            RowDiff::Changed { before, fields, .. } => {
+                assert_eq!(before["count"], "10");
+                assert_eq!(fields, &vec!["count".to_string()]);
+            }
+            other => panic!("expected a changed row, got {:?}", other),
+        }
+        assert_eq!(diff.rows[3].row()["host"], "web-02");
+    }
+
+    #[test]
+    fn test_rows_without_key_fields_compare_whole_rows() {
+        let baseline = vec![json!({"_time": "1", "count": "4"})];
+        let current = vec![
+            json!({"_time": "1", "count": "5"}),
+            json!({"_time": "2", "count": "4"}),
+        ];
+
+        let diff = diff_results(&baseline, &current, &[]);
+
+        assert!(diff.key_fields.is_empty());
+        assert_eq!(diff.summary.added, 2);
+        assert_eq!(diff.summary.removed, 1);
+        assert_eq!(diff.summary.changed, 0);
+    }
+
+    #[test]
+    fn test_explicit_keys_duplicates_and_volatile_fields() {
+        let baseline = vec![
+            json!({"user": "a", "action": "login", "_cd": "1:1"}),
+            json!({"user": "a", "action": "logout", "_cd": "1:2"}),
+        ];
+        let current = vec![
+            json!({"user": "a", "action": "login", "_cd": "9:1"}),
+            json!({"user": "a", "action": "login", "_cd": "9:2"}),
+            json!({"user": "a", "action": "login", "_cd": "9:3"}),
+        ];
+
+        let diff = diff_results(&baseline, &current, &["user".to_string()]);
+
+        let markers: String = diff.rows.iter().map(RowDiff::marker).collect();
+        assert_eq!(markers, " ~+");
+        assert!(diff.has_changes());
+        assert_eq!(diff.changes().count(), 2);
+    }
+
+    #[test]
+    fn test_infer_key_fields_skips_numeric_and_partial_fields() {
+        let rows = vec![
+            json!({"host": "a", "status": "200", "region": "eu", "_time": "x"}),
+            json!({"host": "b", "status": "404"}),
+        ];
+        assert_eq!(infer_key_fields(&rows, &[]), vec!["host".to_string()]);
+        assert!(infer_key_fields(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn test_row_diff_serializes_with_change_tag() {
+        let value = serde_json::to_value(RowDiff::Added {
+            row: json!({"host": "a"}),
+        })
+        .unwrap();
+        assert_eq!(value, json!({"change": "added", "row": {"host": "a"}}));
+    }
+}
@@ -0,0 +1,224 @@
+//! Shared merge of search results gathered from several sources (profiles).
+//!
+//! Purpose:
+//! - Combine the results of one query run against several Splunk deployments into a
+//!   single result set, a client-side stand-in for federated search.
+//!
+//! Responsibilities:
+//! - Collapse rows that are identical apart from their source into one row.
+//! - Optionally sum numeric fields across sources for rows sharing key field values.
+//! - Record every source that contributed to a merged row.
+//!
+//! Does NOT handle:
+//! - Running the searches or tagging rows with their source (frontend concern).
+//! - Output formatting.
+//!
+//! Invariants:
+//! - Merged rows keep the order in which their first contributing row appeared.
+//! - The source field of a merged row lists contributing sources, comma-separated, in
+//!   first-seen order and without duplicates.
+//! - Splunk bookkeeping fields that vary between runs (`_cd`, `_serial`, ...) are ignored
+//!   when comparing rows.
+//! - When summing, missing and non-numeric values add nothing; a sum is an integer when
+//!   every added value is one.
+
+use std::collections::HashMap;
+
+use serde_json::{Map, Number, Value};
+
+use super::result_diff::comparable;
+
+/// Merge `rows`, each tagged with its source in `source_field`.
+///
+/// With empty `sum_by`, rows that are identical apart from their source are collapsed.
+/// Otherwise rows are grouped by their `sum_by` values and each merged row holds those
+/// values, the totals of `sum_fields`, and the contributing sources.
+pub fn merge_results(
+    rows: &[Value],
+    source_field: &str,
+    sum_by: &[String],
+    sum_fields: &[String],
+) -> Vec<Value> {
+    let mut groups: Vec<Group> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+
+    for row in rows {
+        let source = row
+            .get(source_field)
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let key = if sum_by.is_empty() {
+            comparable(&without_field(row, source_field)).to_string()
+        } else {
+            let values: Vec<&Value> = sum_by
+                .iter()
+                .map(|field| row.get(field).unwrap_or(&Value::Null))
+                .collect();
+            serde_json::to_string(&values).unwrap_or_default()
+        };
+
+        let position = *index.entry(key).or_insert_with(|| {
+            groups.push(Group::new(row, source_field, sum_by, sum_fields));
+            groups.len() - 1
+        });
+        let group = &mut groups[position];
+        if !source.is_empty() && !group.sources.iter().any(|seen| seen == source) {
+            group.sources.push(source.to_string());
+        }
+        for (total, field) in group.totals.iter_mut().zip(sum_fields) {
+            total.add(row.get(field));
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|group| group.into_row(source_field, sum_fields))
+        .collect()
+}
+
+/// Rows merged into one output row.
+struct Group {
+    /// The first row for plain merges; the key field values when summing.
+    row: Map<String, Value>,
+    sources: Vec<String>,
+    /// One total per sum field; empty for plain merges.
+    totals: Vec<Total>,
+}
+
+impl Group {
+    fn new(row: &Value, source_field: &str, sum_by: &[String], sum_fields: &[String]) -> Self {
+        let row = if sum_by.is_empty() {
+            match without_field(row, source_field) {
+                Value::Object(map) => map,
+                other => Map::from_iter([("value".to_string(), other)]),
+            }
+        } else {
+            sum_by
+                .iter()
+                .map(|field| {
+                    let value = row.get(field).cloned().unwrap_or(Value::Null);
+                    (field.clone(), value)
+                })
+                .collect()
+        };
+        let totals = if sum_by.is_empty() {
+            Vec::new()
+        } else {
+            sum_fields.iter().map(|_| Total::default()).collect()
+        };
+        Self {
+            row,
+            sources: Vec::new(),
+            totals,
+        }
+    }
+
+    fn into_row(mut self, source_field: &str, sum_fields: &[String]) -> Value {
+        for (field, total) in sum_fields.iter().zip(self.totals) {
+            self.row.insert(field.clone(), total.into_value());
+        }
+        self.row.insert(
+            source_field.to_string(),
+            Value::String(self.sources.join(",")),
+        );
+        Value::Object(self.row)
+    }
+}
+
+/// Running sum of one field.
+#[derive(Default)]
+struct Total {
+    integer: i64,
+    float: f64,
+    is_float: bool,
+}
+
+impl Total {
+    /// Add a JSON number or numeric string (Splunk returns numbers as strings).
+    fn add(&mut self, value: Option<&Value>) {
+        let text = match value {
+            Some(Value::Number(number)) => number.to_string(),
+            Some(Value::String(text)) => text.trim().to_string(),
+            _ => return,
+        };
+        if let Ok(integer) = text.parse::<i64>()
+            && let Some(sum) = self.integer.checked_add(integer)
+        {
+            self.integer = sum;
+        } else if let Ok(float) = text.parse::<f64>()
+            && float.is_finite()
+        {
+            self.float += float;
+            self.is_float = true;
+        }
+    }
+
+    fn into_value(self) -> Value {
+        if !self.is_float {
+            return Value::Number(self.integer.into());
+        }
+        Number::from_f64(self.float + self.integer as f64)
+            .map(Value::Number)
+            .unwrap_or(Value::Null)
+    }
+}
+
+/// `row` without `field` (non-object rows are returned as-is).
+fn without_field(row: &Value, field: &str) -> Value {
+    match row {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .filter(|(name, _)| name.as_str() != field)
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn fields(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_identical_rows_collapse_and_list_sources() {
+        let rows = vec![
+            json!({"host": "web-01", "status": "ok", "_cd": "1:2", "profile": "us"}),
+            json!({"host": "web-02", "status": "ok", "profile": "us"}),
+            json!({"host": "web-01", "status": "ok", "_cd": "9:9", "profile": "eu"}),
+        ];
+        let merged = merge_results(&rows, "profile", &[], &[]);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0]["host"], "web-01");
+        assert_eq!(merged[0]["profile"], "us,eu");
+        assert_eq!(merged[1]["profile"], "us");
+    }
+
+    #[test]
+    fn test_sum_by_key_adds_counts_across_sources() {
+        let rows = vec![
+            json!({"sourcetype": "access", "count": "10", "bytes": "1.5", "profile": "us"}),
+            json!({"sourcetype": "syslog", "count": "3", "profile": "us"}),
+            json!({"sourcetype": "access", "count": 5, "bytes": "2", "profile": "eu"}),
+            json!({"sourcetype": "access", "count": "n/a", "profile": "eu"}),
+        ];
+        let merged = merge_results(
+            &rows,
+            "profile",
+            &fields(&["sourcetype"]),
+            &fields(&["count", "bytes"]),
+        );
+        assert_eq!(
+            merged,
+            vec![
+                json!({"sourcetype": "access", "count": 15, "bytes": 3.5, "profile": "us,eu"}),
+                json!({"sourcetype": "syslog", "count": 3, "bytes": 0, "profile": "us"}),
+            ]
+        );
+    }
+}
@@ -0,0 +1,210 @@
+//! Shared saved-search bulk enable/disable workflow.
+//!
+//! Purpose:
+//! - Apply one action to many saved searches at once, e.g. disabling every search
+//!   owned by a departed user.
+//!
+//! Responsibilities:
+//! - Select saved searches by owner, app, and/or explicit names across apps.
+//! - Plan (dry run) or apply enable or disable to each one.
+//! - Report the per-search outcome, skipping searches already in the target state.
+//!
+//! Does NOT handle:
+//! - Confirmation prompts or output formatting (frontend concerns).
+//! - Owner reassignment (see `saved_search_ownership`).
+//!
+//! Invariants:
+//! - A failure on one saved search is recorded and does not abort the remaining items.
+//! - Requested names that match no saved search are reported as failed items.
+//! - Dry runs and unchanged items never issue write requests.
+
+use anyhow::{Result, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::SplunkClient;
+use crate::workflows::saved_search_ownership::select_searches;
+use crate::workflows::{CancellationProbe, ensure_not_cancelled};
+
+/// Action applied to every selected saved search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SavedSearchBulkAction {
+    Enable,
+    Disable,
+}
+
+impl SavedSearchBulkAction {
+    pub const fn slug(self) -> &'static str {
+        match self {
+            Self::Enable => "enable",
+            Self::Disable => "disable",
+        }
+    }
+}
+
+/// Input for a bulk saved-search update.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SavedSearchBulkRequest {
+    /// Only saved searches owned by this user.
+    pub owner: Option<String>,
+    /// Only saved searches in this app.
+    pub app: Option<String>,
+    /// Only saved searches with these names (all matching searches when empty).
+    pub names: Vec<String>,
+    pub action: SavedSearchBulkAction,
+    /// Only plan the changes without applying them.
+    pub dry_run: bool,
+}
+
+impl SavedSearchBulkRequest {
+    /// Validate the selection and action.
+    pub fn validate(&self) -> Result<()> {
+        if self
+            .owner
+            .as_deref()
+            .is_none_or(|owner| owner.trim().is_empty())
+            && self.names.is_empty()
+        {
+            bail!("Select saved searches with --owner or by name");
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of the bulk action on a single saved search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkItemStatus {
+    Planned,
+    Applied,
+    /// Already in the target state; nothing was sent.
+    Unchanged,
+    Failed,
+}
+
+impl BulkItemStatus {
+    pub const fn slug(self) -> &'static str {
+        match self {
+            Self::Planned => "planned",
+            Self::Applied => "applied",
+            Self::Unchanged => "unchanged",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+/// Per-search entry in a bulk report.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedSearchBulkItem {
+    pub name: String,
+    pub app: String,
+    /// Owner before the action.
+    pub owner: String,
+    /// Disabled state before the action.
+    pub disabled: bool,
+    pub status: BulkItemStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Full report of a bulk saved-search update.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedSearchBulkReport {
+    pub action: String,
+    pub dry_run: bool,
+    pub items: Vec<SavedSearchBulkItem>,
+}
+
+impl SavedSearchBulkReport {
+    /// Number of items with `status`.
+    pub fn count(&self, status: BulkItemStatus) -> usize {
+        self.items
+            .iter()
+            .filter(|item| item.status == status)
+            .count()
+    }
+
+    /// Number of items that failed.
+    pub fn failed_count(&self) -> usize {
+        self.count(BulkItemStatus::Failed)
+    }
+}
+
+/// Run the shared bulk saved-search workflow.
+pub async fn bulk_update_saved_searches(
+    client: &SplunkClient,
+    request: &SavedSearchBulkRequest,
+    cancel: Option<&dyn CancellationProbe>,
+) -> Result<SavedSearchBulkReport> {
+    request.validate()?;
+    ensure_not_cancelled(cancel)?;
+
+    let searches = client
+        .list_saved_search_ownership(request.app.as_deref(), request.owner.as_deref())
+        .await?;
+    let (searches, missing) = select_searches(searches, &request.names);
+
+    let mut items = Vec::with_capacity(searches.len() + missing.len());
+    for search in &searches {
+        ensure_not_cancelled(cancel)?;
+
+        let disable = request.action == SavedSearchBulkAction::Disable;
+        let (status, error) = if search.disabled == disable {
+            (BulkItemStatus::Unchanged, None)
+        } else if request.dry_run {
+            (BulkItemStatus::Planned, None)
+        } else {
+            match client.set_saved_search_disabled(search, disable).await {
+                Ok(()) => (BulkItemStatus::Applied, None),
+                Err(error) => (BulkItemStatus::Failed, Some(error.to_string())),
+            }
+        };
+
+        items.push(SavedSearchBulkItem {
+            name: search.name.clone(),
+            app: search.app.clone(),
+            owner: search.owner.clone(),
+            disabled: search.disabled,
+            status,
+            error,
+        });
+    }
+    items.extend(missing.into_iter().map(|name| SavedSearchBulkItem {
+        name,
+        app: String::new(),
+        owner: String::new(),
+        disabled: false,
+        status: BulkItemStatus::Failed,
+        error: Some("Saved search not found".to_string()),
+    }));
+
+    Ok(SavedSearchBulkReport {
+        action: request.action.slug().to_string(),
+        dry_run: request.dry_run,
+        items,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(action: SavedSearchBulkAction) -> SavedSearchBulkRequest {
+        SavedSearchBulkRequest {
+            owner: Some("departed_user".to_string()),
+            app: None,
+            names: Vec::new(),
+            action,
+            dry_run: true,
+        }
+    }
+
+    #[test]
+    fn validate_requires_a_selection() {
+        let mut req = request(SavedSearchBulkAction::Disable);
+        req.owner = None;
+        assert!(req.validate().is_err());
+
+        req.names = vec!["Errors".to_string()];
+        assert!(req.validate().is_ok());
+    }
+}
@@ -0,0 +1,349 @@
+//! Shared saved-search ownership reassignment workflow.
+//!
+//! Purpose:
+//! - Bulk-reassign saved searches from one owner to another (e.g. user offboarding).
+//!
+//! Responsibilities:
+//! - Select saved searches by current owner, app, and/or explicit names across apps.
+//! - Plan (dry run) or apply ACL ownership changes, optionally overriding sharing.
+//! - Summarize schedule impact: scheduled searches run under the owner's role quotas,
+//!   so every active scheduled search moves to the new owner's quotas.
+//!
+//! Does NOT handle:
+//! - Confirmation prompts or output formatting (frontend concerns).
+//! - Reassigning other knowledge objects (dashboards, macros, lookups).
+//!
+//! Invariants:
+//! - A failure on one saved search is recorded and does not abort the remaining items.
+//! - Requested names that match no saved search are reported as failed items.
+//! - Dry runs and unchanged items never issue write requests.
+
+use anyhow::{Result, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::SplunkClient;
+use crate::models::SavedSearchOwnership;
+use crate::workflows::{CancellationProbe, ensure_not_cancelled};
+
+/// Sharing levels accepted by the Splunk ACL endpoint.
+pub const VALID_SHARING_LEVELS: &[&str] = &["user", "app", "global"];
+
+/// Input for a saved-search ownership reassignment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReassignSavedSearchesRequest {
+    /// Only saved searches owned by this user.
+    pub from_owner: Option<String>,
+    /// User that should own the saved searches afterwards.
+    pub to_owner: String,
+    /// Restrict reassignment to a single app.
+    pub app: Option<String>,
+    /// Only saved searches with these names (all matching searches when empty).
+    pub names: Vec<String>,
+    /// Override the sharing level; existing sharing is kept when `None`.
+    pub sharing: Option<String>,
+    /// Only plan the changes without applying them.
+    pub dry_run: bool,
+}
+
+impl ReassignSavedSearchesRequest {
+    /// Validate the selection, owners, and the optional sharing override.
+    pub fn validate(&self) -> Result<()> {
+        if self.to_owner.trim().is_empty() {
+            bail!("The new owner must be non-empty");
+        }
+        let from_owner = self
+            .from_owner
+            .as_deref()
+            .filter(|owner| !owner.trim().is_empty());
+        if from_owner.is_none() && self.names.is_empty() {
+            bail!("Select saved searches by current owner or by name");
+        }
+        if from_owner == Some(self.to_owner.as_str()) {
+            bail!("The current and new owner must differ");
+        }
+        if let Some(sharing) = &self.sharing
+            && !VALID_SHARING_LEVELS.contains(&sharing.as_str())
+        {
+            bail!(
+                "Invalid sharing level '{}'. Valid values: {}",
+                sharing,
+                VALID_SHARING_LEVELS.join(", ")
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of reassigning a single saved search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReassignStatus {
+    Planned,
+    Reassigned,
+    /// Already owned by the new owner with the requested sharing; nothing was sent.
+    Unchanged,
+    Failed,
+}
+
+impl ReassignStatus {
+    pub const fn slug(self) -> &'static str {
+        match self {
+            Self::Planned => "planned",
+            Self::Reassigned => "reassigned",
+            Self::Unchanged => "unchanged",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+/// Per-search entry in a reassignment report.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedSearchReassignItem {
+    pub name: String,
+    pub app: String,
+    pub previous_sharing: String,
+    pub sharing: String,
+    pub is_scheduled: bool,
+    pub cron_schedule: Option<String>,
+    pub disabled: bool,
+    pub status: ReassignStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Schedule impact of moving saved searches to a new owner.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduleImpact {
+    /// Saved searches matched for reassignment.
+    pub total: usize,
+    /// Matched saved searches that have a schedule.
+    pub scheduled: usize,
+    /// Scheduled and enabled searches that will now run under the new owner's quotas.
+    pub active_scheduled: usize,
+    /// Scheduled but disabled searches (no immediate quota impact).
+    pub disabled_scheduled: usize,
+}
+
+impl ScheduleImpact {
+    fn from_searches(searches: &[SavedSearchOwnership]) -> Self {
+        let scheduled: Vec<_> = searches.iter().filter(|s| s.is_scheduled).collect();
+        let active_scheduled = scheduled.iter().filter(|s| !s.disabled).count();
+        Self {
+            total: searches.len(),
+            scheduled: scheduled.len(),
+            active_scheduled,
+            disabled_scheduled: scheduled.len() - active_scheduled,
+        }
+    }
+}
+
+/// Full report of a saved-search ownership reassignment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedSearchReassignReport {
+    pub from_owner: Option<String>,
+    pub to_owner: String,
+    pub app: Option<String>,
+    pub dry_run: bool,
+    pub schedule_impact: ScheduleImpact,
+    pub items: Vec<SavedSearchReassignItem>,
+}
+
+impl SavedSearchReassignReport {
+    /// Number of items with `status`.
+    pub fn count(&self, status: ReassignStatus) -> usize {
+        self.items
+            .iter()
+            .filter(|item| item.status == status)
+            .count()
+    }
+
+    /// Number of items that failed to reassign.
+    pub fn failed_count(&self) -> usize {
+        self.count(ReassignStatus::Failed)
+    }
+}
+
+/// Run the shared saved-search ownership reassignment workflow.
+pub async fn reassign_saved_searches(
+    client: &SplunkClient,
+    request: &ReassignSavedSearchesRequest,
+    cancel: Option<&dyn CancellationProbe>,
+) -> Result<SavedSearchReassignReport> {
+    request.validate()?;
+    ensure_not_cancelled(cancel)?;
+
+    let searches = client
+        .list_saved_search_ownership(request.app.as_deref(), request.from_owner.as_deref())
+        .await?;
+    let (searches, missing) = select_searches(searches, &request.names);
+
+    let schedule_impact = ScheduleImpact::from_searches(&searches);
+    let mut items = Vec::with_capacity(searches.len() + missing.len());
+
+    for search in &searches {
+        ensure_not_cancelled(cancel)?;
+
+        let sharing = request
+            .sharing
+            .clone()
+            .unwrap_or_else(|| search.sharing.clone());
+        let (status, error) = if search.owner == request.to_owner && sharing == search.sharing {
+            (ReassignStatus::Unchanged, None)
+        } else if request.dry_run {
+            (ReassignStatus::Planned, None)
+        } else {
+            match client
+                .set_saved_search_owner(search, &request.to_owner, &sharing)
+                .await
+            {
+                Ok(()) => (ReassignStatus::Reassigned, None),
+                Err(error) => (ReassignStatus::Failed, Some(error.to_string())),
+            }
+        };
+
+        items.push(SavedSearchReassignItem {
+            name: search.name.clone(),
+            app: search.app.clone(),
+            previous_sharing: search.sharing.clone(),
+            sharing,
+            is_scheduled: search.is_scheduled,
+            cron_schedule: search.cron_schedule.clone(),
+            disabled: search.disabled,
+            status,
+            error,
+        });
+    }
+    items.extend(missing.into_iter().map(|name| SavedSearchReassignItem {
+        name,
+        app: String::new(),
+        previous_sharing: String::new(),
+        sharing: String::new(),
+        is_scheduled: false,
+        cron_schedule: None,
+        disabled: false,
+        status: ReassignStatus::Failed,
+        error: Some("Saved search not found".to_string()),
+    }));
+
+    Ok(SavedSearchReassignReport {
+        from_owner: request.from_owner.clone(),
+        to_owner: request.to_owner.clone(),
+        app: request.app.clone(),
+        dry_run: request.dry_run,
+        schedule_impact,
+        items,
+    })
+}
+
+/// Keep searches named in `names` (all when empty), sorted by app and name, and
+/// return the requested names that matched nothing.
+pub(crate) fn select_searches(
+    mut searches: Vec<SavedSearchOwnership>,
+    names: &[String],
+) -> (Vec<SavedSearchOwnership>, Vec<String>) {
+    if !names.is_empty() {
+        searches.retain(|search| names.contains(&search.name));
+    }
+    searches.sort_by(|left, right| (&left.app, &left.name).cmp(&(&right.app, &right.name)));
+
+    let mut missing = Vec::new();
+    for name in names {
+        if !searches.iter().any(|search| &search.name == name) && !missing.contains(name) {
+            missing.push(name.clone());
+        }
+    }
+    (searches, missing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> ReassignSavedSearchesRequest {
+        ReassignSavedSearchesRequest {
+            from_owner: Some("departed_user".to_string()),
+            to_owner: "svc_account".to_string(),
+            app: None,
+            names: Vec::new(),
+            sharing: None,
+            dry_run: true,
+        }
+    }
+
+    fn ownership(name: &str, is_scheduled: bool, disabled: bool) -> SavedSearchOwnership {
+        SavedSearchOwnership {
+            name: name.to_string(),
+            app: "search".to_string(),
+            owner: "departed_user".to_string(),
+            sharing: "user".to_string(),
+            is_scheduled,
+            cron_schedule: is_scheduled.then(|| "*/5 * * * *".to_string()),
+            disabled,
+        }
+    }
+
+    #[test]
+    fn validate_rejects_same_owner() {
+        let mut req = request();
+        req.to_owner = "departed_user".to_string();
+        assert!(req.validate().is_err());
+    }
+
+    #[test]
+    fn validate_requires_a_selection() {
+        let mut req = request();
+        req.from_owner = None;
+        assert!(req.validate().is_err());
+
+        req.names = vec!["Errors".to_string()];
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn select_searches_filters_names_and_reports_missing() {
+        let mut other_app = ownership("a", false, true);
+        other_app.app = "itsi".to_string();
+        let searches = vec![
+            ownership("b", false, false),
+            ownership("a", false, false),
+            other_app,
+        ];
+        let names = vec!["a".to_string(), "ghost".to_string()];
+        let (selected, missing) = select_searches(searches, &names);
+        let keys: Vec<_> = selected
+            .iter()
+            .map(|s| (s.app.as_str(), s.name.as_str()))
+            .collect();
+        assert_eq!(keys, vec![("itsi", "a"), ("search", "a")]);
+        assert_eq!(missing, vec!["ghost".to_string()]);
+    }
+
+    #[test]
+    fn validate_rejects_unknown_sharing() {
+        let mut req = request();
+        req.sharing = Some("everyone".to_string());
+        assert!(req.validate().is_err());
+
+        req.sharing = Some("app".to_string());
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn schedule_impact_counts_active_and_disabled() {
+        let searches = vec![
+            ownership("a", true, false),
+            ownership("b", true, true),
+            ownership("c", false, false),
+        ];
+        let impact = ScheduleImpact::from_searches(&searches);
+        assert_eq!(
+            impact,
+            ScheduleImpact {
+                total: 3,
+                scheduled: 2,
+                active_scheduled: 1,
+                disabled_scheduled: 1,
+            }
+        );
+    }
+}
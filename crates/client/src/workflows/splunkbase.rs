@@ -0,0 +1,570 @@
+//! Shared Splunkbase app download workflow.
+//!
+//! Purpose:
+//! - Install apps straight from Splunkbase, pinned to a release when needed,
+//!   instead of downloading the package by hand first.
+//!
+//! Responsibilities:
+//! - Authenticate against Splunkbase with an API token or account credentials.
+//! - Search apps and list the releases of an app.
+//! - Download a release, verify its SHA-256 checksum, and upload it to Splunk.
+//!
+//! Does NOT handle:
+//! - Storing Splunkbase credentials (read from flags or the environment by frontends).
+//! - App upgrades or dependency resolution between apps.
+//!
+//! Invariants:
+//! - A package is never uploaded unless its checksum matches the release checksum.
+//! - Without a pinned version the latest release (first listed) is installed.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use secrecy::{ExposeSecret, SecretString};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::info;
+
+use crate::SplunkClient;
+use crate::models::App;
+
+/// Public Splunkbase site.
+pub const DEFAULT_SPLUNKBASE_URL: &str = "https://splunkbase.splunk.com";
+
+/// Upper bound for a single Splunkbase request, including package downloads.
+const SPLUNKBASE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Credentials used to download from Splunkbase.
+#[derive(Debug, Clone)]
+pub enum SplunkbaseCredentials {
+    /// Splunkbase session token, sent as `X-Auth-Token`.
+    Token(SecretString),
+    /// splunk.com account, exchanged for a session token on first use.
+    Login {
+        username: String,
+        password: SecretString,
+    },
+}
+
+/// Connection settings for Splunkbase.
+#[derive(Debug, Clone)]
+pub struct SplunkbaseConfig {
+    /// Splunkbase base URL (overridable for mirrors and tests).
+    pub base_url: String,
+    /// Credentials; only searching works without them.
+    pub credentials: Option<SplunkbaseCredentials>,
+}
+
+impl Default for SplunkbaseConfig {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_SPLUNKBASE_URL.to_string(),
+            credentials: None,
+        }
+    }
+}
+
+impl SplunkbaseConfig {
+    /// Read settings from `SPLUNKBASE_URL`, `SPLUNKBASE_TOKEN`,
+    /// `SPLUNKBASE_USERNAME` and `SPLUNKBASE_PASSWORD`.
+    ///
+    /// A token takes precedence over a username/password pair.
+    pub fn from_env() -> Self {
+        let base_url = splunk_config::env_var_or_none("SPLUNKBASE_URL")
+            .unwrap_or_else(|| DEFAULT_SPLUNKBASE_URL.to_string());
+        let credentials = match (
+            splunk_config::env_var_or_none("SPLUNKBASE_TOKEN"),
+            splunk_config::env_var_or_none("SPLUNKBASE_USERNAME"),
+            splunk_config::env_var_or_none("SPLUNKBASE_PASSWORD"),
+        ) {
+            (Some(token), _, _) => Some(SplunkbaseCredentials::Token(token.into())),
+            (None, Some(username), Some(password)) => Some(SplunkbaseCredentials::Login {
+                username,
+                password: password.into(),
+            }),
+            _ => None,
+        };
+        Self {
+            base_url,
+            credentials,
+        }
+    }
+}
+
+/// Errors specific to Splunkbase downloads.
+#[derive(Debug, thiserror::Error)]
+pub enum SplunkbaseError {
+    #[error(
+        "Splunkbase credentials required: set SPLUNKBASE_TOKEN or SPLUNKBASE_USERNAME/SPLUNKBASE_PASSWORD"
+    )]
+    MissingCredentials,
+    #[error("Splunkbase login failed: {0}")]
+    Login(String),
+    #[error("Splunkbase app {app_id} has no releases")]
+    NoReleases { app_id: u64 },
+    #[error("Splunkbase app {app_id} has no release {version} (available: {available})")]
+    VersionNotFound {
+        app_id: u64,
+        version: String,
+        available: String,
+    },
+    #[error("Splunkbase release {version} of app {app_id} has no checksum to verify")]
+    MissingChecksum { app_id: u64, version: String },
+    #[error("Checksum mismatch for {file_name}: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        file_name: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+/// App summary returned by a Splunkbase search.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SplunkbaseApp {
+    /// Numeric Splunkbase ID (as in `splunkbase.splunk.com/app/<uid>`).
+    pub uid: u64,
+    /// App folder name once installed.
+    #[serde(default)]
+    pub appid: String,
+    /// Display title.
+    #[serde(default)]
+    pub title: String,
+}
+
+/// A downloadable release of a Splunkbase app.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SplunkbaseRelease {
+    /// Release version (e.g. `8.2.0`).
+    pub name: String,
+    /// Package file name.
+    #[serde(default)]
+    pub filename: Option<String>,
+    /// SHA-256 of the package, hex encoded.
+    #[serde(default, alias = "checksum")]
+    pub sha256: Option<String>,
+}
+
+/// A downloaded, checksum-verified release package.
+#[derive(Debug, Clone)]
+pub struct SplunkbasePackage {
+    pub app_id: u64,
+    pub version: String,
+    pub file_name: String,
+    pub content: Vec<u8>,
+}
+
+/// Where an app package comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppPackageSource {
+    /// A local .spl/.tgz file.
+    File(PathBuf),
+    /// A Splunkbase app, optionally pinned to a release version.
+    Splunkbase {
+        app_id: u64,
+        version: Option<String>,
+    },
+}
+
+impl std::fmt::Display for AppPackageSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Splunkbase {
+                app_id,
+                version: Some(version),
+            } => write!(f, "Splunkbase app {app_id} version {version}"),
+            Self::Splunkbase {
+                app_id,
+                version: None,
+            } => write!(f, "Splunkbase app {app_id} (latest)"),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct SearchResponse {
+    #[serde(default)]
+    results: Vec<SplunkbaseApp>,
+}
+
+/// Minimal Splunkbase API client.
+pub struct SplunkbaseClient {
+    http: reqwest::Client,
+    config: SplunkbaseConfig,
+}
+
+impl SplunkbaseClient {
+    /// Build a client for the given settings.
+    pub fn new(config: SplunkbaseConfig) -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .timeout(SPLUNKBASE_TIMEOUT)
+            .build()
+            .context("Failed to build Splunkbase HTTP client")?;
+        Ok(Self { http, config })
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.config.base_url.trim_end_matches('/'), path)
+    }
+
+    /// Search Splunkbase apps by free text.
+    pub async fn search_apps(&self, query: &str, limit: usize) -> Result<Vec<SplunkbaseApp>> {
+        let response = self
+            .http
+            .get(self.url("/api/v1/app/"))
+            .query(&[("query", query), ("limit", &limit.to_string())])
+            .send()
+            .await
+            .context("Failed to search Splunkbase")?;
+        let response = ensure_success(response, "search Splunkbase").await?;
+        let body: SearchResponse = response
+            .json()
+            .await
+            .context("Failed to parse Splunkbase search results")?;
+        Ok(body.results)
+    }
+
+    /// List the releases of an app, newest first.
+    pub async fn list_releases(&self, app_id: u64) -> Result<Vec<SplunkbaseRelease>> {
+        let response = self
+            .http
+            .get(self.url(&format!("/api/v1/app/{app_id}/release/")))
+            .send()
+            .await
+            .with_context(|| format!("Failed to list releases of Splunkbase app {app_id}"))?;
+        let response = ensure_success(response, "list Splunkbase releases").await?;
+        response
+            .json()
+            .await
+            .with_context(|| format!("Failed to parse releases of Splunkbase app {app_id}"))
+    }
+
+    /// Download a release (the latest when `version` is `None`) and verify its checksum.
+    pub async fn download_release(
+        &self,
+        app_id: u64,
+        version: Option<&str>,
+    ) -> Result<SplunkbasePackage> {
+        let releases = self.list_releases(app_id).await?;
+        let release = select_release(app_id, &releases, version)?;
+        let expected = release
+            .sha256
+            .clone()
+            .ok_or_else(|| SplunkbaseError::MissingChecksum {
+                app_id,
+                version: release.name.clone(),
+            })?;
+        let file_name = release
+            .filename
+            .clone()
+            .unwrap_or_else(|| format!("splunkbase-{}-{}.tgz", app_id, release.name));
+
+        let token = self.session_token().await?;
+        info!(
+            "Downloading Splunkbase app {} version {}",
+            app_id, release.name
+        );
+        let response = self
+            .http
+            .get(self.url(&format!("/app/{app_id}/release/{}/download/", release.name)))
+            .header("X-Auth-Token", token.expose_secret())
+            .send()
+            .await
+            .with_context(|| format!("Failed to download Splunkbase app {app_id}"))?;
+        let response = ensure_success(response, "download from Splunkbase").await?;
+        let content = response
+            .bytes()
+            .await
+            .with_context(|| format!("Failed to read Splunkbase app {app_id} package"))?
+            .to_vec();
+
+        verify_checksum(&file_name, &content, &expected)?;
+
+        Ok(SplunkbasePackage {
+            app_id,
+            version: release.name.clone(),
+            file_name,
+            content,
+        })
+    }
+
+    /// Resolve the token sent with downloads, logging in when only credentials are set.
+    async fn session_token(&self) -> Result<SecretString> {
+        match &self.config.credentials {
+            None => Err(SplunkbaseError::MissingCredentials.into()),
+            Some(SplunkbaseCredentials::Token(token)) => Ok(token.clone()),
+            Some(SplunkbaseCredentials::Login { username, password }) => {
+                let response = self
+                    .http
+                    .post(self.url("/api/account:login/"))
+                    .form(&[
+                        ("username", username.as_str()),
+                        ("password", password.expose_secret()),
+                    ])
+                    .send()
+                    .await
+                    .context("Failed to reach Splunkbase login")?;
+                let status = response.status();
+                if !status.is_success() {
+                    return Err(SplunkbaseError::Login(format!("HTTP {status}")).into());
+                }
+                let body = response
+                    .text()
+                    .await
+                    .context("Failed to read Splunkbase login response")?;
+                parse_login_token(&body)
+                    .map(SecretString::from)
+                    .ok_or_else(|| {
+                        SplunkbaseError::Login("no session token in response".to_string()).into()
+                    })
+            }
+        }
+    }
+}
+
+/// Download (when needed) and install an app package on the Splunk server.
+pub async fn install_app_package(
+    client: &SplunkClient,
+    source: &AppPackageSource,
+    splunkbase: &SplunkbaseConfig,
+) -> Result<App> {
+    match source {
+        AppPackageSource::File(path) => Ok(client.install_app(path).await?),
+        AppPackageSource::Splunkbase { app_id, version } => {
+            let package = SplunkbaseClient::new(splunkbase.clone())?
+                .download_release(*app_id, version.as_deref())
+                .await?;
+            info!(
+                "Installing {} ({} bytes) from Splunkbase",
+                package.file_name,
+                package.content.len()
+            );
+            Ok(client
+                .install_app_package(&package.file_name, &package.content)
+                .await?)
+        }
+    }
+}
+
+async fn ensure_success(response: reqwest::Response, action: &str) -> Result<reqwest::Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let body = response.text().await.unwrap_or_default();
+    bail!("Failed to {action}: HTTP {status}: {}", body.trim())
+}
+
+/// Pick the pinned release, or the latest one when no version is given.
+fn select_release<'a>(
+    app_id: u64,
+    releases: &'a [SplunkbaseRelease],
+    version: Option<&str>,
+) -> Result<&'a SplunkbaseRelease, SplunkbaseError> {
+    match version {
+        None => releases
+            .first()
+            .ok_or(SplunkbaseError::NoReleases { app_id }),
+        Some(version) => releases
+            .iter()
+            .find(|release| release.name == version)
+            .ok_or_else(|| SplunkbaseError::VersionNotFound {
+                app_id,
+                version: version.to_string(),
+                available: releases
+                    .iter()
+                    .map(|release| release.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            }),
+    }
+}
+
+fn verify_checksum(file_name: &str, content: &[u8], expected: &str) -> Result<(), SplunkbaseError> {
+    let actual = hex::encode(Sha256::digest(content));
+    if actual.eq_ignore_ascii_case(expected.trim()) {
+        Ok(())
+    } else {
+        Err(SplunkbaseError::ChecksumMismatch {
+            file_name: file_name.to_string(),
+            expected: expected.trim().to_string(),
+            actual,
+        })
+    }
+}
+
+/// Extract the session token from the Atom feed returned by `account:login`.
+fn parse_login_token(body: &str) -> Option<String> {
+    let start = body.find("<id>")? + "<id>".len();
+    let end = start + body[start..].find("</id>")?;
+    let token = body[start..end].trim();
+    (!token.is_empty()).then(|| token.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const PACKAGE: &[u8] = b"fake app package";
+
+    fn release(name: &str, sha256: Option<&str>) -> SplunkbaseRelease {
+        SplunkbaseRelease {
+            name: name.to_string(),
+            filename: Some(format!("app_{name}.tgz")),
+            sha256: sha256.map(str::to_string),
+        }
+    }
+
+    fn config(base_url: String, credentials: Option<SplunkbaseCredentials>) -> SplunkbaseConfig {
+        SplunkbaseConfig {
+            base_url,
+            credentials,
+        }
+    }
+
+    async fn mount_releases(server: &MockServer, sha256: &str) {
+        Mock::given(method("GET"))
+            .and(path("/api/v1/app/2890/release/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"name": "8.3.0", "filename": "app_830.tgz", "sha256": "0000"},
+                {"name": "8.2.0", "filename": "app_820.tgz", "sha256": sha256}
+            ])))
+            .mount(server)
+            .await;
+    }
+
+    #[test]
+    fn select_release_defaults_to_latest_and_honors_pin() {
+        let releases = vec![release("8.3.0", None), release("8.2.0", None)];
+
+        assert_eq!(select_release(1, &releases, None).unwrap().name, "8.3.0");
+        assert_eq!(
+            select_release(1, &releases, Some("8.2.0")).unwrap().name,
+            "8.2.0"
+        );
+
+        let err = select_release(1, &releases, Some("7.0.0")).unwrap_err();
+        assert!(err.to_string().contains("available: 8.3.0, 8.2.0"));
+        assert!(matches!(
+            select_release(1, &[], None),
+            Err(SplunkbaseError::NoReleases { app_id: 1 })
+        ));
+    }
+
+    #[test]
+    fn verify_checksum_rejects_mismatch() {
+        let digest = hex::encode(Sha256::digest(PACKAGE));
+
+        assert!(verify_checksum("app.tgz", PACKAGE, &digest.to_uppercase()).is_ok());
+        assert!(matches!(
+            verify_checksum("app.tgz", b"tampered", &digest),
+            Err(SplunkbaseError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_login_token_reads_atom_id() {
+        let body = "<feed><title>Authentication</title><id>abc123</id></feed>";
+        assert_eq!(parse_login_token(body).as_deref(), Some("abc123"));
+        assert_eq!(parse_login_token("<feed/>"), None);
+    }
+
+    #[tokio::test]
+    async fn download_release_logs_in_and_verifies_pinned_version() {
+        let server = MockServer::start().await;
+        mount_releases(&server, &hex::encode(Sha256::digest(PACKAGE))).await;
+        Mock::given(method("POST"))
+            .and(path("/api/account:login/"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string("<feed><id>session</id></feed>"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/app/2890/release/8.2.0/download/"))
+            .and(header("X-Auth-Token", "session"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(PACKAGE))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = SplunkbaseClient::new(config(
+            server.uri(),
+            Some(SplunkbaseCredentials::Login {
+                username: "user".to_string(),
+                password: "pass".to_string().into(),
+            }),
+        ))
+        .unwrap();
+        let package = client.download_release(2890, Some("8.2.0")).await.unwrap();
+
+        assert_eq!(package.version, "8.2.0");
+        assert_eq!(package.file_name, "app_820.tgz");
+        assert_eq!(package.content, PACKAGE);
+    }
+
+    #[tokio::test]
+    async fn download_release_rejects_checksum_mismatch() {
+        let server = MockServer::start().await;
+        mount_releases(&server, "deadbeef").await;
+        Mock::given(method("GET"))
+            .and(path("/app/2890/release/8.2.0/download/"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(PACKAGE))
+            .mount(&server)
+            .await;
+
+        let client = SplunkbaseClient::new(config(
+            server.uri(),
+            Some(SplunkbaseCredentials::Token("token".to_string().into())),
+        ))
+        .unwrap();
+        let err = client
+            .download_release(2890, Some("8.2.0"))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<SplunkbaseError>(),
+            Some(SplunkbaseError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn download_release_requires_credentials() {
+        let server = MockServer::start().await;
+        mount_releases(&server, "deadbeef").await;
+
+        let client = SplunkbaseClient::new(config(server.uri(), None)).unwrap();
+        let err = client.download_release(2890, None).await.unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<SplunkbaseError>(),
+            Some(SplunkbaseError::MissingCredentials)
+        ));
+    }
+
+    #[tokio::test]
+    async fn search_apps_parses_results() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/app/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "results": [
+                    {"uid": 2890, "appid": "Splunk_TA_windows", "title": "Splunk Add-on for Microsoft Windows"}
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = SplunkbaseClient::new(config(server.uri(), None)).unwrap();
+        let apps = client.search_apps("windows", 10).await.unwrap();
+
+        assert_eq!(apps.len(), 1);
+        assert_eq!(apps[0].uid, 2890);
+        assert_eq!(apps[0].appid, "Splunk_TA_windows");
+    }
+}
@@ -0,0 +1,285 @@
+//! Shared per-user activity report built from the audit index.
+//!
+//! Purpose:
+//! - Summarize what one user did over a recent window: logins, searches run,
+//!   objects modified, and failed authentication attempts, per day.
+//!
+//! Responsibilities:
+//! - Turn a `--since` window (`7d`, `-24h@h`) into a validated earliest time.
+//! - Fetch the user's audit events through `SplunkClient::list_audit_events`.
+//! - Classify each event and count the categories per calendar day.
+//!
+//! Does NOT handle:
+//! - Output formatting (frontend concern).
+//! - Audit events of other users or audit archiving (see the audit commands).
+//!
+//! Invariants:
+//! - Days are listed oldest first and only days with events appear.
+//! - Events whose timestamp has no date are grouped under `unknown`.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::SplunkClient;
+use crate::models::{AuditAction, AuditEvent, AuditResult, ListAuditEventsParams};
+use crate::relative_time::parse_time_modifier;
+use crate::workflows::{CancellationProbe, ensure_not_cancelled};
+
+/// Window used when none is requested.
+pub const DEFAULT_SINCE: &str = "7d";
+
+/// Most audit events fetched for one report.
+pub const DEFAULT_MAX_EVENTS: usize = 10_000;
+
+/// Input for a user activity report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserActivityRequest {
+    pub user: String,
+    /// Window start: a bare duration such as `7d` or a relative time such as `-1w@w`.
+    pub since: String,
+    /// Most audit events to fetch; the report is marked truncated when reached.
+    pub max_events: usize,
+}
+
+impl UserActivityRequest {
+    /// Request for `user` over the default window.
+    pub fn new(user: impl Into<String>) -> Self {
+        Self {
+            user: user.into(),
+            since: DEFAULT_SINCE.to_string(),
+            max_events: DEFAULT_MAX_EVENTS,
+        }
+    }
+
+    /// Earliest time modifier for the audit search (`7d` becomes `-7d`).
+    pub fn earliest(&self) -> Result<String> {
+        let since = self.since.trim();
+        if since.is_empty() {
+            bail!("--since must not be empty");
+        }
+        let relative = if since.starts_with(|c: char| c.is_ascii_digit()) {
+            format!("-{since}")
+        } else {
+            since.to_string()
+        };
+        parse_time_modifier(&relative).with_context(|| format!("Invalid --since '{since}'"))?;
+        Ok(relative)
+    }
+}
+
+/// What an audit event counts as in the report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityCategory {
+    Login,
+    Search,
+    ObjectModified,
+    FailedAuth,
+    Other,
+}
+
+impl ActivityCategory {
+    /// Classify one audit event.
+    pub fn of(event: &AuditEvent) -> Self {
+        match (&event.action, event.result) {
+            (AuditAction::Login, AuditResult::Failure) => Self::FailedAuth,
+            (AuditAction::Login, _) => Self::Login,
+            (AuditAction::Search, _) => Self::Search,
+            (
+                AuditAction::EditUser
+                | AuditAction::CreateUser
+                | AuditAction::DeleteUser
+                | AuditAction::EditRole,
+                _,
+            ) => Self::ObjectModified,
+            (AuditAction::Logout | AuditAction::Unknown, _) => Self::Other,
+        }
+    }
+}
+
+/// Activity counts for one day (or the whole window).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserActivityDay {
+    /// `YYYY-MM-DD`, `unknown`, or `total`.
+    pub day: String,
+    pub logins: usize,
+    pub searches: usize,
+    pub objects_modified: usize,
+    pub failed_auth: usize,
+    pub other: usize,
+}
+
+impl UserActivityDay {
+    fn record(&mut self, category: ActivityCategory) {
+        match category {
+            ActivityCategory::Login => self.logins += 1,
+            ActivityCategory::Search => self.searches += 1,
+            ActivityCategory::ObjectModified => self.objects_modified += 1,
+            ActivityCategory::FailedAuth => self.failed_auth += 1,
+            ActivityCategory::Other => self.other += 1,
+        }
+    }
+
+    /// Number of events counted.
+    pub fn total(&self) -> usize {
+        self.logins + self.searches + self.objects_modified + self.failed_auth + self.other
+    }
+}
+
+/// Result of a user activity report.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserActivityReport {
+    pub user: String,
+    /// Earliest time modifier the audit search used.
+    pub earliest: String,
+    /// Per-day counts, oldest first.
+    pub days: Vec<UserActivityDay>,
+    /// True when `max_events` was reached, so older activity may be missing.
+    pub truncated: bool,
+}
+
+impl UserActivityReport {
+    /// Counts summed over every day.
+    pub fn totals(&self) -> UserActivityDay {
+        self.days.iter().fold(
+            UserActivityDay {
+                day: "total".to_string(),
+                ..Default::default()
+            },
+            |mut totals, day| {
+                totals.logins += day.logins;
+                totals.searches += day.searches;
+                totals.objects_modified += day.objects_modified;
+                totals.failed_auth += day.failed_auth;
+                totals.other += day.other;
+                totals
+            },
+        )
+    }
+}
+
+/// Run the shared user activity report.
+pub async fn user_activity(
+    client: &SplunkClient,
+    request: &UserActivityRequest,
+    cancel: Option<&dyn CancellationProbe>,
+) -> Result<UserActivityReport> {
+    let earliest = request.earliest()?;
+    ensure_not_cancelled(cancel)?;
+
+    let params = ListAuditEventsParams {
+        earliest: Some(earliest.clone()),
+        latest: Some("now".to_string()),
+        count: Some(request.max_events),
+        offset: None,
+        user: Some(request.user.clone()),
+        action: None,
+    };
+    let events = client.list_audit_events(&params).await?;
+    ensure_not_cancelled(cancel)?;
+
+    let mut report = summarize(&request.user, &events);
+    report.earliest = earliest;
+    report.truncated = events.len() >= request.max_events;
+    Ok(report)
+}
+
+/// Group `events` per day and count each activity category.
+pub fn summarize(user: &str, events: &[AuditEvent]) -> UserActivityReport {
+    let mut days: BTreeMap<String, UserActivityDay> = BTreeMap::new();
+    for event in events {
+        let day = event
+            .time
+            .get(..10)
+            .filter(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok())
+            .unwrap_or("unknown");
+        days.entry(day.to_string())
+            .or_insert_with(|| UserActivityDay {
+                day: day.to_string(),
+                ..Default::default()
+            })
+            .record(ActivityCategory::of(event));
+    }
+
+    UserActivityReport {
+        user: user.to_string(),
+        days: days.into_values().collect(),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(time: &str, action: AuditAction, result: AuditResult) -> AuditEvent {
+        AuditEvent {
+            time: time.to_string(),
+            user: "alice".to_string(),
+            action,
+            target: String::new(),
+            result,
+            client_ip: String::new(),
+            details: String::new(),
+            raw: String::new(),
+        }
+    }
+
+    #[test]
+    fn earliest_accepts_bare_durations_and_relative_times() {
+        let mut request = UserActivityRequest::new("alice");
+        assert_eq!(request.earliest().unwrap(), "-7d");
+
+        request.since = "-1w@w".to_string();
+        assert_eq!(request.earliest().unwrap(), "-1w@w");
+
+        request.since = "7 days".to_string();
+        assert!(request.earliest().is_err());
+    }
+
+    #[test]
+    fn summarize_counts_categories_per_day() {
+        let events = vec![
+            event(
+                "2025-01-21T08:00:00.000+00:00",
+                AuditAction::Search,
+                AuditResult::Success,
+            ),
+            event(
+                "2025-01-20T10:30:00.000Z",
+                AuditAction::Login,
+                AuditResult::Success,
+            ),
+            event(
+                "2025-01-20T10:29:00.000Z",
+                AuditAction::Login,
+                AuditResult::Failure,
+            ),
+            event(
+                "2025-01-20T11:00:00.000Z",
+                AuditAction::EditRole,
+                AuditResult::Success,
+            ),
+            event("", AuditAction::Logout, AuditResult::Unknown),
+        ];
+
+        let report = summarize("alice", &events);
+        let days: Vec<&str> = report.days.iter().map(|d| d.day.as_str()).collect();
+        assert_eq!(days, vec!["2025-01-20", "2025-01-21", "unknown"]);
+
+        let first = &report.days[0];
+        assert_eq!(
+            (first.logins, first.failed_auth, first.objects_modified),
+            (1, 1, 1)
+        );
+        assert_eq!(report.days[1].searches, 1);
+        assert_eq!(report.days[2].other, 1);
+
+        let totals = report.totals();
+        assert_eq!(totals.day, "total");
+        assert_eq!(totals.total(), 5);
+    }
+}
@@ -0,0 +1,282 @@
+//! Knowledge object permission (ACL) endpoint tests.
+//!
+//! This module tests reading and changing knowledge object ACLs:
+//! - Reading an object's owner, sharing level and read/write roles
+//! - Posting merged ACL updates to the object's owner/app namespace
+//! - Mapping missing objects to `NotFound`
+//! - Listing ACLs across owners and flagging objects owned by missing users
+//!
+//! # What this does NOT handle
+//! - Bulk ownership reassignment (see saved_searches_tests.rs)
+
+mod common;
+
+use common::*;
+use secrecy::SecretString;
+use splunk_client::models::{AclUpdateParams, KnowledgeObjectKind};
+use splunk_client::workflows::orphaned_objects::{OrphanScanRequest, find_orphaned_objects};
+use splunk_client::{AuthStrategy, ClientError, SplunkClient};
+use wiremock::matchers::{body_string_contains, method, path, query_param};
+
+fn test_client(uri: String) -> SplunkClient {
+    SplunkClient::builder()
+        .base_url(uri)
+        .auth_strategy(AuthStrategy::ApiToken {
+            token: SecretString::new("test-token".to_string().into()),
+        })
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_get_object_acl() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("acl/get_saved_search_acl.json");
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/services/saved/searches/Errors%20in%20the%20last%2024%20hours",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let acl = endpoints::get_object_acl(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        KnowledgeObjectKind::SavedSearch,
+        "Errors in the last 24 hours",
+        None,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(acl.app, "search");
+    assert_eq!(acl.owner, "admin");
+    assert_eq!(acl.sharing, "app");
+    assert_eq!(acl.read, vec!["*"]);
+    assert_eq!(acl.write, vec!["admin", "power"]);
+    assert!(acl.can_change_perms);
+}
+
+#[tokio::test]
+async fn test_get_object_acl_not_found() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/admin/macros/missing"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(mock_server.uri());
+    let result = client
+        .get_object_acl(KnowledgeObjectKind::Macro, "missing")
+        .await;
+
+    assert!(matches!(result, Err(ClientError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_set_object_acl_merges_and_posts_to_owner_namespace() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("acl/get_saved_search_acl.json");
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/services/saved/searches/Errors%20in%20the%20last%2024%20hours",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/servicesNS/admin/search/saved/searches/Errors%20in%20the%20last%2024%20hours/acl",
+        ))
+        .and(body_string_contains("sharing=global"))
+        .and(body_string_contains("owner=admin"))
+        .and(body_string_contains("perms.read=user%2Cpower"))
+        .and(body_string_contains("perms.write=admin%2Cpower"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(mock_server.uri());
+    let params = AclUpdateParams {
+        sharing: Some("global".to_string()),
+        read: Some(vec!["user".to_string(), "power".to_string()]),
+        ..AclUpdateParams::default()
+    };
+    let acl = client
+        .set_object_acl(
+            KnowledgeObjectKind::SavedSearch,
+            "Errors in the last 24 hours",
+            &params,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(acl.sharing, "global");
+    assert_eq!(acl.read, vec!["user", "power"]);
+    assert_eq!(acl.write, vec!["admin", "power"]);
+}
+
+#[tokio::test]
+async fn test_set_object_acl_private_sharing_omits_roles() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("acl/get_saved_search_acl.json");
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/services/data/ui/views/Errors%20in%20the%20last%2024%20hours",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/servicesNS/admin/search/data/ui/views/Errors%20in%20the%20last%2024%20hours/acl",
+        ))
+        .and(body_string_contains("sharing=user"))
+        .and(body_string_contains("owner=jane"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(mock_server.uri());
+    let params = AclUpdateParams {
+        sharing: Some("user".to_string()),
+        owner: Some("jane".to_string()),
+        ..AclUpdateParams::default()
+    };
+    client
+        .set_object_acl(
+            KnowledgeObjectKind::Dashboard,
+            "Errors in the last 24 hours",
+            &params,
+        )
+        .await
+        .unwrap();
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let post = requests
+        .iter()
+        .find(|r| r.method.as_str() == "POST")
+        .expect("ACL update posted");
+    assert!(!String::from_utf8_lossy(&post.body).contains("perms."));
+}
+
+#[tokio::test]
+async fn test_list_object_acls_spans_every_owner() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("acl/list_macro_acls.json");
+
+    Mock::given(method("GET"))
+        .and(path("/servicesNS/-/-/admin/macros"))
+        .and(query_param("count", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let acls = endpoints::list_object_acls(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        KnowledgeObjectKind::Macro,
+        None,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    let owners: Vec<_> = acls.iter().map(|acl| acl.owner.as_str()).collect();
+    assert_eq!(owners, vec!["departed_user", "nobody", "user1"]);
+    assert_eq!(acls[0].sharing, "user");
+    assert!(acls[0].read.is_empty());
+}
+
+#[tokio::test]
+async fn test_find_orphaned_objects_flags_missing_owners() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/authentication/users"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("users/list_users.json")),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/servicesNS/-/search/admin/macros"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("acl/list_macro_acls.json")),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(mock_server.uri());
+    let request = OrphanScanRequest {
+        kinds: vec![KnowledgeObjectKind::Macro],
+        app: Some("search".to_string()),
+        fallback_owner: None,
+    };
+    let report = find_orphaned_objects(&client, &request, None)
+        .await
+        .unwrap();
+
+    assert_eq!(report.scanned, 3);
+    assert_eq!(report.users, 2);
+    assert_eq!(report.orphans.len(), 1);
+    let orphan = &report.orphans[0];
+    assert_eq!(orphan.name, "web_errors");
+    assert_eq!(orphan.owner, "departed_user");
+    assert_eq!(orphan.suggested_owner.as_deref(), Some("user1"));
+}
+
+#[tokio::test]
+async fn test_find_orphaned_objects_rejects_unknown_fallback_owner() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/authentication/users"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("users/list_users.json")),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/servicesNS/-/-/admin/macros"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("acl/list_macro_acls.json")),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(mock_server.uri());
+    let request = OrphanScanRequest {
+        kinds: vec![KnowledgeObjectKind::Macro],
+        app: None,
+        fallback_owner: Some("departed_user".to_string()),
+    };
+    let error = find_orphaned_objects(&client, &request, None)
+        .await
+        .unwrap_err();
+
+    assert!(error.to_string().contains("not an existing user"));
+}
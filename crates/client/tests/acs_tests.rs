@@ -0,0 +1,229 @@
+//! Splunk Cloud and Admin Config Service (ACS) tests.
+//!
+//! This module tests:
+//! - ACS index and HEC token requests against `{acs_url}/{stack}/adminconfig/v2`
+//! - Cloud detection from server info and the on-prem-only operation guards
+//! - Rejection of ACS calls without an API token or stack
+//!
+//! # Invariants
+//! - Guarded operations fail before any request reaches the server
+
+mod common;
+
+use common::*;
+use secrecy::SecretString;
+use splunk_client::models::{AcsHecTokenCreateParams, AcsIndexCreateParams, CreateIndexParams};
+use splunk_client::{AuthStrategy, ClientError, SplunkClient};
+use wiremock::matchers::{body_json, header, method, path, query_param};
+
+fn acs_client(base_url: String, acs_url: String) -> SplunkClient {
+    SplunkClient::builder()
+        .base_url(base_url)
+        .auth_strategy(AuthStrategy::ApiToken {
+            token: SecretString::new("test-token".to_string().into()),
+        })
+        .cloud(true)
+        .acs_stack("acme".to_string())
+        .acs_url(acs_url)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_acs_list_indexes() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/acme/adminconfig/v2/indexes"))
+        .and(query_param("count", "50"))
+        .and(header("Authorization", "Bearer test-token"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("acs/list_indexes.json")),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = acs_client(mock_server.uri(), mock_server.uri());
+    let indexes = client.acs_list_indexes(Some(50), None).await.unwrap();
+
+    assert_eq!(indexes.len(), 2);
+    assert_eq!(indexes[0].name, "main");
+    assert_eq!(indexes[0].total_event_count, Some(1_523_400));
+    assert_eq!(indexes[1].datatype.as_deref(), Some("metric"));
+    assert_eq!(indexes[1].splunk_archival_retention_days, Some(365));
+}
+
+#[tokio::test]
+async fn test_acs_create_and_delete_index() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/acme/adminconfig/v2/indexes"))
+        .and(body_json(serde_json::json!({
+            "name": "web",
+            "searchableDays": 30
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(serde_json::json!({
+            "name": "web",
+            "datatype": "event",
+            "searchableDays": 30,
+            "maxDataSizeMB": 0
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/acme/adminconfig/v2/indexes/web"))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = acs_client(mock_server.uri(), mock_server.uri());
+    let index = client
+        .acs_create_index(&AcsIndexCreateParams {
+            name: "web".to_string(),
+            searchable_days: Some(30),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(index.searchable_days, Some(30));
+
+    client.acs_delete_index("web").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_acs_hec_tokens() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/acme/adminconfig/v2/inputs/http-event-collectors"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("acs/list_hec_tokens.json")),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/acme/adminconfig/v2/inputs/http-event-collectors"))
+        .and(body_json(serde_json::json!({
+            "name": "app-hec",
+            "defaultIndex": "main",
+            "disabled": false,
+            "useAck": false
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(serde_json::json!({
+            "http-event-collector": {
+                "spec": { "name": "app-hec", "defaultIndex": "main" },
+                "token": "new-token-value"
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/acme/adminconfig/v2/inputs/http-event-collectors/app-hec",
+        ))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = acs_client(mock_server.uri(), mock_server.uri());
+    let tokens = client.acs_list_hec_tokens().await.unwrap();
+    assert_eq!(tokens.len(), 2);
+    assert_eq!(tokens[0].allowed_indexes, vec!["main", "web"]);
+    assert_eq!(tokens[1].token, None);
+    assert!(tokens[1].disabled);
+
+    let created = client
+        .acs_create_hec_token(&AcsHecTokenCreateParams {
+            name: "app-hec".to_string(),
+            default_index: Some("main".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(created.token.as_deref(), Some("new-token-value"));
+
+    client.acs_delete_hec_token("app-hec").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_acs_requires_api_token_and_stack() {
+    let client = SplunkClient::builder()
+        .base_url("https://10.0.0.5:8089".to_string())
+        .auth_strategy(AuthStrategy::SessionToken {
+            username: "admin".to_string(),
+            password: SecretString::new("changeme".to_string().into()),
+        })
+        .acs_stack("acme".to_string())
+        .build()
+        .unwrap();
+    let err = client.acs_list_hec_tokens().await.unwrap_err();
+    assert!(matches!(&err, ClientError::ValidationError(msg) if msg.contains("API token")));
+
+    let client = SplunkClient::builder()
+        .base_url("https://10.0.0.5:8089".to_string())
+        .auth_strategy(AuthStrategy::ApiToken {
+            token: SecretString::new("test-token".to_string().into()),
+        })
+        .build()
+        .unwrap();
+    let err = client.acs_list_indexes(None, None).await.unwrap_err();
+    assert!(matches!(&err, ClientError::ValidationError(msg) if msg.contains("SPLUNK_ACS_STACK")));
+}
+
+#[tokio::test]
+async fn test_cloud_detected_from_server_info_blocks_on_prem_operations() {
+    let mock_server = MockServer::start().await;
+
+    let mut info = load_fixture("server/get_server_info.json");
+    info["entry"][0]["content"]["instance_type"] = serde_json::json!("cloud");
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&info))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/services/data/indexes"))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = SplunkClient::builder()
+        .base_url(mock_server.uri())
+        .auth_strategy(AuthStrategy::ApiToken {
+            token: SecretString::new("test-token".to_string().into()),
+        })
+        .build()
+        .unwrap();
+    assert!(!client.is_cloud());
+
+    let server_info = client.get_server_info().await.unwrap();
+    assert!(server_info.is_cloud());
+    assert!(client.is_cloud());
+
+    let err = client
+        .create_index(&CreateIndexParams {
+            name: "web".to_string(),
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        ClientError::UnsupportedOnCloud {
+            operation: "create_index",
+            ..
+        }
+    ));
+    assert!(!err.remediation_hints().is_empty());
+    assert!(matches!(
+        client.restart_server().await.unwrap_err(),
+        ClientError::UnsupportedOnCloud { .. }
+    ));
+}
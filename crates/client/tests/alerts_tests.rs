@@ -3,7 +3,7 @@
 mod common;
 
 use common::*;
-use wiremock::matchers::{method, path, query_param};
+use wiremock::matchers::{body_string_contains, method, path, query_param};
 
 #[tokio::test]
 async fn test_list_fired_alerts() {
@@ -174,3 +174,164 @@ async fn test_list_fired_alerts_empty_response() {
     let alerts = result.unwrap();
     assert!(alerts.is_empty());
 }
+
+#[tokio::test]
+async fn test_list_alert_actions() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("alerts/list_alert_actions.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/alerts/alert_actions"))
+        .and(query_param("output_mode", "json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let actions = endpoints::list_alert_actions(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        None,
+        None,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(actions.len(), 3);
+    assert_eq!(actions[0].name, "email");
+    assert!(!actions[0].is_custom);
+    assert!(actions[1].is_custom);
+    assert_eq!(actions[1].payload_format.as_deref(), Some("json"));
+    assert!(actions[2].disabled);
+}
+
+#[tokio::test]
+async fn test_get_saved_search_alert_actions() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("alerts/get_saved_search_actions.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/saved/searches/Errors%20Last%20Hour"))
+        .and(query_param("output_mode", "json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let actions = endpoints::get_saved_search_alert_actions(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "Errors Last Hour",
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(actions.saved_search, "Errors Last Hour");
+    let names: Vec<&str> = actions.actions.iter().map(|a| a.name.as_str()).collect();
+    assert_eq!(names, ["email", "webhook"]);
+    assert_eq!(
+        actions.actions[0].params.get("to").map(String::as_str),
+        Some("oncall@example.com")
+    );
+    assert!(!actions.actions[0].params.contains_key("cc"));
+    assert_eq!(
+        actions.actions[1]
+            .params
+            .get("param.url")
+            .map(String::as_str),
+        Some("https://hooks.example.com/splunk")
+    );
+}
+
+#[tokio::test]
+async fn test_get_saved_search_alert_actions_not_found() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/saved/searches/Missing"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result = endpoints::get_saved_search_alert_actions(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "Missing",
+        0,
+        None,
+        None,
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(splunk_client::ClientError::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_dispatch_alert_action_test_reports_job_errors() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains("sendalert"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": "alert-test-sid" } }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/alert-test-sid"))
+        .and(query_param("output_mode", "json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "content": {
+                    "sid": "alert-test-sid",
+                    "isDone": true,
+                    "isFailed": false,
+                    "doneProgress": 1.0,
+                    "messages": [{
+                        "type": "ERROR",
+                        "text": "Error in 'sendalert' command: Alert script returned error code 2."
+                    }]
+                }
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let dispatch = endpoints::dispatch_alert_action_test(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "| makeresults | sendalert webhook",
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(dispatch.sid, "alert-test-sid");
+    assert!(dispatch.failed);
+    assert_eq!(
+        dispatch.messages,
+        ["ERROR: Error in 'sendalert' command: Alert script returned error code 2."]
+    );
+}
@@ -12,6 +12,7 @@
 //! - get_app returns detailed info for a single app
 //! - update_app (enable/disable) returns success on valid requests
 //! - install_app returns the installed app details
+//! - install_app_package uploads in-memory packages under the given file name
 //! - remove_app returns success on valid requests
 //!
 //! # What this does NOT handle
@@ -322,6 +323,43 @@ async fn test_install_app_file_not_found() {
     assert!(err.to_string().contains("Failed to read app package file"));
 }
 
+#[tokio::test]
+async fn test_install_app_package_uploads_bytes() {
+    use wiremock::matchers::body_string_contains;
+
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("apps/install_app.json");
+
+    Mock::given(method("POST"))
+        .and(path("/services/apps/appinstall"))
+        .and(query_param("output_mode", "json"))
+        .and(body_string_contains(
+            "filename=\"Splunk_TA_windows-820.tgz\"",
+        ))
+        .and(body_string_contains("downloaded package"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let app = endpoints::install_app_package(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "Splunk_TA_windows-820.tgz",
+        b"downloaded package".to_vec(),
+        3,
+        None,
+        None,
+    )
+    .await
+    .expect("install_app_package should succeed");
+
+    assert_eq!(app.name, "installed_app");
+}
+
 #[tokio::test]
 async fn test_remove_app() {
     let mock_server = MockServer::start().await;
@@ -4,6 +4,7 @@
 //! - Successful login with session key extraction
 //! - Invalid credential handling
 //! - Login response format validation
+//! - Proactive session renewal timing
 //!
 //! # Invariants
 //! - Login response must have sessionKey at the top level, not nested under entry[0].content
@@ -16,7 +17,9 @@
 mod common;
 
 use common::*;
-use splunk_client::ClientError;
+use secrecy::SecretString;
+use splunk_client::{AuthStrategy, ClientError, SplunkClient};
+use std::time::Duration;
 use wiremock::matchers::{method, path, query_param};
 
 #[tokio::test]
@@ -139,3 +142,38 @@ async fn test_login_response_format_regression() {
         "Login response must NOT have sessionKey nested under entry[0][content]"
     );
 }
+
+#[tokio::test]
+async fn test_renew_session_resets_renewal_timer() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("auth/login_success.json");
+    Mock::given(method("POST"))
+        .and(path("/services/auth/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = SplunkClient::builder()
+        .base_url(mock_server.uri())
+        .auth_strategy(AuthStrategy::SessionToken {
+            username: "admin".to_string(),
+            password: SecretString::new("testpassword".to_string().into()),
+        })
+        .session_ttl_seconds(600)
+        .skip_verify(true)
+        .build()
+        .unwrap();
+
+    // Before the first login the keep-alive should renew right away
+    assert_eq!(
+        client.time_until_session_renewal().await,
+        Some(Duration::ZERO)
+    );
+
+    let lifetime = client.renew_session().await.unwrap();
+    assert_eq!(lifetime, Duration::from_secs(600));
+    assert!(client.session_remaining().await.unwrap() > Duration::from_secs(590));
+    assert!(client.time_until_session_renewal().await.unwrap() > Duration::ZERO);
+}
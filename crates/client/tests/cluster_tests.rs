@@ -2,6 +2,11 @@
 //!
 //! This module tests the Splunk cluster API:
 //! - Getting cluster configuration and info
+//! - Listing pending bucket fix-ups and excess bucket copies
+//! - Per-index copy trackers for replication/search factor compliance
+//! - Removing excess bucket copies
+//! - Starting and stopping a data rebalance
+//! - Listing search heads registered with the manager
 //!
 //! # Invariants
 //! - Cluster info includes mode (master, peer, search_head), replication factor, and search factor
@@ -15,8 +20,10 @@
 mod common;
 
 use common::*;
-use splunk_client::models::ClusterMode;
-use wiremock::matchers::{method, path};
+use splunk_client::models::{
+    ClusterFixupLevel, ClusterIndexCompliance, ClusterMode, DataRebalanceAction,
+};
+use wiremock::matchers::{body_string_contains, method, path, query_param};
 
 #[tokio::test]
 async fn test_get_cluster_info() {
@@ -42,3 +49,269 @@ async fn test_get_cluster_info() {
     assert_eq!(info.replication_factor, Some(3));
     assert_eq!(info.search_factor, Some(2));
 }
+
+#[tokio::test]
+async fn test_get_cluster_fixups() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("cluster/get_cluster_fixups.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/cluster/master/fixup"))
+        .and(query_param("level", "replication_factor"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let fixups = endpoints::get_cluster_fixups(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        ClusterFixupLevel::ReplicationFactor,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(fixups.len(), 2);
+    assert_eq!(
+        fixups[0].bucket_id,
+        "main~42~5A6B7C8D-1111-2222-3333-444455556666"
+    );
+    assert_eq!(fixups[0].index, "main");
+    assert_eq!(fixups[0].level, ClusterFixupLevel::ReplicationFactor);
+    assert_eq!(
+        fixups[0].reason.as_deref(),
+        Some("Cannot replicate as bucket hasn't rolled yet.")
+    );
+    assert_eq!(fixups[0].timestamp, Some(1718000600));
+    // Falls back to the initial reason when no latest reason is reported
+    assert_eq!(fixups[1].reason.as_deref(), Some("peer went down"));
+}
+
+#[tokio::test]
+async fn test_get_cluster_excess_buckets_filters_index() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("cluster/get_cluster_indexes.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/cluster/master/indexes"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let all = endpoints::get_cluster_excess_buckets(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        None,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(all.len(), 2);
+    assert_eq!(all[0].name, "main");
+    assert_eq!(all[0].total_excess_bucket_copies, 3);
+    assert!(all[0].has_excess());
+    assert!(!all[1].has_excess());
+
+    let main_only = endpoints::get_cluster_excess_buckets(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        Some("main"),
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(main_only.len(), 1);
+    assert_eq!(main_only[0].buckets_with_excess_copies, 2);
+}
+
+#[tokio::test]
+async fn test_get_cluster_index_copies_compliance() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("cluster/get_cluster_indexes.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/cluster/master/indexes"))
+        .and(query_param("count", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let copies = endpoints::get_cluster_index_copies(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(copies.len(), 2);
+    assert_eq!(copies[0].name, "main");
+    assert_eq!(copies[0].num_buckets, 10);
+    assert!(copies[0].is_searchable);
+    assert_eq!(copies[0].replicated_copies_tracker.len(), 3);
+
+    let main = ClusterIndexCompliance::new(&copies[0], 3, 2);
+    assert_eq!(main.replicated_copies, 2);
+    assert_eq!(main.buckets_below_rf, 2);
+    assert!(main.meets_search_factor());
+    assert!(!main.compliant);
+
+    let web = ClusterIndexCompliance::new(&copies[1], 3, 2);
+    assert_eq!(web.replicated_copies, 3);
+    assert_eq!(web.searchable_copies, 2);
+    assert!(web.compliant);
+}
+
+#[tokio::test]
+async fn test_remove_excess_buckets_for_index() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/cluster/master/control/control/prune_index"))
+        .and(body_string_contains("index=main"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result = endpoints::remove_excess_buckets(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        Some("main"),
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert!(result.success);
+    assert_eq!(
+        result.message.as_deref(),
+        Some("Excess bucket removal initiated for main")
+    );
+}
+
+#[tokio::test]
+async fn test_control_data_rebalance_start_for_index() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/services/cluster/master/control/control/rebalance_buckets",
+        ))
+        .and(body_string_contains("action=start"))
+        .and(body_string_contains("index=main"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result = endpoints::control_data_rebalance(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        DataRebalanceAction::Start,
+        Some("main"),
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert!(result.success);
+    assert_eq!(
+        result.message.as_deref(),
+        Some("Data rebalance started for main")
+    );
+}
+
+#[tokio::test]
+async fn test_control_data_rebalance_status_message() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/services/cluster/master/control/control/rebalance_buckets",
+        ))
+        .and(body_string_contains("action=status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{"content": {"message": "Data rebalance is 42% complete"}}]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result = endpoints::control_data_rebalance(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        DataRebalanceAction::Status,
+        None,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        result.message.as_deref(),
+        Some("Data rebalance is 42% complete")
+    );
+}
+
+#[tokio::test]
+async fn test_get_cluster_search_heads() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("cluster/get_cluster_searchheads.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/cluster/master/searchheads"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let search_heads = endpoints::get_cluster_search_heads(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(search_heads.len(), 2);
+    assert_eq!(search_heads[0].splunk_version.as_deref(), Some("9.2.1"));
+    // Falls back to the entry name when the search head has no label
+    assert_eq!(search_heads[1].label.as_deref(), Some("sh-01"));
+    assert_eq!(
+        search_heads[1].host_port_pair.as_deref(),
+        Some("10.0.0.21:8089")
+    );
+}
@@ -3,6 +3,7 @@
 //! This module tests the Splunk configuration files REST API:
 //! - Listing configuration stanzas for a config file
 //! - Getting a specific configuration stanza
+//! - Creating a configuration stanza
 //! - Listing available config files (static list)
 //!
 //! # Invariants
@@ -11,13 +12,13 @@
 //! - Missing stanzas return NotFound error
 //!
 //! # What this does NOT handle
-//! - Config stanza creation/deletion (not tested here)
+//! - Config stanza deletion (not tested here)
 //! - Config file modifications
 
 mod common;
 
 use common::*;
-use wiremock::matchers::{method, path};
+use wiremock::matchers::{body_string_contains, method, path};
 
 #[tokio::test]
 async fn test_list_config_stanzas() {
@@ -39,6 +40,7 @@ async fn test_list_config_stanzas() {
         "props",
         Some(30),
         None,
+        None,
         3,
         None,
         None,
@@ -86,6 +88,7 @@ async fn test_list_config_stanzas_with_pagination() {
         "transforms",
         Some(10),
         Some(5),
+        None,
         3,
         None,
         None,
@@ -119,6 +122,7 @@ async fn test_list_config_stanzas_empty_response() {
         "inputs",
         Some(30),
         None,
+        None,
         3,
         None,
         None,
@@ -151,6 +155,7 @@ async fn test_get_config_stanza() {
         "test-token",
         "props",
         "source::...",
+        None,
         3,
         None,
         None,
@@ -175,6 +180,43 @@ async fn test_get_config_stanza() {
     );
 }
 
+#[tokio::test]
+async fn test_get_config_stanza_in_namespace() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("configs/get_config_stanza.json");
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/servicesNS/nobody/my_app/configs/conf-props/source::...",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let namespace = splunk_client::models::Namespace {
+        owner: Some("nobody".to_string()),
+        ..splunk_client::models::Namespace::app("my_app")
+    };
+    let client = Client::new();
+    let stanza = endpoints::get_config_stanza(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "props",
+        "source::...",
+        Some(&namespace),
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(stanza.name, "source::...");
+}
+
 #[tokio::test]
 async fn test_get_config_stanza_not_found() {
     let mock_server = MockServer::start().await;
@@ -194,6 +236,7 @@ async fn test_get_config_stanza_not_found() {
         "test-token",
         "props",
         "nonexistent",
+        None,
         3,
         None,
         None,
@@ -209,6 +252,38 @@ async fn test_get_config_stanza_not_found() {
     );
 }
 
+#[tokio::test]
+async fn test_create_config_stanza() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/configs/conf-props"))
+        .and(body_string_contains("name=app%3Ajson"))
+        .and(body_string_contains("KV_MODE=json"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({ "entry": [] })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let settings = vec![("KV_MODE".to_string(), "json".to_string())];
+    let result = endpoints::create_config_stanza(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "props",
+        "app:json",
+        &settings,
+        None,
+        3,
+        None,
+        None,
+    )
+    .await;
+
+    assert!(result.is_ok(), "{:?}", result.err());
+}
+
 #[tokio::test]
 async fn test_list_config_files() {
     // list_config_files returns a static list, no mock server needed
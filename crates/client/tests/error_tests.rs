@@ -586,6 +586,20 @@ fn test_invalid_url_is_not_retryable() {
     assert!(!err.is_retryable(), "InvalidUrl should not be retryable");
 }
 
+/// Test that is_unreachable() separates transport failures from server responses.
+///
+/// Offline fallbacks rely on this to decide when cached data should be shown.
+#[test]
+fn test_is_unreachable_classification() {
+    let refused = ClientError::ConnectionRefused("localhost:8089".to_string());
+    assert!(refused.is_unreachable());
+    assert!(ClientError::MaxRetriesExceeded(3, Box::new(refused)).is_unreachable());
+    assert!(ClientError::CircuitBreakerOpen("/services/data/indexes".to_string()).is_unreachable());
+
+    assert!(!ClientError::NotFound("/some/resource".to_string()).is_unreachable());
+    assert!(!ClientError::AuthFailed("bad password".to_string()).is_unreachable());
+}
+
 /// Test that is_retryable() returns false for NotFound errors.
 ///
 /// This test verifies that not found errors are not considered retryable.
@@ -0,0 +1,80 @@
+//! Event type endpoint tests.
+//!
+//! This module tests the Splunk event type API:
+//! - Listing all event types
+//! - Listing event types defined in a single app namespace
+
+mod common;
+
+use common::*;
+use splunk_client::models::Namespace;
+use wiremock::matchers::{method, path, query_param};
+
+#[tokio::test]
+async fn test_list_event_types() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("event_types/list_event_types.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/saved/eventtypes"))
+        .and(query_param("output_mode", "json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let event_types = endpoints::list_event_types(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        None,
+        None,
+        None,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(event_types.len(), 2);
+    assert_eq!(event_types[0].name, "failed_login");
+    assert_eq!(event_types[0].priority, Some(1));
+    assert!(!event_types[0].disabled);
+    assert_eq!(event_types[1].name, "web_error");
+    assert!(event_types[1].disabled);
+    assert_eq!(event_types[1].priority, Some(5));
+}
+
+#[tokio::test]
+async fn test_list_event_types_in_app_namespace() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("event_types/list_event_types.json");
+
+    Mock::given(method("GET"))
+        .and(path("/servicesNS/-/search/saved/eventtypes"))
+        .and(query_param("search", "eai:acl.app=\"search\""))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let event_types = endpoints::list_event_types(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        None,
+        None,
+        Some(&Namespace::app("search")),
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(event_types.len(), 2);
+}
@@ -0,0 +1,52 @@
+//! Fixture schema drift tests.
+//!
+//! Every registered fixture must still parse into its model. Fields a model
+//! does not capture are tolerated here and reported by `validate-fixtures`.
+
+use splunk_client::testing::fixture_validation::{FixtureOutcome, fixtures_dir, validate_fixtures};
+
+#[test]
+fn test_registered_fixtures_parse_into_models() {
+    let reports = validate_fixtures(&fixtures_dir()).expect("fixtures directory is readable");
+    assert!(
+        reports.iter().any(|r| r.model.is_some()),
+        "no fixtures were checked"
+    );
+
+    let failures: Vec<_> = reports
+        .iter()
+        .filter(|r| {
+            matches!(
+                r.outcome,
+                FixtureOutcome::Failed(_) | FixtureOutcome::Unreadable(_)
+            )
+        })
+        .collect();
+    assert!(failures.is_empty(), "fixtures drifted: {:#?}", failures);
+}
+
+#[test]
+fn test_validate_fixtures_reports_failures_in_captured_responses() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("indexes")).unwrap();
+    std::fs::write(
+        dir.path().join("indexes/list_indexes.json"),
+        r#"{"entry": [{"name": "main", "content": {"maxTotalDataSizeMB": 500}}]}"#,
+    )
+    .unwrap();
+    std::fs::write(dir.path().join("indexes/extra.json"), "{}").unwrap();
+
+    let reports = validate_fixtures(dir.path()).unwrap();
+    assert_eq!(reports.len(), 2);
+    assert_eq!(reports[0].path, "indexes/extra.json");
+    assert_eq!(reports[0].outcome, FixtureOutcome::Unchecked);
+
+    let FixtureOutcome::Failed(entries) = &reports[1].outcome else {
+        panic!("expected failure, got {:?}", reports[1].outcome);
+    };
+    assert_eq!(entries[0].entry, "main");
+    assert_eq!(
+        entries[0].issues.missing_fields,
+        vec!["currentDBSizeMB", "totalEventCount"]
+    );
+}
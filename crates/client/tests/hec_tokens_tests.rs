@@ -0,0 +1,422 @@
+//! HEC token management endpoint tests.
+//!
+//! This module tests the Splunk HTTP input REST API:
+//! - Listing HEC tokens
+//! - Creating a token and reading back its generated value
+//! - Updating and deleting tokens by prefixed or bare name
+//! - Enabling and disabling tokens
+//! - Rotating a token by recreating it with the same settings
+//! - Restoring the old token when a rotation cannot recreate it
+//! - NotFound mapping for missing tokens
+
+mod common;
+
+use common::*;
+use secrecy::{ExposeSecret, SecretString};
+use splunk_client::models::{HecTokenCreateParams, HecTokenUpdateParams};
+use splunk_client::{AuthStrategy, ClientError, SplunkClient};
+use wiremock::matchers::{body_string_contains, method, path};
+
+#[tokio::test]
+async fn test_list_hec_tokens() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("hec_tokens/list_hec_tokens.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/data/inputs/http"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let tokens = endpoints::list_hec_tokens(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        None,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(tokens.len(), 2);
+    assert_eq!(tokens[0].name, "http://web_events");
+    assert_eq!(tokens[0].index.as_deref(), Some("web"));
+    assert_eq!(tokens[0].sourcetype.as_deref(), Some("web:json"));
+    assert!(!tokens[0].disabled);
+    assert!(tokens[1].disabled);
+    assert!(tokens[1].use_ack);
+    assert!(tokens[1].indexes.is_empty());
+}
+
+#[tokio::test]
+async fn test_create_hec_token_returns_generated_value() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("hec_tokens/create_hec_token.json");
+
+    Mock::given(method("POST"))
+        .and(path("/services/data/inputs/http"))
+        .and(body_string_contains("name=app_logs"))
+        .and(body_string_contains("index=app"))
+        .and(body_string_contains("indexes=app"))
+        .and(body_string_contains("sourcetype=app%3Ajson"))
+        .and(body_string_contains("useACK=false"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&fixture))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let params = HecTokenCreateParams {
+        name: "app_logs".to_string(),
+        index: Some("app".to_string()),
+        indexes: vec!["app".to_string()],
+        sourcetype: Some("app:json".to_string()),
+        ..Default::default()
+    };
+
+    let client = Client::new();
+    let token = endpoints::create_hec_token(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        &params,
+        None,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(token.name, "http://app_logs");
+    assert_eq!(
+        token.token.as_ref().unwrap().expose_secret(),
+        "3c2b1a09-8f7e-4d6c-b5a4-93827160fedc"
+    );
+}
+
+#[tokio::test]
+async fn test_update_hec_token_strips_prefix() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/data/inputs/http/web_events"))
+        .and(body_string_contains("disabled=true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "entry": [] })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let params = HecTokenUpdateParams {
+        disabled: Some(true),
+        ..Default::default()
+    };
+
+    let client = Client::new();
+    let result = endpoints::update_hec_token(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "http://web_events",
+        &params,
+        None,
+        3,
+        None,
+        None,
+    )
+    .await;
+
+    assert!(result.is_ok(), "{:?}", result.err());
+}
+
+#[tokio::test]
+async fn test_disable_hec_token_posts_action_path() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/data/inputs/http/web_events/disable"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "entry": [] })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result = endpoints::disable_hec_token(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "http://web_events",
+        None,
+        3,
+        None,
+        None,
+    )
+    .await;
+
+    assert!(result.is_ok(), "{:?}", result.err());
+}
+
+#[tokio::test]
+async fn test_enable_hec_token_not_found() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/data/inputs/http/missing/enable"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result = endpoints::enable_hec_token(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "missing",
+        None,
+        0,
+        None,
+        None,
+    )
+    .await;
+
+    assert!(
+        matches!(result, Err(ClientError::NotFound(_))),
+        "Expected NotFound, got {:?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn test_rotate_hec_token_recreates_with_same_settings() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/data/inputs/http/app_logs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "http://app_logs",
+                "content": {
+                    "token": "old-value",
+                    "index": "app",
+                    "indexes": ["app"],
+                    "sourcetype": "app:json",
+                    "disabled": "1",
+                    "useACK": "0"
+                }
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/services/data/inputs/http/app_logs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "entry": [] })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/services/data/inputs/http"))
+        .and(body_string_contains("name=app_logs"))
+        .and(body_string_contains("indexes=app"))
+        .and(body_string_contains("sourcetype=app%3Ajson"))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .set_body_json(load_fixture("hec_tokens/create_hec_token.json")),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    // The old token was disabled, so the new one is disabled too.
+    Mock::given(method("POST"))
+        .and(path("/services/data/inputs/http/app_logs/disable"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "entry": [] })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = SplunkClient::builder()
+        .base_url(mock_server.uri())
+        .auth_strategy(AuthStrategy::ApiToken {
+            token: SecretString::new("test-token".to_string().into()),
+        })
+        .build()
+        .expect("Failed to build SplunkClient");
+
+    let rotated = client.rotate_hec_token("http://app_logs").await.unwrap();
+
+    assert_eq!(
+        rotated.token.as_ref().unwrap().expose_secret(),
+        "3c2b1a09-8f7e-4d6c-b5a4-93827160fedc"
+    );
+    assert!(rotated.disabled);
+}
+
+/// Mount the lookup and delete of an `app_logs` token defined in `web_app`.
+async fn mount_app_token_for_rotation(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/services/data/inputs/http/app_logs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "http://app_logs",
+                "acl": { "app": "web_app", "owner": "nobody", "sharing": "app" },
+                "content": {
+                    "token": "old-value",
+                    "index": "app",
+                    "sourcetype": "app:json",
+                    "source": "web-tier",
+                    "outputgroup": "primary",
+                    "disabled": "0",
+                    "useACK": "0"
+                }
+            }]
+        })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/servicesNS/nobody/web_app/data/inputs/http/app_logs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "entry": [] })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+}
+
+fn rotation_client(mock_server: &MockServer) -> SplunkClient {
+    SplunkClient::builder()
+        .base_url(mock_server.uri())
+        .auth_strategy(AuthStrategy::ApiToken {
+            token: SecretString::new("test-token".to_string().into()),
+        })
+        .build()
+        .expect("Failed to build SplunkClient")
+}
+
+#[tokio::test]
+async fn test_rotate_hec_token_recreates_in_original_app() {
+    let mock_server = MockServer::start().await;
+    mount_app_token_for_rotation(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/servicesNS/nobody/web_app/data/inputs/http"))
+        .and(body_string_contains("source=web-tier"))
+        .and(body_string_contains("outputgroup=primary"))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .set_body_json(load_fixture("hec_tokens/create_hec_token.json")),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let rotated = rotation_client(&mock_server)
+        .rotate_hec_token("app_logs")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        rotated.token.as_ref().unwrap().expose_secret(),
+        "3c2b1a09-8f7e-4d6c-b5a4-93827160fedc"
+    );
+}
+
+#[tokio::test]
+async fn test_rotate_hec_token_restores_old_value_when_recreate_fails() {
+    let mock_server = MockServer::start().await;
+    mount_app_token_for_rotation(&mock_server).await;
+
+    // Mounted first so the restore, which sends the old value, matches it.
+    Mock::given(method("POST"))
+        .and(path("/servicesNS/nobody/web_app/data/inputs/http"))
+        .and(body_string_contains("token=old-value"))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .set_body_json(load_fixture("hec_tokens/create_hec_token.json")),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/servicesNS/nobody/web_app/data/inputs/http"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+            "messages": [{ "type": "ERROR", "text": "Output group 'primary' is not configured" }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let result = rotation_client(&mock_server)
+        .rotate_hec_token("app_logs")
+        .await;
+
+    assert!(
+        matches!(result, Err(ClientError::InvalidRequest(_))),
+        "Expected the recreate error, got {:?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn test_rotate_hec_token_reports_settings_when_restore_fails() {
+    let mock_server = MockServer::start().await;
+    mount_app_token_for_rotation(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/servicesNS/nobody/web_app/data/inputs/http"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+            "messages": [{ "type": "ERROR", "text": "Output group 'primary' is not configured" }]
+        })))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let err = rotation_client(&mock_server)
+        .rotate_hec_token("app_logs")
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, ClientError::TransactionRollbackError { count: 1, .. }),
+        "Expected a rollback error, got {:?}",
+        err
+    );
+    let message = err.to_string();
+    assert!(message.contains("app=web_app, index=app"), "{}", message);
+    assert!(message.contains("source=web-tier"), "{}", message);
+    assert!(!message.contains("old-value"), "{}", message);
+}
+
+#[tokio::test]
+async fn test_delete_hec_token_not_found() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/services/data/inputs/http/missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "messages": [{ "type": "ERROR", "text": "Could not find object id=missing" }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result = endpoints::delete_hec_token(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "missing",
+        None,
+        0,
+        None,
+        None,
+    )
+    .await;
+
+    assert!(
+        matches!(result, Err(ClientError::NotFound(_))),
+        "Expected NotFound, got {:?}",
+        result
+    );
+}
@@ -0,0 +1,163 @@
+//! HTTP trace recorder tests.
+//!
+//! This module tests capturing request/response traces through the client:
+//! - Every attempt (including login and retries) is recorded
+//! - Credentials are redacted from headers and bodies
+//! - Captures round-trip through the on-disk JSON format
+//!
+//! # Invariants
+//! - Session keys, passwords, and Authorization headers never appear in a capture
+//! - Responses remain readable by the caller after being captured
+//!
+//! # What this does NOT handle
+//! - Redaction rules for individual body formats (see unit tests in `http_trace`)
+
+mod common;
+
+use common::*;
+use secrecy::SecretString;
+use splunk_client::http_trace::HttpTraceRecorder;
+use splunk_client::{AuthStrategy, SplunkClient};
+use wiremock::matchers::{method, path};
+
+fn traced_client(base_url: String, recorder: &HttpTraceRecorder) -> SplunkClient {
+    SplunkClient::builder()
+        .base_url(base_url)
+        .auth_strategy(AuthStrategy::SessionToken {
+            username: "admin".to_string(),
+            password: SecretString::new("hunter2-password".to_string().into()),
+        })
+        .max_retries(1)
+        .no_circuit_breaker()
+        .trace_recorder(recorder.clone())
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_trace_records_login_and_request_with_redaction() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/auth/login"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("auth/login_success.json")),
+        )
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/data/indexes"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("indexes/list_indexes.json")),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let recorder = HttpTraceRecorder::new();
+    let client = traced_client(mock_server.uri(), &recorder);
+
+    let indexes = client.list_indexes(Some(10), Some(0)).await.unwrap();
+    assert_eq!(indexes.len(), 3);
+
+    let entries = recorder.entries();
+    assert_eq!(entries.len(), 2);
+
+    let login = &entries[0];
+    assert_eq!(login.method, "POST");
+    assert_eq!(login.endpoint, "/services/auth/login");
+    assert_eq!(login.status, Some(200));
+
+    let list = &entries[1];
+    assert_eq!(list.method, "GET");
+    assert_eq!(list.status, Some(200));
+    assert!(list.url.contains("/services/data/indexes"));
+    assert_eq!(list.request.headers["authorization"], "<redacted>");
+    let response = list.response.as_ref().unwrap();
+    assert!(response.body_size > 0);
+    assert!(response.body.as_deref().unwrap().contains("main"));
+
+    let serialized = serde_json::to_string(&recorder.to_trace_file()).unwrap();
+    assert!(!serialized.contains("hunter2-password"));
+    assert!(!serialized.contains("test-session-key-12345678"));
+}
+
+#[tokio::test]
+async fn test_trace_records_each_retry_attempt() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("server/get_server_info.json")),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let recorder = HttpTraceRecorder::new();
+    let client = SplunkClient::builder()
+        .base_url(mock_server.uri())
+        .auth_strategy(AuthStrategy::ApiToken {
+            token: SecretString::new("api-token-value".to_string().into()),
+        })
+        .max_retries(1)
+        .no_circuit_breaker()
+        .trace_recorder(recorder.clone())
+        .build()
+        .unwrap();
+
+    client.get_server_info().await.unwrap();
+
+    let entries = recorder.entries();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].attempt, 1);
+    assert_eq!(entries[0].status, Some(503));
+    assert_eq!(entries[1].attempt, 2);
+    assert_eq!(entries[1].status, Some(200));
+}
+
+#[tokio::test]
+async fn test_trace_file_round_trip() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("server/get_server_info.json")),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let recorder = HttpTraceRecorder::new();
+    let client = SplunkClient::builder()
+        .base_url(mock_server.uri())
+        .auth_strategy(AuthStrategy::ApiToken {
+            token: SecretString::new("api-token-value".to_string().into()),
+        })
+        .trace_recorder(recorder.clone())
+        .build()
+        .unwrap();
+
+    client.get_server_info().await.unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let trace_path = dir.path().join("trace.json");
+    recorder.write_to_file(&trace_path).unwrap();
+
+    let contents = std::fs::read_to_string(&trace_path).unwrap();
+    assert!(!contents.contains("api-token-value"));
+
+    let loaded = HttpTraceRecorder::load(&trace_path).unwrap();
+    assert_eq!(
+        loaded.version,
+        splunk_client::http_trace::HTTP_TRACE_FORMAT_VERSION
+    );
+    assert_eq!(loaded.entries, recorder.entries());
+}
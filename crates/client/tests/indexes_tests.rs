@@ -5,6 +5,7 @@
 //! - Creating new indexes
 //! - Modifying existing indexes
 //! - Deleting indexes
+//! - Rolling hot buckets
 //!
 //! # Invariants
 //! - Indexes are returned with their names and metadata
@@ -53,6 +54,11 @@ async fn test_list_indexes() {
     assert_eq!(indexes[0].name, "main");
     assert_eq!(indexes[1].name, "_internal");
     assert_eq!(indexes[2].name, "_audit");
+    assert_eq!(
+        indexes[0].min_time.as_deref(),
+        Some("2024-01-01T00:00:00+00:00")
+    );
+    assert!(indexes[1].max_time.is_none());
 }
 
 #[tokio::test]
@@ -173,6 +179,57 @@ async fn test_delete_index() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_roll_hot_buckets() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/data/indexes/test_index/roll-hot-buckets"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result = endpoints::roll_hot_buckets(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "test_index",
+        3,
+        None,
+        None,
+    )
+    .await;
+
+    assert!(result.is_ok(), "roll failed: {:?}", result);
+}
+
+#[tokio::test]
+async fn test_roll_hot_buckets_not_found() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/data/indexes/nonexistent/roll-hot-buckets"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result = endpoints::roll_hot_buckets(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "nonexistent",
+        3,
+        None,
+        None,
+    )
+    .await;
+
+    assert!(matches!(result, Err(ClientError::NotFound(_))));
+}
+
 #[tokio::test]
 async fn test_list_indexes_unauthorized() {
     let mock_server = MockServer::start().await;
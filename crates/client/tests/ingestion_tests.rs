@@ -0,0 +1,146 @@
+//! Indexing latency and thruput endpoint tests.
+//!
+//! This module tests merging the latency and `metrics.log` thruput searches:
+//! - Joining both searches' rows by group name
+//! - Computing rates over the requested window
+//! - SplunkClient interface passing the grouping and window through
+//!
+//! # Invariants
+//! - Both searches share the same `earliest_time`
+//!
+//! # What this does NOT handle
+//! - The SPL that produces the rows (exercised against a live server only)
+
+mod common;
+
+use common::*;
+use splunk_client::IngestionGroupBy;
+use wiremock::matchers::{body_string_contains, method, path, query_param};
+
+async fn mount_search(
+    mock_server: &MockServer,
+    sid: &str,
+    query_marker: &str,
+    earliest: &str,
+    fixture: &str,
+) {
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains(query_marker))
+        .and(body_string_contains(format!("earliest_time={}", earliest)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": sid } }]
+        })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}", sid)))
+        .and(query_param("output_mode", "json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "content": {
+                    "sid": sid,
+                    "isDone": true,
+                    "doneProgress": 1.0,
+                    "runDuration": 0.8,
+                    "scanCount": 90,
+                    "eventCount": 90,
+                    "resultCount": 3,
+                    "diskUsage": 128
+                }
+            }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}/results", sid)))
+        .and(query_param("output_mode", "json"))
+        .and(query_param("count", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(load_fixture(fixture)))
+        .mount(mock_server)
+        .await;
+}
+
+async fn mount_ingestion_searches(mock_server: &MockServer, field: &str, earliest: &str) {
+    mount_search(
+        mock_server,
+        "test-latency-sid",
+        "_indextime-_time",
+        earliest,
+        "ingestion/latency.json",
+    )
+    .await;
+    mount_search(
+        mock_server,
+        "test-thruput-sid",
+        &format!("group%3Dper_{}_thruput", field),
+        earliest,
+        "ingestion/thruput.json",
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_get_ingestion_stats() {
+    let mock_server = MockServer::start().await;
+    mount_ingestion_searches(&mock_server, "sourcetype", "-3600s").await;
+
+    let client = Client::new();
+    let stats = endpoints::get_ingestion_stats(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        IngestionGroupBy::Sourcetype,
+        3600,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(stats.group_by, IngestionGroupBy::Sourcetype);
+    assert_eq!(stats.window_secs, 3600);
+    let names: Vec<&str> = stats.stats.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, vec!["splunkd", "access_combined", "syslog"]);
+
+    let access = &stats.stats[1];
+    assert_eq!(access.events, 7200);
+    assert_eq!(access.avg_latency_secs, Some(2.5));
+    assert_eq!(access.max_latency_secs, Some(31.0));
+    assert!((access.kb_per_sec - 1.0).abs() < 1e-9);
+
+    let splunkd = &stats.stats[0];
+    assert_eq!(splunkd.events, 360);
+    assert_eq!(splunkd.avg_latency_secs, None);
+}
+
+#[tokio::test]
+async fn test_splunk_client_get_ingestion_stats_by_index() {
+    let mock_server = MockServer::start().await;
+    mount_ingestion_searches(&mock_server, "index", "-900s").await;
+
+    use secrecy::SecretString;
+    use splunk_client::{AuthStrategy, SplunkClient};
+
+    let strategy = AuthStrategy::ApiToken {
+        token: SecretString::new("test-token".to_string().into()),
+    };
+
+    let client = SplunkClient::builder()
+        .base_url(mock_server.uri())
+        .auth_strategy(strategy)
+        .build()
+        .unwrap();
+
+    let stats = client
+        .get_ingestion_stats(IngestionGroupBy::Index, 900)
+        .await
+        .unwrap();
+
+    assert_eq!(stats.group_by, IngestionGroupBy::Index);
+    assert_eq!(stats.stats.len(), 3);
+}
@@ -3,6 +3,8 @@
 //! This module tests the Splunk data inputs REST API:
 //! - Listing inputs by type (TCP, UDP, Monitor, Script)
 //! - Enabling/disabling inputs
+//! - Creating monitor/TCP/UDP inputs and deleting inputs
+//! - Summarizing per-source throughput from `metrics.log` and joining it to inputs
 //!
 //! # Invariants
 //! - Inputs are returned with their names, types, and metadata
@@ -10,7 +12,6 @@
 //! - Enable/disable operations return empty success responses
 //!
 //! # What this does NOT handle
-//! - Input creation/deletion (not tested here)
 //! - Input configuration updates beyond enable/disable
 
 mod common;
@@ -18,9 +19,9 @@ mod common;
 use common::*;
 use secrecy::SecretString;
 use splunk_client::error::ClientError;
-use splunk_client::models::InputType;
+use splunk_client::models::{CreateInputParams, InputType, NewInput};
 use splunk_client::{AuthStrategy, SplunkClient};
-use wiremock::matchers::{method, path};
+use wiremock::matchers::{body_string_contains, method, path, query_param};
 
 // Input type segments keep "/" path separators (e.g., "tcp/raw"), while input names
 // are still path-encoded when needed.
@@ -591,3 +592,197 @@ async fn test_list_inputs_malformed_response() {
 
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_get_input_throughput_joins_series_to_inputs() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains("group%3Dper_source_thruput"))
+        .and(body_string_contains("earliest_time=-3600s"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": "throughput-sid" } }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/throughput-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": {
+                "sid": "throughput-sid",
+                "isDone": true,
+                "doneProgress": 1.0,
+                "resultCount": 3
+            } }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/throughput-sid/results"))
+        .and(query_param("count", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                { "series": "/var/log/messages", "kb": "7200.0", "events": "36000" },
+                { "series": "tcp:9997", "kb": "360", "events": "3600" },
+                { "series": "/var/log/broken", "kb": "not-a-number", "events": "1" }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let report = endpoints::get_input_throughput(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        3600,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(report.window_secs, 3600);
+    assert_eq!(report.sources.len(), 2);
+
+    let inputs: Vec<splunk_client::models::Input> = serde_json::from_value(serde_json::json!([
+        { "name": "/var/log/messages", "input_type": "monitor" },
+        { "name": "9997", "input_type": "tcp/raw", "port": "9997" },
+        { "name": "/var/log/audit", "input_type": "monitor" }
+    ]))
+    .unwrap();
+
+    let messages = report.for_input(&inputs[0]).unwrap();
+    assert_eq!(messages.kb_per_sec, 2.0);
+    assert_eq!(messages.events_per_sec, 10.0);
+    assert_eq!(report.for_input(&inputs[1]).unwrap().events, 3600);
+    assert!(report.is_silent(&inputs[2]));
+}
+
+#[tokio::test]
+async fn test_create_monitor_input_posts_typed_params() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/data/inputs/monitor"))
+        .and(body_string_contains("name=%2Fvar%2Flog%2Fapp"))
+        .and(body_string_contains("index=app"))
+        .and(body_string_contains("sourcetype=app%3Alog"))
+        .and(body_string_contains("whitelist=%5C.log%24"))
+        .and(body_string_contains("recursive=false"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "/var/log/app",
+                "content": {
+                    "index": "app",
+                    "sourcetype": "app:log",
+                    "whitelist": "\\.log$",
+                    "recursive": false,
+                    "disabled": false
+                }
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let params = CreateInputParams {
+        input: NewInput::Monitor {
+            path: "/var/log/app".to_string(),
+            whitelist: Some("\\.log$".to_string()),
+            blacklist: None,
+            recursive: Some(false),
+        },
+        index: Some("app".to_string()),
+        sourcetype: Some("app:log".to_string()),
+        host: None,
+    };
+
+    let client = Client::new();
+    let input = endpoints::create_input(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        &params,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(input.name, "/var/log/app");
+    assert_eq!(input.input_type, InputType::Monitor);
+    assert_eq!(input.sourcetype.as_deref(), Some("app:log"));
+}
+
+#[tokio::test]
+async fn test_create_udp_input_uses_port_as_name() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/data/inputs/udp"))
+        .and(body_string_contains("name=514"))
+        .and(body_string_contains("sourcetype=syslog"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "entry": [{"name": "514", "content": {"sourcetype": "syslog"}}]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let params = CreateInputParams {
+        input: NewInput::Udp { port: 514 },
+        index: None,
+        sourcetype: Some("syslog".to_string()),
+        host: None,
+    };
+
+    let client = Client::new();
+    let input = endpoints::create_input(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        &params,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(input.name, "514");
+    assert_eq!(input.input_type, InputType::Udp);
+}
+
+#[tokio::test]
+async fn test_delete_input_encodes_name() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/services/data/inputs/monitor/%2Fvar%2Flog%2Fapp"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(""))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result = endpoints::delete_input(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "monitor",
+        "/var/log/app",
+        3,
+        None,
+        None,
+    )
+    .await;
+
+    assert!(result.is_ok(), "delete failed: {:?}", result);
+}
@@ -0,0 +1,155 @@
+//! Introspection resource usage endpoint tests.
+//!
+//! This module tests the resource usage report:
+//! - Running the host and process searches over `_introspection`
+//! - Ordering hosts and processes busiest CPU first
+//! - Limiting both searches to one host when asked
+//!
+//! # Invariants
+//! - All rows are requested (`count=0`)
+//!
+//! # What this does NOT handle
+//! - The SPL that produces the rows (exercised against a live server only)
+
+mod common;
+
+use common::*;
+use wiremock::matchers::{body_string_contains, method, path, query_param};
+
+/// Mount one search job whose create request contains `marker`, answering with `fixture`.
+async fn mount_usage_search(
+    mock_server: &MockServer,
+    sid: &str,
+    marker: &str,
+    host_filter: Option<&str>,
+    fixture: &str,
+) {
+    let mut create = Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains(marker))
+        .and(body_string_contains("earliest_time=-15m"));
+    if let Some(filter) = host_filter {
+        create = create.and(body_string_contains(filter));
+    }
+    create
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": sid } }]
+        })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}", sid)))
+        .and(query_param("output_mode", "json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "content": {
+                    "sid": sid,
+                    "isDone": true,
+                    "doneProgress": 1.0,
+                    "runDuration": 0.4,
+                    "scanCount": 120,
+                    "eventCount": 120,
+                    "resultCount": 2,
+                    "diskUsage": 128
+                }
+            }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}/results", sid)))
+        .and(query_param("output_mode", "json"))
+        .and(query_param("count", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(load_fixture(fixture)))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_check_resource_usage() {
+    let mock_server = MockServer::start().await;
+    mount_usage_search(
+        &mock_server,
+        "hosts-sid",
+        "component%3DHostwide",
+        None,
+        "introspection/hosts.json",
+    )
+    .await;
+    mount_usage_search(
+        &mock_server,
+        "processes-sid",
+        "component%3DPerProcess",
+        None,
+        "introspection/processes.json",
+    )
+    .await;
+
+    let client = Client::new();
+    let usage = endpoints::check_resource_usage(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        None,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(usage.time_window, "-15m");
+    assert_eq!(usage.host, None);
+    assert_eq!(usage.hosts.len(), 2);
+    assert_eq!(usage.hosts[0].host, "sh01");
+    assert_eq!(usage.hosts[0].cpu_count, Some(8));
+    assert!((usage.hosts[0].mem_used_pct() - 80.0).abs() < 0.01);
+    assert_eq!(usage.hosts[1].iowait_pct, Some(6.5));
+    assert_eq!(usage.processes.len(), 3);
+    assert_eq!(usage.processes[0].pid, "4182");
+    assert_eq!(usage.processes[0].process_type, "search");
+}
+
+#[tokio::test]
+async fn test_splunk_client_check_resource_usage_filters_host() {
+    let mock_server = MockServer::start().await;
+    // `host="sh01"`, form-encoded
+    let filter = "host%3D%22sh01%22";
+    mount_usage_search(
+        &mock_server,
+        "hosts-sid-client",
+        "component%3DHostwide",
+        Some(filter),
+        "introspection/hosts.json",
+    )
+    .await;
+    mount_usage_search(
+        &mock_server,
+        "processes-sid-client",
+        "component%3DPerProcess",
+        Some(filter),
+        "introspection/processes.json",
+    )
+    .await;
+
+    use secrecy::SecretString;
+    use splunk_client::{AuthStrategy, SplunkClient};
+
+    let strategy = AuthStrategy::ApiToken {
+        token: SecretString::new("test-token".to_string().into()),
+    };
+
+    let client = SplunkClient::builder()
+        .base_url(mock_server.uri())
+        .auth_strategy(strategy)
+        .build()
+        .unwrap();
+
+    let usage = client.check_resource_usage(Some("sh01")).await.unwrap();
+
+    assert_eq!(usage.host.as_deref(), Some("sh01"));
+    assert_eq!(usage.hosts.len(), 2);
+}
@@ -3,8 +3,10 @@
 //! This module tests the Splunk search job lifecycle, including:
 //! - Creating search jobs with various response formats
 //! - Getting job status and progress
-//! - Listing all jobs
-//! - Canceling and deleting jobs
+//! - Listing all jobs (optionally with a server-side filter)
+//! - Listing per-job owner and disk usage for role quota reporting
+//! - Canceling, finalizing, and deleting jobs
+//! - Auto-finalizing a job once a result threshold is reached
 //!
 //! # Invariants
 //! - Job creation returns a valid SID (search ID)
@@ -18,7 +20,8 @@
 mod common;
 
 use common::*;
-use wiremock::matchers::{method, path, query_param};
+use splunk_client::{JobFilter, JobStatusFilter};
+use wiremock::matchers::{body_string_contains, method, path, query_param};
 
 #[tokio::test]
 async fn test_create_search_job() {
@@ -157,6 +160,7 @@ async fn test_list_jobs() {
         "test-token",
         Some(10),
         Some(0),
+        &JobFilter::default(),
         3,
         None,
         None,
@@ -179,6 +183,127 @@ async fn test_list_jobs() {
     assert_eq!(jobs[1].label, None);
 }
 
+#[tokio::test]
+async fn test_list_jobs_sends_server_side_filter() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs"))
+        .and(query_param(
+            "search",
+            "eai:acl.owner=admin eai:acl.app=search dispatchState=RUNNING",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(load_fixture("jobs/list_jobs.json")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let filter = JobFilter {
+        owner: Some("admin".to_string()),
+        app: Some("search".to_string()),
+        status: Some(JobStatusFilter::Running),
+    };
+    let client = Client::new();
+    let jobs = endpoints::list_jobs(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        Some(10),
+        None,
+        &filter,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(jobs.len(), 2);
+}
+
+#[tokio::test]
+async fn test_list_job_usage() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs"))
+        .and(query_param("count", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(load_fixture("jobs/list_jobs.json")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let jobs = endpoints::list_job_usage(&client, &mock_server.uri(), "test-token", 3, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(jobs.len(), 2);
+    assert_eq!(jobs[0].owner, "admin");
+    assert!(jobs[0].is_realtime);
+    assert!(!jobs[0].is_done);
+    assert_eq!(jobs[0].disk_usage, 1024);
+    assert_eq!(jobs[1].owner, "alice");
+    assert!(!jobs[1].is_realtime);
+    assert!(jobs[1].is_done);
+}
+
+#[tokio::test]
+async fn test_list_job_ages_applies_filter() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs"))
+        .and(query_param("count", "0"))
+        .and(query_param(
+            "search",
+            "eai:acl.owner=alice dispatchState=DONE",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [
+                {
+                    "author": "alice",
+                    "published": "2024-01-15T08:00:00.000-05:00",
+                    "content": { "sid": "old-sid", "isDone": true, "dispatchState": "DONE" }
+                },
+                { "author": "alice", "content": { "sid": "new-sid", "label": "Report" } }
+            ]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let filter = JobFilter {
+        owner: Some("alice".to_string()),
+        app: None,
+        status: Some(JobStatusFilter::Done),
+    };
+    let client = Client::new();
+    let jobs = endpoints::list_job_ages(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        &filter,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(jobs.len(), 2);
+    assert_eq!(jobs[0].sid, "old-sid");
+    assert_eq!(jobs[0].owner, "alice");
+    assert_eq!(
+        jobs[0].published.as_deref(),
+        Some("2024-01-15T08:00:00.000-05:00")
+    );
+    assert_eq!(jobs[0].dispatch_state.as_deref(), Some("DONE"));
+    assert!(jobs[0].is_done);
+    assert_eq!(jobs[1].published, None);
+    assert_eq!(jobs[1].label.as_deref(), Some("Report"));
+}
+
 #[tokio::test]
 async fn test_cancel_job() {
     let mock_server = MockServer::start().await;
@@ -228,3 +353,159 @@ async fn test_delete_job() {
 
     assert!(result.is_ok());
 }
+
+#[tokio::test]
+async fn test_finalize_job() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs/test-sid/control"))
+        .and(body_string_contains("action=finalize"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result = endpoints::finalize_job(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "test-sid",
+        3,
+        None,
+        None,
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_set_job_ttl() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs/test-sid/control"))
+        .and(body_string_contains("action=setttl"))
+        .and(body_string_contains("ttl=86400"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result = endpoints::set_job_ttl(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "test-sid",
+        86_400,
+        3,
+        None,
+        None,
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_get_job_status_parses_ttl() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/test-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "content": {
+                    "sid": "test-sid",
+                    "isDone": true,
+                    "ttl": "540"
+                }
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let status = endpoints::get_job_status(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "test-sid",
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(status.ttl, Some(540));
+}
+
+fn job_status(result_count: usize, is_done: bool, is_finalized: bool) -> serde_json::Value {
+    serde_json::json!({
+        "entry": [{
+            "content": {
+                "sid": "test-sid",
+                "isDone": is_done,
+                "isFinalized": is_finalized,
+                "doneProgress": if is_done { 1.0 } else { 0.2 },
+                "eventCount": result_count,
+                "resultCount": result_count
+            }
+        }]
+    })
+}
+
+#[tokio::test]
+async fn test_wait_for_job_finalizes_at_threshold() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/test-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(job_status(10, false, false)))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/test-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(job_status(150, false, false)))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/test-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(job_status(160, true, true)))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs/test-sid/control"))
+        .and(body_string_contains("action=finalize"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let status = endpoints::wait_for_job_with_finalize(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "test-sid",
+        10,
+        30,
+        3,
+        Some(100),
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert!(status.is_done);
+    assert!(status.is_finalized);
+    assert_eq!(status.result_count, 160);
+}
@@ -40,6 +40,37 @@ async fn test_get_kvstore_status() {
     assert_eq!(status.replication_status.oplog_size, 1024);
 }
 
+#[tokio::test]
+async fn test_get_kvstore_status_standalone_members_report_replication_lag() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/kvstore/status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "status",
+                "content": {
+                    "current": {"guid": "A1", "port": 8191, "replicaSet": "splunkrs", "status": "ready"},
+                    "members": {
+                        "A1": {"hostAndPort": "sh1:8191", "optimeDate": 1760860800},
+                        "B2": {"hostAndPort": "sh2:8191", "optimeDate": "1760860710"}
+                    }
+                }
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let status =
+        endpoints::get_kvstore_status(&client, &mock_server.uri(), "test-token", 3, None, None)
+            .await
+            .unwrap();
+
+    assert_eq!(status.current_member.host, "sh1");
+    assert_eq!(status.replication_status.replication_lag_secs, Some(90.0));
+}
+
 #[tokio::test]
 async fn test_splunk_client_get_kvstore_status() {
     let mock_server = MockServer::start().await;
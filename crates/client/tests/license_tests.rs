@@ -4,6 +4,8 @@
 //! - Getting license usage statistics
 //! - Listing license pools
 //! - Listing license stacks
+//! - Listing licenser messages and slaves
+//! - Summarizing daily usage history from a license_usage.log search
 //! - SplunkClient interface for license operations
 //!
 //! # Invariants
@@ -12,12 +14,12 @@
 //!
 //! # What this does NOT handle
 //! - License installation or activation
-//! - License violation handling
 
 mod common;
 
 use common::*;
-use wiremock::matchers::{method, path};
+use splunk_client::{LicenseMessageSeverity, LicenseWarningSummary};
+use wiremock::matchers::{body_string_contains, method, path, query_param};
 
 #[tokio::test]
 async fn test_get_license_usage() {
@@ -99,6 +101,77 @@ async fn test_list_license_stacks() {
     assert_eq!(stacks[0].type_name, "enterprise");
 }
 
+#[tokio::test]
+async fn test_list_license_messages() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("license/list_messages.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/licenser/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result =
+        endpoints::list_license_messages(&client, &mock_server.uri(), "test-token", 3, None, None)
+            .await;
+
+    assert!(result.is_ok());
+    let messages = result.unwrap();
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[0].category, "pool_over_quota");
+    assert_eq!(messages[0].severity, LicenseMessageSeverity::Warn);
+    assert!(messages[0].is_over_quota());
+    assert_eq!(messages[1].name, "8f0c3d9e2b7a4e6f9d1c5b3a7e9f2d4c");
+    assert_eq!(messages[1].create_time, Some(1760659200));
+    assert_eq!(messages[1].slave_id, None);
+}
+
+#[tokio::test]
+async fn test_list_license_slaves_and_summary() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/licenser/slaves"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("license/list_slaves.json")),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/licenser/messages"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("license/list_messages.json")),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let slaves =
+        endpoints::list_license_slaves(&client, &mock_server.uri(), "test-token", 3, None, None)
+            .await
+            .unwrap();
+
+    assert_eq!(slaves.len(), 2);
+    assert_eq!(slaves[0].name, "4A1B2C3D-0000-0000-0000-000000000001");
+    assert_eq!(slaves[0].label, "idx1");
+    assert_eq!(slaves[0].warning_count, 4);
+    assert_eq!(slaves[0].warnings_until_violation(), 1);
+    assert_eq!(slaves[1].warning_count, 0);
+
+    let messages =
+        endpoints::list_license_messages(&client, &mock_server.uri(), "test-token", 3, None, None)
+            .await
+            .unwrap();
+    let summary = LicenseWarningSummary::from_parts(&messages, &slaves);
+    assert_eq!(summary.warnings_until_violation, 1);
+    assert_eq!(summary.over_quota_slaves, vec!["idx1"]);
+    assert!(summary.violating_slaves.is_empty());
+    assert!(summary.is_near_violation());
+}
+
 #[tokio::test]
 async fn test_splunk_client_get_license_usage() {
     let mock_server = MockServer::start().await;
@@ -132,3 +205,54 @@ async fn test_splunk_client_get_license_usage() {
     assert_eq!(usage[0].name, "daily_usage");
     assert_eq!(usage[0].quota, 53687091200);
 }
+
+#[tokio::test]
+async fn test_get_license_usage_history() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains("RolloverSummary"))
+        .and(body_string_contains("earliest_time=-30d%40d"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": "license-history-sid" } }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/license-history-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": "license-history-sid", "isDone": true } }]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/license-history-sid/results"))
+        .and(query_param("count", "0"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("license/usage_history.json")),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let days = endpoints::get_license_usage_history(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        30,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(days.len(), 3);
+    assert_eq!(days[0].day, "2024-01-13");
+    assert_eq!(days[0].used_gb(), 3.0);
+    assert_eq!(days[1].quota_gb(), 5.0);
+    assert!(days[1].is_over_quota());
+    assert!(!days[2].is_over_quota());
+}
@@ -413,7 +413,7 @@ mod apps_live_tests {
 
         // Saved searches may be empty depending on instance configuration; this is a smoke test.
         let _saved_searches = client
-            .list_saved_searches(None, None)
+            .list_saved_searches(None, None, None)
             .await
             .expect("Failed to list saved searches");
     }
@@ -525,7 +525,7 @@ mod apps_live_tests {
         for _attempt in 0..10 {
             // Use a high count to ensure we get all saved searches (default is 30)
             let searches = client
-                .list_saved_searches(Some(1000), None)
+                .list_saved_searches(Some(1000), None, None)
                 .await
                 .expect("Failed to list saved searches");
             if let Some(s) = searches.iter().find(|s| s.name == name) {
@@ -2,13 +2,14 @@
 //!
 //! This module tests the Splunk lookup table files API:
 //! - Listing all lookup table files (CSV-based lookups)
+//! - Reading and saving parsed lookup contents
 //!
 //! # Invariants
 //! - Lookup tables are returned with their metadata (name, filename, owner, app, sharing, size)
 //! - Results are paginated according to the provided count/offset parameters
 //!
 //! # What this does NOT handle
-//! - Lookup file content upload/download (not supported by this endpoint)
+//! - Lookup file upload/download beyond the contents round trip
 //! - KV store lookups (different endpoint)
 
 mod common;
@@ -36,6 +37,7 @@ async fn test_list_lookup_tables() {
         "test-token",
         None,
         None,
+        None,
         3,
         None,
         None,
@@ -96,6 +98,7 @@ async fn test_list_lookup_tables_parses_acl_backed_splunk_shape() {
         "test-token",
         None,
         None,
+        None,
         3,
         None,
         None,
@@ -132,6 +135,7 @@ async fn test_list_lookup_tables_with_pagination() {
         "test-token",
         Some(1),
         Some(0),
+        None,
         3,
         None,
         None,
@@ -164,6 +168,7 @@ async fn test_list_lookup_tables_empty_response() {
         "test-token",
         None,
         None,
+        None,
         3,
         None,
         None,
@@ -200,7 +205,7 @@ async fn test_splunk_client_list_lookup_tables() {
         .build()
         .unwrap();
 
-    let result = client.list_lookup_tables(None, None).await;
+    let result = client.list_lookup_tables(None, None, None).await;
 
     assert!(result.is_ok());
     let lookups = result.unwrap();
@@ -228,6 +233,7 @@ async fn test_list_lookup_tables_unauthorized() {
         "test-token",
         None,
         None,
+        None,
         3,
         None,
         None,
@@ -263,6 +269,7 @@ async fn test_list_lookup_tables_forbidden() {
         "test-token",
         None,
         None,
+        None,
         3,
         None,
         None,
@@ -367,6 +374,7 @@ async fn test_list_lookup_tables_malformed_response() {
         "test-token",
         None,
         None,
+        None,
         3,
         None,
         None,
@@ -375,3 +383,63 @@ async fn test_list_lookup_tables_malformed_response() {
 
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_splunk_client_lookup_contents_round_trip() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/servicesNS/nobody/search/data/lookup-table-files/hosts.csv",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_string("host,owner\nweb-01,ops\n"))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/servicesNS/nobody/search/data/lookup-table-files"))
+        .and(wiremock::matchers::body_string_contains(
+            "host,owner\nweb-01,\"Ops, EU\"\n",
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "hosts.csv",
+                "content": {
+                    "name": "hosts.csv",
+                    "filename": "hosts.csv",
+                    "owner": "nobody",
+                    "app": "search",
+                    "sharing": "app",
+                    "size": 29
+                }
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    use secrecy::SecretString;
+    use splunk_client::{AuthStrategy, SplunkClient};
+
+    let client = SplunkClient::builder()
+        .base_url(mock_server.uri())
+        .auth_strategy(AuthStrategy::ApiToken {
+            token: SecretString::new("test-token".to_string().into()),
+        })
+        .build()
+        .unwrap();
+
+    let mut content = client
+        .get_lookup_contents("hosts.csv", Some("search"), Some("nobody"))
+        .await
+        .unwrap();
+    assert_eq!(content.header, vec!["host", "owner"]);
+    assert_eq!(content.rows, vec![vec!["web-01", "ops"]]);
+
+    content.rows[0][1] = "Ops, EU".to_string();
+    let saved = client
+        .save_lookup_contents("hosts.csv", Some("search"), Some("nobody"), &content)
+        .await
+        .unwrap();
+    assert_eq!(saved.name, "hosts.csv");
+}
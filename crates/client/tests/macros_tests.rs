@@ -27,8 +27,16 @@ async fn test_list_macros() {
         .await;
 
     let client = Client::new();
-    let result =
-        endpoints::list_macros(&client, &mock_server.uri(), "test-token", 3, None, None).await;
+    let result = endpoints::list_macros(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        None,
+        3,
+        None,
+        None,
+    )
+    .await;
 
     assert!(result.is_ok());
     let macros = result.unwrap();
@@ -93,6 +101,7 @@ async fn test_create_macro_endpoint() {
         &mock_server.uri(),
         "test-token",
         &request,
+        None,
         3,
         None,
         None,
@@ -163,6 +172,7 @@ async fn test_update_macro_endpoint() {
         &mock_server.uri(),
         "test-token",
         &request,
+        None,
         3,
         None,
         None,
@@ -189,6 +199,7 @@ async fn test_delete_macro() {
         &mock_server.uri(),
         "test-token",
         "test_macro",
+        None,
         3,
         None,
         None,
@@ -217,6 +228,7 @@ async fn test_get_macro() {
         &mock_server.uri(),
         "test-token",
         "test_macro",
+        None,
         3,
         None,
         None,
@@ -245,6 +257,7 @@ async fn test_get_macro_not_found() {
         &mock_server.uri(),
         "test-token",
         "nonexistent",
+        None,
         3,
         None,
         None,
@@ -255,3 +268,33 @@ async fn test_get_macro_not_found() {
     let err = result.unwrap_err();
     assert!(err.to_string().contains("not found"));
 }
+
+#[tokio::test]
+async fn test_list_macros_in_app_namespace() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("macros/list_macros.json");
+
+    Mock::given(method("GET"))
+        .and(path("/servicesNS/-/my_app/admin/macros"))
+        .and(query_param("search", "eai:acl.app=\"my_app\""))
+        .and(query_param("count", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result = endpoints::list_macros(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        Some(&splunk_client::models::Namespace::app("my_app")),
+        3,
+        None,
+        None,
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
@@ -0,0 +1,101 @@
+//! Server message (bulletin board) endpoint tests.
+//!
+//! This module tests the Splunk messages API:
+//! - Listing bulletin board messages, including the legacy text-under-name format
+//! - Dismissing (deleting) a message
+//!
+//! # Invariants
+//! - Messages are returned most severe first
+//! - Dismissing an unknown message maps to `ClientError::NotFound`
+
+mod common;
+
+use common::*;
+use splunk_client::ClientError;
+use splunk_client::models::ServerMessageSeverity;
+use wiremock::matchers::{method, path, query_param};
+
+#[tokio::test]
+async fn test_list_server_messages() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("messages/list_messages.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/messages"))
+        .and(query_param("output_mode", "json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let messages =
+        endpoints::list_server_messages(&client, &mock_server.uri(), "test-token", 3, None, None)
+            .await
+            .unwrap();
+
+    let names: Vec<&str> = messages.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, ["disk_space", "restart_required", "license_warning"]);
+    assert_eq!(messages[0].severity, ServerMessageSeverity::Error);
+    assert_eq!(messages[0].help.as_deref(), Some("learnmore.diskspace"));
+    assert_eq!(
+        messages[1].message,
+        "Splunk must be restarted for changes to take effect."
+    );
+    assert_eq!(messages[1].time_created, Some(1_707_991_200));
+    assert_eq!(messages[1].help, None);
+}
+
+#[tokio::test]
+async fn test_delete_server_message() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/services/messages/restart_required"))
+        .and(query_param("output_mode", "json"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result = endpoints::delete_server_message(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "restart_required",
+        3,
+        None,
+        None,
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_delete_server_message_not_found() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/services/messages/missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "messages": [{"type": "ERROR", "text": "Not Found"}]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result = endpoints::delete_server_message(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "missing",
+        3,
+        None,
+        None,
+    )
+    .await;
+
+    assert!(matches!(result, Err(ClientError::NotFound(_))));
+}
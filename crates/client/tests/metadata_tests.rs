@@ -0,0 +1,133 @@
+//! Index metadata endpoint tests.
+//!
+//! This module tests the `| metadata` search wrapper:
+//! - Joining the metadata rows with the per-value index lookup
+//! - Single-value and multivalue `indexes` fields
+//! - SplunkClient interface passing the type and index filter through
+//!
+//! # Invariants
+//! - Both searches run over all time
+//!
+//! # What this does NOT handle
+//! - The SPL that produces the rows (exercised against a live server only)
+
+mod common;
+
+use common::*;
+use splunk_client::MetadataType;
+use wiremock::matchers::{body_string_contains, method, path, query_param};
+
+async fn mount_search(mock_server: &MockServer, sid: &str, query_marker: &str, fixture: &str) {
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains(query_marker))
+        .and(body_string_contains("earliest_time=0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": sid } }]
+        })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}", sid)))
+        .and(query_param("output_mode", "json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "content": {
+                    "sid": sid,
+                    "isDone": true,
+                    "doneProgress": 1.0,
+                    "runDuration": 0.4,
+                    "scanCount": 3,
+                    "eventCount": 3,
+                    "resultCount": 3,
+                    "diskUsage": 64
+                }
+            }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}/results", sid)))
+        .and(query_param("output_mode", "json"))
+        .and(query_param("count", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(load_fixture(fixture)))
+        .mount(mock_server)
+        .await;
+}
+
+async fn mount_sourcetype_searches(mock_server: &MockServer, index_filter: &str) {
+    mount_search(
+        mock_server,
+        "test-metadata-sid",
+        &format!("type%3Dsourcetypes+{}", index_filter),
+        "metadata/sourcetypes.json",
+    )
+    .await;
+    mount_search(
+        mock_server,
+        "test-metadata-indexes-sid",
+        "tstats+values%28index%29",
+        "metadata/sourcetype_indexes.json",
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_get_sourcetype_metadata() {
+    let mock_server = MockServer::start().await;
+    mount_sourcetype_searches(&mock_server, "index%3D*").await;
+
+    let client = Client::new();
+    let entries = endpoints::get_metadata(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        MetadataType::Sourcetypes,
+        None,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, vec!["access_combined", "legacy_csv", "syslog"]);
+
+    let access = &entries[0];
+    assert_eq!(access.total_count, 1_250_000);
+    assert_eq!(access.first_time, Some(1_690_000_000));
+    assert_eq!(access.last_time, Some(1_700_086_400));
+    assert_eq!(access.indexes, vec!["main", "web"]);
+
+    assert_eq!(entries[2].indexes, vec!["network"]);
+}
+
+#[tokio::test]
+async fn test_splunk_client_get_metadata_for_one_index() {
+    let mock_server = MockServer::start().await;
+    mount_sourcetype_searches(&mock_server, "index%3D%22web%22").await;
+
+    use secrecy::SecretString;
+    use splunk_client::{AuthStrategy, SplunkClient};
+
+    let strategy = AuthStrategy::ApiToken {
+        token: SecretString::new("test-token".to_string().into()),
+    };
+
+    let client = SplunkClient::builder()
+        .base_url(mock_server.uri())
+        .auth_strategy(strategy)
+        .build()
+        .unwrap();
+
+    let entries = client
+        .get_metadata(MetadataType::Sourcetypes, Some("web"))
+        .await
+        .unwrap();
+
+    assert_eq!(entries.len(), 3);
+}
@@ -0,0 +1,170 @@
+//! Request middleware (interceptor) tests.
+//!
+//! This module tests the `SplunkClientBuilder::with_middleware` hook:
+//! - Interceptors can add headers to outgoing requests
+//! - Interceptors observe every attempt, including retries
+//! - Interceptors can short-circuit requests with a synthetic response
+//!
+//! # Invariants
+//! - Interceptors run in registration order
+//! - Short-circuited requests never reach the server
+//!
+//! # What this does NOT handle
+//! - HTTP trace capture (see http_trace_tests.rs)
+
+mod common;
+
+use common::*;
+use reqwest::StatusCode;
+use reqwest::header::{HeaderMap, HeaderValue};
+use secrecy::SecretString;
+use splunk_client::middleware::{
+    InterceptedResponse, InterceptorAction, RequestContext, RequestInterceptor, ResponseInfo,
+};
+use splunk_client::{AuthStrategy, ClientError, SplunkClient};
+use std::sync::{Arc, Mutex};
+use wiremock::matchers::{header, method, path};
+
+fn client_with(base_url: String, interceptors: Vec<Arc<dyn RequestInterceptor>>) -> SplunkClient {
+    let mut builder = SplunkClient::builder()
+        .base_url(base_url)
+        .auth_strategy(AuthStrategy::ApiToken {
+            token: SecretString::new("api-token".to_string().into()),
+        })
+        .max_retries(1)
+        .no_circuit_breaker()
+        .no_cache();
+    for interceptor in interceptors {
+        builder = builder.with_middleware(interceptor);
+    }
+    builder.build().unwrap()
+}
+
+struct HeaderInjector;
+
+impl RequestInterceptor for HeaderInjector {
+    fn on_request(&self, _ctx: &RequestContext<'_>, headers: &mut HeaderMap) -> InterceptorAction {
+        headers.insert("x-splunk-tenant", HeaderValue::from_static("blue"));
+        InterceptorAction::Continue
+    }
+}
+
+/// (endpoint, attempt, status, short_circuited) for each observed attempt.
+type Observation = (String, usize, Option<u16>, bool);
+
+#[derive(Default)]
+struct Observer {
+    seen: Mutex<Vec<Observation>>,
+}
+
+impl RequestInterceptor for Observer {
+    fn on_response(&self, ctx: &RequestContext<'_>, response: &ResponseInfo<'_>) {
+        self.seen.lock().unwrap().push((
+            ctx.endpoint.to_string(),
+            ctx.attempt,
+            response.status.map(|s| s.as_u16()),
+            response.short_circuited,
+        ));
+    }
+}
+
+struct Blocker;
+
+impl RequestInterceptor for Blocker {
+    fn on_request(&self, ctx: &RequestContext<'_>, _headers: &mut HeaderMap) -> InterceptorAction {
+        if ctx.method == reqwest::Method::DELETE {
+            InterceptorAction::ShortCircuit(InterceptedResponse::json(
+                StatusCode::FORBIDDEN,
+                &serde_json::json!({
+                    "messages": [{"type": "ERROR", "text": "Blocked by policy"}]
+                }),
+            ))
+        } else {
+            InterceptorAction::Continue
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_middleware_injects_headers() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .and(header("x-splunk-tenant", "blue"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("server/get_server_info.json")),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = client_with(mock_server.uri(), vec![Arc::new(HeaderInjector)]);
+
+    let result = client.get_server_info().await;
+    assert!(result.is_ok(), "expected success, got {:?}", result.err());
+}
+
+#[tokio::test]
+async fn test_middleware_observes_each_attempt() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("server/get_server_info.json")),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let observer = Arc::new(Observer::default());
+    let client = client_with(mock_server.uri(), vec![observer.clone()]);
+
+    client.get_server_info().await.unwrap();
+
+    let seen = observer.seen.lock().unwrap().clone();
+    assert_eq!(
+        seen,
+        vec![
+            ("/services/server/info".to_string(), 1, Some(503), false),
+            ("/services/server/info".to_string(), 2, Some(200), false),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_middleware_short_circuits_request() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let observer = Arc::new(Observer::default());
+    let client = client_with(mock_server.uri(), vec![Arc::new(Blocker), observer.clone()]);
+
+    let result = client.delete_index("main").await;
+
+    match result {
+        Err(ClientError::Unauthorized(message)) => {
+            assert!(message.contains("Blocked by policy"));
+        }
+        other => panic!("expected Unauthorized error, got {:?}", other),
+    }
+
+    let seen = observer.seen.lock().unwrap().clone();
+    assert_eq!(seen.len(), 1);
+    assert!(
+        seen[0].3,
+        "observer should see the short-circuited response"
+    );
+}
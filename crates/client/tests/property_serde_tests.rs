@@ -315,6 +315,8 @@ fn search_job_status_strategy() -> impl Strategy<Value = SearchJobStatus> {
                 disk_usage,
                 priority,
                 label,
+                ttl: None,
+                performance: None,
             },
         )
 }
@@ -600,6 +602,8 @@ proptest! {
             disk_usage,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         };
 
         let json = serde_json::to_string(&status).expect("Should serialize");
@@ -749,6 +753,8 @@ mod edge_case_tests {
             disk_usage: 0,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         };
 
         let json = serde_json::to_string(&status).expect("Should serialize");
@@ -774,6 +780,8 @@ mod edge_case_tests {
             disk_usage: 1_000_000_000,
             priority: Some(5),
             label: Some("Test Search".to_string()),
+            ttl: None,
+            performance: None,
         };
 
         let json = serde_json::to_string(&status).expect("Should serialize");
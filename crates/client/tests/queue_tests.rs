@@ -0,0 +1,122 @@
+//! Ingestion queue health endpoint tests.
+//!
+//! This module tests the per-pipeline queue summary from `metrics.log`:
+//! - Ordering queues fullest first and totalling blocked samples
+//! - Flagging queues sustained above 80% full as warnings
+//! - SplunkClient interface normalizing bare `--since` durations
+//!
+//! # Invariants
+//! - All rows are requested (`count=0`) so no pipeline is dropped
+//!
+//! # What this does NOT handle
+//! - The SPL that produces the rows (exercised against a live server only)
+
+mod common;
+
+use common::*;
+use wiremock::matchers::{body_string_contains, method, path, query_param};
+
+async fn mount_queue_search(mock_server: &MockServer, sid: &str, earliest: &str) {
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains("group%3Dqueue"))
+        .and(body_string_contains(format!("earliest_time={}", earliest)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": sid } }]
+        })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}", sid)))
+        .and(query_param("output_mode", "json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "content": {
+                    "sid": sid,
+                    "isDone": true,
+                    "doneProgress": 1.0,
+                    "runDuration": 0.8,
+                    "scanCount": 90,
+                    "eventCount": 90,
+                    "resultCount": 3,
+                    "diskUsage": 128
+                }
+            }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}/results", sid)))
+        .and(query_param("output_mode", "json"))
+        .and(query_param("count", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(load_fixture("queues/health.json")))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_check_queue_health() {
+    let mock_server = MockServer::start().await;
+    mount_queue_search(&mock_server, "test-queue-sid", "-15m").await;
+
+    let client = Client::new();
+    let health = endpoints::check_queue_health(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "-15m",
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert!(!health.is_healthy);
+    assert_eq!(health.time_window, "-15m");
+    assert_eq!(health.blocked_count, 13);
+    let queues: Vec<(&str, &str)> = health
+        .queues
+        .iter()
+        .map(|q| (q.pipeline.as_str(), q.queue.as_str()))
+        .collect();
+    assert_eq!(
+        queues,
+        vec![
+            ("1", "indexqueue"),
+            ("1", "typingqueue"),
+            ("0", "parsingqueue")
+        ]
+    );
+    assert_eq!(
+        health.warnings(),
+        vec!["idx01 pipeline 1 indexqueue averaged 93.4% full (above 80%)".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_splunk_client_check_queue_health_normalizes_since() {
+    let mock_server = MockServer::start().await;
+    mount_queue_search(&mock_server, "test-queue-sid-client", "-1h").await;
+
+    use secrecy::SecretString;
+    use splunk_client::{AuthStrategy, SplunkClient};
+
+    let strategy = AuthStrategy::ApiToken {
+        token: SecretString::new("test-token".to_string().into()),
+    };
+
+    let client = SplunkClient::builder()
+        .base_url(mock_server.uri())
+        .auth_strategy(strategy)
+        .build()
+        .unwrap();
+
+    let health = client.check_queue_health("1h").await.unwrap();
+
+    assert_eq!(health.time_window, "-1h");
+    assert_eq!(health.queues.len(), 3);
+}
@@ -0,0 +1,108 @@
+//! Client-side rate limiter tests.
+//!
+//! This module tests `SplunkClientBuilder::rate_limit`:
+//! - Requests within the burst are sent immediately
+//! - Requests beyond the burst are spaced at the configured rate
+//! - A shared limiter throttles several clients together
+//!
+//! # What this does NOT handle
+//! - Server-side 429 retries (see retry_rate_limit_tests.rs)
+
+mod common;
+
+use common::*;
+use secrecy::SecretString;
+use splunk_client::{AuthStrategy, RateLimiter, SplunkClient, SplunkClientBuilder};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use wiremock::matchers::{method, path};
+
+fn builder(base_url: String) -> SplunkClientBuilder {
+    SplunkClient::builder()
+        .base_url(base_url)
+        .auth_strategy(AuthStrategy::ApiToken {
+            token: SecretString::new("api-token".to_string().into()),
+        })
+        .no_circuit_breaker()
+        .no_cache()
+}
+
+async fn mount_server_info(mock_server: &MockServer, expected: u64) {
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("server/get_server_info.json")),
+        )
+        .expect(expected)
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_rate_limit_spaces_requests_beyond_burst() {
+    let mock_server = MockServer::start().await;
+    mount_server_info(&mock_server, 5).await;
+
+    // Burst of 2, then one request every 100ms: 3 throttled requests ≈ 300ms.
+    let client = builder(mock_server.uri())
+        .rate_limit(10.0, 2)
+        .build()
+        .unwrap();
+
+    let started = Instant::now();
+    for _ in 0..5 {
+        client.get_server_info().await.unwrap();
+    }
+    let elapsed = started.elapsed();
+
+    assert!(
+        elapsed >= Duration::from_millis(250),
+        "expected throttling, finished in {:?}",
+        elapsed
+    );
+}
+
+#[tokio::test]
+async fn test_requests_within_burst_are_not_delayed() {
+    let mock_server = MockServer::start().await;
+    mount_server_info(&mock_server, 3).await;
+
+    let client = builder(mock_server.uri())
+        .rate_limit(1.0, 3)
+        .build()
+        .unwrap();
+
+    let started = Instant::now();
+    for _ in 0..3 {
+        client.get_server_info().await.unwrap();
+    }
+
+    assert!(started.elapsed() < Duration::from_millis(900));
+}
+
+#[tokio::test]
+async fn test_shared_limiter_throttles_clients_together() {
+    let mock_server = MockServer::start().await;
+    mount_server_info(&mock_server, 4).await;
+
+    let limiter = Arc::new(RateLimiter::new(10.0, 1));
+    let first = builder(mock_server.uri())
+        .rate_limiter(limiter.clone())
+        .build()
+        .unwrap();
+    let second = builder(mock_server.uri())
+        .rate_limiter(limiter)
+        .build()
+        .unwrap();
+
+    let started = Instant::now();
+    for _ in 0..2 {
+        first.get_server_info().await.unwrap();
+        second.get_server_info().await.unwrap();
+    }
+
+    assert!(
+        started.elapsed() >= Duration::from_millis(250),
+        "shared budget should throttle both clients"
+    );
+}
@@ -0,0 +1,204 @@
+//! Raw REST request tests.
+//!
+//! This module tests `SplunkClient::raw_request`, the escape hatch for
+//! endpoints without a typed method:
+//! - GET with query parameters and the default JSON output mode
+//! - Non-JSON bodies returned as text
+//! - POST form bodies
+//! - Session renewal on 401 and path validation
+//!
+//! # Invariants
+//! - `output_mode=json` is only added when the caller did not set one
+//! - Paths outside `/services` and `/servicesNS` are rejected before any request
+//!
+//! # What this does NOT handle
+//! - Pretty-printing of response bodies (see `raw_format`)
+
+mod common;
+
+use common::*;
+use secrecy::SecretString;
+use splunk_client::{AuthStrategy, ClientError, RestMethod, SplunkClient};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use wiremock::matchers::{body_string_contains, header, method, path, query_param};
+
+fn token_client(base_url: String) -> SplunkClient {
+    SplunkClient::builder()
+        .base_url(base_url)
+        .auth_strategy(AuthStrategy::ApiToken {
+            token: SecretString::new("test-token".to_string().into()),
+        })
+        .skip_verify(true)
+        .build()
+        .unwrap()
+}
+
+fn params(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_raw_get_returns_json() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/data/indexes"))
+        .and(query_param("count", "1"))
+        .and(query_param("output_mode", "json"))
+        .and(header("Authorization", "Bearer test-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "name": "main" }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = token_client(mock_server.uri());
+    let body = client
+        .raw_request(
+            RestMethod::Get,
+            "/services/data/indexes",
+            &params(&[("count", "1")]),
+            &[],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(body["entry"][0]["name"], "main");
+}
+
+#[tokio::test]
+async fn test_raw_get_returns_text_for_xml() {
+    let mock_server = MockServer::start().await;
+
+    let atom = "<feed><title>server-info</title></feed>";
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .and(query_param("output_mode", "xml"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(atom))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = token_client(mock_server.uri());
+    let body = client
+        .raw_request(
+            RestMethod::Get,
+            "/services/server/info",
+            &params(&[("output_mode", "xml")]),
+            &[],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(body, serde_json::Value::String(atom.to_string()));
+}
+
+#[tokio::test]
+async fn test_raw_post_sends_form_body() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/servicesNS/nobody/search/saved/searches"))
+        .and(query_param("output_mode", "json"))
+        .and(body_string_contains("name=errors"))
+        .and(body_string_contains("search=index%3Dmain+error"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "entry": [{ "name": "errors" }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = token_client(mock_server.uri());
+    let body = client
+        .raw_request(
+            RestMethod::Post,
+            "servicesNS/nobody/search/saved/searches",
+            &[],
+            &params(&[("name", "errors"), ("search", "index=main error")]),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(body["entry"][0]["name"], "errors");
+}
+
+#[tokio::test]
+async fn test_raw_request_renews_session_on_401() {
+    let mock_server = MockServer::start().await;
+
+    let login_fixture = load_fixture("auth/login_success.json");
+    let login_count = Arc::new(AtomicUsize::new(0));
+    let login_count_clone = login_count.clone();
+
+    Mock::given(method("POST"))
+        .and(path("/services/auth/login"))
+        .respond_with(move |_: &wiremock::Request| {
+            login_count_clone.fetch_add(1, Ordering::SeqCst);
+            ResponseTemplate::new(200).set_body_json(&login_fixture)
+        })
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+            "messages": [{"type": "ERROR", "text": "Session expired"}]
+        })))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "name": "server-info" }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = SplunkClient::builder()
+        .base_url(mock_server.uri())
+        .auth_strategy(AuthStrategy::SessionToken {
+            username: "admin".to_string(),
+            password: SecretString::new("testpassword".to_string().into()),
+        })
+        .skip_verify(true)
+        .build()
+        .unwrap();
+
+    let body = client
+        .raw_request(RestMethod::Get, "/services/server/info", &[], &[])
+        .await
+        .unwrap();
+
+    assert_eq!(body["entry"][0]["name"], "server-info");
+    assert_eq!(login_count.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_raw_request_rejects_paths_outside_rest_api() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(wiremock::matchers::any())
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = token_client(mock_server.uri());
+    for path in ["/en-US/account/login", "/services/../en-US/app"] {
+        let result = client.raw_request(RestMethod::Get, path, &[], &[]).await;
+        assert!(
+            matches!(result, Err(ClientError::InvalidRequest(_))),
+            "{}: {:?}",
+            path,
+            result
+        );
+    }
+}
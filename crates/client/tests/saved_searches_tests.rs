@@ -6,6 +6,7 @@
 //! - Updating saved searches
 //! - Deleting saved searches
 //! - SplunkClient interface for saved searches
+//! - Ownership listing and bulk owner reassignment
 //!
 //! # Invariants
 //! - Saved searches are returned with their names, queries, and metadata
@@ -41,6 +42,7 @@ async fn test_list_saved_searches() {
         "test-token",
         None,
         None,
+        None,
         3,
         None,
         None,
@@ -84,6 +86,7 @@ async fn test_create_saved_search() {
         "test-token",
         &params.name,
         &params.search,
+        None,
         3,
         None,
         None,
@@ -110,6 +113,7 @@ async fn test_delete_saved_search() {
         &mock_server.uri(),
         "test-token",
         "my-search",
+        None,
         3,
         None,
         None,
@@ -144,7 +148,7 @@ async fn test_splunk_client_list_saved_searches() {
         .build()
         .unwrap();
 
-    let result = client.list_saved_searches(None, None).await;
+    let result = client.list_saved_searches(None, None, None).await;
 
     assert!(result.is_ok());
     let searches = result.unwrap();
@@ -173,6 +177,7 @@ async fn test_list_saved_searches_with_pagination() {
         "test-token",
         Some(1),
         None,
+        None,
         3,
         None,
         None,
@@ -197,6 +202,7 @@ async fn test_list_saved_searches_with_pagination() {
         "test-token",
         Some(10),
         Some(5),
+        None,
         3,
         None,
         None,
@@ -227,6 +233,7 @@ async fn test_get_saved_search_success() {
         &mock_server.uri(),
         "test-token",
         "Errors in the last 24 hours",
+        None,
         3,
         None,
         None,
@@ -264,6 +271,7 @@ async fn test_get_saved_search_not_found() {
         &mock_server.uri(),
         "test-token",
         "NonExistentSearch",
+        None,
         3,
         None,
         None,
@@ -333,6 +341,8 @@ async fn test_update_saved_search_success() {
         search: Some("index=main"),
         description: None,
         disabled: None,
+        dispatch_earliest_time: None,
+        dispatch_latest_time: None,
     };
 
     let client = Client::new();
@@ -342,6 +352,7 @@ async fn test_update_saved_search_success() {
         "test-token",
         "my-search",
         &params,
+        None,
         3,
         None,
         None,
@@ -366,6 +377,8 @@ async fn test_update_saved_search_not_found() {
         search: Some("index=main"),
         description: None,
         disabled: None,
+        dispatch_earliest_time: None,
+        dispatch_latest_time: None,
     };
 
     let client = Client::new();
@@ -375,6 +388,7 @@ async fn test_update_saved_search_not_found() {
         "test-token",
         "NonExistentSearch",
         &params,
+        None,
         3,
         None,
         None,
@@ -400,6 +414,7 @@ async fn test_splunk_client_update_saved_search() {
         .and(body_string_contains("search=index%3Dmain"))
         .and(body_string_contains("description=Updated+description"))
         .and(body_string_contains("disabled=true"))
+        .and(body_string_contains("dispatch.earliest_time=-7d%40d"))
         .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
         .mount(&mock_server)
         .await;
@@ -424,9 +439,345 @@ async fn test_splunk_client_update_saved_search() {
                 search: Some("index=main".to_string()),
                 description: Some("Updated description".to_string()),
                 disabled: Some(true),
+                dispatch_earliest_time: Some("-7d@d".to_string()),
+                dispatch_latest_time: None,
             },
         )
         .await;
 
     assert!(result.is_ok());
 }
+
+#[tokio::test]
+async fn test_client_namespace_routes_saved_search_requests() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("search/get_saved_search.json");
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/servicesNS/jane/search/saved/searches/Errors%20in%20the%20last%2024%20hours",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/servicesNS/jane/search/saved/searches"))
+        .and(query_param(
+            "search",
+            "eai:acl.app=\"search\" eai:acl.sharing=\"user\"",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = token_client(mock_server.uri());
+    client.set_namespace(Some(splunk_client::models::Namespace {
+        owner: Some("jane".to_string()),
+        app: Some("search".to_string()),
+        sharing: Some("user".to_string()),
+    }));
+
+    let search = client
+        .get_saved_search("Errors in the last 24 hours")
+        .await
+        .unwrap();
+    assert_eq!(search.name, "Errors in the last 24 hours");
+
+    let searches = client.list_saved_searches(None, None, None).await.unwrap();
+    assert_eq!(searches.len(), 1);
+}
+
+fn token_client(base_url: String) -> splunk_client::SplunkClient {
+    use secrecy::SecretString;
+    use splunk_client::{AuthStrategy, SplunkClient};
+
+    let strategy = AuthStrategy::ApiToken {
+        token: SecretString::new("test-token".to_string().into()),
+    };
+
+    SplunkClient::builder()
+        .base_url(base_url)
+        .auth_strategy(strategy)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_list_saved_search_ownership_filters_exact_owner() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("search/list_saved_search_ownership.json");
+
+    Mock::given(method("GET"))
+        .and(path("/servicesNS/-/-/saved/searches"))
+        .and(query_param("output_mode", "json"))
+        .and(query_param("count", "0"))
+        .and(query_param("search", "eai:acl.owner=departed_user"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let searches = endpoints::list_saved_search_ownership(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        None,
+        Some("departed_user"),
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(searches.len(), 3);
+    assert!(searches.iter().all(|s| s.owner == "departed_user"));
+    assert_eq!(searches[0].name, "Nightly License Report");
+    assert_eq!(searches[0].sharing, "app");
+    assert!(searches[0].is_scheduled);
+    assert_eq!(searches[0].cron_schedule.as_deref(), Some("0 2 * * *"));
+    assert!(searches[1].disabled);
+}
+
+#[tokio::test]
+async fn test_set_saved_search_acl() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/servicesNS/departed_user/search/saved/searches/Nightly%20License%20Report/acl",
+        ))
+        .and(body_string_contains("owner=svc_account"))
+        .and(body_string_contains("sharing=app"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result = endpoints::set_saved_search_acl(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "Nightly License Report",
+        "search",
+        "departed_user",
+        "svc_account",
+        "app",
+        3,
+        None,
+        None,
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_set_saved_search_acl_not_found() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/servicesNS/nobody/search/saved/searches/missing/acl"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "messages": [{"type": "ERROR", "text": "Could not find object id=missing"}]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result = endpoints::set_saved_search_acl(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "missing",
+        "search",
+        "nobody",
+        "svc_account",
+        "user",
+        0,
+        None,
+        None,
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(splunk_client::ClientError::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_reassign_saved_searches_dry_run_makes_no_writes() {
+    use splunk_client::workflows::saved_search_ownership::{
+        ReassignSavedSearchesRequest, ReassignStatus, reassign_saved_searches,
+    };
+
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("search/list_saved_search_ownership.json");
+
+    Mock::given(method("GET"))
+        .and(path("/servicesNS/-/-/saved/searches"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = token_client(mock_server.uri());
+    let request = ReassignSavedSearchesRequest {
+        from_owner: Some("departed_user".to_string()),
+        to_owner: "svc_account".to_string(),
+        app: None,
+        names: Vec::new(),
+        sharing: None,
+        dry_run: true,
+    };
+
+    let report = reassign_saved_searches(&client, &request, None)
+        .await
+        .unwrap();
+
+    assert_eq!(report.items.len(), 3);
+    assert!(
+        report
+            .items
+            .iter()
+            .all(|item| item.status == ReassignStatus::Planned)
+    );
+    assert_eq!(report.schedule_impact.scheduled, 2);
+    assert_eq!(report.schedule_impact.active_scheduled, 1);
+    assert_eq!(report.schedule_impact.disabled_scheduled, 1);
+}
+
+#[tokio::test]
+async fn test_reassign_saved_searches_records_failures() {
+    use splunk_client::workflows::saved_search_ownership::{
+        ReassignSavedSearchesRequest, ReassignStatus, reassign_saved_searches,
+    };
+
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("search/list_saved_search_ownership.json");
+
+    Mock::given(method("GET"))
+        .and(path("/servicesNS/-/search/saved/searches"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/servicesNS/departed_user/search/saved/searches/Ad-hoc%20Errors/acl",
+        ))
+        .respond_with(ResponseTemplate::new(404).set_body_string("{}"))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(body_string_contains("owner=svc_account"))
+        .and(body_string_contains("sharing=global"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let client = token_client(mock_server.uri());
+    let request = ReassignSavedSearchesRequest {
+        from_owner: Some("departed_user".to_string()),
+        to_owner: "svc_account".to_string(),
+        app: Some("search".to_string()),
+        names: Vec::new(),
+        sharing: Some("global".to_string()),
+        dry_run: false,
+    };
+
+    let report = reassign_saved_searches(&client, &request, None)
+        .await
+        .unwrap();
+
+    assert_eq!(report.items.len(), 3);
+    assert_eq!(report.failed_count(), 1);
+    let failed = report
+        .items
+        .iter()
+        .find(|item| item.status == ReassignStatus::Failed)
+        .unwrap();
+    assert_eq!(failed.name, "Ad-hoc Errors");
+    assert!(
+        report
+            .items
+            .iter()
+            .filter(|item| item.status == ReassignStatus::Reassigned)
+            .all(|item| item.sharing == "global")
+    );
+}
+
+#[tokio::test]
+async fn test_reassign_saved_searches_by_name_skips_unchanged_and_reports_missing() {
+    use splunk_client::workflows::saved_search_ownership::{
+        ReassignSavedSearchesRequest, ReassignStatus, reassign_saved_searches,
+    };
+
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("search/list_saved_search_ownership.json");
+
+    Mock::given(method("GET"))
+        .and(path("/servicesNS/-/-/saved/searches"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/servicesNS/departed_user/search/saved/searches/Ad-hoc%20Errors/acl",
+        ))
+        .and(body_string_contains("owner=departed_user_2"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = token_client(mock_server.uri());
+    let request = ReassignSavedSearchesRequest {
+        from_owner: None,
+        to_owner: "departed_user_2".to_string(),
+        app: None,
+        names: vec![
+            "Someone Elses Search".to_string(),
+            "Ad-hoc Errors".to_string(),
+            "Ghost Search".to_string(),
+        ],
+        sharing: None,
+        dry_run: false,
+    };
+
+    let report = reassign_saved_searches(&client, &request, None)
+        .await
+        .unwrap();
+
+    let statuses: Vec<_> = report
+        .items
+        .iter()
+        .map(|item| (item.name.as_str(), item.status))
+        .collect();
+    assert_eq!(
+        statuses,
+        vec![
+            ("Ad-hoc Errors", ReassignStatus::Reassigned),
+            ("Someone Elses Search", ReassignStatus::Unchanged),
+            ("Ghost Search", ReassignStatus::Failed),
+        ]
+    );
+    assert_eq!(report.schedule_impact.total, 2);
+}
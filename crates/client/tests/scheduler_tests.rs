@@ -0,0 +1,119 @@
+//! Scheduled search health endpoint tests.
+//!
+//! This module tests the scheduler skip/failure summary:
+//! - Folding per-search scheduler rows into window totals
+//! - Keeping only searches with skipped or failed runs, worst first
+//! - SplunkClient interface normalizing bare `--since` durations
+//!
+//! # Invariants
+//! - Totals include every scheduled search, including clean ones
+//! - All rows are requested (`count=0`) so totals are not truncated
+//!
+//! # What this does NOT handle
+//! - The SPL that produces the rows (exercised against a live server only)
+
+mod common;
+
+use common::*;
+use wiremock::matchers::{body_string_contains, method, path, query_param};
+
+async fn mount_scheduler_search(mock_server: &MockServer, sid: &str, earliest: &str) {
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains("sourcetype%3Dscheduler"))
+        .and(body_string_contains(format!("earliest_time={}", earliest)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": sid } }]
+        })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}", sid)))
+        .and(query_param("output_mode", "json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "content": {
+                    "sid": sid,
+                    "isDone": true,
+                    "doneProgress": 1.0,
+                    "runDuration": 1.5,
+                    "scanCount": 37,
+                    "eventCount": 37,
+                    "resultCount": 3,
+                    "diskUsage": 256
+                }
+            }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}/results", sid)))
+        .and(query_param("output_mode", "json"))
+        .and(query_param("count", "0"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("scheduler/health.json")),
+        )
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_check_scheduler_health() {
+    let mock_server = MockServer::start().await;
+    mount_scheduler_search(&mock_server, "test-scheduler-sid", "-24h").await;
+
+    let client = Client::new();
+    let health = endpoints::check_scheduler_health(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "-24h",
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert!(!health.is_healthy);
+    assert_eq!(health.time_window, "-24h");
+    assert_eq!(health.total_runs, 37);
+    assert_eq!(health.succeeded, 31);
+    assert_eq!(health.skipped, 4);
+    assert_eq!(health.failed, 2);
+    assert_eq!(health.searches.len(), 2);
+    assert_eq!(health.searches[0].name, "Errors Last Hour");
+    assert_eq!(health.searches[0].owner, "admin");
+    assert_eq!(health.searches[1].name, "Threat - Correlation Rollup");
+    assert_eq!(
+        health.searches[1].last_reason.as_deref(),
+        Some("delegated search failed on remote peer")
+    );
+}
+
+#[tokio::test]
+async fn test_splunk_client_check_scheduler_health_normalizes_since() {
+    let mock_server = MockServer::start().await;
+    mount_scheduler_search(&mock_server, "test-scheduler-sid-client", "-7d").await;
+
+    use secrecy::SecretString;
+    use splunk_client::{AuthStrategy, SplunkClient};
+
+    let strategy = AuthStrategy::ApiToken {
+        token: SecretString::new("test-token".to_string().into()),
+    };
+
+    let client = SplunkClient::builder()
+        .base_url(mock_server.uri())
+        .auth_strategy(strategy)
+        .build()
+        .unwrap();
+
+    let health = client.check_scheduler_health("7d").await.unwrap();
+
+    assert_eq!(health.time_window, "-7d");
+    assert_eq!(health.skipped, 4);
+}
@@ -0,0 +1,148 @@
+//! Search load endpoint tests.
+//!
+//! This module tests the search concurrency and per-user quota checks:
+//! - Parsing `server/status/limits/search-concurrency`
+//! - Folding the per-user rows of the job search into running/queued totals
+//! - Flagging queued searches and users at their quota as warnings
+//!
+//! # Invariants
+//! - All rows are requested (`count=0`) so no user is dropped
+//!
+//! # What this does NOT handle
+//! - The SPL that produces the rows (exercised against a live server only)
+
+mod common;
+
+use common::*;
+use wiremock::matchers::{body_string_contains, method, path, query_param};
+
+async fn mount_search_concurrency(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/services/server/status/limits/search-concurrency"))
+        .and(query_param("output_mode", "json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(load_fixture("server/get_search_concurrency.json")),
+        )
+        .mount(mock_server)
+        .await;
+}
+
+async fn mount_user_usage_search(mock_server: &MockServer, sid: &str) {
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains("srchJobsQuota"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": sid } }]
+        })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}", sid)))
+        .and(query_param("output_mode", "json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "content": {
+                    "sid": sid,
+                    "isDone": true,
+                    "doneProgress": 1.0,
+                    "runDuration": 0.2,
+                    "scanCount": 12,
+                    "eventCount": 12,
+                    "resultCount": 3,
+                    "diskUsage": 64
+                }
+            }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}/results", sid)))
+        .and(query_param("output_mode", "json"))
+        .and(query_param("count", "0"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(load_fixture("search_load/user_search_usage.json")),
+        )
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_get_search_concurrency_limits() {
+    let mock_server = MockServer::start().await;
+    mount_search_concurrency(&mock_server).await;
+
+    let client = Client::new();
+    let limits = endpoints::get_search_concurrency_limits(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(limits.max_hist_searches, Some(26));
+    assert_eq!(limits.max_hist_scheduled_searches, Some(12));
+    assert_eq!(limits.max_rt_searches, Some(26));
+    assert_eq!(limits.max_auto_summary_searches, Some(6));
+}
+
+#[tokio::test]
+async fn test_get_user_search_usage() {
+    let mock_server = MockServer::start().await;
+    mount_user_usage_search(&mock_server, "test-search-load-sid").await;
+
+    let client = Client::new();
+    let users =
+        endpoints::get_user_search_usage(&client, &mock_server.uri(), "test-token", 3, None, None)
+            .await
+            .unwrap();
+
+    assert_eq!(users.len(), 3);
+    assert_eq!(users[1].user, "analyst");
+    assert_eq!((users[1].running, users[1].queued), (3, 2));
+    assert_eq!(users[1].quota, Some(3));
+}
+
+#[tokio::test]
+async fn test_check_search_load_flags_user_at_quota() {
+    let mock_server = MockServer::start().await;
+    mount_search_concurrency(&mock_server).await;
+    mount_user_usage_search(&mock_server, "test-search-load-sid-client").await;
+
+    use secrecy::SecretString;
+    use splunk_client::{AuthStrategy, SplunkClient};
+
+    let strategy = AuthStrategy::ApiToken {
+        token: SecretString::new("test-token".to_string().into()),
+    };
+
+    let client = SplunkClient::builder()
+        .base_url(mock_server.uri())
+        .auth_strategy(strategy)
+        .build()
+        .unwrap();
+
+    let load = client.check_search_load().await.unwrap();
+
+    assert!(!load.is_healthy);
+    assert_eq!((load.running, load.queued, load.realtime), (10, 2, 1));
+    assert_eq!(load.limits.max_hist_searches, Some(26));
+    let users: Vec<&str> = load.users.iter().map(|u| u.user.as_str()).collect();
+    assert_eq!(users, vec!["svc_reporting", "analyst", "admin"]);
+    assert_eq!(load.users[0].quota, None);
+    assert_eq!(
+        load.warnings(),
+        vec![
+            "2 search(es) queued".to_string(),
+            "User analyst is at their search quota (3 of 3 running, 2 queued)".to_string(),
+        ]
+    );
+}
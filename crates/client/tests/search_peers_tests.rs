@@ -2,20 +2,21 @@
 //!
 //! This module tests the Splunk distributed search peers API:
 //! - Listing all distributed search peers
+//! - Adding, removing, and quarantining search peers
 //!
 //! # Invariants
 //! - Search peers are returned with their names and metadata
 //! - Results are paginated according to the provided limit/offset parameters
+//! - Peers are addressed by `host:port` entry name, whether given as a URI or a name
 //!
 //! # What this does NOT handle
-//! - Search peer creation/deletion (not supported by Splunk REST API)
 //! - Search peer configuration updates
 
 mod common;
 
 use common::*;
-use splunk_client::models::SearchPeerStatus;
-use wiremock::matchers::{method, path};
+use splunk_client::models::{AddSearchPeerParams, SearchPeerStatus};
+use wiremock::matchers::{body_string_contains, method, path};
 
 #[tokio::test]
 async fn test_list_search_peers() {
@@ -148,3 +149,102 @@ async fn test_list_search_peers_error() {
 
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_add_search_peer_sends_remote_credentials() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/search/distributed/peers"))
+        .and(body_string_contains("name=https%3A%2F%2Fidx1%3A8089"))
+        .and(body_string_contains("remoteUsername=admin"))
+        .and(body_string_contains("remotePassword=changeme"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({"entry": []})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let params = AddSearchPeerParams {
+        uri: "https://idx1:8089".to_string(),
+        remote_username: "admin".to_string(),
+        remote_password: secrecy::SecretString::new("changeme".to_string().into()),
+    };
+    endpoints::add_search_peer(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        &params,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_remove_and_quarantine_search_peer_use_entry_name() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/services/search/distributed/peers/idx1:8089"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(
+            "/services/search/distributed/peers/idx1:8089/quarantine",
+        ))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(
+            "/services/search/distributed/peers/idx1:8089/unquarantine",
+        ))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let uri = mock_server.uri();
+    endpoints::remove_search_peer(
+        &client,
+        &uri,
+        "test-token",
+        "https://idx1:8089",
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    endpoints::set_search_peer_quarantine(
+        &client,
+        &uri,
+        "test-token",
+        "idx1:8089",
+        true,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    endpoints::set_search_peer_quarantine(
+        &client,
+        &uri,
+        "test-token",
+        "idx1:8089",
+        false,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+}
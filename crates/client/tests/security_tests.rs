@@ -0,0 +1,119 @@
+//! Security health endpoint tests.
+//!
+//! This module tests the failed login summary from the `_audit` index:
+//! - Ordering source IPs by failures and totalling failed logins
+//! - Flagging source IPs above the threshold as warnings
+//! - SplunkClient interface normalizing bare `--since` durations
+//!
+//! # Invariants
+//! - All rows are requested (`count=0`) so no source IP is dropped
+//!
+//! # What this does NOT handle
+//! - The SPL that produces the rows (exercised against a live server only)
+
+mod common;
+
+use common::*;
+use wiremock::matchers::{body_string_contains, method, path, query_param};
+
+async fn mount_failed_login_search(mock_server: &MockServer, sid: &str, earliest: &str) {
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains("index%3D_audit"))
+        .and(body_string_contains(format!("earliest_time={}", earliest)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{ "content": { "sid": sid } }]
+        })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}", sid)))
+        .and(query_param("output_mode", "json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "content": {
+                    "sid": sid,
+                    "isDone": true,
+                    "doneProgress": 1.0,
+                    "runDuration": 0.4,
+                    "scanCount": 171,
+                    "eventCount": 171,
+                    "resultCount": 3,
+                    "diskUsage": 128
+                }
+            }]
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/services/search/jobs/{}/results", sid)))
+        .and(query_param("output_mode", "json"))
+        .and(query_param("count", "0"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("security/failed_logins.json")),
+        )
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_check_security_health() {
+    let mock_server = MockServer::start().await;
+    mount_failed_login_search(&mock_server, "test-security-sid", "-1h").await;
+
+    let client = Client::new();
+    let health = endpoints::check_security_health(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "-1h",
+        20,
+        3,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert!(!health.is_healthy);
+    assert_eq!(health.time_window, "-1h");
+    assert_eq!(health.total_failures, 171);
+    let sources: Vec<&str> = health.sources.iter().map(|s| s.src_ip.as_str()).collect();
+    assert_eq!(sources, vec!["203.0.113.7", "198.51.100.4", "10.1.4.22"]);
+    assert_eq!(
+        health.warnings(),
+        vec![
+            "203.0.113.7 had 148 failed logins for 37 user(s) since -1h (threshold 20)".to_string(),
+            "198.51.100.4 had 21 failed logins for 1 user(s) since -1h (threshold 20)".to_string(),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_splunk_client_check_security_health_normalizes_since() {
+    let mock_server = MockServer::start().await;
+    mount_failed_login_search(&mock_server, "test-security-sid-client", "-24h").await;
+
+    use secrecy::SecretString;
+    use splunk_client::{AuthStrategy, SplunkClient};
+
+    let strategy = AuthStrategy::ApiToken {
+        token: SecretString::new("test-token".to_string().into()),
+    };
+
+    let client = SplunkClient::builder()
+        .base_url(mock_server.uri())
+        .auth_strategy(strategy)
+        .build()
+        .unwrap();
+
+    let health = client.check_security_health("24h", 200).await.unwrap();
+
+    assert_eq!(health.time_window, "-24h");
+    assert_eq!(health.threshold, 200);
+    assert!(health.is_healthy);
+    assert_eq!(health.sources.len(), 3);
+}
@@ -3,6 +3,8 @@
 //! This module tests the Splunk server API:
 //! - Getting server information (version, roles, mode)
 //! - Getting health status for splunkd and features
+//! - Getting disk space per partition
+//! - Restarting splunkd and waiting for it to come back
 //!
 //! # Invariants
 //! - Server info includes version, server name, mode (standalone, distributed), and roles
@@ -15,9 +17,38 @@
 mod common;
 
 use common::*;
+use secrecy::SecretString;
 use splunk_client::models::{FeatureStatus, HealthStatus, ServerMode};
+use splunk_client::{AuthStrategy, ClientError, SplunkClient};
+use std::time::Duration;
 use wiremock::matchers::{method, path};
 
+fn server_info_body(startup_time: &str, restart_required: bool) -> serde_json::Value {
+    serde_json::json!({
+        "entry": [{
+            "name": "server-info",
+            "content": {
+                "serverName": "splunk-local",
+                "version": "9.1.2",
+                "build": "123456",
+                "restart_required": restart_required,
+                "startup_time": startup_time
+            }
+        }]
+    })
+}
+
+fn token_client(base_url: String) -> SplunkClient {
+    SplunkClient::builder()
+        .base_url(base_url)
+        .auth_strategy(AuthStrategy::ApiToken {
+            token: SecretString::new("test-token".to_string().into()),
+        })
+        .skip_verify(true)
+        .build()
+        .unwrap()
+}
+
 #[tokio::test]
 async fn test_get_server_info() {
     let mock_server = MockServer::start().await;
@@ -41,6 +72,8 @@ async fn test_get_server_info() {
     assert_eq!(info.mode, Some(ServerMode::Standalone));
     assert!(info.server_roles.contains(&"search_head".to_string()));
     assert!(info.server_roles.contains(&"indexer".to_string()));
+    assert!(!info.restart_required);
+    assert_eq!(info.startup_time, Some(1_705_773_600));
 }
 
 #[tokio::test]
@@ -70,3 +103,147 @@ async fn test_get_health() {
         HealthStatus::Green
     );
 }
+
+#[tokio::test]
+async fn test_get_partitions_space() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("server/get_partitions_space.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/server/status/partitions-space"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let partitions =
+        endpoints::get_partitions_space(&client, &mock_server.uri(), "test-token", 3, None, None)
+            .await
+            .unwrap();
+
+    assert_eq!(partitions.len(), 2);
+    assert_eq!(partitions[0].mount_point, "/opt/splunk");
+    assert_eq!(partitions[0].fs_type.as_deref(), Some("ext4"));
+    assert_eq!(partitions[0].capacity_mb, 102400.0);
+    assert!((partitions[0].used_percent().unwrap() - 55.0).abs() < 1e-9);
+    assert_eq!(partitions[1].free_mb, 30720.0);
+}
+
+#[tokio::test]
+async fn test_check_disk_usage_flags_full_partition() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("server/get_server_info.json")),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/server/status/partitions-space"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(load_fixture("server/get_partitions_space.json")),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let usage = token_client(mock_server.uri())
+        .check_disk_usage()
+        .await
+        .unwrap();
+
+    assert!(!usage.is_healthy);
+    assert_eq!(usage.server_name, "splunk-local");
+    assert!(usage.server_roles.contains(&"indexer".to_string()));
+    assert_eq!(usage.worst().unwrap().mount_point, "/data/splunk");
+    assert_eq!(
+        usage.warnings(),
+        vec!["Partition /data/splunk is 94.0% full (30720 MB free, critical)".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_restart_server() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/server/control/restart"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result =
+        endpoints::restart_server(&client, &mock_server.uri(), "test-token", 0, None, None).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_wait_for_restart_detects_new_startup_time() {
+    let mock_server = MockServer::start().await;
+
+    // First poll still sees the old process, later polls see the restarted one.
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(server_info_body("1705773600", true)),
+        )
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(server_info_body("1705777200", false)),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = token_client(mock_server.uri());
+    let info = client
+        .wait_for_restart(
+            Some(1_705_773_600),
+            Duration::from_secs(5),
+            Duration::from_millis(10),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(info.startup_time, Some(1_705_777_200));
+    assert!(!info.restart_required);
+}
+
+#[tokio::test]
+async fn test_wait_for_restart_times_out() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(server_info_body("1705773600", true)),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = token_client(mock_server.uri());
+    let result = client
+        .wait_for_restart(
+            Some(1_705_773_600),
+            Duration::from_millis(50),
+            Duration::from_millis(10),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(ClientError::OperationTimeout {
+            operation: "wait_for_restart",
+            ..
+        })
+    ));
+}
@@ -5,6 +5,8 @@
 //! - Creating new users
 //! - Modifying existing users
 //! - Deleting users
+//! - Unlocking locked-out users
+//! - Reading the password/lockout policy
 //!
 //! # Invariants
 //! - Users are returned with their names and metadata
@@ -60,8 +62,55 @@ async fn test_list_users() {
     assert_eq!(users[0].default_app, Some("search".to_string()));
     assert_eq!(users[0].roles, vec!["admin", "power"]);
     assert_eq!(users[0].last_successful_login, Some(1737712345));
+    assert!(!users[0].locked_out);
     assert_eq!(users[1].name, "user1");
     assert_eq!(users[1].roles, vec!["user"]);
+    assert!(users[1].locked_out);
+}
+
+#[tokio::test]
+async fn test_get_current_username() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("users/current_context.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/authentication/current-context"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result =
+        endpoints::get_current_username(&client, &mock_server.uri(), "test-token", 3, None, None)
+            .await;
+
+    assert_eq!(result.unwrap(), "analyst");
+}
+
+#[tokio::test]
+async fn test_get_current_context() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("users/current_context.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/authentication/current-context"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let ctx =
+        endpoints::get_current_context(&client, &mock_server.uri(), "test-token", 3, None, None)
+            .await
+            .unwrap();
+
+    assert_eq!(ctx.username, "analyst");
+    assert_eq!(ctx.realname.as_deref(), Some("Analyst"));
+    assert_eq!(ctx.roles, vec!["power", "user"]);
+    assert!(ctx.has_capability("list_inputs"));
+    assert!(!ctx.has_capability("edit_user"));
 }
 
 #[tokio::test]
@@ -353,3 +402,61 @@ async fn test_delete_user_not_found() {
 
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_unlock_user() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("users/unlock_user.json");
+
+    Mock::given(method("POST"))
+        .and(path("/services/authentication/users/user1"))
+        .and(body_string_contains("locked-out=0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result = endpoints::unlock_user(
+        &client,
+        &mock_server.uri(),
+        "test-token",
+        "user1",
+        3,
+        None,
+        None,
+    )
+    .await;
+
+    let user = result.expect("unlock should succeed");
+    assert_eq!(user.name, "user1");
+    assert!(!user.locked_out);
+}
+
+#[tokio::test]
+async fn test_get_password_policy() {
+    let mock_server = MockServer::start().await;
+
+    let fixture = load_fixture("users/password_policy.json");
+
+    Mock::given(method("GET"))
+        .and(path("/services/admin/Splunkd-auth/splunk_auth"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::new();
+    let result =
+        endpoints::get_password_policy(&client, &mock_server.uri(), "test-token", 3, None, None)
+            .await;
+
+    let policy = result.expect("policy should load");
+    assert_eq!(policy.min_length, Some(8));
+    assert_eq!(policy.expire_password_days, Some(90));
+    assert!(policy.enable_password_history);
+    assert_eq!(policy.password_history_count, Some(24));
+    assert!(policy.lockout_users);
+    assert_eq!(policy.lockout_attempts, Some(5));
+    assert_eq!(policy.lockout_mins, Some(30));
+}
@@ -20,6 +20,9 @@ pub const DEFAULT_EXPIRY_BUFFER_SECS: u64 = 60;
 /// Default health check polling interval in seconds.
 pub const DEFAULT_HEALTH_CHECK_INTERVAL_SECS: u64 = 60;
 
+/// Delay in seconds before the session keep-alive retries a failed renewal.
+pub const DEFAULT_SESSION_RENEWAL_RETRY_SECS: u64 = 30;
+
 // =============================================================================
 // Timeout Configuration Bounds
 // =============================================================================
@@ -124,6 +127,40 @@ pub const DEFAULT_INTERNAL_LOGS_COUNT: usize = 100;
 /// Default earliest time for internal logs queries (last 15 minutes).
 pub const DEFAULT_INTERNAL_LOGS_EARLIEST_TIME: &str = "-15m";
 
+/// Default ERROR/WARN spike threshold: a minute is anomalous at this multiple of the session baseline.
+pub const DEFAULT_LOG_SPIKE_MULTIPLIER: f64 = 2.0;
+
+/// Minimum ERROR/WARN count in a minute before it can be flagged as a spike.
+pub const DEFAULT_LOG_SPIKE_MIN_COUNT: usize = 3;
+
+/// Default number of identical messages at which a collapsed row is highlighted.
+pub const DEFAULT_LOG_REPEAT_THRESHOLD: usize = 5;
+
+/// Searches running at least this many seconds trigger a completion notification.
+pub const DEFAULT_NOTIFY_MIN_SEARCH_SECS: u64 = 30;
+
+// =============================================================================
+// Health Threshold Defaults
+// =============================================================================
+
+/// License usage (percent of daily quota) at which health warns.
+pub const DEFAULT_LICENSE_WARNING_PCT: f64 = 70.0;
+
+/// License usage (percent of daily quota) at which health is critical.
+pub const DEFAULT_LICENSE_CRITICAL_PCT: f64 = 90.0;
+
+/// KVStore replication lag in seconds above which health warns.
+pub const DEFAULT_KVSTORE_MAX_REPLICATION_LAG_SECS: u64 = 60;
+
+/// Searchable indexer cluster peers below which health warns (on a cluster manager).
+pub const DEFAULT_MIN_SEARCHABLE_PEERS: u32 = 1;
+
+/// Disk usage (percent of partition capacity) at which health warns.
+pub const DEFAULT_DISK_WARNING_PCT: f64 = 80.0;
+
+/// Disk usage (percent of partition capacity) at which health is critical.
+pub const DEFAULT_DISK_CRITICAL_PCT: f64 = 90.0;
+
 // =============================================================================
 // Test Timing Constants
 // =============================================================================
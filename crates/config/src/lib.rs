@@ -12,14 +12,20 @@ pub mod loader;
 pub mod persistence;
 pub mod types;
 
-pub use loader::{ConfigError, ConfigLoader, SearchDefaultConfig, env_var_or_none};
+pub use loader::{
+    ConfigError, ConfigLoader, SearchDefaultConfig, env_var_or_none, rate_limit_from_env,
+};
 pub use persistence::{
-    ConfigManager, InternalLogsDefaults, ListDefaults, ListType, PersistedOnboardingChecklist,
-    PersistedState, ScrollPositions, SearchDefaults,
+    CachedTeamBundle, ConfigManager, DEFAULT_SNAPSHOT_PROFILE, DataSnapshot, InternalLogsDefaults,
+    ListDefaults, ListType, LogAnomalySettings, NotificationSettings, NotificationTarget,
+    PersistedOnboardingChecklist, PersistedState, ScrollPositions, SearchDefaults, SearchTemplate,
+    SearchTemplateFile, SnapshotKind, TeamBundle, TemplateError, TemplateImportSummary,
+    TemplateParameter, TemplateParameterType, ThemeFile, ThemeLibrary, WorkspaceSession,
 };
 pub use types::{
-    AuthConfig, AuthStrategy, ColorTheme, Config, ConnectionConfig, KeybindAction,
-    KeybindOverrides, ProfileConfig, SecureValue, default_circuit_breaker_enabled,
+    AuthConfig, AuthStrategy, ClipboardBackend, CloudConfig, ColorTheme, Config, ConnectionConfig,
+    HealthThresholds, KeybindAction, KeybindOverrides, NamespaceConfig, ProfileConfig,
+    RateLimitConfig, SecureValue, ThresholdLevel, default_circuit_breaker_enabled,
     default_circuit_failure_threshold, default_circuit_failure_window,
     default_circuit_half_open_requests, default_circuit_reset_timeout,
 };
@@ -31,7 +31,10 @@ use crate::constants::{
     MIN_SESSION_TTL_SECS,
 };
 use crate::persistence::{InternalLogsDefaults, SearchDefaults};
-use crate::types::{AuthConfig, AuthStrategy, Config, ConnectionConfig};
+use crate::types::{
+    AuthConfig, AuthStrategy, CloudConfig, Config, ConnectionConfig, HealthThresholds,
+    NamespaceConfig, RateLimitConfig,
+};
 
 /// Configuration loader that builds config from environment variables and profiles.
 #[derive(Default)]
@@ -51,6 +54,12 @@ pub struct ConfigLoader {
     circuit_failure_window_seconds: Option<u64>,
     circuit_reset_timeout_seconds: Option<u64>,
     circuit_half_open_requests: Option<u32>,
+    rate_limit: Option<RateLimitConfig>,
+    namespace: Option<NamespaceConfig>,
+    cloud: Option<bool>,
+    acs_stack: Option<String>,
+    acs_url: Option<String>,
+    health_thresholds: Option<HealthThresholds>,
     profile_name: Option<String>,
     profile_missing: Option<String>,
     config_path: Option<PathBuf>,
@@ -230,6 +239,30 @@ impl ConfigLoader {
         self
     }
 
+    /// Set the client-side request rate limit.
+    pub fn with_rate_limit(mut self, rate_limit: RateLimitConfig) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Set the default knowledge-object namespace.
+    pub fn with_namespace(mut self, namespace: NamespaceConfig) -> Self {
+        self.namespace = Some(namespace);
+        self
+    }
+
+    /// Mark the deployment as Splunk Cloud (or on-prem), skipping detection.
+    pub fn with_cloud(mut self, cloud: bool) -> Self {
+        self.cloud = Some(cloud);
+        self
+    }
+
+    /// Set the thresholds the aggregated health check flags conditions against.
+    pub fn with_health_thresholds(mut self, thresholds: HealthThresholds) -> Self {
+        self.health_thresholds = Some(thresholds);
+        self
+    }
+
     /// Check if we have a complete configuration (base_url + auth).
     ///
     /// A complete configuration requires:
@@ -299,10 +332,24 @@ impl ConfigLoader {
             circuit_half_open_requests: self
                 .circuit_half_open_requests
                 .unwrap_or(crate::types::connection::default_circuit_half_open_requests()),
+            rate_limit: self.rate_limit,
+            namespace: self.namespace,
+            cloud: (self.cloud.is_some() || self.acs_stack.is_some() || self.acs_url.is_some())
+                .then_some(CloudConfig {
+                    enabled: self.cloud,
+                    acs_stack: self.acs_stack,
+                    acs_url: self.acs_url,
+                }),
+            health_thresholds: self.health_thresholds,
         };
 
         // Validate timeout configuration
         Self::validate_timeout_config(&connection)?;
+        if let Some(thresholds) = &connection.health_thresholds {
+            thresholds
+                .validate()
+                .map_err(|message| ConfigError::InvalidHealthThresholds { message })?;
+        }
 
         Ok(Config {
             connection,
@@ -423,10 +470,12 @@ impl ConfigLoader {
         let InternalLogsDefaults {
             count,
             earliest_time,
+            anomalies,
         } = persisted.unwrap_or_default();
         InternalLogsDefaults {
             count: self.internal_logs_count.unwrap_or(count),
             earliest_time: self.internal_logs_earliest.clone().unwrap_or(earliest_time),
+            anomalies,
         }
     }
 
@@ -529,6 +578,30 @@ impl ConfigLoader {
         self.circuit_half_open_requests = requests;
     }
 
+    pub(crate) fn set_rate_limit(&mut self, rate_limit: Option<RateLimitConfig>) {
+        self.rate_limit = rate_limit;
+    }
+
+    pub(crate) fn set_namespace(&mut self, namespace: Option<NamespaceConfig>) {
+        self.namespace = namespace;
+    }
+
+    pub(crate) fn set_cloud(&mut self, cloud: Option<bool>) {
+        self.cloud = cloud;
+    }
+
+    pub(crate) fn set_acs_stack(&mut self, stack: Option<String>) {
+        self.acs_stack = stack;
+    }
+
+    pub(crate) fn set_acs_url(&mut self, url: Option<String>) {
+        self.acs_url = url;
+    }
+
+    pub(crate) fn set_health_thresholds(&mut self, thresholds: Option<HealthThresholds>) {
+        self.health_thresholds = thresholds;
+    }
+
     pub(crate) fn set_earliest_time(&mut self, earliest: Option<String>) {
         self.earliest_time = earliest;
     }
@@ -22,6 +22,7 @@ use std::time::Duration;
 use super::builder::ConfigLoader;
 use super::error::ConfigError;
 use crate::constants::MAX_MAX_RETRIES;
+use crate::types::{NamespaceConfig, RateLimitConfig};
 
 /// Read an environment variable, returning None if unset, empty, or whitespace-only.
 /// Returns the trimmed value (leading/trailing whitespace removed) if present.
@@ -40,6 +41,23 @@ pub fn env_var_or_none(key: &str) -> Option<String> {
     })
 }
 
+/// Read the client-side rate limit from `SPLUNK_RATE_LIMIT` (`RATE` or `RATE:BURST`).
+///
+/// Exposed so clients built outside the loader (e.g., per-profile fan-out)
+/// honor the same limit.
+pub fn rate_limit_from_env() -> Result<Option<RateLimitConfig>, ConfigError> {
+    env_var_or_none("SPLUNK_RATE_LIMIT")
+        .map(|value| {
+            value
+                .parse::<RateLimitConfig>()
+                .map_err(|message| ConfigError::InvalidValue {
+                    var: "SPLUNK_RATE_LIMIT".to_string(),
+                    message,
+                })
+        })
+        .transpose()
+}
+
 /// Apply environment variable configuration to the loader.
 ///
 /// Environment variables take precedence over profile settings.
@@ -147,6 +165,32 @@ pub fn apply_env(loader: &mut ConfigLoader) -> Result<(), ConfigError> {
             }
         })?));
     }
+    if let Some(rate_limit) = rate_limit_from_env()? {
+        loader.set_rate_limit(Some(rate_limit));
+    }
+    let app = env_var_or_none("SPLUNK_APP");
+    let owner = env_var_or_none("SPLUNK_OWNER");
+    if app.is_some() || owner.is_some() {
+        loader.set_namespace(Some(NamespaceConfig {
+            owner,
+            app,
+            sharing: None,
+        }));
+    }
+    if let Some(cloud) = env_var_or_none("SPLUNK_CLOUD") {
+        loader.set_cloud(Some(cloud.parse().map_err(|_| {
+            ConfigError::InvalidValue {
+                var: "SPLUNK_CLOUD".to_string(),
+                message: "must be true or false".to_string(),
+            }
+        })?));
+    }
+    if let Some(stack) = env_var_or_none("SPLUNK_ACS_STACK") {
+        loader.set_acs_stack(Some(stack));
+    }
+    if let Some(url) = env_var_or_none("SPLUNK_ACS_URL") {
+        loader.set_acs_url(Some(url));
+    }
     // Search defaults
     if let Some(earliest) = env_var_or_none("SPLUNK_EARLIEST_TIME") {
         loader.set_earliest_time(Some(earliest));
@@ -70,6 +70,9 @@ pub enum ConfigError {
     #[error("invalid session expiry buffer: {message}")]
     InvalidExpiryBuffer { message: String },
 
+    #[error("invalid health thresholds: {message}")]
+    InvalidHealthThresholds { message: String },
+
     /// Failed to parse the `.env` file due to invalid syntax.
     ///
     /// SAFETY: This error only includes the byte index of the parse failure,
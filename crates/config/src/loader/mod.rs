@@ -25,5 +25,5 @@ mod tests;
 
 pub use builder::ConfigLoader;
 pub use defaults::SearchDefaultConfig;
-pub use env::env_var_or_none;
+pub use env::{env_var_or_none, rate_limit_from_env};
 pub use error::ConfigError;
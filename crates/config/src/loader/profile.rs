@@ -64,7 +64,8 @@ pub fn apply_profile(loader: &mut ConfigLoader) -> Result<(), ConfigError> {
     let manager = ConfigManager::new_with_path_and_source(config_path, source)
         .map_err(|e| ConfigError::DecryptionFailed(e.to_string()))?;
 
-    let profile = match manager.list_profiles().get(&profile_name) {
+    let profiles = manager.list_profiles();
+    let profile = match profiles.get(&profile_name) {
         Some(p) => p,
         None => {
             loader.set_profile_missing(Some(profile_name));
@@ -111,5 +112,14 @@ fn apply_profile_config(
     if let Some(interval) = profile.health_check_interval_seconds {
         loader.set_health_check_interval_seconds(Some(interval));
     }
+    if let Some(cloud) = profile.cloud {
+        loader.set_cloud(Some(cloud));
+    }
+    if let Some(stack) = &profile.acs_stack {
+        loader.set_acs_stack(Some(stack.clone()));
+    }
+    if let Some(thresholds) = &profile.health_thresholds {
+        loader.set_health_thresholds(Some(thresholds.clone()));
+    }
     Ok(())
 }
@@ -4,6 +4,9 @@
 //! - Test environment variable overrides for profile values.
 //! - Test handling of empty and whitespace-only environment variables.
 //! - Test SPLUNK_CONFIG_PATH environment variable handling.
+//! - Test SPLUNK_RATE_LIMIT parsing.
+//! - Test SPLUNK_APP/SPLUNK_OWNER namespace defaults.
+//! - Test SPLUNK_CLOUD/SPLUNK_ACS_* cloud settings.
 
 use crate::loader::builder::ConfigLoader;
 use crate::loader::env::env_var_or_none;
@@ -439,3 +442,123 @@ fn test_cli_config_path_override_env_var() {
         },
     );
 }
+
+#[test]
+#[serial]
+fn test_splunk_rate_limit_env_var() {
+    let _lock = env_lock().lock().unwrap();
+
+    temp_env::with_vars(
+        [
+            ("SPLUNK_BASE_URL", Some("https://localhost:8089")),
+            ("SPLUNK_API_TOKEN", Some("token")),
+            ("SPLUNK_RATE_LIMIT", Some(" 5:10 ")),
+        ],
+        || {
+            let config = ConfigLoader::new().from_env().unwrap().build().unwrap();
+            let limit = config.connection.rate_limit.expect("rate limit set");
+            assert_eq!(limit.requests_per_second, 5.0);
+            assert_eq!(limit.burst, 10);
+        },
+    );
+
+    temp_env::with_vars(
+        [
+            ("SPLUNK_BASE_URL", Some("https://localhost:8089")),
+            ("SPLUNK_API_TOKEN", Some("token")),
+            ("SPLUNK_RATE_LIMIT", None),
+        ],
+        || {
+            let config = ConfigLoader::new().from_env().unwrap().build().unwrap();
+            assert!(config.connection.rate_limit.is_none());
+        },
+    );
+}
+
+#[test]
+#[serial]
+fn test_invalid_splunk_rate_limit_env_var_rejected() {
+    let _lock = env_lock().lock().unwrap();
+
+    temp_env::with_vars([("SPLUNK_RATE_LIMIT", Some("fast"))], || {
+        let result = ConfigLoader::new().from_env();
+        assert!(matches!(
+            result,
+            Err(crate::loader::error::ConfigError::InvalidValue { ref var, .. })
+                if var == "SPLUNK_RATE_LIMIT"
+        ));
+    });
+}
+
+#[test]
+#[serial]
+fn test_splunk_app_and_owner_env_vars_set_namespace() {
+    let _lock = env_lock().lock().unwrap();
+
+    temp_env::with_vars(
+        [
+            ("SPLUNK_BASE_URL", Some("https://localhost:8089")),
+            ("SPLUNK_API_TOKEN", Some("token")),
+            ("SPLUNK_APP", Some(" my_app ")),
+            ("SPLUNK_OWNER", None),
+        ],
+        || {
+            let config = ConfigLoader::new().from_env().unwrap().build().unwrap();
+            let namespace = config.connection.namespace.expect("namespace set");
+            assert_eq!(namespace.app.as_deref(), Some("my_app"));
+            assert_eq!(namespace.owner, None);
+        },
+    );
+
+    temp_env::with_vars(
+        [
+            ("SPLUNK_BASE_URL", Some("https://localhost:8089")),
+            ("SPLUNK_API_TOKEN", Some("token")),
+            ("SPLUNK_APP", Some("  ")),
+            ("SPLUNK_OWNER", None),
+        ],
+        || {
+            let config = ConfigLoader::new().from_env().unwrap().build().unwrap();
+            assert!(config.connection.namespace.is_none());
+        },
+    );
+}
+
+#[test]
+#[serial]
+fn test_splunk_cloud_env_vars_set_cloud_config() {
+    let _lock = env_lock().lock().unwrap();
+
+    temp_env::with_vars(
+        [
+            ("SPLUNK_BASE_URL", Some("https://acme.splunkcloud.com:8089")),
+            ("SPLUNK_API_TOKEN", Some("token")),
+            ("SPLUNK_CLOUD", Some("true")),
+            ("SPLUNK_ACS_STACK", Some("acme")),
+            ("SPLUNK_ACS_URL", None),
+        ],
+        || {
+            let config = ConfigLoader::new().from_env().unwrap().build().unwrap();
+            let cloud = config.connection.cloud.expect("cloud set");
+            assert_eq!(cloud.enabled, Some(true));
+            assert_eq!(cloud.acs_stack.as_deref(), Some("acme"));
+            assert_eq!(cloud.acs_url, None);
+        },
+    );
+
+    temp_env::with_vars(
+        [
+            ("SPLUNK_CLOUD", Some("yes")),
+            ("SPLUNK_ACS_STACK", None),
+            ("SPLUNK_ACS_URL", None),
+        ],
+        || {
+            let result = ConfigLoader::new().from_env();
+            assert!(matches!(
+                result,
+                Err(crate::loader::error::ConfigError::InvalidValue { ref var, .. })
+                    if var == "SPLUNK_CLOUD"
+            ));
+        },
+    );
+}
@@ -72,6 +72,7 @@ fn test_build_internal_logs_defaults_with_persisted() {
             let persisted = InternalLogsDefaults {
                 count: 100,
                 earliest_time: "-15m".to_string(),
+                ..Default::default()
             };
 
             let defaults = loader.build_internal_logs_defaults(Some(persisted));
@@ -124,6 +125,7 @@ fn test_internal_logs_defaults_env_vars_override_persisted() {
             let persisted = InternalLogsDefaults {
                 count: 200,
                 earliest_time: "-1h".to_string(),
+                ..Default::default()
             };
 
             let defaults = loader.build_internal_logs_defaults(Some(persisted));
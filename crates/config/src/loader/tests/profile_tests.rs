@@ -241,3 +241,42 @@ fn test_profile_missing_with_complete_session_auth_override() {
         AuthStrategy::SessionToken { .. }
     ));
 }
+
+#[test]
+fn test_profile_health_thresholds_are_loaded_and_validated() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("config.json");
+    let config = serde_json::json!({
+        "profiles": {
+            "prod": {
+                "base_url": "https://prod.splunk.com:8089",
+                "api_token": "prod-token-123",
+                "health_thresholds": {"license_warning_pct": 80, "min_searchable_peers": 3}
+            },
+            "broken": {
+                "base_url": "https://prod.splunk.com:8089",
+                "api_token": "prod-token-123",
+                "health_thresholds": {"disk_warning_pct": 95, "disk_critical_pct": 90}
+            }
+        }
+    });
+    std::fs::write(&config_path, config.to_string()).unwrap();
+    let load = |profile: &str| {
+        ConfigLoader::new()
+            .with_profile_name(profile.to_string())
+            .with_config_path(config_path.clone())
+            .from_profile()
+            .unwrap()
+            .build()
+    };
+
+    let thresholds = load("prod").unwrap().connection.health_thresholds.unwrap();
+    assert_eq!(thresholds.license_warning_pct, 80.0);
+    assert_eq!(thresholds.license_critical_pct, 90.0);
+    assert_eq!(thresholds.min_searchable_peers, 3);
+
+    assert!(matches!(
+        load("broken"),
+        Err(ConfigError::InvalidHealthThresholds { .. })
+    ));
+}
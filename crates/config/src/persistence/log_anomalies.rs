@@ -0,0 +1,107 @@
+//! Persisted thresholds for internal logs anomaly highlighting.
+//!
+//! Responsibilities:
+//! - Define `LogAnomalySettings`, stored under `internal_logs_defaults.anomalies`.
+//! - Normalize invalid thresholds back to their defaults.
+//!
+//! Does NOT handle:
+//! - Detecting anomalies (the TUI runs detectors over loaded log entries).
+//!
+//! Invariants:
+//! - Missing fields deserialize to defaults, so older config files load unchanged.
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{
+    DEFAULT_LOG_REPEAT_THRESHOLD, DEFAULT_LOG_SPIKE_MIN_COUNT, DEFAULT_LOG_SPIKE_MULTIPLIER,
+};
+
+/// Thresholds for highlighting anomalies in the internal logs view.
+///
+/// Detection runs client-side over the loaded entries; these values only
+/// control what counts as unusual.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogAnomalySettings {
+    /// Whether anomaly highlighting and repeat collapsing are enabled.
+    pub enabled: bool,
+    /// A minute's ERROR or WARN count must reach this multiple of the session
+    /// baseline (average per minute) to be flagged as a spike.
+    pub spike_multiplier: f64,
+    /// Minimum ERROR or WARN count in a minute before it can be a spike.
+    pub spike_min_count: usize,
+    /// Collapsed identical messages are highlighted at this many occurrences.
+    pub repeat_threshold: usize,
+}
+
+impl Default for LogAnomalySettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            spike_multiplier: DEFAULT_LOG_SPIKE_MULTIPLIER,
+            spike_min_count: DEFAULT_LOG_SPIKE_MIN_COUNT,
+            repeat_threshold: DEFAULT_LOG_REPEAT_THRESHOLD,
+        }
+    }
+}
+
+impl LogAnomalySettings {
+    /// Sanitize thresholds to enforce invariants.
+    ///
+    /// Normalizes invalid values to their defaults:
+    /// - `spike_multiplier` not finite or `<= 1.0` -> 2.0
+    /// - `spike_min_count == 0` -> 3
+    /// - `repeat_threshold < 2` -> 5
+    pub fn sanitize(&self) -> Self {
+        Self {
+            enabled: self.enabled,
+            spike_multiplier: if self.spike_multiplier.is_finite() && self.spike_multiplier > 1.0 {
+                self.spike_multiplier
+            } else {
+                DEFAULT_LOG_SPIKE_MULTIPLIER
+            },
+            spike_min_count: if self.spike_min_count == 0 {
+                DEFAULT_LOG_SPIKE_MIN_COUNT
+            } else {
+                self.spike_min_count
+            },
+            repeat_threshold: if self.repeat_threshold < 2 {
+                DEFAULT_LOG_REPEAT_THRESHOLD
+            } else {
+                self.repeat_threshold
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_anomaly_settings_sanitize_and_missing_fields() {
+        let deserialized: LogAnomalySettings =
+            serde_json::from_str(r#"{"spike_multiplier": 3.0}"#).unwrap();
+        assert!(deserialized.enabled);
+        assert_eq!(deserialized.spike_multiplier, 3.0);
+        assert_eq!(deserialized.repeat_threshold, 5);
+
+        let settings = LogAnomalySettings {
+            enabled: false,
+            spike_multiplier: 0.5,
+            spike_min_count: 0,
+            repeat_threshold: 1,
+        };
+        let sanitized = settings.sanitize();
+        assert!(!sanitized.enabled);
+        assert_eq!(sanitized.spike_multiplier, 2.0);
+        assert_eq!(sanitized.spike_min_count, 3);
+        assert_eq!(sanitized.repeat_threshold, 5);
+
+        let nan = LogAnomalySettings {
+            spike_multiplier: f64::NAN,
+            ..Default::default()
+        };
+        assert_eq!(nan.sanitize().spike_multiplier, 2.0);
+    }
+}
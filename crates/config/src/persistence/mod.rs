@@ -6,6 +6,12 @@
 //! - Read and write user preferences (`PersistedState`) to disk.
 //! - Manage multiple configuration profiles and their secure values.
 //! - Backup corrupt config files before overwriting.
+//! - Cache and layer read-only team config bundles beneath personal settings.
+//! - Store the personal search template library and its shareable file format.
+//! - Store per-profile snapshots of recent server responses for offline viewing.
+//! - Define saved investigation sessions (named TUI workspaces) kept in `PersistedState`.
+//! - Load user-defined TOML color themes from the `themes/` directory beside the user config.
+//! - Define the TUI notification settings kept in `PersistedState`.
 //!
 //! Does NOT handle:
 //! - Loading environment variables (see `loader.rs`).
@@ -20,12 +26,26 @@
 
 use std::path::{Path, PathBuf};
 
+mod log_anomalies;
 mod migration;
+mod notifications;
 mod path;
 mod profiles;
+mod sessions;
+mod snapshots;
 mod state;
+mod team;
+mod templates;
+mod themes;
 
+pub use log_anomalies::LogAnomalySettings;
+pub use notifications::{NotificationSettings, NotificationTarget};
 pub use profiles::{ConfigManager, CredentialStorageError};
+pub use sessions::{MAX_SAVED_SESSIONS, WorkspaceSession, remove_session, save_session};
+pub use snapshots::{
+    DEFAULT_SNAPSHOT_PROFILE, DataSnapshot, SNAPSHOT_FILE_VERSION, SNAPSHOTS_FILE_NAME,
+    SnapshotKind, read_snapshot, snapshots_path, write_snapshot,
+};
 pub use state::{
     ConfigFileError, InternalLogsDefaults, ListDefaults, ListType, PersistedOnboardingChecklist,
     PersistedState, ScrollPositions, SearchDefaults,
@@ -34,6 +54,18 @@ pub use state::{
 // Re-exports for use by other crates
 pub(crate) use migration::migrate_config_file_if_needed;
 pub use path::{default_config_path, legacy_config_path};
+pub use team::{
+    CachedTeamBundle, TEAM_BUNDLE_FILE_NAME, TeamBundle, TeamBundleError, team_bundle_path,
+};
+pub use templates::{
+    SEARCH_TEMPLATE_FILE_VERSION, SEARCH_TEMPLATES_FILE_NAME, SearchTemplate, SearchTemplateFile,
+    TemplateError, TemplateFileError, TemplateImportSummary, TemplateParameter,
+    TemplateParameterType, search_templates_path,
+};
+pub use themes::{
+    HeaderPalette, LogPalette, SeverityPalette, SyntaxPalette, THEMES_DIR_NAME, TablePalette,
+    ThemeFile, ThemeLibrary, UiPalette, read_theme_files, themes_dir,
+};
 
 /// Creates a backup of a corrupt config file before it is overwritten.
 ///
@@ -0,0 +1,134 @@
+//! Persisted notification settings for the TUI.
+//!
+//! Responsibilities:
+//! - Define `NotificationSettings`, stored under `notifications` in the persisted state.
+//! - Provide display helpers and cycle order for the Settings screen.
+//!
+//! Does NOT handle:
+//! - Sending notifications (see `splunk-tui::app::notifier`).
+//!
+//! Invariants:
+//! - Notifications are off unless the user turns them on.
+//! - Missing fields deserialize to defaults, so older config files load unchanged.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::constants::DEFAULT_NOTIFY_MIN_SEARCH_SECS;
+
+/// Where the TUI sends notifications.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationTarget {
+    #[default]
+    Off,
+    /// Desktop notification (requires a TUI built with the `notify` feature).
+    Desktop,
+    /// Run `NotificationSettings::command`.
+    Command,
+}
+
+impl NotificationTarget {
+    /// Human-readable display name for UI surfaces.
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Self::Off => "Off",
+            Self::Desktop => "Desktop",
+            Self::Command => "Command",
+        }
+    }
+
+    /// Next target in the cycle (used by Settings screen "N" key).
+    pub fn cycle_next(self) -> Self {
+        match self {
+            Self::Off => Self::Desktop,
+            Self::Desktop => Self::Command,
+            Self::Command => Self::Off,
+        }
+    }
+}
+
+impl fmt::Display for NotificationTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.display_name())
+    }
+}
+
+/// When and how the TUI notifies about work finishing while it runs.
+///
+/// Notifications are sent when a search that ran for at least
+/// `min_search_secs` completes and when the health check turns unhealthy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationSettings {
+    pub target: NotificationTarget,
+    /// Shell command run for `NotificationTarget::Command`. It receives the
+    /// title and body in `SPLUNK_NOTIFY_TITLE` and `SPLUNK_NOTIFY_BODY`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    /// Searches shorter than this many seconds finish without a notification.
+    pub min_search_secs: u64,
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            target: NotificationTarget::Off,
+            command: None,
+            min_search_secs: DEFAULT_NOTIFY_MIN_SEARCH_SECS,
+        }
+    }
+}
+
+impl NotificationSettings {
+    /// The configured command, if it is not blank.
+    pub fn command(&self) -> Option<&str> {
+        self.command
+            .as_deref()
+            .map(str::trim)
+            .filter(|command| !command.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notification_settings_missing_fields_and_serde() {
+        let settings: NotificationSettings =
+            serde_json::from_str(r#"{"target": "command", "command": "notify-send hi"}"#).unwrap();
+        assert_eq!(settings.target, NotificationTarget::Command);
+        assert_eq!(settings.command(), Some("notify-send hi"));
+        assert_eq!(settings.min_search_secs, DEFAULT_NOTIFY_MIN_SEARCH_SECS);
+
+        let blank = NotificationSettings {
+            command: Some("  ".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(blank.command(), None);
+        assert_eq!(
+            serde_json::to_value(NotificationSettings::default()).unwrap(),
+            serde_json::json!({"target": "off", "min_search_secs": 30})
+        );
+    }
+
+    #[test]
+    fn test_notification_target_cycle_visits_every_target() {
+        let mut target = NotificationTarget::Off;
+        let mut seen = Vec::new();
+        for _ in 0..3 {
+            seen.push(target);
+            target = target.cycle_next();
+        }
+        assert_eq!(target, NotificationTarget::Off);
+        assert_eq!(
+            seen,
+            vec![
+                NotificationTarget::Off,
+                NotificationTarget::Desktop,
+                NotificationTarget::Command
+            ]
+        );
+    }
+}
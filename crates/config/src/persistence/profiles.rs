@@ -16,6 +16,7 @@
 //! - Writes are atomic (temp file + rename).
 //! - Plain text secrets are never written when keyring is enabled.
 
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::path::PathBuf;
 
@@ -52,6 +53,11 @@ pub enum CredentialStorageError {
 use super::migration::migrate_config_file_if_needed;
 use super::path::{default_config_path, legacy_config_path};
 use super::state::{ConfigFile, ConfigFileError, ConfigStorage, PersistedState, read_config_file};
+use super::team::{
+    CachedTeamBundle, layer_team_bundle, read_cached_team_bundle, strip_team_bundle,
+    team_bundle_path, write_cached_team_bundle,
+};
+use super::templates::{SearchTemplate, read_search_templates, search_templates_path};
 use crate::env_var_or_none;
 
 /// Manages loading and saving user configuration to disk.
@@ -64,6 +70,10 @@ pub struct ConfigManager {
     master_key_source: MasterKeySource,
     /// Whether to encrypt the config file on save.
     use_encryption: bool,
+    /// Read-only team bundle layered beneath the personal config, if synced.
+    team_bundle: Option<CachedTeamBundle>,
+    /// Personal search template library (`search-templates.json`).
+    pub(super) search_templates: Vec<SearchTemplate>,
 }
 
 impl ConfigManager {
@@ -182,11 +192,16 @@ impl ConfigManager {
             ConfigFile::default()
         };
 
+        let team_bundle = read_cached_team_bundle(&team_bundle_path(&config_path));
+        let search_templates = read_search_templates(&search_templates_path(&config_path));
+
         Ok(Self {
             config_path,
             config_file,
             master_key_source,
             use_encryption,
+            team_bundle,
+            search_templates,
         })
     }
 
@@ -221,11 +236,15 @@ impl ConfigManager {
     /// Loads persisted state from disk.
     ///
     /// Returns default state if the file doesn't exist or cannot be read.
-    /// Invalid persisted values are sanitized to their defaults.
+    /// Invalid persisted values are sanitized to their defaults, and the
+    /// team bundle (if synced) is layered beneath the personal settings.
     pub fn load(&self) -> PersistedState {
         let mut state = self.config_file.state.clone().unwrap_or_default();
         state.search_defaults = state.search_defaults.sanitize();
         state.internal_logs_defaults = state.internal_logs_defaults.sanitize();
+        if let Some(team) = &self.team_bundle {
+            layer_team_bundle(&mut state, &team.bundle);
+        }
         state
     }
 
@@ -238,10 +257,52 @@ impl ConfigManager {
     /// or the file cannot be written.
     pub fn save(&mut self, state: &PersistedState) -> Result<()> {
         // Update the state while preserving profiles
-        self.config_file.state = Some(state.clone());
+        let mut state = state.clone();
+        if let Some(team) = &self.team_bundle {
+            strip_team_bundle(&mut state, &team.bundle);
+        }
+        self.config_file.state = Some(state);
         self.atomic_save()
     }
 
+    /// Returns the synced team bundle, if any.
+    pub fn team_bundle(&self) -> Option<&CachedTeamBundle> {
+        self.team_bundle.as_ref()
+    }
+
+    /// Returns the path of the team bundle cache for this config file.
+    pub fn team_bundle_path(&self) -> PathBuf {
+        team_bundle_path(&self.config_path)
+    }
+
+    /// Returns the search templates shared by the team bundle.
+    pub fn team_search_templates(&self) -> &[SearchTemplate] {
+        self.team_bundle
+            .as_ref()
+            .map(|team| team.bundle.search_templates.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Validates and caches a freshly synced team bundle.
+    ///
+    /// # Errors
+    /// Returns an error if the bundle is invalid or the cache cannot be written.
+    pub fn install_team_bundle(&mut self, cached: CachedTeamBundle) -> Result<()> {
+        cached.bundle.validate()?;
+        write_cached_team_bundle(&self.team_bundle_path(), &cached)?;
+        self.team_bundle = Some(cached);
+        Ok(())
+    }
+
+    /// Returns true if `name` is only defined by the team bundle.
+    pub fn is_team_profile(&self, name: &str) -> bool {
+        !self.config_file.profiles.contains_key(name)
+            && self
+                .team_bundle
+                .as_ref()
+                .is_some_and(|team| team.bundle.profiles.contains_key(name))
+    }
+
     /// Moves a profile's password to the system keyring.
     pub fn move_password_to_keyring(&mut self, profile_name: &str) -> Result<()> {
         let profile = self
@@ -408,9 +469,19 @@ impl ConfigManager {
             })
     }
 
-    /// Returns a reference to all configured profiles.
-    pub fn list_profiles(&self) -> &BTreeMap<String, ProfileConfig> {
-        &self.config_file.profiles
+    /// Returns all configured profiles.
+    ///
+    /// Team bundle profiles are included beneath personal ones; a personal
+    /// profile with the same name takes precedence.
+    pub fn list_profiles(&self) -> Cow<'_, BTreeMap<String, ProfileConfig>> {
+        match &self.team_bundle {
+            Some(team) if !team.bundle.profiles.is_empty() => {
+                let mut profiles = team.bundle.profiles.clone();
+                profiles.extend(self.config_file.profiles.clone());
+                Cow::Owned(profiles)
+            }
+            _ => Cow::Borrowed(&self.config_file.profiles),
+        }
     }
 
     /// Enables encryption for the configuration file.
@@ -449,6 +520,12 @@ impl ConfigManager {
     /// Also cleans up any keyring entries associated with the profile.
     pub fn delete_profile(&mut self, name: &str) -> Result<()> {
         // Get profile before removing to check for keyring entries
+        if self.is_team_profile(name) {
+            anyhow::bail!(
+                "Profile '{}' comes from the team bundle and cannot be deleted",
+                name
+            );
+        }
         let profile = self
             .config_file
             .profiles
@@ -532,7 +609,9 @@ impl ConfigManager {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::constants::MIGRATION_DELAY_MS;
+    use crate::constants::{
+        DEFAULT_INTERNAL_LOGS_COUNT, DEFAULT_INTERNAL_LOGS_EARLIEST_TIME, MIGRATION_DELAY_MS,
+    };
     use secrecy::SecretString;
     use serial_test::serial;
     use tempfile::NamedTempFile;
@@ -558,6 +637,9 @@ mod tests {
                     session_expiry_buffer_seconds: Some(60),
                     session_ttl_seconds: Some(3600),
                     health_check_interval_seconds: None,
+                    cloud: None,
+                    acs_stack: None,
+                    health_thresholds: None,
                 },
             );
 
@@ -590,19 +672,14 @@ mod tests {
                 internal_logs_defaults: crate::persistence::state::InternalLogsDefaults {
                     count: 0,
                     earliest_time: "   ".to_string(),
+                    ..Default::default()
                 },
                 ..Default::default()
             });
 
-            let state = manager.load();
-            assert_eq!(
-                state.internal_logs_defaults.count,
-                crate::constants::DEFAULT_INTERNAL_LOGS_COUNT
-            );
-            assert_eq!(
-                state.internal_logs_defaults.earliest_time,
-                crate::constants::DEFAULT_INTERNAL_LOGS_EARLIEST_TIME
-            );
+            let defaults = manager.load().internal_logs_defaults;
+            assert_eq!(defaults.count, DEFAULT_INTERNAL_LOGS_COUNT);
+            assert_eq!(defaults.earliest_time, DEFAULT_INTERNAL_LOGS_EARLIEST_TIME);
         });
     }
 
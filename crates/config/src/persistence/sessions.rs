@@ -0,0 +1,140 @@
+//! Saved investigation sessions (named TUI workspaces).
+//!
+//! Responsibilities:
+//! - Define `WorkspaceSession`, a named snapshot of the TUI workspace (screen, query,
+//!   time range, filters, selections, scroll positions).
+//! - Add, look up, and remove sessions in `PersistedState`.
+//!
+//! Does NOT handle:
+//! - Capturing or restoring the workspace (see the TUI `app::sessions` module).
+//! - Persisting to disk (sessions are saved with the rest of `PersistedState`).
+//!
+//! Invariants:
+//! - Session names are unique; saving under an existing name replaces that session.
+//! - Sessions are kept most recently saved first.
+//! - Screens, selections, and filters are stored as plain strings and indices so the
+//!   config crate stays independent of TUI types.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::state::{PersistedState, ScrollPositions};
+
+/// Maximum number of saved sessions; saving more drops the least recently saved.
+pub const MAX_SAVED_SESSIONS: usize = 50;
+
+/// A named snapshot of the TUI workspace.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkspaceSession {
+    /// Session name, e.g. `incident-1234`
+    pub name: String,
+    /// Unix timestamp (seconds) when the session was saved
+    #[serde(default)]
+    pub saved_at: u64,
+    /// Profile active when the session was saved
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    /// Active screen (maps to `CurrentScreen` variants)
+    #[serde(default)]
+    pub current_screen: String,
+    /// Search query text
+    #[serde(default)]
+    pub search_query: String,
+    /// Search earliest time
+    #[serde(default)]
+    pub earliest_time: String,
+    /// Search latest time
+    #[serde(default)]
+    pub latest_time: String,
+    /// JSON path filter over search results
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result_filter: Option<String>,
+    /// Jobs screen text filter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jobs_filter: Option<String>,
+    /// Selected row per list screen (screen name -> index)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub selections: BTreeMap<String, usize>,
+    /// Scroll positions at save time
+    #[serde(default)]
+    pub scroll_positions: ScrollPositions,
+}
+
+impl PersistedState {
+    /// Returns the saved session named `name`.
+    pub fn saved_session(&self, name: &str) -> Option<&WorkspaceSession> {
+        self.saved_sessions
+            .iter()
+            .find(|session| session.name == name)
+    }
+
+    /// Saves `session` first in the list, replacing any session with the same name.
+    pub fn save_session(&mut self, session: WorkspaceSession) {
+        save_session(&mut self.saved_sessions, session);
+    }
+
+    /// Removes the session named `name`, returning whether it existed.
+    pub fn remove_session(&mut self, name: &str) -> bool {
+        remove_session(&mut self.saved_sessions, name)
+    }
+}
+
+/// Saves `session` first in `sessions`, replacing any session with the same name.
+pub fn save_session(sessions: &mut Vec<WorkspaceSession>, session: WorkspaceSession) {
+    sessions.retain(|existing| existing.name != session.name);
+    sessions.insert(0, session);
+    sessions.truncate(MAX_SAVED_SESSIONS);
+}
+
+/// Removes the session named `name` from `sessions`, returning whether it existed.
+pub fn remove_session(sessions: &mut Vec<WorkspaceSession>, name: &str) -> bool {
+    let before = sessions.len();
+    sessions.retain(|session| session.name != name);
+    sessions.len() != before
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(name: &str, query: &str) -> WorkspaceSession {
+        WorkspaceSession {
+            name: name.to_string(),
+            search_query: query.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_saving_replaces_same_name_and_keeps_recent_first() {
+        let mut state = PersistedState::default();
+        state.save_session(session("incident-1", "index=a"));
+        state.save_session(session("incident-2", "index=b"));
+        state.save_session(session("incident-1", "index=c"));
+
+        let names: Vec<&str> = state
+            .saved_sessions
+            .iter()
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["incident-1", "incident-2"]);
+        assert_eq!(
+            state.saved_session("incident-1").unwrap().search_query,
+            "index=c"
+        );
+
+        assert!(state.remove_session("incident-2"));
+        assert!(!state.remove_session("incident-2"));
+        assert!(state.saved_session("incident-2").is_none());
+    }
+
+    #[test]
+    fn test_state_without_sessions_deserializes() {
+        let state: PersistedState = serde_json::from_str(
+            r#"{"auto_refresh":false,"sort_column":"sid","sort_direction":"asc","last_search_query":null}"#,
+        )
+        .unwrap();
+        assert!(state.saved_sessions.is_empty());
+    }
+}
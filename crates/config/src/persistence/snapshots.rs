@@ -0,0 +1,269 @@
+//! Offline snapshots of the most recent successful server responses.
+//!
+//! Responsibilities:
+//! - Define the snapshot kinds (indexes, jobs, health) and the on-disk snapshot file.
+//! - Read and atomically write per-profile snapshots next to the user config.
+//!
+//! Does NOT handle:
+//! - Deciding when the server is unreachable (see the TUI runtime and `splunk-cli --offline`).
+//! - Interpreting snapshot payloads; data is stored as opaque JSON.
+//!
+//! Invariants:
+//! - Snapshots live at `snapshots.json` beside the user config file.
+//! - Each profile keeps at most one snapshot per kind; saving replaces the previous one.
+//! - An unreadable or invalid snapshot file behaves like an empty cache.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::profiles::ConfigManager;
+
+/// File name of the offline snapshot cache, stored beside the user config file.
+pub const SNAPSHOTS_FILE_NAME: &str = "snapshots.json";
+
+/// Current version of the snapshot file format.
+pub const SNAPSHOT_FILE_VERSION: u32 = 1;
+
+/// Key used for snapshots taken without a named profile.
+pub const DEFAULT_SNAPSHOT_PROFILE: &str = "default";
+
+/// Data sets that are snapshotted for offline viewing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SnapshotKind {
+    /// Index list
+    Indexes,
+    /// Search job list
+    Jobs,
+    /// Aggregated health check output
+    Health,
+}
+
+impl std::fmt::Display for SnapshotKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Indexes => "indexes",
+            Self::Jobs => "jobs",
+            Self::Health => "health",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A saved server response and when it was received.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataSnapshot {
+    /// Unix timestamp (seconds) when the response was saved
+    pub saved_at: u64,
+    /// Response payload as JSON
+    pub data: serde_json::Value,
+}
+
+/// On-disk snapshot cache: profile name -> kind -> snapshot.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct SnapshotFile {
+    version: u32,
+    #[serde(default)]
+    profiles: BTreeMap<String, BTreeMap<SnapshotKind, DataSnapshot>>,
+}
+
+impl ConfigManager {
+    /// Returns the path of the offline snapshot cache for this config file.
+    pub fn snapshots_path(&self) -> PathBuf {
+        snapshots_path(self.config_path())
+    }
+
+    /// Saves `data` as the latest `kind` snapshot for `profile`.
+    ///
+    /// # Errors
+    /// Returns an error if `data` cannot be serialized or the cache cannot be written.
+    pub fn save_snapshot<T: Serialize>(
+        &self,
+        profile: Option<&str>,
+        kind: SnapshotKind,
+        data: &T,
+    ) -> Result<()> {
+        let snapshot = DataSnapshot {
+            saved_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            data: serde_json::to_value(data).context("Failed to serialize snapshot data")?,
+        };
+        write_snapshot(&self.snapshots_path(), profile, kind, snapshot)
+    }
+
+    /// Returns the latest `kind` snapshot for `profile`, if one was saved.
+    pub fn load_snapshot(&self, profile: Option<&str>, kind: SnapshotKind) -> Option<DataSnapshot> {
+        read_snapshot(&self.snapshots_path(), profile, kind)
+    }
+}
+
+/// Returns the snapshot cache path for a given user config path.
+pub fn snapshots_path(config_path: &Path) -> PathBuf {
+    config_path.with_file_name(SNAPSHOTS_FILE_NAME)
+}
+
+fn profile_key(profile: Option<&str>) -> String {
+    profile.unwrap_or(DEFAULT_SNAPSHOT_PROFILE).to_string()
+}
+
+/// Reads the snapshot cache, ignoring (and logging) an unusable file.
+fn read_snapshot_file(path: &Path) -> SnapshotFile {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return SnapshotFile::default(),
+        Err(e) => {
+            tracing::warn!(path = %path.display(), error = %e, "Failed to read snapshots");
+            return SnapshotFile::default();
+        }
+    };
+
+    match serde_json::from_str::<SnapshotFile>(&content) {
+        Ok(file) if file.version == SNAPSHOT_FILE_VERSION => file,
+        Ok(file) => {
+            tracing::warn!(
+                path = %path.display(),
+                version = file.version,
+                "Ignoring snapshots file with unsupported version"
+            );
+            SnapshotFile::default()
+        }
+        Err(e) => {
+            tracing::warn!(path = %path.display(), error = %e, "Ignoring invalid snapshots file");
+            SnapshotFile::default()
+        }
+    }
+}
+
+/// Reads one snapshot from the cache at `path`.
+pub fn read_snapshot(
+    path: &Path,
+    profile: Option<&str>,
+    kind: SnapshotKind,
+) -> Option<DataSnapshot> {
+    read_snapshot_file(path)
+        .profiles
+        .remove(&profile_key(profile))
+        .and_then(|mut snapshots| snapshots.remove(&kind))
+}
+
+/// Replaces one snapshot in the cache at `path` and atomically rewrites it (temp file + rename).
+pub fn write_snapshot(
+    path: &Path,
+    profile: Option<&str>,
+    kind: SnapshotKind,
+    snapshot: DataSnapshot,
+) -> Result<()> {
+    let mut file = read_snapshot_file(path);
+    file.version = SNAPSHOT_FILE_VERSION;
+    file.profiles
+        .entry(profile_key(profile))
+        .or_default()
+        .insert(kind, snapshot);
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).context("Failed to create config directory")?;
+    }
+
+    let temp_path = path.with_extension("tmp");
+    let content = serde_json::to_string(&file)?;
+    std::fs::write(&temp_path, content).context("Failed to write temporary snapshots file")?;
+    std::fs::rename(&temp_path, path).context("Failed to rename temporary snapshots file")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn snapshot(saved_at: u64, data: serde_json::Value) -> DataSnapshot {
+        DataSnapshot { saved_at, data }
+    }
+
+    #[test]
+    fn test_snapshots_are_kept_per_profile_and_kind() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(SNAPSHOTS_FILE_NAME);
+
+        write_snapshot(
+            &path,
+            Some("prod"),
+            SnapshotKind::Indexes,
+            snapshot(1, serde_json::json!(["main"])),
+        )
+        .unwrap();
+        write_snapshot(
+            &path,
+            None,
+            SnapshotKind::Indexes,
+            snapshot(2, serde_json::json!(["dev"])),
+        )
+        .unwrap();
+        write_snapshot(
+            &path,
+            Some("prod"),
+            SnapshotKind::Jobs,
+            snapshot(3, serde_json::json!([])),
+        )
+        .unwrap();
+
+        let prod = read_snapshot(&path, Some("prod"), SnapshotKind::Indexes).unwrap();
+        assert_eq!(prod, snapshot(1, serde_json::json!(["main"])));
+        let default = read_snapshot(&path, None, SnapshotKind::Indexes).unwrap();
+        assert_eq!(default.saved_at, 2);
+        assert!(read_snapshot(&path, Some("prod"), SnapshotKind::Health).is_none());
+        assert!(read_snapshot(&path, Some("staging"), SnapshotKind::Indexes).is_none());
+    }
+
+    #[test]
+    fn test_saving_replaces_previous_snapshot() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(SNAPSHOTS_FILE_NAME);
+
+        write_snapshot(
+            &path,
+            None,
+            SnapshotKind::Health,
+            snapshot(1, serde_json::json!({"a": 1})),
+        )
+        .unwrap();
+        write_snapshot(
+            &path,
+            None,
+            SnapshotKind::Health,
+            snapshot(5, serde_json::json!({"a": 2})),
+        )
+        .unwrap();
+
+        let latest = read_snapshot(&path, None, SnapshotKind::Health).unwrap();
+        assert_eq!(latest, snapshot(5, serde_json::json!({"a": 2})));
+    }
+
+    #[test]
+    fn test_invalid_file_is_treated_as_empty() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(SNAPSHOTS_FILE_NAME);
+        std::fs::write(&path, "not json").unwrap();
+
+        assert!(read_snapshot(&path, None, SnapshotKind::Jobs).is_none());
+        write_snapshot(
+            &path,
+            None,
+            SnapshotKind::Jobs,
+            snapshot(7, serde_json::json!([])),
+        )
+        .unwrap();
+        assert_eq!(
+            read_snapshot(&path, None, SnapshotKind::Jobs)
+                .unwrap()
+                .saved_at,
+            7
+        );
+    }
+}
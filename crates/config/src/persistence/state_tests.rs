@@ -0,0 +1,823 @@
+//! Unit tests for persisted state defaults, serialization, and config file reading.
+
+use super::*;
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+#[test]
+fn test_persisted_state_default() {
+    let state = PersistedState::default();
+    assert!(!state.auto_refresh);
+    assert_eq!(state.sort_column, "sid");
+    assert_eq!(state.sort_direction, "asc");
+    assert!(state.last_search_query.is_none());
+    assert!(state.search_history.is_empty());
+    assert_eq!(state.selected_theme, ColorTheme::Default);
+    assert_eq!(state.clipboard_backend, ClipboardBackend::Auto);
+    assert!(state.rest_history.is_empty());
+    assert!(!state.tutorial_completed);
+    // New fields
+    assert_eq!(state.current_screen, "Search");
+    assert_eq!(state.scroll_positions.search_scroll_offset, 0);
+    assert!(state.recent_export_paths.is_empty());
+    assert_eq!(state.export_format, "Json");
+    assert!(state.last_saved_at.is_none());
+}
+
+#[test]
+fn test_serialize_deserialize() {
+    let state = PersistedState {
+        auto_refresh: true,
+        sort_column: "status".to_string(),
+        sort_direction: "desc".to_string(),
+        last_search_query: Some("test query".to_string()),
+        search_history: vec!["query1".to_string(), "query2".to_string()],
+        selected_theme: ColorTheme::Dark,
+        search_defaults: SearchDefaults {
+            earliest_time: "-48h".to_string(),
+            latest_time: "now".to_string(),
+            max_results: 500,
+        },
+        keybind_overrides: KeybindOverrides::default(),
+        list_defaults: ListDefaults::default(),
+        internal_logs_defaults: InternalLogsDefaults::default(),
+        tutorial_completed: true,
+        current_screen: "Jobs".to_string(),
+        scroll_positions: ScrollPositions::default(),
+        recent_export_paths: Vec::new(),
+        export_format: "Json".to_string(),
+        last_saved_at: None,
+        result_renderers: true,
+        no_color: false,
+        onboarding_checklist: PersistedOnboardingChecklist::default(),
+        saved_sessions: Vec::new(),
+        custom_theme: None,
+        clipboard_backend: ClipboardBackend::Auto,
+        rest_history: Vec::new(),
+        notifications: Default::default(),
+        health_thresholds: Default::default(),
+    };
+
+    let json = serde_json::to_string(&state).unwrap();
+    let deserialized: PersistedState = serde_json::from_str(&json).unwrap();
+
+    assert!(deserialized.auto_refresh);
+    assert_eq!(deserialized.sort_column, "status");
+    assert_eq!(deserialized.sort_direction, "desc");
+    assert_eq!(
+        deserialized.last_search_query,
+        Some("test query".to_string())
+    );
+    assert_eq!(deserialized.search_history, vec!["query1", "query2"]);
+    assert_eq!(deserialized.selected_theme, ColorTheme::Dark);
+    assert!(deserialized.tutorial_completed);
+}
+
+#[test]
+fn test_read_legacy_state_file() {
+    let mut temp_file = NamedTempFile::new().unwrap();
+    let legacy_state = PersistedState {
+        auto_refresh: true,
+        sort_column: "status".to_string(),
+        sort_direction: "desc".to_string(),
+        last_search_query: Some("legacy query".to_string()),
+        search_history: Vec::new(),
+        selected_theme: ColorTheme::Default,
+        search_defaults: SearchDefaults::default(),
+        keybind_overrides: KeybindOverrides::default(),
+        list_defaults: ListDefaults::default(),
+        internal_logs_defaults: InternalLogsDefaults::default(),
+        tutorial_completed: false,
+        current_screen: "Search".to_string(),
+        scroll_positions: ScrollPositions::default(),
+        recent_export_paths: Vec::new(),
+        export_format: "Json".to_string(),
+        last_saved_at: None,
+        result_renderers: true,
+        no_color: false,
+        onboarding_checklist: PersistedOnboardingChecklist::default(),
+        saved_sessions: Vec::new(),
+        custom_theme: None,
+        clipboard_backend: ClipboardBackend::Auto,
+        rest_history: Vec::new(),
+        notifications: Default::default(),
+        health_thresholds: Default::default(),
+    };
+
+    writeln!(
+        temp_file,
+        "{}",
+        serde_json::to_string(&legacy_state).unwrap()
+    )
+    .unwrap();
+
+    let (storage, _is_legacy) = read_config_file(temp_file.path()).unwrap();
+    let config_file = match storage {
+        ConfigStorage::Plain(file) => *file,
+        _ => panic!("Expected plain storage"),
+    };
+
+    // Legacy file should result in empty profiles
+    assert!(config_file.profiles.is_empty());
+    // But the state should be preserved
+    assert_eq!(config_file.state.unwrap().sort_column, "status");
+}
+
+#[test]
+fn test_search_defaults_default() {
+    let defaults = SearchDefaults::default();
+    assert_eq!(defaults.earliest_time, "-24h");
+    assert_eq!(defaults.latest_time, "now");
+    assert_eq!(defaults.max_results, 1000);
+}
+
+#[test]
+fn test_search_defaults_serialization() {
+    let defaults = SearchDefaults {
+        earliest_time: "-48h".to_string(),
+        latest_time: "2024-01-01T00:00:00".to_string(),
+        max_results: 500,
+    };
+
+    let json = serde_json::to_string(&defaults).unwrap();
+    assert!(json.contains("-48h"));
+    assert!(json.contains("2024-01-01T00:00:00"));
+    assert!(json.contains("500"));
+
+    let deserialized: SearchDefaults = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.earliest_time, "-48h");
+    assert_eq!(deserialized.latest_time, "2024-01-01T00:00:00");
+    assert_eq!(deserialized.max_results, 500);
+}
+
+#[test]
+fn test_search_defaults_deserialization_uses_defaults_for_missing_fields() {
+    // Test that missing fields use default values
+    let json = r#"{}"#;
+    let deserialized: SearchDefaults = serde_json::from_str(json).unwrap();
+    assert_eq!(deserialized.earliest_time, "-24h");
+    assert_eq!(deserialized.latest_time, "now");
+    assert_eq!(deserialized.max_results, 1000);
+}
+
+#[test]
+fn test_search_defaults_sanitize_empty_earliest_time() {
+    let defaults = SearchDefaults {
+        earliest_time: "".to_string(),
+        latest_time: "now".to_string(),
+        max_results: 1000,
+    };
+
+    let sanitized = defaults.sanitize();
+    assert_eq!(sanitized.earliest_time, "-24h");
+    assert_eq!(sanitized.latest_time, "now");
+    assert_eq!(sanitized.max_results, 1000);
+}
+
+#[test]
+fn test_search_defaults_sanitize_whitespace_earliest_time() {
+    let defaults = SearchDefaults {
+        earliest_time: "   ".to_string(),
+        latest_time: "now".to_string(),
+        max_results: 1000,
+    };
+
+    let sanitized = defaults.sanitize();
+    assert_eq!(sanitized.earliest_time, "-24h");
+    assert_eq!(sanitized.latest_time, "now");
+    assert_eq!(sanitized.max_results, 1000);
+}
+
+#[test]
+fn test_search_defaults_sanitize_empty_latest_time() {
+    let defaults = SearchDefaults {
+        earliest_time: "-24h".to_string(),
+        latest_time: "".to_string(),
+        max_results: 1000,
+    };
+
+    let sanitized = defaults.sanitize();
+    assert_eq!(sanitized.earliest_time, "-24h");
+    assert_eq!(sanitized.latest_time, "now");
+    assert_eq!(sanitized.max_results, 1000);
+}
+
+#[test]
+fn test_search_defaults_sanitize_whitespace_latest_time() {
+    let defaults = SearchDefaults {
+        earliest_time: "-24h".to_string(),
+        latest_time: "   ".to_string(),
+        max_results: 1000,
+    };
+
+    let sanitized = defaults.sanitize();
+    assert_eq!(sanitized.earliest_time, "-24h");
+    assert_eq!(sanitized.latest_time, "now");
+    assert_eq!(sanitized.max_results, 1000);
+}
+
+#[test]
+fn test_search_defaults_sanitize_zero_max_results() {
+    let defaults = SearchDefaults {
+        earliest_time: "-24h".to_string(),
+        latest_time: "now".to_string(),
+        max_results: 0,
+    };
+
+    let sanitized = defaults.sanitize();
+    assert_eq!(sanitized.earliest_time, "-24h");
+    assert_eq!(sanitized.latest_time, "now");
+    assert_eq!(sanitized.max_results, 1000);
+}
+
+#[test]
+fn test_search_defaults_sanitize_multiple_invalid() {
+    let defaults = SearchDefaults {
+        earliest_time: "".to_string(),
+        latest_time: "   ".to_string(),
+        max_results: 0,
+    };
+
+    let sanitized = defaults.sanitize();
+    assert_eq!(sanitized.earliest_time, "-24h");
+    assert_eq!(sanitized.latest_time, "now");
+    assert_eq!(sanitized.max_results, 1000);
+}
+
+#[test]
+fn test_search_defaults_sanitize_valid_values_unchanged() {
+    let defaults = SearchDefaults {
+        earliest_time: "-7d".to_string(),
+        latest_time: "2024-01-01T00:00:00".to_string(),
+        max_results: 500,
+    };
+
+    let sanitized = defaults.sanitize();
+    assert_eq!(sanitized.earliest_time, "-7d");
+    assert_eq!(sanitized.latest_time, "2024-01-01T00:00:00");
+    assert_eq!(sanitized.max_results, 500);
+}
+
+#[test]
+fn test_persisted_state_with_search_defaults_round_trip() {
+    let state = PersistedState {
+        auto_refresh: true,
+        sort_column: "status".to_string(),
+        sort_direction: "desc".to_string(),
+        last_search_query: None,
+        search_history: vec![],
+        selected_theme: ColorTheme::Default,
+        search_defaults: SearchDefaults {
+            earliest_time: "-7d".to_string(),
+            latest_time: "now".to_string(),
+            max_results: 2000,
+        },
+        keybind_overrides: KeybindOverrides::default(),
+        list_defaults: ListDefaults::default(),
+        internal_logs_defaults: InternalLogsDefaults::default(),
+        tutorial_completed: false,
+        current_screen: "Search".to_string(),
+        scroll_positions: ScrollPositions::default(),
+        recent_export_paths: Vec::new(),
+        export_format: "Json".to_string(),
+        last_saved_at: None,
+        result_renderers: true,
+        no_color: false,
+        onboarding_checklist: PersistedOnboardingChecklist::default(),
+        saved_sessions: Vec::new(),
+        custom_theme: None,
+        clipboard_backend: ClipboardBackend::Auto,
+        rest_history: Vec::new(),
+        notifications: Default::default(),
+        health_thresholds: Default::default(),
+    };
+
+    let json = serde_json::to_string(&state).unwrap();
+    let deserialized: PersistedState = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(deserialized.search_defaults.earliest_time, "-7d");
+    assert_eq!(deserialized.search_defaults.latest_time, "now");
+    assert_eq!(deserialized.search_defaults.max_results, 2000);
+}
+
+#[test]
+fn test_persisted_state_backward_compatibility_without_search_defaults() {
+    // Simulate an old config file without search_defaults
+    let json = r#"{
+        "auto_refresh": false,
+        "sort_column": "sid",
+        "sort_direction": "asc",
+        "last_search_query": null,
+        "search_history": [],
+        "selected_theme": "default"
+    }"#;
+
+    let deserialized: PersistedState = serde_json::from_str(json).unwrap();
+    // Result renderers stay on for configs written before the setting existed
+    assert!(deserialized.result_renderers);
+    // Should use defaults for missing search_defaults
+    assert_eq!(deserialized.search_defaults.earliest_time, "-24h");
+    assert_eq!(deserialized.search_defaults.latest_time, "now");
+    assert_eq!(deserialized.search_defaults.max_results, 1000);
+}
+
+#[test]
+fn test_persisted_state_with_keybind_overrides_round_trip() {
+    use crate::types::{KeybindAction, KeybindOverrides};
+
+    let mut overrides = BTreeMap::new();
+    overrides.insert(KeybindAction::Quit, "Ctrl+x".to_string());
+    overrides.insert(KeybindAction::Help, "F1".to_string());
+
+    let state = PersistedState {
+        auto_refresh: true,
+        sort_column: "status".to_string(),
+        sort_direction: "desc".to_string(),
+        last_search_query: None,
+        search_history: vec![],
+        selected_theme: ColorTheme::Default,
+        search_defaults: SearchDefaults::default(),
+        keybind_overrides: KeybindOverrides { overrides },
+        list_defaults: ListDefaults::default(),
+        internal_logs_defaults: InternalLogsDefaults::default(),
+        tutorial_completed: false,
+        current_screen: "Search".to_string(),
+        scroll_positions: ScrollPositions::default(),
+        recent_export_paths: Vec::new(),
+        export_format: "Json".to_string(),
+        last_saved_at: None,
+        result_renderers: true,
+        no_color: false,
+        onboarding_checklist: PersistedOnboardingChecklist::default(),
+        saved_sessions: Vec::new(),
+        custom_theme: None,
+        clipboard_backend: ClipboardBackend::Auto,
+        rest_history: Vec::new(),
+        notifications: Default::default(),
+        health_thresholds: Default::default(),
+    };
+
+    let json = serde_json::to_string(&state).unwrap();
+    let deserialized: PersistedState = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(
+        deserialized.keybind_overrides.get(KeybindAction::Quit),
+        Some("Ctrl+x")
+    );
+    assert_eq!(
+        deserialized.keybind_overrides.get(KeybindAction::Help),
+        Some("F1")
+    );
+}
+
+#[test]
+fn test_persisted_state_backward_compatibility_without_keybind_overrides() {
+    // Simulate an old config file without keybind_overrides
+    let json = r#"{
+        "auto_refresh": false,
+        "sort_column": "sid",
+        "sort_direction": "asc",
+        "last_search_query": null,
+        "search_history": [],
+        "selected_theme": "default",
+        "search_defaults": {
+            "earliest_time": "-24h",
+            "latest_time": "now",
+            "max_results": 1000
+        }
+    }"#;
+
+    let deserialized: PersistedState = serde_json::from_str(json).unwrap();
+    // Should use defaults for missing keybind_overrides
+    assert!(deserialized.keybind_overrides.is_empty());
+}
+
+#[test]
+fn test_list_defaults_default() {
+    let defaults = ListDefaults::default();
+    assert_eq!(defaults.page_size, DEFAULT_LIST_PAGE_SIZE);
+    assert_eq!(defaults.max_items, DEFAULT_LIST_MAX_ITEMS);
+    assert!(defaults.indexes_page_size.is_none());
+    assert!(defaults.jobs_page_size.is_none());
+    assert!(defaults.apps_page_size.is_none());
+    assert!(defaults.users_page_size.is_none());
+    assert!(defaults.roles_page_size.is_none());
+}
+
+#[test]
+fn test_list_defaults_page_size_for_with_overrides() {
+    let defaults = ListDefaults {
+        page_size: 100,
+        max_items: 1000,
+        indexes_page_size: Some(50),
+        jobs_page_size: Some(200),
+        apps_page_size: None,
+        users_page_size: Some(75),
+        roles_page_size: None,
+    };
+
+    assert_eq!(defaults.page_size_for(ListType::Indexes), 50);
+    assert_eq!(defaults.page_size_for(ListType::Jobs), 200);
+    assert_eq!(defaults.page_size_for(ListType::Apps), 100); // Falls back to default
+    assert_eq!(defaults.page_size_for(ListType::Users), 75);
+}
+
+#[test]
+fn test_list_defaults_page_size_for_no_overrides() {
+    let defaults = ListDefaults {
+        page_size: 100,
+        max_items: 1000,
+        indexes_page_size: None,
+        jobs_page_size: None,
+        apps_page_size: None,
+        users_page_size: None,
+        roles_page_size: None,
+    };
+
+    assert_eq!(defaults.page_size_for(ListType::Indexes), 100);
+    assert_eq!(defaults.page_size_for(ListType::Jobs), 100);
+    assert_eq!(defaults.page_size_for(ListType::Apps), 100);
+    assert_eq!(defaults.page_size_for(ListType::Users), 100);
+    assert_eq!(defaults.page_size_for(ListType::Roles), 100);
+}
+
+#[test]
+fn test_list_defaults_serialization() {
+    let defaults = ListDefaults {
+        page_size: 50,
+        max_items: 500,
+        indexes_page_size: Some(25),
+        jobs_page_size: Some(100),
+        apps_page_size: None,
+        users_page_size: None,
+        roles_page_size: Some(30),
+    };
+
+    let json = serde_json::to_string(&defaults).unwrap();
+    assert!(json.contains("50"));
+    assert!(json.contains("500"));
+    assert!(json.contains("25"));
+    assert!(json.contains("100"));
+
+    let deserialized: ListDefaults = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.page_size, 50);
+    assert_eq!(deserialized.max_items, 500);
+    assert_eq!(deserialized.indexes_page_size, Some(25));
+    assert_eq!(deserialized.jobs_page_size, Some(100));
+    assert_eq!(deserialized.apps_page_size, None);
+}
+
+#[test]
+fn test_list_defaults_deserialization_uses_defaults_for_missing_fields() {
+    // Test that missing fields use default values
+    let json = r#"{}"#;
+    let deserialized: ListDefaults = serde_json::from_str(json).unwrap();
+    assert_eq!(deserialized.page_size, DEFAULT_LIST_PAGE_SIZE);
+    assert_eq!(deserialized.max_items, DEFAULT_LIST_MAX_ITEMS);
+    assert!(deserialized.indexes_page_size.is_none());
+}
+
+#[test]
+fn test_persisted_state_backward_compatibility_without_list_defaults() {
+    // Simulate an old config file without list_defaults
+    let json = r#"{
+        "auto_refresh": false,
+        "sort_column": "sid",
+        "sort_direction": "asc",
+        "last_search_query": null,
+        "search_history": [],
+        "selected_theme": "default",
+        "search_defaults": {
+            "earliest_time": "-24h",
+            "latest_time": "now",
+            "max_results": 1000
+        },
+        "keybind_overrides": {}
+    }"#;
+
+    let deserialized: PersistedState = serde_json::from_str(json).unwrap();
+    // Should use defaults for missing list_defaults
+    assert_eq!(deserialized.list_defaults.page_size, DEFAULT_LIST_PAGE_SIZE);
+    assert_eq!(deserialized.list_defaults.max_items, DEFAULT_LIST_MAX_ITEMS);
+}
+
+#[test]
+fn test_persisted_state_with_list_defaults_round_trip() {
+    let state = PersistedState {
+        auto_refresh: true,
+        sort_column: "status".to_string(),
+        sort_direction: "desc".to_string(),
+        last_search_query: None,
+        search_history: vec![],
+        selected_theme: ColorTheme::Default,
+        search_defaults: SearchDefaults::default(),
+        keybind_overrides: KeybindOverrides::default(),
+        list_defaults: ListDefaults {
+            page_size: 75,
+            max_items: 750,
+            indexes_page_size: Some(50),
+            jobs_page_size: Some(100),
+            apps_page_size: None,
+            users_page_size: Some(25),
+            roles_page_size: None,
+        },
+        internal_logs_defaults: InternalLogsDefaults::default(),
+        tutorial_completed: false,
+        current_screen: "Search".to_string(),
+        scroll_positions: ScrollPositions::default(),
+        recent_export_paths: Vec::new(),
+        export_format: "Json".to_string(),
+        last_saved_at: None,
+        result_renderers: true,
+        no_color: false,
+        onboarding_checklist: PersistedOnboardingChecklist::default(),
+        saved_sessions: Vec::new(),
+        custom_theme: None,
+        clipboard_backend: ClipboardBackend::Auto,
+        rest_history: Vec::new(),
+        notifications: Default::default(),
+        health_thresholds: Default::default(),
+    };
+
+    let json = serde_json::to_string(&state).unwrap();
+    let deserialized: PersistedState = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(deserialized.list_defaults.page_size, 75);
+    assert_eq!(deserialized.list_defaults.max_items, 750);
+    assert_eq!(deserialized.list_defaults.indexes_page_size, Some(50));
+    assert_eq!(deserialized.list_defaults.jobs_page_size, Some(100));
+    assert_eq!(deserialized.list_defaults.apps_page_size, None);
+    assert_eq!(deserialized.list_defaults.users_page_size, Some(25));
+}
+
+#[test]
+fn test_internal_logs_defaults_default() {
+    let defaults = InternalLogsDefaults::default();
+    assert_eq!(defaults.count, 100);
+    assert_eq!(defaults.earliest_time, "-15m");
+}
+
+#[test]
+fn test_internal_logs_defaults_serialization() {
+    let defaults = InternalLogsDefaults {
+        count: 50,
+        earliest_time: "-1h".to_string(),
+        ..Default::default()
+    };
+
+    let json = serde_json::to_string(&defaults).unwrap();
+    assert!(json.contains("50"));
+    assert!(json.contains("-1h"));
+
+    let deserialized: InternalLogsDefaults = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.count, 50);
+    assert_eq!(deserialized.earliest_time, "-1h");
+}
+
+#[test]
+fn test_internal_logs_defaults_deserialization_uses_defaults_for_missing_fields() {
+    // Test that missing fields use default values
+    let json = r#"{}"#;
+    let deserialized: InternalLogsDefaults = serde_json::from_str(json).unwrap();
+    assert_eq!(deserialized.count, 100);
+    assert_eq!(deserialized.earliest_time, "-15m");
+}
+
+#[test]
+fn test_persisted_state_backward_compatibility_without_internal_logs_defaults() {
+    // Simulate an old config file without internal_logs_defaults
+    let json = r#"{
+        "auto_refresh": false,
+        "sort_column": "sid",
+        "sort_direction": "asc",
+        "last_search_query": null,
+        "search_history": [],
+        "selected_theme": "default",
+        "search_defaults": {
+            "earliest_time": "-24h",
+            "latest_time": "now",
+            "max_results": 1000
+        },
+        "keybind_overrides": {},
+        "list_defaults": {
+            "page_size": 100,
+            "max_items": 1000
+        }
+    }"#;
+
+    let deserialized: PersistedState = serde_json::from_str(json).unwrap();
+    // Should use defaults for missing internal_logs_defaults
+    assert_eq!(deserialized.internal_logs_defaults.count, 100);
+    assert_eq!(deserialized.internal_logs_defaults.earliest_time, "-15m");
+}
+
+#[test]
+fn test_persisted_state_with_internal_logs_defaults_round_trip() {
+    let state = PersistedState {
+        auto_refresh: true,
+        sort_column: "status".to_string(),
+        sort_direction: "desc".to_string(),
+        last_search_query: None,
+        search_history: vec![],
+        selected_theme: ColorTheme::Default,
+        search_defaults: SearchDefaults::default(),
+        keybind_overrides: KeybindOverrides::default(),
+        list_defaults: ListDefaults::default(),
+        internal_logs_defaults: InternalLogsDefaults {
+            count: 200,
+            earliest_time: "-30m".to_string(),
+            ..Default::default()
+        },
+        tutorial_completed: false,
+        current_screen: "Search".to_string(),
+        scroll_positions: ScrollPositions::default(),
+        recent_export_paths: Vec::new(),
+        export_format: "Json".to_string(),
+        last_saved_at: None,
+        result_renderers: true,
+        no_color: false,
+        onboarding_checklist: PersistedOnboardingChecklist::default(),
+        saved_sessions: Vec::new(),
+        custom_theme: None,
+        clipboard_backend: ClipboardBackend::Auto,
+        rest_history: Vec::new(),
+        notifications: Default::default(),
+        health_thresholds: Default::default(),
+    };
+
+    let json = serde_json::to_string(&state).unwrap();
+    let deserialized: PersistedState = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(deserialized.internal_logs_defaults.count, 200);
+    assert_eq!(deserialized.internal_logs_defaults.earliest_time, "-30m");
+}
+
+#[test]
+fn test_internal_logs_defaults_sanitize_zero_count() {
+    let defaults = InternalLogsDefaults {
+        count: 0,
+        earliest_time: "-15m".to_string(),
+        ..Default::default()
+    };
+
+    let sanitized = defaults.sanitize();
+    assert_eq!(sanitized.count, 100);
+    assert_eq!(sanitized.earliest_time, "-15m");
+}
+
+#[test]
+fn test_internal_logs_defaults_sanitize_empty_earliest_time() {
+    let defaults = InternalLogsDefaults {
+        count: 50,
+        earliest_time: "".to_string(),
+        ..Default::default()
+    };
+
+    let sanitized = defaults.sanitize();
+    assert_eq!(sanitized.count, 50);
+    assert_eq!(sanitized.earliest_time, "-15m");
+}
+
+#[test]
+fn test_internal_logs_defaults_sanitize_whitespace_earliest_time() {
+    let defaults = InternalLogsDefaults {
+        count: 50,
+        earliest_time: "   ".to_string(),
+        ..Default::default()
+    };
+
+    let sanitized = defaults.sanitize();
+    assert_eq!(sanitized.count, 50);
+    assert_eq!(sanitized.earliest_time, "-15m");
+}
+
+#[test]
+fn test_internal_logs_defaults_sanitize_multiple_invalid() {
+    let defaults = InternalLogsDefaults {
+        count: 0,
+        earliest_time: "".to_string(),
+        ..Default::default()
+    };
+
+    let sanitized = defaults.sanitize();
+    assert_eq!(sanitized.count, 100);
+    assert_eq!(sanitized.earliest_time, "-15m");
+}
+
+#[test]
+fn test_internal_logs_defaults_sanitize_valid_values_unchanged() {
+    let defaults = InternalLogsDefaults {
+        count: 200,
+        earliest_time: "-1h".to_string(),
+        ..Default::default()
+    };
+
+    let sanitized = defaults.sanitize();
+    assert_eq!(sanitized.count, 200);
+    assert_eq!(sanitized.earliest_time, "-1h");
+}
+
+#[test]
+fn test_scroll_positions_default() {
+    let positions = ScrollPositions::default();
+    assert_eq!(positions.search_scroll_offset, 0);
+    assert_eq!(positions.index_details_scroll_offset, 0);
+    assert_eq!(positions.help_scroll_offset, 0);
+    assert_eq!(positions.error_scroll_offset, 0);
+}
+
+#[test]
+fn test_persisted_state_with_new_fields_round_trip() {
+    let state = PersistedState {
+        auto_refresh: true,
+        sort_column: "status".to_string(),
+        sort_direction: "desc".to_string(),
+        last_search_query: Some("test".to_string()),
+        search_history: vec!["query1".to_string()],
+        selected_theme: ColorTheme::Dark,
+        search_defaults: SearchDefaults::default(),
+        keybind_overrides: KeybindOverrides::default(),
+        list_defaults: ListDefaults::default(),
+        internal_logs_defaults: InternalLogsDefaults::default(),
+        tutorial_completed: true,
+        // New fields
+        current_screen: "Jobs".to_string(),
+        scroll_positions: ScrollPositions {
+            search_scroll_offset: 100,
+            index_details_scroll_offset: 50,
+            help_scroll_offset: 10,
+            error_scroll_offset: 5,
+        },
+        recent_export_paths: vec!["/path/to/export.json".to_string()],
+        export_format: "Csv".to_string(),
+        last_saved_at: Some(1234567890),
+        result_renderers: true,
+        no_color: false,
+        onboarding_checklist: PersistedOnboardingChecklist {
+            milestones: 0b00011,
+            dismissed_items: vec!["connection_verified".to_string()],
+            session_count: 5,
+            sessions_since_completion: 0,
+            globally_dismissed: false,
+        },
+        saved_sessions: Vec::new(),
+        custom_theme: None,
+        clipboard_backend: ClipboardBackend::Auto,
+        rest_history: Vec::new(),
+        notifications: Default::default(),
+        health_thresholds: Default::default(),
+    };
+
+    let json = serde_json::to_string(&state).unwrap();
+    let deserialized: PersistedState = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(deserialized.current_screen, "Jobs");
+    assert_eq!(deserialized.scroll_positions.search_scroll_offset, 100);
+    assert_eq!(
+        deserialized.scroll_positions.index_details_scroll_offset,
+        50
+    );
+    assert_eq!(deserialized.scroll_positions.help_scroll_offset, 10);
+    assert_eq!(deserialized.scroll_positions.error_scroll_offset, 5);
+    assert_eq!(deserialized.recent_export_paths.len(), 1);
+    assert_eq!(deserialized.export_format, "Csv");
+    assert_eq!(deserialized.last_saved_at, Some(1234567890));
+    assert_eq!(deserialized.onboarding_checklist.milestones, 0b00011);
+    assert_eq!(deserialized.onboarding_checklist.session_count, 5);
+}
+
+#[test]
+fn test_persisted_state_backward_compatibility_without_new_fields() {
+    // Simulate an old config file without the new fields
+    let json = r#"{
+        "auto_refresh": false,
+        "sort_column": "sid",
+        "sort_direction": "asc",
+        "last_search_query": null,
+        "search_history": [],
+        "selected_theme": "default",
+        "search_defaults": {
+            "earliest_time": "-24h",
+            "latest_time": "now",
+            "max_results": 1000
+        },
+        "keybind_overrides": {},
+        "list_defaults": {
+            "page_size": 100,
+            "max_items": 1000
+        },
+        "internal_logs_defaults": {
+            "count": 100,
+            "earliest_time": "-15m"
+        },
+        "tutorial_completed": false
+    }"#;
+
+    let deserialized: PersistedState = serde_json::from_str(json).unwrap();
+    // Should use defaults for missing fields
+    assert_eq!(deserialized.current_screen, "Search");
+    assert_eq!(deserialized.scroll_positions.search_scroll_offset, 0);
+    assert!(deserialized.recent_export_paths.is_empty());
+    assert_eq!(deserialized.export_format, "Json");
+    assert!(deserialized.last_saved_at.is_none());
+}
@@ -0,0 +1,319 @@
+//! Shared team configuration bundles.
+//!
+//! Responsibilities:
+//! - Define the read-only `TeamBundle` a platform team distributes to operators
+//!   (theme, keybindings, search templates, and secret-free profiles).
+//! - Validate bundle contents before they are cached.
+//! - Read and atomically write the locally cached copy next to the user config.
+//!
+//! Does NOT handle:
+//! - Fetching bundles from a path or URL (see `splunk-cli config sync`).
+//! - Layering bundle values beneath personal settings (see `ConfigManager`).
+//!
+//! Invariants:
+//! - Bundles never carry credentials; profiles with a password or API token are rejected.
+//! - Bundle search templates pass the same schema validation as template files.
+//! - The cache lives at `team-bundle.json` in the same directory as the user config file.
+//! - A missing or unreadable cache never prevents startup; it is simply ignored.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::state::PersistedState;
+use super::templates::{SearchTemplate, TemplateError};
+use crate::keybind::{KeybindError, validate_overrides};
+use crate::types::{ColorTheme, KeybindOverrides, ProfileConfig};
+
+/// File name of the cached team bundle, stored beside the user config file.
+pub const TEAM_BUNDLE_FILE_NAME: &str = "team-bundle.json";
+
+/// Team-level configuration layered beneath each user's personal config.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeamBundle {
+    /// Bundle name shown by `config sync` and `config team`
+    pub name: Option<String>,
+    /// Free-form description or version note
+    pub description: Option<String>,
+    /// Theme used when the user has not picked one
+    pub theme: Option<ColorTheme>,
+    /// Keybinding overrides; personal overrides for the same action win
+    pub keybind_overrides: KeybindOverrides,
+    /// Shared search templates
+    pub search_templates: Vec<SearchTemplate>,
+    /// Connection profiles without secrets; personal profiles with the same name win
+    pub profiles: BTreeMap<String, ProfileConfig>,
+}
+
+/// Errors that make a team bundle unusable.
+#[derive(Debug, thiserror::Error)]
+pub enum TeamBundleError {
+    #[error("Failed to parse team bundle: {0}")]
+    Parse(#[from] serde_json::Error),
+
+    #[error("Team bundle profile '{profile}' contains a {field}; bundles must not carry secrets")]
+    SecretInProfile {
+        profile: String,
+        field: &'static str,
+    },
+
+    #[error("Team bundle search template #{index} has an empty name or query")]
+    EmptyTemplate { index: usize },
+
+    #[error("Team bundle search template '{name}' is invalid: {source}")]
+    InvalidTemplate { name: String, source: TemplateError },
+
+    #[error("Team bundle keybindings are invalid: {0}")]
+    InvalidKeybindings(#[from] KeybindError),
+}
+
+impl TeamBundle {
+    /// Parse and validate a bundle from its JSON text.
+    pub fn parse(content: &str) -> Result<Self, TeamBundleError> {
+        let bundle: TeamBundle = serde_json::from_str(content)?;
+        bundle.validate()?;
+        Ok(bundle)
+    }
+
+    /// Check that the bundle carries no secrets and its contents are usable.
+    pub fn validate(&self) -> Result<(), TeamBundleError> {
+        for (name, profile) in &self.profiles {
+            let field = if profile.password.is_some() {
+                Some("password")
+            } else if profile.api_token.is_some() {
+                Some("API token")
+            } else {
+                None
+            };
+            if let Some(field) = field {
+                return Err(TeamBundleError::SecretInProfile {
+                    profile: name.clone(),
+                    field,
+                });
+            }
+        }
+
+        for (index, template) in self.search_templates.iter().enumerate() {
+            if template.name.trim().is_empty() || template.query.trim().is_empty() {
+                return Err(TeamBundleError::EmptyTemplate { index: index + 1 });
+            }
+            template
+                .validate()
+                .map_err(|source| TeamBundleError::InvalidTemplate {
+                    name: template.name.clone(),
+                    source,
+                })?;
+        }
+
+        validate_overrides(&self.keybind_overrides.overrides)?;
+        Ok(())
+    }
+}
+
+/// A team bundle as cached on disk, with where and when it was fetched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedTeamBundle {
+    /// Path or URL the bundle was synced from
+    pub source: String,
+    /// Unix timestamp (seconds) of the last successful sync
+    pub synced_at: u64,
+    /// The validated bundle contents
+    pub bundle: TeamBundle,
+}
+
+/// Returns the team bundle cache path for a given user config path.
+pub fn team_bundle_path(config_path: &Path) -> PathBuf {
+    config_path.with_file_name(TEAM_BUNDLE_FILE_NAME)
+}
+
+/// Applies bundle values beneath the user's persisted state.
+///
+/// The bundle theme applies only while the user is on the default theme, and
+/// bundle keybindings fill in actions the user has not overridden. If the merged
+/// keybindings conflict, the user's own overrides are kept unchanged.
+pub(crate) fn layer_team_bundle(state: &mut PersistedState, bundle: &TeamBundle) {
+    if state.selected_theme == ColorTheme::Default
+        && let Some(theme) = bundle.theme
+    {
+        state.selected_theme = theme;
+    }
+
+    let mut merged = state.keybind_overrides.clone();
+    for (action, key) in &bundle.keybind_overrides.overrides {
+        merged
+            .overrides
+            .entry(*action)
+            .or_insert_with(|| key.clone());
+    }
+    match validate_overrides(&merged.overrides) {
+        Ok(()) => state.keybind_overrides = merged,
+        Err(e) => {
+            tracing::warn!(error = %e, "Team bundle keybindings conflict with personal overrides, ignoring them");
+        }
+    }
+}
+
+/// Removes values inherited from the bundle so they are not persisted as personal settings.
+///
+/// This keeps later bundle updates visible to users who never changed those settings.
+pub(crate) fn strip_team_bundle(state: &mut PersistedState, bundle: &TeamBundle) {
+    if bundle.theme == Some(state.selected_theme) {
+        state.selected_theme = ColorTheme::Default;
+    }
+    state
+        .keybind_overrides
+        .overrides
+        .retain(|action, key| bundle.keybind_overrides.get(*action) != Some(key.as_str()));
+}
+
+/// Reads the cached team bundle, ignoring (and logging) unusable caches.
+pub(crate) fn read_cached_team_bundle(path: &Path) -> Option<CachedTeamBundle> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(e) => {
+            tracing::warn!(path = %path.display(), error = %e, "Failed to read team bundle cache");
+            return None;
+        }
+    };
+
+    let cached = serde_json::from_str::<CachedTeamBundle>(&content)
+        .map_err(TeamBundleError::from)
+        .and_then(|cached| cached.bundle.validate().map(|()| cached));
+    match cached {
+        Ok(cached) => Some(cached),
+        Err(e) => {
+            tracing::warn!(path = %path.display(), error = %e, "Ignoring invalid team bundle cache");
+            None
+        }
+    }
+}
+
+/// Atomically writes the team bundle cache (temp file + rename).
+pub(crate) fn write_cached_team_bundle(path: &Path, cached: &CachedTeamBundle) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).context("Failed to create config directory")?;
+    }
+
+    let temp_path = path.with_extension("tmp");
+    let content = serde_json::to_string_pretty(cached)?;
+    std::fs::write(&temp_path, content).context("Failed to write temporary team bundle file")?;
+    std::fs::rename(&temp_path, path).context("Failed to rename temporary team bundle file")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::KeybindAction;
+
+    #[test]
+    fn test_parse_accepts_full_bundle() {
+        let bundle = TeamBundle::parse(
+            r#"{
+                "name": "platform-standard",
+                "theme": "dark",
+                "keybind_overrides": { "overrides": { "help": "F1" } },
+                "search_templates": [
+                    { "name": "Errors", "query": "index=main log_level=ERROR" }
+                ],
+                "profiles": {
+                    "prod": { "base_url": "https://splunk.example.com:8089", "skip_verify": false }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(bundle.name.as_deref(), Some("platform-standard"));
+        assert_eq!(bundle.theme, Some(ColorTheme::Dark));
+        assert_eq!(
+            bundle.keybind_overrides.get(KeybindAction::Help),
+            Some("F1")
+        );
+        assert_eq!(bundle.search_templates[0].name, "Errors");
+        assert!(bundle.profiles.contains_key("prod"));
+    }
+
+    #[test]
+    fn test_parse_rejects_profile_secrets() {
+        let err = TeamBundle::parse(
+            r#"{ "profiles": { "prod": { "username": "svc", "password": "hunter2" } } }"#,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            TeamBundleError::SecretInProfile { ref profile, field: "password" } if profile == "prod"
+        ));
+    }
+
+    #[test]
+    fn test_parse_rejects_empty_template_query() {
+        let err = TeamBundle::parse(r#"{ "search_templates": [{ "name": "x", "query": " " }] }"#)
+            .unwrap_err();
+
+        assert!(matches!(err, TeamBundleError::EmptyTemplate { index: 1 }));
+    }
+
+    #[test]
+    fn test_layer_and_strip_keep_personal_values() {
+        let mut bundle = TeamBundle {
+            theme: Some(ColorTheme::Dark),
+            ..TeamBundle::default()
+        };
+        bundle
+            .keybind_overrides
+            .overrides
+            .insert(KeybindAction::Help, "F1".to_string());
+        bundle
+            .keybind_overrides
+            .overrides
+            .insert(KeybindAction::Quit, "F10".to_string());
+
+        let mut state = PersistedState::default();
+        state
+            .keybind_overrides
+            .overrides
+            .insert(KeybindAction::Quit, "F12".to_string());
+
+        layer_team_bundle(&mut state, &bundle);
+        assert_eq!(state.selected_theme, ColorTheme::Dark);
+        assert_eq!(state.keybind_overrides.get(KeybindAction::Help), Some("F1"));
+        assert_eq!(
+            state.keybind_overrides.get(KeybindAction::Quit),
+            Some("F12")
+        );
+
+        strip_team_bundle(&mut state, &bundle);
+        assert_eq!(state.selected_theme, ColorTheme::Default);
+        assert_eq!(state.keybind_overrides.get(KeybindAction::Help), None);
+        assert_eq!(
+            state.keybind_overrides.get(KeybindAction::Quit),
+            Some("F12")
+        );
+    }
+
+    #[test]
+    fn test_cache_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = team_bundle_path(&dir.path().join("config.json"));
+        let cached = CachedTeamBundle {
+            source: "https://config.example.com/team.json".to_string(),
+            synced_at: 1_700_000_000,
+            bundle: TeamBundle {
+                name: Some("ops".to_string()),
+                ..TeamBundle::default()
+            },
+        };
+
+        write_cached_team_bundle(&path, &cached).unwrap();
+        let read = read_cached_team_bundle(&path).unwrap();
+
+        assert_eq!(path.file_name().unwrap(), TEAM_BUNDLE_FILE_NAME);
+        assert_eq!(read.source, cached.source);
+        assert_eq!(read.bundle.name.as_deref(), Some("ops"));
+    }
+}
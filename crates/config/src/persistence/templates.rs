@@ -0,0 +1,745 @@
+//! Shareable search templates with parameter schemas.
+//!
+//! Responsibilities:
+//! - Define `SearchTemplate` and its typed `$name$`/`{{name}}` parameters (name, type, default,
+//!   description).
+//! - Validate templates and render them into SPL from parameter values.
+//! - Define the versioned template file used to exchange templates between teams.
+//! - Read and atomically write the personal template library next to the user config.
+//!
+//! Does NOT handle:
+//! - Fetching team bundles (see `team.rs` and `splunk-cli config sync`).
+//! - Prompting for parameter values (see the CLI `config templates render` and the TUI form).
+//!
+//! Invariants:
+//! - Every `$name$` or `{{name}}` placeholder in a query is declared as a parameter, and
+//!   every declared parameter is used; `$$` is a literal `$`.
+//! - Defaults are valid for their parameter type.
+//! - The personal library lives at `search-templates.json` beside the user config file,
+//!   in the same format as exported template files.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use super::profiles::ConfigManager;
+
+/// File name of the personal search template library, stored beside the user config file.
+pub const SEARCH_TEMPLATES_FILE_NAME: &str = "search-templates.json";
+
+/// Current version of the search template file format.
+pub const SEARCH_TEMPLATE_FILE_VERSION: u32 = 1;
+
+/// A named SPL query, optionally parameterized with `$name$` or `{{name}}` placeholders.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchTemplate {
+    /// Short display name
+    pub name: String,
+    /// SPL query text
+    pub query: String,
+    /// Optional explanation of what the template is for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Parameters substituted into the query's placeholders
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<TemplateParameter>,
+}
+
+/// Value type of a template parameter, used to validate supplied values.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateParameterType {
+    /// Any text
+    #[default]
+    String,
+    /// Whole number, e.g. `100`
+    Integer,
+    /// Decimal number, e.g. `0.5`
+    Number,
+    /// `true` or `false`
+    Boolean,
+    /// Splunk time modifier, e.g. `-24h@h`, `now`, or an epoch timestamp
+    Time,
+}
+
+impl std::fmt::Display for TemplateParameterType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::String => "string",
+            Self::Integer => "integer",
+            Self::Number => "number",
+            Self::Boolean => "boolean",
+            Self::Time => "time",
+        };
+        f.write_str(name)
+    }
+}
+
+impl TemplateParameterType {
+    /// Checks that `value` is valid for this type, returning the reason if not.
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        let valid = match self {
+            Self::String => true,
+            Self::Integer => value.parse::<i64>().is_ok(),
+            Self::Number => value.parse::<f64>().is_ok_and(f64::is_finite),
+            Self::Boolean => matches!(value, "true" | "false"),
+            Self::Time => is_time_modifier(value),
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(format!("'{}' is not a valid {}", value, self))
+        }
+    }
+}
+
+/// A typed placeholder in a search template.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemplateParameter {
+    /// Placeholder name, referenced as `$name$` or `{{name}}` in the query
+    pub name: String,
+    /// Value type (defaults to `string`)
+    #[serde(rename = "type", default)]
+    pub kind: TemplateParameterType,
+    /// Value used when none is supplied; parameters without a default are required
+    #[serde(
+        default,
+        deserialize_with = "default_value_from_scalar",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub default: Option<String>,
+    /// Shown next to the input in the TUI form and in `config templates list`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Errors in a template definition or in the values used to render it.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TemplateError {
+    #[error("Template has an empty name or query")]
+    Empty,
+
+    #[error("Parameter name '{0}' must contain only letters, digits, and underscores")]
+    InvalidParameterName(String),
+
+    #[error("Parameter '{0}' is declared more than once")]
+    DuplicateParameter(String),
+
+    #[error("Query uses placeholder '{0}' but no parameter '{0}' is declared")]
+    UndeclaredParameter(String),
+
+    #[error("Parameter '{0}' is declared but not used in the query")]
+    UnusedParameter(String),
+
+    #[error("Query has an unterminated $ placeholder (use $$ for a literal $)")]
+    UnterminatedPlaceholder,
+
+    #[error("Default for parameter '{parameter}' is invalid: {reason}")]
+    InvalidDefault { parameter: String, reason: String },
+
+    #[error("Template has no parameter '{0}'")]
+    UnknownParameter(String),
+
+    #[error("Parameter '{0}' is required")]
+    MissingValue(String),
+
+    #[error("Value for parameter '{parameter}' is invalid: {reason}")]
+    InvalidValue { parameter: String, reason: String },
+}
+
+/// A piece of a parsed query: literal text or a placeholder reference.
+enum Segment<'a> {
+    Text(&'a str),
+    Placeholder(&'a str),
+}
+
+impl SearchTemplate {
+    /// Creates a template declaring one required string parameter per placeholder,
+    /// in order of first use.
+    ///
+    /// # Errors
+    /// Returns an error if the name or query is empty or a placeholder is malformed.
+    pub fn from_query(
+        name: impl Into<String>,
+        query: impl Into<String>,
+    ) -> Result<Self, TemplateError> {
+        let query = query.into();
+        let mut parameters: Vec<TemplateParameter> = Vec::new();
+        for segment in parse_query(&query)? {
+            if let Segment::Placeholder(name) = segment
+                && !parameters.iter().any(|p| p.name == name)
+            {
+                parameters.push(TemplateParameter {
+                    name: name.to_string(),
+                    kind: TemplateParameterType::String,
+                    default: None,
+                    description: None,
+                });
+            }
+        }
+        let template = Self {
+            name: name.into(),
+            query,
+            description: None,
+            parameters,
+        };
+        template.validate()?;
+        Ok(template)
+    }
+
+    /// Checks the template's name, query, and parameter schema.
+    pub fn validate(&self) -> Result<(), TemplateError> {
+        if self.name.trim().is_empty() || self.query.trim().is_empty() {
+            return Err(TemplateError::Empty);
+        }
+
+        let mut declared = HashSet::new();
+        for parameter in &self.parameters {
+            if !is_parameter_name(&parameter.name) {
+                return Err(TemplateError::InvalidParameterName(parameter.name.clone()));
+            }
+            if !declared.insert(parameter.name.as_str()) {
+                return Err(TemplateError::DuplicateParameter(parameter.name.clone()));
+            }
+            if let Some(default) = &parameter.default {
+                parameter
+                    .kind
+                    .check(default)
+                    .map_err(|reason| TemplateError::InvalidDefault {
+                        parameter: parameter.name.clone(),
+                        reason,
+                    })?;
+            }
+        }
+
+        let used = self.placeholders()?;
+        if let Some(name) = used.iter().find(|name| !declared.contains(*name)) {
+            return Err(TemplateError::UndeclaredParameter(name.to_string()));
+        }
+        if let Some(parameter) = self
+            .parameters
+            .iter()
+            .find(|parameter| !used.contains(parameter.name.as_str()))
+        {
+            return Err(TemplateError::UnusedParameter(parameter.name.clone()));
+        }
+        Ok(())
+    }
+
+    /// Names referenced by placeholders in the query.
+    pub fn placeholders(&self) -> Result<HashSet<&str>, TemplateError> {
+        Ok(parse_query(&self.query)?
+            .into_iter()
+            .filter_map(|segment| match segment {
+                Segment::Placeholder(name) => Some(name),
+                Segment::Text(_) => None,
+            })
+            .collect())
+    }
+
+    /// Returns true if the template has parameters that must be filled in before running.
+    pub fn is_parameterized(&self) -> bool {
+        !self.parameters.is_empty()
+    }
+
+    /// Renders the query, substituting supplied values or parameter defaults.
+    ///
+    /// # Errors
+    /// Returns an error for unknown parameters, missing required values, or
+    /// values that are invalid for their parameter type.
+    pub fn render(&self, values: &BTreeMap<String, String>) -> Result<String, TemplateError> {
+        if let Some(name) = values
+            .keys()
+            .find(|name| !self.parameters.iter().any(|p| &p.name == *name))
+        {
+            return Err(TemplateError::UnknownParameter(name.clone()));
+        }
+
+        let mut resolved = BTreeMap::new();
+        for parameter in &self.parameters {
+            let value = values
+                .get(&parameter.name)
+                .or(parameter.default.as_ref())
+                .ok_or_else(|| TemplateError::MissingValue(parameter.name.clone()))?;
+            parameter
+                .kind
+                .check(value)
+                .map_err(|reason| TemplateError::InvalidValue {
+                    parameter: parameter.name.clone(),
+                    reason,
+                })?;
+            resolved.insert(parameter.name.as_str(), value.as_str());
+        }
+
+        let mut query = String::with_capacity(self.query.len());
+        for segment in parse_query(&self.query)? {
+            match segment {
+                Segment::Text(text) => query.push_str(text),
+                Segment::Placeholder(name) => query.push_str(
+                    resolved
+                        .get(name)
+                        .ok_or_else(|| TemplateError::UndeclaredParameter(name.to_string()))?,
+                ),
+            }
+        }
+        Ok(query)
+    }
+}
+
+/// A file of shareable search templates, as written by `config templates export`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchTemplateFile {
+    /// File format version
+    pub version: u32,
+    /// Templates in display order
+    #[serde(default)]
+    pub templates: Vec<SearchTemplate>,
+}
+
+/// Errors that make a search template file unusable.
+#[derive(Debug, thiserror::Error)]
+pub enum TemplateFileError {
+    #[error("Failed to parse search template file: {0}")]
+    Parse(#[from] serde_json::Error),
+
+    #[error(
+        "Unsupported search template file version {0} (expected {SEARCH_TEMPLATE_FILE_VERSION})"
+    )]
+    UnsupportedVersion(u32),
+
+    #[error("Search template #{index} ('{name}') is invalid: {source}")]
+    InvalidTemplate {
+        index: usize,
+        name: String,
+        source: TemplateError,
+    },
+
+    #[error("Search template '{0}' appears more than once")]
+    DuplicateName(String),
+}
+
+impl SearchTemplateFile {
+    /// Creates a file at the current format version.
+    pub fn new(templates: Vec<SearchTemplate>) -> Self {
+        Self {
+            version: SEARCH_TEMPLATE_FILE_VERSION,
+            templates,
+        }
+    }
+
+    /// Parse and validate a template file from its JSON text.
+    pub fn parse(content: &str) -> Result<Self, TemplateFileError> {
+        let file: SearchTemplateFile = serde_json::from_str(content)?;
+        file.validate()?;
+        Ok(file)
+    }
+
+    /// Check the format version, each template, and that names are unique.
+    pub fn validate(&self) -> Result<(), TemplateFileError> {
+        if self.version != SEARCH_TEMPLATE_FILE_VERSION {
+            return Err(TemplateFileError::UnsupportedVersion(self.version));
+        }
+        let mut names = HashSet::new();
+        for (index, template) in self.templates.iter().enumerate() {
+            template
+                .validate()
+                .map_err(|source| TemplateFileError::InvalidTemplate {
+                    index: index + 1,
+                    name: template.name.clone(),
+                    source,
+                })?;
+            if !names.insert(template.name.as_str()) {
+                return Err(TemplateFileError::DuplicateName(template.name.clone()));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of merging imported templates into the personal library.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TemplateImportSummary {
+    /// Names of templates that were not in the library
+    pub added: Vec<String>,
+    /// Names of existing templates that were overwritten
+    pub replaced: Vec<String>,
+    /// Names of existing templates left unchanged (import without replace)
+    pub skipped: Vec<String>,
+}
+
+/// Merges `imported` into `library` by name, replacing existing entries only if `replace` is set.
+pub(crate) fn merge_templates(
+    library: &mut Vec<SearchTemplate>,
+    imported: Vec<SearchTemplate>,
+    replace: bool,
+) -> TemplateImportSummary {
+    let mut summary = TemplateImportSummary::default();
+    for template in imported {
+        match library.iter_mut().find(|t| t.name == template.name) {
+            Some(existing) if replace => {
+                summary.replaced.push(template.name.clone());
+                *existing = template;
+            }
+            Some(_) => summary.skipped.push(template.name),
+            None => {
+                summary.added.push(template.name.clone());
+                library.push(template);
+            }
+        }
+    }
+    summary
+}
+
+impl ConfigManager {
+    /// Returns the path of the personal search template library for this config file.
+    pub fn search_templates_path(&self) -> PathBuf {
+        search_templates_path(self.config_path())
+    }
+
+    /// Returns the personal (imported) search templates.
+    pub fn personal_search_templates(&self) -> &[SearchTemplate] {
+        &self.search_templates
+    }
+
+    /// Returns personal search templates followed by team templates with other names.
+    ///
+    /// A personal template shadows a team template of the same name.
+    pub fn search_templates(&self) -> Vec<SearchTemplate> {
+        let mut templates = self.search_templates.clone();
+        for template in self.team_search_templates() {
+            if !templates.iter().any(|t| t.name == template.name) {
+                templates.push(template.clone());
+            }
+        }
+        templates
+    }
+
+    /// Merges validated templates into the personal library and saves it.
+    ///
+    /// Templates whose name already exists are replaced only if `replace` is set.
+    ///
+    /// # Errors
+    /// Returns an error if the library file cannot be written.
+    pub fn import_search_templates(
+        &mut self,
+        templates: Vec<SearchTemplate>,
+        replace: bool,
+    ) -> Result<TemplateImportSummary> {
+        let mut library = self.search_templates.clone();
+        let summary = merge_templates(&mut library, templates, replace);
+        if !summary.added.is_empty() || !summary.replaced.is_empty() {
+            write_search_templates(&self.search_templates_path(), &library)?;
+            self.search_templates = library;
+        }
+        Ok(summary)
+    }
+}
+
+/// Returns the personal template library path for a given user config path.
+pub fn search_templates_path(config_path: &Path) -> PathBuf {
+    config_path.with_file_name(SEARCH_TEMPLATES_FILE_NAME)
+}
+
+/// Reads the personal template library, ignoring (and logging) an unusable file.
+pub(crate) fn read_search_templates(path: &Path) -> Vec<SearchTemplate> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Vec::new(),
+        Err(e) => {
+            tracing::warn!(path = %path.display(), error = %e, "Failed to read search templates");
+            return Vec::new();
+        }
+    };
+
+    match SearchTemplateFile::parse(&content) {
+        Ok(file) => file.templates,
+        Err(e) => {
+            tracing::warn!(path = %path.display(), error = %e, "Ignoring invalid search templates file");
+            Vec::new()
+        }
+    }
+}
+
+/// Atomically writes the personal template library (temp file + rename).
+pub(crate) fn write_search_templates(path: &Path, templates: &[SearchTemplate]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).context("Failed to create config directory")?;
+    }
+
+    let temp_path = path.with_extension("tmp");
+    let content = serde_json::to_string_pretty(&SearchTemplateFile::new(templates.to_vec()))?;
+    std::fs::write(&temp_path, content)
+        .context("Failed to write temporary search templates file")?;
+    std::fs::rename(&temp_path, path)
+        .context("Failed to rename temporary search templates file")?;
+    Ok(())
+}
+
+/// Splits a query into literal text and `$name$` or `{{name}}` placeholders.
+///
+/// `$$` is a literal `$`; `{{` that does not start a `{{name}}` placeholder is literal text.
+fn parse_query(query: &str) -> Result<Vec<Segment<'_>>, TemplateError> {
+    let mut segments = Vec::new();
+    let mut rest = query;
+    while let Some(start) = rest.find(['$', '{']) {
+        if start > 0 {
+            segments.push(Segment::Text(&rest[..start]));
+        }
+        if rest[start..].starts_with('{') {
+            match braced_placeholder(&rest[start..]) {
+                Some((name, len)) => {
+                    segments.push(Segment::Placeholder(name));
+                    rest = &rest[start + len..];
+                }
+                None => {
+                    segments.push(Segment::Text("{"));
+                    rest = &rest[start + 1..];
+                }
+            }
+            continue;
+        }
+        let after = &rest[start + 1..];
+        let end = after
+            .find('$')
+            .ok_or(TemplateError::UnterminatedPlaceholder)?;
+        if end == 0 {
+            segments.push(Segment::Text("$"));
+        } else {
+            let name = &after[..end];
+            if !is_parameter_name(name) {
+                return Err(TemplateError::InvalidParameterName(name.to_string()));
+            }
+            segments.push(Segment::Placeholder(name));
+        }
+        rest = &after[end + 1..];
+    }
+    if !rest.is_empty() {
+        segments.push(Segment::Text(rest));
+    }
+    Ok(segments)
+}
+
+/// Matches a `{{name}}` placeholder (inner whitespace allowed) at the start of `text`,
+/// returning the name and the placeholder's length.
+fn braced_placeholder(text: &str) -> Option<(&str, usize)> {
+    let inner = text.strip_prefix("{{")?;
+    let end = inner.find("}}")?;
+    let name = inner[..end].trim();
+    is_parameter_name(name).then_some((name, end + 4))
+}
+
+fn is_parameter_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Accepts `now`, epoch seconds, and relative modifiers such as `-24h`, `-7d@d`, or `@w0`.
+fn is_time_modifier(value: &str) -> bool {
+    if value == "now" || value.parse::<f64>().is_ok() {
+        return true;
+    }
+    value.starts_with(['-', '+', '@'])
+        && value.len() > 1
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '+' | '@'))
+}
+
+/// Accepts string, number, or boolean JSON defaults, storing them as text.
+fn default_value_from_scalar<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    use serde::de::Error;
+
+    match Option::<serde_json::Value>::deserialize(deserializer)? {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::String(s)) => Ok(Some(s)),
+        Some(serde_json::Value::Number(n)) => Ok(Some(n.to_string())),
+        Some(serde_json::Value::Bool(b)) => Ok(Some(b.to_string())),
+        Some(other) => Err(D::Error::custom(format!(
+            "parameter default must be a string, number, or boolean, got {}",
+            other
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failed_logins() -> SearchTemplate {
+        serde_json::from_str(
+            r#"{
+                "name": "Failed logins",
+                "query": "index=$index$ action=failure earliest=$since$ | stats count by user | where count >= $threshold$ | eval cost=\"$$5\"",
+                "parameters": [
+                    { "name": "index", "default": "security" },
+                    { "name": "since", "type": "time", "default": "-24h" },
+                    { "name": "threshold", "type": "integer", "default": 5, "description": "Minimum failures" }
+                ]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_render_substitutes_values_and_defaults() {
+        let template = failed_logins();
+        template.validate().unwrap();
+        assert_eq!(template.parameters[2].default.as_deref(), Some("5"));
+
+        let values = BTreeMap::from([("threshold".to_string(), "10".to_string())]);
+        assert_eq!(
+            template.render(&values).unwrap(),
+            "index=security action=failure earliest=-24h | stats count by user | where count >= 10 | eval cost=\"$5\""
+        );
+    }
+
+    #[test]
+    fn test_render_rejects_bad_values() {
+        let mut template = failed_logins();
+
+        let values = BTreeMap::from([("threshold".to_string(), "many".to_string())]);
+        assert!(matches!(
+            template.render(&values),
+            Err(TemplateError::InvalidValue { ref parameter, .. }) if parameter == "threshold"
+        ));
+
+        let values = BTreeMap::from([("host".to_string(), "web".to_string())]);
+        assert_eq!(
+            template.render(&values),
+            Err(TemplateError::UnknownParameter("host".to_string()))
+        );
+
+        template.parameters[0].default = None;
+        assert_eq!(
+            template.render(&BTreeMap::new()),
+            Err(TemplateError::MissingValue("index".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_validate_checks_schema_against_query() {
+        let mut template = failed_logins();
+        template.parameters.pop();
+        assert_eq!(
+            template.validate(),
+            Err(TemplateError::UndeclaredParameter("threshold".to_string()))
+        );
+
+        let mut template = failed_logins();
+        template.query = template.query.replace("$index$", "main");
+        assert_eq!(
+            template.validate(),
+            Err(TemplateError::UnusedParameter("index".to_string()))
+        );
+
+        let mut template = failed_logins();
+        template.parameters[1].default = Some("yesterday".to_string());
+        assert!(matches!(
+            template.validate(),
+            Err(TemplateError::InvalidDefault { ref parameter, .. }) if parameter == "since"
+        ));
+
+        let mut template = failed_logins();
+        template.query.push_str(" | eval x=\"$\"");
+        assert!(template.validate().is_err());
+    }
+
+    #[test]
+    fn test_braced_placeholders() {
+        let template = SearchTemplate::from_query(
+            "User activity",
+            "index={{index}} user={{ user }} | spath path=a{}.b | eval x=\"{{}}\" | search index={{index}}",
+        )
+        .unwrap();
+        let names: Vec<&str> = template
+            .parameters
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["index", "user"]);
+        assert!(template.parameters.iter().all(|p| p.default.is_none()));
+
+        let values = BTreeMap::from([
+            ("index".to_string(), "auth".to_string()),
+            ("user".to_string(), "alice".to_string()),
+        ]);
+        assert_eq!(
+            template.render(&values).unwrap(),
+            "index=auth user=alice | spath path=a{}.b | eval x=\"{{}}\" | search index=auth"
+        );
+
+        assert_eq!(
+            SearchTemplate::from_query("Bad", "index={{bad-name}} | eval x=$y"),
+            Err(TemplateError::UnterminatedPlaceholder)
+        );
+        assert_eq!(
+            SearchTemplate::from_query(" ", "index=main"),
+            Err(TemplateError::Empty)
+        );
+    }
+
+    #[test]
+    fn test_parameter_type_checks() {
+        use TemplateParameterType::*;
+        assert!(Integer.check("42").is_ok());
+        assert!(Integer.check("4.2").is_err());
+        assert!(Number.check("4.2").is_ok());
+        assert!(Boolean.check("true").is_ok());
+        assert!(Boolean.check("yes").is_err());
+        for value in ["now", "-24h", "-7d@d", "@w0", "1700000000"] {
+            assert!(Time.check(value).is_ok(), "{value}");
+        }
+        assert!(Time.check("last week").is_err());
+    }
+
+    #[test]
+    fn test_file_parse_rejects_bad_version_and_duplicates() {
+        let file = SearchTemplateFile::new(vec![failed_logins()]);
+        let json = serde_json::to_string(&file).unwrap();
+        assert_eq!(SearchTemplateFile::parse(&json).unwrap(), file);
+
+        let err = SearchTemplateFile::parse(r#"{ "version": 2, "templates": [] }"#).unwrap_err();
+        assert!(matches!(err, TemplateFileError::UnsupportedVersion(2)));
+
+        let duplicated = SearchTemplateFile::new(vec![failed_logins(), failed_logins()]);
+        let err =
+            SearchTemplateFile::parse(&serde_json::to_string(&duplicated).unwrap()).unwrap_err();
+        assert!(
+            matches!(err, TemplateFileError::DuplicateName(ref name) if name == "Failed logins")
+        );
+    }
+
+    #[test]
+    fn test_merge_and_library_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = search_templates_path(&dir.path().join("config.json"));
+        assert!(read_search_templates(&path).is_empty());
+
+        let mut library = vec![failed_logins()];
+        let mut updated = failed_logins();
+        updated.description = Some("v2".to_string());
+        let errors = SearchTemplate {
+            name: "Errors".to_string(),
+            query: "index=main log_level=ERROR".to_string(),
+            description: None,
+            parameters: Vec::new(),
+        };
+
+        let summary = merge_templates(&mut library, vec![updated.clone(), errors.clone()], false);
+        assert_eq!(summary.added, vec!["Errors"]);
+        assert_eq!(summary.skipped, vec!["Failed logins"]);
+        assert_eq!(library[0].description, None);
+
+        let summary = merge_templates(&mut library, vec![updated], true);
+        assert_eq!(summary.replaced, vec!["Failed logins"]);
+        assert_eq!(library[0].description.as_deref(), Some("v2"));
+
+        write_search_templates(&path, &library).unwrap();
+        assert_eq!(path.file_name().unwrap(), SEARCH_TEMPLATES_FILE_NAME);
+        assert_eq!(read_search_templates(&path), library);
+    }
+}
@@ -0,0 +1,272 @@
+//! User-defined color themes loaded from TOML files.
+//!
+//! Responsibilities:
+//! - Define the theme file format (`ThemeFile`): a built-in base theme plus named
+//!   palettes for each UI area (chrome, header, tables, severity, logs, syntax).
+//! - Read every `*.toml` theme from the `themes/` directory beside the user config.
+//!
+//! Does NOT handle:
+//! - Parsing color values or expanding themes into runtime colors (see `splunk-tui::theme`).
+//! - Persisting which theme is selected (see `PersistedState::custom_theme`).
+//!
+//! Invariants:
+//! - Colors are kept as strings so this crate stays independent of the TUI color types.
+//! - A theme's name defaults to its file stem; when two files share a name, the first
+//!   file in name order wins.
+//! - Unset palette entries keep the base theme's color.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::profiles::ConfigManager;
+use crate::types::ColorTheme;
+
+/// Directory holding user theme files, stored beside the user config file.
+pub const THEMES_DIR_NAME: &str = "themes";
+
+/// A user-defined theme file.
+///
+/// ```toml
+/// name = "Solarized"
+/// base = "dark"
+///
+/// [ui]
+/// border = "#268bd2"
+///
+/// [severity]
+/// error = "#dc322f"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThemeFile {
+    /// Display name (defaults to the file stem)
+    #[serde(default)]
+    pub name: String,
+    /// Built-in theme supplying every color the file does not set
+    #[serde(default)]
+    pub base: ColorTheme,
+    /// Screen chrome: text, borders, titles, and selection
+    #[serde(default)]
+    pub ui: UiPalette,
+    /// Header health indicator
+    #[serde(default)]
+    pub header: HeaderPalette,
+    /// Table header row
+    #[serde(default)]
+    pub table: TablePalette,
+    /// Severity colors used by toasts, status lines, and indicators
+    #[serde(default)]
+    pub severity: SeverityPalette,
+    /// Internal log levels
+    #[serde(default)]
+    pub logs: LogPalette,
+    /// SPL syntax highlighting
+    #[serde(default)]
+    pub syntax: SyntaxPalette,
+}
+
+/// Screen chrome colors.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UiPalette {
+    pub background: Option<String>,
+    pub text: Option<String>,
+    pub text_dim: Option<String>,
+    pub border: Option<String>,
+    pub title: Option<String>,
+    pub accent: Option<String>,
+    pub highlight_fg: Option<String>,
+    pub highlight_bg: Option<String>,
+    pub disabled: Option<String>,
+}
+
+/// Header health indicator colors.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HeaderPalette {
+    pub healthy: Option<String>,
+    pub unhealthy: Option<String>,
+    pub unknown: Option<String>,
+}
+
+/// Table header colors.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TablePalette {
+    pub header_fg: Option<String>,
+    pub header_bg: Option<String>,
+}
+
+/// Severity colors.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SeverityPalette {
+    pub success: Option<String>,
+    pub warning: Option<String>,
+    pub error: Option<String>,
+    pub info: Option<String>,
+}
+
+/// Log level colors.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogPalette {
+    pub error: Option<String>,
+    pub warn: Option<String>,
+    pub info: Option<String>,
+    pub debug: Option<String>,
+    pub component: Option<String>,
+}
+
+/// SPL syntax highlighting colors.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SyntaxPalette {
+    pub command: Option<String>,
+    pub operator: Option<String>,
+    pub function: Option<String>,
+    pub string: Option<String>,
+    pub number: Option<String>,
+    pub comment: Option<String>,
+    pub punctuation: Option<String>,
+    pub pipe: Option<String>,
+    pub comparison: Option<String>,
+}
+
+impl ThemeFile {
+    /// Parses a theme file, naming it `default_name` when it sets no name.
+    ///
+    /// # Errors
+    /// Returns an error if `content` is not valid TOML or has unknown keys.
+    pub fn parse(content: &str, default_name: &str) -> Result<Self, toml::de::Error> {
+        let mut theme: Self = toml::from_str(content)?;
+        if theme.name.trim().is_empty() {
+            theme.name = default_name.to_string();
+        }
+        Ok(theme)
+    }
+}
+
+/// Themes found in the themes directory, plus the files that could not be loaded.
+#[derive(Debug, Clone, Default)]
+pub struct ThemeLibrary {
+    /// Valid themes, sorted by file name
+    pub themes: Vec<ThemeFile>,
+    /// One message per unreadable or invalid theme file
+    pub errors: Vec<String>,
+}
+
+impl ConfigManager {
+    /// Returns the user themes directory for this config file.
+    pub fn themes_dir(&self) -> PathBuf {
+        themes_dir(self.config_path())
+    }
+
+    /// Loads every user theme file.
+    pub fn load_themes(&self) -> ThemeLibrary {
+        read_theme_files(&self.themes_dir())
+    }
+}
+
+/// Returns the user themes directory for a given user config path.
+pub fn themes_dir(config_path: &Path) -> PathBuf {
+    config_path.with_file_name(THEMES_DIR_NAME)
+}
+
+/// Reads every `*.toml` file in `dir`; a missing directory yields no themes.
+pub fn read_theme_files(dir: &Path) -> ThemeLibrary {
+    let mut library = ThemeLibrary::default();
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return library,
+        Err(e) => {
+            library
+                .errors
+                .push(format!("Failed to read {}: {}", dir.display(), e));
+            return library;
+        }
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        let stem = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let parsed = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|content| ThemeFile::parse(&content, &stem).map_err(|e| e.to_string()));
+        match parsed {
+            Ok(theme) if library.themes.iter().any(|t| t.name == theme.name) => {
+                library.errors.push(format!(
+                    "{}: duplicate theme name '{}'",
+                    path.display(),
+                    theme.name
+                ));
+            }
+            Ok(theme) => library.themes.push(theme),
+            Err(e) => library.errors.push(format!("{}: {}", path.display(), e)),
+        }
+    }
+    library
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_theme_file_sections() {
+        let theme = ThemeFile::parse(
+            r##"
+                base = "light"
+
+                [ui]
+                border = "#268bd2"
+
+                [severity]
+                error = "red"
+            "##,
+            "solarized",
+        )
+        .unwrap();
+
+        assert_eq!(theme.name, "solarized");
+        assert_eq!(theme.base, ColorTheme::Light);
+        assert_eq!(theme.ui.border.as_deref(), Some("#268bd2"));
+        assert_eq!(theme.severity.error.as_deref(), Some("red"));
+        assert!(theme.ui.text.is_none());
+    }
+
+    #[test]
+    fn test_parse_theme_file_rejects_unknown_keys() {
+        assert!(ThemeFile::parse("[ui]\nborderr = \"red\"\n", "typo").is_err());
+    }
+
+    #[test]
+    fn test_read_theme_files_collects_themes_and_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("b.toml"), "name = \"Ocean\"\n").unwrap();
+        std::fs::write(dir.path().join("a.toml"), "[ui]\ntext = \"white\"\n").unwrap();
+        std::fs::write(dir.path().join("c.toml"), "name = \"Ocean\"\n").unwrap();
+        std::fs::write(dir.path().join("d.toml"), "not toml [").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+        let library = read_theme_files(dir.path());
+
+        let names: Vec<&str> = library.themes.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["a", "Ocean"]);
+        assert_eq!(library.errors.len(), 2);
+        assert!(
+            read_theme_files(&dir.path().join("missing"))
+                .themes
+                .is_empty()
+        );
+    }
+}
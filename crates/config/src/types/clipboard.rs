@@ -0,0 +1,85 @@
+//! Persisted clipboard backend selection for Splunk TUI configuration.
+//!
+//! Responsibilities:
+//! - Define the user-selectable clipboard backend (`ClipboardBackend`).
+//! - Provide display helpers and cycle order for the Settings screen.
+//!
+//! Does NOT handle:
+//! - Writing to the clipboard or the fallback chain (see `splunk-tui::app::clipboard`).
+//!
+//! Invariants:
+//! - `Auto` is the default and the only backend that falls back to another.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How copy actions deliver text, persisted in config/state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ClipboardBackend {
+    /// Native clipboard, then OSC52, then a temp file.
+    #[default]
+    Auto,
+    /// The OS clipboard only.
+    Native,
+    /// OSC52 terminal escape sequence only (works over SSH and in tmux).
+    Osc52,
+    /// Write copied text to a temp file only.
+    File,
+}
+
+impl ClipboardBackend {
+    /// Human-readable display name for UI surfaces.
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Self::Auto => "Auto (native → OSC52 → file)",
+            Self::Native => "Native",
+            Self::Osc52 => "OSC52 (terminal)",
+            Self::File => "Temp file",
+        }
+    }
+
+    /// Next backend in the cycle (used by Settings screen "b" key).
+    pub fn cycle_next(self) -> Self {
+        match self {
+            Self::Auto => Self::Native,
+            Self::Native => Self::Osc52,
+            Self::Osc52 => Self::File,
+            Self::File => Self::Auto,
+        }
+    }
+}
+
+impl fmt::Display for ClipboardBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.display_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clipboard_backend_cycle_visits_every_backend() {
+        let mut backend = ClipboardBackend::default();
+        assert_eq!(backend, ClipboardBackend::Auto);
+        for expected in [
+            ClipboardBackend::Native,
+            ClipboardBackend::Osc52,
+            ClipboardBackend::File,
+            ClipboardBackend::Auto,
+        ] {
+            backend = backend.cycle_next();
+            assert_eq!(backend, expected);
+        }
+    }
+
+    #[test]
+    fn test_clipboard_backend_serde_snake_case() {
+        let json = serde_json::to_string(&ClipboardBackend::Osc52).unwrap();
+        assert_eq!(json, "\"osc52\"");
+        let parsed: ClipboardBackend = serde_json::from_str("\"file\"").unwrap();
+        assert_eq!(parsed, ClipboardBackend::File);
+    }
+}
@@ -4,7 +4,7 @@
 //! - Define canonical connection/auth configuration types shared across CLI and TUI.
 //!
 //! Responsibilities:
-//! - Define connection settings (URL, TLS verification, timeouts, retries).
+//! - Define connection settings (URL, TLS verification, timeouts, retries, rate limits).
 //! - Define the main `Config` structure combining connection and auth.
 //! - Provide serialization helpers for `Duration`.
 //! - Provide convenience constructors for common config patterns.
@@ -26,6 +26,7 @@ use crate::constants::{
     DEFAULT_SESSION_TTL_SECS, DEFAULT_SPLUNK_PORT, DEFAULT_TIMEOUT_SECS,
 };
 use crate::types::auth::{AuthConfig, AuthStrategy};
+use crate::types::health::HealthThresholds;
 use secrecy::SecretString;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
@@ -56,6 +57,95 @@ mod duration_seconds {
     }
 }
 
+/// Client-side request rate limit (token bucket).
+///
+/// Parsed from `RATE` or `RATE:BURST` (e.g., `10` or `10:20`), where `RATE` is
+/// requests per second and `BURST` is the bucket size. Burst defaults to the
+/// rate rounded up (at least 1).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RateLimitConfig {
+    /// Sustained requests per second.
+    pub requests_per_second: f64,
+    /// Maximum number of requests that may be sent back-to-back.
+    pub burst: u32,
+}
+
+impl RateLimitConfig {
+    /// Create a rate limit, validating that both values are positive.
+    pub fn new(requests_per_second: f64, burst: u32) -> Result<Self, String> {
+        if !requests_per_second.is_finite() || requests_per_second <= 0.0 {
+            return Err(format!(
+                "requests per second must be a positive number (got {})",
+                requests_per_second
+            ));
+        }
+        if burst == 0 {
+            return Err("burst must be at least 1".to_string());
+        }
+        Ok(Self {
+            requests_per_second,
+            burst,
+        })
+    }
+}
+
+impl std::str::FromStr for RateLimitConfig {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (rate, burst) = match s.trim().split_once(':') {
+            Some((rate, burst)) => (rate, Some(burst)),
+            None => (s.trim(), None),
+        };
+        let rate: f64 = rate
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid rate '{}': expected RATE or RATE:BURST", s))?;
+        let burst = match burst {
+            Some(burst) => burst
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid burst in '{}': expected a whole number", s))?,
+            None => (rate.ceil() as u32).max(1),
+        };
+        Self::new(rate, burst)
+    }
+}
+
+/// Default knowledge-object namespace for requests.
+///
+/// Mirrors the client's `Namespace`: requests go to `/servicesNS/{owner}/{app}`,
+/// and listings are limited to objects in `app` shared at `sharing`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NamespaceConfig {
+    /// Object owner (user context), or any owner when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// App context, or any app when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<String>,
+    /// Sharing level (`user`, `app` or `global`), or any when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sharing: Option<String>,
+}
+
+/// Splunk Cloud settings.
+///
+/// Cloud stacks disable on-prem-only operations and manage indexes and HEC
+/// tokens through the Admin Config Service (ACS) instead of splunkd.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CloudConfig {
+    /// Whether the deployment is Splunk Cloud; detected when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// ACS stack name; derived from a `*.splunkcloud.com` base URL when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acs_stack: Option<String>,
+    /// ACS API base URL; `https://admin.splunk.com` when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acs_url: Option<String>,
+}
+
 /// Connection configuration for Splunk server.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConnectionConfig {
@@ -105,6 +195,18 @@ pub struct ConnectionConfig {
     /// Number of requests allowed in half-open state
     #[serde(default = "default_circuit_half_open_requests")]
     pub circuit_half_open_requests: u32,
+    /// Client-side request rate limit; `None` disables throttling.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimitConfig>,
+    /// Default knowledge-object namespace; `None` uses the global `/services` context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<NamespaceConfig>,
+    /// Splunk Cloud settings; `None` detects Cloud and uses ACS defaults.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<CloudConfig>,
+    /// Health check thresholds from the profile; `None` uses the defaults.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_thresholds: Option<HealthThresholds>,
 }
 
 /// Default session expiry buffer in seconds.
@@ -192,6 +294,10 @@ impl Default for Config {
                 circuit_failure_window_seconds: default_circuit_failure_window(),
                 circuit_reset_timeout_seconds: default_circuit_reset_timeout(),
                 circuit_half_open_requests: default_circuit_half_open_requests(),
+                rate_limit: None,
+                namespace: None,
+                cloud: None,
+                health_thresholds: None,
             },
             auth: AuthConfig {
                 strategy: AuthStrategy::SessionToken {
@@ -242,6 +348,10 @@ impl Config {
                 circuit_failure_window_seconds: default_circuit_failure_window(),
                 circuit_reset_timeout_seconds: default_circuit_reset_timeout(),
                 circuit_half_open_requests: default_circuit_half_open_requests(),
+                rate_limit: None,
+                namespace: None,
+                cloud: None,
+                health_thresholds: None,
             },
             auth: AuthConfig {
                 strategy: AuthStrategy::ApiToken { token },
@@ -265,6 +375,10 @@ impl Config {
                 circuit_failure_window_seconds: default_circuit_failure_window(),
                 circuit_reset_timeout_seconds: default_circuit_reset_timeout(),
                 circuit_half_open_requests: default_circuit_half_open_requests(),
+                rate_limit: None,
+                namespace: None,
+                cloud: None,
+                health_thresholds: None,
             },
             auth: AuthConfig {
                 strategy: AuthStrategy::SessionToken { username, password },
@@ -323,6 +437,10 @@ mod tests {
             circuit_failure_window_seconds: default_circuit_failure_window(),
             circuit_reset_timeout_seconds: default_circuit_reset_timeout(),
             circuit_half_open_requests: default_circuit_half_open_requests(),
+            rate_limit: None,
+            namespace: None,
+            cloud: None,
+            health_thresholds: None,
         };
 
         let json = serde_json::to_string(&config).unwrap();
@@ -332,6 +450,30 @@ mod tests {
         assert_eq!(deserialized.max_retries, 5);
     }
 
+    #[test]
+    fn test_rate_limit_parses_rate_and_burst() {
+        let limit: RateLimitConfig = "10:25".parse().unwrap();
+        assert_eq!(limit.requests_per_second, 10.0);
+        assert_eq!(limit.burst, 25);
+
+        let limit: RateLimitConfig = "2.5".parse().unwrap();
+        assert_eq!(limit.requests_per_second, 2.5);
+        assert_eq!(limit.burst, 3);
+
+        let limit: RateLimitConfig = "0.2".parse().unwrap();
+        assert_eq!(limit.burst, 1);
+    }
+
+    #[test]
+    fn test_rate_limit_rejects_invalid_values() {
+        assert!("".parse::<RateLimitConfig>().is_err());
+        assert!("fast".parse::<RateLimitConfig>().is_err());
+        assert!("0".parse::<RateLimitConfig>().is_err());
+        assert!("-1".parse::<RateLimitConfig>().is_err());
+        assert!("10:0".parse::<RateLimitConfig>().is_err());
+        assert!("10:x".parse::<RateLimitConfig>().is_err());
+    }
+
     /// Test that Config Debug output does not expose secrets.
     #[test]
     fn test_config_debug_does_not_expose_secrets() {
@@ -386,6 +528,10 @@ mod tests {
             circuit_failure_window_seconds: default_circuit_failure_window(),
             circuit_reset_timeout_seconds: default_circuit_reset_timeout(),
             circuit_half_open_requests: default_circuit_half_open_requests(),
+            rate_limit: None,
+            namespace: None,
+            cloud: None,
+            health_thresholds: None,
         };
 
         let debug_output = format!("{:?}", config);
@@ -0,0 +1,172 @@
+//! Health check thresholds for Splunk TUI configuration.
+//!
+//! Responsibilities:
+//! - Define `HealthThresholds`, set per profile (`health_thresholds`) and as the
+//!   TUI default in the persisted state.
+//! - Classify a usage percentage against its warning and critical thresholds.
+//!
+//! Does NOT handle:
+//! - Fetching or evaluating health data (see `splunk_client::HealthCheckOutput::threshold_warnings`).
+//!
+//! Invariants:
+//! - Missing fields deserialize to the defaults in `constants`, so a profile can
+//!   set only the thresholds it changes.
+//! - `validate` rejects percentages outside 0-100 and a warning above its critical threshold.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::constants::{
+    DEFAULT_DISK_CRITICAL_PCT, DEFAULT_DISK_WARNING_PCT, DEFAULT_KVSTORE_MAX_REPLICATION_LAG_SECS,
+    DEFAULT_LICENSE_CRITICAL_PCT, DEFAULT_LICENSE_WARNING_PCT, DEFAULT_MIN_SEARCHABLE_PEERS,
+};
+
+/// Where a measured value falls against its thresholds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ThresholdLevel {
+    Ok,
+    Warning,
+    Critical,
+}
+
+impl ThresholdLevel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Warning => "warning",
+            Self::Critical => "critical",
+        }
+    }
+}
+
+impl fmt::Display for ThresholdLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Thresholds the aggregated health check flags conditions against.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HealthThresholds {
+    /// License usage (percent of daily quota) at which health warns.
+    pub license_warning_pct: f64,
+    /// License usage (percent of daily quota) at which health is critical.
+    pub license_critical_pct: f64,
+    /// KVStore replication lag in seconds above which health warns.
+    pub kvstore_max_replication_lag_secs: u64,
+    /// On a cluster manager, fewer searchable peers than this is a warning; 0 disables the check.
+    pub min_searchable_peers: u32,
+    /// Disk usage (percent of partition capacity) at which health warns.
+    pub disk_warning_pct: f64,
+    /// Disk usage (percent of partition capacity) at which health is critical.
+    pub disk_critical_pct: f64,
+}
+
+impl Default for HealthThresholds {
+    fn default() -> Self {
+        Self {
+            license_warning_pct: DEFAULT_LICENSE_WARNING_PCT,
+            license_critical_pct: DEFAULT_LICENSE_CRITICAL_PCT,
+            kvstore_max_replication_lag_secs: DEFAULT_KVSTORE_MAX_REPLICATION_LAG_SECS,
+            min_searchable_peers: DEFAULT_MIN_SEARCHABLE_PEERS,
+            disk_warning_pct: DEFAULT_DISK_WARNING_PCT,
+            disk_critical_pct: DEFAULT_DISK_CRITICAL_PCT,
+        }
+    }
+}
+
+impl HealthThresholds {
+    /// Check that percentages are within 0-100 and each warning is at most its critical threshold.
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, warning, critical) in [
+            (
+                "license",
+                self.license_warning_pct,
+                self.license_critical_pct,
+            ),
+            ("disk", self.disk_warning_pct, self.disk_critical_pct),
+        ] {
+            for (kind, pct) in [("warning", warning), ("critical", critical)] {
+                if !(0.0..=100.0).contains(&pct) {
+                    return Err(format!(
+                        "{} {} threshold must be between 0 and 100, got {}",
+                        name, kind, pct
+                    ));
+                }
+            }
+            if warning > critical {
+                return Err(format!(
+                    "{} warning threshold ({}%) must not be greater than the critical threshold ({}%)",
+                    name, warning, critical
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Level of a license usage percentage.
+    pub fn license_level(&self, pct: f64) -> ThresholdLevel {
+        level(pct, self.license_warning_pct, self.license_critical_pct)
+    }
+
+    /// Level of a disk usage percentage.
+    pub fn disk_level(&self, pct: f64) -> ThresholdLevel {
+        level(pct, self.disk_warning_pct, self.disk_critical_pct)
+    }
+}
+
+fn level(pct: f64, warning: f64, critical: f64) -> ThresholdLevel {
+    if pct >= critical {
+        ThresholdLevel::Critical
+    } else if pct >= warning {
+        ThresholdLevel::Warning
+    } else {
+        ThresholdLevel::Ok
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_thresholds_use_defaults() {
+        let thresholds: HealthThresholds =
+            serde_json::from_str(r#"{"license_warning_pct": 80, "min_searchable_peers": 3}"#)
+                .unwrap();
+        assert_eq!(thresholds.license_warning_pct, 80.0);
+        assert_eq!(thresholds.min_searchable_peers, 3);
+        assert_eq!(
+            thresholds.license_critical_pct,
+            DEFAULT_LICENSE_CRITICAL_PCT
+        );
+        assert_eq!(thresholds.disk_warning_pct, DEFAULT_DISK_WARNING_PCT);
+        assert!(thresholds.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_and_levels() {
+        let thresholds = HealthThresholds::default();
+        assert_eq!(thresholds.license_level(69.9), ThresholdLevel::Ok);
+        assert_eq!(thresholds.license_level(70.0), ThresholdLevel::Warning);
+        assert_eq!(thresholds.license_level(95.0), ThresholdLevel::Critical);
+        assert_eq!(thresholds.disk_level(85.0), ThresholdLevel::Warning);
+
+        let inverted = HealthThresholds {
+            license_warning_pct: 95.0,
+            ..Default::default()
+        };
+        assert!(inverted.validate().unwrap_err().contains("license warning"));
+        let out_of_range = HealthThresholds {
+            disk_critical_pct: 120.0,
+            ..Default::default()
+        };
+        assert!(
+            out_of_range
+                .validate()
+                .unwrap_err()
+                .contains("between 0 and 100")
+        );
+    }
+}
@@ -1,7 +1,7 @@
 //! Configuration type definitions for Splunk TUI.
 //!
 //! Responsibilities:
-//! - Define configuration types for authentication, connections, themes, profiles, and keybindings.
+//! - Define configuration types for authentication, connections, themes, clipboard, health thresholds, profiles, and keybindings.
 //! - Provide serialization helpers for sensitive types (secrets, durations).
 //! - Ensure consistent defaults and type safety across the configuration system.
 //!
@@ -18,17 +18,22 @@
 //! - `KEYRING_SERVICE` is the canonical service name for all keyring operations.
 
 mod auth;
+mod clipboard;
 pub(crate) mod connection;
+mod health;
 pub mod keybind;
 mod profile;
 mod theme;
 
 pub use auth::{AuthConfig, AuthStrategy, KEYRING_SERVICE, SecureValue};
+pub use clipboard::ClipboardBackend;
 pub use connection::{
-    Config, ConnectionConfig, default_circuit_breaker_enabled, default_circuit_failure_threshold,
+    CloudConfig, Config, ConnectionConfig, NamespaceConfig, RateLimitConfig,
+    default_circuit_breaker_enabled, default_circuit_failure_threshold,
     default_circuit_failure_window, default_circuit_half_open_requests,
     default_circuit_reset_timeout,
 };
+pub use health::{HealthThresholds, ThresholdLevel};
 pub use keybind::{KeybindAction, KeybindOverrides};
 pub use profile::ProfileConfig;
 pub use theme::ColorTheme;
@@ -14,6 +14,7 @@
 //! - ProfileConfig uses `#[serde(default)]` for backward compatibility.
 
 use crate::types::auth::SecureValue;
+use crate::types::health::HealthThresholds;
 use serde::{Deserialize, Serialize};
 
 /// Profile configuration for storing named connection profiles.
@@ -44,6 +45,12 @@ pub struct ProfileConfig {
     /// Health check interval in seconds (how often to poll server health)
     /// Default: 60 seconds
     pub health_check_interval_seconds: Option<u64>,
+    /// Whether this profile targets Splunk Cloud; detected from server info when unset
+    pub cloud: Option<bool>,
+    /// Admin Config Service stack name (Splunk Cloud only)
+    pub acs_stack: Option<String>,
+    /// Thresholds for the aggregated health check; unset fields use the defaults
+    pub health_thresholds: Option<HealthThresholds>,
 }
 
 #[cfg(test)]
@@ -68,6 +75,9 @@ mod tests {
             session_expiry_buffer_seconds: Some(default_session_expiry_buffer()),
             session_ttl_seconds: Some(default_session_ttl()),
             health_check_interval_seconds: Some(default_health_check_interval()),
+            cloud: None,
+            acs_stack: None,
+            health_thresholds: None,
         };
 
         let json = serde_json::to_string(&original).unwrap();
@@ -126,6 +136,9 @@ mod tests {
             session_expiry_buffer_seconds: Some(default_session_expiry_buffer()),
             session_ttl_seconds: Some(default_session_ttl()),
             health_check_interval_seconds: Some(default_health_check_interval()),
+            cloud: None,
+            acs_stack: None,
+            health_thresholds: None,
         };
 
         let debug_output = format!("{:?}", profile);
@@ -156,6 +169,9 @@ mod tests {
             session_expiry_buffer_seconds: Some(default_session_expiry_buffer()),
             session_ttl_seconds: Some(default_session_ttl()),
             health_check_interval_seconds: Some(default_health_check_interval()),
+            cloud: None,
+            acs_stack: None,
+            health_thresholds: None,
         };
 
         let debug_output = format!("{:?}", profile);
@@ -28,6 +28,8 @@ pub enum ColorTheme {
     Protanopia,
     Tritanopia,
     Monochrome,
+    /// Light or Dark, chosen from the terminal background at startup.
+    Auto,
 }
 
 impl ColorTheme {
@@ -42,6 +44,7 @@ impl ColorTheme {
             Self::Protanopia => "Protanopia (Blue/Orange)",
             Self::Tritanopia => "Tritanopia (Red/Teal)",
             Self::Monochrome => "Monochrome",
+            Self::Auto => "Auto (terminal)",
         }
     }
 
@@ -55,7 +58,8 @@ impl ColorTheme {
             Self::Deuteranopia => Self::Protanopia,
             Self::Protanopia => Self::Tritanopia,
             Self::Tritanopia => Self::Monochrome,
-            Self::Monochrome => Self::Default,
+            Self::Monochrome => Self::Auto,
+            Self::Auto => Self::Default,
         }
     }
 }
@@ -89,6 +93,7 @@ mod tests {
             "Tritanopia (Red/Teal)"
         );
         assert_eq!(ColorTheme::Monochrome.display_name(), "Monochrome");
+        assert_eq!(ColorTheme::Auto.display_name(), "Auto (terminal)");
     }
 
     #[test]
@@ -106,7 +111,8 @@ mod tests {
         );
         assert_eq!(ColorTheme::Protanopia.cycle_next(), ColorTheme::Tritanopia);
         assert_eq!(ColorTheme::Tritanopia.cycle_next(), ColorTheme::Monochrome);
-        assert_eq!(ColorTheme::Monochrome.cycle_next(), ColorTheme::Default);
+        assert_eq!(ColorTheme::Monochrome.cycle_next(), ColorTheme::Auto);
+        assert_eq!(ColorTheme::Auto.cycle_next(), ColorTheme::Default);
     }
 
     #[test]
@@ -128,6 +134,7 @@ mod tests {
             ColorTheme::Protanopia,
             ColorTheme::Tritanopia,
             ColorTheme::Monochrome,
+            ColorTheme::Auto,
         ] {
             let json = serde_json::to_string(&theme).unwrap();
             let deserialized: ColorTheme = serde_json::from_str(&json).unwrap();
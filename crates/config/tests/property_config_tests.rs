@@ -110,6 +110,10 @@ fn connection_config_strategy() -> impl Strategy<Value = ConnectionConfig> {
                     circuit_failure_window_seconds: circuit_failure_window,
                     circuit_reset_timeout_seconds: circuit_reset_timeout,
                     circuit_half_open_requests,
+                    rate_limit: None,
+                    namespace: None,
+                    cloud: None,
+                    health_thresholds: None,
                 }
             },
         )
@@ -34,8 +34,8 @@ pub use format::ExportFormat;
 pub use redaction::RedactedAction;
 pub use routing::AppActionRoute;
 pub use variants::{
-    Action, InstanceOverview, InstanceStatus, LicenseData, MultiInstanceOverviewData, OverviewData,
-    OverviewResource,
+    Action, InstanceOverview, InstanceStatus, LicenseData, MultiInstanceOverviewData, OfflineData,
+    OverviewData, OverviewResource,
 };
 
 #[cfg(test)]
@@ -31,7 +31,7 @@
 //! // Logs: Handling action: RunSearch(<43 chars, hash=296582a1>)
 //! ```
 
-use crate::action::variants::Action;
+use crate::action::variants::{Action, OfflineData};
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
@@ -68,6 +68,15 @@ impl std::fmt::Debug for RedactedAction<'_> {
             Action::RunSearch { query, .. } => {
                 write!(f, "RunSearch({})", redact_query(query))
             }
+            Action::LoadTopValues { query, .. } => {
+                write!(f, "LoadTopValues({})", redact_query(query))
+            }
+            Action::LoadMacroExpansion { query } => {
+                write!(f, "LoadMacroExpansion({})", redact_query(query))
+            }
+            Action::SearchSplunkbase { query } => {
+                write!(f, "SearchSplunkbase({})", redact_query(query))
+            }
             Action::CopyToClipboard(text) => {
                 write!(f, "CopyToClipboard(<{} chars>)", text.len())
             }
@@ -93,9 +102,92 @@ impl std::fmt::Debug for RedactedAction<'_> {
             Action::DeleteJobsBatch(sids) => {
                 write!(f, "DeleteJobsBatch([{} job(s)])", sids.len())
             }
+            Action::PrunableJobsFound(result) => match result {
+                Ok(sids) => write!(f, "PrunableJobsFound([{} job(s)])", sids.len()),
+                Err(_) => write!(f, "PrunableJobsFound(<error>)"),
+            },
+            Action::BulkUpdateSavedSearches { names, action } => write!(
+                f,
+                "BulkUpdateSavedSearches({}, [{} search(es)])",
+                action.slug(),
+                names.len()
+            ),
+            Action::SavedSearchesBulkUpdated(result) => match result {
+                Ok(report) => write!(
+                    f,
+                    "SavedSearchesBulkUpdated({}, [{} search(es)])",
+                    report.action,
+                    report.items.len()
+                ),
+                Err(_) => write!(f, "SavedSearchesBulkUpdated(<error>)"),
+            },
+            Action::ReassignSavedSearches { names, .. } => {
+                write!(f, "ReassignSavedSearches([{} search(es)])", names.len())
+            }
+            Action::SavedSearchesReassigned(result) => match result {
+                Ok(report) => write!(
+                    f,
+                    "SavedSearchesReassigned([{} search(es)])",
+                    report.items.len()
+                ),
+                Err(_) => write!(f, "SavedSearchesReassigned(<error>)"),
+            },
             Action::EnableApp(name) => write!(f, "EnableApp({})", name),
             Action::DisableApp(name) => write!(f, "DisableApp({})", name),
             Action::SearchInput(c) => write!(f, "SearchInput({:?})", c),
+            // Remote credentials are only used once to exchange trust keys
+            Action::AddSearchPeer { params } => write!(f, "AddSearchPeer({})", params.uri),
+            Action::RunOnboardingWizard { plan } => {
+                write!(f, "RunOnboardingWizard(index={})", plan.index.name)
+            }
+            // The outcome holds the new HEC token
+            Action::OnboardingWizardFinished(result) => match result {
+                Ok(outcome) => write!(f, "OnboardingWizardFinished(index={})", outcome.index),
+                Err(failure) => {
+                    write!(f, "OnboardingWizardFinished(<failed at {}>)", failure.stage)
+                }
+            },
+            // Token values are secrets
+            Action::HecTokensLoaded(result) => match result {
+                Ok(items) => write!(f, "HecTokensLoaded(<{} items>)", items.len()),
+                Err(_) => write!(f, "HecTokensLoaded(<error>)"),
+            },
+            // Sample events are raw log lines and may contain sensitive data
+            Action::RunFieldExtractionTest {
+                samples,
+                extractions,
+            } => write!(
+                f,
+                "RunFieldExtractionTest(<{} samples, {} extractions>)",
+                samples.len(),
+                extractions.len()
+            ),
+            Action::FieldExtractionTested(result) => match result {
+                Ok(preview) => write!(
+                    f,
+                    "FieldExtractionTested(<{} of {} matched>)",
+                    preview.matched_count(),
+                    preview.events.len()
+                ),
+                Err(_) => write!(f, "FieldExtractionTested(<error>)"),
+            },
+            // Form fields may carry passwords or tokens; bodies may hold anything
+            Action::SendRestRequest(request) => write!(
+                f,
+                "SendRestRequest({} {}, <{} query params, {} form fields>)",
+                request.method,
+                request.path,
+                request.query.len(),
+                request.form.len()
+            ),
+            Action::RestResponseLoaded { result, .. } => match result {
+                Ok(_) => write!(f, "RestResponseLoaded(<ok>)"),
+                Err(_) => write!(f, "RestResponseLoaded(<error>)"),
+            },
+            Action::IndexSampleLoaded { index, result } => match result {
+                Ok(events) => write!(f, "IndexSampleLoaded({}, <{} events>)", index, events.len()),
+                Err(_) => write!(f, "IndexSampleLoaded({}, <error>)", index),
+            },
 
             // Search-related actions with sensitive data
             Action::SearchStarted(query) => {
@@ -163,10 +255,129 @@ impl std::fmt::Debug for RedactedAction<'_> {
                 Ok(items) => write!(f, "ClusterPeersLoaded(<{} items>)", items.len()),
                 Err(_) => write!(f, "ClusterPeersLoaded(<error>)"),
             },
+            Action::ClusterBucketHealthLoaded(result) => match result {
+                Ok(health) => write!(
+                    f,
+                    "ClusterBucketHealthLoaded(<{} fixups, {} indexes>)",
+                    health.fixups.len(),
+                    health.excess.len()
+                ),
+                Err(_) => write!(f, "ClusterBucketHealthLoaded(<error>)"),
+            },
+            Action::ClusterIndexComplianceLoaded(result) => match result {
+                Ok(indexes) => write!(
+                    f,
+                    "ClusterIndexComplianceLoaded(<{} indexes, {} non-compliant>)",
+                    indexes.len(),
+                    indexes.iter().filter(|i| !i.compliant).count()
+                ),
+                Err(_) => write!(f, "ClusterIndexComplianceLoaded(<error>)"),
+            },
+            Action::ClusterVersionsLoaded(result) => match result {
+                Ok(report) => write!(
+                    f,
+                    "ClusterVersionsLoaded(<{} nodes, {} skewed>)",
+                    report.nodes.len(),
+                    report.skew.len()
+                ),
+                Err(_) => write!(f, "ClusterVersionsLoaded(<error>)"),
+            },
+            Action::MacroExpansionLoaded(result) => match result {
+                Ok(expansion) => write!(
+                    f,
+                    "MacroExpansionLoaded(<{} macros, {} unknown>)",
+                    expansion.macros.len(),
+                    expansion.unknown.len()
+                ),
+                Err(_) => write!(f, "MacroExpansionLoaded(<error>)"),
+            },
+            Action::DashboardSourceLoaded(result) => match result {
+                Ok(dashboard) => write!(
+                    f,
+                    "DashboardSourceLoaded(<{} bytes>)",
+                    dashboard.xml_data.as_ref().map_or(0, String::len)
+                ),
+                Err(_) => write!(f, "DashboardSourceLoaded(<error>)"),
+            },
+            Action::TstatsBuilderLoaded(result) => match result {
+                Ok(datamodel) => write!(
+                    f,
+                    "TstatsBuilderLoaded(<{} bytes>)",
+                    datamodel.json_data.as_ref().map_or(0, String::len)
+                ),
+                Err(_) => write!(f, "TstatsBuilderLoaded(<error>)"),
+            },
+            Action::TopValuesLoaded { result, .. } => match result {
+                Ok(rows) => write!(f, "TopValuesLoaded(<{} rows>)", rows.len()),
+                Err(_) => write!(f, "TopValuesLoaded(<error>)"),
+            },
+            Action::LookupContentLoaded { name, result, .. } => match result {
+                Ok(content) => write!(
+                    f,
+                    "LookupContentLoaded(name={}, <{} rows>)",
+                    name,
+                    content.rows.len()
+                ),
+                Err(_) => write!(f, "LookupContentLoaded(name={}, <error>)", name),
+            },
+            Action::SaveLookupContent { name, content, .. } => write!(
+                f,
+                "SaveLookupContent(name={}, <{} rows>)",
+                name,
+                content.rows.len()
+            ),
             Action::HealthStatusLoaded(result) => match result {
                 Ok(_) => write!(f, "HealthStatusLoaded(<data>)"),
                 Err(_) => write!(f, "HealthStatusLoaded(<error>)"),
             },
+            Action::SessionExpired(_) => write!(f, "SessionExpired(<error>)"),
+            Action::OfflineSnapshotLoaded { data, .. } => match data {
+                OfflineData::Indexes(items) => {
+                    write!(f, "OfflineSnapshotLoaded(<{} indexes>)", items.len())
+                }
+                OfflineData::Jobs(items) => {
+                    write!(f, "OfflineSnapshotLoaded(<{} jobs>)", items.len())
+                }
+                OfflineData::Health(_) => write!(f, "OfflineSnapshotLoaded(<health>)"),
+            },
+            Action::ServerMessagesLoaded(result) => match result {
+                Ok(messages) => write!(f, "ServerMessagesLoaded(<{} messages>)", messages.len()),
+                Err(_) => write!(f, "ServerMessagesLoaded(<error>)"),
+            },
+            Action::CurrentContextLoaded(result) => match result {
+                Ok(context) => write!(
+                    f,
+                    "CurrentContextLoaded(<{} capabilities>)",
+                    context.capabilities.len()
+                ),
+                Err(_) => write!(f, "CurrentContextLoaded(<error>)"),
+            },
+            Action::SplunkbaseSearchLoaded(result) => match result {
+                Ok(apps) => write!(f, "SplunkbaseSearchLoaded(<{} apps>)", apps.len()),
+                Err(_) => write!(f, "SplunkbaseSearchLoaded(<error>)"),
+            },
+            Action::UserActivityLoaded(result) => match result {
+                Ok(report) => write!(f, "UserActivityLoaded(<{} days>)", report.days.len()),
+                Err(_) => write!(f, "UserActivityLoaded(<error>)"),
+            },
+            Action::OrphanedObjectsFound(result) => match result {
+                Ok(report) => write!(
+                    f,
+                    "OrphanedObjectsFound(<{} orphans of {} objects>)",
+                    report.orphans.len(),
+                    report.scanned
+                ),
+                Err(_) => write!(f, "OrphanedObjectsFound(<error>)"),
+            },
+            Action::AppContentsLoaded(result) => match result {
+                Ok(contents) => write!(
+                    f,
+                    "AppContentsLoaded({}, <{} objects>)",
+                    contents.app,
+                    contents.objects.len()
+                ),
+                Err(_) => write!(f, "AppContentsLoaded(<error>)"),
+            },
 
             // Profile-related actions
             Action::OpenProfileSelectorWithList(profiles) => {
@@ -189,6 +400,20 @@ impl std::fmt::Debug for RedactedAction<'_> {
             Action::ShowErrorDetails(_) => write!(f, "ShowErrorDetails(<redacted>)"),
             Action::ShowErrorDetailsFromCurrent => write!(f, "ShowErrorDetailsFromCurrent"),
 
+            // Operation labels and item names may contain profile names, paths, or SIDs
+            Action::OperationStarted(progress) => write!(
+                f,
+                "OperationStarted(id={}, label=<{} chars>, total={:?})",
+                progress.id,
+                progress.label.len(),
+                progress.total
+            ),
+            Action::OperationProgressed(update) => write!(
+                f,
+                "OperationProgressed(id={}, completed={}, failed={})",
+                update.id, update.completed, update.failed
+            ),
+
             // Non-sensitive simple actions - fall through to default Debug
             other => write!(f, "{:?}", other),
         }
@@ -30,6 +30,7 @@ pub enum AppActionRoute {
     System,
     Focus,
     Undo,
+    Operation,
     DataLoading,
 }
 
@@ -39,8 +40,11 @@ impl Action {
         match self {
             Action::LoadIndexes { .. } => "LoadIndexes",
             Action::LoadJobs { .. } => "LoadJobs",
+            Action::LoadFilteredJobs { .. } => "LoadFilteredJobs",
             Action::LoadClusterInfo => "LoadClusterInfo",
             Action::LoadClusterPeers => "LoadClusterPeers",
+            Action::LoadClusterBucketHealth => "LoadClusterBucketHealth",
+            Action::LoadClusterIndexCompliance => "LoadClusterIndexCompliance",
             Action::SetMaintenanceMode { .. } => "SetMaintenanceMode",
             Action::RebalanceCluster => "RebalanceCluster",
             Action::DecommissionPeer { .. } => "DecommissionPeer",
@@ -50,10 +54,13 @@ impl Action {
             Action::CreateMacro { .. } => "CreateMacro",
             Action::UpdateMacro { .. } => "UpdateMacro",
             Action::DeleteMacro { .. } => "DeleteMacro",
+            Action::LoadMacroExpansion { .. } => "LoadMacroExpansion",
             Action::UpdateSavedSearch { .. } => "UpdateSavedSearch",
             Action::CreateSavedSearch { .. } => "CreateSavedSearch",
             Action::DeleteSavedSearch { .. } => "DeleteSavedSearch",
             Action::ToggleSavedSearch { .. } => "ToggleSavedSearch",
+            Action::BulkUpdateSavedSearches { .. } => "BulkUpdateSavedSearches",
+            Action::ReassignSavedSearches { .. } => "ReassignSavedSearches",
             Action::LoadInternalLogs { .. } => "LoadInternalLogs",
             Action::LoadApps { .. } => "LoadApps",
             Action::LoadUsers { .. } => "LoadUsers",
@@ -70,14 +77,24 @@ impl Action {
             Action::LoadMoreLookups => "LoadMoreLookups",
             Action::DownloadLookup { .. } => "DownloadLookup",
             Action::DeleteLookup { .. } => "DeleteLookup",
+            Action::LoadLookupContent { .. } => "LoadLookupContent",
+            Action::SaveLookupContent { .. } => "SaveLookupContent",
             Action::LoadInputs { .. } => "LoadInputs",
             Action::LoadMoreInputs => "LoadMoreInputs",
+            Action::LoadIngestionStats => "LoadIngestionStats",
+            Action::LoadSourcetypes => "LoadSourcetypes",
+            Action::RunFieldExtractionTest { .. } => "RunFieldExtractionTest",
+            Action::SendRestRequest(_) => "SendRestRequest",
+            Action::LoadMetadata { .. } => "LoadMetadata",
             Action::LoadConfigFiles => "LoadConfigFiles",
             Action::LoadFiredAlerts { .. } => "LoadFiredAlerts",
             Action::LoadMoreFiredAlerts => "LoadMoreFiredAlerts",
             Action::LoadConfigStanzas { .. } => "LoadConfigStanzas",
+            Action::LoadConfigLayers { .. } => "LoadConfigLayers",
             Action::EnableInput { .. } => "EnableInput",
+            Action::LoadHecTokens => "LoadHecTokens",
             Action::DisableInput { .. } => "DisableInput",
+            Action::CreateInput { .. } => "CreateInput",
             Action::SwitchToSettings => "SwitchToSettings",
             Action::RunSearch { .. } => "RunSearch",
             Action::LoadMoreSearchResults { .. } => "LoadMoreSearchResults",
@@ -86,12 +103,28 @@ impl Action {
             Action::DeleteJob(_) => "DeleteJob",
             Action::CancelJobsBatch(_) => "CancelJobsBatch",
             Action::DeleteJobsBatch(_) => "DeleteJobsBatch",
+            Action::ExtendJobTtl { .. } => "ExtendJobTtl",
+            Action::LoadJobPerformance { .. } => "LoadJobPerformance",
+            Action::FindPrunableJobs(_) => "FindPrunableJobs",
             Action::EnableApp(_) => "EnableApp",
             Action::DisableApp(_) => "DisableApp",
             Action::InstallApp { .. } => "InstallApp",
+            Action::SearchSplunkbase { .. } => "SearchSplunkbase",
             Action::RemoveApp { .. } => "RemoveApp",
+            Action::LoadAppContents { .. } => "LoadAppContents",
+            Action::AddSearchPeer { .. } => "AddSearchPeer",
+            Action::RemoveSearchPeer(_) => "RemoveSearchPeer",
+            Action::SetSearchPeerQuarantine { .. } => "SetSearchPeerQuarantine",
             Action::LoadHealth => "LoadHealth",
+            Action::LoadResourceUsage => "LoadResourceUsage",
             Action::RunConnectionDiagnostics => "RunConnectionDiagnostics",
+            Action::LoadServerMessages => "LoadServerMessages",
+            Action::DismissServerMessage { .. } => "DismissServerMessage",
+            Action::LoadCurrentContext => "LoadCurrentContext",
+            Action::RestartServer => "RestartServer",
+            Action::SetNamespace(_) => "SetNamespace",
+            Action::LoadObjectAcl { .. } => "LoadObjectAcl",
+            Action::UpdateObjectAcl { .. } => "UpdateObjectAcl",
             Action::ConnectionDiagnosticsLoaded(_) => "ConnectionDiagnosticsLoaded",
             Action::LoadLicense => "LoadLicense",
             Action::LoadKvstore => "LoadKvstore",
@@ -104,11 +137,19 @@ impl Action {
             Action::CreateIndex { .. } => "CreateIndex",
             Action::ModifyIndex { .. } => "ModifyIndex",
             Action::DeleteIndex { .. } => "DeleteIndex",
+            Action::RollHotBuckets { .. } => "RollHotBuckets",
+            Action::RebalanceIndexData { .. } => "RebalanceIndexData",
+            Action::LoadIndexSample { .. } => "LoadIndexSample",
+            Action::RunOnboardingWizard { .. } => "RunOnboardingWizard",
             Action::CreateUser { .. } => "CreateUser",
             Action::ModifyUser { .. } => "ModifyUser",
             Action::DeleteUser { .. } => "DeleteUser",
+            Action::UnlockUser { .. } => "UnlockUser",
+            Action::FindOrphanedObjects => "FindOrphanedObjects",
             Action::LoadRoles { .. } => "LoadRoles",
             Action::LoadCapabilities => "LoadCapabilities",
+            Action::LoadRoleUsage => "LoadRoleUsage",
+            Action::LoadRoleCapabilityMatrix => "LoadRoleCapabilityMatrix",
             Action::CreateRole { .. } => "CreateRole",
             Action::ModifyRole { .. } => "ModifyRole",
             Action::DeleteRole { .. } => "DeleteRole",
@@ -123,6 +164,7 @@ impl Action {
             Action::DeleteProfile { .. } => "DeleteProfile",
             Action::LoadAuditEvents { .. } => "LoadAuditEvents",
             Action::LoadRecentAuditEvents { .. } => "LoadRecentAuditEvents",
+            Action::LoadUserActivity { .. } => "LoadUserActivity",
             Action::LoadDashboards { .. } => "LoadDashboards",
             Action::LoadMoreDashboards => "LoadMoreDashboards",
             Action::LoadDataModels { .. } => "LoadDataModels",
@@ -132,9 +174,11 @@ impl Action {
             Action::RefreshApps => "RefreshApps",
             Action::RefreshUsers => "RefreshUsers",
             Action::RefreshInternalLogs => "RefreshInternalLogs",
+            Action::RefreshAuditEvents => "RefreshAuditEvents",
             Action::RefreshDashboards => "RefreshDashboards",
             Action::RefreshDataModels => "RefreshDataModels",
             Action::RefreshInputs => "RefreshInputs",
+            Action::RefreshMetadata => "RefreshMetadata",
             Action::LoadWorkloadPools { .. } => "LoadWorkloadPools",
             Action::LoadMoreWorkloadPools => "LoadMoreWorkloadPools",
             Action::LoadWorkloadRules { .. } => "LoadWorkloadRules",
@@ -181,9 +225,11 @@ impl Action {
                 | Action::RefreshUsers
                 | Action::RefreshRoles
                 | Action::RefreshInternalLogs
+                | Action::RefreshAuditEvents
                 | Action::RefreshDashboards
                 | Action::RefreshDataModels
                 | Action::RefreshInputs
+                | Action::RefreshMetadata
                 | Action::LoadMoreWorkloadPools
                 | Action::LoadMoreWorkloadRules
         )
@@ -220,8 +266,14 @@ impl Action {
                 | Action::CycleTheme
                 | Action::NextScreen
                 | Action::PreviousScreen
+                | Action::SwitchToScreen(_)
                 | Action::SwitchToSearch
                 | Action::OpenCommandPalette
+                | Action::OpenServerMessages
+                | Action::OpenBackgroundJobs
+                | Action::OpenNamespaceSelector
+                | Action::OpenSessionPicker
+                | Action::OpenTroubleshootingRecipes
                 | Action::OpenHelpPopup
                 | Action::SetFocus(_)
                 | Action::NextFocus
@@ -232,6 +284,10 @@ impl Action {
                 | Action::ShowErrorDetailsFromCurrent
                 | Action::ClearErrorDetails
                 | Action::Progress(_)
+                | Action::OperationStarted(_)
+                | Action::OperationProgressed(_)
+                | Action::OperationFinished { .. }
+                | Action::CancelOperation
                 | Action::CopyToClipboard(_)
         )
     }
@@ -241,14 +297,29 @@ impl Action {
         match self {
             Action::OpenHelpPopup
             | Action::OpenCommandPalette
+            | Action::OpenServerMessages
+            | Action::OpenBackgroundJobs
+            | Action::OpenNamespaceSelector
+            | Action::OpenSessionPicker
+            | Action::OpenTroubleshootingRecipes
             | Action::SwitchToSearch
             | Action::SwitchToSettingsScreen
             | Action::NextScreen
             | Action::PreviousScreen
+            | Action::SwitchToScreen(_)
             | Action::LoadIndexes { .. }
             | Action::LoadClusterInfo
             | Action::ToggleClusterViewMode
+            | Action::ToggleClusterBucketsView
+            | Action::ToggleClusterComplianceView
+            | Action::ToggleRoleUsageView
+            | Action::ToggleRoleMatrixView
+            | Action::ToggleHealthResourcesView
+            | Action::ToggleMetadataType
+            | Action::ToggleInputsHttpView
+            | Action::ToggleJobPerformanceView
             | Action::LoadJobs { .. }
+            | Action::LoadFilteredJobs { .. }
             | Action::LoadHealth
             | Action::LoadLicense
             | Action::LoadKvstore
@@ -259,6 +330,9 @@ impl Action {
             | Action::LoadRoles { .. }
             | Action::LoadSearchPeers { .. }
             | Action::LoadInputs { .. }
+            | Action::LoadIngestionStats
+            | Action::LoadSourcetypes
+            | Action::LoadMetadata { .. }
             | Action::LoadForwarders { .. }
             | Action::LoadFiredAlerts { .. }
             | Action::LoadLookups { .. }
@@ -288,7 +362,8 @@ impl Action {
 
             Action::SearchStarted(_)
             | Action::SearchComplete(_)
-            | Action::MoreSearchResultsLoaded(_) => AppActionRoute::Search,
+            | Action::MoreSearchResultsLoaded(_)
+            | Action::SearchJobLoaded(_) => AppActionRoute::Search,
 
             Action::StartTutorial { .. }
             | Action::TutorialCompleted
@@ -323,6 +398,7 @@ impl Action {
             | Action::CycleSortColumn
             | Action::ToggleSortDirection
             | Action::CycleTheme
+            | Action::SetNamespace(_)
             | Action::SplValidationResult { .. }
             | Action::ShowErrorDetails(_)
             | Action::ShowErrorDetailsFromCurrent
@@ -331,6 +407,12 @@ impl Action {
             | Action::OpenCreateIndexDialog
             | Action::OpenModifyIndexDialog { .. }
             | Action::OpenDeleteIndexConfirm { .. }
+            | Action::OpenOnboardingWizard
+            | Action::OnboardingWizardFinished(_)
+            | Action::OpenFieldExtractionTester
+            | Action::FieldExtractionTested(_)
+            | Action::OpenRestRequestDialog
+            | Action::RestResponseLoaded { .. }
             | Action::OpenCreateUserDialog
             | Action::OpenModifyUserDialog { .. }
             | Action::OpenDeleteUserConfirm { .. }
@@ -344,6 +426,8 @@ impl Action {
             | Action::SavedSearchCreated(_)
             | Action::SavedSearchDeleted(_)
             | Action::SavedSearchToggled(_)
+            | Action::SavedSearchesBulkUpdated(_)
+            | Action::SavedSearchesReassigned(_)
             | Action::MaintenanceModeSet { .. }
             | Action::ClusterRebalanced { .. }
             | Action::PeerDecommissioned { .. }
@@ -351,6 +435,7 @@ impl Action {
             | Action::OpenDeleteLookupConfirm { .. }
             | Action::LookupDownloaded(_)
             | Action::LookupDeleted(_)
+            | Action::LookupContentSaved(_)
             | Action::ExportSuccess(_)
             | Action::ConnectionDiagnosticsLoaded(_)
             | Action::DismissOnboardingItem
@@ -373,6 +458,11 @@ impl Action {
             | Action::OperationRedone { .. }
             | Action::ShowUndoHistory => AppActionRoute::Undo,
 
+            Action::OperationStarted(_)
+            | Action::OperationProgressed(_)
+            | Action::OperationFinished { .. }
+            | Action::CancelOperation => AppActionRoute::Operation,
+
             _ => AppActionRoute::DataLoading,
         }
     }
@@ -1,4 +1,4 @@
-//! Tests for clipboard and export action redaction.
+//! Tests for clipboard, export, and operation progress action redaction.
 
 use crate::action::format::ExportFormat;
 use crate::action::tests::redacted_debug;
@@ -45,3 +45,24 @@ fn test_unicode_in_payload() {
     );
     assert!(output.contains("chars"), "Should show character count");
 }
+
+#[test]
+fn test_redact_operation_progress() {
+    let mut progress =
+        crate::operation_progress::OperationProgress::new("Fetching profile prod-secret", Some(3));
+    progress.current = Some("prod-secret".to_string());
+    let started = redacted_debug(&Action::OperationStarted(Box::new(progress.clone())));
+    assert!(!started.contains("prod-secret"));
+    assert!(started.contains("OperationStarted"));
+
+    let update = redacted_debug(&Action::OperationProgressed(
+        crate::operation_progress::ProgressUpdate {
+            id: progress.id,
+            completed: 1,
+            failed: 0,
+            current: Some("prod-secret".to_string()),
+        },
+    ));
+    assert!(!update.contains("prod-secret"));
+    assert!(update.contains("completed=1"));
+}
@@ -3,7 +3,8 @@
 use std::collections::HashMap;
 
 use splunk_client::models::{
-    ClusterInfo, ClusterMode, ClusterPeer, HealthCheckOutput, HealthStatus, PeerState, PeerStatus,
+    ClusterIndexCompliance, ClusterInfo, ClusterMode, ClusterNodeRole, ClusterNodeVersion,
+    ClusterPeer, ClusterVersionReport, HealthCheckOutput, HealthStatus, PeerState, PeerStatus,
     SplunkHealth,
 };
 
@@ -26,6 +27,7 @@ fn test_redact_cluster_peers_loaded() {
             replication_status: None,
             bundle_replication_count: None,
             is_captain: None,
+            splunk_version: None,
         },
         ClusterPeer {
             id: "peer2-id".to_string(),
@@ -40,6 +42,7 @@ fn test_redact_cluster_peers_loaded() {
             replication_status: None,
             bundle_replication_count: None,
             is_captain: None,
+            splunk_version: None,
         },
     ];
     let action = Action::ClusterPeersLoaded(Ok(peers));
@@ -83,6 +86,60 @@ fn test_redact_cluster_info_loaded() {
     assert!(output.contains("<data>"), "Should show data indicator");
 }
 
+#[test]
+fn test_redact_cluster_versions_loaded() {
+    let report = ClusterVersionReport::new(vec![
+        ClusterNodeVersion {
+            role: ClusterNodeRole::Manager,
+            name: "internal-cm".to_string(),
+            version: Some("9.1.3".to_string()),
+        },
+        ClusterNodeVersion {
+            role: ClusterNodeRole::Peer,
+            name: "internal-idx1".to_string(),
+            version: Some("9.2.0".to_string()),
+        },
+    ]);
+    let action = Action::ClusterVersionsLoaded(Ok(report));
+    let output = redacted_debug(&action);
+
+    assert!(
+        !output.contains("internal-"),
+        "Should not contain node names"
+    );
+    assert!(
+        output.contains("ClusterVersionsLoaded(<2 nodes, 1 skewed>)"),
+        "Should show node and skew counts"
+    );
+}
+
+#[test]
+fn test_redact_cluster_index_compliance_loaded() {
+    let indexes = vec![
+        ClusterIndexCompliance {
+            index: "internal-secrets".to_string(),
+            compliant: false,
+            ..Default::default()
+        },
+        ClusterIndexCompliance {
+            index: "internal-web".to_string(),
+            compliant: true,
+            ..Default::default()
+        },
+    ];
+    let action = Action::ClusterIndexComplianceLoaded(Ok(indexes));
+    let output = redacted_debug(&action);
+
+    assert!(
+        !output.contains("internal-"),
+        "Should not contain index names"
+    );
+    assert!(
+        output.contains("ClusterIndexComplianceLoaded(<2 indexes, 1 non-compliant>)"),
+        "Should show index and non-compliant counts"
+    );
+}
+
 #[test]
 fn test_redact_health_loaded() {
     let health = HealthCheckOutput {
@@ -91,7 +148,13 @@ fn test_redact_health_loaded() {
         license_usage: None,
         kvstore_status: None,
         log_parsing_health: None,
+        scheduler_health: None,
+        queue_health: None,
+        security_health: None,
         circuit_breaker_states: None,
+        cluster_peers: None,
+        disk_partition: None,
+        search_load: None,
     };
     let action = Action::HealthLoaded(Box::new(Ok(health)));
     let output = redacted_debug(&action);
@@ -23,6 +23,8 @@ fn test_redact_indexes_loaded() {
             thawed_path: None,
             cold_to_frozen_dir: None,
             primary_index: None,
+            min_time: None,
+            max_time: None,
         },
         Index {
             name: "main".to_string(),
@@ -37,6 +39,8 @@ fn test_redact_indexes_loaded() {
             thawed_path: None,
             cold_to_frozen_dir: None,
             primary_index: None,
+            min_time: None,
+            max_time: None,
         },
     ];
     let action = Action::IndexesLoaded(Ok(indexes));
@@ -169,6 +173,7 @@ fn test_redact_users_loaded() {
         default_app: None,
         roles: vec![],
         last_successful_login: None,
+        locked_out: false,
     }];
     let action = Action::UsersLoaded(Ok(users));
     let output = redacted_debug(&action);
@@ -181,3 +186,142 @@ fn test_redact_users_loaded() {
     assert!(output.contains("UsersLoaded"), "Should contain action name");
     assert!(output.contains("1 items"), "Should show item count");
 }
+
+#[test]
+fn test_redact_lookup_content() {
+    let content =
+        splunk_client::models::LookupContent::from_csv("user,password\nalice,hunter2\n").unwrap();
+    let loaded = Action::LookupContentLoaded {
+        name: "creds.csv".to_string(),
+        app: None,
+        owner: None,
+        result: Ok(content.clone()),
+    };
+    let save = Action::SaveLookupContent {
+        name: "creds.csv".to_string(),
+        app: None,
+        owner: None,
+        content,
+    };
+
+    for output in [redacted_debug(&loaded), redacted_debug(&save)] {
+        assert!(
+            !output.contains("hunter2"),
+            "Should not contain cell values"
+        );
+        assert!(output.contains("1 rows"), "Should show row count");
+    }
+}
+
+#[test]
+fn test_redact_onboarding_wizard_finished() {
+    let outcome = crate::wizard::OnboardingOutcome {
+        index: "web".to_string(),
+        sourcetype: "web:json".to_string(),
+        format: crate::wizard::EventFormat::Json,
+        token_name: "web_hec".to_string(),
+        token: Some(secrecy::SecretString::from("6b1c8f2e-secret-token")),
+        hec_url: "https://splunk:8088".to_string(),
+    };
+    let output = redacted_debug(&Action::OnboardingWizardFinished(Ok(outcome)));
+
+    assert!(
+        !output.contains("6b1c8f2e"),
+        "Should not contain the HEC token"
+    );
+    assert!(output.contains("index=web"), "Should show the index");
+}
+
+#[test]
+fn test_redact_hec_tokens_loaded() {
+    let token = splunk_client::models::HecToken {
+        name: "http://web_events".to_string(),
+        token: Some(secrecy::SecretString::from("6b1c8f2e-secret-token")),
+        index: Some("web".to_string()),
+        indexes: vec!["web".to_string()],
+        sourcetype: None,
+        source: None,
+        host: None,
+        outputgroup: None,
+        description: None,
+        app: None,
+        disabled: false,
+        use_ack: false,
+    };
+    let output = redacted_debug(&Action::HecTokensLoaded(Ok(vec![token])));
+
+    assert!(
+        !output.contains("6b1c8f2e"),
+        "Should not contain the HEC token"
+    );
+    assert!(output.contains("<1 items>"), "Should show the item count");
+}
+
+#[test]
+fn test_redact_field_extraction_test() {
+    use splunk_client::workflows::field_extraction::{
+        ExtractedEvent, Extraction, FieldExtractionPreview,
+    };
+
+    let run = redacted_debug(&Action::RunFieldExtractionTest {
+        samples: vec!["user=alice password=hunter2".to_string()],
+        extractions: vec![Extraction::regex(r"user=(?<user>\w+)")],
+    });
+    assert!(!run.contains("hunter2"), "Should not contain sample events");
+    assert!(run.contains("<1 samples, 1 extractions>"));
+
+    let tested = redacted_debug(&Action::FieldExtractionTested(Ok(FieldExtractionPreview {
+        fields: vec!["user".to_string()],
+        events: vec![ExtractedEvent {
+            raw: "user=alice password=hunter2".to_string(),
+            values: [("user".to_string(), "alice".to_string())].into(),
+        }],
+        search: String::new(),
+    })));
+    assert!(
+        !tested.contains("hunter2"),
+        "Should not contain sample events"
+    );
+    assert!(
+        !tested.contains("alice"),
+        "Should not contain extracted values"
+    );
+    assert!(tested.contains("<1 of 1 matched>"));
+}
+
+#[test]
+fn test_redact_index_sample_loaded() {
+    use splunk_client::workflows::event_sample::SampleEvent;
+
+    let output = redacted_debug(&Action::IndexSampleLoaded {
+        index: "web".to_string(),
+        result: Ok(vec![SampleEvent {
+            raw: "user=alice password=hunter2".to_string(),
+            ..Default::default()
+        }]),
+    });
+    assert!(!output.contains("hunter2"), "Should not contain raw events");
+    assert!(output.contains("IndexSampleLoaded(web, <1 events>)"));
+}
+
+#[test]
+fn test_redact_rest_request() {
+    use crate::app::rest_explorer::RestRequest;
+
+    let request =
+        RestRequest::parse("POST /services/authentication/users/bob password=hunter2").unwrap();
+    let output = redacted_debug(&Action::SendRestRequest(request));
+    assert!(
+        !output.contains("hunter2"),
+        "Should not contain form values"
+    );
+    assert!(output.contains("POST /services/authentication/users/bob"));
+    assert!(output.contains("<0 query params, 1 form fields>"));
+
+    let output = redacted_debug(&Action::RestResponseLoaded {
+        request: "GET /services/storage/passwords".to_string(),
+        result: Ok(serde_json::json!({"clear_password": "hunter2"})),
+    });
+    assert!(!output.contains("hunter2"), "Should not contain the body");
+    assert!(output.contains("<ok>"));
+}
@@ -62,6 +62,8 @@ fn test_redact_jobs_loaded() {
             disk_usage: 1024,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         },
         SearchJobStatus {
             sid: "job2".to_string(),
@@ -76,6 +78,8 @@ fn test_redact_jobs_loaded() {
             disk_usage: 512,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         },
     ];
     let action = Action::JobsLoaded(Ok(jobs));
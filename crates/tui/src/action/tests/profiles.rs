@@ -110,9 +110,17 @@ fn test_redact_settings_loaded() {
         recent_export_paths: Vec::new(),
         export_format: "Json".to_string(),
         last_saved_at: None,
+        result_renderers: true,
+        no_color: false,
         onboarding_checklist: splunk_config::PersistedOnboardingChecklist::default(),
+        saved_sessions: Vec::new(),
+        custom_theme: None,
+        clipboard_backend: splunk_config::ClipboardBackend::Auto,
+        rest_history: Vec::new(),
+        notifications: Default::default(),
+        health_thresholds: Default::default(),
     };
-    let action = Action::SettingsLoaded(state);
+    let action = Action::SettingsLoaded(Box::new(state));
     let output = redacted_debug(&action);
 
     assert!(
@@ -122,6 +122,10 @@ fn reducer_routes_are_classified_by_family() {
     assert_eq!(Action::CycleTheme.app_route(), AppActionRoute::System);
     assert_eq!(Action::NextFocus.app_route(), AppActionRoute::Focus);
     assert_eq!(Action::Undo.app_route(), AppActionRoute::Undo);
+    assert_eq!(
+        Action::CancelOperation.app_route(),
+        AppActionRoute::Operation
+    );
     assert_eq!(
         Action::IndexesLoaded(Ok(Vec::new())).app_route(),
         AppActionRoute::DataLoading
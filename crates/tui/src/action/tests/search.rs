@@ -1,6 +1,7 @@
 //! Tests for search-related action redaction.
 
 use splunk_client::SearchMode;
+use splunk_client::workflows::macro_expansion::MacroExpansion;
 use splunk_config::SearchDefaults;
 
 use crate::action::tests::redacted_debug;
@@ -13,6 +14,7 @@ fn test_redact_run_search() {
         search_defaults: SearchDefaults::default(),
         search_mode: SearchMode::Normal,
         realtime_window: None,
+        finalize_after: None,
     };
     let output = redacted_debug(&action);
 
@@ -36,6 +38,59 @@ fn test_redact_run_search() {
     );
 }
 
+#[test]
+fn test_redact_top_values() {
+    let load = Action::LoadTopValues {
+        query: "index=auth password=hunter2 | top limit=20 user".to_string(),
+        field: "user".to_string(),
+        earliest_time: "-24h".to_string(),
+        latest_time: "now".to_string(),
+    };
+    let output = redacted_debug(&load);
+    assert!(
+        output.contains("LoadTopValues"),
+        "Should contain action name"
+    );
+    assert!(
+        !output.contains("hunter2"),
+        "Should NOT contain query content"
+    );
+
+    let loaded = Action::TopValuesLoaded {
+        field: "user".to_string(),
+        result: Ok(vec![serde_json::json!({"user": "alice", "count": "3"})]),
+    };
+    let output = redacted_debug(&loaded);
+    assert!(!output.contains("alice"), "Should NOT contain field values");
+    assert!(output.contains("1 rows"), "Should show row count");
+}
+
+#[test]
+fn test_redact_macro_expansion() {
+    let action = Action::LoadMacroExpansion {
+        query: "`auth_fail(hunter2)` | stats count".to_string(),
+    };
+    let output = redacted_debug(&action);
+    assert!(output.contains("LoadMacroExpansion"));
+    assert!(
+        !output.contains("hunter2"),
+        "Should NOT contain query content"
+    );
+
+    let loaded = Action::MacroExpansionLoaded(Ok(MacroExpansion {
+        original: "`auth_fail(hunter2)`".to_string(),
+        expanded: "index=auth user=hunter2".to_string(),
+        unknown: vec!["auth_fail(1)".to_string()],
+        ..Default::default()
+    }));
+    let output = redacted_debug(&loaded);
+    assert!(
+        !output.contains("hunter2"),
+        "Should NOT contain expanded SPL"
+    );
+    assert!(output.contains("0 macros, 1 unknown"));
+}
+
 #[test]
 fn test_redact_search_started() {
     let action = Action::SearchStarted("SELECT * FROM users WHERE password='secret'".to_string());
@@ -22,3 +22,25 @@ fn test_non_sensitive_action_shown_fully() {
 
     assert!(output.contains("Quit"), "Should show simple action fully");
 }
+
+#[test]
+fn test_add_search_peer_hides_remote_credentials() {
+    let action = Action::AddSearchPeer {
+        params: splunk_client::models::AddSearchPeerParams {
+            uri: "https://idx1:8089".to_string(),
+            remote_username: "peer-admin".to_string(),
+            remote_password: secrecy::SecretString::new("s3cret".to_string().into()),
+        },
+    };
+    let output = redacted_debug(&action);
+
+    assert!(output.contains("https://idx1:8089"), "Should show peer URI");
+    assert!(
+        !output.contains("peer-admin"),
+        "Should not show remote user"
+    );
+    assert!(
+        !output.contains("s3cret"),
+        "Should not show remote password"
+    );
+}
@@ -31,34 +31,53 @@
 use crossterm::event::KeyEvent;
 use serde_json::Value;
 use splunk_client::ClientError;
+use splunk_client::JobFilter;
 use splunk_client::SearchMode;
 use splunk_client::models::{
-    App as SplunkApp, AuditEvent, Capability, ClusterInfo, ClusterPeer, ConfigFile, ConfigStanza,
-    Dashboard, DataModel, FiredAlert, Forwarder, HealthCheckOutput, Index, Input, KvStoreStatus,
-    LicensePool, LicenseStack, LicenseUsage, LogEntry, LookupTable, Macro, Role, SavedSearch,
-    SearchJobStatus, SearchPeer, ShcCaptain, ShcConfig, ShcMember, ShcStatus, SplunkHealth, User,
-    WorkloadPool, WorkloadRule,
+    AclUpdateParams, App as SplunkApp, AuditEvent, Capability, ClusterBucketHealth,
+    ClusterIndexCompliance, ClusterInfo, ClusterPeer, ClusterVersionReport, ConfigFile,
+    ConfigLayer, ConfigStanza, CurrentContext, Dashboard, DataModel, FiredAlert, Forwarder,
+    HealthCheckOutput, HecToken, Index, IngestionStats, Input, KnowledgeObjectKind, KvStoreStatus,
+    LicenseMessage, LicensePool, LicenseSlave, LicenseStack, LicenseUsage, LicenseUsageDay,
+    LogEntry, LookupContent, LookupTable, Macro, MetadataEntry, MetadataType, Namespace, ObjectAcl,
+    ResourceUsage, Role, RoleCapabilityMatrix, RoleUsage, SavedSearch, SearchJobStatus, SearchPeer,
+    ServerInfo, ServerMessage, ShcCaptain, ShcConfig, ShcMember, ShcStatus, SplunkHealth,
+    ThroughputReport, User, WorkloadPool, WorkloadRule,
 };
+use splunk_client::workflows::app_contents::AppContents;
 pub use splunk_client::workflows::diagnostics::{
     ConnectionCheck as DiagnosticCheck, ConnectionDiagnosticsResult, DiagnosticStatus,
     ServerInfoSummary,
 };
+use splunk_client::workflows::event_sample::SampleEvent;
+use splunk_client::workflows::field_extraction::{Extraction, FieldExtractionPreview};
+use splunk_client::workflows::job_prune::JobPrunePolicy;
+use splunk_client::workflows::macro_expansion::MacroExpansion;
 pub use splunk_client::workflows::multi_profile::{
     InstanceOverview, InstanceStatus, MultiInstanceOverviewData,
     ResourceSummary as OverviewResource,
 };
+use splunk_client::workflows::orphaned_objects::OrphanReport;
+use splunk_client::workflows::saved_search_bulk::{SavedSearchBulkAction, SavedSearchBulkReport};
+use splunk_client::workflows::saved_search_ownership::SavedSearchReassignReport;
+use splunk_client::workflows::splunkbase::{AppPackageSource, SplunkbaseApp};
+use splunk_client::workflows::user_activity::UserActivityReport;
 use splunk_config::{PersistedState, SearchDefaults};
 use std::path::PathBuf;
 use std::sync::Arc;
 
 use crate::ConnectionContext;
 use crate::action::format::ExportFormat;
+use crate::app::rest_explorer::RestRequest;
+use crate::app::state::CurrentScreen;
 use crate::ui::ToastLevel;
+use crate::wizard::{OnboardingFailure, OnboardingOutcome, OnboardingPlan};
 
 /// Aggregated license data from multiple API endpoints.
 ///
-/// This struct combines license usage, pools, and stacks into a single
-/// data structure for the TUI license screen.
+/// This struct combines license usage, pools, stacks, licenser messages,
+/// slaves, and the daily usage trend into a single data structure for the TUI
+/// license screen.
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct LicenseData {
     /// License usage information (quota and used bytes)
@@ -67,6 +86,12 @@ pub struct LicenseData {
     pub pools: Vec<LicensePool>,
     /// License stacks
     pub stacks: Vec<LicenseStack>,
+    /// Licenser warning and violation messages
+    pub messages: Vec<LicenseMessage>,
+    /// License slaves with their warning counts
+    pub slaves: Vec<LicenseSlave>,
+    /// Daily indexed volume vs quota, oldest first
+    pub history: Vec<LicenseUsageDay>,
 }
 
 /// Aggregated overview data for all Splunk resources.
@@ -79,6 +104,17 @@ pub struct OverviewData {
     pub resources: Vec<OverviewResource>,
 }
 
+/// Stale screen data read from the offline snapshot cache.
+#[derive(Debug, Clone)]
+pub enum OfflineData {
+    /// Index list from the indexes screen
+    Indexes(Vec<Index>),
+    /// Search job list from the jobs screen
+    Jobs(Vec<SearchJobStatus>),
+    /// Health check output from the health screen
+    Health(Box<HealthCheckOutput>),
+}
+
 /// Unified action type for async TUI event handling.
 ///
 /// Actions flow through a channel from input handlers and async tasks
@@ -101,6 +137,8 @@ pub enum Action {
     NextScreen,
     /// Navigate to the previous screen in cyclic order.
     PreviousScreen,
+    /// Navigate directly to a screen (tab bar click).
+    SwitchToScreen(CurrentScreen),
 
     // Focus Management
     /// Move focus to the next component within the current screen.
@@ -165,12 +203,58 @@ pub enum Action {
         /// Offset for pagination
         offset: usize,
     },
+    /// Load search jobs matching a server-side owner/app/status filter
+    LoadFilteredJobs {
+        /// Number of items to load
+        count: usize,
+        /// Offset for pagination
+        offset: usize,
+        /// Filter applied by Splunk before paging
+        filter: JobFilter,
+    },
     /// Load cluster information
     LoadClusterInfo,
     /// Load health check information
     LoadHealth,
+    /// Load per-host resource usage and top processes from introspection
+    LoadResourceUsage,
     /// Run connection diagnostics (auth, TLS, reachability, server info)
     RunConnectionDiagnostics,
+    /// Open the server messages (bulletin board) popup
+    OpenServerMessages,
+    /// Open the jobs tray listing running and recently finished searches and operations
+    OpenBackgroundJobs,
+    /// Load the server's bulletin board messages
+    LoadServerMessages,
+    /// Dismiss (delete) a bulletin board message by name
+    DismissServerMessage { name: String },
+    /// Load the logged-in user's roles and capabilities
+    LoadCurrentContext,
+    /// Restart splunkd (sent after typed confirmation) and wait for it to come back
+    RestartServer,
+    /// Open the owner/app/sharing namespace selector popup
+    OpenNamespaceSelector,
+    /// Open the saved investigation sessions picker
+    OpenSessionPicker,
+    /// Open the troubleshooting recipes popup
+    OpenTroubleshootingRecipes,
+    /// Set the default namespace for knowledge-object requests (`None` = all)
+    SetNamespace(Option<Namespace>),
+    /// Load a knowledge object's permissions for the permissions popup
+    LoadObjectAcl {
+        kind: KnowledgeObjectKind,
+        name: String,
+    },
+    /// Result of loading a knowledge object's permissions (error is the user-facing message)
+    ObjectAclLoaded(Result<ObjectAcl, String>),
+    /// Change a knowledge object's permissions
+    UpdateObjectAcl {
+        kind: KnowledgeObjectKind,
+        name: String,
+        params: AclUpdateParams,
+    },
+    /// Result of changing a knowledge object's permissions (error is the user-facing message)
+    ObjectAclUpdated(Result<ObjectAcl, String>),
     /// Result of connection diagnostics
     ConnectionDiagnosticsLoaded(Result<ConnectionDiagnosticsResult, Arc<ClientError>>),
     /// Load license information (usage, pools, stacks)
@@ -212,6 +296,10 @@ pub enum Action {
     RetryInstance(String),
     /// Load cluster peers (detailed view)
     LoadClusterPeers,
+    /// Load cluster bucket fix-ups and excess copies
+    LoadClusterBucketHealth,
+    /// Load per-index replication/search factor compliance
+    LoadClusterIndexCompliance,
     /// Load more indexes (pagination)
     LoadMoreIndexes,
     /// Load more jobs (pagination)
@@ -258,6 +346,27 @@ pub enum Action {
     },
     /// Load more inputs (pagination)
     LoadMoreInputs,
+    /// Load per-source ingestion throughput for the inputs table
+    LoadInputThroughput,
+    /// Load HEC tokens for the HTTP inputs view
+    LoadHecTokens,
+    /// Load indexing latency and thruput per sourcetype
+    LoadIngestionStats,
+    /// Load the sourcetype inventory from `| metadata type=sourcetypes`
+    LoadSourcetypes,
+    /// Load hosts or sources with last event times for the metadata screen
+    LoadMetadata { metadata_type: MetadataType },
+    /// Open the field extraction tester for pasted sample events
+    OpenFieldExtractionTester,
+    /// Run regex extractions over sample events with `| makeresults | rex`
+    RunFieldExtractionTest {
+        samples: Vec<String>,
+        extractions: Vec<Extraction>,
+    },
+    /// Open the REST explorer request popup
+    OpenRestRequestDialog,
+    /// Send a raw request from the REST explorer
+    SendRestRequest(RestRequest),
     /// Load more roles (pagination)
     LoadMoreRoles,
     /// Load the list of config files
@@ -271,6 +380,13 @@ pub enum Action {
         /// Offset for pagination
         offset: usize,
     },
+    /// Load every context's copy of a config stanza (layered view and diff)
+    LoadConfigLayers {
+        /// The config file name (e.g., "props", "transforms")
+        config_file: String,
+        /// The stanza name
+        stanza: String,
+    },
     /// Load the list of fired alerts with pagination
     LoadFiredAlerts {
         /// Number of items to load
@@ -296,6 +412,8 @@ pub enum Action {
         /// Number of events to load
         count: usize,
     },
+    /// Summarize one user's audit activity per day (last 7 days)
+    LoadUserActivity { user: String },
     /// Load the list of dashboards with pagination
     LoadDashboards {
         /// Number of items to load
@@ -305,6 +423,11 @@ pub enum Action {
     },
     /// Load more dashboards (pagination)
     LoadMoreDashboards,
+    /// Load a dashboard's source definition for the detail popup
+    LoadDashboardSource {
+        /// Dashboard name
+        name: String,
+    },
     /// Load the list of data models with pagination
     LoadDataModels {
         /// Number of items to load
@@ -314,6 +437,22 @@ pub enum Action {
     },
     /// Load more data models (pagination)
     LoadMoreDataModels,
+    /// Load a data model's definition and open the tstats query builder
+    LoadTstatsBuilder {
+        /// Data model name
+        name: String,
+    },
+    /// Run a `| top` follow-up search for a field of the last search's results
+    LoadTopValues {
+        /// Follow-up query (original query plus `| top <field>`)
+        query: String,
+        /// Field being summarized
+        field: String,
+        /// Earliest time of the original search
+        earliest_time: String,
+        /// Latest time of the original search
+        latest_time: String,
+    },
 
     // Refresh actions (reset pagination, load from offset=0)
     /// Refresh indexes - reset pagination and reload from offset 0
@@ -328,12 +467,16 @@ pub enum Action {
     RefreshRoles,
     /// Refresh internal logs - reload with default parameters
     RefreshInternalLogs,
+    /// Refresh audit events - reload with the audit screen's time range
+    RefreshAuditEvents,
     /// Refresh dashboards - reset pagination and reload from offset 0
     RefreshDashboards,
     /// Refresh data models - reset pagination and reload from offset 0
     RefreshDataModels,
     /// Refresh inputs - reset pagination and reload from offset 0
     RefreshInputs,
+    /// Refresh the metadata screen - reload hosts or sources, whichever is shown
+    RefreshMetadata,
     /// Load the list of workload pools with pagination
     LoadWorkloadPools {
         /// Number of items to load
@@ -358,6 +501,22 @@ pub enum Action {
     SwitchToSettings,
     /// Toggle cluster view mode (Summary <-> Peers)
     ToggleClusterViewMode,
+    /// Toggle cluster bucket health view (Summary <-> Buckets)
+    ToggleClusterBucketsView,
+    /// Toggle cluster index compliance view (Summary <-> Compliance)
+    ToggleClusterComplianceView,
+    /// Toggle roles quota usage view (List <-> Usage)
+    ToggleRoleUsageView,
+    /// Toggle roles capability matrix view (List <-> Matrix)
+    ToggleRoleMatrixView,
+    /// Toggle health resources view (Overview <-> Resources)
+    ToggleHealthResourcesView,
+    /// Toggle the metadata screen listing (Hosts <-> Sources)
+    ToggleMetadataType,
+    /// Toggle the inputs screen listing (Inputs <-> HTTP inputs)
+    ToggleInputsHttpView,
+    /// Toggle the job inspect tab (Details <-> Performance)
+    ToggleJobPerformanceView,
     /// Run a search with the given query and search defaults.
     ///
     /// The search defaults (earliest_time, latest_time, max_results) are passed
@@ -367,6 +526,8 @@ pub enum Action {
         search_defaults: SearchDefaults,
         search_mode: SearchMode,
         realtime_window: Option<u64>,
+        /// Finalize the job once this many results are available.
+        finalize_after: Option<usize>,
     },
     /// Toggle search mode between Normal and Realtime.
     ToggleSearchMode,
@@ -404,6 +565,8 @@ pub enum Action {
     ClusterInfoLoaded(Result<ClusterInfo, Arc<ClientError>>),
     /// Result of loading health check
     HealthLoaded(Box<Result<HealthCheckOutput, Arc<ClientError>>>),
+    /// Result of loading per-host resource usage
+    ResourceUsageLoaded(Result<ResourceUsage, Arc<ClientError>>),
     /// Result of loading license information
     LicenseLoaded(Box<Result<LicenseData, Arc<ClientError>>>),
     /// Result of loading KVStore status
@@ -418,6 +581,10 @@ pub enum Action {
         search: Option<String>,
         description: Option<String>,
         disabled: Option<bool>,
+        /// Earliest time of the scheduled dispatch window
+        dispatch_earliest: Option<String>,
+        /// Latest time of the scheduled dispatch window
+        dispatch_latest: Option<String>,
     },
     /// Result of updating saved search
     SavedSearchUpdated(Result<(), Arc<ClientError>>),
@@ -442,6 +609,20 @@ pub enum Action {
     SavedSearchDeleted(Result<String, Arc<ClientError>>),
     /// Result of toggling saved search state
     SavedSearchToggled(Result<(), Arc<ClientError>>),
+    /// Apply one action to the named saved searches (matched by name in every app)
+    BulkUpdateSavedSearches {
+        names: Vec<String>,
+        action: SavedSearchBulkAction,
+    },
+    /// Result of a bulk saved search update
+    SavedSearchesBulkUpdated(Result<SavedSearchBulkReport, String>),
+    /// Reassign the named saved searches (matched by name in every app) to a new owner
+    ReassignSavedSearches {
+        names: Vec<String>,
+        to_owner: String,
+    },
+    /// Result of a saved search ownership reassignment
+    SavedSearchesReassigned(Result<SavedSearchReassignReport, String>),
 
     // Macro Operations
     /// Result of loading macros
@@ -476,6 +657,10 @@ pub enum Action {
     MacroUpdated(Result<(), Arc<ClientError>>),
     /// Result of deleting a macro
     MacroDeleted(Result<String, Arc<ClientError>>),
+    /// Expand every macro in a search query for the preview popup
+    LoadMacroExpansion { query: String },
+    /// Result of expanding a query's macros (error is the user-facing message)
+    MacroExpansionLoaded(Result<MacroExpansion, String>),
 
     /// Result of loading internal logs
     InternalLogsLoaded(Result<Vec<LogEntry>, Arc<ClientError>>),
@@ -485,6 +670,12 @@ pub enum Action {
     UsersLoaded(Result<Vec<User>, Arc<ClientError>>),
     /// Result of loading cluster peers
     ClusterPeersLoaded(Result<Vec<ClusterPeer>, Arc<ClientError>>),
+    /// Result of loading cluster bucket health
+    ClusterBucketHealthLoaded(Result<ClusterBucketHealth, Arc<ClientError>>),
+    /// Result of loading per-index replication/search factor compliance
+    ClusterIndexComplianceLoaded(Result<Vec<ClusterIndexCompliance>, Arc<ClientError>>),
+    /// Result of collecting Splunk versions across the cluster
+    ClusterVersionsLoaded(Result<ClusterVersionReport, Arc<ClientError>>),
 
     // Cluster management actions
     /// Set maintenance mode on the cluster
@@ -549,30 +740,87 @@ pub enum Action {
     LookupDownloaded(Result<String, Arc<ClientError>>),
     /// Result of deleting a lookup
     LookupDeleted(Result<String, Arc<ClientError>>),
+    /// Load a lookup's contents into the lookup editor
+    LoadLookupContent {
+        name: String,
+        app: Option<String>,
+        owner: Option<String>,
+    },
+    /// Result of loading a lookup's contents
+    LookupContentLoaded {
+        name: String,
+        app: Option<String>,
+        owner: Option<String>,
+        result: Result<LookupContent, Arc<ClientError>>,
+    },
+    /// Replace a lookup's contents by uploading the edited CSV
+    SaveLookupContent {
+        name: String,
+        app: Option<String>,
+        owner: Option<String>,
+        content: LookupContent,
+    },
+    /// Result of saving a lookup's contents
+    LookupContentSaved(Result<String, Arc<ClientError>>),
     /// Result of loading inputs
     InputsLoaded(Result<Vec<Input>, Arc<ClientError>>),
     /// Result of loading more inputs (pagination)
     MoreInputsLoaded(Result<Vec<Input>, Arc<ClientError>>),
+    /// Result of loading input throughput from metrics.log
+    InputThroughputLoaded(Result<ThroughputReport, Arc<ClientError>>),
+    /// Result of loading HEC tokens
+    HecTokensLoaded(Result<Vec<HecToken>, Arc<ClientError>>),
+    /// Result of loading indexing latency and thruput
+    IngestionStatsLoaded(Result<IngestionStats, Arc<ClientError>>),
+    /// Result of loading the sourcetype inventory
+    SourcetypesLoaded(Result<Vec<MetadataEntry>, Arc<ClientError>>),
+    /// Result of a field extraction test (error is the user-facing message)
+    FieldExtractionTested(Result<FieldExtractionPreview, String>),
+    /// Result of a REST explorer request (`request` is its request line)
+    RestResponseLoaded {
+        request: String,
+        result: Result<Value, Arc<ClientError>>,
+    },
+    /// Result of loading hosts or sources for the metadata screen
+    MetadataLoaded {
+        metadata_type: MetadataType,
+        result: Result<Vec<MetadataEntry>, Arc<ClientError>>,
+    },
     /// Result of loading more roles (pagination)
     MoreRolesLoaded(Result<Vec<Role>, Arc<ClientError>>),
     /// Result of loading config files
     ConfigFilesLoaded(Result<Vec<ConfigFile>, Arc<ClientError>>),
     /// Result of loading config stanzas
     ConfigStanzasLoaded(Result<Vec<ConfigStanza>, Arc<ClientError>>),
+    /// Result of loading the layers of a config stanza
+    ConfigLayersLoaded(Result<Vec<ConfigLayer>, Arc<ClientError>>),
     /// Result of loading fired alerts
     FiredAlertsLoaded(Result<Vec<FiredAlert>, Arc<ClientError>>),
     /// Result of loading more fired alerts (pagination)
     MoreFiredAlertsLoaded(Result<Vec<FiredAlert>, Arc<ClientError>>),
     /// Result of loading audit events
     AuditEventsLoaded(Result<Vec<AuditEvent>, Arc<ClientError>>),
+    /// Result of summarizing a user's audit activity
+    UserActivityLoaded(Result<UserActivityReport, String>),
     /// Result of loading dashboards
     DashboardsLoaded(Result<Vec<Dashboard>, Arc<ClientError>>),
     /// Result of loading more dashboards (pagination)
     MoreDashboardsLoaded(Result<Vec<Dashboard>, Arc<ClientError>>),
+    /// Result of loading a dashboard's source definition
+    DashboardSourceLoaded(Result<Dashboard, Arc<ClientError>>),
     /// Result of loading data models
     DataModelsLoaded(Result<Vec<DataModel>, Arc<ClientError>>),
     /// Result of loading more data models (pagination)
     MoreDataModelsLoaded(Result<Vec<DataModel>, Arc<ClientError>>),
+    /// Result of loading a data model definition for the tstats builder
+    TstatsBuilderLoaded(Result<DataModel, Arc<ClientError>>),
+    /// Result of a top-values follow-up search
+    TopValuesLoaded {
+        /// Field that was summarized
+        field: String,
+        /// `| top` result rows
+        result: Result<Vec<Value>, Arc<ClientError>>,
+    },
     /// Result of loading workload pools
     WorkloadPoolsLoaded(Result<Vec<WorkloadPool>, Arc<ClientError>>),
     /// Result of loading more workload pools (pagination)
@@ -621,9 +869,29 @@ pub enum Action {
     ShcCaptainSet { result: Result<(), String> },
 
     /// Result of loading persisted settings
-    SettingsLoaded(PersistedState),
+    SettingsLoaded(Box<PersistedState>),
     /// Result of background health status check
     HealthStatusLoaded(Result<SplunkHealth, Arc<ClientError>>),
+    /// Session renewed by the keep-alive task (new lifetime in seconds)
+    SessionRenewed { expires_in_secs: u64 },
+    /// Keep-alive session renewal failed
+    SessionExpired(Arc<ClientError>),
+    /// Snapshot shown in place of a screen that failed to load because the server is unreachable
+    OfflineSnapshotLoaded {
+        /// Unix timestamp (seconds) when the snapshot was saved
+        saved_at: u64,
+        data: OfflineData,
+    },
+    /// Result of loading the server's bulletin board messages
+    ServerMessagesLoaded(Result<Vec<ServerMessage>, Arc<ClientError>>),
+    /// Result of loading the logged-in user's roles and capabilities
+    CurrentContextLoaded(Result<CurrentContext, Arc<ClientError>>),
+    /// Result of dismissing a bulletin board message (holds its name)
+    ServerMessageDismissed(Result<String, Arc<ClientError>>),
+    /// Result of sending the splunkd restart request
+    ServerRestartRequested(Result<(), Arc<ClientError>>),
+    /// Result of waiting for splunkd to come back after a restart
+    ServerRestarted(Result<ServerInfo, Arc<ClientError>>),
     /// Signals that a search has started with the given query.
     /// Stores the query for accurate status messaging even if search_input is edited.
     SearchStarted(String),
@@ -640,6 +908,8 @@ pub enum Action {
     },
     /// Result of loading more results
     MoreSearchResultsLoaded(Result<(Vec<Value>, usize, Option<usize>), Arc<ClientError>>),
+    /// Status of the job behind the search results, for its artifact TTL
+    SearchJobLoaded(Result<SearchJobStatus, Arc<ClientError>>),
 
     // Job Operations
     /// Cancel a job by SID
@@ -650,6 +920,16 @@ pub enum Action {
     CancelJobsBatch(Vec<String>),
     /// Delete multiple jobs by SID
     DeleteJobsBatch(Vec<String>),
+    /// Set a job's artifact TTL to `ttl_secs` seconds from now
+    ExtendJobTtl { sid: String, ttl_secs: u64 },
+    /// Fetch a job's status with its performance counters
+    LoadJobPerformance { sid: String },
+    /// Result of fetching a job's performance counters
+    JobPerformanceLoaded(Result<SearchJobStatus, Arc<ClientError>>),
+    /// Find jobs old enough to prune under `policy`
+    FindPrunableJobs(JobPrunePolicy),
+    /// SIDs of prunable jobs, oldest first (confirmed before deletion)
+    PrunableJobsFound(Result<Vec<String>, String>),
     /// Job operation completed successfully
     JobOperationComplete(String),
 
@@ -658,16 +938,42 @@ pub enum Action {
     EnableApp(String),
     /// Disable an app by name
     DisableApp(String),
-    /// Install an app from a .spl file
-    InstallApp { file_path: PathBuf },
+    /// Install an app from a .spl file or Splunkbase
+    InstallApp { source: AppPackageSource },
+    /// Search Splunkbase apps for the install dialog
+    SearchSplunkbase { query: String },
+    /// Result of a Splunkbase search (error is the user-facing message)
+    SplunkbaseSearchLoaded(Result<Vec<SplunkbaseApp>, String>),
     /// Remove (uninstall) an app by name
     RemoveApp { app_name: String },
+    /// Load the knowledge objects an app ships for the App Details popup
+    LoadAppContents { app: String },
+    /// Result of loading an app inventory (error is the user-facing message)
+    AppContentsLoaded(Result<AppContents, String>),
 
     // Input Operations
     /// Enable an input by type and name
     EnableInput { input_type: String, name: String },
     /// Disable an input by type and name
     DisableInput { input_type: String, name: String },
+    /// Create a monitor, TCP, or UDP input
+    CreateInput {
+        params: splunk_client::CreateInputParams,
+    },
+    /// Enable a HEC token by name
+    EnableHecToken { name: String },
+    /// Disable a HEC token by name
+    DisableHecToken { name: String },
+
+    // Search Peer Operations
+    /// Add a distributed search peer
+    AddSearchPeer {
+        params: splunk_client::models::AddSearchPeerParams,
+    },
+    /// Remove a distributed search peer by name
+    RemoveSearchPeer(String),
+    /// Quarantine (`true`) or unquarantine (`false`) a search peer
+    SetSearchPeerQuarantine { name: String, quarantine: bool },
 
     // Index Operations
     /// Create a new index
@@ -681,6 +987,17 @@ pub enum Action {
     },
     /// Delete an index
     DeleteIndex { name: String },
+    /// Roll an index's hot buckets to warm
+    RollHotBuckets { name: String },
+    /// Start a data rebalance for an index on the cluster manager
+    RebalanceIndexData { index: String },
+    /// Fetch the newest raw events of an index for the sample viewer
+    LoadIndexSample { index: String },
+    /// Result of sampling an index (error is the user-facing message)
+    IndexSampleLoaded {
+        index: String,
+        result: Result<Vec<SampleEvent>, String>,
+    },
     /// Open index creation dialog
     OpenCreateIndexDialog,
     /// Open index modification dialog
@@ -694,6 +1011,14 @@ pub enum Action {
     /// Result of deleting an index
     IndexDeleted(Result<String, Arc<ClientError>>),
 
+    // Data Onboarding Wizard
+    /// Open the data onboarding wizard (index, sourcetype, and HEC token)
+    OpenOnboardingWizard,
+    /// Create the wizard's index, sourcetype stanza, and HEC token, in order
+    RunOnboardingWizard { plan: OnboardingPlan },
+    /// Result of an onboarding wizard run
+    OnboardingWizardFinished(Result<OnboardingOutcome, OnboardingFailure>),
+
     // User Operations
     /// Create a new user
     CreateUser {
@@ -706,6 +1031,12 @@ pub enum Action {
     },
     /// Delete a user
     DeleteUser { name: String },
+    /// Unlock a user locked out after too many failed logins
+    UnlockUser { name: String },
+    /// Find knowledge objects owned by users that no longer exist
+    FindOrphanedObjects,
+    /// Result of an orphaned object scan (error is the user-facing message)
+    OrphanedObjectsFound(Result<OrphanReport, String>),
     /// Open user creation dialog
     OpenCreateUserDialog,
     /// Open user modification dialog
@@ -735,6 +1066,10 @@ pub enum Action {
     DeleteRole { name: String },
     /// Load capabilities list
     LoadCapabilities,
+    /// Load per-role search quotas and current usage
+    LoadRoleUsage,
+    /// Load every role's effective capabilities
+    LoadRoleCapabilityMatrix,
     /// Open role creation dialog
     OpenCreateRoleDialog,
     /// Open role modification dialog
@@ -751,6 +1086,10 @@ pub enum Action {
     RoleDeleted(Result<String, Arc<ClientError>>),
     /// Result of loading capabilities
     CapabilitiesLoaded(Result<Vec<Capability>, Arc<ClientError>>),
+    /// Result of loading per-role search quota usage
+    RoleUsageLoaded(Result<Vec<RoleUsage>, Arc<ClientError>>),
+    /// Result of loading the role capability matrix
+    RoleCapabilityMatrixLoaded(Result<RoleCapabilityMatrix, Arc<ClientError>>),
 
     // License Operations
     /// Install a license file
@@ -948,4 +1287,21 @@ pub enum Action {
     },
     /// Show undo history
     ShowUndoHistory,
+
+    // =========================================================================
+    // Long-Running Operation Progress
+    // =========================================================================
+    /// A long-running operation started (opens the progress popup)
+    OperationStarted(Box<crate::operation_progress::OperationProgress>),
+    /// Per-item progress for the running operation
+    OperationProgressed(crate::operation_progress::ProgressUpdate),
+    /// A long-running operation finished or was cancelled
+    OperationFinished {
+        /// ID of the finished operation
+        id: u64,
+        /// Whether the operation stopped because the user cancelled it
+        cancelled: bool,
+    },
+    /// Cancel the running operation
+    CancelOperation,
 }
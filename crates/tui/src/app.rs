@@ -11,13 +11,38 @@
 //! - `export`: Export functionality
 //! - `navigation`: Navigation helpers (next/previous item, page up/down, etc.)
 //! - `jobs`: Jobs-specific logic (filtering, sorting)
+//! - `ingestion`: Ingestion table sorting
+//! - `event_sample`: Raw event sample viewer for an index
+//! - `extraction_tester`: Regex field extraction tester for pasted sample events
+//! - `result_chart`: Time-series chart view of loaded search results
+//! - `result_compare`: Compare view of search results against the previous run
+//! - `result_detail`: Field table helpers for the search result detail popup
+//! - `result_filter`: JSON path filtering of loaded search results
+//! - `result_stats`: Summary statistics bar for loaded search results
+//! - `template_form`: Parameter form for search templates with a parameter schema
+//! - `top_values`: Top-values quick stats for fields of search results
+//! - `tstats_builder`: Guided tstats query builder for data models
+//! - `rest_explorer`: Raw REST requests, their history, and the last response
+//! - `time_range`: Time range picker shared by the Search, Internal Logs, and Audit screens
+//! - `troubleshooting`: Curated troubleshooting searches over internal logs
+//! - `lookup_editor`: Lookup table contents editor
+//! - `server_messages`: Bulletin board messages and their unread state
+//! - `background_jobs`: Searches and batch operations shown in the jobs tray
+//! - `log_follow`: Live tail and level/component filters for the internal logs view
+//! - `notifier`: Desktop and command notifications for finished searches and health changes
+//! - `offline`: Snapshot data shown while the server is unreachable
+//! - `sessions`: Saved investigation sessions (named workspaces)
+//! - `themes`: Built-in and user-defined color theme selection
 //! - `mouse`: Mouse event handling
+//! - `hit_areas`: Layout-rect registry for mouse hit-testing
 //! - `popups`: Popup input handling
 //! - `input`: Per-screen input handlers
 //! - `actions`: Action handling
 //! - `parsing`: Parsing helpers for API string values
 //! - `render`: Rendering logic
 
+pub mod app_details;
+pub mod background_jobs;
 pub mod clipboard;
 pub mod command_palette;
 pub mod core;
@@ -25,21 +50,47 @@ pub mod state;
 pub mod structs;
 
 mod actions;
+pub mod event_sample;
 mod export;
+pub mod extraction_tester;
 pub mod footer_layout;
+mod footer_text;
+pub mod hit_areas;
+mod ingestion;
 pub mod input;
 mod jobs;
 mod load_actions;
+pub mod log_anomalies;
+pub mod log_follow;
+pub mod lookup_editor;
 mod mouse;
 mod navigation;
+pub mod notifier;
+mod offline;
 mod parsing;
+mod permissions;
 mod popups;
 mod render;
+pub mod rest_explorer;
+pub mod result_chart;
+mod result_compare;
+pub mod result_detail;
+pub mod result_filter;
+pub mod result_stats;
+pub mod server_messages;
+mod sessions;
+pub mod template_form;
+mod themes;
+pub mod time_range;
+pub mod top_values;
+pub mod troubleshooting;
+pub mod tstats_builder;
 
 pub use state::{
     ClusterViewMode, CurrentScreen, EscAction, FOOTER_HEIGHT, HEADER_HEIGHT, HealthState,
-    ListPaginationState, NavigationContext, NavigationMode, SearchInputMode, SortColumn,
-    SortDirection, SortState, TabAction,
+    HealthViewMode, IngestionSortColumn, IngestionSortState, InputsViewMode, JobInspectTab,
+    ListPaginationState, NavigationContext, NavigationMode, RolesViewMode, SearchInputMode,
+    SortColumn, SortDirection, SortState, TabAction,
 };
 pub use structs::{App, ConnectionContext, SplValidationState};
 
@@ -76,6 +127,13 @@ impl App {
         if self.health_state == HealthState::Healthy && new_state == HealthState::Unhealthy {
             self.toasts
                 .push(Toast::warning("Splunk health status changed to unhealthy"));
+            notifier::send(
+                &self.notifications,
+                notifier::Notification::new(
+                    "Splunk health is red",
+                    "Splunk health status changed to unhealthy",
+                ),
+            );
         }
         self.health_state = new_state;
     }
@@ -86,15 +144,46 @@ impl App {
     pub fn set_server_info(&mut self, server_info: &splunk_client::models::ServerInfo) {
         self.server_version = Some(server_info.version.clone());
         self.server_build = Some(server_info.build.clone());
+        self.server_restart_required = server_info.restart_required;
+    }
+
+    /// Whether splunkd needs a restart, per server info or the bulletin board.
+    pub fn restart_required(&self) -> bool {
+        self.server_restart_required
+            || self
+                .server_messages
+                .messages
+                .iter()
+                .any(|message| message.is_restart_required())
+    }
+
+    /// Record a session renewal lasting `expires_in_secs`.
+    pub fn set_session_renewed(&mut self, expires_in_secs: u64) {
+        self.session_expires_at =
+            Some(std::time::Instant::now() + std::time::Duration::from_secs(expires_in_secs));
+        self.session_expired = false;
+    }
+
+    /// Time left in the current session, if a session renewal has been observed.
+    pub fn session_remaining(&self) -> Option<std::time::Duration> {
+        self.session_expires_at
+            .map(|expires_at| expires_at.saturating_duration_since(std::time::Instant::now()))
     }
 
     /// Set search results (virtualization: formatting is deferred to render time).
     pub fn set_search_results(&mut self, results: Vec<serde_json::Value>) {
+        self.stash_search_baseline();
         self.search_results = results;
         self.search_results_total_count = Some(self.search_results.len());
         self.search_has_more_results = false;
         // Reset scroll offset when new results arrive
         self.search_scroll_offset = 0;
+        self.refresh_result_filter(true);
+        self.apply_pending_search_scroll();
+        self.search_result_stats.reset();
+        self.search_result_stats.update(&self.search_results);
+        self.refresh_search_chart();
+        self.refresh_search_compare();
     }
 
     /// Append more search results (for pagination, virtualization: no eager formatting).
@@ -117,6 +206,11 @@ impl App {
             // If we got fewer, we're likely at the end.
             results_count >= self.search_results_page_size
         };
+        // Evaluate the result filter and stats over the new page only
+        self.refresh_result_filter(false);
+        self.search_result_stats.update(&self.search_results);
+        self.refresh_search_chart();
+        self.refresh_search_compare();
         // Note: No pre-formatting - results are formatted on-demand during rendering
     }
 
@@ -128,10 +222,11 @@ impl App {
 
         // Trigger fetch when user is within threshold items of the end
         let threshold = DEFAULT_SCROLL_THRESHOLD;
+        // Offsets are positions in the (possibly filtered) view, so compare against it
         let loaded_count = self.search_results.len();
         let visible_end = self.search_scroll_offset.saturating_add(threshold);
 
-        if visible_end >= loaded_count {
+        if visible_end >= self.visible_search_result_count() {
             Some(Action::LoadMoreSearchResults {
                 sid: self.search_sid.clone()?,
                 offset: loaded_count,
@@ -204,6 +299,18 @@ impl App {
             return self.handle_config_search_input(key);
         }
 
+        // Internal logs component filter box takes precedence while editing
+        if self.current_screen == CurrentScreen::InternalLogs
+            && self.internal_logs_component_input.is_some()
+        {
+            return self.handle_log_component_filter_input(key);
+        }
+
+        // Result filter box takes precedence while editing
+        if self.current_screen == CurrentScreen::Search && self.is_result_filtering {
+            return self.handle_result_filter_input(key);
+        }
+
         // Onboarding checklist dismiss shortcuts are global while the widget is visible.
         if self.onboarding_checklist.should_show_checklist() {
             let is_shift_d = key.code == KeyCode::Char('D')
@@ -229,6 +336,26 @@ impl App {
             return Some(Action::ShowErrorDetailsFromCurrent);
         }
 
+        // Global 'g' views a search that finished off-screen, while the toast
+        // announcing it is still shown. Screens that bind 'g' themselves
+        // (Search, and Settings for the log repeat threshold) keep it.
+        if key.code == KeyCode::Char('g')
+            && key.modifiers.is_empty()
+            && !matches!(
+                self.current_screen,
+                CurrentScreen::Search | CurrentScreen::Settings
+            )
+            && let Some(toast_id) = self.background_jobs.view_toast
+            && self
+                .toasts
+                .iter()
+                .any(|t| t.id == toast_id && !t.is_expired())
+        {
+            self.background_jobs.view_toast = None;
+            self.toasts.retain(|t| t.id != toast_id);
+            return Some(Action::SwitchToSearch);
+        }
+
         // When in Search screen with QueryFocused mode, skip global binding resolution
         // for printable characters to allow typing (RQ-0101 fix).
         // Tab/BackTab are handled by global keymap for screen navigation (deterministic behavior).
@@ -238,6 +365,14 @@ impl App {
             && (input::helpers::is_printable_char(key)
                 || input::helpers::is_cursor_editing_key(key));
 
+        if let Some(capability) = self.missing_capability_for_key(key) {
+            self.toasts.push(Toast::warning(format!(
+                "Requires the '{}' capability",
+                capability
+            )));
+            return None;
+        }
+
         if !skip_global_bindings
             && let Some(action) = crate::input::keymap::resolve_action(self.current_screen, key)
         {
@@ -288,10 +423,14 @@ impl App {
             && !self.is_filtering
         {
             // Auto-refresh resets pagination to get fresh data
-            Some(Action::LoadJobs {
-                count: self.jobs_pagination.page_size,
-                offset: 0,
-            })
+            Some(self.jobs_load_action(self.jobs_pagination.page_size, 0))
+        } else if self.current_screen == CurrentScreen::InternalLogs
+            && self.internal_logs_follow.active
+            && !self.internal_logs_follow.paused
+            && self.popup.is_none()
+        {
+            // Follow mode polls incrementally from the cursor
+            Some(self.log_follow_action())
         } else if self.current_screen == CurrentScreen::InternalLogs
             && self.auto_refresh
             && self.popup.is_none()
@@ -18,6 +18,7 @@
 //! - `search`: Search lifecycle and results
 //! - `profiles`: Profile switching and management
 //! - `system`: Loading, notifications, clipboard, etc.
+//! - `operations`: Long-running operation progress and cancellation
 
 use crate::action::{Action, AppActionRoute};
 use crate::app::App;
@@ -27,6 +28,7 @@ use crate::app::state::SearchInputMode;
 mod data_loading;
 mod data_loading_handlers;
 mod navigation;
+mod operations;
 mod profiles;
 mod search;
 mod system;
@@ -51,6 +53,7 @@ impl App {
             AppActionRoute::System => self.handle_system_action(action),
             AppActionRoute::Focus => self.handle_focus_action(action),
             AppActionRoute::Undo => self.handle_undo_action(action),
+            AppActionRoute::Operation => self.handle_operation_action(action),
             AppActionRoute::DataLoading => self.handle_data_loading_action(action),
         }
     }
@@ -165,7 +168,13 @@ mod tests {
             license_usage: None,
             kvstore_status: None,
             log_parsing_health: None,
+            scheduler_health: None,
+            queue_health: None,
+            security_health: None,
             circuit_breaker_states: None,
+            cluster_peers: None,
+            disk_partition: None,
+            search_load: None,
         };
 
         app.update(Action::HealthLoaded(Box::new(Ok(health_output))));
@@ -18,6 +18,7 @@
 use crate::action::Action;
 use crate::app::App;
 use crate::ui::Toast;
+use crate::ui::popup::{Popup, PopupType};
 
 impl App {
     /// Handle data loading result actions.
@@ -74,6 +75,9 @@ impl App {
             Action::IndexDeleted(Err(e)) => {
                 self.handle_data_load_error("delete index", e);
             }
+            Action::IndexSampleLoaded { index, result } => {
+                self.handle_index_sample_loaded(index, result);
+            }
 
             // Jobs
             Action::JobsLoaded(Ok(jobs)) => {
@@ -134,6 +138,71 @@ impl App {
             Action::MacroDeleted(Err(e)) => {
                 self.handle_data_load_error("delete macro", e);
             }
+            Action::MacroExpansionLoaded(Ok(expansion)) => {
+                self.handle_macro_expansion_loaded(expansion);
+            }
+            Action::MacroExpansionLoaded(Err(message)) => {
+                self.loading = false;
+                self.toasts.push(Toast::error(format!(
+                    "Failed to expand macros: {}",
+                    message
+                )));
+            }
+            Action::SplunkbaseSearchLoaded(Ok(apps)) => {
+                self.handle_splunkbase_search_loaded(apps);
+            }
+            Action::SplunkbaseSearchLoaded(Err(message)) => {
+                self.loading = false;
+                self.toasts.push(Toast::error(format!(
+                    "Splunkbase search failed: {}",
+                    message
+                )));
+            }
+            Action::AppContentsLoaded(Ok(contents)) => {
+                self.handle_app_contents_loaded(contents);
+            }
+            Action::AppContentsLoaded(Err(message)) => {
+                self.loading = false;
+                self.toasts.push(Toast::error(format!(
+                    "Failed to load app contents: {}",
+                    message
+                )));
+            }
+            Action::OrphanedObjectsFound(Ok(report)) => {
+                self.handle_orphaned_objects_found(report);
+            }
+            Action::OrphanedObjectsFound(Err(message)) => {
+                self.loading = false;
+                self.toasts.push(Toast::error(format!(
+                    "Failed to scan for orphaned objects: {}",
+                    message
+                )));
+            }
+
+            Action::ObjectAclLoaded(Ok(acl)) => {
+                self.handle_object_acl_loaded(acl);
+            }
+            Action::ObjectAclLoaded(Err(message)) => {
+                self.loading = false;
+                self.toasts.push(Toast::error(format!(
+                    "Failed to load permissions: {}",
+                    message
+                )));
+            }
+            Action::ObjectAclUpdated(Ok(acl)) => {
+                self.loading = false;
+                self.toasts.push(Toast::success(format!(
+                    "Permissions of {} '{}' updated ({})",
+                    acl.kind, acl.name, acl.sharing
+                )));
+            }
+            Action::ObjectAclUpdated(Err(message)) => {
+                self.loading = false;
+                self.toasts.push(Toast::error(format!(
+                    "Failed to update permissions: {}",
+                    message
+                )));
+            }
 
             // Internal Logs
             Action::InternalLogsLoaded(Ok(logs)) => {
@@ -158,6 +227,26 @@ impl App {
             Action::ClusterPeersLoaded(Err(e)) => {
                 self.handle_data_load_error("cluster peers", e);
             }
+            Action::ClusterBucketHealthLoaded(Ok(health)) => {
+                self.cluster_bucket_health = Some(health);
+                self.loading = false;
+            }
+            Action::ClusterBucketHealthLoaded(Err(e)) => {
+                self.handle_data_load_error("cluster bucket health", e);
+            }
+            Action::ClusterIndexComplianceLoaded(Ok(indexes)) => {
+                self.cluster_index_compliance = Some(indexes);
+                self.loading = false;
+            }
+            Action::ClusterIndexComplianceLoaded(Err(e)) => {
+                self.handle_data_load_error("cluster index compliance", e);
+            }
+            Action::ClusterVersionsLoaded(Ok(report)) => {
+                self.cluster_versions = Some(report);
+            }
+            Action::ClusterVersionsLoaded(Err(e)) => {
+                self.handle_data_load_error("cluster versions", e);
+            }
 
             // Health
             Action::HealthLoaded(boxed_result) => {
@@ -166,6 +255,86 @@ impl App {
             Action::HealthStatusLoaded(result) => {
                 self.handle_health_status_loaded(result);
             }
+            Action::ResourceUsageLoaded(Ok(usage)) => {
+                self.resource_usage = Some(usage);
+                self.loading = false;
+            }
+            Action::ResourceUsageLoaded(Err(e)) => {
+                self.handle_data_load_error("resource usage", e);
+            }
+
+            // Session keep-alive
+            Action::SessionRenewed { expires_in_secs } => {
+                self.set_session_renewed(expires_in_secs);
+            }
+            Action::SessionExpired(e) => {
+                // Only toast on the transition; the keep-alive keeps retrying.
+                if !self.session_expired {
+                    self.toasts
+                        .push(Toast::error(format!("Session renewal failed: {}", e)));
+                }
+                self.session_expired = true;
+                self.session_expires_at = None;
+            }
+
+            // Offline snapshots
+            Action::OfflineSnapshotLoaded { saved_at, data } => {
+                self.apply_offline_snapshot(saved_at, data);
+            }
+
+            // Server messages
+            Action::ServerMessagesLoaded(Ok(messages)) => {
+                self.server_messages.replace(messages);
+            }
+            Action::ServerMessagesLoaded(Err(e)) => {
+                self.toasts.push(Toast::error(format!(
+                    "Failed to load server messages: {}",
+                    e
+                )));
+            }
+            // Current user context
+            Action::CurrentContextLoaded(Ok(context)) => {
+                self.current_context = Some(context);
+            }
+            Action::CurrentContextLoaded(Err(e)) => {
+                // Leave actions ungated rather than blocking on an unknown context.
+                tracing::warn!("Failed to load current user context: {}", e);
+                self.current_context = None;
+            }
+            Action::ServerMessageDismissed(Ok(name)) => {
+                self.server_messages.remove(&name);
+                self.toasts
+                    .push(Toast::success(format!("Message '{}' dismissed", name)));
+            }
+            Action::ServerMessageDismissed(Err(e)) => {
+                self.toasts
+                    .push(Toast::error(format!("Failed to dismiss message: {}", e)));
+            }
+            Action::ServerRestartRequested(Ok(())) => {
+                self.server_restarting = true;
+                self.toasts.push(Toast::info(
+                    "Restart requested, waiting for Splunk to come back",
+                ));
+            }
+            Action::ServerRestartRequested(Err(e)) => {
+                self.toasts
+                    .push(Toast::error(format!("Failed to restart Splunk: {}", e)));
+            }
+            Action::ServerRestarted(Ok(info)) => {
+                self.server_restarting = false;
+                self.set_server_info(&info);
+                self.toasts.push(Toast::success(format!(
+                    "Splunk restarted (version {})",
+                    info.version
+                )));
+            }
+            Action::ServerRestarted(Err(e)) => {
+                self.server_restarting = false;
+                self.toasts.push(Toast::error(format!(
+                    "Splunk did not come back after restart: {}",
+                    e
+                )));
+            }
 
             // License
             Action::LicenseLoaded(boxed_result) => {
@@ -302,6 +471,41 @@ impl App {
             Action::CapabilitiesLoaded(Err(e)) => {
                 self.handle_data_load_error("capabilities", e);
             }
+            Action::RoleUsageLoaded(Ok(usage)) => {
+                self.role_usage = Some(usage);
+                self.loading = false;
+            }
+            Action::RoleUsageLoaded(Err(e)) => {
+                self.handle_data_load_error("role usage", e);
+            }
+            Action::RoleCapabilityMatrixLoaded(Ok(matrix)) => {
+                let last = matrix.capabilities.len().saturating_sub(1);
+                if self.role_matrix_state.selected().is_some_and(|i| i > last) {
+                    self.role_matrix_state.select(Some(last));
+                }
+                self.role_matrix = Some(matrix);
+                self.loading = false;
+            }
+            Action::RoleCapabilityMatrixLoaded(Err(e)) => {
+                self.handle_data_load_error("role capability matrix", e);
+            }
+            Action::JobPerformanceLoaded(Ok(status)) => {
+                self.job_performance = Some(status);
+                self.loading = false;
+            }
+            Action::JobPerformanceLoaded(Err(e)) => {
+                self.handle_data_load_error("job performance", e);
+            }
+            Action::PrunableJobsFound(Ok(sids)) => {
+                self.handle_prunable_jobs_found(sids);
+            }
+            Action::PrunableJobsFound(Err(message)) => {
+                self.loading = false;
+                self.toasts.push(Toast::error(format!(
+                    "Failed to find jobs to prune: {}",
+                    message
+                )));
+            }
 
             // Search Peers
             Action::SearchPeersLoaded(Ok(peers)) => {
@@ -341,6 +545,17 @@ impl App {
             Action::MoreLookupsLoaded(Ok(lookups)) => {
                 self.handle_more_lookups_loaded(lookups);
             }
+            Action::LookupContentLoaded {
+                name,
+                app,
+                owner,
+                result: Ok(content),
+            } => {
+                self.handle_lookup_content_loaded(name, app, owner, content);
+            }
+            Action::LookupContentLoaded { result: Err(e), .. } => {
+                self.handle_data_load_error("lookup contents", e);
+            }
             Action::MoreLookupsLoaded(Err(e)) => {
                 self.handle_data_load_error("more lookups", e);
             }
@@ -358,6 +573,49 @@ impl App {
             Action::MoreInputsLoaded(Err(e)) => {
                 self.handle_data_load_error("more inputs", e);
             }
+            Action::InputThroughputLoaded(Ok(report)) => {
+                self.handle_input_throughput_loaded(report);
+            }
+            Action::InputThroughputLoaded(Err(e)) => {
+                self.handle_data_load_error("input throughput", e);
+            }
+            Action::HecTokensLoaded(Ok(tokens)) => {
+                self.handle_hec_tokens_loaded(tokens);
+            }
+            Action::HecTokensLoaded(Err(e)) => {
+                self.handle_data_load_error("HEC tokens", e);
+            }
+            Action::IngestionStatsLoaded(Ok(stats)) => {
+                self.ingestion_stats = Some(stats);
+                self.sort_ingestion_stats();
+                self.loading = false;
+            }
+            Action::IngestionStatsLoaded(Err(e)) => {
+                self.handle_data_load_error("ingestion stats", e);
+            }
+            Action::SourcetypesLoaded(Ok(sourcetypes)) => {
+                self.sourcetypes = Some(sourcetypes);
+                self.loading = false;
+            }
+            Action::SourcetypesLoaded(Err(e)) => {
+                self.handle_data_load_error("sourcetypes", e);
+            }
+            Action::MetadataLoaded {
+                metadata_type,
+                result: Ok(entries),
+            } => {
+                // A reply for the type toggled away from is stale.
+                if metadata_type == self.metadata_type {
+                    self.metadata_entries = Some(entries);
+                    self.loading = false;
+                }
+            }
+            Action::MetadataLoaded {
+                metadata_type,
+                result: Err(e),
+            } => {
+                self.handle_data_load_error(metadata_type.as_str(), e);
+            }
 
             // Fired Alerts
             Action::FiredAlertsLoaded(Ok(alerts)) => {
@@ -388,6 +646,17 @@ impl App {
             Action::AuditEventsLoaded(Err(e)) => {
                 self.handle_data_load_error("audit events", e);
             }
+            Action::UserActivityLoaded(Ok(report)) => {
+                self.loading = false;
+                self.popup = Some(Popup::builder(PopupType::UserActivity { report }).build());
+            }
+            Action::UserActivityLoaded(Err(message)) => {
+                self.loading = false;
+                self.toasts.push(Toast::error(format!(
+                    "Failed to load user activity: {}",
+                    message
+                )));
+            }
 
             // Dashboards
             Action::DashboardsLoaded(Ok(dashboards)) => {
@@ -402,6 +671,12 @@ impl App {
             Action::MoreDashboardsLoaded(Err(e)) => {
                 self.handle_data_load_error("more dashboards", e);
             }
+            Action::DashboardSourceLoaded(Ok(dashboard)) => {
+                self.handle_dashboard_source_loaded(dashboard);
+            }
+            Action::DashboardSourceLoaded(Err(e)) => {
+                self.handle_data_load_error("dashboard source", e);
+            }
 
             // Data Models
             Action::DataModelsLoaded(Ok(datamodels)) => {
@@ -416,6 +691,15 @@ impl App {
             Action::MoreDataModelsLoaded(Err(e)) => {
                 self.handle_data_load_error("more data models", e);
             }
+            Action::TstatsBuilderLoaded(Ok(datamodel)) => {
+                self.handle_tstats_builder_loaded(datamodel);
+            }
+            Action::TstatsBuilderLoaded(Err(e)) => {
+                self.handle_data_load_error("data model definition", e);
+            }
+            Action::TopValuesLoaded { field, result } => {
+                self.handle_top_values_loaded(&field, result);
+            }
 
             // Workload Management
             Action::WorkloadPoolsLoaded(Ok(pools)) => {
@@ -504,10 +788,18 @@ impl App {
             Action::ConfigStanzasLoaded(Err(e)) => {
                 self.handle_data_load_error("config stanzas", e);
             }
+            Action::ConfigLayersLoaded(Ok(layers)) => {
+                self.config_layers = Some(layers);
+                self.loading = false;
+                self.ensure_config_diff_app();
+            }
+            Action::ConfigLayersLoaded(Err(e)) => {
+                self.handle_data_load_error("config layers", e);
+            }
 
             // Settings and Overview
             Action::SettingsLoaded(state) => {
-                self.apply_loaded_settings(state);
+                self.apply_loaded_settings(*state);
             }
             Action::OverviewLoaded(data) => {
                 self.overview_data = Some(data);
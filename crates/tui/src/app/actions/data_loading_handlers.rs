@@ -5,10 +5,14 @@
 
 use crate::action::LicenseData;
 use crate::app::App;
+use crate::app::lookup_editor::LookupEditor;
 use crate::app::state::HealthState;
+use crate::app::tstats_builder::TstatsBuilder;
 use crate::onboarding::OnboardingMilestone;
 use crate::ui::Toast;
-use splunk_client::models::DataModel;
+use crate::ui::popup::{Popup, PopupType};
+use splunk_client::models::{DataModel, LookupContent};
+use splunk_config::SnapshotKind;
 
 impl App {
     fn apply_paginated_items<T>(target: &mut Option<Vec<T>>, items: Vec<T>, append: bool) -> usize {
@@ -81,6 +85,7 @@ impl App {
     pub(crate) fn handle_indexes_loaded(&mut self, indexes: Vec<splunk_client::models::Index>) {
         let count = Self::apply_paginated_items(&mut self.indexes, indexes, false);
         self.indexes_pagination.update_loaded(count);
+        self.offline_snapshots.remove(&SnapshotKind::Indexes);
         self.loading = false;
     }
 
@@ -98,6 +103,7 @@ impl App {
         let sel = self.jobs_state.selected();
         let count = Self::apply_paginated_items(&mut self.jobs, jobs, false);
         self.jobs_pagination.update_loaded(count);
+        self.offline_snapshots.remove(&SnapshotKind::Jobs);
         self.loading = false;
         // Rebuild filtered indices and restore selection clamped to new bounds
         self.rebuild_filtered_indices();
@@ -127,13 +133,18 @@ impl App {
         &mut self,
         logs: Vec<splunk_client::models::LogEntry>,
     ) {
+        if self.internal_logs_follow.active {
+            self.merge_followed_logs(logs);
+            return;
+        }
         let sel = self.internal_logs_state.selected();
+        self.internal_log_anomalies
+            .observe(&logs, &self.internal_logs_defaults.anomalies);
         self.internal_logs = Some(logs);
         self.loading = false;
-        if let Some(logs) = &self.internal_logs {
-            self.internal_logs_state
-                .select(sel.map(|i| i.min(logs.len().saturating_sub(1))).or(Some(0)));
-        }
+        let rows = self.internal_log_row_count();
+        self.internal_logs_state
+            .select(sel.map(|i| i.min(rows.saturating_sub(1))).or(Some(0)));
     }
 
     // Health handlers
@@ -149,6 +160,7 @@ impl App {
         match *boxed_result {
             Ok(ref info) => {
                 self.health_info = Some(info.clone());
+                self.offline_snapshots.remove(&SnapshotKind::Health);
                 // Update health state from splunkd_health if available
                 if let Some(ref health) = info.splunkd_health {
                     let new_state = HealthState::from_health_str(&health.health.to_string());
@@ -298,6 +310,25 @@ impl App {
         self.loading = false;
     }
 
+    pub(crate) fn handle_lookup_content_loaded(
+        &mut self,
+        name: String,
+        app: Option<String>,
+        owner: Option<String>,
+        content: LookupContent,
+    ) {
+        self.loading = false;
+        if content.header.is_empty() {
+            self.toasts.push(Toast::warning(format!(
+                "Lookup '{}' is empty; upload a CSV with a header row first",
+                name
+            )));
+            return;
+        }
+        self.lookup_editor = Some(LookupEditor::new(name, app, owner, content));
+        self.popup = Some(Popup::builder(PopupType::LookupEditor).build());
+    }
+
     // Inputs handlers
     pub(crate) fn handle_inputs_loaded(&mut self, inputs: Vec<splunk_client::models::Input>) {
         let count = Self::apply_paginated_items(&mut self.inputs, inputs, false);
@@ -311,6 +342,40 @@ impl App {
         self.loading = false;
     }
 
+    pub(crate) fn handle_input_throughput_loaded(
+        &mut self,
+        report: splunk_client::models::ThroughputReport,
+    ) {
+        let silent = self
+            .inputs
+            .iter()
+            .flatten()
+            .filter(|input| report.is_silent(input))
+            .count();
+        self.toasts.push(if silent == 0 {
+            Toast::info("Throughput loaded: no silent inputs")
+        } else {
+            Toast::warning(format!(
+                "Throughput loaded: {} enabled input(s) silent in the last hour",
+                silent
+            ))
+        });
+        self.input_throughput = Some(report);
+        self.loading = false;
+    }
+
+    pub(crate) fn handle_hec_tokens_loaded(
+        &mut self,
+        tokens: Vec<splunk_client::models::HecToken>,
+    ) {
+        let last = tokens.len().saturating_sub(1);
+        if self.hec_tokens_state.selected().is_some_and(|i| i > last) {
+            self.hec_tokens_state.select(Some(last));
+        }
+        self.hec_tokens = Some(tokens);
+        self.loading = false;
+    }
+
     // Fired alerts handlers
     pub(crate) fn handle_fired_alerts_loaded(
         &mut self,
@@ -349,6 +414,37 @@ impl App {
         self.loading = false;
     }
 
+    pub(crate) fn handle_dashboard_source_loaded(
+        &mut self,
+        dashboard: splunk_client::models::Dashboard,
+    ) {
+        self.dashboard_source = Some(dashboard);
+        self.dashboard_source_scroll_offset = 0;
+        self.popup = Some(Popup::builder(PopupType::DashboardSource).build());
+        self.loading = false;
+    }
+
+    pub(crate) fn handle_macro_expansion_loaded(
+        &mut self,
+        expansion: splunk_client::workflows::macro_expansion::MacroExpansion,
+    ) {
+        self.macro_expansion = Some(expansion);
+        self.macro_expansion_scroll_offset = 0;
+        self.popup = Some(Popup::builder(PopupType::MacroExpansion).build());
+        self.loading = false;
+    }
+
+    /// Confirm deleting the prunable jobs through the batch delete path.
+    pub(crate) fn handle_prunable_jobs_found(&mut self, sids: Vec<String>) {
+        self.loading = false;
+        if sids.is_empty() {
+            self.toasts
+                .push(Toast::info("No jobs match the prune criteria"));
+        } else {
+            self.popup = Some(Popup::builder(PopupType::ConfirmDeleteBatch(sids)).build());
+        }
+    }
+
     // Data models handlers
     pub(crate) fn handle_datamodels_loaded(&mut self, datamodels: Vec<DataModel>) {
         let count = Self::apply_paginated_items(&mut self.data_models, datamodels, false);
@@ -362,6 +458,32 @@ impl App {
         self.loading = false;
     }
 
+    pub(crate) fn handle_tstats_builder_loaded(&mut self, datamodel: DataModel) {
+        self.loading = false;
+        match TstatsBuilder::new(&datamodel) {
+            Some(builder) => {
+                self.tstats_builder = Some(builder);
+                self.popup = Some(Popup::builder(PopupType::TstatsBuilder).build());
+            }
+            None => self.toasts.push(Toast::warning(format!(
+                "Data model '{}' has no datasets to query",
+                datamodel.name
+            ))),
+        }
+    }
+
+    pub(crate) fn handle_top_values_loaded(
+        &mut self,
+        field: &str,
+        result: Result<Vec<serde_json::Value>, std::sync::Arc<splunk_client::ClientError>>,
+    ) {
+        self.loading = false;
+        // The popup may have been closed while the follow-up search was running
+        if let Some(top_values) = self.top_values.as_mut() {
+            top_values.set_results(field, result.map_err(|e| e.to_string()));
+        }
+    }
+
     // Workload management handlers
     pub(crate) fn handle_workload_pools_loaded(
         &mut self,
@@ -403,6 +525,9 @@ impl App {
     pub(crate) fn apply_loaded_settings(&mut self, state: splunk_config::PersistedState) {
         use crate::app::state::{parse_sort_column, parse_sort_direction};
         self.auto_refresh = state.auto_refresh;
+        self.result_renderers = state.result_renderers;
+        self.notifications = state.notifications;
+        self.health_thresholds = state.health_thresholds;
         self.sort_state.column = parse_sort_column(&state.sort_column);
         self.sort_state.direction = parse_sort_direction(&state.sort_direction);
         self.search_history = state.search_history;
@@ -437,6 +562,15 @@ impl App {
                 "cluster peers" => {
                     self.cluster_peers = None;
                 }
+                "cluster bucket health" => {
+                    self.cluster_bucket_health = None;
+                }
+                "cluster index compliance" => {
+                    self.cluster_index_compliance = None;
+                }
+                "cluster versions" => {
+                    self.cluster_versions = None;
+                }
                 "shc status" => {
                     self.shc_status = None;
                     self.shc_unavailable = true;
@@ -495,6 +629,8 @@ impl App {
             resource_name,
             "cluster info"
                 | "cluster peers"
+                | "cluster bucket health"
+                | "cluster index compliance"
                 | "shc status"
                 | "shc members"
                 | "shc captain"
@@ -27,6 +27,8 @@ fn test_indexes_loaded_updates_state() {
         thawed_path: None,
         cold_to_frozen_dir: None,
         primary_index: None,
+        min_time: None,
+        max_time: None,
     }];
 
     app.handle_data_loading_action(Action::IndexesLoaded(Ok(indexes)));
@@ -84,6 +86,59 @@ fn test_health_status_loaded_err() {
     assert_eq!(app.toasts.len(), 1);
 }
 
+#[test]
+fn test_session_renewed_sets_remaining_time() {
+    let mut app = App::new(None, ConnectionContext::default());
+    assert!(app.session_remaining().is_none());
+
+    app.handle_data_loading_action(Action::SessionRenewed {
+        expires_in_secs: 3600,
+    });
+
+    let remaining = app.session_remaining().unwrap();
+    assert!(remaining.as_secs() > 3590);
+    assert!(!app.session_expired);
+}
+
+#[test]
+fn test_session_expired_toasts_once() {
+    let mut app = App::new(None, ConnectionContext::default());
+    app.handle_data_loading_action(Action::SessionRenewed {
+        expires_in_secs: 3600,
+    });
+
+    for _ in 0..2 {
+        let error = splunk_client::ClientError::AuthFailed("bad password".to_string());
+        app.handle_data_loading_action(Action::SessionExpired(Arc::new(error)));
+    }
+
+    assert!(app.session_expired);
+    assert!(app.session_remaining().is_none());
+    assert_eq!(app.toasts.len(), 1);
+
+    // A later renewal clears the expired state
+    app.handle_data_loading_action(Action::SessionRenewed {
+        expires_in_secs: 60,
+    });
+    assert!(!app.session_expired);
+}
+
+#[test]
+fn test_offline_snapshot_until_fresh_load() {
+    let mut app = App::new(None, ConnectionContext::default());
+    app.current_screen = crate::app::state::CurrentScreen::Jobs;
+
+    app.handle_data_loading_action(Action::OfflineSnapshotLoaded {
+        saved_at: 1_700_000_000,
+        data: crate::action::OfflineData::Jobs(Vec::new()),
+    });
+    assert_eq!(app.jobs.as_ref().map(Vec::len), Some(0));
+    assert_eq!(app.offline_since(), Some(1_700_000_000));
+
+    app.handle_data_loading_action(Action::JobsLoaded(Ok(Vec::new())));
+    assert_eq!(app.offline_since(), None);
+}
+
 #[test]
 fn test_health_loaded_with_splunkd_health() {
     let mut app = App::new(None, ConnectionContext::default());
@@ -97,7 +152,13 @@ fn test_health_loaded_with_splunkd_health() {
         license_usage: None,
         kvstore_status: None,
         log_parsing_health: None,
+        scheduler_health: None,
+        queue_health: None,
+        security_health: None,
         circuit_breaker_states: None,
+        cluster_peers: None,
+        disk_partition: None,
+        search_load: None,
     };
 
     app.handle_data_loading_action(Action::HealthLoaded(Box::new(Ok(health_output))));
@@ -124,6 +185,8 @@ fn test_jobs_loaded_preserves_selection() {
             disk_usage: 1024,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         },
         splunk_client::SearchJobStatus {
             sid: "job2".to_string(),
@@ -138,6 +201,8 @@ fn test_jobs_loaded_preserves_selection() {
             disk_usage: 2048,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         },
     ];
 
@@ -202,6 +267,7 @@ fn test_cluster_peers_404_is_treated_as_expected_unclustered_state() {
         replication_status: Some(splunk_client::models::ReplicationStatus::Complete),
         bundle_replication_count: Some(1),
         is_captain: Some(false),
+        splunk_version: None,
     }]);
 
     app.handle_data_loading_action(Action::ClusterPeersLoaded(Err(Arc::new(
@@ -324,6 +390,38 @@ fn test_cluster_info_503_is_not_treated_as_expected_unclustered_state() {
     assert!(!app.loading);
 }
 
+#[test]
+fn test_input_throughput_loaded_warns_about_silent_inputs() {
+    let mut app = App::new(None, ConnectionContext::default());
+    app.loading = true;
+    app.inputs = Some(
+        serde_json::from_value(serde_json::json!([
+            { "name": "/var/log/messages", "input_type": "monitor" },
+            { "name": "/var/log/secure", "input_type": "monitor" }
+        ]))
+        .unwrap(),
+    );
+
+    let report = splunk_client::models::ThroughputReport {
+        window_secs: 3600,
+        sources: vec![splunk_client::models::SourceThroughput {
+            series: "/var/log/messages".to_string(),
+            kb: 360.0,
+            events: 3600,
+            last_seen: None,
+        }],
+    };
+
+    app.handle_data_loading_action(Action::InputThroughputLoaded(Ok(report)));
+
+    assert!(app.input_throughput.is_some());
+    assert!(!app.loading);
+    assert_eq!(
+        app.toasts.last().map(|toast| toast.message.as_str()),
+        Some("Throughput loaded: 1 enabled input(s) silent in the last hour")
+    );
+}
+
 #[test]
 fn test_config_files_loaded_updates_state() {
     let mut app = App::new(None, ConnectionContext::default());
@@ -588,10 +686,18 @@ fn test_settings_loaded_updates_search_defaults_and_page_size() {
         recent_export_paths: Vec::new(),
         export_format: "Json".to_string(),
         last_saved_at: None,
+        result_renderers: true,
+        no_color: false,
         onboarding_checklist: splunk_config::PersistedOnboardingChecklist::default(),
+        saved_sessions: Vec::new(),
+        custom_theme: None,
+        clipboard_backend: splunk_config::ClipboardBackend::Auto,
+        rest_history: Vec::new(),
+        notifications: Default::default(),
+        health_thresholds: Default::default(),
     };
 
-    app.handle_data_loading_action(Action::SettingsLoaded(new_state));
+    app.handle_data_loading_action(Action::SettingsLoaded(Box::new(new_state)));
 
     // Verify search_defaults was updated
     assert_eq!(app.search_defaults.max_results, 500);
@@ -633,10 +739,18 @@ fn test_settings_loaded_handles_zero_max_results() {
         recent_export_paths: Vec::new(),
         export_format: "Json".to_string(),
         last_saved_at: None,
+        result_renderers: true,
+        no_color: false,
         onboarding_checklist: splunk_config::PersistedOnboardingChecklist::default(),
+        saved_sessions: Vec::new(),
+        custom_theme: None,
+        clipboard_backend: splunk_config::ClipboardBackend::Auto,
+        rest_history: Vec::new(),
+        notifications: Default::default(),
+        health_thresholds: Default::default(),
     };
 
-    app.handle_data_loading_action(Action::SettingsLoaded(new_state));
+    app.handle_data_loading_action(Action::SettingsLoaded(Box::new(new_state)));
 
     // Verify search_defaults.max_results was set to 0 (raw value stored)
     assert_eq!(app.search_defaults.max_results, 0);
@@ -1,16 +1,17 @@
 //! Navigation action handlers for the TUI app.
 //!
 //! Responsibilities:
-//! - Handle screen switching actions (SwitchTo*, NextScreen, PreviousScreen)
+//! - Handle screen switching actions (SwitchTo*, NextScreen, PreviousScreen, SwitchToScreen)
 //! - Handle data loading triggers that also switch screens
 //! - Handle list navigation (NavigateDown, NavigateUp, PageDown, PageUp, etc.)
 //! - Handle job inspection mode transitions
 
 use crate::action::Action;
 use crate::app::App;
-use crate::app::state::CurrentScreen;
+use crate::app::state::{CurrentScreen, JobInspectTab};
 use crate::onboarding::OnboardingMilestone;
 use crate::ux_telemetry::ScreenLabel;
+use splunk_client::models::MetadataType;
 
 impl App {
     /// Handle navigation-related actions.
@@ -27,6 +28,21 @@ impl App {
             Action::OpenCommandPalette => {
                 self.open_command_palette();
             }
+            Action::OpenServerMessages => {
+                self.open_server_messages();
+            }
+            Action::OpenBackgroundJobs => {
+                self.open_background_jobs();
+            }
+            Action::OpenNamespaceSelector => {
+                self.open_namespace_selector();
+            }
+            Action::OpenSessionPicker => {
+                self.open_session_picker();
+            }
+            Action::OpenTroubleshootingRecipes => {
+                self.open_troubleshooting_recipes();
+            }
             Action::SwitchToSearch => {
                 self.current_screen = CurrentScreen::Search;
                 self.init_focus_manager_for_screen(CurrentScreen::Search);
@@ -59,6 +75,14 @@ impl App {
                     collector.record_navigation(ScreenLabel::from(prev_screen));
                 }
             }
+            Action::SwitchToScreen(screen) => {
+                self.current_screen = screen;
+                self.init_focus_manager_for_screen(screen);
+                self.clear_error_on_navigation();
+                if let Some(ref mut collector) = self.ux_telemetry {
+                    collector.record_navigation(ScreenLabel::from(screen));
+                }
+            }
             Action::LoadIndexes { offset, .. } => {
                 self.current_screen = CurrentScreen::Indexes;
                 self.init_focus_manager_for_screen(CurrentScreen::Indexes);
@@ -73,7 +97,13 @@ impl App {
             Action::ToggleClusterViewMode => {
                 self.toggle_cluster_view_mode();
             }
-            Action::LoadJobs { offset, .. } => {
+            Action::ToggleClusterBucketsView => {
+                self.cluster_view_mode = self.cluster_view_mode.toggle_buckets();
+            }
+            Action::ToggleClusterComplianceView => {
+                self.cluster_view_mode = self.cluster_view_mode.toggle_compliance();
+            }
+            Action::LoadJobs { offset, .. } | Action::LoadFilteredJobs { offset, .. } => {
                 self.current_screen = CurrentScreen::Jobs;
                 self.init_focus_manager_for_screen(CurrentScreen::Jobs);
                 if offset == 0 {
@@ -191,6 +221,23 @@ impl App {
                     self.workload_rules_pagination.reset();
                 }
             }
+            Action::LoadIngestionStats => {
+                self.current_screen = CurrentScreen::Ingestion;
+                self.init_focus_manager_for_screen(CurrentScreen::Ingestion);
+            }
+            Action::LoadSourcetypes => {
+                self.current_screen = CurrentScreen::Sourcetypes;
+                self.init_focus_manager_for_screen(CurrentScreen::Sourcetypes);
+            }
+            Action::LoadMetadata { metadata_type } => {
+                self.current_screen = CurrentScreen::Metadata;
+                self.init_focus_manager_for_screen(CurrentScreen::Metadata);
+                if metadata_type != self.metadata_type {
+                    self.metadata_type = metadata_type;
+                    self.metadata_entries = None;
+                    self.metadata_state.select(Some(0));
+                }
+            }
             Action::LoadForwarders { offset, .. } => {
                 self.current_screen = CurrentScreen::Forwarders;
                 self.init_focus_manager_for_screen(CurrentScreen::Forwarders);
@@ -204,6 +251,30 @@ impl App {
             Action::ToggleShcViewMode => {
                 self.toggle_shc_view_mode();
             }
+            Action::ToggleRoleUsageView => {
+                self.roles_view_mode = self.roles_view_mode.toggle();
+            }
+            Action::ToggleRoleMatrixView => {
+                self.roles_view_mode = self.roles_view_mode.toggle_matrix();
+            }
+            Action::ToggleHealthResourcesView => {
+                self.health_view_mode = self.health_view_mode.toggle();
+            }
+            Action::ToggleMetadataType => {
+                self.metadata_type = match self.metadata_type {
+                    MetadataType::Hosts => MetadataType::Sources,
+                    _ => MetadataType::Hosts,
+                };
+                self.metadata_entries = None;
+                self.metadata_state.select(Some(0));
+            }
+            Action::ToggleInputsHttpView => {
+                self.inputs_view_mode = self.inputs_view_mode.toggle();
+                self.revealed_hec_token = None;
+            }
+            Action::ToggleJobPerformanceView => {
+                self.job_inspect_tab = self.job_inspect_tab.toggle();
+            }
             Action::NavigateDown => self.next_item(),
             Action::NavigateUp => self.previous_item(),
             Action::PageDown => self.next_page(),
@@ -253,12 +324,14 @@ impl App {
             && self.jobs_state.selected().is_some()
         {
             self.current_screen = CurrentScreen::JobInspect;
+            self.job_inspect_tab = JobInspectTab::Details;
+            self.job_performance = None;
         }
     }
 
     /// Clear error state when navigating to a new screen.
     /// This prevents errors from persisting across screen changes.
-    fn clear_error_on_navigation(&mut self) {
+    pub(crate) fn clear_error_on_navigation(&mut self) {
         self.current_error = None;
         // Also clear validation state if leaving the Search screen
         if self.current_screen != CurrentScreen::Search {
@@ -268,7 +341,7 @@ impl App {
 
     /// Initialize the FocusManager for the given screen.
     /// Sets up focusable component IDs based on the screen type.
-    fn init_focus_manager_for_screen(&mut self, screen: CurrentScreen) {
+    pub(crate) fn init_focus_manager_for_screen(&mut self, screen: CurrentScreen) {
         use crate::focus::FocusManager;
 
         let component_ids: Vec<String> = match screen {
@@ -325,6 +398,18 @@ impl App {
             CurrentScreen::Inputs => {
                 vec!["inputs_list".to_string()]
             }
+            CurrentScreen::Ingestion => {
+                vec!["ingestion_list".to_string()]
+            }
+            CurrentScreen::Sourcetypes => {
+                vec!["sourcetypes_list".to_string()]
+            }
+            CurrentScreen::Metadata => {
+                vec!["metadata_list".to_string()]
+            }
+            CurrentScreen::Rest => {
+                vec!["rest_history_list".to_string()]
+            }
             CurrentScreen::FiredAlerts => {
                 vec!["fired_alerts_list".to_string()]
             }
@@ -444,6 +529,84 @@ mod tests {
         // Note: actual peers load is triggered by side effects, not the action handler
     }
 
+    #[test]
+    fn test_toggle_cluster_compliance_view() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.cluster_view_mode = ClusterViewMode::Buckets;
+
+        app.handle_navigation_action(Action::ToggleClusterComplianceView);
+        assert_eq!(app.cluster_view_mode, ClusterViewMode::Compliance);
+
+        app.handle_navigation_action(Action::ToggleClusterComplianceView);
+        assert_eq!(app.cluster_view_mode, ClusterViewMode::Summary);
+    }
+
+    #[test]
+    fn test_toggle_role_usage_view() {
+        use crate::app::state::RolesViewMode;
+        let mut app = App::new(None, ConnectionContext::default());
+        assert_eq!(app.roles_view_mode, RolesViewMode::List);
+
+        app.handle_navigation_action(Action::ToggleRoleUsageView);
+        assert_eq!(app.roles_view_mode, RolesViewMode::Usage);
+
+        app.handle_navigation_action(Action::ToggleRoleUsageView);
+        assert_eq!(app.roles_view_mode, RolesViewMode::List);
+
+        app.handle_navigation_action(Action::ToggleRoleMatrixView);
+        assert_eq!(app.roles_view_mode, RolesViewMode::Matrix);
+
+        app.handle_navigation_action(Action::ToggleRoleUsageView);
+        assert_eq!(app.roles_view_mode, RolesViewMode::Usage);
+
+        app.handle_navigation_action(Action::ToggleRoleMatrixView);
+        app.handle_navigation_action(Action::ToggleRoleMatrixView);
+        assert_eq!(app.roles_view_mode, RolesViewMode::List);
+    }
+
+    #[test]
+    fn test_toggle_health_resources_view() {
+        use crate::app::state::HealthViewMode;
+        let mut app = App::new(None, ConnectionContext::default());
+        assert_eq!(app.health_view_mode, HealthViewMode::Overview);
+
+        app.handle_navigation_action(Action::ToggleHealthResourcesView);
+        assert_eq!(app.health_view_mode, HealthViewMode::Resources);
+
+        app.handle_navigation_action(Action::ToggleHealthResourcesView);
+        assert_eq!(app.health_view_mode, HealthViewMode::Overview);
+    }
+
+    #[test]
+    fn test_toggle_inputs_http_view_masks_tokens_again() {
+        use crate::app::state::InputsViewMode;
+        let mut app = App::new(None, ConnectionContext::default());
+        assert_eq!(app.inputs_view_mode, InputsViewMode::Inputs);
+
+        app.handle_navigation_action(Action::ToggleInputsHttpView);
+        assert_eq!(app.inputs_view_mode, InputsViewMode::Http);
+
+        app.revealed_hec_token = Some("http://web_events".to_string());
+        app.handle_navigation_action(Action::ToggleInputsHttpView);
+        assert_eq!(app.inputs_view_mode, InputsViewMode::Inputs);
+        assert!(app.revealed_hec_token.is_none());
+    }
+
+    #[test]
+    fn test_toggle_job_performance_view_resets_on_inspect() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.handle_navigation_action(Action::ToggleJobPerformanceView);
+        assert_eq!(app.job_inspect_tab, JobInspectTab::Performance);
+
+        app.jobs = Some(vec![
+            serde_json::from_value(serde_json::json!({ "sid": "job1" })).unwrap(),
+        ]);
+        app.jobs_state.select(Some(0));
+        app.handle_navigation_action(Action::InspectJob);
+        assert_eq!(app.current_screen, CurrentScreen::JobInspect);
+        assert_eq!(app.job_inspect_tab, JobInspectTab::Details);
+    }
+
     #[test]
     fn test_inspect_job_with_no_jobs_does_nothing() {
         let mut app = App::new(None, ConnectionContext::default());
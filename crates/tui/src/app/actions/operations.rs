@@ -0,0 +1,206 @@
+//! Long-running operation action handlers for the TUI app.
+//!
+//! This module tracks the operation shown in the progress popup: it opens the
+//! popup when an operation starts, applies per-item updates, cancels the
+//! operation's token on request, and closes the popup when it finishes.
+//! Operations are also recorded in the jobs tray, and one that finishes while
+//! its popup is hidden (sent to the background) is announced with a toast.
+
+use crate::action::Action;
+use crate::app::App;
+use crate::app::background_jobs::{BackgroundJobKind, BackgroundJobStatus};
+use crate::ui::Toast;
+use crate::ui::popup::{Popup, PopupType};
+
+impl App {
+    /// Handle long-running operation progress actions.
+    pub fn handle_operation_action(&mut self, action: Action) {
+        match action {
+            Action::OperationStarted(progress) => {
+                self.background_jobs
+                    .start_operation(progress.id, &progress.label);
+                self.operation_progress = Some(*progress);
+                // Don't replace a dialog the user is interacting with.
+                if self.popup.is_none() {
+                    self.popup = Some(Popup::builder(PopupType::OperationProgress).build());
+                }
+            }
+            Action::OperationProgressed(update) => {
+                if let Some(progress) = self.operation_progress.as_mut() {
+                    progress.apply(update);
+                }
+            }
+            Action::OperationFinished { id, cancelled } => {
+                let kind = BackgroundJobKind::Operation { id };
+                let Some(progress) = self
+                    .operation_progress
+                    .take_if(|progress| progress.id == id)
+                else {
+                    let status = if cancelled {
+                        BackgroundJobStatus::Cancelled("cancelled".to_string())
+                    } else {
+                        BackgroundJobStatus::Finished("done".to_string())
+                    };
+                    self.background_jobs.finish(kind, status);
+                    return;
+                };
+                let in_background = !matches!(
+                    self.popup.as_ref().map(|p| &p.kind),
+                    Some(PopupType::OperationProgress)
+                );
+                if !in_background {
+                    self.popup = None;
+                }
+
+                let done = match progress.total {
+                    Some(total) => format!("{} of {}", progress.processed(), total),
+                    None => progress.processed().to_string(),
+                };
+                if cancelled {
+                    self.background_jobs.finish(
+                        kind,
+                        BackgroundJobStatus::Cancelled(format!("after {} item(s)", done)),
+                    );
+                    self.toasts.push(Toast::warning(format!(
+                        "{} cancelled after {} item(s)",
+                        progress.label, done
+                    )));
+                    return;
+                }
+
+                let mut summary = format!("{} item(s) done", done);
+                if progress.failed > 0 {
+                    summary.push_str(&format!(", {} failed", progress.failed));
+                }
+                self.background_jobs
+                    .finish(kind, BackgroundJobStatus::Finished(summary.clone()));
+                if in_background {
+                    let toast = if progress.failed > 0 {
+                        Toast::warning
+                    } else {
+                        Toast::success
+                    };
+                    self.toasts
+                        .push(toast(format!("{} finished: {}", progress.label, summary)));
+                }
+            }
+            Action::CancelOperation => {
+                if let Some(progress) = self.operation_progress.as_mut()
+                    && !progress.cancel_requested
+                {
+                    progress.request_cancel();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::operation_progress::{OperationProgress, ProgressUpdate};
+
+    #[test]
+    fn test_operation_lifecycle_opens_updates_and_closes_popup() {
+        let mut app = App::new(None, ConnectionContext::default());
+        let progress = OperationProgress::new("Deleting 2 job(s)", Some(2));
+        let id = progress.id;
+
+        app.update(Action::OperationStarted(Box::new(progress)));
+        assert!(matches!(
+            app.popup.as_ref().map(|p| &p.kind),
+            Some(PopupType::OperationProgress)
+        ));
+
+        app.update(Action::OperationProgressed(ProgressUpdate {
+            id,
+            completed: 1,
+            failed: 0,
+            current: Some("sid-1".to_string()),
+        }));
+        assert_eq!(app.operation_progress.as_ref().unwrap().completed, 1);
+
+        app.update(Action::OperationFinished {
+            id,
+            cancelled: false,
+        });
+        assert!(app.operation_progress.is_none());
+        assert!(app.popup.is_none());
+        assert!(app.toasts.is_empty());
+    }
+
+    #[test]
+    fn test_cancel_operation_cancels_token_and_reports() {
+        let mut app = App::new(None, ConnectionContext::default());
+        let progress = OperationProgress::new("Exporting", Some(1));
+        let token = progress.cancel_token();
+        let id = progress.id;
+        app.update(Action::OperationStarted(Box::new(progress)));
+
+        app.update(Action::CancelOperation);
+        assert!(token.is_cancelled());
+        assert!(app.operation_progress.as_ref().unwrap().cancel_requested);
+
+        app.update(Action::OperationFinished {
+            id,
+            cancelled: true,
+        });
+        assert!(app.popup.is_none());
+        assert!(
+            app.toasts
+                .iter()
+                .any(|t| t.message.contains("Exporting cancelled after 0 of 1"))
+        );
+    }
+
+    #[test]
+    fn test_operation_started_keeps_existing_popup() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.popup = Some(Popup::builder(PopupType::Help).build());
+
+        app.update(Action::OperationStarted(Box::new(OperationProgress::new(
+            "Refreshing profiles",
+            None,
+        ))));
+
+        assert!(matches!(
+            app.popup.as_ref().map(|p| &p.kind),
+            Some(PopupType::Help)
+        ));
+        assert!(app.operation_progress.is_some());
+    }
+
+    #[test]
+    fn test_background_operation_toasts_when_finished() {
+        let mut app = App::new(None, ConnectionContext::default());
+        let progress = OperationProgress::new("Deleting 2 job(s)", Some(2));
+        let id = progress.id;
+        app.update(Action::OperationStarted(Box::new(progress)));
+        app.popup = None;
+        assert_eq!(app.background_jobs.running_count(), 1);
+
+        app.update(Action::OperationProgressed(ProgressUpdate {
+            id,
+            completed: 2,
+            failed: 0,
+            current: None,
+        }));
+        app.update(Action::OperationFinished {
+            id,
+            cancelled: false,
+        });
+
+        assert_eq!(app.background_jobs.running_count(), 0);
+        assert_eq!(
+            app.background_jobs.jobs()[0].status,
+            BackgroundJobStatus::Finished("2 of 2 item(s) done".to_string())
+        );
+        assert!(
+            app.toasts
+                .iter()
+                .any(|t| t.message == "Deleting 2 job(s) finished: 2 of 2 item(s) done")
+        );
+    }
+}
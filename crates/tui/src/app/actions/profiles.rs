@@ -129,9 +129,12 @@ impl App {
         self.profile_name = ctx.profile_name;
         self.base_url = Some(ctx.base_url);
         self.auth_mode = Some(ctx.auth_mode);
+        self.profile_health_thresholds = ctx.health_thresholds;
         // Clear server info until new health check loads
         self.server_version = None;
         self.server_build = None;
+        self.server_restart_required = false;
+        self.server_restarting = false;
         self.toasts.push(Toast::info(format!(
             "Switched to profile: {}",
             self.profile_name.as_deref().unwrap_or("default")
@@ -144,13 +147,25 @@ impl App {
         self.jobs = None;
         self.saved_searches = None;
         self.internal_logs = None;
+        self.internal_log_anomalies.reset();
+        self.internal_logs_follow = Default::default();
         self.cluster_info = None;
         self.cluster_peers = None;
+        self.cluster_bucket_health = None;
+        self.cluster_index_compliance = None;
+        self.cluster_versions = None;
         self.health_info = None;
+        self.resource_usage = None;
+        self.offline_snapshots.clear();
         self.license_info = None;
         self.kvstore_status = None;
         self.apps = None;
         self.users = None;
+        self.role_usage = None;
+        self.role_matrix = None;
+        self.job_performance = None;
+        self.server_messages = Default::default();
+        self.current_context = None;
         self.search_peers = None;
         self.forwarders = None;
         self.lookups = None;
@@ -160,9 +175,18 @@ impl App {
         self.shc_config = None;
         self.shc_unavailable = false;
         self.inputs = None;
+        self.hec_tokens = None;
+        self.revealed_hec_token = None;
+        self.ingestion_stats = None;
+        self.sourcetypes = None;
+        self.metadata_entries = None;
+        self.rest_response = None;
+        self.rest_response_scroll = 0;
         self.fired_alerts = None;
         self.search_results.clear();
+        self.clear_result_filter();
         self.search_sid = None;
+        self.search_job = None;
         self.search_results_total_count = None;
         self.search_has_more_results = false;
 
@@ -178,6 +202,9 @@ impl App {
         self.forwarders_state.select(Some(0));
         self.lookups_state.select(Some(0));
         self.inputs_state.select(Some(0));
+        self.ingestion_state.select(Some(0));
+        self.sourcetypes_state.select(Some(0));
+        self.metadata_state.select(Some(0));
         self.fired_alerts_state.select(Some(0));
         // Trigger reload for current screen
         // The load action will be sent by main.rs after this
@@ -274,6 +301,7 @@ mod tests {
             profile_name: Some("test-profile".to_string()),
             base_url: "https://splunk.example.com".to_string(),
             auth_mode: "session".to_string(),
+            health_thresholds: None,
         };
 
         app.handle_profile_action(Action::ProfileSwitchResult(Ok(ctx)));
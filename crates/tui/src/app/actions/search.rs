@@ -2,13 +2,22 @@
 //!
 //! Responsibilities:
 //! - Handle search lifecycle actions (SearchStarted, SearchComplete)
+//! - Record searches in the jobs tray and announce ones that finish off-screen
+//! - Send the configured notification when a long-running search finishes
 //! - Handle pagination of search results (MoreSearchResultsLoaded)
+//! - Track the search job's artifact TTL for the Search screen warning (SearchJobLoaded)
 //! - Update search state and metadata
 
 use crate::action::Action;
 use crate::app::App;
+use crate::app::background_jobs::{BackgroundJobKind, BackgroundJobStatus, format_count};
+use crate::app::notifier::{self, Notification};
+use crate::app::state::CurrentScreen;
+use crate::app::top_values::SearchScope;
 use crate::onboarding::OnboardingMilestone;
+use crate::operation_progress::format_duration;
 use crate::ui::Toast;
+use crate::ui::screens::job_details::TTL_WARNING_SECS;
 use serde_json::Value;
 
 impl App {
@@ -16,7 +25,14 @@ impl App {
     pub fn handle_search_action(&mut self, action: Action) {
         match action {
             Action::SearchStarted(query) => {
+                self.last_search_scope = Some(SearchScope {
+                    query: query.clone(),
+                    earliest_time: self.search_defaults.earliest_time.clone(),
+                    latest_time: self.search_defaults.latest_time.clone(),
+                });
+                self.background_jobs.start_search(&query);
                 self.running_query = Some(query);
+                self.search_started_at = Some(std::time::Instant::now());
             }
             Action::SearchComplete(Ok((results, sid, total))) => {
                 self.handle_search_complete(results, sid, total);
@@ -55,14 +71,40 @@ impl App {
                 self.loading = false;
                 self.loading_since = None;
             }
+            Action::SearchJobLoaded(Ok(job)) => {
+                // A late status fetch must not attach an older job to newer results
+                if self.search_sid.as_deref() == Some(job.sid.as_str()) {
+                    self.search_job = Some((job, std::time::Instant::now()));
+                }
+            }
+            Action::SearchJobLoaded(Err(e)) => {
+                // The TTL warning is best-effort; the loaded results stay usable
+                tracing::debug!("Failed to load search job status: {}", e);
+            }
             _ => {}
         }
     }
 
+    /// Remaining artifact lifetime of the search job, when it is below the warning threshold.
+    ///
+    /// The TTL is counted down from when the job status was fetched.
+    pub(crate) fn search_job_expiring_ttl(&self) -> Option<usize> {
+        let (job, loaded_at) = self.search_job.as_ref()?;
+        let elapsed = loaded_at.elapsed().as_secs() as usize;
+        job.ttl_expires_within(TTL_WARNING_SECS + elapsed)
+            .then(|| job.ttl.unwrap_or_default().saturating_sub(elapsed))
+    }
+
     fn handle_search_complete(&mut self, results: Vec<Value>, sid: String, total: Option<usize>) {
         let results_count = results.len();
+        self.announce_search_finished(total.unwrap_or(results_count));
         self.set_search_results(results);
+        self.search_result_stats.duration = self
+            .search_started_at
+            .take()
+            .map(|started| started.elapsed());
         self.search_sid = Some(sid);
+        self.search_job = None;
 
         // Set pagination state from initial search results
         self.search_results_total_count = total;
@@ -90,6 +132,38 @@ impl App {
         self.mark_onboarding_milestone(OnboardingMilestone::FirstSearchRun);
     }
 
+    /// Mark the running search finished in the jobs tray, send a notification
+    /// when it ran long enough, and, when the user has moved off the Search
+    /// screen, toast it with a `g` shortcut to view it.
+    fn announce_search_finished(&mut self, count: usize) {
+        let summary = format!("{} results", format_count(count));
+        let Some(job) = self.background_jobs.finish(
+            BackgroundJobKind::Search,
+            BackgroundJobStatus::Finished(summary.clone()),
+        ) else {
+            return;
+        };
+        let elapsed = job.elapsed();
+        if elapsed.as_secs() >= self.notifications.min_search_secs {
+            notifier::send(
+                &self.notifications,
+                Notification::new(
+                    format!("{} finished", job.label),
+                    format!("{} in {}", summary, format_duration(elapsed)),
+                ),
+            );
+        }
+        if self.current_screen == CurrentScreen::Search {
+            return;
+        }
+        let toast = Toast::info(format!(
+            "{} finished: {} — press g to view",
+            job.label, summary
+        ));
+        self.background_jobs.view_toast = Some(toast.id);
+        self.toasts.push(toast);
+    }
+
     fn handle_search_error(
         &mut self,
         error_msg: String,
@@ -111,9 +185,14 @@ impl App {
             );
         }
 
+        self.background_jobs.finish(
+            BackgroundJobKind::Search,
+            BackgroundJobStatus::Failed(error_msg.clone()),
+        );
         self.current_error = Some(details);
         self.toasts.push(Toast::error(error_msg));
         self.running_query = None; // Clear the running query on error
+        self.search_started_at = None;
         self.loading = false;
     }
 }
@@ -154,6 +233,155 @@ mod tests {
         assert!(!app.loading);
     }
 
+    #[test]
+    fn test_search_job_ttl_warning_follows_current_results() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.handle_search_action(Action::SearchComplete(Ok((
+            vec![serde_json::json!({"_raw": "a"})],
+            "search_123".to_string(),
+            Some(1),
+        ))));
+        let job = |sid: &str, ttl: usize| {
+            serde_json::from_value(serde_json::json!({"sid": sid, "ttl": ttl})).unwrap()
+        };
+
+        // A status for an older search is ignored
+        app.handle_search_action(Action::SearchJobLoaded(Ok(job("search_old", 30))));
+        assert!(app.search_job.is_none());
+
+        app.handle_search_action(Action::SearchJobLoaded(Ok(job("search_123", 3600))));
+        assert_eq!(app.search_job_expiring_ttl(), None);
+
+        app.handle_search_action(Action::SearchJobLoaded(Ok(job("search_123", 570))));
+        assert_eq!(app.search_job_expiring_ttl(), Some(570));
+
+        // A new search drops the previous job's warning
+        app.handle_search_action(Action::SearchComplete(Ok((
+            vec![],
+            "search_456".to_string(),
+            Some(0),
+        ))));
+        assert_eq!(app.search_job_expiring_ttl(), None);
+    }
+
+    #[test]
+    fn test_search_stats_track_duration_and_appended_pages() {
+        let mut app = App::new(None, ConnectionContext::default());
+
+        app.handle_search_action(Action::SearchStarted("index=main".to_string()));
+        app.handle_search_action(Action::SearchComplete(Ok((
+            vec![serde_json::json!({"_raw": "a", "host": "web-01"})],
+            "sid".to_string(),
+            Some(2),
+        ))));
+        assert!(app.search_result_stats.duration.is_some());
+        assert!(app.search_started_at.is_none());
+        assert_eq!(app.search_result_stats.field_count(), 2);
+
+        app.handle_search_action(Action::MoreSearchResultsLoaded(Ok((
+            vec![serde_json::json!({"_raw": "b", "user": "admin"})],
+            1,
+            Some(2),
+        ))));
+        assert_eq!(app.search_result_stats.field_count(), 3);
+    }
+
+    #[test]
+    fn test_search_finished_off_screen_toasts_and_g_views_it() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.current_screen = CurrentScreen::Jobs;
+
+        app.handle_search_action(Action::SearchStarted("index=main".to_string()));
+        app.handle_search_action(Action::SearchComplete(Ok((
+            vec![serde_json::json!({"_raw": "a"})],
+            "sid".to_string(),
+            Some(12345),
+        ))));
+
+        let job = &app.background_jobs.jobs()[0];
+        assert_eq!(
+            job.status,
+            BackgroundJobStatus::Finished("12,345 results".to_string())
+        );
+        let toast = app.toasts.last().unwrap();
+        assert_eq!(
+            toast.message,
+            format!("{} finished: 12,345 results — press g to view", job.label)
+        );
+
+        let action = app.handle_input(crossterm::event::KeyEvent::new(
+            crossterm::event::KeyCode::Char('g'),
+            crossterm::event::KeyModifiers::NONE,
+        ));
+        assert!(matches!(action, Some(Action::SwitchToSearch)));
+        assert!(app.background_jobs.view_toast.is_none());
+    }
+
+    #[test]
+    fn test_g_on_settings_cycles_repeat_threshold_not_completed_search() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.current_screen = CurrentScreen::Settings;
+        let threshold = app.internal_logs_defaults.anomalies.repeat_threshold;
+
+        app.handle_search_action(Action::SearchStarted("index=main".to_string()));
+        app.handle_search_action(Action::SearchComplete(Ok((
+            vec![serde_json::json!({"_raw": "a"})],
+            "sid".to_string(),
+            Some(1),
+        ))));
+        assert!(app.background_jobs.view_toast.is_some());
+
+        let action = app.handle_input(crossterm::event::KeyEvent::new(
+            crossterm::event::KeyCode::Char('g'),
+            crossterm::event::KeyModifiers::NONE,
+        ));
+        assert!(!matches!(action, Some(Action::SwitchToSearch)));
+        assert_eq!(app.current_screen, CurrentScreen::Settings);
+        assert_ne!(
+            app.internal_logs_defaults.anomalies.repeat_threshold,
+            threshold
+        );
+        assert!(app.background_jobs.view_toast.is_some());
+    }
+
+    #[test]
+    fn test_long_search_sends_notification() {
+        let recorded = notifier::record_notifications_for_tests();
+        let mut app = App::new(None, ConnectionContext::default());
+        app.notifications.target = splunk_config::NotificationTarget::Command;
+        app.notifications.min_search_secs = 0;
+
+        app.handle_search_action(Action::SearchStarted("index=main".to_string()));
+        app.handle_search_action(Action::SearchComplete(Ok((
+            vec![],
+            "sid".to_string(),
+            Some(1500),
+        ))));
+
+        let recorded = recorded.lock().unwrap();
+        assert_eq!(recorded.len(), 1);
+        let (target, notification) = &recorded[0];
+        assert_eq!(*target, splunk_config::NotificationTarget::Command);
+        assert!(notification.title.starts_with("Search "));
+        assert!(notification.body.starts_with("1,500 results in "));
+    }
+
+    #[test]
+    fn test_search_finished_on_search_screen_does_not_toast() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.current_screen = CurrentScreen::Search;
+
+        app.handle_search_action(Action::SearchStarted("index=main".to_string()));
+        app.handle_search_action(Action::SearchComplete(Ok((
+            vec![],
+            "sid".to_string(),
+            Some(0),
+        ))));
+
+        assert!(app.toasts.is_empty());
+        assert_eq!(app.background_jobs.running_count(), 0);
+    }
+
     #[test]
     fn test_search_complete_with_total_none() {
         let mut app = App::new(None, ConnectionContext::default());
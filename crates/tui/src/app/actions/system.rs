@@ -9,8 +9,12 @@ use crate::action::Action;
 use crate::app::App;
 use crate::app::clipboard;
 use crate::app::input::components::SingleLineInput;
+use crate::app::state::CurrentScreen;
 use crate::ui::{Toast, ToastLevel};
 use ratatui::widgets::{ListState, TableState};
+use splunk_client::models::Namespace;
+use splunk_client::workflows::saved_search_bulk::BulkItemStatus;
+use splunk_client::workflows::saved_search_ownership::ReassignStatus;
 
 impl App {
     /// Handle system/miscellaneous actions.
@@ -60,6 +64,12 @@ impl App {
                 self.search_filter = None;
                 self.rebuild_filtered_indices();
             }
+            Action::CycleSortColumn if self.current_screen == CurrentScreen::Ingestion => {
+                self.cycle_ingestion_sort();
+            }
+            Action::ToggleSortDirection if self.current_screen == CurrentScreen::Ingestion => {
+                self.toggle_ingestion_sort_direction();
+            }
             Action::CycleSortColumn => {
                 self.sort_state.cycle();
                 self.rebuild_filtered_indices();
@@ -71,6 +81,9 @@ impl App {
             Action::CycleTheme => {
                 self.cycle_theme();
             }
+            Action::SetNamespace(namespace) => {
+                self.set_namespace(namespace);
+            }
             Action::SplValidationResult {
                 valid,
                 errors,
@@ -97,6 +110,22 @@ impl App {
             Action::OpenCreateIndexDialog => {
                 self.open_create_index_dialog();
             }
+            Action::OpenOnboardingWizard => {
+                self.onboarding_wizard = Some(crate::wizard::OnboardingWizard::new());
+                self.popup = Some(
+                    crate::ui::popup::Popup::builder(crate::ui::popup::PopupType::OnboardingWizard)
+                        .build(),
+                );
+            }
+            Action::OnboardingWizardFinished(result) => {
+                self.handle_onboarding_wizard_finished(result);
+            }
+            Action::OpenFieldExtractionTester => self.open_extraction_tester(),
+            Action::FieldExtractionTested(result) => self.handle_field_extraction_tested(result),
+            Action::OpenRestRequestDialog => self.open_rest_request_popup(String::new()),
+            Action::RestResponseLoaded { request, result } => {
+                self.handle_rest_response(request, result)
+            }
             Action::OpenModifyIndexDialog { name } => {
                 self.open_modify_index_dialog(name);
             }
@@ -236,6 +265,60 @@ impl App {
                     }
                 }
             }
+            Action::SavedSearchesBulkUpdated(result) => {
+                self.loading = false;
+                match result {
+                    Ok(report) => {
+                        self.selected_saved_searches.clear();
+                        let applied = report.count(BulkItemStatus::Applied);
+                        let unchanged = report.count(BulkItemStatus::Unchanged);
+                        let failed = report.failed_count();
+                        let summary = format!(
+                            "Applied {} to {} saved search(es) ({} unchanged, {} failed)",
+                            report.action, applied, unchanged, failed
+                        );
+                        if failed > 0 {
+                            self.toasts.push(Toast::warning(summary));
+                        } else {
+                            self.toasts.push(Toast::success(summary));
+                        }
+                        self.saved_searches = None;
+                    }
+                    Err(e) => {
+                        self.toasts.push(Toast::error(format!(
+                            "Failed to update saved searches: {}",
+                            e
+                        )));
+                    }
+                }
+            }
+            Action::SavedSearchesReassigned(result) => {
+                self.loading = false;
+                match result {
+                    Ok(report) => {
+                        self.selected_saved_searches.clear();
+                        let reassigned = report.count(ReassignStatus::Reassigned);
+                        let unchanged = report.count(ReassignStatus::Unchanged);
+                        let failed = report.failed_count();
+                        let summary = format!(
+                            "Reassigned {} saved search(es) to {} ({} unchanged, {} failed)",
+                            reassigned, report.to_owner, unchanged, failed
+                        );
+                        if failed > 0 {
+                            self.toasts.push(Toast::warning(summary));
+                        } else {
+                            self.toasts.push(Toast::success(summary));
+                        }
+                        self.saved_searches = None;
+                    }
+                    Err(e) => {
+                        self.toasts.push(Toast::error(format!(
+                            "Failed to reassign saved searches: {}",
+                            e
+                        )));
+                    }
+                }
+            }
             // Cluster management result actions
             Action::MaintenanceModeSet { result } => {
                 self.loading = false;
@@ -384,6 +467,23 @@ impl App {
                     }
                 }
             }
+            Action::LookupContentSaved(result) => {
+                self.loading = false;
+                match result {
+                    Ok(name) => {
+                        self.toasts
+                            .push(Toast::success(format!("Lookup '{}' saved", name)));
+                        if let Some(editor) = self.lookup_editor.as_mut() {
+                            editor.dirty = false;
+                            editor.confirm_discard = false;
+                        }
+                    }
+                    Err(e) => {
+                        self.toasts
+                            .push(Toast::error(format!("Failed to save lookup: {}", e)));
+                    }
+                }
+            }
             Action::ExportSuccess(path) => {
                 // Add to recent export paths, keeping most recent first and limiting size
                 const MAX_RECENT_EXPORTS: usize = 10;
@@ -439,11 +539,23 @@ impl App {
     }
 
     fn handle_copy_to_clipboard(&mut self, content: String) {
-        match clipboard::copy_to_clipboard(content.clone()) {
-            Ok(()) => {
+        match clipboard::copy_to_clipboard(content.clone(), self.clipboard_backend) {
+            Ok(clipboard::ClipboardTarget::Native) => {
                 let preview = Self::clipboard_preview(&content);
                 self.toasts.push(Toast::info(format!("Copied: {preview}")));
             }
+            Ok(clipboard::ClipboardTarget::Osc52) => {
+                let preview = Self::clipboard_preview(&content);
+                self.toasts.push(Toast::info(format!(
+                    "Copied via terminal (OSC52): {preview}"
+                )));
+            }
+            Ok(clipboard::ClipboardTarget::File(path)) => {
+                self.toasts.push(Toast::info(format!(
+                    "Clipboard unavailable; saved to {}",
+                    path.display()
+                )));
+            }
             Err(e) => {
                 let message = format!("Clipboard error: {e}");
                 self.push_error_toast_once(message);
@@ -471,11 +583,13 @@ impl App {
             SingleLineInput::with_value(self.search_filter.clone().unwrap_or_default());
     }
 
-    fn cycle_theme(&mut self) {
-        self.color_theme = self.color_theme.cycle_next();
-        self.theme = crate::theme::Theme::from(self.color_theme);
-        self.toasts
-            .push(Toast::info(format!("Theme: {}", self.color_theme)));
+    fn set_namespace(&mut self, namespace: Option<Namespace>) {
+        let message = match &namespace {
+            Some(namespace) => format!("Namespace: {}", namespace),
+            None => "Namespace: all apps and owners".to_string(),
+        };
+        self.namespace = namespace;
+        self.toasts.push(Toast::info(message));
     }
 
     fn handle_spl_validation_result(
@@ -569,8 +683,30 @@ impl App {
         );
     }
 
+    /// Show the onboarding run's outcome in the wizard, if it is still open.
+    fn handle_onboarding_wizard_finished(
+        &mut self,
+        result: Result<crate::wizard::OnboardingOutcome, crate::wizard::OnboardingFailure>,
+    ) {
+        self.loading = false;
+        match &result {
+            Ok(outcome) => self.toasts.push(Toast::success(format!(
+                "Index '{}' onboarded with HEC token '{}'",
+                outcome.index, outcome.token_name
+            ))),
+            Err(failure) => self.toasts.push(Toast::error(format!(
+                "Onboarding failed to {}: {}",
+                failure.stage, failure.error
+            ))),
+        }
+        if let Some(wizard) = self.onboarding_wizard.as_mut() {
+            wizard.step = crate::wizard::WizardStep::Finished;
+            wizard.result = Some(result);
+        }
+    }
+
     fn open_modify_index_dialog(&mut self, name: String) {
-        use crate::ui::popup::{Popup, PopupType};
+        use crate::ui::popup::{ModifyIndexField, Popup, PopupType};
         if let Some(indexes) = &self.indexes
             && let Some(index) = indexes.iter().find(|i| i.name == name)
         {
@@ -597,6 +733,13 @@ impl App {
                     new_cold_db_path: index.cold_db_path.clone(),
                     new_thawed_path: index.thawed_path.clone(),
                     new_cold_to_frozen_dir: index.cold_to_frozen_dir.clone(),
+                    selected_field: ModifyIndexField::MaxDataSize,
+                    freeze_projection: Some(index.project_freeze(
+                        index.max_total_data_size_mb,
+                        index.frozen_time_period_in_secs,
+                        chrono::Utc::now(),
+                    )),
+                    freeze_acknowledged: false,
                 })
                 .build(),
             );
@@ -722,6 +865,8 @@ impl App {
                     search_name: search.name.clone(),
                     search_input: String::new(),
                     description_input: String::new(),
+                    dispatch_earliest_input: String::new(),
+                    dispatch_latest_input: String::new(),
                     disabled: search.disabled,
                     selected_field: SavedSearchField::Search,
                 })
@@ -777,7 +922,7 @@ impl App {
 
     pub fn clamp_scroll_offsets(&mut self) {
         // Clamp search results scroll offset
-        let max_search_offset = self.search_results.len().saturating_sub(1);
+        let max_search_offset = self.visible_search_result_count().saturating_sub(1);
         self.search_scroll_offset = self.search_scroll_offset.min(max_search_offset);
 
         let filtered_jobs_len = self.filtered_jobs_len();
@@ -811,8 +956,9 @@ impl App {
             Self::clamp_list_selection(&mut self.roles_state, items.len());
         }
 
-        if let Some(items) = self.internal_logs.as_ref() {
-            Self::clamp_table_selection(&mut self.internal_logs_state, items.len());
+        if self.internal_logs.is_some() {
+            let rows = self.internal_log_row_count();
+            Self::clamp_table_selection(&mut self.internal_logs_state, rows);
         }
         if let Some(items) = self.cluster_peers.as_ref() {
             Self::clamp_table_selection(&mut self.cluster_peers_state, items.len());
@@ -826,6 +972,15 @@ impl App {
         if let Some(items) = self.forwarders.as_ref() {
             Self::clamp_table_selection(&mut self.forwarders_state, items.len());
         }
+        if let Some(ingestion) = self.ingestion_stats.as_ref() {
+            Self::clamp_table_selection(&mut self.ingestion_state, ingestion.stats.len());
+        }
+        if let Some(sourcetypes) = self.sourcetypes.as_ref() {
+            Self::clamp_table_selection(&mut self.sourcetypes_state, sourcetypes.len());
+        }
+        if let Some(entries) = self.metadata_entries.as_ref() {
+            Self::clamp_table_selection(&mut self.metadata_state, entries.len());
+        }
         if let Some(items) = self.lookups.as_ref() {
             Self::clamp_table_selection(&mut self.lookups_state, items.len());
         }
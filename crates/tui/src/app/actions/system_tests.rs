@@ -203,6 +203,20 @@ fn test_cycle_theme_changes_theme() {
     assert!(app.toasts[0].message.contains("Theme:"));
 }
 
+#[test]
+fn test_set_namespace_updates_state() {
+    let mut app = App::new(None, ConnectionContext::default());
+
+    app.handle_system_action(Action::SetNamespace(Some(Namespace::app("search"))));
+
+    assert_eq!(app.namespace, Some(Namespace::app("search")));
+    assert!(app.toasts[0].message.contains("-/search"));
+
+    app.handle_system_action(Action::SetNamespace(None));
+
+    assert!(app.namespace.is_none());
+}
+
 #[test]
 fn test_spl_validation_result_updates_state() {
     let mut app = App::new(None, ConnectionContext::default());
@@ -420,3 +434,30 @@ fn test_shc_captain_set_error_updates_loading_and_toast() {
             .any(|t| t.message.contains("Failed to set SHC captain"))
     );
 }
+
+#[test]
+fn test_onboarding_wizard_failure_shows_stage() {
+    let mut app = App::new(None, ConnectionContext::default());
+    app.handle_system_action(Action::OpenOnboardingWizard);
+    assert!(app.onboarding_wizard.is_some());
+    app.loading = true;
+
+    app.handle_system_action(Action::OnboardingWizardFinished(Err(
+        crate::wizard::OnboardingFailure {
+            stage: crate::wizard::OnboardingStage::Token,
+            error: std::sync::Arc::new(splunk_client::ClientError::InvalidResponse(
+                "HEC is disabled".to_string(),
+            )),
+        },
+    )));
+
+    assert!(!app.loading);
+    let wizard = app.onboarding_wizard.as_ref().unwrap();
+    assert_eq!(wizard.step, crate::wizard::WizardStep::Finished);
+    assert!(matches!(wizard.result, Some(Err(_))));
+    assert!(
+        app.toasts
+            .iter()
+            .any(|t| t.message.contains("Onboarding failed to create HEC token"))
+    );
+}
@@ -341,6 +341,9 @@ impl App {
                             session_expiry_buffer_seconds: None,
                             session_ttl_seconds: None,
                             health_check_interval_seconds: None,
+                            cloud: None,
+                            acs_stack: None,
+                            health_thresholds: None,
                         },
                         use_keyring: data.use_keyring,
                         original_name: None,
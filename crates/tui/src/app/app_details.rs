@@ -0,0 +1,132 @@
+//! App Details popup state: the knowledge objects an app ships, one tab per kind.
+//!
+//! Responsibilities:
+//! - Hold the app inventory loaded for the selected app
+//! - Track the active object-kind tab and the object selected in it
+//!
+//! Does NOT handle:
+//! - Does NOT fetch the inventory (handled by Action::LoadAppContents)
+//! - Does NOT handle popup keys (handled by app::popups::app_details)
+//! - Does NOT render the popup (handled by ui::app_details)
+//!
+//! Invariants:
+//! - `selected` stays in range for the active tab whenever it has objects
+
+use splunk_client::workflows::app_contents::{AppContents, AppObject, AppObjectKind};
+
+/// Inventory of one app, browsed by object kind.
+#[derive(Debug, Clone)]
+pub struct AppDetails {
+    /// Objects shipped by the app.
+    pub contents: AppContents,
+    /// Active tab.
+    pub tab: AppObjectKind,
+    /// Index of the selected object within the active tab.
+    pub selected: usize,
+}
+
+impl AppDetails {
+    /// Start on the first tab that has objects (or the first tab when the app ships none).
+    pub fn new(contents: AppContents) -> Self {
+        let tab = AppObjectKind::ALL
+            .into_iter()
+            .find(|kind| contents.count(*kind) > 0)
+            .unwrap_or(AppObjectKind::ALL[0]);
+        Self {
+            contents,
+            tab,
+            selected: 0,
+        }
+    }
+
+    /// Objects in the active tab.
+    pub fn objects(&self) -> Vec<&AppObject> {
+        self.contents.of_kind(self.tab).collect()
+    }
+
+    /// The object selected in the active tab.
+    pub fn selected_object(&self) -> Option<&AppObject> {
+        self.contents.of_kind(self.tab).nth(self.selected)
+    }
+
+    /// Switch to the next tab, wrapping around.
+    pub fn next_tab(&mut self) {
+        self.shift_tab(1);
+    }
+
+    /// Switch to the previous tab, wrapping around.
+    pub fn previous_tab(&mut self) {
+        self.shift_tab(AppObjectKind::ALL.len() - 1);
+    }
+
+    /// Select the next object, stopping at the last one.
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.contents.count(self.tab) {
+            self.selected += 1;
+        }
+    }
+
+    /// Select the previous object, stopping at the first one.
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    fn shift_tab(&mut self, by: usize) {
+        let index = AppObjectKind::ALL
+            .iter()
+            .position(|kind| *kind == self.tab)
+            .unwrap_or(0);
+        self.tab = AppObjectKind::ALL[(index + by) % AppObjectKind::ALL.len()];
+        self.selected = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(kind: AppObjectKind, name: &str) -> AppObject {
+        AppObject {
+            kind,
+            name: name.to_string(),
+            detail: String::new(),
+            disabled: false,
+        }
+    }
+
+    fn details() -> AppDetails {
+        AppDetails::new(AppContents {
+            app: "my_app".to_string(),
+            objects: vec![
+                object(AppObjectKind::Dashboard, "overview"),
+                object(AppObjectKind::Macro, "a"),
+                object(AppObjectKind::Macro, "b"),
+            ],
+        })
+    }
+
+    #[test]
+    fn test_starts_on_first_non_empty_tab() {
+        let details = details();
+        assert_eq!(details.tab, AppObjectKind::Dashboard);
+        assert_eq!(details.selected_object().unwrap().name, "overview");
+    }
+
+    #[test]
+    fn test_tabs_wrap_and_reset_selection() {
+        let mut details = details();
+        details.next_tab();
+        details.next_tab();
+        assert_eq!(details.tab, AppObjectKind::Macro);
+        details.select_next();
+        details.select_next();
+        assert_eq!(details.selected_object().unwrap().name, "b");
+
+        details.next_tab();
+        details.next_tab();
+        assert_eq!(details.tab, AppObjectKind::SavedSearch);
+        assert_eq!(details.selected, 0);
+        details.previous_tab();
+        assert_eq!(details.tab, AppObjectKind::EventType);
+    }
+}
@@ -0,0 +1,265 @@
+//! Registry of searches and batch operations shown in the jobs tray.
+//!
+//! Responsibilities:
+//! - Track searches and batch operations from start to finish, independent of
+//!   the screen the user is on
+//! - Keep recently finished jobs for the jobs tray, newest first
+//! - Track the job selected in the tray and the finished search a completion
+//!   toast offers to show
+//!
+//! Does NOT handle:
+//! - Does NOT run or cancel the work (handled by the search and operation side effects)
+//! - Does NOT handle popup keys (handled by app::popups::background_jobs)
+//! - Does NOT render the tray (handled by ui::background_jobs)
+//!
+//! Invariants:
+//! - At most `MAX_FINISHED_JOBS` finished jobs are kept; running jobs are never dropped
+//! - At most one search is running; starting another marks the previous one superseded
+//! - `selected` stays in range whenever `jobs` is non-empty
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, Instant};
+
+use uuid::Uuid;
+
+/// Finished jobs kept for the tray.
+pub const MAX_FINISHED_JOBS: usize = 20;
+
+/// What a background job is running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackgroundJobKind {
+    Search,
+    /// Batch operation with the id of its `OperationProgress`.
+    Operation {
+        id: u64,
+    },
+}
+
+/// Where a background job is in its lifecycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackgroundJobStatus {
+    Running,
+    /// Finished, with a short result summary (e.g. "12,345 results").
+    Finished(String),
+    /// Failed, with the error message.
+    Failed(String),
+    /// Cancelled or superseded, with what was done before it stopped.
+    Cancelled(String),
+}
+
+/// One search or batch operation.
+#[derive(Debug, Clone)]
+pub struct BackgroundJob {
+    pub kind: BackgroundJobKind,
+    /// Short name shown in toasts and the tray (e.g. "Search 1a2b3c4").
+    pub label: String,
+    /// Longer description, such as the search query.
+    pub detail: Option<String>,
+    pub started_at: Instant,
+    pub finished_at: Option<Instant>,
+    pub status: BackgroundJobStatus,
+}
+
+impl BackgroundJob {
+    pub fn is_running(&self) -> bool {
+        self.status == BackgroundJobStatus::Running
+    }
+
+    /// Run time so far, or the total run time once finished.
+    pub fn elapsed(&self) -> Duration {
+        match self.finished_at {
+            Some(finished_at) => finished_at.duration_since(self.started_at),
+            None => self.started_at.elapsed(),
+        }
+    }
+}
+
+/// Searches and batch operations, newest first.
+#[derive(Debug, Clone, Default)]
+pub struct BackgroundJobs {
+    jobs: Vec<BackgroundJob>,
+    /// Index of the job selected in the tray.
+    pub selected: usize,
+    /// Toast announcing a finished search; `g` shows the results while it is visible.
+    pub view_toast: Option<Uuid>,
+}
+
+impl BackgroundJobs {
+    /// Jobs, newest first.
+    pub fn jobs(&self) -> &[BackgroundJob] {
+        &self.jobs
+    }
+
+    pub fn running_count(&self) -> usize {
+        self.jobs.iter().filter(|job| job.is_running()).count()
+    }
+
+    /// The job selected in the tray.
+    pub fn selected_job(&self) -> Option<&BackgroundJob> {
+        self.jobs.get(self.selected)
+    }
+
+    /// Select the next job, stopping at the last one.
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.jobs.len() {
+            self.selected += 1;
+        }
+    }
+
+    /// Select the previous job, stopping at the first one.
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Record a search that just started.
+    pub fn start_search(&mut self, query: &str) {
+        self.finish(
+            BackgroundJobKind::Search,
+            BackgroundJobStatus::Cancelled("superseded by a newer search".to_string()),
+        );
+        self.view_toast = None;
+        self.start(
+            BackgroundJobKind::Search,
+            format!("Search {}", short_hash(query)),
+            Some(query.to_string()),
+        );
+    }
+
+    /// Record a batch operation that just started.
+    pub fn start_operation(&mut self, id: u64, label: &str) {
+        self.start(BackgroundJobKind::Operation { id }, label.to_string(), None);
+    }
+
+    /// Mark the running job of `kind` finished with `status`.
+    ///
+    /// Returns the finished job, or `None` when no job of that kind is running.
+    pub fn finish(
+        &mut self,
+        kind: BackgroundJobKind,
+        status: BackgroundJobStatus,
+    ) -> Option<BackgroundJob> {
+        let job = self
+            .jobs
+            .iter_mut()
+            .find(|job| job.kind == kind && job.is_running())?;
+        job.status = status;
+        job.finished_at = Some(Instant::now());
+        let job = job.clone();
+        self.prune();
+        Some(job)
+    }
+
+    fn start(&mut self, kind: BackgroundJobKind, label: String, detail: Option<String>) {
+        self.jobs.insert(
+            0,
+            BackgroundJob {
+                kind,
+                label,
+                detail,
+                started_at: Instant::now(),
+                finished_at: None,
+                status: BackgroundJobStatus::Running,
+            },
+        );
+        self.prune();
+    }
+
+    /// Drop the oldest finished jobs beyond `MAX_FINISHED_JOBS`.
+    fn prune(&mut self) {
+        let mut finished = 0;
+        self.jobs.retain(|job| {
+            if job.is_running() {
+                return true;
+            }
+            finished += 1;
+            finished <= MAX_FINISHED_JOBS
+        });
+        self.selected = self.selected.min(self.jobs.len().saturating_sub(1));
+    }
+}
+
+/// Seven hex digits identifying a search query in toasts and the tray.
+fn short_hash(query: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    query.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())[..7].to_string()
+}
+
+/// Format a count with thousands separators (`12345` becomes `12,345`).
+pub fn format_count(count: usize) -> String {
+    let digits = count.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, ch) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(ch);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_lifecycle_and_supersede() {
+        let mut jobs = BackgroundJobs::default();
+        jobs.start_search("index=main");
+        jobs.start_search("index=web");
+        assert_eq!(jobs.running_count(), 1);
+        assert_eq!(
+            jobs.jobs()[1].status,
+            BackgroundJobStatus::Cancelled("superseded by a newer search".to_string())
+        );
+
+        let job = jobs
+            .finish(
+                BackgroundJobKind::Search,
+                BackgroundJobStatus::Finished("3 results".to_string()),
+            )
+            .unwrap();
+        assert_eq!(job.detail.as_deref(), Some("index=web"));
+        assert!(job.label.starts_with("Search "));
+        assert_eq!(job.label.len(), "Search ".len() + 7);
+        assert_eq!(jobs.running_count(), 0);
+        assert!(
+            jobs.finish(
+                BackgroundJobKind::Search,
+                BackgroundJobStatus::Failed("boom".to_string())
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_prune_keeps_running_jobs() {
+        let mut jobs = BackgroundJobs::default();
+        jobs.start_operation(1, "Deleting 2 job(s)");
+        for i in 0..MAX_FINISHED_JOBS + 5 {
+            jobs.start_search(&format!("index=main | head {i}"));
+        }
+        jobs.selected = jobs.jobs().len() - 1;
+        jobs.finish(
+            BackgroundJobKind::Search,
+            BackgroundJobStatus::Finished("0 results".to_string()),
+        );
+
+        assert_eq!(jobs.jobs().len(), MAX_FINISHED_JOBS + 1);
+        assert!(
+            jobs.jobs()
+                .iter()
+                .any(|job| job.kind == BackgroundJobKind::Operation { id: 1 } && job.is_running())
+        );
+        assert!(jobs.selected < jobs.jobs().len());
+    }
+
+    #[test]
+    fn test_format_count() {
+        assert_eq!(format_count(0), "0");
+        assert_eq!(format_count(999), "999");
+        assert_eq!(format_count(12345), "12,345");
+        assert_eq!(format_count(1234567), "1,234,567");
+    }
+}
@@ -1,7 +1,9 @@
 //! Clipboard integration for the TUI App module.
 //!
 //! Responsibilities:
-//! - Provide `copy_to_clipboard(String) -> Result<(), String>` backed by `arboard`.
+//! - Provide `copy_to_clipboard` for the selected `ClipboardBackend`: the OS clipboard
+//!   (`arboard`), an OSC52 escape sequence for remote terminals and tmux, or a temp file.
+//! - Fall back native → OSC52 → temp file for `ClipboardBackend::Auto`.
 //! - Provide a test-only (but always compiled) per-thread override backend so
 //!   integration tests do not depend on the host OS clipboard.
 //!
@@ -12,10 +14,17 @@
 //! Invariants / assumptions:
 //! - Called from the main UI threads (typical TUI event loop).
 //! - If the OS clipboard is unavailable, this module returns an error instead of panicking.
+//! - OSC52 cannot confirm delivery: it only fails when stdout is not a terminal or the
+//!   payload exceeds `OSC52_MAX_ENCODED_LEN`, so terminals that ignore it fail silently.
 
 use std::cell::RefCell;
+use std::io::{IsTerminal, Write};
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
+use base64::{Engine, prelude::BASE64_STANDARD};
+use splunk_config::ClipboardBackend;
+
 /// Thread-local clipboard backend override (used by tests to avoid OS clipboard dependencies).
 #[derive(Clone)]
 enum OverrideBackend {
@@ -27,34 +36,135 @@ thread_local! {
     static OVERRIDE_BACKEND: RefCell<Option<OverrideBackend>> = const { RefCell::new(None) };
 }
 
-/// Copy the given content to the system clipboard.
+/// Largest base64 payload sent over OSC52; many terminals drop longer sequences.
+pub const OSC52_MAX_ENCODED_LEN: usize = 100_000;
+
+/// File name used by the temp-file backend, inside `std::env::temp_dir()`.
+pub const CLIPBOARD_FILE_NAME: &str = "splunk-tui-clipboard.txt";
+
+/// Where copied text ended up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClipboardTarget {
+    /// The OS clipboard
+    Native,
+    /// The terminal's clipboard, via an OSC52 escape sequence
+    Osc52,
+    /// A temp file, for when no clipboard is reachable
+    File(PathBuf),
+}
+
+/// Copy the given content using the selected backend.
 ///
-/// Returns an error string if clipboard initialization or writes fail.
+/// `Auto` tries the OS clipboard, then OSC52, then the temp file, and returns
+/// the first target that accepted the text.
+///
+/// Returns an error string listing each failure if no backend succeeds.
 ///
 /// Note: In tests, a per-thread override backend may be installed to make the
 /// behavior deterministic.
-pub fn copy_to_clipboard(content: String) -> Result<(), String> {
-    OVERRIDE_BACKEND.with(|cell| {
-        if let Some(backend) = cell.borrow().as_ref() {
-            match backend {
-                OverrideBackend::Recording(store) => {
-                    let mut guard = store
-                        .lock()
-                        .map_err(|_| "Clipboard test backend lock poisoned".to_string())?;
-                    *guard = Some(content);
-                    return Ok(());
-                }
-                OverrideBackend::Failing(msg) => return Err(msg.clone()),
+pub fn copy_to_clipboard(
+    content: String,
+    backend: ClipboardBackend,
+) -> Result<ClipboardTarget, String> {
+    let overridden = OVERRIDE_BACKEND.with(|cell| {
+        cell.borrow().as_ref().map(|backend| match backend {
+            OverrideBackend::Recording(store) => {
+                let mut guard = store
+                    .lock()
+                    .map_err(|_| "Clipboard test backend lock poisoned".to_string())?;
+                *guard = Some(content.clone());
+                Ok(ClipboardTarget::Native)
+            }
+            OverrideBackend::Failing(msg) => Err(msg.clone()),
+        })
+    });
+    if let Some(result) = overridden {
+        return result;
+    }
+
+    let mut errors = Vec::new();
+    for method in fallback_order(backend) {
+        let result = match method {
+            ClipboardBackend::Native => copy_native(&content),
+            ClipboardBackend::Osc52 => copy_osc52(&content),
+            ClipboardBackend::File => {
+                copy_to_file(&content, &std::env::temp_dir().join(CLIPBOARD_FILE_NAME))
             }
+            ClipboardBackend::Auto => unreachable!("Auto is expanded by fallback_order"),
+        };
+        match result {
+            Ok(target) => return Ok(target),
+            Err(e) => errors.push(e),
         }
+    }
+    Err(errors.join("; "))
+}
+
+/// Backends to try, in order, for the selected backend.
+pub fn fallback_order(backend: ClipboardBackend) -> &'static [ClipboardBackend] {
+    match backend {
+        ClipboardBackend::Auto => &[
+            ClipboardBackend::Native,
+            ClipboardBackend::Osc52,
+            ClipboardBackend::File,
+        ],
+        ClipboardBackend::Native => &[ClipboardBackend::Native],
+        ClipboardBackend::Osc52 => &[ClipboardBackend::Osc52],
+        ClipboardBackend::File => &[ClipboardBackend::File],
+    }
+}
+
+fn copy_native(content: &str) -> Result<ClipboardTarget, String> {
+    let mut clipboard =
+        arboard::Clipboard::new().map_err(|e| format!("Clipboard unavailable: {e}"))?;
+    clipboard
+        .set_text(content)
+        .map_err(|e| format!("Failed to write to clipboard: {e}"))?;
+    Ok(ClipboardTarget::Native)
+}
+
+fn copy_osc52(content: &str) -> Result<ClipboardTarget, String> {
+    let mut stdout = std::io::stdout();
+    if !stdout.is_terminal() {
+        return Err("OSC52 unavailable: stdout is not a terminal".to_string());
+    }
+    let in_tmux = std::env::var_os("TMUX").is_some();
+    let sequence = osc52_sequence(content, in_tmux)?;
+    stdout
+        .write_all(sequence.as_bytes())
+        .and_then(|()| stdout.flush())
+        .map_err(|e| format!("Failed to write OSC52 sequence: {e}"))?;
+    Ok(ClipboardTarget::Osc52)
+}
 
-        let mut clipboard =
-            arboard::Clipboard::new().map_err(|e| format!("Clipboard unavailable: {e}"))?;
-        clipboard
-            .set_text(content)
-            .map_err(|e| format!("Failed to write to clipboard: {e}"))?;
-        Ok(())
-    })
+/// Build the OSC52 "set clipboard" sequence for `content`.
+///
+/// Inside tmux the sequence is wrapped in a DCS passthrough so tmux forwards it
+/// to the outer terminal (requires `set -g allow-passthrough on` on tmux 3.3+).
+///
+/// Returns an error if the encoded payload exceeds `OSC52_MAX_ENCODED_LEN`.
+pub fn osc52_sequence(content: &str, in_tmux: bool) -> Result<String, String> {
+    let encoded = BASE64_STANDARD.encode(content);
+    if encoded.len() > OSC52_MAX_ENCODED_LEN {
+        return Err(format!(
+            "OSC52 unavailable: {} bytes exceeds the {} byte limit",
+            encoded.len(),
+            OSC52_MAX_ENCODED_LEN
+        ));
+    }
+    let sequence = format!("\x1b]52;c;{encoded}\x07");
+    if in_tmux {
+        Ok(format!("\x1bPtmux;\x1b{sequence}\x1b\\"))
+    } else {
+        Ok(sequence)
+    }
+}
+
+/// Write `content` to `path`, replacing any earlier copy.
+pub fn copy_to_file(content: &str, path: &Path) -> Result<ClipboardTarget, String> {
+    std::fs::write(path, content)
+        .map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+    Ok(ClipboardTarget::File(path.to_path_buf()))
 }
 
 /// Installs a per-thread recording clipboard backend and returns a guard.
@@ -127,3 +237,58 @@ impl Drop for FailingClipboardGuard {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_osc52_sequence_encodes_content() {
+        assert_eq!(
+            osc52_sequence("index=main", false).unwrap(),
+            "\x1b]52;c;aW5kZXg9bWFpbg==\x07"
+        );
+    }
+
+    #[test]
+    fn test_osc52_sequence_wraps_for_tmux() {
+        assert_eq!(
+            osc52_sequence("sid", true).unwrap(),
+            "\x1bPtmux;\x1b\x1b]52;c;c2lk\x07\x1b\\"
+        );
+    }
+
+    #[test]
+    fn test_osc52_sequence_rejects_oversized_payload() {
+        let content = "x".repeat(OSC52_MAX_ENCODED_LEN);
+        assert!(osc52_sequence(&content, false).is_err());
+    }
+
+    #[test]
+    fn test_auto_falls_back_native_then_osc52_then_file() {
+        assert_eq!(
+            fallback_order(ClipboardBackend::Auto),
+            [
+                ClipboardBackend::Native,
+                ClipboardBackend::Osc52,
+                ClipboardBackend::File
+            ]
+        );
+        assert_eq!(
+            fallback_order(ClipboardBackend::Osc52),
+            [ClipboardBackend::Osc52]
+        );
+    }
+
+    #[test]
+    fn test_copy_to_file_overwrites_previous_copy() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(CLIPBOARD_FILE_NAME);
+
+        copy_to_file("first", &path).unwrap();
+        let target = copy_to_file("second", &path).unwrap();
+
+        assert_eq!(target, ClipboardTarget::File(path.clone()));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "second");
+    }
+}
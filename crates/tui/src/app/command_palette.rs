@@ -13,6 +13,7 @@
 use crate::action::Action;
 use crate::app::CurrentScreen;
 use crate::input::keymap::{BindingScope, keybindings};
+use splunk_client::models::MetadataType;
 
 /// Maximum number of recent commands to track
 const MAX_RECENT_COMMANDS: usize = 10;
@@ -255,6 +256,10 @@ fn screen_to_bit(screen: CurrentScreen) -> u32 {
         CurrentScreen::DataModels => 1 << 25,
         CurrentScreen::WorkloadManagement => 1 << 26,
         CurrentScreen::Shc => 1 << 27,
+        CurrentScreen::Ingestion => 1 << 28,
+        CurrentScreen::Sourcetypes => 1 << 29,
+        CurrentScreen::Metadata => 1 << 30,
+        CurrentScreen::Rest => 1 << 31,
     }
 }
 
@@ -406,6 +411,26 @@ fn build_screen_navigation_commands() -> Vec<CommandPaletteItem> {
                 offset: 0,
             },
         ),
+        (
+            Ingestion,
+            "Go to Ingestion",
+            "View indexing latency and thruput",
+            Action::LoadIngestionStats,
+        ),
+        (
+            Sourcetypes,
+            "Go to Sourcetypes",
+            "View sourcetype inventory with first/last seen",
+            Action::LoadSourcetypes,
+        ),
+        (
+            Metadata,
+            "Go to Hosts & Sources",
+            "View hosts and sources with last event times and stale highlighting",
+            Action::LoadMetadata {
+                metadata_type: MetadataType::Hosts,
+            },
+        ),
         (
             Configs,
             "Go to Configs",
@@ -478,6 +503,12 @@ fn build_screen_navigation_commands() -> Vec<CommandPaletteItem> {
             "View search head cluster",
             Action::LoadShcStatus,
         ),
+        (
+            Rest,
+            "Go to REST",
+            "Send raw requests to any REST endpoint",
+            Action::SwitchToScreen(Rest),
+        ),
         (
             Settings,
             "Go to Settings",
@@ -671,6 +702,10 @@ mod tests {
             CurrentScreen::DataModels,
             CurrentScreen::WorkloadManagement,
             CurrentScreen::Shc,
+            CurrentScreen::Rest,
+            CurrentScreen::Ingestion,
+            CurrentScreen::Sourcetypes,
+            CurrentScreen::Metadata,
         ];
 
         let mut bits = std::collections::HashSet::new();
@@ -11,6 +11,7 @@
 //! - Does NOT render (see render.rs)
 
 use crate::app::input::components::SingleLineInput;
+use crate::app::result_stats::ResultStats;
 use crate::app::state::{
     ClusterViewMode, CurrentScreen, ListPaginationState, SearchInputMode, SortState,
 };
@@ -55,6 +56,33 @@ impl App {
     /// * `persisted` - Optional persisted state from previous runs
     /// * `connection_ctx` - Connection context (profile, base_url, auth_mode)
     pub fn new(persisted: Option<PersistedState>, connection_ctx: ConnectionContext) -> Self {
+        let result_renderers = persisted
+            .as_ref()
+            .is_none_or(|state| state.result_renderers);
+        let saved_sessions = persisted
+            .as_ref()
+            .map(|state| state.saved_sessions.clone())
+            .unwrap_or_default();
+        let rest_history = persisted
+            .as_ref()
+            .map(|state| state.rest_history.clone())
+            .unwrap_or_default();
+        let custom_theme = persisted
+            .as_ref()
+            .and_then(|state| state.custom_theme.clone());
+        let no_color = persisted.as_ref().is_some_and(|state| state.no_color);
+        let clipboard_backend = persisted
+            .as_ref()
+            .map(|state| state.clipboard_backend)
+            .unwrap_or_default();
+        let notifications = persisted
+            .as_ref()
+            .map(|state| state.notifications.clone())
+            .unwrap_or_default();
+        let health_thresholds = persisted
+            .as_ref()
+            .map(|state| state.health_thresholds.clone())
+            .unwrap_or_default();
         let (
             auto_refresh,
             sort_column,
@@ -122,6 +150,7 @@ impl App {
             search_results: Vec::new(),
             search_scroll_offset: scroll_positions.search_scroll_offset,
             search_sid: None,
+            search_job: None,
             search_results_total_count: None,
             // Use search_defaults.max_results as the source of truth for pagination page size.
             // This ensures the UI's pagination assumptions match the actual API request page size.
@@ -132,29 +161,63 @@ impl App {
                 search_defaults.max_results
             },
             search_has_more_results: false,
+            search_result_stats: ResultStats::default(),
+            search_chart_view: false,
+            search_chart: None,
+            search_baseline: Vec::new(),
+            search_compare_view: false,
+            search_compare: None,
+            search_started_at: None,
+            result_filter: None,
+            is_result_filtering: false,
+            result_filter_input: SingleLineInput::new(),
+            result_filter_before_edit: None,
+            result_detail: None,
+            result_detail_scroll_offset: 0,
+            result_detail_selected_field: 0,
+            result_renderers,
+            last_search_scope: None,
+            top_values: None,
+            server_messages: Default::default(),
             indexes: None,
             indexes_state: selected_list_state(),
             jobs: None,
             jobs_state: selected_table_state(),
+            job_inspect_tab: crate::app::state::JobInspectTab::Details,
+            job_performance: None,
             saved_searches: None,
             saved_searches_state: selected_list_state(),
             macros: None,
             macros_state: selected_list_state(),
             internal_logs: None,
             internal_logs_state: selected_table_state(),
+            internal_log_anomalies: Default::default(),
+            internal_logs_follow: Default::default(),
+            internal_logs_component_input: None,
             cluster_info: None,
             cluster_peers: None,
             cluster_peers_state: selected_table_state(),
             cluster_view_mode: ClusterViewMode::Summary,
+            cluster_bucket_health: None,
+            cluster_index_compliance: None,
+            cluster_versions: None,
             health_info: None,
+            health_view_mode: crate::app::state::HealthViewMode::Overview,
+            resource_usage: None,
             license_info: None,
             kvstore_status: None,
             apps: None,
             apps_state: selected_list_state(),
+            app_details: None,
             users: None,
             users_state: selected_list_state(),
+            orphan_report: None,
             roles: None,
             roles_state: selected_list_state(),
+            roles_view_mode: crate::app::state::RolesViewMode::List,
+            role_usage: None,
+            role_matrix: None,
+            role_matrix_state: ratatui::widgets::TableState::default().with_selected(Some(0)),
             capabilities: None,
             search_peers: None,
             search_peers_state: selected_table_state(),
@@ -162,6 +225,23 @@ impl App {
             inputs: None,
             inputs_state: selected_table_state(),
             inputs_pagination: default_pagination(),
+            input_throughput: None,
+            inputs_view_mode: crate::app::state::InputsViewMode::Inputs,
+            hec_tokens: None,
+            hec_tokens_state: selected_table_state(),
+            revealed_hec_token: None,
+            ingestion_stats: None,
+            ingestion_state: selected_table_state(),
+            ingestion_sort: Default::default(),
+            sourcetypes: None,
+            sourcetypes_state: selected_table_state(),
+            metadata_entries: None,
+            metadata_state: selected_table_state(),
+            metadata_type: splunk_client::models::MetadataType::Hosts,
+            rest_history,
+            rest_history_state: selected_list_state(),
+            rest_response: None,
+            rest_response_scroll: 0,
             overview_data: None,
             multi_instance_data: None,
             multi_instance_selected_index: 0,
@@ -174,14 +254,21 @@ impl App {
             lookups: None,
             lookups_state: selected_table_state(),
             lookups_pagination: default_pagination(),
+            lookup_editor: None,
             audit_events: None,
             audit_state: selected_table_state(),
+            audit_time_range: Default::default(),
             dashboards: None,
             dashboards_state: selected_list_state(),
             dashboards_pagination: default_pagination(),
+            macro_expansion: None,
+            macro_expansion_scroll_offset: 0,
+            dashboard_source: None,
+            dashboard_source_scroll_offset: 0,
             data_models: None,
             data_models_state: selected_list_state(),
             data_models_pagination: default_pagination(),
+            tstats_builder: None,
             workload_pools: None,
             workload_pools_state: selected_table_state(),
             workload_pools_pagination: default_pagination(),
@@ -203,6 +290,9 @@ impl App {
             config_stanzas_state: selected_table_state(),
             selected_stanza: None,
             config_view_mode: crate::ui::screens::configs::ConfigViewMode::FileList,
+            config_layers: None,
+            config_detail_view: crate::ui::screens::configs::ConfigDetailView::Settings,
+            config_diff_app: None,
             config_search_mode: false,
             config_search_query: SingleLineInput::new(),
             config_search_before_edit: None,
@@ -214,7 +304,19 @@ impl App {
             auto_refresh,
             popup: None,
             color_theme,
-            theme: Theme::from(color_theme),
+            theme: Theme {
+                no_color,
+                ..Theme::from(color_theme)
+            },
+            no_color,
+            no_color_forced: false,
+            color_support: crate::theme::ColorSupport::Indexed,
+            custom_themes: Vec::new(),
+            custom_theme,
+            clipboard_backend,
+            notifications,
+            health_thresholds,
+            profile_health_thresholds: connection_ctx.health_thresholds.clone(),
             search_filter: None,
             is_filtering: false,
             filter_input: SingleLineInput::new(),
@@ -225,10 +327,19 @@ impl App {
                 direction: sort_direction,
             },
             selected_jobs: std::collections::HashSet::new(),
+            selected_saved_searches: std::collections::HashSet::new(),
+            jobs_server_filter: splunk_client::JobFilter::default(),
             health_state: crate::app::state::HealthState::Unknown,
             search_history,
             history_index: None,
             saved_search_input: SingleLineInput::new(),
+            search_templates: Vec::new(),
+            search_template_index: None,
+            template_form: None,
+            onboarding_wizard: None,
+            event_sample: None,
+            time_range_picker: None,
+            extraction_tester: None,
             search_defaults,
             keybind_overrides,
             list_defaults: list_defaults.clone(),
@@ -263,11 +374,19 @@ impl App {
             help_scroll_offset: scroll_positions.help_scroll_offset,
             spinner_frame: 0,
             last_area: ratatui::layout::Rect::default(),
+            hit_areas: crate::app::hit_areas::HitAreas::default(),
             profile_name: connection_ctx.profile_name,
             base_url: Some(connection_ctx.base_url),
             auth_mode: Some(connection_ctx.auth_mode),
             server_version: None,
             server_build: None,
+            server_restart_required: false,
+            server_restarting: false,
+            namespace: None,
+            session_expires_at: None,
+            session_expired: false,
+            offline_snapshots: Default::default(),
+            current_context: None,
             search_input_mode: SearchInputMode::QueryFocused,
             spl_validation_state: SplValidationState::default(),
             spl_validation_pending: false,
@@ -275,16 +394,21 @@ impl App {
             validation_request_id: 0,
             search_mode: SearchMode::Normal,
             realtime_window: None,
+            auto_finalize: false,
             focus_manager: FocusManager::default(),
             focus_navigation_mode: false,
             tutorial_state: None,
             tutorial_completed,
             onboarding_checklist,
             onboarding_checklist_enabled: true,
+            saved_sessions,
+            pending_search_scroll: None,
             command_palette_state: crate::app::command_palette::CommandPaletteState::new(),
             // Undo/Redo system
             undo_buffer: crate::undo::UndoBuffer::new(),
             undo_toast_id: None,
+            operation_progress: None,
+            background_jobs: Default::default(),
             // UX telemetry - initialized to None, set from main.rs
             ux_telemetry: None,
         }
@@ -326,6 +450,8 @@ impl App {
                     .unwrap_or_default()
                     .as_secs(),
             ),
+            result_renderers: self.result_renderers,
+            no_color: self.no_color,
             onboarding_checklist: splunk_config::PersistedOnboardingChecklist {
                 milestones: self.onboarding_checklist.milestones.bits(),
                 dismissed_items: self
@@ -338,9 +464,22 @@ impl App {
                 sessions_since_completion: self.onboarding_checklist.sessions_since_completion,
                 globally_dismissed: self.onboarding_checklist.globally_dismissed,
             },
+            saved_sessions: self.saved_sessions.clone(),
+            custom_theme: self.custom_theme.clone(),
+            clipboard_backend: self.clipboard_backend,
+            rest_history: self.rest_history.clone(),
+            notifications: self.notifications.clone(),
+            health_thresholds: self.health_thresholds.clone(),
         }
     }
 
+    /// Health thresholds in effect: the active profile's, else the Settings ones.
+    pub fn effective_health_thresholds(&self) -> &splunk_config::HealthThresholds {
+        self.profile_health_thresholds
+            .as_ref()
+            .unwrap_or(&self.health_thresholds)
+    }
+
     /// Called at session start to track sessions and auto-hide behavior.
     pub fn on_session_start(&mut self) {
         self.onboarding_checklist.on_session_start();
@@ -0,0 +1,61 @@
+//! Raw event sample viewer for an index.
+//!
+//! Responsibilities:
+//! - Hold the sampled events of one index, the selected event, and the wrap toggle
+//!
+//! Does NOT handle:
+//! - Does NOT fetch events (handled by Action::LoadIndexSample)
+//! - Does NOT handle popup keys (handled by app::popups::event_sample)
+//! - Does NOT render the popup (handled by ui::event_sample)
+//!
+//! Invariants:
+//! - `events` is never empty and `selected` stays in range
+
+use splunk_client::workflows::event_sample::SampleEvent;
+
+/// Sampled events shown in the viewer popup.
+#[derive(Debug, Clone)]
+pub struct EventSampleView {
+    pub index: String,
+    pub events: Vec<SampleEvent>,
+    pub selected: usize,
+    /// Wrap long lines instead of cutting them at the popup edge.
+    pub wrap: bool,
+}
+
+impl EventSampleView {
+    /// Start a viewer, or `None` when the sample is empty.
+    pub fn new(index: String, events: Vec<SampleEvent>) -> Option<Self> {
+        if events.is_empty() {
+            return None;
+        }
+        Some(Self {
+            index,
+            events,
+            selected: 0,
+            wrap: true,
+        })
+    }
+
+    pub fn selected_event(&self) -> &SampleEvent {
+        &self.events[self.selected]
+    }
+
+    pub fn next(&mut self) {
+        if self.selected + 1 < self.events.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn first(&mut self) {
+        self.selected = 0;
+    }
+
+    pub fn last(&mut self) {
+        self.selected = self.events.len() - 1;
+    }
+}
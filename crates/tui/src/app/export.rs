@@ -21,6 +21,7 @@ pub enum ExportTarget {
     Indexes,
     Users,
     Roles,
+    RoleMatrix,
     Apps,
     SavedSearches,
     Macros,
@@ -28,6 +29,7 @@ pub enum ExportTarget {
     Jobs,
     Health,
     License,
+    LicenseTrend,
     Kvstore,
     InternalLogs,
     Overview,
@@ -40,6 +42,7 @@ pub enum ExportTarget {
     AuditEvents,
     Workload,
     ShcStatus,
+    Dashboard,
 }
 
 impl ExportTarget {
@@ -49,6 +52,7 @@ impl ExportTarget {
             ExportTarget::Indexes => "Export Indexes",
             ExportTarget::Users => "Export Users",
             ExportTarget::Roles => "Export Roles",
+            ExportTarget::RoleMatrix => "Export Role Capability Matrix",
             ExportTarget::Apps => "Export Apps",
             ExportTarget::SavedSearches => "Export Saved Searches",
             ExportTarget::Macros => "Export Macros",
@@ -56,6 +60,7 @@ impl ExportTarget {
             ExportTarget::Jobs => "Export Jobs",
             ExportTarget::Health => "Export Health",
             ExportTarget::License => "Export License",
+            ExportTarget::LicenseTrend => "Export License Usage Trend",
             ExportTarget::Kvstore => "Export KVStore",
             ExportTarget::InternalLogs => "Export Internal Logs",
             ExportTarget::Overview => "Export Overview",
@@ -68,6 +73,7 @@ impl ExportTarget {
             ExportTarget::AuditEvents => "Export Audit Events",
             ExportTarget::Workload => "Export Workload Management",
             ExportTarget::ShcStatus => "Export SHC Status",
+            ExportTarget::Dashboard => "Export Dashboard Source",
         }
     }
 
@@ -77,6 +83,7 @@ impl ExportTarget {
             ExportTarget::Indexes => "indexes",
             ExportTarget::Users => "users",
             ExportTarget::Roles => "roles",
+            ExportTarget::RoleMatrix => "role-capability-matrix",
             ExportTarget::Apps => "apps",
             ExportTarget::SavedSearches => "saved-searches",
             ExportTarget::Macros => "macros",
@@ -84,6 +91,7 @@ impl ExportTarget {
             ExportTarget::Jobs => "jobs",
             ExportTarget::Health => "health",
             ExportTarget::License => "license",
+            ExportTarget::LicenseTrend => "license-usage-trend",
             ExportTarget::Kvstore => "kvstore",
             ExportTarget::InternalLogs => "internal-logs",
             ExportTarget::Overview => "overview",
@@ -96,6 +104,7 @@ impl ExportTarget {
             ExportTarget::AuditEvents => "audit-events",
             ExportTarget::Workload => "workload",
             ExportTarget::ShcStatus => "shc-status",
+            ExportTarget::Dashboard => "dashboard",
         };
 
         let ext = match format {
@@ -150,6 +159,10 @@ impl App {
                     serde_json::to_value(v).map_err(|e| format!("Failed to serialize roles: {}", e))
                 })
                 .transpose(),
+            ExportTarget::RoleMatrix => Ok(self
+                .role_matrix
+                .as_ref()
+                .map(|matrix| serde_json::Value::Array(matrix.to_rows()))),
             ExportTarget::Apps => self
                 .apps
                 .as_ref()
@@ -204,6 +217,22 @@ impl App {
                         .map_err(|e| format!("Failed to serialize license: {}", e))
                 })
                 .transpose(),
+            ExportTarget::LicenseTrend => Ok(self.license_info.as_ref().map(|info| {
+                serde_json::Value::Array(
+                    info.history
+                        .iter()
+                        .map(|day| {
+                            serde_json::json!({
+                                "day": day.day,
+                                "used_gb": day.used_gb(),
+                                "quota_gb": day.quota_gb(),
+                                "used_bytes": day.used_bytes,
+                                "quota_bytes": day.quota_bytes,
+                            })
+                        })
+                        .collect(),
+                )
+            })),
             ExportTarget::Kvstore => self
                 .kvstore_status
                 .as_ref()
@@ -302,6 +331,17 @@ impl App {
                         .map_err(|e| format!("Failed to serialize shc status: {}", e))
                 })
                 .transpose(),
+            ExportTarget::Dashboard => {
+                // Export the re-indented source alongside its identifying metadata
+                Ok(self.dashboard_source.as_ref().map(|dashboard| {
+                    serde_json::json!({
+                        "name": dashboard.name,
+                        "label": dashboard.label,
+                        "format": dashboard.source_format().map(|f| f.label()),
+                        "source": dashboard.pretty_source(),
+                    })
+                }))
+            }
         }
     }
 
@@ -386,4 +426,31 @@ mod tests {
 
         assert_eq!(data, Some(serde_json::Value::Array(vec![])));
     }
+
+    #[test]
+    fn test_collect_export_data_license_trend_rows() {
+        let app = App {
+            export_target: Some(ExportTarget::LicenseTrend),
+            license_info: Some(crate::action::LicenseData {
+                usage: vec![],
+                pools: vec![],
+                stacks: vec![],
+                messages: vec![],
+                slaves: vec![],
+                history: vec![splunk_client::models::LicenseUsageDay {
+                    day: "2024-01-14".to_string(),
+                    used_bytes: 6 * 1024 * 1024 * 1024,
+                    quota_bytes: 5 * 1024 * 1024 * 1024,
+                }],
+            }),
+            ..Default::default()
+        };
+
+        let data = app.collect_export_data().unwrap().unwrap();
+
+        assert_eq!(data[0]["day"], "2024-01-14");
+        assert_eq!(data[0]["used_gb"], 6.0);
+        assert_eq!(data[0]["quota_gb"], 5.0);
+        assert_eq!(data[0]["used_bytes"], 6_442_450_944u64);
+    }
 }
@@ -0,0 +1,134 @@
+//! Field extraction tester for pasted sample events.
+//!
+//! Responsibilities:
+//! - Hold the pasted sample events, the regex (or `EXTRACT-` line), and the focused input
+//! - Turn the inputs into the samples and extractions to test
+//! - Hold the preview returned by the server for side-by-side display
+//!
+//! Does NOT handle:
+//! - Does NOT run the test search (handled by Action::RunFieldExtractionTest)
+//! - Does NOT handle popup keys (handled by app::popups::extraction_tester)
+//! - Does NOT render the popup (handled by ui::extraction_tester)
+//!
+//! Invariants:
+//! - `result` is cleared whenever a new test starts
+
+use splunk_client::workflows::field_extraction::{
+    Extraction, FieldExtractionPreview, build_extraction_search, parse_props_extractions,
+    sample_events,
+};
+
+use crate::app::input::components::{MultiLineInput, SingleLineInput};
+
+/// Input focused in the tester.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExtractionTesterFocus {
+    #[default]
+    Samples,
+    Regex,
+}
+
+/// State of the field extraction tester popup.
+pub struct ExtractionTester {
+    /// Sourcetype the tester was opened from, shown in the title.
+    pub sourcetype: Option<String>,
+    /// Sample events, one per line.
+    pub samples: MultiLineInput<'static>,
+    /// A regex with named groups, or a props.conf `EXTRACT-<class> = <regex>` line.
+    pub regex: SingleLineInput,
+    pub focus: ExtractionTesterFocus,
+    /// Whether a test search is in flight.
+    pub running: bool,
+    /// Outcome of the last test (error is the user-facing message).
+    pub result: Option<Result<FieldExtractionPreview, String>>,
+}
+
+impl ExtractionTester {
+    pub fn new(sourcetype: Option<String>) -> Self {
+        Self {
+            sourcetype,
+            samples: MultiLineInput::with_placeholder("Paste sample events, one per line"),
+            regex: SingleLineInput::with_placeholder(
+                "user=(?<user>\\w+)  or  EXTRACT-user = user=(?<user>\\w+)",
+            ),
+            focus: ExtractionTesterFocus::default(),
+            running: false,
+            result: None,
+        }
+    }
+
+    pub fn toggle_focus(&mut self) {
+        self.focus = match self.focus {
+            ExtractionTesterFocus::Samples => ExtractionTesterFocus::Regex,
+            ExtractionTesterFocus::Regex => ExtractionTesterFocus::Samples,
+        };
+    }
+
+    /// Extractions described by the regex input.
+    pub fn extractions(&self) -> Vec<Extraction> {
+        let value = self.regex.value().trim();
+        if value.starts_with("EXTRACT-") {
+            parse_props_extractions(value)
+        } else if value.is_empty() {
+            Vec::new()
+        } else {
+            vec![Extraction::regex(value)]
+        }
+    }
+
+    /// Samples and extractions to test, validated before anything is sent.
+    pub fn request(&self) -> Result<(Vec<String>, Vec<Extraction>), String> {
+        let samples = sample_events(&self.samples.value());
+        let extractions = self.extractions();
+        build_extraction_search(&samples, &extractions).map_err(|e| e.to_string())?;
+        Ok((samples, extractions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extractions_accept_regex_or_props_line() {
+        let mut tester = ExtractionTester::new(None);
+        assert!(tester.extractions().is_empty());
+
+        tester.regex.set_value(r"  user=(?<user>\w+) ");
+        assert_eq!(
+            tester.extractions(),
+            vec![Extraction::regex(r"user=(?<user>\w+)")]
+        );
+
+        tester
+            .regex
+            .set_value(r"EXTRACT-status = (?<status>\d{3}) in message");
+        let extractions = tester.extractions();
+        assert_eq!(extractions[0].class.as_deref(), Some("status"));
+        assert_eq!(extractions[0].source_field, "message");
+    }
+
+    #[test]
+    fn test_request_reports_validation_errors() {
+        let mut tester = ExtractionTester::new(None);
+        tester.regex.set_value(r"user=(?<user>\w+)");
+        assert_eq!(
+            tester.request().unwrap_err(),
+            "no sample events provided".to_string()
+        );
+
+        tester.samples.set_value("user=alice\n\nuser=bob");
+        tester.regex.set_value(r"user=\w+");
+        assert!(
+            tester
+                .request()
+                .unwrap_err()
+                .contains("no named capture groups")
+        );
+
+        tester.regex.set_value(r"user=(?<user>\w+)");
+        let (samples, extractions) = tester.request().unwrap();
+        assert_eq!(samples, vec!["user=alice", "user=bob"]);
+        assert_eq!(extractions.len(), 1);
+    }
+}
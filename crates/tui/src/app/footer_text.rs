@@ -0,0 +1,225 @@
+//! Footer text for the TUI app.
+//!
+//! Responsibilities:
+//! - Build the footer line: mode indicator, loading progress, navigation and
+//!   focus hints, screen hints, and help/quit affordances
+//! - Truncate hints responsively on narrow terminals
+//!
+//! Does NOT handle:
+//! - Does NOT compute hit-test positions (see footer_layout.rs)
+//! - Does NOT draw the footer block (see render.rs)
+//!
+//! Invariants:
+//! - Element widths must match FooterLayout so mouse hit-testing stays aligned.
+
+use crate::app::App;
+use crate::app::footer_layout::FooterLayout;
+use crate::app::state::{
+    CurrentScreen, EscAction, NavigationContext, NavigationMode, SearchInputMode, TabAction,
+};
+use crate::input::keymap::overrides;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+impl App {
+    /// Build footer text with navigation hints, mode indicator, and controls.
+    ///
+    /// Layout: [MODE] | [Loading] | Tab:Action | Esc:Action | [Screen Hints] | ?:Help | q:Quit
+    ///
+    /// Mode indicator appears on screens with special navigation (Search).
+    /// Handles narrow terminals by responsive truncation.
+    pub(super) fn build_footer_text(&self, theme: crate::theme::Theme) -> Vec<Line<'_>> {
+        let hints = crate::input::keymap::footer_hints(self.current_screen);
+        let available_width = self.last_area.width as usize;
+        let nav_ctx = self.navigation_context();
+
+        // Build mode indicator for screens with multiple focus targets
+        let mode_text = if nav_ctx.has_multi_focus {
+            format!("[{}] ", nav_ctx.mode_label())
+        } else {
+            String::new()
+        };
+        let mode_width = mode_text.len();
+
+        // Build navigation hint text
+        let (nav_text, nav_width) = self.format_navigation_hint(&nav_ctx);
+
+        // Build Esc hint (separator added conditionally during render)
+        let esc_text = match nav_ctx.esc_action {
+            EscAction::FocusResults => "Esc:Results ".to_string(),
+            EscAction::FocusQuery => "Esc:Query ".to_string(),
+            EscAction::BackToPreviousScreen => "Esc:Back ".to_string(),
+            EscAction::ClosePopup => "Esc:Close ".to_string(),
+            EscAction::None => String::new(),
+        };
+        let esc_sep_width = if !nav_text.is_empty() && !esc_text.is_empty() {
+            " | ".len()
+        } else {
+            0
+        };
+        let esc_width = esc_text.len() + esc_sep_width;
+
+        // Build focus navigation hint (for multi-focus screens)
+        let (focus_text, focus_width) = self.format_focus_hint(&nav_ctx);
+
+        // Build loading indicator
+        let loading_text = if self.loading {
+            let spinner = self.spinner_char();
+            format!(" {} Loading... {:.0}% |", spinner, self.progress * 100.0)
+        } else {
+            String::new()
+        };
+        let loading_width = loading_text.len();
+
+        let (utility_spans, utility_width) = self.format_utility_hints(&nav_ctx);
+
+        let fixed_width =
+            mode_width + loading_width + nav_width + esc_width + focus_width + utility_width;
+        let hints_available_width = available_width.saturating_sub(fixed_width);
+
+        // Build screen hints string (truncated for narrow terminals)
+        let hints_text = FooterLayout::render_hints_text(&hints, hints_available_width);
+
+        // Build the footer line
+        let mut spans = Vec::new();
+
+        // Mode indicator for multi-focus screens
+        if nav_ctx.has_multi_focus {
+            let mode_style = match nav_ctx.mode {
+                NavigationMode::LocalFocus => Style::default()
+                    .fg(theme.warning)
+                    .add_modifier(Modifier::BOLD),
+                NavigationMode::ScreenCycle => Style::default().fg(theme.accent),
+            };
+            spans.push(Span::styled(mode_text, mode_style));
+        }
+
+        // Loading indicator
+        if self.loading {
+            spans.push(Span::styled(
+                loading_text,
+                Style::default().fg(theme.warning),
+            ));
+        }
+
+        // Navigation hints (context-aware)
+        let has_nav_text = !nav_text.is_empty();
+        let has_focus_text = !focus_text.is_empty();
+        spans.push(Span::raw(nav_text));
+
+        // Focus navigation hint (multi-focus screens only)
+        if has_focus_text {
+            if has_nav_text {
+                spans.push(Span::raw("|"));
+            }
+            spans.push(Span::raw(focus_text));
+        }
+
+        // Esc hint (add separator only if nav_text precedes it)
+        if !esc_text.is_empty() {
+            if has_nav_text || has_focus_text {
+                spans.push(Span::raw(" | "));
+            }
+            spans.push(Span::styled(esc_text, Style::default().fg(theme.text)));
+        }
+
+        // Screen-specific hints
+        if !hints_text.is_empty() {
+            spans.push(Span::raw("|"));
+            spans.push(Span::styled(hints_text, Style::default().fg(theme.accent)));
+        }
+
+        // Help and quit affordances (context-aware so the footer never advertises dead keys).
+        spans.extend(utility_spans);
+
+        vec![Line::from(spans)]
+    }
+
+    /// Format navigation hint based on current navigation context (RQ-0457).
+    ///
+    /// Returns (navigation_text, navigation_width) for footer layout calculation.
+    fn format_navigation_hint(&self, nav_ctx: &NavigationContext) -> (String, usize) {
+        use crate::action::Action;
+
+        let next_key = overrides::get_effective_key_display(Action::NextScreen, "Tab");
+        let prev_key = overrides::get_effective_key_display(Action::PreviousScreen, "Shift+Tab");
+
+        let text = match nav_ctx.tab_action {
+            TabAction::NextScreen => format!(" {}:Next | {}:Prev ", next_key, prev_key),
+            TabAction::PreviousScreen => format!(" {}:Prev ", prev_key),
+            TabAction::None => String::new(),
+            TabAction::ToggleFocus => format!(" {}:Next | {}:Prev ", next_key, prev_key),
+        };
+
+        (text.clone(), text.len())
+    }
+
+    /// Format focus navigation hint for screens with multiple focus targets.
+    ///
+    /// Returns (focus_hint_text, focus_hint_width) for footer layout.
+    fn format_focus_hint(&self, nav_ctx: &NavigationContext) -> (String, usize) {
+        if !nav_ctx.has_multi_focus {
+            return (String::new(), 0);
+        }
+
+        use crate::action::Action;
+        let next_focus_key = overrides::get_effective_key_display(Action::NextFocus, "Ctrl+Tab");
+        let prev_focus_key =
+            overrides::get_effective_key_display(Action::PreviousFocus, "Ctrl+Shift+Tab");
+
+        let text = if self.current_screen == CurrentScreen::Search {
+            match self.search_input_mode {
+                SearchInputMode::QueryFocused => format!(" {}:Results ", next_focus_key),
+                SearchInputMode::ResultsFocused => format!(" {}:Query ", prev_focus_key),
+            }
+        } else {
+            format!(" {}:Focus | {}:Focus ", next_focus_key, prev_focus_key)
+        };
+        (text.clone(), text.len())
+    }
+
+    /// Format help/quit affordances for the current interaction mode.
+    fn format_utility_hints(&self, _nav_ctx: &NavigationContext) -> (Vec<Span<'static>>, usize) {
+        use crate::action::Action;
+
+        let help_key = overrides::get_effective_key_display(Action::OpenHelpPopup, "?");
+        let quit_key = overrides::get_effective_key_display(Action::Quit, "q");
+        let global_quit_key = "Ctrl+Q".to_string();
+
+        if self.current_screen == CurrentScreen::Search
+            && matches!(self.search_input_mode, SearchInputMode::QueryFocused)
+            && self.popup.is_none()
+        {
+            let width = format!("| {}:Quit ", global_quit_key).len();
+            return (
+                vec![
+                    Span::raw("|"),
+                    Span::styled(
+                        format!(" {}:Quit ", global_quit_key),
+                        Style::default().fg(self.theme.error),
+                    ),
+                ],
+                width,
+            );
+        }
+
+        let width = format!("| {}:Help | {}:Quit ", help_key, quit_key).len();
+        (
+            vec![
+                Span::raw("|"),
+                Span::styled(
+                    format!(" {}:Help ", help_key),
+                    Style::default().fg(self.theme.success),
+                ),
+                Span::raw("|"),
+                Span::styled(
+                    format!(" {}:Quit ", quit_key),
+                    Style::default().fg(self.theme.error),
+                ),
+            ],
+            width,
+        )
+    }
+}
@@ -0,0 +1,56 @@
+//! Layout-rect registry for mouse hit-testing.
+//!
+//! Responsibilities:
+//! - Record the clickable regions of the last frame (tab bar, content area,
+//!   popup, and popup buttons) as they are rendered
+//! - Answer which region contains a screen position
+//!
+//! Does NOT handle:
+//! - Does NOT handle mouse events (see app::mouse)
+//! - Does NOT draw anything
+//!
+//! Invariants:
+//! - Cleared at the start of every `App::render`, so rects always match the last frame
+//! - Empty until the first frame is drawn; callers fall back to the default layout
+
+use ratatui::layout::{Position, Rect};
+
+use crate::app::state::CurrentScreen;
+use crate::ui::popup::PopupButton;
+
+/// Clickable regions recorded during the last render.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HitAreas {
+    /// Tab bar entries and the screen each one switches to
+    pub tabs: Vec<(Rect, CurrentScreen)>,
+    /// Area the current screen was rendered into
+    pub content: Rect,
+    /// Area of the active popup, if one was drawn
+    pub popup: Option<Rect>,
+    /// Buttons drawn on the active popup
+    pub popup_buttons: Vec<(Rect, PopupButton)>,
+}
+
+impl HitAreas {
+    /// Forget every region from the previous frame.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Screen switched to by the tab at a position.
+    pub fn tab_at(&self, col: u16, row: u16) -> Option<CurrentScreen> {
+        find_at(&self.tabs, col, row)
+    }
+
+    /// Popup button at a position.
+    pub fn popup_button_at(&self, col: u16, row: u16) -> Option<PopupButton> {
+        find_at(&self.popup_buttons, col, row)
+    }
+}
+
+fn find_at<T: Copy>(areas: &[(Rect, T)], col: u16, row: u16) -> Option<T> {
+    areas
+        .iter()
+        .find(|(area, _)| area.contains(Position::new(col, row)))
+        .map(|(_, target)| *target)
+}
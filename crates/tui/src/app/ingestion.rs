@@ -0,0 +1,121 @@
+//! Ingestion screen specific logic for the TUI app.
+//!
+//! Responsibilities:
+//! - Sort the ingestion table by the selected column and direction
+//!
+//! Does NOT handle:
+//! - Does NOT fetch ingestion statistics (handled by side effects)
+//! - Does NOT render the ingestion table
+
+use std::cmp::Ordering;
+
+use crate::app::App;
+use crate::app::state::{IngestionSortColumn, SortDirection};
+use splunk_client::IngestionStat;
+
+impl App {
+    /// Sort the loaded ingestion statistics by the current sort state.
+    pub(crate) fn sort_ingestion_stats(&mut self) {
+        let sort = self.ingestion_sort;
+        if let Some(ingestion) = self.ingestion_stats.as_mut() {
+            ingestion.stats.sort_by(|a, b| {
+                let ordering = compare_ingestion(a, b, sort.column);
+                match sort.direction {
+                    SortDirection::Asc => ordering,
+                    SortDirection::Desc => ordering.reverse(),
+                }
+            });
+        }
+    }
+
+    /// Cycle the ingestion sort column and re-sort.
+    pub(crate) fn cycle_ingestion_sort(&mut self) {
+        self.ingestion_sort.cycle();
+        self.sort_ingestion_stats();
+    }
+
+    /// Reverse the ingestion sort direction and re-sort.
+    pub(crate) fn toggle_ingestion_sort_direction(&mut self) {
+        self.ingestion_sort.toggle_direction();
+        self.sort_ingestion_stats();
+    }
+}
+
+/// Compare two rows by `column`, ascending. Missing latencies sort lowest;
+/// ties fall back to the name.
+fn compare_ingestion(
+    a: &IngestionStat,
+    b: &IngestionStat,
+    column: IngestionSortColumn,
+) -> Ordering {
+    let latency = |x: Option<f64>, y: Option<f64>| match (x, y) {
+        (Some(x), Some(y)) => x.total_cmp(&y),
+        (x, y) => x.is_some().cmp(&y.is_some()),
+    };
+    let ordering = match column {
+        IngestionSortColumn::Name => Ordering::Equal,
+        IngestionSortColumn::Events => a.events.cmp(&b.events),
+        IngestionSortColumn::Thruput => a.kb.total_cmp(&b.kb),
+        IngestionSortColumn::AvgLatency => latency(a.avg_latency_secs, b.avg_latency_secs),
+        IngestionSortColumn::MaxLatency => latency(a.max_latency_secs, b.max_latency_secs),
+    };
+    ordering.then_with(|| a.name.cmp(&b.name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use splunk_client::{IngestionGroupBy, IngestionStats};
+
+    fn stat(name: &str, kb: f64, avg_latency_secs: Option<f64>) -> IngestionStat {
+        IngestionStat {
+            name: name.to_string(),
+            events: 10,
+            kb,
+            kb_per_sec: kb / 3600.0,
+            events_per_sec: 0.0,
+            avg_latency_secs,
+            max_latency_secs: avg_latency_secs,
+        }
+    }
+
+    fn names(app: &App) -> Vec<&str> {
+        app.ingestion_stats
+            .as_ref()
+            .unwrap()
+            .stats
+            .iter()
+            .map(|s| s.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_ingestion_sort_cycles_columns_and_direction() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.ingestion_stats = Some(IngestionStats {
+            group_by: IngestionGroupBy::Sourcetype,
+            window_secs: 3600,
+            stats: vec![
+                stat("syslog", 36.0, Some(0.5)),
+                stat("access_combined", 3600.0, Some(2.5)),
+                stat("splunkd", 720.0, None),
+            ],
+        });
+
+        app.sort_ingestion_stats();
+        assert_eq!(names(&app), vec!["access_combined", "splunkd", "syslog"]);
+
+        // Thruput -> AvgLatency, still descending; missing latency last.
+        app.cycle_ingestion_sort();
+        assert_eq!(names(&app), vec!["access_combined", "syslog", "splunkd"]);
+
+        app.toggle_ingestion_sort_direction();
+        assert_eq!(names(&app), vec!["splunkd", "syslog", "access_combined"]);
+
+        // AvgLatency -> MaxLatency -> Name, ascending.
+        app.cycle_ingestion_sort();
+        app.cycle_ingestion_sort();
+        assert_eq!(names(&app), vec!["access_combined", "splunkd", "syslog"]);
+    }
+}
@@ -1,6 +1,7 @@
 //! Apps screen input handler.
 //!
 //! Responsibilities:
+//! - Handle Enter to load the selected app's contents for the App Details popup
 //! - Handle 'e' key to enable selected app (if disabled)
 //! - Handle 'd' key to disable selected app (if enabled)
 //! - Handle 'i' key to install app from .spl file
@@ -20,7 +21,7 @@ use crate::app::input::helpers::{
     handle_copy_with_toast, handle_list_export, is_copy_key, is_export_key, should_export_list,
 };
 use crate::ui::ToastLevel;
-use crate::ui::popup::{Popup, PopupType};
+use crate::ui::popup::{InstallAppField, Popup, PopupType};
 use crossterm::event::{KeyCode, KeyEvent};
 
 impl App {
@@ -38,6 +39,13 @@ impl App {
         }
 
         match key.code {
+            KeyCode::Enter => self
+                .apps
+                .as_ref()
+                .and_then(|apps| self.apps_state.selected().and_then(|i| apps.get(i)))
+                .map(|app| Action::LoadAppContents {
+                    app: app.name.clone(),
+                }),
             KeyCode::Char('e') if is_export_key(key) => {
                 let can_export = should_export_list(self.apps.as_ref());
                 handle_list_export(self, can_export, ExportTarget::Apps)
@@ -89,6 +97,11 @@ impl App {
                 self.popup = Some(
                     Popup::builder(PopupType::InstallAppDialog {
                         file_input: String::new(),
+                        splunkbase_query: String::new(),
+                        version_input: String::new(),
+                        selected_field: InstallAppField::File,
+                        splunkbase_results: Vec::new(),
+                        selected_result: None,
                     })
                     .build(),
                 );
@@ -3,6 +3,8 @@
 //! Responsibilities:
 //! - Handle Ctrl+C or 'y' copy of selected event details (vim-style)
 //! - Handle Ctrl+E export of audit events
+//! - Handle 't' to pick the audit time range
+//! - Handle 'u' to drill into the selected event's user activity
 //!
 //! Does NOT handle:
 //! - Does NOT handle global navigation (handled by keymap)
@@ -12,6 +14,7 @@
 use crate::action::Action;
 use crate::app::App;
 use crate::app::export::ExportTarget;
+use crate::app::time_range::TimeRangeTarget;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 impl App {
@@ -49,7 +52,77 @@ impl App {
                 self.begin_export(ExportTarget::AuditEvents);
                 None
             }
+            KeyCode::Char('t') => {
+                self.open_time_range_picker(TimeRangeTarget::Audit);
+                None
+            }
+            KeyCode::Char('u') => {
+                let user = self.audit_events.as_ref().and_then(|events| {
+                    self.audit_state
+                        .selected()
+                        .and_then(|i| events.get(i))
+                        .map(|e| e.user.trim().to_string())
+                });
+
+                match user.filter(|u| !u.is_empty()) {
+                    Some(user) => Some(Action::LoadUserActivity { user }),
+                    None => {
+                        self.push_info_toast_once("No user on selected event");
+                        None
+                    }
+                }
+            }
             _ => None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::state::CurrentScreen;
+    use splunk_client::models::{AuditAction, AuditEvent, AuditResult};
+
+    fn audit_event(user: &str) -> AuditEvent {
+        AuditEvent {
+            time: "2025-01-20T10:30:00.000Z".to_string(),
+            user: user.to_string(),
+            action: AuditAction::Login,
+            target: String::new(),
+            result: AuditResult::Success,
+            client_ip: String::new(),
+            details: String::new(),
+            raw: String::new(),
+        }
+    }
+
+    #[test]
+    #[allow(clippy::field_reassign_with_default)]
+    fn test_audit_user_activity_uses_selected_event_user() {
+        let mut app = App::default();
+        app.current_screen = CurrentScreen::Audit;
+        app.audit_events = Some(vec![audit_event("admin"), audit_event("alice")]);
+        app.audit_state.select(Some(1));
+
+        let action = app.handle_audit_input(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::NONE));
+
+        assert!(matches!(
+            action,
+            Some(Action::LoadUserActivity { ref user }) if user == "alice"
+        ));
+    }
+
+    #[test]
+    #[allow(clippy::field_reassign_with_default)]
+    fn test_audit_user_activity_requires_a_user() {
+        let mut app = App::default();
+        app.current_screen = CurrentScreen::Audit;
+        app.audit_events = Some(vec![audit_event(" ")]);
+        app.audit_state.select(Some(0));
+
+        let action = app.handle_audit_input(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::NONE));
+
+        assert!(action.is_none());
+        assert_eq!(app.toasts.len(), 1);
+    }
+}
@@ -25,6 +25,8 @@ impl App {
     /// - 'j' / Down: Next item
     /// - 'k' / Up: Previous item
     /// - 'y': Copy selected stanza name to clipboard
+    /// - 'v': Cycle settings / layers / diff (stanza detail)
+    /// - 'a': Cycle the app diffed against the effective settings (stanza detail)
     /// - '/': Search stanzas
     /// - '?': Show help
     pub fn handle_configs_input(&mut self, key: KeyEvent) -> Option<Action> {
@@ -65,6 +67,16 @@ impl App {
                 None
             }
 
+            // Layered view and diff
+            KeyCode::Char('v') if view_mode == ConfigViewMode::StanzaDetail => {
+                self.config_detail_view = self.config_detail_view.next();
+                None
+            }
+            KeyCode::Char('a') if view_mode == ConfigViewMode::StanzaDetail => {
+                self.cycle_config_diff_app();
+                None
+            }
+
             // Help
             KeyCode::Char('?') => Some(Action::OpenHelpPopup),
 
@@ -76,14 +88,16 @@ impl App {
     fn handle_configs_refresh(&self, view_mode: ConfigViewMode) -> Option<Action> {
         match view_mode {
             ConfigViewMode::FileList => Some(Action::LoadConfigFiles),
-            ConfigViewMode::StanzaList | ConfigViewMode::StanzaDetail => self
-                .selected_config_file
-                .as_ref()
-                .map(|config_file| Action::LoadConfigStanzas {
-                    config_file: config_file.clone(),
-                    count: 100,
-                    offset: 0,
-                }),
+            ConfigViewMode::StanzaList => {
+                self.selected_config_file
+                    .as_ref()
+                    .map(|config_file| Action::LoadConfigStanzas {
+                        config_file: config_file.clone(),
+                        count: 100,
+                        offset: 0,
+                    })
+            }
+            ConfigViewMode::StanzaDetail => self.load_config_layers_action(),
         }
     }
 
@@ -111,6 +125,9 @@ impl App {
                 if let Some(stanza) = self.get_selected_stanza() {
                     self.selected_stanza = Some(stanza.clone());
                     self.config_view_mode = ConfigViewMode::StanzaDetail;
+                    self.config_layers = None;
+                    self.config_diff_app = None;
+                    return self.load_config_layers_action();
                 }
                 None
             }
@@ -140,6 +157,8 @@ impl App {
                 // Go back to stanza list
                 self.config_view_mode = ConfigViewMode::StanzaList;
                 self.selected_stanza = None;
+                self.config_layers = None;
+                self.config_diff_app = None;
                 None
             }
         }
@@ -172,6 +191,58 @@ impl App {
         }
     }
 
+    /// Action loading the layers of the stanza shown in the detail view.
+    fn load_config_layers_action(&self) -> Option<Action> {
+        let stanza = self.selected_stanza.as_ref()?;
+        Some(Action::LoadConfigLayers {
+            config_file: stanza.config_file.clone(),
+            stanza: stanza.name.clone(),
+        })
+    }
+
+    /// Apps with a layer for the selected stanza, sorted by name.
+    fn config_layer_apps(&self) -> Vec<String> {
+        let (Some(layers), Some(stanza)) = (&self.config_layers, &self.selected_stanza) else {
+            return Vec::new();
+        };
+        let mut apps: Vec<String> = layers
+            .iter()
+            .filter(|layer| {
+                layer.stanza == stanza.name
+                    && layer.scope == splunk_client::models::ConfigScope::App
+            })
+            .map(|layer| layer.app.clone())
+            .collect();
+        apps.sort();
+        apps.dedup();
+        apps
+    }
+
+    /// Keep the diffed app pointing at an app that defines the stanza.
+    pub(crate) fn ensure_config_diff_app(&mut self) {
+        let apps = self.config_layer_apps();
+        if !self
+            .config_diff_app
+            .as_ref()
+            .is_some_and(|app| apps.contains(app))
+        {
+            self.config_diff_app = apps.into_iter().next();
+        }
+    }
+
+    /// Diff the next app that defines the selected stanza.
+    fn cycle_config_diff_app(&mut self) {
+        let apps = self.config_layer_apps();
+        let next = match &self.config_diff_app {
+            Some(current) => apps
+                .iter()
+                .position(|app| app == current)
+                .map_or(0, |i| (i + 1) % apps.len()),
+            None => 0,
+        };
+        self.config_diff_app = apps.get(next).cloned();
+    }
+
     /// Move to the next item based on view mode.
     fn next_configs_item(&mut self, view_mode: ConfigViewMode) {
         match view_mode {
@@ -677,4 +748,63 @@ mod tests {
             "'?' should return OpenHelpPopup action"
         );
     }
+
+    fn layer(app: &str, settings: &[(&str, &str)]) -> splunk_client::models::ConfigLayer {
+        splunk_client::models::ConfigLayer {
+            stanza: "stanza1".to_string(),
+            scope: splunk_client::models::ConfigScope::App,
+            app: app.to_string(),
+            owner: "nobody".to_string(),
+            settings: settings
+                .iter()
+                .map(|(k, v)| (k.to_string(), serde_json::json!(v)))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_enter_on_stanza_loads_layers() {
+        let mut app = create_test_app();
+        app.current_screen = CurrentScreen::Configs;
+        app.config_view_mode = ConfigViewMode::StanzaList;
+        app.config_stanzas = Some(create_test_stanzas());
+        app.rebuild_filtered_stanza_indices();
+        app.config_stanzas_state.select(Some(0));
+
+        let action = app.handle_configs_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        assert_eq!(app.config_view_mode, ConfigViewMode::StanzaDetail);
+        assert!(matches!(
+            action,
+            Some(Action::LoadConfigLayers { ref config_file, ref stanza })
+                if config_file == "props" && stanza == "stanza1"
+        ));
+    }
+
+    #[test]
+    fn test_layered_view_and_diff_app_cycling() {
+        use crate::ui::screens::configs::ConfigDetailView;
+
+        let mut app = create_test_app();
+        app.current_screen = CurrentScreen::Configs;
+        app.config_view_mode = ConfigViewMode::StanzaDetail;
+        app.selected_stanza = create_test_stanzas().into_iter().next();
+        app.update(Action::ConfigLayersLoaded(Ok(vec![
+            layer("zeta", &[("TZ", "EST")]),
+            layer("alpha", &[("TZ", "UTC")]),
+        ])));
+        assert_eq!(app.config_diff_app.as_deref(), Some("alpha"));
+
+        let v = KeyEvent::new(KeyCode::Char('v'), KeyModifiers::NONE);
+        app.handle_configs_input(v);
+        assert_eq!(app.config_detail_view, ConfigDetailView::Layers);
+        app.handle_configs_input(v);
+        assert_eq!(app.config_detail_view, ConfigDetailView::Diff);
+
+        let a = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE);
+        app.handle_configs_input(a);
+        assert_eq!(app.config_diff_app.as_deref(), Some("zeta"));
+        app.handle_configs_input(a);
+        assert_eq!(app.config_diff_app.as_deref(), Some("alpha"));
+    }
 }
@@ -11,6 +11,7 @@ use crossterm::event::{KeyCode, KeyEvent};
 
 use crate::action::Action;
 use crate::app::App;
+use splunk_client::models::KnowledgeObjectKind;
 
 impl App {
     /// Handle input for the Dashboards screen.
@@ -24,6 +25,29 @@ impl App {
                     offset: 0,
                 })
             }
+            KeyCode::Enter => self
+                .dashboards
+                .as_ref()
+                .and_then(|dashboards| {
+                    self.dashboards_state
+                        .selected()
+                        .and_then(|i| dashboards.get(i))
+                })
+                .map(|dashboard| Action::LoadDashboardSource {
+                    name: dashboard.name.clone(),
+                }),
+            KeyCode::Char('p') => self
+                .dashboards
+                .as_ref()
+                .and_then(|dashboards| {
+                    self.dashboards_state
+                        .selected()
+                        .and_then(|i| dashboards.get(i))
+                })
+                .map(|dashboard| Action::LoadObjectAcl {
+                    kind: KnowledgeObjectKind::Dashboard,
+                    name: dashboard.name.clone(),
+                }),
             _ => None,
         }
     }
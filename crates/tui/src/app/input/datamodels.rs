@@ -2,6 +2,7 @@
 //!
 //! Responsibilities:
 //! - Handle keyboard input for the data models list screen.
+//! - Open the tstats query builder for the selected data model.
 //!
 //! Does NOT handle:
 //! - Does NOT render UI (handled by screens::datamodels)
@@ -24,6 +25,17 @@ impl App {
                     offset: 0,
                 })
             }
+            KeyCode::Char('t') => self
+                .data_models
+                .as_ref()
+                .and_then(|datamodels| {
+                    self.data_models_state
+                        .selected()
+                        .and_then(|i| datamodels.get(i))
+                })
+                .map(|datamodel| Action::LoadTstatsBuilder {
+                    name: datamodel.name.clone(),
+                }),
             _ => None,
         }
     }
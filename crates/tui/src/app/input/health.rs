@@ -3,6 +3,7 @@
 //! Responsibilities:
 //! - Handle Ctrl+C or 'y' copy of health status or server name (vim-style)
 //! - Handle Ctrl+E export of health info
+//! - Handle 'R' to open the typed splunkd restart confirmation
 //!
 //! Does NOT handle:
 //! - Does NOT handle global navigation (handled by keymap)
@@ -43,6 +44,10 @@ impl App {
                 self.begin_export(ExportTarget::Health);
                 None
             }
+            KeyCode::Char('R') => {
+                self.open_server_restart_confirm();
+                None
+            }
             _ => None,
         }
     }
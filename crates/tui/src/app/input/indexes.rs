@@ -7,6 +7,8 @@
 //! - Handle 'c' to open create index dialog
 //! - Handle 'm' to open modify index dialog
 //! - Handle 'd' to open delete index confirmation
+//! - Handle 'b'/'B' to confirm a hot bucket roll or data rebalance
+//! - Handle 's' to sample raw events of the selected index
 //!
 //! Does NOT handle:
 //! - Does NOT handle global navigation (handled by keymap)
@@ -81,6 +83,36 @@ impl App {
                 self.toasts.push(Toast::info("No index selected"));
                 None
             }
+            KeyCode::Char('s') => {
+                // Sample the newest raw events of the selected index
+                if let Some(indexes) = &self.indexes
+                    && let Some(selected) = self.indexes_state.selected()
+                    && let Some(index) = indexes.get(selected)
+                {
+                    return Some(Action::LoadIndexSample {
+                        index: index.name.clone(),
+                    });
+                }
+                self.toasts.push(Toast::info("No index selected"));
+                None
+            }
+            KeyCode::Char(c @ ('b' | 'B')) => {
+                // Confirm a hot bucket roll ('b') or data rebalance ('B') for the selected index
+                if let Some(indexes) = &self.indexes
+                    && let Some(selected) = self.indexes_state.selected()
+                    && let Some(index) = indexes.get(selected)
+                {
+                    let kind = if c == 'b' {
+                        PopupType::ConfirmRollHotBuckets(index.name.clone())
+                    } else {
+                        PopupType::ConfirmRebalanceIndex(index.name.clone())
+                    };
+                    self.popup = Some(Popup::builder(kind).build());
+                    return None;
+                }
+                self.toasts.push(Toast::info("No index selected"));
+                None
+            }
             _ => None,
         }
     }
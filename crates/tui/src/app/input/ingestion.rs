@@ -0,0 +1,30 @@
+//! Ingestion screen input handler.
+//!
+//! Responsibilities:
+//! - Handle Ctrl+C or 'y' copy of the selected sourcetype (vim-style)
+//!
+//! Does NOT handle:
+//! - Does NOT handle refresh, sorting, or navigation (handled by keymap)
+//! - Does NOT render the UI (handled by render module)
+//! - Does NOT fetch ingestion data (handled by actions)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::app::input::helpers::{handle_copy_with_toast, is_copy_key};
+use crossterm::event::KeyEvent;
+
+impl App {
+    /// Handle input for the ingestion screen.
+    pub fn handle_ingestion_input(&mut self, key: KeyEvent) -> Option<Action> {
+        if is_copy_key(key) {
+            let content = self.ingestion_stats.as_ref().and_then(|ingestion| {
+                self.ingestion_state
+                    .selected()
+                    .and_then(|i| ingestion.stats.get(i))
+                    .map(|stat| stat.name.clone())
+            });
+            return handle_copy_with_toast(self, content);
+        }
+        None
+    }
+}
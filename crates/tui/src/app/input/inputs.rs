@@ -2,7 +2,9 @@
 //!
 //! Responsibilities:
 //! - Handle keyboard input for the inputs screen
-//! - Trigger input refresh, enable/disable operations
+//! - Trigger input refresh, enable/disable operations, and throughput loading
+//! - Open the add data input form
+//! - In the HTTP inputs view, enable/disable HEC tokens and reveal the selected token
 //!
 //! Does NOT handle:
 //! - Direct state modification (returns Actions)
@@ -14,6 +16,8 @@ use crate::app::export::ExportTarget;
 use crate::app::input::helpers::{
     handle_copy_with_toast, handle_list_export, is_copy_key, is_export_key, should_export_list,
 };
+use crate::app::state::InputsViewMode;
+use crate::ui::popup::{CreateInputField, CreateInputKind, Popup, PopupType};
 use crossterm::event::{KeyCode, KeyEvent};
 
 impl App {
@@ -23,9 +27,15 @@ impl App {
     /// - 'r' / F5: Refresh inputs list
     /// - 'e': Enable selected input
     /// - 'd': Disable selected input
+    /// - 't': Load per-input throughput for the last hour
+    /// - 'a': Add a monitor, TCP, or UDP input
     /// - Enter: Show input details (not implemented yet)
     /// - Ctrl+E: Export inputs list
     pub fn handle_inputs_input(&mut self, key: KeyEvent) -> Option<Action> {
+        if self.inputs_view_mode == InputsViewMode::Http {
+            return self.handle_hec_tokens_input(key);
+        }
+
         if is_copy_key(key) {
             let content = self.inputs.as_ref().and_then(|inputs| {
                 self.inputs_state
@@ -76,6 +86,84 @@ impl App {
                 }
                 None
             }
+            KeyCode::Char('t') => Some(Action::LoadInputThroughput),
+            KeyCode::Char('a') => {
+                self.popup = Some(
+                    Popup::builder(PopupType::CreateInput {
+                        kind: CreateInputKind::Monitor,
+                        target_input: String::new(),
+                        index_input: String::new(),
+                        sourcetype_input: String::new(),
+                        whitelist_input: String::new(),
+                        blacklist_input: String::new(),
+                        selected_field: CreateInputField::Target,
+                    })
+                    .build(),
+                );
+                None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.next_item();
+                None
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.previous_item();
+                None
+            }
+            KeyCode::PageDown => {
+                self.next_page();
+                None
+            }
+            KeyCode::PageUp => {
+                self.previous_page();
+                None
+            }
+            KeyCode::Home => {
+                self.go_to_top();
+                None
+            }
+            KeyCode::End => {
+                self.go_to_bottom();
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Handle keyboard input for the HTTP inputs (HEC tokens) view.
+    ///
+    /// Keybindings:
+    /// - 'e' / 'd': Enable / disable the selected token
+    /// - 'v': Reveal or mask the selected token's value
+    /// - Ctrl+C: Copy the selected token name
+    fn handle_hec_tokens_input(&mut self, key: KeyEvent) -> Option<Action> {
+        let selected = self.hec_tokens.as_ref().and_then(|tokens| {
+            self.hec_tokens_state
+                .selected()
+                .and_then(|selected| tokens.get(selected))
+        });
+
+        if is_copy_key(key) {
+            let content = selected.map(|token| token.input_name().to_string());
+            return handle_copy_with_toast(self, content);
+        }
+
+        match key.code {
+            KeyCode::Char('e') => selected.map(|token| Action::EnableHecToken {
+                name: token.input_name().to_string(),
+            }),
+            KeyCode::Char('d') => selected.map(|token| Action::DisableHecToken {
+                name: token.input_name().to_string(),
+            }),
+            KeyCode::Char('v') => {
+                let name = selected.map(|token| token.name.clone());
+                self.revealed_hec_token = if self.revealed_hec_token == name {
+                    None
+                } else {
+                    name
+                };
+                None
+            }
             KeyCode::Down | KeyCode::Char('j') => {
                 self.next_item();
                 None
@@ -175,4 +263,84 @@ mod tests {
             Some(PopupType::ExportSearch)
         ));
     }
+
+    #[test]
+    fn test_t_loads_input_throughput() {
+        let mut app = App::new(None, ConnectionContext::default());
+
+        let action = app.handle_inputs_input(KeyEvent::new(KeyCode::Char('t'), KeyModifiers::NONE));
+
+        assert!(matches!(action, Some(Action::LoadInputThroughput)));
+    }
+
+    #[test]
+    fn test_a_opens_create_input_form_on_path_field() {
+        let mut app = App::new(None, ConnectionContext::default());
+
+        let action = app.handle_inputs_input(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE));
+
+        assert!(action.is_none());
+        assert!(matches!(
+            app.popup.as_ref().map(|popup| &popup.kind),
+            Some(PopupType::CreateInput {
+                kind: CreateInputKind::Monitor,
+                selected_field: CreateInputField::Target,
+                ..
+            })
+        ));
+    }
+
+    fn app_with_hec_tokens() -> App {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.inputs_view_mode = InputsViewMode::Http;
+        app.hec_tokens = Some(vec![splunk_client::models::HecToken {
+            name: "http://web_events".to_string(),
+            token: Some(secrecy::SecretString::from("6b1c8f2e")),
+            index: Some("web".to_string()),
+            indexes: vec!["web".to_string()],
+            sourcetype: None,
+            source: None,
+            host: None,
+            outputgroup: None,
+            description: None,
+            app: None,
+            disabled: false,
+            use_ack: false,
+        }]);
+        app
+    }
+
+    #[test]
+    fn test_http_view_v_toggles_reveal_of_selected_token() {
+        let mut app = app_with_hec_tokens();
+        let v = KeyEvent::new(KeyCode::Char('v'), KeyModifiers::NONE);
+
+        assert!(app.handle_inputs_input(v).is_none());
+        assert_eq!(app.revealed_hec_token.as_deref(), Some("http://web_events"));
+
+        app.handle_inputs_input(v);
+        assert!(app.revealed_hec_token.is_none());
+    }
+
+    #[test]
+    fn test_http_view_d_disables_selected_token_by_bare_name() {
+        let mut app = app_with_hec_tokens();
+
+        let action = app.handle_inputs_input(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE));
+
+        assert!(
+            matches!(action, Some(Action::DisableHecToken { ref name }) if name == "web_events"),
+            "{:?}",
+            action
+        );
+    }
+
+    #[test]
+    fn test_http_view_refresh_loads_hec_tokens() {
+        let app = app_with_hec_tokens();
+
+        let action = app.translate_refresh_action(Action::RefreshInputs);
+
+        assert!(matches!(action, Action::LoadHecTokens), "{:?}", action);
+    }
 }
@@ -4,6 +4,9 @@
 //! - Handle Ctrl+C or 'y' copy of selected log message (vim-style)
 //! - Handle Ctrl+E export of internal logs
 //! - Handle auto-refresh toggle (a key)
+//! - Handle follow mode (F key) and pause/resume (p key)
+//! - Handle level filter cycle (l key) and component filter entry (/ key)
+//! - Handle time range picker (t key)
 //!
 //! Does NOT handle:
 //! - Does NOT handle global navigation (handled by keymap)
@@ -13,6 +16,7 @@
 use crate::action::Action;
 use crate::app::App;
 use crate::app::export::ExportTarget;
+use crate::app::time_range::TimeRangeTarget;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 impl App {
@@ -23,12 +27,7 @@ impl App {
             && matches!(key.code, KeyCode::Char('c')))
             || (key.modifiers.is_empty() && matches!(key.code, KeyCode::Char('y')));
         if is_copy {
-            let content = self.internal_logs.as_ref().and_then(|logs| {
-                self.internal_logs_state
-                    .selected()
-                    .and_then(|i| logs.get(i))
-                    .map(|l| l.message.clone())
-            });
+            let content = self.selected_internal_log().map(|l| l.message.clone());
 
             if let Some(content) = content.filter(|s| !s.trim().is_empty()) {
                 return Some(Action::CopyToClipboard(content));
@@ -54,6 +53,20 @@ impl App {
                 self.auto_refresh = !self.auto_refresh;
                 None
             }
+            KeyCode::Char('t') => {
+                self.open_time_range_picker(TimeRangeTarget::InternalLogs);
+                None
+            }
+            KeyCode::Char('F') => self.toggle_log_follow(),
+            KeyCode::Char('p') => self.toggle_log_follow_pause(),
+            KeyCode::Char('l') => {
+                self.cycle_log_level_filter();
+                None
+            }
+            KeyCode::Char('/') => {
+                self.begin_log_component_filter();
+                None
+            }
             _ => None,
         }
     }
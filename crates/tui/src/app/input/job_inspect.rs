@@ -2,6 +2,7 @@
 //!
 //! Responsibilities:
 //! - Handle Ctrl+C or 'y' copy of the inspected job's SID (vim-style)
+//! - Handle 't' to extend the inspected job's artifact TTL
 //!
 //! Does NOT handle:
 //! - Does NOT handle global navigation (handled by keymap)
@@ -10,6 +11,7 @@
 
 use crate::action::Action;
 use crate::app::App;
+use crate::ui::screens::job_details::TTL_EXTENSION_SECS;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 impl App {
@@ -27,6 +29,18 @@ impl App {
             return None;
         }
 
+        // 't': extend the inspected job's artifact TTL
+        if key.modifiers.is_empty() && matches!(key.code, KeyCode::Char('t')) {
+            if let Some(job) = self.get_selected_job() {
+                return Some(Action::ExtendJobTtl {
+                    sid: job.sid.clone(),
+                    ttl_secs: TTL_EXTENSION_SECS,
+                });
+            }
+            self.push_info_toast_once("No job selected");
+            return None;
+        }
+
         None
     }
 }
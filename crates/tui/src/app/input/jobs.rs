@@ -4,6 +4,8 @@
 //! - Handle job selection and multi-selection (space key)
 //! - Handle job cancel (c key) and delete (d key)
 //! - Handle auto-refresh toggle (a key)
+//! - Open the server-side filter popup (F key)
+//! - Open the "prune old jobs" popup (P key)
 //! - Handle Ctrl+C copy of selected job SID
 //! - Handle filter input mode
 //!
@@ -15,8 +17,9 @@
 use crate::action::Action;
 use crate::app::App;
 use crate::app::export::ExportTarget;
-use crate::ui::popup::{Popup, PopupType};
+use crate::ui::popup::{JobsFilterField, JobsPruneField, Popup, PopupType};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use splunk_client::JobStatusFilter;
 
 impl App {
     /// Handle input for the jobs screen.
@@ -46,6 +49,30 @@ impl App {
                 self.auto_refresh = !self.auto_refresh;
                 None
             }
+            KeyCode::Char('F') => {
+                self.popup = Some(
+                    Popup::builder(PopupType::JobsFilter {
+                        owner_input: self.jobs_server_filter.owner.clone().unwrap_or_default(),
+                        app_input: self.jobs_server_filter.app.clone().unwrap_or_default(),
+                        status: self.jobs_server_filter.status,
+                        selected_field: JobsFilterField::Owner,
+                    })
+                    .build(),
+                );
+                None
+            }
+            KeyCode::Char('P') => {
+                self.popup = Some(
+                    Popup::builder(PopupType::JobsPrune {
+                        older_than_input: "2d".to_string(),
+                        owner_input: String::new(),
+                        status: Some(JobStatusFilter::Done),
+                        selected_field: JobsPruneField::OlderThan,
+                    })
+                    .build(),
+                );
+                None
+            }
             KeyCode::Char('c') => {
                 if !self.selected_jobs.is_empty() {
                     self.popup = Some(
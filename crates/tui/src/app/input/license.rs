@@ -3,6 +3,7 @@
 //! Responsibilities:
 //! - Handle Ctrl+C or 'y' copy of license info (vim-style)
 //! - Handle Ctrl+E export of license info
+//! - Handle 't' CSV export of the daily usage trend
 //! - Handle 'r' key to refresh license data
 //!
 //! Does NOT handle:
@@ -10,7 +11,7 @@
 //! - Does NOT render the UI (handled by render module)
 //! - Does NOT fetch license data (handled by actions)
 
-use crate::action::Action;
+use crate::action::{Action, ExportFormat};
 use crate::app::App;
 use crate::app::export::ExportTarget;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
@@ -63,6 +64,19 @@ impl App {
                 self.begin_export(ExportTarget::License);
                 None
             }
+            KeyCode::Char('t') if key.modifiers.is_empty() => {
+                let has_history = self
+                    .license_info
+                    .as_ref()
+                    .is_some_and(|info| !info.history.is_empty());
+                if has_history {
+                    self.export_format = ExportFormat::Csv;
+                    self.begin_export(ExportTarget::LicenseTrend);
+                } else {
+                    self.push_info_toast_once("No license usage history to export");
+                }
+                None
+            }
             _ => None,
         }
     }
@@ -16,7 +16,7 @@ use crate::app::export::ExportTarget;
 use crate::app::input::helpers::{
     handle_copy_with_toast, handle_list_export, is_copy_key, is_export_key, should_export_list,
 };
-use splunk_client::models::LookupTable;
+use splunk_client::models::{KnowledgeObjectKind, LookupTable};
 
 impl App {
     /// Handle keyboard input for the lookups screen.
@@ -76,6 +76,9 @@ impl App {
                 handle_list_export(self, can_export, ExportTarget::Lookups)
             }
 
+            // Open the contents editor for the selected lookup
+            KeyCode::Enter => self.selected_lookup_edit_action(),
+
             // Download selected lookup (Ctrl+D or 'd')
             KeyCode::Char('d')
                 if key.modifiers.is_empty() || key.modifiers.contains(KeyModifiers::CONTROL) =>
@@ -90,6 +93,14 @@ impl App {
                 self.selected_lookup_delete_action()
             }
 
+            // View/edit permissions
+            KeyCode::Char('p') if key.modifiers.is_empty() => {
+                self.selected_lookup().map(|lookup| Action::LoadObjectAcl {
+                    kind: KnowledgeObjectKind::Lookup,
+                    name: lookup.name.clone(),
+                })
+            }
+
             // Load more (if available)
             KeyCode::Char('n') if self.lookups_pagination.can_load_more() => {
                 Some(Action::LoadMoreLookups)
@@ -116,6 +127,18 @@ impl App {
         })
     }
 
+    fn selected_lookup_edit_action(&mut self) -> Option<Action> {
+        let action = self
+            .selected_lookup()
+            .map(|lookup| Action::LoadLookupContent {
+                name: lookup.name.clone(),
+                app: Some(lookup.app.clone()),
+                owner: Some(lookup.owner.clone()),
+            });
+        self.loading |= action.is_some();
+        action
+    }
+
     fn selected_lookup_delete_action(&self) -> Option<Action> {
         self.selected_lookup()
             .map(|lookup| Action::OpenDeleteLookupConfirm {
@@ -273,6 +296,25 @@ mod tests {
         ));
     }
 
+    #[test]
+    #[allow(clippy::field_reassign_with_default)]
+    fn test_lookups_enter_loads_contents_in_lookup_namespace() {
+        let mut app = App::default();
+        app.current_screen = CurrentScreen::Lookups;
+        app.lookups = Some(vec![lookup("lookup1", 1024)]);
+        app.lookups_state.select(Some(0));
+
+        let action = app.handle_lookups_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        assert!(matches!(
+            action,
+            Some(Action::LoadLookupContent { ref name, ref app, ref owner })
+                if name == "lookup1"
+                    && app.as_deref() == Some("search")
+                    && owner.as_deref() == Some("admin")
+        ));
+    }
+
     #[test]
     #[allow(clippy::field_reassign_with_default)]
     fn test_lookups_delete_shortcuts_share_behavior() {
@@ -13,6 +13,7 @@ use crossterm::event::{KeyCode, KeyEvent};
 use crate::action::Action;
 use crate::app::App;
 use crate::app::input::helpers::{handle_copy_with_toast, is_copy_key};
+use splunk_client::models::KnowledgeObjectKind;
 
 impl App {
     /// Handle input for the macros screen.
@@ -50,6 +51,18 @@ impl App {
                 }
                 None
             }
+            KeyCode::Char('p') if key.modifiers.is_empty() => {
+                if let Some(macros) = &self.macros
+                    && let Some(selected) = self.macros_state.selected()
+                    && let Some(macro_item) = macros.get(selected)
+                {
+                    return Some(Action::LoadObjectAcl {
+                        kind: KnowledgeObjectKind::Macro,
+                        name: macro_item.name.clone(),
+                    });
+                }
+                None
+            }
 
             // Navigation
             KeyCode::Down | KeyCode::Char('j') => {
@@ -0,0 +1,30 @@
+//! Hosts & Sources screen input handler.
+//!
+//! Responsibilities:
+//! - Handle Ctrl+C or 'y' copy of the selected host or source (vim-style)
+//!
+//! Does NOT handle:
+//! - Does NOT handle refresh, toggle, or navigation (handled by keymap)
+//! - Does NOT render the UI (handled by render module)
+//! - Does NOT fetch hosts or sources (handled by actions)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::app::input::helpers::{handle_copy_with_toast, is_copy_key};
+use crossterm::event::KeyEvent;
+
+impl App {
+    /// Handle input for the hosts & sources screen.
+    pub fn handle_metadata_input(&mut self, key: KeyEvent) -> Option<Action> {
+        if is_copy_key(key) {
+            let content = self.metadata_entries.as_ref().and_then(|entries| {
+                self.metadata_state
+                    .selected()
+                    .and_then(|i| entries.get(i))
+                    .map(|entry| entry.name.clone())
+            });
+            return handle_copy_with_toast(self, content);
+        }
+        None
+    }
+}
@@ -21,6 +21,7 @@ pub mod forwarders;
 pub mod health;
 pub mod helpers;
 pub mod indexes;
+pub mod ingestion;
 pub mod inputs;
 pub mod internal_logs;
 pub mod job_inspect;
@@ -29,14 +30,17 @@ pub mod kvstore;
 pub mod license;
 pub mod lookups;
 pub mod macros;
+pub mod metadata;
 pub mod multi_instance;
 pub mod overview;
+pub mod rest;
 pub mod roles;
 pub mod saved_searches;
 pub mod search;
 pub mod search_peers;
 pub mod settings;
 pub mod shc;
+pub mod sourcetypes;
 pub mod users;
 pub mod workload;
 
@@ -65,6 +69,10 @@ impl App {
             CurrentScreen::Roles => self.handle_roles_input(key),
             CurrentScreen::SearchPeers => self.handle_search_peers_input(key),
             CurrentScreen::Inputs => self.handle_inputs_input(key),
+            CurrentScreen::Ingestion => self.handle_ingestion_input(key),
+            CurrentScreen::Sourcetypes => self.handle_sourcetypes_input(key),
+            CurrentScreen::Metadata => self.handle_metadata_input(key),
+            CurrentScreen::Rest => self.handle_rest_input(key),
             CurrentScreen::Configs => self.handle_configs_input(key),
             CurrentScreen::Settings => self.handle_settings_input(key),
             CurrentScreen::Overview => self.handle_overview_input(key),
@@ -0,0 +1,38 @@
+//! REST explorer screen input handler.
+//!
+//! Responsibilities:
+//! - Handle Enter to resend and 'e' to edit the selected history entry
+//! - Handle Ctrl+C or 'y' copy of the response body (vim-style)
+//!
+//! Does NOT handle:
+//! - Does NOT handle new requests, scrolling, or navigation (handled by keymap)
+//! - Does NOT render the UI (handled by render module)
+//! - Does NOT send requests (handled by actions)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::app::input::helpers::{handle_copy_with_toast, is_copy_key};
+use crossterm::event::{KeyCode, KeyEvent};
+
+impl App {
+    /// Handle input for the REST explorer screen.
+    pub fn handle_rest_input(&mut self, key: KeyEvent) -> Option<Action> {
+        if is_copy_key(key) {
+            let content = self
+                .rest_response
+                .as_ref()
+                .map(|response| response.body.clone());
+            return handle_copy_with_toast(self, content);
+        }
+
+        let line = self.selected_rest_request().cloned()?;
+        match key.code {
+            KeyCode::Enter => self.send_rest_request(&line),
+            KeyCode::Char('e') => {
+                self.open_rest_request_popup(line);
+                None
+            }
+            _ => None,
+        }
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! Responsibilities:
 //! - Handle Ctrl+C or 'y' copy of selected role name (vim-style)
-//! - Handle Ctrl+E export of roles list
+//! - Handle Ctrl+E export of roles list (or the capability matrix in matrix view)
 //! - Handle 'c' to open create role dialog
 //! - Handle 'm' to open modify role dialog
 //! - Handle 'd' to open delete role confirmation
@@ -18,6 +18,7 @@ use crate::app::export::ExportTarget;
 use crate::app::input::helpers::{
     handle_copy_with_toast, handle_list_export, is_copy_key, is_export_key, should_export_list,
 };
+use crate::app::state::RolesViewMode;
 use crate::ui::Toast;
 use crossterm::event::{KeyCode, KeyEvent};
 
@@ -37,6 +38,13 @@ impl App {
 
         match key.code {
             KeyCode::Char('e') if is_export_key(key) => {
+                if self.roles_view_mode == RolesViewMode::Matrix {
+                    let can_export = self
+                        .role_matrix
+                        .as_ref()
+                        .is_some_and(|m| !m.capabilities.is_empty());
+                    return handle_list_export(self, can_export, ExportTarget::RoleMatrix);
+                }
                 let can_export = should_export_list(self.roles.as_ref());
                 handle_list_export(self, can_export, ExportTarget::Roles)
             }
@@ -8,6 +8,9 @@
 //! - Handle 'n' key to create a new saved search
 //! - Handle 'd' key to delete selected saved search
 //! - Handle 't' key to toggle enabled/disabled state
+//! - Handle 'p' key to open the permissions popup
+//! - Handle Space to select saved searches for bulk operations
+//! - Handle 'E'/'D'/'O' keys to enable, disable, or reassign the selection
 //!
 //! Does NOT handle:
 //! - Does NOT handle global navigation (handled by keymap)
@@ -22,8 +25,11 @@ use crate::app::input::helpers::{
 };
 use crate::app::state::CurrentScreen;
 use crate::ui::Toast;
+use crate::ui::popup::{Popup, PopupType};
 use crossterm::event::{KeyCode, KeyEvent};
 use splunk_client::SearchMode;
+use splunk_client::models::KnowledgeObjectKind;
+use splunk_client::workflows::saved_search_bulk::SavedSearchBulkAction;
 
 impl App {
     /// Handle input for the saved searches screen.
@@ -61,6 +67,7 @@ impl App {
                         search_defaults: self.search_defaults.clone(),
                         search_mode: SearchMode::Normal,
                         realtime_window: None,
+                        finalize_after: self.search_finalize_after(),
                     });
                 }
                 None
@@ -98,7 +105,75 @@ impl App {
                 }
                 None
             }
+            KeyCode::Char('p') => {
+                if let Some(search) = self.saved_searches.as_ref().and_then(|searches| {
+                    self.saved_searches_state
+                        .selected()
+                        .and_then(|i| searches.get(i))
+                }) {
+                    return Some(Action::LoadObjectAcl {
+                        kind: KnowledgeObjectKind::SavedSearch,
+                        name: search.name.clone(),
+                    });
+                }
+                self.toasts.push(Toast::info("No saved search selected"));
+                None
+            }
+            KeyCode::Char(' ') => {
+                if let Some(name) = self.selected_saved_search_name()
+                    && !self.selected_saved_searches.remove(&name)
+                {
+                    self.selected_saved_searches.insert(name);
+                }
+                None
+            }
+            KeyCode::Char('E') => self.bulk_saved_search_action(SavedSearchBulkAction::Enable),
+            KeyCode::Char('D') => self.bulk_saved_search_action(SavedSearchBulkAction::Disable),
+            KeyCode::Char('O') => {
+                let names = self.bulk_saved_search_targets();
+                if names.is_empty() {
+                    self.toasts.push(Toast::info("No saved search selected"));
+                } else {
+                    self.popup = Some(
+                        Popup::builder(PopupType::ReassignSavedSearches {
+                            names,
+                            owner_input: String::new(),
+                        })
+                        .build(),
+                    );
+                }
+                None
+            }
             _ => None,
         }
     }
+
+    fn selected_saved_search_name(&self) -> Option<String> {
+        self.saved_searches.as_ref().and_then(|searches| {
+            self.saved_searches_state
+                .selected()
+                .and_then(|i| searches.get(i))
+                .map(|s| s.name.clone())
+        })
+    }
+
+    /// Names targeted by a bulk operation: the multi-selection (sorted), or the
+    /// highlighted saved search when nothing is selected.
+    fn bulk_saved_search_targets(&self) -> Vec<String> {
+        if self.selected_saved_searches.is_empty() {
+            return self.selected_saved_search_name().into_iter().collect();
+        }
+        let mut names: Vec<String> = self.selected_saved_searches.iter().cloned().collect();
+        names.sort();
+        names
+    }
+
+    fn bulk_saved_search_action(&mut self, action: SavedSearchBulkAction) -> Option<Action> {
+        let names = self.bulk_saved_search_targets();
+        if names.is_empty() {
+            self.toasts.push(Toast::info("No saved search selected"));
+            return None;
+        }
+        Some(Action::BulkUpdateSavedSearches { names, action })
+    }
 }
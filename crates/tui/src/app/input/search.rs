@@ -3,8 +3,13 @@
 //! Responsibilities:
 //! - Handle query input and editing (QueryFocused mode)
 //! - Handle result navigation (ResultsFocused mode)
+//! - Handle the JSON path result filter box
 //! - Handle search history navigation
+//! - Toggle real-time mode and auto-finalize
+//! - Open the search time range picker (Ctrl+W)
+//! - Reject searches with invalid time modifiers before submitting
 //! - Handle Ctrl+C copy from results
+//! - Handle 'T' to extend the artifact TTL of the search job (ResultsFocused mode)
 //! - Trigger SPL validation on input changes (debounced)
 //!
 //! Does NOT handle:
@@ -17,6 +22,10 @@ use crate::app::App;
 use crate::app::export::ExportTarget;
 use crate::app::input::helpers::{handle_copy_with_toast, is_copy_key};
 use crate::app::state::SearchInputMode;
+use crate::app::template_form::TemplateForm;
+use crate::app::time_range::{TimeRange, TimeRangeTarget, validate_search_time};
+use crate::ui::popup::{Popup, PopupType};
+use crate::ui::screens::job_details::TTL_EXTENSION_SECS;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use splunk_client::SearchMode;
 use splunk_config::constants::DEFAULT_VALIDATION_DEBOUNCE_MS;
@@ -31,7 +40,7 @@ impl App {
     /// Called whenever the search input changes. Sets up the validation
     /// pending flag and timestamp so the tick handler can dispatch
     /// the actual validation request after the debounce delay.
-    fn trigger_validation(&mut self) {
+    pub(crate) fn trigger_validation(&mut self) {
         // Reset validation state for new input
         self.spl_validation_pending = true;
         self.last_input_change = Some(Instant::now());
@@ -104,12 +113,10 @@ impl App {
         // Decision:
         // - If results exist, copy the JSON for the "current" result (at scroll offset).
         // - Otherwise, copy the current search query.
-        let content = if !self.search_results.is_empty() {
-            let idx = self
-                .search_scroll_offset
-                .min(self.search_results.len().saturating_sub(1));
-            self.search_results
-                .get(idx)
+        let visible = self.visible_search_result_count();
+        let content = if visible > 0 {
+            let idx = self.search_scroll_offset.min(visible.saturating_sub(1));
+            self.visible_search_result(idx)
                 .and_then(|v| serde_json::to_string_pretty(v).ok())
                 .unwrap_or_else(|| "<invalid>".to_string())
         } else {
@@ -145,6 +152,19 @@ impl App {
             KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 self.begin_search_export()
             }
+            KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.insert_next_search_template()
+            }
+            KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.toggle_auto_finalize()
+            }
+            KeyCode::Char('x') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.preview_macro_expansion()
+            }
+            KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.open_time_range_picker(TimeRangeTarget::Search);
+                None
+            }
             _ => {
                 // For all other keys, use tui-input's InputRequest handling
                 // This handles: character input, backspace, delete, cursor movement
@@ -157,19 +177,100 @@ impl App {
     }
 
     /// Handle input when in ResultsFocused mode.
-    fn handle_search_results_focused(&mut self, _key: KeyEvent) -> Option<Action> {
-        // In ResultsFocused mode, navigation keys are handled by global bindings
-        // We just return None here and let the global bindings handle navigation
+    fn handle_search_results_focused(&mut self, key: KeyEvent) -> Option<Action> {
+        // In ResultsFocused mode, navigation keys are handled by global bindings.
+        // Only the result filter box, the result detail and top-values popups and the chart
+        // and compare views are opened here, plus the search job's TTL extension.
+        if key.code == KeyCode::Char('T') {
+            return self.extend_search_job_ttl();
+        }
+        if key.modifiers.is_empty() {
+            match key.code {
+                KeyCode::Char('/') => self.enter_result_filter_mode(),
+                KeyCode::Enter => self.open_result_detail(),
+                KeyCode::Char('v') => self.toggle_search_chart_view(),
+                KeyCode::Char('c') => self.toggle_search_compare_view(),
+                KeyCode::Char('t') => self.open_top_values(),
+                _ => {}
+            }
+        }
         None
     }
 
+    /// Extend the artifact TTL of the job behind the loaded results.
+    fn extend_search_job_ttl(&mut self) -> Option<Action> {
+        let Some(sid) = self.search_sid.clone() else {
+            self.push_info_toast_once("No search job to extend");
+            return None;
+        };
+        Some(Action::ExtendJobTtl {
+            sid,
+            ttl_secs: TTL_EXTENSION_SECS,
+        })
+    }
+
+    /// Open the result filter box, pre-populated with the active expression.
+    fn enter_result_filter_mode(&mut self) {
+        let current = self
+            .result_filter
+            .as_ref()
+            .map(|filter| filter.source().to_string())
+            .unwrap_or_default();
+        self.is_result_filtering = true;
+        self.result_filter_before_edit = Some(current.clone());
+        self.result_filter_input.set_value(current);
+    }
+
+    /// Handle keyboard input while editing the result filter.
+    ///
+    /// Enter compiles and applies the expression (an empty expression clears the
+    /// filter); Esc cancels the edit and keeps the previously applied filter.
+    pub(crate) fn handle_result_filter_input(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Esc => {
+                self.is_result_filtering = false;
+                if let Some(saved) = self.result_filter_before_edit.take() {
+                    self.result_filter_input.set_value(saved);
+                }
+                None
+            }
+            KeyCode::Enter => {
+                let expression = self.result_filter_input.value().trim().to_string();
+                if expression.is_empty() {
+                    self.clear_result_filter();
+                } else if let Err(error) = self.apply_result_filter(&expression) {
+                    // Keep the box open so the expression can be corrected
+                    self.toasts.push(crate::ui::Toast::error(error));
+                    return None;
+                }
+                self.is_result_filtering = false;
+                self.result_filter_before_edit = None;
+                None
+            }
+            _ => {
+                self.result_filter_input.handle_key(key);
+                None
+            }
+        }
+    }
+
     /// Execute the search with current query.
-    fn execute_search(&mut self) -> Option<Action> {
+    pub(crate) fn execute_search(&mut self) -> Option<Action> {
         if self.search_input.is_empty() {
             return None;
         }
 
         let query = self.search_input.value().to_string();
+        let range = TimeRange::new(
+            self.search_defaults.earliest_time.clone(),
+            self.search_defaults.latest_time.clone(),
+        );
+        if let Err(error) = validate_search_time(&query, &range, &chrono::Local::now()) {
+            // Stay in the query box so the time modifier can be fixed
+            self.search_status = error.clone();
+            self.toasts.push(crate::ui::Toast::error(error));
+            return None;
+        }
         self.add_to_history(query.clone());
         self.search_status = format!("Running: {}", query);
         // Switch to ResultsFocused after running search
@@ -179,9 +280,29 @@ impl App {
             search_defaults: self.search_defaults.clone(),
             search_mode: self.search_mode,
             realtime_window: self.realtime_window,
+            finalize_after: self.search_finalize_after(),
         })
     }
 
+    /// Result threshold at which searches are finalized, when auto-finalize is on.
+    ///
+    /// Uses the configured `max_results`, since results past it are not fetched anyway.
+    pub(crate) fn search_finalize_after(&self) -> Option<usize> {
+        self.auto_finalize
+            .then_some(self.search_defaults.max_results)
+    }
+
+    /// Toggle finalizing searches early once enough results are available.
+    fn toggle_auto_finalize(&mut self) -> Option<Action> {
+        self.auto_finalize = !self.auto_finalize;
+        let message = match self.search_finalize_after() {
+            Some(threshold) => format!("Auto-finalize: on (after {} results)", threshold),
+            None => "Auto-finalize: off".to_string(),
+        };
+        self.toasts.push(crate::ui::Toast::info(message));
+        None
+    }
+
     /// Toggle search mode between Normal and Realtime.
     fn toggle_search_mode(&mut self) -> Option<Action> {
         self.search_mode = match self.search_mode {
@@ -197,6 +318,40 @@ impl App {
         None
     }
 
+    /// Replace the query with the next search template, cycling through the list.
+    ///
+    /// Templates with parameters open a form generated from their schema instead.
+    fn insert_next_search_template(&mut self) -> Option<Action> {
+        if self.search_templates.is_empty() {
+            self.toasts.push(crate::ui::Toast::info(
+                "No search templates (run 'splunk-cli templates save' or 'config templates import')",
+            ));
+            return None;
+        }
+
+        let next = self
+            .search_template_index
+            .map_or(0, |idx| (idx + 1) % self.search_templates.len());
+        self.search_template_index = Some(next);
+        self.history_index = None;
+
+        let template = &self.search_templates[next];
+        if template.is_parameterized() {
+            self.template_form = Some(TemplateForm::new(template.clone()));
+            self.popup = Some(Popup::builder(PopupType::TemplateParameters).build());
+            return None;
+        }
+        self.search_input.set_value(template.query.clone());
+        self.toasts.push(crate::ui::Toast::info(format!(
+            "Template {}/{}: {}",
+            next + 1,
+            self.search_templates.len(),
+            template.name
+        )));
+        self.trigger_validation();
+        None
+    }
+
     /// Navigate forward in search history (towards newer queries).
     fn navigate_search_history_forward(&mut self) -> Option<Action> {
         if let Some(curr) = self.history_index {
@@ -239,6 +394,19 @@ impl App {
     }
 
     /// Begin export of search results.
+    /// Request the query's macro expansion preview, if it invokes any macros.
+    fn preview_macro_expansion(&mut self) -> Option<Action> {
+        let query = self.search_input.value();
+        if !query.contains('`') {
+            self.toasts
+                .push(crate::ui::Toast::info("No macros in query".to_string()));
+            return None;
+        }
+        Some(Action::LoadMacroExpansion {
+            query: query.to_string(),
+        })
+    }
+
     fn begin_search_export(&mut self) -> Option<Action> {
         if self.search_results.is_empty() {
             return None;
@@ -251,7 +419,7 @@ impl App {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::app::ConnectionContext;
+    use crate::app::{ConnectionContext, CurrentScreen};
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
     fn key(c: char) -> KeyEvent {
@@ -300,6 +468,22 @@ mod tests {
         assert_eq!(app.search_input.cursor_position(), 5);
     }
 
+    #[test]
+    fn test_ctrl_x_requests_macro_expansion() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.search_input.set_value("index=main");
+
+        assert!(app.handle_search_input(ctrl_key('x')).is_none());
+        assert_eq!(app.search_input.value(), "index=main");
+        assert_eq!(app.toasts.len(), 1);
+
+        app.search_input.set_value("`web` | head 5");
+        let action = app.handle_search_input(ctrl_key('x'));
+        assert!(
+            matches!(action, Some(Action::LoadMacroExpansion { query }) if query == "`web` | head 5")
+        );
+    }
+
     #[test]
     fn test_search_input_cursor_movement() {
         let mut app = App::new(None, ConnectionContext::default());
@@ -406,6 +590,34 @@ mod tests {
         assert_eq!(app.search_input.value(), "current");
     }
 
+    #[test]
+    fn test_ctrl_t_cycles_team_search_templates() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.search_templates = vec![
+            splunk_config::SearchTemplate {
+                name: "Errors".to_string(),
+                query: "index=main log_level=ERROR".to_string(),
+                description: None,
+                parameters: Vec::new(),
+            },
+            splunk_config::SearchTemplate {
+                name: "Logins".to_string(),
+                query: "index=_audit action=login".to_string(),
+                description: None,
+                parameters: Vec::new(),
+            },
+        ];
+
+        app.handle_search_input(ctrl_key('t'));
+        assert_eq!(app.search_input.value(), "index=main log_level=ERROR");
+
+        app.handle_search_input(ctrl_key('t'));
+        assert_eq!(app.search_input.value(), "index=_audit action=login");
+
+        app.handle_search_input(ctrl_key('t'));
+        assert_eq!(app.search_input.value(), "index=main log_level=ERROR");
+    }
+
     #[test]
     fn test_ctrl_r_toggles_search_mode() {
         let mut app = App::new(None, ConnectionContext::default());
@@ -418,6 +630,31 @@ mod tests {
         assert!(matches!(app.search_mode, SearchMode::Normal));
     }
 
+    #[test]
+    fn test_ctrl_f_toggles_auto_finalize() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.search_defaults.max_results = 250;
+        app.search_input.set_value("index=main");
+
+        app.handle_search_input(ctrl_key('f'));
+        assert!(app.auto_finalize);
+        assert_eq!(app.search_input.value(), "index=main");
+
+        let action = app.execute_search();
+        assert!(matches!(
+            action,
+            Some(Action::RunSearch {
+                finalize_after: Some(250),
+                ..
+            })
+        ));
+
+        app.search_input_mode = SearchInputMode::QueryFocused;
+        app.handle_search_input(ctrl_key('f'));
+        assert!(!app.auto_finalize);
+        assert_eq!(app.search_finalize_after(), None);
+    }
+
     #[test]
     fn test_tab_returns_none_for_global_keymap() {
         // Tab now returns None so the global keymap can handle NextScreen action
@@ -793,4 +1030,76 @@ mod tests {
 
         assert!(action.is_none());
     }
+
+    #[test]
+    fn test_result_filter_box_applies_and_cancels() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.current_screen = CurrentScreen::Search;
+        app.search_input_mode = SearchInputMode::ResultsFocused;
+        app.set_search_results(vec![
+            serde_json::json!({"payload": {"user": {"id": 1}}}),
+            serde_json::json!({"payload": {"user": {"id": 2}}}),
+        ]);
+
+        app.handle_input(key('/'));
+        assert!(app.is_result_filtering);
+        for c in "payload.user.id == 2".chars() {
+            app.handle_input(key(c));
+        }
+        app.handle_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(!app.is_result_filtering);
+        assert_eq!(app.visible_search_result_count(), 1);
+
+        // Invalid expressions keep the box open; Esc restores the applied filter
+        app.handle_input(key('/'));
+        app.result_filter_input.set_value("payload[");
+        app.handle_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(app.is_result_filtering);
+        app.handle_input(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(!app.is_result_filtering);
+        assert_eq!(
+            app.result_filter.as_ref().map(|f| f.source()),
+            Some("payload.user.id == 2")
+        );
+    }
+
+    #[test]
+    fn test_v_toggles_chart_view_for_timechart_results() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.current_screen = CurrentScreen::Search;
+        app.search_input_mode = SearchInputMode::ResultsFocused;
+        app.set_search_results(vec![
+            serde_json::json!({"_time": "2026-01-01T00:00:00Z", "count": "3"}),
+            serde_json::json!({"_time": "2026-01-01T01:00:00Z", "count": "5"}),
+        ]);
+
+        app.handle_input(key('v'));
+        assert!(app.search_chart_view);
+        assert!(app.search_chart.is_some());
+
+        app.handle_input(key('v'));
+        assert!(!app.search_chart_view);
+        assert!(app.search_chart.is_none());
+    }
+
+    #[test]
+    fn test_shift_t_extends_search_job_ttl() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.current_screen = CurrentScreen::Search;
+        app.search_input_mode = SearchInputMode::ResultsFocused;
+        let shift_t = KeyEvent::new(KeyCode::Char('T'), KeyModifiers::SHIFT);
+
+        assert!(app.handle_input(shift_t).is_none());
+
+        app.search_sid = Some("search_123".to_string());
+        let action = app.handle_input(shift_t);
+        assert!(
+            matches!(
+                &action,
+                Some(Action::ExtendJobTtl { sid, ttl_secs: 86_400 }) if sid == "search_123"
+            ),
+            "T should extend the search job's TTL, got {:?}",
+            action
+        );
+    }
 }
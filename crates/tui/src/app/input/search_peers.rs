@@ -3,17 +3,20 @@
 //! Responsibilities:
 //! - Handle keyboard input for the search peers screen
 //! - Dispatch actions based on key presses
+//! - Open add, remove, and quarantine popups for search peers
 //!
 //! Does NOT handle:
 //! - Rendering (handled by screen module)
 //! - Data fetching (handled by side effects)
 
 use crossterm::event::{KeyCode, KeyEvent};
+use splunk_client::models::{SearchPeer, SearchPeerStatus};
 
 use crate::action::Action;
 use crate::app::App;
 use crate::app::export::ExportTarget;
 use crate::app::input::helpers::{handle_list_export, is_export_key, should_export_list};
+use crate::ui::popup::{Popup, PopupType, SearchPeerField};
 
 impl App {
     /// Handle keyboard input for the search peers screen.
@@ -72,7 +75,112 @@ impl App {
                 Some(Action::LoadMoreSearchPeers)
             }
 
+            // Add a search peer
+            KeyCode::Char('a') => {
+                self.popup = Some(
+                    Popup::builder(PopupType::AddSearchPeer {
+                        uri_input: String::new(),
+                        username_input: String::new(),
+                        password_input: String::new(),
+                        selected_field: SearchPeerField::Uri,
+                    })
+                    .build(),
+                );
+                None
+            }
+
+            // Remove the selected search peer
+            KeyCode::Char('x') => {
+                if let Some(peer) = self.selected_search_peer() {
+                    self.popup = Some(
+                        Popup::builder(PopupType::ConfirmRemoveSearchPeer(peer.name.clone()))
+                            .build(),
+                    );
+                }
+                None
+            }
+
+            // Quarantine or unquarantine the selected search peer
+            KeyCode::Char('Q') => {
+                if let Some(peer) = self.selected_search_peer() {
+                    self.popup = Some(
+                        Popup::builder(PopupType::ConfirmQuarantineSearchPeer {
+                            name: peer.name.clone(),
+                            quarantine: peer.status != SearchPeerStatus::Quarantined,
+                        })
+                        .build(),
+                    );
+                }
+                None
+            }
+
             _ => None,
         }
     }
+
+    fn selected_search_peer(&self) -> Option<&SearchPeer> {
+        let peers = self.search_peers.as_ref()?;
+        peers.get(self.search_peers_state.selected()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crossterm::event::KeyModifiers;
+
+    fn peer(name: &str, status: SearchPeerStatus) -> SearchPeer {
+        SearchPeer {
+            name: name.to_string(),
+            host: "idx1".to_string(),
+            port: 8089,
+            status,
+            version: None,
+            guid: None,
+            last_connected: None,
+            disabled: None,
+        }
+    }
+
+    #[test]
+    fn test_quarantine_key_toggles_by_peer_status() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.search_peers = Some(vec![
+            peer("idx1:8089", SearchPeerStatus::Up),
+            peer("idx2:8089", SearchPeerStatus::Quarantined),
+        ]);
+        app.search_peers_state.select(Some(0));
+        let shift_q = KeyEvent::new(KeyCode::Char('Q'), KeyModifiers::SHIFT);
+
+        app.handle_search_peers_input(shift_q);
+        assert_eq!(
+            app.popup.as_ref().map(|p| &p.kind),
+            Some(&PopupType::ConfirmQuarantineSearchPeer {
+                name: "idx1:8089".to_string(),
+                quarantine: true,
+            })
+        );
+
+        app.search_peers_state.select(Some(1));
+        app.handle_search_peers_input(shift_q);
+        let action = app.handle_popup_input(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+        assert!(matches!(
+            action,
+            Some(Action::SetSearchPeerQuarantine { ref name, quarantine: false })
+                if name == "idx2:8089"
+        ));
+    }
+
+    #[test]
+    fn test_remove_key_confirms_before_removing() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.search_peers = Some(vec![peer("idx1:8089", SearchPeerStatus::Up)]);
+        app.search_peers_state.select(Some(0));
+
+        app.handle_search_peers_input(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE));
+        let action = app.handle_popup_input(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+
+        assert!(matches!(action, Some(Action::RemoveSearchPeer(ref name)) if name == "idx1:8089"));
+    }
 }
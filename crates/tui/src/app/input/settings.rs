@@ -5,6 +5,12 @@
 //! - Handle 's' key to cycle sort column
 //! - Handle 'd' key to toggle sort direction
 //! - Handle 'c' key to clear search history
+//! - Handle 'v' key to toggle sourcetype result renderers
+//! - Handle 'h'/'m'/'g' keys to adjust internal logs anomaly highlighting
+//! - Handle 'C' key to toggle no-color mode
+//! - Handle 'b' key to cycle the clipboard backend
+//! - Handle 'N' key to cycle the notification target
+//! - Handle 'H' key to edit the health check thresholds
 //!
 //! Does NOT handle:
 //! - Does NOT handle global navigation (handled by keymap)
@@ -13,10 +19,12 @@
 
 use crate::action::Action;
 use crate::app::App;
+use crate::app::notifier;
 // Note: SortColumn and SortDirection methods are used but the types themselves
 // are not directly referenced in this file (used through self.sort_state)
 use crate::ui::Toast;
 use crossterm::event::{KeyCode, KeyEvent};
+use splunk_config::NotificationTarget;
 
 impl App {
     /// Handle input for the settings screen.
@@ -26,11 +34,22 @@ impl App {
             KeyCode::Char('s') => self.cycle_sort_column(),
             KeyCode::Char('d') => self.toggle_sort_direction(),
             KeyCode::Char('c') => self.clear_search_history(),
+            KeyCode::Char('v') => self.toggle_result_renderers(),
+            KeyCode::Char('h') => self.toggle_log_anomalies(),
+            KeyCode::Char('m') => self.cycle_log_spike_multiplier(),
+            KeyCode::Char('g') => self.cycle_log_repeat_threshold(),
             KeyCode::Char('e') => self.open_edit_profile(),
             KeyCode::Char('x') => self.open_delete_profile(),
             KeyCode::Char('u') => self.show_undo_history(),
             KeyCode::Char('?') => Some(Action::StartTutorial { is_replay: true }),
             KeyCode::Char('T') => Some(Action::CycleTheme),
+            KeyCode::Char('C') => {
+                self.toggle_no_color();
+                None
+            }
+            KeyCode::Char('b') => self.cycle_clipboard_backend(),
+            KeyCode::Char('N') => self.cycle_notification_target(),
+            KeyCode::Char('H') => self.open_health_thresholds(),
             _ => None,
         }
     }
@@ -45,6 +64,95 @@ impl App {
         None
     }
 
+    /// Cycle how copy actions deliver text (Auto, native, OSC52, temp file).
+    fn cycle_clipboard_backend(&mut self) -> Option<Action> {
+        self.clipboard_backend = self.clipboard_backend.cycle_next();
+        self.toasts.push(Toast::info(format!(
+            "Clipboard: {}",
+            self.clipboard_backend
+        )));
+        None
+    }
+
+    /// Cycle where notifications go (Off, Desktop, Command), skipping targets
+    /// this build or configuration cannot deliver to.
+    fn cycle_notification_target(&mut self) -> Option<Action> {
+        let mut target = self.notifications.target.cycle_next();
+        while !notifier::is_available(&self.notifications, target) {
+            target = target.cycle_next();
+        }
+        self.notifications.target = target;
+        if target == NotificationTarget::Off
+            && !notifier::DESKTOP_AVAILABLE
+            && self.notifications.command().is_none()
+        {
+            self.toasts.push(Toast::warning(
+                "Notifications: Off (set notifications.command in the config file \
+                 or build with --features notify)",
+            ));
+        } else {
+            self.toasts
+                .push(Toast::info(format!("Notifications: {}", target)));
+        }
+        None
+    }
+
+    /// Toggle sourcetype-specific renderers in the result detail popup.
+    fn toggle_result_renderers(&mut self) -> Option<Action> {
+        self.result_renderers = !self.result_renderers;
+        self.toasts.push(Toast::info(format!(
+            "Result renderers: {}",
+            if self.result_renderers { "On" } else { "Off" }
+        )));
+        None
+    }
+
+    /// Toggle anomaly highlighting in the internal logs view.
+    fn toggle_log_anomalies(&mut self) -> Option<Action> {
+        let anomalies = &mut self.internal_logs_defaults.anomalies;
+        anomalies.enabled = !anomalies.enabled;
+        self.toasts.push(Toast::info(format!(
+            "Internal log highlights: {}",
+            if anomalies.enabled { "On" } else { "Off" }
+        )));
+        self.reanalyze_internal_logs();
+        None
+    }
+
+    /// Cycle the error/warning spike multiplier through common presets.
+    fn cycle_log_spike_multiplier(&mut self) -> Option<Action> {
+        const PRESETS: [f64; 4] = [1.5, 2.0, 3.0, 5.0];
+        let anomalies = &mut self.internal_logs_defaults.anomalies;
+        anomalies.spike_multiplier = PRESETS
+            .iter()
+            .copied()
+            .find(|&preset| preset > anomalies.spike_multiplier)
+            .unwrap_or(PRESETS[0]);
+        self.toasts.push(Toast::info(format!(
+            "Log spike threshold: {}x baseline",
+            anomalies.spike_multiplier
+        )));
+        self.reanalyze_internal_logs();
+        None
+    }
+
+    /// Cycle the repeated-message threshold through common presets.
+    fn cycle_log_repeat_threshold(&mut self) -> Option<Action> {
+        const PRESETS: [usize; 4] = [2, 3, 5, 10];
+        let anomalies = &mut self.internal_logs_defaults.anomalies;
+        anomalies.repeat_threshold = PRESETS
+            .iter()
+            .copied()
+            .find(|&preset| preset > anomalies.repeat_threshold)
+            .unwrap_or(PRESETS[0]);
+        self.toasts.push(Toast::info(format!(
+            "Log repeat threshold: x{}",
+            anomalies.repeat_threshold
+        )));
+        self.reanalyze_internal_logs();
+        None
+    }
+
     /// Cycle to the next sort column.
     fn cycle_sort_column(&mut self) -> Option<Action> {
         self.sort_state.column = self.sort_state.column.next();
@@ -113,6 +221,7 @@ mod tests {
     use crate::app::ConnectionContext;
     use crate::app::state::{SortColumn, SortDirection};
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use splunk_config::ClipboardBackend;
 
     fn create_test_app() -> App {
         App::new(None, ConnectionContext::default())
@@ -133,6 +242,75 @@ mod tests {
         assert_eq!(app.toasts.len(), 1);
     }
 
+    #[test]
+    fn test_toggle_result_renderers() {
+        let mut app = create_test_app();
+        assert!(app.result_renderers);
+
+        app.handle_settings_input(key('v'));
+
+        assert!(!app.result_renderers);
+        assert_eq!(app.toasts.len(), 1);
+    }
+
+    #[test]
+    fn test_cycle_notification_target_skips_unavailable_targets() {
+        let mut app = create_test_app();
+        app.handle_settings_input(key('N'));
+        let expected = if notifier::DESKTOP_AVAILABLE {
+            NotificationTarget::Desktop
+        } else {
+            NotificationTarget::Off
+        };
+        assert_eq!(app.notifications.target, expected);
+
+        app.notifications.target = NotificationTarget::Off;
+        app.notifications.command = Some("true".to_string());
+        app.handle_settings_input(key('N'));
+        if notifier::DESKTOP_AVAILABLE {
+            app.handle_settings_input(key('N'));
+        }
+        assert_eq!(app.notifications.target, NotificationTarget::Command);
+        app.handle_settings_input(key('N'));
+        assert_eq!(app.notifications.target, NotificationTarget::Off);
+    }
+
+    #[test]
+    fn test_cycle_clipboard_backend() {
+        let mut app = create_test_app();
+        assert_eq!(app.clipboard_backend, ClipboardBackend::Auto);
+
+        app.handle_settings_input(key('b'));
+
+        assert_eq!(app.clipboard_backend, ClipboardBackend::Native);
+        assert_eq!(
+            app.get_persisted_state().clipboard_backend,
+            ClipboardBackend::Native
+        );
+        assert_eq!(app.toasts.len(), 1);
+    }
+
+    #[test]
+    fn test_log_anomaly_settings_keys() {
+        let mut app = create_test_app();
+        assert!(app.internal_logs_defaults.anomalies.enabled);
+
+        app.handle_settings_input(key('h'));
+        assert!(!app.internal_logs_defaults.anomalies.enabled);
+
+        app.handle_settings_input(key('m'));
+        assert_eq!(app.internal_logs_defaults.anomalies.spike_multiplier, 3.0);
+        app.handle_settings_input(key('m'));
+        app.handle_settings_input(key('m'));
+        assert_eq!(app.internal_logs_defaults.anomalies.spike_multiplier, 1.5);
+
+        app.handle_settings_input(key('g'));
+        assert_eq!(app.internal_logs_defaults.anomalies.repeat_threshold, 10);
+        app.handle_settings_input(key('g'));
+        assert_eq!(app.internal_logs_defaults.anomalies.repeat_threshold, 2);
+        assert_eq!(app.toasts.len(), 6);
+    }
+
     #[test]
     fn test_cycle_sort_column() {
         let mut app = create_test_app();
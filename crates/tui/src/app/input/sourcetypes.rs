@@ -0,0 +1,30 @@
+//! Sourcetypes screen input handler.
+//!
+//! Responsibilities:
+//! - Handle Ctrl+C or 'y' copy of the selected sourcetype (vim-style)
+//!
+//! Does NOT handle:
+//! - Does NOT handle refresh or navigation (handled by keymap)
+//! - Does NOT render the UI (handled by render module)
+//! - Does NOT fetch sourcetypes (handled by actions)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::app::input::helpers::{handle_copy_with_toast, is_copy_key};
+use crossterm::event::KeyEvent;
+
+impl App {
+    /// Handle input for the sourcetypes screen.
+    pub fn handle_sourcetypes_input(&mut self, key: KeyEvent) -> Option<Action> {
+        if is_copy_key(key) {
+            let content = self.sourcetypes.as_ref().and_then(|sourcetypes| {
+                self.sourcetypes_state
+                    .selected()
+                    .and_then(|i| sourcetypes.get(i))
+                    .map(|entry| entry.name.clone())
+            });
+            return handle_copy_with_toast(self, content);
+        }
+        None
+    }
+}
@@ -6,6 +6,8 @@
 //! - Handle 'c' to open create user dialog
 //! - Handle 'm' to open modify user dialog
 //! - Handle 'd' to open delete user confirmation
+//! - Handle 'U' to unlock a locked-out user
+//! - Handle 'o' to scan for knowledge objects owned by deleted users
 //!
 //! Does NOT handle:
 //! - Does NOT handle global navigation (handled by keymap)
@@ -70,6 +72,26 @@ impl App {
                 self.toasts.push(Toast::info("No user selected"));
                 None
             }
+            KeyCode::Char('U') => {
+                // Unlock the selected user if it is locked out
+                let Some(user) = self
+                    .users
+                    .as_ref()
+                    .and_then(|users| self.users_state.selected().and_then(|i| users.get(i)))
+                else {
+                    self.toasts.push(Toast::info("No user selected"));
+                    return None;
+                };
+                if !user.locked_out {
+                    let message = format!("User '{}' is not locked out", user.name);
+                    self.toasts.push(Toast::info(message));
+                    return None;
+                }
+                Some(Action::UnlockUser {
+                    name: user.name.clone(),
+                })
+            }
+            KeyCode::Char('o') => Some(Action::FindOrphanedObjects),
             _ => None,
         }
     }
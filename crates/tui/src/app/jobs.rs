@@ -126,6 +126,8 @@ mod tests {
             disk_usage: 0,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         }
     }
 
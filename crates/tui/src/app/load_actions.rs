@@ -7,6 +7,7 @@
 //! - Build initial load actions for each screen.
 //! - Build follow-up paginated load actions from explicit `LoadMore*` triggers.
 //! - Translate `Refresh*` and `LoadMore*` actions into concrete `Load*` requests.
+//! - Apply the server-side jobs filter to every jobs load.
 //!
 //! Scope:
 //! - Action construction only; this module does not mutate state or execute side effects.
@@ -19,7 +20,7 @@
 
 use crate::action::Action;
 use crate::app::App;
-use crate::app::state::{CurrentScreen, ListPaginationState, WorkloadViewMode};
+use crate::app::state::{CurrentScreen, InputsViewMode, ListPaginationState, WorkloadViewMode};
 
 impl App {
     fn paged_load_action(
@@ -40,13 +41,46 @@ impl App {
             .then(|| build(pagination.page_size, pagination.current_offset))
     }
 
-    fn internal_logs_action(&self) -> Action {
+    /// Build a jobs load, carrying the server-side jobs filter when one is set.
+    pub(crate) fn jobs_load_action(&self, count: usize, offset: usize) -> Action {
+        if self.jobs_server_filter.is_empty() {
+            Action::LoadJobs { count, offset }
+        } else {
+            Action::LoadFilteredJobs {
+                count,
+                offset,
+                filter: self.jobs_server_filter.clone(),
+            }
+        }
+    }
+
+    pub(crate) fn internal_logs_action(&self) -> Action {
         Action::LoadInternalLogs {
             count: self.internal_logs_defaults.count,
             earliest: self.internal_logs_defaults.earliest_time.clone(),
         }
     }
 
+    pub(crate) fn audit_action(&self) -> Action {
+        Action::LoadAuditEvents {
+            count: 50,
+            offset: 0,
+            earliest: self.audit_time_range.earliest.clone(),
+            latest: self.audit_time_range.latest.clone(),
+        }
+    }
+
+    /// Load the inputs list or the HEC tokens, whichever the inputs screen shows.
+    fn inputs_load_action(&self) -> Action {
+        match self.inputs_view_mode {
+            InputsViewMode::Inputs => self
+                .paged_load_action(&self.inputs_pagination, |count, offset| {
+                    Action::LoadInputs { count, offset }
+                }),
+            InputsViewMode::Http => Action::LoadHecTokens,
+        }
+    }
+
     fn workload_initial_action(&self, view_mode: WorkloadViewMode) -> Action {
         match view_mode {
             WorkloadViewMode::Pools => self
@@ -79,11 +113,10 @@ impl App {
                 .paged_load_more_action(&self.indexes_pagination, |count, offset| {
                     Action::LoadIndexes { count, offset }
                 }),
-            Action::LoadMoreJobs => {
-                self.paged_load_more_action(&self.jobs_pagination, |count, offset| {
-                    Action::LoadJobs { count, offset }
-                })
-            }
+            Action::LoadMoreJobs => self
+                .paged_load_more_action(&self.jobs_pagination, |count, offset| {
+                    self.jobs_load_action(count, offset)
+                }),
             Action::LoadMoreApps => {
                 self.paged_load_more_action(&self.apps_pagination, |count, offset| {
                     Action::LoadApps { count, offset }
@@ -143,10 +176,11 @@ impl App {
                     Action::LoadIndexes { count, offset }
                 }),
             ),
-            Action::RefreshJobs => Some(self.paged_load_action(
-                &self.jobs_pagination,
-                |count, offset| Action::LoadJobs { count, offset },
-            )),
+            Action::RefreshJobs => Some(
+                self.paged_load_action(&self.jobs_pagination, |count, offset| {
+                    self.jobs_load_action(count, offset)
+                }),
+            ),
             Action::RefreshApps => Some(self.paged_load_action(
                 &self.apps_pagination,
                 |count, offset| Action::LoadApps { count, offset },
@@ -160,6 +194,7 @@ impl App {
                 |count, offset| Action::LoadRoles { count, offset },
             )),
             Action::RefreshInternalLogs => Some(self.internal_logs_action()),
+            Action::RefreshAuditEvents => Some(self.audit_action()),
             Action::RefreshDashboards => Some(
                 self.paged_load_action(&self.dashboards_pagination, |count, offset| {
                     Action::LoadDashboards { count, offset }
@@ -170,11 +205,10 @@ impl App {
                     Action::LoadDataModels { count, offset }
                 }),
             ),
-            Action::RefreshInputs => Some(
-                self.paged_load_action(&self.inputs_pagination, |count, offset| {
-                    Action::LoadInputs { count, offset }
-                }),
-            ),
+            Action::RefreshInputs => Some(self.inputs_load_action()),
+            Action::RefreshMetadata => Some(Action::LoadMetadata {
+                metadata_type: self.metadata_type,
+            }),
             _ => None,
         }
     }
@@ -190,11 +224,13 @@ impl App {
                 }),
             ),
             CurrentScreen::Cluster => Some(Action::LoadClusterInfo),
-            CurrentScreen::Jobs => Some(self.paged_load_action(
-                &self.jobs_pagination,
-                |count, offset| Action::LoadJobs { count, offset },
-            )),
+            CurrentScreen::Jobs => Some(
+                self.paged_load_action(&self.jobs_pagination, |count, offset| {
+                    self.jobs_load_action(count, offset)
+                }),
+            ),
             CurrentScreen::JobInspect => None,
+            CurrentScreen::Rest => None,
             CurrentScreen::Health => Some(Action::LoadHealth),
             CurrentScreen::License => Some(Action::LoadLicense),
             CurrentScreen::Kvstore => Some(Action::LoadKvstore),
@@ -218,11 +254,12 @@ impl App {
                     Action::LoadSearchPeers { count, offset }
                 }),
             ),
-            CurrentScreen::Inputs => Some(
-                self.paged_load_action(&self.inputs_pagination, |count, offset| {
-                    Action::LoadInputs { count, offset }
-                }),
-            ),
+            CurrentScreen::Inputs => Some(self.inputs_load_action()),
+            CurrentScreen::Ingestion => Some(Action::LoadIngestionStats),
+            CurrentScreen::Sourcetypes => Some(Action::LoadSourcetypes),
+            CurrentScreen::Metadata => Some(Action::LoadMetadata {
+                metadata_type: self.metadata_type,
+            }),
             CurrentScreen::Configs => Some(Action::LoadConfigFiles),
             CurrentScreen::FiredAlerts => Some(
                 self.paged_load_action(&self.fired_alerts_pagination, |count, offset| {
@@ -239,12 +276,7 @@ impl App {
                     Action::LoadLookups { count, offset }
                 }),
             ),
-            CurrentScreen::Audit => Some(Action::LoadAuditEvents {
-                count: 50,
-                offset: 0,
-                earliest: "-24h".to_string(),
-                latest: "now".to_string(),
-            }),
+            CurrentScreen::Audit => Some(self.audit_action()),
             CurrentScreen::Dashboards => Some(
                 self.paged_load_action(&self.dashboards_pagination, |count, offset| {
                     Action::LoadDashboards { count, offset }
@@ -280,6 +312,8 @@ impl App {
                 self.translated_load_more_action(&Action::LoadMoreForwarders)
             }
             CurrentScreen::Lookups => self.translated_load_more_action(&Action::LoadMoreLookups),
+            // HEC tokens are listed in one request; only the inputs list pages.
+            CurrentScreen::Inputs if self.inputs_view_mode == InputsViewMode::Http => None,
             CurrentScreen::Inputs => self.translated_load_more_action(&Action::LoadMoreInputs),
             CurrentScreen::FiredAlerts => {
                 self.translated_load_more_action(&Action::LoadMoreFiredAlerts)
@@ -317,6 +351,12 @@ impl App {
             return translated;
         }
 
+        // Reloads emitted by side effects and the command palette know nothing
+        // about the server-side jobs filter; re-apply it here.
+        if let Action::LoadJobs { count, offset } = action {
+            return self.jobs_load_action(count, offset);
+        }
+
         action
     }
 }
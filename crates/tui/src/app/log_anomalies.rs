@@ -0,0 +1,530 @@
+//! Client-side anomaly highlighting for the internal logs view.
+//!
+//! Responsibilities:
+//! - Collapse identical log messages into one row with an occurrence count
+//! - Run pluggable detectors that flag rows (ERROR/WARN spikes, repeated messages)
+//! - Keep per-minute level counts for the session so refreshes build a baseline
+//! - Leave out rows hidden by the level/component filter (see app::log_follow)
+//!
+//! Does NOT handle:
+//! - Does NOT render rows or pick component colors (handled by ui::screens::internal_logs)
+//! - Does NOT fetch logs (handled by Action::LoadInternalLogs)
+//!
+//! Invariants:
+//! - Row indices point into the analyzed `LogEntry` slice, newest occurrence first
+//! - With highlighting disabled there is exactly one unflagged row per visible entry
+//! - Detectors observe every entry, including those hidden by the filter
+//! - Rows are only used for a batch of the length they were built from; otherwise
+//!   the view falls back to one row per entry
+//! - Detector session state only changes when a batch is observed, not when
+//!   thresholds change, so re-analyzing is idempotent
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::DateTime;
+use splunk_client::models::{LogEntry, LogLevel};
+use splunk_config::LogAnomalySettings;
+
+use crate::app::App;
+use crate::app::log_follow::LogFilter;
+
+/// Minutes of per-minute counts retained for the session baseline (one day).
+const MAX_BASELINE_MINUTES: usize = 24 * 60;
+
+/// Why a row is highlighted.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Anomaly {
+    /// The entry's minute had an unusual number of entries at this level.
+    Spike {
+        level: LogLevel,
+        /// Entries at `level` in that minute.
+        count: usize,
+        /// Session average per minute at `level`, excluding that minute.
+        baseline: f64,
+    },
+    /// The message occurred at least the configured number of times.
+    Repeated,
+}
+
+/// One displayed row: a log message and every occurrence collapsed into it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogRow {
+    /// Indices of the collapsed entries, newest first (never empty).
+    pub indices: Vec<usize>,
+    pub anomalies: Vec<Anomaly>,
+}
+
+impl LogRow {
+    /// Index of the newest occurrence, which the row displays.
+    pub fn index(&self) -> usize {
+        self.indices[0]
+    }
+
+    /// Number of collapsed occurrences.
+    pub fn count(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn is_spike(&self) -> bool {
+        self.anomalies
+            .iter()
+            .any(|a| matches!(a, Anomaly::Spike { .. }))
+    }
+
+    pub fn is_repeated(&self) -> bool {
+        self.anomalies.contains(&Anomaly::Repeated)
+    }
+}
+
+/// A pluggable anomaly detector.
+///
+/// `observe` is called once per loaded batch and may accumulate session state;
+/// `annotate` flags rows of the current batch and must not mutate state.
+pub trait AnomalyDetector: std::fmt::Debug + Send {
+    /// Fold a newly loaded batch into the detector's session state.
+    fn observe(&mut self, _logs: &[LogEntry]) {}
+
+    /// Flag rows of `logs` that this detector considers anomalous.
+    fn annotate(&self, logs: &[LogEntry], rows: &mut [LogRow], settings: &LogAnomalySettings);
+
+    /// Forget session state (e.g. after switching profiles).
+    fn reset(&mut self) {}
+}
+
+/// Flags collapsed messages that occurred at least `repeat_threshold` times.
+#[derive(Debug, Default)]
+pub struct RepeatDetector;
+
+impl AnomalyDetector for RepeatDetector {
+    fn annotate(&self, _logs: &[LogEntry], rows: &mut [LogRow], settings: &LogAnomalySettings) {
+        for row in rows
+            .iter_mut()
+            .filter(|row| row.count() >= settings.repeat_threshold)
+        {
+            row.anomalies.push(Anomaly::Repeated);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct MinuteCounts {
+    errors: usize,
+    warns: usize,
+}
+
+/// Flags ERROR/WARN entries in minutes well above the session's per-minute average.
+#[derive(Debug, Default)]
+pub struct SpikeDetector {
+    /// Session-wide counts keyed by Unix minute.
+    minutes: BTreeMap<i64, MinuteCounts>,
+}
+
+impl SpikeDetector {
+    /// Baseline per minute for one level, excluding `minute` itself.
+    fn baseline(&self, minute: i64, level: fn(&MinuteCounts) -> usize) -> Option<f64> {
+        let others = self.minutes.len().checked_sub(1).filter(|n| *n > 0)?;
+        let total: usize = self
+            .minutes
+            .iter()
+            .filter(|(m, _)| **m != minute)
+            .map(|(_, counts)| level(counts))
+            .sum();
+        Some(total as f64 / others as f64)
+    }
+}
+
+fn minute_of(entry: &LogEntry) -> Option<i64> {
+    DateTime::parse_from_rfc3339(&entry.time)
+        .ok()
+        .map(|time| time.timestamp().div_euclid(60))
+}
+
+impl AnomalyDetector for SpikeDetector {
+    fn observe(&mut self, logs: &[LogEntry]) {
+        let mut batch: HashMap<i64, MinuteCounts> = HashMap::new();
+        for entry in logs {
+            let Some(minute) = minute_of(entry) else {
+                continue;
+            };
+            let counts = batch.entry(minute).or_default();
+            match entry.level {
+                LogLevel::Error | LogLevel::Fatal => counts.errors += 1,
+                LogLevel::Warn => counts.warns += 1,
+                _ => {}
+            }
+        }
+
+        // Batches are capped by count, so a minute may be only partly loaded;
+        // keep the highest count seen for it across refreshes.
+        for (minute, counts) in batch {
+            let seen = self.minutes.entry(minute).or_default();
+            seen.errors = seen.errors.max(counts.errors);
+            seen.warns = seen.warns.max(counts.warns);
+        }
+        while self.minutes.len() > MAX_BASELINE_MINUTES {
+            self.minutes.pop_first();
+        }
+    }
+
+    fn annotate(&self, logs: &[LogEntry], rows: &mut [LogRow], settings: &LogAnomalySettings) {
+        let spike = |minute: i64, level: LogLevel| -> Option<Anomaly> {
+            let counts = self.minutes.get(&minute)?;
+            let (count, baseline) = match level {
+                LogLevel::Error | LogLevel::Fatal => {
+                    (counts.errors, self.baseline(minute, |c| c.errors)?)
+                }
+                LogLevel::Warn => (counts.warns, self.baseline(minute, |c| c.warns)?),
+                _ => return None,
+            };
+            (count >= settings.spike_min_count
+                && count as f64 >= settings.spike_multiplier * baseline)
+                .then_some(Anomaly::Spike {
+                    level,
+                    count,
+                    baseline,
+                })
+        };
+
+        for row in rows.iter_mut() {
+            let found = row.indices.iter().find_map(|&i| {
+                let entry = logs.get(i)?;
+                spike(minute_of(entry)?, entry.level)
+            });
+            if let Some(anomaly) = found {
+                row.anomalies.push(anomaly);
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.minutes.clear();
+    }
+}
+
+/// The text two entries must share to be collapsed.
+///
+/// splunkd `_raw` starts with a timestamp and thread id, so when the message
+/// has the usual `... Component [thread] - text` header only the text counts.
+fn message_key(entry: &LogEntry) -> &str {
+    let raw = entry.message.as_str();
+    match raw.find(" - ") {
+        Some(pos) if !entry.component.is_empty() && raw[..pos].contains(&entry.component) => {
+            &raw[pos + 3..]
+        }
+        _ => raw,
+    }
+}
+
+fn collapse<'a>(entries: impl Iterator<Item = (usize, &'a LogEntry)>) -> Vec<LogRow> {
+    let mut rows: Vec<LogRow> = Vec::new();
+    let mut by_key: HashMap<(String, &str, &str), usize> = HashMap::new();
+
+    for (i, entry) in entries {
+        let key = (
+            entry.level.to_string(),
+            entry.component.as_str(),
+            message_key(entry),
+        );
+        match by_key.get(&key) {
+            Some(&row) => rows[row].indices.push(i),
+            None => {
+                by_key.insert(key, rows.len());
+                rows.push(LogRow {
+                    indices: vec![i],
+                    anomalies: Vec::new(),
+                });
+            }
+        }
+    }
+
+    rows
+}
+
+/// Rows for the internal logs view plus the detectors that flag them.
+#[derive(Debug)]
+pub struct LogAnomalies {
+    detectors: Vec<Box<dyn AnomalyDetector>>,
+    rows: Vec<LogRow>,
+    /// Number of entries the rows were built from.
+    analyzed: usize,
+    /// Entries hidden from the rows.
+    filter: LogFilter,
+}
+
+impl Default for LogAnomalies {
+    fn default() -> Self {
+        Self::with_detectors(vec![
+            Box::new(SpikeDetector::default()),
+            Box::new(RepeatDetector),
+        ])
+    }
+}
+
+impl LogAnomalies {
+    /// Build with a custom detector set.
+    pub fn with_detectors(detectors: Vec<Box<dyn AnomalyDetector>>) -> Self {
+        Self {
+            detectors,
+            rows: Vec::new(),
+            analyzed: 0,
+            filter: LogFilter::default(),
+        }
+    }
+
+    /// Fold a newly loaded batch into the session baseline and rebuild the rows.
+    pub fn observe(&mut self, logs: &[LogEntry], settings: &LogAnomalySettings) {
+        for detector in &mut self.detectors {
+            detector.observe(logs);
+        }
+        self.analyze(logs, settings);
+    }
+
+    /// Rebuild the rows for `logs` (e.g. after thresholds changed).
+    pub fn analyze(&mut self, logs: &[LogEntry], settings: &LogAnomalySettings) {
+        self.analyzed = logs.len();
+        let visible = logs
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| self.filter.matches(entry));
+        if !settings.enabled {
+            self.rows = visible
+                .map(|(i, _)| LogRow {
+                    indices: vec![i],
+                    anomalies: Vec::new(),
+                })
+                .collect();
+            return;
+        }
+
+        self.rows = collapse(visible);
+        for detector in &self.detectors {
+            detector.annotate(logs, &mut self.rows, settings);
+        }
+    }
+
+    /// Forget the baseline and rows.
+    pub fn reset(&mut self) {
+        for detector in &mut self.detectors {
+            detector.reset();
+        }
+        self.rows.clear();
+        self.analyzed = 0;
+    }
+
+    pub fn filter(&self) -> &LogFilter {
+        &self.filter
+    }
+
+    /// Replace the filter; call `analyze` afterwards to rebuild the rows.
+    pub fn set_filter(&mut self, filter: LogFilter) {
+        self.filter = filter;
+    }
+
+    pub fn rows(&self) -> &[LogRow] {
+        &self.rows
+    }
+
+    /// Rows for a batch of `len` entries, if they were built from one.
+    pub fn rows_for(&self, len: usize) -> Option<&[LogRow]> {
+        (self.analyzed == len).then_some(self.rows.as_slice())
+    }
+
+    /// Short summary for the screen title, e.g. "2 spikes, 1 repeated".
+    pub fn summary(&self) -> Option<String> {
+        let spikes = self.rows.iter().filter(|row| row.is_spike()).count();
+        let repeated = self.rows.iter().filter(|row| row.is_repeated()).count();
+        let mut parts = Vec::new();
+        if spikes > 0 {
+            parts.push(format!(
+                "{} spike{}",
+                spikes,
+                if spikes == 1 { "" } else { "s" }
+            ));
+        }
+        if repeated > 0 {
+            parts.push(format!("{} repeated", repeated));
+        }
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+}
+
+impl App {
+    /// Number of rows in the internal logs table.
+    pub(crate) fn internal_log_row_count(&self) -> usize {
+        let len = self.internal_logs.as_ref().map_or(0, Vec::len);
+        self.internal_log_anomalies
+            .rows_for(len)
+            .map_or(len, <[LogRow]>::len)
+    }
+
+    /// The entry shown in the selected internal logs row.
+    pub(crate) fn selected_internal_log(&self) -> Option<&LogEntry> {
+        let logs = self.internal_logs.as_ref()?;
+        let selected = self.internal_logs_state.selected()?;
+        let index = match self.internal_log_anomalies.rows_for(logs.len()) {
+            Some(rows) => rows.get(selected)?.index(),
+            None => selected,
+        };
+        logs.get(index)
+    }
+
+    /// Rebuild internal log rows after the anomaly thresholds changed.
+    pub(crate) fn reanalyze_internal_logs(&mut self) {
+        if let Some(logs) = &self.internal_logs {
+            self.internal_log_anomalies
+                .analyze(logs, &self.internal_logs_defaults.anomalies);
+            let max = self.internal_log_row_count().saturating_sub(1);
+            if let Some(selected) = self.internal_logs_state.selected() {
+                self.internal_logs_state.select(Some(selected.min(max)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(time: &str, level: LogLevel, component: &str, message: &str) -> LogEntry {
+        LogEntry {
+            time: time.to_string(),
+            index_time: String::new(),
+            serial: None,
+            level,
+            component: component.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    fn settings() -> LogAnomalySettings {
+        LogAnomalySettings {
+            repeat_threshold: 3,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_identical_messages_collapse_ignoring_raw_header() {
+        let logs = vec![
+            entry(
+                "2025-01-20T10:30:02.000+00:00",
+                LogLevel::Warn,
+                "TcpOutputProc",
+                "01-20-2025 10:30:02.000 +0000 WARN TcpOutputProc [11 loop] - Queue full",
+            ),
+            entry(
+                "2025-01-20T10:30:01.000+00:00",
+                LogLevel::Info,
+                "Metrics",
+                "collected",
+            ),
+            entry(
+                "2025-01-20T10:30:00.000+00:00",
+                LogLevel::Warn,
+                "TcpOutputProc",
+                "01-20-2025 10:30:00.000 +0000 WARN TcpOutputProc [11 loop] - Queue full",
+            ),
+        ];
+        let mut anomalies = LogAnomalies::default();
+
+        anomalies.observe(&logs, &settings());
+
+        let rows = anomalies.rows();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].indices, vec![0, 2]);
+        assert_eq!(rows[0].count(), 2);
+        assert!(!rows[0].is_repeated());
+
+        let disabled = LogAnomalySettings {
+            enabled: false,
+            ..settings()
+        };
+        anomalies.analyze(&logs, &disabled);
+        assert_eq!(anomalies.rows().len(), 3);
+    }
+
+    #[test]
+    fn test_repeats_flagged_at_threshold() {
+        let logs: Vec<_> = (0..3)
+            .map(|s| {
+                entry(
+                    &format!("2025-01-20T10:30:0{}.000+00:00", s),
+                    LogLevel::Info,
+                    "Auth",
+                    "login failed",
+                )
+            })
+            .collect();
+        let mut anomalies = LogAnomalies::with_detectors(vec![Box::new(RepeatDetector)]);
+
+        anomalies.observe(&logs, &settings());
+
+        assert!(anomalies.rows()[0].is_repeated());
+        assert_eq!(anomalies.summary().as_deref(), Some("1 repeated"));
+    }
+
+    #[test]
+    fn test_error_spike_against_session_baseline() {
+        let mut anomalies = LogAnomalies::default();
+
+        // Baseline refresh: one error per minute across three minutes.
+        let quiet: Vec<_> = (0..3)
+            .map(|m| {
+                entry(
+                    &format!("2025-01-20T10:0{}:00.000+00:00", m),
+                    LogLevel::Error,
+                    "Indexer",
+                    &format!("error {}", m),
+                )
+            })
+            .collect();
+        anomalies.observe(&quiet, &settings());
+        assert!(anomalies.rows().iter().all(|row| !row.is_spike()));
+
+        // Next refresh: four distinct errors in one new minute.
+        let burst: Vec<_> = (0..4)
+            .map(|s| {
+                entry(
+                    &format!("2025-01-20T10:05:0{}.000+00:00", s),
+                    LogLevel::Error,
+                    "Indexer",
+                    &format!("disk error {}", s),
+                )
+            })
+            .chain([entry(
+                "2025-01-20T10:05:09.000+00:00",
+                LogLevel::Warn,
+                "Indexer",
+                "slow",
+            )])
+            .collect();
+        anomalies.observe(&burst, &settings());
+
+        let rows = anomalies.rows();
+        assert!(rows[..4].iter().all(LogRow::is_spike));
+        assert!(
+            !rows[4].is_spike(),
+            "a single WARN is below the minimum count"
+        );
+        assert_eq!(
+            rows[0].anomalies[0],
+            Anomaly::Spike {
+                level: LogLevel::Error,
+                count: 4,
+                baseline: 1.0
+            }
+        );
+        assert_eq!(anomalies.summary().as_deref(), Some("4 spikes"));
+
+        // A higher multiplier un-flags the same minute without changing the baseline.
+        let strict = LogAnomalySettings {
+            spike_multiplier: 5.0,
+            ..settings()
+        };
+        anomalies.analyze(&burst, &strict);
+        assert!(anomalies.summary().is_none());
+
+        anomalies.reset();
+        assert!(anomalies.rows().is_empty());
+    }
+}
@@ -0,0 +1,366 @@
+//! Live tail ("follow") mode and row filters for the internal logs view.
+//!
+//! Responsibilities:
+//! - Poll for entries newer than the last seen `_time` (the shared `LogCursor`
+//!   used by `splunk-cli logs --tail`), merge them into the loaded logs, and keep
+//!   the newest row selected
+//! - Pause and resume following
+//! - Filter rows by minimum log level and by component
+//!
+//! Does NOT handle:
+//! - Does NOT fetch logs (handled by Action::LoadInternalLogs)
+//! - Does NOT build or flag rows (handled by app::log_anomalies)
+//! - Does NOT render the filter bar (handled by ui::screens::internal_logs)
+//!
+//! Invariants:
+//! - While following, loaded batches are merged newest first and the buffer is
+//!   capped at `MAX_FOLLOWED_LOGS` entries
+//! - A paused follow neither polls nor moves the selection; resuming catches up
+//!   from the cursor
+//! - Filters only hide rows: export, copy, and anomaly baselines see every entry
+
+use splunk_client::models::{LogCursor, LogEntry, LogLevel, take_new_logs};
+
+use crate::action::Action;
+use crate::app::App;
+use crate::app::input::components::SingleLineInput;
+use crate::ui::Toast;
+
+/// Most entries kept in the internal logs buffer while following.
+pub const MAX_FOLLOWED_LOGS: usize = 1000;
+
+/// Follow-mode state for the internal logs view.
+#[derive(Debug, Clone, Default)]
+pub struct LogFollow {
+    /// Whether new entries are polled and merged.
+    pub active: bool,
+    /// Whether polling and auto-scroll are suspended.
+    pub paused: bool,
+    /// Newest entry merged so far.
+    pub cursor: Option<LogCursor>,
+}
+
+impl LogFollow {
+    /// Title badge for the internal logs screen.
+    pub fn badge(&self) -> Option<&'static str> {
+        match (self.active, self.paused) {
+            (true, false) => Some("[FOLLOW]"),
+            (true, true) => Some("[PAUSED]"),
+            (false, _) => None,
+        }
+    }
+}
+
+/// Which internal log entries are shown.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogFilter {
+    /// Hide entries less severe than this level.
+    pub min_level: Option<LogLevel>,
+    /// Case-insensitive text the component must contain (empty matches all).
+    pub component: String,
+}
+
+impl LogFilter {
+    pub fn is_active(&self) -> bool {
+        self.min_level.is_some() || !self.component.is_empty()
+    }
+
+    pub fn matches(&self, entry: &LogEntry) -> bool {
+        let level_ok = self
+            .min_level
+            .is_none_or(|min| severity(entry.level) <= severity(min));
+        level_ok
+            && (self.component.is_empty()
+                || entry
+                    .component
+                    .to_lowercase()
+                    .contains(&self.component.to_lowercase()))
+    }
+
+    /// Label for the level filter, e.g. "WARN+" or "All".
+    pub fn level_label(&self) -> String {
+        match self.min_level {
+            Some(level) => format!("{}+", level),
+            None => "All".to_string(),
+        }
+    }
+}
+
+/// Severity rank, most severe first; unknown levels rank last.
+fn severity(level: LogLevel) -> u8 {
+    match level {
+        LogLevel::Fatal => 0,
+        LogLevel::Error => 1,
+        LogLevel::Warn => 2,
+        LogLevel::Info => 3,
+        LogLevel::Debug => 4,
+        LogLevel::Unknown => 5,
+    }
+}
+
+/// Next minimum level in the `l` key cycle: All → ERROR → WARN → INFO → All.
+fn next_min_level(level: Option<LogLevel>) -> Option<LogLevel> {
+    match level {
+        None => Some(LogLevel::Error),
+        Some(LogLevel::Fatal | LogLevel::Error) => Some(LogLevel::Warn),
+        Some(LogLevel::Warn) => Some(LogLevel::Info),
+        Some(_) => None,
+    }
+}
+
+impl App {
+    /// Start or stop following the internal logs (`F`).
+    ///
+    /// Starting seeds the cursor from the loaded logs and polls right away.
+    pub(crate) fn toggle_log_follow(&mut self) -> Option<Action> {
+        if self.internal_logs_follow.active {
+            self.internal_logs_follow = LogFollow::default();
+            self.toasts.push(Toast::info("Follow: Off"));
+            return None;
+        }
+
+        let mut cursor = None;
+        if let Some(logs) = &self.internal_logs {
+            take_new_logs(&mut cursor, logs);
+        }
+        self.internal_logs_follow = LogFollow {
+            active: true,
+            paused: false,
+            cursor,
+        };
+        self.toasts
+            .push(Toast::info("Following internal logs (p to pause)"));
+        Some(self.log_follow_action())
+    }
+
+    /// Pause or resume following (`p`); resuming polls right away.
+    pub(crate) fn toggle_log_follow_pause(&mut self) -> Option<Action> {
+        let follow = &mut self.internal_logs_follow;
+        if !follow.active {
+            self.push_info_toast_once("Not following (F to follow)");
+            return None;
+        }
+        follow.paused = !follow.paused;
+        let paused = follow.paused;
+        self.toasts.push(Toast::info(if paused {
+            "Follow paused"
+        } else {
+            "Follow resumed"
+        }));
+        (!paused).then(|| self.log_follow_action())
+    }
+
+    /// Poll for entries since the cursor (or the default window before the first entry).
+    pub(crate) fn log_follow_action(&self) -> Action {
+        let earliest = match &self.internal_logs_follow.cursor {
+            Some(cursor) => cursor.time.clone(),
+            None => self.internal_logs_defaults.earliest_time.clone(),
+        };
+        Action::LoadInternalLogs {
+            count: self.internal_logs_defaults.count,
+            earliest,
+        }
+    }
+
+    /// Merge a polled batch into the followed logs, newest first.
+    pub(crate) fn merge_followed_logs(&mut self, logs: Vec<LogEntry>) {
+        let mut merged = take_new_logs(&mut self.internal_logs_follow.cursor, &logs);
+        merged.extend(self.internal_logs.take().unwrap_or_default());
+        merged.truncate(MAX_FOLLOWED_LOGS);
+
+        self.internal_log_anomalies
+            .observe(&merged, &self.internal_logs_defaults.anomalies);
+        self.internal_logs = Some(merged);
+        self.loading = false;
+        if !self.internal_logs_follow.paused {
+            self.internal_logs_state.select(Some(0));
+        }
+    }
+
+    /// Cycle the minimum level shown (`l`).
+    pub(crate) fn cycle_log_level_filter(&mut self) {
+        let mut filter = self.internal_log_anomalies.filter().clone();
+        filter.min_level = next_min_level(filter.min_level);
+        let label = filter.level_label();
+        self.apply_log_filter(filter);
+        self.toasts
+            .push(Toast::info(format!("Level filter: {}", label)));
+    }
+
+    /// Start editing the component filter (`/`).
+    pub(crate) fn begin_log_component_filter(&mut self) {
+        let current = self.internal_log_anomalies.filter().component.clone();
+        self.internal_logs_component_input = Some(SingleLineInput::from(current));
+    }
+
+    /// Handle keys while the component filter is being edited.
+    ///
+    /// Enter applies the text, Esc cancels, and every other key edits it.
+    pub(crate) fn handle_log_component_filter_input(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> Option<Action> {
+        use crossterm::event::KeyCode;
+
+        match key.code {
+            KeyCode::Esc => {
+                self.internal_logs_component_input = None;
+            }
+            KeyCode::Enter => {
+                if let Some(input) = self.internal_logs_component_input.take() {
+                    let mut filter = self.internal_log_anomalies.filter().clone();
+                    filter.component = input.value().trim().to_string();
+                    self.apply_log_filter(filter);
+                }
+            }
+            _ => {
+                if let Some(input) = &mut self.internal_logs_component_input {
+                    input.handle_key(key);
+                }
+            }
+        }
+        None
+    }
+
+    fn apply_log_filter(&mut self, filter: LogFilter) {
+        self.internal_log_anomalies.set_filter(filter);
+        self.reanalyze_internal_logs();
+        if self.internal_logs_state.selected().is_none() && self.internal_log_row_count() > 0 {
+            self.internal_logs_state.select(Some(0));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    fn entry(serial: usize, level: LogLevel, component: &str) -> LogEntry {
+        LogEntry {
+            time: format!("2025-01-24T12:00:{:02}.000Z", serial),
+            index_time: format!("2025-01-24T12:00:{:02}.000Z", serial),
+            serial: Some(serial),
+            level,
+            component: component.to_string(),
+            message: format!("message {}", serial),
+        }
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_follow_polls_from_newest_loaded_entry() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.handle_internal_logs_loaded(vec![
+            entry(2, LogLevel::Info, "Metrics"),
+            entry(1, LogLevel::Info, "Metrics"),
+        ]);
+
+        let action = app.toggle_log_follow();
+
+        assert!(matches!(
+            action,
+            Some(Action::LoadInternalLogs { ref earliest, .. })
+                if earliest == "2025-01-24T12:00:02.000Z"
+        ));
+        assert_eq!(app.internal_logs_follow.badge(), Some("[FOLLOW]"));
+    }
+
+    #[test]
+    fn test_followed_batches_merge_without_duplicates_and_autoscroll() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.handle_internal_logs_loaded(vec![entry(1, LogLevel::Info, "Metrics")]);
+        app.toggle_log_follow();
+        app.internal_logs_state.select(None);
+
+        app.handle_internal_logs_loaded(vec![
+            entry(3, LogLevel::Warn, "TcpOutputProc"),
+            entry(2, LogLevel::Info, "Metrics"),
+            entry(1, LogLevel::Info, "Metrics"),
+        ]);
+
+        let serials: Vec<_> = app
+            .internal_logs
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|log| log.serial)
+            .collect();
+        assert_eq!(serials, [Some(3), Some(2), Some(1)]);
+        assert_eq!(app.internal_logs_state.selected(), Some(0));
+    }
+
+    #[test]
+    fn test_paused_follow_stops_polling_and_keeps_selection() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.current_screen = crate::app::state::CurrentScreen::InternalLogs;
+        app.handle_internal_logs_loaded(vec![
+            entry(2, LogLevel::Info, "Metrics"),
+            entry(1, LogLevel::Info, "Metrics"),
+        ]);
+        app.toggle_log_follow();
+        assert!(matches!(
+            app.handle_tick(),
+            Some(Action::LoadInternalLogs { .. })
+        ));
+
+        assert!(app.toggle_log_follow_pause().is_none());
+        app.internal_logs_state.select(Some(1));
+        assert!(app.handle_tick().is_none());
+        app.handle_internal_logs_loaded(vec![entry(3, LogLevel::Info, "Metrics")]);
+        assert_eq!(app.internal_logs_state.selected(), Some(1));
+
+        assert!(app.toggle_log_follow_pause().is_some());
+        assert_eq!(app.internal_logs_follow.badge(), Some("[FOLLOW]"));
+    }
+
+    #[test]
+    fn test_level_and_component_filters_hide_rows() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.internal_logs_defaults.anomalies.enabled = false;
+        app.handle_internal_logs_loaded(vec![
+            entry(4, LogLevel::Error, "TcpOutputProc"),
+            entry(3, LogLevel::Warn, "DateParser"),
+            entry(2, LogLevel::Info, "TcpOutputProc"),
+            entry(1, LogLevel::Unknown, "Metrics"),
+        ]);
+
+        app.cycle_log_level_filter();
+        app.cycle_log_level_filter();
+        assert_eq!(app.internal_log_anomalies.filter().level_label(), "WARN+");
+        assert_eq!(app.internal_log_row_count(), 2);
+
+        app.begin_log_component_filter();
+        for c in "tcp".chars() {
+            app.handle_log_component_filter_input(key(KeyCode::Char(c)));
+        }
+        app.handle_log_component_filter_input(key(KeyCode::Enter));
+        assert!(app.internal_logs_component_input.is_none());
+        assert_eq!(app.internal_log_row_count(), 1);
+        assert_eq!(
+            app.selected_internal_log().map(|log| log.serial),
+            Some(Some(4))
+        );
+    }
+
+    #[test]
+    fn test_level_filter_cycle_wraps() {
+        let mut level = None;
+        let mut labels = Vec::new();
+        for _ in 0..4 {
+            level = next_min_level(level);
+            labels.push(
+                LogFilter {
+                    min_level: level,
+                    component: String::new(),
+                }
+                .level_label(),
+            );
+        }
+        assert_eq!(labels, ["ERROR+", "WARN+", "INFO+", "All"]);
+    }
+}
@@ -0,0 +1,178 @@
+//! Lookup table contents editor.
+//!
+//! Responsibilities:
+//! - Hold the loaded lookup contents and the cell cursor
+//! - Apply cell edits and row insertions/deletions, tracking unsaved changes
+//!
+//! Does NOT handle:
+//! - Does NOT load or save the lookup (handled by Action::LoadLookupContent/SaveLookupContent)
+//! - Does NOT handle popup keys (handled by app::popups::lookup_editor)
+//! - Does NOT render the popup (handled by ui::lookup_editor)
+//!
+//! Invariants:
+//! - Every row has one cell per header column
+//! - `row`/`col` stay in range whenever the lookup has rows and columns
+
+use splunk_client::models::LookupContent;
+
+/// Editor state for the lookup contents popup.
+#[derive(Debug, Clone)]
+pub struct LookupEditor {
+    pub name: String,
+    pub app: Option<String>,
+    pub owner: Option<String>,
+    pub content: LookupContent,
+    pub row: usize,
+    pub col: usize,
+    /// Input buffer while a cell is being edited.
+    pub editing: Option<String>,
+    /// Whether the contents differ from the last load or save.
+    pub dirty: bool,
+    /// Set after Esc with unsaved changes; a second Esc discards them.
+    pub confirm_discard: bool,
+}
+
+impl LookupEditor {
+    pub fn new(
+        name: String,
+        app: Option<String>,
+        owner: Option<String>,
+        content: LookupContent,
+    ) -> Self {
+        Self {
+            name,
+            app,
+            owner,
+            content,
+            row: 0,
+            col: 0,
+            editing: None,
+            dirty: false,
+            confirm_discard: false,
+        }
+    }
+
+    /// Value of the cell under the cursor, if the lookup has one.
+    pub fn selected_cell(&self) -> Option<&str> {
+        self.content
+            .rows
+            .get(self.row)
+            .and_then(|row| row.get(self.col))
+            .map(String::as_str)
+    }
+
+    pub fn move_row(&mut self, delta: isize) {
+        let last = self.content.rows.len().saturating_sub(1);
+        self.row = self.row.saturating_add_signed(delta).min(last);
+    }
+
+    pub fn move_col(&mut self, delta: isize) {
+        let last = self.content.header.len().saturating_sub(1);
+        self.col = self.col.saturating_add_signed(delta).min(last);
+    }
+
+    /// Start editing the selected cell with its current value.
+    pub fn start_edit(&mut self) {
+        if let Some(value) = self.selected_cell() {
+            self.editing = Some(value.to_string());
+        }
+    }
+
+    /// Write the edit buffer into the selected cell.
+    pub fn commit_edit(&mut self) {
+        let Some(value) = self.editing.take() else {
+            return;
+        };
+        let Some(cell) = self
+            .content
+            .rows
+            .get_mut(self.row)
+            .and_then(|row| row.get_mut(self.col))
+        else {
+            return;
+        };
+        if *cell != value {
+            *cell = value;
+            self.mark_dirty();
+        }
+    }
+
+    /// Insert an empty row below the cursor and move to it.
+    pub fn insert_row(&mut self) {
+        if self.content.header.is_empty() {
+            return;
+        }
+        let index = if self.content.rows.is_empty() {
+            0
+        } else {
+            self.row + 1
+        };
+        self.content
+            .rows
+            .insert(index, vec![String::new(); self.content.header.len()]);
+        self.row = index;
+        self.mark_dirty();
+    }
+
+    /// Delete the row under the cursor.
+    pub fn delete_row(&mut self) {
+        if self.row < self.content.rows.len() {
+            self.content.rows.remove(self.row);
+            self.move_row(0);
+            self.mark_dirty();
+        }
+    }
+
+    fn mark_dirty(&mut self) {
+        self.dirty = true;
+        self.confirm_discard = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn editor() -> LookupEditor {
+        let content = LookupContent::from_csv("host,owner\nweb-01,ops\ndb-01,dba\n").unwrap();
+        LookupEditor::new("hosts.csv".to_string(), None, None, content)
+    }
+
+    #[test]
+    fn test_edit_cell_marks_dirty_only_on_change() {
+        let mut editor = editor();
+        editor.move_row(1);
+        editor.move_col(1);
+
+        editor.start_edit();
+        editor.commit_edit();
+        assert!(!editor.dirty);
+
+        editor.start_edit();
+        editor.editing = Some("sre".to_string());
+        editor.commit_edit();
+        assert!(editor.dirty);
+        assert_eq!(editor.content.rows[1], vec!["db-01", "sre"]);
+    }
+
+    #[test]
+    fn test_insert_and_delete_rows_keep_cursor_in_range() {
+        let mut editor = editor();
+        editor.move_row(5);
+        assert_eq!(editor.row, 1);
+
+        editor.insert_row();
+        assert_eq!(editor.row, 2);
+        assert_eq!(editor.content.rows[2], vec!["", ""]);
+
+        editor.delete_row();
+        editor.delete_row();
+        editor.delete_row();
+        assert!(editor.content.rows.is_empty());
+        assert_eq!(editor.row, 0);
+        assert!(editor.selected_cell().is_none());
+
+        editor.insert_row();
+        assert_eq!(editor.content.rows.len(), 1);
+    }
+}
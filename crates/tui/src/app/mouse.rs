@@ -1,9 +1,11 @@
 //! Mouse event handling for the TUI app.
 //!
 //! Responsibilities:
-//! - Handle mouse scroll events
+//! - Handle mouse scroll events, including scrollable popups
+//! - Handle tab bar clicks (switch screens)
 //! - Handle footer button clicks
-//! - Handle content area clicks for selection
+//! - Handle content area clicks for selection, and a second click on the
+//!   selected row to open it
 //! - Handle popup dialog interactions (confirm/cancel via click)
 //!
 //! Does NOT handle:
@@ -13,14 +15,16 @@
 //! Invariants:
 //! - Popup clicks are handled before content/footer hit-testing.
 //! - Footer hit-testing must use `FooterLayout` for render parity.
+//! - Tab, content, and popup hit-testing use the rects recorded in `App::hit_areas`
+//!   during the last render; before the first frame the default layout is assumed.
 
 use crate::action::Action;
 use crate::app::App;
 use crate::app::footer_layout::FooterLayout;
-use crate::app::state::{CurrentScreen, HEADER_HEIGHT};
-use crate::ui::popup::{POPUP_HEIGHT_PERCENT, POPUP_WIDTH_PERCENT};
-use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
-use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use crate::app::state::{CurrentScreen, FOOTER_HEIGHT, HEADER_HEIGHT, InputsViewMode};
+use crate::ui::popup::{POPUP_HEIGHT_PERCENT, POPUP_WIDTH_PERCENT, PopupButton, PopupType};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Constraint, Direction, Layout, Position, Rect};
 use ratatui::widgets::{ListState, TableState};
 
 impl App {
@@ -34,6 +38,12 @@ impl App {
             MouseEventKind::ScrollUp => Some(Action::NavigateUp),
             MouseEventKind::ScrollDown => Some(Action::NavigateDown),
             MouseEventKind::Down(crossterm::event::MouseButton::Left) => {
+                // Check for tab bar clicks
+                if let Some(screen) = self.hit_areas.tab_at(mouse.column, mouse.row) {
+                    return (screen != self.current_screen)
+                        .then_some(Action::SwitchToScreen(screen));
+                }
+
                 // Check for footer navigation
                 if mouse.row >= self.last_area.height.saturating_sub(2)
                     && mouse.row < self.last_area.height.saturating_sub(1)
@@ -42,8 +52,9 @@ impl App {
                 }
 
                 // Check for content area clicks
-                if mouse.row >= HEADER_HEIGHT
-                    && mouse.row < self.last_area.height - crate::app::state::FOOTER_HEIGHT
+                if self
+                    .content_area()
+                    .contains(Position::new(mouse.column, mouse.row))
                 {
                     return self.handle_content_click(mouse.row, mouse.column);
                 }
@@ -74,6 +85,32 @@ impl App {
         }
     }
 
+    /// Content area of the last frame, or the default layout before the first frame.
+    fn content_area(&self) -> Rect {
+        if self.hit_areas.content.is_empty() {
+            Rect {
+                y: HEADER_HEIGHT,
+                height: self
+                    .last_area
+                    .height
+                    .saturating_sub(HEADER_HEIGHT + FOOTER_HEIGHT),
+                ..self.last_area
+            }
+        } else {
+            self.hit_areas.content
+        }
+    }
+
+    /// Open the selected row, as `Enter` would, when a click landed on the row
+    /// that was already selected (click twice to open).
+    fn open_if_reselected(&mut self, reselected: bool) -> Option<Action> {
+        if reselected {
+            self.dispatch_screen_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+        } else {
+            None
+        }
+    }
+
     /// Handle clicks in the main content area.
     /// Returns Action::SetFocus for multi-focus screens to synchronize focus state
     /// with visible selection. This ensures keyboard navigation works on the
@@ -84,6 +121,9 @@ impl App {
             return None;
         }
 
+        // First row inside the screen's bordered block
+        let top = self.content_area().y + 1;
+
         // Multi-focus screens: update selection AND emit SetFocus action
         match self.current_screen {
             CurrentScreen::Search => {
@@ -97,15 +137,18 @@ impl App {
                 // Configs screen has multiple focusable components
                 match self.config_view_mode {
                     crate::ui::screens::configs::ConfigViewMode::FileList => {
+                        let mut reselected = false;
                         if let Some(index) = calculate_table_click_index(
                             row,
-                            HEADER_HEIGHT + 1,
+                            top,
                             self.config_files_state.offset(),
                             self.config_files.as_deref().map(|v| v.len()).unwrap_or(0),
                         ) {
+                            reselected = self.config_files_state.selected() == Some(index);
                             self.config_files_state.select(Some(index));
                         }
-                        Some(Action::SetFocus("config_files".to_string()))
+                        self.open_if_reselected(reselected)
+                            .or_else(|| Some(Action::SetFocus("config_files".to_string())))
                     }
                     crate::ui::screens::configs::ConfigViewMode::StanzaList
                     | crate::ui::screens::configs::ConfigViewMode::StanzaDetail => {
@@ -117,12 +160,13 @@ impl App {
                                 self.config_stanzas.as_deref().map(|v| v.len()).unwrap_or(0),
                             )
                         };
-                        if let Some(index) =
-                            calculate_table_click_index(row, HEADER_HEIGHT + 1, offset, total)
-                        {
+                        let mut reselected = false;
+                        if let Some(index) = calculate_table_click_index(row, top, offset, total) {
+                            reselected = self.config_stanzas_state.selected() == Some(index);
                             self.config_stanzas_state.select(Some(index));
                         }
-                        Some(Action::SetFocus("config_stanzas".to_string()))
+                        self.open_if_reselected(reselected)
+                            .or_else(|| Some(Action::SetFocus("config_stanzas".to_string())))
                     }
                 }
             }
@@ -130,7 +174,7 @@ impl App {
             CurrentScreen::Jobs => {
                 // Jobs has special double-click handling; preserve InspectJob action
                 // but always set focus on any click
-                let action = self.handle_jobs_click(row);
+                let action = self.handle_jobs_click(row, top);
                 action.or_else(|| Some(Action::SetFocus("jobs_list".to_string())))
             }
 
@@ -139,7 +183,7 @@ impl App {
                 if self.cluster_view_mode == crate::app::state::ClusterViewMode::Peers {
                     if let Some(index) = calculate_table_click_index(
                         row,
-                        HEADER_HEIGHT + 1,
+                        top,
                         self.cluster_peers_state.offset(),
                         self.cluster_peers.as_deref().map(|v| v.len()).unwrap_or(0),
                     ) {
@@ -158,7 +202,7 @@ impl App {
                     crate::app::state::WorkloadViewMode::Pools => {
                         if let Some(index) = calculate_table_click_index(
                             row,
-                            HEADER_HEIGHT + 1,
+                            top,
                             self.workload_pools_state.offset(),
                             self.workload_pools.as_deref().map(|v| v.len()).unwrap_or(0),
                         ) {
@@ -169,7 +213,7 @@ impl App {
                     crate::app::state::WorkloadViewMode::Rules => {
                         if let Some(index) = calculate_table_click_index(
                             row,
-                            HEADER_HEIGHT + 1,
+                            top,
                             self.workload_rules_state.offset(),
                             self.workload_rules.as_deref().map(|v| v.len()).unwrap_or(0),
                         ) {
@@ -186,7 +230,7 @@ impl App {
                 if self.shc_view_mode == crate::app::state::ShcViewMode::Members {
                     if let Some(index) = calculate_table_click_index(
                         row,
-                        HEADER_HEIGHT + 1,
+                        top,
                         self.shc_members_state.offset(),
                         self.shc_members.as_deref().map(|v| v.len()).unwrap_or(0),
                     ) {
@@ -202,31 +246,34 @@ impl App {
             // Single-focus screens: only update selection, no focus change needed
             // These screens have only one component in FocusManager, so focus is already there
             CurrentScreen::Apps => {
-                select_list_click(&mut self.apps_state, self.apps.as_deref(), row);
-                None
+                let reselected =
+                    select_list_click(&mut self.apps_state, self.apps.as_deref(), row, top);
+                self.open_if_reselected(reselected)
             }
             CurrentScreen::Users => {
-                select_list_click(&mut self.users_state, self.users.as_deref(), row);
+                select_list_click(&mut self.users_state, self.users.as_deref(), row, top);
                 None
             }
             CurrentScreen::Roles => {
-                select_list_click(&mut self.roles_state, self.roles.as_deref(), row);
+                select_list_click(&mut self.roles_state, self.roles.as_deref(), row, top);
                 None
             }
             CurrentScreen::Indexes => {
-                select_list_click(&mut self.indexes_state, self.indexes.as_deref(), row);
-                None
+                let reselected =
+                    select_list_click(&mut self.indexes_state, self.indexes.as_deref(), row, top);
+                self.open_if_reselected(reselected)
             }
             CurrentScreen::SavedSearches => {
                 select_list_click(
                     &mut self.saved_searches_state,
                     self.saved_searches.as_deref(),
                     row,
+                    top,
                 );
                 None
             }
             CurrentScreen::Macros => {
-                select_list_click(&mut self.macros_state, self.macros.as_deref(), row);
+                select_list_click(&mut self.macros_state, self.macros.as_deref(), row, top);
                 None
             }
             CurrentScreen::FiredAlerts => {
@@ -234,23 +281,66 @@ impl App {
                     &mut self.fired_alerts_state,
                     self.fired_alerts.as_deref(),
                     row,
+                    top,
                 );
                 None
             }
             CurrentScreen::Dashboards => {
-                select_list_click(&mut self.dashboards_state, self.dashboards.as_deref(), row);
-                None
+                let reselected = select_list_click(
+                    &mut self.dashboards_state,
+                    self.dashboards.as_deref(),
+                    row,
+                    top,
+                );
+                self.open_if_reselected(reselected)
             }
             CurrentScreen::DataModels => {
                 select_list_click(
                     &mut self.data_models_state,
                     self.data_models.as_deref(),
                     row,
+                    top,
+                );
+                None
+            }
+            CurrentScreen::Inputs if self.inputs_view_mode == InputsViewMode::Http => {
+                select_table_click(
+                    &mut self.hec_tokens_state,
+                    self.hec_tokens.as_deref(),
+                    row,
+                    top,
                 );
                 None
             }
             CurrentScreen::Inputs => {
-                select_table_click(&mut self.inputs_state, self.inputs.as_deref(), row);
+                select_table_click(&mut self.inputs_state, self.inputs.as_deref(), row, top);
+                None
+            }
+            CurrentScreen::Ingestion => {
+                select_table_click(
+                    &mut self.ingestion_state,
+                    self.ingestion_stats.as_ref().map(|i| i.stats.as_slice()),
+                    row,
+                    top,
+                );
+                None
+            }
+            CurrentScreen::Sourcetypes => {
+                select_table_click(
+                    &mut self.sourcetypes_state,
+                    self.sourcetypes.as_deref(),
+                    row,
+                    top,
+                );
+                None
+            }
+            CurrentScreen::Metadata => {
+                select_table_click(
+                    &mut self.metadata_state,
+                    self.metadata_entries.as_deref(),
+                    row,
+                    top,
+                );
                 None
             }
             CurrentScreen::SearchPeers => {
@@ -258,29 +348,53 @@ impl App {
                     &mut self.search_peers_state,
                     self.search_peers.as_deref(),
                     row,
+                    top,
                 );
                 None
             }
             CurrentScreen::Forwarders => {
-                select_table_click(&mut self.forwarders_state, self.forwarders.as_deref(), row);
+                select_table_click(
+                    &mut self.forwarders_state,
+                    self.forwarders.as_deref(),
+                    row,
+                    top,
+                );
                 None
             }
             CurrentScreen::Lookups => {
-                select_table_click(&mut self.lookups_state, self.lookups.as_deref(), row);
-                None
+                let reselected =
+                    select_table_click(&mut self.lookups_state, self.lookups.as_deref(), row, top);
+                self.open_if_reselected(reselected)
             }
             CurrentScreen::Audit => {
-                select_table_click(&mut self.audit_state, self.audit_events.as_deref(), row);
-                None
-            }
-            CurrentScreen::InternalLogs => {
                 select_table_click(
-                    &mut self.internal_logs_state,
-                    self.internal_logs.as_deref(),
+                    &mut self.audit_state,
+                    self.audit_events.as_deref(),
                     row,
+                    top,
                 );
                 None
             }
+            CurrentScreen::InternalLogs => {
+                // Rows may collapse repeated messages, so click against rows when available
+                let len = self.internal_logs.as_ref().map_or(0, Vec::len);
+                // The filter bar pushes the table down by its height
+                let filter_bar = self.internal_log_anomalies.filter().is_active()
+                    || self.internal_logs_component_input.is_some();
+                let top = if filter_bar { top + 3 } else { top };
+                match self.internal_log_anomalies.rows_for(len) {
+                    Some(rows) if self.internal_logs.is_some() => {
+                        select_table_click(&mut self.internal_logs_state, Some(rows), row, top)
+                    }
+                    _ => select_table_click(
+                        &mut self.internal_logs_state,
+                        self.internal_logs.as_deref(),
+                        row,
+                        top,
+                    ),
+                };
+                None
+            }
 
             // Non-list screens (no mouse selection)
             _ => None,
@@ -289,13 +403,13 @@ impl App {
 
     /// Special handler for Jobs screen (has filter offset).
     /// Returns Some(Action::InspectJob) for double-click, None for single click.
-    fn handle_jobs_click(&mut self, row: u16) -> Option<Action> {
+    fn handle_jobs_click(&mut self, row: u16, top: u16) -> Option<Action> {
         let filter_offset = if self.is_filtering || self.search_filter.is_some() {
             3
         } else {
             0
         };
-        let header_row = HEADER_HEIGHT + filter_offset + 1;
+        let header_row = top + filter_offset;
         let offset = self.jobs_state.offset();
         let total = self.filtered_jobs_len();
 
@@ -334,20 +448,26 @@ fn total_items<T>(items: Option<&[T]>) -> usize {
     items.map_or(0, <[T]>::len)
 }
 
-fn select_list_click<T>(state: &mut ListState, items: Option<&[T]>, row: u16) {
-    if let Some(index) =
-        calculate_list_click_index(row, HEADER_HEIGHT + 1, state.offset(), total_items(items))
-    {
-        state.select(Some(index));
-    }
+/// Select the clicked list row; returns true if it was already selected.
+fn select_list_click<T>(state: &mut ListState, items: Option<&[T]>, row: u16, top: u16) -> bool {
+    let Some(index) = calculate_list_click_index(row, top, state.offset(), total_items(items))
+    else {
+        return false;
+    };
+    let reselected = state.selected() == Some(index);
+    state.select(Some(index));
+    reselected
 }
 
-fn select_table_click<T>(state: &mut TableState, items: Option<&[T]>, row: u16) {
-    if let Some(index) =
-        calculate_table_click_index(row, HEADER_HEIGHT + 1, state.offset(), total_items(items))
-    {
-        state.select(Some(index));
-    }
+/// Select the clicked table row; returns true if it was already selected.
+fn select_table_click<T>(state: &mut TableState, items: Option<&[T]>, row: u16, top: u16) -> bool {
+    let Some(index) = calculate_table_click_index(row, top, state.offset(), total_items(items))
+    else {
+        return false;
+    };
+    let reselected = state.selected() == Some(index);
+    state.select(Some(index));
+    reselected
 }
 
 /// Calculate the data index from a click row for a table with a header row.
@@ -383,21 +503,33 @@ impl App {
                 self.handle_popup_click(mouse.column, mouse.row)
             }
             MouseEventKind::ScrollUp | MouseEventKind::ScrollDown => {
-                // Scroll events in popups - currently ignored
-                // Could be implemented for scrollable popups (Help, ErrorDetails, etc.)
-                None
+                // Scrollable popups scroll (or move their selection) like Up/Down
+                let kind = &self.popup.as_ref()?.kind;
+                if !scrolls_with_wheel(kind) {
+                    return None;
+                }
+                let code = if mouse.kind == MouseEventKind::ScrollUp {
+                    KeyCode::Up
+                } else {
+                    KeyCode::Down
+                };
+                self.handle_popup_input(KeyEvent::new(code, KeyModifiers::NONE))
             }
             _ => None,
         }
     }
 
     /// Calculate the confirmation popup area on screen.
-    /// Uses the default popup sizing from render.rs.
+    /// Uses the area recorded during the last render, or the default popup sizing
+    /// from render.rs before the popup has been drawn.
     fn get_popup_area(&self) -> Option<Rect> {
         let popup = self.popup.as_ref()?;
         if !popup.kind.is_confirmation() {
             return None;
         }
+        if let Some(area) = self.hit_areas.popup {
+            return Some(area);
+        }
         let screen = self.last_area;
 
         let popup_layout = Layout::default()
@@ -434,6 +566,11 @@ impl App {
             return None;
         }
 
+        if self.hit_areas.popup_button_at(col, row) == Some(PopupButton::Cancel) {
+            self.popup = None;
+            return None;
+        }
+
         let popup = self.popup.take()?;
         if popup.kind.is_confirmation() {
             self.execute_confirmation_action(popup.kind)
@@ -444,6 +581,30 @@ impl App {
     }
 }
 
+/// Popups whose Up/Down keys scroll their content or move their selection.
+fn scrolls_with_wheel(kind: &PopupType) -> bool {
+    matches!(
+        kind,
+        PopupType::Help
+            | PopupType::ErrorDetails
+            | PopupType::IndexDetails
+            | PopupType::DashboardSource
+            | PopupType::MacroExpansion
+            | PopupType::ResultDetail
+            | PopupType::TopValues
+            | PopupType::ServerMessages
+            | PopupType::BackgroundJobs
+            | PopupType::AppDetails
+            | PopupType::OrphanedObjects { .. }
+            | PopupType::UndoHistory { .. }
+            | PopupType::ProfileSelector { .. }
+            | PopupType::NamespaceSelector { .. }
+            | PopupType::SessionPicker { .. }
+            | PopupType::TroubleshootingRecipes { .. }
+            | PopupType::CommandPalette { .. }
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -524,6 +685,8 @@ mod tests {
                 disk_usage: 0,
                 priority: None,
                 label: None,
+                ttl: None,
+                performance: None,
             },
             SearchJobStatus {
                 sid: "job2".to_string(),
@@ -538,6 +701,8 @@ mod tests {
                 disk_usage: 0,
                 priority: None,
                 label: None,
+                ttl: None,
+                performance: None,
             },
         ]);
         app.rebuild_filtered_indices();
@@ -585,6 +750,8 @@ mod tests {
                 disk_usage: 0,
                 priority: None,
                 label: None,
+                ttl: None,
+                performance: None,
             },
             SearchJobStatus {
                 sid: "job2".to_string(),
@@ -599,6 +766,8 @@ mod tests {
                 disk_usage: 0,
                 priority: None,
                 label: None,
+                ttl: None,
+                performance: None,
             },
         ]);
         app.rebuild_filtered_indices();
@@ -10,15 +10,16 @@
 //! - Does NOT handle input events
 
 use crate::app::App;
-use crate::app::state::CurrentScreen;
+use crate::app::state::{CurrentScreen, InputsViewMode, RolesViewMode};
 
 impl App {
     // Navigation helpers
     pub(crate) fn next_item(&mut self) {
         match self.current_screen {
             CurrentScreen::Search => {
-                if !self.search_results.is_empty() {
-                    let max_offset = self.search_results.len().saturating_sub(1);
+                let len = self.visible_search_result_count();
+                if len > 0 {
+                    let max_offset = len.saturating_sub(1);
                     if self.search_scroll_offset < max_offset {
                         self.search_scroll_offset += 1;
                     }
@@ -50,11 +51,10 @@ impl App {
                 }
             }
             CurrentScreen::InternalLogs => {
-                if let Some(logs) = &self.internal_logs {
-                    let i = self.internal_logs_state.selected().unwrap_or(0);
-                    if i < logs.len().saturating_sub(1) {
-                        self.internal_logs_state.select(Some(i + 1));
-                    }
+                let rows = self.internal_log_row_count();
+                let i = self.internal_logs_state.selected().unwrap_or(0);
+                if i < rows.saturating_sub(1) {
+                    self.internal_logs_state.select(Some(i + 1));
                 }
             }
             CurrentScreen::Apps => {
@@ -73,6 +73,22 @@ impl App {
                     }
                 }
             }
+            CurrentScreen::Inputs if self.inputs_view_mode == InputsViewMode::Http => {
+                if let Some(tokens) = &self.hec_tokens {
+                    let i = self.hec_tokens_state.selected().unwrap_or(0);
+                    if i < tokens.len().saturating_sub(1) {
+                        self.hec_tokens_state.select(Some(i + 1));
+                    }
+                }
+            }
+            CurrentScreen::Roles if self.roles_view_mode == RolesViewMode::Matrix => {
+                if let Some(matrix) = &self.role_matrix {
+                    let i = self.role_matrix_state.selected().unwrap_or(0);
+                    if i < matrix.capabilities.len().saturating_sub(1) {
+                        self.role_matrix_state.select(Some(i + 1));
+                    }
+                }
+            }
             CurrentScreen::Inputs => {
                 if let Some(inputs) = &self.inputs {
                     let i = self.inputs_state.selected().unwrap_or(0);
@@ -97,6 +113,36 @@ impl App {
                     }
                 }
             }
+            CurrentScreen::Ingestion => {
+                if let Some(ingestion) = &self.ingestion_stats {
+                    let i = self.ingestion_state.selected().unwrap_or(0);
+                    if i < ingestion.stats.len().saturating_sub(1) {
+                        self.ingestion_state.select(Some(i + 1));
+                    }
+                }
+            }
+            CurrentScreen::Sourcetypes => {
+                if let Some(sourcetypes) = &self.sourcetypes {
+                    let i = self.sourcetypes_state.selected().unwrap_or(0);
+                    if i < sourcetypes.len().saturating_sub(1) {
+                        self.sourcetypes_state.select(Some(i + 1));
+                    }
+                }
+            }
+            CurrentScreen::Metadata => {
+                if let Some(entries) = &self.metadata_entries {
+                    let i = self.metadata_state.selected().unwrap_or(0);
+                    if i < entries.len().saturating_sub(1) {
+                        self.metadata_state.select(Some(i + 1));
+                    }
+                }
+            }
+            CurrentScreen::Rest => {
+                let i = self.rest_history_state.selected().unwrap_or(0);
+                if i < self.rest_history.len().saturating_sub(1) {
+                    self.rest_history_state.select(Some(i + 1));
+                }
+            }
             CurrentScreen::Macros => {
                 if let Some(macros) = &self.macros {
                     let i = self.macros_state.selected().unwrap_or(0);
@@ -160,6 +206,18 @@ impl App {
                     self.users_state.select(Some(i - 1));
                 }
             }
+            CurrentScreen::Roles if self.roles_view_mode == RolesViewMode::Matrix => {
+                let i = self.role_matrix_state.selected().unwrap_or(0);
+                if i > 0 {
+                    self.role_matrix_state.select(Some(i - 1));
+                }
+            }
+            CurrentScreen::Inputs if self.inputs_view_mode == InputsViewMode::Http => {
+                let i = self.hec_tokens_state.selected().unwrap_or(0);
+                if i > 0 {
+                    self.hec_tokens_state.select(Some(i - 1));
+                }
+            }
             CurrentScreen::Inputs => {
                 let i = self.inputs_state.selected().unwrap_or(0);
                 if i > 0 {
@@ -178,6 +236,30 @@ impl App {
                     self.forwarders_state.select(Some(i - 1));
                 }
             }
+            CurrentScreen::Ingestion => {
+                let i = self.ingestion_state.selected().unwrap_or(0);
+                if i > 0 {
+                    self.ingestion_state.select(Some(i - 1));
+                }
+            }
+            CurrentScreen::Sourcetypes => {
+                let i = self.sourcetypes_state.selected().unwrap_or(0);
+                if i > 0 {
+                    self.sourcetypes_state.select(Some(i - 1));
+                }
+            }
+            CurrentScreen::Metadata => {
+                let i = self.metadata_state.selected().unwrap_or(0);
+                if i > 0 {
+                    self.metadata_state.select(Some(i - 1));
+                }
+            }
+            CurrentScreen::Rest => {
+                let i = self.rest_history_state.selected().unwrap_or(0);
+                if i > 0 {
+                    self.rest_history_state.select(Some(i - 1));
+                }
+            }
             CurrentScreen::Macros => {
                 let i = self.macros_state.selected().unwrap_or(0);
                 if i > 0 {
@@ -200,7 +282,7 @@ impl App {
         match self.current_screen {
             CurrentScreen::Search => {
                 // Clamp offset to prevent scrolling past the end
-                let max_offset = self.search_results.len().saturating_sub(1);
+                let max_offset = self.visible_search_result_count().saturating_sub(1);
                 self.search_scroll_offset =
                     self.search_scroll_offset.saturating_add(10).min(max_offset);
             }
@@ -231,12 +313,11 @@ impl App {
                 }
             }
             CurrentScreen::InternalLogs => {
-                if let Some(logs) = &self.internal_logs
-                    && !logs.is_empty()
-                {
+                let rows = self.internal_log_row_count();
+                if rows > 0 {
                     let i = self.internal_logs_state.selected().unwrap_or(0);
                     self.internal_logs_state
-                        .select(Some((i.saturating_add(10)).min(logs.len() - 1)));
+                        .select(Some((i.saturating_add(10)).min(rows - 1)));
                 }
             }
             CurrentScreen::Apps => {
@@ -248,6 +329,15 @@ impl App {
                         .select(Some((i.saturating_add(10)).min(apps.len() - 1)));
                 }
             }
+            CurrentScreen::Inputs if self.inputs_view_mode == InputsViewMode::Http => {
+                if let Some(tokens) = &self.hec_tokens
+                    && !tokens.is_empty()
+                {
+                    let i = self.hec_tokens_state.selected().unwrap_or(0);
+                    self.hec_tokens_state
+                        .select(Some((i.saturating_add(10)).min(tokens.len() - 1)));
+                }
+            }
             CurrentScreen::Inputs => {
                 if let Some(inputs) = &self.inputs
                     && !inputs.is_empty()
@@ -275,6 +365,34 @@ impl App {
                         .select(Some((i.saturating_add(10)).min(forwarders.len() - 1)));
                 }
             }
+            CurrentScreen::Ingestion => {
+                if let Some(ingestion) = &self.ingestion_stats
+                    && !ingestion.stats.is_empty()
+                {
+                    let i = self.ingestion_state.selected().unwrap_or(0);
+                    self.ingestion_state
+                        .select(Some((i.saturating_add(10)).min(ingestion.stats.len() - 1)));
+                }
+            }
+            CurrentScreen::Sourcetypes => {
+                if let Some(sourcetypes) = &self.sourcetypes
+                    && !sourcetypes.is_empty()
+                {
+                    let i = self.sourcetypes_state.selected().unwrap_or(0);
+                    self.sourcetypes_state
+                        .select(Some((i.saturating_add(10)).min(sourcetypes.len() - 1)));
+                }
+            }
+            CurrentScreen::Metadata => {
+                if let Some(entries) = &self.metadata_entries
+                    && !entries.is_empty()
+                {
+                    let i = self.metadata_state.selected().unwrap_or(0);
+                    self.metadata_state
+                        .select(Some((i.saturating_add(10)).min(entries.len() - 1)));
+                }
+            }
+            CurrentScreen::Rest => self.scroll_rest_response(10),
             CurrentScreen::Macros => {
                 if let Some(macros) = &self.macros
                     && !macros.is_empty()
@@ -324,6 +442,10 @@ impl App {
                 let i = self.apps_state.selected().unwrap_or(0);
                 self.apps_state.select(Some(i.saturating_sub(10)));
             }
+            CurrentScreen::Inputs if self.inputs_view_mode == InputsViewMode::Http => {
+                let i = self.hec_tokens_state.selected().unwrap_or(0);
+                self.hec_tokens_state.select(Some(i.saturating_sub(10)));
+            }
             CurrentScreen::Inputs => {
                 let i = self.inputs_state.selected().unwrap_or(0);
                 self.inputs_state.select(Some(i.saturating_sub(10)));
@@ -336,6 +458,19 @@ impl App {
                 let i = self.forwarders_state.selected().unwrap_or(0);
                 self.forwarders_state.select(Some(i.saturating_sub(10)));
             }
+            CurrentScreen::Ingestion => {
+                let i = self.ingestion_state.selected().unwrap_or(0);
+                self.ingestion_state.select(Some(i.saturating_sub(10)));
+            }
+            CurrentScreen::Sourcetypes => {
+                let i = self.sourcetypes_state.selected().unwrap_or(0);
+                self.sourcetypes_state.select(Some(i.saturating_sub(10)));
+            }
+            CurrentScreen::Metadata => {
+                let i = self.metadata_state.selected().unwrap_or(0);
+                self.metadata_state.select(Some(i.saturating_sub(10)));
+            }
+            CurrentScreen::Rest => self.scroll_rest_response(-10),
             CurrentScreen::Macros => {
                 let i = self.macros_state.selected().unwrap_or(0);
                 self.macros_state.select(Some(i.saturating_sub(10)));
@@ -372,6 +507,9 @@ impl App {
             CurrentScreen::Apps => {
                 self.apps_state.select(Some(0));
             }
+            CurrentScreen::Inputs if self.inputs_view_mode == InputsViewMode::Http => {
+                self.hec_tokens_state.select(Some(0));
+            }
             CurrentScreen::Inputs => {
                 self.inputs_state.select(Some(0));
             }
@@ -381,6 +519,18 @@ impl App {
             CurrentScreen::Forwarders => {
                 self.forwarders_state.select(Some(0));
             }
+            CurrentScreen::Ingestion => {
+                self.ingestion_state.select(Some(0));
+            }
+            CurrentScreen::Sourcetypes => {
+                self.sourcetypes_state.select(Some(0));
+            }
+            CurrentScreen::Metadata => {
+                self.metadata_state.select(Some(0));
+            }
+            CurrentScreen::Rest => {
+                self.rest_history_state.select(Some(0));
+            }
             CurrentScreen::Macros => {
                 self.macros_state.select(Some(0));
             }
@@ -400,8 +550,9 @@ impl App {
         match self.current_screen {
             CurrentScreen::Search => {
                 // Scroll to the last valid page (offset such that at least one result is visible)
-                if !self.search_results.is_empty() {
-                    self.search_scroll_offset = self.search_results.len().saturating_sub(1);
+                let len = self.visible_search_result_count();
+                if len > 0 {
+                    self.search_scroll_offset = len.saturating_sub(1);
                 } else {
                     self.search_scroll_offset = 0;
                 }
@@ -425,9 +576,9 @@ impl App {
                 }
             }
             CurrentScreen::InternalLogs => {
-                if let Some(logs) = &self.internal_logs {
+                if self.internal_logs.is_some() {
                     self.internal_logs_state
-                        .select(Some(logs.len().saturating_sub(1)));
+                        .select(Some(self.internal_log_row_count().saturating_sub(1)));
                 }
             }
             CurrentScreen::Apps => {
@@ -435,6 +586,12 @@ impl App {
                     self.apps_state.select(Some(apps.len().saturating_sub(1)));
                 }
             }
+            CurrentScreen::Inputs if self.inputs_view_mode == InputsViewMode::Http => {
+                if let Some(tokens) = &self.hec_tokens {
+                    self.hec_tokens_state
+                        .select(Some(tokens.len().saturating_sub(1)));
+                }
+            }
             CurrentScreen::Inputs => {
                 if let Some(inputs) = &self.inputs {
                     self.inputs_state
@@ -453,6 +610,28 @@ impl App {
                         .select(Some(forwarders.len().saturating_sub(1)));
                 }
             }
+            CurrentScreen::Ingestion => {
+                if let Some(ingestion) = &self.ingestion_stats {
+                    self.ingestion_state
+                        .select(Some(ingestion.stats.len().saturating_sub(1)));
+                }
+            }
+            CurrentScreen::Sourcetypes => {
+                if let Some(sourcetypes) = &self.sourcetypes {
+                    self.sourcetypes_state
+                        .select(Some(sourcetypes.len().saturating_sub(1)));
+                }
+            }
+            CurrentScreen::Metadata => {
+                if let Some(entries) = &self.metadata_entries {
+                    self.metadata_state
+                        .select(Some(entries.len().saturating_sub(1)));
+                }
+            }
+            CurrentScreen::Rest => {
+                self.rest_history_state
+                    .select(Some(self.rest_history.len().saturating_sub(1)));
+            }
             CurrentScreen::Macros => {
                 if let Some(macros) = &self.macros {
                     self.macros_state
@@ -519,6 +698,7 @@ mod tests {
                 replication_status: Some(ReplicationStatus::Complete),
                 bundle_replication_count: None,
                 is_captain: Some(i == 0),
+                splunk_version: None,
             })
             .collect()
     }
@@ -0,0 +1,193 @@
+//! Desktop and command notifications for the TUI App module.
+//!
+//! Responsibilities:
+//! - Provide `send` for the configured `NotificationTarget`: a desktop notification
+//!   (`notify-rust`, behind the `notify` feature) or a user-configured shell command.
+//! - Run delivery on a background thread so a slow notification daemon or command
+//!   never blocks the event loop.
+//! - In unit tests, record notifications per thread so tests do not reach the
+//!   desktop or spawn commands.
+//!
+//! Does NOT handle:
+//! - Does NOT decide when to notify (handled by `App` on search completion and
+//!   health transitions).
+//! - Does NOT manage UI feedback (toasts) (handled by `App`).
+//!
+//! Invariants / assumptions:
+//! - Delivery failures are logged, never surfaced as errors: notifications are best-effort.
+//! - Commands get the title and body through environment variables, never by
+//!   interpolation into the command line, and run with stdio detached from the terminal.
+
+#[cfg(test)]
+use std::cell::RefCell;
+use std::process::{Command, Stdio};
+#[cfg(test)]
+use std::sync::{Arc, Mutex};
+
+use splunk_config::{NotificationSettings, NotificationTarget};
+
+/// Environment variable holding the notification title for commands.
+pub const NOTIFY_TITLE_ENV: &str = "SPLUNK_NOTIFY_TITLE";
+/// Environment variable holding the notification body for commands.
+pub const NOTIFY_BODY_ENV: &str = "SPLUNK_NOTIFY_BODY";
+
+/// Whether this build can show desktop notifications.
+pub const DESKTOP_AVAILABLE: bool = cfg!(feature = "notify");
+
+/// A notification to deliver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    pub title: String,
+    pub body: String,
+}
+
+impl Notification {
+    pub fn new(title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            body: body.into(),
+        }
+    }
+}
+
+/// Notifications captured by `record_notifications_for_tests`.
+#[cfg(test)]
+pub(crate) type RecordedNotifications = Arc<Mutex<Vec<(NotificationTarget, Notification)>>>;
+
+#[cfg(test)]
+thread_local! {
+    static RECORDER: RefCell<Option<RecordedNotifications>> = const { RefCell::new(None) };
+}
+
+/// Record notifications sent from this thread instead of delivering them.
+///
+/// Returns the shared list that receives each `(target, notification)`.
+#[cfg(test)]
+pub(crate) fn record_notifications_for_tests() -> RecordedNotifications {
+    let recorded = Arc::new(Mutex::new(Vec::new()));
+    RECORDER.with(|recorder| *recorder.borrow_mut() = Some(recorded.clone()));
+    recorded
+}
+
+/// Whether `target` can deliver with these settings in this build.
+pub fn is_available(settings: &NotificationSettings, target: NotificationTarget) -> bool {
+    match target {
+        NotificationTarget::Off => true,
+        NotificationTarget::Desktop => DESKTOP_AVAILABLE,
+        NotificationTarget::Command => settings.command().is_some(),
+    }
+}
+
+/// Deliver `notification` through the configured target, in the background.
+pub fn send(settings: &NotificationSettings, notification: Notification) {
+    let target = settings.target;
+    if target == NotificationTarget::Off {
+        return;
+    }
+
+    #[cfg(test)]
+    if let Some(recorded) = RECORDER.with(|recorder| recorder.borrow().clone()) {
+        if let Ok(mut recorded) = recorded.lock() {
+            recorded.push((target, notification));
+        }
+        return;
+    }
+
+    let command = settings.command().map(str::to_string);
+    std::thread::spawn(move || {
+        let result = match target {
+            NotificationTarget::Off => Ok(()),
+            NotificationTarget::Desktop => show_desktop(&notification),
+            NotificationTarget::Command => match command {
+                Some(command) => run_command(&command, &notification),
+                None => Err("no notifications.command is configured".to_string()),
+            },
+        };
+        if let Err(e) = result {
+            tracing::warn!("Failed to send {} notification: {}", target, e);
+        }
+    });
+}
+
+#[cfg(feature = "notify")]
+fn show_desktop(notification: &Notification) -> Result<(), String> {
+    notify_rust::Notification::new()
+        .appname("splunk-tui")
+        .summary(&notification.title)
+        .body(&notification.body)
+        .show()
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(not(feature = "notify"))]
+fn show_desktop(_notification: &Notification) -> Result<(), String> {
+    Err("this build has no desktop notification support (rebuild with --features notify)".into())
+}
+
+/// Run `command` through the platform shell and wait for it to exit.
+fn run_command(command: &str, notification: &Notification) -> Result<(), String> {
+    let mut shell = if cfg!(windows) {
+        let mut shell = Command::new("cmd");
+        shell.args(["/C", command]);
+        shell
+    } else {
+        let mut shell = Command::new("sh");
+        shell.args(["-c", command]);
+        shell
+    };
+    let status = shell
+        .env(NOTIFY_TITLE_ENV, &notification.title)
+        .env(NOTIFY_BODY_ENV, &notification.body)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map_err(|e| e.to_string())?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("command exited with {}", status))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_command_passes_title_and_body_in_env() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("notification.txt");
+        let command = format!(
+            "printf '%s|%s' \"${}\" \"${}\" > '{}'",
+            NOTIFY_TITLE_ENV,
+            NOTIFY_BODY_ENV,
+            out.display()
+        );
+
+        run_command(
+            &command,
+            &Notification::new("Search done", "index=main; rm -rf x"),
+        )
+        .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&out).unwrap(),
+            "Search done|index=main; rm -rf x"
+        );
+        assert!(run_command("exit 3", &Notification::new("t", "b")).is_err());
+    }
+
+    #[test]
+    fn test_command_target_needs_a_command() {
+        let mut settings = NotificationSettings {
+            target: NotificationTarget::Command,
+            ..Default::default()
+        };
+        assert!(!is_available(&settings, NotificationTarget::Command));
+        settings.command = Some("notify-send \"$SPLUNK_NOTIFY_TITLE\"".to_string());
+        assert!(is_available(&settings, NotificationTarget::Command));
+        assert!(is_available(&settings, NotificationTarget::Off));
+    }
+}
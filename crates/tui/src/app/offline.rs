@@ -0,0 +1,98 @@
+//! Offline snapshot display state.
+//!
+//! Responsibilities:
+//! - Apply snapshot data to screens whose refresh failed because the server is unreachable
+//! - Track which screens currently show stale data and since when
+//! - Build the "OFFLINE" banner text for the current screen
+//!
+//! Does NOT handle:
+//! - Reading or writing the snapshot cache (see `runtime::offline`)
+//! - Deciding whether an error means the server is unreachable (see `ClientError::is_unreachable`)
+//!
+//! Invariants:
+//! - A screen leaves offline mode as soon as a fresh load for it succeeds
+
+use splunk_config::SnapshotKind;
+
+use crate::action::OfflineData;
+use crate::app::App;
+use crate::app::state::CurrentScreen;
+
+/// Snapshot kind shown on `screen`, if the screen supports offline data.
+fn snapshot_kind_for_screen(screen: CurrentScreen) -> Option<SnapshotKind> {
+    match screen {
+        CurrentScreen::Indexes => Some(SnapshotKind::Indexes),
+        CurrentScreen::Jobs => Some(SnapshotKind::Jobs),
+        CurrentScreen::Health => Some(SnapshotKind::Health),
+        _ => None,
+    }
+}
+
+impl App {
+    /// Replace a screen's data with a snapshot and mark it offline.
+    pub(crate) fn apply_offline_snapshot(&mut self, saved_at: u64, data: OfflineData) {
+        let kind = match data {
+            OfflineData::Indexes(indexes) => {
+                self.handle_indexes_loaded(indexes);
+                SnapshotKind::Indexes
+            }
+            OfflineData::Jobs(jobs) => {
+                self.handle_jobs_loaded(jobs);
+                SnapshotKind::Jobs
+            }
+            OfflineData::Health(health) => {
+                // Keep the header health indicator as-is; the snapshot says nothing about now.
+                self.health_info = Some(*health);
+                self.loading = false;
+                SnapshotKind::Health
+            }
+        };
+        self.offline_snapshots.insert(kind, saved_at);
+    }
+
+    /// Unix timestamp of the snapshot shown on the current screen, if it is offline.
+    pub fn offline_since(&self) -> Option<u64> {
+        snapshot_kind_for_screen(self.current_screen)
+            .and_then(|kind| self.offline_snapshots.get(&kind).copied())
+    }
+
+    /// Banner text for the current screen while it shows snapshot data.
+    pub fn offline_banner(&self) -> Option<String> {
+        let saved_at = self.offline_since()?;
+        let timestamp = chrono::DateTime::from_timestamp(saved_at as i64, 0)
+            .map(|time| {
+                time.with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+            })
+            .unwrap_or_else(|| "unknown time".to_string());
+        Some(format!(
+            "OFFLINE — data from {} (server unreachable, press r to retry)",
+            timestamp
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::field_reassign_with_default)]
+    fn test_banner_only_on_offline_screen() {
+        let mut app = App::default();
+        app.apply_offline_snapshot(1_700_000_000, OfflineData::Jobs(Vec::new()));
+
+        app.current_screen = CurrentScreen::Jobs;
+        assert_eq!(app.offline_since(), Some(1_700_000_000));
+        assert!(
+            app.offline_banner()
+                .unwrap()
+                .starts_with("OFFLINE — data from ")
+        );
+
+        app.current_screen = CurrentScreen::Indexes;
+        assert_eq!(app.offline_since(), None);
+        assert!(app.offline_banner().is_none());
+    }
+}
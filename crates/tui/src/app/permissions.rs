@@ -0,0 +1,204 @@
+//! Capability-aware gating of screen actions.
+//!
+//! Responsibilities:
+//! - Map mutating screen keys to the Splunk capability they require
+//! - Report which actions the logged-in user cannot perform
+//!
+//! Does NOT handle:
+//! - Does NOT fetch the user's capabilities (handled by Action::LoadCurrentContext)
+//! - Does NOT replace server-side authorization (Splunk still rejects the request)
+//!
+//! Invariants:
+//! - Until the current context is loaded every action is allowed
+//! - Only unmodified (or shifted) character keys are gated; Ctrl/Alt chords pass through
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::App;
+use crate::app::state::CurrentScreen;
+
+/// A screen key that requires a Splunk capability.
+struct RestrictedKey {
+    screen: CurrentScreen,
+    key: char,
+    capability: &'static str,
+}
+
+const RESTRICTED_KEYS: &[RestrictedKey] = &[
+    RestrictedKey {
+        screen: CurrentScreen::Indexes,
+        key: 'c',
+        capability: "indexes_edit",
+    },
+    RestrictedKey {
+        screen: CurrentScreen::Indexes,
+        key: 'w',
+        capability: "indexes_edit",
+    },
+    RestrictedKey {
+        screen: CurrentScreen::Indexes,
+        key: 'm',
+        capability: "indexes_edit",
+    },
+    RestrictedKey {
+        screen: CurrentScreen::Indexes,
+        key: 'd',
+        capability: "indexes_edit",
+    },
+    RestrictedKey {
+        screen: CurrentScreen::Indexes,
+        key: 'b',
+        capability: "indexes_edit",
+    },
+    RestrictedKey {
+        screen: CurrentScreen::Indexes,
+        key: 'B',
+        capability: "indexes_edit",
+    },
+    RestrictedKey {
+        screen: CurrentScreen::Cluster,
+        key: 'm',
+        capability: "edit_indexer_cluster",
+    },
+    RestrictedKey {
+        screen: CurrentScreen::Cluster,
+        key: 'd',
+        capability: "edit_indexer_cluster",
+    },
+    RestrictedKey {
+        screen: CurrentScreen::Cluster,
+        key: 'x',
+        capability: "edit_indexer_cluster",
+    },
+    RestrictedKey {
+        screen: CurrentScreen::Users,
+        key: 'c',
+        capability: "edit_user",
+    },
+    RestrictedKey {
+        screen: CurrentScreen::Users,
+        key: 'm',
+        capability: "edit_user",
+    },
+    RestrictedKey {
+        screen: CurrentScreen::Users,
+        key: 'd',
+        capability: "edit_user",
+    },
+    RestrictedKey {
+        screen: CurrentScreen::Users,
+        key: 'U',
+        capability: "edit_user",
+    },
+    RestrictedKey {
+        screen: CurrentScreen::Roles,
+        key: 'c',
+        capability: "edit_roles",
+    },
+    RestrictedKey {
+        screen: CurrentScreen::Roles,
+        key: 'm',
+        capability: "edit_roles",
+    },
+    RestrictedKey {
+        screen: CurrentScreen::Roles,
+        key: 'd',
+        capability: "edit_roles",
+    },
+];
+
+impl App {
+    /// Returns true if the logged-in user may use `capability`.
+    ///
+    /// Permissive while the current context is unknown (not loaded yet or the
+    /// lookup failed), so the TUI never locks out actions it cannot verify.
+    pub fn has_capability(&self, capability: &str) -> bool {
+        self.current_context
+            .as_ref()
+            .is_none_or(|context| context.has_capability(capability))
+    }
+
+    /// Capability the user lacks for `key` on the current screen, if any.
+    pub fn missing_capability_for_key(&self, key: KeyEvent) -> Option<&'static str> {
+        let KeyCode::Char(c) = key.code else {
+            return None;
+        };
+        if key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return None;
+        }
+
+        RESTRICTED_KEYS
+            .iter()
+            .find(|restricted| restricted.screen == self.current_screen && restricted.key == c)
+            .map(|restricted| restricted.capability)
+            .filter(|capability| !self.has_capability(capability))
+    }
+
+    /// Key labels on the current screen the user lacks permission for.
+    pub fn denied_keys(&self) -> Vec<String> {
+        RESTRICTED_KEYS
+            .iter()
+            .filter(|restricted| restricted.screen == self.current_screen)
+            .filter(|restricted| !self.has_capability(restricted.capability))
+            .map(|restricted| restricted.key.to_string())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use splunk_client::models::CurrentContext;
+
+    fn context_with(capabilities: &[&str]) -> CurrentContext {
+        CurrentContext {
+            username: "analyst".to_string(),
+            capabilities: capabilities.iter().map(|c| c.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    #[allow(clippy::field_reassign_with_default)]
+    fn test_everything_allowed_without_context() {
+        let mut app = App::default();
+        app.current_screen = CurrentScreen::Indexes;
+        let key = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE);
+        assert_eq!(app.missing_capability_for_key(key), None);
+        assert!(app.denied_keys().is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::field_reassign_with_default)]
+    fn test_missing_capability_blocks_screen_keys() {
+        let mut app = App::default();
+        app.current_screen = CurrentScreen::Indexes;
+        app.current_context = Some(context_with(&["search"]));
+
+        let delete = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE);
+        let rebalance = KeyEvent::new(KeyCode::Char('B'), KeyModifiers::SHIFT);
+        let copy = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert_eq!(app.missing_capability_for_key(delete), Some("indexes_edit"));
+        assert_eq!(
+            app.missing_capability_for_key(rebalance),
+            Some("indexes_edit")
+        );
+        assert_eq!(app.missing_capability_for_key(copy), None);
+        assert_eq!(app.denied_keys(), vec!["c", "w", "m", "d", "b", "B"]);
+    }
+
+    #[test]
+    #[allow(clippy::field_reassign_with_default)]
+    fn test_granted_capability_allows_keys() {
+        let mut app = App::default();
+        app.current_screen = CurrentScreen::Cluster;
+        app.current_context = Some(context_with(&["edit_indexer_cluster"]));
+
+        let key = KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE);
+        assert_eq!(app.missing_capability_for_key(key), None);
+        assert!(app.denied_keys().is_empty());
+    }
+}
@@ -0,0 +1,249 @@
+//! Permissions (ACL) popup handler.
+//!
+//! Responsibilities:
+//! - Open the popup with a loaded knowledge object ACL
+//! - Edit the owner and role inputs and cycle the sharing level
+//! - Emit UpdateObjectAcl with only the changed fields on Enter
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::popup module)
+//! - Does NOT fetch or write the ACL (handled by Action::LoadObjectAcl/UpdateObjectAcl)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::ui::popup::{AclField, Popup, PopupType};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use splunk_client::models::{AclUpdateParams, ObjectAcl};
+
+/// Sharing levels, in cycle order.
+const SHARING_LEVELS: [&str; 3] = ["user", "app", "global"];
+
+impl App {
+    /// Show a loaded ACL in the permissions popup.
+    pub(crate) fn handle_object_acl_loaded(&mut self, acl: ObjectAcl) {
+        self.loading = false;
+        self.popup = Some(
+            Popup::builder(PopupType::EditAcl {
+                owner_input: acl.owner.clone(),
+                sharing: acl.sharing.clone(),
+                read_input: acl.read.join(","),
+                write_input: acl.write.join(","),
+                selected_field: AclField::Owner,
+                acl,
+            })
+            .build(),
+        );
+    }
+
+    /// Handle input for the EditAcl popup.
+    pub fn handle_acl_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        let Some(mut kind @ PopupType::EditAcl { .. }) =
+            self.popup.as_ref().map(|popup| popup.kind.clone())
+        else {
+            return None;
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                self.popup = None;
+                None
+            }
+            KeyCode::Enter => {
+                self.popup = None;
+                acl_update_from_popup(&kind)
+            }
+            KeyCode::Tab | KeyCode::Down => {
+                kind.navigate_fields(key.modifiers.contains(KeyModifiers::SHIFT));
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::BackTab | KeyCode::Up => {
+                kind.navigate_fields(true);
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::Left | KeyCode::Right | KeyCode::Char(_) | KeyCode::Backspace => {
+                if update_acl_input(&mut kind, key.code) {
+                    self.replace_popup_kind(kind);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+fn parse_roles(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .map(|role| role.trim().to_string())
+        .filter(|role| !role.is_empty())
+        .collect()
+}
+
+/// Build an update holding only the fields changed in the popup.
+///
+/// Returns `None` when nothing changed or the user may not change the ACL.
+fn acl_update_from_popup(kind: &PopupType) -> Option<Action> {
+    let PopupType::EditAcl {
+        acl,
+        owner_input,
+        sharing,
+        read_input,
+        write_input,
+        ..
+    } = kind
+    else {
+        return None;
+    };
+    if !acl.can_change_perms {
+        return None;
+    }
+
+    let owner = owner_input.trim();
+    let shared = sharing != "user";
+    let read = parse_roles(read_input);
+    let write = parse_roles(write_input);
+    let params = AclUpdateParams {
+        sharing: (*sharing != acl.sharing).then(|| sharing.clone()),
+        owner: (!owner.is_empty() && owner != acl.owner).then(|| owner.to_string()),
+        read: (shared && read != acl.read).then_some(read),
+        write: (shared && write != acl.write).then_some(write),
+    };
+
+    (!params.is_empty()).then(|| Action::UpdateObjectAcl {
+        kind: acl.kind,
+        name: acl.name.clone(),
+        params,
+    })
+}
+
+fn update_acl_input(kind: &mut PopupType, code: KeyCode) -> bool {
+    let PopupType::EditAcl {
+        acl,
+        owner_input,
+        sharing,
+        read_input,
+        write_input,
+        selected_field,
+    } = kind
+    else {
+        return false;
+    };
+    if !acl.can_change_perms {
+        return false;
+    }
+
+    let input = match selected_field {
+        AclField::Owner => owner_input,
+        AclField::Read => read_input,
+        AclField::Write => write_input,
+        AclField::Sharing => {
+            let current = SHARING_LEVELS
+                .iter()
+                .position(|level| *level == sharing.as_str())
+                .unwrap_or(0);
+            let next = match code {
+                KeyCode::Left => (current + SHARING_LEVELS.len() - 1) % SHARING_LEVELS.len(),
+                KeyCode::Right | KeyCode::Char(' ') => (current + 1) % SHARING_LEVELS.len(),
+                _ => return false,
+            };
+            *sharing = SHARING_LEVELS[next].to_string();
+            return true;
+        }
+    };
+    match code {
+        KeyCode::Char(c) => input.push(c),
+        KeyCode::Backspace => {
+            input.pop();
+        }
+        _ => return false,
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use splunk_client::models::KnowledgeObjectKind;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn acl(can_change_perms: bool) -> ObjectAcl {
+        ObjectAcl {
+            name: "Errors".to_string(),
+            kind: KnowledgeObjectKind::SavedSearch,
+            app: "search".to_string(),
+            owner: "admin".to_string(),
+            sharing: "app".to_string(),
+            read: vec!["*".to_string()],
+            write: vec!["admin".to_string()],
+            can_change_perms,
+        }
+    }
+
+    #[test]
+    fn test_acl_popup_sends_only_changed_fields() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.loading = true;
+        app.handle_object_acl_loaded(acl(true));
+        assert!(!app.loading);
+
+        // Owner -> Sharing -> Read
+        app.handle_popup_input(key(KeyCode::Tab));
+        app.handle_popup_input(key(KeyCode::Right));
+        app.handle_popup_input(key(KeyCode::Tab));
+        app.handle_popup_input(key(KeyCode::Backspace));
+        for c in "user, power".chars() {
+            app.handle_popup_input(key(KeyCode::Char(c)));
+        }
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+
+        assert!(matches!(
+            action,
+            Some(Action::UpdateObjectAcl { kind: KnowledgeObjectKind::SavedSearch, name, params })
+                if name == "Errors"
+                    && params.sharing.as_deref() == Some("global")
+                    && params.owner.is_none()
+                    && params.read == Some(vec!["user".to_string(), "power".to_string()])
+                    && params.write.is_none()
+        ));
+        assert!(app.popup.is_none());
+    }
+
+    #[test]
+    fn test_acl_popup_private_sharing_drops_roles() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.handle_object_acl_loaded(acl(true));
+
+        app.handle_popup_input(key(KeyCode::Tab));
+        app.handle_popup_input(key(KeyCode::Left));
+        app.handle_popup_input(key(KeyCode::Tab));
+        app.handle_popup_input(key(KeyCode::Char('x')));
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+
+        assert!(matches!(
+            action,
+            Some(Action::UpdateObjectAcl { params, .. })
+                if params.sharing.as_deref() == Some("user") && params.read.is_none()
+        ));
+    }
+
+    #[test]
+    fn test_acl_popup_without_changes_or_permission_sends_nothing() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.handle_object_acl_loaded(acl(true));
+        assert!(app.handle_popup_input(key(KeyCode::Enter)).is_none());
+
+        app.handle_object_acl_loaded(acl(false));
+        app.handle_popup_input(key(KeyCode::Char('x')));
+        assert!(matches!(
+            app.popup.as_ref().map(|p| &p.kind),
+            Some(PopupType::EditAcl { owner_input, .. }) if owner_input == "admin"
+        ));
+        assert!(app.handle_popup_input(key(KeyCode::Enter)).is_none());
+    }
+}
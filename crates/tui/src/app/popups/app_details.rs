@@ -0,0 +1,108 @@
+//! App Details popup handler.
+//!
+//! Responsibilities:
+//! - Open the App Details popup once an app inventory has loaded
+//! - Switch object-kind tabs and move the selection within a tab
+//! - Copy the selected object's name to the clipboard
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::app_details)
+//! - Does NOT fetch the inventory (handled by Action::LoadAppContents)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::app::app_details::AppDetails;
+use crate::ui::popup::{Popup, PopupType};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use splunk_client::workflows::app_contents::AppContents;
+
+impl App {
+    /// Show a loaded app inventory in the App Details popup.
+    pub(crate) fn handle_app_contents_loaded(&mut self, contents: AppContents) {
+        self.loading = false;
+        self.app_details = Some(AppDetails::new(contents));
+        self.popup = Some(Popup::builder(PopupType::AppDetails).build());
+    }
+
+    /// Handle input for the AppDetails popup.
+    pub fn handle_app_details_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        let details = self.app_details.as_mut()?;
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.popup = None;
+                None
+            }
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => details
+                .selected_object()
+                .map(|object| Action::CopyToClipboard(object.name.clone())),
+            KeyCode::Tab | KeyCode::Char('l') | KeyCode::Right => {
+                details.next_tab();
+                None
+            }
+            KeyCode::BackTab | KeyCode::Char('h') | KeyCode::Left => {
+                details.previous_tab();
+                None
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                details.select_next();
+                None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                details.select_previous();
+                None
+            }
+            KeyCode::Char('r') => Some(Action::LoadAppContents {
+                app: details.contents.app.clone(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use splunk_client::workflows::app_contents::{AppObject, AppObjectKind};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn object(kind: AppObjectKind, name: &str) -> AppObject {
+        AppObject {
+            kind,
+            name: name.to_string(),
+            detail: String::new(),
+            disabled: false,
+        }
+    }
+
+    #[test]
+    fn test_app_details_tabs_copy_and_close() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.handle_app_contents_loaded(AppContents {
+            app: "my_app".to_string(),
+            objects: vec![
+                object(AppObjectKind::SavedSearch, "Errors by host"),
+                object(AppObjectKind::Lookup, "hosts.csv"),
+            ],
+        });
+        assert!(matches!(
+            app.popup.as_ref().map(|p| &p.kind),
+            Some(PopupType::AppDetails)
+        ));
+
+        app.handle_popup_input(key(KeyCode::Tab));
+        app.handle_popup_input(key(KeyCode::Tab));
+        let action =
+            app.handle_popup_input(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert!(matches!(action, Some(Action::CopyToClipboard(name)) if name == "hosts.csv"));
+
+        let action = app.handle_popup_input(key(KeyCode::Char('r')));
+        assert!(matches!(action, Some(Action::LoadAppContents { app }) if app == "my_app"));
+
+        app.handle_popup_input(key(KeyCode::Esc));
+        assert!(app.popup.is_none());
+    }
+}
@@ -0,0 +1,103 @@
+//! Background jobs tray popup handler.
+//!
+//! Responsibilities:
+//! - Open the jobs tray listing running and recently finished searches and operations
+//! - Move the selection and open the selected job: the Search screen for a
+//!   search, the progress popup for a running operation
+//!
+//! Does NOT handle:
+//! - Does NOT track jobs (handled by app::background_jobs)
+//! - Does NOT render the popup (handled by ui::background_jobs)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::app::background_jobs::BackgroundJobKind;
+use crate::ui::popup::{Popup, PopupType};
+use crossterm::event::{KeyCode, KeyEvent};
+
+impl App {
+    /// Open the jobs tray with the newest job selected.
+    pub(crate) fn open_background_jobs(&mut self) {
+        self.background_jobs.selected = 0;
+        self.popup = Some(Popup::builder(PopupType::BackgroundJobs).build());
+    }
+
+    /// Handle input for the BackgroundJobs popup.
+    pub fn handle_background_jobs_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.popup = None;
+                None
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.background_jobs.select_next();
+                None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.background_jobs.select_previous();
+                None
+            }
+            KeyCode::Enter => {
+                let job = self.background_jobs.selected_job()?;
+                match job.kind {
+                    BackgroundJobKind::Search => {
+                        self.popup = None;
+                        Some(Action::SwitchToSearch)
+                    }
+                    BackgroundJobKind::Operation { id } => {
+                        if job.is_running()
+                            && self.operation_progress.as_ref().map(|p| p.id) == Some(id)
+                        {
+                            self.popup = Some(Popup::builder(PopupType::OperationProgress).build());
+                        }
+                        None
+                    }
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::operation_progress::OperationProgress;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_enter_on_search_switches_to_search_screen() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.background_jobs.start_search("index=main");
+        app.update(Action::OpenBackgroundJobs);
+        assert!(matches!(
+            app.popup.as_ref().map(|p| &p.kind),
+            Some(PopupType::BackgroundJobs)
+        ));
+
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+        assert!(matches!(action, Some(Action::SwitchToSearch)));
+        assert!(app.popup.is_none());
+    }
+
+    #[test]
+    fn test_enter_on_running_operation_reopens_progress_popup() {
+        let mut app = App::new(None, ConnectionContext::default());
+        let progress = OperationProgress::new("Deleting 2 job(s)", Some(2));
+        app.update(Action::OperationStarted(Box::new(progress)));
+        app.handle_popup_input(key(KeyCode::Char('b')));
+        assert!(app.popup.is_none());
+
+        app.update(Action::OpenBackgroundJobs);
+        assert!(app.handle_popup_input(key(KeyCode::Enter)).is_none());
+        assert!(matches!(
+            app.popup.as_ref().map(|p| &p.kind),
+            Some(PopupType::OperationProgress)
+        ));
+    }
+}
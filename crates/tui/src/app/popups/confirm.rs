@@ -33,6 +33,10 @@ impl PopupType {
                 | PopupType::DeleteRoleConfirm { .. }
                 | PopupType::DeleteProfileConfirm { .. }
                 | PopupType::DeleteSavedSearchConfirm { .. }
+                | PopupType::ConfirmRemoveSearchPeer(_)
+                | PopupType::ConfirmQuarantineSearchPeer { .. }
+                | PopupType::ConfirmRollHotBuckets(_)
+                | PopupType::ConfirmRebalanceIndex(_)
         )
     }
 }
@@ -56,6 +60,12 @@ impl App {
             }),
             PopupType::ConfirmEnableApp(name) => Some(Action::EnableApp(name)),
             PopupType::ConfirmDisableApp(name) => Some(Action::DisableApp(name)),
+            PopupType::ConfirmRemoveSearchPeer(name) => Some(Action::RemoveSearchPeer(name)),
+            PopupType::ConfirmQuarantineSearchPeer { name, quarantine } => {
+                Some(Action::SetSearchPeerQuarantine { name, quarantine })
+            }
+            PopupType::ConfirmRollHotBuckets(name) => Some(Action::RollHotBuckets { name }),
+            PopupType::ConfirmRebalanceIndex(index) => Some(Action::RebalanceIndexData { index }),
             PopupType::ConfirmRemoveApp(name) => Some(Action::QueueUndoableOperation {
                 operation: UndoableOperation::RemoveApp {
                     app_name: name.clone(),
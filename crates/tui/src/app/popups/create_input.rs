@@ -0,0 +1,260 @@
+//! Create input popup handler.
+//!
+//! Responsibilities:
+//! - Handle the add data input form (type, path or port, index, sourcetype,
+//!   whitelist/blacklist)
+//! - Form navigation and input handling
+//! - Validate the port before building `CreateInputParams`
+//!
+//! Does NOT handle:
+//! - Does NOT render popups (handled by ui::popup module)
+//! - Does NOT call the API (handled by the side-effect dispatcher)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::ui::Toast;
+use crate::ui::popup::{CreateInputField, CreateInputKind, PopupType};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use splunk_client::{CreateInputParams, NewInput};
+
+use super::common::optional_string;
+
+impl App {
+    /// Handle the create input popup.
+    pub fn handle_create_input_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        let Some(mut kind @ PopupType::CreateInput { .. }) =
+            self.popup.as_ref().map(|popup| popup.kind.clone())
+        else {
+            return None;
+        };
+
+        match key.code {
+            KeyCode::Enter => {
+                let params = match build_create_input_params(kind) {
+                    Ok(Some(params)) => params,
+                    // Path or port still empty: keep the form open.
+                    Ok(None) => return None,
+                    Err(message) => {
+                        self.toasts.push(Toast::warning(message));
+                        return None;
+                    }
+                };
+                self.popup = None;
+                Some(Action::CreateInput { params })
+            }
+            KeyCode::Esc => {
+                self.popup = None;
+                None
+            }
+            KeyCode::Tab => {
+                kind.navigate_fields(key.modifiers.contains(KeyModifiers::SHIFT));
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::BackTab | KeyCode::Up => {
+                kind.navigate_fields(true);
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::Down => {
+                kind.navigate_fields(false);
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::Left | KeyCode::Right | KeyCode::Char(_) | KeyCode::Backspace => {
+                if update_create_input(&mut kind, key.code) {
+                    self.replace_popup_kind(kind);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+fn update_create_input(kind: &mut PopupType, code: KeyCode) -> bool {
+    let PopupType::CreateInput {
+        kind: input_kind,
+        target_input,
+        index_input,
+        sourcetype_input,
+        whitelist_input,
+        blacklist_input,
+        selected_field,
+    } = kind
+    else {
+        return false;
+    };
+
+    let input = match (*selected_field, code) {
+        (CreateInputField::Kind, KeyCode::Char(' ') | KeyCode::Right) => {
+            *input_kind = input_kind.next();
+            return true;
+        }
+        (CreateInputField::Kind, KeyCode::Left) => {
+            *input_kind = input_kind.previous();
+            return true;
+        }
+        (CreateInputField::Kind, _) => return false,
+        (CreateInputField::Target, _) => target_input,
+        (CreateInputField::Index, _) => index_input,
+        (CreateInputField::Sourcetype, _) => sourcetype_input,
+        (CreateInputField::Whitelist, _) => whitelist_input,
+        (CreateInputField::Blacklist, _) => blacklist_input,
+    };
+    match code {
+        KeyCode::Char(c) => input.push(c),
+        KeyCode::Backspace => {
+            input.pop();
+        }
+        _ => return false,
+    }
+    true
+}
+
+/// Build the create params from the form, or `None` while the path/port is empty.
+fn build_create_input_params(kind: PopupType) -> Result<Option<CreateInputParams>, String> {
+    let PopupType::CreateInput {
+        kind: input_kind,
+        target_input,
+        index_input,
+        sourcetype_input,
+        whitelist_input,
+        blacklist_input,
+        ..
+    } = kind
+    else {
+        return Ok(None);
+    };
+
+    let target = target_input.trim();
+    if target.is_empty() {
+        return Ok(None);
+    }
+
+    let port = || {
+        target
+            .parse::<u16>()
+            .ok()
+            .filter(|port| *port > 0)
+            .ok_or_else(|| format!("Invalid port '{}': expected 1-65535", target))
+    };
+    let input = match input_kind {
+        CreateInputKind::Monitor => NewInput::Monitor {
+            path: target.to_string(),
+            whitelist: optional_string(whitelist_input.trim().to_string()),
+            blacklist: optional_string(blacklist_input.trim().to_string()),
+            recursive: None,
+        },
+        CreateInputKind::Tcp => NewInput::Tcp { port: port()? },
+        CreateInputKind::Udp => NewInput::Udp { port: port()? },
+    };
+
+    Ok(Some(CreateInputParams {
+        input,
+        index: optional_string(index_input.trim().to_string()),
+        sourcetype: optional_string(sourcetype_input.trim().to_string()),
+        host: None,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::ui::popup::Popup;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn app_with_form() -> App {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.popup = Some(
+            Popup::builder(PopupType::CreateInput {
+                kind: CreateInputKind::Monitor,
+                target_input: String::new(),
+                index_input: String::new(),
+                sourcetype_input: String::new(),
+                whitelist_input: String::new(),
+                blacklist_input: String::new(),
+                selected_field: CreateInputField::Kind,
+            })
+            .build(),
+        );
+        app
+    }
+
+    fn type_text(app: &mut App, text: &str) {
+        for c in text.chars() {
+            app.handle_popup_input(key(KeyCode::Char(c)));
+        }
+    }
+
+    #[test]
+    fn test_create_input_form_submits_monitor_params() {
+        let mut app = app_with_form();
+
+        app.handle_popup_input(key(KeyCode::Tab));
+        type_text(&mut app, "/var/log/app");
+        app.handle_popup_input(key(KeyCode::Tab));
+        type_text(&mut app, "app");
+        app.handle_popup_input(key(KeyCode::Tab));
+        type_text(&mut app, "app:log");
+        app.handle_popup_input(key(KeyCode::Tab));
+        type_text(&mut app, r"\.log$");
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+
+        match action {
+            Some(Action::CreateInput { params }) => {
+                assert_eq!(params.name(), "/var/log/app");
+                assert_eq!(params.index.as_deref(), Some("app"));
+                assert_eq!(params.sourcetype.as_deref(), Some("app:log"));
+                assert!(matches!(
+                    params.input,
+                    NewInput::Monitor { whitelist: Some(ref w), blacklist: None, .. } if w == r"\.log$"
+                ));
+            }
+            other => panic!("Expected CreateInput, got {:?}", other),
+        }
+        assert!(app.popup.is_none());
+    }
+
+    #[test]
+    fn test_create_input_form_udp_skips_monitor_fields_and_validates_port() {
+        let mut app = app_with_form();
+
+        // monitor -> tcp -> udp
+        app.handle_popup_input(key(KeyCode::Right));
+        app.handle_popup_input(key(KeyCode::Right));
+        let content = &app.popup.as_ref().unwrap().content;
+        assert!(content.contains("Type: udp"));
+        assert!(!content.contains("Whitelist"));
+
+        // Backwards from Type wraps to Sourcetype, not the hidden Blacklist field.
+        app.handle_popup_input(key(KeyCode::BackTab));
+        assert!(matches!(
+            app.popup.as_ref().unwrap().kind,
+            PopupType::CreateInput {
+                selected_field: CreateInputField::Sourcetype,
+                ..
+            }
+        ));
+
+        app.handle_popup_input(key(KeyCode::Up));
+        app.handle_popup_input(key(KeyCode::Up));
+        type_text(&mut app, "70000");
+        assert!(app.handle_popup_input(key(KeyCode::Enter)).is_none());
+        assert!(app.popup.is_some());
+
+        for _ in 0..5 {
+            app.handle_popup_input(key(KeyCode::Backspace));
+        }
+        type_text(&mut app, "514");
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+        assert!(matches!(
+            action,
+            Some(Action::CreateInput { ref params }) if params.input == NewInput::Udp { port: 514 }
+        ));
+    }
+}
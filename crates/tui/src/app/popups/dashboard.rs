@@ -0,0 +1,121 @@
+//! Dashboard source popup handler.
+//!
+//! Responsibilities:
+//! - Handle scrolling through the dashboard source view
+//! - Copy the source to the clipboard and hand off to the export popup
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::dashboard_source)
+//! - Does NOT fetch dashboard sources (handled by Action::LoadDashboardSource)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::app::export::ExportTarget;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+impl App {
+    /// Handle input for the DashboardSource popup.
+    pub fn handle_dashboard_source_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.popup = None;
+                self.dashboard_source_scroll_offset = 0;
+                None
+            }
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => self
+                .dashboard_source
+                .as_ref()
+                .and_then(|dashboard| dashboard.pretty_source())
+                .map(Action::CopyToClipboard),
+            KeyCode::Char('e') => {
+                if self.dashboard_source.is_some() {
+                    self.begin_export(ExportTarget::Dashboard);
+                }
+                None
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.dashboard_source_scroll_offset =
+                    self.dashboard_source_scroll_offset.saturating_add(1);
+                None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.dashboard_source_scroll_offset =
+                    self.dashboard_source_scroll_offset.saturating_sub(1);
+                None
+            }
+            KeyCode::PageDown => {
+                self.dashboard_source_scroll_offset =
+                    self.dashboard_source_scroll_offset.saturating_add(10);
+                None
+            }
+            KeyCode::PageUp => {
+                self.dashboard_source_scroll_offset =
+                    self.dashboard_source_scroll_offset.saturating_sub(10);
+                None
+            }
+            KeyCode::Home => {
+                self.dashboard_source_scroll_offset = 0;
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::ui::popup::{Popup, PopupType};
+    use splunk_client::models::Dashboard;
+
+    fn app_with_source() -> App {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.dashboard_source = Some(Dashboard {
+            name: "ops".to_string(),
+            label: "Ops".to_string(),
+            description: None,
+            author: "admin".to_string(),
+            is_dashboard: true,
+            is_visible: true,
+            version: None,
+            xml_data: Some("<dashboard><label>Ops</label></dashboard>".to_string()),
+            updated: None,
+        });
+        app.popup = Some(Popup::builder(PopupType::DashboardSource).build());
+        app
+    }
+
+    #[test]
+    fn test_dashboard_source_export_opens_export_popup() {
+        let mut app = app_with_source();
+
+        let action = app.handle_popup_input(KeyEvent::new(KeyCode::Char('e'), KeyModifiers::NONE));
+
+        assert!(action.is_none());
+        assert_eq!(app.export_target, Some(ExportTarget::Dashboard));
+        assert!(matches!(
+            app.popup.as_ref().map(|p| &p.kind),
+            Some(PopupType::ExportSearch)
+        ));
+        let data = app.collect_export_data().unwrap().unwrap();
+        assert_eq!(data["format"], "Simple XML");
+        assert_eq!(
+            data["source"],
+            "<dashboard>\n  <label>Ops</label>\n</dashboard>\n"
+        );
+    }
+
+    #[test]
+    fn test_dashboard_source_scroll_and_close() {
+        let mut app = app_with_source();
+
+        app.handle_popup_input(KeyEvent::new(KeyCode::PageDown, KeyModifiers::NONE));
+        app.handle_popup_input(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE));
+        assert_eq!(app.dashboard_source_scroll_offset, 9);
+
+        app.handle_popup_input(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(app.popup.is_none());
+        assert_eq!(app.dashboard_source_scroll_offset, 0);
+    }
+}
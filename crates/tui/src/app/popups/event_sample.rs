@@ -0,0 +1,126 @@
+//! Index event sample popup handler.
+//!
+//! Responsibilities:
+//! - Open the viewer when a sample arrives, or report an empty/failed sample
+//! - Move the selection, toggle line wrapping, and copy the selected raw event
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::event_sample)
+//! - Does NOT run the search (handled by side effects)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::app::event_sample::EventSampleView;
+use crate::ui::Toast;
+use crate::ui::popup::{Popup, PopupType};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use splunk_client::workflows::event_sample::SampleEvent;
+
+impl App {
+    pub(crate) fn handle_index_sample_loaded(
+        &mut self,
+        index: String,
+        result: Result<Vec<SampleEvent>, String>,
+    ) {
+        self.loading = false;
+        match result {
+            Ok(events) => match EventSampleView::new(index.clone(), events) {
+                Some(view) => {
+                    self.event_sample = Some(view);
+                    self.popup = Some(Popup::builder(PopupType::EventSample).build());
+                }
+                None => self
+                    .toasts
+                    .push(Toast::info(format!("No events in index '{}'", index))),
+            },
+            Err(message) => self.toasts.push(Toast::error(format!(
+                "Failed to sample index '{}': {}",
+                index, message
+            ))),
+        }
+    }
+
+    /// Handle input for the EventSample popup.
+    pub fn handle_event_sample_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        if matches!(key.code, KeyCode::Esc | KeyCode::Char('q')) {
+            self.popup = None;
+            self.event_sample = None;
+            return None;
+        }
+
+        let view = self.event_sample.as_mut()?;
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => view.next(),
+            KeyCode::Char('k') | KeyCode::Up => view.previous(),
+            KeyCode::Char('g') | KeyCode::Home => view.first(),
+            KeyCode::Char('G') | KeyCode::End => view.last(),
+            KeyCode::Char('w') => view.wrap = !view.wrap,
+            KeyCode::Char('y') => {
+                return Some(Action::CopyToClipboard(view.selected_event().raw.clone()));
+            }
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Some(Action::CopyToClipboard(view.selected_event().raw.clone()));
+            }
+            _ => {}
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn event(raw: &str) -> SampleEvent {
+        SampleEvent {
+            raw: raw.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_index_sample_loaded_opens_viewer_and_copies_selected_event() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.handle_index_sample_loaded(
+            "web".to_string(),
+            Ok(vec![event("first"), event("second")]),
+        );
+        assert!(matches!(
+            app.popup,
+            Some(Popup {
+                kind: PopupType::EventSample,
+                ..
+            })
+        ));
+
+        app.handle_popup_input(key(KeyCode::Char('j')));
+        app.handle_popup_input(key(KeyCode::Char('j')));
+        let action = app.handle_popup_input(key(KeyCode::Char('y')));
+        assert!(matches!(action, Some(Action::CopyToClipboard(raw)) if raw == "second"));
+
+        app.handle_popup_input(key(KeyCode::Char('w')));
+        assert!(!app.event_sample.as_ref().unwrap().wrap);
+
+        app.handle_popup_input(key(KeyCode::Char('q')));
+        assert!(app.popup.is_none());
+        assert!(app.event_sample.is_none());
+    }
+
+    #[test]
+    fn test_index_sample_loaded_empty_shows_toast_without_popup() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.handle_index_sample_loaded("empty".to_string(), Ok(Vec::new()));
+        assert!(app.popup.is_none());
+        assert!(app.event_sample.is_none());
+        assert!(
+            app.toasts
+                .iter()
+                .any(|t| t.message.contains("No events in index 'empty'"))
+        );
+    }
+}
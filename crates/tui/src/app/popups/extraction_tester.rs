@@ -0,0 +1,182 @@
+//! Field extraction tester popup handler.
+//!
+//! Responsibilities:
+//! - Edit the sample events and the regex, and move focus between them
+//! - Start a test from the regex input and store its result
+//! - Copy the generated `| rex` search
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::extraction_tester)
+//! - Does NOT run the search (handled by side effects)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::app::extraction_tester::{ExtractionTester, ExtractionTesterFocus};
+use crate::ui::Toast;
+use crate::ui::popup::{Popup, PopupType};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use splunk_client::workflows::field_extraction::FieldExtractionPreview;
+
+impl App {
+    /// Open the tester, titled with the sourcetype selected on the Sourcetypes screen.
+    pub(crate) fn open_extraction_tester(&mut self) {
+        let sourcetype = self.sourcetypes.as_ref().and_then(|sourcetypes| {
+            self.sourcetypes_state
+                .selected()
+                .and_then(|i| sourcetypes.get(i))
+                .map(|entry| entry.name.clone())
+        });
+        self.extraction_tester = Some(ExtractionTester::new(sourcetype));
+        self.popup = Some(Popup::builder(PopupType::ExtractionTester).build());
+    }
+
+    pub(crate) fn handle_field_extraction_tested(
+        &mut self,
+        result: Result<FieldExtractionPreview, String>,
+    ) {
+        self.loading = false;
+        match self.extraction_tester.as_mut() {
+            Some(tester) => {
+                tester.running = false;
+                tester.result = Some(result);
+            }
+            None => {
+                if let Err(message) = result {
+                    self.toasts.push(Toast::error(format!(
+                        "Field extraction test failed: {}",
+                        message
+                    )));
+                }
+            }
+        }
+    }
+
+    /// Handle input for the ExtractionTester popup.
+    pub fn handle_extraction_tester_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.code == KeyCode::Esc {
+            self.popup = None;
+            self.extraction_tester = None;
+            return None;
+        }
+
+        let tester = self.extraction_tester.as_mut()?;
+        match key.code {
+            KeyCode::Tab | KeyCode::BackTab => tester.toggle_focus(),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                let preview = tester.result.as_ref()?.as_ref().ok()?;
+                return Some(Action::CopyToClipboard(preview.search.clone()));
+            }
+            KeyCode::Enter if tester.focus == ExtractionTesterFocus::Regex => {
+                if tester.running {
+                    return None;
+                }
+                match tester.request() {
+                    Ok((samples, extractions)) => {
+                        tester.running = true;
+                        tester.result = None;
+                        return Some(Action::RunFieldExtractionTest {
+                            samples,
+                            extractions,
+                        });
+                    }
+                    Err(message) => tester.result = Some(Err(message)),
+                }
+            }
+            _ => match tester.focus {
+                ExtractionTesterFocus::Samples => tester.samples.handle_key(key),
+                ExtractionTesterFocus::Regex => tester.regex.handle_key(key),
+            },
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use splunk_client::workflows::field_extraction::ExtractedEvent;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn type_text(app: &mut App, text: &str) {
+        for c in text.chars() {
+            app.handle_popup_input(key(KeyCode::Char(c)));
+        }
+    }
+
+    fn app_with_tester() -> App {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.open_extraction_tester();
+        app
+    }
+
+    #[test]
+    fn test_extraction_tester_enter_adds_sample_lines_then_runs_from_regex() {
+        let mut app = app_with_tester();
+
+        type_text(&mut app, "user=alice");
+        app.handle_popup_input(key(KeyCode::Enter));
+        type_text(&mut app, "user=bob");
+        app.handle_popup_input(key(KeyCode::Tab));
+        type_text(&mut app, r"user=(?<user>\w+)");
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+
+        let Some(Action::RunFieldExtractionTest {
+            samples,
+            extractions,
+        }) = action
+        else {
+            panic!("expected RunFieldExtractionTest, got {:?}", action);
+        };
+        assert_eq!(samples, vec!["user=alice", "user=bob"]);
+        assert_eq!(extractions[0].regex, r"user=(?<user>\w+)");
+        assert!(app.extraction_tester.as_ref().unwrap().running);
+
+        // A second Enter while running does not start another test
+        assert!(app.handle_popup_input(key(KeyCode::Enter)).is_none());
+    }
+
+    #[test]
+    fn test_extraction_tester_shows_validation_error_without_running() {
+        let mut app = app_with_tester();
+        type_text(&mut app, "user=alice");
+        app.handle_popup_input(key(KeyCode::Tab));
+        type_text(&mut app, r"user=\w+");
+
+        assert!(app.handle_popup_input(key(KeyCode::Enter)).is_none());
+        let tester = app.extraction_tester.as_ref().unwrap();
+        assert!(!tester.running);
+        assert!(matches!(&tester.result, Some(Err(msg)) if msg.contains("named capture")));
+    }
+
+    #[test]
+    fn test_extraction_tester_stores_result_and_copies_search() {
+        let mut app = app_with_tester();
+        app.extraction_tester.as_mut().unwrap().running = true;
+
+        app.handle_field_extraction_tested(Ok(FieldExtractionPreview {
+            fields: vec!["user".to_string()],
+            events: vec![ExtractedEvent {
+                raw: "user=alice".to_string(),
+                values: [("user".to_string(), "alice".to_string())].into(),
+            }],
+            search: "| makeresults | rex".to_string(),
+        }));
+        let tester = app.extraction_tester.as_ref().unwrap();
+        assert!(!tester.running);
+        assert!(matches!(&tester.result, Some(Ok(preview)) if preview.matched_count() == 1));
+
+        let action =
+            app.handle_popup_input(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert!(
+            matches!(action, Some(Action::CopyToClipboard(search)) if search == "| makeresults | rex")
+        );
+
+        app.handle_popup_input(key(KeyCode::Esc));
+        assert!(app.popup.is_none());
+        assert!(app.extraction_tester.is_none());
+    }
+}
@@ -0,0 +1,234 @@
+//! Health thresholds popup handler.
+//!
+//! Responsibilities:
+//! - Open the Settings health thresholds form with the current values
+//! - Parse and validate the form, then apply it to the persisted thresholds
+//!
+//! Does NOT handle:
+//! - Does NOT render popups (handled by ui::popup module)
+//! - Does NOT edit a profile's own `health_thresholds` (set in the config file)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::ui::Toast;
+use crate::ui::popup::{HealthThresholdField, Popup, PopupType};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use splunk_config::HealthThresholds;
+
+impl App {
+    /// Open the health thresholds form, unless the active profile sets its own thresholds.
+    pub(crate) fn open_health_thresholds(&mut self) -> Option<Action> {
+        if self.profile_health_thresholds.is_some() {
+            self.toasts.push(Toast::warning(format!(
+                "Health thresholds come from profile '{}'; edit health_thresholds in the config file",
+                self.profile_name.as_deref().unwrap_or("default")
+            )));
+            return None;
+        }
+        let thresholds = &self.health_thresholds;
+        self.popup = Some(
+            Popup::builder(PopupType::HealthThresholds {
+                license_warning_input: thresholds.license_warning_pct.to_string(),
+                license_critical_input: thresholds.license_critical_pct.to_string(),
+                kvstore_lag_input: thresholds.kvstore_max_replication_lag_secs.to_string(),
+                min_peers_input: thresholds.min_searchable_peers.to_string(),
+                disk_warning_input: thresholds.disk_warning_pct.to_string(),
+                disk_critical_input: thresholds.disk_critical_pct.to_string(),
+                selected_field: HealthThresholdField::LicenseWarning,
+            })
+            .build(),
+        );
+        None
+    }
+
+    /// Handle the health thresholds popup.
+    pub fn handle_health_thresholds_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        let Some(mut kind @ PopupType::HealthThresholds { .. }) =
+            self.popup.as_ref().map(|popup| popup.kind.clone())
+        else {
+            return None;
+        };
+
+        match key.code {
+            KeyCode::Enter => {
+                match parse_thresholds(&kind) {
+                    Ok(thresholds) => {
+                        self.health_thresholds = thresholds;
+                        self.popup = None;
+                        self.toasts.push(Toast::info("Health thresholds updated"));
+                    }
+                    Err(e) => self.toasts.push(Toast::warning(e)),
+                }
+                None
+            }
+            KeyCode::Esc => {
+                self.popup = None;
+                None
+            }
+            KeyCode::Tab => {
+                kind.navigate_fields(key.modifiers.contains(KeyModifiers::SHIFT));
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::BackTab | KeyCode::Up => {
+                kind.navigate_fields(true);
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::Down => {
+                kind.navigate_fields(false);
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::Char(_) | KeyCode::Backspace => {
+                if update_health_thresholds(&mut kind, key.code) {
+                    self.replace_popup_kind(kind);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+fn update_health_thresholds(kind: &mut PopupType, code: KeyCode) -> bool {
+    let PopupType::HealthThresholds {
+        license_warning_input,
+        license_critical_input,
+        kvstore_lag_input,
+        min_peers_input,
+        disk_warning_input,
+        disk_critical_input,
+        selected_field,
+    } = kind
+    else {
+        return false;
+    };
+
+    let input = match selected_field {
+        HealthThresholdField::LicenseWarning => license_warning_input,
+        HealthThresholdField::LicenseCritical => license_critical_input,
+        HealthThresholdField::KvstoreLag => kvstore_lag_input,
+        HealthThresholdField::MinSearchablePeers => min_peers_input,
+        HealthThresholdField::DiskWarning => disk_warning_input,
+        HealthThresholdField::DiskCritical => disk_critical_input,
+    };
+    match code {
+        KeyCode::Char(c) if c.is_ascii_digit() || c == '.' => input.push(c),
+        KeyCode::Backspace => {
+            input.pop();
+        }
+        _ => return false,
+    }
+    true
+}
+
+fn parse_thresholds(kind: &PopupType) -> Result<HealthThresholds, String> {
+    let PopupType::HealthThresholds {
+        license_warning_input,
+        license_critical_input,
+        kvstore_lag_input,
+        min_peers_input,
+        disk_warning_input,
+        disk_critical_input,
+        ..
+    } = kind
+    else {
+        return Err("Not a health thresholds form".to_string());
+    };
+
+    fn parse<T: std::str::FromStr>(input: &str, label: &str) -> Result<T, String> {
+        input
+            .trim()
+            .parse()
+            .map_err(|_| format!("{} must be a number, got '{}'", label, input.trim()))
+    }
+
+    let thresholds = HealthThresholds {
+        license_warning_pct: parse(license_warning_input, "License warning")?,
+        license_critical_pct: parse(license_critical_input, "License critical")?,
+        kvstore_max_replication_lag_secs: parse(kvstore_lag_input, "KVStore max lag")?,
+        min_searchable_peers: parse(min_peers_input, "Min searchable peers")?,
+        disk_warning_pct: parse(disk_warning_input, "Disk warning")?,
+        disk_critical_pct: parse(disk_critical_input, "Disk critical")?,
+    };
+    thresholds.validate()?;
+    Ok(thresholds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::empty())
+    }
+
+    fn retype(app: &mut App, value: &str) {
+        for _ in 0..8 {
+            app.handle_popup_input(key(KeyCode::Backspace));
+        }
+        for c in value.chars() {
+            app.handle_popup_input(key(KeyCode::Char(c)));
+        }
+    }
+
+    #[test]
+    fn test_enter_applies_edited_thresholds() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.open_health_thresholds();
+
+        retype(&mut app, "75");
+        app.handle_popup_input(key(KeyCode::Tab));
+        app.handle_popup_input(key(KeyCode::Tab));
+        retype(&mut app, "120");
+
+        assert!(app.handle_popup_input(key(KeyCode::Enter)).is_none());
+        assert!(app.popup.is_none());
+        assert_eq!(app.health_thresholds.license_warning_pct, 75.0);
+        assert_eq!(app.health_thresholds.kvstore_max_replication_lag_secs, 120);
+        assert_eq!(
+            app.get_persisted_state()
+                .health_thresholds
+                .license_warning_pct,
+            75.0
+        );
+    }
+
+    #[test]
+    fn test_invalid_thresholds_keep_popup_open() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.open_health_thresholds();
+
+        // Warning above the 90% critical default.
+        retype(&mut app, "95");
+        app.handle_popup_input(key(KeyCode::Enter));
+
+        assert!(matches!(
+            app.popup.as_ref().map(|popup| &popup.kind),
+            Some(PopupType::HealthThresholds { .. })
+        ));
+        assert_eq!(app.health_thresholds, HealthThresholds::default());
+        assert!(!app.toasts.is_empty());
+    }
+
+    #[test]
+    fn test_profile_thresholds_are_not_edited_in_settings() {
+        let mut app = App::new(
+            None,
+            ConnectionContext {
+                profile_name: Some("prod".to_string()),
+                health_thresholds: Some(HealthThresholds {
+                    min_searchable_peers: 3,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        );
+        app.open_health_thresholds();
+
+        assert!(app.popup.is_none());
+        assert_eq!(app.effective_health_thresholds().min_searchable_peers, 3);
+    }
+}
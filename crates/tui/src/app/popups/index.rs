@@ -2,6 +2,8 @@
 //!
 //! Responsibilities:
 //! - Handle index creation, modification, and deletion popups
+//! - Edit retention settings in the modify dialog and re-project how much data
+//!   they would freeze, requiring acknowledgment above the threshold
 //! - Handle index details view with scrolling and copy-to-clipboard
 //!
 //! Does NOT handle:
@@ -10,7 +12,8 @@
 
 use crate::action::Action;
 use crate::app::App;
-use crate::ui::popup::{Popup, PopupType};
+use crate::ui::Toast;
+use crate::ui::popup::{FREEZE_ACK_THRESHOLD_PERCENT, ModifyIndexField, Popup, PopupType};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 impl App {
@@ -172,7 +175,21 @@ impl App {
                 self.popup = None;
                 None
             }
-            // ModifyIndex - submit
+            // ModifyIndex - submit (blocked until a large projected freeze is acknowledged)
+            (
+                Some(PopupType::ModifyIndex {
+                    freeze_projection: Some(projection),
+                    freeze_acknowledged: false,
+                    ..
+                }),
+                KeyCode::Enter,
+            ) if projection.frozen_percent() > FREEZE_ACK_THRESHOLD_PERCENT => {
+                self.toasts.push(Toast::warning(format!(
+                    "{:.1}% of the index would freeze immediately; press 'a' to acknowledge",
+                    projection.frozen_percent()
+                )));
+                None
+            }
             (Some(PopupType::ModifyIndex { index_name, .. }), KeyCode::Enter) => {
                 let name = index_name.clone();
                 if let Some(Popup {
@@ -206,6 +223,43 @@ impl App {
                     None
                 }
             }
+            // ModifyIndex - field navigation, retention edits and acknowledgment
+            (
+                Some(PopupType::ModifyIndex { .. }),
+                KeyCode::Tab
+                | KeyCode::BackTab
+                | KeyCode::Up
+                | KeyCode::Down
+                | KeyCode::Char(_)
+                | KeyCode::Backspace,
+            ) => {
+                let mut kind = self.popup.as_ref()?.kind.clone();
+                match key.code {
+                    KeyCode::Tab | KeyCode::Down => {
+                        kind.navigate_fields(false);
+                    }
+                    KeyCode::BackTab | KeyCode::Up => {
+                        kind.navigate_fields(true);
+                    }
+                    KeyCode::Char('a') => {
+                        if let PopupType::ModifyIndex {
+                            freeze_acknowledged,
+                            ..
+                        } = &mut kind
+                        {
+                            *freeze_acknowledged = !*freeze_acknowledged;
+                        }
+                    }
+                    code => {
+                        if !edit_retention_field(&mut kind, code) {
+                            return None;
+                        }
+                        self.reproject_index_freeze(&mut kind);
+                    }
+                }
+                self.replace_popup_kind(kind);
+                None
+            }
             // DeleteIndexConfirm - cancel
             (Some(PopupType::DeleteIndexConfirm { .. }), KeyCode::Char('n') | KeyCode::Esc) => {
                 self.popup = None;
@@ -215,3 +269,73 @@ impl App {
         }
     }
 }
+
+impl App {
+    /// Recompute the freeze projection of a ModifyIndex popup from the loaded index stats.
+    ///
+    /// Editing a retention value invalidates any earlier acknowledgment.
+    fn reproject_index_freeze(&self, kind: &mut PopupType) {
+        let PopupType::ModifyIndex {
+            index_name,
+            new_max_data_size_mb,
+            new_frozen_time_period_secs,
+            freeze_projection,
+            freeze_acknowledged,
+            ..
+        } = kind
+        else {
+            return;
+        };
+        *freeze_projection = self
+            .indexes
+            .as_ref()
+            .and_then(|indexes| indexes.iter().find(|index| index.name == *index_name))
+            .map(|index| {
+                index.project_freeze(
+                    *new_max_data_size_mb,
+                    *new_frozen_time_period_secs,
+                    chrono::Utc::now(),
+                )
+            });
+        *freeze_acknowledged = false;
+    }
+}
+
+/// Apply a digit or backspace to the selected retention field.
+///
+/// Returns `false` when the key does not edit the field.
+fn edit_retention_field(kind: &mut PopupType, code: KeyCode) -> bool {
+    let PopupType::ModifyIndex {
+        new_max_data_size_mb,
+        new_frozen_time_period_secs,
+        selected_field,
+        ..
+    } = kind
+    else {
+        return false;
+    };
+    let value = match selected_field {
+        ModifyIndexField::MaxDataSize => new_max_data_size_mb,
+        ModifyIndexField::FrozenTimePeriod => new_frozen_time_period_secs,
+    };
+    match code {
+        KeyCode::Char(c) => {
+            let Some(digit) = c.to_digit(10) else {
+                return false;
+            };
+            let current = value.unwrap_or(0);
+            match current
+                .checked_mul(10)
+                .and_then(|v| v.checked_add(digit as usize))
+            {
+                Some(next) => *value = Some(next),
+                None => return false,
+            }
+        }
+        KeyCode::Backspace => {
+            *value = value.map(|v| v / 10).filter(|v| *v > 0);
+        }
+        _ => return false,
+    }
+    true
+}
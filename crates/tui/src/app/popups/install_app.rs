@@ -0,0 +1,314 @@
+//! Install app popup handler.
+//!
+//! Responsibilities:
+//! - Handle the install app dialog (file path, Splunkbase query, pinned version)
+//! - Pick an app from Splunkbase search results
+//! - Apply Splunkbase search results to the open dialog
+//!
+//! Does NOT handle:
+//! - Does NOT render popups (handled by ui::popup module)
+//! - Does NOT search Splunkbase or install apps (returns SearchSplunkbase/InstallApp)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::ui::Toast;
+use crate::ui::popup::{InstallAppField, PopupType};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use splunk_client::workflows::splunkbase::{AppPackageSource, SplunkbaseApp};
+
+impl App {
+    /// Handle the install app popup.
+    pub fn handle_install_app_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        let Some(mut kind @ PopupType::InstallAppDialog { .. }) =
+            self.popup.as_ref().map(|popup| popup.kind.clone())
+        else {
+            return None;
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                self.popup = None;
+                None
+            }
+            KeyCode::Enter => self.submit_install_app(kind),
+            KeyCode::Tab => {
+                kind.navigate_fields(key.modifiers.contains(KeyModifiers::SHIFT));
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::BackTab => {
+                kind.navigate_fields(true);
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::Up | KeyCode::Down => {
+                if !select_splunkbase_result(&mut kind, key.code == KeyCode::Up) {
+                    kind.navigate_fields(key.code == KeyCode::Up);
+                }
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::Char(_) | KeyCode::Backspace => {
+                if update_install_app_input(&mut kind, key.code) {
+                    self.replace_popup_kind(kind);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Install from the focused source, or search Splunkbase when the query is not an app ID.
+    fn submit_install_app(&mut self, kind: PopupType) -> Option<Action> {
+        let PopupType::InstallAppDialog {
+            file_input,
+            splunkbase_query,
+            version_input,
+            selected_field,
+            splunkbase_results,
+            selected_result,
+        } = kind
+        else {
+            return None;
+        };
+
+        let source = if selected_field == InstallAppField::File {
+            let path = file_input.trim();
+            if path.is_empty() {
+                return None;
+            }
+            AppPackageSource::File(path.into())
+        } else {
+            let query = splunkbase_query.trim();
+            let app_id = match selected_result.and_then(|i| splunkbase_results.get(i)) {
+                Some(app) => app.uid,
+                None => match query.parse::<u64>() {
+                    Ok(app_id) => app_id,
+                    Err(_) if query.is_empty() => return None,
+                    Err(_) => {
+                        return Some(Action::SearchSplunkbase {
+                            query: query.to_string(),
+                        });
+                    }
+                },
+            };
+            let version = version_input.trim();
+            AppPackageSource::Splunkbase {
+                app_id,
+                version: (!version.is_empty()).then(|| version.to_string()),
+            }
+        };
+
+        self.popup = None;
+        Some(Action::InstallApp { source })
+    }
+
+    /// Show Splunkbase search results in the install dialog, if it is still open.
+    pub(crate) fn handle_splunkbase_search_loaded(&mut self, apps: Vec<SplunkbaseApp>) {
+        self.loading = false;
+        let Some(mut kind @ PopupType::InstallAppDialog { .. }) =
+            self.popup.as_ref().map(|popup| popup.kind.clone())
+        else {
+            return;
+        };
+        let PopupType::InstallAppDialog {
+            splunkbase_query,
+            splunkbase_results,
+            selected_result,
+            ..
+        } = &mut kind
+        else {
+            return;
+        };
+
+        if apps.is_empty() {
+            self.toasts.push(Toast::info(format!(
+                "No Splunkbase apps match '{}'",
+                splunkbase_query.trim()
+            )));
+        }
+        *selected_result = (!apps.is_empty()).then_some(0);
+        *splunkbase_results = apps;
+        self.replace_popup_kind(kind);
+    }
+}
+
+/// Move the result selection; returns false when there are no results to select.
+fn select_splunkbase_result(kind: &mut PopupType, up: bool) -> bool {
+    let PopupType::InstallAppDialog {
+        splunkbase_results,
+        selected_result,
+        ..
+    } = kind
+    else {
+        return false;
+    };
+    if splunkbase_results.is_empty() {
+        return false;
+    }
+
+    let last = splunkbase_results.len() - 1;
+    *selected_result = Some(match (*selected_result, up) {
+        (None, _) => 0,
+        (Some(i), true) => i.saturating_sub(1),
+        (Some(i), false) => (i + 1).min(last),
+    });
+    true
+}
+
+fn update_install_app_input(kind: &mut PopupType, code: KeyCode) -> bool {
+    let PopupType::InstallAppDialog {
+        file_input,
+        splunkbase_query,
+        version_input,
+        selected_field,
+        splunkbase_results,
+        selected_result,
+    } = kind
+    else {
+        return false;
+    };
+
+    let input = match selected_field {
+        InstallAppField::File => file_input,
+        InstallAppField::Splunkbase => {
+            // Results belong to the previous query.
+            splunkbase_results.clear();
+            *selected_result = None;
+            splunkbase_query
+        }
+        InstallAppField::Version => version_input,
+    };
+    match code {
+        KeyCode::Char(c) => input.push(c),
+        KeyCode::Backspace => {
+            input.pop();
+        }
+        _ => return false,
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::ui::popup::Popup;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn app_with_dialog() -> App {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.popup = Some(
+            Popup::builder(PopupType::InstallAppDialog {
+                file_input: String::new(),
+                splunkbase_query: String::new(),
+                version_input: String::new(),
+                selected_field: InstallAppField::File,
+                splunkbase_results: Vec::new(),
+                selected_result: None,
+            })
+            .build(),
+        );
+        app
+    }
+
+    fn type_text(app: &mut App, text: &str) {
+        for c in text.chars() {
+            app.handle_popup_input(key(KeyCode::Char(c)));
+        }
+    }
+
+    fn splunkbase_app(uid: u64, appid: &str) -> SplunkbaseApp {
+        SplunkbaseApp {
+            uid,
+            appid: appid.to_string(),
+            title: appid.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_install_app_from_file() {
+        let mut app = app_with_dialog();
+
+        type_text(&mut app, "/tmp/my_app.spl");
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+
+        assert!(matches!(
+            action,
+            Some(Action::InstallApp { source: AppPackageSource::File(path) })
+                if path == std::path::Path::new("/tmp/my_app.spl")
+        ));
+        assert!(app.popup.is_none());
+    }
+
+    #[test]
+    fn test_install_app_by_splunkbase_id_with_version() {
+        let mut app = app_with_dialog();
+
+        app.handle_popup_input(key(KeyCode::Tab));
+        type_text(&mut app, "2890");
+        app.handle_popup_input(key(KeyCode::Tab));
+        type_text(&mut app, "8.2.0");
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+
+        assert!(matches!(
+            action,
+            Some(Action::InstallApp {
+                source: AppPackageSource::Splunkbase { app_id: 2890, version: Some(version) }
+            }) if version == "8.2.0"
+        ));
+    }
+
+    #[test]
+    fn test_install_app_search_then_pick_result() {
+        let mut app = app_with_dialog();
+
+        app.handle_popup_input(key(KeyCode::Tab));
+        type_text(&mut app, "windows");
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+        assert!(matches!(
+            action,
+            Some(Action::SearchSplunkbase { query }) if query == "windows"
+        ));
+        assert!(app.popup.is_some());
+
+        app.handle_splunkbase_search_loaded(vec![
+            splunkbase_app(2890, "Splunk_TA_windows"),
+            splunkbase_app(742, "Splunk_TA_microsoft_ad"),
+        ]);
+        app.handle_popup_input(key(KeyCode::Down));
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+
+        assert!(matches!(
+            action,
+            Some(Action::InstallApp {
+                source: AppPackageSource::Splunkbase {
+                    app_id: 742,
+                    version: None
+                }
+            })
+        ));
+    }
+
+    #[test]
+    fn test_install_app_typing_clears_stale_results() {
+        let mut app = app_with_dialog();
+
+        app.handle_popup_input(key(KeyCode::Tab));
+        type_text(&mut app, "windows");
+        app.handle_splunkbase_search_loaded(vec![splunkbase_app(2890, "Splunk_TA_windows")]);
+        type_text(&mut app, " ad");
+
+        assert!(matches!(
+            app.popup.as_ref().map(|p| &p.kind),
+            Some(PopupType::InstallAppDialog {
+                splunkbase_results,
+                selected_result: None,
+                ..
+            }) if splunkbase_results.is_empty()
+        ));
+    }
+}
@@ -0,0 +1,195 @@
+//! Jobs filter popup handler.
+//!
+//! Responsibilities:
+//! - Handle the server-side jobs filter form (owner, app, status)
+//! - Apply the filter to app state and request a fresh jobs page
+//!
+//! Does NOT handle:
+//! - Does NOT render popups (handled by ui::popup module)
+//! - Does NOT build the Splunk search query (handled by `JobFilter`)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::ui::popup::{JobsFilterField, PopupType};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use splunk_client::{JobFilter, JobStatusFilter};
+
+use super::common::optional_string;
+
+impl App {
+    /// Handle the server-side jobs filter popup.
+    pub fn handle_jobs_filter_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        let Some(mut kind @ PopupType::JobsFilter { .. }) =
+            self.popup.as_ref().map(|popup| popup.kind.clone())
+        else {
+            return None;
+        };
+
+        match key.code {
+            KeyCode::Enter => {
+                let PopupType::JobsFilter {
+                    owner_input,
+                    app_input,
+                    status,
+                    ..
+                } = kind
+                else {
+                    return None;
+                };
+                self.popup = None;
+                self.jobs_server_filter = JobFilter {
+                    owner: optional_string(owner_input.trim().to_string()),
+                    app: optional_string(app_input.trim().to_string()),
+                    status,
+                };
+                // Translated by the main loop into a filtered first-page load.
+                Some(Action::RefreshJobs)
+            }
+            KeyCode::Esc => {
+                self.popup = None;
+                None
+            }
+            KeyCode::Tab => {
+                kind.navigate_fields(key.modifiers.contains(KeyModifiers::SHIFT));
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::BackTab | KeyCode::Up => {
+                kind.navigate_fields(true);
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::Down => {
+                kind.navigate_fields(false);
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::Left | KeyCode::Right | KeyCode::Char(_) | KeyCode::Backspace => {
+                if update_jobs_filter(&mut kind, key.code) {
+                    self.replace_popup_kind(kind);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+fn update_jobs_filter(kind: &mut PopupType, code: KeyCode) -> bool {
+    let PopupType::JobsFilter {
+        owner_input,
+        app_input,
+        status,
+        selected_field,
+    } = kind
+    else {
+        return false;
+    };
+
+    match (*selected_field, code) {
+        (JobsFilterField::Owner, KeyCode::Char(c)) => owner_input.push(c),
+        (JobsFilterField::Owner, KeyCode::Backspace) => {
+            owner_input.pop();
+        }
+        (JobsFilterField::App, KeyCode::Char(c)) => app_input.push(c),
+        (JobsFilterField::App, KeyCode::Backspace) => {
+            app_input.pop();
+        }
+        (JobsFilterField::Status, KeyCode::Char(' ') | KeyCode::Right) => {
+            *status = cycle_status(*status, false);
+        }
+        (JobsFilterField::Status, KeyCode::Left) => *status = cycle_status(*status, true),
+        (JobsFilterField::Status, KeyCode::Backspace) => *status = None,
+        _ => return false,
+    }
+    true
+}
+
+/// Step through "any", then each status in order, wrapping around.
+pub(super) fn cycle_status(
+    status: Option<JobStatusFilter>,
+    backwards: bool,
+) -> Option<JobStatusFilter> {
+    let options: Vec<Option<JobStatusFilter>> = std::iter::once(None)
+        .chain(JobStatusFilter::ALL.iter().copied().map(Some))
+        .collect();
+    let index = options.iter().position(|o| *o == status).unwrap_or(0);
+    let next = if backwards {
+        (index + options.len() - 1) % options.len()
+    } else {
+        (index + 1) % options.len()
+    };
+    options[next]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::ui::popup::Popup;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::empty())
+    }
+
+    fn open_filter(app: &mut App) {
+        app.popup = Some(
+            Popup::builder(PopupType::JobsFilter {
+                owner_input: String::new(),
+                app_input: String::new(),
+                status: None,
+                selected_field: JobsFilterField::Owner,
+            })
+            .build(),
+        );
+    }
+
+    #[test]
+    fn test_enter_applies_filter_and_refreshes() {
+        let mut app = App::new(None, ConnectionContext::default());
+        open_filter(&mut app);
+
+        for c in "admin".chars() {
+            app.handle_popup_input(key(KeyCode::Char(c)));
+        }
+        app.handle_popup_input(key(KeyCode::Tab));
+        for c in "search".chars() {
+            app.handle_popup_input(key(KeyCode::Char(c)));
+        }
+        app.handle_popup_input(key(KeyCode::Tab));
+        app.handle_popup_input(key(KeyCode::Char(' ')));
+
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+        assert!(matches!(action, Some(Action::RefreshJobs)));
+        assert!(app.popup.is_none());
+        assert_eq!(
+            app.jobs_server_filter,
+            JobFilter {
+                owner: Some("admin".to_string()),
+                app: Some("search".to_string()),
+                status: Some(JobStatusFilter::Running),
+            }
+        );
+    }
+
+    #[test]
+    fn test_esc_keeps_existing_filter() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.jobs_server_filter.owner = Some("admin".to_string());
+        open_filter(&mut app);
+
+        assert!(app.handle_popup_input(key(KeyCode::Esc)).is_none());
+        assert!(app.popup.is_none());
+        assert_eq!(app.jobs_server_filter.owner.as_deref(), Some("admin"));
+    }
+
+    #[test]
+    fn test_cycle_status_wraps_in_both_directions() {
+        assert_eq!(cycle_status(None, true), Some(JobStatusFilter::Failed));
+        assert_eq!(cycle_status(Some(JobStatusFilter::Failed), false), None);
+        assert_eq!(
+            cycle_status(Some(JobStatusFilter::Running), false),
+            Some(JobStatusFilter::Done)
+        );
+    }
+}
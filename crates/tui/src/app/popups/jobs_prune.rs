@@ -0,0 +1,173 @@
+//! Jobs prune popup handler.
+//!
+//! Responsibilities:
+//! - Handle the "prune old jobs" form (age, owner, status)
+//! - Validate the age and request the list of prunable jobs
+//!
+//! Does NOT handle:
+//! - Does NOT render popups (handled by ui::popup module)
+//! - Does NOT delete jobs (matches are confirmed via `ConfirmDeleteBatch`)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::ui::Toast;
+use crate::ui::popup::{JobsPruneField, PopupType};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use splunk_client::workflows::job_prune::JobPrunePolicy;
+
+use super::common::optional_string;
+use super::jobs_filter::cycle_status;
+
+impl App {
+    /// Handle the "prune old jobs" popup.
+    pub fn handle_jobs_prune_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        let Some(mut kind @ PopupType::JobsPrune { .. }) =
+            self.popup.as_ref().map(|popup| popup.kind.clone())
+        else {
+            return None;
+        };
+
+        match key.code {
+            KeyCode::Enter => {
+                let PopupType::JobsPrune {
+                    older_than_input,
+                    owner_input,
+                    status,
+                    ..
+                } = kind
+                else {
+                    return None;
+                };
+                let policy = JobPrunePolicy {
+                    older_than: older_than_input.trim().to_string(),
+                    owner: optional_string(owner_input.trim().to_string()),
+                    status,
+                };
+                if let Err(e) = policy.validate() {
+                    self.toasts.push(Toast::warning(format!("{e:#}")));
+                    return None;
+                }
+                self.popup = None;
+                Some(Action::FindPrunableJobs(policy))
+            }
+            KeyCode::Esc => {
+                self.popup = None;
+                None
+            }
+            KeyCode::Tab => {
+                kind.navigate_fields(key.modifiers.contains(KeyModifiers::SHIFT));
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::BackTab | KeyCode::Up => {
+                kind.navigate_fields(true);
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::Down => {
+                kind.navigate_fields(false);
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::Left | KeyCode::Right | KeyCode::Char(_) | KeyCode::Backspace => {
+                if update_jobs_prune(&mut kind, key.code) {
+                    self.replace_popup_kind(kind);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+fn update_jobs_prune(kind: &mut PopupType, code: KeyCode) -> bool {
+    let PopupType::JobsPrune {
+        older_than_input,
+        owner_input,
+        status,
+        selected_field,
+    } = kind
+    else {
+        return false;
+    };
+
+    match (*selected_field, code) {
+        (JobsPruneField::OlderThan, KeyCode::Char(c)) => older_than_input.push(c),
+        (JobsPruneField::OlderThan, KeyCode::Backspace) => {
+            older_than_input.pop();
+        }
+        (JobsPruneField::Owner, KeyCode::Char(c)) => owner_input.push(c),
+        (JobsPruneField::Owner, KeyCode::Backspace) => {
+            owner_input.pop();
+        }
+        (JobsPruneField::Status, KeyCode::Char(' ') | KeyCode::Right) => {
+            *status = cycle_status(*status, false);
+        }
+        (JobsPruneField::Status, KeyCode::Left) => *status = cycle_status(*status, true),
+        (JobsPruneField::Status, KeyCode::Backspace) => *status = None,
+        _ => return false,
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::ui::popup::Popup;
+    use splunk_client::JobStatusFilter;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::empty())
+    }
+
+    fn open_prune(app: &mut App, older_than: &str) {
+        app.popup = Some(
+            Popup::builder(PopupType::JobsPrune {
+                older_than_input: older_than.to_string(),
+                owner_input: String::new(),
+                status: Some(JobStatusFilter::Done),
+                selected_field: JobsPruneField::OlderThan,
+            })
+            .build(),
+        );
+    }
+
+    #[test]
+    fn test_enter_requests_prunable_jobs() {
+        let mut app = App::new(None, ConnectionContext::default());
+        open_prune(&mut app, "2d");
+
+        app.handle_popup_input(key(KeyCode::Tab));
+        for c in "me".chars() {
+            app.handle_popup_input(key(KeyCode::Char(c)));
+        }
+
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+        let Some(Action::FindPrunableJobs(policy)) = action else {
+            panic!("expected FindPrunableJobs, got {action:?}");
+        };
+        assert!(app.popup.is_none());
+        assert_eq!(
+            policy,
+            JobPrunePolicy {
+                older_than: "2d".to_string(),
+                owner: Some("me".to_string()),
+                status: Some(JobStatusFilter::Done),
+            }
+        );
+    }
+
+    #[test]
+    fn test_invalid_age_keeps_popup_open() {
+        let mut app = App::new(None, ConnectionContext::default());
+        open_prune(&mut app, "soon");
+
+        assert!(app.handle_popup_input(key(KeyCode::Enter)).is_none());
+        assert!(matches!(
+            app.popup.as_ref().map(|popup| &popup.kind),
+            Some(PopupType::JobsPrune { .. })
+        ));
+        assert!(!app.toasts.is_empty());
+    }
+}
@@ -0,0 +1,169 @@
+//! Lookup editor popup handler.
+//!
+//! Responsibilities:
+//! - Move the cell cursor, edit cells, and insert/delete rows
+//! - Save the edited contents or close, guarding unsaved changes
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::lookup_editor)
+//! - Does NOT upload the lookup (handled by Action::SaveLookupContent)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::ui::Toast;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Rows moved by PageUp/PageDown.
+const PAGE_ROWS: isize = 10;
+
+impl App {
+    /// Handle input for the LookupEditor popup.
+    pub fn handle_lookup_editor_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let editor = self.lookup_editor.as_mut()?;
+
+        if let Some(buffer) = editor.editing.as_mut() {
+            match key.code {
+                KeyCode::Enter => editor.commit_edit(),
+                KeyCode::Esc => editor.editing = None,
+                KeyCode::Backspace => {
+                    buffer.pop();
+                }
+                KeyCode::Char(c) if !ctrl => buffer.push(c),
+                _ => {}
+            }
+            return None;
+        }
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return self.close_lookup_editor(),
+            KeyCode::Char('s') if ctrl => return self.save_lookup_editor(),
+            KeyCode::Char('c') if ctrl => {
+                return editor
+                    .selected_cell()
+                    .map(|cell| Action::CopyToClipboard(cell.to_string()));
+            }
+            KeyCode::Enter | KeyCode::Char('e') => editor.start_edit(),
+            KeyCode::Char('j') | KeyCode::Down => editor.move_row(1),
+            KeyCode::Char('k') | KeyCode::Up => editor.move_row(-1),
+            KeyCode::Char('l') | KeyCode::Right | KeyCode::Tab => editor.move_col(1),
+            KeyCode::Char('h') | KeyCode::Left | KeyCode::BackTab => editor.move_col(-1),
+            KeyCode::PageDown => editor.move_row(PAGE_ROWS),
+            KeyCode::PageUp => editor.move_row(-PAGE_ROWS),
+            KeyCode::Home => editor.row = 0,
+            KeyCode::End => editor.move_row(isize::MAX),
+            KeyCode::Char('o') => editor.insert_row(),
+            KeyCode::Char('d') => editor.delete_row(),
+            _ => {}
+        }
+        None
+    }
+
+    /// Close the editor, asking for a second Esc when there are unsaved changes.
+    fn close_lookup_editor(&mut self) -> Option<Action> {
+        let editor = self.lookup_editor.as_mut()?;
+        if editor.dirty && !editor.confirm_discard {
+            editor.confirm_discard = true;
+            self.toasts.push(Toast::warning(
+                "Unsaved lookup changes: Ctrl+s to save, Esc again to discard",
+            ));
+            return None;
+        }
+        self.lookup_editor = None;
+        self.popup = None;
+        None
+    }
+
+    fn save_lookup_editor(&mut self) -> Option<Action> {
+        let editor = self.lookup_editor.as_ref()?;
+        if !editor.dirty {
+            self.toasts.push(Toast::info("No lookup changes to save"));
+            return None;
+        }
+        self.loading = true;
+        Some(Action::SaveLookupContent {
+            name: editor.name.clone(),
+            app: editor.app.clone(),
+            owner: editor.owner.clone(),
+            content: editor.content.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::app::lookup_editor::LookupEditor;
+    use crate::ui::popup::{Popup, PopupType};
+    use splunk_client::models::LookupContent;
+
+    fn app_with_editor() -> App {
+        let mut app = App::new(None, ConnectionContext::default());
+        let content = LookupContent::from_csv("host,owner\nweb-01,ops\n").unwrap();
+        app.lookup_editor = Some(LookupEditor::new(
+            "hosts.csv".to_string(),
+            Some("search".to_string()),
+            Some("nobody".to_string()),
+            content,
+        ));
+        app.popup = Some(Popup::builder(PopupType::LookupEditor).build());
+        app
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_lookup_editor_edits_cell_and_saves() {
+        let mut app = app_with_editor();
+
+        app.handle_popup_input(key(KeyCode::Char('l')));
+        app.handle_popup_input(key(KeyCode::Enter));
+        for _ in 0.."ops".len() {
+            app.handle_popup_input(key(KeyCode::Backspace));
+        }
+        for c in "sre".chars() {
+            app.handle_popup_input(key(KeyCode::Char(c)));
+        }
+        app.handle_popup_input(key(KeyCode::Enter));
+        let action =
+            app.handle_popup_input(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+
+        assert!(matches!(
+            action,
+            Some(Action::SaveLookupContent { ref name, ref app, ref content, .. })
+                if name == "hosts.csv"
+                    && app.as_deref() == Some("search")
+                    && content.rows[0] == vec!["web-01", "sre"]
+        ));
+    }
+
+    #[test]
+    fn test_lookup_editor_requires_second_esc_to_discard() {
+        let mut app = app_with_editor();
+        app.handle_popup_input(key(KeyCode::Char('o')));
+
+        app.handle_popup_input(key(KeyCode::Esc));
+        assert!(app.popup.is_some());
+        assert!(app.lookup_editor.as_ref().unwrap().confirm_discard);
+
+        app.handle_popup_input(key(KeyCode::Esc));
+        assert!(app.popup.is_none());
+        assert!(app.lookup_editor.is_none());
+    }
+
+    #[test]
+    fn test_lookup_editor_typing_q_while_editing_does_not_close() {
+        let mut app = app_with_editor();
+        app.handle_popup_input(key(KeyCode::Char('e')));
+        app.handle_popup_input(key(KeyCode::Char('q')));
+
+        assert!(app.popup.is_some());
+        assert_eq!(
+            app.lookup_editor.as_ref().unwrap().editing.as_deref(),
+            Some("web-01q")
+        );
+    }
+}
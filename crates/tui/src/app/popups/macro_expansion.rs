@@ -0,0 +1,89 @@
+//! Macro expansion preview popup handler.
+//!
+//! Responsibilities:
+//! - Handle scrolling through the expanded query and macro list
+//! - Copy the expanded SPL to the clipboard
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::macro_expansion)
+//! - Does NOT expand macros (handled by Action::LoadMacroExpansion)
+
+use crate::action::Action;
+use crate::app::App;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+impl App {
+    /// Handle input for the MacroExpansion popup.
+    pub fn handle_macro_expansion_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.popup = None;
+                self.macro_expansion_scroll_offset = 0;
+                None
+            }
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => self
+                .macro_expansion
+                .as_ref()
+                .map(|expansion| Action::CopyToClipboard(expansion.expanded.clone())),
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.macro_expansion_scroll_offset =
+                    self.macro_expansion_scroll_offset.saturating_add(1);
+                None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.macro_expansion_scroll_offset =
+                    self.macro_expansion_scroll_offset.saturating_sub(1);
+                None
+            }
+            KeyCode::PageDown => {
+                self.macro_expansion_scroll_offset =
+                    self.macro_expansion_scroll_offset.saturating_add(10);
+                None
+            }
+            KeyCode::PageUp => {
+                self.macro_expansion_scroll_offset =
+                    self.macro_expansion_scroll_offset.saturating_sub(10);
+                None
+            }
+            KeyCode::Home => {
+                self.macro_expansion_scroll_offset = 0;
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::ui::popup::PopupType;
+    use splunk_client::workflows::macro_expansion::MacroExpansion;
+
+    #[test]
+    fn test_macro_expansion_copy_scroll_and_close() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.handle_macro_expansion_loaded(MacroExpansion {
+            original: "`web`".to_string(),
+            expanded: "index=web".to_string(),
+            ..Default::default()
+        });
+        assert!(matches!(
+            app.popup.as_ref().map(|p| &p.kind),
+            Some(PopupType::MacroExpansion)
+        ));
+
+        let action =
+            app.handle_popup_input(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert!(matches!(action, Some(Action::CopyToClipboard(s)) if s == "index=web"));
+
+        app.handle_popup_input(KeyEvent::new(KeyCode::PageDown, KeyModifiers::NONE));
+        app.handle_popup_input(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE));
+        assert_eq!(app.macro_expansion_scroll_offset, 9);
+
+        app.handle_popup_input(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(app.popup.is_none());
+        assert_eq!(app.macro_expansion_scroll_offset, 0);
+    }
+}
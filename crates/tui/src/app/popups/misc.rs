@@ -3,11 +3,10 @@
 //! Responsibilities:
 //! - Handle Help popup (scrolling, closing)
 //! - Handle ErrorDetails popup (scrolling, closing)
-//! - Handle InstallAppDialog popup (file input)
 //!
 //! Does NOT handle:
 //! - Does NOT render popups (handled by ui::popup module)
-//! - Does NOT install apps (just returns Action::InstallApp)
+//! - Does NOT handle the install app dialog (see install_app module)
 
 use crate::action::Action;
 use crate::app::App;
@@ -72,47 +71,6 @@ impl App {
         }
     }
 
-    /// Handle InstallAppDialog popup.
-    pub fn handle_install_app_popup(&mut self, key: KeyEvent) -> Option<Action> {
-        match (self.popup.as_ref().map(|p| &p.kind), key.code) {
-            (Some(PopupType::InstallAppDialog { .. }), KeyCode::Esc) => {
-                self.popup = None;
-                None
-            }
-            (Some(PopupType::InstallAppDialog { file_input }), KeyCode::Enter) => {
-                if file_input.is_empty() {
-                    return None;
-                }
-                let path = std::path::PathBuf::from(file_input);
-                self.popup = None;
-                Some(Action::InstallApp { file_path: path })
-            }
-            (Some(PopupType::InstallAppDialog { file_input }), KeyCode::Char(c)) => {
-                let mut new_input = file_input.clone();
-                new_input.push(c);
-                self.popup = Some(
-                    Popup::builder(PopupType::InstallAppDialog {
-                        file_input: new_input,
-                    })
-                    .build(),
-                );
-                None
-            }
-            (Some(PopupType::InstallAppDialog { file_input }), KeyCode::Backspace) => {
-                let mut new_input = file_input.clone();
-                new_input.pop();
-                self.popup = Some(
-                    Popup::builder(PopupType::InstallAppDialog {
-                        file_input: new_input,
-                    })
-                    .build(),
-                );
-                None
-            }
-            _ => None,
-        }
-    }
-
     /// Handle AuthRecovery popup.
     pub fn handle_auth_recovery_popup(&mut self, key: KeyEvent) -> Option<Action> {
         // Get the recovery kind from the current popup if available
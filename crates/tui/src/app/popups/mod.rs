@@ -14,18 +14,47 @@ use crate::app::App;
 use crate::ui::popup::PopupType;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
+mod acl;
+mod app_details;
+mod background_jobs;
 mod command_palette;
 mod common;
 mod confirm;
+mod create_input;
+mod dashboard;
+mod event_sample;
 mod export;
+mod extraction_tester;
+mod health_thresholds;
 mod index;
+mod install_app;
+mod jobs_filter;
+mod jobs_prune;
+mod lookup_editor;
+mod macro_expansion;
 mod macros;
 mod misc;
+mod namespace;
+mod operation_progress;
+mod orphaned_objects;
 mod profile;
+mod reassign_saved_searches;
+mod rest_request;
+mod result_detail;
 mod saved_search;
+mod search_peer;
+mod server_messages;
+mod server_restart;
+mod sessions;
+mod template_form;
+mod time_range;
+mod top_values;
+mod troubleshooting;
+mod tstats_builder;
 mod tutorial;
 mod undo_history;
 mod user;
+mod wizard;
 
 impl App {
     /// Handle keyboard input when a popup is active.
@@ -60,7 +89,11 @@ impl App {
                 | PopupType::DeleteIndexConfirm { .. }
                 | PopupType::DeleteUserConfirm { .. }
                 | PopupType::DeleteLookupConfirm { .. }
-                | PopupType::DeleteRoleConfirm { .. },
+                | PopupType::DeleteRoleConfirm { .. }
+                | PopupType::ConfirmRemoveSearchPeer(_)
+                | PopupType::ConfirmQuarantineSearchPeer { .. }
+                | PopupType::ConfirmRollHotBuckets(_)
+                | PopupType::ConfirmRebalanceIndex(_),
             ) => self.handle_confirm_popup(key),
 
             // Profile management
@@ -78,6 +111,32 @@ impl App {
                 | PopupType::ModifyIndex { .. },
             ) => self.handle_index_popup(key),
 
+            // Dashboard source view
+            Some(PopupType::DashboardSource) => self.handle_dashboard_source_popup(key),
+
+            // Macro expansion preview
+            Some(PopupType::MacroExpansion) => self.handle_macro_expansion_popup(key),
+
+            // Search result detail view
+            Some(PopupType::ResultDetail) => self.handle_result_detail_popup(key),
+            Some(PopupType::TstatsBuilder) => self.handle_tstats_builder_popup(key),
+            Some(PopupType::TemplateParameters) => self.handle_template_form_popup(key),
+            Some(PopupType::OnboardingWizard) => self.handle_onboarding_wizard_popup(key),
+            Some(PopupType::ExtractionTester) => self.handle_extraction_tester_popup(key),
+            Some(PopupType::TimeRangePicker) => self.handle_time_range_picker_popup(key),
+            Some(PopupType::EventSample) => self.handle_event_sample_popup(key),
+            Some(PopupType::TopValues) => self.handle_top_values_popup(key),
+            Some(PopupType::LookupEditor) => self.handle_lookup_editor_popup(key),
+            Some(PopupType::ServerMessages) => self.handle_server_messages_popup(key),
+            Some(PopupType::BackgroundJobs) => self.handle_background_jobs_popup(key),
+            Some(PopupType::AppDetails) => self.handle_app_details_popup(key),
+            Some(PopupType::OrphanedObjects { .. }) => self.handle_orphaned_objects_popup(key),
+            Some(PopupType::ConfirmServerRestart { .. }) => self.handle_server_restart_popup(key),
+            Some(PopupType::RestRequest { .. }) => self.handle_rest_request_popup(key),
+            Some(PopupType::ReassignSavedSearches { .. }) => {
+                self.handle_reassign_saved_searches_popup(key)
+            }
+
             // User management
             Some(PopupType::CreateUser { .. } | PopupType::ModifyUser { .. }) => {
                 self.handle_user_popup(key)
@@ -86,6 +145,20 @@ impl App {
             // Install app dialog
             Some(PopupType::InstallAppDialog { .. }) => self.handle_install_app_popup(key),
 
+            // Namespace selector
+            Some(PopupType::NamespaceSelector { .. }) => self.handle_namespace_popup(key),
+
+            // Saved investigation sessions
+            Some(PopupType::SessionPicker { .. }) => self.handle_session_picker_popup(key),
+
+            // Troubleshooting recipes
+            Some(PopupType::TroubleshootingRecipes { .. }) => {
+                self.handle_troubleshooting_recipes_popup(key)
+            }
+
+            // Knowledge object permissions
+            Some(PopupType::EditAcl { .. }) => self.handle_acl_popup(key),
+
             // Role management (not yet implemented - close popup on Esc)
             Some(PopupType::CreateRole { .. } | PopupType::ModifyRole { .. }) => {
                 if key.code == KeyCode::Esc {
@@ -118,8 +191,8 @@ impl App {
             // Auth recovery popup
             Some(PopupType::AuthRecovery { .. }) => self.handle_auth_recovery_popup(key),
 
-            // Connection diagnostics popup (close on Enter/Esc/q)
-            Some(PopupType::ConnectionDiagnostics { .. }) => {
+            // Read-only report popups (close on Enter/Esc/q)
+            Some(PopupType::ConnectionDiagnostics { .. } | PopupType::UserActivity { .. }) => {
                 if key.code == KeyCode::Enter
                     || key.code == KeyCode::Esc
                     || key.code == KeyCode::Char('q')
@@ -129,6 +202,24 @@ impl App {
                 None
             }
 
+            // Server-side jobs filter
+            Some(PopupType::JobsFilter { .. }) => self.handle_jobs_filter_popup(key),
+
+            // Prune old jobs
+            Some(PopupType::JobsPrune { .. }) => self.handle_jobs_prune_popup(key),
+
+            // Settings health thresholds form
+            Some(PopupType::HealthThresholds { .. }) => self.handle_health_thresholds_popup(key),
+
+            // Add distributed search peer form
+            Some(PopupType::AddSearchPeer { .. }) => self.handle_add_search_peer_popup(key),
+
+            // Add data input form
+            Some(PopupType::CreateInput { .. }) => self.handle_create_input_popup(key),
+
+            // Long-running operation progress (cancel with c/Esc)
+            Some(PopupType::OperationProgress) => self.handle_operation_progress_popup(key),
+
             // No popup active
             None => None,
         }
@@ -681,6 +772,9 @@ mod tests {
                 new_cold_db_path: None,
                 new_thawed_path: None,
                 new_cold_to_frozen_dir: None,
+                selected_field: crate::ui::popup::ModifyIndexField::MaxDataSize,
+                freeze_projection: None,
+                freeze_acknowledged: false,
             })
             .build(),
         );
@@ -717,6 +811,9 @@ mod tests {
                 new_cold_db_path: None,
                 new_thawed_path: None,
                 new_cold_to_frozen_dir: None,
+                selected_field: crate::ui::popup::ModifyIndexField::MaxDataSize,
+                freeze_projection: None,
+                freeze_acknowledged: false,
             })
             .build(),
         );
@@ -864,6 +961,9 @@ mod tests {
                 new_cold_db_path: None,
                 new_thawed_path: None,
                 new_cold_to_frozen_dir: None,
+                selected_field: crate::ui::popup::ModifyIndexField::MaxDataSize,
+                freeze_projection: None,
+                freeze_acknowledged: false,
             })
             .build(),
         );
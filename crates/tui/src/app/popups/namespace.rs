@@ -0,0 +1,198 @@
+//! Namespace selector popup handler.
+//!
+//! Responsibilities:
+//! - Open the selector pre-filled with the current owner/app/sharing namespace
+//! - Edit the app and owner inputs and cycle the sharing filter
+//! - Emit SetNamespace on Enter
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::popup module)
+//! - Does NOT apply the namespace to the client (handled by Action::SetNamespace)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::ui::popup::{NamespaceField, Popup, PopupType};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use splunk_client::models::Namespace;
+
+/// Sharing filter choices, in cycle order (`None` = any sharing level).
+const SHARING_LEVELS: [Option<&str>; 4] = [None, Some("user"), Some("app"), Some("global")];
+
+impl App {
+    /// Open the namespace selector with the current namespace filled in.
+    pub(crate) fn open_namespace_selector(&mut self) {
+        let namespace = self.namespace.clone().unwrap_or_default();
+        self.popup = Some(
+            Popup::builder(PopupType::NamespaceSelector {
+                app_input: namespace.app.unwrap_or_default(),
+                owner_input: namespace.owner.unwrap_or_default(),
+                sharing: namespace.sharing,
+                selected_field: NamespaceField::App,
+            })
+            .build(),
+        );
+    }
+
+    /// Handle input for the NamespaceSelector popup.
+    pub fn handle_namespace_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        let Some(mut kind @ PopupType::NamespaceSelector { .. }) =
+            self.popup.as_ref().map(|popup| popup.kind.clone())
+        else {
+            return None;
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                self.popup = None;
+                None
+            }
+            KeyCode::Enter => {
+                self.popup = None;
+                Some(Action::SetNamespace(namespace_from_popup(&kind)))
+            }
+            KeyCode::Tab | KeyCode::Down => {
+                kind.navigate_fields(key.modifiers.contains(KeyModifiers::SHIFT));
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::BackTab | KeyCode::Up => {
+                kind.navigate_fields(true);
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::Left | KeyCode::Right | KeyCode::Char(_) | KeyCode::Backspace => {
+                if update_namespace_input(&mut kind, key.code) {
+                    self.replace_popup_kind(kind);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+fn namespace_from_popup(kind: &PopupType) -> Option<Namespace> {
+    let PopupType::NamespaceSelector {
+        app_input,
+        owner_input,
+        sharing,
+        ..
+    } = kind
+    else {
+        return None;
+    };
+
+    let non_empty = |value: &str| {
+        let value = value.trim();
+        (!value.is_empty()).then(|| value.to_string())
+    };
+    let namespace = Namespace {
+        owner: non_empty(owner_input),
+        app: non_empty(app_input),
+        sharing: sharing.clone(),
+    };
+    (!namespace.is_empty()).then_some(namespace)
+}
+
+fn update_namespace_input(kind: &mut PopupType, code: KeyCode) -> bool {
+    let PopupType::NamespaceSelector {
+        app_input,
+        owner_input,
+        sharing,
+        selected_field,
+    } = kind
+    else {
+        return false;
+    };
+
+    let input = match selected_field {
+        NamespaceField::App => app_input,
+        NamespaceField::Owner => owner_input,
+        NamespaceField::Sharing => {
+            let current = SHARING_LEVELS
+                .iter()
+                .position(|level| *level == sharing.as_deref())
+                .unwrap_or(0);
+            let next = match code {
+                KeyCode::Left => (current + SHARING_LEVELS.len() - 1) % SHARING_LEVELS.len(),
+                KeyCode::Right | KeyCode::Char(' ') => (current + 1) % SHARING_LEVELS.len(),
+                _ => return false,
+            };
+            *sharing = SHARING_LEVELS[next].map(str::to_string);
+            return true;
+        }
+    };
+    match code {
+        KeyCode::Char(c) => input.push(c),
+        KeyCode::Backspace => {
+            input.pop();
+        }
+        _ => return false,
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn type_text(app: &mut App, text: &str) {
+        for c in text.chars() {
+            app.handle_popup_input(key(KeyCode::Char(c)));
+        }
+    }
+
+    #[test]
+    fn test_namespace_selector_builds_namespace() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.open_namespace_selector();
+
+        type_text(&mut app, "search");
+        app.handle_popup_input(key(KeyCode::Tab));
+        type_text(&mut app, "admin");
+        app.handle_popup_input(key(KeyCode::Tab));
+        app.handle_popup_input(key(KeyCode::Right));
+        app.handle_popup_input(key(KeyCode::Right));
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+
+        assert!(matches!(
+            action,
+            Some(Action::SetNamespace(Some(Namespace { owner: Some(owner), app: Some(app_name), sharing: Some(sharing) })))
+                if owner == "admin" && app_name == "search" && sharing == "app"
+        ));
+        assert!(app.popup.is_none());
+    }
+
+    #[test]
+    fn test_namespace_selector_clears_when_empty() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.namespace = Some(Namespace::app("search"));
+        app.open_namespace_selector();
+
+        for _ in 0.."search".len() {
+            app.handle_popup_input(key(KeyCode::Backspace));
+        }
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+
+        assert!(matches!(action, Some(Action::SetNamespace(None))));
+    }
+
+    #[test]
+    fn test_namespace_selector_sharing_wraps_backwards() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.open_namespace_selector();
+
+        app.handle_popup_input(key(KeyCode::BackTab));
+        app.handle_popup_input(key(KeyCode::Left));
+
+        assert!(matches!(
+            app.popup.as_ref().map(|p| &p.kind),
+            Some(PopupType::NamespaceSelector { sharing: Some(sharing), .. }) if sharing == "global"
+        ));
+    }
+}
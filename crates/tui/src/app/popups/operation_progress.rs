@@ -0,0 +1,54 @@
+//! Operation progress popup input handler.
+//!
+//! Responsibilities:
+//! - Map the cancel keys (c, Esc) to `Action::CancelOperation`
+//! - Hide the popup on `b` so the operation keeps running in the background
+//!
+//! Does NOT handle:
+//! - Does NOT close the popup otherwise (closed when the operation reports it finished)
+//! - Does NOT render the popup (handled by ui::popup module)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::ui::Toast;
+use crossterm::event::{KeyCode, KeyEvent};
+
+impl App {
+    /// Handle keyboard input for the operation progress popup.
+    pub fn handle_operation_progress_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('c') => Some(Action::CancelOperation),
+            KeyCode::Char('b') => {
+                self.popup = None;
+                self.toasts.push(Toast::info(
+                    "Running in the background — press J to see background jobs",
+                ));
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::ui::popup::{Popup, PopupType};
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_cancel_keys_emit_cancel_operation() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.popup = Some(Popup::builder(PopupType::OperationProgress).build());
+
+        for code in [KeyCode::Esc, KeyCode::Char('c')] {
+            let action = app.handle_popup_input(KeyEvent::new(code, KeyModifiers::NONE));
+            assert!(matches!(action, Some(Action::CancelOperation)));
+        }
+
+        let action = app.handle_popup_input(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE));
+        assert!(action.is_none());
+        assert!(app.popup.is_some());
+    }
+}
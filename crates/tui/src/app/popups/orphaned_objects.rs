@@ -0,0 +1,139 @@
+//! Orphaned Objects popup handler.
+//!
+//! Responsibilities:
+//! - Open the Orphaned Objects popup once a scan finds objects owned by missing users
+//! - Move the selection and copy the selected object's name to the clipboard
+//! - Re-run the scan on request
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::orphaned_objects)
+//! - Does NOT scan or reassign objects (handled by Action::FindOrphanedObjects and the CLI)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::ui::Toast;
+use crate::ui::popup::{Popup, PopupType};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use splunk_client::workflows::orphaned_objects::OrphanReport;
+
+impl App {
+    /// Show a finished orphan scan, or a toast when nothing is orphaned.
+    pub(crate) fn handle_orphaned_objects_found(&mut self, report: OrphanReport) {
+        self.loading = false;
+        if report.orphans.is_empty() {
+            self.toasts.push(Toast::success(format!(
+                "No orphaned objects (checked {} objects against {} users)",
+                report.scanned, report.users
+            )));
+            self.orphan_report = None;
+            if matches!(
+                self.popup.as_ref().map(|p| &p.kind),
+                Some(PopupType::OrphanedObjects { .. })
+            ) {
+                self.popup = None;
+            }
+            return;
+        }
+        self.orphan_report = Some(report);
+        self.popup = Some(Popup::builder(PopupType::OrphanedObjects { selected_index: 0 }).build());
+    }
+
+    /// Handle input for the OrphanedObjects popup.
+    pub fn handle_orphaned_objects_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        let Some(PopupType::OrphanedObjects { selected_index }) =
+            self.popup.as_ref().map(|p| &p.kind)
+        else {
+            return None;
+        };
+        let selected_index = *selected_index;
+        let count = self
+            .orphan_report
+            .as_ref()
+            .map_or(0, |report| report.orphans.len());
+
+        let selected_index = match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.popup = None;
+                return None;
+            }
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return self
+                    .orphan_report
+                    .as_ref()
+                    .and_then(|report| report.orphans.get(selected_index))
+                    .map(|orphan| Action::CopyToClipboard(orphan.name.clone()));
+            }
+            KeyCode::Char('r') => return Some(Action::FindOrphanedObjects),
+            KeyCode::Char('j') | KeyCode::Down => (selected_index + 1).min(count.saturating_sub(1)),
+            KeyCode::Char('k') | KeyCode::Up => selected_index.saturating_sub(1),
+            _ => return None,
+        };
+
+        self.popup = Some(Popup::builder(PopupType::OrphanedObjects { selected_index }).build());
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use splunk_client::models::KnowledgeObjectKind;
+    use splunk_client::workflows::orphaned_objects::OrphanedObject;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn orphan(name: &str) -> OrphanedObject {
+        OrphanedObject {
+            kind: KnowledgeObjectKind::Macro,
+            name: name.to_string(),
+            app: "search".to_string(),
+            owner: "departed_user".to_string(),
+            sharing: "user".to_string(),
+            suggested_owner: Some("admin".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_orphaned_objects_select_copy_rescan_and_close() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.handle_orphaned_objects_found(OrphanReport {
+            scanned: 10,
+            users: 3,
+            orphans: vec![orphan("web_errors"), orphan("slow_searches")],
+        });
+        assert!(matches!(
+            app.popup.as_ref().map(|p| &p.kind),
+            Some(PopupType::OrphanedObjects { selected_index: 0 })
+        ));
+
+        app.handle_popup_input(key(KeyCode::Char('j')));
+        app.handle_popup_input(key(KeyCode::Char('j')));
+        let action =
+            app.handle_popup_input(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert!(matches!(action, Some(Action::CopyToClipboard(name)) if name == "slow_searches"));
+
+        let action = app.handle_popup_input(key(KeyCode::Char('r')));
+        assert!(matches!(action, Some(Action::FindOrphanedObjects)));
+
+        app.handle_popup_input(key(KeyCode::Esc));
+        assert!(app.popup.is_none());
+    }
+
+    #[test]
+    fn test_clean_scan_shows_toast_without_popup() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.loading = true;
+        app.handle_orphaned_objects_found(OrphanReport {
+            scanned: 10,
+            users: 3,
+            orphans: Vec::new(),
+        });
+        assert!(!app.loading);
+        assert!(app.popup.is_none());
+        assert!(app.orphan_report.is_none());
+        assert_eq!(app.toasts.len(), 1);
+    }
+}
@@ -0,0 +1,137 @@
+//! Saved search owner reassignment popup handler.
+//!
+//! Responsibilities:
+//! - Collect the new owner for the selected saved searches
+//! - Emit ReassignSavedSearches on Enter
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::popup module)
+//! - Does NOT choose which saved searches to reassign (handled by saved searches input)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::ui::Toast;
+use crate::ui::popup::{Popup, PopupType};
+use crossterm::event::{KeyCode, KeyEvent};
+
+impl App {
+    /// Handle input for the ReassignSavedSearches popup.
+    pub fn handle_reassign_saved_searches_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        let Some(PopupType::ReassignSavedSearches { names, owner_input }) =
+            self.popup.as_ref().map(|p| &p.kind)
+        else {
+            return None;
+        };
+        let names = names.clone();
+        let mut owner_input = owner_input.clone();
+
+        match key.code {
+            KeyCode::Esc => {
+                self.popup = None;
+                return None;
+            }
+            KeyCode::Enter => {
+                let to_owner = owner_input.trim();
+                if to_owner.is_empty() {
+                    self.toasts.push(Toast::info("Enter the new owner"));
+                    return None;
+                }
+                let action = Action::ReassignSavedSearches {
+                    names,
+                    to_owner: to_owner.to_string(),
+                };
+                self.popup = None;
+                return Some(action);
+            }
+            KeyCode::Char(c) => owner_input.push(c),
+            KeyCode::Backspace => {
+                owner_input.pop();
+            }
+            _ => return None,
+        }
+
+        self.popup =
+            Some(Popup::builder(PopupType::ReassignSavedSearches { names, owner_input }).build());
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::app::state::CurrentScreen;
+    use crossterm::event::KeyModifiers;
+    use splunk_client::models::SavedSearch;
+    use splunk_client::workflows::saved_search_bulk::SavedSearchBulkAction;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn app_with_searches() -> App {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.current_screen = CurrentScreen::SavedSearches;
+        app.saved_searches = Some(
+            ["Errors", "License Usage", "Logins"]
+                .into_iter()
+                .map(|name| SavedSearch {
+                    name: name.to_string(),
+                    search: "index=main".to_string(),
+                    description: None,
+                    disabled: false,
+                })
+                .collect(),
+        );
+        app.saved_searches_state.select(Some(0));
+        app
+    }
+
+    #[test]
+    fn test_bulk_disable_targets_selection_or_current_search() {
+        let mut app = app_with_searches();
+
+        let action = app.handle_saved_searches_input(key(KeyCode::Char('D')));
+        let Some(Action::BulkUpdateSavedSearches { names, action }) = action else {
+            panic!("expected BulkUpdateSavedSearches, got {action:?}");
+        };
+        assert_eq!(names, vec!["Errors"]);
+        assert_eq!(action, SavedSearchBulkAction::Disable);
+
+        app.handle_saved_searches_input(key(KeyCode::Char(' ')));
+        app.saved_searches_state.select(Some(2));
+        app.handle_saved_searches_input(key(KeyCode::Char(' ')));
+        let action = app.handle_saved_searches_input(key(KeyCode::Char('E')));
+        let Some(Action::BulkUpdateSavedSearches { names, action }) = action else {
+            panic!("expected BulkUpdateSavedSearches, got {action:?}");
+        };
+        assert_eq!(names, vec!["Errors", "Logins"]);
+        assert_eq!(action, SavedSearchBulkAction::Enable);
+    }
+
+    #[test]
+    fn test_reassign_popup_emits_reassign_for_selection() {
+        let mut app = app_with_searches();
+        app.selected_saved_searches.insert("Logins".to_string());
+        app.selected_saved_searches.insert("Errors".to_string());
+
+        assert!(
+            app.handle_saved_searches_input(key(KeyCode::Char('O')))
+                .is_none()
+        );
+        assert!(app.handle_popup_input(key(KeyCode::Enter)).is_none());
+        assert!(app.popup.is_some());
+
+        for c in "svc_reports".chars() {
+            assert!(app.handle_popup_input(key(KeyCode::Char(c))).is_none());
+        }
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+
+        let Some(Action::ReassignSavedSearches { names, to_owner }) = action else {
+            panic!("expected ReassignSavedSearches, got {action:?}");
+        };
+        assert_eq!(names, vec!["Errors", "Logins"]);
+        assert_eq!(to_owner, "svc_reports");
+        assert!(app.popup.is_none());
+    }
+}
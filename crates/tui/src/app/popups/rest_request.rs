@@ -0,0 +1,96 @@
+//! REST explorer request popup handler.
+//!
+//! Responsibilities:
+//! - Open the request popup, empty or prefilled from the history
+//! - Collect the `METHOD PATH [FORM]` line and emit SendRestRequest on Enter
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::popup module)
+//! - Does NOT parse or send the request (handled by app::rest_explorer)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::app::state::CurrentScreen;
+use crate::ui::popup::{Popup, PopupType};
+use crossterm::event::{KeyCode, KeyEvent};
+
+impl App {
+    /// Open the REST request popup with `input` as the starting line.
+    pub(crate) fn open_rest_request_popup(&mut self, input: String) {
+        self.current_screen = CurrentScreen::Rest;
+        self.popup = Some(Popup::builder(PopupType::RestRequest { input }).build());
+    }
+
+    /// Handle input for the RestRequest popup.
+    pub fn handle_rest_request_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        let Some(PopupType::RestRequest { input }) = self.popup.as_ref().map(|p| &p.kind) else {
+            return None;
+        };
+        let mut input = input.clone();
+
+        match key.code {
+            KeyCode::Esc => {
+                self.popup = None;
+                return None;
+            }
+            KeyCode::Enter => {
+                let action = self.send_rest_request(&input);
+                if action.is_some() {
+                    self.popup = None;
+                }
+                return action;
+            }
+            KeyCode::Char(c) => input.push(c),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            _ => return None,
+        }
+
+        self.popup = Some(Popup::builder(PopupType::RestRequest { input }).build());
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_rest_request_popup_sends_typed_line() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.open_rest_request_popup(String::new());
+        assert_eq!(app.current_screen, CurrentScreen::Rest);
+
+        for c in "GET /services/server/info".chars() {
+            assert!(app.handle_popup_input(key(KeyCode::Char(c))).is_none());
+        }
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+
+        let Some(Action::SendRestRequest(request)) = action else {
+            panic!("expected SendRestRequest, got {:?}", action);
+        };
+        assert_eq!(request.path, "/services/server/info");
+        assert!(app.popup.is_none());
+        assert_eq!(app.rest_history, vec!["GET /services/server/info"]);
+    }
+
+    #[test]
+    fn test_rest_request_popup_keeps_invalid_line_open() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.open_rest_request_popup("GET services".to_string());
+
+        assert!(app.handle_popup_input(key(KeyCode::Enter)).is_none());
+        assert!(matches!(
+            app.popup.as_ref().map(|p| &p.kind),
+            Some(PopupType::RestRequest { input }) if input == "GET services"
+        ));
+        assert!(app.rest_history.is_empty());
+    }
+}
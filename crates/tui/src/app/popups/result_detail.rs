@@ -0,0 +1,208 @@
+//! Result detail popup handler.
+//!
+//! Responsibilities:
+//! - Open the popup for the current search result
+//! - Move the field table selection and scroll the event view
+//! - Copy the result JSON or the selected field value to the clipboard
+//! - Add the selected field as a filter to the search query
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::result_detail)
+//! - Does NOT run the filtered search (the user presses Enter in the query)
+//! - Does NOT choose sourcetype renderers (handled by ui::result_renderers)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::app::result_detail::{ResultField, add_search_filter, result_fields};
+use crate::app::state::SearchInputMode;
+use crate::ui::Toast;
+use crate::ui::popup::{Popup, PopupType};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+impl App {
+    /// Open the result detail popup for the current (scroll offset) result.
+    pub(crate) fn open_result_detail(&mut self) {
+        let visible = self.visible_search_result_count();
+        if visible == 0 {
+            self.toasts.push(Toast::info("No result to show"));
+            return;
+        }
+
+        let idx = self.search_scroll_offset.min(visible - 1);
+        self.result_detail = self.visible_search_result(idx).cloned();
+        self.result_detail_scroll_offset = 0;
+        self.result_detail_selected_field = 0;
+        self.popup = Some(Popup::builder(PopupType::ResultDetail).build());
+    }
+
+    /// The field selected in the result detail table.
+    fn selected_result_field(&self) -> Option<ResultField> {
+        let result = self.result_detail.as_ref()?;
+        result_fields(result)
+            .into_iter()
+            .nth(self.result_detail_selected_field)
+    }
+
+    /// Add the selected field to the search query and focus the query input.
+    fn add_result_field_filter(&mut self) {
+        let Some(field) = self.selected_result_field() else {
+            return;
+        };
+        let term = field.filter_term();
+        let query = add_search_filter(self.search_input.value(), &term);
+        self.search_input.set_value(query);
+        self.search_input_mode = SearchInputMode::QueryFocused;
+        self.popup = None;
+        self.result_detail = None;
+        self.result_detail_scroll_offset = 0;
+        self.toasts.push(Toast::info(format!(
+            "Added filter {} (press Enter to run)",
+            term
+        )));
+    }
+
+    /// Handle input for the ResultDetail popup.
+    pub fn handle_result_detail_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.popup = None;
+                self.result_detail = None;
+                self.result_detail_scroll_offset = 0;
+                self.result_detail_selected_field = 0;
+                None
+            }
+            KeyCode::Char('y') => self
+                .selected_result_field()
+                .map(|field| Action::CopyToClipboard(field.copy_value())),
+            KeyCode::Char('f') => {
+                self.add_result_field_filter();
+                None
+            }
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => self
+                .result_detail
+                .as_ref()
+                .and_then(|result| serde_json::to_string_pretty(result).ok())
+                .map(Action::CopyToClipboard),
+            KeyCode::Char('j') | KeyCode::Down => {
+                let field_count = self.result_detail.as_ref().map_or(0, |result| {
+                    result.as_object().map_or(0, serde_json::Map::len)
+                });
+                if self.result_detail_selected_field + 1 < field_count {
+                    self.result_detail_selected_field += 1;
+                }
+                None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.result_detail_selected_field =
+                    self.result_detail_selected_field.saturating_sub(1);
+                None
+            }
+            KeyCode::PageDown => {
+                self.result_detail_scroll_offset =
+                    self.result_detail_scroll_offset.saturating_add(10);
+                None
+            }
+            KeyCode::PageUp => {
+                self.result_detail_scroll_offset =
+                    self.result_detail_scroll_offset.saturating_sub(10);
+                None
+            }
+            KeyCode::Home => {
+                self.result_detail_scroll_offset = 0;
+                self.result_detail_selected_field = 0;
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::app::state::{CurrentScreen, SearchInputMode};
+    use serde_json::json;
+
+    fn app_with_results() -> App {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.current_screen = CurrentScreen::Search;
+        app.search_input_mode = SearchInputMode::ResultsFocused;
+        app.search_results = vec![
+            json!({ "sourcetype": "syslog", "_raw": "first" }),
+            json!({ "sourcetype": "access_combined", "_raw": "second" }),
+        ];
+        app.search_scroll_offset = 1;
+        app
+    }
+
+    #[test]
+    fn test_enter_opens_detail_for_current_result() {
+        let mut app = app_with_results();
+
+        app.handle_search_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        assert!(matches!(
+            app.popup.as_ref().map(|p| &p.kind),
+            Some(PopupType::ResultDetail)
+        ));
+        assert_eq!(app.result_detail.as_ref().unwrap()["_raw"], "second");
+    }
+
+    #[test]
+    fn test_enter_without_results_shows_toast() {
+        let mut app = app_with_results();
+        app.search_results.clear();
+
+        app.handle_search_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        assert!(app.popup.is_none());
+        assert_eq!(app.toasts.len(), 1);
+    }
+
+    #[test]
+    fn test_result_detail_copy_scroll_and_close() {
+        let mut app = app_with_results();
+        app.open_result_detail();
+
+        let action =
+            app.handle_popup_input(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert!(matches!(action, Some(Action::CopyToClipboard(json)) if json.contains("second")));
+
+        app.handle_popup_input(KeyEvent::new(KeyCode::PageDown, KeyModifiers::NONE));
+        app.handle_popup_input(KeyEvent::new(KeyCode::PageDown, KeyModifiers::NONE));
+        app.handle_popup_input(KeyEvent::new(KeyCode::PageUp, KeyModifiers::NONE));
+        assert_eq!(app.result_detail_scroll_offset, 10);
+
+        app.handle_popup_input(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(app.popup.is_none());
+        assert!(app.result_detail.is_none());
+        assert_eq!(app.result_detail_scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_result_detail_copies_field_and_adds_filter() {
+        let mut app = app_with_results();
+        app.search_input.set_value("index=web | head 100");
+        app.open_result_detail();
+
+        // Fields: sourcetype, then _raw
+        let action = app.handle_popup_input(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+        assert!(
+            matches!(action, Some(Action::CopyToClipboard(value)) if value == "access_combined")
+        );
+
+        app.handle_popup_input(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        app.handle_popup_input(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        assert_eq!(app.result_detail_selected_field, 1);
+        app.handle_popup_input(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE));
+
+        app.handle_popup_input(KeyEvent::new(KeyCode::Char('f'), KeyModifiers::NONE));
+        assert!(app.popup.is_none());
+        assert_eq!(app.search_input_mode, SearchInputMode::QueryFocused);
+        assert_eq!(
+            app.search_input.value(),
+            r#"index=web | head 100 | search sourcetype="access_combined""#
+        );
+    }
+}
@@ -3,6 +3,7 @@
 //! Responsibilities:
 //! - Handle saved search editing popup
 //! - Form navigation and input handling
+//! - Validate the scheduled dispatch window before saving
 //!
 //! Does NOT handle:
 //! - Does NOT render popups (handled by ui::popup module)
@@ -10,9 +11,11 @@
 
 use crate::action::Action;
 use crate::app::App;
+use crate::ui::Toast;
 use crate::ui::popup::{PopupType, SavedSearchField};
 use crate::undo::{SavedSearchRecoveryData, UndoableOperation};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use splunk_client::relative_time::parse_time_modifier;
 
 use super::common::optional_string;
 
@@ -28,17 +31,34 @@ impl App {
                     search_name,
                     search_input,
                     description_input,
+                    dispatch_earliest_input,
+                    dispatch_latest_input,
                     disabled,
                     ..
                 }),
                 KeyCode::Enter,
             ) => {
+                // Keep the dialog open so an invalid dispatch time can be corrected
+                for (label, value) in [
+                    ("Dispatch earliest", &dispatch_earliest_input),
+                    ("Dispatch latest", &dispatch_latest_input),
+                ] {
+                    if !value.is_empty()
+                        && let Err(e) = parse_time_modifier(value)
+                    {
+                        self.toasts.push(Toast::error(format!("{}: {}", label, e)));
+                        return None;
+                    }
+                }
+
                 self.popup = None;
                 Some(Action::UpdateSavedSearch {
                     name: search_name,
                     search: optional_string(search_input),
                     description: optional_string(description_input),
                     disabled: Some(disabled),
+                    dispatch_earliest: optional_string(dispatch_earliest_input),
+                    dispatch_latest: optional_string(dispatch_latest_input),
                 })
             }
             (Some(PopupType::EditSavedSearch { .. }), KeyCode::Esc) => {
@@ -153,6 +173,8 @@ fn update_saved_search_char(kind: &mut PopupType, c: char) -> bool {
         PopupType::EditSavedSearch {
             search_input,
             description_input,
+            dispatch_earliest_input,
+            dispatch_latest_input,
             disabled,
             selected_field,
             ..
@@ -161,6 +183,8 @@ fn update_saved_search_char(kind: &mut PopupType, c: char) -> bool {
                 SavedSearchField::Name => return false,
                 SavedSearchField::Search => search_input.push(c),
                 SavedSearchField::Description => description_input.push(c),
+                SavedSearchField::DispatchEarliest => dispatch_earliest_input.push(c),
+                SavedSearchField::DispatchLatest => dispatch_latest_input.push(c),
                 SavedSearchField::Disabled if c == ' ' => *disabled = !*disabled,
                 _ => return false,
             }
@@ -191,6 +215,8 @@ fn update_saved_search_backspace(kind: &mut PopupType) -> bool {
         PopupType::EditSavedSearch {
             search_input,
             description_input,
+            dispatch_earliest_input,
+            dispatch_latest_input,
             selected_field,
             ..
         } => match selected_field {
@@ -203,6 +229,14 @@ fn update_saved_search_backspace(kind: &mut PopupType) -> bool {
                 description_input.pop();
                 true
             }
+            SavedSearchField::DispatchEarliest => {
+                dispatch_earliest_input.pop();
+                true
+            }
+            SavedSearchField::DispatchLatest => {
+                dispatch_latest_input.pop();
+                true
+            }
         },
         PopupType::CreateSavedSearch {
             name_input,
@@ -223,7 +257,9 @@ fn update_saved_search_backspace(kind: &mut PopupType) -> bool {
                 description_input.pop();
                 true
             }
-            SavedSearchField::Disabled => false,
+            SavedSearchField::Disabled
+            | SavedSearchField::DispatchEarliest
+            | SavedSearchField::DispatchLatest => false,
         },
         _ => false,
     }
@@ -257,6 +293,8 @@ mod tests {
                 search_name: "test-search".to_string(),
                 search_input: String::new(),
                 description_input: String::new(),
+                dispatch_earliest_input: String::new(),
+                dispatch_latest_input: String::new(),
                 disabled: false,
                 selected_field: SavedSearchField::Search,
             })
@@ -279,6 +317,8 @@ mod tests {
                 search_name: "test-search".to_string(),
                 search_input: String::new(),
                 description_input: String::new(),
+                dispatch_earliest_input: String::new(),
+                dispatch_latest_input: String::new(),
                 disabled: false,
                 selected_field: SavedSearchField::Search,
             })
@@ -299,6 +339,22 @@ mod tests {
             })
         ));
 
+        // Tab through the dispatch window fields
+        for expected in [
+            SavedSearchField::DispatchEarliest,
+            SavedSearchField::DispatchLatest,
+        ] {
+            app.handle_popup_input(key(KeyCode::Tab));
+            let Some(Popup {
+                kind: PopupType::EditSavedSearch { selected_field, .. },
+                ..
+            }) = &app.popup
+            else {
+                panic!("expected EditSavedSearch popup");
+            };
+            assert_eq!(*selected_field, expected);
+        }
+
         // Tab again to Disabled
         let action = app.handle_popup_input(key(KeyCode::Tab));
         assert!(action.is_none());
@@ -352,6 +408,8 @@ mod tests {
                 search_name: "test-search".to_string(),
                 search_input: String::new(),
                 description_input: String::new(),
+                dispatch_earliest_input: String::new(),
+                dispatch_latest_input: String::new(),
                 disabled: false,
                 selected_field: SavedSearchField::Search,
             })
@@ -391,6 +449,8 @@ mod tests {
                 search_name: "test-search".to_string(),
                 search_input: String::new(),
                 description_input: String::new(),
+                dispatch_earliest_input: String::new(),
+                dispatch_latest_input: String::new(),
                 disabled: false,
                 selected_field: SavedSearchField::Search,
             })
@@ -436,6 +496,8 @@ mod tests {
                 search_name: "test-search".to_string(),
                 search_input: String::new(),
                 description_input: String::new(),
+                dispatch_earliest_input: String::new(),
+                dispatch_latest_input: String::new(),
                 disabled: false,
                 selected_field: SavedSearchField::Search,
             })
@@ -469,6 +531,8 @@ mod tests {
                 search_name: "test-search".to_string(),
                 search_input: "index=main".to_string(),
                 description_input: String::new(),
+                dispatch_earliest_input: String::new(),
+                dispatch_latest_input: String::new(),
                 disabled: false,
                 selected_field: SavedSearchField::Search,
             })
@@ -493,6 +557,8 @@ mod tests {
                 search_name: "test-search".to_string(),
                 search_input: String::new(),
                 description_input: String::new(),
+                dispatch_earliest_input: String::new(),
+                dispatch_latest_input: String::new(),
                 disabled: false,
                 selected_field: SavedSearchField::Disabled,
             })
@@ -535,6 +601,8 @@ mod tests {
                 search_name: "test-search".to_string(),
                 search_input: "index=main".to_string(),
                 description_input: "Test description".to_string(),
+                dispatch_earliest_input: String::new(),
+                dispatch_latest_input: String::new(),
                 disabled: true,
                 selected_field: SavedSearchField::Search,
             })
@@ -550,6 +618,8 @@ mod tests {
                 search: Some(s),
                 description: Some(d),
                 disabled: Some(dis),
+                dispatch_earliest: None,
+                dispatch_latest: None,
             }) if name == "test-search" && s == "index=main" && d == "Test description" && dis
         ));
         assert!(app.popup.is_none());
@@ -565,6 +635,8 @@ mod tests {
                 search_name: "test-search".to_string(),
                 search_input: String::new(),
                 description_input: String::new(),
+                dispatch_earliest_input: String::new(),
+                dispatch_latest_input: String::new(),
                 disabled: false,
                 selected_field: SavedSearchField::Search,
             })
@@ -580,8 +652,91 @@ mod tests {
                 search: None,
                 description: None,
                 disabled: Some(false),
+                dispatch_earliest: None,
+                dispatch_latest: None,
             }) if name == "test-search"
         ));
         assert!(app.popup.is_none());
     }
+
+    #[test]
+    fn test_edit_saved_search_dispatch_window() {
+        use crate::ui::popup::Popup;
+
+        let mut app = App::new(None, ConnectionContext::default());
+        app.popup = Some(
+            Popup::builder(PopupType::EditSavedSearch {
+                search_name: "nightly".to_string(),
+                search_input: String::new(),
+                description_input: String::new(),
+                dispatch_earliest_input: String::new(),
+                dispatch_latest_input: String::new(),
+                disabled: false,
+                selected_field: SavedSearchField::Description,
+            })
+            .build(),
+        );
+
+        // Description -> Dispatch Earliest
+        app.handle_popup_input(key(KeyCode::Tab));
+        for c in "-1d@dd".chars() {
+            app.handle_popup_input(char_key(c));
+        }
+        assert!(
+            app.popup
+                .as_ref()
+                .unwrap()
+                .content
+                .contains("Dispatch Earliest: -1d@dd (invalid: unknown time unit 'dd'")
+        );
+
+        // Invalid modifiers keep the dialog open
+        assert!(app.handle_popup_input(key(KeyCode::Enter)).is_none());
+        assert!(app.popup.is_some());
+
+        app.handle_popup_input(key(KeyCode::Backspace));
+        app.handle_popup_input(key(KeyCode::Tab));
+        for c in "@d".chars() {
+            app.handle_popup_input(char_key(c));
+        }
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+        assert!(matches!(
+            action,
+            Some(Action::UpdateSavedSearch {
+                dispatch_earliest: Some(ref earliest),
+                dispatch_latest: Some(ref latest),
+                search: None,
+                ..
+            }) if earliest == "-1d@d" && latest == "@d"
+        ));
+        assert!(app.popup.is_none());
+    }
+
+    #[test]
+    fn test_create_saved_search_skips_dispatch_fields() {
+        let mut kind = PopupType::CreateSavedSearch {
+            name_input: String::new(),
+            search_input: String::new(),
+            description_input: String::new(),
+            disabled: false,
+            selected_field: SavedSearchField::Description,
+        };
+
+        kind.navigate_fields(false);
+        assert!(matches!(
+            kind,
+            PopupType::CreateSavedSearch {
+                selected_field: SavedSearchField::Disabled,
+                ..
+            }
+        ));
+        kind.navigate_fields(true);
+        assert!(matches!(
+            kind,
+            PopupType::CreateSavedSearch {
+                selected_field: SavedSearchField::Description,
+                ..
+            }
+        ));
+    }
 }
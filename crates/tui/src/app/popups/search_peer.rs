@@ -0,0 +1,175 @@
+//! Search peer popup handler.
+//!
+//! Responsibilities:
+//! - Handle the add search peer form (URI, remote username, remote password)
+//! - Form navigation and input handling
+//!
+//! Does NOT handle:
+//! - Does NOT render popups (handled by ui::popup module)
+//! - Does NOT handle remove/quarantine confirmations (handled by confirm module)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::ui::popup::{PopupType, SearchPeerField};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use secrecy::SecretString;
+use splunk_client::models::AddSearchPeerParams;
+
+impl App {
+    /// Handle the add search peer popup.
+    pub fn handle_add_search_peer_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        let Some(mut kind @ PopupType::AddSearchPeer { .. }) =
+            self.popup.as_ref().map(|popup| popup.kind.clone())
+        else {
+            return None;
+        };
+
+        match key.code {
+            KeyCode::Enter => {
+                let PopupType::AddSearchPeer {
+                    uri_input,
+                    username_input,
+                    password_input,
+                    ..
+                } = kind
+                else {
+                    return None;
+                };
+                let uri = uri_input.trim();
+                if uri.is_empty() || username_input.is_empty() || password_input.is_empty() {
+                    return None;
+                }
+                self.popup = None;
+                Some(Action::AddSearchPeer {
+                    params: AddSearchPeerParams {
+                        uri: uri.to_string(),
+                        remote_username: username_input,
+                        remote_password: SecretString::new(password_input.into()),
+                    },
+                })
+            }
+            KeyCode::Esc => {
+                self.popup = None;
+                None
+            }
+            KeyCode::Tab => {
+                kind.navigate_fields(key.modifiers.contains(KeyModifiers::SHIFT));
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::BackTab | KeyCode::Up => {
+                kind.navigate_fields(true);
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::Down => {
+                kind.navigate_fields(false);
+                self.replace_popup_kind(kind);
+                None
+            }
+            KeyCode::Char(_) | KeyCode::Backspace => {
+                if update_search_peer_input(&mut kind, key.code) {
+                    self.replace_popup_kind(kind);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+fn update_search_peer_input(kind: &mut PopupType, code: KeyCode) -> bool {
+    let PopupType::AddSearchPeer {
+        uri_input,
+        username_input,
+        password_input,
+        selected_field,
+    } = kind
+    else {
+        return false;
+    };
+
+    let input = match selected_field {
+        SearchPeerField::Uri => uri_input,
+        SearchPeerField::RemoteUsername => username_input,
+        SearchPeerField::RemotePassword => password_input,
+    };
+    match code {
+        KeyCode::Char(c) => input.push(c),
+        KeyCode::Backspace => {
+            input.pop();
+        }
+        _ => return false,
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::ui::popup::Popup;
+    use secrecy::ExposeSecret;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn app_with_form() -> App {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.popup = Some(
+            Popup::builder(PopupType::AddSearchPeer {
+                uri_input: String::new(),
+                username_input: String::new(),
+                password_input: String::new(),
+                selected_field: SearchPeerField::Uri,
+            })
+            .build(),
+        );
+        app
+    }
+
+    fn type_text(app: &mut App, text: &str) {
+        for c in text.chars() {
+            app.handle_popup_input(key(KeyCode::Char(c)));
+        }
+    }
+
+    #[test]
+    fn test_add_search_peer_form_submits_params() {
+        let mut app = app_with_form();
+
+        type_text(&mut app, "https://idx3:8089");
+        app.handle_popup_input(key(KeyCode::Tab));
+        type_text(&mut app, "admin");
+        app.handle_popup_input(key(KeyCode::Tab));
+        type_text(&mut app, "changeme");
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+
+        match action {
+            Some(Action::AddSearchPeer { params }) => {
+                assert_eq!(params.uri, "https://idx3:8089");
+                assert_eq!(params.remote_username, "admin");
+                assert_eq!(params.remote_password.expose_secret(), "changeme");
+            }
+            other => panic!("Expected AddSearchPeer, got {:?}", other),
+        }
+        assert!(app.popup.is_none());
+    }
+
+    #[test]
+    fn test_add_search_peer_form_masks_password_and_requires_fields() {
+        let mut app = app_with_form();
+        type_text(&mut app, "idx3:8089");
+        app.handle_popup_input(key(KeyCode::BackTab));
+        type_text(&mut app, "hunter2");
+
+        let popup = app.popup.as_ref().unwrap();
+        assert!(!popup.content.contains("hunter2"));
+        assert!(popup.content.contains("Remote Password: (set)"));
+
+        // Remote username is still empty, so Enter keeps the form open.
+        assert!(app.handle_popup_input(key(KeyCode::Enter)).is_none());
+        assert!(app.popup.is_some());
+    }
+}
@@ -0,0 +1,98 @@
+//! Server messages popup handler.
+//!
+//! Responsibilities:
+//! - Open the bulletin board messages popup and mark its messages as seen
+//! - Move the selection, dismiss the selected message, or refresh the list
+//! - Copy the selected message text to the clipboard
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::server_messages)
+//! - Does NOT call the server (handled by Action::LoadServerMessages and
+//!   Action::DismissServerMessage)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::ui::popup::{Popup, PopupType};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+impl App {
+    /// Open the server messages popup.
+    pub(crate) fn open_server_messages(&mut self) {
+        self.server_messages.mark_all_seen();
+        self.popup = Some(Popup::builder(PopupType::ServerMessages).build());
+    }
+
+    /// Handle input for the ServerMessages popup.
+    pub fn handle_server_messages_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.popup = None;
+                None
+            }
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => self
+                .server_messages
+                .selected_message()
+                .map(|message| Action::CopyToClipboard(message.message.clone())),
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.server_messages.select_next();
+                None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.server_messages.select_previous();
+                None
+            }
+            KeyCode::Char('d') => self.server_messages.selected_message().map(|message| {
+                Action::DismissServerMessage {
+                    name: message.name.clone(),
+                }
+            }),
+            KeyCode::Char('r') => Some(Action::LoadServerMessages),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use splunk_client::{ServerMessage, ServerMessageSeverity};
+
+    #[test]
+    fn test_server_messages_popup_dismiss_and_close() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.server_messages.replace(vec![
+            ServerMessage {
+                name: "disk_space".to_string(),
+                message: "Disk space low".to_string(),
+                severity: ServerMessageSeverity::Error,
+                server: None,
+                help: None,
+                time_created: None,
+            },
+            ServerMessage {
+                name: "restart_required".to_string(),
+                message: "Restart required".to_string(),
+                severity: ServerMessageSeverity::Warn,
+                server: None,
+                help: None,
+                time_created: None,
+            },
+        ]);
+        app.open_server_messages();
+        assert_eq!(app.server_messages.unread_count(), 0);
+
+        app.handle_popup_input(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        let action = app.handle_popup_input(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE));
+        assert!(matches!(
+            action,
+            Some(Action::DismissServerMessage { name }) if name == "restart_required"
+        ));
+
+        let action = app.handle_popup_input(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE));
+        assert!(matches!(action, Some(Action::LoadServerMessages)));
+
+        app.handle_popup_input(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(app.popup.is_none());
+    }
+}
@@ -0,0 +1,147 @@
+//! Server restart confirmation popup handler.
+//!
+//! Responsibilities:
+//! - Open the typed confirmation popup for restarting splunkd
+//! - Collect the typed server name and emit RestartServer once it matches
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::popup module)
+//! - Does NOT restart the server (handled by Action::RestartServer)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::ui::popup::{Popup, PopupType};
+use crossterm::event::{KeyCode, KeyEvent};
+
+impl App {
+    /// Open the typed confirmation popup for restarting splunkd.
+    ///
+    /// The user must type the server name, so health info has to be loaded first.
+    pub(crate) fn open_server_restart_confirm(&mut self) {
+        let Some(server_name) = self
+            .health_info
+            .as_ref()
+            .and_then(|h| h.server_info.as_ref())
+            .map(|s| s.server_name.clone())
+        else {
+            self.push_info_toast_once("Server info not loaded yet");
+            return;
+        };
+        self.popup = Some(
+            Popup::builder(PopupType::ConfirmServerRestart {
+                server_name,
+                input: String::new(),
+            })
+            .build(),
+        );
+    }
+
+    /// Handle input for the ConfirmServerRestart popup.
+    pub fn handle_server_restart_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        let Some(PopupType::ConfirmServerRestart { server_name, input }) =
+            self.popup.as_ref().map(|p| &p.kind)
+        else {
+            return None;
+        };
+        let server_name = server_name.clone();
+        let mut input = input.clone();
+
+        match key.code {
+            KeyCode::Esc => {
+                self.popup = None;
+                return None;
+            }
+            KeyCode::Enter => {
+                if input != server_name {
+                    return None;
+                }
+                self.popup = None;
+                return Some(Action::RestartServer);
+            }
+            KeyCode::Char(c) => input.push(c),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            _ => return None,
+        }
+
+        self.popup =
+            Some(Popup::builder(PopupType::ConfirmServerRestart { server_name, input }).build());
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crossterm::event::KeyModifiers;
+    use splunk_client::models::HealthCheckOutput;
+    use splunk_client::{ServerMessage, ServerMessageSeverity};
+
+    fn app_with_server_info(restart_required: bool) -> App {
+        let mut app = App::new(None, ConnectionContext::default());
+        let health: HealthCheckOutput = serde_json::from_value(serde_json::json!({
+            "server_info": {
+                "serverName": "sh1",
+                "version": "9.1.2",
+                "build": "abc",
+                "restart_required": restart_required
+            }
+        }))
+        .unwrap();
+        app.set_server_info(health.server_info.as_ref().unwrap());
+        app.health_info = Some(health);
+        app
+    }
+
+    fn type_text(app: &mut App, text: &str) -> Option<Action> {
+        let mut action = None;
+        for c in text.chars() {
+            action = app.handle_popup_input(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        action
+    }
+
+    #[test]
+    fn test_restart_requires_typed_server_name() {
+        let mut app = app_with_server_info(false);
+        app.open_server_restart_confirm();
+
+        type_text(&mut app, "sh2");
+        let action = app.handle_popup_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(action.is_none());
+        assert!(app.popup.is_some());
+
+        app.handle_popup_input(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+        type_text(&mut app, "1");
+        let action = app.handle_popup_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(matches!(action, Some(Action::RestartServer)));
+        assert!(app.popup.is_none());
+    }
+
+    #[test]
+    fn test_restart_confirm_needs_server_info() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.open_server_restart_confirm();
+        assert!(app.popup.is_none());
+    }
+
+    #[test]
+    fn test_restart_required_from_server_info_or_messages() {
+        let app = app_with_server_info(true);
+        assert!(app.restart_required());
+
+        let mut app = app_with_server_info(false);
+        assert!(!app.restart_required());
+        app.server_messages.replace(vec![ServerMessage {
+            name: "restart_required".to_string(),
+            message: "Splunk must be restarted for changes to take effect.".to_string(),
+            severity: ServerMessageSeverity::Warn,
+            server: None,
+            help: None,
+            time_created: None,
+        }]);
+        assert!(app.restart_required());
+    }
+}
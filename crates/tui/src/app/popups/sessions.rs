@@ -0,0 +1,200 @@
+//! Saved sessions picker popup handler.
+//!
+//! Responsibilities:
+//! - Open the picker listing saved investigation sessions
+//! - Restore the selected session, save the current workspace under a typed name,
+//!   or delete the selected session
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::popup module)
+//! - Does NOT capture or restore workspace state (handled by app::sessions)
+//!
+//! Invariants:
+//! - Saving and deleting emit `PersistState` so the change reaches disk immediately
+//! - Empty session names never save
+
+use crate::action::Action;
+use crate::app::App;
+use crate::ui::popup::{Popup, PopupType};
+use crossterm::event::{KeyCode, KeyEvent};
+use splunk_config::WorkspaceSession;
+
+impl App {
+    /// Open the session picker listing the saved sessions.
+    pub(crate) fn open_session_picker(&mut self) {
+        self.popup = Some(
+            Popup::builder(PopupType::SessionPicker {
+                sessions: self.saved_sessions.iter().map(session_entry).collect(),
+                selected_index: 0,
+                name_input: None,
+            })
+            .build(),
+        );
+    }
+
+    /// Handle input for the SessionPicker popup.
+    pub fn handle_session_picker_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        let Some(PopupType::SessionPicker {
+            sessions,
+            selected_index,
+            name_input,
+        }) = self.popup.as_ref().map(|popup| popup.kind.clone())
+        else {
+            return None;
+        };
+        let selected_name = sessions.get(selected_index).map(|(name, _)| name.clone());
+
+        if let Some(mut name) = name_input {
+            match key.code {
+                KeyCode::Esc => self.set_session_name_input(None),
+                KeyCode::Enter => {
+                    let name = name.trim().to_string();
+                    if name.is_empty() {
+                        return None;
+                    }
+                    self.save_session(&name);
+                    self.open_session_picker();
+                    return Some(Action::PersistState);
+                }
+                KeyCode::Backspace => {
+                    name.pop();
+                    self.set_session_name_input(Some(name));
+                }
+                KeyCode::Char(c) => {
+                    name.push(c);
+                    self.set_session_name_input(Some(name));
+                }
+                _ => {}
+            }
+            return None;
+        }
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.popup = None;
+                None
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.set_session_selection(selected_index.saturating_sub(1));
+                None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                let last_index = sessions.len().saturating_sub(1);
+                self.set_session_selection((selected_index + 1).min(last_index));
+                None
+            }
+            KeyCode::Enter => {
+                let name = selected_name?;
+                self.popup = None;
+                self.restore_session(&name)
+            }
+            KeyCode::Char('s') => {
+                self.set_session_name_input(Some(String::new()));
+                None
+            }
+            KeyCode::Char('d') => {
+                let name = selected_name?;
+                self.delete_session(&name);
+                self.open_session_picker();
+                self.set_session_selection(selected_index.min(sessions.len().saturating_sub(2)));
+                Some(Action::PersistState)
+            }
+            _ => None,
+        }
+    }
+
+    fn set_session_selection(&mut self, index: usize) {
+        self.update_session_picker(|selected_index, _| *selected_index = index);
+    }
+
+    fn set_session_name_input(&mut self, input: Option<String>) {
+        self.update_session_picker(|_, name_input| *name_input = input);
+    }
+
+    fn update_session_picker(&mut self, update: impl FnOnce(&mut usize, &mut Option<String>)) {
+        if let Some(PopupType::SessionPicker {
+            sessions,
+            mut selected_index,
+            mut name_input,
+        }) = self.popup.as_ref().map(|popup| popup.kind.clone())
+        {
+            update(&mut selected_index, &mut name_input);
+            self.replace_popup_kind(PopupType::SessionPicker {
+                sessions,
+                selected_index,
+                name_input,
+            });
+        }
+    }
+}
+
+/// Picker row for a session: its name and a one-line summary of what it restores.
+fn session_entry(session: &WorkspaceSession) -> (String, String) {
+    let target = if session.search_query.is_empty() {
+        session.current_screen.clone()
+    } else {
+        format!("{}: {}", session.current_screen, session.search_query)
+    };
+    let summary = format!(
+        "{} ({} to {})",
+        target, session.earliest_time, session.latest_time
+    );
+    (session.name.clone(), summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_session_picker_saves_and_deletes() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.search_input.set_value("index=main");
+        app.open_session_picker();
+
+        app.handle_popup_input(key(KeyCode::Char('s')));
+        for c in "incident-1234".chars() {
+            app.handle_popup_input(key(KeyCode::Char(c)));
+        }
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+
+        assert!(matches!(action, Some(Action::PersistState)));
+        assert_eq!(app.saved_sessions.len(), 1);
+        assert_eq!(app.saved_sessions[0].name, "incident-1234");
+        assert_eq!(app.saved_sessions[0].search_query, "index=main");
+        assert!(matches!(
+            app.popup.as_ref().map(|popup| &popup.kind),
+            Some(PopupType::SessionPicker { sessions, name_input: None, .. }) if sessions.len() == 1
+        ));
+
+        let action = app.handle_popup_input(key(KeyCode::Char('d')));
+
+        assert!(matches!(action, Some(Action::PersistState)));
+        assert!(app.saved_sessions.is_empty());
+    }
+
+    #[test]
+    fn test_session_picker_enter_restores_selected() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.search_input.set_value("index=_internal");
+        app.save_session("first");
+        app.search_input.set_value("index=main");
+        app.save_session("second");
+        app.open_session_picker();
+
+        app.handle_popup_input(key(KeyCode::Down));
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+
+        assert!(app.popup.is_none());
+        assert!(matches!(
+            action,
+            Some(Action::RunSearch { query, .. }) if query == "index=_internal"
+        ));
+    }
+}
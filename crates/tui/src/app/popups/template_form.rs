@@ -0,0 +1,137 @@
+//! Search template parameter form popup handler.
+//!
+//! Responsibilities:
+//! - Edit parameter values and move between fields
+//! - Render the template into the search query, or show why it cannot be rendered
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::template_form)
+//! - Does NOT choose the template (handled by Ctrl+t in the search query box)
+
+use crate::action::Action;
+use crate::app::App;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+impl App {
+    /// Handle input for the TemplateParameters popup.
+    pub fn handle_template_form_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.code == KeyCode::Esc {
+            self.popup = None;
+            self.template_form = None;
+            return None;
+        }
+        if key.code == KeyCode::Enter {
+            self.apply_template_form();
+            return None;
+        }
+
+        let form = self.template_form.as_mut()?;
+        match key.code {
+            KeyCode::Tab | KeyCode::Down => form.next_field(),
+            KeyCode::BackTab | KeyCode::Up => form.previous_field(),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return form.query().ok().map(Action::CopyToClipboard);
+            }
+            _ => {
+                if let Some(input) = form.selected_input_mut() {
+                    input.handle_key(key);
+                }
+                form.error = None;
+            }
+        }
+        None
+    }
+
+    /// Put the rendered template into the search box, or keep the form open with the error.
+    fn apply_template_form(&mut self) {
+        let Some(form) = self.template_form.as_mut() else {
+            return;
+        };
+        match form.query() {
+            Ok(query) => {
+                let name = form.template.name.clone();
+                self.template_form = None;
+                self.popup = None;
+                self.history_index = None;
+                self.search_input.set_value(query);
+                self.toasts
+                    .push(crate::ui::Toast::info(format!("Template: {}", name)));
+                self.trigger_validation();
+            }
+            Err(e) => form.error = Some(e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::app::state::CurrentScreen;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn app_with_template() -> App {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.current_screen = CurrentScreen::Search;
+        app.search_templates = vec![
+            serde_json::from_str(
+                r#"{
+                    "name": "Failed logins",
+                    "query": "index=$index$ action=failure | where count >= $threshold$",
+                    "parameters": [
+                        { "name": "index", "default": "security" },
+                        { "name": "threshold", "type": "integer" }
+                    ]
+                }"#,
+            )
+            .unwrap(),
+        ];
+        app
+    }
+
+    #[test]
+    fn test_ctrl_t_opens_form_and_enter_fills_query() {
+        let mut app = app_with_template();
+
+        app.handle_input(KeyEvent::new(KeyCode::Char('t'), KeyModifiers::CONTROL));
+        assert!(app.template_form.is_some());
+
+        // Required parameter left empty: the form stays open with the error
+        app.handle_popup_input(key(KeyCode::Enter));
+        let form = app.template_form.as_ref().expect("form stays open");
+        assert_eq!(
+            form.error.as_deref(),
+            Some("Parameter 'threshold' is required")
+        );
+
+        app.handle_popup_input(key(KeyCode::Tab));
+        for c in "10".chars() {
+            app.handle_popup_input(key(KeyCode::Char(c)));
+        }
+        assert!(app.template_form.as_ref().unwrap().error.is_none());
+        app.handle_popup_input(key(KeyCode::Enter));
+
+        assert!(app.popup.is_none());
+        assert!(app.template_form.is_none());
+        assert_eq!(
+            app.search_input.value(),
+            "index=security action=failure | where count >= 10"
+        );
+    }
+
+    #[test]
+    fn test_template_form_esc_cancels() {
+        let mut app = app_with_template();
+        app.search_input.set_value("index=main");
+
+        app.handle_input(KeyEvent::new(KeyCode::Char('t'), KeyModifiers::CONTROL));
+        app.handle_popup_input(key(KeyCode::Esc));
+
+        assert!(app.popup.is_none());
+        assert!(app.template_form.is_none());
+        assert_eq!(app.search_input.value(), "index=main");
+    }
+}
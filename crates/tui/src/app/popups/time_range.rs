@@ -0,0 +1,179 @@
+//! Time range picker popup handler.
+//!
+//! Responsibilities:
+//! - Open the picker on the current range of the search, internal logs, or audit screen
+//! - Pick a preset or edit and validate a custom absolute range
+//! - Apply the chosen range and reload the screen that uses it
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::time_range)
+//! - Does NOT parse times (handled by app::time_range)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::app::time_range::{TimeRange, TimeRangeFocus, TimeRangePicker, TimeRangeTarget};
+use crate::ui::Toast;
+use crate::ui::popup::{Popup, PopupType};
+use chrono::Local;
+use crossterm::event::{KeyCode, KeyEvent};
+
+impl App {
+    /// Open the time range picker for `target`, starting from its current range.
+    pub(crate) fn open_time_range_picker(&mut self, target: TimeRangeTarget) {
+        let current = match target {
+            TimeRangeTarget::Search => TimeRange::new(
+                self.search_defaults.earliest_time.clone(),
+                self.search_defaults.latest_time.clone(),
+            ),
+            TimeRangeTarget::InternalLogs => {
+                TimeRange::new(self.internal_logs_defaults.earliest_time.clone(), "now")
+            }
+            TimeRangeTarget::Audit => self.audit_time_range.clone(),
+        };
+        self.time_range_picker = Some(TimeRangePicker::new(target, current));
+        self.popup = Some(Popup::builder(PopupType::TimeRangePicker).build());
+    }
+
+    /// Store `range` for `target`; logs and audit events reload right away.
+    fn apply_time_range(&mut self, target: TimeRangeTarget, range: TimeRange) -> Option<Action> {
+        self.popup = None;
+        self.time_range_picker = None;
+        self.toasts.push(Toast::info(format!(
+            "{} time range: {}",
+            target.label(),
+            range.label()
+        )));
+        match target {
+            TimeRangeTarget::Search => {
+                self.search_defaults.earliest_time = range.earliest;
+                self.search_defaults.latest_time = range.latest;
+                None
+            }
+            TimeRangeTarget::InternalLogs => {
+                self.internal_logs_defaults.earliest_time = range.earliest;
+                Some(self.internal_logs_action())
+            }
+            TimeRangeTarget::Audit => {
+                self.audit_time_range = range;
+                Some(self.audit_action())
+            }
+        }
+    }
+
+    /// Handle input for the TimeRangePicker popup.
+    pub fn handle_time_range_picker_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.code == KeyCode::Esc {
+            self.popup = None;
+            self.time_range_picker = None;
+            return None;
+        }
+
+        let picker = self.time_range_picker.as_mut()?;
+        match (picker.focus, key.code) {
+            (_, KeyCode::Tab | KeyCode::BackTab) => picker.cycle_focus(),
+            (TimeRangeFocus::Presets, KeyCode::Char('j') | KeyCode::Down) => picker.next(),
+            (TimeRangeFocus::Presets, KeyCode::Char('k') | KeyCode::Up) => picker.previous(),
+            (TimeRangeFocus::Presets, KeyCode::Enter) if picker.selected_preset().is_none() => {
+                picker.focus = TimeRangeFocus::Earliest;
+            }
+            (_, KeyCode::Enter) => match picker.range(&Local) {
+                Ok(range) => {
+                    let target = picker.target;
+                    return self.apply_time_range(target, range);
+                }
+                Err(message) => picker.error = Some(message),
+            },
+            (TimeRangeFocus::Presets, KeyCode::Char('q')) => {
+                self.popup = None;
+                self.time_range_picker = None;
+            }
+            (TimeRangeFocus::Earliest, _) => {
+                picker.earliest.handle_key(key);
+                picker.error = None;
+            }
+            (TimeRangeFocus::Latest, _) => {
+                picker.latest.handle_key(key);
+                picker.error = None;
+            }
+            _ => {}
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn type_text(app: &mut App, text: &str) {
+        for c in text.chars() {
+            app.handle_popup_input(key(KeyCode::Char(c)));
+        }
+    }
+
+    #[test]
+    fn test_preset_applies_to_audit_and_reloads() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.open_time_range_picker(TimeRangeTarget::Audit);
+
+        // Last 24 hours -> Last 7 days
+        app.handle_popup_input(key(KeyCode::Char('j')));
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+
+        assert!(matches!(
+            action,
+            Some(Action::LoadAuditEvents { ref earliest, ref latest, .. })
+                if earliest == "-7d" && latest == "now"
+        ));
+        assert_eq!(app.audit_time_range, TimeRange::new("-7d", "now"));
+        assert!(app.popup.is_none());
+    }
+
+    #[test]
+    fn test_custom_range_sets_search_defaults() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.open_time_range_picker(TimeRangeTarget::Search);
+
+        // Move to the custom row and start editing it
+        app.handle_popup_input(key(KeyCode::Up));
+        app.handle_popup_input(key(KeyCode::Up));
+        app.handle_popup_input(key(KeyCode::Up));
+        app.handle_popup_input(key(KeyCode::Enter));
+        type_text(&mut app, "2024-01-15T08:00:00Z");
+        app.handle_popup_input(key(KeyCode::Tab));
+        type_text(&mut app, "2024-01-15T09:30:00+01:00");
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+
+        assert!(action.is_none());
+        assert_eq!(
+            app.search_defaults.earliest_time,
+            "2024-01-15T08:00:00+00:00"
+        );
+        assert_eq!(app.search_defaults.latest_time, "2024-01-15T09:30:00+01:00");
+        assert!(app.time_range_picker.is_none());
+    }
+
+    #[test]
+    fn test_invalid_custom_range_keeps_picker_open() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.open_time_range_picker(TimeRangeTarget::Search);
+        app.handle_popup_input(key(KeyCode::Tab));
+        type_text(&mut app, "2024-01-15T10:00:00Z");
+        app.handle_popup_input(key(KeyCode::Tab));
+        type_text(&mut app, "2024-01-15T09:00:00Z");
+
+        assert!(app.handle_popup_input(key(KeyCode::Enter)).is_none());
+        let picker = app.time_range_picker.as_ref().unwrap();
+        assert_eq!(
+            picker.error.as_deref(),
+            Some("Earliest must be before latest")
+        );
+        assert_eq!(app.search_defaults.earliest_time, "-24h");
+    }
+}
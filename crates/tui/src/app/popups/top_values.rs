@@ -0,0 +1,155 @@
+//! Top-values popup handler.
+//!
+//! Responsibilities:
+//! - Open the field picker for the current search result
+//! - Pick a field and request its top values, or go back to pick another
+//! - Copy the follow-up query to the clipboard
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::top_values)
+//! - Does NOT run the follow-up search (handled by Action::LoadTopValues)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::app::top_values::{TopValues, TopValuesPhase};
+use crate::ui::Toast;
+use crate::ui::popup::{Popup, PopupType};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+impl App {
+    /// Open the top-values field picker for the current (scroll offset) result.
+    pub(crate) fn open_top_values(&mut self) {
+        let Some(scope) = self.last_search_scope.clone() else {
+            self.toasts.push(Toast::info("Run a search first"));
+            return;
+        };
+        let visible = self.visible_search_result_count();
+        if visible == 0 {
+            self.toasts.push(Toast::info("No result to summarize"));
+            return;
+        }
+
+        let idx = self.search_scroll_offset.min(visible - 1);
+        let Some(top_values) = self
+            .visible_search_result(idx)
+            .and_then(|result| TopValues::new(scope, result))
+        else {
+            self.toasts
+                .push(Toast::info("Selected result has no fields to summarize"));
+            return;
+        };
+        self.top_values = Some(top_values);
+        self.popup = Some(Popup::builder(PopupType::TopValues).build());
+    }
+
+    /// Handle input for the TopValues popup.
+    pub fn handle_top_values_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        if matches!(key.code, KeyCode::Esc | KeyCode::Char('q')) {
+            self.popup = None;
+            self.top_values = None;
+            return None;
+        }
+
+        let top_values = self.top_values.as_mut()?;
+        if top_values.phase == TopValuesPhase::SelectField {
+            match key.code {
+                KeyCode::Char('j') | KeyCode::Down => top_values.next_field(),
+                KeyCode::Char('k') | KeyCode::Up => top_values.previous_field(),
+                KeyCode::Enter => return Some(top_values.load_selected()),
+                _ => {}
+            }
+            return None;
+        }
+
+        match key.code {
+            KeyCode::Backspace | KeyCode::Char('h') | KeyCode::Left => {
+                top_values.phase = TopValuesPhase::SelectField;
+            }
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return top_values
+                    .field()
+                    .map(|field| Action::CopyToClipboard(top_values.query(field)));
+            }
+            _ => {}
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::app::state::{CurrentScreen, SearchInputMode};
+    use serde_json::json;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn app_with_results() -> App {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.current_screen = CurrentScreen::Search;
+        app.search_input_mode = SearchInputMode::ResultsFocused;
+        app.handle_search_action(Action::SearchStarted("index=web".to_string()));
+        app.set_search_results(vec![
+            json!({"_raw": "a", "host": "web01", "status": "200"}),
+            json!({"_raw": "b", "host": "web02", "status": "500"}),
+        ]);
+        app
+    }
+
+    #[test]
+    fn test_t_opens_picker_and_enter_requests_top_values() {
+        let mut app = app_with_results();
+
+        app.handle_input(key(KeyCode::Char('t')));
+        assert!(matches!(
+            app.popup.as_ref().map(|p| &p.kind),
+            Some(PopupType::TopValues)
+        ));
+        assert_eq!(app.top_values.as_ref().unwrap().fields, ["host", "status"]);
+
+        app.handle_popup_input(key(KeyCode::Char('j')));
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+        assert!(matches!(
+            action,
+            Some(Action::LoadTopValues { ref query, ref field, ref earliest_time, ref latest_time })
+                if query == "index=web | top limit=20 status"
+                    && field == "status"
+                    && *earliest_time == app.search_defaults.earliest_time
+                    && *latest_time == app.search_defaults.latest_time
+        ));
+
+        app.update(Action::TopValuesLoaded {
+            field: "status".to_string(),
+            result: Ok(vec![
+                json!({"status": "500", "count": "3", "percent": "75"}),
+                json!({"status": "200", "count": "1", "percent": "25"}),
+            ]),
+        });
+        let phase = &app.top_values.as_ref().unwrap().phase;
+        assert!(matches!(phase, TopValuesPhase::Loaded { rows, .. } if rows.len() == 2));
+
+        // Back to the field picker, then close
+        app.handle_popup_input(key(KeyCode::Backspace));
+        assert_eq!(
+            app.top_values.as_ref().unwrap().phase,
+            TopValuesPhase::SelectField
+        );
+        app.handle_popup_input(key(KeyCode::Esc));
+        assert!(app.popup.is_none());
+        assert!(app.top_values.is_none());
+    }
+
+    #[test]
+    fn test_t_without_search_shows_toast() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.current_screen = CurrentScreen::Search;
+        app.search_input_mode = SearchInputMode::ResultsFocused;
+
+        app.handle_input(key(KeyCode::Char('t')));
+        assert!(app.popup.is_none());
+        assert!(app.top_values.is_none());
+    }
+}
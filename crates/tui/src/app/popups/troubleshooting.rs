@@ -0,0 +1,107 @@
+//! Troubleshooting recipes popup handler.
+//!
+//! Responsibilities:
+//! - Open the recipe menu
+//! - Run the selected recipe on the Search screen
+//!
+//! Does NOT handle:
+//! - Does NOT define the recipes (handled by app::troubleshooting)
+//! - Does NOT render the popup (handled by ui::popup module)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::app::state::CurrentScreen;
+use crate::app::troubleshooting::RECIPES;
+use crate::ui::popup::{Popup, PopupType};
+use crossterm::event::{KeyCode, KeyEvent};
+
+impl App {
+    /// Open the troubleshooting recipes popup with the first recipe selected.
+    pub(crate) fn open_troubleshooting_recipes(&mut self) {
+        self.popup =
+            Some(Popup::builder(PopupType::TroubleshootingRecipes { selected_index: 0 }).build());
+    }
+
+    /// Handle input for the TroubleshootingRecipes popup.
+    pub fn handle_troubleshooting_recipes_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        let Some(PopupType::TroubleshootingRecipes { selected_index }) =
+            self.popup.as_ref().map(|popup| popup.kind.clone())
+        else {
+            return None;
+        };
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.popup = None;
+                None
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.replace_popup_kind(PopupType::TroubleshootingRecipes {
+                    selected_index: selected_index.saturating_sub(1),
+                });
+                None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.replace_popup_kind(PopupType::TroubleshootingRecipes {
+                    selected_index: (selected_index + 1).min(RECIPES.len() - 1),
+                });
+                None
+            }
+            KeyCode::Enter => {
+                let recipe = RECIPES.get(selected_index)?;
+                self.popup = None;
+                self.current_screen = CurrentScreen::Search;
+                self.init_focus_manager_for_screen(CurrentScreen::Search);
+                self.clear_error_on_navigation();
+                self.search_input.set_value(recipe.query);
+                self.execute_search()
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_troubleshooting_recipe_runs_on_search_screen() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.current_screen = CurrentScreen::Indexes;
+        app.open_troubleshooting_recipes();
+
+        app.handle_popup_input(key(KeyCode::Down));
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+
+        assert!(app.popup.is_none());
+        assert_eq!(app.current_screen, CurrentScreen::Search);
+        assert_eq!(app.search_input.value(), RECIPES[1].query);
+        assert!(matches!(
+            action,
+            Some(Action::RunSearch { query, .. }) if query == RECIPES[1].query
+        ));
+    }
+
+    #[test]
+    fn test_troubleshooting_selection_stays_in_range() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.open_troubleshooting_recipes();
+
+        app.handle_popup_input(key(KeyCode::Up));
+        for _ in 0..RECIPES.len() + 2 {
+            app.handle_popup_input(key(KeyCode::Down));
+        }
+
+        assert!(matches!(
+            app.popup.as_ref().map(|popup| &popup.kind),
+            Some(PopupType::TroubleshootingRecipes { selected_index }) if *selected_index == RECIPES.len() - 1
+        ));
+    }
+}
@@ -0,0 +1,136 @@
+//! Tstats query builder popup handler.
+//!
+//! Responsibilities:
+//! - Edit the builder selections (dataset, split-by, aggregates, span)
+//! - Copy the generated SPL or run it on the Search screen
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::tstats_builder)
+//! - Does NOT fetch the data model definition (handled by Action::LoadTstatsBuilder)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::app::state::CurrentScreen;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use splunk_client::SearchMode;
+
+impl App {
+    /// Handle input for the TstatsBuilder popup.
+    pub fn handle_tstats_builder_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        if matches!(key.code, KeyCode::Esc | KeyCode::Char('q')) {
+            self.popup = None;
+            self.tstats_builder = None;
+            return None;
+        }
+        if key.code == KeyCode::Enter {
+            return self.run_tstats_builder_query();
+        }
+
+        let builder = self.tstats_builder.as_mut()?;
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Some(Action::CopyToClipboard(builder.query().to_spl()));
+            }
+            KeyCode::Char('j') | KeyCode::Down => builder.next_field(),
+            KeyCode::Char('k') | KeyCode::Up => builder.previous_field(),
+            KeyCode::Char('l') | KeyCode::Right => builder.cycle_object(true),
+            KeyCode::Char('h') | KeyCode::Left => builder.cycle_object(false),
+            KeyCode::Char(' ') => builder.toggle_split_by(),
+            KeyCode::Char('a') => builder.cycle_aggregate(),
+            KeyCode::Char('s') => builder.cycle_span(),
+            KeyCode::Char('o') => builder.summaries_only = !builder.summaries_only,
+            _ => {}
+        }
+        None
+    }
+
+    /// Close the builder and run its query on the Search screen.
+    fn run_tstats_builder_query(&mut self) -> Option<Action> {
+        let query = self.tstats_builder.take()?.query().to_spl();
+        self.popup = None;
+
+        self.search_input.set_value(query.clone());
+        self.current_screen = CurrentScreen::Search;
+        self.add_to_history(query.clone());
+        self.search_status = format!("Running: {}", query);
+        Some(Action::RunSearch {
+            query,
+            search_defaults: self.search_defaults.clone(),
+            search_mode: SearchMode::Normal,
+            realtime_window: None,
+            finalize_after: self.search_finalize_after(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::app::tstats_builder::TstatsBuilder;
+    use crate::ui::popup::{Popup, PopupType};
+    use splunk_client::models::DataModel;
+
+    fn app_with_builder() -> App {
+        let mut app = App::new(None, ConnectionContext::default());
+        let datamodel = DataModel {
+            name: "Authentication".to_string(),
+            displayName: "Authentication".to_string(),
+            description: None,
+            owner: "nobody".to_string(),
+            app: "search".to_string(),
+            is_accelerated: false,
+            json_data: Some(
+                serde_json::json!({
+                    "objects": [{
+                        "objectName": "Authentication",
+                        "parentName": "BaseEvent",
+                        "fields": [{ "fieldName": "user" }, { "fieldName": "dest" }]
+                    }]
+                })
+                .to_string(),
+            ),
+            updated: None,
+        };
+        app.tstats_builder = TstatsBuilder::new(&datamodel);
+        app.popup = Some(Popup::builder(PopupType::TstatsBuilder).build());
+        app
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_tstats_builder_enter_runs_generated_query() {
+        let mut app = app_with_builder();
+
+        app.handle_popup_input(key(KeyCode::Char(' ')));
+        app.handle_popup_input(key(KeyCode::Down));
+        app.handle_popup_input(key(KeyCode::Char('a')));
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+
+        let expected = "| tstats count dc(Authentication.dest) from datamodel=Authentication.Authentication by Authentication.user";
+        assert!(matches!(action, Some(Action::RunSearch { query, .. }) if query == expected));
+        assert!(app.popup.is_none());
+        assert!(app.tstats_builder.is_none());
+        assert_eq!(app.current_screen, CurrentScreen::Search);
+        assert_eq!(app.search_input.value(), expected);
+    }
+
+    #[test]
+    fn test_tstats_builder_copy_and_close() {
+        let mut app = app_with_builder();
+
+        app.handle_popup_input(key(KeyCode::Char('o')));
+        let action =
+            app.handle_popup_input(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert!(
+            matches!(action, Some(Action::CopyToClipboard(spl)) if spl.starts_with("| tstats summariesonly=true count"))
+        );
+
+        app.handle_popup_input(key(KeyCode::Esc));
+        assert!(app.popup.is_none());
+        assert!(app.tstats_builder.is_none());
+    }
+}
@@ -0,0 +1,176 @@
+//! Data onboarding wizard popup handler.
+//!
+//! Responsibilities:
+//! - Edit step inputs, move between fields, and toggle the event format
+//! - Move forward (validating each step) and back between steps
+//! - Start the run from the review step and copy the curl example afterwards
+//!
+//! Does NOT handle:
+//! - Does NOT render the popup (handled by ui::wizard)
+//! - Does NOT create anything on the server (handled by side effects)
+
+use crate::action::Action;
+use crate::app::App;
+use crate::wizard::WizardStep;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+impl App {
+    /// Handle input for the OnboardingWizard popup.
+    pub fn handle_onboarding_wizard_popup(&mut self, key: KeyEvent) -> Option<Action> {
+        let wizard = self.onboarding_wizard.as_mut()?;
+
+        match (wizard.step, key.code) {
+            (WizardStep::Running, KeyCode::Esc) | (WizardStep::Finished, KeyCode::Esc) => {
+                self.close_onboarding_wizard();
+            }
+            (WizardStep::Running, _) => {}
+            (WizardStep::Finished, KeyCode::Enter) => self.close_onboarding_wizard(),
+            (WizardStep::Finished, KeyCode::Char('c'))
+                if key.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                let outcome = wizard.result.as_ref()?.as_ref().ok()?;
+                return Some(Action::CopyToClipboard(outcome.curl_example()));
+            }
+            (WizardStep::Finished, _) => {}
+            (_, KeyCode::Esc) => {
+                if !wizard.back() {
+                    self.close_onboarding_wizard();
+                }
+            }
+            (WizardStep::Review, KeyCode::Enter) => match wizard.plan() {
+                Ok(plan) => {
+                    wizard.step = WizardStep::Running;
+                    return Some(Action::RunOnboardingWizard { plan });
+                }
+                Err(e) => wizard.error = Some(e),
+            },
+            (_, KeyCode::Enter) => {
+                if let Err(e) = wizard.advance() {
+                    wizard.error = Some(e);
+                }
+            }
+            (_, KeyCode::Tab | KeyCode::Down) => wizard.next_field(),
+            (_, KeyCode::BackTab | KeyCode::Up) => wizard.previous_field(),
+            (_, KeyCode::Char(' ') | KeyCode::Left | KeyCode::Right)
+                if wizard.format_selected() =>
+            {
+                wizard.toggle_format();
+            }
+            _ => {
+                if let Some(input) = wizard.selected_input_mut() {
+                    input.handle_key(key);
+                }
+                wizard.error = None;
+            }
+        }
+        None
+    }
+
+    fn close_onboarding_wizard(&mut self) {
+        self.popup = None;
+        self.onboarding_wizard = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::ui::popup::{Popup, PopupType};
+    use crate::wizard::{EventFormat, OnboardingOutcome, OnboardingWizard};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn type_text(app: &mut App, text: &str) {
+        for c in text.chars() {
+            app.handle_popup_input(key(KeyCode::Char(c)));
+        }
+    }
+
+    fn app_with_wizard() -> App {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.onboarding_wizard = Some(OnboardingWizard::new());
+        app.popup = Some(Popup::builder(PopupType::OnboardingWizard).build());
+        app
+    }
+
+    #[test]
+    fn test_wizard_walks_steps_and_runs_plan() {
+        let mut app = app_with_wizard();
+
+        // Empty index name is rejected
+        app.handle_popup_input(key(KeyCode::Enter));
+        let wizard = app.onboarding_wizard.as_ref().unwrap();
+        assert_eq!(wizard.step, WizardStep::Index);
+        assert_eq!(wizard.error.as_deref(), Some("Index name is required"));
+
+        type_text(&mut app, "web");
+        app.handle_popup_input(key(KeyCode::Enter));
+
+        // Sourcetype step: toggle the format on the second field
+        app.handle_popup_input(key(KeyCode::Tab));
+        app.handle_popup_input(key(KeyCode::Char(' ')));
+        assert_eq!(
+            app.onboarding_wizard.as_ref().unwrap().format,
+            EventFormat::Raw
+        );
+        app.handle_popup_input(key(KeyCode::Enter));
+        app.handle_popup_input(key(KeyCode::Enter));
+        assert_eq!(
+            app.onboarding_wizard.as_ref().unwrap().step,
+            WizardStep::Review
+        );
+
+        let action = app.handle_popup_input(key(KeyCode::Enter));
+        let Some(Action::RunOnboardingWizard { plan }) = action else {
+            panic!("expected RunOnboardingWizard, got {:?}", action);
+        };
+        assert_eq!(plan.index.name, "web");
+        assert_eq!(plan.sourcetype, "web:raw");
+        assert_eq!(plan.token.name, "web_hec");
+        assert_eq!(
+            app.onboarding_wizard.as_ref().unwrap().step,
+            WizardStep::Running
+        );
+    }
+
+    #[test]
+    fn test_wizard_esc_goes_back_then_cancels() {
+        let mut app = app_with_wizard();
+        type_text(&mut app, "web");
+        app.handle_popup_input(key(KeyCode::Enter));
+
+        app.handle_popup_input(key(KeyCode::Esc));
+        let wizard = app.onboarding_wizard.as_ref().unwrap();
+        assert_eq!(wizard.step, WizardStep::Index);
+        assert_eq!(wizard.index_name.value(), "web");
+
+        app.handle_popup_input(key(KeyCode::Esc));
+        assert!(app.popup.is_none());
+        assert!(app.onboarding_wizard.is_none());
+    }
+
+    #[test]
+    fn test_wizard_copies_curl_example_when_finished() {
+        let mut app = app_with_wizard();
+        let wizard = app.onboarding_wizard.as_mut().unwrap();
+        wizard.step = WizardStep::Finished;
+        wizard.result = Some(Ok(OnboardingOutcome {
+            index: "web".to_string(),
+            sourcetype: "web:json".to_string(),
+            format: EventFormat::Json,
+            token_name: "web_hec".to_string(),
+            token: Some(secrecy::SecretString::from("abc-123")),
+            hec_url: "https://localhost:8088".to_string(),
+        }));
+
+        let action =
+            app.handle_popup_input(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        let Some(Action::CopyToClipboard(text)) = action else {
+            panic!("expected CopyToClipboard, got {:?}", action);
+        };
+        assert!(text.contains("Authorization: Splunk abc-123"));
+    }
+}
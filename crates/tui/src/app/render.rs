@@ -14,22 +14,20 @@
 //! - Footer hint rendering must stay width-safe for all terminal sizes.
 
 use crate::app::App;
-use crate::app::footer_layout::FooterLayout;
 use crate::app::state::{
-    CurrentScreen, EscAction, FOOTER_HEIGHT, HEADER_HEIGHT, HealthState, NavigationContext,
-    NavigationMode, SearchInputMode, TabAction,
+    CurrentScreen, FOOTER_HEIGHT, HEADER_HEIGHT, HealthState, JobInspectTab, NavigationContext,
+    NavigationMode,
 };
-use crate::input::keymap::overrides;
 use crate::ui::popup::PopupType;
 use crate::ui::screens::{
-    apps, audit, cluster, configs, dashboards, datamodels, forwarders, health, indexes, inputs,
-    kvstore, license, lookups, macros, multi_instance, overview, roles, saved_searches, search,
-    search_peers, settings, shc, users, workload,
+    apps, audit, cluster, configs, dashboards, datamodels, forwarders, health, indexes, ingestion,
+    inputs, kvstore, license, lookups, macros, metadata, multi_instance, overview, rest, roles,
+    saved_searches, search, search_peers, settings, shc, sourcetypes, users, workload,
 };
 use crate::ui::theme::spinner_char;
 use ratatui::{
     Frame,
-    layout::{Alignment, Constraint, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
@@ -52,6 +50,7 @@ impl App {
     /// Render the application UI.
     pub fn render(&mut self, f: &mut Frame) {
         self.last_area = f.area();
+        self.hit_areas.clear();
 
         // Create layout
         let chunks = Layout::default()
@@ -120,36 +119,7 @@ impl App {
             ),
             Span::raw(" - "),
             Span::styled(
-                match self.current_screen {
-                    CurrentScreen::Search => "Search",
-                    CurrentScreen::Indexes => "Indexes",
-                    CurrentScreen::Cluster => "Cluster",
-                    CurrentScreen::Jobs => "Jobs",
-                    CurrentScreen::JobInspect => "Job Details",
-                    CurrentScreen::Health => "Health",
-                    CurrentScreen::License => "License",
-                    CurrentScreen::Kvstore => "KVStore",
-                    CurrentScreen::SavedSearches => "Saved Searches",
-                    CurrentScreen::Macros => "Macros",
-                    CurrentScreen::InternalLogs => "Internal Logs",
-                    CurrentScreen::Apps => "Apps",
-                    CurrentScreen::Users => "Users",
-                    CurrentScreen::Roles => "Roles",
-                    CurrentScreen::SearchPeers => "Search Peers",
-                    CurrentScreen::Inputs => "Data Inputs",
-                    CurrentScreen::Configs => "Config Files",
-                    CurrentScreen::FiredAlerts => "Fired Alerts",
-                    CurrentScreen::Forwarders => "Forwarders",
-                    CurrentScreen::Lookups => "Lookups",
-                    CurrentScreen::Audit => "Audit Events",
-                    CurrentScreen::Dashboards => "Dashboards",
-                    CurrentScreen::DataModels => "Data Models",
-                    CurrentScreen::WorkloadManagement => "Workload Management",
-                    CurrentScreen::Shc => "SHC",
-                    CurrentScreen::Settings => "Settings",
-                    CurrentScreen::Overview => "Overview",
-                    CurrentScreen::MultiInstance => "Multi-Instance",
-                },
+                self.current_screen.title(),
                 Style::default().fg(theme.accent),
             ),
         ];
@@ -173,6 +143,30 @@ impl App {
         header_spans.push(Span::raw(" "));
         header_spans.push(Span::styled(health_label, health_label_style));
 
+        if !self.server_messages.messages.is_empty() {
+            header_spans.push(Span::raw(" | "));
+            header_spans.push(crate::ui::server_messages::header_badge(
+                &self.server_messages,
+                &theme,
+            ));
+        }
+
+        if self.server_restarting {
+            header_spans.push(Span::raw(" | "));
+            header_spans.push(Span::styled(
+                "RESTARTING",
+                Style::default().fg(theme.warning),
+            ));
+        } else if self.restart_required() {
+            header_spans.push(Span::raw(" | "));
+            header_spans.push(Span::styled(
+                "RESTART REQUIRED",
+                Style::default()
+                    .fg(theme.warning)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+
         if !open_circuits.is_empty() {
             header_spans.push(Span::raw(" | "));
             header_spans.push(Span::styled(
@@ -191,8 +185,38 @@ impl App {
             );
         f.render_widget(header, chunks[0]);
 
-        // Main content
-        self.render_content(f, chunks[1]);
+        // Clickable screen tabs on the header's bottom border
+        if chunks[0].height >= 2 {
+            let tab_bar_area = Rect::new(
+                chunks[0].x + 2,
+                chunks[0].bottom() - 1,
+                chunks[0].width.saturating_sub(4),
+                1,
+            );
+            self.hit_areas.tabs =
+                crate::ui::tab_bar::render_tab_bar(f, tab_bar_area, self.current_screen, &theme);
+        }
+
+        // Main content, below an OFFLINE banner while showing snapshot data
+        let content_area = if let Some(banner) = self.offline_banner() {
+            let rows = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(1), Constraint::Min(0)])
+                .split(chunks[1]);
+            f.render_widget(
+                Paragraph::new(banner).alignment(Alignment::Center).style(
+                    Style::default()
+                        .fg(theme.warning)
+                        .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+                ),
+                rows[0],
+            );
+            rows[1]
+        } else {
+            chunks[1]
+        };
+        self.hit_areas.content = content_area;
+        self.render_content(f, content_area);
 
         // Footer with status and per-screen hints
         let footer_text = self.build_footer_text(theme);
@@ -222,7 +246,11 @@ impl App {
 
         // Render popup if active (on top of toasts)
         if let Some(ref popup) = self.popup {
-            crate::ui::popup::render_popup(f, popup, &self.theme, self);
+            let popup_area = crate::ui::popup::render_popup(f, popup, &self.theme, self);
+            self.hit_areas.popup = Some(popup_area);
+            if popup.kind.is_confirmation() {
+                self.hit_areas.popup_buttons = crate::ui::popup::confirmation_buttons(popup_area);
+            }
         }
 
         // Render error details popup if active
@@ -243,11 +271,155 @@ impl App {
         {
             crate::ui::index_details::render_index_details(f, self, &self.theme);
         }
+
+        // Render dashboard source popup if active
+        if let Some(crate::ui::popup::Popup {
+            kind: PopupType::DashboardSource,
+            ..
+        }) = &self.popup
+        {
+            crate::ui::dashboard_source::render_dashboard_source(f, self, &self.theme);
+        }
+
+        // Render macro expansion preview popup if active
+        if let Some(crate::ui::popup::Popup {
+            kind: PopupType::MacroExpansion,
+            ..
+        }) = &self.popup
+        {
+            crate::ui::macro_expansion::render_macro_expansion(f, self, &self.theme);
+        }
+
+        // Render search result detail popup if active
+        if let Some(crate::ui::popup::Popup {
+            kind: PopupType::ResultDetail,
+            ..
+        }) = &self.popup
+        {
+            crate::ui::result_detail::render_result_detail(f, self, &self.theme);
+        }
+
+        // Render tstats query builder popup if active
+        if let Some(crate::ui::popup::Popup {
+            kind: PopupType::TstatsBuilder,
+            ..
+        }) = &self.popup
+        {
+            crate::ui::tstats_builder::render_tstats_builder(f, self, &self.theme);
+        }
+
+        // Render index event sample viewer if active
+        if let Some(crate::ui::popup::Popup {
+            kind: PopupType::EventSample,
+            ..
+        }) = &self.popup
+        {
+            crate::ui::event_sample::render_event_sample(f, self, &self.theme);
+        }
+
+        // Render time range picker if active
+        if let Some(crate::ui::popup::Popup {
+            kind: PopupType::TimeRangePicker,
+            ..
+        }) = &self.popup
+        {
+            crate::ui::time_range::render_time_range_picker(f, self, &self.theme);
+        }
+
+        // Render field extraction tester if active
+        if let Some(crate::ui::popup::Popup {
+            kind: PopupType::ExtractionTester,
+            ..
+        }) = &self.popup
+        {
+            crate::ui::extraction_tester::render_extraction_tester(f, self, &self.theme);
+        }
+
+        // Render search template parameter form if active
+        if let Some(crate::ui::popup::Popup {
+            kind: PopupType::TemplateParameters,
+            ..
+        }) = &self.popup
+        {
+            crate::ui::template_form::render_template_form(f, self, &self.theme);
+        }
+
+        // Render data onboarding wizard if active
+        if let Some(crate::ui::popup::Popup {
+            kind: PopupType::OnboardingWizard,
+            ..
+        }) = &self.popup
+        {
+            crate::ui::wizard::render_onboarding_wizard(f, self, &self.theme);
+        }
+
+        // Render top-values popup if active
+        if let Some(crate::ui::popup::Popup {
+            kind: PopupType::TopValues,
+            ..
+        }) = &self.popup
+        {
+            crate::ui::top_values::render_top_values(f, self, &self.theme);
+        }
+
+        // Render lookup editor popup if active
+        if let Some(crate::ui::popup::Popup {
+            kind: PopupType::LookupEditor,
+            ..
+        }) = &self.popup
+        {
+            crate::ui::lookup_editor::render_lookup_editor(f, self, &self.theme);
+        }
+
+        // Render server messages popup if active
+        if let Some(crate::ui::popup::Popup {
+            kind: PopupType::ServerMessages,
+            ..
+        }) = &self.popup
+        {
+            crate::ui::server_messages::render_server_messages(f, self, &self.theme);
+        }
+
+        // Render background jobs tray if active
+        if let Some(crate::ui::popup::Popup {
+            kind: PopupType::BackgroundJobs,
+            ..
+        }) = &self.popup
+        {
+            crate::ui::background_jobs::render_background_jobs(f, self, &self.theme);
+        }
+
+        // Render app details popup if active
+        if let Some(crate::ui::popup::Popup {
+            kind: PopupType::AppDetails,
+            ..
+        }) = &self.popup
+        {
+            crate::ui::app_details::render_app_details(f, self, &self.theme);
+        }
+
+        // Render orphaned objects popup if active
+        if let Some(crate::ui::popup::Popup {
+            kind: PopupType::OrphanedObjects { .. },
+            ..
+        }) = &self.popup
+        {
+            crate::ui::orphaned_objects::render_orphaned_objects(f, self, &self.theme);
+        }
+
+        if self.theme.no_color {
+            crate::ui::no_color::strip_colors(f.buffer_mut(), &self.theme);
+        }
     }
 
     fn render_content(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
         match self.current_screen {
             CurrentScreen::Search => {
+                let time_range_label = crate::app::time_range::TimeRange::new(
+                    self.search_defaults.earliest_time.clone(),
+                    self.search_defaults.latest_time.clone(),
+                )
+                .label();
                 search::render_search(
                     f,
                     area,
@@ -264,10 +436,21 @@ impl App {
                         search_scroll_offset: self.search_scroll_offset,
                         search_results_total_count: self.search_results_total_count,
                         search_has_more_results: self.search_has_more_results,
+                        result_stats: &self.search_result_stats,
                         theme: &self.theme,
                         spl_validation_state: &self.spl_validation_state,
                         spl_validation_pending: self.spl_validation_pending,
                         search_mode: self.search_mode,
+                        auto_finalize: self.auto_finalize,
+                        time_range_label: &time_range_label,
+                        result_filter: self.result_filter.as_ref(),
+                        result_filter_input: &self.result_filter_input,
+                        is_result_filtering: self.is_result_filtering,
+                        chart_view: self.search_chart_view,
+                        chart: self.search_chart.as_ref(),
+                        compare_view: self.search_compare_view,
+                        compare: self.search_compare.as_ref(),
+                        expiring_ttl: self.search_job_expiring_ttl(),
                     },
                 );
             }
@@ -292,6 +475,9 @@ impl App {
                         loading: self.loading,
                         cluster_info: self.cluster_info.as_ref(),
                         cluster_peers: self.cluster_peers.as_deref(),
+                        bucket_health: self.cluster_bucket_health.as_ref(),
+                        index_compliance: self.cluster_index_compliance.as_deref(),
+                        versions: self.cluster_versions.as_ref(),
                         view_mode: self.cluster_view_mode,
                         peers_state: &mut self.cluster_peers_state,
                         theme: &self.theme,
@@ -308,6 +494,9 @@ impl App {
                     health::HealthRenderConfig {
                         loading: self.loading,
                         health_info: self.health_info.as_ref(),
+                        view_mode: self.health_view_mode,
+                        resource_usage: self.resource_usage.as_ref(),
+                        thresholds: self.effective_health_thresholds(),
                         theme: &self.theme,
                         spinner_frame: self.spinner_frame,
                     },
@@ -345,6 +534,7 @@ impl App {
                         loading: self.loading,
                         saved_searches: self.saved_searches.as_deref(),
                         state: &mut self.saved_searches_state,
+                        selected: &self.selected_saved_searches,
                         theme: &self.theme,
                         spinner_frame: self.spinner_frame,
                     },
@@ -395,6 +585,10 @@ impl App {
                     roles::RolesRenderConfig {
                         loading: self.loading,
                         roles: self.roles.as_deref(),
+                        role_usage: self.role_usage.as_deref(),
+                        role_matrix: self.role_matrix.as_ref(),
+                        matrix_state: &mut self.role_matrix_state,
+                        view_mode: self.roles_view_mode,
                         state: &mut self.roles_state,
                         theme: &self.theme,
                         spinner_frame: self.spinner_frame,
@@ -415,22 +609,30 @@ impl App {
                 );
             }
             CurrentScreen::Settings => {
+                let theme_name = self.theme_name();
                 settings::render_settings(
                     f,
                     area,
                     settings::SettingsRenderConfig {
                         auto_refresh: self.auto_refresh,
+                        result_renderers: self.result_renderers,
                         sort_column: self.sort_state.column.as_str(),
                         sort_direction: self.sort_state.direction.as_str(),
                         search_history_count: self.search_history.len(),
                         profile_info: self.profile_name.as_deref(),
-                        selected_theme: self.color_theme,
+                        theme_name: &theme_name,
+                        clipboard_backend: self.clipboard_backend,
+                        notifications: &self.notifications,
+                        color_support: self.color_support,
                         theme: &self.theme,
                         earliest_time: &self.search_defaults.earliest_time,
                         latest_time: &self.search_defaults.latest_time,
                         max_results: self.search_defaults.max_results,
                         internal_logs_count: self.internal_logs_defaults.count,
                         internal_logs_earliest: &self.internal_logs_defaults.earliest_time,
+                        log_anomalies: &self.internal_logs_defaults.anomalies,
+                        health_thresholds: self.effective_health_thresholds(),
+                        health_thresholds_from_profile: self.profile_health_thresholds.is_some(),
                     },
                 );
             }
@@ -466,7 +668,69 @@ impl App {
                     inputs::InputsRenderConfig {
                         loading: self.loading,
                         inputs: self.inputs.as_deref(),
+                        throughput: self.input_throughput.as_ref(),
                         state: &mut self.inputs_state,
+                        view_mode: self.inputs_view_mode,
+                        hec_tokens: self.hec_tokens.as_deref(),
+                        hec_tokens_state: &mut self.hec_tokens_state,
+                        revealed_hec_token: self.revealed_hec_token.as_deref(),
+                        theme: &self.theme,
+                        spinner_frame: self.spinner_frame,
+                    },
+                );
+            }
+            CurrentScreen::Ingestion => {
+                ingestion::render_ingestion(
+                    f,
+                    area,
+                    ingestion::IngestionRenderConfig {
+                        loading: self.loading,
+                        ingestion: self.ingestion_stats.as_ref(),
+                        sort: self.ingestion_sort,
+                        state: &mut self.ingestion_state,
+                        theme: &self.theme,
+                        spinner_frame: self.spinner_frame,
+                    },
+                );
+            }
+            CurrentScreen::Sourcetypes => {
+                sourcetypes::render_sourcetypes(
+                    f,
+                    area,
+                    sourcetypes::SourcetypesRenderConfig {
+                        loading: self.loading,
+                        sourcetypes: self.sourcetypes.as_deref(),
+                        state: &mut self.sourcetypes_state,
+                        theme: &self.theme,
+                        spinner_frame: self.spinner_frame,
+                    },
+                );
+            }
+            CurrentScreen::Metadata => {
+                metadata::render_metadata(
+                    f,
+                    area,
+                    metadata::MetadataRenderConfig {
+                        loading: self.loading,
+                        metadata_type: self.metadata_type,
+                        entries: self.metadata_entries.as_deref(),
+                        now: chrono::Utc::now().timestamp().max(0) as u64,
+                        state: &mut self.metadata_state,
+                        theme: &self.theme,
+                        spinner_frame: self.spinner_frame,
+                    },
+                );
+            }
+            CurrentScreen::Rest => {
+                rest::render_rest(
+                    f,
+                    area,
+                    rest::RestRenderConfig {
+                        loading: self.loading,
+                        history: &self.rest_history,
+                        state: &mut self.rest_history_state,
+                        response: self.rest_response.as_ref(),
+                        scroll: self.rest_response_scroll,
                         theme: &self.theme,
                         spinner_frame: self.spinner_frame,
                     },
@@ -483,6 +747,9 @@ impl App {
                         stanzas: self.config_stanzas.as_deref(),
                         selected_stanza: self.selected_stanza.as_ref(),
                         view_mode: self.config_view_mode,
+                        layers: self.config_layers.as_deref(),
+                        detail_view: self.config_detail_view,
+                        diff_app: self.config_diff_app.as_deref(),
                         files_state: &mut self.config_files_state,
                         stanzas_state: &mut self.config_stanzas_state,
                         theme: &self.theme,
@@ -662,6 +929,7 @@ impl App {
                 jobs: &filtered_jobs,
                 state: &mut self.jobs_state,
                 auto_refresh: self.auto_refresh,
+                server_filter: &self.jobs_server_filter,
                 filter: &self.search_filter,
                 filter_input: &self.filter_input,
                 is_filtering: self.is_filtering,
@@ -680,9 +948,23 @@ impl App {
         let job = self.get_selected_job();
 
         match job {
-            Some(job) => {
-                job_details::render_details(f, area, job, &self.theme);
-            }
+            Some(job) => match self.job_inspect_tab {
+                JobInspectTab::Details => job_details::render_details(f, area, job, &self.theme),
+                JobInspectTab::Performance => {
+                    let performance = self
+                        .job_performance
+                        .as_ref()
+                        .filter(|status| status.sid == job.sid);
+                    job_details::render_performance(
+                        f,
+                        area,
+                        job,
+                        performance,
+                        self.loading,
+                        &self.theme,
+                    );
+                }
+            },
             None => {
                 let placeholder = Paragraph::new("No job selected or jobs not loaded.")
                     .block(Block::default().borders(Borders::ALL).title("Job Details"))
@@ -701,8 +983,16 @@ impl App {
             internal_logs::InternalLogsRenderConfig {
                 loading: self.loading,
                 logs: self.internal_logs.as_deref(),
+                rows: self
+                    .internal_logs
+                    .as_ref()
+                    .and_then(|logs| self.internal_log_anomalies.rows_for(logs.len())),
+                anomaly_summary: self.internal_log_anomalies.summary(),
                 state: &mut self.internal_logs_state,
                 auto_refresh: self.auto_refresh,
+                follow_badge: self.internal_logs_follow.badge(),
+                filter: self.internal_log_anomalies.filter(),
+                component_input: self.internal_logs_component_input.as_ref(),
                 theme: &self.theme,
                 spinner_frame: self.spinner_frame,
             },
@@ -714,6 +1004,7 @@ impl App {
     /// Returns a vector of spans representing:
     /// - profile@base_url (or just base_url if no profile)
     /// - auth mode (token or session)
+    /// - session time left (session auth, once the keep-alive has renewed it)
     /// - server version (if available)
     ///
     /// Long URLs are truncated to fit the terminal width.
@@ -739,6 +1030,30 @@ impl App {
             ));
         }
 
+        // Add session lifetime for session-token auth
+        if self.session_expired {
+            spans.push(Span::raw(" | "));
+            spans.push(Span::styled(
+                "session expired",
+                Style::default().fg(theme.error),
+            ));
+        } else if let Some(remaining) = self.session_remaining() {
+            spans.push(Span::raw(" | "));
+            spans.push(Span::styled(
+                format!("session {}", Self::format_session_remaining(remaining)),
+                Style::default().fg(theme.accent),
+            ));
+        }
+
+        // Add namespace if one is set
+        if let Some(ref namespace) = self.namespace {
+            spans.push(Span::raw(" | "));
+            spans.push(Span::styled(
+                format!("ns {}", namespace),
+                Style::default().fg(theme.warning),
+            ));
+        }
+
         // Add server version if available
         if let Some(ref version) = self.server_version {
             spans.push(Span::raw(" | "));
@@ -751,6 +1066,16 @@ impl App {
         spans
     }
 
+    /// Format session time left as whole minutes, or seconds under a minute.
+    fn format_session_remaining(remaining: std::time::Duration) -> String {
+        let secs = remaining.as_secs();
+        if secs >= 60 {
+            format!("{}m", secs / 60)
+        } else {
+            format!("{}s", secs)
+        }
+    }
+
     /// Truncate URL for display, keeping the most significant parts.
     ///
     /// For long URLs, shows the end (domain:port) with ellipsis prefix.
@@ -765,206 +1090,7 @@ impl App {
     }
 
     /// Get the current spinner character based on frame counter.
-    fn spinner_char(&self) -> char {
+    pub(super) fn spinner_char(&self) -> char {
         spinner_char(self.spinner_frame)
     }
-
-    /// Build footer text with navigation hints, mode indicator, and controls.
-    ///
-    /// Layout: [MODE] | [Loading] | Tab:Action | Esc:Action | [Screen Hints] | ?:Help | q:Quit
-    ///
-    /// Mode indicator appears on screens with special navigation (Search).
-    /// Handles narrow terminals by responsive truncation.
-    fn build_footer_text(&self, theme: crate::theme::Theme) -> Vec<Line<'_>> {
-        let hints = crate::input::keymap::footer_hints(self.current_screen);
-        let available_width = self.last_area.width as usize;
-        let nav_ctx = self.navigation_context();
-
-        // Build mode indicator for screens with multiple focus targets
-        let mode_text = if nav_ctx.has_multi_focus {
-            format!("[{}] ", nav_ctx.mode_label())
-        } else {
-            String::new()
-        };
-        let mode_width = mode_text.len();
-
-        // Build navigation hint text
-        let (nav_text, nav_width) = self.format_navigation_hint(&nav_ctx);
-
-        // Build Esc hint (separator added conditionally during render)
-        let esc_text = match nav_ctx.esc_action {
-            EscAction::FocusResults => "Esc:Results ".to_string(),
-            EscAction::FocusQuery => "Esc:Query ".to_string(),
-            EscAction::BackToPreviousScreen => "Esc:Back ".to_string(),
-            EscAction::ClosePopup => "Esc:Close ".to_string(),
-            EscAction::None => String::new(),
-        };
-        let esc_sep_width = if !nav_text.is_empty() && !esc_text.is_empty() {
-            " | ".len()
-        } else {
-            0
-        };
-        let esc_width = esc_text.len() + esc_sep_width;
-
-        // Build focus navigation hint (for multi-focus screens)
-        let (focus_text, focus_width) = self.format_focus_hint(&nav_ctx);
-
-        // Build loading indicator
-        let loading_text = if self.loading {
-            let spinner = self.spinner_char();
-            format!(" {} Loading... {:.0}% |", spinner, self.progress * 100.0)
-        } else {
-            String::new()
-        };
-        let loading_width = loading_text.len();
-
-        let (utility_spans, utility_width) = self.format_utility_hints(&nav_ctx);
-
-        let fixed_width =
-            mode_width + loading_width + nav_width + esc_width + focus_width + utility_width;
-        let hints_available_width = available_width.saturating_sub(fixed_width);
-
-        // Build screen hints string (truncated for narrow terminals)
-        let hints_text = FooterLayout::render_hints_text(&hints, hints_available_width);
-
-        // Build the footer line
-        let mut spans = Vec::new();
-
-        // Mode indicator for multi-focus screens
-        if nav_ctx.has_multi_focus {
-            let mode_style = match nav_ctx.mode {
-                NavigationMode::LocalFocus => Style::default()
-                    .fg(theme.warning)
-                    .add_modifier(Modifier::BOLD),
-                NavigationMode::ScreenCycle => Style::default().fg(theme.accent),
-            };
-            spans.push(Span::styled(mode_text, mode_style));
-        }
-
-        // Loading indicator
-        if self.loading {
-            spans.push(Span::styled(
-                loading_text,
-                Style::default().fg(theme.warning),
-            ));
-        }
-
-        // Navigation hints (context-aware)
-        let has_nav_text = !nav_text.is_empty();
-        let has_focus_text = !focus_text.is_empty();
-        spans.push(Span::raw(nav_text));
-
-        // Focus navigation hint (multi-focus screens only)
-        if has_focus_text {
-            if has_nav_text {
-                spans.push(Span::raw("|"));
-            }
-            spans.push(Span::raw(focus_text));
-        }
-
-        // Esc hint (add separator only if nav_text precedes it)
-        if !esc_text.is_empty() {
-            if has_nav_text || has_focus_text {
-                spans.push(Span::raw(" | "));
-            }
-            spans.push(Span::styled(esc_text, Style::default().fg(theme.text)));
-        }
-
-        // Screen-specific hints
-        if !hints_text.is_empty() {
-            spans.push(Span::raw("|"));
-            spans.push(Span::styled(hints_text, Style::default().fg(theme.accent)));
-        }
-
-        // Help and quit affordances (context-aware so the footer never advertises dead keys).
-        spans.extend(utility_spans);
-
-        vec![Line::from(spans)]
-    }
-
-    /// Format navigation hint based on current navigation context (RQ-0457).
-    ///
-    /// Returns (navigation_text, navigation_width) for footer layout calculation.
-    fn format_navigation_hint(&self, nav_ctx: &NavigationContext) -> (String, usize) {
-        use crate::action::Action;
-
-        let next_key = overrides::get_effective_key_display(Action::NextScreen, "Tab");
-        let prev_key = overrides::get_effective_key_display(Action::PreviousScreen, "Shift+Tab");
-
-        let text = match nav_ctx.tab_action {
-            TabAction::NextScreen => format!(" {}:Next | {}:Prev ", next_key, prev_key),
-            TabAction::PreviousScreen => format!(" {}:Prev ", prev_key),
-            TabAction::None => String::new(),
-            TabAction::ToggleFocus => format!(" {}:Next | {}:Prev ", next_key, prev_key),
-        };
-
-        (text.clone(), text.len())
-    }
-
-    /// Format focus navigation hint for screens with multiple focus targets.
-    ///
-    /// Returns (focus_hint_text, focus_hint_width) for footer layout.
-    fn format_focus_hint(&self, nav_ctx: &NavigationContext) -> (String, usize) {
-        if !nav_ctx.has_multi_focus {
-            return (String::new(), 0);
-        }
-
-        use crate::action::Action;
-        let next_focus_key = overrides::get_effective_key_display(Action::NextFocus, "Ctrl+Tab");
-        let prev_focus_key =
-            overrides::get_effective_key_display(Action::PreviousFocus, "Ctrl+Shift+Tab");
-
-        let text = if self.current_screen == CurrentScreen::Search {
-            match self.search_input_mode {
-                SearchInputMode::QueryFocused => format!(" {}:Results ", next_focus_key),
-                SearchInputMode::ResultsFocused => format!(" {}:Query ", prev_focus_key),
-            }
-        } else {
-            format!(" {}:Focus | {}:Focus ", next_focus_key, prev_focus_key)
-        };
-        (text.clone(), text.len())
-    }
-
-    /// Format help/quit affordances for the current interaction mode.
-    fn format_utility_hints(&self, _nav_ctx: &NavigationContext) -> (Vec<Span<'static>>, usize) {
-        use crate::action::Action;
-
-        let help_key = overrides::get_effective_key_display(Action::OpenHelpPopup, "?");
-        let quit_key = overrides::get_effective_key_display(Action::Quit, "q");
-        let global_quit_key = "Ctrl+Q".to_string();
-
-        if self.current_screen == CurrentScreen::Search
-            && matches!(self.search_input_mode, SearchInputMode::QueryFocused)
-            && self.popup.is_none()
-        {
-            let width = format!("| {}:Quit ", global_quit_key).len();
-            return (
-                vec![
-                    Span::raw("|"),
-                    Span::styled(
-                        format!(" {}:Quit ", global_quit_key),
-                        Style::default().fg(self.theme.error),
-                    ),
-                ],
-                width,
-            );
-        }
-
-        let width = format!("| {}:Help | {}:Quit ", help_key, quit_key).len();
-        (
-            vec![
-                Span::raw("|"),
-                Span::styled(
-                    format!(" {}:Help ", help_key),
-                    Style::default().fg(self.theme.success),
-                ),
-                Span::raw("|"),
-                Span::styled(
-                    format!(" {}:Quit ", quit_key),
-                    Style::default().fg(self.theme.error),
-                ),
-            ],
-            width,
-        )
-    }
 }
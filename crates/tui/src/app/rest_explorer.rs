@@ -0,0 +1,261 @@
+//! REST explorer for endpoints without a dedicated screen.
+//!
+//! Responsibilities:
+//! - Parse `METHOD PATH [FORM]` request lines typed in the request popup
+//! - Keep the request history (most recent first, persisted across runs)
+//! - Hold the pretty-printed response of the last request and its scroll offset
+//!
+//! Does NOT handle:
+//! - Does NOT send requests (handled by Action::SendRestRequest)
+//! - Does NOT handle popup keys (handled by app::popups::rest_request)
+//! - Does NOT render the screen (handled by ui::screens::rest)
+//!
+//! Invariants:
+//! - History lines are stored in canonical form, so re-sending one is a no-op reorder
+
+use std::sync::Arc;
+
+use splunk_client::endpoints::{parse_params, split_path_query};
+use splunk_client::raw_format::{RawFormat, pretty_response};
+use splunk_client::{ClientError, RestMethod};
+use splunk_config::constants::DEFAULT_HISTORY_MAX_ITEMS;
+
+use crate::action::Action;
+use crate::app::App;
+use crate::ui::Toast;
+
+/// A raw request parsed from a `METHOD PATH [FORM]` line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestRequest {
+    pub method: RestMethod,
+    /// Endpoint path without its query string.
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    /// POST form fields.
+    pub form: Vec<(String, String)>,
+}
+
+impl RestRequest {
+    /// Parse `GET /services/server/info` or `POST /services/x name=a&search=index=main | head`.
+    ///
+    /// A line starting with the path is a GET. The form body is the rest of the
+    /// line after the path, as `key=value` pairs joined by `&` (like `curl -d`).
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let line = line.trim();
+        if line.is_empty() {
+            return Err("Enter a request like GET /services/server/info".to_string());
+        }
+
+        let (method, rest) = if line.starts_with('/') {
+            (RestMethod::Get, line)
+        } else {
+            let (method, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            (method.parse::<RestMethod>()?, rest.trim_start())
+        };
+
+        let (target, body) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+        if !target.starts_with('/') {
+            return Err("Path must start with / (e.g. /services/server/info)".to_string());
+        }
+
+        let form = parse_params(body.trim());
+        if method == RestMethod::Get && !form.is_empty() {
+            return Err("Form data is only sent with POST requests".to_string());
+        }
+
+        let (path, query) = split_path_query(target);
+        Ok(Self {
+            method,
+            path,
+            query,
+            form,
+        })
+    }
+
+    /// The canonical request line, as stored in the history.
+    pub fn line(&self) -> String {
+        let mut line = format!("{} {}", self.method, self.path);
+        if !self.query.is_empty() {
+            line.push('?');
+            line.push_str(&join_params(&self.query));
+        }
+        if !self.form.is_empty() {
+            line.push(' ');
+            line.push_str(&join_params(&self.form));
+        }
+        line
+    }
+}
+
+fn join_params(params: &[(String, String)]) -> String {
+    params
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Outcome of the last REST explorer request.
+#[derive(Debug, Clone)]
+pub struct RestResponse {
+    /// The request line that produced this response.
+    pub request: String,
+    /// Detected body format, when it was pretty-printed.
+    pub format: Option<RawFormat>,
+    /// Pretty-printed body, or the error message.
+    pub body: String,
+    pub is_error: bool,
+}
+
+impl App {
+    /// Parse a request line, record it in the history, and build the send action.
+    ///
+    /// Invalid lines produce an error toast and no action.
+    pub(crate) fn send_rest_request(&mut self, line: &str) -> Option<Action> {
+        let request = match RestRequest::parse(line) {
+            Ok(request) => request,
+            Err(e) => {
+                self.toasts.push(Toast::error(e));
+                return None;
+            }
+        };
+        self.record_rest_request(request.line());
+        Some(Action::SendRestRequest(request))
+    }
+
+    /// Move `line` to the front of the REST history and select it.
+    fn record_rest_request(&mut self, line: String) {
+        self.rest_history.retain(|entry| entry != &line);
+        self.rest_history.insert(0, line);
+        self.rest_history.truncate(DEFAULT_HISTORY_MAX_ITEMS);
+        self.rest_history_state.select(Some(0));
+    }
+
+    /// The request line selected in the history list.
+    pub(crate) fn selected_rest_request(&self) -> Option<&String> {
+        self.rest_history_state
+            .selected()
+            .and_then(|i| self.rest_history.get(i))
+    }
+
+    /// Show a REST explorer response, pretty-printed, scrolled to the top.
+    pub(crate) fn handle_rest_response(
+        &mut self,
+        request: String,
+        result: Result<serde_json::Value, Arc<ClientError>>,
+    ) {
+        self.loading = false;
+        self.rest_response_scroll = 0;
+        self.rest_response = Some(match result {
+            Ok(body) => {
+                let (format, body) = pretty_response(&body);
+                RestResponse {
+                    request,
+                    format,
+                    body,
+                    is_error: false,
+                }
+            }
+            Err(e) => RestResponse {
+                request,
+                format: None,
+                body: e.to_string(),
+                is_error: true,
+            },
+        });
+    }
+
+    /// Scroll the response body by `delta` lines, stopping at the last line.
+    pub(crate) fn scroll_rest_response(&mut self, delta: isize) {
+        let lines = self
+            .rest_response
+            .as_ref()
+            .map_or(0, |response| response.body.lines().count());
+        self.rest_response_scroll = self
+            .rest_response_scroll
+            .saturating_add_signed(delta)
+            .min(lines.saturating_sub(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+
+    #[test]
+    fn test_parse_request_line() {
+        let request = RestRequest::parse("/services/server/info").unwrap();
+        assert_eq!(request.method, RestMethod::Get);
+        assert_eq!(request.path, "/services/server/info");
+
+        let request = RestRequest::parse(
+            "post /services/saved/searches?output_mode=xml name=t&search=index=main | head",
+        )
+        .unwrap();
+        assert_eq!(request.method, RestMethod::Post);
+        assert_eq!(request.path, "/services/saved/searches");
+        assert_eq!(
+            request.query,
+            vec![("output_mode".to_string(), "xml".to_string())]
+        );
+        assert_eq!(
+            request.form,
+            vec![
+                ("name".to_string(), "t".to_string()),
+                ("search".to_string(), "index=main | head".to_string()),
+            ]
+        );
+        assert_eq!(
+            request.line(),
+            "POST /services/saved/searches?output_mode=xml name=t&search=index=main | head"
+        );
+    }
+
+    #[test]
+    fn test_parse_request_line_errors() {
+        assert!(RestRequest::parse("  ").is_err());
+        assert!(RestRequest::parse("DELETE /services/x").is_err());
+        assert!(RestRequest::parse("GET services/x").is_err());
+        assert!(RestRequest::parse("GET /services/x a=b").is_err());
+    }
+
+    #[test]
+    fn test_send_rest_request_records_history() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.send_rest_request("GET /services/server/info");
+        app.send_rest_request("GET /services/apps/local");
+        let action = app.send_rest_request("get /services/server/info");
+
+        assert!(matches!(action, Some(Action::SendRestRequest(_))));
+        assert_eq!(
+            app.rest_history,
+            vec!["GET /services/server/info", "GET /services/apps/local"]
+        );
+        assert_eq!(
+            app.selected_rest_request().unwrap(),
+            "GET /services/server/info"
+        );
+
+        assert!(app.send_rest_request("GET nope").is_none());
+        assert_eq!(app.rest_history.len(), 2);
+    }
+
+    #[test]
+    fn test_handle_rest_response_pretty_prints() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.handle_rest_response(
+            "GET /services/server/info".to_string(),
+            Ok(serde_json::json!({"entry": [{"name": "server-info"}]})),
+        );
+        let response = app.rest_response.as_ref().unwrap();
+        assert_eq!(response.format, Some(RawFormat::Json));
+        assert!(response.body.contains("\n  \"entry\": ["));
+        let lines = response.body.lines().count();
+
+        app.scroll_rest_response(100);
+        assert_eq!(app.rest_response_scroll, lines - 1);
+        app.scroll_rest_response(-100);
+        assert_eq!(app.rest_response_scroll, 0);
+    }
+}
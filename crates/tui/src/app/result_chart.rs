@@ -0,0 +1,313 @@
+//! Time-series chart data extracted from loaded search results.
+//!
+//! Responsibilities:
+//! - Detect whether results are chartable (`_time` buckets plus numeric fields, e.g. `timechart`)
+//! - Build one series per numeric field with points keyed by epoch seconds
+//! - Compute axis bounds and labels for the Search screen chart view
+//!
+//! Does NOT handle:
+//! - Does NOT render the chart (handled by ui::screens::search)
+//! - Does NOT decide when the chart view is shown (held on `App`)
+//!
+//! Invariants:
+//! - A chart always has at least two timestamps and one non-empty series
+//! - Internal fields (leading `_`) are never charted
+//! - A field is only a series if every non-empty value parses as a number
+
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::{DateTime, FixedOffset};
+use serde_json::Value;
+
+use crate::app::App;
+
+/// Maximum number of series drawn; extra numeric fields are counted but omitted.
+pub const MAX_CHART_SERIES: usize = 8;
+
+/// One numeric field plotted over time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartSeries {
+    /// Field name, used as the legend label.
+    pub name: String,
+    /// `(epoch seconds, value)` points in result order.
+    pub points: Vec<(f64, f64)>,
+}
+
+/// Chartable view of search results with precomputed axis bounds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResultChart {
+    pub series: Vec<ChartSeries>,
+    /// Number of numeric fields beyond `MAX_CHART_SERIES` that are not drawn.
+    pub omitted_series: usize,
+    pub earliest: DateTime<FixedOffset>,
+    pub latest: DateTime<FixedOffset>,
+    /// Smallest and largest plotted value.
+    pub min: f64,
+    pub max: f64,
+}
+
+impl ResultChart {
+    /// Build a chart from results, or `None` when they are not chartable.
+    pub fn from_results(results: &[Value]) -> Option<Self> {
+        let mut times = Vec::new();
+        let mut fields: BTreeMap<&str, Vec<(f64, f64)>> = BTreeMap::new();
+        let mut non_numeric: HashSet<&str> = HashSet::new();
+
+        for object in results.iter().filter_map(Value::as_object) {
+            let Some(time) = object
+                .get("_time")
+                .and_then(Value::as_str)
+                .and_then(|time| DateTime::parse_from_rfc3339(time).ok())
+            else {
+                continue;
+            };
+            let x = time.timestamp() as f64;
+            times.push(time);
+
+            for (key, value) in object {
+                if key.starts_with('_') {
+                    continue;
+                }
+                match numeric_value(value) {
+                    Some(y) => fields.entry(key).or_default().push((x, y)),
+                    None if is_empty(value) => {}
+                    None => {
+                        non_numeric.insert(key);
+                    }
+                }
+            }
+        }
+
+        let earliest = *times.iter().min()?;
+        let latest = *times.iter().max()?;
+        if times.len() < 2 || earliest == latest {
+            return None;
+        }
+
+        let mut series: Vec<ChartSeries> = fields
+            .into_iter()
+            .filter(|(name, _)| !non_numeric.contains(name))
+            .map(|(name, points)| ChartSeries {
+                name: name.to_string(),
+                points,
+            })
+            .collect();
+        if series.is_empty() {
+            return None;
+        }
+        let omitted_series = series.len().saturating_sub(MAX_CHART_SERIES);
+        series.truncate(MAX_CHART_SERIES);
+
+        let values = series.iter().flat_map(|s| s.points.iter().map(|&(_, y)| y));
+        let (min, max) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), y| {
+            (lo.min(y), hi.max(y))
+        });
+
+        Some(Self {
+            series,
+            omitted_series,
+            earliest,
+            latest,
+            min,
+            max,
+        })
+    }
+
+    /// X-axis bounds in epoch seconds.
+    pub fn x_bounds(&self) -> [f64; 2] {
+        [
+            self.earliest.timestamp() as f64,
+            self.latest.timestamp() as f64,
+        ]
+    }
+
+    /// Y-axis bounds, anchored at zero for non-negative data and never zero-height.
+    pub fn y_bounds(&self) -> [f64; 2] {
+        let low = self.min.min(0.0);
+        let high = if self.max > low { self.max } else { low + 1.0 };
+        [low, high]
+    }
+
+    /// Start, middle and end labels for the time axis.
+    pub fn x_labels(&self) -> Vec<String> {
+        let format = if self.earliest.date_naive() == self.latest.date_naive() {
+            "%H:%M:%S"
+        } else {
+            "%Y-%m-%d %H:%M"
+        };
+        let middle = self.earliest + (self.latest - self.earliest) / 2;
+        [self.earliest, middle, self.latest]
+            .iter()
+            .map(|time| time.format(format).to_string())
+            .collect()
+    }
+
+    /// Low, middle and high labels for the value axis.
+    pub fn y_labels(&self) -> Vec<String> {
+        let [low, high] = self.y_bounds();
+        [low, (low + high) / 2.0, high]
+            .iter()
+            .map(|&value| format_axis_value(value))
+            .collect()
+    }
+}
+
+/// Parse a number from a JSON number or numeric string (Splunk returns strings).
+fn numeric_value(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(number) => number.as_f64(),
+        Value::String(text) => text.trim().parse::<f64>().ok().filter(|v| v.is_finite()),
+        _ => None,
+    }
+}
+
+/// Missing buckets (`null` or empty string) do not disqualify a numeric field.
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(text) => text.trim().is_empty(),
+        _ => false,
+    }
+}
+
+/// Compact axis label: `1.5k`, `2.3M`, or up to two decimals.
+fn format_axis_value(value: f64) -> String {
+    let magnitude = value.abs();
+    if magnitude >= 1_000_000_000.0 {
+        format!("{:.1}G", value / 1_000_000_000.0)
+    } else if magnitude >= 1_000_000.0 {
+        format!("{:.1}M", value / 1_000_000.0)
+    } else if magnitude >= 1_000.0 {
+        format!("{:.1}k", value / 1_000.0)
+    } else if value.fract() == 0.0 {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.2}", value)
+    }
+}
+
+impl App {
+    /// Toggle the chart view; stays on the results list if they are not chartable.
+    pub(crate) fn toggle_search_chart_view(&mut self) {
+        if self.search_chart_view {
+            self.search_chart_view = false;
+            self.search_chart = None;
+            return;
+        }
+        self.search_chart_view = true;
+        self.refresh_search_chart();
+        if self.search_chart.is_none() {
+            self.search_chart_view = false;
+            self.push_info_toast_once(
+                "Results are not chartable (need _time buckets and a numeric field)",
+            );
+        }
+    }
+
+    /// Rebuild the chart after results are replaced or appended while the chart view is on.
+    pub(crate) fn refresh_search_chart(&mut self) {
+        if self.search_chart_view {
+            self.search_chart = ResultChart::from_results(&self.search_results);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn timechart() -> Vec<Value> {
+        vec![
+            json!({"_time": "2024-01-15T10:00:00.000+00:00", "_span": "3600", "web": "10", "db": "4"}),
+            json!({"_time": "2024-01-15T11:00:00.000+00:00", "_span": "3600", "web": "25", "db": ""}),
+            json!({"_time": "2024-01-15T12:00:00.000+00:00", "_span": "3600", "web": "5", "db": "8"}),
+        ]
+    }
+
+    #[test]
+    fn test_timechart_results_build_series() {
+        let chart = ResultChart::from_results(&timechart()).expect("chartable");
+
+        let names: Vec<&str> = chart.series.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["db", "web"]);
+        assert_eq!(chart.series[0].points.len(), 2, "empty bucket is skipped");
+        assert_eq!(chart.series[1].points[1].1, 25.0);
+        assert_eq!(chart.y_bounds(), [0.0, 25.0]);
+        assert_eq!(chart.x_labels(), vec!["10:00:00", "11:00:00", "12:00:00"]);
+        assert_eq!(chart.y_labels(), vec!["0", "12.50", "25"]);
+    }
+
+    #[test]
+    fn test_non_chartable_results_return_none() {
+        // No _time
+        assert!(
+            ResultChart::from_results(&[json!({"count": "1"}), json!({"count": "2"})]).is_none()
+        );
+        // Single bucket
+        assert!(ResultChart::from_results(&timechart()[..1]).is_none());
+        // No numeric field
+        let events = vec![
+            json!({"_time": "2024-01-15T10:00:00Z", "host": "a"}),
+            json!({"_time": "2024-01-15T11:00:00Z", "host": "b"}),
+        ];
+        assert!(ResultChart::from_results(&events).is_none());
+    }
+
+    #[test]
+    fn test_mixed_field_is_not_a_series() {
+        let results = vec![
+            json!({"_time": "2024-01-15T10:00:00Z", "count": "3", "host": "1"}),
+            json!({"_time": "2024-01-15T11:00:00Z", "count": "4", "host": "web-01"}),
+        ];
+        let chart = ResultChart::from_results(&results).expect("chartable");
+        assert_eq!(chart.series.len(), 1);
+        assert_eq!(chart.series[0].name, "count");
+    }
+
+    #[test]
+    fn test_series_beyond_limit_are_omitted() {
+        let row = |time: &str| {
+            let mut object = serde_json::Map::new();
+            object.insert("_time".to_string(), json!(time));
+            for i in 0..MAX_CHART_SERIES + 2 {
+                object.insert(format!("s{:02}", i), json!(i));
+            }
+            Value::Object(object)
+        };
+        let results = vec![row("2024-01-15T10:00:00Z"), row("2024-01-16T10:00:00Z")];
+
+        let chart = ResultChart::from_results(&results).expect("chartable");
+        assert_eq!(chart.series.len(), MAX_CHART_SERIES);
+        assert_eq!(chart.omitted_series, 2);
+        assert_eq!(chart.x_labels()[0], "2024-01-15 10:00");
+    }
+
+    #[test]
+    fn test_toggle_chart_view_falls_back_when_not_chartable() {
+        let mut app = App::new(None, crate::app::ConnectionContext::default());
+        app.set_search_results(vec![json!({"_raw": "plain event"})]);
+        app.toggle_search_chart_view();
+        assert!(!app.search_chart_view);
+        assert_eq!(app.toasts.len(), 1);
+
+        app.set_search_results(timechart());
+        app.toggle_search_chart_view();
+        assert!(app.search_chart.is_some());
+
+        // A new, non-chartable search keeps the view on but renders the list
+        app.set_search_results(vec![json!({"_raw": "plain event"})]);
+        assert!(app.search_chart_view);
+        assert!(app.search_chart.is_none());
+
+        app.toggle_search_chart_view();
+        assert!(!app.search_chart_view);
+    }
+
+    #[test]
+    fn test_format_axis_value() {
+        assert_eq!(format_axis_value(1500.0), "1.5k");
+        assert_eq!(format_axis_value(2_300_000.0), "2.3M");
+        assert_eq!(format_axis_value(42.0), "42");
+    }
+}
@@ -0,0 +1,88 @@
+//! Compare view: diff the current search results against the previous run.
+//!
+//! Responsibilities:
+//! - Keep the previous run's results as the comparison baseline
+//! - Toggle the compare view and rebuild the diff as result pages arrive
+//!
+//! Does NOT handle:
+//! - Does NOT pair or classify rows (see `splunk_client::workflows::result_diff`)
+//! - Does NOT render the diff (handled by ui::screens::search)
+//!
+//! Invariants:
+//! - The baseline is replaced by the outgoing results whenever a new search completes,
+//!   so re-running a query (e.g. with a different time range) compares against the last run
+//! - An empty outgoing result set keeps the older baseline
+
+use splunk_client::workflows::result_diff::diff_results;
+
+use crate::app::App;
+
+impl App {
+    /// Keep the outgoing results as the baseline before a new search replaces them.
+    pub(crate) fn stash_search_baseline(&mut self) {
+        if !self.search_results.is_empty() {
+            self.search_baseline = std::mem::take(&mut self.search_results);
+        }
+    }
+
+    /// Toggle the compare view; stays on the results list if there is no previous run.
+    pub(crate) fn toggle_search_compare_view(&mut self) {
+        if self.search_compare_view {
+            self.search_compare_view = false;
+            self.search_compare = None;
+            return;
+        }
+        if self.search_baseline.is_empty() {
+            self.push_info_toast_once(
+                "Nothing to compare yet: run the search again (e.g. with another time range)",
+            );
+            return;
+        }
+        self.search_compare_view = true;
+        self.refresh_search_compare();
+    }
+
+    /// Rebuild the diff after results are replaced or appended while the compare view is on.
+    pub(crate) fn refresh_search_compare(&mut self) {
+        if self.search_compare_view {
+            self.search_compare = Some(diff_results(
+                &self.search_baseline,
+                &self.search_results,
+                &[],
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_compare_against_previous_run() {
+        let mut app = App::default();
+        app.toggle_search_compare_view();
+        assert!(
+            !app.search_compare_view,
+            "no baseline before the second run"
+        );
+
+        app.set_search_results(vec![json!({"host": "a", "count": "1"})]);
+        app.set_search_results(vec![
+            json!({"host": "a", "count": "2"}),
+            json!({"host": "b", "count": "1"}),
+        ]);
+        app.toggle_search_compare_view();
+
+        let diff = app.search_compare.as_ref().expect("diff built");
+        assert_eq!(diff.summary.changed, 1);
+        assert_eq!(diff.summary.added, 1);
+
+        app.append_search_results(vec![json!({"host": "c", "count": "1"})], None);
+        assert_eq!(app.search_compare.as_ref().unwrap().summary.added, 2);
+
+        app.toggle_search_compare_view();
+        assert!(app.search_compare.is_none());
+    }
+}
@@ -0,0 +1,131 @@
+//! Field table helpers for the search result detail popup.
+//!
+//! Responsibilities:
+//! - List the fields of a search result in display order
+//! - Build the `field="value"` filter added to the search query
+//!
+//! Does NOT handle:
+//! - Does NOT handle popup keys (handled by app::popups::result_detail)
+//! - Does NOT render the popup (handled by ui::result_detail)
+//!
+//! Invariants:
+//! - User fields come first, then internal (`_`-prefixed) fields, each sorted by name
+
+use serde_json::Value;
+
+/// One field of a search result; multivalue fields keep every value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResultField {
+    pub name: String,
+    pub values: Vec<String>,
+}
+
+impl ResultField {
+    /// Values as shown in the field table.
+    pub fn display_value(&self) -> String {
+        self.values.join(", ")
+    }
+
+    /// Values as copied to the clipboard, one per line.
+    pub fn copy_value(&self) -> String {
+        self.values.join("\n")
+    }
+
+    /// Search term matching this field's first value, e.g. `status="404"`.
+    pub fn filter_term(&self) -> String {
+        let value = self.values.first().map(String::as_str).unwrap_or_default();
+        let name = if self.name.chars().any(char::is_whitespace) {
+            format!("\"{}\"", self.name)
+        } else {
+            self.name.clone()
+        };
+        format!(
+            "{}=\"{}\"",
+            name,
+            value.replace('\\', "\\\\").replace('"', "\\\"")
+        )
+    }
+}
+
+/// Fields of `result` in display order; non-object results have none.
+pub fn result_fields(result: &Value) -> Vec<ResultField> {
+    let Some(object) = result.as_object() else {
+        return Vec::new();
+    };
+    let mut fields: Vec<ResultField> = object
+        .iter()
+        .map(|(name, value)| ResultField {
+            name: name.clone(),
+            values: match value {
+                Value::Array(values) => values.iter().map(value_text).collect(),
+                other => vec![value_text(other)],
+            },
+        })
+        .collect();
+    fields.sort_by(|a, b| {
+        (a.name.starts_with('_'), &a.name).cmp(&(b.name.starts_with('_'), &b.name))
+    });
+    fields
+}
+
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Add `term` to `query`, as a `| search` clause when the query has pipes.
+pub fn add_search_filter(query: &str, term: &str) -> String {
+    let query = query.trim();
+    if query.is_empty() {
+        term.to_string()
+    } else if query.contains('|') {
+        format!("{} | search {}", query, term)
+    } else {
+        format!("{} {}", query, term)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_result_fields_orders_user_fields_before_internal_ones() {
+        let fields = result_fields(&json!({
+            "_time": "2024-01-15T10:30:00",
+            "status": 404,
+            "host": ["web01", "web02"],
+            "_raw": "GET /missing",
+        }));
+        let names: Vec<&str> = fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["host", "status", "_raw", "_time"]);
+        assert_eq!(fields[0].display_value(), "web01, web02");
+        assert_eq!(fields[0].copy_value(), "web01\nweb02");
+        assert_eq!(fields[1].display_value(), "404");
+    }
+
+    #[test]
+    fn test_filter_term_escapes_value_and_quotes_spaced_names() {
+        let field = ResultField {
+            name: "user agent".to_string(),
+            values: vec![r#"curl "8.0""#.to_string()],
+        };
+        assert_eq!(field.filter_term(), r#""user agent"="curl \"8.0\"""#);
+    }
+
+    #[test]
+    fn test_add_search_filter_uses_search_clause_after_pipes() {
+        assert_eq!(
+            add_search_filter("index=web", r#"status="404""#),
+            r#"index=web status="404""#
+        );
+        assert_eq!(
+            add_search_filter("index=web | stats count by status ", r#"status="404""#),
+            r#"index=web | stats count by status | search status="404""#
+        );
+        assert_eq!(add_search_filter("", "a=\"b\""), "a=\"b\"");
+    }
+}
@@ -0,0 +1,481 @@
+//! JSON path filtering for loaded search results.
+//!
+//! Responsibilities:
+//! - Parse result filter expressions (`payload.user.id`, `items[*].status == "failed"`)
+//! - Project a path into a virtual column value for each result
+//! - Evaluate the filter incrementally as result pages are appended
+//! - Expose the filtered view of results to navigation, copy, and rendering
+//!
+//! Does NOT handle:
+//! - Does NOT fetch results or re-run searches (filtering is client-side only)
+//! - Does NOT handle filter input keys (handled by the search input handler)
+//!
+//! Invariants:
+//! - `matched` holds ascending indices into `App::search_results`
+//! - Results before `evaluated` are never re-evaluated until the results are replaced
+//! - String fields holding JSON documents (e.g. `_raw`) are parsed transparently
+//!   when the path descends into them
+
+use std::cmp::Ordering;
+
+use serde_json::Value;
+
+use crate::app::App;
+
+/// One step of a result path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PathSegment {
+    /// Object key (`user`, `["user.name"]`).
+    Key(String),
+    /// Array index (`[0]`).
+    Index(usize),
+    /// Every array element or object value (`[*]`).
+    Wildcard,
+}
+
+/// Comparison applied to the projected values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FilterOp {
+    /// Bare path: keep results where the path resolves to a non-null value.
+    Exists,
+    Eq,
+    Ne,
+    /// Case-insensitive substring match (`~`).
+    Contains,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+/// Operators in match order (longest first so `>=` wins over `>`).
+const OPERATORS: &[(&str, FilterOp)] = &[
+    ("==", FilterOp::Eq),
+    ("!=", FilterOp::Ne),
+    (">=", FilterOp::Ge),
+    ("<=", FilterOp::Le),
+    ("=", FilterOp::Eq),
+    ("~", FilterOp::Contains),
+    (">", FilterOp::Gt),
+    ("<", FilterOp::Lt),
+];
+
+/// A compiled result filter together with its incremental evaluation state.
+#[derive(Debug, Clone)]
+pub struct ResultFilter {
+    source: String,
+    column: String,
+    path: Vec<PathSegment>,
+    op: FilterOp,
+    operand: String,
+    matched: Vec<usize>,
+    evaluated: usize,
+}
+
+impl ResultFilter {
+    /// Parse a filter expression of the form `path [op value]`.
+    ///
+    /// Supported operators: `==` (or `=`), `!=`, `~` (contains), `>`, `>=`, `<`, `<=`.
+    /// A bare path keeps results where the path exists.
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let source = input.trim();
+        if source.is_empty() {
+            return Err("Filter expression is empty".to_string());
+        }
+
+        let (path_src, op, operand) = split_operator(source);
+        let column = path_src.trim().to_string();
+        let path = parse_path(&column)?;
+        if op != FilterOp::Exists && operand.is_empty() {
+            return Err(format!("Missing value after operator in '{}'", source));
+        }
+
+        Ok(Self {
+            source: source.to_string(),
+            column,
+            path,
+            op,
+            operand,
+            matched: Vec::new(),
+            evaluated: 0,
+        })
+    }
+
+    /// The expression as entered by the user.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// The path part of the expression, used as the virtual column name.
+    pub fn column(&self) -> &str {
+        &self.column
+    }
+
+    /// Indices of matching results, in result order.
+    pub fn matched(&self) -> &[usize] {
+        &self.matched
+    }
+
+    /// Number of results evaluated so far.
+    pub fn evaluated(&self) -> usize {
+        self.evaluated
+    }
+
+    /// Evaluate results that arrived since the last call.
+    pub fn evaluate_pending(&mut self, results: &[Value]) {
+        if self.evaluated > results.len() {
+            self.reset();
+        }
+        for (idx, result) in results.iter().enumerate().skip(self.evaluated) {
+            if self.matches(result) {
+                self.matched.push(idx);
+            }
+        }
+        self.evaluated = results.len();
+    }
+
+    /// Forget all evaluation state (used when the result set is replaced).
+    pub fn reset(&mut self) {
+        self.matched.clear();
+        self.evaluated = 0;
+    }
+
+    /// Virtual column value for a result, or `None` when the path does not resolve.
+    pub fn project(&self, result: &Value) -> Option<String> {
+        let values = self.resolve(result);
+        match values.as_slice() {
+            [] => None,
+            [single] => Some(display_value(single)),
+            many => Some(Value::Array(many.to_vec()).to_string()),
+        }
+    }
+
+    /// Whether a result passes the filter.
+    pub fn matches(&self, result: &Value) -> bool {
+        let values = self.resolve(result);
+        match self.op {
+            FilterOp::Exists => values.iter().any(|v| !v.is_null()),
+            FilterOp::Ne => !values
+                .iter()
+                .any(|v| self.compare(v) == Some(Ordering::Equal)),
+            FilterOp::Contains => {
+                let needle = self.operand.to_lowercase();
+                values
+                    .iter()
+                    .any(|v| display_value(v).to_lowercase().contains(&needle))
+            }
+            op => values.iter().any(|v| {
+                self.compare(v).is_some_and(|ord| match op {
+                    FilterOp::Eq => ord == Ordering::Equal,
+                    FilterOp::Gt => ord == Ordering::Greater,
+                    FilterOp::Ge => ord != Ordering::Less,
+                    FilterOp::Lt => ord == Ordering::Less,
+                    FilterOp::Le => ord != Ordering::Greater,
+                    _ => false,
+                })
+            }),
+        }
+    }
+
+    /// Compare a projected value with the operand, numerically when both are numbers.
+    fn compare(&self, value: &Value) -> Option<Ordering> {
+        let text = display_value(value);
+        match (text.parse::<f64>(), self.operand.parse::<f64>()) {
+            (Ok(lhs), Ok(rhs)) => lhs.partial_cmp(&rhs),
+            _ => Some(text.as_str().cmp(self.operand.as_str())),
+        }
+    }
+
+    fn resolve(&self, result: &Value) -> Vec<Value> {
+        let mut current = vec![result.clone()];
+        for segment in &self.path {
+            let mut next = Vec::new();
+            for value in current {
+                let value = expand_json_string(value);
+                match (segment, value) {
+                    (PathSegment::Key(key), Value::Object(mut map)) => {
+                        if let Some(child) = map.remove(key) {
+                            next.push(child);
+                        }
+                    }
+                    (PathSegment::Index(i), Value::Array(mut items)) if *i < items.len() => {
+                        next.push(items.swap_remove(*i));
+                    }
+                    (PathSegment::Wildcard, Value::Array(items)) => next.extend(items),
+                    (PathSegment::Wildcard, Value::Object(map)) => {
+                        next.extend(map.into_iter().map(|(_, v)| v))
+                    }
+                    _ => {}
+                }
+            }
+            current = next;
+        }
+        current
+    }
+}
+
+/// Split `path op value` on the first operator outside brackets and quotes.
+fn split_operator(source: &str) -> (&str, FilterOp, String) {
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    for (pos, ch) in source.char_indices() {
+        match (quote, ch) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, '[') => depth += 1,
+            (None, ']') => depth = depth.saturating_sub(1),
+            (None, _) if depth == 0 => {
+                let rest = &source[pos..];
+                if let Some((token, op)) = OPERATORS.iter().find(|(t, _)| rest.starts_with(t)) {
+                    let operand = unquote(rest[token.len()..].trim());
+                    return (&source[..pos], *op, operand);
+                }
+            }
+            _ => {}
+        }
+    }
+    (source, FilterOp::Exists, String::new())
+}
+
+/// Parse `a.b[0]["c.d"][*]` into segments.
+fn parse_path(path: &str) -> Result<Vec<PathSegment>, String> {
+    let mut segments = Vec::new();
+    let mut chars = path.chars().peekable();
+    let mut key = String::new();
+    let mut after_dot = false;
+
+    let flush = |key: &mut String, segments: &mut Vec<PathSegment>| {
+        if !key.is_empty() {
+            segments.push(PathSegment::Key(std::mem::take(key)));
+        }
+    };
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '.' => {
+                if key.is_empty() && (segments.is_empty() || after_dot) {
+                    return Err(format!("Path '{}' has an empty segment", path));
+                }
+                flush(&mut key, &mut segments);
+                after_dot = true;
+                continue;
+            }
+            '[' => {
+                flush(&mut key, &mut segments);
+                let mut inner = String::new();
+                let mut closed = false;
+                let mut quote: Option<char> = None;
+                for c in chars.by_ref() {
+                    match (quote, c) {
+                        (Some(q), c) if c == q => quote = None,
+                        (None, '"' | '\'') => quote = Some(c),
+                        (None, ']') => {
+                            closed = true;
+                            break;
+                        }
+                        _ => {}
+                    }
+                    inner.push(c);
+                }
+                if !closed {
+                    return Err(format!("Unclosed '[' in path '{}'", path));
+                }
+                let inner = inner.trim();
+                let segment = if inner == "*" {
+                    PathSegment::Wildcard
+                } else if let Ok(index) = inner.parse::<usize>() {
+                    PathSegment::Index(index)
+                } else if inner.len() >= 2 && (inner.starts_with('"') || inner.starts_with('\'')) {
+                    PathSegment::Key(unquote(inner))
+                } else {
+                    return Err(format!(
+                        "Invalid index '[{}]' in path '{}' (use a number, * or a quoted key)",
+                        inner, path
+                    ));
+                };
+                segments.push(segment);
+            }
+            c if c.is_whitespace() => {
+                return Err(format!("Unexpected whitespace in path '{}'", path));
+            }
+            c => key.push(c),
+        }
+        after_dot = false;
+    }
+    if after_dot {
+        return Err(format!("Path '{}' ends with '.'", path));
+    }
+    flush(&mut key, &mut segments);
+
+    if segments.is_empty() {
+        return Err("Filter path is empty".to_string());
+    }
+    Ok(segments)
+}
+
+/// Strip one pair of matching surrounding quotes.
+fn unquote(value: &str) -> String {
+    let bytes = value.as_bytes();
+    if value.len() >= 2
+        && (bytes[0] == b'"' || bytes[0] == b'\'')
+        && bytes[value.len() - 1] == bytes[0]
+    {
+        value[1..value.len() - 1].to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// Parse strings that hold a JSON object or array so paths can descend into them.
+fn expand_json_string(value: Value) -> Value {
+    if let Value::String(text) = &value {
+        let trimmed = text.trim_start();
+        if (trimmed.starts_with('{') || trimmed.starts_with('['))
+            && let Ok(parsed) = serde_json::from_str::<Value>(trimmed)
+        {
+            return parsed;
+        }
+    }
+    value
+}
+
+/// Render a projected value: strings unquoted, everything else as compact JSON.
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+impl App {
+    /// Number of results in the current view (filtered when a result filter is active).
+    pub fn visible_search_result_count(&self) -> usize {
+        match &self.result_filter {
+            Some(filter) => filter.matched().len(),
+            None => self.search_results.len(),
+        }
+    }
+
+    /// Result at `position` in the current view.
+    pub fn visible_search_result(&self, position: usize) -> Option<&Value> {
+        match &self.result_filter {
+            Some(filter) => filter
+                .matched()
+                .get(position)
+                .and_then(|&idx| self.search_results.get(idx)),
+            None => self.search_results.get(position),
+        }
+    }
+
+    /// Compile and apply a result filter, replacing any previous one.
+    pub(crate) fn apply_result_filter(&mut self, expression: &str) -> Result<(), String> {
+        let mut filter = ResultFilter::parse(expression)?;
+        filter.evaluate_pending(&self.search_results);
+        self.result_filter = Some(filter);
+        self.search_scroll_offset = 0;
+        Ok(())
+    }
+
+    /// Remove the result filter and show every loaded result again.
+    pub(crate) fn clear_result_filter(&mut self) {
+        if self.result_filter.take().is_some() {
+            self.search_scroll_offset = 0;
+        }
+    }
+
+    /// Re-sync the filter with `search_results` after results are replaced or appended.
+    pub(crate) fn refresh_result_filter(&mut self, replaced: bool) {
+        if let Some(filter) = self.result_filter.as_mut() {
+            if replaced {
+                filter.reset();
+            }
+            filter.evaluate_pending(&self.search_results);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::ConnectionContext;
+    use serde_json::json;
+
+    fn event(user_id: i64, status: &str) -> Value {
+        json!({
+            "_time": "2026-01-01T00:00:00Z",
+            "_raw": json!({"payload": {"user": {"id": user_id}, "status": status}}).to_string(),
+            "payload": {"user": {"id": user_id}, "tags": ["a", status]},
+        })
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_expressions() {
+        assert!(ResultFilter::parse("").is_err());
+        assert!(ResultFilter::parse("payload..user").is_err());
+        assert!(ResultFilter::parse("payload[abc]").is_err());
+        assert!(ResultFilter::parse("payload[0").is_err());
+        assert!(ResultFilter::parse("payload.user.id ==").is_err());
+        let filter = ResultFilter::parse(r#"payload["user.name"] == "bob""#).unwrap();
+        assert_eq!(filter.column(), r#"payload["user.name"]"#);
+    }
+
+    #[test]
+    fn test_project_nested_paths_and_json_strings() {
+        let result = event(42, "ok");
+        let project = |expr: &str| ResultFilter::parse(expr).unwrap().project(&result);
+
+        assert_eq!(project("payload.user.id"), Some("42".to_string()));
+        assert_eq!(project("_raw.payload.status"), Some("ok".to_string()));
+        assert_eq!(project("payload.tags[1]"), Some("ok".to_string()));
+        assert_eq!(
+            project("payload.tags[*]"),
+            Some(r#"["a","ok"]"#.to_string())
+        );
+        assert_eq!(project("payload.missing"), None);
+    }
+
+    #[test]
+    fn test_matches_operators() {
+        let result = event(42, "Failed");
+        let matches = |expr: &str| ResultFilter::parse(expr).unwrap().matches(&result);
+
+        assert!(matches("payload.user.id"));
+        assert!(!matches("payload.user.name"));
+        assert!(matches("payload.user.id == 42"));
+        assert!(matches("payload.user.id = 42.0"));
+        assert!(matches("payload.user.id != 7"));
+        assert!(matches("payload.user.id > 9"));
+        assert!(matches("payload.user.id <= 42"));
+        assert!(!matches("payload.user.id < 42"));
+        assert!(matches(r#"_raw.payload.status == "Failed""#));
+        assert!(matches("payload.tags[*] ~ fail"));
+        assert!(!matches("payload.tags[*] ~ success"));
+    }
+
+    #[test]
+    fn test_filter_evaluates_appended_pages_incrementally() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.set_search_results(vec![event(1, "ok"), event(2, "failed")]);
+        app.apply_result_filter("_raw.payload.status == failed")
+            .unwrap();
+        assert_eq!(app.visible_search_result_count(), 1);
+
+        app.append_search_results(vec![event(3, "ok"), event(4, "failed")], Some(10));
+        let filter = app.result_filter.as_ref().unwrap();
+        assert_eq!(filter.matched(), &[1, 3]);
+        assert_eq!(filter.evaluated(), 4);
+        assert_eq!(
+            app.visible_search_result(1)
+                .and_then(|v| v.pointer("/payload/user/id"))
+                .cloned(),
+            Some(json!(4))
+        );
+
+        app.set_search_results(vec![event(5, "failed")]);
+        assert_eq!(app.result_filter.as_ref().unwrap().matched(), &[0]);
+
+        app.clear_result_filter();
+        assert_eq!(app.visible_search_result_count(), 1);
+    }
+}
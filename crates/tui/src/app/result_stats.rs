@@ -0,0 +1,166 @@
+//! Summary statistics for loaded search results.
+//!
+//! Responsibilities:
+//! - Track the earliest/latest `_time` and the distinct field names of loaded results
+//! - Update incrementally as result pages are appended
+//! - Format the compact stats bar shown above the search results
+//!
+//! Does NOT handle:
+//! - Does NOT render the bar (handled by ui::screens::search)
+//! - Does NOT track pagination totals (held on `App`)
+//!
+//! Invariants:
+//! - Results before `counted` are never re-scanned until the results are replaced
+//! - Unparseable `_time` values are ignored rather than failing the update
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset};
+use serde_json::Value;
+
+/// Incrementally maintained statistics over `App::search_results`.
+#[derive(Debug, Clone, Default)]
+pub struct ResultStats {
+    /// Number of leading results already folded into the statistics.
+    counted: usize,
+    fields: HashSet<String>,
+    earliest: Option<DateTime<FixedOffset>>,
+    latest: Option<DateTime<FixedOffset>>,
+    /// Wall-clock time from dispatch to the first page of results.
+    pub duration: Option<Duration>,
+}
+
+impl ResultStats {
+    /// Fold results not yet counted into the statistics.
+    pub fn update(&mut self, results: &[Value]) {
+        for result in results.iter().skip(self.counted) {
+            let Some(object) = result.as_object() else {
+                continue;
+            };
+            for key in object.keys() {
+                if !self.fields.contains(key) {
+                    self.fields.insert(key.clone());
+                }
+            }
+            if let Some(time) = object
+                .get("_time")
+                .and_then(Value::as_str)
+                .and_then(|time| DateTime::parse_from_rfc3339(time).ok())
+            {
+                self.earliest = Some(self.earliest.map_or(time, |earliest| earliest.min(time)));
+                self.latest = Some(self.latest.map_or(time, |latest| latest.max(time)));
+            }
+        }
+        self.counted = results.len();
+    }
+
+    /// Forget all statistics (the results were replaced).
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Number of distinct field names among the loaded results.
+    pub fn field_count(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Earliest and latest `_time` among the loaded results.
+    pub fn time_range(&self) -> Option<(DateTime<FixedOffset>, DateTime<FixedOffset>)> {
+        Some((self.earliest?, self.latest?))
+    }
+
+    /// One-line summary: loaded vs total, `_time` range and span, fields, and duration.
+    pub fn summary(&self, loaded: usize, total: Option<usize>) -> String {
+        let mut parts = vec![match total {
+            Some(total) if total != loaded => format!("{} of {} loaded", loaded, total),
+            _ => format!("{} loaded", loaded),
+        }];
+        if let Some((earliest, latest)) = self.time_range() {
+            let end_format = if earliest.date_naive() == latest.date_naive() {
+                "%H:%M:%S"
+            } else {
+                "%Y-%m-%d %H:%M:%S"
+            };
+            parts.push(format!(
+                "{} → {} (span {})",
+                earliest.format("%Y-%m-%d %H:%M:%S"),
+                latest.format(end_format),
+                format_span((latest - earliest).num_seconds())
+            ));
+        }
+        parts.push(format!("{} fields", self.field_count()));
+        if let Some(duration) = self.duration {
+            parts.push(format!("job {:.1}s", duration.as_secs_f64()));
+        }
+        parts.join(" │ ")
+    }
+}
+
+/// Compact span such as `45s`, `5m 3s`, `2h 10m`, or `3d 4h`.
+fn format_span(seconds: i64) -> String {
+    let (days, hours, minutes, seconds) = (
+        seconds / 86_400,
+        seconds % 86_400 / 3_600,
+        seconds % 3_600 / 60,
+        seconds % 60,
+    );
+    match (days, hours, minutes) {
+        (0, 0, 0) => format!("{}s", seconds),
+        (0, 0, _) => format!("{}m {}s", minutes, seconds),
+        (0, _, _) => format!("{}h {}m", hours, minutes),
+        _ => format!("{}d {}h", days, hours),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_update_folds_appended_pages_only() {
+        let mut stats = ResultStats::default();
+        let mut results = vec![
+            json!({"_time": "2026-02-22T15:05:00.000+00:00", "host": "web-01"}),
+            json!({"_time": "2026-02-22T15:00:00.000+00:00", "user": "admin"}),
+        ];
+        stats.update(&results);
+        assert_eq!(stats.field_count(), 3);
+
+        results.push(json!({"_time": "2026-02-22T16:10:30.000+00:00", "status": 200}));
+        stats.update(&results);
+
+        let (earliest, latest) = stats.time_range().unwrap();
+        assert_eq!(earliest.to_rfc3339(), "2026-02-22T15:00:00+00:00");
+        assert_eq!(latest.to_rfc3339(), "2026-02-22T16:10:30+00:00");
+        assert_eq!(stats.field_count(), 4);
+        assert_eq!(
+            stats.summary(3, Some(120)),
+            "3 of 120 loaded │ 2026-02-22 15:00:00 → 16:10:30 (span 1h 10m) │ 4 fields"
+        );
+    }
+
+    #[test]
+    fn test_summary_without_times_and_with_duration() {
+        let mut stats = ResultStats {
+            duration: Some(Duration::from_millis(2_340)),
+            ..ResultStats::default()
+        };
+        stats.update(&[json!({"count": "5", "_time": "not a time"})]);
+
+        assert_eq!(stats.summary(1, Some(1)), "1 loaded │ 2 fields │ job 2.3s");
+
+        stats.reset();
+        assert_eq!(stats.field_count(), 0);
+        assert!(stats.duration.is_none());
+    }
+
+    #[test]
+    fn test_format_span() {
+        assert_eq!(format_span(45), "45s");
+        assert_eq!(format_span(303), "5m 3s");
+        assert_eq!(format_span(7_800), "2h 10m");
+        assert_eq!(format_span(273_600), "3d 4h");
+    }
+}
@@ -0,0 +1,145 @@
+//! Server messages (bulletin board) shown in the header and messages popup.
+//!
+//! Responsibilities:
+//! - Hold the latest bulletin board messages fetched from the server
+//! - Track which messages were already shown, for the header's unread count
+//! - Track the message selected in the popup
+//!
+//! Does NOT handle:
+//! - Does NOT fetch or dismiss messages (handled by Action::LoadServerMessages
+//!   and Action::DismissServerMessage)
+//! - Does NOT handle popup keys (handled by app::popups::server_messages)
+//! - Does NOT render the popup (handled by ui::server_messages)
+//!
+//! Invariants:
+//! - `selected` stays in range whenever `messages` is non-empty
+//! - Seen names of messages no longer on the server are forgotten
+
+use std::collections::HashSet;
+
+use splunk_client::{ServerMessage, ServerMessageSeverity};
+
+/// Bulletin board messages and their read state.
+#[derive(Debug, Clone, Default)]
+pub struct ServerMessages {
+    /// Messages as returned by the server (most severe first).
+    pub messages: Vec<ServerMessage>,
+    /// Names of messages already shown in the popup.
+    seen: HashSet<String>,
+    /// Index of the message selected in the popup.
+    pub selected: usize,
+}
+
+impl ServerMessages {
+    /// Replace the messages with a fresh list from the server.
+    pub fn replace(&mut self, messages: Vec<ServerMessage>) {
+        self.seen
+            .retain(|name| messages.iter().any(|message| &message.name == name));
+        self.messages = messages;
+        self.clamp_selection();
+    }
+
+    /// Remove a dismissed message.
+    pub fn remove(&mut self, name: &str) {
+        self.messages.retain(|message| message.name != name);
+        self.seen.remove(name);
+        self.clamp_selection();
+    }
+
+    /// Mark every current message as seen.
+    pub fn mark_all_seen(&mut self) {
+        self.seen
+            .extend(self.messages.iter().map(|message| message.name.clone()));
+    }
+
+    /// Number of messages not yet shown in the popup.
+    pub fn unread_count(&self) -> usize {
+        self.unread().count()
+    }
+
+    /// Highest severity among unread messages, if any are unread.
+    pub fn unread_severity(&self) -> Option<ServerMessageSeverity> {
+        self.unread().map(|message| message.severity).max()
+    }
+
+    /// The message selected in the popup.
+    pub fn selected_message(&self) -> Option<&ServerMessage> {
+        self.messages.get(self.selected)
+    }
+
+    /// Select the next message, stopping at the last one.
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.messages.len() {
+            self.selected += 1;
+        }
+    }
+
+    /// Select the previous message, stopping at the first one.
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    fn unread(&self) -> impl Iterator<Item = &ServerMessage> {
+        self.messages
+            .iter()
+            .filter(|message| !self.seen.contains(&message.name))
+    }
+
+    fn clamp_selection(&mut self) {
+        self.selected = self.selected.min(self.messages.len().saturating_sub(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(name: &str, severity: ServerMessageSeverity) -> ServerMessage {
+        ServerMessage {
+            name: name.to_string(),
+            message: format!("{} message", name),
+            severity,
+            server: None,
+            help: None,
+            time_created: None,
+        }
+    }
+
+    #[test]
+    fn test_unread_tracking_survives_refresh() {
+        let mut state = ServerMessages::default();
+        state.replace(vec![
+            message("restart_required", ServerMessageSeverity::Warn),
+            message("notice", ServerMessageSeverity::Info),
+        ]);
+        assert_eq!(state.unread_count(), 2);
+        assert_eq!(state.unread_severity(), Some(ServerMessageSeverity::Warn));
+
+        state.mark_all_seen();
+        assert_eq!(state.unread_count(), 0);
+        assert_eq!(state.unread_severity(), None);
+
+        state.replace(vec![
+            message("disk_space", ServerMessageSeverity::Error),
+            message("restart_required", ServerMessageSeverity::Warn),
+        ]);
+        assert_eq!(state.unread_count(), 1);
+        assert_eq!(state.unread_severity(), Some(ServerMessageSeverity::Error));
+    }
+
+    #[test]
+    fn test_remove_keeps_selection_in_range() {
+        let mut state = ServerMessages::default();
+        state.replace(vec![
+            message("a", ServerMessageSeverity::Info),
+            message("b", ServerMessageSeverity::Info),
+        ]);
+        state.select_next();
+        state.select_next();
+        assert_eq!(state.selected, 1);
+
+        state.remove("b");
+        assert_eq!(state.selected, 0);
+        assert_eq!(state.selected_message().map(|m| m.name.as_str()), Some("a"));
+    }
+}
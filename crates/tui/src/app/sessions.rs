@@ -0,0 +1,265 @@
+//! Saved investigation sessions: capture and restore the whole TUI workspace.
+//!
+//! Responsibilities:
+//! - Capture the current screen, query, time range, filters, list selections and
+//!   scroll positions as a named `WorkspaceSession`
+//! - Restore a saved session and return the action that reloads its data
+//! - Resolve `--session` at startup, falling back to the session picker
+//!
+//! Does NOT handle:
+//! - Does NOT handle picker input (handled by app::popups::sessions)
+//! - Does NOT write sessions to disk (saved with the rest of `PersistedState`)
+//!
+//! Invariants:
+//! - Restoring switches to the saved screen before reloading it, so the reload
+//!   targets the restored screen
+//! - The search scroll position is applied once the re-run search returns results
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use ratatui::widgets::{ListState, TableState};
+use splunk_config::{ScrollPositions, WorkspaceSession};
+
+use crate::action::Action;
+use crate::app::App;
+use crate::app::state::{CurrentScreen, parse_current_screen};
+use crate::ui::Toast;
+
+/// List screens whose selected row is saved with a session.
+const SELECTION_SCREENS: [CurrentScreen; 7] = [
+    CurrentScreen::Indexes,
+    CurrentScreen::Jobs,
+    CurrentScreen::SavedSearches,
+    CurrentScreen::InternalLogs,
+    CurrentScreen::Apps,
+    CurrentScreen::Users,
+    CurrentScreen::Inputs,
+];
+
+/// Either kind of ratatui selection state, so list and table screens share one lookup.
+enum SelectionState<'a> {
+    List(&'a mut ListState),
+    Table(&'a mut TableState),
+}
+
+impl SelectionState<'_> {
+    fn selected(&self) -> Option<usize> {
+        match self {
+            Self::List(state) => state.selected(),
+            Self::Table(state) => state.selected(),
+        }
+    }
+
+    fn select(&mut self, index: usize) {
+        match self {
+            Self::List(state) => state.select(Some(index)),
+            Self::Table(state) => state.select(Some(index)),
+        }
+    }
+}
+
+impl App {
+    /// Snapshot the current workspace as a session named `name`.
+    pub fn capture_session(&mut self, name: &str) -> WorkspaceSession {
+        let selections = SELECTION_SCREENS
+            .into_iter()
+            .filter_map(|screen| {
+                let index = self.selection_state(screen)?.selected()?;
+                Some((screen.as_str().to_string(), index))
+            })
+            .collect();
+
+        WorkspaceSession {
+            name: name.to_string(),
+            saved_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            profile: self.profile_name.clone(),
+            current_screen: self.current_screen.as_str().to_string(),
+            search_query: self.search_input.value().to_string(),
+            earliest_time: self.search_defaults.earliest_time.clone(),
+            latest_time: self.search_defaults.latest_time.clone(),
+            result_filter: self
+                .result_filter
+                .as_ref()
+                .map(|filter| filter.source().to_string()),
+            jobs_filter: self.search_filter.clone(),
+            selections,
+            scroll_positions: ScrollPositions {
+                search_scroll_offset: self.search_scroll_offset,
+                index_details_scroll_offset: self.index_details_scroll_offset,
+                help_scroll_offset: self.help_scroll_offset,
+                error_scroll_offset: self.error_scroll_offset,
+            },
+        }
+    }
+
+    /// Save the current workspace under `name`, replacing a session with the same name.
+    pub fn save_session(&mut self, name: &str) {
+        let session = self.capture_session(name);
+        splunk_config::persistence::save_session(&mut self.saved_sessions, session);
+        self.toasts
+            .push(Toast::info(format!("Saved session '{}'", name)));
+    }
+
+    /// Delete the saved session named `name`.
+    pub fn delete_session(&mut self, name: &str) {
+        if splunk_config::persistence::remove_session(&mut self.saved_sessions, name) {
+            self.toasts
+                .push(Toast::info(format!("Deleted session '{}'", name)));
+        }
+    }
+
+    /// Restore the saved session named `name`.
+    ///
+    /// Returns the action that re-runs the saved search (Search screen) or reloads
+    /// the saved screen, or `None` if no session has that name.
+    pub fn restore_session(&mut self, name: &str) -> Option<Action> {
+        let session = self
+            .saved_sessions
+            .iter()
+            .find(|session| session.name == name)?
+            .clone();
+
+        let screen = parse_current_screen(&session.current_screen);
+        self.current_screen = screen;
+        self.init_focus_manager_for_screen(screen);
+        self.clear_error_on_navigation();
+
+        self.search_input.set_value(session.search_query.clone());
+        if !session.earliest_time.is_empty() {
+            self.search_defaults.earliest_time = session.earliest_time.clone();
+        }
+        if !session.latest_time.is_empty() {
+            self.search_defaults.latest_time = session.latest_time.clone();
+        }
+        match session.result_filter.as_deref() {
+            Some(expression) => {
+                if let Err(error) = self.apply_result_filter(expression) {
+                    self.toasts.push(Toast::warning(format!(
+                        "Session result filter not restored: {}",
+                        error
+                    )));
+                }
+            }
+            None => self.clear_result_filter(),
+        }
+        self.search_filter = session.jobs_filter.clone();
+        self.rebuild_filtered_indices();
+
+        for (screen_name, index) in &session.selections {
+            if let Some(mut state) = self.selection_state(parse_current_screen(screen_name)) {
+                state.select(*index);
+            }
+        }
+        let scroll = &session.scroll_positions;
+        self.index_details_scroll_offset = scroll.index_details_scroll_offset;
+        self.help_scroll_offset = scroll.help_scroll_offset;
+        self.error_scroll_offset = scroll.error_scroll_offset;
+
+        self.toasts
+            .push(Toast::info(format!("Restored session '{}'", name)));
+
+        if screen == CurrentScreen::Search && !self.search_input.is_empty() {
+            self.pending_search_scroll = Some(scroll.search_scroll_offset);
+            self.execute_search()
+        } else {
+            self.search_scroll_offset = scroll.search_scroll_offset;
+            self.load_action_for_screen()
+        }
+    }
+
+    /// Resolve saved sessions at startup.
+    ///
+    /// Restores `requested` when given and known; otherwise opens the session
+    /// picker if any sessions are saved.
+    pub fn start_session(&mut self, requested: Option<&str>) -> Option<Action> {
+        if let Some(name) = requested {
+            if self
+                .saved_sessions
+                .iter()
+                .any(|session| session.name == name)
+            {
+                return self.restore_session(name);
+            }
+            self.toasts
+                .push(Toast::warning(format!("No saved session named '{}'", name)));
+        }
+        if !self.saved_sessions.is_empty() {
+            self.open_session_picker();
+        }
+        None
+    }
+
+    /// Apply the scroll position of a restored session to freshly loaded search results.
+    pub(crate) fn apply_pending_search_scroll(&mut self) {
+        if let Some(offset) = self.pending_search_scroll.take() {
+            let last = self.visible_search_result_count().saturating_sub(1);
+            self.search_scroll_offset = offset.min(last);
+        }
+    }
+
+    fn selection_state(&mut self, screen: CurrentScreen) -> Option<SelectionState<'_>> {
+        Some(match screen {
+            CurrentScreen::Indexes => SelectionState::List(&mut self.indexes_state),
+            CurrentScreen::Jobs => SelectionState::Table(&mut self.jobs_state),
+            CurrentScreen::SavedSearches => SelectionState::List(&mut self.saved_searches_state),
+            CurrentScreen::InternalLogs => SelectionState::Table(&mut self.internal_logs_state),
+            CurrentScreen::Apps => SelectionState::List(&mut self.apps_state),
+            CurrentScreen::Users => SelectionState::List(&mut self.users_state),
+            CurrentScreen::Inputs => SelectionState::Table(&mut self.inputs_state),
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+
+    #[test]
+    fn test_restore_session_round_trips_workspace() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.current_screen = CurrentScreen::Search;
+        app.search_input.set_value("index=main error");
+        app.search_defaults.earliest_time = "-4h".to_string();
+        app.search_defaults.latest_time = "-1h".to_string();
+        app.search_filter = Some("admin".to_string());
+        app.jobs_state.select(Some(3));
+        app.save_session("incident-1234");
+
+        app.search_input.set_value("");
+        app.search_defaults.earliest_time = "-24h".to_string();
+        app.search_filter = None;
+        app.jobs_state.select(Some(0));
+        app.current_screen = CurrentScreen::Indexes;
+
+        let action = app.restore_session("incident-1234");
+
+        assert_eq!(app.current_screen, CurrentScreen::Search);
+        assert_eq!(app.search_input.value(), "index=main error");
+        assert_eq!(app.search_defaults.earliest_time, "-4h");
+        assert_eq!(app.search_defaults.latest_time, "-1h");
+        assert_eq!(app.search_filter.as_deref(), Some("admin"));
+        assert_eq!(app.jobs_state.selected(), Some(3));
+        assert!(matches!(
+            action,
+            Some(Action::RunSearch { query, .. }) if query == "index=main error"
+        ));
+    }
+
+    #[test]
+    fn test_start_session_unknown_name_opens_picker() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.save_session("incident-1234");
+        app.popup = None;
+
+        assert!(app.start_session(Some("missing")).is_none());
+        assert!(matches!(
+            app.popup.as_ref().map(|popup| &popup.kind),
+            Some(crate::ui::popup::PopupType::SessionPicker { .. })
+        ));
+    }
+}
@@ -64,6 +64,9 @@ pub enum CurrentScreen {
     Roles,
     SearchPeers,
     Inputs,
+    Ingestion,
+    Sourcetypes,
+    Metadata,
     Configs,
     Settings,
     Overview,
@@ -76,11 +79,12 @@ pub enum CurrentScreen {
     DataModels,
     WorkloadManagement,
     Shc,
+    Rest,
 }
 
 impl CurrentScreen {
     /// Canonical navigation and serialization order for user-visible screens.
-    pub const ALL: [Self; 27] = [
+    pub const ALL: [Self; 31] = [
         Self::Search,
         Self::Indexes,
         Self::Cluster,
@@ -96,6 +100,9 @@ impl CurrentScreen {
         Self::Roles,
         Self::SearchPeers,
         Self::Inputs,
+        Self::Ingestion,
+        Self::Sourcetypes,
+        Self::Metadata,
         Self::Configs,
         Self::FiredAlerts,
         Self::Forwarders,
@@ -105,6 +112,7 @@ impl CurrentScreen {
         Self::DataModels,
         Self::WorkloadManagement,
         Self::Shc,
+        Self::Rest,
         Self::Settings,
         Self::Overview,
         Self::MultiInstance,
@@ -128,6 +136,44 @@ impl CurrentScreen {
         }
     }
 
+    /// Returns the human-readable screen title shown in the header and tab bar.
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Search => "Search",
+            Self::Indexes => "Indexes",
+            Self::Cluster => "Cluster",
+            Self::Jobs => "Jobs",
+            Self::JobInspect => "Job Details",
+            Self::Health => "Health",
+            Self::License => "License",
+            Self::Kvstore => "KVStore",
+            Self::SavedSearches => "Saved Searches",
+            Self::Macros => "Macros",
+            Self::InternalLogs => "Internal Logs",
+            Self::Apps => "Apps",
+            Self::Users => "Users",
+            Self::Roles => "Roles",
+            Self::SearchPeers => "Search Peers",
+            Self::Inputs => "Data Inputs",
+            Self::Ingestion => "Ingestion",
+            Self::Sourcetypes => "Sourcetypes",
+            Self::Metadata => "Hosts & Sources",
+            Self::Configs => "Config Files",
+            Self::FiredAlerts => "Fired Alerts",
+            Self::Forwarders => "Forwarders",
+            Self::Lookups => "Lookups",
+            Self::Audit => "Audit Events",
+            Self::Dashboards => "Dashboards",
+            Self::DataModels => "Data Models",
+            Self::WorkloadManagement => "Workload Management",
+            Self::Shc => "SHC",
+            Self::Rest => "REST",
+            Self::Settings => "Settings",
+            Self::Overview => "Overview",
+            Self::MultiInstance => "Multi-Instance",
+        }
+    }
+
     /// Returns the screen name as a string for serialization.
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -147,6 +193,9 @@ impl CurrentScreen {
             Self::Roles => "Roles",
             Self::SearchPeers => "SearchPeers",
             Self::Inputs => "Inputs",
+            Self::Ingestion => "Ingestion",
+            Self::Sourcetypes => "Sourcetypes",
+            Self::Metadata => "Metadata",
             Self::Configs => "Configs",
             Self::Settings => "Settings",
             Self::Overview => "Overview",
@@ -159,6 +208,7 @@ impl CurrentScreen {
             Self::DataModels => "DataModels",
             Self::WorkloadManagement => "WorkloadManagement",
             Self::Shc => "Shc",
+            Self::Rest => "Rest",
         }
     }
 }
@@ -269,6 +319,56 @@ pub struct SortState {
     pub direction: SortDirection,
 }
 
+/// Sort column for the ingestion table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IngestionSortColumn {
+    Name,
+    Events,
+    #[default]
+    Thruput,
+    AvgLatency,
+    MaxLatency,
+}
+
+impl IngestionSortColumn {
+    /// Returns the next column in the cycle.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Name => Self::Events,
+            Self::Events => Self::Thruput,
+            Self::Thruput => Self::AvgLatency,
+            Self::AvgLatency => Self::MaxLatency,
+            Self::MaxLatency => Self::Name,
+        }
+    }
+}
+
+/// Sort state for the ingestion table; highest thruput first by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IngestionSortState {
+    pub column: IngestionSortColumn,
+    pub direction: SortDirection,
+}
+
+impl Default for IngestionSortState {
+    fn default() -> Self {
+        Self {
+            column: IngestionSortColumn::Thruput,
+            direction: SortDirection::Desc,
+        }
+    }
+}
+
+impl IngestionSortState {
+    pub fn cycle(&mut self) {
+        self.column = self.column.next();
+    }
+
+    pub fn toggle_direction(&mut self) {
+        self.direction = self.direction.toggle();
+    }
+}
+
 /// View mode for the cluster screen.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ClusterViewMode {
@@ -277,14 +377,128 @@ pub enum ClusterViewMode {
     Summary,
     /// Show cluster peers list.
     Peers,
+    /// Show per-index bucket fix-ups and excess copies.
+    Buckets,
+    /// Show per-index replication/search factor compliance.
+    Compliance,
 }
 
 impl ClusterViewMode {
     /// Toggle between summary and peers view.
+    ///
+    /// The buckets and compliance views return to the summary.
     pub fn toggle(self) -> Self {
         match self {
             Self::Summary => Self::Peers,
-            Self::Peers => Self::Summary,
+            Self::Peers | Self::Buckets | Self::Compliance => Self::Summary,
+        }
+    }
+
+    /// Toggle between the bucket health view and the summary.
+    pub fn toggle_buckets(self) -> Self {
+        match self {
+            Self::Buckets => Self::Summary,
+            Self::Summary | Self::Peers | Self::Compliance => Self::Buckets,
+        }
+    }
+
+    /// Toggle between the index compliance view and the summary.
+    pub fn toggle_compliance(self) -> Self {
+        match self {
+            Self::Compliance => Self::Summary,
+            Self::Summary | Self::Peers | Self::Buckets => Self::Compliance,
+        }
+    }
+}
+
+/// View mode for the roles screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RolesViewMode {
+    /// Show the roles list.
+    #[default]
+    List,
+    /// Show per-role search quotas against current usage.
+    Usage,
+    /// Show the roles × capabilities matrix, including inherited capabilities.
+    Matrix,
+}
+
+impl RolesViewMode {
+    /// Toggle between the roles list and the quota usage view.
+    ///
+    /// The capability matrix switches to the quota usage view.
+    pub fn toggle(self) -> Self {
+        match self {
+            Self::List | Self::Matrix => Self::Usage,
+            Self::Usage => Self::List,
+        }
+    }
+
+    /// Toggle between the capability matrix and the roles list.
+    pub fn toggle_matrix(self) -> Self {
+        match self {
+            Self::Matrix => Self::List,
+            Self::List | Self::Usage => Self::Matrix,
+        }
+    }
+}
+
+/// View mode for the health screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HealthViewMode {
+    /// Show the aggregated health check.
+    #[default]
+    Overview,
+    /// Show per-host CPU, memory, and I/O wait with the busiest processes.
+    Resources,
+}
+
+impl HealthViewMode {
+    /// Toggle between the health overview and the resources view.
+    pub fn toggle(self) -> Self {
+        match self {
+            Self::Overview => Self::Resources,
+            Self::Resources => Self::Overview,
+        }
+    }
+}
+
+/// View mode for the inputs screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputsViewMode {
+    /// Show network, file, and script inputs.
+    #[default]
+    Inputs,
+    /// Show HTTP inputs (HEC tokens).
+    Http,
+}
+
+impl InputsViewMode {
+    /// Toggle between the inputs list and the HTTP inputs list.
+    pub fn toggle(self) -> Self {
+        match self {
+            Self::Inputs => Self::Http,
+            Self::Http => Self::Inputs,
+        }
+    }
+}
+
+/// Tab shown on the job inspect screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JobInspectTab {
+    /// Show job status and counts.
+    #[default]
+    Details,
+    /// Show command timings, dispatch phases, and per-peer counters.
+    Performance,
+}
+
+impl JobInspectTab {
+    /// Toggle between the details and performance tabs.
+    pub fn toggle(self) -> Self {
+        match self {
+            Self::Details => Self::Performance,
+            Self::Performance => Self::Details,
         }
     }
 }
@@ -990,6 +1204,9 @@ mod tests {
             CurrentScreen::Roles,
             CurrentScreen::SearchPeers,
             CurrentScreen::Inputs,
+            CurrentScreen::Ingestion,
+            CurrentScreen::Sourcetypes,
+            CurrentScreen::Metadata,
             CurrentScreen::Configs,
             CurrentScreen::FiredAlerts,
             CurrentScreen::Forwarders,
@@ -999,6 +1216,7 @@ mod tests {
             CurrentScreen::DataModels,
             CurrentScreen::WorkloadManagement,
             CurrentScreen::Shc,
+            CurrentScreen::Rest,
             CurrentScreen::Settings,
             CurrentScreen::Overview,
             CurrentScreen::MultiInstance,
@@ -11,10 +11,14 @@
 
 use crate::action::ExportFormat;
 use crate::app::export::ExportTarget;
+use crate::app::hit_areas::HitAreas;
 use crate::app::input::components::SingleLineInput;
+use crate::app::result_chart::ResultChart;
+use crate::app::result_filter::ResultFilter;
+use crate::app::result_stats::ResultStats;
 use crate::app::state::{
-    ClusterViewMode, CurrentScreen, HealthState, ListPaginationState, SearchInputMode, ShcViewMode,
-    SortState,
+    ClusterViewMode, CurrentScreen, HealthState, HealthViewMode, IngestionSortState,
+    InputsViewMode, ListPaginationState, RolesViewMode, SearchInputMode, ShcViewMode, SortState,
 };
 use crate::error_details::ErrorDetails;
 use crate::focus::FocusManager;
@@ -24,12 +28,15 @@ use crate::ui::Toast;
 use crate::ui::popup::Popup;
 use ratatui::layout::Rect;
 use serde_json::Value;
-use splunk_client::SearchMode;
 use splunk_client::models::{
-    App as SplunkApp, Capability, ClusterInfo, ClusterPeer, DataModel, HealthCheckOutput, Index,
-    KvStoreStatus, LogEntry, Macro, Role, SavedSearch, SearchJobStatus, SearchPeer, User,
+    App as SplunkApp, Capability, ClusterBucketHealth, ClusterIndexCompliance, ClusterInfo,
+    ClusterPeer, ClusterVersionReport, CurrentContext, DataModel, HealthCheckOutput, Index,
+    KvStoreStatus, LogEntry, Macro, Namespace, ResourceUsage, Role, RoleCapabilityMatrix,
+    RoleUsage, SavedSearch, SearchJobStatus, SearchPeer, User,
 };
-use splunk_config::{ColorTheme, KeybindOverrides, ListDefaults, SearchDefaults};
+use splunk_client::workflows::result_diff::ResultDiff;
+use splunk_client::{JobFilter, SearchMode};
+use splunk_config::{ClipboardBackend, ColorTheme, KeybindOverrides, ListDefaults, SearchDefaults};
 use std::collections::HashSet;
 
 /// Main application state.
@@ -44,6 +51,8 @@ pub struct App {
     pub search_results: Vec<Value>,
     pub search_scroll_offset: usize,
     pub search_sid: Option<String>,
+    /// Status of the job behind `search_sid` and when it was fetched (for the TTL warning).
+    pub search_job: Option<(SearchJobStatus, std::time::Instant)>,
 
     // Pagination state for search results
     pub search_results_total_count: Option<usize>,
@@ -52,31 +61,94 @@ pub struct App {
     /// This ensures the UI's pagination assumptions match the actual request page size.
     pub search_results_page_size: usize,
     pub search_has_more_results: bool,
+    /// Summary statistics over the loaded results (updated as pages are appended).
+    pub search_result_stats: ResultStats,
+    /// Whether the Search screen shows results as a time-series chart (toggled with `v`).
+    pub search_chart_view: bool,
+    /// Chart built from the loaded results while the chart view is on (`None` if not chartable).
+    pub search_chart: Option<ResultChart>,
+    /// Results of the previous search run, the baseline of the compare view.
+    pub search_baseline: Vec<serde_json::Value>,
+    /// Whether the Search screen shows the diff against the previous run (toggled with `c`).
+    pub search_compare_view: bool,
+    /// Diff of the loaded results against the baseline while the compare view is on.
+    pub search_compare: Option<ResultDiff>,
+    /// When the running search was dispatched, for the job duration in the stats bar.
+    pub search_started_at: Option<std::time::Instant>,
+
+    // Client-side JSON path filter over loaded search results
+    /// Active result filter; navigation and rendering use its matched view when set.
+    pub result_filter: Option<ResultFilter>,
+    /// Whether the result filter box is being edited.
+    pub is_result_filtering: bool,
+    /// Single-line input for editing the result filter expression.
+    pub result_filter_input: SingleLineInput,
+    /// Saved filter expression for cancel (Esc) while editing.
+    pub result_filter_before_edit: Option<String>,
+
+    // Result detail popup
+    /// Search result shown in the result detail popup.
+    pub result_detail: Option<serde_json::Value>,
+    pub result_detail_scroll_offset: usize,
+    /// Selected row of the result detail field table.
+    pub result_detail_selected_field: usize,
+    /// Whether sourcetype-specific renderers are used in the result detail popup (persisted).
+    pub result_renderers: bool,
+
+    // Top-values quick stats popup
+    /// Query and time range of the last dispatched search, used to scope follow-up searches.
+    pub last_search_scope: Option<crate::app::top_values::SearchScope>,
+    /// Top-values popup state for a field of the loaded results.
+    pub top_values: Option<crate::app::top_values::TopValues>,
+
+    /// Server bulletin board messages shown in the header and messages popup.
+    pub server_messages: crate::app::server_messages::ServerMessages,
 
     // Real data (Option for loading state)
     pub indexes: Option<Vec<Index>>,
     pub indexes_state: ratatui::widgets::ListState,
     pub jobs: Option<Vec<SearchJobStatus>>,
     pub jobs_state: ratatui::widgets::TableState,
+    pub job_inspect_tab: crate::app::state::JobInspectTab,
+    /// Freshly fetched status (with performance counters) of the inspected job.
+    pub job_performance: Option<SearchJobStatus>,
     pub saved_searches: Option<Vec<SavedSearch>>,
     pub saved_searches_state: ratatui::widgets::ListState,
     pub macros: Option<Vec<Macro>>,
     pub macros_state: ratatui::widgets::ListState,
     pub internal_logs: Option<Vec<LogEntry>>,
     pub internal_logs_state: ratatui::widgets::TableState,
+    pub internal_log_anomalies: crate::app::log_anomalies::LogAnomalies,
+    /// Live tail state for the internal logs view.
+    pub internal_logs_follow: crate::app::log_follow::LogFollow,
+    /// Component filter being edited on the internal logs view (`Some` while editing).
+    pub internal_logs_component_input: Option<SingleLineInput>,
     pub cluster_info: Option<ClusterInfo>,
     pub cluster_peers: Option<Vec<ClusterPeer>>,
     pub cluster_peers_state: ratatui::widgets::TableState,
     pub cluster_view_mode: ClusterViewMode,
+    pub cluster_bucket_health: Option<ClusterBucketHealth>,
+    pub cluster_index_compliance: Option<Vec<ClusterIndexCompliance>>,
+    pub cluster_versions: Option<ClusterVersionReport>,
     pub health_info: Option<HealthCheckOutput>,
+    pub health_view_mode: HealthViewMode,
+    pub resource_usage: Option<ResourceUsage>,
     pub license_info: Option<crate::action::LicenseData>,
     pub kvstore_status: Option<KvStoreStatus>,
     pub apps: Option<Vec<SplunkApp>>,
     pub apps_state: ratatui::widgets::ListState,
+    /// Inventory of the selected app (App Details popup state).
+    pub app_details: Option<crate::app::app_details::AppDetails>,
     pub users: Option<Vec<User>>,
     pub users_state: ratatui::widgets::ListState,
+    /// Last orphaned knowledge object scan (Orphaned Objects popup state).
+    pub orphan_report: Option<splunk_client::workflows::orphaned_objects::OrphanReport>,
     pub roles: Option<Vec<Role>>,
     pub roles_state: ratatui::widgets::ListState,
+    pub roles_view_mode: RolesViewMode,
+    pub role_usage: Option<Vec<RoleUsage>>,
+    pub role_matrix: Option<RoleCapabilityMatrix>,
+    pub role_matrix_state: ratatui::widgets::TableState,
     pub capabilities: Option<Vec<Capability>>,
     pub search_peers: Option<Vec<SearchPeer>>,
     pub search_peers_state: ratatui::widgets::TableState,
@@ -84,6 +156,27 @@ pub struct App {
     pub inputs: Option<Vec<splunk_client::models::Input>>,
     pub inputs_state: ratatui::widgets::TableState,
     pub inputs_pagination: ListPaginationState,
+    pub input_throughput: Option<splunk_client::models::ThroughputReport>,
+    pub inputs_view_mode: InputsViewMode,
+    pub hec_tokens: Option<Vec<splunk_client::models::HecToken>>,
+    pub hec_tokens_state: ratatui::widgets::TableState,
+    /// Name of the HEC token whose value is shown unmasked, if any.
+    pub revealed_hec_token: Option<String>,
+    pub ingestion_stats: Option<splunk_client::models::IngestionStats>,
+    pub ingestion_state: ratatui::widgets::TableState,
+    pub ingestion_sort: IngestionSortState,
+    pub sourcetypes: Option<Vec<splunk_client::models::MetadataEntry>>,
+    pub sourcetypes_state: ratatui::widgets::TableState,
+    pub metadata_entries: Option<Vec<splunk_client::models::MetadataEntry>>,
+    pub metadata_state: ratatui::widgets::TableState,
+    /// Hosts or sources, whichever the metadata screen lists (toggled with `t`).
+    pub metadata_type: splunk_client::models::MetadataType,
+    /// REST explorer request lines, most recent first (persisted).
+    pub rest_history: Vec<String>,
+    pub rest_history_state: ratatui::widgets::ListState,
+    pub rest_response: Option<crate::app::rest_explorer::RestResponse>,
+    /// First visible line of the REST explorer response body.
+    pub rest_response_scroll: usize,
     pub overview_data: Option<crate::action::OverviewData>,
 
     // Multi-instance dashboard state
@@ -104,20 +197,32 @@ pub struct App {
     pub lookups: Option<Vec<splunk_client::models::LookupTable>>,
     pub lookups_state: ratatui::widgets::TableState,
     pub lookups_pagination: ListPaginationState,
+    /// Contents editor for the selected lookup (popup state).
+    pub lookup_editor: Option<crate::app::lookup_editor::LookupEditor>,
 
     // Audit events state
     pub audit_events: Option<Vec<splunk_client::models::AuditEvent>>,
     pub audit_state: ratatui::widgets::TableState,
+    /// Time range of the audit events list.
+    pub audit_time_range: crate::app::time_range::TimeRange,
+
+    // Macro expansion preview (search screen popup)
+    pub macro_expansion: Option<splunk_client::workflows::macro_expansion::MacroExpansion>,
+    pub macro_expansion_scroll_offset: usize,
 
     // Dashboards state
     pub dashboards: Option<Vec<splunk_client::models::Dashboard>>,
     pub dashboards_state: ratatui::widgets::ListState,
     pub dashboards_pagination: ListPaginationState,
+    pub dashboard_source: Option<splunk_client::models::Dashboard>,
+    pub dashboard_source_scroll_offset: usize,
 
     // Data models state
     pub data_models: Option<Vec<DataModel>>,
     pub data_models_state: ratatui::widgets::ListState,
     pub data_models_pagination: ListPaginationState,
+    /// Tstats query builder for the selected data model (popup state).
+    pub tstats_builder: Option<crate::app::tstats_builder::TstatsBuilder>,
 
     // Workload management state
     pub workload_pools: Option<Vec<splunk_client::models::WorkloadPool>>,
@@ -147,6 +252,11 @@ pub struct App {
     pub config_stanzas_state: ratatui::widgets::TableState,
     pub selected_stanza: Option<splunk_client::models::ConfigStanza>,
     pub config_view_mode: crate::ui::screens::configs::ConfigViewMode,
+    /// Every context's copy of the selected stanza (layered view and diff).
+    pub config_layers: Option<Vec<splunk_client::models::ConfigLayer>>,
+    pub config_detail_view: crate::ui::screens::configs::ConfigDetailView,
+    /// App whose layer is diffed against the effective stanza settings.
+    pub config_diff_app: Option<String>,
 
     // Configs search state
     pub config_search_mode: bool,
@@ -165,8 +275,26 @@ pub struct App {
 
     /// Currently selected color theme (persisted across runs).
     pub color_theme: ColorTheme,
-    /// Expanded runtime theme derived from `color_theme`.
+    /// Expanded runtime theme derived from `custom_theme` or `color_theme`.
     pub theme: Theme,
+    /// Terminal color depth, detected at startup; RGB theme colors are downgraded to fit.
+    pub color_support: crate::theme::ColorSupport,
+    /// Whether the user turned on no-color mode in Settings (persisted).
+    pub no_color: bool,
+    /// Whether `NO_COLOR` or `--no-color` forces no-color mode for this run.
+    pub no_color_forced: bool,
+    /// User themes loaded from the config `themes/` directory.
+    pub custom_themes: Vec<splunk_config::ThemeFile>,
+    /// Name of the selected user theme, overriding `color_theme` (persisted).
+    pub custom_theme: Option<String>,
+    /// How copy actions deliver text (persisted; `Auto` falls back native → OSC52 → file).
+    pub clipboard_backend: ClipboardBackend,
+    /// Desktop or command notifications for finished searches and health changes (persisted).
+    pub notifications: splunk_config::NotificationSettings,
+    /// Health thresholds for profiles that do not set their own (persisted).
+    pub health_thresholds: splunk_config::HealthThresholds,
+    /// Health thresholds from the active profile's `health_thresholds`, if any.
+    pub profile_health_thresholds: Option<splunk_config::HealthThresholds>,
 
     // Jobs filter state
     pub search_filter: Option<String>,
@@ -185,6 +313,12 @@ pub struct App {
     // Multi-selection state for batch job operations
     pub selected_jobs: HashSet<String>,
 
+    // Multi-selection state (by name) for bulk saved search operations
+    pub selected_saved_searches: HashSet<String>,
+
+    // Server-side jobs filter (owner/app/status), applied to every jobs load
+    pub jobs_server_filter: JobFilter,
+
     // Health monitoring state
     pub health_state: HealthState,
 
@@ -194,6 +328,20 @@ pub struct App {
     /// Saved search input for history navigation restoration.
     pub saved_search_input: SingleLineInput,
 
+    // Personal and team search templates (loaded from disk at startup, never persisted)
+    pub search_templates: Vec<splunk_config::SearchTemplate>,
+    pub search_template_index: Option<usize>,
+    /// Parameter form for the selected search template (popup state).
+    pub template_form: Option<crate::app::template_form::TemplateForm>,
+    /// Data onboarding wizard (popup state).
+    pub onboarding_wizard: Option<crate::wizard::OnboardingWizard>,
+    /// Raw event sample of an index (popup state).
+    pub event_sample: Option<crate::app::event_sample::EventSampleView>,
+    /// Time range picker for the search, internal logs, or audit screen (popup state).
+    pub time_range_picker: Option<crate::app::time_range::TimeRangePicker>,
+    /// Field extraction tester (popup state).
+    pub extraction_tester: Option<crate::app::extraction_tester::ExtractionTester>,
+
     // Search defaults (persisted)
     pub search_defaults: SearchDefaults,
 
@@ -236,6 +384,8 @@ pub struct App {
 
     // Layout tracking
     pub last_area: Rect,
+    /// Clickable regions of the last frame, for mouse hit-testing
+    pub hit_areas: HitAreas,
 
     // Connection context (RQ-0134)
     /// Profile name used for this connection (from CLI --profile or SPLUNK_PROFILE env var)
@@ -248,6 +398,20 @@ pub struct App {
     pub server_version: Option<String>,
     /// Server build (fetched from server info)
     pub server_build: Option<String>,
+    /// Whether server info reports pending changes that need a restart
+    pub server_restart_required: bool,
+    /// Whether a splunkd restart was requested and the server is not back yet
+    pub server_restarting: bool,
+    /// Default owner/app/sharing namespace applied to knowledge-object requests
+    pub namespace: Option<Namespace>,
+    /// When the session token expires (session auth only, set by the keep-alive task)
+    pub session_expires_at: Option<std::time::Instant>,
+    /// Whether the keep-alive task failed to renew the session
+    pub session_expired: bool,
+    /// Screens showing cached snapshot data while offline (kind -> unix time it was saved)
+    pub offline_snapshots: std::collections::BTreeMap<splunk_config::SnapshotKind, u64>,
+    /// Roles and capabilities of the logged-in user (None until loaded)
+    pub current_context: Option<CurrentContext>,
 
     // Search input mode (RQ-0101)
     /// Current input mode for the search screen.
@@ -271,6 +435,8 @@ pub struct App {
     pub search_mode: SearchMode,
     /// Real-time window in seconds (only used when search_mode is Realtime).
     pub realtime_window: Option<u64>,
+    /// Whether searches are finalized once `max_results` results are available.
+    pub auto_finalize: bool,
 
     // Focus management (RQ-0323)
     /// Focus manager for keyboard navigation between components.
@@ -285,6 +451,10 @@ pub struct App {
     pub onboarding_checklist: OnboardingChecklistState,
     /// Whether the checklist overlay should render for this runtime session.
     pub onboarding_checklist_enabled: bool,
+    /// Saved investigation sessions, most recently saved first (persisted)
+    pub saved_sessions: Vec<splunk_config::WorkspaceSession>,
+    /// Search scroll offset to apply when a restored session's search returns results
+    pub pending_search_scroll: Option<usize>,
     /// Command palette state for fuzzy search and recent commands
     pub command_palette_state: crate::app::command_palette::CommandPaletteState,
 
@@ -294,6 +464,11 @@ pub struct App {
     /// Active undo toast ID for countdown updates
     pub undo_toast_id: Option<uuid::Uuid>,
 
+    /// Long-running operation shown in the progress popup, if any
+    pub operation_progress: Option<crate::operation_progress::OperationProgress>,
+    /// Searches and batch operations shown in the jobs tray
+    pub background_jobs: crate::app::background_jobs::BackgroundJobs,
+
     // UX telemetry
     /// UX telemetry collector for friction metrics.
     pub ux_telemetry: Option<crate::ux_telemetry::UxTelemetryCollector>,
@@ -326,4 +501,6 @@ pub struct ConnectionContext {
     pub base_url: String,
     /// Auth mode display string ("token" or "session")
     pub auth_mode: String,
+    /// Health thresholds set by the profile, overriding the ones edited in Settings
+    pub health_thresholds: Option<splunk_config::HealthThresholds>,
 }
@@ -0,0 +1,127 @@
+//! Parameter form for search templates with a parameter schema.
+//!
+//! Responsibilities:
+//! - Hold one input per template parameter, pre-filled with its default
+//! - Track the focused field and the last render error
+//! - Render the template into SPL from the entered values
+//!
+//! Does NOT handle:
+//! - Does NOT handle key input (handled by app::popups::template_form)
+//! - Does NOT render the popup (handled by ui::template_form)
+//!
+//! Invariants:
+//! - `inputs` has exactly one entry per `template.parameters`, in the same order
+//! - An empty input means "use the default", so required parameters must be filled in
+
+use std::collections::BTreeMap;
+
+use splunk_config::{SearchTemplate, TemplateError};
+
+use crate::app::input::components::SingleLineInput;
+
+/// Editable values for a parameterized search template.
+#[derive(Debug, Clone)]
+pub struct TemplateForm {
+    pub template: SearchTemplate,
+    pub inputs: Vec<SingleLineInput>,
+    /// Index of the focused parameter input.
+    pub selected: usize,
+    /// Error from the last attempt to apply the form.
+    pub error: Option<String>,
+}
+
+impl TemplateForm {
+    /// Build a form for `template`, pre-filling each input with the parameter default.
+    pub fn new(template: SearchTemplate) -> Self {
+        let inputs = template
+            .parameters
+            .iter()
+            .map(|parameter| {
+                SingleLineInput::with_value_and_placeholder(
+                    parameter.default.clone().unwrap_or_default(),
+                    format!("{} (required)", parameter.kind),
+                )
+            })
+            .collect();
+        Self {
+            template,
+            inputs,
+            selected: 0,
+            error: None,
+        }
+    }
+
+    pub fn next_field(&mut self) {
+        if !self.inputs.is_empty() {
+            self.selected = (self.selected + 1) % self.inputs.len();
+        }
+    }
+
+    pub fn previous_field(&mut self) {
+        if !self.inputs.is_empty() {
+            self.selected = (self.selected + self.inputs.len() - 1) % self.inputs.len();
+        }
+    }
+
+    /// The focused input, if the template has parameters.
+    pub fn selected_input_mut(&mut self) -> Option<&mut SingleLineInput> {
+        self.inputs.get_mut(self.selected)
+    }
+
+    /// Entered values by parameter name; empty inputs are omitted so defaults apply.
+    pub fn values(&self) -> BTreeMap<String, String> {
+        self.template
+            .parameters
+            .iter()
+            .zip(&self.inputs)
+            .filter(|(_, input)| !input.value().trim().is_empty())
+            .map(|(parameter, input)| (parameter.name.clone(), input.value().trim().to_string()))
+            .collect()
+    }
+
+    /// Render the template with the entered values.
+    pub fn query(&self) -> Result<String, TemplateError> {
+        self.template.render(&self.values())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template() -> SearchTemplate {
+        serde_json::from_str(
+            r#"{
+                "name": "Slow pages",
+                "query": "index=$index$ | where duration > $threshold$",
+                "parameters": [
+                    { "name": "index", "default": "web" },
+                    { "name": "threshold", "type": "number" }
+                ]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_form_prefills_defaults_and_renders() {
+        let mut form = TemplateForm::new(template());
+        assert_eq!(form.inputs[0].value(), "web");
+        assert!(matches!(
+            form.query(),
+            Err(TemplateError::MissingValue(ref name)) if name == "threshold"
+        ));
+
+        form.next_field();
+        form.selected_input_mut().unwrap().set_value("2.5");
+        assert_eq!(form.query().unwrap(), "index=web | where duration > 2.5");
+
+        form.previous_field();
+        form.selected_input_mut().unwrap().clear();
+        assert_eq!(
+            form.query().unwrap(),
+            "index=web | where duration > 2.5",
+            "empty input falls back to the default"
+        );
+    }
+}
@@ -5,8 +5,10 @@
 
 use crate::action::Action;
 use crate::app::state::CurrentScreen;
+use crate::app::time_range::TimeRange;
 use crate::app::{App, ConnectionContext};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use splunk_client::models::MetadataType;
 use splunk_config::{ListDefaults, PersistedState};
 
 #[test]
@@ -252,7 +254,7 @@ fn test_load_more_action_works_normally_under_cap() {
 
 #[test]
 fn test_translate_refresh_action_produces_offset_zero() {
-    let app = App::new(None, ConnectionContext::default());
+    let mut app = App::new(None, ConnectionContext::default());
 
     // Test RefreshIndexes produces LoadIndexes with offset=0
     let action = app.translate_refresh_action(Action::RefreshIndexes);
@@ -333,6 +335,29 @@ fn test_translate_refresh_action_produces_offset_zero() {
         }
         _ => panic!("Expected LoadInternalLogs action, got {:?}", action),
     }
+
+    // Test RefreshAuditEvents reloads with the audit time range
+    app.audit_time_range = TimeRange::new("-7d", "now");
+    let action = app.translate_refresh_action(Action::RefreshAuditEvents);
+    match action {
+        Action::LoadAuditEvents {
+            earliest, latest, ..
+        } => {
+            assert_eq!(earliest, "-7d");
+            assert_eq!(latest, "now");
+        }
+        _ => panic!("Expected LoadAuditEvents action, got {:?}", action),
+    }
+
+    // Test RefreshMetadata reloads whichever metadata type is shown
+    app.metadata_type = MetadataType::Sources;
+    let action = app.translate_refresh_action(Action::RefreshMetadata);
+    assert!(matches!(
+        action,
+        Action::LoadMetadata {
+            metadata_type: MetadataType::Sources
+        }
+    ));
 }
 
 #[test]
@@ -394,3 +419,31 @@ fn test_translate_main_loop_action_normalizes_load_more_and_refresh_actions() {
         _ => panic!("Expected LoadUsers action, got {:?}", refresh),
     }
 }
+
+#[test]
+fn test_jobs_server_filter_applies_to_every_jobs_load() {
+    let mut app = App::new(None, ConnectionContext::default());
+    app.jobs_server_filter.status = Some(splunk_client::JobStatusFilter::Failed);
+
+    for action in [
+        app.translate_main_loop_action(Action::RefreshJobs),
+        app.translate_main_loop_action(Action::LoadJobs {
+            count: 10,
+            offset: 0,
+        }),
+    ] {
+        match action {
+            Action::LoadFilteredJobs { offset, filter, .. } => {
+                assert_eq!(offset, 0);
+                assert_eq!(filter, app.jobs_server_filter);
+            }
+            other => panic!("Expected LoadFilteredJobs action, got {:?}", other),
+        }
+    }
+
+    app.current_screen = CurrentScreen::Jobs;
+    assert!(matches!(
+        app.load_action_for_screen(),
+        Some(Action::LoadFilteredJobs { .. })
+    ));
+}
@@ -0,0 +1,167 @@
+//! Theme selection: built-in themes, `Auto`, and user theme files.
+//!
+//! Responsibilities:
+//! - Hold the user themes loaded from the config `themes/` directory
+//! - Expand the selected built-in or user theme into the runtime `Theme`
+//! - Cycle through built-in themes, then user themes (Settings `T`)
+//! - Toggle no-color mode (Settings `C`), which `NO_COLOR` or `--no-color` can force on
+//!
+//! Does NOT handle:
+//! - Does NOT read theme files (see `splunk_config::ConfigManager::load_themes`)
+//! - Does NOT parse colors (see `crate::theme`)
+//!
+//! Invariants:
+//! - `custom_theme` names a loaded user theme; when it does not (file removed or invalid),
+//!   the built-in `color_theme` is used instead
+//! - The cycle visits every built-in theme, then user themes in file order, then wraps
+
+use splunk_config::{ColorTheme, ThemeLibrary};
+
+use crate::app::App;
+use crate::theme::Theme;
+use crate::ui::Toast;
+
+impl App {
+    /// Install the user themes loaded at startup and re-apply the selected theme.
+    pub fn set_theme_library(&mut self, library: ThemeLibrary) {
+        for error in library.errors {
+            self.toasts
+                .push(Toast::warning(format!("Theme not loaded: {}", error)));
+        }
+        self.custom_themes = library.themes;
+        self.apply_theme();
+    }
+
+    /// Display name of the active theme.
+    pub fn theme_name(&self) -> String {
+        match self.active_custom_theme() {
+            Some(file) => format!("{} (custom)", file.name),
+            None => self.color_theme.to_string(),
+        }
+    }
+
+    /// Expand the selected theme into `self.theme`.
+    pub(crate) fn apply_theme(&mut self) {
+        let custom = self
+            .active_custom_theme()
+            .map(|file| Theme::from_theme_file(file, self.color_support));
+        self.theme = match custom {
+            Some(Ok(theme)) => theme,
+            Some(Err(error)) => {
+                self.toasts.push(Toast::warning(error));
+                self.custom_theme = None;
+                Theme::from(self.color_theme)
+            }
+            None => Theme::from(self.color_theme),
+        };
+        self.theme.no_color = self.no_color || self.no_color_forced;
+    }
+
+    /// Turn no-color mode on or off from Settings.
+    pub(crate) fn toggle_no_color(&mut self) {
+        if self.no_color_forced {
+            self.toasts.push(Toast::warning(
+                "No-color mode is forced by NO_COLOR or --no-color",
+            ));
+            return;
+        }
+        self.no_color = !self.no_color;
+        self.apply_theme();
+        self.toasts.push(Toast::info(format!(
+            "No-color mode: {}",
+            if self.no_color { "On" } else { "Off" }
+        )));
+    }
+
+    /// Switch to the next theme: built-in themes first, then user themes.
+    pub(crate) fn cycle_theme(&mut self) {
+        let next_custom = match self.custom_theme.as_deref() {
+            Some(name) => self
+                .custom_themes
+                .iter()
+                .position(|file| file.name == name)
+                .and_then(|index| self.custom_themes.get(index + 1)),
+            None if self.color_theme.cycle_next() == ColorTheme::Default => {
+                self.custom_themes.first()
+            }
+            None => None,
+        }
+        .map(|file| file.name.clone());
+
+        match next_custom {
+            Some(name) => self.custom_theme = Some(name),
+            None if self.custom_theme.take().is_some() => self.color_theme = ColorTheme::Default,
+            None => self.color_theme = self.color_theme.cycle_next(),
+        }
+        self.apply_theme();
+        self.toasts
+            .push(Toast::info(format!("Theme: {}", self.theme_name())));
+    }
+
+    fn active_custom_theme(&self) -> Option<&splunk_config::ThemeFile> {
+        let name = self.custom_theme.as_deref()?;
+        self.custom_themes.iter().find(|file| file.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use ratatui::style::Color;
+    use splunk_config::ThemeFile;
+
+    fn ocean() -> ThemeFile {
+        ThemeFile::parse("[ui]\nborder = \"blue\"\n", "ocean").unwrap()
+    }
+
+    #[test]
+    fn test_cycle_theme_visits_custom_themes_after_builtins() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.color_theme = ColorTheme::Auto;
+        app.set_theme_library(ThemeLibrary {
+            themes: vec![ocean()],
+            errors: Vec::new(),
+        });
+
+        app.cycle_theme();
+        assert_eq!(app.custom_theme.as_deref(), Some("ocean"));
+        assert_eq!(app.theme.border, Color::Blue);
+        assert_eq!(app.theme_name(), "ocean (custom)");
+
+        app.cycle_theme();
+        assert_eq!(app.custom_theme, None);
+        assert_eq!(app.color_theme, ColorTheme::Default);
+    }
+
+    #[test]
+    fn test_invalid_custom_theme_falls_back_to_builtin() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.custom_theme = Some("broken".to_string());
+        app.set_theme_library(ThemeLibrary {
+            themes: vec![ThemeFile::parse("[ui]\ntext = \"nope\"\n", "broken").unwrap()],
+            errors: vec!["bad.toml: expected `=`".to_string()],
+        });
+
+        assert_eq!(app.custom_theme, None);
+        assert_eq!(app.theme, Theme::from(ColorTheme::Default));
+        assert_eq!(app.toasts.len(), 2);
+    }
+
+    #[test]
+    fn test_no_color_survives_theme_changes_and_respects_force() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.toggle_no_color();
+        assert!(app.no_color && app.theme.no_color);
+
+        app.cycle_theme();
+        assert!(app.theme.no_color);
+
+        app.toggle_no_color();
+        app.no_color_forced = true;
+        app.toggle_no_color();
+        assert!(!app.no_color);
+        app.apply_theme();
+        assert!(app.theme.no_color);
+    }
+}
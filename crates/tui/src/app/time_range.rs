@@ -0,0 +1,423 @@
+//! Time range picker shared by the Search, Internal Logs, and Audit screens.
+//!
+//! Responsibilities:
+//! - Offer the common relative presets (15m, 1h, 24h, 7d, month to date)
+//! - Hold and validate a custom absolute range typed by the user
+//! - Resolve absolute times without an offset in the local time zone
+//! - Validate a search's time modifiers before it is submitted
+//!
+//! Does NOT handle:
+//! - Does NOT handle popup keys (handled by app::popups::time_range)
+//! - Does NOT render the popup (handled by ui::time_range)
+//! - Does NOT persist the chosen range (search defaults are saved with app state)
+//!
+//! Invariants:
+//! - A custom range is only returned when earliest is before latest
+//! - Absolute times are emitted as ISO 8601 with an explicit UTC offset
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone};
+use splunk_client::relative_time::{parse_time_modifier, query_time_terms};
+
+use crate::app::input::components::SingleLineInput;
+
+/// Relative time ranges offered by the picker, all ending now.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeRangePreset {
+    Last15Minutes,
+    LastHour,
+    Last24Hours,
+    Last7Days,
+    MonthToDate,
+}
+
+impl TimeRangePreset {
+    pub const ALL: [Self; 5] = [
+        Self::Last15Minutes,
+        Self::LastHour,
+        Self::Last24Hours,
+        Self::Last7Days,
+        Self::MonthToDate,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Last15Minutes => "Last 15 minutes",
+            Self::LastHour => "Last hour",
+            Self::Last24Hours => "Last 24 hours",
+            Self::Last7Days => "Last 7 days",
+            Self::MonthToDate => "Month to date",
+        }
+    }
+
+    /// Splunk time modifier for the start of the range.
+    pub fn earliest(self) -> &'static str {
+        match self {
+            Self::Last15Minutes => "-15m",
+            Self::LastHour => "-1h",
+            Self::Last24Hours => "-24h",
+            Self::Last7Days => "-7d",
+            Self::MonthToDate => "@mon",
+        }
+    }
+
+    /// Preset matching an `earliest`/`latest` pair, if any.
+    pub fn matching(earliest: &str, latest: &str) -> Option<Self> {
+        (latest == "now")
+            .then(|| Self::ALL.into_iter().find(|p| p.earliest() == earliest))
+            .flatten()
+    }
+}
+
+/// Screen whose time range the picker edits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeRangeTarget {
+    /// `SearchDefaults` used by new searches.
+    Search,
+    /// Internal logs query (earliest only; the query always runs to now).
+    InternalLogs,
+    /// Audit events list.
+    Audit,
+}
+
+impl TimeRangeTarget {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Search => "Search",
+            Self::InternalLogs => "Internal Logs",
+            Self::Audit => "Audit",
+        }
+    }
+
+    /// Whether the target takes a latest time, or always runs to now.
+    pub fn supports_latest(self) -> bool {
+        !matches!(self, Self::InternalLogs)
+    }
+}
+
+/// An `earliest`/`latest` pair of Splunk time modifiers or ISO 8601 times.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeRange {
+    pub earliest: String,
+    pub latest: String,
+}
+
+impl TimeRange {
+    pub fn new(earliest: impl Into<String>, latest: impl Into<String>) -> Self {
+        Self {
+            earliest: earliest.into(),
+            latest: latest.into(),
+        }
+    }
+
+    /// Short description, e.g. `Last 24 hours` or `-4h → -1h`.
+    pub fn label(&self) -> String {
+        match TimeRangePreset::matching(&self.earliest, &self.latest) {
+            Some(preset) => preset.label().to_string(),
+            None => format!("{} → {}", self.earliest, self.latest),
+        }
+    }
+}
+
+impl Default for TimeRange {
+    fn default() -> Self {
+        Self::new("-24h", "now")
+    }
+}
+
+/// Part of the picker that has keyboard focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeRangeFocus {
+    #[default]
+    Presets,
+    Earliest,
+    Latest,
+}
+
+/// State of the time range picker popup.
+#[derive(Debug, Clone)]
+pub struct TimeRangePicker {
+    pub target: TimeRangeTarget,
+    /// Range in effect when the picker opened.
+    pub current: TimeRange,
+    /// Selected row: a preset index, or `TimeRangePreset::ALL.len()` for the custom range.
+    pub selected: usize,
+    pub earliest: SingleLineInput,
+    pub latest: SingleLineInput,
+    pub focus: TimeRangeFocus,
+    /// Validation error for the custom range.
+    pub error: Option<String>,
+}
+
+/// Accepted custom time formats (without offset), most specific first.
+const DATE_TIME_FORMATS: [&str; 4] = [
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M",
+];
+
+impl TimeRangePicker {
+    /// Index of the custom range row.
+    pub const CUSTOM: usize = TimeRangePreset::ALL.len();
+
+    /// Open on the preset matching `current`, or on the custom row prefilled
+    /// with `current` when it is an absolute range.
+    pub fn new(target: TimeRangeTarget, current: TimeRange) -> Self {
+        let preset = TimeRangePreset::ALL.iter().position(|p| {
+            Some(*p) == TimeRangePreset::matching(&current.earliest, &current.latest)
+        });
+        let placeholder = "YYYY-MM-DD HH:MM[:SS] [Z|±hh:mm]";
+        let mut earliest = SingleLineInput::with_placeholder(placeholder);
+        let mut latest = SingleLineInput::with_placeholder(placeholder);
+        if preset.is_none() && DateTime::parse_from_rfc3339(&current.earliest).is_ok() {
+            earliest.set_value(current.earliest.clone());
+            if DateTime::parse_from_rfc3339(&current.latest).is_ok() {
+                latest.set_value(current.latest.clone());
+            }
+        }
+        if !target.supports_latest() {
+            latest.set_placeholder("now");
+        }
+        Self {
+            target,
+            current,
+            selected: preset.unwrap_or(Self::CUSTOM),
+            earliest,
+            latest,
+            focus: TimeRangeFocus::default(),
+            error: None,
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1) % (Self::CUSTOM + 1);
+    }
+
+    pub fn previous(&mut self) {
+        self.selected = self.selected.checked_sub(1).unwrap_or(Self::CUSTOM);
+    }
+
+    /// Move focus Presets → Earliest → Latest (when supported) → Presets.
+    pub fn cycle_focus(&mut self) {
+        self.focus = match self.focus {
+            TimeRangeFocus::Presets => TimeRangeFocus::Earliest,
+            TimeRangeFocus::Earliest if self.target.supports_latest() => TimeRangeFocus::Latest,
+            TimeRangeFocus::Earliest | TimeRangeFocus::Latest => TimeRangeFocus::Presets,
+        };
+        if self.focus != TimeRangeFocus::Presets {
+            self.selected = Self::CUSTOM;
+        }
+    }
+
+    /// The selected preset, or `None` when the custom row is selected.
+    pub fn selected_preset(&self) -> Option<TimeRangePreset> {
+        TimeRangePreset::ALL.get(self.selected).copied()
+    }
+
+    /// The range to apply: the selected preset, or the validated custom range
+    /// with offset-less times read in `tz`.
+    pub fn range<Tz: TimeZone>(&self, tz: &Tz) -> Result<TimeRange, String> {
+        if let Some(preset) = self.selected_preset() {
+            return Ok(TimeRange::new(preset.earliest(), "now"));
+        }
+        let earliest = parse_absolute_time(self.earliest.value(), tz)
+            .map_err(|e| format!("Earliest: {}", e))?;
+        if !self.target.supports_latest() || self.latest.value().trim().is_empty() {
+            return Ok(TimeRange::new(format_time(&earliest), "now"));
+        }
+        let latest =
+            parse_absolute_time(self.latest.value(), tz).map_err(|e| format!("Latest: {}", e))?;
+        if earliest >= latest {
+            return Err("Earliest must be before latest".to_string());
+        }
+        Ok(TimeRange::new(format_time(&earliest), format_time(&latest)))
+    }
+}
+
+/// Check the time modifiers of a search about to run against `now`: the
+/// `range` it runs over and any `earliest=`/`latest=` terms in `query`.
+///
+/// The range must resolve with earliest before latest.
+pub fn validate_search_time<Tz: TimeZone>(
+    query: &str,
+    range: &TimeRange,
+    now: &DateTime<Tz>,
+) -> Result<(), String>
+where
+    Tz::Offset: std::fmt::Display,
+{
+    for (name, value) in query_time_terms(query) {
+        parse_time_modifier(value).map_err(|e| format!("Query {}: {}", name, e))?;
+    }
+    let earliest =
+        parse_time_modifier(&range.earliest).map_err(|e| format!("Earliest time: {}", e))?;
+    let latest = parse_time_modifier(&range.latest).map_err(|e| format!("Latest time: {}", e))?;
+    if let (Some(start), Some(end)) = (earliest.resolve(now), latest.resolve(now))
+        && start >= end
+    {
+        return Err(format!(
+            "Earliest time {} ({}) is not before latest time {} ({})",
+            range.earliest,
+            earliest.preview(now),
+            range.latest,
+            latest.preview(now)
+        ));
+    }
+    Ok(())
+}
+
+fn format_time(time: &DateTime<FixedOffset>) -> String {
+    time.format("%Y-%m-%dT%H:%M:%S%:z").to_string()
+}
+
+/// Parse an absolute time; times without an offset are read in `tz`.
+///
+/// Accepts RFC 3339 (`2024-01-15T08:00:00Z`), `YYYY-MM-DD HH:MM[:SS]`
+/// (with a space or `T`), and a bare date (midnight).
+pub fn parse_absolute_time<Tz: TimeZone>(
+    input: &str,
+    tz: &Tz,
+) -> Result<DateTime<FixedOffset>, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err("enter a date and time".to_string());
+    }
+    if let Ok(time) = DateTime::parse_from_rfc3339(input) {
+        return Ok(time);
+    }
+    let naive = DATE_TIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(input, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(input, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+        .ok_or_else(|| {
+            format!(
+                "'{}' is not a valid time (use YYYY-MM-DD HH:MM[:SS], optionally with Z or ±hh:mm)",
+                input
+            )
+        })?;
+    tz.from_local_datetime(&naive)
+        .single()
+        .map(|time| time.fixed_offset())
+        .ok_or_else(|| {
+            format!(
+                "'{}' is ambiguous or skipped in the local time zone (add an offset)",
+                input
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tz() -> FixedOffset {
+        FixedOffset::west_opt(5 * 3600).unwrap()
+    }
+
+    fn custom(earliest: &str, latest: &str, target: TimeRangeTarget) -> TimeRangePicker {
+        let mut picker = TimeRangePicker::new(target, TimeRange::default());
+        picker.selected = TimeRangePicker::CUSTOM;
+        picker.earliest.set_value(earliest);
+        picker.latest.set_value(latest);
+        picker
+    }
+
+    #[test]
+    fn test_new_selects_matching_preset_or_custom() {
+        let picker = TimeRangePicker::new(TimeRangeTarget::Search, TimeRange::new("-7d", "now"));
+        assert_eq!(picker.selected_preset(), Some(TimeRangePreset::Last7Days));
+
+        let picker = TimeRangePicker::new(
+            TimeRangeTarget::Search,
+            TimeRange::new("2024-01-15T08:00:00+00:00", "2024-01-15T09:00:00+00:00"),
+        );
+        assert_eq!(picker.selected, TimeRangePicker::CUSTOM);
+        assert_eq!(picker.earliest.value(), "2024-01-15T08:00:00+00:00");
+        assert_eq!(picker.latest.value(), "2024-01-15T09:00:00+00:00");
+    }
+
+    #[test]
+    fn test_preset_range() {
+        let mut picker = TimeRangePicker::new(TimeRangeTarget::Audit, TimeRange::default());
+        picker.next();
+        picker.next();
+        assert_eq!(picker.range(&tz()), Ok(TimeRange::new("@mon", "now")));
+    }
+
+    #[test]
+    fn test_custom_range_uses_local_zone_unless_offset_given() {
+        let picker = custom(
+            "2024-01-15 08:00",
+            "2024-01-15T14:30:00Z",
+            TimeRangeTarget::Search,
+        );
+        assert_eq!(
+            picker.range(&tz()),
+            Ok(TimeRange::new(
+                "2024-01-15T08:00:00-05:00",
+                "2024-01-15T14:30:00+00:00"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_custom_range_validation() {
+        let reversed = custom(
+            "2024-01-15 10:00",
+            "2024-01-15 09:00",
+            TimeRangeTarget::Search,
+        );
+        assert_eq!(
+            reversed.range(&tz()),
+            Err("Earliest must be before latest".to_string())
+        );
+
+        let invalid = custom("yesterday", "", TimeRangeTarget::Search);
+        assert!(
+            invalid
+                .range(&tz())
+                .unwrap_err()
+                .starts_with("Earliest: 'yesterday'")
+        );
+
+        // Internal logs ignore latest and always run to now
+        let logs = custom("2024-01-15", "garbage", TimeRangeTarget::InternalLogs);
+        assert_eq!(
+            logs.range(&tz()),
+            Ok(TimeRange::new("2024-01-15T00:00:00-05:00", "now"))
+        );
+    }
+
+    #[test]
+    fn test_validate_search_time() {
+        let now = DateTime::parse_from_rfc3339("2024-01-15T10:37:42-05:00").unwrap();
+        assert_eq!(
+            validate_search_time("index=main", &TimeRange::new("-24h@h", "now"), &now),
+            Ok(())
+        );
+        assert_eq!(
+            validate_search_time("index=main", &TimeRange::new("-1d@d", "-2d"), &now),
+            Err("Earliest time -1d@d (2024-01-14 00:00:00 -05:00) is not before latest time -2d (2024-01-13 10:37:42 -05:00)".to_string())
+        );
+        assert!(
+            validate_search_time("index=main", &TimeRange::new("-24z", "now"), &now)
+                .unwrap_err()
+                .starts_with("Earliest time: unknown time unit 'z'")
+        );
+        assert!(
+            validate_search_time("index=main earliest=-1x", &TimeRange::default(), &now)
+                .unwrap_err()
+                .starts_with("Query earliest: unknown time unit 'x'")
+        );
+    }
+
+    #[test]
+    fn test_time_range_label() {
+        assert_eq!(TimeRange::default().label(), "Last 24 hours");
+        assert_eq!(TimeRange::new("-4h", "-1h").label(), "-4h → -1h");
+    }
+}
@@ -0,0 +1,268 @@
+//! Top-values quick stats for fields of loaded search results.
+//!
+//! Responsibilities:
+//! - Remember the query and time range of the last search (the follow-up scope)
+//! - Hold the field picker and the `| top` results shown in the popup
+//! - Build the follow-up `| top` query and parse its rows
+//!
+//! Does NOT handle:
+//! - Does NOT run the follow-up search (handled by Action::LoadTopValues)
+//! - Does NOT handle popup keys (handled by app::popups::top_values)
+//! - Does NOT render the popup (handled by ui::top_values)
+//!
+//! Invariants:
+//! - `fields` is never empty and `selected` stays in range
+//! - Results for a field other than the requested one are ignored
+
+use serde_json::Value;
+
+use crate::action::Action;
+
+/// Maximum number of distinct values requested from `| top`.
+pub const TOP_VALUES_LIMIT: usize = 20;
+
+/// Query and time range a search was dispatched with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchScope {
+    pub query: String,
+    pub earliest_time: String,
+    pub latest_time: String,
+}
+
+/// One row of `| top` output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopValueRow {
+    pub value: String,
+    pub count: u64,
+    pub percent: f64,
+}
+
+/// What the popup is currently showing.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TopValuesPhase {
+    /// Picking the field to summarize.
+    SelectField,
+    /// Waiting for the follow-up search for `field`.
+    Loading { field: String },
+    /// Top values for `field`.
+    Loaded {
+        field: String,
+        rows: Vec<TopValueRow>,
+    },
+    /// The follow-up search failed.
+    Failed { field: String, error: String },
+}
+
+/// State for the top-values popup.
+#[derive(Debug, Clone)]
+pub struct TopValues {
+    pub scope: SearchScope,
+    /// Fields of the selected result, sorted.
+    pub fields: Vec<String>,
+    pub selected: usize,
+    pub phase: TopValuesPhase,
+}
+
+impl TopValues {
+    /// Start the field picker for `result`, or `None` when it has no user-visible fields.
+    ///
+    /// Internal fields (leading underscore, such as `_raw` and `_time`) are skipped.
+    pub fn new(scope: SearchScope, result: &Value) -> Option<Self> {
+        let mut fields: Vec<String> = result
+            .as_object()?
+            .keys()
+            .filter(|key| !key.starts_with('_'))
+            .cloned()
+            .collect();
+        if fields.is_empty() {
+            return None;
+        }
+        fields.sort();
+        Some(Self {
+            scope,
+            fields,
+            selected: 0,
+            phase: TopValuesPhase::SelectField,
+        })
+    }
+
+    pub fn next_field(&mut self) {
+        self.selected = (self.selected + 1) % self.fields.len();
+    }
+
+    pub fn previous_field(&mut self) {
+        self.selected = (self.selected + self.fields.len() - 1) % self.fields.len();
+    }
+
+    /// The highlighted field in the picker.
+    pub fn selected_field(&self) -> &str {
+        &self.fields[self.selected]
+    }
+
+    /// The field being summarized, once one has been picked.
+    pub fn field(&self) -> Option<&str> {
+        match &self.phase {
+            TopValuesPhase::SelectField => None,
+            TopValuesPhase::Loading { field }
+            | TopValuesPhase::Loaded { field, .. }
+            | TopValuesPhase::Failed { field, .. } => Some(field),
+        }
+    }
+
+    /// Follow-up query for `field`, scoped to the original search.
+    pub fn query(&self, field: &str) -> String {
+        top_values_query(&self.scope.query, field)
+    }
+
+    /// Run the follow-up search for the highlighted field.
+    pub fn load_selected(&mut self) -> Action {
+        let field = self.selected_field().to_string();
+        self.phase = TopValuesPhase::Loading {
+            field: field.clone(),
+        };
+        Action::LoadTopValues {
+            query: self.query(&field),
+            field,
+            earliest_time: self.scope.earliest_time.clone(),
+            latest_time: self.scope.latest_time.clone(),
+        }
+    }
+
+    /// Store follow-up results, ignoring results for a field that is no longer requested.
+    pub fn set_results(&mut self, field: &str, result: Result<Vec<Value>, String>) {
+        if !matches!(&self.phase, TopValuesPhase::Loading { field: loading } if loading == field) {
+            return;
+        }
+        self.phase = match result {
+            Ok(results) => TopValuesPhase::Loaded {
+                field: field.to_string(),
+                rows: parse_top_rows(field, &results),
+            },
+            Err(error) => TopValuesPhase::Failed {
+                field: field.to_string(),
+                error,
+            },
+        };
+    }
+}
+
+/// Append `| top` for `field` to `query`, quoting field names Splunk would not parse bare.
+pub fn top_values_query(query: &str, field: &str) -> String {
+    let bare = field
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | ':' | '-'));
+    let field = if bare {
+        field.to_string()
+    } else {
+        format!("\"{}\"", field.replace('\\', "\\\\").replace('"', "\\\""))
+    };
+    format!(
+        "{} | top limit={} {}",
+        query.trim(),
+        TOP_VALUES_LIMIT,
+        field
+    )
+}
+
+/// Parse `| top` output rows (`<field>`, `count`, `percent`).
+pub fn parse_top_rows(field: &str, results: &[Value]) -> Vec<TopValueRow> {
+    results
+        .iter()
+        .map(|result| {
+            let value = match result.get(field) {
+                Some(Value::String(s)) => s.clone(),
+                Some(Value::Null) | None => String::new(),
+                Some(other) => other.to_string(),
+            };
+            TopValueRow {
+                value,
+                count: number(result.get("count")) as u64,
+                percent: number(result.get("percent")),
+            }
+        })
+        .collect()
+}
+
+/// Splunk returns numbers as strings; accept both.
+fn number(value: Option<&Value>) -> f64 {
+    match value {
+        Some(Value::String(s)) => s.trim().parse().unwrap_or(0.0),
+        Some(Value::Number(n)) => n.as_f64().unwrap_or(0.0),
+        _ => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn scope() -> SearchScope {
+        SearchScope {
+            query: "index=web status>=500 ".to_string(),
+            earliest_time: "-4h".to_string(),
+            latest_time: "now".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_fields_skip_internal_and_sort() {
+        let result = json!({"_raw": "x", "_time": "t", "status": "500", "host": "web01"});
+        let top = TopValues::new(scope(), &result).unwrap();
+        assert_eq!(top.fields, vec!["host", "status"]);
+
+        assert!(TopValues::new(scope(), &json!({"_raw": "x"})).is_none());
+        assert!(TopValues::new(scope(), &json!("x")).is_none());
+    }
+
+    #[test]
+    fn test_top_values_query_quotes_special_fields() {
+        assert_eq!(
+            top_values_query("index=web ", "status"),
+            "index=web | top limit=20 status"
+        );
+        assert_eq!(
+            top_values_query("index=web", "user agent"),
+            "index=web | top limit=20 \"user agent\""
+        );
+    }
+
+    #[test]
+    fn test_load_selected_and_results() {
+        let mut top = TopValues::new(scope(), &json!({"host": "a", "status": "500"})).unwrap();
+        top.next_field();
+        let action = top.load_selected();
+        assert!(matches!(
+            action,
+            Action::LoadTopValues { ref query, ref field, ref earliest_time, .. }
+                if query == "index=web status>=500 | top limit=20 status"
+                    && field == "status"
+                    && earliest_time == "-4h"
+        ));
+
+        // Stale results for another field are ignored
+        top.set_results("host", Ok(vec![]));
+        assert_eq!(top.field(), Some("status"));
+        assert!(matches!(top.phase, TopValuesPhase::Loading { .. }));
+
+        top.set_results(
+            "status",
+            Ok(vec![
+                json!({"status": "500", "count": "42", "percent": "84.000000"}),
+                json!({"status": "503", "count": 8, "percent": "16"}),
+            ]),
+        );
+        let TopValuesPhase::Loaded { rows, .. } = &top.phase else {
+            panic!("expected loaded phase");
+        };
+        assert_eq!(
+            rows[0],
+            TopValueRow {
+                value: "500".to_string(),
+                count: 42,
+                percent: 84.0
+            }
+        );
+        assert_eq!(rows[1].count, 8);
+    }
+}
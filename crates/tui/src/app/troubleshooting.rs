@@ -0,0 +1,111 @@
+//! Curated troubleshooting searches against Splunk's own logs.
+//!
+//! Responsibilities:
+//! - Define the recipe table listed by the troubleshooting recipes popup
+//! - Map a chosen recipe to its SPL
+//!
+//! Does NOT handle:
+//! - Does NOT handle popup keys or run searches (handled by app::popups::troubleshooting)
+//! - Does NOT render the popup (handled by ui::popup module)
+//!
+//! Invariants:
+//! - Every recipe searches `_internal` or `_introspection` and carries its own
+//!   time range, so it runs the same whatever the search defaults are
+
+/// A named troubleshooting search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TroubleshootingRecipe {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub query: &'static str,
+}
+
+/// Recipes in menu order. Add a row here to add a menu entry.
+pub const RECIPES: &[TroubleshootingRecipe] = &[
+    TroubleshootingRecipe {
+        name: "Blocked queues",
+        description: "Ingestion queues reporting blocked=true, by host and queue",
+        query: "index=_internal source=*metrics.log* group=queue blocked=true earliest=-4h \
+                | stats count AS blocked_events latest(_time) AS last_blocked BY host, name \
+                | sort - blocked_events",
+    },
+    TroubleshootingRecipe {
+        name: "Queue fill ratio",
+        description: "Peak fill percentage of each ingestion queue over time",
+        query: "index=_internal source=*metrics.log* group=queue earliest=-4h \
+                | eval fill_pct=round(current_size_kb/max_size_kb*100, 1) \
+                | timechart span=5m max(fill_pct) BY name",
+    },
+    TroubleshootingRecipe {
+        name: "Skipped searches",
+        description: "Scheduled searches the scheduler skipped, with the reason",
+        query: "index=_internal sourcetype=scheduler status=skipped earliest=-24h \
+                | stats count BY app, savedsearch_name, reason \
+                | sort - count",
+    },
+    TroubleshootingRecipe {
+        name: "Bundle replication errors",
+        description: "Knowledge bundle replication warnings and errors to search peers",
+        query: "index=_internal sourcetype=splunkd (component=DistributedBundleReplicationManager OR component=BundleReplicationProvider OR component=ClassicBundleReplicationProvider) (log_level=WARN OR log_level=ERROR) earliest=-24h \
+                | stats count latest(event_message) AS latest_message BY host, component, log_level \
+                | sort - count",
+    },
+    TroubleshootingRecipe {
+        name: "Thruput by sourcetype",
+        description: "Indexing throughput in KB per sourcetype",
+        query: "index=_internal source=*metrics.log* group=per_sourcetype_thruput earliest=-4h \
+                | timechart span=5m sum(kb) BY series",
+    },
+    TroubleshootingRecipe {
+        name: "splunkd errors by component",
+        description: "Components logging the most errors",
+        query: "index=_internal sourcetype=splunkd log_level=ERROR earliest=-4h \
+                | stats count latest(event_message) AS latest_message BY component \
+                | sort - count",
+    },
+    TroubleshootingRecipe {
+        name: "Search head resource usage",
+        description: "splunkd host CPU and memory from introspection",
+        query: "index=_introspection sourcetype=splunk_resource_usage component=Hostwide earliest=-4h \
+                | eval mem_used_pct=round('data.mem_used'/'data.mem'*100, 1) \
+                | eval cpu_pct='data.cpu_system_pct'+'data.cpu_user_pct' \
+                | timechart span=5m avg(cpu_pct) AS cpu_pct avg(mem_used_pct) AS mem_used_pct BY host",
+    },
+    TroubleshootingRecipe {
+        name: "Expensive searches",
+        description: "Searches using the most memory, from introspection",
+        query: "index=_introspection sourcetype=splunk_resource_usage component=PerProcess data.search_props.sid=* earliest=-4h \
+                | stats max(data.mem_used) AS peak_mem_mb max(data.elapsed) AS elapsed_s BY data.search_props.user, data.search_props.sid \
+                | sort - peak_mem_mb \
+                | head 20",
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_recipes_target_internal_indexes_with_time_range() {
+        for recipe in RECIPES {
+            assert!(
+                recipe.query.starts_with("index=_internal ")
+                    || recipe.query.starts_with("index=_introspection "),
+                "{} does not search an internal index",
+                recipe.name
+            );
+            assert!(
+                recipe.query.contains(" earliest="),
+                "{} has no time range",
+                recipe.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_recipe_names_are_unique() {
+        let names: HashSet<_> = RECIPES.iter().map(|recipe| recipe.name).collect();
+        assert_eq!(names.len(), RECIPES.len());
+    }
+}
@@ -0,0 +1,252 @@
+//! Guided `| tstats` query builder for accelerated data models.
+//!
+//! Responsibilities:
+//! - Hold the builder selections (dataset, split-by fields, aggregates, span)
+//! - Generate the SPL preview and the query run from the builder popup
+//!
+//! Does NOT handle:
+//! - Does NOT fetch the data model definition (handled by Action::LoadTstatsBuilder)
+//! - Does NOT handle popup keys (handled by app::popups::tstats_builder)
+//! - Does NOT render the popup (handled by ui::tstats_builder)
+//!
+//! Invariants:
+//! - `objects` is never empty and `object_index`/`field_index` stay in range
+//! - Selections only reference fields of the current dataset (they reset when it changes)
+
+use splunk_client::models::{DataModel, DataModelObject, TstatsAggregate, TstatsQuery};
+
+/// Aggregate functions cycled on a field, in order (`count` is always included).
+const FIELD_FUNCTIONS: &[&str] = &["dc", "sum", "avg", "min", "max", "values"];
+
+/// `_time` bucket spans cycled by the builder.
+const SPANS: &[&str] = &["1m", "5m", "1h", "1d"];
+
+/// Builder state for the tstats popup.
+#[derive(Debug, Clone)]
+pub struct TstatsBuilder {
+    pub datamodel: String,
+    pub accelerated: bool,
+    pub objects: Vec<DataModelObject>,
+    pub object_index: usize,
+    pub field_index: usize,
+    /// Qualified split-by fields, in selection order.
+    pub split_by: Vec<String>,
+    /// Per-field aggregates, in selection order.
+    pub aggregates: Vec<TstatsAggregate>,
+    pub span: Option<&'static str>,
+    pub summaries_only: bool,
+}
+
+impl TstatsBuilder {
+    /// Start a builder for `datamodel`, or `None` when its definition has no datasets.
+    pub fn new(datamodel: &DataModel) -> Option<Self> {
+        let objects = datamodel.objects().filter(|objects| !objects.is_empty())?;
+        Some(Self {
+            datamodel: datamodel.name.clone(),
+            accelerated: datamodel.is_accelerated,
+            objects,
+            object_index: 0,
+            field_index: 0,
+            split_by: Vec::new(),
+            aggregates: Vec::new(),
+            span: None,
+            summaries_only: datamodel.is_accelerated,
+        })
+    }
+
+    /// The selected dataset.
+    pub fn object(&self) -> &DataModelObject {
+        &self.objects[self.object_index]
+    }
+
+    /// The field under the cursor, if the dataset has any.
+    pub fn selected_field(&self) -> Option<&str> {
+        self.object()
+            .fields
+            .get(self.field_index)
+            .map(String::as_str)
+    }
+
+    /// Move to the next (or previous) dataset, clearing field selections.
+    pub fn cycle_object(&mut self, forward: bool) {
+        let len = self.objects.len();
+        self.object_index = if forward {
+            (self.object_index + 1) % len
+        } else {
+            (self.object_index + len - 1) % len
+        };
+        self.field_index = 0;
+        self.split_by.clear();
+        self.aggregates.clear();
+    }
+
+    pub fn next_field(&mut self) {
+        let len = self.object().fields.len();
+        if self.field_index + 1 < len {
+            self.field_index += 1;
+        }
+    }
+
+    pub fn previous_field(&mut self) {
+        self.field_index = self.field_index.saturating_sub(1);
+    }
+
+    /// Add or remove the selected field from the split-by fields.
+    pub fn toggle_split_by(&mut self) {
+        let Some(field) = self.selected_field().map(str::to_string) else {
+            return;
+        };
+        match self.split_by.iter().position(|f| *f == field) {
+            Some(idx) => {
+                self.split_by.remove(idx);
+            }
+            None => self.split_by.push(field),
+        }
+    }
+
+    /// Aggregate function applied to `field`, if any.
+    pub fn aggregate_for(&self, field: &str) -> Option<&str> {
+        self.aggregates
+            .iter()
+            .find(|aggregate| aggregate.field.as_deref() == Some(field))
+            .map(|aggregate| aggregate.function.as_str())
+    }
+
+    /// Cycle the aggregate on the selected field: none → dc → sum → ... → none.
+    pub fn cycle_aggregate(&mut self) {
+        let Some(field) = self.selected_field().map(str::to_string) else {
+            return;
+        };
+        let position = self
+            .aggregates
+            .iter()
+            .position(|aggregate| aggregate.field.as_deref() == Some(field.as_str()));
+        let next = match position {
+            None => Some(FIELD_FUNCTIONS[0]),
+            Some(idx) => FIELD_FUNCTIONS
+                .iter()
+                .position(|function| *function == self.aggregates[idx].function)
+                .and_then(|current| FIELD_FUNCTIONS.get(current + 1))
+                .copied(),
+        };
+
+        match (position, next) {
+            (Some(idx), Some(function)) => self.aggregates[idx].function = function.to_string(),
+            (Some(idx), None) => {
+                self.aggregates.remove(idx);
+            }
+            (None, Some(function)) => self.aggregates.push(TstatsAggregate {
+                function: function.to_string(),
+                field: Some(field),
+            }),
+            (None, None) => {}
+        }
+    }
+
+    /// Cycle the `_time` span: none → 1m → 5m → 1h → 1d → none.
+    pub fn cycle_span(&mut self) {
+        self.span = match self.span {
+            None => Some(SPANS[0]),
+            Some(span) => SPANS
+                .iter()
+                .position(|s| *s == span)
+                .and_then(|idx| SPANS.get(idx + 1))
+                .copied(),
+        };
+    }
+
+    /// The query described by the current selections.
+    pub fn query(&self) -> TstatsQuery {
+        let mut aggregates = vec![TstatsAggregate {
+            function: "count".to_string(),
+            field: None,
+        }];
+        aggregates.extend(self.aggregates.iter().cloned());
+
+        TstatsQuery {
+            datamodel: self.datamodel.clone(),
+            object: Some(self.object().lineage.clone()),
+            aggregates,
+            split_by: self.split_by.clone(),
+            span: self.span.map(str::to_string),
+            filter: None,
+            summaries_only: self.summaries_only,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datamodel() -> DataModel {
+        DataModel {
+            name: "Network_Traffic".to_string(),
+            displayName: "Network Traffic".to_string(),
+            description: None,
+            owner: "nobody".to_string(),
+            app: "search".to_string(),
+            is_accelerated: true,
+            json_data: Some(
+                serde_json::json!({
+                    "objects": [
+                        {
+                            "objectName": "All_Traffic",
+                            "parentName": "BaseEvent",
+                            "fields": [
+                                { "fieldName": "_time" },
+                                { "fieldName": "action" },
+                                { "fieldName": "src" }
+                            ]
+                        },
+                        { "objectName": "Blocked_Traffic", "parentName": "All_Traffic" }
+                    ]
+                })
+                .to_string(),
+            ),
+            updated: None,
+        }
+    }
+
+    #[test]
+    fn test_builder_generates_query_from_selections() {
+        let mut builder = TstatsBuilder::new(&datamodel()).unwrap();
+
+        builder.next_field();
+        builder.toggle_split_by();
+        builder.next_field();
+        builder.cycle_aggregate();
+        builder.cycle_aggregate();
+        builder.cycle_span();
+        builder.cycle_span();
+
+        assert_eq!(
+            builder.query().to_spl(),
+            "| tstats summariesonly=true count sum(All_Traffic.src) from datamodel=Network_Traffic.All_Traffic by All_Traffic.action _time span=5m"
+        );
+    }
+
+    #[test]
+    fn test_builder_cycles_off_and_resets_on_dataset_change() {
+        let mut builder = TstatsBuilder::new(&datamodel()).unwrap();
+        builder.toggle_split_by();
+        for _ in 0..=FIELD_FUNCTIONS.len() {
+            builder.cycle_aggregate();
+        }
+        assert!(builder.aggregate_for("_time").is_none());
+
+        builder.cycle_object(true);
+
+        assert_eq!(builder.object().lineage, "All_Traffic.Blocked_Traffic");
+        assert!(builder.split_by.is_empty());
+        builder.cycle_object(false);
+        assert_eq!(builder.object().lineage, "All_Traffic");
+    }
+
+    #[test]
+    fn test_builder_requires_datasets() {
+        let mut model = datamodel();
+        model.json_data = Some(r#"{"objects": []}"#.to_string());
+        assert!(TstatsBuilder::new(&model).is_none());
+    }
+}
@@ -28,7 +28,7 @@ use std::path::PathBuf;
     name = "splunk-tui",
     about = "Terminal user interface for Splunk Enterprise",
     version,
-    after_help = "Examples:\n  splunk-tui\n  splunk-tui --profile production\n  splunk-tui --config-path /etc/splunk-tui/config.json\n  splunk-tui --log-dir /var/log/splunk-tui --no-mouse\n  splunk-tui --skip-tutorial\n  splunk-tui --fresh\n"
+    after_help = "Examples:\n  splunk-tui\n  splunk-tui --profile production\n  splunk-tui --config-path /etc/splunk-tui/config.json\n  splunk-tui --log-dir /var/log/splunk-tui --no-mouse\n  splunk-tui --no-color\n  splunk-tui --skip-tutorial\n  splunk-tui --fresh\n  splunk-tui --session incident-1234\n"
 )]
 pub struct Cli {
     /// Config profile name to load
@@ -55,6 +55,10 @@ pub struct Cli {
     #[arg(long)]
     pub no_mouse: bool,
 
+    /// Render without colors (also enabled by a non-empty NO_COLOR)
+    #[arg(long)]
+    pub no_color: bool,
+
     /// Skip the first-run tutorial
     #[arg(long)]
     pub skip_tutorial: bool,
@@ -63,6 +67,10 @@ pub struct Cli {
     #[arg(long)]
     pub fresh: bool,
 
+    /// Resume a saved investigation session by name
+    #[arg(long, value_name = "NAME")]
+    pub session: Option<String>,
+
     /// Enable Prometheus metrics endpoint and bind address (e.g., "localhost:9090")
     ///
     /// When enabled, exposes /metrics endpoint for Prometheus scraping.
@@ -110,12 +118,24 @@ mod tests {
         assert!(cli.no_mouse);
     }
 
+    #[test]
+    fn test_cli_no_color_flag() {
+        let cli = Cli::parse_from(["splunk-tui", "--no-color"]);
+        assert!(cli.no_color);
+    }
+
     #[test]
     fn test_cli_fresh_flag() {
         let cli = Cli::parse_from(["splunk-tui", "--fresh"]);
         assert!(cli.fresh);
     }
 
+    #[test]
+    fn test_cli_session_flag() {
+        let cli = Cli::parse_from(["splunk-tui", "--session", "incident-1234"]);
+        assert_eq!(cli.session.as_deref(), Some("incident-1234"));
+    }
+
     #[test]
     fn test_cli_fresh_default_false() {
         let cli = Cli::parse_from(["splunk-tui"]);
@@ -93,6 +93,10 @@ pub struct ErrorDetails {
 
     /// Authentication recovery information (if applicable)
     pub auth_recovery: Option<AuthRecoveryDetails>,
+
+    /// Remediation steps for Splunk-explained failures (quota, capability, license)
+    #[serde(default)]
+    pub hints: Vec<String>,
 }
 
 impl ErrorDetails {
@@ -114,6 +118,7 @@ impl ErrorDetails {
             timestamp: chrono::Utc::now().to_rfc3339(),
             context: HashMap::new(),
             auth_recovery: Self::should_show_auth_recovery(&failure).then(|| (&failure).into()),
+            hints: error.remediation_hints(),
         };
 
         // Extract additional metadata based on specific error variants
@@ -181,6 +186,7 @@ impl ErrorDetails {
             timestamp: chrono::Utc::now().to_rfc3339(),
             context: HashMap::new(),
             auth_recovery: None,
+            hints: Vec::new(),
         }
     }
 
@@ -291,6 +297,24 @@ mod tests {
         assert!(details.messages.is_empty());
         assert!(details.raw_body.is_none());
         assert!(details.auth_recovery.is_none());
+        assert!(details.hints.is_empty());
+    }
+
+    #[test]
+    fn test_error_details_carries_remediation_hints() {
+        let error = splunk_client::ClientError::IndexNotFound("web".to_string());
+        let details = ErrorDetails::from_client_error(&error);
+        assert!(
+            details
+                .hints
+                .iter()
+                .any(|hint| hint.contains("splunk-cli indexes create web")),
+            "{:?}",
+            details.hints
+        );
+
+        let error = splunk_client::ClientError::NotFound("/services/x".to_string());
+        assert!(ErrorDetails::from_client_error(&error).hints.is_empty());
     }
 
     #[test]
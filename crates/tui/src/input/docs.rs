@@ -92,6 +92,9 @@ fn section_heading(section: Section) -> &'static str {
         Section::Roles => "Roles Screen",
         Section::SearchPeers => "Search Peers Screen",
         Section::Inputs => "Data Inputs Screen",
+        Section::Ingestion => "Ingestion Screen",
+        Section::Sourcetypes => "Sourcetypes Screen",
+        Section::Metadata => "Hosts & Sources Screen",
         Section::Configs => "Configuration Files Screen",
         Section::FiredAlerts => "Fired Alerts Screen",
         Section::Forwarders => "Forwarders Screen",
@@ -101,6 +104,7 @@ fn section_heading(section: Section) -> &'static str {
         Section::DataModels => "Data Models Screen",
         Section::Workload => "Workload Management Screen",
         Section::Shc => "SHC Screen",
+        Section::Rest => "REST Explorer Screen",
         Section::Settings => "Settings Screen",
         Section::Overview => "Overview Screen",
         Section::MultiInstance => "Multi-Instance Dashboard Screen",
@@ -60,6 +60,16 @@ pub(crate) fn help_text() -> String {
 ///
 /// Respects keybinding overrides to show user's custom keys.
 pub fn contextual_help_text(screen: CurrentScreen, input_mode: Option<SearchInputMode>) -> String {
+    contextual_help_text_with_denied(screen, input_mode, &[])
+}
+
+/// Like [`contextual_help_text`], but marks current-screen bindings whose keys
+/// are listed in `denied_keys` as unavailable to the logged-in user.
+pub fn contextual_help_text_with_denied(
+    screen: CurrentScreen,
+    input_mode: Option<SearchInputMode>,
+    denied_keys: &[String],
+) -> String {
     let current_section = screen_to_section(screen);
     let priority_keys = get_priority_keys(screen);
 
@@ -82,6 +92,9 @@ pub fn contextual_help_text(screen: CurrentScreen, input_mode: Option<SearchInpu
             out.push_str(&keys);
             out.push_str(&" ".repeat(padding));
             out.push_str(description);
+            if denied_keys.contains(&keys) {
+                out.push_str(" (no permission)");
+            }
             out.push('\n');
         }
     }
@@ -267,6 +280,9 @@ fn section_title(section: Section) -> &'static str {
         Section::Roles => "Roles Screen:",
         Section::SearchPeers => "Search Peers Screen:",
         Section::Inputs => "Data Inputs Screen:",
+        Section::Ingestion => "Ingestion Screen:",
+        Section::Sourcetypes => "Sourcetypes Screen:",
+        Section::Metadata => "Hosts & Sources Screen:",
         Section::Configs => "Configuration Files Screen:",
         Section::FiredAlerts => "Fired Alerts Screen:",
         Section::Forwarders => "Forwarders Screen:",
@@ -279,6 +295,7 @@ fn section_title(section: Section) -> &'static str {
         Section::Overview => "Overview Screen:",
         Section::MultiInstance => "Multi-Instance Dashboard Screen:",
         Section::Shc => "SHC Screen:",
+        Section::Rest => "REST Explorer Screen:",
     }
 }
 
@@ -339,4 +356,12 @@ mod tests {
             "Help should include 'e' keybinding for error details"
         );
     }
+
+    #[test]
+    fn contextual_help_marks_denied_keys() {
+        let help =
+            contextual_help_text_with_denied(CurrentScreen::Indexes, None, &["d".to_string()]);
+        assert!(help.contains("Delete selected index (no permission)"));
+        assert!(!help.contains("Create new index (no permission)"));
+    }
 }
@@ -45,6 +45,66 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: Some(Action::OpenCommandPalette),
             handles_input: true,
         },
+        Keybinding {
+            section: Section::Global,
+            keys: "Ctrl+B",
+            description: "Server messages",
+            scope: BindingScope::Global,
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('b'),
+                modifiers: KeyModifiers::CONTROL,
+            }),
+            action: Some(Action::OpenServerMessages),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Global,
+            keys: "J",
+            description: "Background jobs tray",
+            scope: BindingScope::Global,
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('J'),
+                modifiers: KeyModifiers::SHIFT,
+            }),
+            action: Some(Action::OpenBackgroundJobs),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Global,
+            keys: "Ctrl+N",
+            description: "Namespace (owner/app)",
+            scope: BindingScope::Global,
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('n'),
+                modifiers: KeyModifiers::CONTROL,
+            }),
+            action: Some(Action::OpenNamespaceSelector),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Global,
+            keys: "Ctrl+O",
+            description: "Saved sessions",
+            scope: BindingScope::Global,
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('o'),
+                modifiers: KeyModifiers::CONTROL,
+            }),
+            action: Some(Action::OpenSessionPicker),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Global,
+            keys: "Ctrl+G",
+            description: "Troubleshooting recipes",
+            scope: BindingScope::Global,
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('g'),
+                modifiers: KeyModifiers::CONTROL,
+            }),
+            action: Some(Action::OpenTroubleshootingRecipes),
+            handles_input: true,
+        },
         Keybinding {
             section: Section::Global,
             keys: "q",
@@ -137,6 +197,15 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: None,
             handles_input: false,
         },
+        Keybinding {
+            section: Section::Global,
+            keys: "g",
+            description: "View a finished search (while its completion toast is shown)",
+            scope: BindingScope::Global,
+            matcher: None, // Handled in app.rs before keymap resolution
+            action: None,
+            handles_input: false,
+        },
         // Undo/Redo (Global)
         Keybinding {
             section: Section::Global,
@@ -289,5 +358,95 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: None,
             handles_input: false,
         },
+        Keybinding {
+            section: Section::Search,
+            keys: "Ctrl+t",
+            description: "Insert next search template",
+            scope: BindingScope::Screen(Search),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Search,
+            keys: "Ctrl+f",
+            description: "Toggle auto-finalize at max results",
+            scope: BindingScope::Screen(Search),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Search,
+            keys: "Ctrl+x",
+            description: "Preview macro expansion",
+            scope: BindingScope::Screen(Search),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Search,
+            keys: "Ctrl+w",
+            description: "Pick search time range",
+            scope: BindingScope::Screen(Search),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Search,
+            keys: "/",
+            description: "Filter results by JSON path (results focused)",
+            scope: BindingScope::Screen(Search),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Search,
+            keys: "Enter",
+            description: "Show result detail (results focused)",
+            scope: BindingScope::Screen(Search),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Search,
+            keys: "v",
+            description: "Toggle timechart chart view (results focused)",
+            scope: BindingScope::Screen(Search),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Search,
+            keys: "c",
+            description: "Compare results with the previous run (results focused)",
+            scope: BindingScope::Screen(Search),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Search,
+            keys: "t",
+            description: "Top values of a result field (results focused)",
+            scope: BindingScope::Screen(Search),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Search,
+            keys: "T",
+            description: "Extend search job TTL to 24h (results focused)",
+            scope: BindingScope::Screen(Search),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
     ]
 }
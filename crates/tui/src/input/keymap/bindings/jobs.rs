@@ -96,6 +96,24 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: Some(Action::EnterSearchMode),
             handles_input: true,
         },
+        Keybinding {
+            section: Section::Jobs,
+            keys: "F",
+            description: "Server-side filter (owner/app/status)",
+            scope: BindingScope::Screen(Jobs),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Jobs,
+            keys: "P",
+            description: "Prune old jobs (age/owner/status)",
+            scope: BindingScope::Screen(Jobs),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
         Keybinding {
             section: Section::Jobs,
             keys: "Space",
@@ -205,5 +223,26 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: None,
             handles_input: false,
         },
+        Keybinding {
+            section: Section::JobDetails,
+            keys: "p",
+            description: "Toggle performance tab",
+            scope: BindingScope::Screen(JobInspect),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('p'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::ToggleJobPerformanceView),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::JobDetails,
+            keys: "t",
+            description: "Extend job TTL to 24h",
+            scope: BindingScope::Screen(JobInspect),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
     ]
 }
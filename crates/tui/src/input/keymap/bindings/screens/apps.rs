@@ -1,7 +1,7 @@
 //! Keybindings for the Apps screen.
 //!
 //! Responsibilities:
-//! - Define bindings for app management (refresh, export, copy, navigate, enable, disable, install, remove).
+//! - Define bindings for app management (refresh, export, copy, navigate, contents, enable, disable, install, remove).
 //!
 //! Does NOT handle:
 //! - Resolving input events or mutating App state.
@@ -109,6 +109,15 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: Some(Action::NavigateUp),
             handles_input: true,
         },
+        Keybinding {
+            section: Section::Apps,
+            keys: "Enter",
+            description: "View objects shipped by selected app",
+            scope: BindingScope::Screen(Apps),
+            matcher: None,
+            action: None, // Handled directly in input handler
+            handles_input: false,
+        },
         Keybinding {
             section: Section::Apps,
             keys: "e",
@@ -136,7 +145,7 @@ pub(super) fn bindings() -> Vec<Keybinding> {
         Keybinding {
             section: Section::Apps,
             keys: "i",
-            description: "Install app from .spl file",
+            description: "Install app from .spl file or Splunkbase",
             scope: BindingScope::Screen(Apps),
             matcher: Some(Matcher::Key {
                 code: KeyCode::Char('i'),
@@ -1,7 +1,8 @@
 //! Keybindings for the Audit screen.
 //!
 //! Responsibilities:
-//! - Define bindings for audit events screen (refresh, export, copy, navigate).
+//! - Define bindings for audit events screen (refresh, export, time range, user activity,
+//!   copy, navigate).
 //!
 //! Does NOT handle:
 //! - Resolving input events or mutating App state.
@@ -28,7 +29,7 @@ pub(super) fn bindings() -> Vec<Keybinding> {
                 code: KeyCode::Char('r'),
                 modifiers: KeyModifiers::NONE,
             }),
-            action: Some(Action::LoadRecentAuditEvents { count: 100 }),
+            action: Some(Action::RefreshAuditEvents),
             handles_input: true,
         },
         Keybinding {
@@ -40,6 +41,24 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: None,
             handles_input: false,
         },
+        Keybinding {
+            section: Section::Audit,
+            keys: "t",
+            description: "Pick time range",
+            scope: BindingScope::Screen(Audit),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Audit,
+            keys: "u",
+            description: "Show selected user's activity (last 7 days)",
+            scope: BindingScope::Screen(Audit),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
         Keybinding {
             section: Section::Audit,
             keys: "Ctrl+c",
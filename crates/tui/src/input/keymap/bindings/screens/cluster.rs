@@ -1,7 +1,7 @@
 //! Keybindings for the Cluster screen.
 //!
 //! Responsibilities:
-//! - Define bindings for cluster info and peer management (refresh, toggle view, navigate, export, copy).
+//! - Define bindings for cluster info, peer management, bucket health, and index compliance (refresh, toggle view, navigate, export, copy).
 //!
 //! Does NOT handle:
 //! - Resolving input events or mutating App state.
@@ -43,6 +43,30 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: Some(Action::ToggleClusterViewMode),
             handles_input: true,
         },
+        Keybinding {
+            section: Section::Cluster,
+            keys: "b",
+            description: "Toggle bucket health view",
+            scope: BindingScope::Screen(Cluster),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('b'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::ToggleClusterBucketsView),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Cluster,
+            keys: "i",
+            description: "Toggle index compliance view",
+            scope: BindingScope::Screen(Cluster),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('i'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::ToggleClusterComplianceView),
+            handles_input: true,
+        },
         Keybinding {
             section: Section::Cluster,
             keys: "j/k or Up/Down",
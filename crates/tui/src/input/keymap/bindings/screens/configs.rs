@@ -1,7 +1,8 @@
 //! Keybindings for the Configs screen.
 //!
 //! Responsibilities:
-//! - Define bindings for config file management (refresh, search stanzas, view details, back, navigate).
+//! - Define bindings for config file management (refresh, search stanzas, view details,
+//!   layered view and diff, back, navigate).
 //!
 //! Does NOT handle:
 //! - Resolving input events or mutating App state.
@@ -52,6 +53,24 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: None,
             handles_input: false,
         },
+        Keybinding {
+            section: Section::Configs,
+            keys: "v",
+            description: "Cycle settings / layers / diff view",
+            scope: BindingScope::Screen(Configs),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Configs,
+            keys: "a",
+            description: "Cycle app diffed against effective settings",
+            scope: BindingScope::Screen(Configs),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
         Keybinding {
             section: Section::Configs,
             keys: "h",
@@ -1,7 +1,7 @@
 //! Keybindings for the Dashboards screen.
 //!
 //! Responsibilities:
-//! - Define bindings for dashboard viewing (refresh, navigate).
+//! - Define bindings for dashboard viewing (refresh, source preview, navigate).
 //!
 //! Does NOT handle:
 //! - Resolving input events or mutating App state.
@@ -31,6 +31,24 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: Some(Action::RefreshDashboards),
             handles_input: true,
         },
+        Keybinding {
+            section: Section::Dashboards,
+            keys: "Enter",
+            description: "View dashboard source",
+            scope: BindingScope::Screen(Dashboards),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Dashboards,
+            keys: "p",
+            description: "View/edit permissions (owner, sharing, roles)",
+            scope: BindingScope::Screen(Dashboards),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
         Keybinding {
             section: Section::Dashboards,
             keys: "L",
@@ -1,7 +1,7 @@
 //! Keybindings for the Data Models screen.
 //!
 //! Responsibilities:
-//! - Define bindings for data model viewing (refresh, navigate).
+//! - Define bindings for data model viewing (refresh, navigate, tstats builder).
 //!
 //! Does NOT handle:
 //! - Resolving input events or mutating App state.
@@ -43,6 +43,15 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: Some(Action::LoadMoreDataModels),
             handles_input: true,
         },
+        Keybinding {
+            section: Section::DataModels,
+            keys: "t",
+            description: "Build tstats query for selected data model",
+            scope: BindingScope::Screen(DataModels),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
         Keybinding {
             section: Section::DataModels,
             keys: "j/k or Up/Down",
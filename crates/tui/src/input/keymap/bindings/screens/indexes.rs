@@ -1,7 +1,7 @@
 //! Keybindings for the Indexes screen.
 //!
 //! Responsibilities:
-//! - Define bindings for index management (refresh, view, export, create, modify, delete).
+//! - Define bindings for index management (refresh, view, export, create, onboard, modify, delete).
 //!
 //! Does NOT handle:
 //! - Resolving input events or mutating App state.
@@ -130,6 +130,18 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: Some(Action::OpenCreateIndexDialog),
             handles_input: true,
         },
+        Keybinding {
+            section: Section::Indexes,
+            keys: "w",
+            description: "Onboard data (index, sourcetype, HEC token)",
+            scope: BindingScope::Screen(Indexes),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('w'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::OpenOnboardingWizard),
+            handles_input: true,
+        },
         Keybinding {
             section: Section::Indexes,
             keys: "m",
@@ -154,5 +166,41 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: None,
             handles_input: false,
         },
+        Keybinding {
+            section: Section::Indexes,
+            keys: "s",
+            description: "Sample events",
+            scope: BindingScope::Screen(Indexes),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('s'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Indexes,
+            keys: "b",
+            description: "Roll hot buckets of selected index",
+            scope: BindingScope::Screen(Indexes),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('b'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Indexes,
+            keys: "B",
+            description: "Start data rebalance for selected index",
+            scope: BindingScope::Screen(Indexes),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('B'),
+                modifiers: KeyModifiers::SHIFT,
+            }),
+            action: None,
+            handles_input: false,
+        },
     ]
 }
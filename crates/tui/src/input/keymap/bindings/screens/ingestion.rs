@@ -0,0 +1,116 @@
+//! Keybindings for the Ingestion screen.
+//!
+//! Responsibilities:
+//! - Define bindings for ingestion stats (refresh, sort, copy, navigate).
+//!
+//! Does NOT handle:
+//! - Resolving input events or mutating App state.
+//!
+//! Invariants:
+//! - Ordering matches the rendered help/docs expectations.
+
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::action::Action;
+use crate::app::CurrentScreen;
+use crate::input::keymap::{BindingScope, Keybinding, Matcher, Section};
+
+pub(super) fn bindings() -> Vec<Keybinding> {
+    use CurrentScreen::Ingestion;
+
+    vec![
+        Keybinding {
+            section: Section::Ingestion,
+            keys: "r",
+            description: "Refresh ingestion stats",
+            scope: BindingScope::Screen(Ingestion),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('r'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::LoadIngestionStats),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Ingestion,
+            keys: "s",
+            description: "Cycle sort column",
+            scope: BindingScope::Screen(Ingestion),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('s'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::CycleSortColumn),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Ingestion,
+            keys: "S",
+            description: "Reverse sort direction",
+            scope: BindingScope::Screen(Ingestion),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('S'),
+                modifiers: KeyModifiers::SHIFT,
+            }),
+            action: Some(Action::ToggleSortDirection),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Ingestion,
+            keys: "Ctrl+c",
+            description: "Copy selected sourcetype",
+            scope: BindingScope::Screen(Ingestion),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Ingestion,
+            keys: "j/k or Up/Down",
+            description: "Navigate list",
+            scope: BindingScope::Screen(Ingestion),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('j'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::NavigateDown),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Ingestion,
+            keys: "j/k or Up/Down",
+            description: "Navigate list",
+            scope: BindingScope::Screen(Ingestion),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('k'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::NavigateUp),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Ingestion,
+            keys: "j/k or Up/Down",
+            description: "Navigate list",
+            scope: BindingScope::Screen(Ingestion),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Down,
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::NavigateDown),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Ingestion,
+            keys: "j/k or Up/Down",
+            description: "Navigate list",
+            scope: BindingScope::Screen(Ingestion),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Up,
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::NavigateUp),
+            handles_input: true,
+        },
+    ]
+}
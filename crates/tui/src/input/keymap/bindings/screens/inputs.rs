@@ -1,7 +1,8 @@
 //! Keybindings for the Inputs screen.
 //!
 //! Responsibilities:
-//! - Define bindings for input management (refresh, enable, disable, copy, navigate).
+//! - Define bindings for input management (refresh, add, enable, disable, throughput,
+//!   HTTP inputs view, token reveal, copy, navigate).
 //!
 //! Does NOT handle:
 //! - Resolving input events or mutating App state.
@@ -43,10 +44,22 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: Some(Action::LoadMoreInputs),
             handles_input: true,
         },
+        Keybinding {
+            section: Section::Inputs,
+            keys: "a",
+            description: "Add monitor/TCP/UDP input",
+            scope: BindingScope::Screen(Inputs),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('a'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: None, // Handled by input handler
+            handles_input: true,
+        },
         Keybinding {
             section: Section::Inputs,
             keys: "e",
-            description: "Enable input",
+            description: "Enable input or HEC token",
             scope: BindingScope::Screen(Inputs),
             matcher: Some(Matcher::Key {
                 code: KeyCode::Char('e'),
@@ -58,7 +71,7 @@ pub(super) fn bindings() -> Vec<Keybinding> {
         Keybinding {
             section: Section::Inputs,
             keys: "d",
-            description: "Disable input",
+            description: "Disable input or HEC token",
             scope: BindingScope::Screen(Inputs),
             matcher: Some(Matcher::Key {
                 code: KeyCode::Char('d'),
@@ -67,10 +80,46 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: None, // Handled by input handler
             handles_input: true,
         },
+        Keybinding {
+            section: Section::Inputs,
+            keys: "t",
+            description: "Load throughput (last hour)",
+            scope: BindingScope::Screen(Inputs),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('t'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: None, // Handled by input handler
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Inputs,
+            keys: "h",
+            description: "Toggle HTTP inputs (HEC tokens)",
+            scope: BindingScope::Screen(Inputs),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('h'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::ToggleInputsHttpView),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Inputs,
+            keys: "v",
+            description: "Reveal/mask selected HEC token",
+            scope: BindingScope::Screen(Inputs),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('v'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: None, // Handled by input handler
+            handles_input: true,
+        },
         Keybinding {
             section: Section::Inputs,
             keys: "Ctrl+c",
-            description: "Copy selected input name",
+            description: "Copy selected input or token name",
             scope: BindingScope::Screen(Inputs),
             matcher: None,
             action: None,
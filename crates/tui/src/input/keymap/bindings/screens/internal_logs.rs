@@ -1,7 +1,7 @@
 //! Keybindings for the Internal Logs screen.
 //!
 //! Responsibilities:
-//! - Define bindings for internal log viewing (refresh, export, auto-refresh toggle, copy, navigate).
+//! - Define bindings for internal log viewing (refresh, export, auto-refresh toggle, follow, filters, copy, navigate).
 //!
 //! Does NOT handle:
 //! - Resolving input events or mutating App state.
@@ -61,6 +61,51 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: None,
             handles_input: false,
         },
+        Keybinding {
+            section: Section::InternalLogs,
+            keys: "t",
+            description: "Pick time range",
+            scope: BindingScope::Screen(InternalLogs),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::InternalLogs,
+            keys: "F",
+            description: "Follow new logs (live tail)",
+            scope: BindingScope::Screen(InternalLogs),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::InternalLogs,
+            keys: "p",
+            description: "Pause/resume follow",
+            scope: BindingScope::Screen(InternalLogs),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::InternalLogs,
+            keys: "l",
+            description: "Cycle level filter",
+            scope: BindingScope::Screen(InternalLogs),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::InternalLogs,
+            keys: "/",
+            description: "Filter by component",
+            scope: BindingScope::Screen(InternalLogs),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
         Keybinding {
             section: Section::InternalLogs,
             keys: "Ctrl+c",
@@ -47,6 +47,18 @@ pub fn bindings() -> Vec<Keybinding> {
             action: None, // Handled directly in app/input/macros.rs (needs selected macro)
             handles_input: false,
         },
+        Keybinding {
+            section: Section::Macros,
+            keys: "p",
+            description: "View/edit permissions (owner, sharing, roles)",
+            scope: BindingScope::Screen(CurrentScreen::Macros),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('p'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: None, // Handled directly in app/input/macros.rs (needs selected macro)
+            handles_input: false,
+        },
         Keybinding {
             section: Section::Macros,
             keys: "y",
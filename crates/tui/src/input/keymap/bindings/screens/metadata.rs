@@ -0,0 +1,104 @@
+//! Keybindings for the Hosts & Sources screen.
+//!
+//! Responsibilities:
+//! - Define bindings for hosts & sources (refresh, toggle, copy, navigate).
+//!
+//! Does NOT handle:
+//! - Resolving input events or mutating App state.
+//!
+//! Invariants:
+//! - Ordering matches the rendered help/docs expectations.
+
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::action::Action;
+use crate::app::CurrentScreen;
+use crate::input::keymap::{BindingScope, Keybinding, Matcher, Section};
+
+pub(super) fn bindings() -> Vec<Keybinding> {
+    use CurrentScreen::Metadata;
+
+    vec![
+        Keybinding {
+            section: Section::Metadata,
+            keys: "r",
+            description: "Refresh hosts or sources",
+            scope: BindingScope::Screen(Metadata),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('r'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::RefreshMetadata),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Metadata,
+            keys: "t",
+            description: "Toggle hosts/sources",
+            scope: BindingScope::Screen(Metadata),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('t'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::ToggleMetadataType),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Metadata,
+            keys: "Ctrl+c",
+            description: "Copy selected host or source",
+            scope: BindingScope::Screen(Metadata),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Metadata,
+            keys: "j/k or Up/Down",
+            description: "Navigate list",
+            scope: BindingScope::Screen(Metadata),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('j'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::NavigateDown),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Metadata,
+            keys: "j/k or Up/Down",
+            description: "Navigate list",
+            scope: BindingScope::Screen(Metadata),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('k'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::NavigateUp),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Metadata,
+            keys: "j/k or Up/Down",
+            description: "Navigate list",
+            scope: BindingScope::Screen(Metadata),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Down,
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::NavigateDown),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Metadata,
+            keys: "j/k or Up/Down",
+            description: "Navigate list",
+            scope: BindingScope::Screen(Metadata),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Up,
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::NavigateUp),
+            handles_input: true,
+        },
+    ]
+}
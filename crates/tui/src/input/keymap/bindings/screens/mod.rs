@@ -16,14 +16,18 @@ mod configs;
 mod dashboards;
 mod datamodels;
 mod indexes;
+mod ingestion;
 mod inputs;
 mod internal_logs;
 mod macros;
+mod metadata;
 mod monitoring;
+mod rest;
 mod saved_searches;
 mod search_peers;
 mod settings;
 mod shc;
+mod sourcetypes;
 mod status;
 mod users;
 mod workload;
@@ -46,6 +50,10 @@ pub(super) fn bindings() -> Vec<Keybinding> {
     bindings.extend(dashboards::bindings());
     bindings.extend(datamodels::bindings());
     bindings.extend(inputs::bindings());
+    bindings.extend(ingestion::bindings());
+    bindings.extend(sourcetypes::bindings());
+    bindings.extend(metadata::bindings());
+    bindings.extend(rest::bindings());
     bindings.extend(settings::bindings());
     bindings.extend(monitoring::bindings());
     bindings.extend(workload::bindings());
@@ -287,6 +287,15 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: None,
             handles_input: false,
         },
+        Keybinding {
+            section: Section::Lookups,
+            keys: "Enter",
+            description: "View/edit lookup contents",
+            scope: BindingScope::Screen(Lookups),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
         Keybinding {
             section: Section::Lookups,
             keys: "Ctrl+c",
@@ -363,5 +372,14 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: None,
             handles_input: false,
         },
+        Keybinding {
+            section: Section::Lookups,
+            keys: "p",
+            description: "View/edit permissions (owner, sharing, roles)",
+            scope: BindingScope::Screen(Lookups),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
     ]
 }
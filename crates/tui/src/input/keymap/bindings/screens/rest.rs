@@ -0,0 +1,119 @@
+//! Keybindings for the REST explorer screen.
+//!
+//! Responsibilities:
+//! - Define bindings for the REST explorer (new request, resend, edit, scroll, navigate).
+//!
+//! Does NOT handle:
+//! - Resolving input events or mutating App state.
+//!
+//! Invariants:
+//! - Ordering matches the rendered help/docs expectations.
+
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::action::Action;
+use crate::app::CurrentScreen;
+use crate::input::keymap::{BindingScope, Keybinding, Matcher, Section};
+
+pub(super) fn bindings() -> Vec<Keybinding> {
+    use CurrentScreen::Rest;
+
+    vec![
+        Keybinding {
+            section: Section::Rest,
+            keys: "n",
+            description: "New request",
+            scope: BindingScope::Screen(Rest),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('n'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::OpenRestRequestDialog),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Rest,
+            keys: "Enter",
+            description: "Resend selected request",
+            scope: BindingScope::Screen(Rest),
+            matcher: None,
+            action: None, // Handled directly in input handler
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Rest,
+            keys: "e",
+            description: "Edit selected request",
+            scope: BindingScope::Screen(Rest),
+            matcher: None,
+            action: None, // Handled directly in input handler
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Rest,
+            keys: "Ctrl+c",
+            description: "Copy response body",
+            scope: BindingScope::Screen(Rest),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Rest,
+            keys: "PgDn/PgUp",
+            description: "Scroll response",
+            scope: BindingScope::Screen(Rest),
+            matcher: None,
+            action: None, // Handled by the global page bindings
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Rest,
+            keys: "j/k or Up/Down",
+            description: "Navigate history",
+            scope: BindingScope::Screen(Rest),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('j'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::NavigateDown),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Rest,
+            keys: "j/k or Up/Down",
+            description: "Navigate history",
+            scope: BindingScope::Screen(Rest),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('k'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::NavigateUp),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Rest,
+            keys: "j/k or Up/Down",
+            description: "Navigate history",
+            scope: BindingScope::Screen(Rest),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Down,
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::NavigateDown),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Rest,
+            keys: "j/k or Up/Down",
+            description: "Navigate history",
+            scope: BindingScope::Screen(Rest),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Up,
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::NavigateUp),
+            handles_input: true,
+        },
+    ]
+}
@@ -1,7 +1,8 @@
 //! Keybindings for the Saved Searches screen.
 //!
 //! Responsibilities:
-//! - Define bindings for saved search management (refresh, export, copy, run, navigate).
+//! - Define bindings for saved search management (refresh, export, copy, run, navigate,
+//!   bulk enable/disable/reassign).
 //!
 //! Does NOT handle:
 //! - Resolving input events or mutating App state.
@@ -145,5 +146,50 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: None,
             handles_input: false,
         },
+        Keybinding {
+            section: Section::SavedSearches,
+            keys: "p",
+            description: "View/edit permissions (owner, sharing, roles)",
+            scope: BindingScope::Screen(SavedSearches),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::SavedSearches,
+            keys: "Space",
+            description: "Toggle saved search selection",
+            scope: BindingScope::Screen(SavedSearches),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::SavedSearches,
+            keys: "E",
+            description: "Enable selected saved search(es)",
+            scope: BindingScope::Screen(SavedSearches),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::SavedSearches,
+            keys: "D",
+            description: "Disable selected saved search(es)",
+            scope: BindingScope::Screen(SavedSearches),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::SavedSearches,
+            keys: "O",
+            description: "Reassign owner of selected saved search(es)",
+            scope: BindingScope::Screen(SavedSearches),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
     ]
 }
@@ -1,7 +1,8 @@
 //! Keybindings for the Search Peers screen.
 //!
 //! Responsibilities:
-//! - Define bindings for search peer management (refresh, export, copy, navigate).
+//! - Define bindings for search peer management (refresh, add, remove, quarantine,
+//!   export, copy, navigate).
 //!
 //! Does NOT handle:
 //! - Resolving input events or mutating App state.
@@ -34,6 +35,42 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             }),
             handles_input: true,
         },
+        Keybinding {
+            section: Section::SearchPeers,
+            keys: "a",
+            description: "Add search peer",
+            scope: BindingScope::Screen(SearchPeers),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('a'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: None, // Handled directly in input handler
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::SearchPeers,
+            keys: "x",
+            description: "Remove selected search peer",
+            scope: BindingScope::Screen(SearchPeers),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('x'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: None, // Handled directly in input handler
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::SearchPeers,
+            keys: "Q",
+            description: "Quarantine/unquarantine selected peer",
+            scope: BindingScope::Screen(SearchPeers),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('Q'),
+                modifiers: KeyModifiers::SHIFT,
+            }),
+            action: None, // Handled directly in input handler
+            handles_input: false,
+        },
         Keybinding {
             section: Section::SearchPeers,
             keys: "Ctrl+e",
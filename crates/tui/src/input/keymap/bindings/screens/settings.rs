@@ -1,7 +1,7 @@
 //! Keybindings for the Settings screen.
 //!
 //! Responsibilities:
-//! - Define bindings for settings management (cycle theme, toggle auto-refresh, sort, clear history, reload, profile management).
+//! - Define bindings for settings management (cycle theme, clipboard backend, toggle auto-refresh, sort, clear history, log highlights, reload, profile management).
 //!
 //! Does NOT handle:
 //! - Resolving input events or mutating App state.
@@ -43,6 +43,42 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: Some(Action::CycleTheme),
             handles_input: true,
         },
+        Keybinding {
+            section: Section::Settings,
+            keys: "C",
+            description: "Toggle no-color mode",
+            scope: BindingScope::Screen(Settings),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Settings,
+            keys: "b",
+            description: "Cycle clipboard backend",
+            scope: BindingScope::Screen(Settings),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Settings,
+            keys: "N",
+            description: "Cycle notifications (off/desktop/command)",
+            scope: BindingScope::Screen(Settings),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Settings,
+            keys: "H",
+            description: "Edit health check thresholds",
+            scope: BindingScope::Screen(Settings),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
         Keybinding {
             section: Section::Settings,
             keys: "a",
@@ -79,6 +115,42 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: None,
             handles_input: false,
         },
+        Keybinding {
+            section: Section::Settings,
+            keys: "v",
+            description: "Toggle result renderers",
+            scope: BindingScope::Screen(Settings),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Settings,
+            keys: "h",
+            description: "Toggle internal log highlights",
+            scope: BindingScope::Screen(Settings),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Settings,
+            keys: "m",
+            description: "Cycle log spike threshold",
+            scope: BindingScope::Screen(Settings),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Settings,
+            keys: "g",
+            description: "Cycle log repeat threshold",
+            scope: BindingScope::Screen(Settings),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
         Keybinding {
             section: Section::Settings,
             keys: "r",
@@ -0,0 +1,104 @@
+//! Keybindings for the Sourcetypes screen.
+//!
+//! Responsibilities:
+//! - Define bindings for sourcetypes (refresh, extraction tester, copy, navigate).
+//!
+//! Does NOT handle:
+//! - Resolving input events or mutating App state.
+//!
+//! Invariants:
+//! - Ordering matches the rendered help/docs expectations.
+
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::action::Action;
+use crate::app::CurrentScreen;
+use crate::input::keymap::{BindingScope, Keybinding, Matcher, Section};
+
+pub(super) fn bindings() -> Vec<Keybinding> {
+    use CurrentScreen::Sourcetypes;
+
+    vec![
+        Keybinding {
+            section: Section::Sourcetypes,
+            keys: "r",
+            description: "Refresh sourcetypes",
+            scope: BindingScope::Screen(Sourcetypes),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('r'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::LoadSourcetypes),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Sourcetypes,
+            keys: "x",
+            description: "Test field extractions on sample events",
+            scope: BindingScope::Screen(Sourcetypes),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('x'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::OpenFieldExtractionTester),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Sourcetypes,
+            keys: "Ctrl+c",
+            description: "Copy selected sourcetype",
+            scope: BindingScope::Screen(Sourcetypes),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Sourcetypes,
+            keys: "j/k or Up/Down",
+            description: "Navigate list",
+            scope: BindingScope::Screen(Sourcetypes),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('j'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::NavigateDown),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Sourcetypes,
+            keys: "j/k or Up/Down",
+            description: "Navigate list",
+            scope: BindingScope::Screen(Sourcetypes),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('k'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::NavigateUp),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Sourcetypes,
+            keys: "j/k or Up/Down",
+            description: "Navigate list",
+            scope: BindingScope::Screen(Sourcetypes),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Down,
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::NavigateDown),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Sourcetypes,
+            keys: "j/k or Up/Down",
+            description: "Navigate list",
+            scope: BindingScope::Screen(Sourcetypes),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Up,
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::NavigateUp),
+            handles_input: true,
+        },
+    ]
+}
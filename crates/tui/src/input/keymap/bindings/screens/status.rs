@@ -32,6 +32,18 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: Some(Action::LoadHealth),
             handles_input: true,
         },
+        Keybinding {
+            section: Section::Health,
+            keys: "u",
+            description: "Toggle resources view",
+            scope: BindingScope::Screen(Health),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('u'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::ToggleHealthResourcesView),
+            handles_input: true,
+        },
         Keybinding {
             section: Section::Health,
             keys: "Ctrl+e",
@@ -50,6 +62,15 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: None,
             handles_input: false,
         },
+        Keybinding {
+            section: Section::Health,
+            keys: "R",
+            description: "Restart splunkd",
+            scope: BindingScope::Screen(Health),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
         // License
         Keybinding {
             section: Section::License,
@@ -72,6 +93,15 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: None,
             handles_input: false,
         },
+        Keybinding {
+            section: Section::License,
+            keys: "t",
+            description: "Export usage trend (CSV)",
+            scope: BindingScope::Screen(License),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
         Keybinding {
             section: Section::License,
             keys: "Ctrl+c",
@@ -53,6 +53,24 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: None,
             handles_input: false,
         },
+        Keybinding {
+            section: Section::Users,
+            keys: "U",
+            description: "Unlock selected locked-out user",
+            scope: BindingScope::Screen(Users),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
+        Keybinding {
+            section: Section::Users,
+            keys: "o",
+            description: "Find objects owned by deleted users",
+            scope: BindingScope::Screen(Users),
+            matcher: None,
+            action: None,
+            handles_input: false,
+        },
         Keybinding {
             section: Section::Users,
             keys: "Ctrl+c",
@@ -135,6 +153,30 @@ pub(super) fn bindings() -> Vec<Keybinding> {
             action: Some(Action::LoadMoreRoles),
             handles_input: true,
         },
+        Keybinding {
+            section: Section::Roles,
+            keys: "u",
+            description: "Toggle quota usage view",
+            scope: BindingScope::Screen(Roles),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('u'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::ToggleRoleUsageView),
+            handles_input: true,
+        },
+        Keybinding {
+            section: Section::Roles,
+            keys: "x",
+            description: "Toggle capability matrix view",
+            scope: BindingScope::Screen(Roles),
+            matcher: Some(Matcher::Key {
+                code: KeyCode::Char('x'),
+                modifiers: KeyModifiers::NONE,
+            }),
+            action: Some(Action::ToggleRoleMatrixView),
+            handles_input: true,
+        },
         Keybinding {
             section: Section::Roles,
             keys: "c",
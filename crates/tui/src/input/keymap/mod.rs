@@ -41,6 +41,9 @@ pub enum Section {
     Roles,
     SearchPeers,
     Inputs,
+    Ingestion,
+    Sourcetypes,
+    Metadata,
     Configs,
     FiredAlerts,
     Forwarders,
@@ -50,6 +53,7 @@ pub enum Section {
     DataModels,
     Workload,
     Shc,
+    Rest,
     Settings,
     Overview,
     MultiInstance,
@@ -109,7 +113,7 @@ pub fn keybindings() -> Vec<Keybinding> {
 type ScreenHints = Vec<(&'static str, &'static str)>;
 
 /// Type alias for the cache array: tuple of (screen, hints) for each screen.
-type FooterHintsCache = [(CurrentScreen, ScreenHints); 28];
+type FooterHintsCache = [(CurrentScreen, ScreenHints); 32];
 
 /// Cache for footer hints to avoid per-frame allocations.
 /// Maps each screen to its pre-computed hints vector using a fixed-size array.
@@ -144,6 +148,10 @@ static FOOTER_HINTS_CACHE: LazyLock<FooterHintsCache> = LazyLock::new(|| {
         (screens[25], compute_footer_hints(screens[25])),
         (screens[26], compute_footer_hints(screens[26])),
         (screens[27], compute_footer_hints(screens[27])),
+        (screens[28], compute_footer_hints(screens[28])),
+        (screens[29], compute_footer_hints(screens[29])),
+        (screens[30], compute_footer_hints(screens[30])),
+        (screens[31], compute_footer_hints(screens[31])),
     ]
 });
 
@@ -155,7 +163,7 @@ static FOOTER_HINTS_CACHE: LazyLock<FooterHintsCache> = LazyLock::new(|| {
 /// The count of screens in this array should match the number of variants
 /// in the `CurrentScreen` enum. This is verified by drift detection tests.
 #[doc(hidden)]
-pub fn all_screens() -> [CurrentScreen; 28] {
+pub fn all_screens() -> [CurrentScreen; 32] {
     [
         CurrentScreen::Search,
         CurrentScreen::Indexes,
@@ -173,6 +181,9 @@ pub fn all_screens() -> [CurrentScreen; 28] {
         CurrentScreen::Roles,
         CurrentScreen::SearchPeers,
         CurrentScreen::Inputs,
+        CurrentScreen::Ingestion,
+        CurrentScreen::Sourcetypes,
+        CurrentScreen::Metadata,
         CurrentScreen::Configs,
         CurrentScreen::Settings,
         CurrentScreen::Overview,
@@ -185,6 +196,7 @@ pub fn all_screens() -> [CurrentScreen; 28] {
         CurrentScreen::DataModels,
         CurrentScreen::WorkloadManagement,
         CurrentScreen::Shc,
+        CurrentScreen::Rest,
     ]
 }
 
@@ -211,9 +223,9 @@ fn compute_footer_hints(screen: CurrentScreen) -> ScreenHints {
             continue;
         }
 
-        // Skip entries with duplicate descriptions (e.g., j/k navigation)
-        let key = (binding.keys, binding.description);
-        if !seen.insert(key) {
+        // Skip repeated keys (e.g., j/k navigation, or Enter documented for
+        // both search input modes); the first binding is the primary hint
+        if !seen.insert(binding.keys) {
             continue;
         }
 
@@ -277,6 +289,9 @@ pub fn sections_in_order() -> &'static [Section] {
         Section::Roles,
         Section::SearchPeers,
         Section::Inputs,
+        Section::Ingestion,
+        Section::Sourcetypes,
+        Section::Metadata,
         Section::Configs,
         Section::FiredAlerts,
         Section::Forwarders,
@@ -286,6 +301,7 @@ pub fn sections_in_order() -> &'static [Section] {
         Section::DataModels,
         Section::Workload,
         Section::Shc,
+        Section::Rest,
         Section::Settings,
         Section::Overview,
         Section::MultiInstance,
@@ -344,6 +360,9 @@ pub fn screen_to_section(screen: CurrentScreen) -> Section {
         CurrentScreen::Roles => Section::Roles,
         CurrentScreen::SearchPeers => Section::SearchPeers,
         CurrentScreen::Inputs => Section::Inputs,
+        CurrentScreen::Ingestion => Section::Ingestion,
+        CurrentScreen::Sourcetypes => Section::Sourcetypes,
+        CurrentScreen::Metadata => Section::Metadata,
         CurrentScreen::Configs => Section::Configs,
         CurrentScreen::FiredAlerts => Section::FiredAlerts,
         CurrentScreen::Forwarders => Section::Forwarders,
@@ -353,6 +372,7 @@ pub fn screen_to_section(screen: CurrentScreen) -> Section {
         CurrentScreen::DataModels => Section::DataModels,
         CurrentScreen::WorkloadManagement => Section::Workload,
         CurrentScreen::Shc => Section::Shc,
+        CurrentScreen::Rest => Section::Rest,
         CurrentScreen::Settings => Section::Settings,
         CurrentScreen::Overview => Section::Overview,
         CurrentScreen::MultiInstance => Section::MultiInstance,
@@ -386,6 +406,7 @@ fn shorten_description(desc: &'static str) -> &'static str {
         "Export roles" => "Export",
         "Refresh KVStore status" => "Refresh",
         "Export KVStore status" => "Export",
+        "Export usage trend (CSV)" => "Trend",
         "Copy KVStore status" => "Copy",
         "Cycle sort column" => "Sort",
         "Toggle sort direction" => "Direction",
@@ -396,11 +417,18 @@ fn shorten_description(desc: &'static str) -> &'static str {
         "Inspect job" => "Inspect",
         "Back to jobs" => "Back",
         "View index details" => "Details",
+        "Onboard data (index, sourcetype, HEC token)" => "Onboard",
         "Toggle peers view" => "Peers",
+        "Toggle bucket health view" => "Buckets",
+        "Toggle index compliance view" => "Compliance",
+        "Toggle quota usage view" => "Usage",
         "Run selected search" => "Run",
         "Toggle auto-refresh" => "Auto",
         "Enable selected app" => "Enable",
         "Disable selected app" => "Disable",
+        "Add search peer" => "Add",
+        "Remove selected search peer" => "Remove",
+        "Quarantine/unquarantine selected peer" => "Quarantine",
         "Create new role" => "Create",
         "Modify selected role" => "Modify",
         "Delete selected role" => "Delete",
@@ -408,6 +436,7 @@ fn shorten_description(desc: &'static str) -> &'static str {
         "Cycle theme" => "Theme",
         "Copy selected SID" => "Copy SID",
         "Copy job SID" => "Copy SID",
+        "Extend job TTL to 24h" => "Extend TTL",
         "Copy selected index name" => "Copy",
         "Copy cluster ID" => "Copy",
         "Copy selected saved search name" => "Copy",
@@ -418,6 +447,8 @@ fn shorten_description(desc: &'static str) -> &'static str {
         "Copy query (or current result)" => "Copy",
         "Copy to clipboard" => "Copy",
         "Navigate list" => "Navigate",
+        "Build tstats query for selected data model" => "Tstats",
+        "View/edit lookup contents" => "Edit",
         "Navigate peers list" => "Navigate",
         "Navigate history (query)" => "History",
         "Scroll results (while typing)" => "Scroll",
@@ -460,11 +491,11 @@ pub(crate) fn get_priority_keys(screen: CurrentScreen) -> &'static [&'static str
     match screen {
         CurrentScreen::Search => &["Enter", "Ctrl+e", "PgDn", "PgUp", "Ctrl+j/k", "Home", "End"],
         CurrentScreen::Jobs => &["r", "/", "s", "a", "Space", "c", "d", "Enter"],
-        CurrentScreen::JobInspect => &["Esc", "Ctrl+c"],
-        CurrentScreen::Indexes => &["r", "Enter", "j/k or Up/Down"],
+        CurrentScreen::JobInspect => &["Esc", "Ctrl+c", "t"],
+        CurrentScreen::Indexes => &["r", "Enter", "j/k or Up/Down", "Ctrl+c", "s"],
         CurrentScreen::Cluster => &["r", "p", "j/k or Up/Down"],
         CurrentScreen::Health => &["r"],
-        CurrentScreen::License => &["r"],
+        CurrentScreen::License => &["r", "t"],
         CurrentScreen::Kvstore => &["r"],
         CurrentScreen::SavedSearches => &["r", "Enter", "j/k or Up/Down"],
         CurrentScreen::Macros => &["r", "e", "n", "d", "Ctrl+c", "j/k or Up/Down"],
@@ -472,20 +503,24 @@ pub(crate) fn get_priority_keys(screen: CurrentScreen) -> &'static [&'static str
         CurrentScreen::Apps => &["r", "e", "d", "j/k or Up/Down"],
         CurrentScreen::Users => &["r", "j/k or Up/Down"],
         CurrentScreen::Roles => &["r", "c", "m", "d", "j/k or Up/Down"],
-        CurrentScreen::SearchPeers => &["r", "j/k or Up/Down", "Ctrl+e"],
+        CurrentScreen::SearchPeers => &["r", "a", "x", "Q", "j/k or Up/Down"],
         CurrentScreen::Settings => &["t", "a", "s", "d", "c", "r"],
         CurrentScreen::Overview => &["r", "Ctrl+e", "Ctrl+c"],
         CurrentScreen::MultiInstance => &["r", "j/k or Up/Down", "Ctrl+e", "Ctrl+c"],
-        CurrentScreen::Inputs => &["r", "e", "d", "j/k or Up/Down"],
+        CurrentScreen::Inputs => &["r", "e", "d", "h", "j/k or Up/Down"],
+        CurrentScreen::Ingestion => &["r", "s", "S", "j/k or Up/Down", "Ctrl+c"],
+        CurrentScreen::Sourcetypes => &["r", "x", "j/k or Up/Down", "Ctrl+c"],
+        CurrentScreen::Metadata => &["r", "t", "j/k or Up/Down", "Ctrl+c"],
         CurrentScreen::Configs => &["r", "/", "Enter", "h", "j/k or Up/Down"],
         CurrentScreen::FiredAlerts => &["r", "j/k or Up/Down", "Ctrl+c"],
         CurrentScreen::Forwarders => &["r", "j/k or Up/Down", "Ctrl+e", "Ctrl+c"],
-        CurrentScreen::Lookups => &["r", "j/k or Up/Down", "Ctrl+e", "Ctrl+c"],
+        CurrentScreen::Lookups => &["r", "Enter", "j/k or Up/Down", "Ctrl+e", "Ctrl+c"],
         CurrentScreen::Audit => &["r", "j/k or Up/Down", "Ctrl+e", "Ctrl+c"],
         CurrentScreen::Dashboards => &["r", "j/k or Up/Down"],
-        CurrentScreen::DataModels => &["r", "j/k or Up/Down"],
+        CurrentScreen::DataModels => &["r", "t", "j/k or Up/Down"],
         CurrentScreen::WorkloadManagement => &["r", "w", "j/k or Up/Down", "Ctrl+e"],
         CurrentScreen::Shc => &["r", "m", "j/k or Up/Down", "Ctrl+e"],
+        CurrentScreen::Rest => &["n", "Enter", "e", "j/k or Up/Down", "PgDn/PgUp"],
     }
 }
 
@@ -605,6 +640,9 @@ mod tests {
             CurrentScreen::Overview,
             CurrentScreen::MultiInstance,
             CurrentScreen::Inputs,
+            CurrentScreen::Ingestion,
+            CurrentScreen::Sourcetypes,
+            CurrentScreen::Metadata,
             CurrentScreen::Configs,
             CurrentScreen::FiredAlerts,
             CurrentScreen::Forwarders,
@@ -614,6 +652,7 @@ mod tests {
             CurrentScreen::DataModels,
             CurrentScreen::WorkloadManagement,
             CurrentScreen::Shc,
+            CurrentScreen::Rest,
         ];
 
         for screen in all_screens {
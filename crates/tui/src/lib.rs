@@ -28,8 +28,10 @@ pub mod ux_telemetry;
 
 pub mod input;
 pub mod onboarding;
+pub mod operation_progress;
 pub mod runtime;
 pub mod undo;
+pub mod wizard;
 
 // Re-export commonly used types at the crate root
 pub use action::Action;
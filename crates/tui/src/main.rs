@@ -29,7 +29,7 @@ use futures_util::StreamExt;
 use ratatui::{Terminal, backend::CrosstermBackend};
 use splunk_config::constants::{
     DEFAULT_CHANNEL_CAPACITY, DEFAULT_HEALTH_CHECK_INTERVAL_SECS, DEFAULT_REFRESH_INTERVAL_SECS,
-    DEFAULT_UI_TICK_MS,
+    DEFAULT_SESSION_RENEWAL_RETRY_SECS, DEFAULT_UI_TICK_MS,
 };
 use splunk_config::{
     AuthStrategy as ConfigAuthStrategy, ConfigLoader, ConfigManager, InternalLogsDefaults,
@@ -55,6 +55,7 @@ use tokio::sync::{
 use splunk_tui::runtime::{
     client::create_client,
     config::{load_config_with_defaults, save_and_quit, try_load_config_with_bootstrap_fallback},
+    offline::handle_snapshot_action,
     side_effects::{TaskTracker, handle_side_effects},
     terminal::TerminalGuard,
 };
@@ -140,6 +141,7 @@ async fn main() -> Result<()> {
                         profile_name: resolved_profile_name.clone(),
                         base_url: config.connection.base_url.clone(),
                         auth_mode,
+                        health_thresholds: config.connection.health_thresholds.clone(),
                     };
                     StartupState {
                         phase: StartupPhase::Main,
@@ -171,6 +173,7 @@ async fn main() -> Result<()> {
                             profile_name: None,
                             base_url: "Not connected".to_string(),
                             auth_mode: "bootstrap".to_string(),
+                            health_thresholds: None,
                         },
                     }
                 }
@@ -197,6 +200,7 @@ async fn main() -> Result<()> {
                     profile_name: None,
                     base_url: "Not connected".to_string(),
                     auth_mode: "bootstrap".to_string(),
+                    health_thresholds: None,
                 },
             }
         }
@@ -352,6 +356,8 @@ async fn main() -> Result<()> {
         cli.skip_tutorial,
         persisted_state.tutorial_completed,
     );
+    let search_templates = config_manager_for_first_run.search_templates();
+    let theme_library = config_manager_for_first_run.load_themes();
     drop(config_manager_for_first_run); // Release lock before creating app
 
     // Apply environment variable overrides to search defaults
@@ -370,6 +376,7 @@ async fn main() -> Result<()> {
     persisted_state.internal_logs_defaults = InternalLogsDefaults {
         count: internal_logs_defaults.count,
         earliest_time: internal_logs_defaults.earliest_time,
+        anomalies: internal_logs_defaults.anomalies,
     }
     .sanitize();
 
@@ -388,6 +395,11 @@ async fn main() -> Result<()> {
 
     // Create app with persisted state and pre-built connection context
     let mut app = App::new(Some(persisted_state), connection_ctx);
+    app.namespace = client.as_ref().and_then(|c| c.namespace());
+    app.search_templates = search_templates;
+    app.color_support = splunk_tui::theme::ColorSupport::detect();
+    app.no_color_forced = cli.no_color || splunk_tui::ui::no_color::no_color_env();
+    app.set_theme_library(theme_library);
     if cli.skip_tutorial {
         app.set_onboarding_checklist_enabled(false);
     }
@@ -416,6 +428,9 @@ async fn main() -> Result<()> {
             })
             .build(),
         );
+    } else if let Some(action) = app.start_session(cli.session.as_deref()) {
+        // Resume the requested saved session (or offer the session picker)
+        let _ = tx.try_send(action);
     }
 
     // Track if health check task is already running to prevent duplicates
@@ -425,10 +440,13 @@ async fn main() -> Result<()> {
     if let Some(client_health) = client.clone() {
         spawn_health_check_task(
             &task_tracker,
-            client_health,
+            client_health.clone(),
             tx.clone(),
             health_check_interval_seconds,
         );
+        spawn_session_keepalive_task(&task_tracker, client_health, tx.clone());
+        // Fetch the user's capabilities so the UI can gate restricted actions
+        let _ = tx.try_send(Action::LoadCurrentContext);
     }
 
     // Create UI tick interval for smooth animations
@@ -513,6 +531,7 @@ async fn main() -> Result<()> {
                                                     profile_name: resolved_profile_name,
                                                     base_url: config.connection.base_url.clone(),
                                                     auth_mode,
+                                                    health_thresholds: config.connection.health_thresholds.clone(),
                                                 };
 
                                                 let _ = tx_connect.send(Action::EnterMainMode {
@@ -623,6 +642,8 @@ async fn main() -> Result<()> {
                         app.profile_name = connection_ctx.profile_name.clone();
                         app.base_url = Some(connection_ctx.base_url.clone());
                         app.auth_mode = Some(connection_ctx.auth_mode.clone());
+                        app.profile_health_thresholds = connection_ctx.health_thresholds.clone();
+                        app.namespace = client.as_ref().and_then(|c| c.namespace());
                         app.toasts.push(splunk_tui::ui::Toast::success(
                             "Connected successfully! Welcome to Splunk TUI.".to_string()
                         ));
@@ -638,7 +659,13 @@ async fn main() -> Result<()> {
                                 tx.clone(),
                                 health_check_interval_seconds,
                             );
+                            spawn_session_keepalive_task(
+                                &task_tracker,
+                                client_health.clone(),
+                                tx.clone(),
+                            );
                         }
+                        let _ = tx.try_send(Action::LoadCurrentContext);
                     }
                     continue;
                 }
@@ -787,6 +814,69 @@ fn spawn_health_check_task(
                 }
                 Err(TrySendError::Closed(_)) => break,
             }
+
+            // Refresh the bulletin board alongside health; failures are not
+            // surfaced here to avoid a toast on every tick.
+            match client.list_server_messages().await {
+                Ok(messages) => {
+                    if let Err(TrySendError::Closed(_)) =
+                        tx.try_send(Action::ServerMessagesLoaded(Ok(messages)))
+                    {
+                        break;
+                    }
+                }
+                Err(error) => {
+                    tracing::debug!("Failed to refresh server messages: {}", error);
+                }
+            }
+        }
+    });
+}
+
+/// Renew the session shortly before it expires instead of waiting for a 401.
+///
+/// Exits immediately for API token auth, which has no session to keep alive.
+fn spawn_session_keepalive_task(
+    task_tracker: &TaskTracker,
+    client: Arc<splunk_client::SplunkClient>,
+    tx: Sender<Action>,
+) {
+    task_tracker.spawn(async move {
+        while let Some(wait) = client.time_until_session_renewal().await {
+            tokio::time::sleep(wait).await;
+
+            // A request may have re-authenticated while we slept.
+            if client
+                .time_until_session_renewal()
+                .await
+                .is_some_and(|wait| !wait.is_zero())
+            {
+                let expires_in_secs = client.session_remaining().await.unwrap_or_default();
+                let action = Action::SessionRenewed {
+                    expires_in_secs: expires_in_secs.as_secs(),
+                };
+                if tx.send(action).await.is_err() {
+                    break;
+                }
+                continue;
+            }
+
+            let action = match client.renew_session().await {
+                Ok(lifetime) => Action::SessionRenewed {
+                    expires_in_secs: lifetime.as_secs(),
+                },
+                Err(error) => Action::SessionExpired(Arc::new(error)),
+            };
+            let failed = matches!(action, Action::SessionExpired(_));
+            if tx.send(action).await.is_err() {
+                break;
+            }
+            if failed {
+                tokio::time::sleep(tokio::time::Duration::from_secs(
+                    DEFAULT_SESSION_RENEWAL_RETRY_SECS,
+                ))
+                .await;
+            }
         }
     });
 }
@@ -839,10 +929,49 @@ async fn dispatch_app_action(
     task_tracker: &TaskTracker,
     allow_search_prefetch: bool,
 ) {
-    let is_navigation = matches!(action, Action::NextScreen | Action::PreviousScreen);
+    let is_navigation = matches!(
+        action,
+        Action::NextScreen | Action::PreviousScreen | Action::SwitchToScreen(_)
+    );
     let should_load_cluster_peers = matches!(action, Action::ToggleClusterViewMode);
-    let should_reload_current_screen = matches!(action, Action::ProfileSwitchResult(Ok(_)));
+    let should_load_bucket_health = matches!(action, Action::ToggleClusterBucketsView);
+    let should_load_index_compliance = matches!(action, Action::ToggleClusterComplianceView);
+    let should_load_role_usage = matches!(
+        action,
+        Action::ToggleRoleUsageView | Action::LoadRoles { offset: 0, .. }
+    );
+    let should_load_role_matrix = matches!(
+        action,
+        Action::ToggleRoleMatrixView | Action::LoadRoles { offset: 0, .. }
+    );
+    let should_load_resource_usage = matches!(
+        action,
+        Action::ToggleHealthResourcesView | Action::LoadHealth
+    );
+    let should_load_job_performance = matches!(action, Action::ToggleJobPerformanceView);
+    let should_load_metadata = matches!(action, Action::ToggleMetadataType);
+    let should_load_hec_tokens = matches!(action, Action::ToggleInputsHttpView);
+    let should_load_server_messages = matches!(
+        action,
+        Action::OpenServerMessages
+            | Action::ProfileSwitchResult(Ok(_))
+            | Action::ServerRestarted(Ok(_))
+    );
+    let should_load_current_context = matches!(action, Action::ProfileSwitchResult(Ok(_)));
+    let should_reload_current_screen = matches!(
+        action,
+        Action::ProfileSwitchResult(Ok(_))
+            | Action::ServerRestarted(Ok(_))
+            | Action::SetNamespace(_)
+    );
 
+    handle_snapshot_action(
+        &action,
+        app.profile_name.clone(),
+        config_manager,
+        tx,
+        task_tracker,
+    );
     app.update(action.clone());
     dispatch_side_effect(action, client, tx, config_manager, task_tracker).await;
 
@@ -858,6 +987,127 @@ async fn dispatch_app_action(
         .await;
     }
 
+    if should_load_bucket_health
+        && app.cluster_view_mode == splunk_tui::app::ClusterViewMode::Buckets
+    {
+        dispatch_side_effect(
+            Action::LoadClusterBucketHealth,
+            client,
+            tx,
+            config_manager,
+            task_tracker,
+        )
+        .await;
+    }
+
+    if should_load_index_compliance
+        && app.cluster_view_mode == splunk_tui::app::ClusterViewMode::Compliance
+    {
+        dispatch_side_effect(
+            Action::LoadClusterIndexCompliance,
+            client,
+            tx,
+            config_manager,
+            task_tracker,
+        )
+        .await;
+    }
+
+    if should_load_role_usage && app.roles_view_mode == splunk_tui::app::RolesViewMode::Usage {
+        dispatch_side_effect(
+            Action::LoadRoleUsage,
+            client,
+            tx,
+            config_manager,
+            task_tracker,
+        )
+        .await;
+    }
+
+    if should_load_role_matrix && app.roles_view_mode == splunk_tui::app::RolesViewMode::Matrix {
+        dispatch_side_effect(
+            Action::LoadRoleCapabilityMatrix,
+            client,
+            tx,
+            config_manager,
+            task_tracker,
+        )
+        .await;
+    }
+
+    if should_load_resource_usage
+        && app.health_view_mode == splunk_tui::app::HealthViewMode::Resources
+    {
+        dispatch_side_effect(
+            Action::LoadResourceUsage,
+            client,
+            tx,
+            config_manager,
+            task_tracker,
+        )
+        .await;
+    }
+
+    if should_load_metadata {
+        dispatch_side_effect(
+            Action::LoadMetadata {
+                metadata_type: app.metadata_type,
+            },
+            client,
+            tx,
+            config_manager,
+            task_tracker,
+        )
+        .await;
+    }
+
+    if should_load_hec_tokens && app.inputs_view_mode == splunk_tui::app::InputsViewMode::Http {
+        dispatch_side_effect(
+            Action::LoadHecTokens,
+            client,
+            tx,
+            config_manager,
+            task_tracker,
+        )
+        .await;
+    }
+
+    if should_load_job_performance
+        && app.job_inspect_tab == splunk_tui::app::JobInspectTab::Performance
+        && let Some(sid) = app.get_selected_job().map(|job| job.sid.clone())
+    {
+        dispatch_side_effect(
+            Action::LoadJobPerformance { sid },
+            client,
+            tx,
+            config_manager,
+            task_tracker,
+        )
+        .await;
+    }
+
+    if should_load_server_messages {
+        dispatch_side_effect(
+            Action::LoadServerMessages,
+            client,
+            tx,
+            config_manager,
+            task_tracker,
+        )
+        .await;
+    }
+
+    if should_load_current_context {
+        dispatch_side_effect(
+            Action::LoadCurrentContext,
+            client,
+            tx,
+            config_manager,
+            task_tracker,
+        )
+        .await;
+    }
+
     if (is_navigation || should_reload_current_screen)
         && let Some(load_action) = app.load_action_for_screen()
     {
@@ -0,0 +1,443 @@
+//! Progress tracking and cancellation for long-running TUI operations.
+//!
+//! Bulk deletes, exports, rolling restarts, and multi-profile fetches share one
+//! progress popup. Side-effect handlers drive it through a [`ProgressReporter`],
+//! which emits `Operation*` actions; the app keeps the latest
+//! [`OperationProgress`] snapshot and cancels its token when the user presses
+//! the cancel key.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut reporter = ProgressReporter::start(&tx, "Deleting 3 job(s)", Some(3)).await;
+//! for sid in sids {
+//!     if reporter.is_cancelled() {
+//!         break;
+//!     }
+//!     reporter.begin_item(&sid).await;
+//!     let ok = matches!(reporter.run(client.delete_job(&sid)).await, Some(Ok(_)));
+//!     reporter.item_finished(ok).await;
+//! }
+//! reporter.finish().await;
+//! ```
+//!
+//! # Invariants
+//! - Every `OperationStarted` is followed by exactly one `OperationFinished` with
+//!   the same id, even when the reporter is dropped early.
+//! - Cancellation is cooperative: handlers stop between items and abandon the
+//!   in-flight client call; work the server already accepted is not rolled back.
+
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use splunk_client::workflows::CancellationProbe;
+use tokio::sync::mpsc::Sender;
+use tokio_util::sync::CancellationToken;
+
+use crate::action::Action;
+
+static NEXT_OPERATION_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Width of the text progress bar, in cells.
+const BAR_WIDTH: usize = 20;
+
+/// Snapshot of a long-running operation, owned by the app while it runs.
+#[derive(Debug, Clone)]
+pub struct OperationProgress {
+    /// Unique id used to match progress updates to this operation.
+    pub id: u64,
+    /// Human-readable description (e.g., "Deleting 12 job(s)").
+    pub label: String,
+    /// Total number of items, when known up front.
+    pub total: Option<usize>,
+    pub completed: usize,
+    pub failed: usize,
+    /// Item currently being processed.
+    pub current: Option<String>,
+    pub started_at: Instant,
+    /// Set once the user has asked to cancel.
+    pub cancel_requested: bool,
+    cancel: CancellationToken,
+}
+
+impl OperationProgress {
+    /// Create a new operation snapshot with a fresh id and cancellation token.
+    pub fn new(label: impl Into<String>, total: Option<usize>) -> Self {
+        Self {
+            id: NEXT_OPERATION_ID.fetch_add(1, Ordering::Relaxed),
+            label: label.into(),
+            total,
+            completed: 0,
+            failed: 0,
+            current: None,
+            started_at: Instant::now(),
+            cancel_requested: false,
+            cancel: CancellationToken::new(),
+        }
+    }
+
+    /// Token that is cancelled when the user cancels this operation.
+    pub fn cancel_token(&self) -> CancellationToken {
+        self.cancel.clone()
+    }
+
+    /// Request cancellation of the operation.
+    pub fn request_cancel(&mut self) {
+        self.cancel_requested = true;
+        self.cancel.cancel();
+    }
+
+    /// Number of items processed so far (successful or not).
+    pub fn processed(&self) -> usize {
+        self.completed + self.failed
+    }
+
+    /// Fraction complete (0.0–1.0), when the total is known.
+    pub fn fraction(&self) -> Option<f64> {
+        self.total.map(|total| {
+            if total == 0 {
+                1.0
+            } else {
+                (self.processed() as f64 / total as f64).min(1.0)
+            }
+        })
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+
+    /// Estimated time remaining, extrapolated from the average time per item.
+    pub fn eta(&self) -> Option<Duration> {
+        self.eta_at(self.elapsed())
+    }
+
+    fn eta_at(&self, elapsed: Duration) -> Option<Duration> {
+        let total = self.total?;
+        let processed = self.processed();
+        if processed == 0 {
+            return None;
+        }
+        let remaining = total.saturating_sub(processed) as u32;
+        Some(elapsed / processed as u32 * remaining)
+    }
+
+    /// Apply an update emitted by the operation's reporter.
+    pub fn apply(&mut self, update: ProgressUpdate) {
+        if update.id != self.id {
+            return;
+        }
+        self.completed = update.completed;
+        self.failed = update.failed;
+        self.current = update.current;
+    }
+
+    /// Popup body text describing the current progress.
+    pub fn render_text(&self) -> String {
+        self.render_text_at(self.elapsed())
+    }
+
+    fn render_text_at(&self, elapsed: Duration) -> String {
+        let mut text = format!("{}\n\n", self.label);
+
+        match (self.total, self.fraction()) {
+            (Some(total), Some(fraction)) => {
+                let filled = ((fraction * BAR_WIDTH as f64).round() as usize).min(BAR_WIDTH);
+                text.push_str(&format!(
+                    "[{}{}] {:.0}%\n",
+                    "█".repeat(filled),
+                    "░".repeat(BAR_WIDTH - filled),
+                    fraction * 100.0
+                ));
+                text.push_str(&format!("{} of {} done", self.processed(), total));
+            }
+            _ => text.push_str(&format!("{} done", self.processed())),
+        }
+        if self.failed > 0 {
+            text.push_str(&format!(" ({} failed)", self.failed));
+        }
+        text.push('\n');
+
+        if let Some(current) = &self.current {
+            text.push_str(&format!("Current: {}\n", current));
+        }
+
+        text.push_str(&format!("\nElapsed: {}", format_duration(elapsed)));
+        match self.eta_at(elapsed) {
+            Some(eta) => text.push_str(&format!("   ETA: {}\n", format_duration(eta))),
+            None => text.push_str("   ETA: --:--\n"),
+        }
+
+        if self.cancel_requested {
+            text.push_str("\nCancelling... waiting for the current item to stop");
+        } else {
+            text.push_str("\nPress c or Esc to cancel, b to run in the background");
+        }
+        text
+    }
+}
+
+/// Adapter exposing an operation's token to shared client workflows.
+#[derive(Debug, Clone)]
+pub struct OperationCancelProbe(CancellationToken);
+
+impl CancellationProbe for OperationCancelProbe {
+    fn is_cancelled(&self) -> bool {
+        self.0.is_cancelled()
+    }
+}
+
+/// Per-item progress emitted by a [`ProgressReporter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgressUpdate {
+    pub id: u64,
+    pub completed: usize,
+    pub failed: usize,
+    pub current: Option<String>,
+}
+
+/// Handler-side driver for an [`OperationProgress`].
+///
+/// Created inside the spawned side-effect task; sends progress actions to the
+/// app and exposes the cancellation token wired to the popup's cancel key.
+pub struct ProgressReporter {
+    tx: Sender<Action>,
+    id: u64,
+    completed: usize,
+    failed: usize,
+    current: Option<String>,
+    cancel: CancellationToken,
+    finished: bool,
+}
+
+impl ProgressReporter {
+    /// Announce a new operation and open the progress popup.
+    pub async fn start(
+        tx: &Sender<Action>,
+        label: impl Into<String>,
+        total: Option<usize>,
+    ) -> Self {
+        let progress = OperationProgress::new(label, total);
+        let reporter = Self {
+            tx: tx.clone(),
+            id: progress.id,
+            completed: 0,
+            failed: 0,
+            current: None,
+            cancel: progress.cancel_token(),
+            finished: false,
+        };
+        let _ = tx.send(Action::OperationStarted(Box::new(progress))).await;
+        reporter
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn cancel_token(&self) -> &CancellationToken {
+        &self.cancel
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.is_cancelled()
+    }
+
+    /// Cancellation probe for workflows that accept `Option<&dyn CancellationProbe>`.
+    pub fn probe(&self) -> OperationCancelProbe {
+        OperationCancelProbe(self.cancel.clone())
+    }
+
+    /// Run `fut` unless the operation is cancelled first; `None` means cancelled.
+    pub async fn run<F: Future>(&self, fut: F) -> Option<F::Output> {
+        tokio::select! {
+            output = fut => Some(output),
+            _ = self.cancel.cancelled() => None,
+        }
+    }
+
+    /// Mark `item` as the item currently being processed.
+    pub async fn begin_item(&mut self, item: impl Into<String>) {
+        self.current = Some(item.into());
+        let _ = self.tx.send(self.update_action()).await;
+    }
+
+    /// Record the outcome of the current item.
+    pub async fn item_finished(&mut self, ok: bool) {
+        self.record(ok);
+        let _ = self.tx.send(self.update_action()).await;
+    }
+
+    /// Record an item outcome from a synchronous callback.
+    ///
+    /// Updates are dropped when the action channel is full; the final state is
+    /// still delivered by the next async update or by [`Self::finish`].
+    pub fn item_finished_now(&mut self, item: impl Into<String>, ok: bool) {
+        self.current = Some(item.into());
+        self.record(ok);
+        let _ = self.tx.try_send(self.update_action());
+    }
+
+    /// Close the progress popup. Returns true when the operation was cancelled.
+    pub async fn finish(mut self) -> bool {
+        self.finished = true;
+        let cancelled = self.is_cancelled();
+        let _ = self
+            .tx
+            .send(Action::OperationFinished {
+                id: self.id,
+                cancelled,
+            })
+            .await;
+        cancelled
+    }
+
+    fn record(&mut self, ok: bool) {
+        if ok {
+            self.completed += 1;
+        } else {
+            self.failed += 1;
+        }
+    }
+
+    fn update_action(&self) -> Action {
+        Action::OperationProgressed(ProgressUpdate {
+            id: self.id,
+            completed: self.completed,
+            failed: self.failed,
+            current: self.current.clone(),
+        })
+    }
+}
+
+impl Drop for ProgressReporter {
+    fn drop(&mut self) {
+        if !self.finished {
+            let _ = self.tx.try_send(Action::OperationFinished {
+                id: self.id,
+                cancelled: self.is_cancelled(),
+            });
+        }
+    }
+}
+
+/// Format a duration as `MM:SS`, or `H:MM:SS` past an hour.
+pub(crate) fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, (secs % 3600) / 60, secs % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn progress(total: Option<usize>, completed: usize, failed: usize) -> OperationProgress {
+        let mut progress = OperationProgress::new("Deleting 10 job(s)", total);
+        progress.completed = completed;
+        progress.failed = failed;
+        progress
+    }
+
+    #[test]
+    fn test_eta_extrapolates_average_item_time() {
+        let progress = progress(Some(10), 3, 1);
+        assert_eq!(
+            progress.eta_at(Duration::from_secs(8)),
+            Some(Duration::from_secs(12))
+        );
+    }
+
+    #[test]
+    fn test_eta_unknown_without_total_or_items() {
+        assert_eq!(progress(None, 3, 0).eta_at(Duration::from_secs(5)), None);
+        assert_eq!(
+            progress(Some(10), 0, 0).eta_at(Duration::from_secs(5)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_render_text_shows_counts_eta_and_cancel_hint() {
+        let mut progress = progress(Some(10), 4, 1);
+        progress.current = Some("scheduler__admin__search".to_string());
+
+        let text = progress.render_text_at(Duration::from_secs(65));
+        assert!(text.contains("50%"));
+        assert!(text.contains("5 of 10 done (1 failed)"));
+        assert!(text.contains("Current: scheduler__admin__search"));
+        assert!(text.contains("Elapsed: 01:05   ETA: 01:05"));
+        assert!(text.contains("Press c or Esc to cancel, b to run in the background"));
+
+        progress.request_cancel();
+        assert!(progress.cancel_token().is_cancelled());
+        assert!(
+            progress
+                .render_text_at(Duration::ZERO)
+                .contains("Cancelling")
+        );
+    }
+
+    #[test]
+    fn test_apply_ignores_other_operations() {
+        let mut progress = progress(Some(2), 0, 0);
+        progress.apply(ProgressUpdate {
+            id: progress.id + 1,
+            completed: 2,
+            failed: 0,
+            current: None,
+        });
+        assert_eq!(progress.processed(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_reporter_emits_start_updates_and_finish() {
+        let (tx, mut rx) = tokio::sync::mpsc::channel(16);
+        let mut reporter = ProgressReporter::start(&tx, "Exporting", Some(1)).await;
+        reporter.begin_item("results.json").await;
+        reporter.item_finished(true).await;
+        assert!(!reporter.finish().await);
+
+        assert!(matches!(rx.recv().await, Some(Action::OperationStarted(p)) if p.total == Some(1)));
+        assert!(
+            matches!(rx.recv().await, Some(Action::OperationProgressed(u)) if u.completed == 0)
+        );
+        assert!(
+            matches!(rx.recv().await, Some(Action::OperationProgressed(u)) if u.completed == 1)
+        );
+        assert!(matches!(
+            rx.recv().await,
+            Some(Action::OperationFinished {
+                cancelled: false,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_reporter_run_stops_when_cancelled() {
+        let (tx, mut rx) = tokio::sync::mpsc::channel(16);
+        let reporter = ProgressReporter::start(&tx, "Restarting", None).await;
+        let Some(Action::OperationStarted(mut progress)) = rx.recv().await else {
+            panic!("expected OperationStarted");
+        };
+
+        progress.request_cancel();
+        let output = reporter.run(std::future::pending::<()>()).await;
+        assert!(output.is_none());
+
+        drop(reporter);
+        assert!(matches!(
+            rx.recv().await,
+            Some(Action::OperationFinished {
+                cancelled: true,
+                ..
+            })
+        ));
+    }
+}
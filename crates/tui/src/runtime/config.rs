@@ -38,7 +38,7 @@ pub enum ConfigLoadResult {
     Success {
         search_defaults: SearchDefaultConfig,
         internal_logs_defaults: InternalLogsDefaults,
-        config: Config,
+        config: Box<Config>,
         resolved_profile_name: Option<String>,
     },
     /// Recoverable error - enter bootstrap mode.
@@ -164,7 +164,7 @@ pub fn try_load_config_with_bootstrap_fallback(cli: &Cli) -> Result<ConfigLoadRe
         Ok(config) => Ok(ConfigLoadResult::Success {
             search_defaults,
             internal_logs_defaults,
-            config,
+            config: Box::new(config),
             resolved_profile_name,
         }),
         Err(e) => {
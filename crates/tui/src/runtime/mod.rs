@@ -5,6 +5,7 @@
 //! - Client creation and authentication
 //! - Configuration loading and persistence
 //! - Async side effect handlers for API calls
+//! - Offline snapshot cache for screens the server cannot refresh
 //! - Startup phase management for bootstrap mode
 //!
 //! Does NOT handle:
@@ -17,6 +18,7 @@
 
 pub mod client;
 pub mod config;
+pub mod offline;
 pub mod side_effects;
 pub mod startup;
 pub mod terminal;
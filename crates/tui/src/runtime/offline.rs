@@ -0,0 +1,224 @@
+//! Offline snapshot cache for the TUI.
+//!
+//! Responsibilities:
+//! - Save the most recent successful indexes, jobs, and health responses per profile.
+//! - When one of those screens fails because the server is unreachable, load its snapshot
+//!   and send `Action::OfflineSnapshotLoaded`.
+//!
+//! Does NOT handle:
+//! - The on-disk snapshot format (see `splunk_config` snapshots via `ConfigManager`).
+//! - Rendering the OFFLINE banner (see `app::offline`).
+//!
+//! Invariants:
+//! - Only the first page of a list (a non-paginated `*Loaded` action) is saved.
+//! - Errors the server returned never trigger the fallback; only unreachable-server errors do.
+//! - Cache I/O runs in spawned tasks and failures are only logged.
+
+use std::sync::Arc;
+
+use serde::de::DeserializeOwned;
+use splunk_client::ClientError;
+use splunk_config::{ConfigManager, SnapshotKind};
+use tokio::sync::{Mutex, mpsc::Sender};
+
+use crate::action::{Action, OfflineData};
+use crate::runtime::side_effects::TaskTracker;
+
+/// Save or fall back to a snapshot for a load result, if `action` is one.
+///
+/// `profile` is the active profile name; snapshots are kept per profile.
+pub fn handle_snapshot_action(
+    action: &Action,
+    profile: Option<String>,
+    config_manager: &Arc<Mutex<ConfigManager>>,
+    tx: &Sender<Action>,
+    task_tracker: &TaskTracker,
+) {
+    match action {
+        Action::IndexesLoaded(Ok(indexes)) => spawn_save(
+            task_tracker,
+            config_manager.clone(),
+            profile,
+            SnapshotKind::Indexes,
+            indexes.clone(),
+        ),
+        Action::JobsLoaded(Ok(jobs)) => spawn_save(
+            task_tracker,
+            config_manager.clone(),
+            profile,
+            SnapshotKind::Jobs,
+            jobs.clone(),
+        ),
+        Action::HealthLoaded(result) => match result.as_ref() {
+            Ok(health) => spawn_save(
+                task_tracker,
+                config_manager.clone(),
+                profile,
+                SnapshotKind::Health,
+                health.clone(),
+            ),
+            Err(e) => spawn_fallback(
+                task_tracker,
+                config_manager,
+                profile,
+                SnapshotKind::Health,
+                e,
+                tx,
+            ),
+        },
+        Action::IndexesLoaded(Err(e)) => spawn_fallback(
+            task_tracker,
+            config_manager,
+            profile,
+            SnapshotKind::Indexes,
+            e,
+            tx,
+        ),
+        Action::JobsLoaded(Err(e)) => spawn_fallback(
+            task_tracker,
+            config_manager,
+            profile,
+            SnapshotKind::Jobs,
+            e,
+            tx,
+        ),
+        _ => {}
+    }
+}
+
+fn spawn_save<T: serde::Serialize + Send + 'static>(
+    task_tracker: &TaskTracker,
+    config_manager: Arc<Mutex<ConfigManager>>,
+    profile: Option<String>,
+    kind: SnapshotKind,
+    data: T,
+) {
+    task_tracker.spawn(async move {
+        let manager = config_manager.lock().await;
+        if let Err(e) = manager.save_snapshot(profile.as_deref(), kind, &data) {
+            tracing::warn!(kind = %kind, error = %e, "Failed to save offline snapshot");
+        }
+    });
+}
+
+fn spawn_fallback(
+    task_tracker: &TaskTracker,
+    config_manager: &Arc<Mutex<ConfigManager>>,
+    profile: Option<String>,
+    kind: SnapshotKind,
+    error: &Arc<ClientError>,
+    tx: &Sender<Action>,
+) {
+    if !error.is_unreachable() {
+        return;
+    }
+
+    let config_manager = config_manager.clone();
+    let tx = tx.clone();
+    task_tracker.spawn(async move {
+        let snapshot = config_manager
+            .lock()
+            .await
+            .load_snapshot(profile.as_deref(), kind);
+        let Some(snapshot) = snapshot else {
+            return;
+        };
+
+        let data = match kind {
+            SnapshotKind::Indexes => decode(kind, snapshot.data).map(OfflineData::Indexes),
+            SnapshotKind::Jobs => decode(kind, snapshot.data).map(OfflineData::Jobs),
+            SnapshotKind::Health => decode(kind, snapshot.data).map(OfflineData::Health),
+        };
+        if let Some(data) = data {
+            let _ = tx
+                .send(Action::OfflineSnapshotLoaded {
+                    saved_at: snapshot.saved_at,
+                    data,
+                })
+                .await;
+        }
+    });
+}
+
+/// Decode a snapshot payload, ignoring (and logging) data saved in an incompatible shape.
+fn decode<T: DeserializeOwned>(kind: SnapshotKind, data: serde_json::Value) -> Option<T> {
+    serde_json::from_value(data)
+        .inspect_err(
+            |e| tracing::warn!(kind = %kind, error = %e, "Ignoring unreadable offline snapshot"),
+        )
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use splunk_client::models::Index;
+    use std::time::Duration;
+
+    fn index(name: &str) -> Index {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "totalEventCount": 42,
+            "currentDBSizeMB": 7,
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_error_falls_back_to_saved_snapshot() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let manager = ConfigManager::new_with_path(dir.path().join("config.json")).unwrap();
+        let config_manager = Arc::new(Mutex::new(manager));
+        let (tx, mut rx) = tokio::sync::mpsc::channel(8);
+        let task_tracker = TaskTracker::new();
+        let profile = Some("prod".to_string());
+
+        let loaded = Action::IndexesLoaded(Ok(vec![index("main")]));
+        handle_snapshot_action(
+            &loaded,
+            profile.clone(),
+            &config_manager,
+            &tx,
+            &task_tracker,
+        );
+        task_tracker.close();
+        task_tracker.wait().await;
+
+        let refused = Arc::new(ClientError::ConnectionRefused("localhost:8089".to_string()));
+        let failed = Action::IndexesLoaded(Err(refused));
+        let task_tracker = TaskTracker::new();
+        handle_snapshot_action(&failed, profile, &config_manager, &tx, &task_tracker);
+
+        let action = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        match action {
+            Action::OfflineSnapshotLoaded {
+                data: OfflineData::Indexes(indexes),
+                ..
+            } => assert_eq!(indexes[0].name, "main"),
+            other => panic!("unexpected action: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_server_errors_do_not_fall_back() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let manager = ConfigManager::new_with_path(dir.path().join("config.json")).unwrap();
+        manager
+            .save_snapshot(None, SnapshotKind::Jobs, &Vec::<Index>::new())
+            .unwrap();
+        let config_manager = Arc::new(Mutex::new(manager));
+        let (tx, mut rx) = tokio::sync::mpsc::channel(8);
+        let task_tracker = TaskTracker::new();
+
+        let not_found = Arc::new(ClientError::NotFound("/services/search/jobs".to_string()));
+        let failed = Action::JobsLoaded(Err(not_found));
+        handle_snapshot_action(&failed, None, &config_manager, &tx, &task_tracker);
+        task_tracker.close();
+        task_tracker.wait().await;
+
+        assert!(rx.try_recv().is_err());
+    }
+}
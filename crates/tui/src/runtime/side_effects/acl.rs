@@ -0,0 +1,55 @@
+//! Knowledge object permission (ACL) side effect handlers.
+//!
+//! Responsibilities:
+//! - Handle LoadObjectAcl to fetch an object's permissions for the popup
+//! - Handle UpdateObjectAcl to apply a partial permission change
+//!
+//! Does NOT handle:
+//! - Merging partial updates with the current ACL (handled by the client)
+//! - Popup state or rendering
+
+use splunk_client::models::{AclUpdateParams, KnowledgeObjectKind};
+use tokio::sync::mpsc::Sender;
+
+use crate::action::Action;
+
+use super::{SharedClient, TaskTracker};
+
+/// Handle loading the permissions of a knowledge object.
+pub async fn handle_load_object_acl(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    kind: KnowledgeObjectKind,
+    name: String,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = client
+            .get_object_acl(kind, &name)
+            .await
+            .map_err(|e| format!("{:#}", e));
+        let _ = tx.send(Action::ObjectAclLoaded(result)).await;
+    });
+}
+
+/// Handle changing the permissions of a knowledge object.
+///
+/// Emits `ObjectAclUpdated` with the ACL as written.
+pub async fn handle_update_object_acl(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    kind: KnowledgeObjectKind,
+    name: String,
+    params: AclUpdateParams,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = client
+            .set_object_acl(kind, &name, &params)
+            .await
+            .map_err(|e| format!("{:#}", e));
+        let _ = tx.send(Action::ObjectAclUpdated(result)).await;
+    });
+}
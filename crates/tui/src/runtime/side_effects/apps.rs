@@ -3,6 +3,8 @@
 //! Responsibilities:
 //! - Handle async API calls for app operations.
 //! - Fetch app lists, enable apps, disable apps, install apps, remove apps.
+//! - Search Splunkbase and install apps downloaded from it.
+//! - Load the knowledge objects an app ships for the App Details popup.
 //!
 //! Does NOT handle:
 //! - Direct state modification (sends actions for that).
@@ -10,13 +12,19 @@
 
 use crate::action::Action;
 use crate::ui::ToastLevel;
-use std::path::PathBuf;
+use splunk_client::workflows::app_contents::fetch_app_contents;
+use splunk_client::workflows::splunkbase::{
+    AppPackageSource, SplunkbaseClient, SplunkbaseConfig, install_app_package,
+};
 use tokio::sync::mpsc::Sender;
 
 use super::paginated::build_paginated_action;
 use super::{SharedClient, TaskTracker};
 use splunk_config::constants::DEFAULT_LIST_PAGE_SIZE;
 
+/// Maximum number of Splunkbase search results shown in the install dialog.
+const SPLUNKBASE_SEARCH_LIMIT: usize = 20;
+
 /// Handle loading apps with pagination support.
 ///
 /// Emits `AppsLoaded` when offset == 0 (initial load/refresh).
@@ -113,16 +121,19 @@ pub async fn handle_disable_app(
     });
 }
 
-/// Handle installing an app.
+/// Handle installing an app from a local package or Splunkbase.
+///
+/// Splunkbase credentials are read from the `SPLUNKBASE_*` environment variables.
 pub async fn handle_install_app(
     client: SharedClient,
     tx: Sender<Action>,
     task_tracker: TaskTracker,
-    file_path: PathBuf,
+    source: AppPackageSource,
 ) {
     let _ = tx.send(Action::Loading(true)).await;
     task_tracker.spawn(async move {
-        match client.install_app(&file_path).await {
+        let splunkbase = SplunkbaseConfig::from_env();
+        match install_app_package(&client, &source, &splunkbase).await {
             Ok(app) => {
                 let _ = tx
                     .send(Action::Notify(
@@ -142,7 +153,7 @@ pub async fn handle_install_app(
                 let _ = tx
                     .send(Action::Notify(
                         ToastLevel::Error,
-                        format!("Failed to install app: {}", e),
+                        format!("Failed to install app from {}: {:#}", source, e),
                     ))
                     .await;
                 let _ = tx.send(Action::Loading(false)).await;
@@ -151,6 +162,46 @@ pub async fn handle_install_app(
     });
 }
 
+/// Handle searching Splunkbase for the install dialog.
+///
+/// Emits `SplunkbaseSearchLoaded`; searching needs no Splunk connection.
+pub async fn handle_search_splunkbase(
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    query: String,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = async {
+            SplunkbaseClient::new(SplunkbaseConfig::from_env())?
+                .search_apps(&query, SPLUNKBASE_SEARCH_LIMIT)
+                .await
+        }
+        .await
+        .map_err(|e| format!("{:#}", e));
+        let _ = tx.send(Action::SplunkbaseSearchLoaded(result)).await;
+    });
+}
+
+/// Handle loading the inventory of an app.
+///
+/// Emits `AppContentsLoaded` with the app's saved searches, dashboards, lookups,
+/// macros and event types.
+pub async fn handle_load_app_contents(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    app: String,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = fetch_app_contents(&client, &app)
+            .await
+            .map_err(|e| format!("{:#}", e));
+        let _ = tx.send(Action::AppContentsLoaded(result)).await;
+    });
+}
+
 /// Handle removing an app.
 pub async fn handle_remove_app(
     client: SharedClient,
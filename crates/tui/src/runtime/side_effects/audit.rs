@@ -3,6 +3,7 @@
 //! Responsibilities:
 //! - Handle async API calls for audit event operations.
 //! - Fetch audit events from the Splunk server.
+//! - Summarize one user's audit activity per day.
 //!
 //! Does NOT handle:
 //! - Direct state modification (sends actions for that).
@@ -10,6 +11,7 @@
 
 use crate::action::Action;
 use splunk_client::models::ListAuditEventsParams;
+use splunk_client::workflows::user_activity::{UserActivityRequest, user_activity};
 use std::sync::Arc;
 use tokio::sync::mpsc::Sender;
 
@@ -66,3 +68,20 @@ pub async fn handle_load_recent_audit_events(
         }
     });
 }
+
+/// Handle summarizing a user's audit activity over the default window.
+pub async fn handle_load_user_activity(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    user: String,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let request = UserActivityRequest::new(user);
+        let result = user_activity(&client, &request, None)
+            .await
+            .map_err(|e| format!("{e:#}"));
+        let _ = tx.send(Action::UserActivityLoaded(result)).await;
+    });
+}
@@ -2,7 +2,9 @@
 //!
 //! Responsibilities:
 //! - Handle async API calls for cluster operations.
-//! - Fetch cluster info and peer information.
+//! - Fetch cluster info, peer information, and bucket fix-up/excess copy health.
+//! - Fetch per-index replication/search factor compliance.
+//! - Collect Splunk versions across the cluster for version skew detection.
 //! - Handle cluster management operations (maintenance mode, rebalance, decommission, remove).
 //!
 //! Does NOT handle:
@@ -10,6 +12,7 @@
 //! - UI rendering.
 
 use crate::action::Action;
+use splunk_client::models::ClusterMode;
 use std::sync::Arc;
 use tokio::sync::mpsc::Sender;
 
@@ -26,7 +29,38 @@ pub async fn handle_load_cluster_info(
     task_tracker.spawn(async move {
         match client.get_cluster_info().await {
             Ok(info) => {
+                let is_manager = info.mode == ClusterMode::Manager;
                 let _ = tx.send(Action::ClusterInfoLoaded(Ok(info))).await;
+                // Fix-up and excess copy counts, copy trackers, and node versions are only
+                // served by the manager.
+                if is_manager {
+                    match client.get_cluster_bucket_health().await {
+                        Ok(health) => {
+                            let _ = tx.send(Action::ClusterBucketHealthLoaded(Ok(health))).await;
+                        }
+                        Err(e) => {
+                            tracing::warn!("Failed to load cluster bucket health: {}", e);
+                        }
+                    }
+                    match client.get_cluster_index_compliance().await {
+                        Ok(indexes) => {
+                            let _ = tx
+                                .send(Action::ClusterIndexComplianceLoaded(Ok(indexes)))
+                                .await;
+                        }
+                        Err(e) => {
+                            tracing::warn!("Failed to load cluster index compliance: {}", e);
+                        }
+                    }
+                    match client.get_cluster_versions().await {
+                        Ok(report) => {
+                            let _ = tx.send(Action::ClusterVersionsLoaded(Ok(report))).await;
+                        }
+                        Err(e) => {
+                            tracing::warn!("Failed to collect cluster versions: {}", e);
+                        }
+                    }
+                }
             }
             Err(e) => {
                 let _ = tx.send(Action::ClusterInfoLoaded(Err(Arc::new(e)))).await;
@@ -35,6 +69,50 @@ pub async fn handle_load_cluster_info(
     });
 }
 
+/// Handle loading cluster bucket fix-ups and excess copies.
+pub async fn handle_load_cluster_bucket_health(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        match client.get_cluster_bucket_health().await {
+            Ok(health) => {
+                let _ = tx.send(Action::ClusterBucketHealthLoaded(Ok(health))).await;
+            }
+            Err(e) => {
+                let _ = tx
+                    .send(Action::ClusterBucketHealthLoaded(Err(Arc::new(e))))
+                    .await;
+            }
+        }
+    });
+}
+
+/// Handle loading per-index replication/search factor compliance.
+pub async fn handle_load_cluster_index_compliance(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        match client.get_cluster_index_compliance().await {
+            Ok(indexes) => {
+                let _ = tx
+                    .send(Action::ClusterIndexComplianceLoaded(Ok(indexes)))
+                    .await;
+            }
+            Err(e) => {
+                let _ = tx
+                    .send(Action::ClusterIndexComplianceLoaded(Err(Arc::new(e))))
+                    .await;
+            }
+        }
+    });
+}
+
 /// Handle loading cluster peers.
 pub async fn handle_load_cluster_peers(
     client: SharedClient,
@@ -2,7 +2,7 @@
 //!
 //! Responsibilities:
 //! - Handle async API calls for config operations.
-//! - Fetch config files, stanzas, and stanza details.
+//! - Fetch config files, stanzas, stanza details, and per-context stanza layers.
 //!
 //! Does NOT handle:
 //! - Direct state modification (sends actions for that).
@@ -59,3 +59,32 @@ pub async fn handle_load_config_stanzas(
         }
     });
 }
+
+/// Handle loading every context's copy of a config stanza.
+pub async fn handle_load_config_layers(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    config_file: String,
+    stanza: String,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        match client
+            .list_config_stanza_layers(&config_file, Some(&stanza))
+            .await
+        {
+            Ok(layers) => {
+                let _ = tx.send(Action::ConfigLayersLoaded(Ok(layers))).await;
+            }
+            Err(e) => {
+                let error_msg = format!(
+                    "Failed to load layers for '{}.conf [{}]': {}",
+                    config_file, stanza, e
+                );
+                let _ = tx.send(Action::ConfigLayersLoaded(Err(Arc::new(e)))).await;
+                let _ = tx.send(Action::Notify(ToastLevel::Error, error_msg)).await;
+            }
+        }
+    });
+}
@@ -3,12 +3,14 @@
 //! Responsibilities:
 //! - Handle async API calls for dashboard operations.
 //! - Fetch dashboard lists from the Splunk server.
+//! - Fetch a single dashboard's source definition for the detail popup.
 //!
 //! Does NOT handle:
 //! - Direct state modification (sends actions for that).
 //! - UI rendering.
 
 use crate::action::Action;
+use std::sync::Arc;
 use tokio::sync::mpsc::Sender;
 
 use super::paginated::build_paginated_action;
@@ -27,7 +29,9 @@ pub async fn handle_load_dashboards(
 ) {
     let _ = tx.send(Action::Loading(true)).await;
     task_tracker.spawn(async move {
-        let result = client.list_dashboards(Some(count), Some(offset)).await;
+        let result = client
+            .list_dashboards(Some(count), Some(offset), None)
+            .await;
         let action = build_paginated_action(
             result,
             offset,
@@ -37,3 +41,19 @@ pub async fn handle_load_dashboards(
         let _ = tx.send(action).await;
     });
 }
+
+/// Handle loading a single dashboard with its source definition.
+///
+/// Emits `DashboardSourceLoaded` with the dashboard (including `eai:data`).
+pub async fn handle_load_dashboard_source(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    name: String,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = client.get_dashboard(&name).await.map_err(Arc::new);
+        let _ = tx.send(Action::DashboardSourceLoaded(result)).await;
+    });
+}
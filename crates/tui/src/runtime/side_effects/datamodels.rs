@@ -3,12 +3,14 @@
 //! Responsibilities:
 //! - Handle async API calls for data model operations.
 //! - Fetch data model lists from the Splunk server.
+//! - Fetch a data model definition for the tstats query builder.
 //!
 //! Does NOT handle:
 //! - Direct state modification (sends actions for that).
 //! - UI rendering.
 
 use crate::action::Action;
+use std::sync::Arc;
 use tokio::sync::mpsc::Sender;
 
 use super::paginated::build_paginated_action;
@@ -37,3 +39,19 @@ pub async fn handle_load_datamodels(
         let _ = tx.send(action).await;
     });
 }
+
+/// Handle loading a data model definition for the tstats query builder.
+///
+/// Emits `TstatsBuilderLoaded` with the data model (including `eai:data`).
+pub async fn handle_load_tstats_builder(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    name: String,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = client.get_datamodel(&name).await.map_err(Arc::new);
+        let _ = tx.send(Action::TstatsBuilderLoaded(result)).await;
+    });
+}
@@ -5,10 +5,12 @@
 
 use crate::action::Action;
 use crate::runtime::side_effects::{
-    SharedClient, TaskTracker, alerts, apps, audit, cluster, configs, dashboards, datamodels,
-    export, forwarders, health, indexes, inputs, jobs, kvstore, license, logs, lookups, macros,
-    multi_instance, overview, profiles, roles, search_peers, searches, shc, users, workload,
+    SharedClient, TaskTracker, acl, alerts, apps, audit, cluster, configs, dashboards, datamodels,
+    export, forwarders, health, indexes, ingestion, inputs, jobs, kvstore, license, logs, lookups,
+    macros, messages, metadata, multi_instance, overview, profiles, rest, roles, search_peers,
+    searches, server, shc, users, wizard, workload,
 };
+use splunk_client::JobFilter;
 use splunk_config::ConfigManager;
 use std::sync::Arc;
 use std::time::Instant;
@@ -80,7 +82,22 @@ async fn handle_action(
             indexes::handle_load_indexes(client, tx, task_tracker.clone(), count, offset).await;
         }
         Action::LoadJobs { count, offset } => {
-            jobs::handle_load_jobs(client, tx, task_tracker.clone(), count, offset).await;
+            jobs::handle_load_jobs(
+                client,
+                tx,
+                task_tracker.clone(),
+                count,
+                offset,
+                JobFilter::default(),
+            )
+            .await;
+        }
+        Action::LoadFilteredJobs {
+            count,
+            offset,
+            filter,
+        } => {
+            jobs::handle_load_jobs(client, tx, task_tracker.clone(), count, offset, filter).await;
         }
         Action::LoadClusterInfo => {
             cluster::handle_load_cluster_info(client, tx, task_tracker.clone()).await;
@@ -88,6 +105,12 @@ async fn handle_action(
         Action::LoadClusterPeers => {
             cluster::handle_load_cluster_peers(client, tx, task_tracker.clone()).await;
         }
+        Action::LoadClusterBucketHealth => {
+            cluster::handle_load_cluster_bucket_health(client, tx, task_tracker.clone()).await;
+        }
+        Action::LoadClusterIndexCompliance => {
+            cluster::handle_load_cluster_index_compliance(client, tx, task_tracker.clone()).await;
+        }
         // Cluster management actions
         Action::SetMaintenanceMode { enable } => {
             cluster::handle_set_maintenance_mode(client, tx, task_tracker.clone(), enable).await;
@@ -146,22 +169,26 @@ async fn handle_action(
         Action::DeleteMacro { name } => {
             macros::handle_delete_macro(client, tx, task_tracker.clone(), name).await;
         }
+        Action::LoadMacroExpansion { query } => {
+            macros::handle_load_macro_expansion(client, tx, task_tracker.clone(), query).await;
+        }
         Action::UpdateSavedSearch {
             name,
             search,
             description,
             disabled,
+            dispatch_earliest,
+            dispatch_latest,
         } => {
-            searches::handle_update_saved_search(
-                client,
-                tx,
-                task_tracker.clone(),
-                name,
+            let params = splunk_client::models::SavedSearchUpdateParams {
                 search,
                 description,
                 disabled,
-            )
-            .await;
+                dispatch_earliest_time: dispatch_earliest,
+                dispatch_latest_time: dispatch_latest,
+            };
+            searches::handle_update_saved_search(client, tx, task_tracker.clone(), name, params)
+                .await;
         }
         Action::CreateSavedSearch {
             name,
@@ -187,6 +214,26 @@ async fn handle_action(
             searches::handle_toggle_saved_search(client, tx, task_tracker.clone(), name, disabled)
                 .await;
         }
+        Action::BulkUpdateSavedSearches { names, action } => {
+            searches::handle_bulk_update_saved_searches(
+                client,
+                tx,
+                task_tracker.clone(),
+                names,
+                action,
+            )
+            .await;
+        }
+        Action::ReassignSavedSearches { names, to_owner } => {
+            searches::handle_reassign_saved_searches(
+                client,
+                tx,
+                task_tracker.clone(),
+                names,
+                to_owner,
+            )
+            .await;
+        }
         Action::LoadInternalLogs { count, earliest } => {
             logs::handle_load_internal_logs(client, tx, task_tracker.clone(), count, earliest)
                 .await;
@@ -228,9 +275,61 @@ async fn handle_action(
         Action::DeleteLookup { name, app, owner } => {
             lookups::handle_delete_lookup(client, tx, task_tracker.clone(), name, app, owner).await;
         }
+        Action::LoadLookupContent { name, app, owner } => {
+            lookups::handle_load_lookup_content(client, tx, task_tracker.clone(), name, app, owner)
+                .await;
+        }
+        Action::SaveLookupContent {
+            name,
+            app,
+            owner,
+            content,
+        } => {
+            lookups::handle_save_lookup_content(
+                client,
+                tx,
+                task_tracker.clone(),
+                name,
+                app,
+                owner,
+                content,
+            )
+            .await;
+        }
         Action::LoadInputs { count, offset } => {
             inputs::handle_load_inputs(client, tx, task_tracker.clone(), count, offset).await;
         }
+        Action::LoadInputThroughput => {
+            inputs::handle_load_input_throughput(client, tx, task_tracker.clone()).await;
+        }
+        Action::LoadHecTokens => {
+            inputs::handle_load_hec_tokens(client, tx, task_tracker.clone()).await;
+        }
+        Action::LoadIngestionStats => {
+            ingestion::handle_load_ingestion_stats(client, tx, task_tracker.clone()).await;
+        }
+        Action::LoadSourcetypes => {
+            metadata::handle_load_sourcetypes(client, tx, task_tracker.clone()).await;
+        }
+        Action::LoadMetadata { metadata_type } => {
+            metadata::handle_load_metadata(client, tx, task_tracker.clone(), metadata_type).await;
+        }
+        Action::RunFieldExtractionTest {
+            samples,
+            extractions,
+        } => {
+            metadata::handle_run_field_extraction_test(
+                client,
+                tx,
+                task_tracker.clone(),
+                samples,
+                extractions,
+            )
+            .await;
+        }
+        Action::SendRestRequest(request) => {
+            rest::handle_send_rest_request(client, tx, task_tracker.clone(), request).await;
+        }
         Action::LoadConfigFiles => {
             configs::handle_load_config_files(client, tx, task_tracker.clone()).await;
         }
@@ -252,12 +351,36 @@ async fn handle_action(
             )
             .await;
         }
+        Action::LoadConfigLayers {
+            config_file,
+            stanza,
+        } => {
+            configs::handle_load_config_layers(
+                client,
+                tx,
+                task_tracker.clone(),
+                config_file,
+                stanza,
+            )
+            .await;
+        }
         Action::EnableInput { input_type, name } => {
             inputs::handle_enable_input(client, tx, task_tracker.clone(), input_type, name).await;
         }
         Action::DisableInput { input_type, name } => {
             inputs::handle_disable_input(client, tx, task_tracker.clone(), input_type, name).await;
         }
+        Action::CreateInput { params } => {
+            inputs::handle_create_input(client, tx, task_tracker.clone(), params).await;
+        }
+        Action::EnableHecToken { name } => {
+            inputs::handle_set_hec_token_enabled(client, tx, task_tracker.clone(), name, true)
+                .await;
+        }
+        Action::DisableHecToken { name } => {
+            inputs::handle_set_hec_token_enabled(client, tx, task_tracker.clone(), name, false)
+                .await;
+        }
         Action::SwitchToSettings => {
             profiles::handle_switch_to_settings(config_manager, tx, task_tracker.clone()).await;
         }
@@ -266,6 +389,7 @@ async fn handle_action(
             search_defaults,
             search_mode,
             realtime_window,
+            finalize_after,
         } => {
             searches::handle_run_search(
                 client,
@@ -275,6 +399,7 @@ async fn handle_action(
                 search_defaults,
                 search_mode,
                 realtime_window,
+                finalize_after,
             )
             .await;
         }
@@ -305,21 +430,75 @@ async fn handle_action(
         Action::DeleteJobsBatch(sids) => {
             jobs::handle_delete_jobs_batch(client, tx, task_tracker.clone(), sids).await;
         }
+        Action::ExtendJobTtl { sid, ttl_secs } => {
+            jobs::handle_extend_job_ttl(client, tx, task_tracker.clone(), sid, ttl_secs).await;
+        }
+        Action::LoadJobPerformance { sid } => {
+            jobs::handle_load_job_performance(client, tx, task_tracker.clone(), sid).await;
+        }
+        Action::FindPrunableJobs(policy) => {
+            jobs::handle_find_prunable_jobs(client, tx, task_tracker.clone(), policy).await;
+        }
         Action::EnableApp(name) => {
             apps::handle_enable_app(client, tx, task_tracker.clone(), name).await;
         }
         Action::DisableApp(name) => {
             apps::handle_disable_app(client, tx, task_tracker.clone(), name).await;
         }
-        Action::InstallApp { file_path } => {
-            apps::handle_install_app(client, tx, task_tracker.clone(), file_path).await;
+        Action::InstallApp { source } => {
+            apps::handle_install_app(client, tx, task_tracker.clone(), source).await;
+        }
+        Action::SearchSplunkbase { query } => {
+            apps::handle_search_splunkbase(tx, task_tracker.clone(), query).await;
         }
         Action::RemoveApp { app_name } => {
             apps::handle_remove_app(client, tx, task_tracker.clone(), app_name).await;
         }
+        Action::LoadAppContents { app } => {
+            apps::handle_load_app_contents(client, tx, task_tracker.clone(), app).await;
+        }
+        Action::AddSearchPeer { params } => {
+            search_peers::handle_add_search_peer(client, tx, task_tracker.clone(), params).await;
+        }
+        Action::RemoveSearchPeer(name) => {
+            search_peers::handle_remove_search_peer(client, tx, task_tracker.clone(), name).await;
+        }
+        Action::SetSearchPeerQuarantine { name, quarantine } => {
+            search_peers::handle_set_search_peer_quarantine(
+                client,
+                tx,
+                task_tracker.clone(),
+                name,
+                quarantine,
+            )
+            .await;
+        }
+        Action::LoadServerMessages => {
+            messages::handle_load_server_messages(client, tx, task_tracker.clone()).await;
+        }
+        Action::DismissServerMessage { name } => {
+            messages::handle_dismiss_server_message(client, tx, task_tracker.clone(), name).await;
+        }
+        Action::RestartServer => {
+            server::handle_restart_server(client, tx, task_tracker.clone()).await;
+        }
+        Action::SetNamespace(namespace) => {
+            // Applied synchronously so the screen reload that follows uses it.
+            client.set_namespace(namespace);
+        }
+        Action::LoadObjectAcl { kind, name } => {
+            acl::handle_load_object_acl(client, tx, task_tracker.clone(), kind, name).await;
+        }
+        Action::UpdateObjectAcl { kind, name, params } => {
+            acl::handle_update_object_acl(client, tx, task_tracker.clone(), kind, name, params)
+                .await;
+        }
         Action::LoadHealth => {
             health::handle_load_health(client, tx, task_tracker.clone()).await;
         }
+        Action::LoadResourceUsage => {
+            health::handle_load_resource_usage(client, tx, task_tracker.clone()).await;
+        }
         Action::RunConnectionDiagnostics => {
             health::handle_run_connection_diagnostics(client, tx, task_tracker.clone()).await;
         }
@@ -376,6 +555,18 @@ async fn handle_action(
         Action::DeleteIndex { name } => {
             indexes::handle_delete_index(client, tx, task_tracker.clone(), name).await;
         }
+        Action::RollHotBuckets { name } => {
+            indexes::handle_roll_hot_buckets(client, tx, task_tracker.clone(), name).await;
+        }
+        Action::RebalanceIndexData { index } => {
+            indexes::handle_rebalance_index_data(client, tx, task_tracker.clone(), index).await;
+        }
+        Action::LoadIndexSample { index } => {
+            indexes::handle_load_index_sample(client, tx, task_tracker.clone(), index).await;
+        }
+        Action::RunOnboardingWizard { plan } => {
+            wizard::handle_run_onboarding_wizard(client, tx, task_tracker.clone(), plan).await;
+        }
         // User operations
         Action::CreateUser { params } => {
             users::handle_create_user(client, tx, task_tracker.clone(), params).await;
@@ -386,6 +577,15 @@ async fn handle_action(
         Action::DeleteUser { name } => {
             users::handle_delete_user(client, tx, task_tracker.clone(), name).await;
         }
+        Action::UnlockUser { name } => {
+            users::handle_unlock_user(client, tx, task_tracker.clone(), name).await;
+        }
+        Action::FindOrphanedObjects => {
+            users::handle_find_orphaned_objects(client, tx, task_tracker.clone()).await;
+        }
+        Action::LoadCurrentContext => {
+            users::handle_load_current_context(client, tx, task_tracker.clone()).await;
+        }
         // Role operations
         Action::LoadRoles { count, offset } => {
             roles::handle_load_roles(client, tx, task_tracker.clone(), count, offset).await;
@@ -393,6 +593,12 @@ async fn handle_action(
         Action::LoadCapabilities => {
             roles::handle_load_capabilities(client, tx, task_tracker.clone()).await;
         }
+        Action::LoadRoleUsage => {
+            roles::handle_load_role_usage(client, tx, task_tracker.clone()).await;
+        }
+        Action::LoadRoleCapabilityMatrix => {
+            roles::handle_load_role_capability_matrix(client, tx, task_tracker.clone()).await;
+        }
         Action::CreateRole { params } => {
             roles::handle_create_role(client, tx, task_tracker.clone(), params).await;
         }
@@ -480,14 +686,40 @@ async fn handle_action(
         Action::LoadRecentAuditEvents { count } => {
             audit::handle_load_recent_audit_events(client, tx, task_tracker.clone(), count).await;
         }
+        Action::LoadUserActivity { user } => {
+            audit::handle_load_user_activity(client, tx, task_tracker.clone(), user).await;
+        }
         Action::LoadDashboards { count, offset } => {
             dashboards::handle_load_dashboards(client, tx, task_tracker.clone(), count, offset)
                 .await;
         }
+        Action::LoadDashboardSource { name } => {
+            dashboards::handle_load_dashboard_source(client, tx, task_tracker.clone(), name).await;
+        }
         Action::LoadDataModels { count, offset } => {
             datamodels::handle_load_datamodels(client, tx, task_tracker.clone(), count, offset)
                 .await;
         }
+        Action::LoadTstatsBuilder { name } => {
+            datamodels::handle_load_tstats_builder(client, tx, task_tracker.clone(), name).await;
+        }
+        Action::LoadTopValues {
+            query,
+            field,
+            earliest_time,
+            latest_time,
+        } => {
+            searches::handle_load_top_values(
+                client,
+                tx,
+                task_tracker.clone(),
+                query,
+                field,
+                earliest_time,
+                latest_time,
+            )
+            .await;
+        }
         Action::LoadWorkloadPools { count, offset } => {
             workload::handle_load_workload_pools(client, tx, task_tracker.clone(), count, offset)
                 .await;
@@ -2,6 +2,7 @@
 //!
 //! Responsibilities:
 //! - Handle async data export operations.
+//! - Show export progress and remove partial files when cancelled.
 //!
 //! Does NOT handle:
 //! - Direct state modification (sends actions for that).
@@ -9,6 +10,7 @@
 
 use crate::action::Action;
 use crate::action::ExportFormat;
+use crate::operation_progress::ProgressReporter;
 use crate::runtime::side_effects::TaskTracker;
 use crate::ui::ToastLevel;
 use serde_json::Value;
@@ -24,8 +26,19 @@ pub async fn handle_export_data(
     task_tracker: TaskTracker,
 ) {
     task_tracker.spawn(async move {
-        match crate::export::export_value(&data, &path, format).await {
-            Ok(_) => {
+        let reporter =
+            ProgressReporter::start(&tx, format!("Exporting to {}", path.display()), Some(1)).await;
+        let result = reporter
+            .run(crate::export::export_value(&data, &path, format))
+            .await;
+        reporter.finish().await;
+
+        match result {
+            None => {
+                // Don't leave a partially written file behind.
+                let _ = tokio::fs::remove_file(&path).await;
+            }
+            Some(Ok(_)) => {
                 let _ = tx
                     .send(Action::Notify(
                         ToastLevel::Info,
@@ -34,7 +47,7 @@ pub async fn handle_export_data(
                     .await;
                 let _ = tx.send(Action::ExportSuccess(path)).await;
             }
-            Err(e) => {
+            Some(Err(e)) => {
                 let _ = tx
                     .send(Action::Notify(
                         ToastLevel::Error,
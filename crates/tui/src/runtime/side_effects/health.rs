@@ -3,6 +3,7 @@
 //! Responsibilities:
 //! - Handle async API calls for health check operations.
 //! - Collect health information from multiple endpoints.
+//! - Load per-host resource usage from introspection.
 //!
 //! Does NOT handle:
 //! - Direct state modification (sends actions for that).
@@ -58,6 +59,25 @@ pub async fn handle_load_health(
     });
 }
 
+/// Handle loading per-host resource usage and top processes.
+pub async fn handle_load_resource_usage(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        match client.check_resource_usage(None).await {
+            Ok(usage) => {
+                let _ = tx.send(Action::ResourceUsageLoaded(Ok(usage))).await;
+            }
+            Err(e) => {
+                let _ = tx.send(Action::ResourceUsageLoaded(Err(Arc::new(e)))).await;
+            }
+        }
+    });
+}
+
 /// Run comprehensive connection diagnostics.
 ///
 /// This performs a shared diagnostics probe checking:
@@ -4,6 +4,8 @@
 //! - Handle async API calls for index operations.
 //! - Fetch index lists from the Splunk server.
 //! - Create, modify, and delete indexes.
+//! - Roll hot buckets and start data rebalances.
+//! - Sample the newest raw events of an index.
 //!
 //! Does NOT handle:
 //! - Direct state modification (sends actions for that).
@@ -11,7 +13,8 @@
 
 use crate::action::Action;
 use crate::ui::ToastLevel;
-use splunk_client::{CreateIndexParams, ModifyIndexParams};
+use splunk_client::workflows::event_sample::{DEFAULT_SAMPLE_COUNT, sample_index_events};
+use splunk_client::{CreateIndexParams, DataRebalanceAction, ModifyIndexParams};
 use splunk_config::constants::DEFAULT_LIST_PAGE_SIZE;
 use std::sync::Arc;
 use tokio::sync::mpsc::Sender;
@@ -163,3 +166,88 @@ pub async fn handle_delete_index(
         }
     });
 }
+
+/// Handle rolling an index's hot buckets, then refresh the list.
+pub async fn handle_roll_hot_buckets(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    name: String,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        match client.roll_hot_buckets(&name).await {
+            Ok(()) => {
+                let _ = tx
+                    .send(Action::Notify(
+                        ToastLevel::Success,
+                        format!("Hot buckets of index '{}' rolled to warm", name),
+                    ))
+                    .await;
+                // Refresh indexes list (bucket counts change)
+                let _ = tx
+                    .send(Action::LoadIndexes {
+                        count: DEFAULT_LIST_PAGE_SIZE,
+                        offset: 0,
+                    })
+                    .await;
+            }
+            Err(e) => {
+                let _ = tx
+                    .send(Action::Notify(
+                        ToastLevel::Error,
+                        format!("Failed to roll hot buckets of index '{}': {}", name, e),
+                    ))
+                    .await;
+                let _ = tx.send(Action::Loading(false)).await;
+            }
+        }
+    });
+}
+
+/// Handle starting a data rebalance for an index.
+pub async fn handle_rebalance_index_data(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    index: String,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let notification = match client
+            .control_data_rebalance(DataRebalanceAction::Start, Some(&index))
+            .await
+        {
+            Ok(response) => Action::Notify(
+                ToastLevel::Success,
+                response
+                    .message
+                    .unwrap_or_else(|| format!("Data rebalance started for {}", index)),
+            ),
+            Err(e) => Action::Notify(
+                ToastLevel::Error,
+                format!("Failed to start data rebalance for '{}': {}", index, e),
+            ),
+        };
+        let _ = tx.send(notification).await;
+        let _ = tx.send(Action::Loading(false)).await;
+    });
+}
+
+/// Handle sampling the newest raw events of an index.
+///
+/// Emits `IndexSampleLoaded` with the events or a user-facing error.
+pub async fn handle_load_index_sample(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    index: String,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = sample_index_events(&client, &index, None, DEFAULT_SAMPLE_COUNT)
+            .await
+            .map_err(|e| e.to_string());
+        let _ = tx.send(Action::IndexSampleLoaded { index, result }).await;
+    });
+}
@@ -0,0 +1,35 @@
+//! Side effects for the Ingestion screen.
+//!
+//! Responsibilities:
+//! - Handle LoadIngestionStats to fetch indexing latency and thruput per sourcetype
+//!
+//! Does NOT handle:
+//! - UI rendering (handled by screen module)
+//! - Sorting the table (handled by the app)
+
+use std::sync::Arc;
+
+use splunk_client::IngestionGroupBy;
+use tokio::sync::mpsc::Sender;
+
+use crate::action::Action;
+use crate::runtime::side_effects::{SharedClient, TaskTracker};
+
+/// Look-back window for the Ingestion screen, matching the "last hour" in its title.
+pub const INGESTION_WINDOW_SECS: u64 = 3600;
+
+/// Handle loading indexing latency and thruput per sourcetype.
+pub async fn handle_load_ingestion_stats(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = client
+            .get_ingestion_stats(IngestionGroupBy::Sourcetype, INGESTION_WINDOW_SECS)
+            .await
+            .map_err(Arc::new);
+        let _ = tx.send(Action::IngestionStatsLoaded(result)).await;
+    });
+}
@@ -2,7 +2,9 @@
 //!
 //! Responsibilities:
 //! - Handle async API calls for input operations.
-//! - Fetch input lists, enable inputs, disable inputs.
+//! - Fetch input lists, enable inputs, disable inputs, create inputs.
+//! - Fetch per-source ingestion throughput for the inputs table.
+//! - Fetch, enable, and disable HEC tokens for the HTTP inputs view.
 //!
 //! Does NOT handle:
 //! - Direct state modification (sends actions for that).
@@ -12,7 +14,9 @@ use super::paginated::build_paginated_action;
 use super::{SharedClient, TaskTracker};
 use crate::action::Action;
 use crate::ui::ToastLevel;
+use splunk_client::CreateInputParams;
 use splunk_config::constants::DEFAULT_LIST_PAGE_SIZE;
+use std::sync::Arc;
 use tokio::sync::mpsc::Sender;
 
 /// Handle loading inputs with pagination support.
@@ -39,6 +43,25 @@ pub async fn handle_load_inputs(
     });
 }
 
+/// Look-back window for input throughput, matching the "last hour" shown in the table.
+pub const INPUT_THROUGHPUT_WINDOW_SECS: u64 = 3600;
+
+/// Handle loading per-source ingestion throughput from metrics.log.
+pub async fn handle_load_input_throughput(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = client
+            .get_input_throughput(INPUT_THROUGHPUT_WINDOW_SECS)
+            .await
+            .map_err(Arc::new);
+        let _ = tx.send(Action::InputThroughputLoaded(result)).await;
+    });
+}
+
 /// Handle enabling an input.
 pub async fn handle_enable_input(
     client: SharedClient,
@@ -116,3 +139,96 @@ pub async fn handle_disable_input(
         }
     });
 }
+
+/// Handle creating a monitor, TCP, or UDP input.
+pub async fn handle_create_input(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    params: CreateInputParams,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let name = params.name();
+        match client.create_input(&params).await {
+            Ok(input) => {
+                let _ = tx
+                    .send(Action::Notify(
+                        ToastLevel::Success,
+                        format!(
+                            "Input '{}' ({}) created successfully",
+                            input.name, input.input_type
+                        ),
+                    ))
+                    .await;
+                // Refresh inputs list (reset pagination)
+                let _ = tx
+                    .send(Action::LoadInputs {
+                        count: DEFAULT_LIST_PAGE_SIZE,
+                        offset: 0,
+                    })
+                    .await;
+            }
+            Err(e) => {
+                let _ = tx
+                    .send(Action::Notify(
+                        ToastLevel::Error,
+                        format!("Failed to create input '{}': {}", name, e),
+                    ))
+                    .await;
+                let _ = tx.send(Action::Loading(false)).await;
+            }
+        }
+    });
+}
+
+/// Handle loading HEC tokens.
+pub async fn handle_load_hec_tokens(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = client.list_hec_tokens().await.map_err(Arc::new);
+        let _ = tx.send(Action::HecTokensLoaded(result)).await;
+    });
+}
+
+/// Handle enabling or disabling a HEC token.
+pub async fn handle_set_hec_token_enabled(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    name: String,
+    enabled: bool,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let (result, verb) = if enabled {
+            (client.enable_hec_token(&name).await, "enable")
+        } else {
+            (client.disable_hec_token(&name).await, "disable")
+        };
+        match result {
+            Ok(()) => {
+                let _ = tx
+                    .send(Action::Notify(
+                        ToastLevel::Success,
+                        format!("HEC token '{}' {}d successfully", name, verb),
+                    ))
+                    .await;
+                let _ = tx.send(Action::LoadHecTokens).await;
+            }
+            Err(e) => {
+                let _ = tx
+                    .send(Action::Notify(
+                        ToastLevel::Error,
+                        format!("Failed to {} HEC token '{}': {}", verb, name, e),
+                    ))
+                    .await;
+                let _ = tx.send(Action::Loading(false)).await;
+            }
+        }
+    });
+}
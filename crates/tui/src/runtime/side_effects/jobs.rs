@@ -2,15 +2,21 @@
 //!
 //! Responsibilities:
 //! - Handle async API calls for job operations.
-//! - Fetch job lists, cancel jobs, delete jobs, and batch operations.
+//! - Fetch job lists and job performance, cancel jobs, delete jobs, extend job TTLs, and batch operations.
+//! - Find jobs old enough to prune (deletion goes through the batch delete path).
+//! - Report batch progress through the shared operation progress popup.
 //!
 //! Does NOT handle:
 //! - Direct state modification (sends actions for that).
 //! - UI rendering.
 
 use crate::action::Action;
+use crate::operation_progress::ProgressReporter;
 use crate::ui::ToastLevel;
+use splunk_client::JobFilter;
+use splunk_client::workflows::job_prune::{JobPrunePolicy, find_prunable_jobs};
 use splunk_config::constants::DEFAULT_LIST_PAGE_SIZE;
+use std::sync::Arc;
 use tokio::sync::mpsc::Sender;
 
 use super::paginated::build_paginated_action;
@@ -20,16 +26,20 @@ use super::{SharedClient, TaskTracker};
 ///
 /// Emits `JobsLoaded` when offset == 0 (initial load/refresh).
 /// Emits `MoreJobsLoaded` when offset > 0 (pagination).
+/// An empty `filter` lists every job visible to the current user.
 pub async fn handle_load_jobs(
     client: SharedClient,
     tx: Sender<Action>,
     task_tracker: TaskTracker,
     count: usize,
     offset: usize,
+    filter: JobFilter,
 ) {
     let _ = tx.send(Action::Loading(true)).await;
     task_tracker.spawn(async move {
-        let result = client.list_jobs(Some(count), Some(offset)).await;
+        let result = client
+            .list_jobs_filtered(Some(count), Some(offset), &filter)
+            .await;
         let action =
             build_paginated_action(result, offset, Action::JobsLoaded, Action::MoreJobsLoaded);
         let _ = tx.send(action).await;
@@ -74,6 +84,89 @@ pub async fn handle_cancel_job(
     });
 }
 
+/// Handle extending a single job's artifact TTL.
+pub async fn handle_extend_job_ttl(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    sid: String,
+    ttl_secs: u64,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        match client.set_job_ttl(&sid, ttl_secs).await {
+            Ok(_) => {
+                let _ = tx
+                    .send(Action::JobOperationComplete(format!(
+                        "Extended TTL of job {} to {}h",
+                        sid,
+                        ttl_secs / 3600
+                    )))
+                    .await;
+                // Reload the job list so the new TTL is shown
+                let _ = tx
+                    .send(Action::LoadJobs {
+                        count: DEFAULT_LIST_PAGE_SIZE,
+                        offset: 0,
+                    })
+                    .await;
+                // Refresh the Search screen's warning if these are its results
+                if let Ok(status) = client.get_job_status(&sid).await {
+                    let _ = tx.send(Action::SearchJobLoaded(Ok(status))).await;
+                }
+            }
+            Err(e) => {
+                let _ = tx
+                    .send(Action::Notify(
+                        ToastLevel::Error,
+                        format!("Failed to extend job TTL: {}", e),
+                    ))
+                    .await;
+                let _ = tx.send(Action::Loading(false)).await;
+            }
+        }
+    });
+}
+
+/// Handle fetching a job's status with its performance counters.
+pub async fn handle_load_job_performance(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    sid: String,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        match client.get_job_status(&sid).await {
+            Ok(status) => {
+                let _ = tx.send(Action::JobPerformanceLoaded(Ok(status))).await;
+            }
+            Err(e) => {
+                let _ = tx
+                    .send(Action::JobPerformanceLoaded(Err(Arc::new(e))))
+                    .await;
+            }
+        }
+    });
+}
+
+/// Handle finding jobs old enough to prune.
+pub async fn handle_find_prunable_jobs(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    policy: JobPrunePolicy,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = find_prunable_jobs(&client, &policy, None)
+            .await
+            .map(|jobs| jobs.into_iter().map(|job| job.sid).collect())
+            .map_err(|e| format!("{e:#}"));
+        let _ = tx.send(Action::PrunableJobsFound(result)).await;
+    });
+}
+
 /// Handle deleting a single job.
 pub async fn handle_delete_job(
     client: SharedClient,
@@ -129,16 +222,30 @@ pub async fn handle_cancel_jobs_batch(
         // and to provide clear per-job error reporting.
         // Parallelizing with join_all would require careful rate limiting
         // to avoid triggering Splunk's API throttling.
+        let mut reporter = ProgressReporter::start(
+            &tx_clone,
+            format!("Cancelling {} job(s)", sids.len()),
+            Some(sids.len()),
+        )
+        .await;
         for sid in sids {
-            match client.cancel_job(&sid).await {
-                Ok(_) => {
+            if reporter.is_cancelled() {
+                break;
+            }
+            reporter.begin_item(sid.as_str()).await;
+            match reporter.run(client.cancel_job(&sid)).await {
+                Some(Ok(_)) => {
                     success_count += 1;
+                    reporter.item_finished(true).await;
                 }
-                Err(e) => {
+                Some(Err(e)) => {
                     error_messages.push(format!("{}: {}", sid, e));
+                    reporter.item_finished(false).await;
                 }
+                None => break,
             }
         }
+        reporter.finish().await;
 
         let msg = if success_count > 0 {
             format!("Cancelled {} job(s)", success_count)
@@ -178,16 +285,30 @@ pub async fn handle_delete_jobs_batch(
         // Process jobs sequentially to avoid overwhelming the API
         // and to provide clear per-job error reporting.
         // See CancelJobsBatch for parallelization considerations.
+        let mut reporter = ProgressReporter::start(
+            &tx_clone,
+            format!("Deleting {} job(s)", sids.len()),
+            Some(sids.len()),
+        )
+        .await;
         for sid in sids {
-            match client.delete_job(&sid).await {
-                Ok(_) => {
+            if reporter.is_cancelled() {
+                break;
+            }
+            reporter.begin_item(sid.as_str()).await;
+            match reporter.run(client.delete_job(&sid)).await {
+                Some(Ok(_)) => {
                     success_count += 1;
+                    reporter.item_finished(true).await;
                 }
-                Err(e) => {
+                Some(Err(e)) => {
                     error_messages.push(format!("{}: {}", sid, e));
+                    reporter.item_finished(false).await;
                 }
+                None => break,
             }
         }
+        reporter.finish().await;
 
         let msg = if success_count > 0 {
             format!("Deleted {} job(s)", success_count)
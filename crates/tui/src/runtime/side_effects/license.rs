@@ -2,7 +2,8 @@
 //!
 //! Responsibilities:
 //! - Handle async API calls for license operations.
-//! - Collect license information from multiple endpoints (usage, pools, stacks).
+//! - Collect license information from multiple endpoints (usage, pools, stacks,
+//!   messages, slaves) and the daily usage trend search.
 //! - Handle license installation, pool management, and activation.
 //!
 //! Does NOT handle:
@@ -15,6 +16,7 @@ use std::sync::Arc;
 use tokio::sync::mpsc::Sender;
 
 use crate::action::{Action, LicenseData};
+use splunk_client::models::LICENSE_USAGE_HISTORY_DAYS;
 use splunk_client::{ClientError, CreatePoolParams, ModifyPoolParams};
 
 use super::{SharedClient, TaskTracker};
@@ -27,11 +29,14 @@ pub async fn handle_load_license(
 ) {
     let _ = tx.send(Action::Loading(true)).await;
     task_tracker.spawn(async move {
-        // Collect license data from all three endpoints
+        // Collect license data from all endpoints
         let mut license_data = LicenseData {
             usage: Vec::new(),
             pools: Vec::new(),
             stacks: Vec::new(),
+            messages: Vec::new(),
+            slaves: Vec::new(),
+            history: Vec::new(),
         };
 
         let mut first_error: Option<ClientError> = None;
@@ -66,6 +71,27 @@ pub async fn handle_load_license(
             }
         }
 
+        // Messages and slaves only feed the violation banner, and license
+        // slaves may not expose them, so failures leave them empty.
+        match client.list_license_messages().await {
+            Ok(messages) => license_data.messages = messages,
+            Err(e) => tracing::warn!("Failed to load licenser messages: {}", e),
+        }
+        match client.list_license_slaves().await {
+            Ok(slaves) => license_data.slaves = slaves,
+            Err(e) => tracing::warn!("Failed to load license slaves: {}", e),
+        }
+
+        // The trend needs `_internal` search access, which not every license
+        // viewer has; without it the trend panel shows as empty.
+        match client
+            .get_license_usage_history(LICENSE_USAGE_HISTORY_DAYS)
+            .await
+        {
+            Ok(history) => license_data.history = history,
+            Err(e) => tracing::warn!("Failed to load license usage history: {}", e),
+        }
+
         if let Some(e) = first_error {
             let _ = tx
                 .send(Action::LicenseLoaded(Box::new(Err(Arc::new(e)))))
@@ -5,6 +5,7 @@
 //! - Handle LoadMoreLookups action for pagination
 //! - Handle DownloadLookup action to download lookup files
 //! - Handle DeleteLookup action to delete lookup files
+//! - Handle LoadLookupContent/SaveLookupContent actions for the lookup editor
 //!
 //! Does NOT handle:
 //! - UI rendering (handled by screen module)
@@ -15,6 +16,8 @@ use std::sync::Arc;
 
 use tokio::sync::mpsc::Sender;
 
+use splunk_client::models::LookupContent;
+
 use crate::action::Action;
 use crate::runtime::side_effects::{SharedClient, TaskTracker, paginated::build_paginated_action};
 
@@ -31,7 +34,9 @@ pub async fn handle_load_lookups(
 ) {
     let _ = tx.send(Action::Loading(true)).await;
     task_tracker.spawn(async move {
-        let result = client.list_lookup_tables(Some(count), Some(offset)).await;
+        let result = client
+            .list_lookup_tables(Some(count), Some(offset), None)
+            .await;
         let action = build_paginated_action(
             result,
             offset,
@@ -112,3 +117,55 @@ pub async fn handle_delete_lookup(
         let _ = tx.send(Action::Loading(false)).await;
     });
 }
+
+/// Handle loading a lookup's contents for the lookup editor.
+///
+/// Emits `LookupContentLoaded` with the parsed header and rows, or error on failure.
+pub async fn handle_load_lookup_content(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    name: String,
+    app: Option<String>,
+    owner: Option<String>,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = client
+            .get_lookup_contents(&name, app.as_deref(), owner.as_deref())
+            .await
+            .map_err(Arc::new);
+        let _ = tx
+            .send(Action::LookupContentLoaded {
+                name,
+                app,
+                owner,
+                result,
+            })
+            .await;
+    });
+}
+
+/// Handle saving edited lookup contents.
+///
+/// Emits `LookupContentSaved` with the lookup name on success, or error on failure.
+pub async fn handle_save_lookup_content(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    name: String,
+    app: Option<String>,
+    owner: Option<String>,
+    content: LookupContent,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = client
+            .save_lookup_contents(&name, app.as_deref(), owner.as_deref(), &content)
+            .await
+            .map(|_| name)
+            .map_err(Arc::new);
+        let _ = tx.send(Action::LookupContentSaved(result)).await;
+        let _ = tx.send(Action::Loading(false)).await;
+    });
+}
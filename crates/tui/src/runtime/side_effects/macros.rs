@@ -10,6 +10,7 @@
 
 use crate::action::Action;
 use crate::runtime::side_effects::{SharedClient, TaskTracker};
+use splunk_client::workflows::macro_expansion::preview_macro_expansion;
 use splunk_client::{MacroCreateParams, MacroUpdateParams};
 use std::sync::Arc;
 use tokio::sync::mpsc::Sender;
@@ -56,7 +57,7 @@ pub async fn handle_load_macros(
 ) {
     let _ = action_tx.send(Action::Loading(true)).await;
     task_tracker.spawn(async move {
-        match client.list_macros().await {
+        match client.list_macros(None).await {
             Ok(macros) => {
                 let _ = action_tx.send(Action::MacrosLoaded(Ok(macros))).await;
             }
@@ -154,6 +155,24 @@ pub async fn handle_delete_macro(
     });
 }
 
+/// Expand every macro in a search query for the preview popup.
+///
+/// Emits `MacroExpansionLoaded`; cycles and malformed invocations surface as errors.
+pub async fn handle_load_macro_expansion(
+    client: SharedClient,
+    action_tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    query: String,
+) {
+    let _ = action_tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = preview_macro_expansion(&client, &query)
+            .await
+            .map_err(|e| e.to_string());
+        let _ = action_tx.send(Action::MacroExpansionLoaded(result)).await;
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
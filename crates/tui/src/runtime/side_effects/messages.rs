@@ -0,0 +1,56 @@
+//! Server messages (bulletin board) side effect handlers.
+//!
+//! Responsibilities:
+//! - Handle LoadServerMessages to fetch the bulletin board messages
+//! - Handle DismissServerMessage to delete a message, then refresh the list
+//!
+//! Does NOT handle:
+//! - Background polling (piggybacks on the health check task in main.rs)
+//! - UI rendering or read/unread tracking
+
+use std::sync::Arc;
+
+use tokio::sync::mpsc::Sender;
+
+use crate::action::Action;
+
+use super::{SharedClient, TaskTracker};
+
+/// Handle loading the server's bulletin board messages.
+///
+/// Emits `ServerMessagesLoaded` with the messages, or error on failure.
+pub async fn handle_load_server_messages(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+) {
+    task_tracker.spawn(async move {
+        let result = client.list_server_messages().await.map_err(Arc::new);
+        let _ = tx.send(Action::ServerMessagesLoaded(result)).await;
+    });
+}
+
+/// Handle dismissing a bulletin board message.
+///
+/// Emits `ServerMessageDismissed` with the message name on success, or error on
+/// failure, followed by `ServerMessagesLoaded` with the refreshed list.
+pub async fn handle_dismiss_server_message(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    name: String,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = client
+            .delete_server_message(&name)
+            .await
+            .map(|()| name)
+            .map_err(Arc::new);
+        let _ = tx.send(Action::ServerMessageDismissed(result)).await;
+
+        let refreshed = client.list_server_messages().await.map_err(Arc::new);
+        let _ = tx.send(Action::ServerMessagesLoaded(refreshed)).await;
+        let _ = tx.send(Action::Loading(false)).await;
+    });
+}
@@ -0,0 +1,75 @@
+//! Side effects for the index metadata screens.
+//!
+//! Responsibilities:
+//! - Handle LoadSourcetypes to fetch the sourcetype inventory
+//! - Handle LoadMetadata to fetch hosts or sources with last event times
+//! - Handle RunFieldExtractionTest to run regex extractions over sample events
+//!
+//! Does NOT handle:
+//! - UI rendering (handled by screen module)
+
+use std::sync::Arc;
+
+use splunk_client::MetadataType;
+use splunk_client::workflows::field_extraction::{Extraction, test_field_extraction};
+use tokio::sync::mpsc::Sender;
+
+use crate::action::Action;
+use crate::runtime::side_effects::{SharedClient, TaskTracker};
+
+/// Handle loading every sourcetype with its counts, first/last seen, and indexes.
+pub async fn handle_load_sourcetypes(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = client
+            .get_metadata(MetadataType::Sourcetypes, None)
+            .await
+            .map_err(Arc::new);
+        let _ = tx.send(Action::SourcetypesLoaded(result)).await;
+    });
+}
+
+/// Handle loading every host or source with its counts, first/last seen, and indexes.
+pub async fn handle_load_metadata(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    metadata_type: MetadataType,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = client
+            .get_metadata(metadata_type, None)
+            .await
+            .map_err(Arc::new);
+        let _ = tx
+            .send(Action::MetadataLoaded {
+                metadata_type,
+                result,
+            })
+            .await;
+    });
+}
+
+/// Handle running regex extractions over pasted sample events.
+///
+/// Emits `FieldExtractionTested`; nothing is indexed.
+pub async fn handle_run_field_extraction_test(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    samples: Vec<String>,
+    extractions: Vec<Extraction>,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = test_field_extraction(&client, &samples, &extractions)
+            .await
+            .map_err(|e| e.to_string());
+        let _ = tx.send(Action::FieldExtractionTested(result)).await;
+    });
+}
@@ -82,6 +82,7 @@ mod types;
 mod dispatcher;
 
 // Domain-specific handlers
+mod acl;
 mod alerts;
 mod apps;
 mod audit;
@@ -93,6 +94,7 @@ mod export;
 mod forwarders;
 mod health;
 mod indexes;
+mod ingestion;
 mod inputs;
 mod jobs;
 mod kvstore;
@@ -100,16 +102,21 @@ mod license;
 mod logs;
 mod lookups;
 mod macros;
+mod messages;
+mod metadata;
 mod multi_instance;
 mod overview;
 mod overview_fetch;
 mod paginated;
 mod profiles;
+mod rest;
 mod roles;
 mod search_peers;
 mod searches;
+mod server;
 mod shc;
 mod users;
+mod wizard;
 mod workload;
 
 // Re-export public API
@@ -6,6 +6,7 @@
 //! Responsibilities:
 //! - Load multi-instance overview data from shared client workflows.
 //! - Emit incremental and aggregate TUI actions from the shared payload.
+//! - Report per-profile progress and honor cancellation from the progress popup.
 //!
 //! Scope:
 //! - TUI action dispatch only; aggregation lives in `splunk-client`.
@@ -17,8 +18,9 @@
 //! - Shared multi-profile workflow is the source of truth for dashboard aggregation.
 
 use crate::action::Action;
+use crate::operation_progress::ProgressReporter;
 use splunk_client::workflows::multi_profile::{
-    fetch_instance_overview, fetch_multi_instance_overview,
+    fetch_instance_overview, fetch_multi_instance_overview_with_progress,
 };
 use splunk_config::ConfigManager;
 use std::sync::Arc;
@@ -46,7 +48,24 @@ pub async fn handle_load_multi_instance_overview(
                 .collect::<Vec<_>>()
         };
 
-        match fetch_multi_instance_overview(profiles, None).await {
+        let mut reporter = ProgressReporter::start(
+            &tx,
+            format!("Refreshing {} profile(s)", profiles.len()),
+            Some(profiles.len()),
+        )
+        .await;
+        let probe = reporter.probe();
+        let result =
+            fetch_multi_instance_overview_with_progress(profiles, Some(&probe), &mut |instance| {
+                reporter.item_finished_now(instance.profile_name.as_str(), instance.error.is_none())
+            })
+            .await;
+        let cancelled = reporter.finish().await;
+
+        match result {
+            Err(_) if cancelled => {
+                let _ = tx.send(Action::Loading(false)).await;
+            }
             Ok(data) => {
                 for instance in data.instances.iter().cloned() {
                     let _ = tx.send(Action::MultiInstanceInstanceLoaded(instance)).await;
@@ -228,7 +228,7 @@ pub async fn fetch_license(
 pub async fn fetch_saved_searches(
     client: &splunk_client::SplunkClient,
 ) -> Result<OverviewResource, ClientError> {
-    match tokio::time::timeout(FETCH_TIMEOUT, client.list_saved_searches(None, None)).await {
+    match tokio::time::timeout(FETCH_TIMEOUT, client.list_saved_searches(None, None, None)).await {
         Ok(Ok(saved_searches)) => Ok(OverviewResource {
             resource_type: "saved-searches".to_string(),
             count: saved_searches.len(),
@@ -50,7 +50,7 @@ pub async fn handle_switch_to_settings(
     task_tracker.spawn(async move {
         let cm = config_manager.lock().await;
         let state = cm.load();
-        let _ = tx.send(Action::SettingsLoaded(state)).await;
+        let _ = tx.send(Action::SettingsLoaded(Box::new(state))).await;
     });
 }
 
@@ -161,6 +161,7 @@ pub async fn handle_profile_selected(
             profile_name: Some(profile_name.clone()),
             base_url,
             auth_mode,
+            health_thresholds: profile_config.health_thresholds.clone(),
         };
         let _ = tx.send(Action::ProfileSwitchResult(Ok(ctx))).await;
         let _ = tx.send(Action::ClearAllData).await;
@@ -229,6 +230,7 @@ fn build_client_for_profile(
         .timeout(std::time::Duration::from_secs(
             profile_config.timeout_seconds.unwrap_or(30),
         ))
+        .health_thresholds(profile_config.health_thresholds.clone().unwrap_or_default())
         .build()
         .map_err(|e| {
             Arc::new(ClientError::InvalidRequest(format!(
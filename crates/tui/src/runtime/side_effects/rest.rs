@@ -0,0 +1,39 @@
+//! REST explorer side effect handlers.
+//!
+//! Responsibilities:
+//! - Handle SendRestRequest to send a raw request to any REST endpoint
+//!
+//! Does NOT handle:
+//! - Parsing request lines or recording history (handled by app::rest_explorer)
+//! - Pretty-printing the response (handled when RestResponseLoaded is applied)
+
+use std::sync::Arc;
+
+use tokio::sync::mpsc::Sender;
+
+use crate::action::Action;
+use crate::app::rest_explorer::RestRequest;
+
+use super::{SharedClient, TaskTracker};
+
+/// Handle sending a raw REST request.
+pub async fn handle_send_rest_request(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    request: RestRequest,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = client
+            .raw_request(request.method, &request.path, &request.query, &request.form)
+            .await
+            .map_err(Arc::new);
+        let _ = tx
+            .send(Action::RestResponseLoaded {
+                request: request.line(),
+                result,
+            })
+            .await;
+    });
+}
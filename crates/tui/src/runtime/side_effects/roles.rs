@@ -3,6 +3,8 @@
 //! Responsibilities:
 //! - Handle async API calls for role operations.
 //! - Fetch role lists from the Splunk server.
+//! - Fetch per-role search quotas alongside current usage.
+//! - Fetch every role's effective capabilities (capability matrix).
 //! - Create, modify, and delete roles.
 //!
 //! Does NOT handle:
@@ -61,6 +63,48 @@ pub async fn handle_load_capabilities(
     });
 }
 
+/// Handle loading per-role search quotas and current usage.
+pub async fn handle_load_role_usage(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        match client.role_usage().await {
+            Ok(usage) => {
+                let _ = tx.send(Action::RoleUsageLoaded(Ok(usage))).await;
+            }
+            Err(e) => {
+                let _ = tx.send(Action::RoleUsageLoaded(Err(Arc::new(e)))).await;
+            }
+        }
+    });
+}
+
+/// Handle loading the role capability matrix.
+pub async fn handle_load_role_capability_matrix(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        match client.role_capability_matrix().await {
+            Ok(matrix) => {
+                let _ = tx
+                    .send(Action::RoleCapabilityMatrixLoaded(Ok(matrix)))
+                    .await;
+            }
+            Err(e) => {
+                let _ = tx
+                    .send(Action::RoleCapabilityMatrixLoaded(Err(Arc::new(e))))
+                    .await;
+            }
+        }
+    });
+}
+
 /// Handle creating a new role.
 pub async fn handle_create_role(
     client: SharedClient,
@@ -3,15 +3,19 @@
 //! Responsibilities:
 //! - Handle LoadSearchPeers action to fetch distributed search peers
 //! - Handle LoadMoreSearchPeers action for pagination
+//! - Add, remove, quarantine, and unquarantine search peers
 //!
 //! Does NOT handle:
 //! - UI rendering (handled by screen module)
 //! - Input handling (handled by input handlers)
 
+use splunk_client::models::AddSearchPeerParams;
+use splunk_config::constants::DEFAULT_LIST_PAGE_SIZE;
 use tokio::sync::mpsc::Sender;
 
 use crate::action::Action;
 use crate::runtime::side_effects::{SharedClient, TaskTracker, paginated::build_paginated_action};
+use crate::ui::ToastLevel;
 
 /// Handle loading search peers with pagination support.
 ///
@@ -36,3 +40,98 @@ pub async fn handle_load_search_peers(
         let _ = tx.send(action).await;
     });
 }
+
+/// Handle adding a search peer.
+pub async fn handle_add_search_peer(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    params: AddSearchPeerParams,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = client.add_search_peer(&params).await;
+        send_peer_result(
+            &tx,
+            result,
+            format!("Search peer '{}' added", params.uri),
+            format!("Failed to add search peer '{}'", params.uri),
+        )
+        .await;
+    });
+}
+
+/// Handle removing a search peer.
+pub async fn handle_remove_search_peer(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    name: String,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = client.remove_search_peer(&name).await;
+        send_peer_result(
+            &tx,
+            result,
+            format!("Search peer '{}' removed", name),
+            format!("Failed to remove search peer '{}'", name),
+        )
+        .await;
+    });
+}
+
+/// Handle quarantining or unquarantining a search peer.
+pub async fn handle_set_search_peer_quarantine(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    name: String,
+    quarantine: bool,
+) {
+    let verb = if quarantine {
+        "quarantine"
+    } else {
+        "unquarantine"
+    };
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = client.set_search_peer_quarantine(&name, quarantine).await;
+        send_peer_result(
+            &tx,
+            result,
+            format!("Search peer '{}' {}d", name, verb),
+            format!("Failed to {} search peer '{}'", verb, name),
+        )
+        .await;
+    });
+}
+
+/// Notify the outcome of a peer operation and refresh the list on success.
+async fn send_peer_result<E: std::fmt::Display>(
+    tx: &Sender<Action>,
+    result: Result<(), E>,
+    success: String,
+    failure: String,
+) {
+    match result {
+        Ok(()) => {
+            let _ = tx.send(Action::Notify(ToastLevel::Success, success)).await;
+            let _ = tx
+                .send(Action::LoadSearchPeers {
+                    count: DEFAULT_LIST_PAGE_SIZE,
+                    offset: 0,
+                })
+                .await;
+        }
+        Err(e) => {
+            let _ = tx
+                .send(Action::Notify(
+                    ToastLevel::Error,
+                    format!("{}: {}", failure, e),
+                ))
+                .await;
+            let _ = tx.send(Action::Loading(false)).await;
+        }
+    }
+}
@@ -3,7 +3,10 @@
 //! Responsibilities:
 //! - Handle async API calls for search operations.
 //! - Execute searches with progress callbacks.
+//! - Report searches that were auto-finalized at the result threshold.
 //! - Load saved searches and pagination results.
+//! - Apply bulk enable/disable or owner reassignment to selected saved searches.
+//! - Run top-values follow-up searches for result fields.
 //! - SPL syntax validation.
 //!
 //! Does NOT handle:
@@ -12,6 +15,13 @@
 
 use crate::action::{Action, progress_callback_to_action_sender};
 use crate::error_details::{build_search_error_details, search_error_message};
+use crate::ui::ToastLevel;
+use splunk_client::workflows::saved_search_bulk::{
+    SavedSearchBulkAction, SavedSearchBulkRequest, bulk_update_saved_searches,
+};
+use splunk_client::workflows::saved_search_ownership::{
+    ReassignSavedSearchesRequest, reassign_saved_searches,
+};
 use splunk_client::{SearchMode, SearchRequest, normalize_search_query};
 use splunk_config::SearchDefaults;
 use std::collections::hash_map::DefaultHasher;
@@ -37,7 +47,7 @@ pub async fn handle_load_saved_searches(
 ) {
     let _ = tx.send(Action::Loading(true)).await;
     task_tracker.spawn(async move {
-        match client.list_saved_searches(None, None).await {
+        match client.list_saved_searches(None, None, None).await {
             Ok(searches) => {
                 let _ = tx.send(Action::SavedSearchesLoaded(Ok(searches))).await;
             }
@@ -49,6 +59,7 @@ pub async fn handle_load_saved_searches(
 }
 
 /// Handle running a search.
+#[allow(clippy::too_many_arguments)]
 pub async fn handle_run_search(
     client: SharedClient,
     tx: Sender<Action>,
@@ -57,6 +68,7 @@ pub async fn handle_run_search(
     search_defaults: SearchDefaults,
     search_mode: SearchMode,
     realtime_window: Option<u64>,
+    finalize_after: Option<usize>,
 ) {
     tracing::debug!(
         "handle_run_search called with query: {}",
@@ -106,6 +118,11 @@ pub async fn handle_run_search(
         } else {
             request
         };
+        let request = if let Some(threshold) = finalize_after {
+            request.finalize_after(threshold)
+        } else {
+            request
+        };
 
         // Use search_with_progress for unified timeout and progress handling
         match client
@@ -113,10 +130,29 @@ pub async fn handle_run_search(
             .await
         {
             Ok((results, sid, total)) => {
+                // One status fetch serves the finalize notice and the TTL warning
+                let job = client.get_job_status(&sid).await;
+                if finalize_after.is_some()
+                    && let Ok(status) = &job
+                    && status.is_finalized
+                {
+                    let _ = tx_clone
+                        .send(Action::Notify(
+                            ToastLevel::Info,
+                            format!(
+                                "Search finalized early after {} results; results are partial",
+                                status.result_count
+                            ),
+                        ))
+                        .await;
+                }
                 let _ = tx_clone.send(Action::Progress(1.0)).await;
                 let _ = tx_clone
                     .send(Action::SearchComplete(Ok((results, sid, total))))
                     .await;
+                let _ = tx_clone
+                    .send(Action::SearchJobLoaded(job.map_err(Arc::new)))
+                    .await;
             }
             Err(e) => {
                 let details = build_search_error_details(
@@ -165,6 +201,32 @@ pub async fn handle_load_more_search_results(
     });
 }
 
+/// Handle a top-values follow-up search for a field of the last search's results.
+///
+/// Runs within the original search's time range and emits `TopValuesLoaded`.
+pub async fn handle_load_top_values(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    query: String,
+    field: String,
+    earliest_time: String,
+    latest_time: String,
+) {
+    tracing::debug!(
+        "handle_load_top_values called with query: {}",
+        redact_query_for_log(&query)
+    );
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let normalized_query = normalize_search_query(&query);
+        let request =
+            SearchRequest::new(&normalized_query, true).time_bounds(&earliest_time, &latest_time);
+        let result = client.search(request).await.map_err(Arc::new);
+        let _ = tx.send(Action::TopValuesLoaded { field, result }).await;
+    });
+}
+
 /// Handle SPL validation request (debounced).
 ///
 /// Validates SPL syntax without executing the search. Short queries (< 3 chars)
@@ -229,17 +291,10 @@ pub async fn handle_update_saved_search(
     tx: Sender<Action>,
     task_tracker: TaskTracker,
     name: String,
-    search: Option<String>,
-    description: Option<String>,
-    disabled: Option<bool>,
+    params: splunk_client::models::SavedSearchUpdateParams,
 ) {
     let _ = tx.send(Action::Loading(true)).await;
     task_tracker.spawn(async move {
-        let params = splunk_client::models::SavedSearchUpdateParams {
-            search,
-            description,
-            disabled,
-        };
         match client.update_saved_search(&name, params).await {
             Ok(()) => {
                 let _ = tx.send(Action::SavedSearchUpdated(Ok(()))).await;
@@ -337,3 +392,64 @@ pub async fn handle_toggle_saved_search(
         }
     });
 }
+
+/// Handle applying one action to several saved searches.
+pub async fn handle_bulk_update_saved_searches(
+    client: SharedClient,
+    action_tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    names: Vec<String>,
+    action: SavedSearchBulkAction,
+) {
+    let _ = action_tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let request = SavedSearchBulkRequest {
+            owner: None,
+            app: None,
+            names,
+            action,
+            dry_run: false,
+        };
+        let result = bulk_update_saved_searches(&client, &request, None)
+            .await
+            .map_err(|e| format!("{e:#}"));
+        let refresh = result.is_ok();
+        let _ = action_tx
+            .send(Action::SavedSearchesBulkUpdated(result))
+            .await;
+        if refresh {
+            let _ = action_tx.send(Action::LoadSavedSearches).await;
+        }
+    });
+}
+
+/// Handle reassigning several saved searches to a new owner.
+pub async fn handle_reassign_saved_searches(
+    client: SharedClient,
+    action_tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    names: Vec<String>,
+    to_owner: String,
+) {
+    let _ = action_tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let request = ReassignSavedSearchesRequest {
+            from_owner: None,
+            to_owner,
+            app: None,
+            names,
+            sharing: None,
+            dry_run: false,
+        };
+        let result = reassign_saved_searches(&client, &request, None)
+            .await
+            .map_err(|e| format!("{e:#}"));
+        let refresh = result.is_ok();
+        let _ = action_tx
+            .send(Action::SavedSearchesReassigned(result))
+            .await;
+        if refresh {
+            let _ = action_tx.send(Action::LoadSavedSearches).await;
+        }
+    });
+}
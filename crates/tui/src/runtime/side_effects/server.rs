@@ -0,0 +1,59 @@
+//! Server control side effect handlers.
+//!
+//! Responsibilities:
+//! - Handle RestartServer to restart splunkd and wait for it to come back
+//!
+//! Does NOT handle:
+//! - Typed confirmation (handled by the ConfirmServerRestart popup)
+//! - Restart-required detection (server info and bulletin board messages)
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::mpsc::Sender;
+
+use crate::action::Action;
+
+use super::{SharedClient, TaskTracker};
+
+/// How long to wait for splunkd to come back before reporting a failure.
+const RESTART_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// How often to poll server info while splunkd restarts.
+const RESTART_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Handle restarting splunkd.
+///
+/// Emits `ServerRestartRequested` once the restart is accepted (or fails), then
+/// `ServerRestarted` when the server answers again or the wait times out.
+pub async fn handle_restart_server(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+) {
+    task_tracker.spawn(async move {
+        let previous_startup_time = client
+            .get_server_info()
+            .await
+            .ok()
+            .and_then(|info| info.startup_time);
+
+        if let Err(e) = client.restart_server().await {
+            let _ = tx
+                .send(Action::ServerRestartRequested(Err(Arc::new(e))))
+                .await;
+            return;
+        }
+        let _ = tx.send(Action::ServerRestartRequested(Ok(()))).await;
+
+        let result = client
+            .wait_for_restart(
+                previous_startup_time,
+                RESTART_TIMEOUT,
+                RESTART_POLL_INTERVAL,
+            )
+            .await
+            .map_err(Arc::new);
+        let _ = tx.send(Action::ServerRestarted(result)).await;
+    });
+}
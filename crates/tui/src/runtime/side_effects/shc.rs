@@ -10,6 +10,7 @@
 //! - UI rendering.
 
 use crate::action::Action;
+use crate::operation_progress::ProgressReporter;
 use std::sync::Arc;
 use tokio::sync::mpsc::Sender;
 
@@ -148,13 +149,22 @@ pub async fn handle_rolling_restart_shc(
 ) {
     let _ = tx.send(Action::Loading(true)).await;
     task_tracker.spawn(async move {
-        match client.rolling_restart_shc(force).await {
-            Ok(_) => {
+        let mut reporter =
+            ProgressReporter::start(&tx, "Initiating SHC rolling restart", Some(1)).await;
+        reporter.begin_item("rolling restart").await;
+        let result = reporter.run(client.rolling_restart_shc(force)).await;
+        reporter.finish().await;
+
+        match result {
+            None => {
+                let _ = tx.send(Action::Loading(false)).await;
+            }
+            Some(Ok(_)) => {
                 let _ = tx
                     .send(Action::ShcRollingRestarted { result: Ok(()) })
                     .await;
             }
-            Err(e) => {
+            Some(Err(e)) => {
                 let _ = tx
                     .send(Action::ShcRollingRestarted {
                         result: Err(e.to_string()),
@@ -3,7 +3,9 @@
 //! Responsibilities:
 //! - Handle async API calls for user operations.
 //! - Fetch user lists from the Splunk server.
-//! - Create, modify, and delete users.
+//! - Create, modify, delete, and unlock users.
+//! - Find knowledge objects owned by users that no longer exist.
+//! - Fetch the logged-in user's roles and capabilities.
 //!
 //! Does NOT handle:
 //! - Direct state modification (sends actions for that).
@@ -11,6 +13,7 @@
 
 use crate::action::Action;
 use crate::ui::ToastLevel;
+use splunk_client::workflows::orphaned_objects::{OrphanScanRequest, find_orphaned_objects};
 use splunk_client::{CreateUserParams, ModifyUserParams};
 use splunk_config::constants::DEFAULT_LIST_PAGE_SIZE;
 use std::sync::Arc;
@@ -39,6 +42,21 @@ pub async fn handle_load_users(
     });
 }
 
+/// Handle loading the logged-in user's roles and capabilities.
+///
+/// Runs in the background without toggling the loading indicator.
+/// Emits `CurrentContextLoaded` with the context, or error on failure.
+pub async fn handle_load_current_context(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+) {
+    task_tracker.spawn(async move {
+        let result = client.current_context().await.map_err(Arc::new);
+        let _ = tx.send(Action::CurrentContextLoaded(result)).await;
+    });
+}
+
 /// Handle creating a new user.
 pub async fn handle_create_user(
     client: SharedClient,
@@ -159,3 +177,58 @@ pub async fn handle_delete_user(
         }
     });
 }
+
+/// Handle unlocking a locked-out user.
+pub async fn handle_unlock_user(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    name: String,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        match client.unlock_user(&name).await {
+            Ok(_) => {
+                let _ = tx
+                    .send(Action::Notify(
+                        ToastLevel::Success,
+                        format!("User '{}' unlocked", name),
+                    ))
+                    .await;
+                let _ = tx
+                    .send(Action::LoadUsers {
+                        count: DEFAULT_LIST_PAGE_SIZE,
+                        offset: 0,
+                    })
+                    .await;
+            }
+            Err(e) => {
+                let _ = tx
+                    .send(Action::Notify(
+                        ToastLevel::Error,
+                        format!("Failed to unlock user '{}': {}", name, e),
+                    ))
+                    .await;
+                let _ = tx.send(Action::Loading(false)).await;
+            }
+        }
+    });
+}
+
+/// Handle scanning for orphaned knowledge objects.
+///
+/// Emits `OrphanedObjectsFound` with the saved searches, dashboards, macros and
+/// lookups whose owner is missing from the user list.
+pub async fn handle_find_orphaned_objects(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = find_orphaned_objects(&client, &OrphanScanRequest::default(), None)
+            .await
+            .map_err(|e| format!("{:#}", e));
+        let _ = tx.send(Action::OrphanedObjectsFound(result)).await;
+    });
+}
@@ -0,0 +1,96 @@
+//! Data onboarding wizard side effects (async API calls).
+//!
+//! Responsibilities:
+//! - Create the index, sourcetype stanza, and HEC token of a wizard plan, in order.
+//! - Stop at the first failure and report which stage it was.
+//! - Send the outcome, with the generated token, back via action channel.
+//!
+//! Does NOT handle:
+//! - Does not roll back stages that succeeded before a failure.
+//! - Does not handle input (see app/popups/wizard.rs).
+
+use crate::action::Action;
+use crate::runtime::side_effects::{SharedClient, TaskTracker};
+use crate::wizard::{
+    OnboardingFailure, OnboardingOutcome, OnboardingPlan, OnboardingStage, hec_url,
+};
+use splunk_config::constants::DEFAULT_LIST_PAGE_SIZE;
+use std::sync::Arc;
+use tokio::sync::mpsc::Sender;
+
+/// Config file that holds sourcetype definitions.
+const SOURCETYPE_CONFIG_FILE: &str = "props";
+
+/// Run an onboarding wizard plan.
+pub async fn handle_run_onboarding_wizard(
+    client: SharedClient,
+    tx: Sender<Action>,
+    task_tracker: TaskTracker,
+    plan: OnboardingPlan,
+) {
+    let _ = tx.send(Action::Loading(true)).await;
+    task_tracker.spawn(async move {
+        let result = run_plan(&client, &plan).await;
+        let index_created = !matches!(
+            &result,
+            Err(OnboardingFailure {
+                stage: OnboardingStage::Index,
+                ..
+            })
+        );
+        let _ = tx.send(Action::OnboardingWizardFinished(result)).await;
+        if index_created {
+            let _ = tx
+                .send(Action::LoadIndexes {
+                    count: DEFAULT_LIST_PAGE_SIZE,
+                    offset: 0,
+                })
+                .await;
+        }
+    });
+}
+
+async fn run_plan(
+    client: &SharedClient,
+    plan: &OnboardingPlan,
+) -> Result<OnboardingOutcome, OnboardingFailure> {
+    let fail = |stage| {
+        move |e| OnboardingFailure {
+            stage,
+            error: Arc::new(e),
+        }
+    };
+
+    client
+        .create_index(&plan.index)
+        .await
+        .map_err(fail(OnboardingStage::Index))?;
+    client
+        .create_config_stanza(
+            SOURCETYPE_CONFIG_FILE,
+            &plan.sourcetype,
+            &plan.format.props_settings(),
+        )
+        .await
+        .map_err(fail(OnboardingStage::Sourcetype))?;
+    let mut token = client
+        .create_hec_token(&plan.token)
+        .await
+        .map_err(fail(OnboardingStage::Token))?;
+    // Some servers omit the value from the create response; read it back.
+    if token.token.is_none() {
+        token = client
+            .get_hec_token(&plan.token.name)
+            .await
+            .map_err(fail(OnboardingStage::Token))?;
+    }
+
+    Ok(OnboardingOutcome {
+        index: plan.index.name.clone(),
+        sourcetype: plan.sourcetype.clone(),
+        format: plan.format,
+        token_name: plan.token.name.clone(),
+        token: token.token,
+        hec_url: hec_url(client.base_url()),
+    })
+}
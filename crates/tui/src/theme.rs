@@ -2,7 +2,8 @@
 //!
 //! Responsibilities:
 //! - Define the expanded runtime `Theme` with all color values.
-//! - Provide conversion from `ColorTheme` to `Theme`.
+//! - Provide conversion from `ColorTheme` to `Theme`, resolving `Auto` from the terminal.
+//! - Expand user theme files (`ThemeFile`) and downgrade truecolor for 256-color terminals.
 //!
 //! Does NOT handle:
 //! - Theme persistence (see `splunk-config` which persists `ColorTheme`).
@@ -14,7 +15,7 @@
 //! - Colors are semantically named (error/warn/success/info) for consistent usage.
 
 use ratatui::style::Color;
-use splunk_config::ColorTheme;
+use splunk_config::{ColorTheme, ThemeFile};
 
 /// Expanded runtime theme.
 ///
@@ -68,6 +69,10 @@ pub struct Theme {
     pub syntax_punctuation: Color,
     pub syntax_pipe: Color,
     pub syntax_comparison: Color,
+
+    // Rendering mode
+    /// Render without colors, replacing color-only signals with text and modifiers.
+    pub no_color: bool,
 }
 
 impl Theme {
@@ -113,6 +118,7 @@ impl Theme {
                 syntax_punctuation: Color::DarkGray,
                 syntax_pipe: Color::Yellow,
                 syntax_comparison: Color::Red,
+                no_color: false,
             },
             ColorTheme::Light => Self {
                 background: Color::White,
@@ -153,6 +159,7 @@ impl Theme {
                 syntax_punctuation: Color::Gray,
                 syntax_pipe: Color::Magenta,
                 syntax_comparison: Color::Red,
+                no_color: false,
             },
             ColorTheme::Dark => Self {
                 background: Color::Black,
@@ -193,6 +200,7 @@ impl Theme {
                 syntax_punctuation: Color::DarkGray,
                 syntax_pipe: Color::Indexed(214),
                 syntax_comparison: Color::Red,
+                no_color: false,
             },
             ColorTheme::HighContrast => Self {
                 background: Color::Black,
@@ -233,6 +241,7 @@ impl Theme {
                 syntax_punctuation: Color::White,
                 syntax_pipe: Color::Yellow,
                 syntax_comparison: Color::Red,
+                no_color: false,
             },
             ColorTheme::Deuteranopia => Self {
                 background: Color::Black,
@@ -267,6 +276,7 @@ impl Theme {
                 syntax_punctuation: Color::Indexed(250),
                 syntax_pipe: Color::Indexed(226),
                 syntax_comparison: Color::Indexed(208),
+                no_color: false,
             },
             ColorTheme::Protanopia => Self {
                 background: Color::Black,
@@ -301,6 +311,7 @@ impl Theme {
                 syntax_punctuation: Color::Indexed(250),
                 syntax_pipe: Color::Indexed(214),
                 syntax_comparison: Color::Indexed(202),
+                no_color: false,
             },
             ColorTheme::Tritanopia => Self {
                 background: Color::Black,
@@ -335,6 +346,7 @@ impl Theme {
                 syntax_punctuation: Color::Indexed(250),
                 syntax_pipe: Color::Indexed(197),
                 syntax_comparison: Color::Indexed(161),
+                no_color: false,
             },
             ColorTheme::Monochrome => Self {
                 background: Color::Black,
@@ -369,7 +381,9 @@ impl Theme {
                 syntax_punctuation: Color::Indexed(245),
                 syntax_pipe: Color::Indexed(250),
                 syntax_comparison: Color::Indexed(255),
+                no_color: false,
             },
+            ColorTheme::Auto => Self::from_color_theme(TerminalBackground::detect().color_theme()),
         }
     }
 }
@@ -386,6 +400,226 @@ impl Default for Theme {
     }
 }
 
+/// Color depth the terminal can display, used to downgrade truecolor theme colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSupport {
+    /// 24-bit RGB colors (`COLORTERM=truecolor` or `24bit`)
+    TrueColor,
+    /// The 256-color palette; RGB colors are mapped to the nearest palette entry
+    Indexed,
+}
+
+impl ColorSupport {
+    /// Detect truecolor support from the `COLORTERM` environment variable.
+    pub fn detect() -> Self {
+        Self::from_colorterm(std::env::var("COLORTERM").ok().as_deref())
+    }
+
+    /// Classify a `COLORTERM` value.
+    pub fn from_colorterm(colorterm: Option<&str>) -> Self {
+        match colorterm.map(str::to_ascii_lowercase).as_deref() {
+            Some("truecolor" | "24bit") => Self::TrueColor,
+            _ => Self::Indexed,
+        }
+    }
+
+    /// Human-readable label for the Settings screen.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::TrueColor => "truecolor",
+            Self::Indexed => "256 colors",
+        }
+    }
+}
+
+/// Terminal background brightness, used to resolve `ColorTheme::Auto`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminalBackground {
+    Dark,
+    Light,
+}
+
+impl TerminalBackground {
+    /// Detect the background from the `COLORFGBG` environment variable (dark if unset).
+    pub fn detect() -> Self {
+        Self::from_colorfgbg(std::env::var("COLORFGBG").ok().as_deref())
+    }
+
+    /// Classify a `COLORFGBG` value such as `15;0` or `0;default;15`.
+    ///
+    /// The last field is the background palette index; white (7 or 15) means light.
+    pub fn from_colorfgbg(colorfgbg: Option<&str>) -> Self {
+        match colorfgbg
+            .and_then(|value| value.rsplit(';').next())
+            .and_then(|bg| bg.trim().parse::<u8>().ok())
+        {
+            Some(7 | 15) => Self::Light,
+            _ => Self::Dark,
+        }
+    }
+
+    /// Built-in theme matching this background.
+    pub fn color_theme(self) -> ColorTheme {
+        match self {
+            Self::Dark => ColorTheme::Dark,
+            Self::Light => ColorTheme::Light,
+        }
+    }
+}
+
+impl Theme {
+    /// Expand a user theme file on top of its base theme.
+    ///
+    /// RGB colors are mapped to the 256-color palette unless `support` is truecolor.
+    ///
+    /// # Errors
+    /// Returns a message naming the first palette entry whose color cannot be parsed.
+    pub fn from_theme_file(file: &ThemeFile, support: ColorSupport) -> Result<Self, String> {
+        let mut theme = Self::from_color_theme(file.base);
+        let overrides: [(&mut Color, &Option<String>, &str); 32] = [
+            (&mut theme.background, &file.ui.background, "ui.background"),
+            (&mut theme.text, &file.ui.text, "ui.text"),
+            (&mut theme.text_dim, &file.ui.text_dim, "ui.text_dim"),
+            (&mut theme.border, &file.ui.border, "ui.border"),
+            (&mut theme.title, &file.ui.title, "ui.title"),
+            (&mut theme.accent, &file.ui.accent, "ui.accent"),
+            (
+                &mut theme.highlight_fg,
+                &file.ui.highlight_fg,
+                "ui.highlight_fg",
+            ),
+            (
+                &mut theme.highlight_bg,
+                &file.ui.highlight_bg,
+                "ui.highlight_bg",
+            ),
+            (&mut theme.disabled, &file.ui.disabled, "ui.disabled"),
+            (
+                &mut theme.health_healthy,
+                &file.header.healthy,
+                "header.healthy",
+            ),
+            (
+                &mut theme.health_unhealthy,
+                &file.header.unhealthy,
+                "header.unhealthy",
+            ),
+            (
+                &mut theme.health_unknown,
+                &file.header.unknown,
+                "header.unknown",
+            ),
+            (
+                &mut theme.table_header_fg,
+                &file.table.header_fg,
+                "table.header_fg",
+            ),
+            (
+                &mut theme.table_header_bg,
+                &file.table.header_bg,
+                "table.header_bg",
+            ),
+            (
+                &mut theme.success,
+                &file.severity.success,
+                "severity.success",
+            ),
+            (
+                &mut theme.warning,
+                &file.severity.warning,
+                "severity.warning",
+            ),
+            (&mut theme.error, &file.severity.error, "severity.error"),
+            (&mut theme.info, &file.severity.info, "severity.info"),
+            (&mut theme.log_error, &file.logs.error, "logs.error"),
+            (&mut theme.log_warn, &file.logs.warn, "logs.warn"),
+            (&mut theme.log_info, &file.logs.info, "logs.info"),
+            (&mut theme.log_debug, &file.logs.debug, "logs.debug"),
+            (
+                &mut theme.log_component,
+                &file.logs.component,
+                "logs.component",
+            ),
+            (
+                &mut theme.syntax_command,
+                &file.syntax.command,
+                "syntax.command",
+            ),
+            (
+                &mut theme.syntax_operator,
+                &file.syntax.operator,
+                "syntax.operator",
+            ),
+            (
+                &mut theme.syntax_function,
+                &file.syntax.function,
+                "syntax.function",
+            ),
+            (
+                &mut theme.syntax_string,
+                &file.syntax.string,
+                "syntax.string",
+            ),
+            (
+                &mut theme.syntax_number,
+                &file.syntax.number,
+                "syntax.number",
+            ),
+            (
+                &mut theme.syntax_comment,
+                &file.syntax.comment,
+                "syntax.comment",
+            ),
+            (
+                &mut theme.syntax_punctuation,
+                &file.syntax.punctuation,
+                "syntax.punctuation",
+            ),
+            (&mut theme.syntax_pipe, &file.syntax.pipe, "syntax.pipe"),
+            (
+                &mut theme.syntax_comparison,
+                &file.syntax.comparison,
+                "syntax.comparison",
+            ),
+        ];
+        for (target, value, key) in overrides {
+            if let Some(value) = value {
+                *target = parse_theme_color(value, support).ok_or_else(|| {
+                    format!(
+                        "theme '{}': invalid color '{}' for {}",
+                        file.name, value, key
+                    )
+                })?;
+            }
+        }
+        Ok(theme)
+    }
+}
+
+/// Parse a theme color: a name (`red`, `lightblue`), a palette index (`0`-`255`),
+/// or `#rrggbb`.
+pub fn parse_theme_color(value: &str, support: ColorSupport) -> Option<Color> {
+    match value.trim().parse::<Color>().ok()? {
+        Color::Rgb(r, g, b) if support == ColorSupport::Indexed => {
+            Some(Color::Indexed(rgb_to_ansi256(r, g, b)))
+        }
+        color => Some(color),
+    }
+}
+
+/// Nearest entry of the 256-color palette (6x6x6 cube or grayscale ramp).
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    if r == g && g == b {
+        return match r {
+            0..8 => 16,
+            249.. => 231,
+            _ => 232 + ((u16::from(r) - 8) * 24 / 247) as u8,
+        };
+    }
+    let level = |c: u8| ((u16::from(c) * 5 + 127) / 255) as u8;
+    16 + 36 * level(r) + 6 * level(g) + level(b)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -440,8 +674,9 @@ mod tests {
         ));
         assert!(matches!(
             ColorTheme::Monochrome.cycle_next(),
-            ColorTheme::Default
+            ColorTheme::Auto
         ));
+        assert!(matches!(ColorTheme::Auto.cycle_next(), ColorTheme::Default));
     }
 
     #[test]
@@ -528,6 +763,7 @@ mod tests {
             ColorTheme::Protanopia,
             ColorTheme::Tritanopia,
             ColorTheme::Monochrome,
+            ColorTheme::Auto,
         ] {
             let runtime_theme = Theme::from_color_theme(theme);
             // Basic sanity checks
@@ -535,4 +771,67 @@ mod tests {
             assert_ne!(runtime_theme.success, runtime_theme.error);
         }
     }
+
+    #[test]
+    fn test_terminal_capability_detection() {
+        assert_eq!(
+            ColorSupport::from_colorterm(Some("truecolor")),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(ColorSupport::from_colorterm(None), ColorSupport::Indexed);
+        assert_eq!(
+            TerminalBackground::from_colorfgbg(Some("0;15")),
+            TerminalBackground::Light
+        );
+        assert_eq!(
+            TerminalBackground::from_colorfgbg(Some("15;default;0")),
+            TerminalBackground::Dark
+        );
+        assert_eq!(
+            TerminalBackground::from_colorfgbg(None),
+            TerminalBackground::Dark
+        );
+    }
+
+    #[test]
+    fn test_parse_theme_color_downgrades_rgb() {
+        assert_eq!(
+            parse_theme_color("#ff0000", ColorSupport::TrueColor),
+            Some(Color::Rgb(255, 0, 0))
+        );
+        assert_eq!(
+            parse_theme_color("#ff0000", ColorSupport::Indexed),
+            Some(Color::Indexed(196))
+        );
+        assert_eq!(
+            parse_theme_color("#808080", ColorSupport::Indexed),
+            Some(Color::Indexed(243))
+        );
+        assert_eq!(
+            parse_theme_color("lightblue", ColorSupport::Indexed),
+            Some(Color::LightBlue)
+        );
+        assert_eq!(
+            parse_theme_color("42", ColorSupport::Indexed),
+            Some(Color::Indexed(42))
+        );
+        assert_eq!(parse_theme_color("nope", ColorSupport::Indexed), None);
+    }
+
+    #[test]
+    fn test_from_theme_file_overrides_base() {
+        let file = ThemeFile::parse(
+            "base = \"light\"\n[severity]\nerror = \"#dc322f\"\n",
+            "solarized",
+        )
+        .unwrap();
+        let theme = Theme::from_theme_file(&file, ColorSupport::TrueColor).unwrap();
+        let light = Theme::from_color_theme(ColorTheme::Light);
+        assert_eq!(theme.error, Color::Rgb(0xdc, 0x32, 0x2f));
+        assert_eq!(theme.text, light.text);
+
+        let bad = ThemeFile::parse("[ui]\nborder = \"blurple\"\n", "bad").unwrap();
+        let error = Theme::from_theme_file(&bad, ColorSupport::TrueColor).unwrap_err();
+        assert!(error.contains("ui.border"));
+    }
 }
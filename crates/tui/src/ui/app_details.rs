@@ -0,0 +1,147 @@
+//! App Details popup rendering.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Tabs, Wrap},
+};
+use splunk_client::workflows::app_contents::AppObjectKind;
+
+use crate::app::App;
+use crate::theme::Theme;
+use crate::ui::theme::ThemeExt;
+
+/// Render the App Details popup.
+///
+/// Shows one tab per object kind with its object count, the objects of the
+/// active tab, and the full detail of the selected object below the list.
+pub fn render_app_details(f: &mut Frame, app: &App, theme: &Theme) {
+    let Some(details) = app.app_details.as_ref() else {
+        return;
+    };
+
+    let area = f.area();
+    let popup_width = 110.min(area.width.saturating_sub(4));
+    let popup_height = 28.min(area.height.saturating_sub(4));
+
+    let x = (area.width.saturating_sub(popup_width)) / 2;
+    let y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(
+            " App Details: {} ({} objects) ",
+            details.contents.app,
+            details.contents.objects.len()
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Min(3),
+            Constraint::Length(5),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let titles: Vec<Line> = AppObjectKind::ALL
+        .iter()
+        .map(|kind| {
+            Line::from(format!(
+                "{} ({})",
+                kind.title(),
+                details.contents.count(*kind)
+            ))
+        })
+        .collect();
+    let selected_tab = AppObjectKind::ALL
+        .iter()
+        .position(|kind| *kind == details.tab)
+        .unwrap_or(0);
+    f.render_widget(
+        Tabs::new(titles)
+            .select(selected_tab)
+            .highlight_style(theme.highlight())
+            .block(
+                Block::default()
+                    .borders(Borders::BOTTOM)
+                    .border_style(Style::default().fg(theme.border)),
+            ),
+        chunks[0],
+    );
+
+    let objects = details.objects();
+    if objects.is_empty() {
+        f.render_widget(
+            Paragraph::new(format!(
+                "No {} defined in this app",
+                details.tab.title().to_lowercase()
+            ))
+            .style(theme.text_dim()),
+            chunks[1],
+        );
+    } else {
+        let name_width = objects.iter().map(|o| o.name.len()).max().unwrap_or(0);
+        let items: Vec<ListItem> = objects
+            .iter()
+            .map(|object| {
+                let mut spans = vec![
+                    Span::raw(format!("{:<width$}  ", object.name, width = name_width)),
+                    Span::styled(
+                        object.detail.lines().next().unwrap_or_default().to_string(),
+                        theme.text_dim(),
+                    ),
+                ];
+                if object.disabled {
+                    spans.push(Span::styled(" [disabled]", theme.warning()));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+        let list = List::new(items)
+            .highlight_style(theme.highlight())
+            .highlight_symbol("> ");
+        let mut list_state = ListState::default().with_selected(Some(details.selected));
+        f.render_stateful_widget(list, chunks[1], &mut list_state);
+    }
+
+    let detail = details
+        .selected_object()
+        .map(|object| {
+            vec![
+                Line::from(Span::styled(
+                    object.name.as_str(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                )),
+                Line::from(object.detail.as_str()),
+            ]
+        })
+        .unwrap_or_default();
+    f.render_widget(
+        Paragraph::new(detail)
+            .block(
+                Block::default()
+                    .borders(Borders::TOP)
+                    .border_style(Style::default().fg(theme.border)),
+            )
+            .wrap(Wrap { trim: false }),
+        chunks[2],
+    );
+
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            "Tab/h/l: switch type  j/k: select  r: refresh  Ctrl+c: copy name  Esc: close",
+            Style::default().fg(theme.text_dim),
+        ))),
+        chunks[3],
+    );
+}
@@ -0,0 +1,136 @@
+//! Background jobs tray popup rendering.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+use crate::app::App;
+use crate::app::background_jobs::{BackgroundJob, BackgroundJobStatus};
+use crate::operation_progress::format_duration;
+use crate::theme::Theme;
+use crate::ui::theme::{StatusClass, ThemeExt};
+
+/// Render the background jobs tray.
+///
+/// Lists running and recently finished searches and operations, newest first,
+/// with the selected job's query or outcome below the list.
+pub fn render_background_jobs(f: &mut Frame, app: &App, theme: &Theme) {
+    let state = &app.background_jobs;
+
+    let area = f.area();
+    let popup_width = 100.min(area.width.saturating_sub(4));
+    let popup_height = 22.min(area.height.saturating_sub(4));
+
+    let x = (area.width.saturating_sub(popup_width)) / 2;
+    let y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(
+            " Background Jobs ({} running) ",
+            state.running_count()
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),
+            Constraint::Length(5),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    if state.jobs().is_empty() {
+        f.render_widget(
+            Paragraph::new("No searches or operations since the app started")
+                .style(theme.text_dim()),
+            chunks[0],
+        );
+    } else {
+        let items: Vec<ListItem> = state
+            .jobs()
+            .iter()
+            .map(|job| {
+                let (word, class) = status_label(&job.status);
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:<10}", theme.tagged(word, class)),
+                        status_style(class, theme),
+                    ),
+                    Span::raw(format!("{:>8}  ", format_duration(job.elapsed()))),
+                    Span::raw(job.label.clone()),
+                ]))
+            })
+            .collect();
+        let list = List::new(items)
+            .highlight_style(theme.highlight())
+            .highlight_symbol("> ");
+        let mut list_state = ListState::default().with_selected(Some(state.selected));
+        f.render_stateful_widget(list, chunks[0], &mut list_state);
+    }
+
+    let details = state
+        .selected_job()
+        .map(|job| detail_lines(job, theme))
+        .unwrap_or_default();
+    f.render_widget(
+        Paragraph::new(details)
+            .block(
+                Block::default()
+                    .borders(Borders::TOP)
+                    .border_style(Style::default().fg(theme.border)),
+            )
+            .wrap(Wrap { trim: false }),
+        chunks[1],
+    );
+
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            "j/k: select  Enter: view  Esc: close",
+            Style::default().fg(theme.text_dim),
+        ))),
+        chunks[2],
+    );
+}
+
+fn detail_lines<'a>(job: &'a BackgroundJob, theme: &Theme) -> Vec<Line<'a>> {
+    let outcome = match &job.status {
+        BackgroundJobStatus::Running => "Still running".to_string(),
+        BackgroundJobStatus::Finished(summary) => format!("Finished: {}", summary),
+        BackgroundJobStatus::Failed(error) => format!("Failed: {}", error),
+        BackgroundJobStatus::Cancelled(summary) => format!("Cancelled: {}", summary),
+    };
+    let mut lines = vec![Line::from(outcome)];
+    if let Some(detail) = &job.detail {
+        lines.push(Line::styled(detail.as_str(), theme.text_dim()));
+    }
+    lines
+}
+
+fn status_label(status: &BackgroundJobStatus) -> (&'static str, StatusClass) {
+    match status {
+        BackgroundJobStatus::Running => ("Running", StatusClass::Info),
+        BackgroundJobStatus::Finished(_) => ("Done", StatusClass::Success),
+        BackgroundJobStatus::Failed(_) => ("Failed", StatusClass::Error),
+        BackgroundJobStatus::Cancelled(_) => ("Cancelled", StatusClass::Warning),
+    }
+}
+
+fn status_style(class: StatusClass, theme: &Theme) -> Style {
+    match class {
+        StatusClass::Success => theme.success(),
+        StatusClass::Warning => theme.warning(),
+        StatusClass::Error => theme.error(),
+        StatusClass::Info | StatusClass::Unknown => theme.info(),
+    }
+}
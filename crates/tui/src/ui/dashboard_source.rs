@@ -0,0 +1,117 @@
+//! Dashboard source popup rendering with scrolling support.
+
+use ratatui::{
+    Frame,
+    layout::{Margin, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+};
+
+use crate::app::App;
+use crate::theme::Theme;
+
+/// Render the dashboard source popup.
+///
+/// Shows the re-indented Simple XML (or Dashboard Studio definition) of the
+/// last fetched dashboard. Lines are not wrapped so indentation stays readable.
+pub fn render_dashboard_source(f: &mut Frame, app: &App, theme: &Theme) {
+    let area = f.area();
+
+    let popup_width = 100.min(area.width.saturating_sub(4));
+    let popup_height = 30.min(area.height.saturating_sub(4));
+
+    let x = (area.width.saturating_sub(popup_width)) / 2;
+    let y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    f.render_widget(Clear, popup_area);
+
+    let Some(dashboard) = &app.dashboard_source else {
+        render_message(
+            f,
+            popup_area,
+            theme,
+            "Dashboard Source",
+            "No dashboard loaded",
+        );
+        return;
+    };
+
+    let format = dashboard
+        .source_format()
+        .map(|format| format.label())
+        .unwrap_or("Unknown format");
+    let label = if dashboard.label.is_empty() {
+        &dashboard.name
+    } else {
+        &dashboard.label
+    };
+    let title = format!(" {} ({}) ", label, format);
+
+    let Some(source) = dashboard.pretty_source() else {
+        render_message(f, popup_area, theme, &title, "Dashboard has no source");
+        return;
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Name: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(&dashboard.name, Style::default().fg(theme.accent)),
+            Span::styled(
+                "   e: export  Ctrl+c: copy  Esc: close",
+                Style::default().fg(theme.text_dim),
+            ),
+        ]),
+        Line::default(),
+    ];
+    lines.extend(source.lines().map(|line| Line::from(line.to_string())));
+
+    let visible_lines = popup_height.saturating_sub(2) as usize;
+    let max_offset = lines.len().saturating_sub(visible_lines);
+    let offset = app.dashboard_source_scroll_offset.min(max_offset);
+
+    let paragraph = Paragraph::new(lines.clone())
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .scroll((offset as u16, 0));
+
+    f.render_widget(paragraph, popup_area);
+
+    if lines.len() > visible_lines {
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(Some("↑"))
+            .end_symbol(Some("↓"));
+        let mut scrollbar_state = ScrollbarState::new(max_offset).position(offset);
+        f.render_stateful_widget(
+            scrollbar,
+            popup_area.inner(Margin::new(0, 1)),
+            &mut scrollbar_state,
+        );
+    }
+}
+
+/// Render a centered message when there is no source to show.
+fn render_message(f: &mut Frame, area: Rect, theme: &Theme, title: &str, message: &str) {
+    let lines = vec![
+        Line::from(Span::styled(message, Style::default().fg(theme.error))),
+        Line::default(),
+        Line::from(Span::raw("Press Esc or q to close")),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .alignment(ratatui::layout::Alignment::Center);
+
+    f.render_widget(paragraph, area);
+}
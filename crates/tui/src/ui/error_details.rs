@@ -69,6 +69,22 @@ pub fn render_error_details(f: &mut Frame, error: &ErrorDetails, app: &App, them
         Line::default(),
     ];
 
+    if !error.hints.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "How to fix:",
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(theme.title),
+        )));
+        for hint in &error.hints {
+            lines.push(Line::from(vec![
+                Span::styled("  - ", Style::default().fg(theme.accent)),
+                Span::raw(hint),
+            ]));
+        }
+        lines.push(Line::default());
+    }
+
     if let Some(status) = error.status_code {
         lines.push(Line::from(vec![
             Span::styled(
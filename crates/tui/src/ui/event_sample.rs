@@ -0,0 +1,323 @@
+//! Index event sample popup rendering.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+use splunk_client::workflows::event_sample::SampleEvent;
+
+use crate::app::App;
+use crate::theme::Theme;
+use crate::ui::theme::ThemeExt;
+
+/// Width of the list highlight symbol, subtracted before wrapping.
+const HIGHLIGHT_SYMBOL_WIDTH: usize = 2;
+
+/// Render the index event sample popup.
+///
+/// Each event shows a metadata header followed by its raw text, with
+/// timestamps inside the raw text highlighted.
+pub fn render_event_sample(f: &mut Frame, app: &App, theme: &Theme) {
+    let Some(view) = &app.event_sample else {
+        return;
+    };
+
+    let area = f.area();
+    let popup_width = 120.min(area.width.saturating_sub(4));
+    let popup_height = 36.min(area.height.saturating_sub(4));
+    let x = (area.width.saturating_sub(popup_width)) / 2;
+    let y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(
+            " Sample Events: {} ({} of {}) ",
+            view.index,
+            view.selected + 1,
+            view.events.len()
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(1)])
+        .split(inner);
+
+    let wrap_width = view
+        .wrap
+        .then(|| (chunks[0].width as usize).saturating_sub(HIGHLIGHT_SYMBOL_WIDTH))
+        .filter(|width| *width > 0);
+    let items: Vec<ListItem> = view
+        .events
+        .iter()
+        .map(|event| ListItem::new(event_lines(event, wrap_width, theme)))
+        .collect();
+    let list = List::new(items)
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+        .highlight_symbol("> ");
+    let mut list_state = ListState::default().with_selected(Some(view.selected));
+    f.render_stateful_widget(list, chunks[0], &mut list_state);
+
+    let wrap_hint = if view.wrap { "w: no wrap" } else { "w: wrap" };
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            format!(
+                "j/k: select  g/G: first/last  {}  y/Ctrl+c: copy raw event  Esc/q: close",
+                wrap_hint
+            ),
+            Style::default().fg(theme.text_dim),
+        ))),
+        chunks[1],
+    );
+}
+
+/// Header plus raw text lines of one event, followed by a blank separator.
+fn event_lines(
+    event: &SampleEvent,
+    wrap_width: Option<usize>,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
+    let mut header = vec![Span::styled(
+        event.time.clone().unwrap_or_else(|| "-".to_string()),
+        Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::BOLD),
+    )];
+    for (label, value) in [
+        ("host", &event.host),
+        ("source", &event.source),
+        ("sourcetype", &event.sourcetype),
+    ] {
+        if let Some(value) = value {
+            header.push(Span::styled(
+                format!("  {}={}", label, value),
+                theme.text_dim(),
+            ));
+        }
+    }
+
+    let mut lines = vec![Line::from(header)];
+    let timestamp_style = Style::default().fg(theme.accent);
+    for raw_line in event.raw.lines() {
+        let segments = timestamp_segments(raw_line);
+        for chunk in wrap_segments(&segments, wrap_width) {
+            lines.push(Line::from(
+                chunk
+                    .into_iter()
+                    .map(|(text, is_timestamp)| {
+                        if is_timestamp {
+                            Span::styled(text, timestamp_style)
+                        } else {
+                            Span::raw(text)
+                        }
+                    })
+                    .collect::<Vec<_>>(),
+            ));
+        }
+    }
+    lines.push(Line::default());
+    lines
+}
+
+/// Split `line` into runs, flagging the runs that look like timestamps.
+fn timestamp_segments(line: &str) -> Vec<(String, bool)> {
+    let bytes = line.as_bytes();
+    let mut segments = Vec::new();
+    let mut plain_start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match timestamp_end(bytes, i) {
+            Some(end) => {
+                if plain_start < i {
+                    segments.push((line[plain_start..i].to_string(), false));
+                }
+                segments.push((line[i..end].to_string(), true));
+                i = end;
+                plain_start = end;
+            }
+            None => i += 1,
+        }
+    }
+    if plain_start < bytes.len() {
+        segments.push((line[plain_start..].to_string(), false));
+    }
+    segments
+}
+
+fn digits_at(bytes: &[u8], start: usize, count: usize) -> bool {
+    bytes
+        .get(start..start + count)
+        .is_some_and(|run| run.iter().all(u8::is_ascii_digit))
+}
+
+/// End of a `YYYY-MM-DD[T ]HH:MM:SS[.fff][Z|+hh:mm]` or `HH:MM:SS` timestamp
+/// starting at `start`, if there is one.
+fn timestamp_end(bytes: &[u8], start: usize) -> Option<usize> {
+    if start > 0 && bytes[start - 1].is_ascii_digit() {
+        return None;
+    }
+    let is_date = digits_at(bytes, start, 4)
+        && bytes.get(start + 4) == Some(&b'-')
+        && digits_at(bytes, start + 5, 2)
+        && bytes.get(start + 7) == Some(&b'-')
+        && digits_at(bytes, start + 8, 2);
+    if is_date {
+        let date_end = start + 10;
+        if matches!(bytes.get(date_end), Some(b'T' | b' '))
+            && let Some(end) = time_end(bytes, date_end + 1)
+        {
+            return Some(end);
+        }
+        return Some(date_end);
+    }
+    time_end(bytes, start)
+}
+
+fn time_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let is_time = digits_at(bytes, start, 2)
+        && bytes.get(start + 2) == Some(&b':')
+        && digits_at(bytes, start + 3, 2)
+        && bytes.get(start + 5) == Some(&b':')
+        && digits_at(bytes, start + 6, 2);
+    if !is_time {
+        return None;
+    }
+    let mut end = start + 8;
+    if bytes.get(end) == Some(&b'.') && digits_at(bytes, end + 1, 1) {
+        end += 1;
+        while digits_at(bytes, end, 1) {
+            end += 1;
+        }
+    }
+    match bytes.get(end) {
+        Some(b'Z') => end += 1,
+        Some(b'+' | b'-') if digits_at(bytes, end + 1, 2) => {
+            let mut offset_end = end + 3;
+            if bytes.get(offset_end) == Some(&b':') {
+                offset_end += 1;
+            }
+            if digits_at(bytes, offset_end, 2) {
+                end = offset_end + 2;
+            }
+        }
+        _ => {}
+    }
+    Some(end)
+}
+
+/// Break styled runs into lines of at most `width` characters; `None` keeps
+/// them on one line.
+fn wrap_segments(segments: &[(String, bool)], width: Option<usize>) -> Vec<Vec<(String, bool)>> {
+    let Some(width) = width else {
+        return vec![segments.to_vec()];
+    };
+    let mut lines = vec![Vec::new()];
+    let mut line_len = 0;
+    for (text, flag) in segments {
+        let mut run = String::new();
+        for c in text.chars() {
+            if line_len == width {
+                if !run.is_empty() {
+                    lines
+                        .last_mut()
+                        .unwrap()
+                        .push((std::mem::take(&mut run), *flag));
+                }
+                lines.push(Vec::new());
+                line_len = 0;
+            }
+            run.push(c);
+            line_len += 1;
+        }
+        if !run.is_empty() {
+            lines.last_mut().unwrap().push((run, *flag));
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::app::event_sample::EventSampleView;
+    use ratatui::backend::TestBackend;
+
+    fn timestamps(line: &str) -> Vec<String> {
+        timestamp_segments(line)
+            .into_iter()
+            .filter(|(_, is_timestamp)| *is_timestamp)
+            .map(|(text, _)| text)
+            .collect()
+    }
+
+    #[test]
+    fn test_timestamp_segments_finds_common_formats() {
+        assert_eq!(
+            timestamps("2024-01-15T10:30:00.123+00:00 GET / took 12:00:01"),
+            vec!["2024-01-15T10:30:00.123+00:00", "12:00:01"]
+        );
+        assert_eq!(
+            timestamps("at 2024-01-15 10:30:00Z ok"),
+            vec!["2024-01-15 10:30:00Z"]
+        );
+        assert!(timestamps("id=120240115 ratio 1:2:3").is_empty());
+    }
+
+    #[test]
+    fn test_wrap_segments_keeps_styles_across_breaks() {
+        let segments = vec![("abc".to_string(), false), ("defg".to_string(), true)];
+        assert_eq!(
+            wrap_segments(&segments, Some(5)),
+            vec![
+                vec![("abc".to_string(), false), ("de".to_string(), true)],
+                vec![("fg".to_string(), true)],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_event_sample_shows_header_and_wrapped_raw() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.event_sample = EventSampleView::new(
+            "web".to_string(),
+            vec![SampleEvent {
+                time: Some("2024-01-15T10:30:00.000+00:00".to_string()),
+                host: Some("web01".to_string()),
+                raw: format!("start {} end", "x".repeat(150)),
+                ..Default::default()
+            }],
+        );
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(120, 40)).unwrap();
+        terminal
+            .draw(|f| render_event_sample(f, &app, &Theme::default()))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let screen = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(screen.contains("Sample Events: web (1 of 1)"), "{}", screen);
+        assert!(screen.contains("> 2024-01-15T10:30:00.000+00:00  host=web01"));
+        assert!(screen.contains("start xxx"));
+        assert!(
+            screen.contains("xxx end"),
+            "raw event should wrap: {}",
+            screen
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//! Field extraction tester popup rendering.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, Wrap},
+};
+use splunk_client::workflows::field_extraction::FieldExtractionPreview;
+
+use crate::app::App;
+use crate::app::extraction_tester::ExtractionTesterFocus;
+use crate::theme::Theme;
+use crate::ui::theme::ThemeExt;
+
+/// Widest an extracted field column grows before values are cut off.
+const MAX_FIELD_WIDTH: u16 = 24;
+
+/// Render the field extraction tester popup.
+///
+/// Shows the sample events and regex inputs, then each sample with its
+/// extracted fields side by side; samples the regex missed are dimmed.
+pub fn render_extraction_tester(f: &mut Frame, app: &App, theme: &Theme) {
+    let Some(tester) = &app.extraction_tester else {
+        return;
+    };
+
+    let area = f.area();
+    let popup_width = 110.min(area.width.saturating_sub(4));
+    let popup_height = 34.min(area.height.saturating_sub(4));
+    let x = (area.width.saturating_sub(popup_width)) / 2;
+    let y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    f.render_widget(Clear, popup_area);
+
+    let title = match &tester.sourcetype {
+        Some(sourcetype) => format!(" Field Extraction Tester: {} ", sourcetype),
+        None => " Field Extraction Tester ".to_string(),
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(8),
+            Constraint::Length(3),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let samples_focused = tester.focus == ExtractionTesterFocus::Samples;
+    let samples_block = input_block("Sample events (one per line)", samples_focused, theme);
+    let samples_area = samples_block.inner(chunks[0]);
+    f.render_widget(samples_block, chunks[0]);
+    if samples_focused {
+        f.render_widget(&tester.samples, samples_area);
+    } else if tester.samples.is_empty() {
+        f.render_widget(
+            Paragraph::new(Span::styled(
+                tester.samples.inner().placeholder_text(),
+                theme.text_dim(),
+            )),
+            samples_area,
+        );
+    } else {
+        f.render_widget(Paragraph::new(tester.samples.value()), samples_area);
+    }
+
+    let regex_focused = tester.focus == ExtractionTesterFocus::Regex;
+    let regex_text = if tester.regex.is_empty() {
+        Span::styled(tester.regex.placeholder().unwrap_or(""), theme.text_dim())
+    } else {
+        Span::raw(tester.regex.value())
+    };
+    f.render_widget(
+        Paragraph::new(Line::from(regex_text)).block(input_block(
+            "Regex or EXTRACT- line",
+            regex_focused,
+            theme,
+        )),
+        chunks[1],
+    );
+    if regex_focused {
+        let prefix_width = tester
+            .regex
+            .value()
+            .chars()
+            .take(tester.regex.cursor_position())
+            .count() as u16;
+        let cursor_x = chunks[1].x + 1 + prefix_width;
+        if cursor_x < chunks[1].x + chunks[1].width.saturating_sub(1) {
+            f.set_cursor_position(ratatui::layout::Position::new(cursor_x, chunks[1].y + 1));
+        }
+    }
+
+    match &tester.result {
+        Some(Ok(preview)) => render_preview(f, chunks[2], preview, theme),
+        Some(Err(message)) => f.render_widget(
+            Paragraph::new(Span::styled(message.as_str(), theme.error()))
+                .block(results_block(" Results ", theme))
+                .wrap(Wrap { trim: false }),
+            chunks[2],
+        ),
+        None => {
+            let status = if tester.running {
+                "Running extraction test..."
+            } else {
+                "Press Enter in the regex field to test it against the samples."
+            };
+            f.render_widget(
+                Paragraph::new(Span::styled(status, theme.text_dim()))
+                    .block(results_block(" Results ", theme)),
+                chunks[2],
+            );
+        }
+    }
+
+    let hint = Line::from(Span::styled(
+        "Tab: switch input  Enter (regex): test  Ctrl+c: copy search  Esc: close",
+        Style::default().fg(theme.text_dim),
+    ));
+    f.render_widget(Paragraph::new(hint), chunks[3]);
+}
+
+/// Render each sample with its extracted fields side by side.
+fn render_preview(f: &mut Frame, area: Rect, preview: &FieldExtractionPreview, theme: &Theme) {
+    let header = Row::new(
+        std::iter::once("#".to_string())
+            .chain(preview.fields.iter().cloned())
+            .chain(std::iter::once("_raw".to_string()))
+            .map(Cell::from),
+    )
+    .style(Style::default().add_modifier(Modifier::BOLD));
+
+    let rows = preview.events.iter().enumerate().map(|(i, event)| {
+        let cells = std::iter::once(Cell::from((i + 1).to_string()))
+            .chain(
+                preview
+                    .fields
+                    .iter()
+                    .map(|field| match event.values.get(field) {
+                        Some(value) => {
+                            Cell::from(value.clone()).style(Style::default().fg(theme.accent))
+                        }
+                        None => Cell::from("-"),
+                    }),
+            )
+            .chain(std::iter::once(Cell::from(event.raw.clone())));
+        let row = Row::new(cells);
+        if event.matched() {
+            row
+        } else {
+            row.style(theme.text_dim())
+        }
+    });
+
+    let mut widths = vec![Constraint::Length(3)];
+    widths.extend(preview.fields.iter().map(|field| {
+        let widest = preview
+            .events
+            .iter()
+            .filter_map(|event| event.values.get(field))
+            .map(|value| value.chars().count())
+            .chain([field.chars().count()])
+            .max()
+            .unwrap_or(1);
+        Constraint::Length((widest as u16).min(MAX_FIELD_WIDTH))
+    }));
+    widths.push(Constraint::Min(10));
+
+    let title = format!(
+        " Results: {} of {} matched ",
+        preview.matched_count(),
+        preview.events.len()
+    );
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(results_block(&title, theme));
+    f.render_widget(table, area);
+}
+
+fn input_block<'a>(label: &'a str, focused: bool, theme: &Theme) -> Block<'a> {
+    Block::default()
+        .title(Span::styled(
+            format!(" {} ", label),
+            Style::default().add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_style(if focused {
+            theme.border_focused()
+        } else {
+            theme.border()
+        })
+}
+
+fn results_block<'a>(title: &'a str, theme: &Theme) -> Block<'a> {
+    Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::app::extraction_tester::ExtractionTester;
+    use ratatui::backend::TestBackend;
+    use splunk_client::workflows::field_extraction::ExtractedEvent;
+
+    fn render(app: &App) -> String {
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(120, 40)).unwrap();
+        terminal
+            .draw(|f| render_extraction_tester(f, app, &Theme::default()))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_render_preview_shows_fields_side_by_side() {
+        let mut app = App::new(None, ConnectionContext::default());
+        let mut tester = ExtractionTester::new(Some("app:log".to_string()));
+        tester.samples.set_value("user=alice status=200\ngarbage");
+        tester.result = Some(Ok(FieldExtractionPreview {
+            fields: vec!["user".to_string(), "status".to_string()],
+            events: vec![
+                ExtractedEvent {
+                    raw: "user=alice status=200".to_string(),
+                    values: [
+                        ("user".to_string(), "alice".to_string()),
+                        ("status".to_string(), "200".to_string()),
+                    ]
+                    .into(),
+                },
+                ExtractedEvent {
+                    raw: "garbage".to_string(),
+                    values: Default::default(),
+                },
+            ],
+            search: String::new(),
+        }));
+        app.extraction_tester = Some(tester);
+
+        let screen = render(&app);
+        assert!(
+            screen.contains("Field Extraction Tester: app:log"),
+            "{}",
+            screen
+        );
+        assert!(screen.contains("Results: 1 of 2 matched"));
+        assert!(screen.contains("#   user  status _raw"), "{}", screen);
+        assert!(screen.contains("1   alice 200    user=alice status=200"));
+        assert!(screen.contains("2   -     -      garbage"));
+    }
+}
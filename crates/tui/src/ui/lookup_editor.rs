@@ -0,0 +1,152 @@
+//! Lookup editor popup rendering.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState},
+};
+
+use crate::app::App;
+use crate::app::lookup_editor::LookupEditor;
+use crate::theme::Theme;
+use crate::ui::theme::ThemeExt;
+
+/// Widest a column is drawn, in characters.
+const MAX_COLUMN_WIDTH: usize = 30;
+
+/// Render the lookup editor popup.
+///
+/// Shows a window of columns around the cursor, the selected row and cell,
+/// and the edit buffer in place of the cell being edited.
+pub fn render_lookup_editor(f: &mut Frame, app: &App, theme: &Theme) {
+    let area = f.area();
+
+    let popup_width = (area.width * 9 / 10).max(20).min(area.width);
+    let popup_height = (area.height * 8 / 10).max(8).min(area.height);
+    let popup_area = Rect::new(
+        (area.width - popup_width) / 2,
+        (area.height - popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+
+    f.render_widget(Clear, popup_area);
+
+    let Some(editor) = &app.lookup_editor else {
+        return;
+    };
+
+    let modified = if editor.dirty { " [modified]" } else { "" };
+    let block = Block::default()
+        .title(format!(" Lookup: {}{} ", editor.name, modified))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(1)])
+        .split(inner);
+
+    let widths = column_widths(editor);
+    let columns = visible_columns(&widths, editor.col, chunks[0].width as usize);
+
+    let header = Row::new(
+        columns
+            .clone()
+            .map(|c| Cell::from(editor.content.header[c].clone()).style(theme.table_header())),
+    );
+    let rows = editor.content.rows.iter().enumerate().map(|(r, row)| {
+        Row::new(columns.clone().map(|c| {
+            let selected = r == editor.row && c == editor.col;
+            match (&editor.editing, selected) {
+                (Some(buffer), true) => Cell::from(format!("{}▏", buffer)).style(
+                    Style::default()
+                        .fg(theme.warning)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                (None, true) => Cell::from(row[c].clone())
+                    .style(Style::default().add_modifier(Modifier::REVERSED)),
+                _ => Cell::from(row[c].clone()),
+            }
+        }))
+    });
+
+    let table = Table::new(
+        rows,
+        columns
+            .clone()
+            .map(|c| Constraint::Length(widths[c] as u16)),
+    )
+    .header(header)
+    .row_highlight_style(theme.highlight());
+    let mut state = TableState::default()
+        .with_selected((!editor.content.rows.is_empty()).then_some(editor.row));
+    f.render_stateful_widget(table, chunks[0], &mut state);
+
+    let status = format!(
+        "row {}/{}  col {}/{}  ",
+        (editor.row + 1).min(editor.content.rows.len()),
+        editor.content.rows.len(),
+        editor.col + 1,
+        editor.content.header.len()
+    );
+    let hint = if editor.editing.is_some() {
+        "Enter: apply  Esc: cancel"
+    } else {
+        "hjkl: move  Enter/e: edit  o: add row  d: delete row  Ctrl+c: copy  Ctrl+s: save  Esc: close"
+    };
+    let footer = Line::from(vec![
+        Span::styled(status, Style::default().fg(theme.info)),
+        Span::styled(hint, Style::default().fg(theme.text_dim)),
+    ]);
+    f.render_widget(Paragraph::new(footer), chunks[1]);
+}
+
+/// Display width of each column: its widest value, capped at `MAX_COLUMN_WIDTH`.
+fn column_widths(editor: &LookupEditor) -> Vec<usize> {
+    (0..editor.content.header.len())
+        .map(|c| {
+            std::iter::once(&editor.content.header)
+                .chain(&editor.content.rows)
+                .map(|row| row[c].chars().count())
+                .max()
+                .unwrap_or(0)
+                .clamp(1, MAX_COLUMN_WIDTH)
+        })
+        .collect()
+}
+
+/// The contiguous range of columns that fits `width` and includes `cursor`.
+fn visible_columns(widths: &[usize], cursor: usize, width: usize) -> std::ops::Range<usize> {
+    // Each column is followed by a one-character spacing.
+    let fits =
+        |range: std::ops::Range<usize>| widths[range].iter().map(|w| w + 1).sum::<usize>() <= width;
+
+    let mut start = 0;
+    while start < cursor && !fits(start..cursor + 1) {
+        start += 1;
+    }
+    let mut end = cursor + 1;
+    while end < widths.len() && fits(start..end + 1) {
+        end += 1;
+    }
+    start..end.min(widths.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visible_columns_scroll_to_cursor() {
+        let widths = [10, 10, 10, 10];
+
+        assert_eq!(visible_columns(&widths, 0, 25), 0..2);
+        assert_eq!(visible_columns(&widths, 3, 25), 2..4);
+        assert_eq!(visible_columns(&widths, 1, 100), 0..4);
+    }
+}
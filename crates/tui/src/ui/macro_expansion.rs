@@ -0,0 +1,122 @@
+//! Macro expansion preview popup rendering with scrolling support.
+
+use ratatui::{
+    Frame,
+    layout::{Margin, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap,
+    },
+};
+
+use crate::app::App;
+use crate::theme::Theme;
+use crate::ui::syntax::highlight_spl;
+
+/// Render the macro expansion preview popup.
+///
+/// Shows the fully expanded query first, followed by each expanded macro
+/// (indented by nesting depth) and any macros that could not be resolved.
+pub fn render_macro_expansion(f: &mut Frame, app: &App, theme: &Theme) {
+    let area = f.area();
+
+    let popup_width = 100.min(area.width.saturating_sub(4));
+    let popup_height = 30.min(area.height.saturating_sub(4));
+
+    let x = (area.width.saturating_sub(popup_width)) / 2;
+    let y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    f.render_widget(Clear, popup_area);
+
+    let Some(expansion) = &app.macro_expansion else {
+        return;
+    };
+
+    let bold = Style::default().add_modifier(Modifier::BOLD);
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Expanded query", bold),
+            Span::styled(
+                "   Ctrl+c: copy  Esc: close",
+                Style::default().fg(theme.text_dim),
+            ),
+        ]),
+        Line::default(),
+    ];
+    lines.extend(highlight_spl(&expansion.expanded, theme).lines);
+
+    if !expansion.has_macros() {
+        lines.push(Line::default());
+        lines.push(Line::from(Span::styled(
+            "No macros found in query",
+            Style::default().fg(theme.text_dim),
+        )));
+    }
+
+    if !expansion.macros.is_empty() {
+        lines.push(Line::default());
+        lines.push(Line::from(Span::styled("Expanded macros", bold)));
+        for m in &expansion.macros {
+            let mut spans = vec![
+                Span::raw("  ".repeat(m.depth)),
+                Span::styled(format!("`{}`", m.name), Style::default().fg(theme.accent)),
+            ];
+            if m.iseval {
+                spans.push(Span::styled(
+                    " (eval, not evaluated)",
+                    Style::default().fg(theme.warning),
+                ));
+            }
+            spans.push(Span::raw(format!(" = {}", m.definition)));
+            lines.push(Line::from(spans));
+        }
+    }
+
+    if !expansion.unknown.is_empty() {
+        lines.push(Line::default());
+        lines.push(Line::from(Span::styled(
+            "Unknown or disabled macros (left unexpanded)",
+            bold.fg(theme.warning),
+        )));
+        for name in &expansion.unknown {
+            lines.push(Line::from(format!("  `{}`", name)));
+        }
+    }
+
+    // Lines wrap, so estimate the rendered height to bound scrolling.
+    let inner_width = popup_width.saturating_sub(2).max(1) as usize;
+    let rendered_lines: usize = lines
+        .iter()
+        .map(|line| line.width().div_ceil(inner_width).max(1))
+        .sum();
+    let visible_lines = popup_height.saturating_sub(2) as usize;
+    let max_offset = rendered_lines.saturating_sub(visible_lines);
+    let offset = app.macro_expansion_scroll_offset.min(max_offset);
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(" Macro Expansion ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .wrap(Wrap { trim: false })
+        .scroll((offset as u16, 0));
+
+    f.render_widget(paragraph, popup_area);
+
+    if rendered_lines > visible_lines {
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(Some("↑"))
+            .end_symbol(Some("↓"));
+        let mut scrollbar_state = ScrollbarState::new(max_offset).position(offset);
+        f.render_stateful_widget(
+            scrollbar,
+            popup_area.inner(Margin::new(0, 1)),
+            &mut scrollbar_state,
+        );
+    }
+}
@@ -32,20 +32,38 @@
 //! let style = theme.title(); // Uses ThemeExt trait
 //! ```
 
+pub mod app_details;
+pub mod background_jobs;
 pub mod components;
+pub mod dashboard_source;
 pub mod error_details;
+pub mod event_sample;
+pub mod extraction_tester;
 pub mod index_details;
+pub mod lookup_editor;
+pub mod macro_expansion;
+pub mod no_color;
+pub mod orphaned_objects;
 pub mod popup;
+pub mod result_detail;
+pub mod result_renderers;
 pub mod screens;
+pub mod server_messages;
 pub mod syntax;
+pub mod tab_bar;
+pub mod template_form;
 pub mod theme;
+pub mod time_range;
 pub mod toast;
+pub mod top_values;
+pub mod tstats_builder;
 pub mod widgets;
+pub mod wizard;
 
 // Layout module with flexbox support via taffy
 pub mod layout {
     pub mod flex;
 }
 
-pub use theme::ThemeExt;
+pub use theme::{StatusClass, ThemeExt};
 pub use toast::{Toast, ToastLevel};
@@ -0,0 +1,76 @@
+//! No-color rendering mode for monochrome terminals and colorblind users.
+//!
+//! Responsibilities:
+//! - Detect the `NO_COLOR` convention (<https://no-color.org>)
+//! - Strip every color from a rendered frame, keeping color-only signals visible
+//!   as text attributes
+//!
+//! Does NOT handle:
+//! - Does NOT add status text tags (see `ThemeExt::status_text` and `ThemeExt::tagged`)
+//! - Does NOT store the mode (see `App::no_color` and `Theme::no_color`)
+//!
+//! Invariants:
+//! - After `strip_colors` no cell has a foreground or background color
+//! - Cells drawn on a highlight background (selection, table headers, matches) are
+//!   reversed; error-colored text is bold; disabled text is dimmed
+
+use ratatui::{
+    buffer::Buffer,
+    style::{Color, Modifier},
+};
+
+use crate::theme::Theme;
+
+/// Whether `NO_COLOR` is set to a non-empty value.
+pub fn no_color_env() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+/// Remove all colors from `buffer`, replacing the ones that carry meaning with modifiers.
+pub fn strip_colors(buffer: &mut Buffer, theme: &Theme) {
+    for cell in &mut buffer.content {
+        let mut modifier = cell.modifier;
+        if cell.bg != Color::Reset && cell.bg != theme.background {
+            modifier |= Modifier::REVERSED;
+        }
+        if cell.fg == theme.error || cell.fg == theme.health_unhealthy {
+            modifier |= Modifier::BOLD;
+        } else if cell.fg == theme.disabled {
+            modifier |= Modifier::DIM;
+        }
+        cell.fg = Color::Reset;
+        cell.bg = Color::Reset;
+        cell.modifier = modifier;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{layout::Rect, style::Style};
+
+    #[test]
+    fn test_strip_colors_keeps_signals_as_modifiers() {
+        let theme = Theme::default();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        buffer.set_string(
+            0,
+            0,
+            "s",
+            Style::default()
+                .fg(theme.highlight_fg)
+                .bg(theme.highlight_bg),
+        );
+        buffer.set_string(1, 0, "e", Style::default().fg(theme.error));
+        buffer.set_string(2, 0, "d", Style::default().fg(theme.disabled));
+
+        strip_colors(&mut buffer, &theme);
+
+        for cell in &buffer.content {
+            assert_eq!((cell.fg, cell.bg), (Color::Reset, Color::Reset));
+        }
+        assert!(buffer.content[0].modifier.contains(Modifier::REVERSED));
+        assert!(buffer.content[1].modifier.contains(Modifier::BOLD));
+        assert!(buffer.content[2].modifier.contains(Modifier::DIM));
+    }
+}
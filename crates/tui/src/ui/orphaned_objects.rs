@@ -0,0 +1,137 @@
+//! Orphaned Objects popup rendering.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::app::App;
+use crate::theme::Theme;
+use crate::ui::popup::{Popup, PopupType};
+use crate::ui::theme::ThemeExt;
+
+/// Render the Orphaned Objects popup.
+///
+/// Lists each object owned by a missing user with its suggested new owner,
+/// followed by a scan summary and the reassignment hint.
+pub fn render_orphaned_objects(f: &mut Frame, app: &App, theme: &Theme) {
+    let (
+        Some(report),
+        Some(Popup {
+            kind: PopupType::OrphanedObjects { selected_index },
+            ..
+        }),
+    ) = (app.orphan_report.as_ref(), app.popup.as_ref())
+    else {
+        return;
+    };
+
+    let area = f.area();
+    let popup_width = 110.min(area.width.saturating_sub(4));
+    let popup_height = 24.min(area.height.saturating_sub(4));
+
+    let x = (area.width.saturating_sub(popup_width)) / 2;
+    let y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(
+            " Orphaned Objects ({} owned by {} missing users) ",
+            report.orphans.len(),
+            report.missing_owners().len()
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),
+            Constraint::Length(3),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let kind_width = report
+        .orphans
+        .iter()
+        .map(|o| o.kind.label().len())
+        .max()
+        .unwrap_or(0);
+    let name_width = report
+        .orphans
+        .iter()
+        .map(|o| o.name.len())
+        .max()
+        .unwrap_or(0);
+    let app_width = report
+        .orphans
+        .iter()
+        .map(|o| o.app.len())
+        .max()
+        .unwrap_or(0);
+    let items: Vec<ListItem> = report
+        .orphans
+        .iter()
+        .map(|orphan| {
+            let suggestion = match orphan.suggested_owner.as_deref() {
+                Some(owner) => Span::styled(format!(" -> {owner}"), theme.success()),
+                None => Span::styled(" (no suggestion)", theme.text_dim()),
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<width$}  ", orphan.kind.label(), width = kind_width),
+                    theme.text_dim(),
+                ),
+                Span::raw(format!("{:<width$}  ", orphan.name, width = name_width)),
+                Span::styled(
+                    format!("{:<width$}  ", orphan.app, width = app_width),
+                    theme.text_dim(),
+                ),
+                Span::styled(orphan.owner.as_str(), theme.warning()),
+                suggestion,
+            ]))
+        })
+        .collect();
+    let list = List::new(items)
+        .highlight_style(theme.highlight())
+        .highlight_symbol("> ");
+    let mut list_state = ListState::default().with_selected(Some(*selected_index));
+    f.render_stateful_widget(list, chunks[0], &mut list_state);
+
+    let summary = vec![
+        Line::from(format!(
+            "Checked {} objects against {} users. Missing owners: {}",
+            report.scanned,
+            report.users,
+            report.missing_owners().join(", ")
+        )),
+        Line::from(Span::styled(
+            "Reassign with `splunk-cli saved-searches bulk --reassign-to` or `<kind> acl set --new-owner`",
+            theme.text_dim(),
+        )),
+    ];
+    f.render_widget(
+        Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::TOP)
+                .border_style(Style::default().fg(theme.border)),
+        ),
+        chunks[1],
+    );
+
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            "j/k: select  r: rescan  Ctrl+c: copy name  Esc: close",
+            Style::default().fg(theme.text_dim),
+        ))),
+        chunks[2],
+    );
+}
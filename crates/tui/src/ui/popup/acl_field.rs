@@ -0,0 +1,39 @@
+//! Permissions (ACL) editor field selection for form navigation.
+//!
+//! This module provides the `AclField` enum and its navigation methods
+//! for cycling through the permissions editor fields.
+
+/// Field selection for permissions editor navigation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AclField {
+    /// Owner field
+    Owner,
+    /// Sharing level field
+    Sharing,
+    /// Read roles field
+    Read,
+    /// Write roles field
+    Write,
+}
+
+impl AclField {
+    /// Get the next field in the form (cycles through all fields).
+    pub fn next(self) -> Self {
+        match self {
+            AclField::Owner => AclField::Sharing,
+            AclField::Sharing => AclField::Read,
+            AclField::Read => AclField::Write,
+            AclField::Write => AclField::Owner,
+        }
+    }
+
+    /// Get the previous field in the form (cycles through all fields).
+    pub fn previous(self) -> Self {
+        match self {
+            AclField::Owner => AclField::Write,
+            AclField::Sharing => AclField::Owner,
+            AclField::Read => AclField::Sharing,
+            AclField::Write => AclField::Read,
+        }
+    }
+}
@@ -5,10 +5,20 @@
 
 use crate::action::variants::{ConnectionDiagnosticsResult, DiagnosticStatus};
 use crate::app::App;
+use crate::app::troubleshooting::RECIPES;
 use crate::error_details::AuthRecoveryKind;
 use crate::input::help;
 use crate::onboarding::{TutorialState, TutorialSteps};
-use crate::ui::popup::{MacroField, PopupType, ProfileField, SavedSearchField};
+use crate::ui::popup::{
+    AclField, CreateInputField, CreateInputKind, FREEZE_ACK_THRESHOLD_PERCENT,
+    HealthThresholdField, InstallAppField, JobsFilterField, JobsPruneField, MacroField,
+    ModifyIndexField, NamespaceField, PopupType, ProfileField, SavedSearchField, SearchPeerField,
+};
+use splunk_client::JobStatusFilter;
+use splunk_client::models::{FreezeProjection, ObjectAcl};
+use splunk_client::relative_time::parse_time_modifier;
+use splunk_client::workflows::splunkbase::SplunkbaseApp;
+use splunk_client::workflows::user_activity::UserActivityReport;
 
 /// A modal popup dialog with title, content, and type.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -127,6 +137,65 @@ impl PopupBuilder {
                 "Index Details".to_string(),
                 "Press Esc or q to close, j/k to scroll".to_string(),
             ),
+            PopupType::DashboardSource => (
+                "Dashboard Source".to_string(),
+                "Press Esc or q to close, j/k to scroll, e to export".to_string(),
+            ),
+            PopupType::MacroExpansion => (
+                "Macro Expansion".to_string(),
+                "Press Esc or q to close, j/k to scroll".to_string(),
+            ),
+            PopupType::ResultDetail => (
+                "Result Detail".to_string(),
+                "Press Esc or q to close, j/k to scroll".to_string(),
+            ),
+            PopupType::TstatsBuilder => (
+                "Tstats Query Builder".to_string(),
+                "Press Enter to run, Esc or q to close".to_string(),
+            ),
+            PopupType::TemplateParameters => (
+                "Search Template".to_string(),
+                "Press Enter to apply, Esc to cancel".to_string(),
+            ),
+            PopupType::OnboardingWizard => (
+                "Data Onboarding".to_string(),
+                "Press Enter to continue, Esc to cancel".to_string(),
+            ),
+            PopupType::ExtractionTester => (
+                "Field Extraction Tester".to_string(),
+                "Press Enter to test, Esc to close".to_string(),
+            ),
+            PopupType::TimeRangePicker => {
+                ("Time Range".to_string(), "Press Esc to close".to_string())
+            }
+            PopupType::EventSample => (
+                "Sample Events".to_string(),
+                "Press Esc or q to close".to_string(),
+            ),
+            PopupType::TopValues => (
+                "Top Values".to_string(),
+                "Press Enter to run, Esc or q to close".to_string(),
+            ),
+            PopupType::ServerMessages => (
+                "Server Messages".to_string(),
+                "Press d to dismiss, Esc or q to close".to_string(),
+            ),
+            PopupType::AppDetails => (
+                "App Details".to_string(),
+                "Press Tab to switch object type, Esc or q to close".to_string(),
+            ),
+            PopupType::BackgroundJobs => (
+                "Background Jobs".to_string(),
+                "Press Enter to view, Esc or q to close".to_string(),
+            ),
+            PopupType::OrphanedObjects { .. } => (
+                "Orphaned Objects".to_string(),
+                "Press r to rescan, Esc or q to close".to_string(),
+            ),
+            PopupType::LookupEditor => (
+                "Lookup Editor".to_string(),
+                "Press Enter to edit a cell, Ctrl+s to save, Esc or q to close".to_string(),
+            ),
             PopupType::ConfirmEnableApp(name) => (
                 "Confirm Enable".to_string(),
                 format!("Enable app '{}'? (y/n)", name),
@@ -139,6 +208,16 @@ impl PopupBuilder {
                 profiles,
                 selected_index,
             } => self.build_profile_selector_defaults(profiles, *selected_index),
+            PopupType::SessionPicker {
+                sessions,
+                selected_index,
+                name_input,
+            } => {
+                self.build_session_picker_defaults(sessions, *selected_index, name_input.as_deref())
+            }
+            PopupType::TroubleshootingRecipes { selected_index } => {
+                self.build_troubleshooting_recipes_defaults(*selected_index)
+            }
             PopupType::CreateIndex { name_input, .. } => (
                 "Create Index".to_string(),
                 format!(
@@ -146,12 +225,26 @@ impl PopupBuilder {
                     name_input
                 ),
             ),
-            PopupType::ModifyIndex { index_name, .. } => (
-                "Modify Index".to_string(),
-                format!(
-                    "Modify index '{}':\n\nPress Enter to apply changes, Esc to cancel",
-                    index_name
+            PopupType::ModifyIndex {
+                index_name,
+                current_max_data_size_mb,
+                current_frozen_time_period_secs,
+                new_max_data_size_mb,
+                new_frozen_time_period_secs,
+                selected_field,
+                freeze_projection,
+                freeze_acknowledged,
+                ..
+            } => self.build_modify_index_defaults(
+                index_name,
+                (*current_max_data_size_mb, *new_max_data_size_mb),
+                (
+                    *current_frozen_time_period_secs,
+                    *new_frozen_time_period_secs,
                 ),
+                *selected_field,
+                freeze_projection.as_ref(),
+                *freeze_acknowledged,
             ),
             PopupType::DeleteIndexConfirm { index_name } => (
                 "Confirm Delete".to_string(),
@@ -209,12 +302,20 @@ impl PopupBuilder {
                     app_name
                 ),
             ),
-            PopupType::InstallAppDialog { file_input } => (
-                "Install App".to_string(),
-                format!(
-                    "Enter path to .spl file:\n\n{}\n\nPress Enter to install, Esc to cancel",
-                    file_input
-                ),
+            PopupType::InstallAppDialog {
+                file_input,
+                splunkbase_query,
+                version_input,
+                selected_field,
+                splunkbase_results,
+                selected_result,
+            } => self.build_install_app_defaults(
+                file_input,
+                splunkbase_query,
+                version_input,
+                *selected_field,
+                splunkbase_results,
+                *selected_result,
             ),
             PopupType::CreateProfile {
                 name_input,
@@ -276,12 +377,15 @@ impl PopupBuilder {
                 search_name,
                 search_input,
                 description_input,
+                dispatch_earliest_input,
+                dispatch_latest_input,
                 disabled,
                 selected_field,
             } => self.build_edit_saved_search_defaults(
                 search_name,
                 search_input,
                 description_input,
+                [dispatch_earliest_input, dispatch_latest_input],
                 *disabled,
                 *selected_field,
             ),
@@ -305,6 +409,15 @@ impl PopupBuilder {
                     search_name
                 ),
             ),
+            PopupType::ReassignSavedSearches { names, owner_input } => (
+                "Reassign Saved Searches".to_string(),
+                format!(
+                    "Reassign {} saved search(es) to a new owner:\n\n  {}\n\nNew owner:\n\n> {}\n\nPress Enter to reassign, Esc to cancel",
+                    names.len(),
+                    names.join("\n  "),
+                    owner_input
+                ),
+            ),
             PopupType::DeleteLookupConfirm { lookup_name } => (
                 "Confirm Delete".to_string(),
                 format!(
@@ -360,6 +473,163 @@ impl PopupBuilder {
             PopupType::ConnectionDiagnostics { result } => {
                 self.build_connection_diagnostics_defaults(result)
             }
+            PopupType::UserActivity { report } => self.build_user_activity_defaults(report),
+            PopupType::JobsFilter {
+                owner_input,
+                app_input,
+                status,
+                selected_field,
+            } => self.build_jobs_filter_defaults(owner_input, app_input, *status, *selected_field),
+            PopupType::JobsPrune {
+                older_than_input,
+                owner_input,
+                status,
+                selected_field,
+            } => self.build_jobs_prune_defaults(
+                older_than_input,
+                owner_input,
+                *status,
+                *selected_field,
+            ),
+            PopupType::HealthThresholds {
+                license_warning_input,
+                license_critical_input,
+                kvstore_lag_input,
+                min_peers_input,
+                disk_warning_input,
+                disk_critical_input,
+                selected_field,
+            } => self.build_health_thresholds_defaults(
+                [
+                    license_warning_input,
+                    license_critical_input,
+                    kvstore_lag_input,
+                    min_peers_input,
+                    disk_warning_input,
+                    disk_critical_input,
+                ],
+                *selected_field,
+            ),
+            PopupType::AddSearchPeer {
+                uri_input,
+                username_input,
+                password_input,
+                selected_field,
+            } => (
+                "Add Search Peer".to_string(),
+                format!(
+                    "Add distributed search peer:\n\n{}URI: {}\n{}Remote Username: {}\n{}Remote Password: {}\n\nTab/↑↓ to navigate fields, Enter to add, Esc to cancel",
+                    Self::marker(*selected_field == SearchPeerField::Uri),
+                    if uri_input.is_empty() {
+                        "(required, e.g. https://idx1:8089)"
+                    } else {
+                        uri_input
+                    },
+                    Self::marker(*selected_field == SearchPeerField::RemoteUsername),
+                    if username_input.is_empty() {
+                        "(required)"
+                    } else {
+                        username_input
+                    },
+                    Self::marker(*selected_field == SearchPeerField::RemotePassword),
+                    Self::masked_state(password_input, "(required)", "(set)")
+                ),
+            ),
+            PopupType::CreateInput {
+                kind,
+                target_input,
+                index_input,
+                sourcetype_input,
+                whitelist_input,
+                blacklist_input,
+                selected_field,
+            } => self.build_create_input_defaults(
+                *kind,
+                [
+                    target_input,
+                    index_input,
+                    sourcetype_input,
+                    whitelist_input,
+                    blacklist_input,
+                ],
+                *selected_field,
+            ),
+            PopupType::ConfirmRemoveSearchPeer(name) => (
+                "Confirm Remove".to_string(),
+                format!(
+                    "Remove search peer '{}'?\n\nSearches will no longer be distributed to it.\n\nPress 'y' to confirm, 'n' or Esc to cancel",
+                    name
+                ),
+            ),
+            PopupType::ConfirmRollHotBuckets(name) => (
+                "Confirm Roll".to_string(),
+                format!(
+                    "Roll the hot buckets of index '{}' to warm?\n\nBuckets being written are closed; new hot buckets open on the next event.\n\nPress 'y' to confirm, 'n' or Esc to cancel",
+                    name
+                ),
+            ),
+            PopupType::ConfirmRebalanceIndex(index) => (
+                "Confirm Data Rebalance".to_string(),
+                format!(
+                    "Start a data rebalance for index '{}'?\n\nThe cluster manager moves bucket copies between peers until each holds an even share. This adds replication load while it runs.\n\nPress 'y' to confirm, 'n' or Esc to cancel",
+                    index
+                ),
+            ),
+            PopupType::ConfirmQuarantineSearchPeer { name, quarantine } => {
+                let verb = if *quarantine {
+                    "Quarantine"
+                } else {
+                    "Unquarantine"
+                };
+                (
+                    format!("Confirm {}", verb),
+                    format!("{} search peer '{}'? (y/n)", verb, name),
+                )
+            }
+            PopupType::ConfirmServerRestart { server_name, input } => (
+                "Confirm Restart".to_string(),
+                format!(
+                    "Restart Splunk on '{}'?\n\nSearches and ingestion on this server are interrupted until it comes back.\n\nType the server name to confirm:\n\n{}\n\nPress Enter to restart, Esc to cancel",
+                    server_name, input
+                ),
+            ),
+            PopupType::RestRequest { input } => (
+                "REST Request".to_string(),
+                format!(
+                    "Method, path, and form data (POST only, key=value&key=value):\n\n> {}\n\nExamples:\n  GET /services/server/info\n  GET /services/data/indexes?count=5\n  POST /services/saved/searches/my_search description=Nightly report\n\nPress Enter to send, Esc to cancel",
+                    input
+                ),
+            ),
+            PopupType::NamespaceSelector {
+                app_input,
+                owner_input,
+                sharing,
+                selected_field,
+            } => self.build_namespace_selector_defaults(
+                app_input,
+                owner_input,
+                sharing.as_deref(),
+                *selected_field,
+            ),
+            PopupType::EditAcl {
+                acl,
+                owner_input,
+                sharing,
+                read_input,
+                write_input,
+                selected_field,
+            } => self.build_acl_editor_defaults(
+                acl,
+                owner_input,
+                sharing,
+                read_input,
+                write_input,
+                *selected_field,
+            ),
+            PopupType::OperationProgress => (
+                "Operation in Progress".to_string(),
+                "Press c or Esc to cancel, b to run in the background".to_string(),
+            ),
         }
     }
 
@@ -372,7 +642,11 @@ impl PopupBuilder {
                 };
                 (
                     format!("Help - {:?}", app.current_screen),
-                    help::contextual_help_text(app.current_screen, input_mode),
+                    help::contextual_help_text_with_denied(
+                        app.current_screen,
+                        input_mode,
+                        &app.denied_keys(),
+                    ),
                 )
             }
             // All other popup types use the context-free defaults
@@ -421,6 +695,57 @@ impl PopupBuilder {
         (title, content)
     }
 
+    fn build_session_picker_defaults(
+        &self,
+        sessions: &[(String, String)],
+        selected_index: usize,
+        name_input: Option<&str>,
+    ) -> (String, String) {
+        let title = "Saved Sessions".to_string();
+        let mut content = String::new();
+        if sessions.is_empty() {
+            content.push_str("No saved sessions yet.\n");
+        } else {
+            content.push_str("Resume an investigation:\n\n");
+            for (i, (name, summary)) in sessions.iter().enumerate() {
+                content.push_str(&format!(
+                    "{}{}  {}\n",
+                    Self::marker(i == selected_index),
+                    name,
+                    summary
+                ));
+            }
+        }
+        match name_input {
+            Some(name) => content.push_str(&format!(
+                "\nSave current workspace as: {}_\n\nEnter to save, Esc to cancel",
+                name
+            )),
+            None => content.push_str(
+                "\n↑/↓ to navigate, Enter to restore, s to save current workspace, d to delete, Esc to close",
+            ),
+        }
+        (title, content)
+    }
+
+    fn build_troubleshooting_recipes_defaults(&self, selected_index: usize) -> (String, String) {
+        let title = "Troubleshooting Recipes".to_string();
+        let mut content = String::from("Run a curated search against Splunk's internal logs:\n\n");
+        for (i, recipe) in RECIPES.iter().enumerate() {
+            content.push_str(&format!(
+                "{}{}  {}\n",
+                Self::marker(i == selected_index),
+                recipe.name,
+                recipe.description
+            ));
+        }
+        if let Some(recipe) = RECIPES.get(selected_index) {
+            content.push_str(&format!("\nSPL: {}\n", recipe.query));
+        }
+        content.push_str("\n↑/↓ to navigate, Enter to run on the Search screen, Esc to close");
+        (title, content)
+    }
+
     fn marker(selected: bool) -> &'static str {
         if selected { "> " } else { "  " }
     }
@@ -573,6 +898,7 @@ impl PopupBuilder {
         search_name: &str,
         search_input: &str,
         description_input: &str,
+        [dispatch_earliest_input, dispatch_latest_input]: [&String; 2],
         disabled: bool,
         selected_field: SavedSearchField,
     ) -> (String, String) {
@@ -609,6 +935,16 @@ impl PopupBuilder {
                 )
             }
         ));
+        content.push_str(&format!(
+            "{}Dispatch Earliest: {}\n",
+            Self::marker(selected_field == SavedSearchField::DispatchEarliest),
+            Self::dispatch_time_state(dispatch_earliest_input)
+        ));
+        content.push_str(&format!(
+            "{}Dispatch Latest: {}\n",
+            Self::marker(selected_field == SavedSearchField::DispatchLatest),
+            Self::dispatch_time_state(dispatch_latest_input)
+        ));
         content.push_str(&format!(
             "{}Disabled: {}\n",
             Self::marker(selected_field == SavedSearchField::Disabled),
@@ -619,6 +955,18 @@ impl PopupBuilder {
         (title, content)
     }
 
+    /// Dispatch window input with the time it resolves to right now, e.g.
+    /// `-1d@d (→ 2024-01-14 00:00:00 -05:00)`.
+    fn dispatch_time_state(value: &str) -> String {
+        if value.is_empty() {
+            return "(unchanged)".to_string();
+        }
+        match parse_time_modifier(value) {
+            Ok(modifier) => format!("{} (→ {})", value, modifier.preview(&chrono::Local::now())),
+            Err(e) => format!("{} (invalid: {})", value, e),
+        }
+    }
+
     fn build_create_saved_search_defaults(
         &self,
         name_input: &str,
@@ -667,6 +1015,186 @@ impl PopupBuilder {
         (title, content)
     }
 
+    fn build_create_input_defaults(
+        &self,
+        kind: CreateInputKind,
+        [target, index, sourcetype, whitelist, blacklist]: [&String; 5],
+        selected_field: CreateInputField,
+    ) -> (String, String) {
+        let title = "Add Data Input".to_string();
+        let show = |value: &str, placeholder: &str| {
+            if value.is_empty() {
+                placeholder.to_string()
+            } else {
+                value.to_string()
+            }
+        };
+        let (target_label, target_hint) = match kind {
+            CreateInputKind::Monitor => ("Path", "(required, e.g. /var/log/app)"),
+            CreateInputKind::Tcp | CreateInputKind::Udp => ("Port", "(required, e.g. 514)"),
+        };
+
+        let mut content = String::from("Add a data input:\n\n");
+        content.push_str(&format!(
+            "{}Type: {}\n",
+            Self::marker(selected_field == CreateInputField::Kind),
+            kind.label()
+        ));
+        content.push_str(&format!(
+            "{}{}: {}\n",
+            Self::marker(selected_field == CreateInputField::Target),
+            target_label,
+            show(target, target_hint)
+        ));
+        content.push_str(&format!(
+            "{}Index: {}\n",
+            Self::marker(selected_field == CreateInputField::Index),
+            show(index, "(default)")
+        ));
+        content.push_str(&format!(
+            "{}Sourcetype: {}\n",
+            Self::marker(selected_field == CreateInputField::Sourcetype),
+            show(sourcetype, "(automatic)")
+        ));
+        if kind == CreateInputKind::Monitor {
+            content.push_str(&format!(
+                "{}Whitelist: {}\n",
+                Self::marker(selected_field == CreateInputField::Whitelist),
+                show(whitelist, "(all files)")
+            ));
+            content.push_str(&format!(
+                "{}Blacklist: {}\n",
+                Self::marker(selected_field == CreateInputField::Blacklist),
+                show(blacklist, "(none)")
+            ));
+        }
+
+        content.push_str(
+            "\nTab/↑↓ to navigate fields, Space/←→ to change type, Enter to add, Esc to cancel",
+        );
+        (title, content)
+    }
+
+    fn build_jobs_filter_defaults(
+        &self,
+        owner_input: &str,
+        app_input: &str,
+        status: Option<JobStatusFilter>,
+        selected_field: JobsFilterField,
+    ) -> (String, String) {
+        let title = "Filter Jobs".to_string();
+        let mut content = String::from("Filter jobs on the server (empty fields match all):\n\n");
+
+        content.push_str(&format!(
+            "{}Owner: {}\n",
+            Self::marker(selected_field == JobsFilterField::Owner),
+            if owner_input.is_empty() {
+                "(any)"
+            } else {
+                owner_input
+            }
+        ));
+        content.push_str(&format!(
+            "{}App: {}\n",
+            Self::marker(selected_field == JobsFilterField::App),
+            if app_input.is_empty() {
+                "(any)"
+            } else {
+                app_input
+            }
+        ));
+        content.push_str(&format!(
+            "{}Status: {}\n",
+            Self::marker(selected_field == JobsFilterField::Status),
+            status.map_or("(any)", JobStatusFilter::as_str)
+        ));
+
+        content.push_str(
+            "\nTab/↑↓ to navigate fields, Space/←→ to change status, Enter to apply, Esc to cancel",
+        );
+        (title, content)
+    }
+
+    fn build_jobs_prune_defaults(
+        &self,
+        older_than_input: &str,
+        owner_input: &str,
+        status: Option<JobStatusFilter>,
+        selected_field: JobsPruneField,
+    ) -> (String, String) {
+        let title = "Prune Old Jobs".to_string();
+        let mut content = String::from(
+            "Delete jobs created before the cutoff (matching jobs are listed for confirmation):\n\n",
+        );
+
+        content.push_str(&format!(
+            "{}Older than: {}\n",
+            Self::marker(selected_field == JobsPruneField::OlderThan),
+            if older_than_input.is_empty() {
+                "(required, e.g. 2d or 12h)"
+            } else {
+                older_than_input
+            }
+        ));
+        content.push_str(&format!(
+            "{}Owner: {}\n",
+            Self::marker(selected_field == JobsPruneField::Owner),
+            if owner_input.is_empty() {
+                "(any, \"me\" for yourself)"
+            } else {
+                owner_input
+            }
+        ));
+        content.push_str(&format!(
+            "{}Status: {}\n",
+            Self::marker(selected_field == JobsPruneField::Status),
+            status.map_or("(any)", JobStatusFilter::as_str)
+        ));
+
+        content.push_str(
+            "\nTab/↑↓ to navigate fields, Space/←→ to change status, Enter to find jobs, Esc to cancel",
+        );
+        (title, content)
+    }
+
+    /// `inputs` are in `HealthThresholdField` order.
+    fn build_health_thresholds_defaults(
+        &self,
+        inputs: [&str; 6],
+        selected_field: HealthThresholdField,
+    ) -> (String, String) {
+        let title = "Health Thresholds".to_string();
+        let mut content = String::from(
+            "Thresholds for profiles that do not set health_thresholds in the config file:\n\n",
+        );
+
+        let fields = [
+            (HealthThresholdField::LicenseWarning, "License warning (%)"),
+            (
+                HealthThresholdField::LicenseCritical,
+                "License critical (%)",
+            ),
+            (HealthThresholdField::KvstoreLag, "KVStore max lag (s)"),
+            (
+                HealthThresholdField::MinSearchablePeers,
+                "Min searchable peers",
+            ),
+            (HealthThresholdField::DiskWarning, "Disk warning (%)"),
+            (HealthThresholdField::DiskCritical, "Disk critical (%)"),
+        ];
+        for ((field, label), input) in fields.into_iter().zip(inputs) {
+            content.push_str(&format!(
+                "{}{}: {}\n",
+                Self::marker(selected_field == field),
+                label,
+                input
+            ));
+        }
+
+        content.push_str("\nTab/↑↓ to navigate fields, Enter to save, Esc to cancel");
+        (title, content)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn build_create_macro_defaults(
         &self,
@@ -796,6 +1324,171 @@ impl PopupBuilder {
         (title, content)
     }
 
+    fn build_install_app_defaults(
+        &self,
+        file_input: &str,
+        splunkbase_query: &str,
+        version_input: &str,
+        selected_field: InstallAppField,
+        splunkbase_results: &[SplunkbaseApp],
+        selected_result: Option<usize>,
+    ) -> (String, String) {
+        let mut content = format!(
+            "Install from a local file or Splunkbase:\n\n{}File: {}\n{}Splunkbase: {}\n{}Version: {}\n",
+            Self::marker(selected_field == InstallAppField::File),
+            if file_input.is_empty() {
+                "(path to .spl/.tgz package)"
+            } else {
+                file_input
+            },
+            Self::marker(selected_field == InstallAppField::Splunkbase),
+            if splunkbase_query.is_empty() {
+                "(search text or app ID)"
+            } else {
+                splunkbase_query
+            },
+            Self::marker(selected_field == InstallAppField::Version),
+            if version_input.is_empty() {
+                "(latest)"
+            } else {
+                version_input
+            },
+        );
+
+        if !splunkbase_results.is_empty() {
+            content.push_str("\nSplunkbase results:\n");
+            for (i, app) in splunkbase_results.iter().enumerate() {
+                content.push_str(&format!(
+                    "{}{:>6}  {} ({})\n",
+                    Self::marker(selected_result == Some(i)),
+                    app.uid,
+                    app.title,
+                    app.appid
+                ));
+            }
+        }
+
+        content.push_str(
+            "\nTab to switch fields, ↑↓ to pick a result, Enter to install or search, Esc to cancel",
+        );
+        ("Install App".to_string(), content)
+    }
+
+    fn build_modify_index_defaults(
+        &self,
+        index_name: &str,
+        max_data_size_mb: (Option<usize>, Option<usize>),
+        frozen_time_period_secs: (Option<usize>, Option<usize>),
+        selected_field: ModifyIndexField,
+        projection: Option<&FreezeProjection>,
+        acknowledged: bool,
+    ) -> (String, String) {
+        let value = |value: Option<usize>| {
+            value.map_or_else(|| "(unset)".to_string(), |value| value.to_string())
+        };
+        let projection_text = match projection {
+            Some(projection) => {
+                let mut text = format!(
+                    "Freeze projection (current size {} MB):\n  Older than frozen period: {} MB\n  Over size limit: {} MB\n  Would freeze immediately: {} MB ({:.1}%)",
+                    projection.current_size_mb,
+                    projection.frozen_by_age_mb,
+                    projection.frozen_by_size_mb,
+                    projection.frozen_mb(),
+                    projection.frozen_percent(),
+                );
+                if projection.frozen_percent() > FREEZE_ACK_THRESHOLD_PERCENT {
+                    text.push_str(&format!(
+                        "\n\nWARNING: more than {}% of the data would freeze.\n[{}] Press 'a' to acknowledge before applying",
+                        FREEZE_ACK_THRESHOLD_PERCENT,
+                        if acknowledged { "x" } else { " " },
+                    ));
+                }
+                text
+            }
+            None => "Freeze projection unavailable (index stats not loaded)".to_string(),
+        };
+        let content = format!(
+            "Modify index '{}':\n\n{}Max total data size (MB): {} (current: {})\n{}Frozen time period (secs): {} (current: {})\n\n{}\n\nTab to switch fields, digits to edit, Enter to apply changes, Esc to cancel",
+            index_name,
+            Self::marker(selected_field == ModifyIndexField::MaxDataSize),
+            value(max_data_size_mb.1),
+            value(max_data_size_mb.0),
+            Self::marker(selected_field == ModifyIndexField::FrozenTimePeriod),
+            value(frozen_time_period_secs.1),
+            value(frozen_time_period_secs.0),
+            projection_text,
+        );
+        ("Modify Index".to_string(), content)
+    }
+
+    fn build_namespace_selector_defaults(
+        &self,
+        app_input: &str,
+        owner_input: &str,
+        sharing: Option<&str>,
+        selected_field: NamespaceField,
+    ) -> (String, String) {
+        let content = format!(
+            "Scope knowledge objects to an owner/app namespace:\n\n{}App: {}\n{}Owner: {}\n{}Sharing: {}\n\nLeave App and Owner empty to see all. Private objects are only visible\nin their owner's namespace.\n\nTab to switch fields, ←→ to change sharing, Enter to apply, Esc to cancel",
+            Self::marker(selected_field == NamespaceField::App),
+            if app_input.is_empty() {
+                "(all apps)"
+            } else {
+                app_input
+            },
+            Self::marker(selected_field == NamespaceField::Owner),
+            if owner_input.is_empty() {
+                "(all owners)"
+            } else {
+                owner_input
+            },
+            Self::marker(selected_field == NamespaceField::Sharing),
+            sharing.unwrap_or("(any)"),
+        );
+        ("Namespace".to_string(), content)
+    }
+
+    fn build_acl_editor_defaults(
+        &self,
+        acl: &ObjectAcl,
+        owner_input: &str,
+        sharing: &str,
+        read_input: &str,
+        write_input: &str,
+        selected_field: AclField,
+    ) -> (String, String) {
+        let roles = |input: &str| {
+            if sharing == "user" {
+                "(owner only)".to_string()
+            } else if input.trim().is_empty() {
+                "(none)".to_string()
+            } else {
+                input.to_string()
+            }
+        };
+        let footer = if acl.can_change_perms {
+            "Roles are comma-separated (* = everyone) and only apply to app or global sharing.\n\nTab to switch fields, ←→ to change sharing, Enter to save, Esc to cancel"
+        } else {
+            "You do not have permission to change this object's permissions.\n\nEsc to close"
+        };
+        let content = format!(
+            "Permissions of {} '{}' in app '{}':\n\n{}Owner: {}\n{}Sharing: {}\n{}Read: {}\n{}Write: {}\n\n{}",
+            acl.kind,
+            acl.name,
+            acl.app,
+            Self::marker(selected_field == AclField::Owner),
+            owner_input,
+            Self::marker(selected_field == AclField::Sharing),
+            sharing,
+            Self::marker(selected_field == AclField::Read),
+            roles(read_input),
+            Self::marker(selected_field == AclField::Write),
+            roles(write_input),
+            footer,
+        );
+        ("Permissions".to_string(), content)
+    }
+
     fn build_command_palette_defaults(
         &self,
         input: &str,
@@ -903,6 +1596,33 @@ Configuration:
         (title, content)
     }
 
+    fn build_user_activity_defaults(&self, report: &UserActivityReport) -> (String, String) {
+        let title = format!("User Activity: {} (since {})", report.user, report.earliest);
+        if report.days.is_empty() {
+            return (
+                title,
+                "No audit events for this user in the window.\n\nPress Esc or q to close"
+                    .to_string(),
+            );
+        }
+
+        let mut content = format!(
+            "{:<10}  {:>6}  {:>8}  {:>8}  {:>11}  {:>5}\n",
+            "Day", "Logins", "Searches", "Modified", "Failed auth", "Other"
+        );
+        for day in report.days.iter().chain(std::iter::once(&report.totals())) {
+            content.push_str(&format!(
+                "{:<10}  {:>6}  {:>8}  {:>8}  {:>11}  {:>5}\n",
+                day.day, day.logins, day.searches, day.objects_modified, day.failed_auth, day.other
+            ));
+        }
+        if report.truncated {
+            content.push_str("\nEvent limit reached; older activity may be missing.\n");
+        }
+        content.push_str("\nPress Esc or q to close");
+        (title, content)
+    }
+
     fn build_connection_diagnostics_defaults(
         &self,
         result: &ConnectionDiagnosticsResult,
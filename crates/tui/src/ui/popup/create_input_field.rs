@@ -0,0 +1,91 @@
+//! Create input field selection for form navigation.
+//!
+//! This module provides the `CreateInputKind` selector and the `CreateInputField`
+//! enum with navigation methods that skip the monitor-only fields for network inputs.
+
+/// Kind of input the create input form adds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CreateInputKind {
+    /// File or directory monitor
+    Monitor,
+    /// Raw TCP port
+    Tcp,
+    /// UDP port
+    Udp,
+}
+
+impl CreateInputKind {
+    /// Get the next kind (cycles through all kinds).
+    pub fn next(self) -> Self {
+        match self {
+            CreateInputKind::Monitor => CreateInputKind::Tcp,
+            CreateInputKind::Tcp => CreateInputKind::Udp,
+            CreateInputKind::Udp => CreateInputKind::Monitor,
+        }
+    }
+
+    /// Get the previous kind (cycles through all kinds).
+    pub fn previous(self) -> Self {
+        match self {
+            CreateInputKind::Monitor => CreateInputKind::Udp,
+            CreateInputKind::Tcp => CreateInputKind::Monitor,
+            CreateInputKind::Udp => CreateInputKind::Tcp,
+        }
+    }
+
+    /// Display label for the kind selector.
+    pub fn label(self) -> &'static str {
+        match self {
+            CreateInputKind::Monitor => "monitor (file/directory)",
+            CreateInputKind::Tcp => "tcp",
+            CreateInputKind::Udp => "udp",
+        }
+    }
+}
+
+/// Field selection for create input form navigation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CreateInputField {
+    /// Input kind selector
+    Kind,
+    /// Monitored path or port
+    Target,
+    /// Destination index field
+    Index,
+    /// Sourcetype field
+    Sourcetype,
+    /// Path whitelist regex (monitor only)
+    Whitelist,
+    /// Path blacklist regex (monitor only)
+    Blacklist,
+}
+
+impl CreateInputField {
+    /// Get the next field in the form, skipping monitor-only fields for network inputs.
+    pub fn next(self, kind: CreateInputKind) -> Self {
+        let monitor = kind == CreateInputKind::Monitor;
+        match self {
+            CreateInputField::Kind => CreateInputField::Target,
+            CreateInputField::Target => CreateInputField::Index,
+            CreateInputField::Index => CreateInputField::Sourcetype,
+            CreateInputField::Sourcetype if monitor => CreateInputField::Whitelist,
+            CreateInputField::Sourcetype => CreateInputField::Kind,
+            CreateInputField::Whitelist => CreateInputField::Blacklist,
+            CreateInputField::Blacklist => CreateInputField::Kind,
+        }
+    }
+
+    /// Get the previous field in the form, skipping monitor-only fields for network inputs.
+    pub fn previous(self, kind: CreateInputKind) -> Self {
+        let monitor = kind == CreateInputKind::Monitor;
+        match self {
+            CreateInputField::Kind if monitor => CreateInputField::Blacklist,
+            CreateInputField::Kind => CreateInputField::Sourcetype,
+            CreateInputField::Target => CreateInputField::Kind,
+            CreateInputField::Index => CreateInputField::Target,
+            CreateInputField::Sourcetype => CreateInputField::Index,
+            CreateInputField::Whitelist => CreateInputField::Sourcetype,
+            CreateInputField::Blacklist => CreateInputField::Whitelist,
+        }
+    }
+}
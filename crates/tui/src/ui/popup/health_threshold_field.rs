@@ -0,0 +1,47 @@
+//! Health threshold field selection for form navigation.
+//!
+//! This module provides the `HealthThresholdField` enum and its navigation methods
+//! for cycling through the Settings health thresholds form fields.
+
+/// Field selection for health thresholds form navigation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthThresholdField {
+    /// License usage warning percentage
+    LicenseWarning,
+    /// License usage critical percentage
+    LicenseCritical,
+    /// Maximum KVStore replication lag in seconds
+    KvstoreLag,
+    /// Minimum searchable cluster peers
+    MinSearchablePeers,
+    /// Disk usage warning percentage
+    DiskWarning,
+    /// Disk usage critical percentage
+    DiskCritical,
+}
+
+impl HealthThresholdField {
+    /// Get the next field in the form (cycles through all fields).
+    pub fn next(self) -> Self {
+        match self {
+            HealthThresholdField::LicenseWarning => HealthThresholdField::LicenseCritical,
+            HealthThresholdField::LicenseCritical => HealthThresholdField::KvstoreLag,
+            HealthThresholdField::KvstoreLag => HealthThresholdField::MinSearchablePeers,
+            HealthThresholdField::MinSearchablePeers => HealthThresholdField::DiskWarning,
+            HealthThresholdField::DiskWarning => HealthThresholdField::DiskCritical,
+            HealthThresholdField::DiskCritical => HealthThresholdField::LicenseWarning,
+        }
+    }
+
+    /// Get the previous field in the form (cycles through all fields).
+    pub fn previous(self) -> Self {
+        match self {
+            HealthThresholdField::LicenseWarning => HealthThresholdField::DiskCritical,
+            HealthThresholdField::LicenseCritical => HealthThresholdField::LicenseWarning,
+            HealthThresholdField::KvstoreLag => HealthThresholdField::LicenseCritical,
+            HealthThresholdField::MinSearchablePeers => HealthThresholdField::KvstoreLag,
+            HealthThresholdField::DiskWarning => HealthThresholdField::MinSearchablePeers,
+            HealthThresholdField::DiskCritical => HealthThresholdField::DiskWarning,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Install app field selection for form navigation.
+//!
+//! This module provides the `InstallAppField` enum and its navigation methods
+//! for cycling through the install app dialog fields.
+
+/// Field selection for install app dialog navigation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstallAppField {
+    /// Local package file path field
+    File,
+    /// Splunkbase search query (or app ID) field
+    Splunkbase,
+    /// Pinned Splunkbase release version field
+    Version,
+}
+
+impl InstallAppField {
+    /// Get the next field in the form (cycles through all fields).
+    pub fn next(self) -> Self {
+        match self {
+            InstallAppField::File => InstallAppField::Splunkbase,
+            InstallAppField::Splunkbase => InstallAppField::Version,
+            InstallAppField::Version => InstallAppField::File,
+        }
+    }
+
+    /// Get the previous field in the form (cycles through all fields).
+    pub fn previous(self) -> Self {
+        match self {
+            InstallAppField::File => InstallAppField::Version,
+            InstallAppField::Splunkbase => InstallAppField::File,
+            InstallAppField::Version => InstallAppField::Splunkbase,
+        }
+    }
+}
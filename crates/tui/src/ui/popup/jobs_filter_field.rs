@@ -0,0 +1,35 @@
+//! Jobs filter field selection for form navigation.
+//!
+//! This module provides the `JobsFilterField` enum and its navigation methods
+//! for cycling through the server-side jobs filter form fields.
+
+/// Field selection for jobs filter form navigation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobsFilterField {
+    /// Owner (username) field
+    Owner,
+    /// App context field
+    App,
+    /// Dispatch status selector
+    Status,
+}
+
+impl JobsFilterField {
+    /// Get the next field in the form (cycles through all fields).
+    pub fn next(self) -> Self {
+        match self {
+            JobsFilterField::Owner => JobsFilterField::App,
+            JobsFilterField::App => JobsFilterField::Status,
+            JobsFilterField::Status => JobsFilterField::Owner,
+        }
+    }
+
+    /// Get the previous field in the form (cycles through all fields).
+    pub fn previous(self) -> Self {
+        match self {
+            JobsFilterField::Owner => JobsFilterField::Status,
+            JobsFilterField::App => JobsFilterField::Owner,
+            JobsFilterField::Status => JobsFilterField::App,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Jobs prune field selection for form navigation.
+//!
+//! This module provides the `JobsPruneField` enum and its navigation methods
+//! for cycling through the "prune old jobs" form fields.
+
+/// Field selection for jobs prune form navigation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobsPruneField {
+    /// Minimum job age field
+    OlderThan,
+    /// Owner (username) field
+    Owner,
+    /// Dispatch status selector
+    Status,
+}
+
+impl JobsPruneField {
+    /// Get the next field in the form (cycles through all fields).
+    pub fn next(self) -> Self {
+        match self {
+            JobsPruneField::OlderThan => JobsPruneField::Owner,
+            JobsPruneField::Owner => JobsPruneField::Status,
+            JobsPruneField::Status => JobsPruneField::OlderThan,
+        }
+    }
+
+    /// Get the previous field in the form (cycles through all fields).
+    pub fn previous(self) -> Self {
+        match self {
+            JobsPruneField::OlderThan => JobsPruneField::Status,
+            JobsPruneField::Owner => JobsPruneField::OlderThan,
+            JobsPruneField::Status => JobsPruneField::Owner,
+        }
+    }
+}
@@ -4,11 +4,20 @@
 //! customizable titles, content, and types. Popups are rendered as
 //! centered modal dialogs overlaid on the main UI.
 
+mod acl_field;
 mod builder;
+mod create_input_field;
+mod health_threshold_field;
+mod install_app_field;
+mod jobs_filter_field;
+mod jobs_prune_field;
 mod macro_field;
+mod modify_index_field;
+mod namespace_field;
 mod profile_field;
 mod render;
 mod saved_search_field;
+mod search_peer_field;
 mod types;
 
 /// Default popup dimensions as percentages of screen size.
@@ -16,9 +25,18 @@ pub const POPUP_WIDTH_PERCENT: u16 = 60;
 pub const POPUP_HEIGHT_PERCENT: u16 = 50;
 
 // Re-export public types for backward compatibility
+pub use acl_field::AclField;
 pub use builder::{Popup, PopupBuilder};
+pub use create_input_field::{CreateInputField, CreateInputKind};
+pub use health_threshold_field::HealthThresholdField;
+pub use install_app_field::InstallAppField;
+pub use jobs_filter_field::JobsFilterField;
+pub use jobs_prune_field::JobsPruneField;
 pub use macro_field::MacroField;
+pub use modify_index_field::{FREEZE_ACK_THRESHOLD_PERCENT, ModifyIndexField};
+pub use namespace_field::NamespaceField;
 pub use profile_field::ProfileField;
-pub use render::render_popup;
+pub use render::{PopupButton, confirmation_buttons, render_popup};
 pub use saved_search_field::SavedSearchField;
+pub use search_peer_field::SearchPeerField;
 pub use types::PopupType;
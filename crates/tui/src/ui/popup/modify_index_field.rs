@@ -0,0 +1,31 @@
+//! Modify index retention field selection for form navigation.
+//!
+//! This module provides the `ModifyIndexField` enum and its navigation methods
+//! for cycling through the editable retention fields of the modify index dialog.
+
+/// Projected freeze share (percent of current data) that requires acknowledgment.
+pub const FREEZE_ACK_THRESHOLD_PERCENT: f64 = 10.0;
+
+/// Field selection for modify index navigation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModifyIndexField {
+    /// Maximum total data size (maxTotalDataSizeMB) field
+    MaxDataSize,
+    /// Frozen time period (frozenTimePeriodInSecs) field
+    FrozenTimePeriod,
+}
+
+impl ModifyIndexField {
+    /// Get the next field in the form (cycles through all fields).
+    pub fn next(self) -> Self {
+        match self {
+            ModifyIndexField::MaxDataSize => ModifyIndexField::FrozenTimePeriod,
+            ModifyIndexField::FrozenTimePeriod => ModifyIndexField::MaxDataSize,
+        }
+    }
+
+    /// Get the previous field in the form (cycles through all fields).
+    pub fn previous(self) -> Self {
+        self.next()
+    }
+}
@@ -0,0 +1,35 @@
+//! Namespace selector field selection for form navigation.
+//!
+//! This module provides the `NamespaceField` enum and its navigation methods
+//! for cycling through the namespace selector fields.
+
+/// Field selection for namespace selector navigation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NamespaceField {
+    /// App context field
+    App,
+    /// Owner (user context) field
+    Owner,
+    /// Sharing level filter field
+    Sharing,
+}
+
+impl NamespaceField {
+    /// Get the next field in the form (cycles through all fields).
+    pub fn next(self) -> Self {
+        match self {
+            NamespaceField::App => NamespaceField::Owner,
+            NamespaceField::Owner => NamespaceField::Sharing,
+            NamespaceField::Sharing => NamespaceField::App,
+        }
+    }
+
+    /// Get the previous field in the form (cycles through all fields).
+    pub fn previous(self) -> Self {
+        match self {
+            NamespaceField::App => NamespaceField::Sharing,
+            NamespaceField::Owner => NamespaceField::App,
+            NamespaceField::Sharing => NamespaceField::Owner,
+        }
+    }
+}
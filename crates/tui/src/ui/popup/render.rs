@@ -3,6 +3,7 @@
 //! Responsibilities:
 //! - Render modal popup dialogs with type-specific styling.
 //! - Apply popup-type sizing so dense dialogs remain readable.
+//! - Draw the clickable Yes/No buttons on confirmation popups.
 //!
 //! Does NOT handle:
 //! - Creating popup content (handled by popup builder).
@@ -11,12 +12,13 @@
 //! Invariants:
 //! - Confirmation popups keep default sizing for mouse-hit consistency.
 //! - Scroll state for help/tutorial/undo popups is preserved across renders.
+//! - Operation progress content is rebuilt from app state on every render.
 
 use crate::theme::Theme;
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
-    style::Style,
+    style::{Modifier, Style},
     widgets::{
         Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap,
     },
@@ -33,7 +35,9 @@ use crate::ui::popup::{POPUP_HEIGHT_PERCENT, POPUP_WIDTH_PERCENT, Popup, PopupTy
 /// * `popup` - The popup to render
 /// * `theme` - The color theme to use
 /// * `app` - The app state (for accessing scroll offsets)
-pub fn render_popup(f: &mut Frame, popup: &Popup, theme: &Theme, app: &App) {
+///
+/// Returns the area the popup was drawn in, for mouse hit-testing.
+pub fn render_popup(f: &mut Frame, popup: &Popup, theme: &Theme, app: &App) -> Rect {
     let size = f.area();
     let (popup_width_pct, popup_height_pct) = popup_size(&popup.kind);
     let popup_area = centered_rect(popup_width_pct, popup_height_pct, size);
@@ -46,6 +50,21 @@ pub fn render_popup(f: &mut Frame, popup: &Popup, theme: &Theme, app: &App) {
         | PopupType::ExportSearch
         | PopupType::ErrorDetails
         | PopupType::IndexDetails
+        | PopupType::DashboardSource
+        | PopupType::MacroExpansion
+        | PopupType::ResultDetail
+        | PopupType::TstatsBuilder
+        | PopupType::TemplateParameters
+        | PopupType::OnboardingWizard
+        | PopupType::ExtractionTester
+        | PopupType::EventSample
+        | PopupType::TimeRangePicker
+        | PopupType::TopValues
+        | PopupType::LookupEditor
+        | PopupType::ServerMessages
+        | PopupType::BackgroundJobs
+        | PopupType::AppDetails
+        | PopupType::OrphanedObjects { .. }
         | PopupType::ProfileSelector { .. }
         | PopupType::CreateIndex { .. }
         | PopupType::ModifyIndex { .. }
@@ -54,6 +73,10 @@ pub fn render_popup(f: &mut Frame, popup: &Popup, theme: &Theme, app: &App) {
         | PopupType::CreateRole { .. }
         | PopupType::ModifyRole { .. }
         | PopupType::InstallAppDialog { .. }
+        | PopupType::NamespaceSelector { .. }
+        | PopupType::SessionPicker { .. }
+        | PopupType::TroubleshootingRecipes { .. }
+        | PopupType::EditAcl { .. }
         | PopupType::CreateProfile { .. }
         | PopupType::EditProfile { .. }
         | PopupType::EditSavedSearch { .. }
@@ -63,7 +86,16 @@ pub fn render_popup(f: &mut Frame, popup: &Popup, theme: &Theme, app: &App) {
         | PopupType::TutorialWizard { .. }
         | PopupType::CommandPalette { .. }
         | PopupType::UndoHistory { .. }
-        | PopupType::ConnectionDiagnostics { .. } => theme.border,
+        | PopupType::ConnectionDiagnostics { .. }
+        | PopupType::UserActivity { .. }
+        | PopupType::OperationProgress
+        | PopupType::JobsFilter { .. }
+        | PopupType::JobsPrune { .. }
+        | PopupType::HealthThresholds { .. }
+        | PopupType::AddSearchPeer { .. }
+        | PopupType::CreateInput { .. }
+        | PopupType::RestRequest { .. }
+        | PopupType::ReassignSavedSearches { .. } => theme.border,
         PopupType::ConfirmCancel(_)
         | PopupType::ConfirmDelete(_)
         | PopupType::ConfirmCancelBatch(_)
@@ -77,6 +109,11 @@ pub fn render_popup(f: &mut Frame, popup: &Popup, theme: &Theme, app: &App) {
         | PopupType::DeleteLookupConfirm { .. }
         | PopupType::ConfirmRemoveApp(_)
         | PopupType::DeleteProfileConfirm { .. }
+        | PopupType::ConfirmRemoveSearchPeer(_)
+        | PopupType::ConfirmQuarantineSearchPeer { .. }
+        | PopupType::ConfirmRollHotBuckets(_)
+        | PopupType::ConfirmRebalanceIndex(_)
+        | PopupType::ConfirmServerRestart { .. }
         | PopupType::AuthRecovery { .. } => theme.error,
     };
 
@@ -86,6 +123,21 @@ pub fn render_popup(f: &mut Frame, popup: &Popup, theme: &Theme, app: &App) {
         | PopupType::ExportSearch
         | PopupType::ErrorDetails
         | PopupType::IndexDetails
+        | PopupType::DashboardSource
+        | PopupType::MacroExpansion
+        | PopupType::ResultDetail
+        | PopupType::TstatsBuilder
+        | PopupType::TemplateParameters
+        | PopupType::OnboardingWizard
+        | PopupType::ExtractionTester
+        | PopupType::EventSample
+        | PopupType::TimeRangePicker
+        | PopupType::TopValues
+        | PopupType::LookupEditor
+        | PopupType::ServerMessages
+        | PopupType::BackgroundJobs
+        | PopupType::AppDetails
+        | PopupType::OrphanedObjects { .. }
         | PopupType::ProfileSelector { .. }
         | PopupType::CreateIndex { .. }
         | PopupType::ModifyIndex { .. }
@@ -97,6 +149,10 @@ pub fn render_popup(f: &mut Frame, popup: &Popup, theme: &Theme, app: &App) {
         | PopupType::ModifyRole { .. }
         | PopupType::DeleteRoleConfirm { .. }
         | PopupType::InstallAppDialog { .. }
+        | PopupType::NamespaceSelector { .. }
+        | PopupType::SessionPicker { .. }
+        | PopupType::TroubleshootingRecipes { .. }
+        | PopupType::EditAcl { .. }
         | PopupType::CreateProfile { .. }
         | PopupType::EditProfile { .. }
         | PopupType::DeleteProfileConfirm { .. }
@@ -108,7 +164,18 @@ pub fn render_popup(f: &mut Frame, popup: &Popup, theme: &Theme, app: &App) {
         | PopupType::CommandPalette { .. }
         | PopupType::UndoHistory { .. }
         | PopupType::AuthRecovery { .. }
-        | PopupType::ConnectionDiagnostics { .. } => Wrap { trim: false },
+        | PopupType::ConnectionDiagnostics { .. }
+        | PopupType::UserActivity { .. }
+        | PopupType::OperationProgress
+        | PopupType::JobsFilter { .. }
+        | PopupType::JobsPrune { .. }
+        | PopupType::HealthThresholds { .. }
+        | PopupType::AddSearchPeer { .. }
+        | PopupType::CreateInput { .. }
+        | PopupType::ConfirmRemoveSearchPeer(_)
+        | PopupType::ConfirmServerRestart { .. }
+        | PopupType::RestRequest { .. }
+        | PopupType::ReassignSavedSearches { .. } => Wrap { trim: false },
         PopupType::ConfirmCancel(_)
         | PopupType::ConfirmDelete(_)
         | PopupType::ConfirmCancelBatch(_)
@@ -117,7 +184,10 @@ pub fn render_popup(f: &mut Frame, popup: &Popup, theme: &Theme, app: &App) {
         | PopupType::ConfirmDisableApp(_)
         | PopupType::ConfirmRemoveApp(_)
         | PopupType::DeleteSavedSearchConfirm { .. }
-        | PopupType::DeleteLookupConfirm { .. } => Wrap { trim: true },
+        | PopupType::DeleteLookupConfirm { .. }
+        | PopupType::ConfirmQuarantineSearchPeer { .. }
+        | PopupType::ConfirmRollHotBuckets(_)
+        | PopupType::ConfirmRebalanceIndex(_) => Wrap { trim: true },
     };
 
     // Determine alignment based on popup type
@@ -125,7 +195,8 @@ pub fn render_popup(f: &mut Frame, popup: &Popup, theme: &Theme, app: &App) {
     let alignment = match &popup.kind {
         PopupType::Help
         | PopupType::AuthRecovery { .. }
-        | PopupType::ConnectionDiagnostics { .. } => Alignment::Left,
+        | PopupType::ConnectionDiagnostics { .. }
+        | PopupType::UserActivity { .. } => Alignment::Left,
         _ => Alignment::Center,
     };
 
@@ -265,6 +336,24 @@ pub fn render_popup(f: &mut Frame, popup: &Popup, theme: &Theme, app: &App) {
                 &mut scrollbar_state,
             );
         }
+    } else if popup.kind == PopupType::OperationProgress {
+        // Progress is rendered live from app state so elapsed time and ETA tick
+        let content = app
+            .operation_progress
+            .as_ref()
+            .map(|progress| progress.render_text())
+            .unwrap_or_else(|| popup.content.clone());
+
+        let p = Paragraph::new(content)
+            .block(
+                Block::default()
+                    .title(popup.title.as_str())
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(border_color)),
+            )
+            .alignment(Alignment::Left)
+            .wrap(wrap_mode);
+        f.render_widget(p, popup_area);
     } else {
         let p = Paragraph::new(popup.content.as_str())
             .block(
@@ -277,6 +366,66 @@ pub fn render_popup(f: &mut Frame, popup: &Popup, theme: &Theme, app: &App) {
             .wrap(wrap_mode);
         f.render_widget(p, popup_area);
     }
+
+    if popup.kind.is_confirmation() {
+        for (area, button) in confirmation_buttons(popup_area) {
+            let style = match button {
+                PopupButton::Confirm => Style::default()
+                    .fg(border_color)
+                    .add_modifier(Modifier::BOLD),
+                PopupButton::Cancel => Style::default().fg(theme.text),
+            };
+            f.render_widget(Paragraph::new(button.label()).style(style), area);
+        }
+    }
+
+    popup_area
+}
+
+/// A clickable button on a confirmation popup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PopupButton {
+    /// Confirm the action (same as `y`)
+    Confirm,
+    /// Dismiss the popup (same as `n`)
+    Cancel,
+}
+
+impl PopupButton {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Confirm => "[ Yes ]",
+            Self::Cancel => "[ No ]",
+        }
+    }
+}
+
+/// Button areas on the bottom border of a confirmation popup drawn in `popup_area`.
+///
+/// Empty when the popup is too small to hold both buttons.
+pub fn confirmation_buttons(popup_area: Rect) -> Vec<(Rect, PopupButton)> {
+    const GAP: u16 = 2;
+    let buttons = [PopupButton::Confirm, PopupButton::Cancel];
+    let total = buttons
+        .iter()
+        .map(|button| button.label().len() as u16)
+        .sum::<u16>()
+        + GAP;
+    if popup_area.height < 3 || total + 2 > popup_area.width {
+        return Vec::new();
+    }
+
+    let y = popup_area.bottom() - 1;
+    let mut x = popup_area.x + (popup_area.width - total) / 2;
+    buttons
+        .into_iter()
+        .map(|button| {
+            let width = button.label().len() as u16;
+            let area = Rect::new(x, y, width, 1);
+            x += width + GAP;
+            (area, button)
+        })
+        .collect()
 }
 
 fn popup_size(kind: &PopupType) -> (u16, u16) {
@@ -286,8 +435,12 @@ fn popup_size(kind: &PopupType) -> (u16, u16) {
         PopupType::TutorialWizard { .. } => (72, 72),
         PopupType::CreateProfile { .. }
         | PopupType::EditProfile { .. }
-        | PopupType::ProfileSelector { .. } => (72, 62),
+        | PopupType::ProfileSelector { .. }
+        | PopupType::SessionPicker { .. }
+        | PopupType::TroubleshootingRecipes { .. }
+        | PopupType::InstallAppDialog { .. } => (72, 62),
         PopupType::ConnectionDiagnostics { .. } => (70, 58),
+        PopupType::UserActivity { .. } => (70, 60),
         _ => (POPUP_WIDTH_PERCENT, POPUP_HEIGHT_PERCENT),
     }
 }
@@ -12,6 +12,10 @@ pub enum SavedSearchField {
     Search,
     /// Description field
     Description,
+    /// Earliest time of the scheduled dispatch window (edit form only)
+    DispatchEarliest,
+    /// Latest time of the scheduled dispatch window (edit form only)
+    DispatchLatest,
     /// Disabled toggle field
     Disabled,
 }
@@ -22,7 +26,9 @@ impl SavedSearchField {
         match self {
             SavedSearchField::Name => SavedSearchField::Search,
             SavedSearchField::Search => SavedSearchField::Description,
-            SavedSearchField::Description => SavedSearchField::Disabled,
+            SavedSearchField::Description => SavedSearchField::DispatchEarliest,
+            SavedSearchField::DispatchEarliest => SavedSearchField::DispatchLatest,
+            SavedSearchField::DispatchLatest => SavedSearchField::Disabled,
             SavedSearchField::Disabled => SavedSearchField::Name,
         }
     }
@@ -33,7 +39,17 @@ impl SavedSearchField {
             SavedSearchField::Name => SavedSearchField::Disabled,
             SavedSearchField::Search => SavedSearchField::Name,
             SavedSearchField::Description => SavedSearchField::Search,
-            SavedSearchField::Disabled => SavedSearchField::Description,
+            SavedSearchField::DispatchEarliest => SavedSearchField::Description,
+            SavedSearchField::DispatchLatest => SavedSearchField::DispatchEarliest,
+            SavedSearchField::Disabled => SavedSearchField::DispatchLatest,
         }
     }
+
+    /// Whether the field is part of the scheduled dispatch window.
+    pub fn is_dispatch_time(self) -> bool {
+        matches!(
+            self,
+            SavedSearchField::DispatchEarliest | SavedSearchField::DispatchLatest
+        )
+    }
 }
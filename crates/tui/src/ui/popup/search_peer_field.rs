@@ -0,0 +1,35 @@
+//! Search peer field selection for form navigation.
+//!
+//! This module provides the `SearchPeerField` enum and its navigation methods
+//! for cycling through the add search peer form fields.
+
+/// Field selection for add search peer form navigation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchPeerField {
+    /// Peer management URI field
+    Uri,
+    /// Remote admin username field
+    RemoteUsername,
+    /// Remote admin password field
+    RemotePassword,
+}
+
+impl SearchPeerField {
+    /// Get the next field in the form (cycles through all fields).
+    pub fn next(self) -> Self {
+        match self {
+            SearchPeerField::Uri => SearchPeerField::RemoteUsername,
+            SearchPeerField::RemoteUsername => SearchPeerField::RemotePassword,
+            SearchPeerField::RemotePassword => SearchPeerField::Uri,
+        }
+    }
+
+    /// Get the previous field in the form (cycles through all fields).
+    pub fn previous(self) -> Self {
+        match self {
+            SearchPeerField::Uri => SearchPeerField::RemotePassword,
+            SearchPeerField::RemoteUsername => SearchPeerField::Uri,
+            SearchPeerField::RemotePassword => SearchPeerField::RemoteUsername,
+        }
+    }
+}
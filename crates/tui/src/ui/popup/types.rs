@@ -6,7 +6,15 @@
 use crate::action::variants::ConnectionDiagnosticsResult;
 use crate::error_details::AuthRecoveryKind;
 use crate::onboarding::TutorialState;
-use crate::ui::popup::{MacroField, ProfileField, SavedSearchField};
+use crate::ui::popup::{
+    AclField, CreateInputField, CreateInputKind, HealthThresholdField, InstallAppField,
+    JobsFilterField, JobsPruneField, MacroField, ModifyIndexField, NamespaceField, ProfileField,
+    SavedSearchField, SearchPeerField,
+};
+use splunk_client::JobStatusFilter;
+use splunk_client::models::{FreezeProjection, ObjectAcl};
+use splunk_client::workflows::splunkbase::SplunkbaseApp;
+use splunk_client::workflows::user_activity::UserActivityReport;
 
 /// The type/kind of popup dialog.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -27,6 +35,34 @@ pub enum PopupType {
     ErrorDetails,
     /// Show index details with full metadata
     IndexDetails,
+    /// Show the selected dashboard's Simple XML or Dashboard Studio source
+    DashboardSource,
+    /// Show the search query with its backtick macros expanded
+    MacroExpansion,
+    /// Show the selected search result, rendered for its sourcetype
+    ResultDetail,
+    /// Guided tstats query builder for the selected data model
+    TstatsBuilder,
+    /// Parameter form for a search template with a parameter schema
+    TemplateParameters,
+    /// Data onboarding wizard: index, sourcetype, and HEC token in one flow
+    OnboardingWizard,
+    /// Regex field extraction tester for pasted sample events
+    ExtractionTester,
+    /// Raw events sampled from the selected index
+    EventSample,
+    /// Time range picker with presets and a custom absolute range
+    TimeRangePicker,
+    /// Top values of a field of the loaded search results
+    TopValues,
+    /// Table editor for the selected lookup's contents
+    LookupEditor,
+    /// Server bulletin board messages with a dismiss action
+    ServerMessages,
+    /// Running and recently finished searches and operations (state in `App::background_jobs`)
+    BackgroundJobs,
+    /// Knowledge objects shipped by the selected app, one tab per object kind
+    AppDetails,
     /// Confirm enable app (holds app name)
     ConfirmEnableApp(String),
     /// Confirm disable app (holds app name)
@@ -69,6 +105,12 @@ pub enum PopupType {
         new_cold_db_path: Option<String>,
         new_thawed_path: Option<String>,
         new_cold_to_frozen_dir: Option<String>,
+        /// Retention field being edited
+        selected_field: ModifyIndexField,
+        /// Estimated data frozen immediately by the new retention settings
+        freeze_projection: Option<FreezeProjection>,
+        /// Whether the user acknowledged a projected freeze above the threshold
+        freeze_acknowledged: bool,
     },
     /// Index deletion confirmation
     DeleteIndexConfirm { index_name: String },
@@ -123,8 +165,15 @@ pub enum PopupType {
     DeleteRoleConfirm { role_name: String },
     /// Confirm remove app (holds app name)
     ConfirmRemoveApp(String),
-    /// App installation file path input dialog
-    InstallAppDialog { file_input: String },
+    /// App installation dialog (local file path or Splunkbase search)
+    InstallAppDialog {
+        file_input: String,
+        splunkbase_query: String,
+        version_input: String,
+        selected_field: InstallAppField,
+        splunkbase_results: Vec<SplunkbaseApp>,
+        selected_result: Option<usize>,
+    },
     /// Profile creation dialog
     CreateProfile {
         name_input: String,
@@ -164,6 +213,10 @@ pub enum PopupType {
         search_input: String,
         /// Current description input
         description_input: String,
+        /// Earliest time of the scheduled dispatch window
+        dispatch_earliest_input: String,
+        /// Latest time of the scheduled dispatch window
+        dispatch_latest_input: String,
         /// Disabled toggle state
         disabled: bool,
         /// Currently selected field for navigation
@@ -184,6 +237,11 @@ pub enum PopupType {
     },
     /// Delete saved search confirmation
     DeleteSavedSearchConfirm { search_name: String },
+    /// New owner input for reassigning the named saved searches
+    ReassignSavedSearches {
+        names: Vec<String>,
+        owner_input: String,
+    },
     /// Delete lookup table confirmation
     DeleteLookupConfirm { lookup_name: String },
     /// Create macro dialog
@@ -249,6 +307,137 @@ pub enum PopupType {
         /// The diagnostics result to display
         result: ConnectionDiagnosticsResult,
     },
+    /// Per-day audit activity of one user
+    UserActivity {
+        /// The report to display
+        report: UserActivityReport,
+    },
+    /// Progress of a long-running operation (content rendered from `App::operation_progress`)
+    OperationProgress,
+    /// Server-side jobs filter dialog
+    JobsFilter {
+        /// Owner (username) input
+        owner_input: String,
+        /// App context input
+        app_input: String,
+        /// Dispatch status (None matches every status)
+        status: Option<JobStatusFilter>,
+        /// Currently selected field for navigation
+        selected_field: JobsFilterField,
+    },
+    /// "Prune old jobs" dialog (bulk delete of jobs older than a cutoff)
+    JobsPrune {
+        /// Minimum job age input (e.g. `2d`)
+        older_than_input: String,
+        /// Owner (username) input, `me` for the current user
+        owner_input: String,
+        /// Dispatch status (None matches every status)
+        status: Option<JobStatusFilter>,
+        /// Currently selected field for navigation
+        selected_field: JobsPruneField,
+    },
+    /// Settings editor for the health check thresholds
+    HealthThresholds {
+        /// License usage warning percentage input
+        license_warning_input: String,
+        /// License usage critical percentage input
+        license_critical_input: String,
+        /// Maximum KVStore replication lag (seconds) input
+        kvstore_lag_input: String,
+        /// Minimum searchable cluster peers input
+        min_peers_input: String,
+        /// Disk usage warning percentage input
+        disk_warning_input: String,
+        /// Disk usage critical percentage input
+        disk_critical_input: String,
+        /// Currently selected field for navigation
+        selected_field: HealthThresholdField,
+    },
+    /// Add distributed search peer dialog
+    AddSearchPeer {
+        /// Peer management URI input
+        uri_input: String,
+        /// Remote admin username input
+        username_input: String,
+        /// Remote admin password input (never displayed)
+        password_input: String,
+        /// Currently selected field for navigation
+        selected_field: SearchPeerField,
+    },
+    /// Create monitor/TCP/UDP input dialog
+    CreateInput {
+        /// Kind of input to create
+        kind: CreateInputKind,
+        /// Monitored path (monitor) or port (tcp/udp) input
+        target_input: String,
+        /// Destination index input
+        index_input: String,
+        /// Sourcetype input
+        sourcetype_input: String,
+        /// Path whitelist regex input (monitor only)
+        whitelist_input: String,
+        /// Path blacklist regex input (monitor only)
+        blacklist_input: String,
+        /// Currently selected field for navigation
+        selected_field: CreateInputField,
+    },
+    /// Confirm remove search peer (holds peer name)
+    ConfirmRemoveSearchPeer(String),
+    /// Confirm quarantine (`true`) or unquarantine (`false`) of a search peer
+    ConfirmQuarantineSearchPeer { name: String, quarantine: bool },
+    /// Confirm rolling an index's hot buckets (holds index name)
+    ConfirmRollHotBuckets(String),
+    /// Confirm starting a data rebalance for an index (holds index name)
+    ConfirmRebalanceIndex(String),
+    /// Typed confirmation before restarting splunkd; `input` must match `server_name`
+    ConfirmServerRestart { server_name: String, input: String },
+    /// REST explorer request line (`METHOD PATH [FORM]`)
+    RestRequest { input: String },
+    /// Saved investigation sessions: restore, save the workspace, or delete
+    SessionPicker {
+        /// Saved sessions as (name, summary), most recently saved first
+        sessions: Vec<(String, String)>,
+        /// Currently selected index
+        selected_index: usize,
+        /// Name being typed to save the current workspace (`Some` while naming)
+        name_input: Option<String>,
+    },
+    /// Knowledge objects owned by missing users (report in `App::orphan_report`)
+    OrphanedObjects {
+        /// Index of the selected orphan
+        selected_index: usize,
+    },
+    /// Curated troubleshooting searches (see `app::troubleshooting::RECIPES`)
+    TroubleshootingRecipes {
+        /// Index of the selected recipe
+        selected_index: usize,
+    },
+    /// Default owner/app/sharing namespace for knowledge-object requests
+    NamespaceSelector {
+        /// App context input (empty = all apps)
+        app_input: String,
+        /// Owner input (empty = all owners)
+        owner_input: String,
+        /// Sharing level filter (`None` = any)
+        sharing: Option<String>,
+        /// Currently selected field for navigation
+        selected_field: NamespaceField,
+    },
+    /// Knowledge object permissions (ACL) viewer and editor
+    EditAcl {
+        /// ACL as loaded from the server
+        acl: ObjectAcl,
+        /// Owner input
+        owner_input: String,
+        /// Sharing level (`user`, `app` or `global`)
+        sharing: String,
+        /// Comma-separated read roles input
+        read_input: String,
+        /// Comma-separated write roles input
+        write_input: String,
+        /// Currently selected field for navigation
+        selected_field: AclField,
+    },
 }
 
 impl PopupType {
@@ -263,8 +452,21 @@ impl PopupType {
                 };
                 true
             }
-            Self::CreateSavedSearch { selected_field, .. }
-            | Self::EditSavedSearch { selected_field, .. } => {
+            Self::CreateSavedSearch { selected_field, .. } => {
+                // The dispatch window can only be set when editing
+                loop {
+                    *selected_field = if backwards {
+                        selected_field.previous()
+                    } else {
+                        selected_field.next()
+                    };
+                    if !selected_field.is_dispatch_time() {
+                        break;
+                    }
+                }
+                true
+            }
+            Self::EditSavedSearch { selected_field, .. } => {
                 *selected_field = if backwards {
                     selected_field.previous()
                 } else {
@@ -280,6 +482,82 @@ impl PopupType {
                 };
                 true
             }
+            Self::ModifyIndex { selected_field, .. } => {
+                *selected_field = if backwards {
+                    selected_field.previous()
+                } else {
+                    selected_field.next()
+                };
+                true
+            }
+            Self::EditAcl { selected_field, .. } => {
+                *selected_field = if backwards {
+                    selected_field.previous()
+                } else {
+                    selected_field.next()
+                };
+                true
+            }
+            Self::NamespaceSelector { selected_field, .. } => {
+                *selected_field = if backwards {
+                    selected_field.previous()
+                } else {
+                    selected_field.next()
+                };
+                true
+            }
+            Self::InstallAppDialog { selected_field, .. } => {
+                *selected_field = if backwards {
+                    selected_field.previous()
+                } else {
+                    selected_field.next()
+                };
+                true
+            }
+            Self::AddSearchPeer { selected_field, .. } => {
+                *selected_field = if backwards {
+                    selected_field.previous()
+                } else {
+                    selected_field.next()
+                };
+                true
+            }
+            Self::CreateInput {
+                kind,
+                selected_field,
+                ..
+            } => {
+                *selected_field = if backwards {
+                    selected_field.previous(*kind)
+                } else {
+                    selected_field.next(*kind)
+                };
+                true
+            }
+            Self::JobsFilter { selected_field, .. } => {
+                *selected_field = if backwards {
+                    selected_field.previous()
+                } else {
+                    selected_field.next()
+                };
+                true
+            }
+            Self::JobsPrune { selected_field, .. } => {
+                *selected_field = if backwards {
+                    selected_field.previous()
+                } else {
+                    selected_field.next()
+                };
+                true
+            }
+            Self::HealthThresholds { selected_field, .. } => {
+                *selected_field = if backwards {
+                    selected_field.previous()
+                } else {
+                    selected_field.next()
+                };
+                true
+            }
             _ => false,
         }
     }
@@ -0,0 +1,196 @@
+//! Search result detail popup rendering with a field table and scrolling event view.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Margin, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Cell, Clear, Paragraph, Row, Scrollbar, ScrollbarOrientation,
+        ScrollbarState, Table, TableState,
+    },
+};
+
+use crate::app::App;
+use crate::app::result_detail::result_fields;
+use crate::theme::Theme;
+use crate::ui::result_renderers::render_result;
+use crate::ui::theme::ThemeExt;
+
+/// Widest the field name column grows before names are cut off.
+const MAX_FIELD_NAME_WIDTH: u16 = 24;
+
+/// Most rows the field table takes before it scrolls.
+const MAX_FIELD_TABLE_ROWS: u16 = 12;
+
+/// Render the result detail popup.
+///
+/// Shows every field of the result in a key/value table, then the event:
+/// the renderer registered for the result's sourcetype (when renderers are
+/// enabled in Settings) followed by `_raw`, pretty-printed if it is JSON or XML.
+pub fn render_result_detail(f: &mut Frame, app: &App, theme: &Theme) {
+    let area = f.area();
+
+    let popup_width = 110.min(area.width.saturating_sub(4));
+    let popup_height = 32.min(area.height.saturating_sub(4));
+
+    let x = (area.width.saturating_sub(popup_width)) / 2;
+    let y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    f.render_widget(Clear, popup_area);
+
+    let Some(result) = &app.result_detail else {
+        return;
+    };
+
+    let rendered = render_result(result, theme, app.result_renderers);
+    let title = match rendered.renderer {
+        Some(renderer) => format!(" Result Detail ({}) ", renderer),
+        None => " Result Detail ".to_string(),
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let fields = result_fields(result);
+    let table_height = (fields.len() as u16 + 2).min(MAX_FIELD_TABLE_ROWS + 2);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(table_height),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let sourcetype = Line::from(vec![
+        Span::styled(
+            "Sourcetype: ",
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            result
+                .get("sourcetype")
+                .and_then(|value| value.as_str())
+                .unwrap_or("-")
+                .to_string(),
+            Style::default().fg(theme.accent),
+        ),
+    ]);
+    f.render_widget(Paragraph::new(sourcetype), chunks[0]);
+
+    let name_width = fields
+        .iter()
+        .map(|field| field.name.chars().count() as u16)
+        .max()
+        .unwrap_or(5)
+        .clamp(5, MAX_FIELD_NAME_WIDTH);
+    let header = Row::new([Cell::from("Field"), Cell::from("Value")]).style(theme.table_header());
+    let rows = fields.iter().map(|field| {
+        let name_style = if field.name.starts_with('_') {
+            theme.text_dim()
+        } else {
+            Style::default().fg(theme.accent)
+        };
+        Row::new([
+            Cell::from(field.name.clone()).style(name_style),
+            Cell::from(field.display_value()),
+        ])
+    });
+    let table = Table::new(rows, [Constraint::Length(name_width), Constraint::Min(10)])
+        .header(header)
+        .row_highlight_style(theme.highlight())
+        .block(
+            Block::default()
+                .title(format!(" Fields ({}) ", fields.len()))
+                .borders(Borders::TOP)
+                .border_style(Style::default().fg(theme.border)),
+        );
+    let mut table_state = TableState::default().with_selected(
+        (!fields.is_empty()).then(|| app.result_detail_selected_field.min(fields.len() - 1)),
+    );
+    f.render_stateful_widget(table, chunks[1], &mut table_state);
+
+    let event_title = match rendered.raw_format {
+        Some(format) => format!(" Event (_raw as {}) ", format.name()),
+        None => " Event ".to_string(),
+    };
+    let event_area = chunks[2];
+    let visible_lines = event_area.height.saturating_sub(1) as usize;
+    let line_count = rendered.lines.len();
+    let max_offset = line_count.saturating_sub(visible_lines);
+    let offset = app.result_detail_scroll_offset.min(max_offset);
+    let paragraph = Paragraph::new(rendered.lines)
+        .block(
+            Block::default()
+                .title(event_title)
+                .borders(Borders::TOP)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .scroll((offset as u16, 0));
+    f.render_widget(paragraph, event_area);
+
+    if line_count > visible_lines {
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(Some("↑"))
+            .end_symbol(Some("↓"));
+        let mut scrollbar_state = ScrollbarState::new(max_offset).position(offset);
+        f.render_stateful_widget(
+            scrollbar,
+            event_area.inner(Margin::new(0, 1)),
+            &mut scrollbar_state,
+        );
+    }
+
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            "j/k: field  y: copy value  f: add as filter  PgUp/PgDn: scroll event  Ctrl+c: copy JSON  Esc: close",
+            Style::default().fg(theme.text_dim),
+        ))),
+        chunks[3],
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use ratatui::backend::TestBackend;
+    use serde_json::json;
+
+    #[test]
+    fn test_render_result_detail_shows_field_table_and_pretty_raw() {
+        let mut app = App::new(None, ConnectionContext::default());
+        app.result_detail = Some(json!({
+            "sourcetype": "_json",
+            "status": "404",
+            "_raw": r#"{"status":"404"}"#,
+        }));
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(120, 40)).unwrap();
+        terminal
+            .draw(|f| render_result_detail(f, &app, &Theme::default()))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let screen = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(screen.contains("Fields (3)"), "{}", screen);
+        assert!(screen.contains("sourcetype _json"));
+        assert!(screen.contains("status     404"));
+        assert!(screen.contains("Event (_raw as JSON)"));
+        assert!(screen.contains(r#"  "status": "404""#));
+    }
+}
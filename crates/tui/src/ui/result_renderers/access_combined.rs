@@ -0,0 +1,151 @@
+//! Renderer for Apache/NCSA access logs (`access_combined`, `access_common`).
+//!
+//! Splits the event into client, request, status, size, referer, and user agent,
+//! coloring the status by class.
+
+use ratatui::text::Line;
+use serde_json::Value;
+
+use super::{ResultRenderer, labeled, raw};
+use crate::theme::Theme;
+use crate::ui::theme::{StatusClass, ThemeExt};
+
+pub(super) struct AccessCombinedRenderer;
+
+/// One parsed access log line.
+#[derive(Debug, PartialEq, Eq)]
+struct AccessLine<'a> {
+    client: &'a str,
+    user: &'a str,
+    time: &'a str,
+    request: &'a str,
+    status: u16,
+    bytes: &'a str,
+    referer: Option<&'a str>,
+    user_agent: Option<&'a str>,
+}
+
+/// Parse `client ident user [time] "request" status bytes ["referer" "agent"]`.
+fn parse(raw: &str) -> Option<AccessLine<'_>> {
+    let (client, rest) = raw.trim().split_once(' ')?;
+    let (_ident, rest) = rest.split_once(' ')?;
+    let (user, rest) = rest.split_once(' ')?;
+    let (time, rest) = rest.strip_prefix('[')?.split_once("] ")?;
+    let (request, rest) = rest.strip_prefix('"')?.split_once("\" ")?;
+
+    let mut fields = rest.splitn(3, ' ');
+    let status = fields.next()?.parse().ok()?;
+    let bytes = fields.next()?;
+    let (referer, rest) = quoted(fields.next().unwrap_or_default());
+    let (user_agent, _) = quoted(rest);
+
+    Some(AccessLine {
+        client,
+        user,
+        time,
+        request,
+        status,
+        bytes,
+        referer,
+        user_agent,
+    })
+}
+
+/// Split a leading `"quoted"` value off `text`.
+fn quoted(text: &str) -> (Option<&str>, &str) {
+    text.trim_start()
+        .strip_prefix('"')
+        .and_then(|rest| rest.split_once('"'))
+        .map_or((None, text), |(value, rest)| (Some(value), rest))
+}
+
+impl ResultRenderer for AccessCombinedRenderer {
+    fn name(&self) -> &'static str {
+        "access_combined"
+    }
+
+    fn handles(&self, sourcetype: &str) -> bool {
+        sourcetype.starts_with("access_combined") || sourcetype == "access_common"
+    }
+
+    fn render(&self, result: &Value, theme: &Theme) -> Option<Vec<Line<'static>>> {
+        let line = parse(raw(result)?)?;
+
+        let (status_class, status_style) = match line.status {
+            200..=299 => (StatusClass::Success, theme.success()),
+            300..=399 => (StatusClass::Info, theme.info()),
+            400..=499 => (StatusClass::Warning, theme.warning()),
+            500..=599 => (StatusClass::Error, theme.error()),
+            _ => (StatusClass::Unknown, theme.text()),
+        };
+        let client = if line.user == "-" {
+            line.client.to_string()
+        } else {
+            format!("{} ({})", line.client, line.user)
+        };
+
+        let mut lines = vec![
+            labeled("Client", client, theme.text(), theme),
+            labeled("Time", line.time, theme.text(), theme),
+            labeled("Request", line.request, theme.text(), theme),
+            labeled(
+                "Status",
+                theme.tagged(&line.status.to_string(), status_class),
+                status_style,
+                theme,
+            ),
+            labeled("Bytes", line.bytes, theme.text(), theme),
+        ];
+        if let Some(referer) = line.referer.filter(|referer| *referer != "-") {
+            lines.push(labeled("Referer", referer, theme.text(), theme));
+        }
+        if let Some(user_agent) = line.user_agent {
+            lines.push(labeled("User-Agent", user_agent, theme.text_dim(), theme));
+        }
+        Some(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_combined_line() {
+        let line = parse(
+            r#"203.0.113.9 - alice [20/Jan/2025:10:00:00 +0000] "POST /api/login HTTP/1.1" 401 87 "https://example.com/" "Mozilla/5.0 (X11; Linux)""#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            line,
+            AccessLine {
+                client: "203.0.113.9",
+                user: "alice",
+                time: "20/Jan/2025:10:00:00 +0000",
+                request: "POST /api/login HTTP/1.1",
+                status: 401,
+                bytes: "87",
+                referer: Some("https://example.com/"),
+                user_agent: Some("Mozilla/5.0 (X11; Linux)"),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_common_line_without_referer() {
+        let line =
+            parse(r#"10.0.0.1 - - [20/Jan/2025:10:00:00 +0000] "GET / HTTP/1.0" 304 -"#).unwrap();
+
+        assert_eq!(line.status, 304);
+        assert_eq!(line.bytes, "-");
+        assert_eq!(line.referer, None);
+        assert_eq!(line.user_agent, None);
+    }
+
+    #[test]
+    fn test_parse_rejects_non_access_lines() {
+        assert!(parse("Jan 20 10:00:00 host sshd[1]: Accepted publickey").is_none());
+        assert!(parse(r#"10.0.0.1 - - [time] "GET / HTTP/1.1" abc 1"#).is_none());
+    }
+}
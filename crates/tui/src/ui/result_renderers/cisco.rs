@@ -0,0 +1,143 @@
+//! Renderer for Cisco syslog messages (`cisco:asa`, `cisco:ios`, `cisco_syslog`, ...).
+//!
+//! Extracts the `%FACILITY-SEVERITY-MNEMONIC: message` tag and colors the
+//! message by its syslog severity.
+
+use ratatui::style::Style;
+use ratatui::text::Line;
+use serde_json::Value;
+
+use super::{ResultRenderer, labeled, raw};
+use crate::theme::Theme;
+use crate::ui::theme::ThemeExt;
+
+pub(super) struct CiscoSyslogRenderer;
+
+/// Cisco severity level names, indexed by level.
+const SEVERITY_NAMES: [&str; 8] = [
+    "emergencies",
+    "alerts",
+    "critical",
+    "errors",
+    "warnings",
+    "notifications",
+    "informational",
+    "debugging",
+];
+
+/// A parsed Cisco message tag and text.
+#[derive(Debug, PartialEq, Eq)]
+struct CiscoMessage<'a> {
+    facility: String,
+    severity: u8,
+    mnemonic: &'a str,
+    message: &'a str,
+}
+
+/// Find and parse the first `%FACILITY-SEVERITY-MNEMONIC:` tag in the event.
+fn parse(raw: &str) -> Option<CiscoMessage<'_>> {
+    raw.match_indices('%').find_map(|(start, _)| {
+        let (tag, message) = raw[start + 1..].split_once(':')?;
+        let parts: Vec<&str> = tag.split('-').collect();
+        if parts.len() < 3
+            || !parts.iter().all(|part| {
+                !part.is_empty()
+                    && part
+                        .chars()
+                        .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+            })
+        {
+            return None;
+        }
+
+        let severity = parts[parts.len() - 2];
+        if severity.len() != 1 {
+            return None;
+        }
+        let severity = severity.parse::<u8>().ok().filter(|level| *level <= 7)?;
+
+        Some(CiscoMessage {
+            facility: parts[..parts.len() - 2].join("-"),
+            severity,
+            mnemonic: parts[parts.len() - 1],
+            message: message.trim(),
+        })
+    })
+}
+
+fn severity_style(severity: u8, theme: &Theme) -> Style {
+    match severity {
+        0..=3 => theme.error(),
+        4 => theme.warning(),
+        5 => theme.info(),
+        6 => theme.text(),
+        _ => theme.text_dim(),
+    }
+}
+
+impl ResultRenderer for CiscoSyslogRenderer {
+    fn name(&self) -> &'static str {
+        "cisco syslog"
+    }
+
+    fn handles(&self, sourcetype: &str) -> bool {
+        let sourcetype = sourcetype.to_ascii_lowercase();
+        sourcetype.starts_with("cisco:") || sourcetype == "cisco_syslog"
+    }
+
+    fn render(&self, result: &Value, theme: &Theme) -> Option<Vec<Line<'static>>> {
+        let message = parse(raw(result)?)?;
+        let style = severity_style(message.severity, theme);
+
+        Some(vec![
+            labeled(
+                "Severity",
+                format!(
+                    "{} ({})",
+                    message.severity, SEVERITY_NAMES[message.severity as usize]
+                ),
+                style,
+                theme,
+            ),
+            labeled("Facility", message.facility, theme.text(), theme),
+            labeled("Mnemonic", message.mnemonic, theme.text(), theme),
+            labeled("Message", message.message, style, theme),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_asa_message() {
+        let message = parse(
+            "Jan 20 10:00:00 fw01 %ASA-4-106023: Deny tcp src outside:198.51.100.7/443 dst inside:10.0.0.5/51515",
+        )
+        .unwrap();
+
+        assert_eq!(message.facility, "ASA");
+        assert_eq!(message.severity, 4);
+        assert_eq!(message.mnemonic, "106023");
+        assert!(message.message.starts_with("Deny tcp src outside"));
+    }
+
+    #[test]
+    fn test_parse_ios_message_skips_unrelated_percent() {
+        let message = parse(
+            "cpu 100% 000123: Jan 20 10:00:00: %LINEPROTO-5-UPDOWN: Line protocol on Interface Gi0/1, changed state to down",
+        )
+        .unwrap();
+
+        assert_eq!(message.facility, "LINEPROTO");
+        assert_eq!(message.severity, 5);
+        assert_eq!(message.mnemonic, "UPDOWN");
+    }
+
+    #[test]
+    fn test_parse_rejects_messages_without_tag() {
+        assert!(parse("Jan 20 10:00:00 fw01 connection closed").is_none());
+        assert!(parse("%ASA-9-106023: bad severity").is_none());
+    }
+}
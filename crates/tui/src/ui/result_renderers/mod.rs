@@ -0,0 +1,212 @@
+//! Sourcetype-specific renderers for the search result detail popup.
+//!
+//! Responsibilities:
+//! - Define the [`ResultRenderer`] trait and the compiled-in renderer registry.
+//! - Select a renderer automatically from the result's `sourcetype`.
+//! - Show the raw event below, pretty-printed when it is JSON or XML.
+//!
+//! Does NOT handle:
+//! - Popup layout, scrolling, the field table, or key handling (see `ui::result_detail`).
+//! - Persisting the on/off setting (see `PersistedState::result_renderers`).
+//!
+//! Invariants:
+//! - Renderers never hide data: the raw event is always shown below them.
+//! - A renderer that cannot parse an event returns `None`, leaving only the raw event.
+
+mod access_combined;
+mod cisco;
+mod windows;
+
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use serde_json::Value;
+
+use crate::theme::Theme;
+use crate::ui::theme::ThemeExt;
+
+use access_combined::AccessCombinedRenderer;
+use cisco::CiscoSyslogRenderer;
+use windows::WindowsEventRenderer;
+
+pub use splunk_client::raw_format::{RawFormat, pretty_raw};
+
+/// Pretty-renders results of specific sourcetypes in the detail popup.
+pub trait ResultRenderer: Sync {
+    /// Short name shown in the popup title.
+    fn name(&self) -> &'static str;
+
+    /// Whether this renderer handles results of `sourcetype`.
+    fn handles(&self, sourcetype: &str) -> bool;
+
+    /// Render the result, or `None` when the event does not parse.
+    fn render(&self, result: &Value, theme: &Theme) -> Option<Vec<Line<'static>>>;
+}
+
+/// Compiled-in renderers, checked in order.
+static RENDERERS: &[&dyn ResultRenderer] = &[
+    &AccessCombinedRenderer,
+    &CiscoSyslogRenderer,
+    &WindowsEventRenderer,
+];
+
+/// A search result prepared for the detail popup.
+pub struct RenderedResult {
+    /// Name of the sourcetype renderer used, if any.
+    pub renderer: Option<&'static str>,
+    /// Format `_raw` was pretty-printed as, if it is structured.
+    pub raw_format: Option<RawFormat>,
+    /// Lines to display, renderer output first and the raw event last.
+    pub lines: Vec<Line<'static>>,
+}
+
+/// Returns the renderer registered for the result's sourcetype.
+pub fn renderer_for(result: &Value) -> Option<&'static dyn ResultRenderer> {
+    let sourcetype = field(result, "sourcetype")?;
+    RENDERERS
+        .iter()
+        .copied()
+        .find(|renderer| renderer.handles(sourcetype))
+}
+
+/// Render a result for the detail popup.
+///
+/// When `use_renderers` is false, or no renderer matches or parses the event,
+/// only the raw event is returned.
+pub fn render_result(result: &Value, theme: &Theme, use_renderers: bool) -> RenderedResult {
+    let specific = use_renderers
+        .then(|| renderer_for(result))
+        .flatten()
+        .and_then(|renderer| {
+            renderer
+                .render(result, theme)
+                .map(|lines| (renderer.name(), lines))
+        });
+
+    let mut lines = Vec::new();
+    let renderer = specific.map(|(name, rendered)| {
+        lines.extend(rendered);
+        lines.push(Line::default());
+        lines.push(Line::styled("── Raw event ──", theme.text_dim()));
+        name
+    });
+
+    let mut raw_format = None;
+    match raw(result) {
+        Some(text) => {
+            let text = match pretty_raw(text) {
+                Some((format, pretty)) => {
+                    raw_format = Some(format);
+                    pretty
+                }
+                None => text.to_string(),
+            };
+            lines.extend(text.lines().map(|line| Line::from(line.to_string())));
+        }
+        None => lines.push(Line::styled("(no _raw field)", theme.text_dim())),
+    }
+
+    RenderedResult {
+        renderer,
+        raw_format,
+        lines,
+    }
+}
+
+/// String value of a result field; multivalue fields yield their first value.
+fn field<'a>(result: &'a Value, name: &str) -> Option<&'a str> {
+    match result.get(name)? {
+        Value::String(value) => Some(value.as_str()),
+        Value::Array(values) => values.first().and_then(Value::as_str),
+        _ => None,
+    }
+}
+
+/// The event text of a result.
+fn raw(result: &Value) -> Option<&str> {
+    field(result, "_raw")
+}
+
+/// A `Label: value` line with labels padded so values line up.
+fn labeled(
+    label: &str,
+    value: impl Into<String>,
+    value_style: Style,
+    theme: &Theme,
+) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(format!("{:<12}", label), theme.title()),
+        Span::styled(value.into(), value_style),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn text(lines: &[Line]) -> String {
+        lines
+            .iter()
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_renderer_selected_by_sourcetype() {
+        let access = json!({ "sourcetype": "access_combined", "_raw": "" });
+        let asa = json!({ "sourcetype": "cisco:asa", "_raw": "" });
+        let windows = json!({ "sourcetype": ["WinEventLog:Security"], "_raw": "" });
+        let other = json!({ "sourcetype": "syslog", "_raw": "" });
+
+        assert_eq!(
+            renderer_for(&access).map(|r| r.name()),
+            Some("access_combined")
+        );
+        assert_eq!(renderer_for(&asa).map(|r| r.name()), Some("cisco syslog"));
+        assert_eq!(
+            renderer_for(&windows).map(|r| r.name()),
+            Some("windows event")
+        );
+        assert!(renderer_for(&other).is_none());
+        assert!(renderer_for(&json!({ "_raw": "x" })).is_none());
+    }
+
+    #[test]
+    fn test_render_result_falls_back_to_raw_event() {
+        let theme = Theme::default();
+        let result = json!({ "sourcetype": "access_combined", "_raw": "not an access log" });
+
+        let rendered = render_result(&result, &theme, true);
+
+        assert!(rendered.renderer.is_none());
+        assert!(rendered.raw_format.is_none());
+        assert_eq!(text(&rendered.lines), "not an access log");
+    }
+
+    #[test]
+    fn test_render_result_pretty_prints_json_raw() {
+        let theme = Theme::default();
+        let result = json!({ "sourcetype": "_json", "_raw": r#"{"level":"info"}"# });
+
+        let rendered = render_result(&result, &theme, true);
+
+        assert_eq!(rendered.raw_format, Some(RawFormat::Json));
+        assert_eq!(text(&rendered.lines), "{\n  \"level\": \"info\"\n}");
+    }
+
+    #[test]
+    fn test_render_result_respects_disabled_setting() {
+        let theme = Theme::default();
+        let result = json!({
+            "sourcetype": "access_combined",
+            "_raw": r#"10.0.0.1 - - [20/Jan/2025:10:00:00 +0000] "GET / HTTP/1.1" 200 512 "-" "curl/8.0""#
+        });
+
+        assert_eq!(
+            render_result(&result, &theme, true).renderer,
+            Some("access_combined")
+        );
+        assert!(render_result(&result, &theme, false).renderer.is_none());
+    }
+}
@@ -0,0 +1,164 @@
+//! Renderer for Windows event logs (`WinEventLog:*`, `XmlWinEventLog:*`).
+//!
+//! Summarizes the event by its EventCode, using a short description for
+//! well-known security codes and the first line of the message otherwise.
+
+use ratatui::text::Line;
+use serde_json::Value;
+
+use super::{ResultRenderer, field, labeled, raw};
+use crate::theme::Theme;
+use crate::ui::theme::ThemeExt;
+
+pub(super) struct WindowsEventRenderer;
+
+/// Short descriptions for frequently investigated event codes.
+const WELL_KNOWN_EVENT_CODES: &[(&str, &str)] = &[
+    ("1102", "The audit log was cleared"),
+    ("4624", "An account was successfully logged on"),
+    ("4625", "An account failed to log on"),
+    ("4634", "An account was logged off"),
+    ("4648", "A logon was attempted using explicit credentials"),
+    ("4672", "Special privileges assigned to new logon"),
+    ("4688", "A new process has been created"),
+    ("4720", "A user account was created"),
+    ("4726", "A user account was deleted"),
+    ("4740", "A user account was locked out"),
+    (
+        "4768",
+        "A Kerberos authentication ticket (TGT) was requested",
+    ),
+    ("4771", "Kerberos pre-authentication failed"),
+    ("7045", "A service was installed in the system"),
+];
+
+/// Value of `key=value` in classic multi-line WinEventLog text.
+fn raw_value<'a>(raw: &'a str, key: &str) -> Option<&'a str> {
+    raw.lines().find_map(|line| {
+        line.trim()
+            .strip_prefix(key)?
+            .strip_prefix('=')
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+    })
+}
+
+/// Text of the first `<tag ...>text</tag>` element in XmlWinEventLog text.
+fn xml_value<'a>(raw: &'a str, tag: &str) -> Option<&'a str> {
+    let start = raw.find(&format!("<{}", tag))?;
+    let rest = &raw[start + tag.len() + 1..];
+    if !rest.starts_with(['>', ' ']) {
+        return None;
+    }
+    let (_, rest) = rest.split_once('>')?;
+    let (value, _) = rest.split_once(&format!("</{}>", tag))?;
+    Some(value.trim()).filter(|value| !value.is_empty())
+}
+
+/// Look a value up in the extracted fields, then in the raw text.
+fn lookup<'a>(result: &'a Value, name: &str, xml_tag: &str) -> Option<&'a str> {
+    field(result, name).or_else(|| {
+        let raw = raw(result)?;
+        raw_value(raw, name).or_else(|| xml_value(raw, xml_tag))
+    })
+}
+
+impl ResultRenderer for WindowsEventRenderer {
+    fn name(&self) -> &'static str {
+        "windows event"
+    }
+
+    fn handles(&self, sourcetype: &str) -> bool {
+        let sourcetype = sourcetype.to_ascii_lowercase();
+        sourcetype.starts_with("wineventlog") || sourcetype.starts_with("xmlwineventlog")
+    }
+
+    fn render(&self, result: &Value, theme: &Theme) -> Option<Vec<Line<'static>>> {
+        let code = lookup(result, "EventCode", "EventID")?;
+        let message = lookup(result, "Message", "Message")
+            .and_then(|message| message.lines().next())
+            .map(str::trim);
+        let summary = WELL_KNOWN_EVENT_CODES
+            .iter()
+            .find(|(known, _)| *known == code)
+            .map(|(_, summary)| *summary)
+            .or(message)
+            .unwrap_or("-");
+
+        let mut lines = vec![
+            labeled("EventCode", code, theme.title(), theme),
+            labeled("Summary", summary, theme.text(), theme),
+        ];
+        if let Some(log_name) = lookup(result, "LogName", "Channel") {
+            lines.push(labeled("Log", log_name, theme.text(), theme));
+        }
+        if let Some(computer) = lookup(result, "ComputerName", "Computer") {
+            lines.push(labeled("Computer", computer, theme.text(), theme));
+        }
+        if let Some(keywords) = lookup(result, "Keywords", "Keywords") {
+            let style = if keywords.contains("Failure") {
+                theme.error()
+            } else if keywords.contains("Success") {
+                theme.success()
+            } else {
+                theme.text()
+            };
+            lines.push(labeled("Keywords", keywords, style, theme));
+        }
+        if let Some(account) = field(result, "Account_Name") {
+            lines.push(labeled("Account", account, theme.text(), theme));
+        }
+        Some(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_render_classic_event_from_raw_text() {
+        let theme = Theme::default();
+        let result = json!({
+            "sourcetype": "WinEventLog:Security",
+            "_raw": "01/20/2025 10:00:00 AM\nLogName=Security\nEventCode=4625\nComputerName=dc01.example.com\nKeywords=Audit Failure\nMessage=An account failed to log on.\r\n\r\nSubject:"
+        });
+
+        let lines = WindowsEventRenderer.render(&result, &theme).unwrap();
+        let text: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+
+        assert_eq!(text[0], "EventCode   4625");
+        assert_eq!(text[1], "Summary     An account failed to log on");
+        assert!(text.contains(&"Computer    dc01.example.com".to_string()));
+        assert!(text.contains(&"Keywords    Audit Failure".to_string()));
+    }
+
+    #[test]
+    fn test_render_xml_event_prefers_extracted_fields() {
+        let theme = Theme::default();
+        let result = json!({
+            "sourcetype": "XmlWinEventLog:System",
+            "EventCode": "7036",
+            "Message": "The Print Spooler service entered the stopped state.",
+            "_raw": "<Event><System><EventID Qualifiers='16384'>7036</EventID><Channel>System</Channel></System></Event>"
+        });
+
+        let lines = WindowsEventRenderer.render(&result, &theme).unwrap();
+        let text: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+
+        assert_eq!(
+            text[1],
+            "Summary     The Print Spooler service entered the stopped state."
+        );
+        assert!(text.contains(&"Log         System".to_string()));
+    }
+
+    #[test]
+    fn test_render_requires_event_code() {
+        let theme = Theme::default();
+        let result = json!({ "sourcetype": "WinEventLog:Application", "_raw": "no code here" });
+
+        assert!(WindowsEventRenderer.render(&result, &theme).is_none());
+    }
+}
@@ -1,12 +1,17 @@
 //! Cluster screen rendering.
 //!
 //! Renders the cluster information including ID, mode, replication factors,
-//! and cluster peers list. Supports toggling between summary and peers views.
+//! and cluster peers list. Supports toggling between summary, peers, bucket
+//! health, and index compliance views.
 //!
 //! Responsibilities:
 //! - Render cluster summary information (ID, mode, label, replication factors)
-//! - Render cluster peers as a table with status indicators
-//! - Handle view mode switching (Summary vs Peers)
+//! - Render pending fix-up and excess bucket copy counts in the summary
+//! - Render unsupported version skew in the summary
+//! - Render cluster peers as a table with status indicators and versions
+//! - Render per-index fix-ups and excess copies in the buckets view
+//! - Render per-index copies against RF/SF in the compliance view
+//! - Handle view mode switching (Summary vs Peers vs Buckets vs Compliance)
 //!
 //! Does NOT handle:
 //! - Does NOT fetch data (handled by async tasks in main.rs)
@@ -15,13 +20,16 @@
 use crate::theme::Theme;
 use ratatui::{
     Frame,
-    layout::{Alignment, Constraint, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     widgets::{Block, Borders, Cell, List, ListItem, Row, Table, TableState},
 };
-use splunk_client::models::{ClusterInfo, ClusterPeer};
+use splunk_client::models::{
+    ClusterBucketHealth, ClusterIndexCompliance, ClusterInfo, ClusterNodeRole, ClusterPeer,
+    ClusterVersionReport,
+};
 
 use crate::app::state::ClusterViewMode;
-use crate::ui::theme::ThemeExt;
+use crate::ui::theme::{StatusClass, ThemeExt};
 use crate::ui::widgets::{render_screen_state, render_screen_state_custom};
 
 /// Configuration for rendering the cluster screen.
@@ -32,6 +40,12 @@ pub struct ClusterRenderConfig<'a> {
     pub cluster_info: Option<&'a ClusterInfo>,
     /// The cluster peers to display
     pub cluster_peers: Option<&'a [ClusterPeer]>,
+    /// Pending fix-ups and excess bucket copies (manager only)
+    pub bucket_health: Option<&'a ClusterBucketHealth>,
+    /// Actual copies per index against RF/SF (manager only)
+    pub index_compliance: Option<&'a [ClusterIndexCompliance]>,
+    /// Splunk versions and skew across the cluster (manager only)
+    pub versions: Option<&'a ClusterVersionReport>,
     /// Current view mode
     pub view_mode: ClusterViewMode,
     /// Table state for peers view
@@ -54,6 +68,9 @@ pub fn render_cluster(f: &mut Frame, area: Rect, config: ClusterRenderConfig) {
         loading,
         cluster_info,
         cluster_peers,
+        bucket_health,
+        index_compliance,
+        versions,
         view_mode,
         peers_state,
         theme,
@@ -76,17 +93,47 @@ pub fn render_cluster(f: &mut Frame, area: Rect, config: ClusterRenderConfig) {
 
     match view_mode {
         ClusterViewMode::Summary => {
-            render_summary(f, area, info, theme);
+            render_summary(
+                f,
+                area,
+                info,
+                bucket_health,
+                index_compliance,
+                versions,
+                theme,
+            );
         }
         ClusterViewMode::Peers => {
-            render_peers(f, area, info, cluster_peers, peers_state, loading, theme);
+            render_peers(
+                f,
+                area,
+                cluster_peers,
+                versions,
+                peers_state,
+                loading,
+                theme,
+            );
+        }
+        ClusterViewMode::Buckets => {
+            render_buckets(f, area, bucket_health, loading, theme);
+        }
+        ClusterViewMode::Compliance => {
+            render_compliance(f, area, index_compliance, loading, theme);
         }
     }
 }
 
 /// Render the cluster summary view.
-fn render_summary(f: &mut Frame, area: Rect, info: &ClusterInfo, theme: &Theme) {
-    let items: Vec<ListItem> = vec![
+fn render_summary(
+    f: &mut Frame,
+    area: Rect,
+    info: &ClusterInfo,
+    bucket_health: Option<&ClusterBucketHealth>,
+    index_compliance: Option<&[ClusterIndexCompliance]>,
+    versions: Option<&ClusterVersionReport>,
+    theme: &Theme,
+) {
+    let mut items: Vec<ListItem> = vec![
         ListItem::new(format!("ID: {}", info.id)),
         ListItem::new(format!("Mode: {}", info.mode)),
         ListItem::new(format!("Label: {:?}", info.label)),
@@ -96,6 +143,79 @@ fn render_summary(f: &mut Frame, area: Rect, info: &ClusterInfo, theme: &Theme)
         ListItem::new(format!("Status: {:?}", info.status)),
     ];
 
+    if let Some(health) = bucket_health {
+        let count_style = |count: usize| {
+            if count > 0 {
+                theme.warning()
+            } else {
+                theme.text()
+            }
+        };
+        let fixups = health.total_fixups();
+        let excess = health.total_excess_copies();
+        items.push(
+            ListItem::new(format!(
+                "Pending Fix-ups: {} (press 'b' for details)",
+                fixups
+            ))
+            .style(count_style(fixups)),
+        );
+        items.push(
+            ListItem::new(format!("Excess Bucket Copies: {}", excess)).style(count_style(excess)),
+        );
+    }
+
+    if let Some(indexes) = index_compliance {
+        let below = indexes.iter().filter(|i| !i.compliant).count();
+        let style = if below > 0 {
+            theme.error()
+        } else {
+            theme.text()
+        };
+        items.push(
+            ListItem::new(format!(
+                "Indexes Below RF/SF: {} of {} (press 'i' for details)",
+                below,
+                indexes.len()
+            ))
+            .style(style),
+        );
+    }
+
+    if let Some(versions) = versions {
+        let manager = versions.manager_version().unwrap_or("unknown");
+        if versions.skew.is_empty() {
+            items.push(ListItem::new(format!(
+                "Version Skew: none ({} nodes, manager {})",
+                versions.nodes.len(),
+                manager
+            )));
+        } else {
+            items.push(
+                ListItem::new(format!(
+                    "Version Skew: {} unsupported (manager {})",
+                    versions.skew.len(),
+                    manager
+                ))
+                .style(theme.error()),
+            );
+            items.extend(versions.skew.iter().map(|skew| {
+                ListItem::new(format!(
+                    "  {} {} {}: {}",
+                    skew.role, skew.name, skew.version, skew.reason
+                ))
+                .style(theme.warning())
+            }));
+        }
+        let unknown = versions.unknown_versions().count();
+        if unknown > 0 {
+            items.push(
+                ListItem::new(format!("Version Unknown: {} node(s)", unknown))
+                    .style(theme.warning()),
+            );
+        }
+    }
+
     let list = List::new(items).block(
         Block::default()
             .borders(Borders::ALL)
@@ -110,8 +230,8 @@ fn render_summary(f: &mut Frame, area: Rect, info: &ClusterInfo, theme: &Theme)
 fn render_peers(
     f: &mut Frame,
     area: Rect,
-    _info: &ClusterInfo,
     peers: Option<&[ClusterPeer]>,
+    versions: Option<&ClusterVersionReport>,
     state: &mut TableState,
     loading: bool,
     theme: &Theme,
@@ -163,6 +283,7 @@ fn render_peers(
         "Port",
         "Rep Count",
         "Rep Status",
+        "Version",
     ];
 
     // Create header row with styling
@@ -183,10 +304,18 @@ fn render_peers(
             };
 
             let status_style = theme.status_style(&peer.status.to_string());
+            let name = peer.label.as_deref().unwrap_or(&peer.host);
+            let version = peer.splunk_version.clone().unwrap_or_default();
+            let (version_text, version_style) =
+                if versions.is_some_and(|v| v.is_skewed(ClusterNodeRole::Peer, name)) {
+                    (theme.tagged(&version, StatusClass::Error), theme.error())
+                } else {
+                    (version, theme.text())
+                };
 
             let cells = vec![
                 Cell::from(host_text),
-                Cell::from(peer.status.to_string()).style(status_style),
+                Cell::from(theme.status_text(&peer.status.to_string())).style(status_style),
                 Cell::from(peer.peer_state.to_string()),
                 Cell::from(peer.site.clone().unwrap_or_default()),
                 Cell::from(peer.port.to_string()),
@@ -201,6 +330,7 @@ fn render_peers(
                         .map(|s| s.to_string())
                         .unwrap_or_default(),
                 ),
+                Cell::from(version_text).style(version_style),
             ];
             Row::new(cells).height(1)
         })
@@ -215,6 +345,7 @@ fn render_peers(
         Constraint::Length(6),  // Port
         Constraint::Length(10), // Rep Count
         Constraint::Length(12), // Rep Status
+        Constraint::Length(10), // Version
     ];
 
     let table = Table::new(rows, constraints)
@@ -225,6 +356,257 @@ fn render_peers(
     f.render_stateful_widget(table, area, state);
 }
 
+/// Render actual replicated/searchable copies per index against RF/SF.
+///
+/// Non-compliant indexes are listed first.
+fn render_compliance(
+    f: &mut Frame,
+    area: Rect,
+    indexes: Option<&[ClusterIndexCompliance]>,
+    loading: bool,
+    theme: &Theme,
+) {
+    let title = if loading {
+        "Index Compliance (Loading...)"
+    } else {
+        "Index Compliance - Press 'i' for summary"
+    };
+
+    let Some(indexes) = render_screen_state_custom(
+        f,
+        area,
+        false,
+        indexes,
+        title,
+        "Loading index compliance...",
+        if loading {
+            "Loading index compliance..."
+        } else {
+            "Index compliance is only available on the cluster manager."
+        },
+        0,
+        theme,
+    ) else {
+        return;
+    };
+
+    let below = indexes.iter().filter(|i| !i.compliant).count();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "{} - {} of {} indexes below RF/SF",
+            title,
+            below,
+            indexes.len()
+        ))
+        .border_style(theme.border())
+        .title_style(theme.title());
+
+    if indexes.is_empty() {
+        let paragraph = ratatui::widgets::Paragraph::new("No clustered indexes found.")
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let header = Row::new(
+        [
+            "Index",
+            "Buckets",
+            "Copies/RF",
+            "Below RF",
+            "Searchable/SF",
+            "Below SF",
+            "Status",
+        ]
+        .iter()
+        .map(|h| Cell::from(*h).style(theme.table_header())),
+    )
+    .height(1);
+
+    let mut sorted: Vec<&ClusterIndexCompliance> = indexes.iter().collect();
+    sorted.sort_by_key(|i| i.compliant);
+
+    let rows: Vec<Row> = sorted
+        .into_iter()
+        .map(|i| {
+            let (status, style) = if i.compliant {
+                ("OK", theme.success())
+            } else {
+                ("BELOW FACTOR", theme.error())
+            };
+            Row::new(vec![
+                Cell::from(i.index.clone()),
+                Cell::from(i.buckets.to_string()),
+                Cell::from(format!("{}/{}", i.replicated_copies, i.replication_factor)),
+                Cell::from(i.buckets_below_rf.to_string()),
+                Cell::from(format!("{}/{}", i.searchable_copies, i.search_factor)),
+                Cell::from(i.buckets_below_sf.to_string()),
+                Cell::from(status).style(style),
+            ])
+            .height(1)
+        })
+        .collect();
+
+    let constraints = [
+        Constraint::Min(20),    // Index
+        Constraint::Length(9),  // Buckets
+        Constraint::Length(10), // Copies/RF
+        Constraint::Length(9),  // Below RF
+        Constraint::Length(14), // Searchable/SF
+        Constraint::Length(9),  // Below SF
+        Constraint::Length(13), // Status
+    ];
+
+    let table = Table::new(rows, constraints).header(header).block(block);
+    f.render_widget(table, area);
+}
+
+/// Render per-index fix-up and excess copy counts plus the pending fix-up tasks.
+fn render_buckets(
+    f: &mut Frame,
+    area: Rect,
+    health: Option<&ClusterBucketHealth>,
+    loading: bool,
+    theme: &Theme,
+) {
+    let title = if loading {
+        "Bucket Health (Loading...)"
+    } else {
+        "Bucket Health - Press 'b' for summary"
+    };
+
+    let Some(health) = render_screen_state_custom(
+        f,
+        area,
+        false,
+        health,
+        title,
+        "Loading bucket health...",
+        if loading {
+            "Loading bucket health..."
+        } else {
+            "Bucket health is only available on the cluster manager."
+        },
+        0,
+        theme,
+    ) else {
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    let summaries = health.index_summaries();
+    let index_block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "{} - {} fix-ups, {} excess copies",
+            title,
+            health.total_fixups(),
+            health.total_excess_copies()
+        ))
+        .border_style(theme.border())
+        .title_style(theme.title());
+
+    if summaries.is_empty() {
+        let paragraph =
+            ratatui::widgets::Paragraph::new("No pending fix-ups or excess bucket copies.")
+                .block(index_block)
+                .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let header = Row::new(
+        [
+            "Index",
+            "Fix-ups",
+            "Generation",
+            "RF",
+            "SF",
+            "Excess",
+            "Excess Searchable",
+        ]
+        .iter()
+        .map(|h| Cell::from(*h).style(theme.table_header())),
+    )
+    .height(1);
+
+    let rows: Vec<Row> = summaries
+        .iter()
+        .map(|s| {
+            Row::new(vec![
+                Cell::from(s.index.clone()),
+                Cell::from(s.pending_fixups.to_string()),
+                Cell::from(s.generation_fixups.to_string()),
+                Cell::from(s.replication_factor_fixups.to_string()),
+                Cell::from(s.search_factor_fixups.to_string()),
+                Cell::from(s.excess_bucket_copies.to_string()),
+                Cell::from(s.excess_searchable_copies.to_string()),
+            ])
+            .height(1)
+        })
+        .collect();
+
+    let constraints = [
+        Constraint::Min(20),    // Index
+        Constraint::Length(8),  // Fix-ups
+        Constraint::Length(11), // Generation
+        Constraint::Length(6),  // RF
+        Constraint::Length(6),  // SF
+        Constraint::Length(8),  // Excess
+        Constraint::Length(18), // Excess Searchable
+    ];
+
+    let table = Table::new(rows, constraints)
+        .header(header)
+        .block(index_block);
+    f.render_widget(table, chunks[0]);
+
+    let fixup_block = Block::default()
+        .borders(Borders::ALL)
+        .title("Pending Fix-up Tasks")
+        .border_style(theme.border())
+        .title_style(theme.title());
+
+    let fixup_header = Row::new(
+        ["Bucket", "Index", "Level", "Reason"]
+            .iter()
+            .map(|h| Cell::from(*h).style(theme.table_header())),
+    )
+    .height(1);
+
+    let fixup_rows: Vec<Row> = health
+        .fixups
+        .iter()
+        .map(|task| {
+            Row::new(vec![
+                Cell::from(task.bucket_id.clone()),
+                Cell::from(task.index.clone()),
+                Cell::from(task.level.to_string()),
+                Cell::from(task.reason.clone().unwrap_or_default()),
+            ])
+            .height(1)
+        })
+        .collect();
+
+    let fixup_constraints = [
+        Constraint::Min(30),    // Bucket
+        Constraint::Length(16), // Index
+        Constraint::Length(20), // Level
+        Constraint::Min(20),    // Reason
+    ];
+
+    let fixup_table = Table::new(fixup_rows, fixup_constraints)
+        .header(fixup_header)
+        .block(fixup_block);
+    f.render_widget(fixup_table, chunks[1]);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
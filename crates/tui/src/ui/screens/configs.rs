@@ -2,6 +2,10 @@
 //!
 //! Renders the configuration file browser and viewer for Splunk config files
 //! (props.conf, transforms.conf, inputs.conf, etc.).
+//!
+//! The stanza detail view can also show which context supplies each effective
+//! setting (a `btool --debug` style view) and a unified diff between an app's
+//! layer and the effective settings.
 
 use crate::app::input::components::SingleLineInput;
 use crate::theme::Theme;
@@ -11,7 +15,8 @@ use ratatui::{
     text::{Line, Span, Text},
     widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState, Wrap},
 };
-use splunk_client::models::{ConfigFile, ConfigStanza};
+use splunk_client::models::{ConfigFile, ConfigLayer, ConfigStanza};
+use splunk_client::workflows::effective_config::{app_layer_diff, merge_layers};
 
 use crate::ui::theme::ThemeExt;
 use crate::ui::widgets::render_screen_state;
@@ -28,6 +33,29 @@ pub enum ConfigViewMode {
     StanzaDetail,
 }
 
+/// What the stanza detail view shows.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ConfigDetailView {
+    /// Settings of the selected stanza
+    #[default]
+    Settings,
+    /// Effective settings with the layer that supplies each one
+    Layers,
+    /// Unified diff between an app's layer and the effective settings
+    Diff,
+}
+
+impl ConfigDetailView {
+    /// Cycle to the next detail view.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Settings => Self::Layers,
+            Self::Layers => Self::Diff,
+            Self::Diff => Self::Settings,
+        }
+    }
+}
+
 /// Configuration for rendering the configs screen.
 pub struct ConfigsRenderConfig<'a> {
     /// Whether data is currently loading
@@ -42,6 +70,12 @@ pub struct ConfigsRenderConfig<'a> {
     pub selected_stanza: Option<&'a ConfigStanza>,
     /// Current view mode
     pub view_mode: ConfigViewMode,
+    /// Every context's copy of the selected stanza
+    pub layers: Option<&'a [ConfigLayer]>,
+    /// What the stanza detail view shows
+    pub detail_view: ConfigDetailView,
+    /// App whose layer is diffed against the effective settings
+    pub diff_app: Option<&'a str>,
     /// The current table selection state for config files
     pub files_state: &'a mut TableState,
     /// The current table selection state for stanzas
@@ -297,7 +331,11 @@ fn render_stanza_list(f: &mut Frame, area: Rect, config: ConfigsRenderConfig) {
 /// Render the stanza detail view.
 fn render_stanza_detail(f: &mut Frame, area: Rect, config: ConfigsRenderConfig) {
     let ConfigsRenderConfig {
+        loading,
         selected_stanza,
+        layers,
+        detail_view,
+        diff_app,
         theme,
         ..
     } = config;
@@ -317,9 +355,40 @@ fn render_stanza_detail(f: &mut Frame, area: Rect, config: ConfigsRenderConfig)
         }
     };
 
-    let title = format!("Stanza: [{}]", stanza.name);
+    let (title, lines) = match detail_view {
+        ConfigDetailView::Settings => (
+            format!("Stanza: [{}]", stanza.name),
+            settings_lines(stanza, theme),
+        ),
+        ConfigDetailView::Layers => (
+            format!("Layers: [{}] (v: next view)", stanza.name),
+            layers_lines(stanza, layers, loading, theme),
+        ),
+        ConfigDetailView::Diff => (
+            format!(
+                "Diff: [{}] app:{} vs effective (a: next app, v: next view)",
+                stanza.name,
+                diff_app.unwrap_or("-")
+            ),
+            diff_lines(stanza, layers, diff_app, loading, theme),
+        ),
+    };
+
+    let paragraph = Paragraph::new(Text::from(lines))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(theme.border())
+                .title_style(theme.title()),
+        )
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(paragraph, area);
+}
 
-    // Build content
+/// Lines for the plain settings of a stanza.
+fn settings_lines<'a>(stanza: &'a ConfigStanza, theme: &Theme) -> Vec<Line<'a>> {
     let mut lines = vec![
         Line::from(vec![
             Span::styled("Config File: ", theme.title()),
@@ -340,17 +409,108 @@ fn render_stanza_detail(f: &mut Frame, area: Rect, config: ConfigsRenderConfig)
         }
     }
 
-    let paragraph = Paragraph::new(Text::from(lines))
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(title)
-                .border_style(theme.border())
-                .title_style(theme.title()),
-        )
-        .wrap(Wrap { trim: true });
+    lines
+}
 
-    f.render_widget(paragraph, area);
+/// Placeholder shown while layers are missing.
+fn layers_placeholder(loading: bool) -> Vec<Line<'static>> {
+    let text = if loading {
+        "  Loading layers..."
+    } else {
+        "  Layers not loaded. Press 'r' to load them."
+    };
+    vec![Line::from(text)]
+}
+
+/// Lines for the effective settings of a stanza and the layer supplying each.
+fn layers_lines(
+    stanza: &ConfigStanza,
+    layers: Option<&[ConfigLayer]>,
+    loading: bool,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
+    let Some(layers) = layers else {
+        return layers_placeholder(loading);
+    };
+
+    let mut contexts: Vec<String> = layers
+        .iter()
+        .filter(|layer| layer.stanza == stanza.name)
+        .map(ConfigLayer::source)
+        .collect();
+    contexts.sort();
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Defined in: ", theme.title()),
+            Span::raw(if contexts.is_empty() {
+                "(no contexts)".to_string()
+            } else {
+                contexts.join(", ")
+            }),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled("Effective settings:", theme.title())),
+    ];
+
+    let settings: Vec<_> = merge_layers(layers.to_vec(), None, None)
+        .into_iter()
+        .filter(|setting| setting.stanza == stanza.name)
+        .collect();
+    if settings.is_empty() {
+        lines.push(Line::from("  (no settings)"));
+    }
+    for setting in settings {
+        lines.push(Line::from(vec![
+            Span::raw(format!("  {} = ", setting.key)),
+            Span::styled(setting.value, theme.success()),
+            Span::styled(format!("  [{}]", setting.source), theme.info()),
+        ]));
+        for overridden in setting.overridden {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "      overrides {} = {}",
+                    overridden.source, overridden.value
+                ),
+                theme.text_dim(),
+            )));
+        }
+    }
+
+    lines
+}
+
+/// Lines for the unified diff of an app's layer against the effective settings.
+fn diff_lines(
+    stanza: &ConfigStanza,
+    layers: Option<&[ConfigLayer]>,
+    diff_app: Option<&str>,
+    loading: bool,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
+    let Some(layers) = layers else {
+        return layers_placeholder(loading);
+    };
+    let Some(app) = diff_app else {
+        return vec![Line::from("  No app layer defines this stanza.")];
+    };
+
+    app_layer_diff(layers, &stanza.name, app)
+        .into_iter()
+        .map(|line| {
+            let style = if line.starts_with("@@") {
+                theme.info()
+            } else if line.starts_with("---") || line.starts_with("+++") {
+                theme.title()
+            } else if line.starts_with('-') {
+                theme.error()
+            } else if line.starts_with('+') {
+                theme.success()
+            } else {
+                theme.text()
+            };
+            Line::from(Span::styled(line, style))
+        })
+        .collect()
 }
 
 /// Render a help popup for the configs screen.
@@ -362,6 +522,8 @@ pub fn render_configs_help(f: &mut Frame, theme: &Theme) {
         Line::from("  ↑/↓ or k/j    Navigate up/down"),
         Line::from("  Enter         Select / view details"),
         Line::from("  h             Go back to previous view"),
+        Line::from("  v             Cycle settings / layers / diff (stanza detail)"),
+        Line::from("  a             Cycle the app diffed against effective settings"),
         Line::from(""),
         Line::from("Actions:"),
         Line::from("  r/F5          Refresh data"),
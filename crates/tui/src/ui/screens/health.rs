@@ -1,19 +1,28 @@
 //! Health screen rendering.
 //!
 //! Renders comprehensive Splunk environment health metrics including server info,
-//! splunkd health, license usage, KVStore status, and log parsing health.
+//! threshold alerts, splunkd health, license usage, KVStore status, cluster peers,
+//! log parsing health, scheduler health, ingestion queue fill per pipeline,
+//! failed logins per source IP, running and queued searches against the
+//! concurrency limits and user quotas, and a gauge for the fullest disk partition, or
+//! (toggled with 'u') per-host resource gauges and the busiest processes.
 
+use crate::app::state::HealthViewMode;
 use crate::theme::Theme;
 use ratatui::{
     Frame,
-    layout::{Alignment, Rect},
+    layout::{Alignment, Constraint, Layout, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Cell, LineGauge, Paragraph, Row, Table},
 };
-use splunk_client::{format_bytes, models::HealthCheckOutput};
+use splunk_client::{
+    format_bytes,
+    models::{HealthCheckOutput, HostResourceUsage, PartitionSpace, ResourceUsage},
+};
+use splunk_config::{HealthThresholds, ThresholdLevel};
 
-use crate::ui::theme::ThemeExt;
+use crate::ui::theme::{StatusClass, ThemeExt};
 use crate::ui::widgets::render_screen_state;
 
 /// Configuration for rendering the health screen.
@@ -22,6 +31,12 @@ pub struct HealthRenderConfig<'a> {
     pub loading: bool,
     /// The health information to display
     pub health_info: Option<&'a HealthCheckOutput>,
+    /// Current view mode
+    pub view_mode: HealthViewMode,
+    /// Per-host resource usage and top processes
+    pub resource_usage: Option<&'a ResourceUsage>,
+    /// Thresholds the health data is flagged against
+    pub thresholds: &'a HealthThresholds,
     /// Theme for consistent styling.
     pub theme: &'a Theme,
     /// Current spinner frame for loading animation.
@@ -39,10 +54,18 @@ pub fn render_health(f: &mut Frame, area: Rect, config: HealthRenderConfig) {
     let HealthRenderConfig {
         loading,
         health_info,
+        view_mode,
+        resource_usage,
+        thresholds,
         theme,
         spinner_frame,
     } = config;
 
+    if view_mode == HealthViewMode::Resources {
+        render_resources(f, area, loading, resource_usage, theme, spinner_frame);
+        return;
+    }
+
     let Some(info) = render_screen_state(
         f,
         area,
@@ -57,7 +80,18 @@ pub fn render_health(f: &mut Frame, area: Rect, config: HealthRenderConfig) {
         return;
     };
 
-    let lines = build_health_lines(info, theme);
+    // Keep the fullest partition's gauge visible below the (possibly long) text.
+    let area = match &info.disk_partition {
+        Some(partition) => {
+            let [text_area, disk_area] =
+                Layout::vertical([Constraint::Min(3), Constraint::Length(3)]).areas(area);
+            render_disk_gauge(f, disk_area, partition, thresholds, theme);
+            text_area
+        }
+        None => area,
+    };
+
+    let lines = build_health_lines(info, thresholds, theme);
     let health_widget = Paragraph::new(lines)
         .block(
             Block::default()
@@ -71,7 +105,11 @@ pub fn render_health(f: &mut Frame, area: Rect, config: HealthRenderConfig) {
 }
 
 /// Build text content from health check output.
-fn build_health_lines(health: &HealthCheckOutput, theme: &Theme) -> Vec<Line<'static>> {
+fn build_health_lines(
+    health: &HealthCheckOutput,
+    thresholds: &HealthThresholds,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
     let mut lines: Vec<Line<'static>> = Vec::new();
 
     if let Some(server_info) = &health.server_info {
@@ -92,6 +130,17 @@ fn build_health_lines(health: &HealthCheckOutput, theme: &Theme) -> Vec<Line<'st
         )));
     }
 
+    let alerts = health.threshold_warnings(thresholds);
+    if !alerts.is_empty() {
+        push_section_lines(&mut lines, "Threshold Alerts", theme);
+        for alert in alerts {
+            lines.push(Line::from(Span::styled(
+                format!("  • {}", alert),
+                Style::default().fg(theme.warning),
+            )));
+        }
+    }
+
     if let Some(splunkd_health) = &health.splunkd_health {
         push_section_lines(&mut lines, "Splunkd Health", theme);
 
@@ -123,13 +172,10 @@ fn build_health_lines(health: &HealthCheckOutput, theme: &Theme) -> Vec<Line<'st
         push_section_lines(&mut lines, "License Usage", theme);
         for (i, usage) in license_usage.iter().enumerate() {
             let used_bytes = usage.effective_used_bytes();
-            let percentage = if usage.quota > 0 {
-                (used_bytes as f64 / usage.quota as f64) * 100.0
-            } else {
-                0.0
-            };
+            let percentage = usage.usage_percent().unwrap_or(0.0);
 
-            let (pct_text, pct_color) = percentage_span(percentage, theme);
+            let pct_text = format!("{:.1}%", percentage);
+            let pct_color = level_color(thresholds.license_level(percentage), theme);
             lines.push(Line::from(vec![
                 Span::raw(format!("Pool {}: ", i + 1)),
                 Span::styled(pct_text, Style::default().fg(pct_color)),
@@ -161,6 +207,43 @@ fn build_health_lines(health: &HealthCheckOutput, theme: &Theme) -> Vec<Line<'st
             kvstore_status.replication_status.oplog_used,
             kvstore_status.replication_status.oplog_size
         )));
+        if let Some(lag) = kvstore_status.replication_status.replication_lag_secs {
+            let level = if lag > thresholds.kvstore_max_replication_lag_secs as f64 {
+                ThresholdLevel::Warning
+            } else {
+                ThresholdLevel::Ok
+            };
+            lines.push(Line::from(vec![
+                Span::raw("Replication Lag: "),
+                Span::styled(
+                    format!(
+                        "{:.0}s (max {}s)",
+                        lag, thresholds.kvstore_max_replication_lag_secs
+                    ),
+                    Style::default().fg(level_color(level, theme)),
+                ),
+            ]));
+        }
+    }
+
+    if let Some(peers) = &health.cluster_peers {
+        push_section_lines(&mut lines, "Cluster Peers", theme);
+        let level = if peers.searchable < thresholds.min_searchable_peers as usize {
+            ThresholdLevel::Warning
+        } else {
+            ThresholdLevel::Ok
+        };
+        lines.push(Line::from(vec![
+            Span::raw("Searchable: "),
+            Span::styled(
+                format!(
+                    "{} / {} (min {})",
+                    peers.searchable, peers.total, thresholds.min_searchable_peers
+                ),
+                Style::default().fg(level_color(level, theme)),
+            ),
+        ]));
+        lines.push(Line::from(format!("Down: {}", peers.down)));
     }
 
     if let Some(log_parsing) = &health.log_parsing_health {
@@ -208,6 +291,303 @@ fn build_health_lines(health: &HealthCheckOutput, theme: &Theme) -> Vec<Line<'st
         }
     }
 
+    if let Some(scheduler) = &health.scheduler_health {
+        push_section_lines(&mut lines, "Scheduler Health", theme);
+
+        let (status_class, status_color) = if scheduler.is_healthy {
+            (StatusClass::Success, theme.success)
+        } else if scheduler.failed > 0 {
+            (StatusClass::Error, theme.error)
+        } else {
+            (StatusClass::Warning, theme.warning)
+        };
+
+        lines.push(Line::from(vec![
+            Span::raw("Status: "),
+            Span::styled(
+                theme.tagged(
+                    if scheduler.is_healthy {
+                        "Healthy"
+                    } else {
+                        "Unhealthy"
+                    },
+                    status_class,
+                ),
+                Style::default()
+                    .fg(status_color)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]));
+        lines.push(Line::from(format!(
+            "Runs: {} (succeeded {}, skipped {} [{:.1}%], failed {})",
+            scheduler.total_runs,
+            scheduler.succeeded,
+            scheduler.skipped,
+            scheduler.skip_ratio(),
+            scheduler.failed
+        )));
+        lines.push(Line::from(format!(
+            "Time Window: {}",
+            scheduler.time_window
+        )));
+
+        if !scheduler.searches.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "Skipped/Failed Searches:",
+                theme.title(),
+            )));
+            for search in scheduler.searches.iter().take(5) {
+                let mut line = format!(
+                    "  • {}/{}: {} skipped, {} failed",
+                    search.app, search.name, search.skipped, search.failed
+                );
+                if let Some(reason) = &search.last_reason {
+                    line.push_str(&format!(" ({})", reason));
+                }
+                lines.push(Line::from(line));
+            }
+            if scheduler.searches.len() > 5 {
+                lines.push(Line::from(format!(
+                    "  ... and {} more",
+                    scheduler.searches.len() - 5
+                )));
+            }
+        }
+    }
+
+    if let Some(queues) = &health.queue_health {
+        push_section_lines(&mut lines, "Queue Health", theme);
+
+        let (status_class, status_color) = if queues.is_healthy {
+            (StatusClass::Success, theme.success)
+        } else {
+            (StatusClass::Warning, theme.warning)
+        };
+        lines.push(Line::from(vec![
+            Span::raw("Status: "),
+            Span::styled(
+                theme.tagged(
+                    if queues.is_healthy {
+                        "Healthy"
+                    } else {
+                        "Unhealthy"
+                    },
+                    status_class,
+                ),
+                Style::default()
+                    .fg(status_color)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]));
+        lines.push(Line::from(format!(
+            "Blocked Samples: {} (since {})",
+            queues.blocked_count, queues.time_window
+        )));
+
+        if !queues.queues.is_empty() {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  {:<20} {:>4}  {:<16} {:>6} {:>6} {:>7}",
+                    "Host", "Pipe", "Queue", "Avg", "Max", "Blocked"
+                ),
+                theme.table_header(),
+            )));
+            for queue in queues.queues.iter().take(MAX_QUEUE_ROWS) {
+                let row = format!(
+                    "  {:<20} {:>4}  {:<16} {:>5.1}% {:>5.1}% {:>7}",
+                    queue.host,
+                    queue.pipeline,
+                    queue.queue,
+                    queue.avg_fill_pct,
+                    queue.max_fill_pct,
+                    queue.blocked_count
+                );
+                if queue.is_sustained_full() {
+                    lines.push(Line::from(vec![
+                        Span::styled(row, Style::default().fg(theme.warning)),
+                        Span::styled(
+                            " sustained >80%",
+                            Style::default()
+                                .fg(theme.warning)
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                    ]));
+                } else if queue.blocked_count > 0 {
+                    lines.push(Line::from(Span::styled(
+                        row,
+                        Style::default().fg(theme.warning),
+                    )));
+                } else {
+                    lines.push(Line::from(row));
+                }
+            }
+            if queues.queues.len() > MAX_QUEUE_ROWS {
+                lines.push(Line::from(format!(
+                    "  ... and {} more",
+                    queues.queues.len() - MAX_QUEUE_ROWS
+                )));
+            }
+        }
+    }
+
+    if let Some(security) = &health.security_health {
+        push_section_lines(&mut lines, "Security", theme);
+
+        let (status_class, status_color) = if security.is_healthy {
+            (StatusClass::Success, theme.success)
+        } else {
+            (StatusClass::Error, theme.error)
+        };
+        lines.push(Line::from(vec![
+            Span::raw("Status: "),
+            Span::styled(
+                theme.tagged(
+                    if security.is_healthy {
+                        "Healthy"
+                    } else {
+                        "Possible brute force"
+                    },
+                    status_class,
+                ),
+                Style::default()
+                    .fg(status_color)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]));
+        lines.push(Line::from(format!(
+            "Failed Logins: {} (since {}, threshold {} per IP)",
+            security.total_failures, security.time_window, security.threshold
+        )));
+
+        if !security.sources.is_empty() {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  {:<39} {:>8} {:>5}  {}",
+                    "Source IP", "Failures", "Users", "Last Seen"
+                ),
+                theme.table_header(),
+            )));
+            for source in security.sources.iter().take(MAX_SECURITY_ROWS) {
+                let row = format!(
+                    "  {:<39} {:>8} {:>5}  {}",
+                    source.src_ip, source.failures, source.distinct_users, source.last_seen
+                );
+                if security.is_flagged(source) {
+                    lines.push(Line::from(vec![
+                        Span::styled(row, Style::default().fg(theme.error)),
+                        Span::styled(
+                            " brute force?",
+                            Style::default()
+                                .fg(theme.error)
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                    ]));
+                } else {
+                    lines.push(Line::from(row));
+                }
+            }
+            if security.sources.len() > MAX_SECURITY_ROWS {
+                lines.push(Line::from(format!(
+                    "  ... and {} more",
+                    security.sources.len() - MAX_SECURITY_ROWS
+                )));
+            }
+        }
+    }
+
+    if let Some(load) = &health.search_load {
+        push_section_lines(&mut lines, "Search Load", theme);
+
+        let (status_class, status_color) = if load.is_healthy {
+            (StatusClass::Success, theme.success)
+        } else {
+            (StatusClass::Warning, theme.warning)
+        };
+        lines.push(Line::from(vec![
+            Span::raw("Status: "),
+            Span::styled(
+                theme.tagged(
+                    if load.is_healthy {
+                        "Healthy"
+                    } else {
+                        "Saturated"
+                    },
+                    status_class,
+                ),
+                Style::default()
+                    .fg(status_color)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]));
+        let historical_color = match load.historical_usage_percent() {
+            Some(pct) if pct >= 100.0 => theme.warning,
+            _ => theme.success,
+        };
+        lines.push(Line::from(vec![
+            Span::raw("Historical: "),
+            Span::styled(
+                format!(
+                    "{} / {} running",
+                    load.running.saturating_sub(load.realtime),
+                    limit_text(load.limits.max_hist_searches)
+                ),
+                Style::default().fg(historical_color),
+            ),
+        ]));
+        lines.push(Line::from(format!(
+            "Real-time: {} / {} running",
+            load.realtime,
+            limit_text(load.limits.max_rt_searches)
+        )));
+        let queued_color = if load.queued > 0 {
+            theme.warning
+        } else {
+            theme.success
+        };
+        lines.push(Line::from(vec![
+            Span::raw("Queued: "),
+            Span::styled(load.queued.to_string(), Style::default().fg(queued_color)),
+        ]));
+
+        if !load.users.is_empty() {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  {:<24} {:>7} {:>6} {:>5}",
+                    "User", "Running", "Queued", "Quota"
+                ),
+                theme.table_header(),
+            )));
+            for user in load.users.iter().take(MAX_SEARCH_LOAD_ROWS) {
+                let row = format!(
+                    "  {:<24} {:>7} {:>6} {:>5}",
+                    user.user,
+                    user.running,
+                    user.queued,
+                    limit_text(user.quota)
+                );
+                if user.is_at_quota() {
+                    lines.push(Line::from(vec![
+                        Span::styled(row, Style::default().fg(theme.warning)),
+                        Span::styled(
+                            " at quota",
+                            Style::default()
+                                .fg(theme.warning)
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                    ]));
+                } else {
+                    lines.push(Line::from(row));
+                }
+            }
+            if load.users.len() > MAX_SEARCH_LOAD_ROWS {
+                lines.push(Line::from(format!(
+                    "  ... and {} more",
+                    load.users.len() - MAX_SEARCH_LOAD_ROWS
+                )));
+            }
+        }
+    }
+
     if let Some(cb_states) = &health.circuit_breaker_states
         && !cb_states.is_empty()
     {
@@ -242,6 +622,220 @@ fn build_health_lines(health: &HealthCheckOutput, theme: &Theme) -> Vec<Line<'st
     lines
 }
 
+/// Queues listed in the overview; the fullest come first, so the rest are quiet.
+const MAX_QUEUE_ROWS: usize = 10;
+
+/// Source IPs listed in the overview; the most failures come first.
+const MAX_SECURITY_ROWS: usize = 10;
+
+/// Users listed in the search load section; the busiest come first.
+const MAX_SEARCH_LOAD_ROWS: usize = 10;
+
+/// A concurrency limit or quota, or `-` when splunkd did not report one.
+fn limit_text(limit: Option<u64>) -> String {
+    limit.map_or_else(|| "-".to_string(), |limit| limit.to_string())
+}
+
+/// Render the fullest partition's usage, colored by the disk thresholds.
+fn render_disk_gauge(
+    f: &mut Frame,
+    area: Rect,
+    partition: &PartitionSpace,
+    thresholds: &HealthThresholds,
+    theme: &Theme,
+) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "Disk - fullest partition (warn {}%, crit {}%)",
+            thresholds.disk_warning_pct, thresholds.disk_critical_pct
+        ))
+        .border_style(theme.border())
+        .title_style(theme.title());
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let Some(pct) = partition.used_percent() else {
+        f.render_widget(
+            Paragraph::new(format!("{}: capacity unknown", partition.mount_point))
+                .style(theme.text_dim()),
+            inner,
+        );
+        return;
+    };
+    let color = level_color(thresholds.disk_level(pct), theme);
+    let gauge = LineGauge::default()
+        .ratio((pct / 100.0).clamp(0.0, 1.0))
+        .label(format!(
+            "{} {:.1}% ({:.0}/{:.0} MB free)",
+            partition.mount_point, pct, partition.free_mb, partition.capacity_mb
+        ))
+        .filled_style(Style::default().fg(color))
+        .unfilled_style(theme.text_dim());
+    f.render_widget(gauge, inner);
+}
+
+/// Rows used by each host: a name row and the CPU, memory, and I/O wait gauges.
+const HOST_ROWS: u16 = 4;
+
+/// Render per-host resource gauges above a table of the busiest processes.
+fn render_resources(
+    f: &mut Frame,
+    area: Rect,
+    loading: bool,
+    usage: Option<&ResourceUsage>,
+    theme: &Theme,
+    spinner_frame: u8,
+) {
+    let title = if loading {
+        "Resources (Loading...)"
+    } else {
+        "Resources - Press 'u' for health overview"
+    };
+
+    let Some(usage) = render_screen_state(
+        f,
+        area,
+        loading,
+        usage,
+        title,
+        "Loading resource usage...",
+        "resource usage",
+        spinner_frame,
+        theme,
+    ) else {
+        return;
+    };
+
+    // Give the hosts what they need, but never more than 3/5 of the screen.
+    let hosts_height = (usage.hosts.len() as u16 * HOST_ROWS + 2)
+        .max(3)
+        .min(area.height * 3 / 5);
+    let [hosts_area, processes_area] =
+        Layout::vertical([Constraint::Length(hosts_height), Constraint::Min(3)]).areas(area);
+
+    let hosts_block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("{} - since {}", title, usage.time_window))
+        .border_style(theme.border())
+        .title_style(theme.title());
+    let inner = hosts_block.inner(hosts_area);
+    f.render_widget(hosts_block, hosts_area);
+
+    if usage.hosts.is_empty() {
+        f.render_widget(
+            Paragraph::new("No introspection data found.").alignment(Alignment::Center),
+            inner,
+        );
+    }
+    let mut y = inner.y;
+    for host in &usage.hosts {
+        if y + HOST_ROWS > inner.bottom() {
+            break;
+        }
+        render_host_gauges(
+            f,
+            Rect::new(inner.x, y, inner.width, HOST_ROWS),
+            host,
+            theme,
+        );
+        y += HOST_ROWS;
+    }
+
+    render_top_processes(f, processes_area, usage, theme);
+}
+
+/// Render one host's name row and its CPU, memory, and I/O wait gauges.
+fn render_host_gauges(f: &mut Frame, area: Rect, host: &HostResourceUsage, theme: &Theme) {
+    let mut name = host.host.clone();
+    if let Some(count) = host.cpu_count {
+        name.push_str(&format!(" ({} cores)", count));
+    }
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(name, theme.title()))),
+        Rect::new(area.x, area.y, area.width, 1),
+    );
+
+    let gauges = [
+        ("CPU", Some(host.cpu_pct), String::new()),
+        (
+            "Memory",
+            Some(host.mem_used_pct()),
+            format!(" ({:.0}/{:.0} MB)", host.mem_used_mb, host.mem_total_mb),
+        ),
+        ("I/O wait", host.iowait_pct, String::new()),
+    ];
+    for (row, (label, pct, detail)) in gauges.into_iter().enumerate() {
+        let row_area = Rect::new(area.x, area.y + 1 + row as u16, area.width, 1);
+        let Some(pct) = pct else {
+            f.render_widget(
+                Paragraph::new(format!("  {:<9}n/a", label)).style(theme.text_dim()),
+                row_area,
+            );
+            continue;
+        };
+        let (pct_text, color) = percentage_span(pct, theme);
+        let gauge = LineGauge::default()
+            .ratio((pct / 100.0).clamp(0.0, 1.0))
+            .label(format!("  {:<9}{:>6}{}", label, pct_text, detail))
+            .filled_style(Style::default().fg(color))
+            .unfilled_style(theme.text_dim());
+        f.render_widget(gauge, row_area);
+    }
+}
+
+/// Render the busiest processes, most CPU first.
+fn render_top_processes(f: &mut Frame, area: Rect, usage: &ResourceUsage, theme: &Theme) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Top Processes")
+        .border_style(theme.border())
+        .title_style(theme.title());
+
+    if usage.processes.is_empty() {
+        let paragraph = Paragraph::new("No process data found.")
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let header = Row::new(
+        ["Host", "Process", "Type", "PID", "CPU %", "Memory MB"]
+            .iter()
+            .map(|h| Cell::from(*h).style(theme.table_header())),
+    )
+    .height(1);
+
+    let rows: Vec<Row> = usage
+        .processes
+        .iter()
+        .map(|process| {
+            Row::new(vec![
+                Cell::from(process.host.clone()),
+                Cell::from(process.process.clone()),
+                Cell::from(process.process_type.clone()),
+                Cell::from(process.pid.clone()),
+                Cell::from(format!("{:.1}", process.cpu_pct)),
+                Cell::from(format!("{:.1}", process.mem_used_mb)),
+            ])
+            .height(1)
+        })
+        .collect();
+
+    let constraints = [
+        Constraint::Min(12),    // Host
+        Constraint::Length(14), // Process
+        Constraint::Length(18), // Type
+        Constraint::Length(8),  // PID
+        Constraint::Length(8),  // CPU %
+        Constraint::Length(10), // Memory MB
+    ];
+
+    let table = Table::new(rows, constraints).header(header).block(block);
+    f.render_widget(table, area);
+}
+
 /// Add a section header to the text.
 fn push_section_lines(lines: &mut Vec<Line<'static>>, title: &str, theme: &Theme) {
     if !lines.is_empty() {
@@ -250,6 +844,15 @@ fn push_section_lines(lines: &mut Vec<Line<'static>>, title: &str, theme: &Theme
     lines.push(Line::from(Span::styled(title.to_string(), theme.title())));
 }
 
+/// Semantic color for a threshold level.
+fn level_color(level: ThresholdLevel, theme: &Theme) -> ratatui::style::Color {
+    match level {
+        ThresholdLevel::Ok => theme.success,
+        ThresholdLevel::Warning => theme.warning,
+        ThresholdLevel::Critical => theme.error,
+    }
+}
+
 /// Format license usage percentage and choose a semantic color.
 fn percentage_span(percentage: f64, theme: &Theme) -> (String, ratatui::style::Color) {
     let color = if percentage < 70.0 {
@@ -263,146 +866,5 @@ fn percentage_span(percentage: f64, theme: &Theme) -> (String, ratatui::style::C
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-    use splunk_client::models::{LicenseUsage, LogParsingError, LogParsingHealth, ServerInfo};
-
-    fn flatten_lines(lines: Vec<Line>) -> String {
-        lines
-            .into_iter()
-            .map(|l| {
-                l.spans
-                    .into_iter()
-                    .map(|s| s.content.to_string())
-                    .collect::<String>()
-            })
-            .collect::<Vec<String>>()
-            .join("\n")
-    }
-
-    #[test]
-    fn test_format_bytes() {
-        assert_eq!(format_bytes(0), "0 B");
-        assert_eq!(format_bytes(512), "512 B");
-        assert_eq!(format_bytes(1024), "1.0 KB");
-        assert_eq!(format_bytes(1536), "1.5 KB");
-        assert_eq!(format_bytes(1024 * 1024), "1.0 MB");
-        assert_eq!(format_bytes(1024 * 1024 * 1024), "1.0 GB");
-        assert_eq!(format_bytes(1024 * 1024 * 1024 * 1024), "1.0 TB");
-    }
-
-    #[test]
-    fn test_build_health_text_empty() {
-        let health = HealthCheckOutput {
-            server_info: None,
-            splunkd_health: None,
-            license_usage: None,
-            kvstore_status: None,
-            log_parsing_health: None,
-            circuit_breaker_states: None,
-        };
-        let lines = build_health_lines(&health, &Theme::default());
-        assert_eq!(lines.len(), 1);
-        assert!(
-            lines[0].spans[0]
-                .content
-                .contains("No health data available.")
-        );
-    }
-
-    #[test]
-    fn test_build_health_text_with_server_info() {
-        let health = HealthCheckOutput {
-            server_info: Some(ServerInfo {
-                server_name: "splunk01".to_string(),
-                version: "9.0.0".to_string(),
-                build: "abc123".to_string(),
-                mode: Some(splunk_client::models::ServerMode::Standalone),
-                server_roles: vec![],
-                os_name: Some("Linux".to_string()),
-            }),
-            splunkd_health: None,
-            license_usage: None,
-            kvstore_status: None,
-            log_parsing_health: None,
-            circuit_breaker_states: None,
-        };
-        let lines = build_health_lines(&health, &Theme::default());
-        let text = flatten_lines(lines);
-        assert!(text.contains("Server Information"));
-        assert!(text.contains("Name: splunk01"));
-        assert!(text.contains("Version: 9.0.0"));
-    }
-
-    #[test]
-    fn test_build_health_text_with_license() {
-        let health = HealthCheckOutput {
-            server_info: None,
-            splunkd_health: None,
-            license_usage: Some(vec![LicenseUsage {
-                name: "test_license".to_string(),
-                quota: 1024 * 1024 * 1024,           // 1 GB
-                used_bytes: Some(512 * 1024 * 1024), // 512 MB
-                slaves_usage_bytes: None,
-                stack_id: None,
-            }]),
-            kvstore_status: None,
-            log_parsing_health: None,
-            circuit_breaker_states: None,
-        };
-        let lines = build_health_lines(&health, &Theme::default());
-        let text = flatten_lines(lines);
-        assert!(text.contains("License Usage"));
-        assert!(text.contains("50.0%"));
-    }
-
-    #[test]
-    fn test_build_health_text_with_log_parsing() {
-        let health = HealthCheckOutput {
-            server_info: None,
-            splunkd_health: None,
-            license_usage: None,
-            kvstore_status: None,
-            log_parsing_health: Some(LogParsingHealth {
-                is_healthy: false,
-                total_errors: 3,
-                errors: vec![
-                    LogParsingError {
-                        time: "2025-01-20T10:00:00".to_string(),
-                        source: "/var/log/splunk/metrics.log".to_string(),
-                        sourcetype: "splunkd".to_string(),
-                        message: "Failed to parse timestamp".to_string(),
-                        log_level: splunk_client::models::LogLevel::Error,
-                        component: "DateParser".to_string(),
-                    },
-                    LogParsingError {
-                        time: "2025-01-20T10:01:00".to_string(),
-                        source: "/var/log/splunk/metrics.log".to_string(),
-                        sourcetype: "splunkd".to_string(),
-                        message: "Invalid timestamp format".to_string(),
-                        log_level: splunk_client::models::LogLevel::Error,
-                        component: "DateParser".to_string(),
-                    },
-                    LogParsingError {
-                        time: "2025-01-20T10:02:00".to_string(),
-                        source: "/var/log/splunk/metrics.log".to_string(),
-                        sourcetype: "splunkd".to_string(),
-                        message: "Timestamp out of range".to_string(),
-                        log_level: splunk_client::models::LogLevel::Error,
-                        component: "DateParser".to_string(),
-                    },
-                ],
-                time_window: "-24h".to_string(),
-            }),
-            circuit_breaker_states: None,
-        };
-        let lines = build_health_lines(&health, &Theme::default());
-        let text = flatten_lines(lines);
-        assert!(text.contains("Log Parsing Health"));
-        assert!(text.contains("Total Errors: 3"));
-        assert!(text.contains("Recent Errors:"));
-        assert!(text.contains("Failed to parse timestamp"));
-        assert!(text.contains("Invalid timestamp format"));
-        assert!(text.contains("Timestamp out of range"));
-    }
-}
+#[path = "health_tests.rs"]
+mod tests;
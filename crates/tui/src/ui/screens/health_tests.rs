@@ -0,0 +1,528 @@
+//! Unit tests for health screen text building and resource gauge rendering.
+
+use super::*;
+use splunk_client::models::{
+    LicenseUsage, LogParsingError, LogParsingHealth, ScheduledSearchHealth, SchedulerHealth,
+    ServerInfo,
+};
+
+fn flatten_lines(lines: Vec<Line>) -> String {
+    lines
+        .into_iter()
+        .map(|l| {
+            l.spans
+                .into_iter()
+                .map(|s| s.content.to_string())
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[test]
+fn test_format_bytes() {
+    assert_eq!(format_bytes(0), "0 B");
+    assert_eq!(format_bytes(512), "512 B");
+    assert_eq!(format_bytes(1024), "1.0 KB");
+    assert_eq!(format_bytes(1536), "1.5 KB");
+    assert_eq!(format_bytes(1024 * 1024), "1.0 MB");
+    assert_eq!(format_bytes(1024 * 1024 * 1024), "1.0 GB");
+    assert_eq!(format_bytes(1024 * 1024 * 1024 * 1024), "1.0 TB");
+}
+
+#[test]
+fn test_build_health_text_empty() {
+    let health = HealthCheckOutput {
+        server_info: None,
+        splunkd_health: None,
+        license_usage: None,
+        kvstore_status: None,
+        log_parsing_health: None,
+        scheduler_health: None,
+        queue_health: None,
+        security_health: None,
+        circuit_breaker_states: None,
+        cluster_peers: None,
+        disk_partition: None,
+        search_load: None,
+    };
+    let lines = build_health_lines(&health, &HealthThresholds::default(), &Theme::default());
+    assert_eq!(lines.len(), 1);
+    assert!(
+        lines[0].spans[0]
+            .content
+            .contains("No health data available.")
+    );
+}
+
+#[test]
+fn test_build_health_text_with_server_info() {
+    let health = HealthCheckOutput {
+        server_info: Some(ServerInfo {
+            server_name: "splunk01".to_string(),
+            version: "9.0.0".to_string(),
+            build: "abc123".to_string(),
+            mode: Some(splunk_client::models::ServerMode::Standalone),
+            server_roles: vec![],
+            os_name: Some("Linux".to_string()),
+            restart_required: false,
+            startup_time: None,
+            instance_type: None,
+        }),
+        splunkd_health: None,
+        license_usage: None,
+        kvstore_status: None,
+        log_parsing_health: None,
+        scheduler_health: None,
+        queue_health: None,
+        security_health: None,
+        circuit_breaker_states: None,
+        cluster_peers: None,
+        disk_partition: None,
+        search_load: None,
+    };
+    let lines = build_health_lines(&health, &HealthThresholds::default(), &Theme::default());
+    let text = flatten_lines(lines);
+    assert!(text.contains("Server Information"));
+    assert!(text.contains("Name: splunk01"));
+    assert!(text.contains("Version: 9.0.0"));
+}
+
+#[test]
+fn test_build_health_text_flags_thresholds() {
+    let health = HealthCheckOutput {
+        server_info: None,
+        splunkd_health: None,
+        license_usage: Some(vec![LicenseUsage {
+            name: "pool".to_string(),
+            quota: 1000,
+            used_bytes: Some(850),
+            slaves_usage_bytes: None,
+            stack_id: Some("enterprise".to_string()),
+        }]),
+        kvstore_status: None,
+        log_parsing_health: None,
+        scheduler_health: None,
+        queue_health: None,
+        security_health: None,
+        cluster_peers: Some(splunk_client::models::ClusterPeerHealth {
+            total: 3,
+            searchable: 2,
+            down: 1,
+        }),
+        circuit_breaker_states: None,
+        disk_partition: None,
+        search_load: None,
+    };
+    let thresholds = HealthThresholds {
+        min_searchable_peers: 3,
+        ..Default::default()
+    };
+
+    let text = flatten_lines(build_health_lines(&health, &thresholds, &Theme::default()));
+    assert!(text.contains("Threshold Alerts"));
+    assert!(text.contains("License stack enterprise is at 85.0% of its daily quota (warning)"));
+    assert!(text.contains("Searchable: 2 / 3 (min 3)"));
+
+    let relaxed = HealthThresholds {
+        license_warning_pct: 90.0,
+        license_critical_pct: 95.0,
+        min_searchable_peers: 2,
+        ..Default::default()
+    };
+    let text = flatten_lines(build_health_lines(&health, &relaxed, &Theme::default()));
+    assert!(!text.contains("Threshold Alerts"));
+}
+
+#[test]
+fn test_build_health_text_with_license() {
+    let health = HealthCheckOutput {
+        server_info: None,
+        splunkd_health: None,
+        license_usage: Some(vec![LicenseUsage {
+            name: "test_license".to_string(),
+            quota: 1024 * 1024 * 1024,           // 1 GB
+            used_bytes: Some(512 * 1024 * 1024), // 512 MB
+            slaves_usage_bytes: None,
+            stack_id: None,
+        }]),
+        kvstore_status: None,
+        log_parsing_health: None,
+        scheduler_health: None,
+        queue_health: None,
+        security_health: None,
+        circuit_breaker_states: None,
+        cluster_peers: None,
+        disk_partition: None,
+        search_load: None,
+    };
+    let lines = build_health_lines(&health, &HealthThresholds::default(), &Theme::default());
+    let text = flatten_lines(lines);
+    assert!(text.contains("License Usage"));
+    assert!(text.contains("50.0%"));
+}
+
+#[test]
+fn test_build_health_text_with_log_parsing() {
+    let health = HealthCheckOutput {
+        server_info: None,
+        splunkd_health: None,
+        license_usage: None,
+        kvstore_status: None,
+        log_parsing_health: Some(LogParsingHealth {
+            is_healthy: false,
+            total_errors: 3,
+            errors: vec![
+                LogParsingError {
+                    time: "2025-01-20T10:00:00".to_string(),
+                    source: "/var/log/splunk/metrics.log".to_string(),
+                    sourcetype: "splunkd".to_string(),
+                    message: "Failed to parse timestamp".to_string(),
+                    log_level: splunk_client::models::LogLevel::Error,
+                    component: "DateParser".to_string(),
+                },
+                LogParsingError {
+                    time: "2025-01-20T10:01:00".to_string(),
+                    source: "/var/log/splunk/metrics.log".to_string(),
+                    sourcetype: "splunkd".to_string(),
+                    message: "Invalid timestamp format".to_string(),
+                    log_level: splunk_client::models::LogLevel::Error,
+                    component: "DateParser".to_string(),
+                },
+                LogParsingError {
+                    time: "2025-01-20T10:02:00".to_string(),
+                    source: "/var/log/splunk/metrics.log".to_string(),
+                    sourcetype: "splunkd".to_string(),
+                    message: "Timestamp out of range".to_string(),
+                    log_level: splunk_client::models::LogLevel::Error,
+                    component: "DateParser".to_string(),
+                },
+            ],
+            time_window: "-24h".to_string(),
+        }),
+        scheduler_health: None,
+        queue_health: None,
+        security_health: None,
+        circuit_breaker_states: None,
+        cluster_peers: None,
+        disk_partition: None,
+        search_load: None,
+    };
+    let lines = build_health_lines(&health, &HealthThresholds::default(), &Theme::default());
+    let text = flatten_lines(lines);
+    assert!(text.contains("Log Parsing Health"));
+    assert!(text.contains("Total Errors: 3"));
+    assert!(text.contains("Recent Errors:"));
+    assert!(text.contains("Failed to parse timestamp"));
+    assert!(text.contains("Invalid timestamp format"));
+    assert!(text.contains("Timestamp out of range"));
+}
+
+#[test]
+fn test_build_health_text_with_scheduler_health() {
+    let search = |name: &str, skipped: u64, failed: u64| ScheduledSearchHealth {
+        app: "search".to_string(),
+        name: name.to_string(),
+        owner: "admin".to_string(),
+        runs: 24,
+        succeeded: 24 - skipped - failed,
+        skipped,
+        failed,
+        last_reason: Some("concurrency limit reached".to_string()),
+        last_run: None,
+    };
+    let health = HealthCheckOutput {
+        server_info: None,
+        splunkd_health: None,
+        license_usage: None,
+        kvstore_status: None,
+        log_parsing_health: None,
+        scheduler_health: Some(SchedulerHealth::from_searches(
+            "-24h".to_string(),
+            vec![
+                search("Errors Last Hour", 4, 0),
+                search("Nightly Rollup", 0, 0),
+            ],
+        )),
+        queue_health: None,
+        security_health: None,
+        circuit_breaker_states: None,
+        cluster_peers: None,
+        disk_partition: None,
+        search_load: None,
+    };
+    let lines = build_health_lines(&health, &HealthThresholds::default(), &Theme::default());
+    let text = flatten_lines(lines);
+    assert!(text.contains("Scheduler Health"));
+    assert!(text.contains("Status: Unhealthy"));
+    assert!(text.contains("Runs: 48 (succeeded 44, skipped 4 [8.3%], failed 0)"));
+    assert!(
+        text.contains("search/Errors Last Hour: 4 skipped, 0 failed (concurrency limit reached)")
+    );
+    assert!(!text.contains("Nightly Rollup"));
+}
+
+#[test]
+fn test_build_health_text_with_queue_health() {
+    use splunk_client::models::{PipelineQueueHealth, QueueHealth};
+
+    let queue = |name: &str, avg_fill_pct: f64| PipelineQueueHealth {
+        host: "idx01".to_string(),
+        pipeline: "0".to_string(),
+        queue: name.to_string(),
+        avg_fill_pct,
+        max_fill_pct: 100.0,
+        blocked_count: 2,
+        samples: 30,
+    };
+    let health = HealthCheckOutput {
+        server_info: None,
+        splunkd_health: None,
+        license_usage: None,
+        kvstore_status: None,
+        log_parsing_health: None,
+        scheduler_health: None,
+        queue_health: Some(QueueHealth::from_queues(
+            "-15m".to_string(),
+            vec![queue("parsingqueue", 12.0), queue("indexqueue", 91.5)],
+        )),
+        security_health: None,
+        circuit_breaker_states: None,
+        cluster_peers: None,
+        disk_partition: None,
+        search_load: None,
+    };
+    let text = flatten_lines(build_health_lines(
+        &health,
+        &HealthThresholds::default(),
+        &Theme::default(),
+    ));
+    assert!(text.contains("Queue Health"));
+    assert!(text.contains("Blocked Samples: 4 (since -15m)"));
+    assert!(text.contains("indexqueue        91.5% 100.0%       2 sustained >80%"));
+    assert!(!text.contains("parsingqueue        12.0% 100.0%       2 sustained"));
+    let index_pos = text.find("indexqueue").unwrap();
+    assert!(index_pos < text.find("parsingqueue").unwrap());
+}
+
+#[test]
+fn test_build_health_text_with_security_health() {
+    use splunk_client::models::{FailedLoginSource, SecurityHealth};
+
+    let source = |src_ip: &str, failures: u64| FailedLoginSource {
+        src_ip: src_ip.to_string(),
+        failures,
+        distinct_users: 2,
+        first_seen: "2025-01-20T10:00:00+0000".to_string(),
+        last_seen: "2025-01-20T10:30:00+0000".to_string(),
+    };
+    let health = HealthCheckOutput {
+        server_info: None,
+        splunkd_health: None,
+        license_usage: None,
+        kvstore_status: None,
+        log_parsing_health: None,
+        scheduler_health: None,
+        queue_health: None,
+        security_health: Some(SecurityHealth::from_sources(
+            "-1h".to_string(),
+            20,
+            vec![source("10.0.0.1", 3), source("203.0.113.7", 45)],
+        )),
+        circuit_breaker_states: None,
+        cluster_peers: None,
+        disk_partition: None,
+        search_load: None,
+    };
+    let text = flatten_lines(build_health_lines(
+        &health,
+        &HealthThresholds::default(),
+        &Theme::default(),
+    ));
+    assert!(text.contains("Security"));
+    assert!(text.contains("Failed Logins: 48 (since -1h, threshold 20 per IP)"));
+    assert!(text.contains("203.0.113.7"));
+    assert!(text.contains("45     2  2025-01-20T10:30:00+0000 brute force?"));
+    assert!(!text.contains("3     2  2025-01-20T10:30:00+0000 brute force?"));
+    let flagged_pos = text.find("203.0.113.7").unwrap();
+    assert!(flagged_pos < text.find("10.0.0.1").unwrap());
+}
+
+#[test]
+fn test_build_health_text_with_search_load() {
+    use splunk_client::models::{SearchConcurrencyLimits, SearchLoad, UserSearchUsage};
+
+    let user = |name: &str, running: u64, queued: u64, quota: Option<u64>| UserSearchUsage {
+        user: name.to_string(),
+        running,
+        queued,
+        realtime: 0,
+        quota,
+    };
+    let health = HealthCheckOutput {
+        server_info: None,
+        splunkd_health: None,
+        license_usage: None,
+        kvstore_status: None,
+        log_parsing_health: None,
+        scheduler_health: None,
+        queue_health: None,
+        security_health: None,
+        circuit_breaker_states: None,
+        cluster_peers: None,
+        disk_partition: None,
+        search_load: Some(SearchLoad::new(
+            SearchConcurrencyLimits {
+                max_hist_searches: Some(26),
+                ..Default::default()
+            },
+            vec![user("admin", 1, 0, None), user("analyst", 3, 2, Some(3))],
+        )),
+    };
+    let text = flatten_lines(build_health_lines(
+        &health,
+        &HealthThresholds::default(),
+        &Theme::default(),
+    ));
+    assert!(text.contains("Search Load"));
+    assert!(text.contains("Historical: 4 / 26 running"));
+    assert!(text.contains("Real-time: 0 / - running"));
+    assert!(text.contains("Queued: 2"));
+    assert!(text.contains("analyst"));
+    assert!(text.contains("3      2     3 at quota"));
+    assert!(!text.contains("1      0     - at quota"));
+    assert!(text.find("analyst").unwrap() < text.find("admin").unwrap());
+}
+
+#[test]
+fn test_render_resources_shows_gauges_and_processes() {
+    use ratatui::backend::TestBackend;
+    use splunk_client::models::ProcessResourceUsage;
+
+    let usage = ResourceUsage::new(
+        "-15m".to_string(),
+        None,
+        vec![HostResourceUsage {
+            host: "idx01".to_string(),
+            cpu_pct: 42.5,
+            mem_used_mb: 4096.0,
+            mem_total_mb: 8192.0,
+            iowait_pct: None,
+            cpu_count: Some(4),
+            last_seen: None,
+        }],
+        vec![ProcessResourceUsage {
+            host: "idx01".to_string(),
+            process: "splunkd".to_string(),
+            process_type: "search".to_string(),
+            pid: "4242".to_string(),
+            cpu_pct: 97.0,
+            mem_used_mb: 512.0,
+        }],
+    );
+    let mut terminal = ratatui::Terminal::new(TestBackend::new(100, 20)).unwrap();
+
+    terminal
+        .draw(|f| {
+            render_health(
+                f,
+                f.area(),
+                HealthRenderConfig {
+                    loading: false,
+                    health_info: None,
+                    view_mode: HealthViewMode::Resources,
+                    resource_usage: Some(&usage),
+                    thresholds: &HealthThresholds::default(),
+                    theme: &Theme::default(),
+                    spinner_frame: 0,
+                },
+            );
+        })
+        .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let screen: String = (0..buffer.area.height)
+        .map(|y| {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert!(screen.contains("since -15m"), "{screen}");
+    assert!(screen.contains("idx01 (4 cores)"), "{screen}");
+    assert!(screen.contains("CPU       42.5%"), "{screen}");
+    assert!(
+        screen.contains("Memory    50.0% (4096/8192 MB)"),
+        "{screen}"
+    );
+    assert!(screen.contains("I/O wait n/a"), "{screen}");
+    assert!(screen.contains("Top Processes"), "{screen}");
+    assert!(screen.contains("4242"), "{screen}");
+}
+
+#[test]
+fn test_render_health_shows_disk_gauge_and_alert() {
+    use ratatui::backend::TestBackend;
+
+    let health = HealthCheckOutput {
+        server_info: None,
+        splunkd_health: None,
+        license_usage: None,
+        kvstore_status: None,
+        log_parsing_health: None,
+        scheduler_health: None,
+        queue_health: None,
+        security_health: None,
+        circuit_breaker_states: None,
+        cluster_peers: None,
+        disk_partition: Some(PartitionSpace {
+            mount_point: "/opt/splunk".to_string(),
+            fs_type: Some("ext4".to_string()),
+            capacity_mb: 1000.0,
+            free_mb: 80.0,
+        }),
+        search_load: None,
+    };
+    let mut terminal = ratatui::Terminal::new(TestBackend::new(100, 20)).unwrap();
+
+    terminal
+        .draw(|f| {
+            render_health(
+                f,
+                f.area(),
+                HealthRenderConfig {
+                    loading: false,
+                    health_info: Some(&health),
+                    view_mode: HealthViewMode::Overview,
+                    resource_usage: None,
+                    thresholds: &HealthThresholds::default(),
+                    theme: &Theme::default(),
+                    spinner_frame: 0,
+                },
+            );
+        })
+        .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let screen: String = (0..buffer.area.height)
+        .map(|y| {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert!(
+        screen.contains("Disk - fullest partition (warn 80%, crit 90%)"),
+        "{screen}"
+    );
+    assert!(
+        screen.contains("/opt/splunk 92.0% (80/1000 MB free)"),
+        "{screen}"
+    );
+    assert!(
+        screen.contains("Partition /opt/splunk is 92.0% full (80 MB free, critical)"),
+        "{screen}"
+    );
+}
@@ -0,0 +1,194 @@
+//! Ingestion screen for the TUI.
+//!
+//! Responsibilities:
+//! - Render indexing latency and thruput per sourcetype as a sortable table
+//! - Mark the sorted column with its direction
+//! - Display loading state and empty state
+//!
+//! Does NOT handle:
+//! - Data fetching (handled by side effects)
+//! - Sorting the rows (handled by the app)
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Rect},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+use splunk_client::IngestionStats;
+
+use crate::app::state::{IngestionSortColumn, IngestionSortState, SortDirection};
+use crate::theme::Theme;
+use crate::ui::theme::ThemeExt;
+use crate::ui::widgets::render_screen_state;
+
+/// Configuration for rendering the ingestion screen.
+pub struct IngestionRenderConfig<'a> {
+    /// Whether data is currently loading.
+    pub loading: bool,
+    /// Latency and thruput per sourcetype, already sorted.
+    pub ingestion: Option<&'a IngestionStats>,
+    /// Current sort column and direction, shown in the header.
+    pub sort: IngestionSortState,
+    /// The table state for selection.
+    pub state: &'a mut TableState,
+    /// The theme to use for styling.
+    pub theme: &'a Theme,
+    /// Current spinner frame for loading animation
+    pub spinner_frame: u8,
+}
+
+/// Render the ingestion screen.
+pub fn render_ingestion(f: &mut Frame, area: Rect, config: IngestionRenderConfig) {
+    let IngestionRenderConfig {
+        loading,
+        ingestion,
+        sort,
+        state,
+        theme,
+        spinner_frame,
+    } = config;
+
+    let Some(ingestion) = render_screen_state(
+        f,
+        area,
+        loading,
+        ingestion,
+        "Ingestion",
+        "Loading ingestion stats...",
+        "ingestion stats",
+        spinner_frame,
+        theme,
+    ) else {
+        return;
+    };
+
+    let title = format!(
+        "Ingestion by Sourcetype (last {}m)",
+        ingestion.window_secs / 60
+    );
+
+    if ingestion.stats.is_empty() {
+        let empty = Paragraph::new("No events indexed in the window.")
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .alignment(Alignment::Center);
+        f.render_widget(empty, area);
+        return;
+    }
+
+    let indicator = match sort.direction {
+        SortDirection::Asc => "↑",
+        SortDirection::Desc => "↓",
+    };
+    let header_cells = [
+        ("Sourcetype", IngestionSortColumn::Name),
+        ("Events", IngestionSortColumn::Events),
+        ("KB/s", IngestionSortColumn::Thruput),
+        ("Avg Latency", IngestionSortColumn::AvgLatency),
+        ("Max Latency", IngestionSortColumn::MaxLatency),
+    ]
+    .into_iter()
+    .map(|(label, column)| {
+        let text = if sort.column == column {
+            format!("{} {}", label, indicator)
+        } else {
+            label.to_string()
+        };
+        Cell::from(text).style(theme.table_header())
+    });
+    let header = Row::new(header_cells).height(1);
+
+    let rows: Vec<Row> = ingestion
+        .stats
+        .iter()
+        .map(|stat| {
+            Row::new(vec![
+                Cell::from(stat.name.clone()),
+                Cell::from(stat.events.to_string()),
+                Cell::from(format!("{:.2}", stat.kb_per_sec)),
+                Cell::from(format_latency(stat.avg_latency_secs)),
+                Cell::from(format_latency(stat.max_latency_secs)),
+            ])
+            .height(1)
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(36),
+            Constraint::Percentage(16),
+            Constraint::Percentage(16),
+            Constraint::Percentage(16),
+            Constraint::Percentage(16),
+        ],
+    )
+    .header(header)
+    .block(Block::default().borders(Borders::ALL).title(title))
+    .row_highlight_style(theme.highlight())
+    .highlight_symbol("> ");
+
+    f.render_stateful_widget(table, area, state);
+}
+
+/// Latency in seconds, or `-` when no events were searchable.
+fn format_latency(secs: Option<f64>) -> String {
+    secs.map(|s| format!("{:.1}s", s))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use splunk_client::{IngestionGroupBy, IngestionStat};
+
+    #[test]
+    fn test_render_ingestion_marks_sorted_column() {
+        let ingestion = IngestionStats {
+            group_by: IngestionGroupBy::Sourcetype,
+            window_secs: 3600,
+            stats: vec![IngestionStat {
+                name: "access_combined".to_string(),
+                events: 7200,
+                kb: 3600.0,
+                kb_per_sec: 1.0,
+                events_per_sec: 2.0,
+                avg_latency_secs: Some(2.5),
+                max_latency_secs: None,
+            }],
+        };
+        let mut state = TableState::default();
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(100, 6)).unwrap();
+
+        terminal
+            .draw(|f| {
+                render_ingestion(
+                    f,
+                    f.area(),
+                    IngestionRenderConfig {
+                        loading: false,
+                        ingestion: Some(&ingestion),
+                        sort: IngestionSortState::default(),
+                        state: &mut state,
+                        theme: &Theme::default(),
+                        spinner_frame: 0,
+                    },
+                );
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let screen: String = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(screen.contains("(last 60m)"), "{screen}");
+        assert!(screen.contains("KB/s ↓"), "{screen}");
+        assert!(screen.contains("access_combined"), "{screen}");
+        assert!(screen.contains("2.5s"), "{screen}");
+    }
+}
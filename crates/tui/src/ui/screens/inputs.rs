@@ -1,17 +1,21 @@
 //! Inputs screen rendering.
 //!
-//! Renders the list of Splunk data inputs with their types and status.
+//! Renders the list of Splunk data inputs with their types and status, plus
+//! per-input throughput once it has been loaded from metrics.log. The HTTP
+//! view lists HEC tokens instead, with values masked until revealed.
 
+use crate::app::InputsViewMode;
 use crate::theme::Theme;
 use crate::ui::theme::ThemeExt;
 use crate::ui::widgets::render_screen_state;
 use ratatui::{
     Frame,
-    layout::{Alignment, Rect},
+    layout::{Alignment, Constraint, Rect},
     text::{Line, Span},
     widgets::{Block, Borders, Cell, Row, Table, TableState},
 };
-use splunk_client::models::Input;
+use secrecy::ExposeSecret;
+use splunk_client::models::{HecToken, Input, ThroughputReport};
 
 /// Configuration for rendering the inputs screen.
 pub struct InputsRenderConfig<'a> {
@@ -19,8 +23,18 @@ pub struct InputsRenderConfig<'a> {
     pub loading: bool,
     /// The list of inputs to display
     pub inputs: Option<&'a [Input]>,
+    /// Per-source throughput over the last hour, when loaded
+    pub throughput: Option<&'a ThroughputReport>,
     /// The current table selection state
     pub state: &'a mut TableState,
+    /// Whether the data inputs or the HTTP inputs (HEC tokens) are shown
+    pub view_mode: InputsViewMode,
+    /// The list of HEC tokens to display in the HTTP view
+    pub hec_tokens: Option<&'a [HecToken]>,
+    /// The HEC token table selection state
+    pub hec_tokens_state: &'a mut TableState,
+    /// Full name of the token whose value is currently revealed
+    pub revealed_hec_token: Option<&'a str>,
     /// Theme for consistent styling.
     pub theme: &'a Theme,
     /// Current spinner frame for loading animation
@@ -38,11 +52,30 @@ pub fn render_inputs(f: &mut Frame, area: Rect, config: InputsRenderConfig) {
     let InputsRenderConfig {
         loading,
         inputs,
+        throughput,
         state,
+        view_mode,
+        hec_tokens,
+        hec_tokens_state,
+        revealed_hec_token,
         theme,
         spinner_frame,
     } = config;
 
+    if view_mode == InputsViewMode::Http {
+        render_hec_tokens(
+            f,
+            area,
+            loading,
+            hec_tokens,
+            hec_tokens_state,
+            revealed_hec_token,
+            theme,
+            spinner_frame,
+        );
+        return;
+    }
+
     let Some(inputs) = render_screen_state(
         f,
         area,
@@ -66,14 +99,19 @@ pub fn render_inputs(f: &mut Frame, area: Rect, config: InputsRenderConfig) {
     }
 
     // Header
-    let header = Row::new(vec![
+    let mut header_cells = vec![
         Cell::from("Name").style(theme.table_header()),
         Cell::from("Type").style(theme.table_header()),
         Cell::from("Host").style(theme.table_header()),
         Cell::from("Source").style(theme.table_header()),
         Cell::from("Sourcetype").style(theme.table_header()),
-        Cell::from("Status").style(theme.table_header()),
-    ]);
+    ];
+    if throughput.is_some() {
+        header_cells.push(Cell::from("KB/s").style(theme.table_header()));
+        header_cells.push(Cell::from("Ev/s").style(theme.table_header()));
+    }
+    header_cells.push(Cell::from("Status").style(theme.table_header()));
+    let header = Row::new(header_cells);
 
     // Rows
     let rows: Vec<Row> = inputs
@@ -81,41 +119,184 @@ pub fn render_inputs(f: &mut Frame, area: Rect, config: InputsRenderConfig) {
         .map(|input| {
             let status = if input.disabled {
                 Span::styled("Disabled", theme.error())
+            } else if throughput.is_some_and(|report| report.is_silent(input)) {
+                Span::styled("Silent", theme.warning())
             } else {
                 Span::styled("Enabled", theme.success())
             };
 
-            Row::new(vec![
+            let mut cells = vec![
                 Cell::from(input.name.as_str()),
                 Cell::from(input.input_type.to_string()),
                 Cell::from(input.host.as_deref().unwrap_or("-")),
                 Cell::from(input.source.as_deref().unwrap_or("-")),
                 Cell::from(input.sourcetype.as_deref().unwrap_or("-")),
+            ];
+            if let Some(report) = throughput {
+                // Inputs that cannot be matched to a metrics.log series show "-".
+                let rates = report.for_input(input);
+                cells.push(Cell::from(rates.map_or_else(
+                    || "-".to_string(),
+                    |t| format!("{:.2}", t.kb_per_sec),
+                )));
+                cells.push(Cell::from(rates.map_or_else(
+                    || "-".to_string(),
+                    |t| format!("{:.2}", t.events_per_sec),
+                )));
+            }
+            cells.push(Cell::from(Line::from(vec![status])));
+
+            Row::new(cells)
+        })
+        .collect();
+
+    let widths = if throughput.is_some() {
+        vec![
+            Constraint::Percentage(18),
+            Constraint::Percentage(10),
+            Constraint::Percentage(12),
+            Constraint::Percentage(18),
+            Constraint::Percentage(14),
+            Constraint::Percentage(9),
+            Constraint::Percentage(9),
+            Constraint::Percentage(10),
+        ]
+    } else {
+        vec![
+            Constraint::Percentage(20),
+            Constraint::Percentage(15),
+            Constraint::Percentage(15),
+            Constraint::Percentage(20),
+            Constraint::Percentage(20),
+            Constraint::Percentage(10),
+        ]
+    };
+    let title = if throughput.is_some() {
+        "Data Inputs (throughput: last hour)"
+    } else {
+        "Data Inputs"
+    };
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(theme.border())
+                .title_style(theme.title()),
+        )
+        .row_highlight_style(theme.highlight());
+
+    f.render_stateful_widget(table, area, state);
+}
+
+/// Render the HTTP inputs view: one row per HEC token.
+#[allow(clippy::too_many_arguments)]
+fn render_hec_tokens(
+    f: &mut Frame,
+    area: Rect,
+    loading: bool,
+    tokens: Option<&[HecToken]>,
+    state: &mut TableState,
+    revealed: Option<&str>,
+    theme: &Theme,
+    spinner_frame: u8,
+) {
+    let Some(tokens) = render_screen_state(
+        f,
+        area,
+        loading,
+        tokens,
+        "HTTP Inputs (HEC Tokens)",
+        "Loading HEC tokens...",
+        "HEC tokens",
+        spinner_frame,
+        theme,
+    ) else {
+        return;
+    };
+
+    if tokens.is_empty() {
+        let placeholder = ratatui::widgets::Paragraph::new("No HEC tokens found.")
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("HTTP Inputs (HEC Tokens)"),
+            )
+            .alignment(Alignment::Center);
+        f.render_widget(placeholder, area);
+        return;
+    }
+
+    let header = Row::new(
+        [
+            "Name",
+            "Token",
+            "Index",
+            "Indexes",
+            "Sourcetype",
+            "Ack",
+            "Status",
+        ]
+        .into_iter()
+        .map(|title| Cell::from(title).style(theme.table_header())),
+    );
+
+    let rows: Vec<Row> = tokens
+        .iter()
+        .map(|token| {
+            // Values stay masked unless this exact token was revealed with `v`.
+            let value = match &token.token {
+                Some(value) if revealed == Some(token.name.as_str()) => {
+                    value.expose_secret().to_string()
+                }
+                Some(_) => "********".to_string(),
+                None => "-".to_string(),
+            };
+            let indexes = if token.indexes.is_empty() {
+                "(any)".to_string()
+            } else {
+                token.indexes.join(",")
+            };
+            let status = if token.disabled {
+                Span::styled("Disabled", theme.error())
+            } else {
+                Span::styled("Enabled", theme.success())
+            };
+
+            Row::new(vec![
+                Cell::from(token.input_name()),
+                Cell::from(value),
+                Cell::from(token.index.as_deref().unwrap_or("-")),
+                Cell::from(indexes),
+                Cell::from(token.sourcetype.as_deref().unwrap_or("-")),
+                Cell::from(if token.use_ack { "yes" } else { "no" }),
                 Cell::from(Line::from(vec![status])),
             ])
         })
         .collect();
 
-    let table = Table::new(
-        rows,
-        [
-            ratatui::layout::Constraint::Percentage(20),
-            ratatui::layout::Constraint::Percentage(15),
-            ratatui::layout::Constraint::Percentage(15),
-            ratatui::layout::Constraint::Percentage(20),
-            ratatui::layout::Constraint::Percentage(20),
-            ratatui::layout::Constraint::Percentage(10),
-        ],
-    )
-    .header(header)
-    .block(
-        Block::default()
-            .borders(Borders::ALL)
-            .title("Data Inputs")
-            .border_style(theme.border())
-            .title_style(theme.title()),
-    )
-    .row_highlight_style(theme.highlight());
+    let widths = [
+        Constraint::Percentage(18),
+        Constraint::Percentage(28),
+        Constraint::Percentage(12),
+        Constraint::Percentage(14),
+        Constraint::Percentage(14),
+        Constraint::Percentage(5),
+        Constraint::Percentage(9),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("HTTP Inputs (HEC Tokens)")
+                .border_style(theme.border())
+                .title_style(theme.title()),
+        )
+        .row_highlight_style(theme.highlight());
 
     f.render_stateful_widget(table, area, state);
 }
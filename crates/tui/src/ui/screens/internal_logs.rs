@@ -1,10 +1,22 @@
 //! Internal logs screen.
+//!
+//! Rows come from `app::log_anomalies`: repeated messages are collapsed with a
+//! `xN` counter, spike rows are marked with `▲`, and components get a stable
+//! color so the same subsystem is easy to follow down the list.
+//!
+//! A filter bar appears above the table while a level/component filter is set
+//! or the component filter is being edited; follow mode adds a title badge.
 
+use crate::app::input::components::SingleLineInput;
+use crate::app::log_anomalies::LogRow;
+use crate::app::log_follow::LogFilter;
 use crate::theme::Theme;
 use ratatui::{
     Frame,
-    layout::Rect,
-    widgets::{Block, Borders, Cell, Row, Table, TableState},
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
 };
 use splunk_client::models::{LogEntry, LogLevel};
 
@@ -15,35 +27,77 @@ use crate::ui::widgets::render_screen_state;
 pub struct InternalLogsRenderConfig<'a> {
     pub loading: bool,
     pub logs: Option<&'a [LogEntry]>,
+    /// Collapsed, anomaly-flagged rows; `None` renders one row per entry.
+    pub rows: Option<&'a [LogRow]>,
+    /// Anomaly summary appended to the title (e.g. "2 spikes, 1 repeated").
+    pub anomaly_summary: Option<String>,
     pub state: &'a mut TableState,
     pub auto_refresh: bool,
+    /// Follow-mode badge for the title ("[FOLLOW]" or "[PAUSED]").
+    pub follow_badge: Option<&'static str>,
+    /// Active level/component filter.
+    pub filter: &'a LogFilter,
+    /// Component filter being edited, if any.
+    pub component_input: Option<&'a SingleLineInput>,
     pub theme: &'a Theme,
     /// Current spinner frame for loading animation
     pub spinner_frame: u8,
 }
 
+/// Stable color for a component name, drawn from non-severity theme colors.
+fn component_color(component: &str, theme: &Theme) -> Color {
+    let palette = [
+        theme.log_component,
+        theme.info,
+        theme.success,
+        theme.log_debug,
+    ];
+    let hash = component.bytes().fold(0usize, |hash, b| {
+        hash.wrapping_mul(31).wrapping_add(b as usize)
+    });
+    palette[hash % palette.len()]
+}
+
 /// Render the internal logs screen.
 pub fn render_internal_logs(f: &mut Frame, area: Rect, config: InternalLogsRenderConfig) {
     let theme = config.theme;
 
-    let title = if config.auto_refresh {
-        "Internal Logs (_internal) [AUTO]"
+    let mut title = if config.auto_refresh {
+        "Internal Logs (_internal) [AUTO]".to_string()
     } else {
-        "Internal Logs (_internal)"
+        "Internal Logs (_internal)".to_string()
     };
+    if let Some(badge) = config.follow_badge {
+        title.push(' ');
+        title.push_str(badge);
+    }
+    if let Some(summary) = &config.anomaly_summary {
+        title.push_str(&format!(" - {}", summary));
+    }
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .title(title)
+        .title(title.as_str())
         .border_style(theme.border())
         .title_style(theme.title());
 
+    let area = if config.filter.is_active() || config.component_input.is_some() {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+        render_filter_bar(f, chunks[0], config.filter, config.component_input, theme);
+        chunks[1]
+    } else {
+        area
+    };
+
     let Some(logs) = render_screen_state(
         f,
         area,
         config.loading,
         config.logs,
-        title,
+        &title,
         "Loading internal logs...",
         "logs",
         config.spinner_frame,
@@ -57,7 +111,22 @@ pub fn render_internal_logs(f: &mut Frame, area: Rect, config: InternalLogsRende
         .map(|h| Cell::from(*h).style(theme.table_header()));
     let header = Row::new(header_cells).height(1);
 
-    let rows = logs.iter().map(|log| {
+    let plain_rows;
+    let rows = match config.rows {
+        Some(rows) => rows,
+        None => {
+            plain_rows = (0..logs.len())
+                .map(|i| LogRow {
+                    indices: vec![i],
+                    anomalies: Vec::new(),
+                })
+                .collect::<Vec<_>>();
+            &plain_rows
+        }
+    };
+
+    let table_rows = rows.iter().filter_map(|row| {
+        let log = logs.get(row.index())?;
         let level_style = match log.level {
             LogLevel::Error | LogLevel::Fatal => theme.error(),
             LogLevel::Warn => theme.warning(),
@@ -66,17 +135,37 @@ pub fn render_internal_logs(f: &mut Frame, area: Rect, config: InternalLogsRende
             LogLevel::Unknown => theme.text(),
         };
 
+        let mut message = Vec::new();
+        if row.is_spike() {
+            message.push(Span::styled("▲ ", level_style.add_modifier(Modifier::BOLD)));
+        }
+        if row.count() > 1 {
+            let count_style = if row.is_repeated() {
+                theme.warning().add_modifier(Modifier::BOLD)
+            } else {
+                theme.text_dim()
+            };
+            message.push(Span::styled(format!("x{} ", row.count()), count_style));
+        }
+        let message_style = if row.is_spike() {
+            level_style
+        } else {
+            Style::default()
+        };
+        message.push(Span::styled(log.message.as_str(), message_style));
+
         let cells = vec![
             Cell::from(log.time.as_str()),
             Cell::from(log.level.to_string()).style(level_style),
-            Cell::from(log.component.as_str()).style(theme.text_dim()),
-            Cell::from(log.message.as_str()),
+            Cell::from(log.component.as_str())
+                .style(Style::default().fg(component_color(&log.component, theme))),
+            Cell::from(Line::from(message)),
         ];
-        Row::new(cells)
+        Some(Row::new(cells))
     });
 
     let table = Table::new(
-        rows,
+        table_rows,
         [
             ratatui::layout::Constraint::Length(25), // Time
             ratatui::layout::Constraint::Length(10), // Level
@@ -91,3 +180,61 @@ pub fn render_internal_logs(f: &mut Frame, area: Rect, config: InternalLogsRende
 
     f.render_stateful_widget(table, area, config.state);
 }
+
+/// Render the level/component filter bar.
+fn render_filter_bar(
+    f: &mut Frame,
+    area: Rect,
+    filter: &LogFilter,
+    component_input: Option<&SingleLineInput>,
+    theme: &Theme,
+) {
+    let mut spans = vec![
+        Span::styled("Level: ", theme.title()),
+        Span::styled(filter.level_label(), theme.text()),
+        Span::styled("  Component: ", theme.title()),
+    ];
+    match component_input {
+        Some(input) => {
+            spans.push(Span::styled(format!("{}_", input.value()), theme.text()));
+            spans.push(Span::styled(
+                "  (Enter to apply, Esc to cancel)",
+                theme.text_dim(),
+            ));
+        }
+        None if filter.component.is_empty() => {
+            spans.push(Span::styled("any", theme.text_dim()));
+            spans.push(Span::styled("  (l: level, /: component)", theme.text_dim()));
+        }
+        None => {
+            spans.push(Span::styled(filter.component.as_str(), theme.text()));
+            spans.push(Span::styled("  (l: level, /: component)", theme.text_dim()));
+        }
+    }
+
+    let bar = Paragraph::new(Line::from(spans)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Filters")
+            .border_style(theme.border())
+            .title_style(theme.title()),
+    );
+    f.render_widget(bar, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_component_color_is_stable_and_not_severity() {
+        let theme = Theme::default();
+        let color = component_color("TcpOutputProc", &theme);
+        assert_eq!(color, component_color("TcpOutputProc", &theme));
+        for component in ["Metrics", "DateParser", "Indexer", "ExecProcessor", ""] {
+            let color = component_color(component, &theme);
+            assert_ne!(color, theme.error);
+            assert_ne!(color, theme.warning);
+        }
+    }
+}
@@ -2,6 +2,9 @@
 //!
 //! Renders a detailed view of a single search job, showing all available
 //! metadata including status, duration, counts, and other properties.
+//! Jobs whose artifacts are about to expire get a warning with the
+//! one-key TTL extension hint. A second tab shows the job's performance
+//! counters: command timings, dispatch phases, and per-peer streaming.
 
 use crate::theme::Theme;
 use ratatui::text::{Line, Span};
@@ -10,10 +13,16 @@ use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
 };
-use splunk_client::models::SearchJobStatus;
+use splunk_client::models::{PerformanceEntry, SearchJobStatus};
 
 use crate::ui::theme::ThemeExt;
 
+/// Remaining artifact lifetime below which the TTL warning is shown.
+pub const TTL_WARNING_SECS: usize = 10 * 60;
+
+/// Lifetime applied by the one-key TTL extension.
+pub const TTL_EXTENSION_SECS: u64 = 24 * 60 * 60;
+
 /// Render detailed information about a single search job.
 ///
 /// # Arguments
@@ -55,7 +64,20 @@ pub fn render_details(f: &mut Frame, area: Rect, job: &SearchJobStatus, theme: &
         theme.warning()
     };
 
-    let details = vec![
+    let mut details = Vec::new();
+    if job.ttl_expires_within(TTL_WARNING_SECS) {
+        details.push(Line::from(Span::styled(
+            format!(
+                "Artifacts expire in {} - press t to extend TTL to {}h",
+                format_ttl(job.ttl.unwrap_or_default()),
+                TTL_EXTENSION_SECS / 3600
+            ),
+            theme.warning(),
+        )));
+        details.push(Line::from(""));
+    }
+
+    details.extend([
         Line::from(vec![
             Span::styled("Status: ", theme.title()),
             Span::styled(status_text, status_style),
@@ -96,10 +118,131 @@ pub fn render_details(f: &mut Frame, area: Rect, job: &SearchJobStatus, theme: &
             Span::styled("Finalized: ", theme.title()),
             Span::raw(if job.is_finalized { "Yes" } else { "No" }),
         ]),
-    ];
+        Line::from(vec![
+            Span::styled("Expires In: ", theme.title()),
+            Span::raw(job.ttl.map_or("N/A".to_string(), format_ttl)),
+        ]),
+    ]);
 
     let details_paragraph = Paragraph::new(details)
         .block(Block::default().borders(Borders::ALL))
         .alignment(Alignment::Left);
     f.render_widget(details_paragraph, chunks[1]);
 }
+
+/// Render the performance tab for a single search job.
+///
+/// `performance` is the freshly fetched status of `job`; `None` while it loads
+/// or when the fetch failed.
+pub fn render_performance(
+    f: &mut Frame,
+    area: Rect,
+    job: &SearchJobStatus,
+    performance: Option<&SearchJobStatus>,
+    loading: bool,
+    theme: &Theme,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
+        .split(area);
+
+    let title = Paragraph::new(format!("Job: {}", job.sid))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Job Performance (p: details)")
+                .title_style(theme.title())
+                .border_style(theme.border()),
+        )
+        .alignment(Alignment::Center);
+    f.render_widget(title, chunks[0]);
+
+    let lines = match performance {
+        Some(status) => match status.performance.as_ref().filter(|perf| !perf.is_empty()) {
+            Some(perf) => {
+                let mut lines = vec![Line::from(vec![
+                    Span::styled("Run Duration: ", theme.title()),
+                    Span::raw(format!("{:.2} seconds", status.run_duration)),
+                ])];
+                for (title, entries) in [
+                    ("Commands", perf.commands()),
+                    ("Dispatch Phases", perf.dispatch_phases()),
+                    ("Remote Peers", perf.peers()),
+                ] {
+                    push_performance_section(&mut lines, title, &entries, theme);
+                }
+                lines
+            }
+            None => vec![Line::from(Span::styled(
+                "No performance data reported for this job.",
+                theme.text_dim(),
+            ))],
+        },
+        None if loading => vec![Line::from("Loading performance data...")],
+        None => vec![Line::from(Span::styled(
+            "Performance data unavailable. Press p twice to retry.",
+            theme.text_dim(),
+        ))],
+    };
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL))
+        .alignment(Alignment::Left);
+    f.render_widget(paragraph, chunks[1]);
+}
+
+/// Append one titled, column-aligned performance section (skipped when empty).
+fn push_performance_section(
+    lines: &mut Vec<Line<'static>>,
+    title: &str,
+    entries: &[PerformanceEntry],
+    theme: &Theme,
+) {
+    if entries.is_empty() {
+        return;
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(title.to_string(), theme.title())));
+    lines.push(Line::from(Span::styled(
+        format!(
+            "  {:<36} {:>10} {:>11} {:>12} {:>12}",
+            "Name", "Duration", "Invocations", "Input", "Output"
+        ),
+        theme.table_header(),
+    )));
+    for entry in entries {
+        lines.push(Line::from(format!(
+            "  {:<36} {:>9.3}s {:>11} {:>12} {:>12}",
+            entry.name,
+            entry.counters.duration_secs,
+            entry.counters.invocations,
+            entry.counters.input_count,
+            entry.counters.output_count
+        )));
+    }
+}
+
+/// Format a remaining TTL in seconds as `1h 5m`, `9m 30s`, or `45s`.
+pub(crate) fn format_ttl(secs: usize) -> String {
+    let (hours, minutes, seconds) = (secs / 3600, (secs % 3600) / 60, secs % 60);
+    if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m {}s", minutes, seconds)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_ttl() {
+        assert_eq!(format_ttl(45), "45s");
+        assert_eq!(format_ttl(570), "9m 30s");
+        assert_eq!(format_ttl(3900), "1h 5m");
+    }
+}
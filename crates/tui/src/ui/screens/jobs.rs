@@ -2,7 +2,7 @@
 //!
 //! Renders the search jobs list as a table with status, duration, and result counts.
 //! Supports filtering jobs by SID or status substring match with highlighting,
-//! and sorting by any column.
+//! and sorting by any column. An active server-side filter is shown in the title.
 //!
 //! Uses the centralized theme system via [`ThemeExt`] for consistent styling.
 
@@ -16,6 +16,7 @@ use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
 };
+use splunk_client::JobFilter;
 use splunk_client::models::SearchJobStatus;
 use std::collections::HashSet;
 
@@ -27,6 +28,8 @@ pub struct JobsRenderConfig<'a> {
     pub state: &'a mut TableState,
     /// Whether auto-refresh is enabled
     pub auto_refresh: bool,
+    /// Active server-side filter (owner/app/status)
+    pub server_filter: &'a JobFilter,
     /// Optional filter string for filtering jobs
     pub filter: &'a Option<String>,
     /// Current filter input (for display when filtering)
@@ -55,6 +58,7 @@ pub fn render_jobs(f: &mut Frame, area: Rect, config: JobsRenderConfig) {
         jobs,
         state,
         auto_refresh,
+        server_filter,
         filter,
         filter_input,
         is_filtering,
@@ -190,11 +194,7 @@ pub fn render_jobs(f: &mut Frame, area: Rect, config: JobsRenderConfig) {
     .header(Row::new(header_cells).style(theme.table_header()))
     .block(
         Block::default()
-            .title(if auto_refresh {
-                "Search Jobs [AUTO]"
-            } else {
-                "Search Jobs"
-            })
+            .title(jobs_title(auto_refresh, server_filter))
             .borders(Borders::ALL)
             .border_style(theme.border())
             .title_style(theme.title()),
@@ -205,6 +205,18 @@ pub fn render_jobs(f: &mut Frame, area: Rect, config: JobsRenderConfig) {
     f.render_stateful_widget(table, table_area, state);
 }
 
+/// Build the table title, noting auto-refresh and any server-side filter.
+fn jobs_title(auto_refresh: bool, server_filter: &JobFilter) -> String {
+    let mut title = String::from("Search Jobs");
+    if !server_filter.is_empty() {
+        title.push_str(&format!(" ({})", server_filter));
+    }
+    if auto_refresh {
+        title.push_str(" [AUTO]");
+    }
+    title
+}
+
 /// Create a header cell with optional sort indicator.
 fn header_cell<'a>(text: &'a str, is_sorted: bool, indicator: &str) -> Cell<'a> {
     if is_sorted {
@@ -85,7 +85,7 @@ fn render_member_section(f: &mut Frame, area: Rect, status: &KvStoreStatus, them
         ]),
         Row::new(vec![
             Cell::from("Status"),
-            Cell::from(member.status.to_string())
+            Cell::from(theme.status_text(&member.status.to_string()))
                 .style(theme.status_style(&member.status.to_string())),
         ]),
     ];
@@ -202,6 +202,7 @@ mod tests {
             replication_status: KvStoreReplicationStatus {
                 oplog_size: 1024 * 1024 * 1024,
                 oplog_used: 50.0,
+                replication_lag_secs: None,
             },
         };
 
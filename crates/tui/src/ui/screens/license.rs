@@ -1,7 +1,8 @@
 //! License screen rendering.
 //!
-//! Renders comprehensive Splunk license information including usage,
-//! license pools, and license stacks.
+//! Renders comprehensive Splunk license information including usage, a daily
+//! usage trend against quota, license pools, and license stacks, with a
+//! warning banner when licenser warnings approach a violation.
 
 use crate::action::LicenseData;
 use crate::theme::Theme;
@@ -11,11 +12,15 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::Style,
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    widgets::{Bar, BarChart, BarGroup, Block, Borders, Cell, Paragraph, Row, Table},
 };
 use splunk_client::format_bytes;
+use splunk_client::models::{
+    LICENSE_USAGE_HISTORY_DAYS, LICENSE_VIOLATION_WARNING_LIMIT, LICENSE_WARNING_WINDOW_DAYS,
+    LicenseUsageDay, LicenseWarningSummary,
+};
 
-use crate::ui::theme::ThemeExt;
+use crate::ui::theme::{StatusClass, ThemeExt};
 
 /// Configuration for rendering the license screen.
 pub struct LicenseRenderConfig<'a> {
@@ -58,19 +63,74 @@ pub fn render_license(f: &mut Frame, area: Rect, config: LicenseRenderConfig) {
         return;
     };
 
-    // Create layout with three sections: usage, pools, stacks
+    let summary = LicenseWarningSummary::from_parts(&info.messages, &info.slaves);
+    let banner = violation_banner(&summary);
+    let banner_height = if banner.is_some() { 3 } else { 0 };
+
+    // Create layout with an optional banner and four sections: usage, trend, pools, stacks
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Percentage(30), // Usage section
-            Constraint::Percentage(35), // Pools section
-            Constraint::Percentage(35), // Stacks section
+            Constraint::Length(banner_height), // Violation banner
+            Constraint::Percentage(25),        // Usage section
+            Constraint::Percentage(25),        // Trend section
+            Constraint::Percentage(25),        // Pools section
+            Constraint::Percentage(25),        // Stacks section
         ])
         .split(area);
 
-    render_usage_section(f, chunks[0], info, theme);
-    render_pools_section(f, chunks[1], info, theme);
-    render_stacks_section(f, chunks[2], info, theme);
+    if let Some(text) = banner {
+        let color = if summary.is_in_violation() {
+            theme.error
+        } else {
+            theme.warning
+        };
+        let widget = Paragraph::new(Line::from(Span::styled(text, Style::default().fg(color))))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("License Warning")
+                    .border_style(Style::default().fg(color))
+                    .title_style(theme.title()),
+            );
+        f.render_widget(widget, chunks[0]);
+    }
+
+    render_usage_section(f, chunks[1], info, theme);
+    render_trend_section(f, chunks[2], info, theme);
+    render_pools_section(f, chunks[3], info, theme);
+    render_stacks_section(f, chunks[4], info, theme);
+}
+
+/// Banner text when slaves are in or near license violation, `None` otherwise.
+fn violation_banner(summary: &LicenseWarningSummary) -> Option<String> {
+    if !summary.is_in_violation() && !summary.is_near_violation() {
+        return None;
+    }
+
+    let mut text = if summary.is_in_violation() {
+        format!(
+            "License violation: {} reached {} warnings in {} days",
+            summary.violating_slaves.join(", "),
+            LICENSE_VIOLATION_WARNING_LIMIT,
+            LICENSE_WARNING_WINDOW_DAYS
+        )
+    } else {
+        format!(
+            "{} of {} warnings in {} days; {} more until violation",
+            summary.warning_count,
+            LICENSE_VIOLATION_WARNING_LIMIT,
+            LICENSE_WARNING_WINDOW_DAYS,
+            summary.warnings_until_violation
+        )
+    };
+    if !summary.over_quota_slaves.is_empty() {
+        text.push_str(&format!(
+            " | Over quota: {}",
+            summary.over_quota_slaves.join(", ")
+        ));
+    }
+    Some(text)
 }
 
 /// Render the license usage section.
@@ -143,6 +203,97 @@ fn render_usage_section(f: &mut Frame, area: Rect, info: &LicenseData, theme: &T
     f.render_widget(usage_widget, area);
 }
 
+/// Render the daily usage trend as a bar chart scaled against the quota.
+///
+/// Bars are scaled so a full-height bar is the larger of the quota and the
+/// peak day; days over quota are drawn in the error color.
+fn render_trend_section(f: &mut Frame, area: Rect, info: &LicenseData, theme: &Theme) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "Daily Usage vs Quota ({} days)",
+            LICENSE_USAGE_HISTORY_DAYS
+        ))
+        .border_style(theme.border())
+        .title_style(theme.title());
+
+    if info.history.is_empty() {
+        let empty = Paragraph::new("No license usage history available.").block(block);
+        f.render_widget(empty, area);
+        return;
+    }
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    // Bars are in MB so days under 1 GB still show
+    let to_mb = |bytes: usize| (bytes / (1024 * 1024)) as u64;
+    let bars: Vec<Bar> = info
+        .history
+        .iter()
+        .map(|day| {
+            let color = if day.is_over_quota() {
+                theme.error
+            } else {
+                theme.info
+            };
+            // Over-quota days are only colored; mark them when colors are off
+            let marker = if theme.no_color && day.is_over_quota() {
+                "!"
+            } else {
+                ""
+            };
+            Bar::new(to_mb(day.used_bytes))
+                .text_value(marker.to_string())
+                .style(Style::default().fg(color))
+        })
+        .collect();
+
+    let peak = info.history.iter().map(|d| d.used_bytes).max().unwrap_or(0);
+    let quota = info
+        .history
+        .iter()
+        .map(|d| d.quota_bytes)
+        .max()
+        .unwrap_or(0);
+    let count = bars.len() as u16;
+    let bar_width = (chunks[0].width.saturating_sub(count.saturating_sub(1)) / count.max(1)).max(1);
+
+    let chart = BarChart::default()
+        .data(BarGroup::new(bars))
+        .bar_width(bar_width)
+        .bar_gap(1)
+        .max(to_mb(peak.max(quota)).max(1));
+    f.render_widget(chart, chunks[0]);
+
+    let caption = Paragraph::new(trend_caption(&info.history)).style(theme.text_dim());
+    f.render_widget(caption, chunks[1]);
+}
+
+/// One-line summary of the usage trend: range, peak day, quota, and overages.
+fn trend_caption(history: &[LicenseUsageDay]) -> String {
+    let (Some(first), Some(last)) = (history.first(), history.last()) else {
+        return String::new();
+    };
+    let peak = history.iter().max_by_key(|d| d.used_bytes).unwrap_or(first);
+    let quota = history.iter().map(|d| d.quota_gb()).fold(0.0, f64::max);
+    let over = history.iter().filter(|d| d.is_over_quota()).count();
+
+    format!(
+        "{} to {} | Peak {:.2} GB ({}) | Quota {:.2} GB | {} day(s) over quota",
+        first.day,
+        last.day,
+        peak.used_gb(),
+        peak.day,
+        quota,
+        over
+    )
+}
+
 /// Render the license pools table.
 fn render_pools_section(f: &mut Frame, area: Rect, info: &LicenseData, theme: &Theme) {
     let header = Row::new(vec![
@@ -249,20 +400,46 @@ fn render_stacks_section(f: &mut Frame, area: Rect, info: &LicenseData, theme: &
 
 /// Format license usage percentage and choose a semantic color.
 fn percentage_span(percentage: f64, theme: &Theme) -> (String, ratatui::style::Color) {
-    let color = if percentage < 70.0 {
-        theme.success
+    let (class, color) = if percentage < 70.0 {
+        (StatusClass::Success, theme.success)
     } else if percentage < 90.0 {
-        theme.warning
+        (StatusClass::Warning, theme.warning)
     } else {
-        theme.error
+        (StatusClass::Error, theme.error)
     };
-    (format!("{:.1}%", percentage), color)
+    (theme.tagged(&format!("{:.1}%", percentage), class), color)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use splunk_client::models::{LicensePool, LicenseStack, LicenseUsage};
+    use splunk_client::models::{
+        LicenseMessage, LicensePool, LicenseSlave, LicenseStack, LicenseUsage,
+    };
+
+    fn usage_day(day: &str, used_gb: usize, quota_gb: usize) -> LicenseUsageDay {
+        const GB: usize = 1024 * 1024 * 1024;
+        LicenseUsageDay {
+            day: day.to_string(),
+            used_bytes: used_gb * GB,
+            quota_bytes: quota_gb * GB,
+        }
+    }
+
+    #[test]
+    fn test_trend_caption() {
+        let history = vec![
+            usage_day("2024-01-13", 3, 5),
+            usage_day("2024-01-14", 6, 5),
+            usage_day("2024-01-15", 2, 5),
+        ];
+
+        assert_eq!(
+            trend_caption(&history),
+            "2024-01-13 to 2024-01-15 | Peak 6.00 GB (2024-01-14) | Quota 5.00 GB | 1 day(s) over quota"
+        );
+        assert_eq!(trend_caption(&[]), "");
+    }
 
     #[test]
     fn test_percentage_span() {
@@ -290,6 +467,9 @@ mod tests {
             usage: vec![],
             pools: vec![],
             stacks: vec![],
+            messages: vec![],
+            slaves: vec![],
+            history: vec![],
         };
 
         assert!(data.usage.is_empty());
@@ -320,6 +500,9 @@ mod tests {
                 type_name: "enterprise".to_string(),
                 label: "Enterprise Stack".to_string(),
             }],
+            messages: vec![],
+            slaves: vec![],
+            history: vec![],
         };
 
         assert_eq!(data.usage.len(), 1);
@@ -330,4 +513,44 @@ mod tests {
         assert_eq!(data.pools[0].name, "pool1");
         assert_eq!(data.stacks[0].name, "stack1");
     }
+
+    fn slave(label: &str, warning_count: usize) -> LicenseSlave {
+        LicenseSlave {
+            name: format!("GUID-{}", label),
+            label: label.to_string(),
+            warning_count,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_violation_banner_hidden_when_healthy() {
+        let summary = LicenseWarningSummary::from_parts(&[], &[slave("idx1", 1)]);
+        assert_eq!(violation_banner(&summary), None);
+    }
+
+    #[test]
+    fn test_violation_banner_countdown_and_over_quota() {
+        let messages = vec![LicenseMessage {
+            category: "pool_over_quota".to_string(),
+            slave_id: Some("GUID-idx1".to_string()),
+            ..Default::default()
+        }];
+        let summary = LicenseWarningSummary::from_parts(&messages, &[slave("idx1", 4)]);
+
+        assert_eq!(
+            violation_banner(&summary).as_deref(),
+            Some("4 of 5 warnings in 30 days; 1 more until violation | Over quota: idx1")
+        );
+    }
+
+    #[test]
+    fn test_violation_banner_in_violation() {
+        let summary = LicenseWarningSummary::from_parts(&[], &[slave("idx1", 5), slave("idx2", 0)]);
+
+        assert_eq!(
+            violation_banner(&summary).as_deref(),
+            Some("License violation: idx1 reached 5 warnings in 30 days")
+        );
+    }
 }
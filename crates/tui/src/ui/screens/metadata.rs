@@ -0,0 +1,229 @@
+//! Hosts & Sources screen for the TUI.
+//!
+//! Responsibilities:
+//! - Render hosts or sources with event counts, last event time, age, and
+//!   the indexes each appears in
+//! - Highlight entries with no events within the stale threshold, so silent
+//!   forwarders stand out
+//! - Display loading state and empty state
+//!
+//! Does NOT handle:
+//! - Data fetching (handled by side effects)
+//! - Toggling between hosts and sources (handled by actions)
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Rect},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+use splunk_client::{MetadataEntry, MetadataType};
+
+use crate::theme::Theme;
+use crate::ui::theme::ThemeExt;
+use crate::ui::widgets::render_screen_state;
+
+/// Hours without events after which a host or source is highlighted as stale.
+pub const STALE_THRESHOLD_HOURS: u64 = 24;
+
+/// Configuration for rendering the hosts & sources screen.
+pub struct MetadataRenderConfig<'a> {
+    /// Whether data is currently loading.
+    pub loading: bool,
+    /// Whether hosts or sources are listed.
+    pub metadata_type: MetadataType,
+    /// Hosts or sources ordered by name.
+    pub entries: Option<&'a [MetadataEntry]>,
+    /// Current time in epoch seconds, for ages and staleness.
+    pub now: u64,
+    /// The table state for selection.
+    pub state: &'a mut TableState,
+    /// The theme to use for styling.
+    pub theme: &'a Theme,
+    /// Current spinner frame for loading animation
+    pub spinner_frame: u8,
+}
+
+/// Render the hosts & sources screen.
+pub fn render_metadata(f: &mut Frame, area: Rect, config: MetadataRenderConfig) {
+    let MetadataRenderConfig {
+        loading,
+        metadata_type,
+        entries,
+        now,
+        state,
+        theme,
+        spinner_frame,
+    } = config;
+
+    let (label, column) = match metadata_type {
+        MetadataType::Hosts => ("Hosts", "Host"),
+        MetadataType::Sources => ("Sources", "Source"),
+        MetadataType::Sourcetypes => ("Sourcetypes", "Sourcetype"),
+    };
+
+    let Some(entries) = render_screen_state(
+        f,
+        area,
+        loading,
+        entries,
+        label,
+        &format!("Loading {}...", metadata_type),
+        metadata_type.as_str(),
+        spinner_frame,
+        theme,
+    ) else {
+        return;
+    };
+
+    let threshold_secs = STALE_THRESHOLD_HOURS * 3600;
+    let stale_count = entries
+        .iter()
+        .filter(|entry| entry.is_stale(now, threshold_secs))
+        .count();
+    let title = format!(
+        "{} ({}, {} stale >{}h)",
+        label,
+        entries.len(),
+        stale_count,
+        STALE_THRESHOLD_HOURS
+    );
+
+    if entries.is_empty() {
+        let empty = Paragraph::new(format!("No {} have been indexed.", metadata_type))
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .alignment(Alignment::Center);
+        f.render_widget(empty, area);
+        return;
+    }
+
+    let header_cells = [column, "Events", "Last Seen", "Age", "Indexes"]
+        .into_iter()
+        .map(|h| Cell::from(h).style(theme.table_header()));
+    let header = Row::new(header_cells).height(1);
+
+    let rows: Vec<Row> = entries
+        .iter()
+        .map(|entry| {
+            let row = Row::new(vec![
+                Cell::from(entry.name.clone()),
+                Cell::from(entry.total_count.to_string()),
+                Cell::from(format_time(entry.last_time)),
+                Cell::from(format_age(now, entry.last_time)),
+                Cell::from(entry.indexes.join(", ")),
+            ])
+            .height(1);
+            if entry.is_stale(now, threshold_secs) {
+                row.style(theme.warning())
+            } else {
+                row
+            }
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(34),
+            Constraint::Percentage(12),
+            Constraint::Percentage(18),
+            Constraint::Percentage(10),
+            Constraint::Percentage(26),
+        ],
+    )
+    .header(header)
+    .block(Block::default().borders(Borders::ALL).title(title))
+    .row_highlight_style(theme.highlight())
+    .highlight_symbol("> ");
+
+    f.render_stateful_widget(table, area, state);
+}
+
+/// Epoch seconds as a UTC date and time, or `-` when unknown.
+fn format_time(epoch: Option<u64>) -> String {
+    epoch
+        .and_then(|t| chrono::DateTime::from_timestamp(t as i64, 0))
+        .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Time since `epoch` in minutes, hours, or days, or `-` when unknown.
+fn format_age(now: u64, epoch: Option<u64>) -> String {
+    let Some(epoch) = epoch else {
+        return "-".to_string();
+    };
+    let secs = now.saturating_sub(epoch);
+    if secs < 3600 {
+        format!("{}m", secs / 60)
+    } else if secs < 48 * 3600 {
+        format!("{}h", secs / 3600)
+    } else {
+        format!("{}d", secs / 86_400)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+
+    fn host(name: &str, last_time: u64) -> MetadataEntry {
+        MetadataEntry {
+            name: name.to_string(),
+            total_count: 100,
+            first_time: None,
+            last_time: Some(last_time),
+            recent_time: None,
+            indexes: vec!["main".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_format_age_picks_unit() {
+        assert_eq!(format_age(10_000, Some(10_000 - 120)), "2m");
+        assert_eq!(format_age(200_000, Some(200_000 - 5 * 3600)), "5h");
+        assert_eq!(format_age(1_000_000, Some(1_000_000 - 3 * 86_400)), "3d");
+        assert_eq!(format_age(1_000, None), "-");
+    }
+
+    #[test]
+    fn test_render_metadata_highlights_stale_hosts() {
+        let now = 1_700_086_400;
+        let entries = vec![host("old-fwd", now - 30 * 3600), host("web01", now - 600)];
+        let theme = Theme::default();
+        let mut state = TableState::default();
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(120, 6)).unwrap();
+
+        terminal
+            .draw(|f| {
+                render_metadata(
+                    f,
+                    f.area(),
+                    MetadataRenderConfig {
+                        loading: false,
+                        metadata_type: MetadataType::Hosts,
+                        entries: Some(&entries),
+                        now,
+                        state: &mut state,
+                        theme: &theme,
+                        spinner_frame: 0,
+                    },
+                );
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let line = |y: u16| -> String {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect()
+        };
+        assert!(line(0).contains("Hosts (2, 1 stale >24h)"), "{}", line(0));
+        assert!(line(1).contains("Host"), "{}", line(1));
+        assert!(line(2).contains("old-fwd") && line(2).contains("30h"));
+        assert!(line(3).contains("web01") && line(3).contains("10m"));
+
+        // Column 1 is the first cell inside the left border.
+        assert_eq!(buffer[(1, 2)].fg, theme.warning);
+        assert_ne!(buffer[(1, 3)].fg, theme.warning);
+    }
+}
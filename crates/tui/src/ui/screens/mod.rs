@@ -12,6 +12,7 @@ pub mod datamodels;
 pub mod forwarders;
 pub mod health;
 pub mod indexes;
+pub mod ingestion;
 pub mod inputs;
 pub mod internal_logs;
 pub mod job_details;
@@ -20,13 +21,16 @@ pub mod kvstore;
 pub mod license;
 pub mod lookups;
 pub mod macros;
+pub mod metadata;
 pub mod multi_instance;
 pub mod overview;
+pub mod rest;
 pub mod roles;
 pub mod saved_searches;
 pub mod search;
 pub mod search_peers;
 pub mod settings;
 pub mod shc;
+pub mod sourcetypes;
 pub mod users;
 pub mod workload;
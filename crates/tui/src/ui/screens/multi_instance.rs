@@ -122,7 +122,10 @@ fn render_instance_list(
 
             Row::new(vec![
                 Cell::from(instance.profile_name.clone()),
-                Cell::from(Span::styled(status_text, Style::default().fg(status_color))),
+                Cell::from(Span::styled(
+                    theme.status_text(&status_text),
+                    Style::default().fg(status_color),
+                )),
                 Cell::from(instance.job_count.to_string()),
             ])
             .height(1)
@@ -204,7 +207,7 @@ fn render_instance_details(
         Line::from(vec![
             Span::styled("Health:   ", theme.title()),
             Span::styled(
-                &instance.health_status,
+                theme.status_text(&instance.health_status),
                 Style::default().fg(theme.status_color(&instance.health_status)),
             ),
         ]),
@@ -246,7 +249,7 @@ fn render_instance_details(
                 Span::styled(format!("{:>6}", resource.count), theme.title()),
                 Span::raw("  "),
                 Span::styled(
-                    format!("{}{}", resource.status, error_indicator),
+                    format!("{}{}", theme.status_text(&resource.status), error_indicator),
                     Style::default().fg(status_color),
                 ),
             ]));
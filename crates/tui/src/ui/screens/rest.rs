@@ -0,0 +1,164 @@
+//! REST explorer screen for the TUI.
+//!
+//! Responsibilities:
+//! - Render the request history (most recent first) next to the last response
+//! - Show the response pretty-printed as JSON or XML, scrolled by the app
+//! - Display the sending state and a hint when nothing has been sent yet
+//!
+//! Does NOT handle:
+//! - Sending requests (handled by side effects)
+//! - Editing request lines (handled by the REST request popup)
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+};
+
+use crate::app::rest_explorer::RestResponse;
+use crate::theme::Theme;
+use crate::ui::theme::{ThemeExt, spinner_char};
+
+/// Configuration for rendering the REST explorer screen.
+pub struct RestRenderConfig<'a> {
+    /// Whether a request is in flight.
+    pub loading: bool,
+    /// Request lines, most recent first.
+    pub history: &'a [String],
+    /// The list state for history selection.
+    pub state: &'a mut ListState,
+    /// The last response, if any request has completed.
+    pub response: Option<&'a RestResponse>,
+    /// First response line shown.
+    pub scroll: usize,
+    /// The theme to use for styling.
+    pub theme: &'a Theme,
+    /// Current spinner frame for loading animation
+    pub spinner_frame: u8,
+}
+
+/// Render the REST explorer screen.
+pub fn render_rest(f: &mut Frame, area: Rect, config: RestRenderConfig) {
+    let RestRenderConfig {
+        loading,
+        history,
+        state,
+        response,
+        scroll,
+        theme,
+        spinner_frame,
+    } = config;
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+        .split(area);
+
+    let items: Vec<ListItem> = history
+        .iter()
+        .map(|line| ListItem::new(line.as_str()))
+        .collect();
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("History ({})", history.len()))
+                .border_style(theme.border())
+                .title_style(theme.title()),
+        )
+        .highlight_style(theme.highlight());
+    f.render_stateful_widget(list, chunks[0], state);
+
+    let (title, body, style) = match response {
+        _ if loading => (
+            format!("{} Sending request...", spinner_char(spinner_frame)),
+            String::new(),
+            theme.text(),
+        ),
+        Some(response) => {
+            let title = match response.format {
+                Some(format) => format!("{} ({})", response.request, format.name()),
+                None if response.is_error => format!("{} (error)", response.request),
+                None => response.request.clone(),
+            };
+            let style = if response.is_error {
+                theme.error()
+            } else {
+                theme.text()
+            };
+            (title, response.body.clone(), style)
+        }
+        None => (
+            "Response".to_string(),
+            "Press n to send a request, e.g. GET /services/server/info".to_string(),
+            theme.text_dim(),
+        ),
+    };
+
+    let paragraph = Paragraph::new(body)
+        .style(style)
+        .scroll((scroll.min(u16::MAX as usize) as u16, 0))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(theme.border())
+                .title_style(theme.title()),
+        );
+    f.render_widget(paragraph, chunks[1]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use splunk_client::raw_format::RawFormat;
+
+    #[test]
+    fn test_render_rest_shows_history_and_scrolled_response() {
+        let history = vec!["GET /services/server/info".to_string()];
+        let response = RestResponse {
+            request: history[0].clone(),
+            format: Some(RawFormat::Json),
+            body: "{\n  \"entry\": [],\n  \"paging\": {}\n}".to_string(),
+            is_error: false,
+        };
+        let theme = Theme::default();
+        let mut state = ListState::default().with_selected(Some(0));
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(100, 5)).unwrap();
+
+        terminal
+            .draw(|f| {
+                render_rest(
+                    f,
+                    f.area(),
+                    RestRenderConfig {
+                        loading: false,
+                        history: &history,
+                        state: &mut state,
+                        response: Some(&response),
+                        scroll: 1,
+                        theme: &theme,
+                        spinner_frame: 0,
+                    },
+                );
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let line = |y: u16| -> String {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect()
+        };
+        assert!(line(0).contains("History (1)"), "{}", line(0));
+        assert!(
+            line(0).contains("GET /services/server/info (JSON)"),
+            "{}",
+            line(0)
+        );
+        assert!(line(1).contains("GET /services/server/info"));
+        assert!(line(1).contains("\"entry\": []"), "{}", line(1));
+        assert!(line(2).contains("\"paging\": {}"), "{}", line(2));
+    }
+}
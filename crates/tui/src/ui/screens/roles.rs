@@ -1,16 +1,19 @@
 //! Roles screen rendering.
 //!
-//! Renders the list of Splunk roles with their capabilities and settings.
+//! Renders the list of Splunk roles with their capabilities and settings,
+//! (toggled with 'u') each role's search quotas against current usage by the
+//! role's users, or (toggled with 'x') the roles × capabilities matrix.
 
+use crate::app::state::RolesViewMode;
 use crate::theme::Theme;
 use crate::ui::theme::ThemeExt;
-use crate::ui::widgets::render_screen_state;
+use crate::ui::widgets::{render_screen_state, render_screen_state_custom};
 use ratatui::{
     Frame,
-    layout::Rect,
-    widgets::{Block, Borders, List, ListItem, ListState},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    widgets::{Block, Borders, Cell, List, ListItem, ListState, Paragraph, Row, Table, TableState},
 };
-use splunk_client::models::Role;
+use splunk_client::models::{CapabilityGrant, Role, RoleCapabilityMatrix, RoleUsage};
 
 /// Configuration for rendering the roles screen.
 pub struct RolesRenderConfig<'a> {
@@ -18,6 +21,14 @@ pub struct RolesRenderConfig<'a> {
     pub loading: bool,
     /// The list of roles to display
     pub roles: Option<&'a [Role]>,
+    /// Per-role search quotas and current usage
+    pub role_usage: Option<&'a [RoleUsage]>,
+    /// Effective capabilities of every role
+    pub role_matrix: Option<&'a RoleCapabilityMatrix>,
+    /// Selected capability row in the matrix view
+    pub matrix_state: &'a mut TableState,
+    /// Current view mode
+    pub view_mode: RolesViewMode,
     /// The current list selection state
     pub state: &'a mut ListState,
     /// Theme for consistent styling.
@@ -37,11 +48,35 @@ pub fn render_roles(f: &mut Frame, area: Rect, config: RolesRenderConfig) {
     let RolesRenderConfig {
         loading,
         roles,
+        role_usage,
+        role_matrix,
+        matrix_state,
+        view_mode,
         state,
         theme,
         spinner_frame,
     } = config;
 
+    match view_mode {
+        RolesViewMode::Usage => {
+            render_usage(f, area, loading, role_usage, theme, spinner_frame);
+            return;
+        }
+        RolesViewMode::Matrix => {
+            render_matrix(
+                f,
+                area,
+                loading,
+                role_matrix,
+                matrix_state,
+                theme,
+                spinner_frame,
+            );
+            return;
+        }
+        RolesViewMode::List => {}
+    }
+
     let Some(roles) = render_screen_state(
         f,
         area,
@@ -90,3 +125,282 @@ pub fn render_roles(f: &mut Frame, area: Rect, config: RolesRenderConfig) {
         .highlight_style(theme.highlight());
     f.render_stateful_widget(list, area, state);
 }
+
+/// Format a used/quota pair; unset and `0` quotas are unlimited in Splunk.
+fn used_of(used: impl ToString, quota: Option<i32>) -> String {
+    match quota {
+        Some(q) if q > 0 => format!("{}/{}", used.to_string(), q),
+        _ => format!("{}/-", used.to_string()),
+    }
+}
+
+/// Render per-role search quotas against the current usage of each role's users.
+///
+/// Per-user columns show the busiest user against the per-user quota; the
+/// running columns show role-wide totals against the cumulative quota. Roles
+/// with users at a limit are listed first.
+fn render_usage(
+    f: &mut Frame,
+    area: Rect,
+    loading: bool,
+    usage: Option<&[RoleUsage]>,
+    theme: &Theme,
+    spinner_frame: u8,
+) {
+    let title = if loading {
+        "Role Quota Usage (Loading...)"
+    } else {
+        "Role Quota Usage - Press 'u' for roles list"
+    };
+
+    let Some(usage) = render_screen_state_custom(
+        f,
+        area,
+        loading,
+        usage,
+        title,
+        "Loading role quota usage...",
+        "No role quota usage loaded. Press 'r' to refresh.",
+        spinner_frame,
+        theme,
+    ) else {
+        return;
+    };
+
+    let at_limit = usage.iter().filter(|u| u.users_at_limit > 0).count();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "{} - {} of {} roles with users at a limit",
+            title,
+            at_limit,
+            usage.len()
+        ))
+        .border_style(theme.border())
+        .title_style(theme.title());
+
+    if usage.is_empty() {
+        let paragraph = Paragraph::new("No roles found.")
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let header = Row::new(
+        [
+            "Role",
+            "Users",
+            "Searches/User",
+            "RT/User",
+            "Disk MB/User",
+            "Searches",
+            "RT Searches",
+            "At Limit",
+        ]
+        .iter()
+        .map(|h| Cell::from(*h).style(theme.table_header())),
+    )
+    .height(1);
+
+    let mut sorted: Vec<&RoleUsage> = usage.iter().collect();
+    sorted.sort_by_key(|u| std::cmp::Reverse(u.users_at_limit));
+
+    let rows: Vec<Row> = sorted
+        .into_iter()
+        .map(|u| {
+            let limit_style = if u.users_at_limit > 0 {
+                theme.error()
+            } else {
+                theme.text()
+            };
+            Row::new(vec![
+                Cell::from(u.role.clone()),
+                Cell::from(u.users.to_string()),
+                Cell::from(used_of(u.peak_user_jobs, u.srch_jobs_quota)),
+                Cell::from(used_of(u.peak_user_rt_jobs, u.rt_srch_jobs_quota)),
+                Cell::from(used_of(u.peak_user_disk_mb, u.srch_disk_quota_mb)),
+                Cell::from(used_of(u.running_jobs, u.cumulative_srch_jobs_quota)),
+                Cell::from(used_of(u.running_rt_jobs, u.cumulative_rt_srch_jobs_quota)),
+                Cell::from(u.users_at_limit.to_string()).style(limit_style),
+            ])
+            .height(1)
+        })
+        .collect();
+
+    let constraints = [
+        Constraint::Min(16),    // Role
+        Constraint::Length(7),  // Users
+        Constraint::Length(15), // Searches/User
+        Constraint::Length(9),  // RT/User
+        Constraint::Length(14), // Disk MB/User
+        Constraint::Length(10), // Searches
+        Constraint::Length(13), // RT Searches
+        Constraint::Length(9),  // At Limit
+    ];
+
+    let table = Table::new(rows, constraints).header(header).block(block);
+    f.render_widget(table, area);
+}
+
+/// Matrix cell marker: `●` for a direct grant, `○` for an inherited one.
+fn grant_marker(grant: Option<&CapabilityGrant>) -> &'static str {
+    match grant {
+        Some(CapabilityGrant::Direct) => "●",
+        Some(CapabilityGrant::Inherited { .. }) => "○",
+        None => "",
+    }
+}
+
+/// Render the roles × capabilities matrix.
+///
+/// One row per capability and one column per role; the line below the table
+/// spells out how each role holds the selected capability.
+fn render_matrix(
+    f: &mut Frame,
+    area: Rect,
+    loading: bool,
+    matrix: Option<&RoleCapabilityMatrix>,
+    state: &mut TableState,
+    theme: &Theme,
+    spinner_frame: u8,
+) {
+    let title = if loading {
+        "Role Capability Matrix (Loading...)"
+    } else {
+        "Role Capability Matrix - ● direct ○ inherited - Press 'x' for roles list"
+    };
+
+    let Some(matrix) = render_screen_state_custom(
+        f,
+        area,
+        loading,
+        matrix,
+        title,
+        "Loading role capabilities...",
+        "No role capabilities loaded. Press 'r' to refresh.",
+        spinner_frame,
+        theme,
+    ) else {
+        return;
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "{} - {} roles, {} capabilities",
+            title,
+            matrix.roles.len(),
+            matrix.capabilities.len()
+        ))
+        .border_style(theme.border())
+        .title_style(theme.title());
+
+    if matrix.capabilities.is_empty() {
+        let paragraph = Paragraph::new("No capabilities found.")
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(2)])
+        .split(inner);
+
+    let header = Row::new(
+        std::iter::once("Capability")
+            .chain(matrix.roles.iter().map(String::as_str))
+            .map(|h| Cell::from(h.to_string()).style(theme.table_header())),
+    )
+    .height(1);
+
+    let rows: Vec<Row> = matrix
+        .capabilities
+        .iter()
+        .map(|cap| {
+            let cells = matrix.roles.iter().map(|role| {
+                let grant = matrix.grant(role, cap);
+                let style = match grant {
+                    Some(CapabilityGrant::Direct) => theme.success(),
+                    _ => theme.text_dim(),
+                };
+                Cell::from(grant_marker(grant)).style(style)
+            });
+            Row::new(std::iter::once(Cell::from(cap.clone())).chain(cells)).height(1)
+        })
+        .collect();
+
+    let capability_width = matrix
+        .capabilities
+        .iter()
+        .map(|c| c.len())
+        .max()
+        .unwrap_or(0)
+        .clamp(10, 40) as u16;
+    let constraints: Vec<Constraint> = std::iter::once(Constraint::Length(capability_width))
+        .chain(
+            matrix
+                .roles
+                .iter()
+                .map(|role| Constraint::Length(role.len().clamp(3, 16) as u16)),
+        )
+        .collect();
+
+    let table = Table::new(rows, constraints)
+        .header(header)
+        .row_highlight_style(theme.highlight());
+    f.render_stateful_widget(table, chunks[0], state);
+
+    let detail = state
+        .selected()
+        .and_then(|i| matrix.capabilities.get(i))
+        .map(|cap| {
+            let holders: Vec<String> = matrix
+                .roles
+                .iter()
+                .filter_map(|role| {
+                    matrix
+                        .grant(role, cap)
+                        .map(|grant| format!("{} ({})", role, grant.label()))
+                })
+                .collect();
+            if holders.is_empty() {
+                format!("{cap}: no role")
+            } else {
+                format!("{cap}: {}", holders.join(", "))
+            }
+        })
+        .unwrap_or_default();
+    f.render_widget(
+        Paragraph::new(detail)
+            .style(theme.text_dim())
+            .wrap(ratatui::widgets::Wrap { trim: true }),
+        chunks[1],
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_used_of_treats_unset_and_zero_as_unlimited() {
+        assert_eq!(used_of(3, Some(3)), "3/3");
+        assert_eq!(used_of(2, Some(0)), "2/-");
+        assert_eq!(used_of(120u64, None), "120/-");
+    }
+
+    #[test]
+    fn test_grant_marker_distinguishes_direct_and_inherited() {
+        let inherited = CapabilityGrant::Inherited {
+            via: "user".to_string(),
+        };
+        assert_eq!(grant_marker(Some(&CapabilityGrant::Direct)), "●");
+        assert_eq!(grant_marker(Some(&inherited)), "○");
+        assert_eq!(grant_marker(None), "");
+    }
+}
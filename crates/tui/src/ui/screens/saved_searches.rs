@@ -1,6 +1,9 @@
 //! Saved searches screen rendering.
 //!
-//! Renders the list of Splunk saved searches.
+//! Renders the list of Splunk saved searches, with `[x]` markers once any are
+//! selected for bulk operations.
+
+use std::collections::HashSet;
 
 use ratatui::{
     Frame,
@@ -23,6 +26,8 @@ pub struct SavedSearchesRenderConfig<'a> {
     pub saved_searches: Option<&'a [SavedSearch]>,
     /// The current list selection state
     pub state: &'a mut ListState,
+    /// Names selected for bulk operations
+    pub selected: &'a HashSet<String>,
     /// Theme for consistent styling.
     pub theme: &'a Theme,
     /// Current spinner frame for loading animation
@@ -35,6 +40,7 @@ pub fn render_saved_searches(f: &mut Frame, area: Rect, config: SavedSearchesRen
         loading,
         saved_searches,
         state,
+        selected,
         theme,
         spinner_frame,
     } = config;
@@ -67,7 +73,14 @@ pub fn render_saved_searches(f: &mut Frame, area: Rect, config: SavedSearchesRen
             } else {
                 theme.text()
             };
-            ListItem::new(s.name.clone()).style(style)
+            let label = if selected.is_empty() {
+                s.name.clone()
+            } else if selected.contains(&s.name) {
+                format!("[x] {}", s.name)
+            } else {
+                format!("[ ] {}", s.name)
+            };
+            ListItem::new(label).style(style)
         })
         .collect();
 
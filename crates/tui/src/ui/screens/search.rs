@@ -1,20 +1,31 @@
 //! Search screen rendering.
 //!
 //! Renders the search input, status, and results for running Splunk searches.
-//! Includes real-time SPL validation feedback.
+//! Includes real-time SPL validation feedback, a stats bar summarizing the loaded
+//! results, and the JSON path result filter, whose projected value is shown as a
+//! virtual column above each result. Timechart-shaped results can be shown as a
+//! line chart instead of the JSON list, and any results as a +/- diff against the
+//! previous run. Results whose job artifacts are about to expire get a warning
+//! with the one-key TTL extension hint.
 
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::Style,
+    symbols::Marker,
     text::Line,
-    widgets::{Block, Borders, Gauge, Paragraph},
+    widgets::{Axis, Block, Borders, Chart, Dataset, Gauge, GraphType, LegendPosition, Paragraph},
 };
 
 use crate::app::SplValidationState;
 use crate::app::input::components::SingleLineInput;
+use crate::app::result_chart::ResultChart;
+use crate::app::result_filter::ResultFilter;
+use crate::app::result_stats::ResultStats;
 use crate::theme::Theme;
+use crate::ui::screens::job_details::{TTL_EXTENSION_SECS, format_ttl};
 use splunk_client::SearchMode;
+use splunk_client::workflows::result_diff::{ResultDiff, RowDiff};
 
 use crate::ui::theme::ThemeExt;
 
@@ -38,6 +49,8 @@ pub struct SearchRenderConfig<'a> {
     pub search_results_total_count: Option<usize>,
     /// Whether more results can be loaded
     pub search_has_more_results: bool,
+    /// Summary statistics over the loaded results (shown above them).
+    pub result_stats: &'a ResultStats,
     /// Theme for consistent styling.
     pub theme: &'a Theme,
     /// SPL validation state for real-time feedback.
@@ -46,6 +59,26 @@ pub struct SearchRenderConfig<'a> {
     pub spl_validation_pending: bool,
     /// Current search mode (normal or realtime).
     pub search_mode: SearchMode,
+    /// Whether searches are finalized early at the result threshold.
+    pub auto_finalize: bool,
+    /// Time range new searches run over, shown in the query box title.
+    pub time_range_label: &'a str,
+    /// Active JSON path result filter (scroll offset indexes its matches when set).
+    pub result_filter: Option<&'a ResultFilter>,
+    /// Result filter input (shown while editing).
+    pub result_filter_input: &'a SingleLineInput,
+    /// Whether the result filter box is being edited.
+    pub is_result_filtering: bool,
+    /// Whether the chart view is on (falls back to the list when `chart` is `None`).
+    pub chart_view: bool,
+    /// Chart built from the loaded results, if they are chartable.
+    pub chart: Option<&'a ResultChart>,
+    /// Whether the compare view is on (falls back to the list when `compare` is `None`).
+    pub compare_view: bool,
+    /// Diff of the loaded results against the previous run.
+    pub compare: Option<&'a ResultDiff>,
+    /// Remaining artifact lifetime of the search job, when it is about to expire.
+    pub expiring_ttl: Option<usize>,
 }
 
 /// Render the search screen.
@@ -66,19 +99,35 @@ pub fn render_search(f: &mut Frame, area: Rect, config: SearchRenderConfig) {
         search_scroll_offset,
         search_results_total_count,
         search_has_more_results,
+        result_stats,
         theme,
         spl_validation_state,
         spl_validation_pending,
         search_mode,
+        auto_finalize,
+        time_range_label,
+        result_filter,
+        result_filter_input,
+        is_result_filtering,
+        chart_view,
+        chart,
+        compare_view,
+        compare,
+        expiring_ttl,
     } = config;
 
+    let show_filter = is_result_filtering || result_filter.is_some();
+    let show_stats = !search_results.is_empty();
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints(
             [
-                Constraint::Length(3), // Search input
-                Constraint::Length(3), // Status
-                Constraint::Min(0),    // Results
+                Constraint::Length(3),                                 // Search input
+                Constraint::Length(3),                                 // Status
+                Constraint::Length(if show_filter { 3 } else { 0 }),   // Result filter
+                Constraint::Length(if show_stats { 1 } else { 0 }),    // Stats bar
+                Constraint::Length(u16::from(expiring_ttl.is_some())), // TTL warning
+                Constraint::Min(0),                                    // Results
             ]
             .as_ref(),
         )
@@ -103,10 +152,12 @@ pub fn render_search(f: &mut Frame, area: Rect, config: SearchRenderConfig) {
         }
     };
 
-    // Build input title with realtime indicator and validation status
-    let mode_indicator = match search_mode {
-        SearchMode::Realtime => "[RT] ",
-        SearchMode::Normal => "",
+    // Build input title with realtime/auto-finalize indicators and validation status
+    let mode_indicator = match (search_mode, auto_finalize) {
+        (SearchMode::Realtime, true) => "[RT] [AF] ",
+        (SearchMode::Realtime, false) => "[RT] ",
+        (SearchMode::Normal, true) => "[AF] ",
+        (SearchMode::Normal, false) => "",
     };
     let input_value = search_input.value();
     let input_title = if input_value.len() < 3 {
@@ -120,6 +171,7 @@ pub fn render_search(f: &mut Frame, area: Rect, config: SearchRenderConfig) {
         Block::default()
             .borders(Borders::ALL)
             .title(input_title)
+            .title(Line::from(format!(" {} ", time_range_label)).right_aligned())
             .border_style(Style::default().fg(border_color))
             .title_style(Style::default().fg(border_color)),
     );
@@ -197,8 +249,58 @@ pub fn render_search(f: &mut Frame, area: Rect, config: SearchRenderConfig) {
         f.render_widget(status, chunks[1]);
     }
 
+    if show_filter {
+        render_result_filter(
+            f,
+            chunks[2],
+            result_filter,
+            result_filter_input,
+            is_result_filtering,
+            theme,
+        );
+    }
+
+    if show_stats {
+        let stats = Paragraph::new(format!(
+            " {}",
+            result_stats.summary(search_results.len(), search_results_total_count)
+        ))
+        .style(theme.text_dim());
+        f.render_widget(stats, chunks[3]);
+    }
+
+    if let Some(ttl) = expiring_ttl {
+        let warning = Paragraph::new(format!(
+            " Artifacts expire in {} - press T to extend TTL to {}h",
+            format_ttl(ttl),
+            TTL_EXTENSION_SECS / 3600
+        ))
+        .style(theme.warning());
+        f.render_widget(warning, chunks[4]);
+    }
+
+    if chart_view && let Some(chart) = chart {
+        render_result_chart(f, chunks[5], chart, theme);
+        return;
+    }
+
+    if compare_view && let Some(diff) = compare {
+        render_result_diff(f, chunks[5], diff, search_scroll_offset, theme);
+        return;
+    }
+
     // Calculate actual viewport height from available area
-    let available_height = chunks[2].height.saturating_sub(2) as usize; // Account for borders
+    let available_height = chunks[5].height.saturating_sub(2) as usize; // Account for borders
+
+    // Positions in the current view map to indices into `search_results`
+    let visible_count = result_filter.map_or(search_results.len(), |filter| filter.matched().len());
+    let result_at = |position: usize| match result_filter {
+        Some(filter) => filter
+            .matched()
+            .get(position)
+            .and_then(|&idx| search_results.get(idx)),
+        None => search_results.get(position),
+    };
 
     // Results
     if search_results.is_empty() {
@@ -211,31 +313,62 @@ pub fn render_search(f: &mut Frame, area: Rect, config: SearchRenderConfig) {
                     .title_style(theme.title()),
             )
             .alignment(Alignment::Center);
-        f.render_widget(placeholder, chunks[2]);
+        f.render_widget(placeholder, chunks[5]);
+    } else if visible_count == 0 {
+        let placeholder = Paragraph::new(format!(
+            "None of the {} loaded results match the filter.",
+            search_results.len()
+        ))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(filtered_title(result_filter, 0, 0, 0, search_results.len()))
+                .border_style(theme.border())
+                .title_style(theme.title()),
+        )
+        .alignment(Alignment::Center);
+        f.render_widget(placeholder, chunks[5]);
     } else {
         // Virtualization: Only format and render visible results
-        let visible_end = (search_scroll_offset + available_height).min(search_results.len());
-
-        let results_text: Vec<Line> = search_results
-            .iter()
-            .enumerate()
-            .skip(search_scroll_offset)
-            .take_while(|(i, _)| *i < visible_end)
-            .flat_map(|(_, v)| {
+        let visible_end = (search_scroll_offset + available_height).min(visible_count);
+
+        let results_text: Vec<Line> = (search_scroll_offset..visible_end)
+            .filter_map(result_at)
+            .flat_map(|v| {
+                // Virtual column: the filter path projected from this result
+                let column = result_filter.map(|filter| {
+                    Line::styled(
+                        format!(
+                            "▸ {} = {}",
+                            filter.column(),
+                            filter.project(v).unwrap_or_else(|| "(missing)".to_string())
+                        ),
+                        theme.info(),
+                    )
+                });
+
                 // Format each result on-demand
                 let formatted =
                     serde_json::to_string_pretty(v).unwrap_or_else(|_| "<invalid>".to_string());
 
                 // Split multi-line JSON into separate Lines
-                formatted
-                    .lines()
-                    .map(|line| Line::from(line.to_string()))
+                column
+                    .into_iter()
+                    .chain(formatted.lines().map(|line| Line::from(line.to_string())))
                     .collect::<Vec<_>>()
             })
             .collect();
 
         // Build title with pagination info
-        let title = if let Some(total) = search_results_total_count {
+        let title = if result_filter.is_some() {
+            filtered_title(
+                result_filter,
+                search_scroll_offset + 1,
+                visible_end,
+                visible_count,
+                search_results.len(),
+            )
+        } else if let Some(total) = search_results_total_count {
             if search_has_more_results {
                 format!(
                     "Results ({}-{} / {} total, loading...)",
@@ -254,6 +387,11 @@ pub fn render_search(f: &mut Frame, area: Rect, config: SearchRenderConfig) {
         } else {
             format!("Results ({} loaded)", search_results.len())
         };
+        let title = if chart_view {
+            format!("{} - not chartable", title)
+        } else {
+            title
+        };
 
         let results = Paragraph::new(results_text).block(
             Block::default()
@@ -262,7 +400,201 @@ pub fn render_search(f: &mut Frame, area: Rect, config: SearchRenderConfig) {
                 .border_style(theme.border())
                 .title_style(theme.title()),
         );
-        f.render_widget(results, chunks[2]);
+        f.render_widget(results, chunks[5]);
+    }
+}
+
+/// Render chartable results as one line per numeric field over `_time`.
+fn render_result_chart(f: &mut Frame, area: Rect, chart: &ResultChart, theme: &Theme) {
+    let palette = [
+        theme.info,
+        theme.success,
+        theme.warning,
+        theme.accent,
+        theme.error,
+        theme.syntax_function,
+        theme.syntax_string,
+        theme.syntax_command,
+    ];
+    let datasets: Vec<Dataset> = chart
+        .series
+        .iter()
+        .zip(palette.iter().cycle())
+        .map(|(series, &color)| {
+            Dataset::default()
+                .name(series.name.clone())
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(color))
+                .data(&series.points)
+        })
+        .collect();
+
+    let mut title = format!("Chart ({} series, v for list)", chart.series.len());
+    if chart.omitted_series > 0 {
+        title = format!("{} - {} more not shown", title, chart.omitted_series);
+    }
+
+    let widget = Chart::new(datasets)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(theme.border())
+                .title_style(theme.title()),
+        )
+        .x_axis(
+            Axis::default()
+                .title("_time")
+                .style(theme.text_dim())
+                .bounds(chart.x_bounds())
+                .labels(chart.x_labels()),
+        )
+        .y_axis(
+            Axis::default()
+                .style(theme.text_dim())
+                .bounds(chart.y_bounds())
+                .labels(chart.y_labels()),
+        )
+        .legend_position(Some(LegendPosition::TopLeft))
+        // Always show the legend; series names are the only way to tell lines apart
+        .hidden_legend_constraints((Constraint::Percentage(100), Constraint::Percentage(100)));
+    f.render_widget(widget, area);
+}
+
+/// Render the diff against the previous run, one colored row per line from `scroll_offset`.
+fn render_result_diff(
+    f: &mut Frame,
+    area: Rect,
+    diff: &ResultDiff,
+    scroll_offset: usize,
+    theme: &Theme,
+) {
+    let available_height = area.height.saturating_sub(2) as usize;
+    let start = scroll_offset.min(diff.rows.len().saturating_sub(1));
+    let lines: Vec<Line> = diff
+        .rows
+        .iter()
+        .skip(start)
+        .flat_map(|row| diff_lines(row, theme))
+        .take(available_height)
+        .collect();
+
+    let summary = diff.summary;
+    let title = format!(
+        "Compare with previous run (+{} -{} ~{}, {} unchanged; c for list)",
+        summary.added, summary.removed, summary.changed, summary.unchanged
+    );
+    let widget = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(theme.border())
+            .title_style(theme.title()),
+    );
+    f.render_widget(widget, area);
+}
+
+/// Lines for one diff row: the marked row, plus `field: before → after` for changed rows.
+fn diff_lines(row: &RowDiff, theme: &Theme) -> Vec<Line<'static>> {
+    let compact = |value: &serde_json::Value| serde_json::to_string(value).unwrap_or_default();
+    let style = match row {
+        RowDiff::Added { .. } => theme.success(),
+        RowDiff::Removed { .. } => theme.error(),
+        RowDiff::Changed { .. } => theme.warning(),
+        RowDiff::Unchanged { .. } => theme.text_dim(),
+    };
+    let mut lines = vec![Line::styled(
+        format!("{} {}", row.marker(), compact(row.row())),
+        style,
+    )];
+    if let RowDiff::Changed {
+        before,
+        after,
+        fields,
+    } = row
+    {
+        let value = |row: &serde_json::Value, field: &str| {
+            row.get(field)
+                .map_or_else(|| "(missing)".to_string(), compact)
+        };
+        lines.extend(fields.iter().map(|field| {
+            Line::styled(
+                format!(
+                    "    {}: {} → {}",
+                    field,
+                    value(before, field),
+                    value(after, field)
+                ),
+                style,
+            )
+        }));
+    }
+    lines
+}
+
+/// Title for the results block while a result filter is active.
+fn filtered_title(
+    result_filter: Option<&ResultFilter>,
+    start: usize,
+    end: usize,
+    matched: usize,
+    loaded: usize,
+) -> String {
+    let expression = result_filter.map(ResultFilter::source).unwrap_or_default();
+    if matched == 0 {
+        format!("Results (0 of {} loaded match `{}`)", loaded, expression)
+    } else {
+        format!(
+            "Results ({}-{} / {} of {} loaded match `{}`)",
+            start, end, matched, loaded, expression
+        )
+    }
+}
+
+/// Render the result filter box (input while editing, active expression otherwise).
+fn render_result_filter(
+    f: &mut Frame,
+    area: Rect,
+    result_filter: Option<&ResultFilter>,
+    input: &SingleLineInput,
+    is_editing: bool,
+    theme: &Theme,
+) {
+    let text = if is_editing {
+        format!("{} (Enter to apply, Esc to cancel)", input.value())
+    } else if let Some(filter) = result_filter {
+        format!(
+            "{} (/ to edit, apply an empty filter to clear)",
+            filter.source()
+        )
+    } else {
+        String::new()
+    };
+
+    let paragraph = Paragraph::new(text).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("JSON Path Filter (e.g. payload.user.id == 42)")
+            .border_style(if is_editing {
+                theme.border_focused()
+            } else {
+                theme.border()
+            })
+            .title_style(theme.title()),
+    );
+    f.render_widget(paragraph, area);
+
+    if is_editing {
+        let prefix: String = input
+            .value()
+            .chars()
+            .take(input.cursor_position())
+            .collect();
+        let cursor_x = area.x + 1 + prefix.chars().count() as u16;
+        if cursor_x < area.x + area.width.saturating_sub(1) {
+            f.set_cursor_position(ratatui::layout::Position::new(cursor_x, area.y + 1));
+        }
     }
 }
 
@@ -284,10 +616,21 @@ mod tests {
             search_scroll_offset: 0,
             search_results_total_count: None,
             search_has_more_results: false,
+            result_stats: &ResultStats::default(),
             theme: &Theme::default(),
             spl_validation_state: &SplValidationState::default(),
             spl_validation_pending: false,
             search_mode: SearchMode::Normal,
+            auto_finalize: false,
+            time_range_label: "Last 24 hours",
+            result_filter: None,
+            result_filter_input: &input,
+            is_result_filtering: false,
+            chart_view: false,
+            chart: None,
+            compare_view: false,
+            compare: None,
+            expiring_ttl: None,
         };
 
         assert_eq!(config.search_input.value(), "index=_internal");
@@ -315,13 +658,216 @@ mod tests {
                         search_scroll_offset: 0,
                         search_results_total_count: None,
                         search_has_more_results: false,
+                        result_stats: &ResultStats::default(),
+                        theme: &Theme::default(),
+                        spl_validation_state: &SplValidationState::default(),
+                        spl_validation_pending: false,
+                        search_mode: SearchMode::Normal,
+                        auto_finalize: false,
+                        time_range_label: "Last 24 hours",
+                        result_filter: None,
+                        result_filter_input: &input,
+                        is_result_filtering: false,
+                        chart_view: false,
+                        chart: None,
+                        compare_view: false,
+                        compare: None,
+                        expiring_ttl: None,
+                    },
+                );
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_render_search_shows_stats_bar_above_results() {
+        let backend = TestBackend::new(100, 24);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let input = SingleLineInput::with_value("index=main");
+        let results = vec![
+            serde_json::json!({"_time": "2026-02-22T15:00:00.000+00:00", "host": "web-01"}),
+            serde_json::json!({"_time": "2026-02-22T15:05:00.000+00:00", "host": "web-02"}),
+        ];
+        let mut stats = ResultStats::default();
+        stats.update(&results);
+
+        terminal
+            .draw(|f| {
+                render_search(
+                    f,
+                    f.area(),
+                    SearchRenderConfig {
+                        search_input: &input,
+                        is_query_focused: false,
+                        search_status: "Search complete",
+                        loading: false,
+                        progress: 1.0,
+                        search_results: &results,
+                        search_scroll_offset: 0,
+                        search_results_total_count: Some(50),
+                        search_has_more_results: true,
+                        result_stats: &stats,
+                        theme: &Theme::default(),
+                        spl_validation_state: &SplValidationState::default(),
+                        spl_validation_pending: false,
+                        search_mode: SearchMode::Normal,
+                        auto_finalize: false,
+                        time_range_label: "Last 24 hours",
+                        result_filter: None,
+                        result_filter_input: &input,
+                        is_result_filtering: false,
+                        chart_view: false,
+                        chart: None,
+                        compare_view: false,
+                        compare: None,
+                        expiring_ttl: None,
+                    },
+                );
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let stats_row: String = (0..buffer.area.width)
+            .map(|x| buffer[(x, 6)].symbol())
+            .collect();
+        assert!(
+            stats_row.contains(
+                "2 of 50 loaded │ 2026-02-22 15:00:00 → 15:05:00 (span 5m 0s) │ 2 fields"
+            ),
+            "unexpected stats row: {stats_row}"
+        );
+    }
+
+    #[test]
+    fn test_render_search_chart_view_shows_legend_and_axis_labels() {
+        let backend = TestBackend::new(100, 30);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let input = SingleLineInput::with_value("index=main | timechart count by host");
+        let results = vec![
+            serde_json::json!({"_time": "2026-02-22T15:00:00.000+00:00", "web-01": "3", "web-02": "7"}),
+            serde_json::json!({"_time": "2026-02-22T16:00:00.000+00:00", "web-01": "9", "web-02": "1"}),
+        ];
+        let chart = ResultChart::from_results(&results).expect("chartable");
+
+        terminal
+            .draw(|f| {
+                render_search(
+                    f,
+                    f.area(),
+                    SearchRenderConfig {
+                        search_input: &input,
+                        is_query_focused: false,
+                        search_status: "Search complete",
+                        loading: false,
+                        progress: 1.0,
+                        search_results: &results,
+                        search_scroll_offset: 0,
+                        search_results_total_count: Some(2),
+                        search_has_more_results: false,
+                        result_stats: &ResultStats::default(),
                         theme: &Theme::default(),
                         spl_validation_state: &SplValidationState::default(),
                         spl_validation_pending: false,
                         search_mode: SearchMode::Normal,
+                        auto_finalize: false,
+                        time_range_label: "Last 24 hours",
+                        result_filter: None,
+                        result_filter_input: &input,
+                        is_result_filtering: false,
+                        chart_view: true,
+                        chart: Some(&chart),
+                        compare_view: false,
+                        compare: None,
+                        expiring_ttl: None,
                     },
                 );
             })
             .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let screen: String = (0..buffer.area.height)
+            .flat_map(|y| (0..buffer.area.width).map(move |x| (x, y)))
+            .map(|pos| buffer[pos].symbol())
+            .collect();
+        for expected in [
+            "Chart (2 series",
+            "web-01",
+            "web-02",
+            "15:00:00",
+            "16:00:00",
+        ] {
+            assert!(screen.contains(expected), "missing {expected:?}");
+        }
+        assert!(
+            !screen.contains("\"_time\""),
+            "JSON list should be replaced"
+        );
+    }
+
+    #[test]
+    fn test_render_search_compare_view_marks_rows() {
+        let backend = TestBackend::new(100, 30);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let input = SingleLineInput::with_value("index=main | stats count by host");
+        let baseline = vec![
+            serde_json::json!({"host": "web-01", "count": "3"}),
+            serde_json::json!({"host": "web-02", "count": "7"}),
+        ];
+        let results = vec![
+            serde_json::json!({"host": "web-01", "count": "4"}),
+            serde_json::json!({"host": "web-03", "count": "1"}),
+        ];
+        let diff = splunk_client::workflows::result_diff::diff_results(&baseline, &results, &[]);
+
+        terminal
+            .draw(|f| {
+                render_search(
+                    f,
+                    f.area(),
+                    SearchRenderConfig {
+                        search_input: &input,
+                        is_query_focused: false,
+                        search_status: "Search complete",
+                        loading: false,
+                        progress: 1.0,
+                        search_results: &results,
+                        search_scroll_offset: 0,
+                        search_results_total_count: Some(2),
+                        search_has_more_results: false,
+                        result_stats: &ResultStats::default(),
+                        theme: &Theme::default(),
+                        spl_validation_state: &SplValidationState::default(),
+                        spl_validation_pending: false,
+                        search_mode: SearchMode::Normal,
+                        auto_finalize: false,
+                        time_range_label: "Last 24 hours",
+                        result_filter: None,
+                        result_filter_input: &input,
+                        is_result_filtering: false,
+                        chart_view: false,
+                        chart: None,
+                        compare_view: true,
+                        compare: Some(&diff),
+                        expiring_ttl: None,
+                    },
+                );
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let rows: Vec<String> = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect()
+            })
+            .collect();
+        let screen = rows.join("\n");
+        assert!(screen.contains("Compare with previous run (+1 -1 ~1, 0 unchanged"));
+        assert!(screen.contains("count: \"3\" → \"4\""));
+        let removed = rows.iter().position(|row| row.contains("│- {")).unwrap();
+        assert!(rows[removed].contains("web-02"));
+        let added = rows.iter().position(|row| row.contains("│+ {")).unwrap();
+        assert!(rows[added].contains("web-03"));
     }
 }
@@ -81,7 +81,7 @@ pub fn render_search_peers(f: &mut Frame, area: Rect, config: SearchPeersRenderC
                 Cell::from(peer.name.clone()),
                 Cell::from(peer.host.clone()),
                 Cell::from(peer.port.to_string()),
-                Cell::from(peer.status.to_string()).style(status_style),
+                Cell::from(theme.status_text(&peer.status.to_string())).style(status_style),
                 Cell::from(peer.version.as_deref().unwrap_or("N/A")),
             ];
             Row::new(cells).height(1)
@@ -25,6 +25,8 @@ use ratatui::{
 pub struct SettingsRenderConfig<'a> {
     /// Current auto-refresh state
     pub auto_refresh: bool,
+    /// Whether sourcetype result renderers are enabled
+    pub result_renderers: bool,
     /// Current sort column
     pub sort_column: &'a str,
     /// Current sort direction
@@ -33,8 +35,14 @@ pub struct SettingsRenderConfig<'a> {
     pub search_history_count: usize,
     /// Current profile name (if SPLUNK_PROFILE is set)
     pub profile_info: Option<&'a str>,
-    /// Selected persisted theme (for display).
-    pub selected_theme: splunk_config::ColorTheme,
+    /// Display name of the active theme (built-in or user theme file).
+    pub theme_name: &'a str,
+    /// How copy actions deliver text.
+    pub clipboard_backend: splunk_config::ClipboardBackend,
+    /// Where finished-search and health notifications go.
+    pub notifications: &'a splunk_config::NotificationSettings,
+    /// Detected terminal color depth.
+    pub color_support: crate::theme::ColorSupport,
     /// Runtime expanded theme (for colors).
     pub theme: &'a crate::theme::Theme,
     /// Default earliest time for searches (e.g., "-24h").
@@ -47,14 +55,102 @@ pub struct SettingsRenderConfig<'a> {
     pub internal_logs_count: usize,
     /// Default earliest time for internal logs queries.
     pub internal_logs_earliest: &'a str,
+    /// Anomaly highlighting thresholds for the internal logs view.
+    pub log_anomalies: &'a splunk_config::LogAnomalySettings,
+    /// Health check thresholds in effect.
+    pub health_thresholds: &'a splunk_config::HealthThresholds,
+    /// Whether `health_thresholds` come from the active profile rather than Settings.
+    pub health_thresholds_from_profile: bool,
 }
 
-fn settings_shortcut_rows() -> [&'static str; 4] {
+fn settings_shortcut_rows() -> [&'static str; 5] {
     [
-        "t:Diagnostics  T:Theme  a:Auto-refresh",
-        "s:Sort column  d:Direction  c:Clear history",
+        "t:Diagnostics  T:Theme  C:No color  b:Clipboard  a:Auto-refresh",
+        "s:Sort column  d:Direction  c:Clear history  v:Renderers",
+        "h:Log highlights  m:Spike threshold  g:Repeat threshold",
         "p:Switch profile  n:Create  e:Edit  x:Delete",
-        "u:Undo history  ?:Replay tutorial",
+        "u:Undo history  N:Notifications  H:Health thresholds  ?:Replay tutorial",
+    ]
+}
+
+/// One-line description of the notification target and when it fires.
+fn notifications_text(settings: &splunk_config::NotificationSettings) -> String {
+    use splunk_config::NotificationTarget;
+    let target = match (settings.target, settings.command()) {
+        (NotificationTarget::Off, _) => return "Off".to_string(),
+        (NotificationTarget::Command, Some(command)) => format!("Command ({})", command),
+        (target, _) => target.to_string(),
+    };
+    format!(
+        "{}, searches over {}s and health turning red",
+        target, settings.min_search_secs
+    )
+}
+
+/// One-line description of the log anomaly thresholds.
+fn log_anomalies_text(settings: &splunk_config::LogAnomalySettings) -> String {
+    if !settings.enabled {
+        return "Off".to_string();
+    }
+    format!(
+        "spikes at {}x baseline (min {}/min), repeats at x{}",
+        settings.spike_multiplier, settings.spike_min_count, settings.repeat_threshold
+    )
+}
+
+/// One-line description of the health check thresholds.
+fn health_thresholds_text(
+    thresholds: &splunk_config::HealthThresholds,
+    from_profile: bool,
+) -> String {
+    format!(
+        "license {}/{}%, KVStore lag {}s, min peers {}, disk {}/{}%{}",
+        thresholds.license_warning_pct,
+        thresholds.license_critical_pct,
+        thresholds.kvstore_max_replication_lag_secs,
+        thresholds.min_searchable_peers,
+        thresholds.disk_warning_pct,
+        thresholds.disk_critical_pct,
+        if from_profile { " (from profile)" } else { "" }
+    )
+}
+
+/// Preview of the active theme: severity, header health, table header, and selection colors.
+fn theme_preview_lines(theme: &crate::theme::Theme) -> [Line<'static>; 2] {
+    let swatch = |color, label: &'static str| {
+        [
+            Span::styled("█", Style::default().fg(color)),
+            Span::styled(label, theme.text_dim()),
+        ]
+    };
+    // Indent to align with the theme value
+    let indent = || Span::styled("                ", theme.text());
+
+    let severity = [
+        swatch(theme.success, " success  "),
+        swatch(theme.warning, " warning  "),
+        swatch(theme.error, " error  "),
+        swatch(theme.info, " info"),
+    ];
+    let chrome = [
+        Span::styled("●", Style::default().fg(theme.health_healthy)),
+        Span::styled("●", Style::default().fg(theme.health_unhealthy)),
+        Span::styled("●", Style::default().fg(theme.health_unknown)),
+        Span::styled(" header  ", theme.text_dim()),
+        Span::styled(" Table ", theme.table_header()),
+        Span::raw("  "),
+        Span::styled(" Selected ", theme.highlight()),
+        Span::raw("  "),
+        Span::styled("Border", theme.border()),
+    ];
+
+    [
+        Line::from(
+            std::iter::once(indent())
+                .chain(severity.into_iter().flatten())
+                .collect::<Vec<_>>(),
+        ),
+        Line::from(std::iter::once(indent()).chain(chrome).collect::<Vec<_>>()),
     ]
 }
 
@@ -96,6 +192,10 @@ pub fn render_settings(f: &mut Frame, area: Rect, config: SettingsRenderConfig)
 
     let auto_refresh_text = format!("[{}]", if config.auto_refresh { "On" } else { "Off" });
 
+    let renderers_text = format!("[{}]", if config.result_renderers { "On" } else { "Off" });
+
+    let no_color_text = format!("[{}]", if theme.no_color { "On" } else { "Off" });
+
     let profile_display = config.profile_info.unwrap_or("N/A");
 
     let shortcut_rows = settings_shortcut_rows();
@@ -104,7 +204,11 @@ pub fn render_settings(f: &mut Frame, area: Rect, config: SettingsRenderConfig)
     // Content lines - using ThemeExt for consistent styling.
     let mut content = vec![Line::from(vec![
         Span::styled("Theme:          ", theme.title()),
-        Span::styled(config.selected_theme.to_string(), theme.text()),
+        Span::styled(config.theme_name, theme.text()),
+        Span::styled(
+            format!("  ({})", config.color_support.label()),
+            theme.text_dim(),
+        ),
     ])];
 
     if compact_layout {
@@ -121,6 +225,8 @@ pub fn render_settings(f: &mut Frame, area: Rect, config: SettingsRenderConfig)
             Span::styled(profile_display, theme.text()),
             Span::styled("  History: ", theme.title()),
             Span::styled(format!("{}", config.search_history_count), theme.text()),
+            Span::styled("  Renderers: ", theme.title()),
+            Span::styled(&renderers_text, theme.text()),
         ]));
         content.push(Line::from(""));
         content.push(Line::from(vec![
@@ -143,19 +249,22 @@ pub fn render_settings(f: &mut Frame, area: Rect, config: SettingsRenderConfig)
                 theme.text(),
             ),
         ]));
-    } else {
-        // Theme preview showing key semantic colors
         content.push(Line::from(vec![
-            Span::styled("                ", theme.text()), // Indent to align with theme value
-            Span::styled("█", Style::default().fg(theme.success)),
-            Span::styled(" success  ", theme.text_dim()),
-            Span::styled("█", Style::default().fg(theme.warning)),
-            Span::styled(" warning  ", theme.text_dim()),
-            Span::styled("█", Style::default().fg(theme.error)),
-            Span::styled(" error  ", theme.text_dim()),
-            Span::styled("█", Style::default().fg(theme.info)),
-            Span::styled(" info", theme.text_dim()),
+            Span::styled("Log highlights:  ", theme.title()),
+            Span::styled(log_anomalies_text(config.log_anomalies), theme.text()),
+        ]));
+        content.push(Line::from(vec![
+            Span::styled("Health:          ", theme.title()),
+            Span::styled(
+                health_thresholds_text(
+                    config.health_thresholds,
+                    config.health_thresholds_from_profile,
+                ),
+                theme.text(),
+            ),
         ]));
+    } else {
+        content.extend(theme_preview_lines(theme));
         content.push(Line::from(vec![
             Span::styled("Auto-refresh:   ", theme.title()),
             Span::styled(&auto_refresh_text, auto_refresh_style),
@@ -168,6 +277,22 @@ pub fn render_settings(f: &mut Frame, area: Rect, config: SettingsRenderConfig)
             Span::styled("Sort direction: ", theme.title()),
             Span::styled(config.sort_direction, theme.text()),
         ]));
+        content.push(Line::from(vec![
+            Span::styled("Renderers:      ", theme.title()),
+            Span::styled(&renderers_text, theme.text()),
+        ]));
+        content.push(Line::from(vec![
+            Span::styled("No-color:       ", theme.title()),
+            Span::styled(&no_color_text, theme.text()),
+        ]));
+        content.push(Line::from(vec![
+            Span::styled("Clipboard:      ", theme.title()),
+            Span::styled(config.clipboard_backend.display_name(), theme.text()),
+        ]));
+        content.push(Line::from(vec![
+            Span::styled("Notifications:  ", theme.title()),
+            Span::styled(notifications_text(config.notifications), theme.text()),
+        ]));
         content.push(Line::from(vec![
             Span::styled("Search history: ", theme.title()),
             Span::styled(
@@ -209,6 +334,25 @@ pub fn render_settings(f: &mut Frame, area: Rect, config: SettingsRenderConfig)
             Span::styled("  Earliest time: ", theme.title()),
             Span::styled(config.internal_logs_earliest, theme.text()),
         ]));
+        content.push(Line::from(vec![
+            Span::styled("  Highlights:    ", theme.title()),
+            Span::styled(log_anomalies_text(config.log_anomalies), theme.text()),
+        ]));
+        content.push(Line::from(""));
+        content.push(Line::from(vec![Span::styled(
+            "Health Thresholds",
+            theme.title(),
+        )]));
+        content.push(Line::from(vec![
+            Span::styled("  ", theme.title()),
+            Span::styled(
+                health_thresholds_text(
+                    config.health_thresholds,
+                    config.health_thresholds_from_profile,
+                ),
+                theme.text(),
+            ),
+        ]));
     }
 
     content.push(Line::from(""));
@@ -229,6 +373,10 @@ pub fn render_settings(f: &mut Frame, area: Rect, config: SettingsRenderConfig)
         shortcut_rows[3],
         theme.text_dim(),
     )]));
+    content.push(Line::from(vec![Span::styled(
+        shortcut_rows[4],
+        theme.text_dim(),
+    )]));
 
     f.render_widget(
         Paragraph::new(content)
@@ -254,6 +402,9 @@ mod tests {
 
         assert!(joined.contains("t:Diagnostics"));
         assert!(joined.contains("T:Theme"));
+        assert!(joined.contains("C:No color"));
+        assert!(joined.contains("v:Renderers"));
+        assert!(joined.contains("h:Log highlights"));
         assert!(joined.contains("u:Undo history"));
         assert!(joined.contains("?:Replay tutorial"));
     }
@@ -184,7 +184,7 @@ fn render_members(
 
             let cells = vec![
                 Cell::from(host_text),
-                Cell::from(member.status.to_string()).style(status_style),
+                Cell::from(theme.status_text(&member.status.to_string())).style(status_style),
                 Cell::from(if member.is_captain { "Yes" } else { "" }),
                 Cell::from(member.port.to_string()),
                 Cell::from(member.site.clone().unwrap_or_default()),
@@ -0,0 +1,163 @@
+//! Sourcetypes screen for the TUI.
+//!
+//! Responsibilities:
+//! - Render the sourcetype inventory with event counts, first/last seen
+//!   times, and the indexes each sourcetype appears in
+//! - Display loading state and empty state
+//!
+//! Does NOT handle:
+//! - Data fetching (handled by side effects)
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Rect},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+use splunk_client::MetadataEntry;
+
+use crate::theme::Theme;
+use crate::ui::theme::ThemeExt;
+use crate::ui::widgets::render_screen_state;
+
+/// Configuration for rendering the sourcetypes screen.
+pub struct SourcetypesRenderConfig<'a> {
+    /// Whether data is currently loading.
+    pub loading: bool,
+    /// Sourcetypes ordered by name.
+    pub sourcetypes: Option<&'a [MetadataEntry]>,
+    /// The table state for selection.
+    pub state: &'a mut TableState,
+    /// The theme to use for styling.
+    pub theme: &'a Theme,
+    /// Current spinner frame for loading animation
+    pub spinner_frame: u8,
+}
+
+/// Render the sourcetypes screen.
+pub fn render_sourcetypes(f: &mut Frame, area: Rect, config: SourcetypesRenderConfig) {
+    let SourcetypesRenderConfig {
+        loading,
+        sourcetypes,
+        state,
+        theme,
+        spinner_frame,
+    } = config;
+
+    let Some(sourcetypes) = render_screen_state(
+        f,
+        area,
+        loading,
+        sourcetypes,
+        "Sourcetypes",
+        "Loading sourcetypes...",
+        "sourcetypes",
+        spinner_frame,
+        theme,
+    ) else {
+        return;
+    };
+
+    let title = format!("Sourcetypes ({})", sourcetypes.len());
+
+    if sourcetypes.is_empty() {
+        let empty = Paragraph::new("No sourcetypes have been indexed.")
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .alignment(Alignment::Center);
+        f.render_widget(empty, area);
+        return;
+    }
+
+    let header_cells = ["Sourcetype", "Events", "First Seen", "Last Seen", "Indexes"]
+        .into_iter()
+        .map(|h| Cell::from(h).style(theme.table_header()));
+    let header = Row::new(header_cells).height(1);
+
+    let rows: Vec<Row> = sourcetypes
+        .iter()
+        .map(|entry| {
+            Row::new(vec![
+                Cell::from(entry.name.clone()),
+                Cell::from(entry.total_count.to_string()),
+                Cell::from(format_time(entry.first_time)),
+                Cell::from(format_time(entry.last_time)),
+                Cell::from(entry.indexes.join(", ")),
+            ])
+            .height(1)
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(28),
+            Constraint::Percentage(12),
+            Constraint::Percentage(18),
+            Constraint::Percentage(18),
+            Constraint::Percentage(24),
+        ],
+    )
+    .header(header)
+    .block(Block::default().borders(Borders::ALL).title(title))
+    .row_highlight_style(theme.highlight())
+    .highlight_symbol("> ");
+
+    f.render_stateful_widget(table, area, state);
+}
+
+/// Epoch seconds as a UTC date and time, or `-` when unknown.
+fn format_time(epoch: Option<u64>) -> String {
+    epoch
+        .and_then(|t| chrono::DateTime::from_timestamp(t as i64, 0))
+        .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+
+    #[test]
+    fn test_render_sourcetypes_shows_times_and_indexes() {
+        let sourcetypes = vec![MetadataEntry {
+            name: "access_combined".to_string(),
+            total_count: 1_250_000,
+            first_time: Some(1_690_000_000),
+            last_time: Some(1_700_086_400),
+            recent_time: Some(1_700_086_405),
+            indexes: vec!["main".to_string(), "web".to_string()],
+        }];
+        let mut state = TableState::default();
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(120, 6)).unwrap();
+
+        terminal
+            .draw(|f| {
+                render_sourcetypes(
+                    f,
+                    f.area(),
+                    SourcetypesRenderConfig {
+                        loading: false,
+                        sourcetypes: Some(&sourcetypes),
+                        state: &mut state,
+                        theme: &Theme::default(),
+                        spinner_frame: 0,
+                    },
+                );
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let screen: String = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(screen.contains("Sourcetypes (1)"), "{screen}");
+        assert!(screen.contains("1250000"), "{screen}");
+        assert!(screen.contains("2023-11-15 22:13"), "{screen}");
+        assert!(screen.contains("main, web"), "{screen}");
+    }
+}
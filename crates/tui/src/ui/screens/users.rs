@@ -1,6 +1,7 @@
 //! Users screen rendering.
 //!
-//! Renders the list of Splunk users with their roles and last login times.
+//! Renders the list of Splunk users with their roles, last login times, and
+//! lockout state.
 
 use crate::theme::Theme;
 use ratatui::{
@@ -72,10 +73,19 @@ pub fn render_users(f: &mut Frame, area: Rect, config: UsersRenderConfig) {
                 .map(format_last_login)
                 .unwrap_or_else(|| String::from("never"));
 
-            ListItem::new(format!(
-                "{} ({}) - Roles: {} - Last login: {}",
-                name, realname, roles, last_login
-            ))
+            let item = ListItem::new(format!(
+                "{}{} ({}) - Roles: {} - Last login: {}",
+                if user.locked_out { "[LOCKED] " } else { "" },
+                name,
+                realname,
+                roles,
+                last_login
+            ));
+            if user.locked_out {
+                item.style(theme.error())
+            } else {
+                item
+            }
         })
         .collect();
 
@@ -0,0 +1,152 @@
+//! Server messages (bulletin board) popup rendering.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+use splunk_client::{ServerMessage, ServerMessageSeverity};
+
+use crate::app::App;
+use crate::app::server_messages::ServerMessages;
+use crate::theme::Theme;
+use crate::ui::theme::ThemeExt;
+
+/// Render the server messages popup.
+///
+/// Shows the bulletin board messages, most severe first, with the full text
+/// of the selected message below the list.
+pub fn render_server_messages(f: &mut Frame, app: &App, theme: &Theme) {
+    let state = &app.server_messages;
+
+    let area = f.area();
+    let popup_width = 100.min(area.width.saturating_sub(4));
+    let popup_height = 24.min(area.height.saturating_sub(4));
+
+    let x = (area.width.saturating_sub(popup_width)) / 2;
+    let y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(" Server Messages ({}) ", state.messages.len()))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),
+            Constraint::Length(7),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    if state.messages.is_empty() {
+        f.render_widget(
+            Paragraph::new("No messages on the bulletin board").style(theme.text_dim()),
+            chunks[0],
+        );
+    } else {
+        let items: Vec<ListItem> = state
+            .messages
+            .iter()
+            .map(|message| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:<6}", message.severity),
+                        severity_style(message.severity, theme),
+                    ),
+                    Span::raw(format!("{:<20} ", format_created(message.time_created))),
+                    Span::raw(
+                        message
+                            .message
+                            .lines()
+                            .next()
+                            .unwrap_or_default()
+                            .to_string(),
+                    ),
+                ]))
+            })
+            .collect();
+        let list = List::new(items)
+            .highlight_style(theme.highlight())
+            .highlight_symbol("> ");
+        let mut list_state = ListState::default().with_selected(Some(state.selected));
+        f.render_stateful_widget(list, chunks[0], &mut list_state);
+    }
+
+    let details = state
+        .selected_message()
+        .map(|message| detail_lines(message, theme))
+        .unwrap_or_default();
+    f.render_widget(
+        Paragraph::new(details)
+            .block(
+                Block::default()
+                    .borders(Borders::TOP)
+                    .border_style(Style::default().fg(theme.border)),
+            )
+            .wrap(Wrap { trim: false }),
+        chunks[1],
+    );
+
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            "j/k: select  d: dismiss  r: refresh  Ctrl+c: copy  Esc: close",
+            Style::default().fg(theme.text_dim),
+        ))),
+        chunks[2],
+    );
+}
+
+/// Header badge with the message count, highlighted while messages are unread.
+pub fn header_badge(state: &ServerMessages, theme: &Theme) -> Span<'static> {
+    match state.unread_severity() {
+        Some(severity) => Span::styled(
+            format!("Messages: {} new (Ctrl+B)", state.unread_count()),
+            severity_style(severity, theme).add_modifier(Modifier::BOLD),
+        ),
+        None => Span::styled(
+            format!("Messages: {}", state.messages.len()),
+            theme.text_dim(),
+        ),
+    }
+}
+
+fn detail_lines<'a>(message: &'a ServerMessage, theme: &Theme) -> Vec<Line<'a>> {
+    let mut source = message.name.clone();
+    if let Some(server) = &message.server {
+        source.push_str(&format!(" on {}", server));
+    }
+    let mut lines = vec![Line::from(Span::styled(
+        source,
+        Style::default().add_modifier(Modifier::BOLD),
+    ))];
+    lines.extend(message.message.lines().map(Line::from));
+    if let Some(help) = &message.help {
+        lines.push(Line::styled(format!("Help: {}", help), theme.text_dim()));
+    }
+    lines
+}
+
+/// Style for a message severity label.
+pub fn severity_style(severity: ServerMessageSeverity, theme: &Theme) -> Style {
+    match severity {
+        ServerMessageSeverity::Error => theme.error().add_modifier(Modifier::BOLD),
+        ServerMessageSeverity::Warn => theme.warning(),
+        ServerMessageSeverity::Info => theme.info(),
+    }
+}
+
+fn format_created(time_created: Option<u64>) -> String {
+    time_created
+        .and_then(|t| chrono::DateTime::from_timestamp(t as i64, 0))
+        .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
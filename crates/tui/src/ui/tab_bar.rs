@@ -0,0 +1,164 @@
+//! Clickable screen tab bar drawn on the header's bottom border.
+//!
+//! Responsibilities:
+//! - Show the screens around the current one in navigation order, with
+//!   arrows when more screens are hidden on either side
+//! - Report the area of every tab and arrow for mouse hit-testing
+//!
+//! Does NOT handle:
+//! - Does NOT handle clicks (see app::mouse)
+//!
+//! Invariants:
+//! - The window of visible tabs always contains the active screen
+//! - Job Details highlights the Jobs tab, since it is not in the screen cycle
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crate::app::state::CurrentScreen;
+use crate::theme::Theme;
+
+const SEPARATOR: &str = "│";
+const LEFT_ARROW: &str = "◀";
+const RIGHT_ARROW: &str = "▶";
+
+/// Render the tab bar into the single-row `area`.
+///
+/// Returns the area of each tab and arrow with the screen it switches to.
+pub fn render_tab_bar(
+    f: &mut Frame,
+    area: Rect,
+    current: CurrentScreen,
+    theme: &Theme,
+) -> Vec<(Rect, CurrentScreen)> {
+    let active = match current {
+        CurrentScreen::JobInspect => CurrentScreen::Jobs,
+        screen => screen,
+    };
+    let screens = &CurrentScreen::ALL;
+    let Some(active_index) = screens.iter().position(|screen| *screen == active) else {
+        return Vec::new();
+    };
+    let (first, last) = visible_window(active_index, area.width);
+
+    let mut spans = Vec::new();
+    let mut hits = Vec::new();
+    let mut x = area.x;
+    let mut push = |spans: &mut Vec<Span<'static>>, text: String, style: Style| {
+        let width = text.chars().count() as u16;
+        let rect = Rect::new(x, area.y, width, 1);
+        x += width;
+        spans.push(Span::styled(text, style));
+        rect
+    };
+
+    let arrow_style = Style::default().fg(theme.accent);
+    if first > 0 {
+        let rect = push(&mut spans, LEFT_ARROW.to_string(), arrow_style);
+        hits.push((rect, screens[first - 1]));
+    }
+    for (index, screen) in screens.iter().enumerate().take(last + 1).skip(first) {
+        if index > first {
+            push(
+                &mut spans,
+                SEPARATOR.to_string(),
+                Style::default().fg(theme.border),
+            );
+        }
+        let style = if index == active_index {
+            Style::default()
+                .fg(theme.highlight_fg)
+                .bg(theme.highlight_bg)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.text_dim)
+        };
+        let rect = push(&mut spans, tab_label(*screen), style);
+        hits.push((rect, *screen));
+    }
+    if last + 1 < screens.len() {
+        let rect = push(&mut spans, RIGHT_ARROW.to_string(), arrow_style);
+        hits.push((rect, screens[last + 1]));
+    }
+
+    let used = x - area.x;
+    f.render_widget(
+        Paragraph::new(Line::from(spans)),
+        Rect::new(area.x, area.y, used, 1),
+    );
+    hits
+}
+
+fn tab_label(screen: CurrentScreen) -> String {
+    format!(" {} ", screen.title())
+}
+
+/// First and last index of the tabs that fit in `width` around `active`.
+///
+/// Grows the window one tab at a time, alternating right and left, keeping
+/// room for both arrows.
+fn visible_window(active: usize, width: u16) -> (usize, usize) {
+    let screens = &CurrentScreen::ALL;
+    let tab_width = |index: usize| tab_label(screens[index]).chars().count() + 1;
+    let budget =
+        usize::from(width).saturating_sub(LEFT_ARROW.chars().count() + RIGHT_ARROW.chars().count());
+
+    let (mut first, mut last) = (active, active);
+    let mut used = tab_width(active);
+    loop {
+        let grow_right = last + 1 < screens.len() && used + tab_width(last + 1) <= budget;
+        if grow_right {
+            last += 1;
+            used += tab_width(last);
+        }
+        let grow_left = first > 0 && used + tab_width(first - 1) <= budget;
+        if grow_left {
+            first -= 1;
+            used += tab_width(first);
+        }
+        if !grow_right && !grow_left {
+            return (first, last);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    #[test]
+    fn test_tab_bar_keeps_active_tab_visible_and_reports_hits() {
+        let backend = TestBackend::new(60, 1);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut hits = Vec::new();
+        terminal
+            .draw(|f| {
+                hits = render_tab_bar(f, f.area(), CurrentScreen::Settings, &Theme::default());
+            })
+            .unwrap();
+
+        assert!(
+            hits.iter()
+                .any(|(_, screen)| *screen == CurrentScreen::Settings)
+        );
+        assert!(hits.iter().all(|(rect, _)| rect.right() <= 60));
+        let position = |target: CurrentScreen| CurrentScreen::ALL.iter().position(|s| *s == target);
+        let (arrow, target) = hits[0];
+        assert_eq!(arrow.x, 0);
+        assert!(position(target) < position(hits[1].1));
+    }
+
+    #[test]
+    fn test_visible_window_shows_every_tab_when_wide() {
+        assert_eq!(
+            visible_window(0, u16::MAX),
+            (0, CurrentScreen::ALL.len() - 1)
+        );
+    }
+}
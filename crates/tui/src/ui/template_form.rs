@@ -0,0 +1,143 @@
+//! Search template parameter form rendering.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+use crate::app::App;
+use crate::theme::Theme;
+use crate::ui::syntax::highlight_spl;
+use crate::ui::theme::ThemeExt;
+
+/// Render the search template parameter form.
+///
+/// Shows one input per parameter (labelled with its type and description),
+/// a live preview of the rendered SPL, and the last apply error.
+pub fn render_template_form(f: &mut Frame, app: &App, theme: &Theme) {
+    let Some(form) = &app.template_form else {
+        return;
+    };
+
+    let area = f.area();
+    let field_rows = form.inputs.len() as u16 * 3;
+    let popup_width = 90.min(area.width.saturating_sub(4));
+    let popup_height = (field_rows + 12).min(area.height.saturating_sub(4));
+
+    let x = (area.width.saturating_sub(popup_width)) / 2;
+    let y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(" Search Template: {} ", form.template.name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(3),
+            Constraint::Length(5),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let description = form.template.description.as_deref().unwrap_or("");
+    f.render_widget(
+        Paragraph::new(description).style(theme.text_dim()),
+        chunks[0],
+    );
+
+    let fields = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(3); form.inputs.len()])
+        .split(chunks[1]);
+    for (i, (parameter, input)) in form
+        .template
+        .parameters
+        .iter()
+        .zip(&form.inputs)
+        .enumerate()
+    {
+        let Some(&field_area) = fields.get(i) else {
+            break;
+        };
+        let focused = i == form.selected;
+        let mut title = vec![
+            Span::styled(
+                format!(" {} ", parameter.name),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("({}) ", parameter.kind), theme.text_dim()),
+        ];
+        if let Some(description) = &parameter.description {
+            title.push(Span::styled(format!("{} ", description), theme.text_dim()));
+        }
+        let text = if input.is_empty() {
+            Span::styled(input.placeholder().unwrap_or(""), theme.text_dim())
+        } else {
+            Span::raw(input.value())
+        };
+        let widget = Paragraph::new(Line::from(text)).block(
+            Block::default()
+                .title(Line::from(title))
+                .borders(Borders::ALL)
+                .border_style(if focused {
+                    theme.border_focused()
+                } else {
+                    theme.border()
+                }),
+        );
+        f.render_widget(widget, field_area);
+
+        if focused {
+            let prefix: String = input
+                .value()
+                .chars()
+                .take(input.cursor_position())
+                .collect();
+            let cursor_x = field_area.x + 1 + prefix.chars().count() as u16;
+            if cursor_x < field_area.x + field_area.width.saturating_sub(1) {
+                f.set_cursor_position(ratatui::layout::Position::new(cursor_x, field_area.y + 1));
+            }
+        }
+    }
+
+    let preview = match form.query() {
+        Ok(query) => Paragraph::new(highlight_spl(&query, theme)),
+        Err(e) => Paragraph::new(Line::styled(e.to_string(), theme.text_dim())),
+    };
+    f.render_widget(
+        preview
+            .block(
+                Block::default()
+                    .title(" SPL ")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(theme.border)),
+            )
+            .wrap(Wrap { trim: false }),
+        chunks[2],
+    );
+
+    if let Some(error) = &form.error {
+        f.render_widget(
+            Paragraph::new(Line::styled(error.as_str(), theme.error())),
+            chunks[3],
+        );
+    }
+
+    let hint = Line::from(Span::styled(
+        "Tab/Up/Down: field  Ctrl+c: copy SPL  Enter: use query  Esc: cancel",
+        Style::default().fg(theme.text_dim),
+    ));
+    f.render_widget(Paragraph::new(hint), chunks[4]);
+}
@@ -33,6 +33,44 @@ impl Default for PatternIndicators {
     }
 }
 
+/// Severity class of a status value, shared by status colors, indicators, and
+/// the text tags shown in no-color mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusClass {
+    Success,
+    Warning,
+    Error,
+    Info,
+    Unknown,
+}
+
+impl StatusClass {
+    /// Classify a status string such as `Up`, `degraded`, or `failed`.
+    pub fn of(status: &str) -> Self {
+        match status.to_lowercase().as_str() {
+            "ok" | "healthy" | "green" | "active" | "installed" | "available" | "up"
+            | "running" | "ready" => Self::Success,
+            "warning" | "yellow" | "degraded" | "pending" | "starting" | "stopping" | "cached"
+            | "quarantined" => Self::Warning,
+            "error" | "unhealthy" | "red" | "timeout" | "down" | "critical" | "failed"
+            | "stopped" => Self::Error,
+            "loading" | "info" => Self::Info,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Text tag standing in for the status color in no-color mode.
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::Success => "[OK]",
+            Self::Warning => "[WARN]",
+            Self::Error => "[ERR]",
+            Self::Info => "[INFO]",
+            Self::Unknown => "[?]",
+        }
+    }
+}
+
 /// Spinner characters for animated loading indicator.
 ///
 /// These Braille patterns create a smooth spinning animation when cycled.
@@ -108,6 +146,12 @@ pub trait ThemeExt {
     /// Get status indicator character for a status string.
     /// Returns a character indicator suitable for users who cannot distinguish colors.
     fn status_indicator(&self, status: &str) -> char;
+
+    /// Prefix `text` with the tag for `class` in no-color mode; unchanged otherwise.
+    fn tagged(&self, text: &str, class: StatusClass) -> String;
+
+    /// Status string as displayed: tagged with its class in no-color mode.
+    fn status_text(&self, status: &str) -> String;
 }
 
 impl ThemeExt for Theme {
@@ -185,21 +229,12 @@ impl ThemeExt for Theme {
     }
 
     fn status_color(&self, status: &str) -> Color {
-        match status.to_lowercase().as_str() {
-            // Success states
-            "ok" | "healthy" | "green" | "active" | "installed" | "available" | "up"
-            | "running" | "ready" => self.success,
-            // Warning states
-            "warning" | "yellow" | "degraded" | "pending" | "starting" | "stopping" | "cached" => {
-                self.warning
-            }
-            // Error states
-            "error" | "unhealthy" | "red" | "timeout" | "down" | "critical" | "failed"
-            | "stopped" => self.error,
-            // Info/Neutral states
-            "loading" => self.info,
-            // Unknown/default
-            _ => self.text,
+        match StatusClass::of(status) {
+            StatusClass::Success => self.success,
+            StatusClass::Warning => self.warning,
+            StatusClass::Error => self.error,
+            StatusClass::Info => self.info,
+            StatusClass::Unknown => self.text,
         }
     }
 
@@ -213,23 +248,26 @@ impl ThemeExt for Theme {
 
     fn status_indicator(&self, status: &str) -> char {
         let patterns = self.pattern_indicators();
-        match status.to_lowercase().as_str() {
-            // Success states
-            "ok" | "healthy" | "green" | "active" | "installed" | "available" | "up"
-            | "running" | "ready" => patterns.success,
-            // Warning states
-            "warning" | "yellow" | "degraded" | "pending" | "starting" | "stopping" | "cached" => {
-                patterns.warning
-            }
-            // Error states
-            "error" | "unhealthy" | "red" | "timeout" | "down" | "critical" | "failed"
-            | "stopped" => patterns.error,
-            // Info/Neutral states
-            "loading" | "info" => patterns.info,
-            // Unknown/default
-            _ => patterns.unknown,
+        match StatusClass::of(status) {
+            StatusClass::Success => patterns.success,
+            StatusClass::Warning => patterns.warning,
+            StatusClass::Error => patterns.error,
+            StatusClass::Info => patterns.info,
+            StatusClass::Unknown => patterns.unknown,
+        }
+    }
+
+    fn tagged(&self, text: &str, class: StatusClass) -> String {
+        if self.no_color {
+            format!("{} {}", class.tag(), text)
+        } else {
+            text.to_string()
         }
     }
+
+    fn status_text(&self, status: &str) -> String {
+        self.tagged(status, StatusClass::of(status))
+    }
 }
 
 /// Helper functions for common style patterns.
@@ -563,4 +601,16 @@ mod tests {
         assert_eq!(theme.status_indicator(""), '?');
         assert_eq!(theme.status_indicator("foobar"), '?');
     }
+
+    #[test]
+    fn test_status_text_tags_only_in_no_color_mode() {
+        let mut theme = Theme::default();
+        assert_eq!(theme.status_text("Up"), "Up");
+
+        theme.no_color = true;
+        assert_eq!(theme.status_text("Up"), "[OK] Up");
+        assert_eq!(theme.status_text("Pending"), "[WARN] Pending");
+        assert_eq!(theme.status_text("Down"), "[ERR] Down");
+        assert_eq!(theme.tagged("95.0%", StatusClass::Error), "[ERR] 95.0%");
+    }
 }
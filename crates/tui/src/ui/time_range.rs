@@ -0,0 +1,201 @@
+//! Time range picker popup rendering.
+
+use chrono::Local;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::app::App;
+use crate::app::input::components::SingleLineInput;
+use crate::app::time_range::{TimeRangeFocus, TimeRangePicker, TimeRangePreset};
+use crate::theme::Theme;
+use crate::ui::theme::ThemeExt;
+
+/// Render the time range picker popup.
+///
+/// Lists the presets and a custom row; the custom range inputs below are
+/// read in the local time zone unless the entry carries an offset.
+pub fn render_time_range_picker(f: &mut Frame, app: &App, theme: &Theme) {
+    let Some(picker) = &app.time_range_picker else {
+        return;
+    };
+
+    let area = f.area();
+    let popup_width = 70.min(area.width.saturating_sub(4));
+    let popup_height = 22.min(area.height.saturating_sub(4));
+    let x = (area.width.saturating_sub(popup_width)) / 2;
+    let y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(" Time Range: {} ", picker.target.label()))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(TimeRangePicker::CUSTOM as u16 + 1),
+            Constraint::Length(1),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    f.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("Current: ", theme.title()),
+            Span::styled(picker.current.label(), theme.text()),
+        ])),
+        chunks[0],
+    );
+
+    let mut items: Vec<ListItem> = TimeRangePreset::ALL
+        .iter()
+        .map(|preset| {
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{:<18}", preset.label())),
+                Span::styled(format!("{} → now", preset.earliest()), theme.text_dim()),
+            ]))
+        })
+        .collect();
+    items.push(ListItem::new("Custom range"));
+    let presets_focused = picker.focus == TimeRangeFocus::Presets;
+    let list = List::new(items)
+        .highlight_style(if presets_focused {
+            theme.highlight()
+        } else {
+            Style::default().add_modifier(Modifier::BOLD)
+        })
+        .highlight_symbol("> ");
+    let mut list_state = ListState::default().with_selected(Some(picker.selected));
+    f.render_stateful_widget(list, chunks[1], &mut list_state);
+
+    f.render_widget(
+        Paragraph::new(Span::styled(
+            format!(
+                "Custom times without an offset use local time (UTC{})",
+                Local::now().format("%:z")
+            ),
+            theme.text_dim(),
+        )),
+        chunks[2],
+    );
+
+    render_input(
+        f,
+        chunks[3],
+        "Earliest",
+        &picker.earliest,
+        picker.focus == TimeRangeFocus::Earliest,
+        theme,
+    );
+    let latest_label = if picker.target.supports_latest() {
+        "Latest"
+    } else {
+        "Latest (always now for this screen)"
+    };
+    render_input(
+        f,
+        chunks[4],
+        latest_label,
+        &picker.latest,
+        picker.focus == TimeRangeFocus::Latest,
+        theme,
+    );
+
+    if let Some(error) = &picker.error {
+        f.render_widget(
+            Paragraph::new(Span::styled(error.as_str(), theme.error())),
+            chunks[5],
+        );
+    }
+
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            "j/k: select  Tab: custom inputs  Enter: apply  Esc: close",
+            Style::default().fg(theme.text_dim),
+        ))),
+        chunks[6],
+    );
+}
+
+fn render_input(
+    f: &mut Frame,
+    area: Rect,
+    label: &str,
+    input: &SingleLineInput,
+    focused: bool,
+    theme: &Theme,
+) {
+    let text = if input.is_empty() {
+        Span::styled(input.placeholder().unwrap_or(""), theme.text_dim())
+    } else {
+        Span::raw(input.value())
+    };
+    let block = Block::default()
+        .title(format!(" {} ", label))
+        .borders(Borders::ALL)
+        .border_style(if focused {
+            theme.border_focused()
+        } else {
+            theme.border()
+        });
+    f.render_widget(Paragraph::new(Line::from(text)).block(block), area);
+
+    if focused {
+        let prefix_width = input.value().chars().take(input.cursor_position()).count() as u16;
+        let cursor_x = area.x + 1 + prefix_width;
+        if cursor_x < area.x + area.width.saturating_sub(1) {
+            f.set_cursor_position(ratatui::layout::Position::new(cursor_x, area.y + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::app::time_range::{TimeRange, TimeRangeTarget};
+    use ratatui::backend::TestBackend;
+
+    #[test]
+    fn test_render_time_range_picker_lists_presets_and_error() {
+        let mut app = App::new(None, ConnectionContext::default());
+        let mut picker = TimeRangePicker::new(TimeRangeTarget::Audit, TimeRange::new("-7d", "now"));
+        picker.error = Some("Earliest must be before latest".to_string());
+        app.time_range_picker = Some(picker);
+
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal
+            .draw(|f| render_time_range_picker(f, &app, &Theme::default()))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let screen = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(screen.contains("Time Range: Audit"), "{}", screen);
+        assert!(screen.contains("Current: Last 7 days"));
+        assert!(screen.contains("> Last 7 days       -7d → now"));
+        assert!(screen.contains("Month to date     @mon → now"));
+        assert!(screen.contains("Custom range"));
+        assert!(screen.contains("Earliest must be before latest"));
+    }
+}
@@ -0,0 +1,154 @@
+//! Top-values popup rendering.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Cell, Clear, List, ListItem, ListState, Paragraph, Row, Table, Wrap,
+    },
+};
+
+use crate::app::App;
+use crate::app::top_values::{TopValueRow, TopValues, TopValuesPhase};
+use crate::theme::Theme;
+use crate::ui::syntax::highlight_spl;
+use crate::ui::theme::ThemeExt;
+
+/// Render the top-values popup.
+///
+/// Shows the field picker, or the `| top` table for the picked field with the
+/// follow-up SPL it was computed from.
+pub fn render_top_values(f: &mut Frame, app: &App, theme: &Theme) {
+    let Some(top_values) = &app.top_values else {
+        return;
+    };
+
+    let area = f.area();
+    let popup_width = 80.min(area.width.saturating_sub(4));
+    let popup_height = 30.min(area.height.saturating_sub(4));
+
+    let x = (area.width.saturating_sub(popup_width)) / 2;
+    let y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    f.render_widget(Clear, popup_area);
+
+    let title = match top_values.field() {
+        Some(field) => format!(" Top Values: {} ", field),
+        None => " Top Values: select a field ".to_string(),
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),
+            Constraint::Length(4),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let hint = match &top_values.phase {
+        TopValuesPhase::SelectField => {
+            render_field_picker(f, chunks[0], top_values, theme);
+            "j/k: field  Enter: top values  Esc: close"
+        }
+        TopValuesPhase::Loading { .. } => {
+            f.render_widget(
+                Paragraph::new("Running follow-up search...").style(theme.text_dim()),
+                chunks[0],
+            );
+            "Backspace: pick another field  Esc: close"
+        }
+        TopValuesPhase::Loaded { rows, .. } => {
+            render_rows(f, chunks[0], rows, theme);
+            "Backspace: pick another field  Ctrl+c: copy SPL  Esc: close"
+        }
+        TopValuesPhase::Failed { error, .. } => {
+            f.render_widget(
+                Paragraph::new(Line::styled(error.as_str(), theme.error()))
+                    .wrap(Wrap { trim: false }),
+                chunks[0],
+            );
+            "Backspace: pick another field  Ctrl+c: copy SPL  Esc: close"
+        }
+    };
+
+    let query = top_values.field().map_or_else(
+        || top_values.scope.query.clone(),
+        |field| top_values.query(field),
+    );
+    let scope = format!(
+        " SPL ({} to {}) ",
+        top_values.scope.earliest_time, top_values.scope.latest_time
+    );
+    f.render_widget(
+        Paragraph::new(highlight_spl(&query, theme))
+            .block(
+                Block::default()
+                    .title(scope)
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(theme.border)),
+            )
+            .wrap(Wrap { trim: false }),
+        chunks[1],
+    );
+
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            hint,
+            Style::default().fg(theme.text_dim),
+        ))),
+        chunks[2],
+    );
+}
+
+fn render_field_picker(f: &mut Frame, area: Rect, top_values: &TopValues, theme: &Theme) {
+    let items: Vec<ListItem> = top_values
+        .fields
+        .iter()
+        .map(|field| ListItem::new(field.as_str()))
+        .collect();
+    let list = List::new(items)
+        .highlight_style(theme.highlight())
+        .highlight_symbol("> ");
+    let mut state = ListState::default().with_selected(Some(top_values.selected));
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn render_rows(f: &mut Frame, area: Rect, rows: &[TopValueRow], theme: &Theme) {
+    if rows.is_empty() {
+        f.render_widget(
+            Paragraph::new("No values found").style(theme.text_dim()),
+            area,
+        );
+        return;
+    }
+
+    let header = Row::new(["Value", "Count", "Percent"])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+    let table_rows = rows.iter().map(|row| {
+        Row::new([
+            Cell::from(row.value.as_str()),
+            Cell::from(row.count.to_string()),
+            Cell::from(format!("{:.2}%", row.percent)),
+        ])
+    });
+    let table = Table::new(
+        table_rows,
+        [
+            Constraint::Min(20),
+            Constraint::Length(10),
+            Constraint::Length(9),
+        ],
+    )
+    .header(header);
+    f.render_widget(table, area);
+}
@@ -0,0 +1,133 @@
+//! Tstats query builder popup rendering.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+use crate::app::App;
+use crate::theme::Theme;
+use crate::ui::syntax::highlight_spl;
+use crate::ui::theme::ThemeExt;
+
+/// Render the tstats query builder popup.
+///
+/// Shows the selected dataset and options, the dataset's fields with their
+/// split-by/aggregate markers, and a live preview of the generated SPL.
+pub fn render_tstats_builder(f: &mut Frame, app: &App, theme: &Theme) {
+    let area = f.area();
+
+    let popup_width = 100.min(area.width.saturating_sub(4));
+    let popup_height = 30.min(area.height.saturating_sub(4));
+
+    let x = (area.width.saturating_sub(popup_width)) / 2;
+    let y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    f.render_widget(Clear, popup_area);
+
+    let Some(builder) = &app.tstats_builder else {
+        return;
+    };
+
+    let block = Block::default()
+        .title(format!(" Tstats Query Builder: {} ", builder.datamodel))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(3),
+            Constraint::Length(5),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let label = Style::default().add_modifier(Modifier::BOLD);
+    let value = Style::default().fg(theme.accent);
+    let on_off = |enabled: bool| if enabled { "on" } else { "off" };
+    let header = vec![
+        Line::from(vec![
+            Span::styled("Dataset:  ", label),
+            Span::styled(format!("< {} >", builder.object().lineage), value),
+            Span::styled(
+                format!("  ({}/{})", builder.object_index + 1, builder.objects.len()),
+                Style::default().fg(theme.text_dim),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Span:     ", label),
+            Span::styled(builder.span.unwrap_or("none"), value),
+            Span::styled("   summariesonly: ", label),
+            Span::styled(on_off(builder.summaries_only), value),
+        ]),
+        Line::from(vec![
+            Span::styled("Accelerated: ", label),
+            if builder.accelerated {
+                Span::styled("yes", Style::default().fg(theme.success))
+            } else {
+                Span::styled(
+                    "no (tstats reads raw events; summariesonly returns nothing)",
+                    Style::default().fg(theme.warning),
+                )
+            },
+        ]),
+    ];
+    f.render_widget(Paragraph::new(header), chunks[0]);
+
+    let items: Vec<ListItem> = builder
+        .object()
+        .fields
+        .iter()
+        .map(|field| {
+            let split = builder.split_by.contains(field);
+            let aggregate = builder.aggregate_for(field);
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    if split { "[by] " } else { "     " },
+                    Style::default().fg(theme.success),
+                ),
+                Span::styled(
+                    format!("{:<8}", aggregate.unwrap_or("")),
+                    Style::default().fg(theme.info),
+                ),
+                Span::raw(field.clone()),
+            ]))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(" Fields ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .highlight_style(theme.highlight());
+    let mut state =
+        ListState::default().with_selected(builder.selected_field().map(|_| builder.field_index));
+    f.render_stateful_widget(list, chunks[1], &mut state);
+
+    let preview = Paragraph::new(highlight_spl(&builder.query().to_spl(), theme))
+        .block(
+            Block::default()
+                .title(" SPL ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(preview, chunks[2]);
+
+    let hint = Line::from(Span::styled(
+        "h/l: dataset  Space: split by  a: aggregate  s: span  o: summariesonly  Ctrl+c: copy  Enter: run",
+        Style::default().fg(theme.text_dim),
+    ));
+    f.render_widget(Paragraph::new(hint), chunks[3]);
+}
@@ -0,0 +1,361 @@
+//! Data onboarding wizard rendering.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+use crate::app::App;
+use crate::app::input::components::SingleLineInput;
+use crate::theme::Theme;
+use crate::ui::theme::ThemeExt;
+use crate::wizard::{OnboardingStage, OnboardingWizard, WizardStep};
+
+/// Render the data onboarding wizard.
+///
+/// Input steps show their fields; the review step lists what will be created;
+/// the result step shows what was created and a curl example for the token.
+pub fn render_onboarding_wizard(f: &mut Frame, app: &App, theme: &Theme) {
+    let Some(wizard) = &app.onboarding_wizard else {
+        return;
+    };
+
+    let area = f.area();
+    let popup_width = 96.min(area.width.saturating_sub(4));
+    let popup_height = 20.min(area.height.saturating_sub(4));
+    let x = (area.width.saturating_sub(popup_width)) / 2;
+    let y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    f.render_widget(Clear, popup_area);
+
+    let step_title = match wizard.step {
+        WizardStep::Index => "Index",
+        WizardStep::Sourcetype => "Sourcetype",
+        WizardStep::Token => "HEC Token",
+        WizardStep::Review => "Review",
+        WizardStep::Running => "Creating",
+        WizardStep::Finished => "Result",
+    };
+    let title = match wizard.step.number() {
+        Some(n) => format!(" Data Onboarding - Step {} of 4: {} ", n, step_title),
+        None => format!(" Data Onboarding - {} ", step_title),
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    match wizard.step {
+        WizardStep::Index => {
+            let fields = field_areas(chunks[0], 2);
+            render_input(
+                f,
+                fields[0],
+                "Index name",
+                &wizard.index_name,
+                wizard.selected == 0,
+                theme,
+            );
+            render_input(
+                f,
+                fields[1],
+                "Max size (MB)",
+                &wizard.max_size_mb,
+                wizard.selected == 1,
+                theme,
+            );
+        }
+        WizardStep::Sourcetype => {
+            let fields = field_areas(chunks[0], 2);
+            render_input(
+                f,
+                fields[0],
+                "Sourcetype",
+                &wizard.sourcetype,
+                wizard.selected == 0,
+                theme,
+            );
+            let format = Paragraph::new(Line::from(vec![
+                Span::raw(format!("< {} >  ", wizard.format)),
+                Span::styled(
+                    wizard
+                        .format
+                        .props_settings()
+                        .iter()
+                        .map(|(k, v)| format!("{}={}", k, v))
+                        .collect::<Vec<_>>()
+                        .join("  "),
+                    theme.text_dim(),
+                ),
+            ]))
+            .block(field_block("Event format", wizard.format_selected(), theme));
+            f.render_widget(format, fields[1]);
+        }
+        WizardStep::Token => {
+            let fields = field_areas(chunks[0], 2);
+            render_input(f, fields[0], "Token name", &wizard.token_name, true, theme);
+            let scope = format!(
+                "Writes only to index '{}', default sourcetype '{}'",
+                wizard.index_name.value().trim(),
+                wizard.sourcetype_name()
+            );
+            f.render_widget(Paragraph::new(scope).style(theme.text_dim()), fields[1]);
+        }
+        WizardStep::Review => render_review(f, chunks[0], wizard, theme),
+        WizardStep::Running => {
+            f.render_widget(
+                Paragraph::new("Creating index, sourcetype, and HEC token...")
+                    .style(theme.text_dim()),
+                chunks[0],
+            );
+        }
+        WizardStep::Finished => render_result(f, chunks[0], wizard, theme),
+    }
+
+    if let Some(error) = &wizard.error {
+        f.render_widget(
+            Paragraph::new(Line::styled(error.as_str(), theme.error())),
+            chunks[1],
+        );
+    }
+
+    let hint = match wizard.step {
+        WizardStep::Index | WizardStep::Token => {
+            "Tab/Up/Down: field  Enter: next  Esc: back/cancel"
+        }
+        WizardStep::Sourcetype => {
+            "Tab/Up/Down: field  Space: change format  Enter: next  Esc: back"
+        }
+        WizardStep::Review => "Enter: create  Esc: back",
+        WizardStep::Running => "Esc: close (creation continues)",
+        WizardStep::Finished => "Ctrl+c: copy curl example  Enter/Esc: close",
+    };
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(hint, theme.text_dim()))),
+        chunks[2],
+    );
+}
+
+/// Split `area` into `count` three-row field areas.
+fn field_areas(area: Rect, count: usize) -> std::rc::Rc<[Rect]> {
+    let mut constraints = vec![Constraint::Length(3); count];
+    constraints.push(Constraint::Min(0));
+    Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(area)
+}
+
+fn field_block<'a>(label: &'a str, focused: bool, theme: &Theme) -> Block<'a> {
+    Block::default()
+        .title(Span::styled(
+            format!(" {} ", label),
+            Style::default().add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_style(if focused {
+            theme.border_focused()
+        } else {
+            theme.border()
+        })
+}
+
+fn render_input(
+    f: &mut Frame,
+    area: Rect,
+    label: &str,
+    input: &SingleLineInput,
+    focused: bool,
+    theme: &Theme,
+) {
+    let text = if input.is_empty() {
+        Span::styled(input.placeholder().unwrap_or(""), theme.text_dim())
+    } else {
+        Span::raw(input.value())
+    };
+    f.render_widget(
+        Paragraph::new(Line::from(text)).block(field_block(label, focused, theme)),
+        area,
+    );
+
+    if focused {
+        let prefix: String = input
+            .value()
+            .chars()
+            .take(input.cursor_position())
+            .collect();
+        let cursor_x = area.x + 1 + prefix.chars().count() as u16;
+        if cursor_x < area.x + area.width.saturating_sub(1) {
+            f.set_cursor_position(ratatui::layout::Position::new(cursor_x, area.y + 1));
+        }
+    }
+}
+
+fn render_review(f: &mut Frame, area: Rect, wizard: &OnboardingWizard, theme: &Theme) {
+    let lines = match wizard.plan() {
+        Ok(plan) => {
+            let max_size = plan
+                .index
+                .max_data_size_mb
+                .map_or("server default".to_string(), |mb| format!("{} MB", mb));
+            let settings = plan
+                .format
+                .props_settings()
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect::<Vec<_>>()
+                .join(", ");
+            vec![
+                Line::from("The following will be created:"),
+                Line::from(""),
+                Line::from(format!(
+                    "1. Index '{}' (max size: {})",
+                    plan.index.name, max_size
+                )),
+                Line::from(format!(
+                    "2. Sourcetype [{}] in props.conf ({})",
+                    plan.sourcetype, settings
+                )),
+                Line::from(format!(
+                    "3. HEC token '{}' writing to index '{}'",
+                    plan.token.name, plan.index.name
+                )),
+            ]
+        }
+        Err(e) => vec![Line::styled(e, theme.error())],
+    };
+    f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), area);
+}
+
+fn render_result(f: &mut Frame, area: Rect, wizard: &OnboardingWizard, theme: &Theme) {
+    let Some(result) = &wizard.result else {
+        return;
+    };
+
+    match result {
+        Ok(outcome) => {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(4), Constraint::Min(3)])
+                .split(area);
+            let summary = vec![
+                Line::styled(format!("✓ Index '{}'", outcome.index), theme.success()),
+                Line::styled(
+                    format!("✓ Sourcetype '{}'", outcome.sourcetype),
+                    theme.success(),
+                ),
+                Line::styled(
+                    format!("✓ HEC token '{}'", outcome.token_name),
+                    theme.success(),
+                ),
+            ];
+            f.render_widget(Paragraph::new(summary), chunks[0]);
+            f.render_widget(
+                Paragraph::new(outcome.curl_example())
+                    .block(
+                        Block::default()
+                            .title(" Send a test event ")
+                            .borders(Borders::ALL)
+                            .border_style(Style::default().fg(theme.border)),
+                    )
+                    .wrap(Wrap { trim: false }),
+                chunks[1],
+            );
+        }
+        Err(failure) => {
+            let mut lines: Vec<Line> = failure
+                .stage
+                .completed_before()
+                .iter()
+                .map(|stage| Line::styled(format!("✓ {}", stage), theme.success()))
+                .collect();
+            lines.push(Line::styled(
+                format!("✗ {}: {}", failure.stage, failure.error),
+                theme.error(),
+            ));
+            if failure.stage != OnboardingStage::Index {
+                lines.push(Line::from(""));
+                lines.push(Line::styled(
+                    "Objects created before the failure were kept.",
+                    theme.text_dim(),
+                ));
+            }
+            f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), area);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionContext;
+    use crate::wizard::{EventFormat, OnboardingOutcome};
+    use ratatui::backend::TestBackend;
+
+    fn render(app: &App) -> String {
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(110, 24)).unwrap();
+        terminal
+            .draw(|f| render_onboarding_wizard(f, app, &Theme::default()))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_render_review_lists_plan() {
+        let mut app = App::new(None, ConnectionContext::default());
+        let mut wizard = OnboardingWizard::new();
+        wizard.index_name.set_value("web");
+        wizard.step = WizardStep::Review;
+        app.onboarding_wizard = Some(wizard);
+
+        let screen = render(&app);
+        assert!(screen.contains("Step 4 of 4: Review"), "{}", screen);
+        assert!(screen.contains("1. Index 'web' (max size: server default)"));
+        assert!(screen.contains("2. Sourcetype [web:json] in props.conf"));
+        assert!(screen.contains("3. HEC token 'web_hec' writing to index 'web'"));
+    }
+
+    #[test]
+    fn test_render_result_shows_curl_example() {
+        let mut app = App::new(None, ConnectionContext::default());
+        let mut wizard = OnboardingWizard::new();
+        wizard.step = WizardStep::Finished;
+        wizard.result = Some(Ok(OnboardingOutcome {
+            index: "web".to_string(),
+            sourcetype: "web:json".to_string(),
+            format: EventFormat::Json,
+            token_name: "web_hec".to_string(),
+            token: Some(secrecy::SecretString::from("abc-123")),
+            hec_url: "https://localhost:8088".to_string(),
+        }));
+        app.onboarding_wizard = Some(wizard);
+
+        let screen = render(&app);
+        assert!(screen.contains("✓ HEC token 'web_hec'"), "{}", screen);
+        assert!(screen.contains("curl -k https://localhost:8088/services/collector/event"));
+        assert!(screen.contains("Authorization: Splunk abc-123"));
+    }
+}
@@ -34,6 +34,9 @@ pub enum ScreenLabel {
     Roles,
     SearchPeers,
     Inputs,
+    Ingestion,
+    Sourcetypes,
+    Metadata,
     Configs,
     Settings,
     Overview,
@@ -46,6 +49,7 @@ pub enum ScreenLabel {
     DataModels,
     WorkloadManagement,
     Shc,
+    Rest,
     Unknown,
 }
 
@@ -67,6 +71,9 @@ impl ScreenLabel {
             Self::Roles => "roles",
             Self::SearchPeers => "search_peers",
             Self::Inputs => "inputs",
+            Self::Ingestion => "ingestion",
+            Self::Sourcetypes => "sourcetypes",
+            Self::Metadata => "metadata",
             Self::Configs => "configs",
             Self::Settings => "settings",
             Self::Overview => "overview",
@@ -79,6 +86,7 @@ impl ScreenLabel {
             Self::DataModels => "data_models",
             Self::WorkloadManagement => "workload_management",
             Self::Shc => "shc",
+            Self::Rest => "rest",
             Self::Unknown => "unknown",
         }
     }
@@ -103,6 +111,9 @@ impl From<crate::app::state::CurrentScreen> for ScreenLabel {
             crate::app::state::CurrentScreen::Roles => Self::Roles,
             crate::app::state::CurrentScreen::SearchPeers => Self::SearchPeers,
             crate::app::state::CurrentScreen::Inputs => Self::Inputs,
+            crate::app::state::CurrentScreen::Ingestion => Self::Ingestion,
+            crate::app::state::CurrentScreen::Sourcetypes => Self::Sourcetypes,
+            crate::app::state::CurrentScreen::Metadata => Self::Metadata,
             crate::app::state::CurrentScreen::Configs => Self::Configs,
             crate::app::state::CurrentScreen::Settings => Self::Settings,
             crate::app::state::CurrentScreen::Overview => Self::Overview,
@@ -115,6 +126,7 @@ impl From<crate::app::state::CurrentScreen> for ScreenLabel {
             crate::app::state::CurrentScreen::DataModels => Self::DataModels,
             crate::app::state::CurrentScreen::WorkloadManagement => Self::WorkloadManagement,
             crate::app::state::CurrentScreen::Shc => Self::Shc,
+            crate::app::state::CurrentScreen::Rest => Self::Rest,
         }
     }
 }
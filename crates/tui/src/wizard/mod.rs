@@ -0,0 +1,12 @@
+//! Guided multi-step wizards that create several server objects in one flow.
+//!
+//! - `state`: Step-by-step inputs and validation for data onboarding
+//! - `plan`: The objects to create, the run outcome, and the HEC curl example
+
+pub mod plan;
+pub mod state;
+
+pub use plan::{
+    EventFormat, OnboardingFailure, OnboardingOutcome, OnboardingPlan, OnboardingStage, hec_url,
+};
+pub use state::{OnboardingWizard, WizardStep};
@@ -0,0 +1,210 @@
+//! What the data onboarding wizard creates, and what it reports back.
+//!
+//! Responsibilities:
+//! - Describe the index, sourcetype stanza, and HEC token to create
+//! - Derive the HEC collector URL from the management URL
+//! - Build a ready-to-use curl example for the created token
+//!
+//! Does NOT handle:
+//! - Does NOT call the Splunk API (handled by runtime::side_effects::wizard)
+//! - Does NOT collect input (handled by wizard::state)
+
+use std::fmt;
+use std::sync::Arc;
+
+use secrecy::{ExposeSecret, SecretString};
+use splunk_client::{ClientError, CreateIndexParams, HecTokenCreateParams};
+
+/// Default port of the HTTP Event Collector.
+pub const DEFAULT_HEC_PORT: u16 = 8088;
+
+/// How events sent with the new token are parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EventFormat {
+    /// JSON objects; fields are extracted at search time.
+    #[default]
+    Json,
+    /// One plain-text event per line.
+    Raw,
+}
+
+impl EventFormat {
+    pub fn toggle(self) -> Self {
+        match self {
+            Self::Json => Self::Raw,
+            Self::Raw => Self::Json,
+        }
+    }
+
+    /// Suffix for the default sourcetype name (e.g., `web:json`).
+    pub fn suffix(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Raw => "raw",
+        }
+    }
+
+    /// props.conf settings for a sourcetype of this format.
+    pub fn props_settings(self) -> Vec<(String, String)> {
+        let settings: &[(&str, &str)] = match self {
+            Self::Json => &[("KV_MODE", "json"), ("SHOULD_LINEMERGE", "false")],
+            Self::Raw => &[
+                ("SHOULD_LINEMERGE", "false"),
+                ("LINE_BREAKER", "([\\r\\n]+)"),
+            ],
+        };
+        settings
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+}
+
+impl fmt::Display for EventFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json => write!(f, "JSON"),
+            Self::Raw => write!(f, "Raw text"),
+        }
+    }
+}
+
+/// Everything the wizard creates, in order.
+#[derive(Debug, Clone)]
+pub struct OnboardingPlan {
+    pub index: CreateIndexParams,
+    pub sourcetype: String,
+    pub format: EventFormat,
+    /// Token scoped to `index`, with `sourcetype` as its default.
+    pub token: HecTokenCreateParams,
+}
+
+/// One creation step of the plan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnboardingStage {
+    Index,
+    Sourcetype,
+    Token,
+}
+
+impl OnboardingStage {
+    pub const ALL: [Self; 3] = [Self::Index, Self::Sourcetype, Self::Token];
+
+    /// Stages that finished before this one was attempted.
+    pub fn completed_before(self) -> &'static [Self] {
+        let position = Self::ALL.iter().position(|s| *s == self).unwrap_or(0);
+        &Self::ALL[..position]
+    }
+}
+
+impl fmt::Display for OnboardingStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Index => write!(f, "create index"),
+            Self::Sourcetype => write!(f, "define sourcetype"),
+            Self::Token => write!(f, "create HEC token"),
+        }
+    }
+}
+
+/// Result of a completed onboarding run.
+#[derive(Debug, Clone)]
+pub struct OnboardingOutcome {
+    pub index: String,
+    pub sourcetype: String,
+    pub format: EventFormat,
+    pub token_name: String,
+    /// Generated token value; `None` if the server did not return it.
+    pub token: Option<SecretString>,
+    /// HEC collector base URL (e.g., `https://splunk.example.com:8088`).
+    pub hec_url: String,
+}
+
+impl OnboardingOutcome {
+    /// A curl command that sends one test event through the new token.
+    pub fn curl_example(&self) -> String {
+        let token = self
+            .token
+            .as_ref()
+            .map(|t| t.expose_secret().to_string())
+            .unwrap_or_else(|| "<token>".to_string());
+        let event = match self.format {
+            EventFormat::Json => serde_json::json!({ "message": "hello from splunk-tui" }),
+            EventFormat::Raw => serde_json::json!("hello from splunk-tui"),
+        };
+        let body = serde_json::json!({
+            "event": event,
+            "sourcetype": self.sourcetype,
+            "index": self.index,
+        });
+        format!(
+            "curl -k {}/services/collector/event \\\n  -H \"Authorization: Splunk {}\" \\\n  -d '{}'",
+            self.hec_url, token, body
+        )
+    }
+}
+
+/// A run that stopped at `stage`; earlier stages were created.
+#[derive(Debug, Clone)]
+pub struct OnboardingFailure {
+    pub stage: OnboardingStage,
+    pub error: Arc<ClientError>,
+}
+
+/// HEC collector URL on the same host as the management URL.
+///
+/// `https://splunk.example.com:8089` becomes `https://splunk.example.com:8088`.
+pub fn hec_url(base_url: &str) -> String {
+    let rest = base_url
+        .split_once("://")
+        .map_or(base_url, |(_, rest)| rest);
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or(rest);
+    let host = match authority.rsplit_once(':') {
+        // Keep bracketed IPv6 hosts intact.
+        Some((host, port)) if !port.contains(']') => host,
+        _ => authority,
+    };
+    format!("https://{}:{}", host, DEFAULT_HEC_PORT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hec_url_swaps_port() {
+        assert_eq!(
+            hec_url("https://splunk.example.com:8089"),
+            "https://splunk.example.com:8088"
+        );
+        assert_eq!(hec_url("http://localhost"), "https://localhost:8088");
+        assert_eq!(hec_url("https://[::1]:8089/"), "https://[::1]:8088");
+    }
+
+    #[test]
+    fn test_curl_example_uses_token_index_and_sourcetype() {
+        let outcome = OnboardingOutcome {
+            index: "web".to_string(),
+            sourcetype: "web:json".to_string(),
+            format: EventFormat::Json,
+            token_name: "web_hec".to_string(),
+            token: Some(SecretString::from("abc-123")),
+            hec_url: "https://splunk:8088".to_string(),
+        };
+        let curl = outcome.curl_example();
+        assert!(curl.starts_with("curl -k https://splunk:8088/services/collector/event"));
+        assert!(curl.contains("Authorization: Splunk abc-123"));
+        assert!(curl.contains(r#""index":"web""#));
+        assert!(curl.contains(r#""sourcetype":"web:json""#));
+        assert!(curl.contains(r#""event":{"message":"hello from splunk-tui"}"#));
+    }
+
+    #[test]
+    fn test_completed_before() {
+        assert!(OnboardingStage::Index.completed_before().is_empty());
+        assert_eq!(
+            OnboardingStage::Token.completed_before(),
+            &[OnboardingStage::Index, OnboardingStage::Sourcetype]
+        );
+    }
+}
@@ -0,0 +1,317 @@
+//! Step-by-step state of the data onboarding wizard.
+//!
+//! Responsibilities:
+//! - Hold the inputs for each step and the focused field
+//! - Validate a step before moving on, and build the final plan
+//! - Hold the outcome of the run for the result step
+//!
+//! Does NOT handle:
+//! - Does NOT handle key input (handled by app::popups::wizard)
+//! - Does NOT render the popup (handled by ui::wizard)
+//!
+//! Invariants:
+//! - Empty sourcetype and token names fall back to defaults derived from the index
+
+use splunk_client::{CreateIndexParams, HecTokenCreateParams};
+
+use crate::app::input::components::SingleLineInput;
+use crate::wizard::plan::{EventFormat, OnboardingFailure, OnboardingOutcome, OnboardingPlan};
+
+/// Longest index name Splunk accepts.
+const MAX_INDEX_NAME_LEN: usize = 80;
+
+/// Wizard steps, in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WizardStep {
+    #[default]
+    Index,
+    Sourcetype,
+    Token,
+    Review,
+    /// Creating objects on the server.
+    Running,
+    /// Showing the outcome of the run.
+    Finished,
+}
+
+impl WizardStep {
+    /// Number of focusable fields on this step.
+    fn field_count(self) -> usize {
+        match self {
+            Self::Index | Self::Sourcetype => 2,
+            Self::Token => 1,
+            Self::Review | Self::Running | Self::Finished => 0,
+        }
+    }
+
+    /// Position among the input steps, for the "Step n of 4" title.
+    pub fn number(self) -> Option<usize> {
+        match self {
+            Self::Index => Some(1),
+            Self::Sourcetype => Some(2),
+            Self::Token => Some(3),
+            Self::Review => Some(4),
+            Self::Running | Self::Finished => None,
+        }
+    }
+}
+
+/// Inputs and progress of the data onboarding wizard.
+#[derive(Debug, Clone)]
+pub struct OnboardingWizard {
+    pub step: WizardStep,
+    pub index_name: SingleLineInput,
+    pub max_size_mb: SingleLineInput,
+    pub sourcetype: SingleLineInput,
+    pub format: EventFormat,
+    pub token_name: SingleLineInput,
+    /// Index of the focused field within the current step.
+    pub selected: usize,
+    /// Validation error for the current step.
+    pub error: Option<String>,
+    /// Outcome of the run, once it has finished.
+    pub result: Option<Result<OnboardingOutcome, OnboardingFailure>>,
+}
+
+impl Default for OnboardingWizard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OnboardingWizard {
+    pub fn new() -> Self {
+        Self {
+            step: WizardStep::Index,
+            index_name: SingleLineInput::with_placeholder("e.g. web (required)"),
+            max_size_mb: SingleLineInput::with_placeholder("server default"),
+            sourcetype: SingleLineInput::new(),
+            format: EventFormat::default(),
+            token_name: SingleLineInput::new(),
+            selected: 0,
+            error: None,
+            result: None,
+        }
+    }
+
+    pub fn next_field(&mut self) {
+        let count = self.step.field_count();
+        if count > 0 {
+            self.selected = (self.selected + 1) % count;
+        }
+    }
+
+    pub fn previous_field(&mut self) {
+        let count = self.step.field_count();
+        if count > 0 {
+            self.selected = (self.selected + count - 1) % count;
+        }
+    }
+
+    /// Whether the focused field is the event format toggle.
+    pub fn format_selected(&self) -> bool {
+        self.step == WizardStep::Sourcetype && self.selected == 1
+    }
+
+    /// Switch the event format, updating the default sourcetype name to match.
+    pub fn toggle_format(&mut self) {
+        self.format = self.format.toggle();
+        self.refresh_placeholders();
+    }
+
+    /// The focused text input, if the focused field is one.
+    pub fn selected_input_mut(&mut self) -> Option<&mut SingleLineInput> {
+        match (self.step, self.selected) {
+            (WizardStep::Index, 0) => Some(&mut self.index_name),
+            (WizardStep::Index, 1) => Some(&mut self.max_size_mb),
+            (WizardStep::Sourcetype, 0) => Some(&mut self.sourcetype),
+            (WizardStep::Token, 0) => Some(&mut self.token_name),
+            _ => None,
+        }
+    }
+
+    /// Validate the current step and move to the next one.
+    pub fn advance(&mut self) -> Result<(), String> {
+        let next = match self.step {
+            WizardStep::Index => {
+                validate_index_name(self.index_name.value().trim())?;
+                self.max_size()?;
+                WizardStep::Sourcetype
+            }
+            WizardStep::Sourcetype => WizardStep::Token,
+            WizardStep::Token => WizardStep::Review,
+            step => step,
+        };
+        self.go_to(next);
+        Ok(())
+    }
+
+    /// Go back one step; returns false on the first step.
+    pub fn back(&mut self) -> bool {
+        let previous = match self.step {
+            WizardStep::Sourcetype => WizardStep::Index,
+            WizardStep::Token => WizardStep::Sourcetype,
+            WizardStep::Review => WizardStep::Token,
+            _ => return false,
+        };
+        self.go_to(previous);
+        true
+    }
+
+    fn go_to(&mut self, step: WizardStep) {
+        self.step = step;
+        self.selected = 0;
+        self.error = None;
+        self.refresh_placeholders();
+    }
+
+    /// Show the derived default names in the empty sourcetype and token inputs.
+    fn refresh_placeholders(&mut self) {
+        let index = self.index_name.value().trim().to_string();
+        self.sourcetype
+            .set_placeholder(format!("{}:{}", index, self.format.suffix()));
+        self.token_name.set_placeholder(format!("{}_hec", index));
+    }
+
+    /// The sourcetype to define, falling back to `<index>:<format>`.
+    pub fn sourcetype_name(&self) -> String {
+        non_empty(self.sourcetype.value()).unwrap_or_else(|| {
+            format!(
+                "{}:{}",
+                self.index_name.value().trim(),
+                self.format.suffix()
+            )
+        })
+    }
+
+    /// The token name, falling back to `<index>_hec`.
+    pub fn token_name(&self) -> String {
+        non_empty(self.token_name.value())
+            .unwrap_or_else(|| format!("{}_hec", self.index_name.value().trim()))
+    }
+
+    fn max_size(&self) -> Result<Option<usize>, String> {
+        match non_empty(self.max_size_mb.value()) {
+            None => Ok(None),
+            Some(value) => value
+                .parse::<usize>()
+                .ok()
+                .filter(|mb| *mb > 0)
+                .map(Some)
+                .ok_or_else(|| {
+                    format!("Max size must be a positive number of MB, got '{}'", value)
+                }),
+        }
+    }
+
+    /// Everything to create, with defaults applied.
+    pub fn plan(&self) -> Result<OnboardingPlan, String> {
+        let index = self.index_name.value().trim().to_string();
+        validate_index_name(&index)?;
+        let sourcetype = self.sourcetype_name();
+        Ok(OnboardingPlan {
+            index: CreateIndexParams {
+                name: index.clone(),
+                max_data_size_mb: self.max_size()?,
+                ..Default::default()
+            },
+            token: HecTokenCreateParams {
+                name: self.token_name(),
+                index: Some(index.clone()),
+                indexes: vec![index],
+                sourcetype: Some(sourcetype.clone()),
+                description: Some("Created by the splunk-tui onboarding wizard".to_string()),
+                use_ack: false,
+                ..Default::default()
+            },
+            sourcetype,
+            format: self.format,
+        })
+    }
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// Check an index name against Splunk's naming rules.
+fn validate_index_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Index name is required".to_string());
+    }
+    if name.len() > MAX_INDEX_NAME_LEN {
+        return Err(format!(
+            "Index name must be at most {} characters",
+            MAX_INDEX_NAME_LEN
+        ));
+    }
+    if name.starts_with(['_', '-']) {
+        return Err("Index name cannot start with '_' or '-'".to_string());
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+    {
+        return Err("Index name may only use lowercase letters, digits, '_' and '-'".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_step_validates_before_advancing() {
+        let mut wizard = OnboardingWizard::new();
+        assert_eq!(wizard.advance(), Err("Index name is required".to_string()));
+
+        wizard.index_name.set_value("Web");
+        assert!(wizard.advance().unwrap_err().contains("lowercase"));
+
+        wizard.index_name.set_value("web");
+        wizard.max_size_mb.set_value("lots");
+        assert!(wizard.advance().unwrap_err().contains("positive number"));
+
+        wizard.max_size_mb.set_value("500");
+        assert_eq!(wizard.advance(), Ok(()));
+        assert_eq!(wizard.step, WizardStep::Sourcetype);
+        assert_eq!(wizard.sourcetype.placeholder(), Some("web:json"));
+    }
+
+    #[test]
+    fn test_plan_applies_defaults_and_scopes_token() {
+        let mut wizard = OnboardingWizard::new();
+        wizard.index_name.set_value("web");
+        wizard.format = EventFormat::Raw;
+
+        let plan = wizard.plan().unwrap();
+        assert_eq!(plan.index.name, "web");
+        assert_eq!(plan.index.max_data_size_mb, None);
+        assert_eq!(plan.sourcetype, "web:raw");
+        assert_eq!(plan.token.name, "web_hec");
+        assert_eq!(plan.token.index.as_deref(), Some("web"));
+        assert_eq!(plan.token.indexes, vec!["web"]);
+        assert_eq!(plan.token.sourcetype.as_deref(), Some("web:raw"));
+
+        wizard.sourcetype.set_value("nginx:access");
+        wizard.token_name.set_value("edge");
+        let plan = wizard.plan().unwrap();
+        assert_eq!(plan.sourcetype, "nginx:access");
+        assert_eq!(plan.token.name, "edge");
+    }
+
+    #[test]
+    fn test_back_stops_at_first_step() {
+        let mut wizard = OnboardingWizard::new();
+        wizard.index_name.set_value("web");
+        wizard.advance().unwrap();
+        wizard.advance().unwrap();
+        assert_eq!(wizard.step, WizardStep::Token);
+        assert!(wizard.back());
+        assert!(wizard.back());
+        assert!(!wizard.back());
+        assert_eq!(wizard.step, WizardStep::Index);
+    }
+}
@@ -44,6 +44,8 @@ fn create_mock_jobs(count: usize) -> Vec<SearchJobStatus> {
             cursor_time: None,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         })
         .collect()
 }
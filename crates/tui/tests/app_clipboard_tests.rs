@@ -78,6 +78,8 @@ fn test_ctrl_c_copies_selected_job_sid() {
             cursor_time: None,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         })
         .collect();
 
@@ -108,6 +110,8 @@ fn test_ctrl_c_copies_selected_index_name() {
         thawed_path: None,
         cold_to_frozen_dir: None,
         primary_index: None,
+        min_time: None,
+        max_time: None,
     }]);
     app.indexes_state.select(Some(0));
 
@@ -172,6 +176,7 @@ fn test_ctrl_c_copies_selected_username() {
         default_app: None,
         roles: vec!["admin".to_string()],
         last_successful_login: None,
+        locked_out: false,
     }]);
     app.users_state.select(Some(0));
 
@@ -238,7 +243,13 @@ fn test_ctrl_c_copies_health_status() {
         license_usage: None,
         kvstore_status: None,
         log_parsing_health: None,
+        scheduler_health: None,
+        queue_health: None,
+        security_health: None,
         circuit_breaker_states: None,
+        cluster_peers: None,
+        disk_partition: None,
+        search_load: None,
     });
 
     let action = app.handle_input(ctrl_key('c'));
@@ -3,6 +3,9 @@
 //! This module tests:
 //! - Up/down navigation in indexes list
 //! - Selection state management
+//! - Hot bucket roll and data rebalance confirmations
+//! - Sampling raw events of the selected index
+//! - Freeze acknowledgment in the modify index dialog
 //!
 //! ## Invariants
 //! - Navigation must stay within bounds of the indexes list
@@ -10,6 +13,9 @@
 //! ## Test Organization
 //! Tests focus on list navigation behavior.
 
+mod helpers;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use helpers::*;
 use splunk_client::models::Index;
 use splunk_tui::{CurrentScreen, action::Action, app::App, app::ConnectionContext};
 
@@ -31,6 +37,8 @@ fn test_indexes_navigation() {
             thawed_path: None,
             cold_to_frozen_dir: None,
             primary_index: None,
+            min_time: None,
+            max_time: None,
         },
         Index {
             name: "index2".to_string(),
@@ -45,6 +53,8 @@ fn test_indexes_navigation() {
             thawed_path: None,
             cold_to_frozen_dir: None,
             primary_index: None,
+            min_time: None,
+            max_time: None,
         },
     ]);
     app.indexes_state.select(Some(0));
@@ -65,3 +75,90 @@ fn test_indexes_navigation() {
         "Should move to index 0"
     );
 }
+
+#[test]
+fn test_indexes_roll_and_rebalance_require_confirmation() {
+    let mut app = App::new(None, ConnectionContext::default());
+    app.current_screen = CurrentScreen::Indexes;
+    app.indexes = Some(vec![Index {
+        name: "main".to_string(),
+        total_event_count: 100,
+        current_db_size_mb: 10,
+        max_total_data_size_mb: None,
+        max_warm_db_count: None,
+        max_hot_buckets: None,
+        frozen_time_period_in_secs: None,
+        cold_db_path: None,
+        home_path: None,
+        thawed_path: None,
+        cold_to_frozen_dir: None,
+        primary_index: None,
+        min_time: None,
+        max_time: None,
+    }]);
+    app.indexes_state.select(Some(0));
+
+    assert!(app.handle_input(key('b')).is_none());
+    assert!(matches!(
+        app.popup.as_ref().map(|p| &p.kind),
+        Some(splunk_tui::PopupType::ConfirmRollHotBuckets(name)) if name == "main"
+    ));
+    let action = app.handle_input(key('y'));
+    assert!(matches!(action, Some(Action::RollHotBuckets { name }) if name == "main"));
+    assert!(app.popup.is_none());
+
+    assert!(app.handle_input(key('B')).is_none());
+    let action = app.handle_input(key('y'));
+    assert!(matches!(action, Some(Action::RebalanceIndexData { index }) if index == "main"));
+
+    let action = app.handle_input(key('s'));
+    assert!(matches!(action, Some(Action::LoadIndexSample { index }) if index == "main"));
+}
+
+#[test]
+fn test_modify_index_requires_acknowledging_large_freeze() {
+    let mut app = App::new(None, ConnectionContext::default());
+    app.current_screen = CurrentScreen::Indexes;
+    app.indexes = Some(vec![Index {
+        name: "main".to_string(),
+        total_event_count: 100,
+        current_db_size_mb: 1000,
+        max_total_data_size_mb: Some(5000),
+        max_warm_db_count: None,
+        max_hot_buckets: None,
+        frozen_time_period_in_secs: None,
+        cold_db_path: None,
+        home_path: None,
+        thawed_path: None,
+        cold_to_frozen_dir: None,
+        primary_index: None,
+        min_time: None,
+        max_time: None,
+    }]);
+    app.update(Action::OpenModifyIndexDialog {
+        name: "main".to_string(),
+    });
+
+    // 5000 MB -> 500 MB freezes half of the current 1000 MB.
+    app.handle_input(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+    assert!(matches!(
+        app.popup.as_ref().map(|p| &p.kind),
+        Some(splunk_tui::PopupType::ModifyIndex {
+            new_max_data_size_mb: Some(500),
+            freeze_projection: Some(projection),
+            ..
+        }) if projection.frozen_mb() == 500
+    ));
+
+    let action = app.handle_input(enter_key());
+    assert!(action.is_none(), "Large freeze must be acknowledged first");
+    assert!(app.popup.is_some());
+
+    app.handle_input(key('a'));
+    let action = app.handle_input(enter_key());
+    assert!(matches!(
+        action,
+        Some(Action::ModifyIndex { name, params })
+            if name == "main" && params.max_data_size_mb == Some(500)
+    ));
+}
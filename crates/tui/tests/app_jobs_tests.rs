@@ -38,6 +38,8 @@ fn create_mock_jobs(count: usize) -> Vec<SearchJobStatus> {
             cursor_time: None,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         })
         .collect()
 }
@@ -195,6 +197,26 @@ fn test_job_inspection_flow() {
     );
 }
 
+#[test]
+fn test_job_inspect_extend_ttl() {
+    let mut app = App::new(None, ConnectionContext::default());
+    app.current_screen = CurrentScreen::Jobs;
+    app.update(Action::JobsLoaded(Ok(create_mock_jobs(3))));
+    app.jobs_state.select(Some(1));
+    app.update(Action::InspectJob);
+
+    let expected_sid = app.get_selected_job().unwrap().sid.clone();
+    let action = app.handle_input(key('t'));
+    assert!(
+        matches!(
+            &action,
+            Some(Action::ExtendJobTtl { sid, ttl_secs: 86_400 }) if *sid == expected_sid
+        ),
+        "t should extend the inspected job's TTL, got {:?}",
+        action
+    );
+}
+
 #[test]
 fn test_job_inspection_without_jobs() {
     let mut app = App::new(None, ConnectionContext::default());
@@ -528,6 +550,8 @@ fn test_sort_changes_rebuild_indices() {
             cursor_time: None,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         },
         SearchJobStatus {
             sid: "job_2".to_string(),
@@ -542,6 +566,8 @@ fn test_sort_changes_rebuild_indices() {
             cursor_time: None,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         },
     ];
     app.update(Action::JobsLoaded(Ok(jobs)));
@@ -592,6 +618,8 @@ fn test_filtered_job_selection_inspect() {
             cursor_time: None,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         },
         SearchJobStatus {
             sid: "bbb_job".to_string(),
@@ -606,6 +634,8 @@ fn test_filtered_job_selection_inspect() {
             cursor_time: None,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         },
         SearchJobStatus {
             sid: "ccc_job".to_string(),
@@ -620,6 +650,8 @@ fn test_filtered_job_selection_inspect() {
             cursor_time: None,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         },
     ];
     app.update(Action::JobsLoaded(Ok(jobs)));
@@ -667,6 +699,8 @@ fn test_filtered_job_selection_cancel() {
             cursor_time: None,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         },
         SearchJobStatus {
             sid: "target_job".to_string(),
@@ -681,6 +715,8 @@ fn test_filtered_job_selection_cancel() {
             cursor_time: None,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         },
         SearchJobStatus {
             sid: "last_job".to_string(),
@@ -695,6 +731,8 @@ fn test_filtered_job_selection_cancel() {
             cursor_time: None,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         },
     ];
     app.update(Action::JobsLoaded(Ok(jobs)));
@@ -754,6 +792,8 @@ fn test_filtered_job_selection_delete() {
             cursor_time: None,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         },
         SearchJobStatus {
             sid: "delete_this".to_string(),
@@ -768,6 +808,8 @@ fn test_filtered_job_selection_delete() {
             cursor_time: None,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         },
     ];
     app.update(Action::JobsLoaded(Ok(jobs)));
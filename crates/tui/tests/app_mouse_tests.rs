@@ -1,10 +1,12 @@
 //! Integration tests for mouse selection across all TUI list screens.
 //!
 //! These tests verify that mouse clicks correctly select items in all
-//! list and table screens in the TUI.
+//! list and table screens in the TUI, and that tab bar, popup button, and
+//! scroll wheel hit-testing uses the rects recorded during render.
 
 use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::layout::Rect;
+use ratatui::{Terminal, backend::TestBackend};
 use splunk_client::models::{
     App as SplunkApp, AuditAction, AuditEvent, AuditResult, Dashboard, DataModel, FiredAlert,
     Forwarder, Index, Input, InputType, LookupTable, Macro, Role, SavedSearch, SearchJobStatus,
@@ -16,6 +18,7 @@ use splunk_tui::app::App;
 use splunk_tui::app::state::{
     ClusterViewMode, CurrentScreen, HEADER_HEIGHT, ShcViewMode, WorkloadViewMode,
 };
+use splunk_tui::ui::popup::{Popup, PopupButton, PopupType};
 
 // ============================================================================
 // PlainList Screen Tests (ListState, no header)
@@ -41,6 +44,8 @@ fn test_mouse_click_selects_index_in_indexes_list() {
             thawed_path: None,
             cold_to_frozen_dir: None,
             primary_index: None,
+            min_time: None,
+            max_time: None,
         },
         Index {
             name: "idx2".into(),
@@ -55,6 +60,8 @@ fn test_mouse_click_selects_index_in_indexes_list() {
             thawed_path: None,
             cold_to_frozen_dir: None,
             primary_index: None,
+            min_time: None,
+            max_time: None,
         },
         Index {
             name: "idx3".into(),
@@ -69,6 +76,8 @@ fn test_mouse_click_selects_index_in_indexes_list() {
             thawed_path: None,
             cold_to_frozen_dir: None,
             primary_index: None,
+            min_time: None,
+            max_time: None,
         },
     ]);
 
@@ -146,6 +155,7 @@ fn test_mouse_click_selects_user_in_users_list() {
             default_app: None,
             roles: vec![],
             last_successful_login: None,
+            locked_out: false,
         },
         User {
             name: "user2".into(),
@@ -155,6 +165,7 @@ fn test_mouse_click_selects_user_in_users_list() {
             default_app: None,
             roles: vec![],
             last_successful_login: None,
+            locked_out: false,
         },
     ]);
 
@@ -185,6 +196,9 @@ fn test_mouse_click_selects_role_in_roles_list() {
             default_app: None,
             cumulative_srch_jobs_quota: None,
             cumulative_rt_srch_jobs_quota: None,
+            srch_jobs_quota: None,
+            rt_srch_jobs_quota: None,
+            srch_disk_quota: None,
         },
         Role {
             name: "role2".into(),
@@ -195,6 +209,9 @@ fn test_mouse_click_selects_role_in_roles_list() {
             default_app: None,
             cumulative_srch_jobs_quota: None,
             cumulative_rt_srch_jobs_quota: None,
+            srch_jobs_quota: None,
+            rt_srch_jobs_quota: None,
+            srch_disk_quota: None,
         },
     ]);
 
@@ -428,6 +445,8 @@ fn test_mouse_click_outside_list_area_does_nothing() {
         thawed_path: None,
         cold_to_frozen_dir: None,
         primary_index: None,
+        min_time: None,
+        max_time: None,
     }]);
     app.indexes_state.select(Some(0));
 
@@ -727,6 +746,7 @@ fn test_mouse_click_selects_cluster_peer_in_peers_view() {
             replication_status: Some(ReplicationStatus::Complete),
             bundle_replication_count: None,
             is_captain: None,
+            splunk_version: None,
         },
         ClusterPeer {
             id: "p2".into(),
@@ -741,6 +761,7 @@ fn test_mouse_click_selects_cluster_peer_in_peers_view() {
             replication_status: Some(ReplicationStatus::Complete),
             bundle_replication_count: None,
             is_captain: None,
+            splunk_version: None,
         },
     ]);
 
@@ -957,6 +978,8 @@ fn test_double_click_on_different_job_does_not_trigger_inspect() {
             disk_usage: 0,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         },
         SearchJobStatus {
             sid: "job2".into(),
@@ -971,6 +994,8 @@ fn test_double_click_on_different_job_does_not_trigger_inspect() {
             disk_usage: 0,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         },
     ]);
 
@@ -1137,3 +1162,164 @@ fn test_scroll_down_returns_navigate_down_action() {
     let action = app.handle_mouse(event);
     assert!(matches!(action, Some(Action::NavigateDown)));
 }
+
+// ============================================================================
+// Hit-Area Registry Tests (rects recorded during render)
+// ============================================================================
+
+fn click(column: u16, row: u16) -> MouseEvent {
+    MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column,
+        row,
+        modifiers: KeyModifiers::empty(),
+    }
+}
+
+fn render(app: &mut App) {
+    let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+    terminal.draw(|f| app.render(f)).unwrap();
+}
+
+fn index(name: &str) -> Index {
+    Index {
+        name: name.into(),
+        max_total_data_size_mb: None,
+        current_db_size_mb: 0,
+        total_event_count: 0,
+        max_warm_db_count: None,
+        max_hot_buckets: None,
+        frozen_time_period_in_secs: None,
+        cold_db_path: None,
+        home_path: None,
+        thawed_path: None,
+        cold_to_frozen_dir: None,
+        primary_index: None,
+        min_time: None,
+        max_time: None,
+    }
+}
+
+#[test]
+fn test_tab_bar_click_switches_screen() {
+    let mut app = App::new(None, ConnectionContext::default());
+    app.current_screen = CurrentScreen::Search;
+    render(&mut app);
+
+    let (tab, _) = *app
+        .hit_areas
+        .tabs
+        .iter()
+        .find(|(_, screen)| *screen == CurrentScreen::Indexes)
+        .expect("Indexes tab should be visible next to Search");
+    let action = app.handle_mouse(click(tab.x + 1, tab.y));
+    assert!(matches!(
+        action,
+        Some(Action::SwitchToScreen(CurrentScreen::Indexes))
+    ));
+
+    app.update(action.unwrap());
+    assert_eq!(app.current_screen, CurrentScreen::Indexes);
+
+    // Clicking the active tab does nothing
+    render(&mut app);
+    let (tab, _) = *app
+        .hit_areas
+        .tabs
+        .iter()
+        .find(|(_, screen)| *screen == CurrentScreen::Indexes)
+        .unwrap();
+    assert!(app.handle_mouse(click(tab.x + 1, tab.y)).is_none());
+}
+
+#[test]
+fn test_click_selected_index_twice_opens_details() {
+    let mut app = App::new(None, ConnectionContext::default());
+    app.current_screen = CurrentScreen::Indexes;
+    app.indexes = Some(vec![index("idx1"), index("idx2")]);
+    render(&mut app);
+
+    let row = app.hit_areas.content.y + 2;
+    assert!(app.handle_mouse(click(10, row)).is_none());
+    assert_eq!(app.indexes_state.selected(), Some(1));
+    assert!(app.popup.is_none());
+
+    app.handle_mouse(click(10, row));
+    assert!(matches!(
+        app.popup.as_ref().map(|p| &p.kind),
+        Some(PopupType::IndexDetails)
+    ));
+}
+
+#[test]
+fn test_confirmation_popup_cancel_button_closes_without_action() {
+    let mut app = App::new(None, ConnectionContext::default());
+    app.current_screen = CurrentScreen::Jobs;
+    app.popup = Some(Popup::builder(PopupType::ConfirmCancel("sid_1".to_string())).build());
+    render(&mut app);
+
+    let (cancel, _) = *app
+        .hit_areas
+        .popup_buttons
+        .iter()
+        .find(|(_, button)| *button == PopupButton::Cancel)
+        .expect("confirmation popup should draw a cancel button");
+    let action = app.handle_mouse(click(cancel.x + 1, cancel.y));
+
+    assert!(action.is_none());
+    assert!(app.popup.is_none());
+}
+
+#[test]
+fn test_confirmation_popup_confirm_button_executes_action() {
+    let mut app = App::new(None, ConnectionContext::default());
+    app.current_screen = CurrentScreen::Jobs;
+    app.popup = Some(Popup::builder(PopupType::ConfirmCancel("sid_1".to_string())).build());
+    render(&mut app);
+
+    let (confirm, _) = *app
+        .hit_areas
+        .popup_buttons
+        .iter()
+        .find(|(_, button)| *button == PopupButton::Confirm)
+        .unwrap();
+    let action = app.handle_mouse(click(confirm.x + 1, confirm.y));
+
+    assert!(matches!(action, Some(Action::CancelJob(sid)) if sid == "sid_1"));
+}
+
+#[test]
+fn test_scroll_wheel_scrolls_help_popup() {
+    let mut app = App::new(None, ConnectionContext::default());
+    app.popup = Some(Popup::builder(PopupType::Help).build());
+
+    let scroll = |kind| MouseEvent {
+        kind,
+        column: 40,
+        row: 12,
+        modifiers: KeyModifiers::empty(),
+    };
+    app.handle_mouse(scroll(MouseEventKind::ScrollDown));
+    app.handle_mouse(scroll(MouseEventKind::ScrollDown));
+    assert_eq!(app.help_scroll_offset, 2);
+
+    app.handle_mouse(scroll(MouseEventKind::ScrollUp));
+    assert_eq!(app.help_scroll_offset, 1);
+    assert!(app.popup.is_some());
+}
+
+#[test]
+fn test_scroll_wheel_ignored_in_form_popup() {
+    let mut app = App::new(None, ConnectionContext::default());
+    app.popup = Some(Popup::builder(PopupType::ConfirmDelete("sid_1".to_string())).build());
+
+    let action = app.handle_mouse(MouseEvent {
+        kind: MouseEventKind::ScrollDown,
+        column: 40,
+        row: 12,
+        modifiers: KeyModifiers::empty(),
+    });
+
+    assert!(action.is_none());
+    assert!(app.popup.is_some());
+}
@@ -36,6 +36,8 @@ fn create_mock_jobs(count: usize) -> Vec<SearchJobStatus> {
             cursor_time: None,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         })
         .collect()
 }
@@ -290,7 +292,12 @@ fn test_shift_tab_cycles_backwards() {
     app.update(action.unwrap());
     assert_eq!(app.current_screen, CurrentScreen::Settings);
 
-    // Shift+Tab from Settings should go to Shc
+    // Shift+Tab from Settings should go to Rest
+    let action = app.handle_input(shift_tab_key());
+    app.update(action.unwrap());
+    assert_eq!(app.current_screen, CurrentScreen::Rest);
+
+    // Shift+Tab from Rest should go to Shc
     let action = app.handle_input(shift_tab_key());
     app.update(action.unwrap());
     assert_eq!(app.current_screen, CurrentScreen::Shc);
@@ -335,7 +342,22 @@ fn test_shift_tab_cycles_backwards() {
     app.update(action.unwrap());
     assert_eq!(app.current_screen, CurrentScreen::Configs);
 
-    // Shift+Tab from Configs should go to Inputs
+    // Shift+Tab from Configs should go to Hosts & Sources
+    let action = app.handle_input(shift_tab_key());
+    app.update(action.unwrap());
+    assert_eq!(app.current_screen, CurrentScreen::Metadata);
+
+    // Shift+Tab from Hosts & Sources should go to Sourcetypes
+    let action = app.handle_input(shift_tab_key());
+    app.update(action.unwrap());
+    assert_eq!(app.current_screen, CurrentScreen::Sourcetypes);
+
+    // Shift+Tab from Sourcetypes should go to Ingestion
+    let action = app.handle_input(shift_tab_key());
+    app.update(action.unwrap());
+    assert_eq!(app.current_screen, CurrentScreen::Ingestion);
+
+    // Shift+Tab from Ingestion should go to Inputs
     let action = app.handle_input(shift_tab_key());
     app.update(action.unwrap());
     assert_eq!(app.current_screen, CurrentScreen::Inputs);
@@ -371,6 +393,9 @@ fn test_navigation_from_all_screens() {
         CurrentScreen::Roles,
         CurrentScreen::SearchPeers,
         CurrentScreen::Inputs,
+        CurrentScreen::Ingestion,
+        CurrentScreen::Sourcetypes,
+        CurrentScreen::Metadata,
         CurrentScreen::Configs,
         CurrentScreen::FiredAlerts,
         CurrentScreen::Settings,
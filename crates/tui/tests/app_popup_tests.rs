@@ -40,6 +40,8 @@ fn create_mock_jobs(count: usize) -> Vec<SearchJobStatus> {
             cursor_time: None,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         })
         .collect()
 }
@@ -607,6 +609,8 @@ fn test_popup_delete_index_mouse_click_inside() {
         thawed_path: None,
         cold_to_frozen_dir: None,
         primary_index: None,
+        min_time: None,
+        max_time: None,
     };
     app.update(Action::IndexesLoaded(Ok(vec![index])));
     app.indexes_state.select(Some(0));
@@ -34,6 +34,8 @@ fn create_mock_jobs(count: usize) -> Vec<SearchJobStatus> {
             cursor_time: None,
             priority: None,
             label: None,
+            ttl: None,
+            performance: None,
         })
         .collect()
 }
@@ -75,6 +77,9 @@ fn test_rapid_screen_switching() {
                 | CurrentScreen::Roles
                 | CurrentScreen::SearchPeers
                 | CurrentScreen::Inputs
+                | CurrentScreen::Ingestion
+                | CurrentScreen::Sourcetypes
+                | CurrentScreen::Metadata
                 | CurrentScreen::Configs
                 | CurrentScreen::FiredAlerts
                 | CurrentScreen::Settings
@@ -87,6 +92,7 @@ fn test_rapid_screen_switching() {
                 | CurrentScreen::DataModels
                 | CurrentScreen::WorkloadManagement
                 | CurrentScreen::Shc
+                | CurrentScreen::Rest
                 | CurrentScreen::License
                 | CurrentScreen::Kvstore
         ),
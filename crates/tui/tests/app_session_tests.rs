@@ -248,6 +248,8 @@ fn test_session_error_recovery_flow() {
         cursor_time: None,
         priority: None,
         label: None,
+        ttl: None,
+        performance: None,
     }];
     app.update(Action::JobsLoaded(Ok(jobs)));
 
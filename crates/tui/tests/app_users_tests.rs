@@ -0,0 +1,63 @@
+//! Tests for users screen actions.
+//!
+//! This module tests:
+//! - Unlocking a locked-out user
+//! - Blocking user management without the `edit_user` capability
+//!
+//! ## Invariants
+//! - Unlock is only offered for users that are locked out
+
+mod helpers;
+use helpers::*;
+use splunk_client::models::CurrentContext;
+use splunk_tui::{CurrentScreen, action::Action, app::App, app::ConnectionContext};
+
+#[test]
+fn test_users_unlock_only_for_locked_out_user() {
+    let mut app = App::new(None, ConnectionContext::default());
+    app.current_screen = CurrentScreen::Users;
+    let mut users = create_mock_users();
+    users[1].locked_out = true;
+    app.users = Some(users);
+
+    app.users_state.select(Some(0));
+    assert!(app.handle_input(key('U')).is_none());
+    assert!(
+        app.toasts
+            .iter()
+            .any(|t| t.message.contains("'admin' is not locked out"))
+    );
+
+    app.users_state.select(Some(1));
+    let action = app.handle_input(key('U'));
+    assert!(matches!(action, Some(Action::UnlockUser { name }) if name == "power_user"));
+}
+
+#[test]
+fn test_users_management_blocked_without_edit_user() {
+    let mut app = App::new(None, ConnectionContext::default());
+    app.current_screen = CurrentScreen::Users;
+    let mut users = create_mock_users();
+    users[1].locked_out = true;
+    app.users = Some(users);
+    app.users_state.select(Some(1));
+    app.update(Action::CurrentContextLoaded(Ok(CurrentContext {
+        username: "analyst".to_string(),
+        capabilities: vec!["search".to_string()],
+        ..Default::default()
+    })));
+
+    assert!(app.handle_input(key('U')).is_none());
+    assert!(app.handle_input(key('c')).is_none());
+    assert!(
+        app.toasts
+            .iter()
+            .any(|t| t.message == "Requires the 'edit_user' capability")
+    );
+
+    // Navigation is unaffected
+    assert!(matches!(
+        app.handle_input(key('j')),
+        Some(Action::NavigateDown)
+    ));
+}
@@ -114,6 +114,9 @@ fn test_first_run_detection_with_profiles() {
             health_check_interval_seconds: None,
             session_expiry_buffer_seconds: None,
             session_ttl_seconds: None,
+            cloud: None,
+            acs_stack: None,
+            health_thresholds: None,
         };
         config_manager.save_profile("default", profile).unwrap();
 
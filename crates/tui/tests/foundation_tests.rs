@@ -687,6 +687,8 @@ fn test_jobs_click_returns_focus_action() {
         disk_usage: 0,
         priority: None,
         label: None,
+        ttl: None,
+        performance: None,
     }]);
     // Note: rebuild_filtered_indices() is pub(crate) and not accessible from integration tests.
     // Double-click behavior is tested in unit tests in mouse.rs.
@@ -293,6 +293,7 @@ pub fn create_mock_users() -> Vec<User> {
             default_app: Some("launcher".to_string()),
             roles: vec!["admin".to_string(), "can_delete".to_string()],
             last_successful_login: Some(1736956200), // 2024-01-15 10:30:00 UTC
+            locked_out: false,
         },
         User {
             name: "power_user".to_string(),
@@ -302,6 +303,7 @@ pub fn create_mock_users() -> Vec<User> {
             default_app: Some("search".to_string()),
             roles: vec!["power".to_string()],
             last_successful_login: Some(1736870400), // 2024-01-14 10:00:00 UTC
+            locked_out: false,
         },
         User {
             name: "user_no_roles".to_string(),
@@ -311,6 +313,7 @@ pub fn create_mock_users() -> Vec<User> {
             default_app: None,
             roles: vec![],
             last_successful_login: None,
+            locked_out: false,
         },
     ]
 }
@@ -331,6 +334,8 @@ pub fn create_mock_jobs() -> Vec<SearchJobStatus> {
             cursor_time: Some("2024-01-15T10:30:00.000Z".to_string()),
             priority: Some(5),
             label: Some("Scheduled search".to_string()),
+            ttl: None,
+            performance: None,
         },
         SearchJobStatus {
             sid: "admin_search_9876543210".to_string(),
@@ -345,6 +350,8 @@ pub fn create_mock_jobs() -> Vec<SearchJobStatus> {
             cursor_time: Some("2024-01-15T10:29:00.000Z".to_string()),
             priority: Some(3),
             label: Some("Ad-hoc search".to_string()),
+            ttl: None,
+            performance: None,
         },
     ]
 }
@@ -364,6 +371,8 @@ pub fn create_mock_index() -> Index {
         thawed_path: Some("/opt/splunk/thawed".to_string()),
         cold_to_frozen_dir: Some("/opt/splunk/frozen".to_string()),
         primary_index: Some(false),
+        min_time: None,
+        max_time: None,
     }
 }
 
@@ -8,7 +8,7 @@ use splunk_tui::app::CurrentScreen;
 
 /// The expected number of screens in all_screens().
 /// This must be updated when adding a new screen to the application.
-const EXPECTED_SCREEN_COUNT: usize = 28;
+const EXPECTED_SCREEN_COUNT: usize = 32;
 
 /// Verifies that all_screens() count matches the expected count.
 ///
@@ -122,6 +122,9 @@ fn test_all_screens_have_section_mappings() {
         CurrentScreen::Roles,
         CurrentScreen::SearchPeers,
         CurrentScreen::Inputs,
+        CurrentScreen::Ingestion,
+        CurrentScreen::Sourcetypes,
+        CurrentScreen::Metadata,
         CurrentScreen::Configs,
         CurrentScreen::Settings,
         CurrentScreen::Overview,
@@ -134,6 +137,7 @@ fn test_all_screens_have_section_mappings() {
         CurrentScreen::DataModels,
         CurrentScreen::WorkloadManagement,
         CurrentScreen::Shc,
+        CurrentScreen::Rest,
     ];
 
     // Verify we have the expected number of screens
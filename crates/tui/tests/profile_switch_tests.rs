@@ -148,6 +148,7 @@ fn test_profile_switch_success_updates_context() {
         profile_name: Some("production".to_string()),
         base_url: "https://splunk.prod.example.com:8089".to_string(),
         auth_mode: "token".to_string(),
+        health_thresholds: None,
     };
     app.update(Action::ProfileSwitchResult(Ok(ctx)));
 
@@ -196,6 +197,7 @@ fn test_profile_switch_clears_server_info() {
         profile_name: Some("dev".to_string()),
         base_url: "https://splunk.dev.example.com:8089".to_string(),
         auth_mode: "session (admin)".to_string(),
+        health_thresholds: None,
     };
     app.update(Action::ProfileSwitchResult(Ok(ctx)));
 
@@ -355,6 +357,7 @@ fn test_profile_switch_updates_context_for_header() {
         profile_name: Some("new_profile".to_string()),
         base_url: "https://new.splunk.com:8089".to_string(),
         auth_mode: "token".to_string(),
+        health_thresholds: None,
     };
     app.update(Action::ProfileSwitchResult(Ok(ctx)));
 
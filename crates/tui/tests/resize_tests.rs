@@ -390,6 +390,8 @@ fn test_resize_with_jobs_loaded() {
             cursor_time: Some("2024-01-15T10:30:00.000Z".to_string()),
             priority: Some(5),
             label: Some("Test job".to_string()),
+            ttl: None,
+            performance: None,
         }])));
 
     // Initial render
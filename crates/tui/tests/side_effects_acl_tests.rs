@@ -0,0 +1,117 @@
+//! Knowledge object permission (ACL) side effect handler tests.
+//!
+//! This module tests LoadObjectAcl and UpdateObjectAcl, which back the
+//! permissions popup on the saved searches, macros, lookups and dashboards screens.
+
+mod common;
+
+use common::*;
+use splunk_client::models::{AclUpdateParams, KnowledgeObjectKind};
+use wiremock::matchers::{body_string_contains, method, path};
+
+const SEARCH_PATH: &str = "/services/saved/searches/Errors%20in%20the%20last%2024%20hours";
+
+/// Test that loading an ACL sends ObjectAclLoaded with the flattened permissions.
+#[tokio::test]
+async fn test_load_object_acl_success() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    let fixture = load_fixture("acl/get_saved_search_acl.json");
+    Mock::given(method("GET"))
+        .and(path(SEARCH_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(
+            Action::LoadObjectAcl {
+                kind: KnowledgeObjectKind::SavedSearch,
+                name: "Errors in the last 24 hours".to_string(),
+            },
+            2,
+        )
+        .await;
+
+    assert!(
+        actions.iter().any(|a| matches!(
+            a,
+            Action::ObjectAclLoaded(Ok(acl))
+                if acl.sharing == "app" && acl.write == vec!["admin", "power"]
+        )),
+        "Should send ObjectAclLoaded with the saved search ACL"
+    );
+}
+
+/// Test that updating an ACL posts the merged ACL and sends ObjectAclUpdated.
+#[tokio::test]
+async fn test_update_object_acl_success() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    let fixture = load_fixture("acl/get_saved_search_acl.json");
+    Mock::given(method("GET"))
+        .and(path(SEARCH_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&harness.mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(
+            "/servicesNS/admin/search/saved/searches/Errors%20in%20the%20last%2024%20hours/acl",
+        ))
+        .and(body_string_contains("sharing=global"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .expect(1)
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(
+            Action::UpdateObjectAcl {
+                kind: KnowledgeObjectKind::SavedSearch,
+                name: "Errors in the last 24 hours".to_string(),
+                params: AclUpdateParams {
+                    sharing: Some("global".to_string()),
+                    ..AclUpdateParams::default()
+                },
+            },
+            2,
+        )
+        .await;
+
+    assert!(
+        actions.iter().any(|a| matches!(
+            a,
+            Action::ObjectAclUpdated(Ok(acl)) if acl.sharing == "global"
+        )),
+        "Should send ObjectAclUpdated with the written ACL"
+    );
+}
+
+/// Test that a missing object sends ObjectAclLoaded(Err).
+#[tokio::test]
+async fn test_load_object_acl_not_found() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/admin/macros/missing"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(
+            Action::LoadObjectAcl {
+                kind: KnowledgeObjectKind::Macro,
+                name: "missing".to_string(),
+            },
+            2,
+        )
+        .await;
+
+    assert!(
+        actions
+            .iter()
+            .any(|a| matches!(a, Action::ObjectAclLoaded(Err(_)))),
+        "Should send ObjectAclLoaded(Err)"
+    );
+}
@@ -1,7 +1,7 @@
 //! Apps side effect handler tests.
 //!
 //! This module tests app-related side effect handlers including
-//! LoadApps, EnableApp, and DisableApp.
+//! LoadApps, EnableApp, DisableApp, InstallApp, and LoadAppContents.
 
 mod common;
 
@@ -184,3 +184,138 @@ async fn test_disable_app_error() {
         "Should send Loading(false) on error"
     );
 }
+
+#[tokio::test]
+async fn test_install_app_from_file_success() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let package = temp_dir.path().join("my_app.spl");
+    std::fs::write(&package, b"package").unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/services/apps/appinstall"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{"name": "my_app", "content": {"disabled": false}}]
+        })))
+        .expect(1)
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(
+            Action::InstallApp {
+                source: splunk_client::workflows::splunkbase::AppPackageSource::File(package),
+            },
+            2,
+        )
+        .await;
+
+    assert!(
+        actions.iter().any(|a| matches!(
+            a,
+            Action::Notify(splunk_tui::ui::ToastLevel::Success, msg) if msg.contains("my_app")
+        )),
+        "Should send success notification"
+    );
+    assert!(
+        actions.iter().any(|a| matches!(a, Action::LoadApps { .. })),
+        "Should send LoadApps to refresh"
+    );
+}
+
+#[tokio::test]
+async fn test_install_app_from_missing_file_error() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    let actions = harness
+        .handle_and_collect(
+            Action::InstallApp {
+                source: splunk_client::workflows::splunkbase::AppPackageSource::File(
+                    "/nonexistent/my_app.spl".into(),
+                ),
+            },
+            2,
+        )
+        .await;
+
+    assert!(
+        actions.iter().any(|a| matches!(
+            a,
+            Action::Notify(splunk_tui::ui::ToastLevel::Error, msg)
+                if msg.contains("/nonexistent/my_app.spl")
+        )),
+        "Should send error notification naming the package"
+    );
+}
+
+#[tokio::test]
+async fn test_load_app_contents_success() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    for endpoint in [
+        "saved/searches",
+        "data/lookup-table-files",
+        "admin/macros",
+        "saved/eventtypes",
+    ] {
+        Mock::given(method("GET"))
+            .and(path(format!("/servicesNS/-/my_app/{endpoint}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "entry": []
+            })))
+            .mount(&harness.mock_server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path("/servicesNS/-/my_app/data/ui/views"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{"name": "overview", "content": {"label": "Overview"}}]
+        })))
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(
+            Action::LoadAppContents {
+                app: "my_app".to_string(),
+            },
+            2,
+        )
+        .await;
+
+    assert!(
+        actions.iter().any(|a| matches!(
+            a,
+            Action::AppContentsLoaded(Ok(contents))
+                if contents.app == "my_app" && contents.objects.len() == 1
+        )),
+        "Should send AppContentsLoaded with the app's dashboard"
+    );
+}
+
+#[tokio::test]
+async fn test_load_app_contents_error() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(
+            Action::LoadAppContents {
+                app: "missing_app".to_string(),
+            },
+            2,
+        )
+        .await;
+
+    assert!(
+        actions
+            .iter()
+            .any(|a| matches!(a, Action::AppContentsLoaded(Err(_)))),
+        "Should send AppContentsLoaded error"
+    );
+}
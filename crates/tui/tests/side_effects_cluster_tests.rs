@@ -1,12 +1,14 @@
 //! Cluster side effect handler tests.
 //!
 //! This module tests the cluster-related side effect handlers including
-//! LoadClusterInfo and LoadClusterPeers.
+//! LoadClusterInfo, LoadClusterPeers, LoadClusterBucketHealth, and
+//! LoadClusterIndexCompliance, plus the
+//! version skew report collected when the target is the cluster manager.
 
 mod common;
 
 use common::*;
-use wiremock::matchers::{method, path};
+use wiremock::matchers::{method, path, query_param};
 
 #[tokio::test]
 async fn test_load_cluster_info_success() {
@@ -96,3 +98,155 @@ async fn test_load_cluster_peers_success() {
         "Should send ClusterPeersLoaded(Ok)"
     );
 }
+
+#[tokio::test]
+async fn test_load_cluster_bucket_health_success() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    let fixups = load_fixture("cluster/get_cluster_fixups.json");
+    Mock::given(method("GET"))
+        .and(path("/services/cluster/master/fixup"))
+        .and(query_param("level", "search_factor"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixups))
+        .mount(&harness.mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/cluster/master/fixup"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"entry": []})))
+        .mount(&harness.mock_server)
+        .await;
+
+    let indexes = load_fixture("cluster/get_cluster_indexes.json");
+    Mock::given(method("GET"))
+        .and(path("/services/cluster/master/indexes"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&indexes))
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(Action::LoadClusterBucketHealth, 2)
+        .await;
+
+    let health = actions
+        .iter()
+        .find_map(|a| match a {
+            Action::ClusterBucketHealthLoaded(Ok(health)) => Some(health),
+            _ => None,
+        })
+        .expect("Should send ClusterBucketHealthLoaded(Ok)");
+
+    assert_eq!(health.total_fixups(), 2);
+    assert_eq!(
+        health.fixup_count(splunk_client::models::ClusterFixupLevel::SearchFactor),
+        2
+    );
+    assert_eq!(health.total_excess_copies(), 3);
+}
+
+#[tokio::test]
+async fn test_load_cluster_info_on_manager_collects_versions() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/cluster/master/config"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{"content": {"id": "cluster-01", "mode": "manager"}}]
+        })))
+        .mount(&harness.mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(load_fixture("server/get_server_info.json")),
+        )
+        .mount(&harness.mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/cluster/master/peers"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "content": {
+                    "id": "peer-01",
+                    "label": "idx1",
+                    "status": "Up",
+                    "peer_state": "searchable",
+                    "guid": "guid-01",
+                    "host": "idx1.example.com",
+                    "port": 8089,
+                    "splunk_version": "9.2.0"
+                }
+            }]
+        })))
+        .mount(&harness.mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/cluster/master/searchheads"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(load_fixture("cluster/get_cluster_searchheads.json")),
+        )
+        .mount(&harness.mock_server)
+        .await;
+    for endpoint in ["fixup", "indexes"] {
+        Mock::given(method("GET"))
+            .and(path(format!("/services/cluster/master/{endpoint}")))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"entry": []})),
+            )
+            .mount(&harness.mock_server)
+            .await;
+    }
+
+    let actions = harness.handle_and_collect(Action::LoadClusterInfo, 4).await;
+
+    let report = actions
+        .iter()
+        .find_map(|a| match a {
+            Action::ClusterVersionsLoaded(Ok(report)) => Some(report),
+            _ => None,
+        })
+        .expect("Should send ClusterVersionsLoaded(Ok)");
+
+    // Manager (9.1.2), one peer, and both registered search heads
+    assert_eq!(report.nodes.len(), 4);
+    assert_eq!(report.manager_version(), Some("9.1.2"));
+    let flagged: Vec<&str> = report.skew.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(flagged, vec!["idx1", "cm-01", "sh-01"]);
+}
+
+#[tokio::test]
+async fn test_load_cluster_index_compliance_success() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    let config = load_fixture("cluster/get_cluster_info.json");
+    Mock::given(method("GET"))
+        .and(path("/services/cluster/master/config"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&config))
+        .mount(&harness.mock_server)
+        .await;
+
+    let indexes = load_fixture("cluster/get_cluster_indexes.json");
+    Mock::given(method("GET"))
+        .and(path("/services/cluster/master/indexes"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&indexes))
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(Action::LoadClusterIndexCompliance, 2)
+        .await;
+
+    let indexes = actions
+        .iter()
+        .find_map(|a| match a {
+            Action::ClusterIndexComplianceLoaded(Ok(indexes)) => Some(indexes),
+            _ => None,
+        })
+        .expect("Should send ClusterIndexComplianceLoaded(Ok)");
+
+    assert_eq!(indexes.len(), 2);
+    assert_eq!(indexes[0].index, "main");
+    assert!(!indexes[0].compliant);
+    assert_eq!(indexes[0].buckets_below_rf, 2);
+    assert!(indexes[1].compliant);
+}
@@ -42,6 +42,30 @@ async fn test_diagnostics_all_healthy() {
         .mount(&harness.mock_server)
         .await;
 
+    let partitions = serde_json::json!({
+        "entry": [{
+            "name": "1",
+            "content": {
+                "mount_point": "/opt/splunk",
+                "fs_type": "ext4",
+                "capacity": "102400",
+                "free": "81920"
+            }
+        }]
+    });
+    Mock::given(method("GET"))
+        .and(path("/services/server/status/partitions-space"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&partitions))
+        .mount(&harness.mock_server)
+        .await;
+
+    let search_concurrency = load_fixture("server/get_search_concurrency.json");
+    Mock::given(method("GET"))
+        .and(path("/services/server/status/limits/search-concurrency"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&search_concurrency))
+        .mount(&harness.mock_server)
+        .await;
+
     // Log parsing health uses a search
     let create_job_response = serde_json::json!({
         "sid": "test-sid"
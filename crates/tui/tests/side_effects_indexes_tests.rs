@@ -1,11 +1,13 @@
 //! Indexes side effect handler tests.
 //!
 //! This module tests the LoadIndexes side effect handler which fetches
-//! index information from the Splunk REST API.
+//! index information from the Splunk REST API, and the hot bucket roll,
+//! data rebalance, and event sampling handlers.
 
 mod common;
 
 use common::*;
+use splunk_tui::ToastLevel;
 use wiremock::matchers::{method, path};
 
 #[tokio::test]
@@ -91,3 +93,97 @@ async fn test_load_indexes_error() {
         "Should send IndexesLoaded(Err)"
     );
 }
+
+#[tokio::test]
+async fn test_roll_hot_buckets_notifies_and_reloads() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/data/indexes/main/roll-hot-buckets"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(
+            Action::RollHotBuckets {
+                name: "main".to_string(),
+            },
+            3,
+        )
+        .await;
+
+    assert!(
+        actions.iter().any(|a| matches!(
+            a,
+            Action::Notify(ToastLevel::Success, msg) if msg.contains("'main' rolled to warm")
+        )),
+        "Should notify success"
+    );
+    assert!(
+        actions
+            .iter()
+            .any(|a| matches!(a, Action::LoadIndexes { offset: 0, .. })),
+        "Should reload indexes"
+    );
+}
+
+#[tokio::test]
+async fn test_rebalance_index_data_error_notifies() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/services/cluster/master/control/control/rebalance_buckets",
+        ))
+        .respond_with(ResponseTemplate::new(400).set_body_string("not a cluster manager"))
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(
+            Action::RebalanceIndexData {
+                index: "main".to_string(),
+            },
+            3,
+        )
+        .await;
+
+    assert!(
+        actions.iter().any(|a| matches!(
+            a,
+            Action::Notify(ToastLevel::Error, msg) if msg.contains("data rebalance for 'main'")
+        )),
+        "Should notify the failure"
+    );
+    assert!(actions.iter().any(|a| matches!(a, Action::Loading(false))));
+}
+
+#[tokio::test]
+async fn test_load_index_sample_error_reports_index() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .respond_with(ResponseTemplate::new(400).set_body_string("Unknown index"))
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(
+            Action::LoadIndexSample {
+                index: "missing".to_string(),
+            },
+            2,
+        )
+        .await;
+
+    assert!(
+        actions.iter().any(|a| matches!(
+            a,
+            Action::IndexSampleLoaded { index, result: Err(_) } if index == "missing"
+        )),
+        "Should report the failed sample"
+    );
+}
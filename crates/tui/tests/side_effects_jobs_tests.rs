@@ -1,12 +1,12 @@
 //! Jobs side effect handler tests.
 //!
 //! This module tests job-related side effect handlers including
-//! LoadJobs, CancelJob, DeleteJob, and batch operations.
+//! LoadJobs, CancelJob, DeleteJob, ExtendJobTtl, LoadJobPerformance, and batch operations.
 
 mod common;
 
 use common::*;
-use wiremock::matchers::{method, path};
+use wiremock::matchers::{body_string_contains, method, path};
 
 #[tokio::test]
 async fn test_load_jobs_success() {
@@ -144,6 +144,85 @@ async fn test_cancel_job_error() {
     );
 }
 
+#[tokio::test]
+async fn test_extend_job_ttl_success() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs/test-job-sid/control"))
+        .and(body_string_contains("action=setttl"))
+        .and(body_string_contains("ttl=86400"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(
+            Action::ExtendJobTtl {
+                sid: "test-job-sid".to_string(),
+                ttl_secs: 86_400,
+            },
+            2,
+        )
+        .await;
+
+    assert!(
+        actions.iter().any(
+            |a| matches!(a, Action::JobOperationComplete(msg) if msg.contains("test-job-sid"))
+        ),
+        "Should send JobOperationComplete"
+    );
+    assert!(
+        actions.iter().any(|a| matches!(a, Action::LoadJobs { .. })),
+        "Should send LoadJobs to refresh"
+    );
+}
+
+#[tokio::test]
+async fn test_load_job_performance_success() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/perf-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "entry": [{
+                "name": "perf-sid",
+                "content": {
+                    "sid": "perf-sid",
+                    "isDone": true,
+                    "performance": {
+                        "command.search": {"duration_secs": 0.5, "invocations": 3},
+                        "dispatch.stream.remote.idx1": {"duration_secs": 0.3, "invocations": 2}
+                    }
+                }
+            }]
+        })))
+        .expect(1)
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(
+            Action::LoadJobPerformance {
+                sid: "perf-sid".to_string(),
+            },
+            2,
+        )
+        .await;
+
+    let status = actions
+        .iter()
+        .find_map(|a| match a {
+            Action::JobPerformanceLoaded(Ok(status)) => Some(status),
+            _ => None,
+        })
+        .expect("Should send JobPerformanceLoaded");
+    let performance = status.performance.as_ref().unwrap();
+    assert_eq!(performance.commands().len(), 1);
+    assert_eq!(performance.peers()[0].name, "idx1");
+}
+
 #[tokio::test]
 async fn test_delete_job_success() {
     let mut harness = SideEffectsTestHarness::new().await;
@@ -333,6 +412,53 @@ async fn test_delete_jobs_batch_all_success() {
     );
 }
 
+/// Test that batch deletes drive the shared operation progress popup.
+#[tokio::test]
+async fn test_delete_jobs_batch_reports_progress() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/services/search/jobs/job1"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&harness.mock_server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/services/search/jobs/job2"))
+        .respond_with(ResponseTemplate::new(404).set_body_string("Not found"))
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(
+            Action::DeleteJobsBatch(vec!["job1".to_string(), "job2".to_string()]),
+            2,
+        )
+        .await;
+
+    let started = actions.iter().find_map(|a| match a {
+        Action::OperationStarted(progress) => Some(progress),
+        _ => None,
+    });
+    let started = started.expect("Should open the progress popup");
+    assert_eq!(started.total, Some(2));
+
+    let last_update = actions
+        .iter()
+        .filter_map(|a| match a {
+            Action::OperationProgressed(update) => Some(update),
+            _ => None,
+        })
+        .next_back()
+        .expect("Should report per-item progress");
+    assert_eq!((last_update.completed, last_update.failed), (1, 1));
+
+    assert!(actions.iter().any(|a| matches!(
+        a,
+        Action::OperationFinished { id, cancelled: false } if *id == started.id
+    )));
+}
+
 #[tokio::test]
 async fn test_delete_jobs_batch_all_failure() {
     let mut harness = SideEffectsTestHarness::new().await;
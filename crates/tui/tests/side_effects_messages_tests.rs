@@ -0,0 +1,107 @@
+//! Server messages side effect handler tests.
+//!
+//! This module tests loading and dismissing bulletin board messages.
+//! Key tests verify that DismissServerMessage refreshes the message list so the
+//! header badge reflects the server state.
+
+mod common;
+
+use common::*;
+use wiremock::matchers::{method, path};
+
+/// Test that loading server messages sends ServerMessagesLoaded, most severe first.
+#[tokio::test]
+async fn test_load_server_messages_success() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    let fixture = load_fixture("messages/list_messages.json");
+    Mock::given(method("GET"))
+        .and(path("/services/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(Action::LoadServerMessages, 2)
+        .await;
+
+    let messages = actions
+        .iter()
+        .find_map(|a| match a {
+            Action::ServerMessagesLoaded(Ok(messages)) => Some(messages),
+            _ => None,
+        })
+        .expect("Should send ServerMessagesLoaded(Ok)");
+    assert_eq!(messages.len(), 3);
+    assert_eq!(messages[0].name, "disk_space");
+}
+
+/// Test that dismissing a message sends ServerMessageDismissed and a refreshed list.
+#[tokio::test]
+async fn test_dismiss_server_message_refreshes_list() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/services/messages/restart_required"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&harness.mock_server)
+        .await;
+
+    let fixture = load_fixture("messages/list_messages.json");
+    Mock::given(method("GET"))
+        .and(path("/services/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(
+            Action::DismissServerMessage {
+                name: "restart_required".to_string(),
+            },
+            2,
+        )
+        .await;
+
+    assert!(
+        actions.iter().any(
+            |a| matches!(a, Action::ServerMessageDismissed(Ok(name)) if name == "restart_required")
+        ),
+        "Should send ServerMessageDismissed(Ok)"
+    );
+    assert!(
+        actions
+            .iter()
+            .any(|a| matches!(a, Action::ServerMessagesLoaded(Ok(_)))),
+        "Should send ServerMessagesLoaded to refresh the list"
+    );
+}
+
+/// Test that a failed dismissal is reported as an error.
+#[tokio::test]
+async fn test_dismiss_server_message_not_found() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/services/messages/missing"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(
+            Action::DismissServerMessage {
+                name: "missing".to_string(),
+            },
+            2,
+        )
+        .await;
+
+    assert!(
+        actions
+            .iter()
+            .any(|a| matches!(a, Action::ServerMessageDismissed(Err(_)))),
+        "Should send ServerMessageDismissed(Err)"
+    );
+}
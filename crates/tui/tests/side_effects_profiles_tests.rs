@@ -28,6 +28,9 @@ async fn test_save_profile_without_rename() {
             session_expiry_buffer_seconds: Some(60),
             session_ttl_seconds: Some(3600),
             health_check_interval_seconds: Some(60),
+            cloud: None,
+            acs_stack: None,
+            health_thresholds: None,
         };
         cm.save_profile("test-profile", profile)
             .expect("Failed to save profile");
@@ -47,6 +50,9 @@ async fn test_save_profile_without_rename() {
         session_expiry_buffer_seconds: Some(60),
         session_ttl_seconds: Some(3600),
         health_check_interval_seconds: Some(60),
+        cloud: None,
+        acs_stack: None,
+        health_thresholds: None,
     };
 
     let actions = harness
@@ -102,6 +108,9 @@ async fn test_save_profile_with_rename() {
             session_expiry_buffer_seconds: Some(60),
             session_ttl_seconds: Some(3600),
             health_check_interval_seconds: Some(60),
+            cloud: None,
+            acs_stack: None,
+            health_thresholds: None,
         };
         cm.save_profile("old-profile", profile)
             .expect("Failed to save profile");
@@ -121,6 +130,9 @@ async fn test_save_profile_with_rename() {
         session_expiry_buffer_seconds: Some(60),
         session_ttl_seconds: Some(3600),
         health_check_interval_seconds: Some(60),
+        cloud: None,
+        acs_stack: None,
+        health_thresholds: None,
     };
 
     let actions = harness
@@ -187,6 +199,9 @@ async fn test_save_profile_rename_same_name() {
             session_expiry_buffer_seconds: Some(60),
             session_ttl_seconds: Some(3600),
             health_check_interval_seconds: Some(60),
+            cloud: None,
+            acs_stack: None,
+            health_thresholds: None,
         };
         cm.save_profile("same-profile", profile)
             .expect("Failed to save profile");
@@ -206,6 +221,9 @@ async fn test_save_profile_rename_same_name() {
         session_expiry_buffer_seconds: Some(60),
         session_ttl_seconds: Some(3600),
         health_check_interval_seconds: Some(60),
+        cloud: None,
+        acs_stack: None,
+        health_thresholds: None,
     };
 
     let actions = harness
@@ -264,6 +282,9 @@ async fn test_open_profile_switcher_with_profiles() {
             session_expiry_buffer_seconds: Some(60),
             session_ttl_seconds: Some(3600),
             health_check_interval_seconds: Some(60),
+            cloud: None,
+            acs_stack: None,
+            health_thresholds: None,
         };
         cm.save_profile("test-profile", profile)
             .expect("Failed to save profile");
@@ -338,6 +359,9 @@ async fn test_save_profile_from_tutorial_emits_tutorial_action() {
         session_expiry_buffer_seconds: Some(60),
         session_ttl_seconds: Some(3600),
         health_check_interval_seconds: Some(60),
+        cloud: None,
+        acs_stack: None,
+        health_thresholds: None,
     };
 
     let actions = harness
@@ -390,6 +414,9 @@ async fn test_save_profile_without_tutorial_does_not_emit_tutorial_action() {
         session_expiry_buffer_seconds: Some(60),
         session_ttl_seconds: Some(3600),
         health_check_interval_seconds: Some(60),
+        cloud: None,
+        acs_stack: None,
+        health_thresholds: None,
     };
 
     let actions = harness
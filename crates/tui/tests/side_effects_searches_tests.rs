@@ -65,6 +65,7 @@ async fn test_run_search_success() {
                 search_defaults,
                 search_mode: SearchMode::Normal,
                 realtime_window: None,
+                finalize_after: None,
             },
             4, // Expect 4 actions: Loading(true), Progress, SearchStarted, SearchComplete
         )
@@ -148,6 +149,7 @@ async fn test_run_search_normalizes_bare_spl_queries() {
                 search_defaults,
                 search_mode: SearchMode::Normal,
                 realtime_window: None,
+                finalize_after: None,
             },
             4,
         )
@@ -161,6 +163,86 @@ async fn test_run_search_normalizes_bare_spl_queries() {
     );
 }
 
+#[tokio::test]
+async fn test_run_search_auto_finalize_notifies_partial_results() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "sid": "finalize-sid"
+        })))
+        .mount(&harness.mock_server)
+        .await;
+
+    let job_status = |is_done: bool, result_count: usize| {
+        serde_json::json!({
+            "entry": [{
+                "content": {
+                    "sid": "finalize-sid",
+                    "isDone": is_done,
+                    "isFinalized": is_done,
+                    "doneProgress": if is_done { 1.0 } else { 0.4 },
+                    "eventCount": result_count,
+                    "resultCount": result_count
+                }
+            }]
+        })
+    };
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/finalize-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(job_status(false, 120)))
+        .up_to_n_times(1)
+        .mount(&harness.mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/finalize-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(job_status(true, 130)))
+        .mount(&harness.mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs/finalize-sid/control"))
+        .and(body_string_contains("action=finalize"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&harness.mock_server)
+        .await;
+
+    let results_fixture = load_fixture("search/get_results.json");
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/finalize-sid/results"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&results_fixture))
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(
+            Action::RunSearch {
+                query: "index=main".to_string(),
+                search_defaults: splunk_config::SearchDefaults::default(),
+                search_mode: SearchMode::Normal,
+                realtime_window: None,
+                finalize_after: Some(100),
+            },
+            5,
+        )
+        .await;
+
+    assert!(
+        actions.iter().any(|a| matches!(
+            a,
+            Action::Notify(_, message) if message.contains("finalized early after 130 results")
+        )),
+        "Should notify that results are partial"
+    );
+    assert!(
+        actions
+            .iter()
+            .any(|a| matches!(a, Action::SearchComplete(Ok(_)))),
+        "Should send SearchComplete(Ok)"
+    );
+}
+
 #[tokio::test]
 async fn test_run_search_error() {
     let mut harness = SideEffectsTestHarness::new().await;
@@ -185,6 +267,7 @@ async fn test_run_search_error() {
                 search_defaults,
                 search_mode: SearchMode::Normal,
                 realtime_window: None,
+                finalize_after: None,
             },
             2,
         )
@@ -363,3 +446,73 @@ async fn test_load_saved_searches_success() {
 
     assert_eq!(searches.len(), 2);
 }
+
+#[tokio::test]
+async fn test_load_top_values_uses_original_time_range() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    Mock::given(method("POST"))
+        .and(path("/services/search/jobs"))
+        .and(body_string_contains(
+            "search=search+index%3Dweb+%7C+top+limit%3D20+status",
+        ))
+        .and(body_string_contains("earliest_time=-4h"))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(serde_json::json!({"sid": "top-sid"})),
+        )
+        .mount(&harness.mock_server)
+        .await;
+
+    let job_status = serde_json::json!({
+        "entry": [{
+            "content": {
+                "sid": "top-sid",
+                "isDone": true,
+                "doneProgress": 1.0,
+                "runDuration": 0.1,
+                "scanCount": 4,
+                "eventCount": 4,
+                "resultCount": 2,
+                "diskUsage": 0
+            }
+        }]
+    });
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/top-sid"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&job_status))
+        .mount(&harness.mock_server)
+        .await;
+
+    let results = serde_json::json!({
+        "results": [
+            {"status": "500", "count": "3", "percent": "75.000000"},
+            {"status": "200", "count": "1", "percent": "25.000000"}
+        ]
+    });
+    Mock::given(method("GET"))
+        .and(path("/services/search/jobs/top-sid/results"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&results))
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(
+            Action::LoadTopValues {
+                query: "index=web | top limit=20 status".to_string(),
+                field: "status".to_string(),
+                earliest_time: "-4h".to_string(),
+                latest_time: "now".to_string(),
+            },
+            2,
+        )
+        .await;
+
+    let rows = actions
+        .iter()
+        .find_map(|a| match a {
+            Action::TopValuesLoaded { field, result } if field == "status" => result.as_ref().ok(),
+            _ => None,
+        })
+        .expect("Should send TopValuesLoaded(Ok)");
+    assert_eq!(rows.len(), 2);
+}
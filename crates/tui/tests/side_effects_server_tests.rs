@@ -0,0 +1,83 @@
+//! Server control side effect handler tests.
+//!
+//! This module tests RestartServer: the restart request is reported as soon as
+//! splunkd accepts it, and failures are surfaced without waiting for a restart.
+
+mod common;
+
+use common::*;
+use wiremock::matchers::{method, path};
+
+fn server_info_body() -> serde_json::Value {
+    serde_json::json!({
+        "entry": [{
+            "name": "server-info",
+            "content": {
+                "serverName": "splunk-local",
+                "version": "9.1.2",
+                "build": "123456",
+                "restart_required": true,
+                "startup_time": "1705773600"
+            }
+        }]
+    })
+}
+
+/// Test that an accepted restart sends ServerRestartRequested(Ok).
+#[tokio::test]
+async fn test_restart_server_reports_request() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(server_info_body()))
+        .mount(&harness.mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/services/server/control/restart"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness.handle_and_collect(Action::RestartServer, 2).await;
+
+    assert!(
+        actions
+            .iter()
+            .any(|a| matches!(a, Action::ServerRestartRequested(Ok(())))),
+        "Should send ServerRestartRequested(Ok)"
+    );
+}
+
+/// Test that a rejected restart sends ServerRestartRequested(Err) and stops.
+#[tokio::test]
+async fn test_restart_server_failure() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    Mock::given(method("GET"))
+        .and(path("/services/server/info"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(server_info_body()))
+        .mount(&harness.mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/services/server/control/restart"))
+        .respond_with(ResponseTemplate::new(403))
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness.handle_and_collect(Action::RestartServer, 2).await;
+
+    assert!(
+        actions
+            .iter()
+            .any(|a| matches!(a, Action::ServerRestartRequested(Err(_)))),
+        "Should send ServerRestartRequested(Err)"
+    );
+    assert!(
+        !actions
+            .iter()
+            .any(|a| matches!(a, Action::ServerRestarted(_))),
+        "Should not wait for a restart that was never accepted"
+    );
+}
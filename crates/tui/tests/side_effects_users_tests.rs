@@ -1,12 +1,14 @@
 //! Users side effect handler tests.
 //!
 //! This module tests the LoadUsers side effect handler which fetches
-//! user information from the Splunk REST API.
+//! user information from the Splunk REST API, the UnlockUser handler, and
+//! the LoadCurrentContext handler.
 
 mod common;
 
 use common::*;
-use wiremock::matchers::{method, path};
+use splunk_tui::ToastLevel;
+use wiremock::matchers::{body_string_contains, method, path};
 
 #[tokio::test]
 async fn test_load_users_success() {
@@ -52,3 +54,58 @@ async fn test_load_users_success() {
         "Should send UsersLoaded(Ok)"
     );
 }
+
+#[tokio::test]
+async fn test_unlock_user_notifies_and_reloads() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    let fixture = load_fixture("users/unlock_user.json");
+    Mock::given(method("POST"))
+        .and(path("/services/authentication/users/user1"))
+        .and(body_string_contains("locked-out=0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .expect(1)
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(
+            Action::UnlockUser {
+                name: "user1".to_string(),
+            },
+            3,
+        )
+        .await;
+
+    assert!(actions.iter().any(|a| matches!(
+        a,
+        Action::Notify(ToastLevel::Success, msg) if msg.contains("'user1' unlocked")
+    )));
+    assert!(
+        actions
+            .iter()
+            .any(|a| matches!(a, Action::LoadUsers { offset: 0, .. }))
+    );
+}
+
+#[tokio::test]
+async fn test_load_current_context_success() {
+    let mut harness = SideEffectsTestHarness::new().await;
+
+    let fixture = load_fixture("users/current_context.json");
+    Mock::given(method("GET"))
+        .and(path("/services/authentication/current-context"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture))
+        .mount(&harness.mock_server)
+        .await;
+
+    let actions = harness
+        .handle_and_collect(Action::LoadCurrentContext, 1)
+        .await;
+
+    assert!(actions.iter().any(|a| matches!(
+        a,
+        Action::CurrentContextLoaded(Ok(context))
+            if context.username == "analyst" && context.has_capability("list_inputs")
+    )));
+}
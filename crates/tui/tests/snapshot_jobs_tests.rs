@@ -70,6 +70,8 @@ fn snapshot_jobs_screen_running_with_progress() {
         cursor_time: Some("2024-01-15T10:30:00.000Z".to_string()),
         priority: Some(3),
         label: Some("Test search".to_string()),
+        ttl: None,
+        performance: None,
     }];
 
     harness.app.jobs = Some(jobs);
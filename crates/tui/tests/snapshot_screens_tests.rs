@@ -67,6 +67,43 @@ fn snapshot_job_details_screen_running_job() {
     insta::assert_snapshot!(harness.render());
 }
 
+#[test]
+fn snapshot_job_details_screen_expiring_job() {
+    let mut harness = TuiHarness::new(80, 24);
+    harness.app.current_screen = splunk_tui::CurrentScreen::JobInspect;
+    let mut jobs = create_mock_jobs();
+    jobs[0].ttl = Some(570);
+    harness.app.jobs = Some(jobs);
+    // Manually populate filtered_job_indices since tests don't trigger event handlers
+    harness.app.filtered_job_indices = vec![0, 1];
+    harness.app.jobs_state.select(Some(0));
+
+    insta::assert_snapshot!(harness.render());
+}
+
+#[test]
+fn snapshot_job_details_screen_performance_tab() {
+    let mut harness = TuiHarness::new(100, 30);
+    harness.app.current_screen = splunk_tui::CurrentScreen::JobInspect;
+    let jobs = create_mock_jobs();
+    let mut status = jobs[0].clone();
+    status.performance = serde_json::from_value(serde_json::json!({
+        "command.search": {"duration_secs": 0.82, "invocations": 6, "input_count": 0, "output_count": 1200},
+        "command.stats": {"duration_secs": 0.21, "invocations": 2, "input_count": 1200, "output_count": 4},
+        "dispatch.fetch": {"duration_secs": 1.9, "invocations": 7},
+        "dispatch.stream.remote.idx1": {"duration_secs": 1.1, "invocations": 4, "input_count": 800, "output_count": 2048}
+    }))
+    .unwrap();
+    harness.app.jobs = Some(jobs);
+    // Manually populate filtered_job_indices since tests don't trigger event handlers
+    harness.app.filtered_job_indices = vec![0, 1];
+    harness.app.jobs_state.select(Some(0));
+    harness.app.job_inspect_tab = splunk_tui::app::JobInspectTab::Performance;
+    harness.app.job_performance = Some(status);
+
+    insta::assert_snapshot!(harness.render());
+}
+
 #[test]
 fn snapshot_job_details_screen_no_job() {
     let mut harness = TuiHarness::new(80, 24);
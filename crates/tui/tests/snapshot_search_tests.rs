@@ -43,6 +43,24 @@ fn snapshot_search_screen_with_results() {
     insta::assert_snapshot!(harness.render());
 }
 
+#[test]
+fn snapshot_search_screen_expiring_job() {
+    let mut harness = TuiHarness::new(80, 24);
+    harness.app.current_screen = splunk_tui::CurrentScreen::Search;
+    harness.app.search_input.set_value("index=main ERROR");
+    harness.app.search_status = "Search complete: index=main ERROR".to_string();
+    harness.app.set_search_results(vec![
+        serde_json::json!({"_time": "2024-01-15T10:30:00.000Z", "level": "ERROR", "message": "Connection failed"}),
+    ]);
+    harness.app.search_sid = Some("search_12345".to_string());
+    let mut job = helpers::create_mock_jobs().remove(0);
+    job.sid = "search_12345".to_string();
+    job.ttl = Some(570);
+    harness.app.search_job = Some((job, std::time::Instant::now()));
+
+    insta::assert_snapshot!(harness.render());
+}
+
 #[test]
 fn snapshot_search_screen_empty() {
     let mut harness = TuiHarness::new(80, 24);